@@ -11,6 +11,13 @@ button-connect = Verbinden
 button-save = Speichern
 button-create = Erstellen
 button-edit = Bearbeiten
+button-check-ports = Ports prüfen
+button-statistics = Statistik
+button-dashboard = Dashboard
+button-refresh = Aktualisieren
+button-doors = Doors
+button-doors-leave = Verlassen
+button-doors-back = Zurück
 button-update = Aktualisieren
 button-accept-new-certificate = Neues Zertifikat akzeptieren
 button-close = Schließen
@@ -18,9 +25,23 @@ button-choose-avatar = Avatar auswählen
 button-clear-avatar = Löschen
 button-choose-image = Bild auswählen
 button-clear-image = Löschen
+button-add-file = Datei hinzufügen
 button-overwrite = Überschreiben
 button-change-password = Passwort ändern
 button-browse = Durchsuchen
+button-undo = Rückgängig
+button-add = Hinzufügen
+button-remove = Entfernen
+button-view = Anzeigen
+button-hide = Ausblenden
+button-export = Exportieren…
+button-trust-certificate = Zertifikat vertrauen
+button-skip = Überspringen
+button-skip-existing = Vorhandene überspringen
+button-download-anyway = Trotzdem herunterladen
+button-unlock = Entsperren
+button-view-as-user = Als Benutzer ansehen
+button-post-comment = Senden
 
 # =============================================================================
 # Titles
@@ -35,19 +56,29 @@ title-user-create = Benutzer erstellen
 title-user-edit = Benutzer bearbeiten
 title-update-user = Benutzer aktualisieren
 title-user-management = Benutzer verwalten
+title-permission-audit = Berechtigungsprüfung
+title-impersonate = Ansicht als { $username }
 title-confirm-delete = Löschen bestätigen
 title-connected = Verbunden
 title-settings = Einstellungen
 title-bookmarks = Lesezeichen
 title-users = Benutzer
+title-friends = Freunde
+friend-online = online
+friend-offline = offline
 title-edit-server-info = Server-Info bearbeiten
 title-fingerprint-mismatch = Zertifikat-Fingerabdruck stimmt nicht überein!
+title-fingerprint-new-server = Neues Serverzertifikat
+title-create-password-vault = Gespeicherte Passwörter schützen
+title-unlock-password-vault = Gespeicherte Passwörter entsperren
+title-unlock-config = Einstellungen entsperren
 title-server-info = Server-Info
 title-user-info = Benutzer-Info
 title-about = Über
 title-change-password = Passwort ändern
 title-news = Neuigkeiten
 title-transfers = Übertragungen
+title-transfer-history = Übertragungsverlauf
 title-news-create = Beitrag erstellen
 title-news-edit = Beitrag bearbeiten
 title-channel-members = Mitglieder
@@ -58,10 +89,14 @@ title-channel-members = Mitglieder
 
 placeholder-username = Benutzername
 placeholder-password = Passwort
+placeholder-master-passphrase = Master-Passphrase
+placeholder-confirm-master-passphrase = Master-Passphrase bestätigen
 placeholder-port = Port
 placeholder-server-address = Serveradresse
 placeholder-server-name = Servername
+placeholder-folder-name = Ordnername
 placeholder-username-optional = Benutzername (optional)
+placeholder-fallback-addresses-optional = Ausweichadressen (optional, kommagetrennt)
 placeholder-password-optional = Passwort (optional)
 placeholder-password-keep-current = Passwort
 placeholder-current-password = Aktuelles Passwort
@@ -70,10 +105,18 @@ placeholder-confirm-password = Passwort bestätigen
 placeholder-message = Nachricht eingeben…
 placeholder-no-permission = Keine Berechtigung
 placeholder-broadcast-message = Rundnachricht
+placeholder-broadcast-channel = Kanal wählen
+placeholder-broadcast-permission = Berechtigung wählen
 placeholder-server-description = Serverbeschreibung
 placeholder-news-body = Nachrichteninhalt
+placeholder-news-caption = Beschriftung (optional)
+placeholder-news-file-path = Pfad im Dateibereich
 placeholder-nickname-optional = Spitzname (optional)
+placeholder-status-optional = Status (optional)
+placeholder-download-folder-optional = Download-Ordner (optional)
 placeholder-proxy-address = Proxy-Adresse
+placeholder-notification-rules-channel = #kanal
+placeholder-notification-rules-keyword = Stichwort
 placeholder-proxy-port = 9050
 placeholder-proxy-username = Benutzername (optional)
 placeholder-proxy-password = Passwort (optional)
@@ -86,13 +129,20 @@ placeholder-auto-join-channels = #kanal1 #kanal2 (durch Leerzeichen getrennt)
 # =============================================================================
 
 label-auto-connect = Auto-Verbindung
+label-server-avatar = Avatar:
 label-add-bookmark = Lesezeichen
+label-lan-servers = In diesem Netzwerk
+label-use-sso = Single Sign-On
 label-admin = Admin
 label-shared-account = Gemeinsames Konto
 label-enabled = Aktiviert
+label-erase-personal-data = Persönliche Daten ebenfalls löschen
+label-broadcast-require-ack = Empfänger um Bestätigung bitten
 label-permissions = Berechtigungen:
 label-expected-fingerprint = Erwarteter Fingerabdruck:
 label-received-fingerprint = Empfangener Fingerabdruck:
+label-certificate-fingerprint = Zertifikat-Fingerabdruck:
+label-identity-key = Identitätsschlüssel:
 label-theme = Design
 label-chat-history-retention = Chatverlauf:
 chat-history-forever = Für immer
@@ -100,6 +150,10 @@ chat-history-30-days = 30 Tage
 chat-history-14-days = 14 Tage
 chat-history-7-days = 7 Tage
 chat-history-disabled = Deaktiviert
+collision-policy-rename = Beide behalten (umbenennen)
+collision-policy-overwrite = Überschreiben
+collision-policy-skip = Überspringen
+collision-policy-ask = Nachfragen
 label-max-scrollback = Max. Verlauf:
 label-chat-font-size = Schriftgröße:
 label-show-connection-events = Verbindungsereignisse anzeigen
@@ -107,6 +161,19 @@ label-show-channel-events = Beitritts-/Verlassensereignisse anzeigen
 label-show-timestamps = Zeitstempel anzeigen
 label-use-24-hour-time = 24-Stunden-Format verwenden
 label-show-seconds = Sekunden anzeigen
+label-split-view = Dateien, Neuigkeiten und Verbindungsmonitor neben dem Chat anzeigen
+label-spell-check = Rechtschreibung in Chat und Neuigkeiten prüfen
+label-spell-check-no-dictionary = Für deine Sprache wurde kein Wörterbuch gefunden. Lege die .aff- und .dic-Dateien in den Ordner dictionaries neben config.json.
+label-send-read-receipts = Lesebestätigungen für Direktnachrichten senden
+label-user-list-grouping = Benutzerliste gruppieren:
+label-user-list-sort = Benutzerliste sortieren:
+user-list-group-none = Keine
+user-list-group-admins = Admins zuerst
+user-list-group-channel = Nach Kanal
+user-list-group-status = Nach Status
+user-list-sort-alphabetical = Alphabetisch
+user-list-sort-idle = Inaktivität
+user-list-sort-connect = Verbindungszeit
 label-server-name = Name:
 label-server-description = Beschreibung:
 label-server-version = Version:
@@ -140,6 +207,27 @@ tab-files = Dateien
 tab-channels = Kanäle
 label-connections-short = Verbindungen:
 label-transfers-short = Übertragungen:
+label-active-transfers-short = Aktiv:
+label-queue-order-short = Warteschlange:
+label-trusted-weight-short = Vertrauensgewicht:
+label-nat-mapping-short = Portweiterleitung:
+label-nat-external-ip-short = Externe IP:
+label-nat-not-mapped = Nicht weitergeleitet
+connectivity-check-running = Ports werden geprüft…
+check-service-bbs = BBS
+check-service-transfer = Übertragungen
+check-service-websocket = WebSocket
+check-service-transfer-websocket = WebSocket-Übertragungen
+check-service-voice = Sprache
+check-state-open = Offen
+check-state-closed = Geschlossen
+check-state-filtered = Gefiltert
+check-state-untested = Nicht geprüft
+label-check-port = { $service } ({ $protocol } { $port }):
+label-queue-order-small-first = Kleine Dateien zuerst
+label-queue-order-arrival = Eingangsreihenfolge
+label-small-files-first = Kleine Dateien zuerst einreihen
+label-unlimited = Unbegrenzt
 label-reindex-short = Neuindiz.:
 label-persistent-short = Permanent:
 label-auto-join-short = Auto-Beitritt:
@@ -156,6 +244,19 @@ label-download-location = Download-Ordner:
 label-queue-transfers = Übertragungen in Warteschlange stellen
 label-download-limit = Download-Limit:
 label-upload-limit = Upload-Limit:
+label-auto-open-folder = Ordner nach abgeschlossenem Download öffnen
+label-collision-policy = Falls Datei existiert:
+label-require-fingerprint-approval = Vor dem Vertrauen eines neuen Serverzertifikats fragen
+label-known-servers = Bekannte Server
+known-servers-empty = Noch keine Serverzertifikate gespeichert
+known-server-first-seen = Zuerst gesehen { $date }
+known-server-first-seen-unknown = Zuerst gesehen: unbekannt
+label-encrypt-config = Einstellungen, Lesezeichen und Chatverlauf mit einer Master-Passphrase verschlüsseln
+config-encryption-locked-note = Die Einstellungen sind gesperrt. Starte Nexus neu und gib die Master-Passphrase ein, um dies zu ändern.
+label-upload-rule-extensions = Erlaubte Dateitypen:
+label-upload-rule-max-file-size = Größte Datei (MB):
+label-upload-rule-max-total-size = Größter Upload (MB):
+label-auto-download-pattern = Dateinamen:
 label-persistent-channels = Permanente Kanäle:
 label-auto-join-channels = Auto-Beitritts-Kanäle:
 label-channels = Kanäle
@@ -169,11 +270,14 @@ permission-user_info = Benutzerinfo
 permission-chat_send = Chat Senden
 permission-chat_receive = Chat Empfangen
 permission-chat_join = Chat Beitreten
+permission-chat_language = Chat-Sprache
 permission-chat_create = Chat Erstellen
 permission-chat_list = Chat Liste
 permission-chat_secret = Chat Geheim
 permission-chat_topic = Chat-Thema
 permission-chat_topic_edit = Chat-Thema Bearbeiten
+permission-chat_topic_lock = Chat-Thema Sperren
+permission-chat_entry_edit = Chat-Begrüßung Bearbeiten
 permission-user_broadcast = Benutzer-Rundnachricht
 permission-user_create = Benutzer Erstellen
 permission-user_delete = Benutzer Löschen
@@ -184,6 +288,7 @@ permission-news_list = Neuigkeiten Anzeigen
 permission-news_create = Neuigkeiten Erstellen
 permission-news_edit = Neuigkeiten Bearbeiten
 permission-news_delete = Neuigkeiten Löschen
+permission-file_comment = Datei kommentieren
 permission-file_copy = Datei Kopieren
 permission-file_create_dir = Verzeichniserstellung
 permission-file_delete = Datei Löschen
@@ -203,6 +308,11 @@ permission-trust_create = Vertrauen Erstellen
 permission-trust_delete = Vertrauen Löschen
 permission-trust_list = Vertrauensliste
 permission-connection_monitor = Verbindungsmonitor
+permission-log_view = Log anzeigen
+permission-door_play = Doors spielen
+permission-voice_broadcast = Sprachübertragung
+permission-voice_clip_upload = Sprache Clip Hochladen
+permission-voice_limit = Sprache Limit
 permission-voice_listen = Sprache Hören
 permission-voice_talk = Sprache Sprechen
 
@@ -227,19 +337,31 @@ tooltip-files-paste = Einfügen
 tooltip-files-search = Dateien suchen
 tooltip-manage-users = Benutzer Verwalten
 tooltip-connection-monitor = Verbindungsmonitor
+tooltip-server-log = Server-Log
 tooltip-server-info = Server-Info
 tooltip-about = Über
 tooltip-transfers = Übertragungen
 tooltip-download-all = Alles herunterladen
 tooltip-upload = Hochladen
 file-picker-upload-title = Dateien zum Hochladen auswählen
+file-picker-download-to-title = Download-Ordner auswählen
+folder-picker-upload-watch-title = Ordner für automatisches Hochladen wählen
 drop-to-upload = Zum Hochladen ablegen
 tooltip-clear-inactive = Inaktive löschen
+tooltip-transfer-history = Übertragungsverlauf
+tooltip-transfer-history-clear = Verlauf löschen
+tooltip-transfer-history-remove = Aus dem Verlauf entfernen
 tooltip-transfer-pause = Pausieren
 tooltip-transfer-resume = Fortsetzen
 tooltip-transfer-cancel = Abbrechen
 tooltip-transfer-remove = Entfernen
 tooltip-transfer-open-folder = Ordner öffnen
+tooltip-transfer-open-file = Datei öffnen
+tooltip-transfer-reveal = Im Dateimanager anzeigen
+tooltip-transfer-copy-checksum = SHA-256 kopieren
+tooltip-transfer-collision-rename = Beide behalten (umbenennen)
+tooltip-transfer-collision-overwrite = Vorhandene Dateien überschreiben
+tooltip-transfer-collision-skip = Vorhandene Dateien überspringen
 tooltip-transfer-move-up = Nach oben
 tooltip-transfer-move-down = Nach unten
 tooltip-transfer-retry = Erneut versuchen
@@ -252,16 +374,34 @@ tooltip-disconnect = Trennen
 tooltip-edit = Bearbeiten
 tooltip-info = Info
 tooltip-message = Nachricht
+tooltip-add-friend = Freund hinzufügen
+tooltip-remove-friend = Freund entfernen
 tooltip-kick = Rauswerfen
 tooltip-close = Schließen
+tooltip-retry-send = Erneut senden
+tooltip-cancel-send = Senden abbrechen
+tooltip-message-sent = Gesendet
+tooltip-message-delivered = Zugestellt
+tooltip-message-read = Gelesen
 tooltip-new-tab = Neuer Tab
 tooltip-close-tab = Tab schließen
 tooltip-add-bookmark = Lesezeichen hinzufügen
+tooltip-new-folder = Neuer Ordner
 tooltip-create-user = Benutzer erstellen
+tooltip-permission-audit = Berechtigungen prüfen
 tooltip-create-news = Beitrag erstellen
+tooltip-news-search = Neuigkeiten durchsuchen
+tooltip-news-search-clear = Suche löschen
 tooltip-delete = Löschen
 context-menu-download = Herunterladen
+context-menu-download-to = Herunterladen nach…
+context-menu-download-select = Auswahl herunterladen…
+context-menu-auto-download = Automatisch herunterladen…
 context-menu-upload = Hochladen
+context-menu-watch-folder = Lokalen Ordner überwachen…
+context-menu-screenshot-folder = Screenshots hier teilen
+context-menu-unwatch-folder = Ordnerüberwachung beenden
+context-menu-upload-rules = Upload-Regeln…
 context-menu-open = Öffnen
 
 # =============================================================================
@@ -269,6 +409,7 @@ context-menu-open = Öffnen
 # =============================================================================
 
 panel-connection-monitor = Verbindungsmonitor
+panel-server-log = Server-Log
 panel-active-connections = Aktive Verbindungen: { $count }
 tab-connections = Verbindungen
 tab-transfers = Übertragungen
@@ -283,9 +424,97 @@ menu-copy = Kopieren
 menu-info = Info
 menu-kick = Rauswerfen
 menu-ban = Sperren
+menu-pop-out = Abdocken
+menu-mute-tab = Stummschalten
+menu-unmute-tab = Stummschaltung aufheben
+menu-connect-all = Alle verbinden
+menu-rename = Umbenennen
+menu-delete = Löschen
 connection-monitor-loading = Verbindungen werden geladen…
 connection-monitor-no-connections = Keine Verbindungen
 connection-monitor-no-transfers = Keine aktiven Übertragungen
+connection-monitor-queued = Wartend
+connection-monitor-weight = Fair-Share-Gewicht: ×{ $weight }
+connection-monitor-listed = Gelistet in { $zone }
+connection-monitor-queued-count = { $count } wartend
+placeholder-server-log-module = Nach Modul filtern
+server-log-loading = Server-Log wird geladen…
+server-log-empty = Keine Log-Einträge
+server-log-level-error = Nur Fehler
+server-log-level-warn = Warnungen und höher
+server-log-level-info = Info und höher
+server-log-level-debug = Alles (Debug)
+panel-statistics = Statistik
+stats-loading = Statistik wird geladen…
+panel-snippet = Snippet
+snippet-loading = Snippet wird geladen…
+snippet-details = Geteilt von { $author } · läuft ab am { $expires }
+stats-range-days = Letzte { $days } Tage
+stats-chart-max = Höchster Tag: { $value }
+stats-metric-logins = Anmeldungen
+stats-metric-messages = Nachrichten
+stats-metric-uploads = Uploads
+stats-metric-upload-bytes = Hochgeladene Daten
+stats-metric-downloads = Downloads
+stats-metric-download-bytes = Heruntergeladene Daten
+stats-metric-peak-sessions = Max. Benutzer online
+panel-admin-dashboard = Admin-Dashboard
+dashboard-loading = Dashboard wird geladen…
+dashboard-connections = Verbindungen
+dashboard-sessions = Benutzer online
+dashboard-connection-count = Verbindungen
+dashboard-transfer-connections = Übertragungsverbindungen
+dashboard-transfers = Übertragungen
+dashboard-active-transfers = Laufend
+dashboard-queued-transfers = In Warteschlange
+dashboard-today = Heute
+dashboard-disk = Dateibereich
+dashboard-disk-free = Freier Speicher
+dashboard-disk-free-of = { $free } von { $total } frei
+dashboard-disk-unknown = Unbekannt
+dashboard-recent-audit = Letzte Audit-Ereignisse
+dashboard-audit-empty = Noch keine Audit-Ereignisse
+dashboard-updated = Aktualisiert um { $time }
+panel-doors = Doors
+doors-loading = Doors werden geladen…
+doors-empty = Keine Doors auf diesem Server
+doors-starting = Wird gestartet…
+doors-playing-hint = Tastatureingaben gehen an die Door. Mit Verlassen beenden.
+doors-exited = Die Door wurde beendet
+doors-exited-code = Die Door wurde mit Code { $code } beendet
+
+# =============================================================================
+# Quick Switcher
+# =============================================================================
+
+quick-switcher-placeholder = Zu Kanal, Nachricht, Lesezeichen oder Bereich springen...
+user-list-filter-placeholder = Benutzer filtern...
+user-list-section-admins = Admins
+user-list-section-users = Benutzer
+user-list-section-no-channel = Andere
+user-list-section-online = Online
+user-list-section-away = Abwesend
+user-list-section-dnd = Nicht stören
+user-list-idle = Inaktiv seit { $duration }
+quick-switcher-no-matches = Keine Treffer
+quick-switcher-bookmark = Lesezeichen
+
+# =============================================================================
+# Chat Input
+# =============================================================================
+
+chat-paste-prompt = { $count } Zeilen einfügen?
+chat-paste-too-long-prompt = Zu lang für eine Nachricht (max. { $max }). Trotzdem senden?
+button-send-as-messages = Als { $count } Nachrichten senden
+button-send-as-one-message = Als eine Nachricht senden
+button-send-in-parts = In { $count } Teilen senden
+button-share-as-snippet = Als Snippet teilen
+button-expand-code = Alle { $count } Zeilen anzeigen
+button-collapse-code = Einklappen
+chat-broadcast-ack-prompt = { $username } bittet Sie, die Rundnachricht zu bestätigen
+button-acknowledge = Bestätigen
+button-dismiss = Schließen
+spell-no-suggestions = Keine Vorschläge
 
 # =============================================================================
 # Empty States
@@ -296,10 +525,31 @@ empty-no-connections = Keine Verbindungen
 empty-no-bookmarks = Keine Lesezeichen
 empty-no-users = Keine Benutzer online
 empty-no-channel-members = Keine Mitglieder im Kanal
+empty-no-matching-users = Keine passenden Benutzer
 user-management-loading = Benutzer werden geladen…
 user-management-no-users = Keine Benutzer gefunden
+permission-audit-hint = Wähle eine Berechtigung, um zu sehen, wer sie hat, oder klicke auf einen Benutzer, um alles zu sehen, was er darf.
+permission-audit-loading = Wird geladen…
+permission-audit-no-holders = Kein Konto hat diese Berechtigung
+permission-audit-effective = Wirksame Berechtigungen von { $username }
+permission-audit-admin = Administratoren haben alle Berechtigungen
+permission-audit-no-permissions = Dieses Konto hat keine Berechtigungen
+permission-audit-restricted = Vergeben, aber für geteilte Konten nicht verfügbar:
+permission-audit-disabled = Deaktiviert
+impersonate-notice = Schreibgeschützte Ansicht dessen, was dieses Konto sieht. Jeder Aufruf wird im Prüfprotokoll festgehalten.
+impersonate-permissions = Berechtigungen
+impersonate-files = Dateien ({ $path })
+impersonate-no-file-access = Dieses Konto kann keine Dateien durchsuchen
+impersonate-empty-folder = Dieser Ordner ist leer
+impersonate-channels = Kanäle
+impersonate-no-channel-access = Dieses Konto kann keine Kanäle auflisten
+impersonate-no-channels = Keine sichtbaren Kanäle
+impersonate-secret = Geheim
 news-loading = Neuigkeiten werden geladen…
 news-no-posts = Noch keine Beiträge vorhanden
+news-search-placeholder = Neuigkeiten durchsuchen… (author:Name since:JJJJ-MM-TT until:JJJJ-MM-TT)
+news-searching = Suche läuft…
+news-search-no-results = Keine passenden Beiträge
 news-updated = Aktualisiert
 files-loading = Dateien werden geladen…
 files-empty = Dieses Verzeichnis ist leer
@@ -311,10 +561,19 @@ files-column-name = Name
 files-column-size = Größe
 files-column-modified = Geändert
 files-column-path = Pfad
+files-column-rating = Bewertung
 files-search-placeholder = Dateien suchen…
 files-search-breadcrumb = Suche - { $query }
 files-searching = Suche läuft…
 files-no-results = Keine Dateien gefunden
+files-highlights = Highlights
+files-highlights-recent = Neue Uploads dieser Woche
+files-highlights-popular = Am häufigsten heruntergeladen
+files-highlights-empty = Noch nichts vorhanden
+files-highlights-downloads = { $count } { $count ->
+    [one] Download
+   *[other] Downloads
+}
 files-search-query-too-short = Suchanfrage ist zu kurz (min. { $min_length } Zeichen)
 files-search-query-too-long = Suchanfrage ist zu lang (max. { $max_length } Zeichen)
 files-search-query-invalid = Suchanfrage enthält ungültige Zeichen
@@ -325,6 +584,30 @@ files-share = Teilen
 files-rename = Umbenennen
 files-rename-title = "{ $name }" umbenennen
 files-rename-placeholder = Neuer Name
+files-upload-rule-title = Upload-Regeln für "{ $name }"
+files-upload-rule-loading = Wird geladen…
+files-upload-rule-extensions-placeholder = Jeder Typ (z. B. mp3, flac)
+files-upload-rule-no-limit = Keine Begrenzung
+files-upload-rule-remove = Regel entfernen
+files-upload-rule-help = Regeln gelten für diesen Ordner und seine Unterordner, sofern ein Unterordner keine eigene hat.
+files-auto-download-title = Automatisch herunterladen aus "{ $name }"
+files-auto-download-pattern-placeholder = z. B. *.mp3, *.m4a
+files-auto-download-remove = Nicht mehr automatisch herunterladen
+files-auto-download-help = Neue Dateien in diesem Ordner, deren Namen passen, werden heruntergeladen, während Sie über dieses Lesezeichen verbunden sind. Verwenden Sie * für beliebigen Text und ? für ein einzelnes Zeichen, und trennen Sie Muster mit Kommas. Bereits heruntergeladene Dateien werden übersprungen.
+files-upload-rule-hint = Uploads hier: { $limits }
+files-upload-rule-hint-types = nur { $extensions }
+files-upload-rule-hint-file-size = bis { $size } pro Datei
+files-upload-rule-hint-total-size = bis { $size } pro Upload
+files-upload-preview-title = Dateien existieren bereits
+files-upload-preview-comparing = Wird mit den Dateien auf dem Server verglichen…
+files-upload-preview-summary = { $new } neu, { $identical } bereits auf dem Server, { $conflicts } abweichend:
+files-upload-preview-blocked = { $count } { $count ->
+    [one] Datei hat
+   *[other] Dateien haben
+} einen gleichnamigen Ordner auf dem Server und { $count ->
+    [one] wird
+   *[other] werden
+} übersprungen
 files-cut = Ausschneiden
 files-copy = Kopieren
 files-paste = Einfügen
@@ -334,6 +617,13 @@ files-delete-confirm-title = Löschen bestätigen
 files-delete-confirm-message = Möchten Sie "{ $name }" wirklich löschen?
 # File Overwrite Dialog
 files-overwrite-title = Datei existiert bereits
+files-download-to-title = { $name } herunterladen
+label-remember-for-server = Für diesen Server merken
+label-select-all = Alle auswählen
+files-download-select-summary = { $count } von { $total } ausgewählt ({ $size })
+files-download-select-too-many = Es können höchstens { $max } einzelne Dateien ausgewählt werden. Lade stattdessen den ganzen Ordner herunter.
+files-download-duplicate-title = Bereits heruntergeladen
+files-download-duplicate-message = "{ $name }" ist bereits auf diesem Computer vorhanden unter { $path }. Möchten Sie die Datei erneut herunterladen?
 files-overwrite-message = Eine Datei oder ein Ordner mit dem Namen "{ $name }" existiert bereits am Zielort. Möchten Sie sie ersetzen?
 
 # File Info Dialog
@@ -349,6 +639,17 @@ files-info-directory = Verzeichnis
 files-info-symlink = Symlink:
 files-info-yes = Ja
 files-info-na = N/A
+files-info-rating = Bewertung:
+files-info-rating-value = { $average } ({ $count } { $count ->
+    [one] Bewertung
+   *[other] Bewertungen
+})
+files-info-not-rated = Noch nicht bewertet
+files-info-your-rating = Deine Bewertung:
+files-info-comments = Kommentare ({ $count })
+files-comments-loading = Kommentare werden geladen…
+files-comments-empty = Noch keine Kommentare
+files-comment-placeholder = Kommentar schreiben…
 
 # =============================================================================
 # Directory Name Validation Errors
@@ -367,6 +668,7 @@ err-dir-name-invalid = Verzeichnisname enthält ungültige Zeichen
 tab-general = Allgemein
 tab-chat = Chat
 tab-network = Netzwerk
+tab-security = Sicherheit
 tab-audio = Audio
 
 # =============================================================================
@@ -388,6 +690,8 @@ chat-prefix-system = [SYS]
 chat-prefix-error = [FEH]
 chat-prefix-info = [INFO]
 chat-prefix-broadcast = [BROADCAST]
+chat-prefix-queued = [PENDING]
+chat-export-subtitle = Exportiert von { $server } am { $date }
 
 # =============================================================================
 # Success Messages
@@ -411,11 +715,28 @@ msg-channel-member-count = { $count } { $count ->
    *[other] Mitglieder
 }
 channel-secret = geheim
+channel-info-topic = Thema
+channel-info-rules = Regeln
+channel-info-language = Sprache
 msg-broadcast-sent = Broadcast erfolgreich gesendet
+msg-broadcast-delivered = Rundnachricht an { $count } { $count ->
+    [one] Benutzer
+   *[other] Benutzer
+} zugestellt
+msg-broadcast-scheduled = Rundnachricht geplant für { $time }
+msg-broadcast-acknowledged = Rundnachricht von { $count } von { $delivered } Benutzern bestätigt
+msg-storage-low = Wenig Speicherplatz auf dem Server für { $volume }: { $free } frei, unter dem Minimum von { $threshold }
+msg-storage-recovered = Speicherplatz auf dem Server für { $volume } wieder ausreichend: { $free } frei
+storage-volume-files = den Dateibereich
+storage-volume-database = die Datenbank
+msg-friend-added = { $username } zu Freunden hinzugefügt
+msg-friend-removed = { $username } aus Freunden entfernt
 msg-user-created = Benutzer erfolgreich erstellt
 msg-user-created-name = Benutzer '{ $username }' erfolgreich erstellt
 msg-user-deleted = Benutzer erfolgreich gelöscht
 msg-user-deleted-name = Benutzer '{ $username }' erfolgreich gelöscht
+msg-user-erased = Benutzer gelöscht und persönliche Daten entfernt
+msg-user-erased-name = Benutzer '{ $username }' gelöscht und persönliche Daten entfernt
 msg-user-updated = Benutzer erfolgreich aktualisiert
 msg-user-updated-name = Benutzer '{ $username }' erfolgreich aktualisiert
 msg-permissions-updated = Deine Berechtigungen wurden aktualisiert
@@ -430,6 +751,8 @@ msg-welcome-back = Willkommen zurück
 msg-status-set = Status gesetzt: { $status }
 msg-status-cleared = Status gelöscht
 msg-user-is-away = { $nickname } ist abwesend
+msg-group-member-joined = { $nickname } ist dem Gespräch beigetreten
+msg-group-member-left = { $nickname } hat das Gespräch verlassen
 msg-user-is-away-status = { $nickname } ist abwesend: { $status }
 
 
@@ -441,6 +764,14 @@ msg-topic-cleared = Thema gelöscht von { $username }
 msg-topic-set = Thema gesetzt von { $username }: { $topic }
 msg-secret-set = Geheimmodus aktiviert von { $username }
 msg-secret-cleared = Geheimmodus deaktiviert von { $username }
+msg-topic-locked = Thema gesperrt von { $username }
+msg-topic-unlocked = Thema entsperrt von { $username }
+msg-rules-set = Kanalregeln aktualisiert von { $username }
+msg-rules-cleared = Kanalregeln gelöscht von { $username }
+msg-language-set = Kanalsprache von { $username } auf { $language } gesetzt
+msg-language-cleared = Kanalsprache von { $username } entfernt
+msg-voice-limit-set = Sprachlimit auf { $limit } gesetzt von { $username }
+msg-voice-limit-cleared = Sprachlimit aufgehoben von { $username }
 msg-server-info-updated = Serverkonfiguration aktualisiert
 msg-topic-display = Thema: { $topic }
 confirm-delete-user = Möchten Sie den Benutzer '{ $username }' wirklich löschen?
@@ -460,6 +791,7 @@ err-user-kick-failed = Benutzer konnte nicht rausgeworfen werden
 err-unknown = Unbekannter Fehler
 err-no-shutdown-handle = Verbindungsfehler: Kein Shutdown-Handle
 err-userlist-failed = Benutzerliste konnte nicht aktualisiert werden
+err-request-timeout = Der Server hat nicht rechtzeitig geantwortet
 err-port-invalid = Port muss eine gültige Zahl sein (1-65535)
 
 # Network connection errors
@@ -473,12 +805,16 @@ err-connection-closed = Verbindung geschlossen
 err-could-not-determine-config-dir = Konfigurationsverzeichnis konnte nicht ermittelt werden
 err-message-too-long = Nachricht ist zu lang ({ $length } Zeichen, max { $max })
 err-send-failed = Nachricht konnte nicht gesendet werden
+err-checksum-failed = Prüfsumme konnte nicht berechnet werden
 err-no-chat-permission = Sie haben keine Berechtigung, Nachrichten zu senden
+err-chat-window-command = In einem Chatfenster können nur Nachrichten und /me gesendet werden
 err-console-no-send = Verwenden Sie /{ $join }, um einem Kanal beizutreten, oder /{ $msg }, um eine Nachricht an einen Benutzer zu senden
 err-broadcast-too-long = Rundnachricht ist zu lang ({ $length } Zeichen, max { $max })
 err-broadcast-send-failed = Rundnachricht konnte nicht gesendet werden
+err-broadcast-target-required = Wählen Sie, wer die Rundnachricht erhält
 err-name-required = Lesezeichenname ist erforderlich
 err-address-required = Serveradresse ist erforderlich
+err-fallback-address-invalid = Ungültige Ausweichadresse: { $address }
 err-port-required = Port ist erforderlich
 err-username-required = Benutzername ist erforderlich
 err-password-required = Passwort ist erforderlich
@@ -496,13 +832,20 @@ err-nickname-too-long = Spitzname ist zu lang (max { $max } Zeichen)
 err-nickname-invalid = Spitzname enthält ungültige Zeichen
 err-password-too-long = Passwort ist zu lang (max { $max } Zeichen)
 err-passwords-do-not-match = Passwörter stimmen nicht überein
+err-wrong-master-passphrase = Falsche Master-Passphrase
+err-master-passphrase-empty = Master-Passphrase darf nicht leer sein
+err-password-vault = Fehler im Passwort-Tresor: { $error }
 err-current-password-required = Aktuelles Passwort ist erforderlich
 err-new-password-required = Neues Passwort ist erforderlich
 err-confirm-password-required = Bitte bestätigen Sie Ihr neues Passwort
 err-topic-too-long = Thema ist zu lang ({ $length } Zeichen, max { $max })
+err-entry-message-too-long = Begrüßungsnachricht ist zu lang ({ $length } Zeichen, max { $max })
+err-rules-too-long = Regeln sind zu lang ({ $length } Zeichen, max { $max })
 err-avatar-unsupported-type = Nicht unterstützter Dateityp. Verwenden Sie PNG, WebP, JPEG oder SVG.
 err-avatar-too-large = Avatar zu groß. Maximale Größe ist { $max_kb }KB.
 err-avatar-decode-failed = Avatar konnte nicht dekodiert werden. Die Datei ist möglicherweise beschädigt.
+err-known-server-export = Zertifikat konnte nicht exportiert werden: { $error }
+err-config-encryption = Einstellungen konnten nicht verschlüsselt werden: { $error }
 err-server-name-empty = Servername darf nicht leer sein
 err-server-name-too-long = Servername ist zu lang (max. { $max } Zeichen)
 err-server-name-contains-newlines = Servername darf keine Zeilenumbrüche enthalten
@@ -523,14 +866,23 @@ err-failed-send-update = Aktualisierung konnte nicht gesendet werden: { $error }
 # =============================================================================
 
 err-failed-save-config = Konfiguration konnte nicht gespeichert werden: { $error }
+err-friends-no-bookmark = Freunde werden im Lesezeichen des Servers gespeichert; lege zuerst ein Lesezeichen an
+err-friends-too-many = Zu viele Freunde (max. { $max })
+err-friend-exists = { $username } ist bereits ein Freund
+err-friend-not-found = { $username } ist kein Freund
 err-failed-save-settings = Einstellungen konnten nicht gespeichert werden: { $error }
 err-invalid-port-bookmark = Ungültiger Port im Lesezeichen: { $name }
 err-failed-send-broadcast = Rundnachricht konnte nicht gesendet werden: { $error }
 err-failed-send-message = Nachricht konnte nicht gesendet werden: { $error }
+err-failed-start-group = Gruppengespräch konnte nicht gestartet werden: { $error }
+err-failed-share-snippet = Snippet konnte nicht geteilt werden: { $error }
 err-failed-create-user = Benutzer konnte nicht erstellt werden: { $error }
 err-failed-delete-user = Benutzer konnte nicht gelöscht werden: { $error }
 err-failed-update-user = Benutzer konnte nicht aktualisiert werden: { $error }
 err-failed-update-topic = Thema konnte nicht aktualisiert werden: { $error }
+err-failed-lock-topic = Thema konnte nicht gesperrt werden: { $error }
+err-failed-set-language = Kanalsprache konnte nicht gesetzt werden: { $error }
+err-failed-update-entry = Kanal konnte nicht aktualisiert werden: { $error }
 err-message-too-long-details = { $error } ({ $length } Zeichen, max { $max })
 
 # Network connection errors (with parameters)
@@ -545,6 +897,14 @@ err-tls-handshake-failed = TLS-Handshake fehlgeschlagen: { $error }
 err-failed-send-handshake = Handshake konnte nicht gesendet werden: { $error }
 err-failed-read-handshake = Handshake-Antwort konnte nicht gelesen werden: { $error }
 err-handshake-failed = Handshake fehlgeschlagen: { $error }
+err-proof-of-work-too-hard = Der Server verlangt zu viel Arbeitsnachweis (Schwierigkeit { $difficulty })
+err-sso-not-offered = Dieser Server bietet kein Single Sign-On an
+err-sso-failed = Single Sign-On fehlgeschlagen: { $error }
+err-sso-browser = Browser konnte nicht geöffnet werden: { $error }
+err-sso-timeout = Zeitüberschreitung beim Warten auf den Browser (Single Sign-On)
+err-sso-state-mismatch = Die Single-Sign-On-Antwort passt nicht zur Anfrage
+sso-browser-done = Angemeldet. Sie können dieses Fenster schließen und zu Nexus zurückkehren.
+sso-browser-failed = Anmeldung fehlgeschlagen. Sie können dieses Fenster schließen und zu Nexus zurückkehren.
 err-failed-parse-handshake = Handshake-Antwort konnte nicht analysiert werden: { $error }
 err-failed-send-login = Anmeldung konnte nicht gesendet werden: { $error }
 err-failed-read-login = Anmeldeantwort konnte nicht gelesen werden: { $error }
@@ -552,6 +912,7 @@ err-failed-parse-login = Anmeldeantwort konnte nicht analysiert werden: { $error
 err-failed-create-server-name = Servername konnte nicht erstellt werden: { $error }
 err-failed-create-config-dir = Konfigurationsverzeichnis konnte nicht erstellt werden: { $error }
 err-failed-serialize-config = Konfiguration konnte nicht serialisiert werden: { $error }
+err-config-locked = Die Einstellungen sind verschlüsselt und gesperrt; Änderungen können erst nach dem Entsperren gespeichert werden
 err-failed-write-config = Konfigurationsdatei konnte nicht geschrieben werden: { $error }
 err-failed-read-config-metadata = Metadaten der Konfigurationsdatei konnten nicht gelesen werden: { $error }
 err-failed-set-config-permissions = Berechtigungen der Konfigurationsdatei konnten nicht gesetzt werden: { $error }
@@ -561,6 +922,10 @@ err-failed-set-config-permissions = Berechtigungen der Konfigurationsdatei konnt
 # =============================================================================
 
 fingerprint-warning = Dies könnte auf ein Sicherheitsproblem (MITM-Angriff) hinweisen oder das Serverzertifikat wurde neu generiert. Akzeptieren Sie nur, wenn Sie dem Serveradministrator vertrauen.
+fingerprint-new-server-prompt = Dies ist die erste Verbindung zu diesem Server. Vertraue dem Zertifikat nur, wenn der Fingerabdruck unten mit dem vom Serveradministrator veröffentlichten übereinstimmt.
+password-vault-create-description = Es ist kein Systemschlüsselbund verfügbar. Wähle eine Master-Passphrase, um deine gespeicherten Lesezeichen-Passwörter zu verschlüsseln, oder überspringe, um sie in der Konfigurationsdatei zu behalten.
+password-vault-unlock-description = Gib deine Master-Passphrase ein, um deine gespeicherten Lesezeichen-Passwörter zu entsperren. Wenn du überspringst, verbinden sich Lesezeichen bis zum Neustart von Nexus ohne Passwort.
+config-unlock-description = Deine Einstellungen, Lesezeichen und dein Chatverlauf sind verschlüsselt. Gib deine Master-Passphrase ein, um sie zu entsperren. Wenn du überspringst, startet Nexus mit Standardeinstellungen und nichts wird bis zum Neustart gespeichert.
 
 # =============================================================================
 # User Info Display
@@ -577,10 +942,12 @@ user-info-account = Konto: { $username }
 user-info-connected = Verbunden:
 user-info-connected-value = vor { $duration }
 user-info-connected-value-sessions = vor { $duration } ({ $count } Sitzungen)
+user-info-idle = Inaktiv:
 user-info-features = Funktionen:
 user-info-features-value = { $features }
 user-info-features-none = Keine
 user-info-locale = Sprache:
+user-info-client = Client:
 user-info-address = Adresse:
 user-info-addresses = Adressen:
 user-info-created = Erstellt:
@@ -589,6 +956,12 @@ user-info-unknown = Unbekannt
 user-info-loading = Benutzerinformationen werden geladen…
 user-info-status = Status:
 user-info-channels = Kanäle:
+user-info-rank = Rang:
+user-info-logins = Anmeldungen:
+user-info-messages = Nachrichten:
+user-info-uploads = Uploads:
+user-info-downloaded = Heruntergeladen:
+user-info-last-login = Letzte Anmeldung:
 
 # =============================================================================
 # Time Duration
@@ -624,21 +997,39 @@ cmd-me-usage = Verwendung: /{ $command } <aktion>
 err-me-no-target = Verwenden Sie /{ $join }, um einem Kanal beizutreten, oder /{ $msg }, um eine Aktion an einen Benutzer zu senden
 cmd-message-desc = Nachricht an Benutzer senden
 cmd-message-usage = Verwendung: /{ $command } <benutzername> <nachricht>
+cmd-group-desc = Ein Gruppengespräch mit mehreren Benutzern beginnen
+cmd-group-usage = Verwendung: /{ $command } <benutzername> [<benutzername>...]
+cmd-invite-desc = Einen Benutzer zum aktuellen Gruppengespräch hinzufügen
+cmd-invite-usage = Verwendung: /{ $command } <benutzername>
 cmd-ping-desc = Latenz zum Server messen
 cmd-ping-usage = Verwendung: /{ $command }
+cmd-radio-desc = Audio an die Sprachzuhörer des Kanals übertragen
+cmd-radio-usage = Verwendung: /{ $command }
+err-radio-no-channel = Dieser Befehl funktioniert nur in einem Kanal
+err-radio-in-voice = Verlasse den Sprachchat, bevor du eine Übertragung startest
 ping-result = Antwort: { $ms }ms
 cmd-userinfo-desc = Informationen über einen Benutzer anzeigen
 cmd-userinfo-usage = Verwendung: /{ $command } <benutzername>
 cmd-kick-desc = Benutzer vom Server entfernen
 cmd-kick-usage = Verwendung: /{ $command } <benutzername> [grund]
+cmd-top-desc = Aktivste Benutzer anzeigen
+cmd-top-usage = Verwendung: /{ $command } [logins|messages|uploads|downloaded]
 cmd-topic-desc = Chat-Thema anzeigen oder verwalten
-cmd-topic-usage = Verwendung: /{ $command } [set|clear] [thema]
+cmd-topic-usage = Verwendung: /{ $command } [set|clear|lock|unlock|history] [thema]
 cmd-topic-set-usage = Verwendung: /{ $command } set <thema>
 cmd-topic-none = Kein Thema gesetzt
 cmd-broadcast-desc = Broadcast an alle Benutzer senden
 cmd-broadcast-usage = Verwendung: /{ $command } <nachricht>
 cmd-clear-desc = Chat-Verlauf für aktuellen Tab löschen
 cmd-clear-usage = Verwendung: /{ $command }
+cmd-export-desc = Den aktuellen Tab als HTML-Datei speichern
+cmd-export-usage = Verwendung: /{ $command }
+cmd-clip-desc = Soundboard-Clips auflisten, abspielen, hinzufügen oder löschen
+cmd-clip-usage = Verwendung: /{ $command } [liste|abspielen <Name>|hinzufügen <Name>|löschen <Name>|<Name>]
+cmd-clip-arg-list = liste
+cmd-clip-arg-play = abspielen
+cmd-clip-arg-add = hinzufügen
+cmd-clip-arg-delete = löschen
 cmd-window-desc = Chat-Tabs verwalten
 cmd-window-usage = Verwendung: /{ $command } [weiter|zurück|schließen [benutzername]]
 cmd-window-arg-next = weiter
@@ -653,6 +1044,10 @@ cmd-window-not-found = Tab nicht gefunden: { $name }
 cmd-focus-desc = Server-Chat oder Nachrichtenfenster eines Benutzers fokussieren
 cmd-focus-usage = Verwendung: /{ $command } [benutzername]
 cmd-focus-not-found = Benutzer nicht gefunden: { $name }
+cmd-friend-desc = Freunde auflisten, hinzufügen oder entfernen
+cmd-friend-usage = Verwendung: /{ $command } [list] | add <Benutzername> | remove <Benutzername>
+cmd-friend-list-header = Freunde:
+cmd-friend-list-empty = Noch keine Freunde. Füge einen mit /{ $command } add <Benutzername> hinzu
 cmd-list-desc = Verbundene/alle Benutzer anzeigen
 cmd-list-arg-all = alle
 cmd-list-usage = Verwendung: /{ $command } [alle]
@@ -669,7 +1064,17 @@ cmd-list-all-output = Benutzer: { $users } ({ $count } { $count ->
 cmd-help-usage = Verwendung: /{ $command } [befehl]
 cmd-topic-arg-set = setzen
 cmd-topic-arg-clear = löschen
+cmd-topic-arg-lock = sperren
+cmd-topic-arg-unlock = entsperren
+cmd-topic-arg-history = verlauf
 cmd-topic-permission-denied = Sie haben keine Berechtigung, das Thema zu bearbeiten
+cmd-topic-lock-permission-denied = Sie haben keine Berechtigung, das Thema zu sperren
+msg-topic-already-locked = Thema ist bereits gesperrt
+msg-topic-already-unlocked = Thema ist bereits entsperrt
+msg-topic-history-header = Themenverlauf für { $channel }:
+msg-topic-history-set = { $time } { $username }: { $topic }
+msg-topic-history-cleared = { $time } { $username } hat das Thema gelöscht
+msg-topic-history-empty = Keine Themenänderungen aufgezeichnet
 cmd-serverinfo-desc = Serverinformationen anzeigen
 cmd-serverinfo-usage = Verwendung: /{ $command }
 cmd-serverinfo-header = [server]
@@ -686,15 +1091,55 @@ cmd-unban-desc = IP-Sperre aufheben
 cmd-unban-usage = Verwendung: /{ $command } <ziel>
 cmd-bans-desc = Aktive Sperren anzeigen
 cmd-bans-usage = Verwendung: /{ $command }
+cmd-register-desc = Aus einer Gastsitzung ein Konto registrieren
+cmd-register-usage = Verwendung: /{ $command } <benutzername> <passwort>
 cmd-reindex-desc = Datei-Neuindizierung auslösen
 cmd-reindex-usage = Verwendung: /{ $command }
+cmd-cleanup-desc = Überreste abgebrochener Übertragungen im Dateibereich finden
+cmd-cleanup-usage = Verwendung: /{ $command } [delete]
+cmd-filecheck-desc = Dateibereich mit Suchindex und Dateimetadaten abgleichen
+cmd-filecheck-usage = Verwendung: /{ $command } [repair]
+cmd-screenshot-desc = Einen kommentierten Screenshot im aktuellen Tab teilen
+cmd-screenshot-usage = Verwendung: /{ $command }
+err-screenshot-no-target = Screenshots können nur in einem Kanal oder einer Privatnachricht geteilt werden
+err-screenshot-no-folder = Kein Screenshot-Ordner festgelegt – wähle in Dateien bei einem Upload-Ordner „Screenshots hier teilen“
+err-screenshot-capture = Screenshot fehlgeschlagen: { $error }
+err-screenshot-save = Screenshot konnte nicht gespeichert werden: { $error }
+err-screenshot-upload = Screenshot-Upload fehlgeschlagen: { $error }
+err-export-empty = In diesem Tab gibt es keine Nachrichten zum Exportieren
+err-chat-export = Chat konnte nicht exportiert werden: { $error }
+screenshot-title = Screenshot teilen
+screenshot-tool-crop = Zuschneiden
+screenshot-tool-arrow = Pfeil
+screenshot-hint-crop = Ziehe einen Rahmen um den Bereich, der erhalten bleiben soll
+screenshot-hint-arrow = Ziehe vom Ende zur Spitze des Pfeils
 msg-reindex-triggered = Datei-Neuindizierung wurde ausgelöst
+msg-cleanup-nothing = Keine Überreste abgebrochener Übertragungen gefunden
+msg-cleanup-found = Überreste abgebrochener Übertragungen gefunden:
+msg-cleanup-deleted = Überreste abgebrochener Übertragungen gelöscht:
+msg-cleanup-parts = Verwaiste .part-Dateien: { $count } ({ $size })
+msg-cleanup-temp = Übrig gebliebene temporäre Dateien: { $count } ({ $size })
+msg-cleanup-journal = Upload-Journaleinträge ohne Datei: { $count }
+msg-cleanup-index = Suchindexeinträge ohne Datei: { $count }
+msg-cleanup-hint = Prüfen Sie die obigen Dateien und führen Sie dann /cleanup delete aus, um sie zu entfernen
+msg-filecheck-issue = { $kind }: { $path }
+msg-filecheck-issue-repaired = { $kind }: { $path } (repariert)
+msg-filecheck-clean = Die Prüfung des Dateibereichs hat in { $entries } Einträgen keine Probleme gefunden
+msg-filecheck-done = Die Prüfung des Dateibereichs hat { $issues } Probleme in { $entries } Einträgen gefunden, { $repaired } repariert
+msg-filecheck-index-skipped = Der Suchindex wurde nicht verglichen, da er noch nicht erstellt ist oder eine Neuerstellung aussteht
+msg-filecheck-hint = Führen Sie /filecheck repair aus, um den Index neu zu erstellen und Metadaten fehlender Pfade zu entfernen
+filecheck-not-indexed = Nicht im Suchindex
+filecheck-indexed-but-missing = Im Suchindex, aber nicht auf der Festplatte
+filecheck-index-outdated = Im Suchindex veraltet
+filecheck-orphaned-activity = Verlauf eines fehlenden Pfads
+filecheck-orphaned-reviews = Bewertungen oder Kommentare eines fehlenden Pfads
+filecheck-orphaned-upload-rule = Upload-Regel eines fehlenden Ordners
 cmd-join-desc = Einem Kanal beitreten oder einen erstellen
 cmd-join-usage = Verwendung: /{ $command } #kanal
 cmd-leave-desc = Einen Kanal verlassen
 cmd-leave-usage = Verwendung: /{ $command } [#kanal]
 cmd-channels-desc = Verfügbare Kanäle anzeigen
-cmd-channels-usage = Verwendung: /{ $command }
+cmd-channels-usage = Verwendung: /{ $command } [sprache]
 cmd-secret-desc = Geheimmodus für aktuellen Kanal umschalten
 cmd-secret-usage = Verwendung: /{ $command } [an|aus]
 cmd-secret-arg-on = an
@@ -707,12 +1152,40 @@ msg-secret-enabled = Kanal ist jetzt geheim
 msg-secret-disabled = Kanal ist jetzt öffentlich
 msg-secret-status-on = Dieser Kanal ist geheim
 msg-secret-status-off = Dieser Kanal ist öffentlich
+cmd-voicelimit-desc = Sprachteilnehmer-Limit des aktuellen Kanals anzeigen oder festlegen
+cmd-voicelimit-usage = Verwendung: /{ $command } [<Anzahl>|aus]
+cmd-vt-desc = Eine Textnachricht an deine Sprachsitzung senden
+cmd-vt-usage = Verwendung: /{ $command } <Nachricht>
+cmd-voicelimit-arg-off = aus
+err-voicelimit-no-channel = Dieser Befehl funktioniert nur in einem Kanal
+msg-voice-limit-status = In diesem Kanal sind höchstens { $limit } Nutzer im Sprachchat erlaubt
+msg-voice-limit-status-none = Dieser Kanal hat kein Sprachlimit
+msg-voice-clip-none = Keine Soundboard-Clips vorhanden
+msg-voice-clip-list = Soundboard-Clips ({ $count }):
+msg-voice-clip-entry = { $name } ({ $seconds } s, von { $nickname })
+msg-voice-clip-uploaded = Clip zum Soundboard hinzugefügt
+msg-voice-clip-deleted = Clip vom Soundboard gelöscht
 cmd-trust-desc = IP, CIDR-Bereich oder Nickname vertrauen, um Sperren zu umgehen
 cmd-trust-usage = Verwendung: /{ $command } <ziel> [dauer] [grund]
 cmd-untrust-desc = Vertrauenswürdigen IP-Eintrag entfernen
 cmd-untrust-usage = Verwendung: /{ $command } <ziel>
 cmd-trusted-desc = Vertrauenswürdige IPs anzeigen
 cmd-trusted-usage = Verwendung: /{ $command }
+cmd-ldap-desc = Verzeichnisgruppen-Zuordnungen anzeigen, setzen oder entfernen
+cmd-ldap-usage = Verwendung: /{ $command } [list|set <Berechtigungen|admin|none> <Gruppen-DN>|remove <Gruppen-DN>]
+cmd-nickname-desc = Spitznamen für dein Konto reservieren oder freigeben
+cmd-nickname-usage = Verwendung: /{ $command } [register <Spitzname> [Benutzername]|release <Spitzname>]
+cmd-history-desc = Anzeigen oder festlegen, wie lange der Server Ihre Direktnachrichten aufbewahrt, oder ältere laden
+cmd-history-usage = Verwendung: /{ $command } [<Tage>|more]
+cmd-alias-desc = Kurzbefehle für Befehle und Nachrichten anlegen
+cmd-alias-usage = Verwendung: /{ $command } [list|set <Name> <Text>|remove <Name>]
+cmd-alias-list-header = Deine Aliase:
+cmd-alias-list-empty = Noch keine Aliase. Lege einen an mit /{ $command } set <Name> <Text>
+cmd-alias-set = /{ $name } führt jetzt aus: { $text }
+cmd-alias-removed = Alias /{ $name } entfernt
+cmd-alias-not-found = Kein Alias namens /{ $name }
+cmd-alias-invalid-name = Alias-Namen dürfen nur Buchstaben, Ziffern, - und _ enthalten (bis zu { $max } Zeichen)
+cmd-alias-builtin = /{ $name } ist ein eingebauter Befehl und kann nicht als Alias verwendet werden
 
 
 # =============================================================================
@@ -726,9 +1199,25 @@ err-server-image-invalid-format = Ungültiges Serverbild-Format (muss eine Data-
 err-server-image-unsupported-type = Nicht unterstützter Serverbild-Typ (nur PNG, WebP, JPEG oder SVG)
 err-server-image-decode-failed = Bild konnte nicht dekodiert werden. Die Datei ist möglicherweise beschädigt.
 err-failed-read-image = Fehler beim Lesen des Bildes: { $error }
-err-news-empty = Der Beitrag muss Text oder ein Bild enthalten
+err-news-empty = Der Beitrag muss Text oder einen Anhang enthalten
 err-news-body-too-long = Der Inhalt ist zu lang ({ $length } Zeichen, maximal { $max })
 err-news-body-invalid-characters = Der Inhalt enthält ungültige Steuerzeichen
+err-news-too-many-attachments = Ein Beitrag kann höchstens { $max } Anhänge haben
+err-news-caption-too-long = Beschriftung ist zu lang (max. { $max } Zeichen)
+err-news-caption-invalid-characters = Beschriftung muss einzeilig sein und darf keine Steuerzeichen enthalten
+err-news-file-path-invalid = Ungültiger Dateipfad
+err-news-search-date = Ungültiges Datum: { $date } (JJJJ-MM-TT verwenden)
+err-file-comment-empty = Der Kommentar darf nicht leer sein
+err-file-comment-too-long = Der Kommentar ist zu lang ({ $length } Zeichen, maximal { $max })
+err-file-comment-invalid-characters = Der Kommentar enthält ungültige Steuerzeichen
+err-upload-rule-invalid-extension = Ungültiger Dateityp "{ $extension }" (Buchstaben und Ziffern verwenden, z. B. mp3)
+err-auto-download-pattern-required = Geben Sie mindestens ein Dateinamenmuster ein
+err-auto-download-pattern-invalid = Ungültiges Muster "{ $pattern }" (Muster gelten für Dateinamen und dürfen keine Schrägstriche enthalten)
+err-upload-rule-too-many-extensions = Zu viele Dateitypen (max. { $max })
+err-upload-rule-invalid-size = Ungültige Größe "{ $value }" (Anzahl Megabyte eingeben)
+err-ldap-group-empty = Gruppen-DN darf nicht leer sein
+err-ldap-group-too-long = Gruppen-DN ist zu lang (maximal { $max } Zeichen)
+err-ldap-group-invalid-characters = Gruppen-DN darf keine umgebenden Leerzeichen oder Steuerzeichen enthalten
 err-news-image-decode-failed = Bild konnte nicht dekodiert werden. Die Datei ist möglicherweise beschädigt.
 
 # Proxy errors
@@ -739,9 +1228,31 @@ err-proxy-connection-timeout = Proxy-Verbindung nach { $seconds } { $seconds ->
 } abgelaufen
 err-proxy-address-required = Proxy-Adresse ist erforderlich, wenn Proxy aktiviert ist
 err-proxy-port-invalid = Proxy-Port muss zwischen 1 und 65535 liegen
-err-news-image-too-large = Das Bild ist zu groß (maximal 512KB)
+err-news-image-too-large = Das Bild ist zu groß (maximal { $max_kb }KB)
 err-news-image-unsupported-type = Nicht unterstützter Bildtyp (nur PNG, WebP, JPEG oder SVG)
 err-topic-no-channel = Dieser Befehl funktioniert nur in einem Kanal
+err-entry-no-channel = Dieser Befehl funktioniert nur in einem Kanal
+cmd-welcome-desc = Nachricht für Benutzer, die dem Kanal beitreten, anzeigen oder setzen
+cmd-welcome-usage = Verwendung: /{ $command } [setzen <nachricht>|löschen]
+cmd-welcome-arg-set = setzen
+cmd-welcome-arg-clear = löschen
+cmd-welcome-none = Keine Begrüßungsnachricht gesetzt
+msg-entry-message-display = Begrüßungsnachricht: { $message }
+msg-entry-message-updated = Begrüßungsnachricht aktualisiert
+msg-entry-message-cleared = Begrüßungsnachricht gelöscht
+cmd-rules-desc = Kanalregeln anzeigen oder setzen
+cmd-rules-usage = Verwendung: /{ $command } [setzen <regeln>|löschen]
+cmd-rules-arg-set = setzen
+cmd-rules-arg-clear = löschen
+cmd-rules-none = Keine Regeln gesetzt
+msg-rules-header = Regeln für { $channel }:
+cmd-language-desc = Sprach-Tag des Kanals anzeigen oder setzen
+cmd-language-usage = Verwendung: /{ $command } [setzen <sprache>|löschen]
+cmd-language-arg-set = setzen
+cmd-language-arg-clear = löschen
+msg-language-status = Kanalsprache: { $language }
+msg-language-none = Keine Sprache gesetzt
+err-language-invalid = Ungültiges Sprach-Tag: { $language } (z. B. de oder pt-BR)
 err-join-channel = Fehler beim Beitreten des Kanals: { $error }
 err-leave-channel = Fehler beim Verlassen des Kanals: { $error }
 err-list-channels = Fehler beim Auflisten der Kanäle: { $error }
@@ -751,6 +1262,8 @@ err-channel-too-long = Der Kanalname ist zu lang (max. { $max } Zeichen)
 err-channel-missing-prefix = Der Kanalname muss mit # beginnen
 err-channel-invalid-characters = Der Kanalname enthält ungültige Zeichen
 err-leave-no-channel = Du musst in einem Kanal sein, um /{ $command } ohne Argumente zu verwenden
+err-invite-no-group = Verwenden Sie /{ $command } in einem Gruppengespräch-Tab
+err-group-too-many-members = Ein Gruppengespräch kann höchstens { $max } Mitglieder haben
 err-not-in-channel = Du bist nicht im Kanal { $channel }
 err-leave-already-pending = Warte bereits auf Serverantwort
 err-uri-connection-failed = Verbindung zu { $host } fehlgeschlagen: { $error }
@@ -762,10 +1275,28 @@ label-port = Port:
 # =============================================================================
 
 transfers-empty = Keine Übertragungen
+transfer-history-search-placeholder = Nach Name, Server oder Ordner suchen…
+transfer-history-empty = Noch keine abgeschlossenen Übertragungen
+transfer-history-no-matches = Keine Übertragungen entsprechen Ihrer Suche
 
 # Transfer statuses
 transfer-status-queued = In Warteschlange
 transfer-status-connecting = Verbinde…
+transfer-status-server-queued = Warte auf freien Server-Platz (#{ $position })
+transfer-collision-pending = Datei existiert: { $path }
+collision-action-renamed = { $count } umbenannt
+collision-action-overwritten = { $count } überschrieben
+collision-action-skipped = { $count } übersprungen
+transfer-bar-count = { $count } { $count ->
+    [one] Übertragung
+   *[other] Übertragungen
+}
+status-bar-users = { $count } { $count ->
+    [one] Benutzer
+   *[other] Benutzer
+}
+status-bar-latency = { $ms } ms
+status-bar-server-time = Serverzeit { $time } ({ $zone })
 transfer-status-transferring = Übertragung
 transfer-status-paused = Pausiert
 transfer-status-completed = Abgeschlossen
@@ -828,6 +1359,7 @@ event-broadcast = Broadcast
 event-chat-message = Chat-Nachricht
 event-chat-mention = Chat-Erwähnung
 event-connection-lost = Verbindung verloren
+event-friend-online = Freund online
 event-news-post = Neuigkeiten
 event-permissions-changed = Berechtigungen geändert
 event-transfer-complete = Übertragung abgeschlossen
@@ -862,6 +1394,17 @@ settings-sound-test = Test
 settings-notification-test = Test
 event-settings-show-toast = Toast anzeigen
 settings-toast-test = Test
+settings-do-not-disturb = Nicht stören
+settings-quiet-hours = Ruhezeiten
+settings-do-not-disturb-hint = Unterdrückt Benachrichtigungen, Toasts und Töne. Ungelesen-Markierungen werden weiter gezählt, und andere sehen 🔕 neben deinem Namen.
+settings-notification-rules = Benachrichtigungsregeln
+settings-notification-rules-server = Server:
+settings-notification-rules-channels = Kanal-Ausnahmen
+settings-notification-rules-keywords = Beobachtete Stichwörter
+settings-notification-rules-no-bookmarks = Speichere einen Server als Lesezeichen, um Benachrichtigungsregeln festzulegen.
+channel-notification-all = Alle Nachrichten
+channel-notification-mentions = Nur Erwähnungen
+channel-notification-mute = Stumm
 
 # Sound names
 sound-none = Keiner
@@ -880,6 +1423,8 @@ notification-chat-message = Neue Chat-Nachricht
 notification-chat-message-from = Chat von {$username}
 notification-connection-lost = Verbindung verloren
 notification-connection-lost-from = Verbindung zu {$server} getrennt
+notification-friend-online = Ein Freund ist online
+notification-friend-online-name = {$username} ist online
 notification-download-complete = Download abgeschlossen
 notification-download-failed = Download fehlgeschlagen
 notification-news-post = Neue Neuigkeit
@@ -933,6 +1478,18 @@ ban-duration-7d = 7 Tage
 ban-duration-30d = 30 Tage
 ban-duration-permanent = Dauerhaft
 
+# Broadcast target and delay options
+broadcast-target-label = Senden an:
+broadcast-target-all = Alle
+broadcast-target-channel = Mitglieder eines Kanals
+broadcast-target-permission = Benutzer mit einer Berechtigung
+broadcast-delay-label = Senden:
+broadcast-delay-now = Jetzt
+broadcast-delay-5m = In 5 Minuten
+broadcast-delay-15m = In 15 Minuten
+broadcast-delay-1h = In 1 Stunde
+broadcast-delay-1d = In 1 Tag
+
 # =============================================================================
 # Ban System
 # =============================================================================
@@ -982,12 +1539,32 @@ msg-untrusted-success = Vertrauenseintrag erfolgreich entfernt
 # Trust list messages
 msg-trust-list-header = Vertrauenswürdige IPs:
 msg-trust-list-empty = Keine vertrauenswürdigen IPs
+msg-ldap-group-list-header = Verzeichnisgruppen:
+msg-ldap-group-list-empty = Keine Verzeichnisgruppen zugeordnet
+msg-ldap-group-admin = Admin
+msg-ldap-group-no-permissions = keine Berechtigungen
+msg-ldap-group-set = Verzeichnisgruppe { $group } zugeordnet
+msg-ldap-group-removed = Verzeichnisgruppe { $group } entfernt
+msg-guest-upgraded = Konto { $username } erstellt. Melde dich beim nächsten Mal damit an, um es zu verwenden.
+msg-nickname-registered = Spitzname { $nickname } ist jetzt für { $username } reserviert.
+msg-nickname-released = Spitzname { $nickname } freigegeben.
+msg-history-days = Der Server bewahrt Ihre Direktnachrichten { $days } Tage auf.
+msg-history-off = Der Server bewahrt Ihre Direktnachrichten nicht auf. Mit /history <Tage> aktivieren.
+msg-history-no-older = Keine älteren Nachrichten mit { $nickname }.
+err-history-days-too-long = Der Nachrichtenverlauf kann höchstens { $max } Tage aufbewahrt werden
+err-history-more-tab = Verwenden Sie /history more in einem Direktnachrichten-Tab
 msg-trust-permanent = dauerhaft
 msg-trust-expired = abgelaufen
 msg-trust-remaining = { $time } verbleibend
 msg-trust-remaining-days = { $days }T { $hours }Std
 msg-trust-remaining-hours = { $hours }Std { $minutes }Min
 msg-trust-remaining-minutes = { $minutes }Min
+msg-leaderboard-header = Aktivste Benutzer nach { $stat }:
+msg-leaderboard-empty = Noch keine Aktivität erfasst
+leaderboard-stat-logins = Anmeldungen
+leaderboard-stat-messages = Nachrichten
+leaderboard-stat-uploads = Uploads
+leaderboard-stat-downloaded = heruntergeladenen Daten
 
 # =============================================================================
 # Voice Chat
@@ -996,6 +1573,16 @@ msg-trust-remaining-minutes = { $minutes }Min
 # Voice error messages
 err-voice-join = Sprachchat beitreten fehlgeschlagen: { $error }
 err-voice-leave = Sprachchat verlassen fehlgeschlagen: { $error }
+err-voice-limit = Sprachlimit konnte nicht gesetzt werden: { $error }
+err-voice-clip = Soundboard: { $error }
+err-voice-text = Sprachtext: { $error }
+err-voice-clip-name-empty = Clipname darf nicht leer sein
+err-voice-clip-name-too-long = Clipname ist zu lang (max. { $max } Zeichen)
+err-voice-clip-name-invalid = Clipnamen dürfen nur Buchstaben, Ziffern, - und _ enthalten
+err-voice-clip-too-large = Clip ist zu groß (max. 256 KB)
+err-voice-clip-unsupported-type = Nicht unterstütztes Clip-Format (nur Ogg Vorbis)
+msg-voice-queued = Der Sprachchat ist voll – du bist Nummer { $position } in der Warteschlange
+msg-voice-queue-cancelled = Du wurdest aus der Sprach-Warteschlange entfernt
 err-voice-no-token = Sprachchat beigetreten, aber kein Token erhalten
 err-voice-already-active = Du bist bereits in einem Sprachchat auf einem anderen Server
 err-voice-no-permission = Du hast keine Berechtigung, dem Sprachchat beizutreten
@@ -1021,7 +1608,12 @@ msg-voice-joined-empty = Sprachchat in { $target } beigetreten
 msg-voice-left = Sprachchat in { $target } verlassen
 msg-voice-user-joined = { $nickname } ist dem Sprachchat beigetreten
 msg-voice-user-left = { $nickname } hat den Sprachchat verlassen
+msg-voice-broadcast-started = { $nickname } überträgt im Sprachchat - tritt bei, um zuzuhören
+msg-voice-broadcast-ended = Die Sprachübertragung wurde beendet
+msg-voice-clip-played = { $nickname } hat { $name } abgespielt
+msg-voice-text = [Sprache] { $nickname }: { $message }
 msg-voice-you-left = Du hast den Sprachchat verlassen
+msg-voice-tcp-relay = Voice-UDP-Verbindung fehlgeschlagen ({ $error }) - Sprache wird stattdessen über die Serververbindung übertragen
 
 # Voice UI
 voice-join-tooltip = Sprachchat beitreten
@@ -1029,6 +1621,8 @@ voice-leave-tooltip = Sprachchat verlassen
 voice-mute-all-tooltip = Alle stummschalten
 voice-unmute-all-tooltip = Stummschaltung aufheben
 voice-bar-participants = ({ $count })
+voice-bar-queued = (Warteschlange: { $position })
+voice-bar-listeners = ({ $count } hören zu)
 voice-indicator-tooltip = Im Sprachchat
 tooltip-mute = Stummschalten
 tooltip-unmute = Stummschaltung aufheben
@@ -1039,6 +1633,12 @@ voice-quality-medium = Mittel (32 kbps)
 voice-quality-high = Hoch (64 kbps)
 voice-quality-very-high = Sehr hoch (96 kbps)
 
+# Voice connection quality
+voice-connection-good = Gut
+voice-connection-fair = Mittel
+voice-connection-poor = Schlecht
+voice-connection-tooltip = Verbindung: { $quality } ({ $loss }% Verlust, { $jitter } ms Jitter)
+
 # PTT modes
 ptt-mode-hold = Halten zum Sprechen
 ptt-mode-toggle = Umschalten
@@ -1053,6 +1653,8 @@ ptt-delay-500ms = 500ms
 audio-output-device = Ausgabegerät
 audio-input-device = Eingabegerät
 audio-refresh-devices = Geräte aktualisieren
+audio-clip-volume = Soundboard-Lautstärke
+audio-voice-text-tts = Sprachtext-Nachrichten vorlesen
 audio-voice-quality = Sprachqualität
 audio-ptt-key = Push-to-Talk-Taste
 audio-ptt-mode = PTT-Modus
@@ -1079,6 +1681,44 @@ mic-boost-6db = +6 dB
 mic-boost-12db = +12 dB
 mic-boost-18db = +18 dB
 
+# =============================================================================
+# Global Hotkeys
+# =============================================================================
+
+# Settings
+settings-toggle-window-key = Taste zum Ein-/Ausblenden
+button-clear-hotkey = Entfernen
+
+# Shortcut descriptions (shown by the desktop on Wayland)
+hotkey-push-to-talk = Push-to-Talk
+hotkey-toggle-window = Nexus ein- oder ausblenden
+
+# Errors
+err-window-hotkey-failed = Taste zum Ein-/Ausblenden konnte nicht registriert werden: { $error }
+err-global-shortcuts-unavailable = Portal für globale Tastenkürzel nicht verfügbar, Tasten funktionieren nur, wenn Nexus fokussiert ist: { $error }
+
+# =============================================================================
+# Auto Away
+# =============================================================================
+
+settings-auto-away = Automatisch abwesend bei Inaktivität
+settings-auto-away-minutes = Minuten inaktiv
+
+# =============================================================================
+# Connection Health
+# =============================================================================
+
+# Server list indicator
+connection-health-good = Gut
+connection-health-fair = Mittel
+connection-health-poor = Schlecht
+connection-health-tooltip = Verbindung: { $quality }
+connection-health-latency = Latenz: { $ms } ms
+connection-health-jitter = Jitter: { $ms } ms
+connection-health-last-drop = Letzter verlorener Ping: { $time }
+connection-health-no-drops = Keine verlorenen Pings
+connection-health-measuring = Latenz wird gemessen…
+
 # =============================================================================
 # System Tray (Windows/Linux only)
 # =============================================================================
@@ -1107,14 +1747,33 @@ tray-tooltip-unread = Nexus BBS - { $count ->
    *[other] { $count } ungelesene Unterhaltungen
 }
 
+# =============================================================================
+# Notification Actions (macOS/Windows only)
+# =============================================================================
+
+# Actions
+notification-reply = Antworten
+notification-reply-send = Senden
+notification-reply-placeholder = Antworten…
+notification-mark-read = Als gelesen markieren
+
 # =============================================================================
 # Toast Notifications
 # =============================================================================
 
 toast-link-copied = Link in Zwischenablage kopiert
 toast-copied = In Zwischenablage kopiert
+toast-checksum-copied = SHA-256 in Zwischenablage kopiert
 toast-download-started = Lade { $filename } herunter
 toast-download-queued = Download in Warteschlange: { $filename }
+toast-downloads-started = { $count } { $count ->
+    [one] Datei wird
+   *[other] Dateien werden
+} heruntergeladen
+toast-downloads-queued = { $count } { $count ->
+    [one] Download
+   *[other] Downloads
+} in Warteschlange
 toast-upload-started = Lade { $filename } hoch
 toast-upload-queued = Upload in Warteschlange: { $filename }
 toast-uploads-started = { $count } { $count ->
@@ -1125,3 +1784,28 @@ toast-uploads-queued = { $count } { $count ->
     [one] Upload
    *[other] Uploads
 } in Warteschlange
+toast-upload-nothing-new = Nichts hochzuladen — der Server hat diese Dateien bereits
+toast-upload-watch-started = { $folder } wird überwacht – neue Dateien werden automatisch hochgeladen
+toast-upload-watch-stopped = { $folder } wird nicht mehr überwacht
+toast-auto-download-started = Lade { $pattern } aus { $folder } automatisch herunter
+toast-auto-download-stopped = Automatisches Herunterladen aus { $folder } beendet
+toast-screenshot-folder-set = Screenshots werden in diesen Ordner hochgeladen
+toast-chat-exported = Chat exportiert
+
+# =============================================================================
+# First-run Tips
+# =============================================================================
+
+tip-welcome-title = Willkommen bei Nexus
+tip-welcome = Gib unten die Adresse eines Servers ein und verbinde dich. Mit einem Lesezeichen bleibt der Server samt Anmeldung in der Liste links und ist beim nächsten Mal nur einen Klick entfernt.
+tip-fingerprint = Jeder Server hat einen Zertifikat-Fingerabdruck, ähnlich einer Unterschrift. Nexus merkt ihn sich bei der ersten Verbindung und warnt dich, wenn er sich ändert, denn das kann bedeuten, dass sich jemand als der Server ausgibt. Akzeptiere einen neuen Fingerabdruck nur, wenn der Admin des Servers dir die Änderung mitgeteilt hat.
+tip-server-list = Links findest du deine Lesezeichen und offenen Verbindungen. Klicke auf eine, um den Server zu wechseln, oder ziehe Lesezeichen, um sie umzusortieren.
+tip-toolbar = Über die Symbolleiste öffnest du Dateien, News, Einstellungen und mehr. Fahre mit der Maus über ein Symbol, um zu sehen, was es tut.
+tip-chat = Hier chattest du mit allen auf dem Server. Tippe /, um die verfügbaren Befehle zu sehen, etwa /join, um einen Kanal zu betreten.
+tip-user-list = Rechts stehen die Benutzer, die gerade online sind. Klicke auf einen Namen, um ihm zu schreiben oder Infos zu sehen.
+tip-counter = Tipp { $current } von { $total }
+button-add-bookmark = Lesezeichen hinzufügen
+button-tip-next = Weiter
+button-tip-done = Fertig
+button-tip-got-it = Verstanden
+button-skip-tips = Tipps überspringen
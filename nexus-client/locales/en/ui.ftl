@@ -11,6 +11,13 @@ button-connect = Connect
 button-save = Save
 button-create = Create
 button-edit = Edit
+button-check-ports = Check Ports
+button-statistics = Statistics
+button-dashboard = Dashboard
+button-refresh = Refresh
+button-doors = Doors
+button-doors-leave = Leave
+button-doors-back = Back
 button-update = Update
 button-accept-new-certificate = Accept New Certificate
 button-close = Close
@@ -18,9 +25,23 @@ button-choose-avatar = Choose Avatar
 button-clear-avatar = Clear
 button-choose-image = Choose Image
 button-clear-image = Clear
+button-add-file = Add File
 button-overwrite = Overwrite
 button-change-password = Change Password
 button-browse = Browse…
+button-undo = Undo
+button-add = Add
+button-remove = Remove
+button-view = View
+button-hide = Hide
+button-export = Export…
+button-trust-certificate = Trust Certificate
+button-skip = Skip
+button-skip-existing = Skip Existing
+button-download-anyway = Download Anyway
+button-unlock = Unlock
+button-view-as-user = View as User
+button-post-comment = Post
 
 # =============================================================================
 # Titles
@@ -35,20 +56,30 @@ title-user-create = User Create
 title-user-edit = User Edit
 title-update-user = Update User
 title-user-management = Manage Users
+title-permission-audit = Permission Audit
+title-impersonate = Viewing as { $username }
 title-confirm-delete = Confirm Delete
 title-connected = Connected
 title-settings = Settings
 title-bookmarks = Bookmarks
 title-users = Users
+title-friends = Friends
+friend-online = online
+friend-offline = offline
 title-channel-members = Members
 title-edit-server-info = Edit Server Info
 title-fingerprint-mismatch = Certificate Fingerprint Mismatch!
+title-fingerprint-new-server = New Server Certificate
+title-create-password-vault = Protect Saved Passwords
+title-unlock-password-vault = Unlock Saved Passwords
+title-unlock-config = Unlock Settings
 title-server-info = Server Info
 title-user-info = User Info
 title-about = About
 title-change-password = Change Password
 title-news = News
 title-transfers = Transfers
+title-transfer-history = Transfer History
 title-news-create = Create News Post
 title-news-edit = Edit News Post
 
@@ -58,10 +89,14 @@ title-news-edit = Edit News Post
 
 placeholder-username = Username
 placeholder-password = Password
+placeholder-master-passphrase = Master passphrase
+placeholder-confirm-master-passphrase = Confirm master passphrase
 placeholder-port = Port
 placeholder-server-address = Server Address
 placeholder-server-name = Server Name
+placeholder-folder-name = Folder Name
 placeholder-username-optional = Username (optional)
+placeholder-fallback-addresses-optional = Fallback addresses (optional, comma-separated)
 placeholder-password-optional = Password (optional)
 placeholder-password-keep-current = Password
 placeholder-current-password = Current Password
@@ -70,10 +105,18 @@ placeholder-confirm-password = Confirm Password
 placeholder-message = Type a message…
 placeholder-no-permission = No Permission
 placeholder-broadcast-message = Broadcast Message
+placeholder-broadcast-channel = Choose a channel
+placeholder-broadcast-permission = Choose a permission
 placeholder-server-description = Server Description
 placeholder-news-body = News Content
+placeholder-news-caption = Caption (optional)
+placeholder-news-file-path = Path in the file area
 placeholder-nickname-optional = Nickname (optional)
+placeholder-status-optional = Status (optional)
+placeholder-download-folder-optional = Download Folder (optional)
 placeholder-proxy-address = Proxy Address
+placeholder-notification-rules-channel = #channel
+placeholder-notification-rules-keyword = Keyword
 placeholder-proxy-port = 9050
 placeholder-proxy-username = Username (optional)
 placeholder-proxy-password = Password (optional)
@@ -84,13 +127,20 @@ placeholder-download-location = No download location set
 # =============================================================================
 
 label-auto-connect = Auto-Connect
+label-server-avatar = Avatar:
 label-add-bookmark = Add Bookmark
+label-lan-servers = On This Network
+label-use-sso = Single Sign-On
 label-admin = Admin
 label-shared-account = Shared Account
 label-enabled = Enabled
+label-erase-personal-data = Also erase personal data
+label-broadcast-require-ack = Ask recipients to acknowledge
 label-permissions = Permissions:
 label-expected-fingerprint = Expected fingerprint:
 label-received-fingerprint = Received fingerprint:
+label-certificate-fingerprint = Certificate fingerprint:
+label-identity-key = Identity key:
 label-theme = Theme:
 label-chat-history-retention = Chat History:
 chat-history-forever = Forever
@@ -98,6 +148,10 @@ chat-history-30-days = 30 Days
 chat-history-14-days = 14 Days
 chat-history-7-days = 7 Days
 chat-history-disabled = Disabled
+collision-policy-rename = Keep both (rename)
+collision-policy-overwrite = Overwrite
+collision-policy-skip = Skip
+collision-policy-ask = Ask
 label-max-scrollback = Max Scrollback:
 label-chat-font-size = Font Size:
 label-show-connection-events = Show Connect/Disconnect Events
@@ -105,6 +159,19 @@ label-show-channel-events = Show Join/Leave Events
 label-show-timestamps = Show Timestamps
 label-use-24-hour-time = Use 24-Hour Time
 label-show-seconds = Show Seconds
+label-split-view = Show Files, News and Connection Monitor Beside Chat
+label-spell-check = Check Spelling in Chat and News
+label-spell-check-no-dictionary = No spelling dictionary was found for your language. Put its .aff and .dic files in the dictionaries folder next to config.json.
+label-send-read-receipts = Send Read Receipts for User Messages
+label-user-list-grouping = User List Grouping:
+label-user-list-sort = User List Sorting:
+user-list-group-none = None
+user-list-group-admins = Admins First
+user-list-group-channel = By Channel
+user-list-group-status = By Status
+user-list-sort-alphabetical = Alphabetical
+user-list-sort-idle = Idle Time
+user-list-sort-connect = Connect Time
 label-server-name = Name:
 label-server-description = Description:
 label-server-version = Version:
@@ -128,6 +195,27 @@ tab-files = Files
 tab-channels = Channels
 label-connections-short = Connections:
 label-transfers-short = Transfers:
+label-active-transfers-short = Active:
+label-queue-order-short = Queue Order:
+label-trusted-weight-short = Trusted Weight:
+label-nat-mapping-short = Port Mapping:
+label-nat-external-ip-short = External IP:
+label-nat-not-mapped = Not mapped
+connectivity-check-running = Checking ports…
+check-service-bbs = BBS
+check-service-transfer = Transfers
+check-service-websocket = WebSocket
+check-service-transfer-websocket = WebSocket transfers
+check-service-voice = Voice
+check-state-open = Open
+check-state-closed = Closed
+check-state-filtered = Filtered
+check-state-untested = Not tested
+label-check-port = { $service } ({ $protocol } { $port }):
+label-queue-order-small-first = Small files first
+label-queue-order-arrival = Arrival order
+label-small-files-first = Queue small files first
+label-unlimited = Unlimited
 label-reindex-short = Reindex:
 label-persistent-short = Persistent:
 label-auto-join-short = Auto-join:
@@ -159,6 +247,19 @@ label-download-location = Download Location:
 label-queue-transfers = Queue Transfers
 label-download-limit = Download Limit:
 label-upload-limit = Upload Limit:
+label-auto-open-folder = Open folder when a download completes
+label-collision-policy = If File Exists:
+label-require-fingerprint-approval = Ask before trusting a new server's certificate
+label-known-servers = Known Servers
+known-servers-empty = No server certificates saved yet
+known-server-first-seen = First seen { $date }
+known-server-first-seen-unknown = First seen: unknown
+label-encrypt-config = Encrypt settings, bookmarks and chat history with a master passphrase
+config-encryption-locked-note = Settings are locked. Restart Nexus and enter the master passphrase to change this.
+label-upload-rule-extensions = Allowed File Types:
+label-upload-rule-max-file-size = Largest File (MB):
+label-upload-rule-max-total-size = Largest Upload (MB):
+label-auto-download-pattern = File Names:
 
 # =============================================================================
 # Permission Display Names
@@ -169,11 +270,14 @@ permission-user_info = User Info
 permission-chat_send = Chat Send
 permission-chat_receive = Chat Receive
 permission-chat_join = Chat Join
+permission-chat_language = Chat Language
 permission-chat_create = Chat Create
 permission-chat_list = Chat List
 permission-chat_secret = Chat Secret
 permission-chat_topic = Chat Topic
 permission-chat_topic_edit = Chat Topic Edit
+permission-chat_topic_lock = Chat Topic Lock
+permission-chat_entry_edit = Chat Entry Edit
 permission-user_broadcast = User Broadcast
 permission-user_create = User Create
 permission-user_delete = User Delete
@@ -189,6 +293,7 @@ permission-news_list = News List
 permission-news_create = News Create
 permission-news_edit = News Edit
 permission-news_delete = News Delete
+permission-file_comment = File Comment
 permission-file_copy = File Copy
 permission-file_create_dir = Directory Create
 permission-file_delete = File Delete
@@ -208,6 +313,11 @@ permission-trust_create = Trust Create
 permission-trust_delete = Trust Delete
 permission-trust_list = Trust List
 permission-connection_monitor = Connection Monitor
+permission-log_view = Log View
+permission-door_play = Door Play
+permission-voice_broadcast = Voice Broadcast
+permission-voice_clip_upload = Voice Clip Upload
+permission-voice_limit = Voice Limit
 permission-voice_listen = Voice Listen
 permission-voice_talk = Voice Talk
 
@@ -232,19 +342,31 @@ tooltip-files-paste = Paste
 tooltip-files-search = Search files
 tooltip-manage-users = Manage Users
 tooltip-connection-monitor = Connection Monitor
+tooltip-server-log = Server Log
 tooltip-server-info = Server Info
 tooltip-about = About
 tooltip-transfers = Transfers
 tooltip-download-all = Download All
 tooltip-upload = Upload
 file-picker-upload-title = Select files to upload
+file-picker-download-to-title = Select download folder
+folder-picker-upload-watch-title = Select folder to upload from automatically
 drop-to-upload = Drop to upload
 tooltip-clear-inactive = Clear Inactive
+tooltip-transfer-history = Transfer History
+tooltip-transfer-history-clear = Clear History
+tooltip-transfer-history-remove = Remove from History
 tooltip-transfer-pause = Pause
 tooltip-transfer-resume = Resume
 tooltip-transfer-cancel = Cancel
 tooltip-transfer-remove = Remove
 tooltip-transfer-open-folder = Open Folder
+tooltip-transfer-open-file = Open File
+tooltip-transfer-reveal = Reveal in File Manager
+tooltip-transfer-copy-checksum = Copy SHA-256
+tooltip-transfer-collision-rename = Keep both (rename)
+tooltip-transfer-collision-overwrite = Overwrite existing files
+tooltip-transfer-collision-skip = Skip existing files
 tooltip-transfer-move-up = Move Up
 tooltip-transfer-move-down = Move Down
 tooltip-transfer-retry = Retry
@@ -257,16 +379,34 @@ tooltip-disconnect = Disconnect
 tooltip-edit = Edit
 tooltip-info = Info
 tooltip-message = Message
+tooltip-add-friend = Add friend
+tooltip-remove-friend = Remove friend
 tooltip-kick = Kick
 tooltip-close = Close
+tooltip-retry-send = Retry sending
+tooltip-cancel-send = Cancel sending
+tooltip-message-sent = Sent
+tooltip-message-delivered = Delivered
+tooltip-message-read = Read
 tooltip-new-tab = New Tab
 tooltip-close-tab = Close Tab
 tooltip-add-bookmark = Add Bookmark
+tooltip-new-folder = New Folder
 tooltip-create-user = Create User
+tooltip-permission-audit = Audit Permissions
 tooltip-create-news = Create News Post
+tooltip-news-search = Search news
+tooltip-news-search-clear = Clear search
 tooltip-delete = Delete
 context-menu-download = Download
+context-menu-download-to = Download To…
+context-menu-download-select = Download Selected…
+context-menu-auto-download = Auto-Download…
 context-menu-upload = Upload
+context-menu-watch-folder = Watch Local Folder…
+context-menu-screenshot-folder = Share Screenshots Here
+context-menu-unwatch-folder = Stop Watching Folder
+context-menu-upload-rules = Upload Rules…
 context-menu-open = Open
 
 # =============================================================================
@@ -274,6 +414,7 @@ context-menu-open = Open
 # =============================================================================
 
 panel-connection-monitor = Connection Monitor
+panel-server-log = Server Log
 panel-active-connections = Active Connections: { $count }
 tab-connections = Connections
 tab-transfers = Transfers
@@ -288,9 +429,97 @@ menu-info = Info
 menu-copy = Copy
 menu-kick = Kick
 menu-ban = Ban
+menu-pop-out = Pop Out
+menu-mute-tab = Mute
+menu-unmute-tab = Unmute
+menu-connect-all = Connect All
+menu-rename = Rename
+menu-delete = Delete
 connection-monitor-loading = Loading connections…
 connection-monitor-no-connections = No connections
 connection-monitor-no-transfers = No active transfers
+connection-monitor-queued = Queued
+connection-monitor-weight = Fair-share weight: ×{ $weight }
+connection-monitor-listed = Listed in { $zone }
+connection-monitor-queued-count = { $count } queued
+placeholder-server-log-module = Filter by module
+server-log-loading = Loading server log…
+server-log-empty = No log lines
+server-log-level-error = Errors only
+server-log-level-warn = Warnings and above
+server-log-level-info = Info and above
+server-log-level-debug = Everything (debug)
+panel-statistics = Statistics
+stats-loading = Loading statistics…
+panel-snippet = Snippet
+snippet-loading = Loading snippet…
+snippet-details = Shared by { $author } · expires { $expires }
+stats-range-days = Last { $days } days
+stats-chart-max = Highest day: { $value }
+stats-metric-logins = Logins
+stats-metric-messages = Messages
+stats-metric-uploads = Uploads
+stats-metric-upload-bytes = Uploaded data
+stats-metric-downloads = Downloads
+stats-metric-download-bytes = Downloaded data
+stats-metric-peak-sessions = Peak users online
+panel-admin-dashboard = Admin Dashboard
+dashboard-loading = Loading dashboard…
+dashboard-connections = Connections
+dashboard-sessions = Users online
+dashboard-connection-count = Connections
+dashboard-transfer-connections = Transfer connections
+dashboard-transfers = Transfers
+dashboard-active-transfers = Running
+dashboard-queued-transfers = Queued
+dashboard-today = Today
+dashboard-disk = File Area
+dashboard-disk-free = Free space
+dashboard-disk-free-of = { $free } free of { $total }
+dashboard-disk-unknown = Unknown
+dashboard-recent-audit = Recent Audit Events
+dashboard-audit-empty = No audit events yet
+dashboard-updated = Updated { $time }
+panel-doors = Doors
+doors-loading = Loading doors…
+doors-empty = No doors on this server
+doors-starting = Starting…
+doors-playing-hint = Keys go to the door. Use Leave to quit.
+doors-exited = The door has ended
+doors-exited-code = The door ended with code { $code }
+
+# =============================================================================
+# Quick Switcher
+# =============================================================================
+
+quick-switcher-placeholder = Jump to a channel, message, bookmark or panel...
+user-list-filter-placeholder = Filter users...
+user-list-section-admins = Admins
+user-list-section-users = Users
+user-list-section-no-channel = Other
+user-list-section-online = Online
+user-list-section-away = Away
+user-list-section-dnd = Do Not Disturb
+user-list-idle = Idle { $duration }
+quick-switcher-no-matches = No matches
+quick-switcher-bookmark = Bookmark
+
+# =============================================================================
+# Chat Input
+# =============================================================================
+
+chat-paste-prompt = Paste { $count } lines?
+chat-paste-too-long-prompt = Too long for one message (max { $max }). Send it anyway?
+button-send-as-messages = Send as { $count } messages
+button-send-as-one-message = Send as one message
+button-send-in-parts = Send in { $count } parts
+button-share-as-snippet = Share as snippet
+button-expand-code = Show all { $count } lines
+button-collapse-code = Collapse
+chat-broadcast-ack-prompt = { $username } asked you to acknowledge their broadcast
+button-acknowledge = Acknowledge
+button-dismiss = Dismiss
+spell-no-suggestions = No suggestions
 
 # =============================================================================
 # Empty States
@@ -301,11 +530,35 @@ empty-no-connections = No connections
 empty-no-bookmarks = No bookmarks
 empty-no-users = No users online
 empty-no-channel-members = No members in channel
+empty-no-matching-users = No matching users
 user-management-loading = Loading users…
 user-management-no-users = No users found
+permission-audit-hint = Pick a permission to see who holds it, or click a user to see everything they can do.
+permission-audit-loading = Loading…
+permission-audit-no-holders = No account holds this permission
+permission-audit-effective = Effective permissions of { $username }
+permission-audit-admin = Admins hold every permission
+permission-audit-no-permissions = This account has no permissions
+permission-audit-restricted = Granted but unavailable to shared accounts:
+permission-audit-disabled = Disabled
+impersonate-notice = Read-only view of what this account sees. Every view is recorded in the audit log.
+impersonate-permissions = Permissions
+impersonate-files = Files ({ $path })
+impersonate-no-file-access = This account can't browse files
+impersonate-empty-folder = This folder is empty
+impersonate-channels = Channels
+impersonate-no-channel-access = This account can't list channels
+impersonate-no-channels = No visible channels
+impersonate-secret = Secret
 news-loading = Loading news…
 news-no-posts = No news posts yet
+news-search-placeholder = Search news… (author:name since:YYYY-MM-DD until:YYYY-MM-DD)
+news-searching = Searching…
+news-search-no-results = No matching news posts
 transfers-empty = No transfers
+transfer-history-search-placeholder = Search by name, server or folder…
+transfer-history-empty = No finished transfers yet
+transfer-history-no-matches = No transfers match your search
 news-updated = Updated
 files-loading = Loading files…
 files-empty = This directory is empty
@@ -317,10 +570,19 @@ files-column-name = Name
 files-column-size = Size
 files-column-modified = Modified
 files-column-path = Path
+files-column-rating = Rating
 files-search-placeholder = Search files…
 files-search-breadcrumb = Search - { $query }
 files-searching = Searching…
 files-no-results = No files found
+files-highlights = Highlights
+files-highlights-recent = New uploads this week
+files-highlights-popular = Most downloaded
+files-highlights-empty = Nothing here yet
+files-highlights-downloads = { $count } { $count ->
+    [one] download
+   *[other] downloads
+}
 files-search-query-too-short = Search query is too short (min { $min_length } characters)
 files-search-query-too-long = Search query is too long (max { $max_length } characters)
 files-search-query-invalid = Search query contains invalid characters
@@ -331,6 +593,27 @@ files-share = Share
 files-rename = Rename
 files-rename-title = Rename "{ $name }"
 files-rename-placeholder = New name
+files-upload-rule-title = Upload Rules for "{ $name }"
+files-upload-rule-loading = Loading…
+files-upload-rule-extensions-placeholder = Any type (e.g. mp3, flac)
+files-upload-rule-no-limit = No limit
+files-upload-rule-remove = Remove Rule
+files-upload-rule-help = Rules apply to this folder and its subfolders, unless a subfolder has its own.
+files-auto-download-title = Auto-Download from "{ $name }"
+files-auto-download-pattern-placeholder = e.g. *.mp3, *.m4a
+files-auto-download-remove = Stop Auto-Downloading
+files-auto-download-help = New files in this folder whose names match are downloaded while you are connected through this bookmark. Use * for any text and ? for a single character, and separate patterns with commas. Files you already downloaded are skipped.
+files-upload-rule-hint = Uploads here: { $limits }
+files-upload-rule-hint-types = { $extensions } only
+files-upload-rule-hint-file-size = up to { $size } per file
+files-upload-rule-hint-total-size = up to { $size } per upload
+files-upload-preview-title = Files Already Exist
+files-upload-preview-comparing = Comparing with the files on the server…
+files-upload-preview-summary = { $new } new, { $identical } already on the server, { $conflicts } different:
+files-upload-preview-blocked = { $count } { $count ->
+    [one] file has
+   *[other] files have
+} a folder with the same name on the server and will be skipped
 files-cut = Cut
 files-copy = Copy
 files-paste = Paste
@@ -341,6 +624,13 @@ files-delete-confirm-message = Are you sure you want to delete "{ $name }"?
 
 # File Overwrite Dialog
 files-overwrite-title = File Already Exists
+files-download-to-title = Download { $name }
+label-remember-for-server = Remember for this server
+label-select-all = Select all
+files-download-select-summary = { $count } of { $total } selected ({ $size })
+files-download-select-too-many = At most { $max } individual files can be selected. Download the whole folder instead.
+files-download-duplicate-title = Already Downloaded
+files-download-duplicate-message = "{ $name }" is already on this computer at { $path }. Do you want to download it again?
 files-overwrite-message = A file or folder named "{ $name }" already exists at the destination. Do you want to replace it?
 
 # File Info Dialog
@@ -356,6 +646,17 @@ files-info-directory = Directory
 files-info-symlink = Symlink:
 files-info-yes = Yes
 files-info-na = N/A
+files-info-rating = Rating:
+files-info-rating-value = { $average } ({ $count } { $count ->
+    [one] rating
+   *[other] ratings
+})
+files-info-not-rated = Not rated yet
+files-info-your-rating = Your rating:
+files-info-comments = Comments ({ $count })
+files-comments-loading = Loading comments…
+files-comments-empty = No comments yet
+files-comment-placeholder = Write a comment…
 
 # =============================================================================
 # Directory Name Validation Errors
@@ -374,6 +675,7 @@ err-dir-name-invalid = Directory name contains invalid characters
 tab-general = General
 tab-chat = Chat
 tab-network = Network
+tab-security = Security
 tab-audio = Audio
 
 # =============================================================================
@@ -395,6 +697,8 @@ chat-prefix-system = [SYS]
 chat-prefix-error = [ERR]
 chat-prefix-info = [INFO]
 chat-prefix-broadcast = [BROADCAST]
+chat-prefix-queued = [PENDING]
+chat-export-subtitle = Exported from { $server } on { $date }
 
 # =============================================================================
 # Success Messages
@@ -409,10 +713,24 @@ msg-logged-in-as-shared-admin = Logged in as { $nickname } ({ $username }) [admi
 msg-user-kicked-success = User kicked successfully
 msg-user-kicked-success-name = User '{ $nickname }' kicked successfully
 msg-broadcast-sent = Broadcast sent successfully
+msg-broadcast-delivered = Broadcast delivered to { $count } { $count ->
+    [one] user
+   *[other] users
+}
+msg-broadcast-scheduled = Broadcast scheduled for { $time }
+msg-broadcast-acknowledged = Broadcast acknowledged by { $count } of { $delivered } users
+msg-storage-low = Low disk space on the server for { $volume }: { $free } free, below the { $threshold } minimum
+msg-storage-recovered = Disk space on the server for { $volume } recovered: { $free } free
+storage-volume-files = the file area
+storage-volume-database = the database
+msg-friend-added = Added { $username } to friends
+msg-friend-removed = Removed { $username } from friends
 msg-user-created = User created successfully
 msg-user-created-name = User '{ $username }' created successfully
 msg-user-deleted = User deleted successfully
 msg-user-deleted-name = User '{ $username }' deleted successfully
+msg-user-erased = User deleted and personal data erased
+msg-user-erased-name = User '{ $username }' deleted and personal data erased
 msg-user-updated = User updated successfully
 msg-user-updated-name = User '{ $username }' updated successfully
 msg-permissions-updated = Your permissions have been updated
@@ -427,6 +745,8 @@ msg-welcome-back = Welcome back
 msg-status-set = Status set: { $status }
 msg-status-cleared = Status cleared
 msg-user-is-away = { $nickname } is away
+msg-group-member-joined = { $nickname } joined the conversation
+msg-group-member-left = { $nickname } left the conversation
 msg-user-is-away-status = { $nickname } is away: { $status }
 
 
@@ -439,6 +759,14 @@ msg-topic-cleared = Topic cleared by { $username }
 msg-topic-set = Topic set by { $username }: { $topic }
 msg-secret-set = Secret mode enabled by { $username }
 msg-secret-cleared = Secret mode disabled by { $username }
+msg-topic-locked = Topic locked by { $username }
+msg-topic-unlocked = Topic unlocked by { $username }
+msg-rules-set = Channel rules updated by { $username }
+msg-rules-cleared = Channel rules cleared by { $username }
+msg-language-set = Channel language set to { $language } by { $username }
+msg-language-cleared = Channel language cleared by { $username }
+msg-voice-limit-set = Voice limit set to { $limit } by { $username }
+msg-voice-limit-cleared = Voice limit removed by { $username }
 msg-server-info-updated = Server configuration updated
 msg-topic-display = Topic: { $topic }
 confirm-delete-user = Are you sure you want to delete user '{ $username }'?
@@ -457,6 +785,7 @@ err-failed-update-server-info = Failed to update server info: { $error }
 err-user-kick-failed = Failed to kick user
 err-no-shutdown-handle = Connection error: No shutdown handle
 err-userlist-failed = Failed to refresh user list
+err-request-timeout = The server didn't respond in time
 err-port-invalid = Port must be a valid number (1-65535)
 
 # Network connection errors
@@ -470,12 +799,16 @@ err-connection-closed = Connection closed
 err-could-not-determine-config-dir = Could not determine config directory
 err-message-too-long = Message is too long ({ $length } characters, max { $max })
 err-send-failed = Failed to send message
+err-checksum-failed = Failed to compute checksum
 err-no-chat-permission = You don't have permission to send messages
+err-chat-window-command = Only messages and /me can be sent from a chat window
 err-console-no-send = Use /{ $join } to enter a channel or /{ $msg } to send a user message
 err-broadcast-too-long = Broadcast is too long ({ $length } characters, max { $max })
 err-broadcast-send-failed = Failed to send broadcast
+err-broadcast-target-required = Choose who gets the broadcast
 err-name-required = Bookmark name is required
 err-address-required = Server address is required
+err-fallback-address-invalid = Invalid fallback address: { $address }
 err-port-required = Port is required
 err-username-required = Username is required
 err-password-required = Password is required
@@ -493,13 +826,20 @@ err-nickname-too-long = Nickname is too long (max { $max } characters)
 err-nickname-invalid = Nickname contains invalid characters
 err-password-too-long = Password is too long (max { $max } characters)
 err-passwords-do-not-match = Passwords do not match
+err-wrong-master-passphrase = Wrong master passphrase
+err-master-passphrase-empty = Master passphrase cannot be empty
+err-password-vault = Password vault error: { $error }
 err-current-password-required = Current password is required
 err-new-password-required = New password is required
 err-confirm-password-required = Please confirm your new password
 err-topic-too-long = Topic is too long ({ $length } characters, max { $max })
+err-entry-message-too-long = Entry message is too long ({ $length } characters, max { $max })
+err-rules-too-long = Rules are too long ({ $length } characters, max { $max })
 err-avatar-unsupported-type = Unsupported file type. Use PNG, WebP, JPEG, or SVG.
 err-avatar-too-large = Avatar too large. Maximum size is { $max_kb }KB.
 err-avatar-decode-failed = Failed to decode avatar. The file may be corrupted.
+err-known-server-export = Failed to export certificate: { $error }
+err-config-encryption = Failed to encrypt settings: { $error }
 err-server-name-empty = Server name cannot be empty
 err-server-name-too-long = Server name is too long (max { $max } characters)
 err-server-name-contains-newlines = Server name cannot contain newlines
@@ -519,9 +859,25 @@ err-server-image-invalid-format = Invalid server image format (must be a data UR
 err-server-image-unsupported-type = Unsupported server image type (PNG, WebP, JPEG, or SVG only)
 err-server-image-decode-failed = Failed to decode image. The file may be corrupted.
 err-failed-read-image = Failed to read image: { $error }
-err-news-empty = News post must have either body text or an image
+err-news-empty = News post must have either body text or an attachment
 err-news-body-too-long = Body is too long ({ $length } characters, max { $max })
 err-news-body-invalid-characters = Body contains invalid control characters
+err-news-too-many-attachments = A post can have at most { $max } attachments
+err-news-caption-too-long = Caption is too long (max { $max } characters)
+err-news-caption-invalid-characters = Caption must be a single line without control characters
+err-news-file-path-invalid = Invalid file path
+err-news-search-date = Invalid date: { $date } (use YYYY-MM-DD)
+err-file-comment-empty = Comment cannot be empty
+err-file-comment-too-long = Comment is too long ({ $length } characters, max { $max })
+err-file-comment-invalid-characters = Comment contains invalid control characters
+err-upload-rule-invalid-extension = Invalid file type "{ $extension }" (use letters and digits, like mp3)
+err-auto-download-pattern-required = Enter at least one file name pattern
+err-auto-download-pattern-invalid = Invalid pattern "{ $pattern }" (patterns match file names, so they can't contain slashes)
+err-upload-rule-too-many-extensions = Too many file types (max { $max })
+err-upload-rule-invalid-size = Invalid size "{ $value }" (enter a number of megabytes)
+err-ldap-group-empty = Group DN cannot be empty
+err-ldap-group-too-long = Group DN is too long (max { $max } characters)
+err-ldap-group-invalid-characters = Group DN cannot have surrounding spaces or control characters
 err-news-image-decode-failed = Failed to decode image. The file may be corrupted.
 
 # Proxy errors
@@ -532,7 +888,7 @@ err-proxy-connection-timeout = Proxy connection timed out after { $seconds } { $
 }
 err-proxy-address-required = Proxy address is required when proxy is enabled
 err-proxy-port-invalid = Proxy port must be between 1 and 65535
-err-news-image-too-large = Image is too large (max 512KB)
+err-news-image-too-large = Image is too large (max { $max_kb }KB)
 err-news-image-unsupported-type = Unsupported image type (PNG, WebP, JPEG, or SVG only)
 
 # =============================================================================
@@ -540,14 +896,23 @@ err-news-image-unsupported-type = Unsupported image type (PNG, WebP, JPEG, or SV
 # =============================================================================
 
 err-failed-save-config = Failed to save config: { $error }
+err-friends-no-bookmark = Friends are saved in the server's bookmark; bookmark this server first
+err-friends-too-many = Too many friends (max { $max })
+err-friend-exists = { $username } is already a friend
+err-friend-not-found = { $username } is not a friend
 err-failed-save-settings = Failed to save settings: { $error }
 err-invalid-port-bookmark = Invalid port in bookmark: { $name }
 err-failed-send-broadcast = Failed to send broadcast: { $error }
 err-failed-send-message = Failed to send message: { $error }
+err-failed-start-group = Failed to start group conversation: { $error }
+err-failed-share-snippet = Failed to share snippet: { $error }
 err-failed-create-user = Failed to create user: { $error }
 err-failed-delete-user = Failed to delete user: { $error }
 err-failed-update-user = Failed to update user: { $error }
 err-failed-update-topic = Failed to update topic: { $error }
+err-failed-lock-topic = Failed to lock topic: { $error }
+err-failed-set-language = Failed to set channel language: { $error }
+err-failed-update-entry = Failed to update channel: { $error }
 err-message-too-long-details = { $error } ({ $length } characters, max { $max })
 
 # Network connection errors (with parameters)
@@ -562,6 +927,14 @@ err-tls-handshake-failed = TLS handshake failed: { $error }
 err-failed-send-handshake = Failed to send handshake: { $error }
 err-failed-read-handshake = Failed to read handshake response: { $error }
 err-handshake-failed = Handshake failed: { $error }
+err-proof-of-work-too-hard = The server asked for too much proof of work (difficulty { $difficulty })
+err-sso-not-offered = This server does not offer single sign-on
+err-sso-failed = Single sign-on failed: { $error }
+err-sso-browser = Could not open the browser: { $error }
+err-sso-timeout = Single sign-on timed out waiting for the browser
+err-sso-state-mismatch = Single sign-on response did not match the request
+sso-browser-done = Signed in. You can close this window and return to Nexus.
+sso-browser-failed = Sign-in failed. You can close this window and return to Nexus.
 err-failed-parse-handshake = Failed to parse handshake response: { $error }
 err-failed-send-login = Failed to send login: { $error }
 err-failed-read-login = Failed to read login response: { $error }
@@ -569,6 +942,7 @@ err-failed-parse-login = Failed to parse login response: { $error }
 err-failed-create-server-name = Failed to create server name: { $error }
 err-failed-create-config-dir = Failed to create config directory: { $error }
 err-failed-serialize-config = Failed to serialize config: { $error }
+err-config-locked = Settings are encrypted and locked; changes can't be saved until they're unlocked
 err-failed-write-config = Failed to write config file: { $error }
 err-failed-read-config-metadata = Failed to read config file metadata: { $error }
 err-failed-set-config-permissions = Failed to set config file permissions: { $error }
@@ -578,6 +952,10 @@ err-failed-set-config-permissions = Failed to set config file permissions: { $er
 # =============================================================================
 
 fingerprint-warning = This could indicate a security issue (MITM attack) or the server's certificate was regenerated. Only accept if you trust the server administrator.
+fingerprint-new-server-prompt = This is the first connection to this server. Only trust the certificate if the fingerprint below matches the one published by the server administrator.
+password-vault-create-description = No system keychain is available. Choose a master passphrase to encrypt your saved bookmark passwords, or skip to keep them in the config file.
+password-vault-unlock-description = Enter your master passphrase to unlock your saved bookmark passwords. If you skip, bookmarks connect without their passwords until Nexus is restarted.
+config-unlock-description = Your settings, bookmarks and chat history are encrypted. Enter your master passphrase to unlock them. If you skip, Nexus starts with default settings and nothing is saved until it's restarted.
 
 # =============================================================================
 # User Info Display
@@ -594,10 +972,12 @@ user-info-account = Account: { $username }
 user-info-connected = Connected:
 user-info-connected-value = { $duration } ago
 user-info-connected-value-sessions = { $duration } ago ({ $count } sessions)
+user-info-idle = Idle:
 user-info-features = Features:
 user-info-features-value = { $features }
 user-info-features-none = None
 user-info-locale = Locale:
+user-info-client = Client:
 user-info-address = Address:
 user-info-addresses = Addresses:
 user-info-created = Created:
@@ -606,6 +986,12 @@ user-info-unknown = Unknown
 user-info-loading = Loading user info…
 user-info-status = Status:
 user-info-channels = Channels:
+user-info-rank = Rank:
+user-info-logins = Logins:
+user-info-messages = Messages:
+user-info-uploads = Uploads:
+user-info-downloaded = Downloaded:
+user-info-last-login = Last login:
 
 # =============================================================================
 # Time Duration
@@ -642,27 +1028,69 @@ err-me-no-target = Use /{ $join } to enter a channel or /{ $msg } to send an act
 cmd-help-escape-hint = Tip: Use // to send a message starting with /
 cmd-message-desc = Send a message to a user
 cmd-message-usage = Usage: /{ $command } <nickname> <message>
+cmd-group-desc = Start a group conversation with several users
+cmd-group-usage = Usage: /{ $command } <nickname> [<nickname>...]
+cmd-invite-desc = Add a user to the current group conversation
+cmd-invite-usage = Usage: /{ $command } <nickname>
 cmd-ping-desc = Measure latency to server
 cmd-ping-usage = Usage: /{ $command }
+cmd-radio-desc = Broadcast audio to the channel's voice listeners
+cmd-radio-usage = Usage: /{ $command }
+err-radio-no-channel = This command only works in a channel
+err-radio-in-voice = Leave voice before starting a broadcast
 ping-result = Pong: { $ms }ms
 cmd-userinfo-desc = Show information about a user
 cmd-userinfo-usage = Usage: /{ $command } <nickname>
 cmd-kick-desc = Kick a user from the server
 cmd-kick-usage = Usage: /{ $command } <nickname> [reason]
+cmd-top-desc = Show the most active users
+cmd-top-usage = Usage: /{ $command } [logins|messages|uploads|downloaded]
 cmd-topic-desc = View or manage the chat topic
-cmd-topic-usage = Usage: /{ $command } [set|clear] [topic]
+cmd-topic-usage = Usage: /{ $command } [set|clear|lock|unlock|history] [topic]
 cmd-topic-arg-set = set
 cmd-topic-arg-clear = clear
+cmd-topic-arg-lock = lock
+cmd-topic-arg-unlock = unlock
+cmd-topic-arg-history = history
 cmd-topic-set-usage = Usage: /{ $command } set <topic>
 cmd-topic-none = No topic is set
 cmd-topic-permission-denied = You don't have permission to edit the topic
+cmd-topic-lock-permission-denied = You don't have permission to lock the topic
+msg-topic-already-locked = Topic is already locked
+msg-topic-already-unlocked = Topic is already unlocked
+msg-topic-history-header = Topic history for { $channel }:
+msg-topic-history-set = { $time } { $username }: { $topic }
+msg-topic-history-cleared = { $time } { $username } cleared the topic
+msg-topic-history-empty = No topic changes recorded
 err-topic-no-channel = This command only works in a channel
+err-entry-no-channel = This command only works in a channel
+cmd-welcome-desc = View or set the message shown to users joining the channel
+cmd-welcome-usage = Usage: /{ $command } [set <message>|clear]
+cmd-welcome-arg-set = set
+cmd-welcome-arg-clear = clear
+cmd-welcome-none = No entry message is set
+msg-entry-message-display = Entry message: { $message }
+msg-entry-message-updated = Entry message updated
+msg-entry-message-cleared = Entry message cleared
+cmd-rules-desc = View or set the channel rules
+cmd-rules-usage = Usage: /{ $command } [set <rules>|clear]
+cmd-rules-arg-set = set
+cmd-rules-arg-clear = clear
+cmd-rules-none = No rules are set
+msg-rules-header = Rules for { $channel }:
+cmd-language-desc = View or set the channel's language tag
+cmd-language-usage = Usage: /{ $command } [set <language>|clear]
+cmd-language-arg-set = set
+cmd-language-arg-clear = clear
+msg-language-status = Channel language: { $language }
+msg-language-none = No language is set
+err-language-invalid = Invalid language tag: { $language } (use e.g. en or pt-BR)
 cmd-join-desc = Join or create a channel
 cmd-join-usage = Usage: /{ $command } #channel
 cmd-leave-desc = Leave a channel
 cmd-leave-usage = Usage: /{ $command } [#channel]
 cmd-channels-desc = List available channels
-cmd-channels-usage = Usage: /{ $command }
+cmd-channels-usage = Usage: /{ $command } [language]
 cmd-secret-desc = Toggle secret mode on current channel
 cmd-secret-usage = Usage: /{ $command } [on|off]
 cmd-secret-arg-on = on
@@ -675,13 +1103,38 @@ msg-secret-enabled = Channel is now secret
 msg-secret-disabled = Channel is now public
 msg-secret-status-on = This channel is secret
 msg-secret-status-off = This channel is public
+cmd-voicelimit-desc = View or set the voice participant limit of the current channel
+cmd-voicelimit-usage = Usage: /{ $command } [<count>|off]
+cmd-vt-desc = Send a text message to your voice session
+cmd-vt-usage = Usage: /{ $command } <message>
+cmd-voicelimit-arg-off = off
+err-voicelimit-no-channel = This command only works in a channel
+msg-voice-limit-status = This channel allows at most { $limit } users in voice
+msg-voice-limit-status-none = This channel has no voice limit
+msg-voice-clip-none = No soundboard clips
+msg-voice-clip-list = Soundboard clips ({ $count }):
+msg-voice-clip-entry = { $name } ({ $seconds }s, by { $nickname })
+msg-voice-clip-uploaded = Clip added to the soundboard
+msg-voice-clip-deleted = Clip deleted from the soundboard
 cmd-broadcast-desc = Send a broadcast to all users
 cmd-broadcast-usage = Usage: /{ $command } <message>
 cmd-clear-desc = Clear chat history for current tab
 cmd-clear-usage = Usage: /{ $command }
+cmd-export-desc = Save the current tab as an HTML file
+cmd-export-usage = Usage: /{ $command }
+cmd-clip-desc = List, play, add, or delete soundboard clips
+cmd-clip-usage = Usage: /{ $command } [list|play <name>|add <name>|delete <name>|<name>]
+cmd-clip-arg-list = list
+cmd-clip-arg-play = play
+cmd-clip-arg-add = add
+cmd-clip-arg-delete = delete
 cmd-focus-desc = Focus server chat or a user's message tab
 cmd-focus-usage = Usage: /{ $command } [nickname]
 cmd-focus-not-found = User not found: { $name }
+cmd-friend-desc = List, add, or remove friends
+cmd-friend-usage = Usage: /{ $command } [list] | add <username> | remove <username>
+cmd-friend-list-header = Friends:
+cmd-friend-list-empty = No friends yet. Add one with /{ $command } add <username>
 cmd-list-desc = Show connected/all users
 cmd-list-arg-all = all
 cmd-list-usage = Usage: /{ $command } [all]
@@ -722,15 +1175,70 @@ cmd-unban-desc = Remove an IP ban
 cmd-unban-usage = Usage: /{ $command } <target>
 cmd-bans-desc = List active bans
 cmd-bans-usage = Usage: /{ $command }
+cmd-register-desc = Register an account from a guest session
+cmd-register-usage = Usage: /{ $command } <username> <password>
 cmd-reindex-desc = Trigger a file index rebuild
 cmd-reindex-usage = Usage: /{ $command }
+cmd-cleanup-desc = Find leftovers of aborted transfers in the file area
+cmd-cleanup-usage = Usage: /{ $command } [delete]
+cmd-filecheck-desc = Check the file area against the search index and file metadata
+cmd-filecheck-usage = Usage: /{ $command } [repair]
+cmd-screenshot-desc = Share an annotated screenshot in the current tab
+cmd-screenshot-usage = Usage: /{ $command }
+err-screenshot-no-target = Screenshots can only be shared in a channel or user message tab
+err-screenshot-no-folder = No screenshot folder set — choose "Share Screenshots Here" on an upload folder in Files
+err-screenshot-capture = Screenshot failed: { $error }
+err-screenshot-save = Could not save screenshot: { $error }
+err-screenshot-upload = Screenshot upload failed: { $error }
+err-export-empty = There are no messages to export in this tab
+err-chat-export = Could not export chat: { $error }
+screenshot-title = Share Screenshot
+screenshot-tool-crop = Crop
+screenshot-tool-arrow = Arrow
+screenshot-hint-crop = Drag a box around the part to keep
+screenshot-hint-arrow = Drag from the tail to the tip of the arrow
 msg-reindex-triggered = File index rebuild triggered
+msg-cleanup-nothing = No leftovers of aborted transfers found
+msg-cleanup-found = Leftovers of aborted transfers found:
+msg-cleanup-deleted = Leftovers of aborted transfers deleted:
+msg-cleanup-parts = Orphaned .part files: { $count } ({ $size })
+msg-cleanup-temp = Leftover temp files: { $count } ({ $size })
+msg-cleanup-journal = Upload journal entries without a file: { $count }
+msg-cleanup-index = Search index entries without a file: { $count }
+msg-cleanup-hint = Check the files above, then run /cleanup delete to remove them
+msg-filecheck-issue = { $kind }: { $path }
+msg-filecheck-issue-repaired = { $kind }: { $path } (repaired)
+msg-filecheck-clean = File area check found no issues in { $entries } entries
+msg-filecheck-done = File area check found { $issues } issues in { $entries } entries, { $repaired } repaired
+msg-filecheck-index-skipped = The search index was not compared because it is not built yet or a rebuild is pending
+msg-filecheck-hint = Run /filecheck repair to rebuild the index and remove metadata of missing paths
+filecheck-not-indexed = Not in the search index
+filecheck-indexed-but-missing = In the search index but missing on disk
+filecheck-index-outdated = Outdated in the search index
+filecheck-orphaned-activity = History of a missing path
+filecheck-orphaned-reviews = Ratings or comments of a missing path
+filecheck-orphaned-upload-rule = Upload rule of a missing folder
 cmd-trust-desc = Trust an IP, CIDR range, or nickname to bypass bans
 cmd-trust-usage = Usage: /{ $command } <target> [duration] [reason]
 cmd-untrust-desc = Remove a trusted IP entry
 cmd-untrust-usage = Usage: /{ $command } <target>
 cmd-trusted-desc = List trusted IPs
 cmd-trusted-usage = Usage: /{ $command }
+cmd-ldap-desc = List, set, or remove directory group mappings
+cmd-ldap-usage = Usage: /{ $command } [list|set <permissions|admin|none> <group DN>|remove <group DN>]
+cmd-nickname-desc = Reserve or release nicknames for your account
+cmd-nickname-usage = Usage: /{ $command } [register <nickname> [username]|release <nickname>]
+cmd-history-desc = Show or set how long the server keeps your user messages, or load older ones
+cmd-history-usage = Usage: /{ $command } [<days>|more]
+cmd-alias-desc = Create shortcuts for commands and messages
+cmd-alias-usage = Usage: /{ $command } [list|set <name> <text>|remove <name>]
+cmd-alias-list-header = Your aliases:
+cmd-alias-list-empty = No aliases yet. Create one with /{ $command } set <name> <text>
+cmd-alias-set = /{ $name } now runs: { $text }
+cmd-alias-removed = Alias /{ $name } removed
+cmd-alias-not-found = No alias named /{ $name }
+cmd-alias-invalid-name = Alias names can only use letters, numbers, - and _ (up to { $max } characters)
+cmd-alias-builtin = /{ $name } is a built-in command and can't be used as an alias
 
 # =============================================================================
 # About Panel
@@ -748,6 +1256,21 @@ label-port = Port:
 # Transfer statuses
 transfer-status-queued = Queued
 transfer-status-connecting = Connecting…
+transfer-status-server-queued = Waiting for server slot (#{ $position })
+transfer-collision-pending = File exists: { $path }
+collision-action-renamed = { $count } renamed
+collision-action-overwritten = { $count } overwritten
+collision-action-skipped = { $count } skipped
+transfer-bar-count = { $count } { $count ->
+    [one] transfer
+   *[other] transfers
+}
+status-bar-users = { $count } { $count ->
+    [one] user
+   *[other] users
+}
+status-bar-latency = { $ms } ms
+status-bar-server-time = Server time { $time } ({ $zone })
 transfer-status-transferring = Transferring
 transfer-status-paused = Paused
 transfer-status-completed = Completed
@@ -811,6 +1334,7 @@ event-broadcast = Broadcast
 event-chat-message = Chat Message
 event-chat-mention = Chat Mention
 event-connection-lost = Connection Lost
+event-friend-online = Friend Online
 event-chat-join = Chat Join
 event-chat-leave = Chat Leave
 event-news-post = News Post
@@ -845,6 +1369,17 @@ settings-sound-test = Test
 settings-notification-test = Test
 event-settings-show-toast = Show Toast
 settings-toast-test = Test
+settings-do-not-disturb = Do Not Disturb
+settings-quiet-hours = Quiet Hours
+settings-do-not-disturb-hint = Silences notifications, toasts, and sounds. Unread badges still count up, and others see 🔕 next to your name.
+settings-notification-rules = Notification Rules
+settings-notification-rules-server = Server:
+settings-notification-rules-channels = Channel Overrides
+settings-notification-rules-keywords = Watched Keywords
+settings-notification-rules-no-bookmarks = Bookmark a server to set notification rules for it.
+channel-notification-all = All messages
+channel-notification-mentions = Mentions only
+channel-notification-mute = Mute
 
 # Sound names
 sound-none = None
@@ -863,6 +1398,8 @@ notification-chat-message = New chat message
 notification-chat-message-from = Chat from {$username}
 notification-connection-lost = Connection lost
 notification-connection-lost-from = Disconnected from {$server}
+notification-friend-online = A friend came online
+notification-friend-online-name = {$username} is online
 notification-download-complete = Download complete
 notification-download-failed = Download failed
 notification-news-post = New news post
@@ -916,6 +1453,18 @@ ban-duration-7d = 7 days
 ban-duration-30d = 30 days
 ban-duration-permanent = Permanent
 
+# Broadcast target and delay options
+broadcast-target-label = Send to:
+broadcast-target-all = Everyone
+broadcast-target-channel = Members of a channel
+broadcast-target-permission = Users with a permission
+broadcast-delay-label = Send:
+broadcast-delay-now = Now
+broadcast-delay-5m = In 5 minutes
+broadcast-delay-15m = In 15 minutes
+broadcast-delay-1h = In 1 hour
+broadcast-delay-1d = In 1 day
+
 # =============================================================================
 # Ban System
 # =============================================================================
@@ -965,12 +1514,32 @@ msg-untrusted-success = Trust entry removed successfully
 # Trust list messages
 msg-trust-list-header = Trusted IPs:
 msg-trust-list-empty = No trusted IPs
+msg-ldap-group-list-header = Directory groups:
+msg-ldap-group-list-empty = No directory groups mapped
+msg-ldap-group-admin = admin
+msg-ldap-group-no-permissions = no permissions
+msg-ldap-group-set = Directory group { $group } mapped
+msg-ldap-group-removed = Directory group { $group } removed
+msg-guest-upgraded = Account { $username } created. Log in with it next time to use it.
+msg-nickname-registered = Nickname { $nickname } is now reserved for { $username }.
+msg-nickname-released = Nickname { $nickname } released.
+msg-history-days = The server keeps your user messages for { $days } days.
+msg-history-off = The server doesn't keep your user messages. Use /history <days> to turn it on.
+msg-history-no-older = No older messages with { $nickname }.
+err-history-days-too-long = Message history can be kept for at most { $max } days
+err-history-more-tab = Use /history more in a user message tab
 msg-trust-permanent = permanent
 msg-trust-expired = expired
 msg-trust-remaining = { $time } remaining
 msg-trust-remaining-days = { $days }d { $hours }h
 msg-trust-remaining-hours = { $hours }h { $minutes }m
 msg-trust-remaining-minutes = { $minutes }m
+msg-leaderboard-header = Most active users by { $stat }:
+msg-leaderboard-empty = No activity recorded yet
+leaderboard-stat-logins = logins
+leaderboard-stat-messages = messages
+leaderboard-stat-uploads = uploads
+leaderboard-stat-downloaded = data downloaded
 
 # =============================================================================
 # Channel System
@@ -987,6 +1556,8 @@ err-channel-too-long = Channel name is too long (max { $max } characters)
 err-channel-missing-prefix = Channel name must start with #
 err-channel-invalid-characters = Channel name contains invalid characters
 err-leave-no-channel = You must be in a channel to use /{ $command } without arguments
+err-invite-no-group = Use /{ $command } in a group conversation tab
+err-group-too-many-members = A group conversation can have at most { $max } members
 err-not-in-channel = You are not in channel { $channel }
 err-leave-already-pending = Already waiting for server response
 err-uri-connection-failed = Failed to connect to { $host }: { $error }
@@ -1004,6 +1575,9 @@ msg-channel-member-count = { $count } { $count ->
    *[other] members
 }
 channel-secret = secret
+channel-info-topic = Topic
+channel-info-rules = Rules
+channel-info-language = Language
 
 # =============================================================================
 # Voice Chat
@@ -1012,6 +1586,16 @@ channel-secret = secret
 # Voice error messages
 err-voice-join = Failed to join voice: { $error }
 err-voice-leave = Failed to leave voice: { $error }
+err-voice-limit = Failed to set voice limit: { $error }
+err-voice-clip = Soundboard: { $error }
+err-voice-text = Voice text: { $error }
+err-voice-clip-name-empty = Clip name cannot be empty
+err-voice-clip-name-too-long = Clip name is too long (max { $max } characters)
+err-voice-clip-name-invalid = Clip names may only contain letters, digits, - and _
+err-voice-clip-too-large = Clip is too large (max 256KB)
+err-voice-clip-unsupported-type = Unsupported clip format (Ogg Vorbis only)
+msg-voice-queued = Voice is full - you are number { $position } in the queue
+msg-voice-queue-cancelled = You were removed from the voice queue
 err-voice-no-token = Voice join succeeded but no token received
 err-voice-already-active = You are already in a voice session on another server
 err-voice-no-permission = You don't have permission to join voice chat
@@ -1037,7 +1621,12 @@ msg-voice-joined-empty = Joined voice in { $target }
 msg-voice-left = Left voice in { $target }
 msg-voice-user-joined = { $nickname } joined voice
 msg-voice-user-left = { $nickname } left voice
+msg-voice-broadcast-started = { $nickname } is broadcasting in voice - join to listen
+msg-voice-broadcast-ended = The voice broadcast has ended
+msg-voice-clip-played = { $nickname } played { $name }
+msg-voice-text = [voice] { $nickname }: { $message }
 msg-voice-you-left = You have left voice chat
+msg-voice-tcp-relay = Voice UDP connection failed ({ $error }) - relaying voice through the server connection instead
 
 # Voice UI
 voice-join-tooltip = Join voice chat
@@ -1045,6 +1634,8 @@ voice-leave-tooltip = Leave voice chat
 voice-mute-all-tooltip = Mute All
 voice-unmute-all-tooltip = Unmute All
 voice-bar-participants = ({ $count })
+voice-bar-queued = (queued: { $position })
+voice-bar-listeners = ({ $count } listening)
 voice-indicator-tooltip = In voice chat
 tooltip-mute = Mute
 tooltip-unmute = Unmute
@@ -1055,6 +1646,12 @@ voice-quality-medium = Medium (32 kbps)
 voice-quality-high = High (64 kbps)
 voice-quality-very-high = Very High (96 kbps)
 
+# Voice connection quality
+voice-connection-good = Good
+voice-connection-fair = Fair
+voice-connection-poor = Poor
+voice-connection-tooltip = Connection: { $quality } ({ $loss }% loss, { $jitter } ms jitter)
+
 # PTT modes
 ptt-mode-hold = Hold to talk
 ptt-mode-toggle = Toggle
@@ -1069,6 +1666,8 @@ ptt-delay-500ms = 500ms
 audio-output-device = Output Device
 audio-input-device = Input Device
 audio-refresh-devices = Refresh Devices
+audio-clip-volume = Soundboard Volume
+audio-voice-text-tts = Read Voice Texts Aloud
 audio-voice-quality = Voice Quality
 audio-ptt-key = Push-to-Talk Key
 audio-ptt-mode = PTT Mode
@@ -1096,6 +1695,44 @@ mic-boost-6db = +6 dB
 mic-boost-12db = +12 dB
 mic-boost-18db = +18 dB
 
+# =============================================================================
+# Global Hotkeys
+# =============================================================================
+
+# Settings
+settings-toggle-window-key = Show/Hide Window Key
+button-clear-hotkey = Clear
+
+# Shortcut descriptions (shown by the desktop on Wayland)
+hotkey-push-to-talk = Push to talk
+hotkey-toggle-window = Show or hide Nexus
+
+# Errors
+err-window-hotkey-failed = Show/hide window hotkey failed to register: { $error }
+err-global-shortcuts-unavailable = Global shortcuts portal unavailable, hotkeys only work while Nexus is focused: { $error }
+
+# =============================================================================
+# Auto Away
+# =============================================================================
+
+settings-auto-away = Go away automatically when idle
+settings-auto-away-minutes = Minutes idle
+
+# =============================================================================
+# Connection Health
+# =============================================================================
+
+# Server list indicator
+connection-health-good = Good
+connection-health-fair = Fair
+connection-health-poor = Poor
+connection-health-tooltip = Connection: { $quality }
+connection-health-latency = Latency: { $ms } ms
+connection-health-jitter = Jitter: { $ms } ms
+connection-health-last-drop = Last dropped ping: { $time }
+connection-health-no-drops = No dropped pings
+connection-health-measuring = Measuring latency…
+
 # =============================================================================
 # System Tray (Windows/Linux only)
 # =============================================================================
@@ -1124,14 +1761,33 @@ tray-tooltip-unread = Nexus BBS - { $count ->
    *[other] { $count } unread conversations
 }
 
+# =============================================================================
+# Notification Actions (macOS/Windows only)
+# =============================================================================
+
+# Actions
+notification-reply = Reply
+notification-reply-send = Send
+notification-reply-placeholder = Reply…
+notification-mark-read = Mark read
+
 # =============================================================================
 # Toast Notifications
 # =============================================================================
 
 toast-link-copied = Link copied to clipboard
 toast-copied = Copied to clipboard
+toast-checksum-copied = SHA-256 copied to clipboard
 toast-download-started = Downloading { $filename }
 toast-download-queued = Download queued: { $filename }
+toast-downloads-started = Downloading { $count } { $count ->
+    [one] file
+   *[other] files
+}
+toast-downloads-queued = { $count } { $count ->
+    [one] download
+   *[other] downloads
+} queued
 toast-upload-started = Uploading { $filename }
 toast-upload-queued = Upload queued: { $filename }
 toast-uploads-started = Uploading { $count } { $count ->
@@ -1142,3 +1798,28 @@ toast-uploads-queued = { $count } { $count ->
     [one] upload
    *[other] uploads
 } queued
+toast-upload-nothing-new = Nothing to upload — the server already has these files
+toast-upload-watch-started = Watching { $folder } — new files will be uploaded automatically
+toast-upload-watch-stopped = Stopped watching { $folder }
+toast-auto-download-started = Auto-downloading { $pattern } from { $folder }
+toast-auto-download-stopped = Stopped auto-downloading from { $folder }
+toast-screenshot-folder-set = Screenshots will be uploaded to this folder
+toast-chat-exported = Chat exported
+
+# =============================================================================
+# First-run Tips
+# =============================================================================
+
+tip-welcome-title = Welcome to Nexus
+tip-welcome = Enter a server's address below and connect. Add a bookmark to keep the server in the list on the left, with your login, so it's one click away next time.
+tip-fingerprint = Every server has a certificate fingerprint, like a signature. Nexus remembers it the first time you connect and warns you if it ever changes, because that can mean someone is pretending to be the server. Only accept a new fingerprint if the server's admin told you it changed.
+tip-server-list = Your bookmarks and open connections are on the left. Click one to switch servers, or drag bookmarks to rearrange them.
+tip-toolbar = The toolbar opens files, news, settings and more. Hover over an icon to see what it does.
+tip-chat = Chat with everyone on the server here. Type / to see the commands you can use, like /join to enter a channel.
+tip-user-list = Users online right now are listed on the right. Click a name to message them or see their info.
+tip-counter = Tip { $current } of { $total }
+button-add-bookmark = Add Bookmark
+button-tip-next = Next
+button-tip-done = Done
+button-tip-got-it = Got It
+button-skip-tips = Skip Tips
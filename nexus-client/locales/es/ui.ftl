@@ -11,6 +11,13 @@ button-connect = Conectar
 button-save = Guardar
 button-create = Crear
 button-edit = Editar
+button-check-ports = Comprobar puertos
+button-statistics = Estadísticas
+button-dashboard = Panel
+button-refresh = Actualizar
+button-doors = Puertas
+button-doors-leave = Salir
+button-doors-back = Volver
 button-update = Actualizar
 
 button-accept-new-certificate = Aceptar Nuevo Certificado
@@ -19,9 +26,23 @@ button-choose-avatar = Elegir Icono
 button-clear-avatar = Borrar
 button-choose-image = Elegir Imagen
 button-clear-image = Borrar
+button-add-file = Añadir archivo
 button-overwrite = Sobrescribir
 button-change-password = Cambiar Contraseña
 button-browse = Examinar
+button-undo = Deshacer
+button-add = Añadir
+button-remove = Quitar
+button-view = Ver
+button-hide = Ocultar
+button-export = Exportar…
+button-trust-certificate = Confiar en el certificado
+button-skip = Omitir
+button-skip-existing = Omitir existentes
+button-download-anyway = Descargar de todos modos
+button-unlock = Desbloquear
+button-view-as-user = Ver como Usuario
+button-post-comment = Publicar
 
 # =============================================================================
 # Titles
@@ -36,19 +57,29 @@ title-user-create = Crear Usuario
 title-user-edit = Editar Usuario
 title-update-user = Actualizar Usuario
 title-user-management = Gestionar Usuarios
+title-permission-audit = Auditoría de Permisos
+title-impersonate = Viendo como { $username }
 title-confirm-delete = Confirmar Eliminación
 title-connected = Conectados
 title-settings = Configuración
 title-bookmarks = Marcadores
 title-users = Usuarios
+title-friends = Amigos
+friend-online = conectado
+friend-offline = desconectado
 title-edit-server-info = Editar Info del Servidor
 title-fingerprint-mismatch = ¡Huella del Certificado No Coincide!
+title-fingerprint-new-server = Certificado de servidor nuevo
+title-create-password-vault = Proteger contraseñas guardadas
+title-unlock-password-vault = Desbloquear contraseñas guardadas
+title-unlock-config = Desbloquear ajustes
 title-server-info = Info del Servidor
 title-user-info = Info del Usuario
 title-about = Acerca de
 title-change-password = Cambiar Contraseña
 title-news = Noticias
 title-transfers = Transferencias
+title-transfer-history = Historial de transferencias
 title-news-create = Crear Publicación
 title-news-edit = Editar Publicación
 title-channel-members = Miembros
@@ -59,10 +90,14 @@ title-channel-members = Miembros
 
 placeholder-username = Nombre de usuario
 placeholder-password = Contraseña
+placeholder-master-passphrase = Frase de contraseña maestra
+placeholder-confirm-master-passphrase = Confirmar frase de contraseña maestra
 placeholder-port = Puerto
 placeholder-server-address = Dirección del Servidor
 placeholder-server-name = Nombre del Servidor
+placeholder-folder-name = Nombre de la carpeta
 placeholder-username-optional = Nombre de usuario (opcional)
+placeholder-fallback-addresses-optional = Direcciones alternativas (opcional, separadas por comas)
 placeholder-password-optional = Contraseña (opcional)
 placeholder-password-keep-current = Contraseña
 placeholder-current-password = Contraseña Actual
@@ -71,10 +106,18 @@ placeholder-confirm-password = Confirmar Contraseña
 placeholder-message = Escribe un mensaje…
 placeholder-no-permission = Sin permiso
 placeholder-broadcast-message = Mensaje de difusión
+placeholder-broadcast-channel = Elija un canal
+placeholder-broadcast-permission = Elija un permiso
 placeholder-server-description = Descripción del servidor
 placeholder-news-body = Contenido de noticia
+placeholder-news-caption = Leyenda (opcional)
+placeholder-news-file-path = Ruta en el área de archivos
 placeholder-nickname-optional = Apodo (opcional)
+placeholder-status-optional = Estado (opcional)
+placeholder-download-folder-optional = Carpeta de descargas (opcional)
 placeholder-proxy-address = Dirección del Proxy
+placeholder-notification-rules-channel = #canal
+placeholder-notification-rules-keyword = Palabra clave
 placeholder-proxy-port = 9050
 placeholder-proxy-username = Usuario (opcional)
 placeholder-proxy-password = Contraseña (opcional)
@@ -87,13 +130,20 @@ placeholder-auto-join-channels = #canal1 #canal2 (separados por espacios)
 # =============================================================================
 
 label-auto-connect = Auto-Conectar
+label-server-avatar = Avatar:
 label-add-bookmark = Marcador
+label-lan-servers = En esta red
+label-use-sso = Inicio de sesión único
 label-admin = Administrador
 label-shared-account = Cuenta Compartida
 label-enabled = Habilitado
+label-erase-personal-data = Borrar también los datos personales
+label-broadcast-require-ack = Pedir confirmación a los destinatarios
 label-permissions = Permisos:
 label-expected-fingerprint = Huella esperada:
 label-received-fingerprint = Huella recibida:
+label-certificate-fingerprint = Huella del certificado:
+label-identity-key = Clave de identidad:
 label-theme = Tema
 label-chat-history-retention = Historial de chat:
 chat-history-forever = Para siempre
@@ -101,6 +151,10 @@ chat-history-30-days = 30 días
 chat-history-14-days = 14 días
 chat-history-7-days = 7 días
 chat-history-disabled = Desactivado
+collision-policy-rename = Conservar ambos (renombrar)
+collision-policy-overwrite = Sobrescribir
+collision-policy-skip = Omitir
+collision-policy-ask = Preguntar
 label-max-scrollback = Historial máximo:
 label-chat-font-size = Tamaño de fuente:
 label-show-connection-events = Mostrar eventos de conexión/desconexión
@@ -108,6 +162,19 @@ label-show-channel-events = Mostrar eventos de entrada/salida
 label-show-timestamps = Mostrar marcas de tiempo
 label-use-24-hour-time = Usar formato de 24 horas
 label-show-seconds = Mostrar segundos
+label-split-view = Mostrar archivos, noticias y monitor de conexiones junto al chat
+label-spell-check = Revisar la ortografía en el chat y las noticias
+label-spell-check-no-dictionary = No se encontró un diccionario para tu idioma. Coloca sus archivos .aff y .dic en la carpeta dictionaries junto a config.json.
+label-send-read-receipts = Enviar confirmaciones de lectura de mensajes privados
+label-user-list-grouping = Agrupar lista de usuarios:
+label-user-list-sort = Ordenar lista de usuarios:
+user-list-group-none = Ninguno
+user-list-group-admins = Administradores primero
+user-list-group-channel = Por canal
+user-list-group-status = Por estado
+user-list-sort-alphabetical = Alfabético
+user-list-sort-idle = Tiempo inactivo
+user-list-sort-connect = Tiempo de conexión
 label-server-name = Nombre:
 label-server-description = Descripción:
 label-server-version = Versión:
@@ -141,6 +208,27 @@ tab-files = Archivos
 tab-channels = Canales
 label-connections-short = Conexiones:
 label-transfers-short = Transferencias:
+label-active-transfers-short = Activas:
+label-queue-order-short = Orden de cola:
+label-trusted-weight-short = Peso de confianza:
+label-nat-mapping-short = Asignación de puertos:
+label-nat-external-ip-short = IP externa:
+label-nat-not-mapped = Sin asignar
+connectivity-check-running = Comprobando puertos…
+check-service-bbs = BBS
+check-service-transfer = Transferencias
+check-service-websocket = WebSocket
+check-service-transfer-websocket = Transferencias WebSocket
+check-service-voice = Voz
+check-state-open = Abierto
+check-state-closed = Cerrado
+check-state-filtered = Filtrado
+check-state-untested = Sin probar
+label-check-port = { $service } ({ $protocol } { $port }):
+label-queue-order-small-first = Archivos pequeños primero
+label-queue-order-arrival = Orden de llegada
+label-small-files-first = Poner en cola primero los archivos pequeños
+label-unlimited = Ilimitado
 label-reindex-short = Reindexar:
 label-persistent-short = Persistentes:
 label-auto-join-short = Auto-unión:
@@ -157,6 +245,19 @@ label-download-location = Ubicación de Descarga:
 label-queue-transfers = Poner Transferencias en Cola
 label-download-limit = Límite de Descargas:
 label-upload-limit = Límite de Subidas:
+label-auto-open-folder = Abrir la carpeta al completar una descarga
+label-collision-policy = Si el archivo existe:
+label-require-fingerprint-approval = Preguntar antes de confiar en el certificado de un servidor nuevo
+label-known-servers = Servidores conocidos
+known-servers-empty = Aún no hay certificados de servidor guardados
+known-server-first-seen = Visto por primera vez { $date }
+known-server-first-seen-unknown = Visto por primera vez: desconocido
+label-encrypt-config = Cifrar ajustes, marcadores e historial de chat con una frase de contraseña maestra
+config-encryption-locked-note = Los ajustes están bloqueados. Reinicia Nexus e introduce la frase de contraseña maestra para cambiar esto.
+label-upload-rule-extensions = Tipos de archivo permitidos:
+label-upload-rule-max-file-size = Archivo más grande (MB):
+label-upload-rule-max-total-size = Subida más grande (MB):
+label-auto-download-pattern = Nombres de archivo:
 label-persistent-channels = Canales Persistentes:
 label-auto-join-channels = Canales Auto-Unión:
 label-channels = Canales
@@ -170,11 +271,14 @@ permission-user_info = Info de Usuario
 permission-chat_send = Enviar Chat
 permission-chat_receive = Recibir Chat
 permission-chat_join = Unirse al Chat
+permission-chat_language = Idioma de chat
 permission-chat_create = Crear Chat
 permission-chat_list = Lista de Chats
 permission-chat_secret = Canal Secreto
 permission-chat_topic = Tema del Chat
 permission-chat_topic_edit = Editar Tema del Chat
+permission-chat_topic_lock = Bloquear Tema del Chat
+permission-chat_entry_edit = Editar Bienvenida del Chat
 permission-user_broadcast = Difusión de Usuario
 permission-user_create = Crear Usuario
 permission-user_delete = Eliminar Usuario
@@ -185,6 +289,7 @@ permission-news_list = Lista de Noticias
 permission-news_create = Crear Noticias
 permission-news_edit = Editar Noticias
 permission-news_delete = Eliminar Noticias
+permission-file_comment = Comentar archivos
 permission-file_copy = Copiar Archivo
 permission-file_create_dir = Directorio Crear
 permission-file_delete = Eliminar Archivo
@@ -204,6 +309,11 @@ permission-trust_create = Crear Confianza
 permission-trust_delete = Eliminar Confianza
 permission-trust_list = Lista de Confianza
 permission-connection_monitor = Monitor de Conexiones
+permission-log_view = Ver registro
+permission-door_play = Jugar puertas
+permission-voice_broadcast = Emisión de Voz
+permission-voice_clip_upload = Subir Clips de Voz
+permission-voice_limit = Límite de Voz
 permission-voice_listen = Escuchar Voz
 permission-voice_talk = Hablar por Voz
 
@@ -228,19 +338,31 @@ tooltip-files-paste = Pegar
 tooltip-files-search = Buscar archivos
 tooltip-manage-users = Gestionar Usuarios
 tooltip-connection-monitor = Monitor de Conexiones
+tooltip-server-log = Registro del servidor
 tooltip-server-info = Info del Servidor
 tooltip-about = Acerca de
 tooltip-transfers = Transferencias
 tooltip-download-all = Descargar Todo
 tooltip-upload = Subir
 file-picker-upload-title = Seleccionar archivos para subir
+file-picker-download-to-title = Seleccionar carpeta de descargas
+folder-picker-upload-watch-title = Selecciona la carpeta para subir automáticamente
 drop-to-upload = Soltar para subir
 tooltip-clear-inactive = Limpiar Inactivos
+tooltip-transfer-history = Historial de transferencias
+tooltip-transfer-history-clear = Borrar historial
+tooltip-transfer-history-remove = Quitar del historial
 tooltip-transfer-pause = Pausar
 tooltip-transfer-resume = Reanudar
 tooltip-transfer-cancel = Cancelar
 tooltip-transfer-remove = Eliminar
 tooltip-transfer-open-folder = Abrir Carpeta
+tooltip-transfer-open-file = Abrir Archivo
+tooltip-transfer-reveal = Mostrar en el Gestor de Archivos
+tooltip-transfer-copy-checksum = Copiar SHA-256
+tooltip-transfer-collision-rename = Conservar ambos (renombrar)
+tooltip-transfer-collision-overwrite = Sobrescribir archivos existentes
+tooltip-transfer-collision-skip = Omitir archivos existentes
 tooltip-transfer-move-up = Subir
 tooltip-transfer-move-down = Bajar
 tooltip-transfer-retry = Reintentar
@@ -253,16 +375,34 @@ tooltip-disconnect = Desconectar
 tooltip-edit = Editar
 tooltip-info = Info
 tooltip-message = Mensaje
+tooltip-add-friend = Añadir amigo
+tooltip-remove-friend = Quitar amigo
 tooltip-kick = Expulsar
 tooltip-close = Cerrar
+tooltip-retry-send = Reintentar envío
+tooltip-cancel-send = Cancelar envío
+tooltip-message-sent = Enviado
+tooltip-message-delivered = Entregado
+tooltip-message-read = Leído
 tooltip-new-tab = Nueva Pestaña
 tooltip-close-tab = Cerrar Pestaña
 tooltip-add-bookmark = Añadir Marcador
+tooltip-new-folder = Nueva carpeta
 tooltip-create-user = Crear Usuario
+tooltip-permission-audit = Auditar Permisos
 tooltip-create-news = Crear Publicación
+tooltip-news-search = Buscar noticias
+tooltip-news-search-clear = Borrar búsqueda
 tooltip-delete = Eliminar
 context-menu-download = Descargar
+context-menu-download-to = Descargar en…
+context-menu-download-select = Descargar selección…
+context-menu-auto-download = Descarga automática…
 context-menu-upload = Subir
+context-menu-watch-folder = Vigilar carpeta local…
+context-menu-screenshot-folder = Compartir capturas aquí
+context-menu-unwatch-folder = Dejar de vigilar carpeta
+context-menu-upload-rules = Reglas de subida…
 context-menu-open = Abrir
 
 # =============================================================================
@@ -270,6 +410,7 @@ context-menu-open = Abrir
 # =============================================================================
 
 panel-connection-monitor = Monitor de Conexiones
+panel-server-log = Registro del servidor
 panel-active-connections = Conexiones Activas: { $count }
 tab-connections = Conexiones
 tab-transfers = Transferencias
@@ -284,9 +425,97 @@ menu-info = Info
 menu-copy = Copiar
 menu-kick = Expulsar
 menu-ban = Bloquear
+menu-pop-out = Separar
+menu-mute-tab = Silenciar
+menu-unmute-tab = Activar sonido
+menu-connect-all = Conectar todos
+menu-rename = Renombrar
+menu-delete = Eliminar
 connection-monitor-loading = Cargando conexiones…
 connection-monitor-no-connections = Sin conexiones
 connection-monitor-no-transfers = Sin transferencias activas
+connection-monitor-queued = En cola
+connection-monitor-weight = Peso de reparto justo: ×{ $weight }
+connection-monitor-listed = Listada en { $zone }
+connection-monitor-queued-count = { $count } en cola
+placeholder-server-log-module = Filtrar por módulo
+server-log-loading = Cargando registro del servidor…
+server-log-empty = No hay líneas de registro
+server-log-level-error = Solo errores
+server-log-level-warn = Advertencias y superiores
+server-log-level-info = Info y superiores
+server-log-level-debug = Todo (depuración)
+panel-statistics = Estadísticas
+stats-loading = Cargando estadísticas…
+panel-snippet = Fragmento
+snippet-loading = Cargando fragmento…
+snippet-details = Compartido por { $author } · caduca el { $expires }
+stats-range-days = Últimos { $days } días
+stats-chart-max = Día máximo: { $value }
+stats-metric-logins = Inicios de sesión
+stats-metric-messages = Mensajes
+stats-metric-uploads = Subidas
+stats-metric-upload-bytes = Datos subidos
+stats-metric-downloads = Descargas
+stats-metric-download-bytes = Datos descargados
+stats-metric-peak-sessions = Máximo de usuarios conectados
+panel-admin-dashboard = Panel de administración
+dashboard-loading = Cargando panel…
+dashboard-connections = Conexiones
+dashboard-sessions = Usuarios conectados
+dashboard-connection-count = Conexiones
+dashboard-transfer-connections = Conexiones de transferencia
+dashboard-transfers = Transferencias
+dashboard-active-transfers = En curso
+dashboard-queued-transfers = En cola
+dashboard-today = Hoy
+dashboard-disk = Área de archivos
+dashboard-disk-free = Espacio libre
+dashboard-disk-free-of = { $free } libres de { $total }
+dashboard-disk-unknown = Desconocido
+dashboard-recent-audit = Eventos de auditoría recientes
+dashboard-audit-empty = Aún no hay eventos de auditoría
+dashboard-updated = Actualizado a las { $time }
+panel-doors = Puertas
+doors-loading = Cargando puertas…
+doors-empty = No hay puertas en este servidor
+doors-starting = Iniciando…
+doors-playing-hint = Las teclas van a la puerta. Usa Salir para terminar.
+doors-exited = La puerta ha terminado
+doors-exited-code = La puerta terminó con el código { $code }
+
+# =============================================================================
+# Quick Switcher
+# =============================================================================
+
+quick-switcher-placeholder = Ir a un canal, mensaje, marcador o panel...
+user-list-filter-placeholder = Filtrar usuarios...
+user-list-section-admins = Administradores
+user-list-section-users = Usuarios
+user-list-section-no-channel = Otros
+user-list-section-online = En línea
+user-list-section-away = Ausente
+user-list-section-dnd = No molestar
+user-list-idle = Inactivo { $duration }
+quick-switcher-no-matches = Sin coincidencias
+quick-switcher-bookmark = Marcador
+
+# =============================================================================
+# Chat Input
+# =============================================================================
+
+chat-paste-prompt = ¿Pegar { $count } líneas?
+chat-paste-too-long-prompt = Demasiado largo para un mensaje (máx. { $max }). ¿Enviarlo de todos modos?
+button-send-as-messages = Enviar como { $count } mensajes
+button-send-as-one-message = Enviar como un solo mensaje
+button-send-in-parts = Enviar en { $count } partes
+button-share-as-snippet = Compartir como fragmento
+button-expand-code = Mostrar las { $count } líneas
+button-collapse-code = Contraer
+chat-broadcast-ack-prompt = { $username } le pide que confirme su difusión
+button-acknowledge = Confirmar
+button-dismiss = Descartar
+spell-no-suggestions = No hay sugerencias
 
 # =============================================================================
 # Empty States
@@ -297,11 +526,35 @@ empty-no-connections = Sin conexiones
 empty-no-bookmarks = Sin marcadores
 empty-no-users = No hay usuarios conectados
 empty-no-channel-members = No hay miembros en el canal
+empty-no-matching-users = No hay usuarios que coincidan
 user-management-loading = Cargando usuarios…
 user-management-no-users = No se encontraron usuarios
+permission-audit-hint = Elige un permiso para ver quién lo tiene, o haz clic en un usuario para ver todo lo que puede hacer.
+permission-audit-loading = Cargando…
+permission-audit-no-holders = Ninguna cuenta tiene este permiso
+permission-audit-effective = Permisos efectivos de { $username }
+permission-audit-admin = Los administradores tienen todos los permisos
+permission-audit-no-permissions = Esta cuenta no tiene permisos
+permission-audit-restricted = Concedidos pero no disponibles para cuentas compartidas:
+permission-audit-disabled = Deshabilitada
+impersonate-notice = Vista de solo lectura de lo que ve esta cuenta. Cada consulta queda registrada en el registro de auditoría.
+impersonate-permissions = Permisos
+impersonate-files = Archivos ({ $path })
+impersonate-no-file-access = Esta cuenta no puede explorar archivos
+impersonate-empty-folder = Esta carpeta está vacía
+impersonate-channels = Canales
+impersonate-no-channel-access = Esta cuenta no puede listar canales
+impersonate-no-channels = No hay canales visibles
+impersonate-secret = Secreto
 news-loading = Cargando noticias…
 news-no-posts = Aún no hay publicaciones
+news-search-placeholder = Buscar noticias… (author:nombre since:AAAA-MM-DD until:AAAA-MM-DD)
+news-searching = Buscando…
+news-search-no-results = No hay publicaciones que coincidan
 transfers-empty = Sin transferencias
+transfer-history-search-placeholder = Buscar por nombre, servidor o carpeta…
+transfer-history-empty = Aún no hay transferencias finalizadas
+transfer-history-no-matches = Ninguna transferencia coincide con su búsqueda
 news-updated = Actualizado
 files-loading = Cargando archivos…
 files-empty = Este directorio está vacío
@@ -313,10 +566,19 @@ files-column-name = Nombre
 files-column-size = Tamaño
 files-column-modified = Modificado
 files-column-path = Ruta
+files-column-rating = Valoración
 files-search-placeholder = Buscar archivos…
 files-search-breadcrumb = Búsqueda - { $query }
 files-searching = Buscando…
 files-no-results = No se encontraron archivos
+files-highlights = Destacados
+files-highlights-recent = Subidas nuevas esta semana
+files-highlights-popular = Más descargados
+files-highlights-empty = Aún no hay nada
+files-highlights-downloads = { $count } { $count ->
+    [one] descarga
+   *[other] descargas
+}
 files-search-query-too-short = La consulta de búsqueda es muy corta (mín. { $min_length } caracteres)
 files-search-query-too-long = La consulta de búsqueda es muy larga (máx. { $max_length } caracteres)
 files-search-query-invalid = La consulta de búsqueda contiene caracteres no válidos
@@ -327,6 +589,30 @@ files-share = Compartir
 files-rename = Renombrar
 files-rename-title = Renombrar "{ $name }"
 files-rename-placeholder = Nuevo nombre
+files-upload-rule-title = Reglas de subida de "{ $name }"
+files-upload-rule-loading = Cargando…
+files-upload-rule-extensions-placeholder = Cualquier tipo (p. ej. mp3, flac)
+files-upload-rule-no-limit = Sin límite
+files-upload-rule-remove = Quitar regla
+files-upload-rule-help = Las reglas se aplican a esta carpeta y sus subcarpetas, salvo que una subcarpeta tenga las suyas.
+files-auto-download-title = Descarga automática de "{ $name }"
+files-auto-download-pattern-placeholder = p. ej. *.mp3, *.m4a
+files-auto-download-remove = Detener la descarga automática
+files-auto-download-help = Los archivos nuevos de esta carpeta cuyos nombres coincidan se descargan mientras esté conectado mediante este marcador. Use * para cualquier texto y ? para un solo carácter, y separe los patrones con comas. Los archivos que ya descargó se omiten.
+files-upload-rule-hint = Subidas aquí: { $limits }
+files-upload-rule-hint-types = solo { $extensions }
+files-upload-rule-hint-file-size = hasta { $size } por archivo
+files-upload-rule-hint-total-size = hasta { $size } por subida
+files-upload-preview-title = Los archivos ya existen
+files-upload-preview-comparing = Comparando con los archivos del servidor…
+files-upload-preview-summary = { $new } nuevos, { $identical } ya en el servidor, { $conflicts } distintos:
+files-upload-preview-blocked = { $count } { $count ->
+    [one] archivo tiene
+   *[other] archivos tienen
+} una carpeta con el mismo nombre en el servidor y se { $count ->
+    [one] omitirá
+   *[other] omitirán
+}
 files-cut = Cortar
 files-copy = Copiar
 files-paste = Pegar
@@ -336,6 +622,13 @@ files-delete-confirm-title = Confirmar eliminación
 files-delete-confirm-message = ¿Está seguro de que desea eliminar "{ $name }"?
 # File Overwrite Dialog
 files-overwrite-title = El archivo ya existe
+files-download-to-title = Descargar { $name }
+label-remember-for-server = Recordar para este servidor
+label-select-all = Seleccionar todo
+files-download-select-summary = { $count } de { $total } seleccionados ({ $size })
+files-download-select-too-many = Se pueden seleccionar como máximo { $max } archivos individuales. Descarga la carpeta completa en su lugar.
+files-download-duplicate-title = Ya descargado
+files-download-duplicate-message = "{ $name }" ya está en este equipo en { $path }. ¿Desea descargarlo de nuevo?
 files-overwrite-message = Ya existe un archivo o carpeta llamado "{ $name }" en el destino. ¿Desea reemplazarlo?
 
 # File Info Dialog
@@ -351,6 +644,17 @@ files-info-directory = Directorio
 files-info-symlink = Enlace simbólico:
 files-info-yes = Sí
 files-info-na = N/D
+files-info-rating = Valoración:
+files-info-rating-value = { $average } ({ $count } { $count ->
+    [one] valoración
+   *[other] valoraciones
+})
+files-info-not-rated = Sin valorar todavía
+files-info-your-rating = Tu valoración:
+files-info-comments = Comentarios ({ $count })
+files-comments-loading = Cargando comentarios…
+files-comments-empty = Todavía no hay comentarios
+files-comment-placeholder = Escribe un comentario…
 
 # =============================================================================
 # Directory Name Validation Errors
@@ -369,6 +673,7 @@ err-dir-name-invalid = El nombre del directorio contiene caracteres inválidos
 tab-general = General
 tab-chat = Chat
 tab-network = Red
+tab-security = Seguridad
 tab-audio = Audio
 
 # =============================================================================
@@ -390,6 +695,8 @@ chat-prefix-system = [SIS]
 chat-prefix-error = [ERR]
 chat-prefix-info = [INFO]
 chat-prefix-broadcast = [BROADCAST]
+chat-prefix-queued = [PENDING]
+chat-export-subtitle = Exportado de { $server } el { $date }
 
 # =============================================================================
 # Success Messages
@@ -413,11 +720,28 @@ msg-channel-member-count = { $count } { $count ->
    *[other] miembros
 }
 channel-secret = secreto
+channel-info-topic = Tema
+channel-info-rules = Reglas
+channel-info-language = Idioma
 msg-broadcast-sent = Difusión enviada exitosamente
+msg-broadcast-delivered = Difusión entregada a { $count } { $count ->
+    [one] usuario
+   *[other] usuarios
+}
+msg-broadcast-scheduled = Difusión programada para { $time }
+msg-broadcast-acknowledged = Difusión confirmada por { $count } de { $delivered } usuarios
+msg-storage-low = Poco espacio en disco en el servidor para { $volume }: { $free } libres, por debajo del mínimo de { $threshold }
+msg-storage-recovered = Espacio en disco en el servidor para { $volume } recuperado: { $free } libres
+storage-volume-files = el área de archivos
+storage-volume-database = la base de datos
+msg-friend-added = { $username } añadido a amigos
+msg-friend-removed = { $username } quitado de amigos
 msg-user-created = Usuario creado exitosamente
 msg-user-created-name = Usuario '{ $username }' creado exitosamente
 msg-user-deleted = Usuario eliminado exitosamente
 msg-user-deleted-name = Usuario '{ $username }' eliminado exitosamente
+msg-user-erased = Usuario eliminado y datos personales borrados
+msg-user-erased-name = Usuario '{ $username }' eliminado y datos personales borrados
 msg-user-updated = Usuario actualizado exitosamente
 msg-user-updated-name = Usuario '{ $username }' actualizado exitosamente
 msg-permissions-updated = Tus permisos han sido actualizados
@@ -432,6 +756,8 @@ msg-welcome-back = Bienvenido de vuelta
 msg-status-set = Estado establecido: { $status }
 msg-status-cleared = Estado borrado
 msg-user-is-away = { $nickname } está ausente
+msg-group-member-joined = { $nickname } se unió a la conversación
+msg-group-member-left = { $nickname } salió de la conversación
 msg-user-is-away-status = { $nickname } está ausente: { $status }
 
 
@@ -444,6 +770,14 @@ msg-topic-cleared = Tema eliminado por { $username }
 msg-topic-set = Tema establecido por { $username }: { $topic }
 msg-secret-set = Modo secreto habilitado por { $username }
 msg-secret-cleared = Modo secreto deshabilitado por { $username }
+msg-topic-locked = Tema bloqueado por { $username }
+msg-topic-unlocked = Tema desbloqueado por { $username }
+msg-rules-set = Reglas del canal actualizadas por { $username }
+msg-rules-cleared = Reglas del canal eliminadas por { $username }
+msg-language-set = Idioma del canal establecido en { $language } por { $username }
+msg-language-cleared = Idioma del canal eliminado por { $username }
+msg-voice-limit-set = Límite de voz establecido en { $limit } por { $username }
+msg-voice-limit-cleared = Límite de voz eliminado por { $username }
 msg-server-info-updated = Configuración del servidor actualizada
 msg-topic-display = Tema: { $topic }
 confirm-delete-user = ¿Estás seguro de que deseas eliminar al usuario '{ $username }'?
@@ -463,8 +797,10 @@ err-user-kick-failed = Error al expulsar usuario
 err-unknown = Error desconocido
 err-no-shutdown-handle = Error de conexión: Sin manejador de cierre
 err-userlist-failed = Error al actualizar lista de usuarios
+err-request-timeout = El servidor no respondió a tiempo
 err-port-invalid = El puerto debe ser un número válido (1-65535)
 err-no-chat-permission = No tienes permiso para enviar mensajes
+err-chat-window-command = Desde una ventana de chat solo se pueden enviar mensajes y /me
 err-console-no-send = Usa /{ $join } para entrar a un canal o /{ $msg } para enviar un mensaje a un usuario
 
 # Network connection errors
@@ -478,10 +814,13 @@ err-connection-closed = Conexión cerrada
 err-could-not-determine-config-dir = No se pudo determinar el directorio de configuración
 err-message-too-long = El mensaje es demasiado largo ({ $length } caracteres, máx { $max })
 err-send-failed = Error al enviar mensaje
+err-checksum-failed = No se pudo calcular la suma de verificación
 err-broadcast-too-long = La difusión es demasiado larga ({ $length } caracteres, máx { $max })
 err-broadcast-send-failed = Error al enviar difusión
+err-broadcast-target-required = Elija quién recibe la difusión
 err-name-required = El nombre del marcador es requerido
 err-address-required = La dirección del servidor es requerida
+err-fallback-address-invalid = Dirección alternativa no válida: { $address }
 err-port-required = El puerto es requerido
 err-username-required = El nombre de usuario es requerido
 err-password-required = La contraseña es requerida
@@ -499,13 +838,20 @@ err-nickname-too-long = El apodo es demasiado largo (máx { $max } caracteres)
 err-nickname-invalid = El apodo contiene caracteres inválidos
 err-password-too-long = La contraseña es demasiado larga (máx { $max } caracteres)
 err-passwords-do-not-match = Las contraseñas no coinciden
+err-wrong-master-passphrase = Frase de contraseña maestra incorrecta
+err-master-passphrase-empty = La frase de contraseña maestra no puede estar vacía
+err-password-vault = Error del almacén de contraseñas: { $error }
 err-current-password-required = La contraseña actual es requerida
 err-new-password-required = La nueva contraseña es requerida
 err-confirm-password-required = Por favor confirme su nueva contraseña
 err-topic-too-long = El tema es demasiado largo ({ $length } caracteres, máx { $max })
+err-entry-message-too-long = El mensaje de bienvenida es demasiado largo ({ $length } caracteres, máx. { $max })
+err-rules-too-long = Las reglas son demasiado largas ({ $length } caracteres, máx. { $max })
 err-avatar-unsupported-type = Tipo de archivo no soportado. Use PNG, WebP, JPEG o SVG.
 err-avatar-too-large = Icono demasiado grande. El tamaño máximo es { $max_kb }KB.
 err-avatar-decode-failed = Error al decodificar la imagen. El archivo puede estar dañado.
+err-known-server-export = No se pudo exportar el certificado: { $error }
+err-config-encryption = No se pudieron cifrar los ajustes: { $error }
 err-server-name-empty = El nombre del servidor no puede estar vacío
 err-server-name-too-long = El nombre del servidor es demasiado largo (máx. { $max } caracteres)
 err-server-name-contains-newlines = El nombre del servidor no puede contener saltos de línea
@@ -526,14 +872,23 @@ err-failed-send-update = Error al enviar actualización: { $error }
 # =============================================================================
 
 err-failed-save-config = Error al guardar configuración: { $error }
+err-friends-no-bookmark = Los amigos se guardan en el marcador del servidor; añade primero un marcador
+err-friends-too-many = Demasiados amigos (máx. { $max })
+err-friend-exists = { $username } ya es un amigo
+err-friend-not-found = { $username } no es un amigo
 err-failed-save-settings = Error al guardar configuración: { $error }
 err-invalid-port-bookmark = Puerto inválido en marcador: { $name }
 err-failed-send-broadcast = Error al enviar difusión: { $error }
 err-failed-send-message = Error al enviar mensaje: { $error }
+err-failed-start-group = No se pudo iniciar la conversación de grupo: { $error }
+err-failed-share-snippet = No se pudo compartir el fragmento: { $error }
 err-failed-create-user = Error al crear usuario: { $error }
 err-failed-delete-user = Error al eliminar usuario: { $error }
 err-failed-update-user = Error al actualizar usuario: { $error }
 err-failed-update-topic = Error al actualizar tema: { $error }
+err-failed-lock-topic = Error al bloquear tema: { $error }
+err-failed-set-language = Error al establecer el idioma del canal: { $error }
+err-failed-update-entry = Error al actualizar el canal: { $error }
 err-message-too-long-details = { $error } ({ $length } caracteres, máx { $max })
 
 # Network connection errors (with parameters)
@@ -548,6 +903,14 @@ err-tls-handshake-failed = Error en el handshake TLS: { $error }
 err-failed-send-handshake = Error al enviar handshake: { $error }
 err-failed-read-handshake = Error al leer respuesta del handshake: { $error }
 err-handshake-failed = Error en el handshake: { $error }
+err-proof-of-work-too-hard = El servidor pidió demasiada prueba de trabajo (dificultad { $difficulty })
+err-sso-not-offered = Este servidor no ofrece inicio de sesión único
+err-sso-failed = Error en el inicio de sesión único: { $error }
+err-sso-browser = No se pudo abrir el navegador: { $error }
+err-sso-timeout = Se agotó el tiempo de espera del navegador para el inicio de sesión único
+err-sso-state-mismatch = La respuesta del inicio de sesión único no coincide con la solicitud
+sso-browser-done = Sesión iniciada. Puedes cerrar esta ventana y volver a Nexus.
+sso-browser-failed = No se pudo iniciar sesión. Puedes cerrar esta ventana y volver a Nexus.
 err-failed-parse-handshake = Error al analizar respuesta del handshake: { $error }
 err-failed-send-login = Error al enviar inicio de sesión: { $error }
 err-failed-read-login = Error al leer respuesta de inicio de sesión: { $error }
@@ -555,6 +918,7 @@ err-failed-parse-login = Error al analizar respuesta de inicio de sesión: { $er
 err-failed-create-server-name = Error al crear nombre del servidor: { $error }
 err-failed-create-config-dir = Error al crear directorio de configuración: { $error }
 err-failed-serialize-config = Error al serializar configuración: { $error }
+err-config-locked = Los ajustes están cifrados y bloqueados; los cambios no se pueden guardar hasta desbloquearlos
 err-failed-write-config = Error al escribir archivo de configuración: { $error }
 err-failed-read-config-metadata = Error al leer metadatos del archivo de configuración: { $error }
 err-failed-set-config-permissions = Error al establecer permisos del archivo de configuración: { $error }
@@ -564,6 +928,10 @@ err-failed-set-config-permissions = Error al establecer permisos del archivo de
 # =============================================================================
 
 fingerprint-warning = Esto podría indicar un problema de seguridad (ataque MITM) o que el certificado del servidor fue regenerado. Solo acepta si confías en el administrador del servidor.
+fingerprint-new-server-prompt = Esta es la primera conexión a este servidor. Confía en el certificado solo si la huella de abajo coincide con la publicada por el administrador del servidor.
+password-vault-create-description = No hay ningún llavero del sistema disponible. Elige una frase de contraseña maestra para cifrar las contraseñas guardadas de tus marcadores, u omite para mantenerlas en el archivo de configuración.
+password-vault-unlock-description = Introduce tu frase de contraseña maestra para desbloquear las contraseñas guardadas de tus marcadores. Si omites, los marcadores se conectarán sin contraseña hasta que reinicies Nexus.
+config-unlock-description = Tus ajustes, marcadores e historial de chat están cifrados. Introduce tu frase de contraseña maestra para desbloquearlos. Si omites este paso, Nexus se inicia con los ajustes predeterminados y no se guarda nada hasta reiniciarlo.
 
 # =============================================================================
 # User Info Display
@@ -580,10 +948,12 @@ user-info-account = Cuenta: { $username }
 user-info-connected = Conectado:
 user-info-connected-value = hace { $duration }
 user-info-connected-value-sessions = hace { $duration } ({ $count } sesiones)
+user-info-idle = Inactivo:
 user-info-features = Características:
 user-info-features-value = { $features }
 user-info-features-none = Ninguna
 user-info-locale = Idioma:
+user-info-client = Cliente:
 user-info-address = Dirección:
 user-info-addresses = Direcciones:
 user-info-created = Creado:
@@ -592,6 +962,12 @@ user-info-unknown = Desconocido
 user-info-loading = Cargando info del usuario…
 user-info-status = Estado:
 user-info-channels = Canales:
+user-info-rank = Rango:
+user-info-logins = Inicios de sesión:
+user-info-messages = Mensajes:
+user-info-uploads = Subidas:
+user-info-downloaded = Descargado:
+user-info-last-login = Último inicio de sesión:
 
 # =============================================================================
 # Time Duration
@@ -627,24 +1003,46 @@ err-me-no-target = Usa /{ $join } para entrar a un canal o /{ $msg } para enviar
 cmd-help-escape-hint = Consejo: Usa // para enviar un mensaje que comience con /
 cmd-message-desc = Enviar un mensaje a un usuario
 cmd-message-usage = Uso: /{ $command } <usuario> <mensaje>
+cmd-group-desc = Inicia una conversación de grupo con varios usuarios
+cmd-group-usage = Uso: /{ $command } <usuario> [<usuario>...]
+cmd-invite-desc = Añade un usuario a la conversación de grupo actual
+cmd-invite-usage = Uso: /{ $command } <usuario>
 cmd-ping-desc = Medir latencia al servidor
 cmd-ping-usage = Uso: /{ $command }
+cmd-radio-desc = Emitir audio a los oyentes de voz del canal
+cmd-radio-usage = Uso: /{ $command }
+err-radio-no-channel = Este comando solo funciona en un canal
+err-radio-in-voice = Sal de la voz antes de iniciar una emisión
 ping-result = Respuesta: { $ms }ms
 cmd-userinfo-desc = Mostrar información sobre un usuario
 cmd-userinfo-usage = Uso: /{ $command } <usuario>
 cmd-kick-desc = Expulsar a un usuario del servidor
 cmd-kick-usage = Uso: /{ $command } <usuario> [razón]
+cmd-top-desc = Mostrar los usuarios más activos
+cmd-top-usage = Uso: /{ $command } [logins|messages|uploads|downloaded]
 cmd-topic-desc = Ver o gestionar el tema del chat
-cmd-topic-usage = Uso: /{ $command } [set|clear] [tema]
+cmd-topic-usage = Uso: /{ $command } [set|clear|lock|unlock|history] [tema]
 cmd-topic-set-usage = Uso: /{ $command } set <tema>
 cmd-topic-none = No hay tema establecido
 cmd-broadcast-desc = Enviar un mensaje a todos los usuarios
 cmd-broadcast-usage = Uso: /{ $command } <mensaje>
 cmd-clear-desc = Limpiar historial de chat de la pestaña actual
 cmd-clear-usage = Uso: /{ $command }
+cmd-export-desc = Guarda la pestaña actual como archivo HTML
+cmd-export-usage = Uso: /{ $command }
+cmd-clip-desc = Listar, reproducir, añadir o eliminar clips de la mesa de sonidos
+cmd-clip-usage = Uso: /{ $command } [lista|reproducir <nombre>|añadir <nombre>|eliminar <nombre>|<nombre>]
+cmd-clip-arg-list = lista
+cmd-clip-arg-play = reproducir
+cmd-clip-arg-add = añadir
+cmd-clip-arg-delete = eliminar
 cmd-focus-desc = Enfocar chat del servidor o ventana de mensajes de un usuario
 cmd-focus-usage = Uso: /{ $command } [usuario]
 cmd-focus-not-found = Usuario no encontrado: { $name }
+cmd-friend-desc = Listar, añadir o quitar amigos
+cmd-friend-usage = Uso: /{ $command } [list] | add <usuario> | remove <usuario>
+cmd-friend-list-header = Amigos:
+cmd-friend-list-empty = Aún no tienes amigos. Añade uno con /{ $command } add <usuario>
 cmd-list-desc = Mostrar usuarios conectados/todos
 cmd-list-arg-all = todos
 cmd-list-usage = Uso: /{ $command } [todos]
@@ -661,7 +1059,17 @@ cmd-list-all-output = Usuarios: { $users } ({ $count } { $count ->
 cmd-help-usage = Uso: /{ $command } [comando]
 cmd-topic-arg-set = establecer
 cmd-topic-arg-clear = limpiar
+cmd-topic-arg-lock = bloquear
+cmd-topic-arg-unlock = desbloquear
+cmd-topic-arg-history = historial
 cmd-topic-permission-denied = No tienes permiso para editar el tema
+cmd-topic-lock-permission-denied = No tienes permiso para bloquear el tema
+msg-topic-already-locked = El tema ya está bloqueado
+msg-topic-already-unlocked = El tema ya está desbloqueado
+msg-topic-history-header = Historial del tema de { $channel }:
+msg-topic-history-set = { $time } { $username }: { $topic }
+msg-topic-history-cleared = { $time } { $username } limpió el tema
+msg-topic-history-empty = No hay cambios de tema registrados
 cmd-window-desc = Gestionar pestañas de chat
 cmd-window-usage = Uso: /{ $command } [siguiente|anterior|cerrar [usuario]]
 cmd-window-arg-next = siguiente
@@ -689,15 +1097,55 @@ cmd-unban-desc = Eliminar un baneo de IP
 cmd-unban-usage = Uso: /{ $command } <objetivo>
 cmd-bans-desc = Listar baneos activos
 cmd-bans-usage = Uso: /{ $command }
+cmd-register-desc = Registrar una cuenta desde una sesión de invitado
+cmd-register-usage = Uso: /{ $command } <usuario> <contraseña>
 cmd-reindex-desc = Activar reconstrucción del índice de archivos
 cmd-reindex-usage = Uso: /{ $command }
+cmd-cleanup-desc = Buscar restos de transferencias abortadas en el área de archivos
+cmd-cleanup-usage = Uso: /{ $command } [delete]
+cmd-filecheck-desc = Comprobar el área de archivos con el índice de búsqueda y los metadatos de archivos
+cmd-filecheck-usage = Uso: /{ $command } [repair]
+cmd-screenshot-desc = Compartir una captura de pantalla anotada en la pestaña actual
+cmd-screenshot-usage = Uso: /{ $command }
+err-screenshot-no-target = Las capturas solo se pueden compartir en un canal o en una pestaña de mensaje de usuario
+err-screenshot-no-folder = No hay carpeta de capturas: elige «Compartir capturas aquí» en una carpeta de subida en Archivos
+err-screenshot-capture = Error al capturar la pantalla: { $error }
+err-screenshot-save = No se pudo guardar la captura: { $error }
+err-screenshot-upload = Error al subir la captura: { $error }
+err-export-empty = No hay mensajes para exportar en esta pestaña
+err-chat-export = No se pudo exportar el chat: { $error }
+screenshot-title = Compartir captura
+screenshot-tool-crop = Recortar
+screenshot-tool-arrow = Flecha
+screenshot-hint-crop = Arrastra un recuadro alrededor de la parte que quieres conservar
+screenshot-hint-arrow = Arrastra desde la cola hasta la punta de la flecha
 msg-reindex-triggered = Reconstrucción del índice de archivos activada
+msg-cleanup-nothing = No se encontraron restos de transferencias abortadas
+msg-cleanup-found = Restos de transferencias abortadas encontrados:
+msg-cleanup-deleted = Restos de transferencias abortadas eliminados:
+msg-cleanup-parts = Archivos .part huérfanos: { $count } ({ $size })
+msg-cleanup-temp = Archivos temporales sobrantes: { $count } ({ $size })
+msg-cleanup-journal = Entradas del registro de subidas sin archivo: { $count }
+msg-cleanup-index = Entradas del índice de búsqueda sin archivo: { $count }
+msg-cleanup-hint = Revise los archivos anteriores y luego ejecute /cleanup delete para eliminarlos
+msg-filecheck-issue = { $kind }: { $path }
+msg-filecheck-issue-repaired = { $kind }: { $path } (reparado)
+msg-filecheck-clean = La comprobación del área de archivos no encontró problemas en { $entries } entradas
+msg-filecheck-done = La comprobación del área de archivos encontró { $issues } problemas en { $entries } entradas, { $repaired } reparados
+msg-filecheck-index-skipped = No se comparó el índice de búsqueda porque aún no se ha creado o hay una reconstrucción pendiente
+msg-filecheck-hint = Ejecute /filecheck repair para reconstruir el índice y eliminar los metadatos de rutas que faltan
+filecheck-not-indexed = No está en el índice de búsqueda
+filecheck-indexed-but-missing = En el índice de búsqueda pero falta en el disco
+filecheck-index-outdated = Desactualizado en el índice de búsqueda
+filecheck-orphaned-activity = Historial de una ruta que falta
+filecheck-orphaned-reviews = Valoraciones o comentarios de una ruta que falta
+filecheck-orphaned-upload-rule = Regla de subida de una carpeta que falta
 cmd-join-desc = Unirse o crear un canal
 cmd-join-usage = Uso: /{ $command } #canal
 cmd-leave-desc = Abandonar un canal
 cmd-leave-usage = Uso: /{ $command } [#canal]
 cmd-channels-desc = Listar canales disponibles
-cmd-channels-usage = Uso: /{ $command }
+cmd-channels-usage = Uso: /{ $command } [idioma]
 cmd-secret-desc = Alternar modo secreto en el canal actual
 cmd-secret-usage = Uso: /{ $command } [on|off]
 cmd-secret-arg-on = on
@@ -710,12 +1158,40 @@ msg-secret-enabled = El canal ahora es secreto
 msg-secret-disabled = El canal ahora es público
 msg-secret-status-on = Este canal es secreto
 msg-secret-status-off = Este canal es público
+cmd-voicelimit-desc = Ver o establecer el límite de participantes de voz del canal actual
+cmd-voicelimit-usage = Uso: /{ $command } [<cantidad>|off]
+cmd-vt-desc = Enviar un mensaje de texto a tu sesión de voz
+cmd-vt-usage = Uso: /{ $command } <mensaje>
+cmd-voicelimit-arg-off = off
+err-voicelimit-no-channel = Este comando solo funciona en un canal
+msg-voice-limit-status = Este canal permite como máximo { $limit } usuarios en voz
+msg-voice-limit-status-none = Este canal no tiene límite de voz
+msg-voice-clip-none = No hay clips en la mesa de sonidos
+msg-voice-clip-list = Clips de la mesa de sonidos ({ $count }):
+msg-voice-clip-entry = { $name } ({ $seconds } s, de { $nickname })
+msg-voice-clip-uploaded = Clip añadido a la mesa de sonidos
+msg-voice-clip-deleted = Clip eliminado de la mesa de sonidos
 cmd-trust-desc = Confiar en una IP, rango CIDR o apodo para evadir baneos
 cmd-trust-usage = Uso: /{ $command } <objetivo> [duración] [razón]
 cmd-untrust-desc = Eliminar una entrada de IP confiable
 cmd-untrust-usage = Uso: /{ $command } <objetivo>
 cmd-trusted-desc = Listar IPs confiables
 cmd-trusted-usage = Uso: /{ $command }
+cmd-ldap-desc = Listar, establecer o eliminar asignaciones de grupos del directorio
+cmd-ldap-usage = Uso: /{ $command } [list|set <permisos|admin|none> <DN del grupo>|remove <DN del grupo>]
+cmd-nickname-desc = Reservar o liberar apodos para tu cuenta
+cmd-nickname-usage = Uso: /{ $command } [register <apodo> [usuario]|release <apodo>]
+cmd-history-desc = Muestra o define cuánto tiempo guarda el servidor tus mensajes privados, o carga mensajes anteriores
+cmd-history-usage = Uso: /{ $command } [<días>|more]
+cmd-alias-desc = Crear atajos para comandos y mensajes
+cmd-alias-usage = Uso: /{ $command } [list|set <nombre> <texto>|remove <nombre>]
+cmd-alias-list-header = Tus alias:
+cmd-alias-list-empty = Aún no hay alias. Crea uno con /{ $command } set <nombre> <texto>
+cmd-alias-set = /{ $name } ahora ejecuta: { $text }
+cmd-alias-removed = Alias /{ $name } eliminado
+cmd-alias-not-found = No hay ningún alias llamado /{ $name }
+cmd-alias-invalid-name = Los nombres de alias solo pueden usar letras, números, - y _ (hasta { $max } caracteres)
+cmd-alias-builtin = /{ $name } es un comando integrado y no se puede usar como alias
 
 # =============================================================================
 # About Panel
@@ -728,9 +1204,25 @@ err-server-image-invalid-format = Formato de imagen del servidor inválido (debe
 err-server-image-unsupported-type = Tipo de imagen del servidor no compatible (solo PNG, WebP, JPEG o SVG)
 err-server-image-decode-failed = Error al decodificar la imagen. El archivo puede estar corrupto.
 err-failed-read-image = Error al leer la imagen: { $error }
-err-news-empty = La publicación debe tener texto o una imagen
+err-news-empty = La publicación debe tener texto o un adjunto
 err-news-body-too-long = El contenido es demasiado largo ({ $length } caracteres, máximo { $max })
 err-news-body-invalid-characters = El contenido contiene caracteres de control inválidos
+err-news-too-many-attachments = Una publicación puede tener como máximo { $max } adjuntos
+err-news-caption-too-long = La leyenda es demasiado larga (máx. { $max } caracteres)
+err-news-caption-invalid-characters = La leyenda debe ser una sola línea sin caracteres de control
+err-news-file-path-invalid = Ruta de archivo inválida
+err-news-search-date = Fecha no válida: { $date } (usa AAAA-MM-DD)
+err-file-comment-empty = El comentario no puede estar vacío
+err-file-comment-too-long = El comentario es demasiado largo ({ $length } caracteres, máximo { $max })
+err-file-comment-invalid-characters = El comentario contiene caracteres de control no válidos
+err-upload-rule-invalid-extension = Tipo de archivo no válido "{ $extension }" (usa letras y dígitos, como mp3)
+err-auto-download-pattern-required = Introduzca al menos un patrón de nombre de archivo
+err-auto-download-pattern-invalid = Patrón no válido "{ $pattern }" (los patrones se aplican a nombres de archivo y no pueden contener barras)
+err-upload-rule-too-many-extensions = Demasiados tipos de archivo (máx. { $max })
+err-upload-rule-invalid-size = Tamaño no válido "{ $value }" (introduce un número de megabytes)
+err-ldap-group-empty = El DN del grupo no puede estar vacío
+err-ldap-group-too-long = El DN del grupo es demasiado largo (máximo { $max } caracteres)
+err-ldap-group-invalid-characters = El DN del grupo no puede tener espacios alrededor ni caracteres de control
 err-news-image-decode-failed = Error al decodificar la imagen. El archivo puede estar corrupto.
 
 # Proxy errors
@@ -741,9 +1233,31 @@ err-proxy-connection-timeout = Tiempo de espera del proxy agotado después de {
 }
 err-proxy-address-required = Se requiere la dirección del proxy cuando está habilitado
 err-proxy-port-invalid = El puerto del proxy debe estar entre 1 y 65535
-err-news-image-too-large = La imagen es demasiado grande (máximo 512KB)
+err-news-image-too-large = La imagen es demasiado grande (máximo { $max_kb }KB)
 err-news-image-unsupported-type = Tipo de imagen no soportado (solo PNG, WebP, JPEG o SVG)
 err-topic-no-channel = Este comando solo funciona en un canal
+err-entry-no-channel = Este comando solo funciona en un canal
+cmd-welcome-desc = Ver o establecer el mensaje mostrado a quienes se unen al canal
+cmd-welcome-usage = Uso: /{ $command } [establecer <mensaje>|limpiar]
+cmd-welcome-arg-set = establecer
+cmd-welcome-arg-clear = limpiar
+cmd-welcome-none = No hay mensaje de bienvenida
+msg-entry-message-display = Mensaje de bienvenida: { $message }
+msg-entry-message-updated = Mensaje de bienvenida actualizado
+msg-entry-message-cleared = Mensaje de bienvenida eliminado
+cmd-rules-desc = Ver o establecer las reglas del canal
+cmd-rules-usage = Uso: /{ $command } [establecer <reglas>|limpiar]
+cmd-rules-arg-set = establecer
+cmd-rules-arg-clear = limpiar
+cmd-rules-none = No hay reglas establecidas
+msg-rules-header = Reglas de { $channel }:
+cmd-language-desc = Ver o establecer la etiqueta de idioma del canal
+cmd-language-usage = Uso: /{ $command } [establecer <idioma>|limpiar]
+cmd-language-arg-set = establecer
+cmd-language-arg-clear = limpiar
+msg-language-status = Idioma del canal: { $language }
+msg-language-none = No hay idioma establecido
+err-language-invalid = Etiqueta de idioma no válida: { $language } (p. ej. es o pt-BR)
 err-join-channel = Error al unirse al canal: { $error }
 err-leave-channel = Error al abandonar el canal: { $error }
 err-list-channels = Error al listar canales: { $error }
@@ -753,6 +1267,8 @@ err-channel-too-long = El nombre del canal es demasiado largo (máx. { $max } ca
 err-channel-missing-prefix = El nombre del canal debe comenzar con #
 err-channel-invalid-characters = El nombre del canal contiene caracteres inválidos
 err-leave-no-channel = Debes estar en un canal para usar /{ $command } sin argumentos
+err-invite-no-group = Usa /{ $command } en una pestaña de conversación de grupo
+err-group-too-many-members = Una conversación de grupo puede tener como máximo { $max } miembros
 err-not-in-channel = No estás en el canal { $channel }
 err-leave-already-pending = Ya esperando respuesta del servidor
 err-uri-connection-failed = Error al conectar a { $host }: { $error }
@@ -766,6 +1282,30 @@ label-port = Puerto:
 # Transfer statuses
 transfer-status-queued = En cola
 transfer-status-connecting = Conectando…
+transfer-status-server-queued = Esperando un hueco en el servidor (#{ $position })
+transfer-collision-pending = El archivo existe: { $path }
+collision-action-renamed = { $count } { $count ->
+    [one] renombrado
+   *[other] renombrados
+}
+collision-action-overwritten = { $count } { $count ->
+    [one] sobrescrito
+   *[other] sobrescritos
+}
+collision-action-skipped = { $count } { $count ->
+    [one] omitido
+   *[other] omitidos
+}
+transfer-bar-count = { $count } { $count ->
+    [one] transferencia
+   *[other] transferencias
+}
+status-bar-users = { $count } { $count ->
+    [one] usuario
+   *[other] usuarios
+}
+status-bar-latency = { $ms } ms
+status-bar-server-time = Hora del servidor { $time } ({ $zone })
 transfer-status-transferring = Transfiriendo
 transfer-status-paused = Pausado
 transfer-status-completed = Completado
@@ -827,6 +1367,7 @@ event-broadcast = Difusión
 event-chat-message = Mensaje de chat
 event-chat-mention = Mención en chat
 event-connection-lost = Conexión perdida
+event-friend-online = Amigo conectado
 event-news-post = Publicación de noticias
 event-permissions-changed = Permisos cambiados
 event-transfer-complete = Transferencia completada
@@ -861,6 +1402,17 @@ settings-sound-test = Probar
 settings-notification-test = Probar
 event-settings-show-toast = Mostrar Toast
 settings-toast-test = Probar
+settings-do-not-disturb = No molestar
+settings-quiet-hours = Horas de silencio
+settings-do-not-disturb-hint = Silencia notificaciones, avisos y sonidos. Los indicadores de no leídos siguen sumando y los demás ven 🔕 junto a tu nombre.
+settings-notification-rules = Reglas de notificación
+settings-notification-rules-server = Servidor:
+settings-notification-rules-channels = Excepciones por canal
+settings-notification-rules-keywords = Palabras clave vigiladas
+settings-notification-rules-no-bookmarks = Guarda un servidor en marcadores para definir sus reglas de notificación.
+channel-notification-all = Todos los mensajes
+channel-notification-mentions = Solo menciones
+channel-notification-mute = Silenciar
 
 # Sound names
 sound-none = Ninguno
@@ -879,6 +1431,8 @@ notification-chat-message = Nuevo mensaje de chat
 notification-chat-message-from = Chat de {$username}
 notification-connection-lost = Conexión perdida
 notification-connection-lost-from = Desconectado de {$server}
+notification-friend-online = Un amigo se ha conectado
+notification-friend-online-name = {$username} se ha conectado
 notification-download-complete = Descarga completada
 notification-download-failed = Descarga fallida
 notification-news-post = Nueva publicación de noticias
@@ -932,6 +1486,18 @@ ban-duration-7d = 7 días
 ban-duration-30d = 30 días
 ban-duration-permanent = Permanente
 
+# Broadcast target and delay options
+broadcast-target-label = Enviar a:
+broadcast-target-all = Todos
+broadcast-target-channel = Miembros de un canal
+broadcast-target-permission = Usuarios con un permiso
+broadcast-delay-label = Enviar:
+broadcast-delay-now = Ahora
+broadcast-delay-5m = En 5 minutos
+broadcast-delay-15m = En 15 minutos
+broadcast-delay-1h = En 1 hora
+broadcast-delay-1d = En 1 día
+
 # =============================================================================
 # Ban System
 # =============================================================================
@@ -981,12 +1547,32 @@ msg-untrusted-success = Entrada de confianza eliminada exitosamente
 # Trust list messages
 msg-trust-list-header = IPs confiables:
 msg-trust-list-empty = No hay IPs confiables
+msg-ldap-group-list-header = Grupos del directorio:
+msg-ldap-group-list-empty = No hay grupos del directorio asignados
+msg-ldap-group-admin = administrador
+msg-ldap-group-no-permissions = sin permisos
+msg-ldap-group-set = Grupo del directorio { $group } asignado
+msg-ldap-group-removed = Grupo del directorio { $group } eliminado
+msg-guest-upgraded = Cuenta { $username } creada. Inicia sesión con ella la próxima vez para usarla.
+msg-nickname-registered = El apodo { $nickname } queda reservado para { $username }.
+msg-nickname-released = Apodo { $nickname } liberado.
+msg-history-days = El servidor guarda tus mensajes privados durante { $days } días.
+msg-history-off = El servidor no guarda tus mensajes privados. Usa /history <días> para activarlo.
+msg-history-no-older = No hay mensajes anteriores con { $nickname }.
+err-history-days-too-long = El historial de mensajes se puede conservar como máximo { $max } días
+err-history-more-tab = Usa /history more en una pestaña de mensajes privados
 msg-trust-permanent = permanente
 msg-trust-expired = expirado
 msg-trust-remaining = { $time } restante
 msg-trust-remaining-days = { $days }d { $hours }h
 msg-trust-remaining-hours = { $hours }h { $minutes }m
 msg-trust-remaining-minutes = { $minutes }m
+msg-leaderboard-header = Usuarios más activos por { $stat }:
+msg-leaderboard-empty = Aún no hay actividad registrada
+leaderboard-stat-logins = inicios de sesión
+leaderboard-stat-messages = mensajes
+leaderboard-stat-uploads = subidas
+leaderboard-stat-downloaded = datos descargados
 
 # =============================================================================
 # Voice Chat
@@ -995,6 +1581,16 @@ msg-trust-remaining-minutes = { $minutes }m
 # Voice error messages
 err-voice-join = Error al unirse al chat de voz: { $error }
 err-voice-leave = Error al salir del chat de voz: { $error }
+err-voice-limit = No se pudo establecer el límite de voz: { $error }
+err-voice-clip = Mesa de sonidos: { $error }
+err-voice-text = Texto de voz: { $error }
+err-voice-clip-name-empty = El nombre del clip no puede estar vacío
+err-voice-clip-name-too-long = El nombre del clip es demasiado largo (máx. { $max } caracteres)
+err-voice-clip-name-invalid = Los nombres de clip solo pueden contener letras, dígitos, - y _
+err-voice-clip-too-large = El clip es demasiado grande (máx. 256 KB)
+err-voice-clip-unsupported-type = Formato de clip no compatible (solo Ogg Vorbis)
+msg-voice-queued = La voz está llena: eres el número { $position } en la cola
+msg-voice-queue-cancelled = Has sido eliminado de la cola de voz
 err-voice-no-token = La conexión al chat de voz fue exitosa pero no se recibió un token
 err-voice-already-active = Ya estás en una sesión de voz en otro servidor
 err-voice-no-permission = No tienes permiso para unirte al chat de voz
@@ -1020,7 +1616,12 @@ msg-voice-joined-empty = Te uniste al chat de voz en { $target }
 msg-voice-left = Saliste del chat de voz en { $target }
 msg-voice-user-joined = { $nickname } se unió al chat de voz
 msg-voice-user-left = { $nickname } salió del chat de voz
+msg-voice-broadcast-started = { $nickname } está emitiendo en la voz - únete para escuchar
+msg-voice-broadcast-ended = La emisión de voz ha terminado
+msg-voice-clip-played = { $nickname } reprodujo { $name }
+msg-voice-text = [voz] { $nickname }: { $message }
 msg-voice-you-left = Has salido del chat de voz
+msg-voice-tcp-relay = La conexión UDP de voz falló ({ $error }) - la voz se transmite a través de la conexión con el servidor
 
 # Voice UI
 voice-join-tooltip = Unirse al chat de voz
@@ -1028,6 +1629,8 @@ voice-leave-tooltip = Salir del chat de voz
 voice-mute-all-tooltip = Silenciar todo
 voice-unmute-all-tooltip = Activar sonido
 voice-bar-participants = ({ $count })
+voice-bar-queued = (en cola: { $position })
+voice-bar-listeners = ({ $count } escuchando)
 voice-indicator-tooltip = En chat de voz
 tooltip-mute = Silenciar
 tooltip-unmute = Activar sonido
@@ -1038,6 +1641,12 @@ voice-quality-medium = Media (32 kbps)
 voice-quality-high = Alta (64 kbps)
 voice-quality-very-high = Muy alta (96 kbps)
 
+# Voice connection quality
+voice-connection-good = Buena
+voice-connection-fair = Regular
+voice-connection-poor = Mala
+voice-connection-tooltip = Conexión: { $quality } ({ $loss }% de pérdida, { $jitter } ms de jitter)
+
 # PTT modes
 ptt-mode-hold = Mantener para hablar
 ptt-mode-toggle = Alternar
@@ -1052,6 +1661,8 @@ ptt-delay-500ms = 500ms
 audio-output-device = Dispositivo de salida
 audio-input-device = Dispositivo de entrada
 audio-refresh-devices = Actualizar dispositivos
+audio-clip-volume = Volumen de la mesa de sonidos
+audio-voice-text-tts = Leer en voz alta los mensajes de texto de voz
 audio-voice-quality = Calidad de voz
 audio-ptt-key = Tecla Push-to-Talk
 audio-ptt-mode = Modo PTT
@@ -1078,6 +1689,44 @@ mic-boost-6db = +6 dB
 mic-boost-12db = +12 dB
 mic-boost-18db = +18 dB
 
+# =============================================================================
+# Global Hotkeys
+# =============================================================================
+
+# Settings
+settings-toggle-window-key = Tecla para mostrar/ocultar ventana
+button-clear-hotkey = Quitar
+
+# Shortcut descriptions (shown by the desktop on Wayland)
+hotkey-push-to-talk = Pulsar para hablar
+hotkey-toggle-window = Mostrar u ocultar Nexus
+
+# Errors
+err-window-hotkey-failed = No se pudo registrar la tecla para mostrar/ocultar la ventana: { $error }
+err-global-shortcuts-unavailable = El portal de atajos globales no está disponible, las teclas solo funcionan con Nexus enfocado: { $error }
+
+# =============================================================================
+# Auto Away
+# =============================================================================
+
+settings-auto-away = Ausente automáticamente al estar inactivo
+settings-auto-away-minutes = Minutos de inactividad
+
+# =============================================================================
+# Connection Health
+# =============================================================================
+
+# Server list indicator
+connection-health-good = Buena
+connection-health-fair = Regular
+connection-health-poor = Mala
+connection-health-tooltip = Conexión: { $quality }
+connection-health-latency = Latencia: { $ms } ms
+connection-health-jitter = Jitter: { $ms } ms
+connection-health-last-drop = Último ping perdido: { $time }
+connection-health-no-drops = Ningún ping perdido
+connection-health-measuring = Midiendo latencia…
+
 # =============================================================================
 # System Tray (Windows/Linux only)
 # =============================================================================
@@ -1106,14 +1755,33 @@ tray-tooltip-unread = Nexus BBS - { $count ->
    *[other] { $count } conversaciones sin leer
 }
 
+# =============================================================================
+# Notification Actions (macOS/Windows only)
+# =============================================================================
+
+# Actions
+notification-reply = Responder
+notification-reply-send = Enviar
+notification-reply-placeholder = Responder…
+notification-mark-read = Marcar como leído
+
 # =============================================================================
 # Toast Notifications
 # =============================================================================
 
 toast-link-copied = Enlace copiado al portapapeles
 toast-copied = Copiado al portapapeles
+toast-checksum-copied = SHA-256 copiado al portapapeles
 toast-download-started = Descargando { $filename }
 toast-download-queued = Descarga en cola: { $filename }
+toast-downloads-started = Descargando { $count } { $count ->
+    [one] archivo
+   *[other] archivos
+}
+toast-downloads-queued = { $count } { $count ->
+    [one] descarga
+   *[other] descargas
+} en cola
 toast-upload-started = Subiendo { $filename }
 toast-upload-queued = Subida en cola: { $filename }
 toast-uploads-started = Subiendo { $count } { $count ->
@@ -1124,3 +1792,28 @@ toast-uploads-queued = { $count } { $count ->
     [one] subida
    *[other] subidas
 } en cola
+toast-upload-nothing-new = Nada que subir: el servidor ya tiene estos archivos
+toast-upload-watch-started = Vigilando { $folder }: los archivos nuevos se subirán automáticamente
+toast-upload-watch-stopped = Se dejó de vigilar { $folder }
+toast-auto-download-started = Descargando automáticamente { $pattern } de { $folder }
+toast-auto-download-stopped = Se detuvo la descarga automática de { $folder }
+toast-screenshot-folder-set = Las capturas se subirán a esta carpeta
+toast-chat-exported = Chat exportado
+
+# =============================================================================
+# First-run Tips
+# =============================================================================
+
+tip-welcome-title = Bienvenido a Nexus
+tip-welcome = Introduce abajo la dirección de un servidor y conéctate. Añade un marcador para guardar el servidor, con tu inicio de sesión, en la lista de la izquierda y tenerlo a un clic la próxima vez.
+tip-fingerprint = Cada servidor tiene una huella de certificado, como una firma. Nexus la recuerda la primera vez que te conectas y te avisa si cambia, porque eso puede significar que alguien se hace pasar por el servidor. Acepta una huella nueva solo si el administrador del servidor te dijo que cambió.
+tip-server-list = Tus marcadores y conexiones abiertas están a la izquierda. Haz clic en uno para cambiar de servidor o arrastra los marcadores para reordenarlos.
+tip-toolbar = La barra de herramientas abre archivos, noticias, ajustes y más. Pasa el ratón sobre un icono para ver qué hace.
+tip-chat = Aquí chateas con todos en el servidor. Escribe / para ver los comandos que puedes usar, como /join para entrar en un canal.
+tip-user-list = Los usuarios conectados ahora aparecen a la derecha. Haz clic en un nombre para enviarle un mensaje o ver su información.
+tip-counter = Consejo { $current } de { $total }
+button-add-bookmark = Añadir marcador
+button-tip-next = Siguiente
+button-tip-done = Hecho
+button-tip-got-it = Entendido
+button-skip-tips = Omitir consejos
@@ -11,6 +11,13 @@ button-connect = Connecter
 button-save = Enregistrer
 button-create = Créer
 button-edit = Modifier
+button-check-ports = Tester les ports
+button-statistics = Statistiques
+button-dashboard = Tableau de bord
+button-refresh = Actualiser
+button-doors = Portes
+button-doors-leave = Quitter
+button-doors-back = Retour
 button-update = Mettre à jour
 
 button-accept-new-certificate = Accepter le Nouveau Certificat
@@ -19,9 +26,23 @@ button-choose-avatar = Choisir une Icône
 button-clear-avatar = Effacer
 button-choose-image = Choisir une Image
 button-clear-image = Effacer
+button-add-file = Ajouter un fichier
 button-overwrite = Écraser
 button-change-password = Changer le Mot de Passe
 button-browse = Parcourir
+button-undo = Défaire
+button-add = Ajouter
+button-remove = Retirer
+button-view = Afficher
+button-hide = Masquer
+button-export = Exporter…
+button-trust-certificate = Faire confiance au certificat
+button-skip = Ignorer
+button-skip-existing = Ignorer les existants
+button-download-anyway = Télécharger quand même
+button-unlock = Déverrouiller
+button-view-as-user = Voir en tant qu'utilisateur
+button-post-comment = Publier
 
 # =============================================================================
 # Titles
@@ -36,19 +57,29 @@ title-user-create = Créer un utilisateur
 title-user-edit = Modifier l'utilisateur
 title-update-user = Mettre à jour l'utilisateur
 title-user-management = Gérer les Utilisateurs
+title-permission-audit = Audit des Permissions
+title-impersonate = Vue en tant que { $username }
 title-confirm-delete = Confirmer la Suppression
 title-connected = Connectés
 title-settings = Paramètres
 title-bookmarks = Signets
 title-users = Utilisateurs
+title-friends = Amis
+friend-online = en ligne
+friend-offline = hors ligne
 title-edit-server-info = Modifier les infos serveur
 title-fingerprint-mismatch = Empreinte du certificat non concordante !
+title-fingerprint-new-server = Nouveau certificat de serveur
+title-create-password-vault = Protéger les mots de passe enregistrés
+title-unlock-password-vault = Déverrouiller les mots de passe enregistrés
+title-unlock-config = Déverrouiller les paramètres
 title-server-info = Infos Serveur
 title-user-info = Infos Utilisateur
 title-about = À propos
 title-change-password = Changer le Mot de Passe
 title-news = Actualités
 title-transfers = Transferts
+title-transfer-history = Historique des transferts
 title-news-create = Créer une Publication
 title-news-edit = Modifier la Publication
 title-channel-members = Membres
@@ -59,10 +90,14 @@ title-channel-members = Membres
 
 placeholder-username = Nom d'utilisateur
 placeholder-password = Mot de passe
+placeholder-master-passphrase = Phrase secrète principale
+placeholder-confirm-master-passphrase = Confirmer la phrase secrète principale
 placeholder-port = Port
 placeholder-server-address = Adresse du serveur
 placeholder-server-name = Nom du serveur
+placeholder-folder-name = Nom du dossier
 placeholder-username-optional = Nom d'utilisateur (optionnel)
+placeholder-fallback-addresses-optional = Adresses de secours (optionnel, séparées par des virgules)
 placeholder-password-optional = Mot de passe (optionnel)
 placeholder-password-keep-current = Mot de passe
 placeholder-current-password = Mot de Passe Actuel
@@ -71,10 +106,18 @@ placeholder-confirm-password = Confirmer le Mot de Passe
 placeholder-message = Tapez un message…
 placeholder-no-permission = Pas de permission
 placeholder-broadcast-message = Message de diffusion
+placeholder-broadcast-channel = Choisir un canal
+placeholder-broadcast-permission = Choisir une permission
 placeholder-server-description = Description du serveur
 placeholder-news-body = Contenu de l'article
+placeholder-news-caption = Légende (facultatif)
+placeholder-news-file-path = Chemin dans la zone de fichiers
 placeholder-nickname-optional = Pseudo (optionnel)
+placeholder-status-optional = Statut (optionnel)
+placeholder-download-folder-optional = Dossier de téléchargement (optionnel)
 placeholder-proxy-address = Adresse du Proxy
+placeholder-notification-rules-channel = #salon
+placeholder-notification-rules-keyword = Mot-clé
 placeholder-proxy-port = 9050
 placeholder-proxy-username = Nom d'utilisateur (optionnel)
 placeholder-proxy-password = Mot de passe (optionnel)
@@ -87,13 +130,20 @@ placeholder-auto-join-channels = #canal1 #canal2 (séparés par des espaces)
 # =============================================================================
 
 label-auto-connect = Connexion auto
+label-server-avatar = Avatar :
 label-add-bookmark = Ajouter un favori
+label-lan-servers = Sur ce réseau
+label-use-sso = Authentification unique
 label-admin = Administrateur
 label-shared-account = Compte Partagé
 label-enabled = Activé
+label-erase-personal-data = Effacer aussi les données personnelles
+label-broadcast-require-ack = Demander une confirmation aux destinataires
 label-permissions = Permissions :
 label-expected-fingerprint = Empreinte attendue :
 label-received-fingerprint = Empreinte reçue :
+label-certificate-fingerprint = Empreinte du certificat :
+label-identity-key = Clé d'identité :
 label-theme = Thème
 label-chat-history-retention = Historique de chat :
 chat-history-forever = Toujours
@@ -101,6 +151,10 @@ chat-history-30-days = 30 jours
 chat-history-14-days = 14 jours
 chat-history-7-days = 7 jours
 chat-history-disabled = Désactivé
+collision-policy-rename = Conserver les deux (renommer)
+collision-policy-overwrite = Écraser
+collision-policy-skip = Ignorer
+collision-policy-ask = Demander
 label-max-scrollback = Historique max :
 label-chat-font-size = Taille de police :
 label-show-connection-events = Afficher les événements de connexion/déconnexion
@@ -108,6 +162,19 @@ label-show-channel-events = Afficher les événements d’entrée/sortie
 label-show-timestamps = Afficher les horodatages
 label-use-24-hour-time = Utiliser le format 24 heures
 label-show-seconds = Afficher les secondes
+label-split-view = Afficher les fichiers, les actualités et le moniteur de connexions à côté du chat
+label-spell-check = Vérifier l'orthographe dans le chat et les actualités
+label-spell-check-no-dictionary = Aucun dictionnaire n'a été trouvé pour votre langue. Placez ses fichiers .aff et .dic dans le dossier dictionaries à côté de config.json.
+label-send-read-receipts = Envoyer des accusés de lecture pour les messages privés
+label-user-list-grouping = Regroupement de la liste :
+label-user-list-sort = Tri de la liste :
+user-list-group-none = Aucun
+user-list-group-admins = Admins d'abord
+user-list-group-channel = Par canal
+user-list-group-status = Par statut
+user-list-sort-alphabetical = Alphabétique
+user-list-sort-idle = Temps d'inactivité
+user-list-sort-connect = Heure de connexion
 label-server-name = Nom :
 label-server-description = Description :
 label-server-version = Version :
@@ -141,6 +208,27 @@ tab-files = Fichiers
 tab-channels = Canaux
 label-connections-short = Connexions:
 label-transfers-short = Transferts:
+label-active-transfers-short = Actifs:
+label-queue-order-short = Ordre de file:
+label-trusted-weight-short = Poids de confiance:
+label-nat-mapping-short = Redirection de ports:
+label-nat-external-ip-short = IP externe:
+label-nat-not-mapped = Non redirigé
+connectivity-check-running = Test des ports…
+check-service-bbs = BBS
+check-service-transfer = Transferts
+check-service-websocket = WebSocket
+check-service-transfer-websocket = Transferts WebSocket
+check-service-voice = Voix
+check-state-open = Ouvert
+check-state-closed = Fermé
+check-state-filtered = Filtré
+check-state-untested = Non testé
+label-check-port = { $service } ({ $protocol } { $port }):
+label-queue-order-small-first = Petits fichiers d'abord
+label-queue-order-arrival = Ordre d'arrivée
+label-small-files-first = Placer les petits fichiers en premier
+label-unlimited = Illimité
 label-reindex-short = Réindexer:
 label-persistent-short = Persistants:
 label-auto-join-short = Auto-rejoindre:
@@ -157,6 +245,19 @@ label-download-location = Emplacement de téléchargement:
 label-queue-transfers = Mettre les transferts en file d'attente
 label-download-limit = Limite de téléchargements:
 label-upload-limit = Limite d'envois:
+label-auto-open-folder = Ouvrir le dossier à la fin d'un téléchargement
+label-collision-policy = Si le fichier existe:
+label-require-fingerprint-approval = Demander avant de faire confiance au certificat d'un nouveau serveur
+label-known-servers = Serveurs connus
+known-servers-empty = Aucun certificat de serveur enregistré
+known-server-first-seen = Vu pour la première fois le { $date }
+known-server-first-seen-unknown = Vu pour la première fois : inconnu
+label-encrypt-config = Chiffrer les paramètres, les favoris et l'historique de discussion avec une phrase secrète principale
+config-encryption-locked-note = Les paramètres sont verrouillés. Redémarrez Nexus et saisissez la phrase secrète principale pour modifier ceci.
+label-upload-rule-extensions = Types de fichiers autorisés :
+label-upload-rule-max-file-size = Plus gros fichier (Mo) :
+label-upload-rule-max-total-size = Plus gros envoi (Mo) :
+label-auto-download-pattern = Noms de fichiers :
 label-persistent-channels = Canaux Persistants:
 label-auto-join-channels = Canaux Auto-Rejoindre:
 label-channels = Canaux
@@ -170,11 +271,14 @@ permission-user_info = Info Utilisateur
 permission-chat_send = Envoyer Chat
 permission-chat_receive = Recevoir Chat
 permission-chat_join = Rejoindre Chat
+permission-chat_language = Langue du chat
 permission-chat_create = Créer un Canal
 permission-chat_list = Liste des Canaux
 permission-chat_secret = Canal Secret
 permission-chat_topic = Sujet du Chat
 permission-chat_topic_edit = Modifier Sujet du Chat
+permission-chat_topic_lock = Verrouiller Sujet du Chat
+permission-chat_entry_edit = Modifier Accueil du Chat
 permission-user_broadcast = Diffusion Utilisateur
 permission-user_create = Créer Utilisateur
 permission-user_delete = Supprimer Utilisateur
@@ -185,6 +289,7 @@ permission-news_list = Liste des Actualités
 permission-news_create = Créer Actualité
 permission-news_edit = Modifier Actualité
 permission-news_delete = Supprimer Actualité
+permission-file_comment = Commenter les fichiers
 permission-file_copy = Copier Fichier
 permission-file_create_dir = Répertoire Créer
 permission-file_delete = Supprimer Fichier
@@ -204,6 +309,11 @@ permission-trust_create = Créer Confiance
 permission-trust_delete = Supprimer Confiance
 permission-trust_list = Liste de Confiance
 permission-connection_monitor = Moniteur de Connexions
+permission-log_view = Voir le journal
+permission-door_play = Jouer aux portes
+permission-voice_broadcast = Diffusion Vocale
+permission-voice_clip_upload = Téléverser des Extraits Vocaux
+permission-voice_limit = Limite Vocale
 permission-voice_listen = Écoute Vocale
 permission-voice_talk = Parler en Vocal
 
@@ -228,19 +338,31 @@ tooltip-files-paste = Coller
 tooltip-files-search = Rechercher des fichiers
 tooltip-manage-users = Gérer les Utilisateurs
 tooltip-connection-monitor = Moniteur de Connexions
+tooltip-server-log = Journal du serveur
 tooltip-server-info = Info Serveur
 tooltip-about = À propos
 tooltip-transfers = Transferts
 tooltip-download-all = Tout Télécharger
 tooltip-upload = Téléverser
 file-picker-upload-title = Sélectionner les fichiers à téléverser
+file-picker-download-to-title = Sélectionner le dossier de téléchargement
+folder-picker-upload-watch-title = Sélectionnez le dossier à téléverser automatiquement
 drop-to-upload = Déposer pour téléverser
 tooltip-clear-inactive = Effacer Inactifs
+tooltip-transfer-history = Historique des transferts
+tooltip-transfer-history-clear = Effacer l'historique
+tooltip-transfer-history-remove = Retirer de l'historique
 tooltip-transfer-pause = Pause
 tooltip-transfer-resume = Reprendre
 tooltip-transfer-cancel = Annuler
 tooltip-transfer-remove = Supprimer
 tooltip-transfer-open-folder = Ouvrir le Dossier
+tooltip-transfer-open-file = Ouvrir le Fichier
+tooltip-transfer-reveal = Afficher dans le Gestionnaire de Fichiers
+tooltip-transfer-copy-checksum = Copier le SHA-256
+tooltip-transfer-collision-rename = Conserver les deux (renommer)
+tooltip-transfer-collision-overwrite = Écraser les fichiers existants
+tooltip-transfer-collision-skip = Ignorer les fichiers existants
 tooltip-transfer-move-up = Monter
 tooltip-transfer-move-down = Descendre
 tooltip-transfer-retry = Réessayer
@@ -253,16 +375,34 @@ tooltip-disconnect = Déconnecter
 tooltip-edit = Modifier
 tooltip-info = Info
 tooltip-message = Message
+tooltip-add-friend = Ajouter un ami
+tooltip-remove-friend = Retirer l'ami
 tooltip-kick = Expulser
 tooltip-close = Fermer
+tooltip-retry-send = Réessayer l'envoi
+tooltip-cancel-send = Annuler l'envoi
+tooltip-message-sent = Envoyé
+tooltip-message-delivered = Distribué
+tooltip-message-read = Lu
 tooltip-new-tab = Nouvel Onglet
 tooltip-close-tab = Fermer l'Onglet
 tooltip-add-bookmark = Ajouter un signet
+tooltip-new-folder = Nouveau dossier
 tooltip-create-user = Créer un Utilisateur
+tooltip-permission-audit = Auditer les Permissions
 tooltip-create-news = Créer une Publication
+tooltip-news-search = Rechercher des actualités
+tooltip-news-search-clear = Effacer la recherche
 tooltip-delete = Supprimer
 context-menu-download = Télécharger
+context-menu-download-to = Télécharger vers…
+context-menu-download-select = Télécharger une sélection…
+context-menu-auto-download = Téléchargement automatique…
 context-menu-upload = Téléverser
+context-menu-watch-folder = Surveiller un dossier local…
+context-menu-screenshot-folder = Partager les captures ici
+context-menu-unwatch-folder = Arrêter la surveillance du dossier
+context-menu-upload-rules = Règles de téléversement…
 context-menu-open = Ouvrir
 
 # =============================================================================
@@ -270,6 +410,7 @@ context-menu-open = Ouvrir
 # =============================================================================
 
 panel-connection-monitor = Moniteur de Connexions
+panel-server-log = Journal du serveur
 panel-active-connections = Connexions Actives: { $count }
 tab-connections = Connexions
 tab-transfers = Transferts
@@ -284,9 +425,97 @@ menu-info = Infos
 menu-copy = Copier
 menu-kick = Expulser
 menu-ban = Bannir
+menu-pop-out = Détacher
+menu-mute-tab = Mettre en sourdine
+menu-unmute-tab = Réactiver le son
+menu-connect-all = Tout connecter
+menu-rename = Renommer
+menu-delete = Supprimer
 connection-monitor-loading = Chargement des connexions…
 connection-monitor-no-connections = Aucune connexion
 connection-monitor-no-transfers = Aucun transfert actif
+connection-monitor-queued = En file
+connection-monitor-weight = Poids de partage équitable: ×{ $weight }
+connection-monitor-listed = Listée dans { $zone }
+connection-monitor-queued-count = { $count } en file
+placeholder-server-log-module = Filtrer par module
+server-log-loading = Chargement du journal du serveur…
+server-log-empty = Aucune ligne de journal
+server-log-level-error = Erreurs uniquement
+server-log-level-warn = Avertissements et plus
+server-log-level-info = Info et plus
+server-log-level-debug = Tout (débogage)
+panel-statistics = Statistiques
+stats-loading = Chargement des statistiques…
+panel-snippet = Extrait
+snippet-loading = Chargement de l'extrait…
+snippet-details = Partagé par { $author } · expire le { $expires }
+stats-range-days = { $days } derniers jours
+stats-chart-max = Jour le plus élevé : { $value }
+stats-metric-logins = Connexions
+stats-metric-messages = Messages
+stats-metric-uploads = Envois
+stats-metric-upload-bytes = Données envoyées
+stats-metric-downloads = Téléchargements
+stats-metric-download-bytes = Données téléchargées
+stats-metric-peak-sessions = Pic d'utilisateurs en ligne
+panel-admin-dashboard = Tableau de bord admin
+dashboard-loading = Chargement du tableau de bord…
+dashboard-connections = Connexions
+dashboard-sessions = Utilisateurs en ligne
+dashboard-connection-count = Connexions
+dashboard-transfer-connections = Connexions de transfert
+dashboard-transfers = Transferts
+dashboard-active-transfers = En cours
+dashboard-queued-transfers = En attente
+dashboard-today = Aujourd'hui
+dashboard-disk = Zone de fichiers
+dashboard-disk-free = Espace libre
+dashboard-disk-free-of = { $free } libres sur { $total }
+dashboard-disk-unknown = Inconnu
+dashboard-recent-audit = Événements d'audit récents
+dashboard-audit-empty = Aucun événement d'audit pour l'instant
+dashboard-updated = Mis à jour à { $time }
+panel-doors = Portes
+doors-loading = Chargement des portes…
+doors-empty = Aucune porte sur ce serveur
+doors-starting = Démarrage…
+doors-playing-hint = Les touches vont à la porte. Utilisez Quitter pour sortir.
+doors-exited = La porte s'est terminée
+doors-exited-code = La porte s'est terminée avec le code { $code }
+
+# =============================================================================
+# Quick Switcher
+# =============================================================================
+
+quick-switcher-placeholder = Aller à un salon, message, signet ou panneau...
+user-list-filter-placeholder = Filtrer les utilisateurs...
+user-list-section-admins = Admins
+user-list-section-users = Utilisateurs
+user-list-section-no-channel = Autres
+user-list-section-online = En ligne
+user-list-section-away = Absent
+user-list-section-dnd = Ne pas déranger
+user-list-idle = Inactif depuis { $duration }
+quick-switcher-no-matches = Aucun résultat
+quick-switcher-bookmark = Signet
+
+# =============================================================================
+# Chat Input
+# =============================================================================
+
+chat-paste-prompt = Coller { $count } lignes ?
+chat-paste-too-long-prompt = Trop long pour un seul message (max { $max }). L'envoyer quand même ?
+button-send-as-messages = Envoyer en { $count } messages
+button-send-as-one-message = Envoyer en un seul message
+button-send-in-parts = Envoyer en { $count } parties
+button-share-as-snippet = Partager comme extrait
+button-expand-code = Afficher les { $count } lignes
+button-collapse-code = Réduire
+chat-broadcast-ack-prompt = { $username } vous demande de confirmer sa diffusion
+button-acknowledge = Confirmer
+button-dismiss = Ignorer
+spell-no-suggestions = Aucune suggestion
 
 # =============================================================================
 # Empty States
@@ -297,11 +526,35 @@ empty-no-connections = Aucune connexion
 empty-no-bookmarks = Aucun favori
 empty-no-users = Aucun utilisateur connecté
 empty-no-channel-members = Aucun membre dans le canal
+empty-no-matching-users = Aucun utilisateur correspondant
 user-management-loading = Chargement des utilisateurs…
 user-management-no-users = Aucun utilisateur trouvé
+permission-audit-hint = Choisissez une permission pour voir qui la détient, ou cliquez sur un utilisateur pour voir tout ce qu'il peut faire.
+permission-audit-loading = Chargement…
+permission-audit-no-holders = Aucun compte ne détient cette permission
+permission-audit-effective = Permissions effectives de { $username }
+permission-audit-admin = Les administrateurs détiennent toutes les permissions
+permission-audit-no-permissions = Ce compte n'a aucune permission
+permission-audit-restricted = Accordées mais indisponibles pour les comptes partagés :
+permission-audit-disabled = Désactivé
+impersonate-notice = Vue en lecture seule de ce que voit ce compte. Chaque consultation est enregistrée dans le journal d'audit.
+impersonate-permissions = Permissions
+impersonate-files = Fichiers ({ $path })
+impersonate-no-file-access = Ce compte ne peut pas parcourir les fichiers
+impersonate-empty-folder = Ce dossier est vide
+impersonate-channels = Canaux
+impersonate-no-channel-access = Ce compte ne peut pas lister les canaux
+impersonate-no-channels = Aucun canal visible
+impersonate-secret = Secret
 news-loading = Chargement des actualités…
 news-no-posts = Aucune publication pour le moment
+news-search-placeholder = Rechercher des actualités… (author:nom since:AAAA-MM-JJ until:AAAA-MM-JJ)
+news-searching = Recherche en cours…
+news-search-no-results = Aucune publication correspondante
 transfers-empty = Aucun transfert
+transfer-history-search-placeholder = Rechercher par nom, serveur ou dossier…
+transfer-history-empty = Aucun transfert terminé pour le moment
+transfer-history-no-matches = Aucun transfert ne correspond à votre recherche
 news-updated = Modifié
 files-loading = Chargement des fichiers…
 files-empty = Ce répertoire est vide
@@ -313,10 +566,19 @@ files-column-name = Nom
 files-column-size = Taille
 files-column-modified = Modifié
 files-column-path = Chemin
+files-column-rating = Note
 files-search-placeholder = Rechercher des fichiers…
 files-search-breadcrumb = Recherche - { $query }
 files-searching = Recherche en cours…
 files-no-results = Aucun fichier trouvé
+files-highlights = À la une
+files-highlights-recent = Nouveaux envois cette semaine
+files-highlights-popular = Les plus téléchargés
+files-highlights-empty = Rien pour le moment
+files-highlights-downloads = { $count } { $count ->
+    [one] téléchargement
+   *[other] téléchargements
+}
 files-search-query-too-short = La requête de recherche est trop courte (min. { $min_length } caractères)
 files-search-query-too-long = La requête de recherche est trop longue (max. { $max_length } caractères)
 files-search-query-invalid = La requête de recherche contient des caractères non valides
@@ -327,6 +589,30 @@ files-share = Partager
 files-rename = Renommer
 files-rename-title = Renommer « { $name } »
 files-rename-placeholder = Nouveau nom
+files-upload-rule-title = Règles de téléversement de « { $name } »
+files-upload-rule-loading = Chargement…
+files-upload-rule-extensions-placeholder = Tout type (ex. mp3, flac)
+files-upload-rule-no-limit = Aucune limite
+files-upload-rule-remove = Supprimer la règle
+files-upload-rule-help = Les règles s'appliquent à ce dossier et à ses sous-dossiers, sauf si un sous-dossier a les siennes.
+files-auto-download-title = Téléchargement automatique depuis « { $name } »
+files-auto-download-pattern-placeholder = p. ex. *.mp3, *.m4a
+files-auto-download-remove = Arrêter le téléchargement automatique
+files-auto-download-help = Les nouveaux fichiers de ce dossier dont le nom correspond sont téléchargés tant que vous êtes connecté via ce favori. Utilisez * pour n'importe quel texte et ? pour un seul caractère, et séparez les motifs par des virgules. Les fichiers déjà téléchargés sont ignorés.
+files-upload-rule-hint = Téléversements ici : { $limits }
+files-upload-rule-hint-types = { $extensions } uniquement
+files-upload-rule-hint-file-size = jusqu'à { $size } par fichier
+files-upload-rule-hint-total-size = jusqu'à { $size } par envoi
+files-upload-preview-title = Les fichiers existent déjà
+files-upload-preview-comparing = Comparaison avec les fichiers du serveur…
+files-upload-preview-summary = { $new } nouveaux, { $identical } déjà sur le serveur, { $conflicts } différents :
+files-upload-preview-blocked = { $count } { $count ->
+    [one] fichier a
+   *[other] fichiers ont
+} un dossier du même nom sur le serveur et { $count ->
+    [one] sera ignoré
+   *[other] seront ignorés
+}
 files-cut = Couper
 files-copy = Copier
 files-paste = Coller
@@ -336,6 +622,13 @@ files-delete-confirm-title = Confirmer la suppression
 files-delete-confirm-message = Êtes-vous sûr de vouloir supprimer "{ $name }" ?
 # File Overwrite Dialog
 files-overwrite-title = Le fichier existe déjà
+files-download-to-title = Télécharger { $name }
+label-remember-for-server = Mémoriser pour ce serveur
+label-select-all = Tout sélectionner
+files-download-select-summary = { $count } sur { $total } sélectionnés ({ $size })
+files-download-select-too-many = Vous pouvez sélectionner au maximum { $max } fichiers individuels. Téléchargez plutôt le dossier entier.
+files-download-duplicate-title = Déjà téléchargé
+files-download-duplicate-message = « { $name } » est déjà présent sur cet ordinateur dans { $path }. Voulez-vous le télécharger à nouveau ?
 files-overwrite-message = Un fichier ou dossier nommé « { $name } » existe déjà à la destination. Voulez-vous le remplacer ?
 
 # File Info Dialog
@@ -351,6 +644,17 @@ files-info-directory = Dossier
 files-info-symlink = Lien symbolique :
 files-info-yes = Oui
 files-info-na = N/D
+files-info-rating = Note :
+files-info-rating-value = { $average } ({ $count } { $count ->
+    [one] note
+   *[other] notes
+})
+files-info-not-rated = Pas encore noté
+files-info-your-rating = Votre note :
+files-info-comments = Commentaires ({ $count })
+files-comments-loading = Chargement des commentaires…
+files-comments-empty = Aucun commentaire pour l'instant
+files-comment-placeholder = Écrire un commentaire…
 
 # =============================================================================
 # Directory Name Validation Errors
@@ -369,6 +673,7 @@ err-dir-name-invalid = Le nom du dossier contient des caractères invalides
 tab-general = Général
 tab-chat = Discussion
 tab-network = Réseau
+tab-security = Sécurité
 tab-audio = Audio
 
 # =============================================================================
@@ -390,6 +695,8 @@ chat-prefix-system = [SYS]
 chat-prefix-error = [ERR]
 chat-prefix-info = [INFO]
 chat-prefix-broadcast = [BROADCAST]
+chat-prefix-queued = [PENDING]
+chat-export-subtitle = Exporté depuis { $server } le { $date }
 
 # =============================================================================
 # Success Messages
@@ -413,11 +720,28 @@ msg-channel-member-count = { $count } { $count ->
    *[other] membres
 }
 channel-secret = secret
+channel-info-topic = Sujet
+channel-info-rules = Règles
+channel-info-language = Langue
 msg-broadcast-sent = Diffusion envoyée avec succès
+msg-broadcast-delivered = Diffusion remise à { $count } { $count ->
+    [one] utilisateur
+   *[other] utilisateurs
+}
+msg-broadcast-scheduled = Diffusion programmée pour { $time }
+msg-broadcast-acknowledged = Diffusion confirmée par { $count } sur { $delivered } utilisateurs
+msg-storage-low = Espace disque faible sur le serveur pour { $volume } : { $free } libres, sous le minimum de { $threshold }
+msg-storage-recovered = Espace disque sur le serveur pour { $volume } rétabli : { $free } libres
+storage-volume-files = la zone de fichiers
+storage-volume-database = la base de données
+msg-friend-added = { $username } ajouté aux amis
+msg-friend-removed = { $username } retiré des amis
 msg-user-created = Utilisateur créé avec succès
 msg-user-created-name = Utilisateur '{ $username }' créé avec succès
 msg-user-deleted = Utilisateur supprimé avec succès
 msg-user-deleted-name = Utilisateur '{ $username }' supprimé avec succès
+msg-user-erased = Utilisateur supprimé et données personnelles effacées
+msg-user-erased-name = Utilisateur '{ $username }' supprimé et données personnelles effacées
 msg-user-updated = Utilisateur mis à jour avec succès
 msg-user-updated-name = Utilisateur '{ $username }' mis à jour avec succès
 msg-permissions-updated = Vos permissions ont été mises à jour
@@ -432,6 +756,8 @@ msg-welcome-back = Bon retour
 msg-status-set = Statut défini : { $status }
 msg-status-cleared = Statut effacé
 msg-user-is-away = { $nickname } est absent
+msg-group-member-joined = { $nickname } a rejoint la conversation
+msg-group-member-left = { $nickname } a quitté la conversation
 msg-user-is-away-status = { $nickname } est absent: { $status }
 
 # =============================================================================
@@ -442,6 +768,14 @@ msg-topic-cleared = Sujet effacé par { $username }
 msg-topic-set = Sujet défini par { $username } : { $topic }
 msg-secret-set = Mode secret activé par { $username }
 msg-secret-cleared = Mode secret désactivé par { $username }
+msg-topic-locked = Sujet verrouillé par { $username }
+msg-topic-unlocked = Sujet déverrouillé par { $username }
+msg-rules-set = Règles du canal mises à jour par { $username }
+msg-rules-cleared = Règles du canal effacées par { $username }
+msg-language-set = Langue du salon définie sur { $language } par { $username }
+msg-language-cleared = Langue du salon effacée par { $username }
+msg-voice-limit-set = Limite vocale fixée à { $limit } par { $username }
+msg-voice-limit-cleared = Limite vocale supprimée par { $username }
 msg-server-info-updated = Configuration du serveur mise à jour
 msg-topic-display = Sujet : { $topic }
 confirm-delete-user = Êtes-vous sûr de vouloir supprimer l'utilisateur '{ $username }' ?
@@ -461,8 +795,10 @@ err-user-kick-failed = Échec de l'expulsion de l'utilisateur
 err-unknown = Erreur inconnue
 err-no-shutdown-handle = Erreur de connexion : Pas de gestionnaire d'arrêt
 err-userlist-failed = Échec de l'actualisation de la liste des utilisateurs
+err-request-timeout = Le serveur n'a pas répondu à temps
 err-port-invalid = Le port doit être un numéro valide (1-65535)
 err-no-chat-permission = Vous n'avez pas la permission d'envoyer des messages
+err-chat-window-command = Seuls les messages et /me peuvent être envoyés depuis une fenêtre de discussion
 err-console-no-send = Utilisez /{ $join } pour rejoindre un canal ou /{ $msg } pour envoyer un message à un utilisateur
 
 # Network connection errors
@@ -476,10 +812,13 @@ err-connection-closed = Connexion fermée
 err-could-not-determine-config-dir = Impossible de déterminer le répertoire de configuration
 err-message-too-long = Le message est trop long ({ $length } caractères, max { $max })
 err-send-failed = Échec de l'envoi du message
+err-checksum-failed = Échec du calcul de la somme de contrôle
 err-broadcast-too-long = La diffusion est trop longue ({ $length } caractères, max { $max })
 err-broadcast-send-failed = Échec de l'envoi de la diffusion
+err-broadcast-target-required = Choisissez qui reçoit la diffusion
 err-name-required = Le nom du signet est requis
 err-address-required = L'adresse du serveur est requise
+err-fallback-address-invalid = Adresse de secours invalide : { $address }
 err-port-required = Le port est requis
 err-username-required = Le nom d'utilisateur est requis
 err-password-required = Le mot de passe est requis
@@ -497,13 +836,20 @@ err-nickname-too-long = Le pseudonyme est trop long (max { $max } caractères)
 err-nickname-invalid = Le pseudonyme contient des caractères invalides
 err-password-too-long = Le mot de passe est trop long (max { $max } caractères)
 err-passwords-do-not-match = Les mots de passe ne correspondent pas
+err-wrong-master-passphrase = Phrase secrète principale incorrecte
+err-master-passphrase-empty = La phrase secrète principale ne peut pas être vide
+err-password-vault = Erreur du coffre de mots de passe : { $error }
 err-current-password-required = Le mot de passe actuel est requis
 err-new-password-required = Le nouveau mot de passe est requis
 err-confirm-password-required = Veuillez confirmer votre nouveau mot de passe
 err-topic-too-long = Le sujet est trop long ({ $length } caractères, max { $max })
+err-entry-message-too-long = Le message d'accueil est trop long ({ $length } caractères, max { $max })
+err-rules-too-long = Les règles sont trop longues ({ $length } caractères, max { $max })
 err-avatar-unsupported-type = Type de fichier non pris en charge. Utilisez PNG, WebP, JPEG ou SVG.
 err-avatar-too-large = Icône trop grande. La taille maximale est de { $max_kb }Ko.
 err-avatar-decode-failed = Échec du décodage de l'image. Le fichier est peut-être corrompu.
+err-known-server-export = Impossible d'exporter le certificat : { $error }
+err-config-encryption = Impossible de chiffrer les paramètres : { $error }
 err-server-name-empty = Le nom du serveur ne peut pas être vide
 err-server-name-too-long = Le nom du serveur est trop long (max { $max } caractères)
 err-server-name-contains-newlines = Le nom du serveur ne peut pas contenir de sauts de ligne
@@ -524,14 +870,23 @@ err-failed-send-update = Échec de l'envoi de la mise à jour : { $error }
 # =============================================================================
 
 err-failed-save-config = Échec de l'enregistrement de la configuration : { $error }
+err-friends-no-bookmark = Les amis sont enregistrés dans le favori du serveur ; ajoutez d'abord ce serveur aux favoris
+err-friends-too-many = Trop d'amis (max { $max })
+err-friend-exists = { $username } est déjà un ami
+err-friend-not-found = { $username } n'est pas un ami
 err-failed-save-settings = Échec de l'enregistrement des paramètres : { $error }
 err-invalid-port-bookmark = Port invalide dans le signet : { $name }
 err-failed-send-broadcast = Échec de l'envoi de la diffusion : { $error }
 err-failed-send-message = Échec de l'envoi du message : { $error }
+err-failed-start-group = Impossible de démarrer la conversation de groupe : { $error }
+err-failed-share-snippet = Impossible de partager l'extrait : { $error }
 err-failed-create-user = Échec de la création de l'utilisateur : { $error }
 err-failed-delete-user = Échec de la suppression de l'utilisateur : { $error }
 err-failed-update-user = Échec de la mise à jour de l'utilisateur : { $error }
 err-failed-update-topic = Échec de la mise à jour du sujet : { $error }
+err-failed-lock-topic = Échec du verrouillage du sujet : { $error }
+err-failed-set-language = Échec de la définition de la langue du salon : { $error }
+err-failed-update-entry = Échec de la mise à jour du canal : { $error }
 err-message-too-long-details = { $error } ({ $length } caractères, max { $max })
 
 # Network connection errors (with parameters)
@@ -546,6 +901,14 @@ err-tls-handshake-failed = Échec du handshake TLS : { $error }
 err-failed-send-handshake = Échec de l'envoi du handshake : { $error }
 err-failed-read-handshake = Échec de la lecture de la réponse du handshake : { $error }
 err-handshake-failed = Échec du handshake : { $error }
+err-proof-of-work-too-hard = Le serveur demande une preuve de travail trop élevée (difficulté { $difficulty })
+err-sso-not-offered = Ce serveur ne propose pas l'authentification unique
+err-sso-failed = Échec de l'authentification unique : { $error }
+err-sso-browser = Impossible d'ouvrir le navigateur : { $error }
+err-sso-timeout = L'authentification unique a expiré en attendant le navigateur
+err-sso-state-mismatch = La réponse de l'authentification unique ne correspond pas à la demande
+sso-browser-done = Connecté. Vous pouvez fermer cette fenêtre et revenir à Nexus.
+sso-browser-failed = Échec de la connexion. Vous pouvez fermer cette fenêtre et revenir à Nexus.
 err-failed-parse-handshake = Échec de l'analyse de la réponse du handshake : { $error }
 err-failed-send-login = Échec de l'envoi de la connexion : { $error }
 err-failed-read-login = Échec de la lecture de la réponse de connexion : { $error }
@@ -553,6 +916,7 @@ err-failed-parse-login = Échec de l'analyse de la réponse de connexion : { $er
 err-failed-create-server-name = Échec de la création du nom du serveur : { $error }
 err-failed-create-config-dir = Échec de la création du répertoire de configuration : { $error }
 err-failed-serialize-config = Échec de la sérialisation de la configuration : { $error }
+err-config-locked = Les paramètres sont chiffrés et verrouillés ; les modifications ne peuvent pas être enregistrées avant leur déverrouillage
 err-failed-write-config = Échec de l'écriture du fichier de configuration : { $error }
 err-failed-read-config-metadata = Échec de la lecture des métadonnées du fichier de configuration : { $error }
 err-failed-set-config-permissions = Échec de la définition des permissions du fichier de configuration : { $error }
@@ -562,6 +926,10 @@ err-failed-set-config-permissions = Échec de la définition des permissions du
 # =============================================================================
 
 fingerprint-warning = Cela pourrait indiquer un problème de sécurité (attaque MITM) ou que le certificat du serveur a été régénéré. N'acceptez que si vous faites confiance à l'administrateur du serveur.
+fingerprint-new-server-prompt = C'est la première connexion à ce serveur. Ne faites confiance au certificat que si l'empreinte ci-dessous correspond à celle publiée par l'administrateur du serveur.
+password-vault-create-description = Aucun trousseau système n'est disponible. Choisissez une phrase secrète principale pour chiffrer les mots de passe enregistrés de vos favoris, ou ignorez pour les garder dans le fichier de configuration.
+password-vault-unlock-description = Saisissez votre phrase secrète principale pour déverrouiller les mots de passe enregistrés de vos favoris. Si vous ignorez, les favoris se connectent sans mot de passe jusqu'au redémarrage de Nexus.
+config-unlock-description = Vos paramètres, favoris et historique de discussion sont chiffrés. Saisissez votre phrase secrète principale pour les déverrouiller. Si vous passez, Nexus démarre avec les paramètres par défaut et rien n'est enregistré jusqu'au redémarrage.
 
 # =============================================================================
 # User Info Display
@@ -578,10 +946,12 @@ user-info-account = Compte : { $username }
 user-info-connected = Connecté :
 user-info-connected-value = il y a { $duration }
 user-info-connected-value-sessions = il y a { $duration } ({ $count } sessions)
+user-info-idle = Inactif :
 user-info-features = Fonctionnalités :
 user-info-features-value = { $features }
 user-info-features-none = Aucune
 user-info-locale = Langue :
+user-info-client = Client :
 user-info-address = Adresse :
 user-info-addresses = Adresses :
 user-info-created = Créé :
@@ -590,6 +960,12 @@ user-info-unknown = Inconnu
 user-info-loading = Chargement des informations utilisateur…
 user-info-status = Statut:
 user-info-channels = Canaux:
+user-info-rank = Rang :
+user-info-logins = Connexions :
+user-info-messages = Messages :
+user-info-uploads = Envois :
+user-info-downloaded = Téléchargé :
+user-info-last-login = Dernière connexion :
 
 # =============================================================================
 # Time Duration
@@ -625,24 +1001,46 @@ err-me-no-target = Utilisez /{ $join } pour rejoindre un canal ou /{ $msg } pour
 cmd-help-escape-hint = Astuce : Utilisez // pour envoyer un message commençant par /
 cmd-message-desc = Envoyer un message à un utilisateur
 cmd-message-usage = Utilisation : /{ $command } <utilisateur> <message>
+cmd-group-desc = Démarrer une conversation de groupe avec plusieurs utilisateurs
+cmd-group-usage = Utilisation : /{ $command } <utilisateur> [<utilisateur>...]
+cmd-invite-desc = Ajouter un utilisateur à la conversation de groupe actuelle
+cmd-invite-usage = Utilisation : /{ $command } <utilisateur>
 cmd-ping-desc = Mesurer la latence vers le serveur
 cmd-ping-usage = Utilisation : /{ $command }
+cmd-radio-desc = Diffuser de l'audio aux auditeurs vocaux du salon
+cmd-radio-usage = Utilisation : /{ $command }
+err-radio-no-channel = Cette commande ne fonctionne que dans un salon
+err-radio-in-voice = Quittez le vocal avant de lancer une diffusion
 ping-result = Réponse : { $ms }ms
 cmd-userinfo-desc = Afficher les informations sur un utilisateur
 cmd-userinfo-usage = Utilisation : /{ $command } <utilisateur>
 cmd-kick-desc = Expulser un utilisateur du serveur
 cmd-kick-usage = Utilisation : /{ $command } <utilisateur> [raison]
+cmd-top-desc = Afficher les utilisateurs les plus actifs
+cmd-top-usage = Utilisation : /{ $command } [logins|messages|uploads|downloaded]
 cmd-topic-desc = Afficher ou gérer le sujet du chat
-cmd-topic-usage = Utilisation : /{ $command } [set|clear] [sujet]
+cmd-topic-usage = Utilisation : /{ $command } [set|clear|lock|unlock|history] [sujet]
 cmd-topic-set-usage = Utilisation : /{ $command } set <sujet>
 cmd-topic-none = Aucun sujet défini
 cmd-broadcast-desc = Envoyer une diffusion à tous les utilisateurs
 cmd-broadcast-usage = Utilisation : /{ $command } <message>
 cmd-clear-desc = Effacer l'historique du chat de l'onglet actuel
 cmd-clear-usage = Utilisation : /{ $command }
+cmd-export-desc = Enregistrer l'onglet actuel dans un fichier HTML
+cmd-export-usage = Utilisation : /{ $command }
+cmd-clip-desc = Lister, jouer, ajouter ou supprimer des extraits de la table de sons
+cmd-clip-usage = Usage : /{ $command } [liste|jouer <nom>|ajouter <nom>|supprimer <nom>|<nom>]
+cmd-clip-arg-list = liste
+cmd-clip-arg-play = jouer
+cmd-clip-arg-add = ajouter
+cmd-clip-arg-delete = supprimer
 cmd-focus-desc = Focaliser le chat serveur ou la fenêtre de messages d'un utilisateur
 cmd-focus-usage = Utilisation : /{ $command } [utilisateur]
 cmd-focus-not-found = Utilisateur non trouvé : { $name }
+cmd-friend-desc = Lister, ajouter ou retirer des amis
+cmd-friend-usage = Utilisation : /{ $command } [list] | add <utilisateur> | remove <utilisateur>
+cmd-friend-list-header = Amis :
+cmd-friend-list-empty = Pas encore d'amis. Ajoutez-en un avec /{ $command } add <utilisateur>
 cmd-list-desc = Afficher les utilisateurs connectés/tous
 cmd-list-arg-all = tous
 cmd-list-usage = Utilisation : /{ $command } [tous]
@@ -659,7 +1057,17 @@ cmd-list-all-output = Utilisateurs : { $users } ({ $count } { $count ->
 cmd-help-usage = Utilisation : /{ $command } [commande]
 cmd-topic-arg-set = définir
 cmd-topic-arg-clear = effacer
+cmd-topic-arg-lock = verrouiller
+cmd-topic-arg-unlock = déverrouiller
+cmd-topic-arg-history = historique
 cmd-topic-permission-denied = Vous n'avez pas la permission de modifier le sujet
+cmd-topic-lock-permission-denied = Vous n'avez pas la permission de verrouiller le sujet
+msg-topic-already-locked = Le sujet est déjà verrouillé
+msg-topic-already-unlocked = Le sujet est déjà déverrouillé
+msg-topic-history-header = Historique du sujet de { $channel } :
+msg-topic-history-set = { $time } { $username } : { $topic }
+msg-topic-history-cleared = { $time } { $username } a effacé le sujet
+msg-topic-history-empty = Aucun changement de sujet enregistré
 cmd-window-desc = Gérer les onglets de chat
 cmd-window-usage = Utilisation : /{ $command } [suivant|précédent|fermer [utilisateur]]
 cmd-window-arg-next = suivant
@@ -687,15 +1095,55 @@ cmd-unban-desc = Supprimer un bannissement IP
 cmd-unban-usage = Utilisation: /{ $command } <cible>
 cmd-bans-desc = Lister les bannissements actifs
 cmd-bans-usage = Utilisation: /{ $command }
+cmd-register-desc = Enregistrer un compte depuis une session invité
+cmd-register-usage = Utilisation : /{ $command } <utilisateur> <mot_de_passe>
 cmd-reindex-desc = Déclencher la reconstruction de l'index des fichiers
 cmd-reindex-usage = Utilisation: /{ $command }
+cmd-cleanup-desc = Rechercher les restes de transferts interrompus dans la zone de fichiers
+cmd-cleanup-usage = Utilisation: /{ $command } [delete]
+cmd-filecheck-desc = Vérifier la zone de fichiers par rapport à l'index de recherche et aux métadonnées des fichiers
+cmd-filecheck-usage = Utilisation : /{ $command } [repair]
+cmd-screenshot-desc = Partager une capture d'écran annotée dans l'onglet actuel
+cmd-screenshot-usage = Utilisation : /{ $command }
+err-screenshot-no-target = Les captures ne peuvent être partagées que dans un canal ou un onglet de message privé
+err-screenshot-no-folder = Aucun dossier de captures défini — choisissez « Partager les captures ici » sur un dossier de dépôt dans Fichiers
+err-screenshot-capture = Échec de la capture : { $error }
+err-screenshot-save = Impossible d'enregistrer la capture : { $error }
+err-screenshot-upload = Échec de l'envoi de la capture : { $error }
+err-export-empty = Il n'y a aucun message à exporter dans cet onglet
+err-chat-export = Impossible d'exporter le chat : { $error }
+screenshot-title = Partager la capture
+screenshot-tool-crop = Rogner
+screenshot-tool-arrow = Flèche
+screenshot-hint-crop = Tracez un cadre autour de la partie à conserver
+screenshot-hint-arrow = Faites glisser de la base vers la pointe de la flèche
 msg-reindex-triggered = Reconstruction de l'index des fichiers déclenchée
+msg-cleanup-nothing = Aucun reste de transfert interrompu trouvé
+msg-cleanup-found = Restes de transferts interrompus trouvés :
+msg-cleanup-deleted = Restes de transferts interrompus supprimés :
+msg-cleanup-parts = Fichiers .part orphelins : { $count } ({ $size })
+msg-cleanup-temp = Fichiers temporaires restants : { $count } ({ $size })
+msg-cleanup-journal = Entrées du journal d'envoi sans fichier : { $count }
+msg-cleanup-index = Entrées de l'index de recherche sans fichier : { $count }
+msg-cleanup-hint = Vérifiez les fichiers ci-dessus, puis exécutez /cleanup delete pour les supprimer
+msg-filecheck-issue = { $kind } : { $path }
+msg-filecheck-issue-repaired = { $kind } : { $path } (réparé)
+msg-filecheck-clean = La vérification de la zone de fichiers n'a trouvé aucun problème dans { $entries } entrées
+msg-filecheck-done = La vérification de la zone de fichiers a trouvé { $issues } problèmes dans { $entries } entrées, { $repaired } réparés
+msg-filecheck-index-skipped = L'index de recherche n'a pas été comparé car il n'est pas encore construit ou une reconstruction est en attente
+msg-filecheck-hint = Exécutez /filecheck repair pour reconstruire l'index et supprimer les métadonnées des chemins manquants
+filecheck-not-indexed = Absent de l'index de recherche
+filecheck-indexed-but-missing = Dans l'index de recherche mais absent du disque
+filecheck-index-outdated = Obsolète dans l'index de recherche
+filecheck-orphaned-activity = Historique d'un chemin manquant
+filecheck-orphaned-reviews = Notes ou commentaires d'un chemin manquant
+filecheck-orphaned-upload-rule = Règle de dépôt d'un dossier manquant
 cmd-join-desc = Rejoindre ou créer un canal
 cmd-join-usage = Utilisation: /{ $command } #canal
 cmd-leave-desc = Quitter un canal
 cmd-leave-usage = Utilisation: /{ $command } [#canal]
 cmd-channels-desc = Lister les canaux disponibles
-cmd-channels-usage = Utilisation: /{ $command }
+cmd-channels-usage = Utilisation: /{ $command } [langue]
 cmd-secret-desc = Basculer le mode secret sur le canal actuel
 cmd-secret-usage = Utilisation: /{ $command } [on|off]
 cmd-secret-arg-on = on
@@ -708,12 +1156,40 @@ msg-secret-enabled = Le canal est maintenant secret
 msg-secret-disabled = Le canal est maintenant public
 msg-secret-status-on = Ce canal est secret
 msg-secret-status-off = Ce canal est public
+cmd-voicelimit-desc = Afficher ou définir la limite de participants vocaux du canal actuel
+cmd-voicelimit-usage = Usage : /{ $command } [<nombre>|off]
+cmd-vt-desc = Envoyer un message texte à votre session vocale
+cmd-vt-usage = Usage : /{ $command } <message>
+cmd-voicelimit-arg-off = off
+err-voicelimit-no-channel = Cette commande ne fonctionne que dans un canal
+msg-voice-limit-status = Ce canal autorise au plus { $limit } utilisateurs en vocal
+msg-voice-limit-status-none = Ce canal n'a pas de limite vocale
+msg-voice-clip-none = Aucun extrait dans la table de sons
+msg-voice-clip-list = Extraits de la table de sons ({ $count }) :
+msg-voice-clip-entry = { $name } ({ $seconds } s, par { $nickname })
+msg-voice-clip-uploaded = Extrait ajouté à la table de sons
+msg-voice-clip-deleted = Extrait supprimé de la table de sons
 cmd-trust-desc = Faire confiance à une IP, une plage CIDR ou un pseudonyme pour contourner les bannissements
 cmd-trust-usage = Utilisation: /{ $command } <cible> [durée] [raison]
 cmd-untrust-desc = Supprimer une entrée IP de confiance
 cmd-untrust-usage = Utilisation: /{ $command } <cible>
 cmd-trusted-desc = Lister les IPs de confiance
 cmd-trusted-usage = Utilisation: /{ $command }
+cmd-ldap-desc = Lister, définir ou supprimer les correspondances de groupes de l'annuaire
+cmd-ldap-usage = Utilisation: /{ $command } [list|set <permissions|admin|none> <DN du groupe>|remove <DN du groupe>]
+cmd-nickname-desc = Réserver ou libérer des pseudonymes pour votre compte
+cmd-nickname-usage = Utilisation : /{ $command } [register <pseudonyme> [utilisateur]|release <pseudonyme>]
+cmd-history-desc = Afficher ou définir combien de temps le serveur conserve vos messages privés, ou charger les plus anciens
+cmd-history-usage = Utilisation : /{ $command } [<jours>|more]
+cmd-alias-desc = Créer des raccourcis pour les commandes et les messages
+cmd-alias-usage = Utilisation: /{ $command } [list|set <nom> <texte>|remove <nom>]
+cmd-alias-list-header = Vos alias :
+cmd-alias-list-empty = Aucun alias pour l'instant. Créez-en un avec /{ $command } set <nom> <texte>
+cmd-alias-set = /{ $name } exécute maintenant : { $text }
+cmd-alias-removed = Alias /{ $name } supprimé
+cmd-alias-not-found = Aucun alias nommé /{ $name }
+cmd-alias-invalid-name = Les noms d'alias ne peuvent contenir que des lettres, des chiffres, - et _ ({ $max } caractères maximum)
+cmd-alias-builtin = /{ $name } est une commande intégrée et ne peut pas servir d'alias
 
 # =============================================================================
 # About Panel
@@ -726,9 +1202,25 @@ err-server-image-invalid-format = Format d'image du serveur invalide (doit être
 err-server-image-unsupported-type = Type d'image du serveur non pris en charge (PNG, WebP, JPEG ou SVG uniquement)
 err-server-image-decode-failed = Échec du décodage de l'image. Le fichier peut être corrompu.
 err-failed-read-image = Échec de lecture de l'image : { $error }
-err-news-empty = La publication doit contenir du texte ou une image
+err-news-empty = La publication doit contenir du texte ou une pièce jointe
 err-news-body-too-long = Le contenu est trop long ({ $length } caractères, max { $max })
 err-news-body-invalid-characters = Le contenu contient des caractères de contrôle invalides
+err-news-too-many-attachments = Une publication peut avoir au maximum { $max } pièces jointes
+err-news-caption-too-long = La légende est trop longue (max { $max } caractères)
+err-news-caption-invalid-characters = La légende doit tenir sur une seule ligne sans caractères de contrôle
+err-news-file-path-invalid = Chemin de fichier invalide
+err-news-search-date = Date invalide : { $date } (utilisez AAAA-MM-JJ)
+err-file-comment-empty = Le commentaire ne peut pas être vide
+err-file-comment-too-long = Le commentaire est trop long ({ $length } caractères, max { $max })
+err-file-comment-invalid-characters = Le commentaire contient des caractères de contrôle invalides
+err-upload-rule-invalid-extension = Type de fichier invalide « { $extension } » (lettres et chiffres, comme mp3)
+err-auto-download-pattern-required = Saisissez au moins un motif de nom de fichier
+err-auto-download-pattern-invalid = Motif invalide « { $pattern } » (les motifs portent sur les noms de fichiers et ne peuvent pas contenir de barres obliques)
+err-upload-rule-too-many-extensions = Trop de types de fichiers (max { $max })
+err-upload-rule-invalid-size = Taille invalide « { $value } » (saisissez un nombre de mégaoctets)
+err-ldap-group-empty = Le DN du groupe ne peut pas être vide
+err-ldap-group-too-long = Le DN du groupe est trop long (maximum { $max } caractères)
+err-ldap-group-invalid-characters = Le DN du groupe ne peut pas contenir d'espaces autour ni de caractères de contrôle
 err-news-image-decode-failed = Échec du décodage de l'image. Le fichier peut être corrompu.
 
 # Proxy errors
@@ -739,9 +1231,31 @@ err-proxy-connection-timeout = Délai de connexion au proxy dépassé après { $
 }
 err-proxy-address-required = L'adresse du proxy est requise lorsque le proxy est activé
 err-proxy-port-invalid = Le port du proxy doit être compris entre 1 et 65535
-err-news-image-too-large = L'image est trop volumineuse (max 512 Ko)
+err-news-image-too-large = L'image est trop volumineuse (max { $max_kb } Ko)
 err-news-image-unsupported-type = Type d'image non supporté (PNG, WebP, JPEG ou SVG uniquement)
 err-topic-no-channel = Cette commande ne fonctionne que dans un canal
+err-entry-no-channel = Cette commande ne fonctionne que dans un canal
+cmd-welcome-desc = Afficher ou définir le message montré aux utilisateurs qui rejoignent le canal
+cmd-welcome-usage = Utilisation : /{ $command } [définir <message>|effacer]
+cmd-welcome-arg-set = définir
+cmd-welcome-arg-clear = effacer
+cmd-welcome-none = Aucun message d'accueil défini
+msg-entry-message-display = Message d'accueil : { $message }
+msg-entry-message-updated = Message d'accueil mis à jour
+msg-entry-message-cleared = Message d'accueil effacé
+cmd-rules-desc = Afficher ou définir les règles du canal
+cmd-rules-usage = Utilisation : /{ $command } [définir <règles>|effacer]
+cmd-rules-arg-set = définir
+cmd-rules-arg-clear = effacer
+cmd-rules-none = Aucune règle définie
+msg-rules-header = Règles de { $channel } :
+cmd-language-desc = Afficher ou définir la langue du salon
+cmd-language-usage = Utilisation : /{ $command } [définir <langue>|effacer]
+cmd-language-arg-set = définir
+cmd-language-arg-clear = effacer
+msg-language-status = Langue du salon : { $language }
+msg-language-none = Aucune langue définie
+err-language-invalid = Étiquette de langue invalide : { $language } (ex. fr ou pt-BR)
 err-join-channel = Échec de la connexion au canal: { $error }
 err-leave-channel = Échec de la sortie du canal: { $error }
 err-list-channels = Échec de la liste des canaux: { $error }
@@ -751,6 +1265,8 @@ err-channel-too-long = Le nom du canal est trop long (max { $max } caractères)
 err-channel-missing-prefix = Le nom du canal doit commencer par #
 err-channel-invalid-characters = Le nom du canal contient des caractères invalides
 err-leave-no-channel = Vous devez être dans un canal pour utiliser /{ $command } sans arguments
+err-invite-no-group = Utilisez /{ $command } dans un onglet de conversation de groupe
+err-group-too-many-members = Une conversation de groupe peut avoir au plus { $max } membres
 err-not-in-channel = Vous n'êtes pas dans le canal { $channel }
 err-leave-already-pending = Attente de la réponse du serveur en cours
 err-uri-connection-failed = Échec de la connexion à { $host } : { $error }
@@ -764,6 +1280,30 @@ label-port = Port:
 # Transfer statuses
 transfer-status-queued = En attente
 transfer-status-connecting = Connexion…
+transfer-status-server-queued = En attente d'un créneau serveur (#{ $position })
+transfer-collision-pending = Le fichier existe: { $path }
+collision-action-renamed = { $count } { $count ->
+    [one] renommé
+   *[other] renommés
+}
+collision-action-overwritten = { $count } { $count ->
+    [one] écrasé
+   *[other] écrasés
+}
+collision-action-skipped = { $count } { $count ->
+    [one] ignoré
+   *[other] ignorés
+}
+transfer-bar-count = { $count } { $count ->
+    [one] transfert
+   *[other] transferts
+}
+status-bar-users = { $count } { $count ->
+    [one] utilisateur
+   *[other] utilisateurs
+}
+status-bar-latency = { $ms } ms
+status-bar-server-time = Heure du serveur { $time } ({ $zone })
 transfer-status-transferring = Transfert en cours
 transfer-status-paused = En pause
 transfer-status-completed = Terminé
@@ -825,6 +1365,7 @@ event-broadcast = Diffusion
 event-chat-message = Message de chat
 event-chat-mention = Mention dans le chat
 event-connection-lost = Connexion perdue
+event-friend-online = Ami en ligne
 event-news-post = Publication d'actualité
 event-permissions-changed = Permissions modifiées
 event-transfer-complete = Transfert terminé
@@ -859,6 +1400,17 @@ settings-sound-test = Test
 settings-notification-test = Test
 event-settings-show-toast = Afficher le Toast
 settings-toast-test = Test
+settings-do-not-disturb = Ne pas déranger
+settings-quiet-hours = Heures calmes
+settings-do-not-disturb-hint = Coupe les notifications, toasts et sons. Les badges non lus continuent de s'incrémenter et les autres voient 🔕 à côté de votre nom.
+settings-notification-rules = Règles de notification
+settings-notification-rules-server = Serveur :
+settings-notification-rules-channels = Exceptions par salon
+settings-notification-rules-keywords = Mots-clés surveillés
+settings-notification-rules-no-bookmarks = Ajoutez un serveur aux favoris pour définir ses règles de notification.
+channel-notification-all = Tous les messages
+channel-notification-mentions = Mentions uniquement
+channel-notification-mute = Muet
 
 # Sound names
 sound-none = Aucun
@@ -877,6 +1429,8 @@ notification-chat-message = Nouveau message de chat
 notification-chat-message-from = Chat de {$username}
 notification-connection-lost = Connexion perdue
 notification-connection-lost-from = Déconnecté de {$server}
+notification-friend-online = Un ami est en ligne
+notification-friend-online-name = {$username} est en ligne
 notification-download-complete = Téléchargement terminé
 notification-download-failed = Téléchargement échoué
 notification-news-post = Nouvelle publication d'actualité
@@ -930,6 +1484,18 @@ ban-duration-7d = 7 jours
 ban-duration-30d = 30 jours
 ban-duration-permanent = Permanent
 
+# Broadcast target and delay options
+broadcast-target-label = Envoyer à :
+broadcast-target-all = Tout le monde
+broadcast-target-channel = Membres d'un canal
+broadcast-target-permission = Utilisateurs ayant une permission
+broadcast-delay-label = Envoyer :
+broadcast-delay-now = Maintenant
+broadcast-delay-5m = Dans 5 minutes
+broadcast-delay-15m = Dans 15 minutes
+broadcast-delay-1h = Dans 1 heure
+broadcast-delay-1d = Dans 1 jour
+
 # =============================================================================
 # Ban System
 # =============================================================================
@@ -979,12 +1545,32 @@ msg-untrusted-success = Entrée de confiance supprimée avec succès
 # Trust list messages
 msg-trust-list-header = IPs de confiance :
 msg-trust-list-empty = Aucune IP de confiance
+msg-ldap-group-list-header = Groupes de l'annuaire :
+msg-ldap-group-list-empty = Aucun groupe de l'annuaire associé
+msg-ldap-group-admin = admin
+msg-ldap-group-no-permissions = aucune permission
+msg-ldap-group-set = Groupe de l'annuaire { $group } associé
+msg-ldap-group-removed = Groupe de l'annuaire { $group } supprimé
+msg-guest-upgraded = Compte { $username } créé. Connectez-vous avec la prochaine fois pour l'utiliser.
+msg-nickname-registered = Le pseudonyme { $nickname } est maintenant réservé à { $username }.
+msg-nickname-released = Pseudonyme { $nickname } libéré.
+msg-history-days = Le serveur conserve vos messages privés pendant { $days } jours.
+msg-history-off = Le serveur ne conserve pas vos messages privés. Utilisez /history <jours> pour l'activer.
+msg-history-no-older = Aucun message plus ancien avec { $nickname }.
+err-history-days-too-long = L'historique des messages peut être conservé au plus { $max } jours
+err-history-more-tab = Utilisez /history more dans un onglet de messages privés
 msg-trust-permanent = permanent
 msg-trust-expired = expiré
 msg-trust-remaining = { $time } restant
 msg-trust-remaining-days = { $days }j { $hours }h
 msg-trust-remaining-hours = { $hours }h { $minutes }m
 msg-trust-remaining-minutes = { $minutes }m
+msg-leaderboard-header = Utilisateurs les plus actifs par { $stat } :
+msg-leaderboard-empty = Aucune activité enregistrée pour l'instant
+leaderboard-stat-logins = connexions
+leaderboard-stat-messages = messages
+leaderboard-stat-uploads = envois
+leaderboard-stat-downloaded = données téléchargées
 
 # =============================================================================
 # Voice Chat
@@ -993,6 +1579,16 @@ msg-trust-remaining-minutes = { $minutes }m
 # Voice error messages
 err-voice-join = Échec de connexion au chat vocal : { $error }
 err-voice-leave = Échec de déconnexion du chat vocal : { $error }
+err-voice-limit = Impossible de définir la limite vocale : { $error }
+err-voice-clip = Table de sons : { $error }
+err-voice-text = Texte vocal : { $error }
+err-voice-clip-name-empty = Le nom de l'extrait ne peut pas être vide
+err-voice-clip-name-too-long = Le nom de l'extrait est trop long (max { $max } caractères)
+err-voice-clip-name-invalid = Les noms d'extrait ne peuvent contenir que des lettres, des chiffres, - et _
+err-voice-clip-too-large = L'extrait est trop volumineux (max 256 Ko)
+err-voice-clip-unsupported-type = Format d'extrait non pris en charge (Ogg Vorbis uniquement)
+msg-voice-queued = Le vocal est plein - vous êtes numéro { $position } dans la file
+msg-voice-queue-cancelled = Vous avez été retiré de la file vocale
 err-voice-no-token = Connexion au chat vocal réussie mais aucun jeton reçu
 err-voice-already-active = Vous êtes déjà dans une session vocale sur un autre serveur
 err-voice-no-permission = Vous n'avez pas la permission de rejoindre le chat vocal
@@ -1018,7 +1614,12 @@ msg-voice-joined-empty = Rejoint le chat vocal dans { $target }
 msg-voice-left = Quitté le chat vocal dans { $target }
 msg-voice-user-joined = { $nickname } a rejoint le chat vocal
 msg-voice-user-left = { $nickname } a quitté le chat vocal
+msg-voice-broadcast-started = { $nickname } diffuse en vocal - rejoignez pour écouter
+msg-voice-broadcast-ended = La diffusion vocale est terminée
+msg-voice-clip-played = { $nickname } a joué { $name }
+msg-voice-text = [vocal] { $nickname } : { $message }
 msg-voice-you-left = Vous avez quitté le chat vocal
+msg-voice-tcp-relay = La connexion UDP vocale a échoué ({ $error }) - la voix passe par la connexion au serveur
 
 # Voice UI
 voice-join-tooltip = Rejoindre le chat vocal
@@ -1026,6 +1627,8 @@ voice-leave-tooltip = Quitter le chat vocal
 voice-mute-all-tooltip = Tout couper
 voice-unmute-all-tooltip = Réactiver le son
 voice-bar-participants = ({ $count })
+voice-bar-queued = (en file : { $position })
+voice-bar-listeners = ({ $count } à l'écoute)
 voice-indicator-tooltip = En chat vocal
 tooltip-mute = Couper le son
 tooltip-unmute = Rétablir le son
@@ -1036,6 +1639,12 @@ voice-quality-medium = Moyenne (32 kbps)
 voice-quality-high = Haute (64 kbps)
 voice-quality-very-high = Très haute (96 kbps)
 
+# Voice connection quality
+voice-connection-good = Bonne
+voice-connection-fair = Moyenne
+voice-connection-poor = Mauvaise
+voice-connection-tooltip = Connexion : { $quality } ({ $loss } % de perte, gigue de { $jitter } ms)
+
 # PTT modes
 ptt-mode-hold = Maintenir pour parler
 ptt-mode-toggle = Basculer
@@ -1050,6 +1659,8 @@ ptt-delay-500ms = 500ms
 audio-output-device = Périphérique de sortie
 audio-input-device = Périphérique d'entrée
 audio-refresh-devices = Actualiser les périphériques
+audio-clip-volume = Volume de la table de sons
+audio-voice-text-tts = Lire à voix haute les messages texte vocaux
 audio-voice-quality = Qualité vocale
 audio-ptt-key = Touche Push-to-Talk
 audio-ptt-mode = Mode PTT
@@ -1076,6 +1687,44 @@ mic-boost-6db = +6 dB
 mic-boost-12db = +12 dB
 mic-boost-18db = +18 dB
 
+# =============================================================================
+# Global Hotkeys
+# =============================================================================
+
+# Settings
+settings-toggle-window-key = Touche afficher/masquer la fenêtre
+button-clear-hotkey = Effacer
+
+# Shortcut descriptions (shown by the desktop on Wayland)
+hotkey-push-to-talk = Appuyer pour parler
+hotkey-toggle-window = Afficher ou masquer Nexus
+
+# Errors
+err-window-hotkey-failed = Échec de l'enregistrement de la touche afficher/masquer : { $error }
+err-global-shortcuts-unavailable = Portail des raccourcis globaux indisponible, les touches ne fonctionnent que lorsque Nexus a le focus : { $error }
+
+# =============================================================================
+# Auto Away
+# =============================================================================
+
+settings-auto-away = Absent automatiquement en cas d'inactivité
+settings-auto-away-minutes = Minutes d'inactivité
+
+# =============================================================================
+# Connection Health
+# =============================================================================
+
+# Server list indicator
+connection-health-good = Bonne
+connection-health-fair = Moyenne
+connection-health-poor = Mauvaise
+connection-health-tooltip = Connexion : { $quality }
+connection-health-latency = Latence : { $ms } ms
+connection-health-jitter = Gigue : { $ms } ms
+connection-health-last-drop = Dernier ping perdu : { $time }
+connection-health-no-drops = Aucun ping perdu
+connection-health-measuring = Mesure de la latence…
+
 # =============================================================================
 # System Tray (Windows/Linux only)
 # =============================================================================
@@ -1104,14 +1753,33 @@ tray-tooltip-unread = Nexus BBS - { $count ->
    *[other] { $count } conversations non lues
 }
 
+# =============================================================================
+# Notification Actions (macOS/Windows only)
+# =============================================================================
+
+# Actions
+notification-reply = Répondre
+notification-reply-send = Envoyer
+notification-reply-placeholder = Répondre…
+notification-mark-read = Marquer comme lu
+
 # =============================================================================
 # Toast Notifications
 # =============================================================================
 
 toast-link-copied = Lien copié dans le presse-papiers
 toast-copied = Copié dans le presse-papiers
+toast-checksum-copied = SHA-256 copié dans le presse-papiers
 toast-download-started = Téléchargement de { $filename }
 toast-download-queued = Téléchargement en file d'attente : { $filename }
+toast-downloads-started = Téléchargement de { $count } { $count ->
+    [one] fichier
+   *[other] fichiers
+}
+toast-downloads-queued = { $count } { $count ->
+    [one] téléchargement
+   *[other] téléchargements
+} en file d'attente
 toast-upload-started = Envoi de { $filename }
 toast-upload-queued = Envoi en file d'attente : { $filename }
 toast-uploads-started = Envoi de { $count } { $count ->
@@ -1122,3 +1790,28 @@ toast-uploads-queued = { $count } { $count ->
     [one] envoi
    *[other] envois
 } en file d'attente
+toast-upload-nothing-new = Rien à envoyer : le serveur a déjà ces fichiers
+toast-upload-watch-started = Surveillance de { $folder } : les nouveaux fichiers seront téléversés automatiquement
+toast-upload-watch-stopped = Surveillance de { $folder } arrêtée
+toast-auto-download-started = Téléchargement automatique de { $pattern } depuis { $folder }
+toast-auto-download-stopped = Téléchargement automatique depuis { $folder } arrêté
+toast-screenshot-folder-set = Les captures seront envoyées dans ce dossier
+toast-chat-exported = Chat exporté
+
+# =============================================================================
+# First-run Tips
+# =============================================================================
+
+tip-welcome-title = Bienvenue sur Nexus
+tip-welcome = Saisissez ci-dessous l'adresse d'un serveur et connectez-vous. Ajoutez un favori pour garder le serveur, avec vos identifiants, dans la liste de gauche, à un clic la prochaine fois.
+tip-fingerprint = Chaque serveur a une empreinte de certificat, comme une signature. Nexus la mémorise lors de la première connexion et vous avertit si elle change, car cela peut signifier que quelqu'un se fait passer pour le serveur. N'acceptez une nouvelle empreinte que si l'administrateur du serveur vous a prévenu du changement.
+tip-server-list = Vos favoris et connexions ouvertes sont à gauche. Cliquez sur l'un d'eux pour changer de serveur, ou faites glisser les favoris pour les réorganiser.
+tip-toolbar = La barre d'outils ouvre les fichiers, les actualités, les paramètres et plus encore. Survolez une icône pour voir ce qu'elle fait.
+tip-chat = Discutez ici avec tout le monde sur le serveur. Tapez / pour voir les commandes disponibles, comme /join pour rejoindre un salon.
+tip-user-list = Les utilisateurs en ligne sont listés à droite. Cliquez sur un nom pour lui écrire ou voir ses informations.
+tip-counter = Astuce { $current } sur { $total }
+button-add-bookmark = Ajouter un favori
+button-tip-next = Suivant
+button-tip-done = Terminé
+button-tip-got-it = Compris
+button-skip-tips = Passer les astuces
@@ -11,6 +11,13 @@ button-connect = Connetti
 button-save = Salva
 button-create = Crea
 button-edit = Modifica
+button-check-ports = Verifica porte
+button-statistics = Statistiche
+button-dashboard = Dashboard
+button-refresh = Aggiorna
+button-doors = Porte
+button-doors-leave = Esci
+button-doors-back = Indietro
 button-update = Aggiorna
 button-accept-new-certificate = Accetta Nuovo Certificato
 button-close = Chiudi
@@ -18,9 +25,23 @@ button-choose-avatar = Scegli Icona
 button-clear-avatar = Cancella
 button-choose-image = Scegli Immagine
 button-clear-image = Cancella
+button-add-file = Aggiungi file
 button-overwrite = Sovrascrivi
 button-change-password = Cambia Password
 button-browse = Sfoglia
+button-undo = Annulla modifica
+button-add = Aggiungi
+button-remove = Rimuovi
+button-view = Mostra
+button-hide = Nascondi
+button-export = Esporta…
+button-trust-certificate = Considera attendibile
+button-skip = Salta
+button-skip-existing = Salta esistenti
+button-download-anyway = Scarica comunque
+button-unlock = Sblocca
+button-view-as-user = Visualizza come Utente
+button-post-comment = Pubblica
 
 # =============================================================================
 # Titles
@@ -35,19 +56,29 @@ title-user-create = Crea utente
 title-user-edit = Modifica utente
 title-update-user = Aggiorna utente
 title-user-management = Gestione Utenti
+title-permission-audit = Verifica Permessi
+title-impersonate = Visualizzazione come { $username }
 title-confirm-delete = Conferma Eliminazione
 title-connected = Connessi
 title-settings = Impostazioni
 title-bookmarks = Segnalibri
 title-users = Utenti
+title-friends = Amici
+friend-online = online
+friend-offline = offline
 title-edit-server-info = Modifica Info Server
 title-fingerprint-mismatch = Impronta certificato non corrispondente!
+title-fingerprint-new-server = Nuovo certificato del server
+title-create-password-vault = Proteggi le password salvate
+title-unlock-password-vault = Sblocca le password salvate
+title-unlock-config = Sblocca impostazioni
 title-server-info = Info Server
 title-user-info = Info Utente
 title-about = Informazioni
 title-change-password = Cambia Password
 title-news = Notizie
 title-transfers = Trasferimenti
+title-transfer-history = Cronologia trasferimenti
 title-news-create = Crea Pubblicazione
 title-news-edit = Modifica Pubblicazione
 title-channel-members = Membri
@@ -58,10 +89,14 @@ title-channel-members = Membri
 
 placeholder-username = Nome utente
 placeholder-password = Password
+placeholder-master-passphrase = Passphrase principale
+placeholder-confirm-master-passphrase = Conferma passphrase principale
 placeholder-port = Porta
 placeholder-server-address = Indirizzo del server
 placeholder-server-name = Nome server
+placeholder-folder-name = Nome della cartella
 placeholder-username-optional = Nome utente (opzionale)
+placeholder-fallback-addresses-optional = Indirizzi di riserva (opzionale, separati da virgole)
 placeholder-password-optional = Password (opzionale)
 placeholder-password-keep-current = Password
 placeholder-current-password = Password Attuale
@@ -70,10 +105,18 @@ placeholder-confirm-password = Conferma Password
 placeholder-message = Scrivi un messaggio…
 placeholder-no-permission = Nessun permesso
 placeholder-broadcast-message = Messaggio broadcast
+placeholder-broadcast-channel = Scegli un canale
+placeholder-broadcast-permission = Scegli un permesso
 placeholder-server-description = Descrizione del server
 placeholder-news-body = Contenuto notizia
+placeholder-news-caption = Didascalia (facoltativa)
+placeholder-news-file-path = Percorso nell'area file
 placeholder-nickname-optional = Nickname (opzionale)
+placeholder-status-optional = Stato (opzionale)
+placeholder-download-folder-optional = Cartella download (opzionale)
 placeholder-proxy-address = Indirizzo Proxy
+placeholder-notification-rules-channel = #canale
+placeholder-notification-rules-keyword = Parola chiave
 placeholder-proxy-port = 9050
 placeholder-proxy-username = Nome utente (opzionale)
 placeholder-proxy-password = Password (opzionale)
@@ -86,13 +129,20 @@ placeholder-auto-join-channels = #canale1 #canale2 (separati da spazi)
 # =============================================================================
 
 label-auto-connect = Auto-Connessione
+label-server-avatar = Avatar:
 label-add-bookmark = Segnalibro
+label-lan-servers = In questa rete
+label-use-sso = Single sign-on
 label-admin = Amministratore
 label-shared-account = Account Condiviso
 label-enabled = Abilitato
+label-erase-personal-data = Cancella anche i dati personali
+label-broadcast-require-ack = Chiedi conferma ai destinatari
 label-permissions = Permessi:
 label-expected-fingerprint = Impronta prevista:
 label-received-fingerprint = Impronta ricevuta:
+label-certificate-fingerprint = Impronta del certificato:
+label-identity-key = Chiave di identità:
 label-theme = Tema
 label-chat-history-retention = Cronologia chat:
 chat-history-forever = Per sempre
@@ -100,6 +150,10 @@ chat-history-30-days = 30 giorni
 chat-history-14-days = 14 giorni
 chat-history-7-days = 7 giorni
 chat-history-disabled = Disabilitato
+collision-policy-rename = Mantieni entrambi (rinomina)
+collision-policy-overwrite = Sovrascrivi
+collision-policy-skip = Salta
+collision-policy-ask = Chiedi
 label-max-scrollback = Cronologia max:
 label-chat-font-size = Dimensione font:
 label-show-connection-events = Mostra eventi di connessione/disconnessione
@@ -107,6 +161,19 @@ label-show-channel-events = Mostra eventi di entrata/uscita
 label-show-timestamps = Mostra timestamp
 label-use-24-hour-time = Usa formato 24 ore
 label-show-seconds = Mostra secondi
+label-split-view = Mostra file, notizie e monitor connessioni accanto alla chat
+label-spell-check = Controlla l'ortografia in chat e nelle notizie
+label-spell-check-no-dictionary = Nessun dizionario trovato per la tua lingua. Metti i file .aff e .dic nella cartella dictionaries accanto a config.json.
+label-send-read-receipts = Invia conferme di lettura per i messaggi privati
+label-user-list-grouping = Raggruppa elenco utenti:
+label-user-list-sort = Ordina elenco utenti:
+user-list-group-none = Nessuno
+user-list-group-admins = Prima gli admin
+user-list-group-channel = Per canale
+user-list-group-status = Per stato
+user-list-sort-alphabetical = Alfabetico
+user-list-sort-idle = Tempo di inattività
+user-list-sort-connect = Ora di connessione
 label-server-name = Nome:
 label-server-description = Descrizione:
 label-server-version = Versione:
@@ -140,6 +207,27 @@ tab-files = File
 tab-channels = Canali
 label-connections-short = Connessioni:
 label-transfers-short = Trasferimenti:
+label-active-transfers-short = Attivi:
+label-queue-order-short = Ordine coda:
+label-trusted-weight-short = Peso fidato:
+label-nat-mapping-short = Mappatura porte:
+label-nat-external-ip-short = IP esterno:
+label-nat-not-mapped = Non mappato
+connectivity-check-running = Verifica delle porte…
+check-service-bbs = BBS
+check-service-transfer = Trasferimenti
+check-service-websocket = WebSocket
+check-service-transfer-websocket = Trasferimenti WebSocket
+check-service-voice = Voce
+check-state-open = Aperta
+check-state-closed = Chiusa
+check-state-filtered = Filtrata
+check-state-untested = Non verificata
+label-check-port = { $service } ({ $protocol } { $port }):
+label-queue-order-small-first = Prima i file piccoli
+label-queue-order-arrival = Ordine di arrivo
+label-small-files-first = Metti in coda prima i file piccoli
+label-unlimited = Illimitato
 label-reindex-short = Reindicizza:
 label-persistent-short = Persistenti:
 label-auto-join-short = Auto-unione:
@@ -156,6 +244,19 @@ label-download-location = Posizione Download:
 label-queue-transfers = Accoda Trasferimenti
 label-download-limit = Limite Download:
 label-upload-limit = Limite Upload:
+label-auto-open-folder = Apri la cartella al termine di un download
+label-collision-policy = Se il file esiste:
+label-require-fingerprint-approval = Chiedi prima di considerare attendibile il certificato di un nuovo server
+label-known-servers = Server conosciuti
+known-servers-empty = Nessun certificato del server salvato
+known-server-first-seen = Visto per la prima volta il { $date }
+known-server-first-seen-unknown = Visto per la prima volta: sconosciuto
+label-encrypt-config = Cifra impostazioni, segnalibri e cronologia chat con una passphrase principale
+config-encryption-locked-note = Le impostazioni sono bloccate. Riavvia Nexus e inserisci la passphrase principale per modificarlo.
+label-upload-rule-extensions = Tipi di file consentiti:
+label-upload-rule-max-file-size = File più grande (MB):
+label-upload-rule-max-total-size = Caricamento più grande (MB):
+label-auto-download-pattern = Nomi dei file:
 label-persistent-channels = Canali Persistenti:
 label-auto-join-channels = Canali Auto-Unione:
 label-channels = Canali
@@ -169,11 +270,14 @@ permission-user_info = Info Utente
 permission-chat_send = Invia Chat
 permission-chat_receive = Ricevi Chat
 permission-chat_join = Entra in Chat
+permission-chat_language = Lingua chat
 permission-chat_create = Crea Chat
 permission-chat_list = Elenco Chat
 permission-chat_secret = Chat Segreto
 permission-chat_topic = Argomento Chat
 permission-chat_topic_edit = Modifica Argomento Chat
+permission-chat_topic_lock = Blocca Argomento Chat
+permission-chat_entry_edit = Modifica Benvenuto Chat
 permission-user_broadcast = Broadcast Utente
 permission-user_create = Crea Utente
 permission-user_delete = Elimina Utente
@@ -184,6 +288,7 @@ permission-news_list = Lista Notizie
 permission-news_create = Crea Notizia
 permission-news_edit = Modifica Notizia
 permission-news_delete = Elimina Notizia
+permission-file_comment = Commentare file
 permission-file_copy = Copia File
 permission-file_create_dir = Directory Crea
 permission-file_delete = Elimina File
@@ -203,6 +308,11 @@ permission-trust_create = Crea Fiducia
 permission-trust_delete = Elimina Fiducia
 permission-trust_list = Lista Fiducia
 permission-connection_monitor = Monitor Connessioni
+permission-log_view = Visualizza log
+permission-door_play = Gioca porte
+permission-voice_broadcast = Trasmissione Vocale
+permission-voice_clip_upload = Caricamento Clip Vocali
+permission-voice_limit = Limite Vocale
 permission-voice_listen = Ascolto Vocale
 permission-voice_talk = Parla Vocale
 
@@ -227,19 +337,31 @@ tooltip-files-paste = Incolla
 tooltip-files-search = Cerca file
 tooltip-manage-users = Gestisci Utenti
 tooltip-connection-monitor = Monitor Connessioni
+tooltip-server-log = Log del server
 tooltip-server-info = Info Server
 tooltip-about = Informazioni
 tooltip-transfers = Trasferimenti
 tooltip-download-all = Scarica Tutto
 tooltip-upload = Carica
 file-picker-upload-title = Seleziona file da caricare
+file-picker-download-to-title = Seleziona cartella download
+folder-picker-upload-watch-title = Seleziona la cartella da caricare automaticamente
 drop-to-upload = Rilascia per caricare
 tooltip-clear-inactive = Cancella Inattivi
+tooltip-transfer-history = Cronologia trasferimenti
+tooltip-transfer-history-clear = Cancella cronologia
+tooltip-transfer-history-remove = Rimuovi dalla cronologia
 tooltip-transfer-pause = Pausa
 tooltip-transfer-resume = Riprendi
 tooltip-transfer-cancel = Annulla
 tooltip-transfer-remove = Rimuovi
 tooltip-transfer-open-folder = Apri Cartella
+tooltip-transfer-open-file = Apri File
+tooltip-transfer-reveal = Mostra nel File Manager
+tooltip-transfer-copy-checksum = Copia SHA-256
+tooltip-transfer-collision-rename = Mantieni entrambi (rinomina)
+tooltip-transfer-collision-overwrite = Sovrascrivi i file esistenti
+tooltip-transfer-collision-skip = Salta i file esistenti
 tooltip-transfer-move-up = Sposta Su
 tooltip-transfer-move-down = Sposta Giù
 tooltip-transfer-retry = Riprova
@@ -252,16 +374,34 @@ tooltip-disconnect = Disconnetti
 tooltip-edit = Modifica
 tooltip-info = Info
 tooltip-message = Messaggio
+tooltip-add-friend = Aggiungi amico
+tooltip-remove-friend = Rimuovi amico
 tooltip-kick = Espelli
 tooltip-close = Chiudi
+tooltip-retry-send = Riprova invio
+tooltip-cancel-send = Annulla invio
+tooltip-message-sent = Inviato
+tooltip-message-delivered = Consegnato
+tooltip-message-read = Letto
 tooltip-new-tab = Nuova Scheda
 tooltip-close-tab = Chiudi Scheda
 tooltip-add-bookmark = Aggiungi Segnalibro
+tooltip-new-folder = Nuova cartella
 tooltip-create-user = Crea Utente
+tooltip-permission-audit = Verifica Permessi
 tooltip-create-news = Crea Pubblicazione
+tooltip-news-search = Cerca notizie
+tooltip-news-search-clear = Cancella ricerca
 tooltip-delete = Elimina
 context-menu-download = Scarica
+context-menu-download-to = Scarica in…
+context-menu-download-select = Scarica selezione…
+context-menu-auto-download = Download automatico…
 context-menu-upload = Carica
+context-menu-watch-folder = Monitora cartella locale…
+context-menu-screenshot-folder = Condividi screenshot qui
+context-menu-unwatch-folder = Interrompi monitoraggio cartella
+context-menu-upload-rules = Regole di caricamento…
 context-menu-open = Apri
 
 # =============================================================================
@@ -269,6 +409,7 @@ context-menu-open = Apri
 # =============================================================================
 
 panel-connection-monitor = Monitor Connessioni
+panel-server-log = Log del server
 panel-active-connections = Connessioni Attive: { $count }
 tab-connections = Connessioni
 tab-transfers = Trasferimenti
@@ -283,9 +424,97 @@ menu-info = Info
 menu-copy = Copia
 menu-kick = Espelli
 menu-ban = Blocca
+menu-pop-out = Separa
+menu-mute-tab = Silenzia
+menu-unmute-tab = Riattiva audio
+menu-connect-all = Connetti tutti
+menu-rename = Rinomina
+menu-delete = Elimina
 connection-monitor-loading = Caricamento connessioni…
 connection-monitor-no-connections = Nessuna connessione
 connection-monitor-no-transfers = Nessun trasferimento attivo
+connection-monitor-queued = In coda
+connection-monitor-weight = Peso di equa ripartizione: ×{ $weight }
+connection-monitor-listed = Elencato in { $zone }
+connection-monitor-queued-count = { $count } in coda
+placeholder-server-log-module = Filtra per modulo
+server-log-loading = Caricamento log del server…
+server-log-empty = Nessuna riga di log
+server-log-level-error = Solo errori
+server-log-level-warn = Avvisi e superiori
+server-log-level-info = Info e superiori
+server-log-level-debug = Tutto (debug)
+panel-statistics = Statistiche
+stats-loading = Caricamento statistiche…
+panel-snippet = Snippet
+snippet-loading = Caricamento snippet…
+snippet-details = Condiviso da { $author } · scade il { $expires }
+stats-range-days = Ultimi { $days } giorni
+stats-chart-max = Giorno massimo: { $value }
+stats-metric-logins = Accessi
+stats-metric-messages = Messaggi
+stats-metric-uploads = Caricamenti
+stats-metric-upload-bytes = Dati caricati
+stats-metric-downloads = Download
+stats-metric-download-bytes = Dati scaricati
+stats-metric-peak-sessions = Picco di utenti online
+panel-admin-dashboard = Dashboard amministratore
+dashboard-loading = Caricamento dashboard…
+dashboard-connections = Connessioni
+dashboard-sessions = Utenti online
+dashboard-connection-count = Connessioni
+dashboard-transfer-connections = Connessioni di trasferimento
+dashboard-transfers = Trasferimenti
+dashboard-active-transfers = In corso
+dashboard-queued-transfers = In coda
+dashboard-today = Oggi
+dashboard-disk = Area file
+dashboard-disk-free = Spazio libero
+dashboard-disk-free-of = { $free } liberi su { $total }
+dashboard-disk-unknown = Sconosciuto
+dashboard-recent-audit = Eventi di audit recenti
+dashboard-audit-empty = Nessun evento di audit
+dashboard-updated = Aggiornato alle { $time }
+panel-doors = Porte
+doors-loading = Caricamento porte…
+doors-empty = Nessuna porta su questo server
+doors-starting = Avvio…
+doors-playing-hint = I tasti vanno alla porta. Usa Esci per uscire.
+doors-exited = La porta è terminata
+doors-exited-code = La porta è terminata con codice { $code }
+
+# =============================================================================
+# Quick Switcher
+# =============================================================================
+
+quick-switcher-placeholder = Vai a un canale, messaggio, segnalibro o pannello...
+user-list-filter-placeholder = Filtra utenti...
+user-list-section-admins = Admin
+user-list-section-users = Utenti
+user-list-section-no-channel = Altri
+user-list-section-online = Online
+user-list-section-away = Assente
+user-list-section-dnd = Non disturbare
+user-list-idle = Inattivo da { $duration }
+quick-switcher-no-matches = Nessun risultato
+quick-switcher-bookmark = Segnalibro
+
+# =============================================================================
+# Chat Input
+# =============================================================================
+
+chat-paste-prompt = Incollare { $count } righe?
+chat-paste-too-long-prompt = Troppo lungo per un messaggio (max { $max }). Inviarlo comunque?
+button-send-as-messages = Invia come { $count } messaggi
+button-send-as-one-message = Invia come un unico messaggio
+button-send-in-parts = Invia in { $count } parti
+button-share-as-snippet = Condividi come snippet
+button-expand-code = Mostra tutte le { $count } righe
+button-collapse-code = Comprimi
+chat-broadcast-ack-prompt = { $username } ti chiede di confermare il suo broadcast
+button-acknowledge = Conferma
+button-dismiss = Ignora
+spell-no-suggestions = Nessun suggerimento
 
 # =============================================================================
 # Empty States
@@ -296,11 +525,35 @@ empty-no-connections = Nessuna connessione
 empty-no-bookmarks = Nessun segnalibro
 empty-no-users = Nessun utente online
 empty-no-channel-members = Nessun membro nel canale
+empty-no-matching-users = Nessun utente corrispondente
 user-management-loading = Caricamento utenti…
 user-management-no-users = Nessun utente trovato
+permission-audit-hint = Scegli un permesso per vedere chi lo possiede, o fai clic su un utente per vedere tutto ciò che può fare.
+permission-audit-loading = Caricamento…
+permission-audit-no-holders = Nessun account possiede questo permesso
+permission-audit-effective = Permessi effettivi di { $username }
+permission-audit-admin = Gli amministratori possiedono tutti i permessi
+permission-audit-no-permissions = Questo account non ha permessi
+permission-audit-restricted = Concessi ma non disponibili per gli account condivisi:
+permission-audit-disabled = Disabilitato
+impersonate-notice = Vista in sola lettura di ciò che vede questo account. Ogni visualizzazione viene registrata nel registro di controllo.
+impersonate-permissions = Permessi
+impersonate-files = File ({ $path })
+impersonate-no-file-access = Questo account non può sfogliare i file
+impersonate-empty-folder = Questa cartella è vuota
+impersonate-channels = Canali
+impersonate-no-channel-access = Questo account non può elencare i canali
+impersonate-no-channels = Nessun canale visibile
+impersonate-secret = Segreto
 news-loading = Caricamento notizie…
 news-no-posts = Nessuna notizia ancora
+news-search-placeholder = Cerca notizie… (author:nome since:AAAA-MM-GG until:AAAA-MM-GG)
+news-searching = Ricerca in corso…
+news-search-no-results = Nessun post corrispondente
 transfers-empty = Nessun trasferimento
+transfer-history-search-placeholder = Cerchi per nome, server o cartella…
+transfer-history-empty = Nessun trasferimento concluso
+transfer-history-no-matches = Nessun trasferimento corrisponde alla ricerca
 news-updated = Aggiornato
 files-loading = Caricamento file…
 files-empty = Questa cartella è vuota
@@ -312,10 +565,16 @@ files-column-name = Nome
 files-column-size = Dimensione
 files-column-modified = Modificato
 files-column-path = Percorso
+files-column-rating = Valutazione
 files-search-placeholder = Cerca file…
 files-search-breadcrumb = Ricerca - { $query }
 files-searching = Ricerca in corso…
 files-no-results = Nessun file trovato
+files-highlights = In evidenza
+files-highlights-recent = Nuovi caricamenti della settimana
+files-highlights-popular = Più scaricati
+files-highlights-empty = Ancora niente
+files-highlights-downloads = { $count } download
 files-search-query-too-short = La query di ricerca è troppo corta (min. { $min_length } caratteri)
 files-search-query-too-long = La query di ricerca è troppo lunga (max. { $max_length } caratteri)
 files-search-query-invalid = La query di ricerca contiene caratteri non validi
@@ -326,6 +585,30 @@ files-share = Condividi
 files-rename = Rinomina
 files-rename-title = Rinomina "{ $name }"
 files-rename-placeholder = Nuovo nome
+files-upload-rule-title = Regole di caricamento per "{ $name }"
+files-upload-rule-loading = Caricamento…
+files-upload-rule-extensions-placeholder = Qualsiasi tipo (es. mp3, flac)
+files-upload-rule-no-limit = Nessun limite
+files-upload-rule-remove = Rimuovi regola
+files-upload-rule-help = Le regole valgono per questa cartella e le sue sottocartelle, a meno che una sottocartella non abbia le proprie.
+files-auto-download-title = Download automatico da "{ $name }"
+files-auto-download-pattern-placeholder = ad es. *.mp3, *.m4a
+files-auto-download-remove = Interrompi download automatico
+files-auto-download-help = I nuovi file di questa cartella il cui nome corrisponde vengono scaricati mentre è connesso tramite questo segnalibro. Usi * per qualsiasi testo e ? per un singolo carattere, e separi i modelli con virgole. I file già scaricati vengono saltati.
+files-upload-rule-hint = Caricamenti qui: { $limits }
+files-upload-rule-hint-types = solo { $extensions }
+files-upload-rule-hint-file-size = fino a { $size } per file
+files-upload-rule-hint-total-size = fino a { $size } per caricamento
+files-upload-preview-title = I file esistono già
+files-upload-preview-comparing = Confronto con i file sul server…
+files-upload-preview-summary = { $new } nuovi, { $identical } già sul server, { $conflicts } diversi:
+files-upload-preview-blocked = { $count } { $count ->
+    [one] file ha
+   *[other] file hanno
+} una cartella con lo stesso nome sul server e { $count ->
+    [one] verrà saltato
+   *[other] verranno saltati
+}
 files-cut = Taglia
 files-copy = Copia
 files-paste = Incolla
@@ -335,6 +618,13 @@ files-delete-confirm-title = Conferma eliminazione
 files-delete-confirm-message = Sei sicuro di voler eliminare "{ $name }"?
 # File Overwrite Dialog
 files-overwrite-title = Il file esiste già
+files-download-to-title = Scarica { $name }
+label-remember-for-server = Ricorda per questo server
+label-select-all = Seleziona tutto
+files-download-select-summary = { $count } di { $total } selezionati ({ $size })
+files-download-select-too-many = Puoi selezionare al massimo { $max } file singoli. Scarica invece l'intera cartella.
+files-download-duplicate-title = Già scaricato
+files-download-duplicate-message = "{ $name }" è già presente su questo computer in { $path }. Vuole scaricarlo di nuovo?
 files-overwrite-message = Un file o una cartella con il nome "{ $name }" esiste già nella destinazione. Vuoi sostituirlo?
 
 # File Info Dialog
@@ -350,6 +640,17 @@ files-info-directory = Cartella
 files-info-symlink = Symlink:
 files-info-yes = Sì
 files-info-na = N/D
+files-info-rating = Valutazione:
+files-info-rating-value = { $average } ({ $count } { $count ->
+    [one] valutazione
+   *[other] valutazioni
+})
+files-info-not-rated = Non ancora valutato
+files-info-your-rating = La tua valutazione:
+files-info-comments = Commenti ({ $count })
+files-comments-loading = Caricamento commenti…
+files-comments-empty = Ancora nessun commento
+files-comment-placeholder = Scrivi un commento…
 
 # =============================================================================
 # Directory Name Validation Errors
@@ -368,6 +669,7 @@ err-dir-name-invalid = Il nome della cartella contiene caratteri non validi
 tab-general = Generale
 tab-chat = Chat
 tab-network = Rete
+tab-security = Sicurezza
 tab-audio = Audio
 
 # =============================================================================
@@ -389,6 +691,8 @@ chat-prefix-system = [SIS]
 chat-prefix-error = [ERR]
 chat-prefix-info = [INFO]
 chat-prefix-broadcast = [BROADCAST]
+chat-prefix-queued = [PENDING]
+chat-export-subtitle = Esportato da { $server } il { $date }
 
 # =============================================================================
 # Success Messages
@@ -412,11 +716,28 @@ msg-channel-member-count = { $count } { $count ->
    *[other] membri
 }
 channel-secret = segreto
+channel-info-topic = Argomento
+channel-info-rules = Regole
+channel-info-language = Lingua
 msg-broadcast-sent = Broadcast inviato con successo
+msg-broadcast-delivered = Broadcast consegnato a { $count } { $count ->
+    [one] utente
+   *[other] utenti
+}
+msg-broadcast-scheduled = Broadcast programmato per { $time }
+msg-broadcast-acknowledged = Broadcast confermato da { $count } utenti su { $delivered }
+msg-storage-low = Spazio su disco scarso sul server per { $volume }: { $free } liberi, sotto il minimo di { $threshold }
+msg-storage-recovered = Spazio su disco sul server per { $volume } ripristinato: { $free } liberi
+storage-volume-files = l'area file
+storage-volume-database = il database
+msg-friend-added = { $username } aggiunto agli amici
+msg-friend-removed = { $username } rimosso dagli amici
 msg-user-created = Utente creato con successo
 msg-user-created-name = Utente '{ $username }' creato con successo
 msg-user-deleted = Utente eliminato con successo
 msg-user-deleted-name = Utente '{ $username }' eliminato con successo
+msg-user-erased = Utente eliminato e dati personali cancellati
+msg-user-erased-name = Utente '{ $username }' eliminato e dati personali cancellati
 msg-user-updated = Utente aggiornato con successo
 msg-user-updated-name = Utente '{ $username }' aggiornato con successo
 msg-permissions-updated = I tuoi permessi sono stati aggiornati
@@ -431,6 +752,8 @@ msg-welcome-back = Bentornato
 msg-status-set = Stato impostato: { $status }
 msg-status-cleared = Stato cancellato
 msg-user-is-away = { $nickname } è assente
+msg-group-member-joined = { $nickname } si è unito alla conversazione
+msg-group-member-left = { $nickname } ha lasciato la conversazione
 msg-user-is-away-status = { $nickname } è assente: { $status }
 
 
@@ -444,6 +767,14 @@ msg-topic-cleared = Argomento cancellato da { $username }
 msg-topic-set = Argomento impostato da { $username }: { $topic }
 msg-secret-set = Modalità segreta abilitata da { $username }
 msg-secret-cleared = Modalità segreta disabilitata da { $username }
+msg-topic-locked = Argomento bloccato da { $username }
+msg-topic-unlocked = Argomento sbloccato da { $username }
+msg-rules-set = Regole del canale aggiornate da { $username }
+msg-rules-cleared = Regole del canale cancellate da { $username }
+msg-language-set = Lingua del canale impostata su { $language } da { $username }
+msg-language-cleared = Lingua del canale cancellata da { $username }
+msg-voice-limit-set = Limite vocale impostato a { $limit } da { $username }
+msg-voice-limit-cleared = Limite vocale rimosso da { $username }
 msg-server-info-updated = Configurazione del server aggiornata
 msg-topic-display = Argomento: { $topic }
 confirm-delete-user = Sei sicuro di voler eliminare l'utente '{ $username }'?
@@ -463,6 +794,7 @@ err-user-kick-failed = Impossibile espellere l'utente
 err-unknown = Errore sconosciuto
 err-no-shutdown-handle = Errore di connessione: Nessun handle di chiusura
 err-userlist-failed = Impossibile aggiornare la lista utenti
+err-request-timeout = Il server non ha risposto in tempo
 err-port-invalid = La porta deve essere un numero valido (1-65535)
 
 # Network connection errors
@@ -476,12 +808,16 @@ err-connection-closed = Connessione chiusa
 err-could-not-determine-config-dir = Impossibile determinare la directory di configurazione
 err-message-too-long = Il messaggio è troppo lungo ({ $length } caratteri, max { $max })
 err-send-failed = Impossibile inviare il messaggio
+err-checksum-failed = Impossibile calcolare il checksum
 err-no-chat-permission = Non hai il permesso di inviare messaggi
+err-chat-window-command = Da una finestra di chat si possono inviare solo messaggi e /me
 err-console-no-send = Usa /{ $join } per entrare in un canale o /{ $msg } per inviare un messaggio a un utente
 err-broadcast-too-long = Il broadcast è troppo lungo ({ $length } caratteri, max { $max })
 err-broadcast-send-failed = Impossibile inviare il broadcast
+err-broadcast-target-required = Scegli chi riceve il broadcast
 err-name-required = Il nome del segnalibro è obbligatorio
 err-address-required = L'indirizzo del server è obbligatorio
+err-fallback-address-invalid = Indirizzo di riserva non valido: { $address }
 err-port-required = La porta è obbligatoria
 err-username-required = Il nome utente è obbligatorio
 err-password-required = La password è obbligatoria
@@ -499,13 +835,20 @@ err-nickname-too-long = Il soprannome è troppo lungo (max { $max } caratteri)
 err-nickname-invalid = Il soprannome contiene caratteri non validi
 err-password-too-long = La password è troppo lunga (max { $max } caratteri)
 err-passwords-do-not-match = Le password non corrispondono
+err-wrong-master-passphrase = Passphrase principale errata
+err-master-passphrase-empty = La passphrase principale non può essere vuota
+err-password-vault = Errore del portachiavi password: { $error }
 err-current-password-required = La password attuale è obbligatoria
 err-new-password-required = La nuova password è obbligatoria
 err-confirm-password-required = Per favore conferma la nuova password
 err-topic-too-long = L'argomento è troppo lungo ({ $length } caratteri, max { $max })
+err-entry-message-too-long = Il messaggio di benvenuto è troppo lungo ({ $length } caratteri, max { $max })
+err-rules-too-long = Le regole sono troppo lunghe ({ $length } caratteri, max { $max })
 err-avatar-unsupported-type = Tipo di file non supportato. Usa PNG, WebP, JPEG o SVG.
 err-avatar-too-large = Icona troppo grande. La dimensione massima è { $max_kb }KB.
 err-avatar-decode-failed = Impossibile decodificare l'immagine. Il file potrebbe essere danneggiato.
+err-known-server-export = Impossibile esportare il certificato: { $error }
+err-config-encryption = Impossibile cifrare le impostazioni: { $error }
 err-server-name-empty = Il nome del server non può essere vuoto
 err-server-name-too-long = Il nome del server è troppo lungo (max { $max } caratteri)
 err-server-name-contains-newlines = Il nome del server non può contenere interruzioni di riga
@@ -526,14 +869,23 @@ err-failed-send-update = Impossibile inviare l'aggiornamento: { $error }
 # =============================================================================
 
 err-failed-save-config = Impossibile salvare la configurazione: { $error }
+err-friends-no-bookmark = Gli amici sono salvati nel segnalibro del server; aggiungi prima un segnalibro
+err-friends-too-many = Troppi amici (max { $max })
+err-friend-exists = { $username } è già un amico
+err-friend-not-found = { $username } non è un amico
 err-failed-save-settings = Impossibile salvare le impostazioni: { $error }
 err-invalid-port-bookmark = Porta non valida nel segnalibro: { $name }
 err-failed-send-broadcast = Impossibile inviare il broadcast: { $error }
 err-failed-send-message = Impossibile inviare il messaggio: { $error }
+err-failed-start-group = Impossibile avviare la conversazione di gruppo: { $error }
+err-failed-share-snippet = Impossibile condividere lo snippet: { $error }
 err-failed-create-user = Impossibile creare l'utente: { $error }
 err-failed-delete-user = Impossibile eliminare l'utente: { $error }
 err-failed-update-user = Impossibile aggiornare l'utente: { $error }
 err-failed-update-topic = Impossibile aggiornare l'argomento: { $error }
+err-failed-lock-topic = Impossibile bloccare l'argomento: { $error }
+err-failed-set-language = Impossibile impostare la lingua del canale: { $error }
+err-failed-update-entry = Impossibile aggiornare il canale: { $error }
 err-message-too-long-details = { $error } ({ $length } caratteri, max { $max })
 
 # Network connection errors (with parameters)
@@ -548,6 +900,14 @@ err-tls-handshake-failed = Handshake TLS fallito: { $error }
 err-failed-send-handshake = Impossibile inviare l'handshake: { $error }
 err-failed-read-handshake = Impossibile leggere la risposta dell'handshake: { $error }
 err-handshake-failed = Handshake fallito: { $error }
+err-proof-of-work-too-hard = Il server ha richiesto una prova di lavoro eccessiva (difficoltà { $difficulty })
+err-sso-not-offered = Questo server non offre il single sign-on
+err-sso-failed = Single sign-on non riuscito: { $error }
+err-sso-browser = Impossibile aprire il browser: { $error }
+err-sso-timeout = Tempo scaduto in attesa del browser per il single sign-on
+err-sso-state-mismatch = La risposta del single sign-on non corrisponde alla richiesta
+sso-browser-done = Accesso eseguito. Puoi chiudere questa finestra e tornare a Nexus.
+sso-browser-failed = Accesso non riuscito. Puoi chiudere questa finestra e tornare a Nexus.
 err-failed-parse-handshake = Impossibile analizzare la risposta dell'handshake: { $error }
 err-failed-send-login = Impossibile inviare l'accesso: { $error }
 err-failed-read-login = Impossibile leggere la risposta di accesso: { $error }
@@ -555,6 +915,7 @@ err-failed-parse-login = Impossibile analizzare la risposta di accesso: { $error
 err-failed-create-server-name = Impossibile creare il nome del server: { $error }
 err-failed-create-config-dir = Impossibile creare la directory di configurazione: { $error }
 err-failed-serialize-config = Impossibile serializzare la configurazione: { $error }
+err-config-locked = Le impostazioni sono cifrate e bloccate; le modifiche non possono essere salvate finché non vengono sbloccate
 err-failed-write-config = Impossibile scrivere il file di configurazione: { $error }
 err-failed-read-config-metadata = Impossibile leggere i metadati del file di configurazione: { $error }
 err-failed-set-config-permissions = Impossibile impostare i permessi del file di configurazione: { $error }
@@ -564,6 +925,10 @@ err-failed-set-config-permissions = Impossibile impostare i permessi del file di
 # =============================================================================
 
 fingerprint-warning = Questo potrebbe indicare un problema di sicurezza (attacco MITM) o che il certificato del server è stato rigenerato. Accetta solo se ti fidi dell'amministratore del server.
+fingerprint-new-server-prompt = Questa è la prima connessione a questo server. Considera attendibile il certificato solo se l'impronta qui sotto corrisponde a quella pubblicata dall'amministratore del server.
+password-vault-create-description = Nessun portachiavi di sistema disponibile. Scegli una passphrase principale per cifrare le password salvate dei segnalibri, oppure salta per mantenerle nel file di configurazione.
+password-vault-unlock-description = Inserisci la passphrase principale per sbloccare le password salvate dei segnalibri. Se salti, i segnalibri si connettono senza password fino al riavvio di Nexus.
+config-unlock-description = Impostazioni, segnalibri e cronologia chat sono cifrati. Inserisci la passphrase principale per sbloccarli. Se salti, Nexus si avvia con le impostazioni predefinite e non viene salvato nulla fino al riavvio.
 
 # =============================================================================
 # User Info Display
@@ -580,10 +945,12 @@ user-info-account = Account: { $username }
 user-info-connected = Connesso:
 user-info-connected-value = { $duration } fa
 user-info-connected-value-sessions = { $duration } fa ({ $count } sessioni)
+user-info-idle = Inattivo:
 user-info-features = Funzionalità:
 user-info-features-value = { $features }
 user-info-features-none = Nessuna
 user-info-locale = Lingua:
+user-info-client = Client:
 user-info-address = Indirizzo:
 user-info-addresses = Indirizzi:
 user-info-created = Creato:
@@ -592,6 +959,12 @@ user-info-unknown = Sconosciuto
 user-info-loading = Caricamento informazioni utente…
 user-info-status = Stato:
 user-info-channels = Canali:
+user-info-rank = Grado:
+user-info-logins = Accessi:
+user-info-messages = Messaggi:
+user-info-uploads = Caricamenti:
+user-info-downloaded = Scaricati:
+user-info-last-login = Ultimo accesso:
 
 # =============================================================================
 # Time Duration
@@ -627,24 +1000,46 @@ err-me-no-target = Usa /{ $join } per entrare in un canale o /{ $msg } per invia
 cmd-help-escape-hint = Suggerimento: Usa // per inviare un messaggio che inizia con /
 cmd-message-desc = Invia un messaggio a un utente
 cmd-message-usage = Uso: /{ $command } <utente> <messaggio>
+cmd-group-desc = Avvia una conversazione di gruppo con più utenti
+cmd-group-usage = Uso: /{ $command } <utente> [<utente>...]
+cmd-invite-desc = Aggiungi un utente alla conversazione di gruppo attuale
+cmd-invite-usage = Uso: /{ $command } <utente>
 cmd-ping-desc = Misura la latenza verso il server
 cmd-ping-usage = Uso: /{ $command }
+cmd-radio-desc = Trasmetti audio agli ascoltatori vocali del canale
+cmd-radio-usage = Uso: /{ $command }
+err-radio-no-channel = Questo comando funziona solo in un canale
+err-radio-in-voice = Esci dalla voce prima di avviare una trasmissione
 ping-result = Risposta: { $ms }ms
 cmd-userinfo-desc = Mostra informazioni su un utente
 cmd-userinfo-usage = Uso: /{ $command } <utente>
 cmd-kick-desc = Espelli un utente dal server
 cmd-kick-usage = Uso: /{ $command } <utente> [motivo]
+cmd-top-desc = Mostra gli utenti più attivi
+cmd-top-usage = Uso: /{ $command } [logins|messages|uploads|downloaded]
 cmd-topic-desc = Visualizza o gestisci l'argomento della chat
-cmd-topic-usage = Uso: /{ $command } [set|clear] [argomento]
+cmd-topic-usage = Uso: /{ $command } [set|clear|lock|unlock|history] [argomento]
 cmd-topic-set-usage = Uso: /{ $command } set <argomento>
 cmd-topic-none = Nessun argomento impostato
 cmd-broadcast-desc = Invia un messaggio broadcast a tutti gli utenti
 cmd-broadcast-usage = Uso: /{ $command } <messaggio>
 cmd-clear-desc = Cancella la cronologia chat della scheda corrente
 cmd-clear-usage = Uso: /{ $command }
+cmd-export-desc = Salva la scheda attuale come file HTML
+cmd-export-usage = Uso: /{ $command }
+cmd-clip-desc = Elenca, riproduci, aggiungi o elimina clip della soundboard
+cmd-clip-usage = Uso: /{ $command } [elenco|riproduci <nome>|aggiungi <nome>|elimina <nome>|<nome>]
+cmd-clip-arg-list = elenco
+cmd-clip-arg-play = riproduci
+cmd-clip-arg-add = aggiungi
+cmd-clip-arg-delete = elimina
 cmd-focus-desc = Focalizza la chat del server o la finestra messaggi di un utente
 cmd-focus-usage = Uso: /{ $command } [utente]
 cmd-focus-not-found = Utente non trovato: { $name }
+cmd-friend-desc = Elenca, aggiungi o rimuovi amici
+cmd-friend-usage = Uso: /{ $command } [list] | add <utente> | remove <utente>
+cmd-friend-list-header = Amici:
+cmd-friend-list-empty = Ancora nessun amico. Aggiungine uno con /{ $command } add <utente>
 cmd-list-desc = Mostra utenti connessi/tutti
 cmd-list-arg-all = tutti
 cmd-list-usage = Uso: /{ $command } [tutti]
@@ -661,7 +1056,17 @@ cmd-list-all-output = Utenti: { $users } ({ $count } { $count ->
 cmd-help-usage = Uso: /{ $command } [comando]
 cmd-topic-arg-set = imposta
 cmd-topic-arg-clear = cancella
+cmd-topic-arg-lock = blocca
+cmd-topic-arg-unlock = sblocca
+cmd-topic-arg-history = cronologia
 cmd-topic-permission-denied = Non hai il permesso di modificare l'argomento
+cmd-topic-lock-permission-denied = Non hai il permesso di bloccare l'argomento
+msg-topic-already-locked = L'argomento è già bloccato
+msg-topic-already-unlocked = L'argomento è già sbloccato
+msg-topic-history-header = Cronologia argomento di { $channel }:
+msg-topic-history-set = { $time } { $username }: { $topic }
+msg-topic-history-cleared = { $time } { $username } ha cancellato l'argomento
+msg-topic-history-empty = Nessuna modifica dell'argomento registrata
 cmd-window-desc = Gestisci le schede chat
 cmd-window-usage = Uso: /{ $command } [prossimo|precedente|chiudi [utente]]
 cmd-window-arg-next = prossimo
@@ -689,15 +1094,55 @@ cmd-unban-desc = Rimuovi un ban IP
 cmd-unban-usage = Uso: /{ $command } <obiettivo>
 cmd-bans-desc = Elenca i ban attivi
 cmd-bans-usage = Uso: /{ $command }
+cmd-register-desc = Registra un account da una sessione ospite
+cmd-register-usage = Uso: /{ $command } <utente> <password>
 cmd-reindex-desc = Avvia ricostruzione indice file
 cmd-reindex-usage = Uso: /{ $command }
+cmd-cleanup-desc = Trova i residui dei trasferimenti interrotti nell'area file
+cmd-cleanup-usage = Uso: /{ $command } [delete]
+cmd-filecheck-desc = Verifica l'area file rispetto all'indice di ricerca e ai metadati dei file
+cmd-filecheck-usage = Uso: /{ $command } [repair]
+cmd-screenshot-desc = Condividi uno screenshot annotato nella scheda corrente
+cmd-screenshot-usage = Uso: /{ $command }
+err-screenshot-no-target = Gli screenshot possono essere condivisi solo in un canale o in una scheda di messaggio utente
+err-screenshot-no-folder = Nessuna cartella per gli screenshot — scegli «Condividi screenshot qui» su una cartella di caricamento in File
+err-screenshot-capture = Screenshot non riuscito: { $error }
+err-screenshot-save = Impossibile salvare lo screenshot: { $error }
+err-screenshot-upload = Caricamento dello screenshot non riuscito: { $error }
+err-export-empty = Non ci sono messaggi da esportare in questa scheda
+err-chat-export = Impossibile esportare la chat: { $error }
+screenshot-title = Condividi screenshot
+screenshot-tool-crop = Ritaglia
+screenshot-tool-arrow = Freccia
+screenshot-hint-crop = Trascina un riquadro attorno alla parte da mantenere
+screenshot-hint-arrow = Trascina dalla coda alla punta della freccia
 msg-reindex-triggered = Ricostruzione indice file avviata
+msg-cleanup-nothing = Nessun residuo di trasferimenti interrotti trovato
+msg-cleanup-found = Residui di trasferimenti interrotti trovati:
+msg-cleanup-deleted = Residui di trasferimenti interrotti eliminati:
+msg-cleanup-parts = File .part orfani: { $count } ({ $size })
+msg-cleanup-temp = File temporanei rimasti: { $count } ({ $size })
+msg-cleanup-journal = Voci del registro di caricamento senza file: { $count }
+msg-cleanup-index = Voci dell'indice di ricerca senza file: { $count }
+msg-cleanup-hint = Controlli i file qui sopra, poi esegua /cleanup delete per rimuoverli
+msg-filecheck-issue = { $kind }: { $path }
+msg-filecheck-issue-repaired = { $kind }: { $path } (riparato)
+msg-filecheck-clean = Il controllo dell'area file non ha trovato problemi in { $entries } voci
+msg-filecheck-done = Il controllo dell'area file ha trovato { $issues } problemi in { $entries } voci, { $repaired } riparati
+msg-filecheck-index-skipped = L'indice di ricerca non è stato confrontato perché non è ancora stato creato o è in attesa di ricostruzione
+msg-filecheck-hint = Esegua /filecheck repair per ricostruire l'indice e rimuovere i metadati dei percorsi mancanti
+filecheck-not-indexed = Non presente nell'indice di ricerca
+filecheck-indexed-but-missing = Nell'indice di ricerca ma mancante sul disco
+filecheck-index-outdated = Obsoleto nell'indice di ricerca
+filecheck-orphaned-activity = Cronologia di un percorso mancante
+filecheck-orphaned-reviews = Valutazioni o commenti di un percorso mancante
+filecheck-orphaned-upload-rule = Regola di caricamento di una cartella mancante
 cmd-join-desc = Entra o crea un canale
 cmd-join-usage = Uso: /{ $command } #canale
 cmd-leave-desc = Lascia un canale
 cmd-leave-usage = Uso: /{ $command } [#canale]
 cmd-channels-desc = Elenca i canali disponibili
-cmd-channels-usage = Uso: /{ $command }
+cmd-channels-usage = Uso: /{ $command } [lingua]
 cmd-secret-desc = Attiva/disattiva la modalità segreta sul canale corrente
 cmd-secret-usage = Uso: /{ $command } [on|off]
 cmd-secret-arg-on = on
@@ -710,12 +1155,40 @@ msg-secret-enabled = Il canale è ora segreto
 msg-secret-disabled = Il canale è ora pubblico
 msg-secret-status-on = Questo canale è segreto
 msg-secret-status-off = Questo canale è pubblico
+cmd-voicelimit-desc = Visualizza o imposta il limite di partecipanti vocali del canale corrente
+cmd-voicelimit-usage = Uso: /{ $command } [<numero>|off]
+cmd-vt-desc = Invia un messaggio di testo alla tua sessione vocale
+cmd-vt-usage = Uso: /{ $command } <messaggio>
+cmd-voicelimit-arg-off = off
+err-voicelimit-no-channel = Questo comando funziona solo in un canale
+msg-voice-limit-status = Questo canale consente al massimo { $limit } utenti in vocale
+msg-voice-limit-status-none = Questo canale non ha limite vocale
+msg-voice-clip-none = Nessuna clip nella soundboard
+msg-voice-clip-list = Clip della soundboard ({ $count }):
+msg-voice-clip-entry = { $name } ({ $seconds } s, di { $nickname })
+msg-voice-clip-uploaded = Clip aggiunta alla soundboard
+msg-voice-clip-deleted = Clip eliminata dalla soundboard
 cmd-trust-desc = Considera attendibile un IP, intervallo CIDR o nickname per bypassare i ban
 cmd-trust-usage = Uso: /{ $command } <obiettivo> [durata] [motivo]
 cmd-untrust-desc = Rimuovi una voce IP attendibile
 cmd-untrust-usage = Uso: /{ $command } <obiettivo>
 cmd-trusted-desc = Elenca gli IP attendibili
 cmd-trusted-usage = Uso: /{ $command }
+cmd-ldap-desc = Elenca, imposta o rimuovi le associazioni dei gruppi della directory
+cmd-ldap-usage = Uso: /{ $command } [list|set <permessi|admin|none> <DN del gruppo>|remove <DN del gruppo>]
+cmd-nickname-desc = Riserva o libera soprannomi per il tuo account
+cmd-nickname-usage = Uso: /{ $command } [register <soprannome> [utente]|release <soprannome>]
+cmd-history-desc = Mostra o imposta per quanto tempo il server conserva i tuoi messaggi privati, o carica quelli precedenti
+cmd-history-usage = Uso: /{ $command } [<giorni>|more]
+cmd-alias-desc = Crea scorciatoie per comandi e messaggi
+cmd-alias-usage = Uso: /{ $command } [list|set <nome> <testo>|remove <nome>]
+cmd-alias-list-header = I tuoi alias:
+cmd-alias-list-empty = Nessun alias. Creane uno con /{ $command } set <nome> <testo>
+cmd-alias-set = /{ $name } ora esegue: { $text }
+cmd-alias-removed = Alias /{ $name } rimosso
+cmd-alias-not-found = Nessun alias chiamato /{ $name }
+cmd-alias-invalid-name = I nomi degli alias possono contenere solo lettere, numeri, - e _ (fino a { $max } caratteri)
+cmd-alias-builtin = /{ $name } è un comando integrato e non può essere usato come alias
 
 # =============================================================================
 # About Panel
@@ -728,9 +1201,25 @@ err-server-image-invalid-format = Formato immagine del server non valido (deve e
 err-server-image-unsupported-type = Tipo di immagine del server non supportato (solo PNG, WebP, JPEG o SVG)
 err-server-image-decode-failed = Impossibile decodificare l'immagine. Il file potrebbe essere corrotto.
 err-failed-read-image = Impossibile leggere l'immagine: { $error }
-err-news-empty = La pubblicazione deve contenere testo o un'immagine
+err-news-empty = La pubblicazione deve contenere testo o un allegato
 err-news-body-too-long = Il contenuto è troppo lungo ({ $length } caratteri, massimo { $max })
 err-news-body-invalid-characters = Il contenuto contiene caratteri di controllo non validi
+err-news-too-many-attachments = Una pubblicazione può avere al massimo { $max } allegati
+err-news-caption-too-long = La didascalia è troppo lunga (max { $max } caratteri)
+err-news-caption-invalid-characters = La didascalia deve essere su una sola riga senza caratteri di controllo
+err-news-file-path-invalid = Percorso file non valido
+err-news-search-date = Data non valida: { $date } (usa AAAA-MM-GG)
+err-file-comment-empty = Il commento non può essere vuoto
+err-file-comment-too-long = Il commento è troppo lungo ({ $length } caratteri, massimo { $max })
+err-file-comment-invalid-characters = Il commento contiene caratteri di controllo non validi
+err-upload-rule-invalid-extension = Tipo di file non valido "{ $extension }" (usa lettere e cifre, come mp3)
+err-auto-download-pattern-required = Inserisca almeno un modello di nome file
+err-auto-download-pattern-invalid = Modello non valido "{ $pattern }" (i modelli riguardano i nomi dei file e non possono contenere barre)
+err-upload-rule-too-many-extensions = Troppi tipi di file (max { $max })
+err-upload-rule-invalid-size = Dimensione non valida "{ $value }" (inserisci un numero di megabyte)
+err-ldap-group-empty = Il DN del gruppo non può essere vuoto
+err-ldap-group-too-long = Il DN del gruppo è troppo lungo (massimo { $max } caratteri)
+err-ldap-group-invalid-characters = Il DN del gruppo non può avere spazi iniziali o finali né caratteri di controllo
 err-news-image-decode-failed = Impossibile decodificare l'immagine. Il file potrebbe essere corrotto.
 
 # Proxy errors
@@ -741,9 +1230,31 @@ err-proxy-connection-timeout = Timeout connessione proxy dopo { $seconds } { $se
 }
 err-proxy-address-required = L'indirizzo del proxy è richiesto quando il proxy è abilitato
 err-proxy-port-invalid = La porta del proxy deve essere compresa tra 1 e 65535
-err-news-image-too-large = L'immagine è troppo grande (massimo 512KB)
+err-news-image-too-large = L'immagine è troppo grande (massimo { $max_kb }KB)
 err-news-image-unsupported-type = Tipo di immagine non supportato (solo PNG, WebP, JPEG o SVG)
 err-topic-no-channel = Questo comando funziona solo in un canale
+err-entry-no-channel = Questo comando funziona solo in un canale
+cmd-welcome-desc = Visualizza o imposta il messaggio mostrato a chi entra nel canale
+cmd-welcome-usage = Uso: /{ $command } [imposta <messaggio>|cancella]
+cmd-welcome-arg-set = imposta
+cmd-welcome-arg-clear = cancella
+cmd-welcome-none = Nessun messaggio di benvenuto impostato
+msg-entry-message-display = Messaggio di benvenuto: { $message }
+msg-entry-message-updated = Messaggio di benvenuto aggiornato
+msg-entry-message-cleared = Messaggio di benvenuto cancellato
+cmd-rules-desc = Visualizza o imposta le regole del canale
+cmd-rules-usage = Uso: /{ $command } [imposta <regole>|cancella]
+cmd-rules-arg-set = imposta
+cmd-rules-arg-clear = cancella
+cmd-rules-none = Nessuna regola impostata
+msg-rules-header = Regole di { $channel }:
+cmd-language-desc = Visualizza o imposta la lingua del canale
+cmd-language-usage = Uso: /{ $command } [imposta <lingua>|cancella]
+cmd-language-arg-set = imposta
+cmd-language-arg-clear = cancella
+msg-language-status = Lingua del canale: { $language }
+msg-language-none = Nessuna lingua impostata
+err-language-invalid = Tag di lingua non valido: { $language } (es. it o pt-BR)
 err-join-channel = Impossibile entrare nel canale: { $error }
 err-leave-channel = Impossibile lasciare il canale: { $error }
 err-list-channels = Impossibile elencare i canali: { $error }
@@ -753,6 +1264,8 @@ err-channel-too-long = Il nome del canale è troppo lungo (max { $max } caratter
 err-channel-missing-prefix = Il nome del canale deve iniziare con #
 err-channel-invalid-characters = Il nome del canale contiene caratteri non validi
 err-leave-no-channel = Devi essere in un canale per usare /{ $command } senza argomenti
+err-invite-no-group = Usa /{ $command } in una scheda di conversazione di gruppo
+err-group-too-many-members = Una conversazione di gruppo può avere al massimo { $max } membri
 err-not-in-channel = Non sei nel canale { $channel }
 err-leave-already-pending = In attesa di risposta dal server
 err-uri-connection-failed = Connessione a { $host } non riuscita: { $error }
@@ -766,6 +1279,30 @@ label-port = Porta:
 # Transfer statuses
 transfer-status-queued = In coda
 transfer-status-connecting = Connessione…
+transfer-status-server-queued = In attesa di uno slot sul server (#{ $position })
+transfer-collision-pending = Il file esiste: { $path }
+collision-action-renamed = { $count } { $count ->
+    [one] rinominato
+   *[other] rinominati
+}
+collision-action-overwritten = { $count } { $count ->
+    [one] sovrascritto
+   *[other] sovrascritti
+}
+collision-action-skipped = { $count } { $count ->
+    [one] saltato
+   *[other] saltati
+}
+transfer-bar-count = { $count } { $count ->
+    [one] trasferimento
+   *[other] trasferimenti
+}
+status-bar-users = { $count } { $count ->
+    [one] utente
+   *[other] utenti
+}
+status-bar-latency = { $ms } ms
+status-bar-server-time = Ora del server { $time } ({ $zone })
 transfer-status-transferring = Trasferimento
 transfer-status-paused = In pausa
 transfer-status-completed = Completato
@@ -827,6 +1364,7 @@ event-broadcast = Trasmissione
 event-chat-message = Messaggio di chat
 event-chat-mention = Menzione in chat
 event-connection-lost = Connessione persa
+event-friend-online = Amico online
 event-news-post = Post di notizie
 event-permissions-changed = Permessi modificati
 event-transfer-complete = Trasferimento completato
@@ -861,6 +1399,17 @@ settings-sound-test = Test
 settings-notification-test = Test
 event-settings-show-toast = Mostra Toast
 settings-toast-test = Test
+settings-do-not-disturb = Non disturbare
+settings-quiet-hours = Ore di silenzio
+settings-do-not-disturb-hint = Silenzia notifiche, avvisi e suoni. I badge dei non letti continuano ad aumentare e gli altri vedono 🔕 accanto al tuo nome.
+settings-notification-rules = Regole di notifica
+settings-notification-rules-server = Server:
+settings-notification-rules-channels = Eccezioni per canale
+settings-notification-rules-keywords = Parole chiave osservate
+settings-notification-rules-no-bookmarks = Aggiungi un server ai segnalibri per impostarne le regole di notifica.
+channel-notification-all = Tutti i messaggi
+channel-notification-mentions = Solo menzioni
+channel-notification-mute = Silenzia
 
 # Sound names
 sound-none = Nessuno
@@ -879,6 +1428,8 @@ notification-chat-message = Nuovo messaggio di chat
 notification-chat-message-from = Chat da {$username}
 notification-connection-lost = Connessione persa
 notification-connection-lost-from = Disconnesso da {$server}
+notification-friend-online = Un amico è online
+notification-friend-online-name = {$username} è online
 notification-download-complete = Download completato
 notification-download-failed = Download fallito
 notification-news-post = Nuovo post di notizie
@@ -932,6 +1483,18 @@ ban-duration-7d = 7 giorni
 ban-duration-30d = 30 giorni
 ban-duration-permanent = Permanente
 
+# Broadcast target and delay options
+broadcast-target-label = Invia a:
+broadcast-target-all = Tutti
+broadcast-target-channel = Membri di un canale
+broadcast-target-permission = Utenti con un permesso
+broadcast-delay-label = Invia:
+broadcast-delay-now = Ora
+broadcast-delay-5m = Tra 5 minuti
+broadcast-delay-15m = Tra 15 minuti
+broadcast-delay-1h = Tra 1 ora
+broadcast-delay-1d = Tra 1 giorno
+
 # =============================================================================
 # Ban System
 # =============================================================================
@@ -981,12 +1544,32 @@ msg-untrusted-success = Voce di attendibilità rimossa con successo
 # Trust list messages
 msg-trust-list-header = IP attendibili:
 msg-trust-list-empty = Nessun IP attendibile
+msg-ldap-group-list-header = Gruppi della directory:
+msg-ldap-group-list-empty = Nessun gruppo della directory associato
+msg-ldap-group-admin = amministratore
+msg-ldap-group-no-permissions = nessun permesso
+msg-ldap-group-set = Gruppo della directory { $group } associato
+msg-ldap-group-removed = Gruppo della directory { $group } rimosso
+msg-guest-upgraded = Account { $username } creato. Accedi con esso la prossima volta per usarlo.
+msg-nickname-registered = Il soprannome { $nickname } è ora riservato a { $username }.
+msg-nickname-released = Soprannome { $nickname } liberato.
+msg-history-days = Il server conserva i tuoi messaggi privati per { $days } giorni.
+msg-history-off = Il server non conserva i tuoi messaggi privati. Usa /history <giorni> per attivarlo.
+msg-history-no-older = Nessun messaggio precedente con { $nickname }.
+err-history-days-too-long = La cronologia dei messaggi può essere conservata al massimo { $max } giorni
+err-history-more-tab = Usa /history more in una scheda di messaggi privati
 msg-trust-permanent = permanente
 msg-trust-expired = scaduto
 msg-trust-remaining = { $time } rimanente
 msg-trust-remaining-days = { $days }g { $hours }h
 msg-trust-remaining-hours = { $hours }h { $minutes }m
 msg-trust-remaining-minutes = { $minutes }m
+msg-leaderboard-header = Utenti più attivi per { $stat }:
+msg-leaderboard-empty = Nessuna attività registrata finora
+leaderboard-stat-logins = accessi
+leaderboard-stat-messages = messaggi
+leaderboard-stat-uploads = caricamenti
+leaderboard-stat-downloaded = dati scaricati
 
 # =============================================================================
 # Voice Chat
@@ -995,6 +1578,16 @@ msg-trust-remaining-minutes = { $minutes }m
 # Voice error messages
 err-voice-join = Impossibile entrare nella chat vocale: { $error }
 err-voice-leave = Impossibile uscire dalla chat vocale: { $error }
+err-voice-limit = Impossibile impostare il limite vocale: { $error }
+err-voice-clip = Soundboard: { $error }
+err-voice-text = Testo vocale: { $error }
+err-voice-clip-name-empty = Il nome della clip non può essere vuoto
+err-voice-clip-name-too-long = Il nome della clip è troppo lungo (max { $max } caratteri)
+err-voice-clip-name-invalid = I nomi delle clip possono contenere solo lettere, cifre, - e _
+err-voice-clip-too-large = La clip è troppo grande (max 256 KB)
+err-voice-clip-unsupported-type = Formato della clip non supportato (solo Ogg Vorbis)
+msg-voice-queued = Il vocale è pieno - sei il numero { $position } in coda
+msg-voice-queue-cancelled = Sei stato rimosso dalla coda vocale
 err-voice-no-token = Accesso alla chat vocale riuscito ma nessun token ricevuto
 err-voice-already-active = Sei già in una sessione vocale su un altro server
 err-voice-no-permission = Non hai il permesso di entrare nella chat vocale
@@ -1020,7 +1613,12 @@ msg-voice-joined-empty = Entrato nella chat vocale in { $target }
 msg-voice-left = Uscito dalla chat vocale in { $target }
 msg-voice-user-joined = { $nickname } è entrato nella chat vocale
 msg-voice-user-left = { $nickname } è uscito dalla chat vocale
+msg-voice-broadcast-started = { $nickname } sta trasmettendo in voce - unisciti per ascoltare
+msg-voice-broadcast-ended = La trasmissione vocale è terminata
+msg-voice-clip-played = { $nickname } ha riprodotto { $name }
+msg-voice-text = [voce] { $nickname }: { $message }
 msg-voice-you-left = Hai lasciato la chat vocale
+msg-voice-tcp-relay = Connessione UDP vocale non riuscita ({ $error }) - la voce passa attraverso la connessione al server
 
 # Voice UI
 voice-join-tooltip = Entra nella chat vocale
@@ -1028,6 +1626,8 @@ voice-leave-tooltip = Esci dalla chat vocale
 voice-mute-all-tooltip = Disattiva tutto
 voice-unmute-all-tooltip = Riattiva audio
 voice-bar-participants = ({ $count })
+voice-bar-queued = (in coda: { $position })
+voice-bar-listeners = ({ $count } in ascolto)
 voice-indicator-tooltip = In chat vocale
 tooltip-mute = Disattiva audio
 tooltip-unmute = Attiva audio
@@ -1038,6 +1638,12 @@ voice-quality-medium = Media (32 kbps)
 voice-quality-high = Alta (64 kbps)
 voice-quality-very-high = Molto alta (96 kbps)
 
+# Voice connection quality
+voice-connection-good = Buona
+voice-connection-fair = Discreta
+voice-connection-poor = Scarsa
+voice-connection-tooltip = Connessione: { $quality } ({ $loss }% di perdita, jitter { $jitter } ms)
+
 # PTT modes
 ptt-mode-hold = Tieni premuto per parlare
 ptt-mode-toggle = Attiva/Disattiva
@@ -1052,6 +1658,8 @@ ptt-delay-500ms = 500ms
 audio-output-device = Dispositivo di uscita
 audio-input-device = Dispositivo di ingresso
 audio-refresh-devices = Aggiorna dispositivi
+audio-clip-volume = Volume soundboard
+audio-voice-text-tts = Leggi ad alta voce i messaggi di testo vocali
 audio-voice-quality = Qualità vocale
 audio-ptt-key = Tasto Push-to-Talk
 audio-ptt-mode = Modalità PTT
@@ -1078,6 +1686,44 @@ mic-boost-6db = +6 dB
 mic-boost-12db = +12 dB
 mic-boost-18db = +18 dB
 
+# =============================================================================
+# Global Hotkeys
+# =============================================================================
+
+# Settings
+settings-toggle-window-key = Tasto mostra/nascondi finestra
+button-clear-hotkey = Cancella
+
+# Shortcut descriptions (shown by the desktop on Wayland)
+hotkey-push-to-talk = Premi per parlare
+hotkey-toggle-window = Mostra o nascondi Nexus
+
+# Errors
+err-window-hotkey-failed = Impossibile registrare il tasto mostra/nascondi finestra: { $error }
+err-global-shortcuts-unavailable = Portale delle scorciatoie globali non disponibile, i tasti funzionano solo con Nexus in primo piano: { $error }
+
+# =============================================================================
+# Auto Away
+# =============================================================================
+
+settings-auto-away = Assente automaticamente quando inattivo
+settings-auto-away-minutes = Minuti di inattività
+
+# =============================================================================
+# Connection Health
+# =============================================================================
+
+# Server list indicator
+connection-health-good = Buona
+connection-health-fair = Discreta
+connection-health-poor = Scarsa
+connection-health-tooltip = Connessione: { $quality }
+connection-health-latency = Latenza: { $ms } ms
+connection-health-jitter = Jitter: { $ms } ms
+connection-health-last-drop = Ultimo ping perso: { $time }
+connection-health-no-drops = Nessun ping perso
+connection-health-measuring = Misurazione della latenza…
+
 # =============================================================================
 # System Tray (Windows/Linux only)
 # =============================================================================
@@ -1106,14 +1752,33 @@ tray-tooltip-unread = Nexus BBS - { $count ->
    *[other] { $count } conversazioni non lette
 }
 
+# =============================================================================
+# Notification Actions (macOS/Windows only)
+# =============================================================================
+
+# Actions
+notification-reply = Rispondi
+notification-reply-send = Invia
+notification-reply-placeholder = Rispondi…
+notification-mark-read = Segna come letto
+
 # =============================================================================
 # Toast Notifications
 # =============================================================================
 
 toast-link-copied = Link copiato negli appunti
 toast-copied = Copiato negli appunti
+toast-checksum-copied = SHA-256 copiato negli appunti
 toast-download-started = Download di { $filename }
 toast-download-queued = Download in coda: { $filename }
+toast-downloads-started = Download di { $count } { $count ->
+    [one] file
+   *[other] file
+}
+toast-downloads-queued = { $count } { $count ->
+    [one] download
+   *[other] download
+} in coda
 toast-upload-started = Upload di { $filename }
 toast-upload-queued = Upload in coda: { $filename }
 toast-uploads-started = Upload di { $count } { $count ->
@@ -1124,3 +1789,28 @@ toast-uploads-queued = { $count } { $count ->
     [one] upload
    *[other] upload
 } in coda
+toast-upload-nothing-new = Niente da caricare: il server ha già questi file
+toast-upload-watch-started = Monitoraggio di { $folder }: i nuovi file verranno caricati automaticamente
+toast-upload-watch-stopped = Monitoraggio di { $folder } interrotto
+toast-auto-download-started = Download automatico di { $pattern } da { $folder }
+toast-auto-download-stopped = Download automatico da { $folder } interrotto
+toast-screenshot-folder-set = Gli screenshot verranno caricati in questa cartella
+toast-chat-exported = Chat esportata
+
+# =============================================================================
+# First-run Tips
+# =============================================================================
+
+tip-welcome-title = Benvenuto in Nexus
+tip-welcome = Inserisci qui sotto l'indirizzo di un server e connettiti. Aggiungi un segnalibro per tenere il server, con il tuo accesso, nell'elenco a sinistra, a un clic di distanza la prossima volta.
+tip-fingerprint = Ogni server ha un'impronta del certificato, come una firma. Nexus la ricorda alla prima connessione e ti avvisa se cambia, perché potrebbe significare che qualcuno si sta spacciando per il server. Accetta una nuova impronta solo se l'amministratore del server ti ha detto che è cambiata.
+tip-server-list = I tuoi segnalibri e le connessioni aperte sono a sinistra. Fai clic su uno per cambiare server o trascina i segnalibri per riordinarli.
+tip-toolbar = La barra degli strumenti apre file, notizie, impostazioni e altro. Passa il mouse su un'icona per vedere cosa fa.
+tip-chat = Qui chatti con tutti sul server. Digita / per vedere i comandi disponibili, come /join per entrare in un canale.
+tip-user-list = Gli utenti online sono elencati a destra. Fai clic su un nome per scrivergli o vedere le sue informazioni.
+tip-counter = Suggerimento { $current } di { $total }
+button-add-bookmark = Aggiungi segnalibro
+button-tip-next = Avanti
+button-tip-done = Fatto
+button-tip-got-it = Ho capito
+button-skip-tips = Salta suggerimenti
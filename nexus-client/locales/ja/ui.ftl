@@ -11,6 +11,13 @@ button-connect = 接続
 button-save = 保存
 button-create = 作成
 button-edit = 編集
+button-check-ports = ポートを確認
+button-statistics = 統計
+button-dashboard = ダッシュボード
+button-refresh = 更新
+button-doors = ドア
+button-doors-leave = 退出
+button-doors-back = 戻る
 button-update = 更新
 button-accept-new-certificate = 新しい証明書を受け入れる
 button-close = 閉じる
@@ -18,9 +25,23 @@ button-choose-avatar = アバターを選択
 button-clear-avatar = クリア
 button-choose-image = 画像を選択
 button-clear-image = クリア
+button-add-file = ファイルを追加
 button-overwrite = 上書き
 button-change-password = パスワードを変更
 button-browse = 参照
+button-undo = 元に戻す
+button-add = 追加
+button-remove = 削除
+button-view = 表示
+button-hide = 非表示
+button-export = エクスポート…
+button-trust-certificate = 証明書を信頼
+button-skip = スキップ
+button-skip-existing = 既存をスキップ
+button-download-anyway = それでもダウンロード
+button-unlock = ロック解除
+button-view-as-user = ユーザーとして表示
+button-post-comment = 投稿
 
 # =============================================================================
 # Titles
@@ -35,19 +56,29 @@ title-user-create = ユーザー作成
 title-user-edit = ユーザー編集
 title-update-user = ユーザー更新
 title-user-management = ユーザー管理
+title-permission-audit = 権限の監査
+title-impersonate = { $username } として表示中
 title-confirm-delete = 削除確認
 title-connected = 接続中
 title-settings = 設定
 title-bookmarks = ブックマーク
 title-users = ユーザー
+title-friends = フレンド
+friend-online = オンライン
+friend-offline = オフライン
 title-edit-server-info = サーバー情報を編集
 title-fingerprint-mismatch = 証明書のフィンガープリントが一致しません！
+title-fingerprint-new-server = 新しいサーバー証明書
+title-create-password-vault = 保存済みパスワードの保護
+title-unlock-password-vault = 保存済みパスワードのロック解除
+title-unlock-config = 設定のロック解除
 title-server-info = サーバー情報
 title-user-info = ユーザー情報
 title-about = アプリについて
 title-change-password = パスワードを変更
 title-news = ニュース
 title-transfers = 転送
+title-transfer-history = 転送履歴
 title-news-create = 投稿を作成
 title-news-edit = 投稿を編集
 title-channel-members = メンバー
@@ -58,10 +89,14 @@ title-channel-members = メンバー
 
 placeholder-username = ユーザー名
 placeholder-password = パスワード
+placeholder-master-passphrase = マスターパスフレーズ
+placeholder-confirm-master-passphrase = マスターパスフレーズの確認
 placeholder-port = ポート
 placeholder-server-address = サーバーアドレス
 placeholder-server-name = サーバー名
+placeholder-folder-name = フォルダ名
 placeholder-username-optional = ユーザー名（任意）
+placeholder-fallback-addresses-optional = 代替アドレス（任意、カンマ区切り）
 placeholder-password-optional = パスワード（任意）
 placeholder-password-keep-current = パスワード
 placeholder-current-password = 現在のパスワード
@@ -70,10 +105,18 @@ placeholder-confirm-password = パスワードを確認
 placeholder-message = メッセージを入力…
 placeholder-no-permission = 権限がありません
 placeholder-broadcast-message = ブロードキャストメッセージ
+placeholder-broadcast-channel = チャンネルを選択
+placeholder-broadcast-permission = 権限を選択
 placeholder-server-description = サーバー説明
 placeholder-news-body = ニュース内容
+placeholder-news-caption = キャプション（任意）
+placeholder-news-file-path = ファイルエリア内のパス
 placeholder-nickname-optional = ニックネーム（任意）
+placeholder-status-optional = ステータス（任意）
+placeholder-download-folder-optional = ダウンロードフォルダ（任意）
 placeholder-proxy-address = プロキシアドレス
+placeholder-notification-rules-channel = #チャンネル
+placeholder-notification-rules-keyword = キーワード
 placeholder-proxy-port = 9050
 placeholder-proxy-username = ユーザー名（任意）
 placeholder-proxy-password = パスワード（任意）
@@ -86,13 +129,20 @@ placeholder-auto-join-channels = #チャンネル1 #チャンネル2（スペー
 # =============================================================================
 
 label-auto-connect = 自動接続
+label-server-avatar = アバター:
 label-add-bookmark = ブックマークに追加
+label-lan-servers = このネットワーク上
+label-use-sso = シングルサインオン
 label-admin = 管理者
 label-shared-account = 共有アカウント
 label-enabled = 有効
+label-erase-personal-data = 個人データも消去する
+label-broadcast-require-ack = 受信者に確認を求める
 label-permissions = 権限:
 label-expected-fingerprint = 期待されるフィンガープリント:
 label-received-fingerprint = 受信したフィンガープリント:
+label-certificate-fingerprint = 証明書のフィンガープリント:
+label-identity-key = ID キー:
 label-theme = テーマ
 label-chat-history-retention = チャット履歴:
 chat-history-forever = 永久
@@ -100,6 +150,10 @@ chat-history-30-days = 30日
 chat-history-14-days = 14日
 chat-history-7-days = 7日
 chat-history-disabled = 無効
+collision-policy-rename = 両方保持（名前を変更）
+collision-policy-overwrite = 上書き
+collision-policy-skip = スキップ
+collision-policy-ask = 確認する
 label-max-scrollback = 最大履歴:
 label-chat-font-size = フォントサイズ:
 label-show-connection-events = 接続/切断イベントを表示
@@ -107,6 +161,19 @@ label-show-channel-events = 参加/退出イベントを表示
 label-show-timestamps = タイムスタンプを表示
 label-use-24-hour-time = 24時間形式を使用
 label-show-seconds = 秒を表示
+label-split-view = ファイル、ニュース、接続モニターをチャットの横に表示
+label-spell-check = チャットとニュースでスペルチェックを行う
+label-spell-check-no-dictionary = お使いの言語の辞書が見つかりません。.aff と .dic ファイルを config.json と同じ場所の dictionaries フォルダーに置いてください。
+label-send-read-receipts = ユーザーメッセージの既読通知を送信
+label-user-list-grouping = ユーザー一覧のグループ化:
+label-user-list-sort = ユーザー一覧の並び順:
+user-list-group-none = なし
+user-list-group-admins = 管理者を先頭に
+user-list-group-channel = チャンネル別
+user-list-group-status = 状態別
+user-list-sort-alphabetical = 名前順
+user-list-sort-idle = アイドル時間
+user-list-sort-connect = 接続時間
 label-server-name = 名前:
 label-server-description = 説明:
 label-server-version = バージョン:
@@ -137,6 +204,27 @@ tab-files = ファイル
 tab-channels = チャンネル
 label-connections-short = 接続:
 label-transfers-short = 転送:
+label-active-transfers-short = アクティブ:
+label-queue-order-short = キュー順序:
+label-trusted-weight-short = 信頼済みの重み:
+label-nat-mapping-short = ポートマッピング:
+label-nat-external-ip-short = 外部IP:
+label-nat-not-mapped = 未マッピング
+connectivity-check-running = ポートを確認中…
+check-service-bbs = BBS
+check-service-transfer = 転送
+check-service-websocket = WebSocket
+check-service-transfer-websocket = WebSocket 転送
+check-service-voice = ボイス
+check-state-open = 開放
+check-state-closed = 拒否
+check-state-filtered = フィルタ
+check-state-untested = 未確認
+label-check-port = { $service } ({ $protocol } { $port }):
+label-queue-order-small-first = 小さいファイルを優先
+label-queue-order-arrival = 到着順
+label-small-files-first = 小さいファイルを優先してキューに入れる
+label-unlimited = 無制限
 label-reindex-short = 再索引:
 label-persistent-short = 常設:
 label-auto-join-short = 自動参加:
@@ -153,6 +241,19 @@ label-download-location = ダウンロード場所:
 label-queue-transfers = 転送をキューに追加
 label-download-limit = ダウンロード制限:
 label-upload-limit = アップロード制限:
+label-auto-open-folder = ダウンロード完了時にフォルダを開く
+label-collision-policy = ファイルが存在する場合:
+label-require-fingerprint-approval = 新しいサーバーの証明書を信頼する前に確認する
+label-known-servers = 既知のサーバー
+known-servers-empty = 保存されたサーバー証明書はまだありません
+known-server-first-seen = 初回確認 { $date }
+known-server-first-seen-unknown = 初回確認: 不明
+label-encrypt-config = マスターパスフレーズで設定、ブックマーク、チャット履歴を暗号化する
+config-encryption-locked-note = 設定はロックされています。変更するには Nexus を再起動してマスターパスフレーズを入力してください。
+label-upload-rule-extensions = 許可するファイル形式:
+label-upload-rule-max-file-size = 最大ファイルサイズ (MB):
+label-upload-rule-max-total-size = 最大アップロードサイズ (MB):
+label-auto-download-pattern = ファイル名:
 label-persistent-channels = 常設チャンネル:
 label-auto-join-channels = 自動参加チャンネル:
 label-channels = チャンネル
@@ -166,11 +267,14 @@ permission-user_info = ユーザー情報
 permission-chat_send = チャット送信
 permission-chat_receive = チャット受信
 permission-chat_join = チャンネル参加
+permission-chat_language = チャット言語
 permission-chat_create = チャンネル作成
 permission-chat_list = チャンネル一覧
 permission-chat_secret = チャンネル秘密設定
 permission-chat_topic = チャットトピック
 permission-chat_topic_edit = チャットトピック編集
+permission-chat_topic_lock = チャットトピックロック
+permission-chat_entry_edit = チャット入室メッセージ編集
 permission-user_broadcast = ユーザーブロードキャスト
 permission-user_create = ユーザー作成
 permission-user_delete = ユーザー削除
@@ -181,6 +285,7 @@ permission-news_list = ニュース一覧
 permission-news_create = ニュース作成
 permission-news_edit = ニュース編集
 permission-news_delete = ニュース削除
+permission-file_comment = ファイルへのコメント
 permission-file_copy = ファイルコピー
 permission-file_create_dir = ディレクトリ作成
 permission-file_delete = ファイル削除
@@ -200,6 +305,11 @@ permission-trust_create = 信頼作成
 permission-trust_delete = 信頼削除
 permission-trust_list = 信頼リスト
 permission-connection_monitor = 接続モニター
+permission-log_view = ログ表示
+permission-door_play = ドアのプレイ
+permission-voice_broadcast = ボイス配信
+permission-voice_clip_upload = ボイスクリップのアップロード
+permission-voice_limit = ボイス上限
 permission-voice_listen = ボイス受信
 permission-voice_talk = ボイス送信
 
@@ -224,19 +334,31 @@ tooltip-files-paste = 貼り付け
 tooltip-files-search = ファイル検索
 tooltip-manage-users = ユーザー管理
 tooltip-connection-monitor = 接続モニター
+tooltip-server-log = サーバーログ
 tooltip-server-info = サーバー情報
 tooltip-about = アプリについて
 tooltip-transfers = 転送
 tooltip-download-all = すべてダウンロード
 tooltip-upload = アップロード
 file-picker-upload-title = アップロードするファイルを選択
+file-picker-download-to-title = ダウンロードフォルダを選択
+folder-picker-upload-watch-title = 自動アップロードするフォルダーを選択
 drop-to-upload = ドロップしてアップロード
 tooltip-clear-inactive = 非アクティブを消去
+tooltip-transfer-history = 転送履歴
+tooltip-transfer-history-clear = 履歴を消去
+tooltip-transfer-history-remove = 履歴から削除
 tooltip-transfer-pause = 一時停止
 tooltip-transfer-resume = 再開
 tooltip-transfer-cancel = キャンセル
 tooltip-transfer-remove = 削除
 tooltip-transfer-open-folder = フォルダを開く
+tooltip-transfer-open-file = ファイルを開く
+tooltip-transfer-reveal = ファイルマネージャーで表示
+tooltip-transfer-copy-checksum = SHA-256 をコピー
+tooltip-transfer-collision-rename = 両方保持（名前を変更）
+tooltip-transfer-collision-overwrite = 既存のファイルを上書き
+tooltip-transfer-collision-skip = 既存のファイルをスキップ
 tooltip-transfer-move-up = 上に移動
 tooltip-transfer-move-down = 下に移動
 tooltip-transfer-retry = 再試行
@@ -249,16 +371,34 @@ tooltip-disconnect = 切断
 tooltip-edit = 編集
 tooltip-info = 情報
 tooltip-message = メッセージ
+tooltip-add-friend = フレンドに追加
+tooltip-remove-friend = フレンドから削除
 tooltip-kick = キック
 tooltip-close = 閉じる
+tooltip-retry-send = 再送信
+tooltip-cancel-send = 送信を取り消す
+tooltip-message-sent = 送信済み
+tooltip-message-delivered = 配信済み
+tooltip-message-read = 既読
 tooltip-new-tab = 新しいタブ
 tooltip-close-tab = タブを閉じる
 tooltip-add-bookmark = ブックマークを追加
+tooltip-new-folder = 新しいフォルダ
 tooltip-create-user = ユーザーを作成
+tooltip-permission-audit = 権限を監査
 tooltip-create-news = 投稿を作成
+tooltip-news-search = ニュースを検索
+tooltip-news-search-clear = 検索をクリア
 tooltip-delete = 削除
 context-menu-download = ダウンロード
+context-menu-download-to = 保存先を指定してダウンロード…
+context-menu-download-select = 選択してダウンロード…
+context-menu-auto-download = 自動ダウンロード…
 context-menu-upload = アップロード
+context-menu-watch-folder = ローカルフォルダーを監視…
+context-menu-screenshot-folder = ここでスクリーンショットを共有
+context-menu-unwatch-folder = フォルダーの監視を停止
+context-menu-upload-rules = アップロードルール…
 context-menu-open = 開く
 
 # =============================================================================
@@ -266,6 +406,7 @@ context-menu-open = 開く
 # =============================================================================
 
 panel-connection-monitor = 接続モニター
+panel-server-log = サーバーログ
 panel-active-connections = アクティブ接続: { $count }
 tab-connections = 接続
 tab-transfers = 転送
@@ -280,9 +421,97 @@ menu-info = 情報
 menu-copy = コピー
 menu-kick = キック
 menu-ban = 禁止
+menu-pop-out = 別ウィンドウで開く
+menu-mute-tab = ミュート
+menu-unmute-tab = ミュート解除
+menu-connect-all = すべて接続
+menu-rename = 名前を変更
+menu-delete = 削除
 connection-monitor-loading = 接続を読み込み中…
 connection-monitor-no-connections = 接続なし
 connection-monitor-no-transfers = アクティブな転送なし
+connection-monitor-queued = 待機中
+connection-monitor-weight = 公平配分の重み: ×{ $weight }
+connection-monitor-listed = { $zone } に登録されています
+connection-monitor-queued-count = { $count } 件待機中
+placeholder-server-log-module = モジュールで絞り込み
+server-log-loading = サーバーログを読み込み中…
+server-log-empty = ログはありません
+server-log-level-error = エラーのみ
+server-log-level-warn = 警告以上
+server-log-level-info = 情報以上
+server-log-level-debug = すべて（デバッグ）
+panel-statistics = 統計
+stats-loading = 統計を読み込み中…
+panel-snippet = スニペット
+snippet-loading = スニペットを読み込み中…
+snippet-details = { $author } が共有 · { $expires } に期限切れ
+stats-range-days = 過去 { $days } 日間
+stats-chart-max = 最大の日: { $value }
+stats-metric-logins = ログイン
+stats-metric-messages = メッセージ
+stats-metric-uploads = アップロード
+stats-metric-upload-bytes = アップロード量
+stats-metric-downloads = ダウンロード
+stats-metric-download-bytes = ダウンロード量
+stats-metric-peak-sessions = 最大同時接続ユーザー
+panel-admin-dashboard = 管理ダッシュボード
+dashboard-loading = ダッシュボードを読み込み中…
+dashboard-connections = 接続
+dashboard-sessions = オンラインのユーザー
+dashboard-connection-count = 接続数
+dashboard-transfer-connections = 転送接続
+dashboard-transfers = 転送
+dashboard-active-transfers = 実行中
+dashboard-queued-transfers = 待機中
+dashboard-today = 今日
+dashboard-disk = ファイル領域
+dashboard-disk-free = 空き容量
+dashboard-disk-free-of = { $total } 中 { $free } 空き
+dashboard-disk-unknown = 不明
+dashboard-recent-audit = 最近の監査イベント
+dashboard-audit-empty = 監査イベントはまだありません
+dashboard-updated = { $time } に更新
+panel-doors = ドア
+doors-loading = ドアを読み込み中…
+doors-empty = このサーバーにはドアがありません
+doors-starting = 起動中…
+doors-playing-hint = キー入力はドアに送られます。終了するには退出を押してください。
+doors-exited = ドアが終了しました
+doors-exited-code = ドアがコード { $code } で終了しました
+
+# =============================================================================
+# Quick Switcher
+# =============================================================================
+
+quick-switcher-placeholder = チャンネル、メッセージ、ブックマーク、パネルへ移動...
+user-list-filter-placeholder = ユーザーを絞り込む...
+user-list-section-admins = 管理者
+user-list-section-users = ユーザー
+user-list-section-no-channel = その他
+user-list-section-online = オンライン
+user-list-section-away = 離席中
+user-list-section-dnd = おやすみモード
+user-list-idle = アイドル { $duration }
+quick-switcher-no-matches = 一致する項目がありません
+quick-switcher-bookmark = ブックマーク
+
+# =============================================================================
+# Chat Input
+# =============================================================================
+
+chat-paste-prompt = { $count } 行を貼り付けますか？
+chat-paste-too-long-prompt = 1 つのメッセージには長すぎます (最大 { $max })。送信しますか?
+button-send-as-messages = { $count } 件のメッセージとして送信
+button-send-as-one-message = 1 件のメッセージとして送信
+button-send-in-parts = { $count } 回に分けて送信
+button-share-as-snippet = スニペットとして共有
+button-expand-code = 全 { $count } 行を表示
+button-collapse-code = 折りたたむ
+chat-broadcast-ack-prompt = { $username } がブロードキャストの確認を求めています
+button-acknowledge = 確認
+button-dismiss = 閉じる
+spell-no-suggestions = 候補がありません
 
 # =============================================================================
 # Empty States
@@ -293,11 +522,35 @@ empty-no-connections = 接続なし
 empty-no-bookmarks = ブックマークなし
 empty-no-users = オンラインユーザーなし
 empty-no-channel-members = チャンネルにメンバーがいません
+empty-no-matching-users = 一致するユーザーはいません
 user-management-loading = ユーザーを読み込み中…
 user-management-no-users = ユーザーが見つかりません
+permission-audit-hint = 権限を選ぶと保持者を表示し、ユーザーをクリックするとそのユーザーができることをすべて表示します。
+permission-audit-loading = 読み込み中…
+permission-audit-no-holders = この権限を持つアカウントはありません
+permission-audit-effective = { $username } の有効な権限
+permission-audit-admin = 管理者はすべての権限を持ちます
+permission-audit-no-permissions = このアカウントには権限がありません
+permission-audit-restricted = 付与済みですが共有アカウントでは使用できません:
+permission-audit-disabled = 無効
+impersonate-notice = このアカウントから見える内容の読み取り専用ビューです。表示のたびに監査ログに記録されます。
+impersonate-permissions = 権限
+impersonate-files = ファイル ({ $path })
+impersonate-no-file-access = このアカウントはファイルを閲覧できません
+impersonate-empty-folder = このフォルダーは空です
+impersonate-channels = チャンネル
+impersonate-no-channel-access = このアカウントはチャンネル一覧を表示できません
+impersonate-no-channels = 表示できるチャンネルはありません
+impersonate-secret = シークレット
 news-loading = ニュースを読み込み中…
 news-no-posts = まだ投稿がありません
+news-search-placeholder = ニュースを検索… (author:名前 since:YYYY-MM-DD until:YYYY-MM-DD)
+news-searching = 検索中…
+news-search-no-results = 一致する投稿はありません
 transfers-empty = 転送なし
+transfer-history-search-placeholder = 名前、サーバー、フォルダーで検索…
+transfer-history-empty = 完了した転送はまだありません
+transfer-history-no-matches = 検索に一致する転送はありません
 news-updated = 更新日時
 files-loading = ファイルを読み込み中…
 files-empty = このディレクトリは空です
@@ -309,10 +562,16 @@ files-column-name = 名前
 files-column-size = サイズ
 files-column-modified = 更新日時
 files-column-path = パス
+files-column-rating = 評価
 files-search-placeholder = ファイルを検索…
 files-search-breadcrumb = 検索 - { $query }
 files-searching = 検索中…
 files-no-results = ファイルが見つかりません
+files-highlights = ハイライト
+files-highlights-recent = 今週の新着アップロード
+files-highlights-popular = ダウンロード数の多いファイル
+files-highlights-empty = まだありません
+files-highlights-downloads = { $count } 回
 files-search-query-too-short = 検索クエリが短すぎます（最小{ $min_length }文字）
 files-search-query-too-long = 検索クエリが長すぎます（最大{ $max_length }文字）
 files-search-query-invalid = 検索クエリに無効な文字が含まれています
@@ -323,6 +582,24 @@ files-share = 共有
 files-rename = 名前を変更
 files-rename-title = 「{ $name }」の名前を変更
 files-rename-placeholder = 新しい名前
+files-upload-rule-title = 「{ $name }」のアップロードルール
+files-upload-rule-loading = 読み込み中…
+files-upload-rule-extensions-placeholder = すべての形式 (例: mp3, flac)
+files-upload-rule-no-limit = 制限なし
+files-upload-rule-remove = ルールを削除
+files-upload-rule-help = ルールはこのフォルダーとそのサブフォルダーに適用されます (独自のルールを持つサブフォルダーを除く)。
+files-auto-download-title = 「{ $name }」からの自動ダウンロード
+files-auto-download-pattern-placeholder = 例: *.mp3, *.m4a
+files-auto-download-remove = 自動ダウンロードを停止
+files-auto-download-help = このブックマークで接続している間、名前が一致するこのフォルダーの新しいファイルがダウンロードされます。* は任意の文字列、? は 1 文字に一致します。複数のパターンはカンマで区切ってください。ダウンロード済みのファイルはスキップされます。
+files-upload-rule-hint = このフォルダーへのアップロード: { $limits }
+files-upload-rule-hint-types = { $extensions } のみ
+files-upload-rule-hint-file-size = 1ファイル { $size } まで
+files-upload-rule-hint-total-size = 1回 { $size } まで
+files-upload-preview-title = ファイルは既に存在します
+files-upload-preview-comparing = サーバー上のファイルと比較中…
+files-upload-preview-summary = 新規 { $new } 件、サーバーに既存 { $identical } 件、内容が異なる { $conflicts } 件:
+files-upload-preview-blocked = { $count } 件のファイルはサーバー上に同名のフォルダーがあるためスキップされます
 files-cut = 切り取り
 files-copy = コピー
 files-paste = 貼り付け
@@ -332,6 +609,13 @@ files-delete-confirm-title = 削除の確認
 files-delete-confirm-message = "{ $name }" を削除してもよろしいですか？
 # File Overwrite Dialog
 files-overwrite-title = ファイルが既に存在します
+files-download-to-title = { $name } をダウンロード
+label-remember-for-server = このサーバーで記憶する
+label-select-all = すべて選択
+files-download-select-summary = { $total } 件中 { $count } 件を選択 ({ $size })
+files-download-select-too-many = 個別に選択できるファイルは最大 { $max } 件です。代わりにフォルダー全体をダウンロードしてください。
+files-download-duplicate-title = ダウンロード済み
+files-download-duplicate-message = 「{ $name }」はこのコンピューターの { $path } に既にあります。もう一度ダウンロードしますか？
 files-overwrite-message = "{ $name }" という名前のファイルまたはフォルダが宛先に既に存在します。置き換えますか？
 
 # File Info Dialog
@@ -347,6 +631,14 @@ files-info-directory = ディレクトリ
 files-info-symlink = シンボリックリンク：
 files-info-yes = はい
 files-info-na = N/A
+files-info-rating = 評価:
+files-info-rating-value = { $average }（{ $count } 件の評価）
+files-info-not-rated = まだ評価されていません
+files-info-your-rating = あなたの評価:
+files-info-comments = コメント（{ $count }）
+files-comments-loading = コメントを読み込み中…
+files-comments-empty = まだコメントはありません
+files-comment-placeholder = コメントを書く…
 
 # =============================================================================
 # Directory Name Validation Errors
@@ -365,6 +657,7 @@ err-dir-name-invalid = フォルダ名に無効な文字が含まれています
 tab-general = 一般
 tab-chat = チャット
 tab-network = ネットワーク
+tab-security = セキュリティ
 tab-audio = オーディオ
 
 # =============================================================================
@@ -386,6 +679,8 @@ chat-prefix-system = [システム]
 chat-prefix-error = [エラー]
 chat-prefix-info = [情報]
 chat-prefix-broadcast = [BROADCAST]
+chat-prefix-queued = [PENDING]
+chat-export-subtitle = { $server } から { $date } にエクスポート
 
 # =============================================================================
 # Success Messages
@@ -406,11 +701,25 @@ msg-channel-list-header = 利用可能なチャンネル:
 msg-no-channels = 利用可能なチャンネルがありません
 msg-channel-member-count = { $count } メンバー
 channel-secret = シークレット
+channel-info-topic = トピック
+channel-info-rules = ルール
+channel-info-language = 言語
 msg-broadcast-sent = ブロードキャストを正常に送信しました
+msg-broadcast-delivered = ブロードキャストを { $count } 人に配信しました
+msg-broadcast-scheduled = ブロードキャストを { $time } に予約しました
+msg-broadcast-acknowledged = ブロードキャストは { $delivered } 人中 { $count } 人が確認しました
+msg-storage-low = サーバーの{ $volume }の空き容量が不足しています: 空き { $free }（最小 { $threshold }）
+msg-storage-recovered = サーバーの{ $volume }の空き容量が回復しました: 空き { $free }
+storage-volume-files = ファイル領域
+storage-volume-database = データベース
+msg-friend-added = { $username } をフレンドに追加しました
+msg-friend-removed = { $username } をフレンドから削除しました
 msg-user-created = ユーザーを正常に作成しました
 msg-user-created-name = ユーザー '{ $username }' を正常に作成しました
 msg-user-deleted = ユーザーを正常に削除しました
 msg-user-deleted-name = ユーザー '{ $username }' を正常に削除しました
+msg-user-erased = ユーザーを削除し、個人データを消去しました
+msg-user-erased-name = ユーザー '{ $username }' を削除し、個人データを消去しました
 msg-user-updated = ユーザーを正常に更新しました
 msg-user-updated-name = ユーザー '{ $username }' を正常に更新しました
 msg-permissions-updated = 権限が更新されました
@@ -425,6 +734,8 @@ msg-welcome-back = おかえりなさい
 msg-status-set = ステータスを設定: { $status }
 msg-status-cleared = ステータスをクリア
 msg-user-is-away = { $nickname } は離席中です
+msg-group-member-joined = { $nickname } が会話に参加しました
+msg-group-member-left = { $nickname } が会話から退出しました
 msg-user-is-away-status = { $nickname } は離席中です: { $status }
 
 
@@ -436,6 +747,14 @@ msg-topic-cleared = { $username } によってトピックがクリアされま
 msg-topic-set = { $username } によってトピックが設定されました: { $topic }
 msg-secret-set = { $username } によってシークレットモードが有効化されました
 msg-secret-cleared = { $username } によってシークレットモードが無効化されました
+msg-topic-locked = { $username } によってトピックがロックされました
+msg-topic-unlocked = { $username } によってトピックのロックが解除されました
+msg-rules-set = { $username } がチャンネルルールを更新しました
+msg-rules-cleared = { $username } がチャンネルルールをクリアしました
+msg-language-set = { $username } がチャンネルの言語を { $language } に設定しました
+msg-language-cleared = { $username } がチャンネルの言語をクリアしました
+msg-voice-limit-set = { $username } がボイス上限を { $limit } に設定しました
+msg-voice-limit-cleared = { $username } がボイス上限を解除しました
 msg-server-info-updated = サーバー設定が更新されました
 msg-topic-display = トピック: { $topic }
 confirm-delete-user = ユーザー '{ $username }' を削除してもよろしいですか？
@@ -455,6 +774,7 @@ err-user-kick-failed = ユーザーのキックに失敗しました
 err-unknown = 不明なエラー
 err-no-shutdown-handle = 接続エラー: シャットダウンハンドルがありません
 err-userlist-failed = ユーザーリストの更新に失敗しました
+err-request-timeout = サーバーが時間内に応答しませんでした
 err-port-invalid = ポートは有効な数字である必要があります（1-65535）
 
 # Network connection errors
@@ -468,12 +788,16 @@ err-connection-closed = 接続が閉じられました
 err-could-not-determine-config-dir = 設定ディレクトリを特定できませんでした
 err-message-too-long = メッセージが長すぎます（{ $length }文字、最大{ $max }文字）
 err-send-failed = メッセージの送信に失敗しました
+err-checksum-failed = チェックサムの計算に失敗しました
 err-no-chat-permission = メッセージを送信する権限がありません
+err-chat-window-command = チャットウィンドウから送信できるのはメッセージと /me のみです
 err-console-no-send = /{ $join }でチャンネルに参加するか、/{ $msg }でユーザーにメッセージを送信してください
 err-broadcast-too-long = ブロードキャストが長すぎます（{ $length }文字、最大{ $max }文字）
 err-broadcast-send-failed = ブロードキャストの送信に失敗しました
+err-broadcast-target-required = ブロードキャストの送信先を選択してください
 err-name-required = ブックマーク名は必須です
 err-address-required = サーバーアドレスは必須です
+err-fallback-address-invalid = 無効な代替アドレス: { $address }
 err-port-required = ポートは必須です
 err-username-required = ユーザー名は必須です
 err-password-required = パスワードは必須です
@@ -491,13 +815,20 @@ err-nickname-too-long = ニックネームが長すぎます（最大{ $max }文
 err-nickname-invalid = ニックネームに無効な文字が含まれています
 err-password-too-long = パスワードが長すぎます（最大{ $max }文字）
 err-passwords-do-not-match = パスワードが一致しません
+err-wrong-master-passphrase = マスターパスフレーズが間違っています
+err-master-passphrase-empty = マスターパスフレーズを入力してください
+err-password-vault = パスワード保管庫のエラー: { $error }
 err-current-password-required = 現在のパスワードは必須です
 err-new-password-required = 新しいパスワードは必須です
 err-confirm-password-required = 新しいパスワードを確認してください
 err-topic-too-long = トピックが長すぎます（{ $length }文字、最大{ $max }文字）
+err-entry-message-too-long = 入室メッセージが長すぎます（{ $length }文字、最大{ $max }文字）
+err-rules-too-long = ルールが長すぎます（{ $length }文字、最大{ $max }文字）
 err-avatar-unsupported-type = サポートされていないファイル形式です。PNG、WebP、JPEG、またはSVGを使用してください。
 err-avatar-too-large = アバターが大きすぎます。最大サイズは{ $max_kb }KBです。
 err-avatar-decode-failed = アバターのデコードに失敗しました。ファイルが破損している可能性があります。
+err-known-server-export = 証明書をエクスポートできませんでした: { $error }
+err-config-encryption = 設定を暗号化できませんでした: { $error }
 err-server-name-empty = サーバー名は空にできません
 err-server-name-too-long = サーバー名が長すぎます（最大 { $max } 文字）
 err-server-name-contains-newlines = サーバー名に改行を含めることはできません
@@ -518,14 +849,23 @@ err-failed-send-update = 更新の送信に失敗しました: { $error }
 # =============================================================================
 
 err-failed-save-config = 設定の保存に失敗しました: { $error }
+err-friends-no-bookmark = フレンドはサーバーのブックマークに保存されます。先にブックマークしてください
+err-friends-too-many = フレンドが多すぎます（最大 { $max }）
+err-friend-exists = { $username } はすでにフレンドです
+err-friend-not-found = { $username } はフレンドではありません
 err-failed-save-settings = 設定の保存に失敗しました: { $error }
 err-invalid-port-bookmark = ブックマークのポートが無効です: { $name }
 err-failed-send-broadcast = ブロードキャストの送信に失敗しました: { $error }
 err-failed-send-message = メッセージの送信に失敗しました: { $error }
+err-failed-start-group = グループ会話を開始できませんでした: { $error }
+err-failed-share-snippet = スニペットを共有できませんでした: { $error }
 err-failed-create-user = ユーザーの作成に失敗しました: { $error }
 err-failed-delete-user = ユーザーの削除に失敗しました: { $error }
 err-failed-update-user = ユーザーの更新に失敗しました: { $error }
 err-failed-update-topic = トピックの更新に失敗しました: { $error }
+err-failed-lock-topic = トピックのロックに失敗しました: { $error }
+err-failed-set-language = チャンネルの言語の設定に失敗しました: { $error }
+err-failed-update-entry = チャンネルの更新に失敗しました: { $error }
 err-message-too-long-details = { $error }（{ $length }文字、最大{ $max }）
 
 # Network connection errors (with parameters)
@@ -537,6 +877,14 @@ err-tls-handshake-failed = TLSハンドシェイクに失敗しました: { $err
 err-failed-send-handshake = ハンドシェイクの送信に失敗しました: { $error }
 err-failed-read-handshake = ハンドシェイク応答の読み取りに失敗しました: { $error }
 err-handshake-failed = ハンドシェイクに失敗しました: { $error }
+err-proof-of-work-too-hard = サーバーが要求するプルーフ・オブ・ワークが大きすぎます（難易度 { $difficulty }）
+err-sso-not-offered = このサーバーはシングルサインオンに対応していません
+err-sso-failed = シングルサインオンに失敗しました: { $error }
+err-sso-browser = ブラウザーを開けませんでした: { $error }
+err-sso-timeout = ブラウザーでのシングルサインオンがタイムアウトしました
+err-sso-state-mismatch = シングルサインオンの応答が要求と一致しません
+sso-browser-done = サインインしました。このウィンドウを閉じて Nexus に戻ってください。
+sso-browser-failed = サインインに失敗しました。このウィンドウを閉じて Nexus に戻ってください。
 err-failed-parse-handshake = ハンドシェイク応答の解析に失敗しました: { $error }
 err-failed-send-login = ログインの送信に失敗しました: { $error }
 err-failed-read-login = ログイン応答の読み取りに失敗しました: { $error }
@@ -544,6 +892,7 @@ err-failed-parse-login = ログイン応答の解析に失敗しました: { $er
 err-failed-create-server-name = サーバー名の作成に失敗しました: { $error }
 err-failed-create-config-dir = 設定ディレクトリの作成に失敗しました: { $error }
 err-failed-serialize-config = 設定のシリアライズに失敗しました: { $error }
+err-config-locked = 設定は暗号化されロックされています。ロックを解除するまで変更は保存できません
 err-failed-write-config = 設定ファイルの書き込みに失敗しました: { $error }
 err-failed-read-config-metadata = 設定ファイルのメタデータの読み取りに失敗しました: { $error }
 err-failed-set-config-permissions = 設定ファイルの権限の設定に失敗しました: { $error }
@@ -553,6 +902,10 @@ err-failed-set-config-permissions = 設定ファイルの権限の設定に失
 # =============================================================================
 
 fingerprint-warning = これはセキュリティ上の問題（MITM攻撃）またはサーバーの証明書が再生成されたことを示している可能性があります。サーバー管理者を信頼している場合のみ受け入れてください。
+fingerprint-new-server-prompt = このサーバーへの初めての接続です。下のフィンガープリントがサーバー管理者が公開したものと一致する場合にのみ証明書を信頼してください。
+password-vault-create-description = システムのキーチェーンが利用できません。ブックマークの保存済みパスワードを暗号化するマスターパスフレーズを設定するか、スキップして設定ファイルに保存したままにします。
+password-vault-unlock-description = マスターパスフレーズを入力して、ブックマークの保存済みパスワードのロックを解除してください。スキップすると、Nexus を再起動するまでブックマークはパスワードなしで接続します。
+config-unlock-description = 設定、ブックマーク、チャット履歴は暗号化されています。ロックを解除するにはマスターパスフレーズを入力してください。スキップすると、Nexus は既定の設定で起動し、再起動するまで何も保存されません。
 
 # =============================================================================
 # User Info Display
@@ -569,10 +922,12 @@ user-info-account = アカウント: { $username }
 user-info-connected = 接続:
 user-info-connected-value = { $duration }前
 user-info-connected-value-sessions = { $duration }前（{ $count }セッション）
+user-info-idle = アイドル:
 user-info-features = 機能:
 user-info-features-value = { $features }
 user-info-features-none = なし
 user-info-locale = ロケール:
+user-info-client = クライアント:
 user-info-address = アドレス:
 user-info-addresses = アドレス:
 user-info-created = 作成日:
@@ -581,6 +936,12 @@ user-info-unknown = 不明
 user-info-loading = ユーザー情報を読み込み中…
 user-info-status = ステータス:
 user-info-channels = チャンネル:
+user-info-rank = ランク:
+user-info-logins = ログイン:
+user-info-messages = メッセージ:
+user-info-uploads = アップロード:
+user-info-downloaded = ダウンロード量:
+user-info-last-login = 最終ログイン:
 
 # =============================================================================
 # Time Duration
@@ -604,26 +965,51 @@ cmd-me-usage = 使用方法: /{ $command } <アクション>
 err-me-no-target = /{ $join }でチャンネルに参加するか、/{ $msg }でユーザーにアクションを送信してください
 cmd-message-desc = ユーザーにメッセージを送信
 cmd-message-usage = 使用方法: /{ $command } <ユーザー名> <メッセージ>
+cmd-group-desc = 複数のユーザーとグループ会話を開始
+cmd-group-usage = 使用方法: /{ $command } <ユーザー名> [<ユーザー名>...]
+cmd-invite-desc = 現在のグループ会話にユーザーを追加
+cmd-invite-usage = 使用方法: /{ $command } <ユーザー名>
 cmd-ping-desc = サーバーへの遅延を測定
 cmd-ping-usage = 使用方法: /{ $command }
+cmd-radio-desc = チャンネルのボイスリスナーに音声を配信
+cmd-radio-usage = 使用法: /{ $command }
+err-radio-no-channel = このコマンドはチャンネルでのみ使用できます
+err-radio-in-voice = 配信を開始する前にボイスから退出してください
 ping-result = 応答: { $ms }ms
 cmd-userinfo-desc = ユーザーの情報を表示
 cmd-userinfo-usage = 使用方法: /{ $command } <ユーザー名>
 cmd-kick-desc = ユーザーをサーバーからキック
 cmd-kick-usage = 使用方法: /{ $command } <ユーザー名> [理由]
+cmd-top-desc = 最もアクティブなユーザーを表示
+cmd-top-usage = 使用方法: /{ $command } [logins|messages|uploads|downloaded]
 cmd-topic-desc = チャットトピックを表示または管理
-cmd-topic-usage = 使用方法: /{ $command } [設定|クリア] [トピック]
+cmd-topic-usage = 使用方法: /{ $command } [設定|クリア|ロック|ロック解除|履歴] [トピック]
 cmd-topic-arg-set = 設定
 cmd-topic-arg-clear = クリア
+cmd-topic-arg-lock = ロック
+cmd-topic-arg-unlock = ロック解除
+cmd-topic-arg-history = 履歴
 cmd-topic-set-usage = 使用方法: /{ $command } 設定 <トピック>
 cmd-topic-none = トピックが設定されていません
 cmd-broadcast-desc = 全ユーザーにブロードキャストを送信
 cmd-broadcast-usage = 使用方法: /{ $command } <メッセージ>
 cmd-clear-desc = 現在のタブのチャット履歴をクリア
 cmd-clear-usage = 使用方法: /{ $command }
+cmd-export-desc = 現在のタブを HTML ファイルとして保存
+cmd-export-usage = 使用方法: /{ $command }
+cmd-clip-desc = サウンドボードのクリップを一覧・再生・追加・削除
+cmd-clip-usage = 使い方: /{ $command } [一覧|再生 <名前>|追加 <名前>|削除 <名前>|<名前>]
+cmd-clip-arg-list = 一覧
+cmd-clip-arg-play = 再生
+cmd-clip-arg-add = 追加
+cmd-clip-arg-delete = 削除
 cmd-focus-desc = サーバーチャットまたはユーザーのメッセージウィンドウにフォーカス
 cmd-focus-usage = 使用方法: /{ $command } [ユーザー名]
 cmd-focus-not-found = ユーザーが見つかりません: { $name }
+cmd-friend-desc = フレンドの一覧表示、追加、削除
+cmd-friend-usage = 使用法: /{ $command } [list] | add <ユーザー名> | remove <ユーザー名>
+cmd-friend-list-header = フレンド:
+cmd-friend-list-empty = フレンドはまだいません。/{ $command } add <ユーザー名> で追加できます
 cmd-list-desc = 接続中/全ユーザーを表示
 cmd-list-arg-all = 全て
 cmd-list-usage = 使用方法: /{ $command } [全て]
@@ -633,6 +1019,13 @@ cmd-list-all-no-permission = 全ユーザーを表示するにはuser_editまた
 cmd-list-all-output = ユーザー: { $users } ({ $count }人)
 cmd-help-usage = 使用方法: /{ $command } [コマンド]
 cmd-topic-permission-denied = トピックを編集する権限がありません
+cmd-topic-lock-permission-denied = トピックをロックする権限がありません
+msg-topic-already-locked = トピックは既にロックされています
+msg-topic-already-unlocked = トピックは既にロック解除されています
+msg-topic-history-header = { $channel } のトピック履歴:
+msg-topic-history-set = { $time } { $username }: { $topic }
+msg-topic-history-cleared = { $time } { $username } がトピックをクリアしました
+msg-topic-history-empty = トピックの変更履歴はありません
 cmd-window-desc = チャットタブを管理
 cmd-window-usage = 使用方法: /{ $command } [次|前|閉じる [ユーザー名]]
 cmd-window-arg-next = 次
@@ -657,15 +1050,55 @@ cmd-unban-desc = IP BANを解除
 cmd-unban-usage = 使用法: /{ $command } <対象>
 cmd-bans-desc = 有効なBANを一覧表示
 cmd-bans-usage = 使用法: /{ $command }
+cmd-register-desc = ゲストセッションからアカウントを登録
+cmd-register-usage = 使用法: /{ $command } <ユーザー名> <パスワード>
 cmd-reindex-desc = ファイルインデックスの再構築をトリガー
 cmd-reindex-usage = 使用法: /{ $command }
+cmd-cleanup-desc = ファイル領域から中断された転送の残りを探す
+cmd-cleanup-usage = 使用法: /{ $command } [delete]
+cmd-filecheck-desc = ファイル領域を検索インデックスおよびファイルメタデータと照合します
+cmd-filecheck-usage = 使い方: /{ $command } [repair]
+cmd-screenshot-desc = 注釈付きのスクリーンショットを現在のタブで共有
+cmd-screenshot-usage = 使い方: /{ $command }
+err-screenshot-no-target = スクリーンショットはチャンネルかユーザーメッセージのタブでのみ共有できます
+err-screenshot-no-folder = スクリーンショットの保存先がありません — ファイルのアップロードフォルダで「ここでスクリーンショットを共有」を選んでください
+err-screenshot-capture = スクリーンショットに失敗しました: { $error }
+err-screenshot-save = スクリーンショットを保存できませんでした: { $error }
+err-screenshot-upload = スクリーンショットのアップロードに失敗しました: { $error }
+err-export-empty = このタブにはエクスポートするメッセージがありません
+err-chat-export = チャットをエクスポートできませんでした: { $error }
+screenshot-title = スクリーンショットを共有
+screenshot-tool-crop = 切り抜き
+screenshot-tool-arrow = 矢印
+screenshot-hint-crop = 残したい部分を囲むようにドラッグします
+screenshot-hint-arrow = 矢印の根元から先端までドラッグします
 msg-reindex-triggered = ファイルインデックスの再構築がトリガーされました
+msg-cleanup-nothing = 中断された転送の残りは見つかりませんでした
+msg-cleanup-found = 中断された転送の残りが見つかりました:
+msg-cleanup-deleted = 中断された転送の残りを削除しました:
+msg-cleanup-parts = 孤立した .part ファイル: { $count } ({ $size })
+msg-cleanup-temp = 残った一時ファイル: { $count } ({ $size })
+msg-cleanup-journal = ファイルのないアップロード記録: { $count }
+msg-cleanup-index = ファイルのない検索インデックス項目: { $count }
+msg-cleanup-hint = 上記のファイルを確認してから /cleanup delete を実行して削除してください
+msg-filecheck-issue = { $kind }: { $path }
+msg-filecheck-issue-repaired = { $kind }: { $path }（修復済み）
+msg-filecheck-clean = ファイル領域のチェックで問題は見つかりませんでした（{ $entries } 件）
+msg-filecheck-done = ファイル領域のチェックで { $entries } 件中 { $issues } 件の問題が見つかりました（{ $repaired } 件修復済み）
+msg-filecheck-index-skipped = 検索インデックスが未作成か再構築待ちのため、比較されませんでした
+msg-filecheck-hint = /filecheck repair を実行すると、インデックスを再構築し、存在しないパスのメタデータを削除します
+filecheck-not-indexed = 検索インデックスにありません
+filecheck-indexed-but-missing = 検索インデックスにありますがディスクにありません
+filecheck-index-outdated = 検索インデックスの情報が古くなっています
+filecheck-orphaned-activity = 存在しないパスの履歴
+filecheck-orphaned-reviews = 存在しないパスの評価またはコメント
+filecheck-orphaned-upload-rule = 存在しないフォルダーのアップロードルール
 cmd-join-desc = チャンネルに参加または作成
 cmd-join-usage = 使用法: /{ $command } #チャンネル
 cmd-leave-desc = チャンネルを退出
 cmd-leave-usage = 使用法: /{ $command } [#チャンネル]
 cmd-channels-desc = 利用可能なチャンネルを一覧表示
-cmd-channels-usage = 使用法: /{ $command }
+cmd-channels-usage = 使用法: /{ $command } [言語]
 cmd-secret-desc = 現在のチャンネルのシークレットモードを切り替え
 cmd-secret-usage = 使用法: /{ $command } [オン|オフ]
 cmd-secret-arg-on = オン
@@ -678,12 +1111,40 @@ msg-secret-enabled = チャンネルがシークレットになりました
 msg-secret-disabled = チャンネルがパブリックになりました
 msg-secret-status-on = このチャンネルは秘密です
 msg-secret-status-off = このチャンネルは公開です
+cmd-voicelimit-desc = 現在のチャンネルのボイス参加者上限を表示または設定
+cmd-voicelimit-usage = 使い方: /{ $command } [<人数>|オフ]
+cmd-vt-desc = ボイスセッションにテキストメッセージを送信
+cmd-vt-usage = 使い方: /{ $command } <メッセージ>
+cmd-voicelimit-arg-off = オフ
+err-voicelimit-no-channel = このコマンドはチャンネルでのみ使用できます
+msg-voice-limit-status = このチャンネルのボイスは最大 { $limit } 人です
+msg-voice-limit-status-none = このチャンネルにはボイス上限がありません
+msg-voice-clip-none = サウンドボードのクリップはありません
+msg-voice-clip-list = サウンドボードのクリップ ({ $count }):
+msg-voice-clip-entry = { $name } ({ $seconds }秒, { $nickname })
+msg-voice-clip-uploaded = クリップをサウンドボードに追加しました
+msg-voice-clip-deleted = クリップをサウンドボードから削除しました
 cmd-trust-desc = IPアドレス、CIDRレンジ、またはニックネームを信頼してBANをバイパス
 cmd-trust-usage = 使用法: /{ $command } <対象> [期間] [理由]
 cmd-untrust-desc = 信頼済みIPエントリを削除
 cmd-untrust-usage = 使用法: /{ $command } <対象>
 cmd-trusted-desc = 信頼済みIPを一覧表示
 cmd-trusted-usage = 使用法: /{ $command }
+cmd-ldap-desc = ディレクトリグループの割り当てを一覧表示、設定、削除
+cmd-ldap-usage = 使用法: /{ $command } [list|set <権限|admin|none> <グループDN>|remove <グループDN>]
+cmd-nickname-desc = アカウント用のニックネームを予約または解放
+cmd-nickname-usage = 使用法: /{ $command } [register <ニックネーム> [ユーザー名]|release <ニックネーム>]
+cmd-history-desc = サーバーがユーザーメッセージを保存する期間を表示・設定、または古いメッセージを読み込み
+cmd-history-usage = 使用法: /{ $command } [<日数>|more]
+cmd-alias-desc = コマンドやメッセージのショートカットを作成
+cmd-alias-usage = 使用法: /{ $command } [list|set <名前> <テキスト>|remove <名前>]
+cmd-alias-list-header = エイリアス一覧:
+cmd-alias-list-empty = エイリアスはまだありません。/{ $command } set <名前> <テキスト> で作成できます
+cmd-alias-set = /{ $name } の実行内容: { $text }
+cmd-alias-removed = エイリアス /{ $name } を削除しました
+cmd-alias-not-found = /{ $name } という名前のエイリアスはありません
+cmd-alias-invalid-name = エイリアス名には英数字、- と _ のみ使用できます（最大 { $max } 文字）
+cmd-alias-builtin = /{ $name } は組み込みコマンドのため、エイリアスとして使用できません
 
 # =============================================================================
 # About Panel
@@ -696,9 +1157,25 @@ err-server-image-invalid-format = サーバー画像の形式が無効です（b
 err-server-image-unsupported-type = サポートされていないサーバー画像タイプです（PNG、WebP、JPEG、SVGのみ）
 err-server-image-decode-failed = 画像のデコードに失敗しました。ファイルが破損している可能性があります。
 err-failed-read-image = 画像の読み込みに失敗しました: { $error }
-err-news-empty = 投稿にはテキストまたは画像が必要です
+err-news-empty = 投稿にはテキストまたは添付ファイルが必要です
 err-news-body-too-long = 本文が長すぎます（{ $length }文字、最大{ $max }文字）
 err-news-body-invalid-characters = 本文に無効な制御文字が含まれています
+err-news-too-many-attachments = 投稿に添付できるのは最大 { $max } 件です
+err-news-caption-too-long = キャプションが長すぎます（最大 { $max } 文字）
+err-news-caption-invalid-characters = キャプションは制御文字を含まない1行にしてください
+err-news-file-path-invalid = 無効なファイルパスです
+err-news-search-date = 無効な日付: { $date } (YYYY-MM-DD 形式で入力してください)
+err-file-comment-empty = コメントを入力してください
+err-file-comment-too-long = コメントが長すぎます（{ $length } 文字、最大 { $max }）
+err-file-comment-invalid-characters = コメントに無効な制御文字が含まれています
+err-upload-rule-invalid-extension = 無効なファイル形式「{ $extension }」(mp3 のように英数字を使用してください)
+err-auto-download-pattern-required = ファイル名のパターンを 1 つ以上入力してください
+err-auto-download-pattern-invalid = 無効なパターン「{ $pattern }」(パターンはファイル名に一致するため、スラッシュは使用できません)
+err-upload-rule-too-many-extensions = ファイル形式が多すぎます (最大 { $max })
+err-upload-rule-invalid-size = 無効なサイズ「{ $value }」(メガバイト数を入力してください)
+err-ldap-group-empty = グループDNを入力してください
+err-ldap-group-too-long = グループDNが長すぎます（最大{ $max }文字）
+err-ldap-group-invalid-characters = グループDNの前後に空白や制御文字は使用できません
 err-news-image-decode-failed = 画像のデコードに失敗しました。ファイルが破損している可能性があります。
 
 # Proxy errors
@@ -706,9 +1183,31 @@ err-proxy-connection-failed = プロキシへの接続に失敗しました: { $
 err-proxy-connection-timeout = プロキシ接続が{ $seconds }秒後にタイムアウトしました
 err-proxy-address-required = プロキシが有効な場合、プロキシアドレスは必須です
 err-proxy-port-invalid = プロキシポートは1から65535の間である必要があります
-err-news-image-too-large = 画像が大きすぎます（最大512KB）
+err-news-image-too-large = 画像が大きすぎます（最大{ $max_kb }KB）
 err-news-image-unsupported-type = 未対応の画像形式です（PNG、WebP、JPEG、SVGのみ対応）
 err-topic-no-channel = このコマンドはチャンネル内でのみ動作します
+err-entry-no-channel = このコマンドはチャンネルでのみ使用できます
+cmd-welcome-desc = チャンネル参加時に表示するメッセージを表示または設定
+cmd-welcome-usage = 使用方法: /{ $command } [設定 <メッセージ>|クリア]
+cmd-welcome-arg-set = 設定
+cmd-welcome-arg-clear = クリア
+cmd-welcome-none = 入室メッセージは設定されていません
+msg-entry-message-display = 入室メッセージ: { $message }
+msg-entry-message-updated = 入室メッセージを更新しました
+msg-entry-message-cleared = 入室メッセージをクリアしました
+cmd-rules-desc = チャンネルルールを表示または設定
+cmd-rules-usage = 使用方法: /{ $command } [設定 <ルール>|クリア]
+cmd-rules-arg-set = 設定
+cmd-rules-arg-clear = クリア
+cmd-rules-none = ルールは設定されていません
+msg-rules-header = { $channel } のルール:
+cmd-language-desc = チャンネルの言語タグを表示または設定
+cmd-language-usage = 使用方法: /{ $command } [設定 <言語>|クリア]
+cmd-language-arg-set = 設定
+cmd-language-arg-clear = クリア
+msg-language-status = チャンネルの言語: { $language }
+msg-language-none = 言語が設定されていません
+err-language-invalid = 無効な言語タグ: { $language }（例: ja、pt-BR）
 err-join-channel = チャンネルに参加できませんでした: { $error }
 err-leave-channel = チャンネルを退出できませんでした: { $error }
 err-list-channels = チャンネル一覧を取得できませんでした: { $error }
@@ -718,6 +1217,8 @@ err-channel-too-long = チャンネル名が長すぎます（最大{ $max }文
 err-channel-missing-prefix = チャンネル名は#で始まる必要があります
 err-channel-invalid-characters = チャンネル名に無効な文字が含まれています
 err-leave-no-channel = 引数なしで/{ $command }を使用するにはチャンネルにいる必要があります
+err-invite-no-group = /{ $command } はグループ会話タブで使用してください
+err-group-too-many-members = グループ会話のメンバーは最大 { $max } 人です
 err-not-in-channel = チャンネル { $channel } にいません
 err-leave-already-pending = すでにサーバーからの応答を待っています
 err-uri-connection-failed = { $host } への接続に失敗しました: { $error }
@@ -731,6 +1232,15 @@ label-port = ポート:
 # Transfer statuses
 transfer-status-queued = 待機中
 transfer-status-connecting = 接続中…
+transfer-status-server-queued = サーバーの空きを待機中 (#{ $position })
+transfer-collision-pending = ファイルが存在します: { $path }
+collision-action-renamed = { $count } 件を名前変更
+collision-action-overwritten = { $count } 件を上書き
+collision-action-skipped = { $count } 件をスキップ
+transfer-bar-count = { $count } 件の転送
+status-bar-users = { $count } 人のユーザー
+status-bar-latency = { $ms } ms
+status-bar-server-time = サーバー時刻 { $time } ({ $zone })
 transfer-status-transferring = 転送中
 transfer-status-paused = 一時停止
 transfer-status-completed = 完了
@@ -789,6 +1299,7 @@ event-broadcast = ブロードキャスト
 event-chat-message = チャットメッセージ
 event-chat-mention = チャットでのメンション
 event-connection-lost = 接続が切断されました
+event-friend-online = フレンドのオンライン
 event-news-post = ニュース投稿
 event-permissions-changed = 権限が変更されました
 event-transfer-complete = 転送完了
@@ -823,6 +1334,17 @@ settings-sound-test = テスト
 settings-notification-test = テスト
 event-settings-show-toast = トーストを表示
 settings-toast-test = テスト
+settings-do-not-disturb = おやすみモード
+settings-quiet-hours = サイレント時間
+settings-do-not-disturb-hint = 通知、トースト、サウンドを停止します。未読バッジは引き続き加算され、他のユーザーには名前の横に 🔕 が表示されます。
+settings-notification-rules = 通知ルール
+settings-notification-rules-server = サーバー:
+settings-notification-rules-channels = チャンネル別設定
+settings-notification-rules-keywords = 監視キーワード
+settings-notification-rules-no-bookmarks = 通知ルールを設定するにはサーバーをブックマークしてください。
+channel-notification-all = すべてのメッセージ
+channel-notification-mentions = メンションのみ
+channel-notification-mute = ミュート
 
 # Sound names
 sound-none = なし
@@ -841,6 +1363,8 @@ notification-chat-message = 新しいチャットメッセージ
 notification-chat-message-from = {$username}からのチャット
 notification-connection-lost = 接続が切断されました
 notification-connection-lost-from = {$server}から切断されました
+notification-friend-online = フレンドがオンラインになりました
+notification-friend-online-name = {$username} がオンラインになりました
 notification-download-complete = ダウンロード完了
 notification-download-failed = ダウンロード失敗
 notification-news-post = 新しいニュース投稿
@@ -894,6 +1418,18 @@ ban-duration-7d = 7日
 ban-duration-30d = 30日
 ban-duration-permanent = 永久
 
+# Broadcast target and delay options
+broadcast-target-label = 送信先:
+broadcast-target-all = 全員
+broadcast-target-channel = チャンネルのメンバー
+broadcast-target-permission = 権限を持つユーザー
+broadcast-delay-label = 送信:
+broadcast-delay-now = 今すぐ
+broadcast-delay-5m = 5 分後
+broadcast-delay-15m = 15 分後
+broadcast-delay-1h = 1 時間後
+broadcast-delay-1d = 1 日後
+
 # =============================================================================
 # Ban System
 # =============================================================================
@@ -943,12 +1479,32 @@ msg-untrusted-success = 信頼エントリを削除しました
 # Trust list messages
 msg-trust-list-header = 信頼済みIP:
 msg-trust-list-empty = 信頼済みIPはありません
+msg-ldap-group-list-header = ディレクトリグループ:
+msg-ldap-group-list-empty = 割り当てられたディレクトリグループはありません
+msg-ldap-group-admin = 管理者
+msg-ldap-group-no-permissions = 権限なし
+msg-ldap-group-set = ディレクトリグループ { $group } を割り当てました
+msg-ldap-group-removed = ディレクトリグループ { $group } を削除しました
+msg-guest-upgraded = アカウント { $username } を作成しました。次回からこのアカウントでログインしてください。
+msg-nickname-registered = ニックネーム { $nickname } を { $username } 用に予約しました。
+msg-nickname-released = ニックネーム { $nickname } を解放しました。
+msg-history-days = サーバーはユーザーメッセージを { $days } 日間保存します。
+msg-history-off = サーバーはユーザーメッセージを保存していません。/history <日数> で有効にできます。
+msg-history-no-older = { $nickname } とのこれより古いメッセージはありません。
+err-history-days-too-long = メッセージ履歴の保存期間は最大 { $max } 日です
+err-history-more-tab = /history more はユーザーメッセージタブで使用してください
 msg-trust-permanent = 永続
 msg-trust-expired = 期限切れ
 msg-trust-remaining = 残り { $time }
 msg-trust-remaining-days = { $days }日 { $hours }時間
 msg-trust-remaining-hours = { $hours }時間 { $minutes }分
 msg-trust-remaining-minutes = { $minutes }分
+msg-leaderboard-header = { $stat }が多いユーザー:
+msg-leaderboard-empty = まだアクティビティの記録はありません
+leaderboard-stat-logins = ログイン数
+leaderboard-stat-messages = メッセージ数
+leaderboard-stat-uploads = アップロード数
+leaderboard-stat-downloaded = ダウンロード量
 
 # =============================================================================
 # Voice Chat
@@ -957,6 +1513,16 @@ msg-trust-remaining-minutes = { $minutes }分
 # Voice error messages
 err-voice-join = ボイスチャットへの参加に失敗しました: { $error }
 err-voice-leave = ボイスチャットからの退出に失敗しました: { $error }
+err-voice-limit = ボイス上限の設定に失敗しました: { $error }
+err-voice-clip = サウンドボード: { $error }
+err-voice-text = ボイステキスト: { $error }
+err-voice-clip-name-empty = クリップ名を入力してください
+err-voice-clip-name-too-long = クリップ名が長すぎます（最大 { $max } 文字）
+err-voice-clip-name-invalid = クリップ名には英数字、- と _ のみ使用できます
+err-voice-clip-too-large = クリップが大きすぎます（最大 256KB）
+err-voice-clip-unsupported-type = サポートされていないクリップ形式です（Ogg Vorbis のみ）
+msg-voice-queued = ボイスが満員です - 待機順は { $position } 番目です
+msg-voice-queue-cancelled = ボイスの待機列から外されました
 err-voice-no-token = ボイスチャットに参加しましたが、トークンを受信できませんでした
 err-voice-already-active = 別のサーバーでボイスチャットに参加中です
 err-voice-no-permission = ボイスチャットに参加する権限がありません
@@ -979,7 +1545,12 @@ msg-voice-joined-empty = { $target } のボイスチャットに参加しまし
 msg-voice-left = { $target } のボイスチャットから退出しました
 msg-voice-user-joined = { $nickname } がボイスチャットに参加しました
 msg-voice-user-left = { $nickname } がボイスチャットから退出しました
+msg-voice-broadcast-started = { $nickname } がボイスで配信中です - 参加して聴く
+msg-voice-broadcast-ended = ボイス配信が終了しました
+msg-voice-clip-played = { $nickname } が { $name } を再生しました
+msg-voice-text = [ボイス] { $nickname }: { $message }
 msg-voice-you-left = ボイスチャットを退出しました
+msg-voice-tcp-relay = ボイスのUDP接続に失敗しました ({ $error }) - 代わりにサーバー接続経由で音声を中継します
 
 # Voice UI
 voice-join-tooltip = ボイスチャットに参加
@@ -987,6 +1558,8 @@ voice-leave-tooltip = ボイスチャットから退出
 voice-mute-all-tooltip = 全員ミュート
 voice-unmute-all-tooltip = ミュート解除
 voice-bar-participants = ({ $count })
+voice-bar-queued = (待機中: { $position })
+voice-bar-listeners = ({ $count } 人が視聴中)
 voice-indicator-tooltip = ボイスチャット中
 tooltip-mute = ミュート
 tooltip-unmute = ミュート解除
@@ -997,6 +1570,12 @@ voice-quality-medium = 中 (32 kbps)
 voice-quality-high = 高 (64 kbps)
 voice-quality-very-high = 最高 (96 kbps)
 
+# Voice connection quality
+voice-connection-good = 良好
+voice-connection-fair = 普通
+voice-connection-poor = 不安定
+voice-connection-tooltip = 接続: { $quality }（損失 { $loss }%、ジッター { $jitter } ms）
+
 # PTT modes
 ptt-mode-hold = 押している間話す
 ptt-mode-toggle = 切り替え
@@ -1011,6 +1590,8 @@ ptt-delay-500ms = 500ms
 audio-output-device = 出力デバイス
 audio-input-device = 入力デバイス
 audio-refresh-devices = デバイスを更新
+audio-clip-volume = サウンドボード音量
+audio-voice-text-tts = ボイステキストメッセージを読み上げる
 audio-voice-quality = 音声品質
 audio-ptt-key = プッシュトゥトークキー
 audio-ptt-mode = PTTモード
@@ -1037,6 +1618,44 @@ mic-boost-6db = +6 dB
 mic-boost-12db = +12 dB
 mic-boost-18db = +18 dB
 
+# =============================================================================
+# Global Hotkeys
+# =============================================================================
+
+# Settings
+settings-toggle-window-key = ウィンドウ表示切替キー
+button-clear-hotkey = クリア
+
+# Shortcut descriptions (shown by the desktop on Wayland)
+hotkey-push-to-talk = プッシュトゥトーク
+hotkey-toggle-window = Nexus を表示/非表示
+
+# Errors
+err-window-hotkey-failed = ウィンドウ表示切替キーの登録に失敗しました: { $error }
+err-global-shortcuts-unavailable = グローバルショートカットポータルを利用できません。ホットキーは Nexus がフォーカスされている間のみ動作します: { $error }
+
+# =============================================================================
+# Auto Away
+# =============================================================================
+
+settings-auto-away = アイドル時に自動的に離席にする
+settings-auto-away-minutes = アイドル時間（分）
+
+# =============================================================================
+# Connection Health
+# =============================================================================
+
+# Server list indicator
+connection-health-good = 良好
+connection-health-fair = 普通
+connection-health-poor = 不良
+connection-health-tooltip = 接続: { $quality }
+connection-health-latency = 遅延: { $ms } ms
+connection-health-jitter = ジッター: { $ms } ms
+connection-health-last-drop = 最後に失われた ping: { $time }
+connection-health-no-drops = 失われた ping はありません
+connection-health-measuring = 遅延を測定中…
+
 # =============================================================================
 # System Tray (Windows/Linux only)
 # =============================================================================
@@ -1062,15 +1681,53 @@ tray-tooltip-voice = Nexus BBS - ボイス中: { $target }
 tray-tooltip-voice-muted = Nexus BBS - ボイス中 (ミュート): { $target }
 tray-tooltip-unread = Nexus BBS - 未読の会話 { $count } 件
 
+# =============================================================================
+# Notification Actions (macOS/Windows only)
+# =============================================================================
+
+# Actions
+notification-reply = 返信
+notification-reply-send = 送信
+notification-reply-placeholder = 返信…
+notification-mark-read = 既読にする
+
 # =============================================================================
 # Toast Notifications
 # =============================================================================
 
 toast-link-copied = リンクをクリップボードにコピーしました
 toast-copied = クリップボードにコピーしました
+toast-checksum-copied = SHA-256 をクリップボードにコピーしました
 toast-download-started = { $filename } をダウンロード中
 toast-download-queued = ダウンロード待ち: { $filename }
+toast-downloads-started = { $count } 個のファイルをダウンロード中
+toast-downloads-queued = { $count } 個のダウンロード待ち
 toast-upload-started = { $filename } をアップロード中
 toast-upload-queued = アップロード待ち: { $filename }
 toast-uploads-started = { $count } 個のファイルをアップロード中
 toast-uploads-queued = { $count } 個のアップロード待ち
+toast-upload-nothing-new = アップロードするものはありません — サーバーには既にこれらのファイルがあります
+toast-upload-watch-started = { $folder } を監視中 — 新しいファイルは自動的にアップロードされます
+toast-upload-watch-stopped = { $folder } の監視を停止しました
+toast-auto-download-started = { $folder } から { $pattern } を自動ダウンロード中
+toast-auto-download-stopped = { $folder } からの自動ダウンロードを停止しました
+toast-screenshot-folder-set = スクリーンショットはこのフォルダにアップロードされます
+toast-chat-exported = チャットをエクスポートしました
+
+# =============================================================================
+# First-run Tips
+# =============================================================================
+
+tip-welcome-title = Nexus へようこそ
+tip-welcome = 下にサーバーのアドレスを入力して接続します。ブックマークを追加すると、ログイン情報とともにサーバーが左のリストに残り、次回はワンクリックで接続できます。
+tip-fingerprint = 各サーバーには署名のような証明書フィンガープリントがあります。Nexus は初回接続時にこれを記憶し、変わった場合は警告します。誰かがサーバーになりすましている可能性があるためです。サーバー管理者から変更を知らされた場合にのみ、新しいフィンガープリントを受け入れてください。
+tip-server-list = ブックマークと接続中のサーバーは左側にあります。クリックでサーバーを切り替え、ドラッグでブックマークを並べ替えられます。
+tip-toolbar = ツールバーからファイル、ニュース、設定などを開けます。アイコンにマウスを合わせると機能が表示されます。
+tip-chat = ここでサーバー上の全員とチャットできます。/ を入力すると、チャンネルに入る /join など使えるコマンドが表示されます。
+tip-user-list = 現在オンラインのユーザーは右側に表示されます。名前をクリックするとメッセージを送ったり情報を見たりできます。
+tip-counter = ヒント { $current } / { $total }
+button-add-bookmark = ブックマークを追加
+button-tip-next = 次へ
+button-tip-done = 完了
+button-tip-got-it = 了解
+button-skip-tips = ヒントをスキップ
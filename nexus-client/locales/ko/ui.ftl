@@ -11,6 +11,13 @@ button-connect = 연결
 button-save = 저장
 button-create = 생성
 button-edit = 편집
+button-check-ports = 포트 확인
+button-statistics = 통계
+button-dashboard = 대시보드
+button-refresh = 새로 고침
+button-doors = 도어
+button-doors-leave = 나가기
+button-doors-back = 뒤로
 button-update = 업데이트
 button-accept-new-certificate = 새 인증서 수락
 button-close = 닫기
@@ -18,9 +25,23 @@ button-choose-avatar = 아바타 선택
 button-clear-avatar = 지우기
 button-choose-image = 이미지 선택
 button-clear-image = 지우기
+button-add-file = 파일 추가
 button-overwrite = 덮어쓰기
 button-change-password = 비밀번호 변경
 button-browse = 찾아보기
+button-undo = 실행 취소
+button-add = 추가
+button-remove = 제거
+button-view = 보기
+button-hide = 숨기기
+button-export = 내보내기…
+button-trust-certificate = 인증서 신뢰
+button-skip = 건너뛰기
+button-skip-existing = 기존 파일 건너뛰기
+button-download-anyway = 그래도 다운로드
+button-unlock = 잠금 해제
+button-view-as-user = 사용자로 보기
+button-post-comment = 게시
 
 # =============================================================================
 # Titles
@@ -35,19 +56,29 @@ title-user-create = 사용자 생성
 title-user-edit = 사용자 편집
 title-update-user = 사용자 업데이트
 title-user-management = 사용자 관리
+title-permission-audit = 권한 감사
+title-impersonate = { $username }(으)로 보는 중
 title-confirm-delete = 삭제 확인
 title-connected = 연결됨
 title-settings = 설정
 title-bookmarks = 북마크
 title-users = 사용자
+title-friends = 친구
+friend-online = 온라인
+friend-offline = 오프라인
 title-edit-server-info = 서버 정보 편집
 title-fingerprint-mismatch = 인증서 지문이 일치하지 않습니다!
+title-fingerprint-new-server = 새 서버 인증서
+title-create-password-vault = 저장된 비밀번호 보호
+title-unlock-password-vault = 저장된 비밀번호 잠금 해제
+title-unlock-config = 설정 잠금 해제
 title-server-info = 서버 정보
 title-user-info = 사용자 정보
 title-about = 정보
 title-change-password = 비밀번호 변경
 title-news = 뉴스
 title-transfers = 전송
+title-transfer-history = 전송 기록
 title-news-create = 게시물 작성
 title-news-edit = 게시물 편집
 title-channel-members = 멤버
@@ -58,10 +89,14 @@ title-channel-members = 멤버
 
 placeholder-username = 사용자 이름
 placeholder-password = 비밀번호
+placeholder-master-passphrase = 마스터 암호 문구
+placeholder-confirm-master-passphrase = 마스터 암호 문구 확인
 placeholder-port = 포트
 placeholder-server-address = 서버 주소
 placeholder-server-name = 서버 이름
+placeholder-folder-name = 폴더 이름
 placeholder-username-optional = 사용자 이름 (선택)
+placeholder-fallback-addresses-optional = 대체 주소 (선택 사항, 쉼표로 구분)
 placeholder-password-optional = 비밀번호 (선택)
 placeholder-password-keep-current = 비밀번호
 placeholder-current-password = 현재 비밀번호
@@ -70,10 +105,18 @@ placeholder-confirm-password = 비밀번호 확인
 placeholder-message = 메시지를 입력하세요…
 placeholder-no-permission = 권한 없음
 placeholder-broadcast-message = 방송 메시지
+placeholder-broadcast-channel = 채널 선택
+placeholder-broadcast-permission = 권한 선택
 placeholder-server-description = 서버 설명
 placeholder-news-body = 뉴스 내용
+placeholder-news-caption = 캡션 (선택 사항)
+placeholder-news-file-path = 파일 영역 내 경로
 placeholder-nickname-optional = 별명 (선택사항)
+placeholder-status-optional = 상태 (선택 사항)
+placeholder-download-folder-optional = 다운로드 폴더 (선택사항)
 placeholder-proxy-address = 프록시 주소
+placeholder-notification-rules-channel = #채널
+placeholder-notification-rules-keyword = 키워드
 placeholder-proxy-port = 9050
 placeholder-proxy-username = 사용자명 (선택)
 placeholder-proxy-password = 비밀번호 (선택)
@@ -86,13 +129,20 @@ placeholder-auto-join-channels = #채널1 #채널2 (공백으로 구분)
 # =============================================================================
 
 label-auto-connect = 자동 연결
+label-server-avatar = 아바타:
 label-add-bookmark = 북마크 추가
+label-lan-servers = 이 네트워크
+label-use-sso = 싱글 사인온
 label-admin = 관리자
 label-shared-account = 공유 계정
 label-enabled = 활성화
+label-erase-personal-data = 개인 데이터도 삭제
+label-broadcast-require-ack = 수신자에게 확인 요청
 label-permissions = 권한:
 label-expected-fingerprint = 예상 지문:
 label-received-fingerprint = 수신된 지문:
+label-certificate-fingerprint = 인증서 지문:
+label-identity-key = 신원 키:
 label-theme = 테마
 label-chat-history-retention = 채팅 기록:
 chat-history-forever = 영구
@@ -100,6 +150,10 @@ chat-history-30-days = 30일
 chat-history-14-days = 14일
 chat-history-7-days = 7일
 chat-history-disabled = 비활성화
+collision-policy-rename = 둘 다 유지 (이름 변경)
+collision-policy-overwrite = 덮어쓰기
+collision-policy-skip = 건너뛰기
+collision-policy-ask = 묻기
 label-max-scrollback = 최대 스크롤백:
 label-chat-font-size = 글꼴 크기:
 label-show-connection-events = 연결/연결 해제 이벤트 표시
@@ -107,6 +161,19 @@ label-show-channel-events = 입장/퇴장 이벤트 표시
 label-show-timestamps = 타임스탬프 표시
 label-use-24-hour-time = 24시간 형식 사용
 label-show-seconds = 초 표시
+label-split-view = 파일, 뉴스, 연결 모니터를 채팅 옆에 표시
+label-spell-check = 채팅과 뉴스에서 맞춤법 검사
+label-spell-check-no-dictionary = 사용 중인 언어의 사전을 찾을 수 없습니다. .aff 및 .dic 파일을 config.json 옆의 dictionaries 폴더에 넣으세요.
+label-send-read-receipts = 사용자 메시지 읽음 확인 보내기
+label-user-list-grouping = 사용자 목록 그룹:
+label-user-list-sort = 사용자 목록 정렬:
+user-list-group-none = 없음
+user-list-group-admins = 관리자 먼저
+user-list-group-channel = 채널별
+user-list-group-status = 상태별
+user-list-sort-alphabetical = 이름순
+user-list-sort-idle = 유휴 시간
+user-list-sort-connect = 접속 시간
 label-server-name = 이름:
 label-server-description = 설명:
 label-server-version = 버전:
@@ -137,6 +204,27 @@ tab-files = 파일
 tab-channels = 채널
 label-connections-short = 연결:
 label-transfers-short = 전송:
+label-active-transfers-short = 활성:
+label-queue-order-short = 대기열 순서:
+label-trusted-weight-short = 신뢰 가중치:
+label-nat-mapping-short = 포트 매핑:
+label-nat-external-ip-short = 외부 IP:
+label-nat-not-mapped = 매핑 안 됨
+connectivity-check-running = 포트 확인 중…
+check-service-bbs = BBS
+check-service-transfer = 전송
+check-service-websocket = WebSocket
+check-service-transfer-websocket = WebSocket 전송
+check-service-voice = 음성
+check-state-open = 열림
+check-state-closed = 닫힘
+check-state-filtered = 필터됨
+check-state-untested = 확인 안 됨
+label-check-port = { $service } ({ $protocol } { $port }):
+label-queue-order-small-first = 작은 파일 우선
+label-queue-order-arrival = 도착 순서
+label-small-files-first = 작은 파일을 먼저 대기열에 배치
+label-unlimited = 무제한
 label-reindex-short = 재색인:
 label-persistent-short = 상시:
 label-auto-join-short = 자동 참가:
@@ -153,6 +241,19 @@ label-download-location = 다운로드 위치:
 label-queue-transfers = 전송 대기열
 label-download-limit = 다운로드 제한:
 label-upload-limit = 업로드 제한:
+label-auto-open-folder = 다운로드 완료 시 폴더 열기
+label-collision-policy = 파일이 존재하는 경우:
+label-require-fingerprint-approval = 새 서버의 인증서를 신뢰하기 전에 확인
+label-known-servers = 알려진 서버
+known-servers-empty = 저장된 서버 인증서가 없습니다
+known-server-first-seen = 처음 확인 { $date }
+known-server-first-seen-unknown = 처음 확인: 알 수 없음
+label-encrypt-config = 마스터 암호 문구로 설정, 북마크 및 채팅 기록 암호화
+config-encryption-locked-note = 설정이 잠겨 있습니다. 변경하려면 Nexus를 다시 시작하고 마스터 암호 문구를 입력하세요.
+label-upload-rule-extensions = 허용되는 파일 형식:
+label-upload-rule-max-file-size = 최대 파일 크기 (MB):
+label-upload-rule-max-total-size = 최대 업로드 크기 (MB):
+label-auto-download-pattern = 파일 이름:
 label-persistent-channels = 상시 채널:
 label-auto-join-channels = 자동 참가 채널:
 label-channels = 채널
@@ -166,11 +267,14 @@ permission-user_info = 사용자 정보
 permission-chat_send = 채팅 전송
 permission-chat_receive = 채팅 수신
 permission-chat_join = 채널 참가
+permission-chat_language = 채팅 언어
 permission-chat_create = 채널 생성
 permission-chat_list = 채널 목록
 permission-chat_secret = 채널 비공개
 permission-chat_topic = 채팅 주제
 permission-chat_topic_edit = 채팅 주제 편집
+permission-chat_topic_lock = 채팅 주제 잠금
+permission-chat_entry_edit = 채팅 입장 메시지 편집
 permission-user_broadcast = 사용자 브로드캐스트
 permission-user_create = 사용자 생성
 permission-user_delete = 사용자 삭제
@@ -181,6 +285,7 @@ permission-news_list = 뉴스 목록
 permission-news_create = 뉴스 작성
 permission-news_edit = 뉴스 편집
 permission-news_delete = 뉴스 삭제
+permission-file_comment = 파일 댓글
 permission-file_copy = 파일 복사
 permission-file_create_dir = 디렉토리 생성
 permission-file_delete = 파일 삭제
@@ -200,6 +305,11 @@ permission-trust_create = 신뢰 생성
 permission-trust_delete = 신뢰 삭제
 permission-trust_list = 신뢰 목록
 permission-connection_monitor = 연결 모니터
+permission-log_view = 로그 보기
+permission-door_play = 도어 플레이
+permission-voice_broadcast = 음성 방송
+permission-voice_clip_upload = 음성 클립 업로드
+permission-voice_limit = 음성 제한
 permission-voice_listen = 음성 듣기
 permission-voice_talk = 음성 말하기
 
@@ -224,19 +334,31 @@ tooltip-files-paste = 붙여넣기
 tooltip-files-search = 파일 검색
 tooltip-manage-users = 사용자 관리
 tooltip-connection-monitor = 연결 모니터
+tooltip-server-log = 서버 로그
 tooltip-server-info = 서버 정보
 tooltip-about = 정보
 tooltip-transfers = 전송
 tooltip-download-all = 전체 다운로드
 tooltip-upload = 업로드
 file-picker-upload-title = 업로드할 파일 선택
+file-picker-download-to-title = 다운로드 폴더 선택
+folder-picker-upload-watch-title = 자동으로 업로드할 폴더 선택
 drop-to-upload = 드롭하여 업로드
 tooltip-clear-inactive = 비활성 항목 지우기
+tooltip-transfer-history = 전송 기록
+tooltip-transfer-history-clear = 기록 지우기
+tooltip-transfer-history-remove = 기록에서 제거
 tooltip-transfer-pause = 일시 정지
 tooltip-transfer-resume = 재개
 tooltip-transfer-cancel = 취소
 tooltip-transfer-remove = 제거
 tooltip-transfer-open-folder = 폴더 열기
+tooltip-transfer-open-file = 파일 열기
+tooltip-transfer-reveal = 파일 관리자에서 보기
+tooltip-transfer-copy-checksum = SHA-256 복사
+tooltip-transfer-collision-rename = 둘 다 유지 (이름 변경)
+tooltip-transfer-collision-overwrite = 기존 파일 덮어쓰기
+tooltip-transfer-collision-skip = 기존 파일 건너뛰기
 tooltip-transfer-move-up = 위로 이동
 tooltip-transfer-move-down = 아래로 이동
 tooltip-transfer-retry = 다시 시도
@@ -249,16 +371,34 @@ tooltip-disconnect = 연결 끊기
 tooltip-edit = 편집
 tooltip-info = 정보
 tooltip-message = 메시지
+tooltip-add-friend = 친구 추가
+tooltip-remove-friend = 친구 삭제
 tooltip-kick = 추방
 tooltip-close = 닫기
+tooltip-retry-send = 다시 보내기
+tooltip-cancel-send = 보내기 취소
+tooltip-message-sent = 보냄
+tooltip-message-delivered = 전달됨
+tooltip-message-read = 읽음
 tooltip-new-tab = 새 탭
 tooltip-close-tab = 탭 닫기
 tooltip-add-bookmark = 북마크 추가
+tooltip-new-folder = 새 폴더
 tooltip-create-user = 사용자 생성
+tooltip-permission-audit = 권한 감사
 tooltip-create-news = 게시물 작성
+tooltip-news-search = 뉴스 검색
+tooltip-news-search-clear = 검색 지우기
 tooltip-delete = 삭제
 context-menu-download = 다운로드
+context-menu-download-to = 다른 위치에 다운로드…
+context-menu-download-select = 선택하여 다운로드…
+context-menu-auto-download = 자동 다운로드…
 context-menu-upload = 업로드
+context-menu-watch-folder = 로컬 폴더 감시…
+context-menu-screenshot-folder = 여기에 스크린샷 공유
+context-menu-unwatch-folder = 폴더 감시 중지
+context-menu-upload-rules = 업로드 규칙…
 context-menu-open = 열기
 
 # =============================================================================
@@ -266,6 +406,7 @@ context-menu-open = 열기
 # =============================================================================
 
 panel-connection-monitor = 연결 모니터
+panel-server-log = 서버 로그
 panel-active-connections = 활성 연결: { $count }
 tab-connections = 연결
 tab-transfers = 전송
@@ -280,9 +421,97 @@ menu-info = 정보
 menu-copy = 복사
 menu-kick = 추방
 menu-ban = 차단
+menu-pop-out = 새 창으로 분리
+menu-mute-tab = 음소거
+menu-unmute-tab = 음소거 해제
+menu-connect-all = 모두 연결
+menu-rename = 이름 변경
+menu-delete = 삭제
 connection-monitor-loading = 연결 로드 중…
 connection-monitor-no-connections = 연결 없음
 connection-monitor-no-transfers = 활성 전송 없음
+connection-monitor-queued = 대기 중
+connection-monitor-weight = 공정 분배 가중치: ×{ $weight }
+connection-monitor-listed = { $zone }에 등록됨
+connection-monitor-queued-count = { $count }개 대기 중
+placeholder-server-log-module = 모듈로 필터
+server-log-loading = 서버 로그 불러오는 중…
+server-log-empty = 로그 줄 없음
+server-log-level-error = 오류만
+server-log-level-warn = 경고 이상
+server-log-level-info = 정보 이상
+server-log-level-debug = 전체 (디버그)
+panel-statistics = 통계
+stats-loading = 통계를 불러오는 중…
+panel-snippet = 스니펫
+snippet-loading = 스니펫 불러오는 중…
+snippet-details = { $author } 님이 공유 · { $expires }에 만료
+stats-range-days = 최근 { $days }일
+stats-chart-max = 최고 일: { $value }
+stats-metric-logins = 로그인
+stats-metric-messages = 메시지
+stats-metric-uploads = 업로드
+stats-metric-upload-bytes = 업로드한 데이터
+stats-metric-downloads = 다운로드
+stats-metric-download-bytes = 다운로드한 데이터
+stats-metric-peak-sessions = 최대 동시 접속자
+panel-admin-dashboard = 관리자 대시보드
+dashboard-loading = 대시보드 불러오는 중…
+dashboard-connections = 연결
+dashboard-sessions = 온라인 사용자
+dashboard-connection-count = 연결 수
+dashboard-transfer-connections = 전송 연결
+dashboard-transfers = 전송
+dashboard-active-transfers = 진행 중
+dashboard-queued-transfers = 대기 중
+dashboard-today = 오늘
+dashboard-disk = 파일 영역
+dashboard-disk-free = 여유 공간
+dashboard-disk-free-of = { $total } 중 { $free } 남음
+dashboard-disk-unknown = 알 수 없음
+dashboard-recent-audit = 최근 감사 이벤트
+dashboard-audit-empty = 아직 감사 이벤트가 없습니다
+dashboard-updated = { $time }에 업데이트됨
+panel-doors = 도어
+doors-loading = 도어를 불러오는 중…
+doors-empty = 이 서버에 도어가 없습니다
+doors-starting = 시작하는 중…
+doors-playing-hint = 키 입력은 도어로 전달됩니다. 끝내려면 나가기를 누르세요.
+doors-exited = 도어가 종료되었습니다
+doors-exited-code = 도어가 코드 { $code }(으)로 종료되었습니다
+
+# =============================================================================
+# Quick Switcher
+# =============================================================================
+
+quick-switcher-placeholder = 채널, 메시지, 북마크 또는 패널로 이동...
+user-list-filter-placeholder = 사용자 필터...
+user-list-section-admins = 관리자
+user-list-section-users = 사용자
+user-list-section-no-channel = 기타
+user-list-section-online = 온라인
+user-list-section-away = 자리 비움
+user-list-section-dnd = 방해 금지
+user-list-idle = 유휴 { $duration }
+quick-switcher-no-matches = 일치하는 항목 없음
+quick-switcher-bookmark = 북마크
+
+# =============================================================================
+# Chat Input
+# =============================================================================
+
+chat-paste-prompt = { $count }줄을 붙여넣을까요?
+chat-paste-too-long-prompt = 메시지 하나로는 너무 깁니다 (최대 { $max }). 그래도 보낼까요?
+button-send-as-messages = 메시지 { $count }개로 보내기
+button-send-as-one-message = 메시지 하나로 보내기
+button-send-in-parts = { $count }개로 나누어 보내기
+button-share-as-snippet = 스니펫으로 공유
+button-expand-code = { $count }줄 모두 보기
+button-collapse-code = 접기
+chat-broadcast-ack-prompt = { $username } 님이 브로드캐스트 확인을 요청했습니다
+button-acknowledge = 확인
+button-dismiss = 닫기
+spell-no-suggestions = 제안 없음
 
 # =============================================================================
 # Empty States
@@ -293,11 +522,35 @@ empty-no-connections = 연결 없음
 empty-no-bookmarks = 북마크 없음
 empty-no-users = 온라인 사용자 없음
 empty-no-channel-members = 채널에 멤버가 없습니다
+empty-no-matching-users = 일치하는 사용자 없음
 user-management-loading = 사용자 로딩 중…
 user-management-no-users = 사용자를 찾을 수 없습니다
+permission-audit-hint = 권한을 선택하면 보유자를 보여주고, 사용자를 클릭하면 그 사용자가 할 수 있는 모든 것을 보여줍니다.
+permission-audit-loading = 로딩 중…
+permission-audit-no-holders = 이 권한을 가진 계정이 없습니다
+permission-audit-effective = { $username }의 유효 권한
+permission-audit-admin = 관리자는 모든 권한을 가집니다
+permission-audit-no-permissions = 이 계정에는 권한이 없습니다
+permission-audit-restricted = 부여되었지만 공유 계정에서는 사용할 수 없음:
+permission-audit-disabled = 비활성화됨
+impersonate-notice = 이 계정에 보이는 내용의 읽기 전용 보기입니다. 모든 조회는 감사 로그에 기록됩니다.
+impersonate-permissions = 권한
+impersonate-files = 파일 ({ $path })
+impersonate-no-file-access = 이 계정은 파일을 탐색할 수 없습니다
+impersonate-empty-folder = 이 폴더는 비어 있습니다
+impersonate-channels = 채널
+impersonate-no-channel-access = 이 계정은 채널 목록을 볼 수 없습니다
+impersonate-no-channels = 보이는 채널이 없습니다
+impersonate-secret = 비밀
 news-loading = 뉴스 로딩 중…
 news-no-posts = 아직 게시물이 없습니다
+news-search-placeholder = 뉴스 검색… (author:이름 since:YYYY-MM-DD until:YYYY-MM-DD)
+news-searching = 검색 중…
+news-search-no-results = 일치하는 게시물이 없습니다
 transfers-empty = 전송 없음
+transfer-history-search-placeholder = 이름, 서버 또는 폴더로 검색…
+transfer-history-empty = 아직 완료된 전송이 없습니다
+transfer-history-no-matches = 검색과 일치하는 전송이 없습니다
 news-updated = 수정됨
 files-loading = 파일 로딩 중…
 files-empty = 이 디렉토리가 비어 있습니다
@@ -309,10 +562,16 @@ files-column-name = 이름
 files-column-size = 크기
 files-column-modified = 수정일
 files-column-path = 경로
+files-column-rating = 평점
 files-search-placeholder = 파일 검색…
 files-search-breadcrumb = 검색 - { $query }
 files-searching = 검색 중…
 files-no-results = 파일을 찾을 수 없습니다
+files-highlights = 하이라이트
+files-highlights-recent = 이번 주 새 업로드
+files-highlights-popular = 가장 많이 다운로드됨
+files-highlights-empty = 아직 없습니다
+files-highlights-downloads = { $count }회
 files-search-query-too-short = 검색어가 너무 짧습니다 (최소 { $min_length }자)
 files-search-query-too-long = 검색어가 너무 깁니다 (최대 { $max_length }자)
 files-search-query-invalid = 검색어에 잘못된 문자가 포함되어 있습니다
@@ -323,6 +582,24 @@ files-share = 공유
 files-rename = 이름 변경
 files-rename-title = "{ $name }" 이름 변경
 files-rename-placeholder = 새 이름
+files-upload-rule-title = "{ $name }" 업로드 규칙
+files-upload-rule-loading = 불러오는 중…
+files-upload-rule-extensions-placeholder = 모든 형식 (예: mp3, flac)
+files-upload-rule-no-limit = 제한 없음
+files-upload-rule-remove = 규칙 제거
+files-upload-rule-help = 규칙은 이 폴더와 하위 폴더에 적용됩니다. 단, 자체 규칙이 있는 하위 폴더는 제외됩니다.
+files-auto-download-title = "{ $name }"에서 자동 다운로드
+files-auto-download-pattern-placeholder = 예: *.mp3, *.m4a
+files-auto-download-remove = 자동 다운로드 중지
+files-auto-download-help = 이 북마크로 연결되어 있는 동안 이 폴더에서 이름이 일치하는 새 파일이 다운로드됩니다. *는 임의의 텍스트, ?는 한 글자와 일치하며, 패턴은 쉼표로 구분합니다. 이미 다운로드한 파일은 건너뜁니다.
+files-upload-rule-hint = 이 폴더 업로드: { $limits }
+files-upload-rule-hint-types = { $extensions }만
+files-upload-rule-hint-file-size = 파일당 최대 { $size }
+files-upload-rule-hint-total-size = 업로드당 최대 { $size }
+files-upload-preview-title = 파일이 이미 있습니다
+files-upload-preview-comparing = 서버의 파일과 비교하는 중…
+files-upload-preview-summary = 새 파일 { $new }개, 서버에 이미 있음 { $identical }개, 내용이 다름 { $conflicts }개:
+files-upload-preview-blocked = 파일 { $count }개는 서버에 같은 이름의 폴더가 있어 건너뜁니다
 files-cut = 잘라내기
 files-copy = 복사
 files-paste = 붙여넣기
@@ -332,6 +609,13 @@ files-delete-confirm-title = 삭제 확인
 files-delete-confirm-message = "{ $name }"을(를) 삭제하시겠습니까?
 # File Overwrite Dialog
 files-overwrite-title = 파일이 이미 존재합니다
+files-download-to-title = { $name } 다운로드
+label-remember-for-server = 이 서버에 기억
+label-select-all = 모두 선택
+files-download-select-summary = { $total }개 중 { $count }개 선택됨 ({ $size })
+files-download-select-too-many = 개별 파일은 최대 { $max }개까지 선택할 수 있습니다. 대신 폴더 전체를 다운로드하세요.
+files-download-duplicate-title = 이미 다운로드됨
+files-download-duplicate-message = "{ $name }"이(가) 이 컴퓨터의 { $path }에 이미 있습니다. 다시 다운로드하시겠습니까?
 files-overwrite-message = "{ $name }" 이름의 파일 또는 폴더가 대상 위치에 이미 존재합니다. 교체하시겠습니까?
 
 # File Info Dialog
@@ -347,6 +631,14 @@ files-info-directory = 디렉토리
 files-info-symlink = 심볼릭 링크:
 files-info-yes = 예
 files-info-na = N/A
+files-info-rating = 평점:
+files-info-rating-value = { $average } (평가 { $count }개)
+files-info-not-rated = 아직 평가 없음
+files-info-your-rating = 내 평점:
+files-info-comments = 댓글 ({ $count })
+files-comments-loading = 댓글 불러오는 중…
+files-comments-empty = 아직 댓글이 없습니다
+files-comment-placeholder = 댓글 작성…
 
 # =============================================================================
 # Directory Name Validation Errors
@@ -365,6 +657,7 @@ err-dir-name-invalid = 폴더 이름에 잘못된 문자가 포함되어 있습
 tab-general = 일반
 tab-chat = 채팅
 tab-network = 네트워크
+tab-security = 보안
 tab-audio = 오디오
 
 # =============================================================================
@@ -386,6 +679,8 @@ chat-prefix-system = [시스템]
 chat-prefix-error = [오류]
 chat-prefix-info = [정보]
 chat-prefix-broadcast = [BROADCAST]
+chat-prefix-queued = [PENDING]
+chat-export-subtitle = { $date }에 { $server }에서 내보냄
 
 # =============================================================================
 # Success Messages
@@ -406,11 +701,25 @@ msg-channel-list-header = 사용 가능한 채널:
 msg-no-channels = 사용 가능한 채널이 없습니다
 msg-channel-member-count = { $count }명의 멤버
 channel-secret = 비공개
+channel-info-topic = 주제
+channel-info-rules = 규칙
+channel-info-language = 언어
 msg-broadcast-sent = 공지가 성공적으로 전송되었습니다
+msg-broadcast-delivered = 브로드캐스트를 { $count }명에게 전달했습니다
+msg-broadcast-scheduled = 브로드캐스트가 { $time }에 예약되었습니다
+msg-broadcast-acknowledged = 브로드캐스트를 { $delivered }명 중 { $count }명이 확인했습니다
+msg-storage-low = 서버의 { $volume } 디스크 공간이 부족합니다: { $free } 남음, 최소 { $threshold } 미만
+msg-storage-recovered = 서버의 { $volume } 디스크 공간이 회복되었습니다: { $free } 남음
+storage-volume-files = 파일 영역
+storage-volume-database = 데이터베이스
+msg-friend-added = { $username }님을 친구에 추가했습니다
+msg-friend-removed = { $username }님을 친구에서 삭제했습니다
 msg-user-created = 사용자가 성공적으로 생성되었습니다
 msg-user-created-name = 사용자 '{ $username }'이(가) 성공적으로 생성되었습니다
 msg-user-deleted = 사용자가 성공적으로 삭제되었습니다
 msg-user-deleted-name = 사용자 '{ $username }'이(가) 성공적으로 삭제되었습니다
+msg-user-erased = 사용자를 삭제하고 개인 데이터를 지웠습니다
+msg-user-erased-name = 사용자 '{ $username }'을(를) 삭제하고 개인 데이터를 지웠습니다
 msg-user-updated = 사용자가 성공적으로 업데이트되었습니다
 msg-user-updated-name = 사용자 '{ $username }'이(가) 성공적으로 업데이트되었습니다
 msg-permissions-updated = 권한이 업데이트되었습니다
@@ -425,6 +734,8 @@ msg-welcome-back = 다시 오신 것을 환영합니다
 msg-status-set = 상태 설정: { $status }
 msg-status-cleared = 상태 지움
 msg-user-is-away = { $nickname }님이 자리 비움 상태입니다
+msg-group-member-joined = { $nickname }님이 대화에 참여했습니다
+msg-group-member-left = { $nickname }님이 대화에서 나갔습니다
 msg-user-is-away-status = { $nickname }님이 자리 비움 상태입니다: { $status }
 
 
@@ -436,6 +747,14 @@ msg-topic-cleared = { $username }님이 주제를 삭제함
 msg-topic-set = { $username }님이 주제를 설정함: { $topic }
 msg-secret-set = { $username }님이 비밀 모드를 활성화함
 msg-secret-cleared = { $username }님이 비밀 모드를 비활성화함
+msg-topic-locked = { $username }님이 주제를 잠금
+msg-topic-unlocked = { $username }님이 주제 잠금을 해제함
+msg-rules-set = { $username }님이 채널 규칙을 업데이트함
+msg-rules-cleared = { $username }님이 채널 규칙을 지움
+msg-language-set = { $username }님이 채널 언어를 { $language }(으)로 설정했습니다
+msg-language-cleared = { $username }님이 채널 언어를 지웠습니다
+msg-voice-limit-set = { $username }님이 음성 제한을 { $limit }(으)로 설정했습니다
+msg-voice-limit-cleared = { $username }님이 음성 제한을 해제했습니다
 msg-server-info-updated = 서버 설정이 업데이트되었습니다
 msg-topic-display = 주제: { $topic }
 confirm-delete-user = '{ $username }' 사용자를 삭제하시겠습니까?
@@ -455,6 +774,7 @@ err-user-kick-failed = 사용자 추방 실패
 err-unknown = 알 수 없는 오류
 err-no-shutdown-handle = 연결 오류: 종료 핸들 없음
 err-userlist-failed = 사용자 목록 새로고침 실패
+err-request-timeout = 서버가 제때 응답하지 않았습니다
 err-port-invalid = 포트는 유효한 숫자여야 합니다 (1-65535)
 
 # Network connection errors
@@ -468,12 +788,16 @@ err-connection-closed = 연결이 종료되었습니다
 err-could-not-determine-config-dir = 설정 디렉토리를 확인할 수 없습니다
 err-message-too-long = 메시지가 너무 깁니다 ({ $length }자, 최대 { $max }자)
 err-send-failed = 메시지 전송 실패
+err-checksum-failed = 체크섬 계산 실패
 err-no-chat-permission = 메시지를 보낼 권한이 없습니다
+err-chat-window-command = 채팅 창에서는 메시지와 /me만 보낼 수 있습니다
 err-console-no-send = /{ $join }으로 채널에 참가하거나 /{ $msg }로 사용자에게 메시지를 보내세요
 err-broadcast-too-long = 방송이 너무 깁니다 ({ $length }자, 최대 { $max }자)
 err-broadcast-send-failed = 브로드캐스트 전송 실패
+err-broadcast-target-required = 브로드캐스트를 받을 대상을 선택하세요
 err-name-required = 북마크 이름은 필수입니다
 err-address-required = 서버 주소는 필수입니다
+err-fallback-address-invalid = 잘못된 대체 주소: { $address }
 err-port-required = 포트는 필수입니다
 err-username-required = 사용자 이름은 필수입니다
 err-password-required = 비밀번호는 필수입니다
@@ -491,13 +815,20 @@ err-nickname-too-long = 닉네임이 너무 깁니다 (최대 { $max }자)
 err-nickname-invalid = 닉네임에 잘못된 문자가 포함되어 있습니다
 err-password-too-long = 비밀번호가 너무 깁니다 (최대 { $max }자)
 err-passwords-do-not-match = 비밀번호가 일치하지 않습니다
+err-wrong-master-passphrase = 마스터 암호 문구가 올바르지 않습니다
+err-master-passphrase-empty = 마스터 암호 문구를 입력하세요
+err-password-vault = 비밀번호 보관함 오류: { $error }
 err-current-password-required = 현재 비밀번호는 필수입니다
 err-new-password-required = 새 비밀번호는 필수입니다
 err-confirm-password-required = 새 비밀번호를 확인해 주세요
 err-topic-too-long = 주제가 너무 깁니다 ({ $length }자, 최대 { $max }자)
+err-entry-message-too-long = 입장 메시지가 너무 깁니다 ({ $length }자, 최대 { $max }자)
+err-rules-too-long = 규칙이 너무 깁니다 ({ $length }자, 최대 { $max }자)
 err-avatar-unsupported-type = 지원되지 않는 파일 형식입니다. PNG, WebP, JPEG 또는 SVG를 사용하세요.
 err-avatar-too-large = 아바타가 너무 큽니다. 최대 크기는 { $max_kb }KB입니다.
 err-avatar-decode-failed = 아바타를 디코딩할 수 없습니다. 파일이 손상되었을 수 있습니다.
+err-known-server-export = 인증서를 내보내지 못했습니다: { $error }
+err-config-encryption = 설정을 암호화하지 못했습니다: { $error }
 err-server-name-empty = 서버 이름은 비워둘 수 없습니다
 err-server-name-too-long = 서버 이름이 너무 깁니다 (최대 { $max }자)
 err-server-name-contains-newlines = 서버 이름에 줄바꿈을 포함할 수 없습니다
@@ -518,14 +849,23 @@ err-failed-send-update = 업데이트 전송 실패: { $error }
 # =============================================================================
 
 err-failed-save-config = 설정 저장 실패: { $error }
+err-friends-no-bookmark = 친구는 서버 북마크에 저장됩니다. 먼저 이 서버를 북마크하세요
+err-friends-too-many = 친구가 너무 많습니다 (최대 { $max })
+err-friend-exists = { $username }님은 이미 친구입니다
+err-friend-not-found = { $username }님은 친구가 아닙니다
 err-failed-save-settings = 설정 저장 실패: { $error }
 err-invalid-port-bookmark = 북마크의 포트가 잘못되었습니다: { $name }
 err-failed-send-broadcast = 브로드캐스트 전송 실패: { $error }
 err-failed-send-message = 메시지 전송 실패: { $error }
+err-failed-start-group = 그룹 대화를 시작하지 못했습니다: { $error }
+err-failed-share-snippet = 스니펫을 공유하지 못했습니다: { $error }
 err-failed-create-user = 사용자 생성 실패: { $error }
 err-failed-delete-user = 사용자 삭제 실패: { $error }
 err-failed-update-user = 사용자 업데이트 실패: { $error }
 err-failed-update-topic = 주제 업데이트 실패: { $error }
+err-failed-lock-topic = 주제 잠금 실패: { $error }
+err-failed-set-language = 채널 언어 설정 실패: { $error }
+err-failed-update-entry = 채널 업데이트 실패: { $error }
 err-message-too-long-details = { $error } ({ $length }자, 최대 { $max })
 
 # Network connection errors (with parameters)
@@ -537,6 +877,14 @@ err-tls-handshake-failed = TLS 핸드셰이크 실패: { $error }
 err-failed-send-handshake = 핸드셰이크 전송 실패: { $error }
 err-failed-read-handshake = 핸드셰이크 응답 읽기 실패: { $error }
 err-handshake-failed = 핸드셰이크 실패: { $error }
+err-proof-of-work-too-hard = 서버가 너무 많은 작업 증명을 요구했습니다 (난이도 { $difficulty })
+err-sso-not-offered = 이 서버는 싱글 사인온을 제공하지 않습니다
+err-sso-failed = 싱글 사인온 실패: { $error }
+err-sso-browser = 브라우저를 열 수 없습니다: { $error }
+err-sso-timeout = 브라우저의 싱글 사인온 응답 대기 시간이 초과되었습니다
+err-sso-state-mismatch = 싱글 사인온 응답이 요청과 일치하지 않습니다
+sso-browser-done = 로그인되었습니다. 이 창을 닫고 Nexus로 돌아가세요.
+sso-browser-failed = 로그인에 실패했습니다. 이 창을 닫고 Nexus로 돌아가세요.
 err-failed-parse-handshake = 핸드셰이크 응답 구문 분석 실패: { $error }
 err-failed-send-login = 로그인 전송 실패: { $error }
 err-failed-read-login = 로그인 응답 읽기 실패: { $error }
@@ -544,6 +892,7 @@ err-failed-parse-login = 로그인 응답 구문 분석 실패: { $error }
 err-failed-create-server-name = 서버 이름 생성 실패: { $error }
 err-failed-create-config-dir = 설정 디렉토리 생성 실패: { $error }
 err-failed-serialize-config = 설정 직렬화 실패: { $error }
+err-config-locked = 설정이 암호화되어 잠겨 있습니다. 잠금을 해제할 때까지 변경 사항을 저장할 수 없습니다
 err-failed-write-config = 설정 파일 쓰기 실패: { $error }
 err-failed-read-config-metadata = 설정 파일 메타데이터 읽기 실패: { $error }
 err-failed-set-config-permissions = 설정 파일 권한 설정 실패: { $error }
@@ -553,6 +902,10 @@ err-failed-set-config-permissions = 설정 파일 권한 설정 실패: { $error
 # =============================================================================
 
 fingerprint-warning = 이는 보안 문제(MITM 공격)를 나타내거나 서버 인증서가 재생성되었을 수 있습니다. 서버 관리자를 신뢰하는 경우에만 수락하세요.
+fingerprint-new-server-prompt = 이 서버에 처음 연결합니다. 아래 지문이 서버 관리자가 공개한 지문과 일치하는 경우에만 인증서를 신뢰하세요.
+password-vault-create-description = 사용할 수 있는 시스템 키체인이 없습니다. 저장된 북마크 비밀번호를 암호화할 마스터 암호 문구를 정하거나, 건너뛰어 설정 파일에 그대로 둡니다.
+password-vault-unlock-description = 저장된 북마크 비밀번호의 잠금을 해제하려면 마스터 암호 문구를 입력하세요. 건너뛰면 Nexus를 다시 시작할 때까지 북마크가 비밀번호 없이 연결됩니다.
+config-unlock-description = 설정, 북마크 및 채팅 기록이 암호화되어 있습니다. 잠금을 해제하려면 마스터 암호 문구를 입력하세요. 건너뛰면 Nexus가 기본 설정으로 시작되며 다시 시작할 때까지 아무것도 저장되지 않습니다.
 
 # =============================================================================
 # User Info Display
@@ -569,10 +922,12 @@ user-info-account = 계정: { $username }
 user-info-connected = 연결됨:
 user-info-connected-value = { $duration } 전
 user-info-connected-value-sessions = { $duration } 전 ({ $count }개 세션)
+user-info-idle = 유휴:
 user-info-features = 기능:
 user-info-features-value = { $features }
 user-info-features-none = 없음
 user-info-locale = 언어:
+user-info-client = 클라이언트:
 user-info-address = 주소:
 user-info-addresses = 주소:
 user-info-created = 생성일:
@@ -581,6 +936,12 @@ user-info-unknown = 알 수 없음
 user-info-loading = 사용자 정보 로드 중…
 user-info-status = 상태:
 user-info-channels = 채널:
+user-info-rank = 등급:
+user-info-logins = 로그인:
+user-info-messages = 메시지:
+user-info-uploads = 업로드:
+user-info-downloaded = 다운로드량:
+user-info-last-login = 마지막 로그인:
 
 # =============================================================================
 # Time Duration
@@ -604,26 +965,51 @@ cmd-me-usage = 사용법: /{ $command } <액션>
 err-me-no-target = /{ $join }으로 채널에 참가하거나 /{ $msg }로 사용자에게 액션을 보내세요
 cmd-message-desc = 사용자에게 메시지 보내기
 cmd-message-usage = 사용법: /{ $command } <사용자명> <메시지>
+cmd-group-desc = 여러 사용자와 그룹 대화 시작
+cmd-group-usage = 사용법: /{ $command } <사용자명> [<사용자명>...]
+cmd-invite-desc = 현재 그룹 대화에 사용자 추가
+cmd-invite-usage = 사용법: /{ $command } <사용자명>
 cmd-ping-desc = 서버 지연 시간 측정
 cmd-ping-usage = 사용법: /{ $command }
+cmd-radio-desc = 채널의 음성 청취자에게 오디오 방송
+cmd-radio-usage = 사용법: /{ $command }
+err-radio-no-channel = 이 명령은 채널에서만 작동합니다
+err-radio-in-voice = 방송을 시작하기 전에 음성에서 나가세요
 ping-result = 응답: { $ms }ms
 cmd-userinfo-desc = 사용자 정보 표시
 cmd-userinfo-usage = 사용법: /{ $command } <사용자명>
 cmd-kick-desc = 서버에서 사용자 추방
 cmd-kick-usage = 사용법: /{ $command } <사용자명> [사유]
+cmd-top-desc = 가장 활발한 사용자 표시
+cmd-top-usage = 사용법: /{ $command } [logins|messages|uploads|downloaded]
 cmd-topic-desc = 채팅 주제 보기 또는 관리
-cmd-topic-usage = 사용법: /{ $command } [설정|지우기] [주제]
+cmd-topic-usage = 사용법: /{ $command } [설정|지우기|잠금|잠금해제|기록] [주제]
 cmd-topic-arg-set = 설정
 cmd-topic-arg-clear = 지우기
+cmd-topic-arg-lock = 잠금
+cmd-topic-arg-unlock = 잠금해제
+cmd-topic-arg-history = 기록
 cmd-topic-set-usage = 사용법: /{ $command } 설정 <주제>
 cmd-topic-none = 설정된 주제가 없습니다
 cmd-broadcast-desc = 모든 사용자에게 공지 보내기
 cmd-broadcast-usage = 사용법: /{ $command } <메시지>
 cmd-clear-desc = 현재 탭의 채팅 기록 지우기
 cmd-clear-usage = 사용법: /{ $command }
+cmd-export-desc = 현재 탭을 HTML 파일로 저장
+cmd-export-usage = 사용법: /{ $command }
+cmd-clip-desc = 사운드보드 클립 목록, 재생, 추가, 삭제
+cmd-clip-usage = 사용법: /{ $command } [목록|재생 <이름>|추가 <이름>|삭제 <이름>|<이름>]
+cmd-clip-arg-list = 목록
+cmd-clip-arg-play = 재생
+cmd-clip-arg-add = 추가
+cmd-clip-arg-delete = 삭제
 cmd-focus-desc = 서버 채팅 또는 사용자 메시지 창에 포커스
 cmd-focus-usage = 사용법: /{ $command } [사용자명]
 cmd-focus-not-found = 사용자를 찾을 수 없습니다: { $name }
+cmd-friend-desc = 친구 목록 보기, 추가 또는 삭제
+cmd-friend-usage = 사용법: /{ $command } [list] | add <사용자명> | remove <사용자명>
+cmd-friend-list-header = 친구:
+cmd-friend-list-empty = 아직 친구가 없습니다. /{ $command } add <사용자명>으로 추가하세요
 cmd-list-desc = 접속 중/전체 사용자 표시
 cmd-list-arg-all = 전체
 cmd-list-usage = 사용법: /{ $command } [전체]
@@ -633,6 +1019,13 @@ cmd-list-all-no-permission = 전체 사용자를 보려면 user_edit 또는 user
 cmd-list-all-output = 사용자: { $users } ({ $count }명)
 cmd-help-usage = 사용법: /{ $command } [명령어]
 cmd-topic-permission-denied = 주제를 편집할 권한이 없습니다
+cmd-topic-lock-permission-denied = 주제를 잠글 권한이 없습니다
+msg-topic-already-locked = 주제가 이미 잠겨 있습니다
+msg-topic-already-unlocked = 주제가 이미 잠금 해제되어 있습니다
+msg-topic-history-header = { $channel } 주제 기록:
+msg-topic-history-set = { $time } { $username }: { $topic }
+msg-topic-history-cleared = { $time } { $username }님이 주제를 지움
+msg-topic-history-empty = 기록된 주제 변경이 없습니다
 cmd-window-desc = 채팅 탭 관리
 cmd-window-usage = 사용법: /{ $command } [다음|이전|닫기 [사용자명]]
 cmd-window-arg-next = 다음
@@ -657,15 +1050,55 @@ cmd-unban-desc = IP 차단 해제
 cmd-unban-usage = 사용법: /{ $command } <대상>
 cmd-bans-desc = 활성 차단 목록 표시
 cmd-bans-usage = 사용법: /{ $command }
+cmd-register-desc = 게스트 세션에서 계정 등록
+cmd-register-usage = 사용법: /{ $command } <사용자명> <비밀번호>
 cmd-reindex-desc = 파일 인덱스 재구성 트리거
 cmd-reindex-usage = 사용법: /{ $command }
+cmd-cleanup-desc = 파일 영역에서 중단된 전송의 잔여물 찾기
+cmd-cleanup-usage = 사용법: /{ $command } [delete]
+cmd-filecheck-desc = 파일 영역을 검색 색인 및 파일 메타데이터와 대조합니다
+cmd-filecheck-usage = 사용법: /{ $command } [repair]
+cmd-screenshot-desc = 주석을 단 스크린샷을 현재 탭에 공유
+cmd-screenshot-usage = 사용법: /{ $command }
+err-screenshot-no-target = 스크린샷은 채널이나 사용자 메시지 탭에서만 공유할 수 있습니다
+err-screenshot-no-folder = 스크린샷 폴더가 설정되지 않았습니다 — 파일에서 업로드 폴더의 "여기에 스크린샷 공유"를 선택하세요
+err-screenshot-capture = 스크린샷 실패: { $error }
+err-screenshot-save = 스크린샷을 저장할 수 없습니다: { $error }
+err-screenshot-upload = 스크린샷 업로드 실패: { $error }
+err-export-empty = 이 탭에는 내보낼 메시지가 없습니다
+err-chat-export = 채팅을 내보내지 못했습니다: { $error }
+screenshot-title = 스크린샷 공유
+screenshot-tool-crop = 자르기
+screenshot-tool-arrow = 화살표
+screenshot-hint-crop = 남길 부분을 드래그해서 선택하세요
+screenshot-hint-arrow = 화살표의 꼬리에서 머리 쪽으로 드래그하세요
 msg-reindex-triggered = 파일 인덱스 재구성이 트리거되었습니다
+msg-cleanup-nothing = 중단된 전송의 잔여물이 없습니다
+msg-cleanup-found = 중단된 전송의 잔여물을 찾았습니다:
+msg-cleanup-deleted = 중단된 전송의 잔여물을 삭제했습니다:
+msg-cleanup-parts = 고아 .part 파일: { $count } ({ $size })
+msg-cleanup-temp = 남은 임시 파일: { $count } ({ $size })
+msg-cleanup-journal = 파일이 없는 업로드 기록 항목: { $count }
+msg-cleanup-index = 파일이 없는 검색 인덱스 항목: { $count }
+msg-cleanup-hint = 위 파일을 확인한 후 /cleanup delete를 실행하여 삭제하십시오
+msg-filecheck-issue = { $kind }: { $path }
+msg-filecheck-issue-repaired = { $kind }: { $path } (복구됨)
+msg-filecheck-clean = 파일 영역 검사에서 { $entries }개 항목 중 문제가 발견되지 않았습니다
+msg-filecheck-done = 파일 영역 검사에서 { $entries }개 항목 중 { $issues }개의 문제가 발견되었습니다 ({ $repaired }개 복구됨)
+msg-filecheck-index-skipped = 검색 색인이 아직 만들어지지 않았거나 재구축 대기 중이므로 비교하지 않았습니다
+msg-filecheck-hint = /filecheck repair를 실행하면 색인을 재구축하고 없는 경로의 메타데이터를 제거합니다
+filecheck-not-indexed = 검색 색인에 없음
+filecheck-indexed-but-missing = 검색 색인에 있지만 디스크에 없음
+filecheck-index-outdated = 검색 색인의 정보가 오래됨
+filecheck-orphaned-activity = 없는 경로의 기록
+filecheck-orphaned-reviews = 없는 경로의 평가 또는 댓글
+filecheck-orphaned-upload-rule = 없는 폴더의 업로드 규칙
 cmd-join-desc = 채널에 참가하거나 생성
 cmd-join-usage = 사용법: /{ $command } #채널
 cmd-leave-desc = 채널 나가기
 cmd-leave-usage = 사용법: /{ $command } [#채널]
 cmd-channels-desc = 사용 가능한 채널 목록
-cmd-channels-usage = 사용법: /{ $command }
+cmd-channels-usage = 사용법: /{ $command } [언어]
 cmd-secret-desc = 현재 채널의 비밀 모드 전환
 cmd-secret-usage = 사용법: /{ $command } [켜기|끄기]
 cmd-secret-arg-on = 켜기
@@ -678,12 +1111,40 @@ msg-secret-enabled = 채널이 이제 비밀입니다
 msg-secret-disabled = 채널이 이제 공개입니다
 msg-secret-status-on = 이 채널은 비공개입니다
 msg-secret-status-off = 이 채널은 공개입니다
+cmd-voicelimit-desc = 현재 채널의 음성 참여자 제한을 보거나 설정합니다
+cmd-voicelimit-usage = 사용법: /{ $command } [<인원>|끄기]
+cmd-vt-desc = 음성 세션에 텍스트 메시지 보내기
+cmd-vt-usage = 사용법: /{ $command } <메시지>
+cmd-voicelimit-arg-off = 끄기
+err-voicelimit-no-channel = 이 명령은 채널에서만 사용할 수 있습니다
+msg-voice-limit-status = 이 채널은 음성에 최대 { $limit }명까지 허용합니다
+msg-voice-limit-status-none = 이 채널에는 음성 제한이 없습니다
+msg-voice-clip-none = 사운드보드 클립이 없습니다
+msg-voice-clip-list = 사운드보드 클립 ({ $count }):
+msg-voice-clip-entry = { $name } ({ $seconds }초, { $nickname })
+msg-voice-clip-uploaded = 클립을 사운드보드에 추가했습니다
+msg-voice-clip-deleted = 클립을 사운드보드에서 삭제했습니다
 cmd-trust-desc = IP, CIDR 범위 또는 닉네임을 신뢰하여 차단 우회
 cmd-trust-usage = 사용법: /{ $command } <대상> [기간] [사유]
 cmd-untrust-desc = 신뢰된 IP 항목 제거
 cmd-untrust-usage = 사용법: /{ $command } <대상>
 cmd-trusted-desc = 신뢰된 IP 목록 표시
 cmd-trusted-usage = 사용법: /{ $command }
+cmd-ldap-desc = 디렉터리 그룹 매핑 나열, 설정 또는 제거
+cmd-ldap-usage = 사용법: /{ $command } [list|set <권한|admin|none> <그룹 DN>|remove <그룹 DN>]
+cmd-nickname-desc = 계정의 닉네임 예약 또는 해제
+cmd-nickname-usage = 사용법: /{ $command } [register <닉네임> [사용자명]|release <닉네임>]
+cmd-history-desc = 서버가 사용자 메시지를 보관하는 기간을 표시하거나 설정하고, 이전 메시지를 불러옵니다
+cmd-history-usage = 사용법: /{ $command } [<일수>|more]
+cmd-alias-desc = 명령과 메시지의 단축키 만들기
+cmd-alias-usage = 사용법: /{ $command } [list|set <이름> <텍스트>|remove <이름>]
+cmd-alias-list-header = 내 별칭:
+cmd-alias-list-empty = 아직 별칭이 없습니다. /{ $command } set <이름> <텍스트>로 만드세요
+cmd-alias-set = /{ $name } 실행 내용: { $text }
+cmd-alias-removed = 별칭 /{ $name }을(를) 삭제했습니다
+cmd-alias-not-found = /{ $name } 별칭이 없습니다
+cmd-alias-invalid-name = 별칭 이름에는 문자, 숫자, - 및 _만 사용할 수 있습니다 (최대 { $max }자)
+cmd-alias-builtin = /{ $name }은(는) 기본 명령이라 별칭으로 사용할 수 없습니다
 
 # =============================================================================
 # About Panel
@@ -696,9 +1157,25 @@ err-server-image-invalid-format = 서버 이미지 형식이 잘못되었습니
 err-server-image-unsupported-type = 지원되지 않는 서버 이미지 유형입니다 (PNG, WebP, JPEG 또는 SVG만 지원)
 err-server-image-decode-failed = 이미지를 디코딩할 수 없습니다. 파일이 손상되었을 수 있습니다.
 err-failed-read-image = 이미지를 읽을 수 없습니다: { $error }
-err-news-empty = 게시물에는 텍스트 또는 이미지가 필요합니다
+err-news-empty = 게시물에는 텍스트 또는 첨부 파일이 필요합니다
 err-news-body-too-long = 내용이 너무 깁니다 ({ $length }자, 최대 { $max }자)
 err-news-body-invalid-characters = 본문에 잘못된 제어 문자가 포함되어 있습니다
+err-news-too-many-attachments = 게시물에는 최대 { $max }개의 첨부 파일을 추가할 수 있습니다
+err-news-caption-too-long = 캡션이 너무 깁니다 (최대 { $max }자)
+err-news-caption-invalid-characters = 캡션은 제어 문자 없이 한 줄이어야 합니다
+err-news-file-path-invalid = 잘못된 파일 경로입니다
+err-news-search-date = 잘못된 날짜: { $date } (YYYY-MM-DD 형식 사용)
+err-file-comment-empty = 댓글은 비워 둘 수 없습니다
+err-file-comment-too-long = 댓글이 너무 깁니다 ({ $length }자, 최대 { $max })
+err-file-comment-invalid-characters = 댓글에 잘못된 제어 문자가 있습니다
+err-upload-rule-invalid-extension = 잘못된 파일 형식 "{ $extension }" (mp3처럼 문자와 숫자를 사용하세요)
+err-auto-download-pattern-required = 파일 이름 패턴을 하나 이상 입력하십시오
+err-auto-download-pattern-invalid = 잘못된 패턴 "{ $pattern }" (패턴은 파일 이름과 일치하므로 슬래시를 포함할 수 없습니다)
+err-upload-rule-too-many-extensions = 파일 형식이 너무 많습니다 (최대 { $max })
+err-upload-rule-invalid-size = 잘못된 크기 "{ $value }" (메가바이트 수를 입력하세요)
+err-ldap-group-empty = 그룹 DN은 비워둘 수 없습니다
+err-ldap-group-too-long = 그룹 DN이 너무 깁니다 (최대 { $max }자)
+err-ldap-group-invalid-characters = 그룹 DN 앞뒤에 공백이나 제어 문자를 사용할 수 없습니다
 err-news-image-decode-failed = 이미지를 디코딩할 수 없습니다. 파일이 손상되었을 수 있습니다.
 
 # Proxy errors
@@ -706,9 +1183,31 @@ err-proxy-connection-failed = 프록시 연결 실패: { $error }
 err-proxy-connection-timeout = { $seconds }초 후 프록시 연결 시간 초과
 err-proxy-address-required = 프록시가 활성화된 경우 프록시 주소가 필요합니다
 err-proxy-port-invalid = 프록시 포트는 1에서 65535 사이여야 합니다
-err-news-image-too-large = 이미지가 너무 큽니다 (최대 512KB)
+err-news-image-too-large = 이미지가 너무 큽니다 (최대 { $max_kb }KB)
 err-news-image-unsupported-type = 지원되지 않는 이미지 유형입니다 (PNG, WebP, JPEG 또는 SVG만 지원)
 err-topic-no-channel = 이 명령은 채널에서만 작동합니다
+err-entry-no-channel = 이 명령은 채널에서만 작동합니다
+cmd-welcome-desc = 채널에 입장하는 사용자에게 표시할 메시지 보기 또는 설정
+cmd-welcome-usage = 사용법: /{ $command } [설정 <메시지>|지우기]
+cmd-welcome-arg-set = 설정
+cmd-welcome-arg-clear = 지우기
+cmd-welcome-none = 설정된 입장 메시지가 없습니다
+msg-entry-message-display = 입장 메시지: { $message }
+msg-entry-message-updated = 입장 메시지가 업데이트되었습니다
+msg-entry-message-cleared = 입장 메시지가 삭제되었습니다
+cmd-rules-desc = 채널 규칙 보기 또는 설정
+cmd-rules-usage = 사용법: /{ $command } [설정 <규칙>|지우기]
+cmd-rules-arg-set = 설정
+cmd-rules-arg-clear = 지우기
+cmd-rules-none = 설정된 규칙이 없습니다
+msg-rules-header = { $channel } 규칙:
+cmd-language-desc = 채널 언어 태그 보기 또는 설정
+cmd-language-usage = 사용법: /{ $command } [설정 <언어>|지우기]
+cmd-language-arg-set = 설정
+cmd-language-arg-clear = 지우기
+msg-language-status = 채널 언어: { $language }
+msg-language-none = 설정된 언어가 없습니다
+err-language-invalid = 잘못된 언어 태그: { $language } (예: ko, pt-BR)
 err-join-channel = 채널 참가 실패: { $error }
 err-leave-channel = 채널 나가기 실패: { $error }
 err-list-channels = 채널 목록 가져오기 실패: { $error }
@@ -718,6 +1217,8 @@ err-channel-too-long = 채널 이름이 너무 깁니다 (최대 { $max }자)
 err-channel-missing-prefix = 채널 이름은 #으로 시작해야 합니다
 err-channel-invalid-characters = 채널 이름에 잘못된 문자가 포함되어 있습니다
 err-leave-no-channel = 인수 없이 /{ $command }를 사용하려면 채널에 있어야 합니다
+err-invite-no-group = /{ $command }는 그룹 대화 탭에서 사용하세요
+err-group-too-many-members = 그룹 대화는 최대 { $max }명까지 참여할 수 있습니다
 err-not-in-channel = 채널 { $channel }에 있지 않습니다
 err-leave-already-pending = 이미 서버 응답을 기다리는 중입니다
 err-uri-connection-failed = { $host }에 연결하지 못했습니다: { $error }
@@ -731,6 +1232,15 @@ label-port = 포트:
 # Transfer statuses
 transfer-status-queued = 대기 중
 transfer-status-connecting = 연결 중…
+transfer-status-server-queued = 서버 슬롯 대기 중 (#{ $position })
+transfer-collision-pending = 파일이 존재합니다: { $path }
+collision-action-renamed = { $count }개 이름 변경
+collision-action-overwritten = { $count }개 덮어씀
+collision-action-skipped = { $count }개 건너뜀
+transfer-bar-count = 전송 { $count }건
+status-bar-users = 사용자 { $count }명
+status-bar-latency = { $ms } ms
+status-bar-server-time = 서버 시간 { $time } ({ $zone })
 transfer-status-transferring = 전송 중
 transfer-status-paused = 일시 정지
 transfer-status-completed = 완료
@@ -789,6 +1299,7 @@ event-broadcast = 브로드캐스트
 event-chat-message = 채팅 메시지
 event-chat-mention = 채팅 멘션
 event-connection-lost = 연결 끊김
+event-friend-online = 친구 온라인
 event-news-post = 뉴스 게시물
 event-permissions-changed = 권한 변경됨
 event-transfer-complete = 전송 완료
@@ -823,6 +1334,17 @@ settings-sound-test = 테스트
 settings-notification-test = 테스트
 event-settings-show-toast = 토스트 표시
 settings-toast-test = 테스트
+settings-do-not-disturb = 방해 금지
+settings-quiet-hours = 조용한 시간
+settings-do-not-disturb-hint = 알림, 토스트, 소리를 끕니다. 읽지 않음 배지는 계속 쌓이며, 다른 사용자에게는 이름 옆에 🔕가 표시됩니다.
+settings-notification-rules = 알림 규칙
+settings-notification-rules-server = 서버:
+settings-notification-rules-channels = 채널별 설정
+settings-notification-rules-keywords = 감시 키워드
+settings-notification-rules-no-bookmarks = 알림 규칙을 설정하려면 서버를 북마크하세요.
+channel-notification-all = 모든 메시지
+channel-notification-mentions = 멘션만
+channel-notification-mute = 음소거
 
 # Sound names
 sound-none = 없음
@@ -841,6 +1363,8 @@ notification-chat-message = 새 채팅 메시지
 notification-chat-message-from = {$username}님의 채팅
 notification-connection-lost = 연결이 끊어졌습니다
 notification-connection-lost-from = {$server}에서 연결이 끊어졌습니다
+notification-friend-online = 친구가 온라인 상태가 되었습니다
+notification-friend-online-name = {$username}님이 온라인 상태입니다
 notification-download-complete = 다운로드 완료
 notification-download-failed = 다운로드 실패
 notification-news-post = 새 뉴스 게시물
@@ -894,6 +1418,18 @@ ban-duration-7d = 7일
 ban-duration-30d = 30일
 ban-duration-permanent = 영구
 
+# Broadcast target and delay options
+broadcast-target-label = 받는 대상:
+broadcast-target-all = 모두
+broadcast-target-channel = 채널 멤버
+broadcast-target-permission = 권한이 있는 사용자
+broadcast-delay-label = 보내기:
+broadcast-delay-now = 지금
+broadcast-delay-5m = 5분 후
+broadcast-delay-15m = 15분 후
+broadcast-delay-1h = 1시간 후
+broadcast-delay-1d = 1일 후
+
 # =============================================================================
 # Ban System
 # =============================================================================
@@ -943,12 +1479,32 @@ msg-untrusted-success = 신뢰 항목이 성공적으로 제거됨
 # Trust list messages
 msg-trust-list-header = 신뢰된 IP:
 msg-trust-list-empty = 신뢰된 IP 없음
+msg-ldap-group-list-header = 디렉터리 그룹:
+msg-ldap-group-list-empty = 매핑된 디렉터리 그룹 없음
+msg-ldap-group-admin = 관리자
+msg-ldap-group-no-permissions = 권한 없음
+msg-ldap-group-set = 디렉터리 그룹 { $group } 매핑됨
+msg-ldap-group-removed = 디렉터리 그룹 { $group } 제거됨
+msg-guest-upgraded = 계정 { $username }이(가) 생성되었습니다. 다음부터 이 계정으로 로그인하세요.
+msg-nickname-registered = 닉네임 { $nickname }이(가) { $username } 용으로 예약되었습니다.
+msg-nickname-released = 닉네임 { $nickname }이(가) 해제되었습니다.
+msg-history-days = 서버가 사용자 메시지를 { $days }일 동안 보관합니다.
+msg-history-off = 서버가 사용자 메시지를 보관하지 않습니다. /history <일수>로 켤 수 있습니다.
+msg-history-no-older = { $nickname }님과의 이전 메시지가 없습니다.
+err-history-days-too-long = 메시지 기록은 최대 { $max }일까지 보관할 수 있습니다
+err-history-more-tab = /history more는 사용자 메시지 탭에서 사용하세요
 msg-trust-permanent = 영구
 msg-trust-expired = 만료됨
 msg-trust-remaining = { $time } 남음
 msg-trust-remaining-days = { $days }일 { $hours }시간
 msg-trust-remaining-hours = { $hours }시간 { $minutes }분
 msg-trust-remaining-minutes = { $minutes }분
+msg-leaderboard-header = { $stat } 기준 가장 활발한 사용자:
+msg-leaderboard-empty = 아직 기록된 활동이 없습니다
+leaderboard-stat-logins = 로그인 수
+leaderboard-stat-messages = 메시지 수
+leaderboard-stat-uploads = 업로드 수
+leaderboard-stat-downloaded = 다운로드량
 
 # =============================================================================
 # Voice Chat
@@ -957,6 +1513,16 @@ msg-trust-remaining-minutes = { $minutes }분
 # Voice error messages
 err-voice-join = 음성 채팅 참여 실패: { $error }
 err-voice-leave = 음성 채팅 나가기 실패: { $error }
+err-voice-limit = 음성 제한 설정 실패: { $error }
+err-voice-clip = 사운드보드: { $error }
+err-voice-text = 음성 텍스트: { $error }
+err-voice-clip-name-empty = 클립 이름은 비워 둘 수 없습니다
+err-voice-clip-name-too-long = 클립 이름이 너무 깁니다 (최대 { $max }자)
+err-voice-clip-name-invalid = 클립 이름에는 문자, 숫자, - 및 _만 사용할 수 있습니다
+err-voice-clip-too-large = 클립이 너무 큽니다 (최대 256KB)
+err-voice-clip-unsupported-type = 지원되지 않는 클립 형식입니다 (Ogg Vorbis만 지원)
+msg-voice-queued = 음성이 가득 찼습니다 - 대기열 { $position }번째입니다
+msg-voice-queue-cancelled = 음성 대기열에서 제거되었습니다
 err-voice-no-token = 음성 채팅 참여에 성공했지만 토큰을 받지 못했습니다
 err-voice-already-active = 이미 다른 서버에서 음성 채팅 중입니다
 err-voice-no-permission = 음성 채팅에 참여할 권한이 없습니다
@@ -979,7 +1545,12 @@ msg-voice-joined-empty = { $target }의 음성 채팅에 참여했습니다
 msg-voice-left = { $target }의 음성 채팅에서 나갔습니다
 msg-voice-user-joined = { $nickname }님이 음성 채팅에 참여했습니다
 msg-voice-user-left = { $nickname }님이 음성 채팅에서 나갔습니다
+msg-voice-broadcast-started = { $nickname }님이 음성으로 방송 중입니다 - 참여해서 들어보세요
+msg-voice-broadcast-ended = 음성 방송이 종료되었습니다
+msg-voice-clip-played = { $nickname }님이 { $name }을(를) 재생했습니다
+msg-voice-text = [음성] { $nickname }: { $message }
 msg-voice-you-left = 음성 채팅을 나갔습니다
+msg-voice-tcp-relay = 음성 UDP 연결 실패 ({ $error }) - 대신 서버 연결을 통해 음성을 중계합니다
 
 # Voice UI
 voice-join-tooltip = 음성 채팅 참여
@@ -987,6 +1558,8 @@ voice-leave-tooltip = 음성 채팅 나가기
 voice-mute-all-tooltip = 전체 음소거
 voice-unmute-all-tooltip = 음소거 해제
 voice-bar-participants = ({ $count })
+voice-bar-queued = (대기: { $position })
+voice-bar-listeners = ({ $count }명 청취 중)
 voice-indicator-tooltip = 음성 채팅 중
 tooltip-mute = 음소거
 tooltip-unmute = 음소거 해제
@@ -997,6 +1570,12 @@ voice-quality-medium = 보통 (32 kbps)
 voice-quality-high = 높음 (64 kbps)
 voice-quality-very-high = 매우 높음 (96 kbps)
 
+# Voice connection quality
+voice-connection-good = 좋음
+voice-connection-fair = 보통
+voice-connection-poor = 나쁨
+voice-connection-tooltip = 연결: { $quality } (손실 { $loss }%, 지터 { $jitter } ms)
+
 # PTT modes
 ptt-mode-hold = 누르고 있는 동안 말하기
 ptt-mode-toggle = 전환
@@ -1011,6 +1590,8 @@ ptt-delay-500ms = 500ms
 audio-output-device = 출력 장치
 audio-input-device = 입력 장치
 audio-refresh-devices = 장치 새로 고침
+audio-clip-volume = 사운드보드 볼륨
+audio-voice-text-tts = 음성 텍스트 메시지 읽어 주기
 audio-voice-quality = 음성 품질
 audio-ptt-key = 푸시 투 토크 키
 audio-ptt-mode = PTT 모드
@@ -1037,6 +1618,44 @@ mic-boost-6db = +6 dB
 mic-boost-12db = +12 dB
 mic-boost-18db = +18 dB
 
+# =============================================================================
+# Global Hotkeys
+# =============================================================================
+
+# Settings
+settings-toggle-window-key = 창 표시/숨기기 키
+button-clear-hotkey = 지우기
+
+# Shortcut descriptions (shown by the desktop on Wayland)
+hotkey-push-to-talk = 푸시 투 토크
+hotkey-toggle-window = Nexus 표시 또는 숨기기
+
+# Errors
+err-window-hotkey-failed = 창 표시/숨기기 단축키 등록 실패: { $error }
+err-global-shortcuts-unavailable = 전역 단축키 포털을 사용할 수 없어 Nexus에 포커스가 있을 때만 단축키가 작동합니다: { $error }
+
+# =============================================================================
+# Auto Away
+# =============================================================================
+
+settings-auto-away = 유휴 상태일 때 자동으로 자리 비움
+settings-auto-away-minutes = 유휴 시간(분)
+
+# =============================================================================
+# Connection Health
+# =============================================================================
+
+# Server list indicator
+connection-health-good = 좋음
+connection-health-fair = 보통
+connection-health-poor = 나쁨
+connection-health-tooltip = 연결: { $quality }
+connection-health-latency = 지연 시간: { $ms } ms
+connection-health-jitter = 지터: { $ms } ms
+connection-health-last-drop = 마지막 손실된 핑: { $time }
+connection-health-no-drops = 손실된 핑 없음
+connection-health-measuring = 지연 시간 측정 중…
+
 # =============================================================================
 # System Tray (Windows/Linux only)
 # =============================================================================
@@ -1062,15 +1681,53 @@ tray-tooltip-voice = Nexus BBS - 음성 채팅 중: { $target }
 tray-tooltip-voice-muted = Nexus BBS - 음성 채팅 중 (음소거): { $target }
 tray-tooltip-unread = Nexus BBS - 읽지 않은 대화 { $count }개
 
+# =============================================================================
+# Notification Actions (macOS/Windows only)
+# =============================================================================
+
+# Actions
+notification-reply = 답장
+notification-reply-send = 보내기
+notification-reply-placeholder = 답장…
+notification-mark-read = 읽음으로 표시
+
 # =============================================================================
 # Toast Notifications
 # =============================================================================
 
 toast-link-copied = 링크를 클립보드에 복사했습니다
 toast-copied = 클립보드에 복사했습니다
+toast-checksum-copied = SHA-256이 클립보드에 복사됨
 toast-download-started = { $filename } 다운로드 중
 toast-download-queued = 다운로드 대기: { $filename }
+toast-downloads-started = { $count }개 파일 다운로드 중
+toast-downloads-queued = { $count }개 다운로드 대기
 toast-upload-started = { $filename } 업로드 중
 toast-upload-queued = 업로드 대기: { $filename }
 toast-uploads-started = { $count }개 파일 업로드 중
 toast-uploads-queued = { $count }개 업로드 대기
+toast-upload-nothing-new = 업로드할 항목이 없습니다 — 서버에 이미 이 파일들이 있습니다
+toast-upload-watch-started = { $folder } 감시 중 — 새 파일이 자동으로 업로드됩니다
+toast-upload-watch-stopped = { $folder } 감시를 중지했습니다
+toast-auto-download-started = { $folder }에서 { $pattern } 자동 다운로드 중
+toast-auto-download-stopped = { $folder }에서 자동 다운로드를 중지했습니다
+toast-screenshot-folder-set = 스크린샷이 이 폴더에 업로드됩니다
+toast-chat-exported = 채팅을 내보냈습니다
+
+# =============================================================================
+# First-run Tips
+# =============================================================================
+
+tip-welcome-title = Nexus에 오신 것을 환영합니다
+tip-welcome = 아래에 서버 주소를 입력하고 연결하세요. 북마크를 추가하면 로그인 정보와 함께 서버가 왼쪽 목록에 남아 다음에 클릭 한 번으로 연결할 수 있습니다.
+tip-fingerprint = 모든 서버에는 서명과 같은 인증서 지문이 있습니다. Nexus는 처음 연결할 때 이를 기억하고, 바뀌면 경고합니다. 누군가 서버를 사칭하고 있을 수 있기 때문입니다. 서버 관리자가 변경을 알려준 경우에만 새 지문을 수락하세요.
+tip-server-list = 북마크와 열린 연결은 왼쪽에 있습니다. 클릭하여 서버를 전환하거나 북마크를 끌어 순서를 바꿀 수 있습니다.
+tip-toolbar = 도구 모음에서 파일, 뉴스, 설정 등을 열 수 있습니다. 아이콘 위에 마우스를 올리면 기능을 볼 수 있습니다.
+tip-chat = 여기서 서버의 모든 사람과 채팅합니다. /를 입력하면 채널에 들어가는 /join 같은 사용 가능한 명령을 볼 수 있습니다.
+tip-user-list = 지금 온라인인 사용자는 오른쪽에 표시됩니다. 이름을 클릭하면 메시지를 보내거나 정보를 볼 수 있습니다.
+tip-counter = 팁 { $current }/{ $total }
+button-add-bookmark = 북마크 추가
+button-tip-next = 다음
+button-tip-done = 완료
+button-tip-got-it = 확인
+button-skip-tips = 팁 건너뛰기
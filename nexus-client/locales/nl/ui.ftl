@@ -11,6 +11,13 @@ button-connect = Verbinden
 button-save = Opslaan
 button-create = Aanmaken
 button-edit = Bewerken
+button-check-ports = Poorten testen
+button-statistics = Statistieken
+button-dashboard = Dashboard
+button-refresh = Vernieuwen
+button-doors = Doors
+button-doors-leave = Verlaten
+button-doors-back = Terug
 button-update = Bijwerken
 
 button-accept-new-certificate = Nieuw Certificaat Accepteren
@@ -19,9 +26,23 @@ button-choose-avatar = Avatar Kiezen
 button-clear-avatar = Wissen
 button-choose-image = Afbeelding Kiezen
 button-clear-image = Wissen
+button-add-file = Bestand toevoegen
 button-overwrite = Overschrijven
 button-change-password = Wachtwoord Wijzigen
 button-browse = Bladeren
+button-undo = Ongedaan maken
+button-add = Toevoegen
+button-remove = Verwijderen
+button-view = Bekijken
+button-hide = Verbergen
+button-export = Exporteren…
+button-trust-certificate = Certificaat vertrouwen
+button-skip = Overslaan
+button-skip-existing = Bestaande overslaan
+button-download-anyway = Toch downloaden
+button-unlock = Ontgrendelen
+button-view-as-user = Bekijken als Gebruiker
+button-post-comment = Plaatsen
 
 # =============================================================================
 # Titles
@@ -36,19 +57,29 @@ title-user-create = Gebruiker aanmaken
 title-user-edit = Gebruiker bewerken
 title-update-user = Gebruiker bijwerken
 title-user-management = Gebruikers Beheren
+title-permission-audit = Rechtencontrole
+title-impersonate = Bekijken als { $username }
 title-confirm-delete = Verwijdering Bevestigen
 title-connected = Verbonden
 title-settings = Instellingen
 title-bookmarks = Bladwijzers
 title-users = Gebruikers
+title-friends = Vrienden
+friend-online = online
+friend-offline = offline
 title-edit-server-info = Server Info bewerken
 title-fingerprint-mismatch = Certificaatvingerafdruk komt niet overeen!
+title-fingerprint-new-server = Nieuw servercertificaat
+title-create-password-vault = Opgeslagen wachtwoorden beschermen
+title-unlock-password-vault = Opgeslagen wachtwoorden ontgrendelen
+title-unlock-config = Instellingen ontgrendelen
 title-server-info = Server Info
 title-user-info = Gebruiker Info
 title-about = Over
 title-change-password = Wachtwoord Wijzigen
 title-news = Nieuws
 title-transfers = Overdrachten
+title-transfer-history = Overdrachtgeschiedenis
 title-news-create = Bericht Maken
 title-news-edit = Bericht Bewerken
 title-channel-members = Leden
@@ -59,10 +90,14 @@ title-channel-members = Leden
 
 placeholder-username = Gebruikersnaam
 placeholder-password = Wachtwoord
+placeholder-master-passphrase = Hoofdwachtzin
+placeholder-confirm-master-passphrase = Hoofdwachtzin bevestigen
 placeholder-port = Poort
 placeholder-server-address = Serveradres
 placeholder-server-name = Servernaam
+placeholder-folder-name = Mapnaam
 placeholder-username-optional = Gebruikersnaam (optioneel)
+placeholder-fallback-addresses-optional = Uitwijkadressen (optioneel, kommagescheiden)
 placeholder-password-optional = Wachtwoord (optioneel)
 placeholder-password-keep-current = Wachtwoord
 placeholder-current-password = Huidig Wachtwoord
@@ -71,10 +106,18 @@ placeholder-confirm-password = Bevestig Wachtwoord
 placeholder-message = Typ een bericht…
 placeholder-no-permission = Geen toestemming
 placeholder-broadcast-message = Broadcastbericht
+placeholder-broadcast-channel = Kies een kanaal
+placeholder-broadcast-permission = Kies een recht
 placeholder-server-description = Serverbeschrijving
 placeholder-news-body = Nieuwsinhoud
+placeholder-news-caption = Bijschrift (optioneel)
+placeholder-news-file-path = Pad in het bestandsgebied
 placeholder-nickname-optional = Bijnaam (optioneel)
+placeholder-status-optional = Status (optioneel)
+placeholder-download-folder-optional = Downloadmap (optioneel)
 placeholder-proxy-address = Proxy-adres
+placeholder-notification-rules-channel = #kanaal
+placeholder-notification-rules-keyword = Trefwoord
 placeholder-proxy-port = 9050
 placeholder-proxy-username = Gebruikersnaam (optioneel)
 placeholder-proxy-password = Wachtwoord (optioneel)
@@ -87,13 +130,20 @@ placeholder-auto-join-channels = #kanaal1 #kanaal2 (spatie-gescheiden)
 # =============================================================================
 
 label-auto-connect = Auto-Verbinden
+label-server-avatar = Avatar:
 label-add-bookmark = Bladwijzer
+label-lan-servers = Op dit netwerk
+label-use-sso = Single sign-on
 label-admin = Beheerder
 label-shared-account = Gedeeld Account
 label-enabled = Ingeschakeld
+label-erase-personal-data = Ook persoonlijke gegevens wissen
+label-broadcast-require-ack = Ontvangers om bevestiging vragen
 label-permissions = Machtigingen:
 label-expected-fingerprint = Verwachte vingerafdruk:
 label-received-fingerprint = Ontvangen vingerafdruk:
+label-certificate-fingerprint = Certificaatvingerafdruk:
+label-identity-key = Identiteitssleutel:
 label-theme = Thema
 label-chat-history-retention = Chatgeschiedenis:
 chat-history-forever = Altijd
@@ -101,6 +151,10 @@ chat-history-30-days = 30 dagen
 chat-history-14-days = 14 dagen
 chat-history-7-days = 7 dagen
 chat-history-disabled = Uitgeschakeld
+collision-policy-rename = Beide behouden (hernoemen)
+collision-policy-overwrite = Overschrijven
+collision-policy-skip = Overslaan
+collision-policy-ask = Vragen
 label-max-scrollback = Max. scrollback:
 label-chat-font-size = Lettergrootte:
 label-show-connection-events = Verbindings-/verbrekingsgebeurtenissen weergeven
@@ -108,6 +162,19 @@ label-show-channel-events = Deelname-/vertrekgebeurtenissen weergeven
 label-show-timestamps = Tijdstempels weergeven
 label-use-24-hour-time = 24-uursformaat gebruiken
 label-show-seconds = Seconden weergeven
+label-split-view = Bestanden, nieuws en verbindingsmonitor naast de chat weergeven
+label-spell-check = Spelling controleren in chat en nieuws
+label-spell-check-no-dictionary = Er is geen woordenboek voor je taal gevonden. Zet de .aff- en .dic-bestanden in de map dictionaries naast config.json.
+label-send-read-receipts = Leesbevestigingen voor privéberichten versturen
+label-user-list-grouping = Gebruikerslijst groeperen:
+label-user-list-sort = Gebruikerslijst sorteren:
+user-list-group-none = Geen
+user-list-group-admins = Beheerders eerst
+user-list-group-channel = Per kanaal
+user-list-group-status = Per status
+user-list-sort-alphabetical = Alfabetisch
+user-list-sort-idle = Inactieve tijd
+user-list-sort-connect = Verbindingstijd
 label-server-name = Naam:
 label-server-description = Beschrijving:
 label-server-version = Versie:
@@ -141,6 +208,27 @@ tab-files = Bestanden
 tab-channels = Kanalen
 label-connections-short = Verbindingen:
 label-transfers-short = Overdrachten:
+label-active-transfers-short = Actief:
+label-queue-order-short = Wachtrijvolgorde:
+label-trusted-weight-short = Vertrouwd gewicht:
+label-nat-mapping-short = Poorttoewijzing:
+label-nat-external-ip-short = Extern IP:
+label-nat-not-mapped = Niet toegewezen
+connectivity-check-running = Poorten worden getest…
+check-service-bbs = BBS
+check-service-transfer = Overdrachten
+check-service-websocket = WebSocket
+check-service-transfer-websocket = WebSocket-overdrachten
+check-service-voice = Spraak
+check-state-open = Open
+check-state-closed = Gesloten
+check-state-filtered = Gefilterd
+check-state-untested = Niet getest
+label-check-port = { $service } ({ $protocol } { $port }):
+label-queue-order-small-first = Kleine bestanden eerst
+label-queue-order-arrival = Volgorde van aankomst
+label-small-files-first = Kleine bestanden eerst in de wachtrij
+label-unlimited = Onbeperkt
 label-reindex-short = Herindexeren:
 label-persistent-short = Permanent:
 label-auto-join-short = Auto-join:
@@ -157,6 +245,19 @@ label-download-location = Downloadlocatie:
 label-queue-transfers = Overdrachten in Wachtrij Zetten
 label-download-limit = Downloadlimiet:
 label-upload-limit = Uploadlimiet:
+label-auto-open-folder = Map openen als een download voltooid is
+label-collision-policy = Als bestand bestaat:
+label-require-fingerprint-approval = Vragen voordat het certificaat van een nieuwe server wordt vertrouwd
+label-known-servers = Bekende servers
+known-servers-empty = Nog geen servercertificaten opgeslagen
+known-server-first-seen = Eerst gezien { $date }
+known-server-first-seen-unknown = Eerst gezien: onbekend
+label-encrypt-config = Instellingen, bladwijzers en chatgeschiedenis versleutelen met een hoofdwachtzin
+config-encryption-locked-note = De instellingen zijn vergrendeld. Start Nexus opnieuw en voer de hoofdwachtzin in om dit te wijzigen.
+label-upload-rule-extensions = Toegestane bestandstypen:
+label-upload-rule-max-file-size = Grootste bestand (MB):
+label-upload-rule-max-total-size = Grootste upload (MB):
+label-auto-download-pattern = Bestandsnamen:
 label-persistent-channels = Permanente Kanalen:
 label-auto-join-channels = Auto-Join Kanalen:
 label-channels = Kanalen
@@ -170,11 +271,14 @@ permission-user_info = Gebruikersinfo
 permission-chat_send = Chat Verzenden
 permission-chat_receive = Chat Ontvangen
 permission-chat_join = Chat Deelnemen
+permission-chat_language = Chattaal
 permission-chat_create = Chat Aanmaken
 permission-chat_list = Chat Lijst
 permission-chat_secret = Chat Geheim
 permission-chat_topic = Chat Onderwerp
 permission-chat_topic_edit = Chat Onderwerp Bewerken
+permission-chat_topic_lock = Chat Onderwerp Vergrendelen
+permission-chat_entry_edit = Chat Welkomstbericht Bewerken
 permission-user_broadcast = Gebruiker Broadcast
 permission-user_create = Gebruiker Aanmaken
 permission-user_delete = Gebruiker Verwijderen
@@ -185,6 +289,7 @@ permission-news_list = Nieuwslijst
 permission-news_create = Nieuws Maken
 permission-news_edit = Nieuws Bewerken
 permission-news_delete = Nieuws Verwijderen
+permission-file_comment = Bestanden beoordelen
 permission-file_copy = Bestand Kopiëren
 permission-file_create_dir = Directory Aanmaken
 permission-file_delete = Bestand Verwijderen
@@ -204,6 +309,11 @@ permission-trust_create = Vertrouwen Toevoegen
 permission-trust_delete = Vertrouwen Verwijderen
 permission-trust_list = Vertrouwenslijst
 permission-connection_monitor = Verbindingsmonitor
+permission-log_view = Log bekijken
+permission-door_play = Doors spelen
+permission-voice_broadcast = Spraakuitzending
+permission-voice_clip_upload = Spraakclip Uploaden
+permission-voice_limit = Spraak Limiet
 permission-voice_listen = Spraak Luisteren
 permission-voice_talk = Spraak Praten
 
@@ -228,19 +338,31 @@ tooltip-files-paste = Plakken
 tooltip-files-search = Bestanden zoeken
 tooltip-manage-users = Gebruikers Beheren
 tooltip-connection-monitor = Verbindingsmonitor
+tooltip-server-log = Serverlog
 tooltip-server-info = Server-Info
 tooltip-about = Over
 tooltip-transfers = Overdrachten
 tooltip-download-all = Alles Downloaden
 tooltip-upload = Uploaden
 file-picker-upload-title = Selecteer bestanden om te uploaden
+file-picker-download-to-title = Selecteer downloadmap
+folder-picker-upload-watch-title = Selecteer de map om automatisch te uploaden
 drop-to-upload = Neerzetten om te uploaden
 tooltip-clear-inactive = Inactieve wissen
+tooltip-transfer-history = Overdrachtgeschiedenis
+tooltip-transfer-history-clear = Geschiedenis wissen
+tooltip-transfer-history-remove = Uit geschiedenis verwijderen
 tooltip-transfer-pause = Pauzeren
 tooltip-transfer-resume = Hervatten
 tooltip-transfer-cancel = Annuleren
 tooltip-transfer-remove = Verwijderen
 tooltip-transfer-open-folder = Map Openen
+tooltip-transfer-open-file = Bestand Openen
+tooltip-transfer-reveal = Tonen in Bestandsbeheer
+tooltip-transfer-copy-checksum = SHA-256 Kopiëren
+tooltip-transfer-collision-rename = Beide behouden (hernoemen)
+tooltip-transfer-collision-overwrite = Bestaande bestanden overschrijven
+tooltip-transfer-collision-skip = Bestaande bestanden overslaan
 tooltip-transfer-move-up = Omhoog
 tooltip-transfer-move-down = Omlaag
 tooltip-transfer-retry = Opnieuw proberen
@@ -253,16 +375,34 @@ tooltip-disconnect = Verbinding verbreken
 tooltip-edit = Bewerken
 tooltip-info = Info
 tooltip-message = Bericht
+tooltip-add-friend = Vriend toevoegen
+tooltip-remove-friend = Vriend verwijderen
 tooltip-kick = Verwijderen
 tooltip-add-bookmark = Bladwijzer Toevoegen
+tooltip-new-folder = Nieuwe map
 tooltip-close = Sluiten
+tooltip-retry-send = Opnieuw verzenden
+tooltip-cancel-send = Verzenden annuleren
+tooltip-message-sent = Verzonden
+tooltip-message-delivered = Afgeleverd
+tooltip-message-read = Gelezen
 tooltip-new-tab = Nieuw Tabblad
 tooltip-close-tab = Tabblad Sluiten
 tooltip-create-user = Gebruiker Aanmaken
+tooltip-permission-audit = Rechten Controleren
 tooltip-create-news = Nieuwsbericht Aanmaken
+tooltip-news-search = Nieuws zoeken
+tooltip-news-search-clear = Zoekopdracht wissen
 tooltip-delete = Verwijderen
 context-menu-download = Downloaden
+context-menu-download-to = Downloaden naar…
+context-menu-download-select = Selectie downloaden…
+context-menu-auto-download = Automatisch downloaden…
 context-menu-upload = Uploaden
+context-menu-watch-folder = Lokale map bewaken…
+context-menu-screenshot-folder = Schermafbeeldingen hier delen
+context-menu-unwatch-folder = Map niet meer bewaken
+context-menu-upload-rules = Uploadregels…
 context-menu-open = Openen
 
 # =============================================================================
@@ -270,6 +410,7 @@ context-menu-open = Openen
 # =============================================================================
 
 panel-connection-monitor = Verbindingsmonitor
+panel-server-log = Serverlog
 panel-active-connections = Actieve Verbindingen: { $count }
 tab-connections = Verbindingen
 tab-transfers = Overdrachten
@@ -284,9 +425,97 @@ menu-info = Info
 menu-copy = Kopiëren
 menu-kick = Verwijderen
 menu-ban = Blokkeren
+menu-pop-out = Losmaken
+menu-mute-tab = Dempen
+menu-unmute-tab = Dempen opheffen
+menu-connect-all = Alles verbinden
+menu-rename = Hernoemen
+menu-delete = Verwijderen
 connection-monitor-loading = Verbindingen laden…
 connection-monitor-no-connections = Geen verbindingen
 connection-monitor-no-transfers = Geen actieve overdrachten
+connection-monitor-queued = In wachtrij
+connection-monitor-weight = Eerlijke-verdelingsgewicht: ×{ $weight }
+connection-monitor-listed = Vermeld in { $zone }
+connection-monitor-queued-count = { $count } in wachtrij
+placeholder-server-log-module = Filteren op module
+server-log-loading = Serverlog laden…
+server-log-empty = Geen logregels
+server-log-level-error = Alleen fouten
+server-log-level-warn = Waarschuwingen en hoger
+server-log-level-info = Info en hoger
+server-log-level-debug = Alles (debug)
+panel-statistics = Statistieken
+stats-loading = Statistieken laden…
+panel-snippet = Snippet
+snippet-loading = Snippet laden…
+snippet-details = Gedeeld door { $author } · verloopt op { $expires }
+stats-range-days = Laatste { $days } dagen
+stats-chart-max = Hoogste dag: { $value }
+stats-metric-logins = Aanmeldingen
+stats-metric-messages = Berichten
+stats-metric-uploads = Uploads
+stats-metric-upload-bytes = Geüploade data
+stats-metric-downloads = Downloads
+stats-metric-download-bytes = Gedownloade data
+stats-metric-peak-sessions = Piek gebruikers online
+panel-admin-dashboard = Beheerdashboard
+dashboard-loading = Dashboard laden…
+dashboard-connections = Verbindingen
+dashboard-sessions = Gebruikers online
+dashboard-connection-count = Verbindingen
+dashboard-transfer-connections = Overdrachtverbindingen
+dashboard-transfers = Overdrachten
+dashboard-active-transfers = Bezig
+dashboard-queued-transfers = In wachtrij
+dashboard-today = Vandaag
+dashboard-disk = Bestandsgebied
+dashboard-disk-free = Vrije ruimte
+dashboard-disk-free-of = { $free } vrij van { $total }
+dashboard-disk-unknown = Onbekend
+dashboard-recent-audit = Recente auditgebeurtenissen
+dashboard-audit-empty = Nog geen auditgebeurtenissen
+dashboard-updated = Bijgewerkt om { $time }
+panel-doors = Doors
+doors-loading = Doors laden…
+doors-empty = Geen doors op deze server
+doors-starting = Starten…
+doors-playing-hint = Toetsen gaan naar de door. Gebruik Verlaten om te stoppen.
+doors-exited = De door is beëindigd
+doors-exited-code = De door is beëindigd met code { $code }
+
+# =============================================================================
+# Quick Switcher
+# =============================================================================
+
+quick-switcher-placeholder = Ga naar een kanaal, bericht, bladwijzer of paneel...
+user-list-filter-placeholder = Gebruikers filteren...
+user-list-section-admins = Beheerders
+user-list-section-users = Gebruikers
+user-list-section-no-channel = Overig
+user-list-section-online = Online
+user-list-section-away = Afwezig
+user-list-section-dnd = Niet storen
+user-list-idle = Inactief { $duration }
+quick-switcher-no-matches = Geen resultaten
+quick-switcher-bookmark = Bladwijzer
+
+# =============================================================================
+# Chat Input
+# =============================================================================
+
+chat-paste-prompt = { $count } regels plakken?
+chat-paste-too-long-prompt = Te lang voor één bericht (max. { $max }). Toch versturen?
+button-send-as-messages = Verzenden als { $count } berichten
+button-send-as-one-message = Verzenden als één bericht
+button-send-in-parts = Versturen in { $count } delen
+button-share-as-snippet = Delen als snippet
+button-expand-code = Alle { $count } regels tonen
+button-collapse-code = Inklappen
+chat-broadcast-ack-prompt = { $username } vraagt u de broadcast te bevestigen
+button-acknowledge = Bevestigen
+button-dismiss = Sluiten
+spell-no-suggestions = Geen suggesties
 
 # =============================================================================
 # Empty States
@@ -297,11 +526,35 @@ empty-no-connections = Geen verbindingen
 empty-no-bookmarks = Geen bladwijzers
 empty-no-users = Geen gebruikers online
 empty-no-channel-members = Geen leden in kanaal
+empty-no-matching-users = Geen overeenkomende gebruikers
 user-management-loading = Gebruikers laden…
 user-management-no-users = Geen gebruikers gevonden
+permission-audit-hint = Kies een recht om te zien wie het heeft, of klik op een gebruiker om alles te zien wat die mag.
+permission-audit-loading = Laden…
+permission-audit-no-holders = Geen enkel account heeft dit recht
+permission-audit-effective = Effectieve rechten van { $username }
+permission-audit-admin = Beheerders hebben alle rechten
+permission-audit-no-permissions = Dit account heeft geen rechten
+permission-audit-restricted = Toegekend maar niet beschikbaar voor gedeelde accounts:
+permission-audit-disabled = Uitgeschakeld
+impersonate-notice = Alleen-lezen weergave van wat dit account ziet. Elke weergave wordt vastgelegd in het auditlogboek.
+impersonate-permissions = Rechten
+impersonate-files = Bestanden ({ $path })
+impersonate-no-file-access = Dit account kan geen bestanden bekijken
+impersonate-empty-folder = Deze map is leeg
+impersonate-channels = Kanalen
+impersonate-no-channel-access = Dit account kan geen kanalen weergeven
+impersonate-no-channels = Geen zichtbare kanalen
+impersonate-secret = Geheim
 news-loading = Nieuws laden…
 news-no-posts = Nog geen berichten
+news-search-placeholder = Nieuws zoeken… (author:naam since:JJJJ-MM-DD until:JJJJ-MM-DD)
+news-searching = Zoeken…
+news-search-no-results = Geen overeenkomende berichten
 transfers-empty = Geen overdrachten
+transfer-history-search-placeholder = Zoeken op naam, server of map…
+transfer-history-empty = Nog geen voltooide overdrachten
+transfer-history-no-matches = Geen overdrachten komen overeen met uw zoekopdracht
 news-updated = Bijgewerkt
 files-loading = Bestanden laden…
 files-empty = Deze map is leeg
@@ -313,10 +566,19 @@ files-column-name = Naam
 files-column-size = Grootte
 files-column-modified = Gewijzigd
 files-column-path = Pad
+files-column-rating = Beoordeling
 files-search-placeholder = Bestanden zoeken…
 files-search-breadcrumb = Zoeken - { $query }
 files-searching = Zoeken…
 files-no-results = Geen bestanden gevonden
+files-highlights = Uitgelicht
+files-highlights-recent = Nieuwe uploads deze week
+files-highlights-popular = Meest gedownload
+files-highlights-empty = Nog niets
+files-highlights-downloads = { $count } { $count ->
+    [one] download
+   *[other] downloads
+}
 files-search-query-too-short = Zoekopdracht is te kort (min. { $min_length } tekens)
 files-search-query-too-long = Zoekopdracht is te lang (max. { $max_length } tekens)
 files-search-query-invalid = Zoekopdracht bevat ongeldige tekens
@@ -327,6 +589,30 @@ files-share = Delen
 files-rename = Hernoemen
 files-rename-title = "{ $name }" hernoemen
 files-rename-placeholder = Nieuwe naam
+files-upload-rule-title = Uploadregels voor "{ $name }"
+files-upload-rule-loading = Laden…
+files-upload-rule-extensions-placeholder = Elk type (bijv. mp3, flac)
+files-upload-rule-no-limit = Geen limiet
+files-upload-rule-remove = Regel verwijderen
+files-upload-rule-help = Regels gelden voor deze map en de submappen, tenzij een submap eigen regels heeft.
+files-auto-download-title = Automatisch downloaden uit "{ $name }"
+files-auto-download-pattern-placeholder = bijv. *.mp3, *.m4a
+files-auto-download-remove = Automatisch downloaden stoppen
+files-auto-download-help = Nieuwe bestanden in deze map waarvan de naam overeenkomt, worden gedownload zolang u via deze bladwijzer verbonden bent. Gebruik * voor willekeurige tekst en ? voor één teken, en scheid patronen met komma's. Bestanden die u al hebt gedownload worden overgeslagen.
+files-upload-rule-hint = Uploads hier: { $limits }
+files-upload-rule-hint-types = alleen { $extensions }
+files-upload-rule-hint-file-size = tot { $size } per bestand
+files-upload-rule-hint-total-size = tot { $size } per upload
+files-upload-preview-title = Bestanden bestaan al
+files-upload-preview-comparing = Vergelijken met de bestanden op de server…
+files-upload-preview-summary = { $new } nieuw, { $identical } al op de server, { $conflicts } verschillend:
+files-upload-preview-blocked = { $count } { $count ->
+    [one] bestand heeft
+   *[other] bestanden hebben
+} een map met dezelfde naam op de server en { $count ->
+    [one] wordt
+   *[other] worden
+} overgeslagen
 files-cut = Knippen
 files-copy = Kopiëren
 files-paste = Plakken
@@ -336,6 +622,13 @@ files-delete-confirm-title = Verwijderen bevestigen
 files-delete-confirm-message = Weet u zeker dat u "{ $name }" wilt verwijderen?
 # File Overwrite Dialog
 files-overwrite-title = Bestand bestaat al
+files-download-to-title = { $name } downloaden
+label-remember-for-server = Onthouden voor deze server
+label-select-all = Alles selecteren
+files-download-select-summary = { $count } van { $total } geselecteerd ({ $size })
+files-download-select-too-many = Er kunnen maximaal { $max } afzonderlijke bestanden worden geselecteerd. Download in plaats daarvan de hele map.
+files-download-duplicate-title = Al gedownload
+files-download-duplicate-message = "{ $name }" staat al op deze computer in { $path }. Wilt u het opnieuw downloaden?
 files-overwrite-message = Een bestand of map met de naam "{ $name }" bestaat al op de bestemming. Wilt u het vervangen?
 
 # File Info Dialog
@@ -351,6 +644,17 @@ files-info-directory = Map
 files-info-symlink = Symlink:
 files-info-yes = Ja
 files-info-na = N.v.t.
+files-info-rating = Beoordeling:
+files-info-rating-value = { $average } ({ $count } { $count ->
+    [one] beoordeling
+   *[other] beoordelingen
+})
+files-info-not-rated = Nog niet beoordeeld
+files-info-your-rating = Jouw beoordeling:
+files-info-comments = Reacties ({ $count })
+files-comments-loading = Reacties laden…
+files-comments-empty = Nog geen reacties
+files-comment-placeholder = Schrijf een reactie…
 
 # =============================================================================
 # Directory Name Validation Errors
@@ -369,6 +673,7 @@ err-dir-name-invalid = Mapnaam bevat ongeldige tekens
 tab-general = Algemeen
 tab-chat = Chat
 tab-network = Netwerk
+tab-security = Beveiliging
 tab-audio = Audio
 
 # =============================================================================
@@ -390,6 +695,8 @@ chat-prefix-system = [SYS]
 chat-prefix-error = [FOUT]
 chat-prefix-info = [INFO]
 chat-prefix-broadcast = [BROADCAST]
+chat-prefix-queued = [PENDING]
+chat-export-subtitle = Geëxporteerd van { $server } op { $date }
 
 # =============================================================================
 # Success Messages
@@ -413,11 +720,28 @@ msg-channel-member-count = { $count } { $count ->
    *[other] leden
 }
 channel-secret = geheim
+channel-info-topic = Onderwerp
+channel-info-rules = Regels
+channel-info-language = Taal
 msg-broadcast-sent = Broadcast succesvol verzonden
+msg-broadcast-delivered = Broadcast afgeleverd bij { $count } { $count ->
+    [one] gebruiker
+   *[other] gebruikers
+}
+msg-broadcast-scheduled = Broadcast gepland voor { $time }
+msg-broadcast-acknowledged = Broadcast bevestigd door { $count } van { $delivered } gebruikers
+msg-storage-low = Weinig schijfruimte op de server voor { $volume }: { $free } vrij, onder het minimum van { $threshold }
+msg-storage-recovered = Schijfruimte op de server voor { $volume } hersteld: { $free } vrij
+storage-volume-files = het bestandsgebied
+storage-volume-database = de database
+msg-friend-added = { $username } toegevoegd aan vrienden
+msg-friend-removed = { $username } verwijderd uit vrienden
 msg-user-created = Gebruiker succesvol aangemaakt
 msg-user-created-name = Gebruiker '{ $username }' succesvol aangemaakt
 msg-user-deleted = Gebruiker succesvol verwijderd
 msg-user-deleted-name = Gebruiker '{ $username }' succesvol verwijderd
+msg-user-erased = Gebruiker verwijderd en persoonlijke gegevens gewist
+msg-user-erased-name = Gebruiker '{ $username }' verwijderd en persoonlijke gegevens gewist
 msg-user-updated = Gebruiker succesvol bijgewerkt
 msg-user-updated-name = Gebruiker '{ $username }' succesvol bijgewerkt
 msg-permissions-updated = Je machtigingen zijn bijgewerkt
@@ -432,6 +756,8 @@ msg-welcome-back = Welkom terug
 msg-status-set = Status ingesteld: { $status }
 msg-status-cleared = Status gewist
 msg-user-is-away = { $nickname } is afwezig
+msg-group-member-joined = { $nickname } neemt deel aan het gesprek
+msg-group-member-left = { $nickname } heeft het gesprek verlaten
 msg-user-is-away-status = { $nickname } is afwezig: { $status }
 
 # =============================================================================
@@ -442,6 +768,14 @@ msg-topic-cleared = Onderwerp gewist door { $username }
 msg-topic-set = Onderwerp ingesteld door { $username }: { $topic }
 msg-secret-set = Geheime modus ingeschakeld door { $username }
 msg-secret-cleared = Geheime modus uitgeschakeld door { $username }
+msg-topic-locked = Onderwerp vergrendeld door { $username }
+msg-topic-unlocked = Onderwerp ontgrendeld door { $username }
+msg-rules-set = Kanaalregels bijgewerkt door { $username }
+msg-rules-cleared = Kanaalregels gewist door { $username }
+msg-language-set = Kanaaltaal ingesteld op { $language } door { $username }
+msg-language-cleared = Kanaaltaal gewist door { $username }
+msg-voice-limit-set = Spraaklimiet ingesteld op { $limit } door { $username }
+msg-voice-limit-cleared = Spraaklimiet verwijderd door { $username }
 msg-server-info-updated = Serverconfiguratie bijgewerkt
 msg-topic-display = Onderwerp: { $topic }
 confirm-delete-user = Weet je zeker dat je gebruiker '{ $username }' wilt verwijderen?
@@ -461,6 +795,7 @@ err-user-kick-failed = Kan gebruiker niet verwijderen
 err-unknown = Onbekende fout
 err-no-shutdown-handle = Verbindingsfout: Geen afsluithandle
 err-userlist-failed = Kan gebruikerslijst niet vernieuwen
+err-request-timeout = De server reageerde niet op tijd
 err-port-invalid = Poort moet een geldig nummer zijn (1-65535)
 
 # Network connection errors
@@ -474,12 +809,16 @@ err-connection-closed = Verbinding gesloten
 err-could-not-determine-config-dir = Kan configuratiemap niet bepalen
 err-message-too-long = Bericht is te lang ({ $length } tekens, max { $max })
 err-send-failed = Kan bericht niet verzenden
+err-checksum-failed = Controlesom berekenen mislukt
 err-no-chat-permission = Je hebt geen toestemming om berichten te verzenden
+err-chat-window-command = Vanuit een chatvenster kunnen alleen berichten en /me worden verzonden
 err-console-no-send = Gebruik /{ $join } om een kanaal te betreden of /{ $msg } om een bericht naar een gebruiker te sturen
 err-broadcast-too-long = Broadcast is te lang ({ $length } tekens, max { $max })
 err-broadcast-send-failed = Kan broadcast niet verzenden
+err-broadcast-target-required = Kies wie de broadcast ontvangt
 err-name-required = Bladwijzernaam is vereist
 err-address-required = Serveradres is vereist
+err-fallback-address-invalid = Ongeldig uitwijkadres: { $address }
 err-port-required = Poort is vereist
 err-username-required = Gebruikersnaam is vereist
 err-password-required = Wachtwoord is vereist
@@ -497,13 +836,20 @@ err-nickname-too-long = Bijnaam is te lang (max { $max } tekens)
 err-nickname-invalid = Bijnaam bevat ongeldige tekens
 err-password-too-long = Wachtwoord is te lang (max { $max } tekens)
 err-passwords-do-not-match = Wachtwoorden komen niet overeen
+err-wrong-master-passphrase = Onjuiste hoofdwachtzin
+err-master-passphrase-empty = Hoofdwachtzin mag niet leeg zijn
+err-password-vault = Fout in wachtwoordkluis: { $error }
 err-current-password-required = Huidig wachtwoord is vereist
 err-new-password-required = Nieuw wachtwoord is vereist
 err-confirm-password-required = Bevestig uw nieuwe wachtwoord
 err-topic-too-long = Onderwerp is te lang ({ $length } tekens, max { $max })
+err-entry-message-too-long = Welkomstbericht is te lang ({ $length } tekens, max { $max })
+err-rules-too-long = Regels zijn te lang ({ $length } tekens, max { $max })
 err-avatar-unsupported-type = Niet-ondersteund bestandstype. Gebruik PNG, WebP, JPEG of SVG.
 err-avatar-too-large = Avatar te groot. Maximale grootte is { $max_kb }KB.
 err-avatar-decode-failed = Kan avatar niet decoderen. Het bestand is mogelijk beschadigd.
+err-known-server-export = Kan certificaat niet exporteren: { $error }
+err-config-encryption = Kan instellingen niet versleutelen: { $error }
 err-server-name-empty = Servernaam mag niet leeg zijn
 err-server-name-too-long = Servernaam is te lang (max { $max } tekens)
 err-server-name-contains-newlines = Servernaam mag geen regelovergangen bevatten
@@ -524,14 +870,23 @@ err-failed-send-update = Kan update niet verzenden: { $error }
 # =============================================================================
 
 err-failed-save-config = Kan configuratie niet opslaan: { $error }
+err-friends-no-bookmark = Vrienden worden opgeslagen in de bladwijzer van de server; maak eerst een bladwijzer aan
+err-friends-too-many = Te veel vrienden (max { $max })
+err-friend-exists = { $username } is al een vriend
+err-friend-not-found = { $username } is geen vriend
 err-failed-save-settings = Kan instellingen niet opslaan: { $error }
 err-invalid-port-bookmark = Ongeldige poort in bladwijzer: { $name }
 err-failed-send-broadcast = Kan broadcast niet verzenden: { $error }
 err-failed-send-message = Kan bericht niet verzenden: { $error }
+err-failed-start-group = Groepsgesprek starten mislukt: { $error }
+err-failed-share-snippet = Snippet delen mislukt: { $error }
 err-failed-create-user = Kan gebruiker niet aanmaken: { $error }
 err-failed-delete-user = Kan gebruiker niet verwijderen: { $error }
 err-failed-update-user = Kan gebruiker niet bijwerken: { $error }
 err-failed-update-topic = Kan onderwerp niet bijwerken: { $error }
+err-failed-lock-topic = Kan onderwerp niet vergrendelen: { $error }
+err-failed-set-language = Kan kanaaltaal niet instellen: { $error }
+err-failed-update-entry = Kan kanaal niet bijwerken: { $error }
 err-message-too-long-details = { $error } ({ $length } tekens, max { $max })
 
 # Network connection errors (with parameters)
@@ -546,6 +901,14 @@ err-tls-handshake-failed = TLS-handshake mislukt: { $error }
 err-failed-send-handshake = Kan handshake niet verzenden: { $error }
 err-failed-read-handshake = Kan handshake-respons niet lezen: { $error }
 err-handshake-failed = Handshake mislukt: { $error }
+err-proof-of-work-too-hard = De server vroeg te veel proof-of-work (moeilijkheid { $difficulty })
+err-sso-not-offered = Deze server biedt geen single sign-on
+err-sso-failed = Single sign-on mislukt: { $error }
+err-sso-browser = Kan de browser niet openen: { $error }
+err-sso-timeout = Single sign-on verlopen tijdens het wachten op de browser
+err-sso-state-mismatch = Het single sign-on-antwoord komt niet overeen met het verzoek
+sso-browser-done = Aangemeld. Je kunt dit venster sluiten en teruggaan naar Nexus.
+sso-browser-failed = Aanmelden mislukt. Je kunt dit venster sluiten en teruggaan naar Nexus.
 err-failed-parse-handshake = Kan handshake-respons niet verwerken: { $error }
 err-failed-send-login = Kan aanmelding niet verzenden: { $error }
 err-failed-read-login = Kan aanmeldrespons niet lezen: { $error }
@@ -553,6 +916,7 @@ err-failed-parse-login = Kan aanmeldrespons niet verwerken: { $error }
 err-failed-create-server-name = Kan servernaam niet maken: { $error }
 err-failed-create-config-dir = Kan configuratiemap niet maken: { $error }
 err-failed-serialize-config = Kan configuratie niet serialiseren: { $error }
+err-config-locked = De instellingen zijn versleuteld en vergrendeld; wijzigingen kunnen pas worden opgeslagen na ontgrendelen
 err-failed-write-config = Kan configuratiebestand niet schrijven: { $error }
 err-failed-read-config-metadata = Kan metadata van configuratiebestand niet lezen: { $error }
 err-failed-set-config-permissions = Kan machtigingen van configuratiebestand niet instellen: { $error }
@@ -562,6 +926,10 @@ err-failed-set-config-permissions = Kan machtigingen van configuratiebestand nie
 # =============================================================================
 
 fingerprint-warning = Dit kan wijzen op een beveiligingsprobleem (MITM-aanval) of het servercertificaat is opnieuw gegenereerd. Accepteer alleen als je de serverbeheerder vertrouwt.
+fingerprint-new-server-prompt = Dit is de eerste verbinding met deze server. Vertrouw het certificaat alleen als de vingerafdruk hieronder overeenkomt met die van de serverbeheerder.
+password-vault-create-description = Er is geen systeemsleutelhanger beschikbaar. Kies een hoofdwachtzin om de opgeslagen wachtwoorden van je bladwijzers te versleutelen, of sla over om ze in het configuratiebestand te houden.
+password-vault-unlock-description = Voer je hoofdwachtzin in om de opgeslagen wachtwoorden van je bladwijzers te ontgrendelen. Als je overslaat, verbinden bladwijzers zonder wachtwoord tot Nexus opnieuw wordt gestart.
+config-unlock-description = Je instellingen, bladwijzers en chatgeschiedenis zijn versleuteld. Voer je hoofdwachtzin in om ze te ontgrendelen. Als je overslaat, start Nexus met standaardinstellingen en wordt er niets opgeslagen tot een herstart.
 
 # =============================================================================
 # User Info Display
@@ -578,10 +946,12 @@ user-info-account = Account: { $username }
 user-info-connected = Verbonden:
 user-info-connected-value = { $duration } geleden
 user-info-connected-value-sessions = { $duration } geleden ({ $count } sessies)
+user-info-idle = Inactief:
 user-info-features = Functies:
 user-info-features-value = { $features }
 user-info-features-none = Geen
 user-info-locale = Taal:
+user-info-client = Client:
 user-info-address = Adres:
 user-info-addresses = Adressen:
 user-info-created = Aangemaakt:
@@ -590,6 +960,12 @@ user-info-unknown = Onbekend
 user-info-loading = Gebruikersinformatie laden…
 user-info-status = Status:
 user-info-channels = Kanalen:
+user-info-rank = Rang:
+user-info-logins = Aanmeldingen:
+user-info-messages = Berichten:
+user-info-uploads = Uploads:
+user-info-downloaded = Gedownload:
+user-info-last-login = Laatste aanmelding:
 
 # =============================================================================
 # Time Duration
@@ -625,24 +1001,46 @@ cmd-me-usage = Gebruik: /{ $command } <actie>
 err-me-no-target = Gebruik /{ $join } om een kanaal te betreden of /{ $msg } om een actie naar een gebruiker te sturen
 cmd-message-desc = Stuur een bericht naar een gebruiker
 cmd-message-usage = Gebruik: /{ $command } <gebruikersnaam> <bericht>
+cmd-group-desc = Start een groepsgesprek met meerdere gebruikers
+cmd-group-usage = Gebruik: /{ $command } <gebruikersnaam> [<gebruikersnaam>...]
+cmd-invite-desc = Voeg een gebruiker toe aan het huidige groepsgesprek
+cmd-invite-usage = Gebruik: /{ $command } <gebruikersnaam>
 cmd-ping-desc = Meet latentie naar server
 cmd-ping-usage = Gebruik: /{ $command }
+cmd-radio-desc = Audio uitzenden naar de spraakluisteraars van het kanaal
+cmd-radio-usage = Gebruik: /{ $command }
+err-radio-no-channel = Deze opdracht werkt alleen in een kanaal
+err-radio-in-voice = Verlaat spraak voordat je een uitzending start
 ping-result = Antwoord: { $ms }ms
 cmd-userinfo-desc = Toon informatie over een gebruiker
 cmd-userinfo-usage = Gebruik: /{ $command } <gebruikersnaam>
 cmd-kick-desc = Verwijder een gebruiker van de server
 cmd-kick-usage = Gebruik: /{ $command } <gebruikersnaam> [reden]
+cmd-top-desc = Meest actieve gebruikers tonen
+cmd-top-usage = Gebruik: /{ $command } [logins|messages|uploads|downloaded]
 cmd-topic-desc = Bekijk of beheer het chatonderwerp
-cmd-topic-usage = Gebruik: /{ $command } [set|clear] [onderwerp]
+cmd-topic-usage = Gebruik: /{ $command } [set|clear|lock|unlock|history] [onderwerp]
 cmd-topic-set-usage = Gebruik: /{ $command } set <onderwerp>
 cmd-topic-none = Er is geen onderwerp ingesteld
 cmd-broadcast-desc = Stuur een broadcast naar alle gebruikers
 cmd-broadcast-usage = Gebruik: /{ $command } <bericht>
 cmd-clear-desc = Chatgeschiedenis van huidige tab wissen
 cmd-clear-usage = Gebruik: /{ $command }
+cmd-export-desc = Sla het huidige tabblad op als HTML-bestand
+cmd-export-usage = Gebruik: /{ $command }
+cmd-clip-desc = Soundboard-clips weergeven, afspelen, toevoegen of verwijderen
+cmd-clip-usage = Gebruik: /{ $command } [lijst|afspelen <naam>|toevoegen <naam>|verwijderen <naam>|<naam>]
+cmd-clip-arg-list = lijst
+cmd-clip-arg-play = afspelen
+cmd-clip-arg-add = toevoegen
+cmd-clip-arg-delete = verwijderen
 cmd-focus-desc = Focus op serverchat of berichtenvenster van een gebruiker
 cmd-focus-usage = Gebruik: /{ $command } [gebruikersnaam]
 cmd-focus-not-found = Gebruiker niet gevonden: { $name }
+cmd-friend-desc = Vrienden weergeven, toevoegen of verwijderen
+cmd-friend-usage = Gebruik: /{ $command } [list] | add <gebruikersnaam> | remove <gebruikersnaam>
+cmd-friend-list-header = Vrienden:
+cmd-friend-list-empty = Nog geen vrienden. Voeg er een toe met /{ $command } add <gebruikersnaam>
 cmd-list-desc = Verbonden/alle gebruikers weergeven
 cmd-list-arg-all = alle
 cmd-list-usage = Gebruik: /{ $command } [alle]
@@ -659,7 +1057,17 @@ cmd-list-all-output = Gebruikers: { $users } ({ $count } { $count ->
 cmd-help-usage = Gebruik: /{ $command } [commando]
 cmd-topic-arg-set = instellen
 cmd-topic-arg-clear = wissen
+cmd-topic-arg-lock = vergrendelen
+cmd-topic-arg-unlock = ontgrendelen
+cmd-topic-arg-history = geschiedenis
 cmd-topic-permission-denied = Je hebt geen toestemming om het onderwerp te bewerken
+cmd-topic-lock-permission-denied = Je hebt geen toestemming om het onderwerp te vergrendelen
+msg-topic-already-locked = Onderwerp is al vergrendeld
+msg-topic-already-unlocked = Onderwerp is al ontgrendeld
+msg-topic-history-header = Onderwerpgeschiedenis van { $channel }:
+msg-topic-history-set = { $time } { $username }: { $topic }
+msg-topic-history-cleared = { $time } { $username } heeft het onderwerp gewist
+msg-topic-history-empty = Geen onderwerpwijzigingen vastgelegd
 cmd-window-desc = Beheer chat-tabbladen
 cmd-window-usage = Gebruik: /{ $command } [volgende|vorige|sluiten [gebruikersnaam]]
 cmd-window-arg-next = volgende
@@ -687,15 +1095,55 @@ cmd-unban-desc = Verwijder een IP-ban
 cmd-unban-usage = Gebruik: /{ $command } <doel>
 cmd-bans-desc = Toon actieve bans
 cmd-bans-usage = Gebruik: /{ $command }
+cmd-register-desc = Een account registreren vanuit een gastsessie
+cmd-register-usage = Gebruik: /{ $command } <gebruikersnaam> <wachtwoord>
 cmd-reindex-desc = Start herindexering van bestanden
 cmd-reindex-usage = Gebruik: /{ $command }
+cmd-cleanup-desc = Resten van afgebroken overdrachten in het bestandsgebied zoeken
+cmd-cleanup-usage = Gebruik: /{ $command } [delete]
+cmd-filecheck-desc = Het bestandsgebied controleren tegen de zoekindex en bestandsmetadata
+cmd-filecheck-usage = Gebruik: /{ $command } [repair]
+cmd-screenshot-desc = Een geannoteerde schermafbeelding delen in het huidige tabblad
+cmd-screenshot-usage = Gebruik: /{ $command }
+err-screenshot-no-target = Schermafbeeldingen kunnen alleen in een kanaal of gebruikersberichttabblad worden gedeeld
+err-screenshot-no-folder = Geen map voor schermafbeeldingen ingesteld — kies "Schermafbeeldingen hier delen" op een uploadmap in Bestanden
+err-screenshot-capture = Schermafbeelding mislukt: { $error }
+err-screenshot-save = Kan schermafbeelding niet opslaan: { $error }
+err-screenshot-upload = Uploaden van schermafbeelding mislukt: { $error }
+err-export-empty = Er zijn geen berichten om te exporteren in dit tabblad
+err-chat-export = Chat exporteren mislukt: { $error }
+screenshot-title = Schermafbeelding delen
+screenshot-tool-crop = Bijsnijden
+screenshot-tool-arrow = Pijl
+screenshot-hint-crop = Sleep een kader rond het deel dat je wilt behouden
+screenshot-hint-arrow = Sleep van de staart naar de punt van de pijl
 msg-reindex-triggered = Herindexering van bestanden gestart
+msg-cleanup-nothing = Geen resten van afgebroken overdrachten gevonden
+msg-cleanup-found = Resten van afgebroken overdrachten gevonden:
+msg-cleanup-deleted = Resten van afgebroken overdrachten verwijderd:
+msg-cleanup-parts = Verweesde .part-bestanden: { $count } ({ $size })
+msg-cleanup-temp = Achtergebleven tijdelijke bestanden: { $count } ({ $size })
+msg-cleanup-journal = Uploadjournaalitems zonder bestand: { $count }
+msg-cleanup-index = Zoekindexitems zonder bestand: { $count }
+msg-cleanup-hint = Controleer de bestanden hierboven en voer dan /cleanup delete uit om ze te verwijderen
+msg-filecheck-issue = { $kind }: { $path }
+msg-filecheck-issue-repaired = { $kind }: { $path } (hersteld)
+msg-filecheck-clean = De controle van het bestandsgebied vond geen problemen in { $entries } items
+msg-filecheck-done = De controle van het bestandsgebied vond { $issues } problemen in { $entries } items, { $repaired } hersteld
+msg-filecheck-index-skipped = De zoekindex is niet vergeleken omdat deze nog niet is opgebouwd of een herbouw in afwachting is
+msg-filecheck-hint = Voer /filecheck repair uit om de index opnieuw op te bouwen en metadata van ontbrekende paden te verwijderen
+filecheck-not-indexed = Niet in de zoekindex
+filecheck-indexed-but-missing = In de zoekindex maar ontbreekt op schijf
+filecheck-index-outdated = Verouderd in de zoekindex
+filecheck-orphaned-activity = Geschiedenis van een ontbrekend pad
+filecheck-orphaned-reviews = Beoordelingen of opmerkingen van een ontbrekend pad
+filecheck-orphaned-upload-rule = Uploadregel van een ontbrekende map
 cmd-join-desc = Toetreden tot of aanmaken van een kanaal
 cmd-join-usage = Gebruik: /{ $command } #kanaal
 cmd-leave-desc = Verlaat een kanaal
 cmd-leave-usage = Gebruik: /{ $command } [#kanaal]
 cmd-channels-desc = Toon beschikbare kanalen
-cmd-channels-usage = Gebruik: /{ $command }
+cmd-channels-usage = Gebruik: /{ $command } [taal]
 cmd-secret-desc = Schakel geheime modus voor het huidige kanaal
 cmd-secret-usage = Gebruik: /{ $command } [aan|uit]
 cmd-secret-arg-on = aan
@@ -708,12 +1156,40 @@ msg-secret-enabled = Kanaal is nu geheim
 msg-secret-disabled = Kanaal is nu openbaar
 msg-secret-status-on = Dit kanaal is geheim
 msg-secret-status-off = Dit kanaal is openbaar
+cmd-voicelimit-desc = Bekijk of stel de spraakdeelnemerslimiet van het huidige kanaal in
+cmd-voicelimit-usage = Gebruik: /{ $command } [<aantal>|uit]
+cmd-vt-desc = Een tekstbericht naar je spraaksessie sturen
+cmd-vt-usage = Gebruik: /{ $command } <bericht>
+cmd-voicelimit-arg-off = uit
+err-voicelimit-no-channel = Dit commando werkt alleen in een kanaal
+msg-voice-limit-status = Dit kanaal staat maximaal { $limit } gebruikers in spraak toe
+msg-voice-limit-status-none = Dit kanaal heeft geen spraaklimiet
+msg-voice-clip-none = Geen soundboard-clips
+msg-voice-clip-list = Soundboard-clips ({ $count }):
+msg-voice-clip-entry = { $name } ({ $seconds } s, door { $nickname })
+msg-voice-clip-uploaded = Clip toegevoegd aan het soundboard
+msg-voice-clip-deleted = Clip verwijderd van het soundboard
 cmd-trust-desc = Vertrouw een IP, CIDR-bereik of bijnaam om bans te omzeilen
 cmd-trust-usage = Gebruik: /{ $command } <doel> [duur] [reden]
 cmd-untrust-desc = Verwijder een vertrouwde IP-invoer
 cmd-untrust-usage = Gebruik: /{ $command } <doel>
 cmd-trusted-desc = Toon vertrouwde IPs
 cmd-trusted-usage = Gebruik: /{ $command }
+cmd-ldap-desc = Koppelingen van directorygroepen weergeven, instellen of verwijderen
+cmd-ldap-usage = Gebruik: /{ $command } [list|set <rechten|admin|none> <groeps-DN>|remove <groeps-DN>]
+cmd-nickname-desc = Bijnamen voor je account reserveren of vrijgeven
+cmd-nickname-usage = Gebruik: /{ $command } [register <bijnaam> [gebruikersnaam]|release <bijnaam>]
+cmd-history-desc = Toon of stel in hoe lang de server je privéberichten bewaart, of laad oudere berichten
+cmd-history-usage = Gebruik: /{ $command } [<dagen>|more]
+cmd-alias-desc = Snelkoppelingen maken voor commando's en berichten
+cmd-alias-usage = Gebruik: /{ $command } [list|set <naam> <tekst>|remove <naam>]
+cmd-alias-list-header = Je aliassen:
+cmd-alias-list-empty = Nog geen aliassen. Maak er een met /{ $command } set <naam> <tekst>
+cmd-alias-set = /{ $name } voert nu uit: { $text }
+cmd-alias-removed = Alias /{ $name } verwijderd
+cmd-alias-not-found = Geen alias met de naam /{ $name }
+cmd-alias-invalid-name = Aliasnamen mogen alleen letters, cijfers, - en _ bevatten (maximaal { $max } tekens)
+cmd-alias-builtin = /{ $name } is een ingebouwd commando en kan niet als alias worden gebruikt
 
 # =============================================================================
 # About Panel
@@ -726,9 +1202,25 @@ err-server-image-invalid-format = Ongeldig serverafbeeldingsformaat (moet een da
 err-server-image-unsupported-type = Niet-ondersteund serverafbeeldingstype (alleen PNG, WebP, JPEG of SVG)
 err-server-image-decode-failed = Kan afbeelding niet decoderen. Het bestand is mogelijk beschadigd.
 err-failed-read-image = Kan afbeelding niet lezen: { $error }
-err-news-empty = Bericht moet tekst of een afbeelding bevatten
+err-news-empty = Bericht moet tekst of een bijlage bevatten
 err-news-body-too-long = Inhoud is te lang ({ $length } tekens, maximaal { $max })
 err-news-body-invalid-characters = Inhoud bevat ongeldige controletekens
+err-news-too-many-attachments = Een bericht kan maximaal { $max } bijlagen hebben
+err-news-caption-too-long = Bijschrift is te lang (max { $max } tekens)
+err-news-caption-invalid-characters = Bijschrift moet één regel zijn zonder controletekens
+err-news-file-path-invalid = Ongeldig bestandspad
+err-news-search-date = Ongeldige datum: { $date } (gebruik JJJJ-MM-DD)
+err-file-comment-empty = Reactie mag niet leeg zijn
+err-file-comment-too-long = Reactie is te lang ({ $length } tekens, max { $max })
+err-file-comment-invalid-characters = Reactie bevat ongeldige besturingstekens
+err-upload-rule-invalid-extension = Ongeldig bestandstype "{ $extension }" (gebruik letters en cijfers, zoals mp3)
+err-auto-download-pattern-required = Voer ten minste één bestandsnaampatroon in
+err-auto-download-pattern-invalid = Ongeldig patroon "{ $pattern }" (patronen gelden voor bestandsnamen en mogen geen schuine strepen bevatten)
+err-upload-rule-too-many-extensions = Te veel bestandstypen (max. { $max })
+err-upload-rule-invalid-size = Ongeldige grootte "{ $value }" (voer een aantal megabytes in)
+err-ldap-group-empty = Groeps-DN mag niet leeg zijn
+err-ldap-group-too-long = Groeps-DN is te lang (max { $max } tekens)
+err-ldap-group-invalid-characters = Groeps-DN mag geen omringende spaties of stuurtekens bevatten
 err-news-image-decode-failed = Kan afbeelding niet decoderen. Het bestand is mogelijk beschadigd.
 
 # Proxy errors
@@ -739,9 +1231,31 @@ err-proxy-connection-timeout = Proxy-verbinding time-out na { $seconds } { $seco
 }
 err-proxy-address-required = Proxy-adres is vereist wanneer proxy is ingeschakeld
 err-proxy-port-invalid = Proxy-poort moet tussen 1 en 65535 liggen
-err-news-image-too-large = Afbeelding is te groot (max 512KB)
+err-news-image-too-large = Afbeelding is te groot (max { $max_kb }KB)
 err-news-image-unsupported-type = Niet-ondersteund afbeeldingstype (alleen PNG, WebP, JPEG of SVG)
 err-topic-no-channel = Dit commando werkt alleen in een kanaal
+err-entry-no-channel = Dit commando werkt alleen in een kanaal
+cmd-welcome-desc = Bekijk of stel het bericht in dat gebruikers zien bij het betreden van het kanaal
+cmd-welcome-usage = Gebruik: /{ $command } [instellen <bericht>|wissen]
+cmd-welcome-arg-set = instellen
+cmd-welcome-arg-clear = wissen
+cmd-welcome-none = Geen welkomstbericht ingesteld
+msg-entry-message-display = Welkomstbericht: { $message }
+msg-entry-message-updated = Welkomstbericht bijgewerkt
+msg-entry-message-cleared = Welkomstbericht gewist
+cmd-rules-desc = Bekijk of stel de kanaalregels in
+cmd-rules-usage = Gebruik: /{ $command } [instellen <regels>|wissen]
+cmd-rules-arg-set = instellen
+cmd-rules-arg-clear = wissen
+cmd-rules-none = Geen regels ingesteld
+msg-rules-header = Regels voor { $channel }:
+cmd-language-desc = De taaltag van het kanaal bekijken of instellen
+cmd-language-usage = Gebruik: /{ $command } [instellen <taal>|wissen]
+cmd-language-arg-set = instellen
+cmd-language-arg-clear = wissen
+msg-language-status = Kanaaltaal: { $language }
+msg-language-none = Geen taal ingesteld
+err-language-invalid = Ongeldige taaltag: { $language } (bijv. nl of pt-BR)
 err-join-channel = Kon niet toetreden tot kanaal: { $error }
 err-leave-channel = Kon kanaal niet verlaten: { $error }
 err-list-channels = Kon kanalen niet weergeven: { $error }
@@ -751,6 +1265,8 @@ err-channel-too-long = Kanaalnaam is te lang (max { $max } tekens)
 err-channel-missing-prefix = Kanaalnaam moet beginnen met #
 err-channel-invalid-characters = Kanaalnaam bevat ongeldige tekens
 err-leave-no-channel = Je moet in een kanaal zijn om /{ $command } zonder argumenten te gebruiken
+err-invite-no-group = Gebruik /{ $command } in een groepsgesprektabblad
+err-group-too-many-members = Een groepsgesprek kan maximaal { $max } leden hebben
 err-not-in-channel = Je bent niet in kanaal { $channel }
 err-leave-already-pending = Wacht al op serverreactie
 err-uri-connection-failed = Verbinding met { $host } mislukt: { $error }
@@ -764,6 +1280,21 @@ label-port = Poort:
 # Transfer statuses
 transfer-status-queued = In wachtrij
 transfer-status-connecting = Verbinden…
+transfer-status-server-queued = Wachten op vrije serverplek (#{ $position })
+transfer-collision-pending = Bestand bestaat: { $path }
+collision-action-renamed = { $count } hernoemd
+collision-action-overwritten = { $count } overschreven
+collision-action-skipped = { $count } overgeslagen
+transfer-bar-count = { $count } { $count ->
+    [one] overdracht
+   *[other] overdrachten
+}
+status-bar-users = { $count } { $count ->
+    [one] gebruiker
+   *[other] gebruikers
+}
+status-bar-latency = { $ms } ms
+status-bar-server-time = Servertijd { $time } ({ $zone })
 transfer-status-transferring = Bezig met overdracht
 transfer-status-paused = Gepauzeerd
 transfer-status-completed = Voltooid
@@ -825,6 +1356,7 @@ event-broadcast = Uitzending
 event-chat-message = Chatbericht
 event-chat-mention = Chatvermelding
 event-connection-lost = Verbinding verbroken
+event-friend-online = Vriend online
 event-news-post = Nieuwsbericht
 event-permissions-changed = Rechten gewijzigd
 event-transfer-complete = Overdracht voltooid
@@ -859,6 +1391,17 @@ settings-sound-test = Test
 settings-notification-test = Test
 event-settings-show-toast = Toast tonen
 settings-toast-test = Test
+settings-do-not-disturb = Niet storen
+settings-quiet-hours = Stille uren
+settings-do-not-disturb-hint = Dempt meldingen, toasts en geluiden. Ongelezen-badges blijven oplopen en anderen zien 🔕 naast je naam.
+settings-notification-rules = Meldingsregels
+settings-notification-rules-server = Server:
+settings-notification-rules-channels = Kanaaluitzonderingen
+settings-notification-rules-keywords = Gevolgde trefwoorden
+settings-notification-rules-no-bookmarks = Maak een bladwijzer van een server om er meldingsregels voor in te stellen.
+channel-notification-all = Alle berichten
+channel-notification-mentions = Alleen vermeldingen
+channel-notification-mute = Dempen
 
 # Sound names
 sound-none = Geen
@@ -877,6 +1420,8 @@ notification-chat-message = Nieuw chatbericht
 notification-chat-message-from = Chat van {$username}
 notification-connection-lost = Verbinding verbroken
 notification-connection-lost-from = Verbinding met {$server} verbroken
+notification-friend-online = Een vriend is online gekomen
+notification-friend-online-name = {$username} is online
 notification-download-complete = Download voltooid
 notification-download-failed = Download mislukt
 notification-news-post = Nieuw nieuwsbericht
@@ -930,6 +1475,18 @@ ban-duration-7d = 7 dagen
 ban-duration-30d = 30 dagen
 ban-duration-permanent = Permanent
 
+# Broadcast target and delay options
+broadcast-target-label = Versturen naar:
+broadcast-target-all = Iedereen
+broadcast-target-channel = Leden van een kanaal
+broadcast-target-permission = Gebruikers met een recht
+broadcast-delay-label = Versturen:
+broadcast-delay-now = Nu
+broadcast-delay-5m = Over 5 minuten
+broadcast-delay-15m = Over 15 minuten
+broadcast-delay-1h = Over 1 uur
+broadcast-delay-1d = Over 1 dag
+
 # =============================================================================
 # Ban System
 # =============================================================================
@@ -979,12 +1536,32 @@ msg-untrusted-success = Vertrouwensinvoer succesvol verwijderd
 # Trust list messages
 msg-trust-list-header = Vertrouwde IPs:
 msg-trust-list-empty = Geen vertrouwde IPs
+msg-ldap-group-list-header = Directorygroepen:
+msg-ldap-group-list-empty = Geen directorygroepen gekoppeld
+msg-ldap-group-admin = beheerder
+msg-ldap-group-no-permissions = geen rechten
+msg-ldap-group-set = Directorygroep { $group } gekoppeld
+msg-ldap-group-removed = Directorygroep { $group } verwijderd
+msg-guest-upgraded = Account { $username } aangemaakt. Log de volgende keer hiermee in om het te gebruiken.
+msg-nickname-registered = Bijnaam { $nickname } is nu gereserveerd voor { $username }.
+msg-nickname-released = Bijnaam { $nickname } vrijgegeven.
+msg-history-days = De server bewaart je privéberichten { $days } dagen.
+msg-history-off = De server bewaart je privéberichten niet. Gebruik /history <dagen> om dit aan te zetten.
+msg-history-no-older = Geen oudere berichten met { $nickname }.
+err-history-days-too-long = Berichtgeschiedenis kan maximaal { $max } dagen worden bewaard
+err-history-more-tab = Gebruik /history more in een privéberichttabblad
 msg-trust-permanent = permanent
 msg-trust-expired = verlopen
 msg-trust-remaining = { $time } resterend
 msg-trust-remaining-days = { $days }d { $hours }u
 msg-trust-remaining-hours = { $hours }u { $minutes }m
 msg-trust-remaining-minutes = { $minutes }m
+msg-leaderboard-header = Meest actieve gebruikers op { $stat }:
+msg-leaderboard-empty = Nog geen activiteit vastgelegd
+leaderboard-stat-logins = aanmeldingen
+leaderboard-stat-messages = berichten
+leaderboard-stat-uploads = uploads
+leaderboard-stat-downloaded = gedownloade gegevens
 
 # =============================================================================
 # Voice Chat
@@ -993,6 +1570,16 @@ msg-trust-remaining-minutes = { $minutes }m
 # Voice error messages
 err-voice-join = Kon niet deelnemen aan spraakchat: { $error }
 err-voice-leave = Kon spraakchat niet verlaten: { $error }
+err-voice-limit = Kan spraaklimiet niet instellen: { $error }
+err-voice-clip = Soundboard: { $error }
+err-voice-text = Spraaktekst: { $error }
+err-voice-clip-name-empty = Clipnaam mag niet leeg zijn
+err-voice-clip-name-too-long = Clipnaam is te lang (max { $max } tekens)
+err-voice-clip-name-invalid = Clipnamen mogen alleen letters, cijfers, - en _ bevatten
+err-voice-clip-too-large = Clip is te groot (max 256 KB)
+err-voice-clip-unsupported-type = Niet-ondersteund clipformaat (alleen Ogg Vorbis)
+msg-voice-queued = Spraak is vol - je bent nummer { $position } in de wachtrij
+msg-voice-queue-cancelled = Je bent uit de spraakwachtrij verwijderd
 err-voice-no-token = Spraakchat gestart maar geen token ontvangen
 err-voice-already-active = Je bent al in een spraakchat op een andere server
 err-voice-no-permission = Je hebt geen toestemming om deel te nemen aan spraakchat
@@ -1018,7 +1605,12 @@ msg-voice-joined-empty = Spraakchat in { $target } gestart
 msg-voice-left = Spraakchat in { $target } verlaten
 msg-voice-user-joined = { $nickname } is toegetreden tot spraakchat
 msg-voice-user-left = { $nickname } heeft spraakchat verlaten
+msg-voice-broadcast-started = { $nickname } zendt uit in spraak - doe mee om te luisteren
+msg-voice-broadcast-ended = De spraakuitzending is beëindigd
+msg-voice-clip-played = { $nickname } speelde { $name } af
+msg-voice-text = [spraak] { $nickname }: { $message }
 msg-voice-you-left = Je hebt spraakchat verlaten
+msg-voice-tcp-relay = UDP-spraakverbinding mislukt ({ $error }) - spraak gaat via de serververbinding
 
 # Voice UI
 voice-join-tooltip = Spraakchat starten
@@ -1026,6 +1618,8 @@ voice-leave-tooltip = Spraakchat verlaten
 voice-mute-all-tooltip = Alles dempen
 voice-unmute-all-tooltip = Dempen opheffen
 voice-bar-participants = ({ $count })
+voice-bar-queued = (wachtrij: { $position })
+voice-bar-listeners = ({ $count } luisteren)
 voice-indicator-tooltip = In spraakchat
 tooltip-mute = Dempen
 tooltip-unmute = Dempen opheffen
@@ -1036,6 +1630,12 @@ voice-quality-medium = Gemiddeld (32 kbps)
 voice-quality-high = Hoog (64 kbps)
 voice-quality-very-high = Zeer hoog (96 kbps)
 
+# Voice connection quality
+voice-connection-good = Goed
+voice-connection-fair = Matig
+voice-connection-poor = Slecht
+voice-connection-tooltip = Verbinding: { $quality } ({ $loss }% verlies, { $jitter } ms jitter)
+
 # PTT modes
 ptt-mode-hold = Ingedrukt houden om te praten
 ptt-mode-toggle = Schakelen
@@ -1050,6 +1650,8 @@ ptt-delay-500ms = 500ms
 audio-output-device = Uitvoerapparaat
 audio-input-device = Invoerapparaat
 audio-refresh-devices = Apparaten vernieuwen
+audio-clip-volume = Soundboard-volume
+audio-voice-text-tts = Spraaktekstberichten voorlezen
 audio-voice-quality = Spraakkwaliteit
 audio-ptt-key = Push-to-Talk-toets
 audio-ptt-mode = PTT-modus
@@ -1076,6 +1678,44 @@ mic-boost-6db = +6 dB
 mic-boost-12db = +12 dB
 mic-boost-18db = +18 dB
 
+# =============================================================================
+# Global Hotkeys
+# =============================================================================
+
+# Settings
+settings-toggle-window-key = Toets venster tonen/verbergen
+button-clear-hotkey = Wissen
+
+# Shortcut descriptions (shown by the desktop on Wayland)
+hotkey-push-to-talk = Push-to-talk
+hotkey-toggle-window = Nexus tonen of verbergen
+
+# Errors
+err-window-hotkey-failed = Toets voor venster tonen/verbergen kon niet worden geregistreerd: { $error }
+err-global-shortcuts-unavailable = Portaal voor globale sneltoetsen niet beschikbaar, sneltoetsen werken alleen als Nexus focus heeft: { $error }
+
+# =============================================================================
+# Auto Away
+# =============================================================================
+
+settings-auto-away = Automatisch afwezig bij inactiviteit
+settings-auto-away-minutes = Minuten inactief
+
+# =============================================================================
+# Connection Health
+# =============================================================================
+
+# Server list indicator
+connection-health-good = Goed
+connection-health-fair = Redelijk
+connection-health-poor = Slecht
+connection-health-tooltip = Verbinding: { $quality }
+connection-health-latency = Latentie: { $ms } ms
+connection-health-jitter = Jitter: { $ms } ms
+connection-health-last-drop = Laatste verloren ping: { $time }
+connection-health-no-drops = Geen verloren pings
+connection-health-measuring = Latentie meten…
+
 # =============================================================================
 # System Tray (Windows/Linux only)
 # =============================================================================
@@ -1104,14 +1744,33 @@ tray-tooltip-unread = Nexus BBS - { $count ->
    *[other] { $count } ongelezen gesprekken
 }
 
+# =============================================================================
+# Notification Actions (macOS/Windows only)
+# =============================================================================
+
+# Actions
+notification-reply = Beantwoorden
+notification-reply-send = Verzenden
+notification-reply-placeholder = Beantwoorden…
+notification-mark-read = Markeren als gelezen
+
 # =============================================================================
 # Toast Notifications
 # =============================================================================
 
 toast-link-copied = Link gekopieerd naar klembord
 toast-copied = Gekopieerd naar klembord
+toast-checksum-copied = SHA-256 gekopieerd naar klembord
 toast-download-started = { $filename } downloaden
 toast-download-queued = Download in wachtrij: { $filename }
+toast-downloads-started = { $count } { $count ->
+    [one] bestand
+   *[other] bestanden
+} downloaden
+toast-downloads-queued = { $count } { $count ->
+    [one] download
+   *[other] downloads
+} in wachtrij
 toast-upload-started = { $filename } uploaden
 toast-upload-queued = Upload in wachtrij: { $filename }
 toast-uploads-started = { $count } { $count ->
@@ -1122,3 +1781,28 @@ toast-uploads-queued = { $count } { $count ->
     [one] upload
    *[other] uploads
 } in wachtrij
+toast-upload-nothing-new = Niets te uploaden — de server heeft deze bestanden al
+toast-upload-watch-started = { $folder } wordt bewaakt — nieuwe bestanden worden automatisch geüpload
+toast-upload-watch-stopped = { $folder } wordt niet meer bewaakt
+toast-auto-download-started = { $pattern } wordt automatisch gedownload uit { $folder }
+toast-auto-download-stopped = Automatisch downloaden uit { $folder } gestopt
+toast-screenshot-folder-set = Schermafbeeldingen worden naar deze map geüpload
+toast-chat-exported = Chat geëxporteerd
+
+# =============================================================================
+# First-run Tips
+# =============================================================================
+
+tip-welcome-title = Welkom bij Nexus
+tip-welcome = Vul hieronder het adres van een server in en maak verbinding. Voeg een bladwijzer toe om de server, met je aanmelding, in de lijst links te bewaren, zodat hij de volgende keer één klik verwijderd is.
+tip-fingerprint = Elke server heeft een certificaatvingerafdruk, een soort handtekening. Nexus onthoudt die bij de eerste verbinding en waarschuwt je als hij verandert, omdat dat kan betekenen dat iemand zich voordoet als de server. Accepteer een nieuwe vingerafdruk alleen als de beheerder van de server je over de wijziging heeft verteld.
+tip-server-list = Je bladwijzers en open verbindingen staan links. Klik op een ervan om van server te wisselen, of sleep bladwijzers om ze te herschikken.
+tip-toolbar = De werkbalk opent bestanden, nieuws, instellingen en meer. Beweeg over een pictogram om te zien wat het doet.
+tip-chat = Chat hier met iedereen op de server. Typ / om de beschikbare opdrachten te zien, zoals /join om een kanaal te betreden.
+tip-user-list = Gebruikers die nu online zijn staan rechts. Klik op een naam om ze een bericht te sturen of hun info te bekijken.
+tip-counter = Tip { $current } van { $total }
+button-add-bookmark = Bladwijzer toevoegen
+button-tip-next = Volgende
+button-tip-done = Klaar
+button-tip-got-it = Begrepen
+button-skip-tips = Tips overslaan
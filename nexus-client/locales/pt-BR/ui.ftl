@@ -11,6 +11,13 @@ button-connect = Conectar
 button-save = Salvar
 button-create = Criar
 button-edit = Editar
+button-check-ports = Verificar portas
+button-statistics = Estatísticas
+button-dashboard = Painel
+button-refresh = Atualizar
+button-doors = Portas
+button-doors-leave = Sair
+button-doors-back = Voltar
 button-update = Atualizar
 button-accept-new-certificate = Aceitar Novo Certificado
 button-close = Fechar
@@ -18,9 +25,23 @@ button-choose-avatar = Escolher Ícone
 button-clear-avatar = Limpar
 button-choose-image = Escolher Imagem
 button-clear-image = Limpar
+button-add-file = Adicionar arquivo
 button-overwrite = Substituir
 button-change-password = Alterar Senha
 button-browse = Procurar
+button-undo = Desfazer
+button-add = Adicionar
+button-remove = Remover
+button-view = Ver
+button-hide = Ocultar
+button-export = Exportar…
+button-trust-certificate = Confiar no certificado
+button-skip = Pular
+button-skip-existing = Pular existentes
+button-download-anyway = Baixar mesmo assim
+button-unlock = Desbloquear
+button-view-as-user = Ver como Usuário
+button-post-comment = Publicar
 
 # =============================================================================
 # Titles
@@ -35,19 +56,29 @@ title-user-create = Criar Usuário
 title-user-edit = Editar Usuário
 title-update-user = Atualizar Usuário
 title-user-management = Gerenciar Usuários
+title-permission-audit = Auditoria de Permissões
+title-impersonate = Visualizando como { $username }
 title-confirm-delete = Confirmar Exclusão
 title-connected = Conectados
 title-settings = Configurações
 title-bookmarks = Favoritos
 title-users = Usuários
+title-friends = Amigos
+friend-online = online
+friend-offline = offline
 title-edit-server-info = Editar Info do Servidor
 title-fingerprint-mismatch = Impressão Digital do Certificado Não Corresponde!
+title-fingerprint-new-server = Novo certificado do servidor
+title-create-password-vault = Proteger senhas salvas
+title-unlock-password-vault = Desbloquear senhas salvas
+title-unlock-config = Desbloquear configurações
 title-server-info = Info do Servidor
 title-user-info = Info do Usuário
 title-about = Sobre
 title-change-password = Alterar Senha
 title-news = Notícias
 title-transfers = Transferências
+title-transfer-history = Histórico de transferências
 title-news-create = Criar Publicação
 title-news-edit = Editar Publicação
 title-channel-members = Membros
@@ -58,10 +89,14 @@ title-channel-members = Membros
 
 placeholder-username = Nome de usuário
 placeholder-password = Senha
+placeholder-master-passphrase = Frase-senha mestra
+placeholder-confirm-master-passphrase = Confirmar frase-senha mestra
 placeholder-port = Porta
 placeholder-server-address = Endereço do Servidor
 placeholder-server-name = Nome do Servidor
+placeholder-folder-name = Nome da pasta
 placeholder-username-optional = Nome de usuário (opcional)
+placeholder-fallback-addresses-optional = Endereços alternativos (opcional, separados por vírgula)
 placeholder-password-optional = Senha (opcional)
 placeholder-password-keep-current = Senha
 placeholder-current-password = Senha Atual
@@ -70,10 +105,18 @@ placeholder-confirm-password = Confirmar Senha
 placeholder-message = Digite uma mensagem…
 placeholder-no-permission = Sem permissão
 placeholder-broadcast-message = Mensagem de transmissão
+placeholder-broadcast-channel = Escolha um canal
+placeholder-broadcast-permission = Escolha uma permissão
 placeholder-server-description = Descrição do servidor
 placeholder-news-body = Conteúdo da notícia
+placeholder-news-caption = Legenda (opcional)
+placeholder-news-file-path = Caminho na área de arquivos
 placeholder-nickname-optional = Apelido (opcional)
+placeholder-status-optional = Status (opcional)
+placeholder-download-folder-optional = Pasta de downloads (opcional)
 placeholder-proxy-address = Endereço do proxy
+placeholder-notification-rules-channel = #canal
+placeholder-notification-rules-keyword = Palavra-chave
 placeholder-proxy-port = 9050
 placeholder-proxy-username = Nome de usuário (opcional)
 placeholder-proxy-password = Senha (opcional)
@@ -86,13 +129,20 @@ placeholder-auto-join-channels = #canal1 #canal2 (separados por espaços)
 # =============================================================================
 
 label-auto-connect = Auto-Conectar
+label-server-avatar = Avatar:
 label-add-bookmark = Favorito
+label-lan-servers = Nesta rede
+label-use-sso = Login único (SSO)
 label-admin = Admin
 label-shared-account = Conta Compartilhada
 label-enabled = Habilitado
+label-erase-personal-data = Apagar também os dados pessoais
+label-broadcast-require-ack = Pedir confirmação aos destinatários
 label-permissions = Permissões:
 label-expected-fingerprint = Impressão digital esperada:
 label-received-fingerprint = Impressão digital recebida:
+label-certificate-fingerprint = Impressão digital do certificado:
+label-identity-key = Chave de identidade:
 label-theme = Tema
 label-chat-history-retention = Histórico de chat:
 chat-history-forever = Para sempre
@@ -100,6 +150,10 @@ chat-history-30-days = 30 dias
 chat-history-14-days = 14 dias
 chat-history-7-days = 7 dias
 chat-history-disabled = Desativado
+collision-policy-rename = Manter ambos (renomear)
+collision-policy-overwrite = Sobrescrever
+collision-policy-skip = Pular
+collision-policy-ask = Perguntar
 label-max-scrollback = Histórico máximo:
 label-chat-font-size = Tamanho da fonte:
 label-show-connection-events = Mostrar eventos de conexão/desconexão
@@ -107,6 +161,19 @@ label-show-channel-events = Mostrar eventos de entrada/saída
 label-show-timestamps = Mostrar horários
 label-use-24-hour-time = Usar formato de 24 horas
 label-show-seconds = Mostrar segundos
+label-split-view = Mostrar arquivos, notícias e monitor de conexões ao lado do chat
+label-spell-check = Verificar a ortografia no chat e nas notícias
+label-spell-check-no-dictionary = Nenhum dicionário foi encontrado para o seu idioma. Coloque os arquivos .aff e .dic na pasta dictionaries ao lado de config.json.
+label-send-read-receipts = Enviar confirmações de leitura de mensagens privadas
+label-user-list-grouping = Agrupar lista de usuários:
+label-user-list-sort = Ordenar lista de usuários:
+user-list-group-none = Nenhum
+user-list-group-admins = Administradores primeiro
+user-list-group-channel = Por canal
+user-list-group-status = Por status
+user-list-sort-alphabetical = Alfabética
+user-list-sort-idle = Tempo ocioso
+user-list-sort-connect = Tempo de conexão
 label-server-name = Nome:
 label-server-description = Descrição:
 label-server-version = Versão:
@@ -140,6 +207,27 @@ tab-files = Arquivos
 tab-channels = Canais
 label-connections-short = Conexões:
 label-transfers-short = Transferências:
+label-active-transfers-short = Ativas:
+label-queue-order-short = Ordem da fila:
+label-trusted-weight-short = Peso confiável:
+label-nat-mapping-short = Mapeamento de portas:
+label-nat-external-ip-short = IP externo:
+label-nat-not-mapped = Não mapeado
+connectivity-check-running = Verificando portas…
+check-service-bbs = BBS
+check-service-transfer = Transferências
+check-service-websocket = WebSocket
+check-service-transfer-websocket = Transferências WebSocket
+check-service-voice = Voz
+check-state-open = Aberta
+check-state-closed = Fechada
+check-state-filtered = Filtrada
+check-state-untested = Não testada
+label-check-port = { $service } ({ $protocol } { $port }):
+label-queue-order-small-first = Arquivos pequenos primeiro
+label-queue-order-arrival = Ordem de chegada
+label-small-files-first = Colocar arquivos pequenos primeiro na fila
+label-unlimited = Ilimitado
 label-reindex-short = Reindexar:
 label-persistent-short = Persistentes:
 label-auto-join-short = Auto-entrada:
@@ -156,6 +244,19 @@ label-download-location = Local de Download:
 label-queue-transfers = Enfileirar Transferências
 label-download-limit = Limite de Downloads:
 label-upload-limit = Limite de Uploads:
+label-auto-open-folder = Abrir a pasta ao concluir um download
+label-collision-policy = Se o arquivo existir:
+label-require-fingerprint-approval = Perguntar antes de confiar no certificado de um novo servidor
+label-known-servers = Servidores conhecidos
+known-servers-empty = Nenhum certificado de servidor salvo ainda
+known-server-first-seen = Visto pela primeira vez em { $date }
+known-server-first-seen-unknown = Visto pela primeira vez: desconhecido
+label-encrypt-config = Criptografar configurações, favoritos e histórico de chat com uma frase-senha mestra
+config-encryption-locked-note = As configurações estão bloqueadas. Reinicie o Nexus e digite a frase-senha mestra para alterar isso.
+label-upload-rule-extensions = Tipos de arquivo permitidos:
+label-upload-rule-max-file-size = Maior arquivo (MB):
+label-upload-rule-max-total-size = Maior envio (MB):
+label-auto-download-pattern = Nomes de arquivo:
 label-persistent-channels = Canais Persistentes:
 label-auto-join-channels = Canais Auto-Entrada:
 label-channels = Canais
@@ -169,11 +270,14 @@ permission-user_info = Info do Usuário
 permission-chat_send = Enviar no Chat
 permission-chat_receive = Receber Chat
 permission-chat_join = Entrar no Chat
+permission-chat_language = Idioma do chat
 permission-chat_create = Criar Canal
 permission-chat_list = Lista de Canais
 permission-chat_secret = Canal Secreto
 permission-chat_topic = Tópico do Chat
 permission-chat_topic_edit = Editar Tópico do Chat
+permission-chat_topic_lock = Bloquear Tópico do Chat
+permission-chat_entry_edit = Editar Boas-vindas do Chat
 permission-user_broadcast = Difusão de Usuário
 permission-user_create = Criar Usuário
 permission-user_delete = Excluir Usuário
@@ -184,6 +288,7 @@ permission-news_list = Lista de Notícias
 permission-news_create = Criar Notícia
 permission-news_edit = Editar Notícia
 permission-news_delete = Excluir Notícia
+permission-file_comment = Comentar arquivos
 permission-file_copy = Copiar Arquivo
 permission-file_create_dir = Diretório Criar
 permission-file_delete = Excluir Arquivo
@@ -203,6 +308,11 @@ permission-trust_create = Criar Confiança
 permission-trust_delete = Excluir Confiança
 permission-trust_list = Lista de Confiança
 permission-connection_monitor = Monitor de Conexões
+permission-log_view = Ver log
+permission-door_play = Jogar portas
+permission-voice_broadcast = Transmissão de Voz
+permission-voice_clip_upload = Enviar Clipes de Voz
+permission-voice_limit = Limite de Voz
 permission-voice_listen = Ouvir Voz
 permission-voice_talk = Falar por Voz
 
@@ -227,19 +337,31 @@ tooltip-files-paste = Colar
 tooltip-files-search = Pesquisar arquivos
 tooltip-manage-users = Gerenciar Usuários
 tooltip-connection-monitor = Monitor de Conexões
+tooltip-server-log = Log do servidor
 tooltip-server-info = Info do Servidor
 tooltip-about = Sobre
 tooltip-transfers = Transferências
 tooltip-download-all = Baixar Tudo
 tooltip-upload = Enviar
 file-picker-upload-title = Selecionar arquivos para enviar
+file-picker-download-to-title = Selecionar pasta de downloads
+folder-picker-upload-watch-title = Selecione a pasta para enviar automaticamente
 drop-to-upload = Soltar para enviar
 tooltip-clear-inactive = Limpar Inativos
+tooltip-transfer-history = Histórico de transferências
+tooltip-transfer-history-clear = Limpar histórico
+tooltip-transfer-history-remove = Remover do histórico
 tooltip-transfer-pause = Pausar
 tooltip-transfer-resume = Retomar
 tooltip-transfer-cancel = Cancelar
 tooltip-transfer-remove = Remover
 tooltip-transfer-open-folder = Abrir Pasta
+tooltip-transfer-open-file = Abrir Arquivo
+tooltip-transfer-reveal = Mostrar no Gerenciador de Arquivos
+tooltip-transfer-copy-checksum = Copiar SHA-256
+tooltip-transfer-collision-rename = Manter ambos (renomear)
+tooltip-transfer-collision-overwrite = Sobrescrever arquivos existentes
+tooltip-transfer-collision-skip = Pular arquivos existentes
 tooltip-transfer-move-up = Mover para Cima
 tooltip-transfer-move-down = Mover para Baixo
 tooltip-transfer-retry = Tentar Novamente
@@ -252,16 +374,34 @@ tooltip-disconnect = Desconectar
 tooltip-edit = Editar
 tooltip-info = Info
 tooltip-message = Mensagem
+tooltip-add-friend = Adicionar amigo
+tooltip-remove-friend = Remover amigo
 tooltip-kick = Expulsar
 tooltip-close = Fechar
+tooltip-retry-send = Tentar enviar novamente
+tooltip-cancel-send = Cancelar envio
+tooltip-message-sent = Enviada
+tooltip-message-delivered = Entregue
+tooltip-message-read = Lida
 tooltip-new-tab = Nova Aba
 tooltip-close-tab = Fechar Aba
 tooltip-add-bookmark = Adicionar Favorito
+tooltip-new-folder = Nova pasta
 tooltip-create-user = Criar Usuário
+tooltip-permission-audit = Auditar Permissões
 tooltip-create-news = Criar Publicação
+tooltip-news-search = Pesquisar notícias
+tooltip-news-search-clear = Limpar pesquisa
 tooltip-delete = Excluir
 context-menu-download = Baixar
+context-menu-download-to = Baixar para…
+context-menu-download-select = Baixar seleção…
+context-menu-auto-download = Download automático…
 context-menu-upload = Enviar
+context-menu-watch-folder = Monitorar pasta local…
+context-menu-screenshot-folder = Compartilhar capturas aqui
+context-menu-unwatch-folder = Parar de monitorar pasta
+context-menu-upload-rules = Regras de envio…
 context-menu-open = Abrir
 
 # =============================================================================
@@ -269,6 +409,7 @@ context-menu-open = Abrir
 # =============================================================================
 
 panel-connection-monitor = Monitor de Conexões
+panel-server-log = Log do servidor
 panel-active-connections = Conexões Ativas: { $count }
 tab-connections = Conexões
 tab-transfers = Transferências
@@ -283,9 +424,97 @@ menu-info = Info
 menu-copy = Copiar
 menu-kick = Expulsar
 menu-ban = Banir
+menu-pop-out = Destacar
+menu-mute-tab = Silenciar
+menu-unmute-tab = Reativar som
+menu-connect-all = Conectar todos
+menu-rename = Renomear
+menu-delete = Excluir
 connection-monitor-loading = Carregando conexões…
 connection-monitor-no-connections = Sem conexões
 connection-monitor-no-transfers = Sem transferências ativas
+connection-monitor-queued = Na fila
+connection-monitor-weight = Peso de divisão justa: ×{ $weight }
+connection-monitor-listed = Listado em { $zone }
+connection-monitor-queued-count = { $count } na fila
+placeholder-server-log-module = Filtrar por módulo
+server-log-loading = Carregando log do servidor…
+server-log-empty = Nenhuma linha de log
+server-log-level-error = Somente erros
+server-log-level-warn = Avisos e acima
+server-log-level-info = Info e acima
+server-log-level-debug = Tudo (depuração)
+panel-statistics = Estatísticas
+stats-loading = Carregando estatísticas…
+panel-snippet = Snippet
+snippet-loading = Carregando snippet…
+snippet-details = Compartilhado por { $author } · expira em { $expires }
+stats-range-days = Últimos { $days } dias
+stats-chart-max = Maior dia: { $value }
+stats-metric-logins = Logins
+stats-metric-messages = Mensagens
+stats-metric-uploads = Envios
+stats-metric-upload-bytes = Dados enviados
+stats-metric-downloads = Downloads
+stats-metric-download-bytes = Dados baixados
+stats-metric-peak-sessions = Pico de usuários online
+panel-admin-dashboard = Painel de administração
+dashboard-loading = Carregando painel…
+dashboard-connections = Conexões
+dashboard-sessions = Usuários online
+dashboard-connection-count = Conexões
+dashboard-transfer-connections = Conexões de transferência
+dashboard-transfers = Transferências
+dashboard-active-transfers = Em andamento
+dashboard-queued-transfers = Na fila
+dashboard-today = Hoje
+dashboard-disk = Área de arquivos
+dashboard-disk-free = Espaço livre
+dashboard-disk-free-of = { $free } livres de { $total }
+dashboard-disk-unknown = Desconhecido
+dashboard-recent-audit = Eventos de auditoria recentes
+dashboard-audit-empty = Nenhum evento de auditoria ainda
+dashboard-updated = Atualizado às { $time }
+panel-doors = Portas
+doors-loading = Carregando portas…
+doors-empty = Nenhuma porta neste servidor
+doors-starting = Iniciando…
+doors-playing-hint = As teclas vão para a porta. Use Sair para terminar.
+doors-exited = A porta terminou
+doors-exited-code = A porta terminou com o código { $code }
+
+# =============================================================================
+# Quick Switcher
+# =============================================================================
+
+quick-switcher-placeholder = Ir para um canal, mensagem, favorito ou painel...
+user-list-filter-placeholder = Filtrar usuários...
+user-list-section-admins = Administradores
+user-list-section-users = Usuários
+user-list-section-no-channel = Outros
+user-list-section-online = Online
+user-list-section-away = Ausente
+user-list-section-dnd = Não perturbe
+user-list-idle = Ocioso há { $duration }
+quick-switcher-no-matches = Nenhum resultado
+quick-switcher-bookmark = Favorito
+
+# =============================================================================
+# Chat Input
+# =============================================================================
+
+chat-paste-prompt = Colar { $count } linhas?
+chat-paste-too-long-prompt = Longo demais para uma mensagem (máx. { $max }). Enviar mesmo assim?
+button-send-as-messages = Enviar como { $count } mensagens
+button-send-as-one-message = Enviar como uma mensagem
+button-send-in-parts = Enviar em { $count } partes
+button-share-as-snippet = Compartilhar como snippet
+button-expand-code = Mostrar todas as { $count } linhas
+button-collapse-code = Recolher
+chat-broadcast-ack-prompt = { $username } pediu que você confirme a transmissão
+button-acknowledge = Confirmar
+button-dismiss = Dispensar
+spell-no-suggestions = Nenhuma sugestão
 
 # =============================================================================
 # Empty States
@@ -296,11 +525,35 @@ empty-no-connections = Sem conexões
 empty-no-bookmarks = Sem favoritos
 empty-no-users = Nenhum usuário online
 empty-no-channel-members = Nenhum membro no canal
+empty-no-matching-users = Nenhum usuário correspondente
 user-management-loading = Carregando usuários…
 user-management-no-users = Nenhum usuário encontrado
+permission-audit-hint = Escolha uma permissão para ver quem a possui, ou clique em um usuário para ver tudo o que ele pode fazer.
+permission-audit-loading = Carregando…
+permission-audit-no-holders = Nenhuma conta possui esta permissão
+permission-audit-effective = Permissões efetivas de { $username }
+permission-audit-admin = Administradores possuem todas as permissões
+permission-audit-no-permissions = Esta conta não tem permissões
+permission-audit-restricted = Concedidas, mas indisponíveis para contas compartilhadas:
+permission-audit-disabled = Desativada
+impersonate-notice = Visualização somente leitura do que esta conta vê. Cada visualização é registrada no log de auditoria.
+impersonate-permissions = Permissões
+impersonate-files = Arquivos ({ $path })
+impersonate-no-file-access = Esta conta não pode navegar pelos arquivos
+impersonate-empty-folder = Esta pasta está vazia
+impersonate-channels = Canais
+impersonate-no-channel-access = Esta conta não pode listar canais
+impersonate-no-channels = Nenhum canal visível
+impersonate-secret = Secreto
 news-loading = Carregando notícias…
 news-no-posts = Nenhuma publicação ainda
+news-search-placeholder = Pesquisar notícias… (author:nome since:AAAA-MM-DD until:AAAA-MM-DD)
+news-searching = Pesquisando…
+news-search-no-results = Nenhuma publicação encontrada
 transfers-empty = Sem transferências
+transfer-history-search-placeholder = Pesquisar por nome, servidor ou pasta…
+transfer-history-empty = Nenhuma transferência concluída ainda
+transfer-history-no-matches = Nenhuma transferência corresponde à sua pesquisa
 news-updated = Atualizado
 files-loading = Carregando arquivos…
 files-empty = Este diretório está vazio
@@ -312,10 +565,19 @@ files-column-name = Nome
 files-column-size = Tamanho
 files-column-modified = Modificado
 files-column-path = Caminho
+files-column-rating = Avaliação
 files-search-placeholder = Pesquisar arquivos…
 files-search-breadcrumb = Pesquisa - { $query }
 files-searching = Pesquisando…
 files-no-results = Nenhum arquivo encontrado
+files-highlights = Destaques
+files-highlights-recent = Novos envios desta semana
+files-highlights-popular = Mais baixados
+files-highlights-empty = Nada por aqui ainda
+files-highlights-downloads = { $count } { $count ->
+    [one] download
+   *[other] downloads
+}
 files-search-query-too-short = A consulta de pesquisa é muito curta (mín. { $min_length } caracteres)
 files-search-query-too-long = A consulta de pesquisa é muito longa (máx. { $max_length } caracteres)
 files-search-query-invalid = A consulta de pesquisa contém caracteres inválidos
@@ -326,6 +588,30 @@ files-share = Compartilhar
 files-rename = Renomear
 files-rename-title = Renomear "{ $name }"
 files-rename-placeholder = Novo nome
+files-upload-rule-title = Regras de envio de "{ $name }"
+files-upload-rule-loading = Carregando…
+files-upload-rule-extensions-placeholder = Qualquer tipo (ex.: mp3, flac)
+files-upload-rule-no-limit = Sem limite
+files-upload-rule-remove = Remover regra
+files-upload-rule-help = As regras valem para esta pasta e suas subpastas, a menos que uma subpasta tenha as suas próprias.
+files-auto-download-title = Download automático de "{ $name }"
+files-auto-download-pattern-placeholder = ex.: *.mp3, *.m4a
+files-auto-download-remove = Parar download automático
+files-auto-download-help = Os arquivos novos desta pasta cujos nomes correspondem são baixados enquanto você estiver conectado por este favorito. Use * para qualquer texto e ? para um único caractere, e separe os padrões com vírgulas. Arquivos já baixados são ignorados.
+files-upload-rule-hint = Envios aqui: { $limits }
+files-upload-rule-hint-types = somente { $extensions }
+files-upload-rule-hint-file-size = até { $size } por arquivo
+files-upload-rule-hint-total-size = até { $size } por envio
+files-upload-preview-title = Os arquivos já existem
+files-upload-preview-comparing = Comparando com os arquivos do servidor…
+files-upload-preview-summary = { $new } novos, { $identical } já no servidor, { $conflicts } diferentes:
+files-upload-preview-blocked = { $count } { $count ->
+    [one] arquivo tem
+   *[other] arquivos têm
+} uma pasta com o mesmo nome no servidor e { $count ->
+    [one] será pulado
+   *[other] serão pulados
+}
 files-cut = Recortar
 files-copy = Copiar
 files-paste = Colar
@@ -335,6 +621,13 @@ files-delete-confirm-title = Confirmar exclusão
 files-delete-confirm-message = Tem certeza de que deseja excluir "{ $name }"?
 # File Overwrite Dialog
 files-overwrite-title = O arquivo já existe
+files-download-to-title = Baixar { $name }
+label-remember-for-server = Lembrar para este servidor
+label-select-all = Selecionar tudo
+files-download-select-summary = { $count } de { $total } selecionados ({ $size })
+files-download-select-too-many = É possível selecionar no máximo { $max } arquivos individuais. Baixe a pasta inteira em vez disso.
+files-download-duplicate-title = Já baixado
+files-download-duplicate-message = "{ $name }" já está neste computador em { $path }. Deseja baixá-lo novamente?
 files-overwrite-message = Um arquivo ou pasta chamado "{ $name }" já existe no destino. Deseja substituí-lo?
 
 # File Info Dialog
@@ -350,6 +643,17 @@ files-info-directory = Diretório
 files-info-symlink = Symlink:
 files-info-yes = Sim
 files-info-na = N/D
+files-info-rating = Avaliação:
+files-info-rating-value = { $average } ({ $count } { $count ->
+    [one] avaliação
+   *[other] avaliações
+})
+files-info-not-rated = Ainda não avaliado
+files-info-your-rating = Sua avaliação:
+files-info-comments = Comentários ({ $count })
+files-comments-loading = Carregando comentários…
+files-comments-empty = Nenhum comentário ainda
+files-comment-placeholder = Escreva um comentário…
 
 # =============================================================================
 # Directory Name Validation Errors
@@ -368,6 +672,7 @@ err-dir-name-invalid = O nome do diretório contém caracteres inválidos
 tab-general = Geral
 tab-chat = Chat
 tab-network = Rede
+tab-security = Segurança
 tab-audio = Áudio
 
 # =============================================================================
@@ -390,6 +695,8 @@ chat-prefix-system = [SIS]
 chat-prefix-error = [ERR]
 chat-prefix-info = [INFO]
 chat-prefix-broadcast = [BROADCAST]
+chat-prefix-queued = [PENDING]
+chat-export-subtitle = Exportado de { $server } em { $date }
 
 # =============================================================================
 # Success Messages
@@ -413,11 +720,28 @@ msg-channel-member-count = { $count } { $count ->
    *[other] membros
 }
 channel-secret = secreto
+channel-info-topic = Tópico
+channel-info-rules = Regras
+channel-info-language = Idioma
 msg-broadcast-sent = Transmissão enviada com sucesso
+msg-broadcast-delivered = Transmissão entregue a { $count } { $count ->
+    [one] usuário
+   *[other] usuários
+}
+msg-broadcast-scheduled = Transmissão agendada para { $time }
+msg-broadcast-acknowledged = Transmissão confirmada por { $count } de { $delivered } usuários
+msg-storage-low = Pouco espaço em disco no servidor para { $volume }: { $free } livres, abaixo do mínimo de { $threshold }
+msg-storage-recovered = Espaço em disco no servidor para { $volume } recuperado: { $free } livres
+storage-volume-files = a área de arquivos
+storage-volume-database = o banco de dados
+msg-friend-added = { $username } adicionado aos amigos
+msg-friend-removed = { $username } removido dos amigos
 msg-user-created = Usuário criado com sucesso
 msg-user-created-name = Usuário '{ $username }' criado com sucesso
 msg-user-deleted = Usuário excluído com sucesso
 msg-user-deleted-name = Usuário '{ $username }' excluído com sucesso
+msg-user-erased = Usuário excluído e dados pessoais apagados
+msg-user-erased-name = Usuário '{ $username }' excluído e dados pessoais apagados
 msg-user-updated = Usuário atualizado com sucesso
 msg-user-updated-name = Usuário '{ $username }' atualizado com sucesso
 msg-permissions-updated = Suas permissões foram atualizadas
@@ -432,6 +756,8 @@ msg-welcome-back = Bem-vindo de volta
 msg-status-set = Status definido: { $status }
 msg-status-cleared = Status limpo
 msg-user-is-away = { $nickname } está ausente
+msg-group-member-joined = { $nickname } entrou na conversa
+msg-group-member-left = { $nickname } saiu da conversa
 msg-user-is-away-status = { $nickname } está ausente: { $status }
 
 # =============================================================================
@@ -442,6 +768,14 @@ msg-topic-cleared = Tema limpo por { $username }
 msg-topic-set = Tema definido por { $username }: { $topic }
 msg-secret-set = Modo secreto habilitado por { $username }
 msg-secret-cleared = Modo secreto desabilitado por { $username }
+msg-topic-locked = Tópico bloqueado por { $username }
+msg-topic-unlocked = Tópico desbloqueado por { $username }
+msg-rules-set = Regras do canal atualizadas por { $username }
+msg-rules-cleared = Regras do canal removidas por { $username }
+msg-language-set = Idioma do canal definido como { $language } por { $username }
+msg-language-cleared = Idioma do canal removido por { $username }
+msg-voice-limit-set = Limite de voz definido como { $limit } por { $username }
+msg-voice-limit-cleared = Limite de voz removido por { $username }
 msg-server-info-updated = Configuração do servidor atualizada
 msg-topic-display = Tópico: { $topic }
 confirm-delete-user = Tem certeza que deseja excluir o usuário '{ $username }'?
@@ -461,6 +795,7 @@ err-user-kick-failed = Falha ao expulsar usuário
 err-unknown = Erro desconhecido
 err-no-shutdown-handle = Erro de conexão: Sem handle de desligamento
 err-userlist-failed = Falha ao atualizar lista de usuários
+err-request-timeout = O servidor não respondeu a tempo
 err-port-invalid = A porta deve ser um número válido (1-65535)
 
 # Network connection errors
@@ -474,12 +809,16 @@ err-connection-closed = Conexão fechada
 err-could-not-determine-config-dir = Não foi possível determinar o diretório de configuração
 err-message-too-long = Mensagem muito longa ({ $length } caracteres, máx { $max })
 err-send-failed = Falha ao enviar mensagem
+err-checksum-failed = Falha ao calcular a soma de verificação
 err-no-chat-permission = Você não tem permissão para enviar mensagens
+err-chat-window-command = Somente mensagens e /me podem ser enviados de uma janela de chat
 err-console-no-send = Use /{ $join } para entrar em um canal ou /{ $msg } para enviar uma mensagem a um usuário
 err-broadcast-too-long = Broadcast muito longo ({ $length } caracteres, máx { $max })
 err-broadcast-send-failed = Falha ao enviar difusão
+err-broadcast-target-required = Escolha quem recebe a transmissão
 err-name-required = O nome do favorito é obrigatório
 err-address-required = O endereço do servidor é obrigatório
+err-fallback-address-invalid = Endereço alternativo inválido: { $address }
 err-port-required = A porta é obrigatória
 err-username-required = O nome de usuário é obrigatório
 err-password-required = A senha é obrigatória
@@ -497,13 +836,20 @@ err-nickname-too-long = O apelido é muito longo (máx { $max } caracteres)
 err-nickname-invalid = O apelido contém caracteres inválidos
 err-password-too-long = A senha é muito longa (máx { $max } caracteres)
 err-passwords-do-not-match = As senhas não correspondem
+err-wrong-master-passphrase = Frase-senha mestra incorreta
+err-master-passphrase-empty = A frase-senha mestra não pode ficar vazia
+err-password-vault = Erro no cofre de senhas: { $error }
 err-current-password-required = A senha atual é obrigatória
 err-new-password-required = A nova senha é obrigatória
 err-confirm-password-required = Por favor, confirme sua nova senha
 err-topic-too-long = O tópico é muito longo ({ $length } caracteres, máx { $max })
+err-entry-message-too-long = A mensagem de boas-vindas é muito longa ({ $length } caracteres, máx. { $max })
+err-rules-too-long = As regras são muito longas ({ $length } caracteres, máx. { $max })
 err-avatar-unsupported-type = Tipo de arquivo não suportado. Use PNG, WebP, JPEG ou SVG.
 err-avatar-too-large = Ícone muito grande. O tamanho máximo é { $max_kb }KB.
 err-avatar-decode-failed = Falha ao decodificar a imagem. O arquivo pode estar corrompido.
+err-known-server-export = Falha ao exportar o certificado: { $error }
+err-config-encryption = Falha ao criptografar as configurações: { $error }
 err-server-name-empty = O nome do servidor não pode estar vazio
 err-server-name-too-long = O nome do servidor é muito longo (máx { $max } caracteres)
 err-server-name-contains-newlines = O nome do servidor não pode conter quebras de linha
@@ -524,14 +870,23 @@ err-failed-send-update = Falha ao enviar atualização: { $error }
 # =============================================================================
 
 err-failed-save-config = Falha ao salvar configuração: { $error }
+err-friends-no-bookmark = Os amigos são salvos no favorito do servidor; adicione este servidor aos favoritos primeiro
+err-friends-too-many = Amigos demais (máx. { $max })
+err-friend-exists = { $username } já é um amigo
+err-friend-not-found = { $username } não é um amigo
 err-failed-save-settings = Falha ao salvar configurações: { $error }
 err-invalid-port-bookmark = Porta inválida no favorito: { $name }
 err-failed-send-broadcast = Falha ao enviar difusão: { $error }
 err-failed-send-message = Falha ao enviar mensagem: { $error }
+err-failed-start-group = Falha ao iniciar a conversa em grupo: { $error }
+err-failed-share-snippet = Falha ao compartilhar snippet: { $error }
 err-failed-create-user = Falha ao criar usuário: { $error }
 err-failed-delete-user = Falha ao excluir usuário: { $error }
 err-failed-update-user = Falha ao atualizar usuário: { $error }
 err-failed-update-topic = Falha ao atualizar tópico: { $error }
+err-failed-lock-topic = Falha ao bloquear tópico: { $error }
+err-failed-set-language = Falha ao definir o idioma do canal: { $error }
+err-failed-update-entry = Falha ao atualizar o canal: { $error }
 err-message-too-long-details = { $error } ({ $length } caracteres, máx { $max })
 
 # Network connection errors (with parameters)
@@ -546,6 +901,14 @@ err-tls-handshake-failed = Falha no handshake TLS: { $error }
 err-failed-send-handshake = Falha ao enviar handshake: { $error }
 err-failed-read-handshake = Falha ao ler resposta do handshake: { $error }
 err-handshake-failed = Falha no handshake: { $error }
+err-proof-of-work-too-hard = O servidor pediu prova de trabalho demais (dificuldade { $difficulty })
+err-sso-not-offered = Este servidor não oferece login único
+err-sso-failed = Falha no login único: { $error }
+err-sso-browser = Não foi possível abrir o navegador: { $error }
+err-sso-timeout = O login único expirou aguardando o navegador
+err-sso-state-mismatch = A resposta do login único não corresponde à solicitação
+sso-browser-done = Login realizado. Você pode fechar esta janela e voltar ao Nexus.
+sso-browser-failed = Falha no login. Você pode fechar esta janela e voltar ao Nexus.
 err-failed-parse-handshake = Falha ao analisar resposta do handshake: { $error }
 err-failed-send-login = Falha ao enviar login: { $error }
 err-failed-read-login = Falha ao ler resposta de login: { $error }
@@ -553,6 +916,7 @@ err-failed-parse-login = Falha ao analisar resposta de login: { $error }
 err-failed-create-server-name = Falha ao criar nome do servidor: { $error }
 err-failed-create-config-dir = Falha ao criar diretório de configuração: { $error }
 err-failed-serialize-config = Falha ao serializar configuração: { $error }
+err-config-locked = As configurações estão criptografadas e bloqueadas; as alterações não podem ser salvas até serem desbloqueadas
 err-failed-write-config = Falha ao escrever arquivo de configuração: { $error }
 err-failed-read-config-metadata = Falha ao ler metadados do arquivo de configuração: { $error }
 err-failed-set-config-permissions = Falha ao definir permissões do arquivo de configuração: { $error }
@@ -562,6 +926,10 @@ err-failed-set-config-permissions = Falha ao definir permissões do arquivo de c
 # =============================================================================
 
 fingerprint-warning = Isso pode indicar um problema de segurança (ataque MITM) ou que o certificado do servidor foi regenerado. Aceite apenas se você confiar no administrador do servidor.
+fingerprint-new-server-prompt = Esta é a primeira conexão com este servidor. Só confie no certificado se a impressão digital abaixo corresponder à publicada pelo administrador do servidor.
+password-vault-create-description = Nenhum chaveiro do sistema está disponível. Escolha uma frase-senha mestra para criptografar as senhas salvas dos seus favoritos, ou pule para mantê-las no arquivo de configuração.
+password-vault-unlock-description = Digite sua frase-senha mestra para desbloquear as senhas salvas dos seus favoritos. Se você pular, os favoritos conectam sem senha até o Nexus ser reiniciado.
+config-unlock-description = Suas configurações, favoritos e histórico de chat estão criptografados. Digite sua frase-senha mestra para desbloqueá-los. Se você pular, o Nexus inicia com as configurações padrão e nada é salvo até reiniciá-lo.
 
 # =============================================================================
 # User Info Display
@@ -578,10 +946,12 @@ user-info-account = Conta: { $username }
 user-info-connected = Conectado:
 user-info-connected-value = há { $duration }
 user-info-connected-value-sessions = há { $duration } ({ $count } sessões)
+user-info-idle = Ocioso:
 user-info-features = Recursos:
 user-info-features-value = { $features }
 user-info-features-none = Nenhum
 user-info-locale = Idioma:
+user-info-client = Cliente:
 user-info-address = Endereço:
 user-info-addresses = Endereços:
 user-info-created = Criado:
@@ -590,6 +960,12 @@ user-info-unknown = Desconhecido
 user-info-loading = Carregando informações do usuário…
 user-info-status = Status:
 user-info-channels = Canais:
+user-info-rank = Nível:
+user-info-logins = Logins:
+user-info-messages = Mensagens:
+user-info-uploads = Envios:
+user-info-downloaded = Baixado:
+user-info-last-login = Último login:
 
 # =============================================================================
 # Time Duration
@@ -625,26 +1001,51 @@ err-me-no-target = Use /{ $join } para entrar em um canal ou /{ $msg } para envi
 cmd-help-escape-hint = Dica: Use // para enviar uma mensagem que começa com /
 cmd-message-desc = Enviar uma mensagem a um usuário
 cmd-message-usage = Uso: /{ $command } <usuário> <mensagem>
+cmd-group-desc = Inicia uma conversa em grupo com vários usuários
+cmd-group-usage = Uso: /{ $command } <usuário> [<usuário>...]
+cmd-invite-desc = Adiciona um usuário à conversa em grupo atual
+cmd-invite-usage = Uso: /{ $command } <usuário>
 cmd-ping-desc = Medir latência para o servidor
 cmd-ping-usage = Uso: /{ $command }
+cmd-radio-desc = Transmitir áudio para os ouvintes de voz do canal
+cmd-radio-usage = Uso: /{ $command }
+err-radio-no-channel = Este comando só funciona em um canal
+err-radio-in-voice = Saia da voz antes de iniciar uma transmissão
 ping-result = Resposta: { $ms }ms
 cmd-userinfo-desc = Mostrar informações sobre um usuário
 cmd-userinfo-usage = Uso: /{ $command } <usuário>
 cmd-kick-desc = Expulsar um usuário do servidor
 cmd-kick-usage = Uso: /{ $command } <usuário> [motivo]
+cmd-top-desc = Mostrar os usuários mais ativos
+cmd-top-usage = Uso: /{ $command } [logins|messages|uploads|downloaded]
 cmd-topic-desc = Ver ou gerenciar o tópico do chat
-cmd-topic-usage = Uso: /{ $command } [definir|limpar] [tópico]
+cmd-topic-usage = Uso: /{ $command } [definir|limpar|bloquear|desbloquear|historico] [tópico]
 cmd-topic-arg-set = definir
 cmd-topic-arg-clear = limpar
+cmd-topic-arg-lock = bloquear
+cmd-topic-arg-unlock = desbloquear
+cmd-topic-arg-history = historico
 cmd-topic-set-usage = Uso: /{ $command } definir <tópico>
 cmd-topic-none = Nenhum tópico definido
 cmd-broadcast-desc = Enviar uma mensagem para todos os usuários
 cmd-broadcast-usage = Uso: /{ $command } <mensagem>
 cmd-clear-desc = Limpar histórico de chat da aba atual
 cmd-clear-usage = Uso: /{ $command }
+cmd-export-desc = Salva a aba atual como arquivo HTML
+cmd-export-usage = Uso: /{ $command }
+cmd-clip-desc = Listar, reproduzir, adicionar ou excluir clipes da mesa de sons
+cmd-clip-usage = Uso: /{ $command } [lista|tocar <nome>|adicionar <nome>|excluir <nome>|<nome>]
+cmd-clip-arg-list = lista
+cmd-clip-arg-play = tocar
+cmd-clip-arg-add = adicionar
+cmd-clip-arg-delete = excluir
 cmd-focus-desc = Focar no chat do servidor ou janela de mensagens de um usuário
 cmd-focus-usage = Uso: /{ $command } [usuário]
 cmd-focus-not-found = Usuário não encontrado: { $name }
+cmd-friend-desc = Listar, adicionar ou remover amigos
+cmd-friend-usage = Uso: /{ $command } [list] | add <usuário> | remove <usuário>
+cmd-friend-list-header = Amigos:
+cmd-friend-list-empty = Nenhum amigo ainda. Adicione um com /{ $command } add <usuário>
 cmd-list-desc = Mostrar usuários conectados/todos
 cmd-list-arg-all = todos
 cmd-list-usage = Uso: /{ $command } [todos]
@@ -660,6 +1061,13 @@ cmd-list-all-output = Usuários: { $users } ({ $count } { $count ->
 })
 cmd-help-usage = Uso: /{ $command } [comando]
 cmd-topic-permission-denied = Você não tem permissão para editar o tópico
+cmd-topic-lock-permission-denied = Você não tem permissão para bloquear o tópico
+msg-topic-already-locked = O tópico já está bloqueado
+msg-topic-already-unlocked = O tópico já está desbloqueado
+msg-topic-history-header = Histórico do tópico de { $channel }:
+msg-topic-history-set = { $time } { $username }: { $topic }
+msg-topic-history-cleared = { $time } { $username } limpou o tópico
+msg-topic-history-empty = Nenhuma alteração de tópico registrada
 cmd-window-desc = Gerenciar abas de chat
 cmd-window-usage = Uso: /{ $command } [próximo|anterior|fechar [usuário]]
 cmd-window-arg-next = próximo
@@ -687,15 +1095,55 @@ cmd-unban-desc = Remover um banimento de IP
 cmd-unban-usage = Uso: /{ $command } <alvo>
 cmd-bans-desc = Listar banimentos ativos
 cmd-bans-usage = Uso: /{ $command }
+cmd-register-desc = Registrar uma conta a partir de uma sessão de convidado
+cmd-register-usage = Uso: /{ $command } <usuário> <senha>
 cmd-reindex-desc = Disparar reconstrução do índice de arquivos
 cmd-reindex-usage = Uso: /{ $command }
+cmd-cleanup-desc = Procurar restos de transferências interrompidas na área de arquivos
+cmd-cleanup-usage = Uso: /{ $command } [delete]
+cmd-filecheck-desc = Verificar a área de arquivos em relação ao índice de pesquisa e aos metadados dos arquivos
+cmd-filecheck-usage = Uso: /{ $command } [repair]
+cmd-screenshot-desc = Compartilhar uma captura de tela anotada na aba atual
+cmd-screenshot-usage = Uso: /{ $command }
+err-screenshot-no-target = Capturas só podem ser compartilhadas em um canal ou aba de mensagem de usuário
+err-screenshot-no-folder = Nenhuma pasta de capturas definida — escolha "Compartilhar capturas aqui" em uma pasta de envio em Arquivos
+err-screenshot-capture = Falha na captura de tela: { $error }
+err-screenshot-save = Não foi possível salvar a captura: { $error }
+err-screenshot-upload = Falha ao enviar a captura: { $error }
+err-export-empty = Não há mensagens para exportar nesta aba
+err-chat-export = Não foi possível exportar o chat: { $error }
+screenshot-title = Compartilhar captura
+screenshot-tool-crop = Recortar
+screenshot-tool-arrow = Seta
+screenshot-hint-crop = Arraste um retângulo em volta da parte a manter
+screenshot-hint-arrow = Arraste da base até a ponta da seta
 msg-reindex-triggered = Reconstrução do índice de arquivos disparada
+msg-cleanup-nothing = Nenhum resto de transferências interrompidas encontrado
+msg-cleanup-found = Restos de transferências interrompidas encontrados:
+msg-cleanup-deleted = Restos de transferências interrompidas excluídos:
+msg-cleanup-parts = Arquivos .part órfãos: { $count } ({ $size })
+msg-cleanup-temp = Arquivos temporários restantes: { $count } ({ $size })
+msg-cleanup-journal = Entradas do registro de envios sem arquivo: { $count }
+msg-cleanup-index = Entradas do índice de busca sem arquivo: { $count }
+msg-cleanup-hint = Verifique os arquivos acima e depois execute /cleanup delete para removê-los
+msg-filecheck-issue = { $kind }: { $path }
+msg-filecheck-issue-repaired = { $kind }: { $path } (reparado)
+msg-filecheck-clean = A verificação da área de arquivos não encontrou problemas em { $entries } entradas
+msg-filecheck-done = A verificação da área de arquivos encontrou { $issues } problemas em { $entries } entradas, { $repaired } reparados
+msg-filecheck-index-skipped = O índice de pesquisa não foi comparado porque ainda não foi criado ou há uma reconstrução pendente
+msg-filecheck-hint = Execute /filecheck repair para reconstruir o índice e remover os metadados de caminhos ausentes
+filecheck-not-indexed = Fora do índice de pesquisa
+filecheck-indexed-but-missing = No índice de pesquisa, mas ausente no disco
+filecheck-index-outdated = Desatualizado no índice de pesquisa
+filecheck-orphaned-activity = Histórico de um caminho ausente
+filecheck-orphaned-reviews = Avaliações ou comentários de um caminho ausente
+filecheck-orphaned-upload-rule = Regra de envio de uma pasta ausente
 cmd-join-desc = Entrar ou criar um canal
 cmd-join-usage = Uso: /{ $command } #canal
 cmd-leave-desc = Sair de um canal
 cmd-leave-usage = Uso: /{ $command } [#canal]
 cmd-channels-desc = Listar canais disponíveis
-cmd-channels-usage = Uso: /{ $command }
+cmd-channels-usage = Uso: /{ $command } [idioma]
 cmd-secret-desc = Alternar modo secreto no canal atual
 cmd-secret-usage = Uso: /{ $command } [on|off]
 cmd-secret-arg-on = on
@@ -708,12 +1156,40 @@ msg-secret-enabled = O canal agora é secreto
 msg-secret-disabled = O canal agora é público
 msg-secret-status-on = Este canal é secreto
 msg-secret-status-off = Este canal é público
+cmd-voicelimit-desc = Ver ou definir o limite de participantes de voz do canal atual
+cmd-voicelimit-usage = Uso: /{ $command } [<quantidade>|off]
+cmd-vt-desc = Enviar uma mensagem de texto para sua sessão de voz
+cmd-vt-usage = Uso: /{ $command } <mensagem>
+cmd-voicelimit-arg-off = off
+err-voicelimit-no-channel = Este comando só funciona em um canal
+msg-voice-limit-status = Este canal permite no máximo { $limit } usuários em voz
+msg-voice-limit-status-none = Este canal não tem limite de voz
+msg-voice-clip-none = Nenhum clipe na mesa de sons
+msg-voice-clip-list = Clipes da mesa de sons ({ $count }):
+msg-voice-clip-entry = { $name } ({ $seconds } s, por { $nickname })
+msg-voice-clip-uploaded = Clipe adicionado à mesa de sons
+msg-voice-clip-deleted = Clipe excluído da mesa de sons
 cmd-trust-desc = Confiar em um IP, faixa CIDR ou apelido para ignorar banimentos
 cmd-trust-usage = Uso: /{ $command } <alvo> [duração] [motivo]
 cmd-untrust-desc = Remover uma entrada de IP confiável
 cmd-untrust-usage = Uso: /{ $command } <alvo>
 cmd-trusted-desc = Listar IPs confiáveis
 cmd-trusted-usage = Uso: /{ $command }
+cmd-ldap-desc = Listar, definir ou remover mapeamentos de grupos do diretório
+cmd-ldap-usage = Uso: /{ $command } [list|set <permissões|admin|none> <DN do grupo>|remove <DN do grupo>]
+cmd-nickname-desc = Reservar ou liberar apelidos para sua conta
+cmd-nickname-usage = Uso: /{ $command } [register <apelido> [usuário]|release <apelido>]
+cmd-history-desc = Mostra ou define por quanto tempo o servidor guarda suas mensagens privadas, ou carrega as mais antigas
+cmd-history-usage = Uso: /{ $command } [<dias>|more]
+cmd-alias-desc = Criar atalhos para comandos e mensagens
+cmd-alias-usage = Uso: /{ $command } [list|set <nome> <texto>|remove <nome>]
+cmd-alias-list-header = Seus aliases:
+cmd-alias-list-empty = Nenhum alias ainda. Crie um com /{ $command } set <nome> <texto>
+cmd-alias-set = /{ $name } agora executa: { $text }
+cmd-alias-removed = Alias /{ $name } removido
+cmd-alias-not-found = Nenhum alias chamado /{ $name }
+cmd-alias-invalid-name = Nomes de alias só podem usar letras, números, - e _ (até { $max } caracteres)
+cmd-alias-builtin = /{ $name } é um comando embutido e não pode ser usado como alias
 
 # =============================================================================
 # About Panel
@@ -726,9 +1202,25 @@ err-server-image-invalid-format = Formato de imagem do servidor inválido (deve
 err-server-image-unsupported-type = Tipo de imagem do servidor não suportado (apenas PNG, WebP, JPEG ou SVG)
 err-server-image-decode-failed = Falha ao decodificar a imagem. O arquivo pode estar corrompido.
 err-failed-read-image = Falha ao ler a imagem: { $error }
-err-news-empty = A publicação deve ter texto ou uma imagem
+err-news-empty = A publicação deve ter texto ou um anexo
 err-news-body-too-long = O conteúdo é muito longo ({ $length } caracteres, máximo { $max })
 err-news-body-invalid-characters = O conteúdo contém caracteres de controle inválidos
+err-news-too-many-attachments = Uma publicação pode ter no máximo { $max } anexos
+err-news-caption-too-long = A legenda é muito longa (máx. { $max } caracteres)
+err-news-caption-invalid-characters = A legenda deve ser uma única linha sem caracteres de controle
+err-news-file-path-invalid = Caminho de arquivo inválido
+err-news-search-date = Data inválida: { $date } (use AAAA-MM-DD)
+err-file-comment-empty = O comentário não pode estar vazio
+err-file-comment-too-long = O comentário é muito longo ({ $length } caracteres, máximo { $max })
+err-file-comment-invalid-characters = O comentário contém caracteres de controle inválidos
+err-upload-rule-invalid-extension = Tipo de arquivo inválido "{ $extension }" (use letras e dígitos, como mp3)
+err-auto-download-pattern-required = Informe pelo menos um padrão de nome de arquivo
+err-auto-download-pattern-invalid = Padrão inválido "{ $pattern }" (os padrões se aplicam a nomes de arquivo e não podem conter barras)
+err-upload-rule-too-many-extensions = Tipos de arquivo demais (máx. { $max })
+err-upload-rule-invalid-size = Tamanho inválido "{ $value }" (informe um número de megabytes)
+err-ldap-group-empty = O DN do grupo não pode estar vazio
+err-ldap-group-too-long = O DN do grupo é muito longo (máximo { $max } caracteres)
+err-ldap-group-invalid-characters = O DN do grupo não pode ter espaços nas extremidades nem caracteres de controle
 err-news-image-decode-failed = Falha ao decodificar a imagem. O arquivo pode estar corrompido.
 
 # Proxy errors
@@ -739,9 +1231,31 @@ err-proxy-connection-timeout = Tempo limite da conexão com o proxy após { $sec
 }
 err-proxy-address-required = O endereço do proxy é obrigatório quando o proxy está ativado
 err-proxy-port-invalid = A porta do proxy deve estar entre 1 e 65535
-err-news-image-too-large = A imagem é muito grande (máximo 512KB)
+err-news-image-too-large = A imagem é muito grande (máximo { $max_kb }KB)
 err-news-image-unsupported-type = Tipo de imagem não suportado (apenas PNG, WebP, JPEG ou SVG)
 err-topic-no-channel = Este comando só funciona em um canal
+err-entry-no-channel = Este comando só funciona em um canal
+cmd-welcome-desc = Ver ou definir a mensagem mostrada a quem entra no canal
+cmd-welcome-usage = Uso: /{ $command } [definir <mensagem>|limpar]
+cmd-welcome-arg-set = definir
+cmd-welcome-arg-clear = limpar
+cmd-welcome-none = Nenhuma mensagem de boas-vindas definida
+msg-entry-message-display = Mensagem de boas-vindas: { $message }
+msg-entry-message-updated = Mensagem de boas-vindas atualizada
+msg-entry-message-cleared = Mensagem de boas-vindas removida
+cmd-rules-desc = Ver ou definir as regras do canal
+cmd-rules-usage = Uso: /{ $command } [definir <regras>|limpar]
+cmd-rules-arg-set = definir
+cmd-rules-arg-clear = limpar
+cmd-rules-none = Nenhuma regra definida
+msg-rules-header = Regras de { $channel }:
+cmd-language-desc = Ver ou definir a etiqueta de idioma do canal
+cmd-language-usage = Uso: /{ $command } [definir <idioma>|limpar]
+cmd-language-arg-set = definir
+cmd-language-arg-clear = limpar
+msg-language-status = Idioma do canal: { $language }
+msg-language-none = Nenhum idioma definido
+err-language-invalid = Etiqueta de idioma inválida: { $language } (ex.: pt ou pt-BR)
 err-join-channel = Falha ao entrar no canal: { $error }
 err-leave-channel = Falha ao sair do canal: { $error }
 err-list-channels = Falha ao listar canais: { $error }
@@ -751,6 +1265,8 @@ err-channel-too-long = O nome do canal é muito longo (máx. { $max } caracteres
 err-channel-missing-prefix = O nome do canal deve começar com #
 err-channel-invalid-characters = O nome do canal contém caracteres inválidos
 err-leave-no-channel = Você deve estar em um canal para usar /{ $command } sem argumentos
+err-invite-no-group = Use /{ $command } em uma aba de conversa em grupo
+err-group-too-many-members = Uma conversa em grupo pode ter no máximo { $max } membros
 err-not-in-channel = Você não está no canal { $channel }
 err-leave-already-pending = Já aguardando resposta do servidor
 err-uri-connection-failed = Falha ao conectar a { $host }: { $error }
@@ -764,6 +1280,30 @@ label-port = Porta:
 # Transfer statuses
 transfer-status-queued = Na fila
 transfer-status-connecting = Conectando…
+transfer-status-server-queued = Aguardando vaga no servidor (#{ $position })
+transfer-collision-pending = O arquivo existe: { $path }
+collision-action-renamed = { $count } { $count ->
+    [one] renomeado
+   *[other] renomeados
+}
+collision-action-overwritten = { $count } { $count ->
+    [one] sobrescrito
+   *[other] sobrescritos
+}
+collision-action-skipped = { $count } { $count ->
+    [one] pulado
+   *[other] pulados
+}
+transfer-bar-count = { $count } { $count ->
+    [one] transferência
+   *[other] transferências
+}
+status-bar-users = { $count } { $count ->
+    [one] usuário
+   *[other] usuários
+}
+status-bar-latency = { $ms } ms
+status-bar-server-time = Hora do servidor { $time } ({ $zone })
 transfer-status-transferring = Transferindo
 transfer-status-paused = Pausado
 transfer-status-completed = Concluído
@@ -825,6 +1365,7 @@ event-broadcast = Transmissão
 event-chat-message = Mensagem de chat
 event-chat-mention = Menção no chat
 event-connection-lost = Conexão perdida
+event-friend-online = Amigo online
 event-news-post = Postagem de notícias
 event-permissions-changed = Permissões alteradas
 event-transfer-complete = Transferência concluída
@@ -859,6 +1400,17 @@ settings-sound-test = Testar
 settings-notification-test = Testar
 event-settings-show-toast = Mostrar Toast
 settings-toast-test = Testar
+settings-do-not-disturb = Não perturbe
+settings-quiet-hours = Horário silencioso
+settings-do-not-disturb-hint = Silencia notificações, avisos e sons. Os indicadores de não lidas continuam contando, e os outros veem 🔕 ao lado do seu nome.
+settings-notification-rules = Regras de notificação
+settings-notification-rules-server = Servidor:
+settings-notification-rules-channels = Exceções por canal
+settings-notification-rules-keywords = Palavras-chave monitoradas
+settings-notification-rules-no-bookmarks = Adicione um servidor aos favoritos para definir suas regras de notificação.
+channel-notification-all = Todas as mensagens
+channel-notification-mentions = Apenas menções
+channel-notification-mute = Silenciar
 
 # Sound names
 sound-none = Nenhum
@@ -877,6 +1429,8 @@ notification-chat-message = Nova mensagem de chat
 notification-chat-message-from = Chat de {$username}
 notification-connection-lost = Conexão perdida
 notification-connection-lost-from = Desconectado de {$server}
+notification-friend-online = Um amigo ficou online
+notification-friend-online-name = {$username} está online
 notification-download-complete = Download concluído
 notification-download-failed = Download falhou
 notification-news-post = Nova postagem de notícias
@@ -930,6 +1484,18 @@ ban-duration-7d = 7 dias
 ban-duration-30d = 30 dias
 ban-duration-permanent = Permanente
 
+# Broadcast target and delay options
+broadcast-target-label = Enviar para:
+broadcast-target-all = Todos
+broadcast-target-channel = Membros de um canal
+broadcast-target-permission = Usuários com uma permissão
+broadcast-delay-label = Enviar:
+broadcast-delay-now = Agora
+broadcast-delay-5m = Em 5 minutos
+broadcast-delay-15m = Em 15 minutos
+broadcast-delay-1h = Em 1 hora
+broadcast-delay-1d = Em 1 dia
+
 # =============================================================================
 # Ban System
 # =============================================================================
@@ -979,12 +1545,32 @@ msg-untrusted-success = Entrada de confiança removida com sucesso
 # Trust list messages
 msg-trust-list-header = IPs confiáveis:
 msg-trust-list-empty = Nenhum IP confiável
+msg-ldap-group-list-header = Grupos do diretório:
+msg-ldap-group-list-empty = Nenhum grupo do diretório mapeado
+msg-ldap-group-admin = administrador
+msg-ldap-group-no-permissions = sem permissões
+msg-ldap-group-set = Grupo do diretório { $group } mapeado
+msg-ldap-group-removed = Grupo do diretório { $group } removido
+msg-guest-upgraded = Conta { $username } criada. Entre com ela da próxima vez para usá-la.
+msg-nickname-registered = O apelido { $nickname } agora está reservado para { $username }.
+msg-nickname-released = Apelido { $nickname } liberado.
+msg-history-days = O servidor guarda suas mensagens privadas por { $days } dias.
+msg-history-off = O servidor não guarda suas mensagens privadas. Use /history <dias> para ativar.
+msg-history-no-older = Não há mensagens mais antigas com { $nickname }.
+err-history-days-too-long = O histórico de mensagens pode ser mantido por no máximo { $max } dias
+err-history-more-tab = Use /history more em uma aba de mensagens privadas
 msg-trust-permanent = permanente
 msg-trust-expired = expirado
 msg-trust-remaining = { $time } restante
 msg-trust-remaining-days = { $days }d { $hours }h
 msg-trust-remaining-hours = { $hours }h { $minutes }m
 msg-trust-remaining-minutes = { $minutes }m
+msg-leaderboard-header = Usuários mais ativos por { $stat }:
+msg-leaderboard-empty = Nenhuma atividade registrada ainda
+leaderboard-stat-logins = logins
+leaderboard-stat-messages = mensagens
+leaderboard-stat-uploads = envios
+leaderboard-stat-downloaded = dados baixados
 
 # =============================================================================
 # Voice Chat
@@ -993,6 +1579,16 @@ msg-trust-remaining-minutes = { $minutes }m
 # Voice error messages
 err-voice-join = Falha ao entrar no chat de voz: { $error }
 err-voice-leave = Falha ao sair do chat de voz: { $error }
+err-voice-limit = Falha ao definir limite de voz: { $error }
+err-voice-clip = Mesa de sons: { $error }
+err-voice-text = Texto de voz: { $error }
+err-voice-clip-name-empty = O nome do clipe não pode estar vazio
+err-voice-clip-name-too-long = O nome do clipe é muito longo (máx. { $max } caracteres)
+err-voice-clip-name-invalid = Nomes de clipe só podem conter letras, dígitos, - e _
+err-voice-clip-too-large = O clipe é muito grande (máx. 256 KB)
+err-voice-clip-unsupported-type = Formato de clipe não suportado (somente Ogg Vorbis)
+msg-voice-queued = A voz está cheia - você é o número { $position } na fila
+msg-voice-queue-cancelled = Você foi removido da fila de voz
 err-voice-no-token = Entrada no chat de voz bem-sucedida, mas nenhum token recebido
 err-voice-already-active = Você já está em uma sessão de voz em outro servidor
 err-voice-no-permission = Você não tem permissão para entrar no chat de voz
@@ -1018,7 +1614,12 @@ msg-voice-joined-empty = Entrou no chat de voz em { $target }
 msg-voice-left = Saiu do chat de voz em { $target }
 msg-voice-user-joined = { $nickname } entrou no chat de voz
 msg-voice-user-left = { $nickname } saiu do chat de voz
+msg-voice-broadcast-started = { $nickname } está transmitindo na voz - entre para ouvir
+msg-voice-broadcast-ended = A transmissão de voz terminou
+msg-voice-clip-played = { $nickname } tocou { $name }
+msg-voice-text = [voz] { $nickname }: { $message }
 msg-voice-you-left = Você saiu do chat de voz
+msg-voice-tcp-relay = A conexão UDP de voz falhou ({ $error }) - a voz será retransmitida pela conexão com o servidor
 
 # Voice UI
 voice-join-tooltip = Entrar no chat de voz
@@ -1026,6 +1627,8 @@ voice-leave-tooltip = Sair do chat de voz
 voice-mute-all-tooltip = Silenciar tudo
 voice-unmute-all-tooltip = Ativar som
 voice-bar-participants = ({ $count })
+voice-bar-queued = (na fila: { $position })
+voice-bar-listeners = ({ $count } ouvindo)
 voice-indicator-tooltip = No chat de voz
 tooltip-mute = Silenciar
 tooltip-unmute = Ativar som
@@ -1036,6 +1639,12 @@ voice-quality-medium = Média (32 kbps)
 voice-quality-high = Alta (64 kbps)
 voice-quality-very-high = Muito alta (96 kbps)
 
+# Voice connection quality
+voice-connection-good = Boa
+voice-connection-fair = Regular
+voice-connection-poor = Ruim
+voice-connection-tooltip = Conexão: { $quality } ({ $loss }% de perda, jitter de { $jitter } ms)
+
 # PTT modes
 ptt-mode-hold = Manter pressionado para falar
 ptt-mode-toggle = Alternar
@@ -1050,6 +1659,8 @@ ptt-delay-500ms = 500ms
 audio-output-device = Dispositivo de saída
 audio-input-device = Dispositivo de entrada
 audio-refresh-devices = Atualizar dispositivos
+audio-clip-volume = Volume da mesa de sons
+audio-voice-text-tts = Ler mensagens de texto de voz em voz alta
 audio-voice-quality = Qualidade de voz
 audio-ptt-key = Tecla Push-to-Talk
 audio-ptt-mode = Modo PTT
@@ -1076,6 +1687,44 @@ mic-boost-6db = +6 dB
 mic-boost-12db = +12 dB
 mic-boost-18db = +18 dB
 
+# =============================================================================
+# Global Hotkeys
+# =============================================================================
+
+# Settings
+settings-toggle-window-key = Tecla mostrar/ocultar janela
+button-clear-hotkey = Limpar
+
+# Shortcut descriptions (shown by the desktop on Wayland)
+hotkey-push-to-talk = Pressione para falar
+hotkey-toggle-window = Mostrar ou ocultar o Nexus
+
+# Errors
+err-window-hotkey-failed = Falha ao registrar tecla de mostrar/ocultar janela: { $error }
+err-global-shortcuts-unavailable = Portal de atalhos globais indisponível, as teclas só funcionam com o Nexus em foco: { $error }
+
+# =============================================================================
+# Auto Away
+# =============================================================================
+
+settings-auto-away = Ficar ausente automaticamente quando inativo
+settings-auto-away-minutes = Minutos de inatividade
+
+# =============================================================================
+# Connection Health
+# =============================================================================
+
+# Server list indicator
+connection-health-good = Boa
+connection-health-fair = Regular
+connection-health-poor = Ruim
+connection-health-tooltip = Conexão: { $quality }
+connection-health-latency = Latência: { $ms } ms
+connection-health-jitter = Jitter: { $ms } ms
+connection-health-last-drop = Último ping perdido: { $time }
+connection-health-no-drops = Nenhum ping perdido
+connection-health-measuring = Medindo latência…
+
 # =============================================================================
 # System Tray (Windows/Linux only)
 # =============================================================================
@@ -1104,14 +1753,33 @@ tray-tooltip-unread = Nexus BBS - { $count ->
    *[other] { $count } conversas não lidas
 }
 
+# =============================================================================
+# Notification Actions (macOS/Windows only)
+# =============================================================================
+
+# Actions
+notification-reply = Responder
+notification-reply-send = Enviar
+notification-reply-placeholder = Responder…
+notification-mark-read = Marcar como lida
+
 # =============================================================================
 # Toast Notifications
 # =============================================================================
 
 toast-link-copied = Link copiado para a área de transferência
 toast-copied = Copiado para a área de transferência
+toast-checksum-copied = SHA-256 copiado para a área de transferência
 toast-download-started = Baixando { $filename }
 toast-download-queued = Download na fila: { $filename }
+toast-downloads-started = Baixando { $count } { $count ->
+    [one] arquivo
+   *[other] arquivos
+}
+toast-downloads-queued = { $count } { $count ->
+    [one] download
+   *[other] downloads
+} na fila
 toast-upload-started = Enviando { $filename }
 toast-upload-queued = Upload na fila: { $filename }
 toast-uploads-started = Enviando { $count } { $count ->
@@ -1122,3 +1790,28 @@ toast-uploads-queued = { $count } { $count ->
     [one] upload
    *[other] uploads
 } na fila
+toast-upload-nothing-new = Nada para enviar — o servidor já tem estes arquivos
+toast-upload-watch-started = Monitorando { $folder } — novos arquivos serão enviados automaticamente
+toast-upload-watch-stopped = Parou de monitorar { $folder }
+toast-auto-download-started = Baixando automaticamente { $pattern } de { $folder }
+toast-auto-download-stopped = Download automático de { $folder } interrompido
+toast-screenshot-folder-set = As capturas serão enviadas para esta pasta
+toast-chat-exported = Chat exportado
+
+# =============================================================================
+# First-run Tips
+# =============================================================================
+
+tip-welcome-title = Bem-vindo ao Nexus
+tip-welcome = Digite abaixo o endereço de um servidor e conecte-se. Adicione um favorito para manter o servidor, com seu login, na lista à esquerda, a um clique de distância na próxima vez.
+tip-fingerprint = Todo servidor tem uma impressão digital de certificado, como uma assinatura. O Nexus a memoriza na primeira conexão e avisa se ela mudar, pois isso pode significar que alguém está se passando pelo servidor. Só aceite uma nova impressão digital se o administrador do servidor disse que ela mudou.
+tip-server-list = Seus favoritos e conexões abertas ficam à esquerda. Clique em um para trocar de servidor ou arraste os favoritos para reorganizá-los.
+tip-toolbar = A barra de ferramentas abre arquivos, notícias, configurações e mais. Passe o mouse sobre um ícone para ver o que ele faz.
+tip-chat = Converse aqui com todos no servidor. Digite / para ver os comandos disponíveis, como /join para entrar em um canal.
+tip-user-list = Os usuários online agora aparecem à direita. Clique em um nome para enviar uma mensagem ou ver as informações.
+tip-counter = Dica { $current } de { $total }
+button-add-bookmark = Adicionar favorito
+button-tip-next = Próxima
+button-tip-done = Concluir
+button-tip-got-it = Entendi
+button-skip-tips = Pular dicas
@@ -11,6 +11,13 @@ button-connect = Ligar
 button-save = Guardar
 button-create = Criar
 button-edit = Editar
+button-check-ports = Verificar portas
+button-statistics = Estatísticas
+button-dashboard = Painel
+button-refresh = Atualizar
+button-doors = Portas
+button-doors-leave = Sair
+button-doors-back = Voltar
 button-update = Atualizar
 button-accept-new-certificate = Aceitar Novo Certificado
 button-close = Fechar
@@ -18,9 +25,23 @@ button-choose-avatar = Escolher Ícone
 button-clear-avatar = Limpar
 button-choose-image = Escolher Imagem
 button-clear-image = Limpar
+button-add-file = Adicionar ficheiro
 button-overwrite = Substituir
 button-change-password = Alterar Palavra-passe
 button-browse = Procurar
+button-undo = Anular
+button-add = Adicionar
+button-remove = Remover
+button-view = Ver
+button-hide = Ocultar
+button-export = Exportar…
+button-trust-certificate = Confiar no certificado
+button-skip = Ignorar
+button-skip-existing = Ignorar existentes
+button-download-anyway = Transferir mesmo assim
+button-unlock = Desbloquear
+button-view-as-user = Ver como Utilizador
+button-post-comment = Publicar
 
 # =============================================================================
 # Titles
@@ -35,19 +56,29 @@ title-user-create = Criar Utilizador
 title-user-edit = Editar Utilizador
 title-update-user = Actualizar Utilizador
 title-user-management = Gerir Utilizadores
+title-permission-audit = Auditoria de Permissões
+title-impersonate = A ver como { $username }
 title-confirm-delete = Confirmar Eliminação
 title-connected = Ligados
 title-settings = Definições
 title-bookmarks = Marcadores
 title-users = Utilizadores
+title-friends = Amigos
+friend-online = online
+friend-offline = offline
 title-edit-server-info = Editar Info do Servidor
 title-fingerprint-mismatch = Impressão Digital do Certificado Não Corresponde!
+title-fingerprint-new-server = Novo certificado do servidor
+title-create-password-vault = Proteger palavras-passe guardadas
+title-unlock-password-vault = Desbloquear palavras-passe guardadas
+title-unlock-config = Desbloquear definições
 title-server-info = Info do Servidor
 title-user-info = Info do Utilizador
 title-about = Sobre
 title-change-password = Alterar Palavra-passe
 title-news = Notícias
 title-transfers = Transferências
+title-transfer-history = Histórico de transferências
 title-news-create = Criar Publicação
 title-news-edit = Editar Publicação
 title-channel-members = Membros
@@ -58,10 +89,14 @@ title-channel-members = Membros
 
 placeholder-username = Nome de utilizador
 placeholder-password = Palavra-passe
+placeholder-master-passphrase = Frase-passe mestra
+placeholder-confirm-master-passphrase = Confirmar frase-passe mestra
 placeholder-port = Porta
 placeholder-server-address = Endereço do Servidor
 placeholder-server-name = Nome do Servidor
+placeholder-folder-name = Nome da pasta
 placeholder-username-optional = Nome de utilizador (opcional)
+placeholder-fallback-addresses-optional = Endereços alternativos (opcional, separados por vírgulas)
 placeholder-password-optional = Palavra-passe (opcional)
 placeholder-password-keep-current = Palavra-passe
 placeholder-current-password = Palavra-passe Actual
@@ -70,10 +105,18 @@ placeholder-confirm-password = Confirmar Palavra-passe
 placeholder-message = Escreva uma mensagem…
 placeholder-no-permission = Sem permissão
 placeholder-broadcast-message = Mensagem de difusão
+placeholder-broadcast-channel = Escolha um canal
+placeholder-broadcast-permission = Escolha uma permissão
 placeholder-server-description = Descrição do servidor
 placeholder-news-body = Conteúdo da notícia
+placeholder-news-caption = Legenda (opcional)
+placeholder-news-file-path = Caminho na área de ficheiros
 placeholder-nickname-optional = Alcunha (opcional)
+placeholder-status-optional = Estado (opcional)
+placeholder-download-folder-optional = Pasta de transferências (opcional)
 placeholder-proxy-address = Endereço do proxy
+placeholder-notification-rules-channel = #canal
+placeholder-notification-rules-keyword = Palavra-chave
 placeholder-proxy-port = 9050
 placeholder-proxy-username = Nome de utilizador (opcional)
 placeholder-proxy-password = Palavra-passe (opcional)
@@ -86,13 +129,20 @@ placeholder-auto-join-channels = #canal1 #canal2 (separados por espaços)
 # =============================================================================
 
 label-auto-connect = Auto-Ligar
+label-server-avatar = Avatar:
 label-add-bookmark = Marcador
+label-lan-servers = Nesta rede
+label-use-sso = Início de sessão único
 label-admin = Administrador
 label-shared-account = Conta Partilhada
 label-enabled = Activo
+label-erase-personal-data = Apagar também os dados pessoais
+label-broadcast-require-ack = Pedir confirmação aos destinatários
 label-permissions = Permissões:
 label-expected-fingerprint = Impressão digital esperada:
 label-received-fingerprint = Impressão digital recebida:
+label-certificate-fingerprint = Impressão digital do certificado:
+label-identity-key = Chave de identidade:
 label-theme = Tema
 label-chat-history-retention = Histórico de chat:
 chat-history-forever = Para sempre
@@ -100,6 +150,10 @@ chat-history-30-days = 30 dias
 chat-history-14-days = 14 dias
 chat-history-7-days = 7 dias
 chat-history-disabled = Desactivado
+collision-policy-rename = Manter ambos (mudar o nome)
+collision-policy-overwrite = Substituir
+collision-policy-skip = Ignorar
+collision-policy-ask = Perguntar
 label-max-scrollback = Histórico máximo:
 label-chat-font-size = Tamanho da fonte:
 label-show-connection-events = Mostrar eventos de ligação/desligação
@@ -107,6 +161,19 @@ label-show-channel-events = Mostrar eventos de entrada/saída
 label-show-timestamps = Mostrar carimbos de data/hora
 label-use-24-hour-time = Usar formato de 24 horas
 label-show-seconds = Mostrar segundos
+label-split-view = Mostrar ficheiros, notícias e monitor de ligações ao lado da conversa
+label-spell-check = Verificar a ortografia na conversa e nas notícias
+label-spell-check-no-dictionary = Não foi encontrado nenhum dicionário para o seu idioma. Coloque os ficheiros .aff e .dic na pasta dictionaries junto a config.json.
+label-send-read-receipts = Enviar confirmações de leitura de mensagens privadas
+label-user-list-grouping = Agrupar lista de utilizadores:
+label-user-list-sort = Ordenar lista de utilizadores:
+user-list-group-none = Nenhum
+user-list-group-admins = Administradores primeiro
+user-list-group-channel = Por canal
+user-list-group-status = Por estado
+user-list-sort-alphabetical = Alfabética
+user-list-sort-idle = Tempo inativo
+user-list-sort-connect = Tempo de ligação
 label-server-name = Nome:
 label-server-description = Descrição:
 label-server-version = Versão:
@@ -140,6 +207,27 @@ tab-files = Ficheiros
 tab-channels = Canais
 label-connections-short = Conexões:
 label-transfers-short = Transferências:
+label-active-transfers-short = Ativas:
+label-queue-order-short = Ordem da fila:
+label-trusted-weight-short = Peso fidedigno:
+label-nat-mapping-short = Mapeamento de portas:
+label-nat-external-ip-short = IP externo:
+label-nat-not-mapped = Não mapeado
+connectivity-check-running = A verificar portas…
+check-service-bbs = BBS
+check-service-transfer = Transferências
+check-service-websocket = WebSocket
+check-service-transfer-websocket = Transferências WebSocket
+check-service-voice = Voz
+check-state-open = Aberta
+check-state-closed = Fechada
+check-state-filtered = Filtrada
+check-state-untested = Não testada
+label-check-port = { $service } ({ $protocol } { $port }):
+label-queue-order-small-first = Ficheiros pequenos primeiro
+label-queue-order-arrival = Ordem de chegada
+label-small-files-first = Colocar ficheiros pequenos primeiro na fila
+label-unlimited = Ilimitado
 label-reindex-short = Reindexar:
 label-persistent-short = Persistentes:
 label-auto-join-short = Auto-entrada:
@@ -156,6 +244,19 @@ label-download-location = Local de Transferência:
 label-queue-transfers = Enfileirar Transferências
 label-download-limit = Limite de Downloads:
 label-upload-limit = Limite de Uploads:
+label-auto-open-folder = Abrir a pasta ao concluir uma transferência
+label-collision-policy = Se o ficheiro existir:
+label-require-fingerprint-approval = Perguntar antes de confiar no certificado de um novo servidor
+label-known-servers = Servidores conhecidos
+known-servers-empty = Ainda não há certificados de servidor guardados
+known-server-first-seen = Visto pela primeira vez em { $date }
+known-server-first-seen-unknown = Visto pela primeira vez: desconhecido
+label-encrypt-config = Encriptar definições, marcadores e histórico de chat com uma frase-passe mestra
+config-encryption-locked-note = As definições estão bloqueadas. Reinicie o Nexus e introduza a frase-passe mestra para alterar isto.
+label-upload-rule-extensions = Tipos de ficheiro permitidos:
+label-upload-rule-max-file-size = Maior ficheiro (MB):
+label-upload-rule-max-total-size = Maior carregamento (MB):
+label-auto-download-pattern = Nomes de ficheiro:
 label-persistent-channels = Canais Persistentes:
 label-auto-join-channels = Canais Auto-Entrada:
 label-channels = Canais
@@ -169,11 +270,14 @@ permission-user_info = Info do Utilizador
 permission-chat_send = Enviar Chat
 permission-chat_receive = Receber Chat
 permission-chat_join = Entrar no Chat
+permission-chat_language = Idioma do chat
 permission-chat_create = Criar Canal
 permission-chat_list = Lista de Canais
 permission-chat_secret = Canal Secreto
 permission-chat_topic = Tópico do Chat
 permission-chat_topic_edit = Editar Tópico do Chat
+permission-chat_topic_lock = Bloquear Tópico do Chat
+permission-chat_entry_edit = Editar Boas-vindas do Chat
 permission-user_broadcast = Difusão de Utilizador
 permission-user_create = Criar Utilizador
 permission-user_delete = Eliminar Utilizador
@@ -184,6 +288,7 @@ permission-news_list = Lista de Notícias
 permission-news_create = Criar Notícia
 permission-news_edit = Editar Notícia
 permission-news_delete = Eliminar Notícia
+permission-file_comment = Comentar ficheiros
 permission-file_copy = Copiar Ficheiro
 permission-file_create_dir = Directório Criar
 permission-file_delete = Eliminar Ficheiro
@@ -203,6 +308,11 @@ permission-trust_create = Criar Confiança
 permission-trust_delete = Remover Confiança
 permission-trust_list = Lista de Confiança
 permission-connection_monitor = Monitor de Ligações
+permission-log_view = Ver registo
+permission-door_play = Jogar portas
+permission-voice_broadcast = Transmissão de Voz
+permission-voice_clip_upload = Carregar Clipes de Voz
+permission-voice_limit = Limite de Voz
 permission-voice_listen = Ouvir Voz
 permission-voice_talk = Falar por Voz
 
@@ -227,19 +337,31 @@ tooltip-files-paste = Colar
 tooltip-files-search = Pesquisar ficheiros
 tooltip-manage-users = Gerir Utilizadores
 tooltip-connection-monitor = Monitor de Ligações
+tooltip-server-log = Registo do servidor
 tooltip-server-info = Info do Servidor
 tooltip-about = Sobre
 tooltip-transfers = Transferências
 tooltip-download-all = Transferir Tudo
 tooltip-upload = Carregar
 file-picker-upload-title = Selecionar ficheiros para carregar
+file-picker-download-to-title = Selecionar pasta de transferências
+folder-picker-upload-watch-title = Selecione a pasta a carregar automaticamente
 drop-to-upload = Largar para carregar
 tooltip-clear-inactive = Limpar Inativos
+tooltip-transfer-history = Histórico de transferências
+tooltip-transfer-history-clear = Limpar histórico
+tooltip-transfer-history-remove = Remover do histórico
 tooltip-transfer-pause = Pausar
 tooltip-transfer-resume = Retomar
 tooltip-transfer-cancel = Cancelar
 tooltip-transfer-remove = Remover
 tooltip-transfer-open-folder = Abrir Pasta
+tooltip-transfer-open-file = Abrir Ficheiro
+tooltip-transfer-reveal = Mostrar no Gestor de Ficheiros
+tooltip-transfer-copy-checksum = Copiar SHA-256
+tooltip-transfer-collision-rename = Manter ambos (mudar o nome)
+tooltip-transfer-collision-overwrite = Substituir ficheiros existentes
+tooltip-transfer-collision-skip = Ignorar ficheiros existentes
 tooltip-transfer-move-up = Mover para Cima
 tooltip-transfer-move-down = Mover para Baixo
 tooltip-transfer-retry = Tentar Novamente
@@ -252,16 +374,34 @@ tooltip-disconnect = Desconectar
 tooltip-edit = Editar
 tooltip-info = Info
 tooltip-message = Mensagem
+tooltip-add-friend = Adicionar amigo
+tooltip-remove-friend = Remover amigo
 tooltip-kick = Expulsar
 tooltip-close = Fechar
+tooltip-retry-send = Tentar enviar novamente
+tooltip-cancel-send = Cancelar envio
+tooltip-message-sent = Enviada
+tooltip-message-delivered = Entregue
+tooltip-message-read = Lida
 tooltip-new-tab = Novo Separador
 tooltip-close-tab = Fechar Separador
 tooltip-add-bookmark = Adicionar Marcador
+tooltip-new-folder = Nova pasta
 tooltip-create-user = Criar Utilizador
+tooltip-permission-audit = Auditar Permissões
 tooltip-create-news = Criar Publicação
+tooltip-news-search = Pesquisar notícias
+tooltip-news-search-clear = Limpar pesquisa
 tooltip-delete = Eliminar
 context-menu-download = Transferir
+context-menu-download-to = Transferir para…
+context-menu-download-select = Transferir seleção…
+context-menu-auto-download = Transferência automática…
 context-menu-upload = Carregar
+context-menu-watch-folder = Monitorizar pasta local…
+context-menu-screenshot-folder = Partilhar capturas aqui
+context-menu-unwatch-folder = Parar de monitorizar pasta
+context-menu-upload-rules = Regras de carregamento…
 context-menu-open = Abrir
 
 # =============================================================================
@@ -269,6 +409,7 @@ context-menu-open = Abrir
 # =============================================================================
 
 panel-connection-monitor = Monitor de Ligações
+panel-server-log = Registo do servidor
 panel-active-connections = Ligações Ativas: { $count }
 tab-connections = Ligações
 tab-transfers = Transferências
@@ -283,9 +424,97 @@ menu-info = Info
 menu-copy = Copiar
 menu-kick = Expulsar
 menu-ban = Banir
+menu-pop-out = Destacar
+menu-mute-tab = Silenciar
+menu-unmute-tab = Reativar som
+menu-connect-all = Ligar a todos
+menu-rename = Renomear
+menu-delete = Eliminar
 connection-monitor-loading = A carregar ligações…
 connection-monitor-no-connections = Sem ligações
 connection-monitor-no-transfers = Sem transferências ativas
+connection-monitor-queued = Em fila
+connection-monitor-weight = Peso de partilha justa: ×{ $weight }
+connection-monitor-listed = Listado em { $zone }
+connection-monitor-queued-count = { $count } em fila
+placeholder-server-log-module = Filtrar por módulo
+server-log-loading = A carregar registo do servidor…
+server-log-empty = Sem linhas de registo
+server-log-level-error = Apenas erros
+server-log-level-warn = Avisos e superiores
+server-log-level-info = Info e superiores
+server-log-level-debug = Tudo (depuração)
+panel-statistics = Estatísticas
+stats-loading = A carregar estatísticas…
+panel-snippet = Snippet
+snippet-loading = A carregar snippet…
+snippet-details = Partilhado por { $author } · expira a { $expires }
+stats-range-days = Últimos { $days } dias
+stats-chart-max = Dia mais alto: { $value }
+stats-metric-logins = Inícios de sessão
+stats-metric-messages = Mensagens
+stats-metric-uploads = Carregamentos
+stats-metric-upload-bytes = Dados carregados
+stats-metric-downloads = Transferências
+stats-metric-download-bytes = Dados transferidos
+stats-metric-peak-sessions = Pico de utilizadores online
+panel-admin-dashboard = Painel de administração
+dashboard-loading = A carregar painel…
+dashboard-connections = Ligações
+dashboard-sessions = Utilizadores online
+dashboard-connection-count = Ligações
+dashboard-transfer-connections = Ligações de transferência
+dashboard-transfers = Transferências
+dashboard-active-transfers = Em curso
+dashboard-queued-transfers = Em fila
+dashboard-today = Hoje
+dashboard-disk = Área de ficheiros
+dashboard-disk-free = Espaço livre
+dashboard-disk-free-of = { $free } livres de { $total }
+dashboard-disk-unknown = Desconhecido
+dashboard-recent-audit = Eventos de auditoria recentes
+dashboard-audit-empty = Ainda não há eventos de auditoria
+dashboard-updated = Atualizado às { $time }
+panel-doors = Portas
+doors-loading = A carregar portas…
+doors-empty = Nenhuma porta neste servidor
+doors-starting = A iniciar…
+doors-playing-hint = As teclas vão para a porta. Use Sair para terminar.
+doors-exited = A porta terminou
+doors-exited-code = A porta terminou com o código { $code }
+
+# =============================================================================
+# Quick Switcher
+# =============================================================================
+
+quick-switcher-placeholder = Ir para um canal, mensagem, marcador ou painel...
+user-list-filter-placeholder = Filtrar utilizadores...
+user-list-section-admins = Administradores
+user-list-section-users = Utilizadores
+user-list-section-no-channel = Outros
+user-list-section-online = Online
+user-list-section-away = Ausente
+user-list-section-dnd = Não incomodar
+user-list-idle = Inativo há { $duration }
+quick-switcher-no-matches = Sem resultados
+quick-switcher-bookmark = Marcador
+
+# =============================================================================
+# Chat Input
+# =============================================================================
+
+chat-paste-prompt = Colar { $count } linhas?
+chat-paste-too-long-prompt = Demasiado longo para uma mensagem (máx. { $max }). Enviar na mesma?
+button-send-as-messages = Enviar como { $count } mensagens
+button-send-as-one-message = Enviar como uma mensagem
+button-send-in-parts = Enviar em { $count } partes
+button-share-as-snippet = Partilhar como snippet
+button-expand-code = Mostrar todas as { $count } linhas
+button-collapse-code = Recolher
+chat-broadcast-ack-prompt = { $username } pede-lhe que confirme a difusão
+button-acknowledge = Confirmar
+button-dismiss = Dispensar
+spell-no-suggestions = Sem sugestões
 
 # =============================================================================
 # Empty States
@@ -296,11 +525,35 @@ empty-no-connections = Sem ligações
 empty-no-bookmarks = Sem marcadores
 empty-no-users = Nenhum utilizador online
 empty-no-channel-members = Nenhum membro no canal
+empty-no-matching-users = Nenhum utilizador correspondente
 user-management-loading = A carregar utilizadores…
 user-management-no-users = Nenhum utilizador encontrado
+permission-audit-hint = Escolha uma permissão para ver quem a possui, ou clique num utilizador para ver tudo o que pode fazer.
+permission-audit-loading = A carregar…
+permission-audit-no-holders = Nenhuma conta possui esta permissão
+permission-audit-effective = Permissões efetivas de { $username }
+permission-audit-admin = Os administradores possuem todas as permissões
+permission-audit-no-permissions = Esta conta não tem permissões
+permission-audit-restricted = Concedidas, mas indisponíveis para contas partilhadas:
+permission-audit-disabled = Desativada
+impersonate-notice = Vista só de leitura do que esta conta vê. Cada consulta fica registada no registo de auditoria.
+impersonate-permissions = Permissões
+impersonate-files = Ficheiros ({ $path })
+impersonate-no-file-access = Esta conta não pode navegar pelos ficheiros
+impersonate-empty-folder = Esta pasta está vazia
+impersonate-channels = Canais
+impersonate-no-channel-access = Esta conta não pode listar canais
+impersonate-no-channels = Nenhum canal visível
+impersonate-secret = Secreto
 news-loading = A carregar notícias…
 news-no-posts = Ainda não há publicações
+news-search-placeholder = Pesquisar notícias… (author:nome since:AAAA-MM-DD until:AAAA-MM-DD)
+news-searching = A pesquisar…
+news-search-no-results = Nenhuma publicação encontrada
 transfers-empty = Sem transferências
+transfer-history-search-placeholder = Pesquisar por nome, servidor ou pasta…
+transfer-history-empty = Ainda não há transferências concluídas
+transfer-history-no-matches = Nenhuma transferência corresponde à sua pesquisa
 news-updated = Atualizado
 files-loading = A carregar ficheiros…
 files-empty = Este diretório está vazio
@@ -312,10 +565,19 @@ files-column-name = Nome
 files-column-size = Tamanho
 files-column-modified = Modificado
 files-column-path = Caminho
+files-column-rating = Classificação
 files-search-placeholder = Pesquisar ficheiros…
 files-search-breadcrumb = Pesquisa - { $query }
 files-searching = A pesquisar…
 files-no-results = Nenhum ficheiro encontrado
+files-highlights = Destaques
+files-highlights-recent = Novos carregamentos desta semana
+files-highlights-popular = Mais transferidos
+files-highlights-empty = Ainda não há nada
+files-highlights-downloads = { $count } { $count ->
+    [one] transferência
+   *[other] transferências
+}
 files-search-query-too-short = A consulta de pesquisa é muito curta (mín. { $min_length } caracteres)
 files-search-query-too-long = A consulta de pesquisa é muito longa (máx. { $max_length } caracteres)
 files-search-query-invalid = A consulta de pesquisa contém caracteres inválidos
@@ -326,6 +588,30 @@ files-share = Partilhar
 files-rename = Renomear
 files-rename-title = Renomear "{ $name }"
 files-rename-placeholder = Novo nome
+files-upload-rule-title = Regras de carregamento de "{ $name }"
+files-upload-rule-loading = A carregar…
+files-upload-rule-extensions-placeholder = Qualquer tipo (ex.: mp3, flac)
+files-upload-rule-no-limit = Sem limite
+files-upload-rule-remove = Remover regra
+files-upload-rule-help = As regras aplicam-se a esta pasta e às suas subpastas, exceto se uma subpasta tiver as suas próprias.
+files-auto-download-title = Transferência automática de "{ $name }"
+files-auto-download-pattern-placeholder = p. ex. *.mp3, *.m4a
+files-auto-download-remove = Parar transferência automática
+files-auto-download-help = Os ficheiros novos desta pasta cujos nomes correspondem são transferidos enquanto estiver ligado através deste marcador. Use * para qualquer texto e ? para um único carácter, e separe os padrões com vírgulas. Os ficheiros já transferidos são ignorados.
+files-upload-rule-hint = Carregamentos aqui: { $limits }
+files-upload-rule-hint-types = apenas { $extensions }
+files-upload-rule-hint-file-size = até { $size } por ficheiro
+files-upload-rule-hint-total-size = até { $size } por carregamento
+files-upload-preview-title = Os ficheiros já existem
+files-upload-preview-comparing = A comparar com os ficheiros do servidor…
+files-upload-preview-summary = { $new } novos, { $identical } já no servidor, { $conflicts } diferentes:
+files-upload-preview-blocked = { $count } { $count ->
+    [one] ficheiro tem
+   *[other] ficheiros têm
+} uma pasta com o mesmo nome no servidor e { $count ->
+    [one] será ignorado
+   *[other] serão ignorados
+}
 files-cut = Cortar
 files-copy = Copiar
 files-paste = Colar
@@ -335,6 +621,13 @@ files-delete-confirm-title = Confirmar eliminação
 files-delete-confirm-message = Tem a certeza de que pretende eliminar "{ $name }"?
 # File Overwrite Dialog
 files-overwrite-title = O ficheiro já existe
+files-download-to-title = Transferir { $name }
+label-remember-for-server = Lembrar para este servidor
+label-select-all = Selecionar tudo
+files-download-select-summary = { $count } de { $total } selecionados ({ $size })
+files-download-select-too-many = É possível selecionar no máximo { $max } ficheiros individuais. Transfira a pasta inteira em vez disso.
+files-download-duplicate-title = Já transferido
+files-download-duplicate-message = "{ $name }" já está neste computador em { $path }. Pretende transferi-lo novamente?
 files-overwrite-message = Já existe um ficheiro ou pasta com o nome "{ $name }" no destino. Pretende substituí-lo?
 
 # File Info Dialog
@@ -350,6 +643,17 @@ files-info-directory = Pasta
 files-info-symlink = Symlink:
 files-info-yes = Sim
 files-info-na = N/D
+files-info-rating = Classificação:
+files-info-rating-value = { $average } ({ $count } { $count ->
+    [one] classificação
+   *[other] classificações
+})
+files-info-not-rated = Ainda sem classificação
+files-info-your-rating = A sua classificação:
+files-info-comments = Comentários ({ $count })
+files-comments-loading = A carregar comentários…
+files-comments-empty = Ainda não há comentários
+files-comment-placeholder = Escreva um comentário…
 
 # =============================================================================
 # Directory Name Validation Errors
@@ -368,6 +672,7 @@ err-dir-name-invalid = O nome do diretório contém caracteres inválidos
 tab-general = Geral
 tab-chat = Chat
 tab-network = Rede
+tab-security = Segurança
 tab-audio = Áudio
 
 # =============================================================================
@@ -389,6 +694,8 @@ chat-prefix-system = [SIS]
 chat-prefix-error = [ERR]
 chat-prefix-info = [INFO]
 chat-prefix-broadcast = [BROADCAST]
+chat-prefix-queued = [PENDING]
+chat-export-subtitle = Exportado de { $server } em { $date }
 
 # =============================================================================
 # Success Messages
@@ -412,11 +719,28 @@ msg-channel-member-count = { $count } { $count ->
    *[other] membros
 }
 channel-secret = secreto
+channel-info-topic = Tópico
+channel-info-rules = Regras
+channel-info-language = Idioma
 msg-broadcast-sent = Difusão enviada com sucesso
+msg-broadcast-delivered = Difusão entregue a { $count } { $count ->
+    [one] utilizador
+   *[other] utilizadores
+}
+msg-broadcast-scheduled = Difusão agendada para { $time }
+msg-broadcast-acknowledged = Difusão confirmada por { $count } de { $delivered } utilizadores
+msg-storage-low = Pouco espaço em disco no servidor para { $volume }: { $free } livres, abaixo do mínimo de { $threshold }
+msg-storage-recovered = Espaço em disco no servidor para { $volume } recuperado: { $free } livres
+storage-volume-files = a área de ficheiros
+storage-volume-database = a base de dados
+msg-friend-added = { $username } adicionado aos amigos
+msg-friend-removed = { $username } removido dos amigos
 msg-user-created = Utilizador criado com sucesso
 msg-user-created-name = Utilizador '{ $username }' criado com sucesso
 msg-user-deleted = Utilizador eliminado com sucesso
 msg-user-deleted-name = Utilizador '{ $username }' eliminado com sucesso
+msg-user-erased = Utilizador eliminado e dados pessoais apagados
+msg-user-erased-name = Utilizador '{ $username }' eliminado e dados pessoais apagados
 msg-user-updated = Utilizador actualizado com sucesso
 msg-user-updated-name = Utilizador '{ $username }' actualizado com sucesso
 msg-permissions-updated = As suas permissões foram actualizadas
@@ -431,6 +755,8 @@ msg-welcome-back = Bem-vindo de volta
 msg-status-set = Estado definido: { $status }
 msg-status-cleared = Estado limpo
 msg-user-is-away = { $nickname } está ausente
+msg-group-member-joined = { $nickname } entrou na conversa
+msg-group-member-left = { $nickname } saiu da conversa
 msg-user-is-away-status = { $nickname } está ausente: { $status }
 
 
@@ -442,6 +768,14 @@ msg-topic-cleared = Tópico limpo por { $username }
 msg-topic-set = Tópico definido por { $username }: { $topic }
 msg-secret-set = Modo secreto ativado por { $username }
 msg-secret-cleared = Modo secreto desativado por { $username }
+msg-topic-locked = Tópico bloqueado por { $username }
+msg-topic-unlocked = Tópico desbloqueado por { $username }
+msg-rules-set = Regras do canal atualizadas por { $username }
+msg-rules-cleared = Regras do canal removidas por { $username }
+msg-language-set = Idioma do canal definido como { $language } por { $username }
+msg-language-cleared = Idioma do canal removido por { $username }
+msg-voice-limit-set = Limite de voz definido para { $limit } por { $username }
+msg-voice-limit-cleared = Limite de voz removido por { $username }
 msg-server-info-updated = Configuração do servidor atualizada
 msg-topic-display = Tópico: { $topic }
 confirm-delete-user = Tem a certeza de que deseja eliminar o utilizador '{ $username }'?
@@ -461,6 +795,7 @@ err-user-kick-failed = Falha ao expulsar utilizador
 err-unknown = Erro desconhecido
 err-no-shutdown-handle = Erro de ligação: Sem handle de encerramento
 err-userlist-failed = Falha ao actualizar lista de utilizadores
+err-request-timeout = O servidor não respondeu a tempo
 err-port-invalid = A porta deve ser um número válido (1-65535)
 
 # Network connection errors
@@ -474,12 +809,16 @@ err-connection-closed = Ligação encerrada
 err-could-not-determine-config-dir = Não foi possível determinar o directório de configuração
 err-message-too-long = A mensagem é muito longa ({ $length } caracteres, máx { $max })
 err-send-failed = Falha ao enviar mensagem
+err-checksum-failed = Falha ao calcular a soma de verificação
 err-no-chat-permission = Não tem permissão para enviar mensagens
+err-chat-window-command = Apenas mensagens e /me podem ser enviados a partir de uma janela de conversa
 err-console-no-send = Use /{ $join } para entrar num canal ou /{ $msg } para enviar uma mensagem a um utilizador
 err-broadcast-too-long = A difusão é muito longa ({ $length } caracteres, máx { $max })
 err-broadcast-send-failed = Falha ao enviar difusão
+err-broadcast-target-required = Escolha quem recebe a difusão
 err-name-required = O nome do marcador é obrigatório
 err-address-required = O endereço do servidor é obrigatório
+err-fallback-address-invalid = Endereço alternativo inválido: { $address }
 err-port-required = A porta é obrigatória
 err-username-required = O nome de utilizador é obrigatório
 err-password-required = A palavra-passe é obrigatória
@@ -497,13 +836,20 @@ err-nickname-too-long = A alcunha é demasiado longa (máx { $max } caracteres)
 err-nickname-invalid = A alcunha contém caracteres inválidos
 err-password-too-long = A palavra-passe é demasiado longa (máx { $max } caracteres)
 err-passwords-do-not-match = As palavras-passe não correspondem
+err-wrong-master-passphrase = Frase-passe mestra incorreta
+err-master-passphrase-empty = A frase-passe mestra não pode estar vazia
+err-password-vault = Erro no cofre de palavras-passe: { $error }
 err-current-password-required = A palavra-passe actual é obrigatória
 err-new-password-required = A nova palavra-passe é obrigatória
 err-confirm-password-required = Por favor, confirme a sua nova palavra-passe
 err-topic-too-long = O tópico é demasiado longo ({ $length } caracteres, máx { $max })
+err-entry-message-too-long = A mensagem de boas-vindas é demasiado longa ({ $length } caracteres, máx. { $max })
+err-rules-too-long = As regras são demasiado longas ({ $length } caracteres, máx. { $max })
 err-avatar-unsupported-type = Tipo de ficheiro não suportado. Use PNG, WebP, JPEG ou SVG.
 err-avatar-too-large = Ícone demasiado grande. O tamanho máximo é { $max_kb }KB.
 err-avatar-decode-failed = Falha ao descodificar a imagem. O ficheiro pode estar corrompido.
+err-known-server-export = Falha ao exportar o certificado: { $error }
+err-config-encryption = Falha ao encriptar as definições: { $error }
 err-server-name-empty = O nome do servidor não pode estar vazio
 err-server-name-too-long = O nome do servidor é demasiado longo (máx { $max } caracteres)
 err-server-name-contains-newlines = O nome do servidor não pode conter quebras de linha
@@ -524,14 +870,23 @@ err-failed-send-update = Falha ao enviar atualização: { $error }
 # =============================================================================
 
 err-failed-save-config = Falha ao guardar configuração: { $error }
+err-friends-no-bookmark = Os amigos são guardados no marcador do servidor; adicione primeiro um marcador
+err-friends-too-many = Demasiados amigos (máx. { $max })
+err-friend-exists = { $username } já é um amigo
+err-friend-not-found = { $username } não é um amigo
 err-failed-save-settings = Falha ao guardar definições: { $error }
 err-invalid-port-bookmark = Porta inválida no marcador: { $name }
 err-failed-send-broadcast = Falha ao enviar difusão: { $error }
 err-failed-send-message = Falha ao enviar mensagem: { $error }
+err-failed-start-group = Falha ao iniciar a conversa de grupo: { $error }
+err-failed-share-snippet = Falha ao partilhar o snippet: { $error }
 err-failed-create-user = Falha ao criar utilizador: { $error }
 err-failed-delete-user = Falha ao eliminar utilizador: { $error }
 err-failed-update-user = Falha ao actualizar utilizador: { $error }
 err-failed-update-topic = Falha ao actualizar tópico: { $error }
+err-failed-lock-topic = Falha ao bloquear tópico: { $error }
+err-failed-set-language = Falha ao definir o idioma do canal: { $error }
+err-failed-update-entry = Falha ao atualizar o canal: { $error }
 err-message-too-long-details = { $error } ({ $length } caracteres, máx { $max })
 
 # Network connection errors (with parameters)
@@ -546,6 +901,14 @@ err-tls-handshake-failed = Falha no handshake TLS: { $error }
 err-failed-send-handshake = Falha ao enviar handshake: { $error }
 err-failed-read-handshake = Falha ao ler resposta do handshake: { $error }
 err-handshake-failed = Falha no handshake: { $error }
+err-proof-of-work-too-hard = O servidor pediu demasiada prova de trabalho (dificuldade { $difficulty })
+err-sso-not-offered = Este servidor não oferece início de sessão único
+err-sso-failed = Falha no início de sessão único: { $error }
+err-sso-browser = Não foi possível abrir o navegador: { $error }
+err-sso-timeout = O início de sessão único expirou à espera do navegador
+err-sso-state-mismatch = A resposta do início de sessão único não corresponde ao pedido
+sso-browser-done = Sessão iniciada. Pode fechar esta janela e voltar ao Nexus.
+sso-browser-failed = Falha ao iniciar sessão. Pode fechar esta janela e voltar ao Nexus.
 err-failed-parse-handshake = Falha ao analisar resposta do handshake: { $error }
 err-failed-send-login = Falha ao enviar autenticação: { $error }
 err-failed-read-login = Falha ao ler resposta de autenticação: { $error }
@@ -553,6 +916,7 @@ err-failed-parse-login = Falha ao analisar resposta de autenticação: { $error
 err-failed-create-server-name = Falha ao criar nome do servidor: { $error }
 err-failed-create-config-dir = Falha ao criar directório de configuração: { $error }
 err-failed-serialize-config = Falha ao serializar configuração: { $error }
+err-config-locked = As definições estão encriptadas e bloqueadas; as alterações não podem ser guardadas até serem desbloqueadas
 err-failed-write-config = Falha ao escrever ficheiro de configuração: { $error }
 err-failed-read-config-metadata = Falha ao ler metadados do ficheiro de configuração: { $error }
 err-failed-set-config-permissions = Falha ao definir permissões do ficheiro de configuração: { $error }
@@ -562,6 +926,10 @@ err-failed-set-config-permissions = Falha ao definir permissões do ficheiro de
 # =============================================================================
 
 fingerprint-warning = Isto pode indicar um problema de segurança (ataque MITM) ou que o certificado do servidor foi regenerado. Aceite apenas se confiar no administrador do servidor.
+fingerprint-new-server-prompt = Esta é a primeira ligação a este servidor. Só confie no certificado se a impressão digital abaixo corresponder à publicada pelo administrador do servidor.
+password-vault-create-description = Não há nenhum porta-chaves do sistema disponível. Escolha uma frase-passe mestra para cifrar as palavras-passe guardadas dos seus marcadores, ou ignore para as manter no ficheiro de configuração.
+password-vault-unlock-description = Introduza a sua frase-passe mestra para desbloquear as palavras-passe guardadas dos seus marcadores. Se ignorar, os marcadores ligam-se sem palavra-passe até o Nexus ser reiniciado.
+config-unlock-description = As suas definições, marcadores e histórico de chat estão encriptados. Introduza a sua frase-passe mestra para os desbloquear. Se ignorar, o Nexus inicia com as definições predefinidas e nada é guardado até ser reiniciado.
 
 # =============================================================================
 # User Info Display
@@ -578,10 +946,12 @@ user-info-account = Conta: { $username }
 user-info-connected = Ligado:
 user-info-connected-value = há { $duration }
 user-info-connected-value-sessions = há { $duration } ({ $count } sessões)
+user-info-idle = Inativo:
 user-info-features = Funcionalidades:
 user-info-features-value = { $features }
 user-info-features-none = Nenhuma
 user-info-locale = Idioma:
+user-info-client = Cliente:
 user-info-address = Endereço:
 user-info-addresses = Endereços:
 user-info-created = Criado:
@@ -590,6 +960,12 @@ user-info-unknown = Desconhecido
 user-info-loading = A carregar informações do utilizador…
 user-info-status = Estado:
 user-info-channels = Canais:
+user-info-rank = Nível:
+user-info-logins = Inícios de sessão:
+user-info-messages = Mensagens:
+user-info-uploads = Envios:
+user-info-downloaded = Transferido:
+user-info-last-login = Último início de sessão:
 
 # =============================================================================
 # Time Duration
@@ -625,26 +1001,51 @@ err-me-no-target = Use /{ $join } para entrar num canal ou /{ $msg } para enviar
 cmd-help-escape-hint = Dica: Use // para enviar uma mensagem que comece com /
 cmd-message-desc = Enviar uma mensagem a um utilizador
 cmd-message-usage = Uso: /{ $command } <utilizador> <mensagem>
+cmd-group-desc = Inicia uma conversa de grupo com vários utilizadores
+cmd-group-usage = Uso: /{ $command } <utilizador> [<utilizador>...]
+cmd-invite-desc = Adiciona um utilizador à conversa de grupo atual
+cmd-invite-usage = Uso: /{ $command } <utilizador>
 cmd-ping-desc = Medir latência para o servidor
 cmd-ping-usage = Uso: /{ $command }
+cmd-radio-desc = Transmitir áudio para os ouvintes de voz do canal
+cmd-radio-usage = Utilização: /{ $command }
+err-radio-no-channel = Este comando só funciona num canal
+err-radio-in-voice = Saia da voz antes de iniciar uma transmissão
 ping-result = Resposta: { $ms }ms
 cmd-userinfo-desc = Mostrar informações sobre um utilizador
 cmd-userinfo-usage = Uso: /{ $command } <utilizador>
 cmd-kick-desc = Expulsar um utilizador do servidor
 cmd-kick-usage = Uso: /{ $command } <utilizador> [motivo]
+cmd-top-desc = Mostrar os utilizadores mais ativos
+cmd-top-usage = Utilização: /{ $command } [logins|messages|uploads|downloaded]
 cmd-topic-desc = Ver ou gerir o tópico do chat
-cmd-topic-usage = Uso: /{ $command } [definir|limpar] [tópico]
+cmd-topic-usage = Uso: /{ $command } [definir|limpar|bloquear|desbloquear|historico] [tópico]
 cmd-topic-arg-set = definir
 cmd-topic-arg-clear = limpar
+cmd-topic-arg-lock = bloquear
+cmd-topic-arg-unlock = desbloquear
+cmd-topic-arg-history = historico
 cmd-topic-set-usage = Uso: /{ $command } definir <tópico>
 cmd-topic-none = Nenhum tópico definido
 cmd-broadcast-desc = Enviar uma difusão para todos os utilizadores
 cmd-broadcast-usage = Uso: /{ $command } <mensagem>
 cmd-clear-desc = Limpar histórico de chat do separador atual
 cmd-clear-usage = Uso: /{ $command }
+cmd-export-desc = Guarda o separador atual como ficheiro HTML
+cmd-export-usage = Uso: /{ $command }
+cmd-clip-desc = Listar, reproduzir, adicionar ou eliminar clipes da mesa de sons
+cmd-clip-usage = Utilização: /{ $command } [lista|tocar <nome>|adicionar <nome>|eliminar <nome>|<nome>]
+cmd-clip-arg-list = lista
+cmd-clip-arg-play = tocar
+cmd-clip-arg-add = adicionar
+cmd-clip-arg-delete = eliminar
 cmd-focus-desc = Focar no chat do servidor ou janela de mensagens de um utilizador
 cmd-focus-usage = Uso: /{ $command } [utilizador]
 cmd-focus-not-found = Utilizador não encontrado: { $name }
+cmd-friend-desc = Listar, adicionar ou remover amigos
+cmd-friend-usage = Utilização: /{ $command } [list] | add <utilizador> | remove <utilizador>
+cmd-friend-list-header = Amigos:
+cmd-friend-list-empty = Ainda sem amigos. Adicione um com /{ $command } add <utilizador>
 cmd-list-desc = Mostrar utilizadores ligados/todos
 cmd-list-arg-all = todos
 cmd-list-usage = Uso: /{ $command } [todos]
@@ -660,6 +1061,13 @@ cmd-list-all-output = Utilizadores: { $users } ({ $count } { $count ->
 })
 cmd-help-usage = Uso: /{ $command } [comando]
 cmd-topic-permission-denied = Não tem permissão para editar o tópico
+cmd-topic-lock-permission-denied = Não tem permissão para bloquear o tópico
+msg-topic-already-locked = O tópico já está bloqueado
+msg-topic-already-unlocked = O tópico já está desbloqueado
+msg-topic-history-header = Histórico do tópico de { $channel }:
+msg-topic-history-set = { $time } { $username }: { $topic }
+msg-topic-history-cleared = { $time } { $username } limpou o tópico
+msg-topic-history-empty = Nenhuma alteração de tópico registada
 cmd-window-desc = Gerir separadores de chat
 cmd-window-usage = Uso: /{ $command } [próximo|anterior|fechar [utilizador]]
 cmd-window-arg-next = próximo
@@ -687,15 +1095,55 @@ cmd-unban-desc = Remover um banimento de IP
 cmd-unban-usage = Uso: /{ $command } <alvo>
 cmd-bans-desc = Listar banimentos ativos
 cmd-bans-usage = Uso: /{ $command }
+cmd-register-desc = Registar uma conta a partir de uma sessão de convidado
+cmd-register-usage = Utilização: /{ $command } <utilizador> <palavra-passe>
 cmd-reindex-desc = Acionar reconstrução do índice de ficheiros
 cmd-reindex-usage = Uso: /{ $command }
+cmd-cleanup-desc = Procurar restos de transferências interrompidas na área de ficheiros
+cmd-cleanup-usage = Uso: /{ $command } [delete]
+cmd-filecheck-desc = Verificar a área de ficheiros em relação ao índice de pesquisa e aos metadados dos ficheiros
+cmd-filecheck-usage = Utilização: /{ $command } [repair]
+cmd-screenshot-desc = Partilhar uma captura de ecrã anotada no separador atual
+cmd-screenshot-usage = Utilização: /{ $command }
+err-screenshot-no-target = As capturas só podem ser partilhadas num canal ou separador de mensagem de utilizador
+err-screenshot-no-folder = Nenhuma pasta de capturas definida — escolha "Partilhar capturas aqui" numa pasta de envio em Ficheiros
+err-screenshot-capture = Falha na captura de ecrã: { $error }
+err-screenshot-save = Não foi possível guardar a captura: { $error }
+err-screenshot-upload = Falha ao enviar a captura: { $error }
+err-export-empty = Não há mensagens para exportar neste separador
+err-chat-export = Não foi possível exportar o chat: { $error }
+screenshot-title = Partilhar captura
+screenshot-tool-crop = Recortar
+screenshot-tool-arrow = Seta
+screenshot-hint-crop = Arraste um retângulo à volta da parte a manter
+screenshot-hint-arrow = Arraste da base até à ponta da seta
 msg-reindex-triggered = Reconstrução do índice de ficheiros acionada
+msg-cleanup-nothing = Nenhum resto de transferências interrompidas encontrado
+msg-cleanup-found = Restos de transferências interrompidas encontrados:
+msg-cleanup-deleted = Restos de transferências interrompidas eliminados:
+msg-cleanup-parts = Ficheiros .part órfãos: { $count } ({ $size })
+msg-cleanup-temp = Ficheiros temporários restantes: { $count } ({ $size })
+msg-cleanup-journal = Entradas do registo de envios sem ficheiro: { $count }
+msg-cleanup-index = Entradas do índice de pesquisa sem ficheiro: { $count }
+msg-cleanup-hint = Verifique os ficheiros acima e depois execute /cleanup delete para os remover
+msg-filecheck-issue = { $kind }: { $path }
+msg-filecheck-issue-repaired = { $kind }: { $path } (reparado)
+msg-filecheck-clean = A verificação da área de ficheiros não encontrou problemas em { $entries } entradas
+msg-filecheck-done = A verificação da área de ficheiros encontrou { $issues } problemas em { $entries } entradas, { $repaired } reparados
+msg-filecheck-index-skipped = O índice de pesquisa não foi comparado porque ainda não foi criado ou há uma reconstrução pendente
+msg-filecheck-hint = Execute /filecheck repair para reconstruir o índice e remover os metadados de caminhos em falta
+filecheck-not-indexed = Fora do índice de pesquisa
+filecheck-indexed-but-missing = No índice de pesquisa, mas em falta no disco
+filecheck-index-outdated = Desatualizado no índice de pesquisa
+filecheck-orphaned-activity = Histórico de um caminho em falta
+filecheck-orphaned-reviews = Avaliações ou comentários de um caminho em falta
+filecheck-orphaned-upload-rule = Regra de carregamento de uma pasta em falta
 cmd-join-desc = Entrar ou criar um canal
 cmd-join-usage = Uso: /{ $command } #canal
 cmd-leave-desc = Sair de um canal
 cmd-leave-usage = Uso: /{ $command } [#canal]
 cmd-channels-desc = Listar canais disponíveis
-cmd-channels-usage = Uso: /{ $command }
+cmd-channels-usage = Uso: /{ $command } [idioma]
 cmd-secret-desc = Alternar modo secreto no canal atual
 cmd-secret-usage = Uso: /{ $command } [on|off]
 cmd-secret-arg-on = on
@@ -708,12 +1156,40 @@ msg-secret-enabled = O canal agora é secreto
 msg-secret-disabled = O canal agora é público
 msg-secret-status-on = Este canal é secreto
 msg-secret-status-off = Este canal é público
+cmd-voicelimit-desc = Ver ou definir o limite de participantes de voz do canal atual
+cmd-voicelimit-usage = Utilização: /{ $command } [<quantidade>|off]
+cmd-vt-desc = Enviar uma mensagem de texto para a sua sessão de voz
+cmd-vt-usage = Utilização: /{ $command } <mensagem>
+cmd-voicelimit-arg-off = off
+err-voicelimit-no-channel = Este comando só funciona num canal
+msg-voice-limit-status = Este canal permite no máximo { $limit } utilizadores em voz
+msg-voice-limit-status-none = Este canal não tem limite de voz
+msg-voice-clip-none = Nenhum clipe na mesa de sons
+msg-voice-clip-list = Clipes da mesa de sons ({ $count }):
+msg-voice-clip-entry = { $name } ({ $seconds } s, por { $nickname })
+msg-voice-clip-uploaded = Clipe adicionado à mesa de sons
+msg-voice-clip-deleted = Clipe eliminado da mesa de sons
 cmd-trust-desc = Confiar num IP, intervalo CIDR ou alcunha para contornar banimentos
 cmd-trust-usage = Uso: /{ $command } <alvo> [duração] [motivo]
 cmd-untrust-desc = Remover uma entrada de IP de confiança
 cmd-untrust-usage = Uso: /{ $command } <alvo>
 cmd-trusted-desc = Listar IPs de confiança
 cmd-trusted-usage = Uso: /{ $command }
+cmd-ldap-desc = Listar, definir ou remover mapeamentos de grupos do diretório
+cmd-ldap-usage = Uso: /{ $command } [list|set <permissões|admin|none> <DN do grupo>|remove <DN do grupo>]
+cmd-nickname-desc = Reservar ou libertar alcunhas para a sua conta
+cmd-nickname-usage = Utilização: /{ $command } [register <alcunha> [utilizador]|release <alcunha>]
+cmd-history-desc = Mostra ou define durante quanto tempo o servidor guarda as suas mensagens privadas, ou carrega as mais antigas
+cmd-history-usage = Utilização: /{ $command } [<dias>|more]
+cmd-alias-desc = Criar atalhos para comandos e mensagens
+cmd-alias-usage = Uso: /{ $command } [list|set <nome> <texto>|remove <nome>]
+cmd-alias-list-header = Os seus aliases:
+cmd-alias-list-empty = Ainda não há aliases. Crie um com /{ $command } set <nome> <texto>
+cmd-alias-set = /{ $name } executa agora: { $text }
+cmd-alias-removed = Alias /{ $name } removido
+cmd-alias-not-found = Nenhum alias chamado /{ $name }
+cmd-alias-invalid-name = Os nomes de alias só podem usar letras, números, - e _ (até { $max } caracteres)
+cmd-alias-builtin = /{ $name } é um comando incorporado e não pode ser usado como alias
 
 # =============================================================================
 # About Panel
@@ -726,9 +1202,25 @@ err-server-image-invalid-format = Formato de imagem do servidor inválido (deve
 err-server-image-unsupported-type = Tipo de imagem do servidor não suportado (apenas PNG, WebP, JPEG ou SVG)
 err-server-image-decode-failed = Falha ao descodificar a imagem. O ficheiro pode estar corrompido.
 err-failed-read-image = Falha ao ler a imagem: { $error }
-err-news-empty = A publicação deve ter texto ou uma imagem
+err-news-empty = A publicação deve ter texto ou um anexo
 err-news-body-too-long = O conteúdo é demasiado longo ({ $length } caracteres, máximo { $max })
 err-news-body-invalid-characters = O conteúdo contém caracteres de controlo inválidos
+err-news-too-many-attachments = Uma publicação pode ter no máximo { $max } anexos
+err-news-caption-too-long = A legenda é demasiado longa (máx. { $max } caracteres)
+err-news-caption-invalid-characters = A legenda deve ser uma única linha sem caracteres de controlo
+err-news-file-path-invalid = Caminho de ficheiro inválido
+err-news-search-date = Data inválida: { $date } (use AAAA-MM-DD)
+err-file-comment-empty = O comentário não pode estar vazio
+err-file-comment-too-long = O comentário é demasiado longo ({ $length } caracteres, máximo { $max })
+err-file-comment-invalid-characters = O comentário contém caracteres de controlo inválidos
+err-upload-rule-invalid-extension = Tipo de ficheiro inválido "{ $extension }" (use letras e dígitos, como mp3)
+err-auto-download-pattern-required = Introduza pelo menos um padrão de nome de ficheiro
+err-auto-download-pattern-invalid = Padrão inválido "{ $pattern }" (os padrões aplicam-se a nomes de ficheiro e não podem conter barras)
+err-upload-rule-too-many-extensions = Demasiados tipos de ficheiro (máx. { $max })
+err-upload-rule-invalid-size = Tamanho inválido "{ $value }" (introduza um número de megabytes)
+err-ldap-group-empty = O DN do grupo não pode estar vazio
+err-ldap-group-too-long = O DN do grupo é demasiado longo (máximo { $max } caracteres)
+err-ldap-group-invalid-characters = O DN do grupo não pode ter espaços nas extremidades nem caracteres de controlo
 err-news-image-decode-failed = Falha ao descodificar a imagem. O ficheiro pode estar corrompido.
 
 # Proxy errors
@@ -739,9 +1231,31 @@ err-proxy-connection-timeout = Tempo limite da ligação ao proxy após { $secon
 }
 err-proxy-address-required = O endereço do proxy é obrigatório quando o proxy está ativado
 err-proxy-port-invalid = A porta do proxy deve estar entre 1 e 65535
-err-news-image-too-large = A imagem é demasiado grande (máximo 512KB)
+err-news-image-too-large = A imagem é demasiado grande (máximo { $max_kb }KB)
 err-news-image-unsupported-type = Tipo de imagem não suportado (apenas PNG, WebP, JPEG ou SVG)
 err-topic-no-channel = Este comando só funciona num canal
+err-entry-no-channel = Este comando só funciona num canal
+cmd-welcome-desc = Ver ou definir a mensagem mostrada a quem entra no canal
+cmd-welcome-usage = Uso: /{ $command } [definir <mensagem>|limpar]
+cmd-welcome-arg-set = definir
+cmd-welcome-arg-clear = limpar
+cmd-welcome-none = Nenhuma mensagem de boas-vindas definida
+msg-entry-message-display = Mensagem de boas-vindas: { $message }
+msg-entry-message-updated = Mensagem de boas-vindas atualizada
+msg-entry-message-cleared = Mensagem de boas-vindas removida
+cmd-rules-desc = Ver ou definir as regras do canal
+cmd-rules-usage = Uso: /{ $command } [definir <regras>|limpar]
+cmd-rules-arg-set = definir
+cmd-rules-arg-clear = limpar
+cmd-rules-none = Nenhuma regra definida
+msg-rules-header = Regras de { $channel }:
+cmd-language-desc = Ver ou definir a etiqueta de idioma do canal
+cmd-language-usage = Uso: /{ $command } [definir <idioma>|limpar]
+cmd-language-arg-set = definir
+cmd-language-arg-clear = limpar
+msg-language-status = Idioma do canal: { $language }
+msg-language-none = Nenhum idioma definido
+err-language-invalid = Etiqueta de idioma inválida: { $language } (ex.: pt ou pt-PT)
 err-join-channel = Falha ao entrar no canal: { $error }
 err-leave-channel = Falha ao sair do canal: { $error }
 err-list-channels = Falha ao listar canais: { $error }
@@ -751,6 +1265,8 @@ err-channel-too-long = O nome do canal é demasiado longo (máx. { $max } caract
 err-channel-missing-prefix = O nome do canal deve começar com #
 err-channel-invalid-characters = O nome do canal contém caracteres inválidos
 err-leave-no-channel = Tem de estar num canal para usar /{ $command } sem argumentos
+err-invite-no-group = Utilize /{ $command } num separador de conversa de grupo
+err-group-too-many-members = Uma conversa de grupo pode ter no máximo { $max } membros
 err-not-in-channel = Não está no canal { $channel }
 err-leave-already-pending = Já a aguardar resposta do servidor
 err-uri-connection-failed = Falha ao ligar a { $host }: { $error }
@@ -764,6 +1280,27 @@ label-port = Porta:
 # Transfer statuses
 transfer-status-queued = Em fila
 transfer-status-connecting = A ligar…
+transfer-status-server-queued = A aguardar vaga no servidor (#{ $position })
+transfer-collision-pending = O ficheiro existe: { $path }
+collision-action-renamed = { $count } com nome alterado
+collision-action-overwritten = { $count } { $count ->
+    [one] substituído
+   *[other] substituídos
+}
+collision-action-skipped = { $count } { $count ->
+    [one] ignorado
+   *[other] ignorados
+}
+transfer-bar-count = { $count } { $count ->
+    [one] transferência
+   *[other] transferências
+}
+status-bar-users = { $count } { $count ->
+    [one] utilizador
+   *[other] utilizadores
+}
+status-bar-latency = { $ms } ms
+status-bar-server-time = Hora do servidor { $time } ({ $zone })
 transfer-status-transferring = A transferir
 transfer-status-paused = Em pausa
 transfer-status-completed = Concluído
@@ -825,6 +1362,7 @@ event-broadcast = Transmissão
 event-chat-message = Mensagem de chat
 event-chat-mention = Menção no chat
 event-connection-lost = Ligação perdida
+event-friend-online = Amigo online
 event-news-post = Publicação de notícias
 event-permissions-changed = Permissões alteradas
 event-transfer-complete = Transferência concluída
@@ -859,6 +1397,17 @@ settings-sound-test = Testar
 settings-notification-test = Testar
 event-settings-show-toast = Mostrar Toast
 settings-toast-test = Testar
+settings-do-not-disturb = Não incomodar
+settings-quiet-hours = Horas de silêncio
+settings-do-not-disturb-hint = Silencia notificações, avisos e sons. Os indicadores de não lidas continuam a contar, e os outros veem 🔕 junto ao seu nome.
+settings-notification-rules = Regras de notificação
+settings-notification-rules-server = Servidor:
+settings-notification-rules-channels = Exceções por canal
+settings-notification-rules-keywords = Palavras-chave monitorizadas
+settings-notification-rules-no-bookmarks = Adicione um servidor aos marcadores para definir as suas regras de notificação.
+channel-notification-all = Todas as mensagens
+channel-notification-mentions = Apenas menções
+channel-notification-mute = Silenciar
 
 # Sound names
 sound-none = Nenhum
@@ -877,6 +1426,8 @@ notification-chat-message = Nova mensagem de chat
 notification-chat-message-from = Chat de {$username}
 notification-connection-lost = Ligação perdida
 notification-connection-lost-from = Desligado de {$server}
+notification-friend-online = Um amigo ficou online
+notification-friend-online-name = {$username} está online
 notification-download-complete = Transferência concluída
 notification-download-failed = Transferência falhou
 notification-news-post = Nova publicação de notícias
@@ -930,6 +1481,18 @@ ban-duration-7d = 7 dias
 ban-duration-30d = 30 dias
 ban-duration-permanent = Permanente
 
+# Broadcast target and delay options
+broadcast-target-label = Enviar para:
+broadcast-target-all = Todos
+broadcast-target-channel = Membros de um canal
+broadcast-target-permission = Utilizadores com uma permissão
+broadcast-delay-label = Enviar:
+broadcast-delay-now = Agora
+broadcast-delay-5m = Daqui a 5 minutos
+broadcast-delay-15m = Daqui a 15 minutos
+broadcast-delay-1h = Daqui a 1 hora
+broadcast-delay-1d = Daqui a 1 dia
+
 # =============================================================================
 # Ban System
 # =============================================================================
@@ -979,12 +1542,32 @@ msg-untrusted-success = Entrada de confiança removida com sucesso
 # Trust list messages
 msg-trust-list-header = IPs de confiança:
 msg-trust-list-empty = Sem IPs de confiança
+msg-ldap-group-list-header = Grupos do diretório:
+msg-ldap-group-list-empty = Nenhum grupo do diretório mapeado
+msg-ldap-group-admin = administrador
+msg-ldap-group-no-permissions = sem permissões
+msg-ldap-group-set = Grupo do diretório { $group } mapeado
+msg-ldap-group-removed = Grupo do diretório { $group } removido
+msg-guest-upgraded = Conta { $username } criada. Inicie sessão com ela da próxima vez para a usar.
+msg-nickname-registered = A alcunha { $nickname } está agora reservada para { $username }.
+msg-nickname-released = Alcunha { $nickname } libertada.
+msg-history-days = O servidor guarda as suas mensagens privadas durante { $days } dias.
+msg-history-off = O servidor não guarda as suas mensagens privadas. Utilize /history <dias> para ativar.
+msg-history-no-older = Não há mensagens mais antigas com { $nickname }.
+err-history-days-too-long = O histórico de mensagens pode ser guardado no máximo { $max } dias
+err-history-more-tab = Utilize /history more num separador de mensagens privadas
 msg-trust-permanent = permanente
 msg-trust-expired = expirado
 msg-trust-remaining = { $time } restante
 msg-trust-remaining-days = { $days }d { $hours }h
 msg-trust-remaining-hours = { $hours }h { $minutes }m
 msg-trust-remaining-minutes = { $minutes }m
+msg-leaderboard-header = Utilizadores mais ativos por { $stat }:
+msg-leaderboard-empty = Ainda não há atividade registada
+leaderboard-stat-logins = inícios de sessão
+leaderboard-stat-messages = mensagens
+leaderboard-stat-uploads = envios
+leaderboard-stat-downloaded = dados transferidos
 
 # =============================================================================
 # Voice Chat
@@ -993,6 +1576,16 @@ msg-trust-remaining-minutes = { $minutes }m
 # Voice error messages
 err-voice-join = Falha ao entrar no chat de voz: { $error }
 err-voice-leave = Falha ao sair do chat de voz: { $error }
+err-voice-limit = Falha ao definir o limite de voz: { $error }
+err-voice-clip = Mesa de sons: { $error }
+err-voice-text = Texto de voz: { $error }
+err-voice-clip-name-empty = O nome do clipe não pode estar vazio
+err-voice-clip-name-too-long = O nome do clipe é demasiado longo (máx. { $max } caracteres)
+err-voice-clip-name-invalid = Os nomes de clipe só podem conter letras, dígitos, - e _
+err-voice-clip-too-large = O clipe é demasiado grande (máx. 256 KB)
+err-voice-clip-unsupported-type = Formato de clipe não suportado (apenas Ogg Vorbis)
+msg-voice-queued = A voz está cheia - é o número { $position } na fila
+msg-voice-queue-cancelled = Foi removido da fila de voz
 err-voice-no-token = Entrada no chat de voz bem-sucedida, mas nenhum token recebido
 err-voice-already-active = Já está numa sessão de voz noutro servidor
 err-voice-no-permission = Não tem permissão para entrar no chat de voz
@@ -1018,7 +1611,12 @@ msg-voice-joined-empty = Entrou no chat de voz em { $target }
 msg-voice-left = Saiu do chat de voz em { $target }
 msg-voice-user-joined = { $nickname } entrou no chat de voz
 msg-voice-user-left = { $nickname } saiu do chat de voz
+msg-voice-broadcast-started = { $nickname } está a transmitir na voz - entre para ouvir
+msg-voice-broadcast-ended = A transmissão de voz terminou
+msg-voice-clip-played = { $nickname } tocou { $name }
+msg-voice-text = [voz] { $nickname }: { $message }
 msg-voice-you-left = Saiu do chat de voz
+msg-voice-tcp-relay = A ligação UDP de voz falhou ({ $error }) - a voz será retransmitida pela ligação ao servidor
 
 # Voice UI
 voice-join-tooltip = Entrar no chat de voz
@@ -1026,6 +1624,8 @@ voice-leave-tooltip = Sair do chat de voz
 voice-mute-all-tooltip = Silenciar tudo
 voice-unmute-all-tooltip = Ativar som
 voice-bar-participants = ({ $count })
+voice-bar-queued = (na fila: { $position })
+voice-bar-listeners = ({ $count } a ouvir)
 voice-indicator-tooltip = No chat de voz
 tooltip-mute = Silenciar
 tooltip-unmute = Ativar som
@@ -1036,6 +1636,12 @@ voice-quality-medium = Média (32 kbps)
 voice-quality-high = Alta (64 kbps)
 voice-quality-very-high = Muito alta (96 kbps)
 
+# Voice connection quality
+voice-connection-good = Boa
+voice-connection-fair = Razoável
+voice-connection-poor = Má
+voice-connection-tooltip = Ligação: { $quality } ({ $loss }% de perda, jitter de { $jitter } ms)
+
 # PTT modes
 ptt-mode-hold = Manter premido para falar
 ptt-mode-toggle = Alternar
@@ -1050,6 +1656,8 @@ ptt-delay-500ms = 500ms
 audio-output-device = Dispositivo de saída
 audio-input-device = Dispositivo de entrada
 audio-refresh-devices = Atualizar dispositivos
+audio-clip-volume = Volume da mesa de sons
+audio-voice-text-tts = Ler mensagens de texto de voz em voz alta
 audio-voice-quality = Qualidade de voz
 audio-ptt-key = Tecla Push-to-Talk
 audio-ptt-mode = Modo PTT
@@ -1076,6 +1684,44 @@ mic-boost-6db = +6 dB
 mic-boost-12db = +12 dB
 mic-boost-18db = +18 dB
 
+# =============================================================================
+# Global Hotkeys
+# =============================================================================
+
+# Settings
+settings-toggle-window-key = Tecla mostrar/ocultar janela
+button-clear-hotkey = Limpar
+
+# Shortcut descriptions (shown by the desktop on Wayland)
+hotkey-push-to-talk = Premir para falar
+hotkey-toggle-window = Mostrar ou ocultar o Nexus
+
+# Errors
+err-window-hotkey-failed = Falha ao registar tecla de mostrar/ocultar janela: { $error }
+err-global-shortcuts-unavailable = Portal de atalhos globais indisponível, as teclas só funcionam com o Nexus em foco: { $error }
+
+# =============================================================================
+# Auto Away
+# =============================================================================
+
+settings-auto-away = Ficar ausente automaticamente quando inativo
+settings-auto-away-minutes = Minutos de inatividade
+
+# =============================================================================
+# Connection Health
+# =============================================================================
+
+# Server list indicator
+connection-health-good = Boa
+connection-health-fair = Razoável
+connection-health-poor = Má
+connection-health-tooltip = Ligação: { $quality }
+connection-health-latency = Latência: { $ms } ms
+connection-health-jitter = Jitter: { $ms } ms
+connection-health-last-drop = Último ping perdido: { $time }
+connection-health-no-drops = Nenhum ping perdido
+connection-health-measuring = A medir a latência…
+
 # =============================================================================
 # System Tray (Windows/Linux only)
 # =============================================================================
@@ -1104,14 +1750,33 @@ tray-tooltip-unread = Nexus BBS - { $count ->
    *[other] { $count } conversas não lidas
 }
 
+# =============================================================================
+# Notification Actions (macOS/Windows only)
+# =============================================================================
+
+# Actions
+notification-reply = Responder
+notification-reply-send = Enviar
+notification-reply-placeholder = Responder…
+notification-mark-read = Marcar como lida
+
 # =============================================================================
 # Toast Notifications
 # =============================================================================
 
 toast-link-copied = Ligação copiada para a área de transferência
 toast-copied = Copiado para a área de transferência
+toast-checksum-copied = SHA-256 copiado para a área de transferência
 toast-download-started = A transferir { $filename }
 toast-download-queued = Transferência na fila: { $filename }
+toast-downloads-started = A transferir { $count } { $count ->
+    [one] ficheiro
+   *[other] ficheiros
+}
+toast-downloads-queued = { $count } { $count ->
+    [one] transferência
+   *[other] transferências
+} na fila
 toast-upload-started = A enviar { $filename }
 toast-upload-queued = Envio na fila: { $filename }
 toast-uploads-started = A enviar { $count } { $count ->
@@ -1122,3 +1787,28 @@ toast-uploads-queued = { $count } { $count ->
     [one] envio
    *[other] envios
 } na fila
+toast-upload-nothing-new = Nada para carregar — o servidor já tem estes ficheiros
+toast-upload-watch-started = A monitorizar { $folder } — os novos ficheiros serão carregados automaticamente
+toast-upload-watch-stopped = Deixou de monitorizar { $folder }
+toast-auto-download-started = A transferir automaticamente { $pattern } de { $folder }
+toast-auto-download-stopped = Transferência automática de { $folder } parada
+toast-screenshot-folder-set = As capturas serão enviadas para esta pasta
+toast-chat-exported = Chat exportado
+
+# =============================================================================
+# First-run Tips
+# =============================================================================
+
+tip-welcome-title = Bem-vindo ao Nexus
+tip-welcome = Introduza abaixo o endereço de um servidor e ligue-se. Adicione um marcador para guardar o servidor, com o seu início de sessão, na lista à esquerda, à distância de um clique da próxima vez.
+tip-fingerprint = Cada servidor tem uma impressão digital de certificado, como uma assinatura. O Nexus memoriza-a na primeira ligação e avisa se mudar, pois isso pode significar que alguém se está a fazer passar pelo servidor. Só aceite uma nova impressão digital se o administrador do servidor lhe disse que mudou.
+tip-server-list = Os seus marcadores e ligações abertas estão à esquerda. Clique num para mudar de servidor ou arraste os marcadores para os reorganizar.
+tip-toolbar = A barra de ferramentas abre ficheiros, notícias, definições e mais. Passe o rato sobre um ícone para ver o que faz.
+tip-chat = Converse aqui com todos no servidor. Escreva / para ver os comandos disponíveis, como /join para entrar num canal.
+tip-user-list = Os utilizadores online agora aparecem à direita. Clique num nome para enviar uma mensagem ou ver as informações.
+tip-counter = Dica { $current } de { $total }
+button-add-bookmark = Adicionar marcador
+button-tip-next = Seguinte
+button-tip-done = Concluir
+button-tip-got-it = Entendido
+button-skip-tips = Saltar dicas
@@ -11,6 +11,13 @@ button-connect = Подключиться
 button-save = Сохранить
 button-create = Создать
 button-edit = Редактировать
+button-check-ports = Проверить порты
+button-statistics = Статистика
+button-dashboard = Панель
+button-refresh = Обновить
+button-doors = Двери
+button-doors-leave = Выйти
+button-doors-back = Назад
 button-update = Обновить
 button-accept-new-certificate = Принять новый сертификат
 button-close = Закрыть
@@ -18,9 +25,23 @@ button-choose-avatar = Выбрать аватар
 button-clear-avatar = Очистить
 button-choose-image = Выбрать изображение
 button-clear-image = Очистить
+button-add-file = Добавить файл
 button-overwrite = Перезаписать
 button-change-password = Изменить пароль
 button-browse = Обзор
+button-undo = Отменить
+button-add = Добавить
+button-remove = Удалить
+button-view = Показать
+button-hide = Скрыть
+button-export = Экспорт…
+button-trust-certificate = Доверять сертификату
+button-skip = Пропустить
+button-skip-existing = Пропустить существующие
+button-download-anyway = Всё равно скачать
+button-unlock = Разблокировать
+button-view-as-user = Смотреть как пользователь
+button-post-comment = Отправить
 
 # =============================================================================
 # Titles
@@ -35,19 +56,29 @@ title-user-create = Создать пользователя
 title-user-edit = Редактировать пользователя
 title-update-user = Обновить пользователя
 title-user-management = Управление Пользователями
+title-permission-audit = Аудит прав
+title-impersonate = Просмотр от имени { $username }
 title-confirm-delete = Подтвердить Удаление
 title-connected = Подключённые
 title-settings = Настройки
 title-bookmarks = Закладки
 title-users = Пользователи
+title-friends = Друзья
+friend-online = в сети
+friend-offline = не в сети
 title-edit-server-info = Редактировать информацию о сервере
 title-fingerprint-mismatch = Отпечаток сертификата не совпадает!
+title-fingerprint-new-server = Новый сертификат сервера
+title-create-password-vault = Защита сохранённых паролей
+title-unlock-password-vault = Разблокировка сохранённых паролей
+title-unlock-config = Разблокировать настройки
 title-server-info = Информация о сервере
 title-user-info = Информация о пользователе
 title-about = О программе
 title-change-password = Изменить пароль
 title-news = Новости
 title-transfers = Передачи
+title-transfer-history = История передач
 title-news-create = Создать Публикацию
 title-news-edit = Редактировать Публикацию
 title-channel-members = Участники
@@ -58,10 +89,14 @@ title-channel-members = Участники
 
 placeholder-username = Имя пользователя
 placeholder-password = Пароль
+placeholder-master-passphrase = Мастер-фраза
+placeholder-confirm-master-passphrase = Подтвердите мастер-фразу
 placeholder-port = Порт
 placeholder-server-address = Адрес сервера
 placeholder-server-name = Имя сервера
+placeholder-folder-name = Имя папки
 placeholder-username-optional = Имя пользователя (необязательно)
+placeholder-fallback-addresses-optional = Резервные адреса (необязательно, через запятую)
 placeholder-password-optional = Пароль (необязательно)
 placeholder-password-keep-current = Пароль
 placeholder-current-password = Текущий пароль
@@ -70,10 +105,18 @@ placeholder-confirm-password = Подтвердите пароль
 placeholder-message = Введите сообщение…
 placeholder-no-permission = Нет разрешения
 placeholder-broadcast-message = Сообщение рассылки
+placeholder-broadcast-channel = Выберите канал
+placeholder-broadcast-permission = Выберите право
 placeholder-server-description = Описание сервера
 placeholder-news-body = Содержимое новости
+placeholder-news-caption = Подпись (необязательно)
+placeholder-news-file-path = Путь в файловой области
 placeholder-nickname-optional = Псевдоним (опционально)
+placeholder-status-optional = Статус (необязательно)
+placeholder-download-folder-optional = Папка загрузок (опционально)
 placeholder-proxy-address = Адрес прокси
+placeholder-notification-rules-channel = #канал
+placeholder-notification-rules-keyword = Ключевое слово
 placeholder-proxy-port = 9050
 placeholder-proxy-username = Имя пользователя (необязательно)
 placeholder-proxy-password = Пароль (необязательно)
@@ -86,13 +129,20 @@ placeholder-auto-join-channels = #канал1 #канал2 (через проб
 # =============================================================================
 
 label-auto-connect = Автоподключение
+label-server-avatar = Аватар:
 label-add-bookmark = Добавить закладку
+label-lan-servers = В этой сети
+label-use-sso = Единый вход (SSO)
 label-admin = Администратор
 label-shared-account = Общий Аккаунт
 label-enabled = Включён
+label-erase-personal-data = Также стереть личные данные
+label-broadcast-require-ack = Просить получателей подтвердить
 label-permissions = Разрешения:
 label-expected-fingerprint = Ожидаемый отпечаток:
 label-received-fingerprint = Полученный отпечаток:
+label-certificate-fingerprint = Отпечаток сертификата:
+label-identity-key = Ключ идентификации:
 label-theme = Тема
 label-chat-history-retention = История чата:
 chat-history-forever = Навсегда
@@ -100,6 +150,10 @@ chat-history-30-days = 30 дней
 chat-history-14-days = 14 дней
 chat-history-7-days = 7 дней
 chat-history-disabled = Отключено
+collision-policy-rename = Сохранить оба (переименовать)
+collision-policy-overwrite = Перезаписать
+collision-policy-skip = Пропустить
+collision-policy-ask = Спрашивать
 label-max-scrollback = Макс. история:
 label-chat-font-size = Размер шрифта:
 label-show-connection-events = Показывать события подключения/отключения
@@ -107,6 +161,19 @@ label-show-channel-events = Показывать события входа/вы
 label-show-timestamps = Показывать время
 label-use-24-hour-time = Использовать 24-часовой формат
 label-show-seconds = Показывать секунды
+label-split-view = Показывать файлы, новости и монитор подключений рядом с чатом
+label-spell-check = Проверять орфографию в чате и новостях
+label-spell-check-no-dictionary = Словарь для вашего языка не найден. Поместите файлы .aff и .dic в папку dictionaries рядом с config.json.
+label-send-read-receipts = Отправлять уведомления о прочтении личных сообщений
+label-user-list-grouping = Группировка списка:
+label-user-list-sort = Сортировка списка:
+user-list-group-none = Нет
+user-list-group-admins = Сначала админы
+user-list-group-channel = По каналам
+user-list-group-status = По статусу
+user-list-sort-alphabetical = По алфавиту
+user-list-sort-idle = Время простоя
+user-list-sort-connect = Время подключения
 label-server-name = Название:
 label-server-description = Описание:
 label-server-version = Версия:
@@ -141,6 +208,27 @@ tab-files = Файлы
 tab-channels = Каналы
 label-connections-short = Подключения:
 label-transfers-short = Передачи:
+label-active-transfers-short = Активные:
+label-queue-order-short = Порядок очереди:
+label-trusted-weight-short = Вес доверенных:
+label-nat-mapping-short = Проброс портов:
+label-nat-external-ip-short = Внешний IP:
+label-nat-not-mapped = Не проброшен
+connectivity-check-running = Проверка портов…
+check-service-bbs = BBS
+check-service-transfer = Передачи
+check-service-websocket = WebSocket
+check-service-transfer-websocket = Передачи WebSocket
+check-service-voice = Голос
+check-state-open = Открыт
+check-state-closed = Закрыт
+check-state-filtered = Фильтруется
+check-state-untested = Не проверен
+label-check-port = { $service } ({ $protocol } { $port }):
+label-queue-order-small-first = Сначала маленькие файлы
+label-queue-order-arrival = По порядку поступления
+label-small-files-first = Ставить маленькие файлы в очередь первыми
+label-unlimited = Без ограничений
 label-reindex-short = Переиндекс.:
 label-persistent-short = Постоянные:
 label-auto-join-short = Авто-вход:
@@ -157,6 +245,19 @@ label-download-location = Папка загрузок:
 label-queue-transfers = Очередь Передач
 label-download-limit = Лимит загрузок:
 label-upload-limit = Лимит отправок:
+label-auto-open-folder = Открывать папку по завершении загрузки
+label-collision-policy = Если файл существует:
+label-require-fingerprint-approval = Спрашивать перед доверием сертификату нового сервера
+label-known-servers = Известные серверы
+known-servers-empty = Сохранённых сертификатов серверов пока нет
+known-server-first-seen = Впервые замечен { $date }
+known-server-first-seen-unknown = Впервые замечен: неизвестно
+label-encrypt-config = Шифровать настройки, закладки и историю чата мастер-фразой
+config-encryption-locked-note = Настройки заблокированы. Перезапустите Nexus и введите мастер-фразу, чтобы изменить это.
+label-upload-rule-extensions = Разрешённые типы файлов:
+label-upload-rule-max-file-size = Макс. размер файла (МБ):
+label-upload-rule-max-total-size = Макс. размер загрузки (МБ):
+label-auto-download-pattern = Имена файлов:
 label-persistent-channels = Постоянные каналы:
 label-auto-join-channels = Авто-вход в каналы:
 label-channels = Каналы
@@ -170,11 +271,14 @@ permission-user_info = Инфо Пользователя
 permission-chat_send = Отправка в Чате
 permission-chat_receive = Получение Чата
 permission-chat_join = Присоединение к Каналу
+permission-chat_language = Язык чата
 permission-chat_create = Создание Канала
 permission-chat_list = Список Каналов
 permission-chat_secret = Секретный Канал
 permission-chat_topic = Тема Чата
 permission-chat_topic_edit = Редактирование Темы Чата
+permission-chat_topic_lock = Блокировка Темы Чата
+permission-chat_entry_edit = Редактирование Приветствия Чата
 permission-user_broadcast = Рассылка Пользователя
 permission-user_create = Создание Пользователя
 permission-user_delete = Удаление Пользователя
@@ -185,6 +289,7 @@ permission-news_list = Список Новостей
 permission-news_create = Создать Новость
 permission-news_edit = Редактировать Новость
 permission-news_delete = Удалить Новость
+permission-file_comment = Комментирование файлов
 permission-file_copy = Копирование Файла
 permission-file_create_dir = Каталог Создать
 permission-file_delete = Удалить Файл
@@ -204,6 +309,11 @@ permission-trust_create = Создать Доверие
 permission-trust_delete = Удалить Доверие
 permission-trust_list = Список Доверия
 permission-connection_monitor = Монитор Подключений
+permission-log_view = Просмотр журнала
+permission-door_play = Запуск дверей
+permission-voice_broadcast = Голосовая трансляция
+permission-voice_clip_upload = Загрузка Голосовых Клипов
+permission-voice_limit = Голосовой Лимит
 permission-voice_listen = Голосовое Прослушивание
 permission-voice_talk = Голосовой Разговор
 
@@ -228,19 +338,31 @@ tooltip-files-paste = Вставить
 tooltip-files-search = Поиск файлов
 tooltip-manage-users = Управление Пользователями
 tooltip-connection-monitor = Монитор Подключений
+tooltip-server-log = Журнал сервера
 tooltip-server-info = Информация о Сервере
 tooltip-about = О программе
 tooltip-transfers = Передачи
 tooltip-download-all = Скачать всё
 tooltip-upload = Загрузить
 file-picker-upload-title = Выберите файлы для загрузки
+file-picker-download-to-title = Выберите папку загрузок
+folder-picker-upload-watch-title = Выберите папку для автоматической загрузки
 drop-to-upload = Отпустите для загрузки
 tooltip-clear-inactive = Очистить неактивные
+tooltip-transfer-history = История передач
+tooltip-transfer-history-clear = Очистить историю
+tooltip-transfer-history-remove = Удалить из истории
 tooltip-transfer-pause = Пауза
 tooltip-transfer-resume = Продолжить
 tooltip-transfer-cancel = Отменить
 tooltip-transfer-remove = Удалить
 tooltip-transfer-open-folder = Открыть папку
+tooltip-transfer-open-file = Открыть файл
+tooltip-transfer-reveal = Показать в файловом менеджере
+tooltip-transfer-copy-checksum = Копировать SHA-256
+tooltip-transfer-collision-rename = Сохранить оба (переименовать)
+tooltip-transfer-collision-overwrite = Перезаписать существующие файлы
+tooltip-transfer-collision-skip = Пропустить существующие файлы
 tooltip-transfer-move-up = Вверх
 tooltip-transfer-move-down = Вниз
 tooltip-transfer-retry = Повторить
@@ -253,16 +375,34 @@ tooltip-disconnect = Отключиться
 tooltip-edit = Редактировать
 tooltip-info = Инфо
 tooltip-message = Сообщение
+tooltip-add-friend = Добавить в друзья
+tooltip-remove-friend = Удалить из друзей
 tooltip-kick = Выгнать
 tooltip-close = Закрыть
+tooltip-retry-send = Отправить снова
+tooltip-cancel-send = Отменить отправку
+tooltip-message-sent = Отправлено
+tooltip-message-delivered = Доставлено
+tooltip-message-read = Прочитано
 tooltip-new-tab = Новая вкладка
 tooltip-close-tab = Закрыть вкладку
 tooltip-add-bookmark = Добавить закладку
+tooltip-new-folder = Новая папка
 tooltip-create-user = Создать пользователя
+tooltip-permission-audit = Аудит прав
 tooltip-create-news = Создать публикацию
+tooltip-news-search = Поиск новостей
+tooltip-news-search-clear = Очистить поиск
 tooltip-delete = Удалить
 context-menu-download = Скачать
+context-menu-download-to = Скачать в…
+context-menu-download-select = Скачать выбранное…
+context-menu-auto-download = Автозагрузка…
 context-menu-upload = Загрузить
+context-menu-watch-folder = Отслеживать локальную папку…
+context-menu-screenshot-folder = Делиться скриншотами здесь
+context-menu-unwatch-folder = Прекратить отслеживание папки
+context-menu-upload-rules = Правила загрузки…
 context-menu-open = Открыть
 
 # =============================================================================
@@ -270,6 +410,7 @@ context-menu-open = Открыть
 # =============================================================================
 
 panel-connection-monitor = Монитор Подключений
+panel-server-log = Журнал сервера
 panel-active-connections = Активные Подключения: { $count }
 tab-connections = Подключения
 tab-transfers = Передачи
@@ -284,9 +425,101 @@ menu-info = Информация
 menu-copy = Копировать
 menu-kick = Выгнать
 menu-ban = Заблокировать
+menu-pop-out = Открепить
+menu-mute-tab = Отключить уведомления
+menu-unmute-tab = Включить уведомления
+menu-connect-all = Подключить все
+menu-rename = Переименовать
+menu-delete = Удалить
 connection-monitor-loading = Загрузка подключений…
 connection-monitor-no-connections = Нет подключений
 connection-monitor-no-transfers = Нет активных передач
+connection-monitor-queued = В очереди
+connection-monitor-weight = Вес справедливой доли: ×{ $weight }
+connection-monitor-listed = В списке { $zone }
+connection-monitor-queued-count = в очереди: { $count }
+placeholder-server-log-module = Фильтр по модулю
+server-log-loading = Загрузка журнала сервера…
+server-log-empty = Нет записей журнала
+server-log-level-error = Только ошибки
+server-log-level-warn = Предупреждения и выше
+server-log-level-info = Информация и выше
+server-log-level-debug = Всё (отладка)
+panel-statistics = Статистика
+stats-loading = Загрузка статистики…
+panel-snippet = Сниппет
+snippet-loading = Загрузка сниппета…
+snippet-details = Поделился { $author } · истекает { $expires }
+stats-range-days = Последние { $days } дн.
+stats-chart-max = Максимум за день: { $value }
+stats-metric-logins = Входы
+stats-metric-messages = Сообщения
+stats-metric-uploads = Загрузки на сервер
+stats-metric-upload-bytes = Загружено данных
+stats-metric-downloads = Скачивания
+stats-metric-download-bytes = Скачано данных
+stats-metric-peak-sessions = Пик пользователей онлайн
+panel-admin-dashboard = Панель администратора
+dashboard-loading = Загрузка панели…
+dashboard-connections = Подключения
+dashboard-sessions = Пользователи онлайн
+dashboard-connection-count = Подключения
+dashboard-transfer-connections = Подключения передачи
+dashboard-transfers = Передачи
+dashboard-active-transfers = Выполняются
+dashboard-queued-transfers = В очереди
+dashboard-today = Сегодня
+dashboard-disk = Файловая область
+dashboard-disk-free = Свободно
+dashboard-disk-free-of = Свободно { $free } из { $total }
+dashboard-disk-unknown = Неизвестно
+dashboard-recent-audit = Последние события аудита
+dashboard-audit-empty = Событий аудита пока нет
+dashboard-updated = Обновлено в { $time }
+panel-doors = Двери
+doors-loading = Загрузка дверей…
+doors-empty = На этом сервере нет дверей
+doors-starting = Запуск…
+doors-playing-hint = Нажатия клавиш передаются двери. Чтобы выйти, нажмите «Выйти».
+doors-exited = Дверь завершилась
+doors-exited-code = Дверь завершилась с кодом { $code }
+
+# =============================================================================
+# Quick Switcher
+# =============================================================================
+
+quick-switcher-placeholder = Перейти к каналу, сообщению, закладке или панели...
+user-list-filter-placeholder = Фильтр пользователей...
+user-list-section-admins = Админы
+user-list-section-users = Пользователи
+user-list-section-no-channel = Другие
+user-list-section-online = В сети
+user-list-section-away = Отошёл
+user-list-section-dnd = Не беспокоить
+user-list-idle = Неактивен { $duration }
+quick-switcher-no-matches = Ничего не найдено
+quick-switcher-bookmark = Закладка
+
+# =============================================================================
+# Chat Input
+# =============================================================================
+
+chat-paste-prompt = Вставить { $count } { $count ->
+chat-paste-too-long-prompt = Слишком длинно для одного сообщения (макс. { $max }). Всё равно отправить?
+    [one] строку
+    [few] строки
+   *[other] строк
+}?
+button-send-as-messages = Отправить отдельными сообщениями ({ $count })
+button-send-as-one-message = Отправить одним сообщением
+button-send-in-parts = Отправить частями: { $count }
+button-share-as-snippet = Поделиться как сниппет
+button-expand-code = Показать все строки ({ $count })
+button-collapse-code = Свернуть
+chat-broadcast-ack-prompt = { $username } просит вас подтвердить рассылку
+button-acknowledge = Подтвердить
+button-dismiss = Закрыть
+spell-no-suggestions = Нет вариантов
 
 # =============================================================================
 # Empty States
@@ -297,11 +530,35 @@ empty-no-connections = Нет подключений
 empty-no-bookmarks = Нет закладок
 empty-no-users = Нет пользователей онлайн
 empty-no-channel-members = Нет участников в канале
+empty-no-matching-users = Нет подходящих пользователей
 user-management-loading = Загрузка пользователей…
 user-management-no-users = Пользователи не найдены
+permission-audit-hint = Выберите право, чтобы увидеть, у кого оно есть, или нажмите на пользователя, чтобы увидеть всё, что он может делать.
+permission-audit-loading = Загрузка…
+permission-audit-no-holders = Ни у одной учётной записи нет этого права
+permission-audit-effective = Действующие права { $username }
+permission-audit-admin = Администраторы имеют все права
+permission-audit-no-permissions = У этой учётной записи нет прав
+permission-audit-restricted = Выданы, но недоступны для общих учётных записей:
+permission-audit-disabled = Отключена
+impersonate-notice = Просмотр того, что видит эта учётная запись, только для чтения. Каждый просмотр записывается в журнал аудита.
+impersonate-permissions = Права
+impersonate-files = Файлы ({ $path })
+impersonate-no-file-access = Эта учётная запись не может просматривать файлы
+impersonate-empty-folder = Эта папка пуста
+impersonate-channels = Каналы
+impersonate-no-channel-access = Эта учётная запись не может просматривать список каналов
+impersonate-no-channels = Нет видимых каналов
+impersonate-secret = Секретный
 news-loading = Загрузка новостей…
 news-no-posts = Пока нет публикаций
+news-search-placeholder = Поиск новостей… (author:имя since:ГГГГ-ММ-ДД until:ГГГГ-ММ-ДД)
+news-searching = Поиск…
+news-search-no-results = Подходящих публикаций нет
 transfers-empty = Нет передач
+transfer-history-search-placeholder = Поиск по имени, серверу или папке…
+transfer-history-empty = Завершённых передач пока нет
+transfer-history-no-matches = Нет передач, соответствующих запросу
 news-updated = Обновлено
 files-loading = Загрузка файлов…
 files-empty = Эта папка пуста
@@ -313,10 +570,20 @@ files-column-name = Имя
 files-column-size = Размер
 files-column-modified = Изменён
 files-column-path = Путь
+files-column-rating = Оценка
 files-search-placeholder = Поиск файлов…
 files-search-breadcrumb = Поиск - { $query }
 files-searching = Поиск…
 files-no-results = Файлы не найдены
+files-highlights = Подборка
+files-highlights-recent = Новые загрузки за неделю
+files-highlights-popular = Самые скачиваемые
+files-highlights-empty = Пока ничего нет
+files-highlights-downloads = { $count } { $count ->
+    [one] скачивание
+    [few] скачивания
+   *[other] скачиваний
+}
 files-search-query-too-short = Поисковый запрос слишком короткий (мин. { $min_length } символов)
 files-search-query-too-long = Поисковый запрос слишком длинный (макс. { $max_length } символов)
 files-search-query-invalid = Поисковый запрос содержит недопустимые символы
@@ -327,6 +594,24 @@ files-share = Поделиться
 files-rename = Переименовать
 files-rename-title = Переименовать "{ $name }"
 files-rename-placeholder = Новое имя
+files-upload-rule-title = Правила загрузки для "{ $name }"
+files-upload-rule-loading = Загрузка…
+files-upload-rule-extensions-placeholder = Любой тип (например, mp3, flac)
+files-upload-rule-no-limit = Без ограничений
+files-upload-rule-remove = Удалить правило
+files-upload-rule-help = Правила действуют для этой папки и её подпапок, если у подпапки нет собственных.
+files-auto-download-title = Автозагрузка из «{ $name }»
+files-auto-download-pattern-placeholder = например, *.mp3, *.m4a
+files-auto-download-remove = Остановить автозагрузку
+files-auto-download-help = Новые файлы в этой папке с подходящими именами скачиваются, пока вы подключены через эту закладку. * соответствует любому тексту, ? — одному символу; разделяйте шаблоны запятыми. Уже скачанные файлы пропускаются.
+files-upload-rule-hint = Загрузка сюда: { $limits }
+files-upload-rule-hint-types = только { $extensions }
+files-upload-rule-hint-file-size = до { $size } на файл
+files-upload-rule-hint-total-size = до { $size } за загрузку
+files-upload-preview-title = Файлы уже существуют
+files-upload-preview-comparing = Сравнение с файлами на сервере…
+files-upload-preview-summary = Новых: { $new }, уже на сервере: { $identical }, отличаются: { $conflicts }:
+files-upload-preview-blocked = Файлов с одноимённой папкой на сервере: { $count } — они будут пропущены
 files-cut = Вырезать
 files-copy = Копировать
 files-paste = Вставить
@@ -336,6 +621,13 @@ files-delete-confirm-title = Подтверждение удаления
 files-delete-confirm-message = Вы уверены, что хотите удалить "{ $name }"?
 # File Overwrite Dialog
 files-overwrite-title = Файл уже существует
+files-download-to-title = Скачать { $name }
+label-remember-for-server = Запомнить для этого сервера
+label-select-all = Выбрать все
+files-download-select-summary = Выбрано { $count } из { $total } ({ $size })
+files-download-select-too-many = Можно выбрать не более { $max } отдельных файлов. Скачайте папку целиком.
+files-download-duplicate-title = Уже скачано
+files-download-duplicate-message = «{ $name }» уже есть на этом компьютере: { $path }. Скачать его снова?
 files-overwrite-message = Файл или папка с именем "{ $name }" уже существует в месте назначения. Хотите заменить?
 
 # File Info Dialog
@@ -351,6 +643,18 @@ files-info-directory = Каталог
 files-info-symlink = Симв. ссылка:
 files-info-yes = Да
 files-info-na = Н/Д
+files-info-rating = Оценка:
+files-info-rating-value = { $average } ({ $count } { $count ->
+    [one] оценка
+    [few] оценки
+   *[other] оценок
+})
+files-info-not-rated = Ещё не оценено
+files-info-your-rating = Ваша оценка:
+files-info-comments = Комментарии ({ $count })
+files-comments-loading = Загрузка комментариев…
+files-comments-empty = Комментариев пока нет
+files-comment-placeholder = Напишите комментарий…
 
 # =============================================================================
 # Directory Name Validation Errors
@@ -369,6 +673,7 @@ err-dir-name-invalid = Имя папки содержит недопустимы
 tab-general = Общие
 tab-chat = Чат
 tab-network = Сеть
+tab-security = Безопасность
 tab-audio = Аудио
 
 # =============================================================================
@@ -390,6 +695,8 @@ chat-prefix-system = [СИС]
 chat-prefix-error = [ОШБ]
 chat-prefix-info = [ИНФ]
 chat-prefix-broadcast = [BROADCAST]
+chat-prefix-queued = [PENDING]
+chat-export-subtitle = Экспортировано с { $server } { $date }
 
 # =============================================================================
 # Success Messages
@@ -414,11 +721,28 @@ msg-channel-member-count = { $count } { $count ->
    *[other] участников
 }
 channel-secret = секретный
+channel-info-topic = Тема
+channel-info-rules = Правила
+channel-info-language = Язык
 msg-broadcast-sent = Рассылка успешно отправлена
+msg-broadcast-delivered = Рассылка доставлена { $count } { $count ->
+    [one] пользователю
+   *[other] пользователям
+}
+msg-broadcast-scheduled = Рассылка запланирована на { $time }
+msg-broadcast-acknowledged = Рассылку подтвердили { $count } из { $delivered } пользователей
+msg-storage-low = Мало места на диске сервера для { $volume }: свободно { $free }, меньше минимума { $threshold }
+msg-storage-recovered = Место на диске сервера для { $volume } освободилось: свободно { $free }
+storage-volume-files = файловой области
+storage-volume-database = базы данных
+msg-friend-added = { $username } добавлен в друзья
+msg-friend-removed = { $username } удалён из друзей
 msg-user-created = Пользователь успешно создан
 msg-user-created-name = Пользователь '{ $username }' успешно создан
 msg-user-deleted = Пользователь успешно удалён
 msg-user-deleted-name = Пользователь '{ $username }' успешно удалён
+msg-user-erased = Пользователь удалён, личные данные стёрты
+msg-user-erased-name = Пользователь '{ $username }' удалён, личные данные стёрты
 msg-user-updated = Пользователь успешно обновлён
 msg-user-updated-name = Пользователь '{ $username }' успешно обновлён
 msg-permissions-updated = Ваши разрешения были обновлены
@@ -433,6 +757,8 @@ msg-welcome-back = С возвращением
 msg-status-set = Статус установлен: { $status }
 msg-status-cleared = Статус очищен
 msg-user-is-away = { $nickname } отошёл
+msg-group-member-joined = { $nickname } присоединился к беседе
+msg-group-member-left = { $nickname } покинул беседу
 msg-user-is-away-status = { $nickname } отошёл: { $status }
 
 
@@ -444,6 +770,14 @@ msg-topic-cleared = Тема очищена пользователем { $userna
 msg-topic-set = Тема установлена пользователем { $username }: { $topic }
 msg-secret-set = Секретный режим включён пользователем { $username }
 msg-secret-cleared = Секретный режим отключён пользователем { $username }
+msg-topic-locked = Тема заблокирована пользователем { $username }
+msg-topic-unlocked = Тема разблокирована пользователем { $username }
+msg-rules-set = Правила канала обновлены пользователем { $username }
+msg-rules-cleared = Правила канала очищены пользователем { $username }
+msg-language-set = { $username } установил язык канала: { $language }
+msg-language-cleared = { $username } сбросил язык канала
+msg-voice-limit-set = Голосовой лимит установлен на { $limit } пользователем { $username }
+msg-voice-limit-cleared = Голосовой лимит снят пользователем { $username }
 msg-server-info-updated = Конфигурация сервера обновлена
 msg-topic-display = Тема: { $topic }
 confirm-delete-user = Вы уверены, что хотите удалить пользователя '{ $username }'?
@@ -463,6 +797,7 @@ err-user-kick-failed = Не удалось выгнать пользовател
 err-unknown = Неизвестная ошибка
 err-no-shutdown-handle = Ошибка подключения: Нет дескриптора завершения
 err-userlist-failed = Не удалось обновить список пользователей
+err-request-timeout = Сервер не ответил вовремя
 err-port-invalid = Порт должен быть допустимым числом (1-65535)
 
 # Network connection errors
@@ -476,12 +811,16 @@ err-connection-closed = Соединение закрыто
 err-could-not-determine-config-dir = Не удалось определить каталог конфигурации
 err-message-too-long = Сообщение слишком длинное ({ $length } символов, макс { $max })
 err-send-failed = Не удалось отправить сообщение
+err-checksum-failed = Не удалось вычислить контрольную сумму
 err-no-chat-permission = У вас нет разрешения отправлять сообщения
+err-chat-window-command = Из окна чата можно отправлять только сообщения и /me
 err-console-no-send = Используйте /{ $join } для входа в канал или /{ $msg } для отправки сообщения пользователю
 err-broadcast-too-long = Объявление слишком длинное ({ $length } символов, макс { $max })
 err-broadcast-send-failed = Не удалось отправить рассылку
+err-broadcast-target-required = Выберите, кто получит рассылку
 err-name-required = Требуется имя закладки
 err-address-required = Требуется адрес сервера
+err-fallback-address-invalid = Недопустимый резервный адрес: { $address }
 err-port-required = Требуется порт
 err-username-required = Требуется имя пользователя
 err-password-required = Требуется пароль
@@ -499,13 +838,20 @@ err-nickname-too-long = Псевдоним слишком длинный (мак
 err-nickname-invalid = Псевдоним содержит недопустимые символы
 err-password-too-long = Пароль слишком длинный (макс { $max } символов)
 err-passwords-do-not-match = Пароли не совпадают
+err-wrong-master-passphrase = Неверная мастер-фраза
+err-master-passphrase-empty = Мастер-фраза не может быть пустой
+err-password-vault = Ошибка хранилища паролей: { $error }
 err-current-password-required = Текущий пароль обязателен
 err-new-password-required = Новый пароль обязателен
 err-confirm-password-required = Пожалуйста, подтвердите новый пароль
 err-topic-too-long = Тема слишком длинная ({ $length } символов, макс { $max })
+err-entry-message-too-long = Приветственное сообщение слишком длинное ({ $length } символов, макс. { $max })
+err-rules-too-long = Правила слишком длинные ({ $length } символов, макс. { $max })
 err-avatar-unsupported-type = Неподдерживаемый тип файла. Используйте PNG, WebP, JPEG или SVG.
 err-avatar-too-large = Аватар слишком большой. Максимальный размер { $max_kb }КБ.
 err-avatar-decode-failed = Не удалось декодировать аватар. Возможно, файл повреждён.
+err-known-server-export = Не удалось экспортировать сертификат: { $error }
+err-config-encryption = Не удалось зашифровать настройки: { $error }
 err-server-name-empty = Имя сервера не может быть пустым
 err-server-name-too-long = Название сервера слишком длинное (макс. { $max } символов)
 err-server-name-contains-newlines = Название сервера не может содержать переносы строк
@@ -526,14 +872,23 @@ err-failed-send-update = Не удалось отправить обновлен
 # =============================================================================
 
 err-failed-save-config = Не удалось сохранить конфигурацию: { $error }
+err-friends-no-bookmark = Друзья хранятся в закладке сервера; сначала добавьте сервер в закладки
+err-friends-too-many = Слишком много друзей (макс. { $max })
+err-friend-exists = { $username } уже в друзьях
+err-friend-not-found = { $username } нет в друзьях
 err-failed-save-settings = Не удалось сохранить настройки: { $error }
 err-invalid-port-bookmark = Недопустимый порт в закладке: { $name }
 err-failed-send-broadcast = Не удалось отправить рассылку: { $error }
 err-failed-send-message = Не удалось отправить сообщение: { $error }
+err-failed-start-group = Не удалось начать групповую беседу: { $error }
+err-failed-share-snippet = Не удалось поделиться сниппетом: { $error }
 err-failed-create-user = Не удалось создать пользователя: { $error }
 err-failed-delete-user = Не удалось удалить пользователя: { $error }
 err-failed-update-user = Не удалось обновить пользователя: { $error }
 err-failed-update-topic = Не удалось обновить тему: { $error }
+err-failed-lock-topic = Не удалось заблокировать тему: { $error }
+err-failed-set-language = Не удалось установить язык канала: { $error }
+err-failed-update-entry = Не удалось обновить канал: { $error }
 err-message-too-long-details = { $error } ({ $length } символов, макс { $max })
 
 # Network connection errors (with parameters)
@@ -549,6 +904,14 @@ err-tls-handshake-failed = Ошибка TLS-рукопожатия: { $error }
 err-failed-send-handshake = Не удалось отправить рукопожатие: { $error }
 err-failed-read-handshake = Не удалось прочитать ответ рукопожатия: { $error }
 err-handshake-failed = Ошибка рукопожатия: { $error }
+err-proof-of-work-too-hard = Сервер запросил слишком сложное доказательство работы (сложность { $difficulty })
+err-sso-not-offered = Этот сервер не поддерживает единый вход
+err-sso-failed = Ошибка единого входа: { $error }
+err-sso-browser = Не удалось открыть браузер: { $error }
+err-sso-timeout = Истекло время ожидания браузера при едином входе
+err-sso-state-mismatch = Ответ единого входа не соответствует запросу
+sso-browser-done = Вход выполнен. Можно закрыть это окно и вернуться в Nexus.
+sso-browser-failed = Не удалось войти. Можно закрыть это окно и вернуться в Nexus.
 err-failed-parse-handshake = Не удалось разобрать ответ рукопожатия: { $error }
 err-failed-send-login = Не удалось отправить данные для входа: { $error }
 err-failed-read-login = Не удалось прочитать ответ при входе: { $error }
@@ -556,6 +919,7 @@ err-failed-parse-login = Не удалось разобрать ответ пр
 err-failed-create-server-name = Не удалось создать имя сервера: { $error }
 err-failed-create-config-dir = Не удалось создать каталог конфигурации: { $error }
 err-failed-serialize-config = Не удалось сериализовать конфигурацию: { $error }
+err-config-locked = Настройки зашифрованы и заблокированы; изменения нельзя сохранить до разблокировки
 err-failed-write-config = Не удалось записать файл конфигурации: { $error }
 err-failed-read-config-metadata = Не удалось прочитать метаданные файла конфигурации: { $error }
 err-failed-set-config-permissions = Не удалось установить права доступа к файлу конфигурации: { $error }
@@ -565,6 +929,10 @@ err-failed-set-config-permissions = Не удалось установить п
 # =============================================================================
 
 fingerprint-warning = Это может указывать на проблему безопасности (атака MITM) или на то, что сертификат сервера был перегенерирован. Принимайте только если доверяете администратору сервера.
+fingerprint-new-server-prompt = Это первое подключение к этому серверу. Доверяйте сертификату, только если отпечаток ниже совпадает с опубликованным администратором сервера.
+password-vault-create-description = Системная связка ключей недоступна. Задайте мастер-фразу, чтобы зашифровать сохранённые пароли закладок, или пропустите, чтобы оставить их в файле конфигурации.
+password-vault-unlock-description = Введите мастер-фразу, чтобы разблокировать сохранённые пароли закладок. Если пропустить, закладки будут подключаться без паролей до перезапуска Nexus.
+config-unlock-description = Ваши настройки, закладки и история чата зашифрованы. Введите мастер-фразу, чтобы разблокировать их. Если пропустить, Nexus запустится с настройками по умолчанию и ничего не будет сохраняться до перезапуска.
 
 # =============================================================================
 # User Info Display
@@ -581,10 +949,12 @@ user-info-account = Аккаунт: { $username }
 user-info-connected = Подключён:
 user-info-connected-value = { $duration } назад
 user-info-connected-value-sessions = { $duration } назад ({ $count } сеансов)
+user-info-idle = Неактивен:
 user-info-features = Возможности:
 user-info-features-value = { $features }
 user-info-features-none = Нет
 user-info-locale = Язык:
+user-info-client = Клиент:
 user-info-address = Адрес:
 user-info-addresses = Адреса:
 user-info-created = Создан:
@@ -593,6 +963,12 @@ user-info-unknown = Неизвестно
 user-info-loading = Загрузка информации о пользователе…
 user-info-status = Статус:
 user-info-channels = Каналы:
+user-info-rank = Ранг:
+user-info-logins = Входы:
+user-info-messages = Сообщения:
+user-info-uploads = Загрузки:
+user-info-downloaded = Скачано:
+user-info-last-login = Последний вход:
 
 # =============================================================================
 # Time Duration
@@ -632,26 +1008,51 @@ cmd-me-usage = Использование: /{ $command } <действие>
 err-me-no-target = Используйте /{ $join } для входа в канал или /{ $msg } для отправки действия пользователю
 cmd-message-desc = Отправить сообщение пользователю
 cmd-message-usage = Использование: /{ $command } <имя_пользователя> <сообщение>
+cmd-group-desc = Начать групповую беседу с несколькими пользователями
+cmd-group-usage = Использование: /{ $command } <имя_пользователя> [<имя_пользователя>...]
+cmd-invite-desc = Добавить пользователя в текущую групповую беседу
+cmd-invite-usage = Использование: /{ $command } <имя_пользователя>
 cmd-ping-desc = Измерить задержку до сервера
 cmd-ping-usage = Использование: /{ $command }
+cmd-radio-desc = Транслировать звук слушателям голосового канала
+cmd-radio-usage = Использование: /{ $command }
+err-radio-no-channel = Эта команда работает только в канале
+err-radio-in-voice = Выйдите из голосового чата, прежде чем начинать трансляцию
 ping-result = Ответ: { $ms }мс
 cmd-userinfo-desc = Показать информацию о пользователе
 cmd-userinfo-usage = Использование: /{ $command } <имя_пользователя>
 cmd-kick-desc = Отключить пользователя от сервера
 cmd-kick-usage = Использование: /{ $command } <имя_пользователя> [причина]
+cmd-top-desc = Показать самых активных пользователей
+cmd-top-usage = Использование: /{ $command } [logins|messages|uploads|downloaded]
 cmd-topic-desc = Просмотр или управление темой чата
-cmd-topic-usage = Использование: /{ $command } [установить|очистить] [тема]
+cmd-topic-usage = Использование: /{ $command } [установить|очистить|заблокировать|разблокировать|история] [тема]
 cmd-topic-arg-set = установить
 cmd-topic-arg-clear = очистить
+cmd-topic-arg-lock = заблокировать
+cmd-topic-arg-unlock = разблокировать
+cmd-topic-arg-history = история
 cmd-topic-set-usage = Использование: /{ $command } установить <тема>
 cmd-topic-none = Тема не установлена
 cmd-broadcast-desc = Отправить сообщение всем пользователям
 cmd-broadcast-usage = Использование: /{ $command } <сообщение>
 cmd-clear-desc = Очистить историю чата текущей вкладки
 cmd-clear-usage = Использование: /{ $command }
+cmd-export-desc = Сохранить текущую вкладку в HTML-файл
+cmd-export-usage = Использование: /{ $command }
+cmd-clip-desc = Список, воспроизведение, добавление и удаление клипов звуковой панели
+cmd-clip-usage = Использование: /{ $command } [список|играть <имя>|добавить <имя>|удалить <имя>|<имя>]
+cmd-clip-arg-list = список
+cmd-clip-arg-play = играть
+cmd-clip-arg-add = добавить
+cmd-clip-arg-delete = удалить
 cmd-focus-desc = Переключиться на чат сервера или окно сообщений пользователя
 cmd-focus-usage = Использование: /{ $command } [имя_пользователя]
 cmd-focus-not-found = Пользователь не найден: { $name }
+cmd-friend-desc = Показать, добавить или удалить друзей
+cmd-friend-usage = Использование: /{ $command } [list] | add <имя> | remove <имя>
+cmd-friend-list-header = Друзья:
+cmd-friend-list-empty = Друзей пока нет. Добавьте с помощью /{ $command } add <имя>
 cmd-list-desc = Показать подключённых/всех пользователей
 cmd-list-arg-all = все
 cmd-list-usage = Использование: /{ $command } [все]
@@ -669,6 +1070,13 @@ cmd-list-all-output = Пользователи: { $users } ({ $count } { $count
 })
 cmd-help-usage = Использование: /{ $command } [команда]
 cmd-topic-permission-denied = У вас нет разрешения на редактирование темы
+cmd-topic-lock-permission-denied = У вас нет разрешения на блокировку темы
+msg-topic-already-locked = Тема уже заблокирована
+msg-topic-already-unlocked = Тема уже разблокирована
+msg-topic-history-header = История темы { $channel }:
+msg-topic-history-set = { $time } { $username }: { $topic }
+msg-topic-history-cleared = { $time } { $username } очистил тему
+msg-topic-history-empty = Изменения темы не записаны
 cmd-window-desc = Управление вкладками чата
 cmd-window-usage = Использование: /{ $command } [следующая|предыдущая|закрыть [имя_пользователя]]
 cmd-window-arg-next = следующая
@@ -697,15 +1105,55 @@ cmd-unban-desc = Снять IP-бан
 cmd-unban-usage = Использование: /{ $command } <цель>
 cmd-bans-desc = Показать активные баны
 cmd-bans-usage = Использование: /{ $command }
+cmd-register-desc = Зарегистрировать учетную запись из гостевой сессии
+cmd-register-usage = Использование: /{ $command } <имя_пользователя> <пароль>
 cmd-reindex-desc = Запустить перестроение индекса файлов
 cmd-reindex-usage = Использование: /{ $command }
+cmd-cleanup-desc = Найти остатки прерванных передач в файловой области
+cmd-cleanup-usage = Использование: /{ $command } [delete]
+cmd-filecheck-desc = Сверить файловую область с поисковым индексом и метаданными файлов
+cmd-filecheck-usage = Использование: /{ $command } [repair]
+cmd-screenshot-desc = Поделиться скриншотом с пометками в текущей вкладке
+cmd-screenshot-usage = Использование: /{ $command }
+err-screenshot-no-target = Скриншотами можно делиться только в канале или во вкладке личных сообщений
+err-screenshot-no-folder = Папка для скриншотов не задана — выберите «Делиться скриншотами здесь» у папки загрузки в разделе «Файлы»
+err-screenshot-capture = Не удалось сделать скриншот: { $error }
+err-screenshot-save = Не удалось сохранить скриншот: { $error }
+err-screenshot-upload = Не удалось загрузить скриншот: { $error }
+err-export-empty = В этой вкладке нет сообщений для экспорта
+err-chat-export = Не удалось экспортировать чат: { $error }
+screenshot-title = Поделиться скриншотом
+screenshot-tool-crop = Обрезать
+screenshot-tool-arrow = Стрелка
+screenshot-hint-crop = Выделите рамкой область, которую нужно оставить
+screenshot-hint-arrow = Проведите от основания к острию стрелки
 msg-reindex-triggered = Перестроение индекса файлов запущено
+msg-cleanup-nothing = Остатков прерванных передач не найдено
+msg-cleanup-found = Найдены остатки прерванных передач:
+msg-cleanup-deleted = Остатки прерванных передач удалены:
+msg-cleanup-parts = Осиротевшие файлы .part: { $count } ({ $size })
+msg-cleanup-temp = Оставшиеся временные файлы: { $count } ({ $size })
+msg-cleanup-journal = Записи журнала загрузок без файла: { $count }
+msg-cleanup-index = Записи поискового индекса без файла: { $count }
+msg-cleanup-hint = Проверьте файлы выше, затем выполните /cleanup delete, чтобы удалить их
+msg-filecheck-issue = { $kind }: { $path }
+msg-filecheck-issue-repaired = { $kind }: { $path } (исправлено)
+msg-filecheck-clean = Проверка файловой области не нашла проблем в { $entries } записях
+msg-filecheck-done = Проверка файловой области нашла проблем: { $issues } в { $entries } записях, исправлено: { $repaired }
+msg-filecheck-index-skipped = Поисковый индекс не сравнивался, так как он ещё не построен или ожидает перестроения
+msg-filecheck-hint = Выполните /filecheck repair, чтобы перестроить индекс и удалить метаданные отсутствующих путей
+filecheck-not-indexed = Нет в поисковом индексе
+filecheck-indexed-but-missing = Есть в поисковом индексе, но нет на диске
+filecheck-index-outdated = Устарело в поисковом индексе
+filecheck-orphaned-activity = История отсутствующего пути
+filecheck-orphaned-reviews = Оценки или комментарии отсутствующего пути
+filecheck-orphaned-upload-rule = Правило загрузки отсутствующей папки
 cmd-join-desc = Присоединиться или создать канал
 cmd-join-usage = Использование: /{ $command } #канал
 cmd-leave-desc = Покинуть канал
 cmd-leave-usage = Использование: /{ $command } [#канал]
 cmd-channels-desc = Показать доступные каналы
-cmd-channels-usage = Использование: /{ $command }
+cmd-channels-usage = Использование: /{ $command } [язык]
 cmd-secret-desc = Переключить секретный режим текущего канала
 cmd-secret-usage = Использование: /{ $command } [вкл|выкл]
 cmd-secret-arg-on = вкл
@@ -718,12 +1166,40 @@ msg-secret-enabled = Канал теперь секретный
 msg-secret-disabled = Канал теперь публичный
 msg-secret-status-on = Этот канал секретный
 msg-secret-status-off = Этот канал публичный
+cmd-voicelimit-desc = Показать или задать лимит участников голосового чата текущего канала
+cmd-voicelimit-usage = Использование: /{ $command } [<число>|выкл]
+cmd-vt-desc = Отправить текстовое сообщение в голосовую сессию
+cmd-vt-usage = Использование: /{ $command } <сообщение>
+cmd-voicelimit-arg-off = выкл
+err-voicelimit-no-channel = Эта команда работает только в канале
+msg-voice-limit-status = В голосовом чате этого канала может быть не более { $limit } пользователей
+msg-voice-limit-status-none = У этого канала нет голосового лимита
+msg-voice-clip-none = Нет клипов звуковой панели
+msg-voice-clip-list = Клипы звуковой панели ({ $count }):
+msg-voice-clip-entry = { $name } ({ $seconds } с, от { $nickname })
+msg-voice-clip-uploaded = Клип добавлен на звуковую панель
+msg-voice-clip-deleted = Клип удалён со звуковой панели
 cmd-trust-desc = Добавить IP, диапазон CIDR или никнейм в доверенные для обхода банов
 cmd-trust-usage = Использование: /{ $command } <цель> [длительность] [причина]
 cmd-untrust-desc = Удалить запись из доверенных IP
 cmd-untrust-usage = Использование: /{ $command } <цель>
 cmd-trusted-desc = Показать доверенные IP
 cmd-trusted-usage = Использование: /{ $command }
+cmd-ldap-desc = Показать, задать или удалить сопоставления групп каталога
+cmd-ldap-usage = Использование: /{ $command } [list|set <права|admin|none> <DN группы>|remove <DN группы>]
+cmd-nickname-desc = Зарезервировать или освободить псевдонимы для вашей учетной записи
+cmd-nickname-usage = Использование: /{ $command } [register <псевдоним> [имя_пользователя]|release <псевдоним>]
+cmd-history-desc = Показать или задать, сколько сервер хранит ваши личные сообщения, или загрузить более ранние
+cmd-history-usage = Использование: /{ $command } [<дни>|more]
+cmd-alias-desc = Создать сокращения для команд и сообщений
+cmd-alias-usage = Использование: /{ $command } [list|set <имя> <текст>|remove <имя>]
+cmd-alias-list-header = Ваши псевдонимы:
+cmd-alias-list-empty = Псевдонимов пока нет. Создайте: /{ $command } set <имя> <текст>
+cmd-alias-set = /{ $name } теперь выполняет: { $text }
+cmd-alias-removed = Псевдоним /{ $name } удалён
+cmd-alias-not-found = Нет псевдонима /{ $name }
+cmd-alias-invalid-name = Имя псевдонима может содержать только буквы, цифры, - и _ (до { $max } символов)
+cmd-alias-builtin = /{ $name } — встроенная команда, её нельзя использовать как псевдоним
 
 # =============================================================================
 # About Panel
@@ -736,9 +1212,25 @@ err-server-image-invalid-format = Недопустимый формат изоб
 err-server-image-unsupported-type = Неподдерживаемый тип изображения сервера (только PNG, WebP, JPEG или SVG)
 err-server-image-decode-failed = Не удалось декодировать изображение. Возможно, файл повреждён.
 err-failed-read-image = Не удалось прочитать изображение: { $error }
-err-news-empty = Публикация должна содержать текст или изображение
+err-news-empty = Публикация должна содержать текст или вложение
 err-news-body-too-long = Содержимое слишком длинное ({ $length } символов, максимум { $max })
 err-news-body-invalid-characters = Содержимое содержит недопустимые управляющие символы
+err-news-too-many-attachments = Публикация может содержать не более { $max } вложений
+err-news-caption-too-long = Подпись слишком длинная (макс. { $max } символов)
+err-news-caption-invalid-characters = Подпись должна быть одной строкой без управляющих символов
+err-news-file-path-invalid = Недопустимый путь к файлу
+err-news-search-date = Неверная дата: { $date } (используйте ГГГГ-ММ-ДД)
+err-file-comment-empty = Комментарий не может быть пустым
+err-file-comment-too-long = Комментарий слишком длинный ({ $length } символов, максимум { $max })
+err-file-comment-invalid-characters = Комментарий содержит недопустимые управляющие символы
+err-upload-rule-invalid-extension = Недопустимый тип файла "{ $extension }" (используйте буквы и цифры, например mp3)
+err-auto-download-pattern-required = Введите хотя бы один шаблон имени файла
+err-auto-download-pattern-invalid = Недопустимый шаблон «{ $pattern }» (шаблоны применяются к именам файлов и не могут содержать косую черту)
+err-upload-rule-too-many-extensions = Слишком много типов файлов (макс. { $max })
+err-upload-rule-invalid-size = Недопустимый размер "{ $value }" (введите число мегабайт)
+err-ldap-group-empty = DN группы не может быть пустым
+err-ldap-group-too-long = DN группы слишком длинный (максимум { $max } символов)
+err-ldap-group-invalid-characters = DN группы не может содержать пробелы по краям или управляющие символы
 err-news-image-decode-failed = Не удалось декодировать изображение. Возможно, файл повреждён.
 
 # Proxy errors
@@ -750,9 +1242,31 @@ err-proxy-connection-timeout = Время ожидания подключени
 }
 err-proxy-address-required = Адрес прокси обязателен, когда прокси включен
 err-proxy-port-invalid = Порт прокси должен быть от 1 до 65535
-err-news-image-too-large = Изображение слишком большое (максимум 512КБ)
+err-news-image-too-large = Изображение слишком большое (максимум { $max_kb }КБ)
 err-news-image-unsupported-type = Неподдерживаемый тип изображения (только PNG, WebP, JPEG или SVG)
 err-topic-no-channel = Эта команда работает только в канале
+err-entry-no-channel = Эта команда работает только в канале
+cmd-welcome-desc = Просмотреть или задать сообщение для входящих в канал
+cmd-welcome-usage = Использование: /{ $command } [установить <сообщение>|очистить]
+cmd-welcome-arg-set = установить
+cmd-welcome-arg-clear = очистить
+cmd-welcome-none = Приветственное сообщение не задано
+msg-entry-message-display = Приветственное сообщение: { $message }
+msg-entry-message-updated = Приветственное сообщение обновлено
+msg-entry-message-cleared = Приветственное сообщение очищено
+cmd-rules-desc = Просмотреть или задать правила канала
+cmd-rules-usage = Использование: /{ $command } [установить <правила>|очистить]
+cmd-rules-arg-set = установить
+cmd-rules-arg-clear = очистить
+cmd-rules-none = Правила не заданы
+msg-rules-header = Правила { $channel }:
+cmd-language-desc = Показать или задать языковой тег канала
+cmd-language-usage = Использование: /{ $command } [установить <язык>|очистить]
+cmd-language-arg-set = установить
+cmd-language-arg-clear = очистить
+msg-language-status = Язык канала: { $language }
+msg-language-none = Язык не задан
+err-language-invalid = Недопустимый языковой тег: { $language } (например, ru или pt-BR)
 err-join-channel = Не удалось присоединиться к каналу: { $error }
 err-leave-channel = Не удалось покинуть канал: { $error }
 err-list-channels = Не удалось получить список каналов: { $error }
@@ -762,6 +1276,8 @@ err-channel-too-long = Имя канала слишком длинное (мак
 err-channel-missing-prefix = Имя канала должно начинаться с #
 err-channel-invalid-characters = Имя канала содержит недопустимые символы
 err-leave-no-channel = Вы должны быть в канале, чтобы использовать /{ $command } без аргументов
+err-invite-no-group = Используйте /{ $command } во вкладке групповой беседы
+err-group-too-many-members = В групповой беседе может быть не более { $max } участников
 err-not-in-channel = Вы не находитесь в канале { $channel }
 err-leave-already-pending = Уже ожидается ответ от сервера
 err-uri-connection-failed = Не удалось подключиться к { $host }: { $error }
@@ -775,6 +1291,35 @@ label-port = Порт:
 # Transfer statuses
 transfer-status-queued = В очереди
 transfer-status-connecting = Подключение…
+transfer-status-server-queued = Ожидание свободного места на сервере (#{ $position })
+transfer-collision-pending = Файл существует: { $path }
+collision-action-renamed = { $count } { $count ->
+    [one] переименован
+    [few] переименовано
+   *[other] переименовано
+}
+collision-action-overwritten = { $count } { $count ->
+    [one] перезаписан
+    [few] перезаписано
+   *[other] перезаписано
+}
+collision-action-skipped = { $count } { $count ->
+    [one] пропущен
+    [few] пропущено
+   *[other] пропущено
+}
+transfer-bar-count = { $count } { $count ->
+    [one] передача
+    [few] передачи
+   *[other] передач
+}
+status-bar-users = { $count } { $count ->
+    [one] пользователь
+    [few] пользователя
+   *[other] пользователей
+}
+status-bar-latency = { $ms } мс
+status-bar-server-time = Время сервера { $time } ({ $zone })
 transfer-status-transferring = Передача
 transfer-status-paused = Приостановлено
 transfer-status-completed = Завершено
@@ -837,6 +1382,7 @@ event-broadcast = Трансляция
 event-chat-message = Сообщение в чате
 event-chat-mention = Упоминание в чате
 event-connection-lost = Соединение потеряно
+event-friend-online = Друг в сети
 event-news-post = Новость
 event-permissions-changed = Права изменены
 event-transfer-complete = Передача завершена
@@ -871,6 +1417,17 @@ settings-sound-test = Тест
 settings-notification-test = Тест
 event-settings-show-toast = Показать тост
 settings-toast-test = Тест
+settings-do-not-disturb = Не беспокоить
+settings-quiet-hours = Тихие часы
+settings-do-not-disturb-hint = Отключает уведомления, всплывающие сообщения и звуки. Счётчики непрочитанного продолжают расти, а другие видят 🔕 рядом с вашим именем.
+settings-notification-rules = Правила уведомлений
+settings-notification-rules-server = Сервер:
+settings-notification-rules-channels = Настройки каналов
+settings-notification-rules-keywords = Отслеживаемые слова
+settings-notification-rules-no-bookmarks = Добавьте сервер в закладки, чтобы настроить для него правила уведомлений.
+channel-notification-all = Все сообщения
+channel-notification-mentions = Только упоминания
+channel-notification-mute = Без звука
 
 # Sound names
 sound-none = Нет
@@ -889,6 +1446,8 @@ notification-chat-message = Новое сообщение в чате
 notification-chat-message-from = Чат от {$username}
 notification-connection-lost = Соединение потеряно
 notification-connection-lost-from = Отключено от {$server}
+notification-friend-online = Друг появился в сети
+notification-friend-online-name = {$username} в сети
 notification-download-complete = Загрузка завершена
 notification-download-failed = Загрузка не удалась
 notification-news-post = Новая новость
@@ -942,6 +1501,18 @@ ban-duration-7d = 7 дней
 ban-duration-30d = 30 дней
 ban-duration-permanent = Постоянно
 
+# Broadcast target and delay options
+broadcast-target-label = Кому:
+broadcast-target-all = Всем
+broadcast-target-channel = Участникам канала
+broadcast-target-permission = Пользователям с правом
+broadcast-delay-label = Отправить:
+broadcast-delay-now = Сейчас
+broadcast-delay-5m = Через 5 минут
+broadcast-delay-15m = Через 15 минут
+broadcast-delay-1h = Через 1 час
+broadcast-delay-1d = Через 1 день
+
 # =============================================================================
 # Ban System
 # =============================================================================
@@ -991,12 +1562,32 @@ msg-untrusted-success = Запись доверия успешно удален
 # Trust list messages
 msg-trust-list-header = Доверенные IP:
 msg-trust-list-empty = Нет доверенных IP
+msg-ldap-group-list-header = Группы каталога:
+msg-ldap-group-list-empty = Нет сопоставленных групп каталога
+msg-ldap-group-admin = администратор
+msg-ldap-group-no-permissions = нет прав
+msg-ldap-group-set = Группа каталога { $group } сопоставлена
+msg-ldap-group-removed = Группа каталога { $group } удалена
+msg-guest-upgraded = Учетная запись { $username } создана. Войдите с ней в следующий раз, чтобы использовать ее.
+msg-nickname-registered = Псевдоним { $nickname } теперь зарезервирован за { $username }.
+msg-nickname-released = Псевдоним { $nickname } освобожден.
+msg-history-days = Сервер хранит ваши личные сообщения { $days } дн.
+msg-history-off = Сервер не хранит ваши личные сообщения. Включите командой /history <дни>.
+msg-history-no-older = Более ранних сообщений с { $nickname } нет.
+err-history-days-too-long = История сообщений может храниться не более { $max } дн.
+err-history-more-tab = Используйте /history more во вкладке личных сообщений
 msg-trust-permanent = постоянно
 msg-trust-expired = истёк
 msg-trust-remaining = { $time } осталось
 msg-trust-remaining-days = { $days }д { $hours }ч
 msg-trust-remaining-hours = { $hours }ч { $minutes }м
 msg-trust-remaining-minutes = { $minutes }м
+msg-leaderboard-header = Самые активные пользователи по { $stat }:
+msg-leaderboard-empty = Активность пока не зарегистрирована
+leaderboard-stat-logins = входам
+leaderboard-stat-messages = сообщениям
+leaderboard-stat-uploads = загрузкам
+leaderboard-stat-downloaded = объёму скачанного
 
 # =============================================================================
 # Voice Chat
@@ -1005,6 +1596,16 @@ msg-trust-remaining-minutes = { $minutes }м
 # Voice error messages
 err-voice-join = Не удалось подключиться к голосовому чату: { $error }
 err-voice-leave = Не удалось выйти из голосового чата: { $error }
+err-voice-limit = Не удалось установить голосовой лимит: { $error }
+err-voice-clip = Звуковая панель: { $error }
+err-voice-text = Голосовой текст: { $error }
+err-voice-clip-name-empty = Имя клипа не может быть пустым
+err-voice-clip-name-too-long = Имя клипа слишком длинное (макс. { $max } символов)
+err-voice-clip-name-invalid = Имена клипов могут содержать только буквы, цифры, - и _
+err-voice-clip-too-large = Клип слишком большой (макс. 256 КБ)
+err-voice-clip-unsupported-type = Неподдерживаемый формат клипа (только Ogg Vorbis)
+msg-voice-queued = Голосовой чат заполнен — вы { $position }-й в очереди
+msg-voice-queue-cancelled = Вы удалены из очереди голосового чата
 err-voice-no-token = Подключение к голосовому чату выполнено, но токен не получен
 err-voice-already-active = Вы уже находитесь в голосовом чате на другом сервере
 err-voice-no-permission = У вас нет разрешения на подключение к голосовому чату
@@ -1031,7 +1632,12 @@ msg-voice-joined-empty = Присоединился к голосовому ча
 msg-voice-left = Покинул голосовой чат в { $target }
 msg-voice-user-joined = { $nickname } присоединился к голосовому чату
 msg-voice-user-left = { $nickname } покинул голосовой чат
+msg-voice-broadcast-started = { $nickname } ведёт трансляцию в голосовом чате - присоединяйтесь, чтобы слушать
+msg-voice-broadcast-ended = Голосовая трансляция завершена
+msg-voice-clip-played = { $nickname } воспроизвёл(а) { $name }
+msg-voice-text = [голос] { $nickname }: { $message }
 msg-voice-you-left = Вы покинули голосовой чат
+msg-voice-tcp-relay = Не удалось установить UDP-соединение для голоса ({ $error }) - голос передаётся через соединение с сервером
 
 # Voice UI
 voice-join-tooltip = Присоединиться к голосовому чату
@@ -1039,6 +1645,8 @@ voice-leave-tooltip = Покинуть голосовой чат
 voice-mute-all-tooltip = Отключить всех
 voice-unmute-all-tooltip = Включить звук
 voice-bar-participants = ({ $count })
+voice-bar-queued = (в очереди: { $position })
+voice-bar-listeners = (слушают: { $count })
 voice-indicator-tooltip = В голосовом чате
 tooltip-mute = Отключить звук
 tooltip-unmute = Включить звук
@@ -1049,6 +1657,12 @@ voice-quality-medium = Среднее (32 кбит/с)
 voice-quality-high = Высокое (64 кбит/с)
 voice-quality-very-high = Очень высокое (96 кбит/с)
 
+# Voice connection quality
+voice-connection-good = Хорошее
+voice-connection-fair = Среднее
+voice-connection-poor = Плохое
+voice-connection-tooltip = Соединение: { $quality } (потери { $loss }%, джиттер { $jitter } мс)
+
 # PTT modes
 ptt-mode-hold = Удерживать для разговора
 ptt-mode-toggle = Переключение
@@ -1063,6 +1677,8 @@ ptt-delay-500ms = 500мс
 audio-output-device = Устройство вывода
 audio-input-device = Устройство ввода
 audio-refresh-devices = Обновить устройства
+audio-clip-volume = Громкость звуковой панели
+audio-voice-text-tts = Зачитывать голосовые текстовые сообщения
 audio-voice-quality = Качество голоса
 audio-ptt-key = Клавиша Push-to-Talk
 audio-ptt-mode = Режим PTT
@@ -1084,6 +1700,44 @@ audio-echo-cancellation = Эхоподавление
 audio-agc = Автоматическая регулировка усиления
 audio-transient-suppression = Подавление шума клавиатуры
 
+# =============================================================================
+# Global Hotkeys
+# =============================================================================
+
+# Settings
+settings-toggle-window-key = Клавиша показа/скрытия окна
+button-clear-hotkey = Очистить
+
+# Shortcut descriptions (shown by the desktop on Wayland)
+hotkey-push-to-talk = Push-to-Talk
+hotkey-toggle-window = Показать или скрыть Nexus
+
+# Errors
+err-window-hotkey-failed = Не удалось зарегистрировать клавишу показа/скрытия окна: { $error }
+err-global-shortcuts-unavailable = Портал глобальных сочетаний клавиш недоступен, клавиши работают только когда Nexus в фокусе: { $error }
+
+# =============================================================================
+# Auto Away
+# =============================================================================
+
+settings-auto-away = Автоматически отходить при бездействии
+settings-auto-away-minutes = Минут бездействия
+
+# =============================================================================
+# Connection Health
+# =============================================================================
+
+# Server list indicator
+connection-health-good = Хорошее
+connection-health-fair = Среднее
+connection-health-poor = Плохое
+connection-health-tooltip = Соединение: { $quality }
+connection-health-latency = Задержка: { $ms } мс
+connection-health-jitter = Джиттер: { $ms } мс
+connection-health-last-drop = Последний потерянный пинг: { $time }
+connection-health-no-drops = Потерянных пингов нет
+connection-health-measuring = Измерение задержки…
+
 # =============================================================================
 # System Tray (Windows/Linux only)
 # =============================================================================
@@ -1113,14 +1767,35 @@ tray-tooltip-unread = Nexus BBS - { $count ->
    *[other] { $count } непрочитанных разговоров
 }
 
+# =============================================================================
+# Notification Actions (macOS/Windows only)
+# =============================================================================
+
+# Actions
+notification-reply = Ответить
+notification-reply-send = Отправить
+notification-reply-placeholder = Ответить…
+notification-mark-read = Отметить как прочитанное
+
 # =============================================================================
 # Toast Notifications
 # =============================================================================
 
 toast-link-copied = Ссылка скопирована в буфер обмена
 toast-copied = Скопировано в буфер обмена
+toast-checksum-copied = SHA-256 скопирован в буфер обмена
 toast-download-started = Скачивание { $filename }
 toast-download-queued = Загрузка в очереди: { $filename }
+toast-downloads-started = Скачивание { $count } { $count ->
+    [one] файла
+    [few] файлов
+   *[other] файлов
+}
+toast-downloads-queued = { $count } { $count ->
+    [one] загрузка
+    [few] загрузки
+   *[other] загрузок
+} в очереди
 toast-upload-started = Отправка { $filename }
 toast-upload-queued = Отправка в очереди: { $filename }
 toast-uploads-started = Отправка { $count } { $count ->
@@ -1133,3 +1808,28 @@ toast-uploads-queued = { $count } { $count ->
     [few] отправки
    *[other] отправок
 } в очереди
+toast-upload-nothing-new = Нечего отправлять — на сервере уже есть эти файлы
+toast-upload-watch-started = Отслеживается { $folder } — новые файлы будут загружаться автоматически
+toast-upload-watch-stopped = Отслеживание { $folder } прекращено
+toast-auto-download-started = Автозагрузка { $pattern } из { $folder }
+toast-auto-download-stopped = Автозагрузка из { $folder } остановлена
+toast-screenshot-folder-set = Скриншоты будут загружаться в эту папку
+toast-chat-exported = Чат экспортирован
+
+# =============================================================================
+# First-run Tips
+# =============================================================================
+
+tip-welcome-title = Добро пожаловать в Nexus
+tip-welcome = Введите ниже адрес сервера и подключитесь. Добавьте закладку, чтобы сервер вместе с вашими учётными данными остался в списке слева и в следующий раз был в одном клике.
+tip-fingerprint = У каждого сервера есть отпечаток сертификата — что-то вроде подписи. Nexus запоминает его при первом подключении и предупреждает, если он изменится, ведь это может означать, что кто-то выдаёт себя за сервер. Принимайте новый отпечаток, только если администратор сервера сообщил вам об изменении.
+tip-server-list = Ваши закладки и открытые подключения находятся слева. Нажмите на одно из них, чтобы переключить сервер, или перетащите закладки, чтобы изменить их порядок.
+tip-toolbar = Панель инструментов открывает файлы, новости, настройки и многое другое. Наведите курсор на значок, чтобы увидеть, что он делает.
+tip-chat = Здесь вы общаетесь со всеми на сервере. Введите /, чтобы увидеть доступные команды, например /join для входа в канал.
+tip-user-list = Пользователи в сети перечислены справа. Нажмите на имя, чтобы написать сообщение или посмотреть информацию.
+tip-counter = Совет { $current } из { $total }
+button-add-bookmark = Добавить закладку
+button-tip-next = Далее
+button-tip-done = Готово
+button-tip-got-it = Понятно
+button-skip-tips = Пропустить советы
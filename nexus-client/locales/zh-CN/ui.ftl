@@ -11,6 +11,13 @@ button-connect = 连接
 button-save = 保存
 button-create = 创建
 button-edit = 编辑
+button-check-ports = 检测端口
+button-statistics = 统计
+button-dashboard = 仪表板
+button-refresh = 刷新
+button-doors = 门程序
+button-doors-leave = 离开
+button-doors-back = 返回
 button-update = 更新
 button-accept-new-certificate = 接受新证书
 button-close = 关闭
@@ -18,9 +25,23 @@ button-choose-avatar = 选择头像
 button-clear-avatar = 清除
 button-choose-image = 选择图片
 button-clear-image = 清除
+button-add-file = 添加文件
 button-overwrite = 覆盖
 button-change-password = 修改密码
 button-browse = 浏览
+button-undo = 撤销
+button-add = 添加
+button-remove = 移除
+button-view = 查看
+button-hide = 隐藏
+button-export = 导出…
+button-trust-certificate = 信任证书
+button-skip = 跳过
+button-skip-existing = 跳过已有文件
+button-download-anyway = 仍然下载
+button-unlock = 解锁
+button-view-as-user = 以用户身份查看
+button-post-comment = 发布
 
 # =============================================================================
 # Titles
@@ -35,19 +56,29 @@ title-user-create = 创建用户
 title-user-edit = 编辑用户
 title-update-user = 更新用户
 title-user-management = 用户管理
+title-permission-audit = 权限审计
+title-impersonate = 正在以 { $username } 身份查看
 title-confirm-delete = 确认删除
 title-connected = 已连接
 title-settings = 设置
 title-bookmarks = 书签
 title-users = 用户
+title-friends = 好友
+friend-online = 在线
+friend-offline = 离线
 title-edit-server-info = 编辑服务器信息
 title-fingerprint-mismatch = 证书指纹不匹配！
+title-fingerprint-new-server = 新服务器证书
+title-create-password-vault = 保护已保存的密码
+title-unlock-password-vault = 解锁已保存的密码
+title-unlock-config = 解锁设置
 title-server-info = 服务器信息
 title-user-info = 用户信息
 title-about = 关于
 title-change-password = 修改密码
 title-news = 新闻
 title-transfers = 传输
+title-transfer-history = 传输历史
 title-news-create = 创建帖子
 title-news-edit = 编辑帖子
 title-channel-members = 成员
@@ -58,10 +89,14 @@ title-channel-members = 成员
 
 placeholder-username = 用户名
 placeholder-password = 密码
+placeholder-master-passphrase = 主密码短语
+placeholder-confirm-master-passphrase = 确认主密码短语
 placeholder-port = 端口
 placeholder-server-address = 服务器地址
 placeholder-server-name = 服务器名称
+placeholder-folder-name = 文件夹名称
 placeholder-username-optional = 用户名（可选）
+placeholder-fallback-addresses-optional = 备用地址（可选，以逗号分隔）
 placeholder-password-optional = 密码（可选）
 placeholder-password-keep-current = 密码
 placeholder-current-password = 当前密码
@@ -70,10 +105,18 @@ placeholder-confirm-password = 确认密码
 placeholder-message = 输入消息…
 placeholder-no-permission = 无权限
 placeholder-broadcast-message = 广播消息
+placeholder-broadcast-channel = 选择频道
+placeholder-broadcast-permission = 选择权限
 placeholder-server-description = 服务器描述
 placeholder-news-body = 新闻内容
+placeholder-news-caption = 说明（可选）
+placeholder-news-file-path = 文件区中的路径
 placeholder-nickname-optional = 昵称（可选）
+placeholder-status-optional = 状态（可选）
+placeholder-download-folder-optional = 下载文件夹（可选）
 placeholder-proxy-address = 代理地址
+placeholder-notification-rules-channel = #频道
+placeholder-notification-rules-keyword = 关键词
 placeholder-proxy-port = 9050
 placeholder-proxy-username = 用户名（可选）
 placeholder-proxy-password = 密码（可选）
@@ -86,13 +129,20 @@ placeholder-auto-join-channels = #频道1 #频道2（空格分隔）
 # =============================================================================
 
 label-auto-connect = 自动连接
+label-server-avatar = 头像:
 label-add-bookmark = 书签
+label-lan-servers = 本地网络
+label-use-sso = 单点登录
 label-admin = 管理员
 label-shared-account = 共享账户
 label-enabled = 已启用
+label-erase-personal-data = 同时清除个人数据
+label-broadcast-require-ack = 要求接收者确认
 label-permissions = 权限：
 label-expected-fingerprint = 预期指纹：
 label-received-fingerprint = 收到的指纹：
+label-certificate-fingerprint = 证书指纹:
+label-identity-key = 身份密钥:
 label-theme = 主题
 label-chat-history-retention = 聊天记录：
 chat-history-forever = 永久
@@ -100,6 +150,10 @@ chat-history-30-days = 30天
 chat-history-14-days = 14天
 chat-history-7-days = 7天
 chat-history-disabled = 禁用
+collision-policy-rename = 保留两者（重命名）
+collision-policy-overwrite = 覆盖
+collision-policy-skip = 跳过
+collision-policy-ask = 询问
 label-max-scrollback = 最大历史记录：
 label-chat-font-size = 字体大小：
 label-show-connection-events = 显示连接/断开事件
@@ -107,6 +161,19 @@ label-show-channel-events = 显示加入/离开事件
 label-show-timestamps = 显示时间戳
 label-use-24-hour-time = 使用24小时制
 label-show-seconds = 显示秒
+label-split-view = 在聊天旁显示文件、新闻和连接监视器
+label-spell-check = 在聊天和新闻中检查拼写
+label-spell-check-no-dictionary = 未找到你所用语言的词典。请将 .aff 和 .dic 文件放入 config.json 旁边的 dictionaries 文件夹。
+label-send-read-receipts = 发送私信已读回执
+label-user-list-grouping = 用户列表分组:
+label-user-list-sort = 用户列表排序:
+user-list-group-none = 无
+user-list-group-admins = 管理员优先
+user-list-group-channel = 按频道
+user-list-group-status = 按状态
+user-list-sort-alphabetical = 按字母
+user-list-sort-idle = 空闲时间
+user-list-sort-connect = 连接时间
 label-server-name = 名称：
 label-server-description = 描述：
 label-server-version = 版本：
@@ -137,6 +204,27 @@ tab-files = 文件
 tab-channels = 频道
 label-connections-short = 连接：
 label-transfers-short = 传输：
+label-active-transfers-short = 活动：
+label-queue-order-short = 队列顺序：
+label-trusted-weight-short = 信任权重：
+label-nat-mapping-short = 端口映射：
+label-nat-external-ip-short = 外部 IP：
+label-nat-not-mapped = 未映射
+connectivity-check-running = 正在检测端口…
+check-service-bbs = BBS
+check-service-transfer = 传输
+check-service-websocket = WebSocket
+check-service-transfer-websocket = WebSocket 传输
+check-service-voice = 语音
+check-state-open = 开放
+check-state-closed = 关闭
+check-state-filtered = 被过滤
+check-state-untested = 未检测
+label-check-port = { $service }（{ $protocol } { $port }）：
+label-queue-order-small-first = 小文件优先
+label-queue-order-arrival = 到达顺序
+label-small-files-first = 小文件优先排队
+label-unlimited = 无限制
 label-reindex-short = 重建索引：
 label-persistent-short = 常驻：
 label-auto-join-short = 自动加入：
@@ -153,6 +241,19 @@ label-download-location = 下载位置:
 label-queue-transfers = 队列传输
 label-download-limit = 下载限制:
 label-upload-limit = 上传限制:
+label-auto-open-folder = 下载完成时打开文件夹
+label-collision-policy = 文件已存在时：
+label-require-fingerprint-approval = 信任新服务器的证书前询问
+label-known-servers = 已知服务器
+known-servers-empty = 尚未保存服务器证书
+known-server-first-seen = 首次见到 { $date }
+known-server-first-seen-unknown = 首次见到: 未知
+label-encrypt-config = 使用主密码短语加密设置、书签和聊天记录
+config-encryption-locked-note = 设置已锁定。请重新启动 Nexus 并输入主密码短语以更改此项。
+label-upload-rule-extensions = 允许的文件类型：
+label-upload-rule-max-file-size = 最大文件 (MB)：
+label-upload-rule-max-total-size = 最大上传 (MB)：
+label-auto-download-pattern = 文件名:
 label-persistent-channels = 常驻频道:
 label-auto-join-channels = 自动加入频道:
 label-channels = 频道
@@ -166,11 +267,14 @@ permission-user_info = 用户信息
 permission-chat_send = 发送聊天
 permission-chat_receive = 接收聊天
 permission-chat_join = 加入频道
+permission-chat_language = 聊天语言
 permission-chat_create = 创建频道
 permission-chat_list = 频道列表
 permission-chat_secret = 频道私密
 permission-chat_topic = 聊天主题
 permission-chat_topic_edit = 编辑聊天主题
+permission-chat_topic_lock = 锁定聊天主题
+permission-chat_entry_edit = 编辑聊天入场消息
 permission-user_broadcast = 用户广播
 permission-user_create = 创建用户
 permission-user_delete = 删除用户
@@ -181,6 +285,7 @@ permission-news_list = 新闻列表
 permission-news_create = 创建新闻
 permission-news_edit = 编辑新闻
 permission-news_delete = 删除新闻
+permission-file_comment = 评论文件
 permission-file_copy = 文件复制
 permission-file_create_dir = 目录创建
 permission-file_delete = 删除文件
@@ -200,6 +305,11 @@ permission-trust_create = 创建信任
 permission-trust_delete = 删除信任
 permission-trust_list = 信任列表
 permission-connection_monitor = 连接监视器
+permission-log_view = 查看日志
+permission-door_play = 运行门程序
+permission-voice_broadcast = 语音广播
+permission-voice_clip_upload = 上传语音片段
+permission-voice_limit = 语音上限
 permission-voice_listen = 语音收听
 permission-voice_talk = 语音发言
 
@@ -224,19 +334,31 @@ tooltip-files-paste = 粘贴
 tooltip-files-search = 搜索文件
 tooltip-manage-users = 管理用户
 tooltip-connection-monitor = 连接监视器
+tooltip-server-log = 服务器日志
 tooltip-server-info = 服务器信息
 tooltip-about = 关于
 tooltip-transfers = 传输
 tooltip-download-all = 全部下载
 tooltip-upload = 上传
 file-picker-upload-title = 选择要上传的文件
+file-picker-download-to-title = 选择下载文件夹
+folder-picker-upload-watch-title = 选择要自动上传的文件夹
 drop-to-upload = 拖放以上传
 tooltip-clear-inactive = 清除非活动项
+tooltip-transfer-history = 传输历史
+tooltip-transfer-history-clear = 清除历史
+tooltip-transfer-history-remove = 从历史中移除
 tooltip-transfer-pause = 暂停
 tooltip-transfer-resume = 继续
 tooltip-transfer-cancel = 取消
 tooltip-transfer-remove = 移除
 tooltip-transfer-open-folder = 打开文件夹
+tooltip-transfer-open-file = 打开文件
+tooltip-transfer-reveal = 在文件管理器中显示
+tooltip-transfer-copy-checksum = 复制 SHA-256
+tooltip-transfer-collision-rename = 保留两者（重命名）
+tooltip-transfer-collision-overwrite = 覆盖现有文件
+tooltip-transfer-collision-skip = 跳过现有文件
 tooltip-transfer-move-up = 上移
 tooltip-transfer-move-down = 下移
 tooltip-transfer-retry = 重试
@@ -249,16 +371,34 @@ tooltip-disconnect = 断开连接
 tooltip-edit = 编辑
 tooltip-info = 信息
 tooltip-message = 消息
+tooltip-add-friend = 添加好友
+tooltip-remove-friend = 移除好友
 tooltip-kick = 踢出
 tooltip-close = 关闭
+tooltip-retry-send = 重新发送
+tooltip-cancel-send = 取消发送
+tooltip-message-sent = 已发送
+tooltip-message-delivered = 已送达
+tooltip-message-read = 已读
 tooltip-new-tab = 新标签页
 tooltip-close-tab = 关闭标签页
 tooltip-add-bookmark = 添加书签
+tooltip-new-folder = 新建文件夹
 tooltip-create-user = 创建用户
+tooltip-permission-audit = 审计权限
 tooltip-create-news = 创建帖子
+tooltip-news-search = 搜索新闻
+tooltip-news-search-clear = 清除搜索
 tooltip-delete = 删除
 context-menu-download = 下载
+context-menu-download-to = 下载到…
+context-menu-download-select = 选择下载…
+context-menu-auto-download = 自动下载…
 context-menu-upload = 上传
+context-menu-watch-folder = 监视本地文件夹…
+context-menu-screenshot-folder = 在此分享截图
+context-menu-unwatch-folder = 停止监视文件夹
+context-menu-upload-rules = 上传规则…
 context-menu-open = 打开
 
 # =============================================================================
@@ -266,6 +406,7 @@ context-menu-open = 打开
 # =============================================================================
 
 panel-connection-monitor = 连接监视器
+panel-server-log = 服务器日志
 panel-active-connections = 活跃连接: { $count }
 tab-connections = 连接
 tab-transfers = 传输
@@ -280,9 +421,97 @@ menu-info = 信息
 menu-copy = 复制
 menu-kick = 踢出
 menu-ban = 封禁
+menu-pop-out = 弹出
+menu-mute-tab = 静音
+menu-unmute-tab = 取消静音
+menu-connect-all = 全部连接
+menu-rename = 重命名
+menu-delete = 删除
 connection-monitor-loading = 正在加载连接…
 connection-monitor-no-connections = 无连接
 connection-monitor-no-transfers = 无活跃传输
+connection-monitor-queued = 排队中
+connection-monitor-weight = 公平分配权重：×{ $weight }
+connection-monitor-listed = 已被 { $zone } 列入
+connection-monitor-queued-count = { $count } 个排队中
+placeholder-server-log-module = 按模块筛选
+server-log-loading = 正在加载服务器日志…
+server-log-empty = 没有日志
+server-log-level-error = 仅错误
+server-log-level-warn = 警告及以上
+server-log-level-info = 信息及以上
+server-log-level-debug = 全部（调试）
+panel-statistics = 统计
+stats-loading = 正在加载统计…
+panel-snippet = 代码片段
+snippet-loading = 正在加载代码片段…
+snippet-details = 由 { $author } 分享 · { $expires } 过期
+stats-range-days = 最近 { $days } 天
+stats-chart-max = 最高日：{ $value }
+stats-metric-logins = 登录
+stats-metric-messages = 消息
+stats-metric-uploads = 上传
+stats-metric-upload-bytes = 上传数据量
+stats-metric-downloads = 下载
+stats-metric-download-bytes = 下载数据量
+stats-metric-peak-sessions = 在线用户峰值
+panel-admin-dashboard = 管理仪表板
+dashboard-loading = 正在加载仪表板…
+dashboard-connections = 连接
+dashboard-sessions = 在线用户
+dashboard-connection-count = 连接数
+dashboard-transfer-connections = 传输连接
+dashboard-transfers = 传输
+dashboard-active-transfers = 进行中
+dashboard-queued-transfers = 排队中
+dashboard-today = 今天
+dashboard-disk = 文件区
+dashboard-disk-free = 可用空间
+dashboard-disk-free-of = { $total } 中可用 { $free }
+dashboard-disk-unknown = 未知
+dashboard-recent-audit = 最近的审计事件
+dashboard-audit-empty = 暂无审计事件
+dashboard-updated = 更新于 { $time }
+panel-doors = 门程序
+doors-loading = 正在加载门程序…
+doors-empty = 此服务器没有门程序
+doors-starting = 正在启动…
+doors-playing-hint = 按键会发送到门程序。使用离开退出。
+doors-exited = 门程序已结束
+doors-exited-code = 门程序以代码 { $code } 结束
+
+# =============================================================================
+# Quick Switcher
+# =============================================================================
+
+quick-switcher-placeholder = 跳转到频道、消息、书签或面板...
+user-list-filter-placeholder = 筛选用户...
+user-list-section-admins = 管理员
+user-list-section-users = 用户
+user-list-section-no-channel = 其他
+user-list-section-online = 在线
+user-list-section-away = 离开
+user-list-section-dnd = 勿扰
+user-list-idle = 空闲 { $duration }
+quick-switcher-no-matches = 没有匹配项
+quick-switcher-bookmark = 书签
+
+# =============================================================================
+# Chat Input
+# =============================================================================
+
+chat-paste-prompt = 粘贴 { $count } 行？
+chat-paste-too-long-prompt = 内容太长，无法作为一条消息发送（最多 { $max }）。仍要发送吗？
+button-send-as-messages = 作为 { $count } 条消息发送
+button-send-as-one-message = 作为一条消息发送
+button-send-in-parts = 分 { $count } 条发送
+button-share-as-snippet = 作为代码片段分享
+button-expand-code = 显示全部 { $count } 行
+button-collapse-code = 收起
+chat-broadcast-ack-prompt = { $username } 请您确认其广播
+button-acknowledge = 确认
+button-dismiss = 忽略
+spell-no-suggestions = 没有建议
 
 # =============================================================================
 # Empty States
@@ -293,11 +522,35 @@ empty-no-connections = 无连接
 empty-no-bookmarks = 无书签
 empty-no-users = 没有在线用户
 empty-no-channel-members = 频道中没有成员
+empty-no-matching-users = 没有匹配的用户
 user-management-loading = 正在加载用户…
 user-management-no-users = 未找到用户
+permission-audit-hint = 选择一个权限以查看谁拥有它，或点击用户以查看其能做的一切。
+permission-audit-loading = 加载中…
+permission-audit-no-holders = 没有账户拥有此权限
+permission-audit-effective = { $username } 的有效权限
+permission-audit-admin = 管理员拥有所有权限
+permission-audit-no-permissions = 此账户没有任何权限
+permission-audit-restricted = 已授予但共享账户无法使用：
+permission-audit-disabled = 已禁用
+impersonate-notice = 此账户所见内容的只读视图。每次查看都会记录在审计日志中。
+impersonate-permissions = 权限
+impersonate-files = 文件（{ $path }）
+impersonate-no-file-access = 此账户无法浏览文件
+impersonate-empty-folder = 此文件夹为空
+impersonate-channels = 频道
+impersonate-no-channel-access = 此账户无法列出频道
+impersonate-no-channels = 没有可见的频道
+impersonate-secret = 秘密
 news-loading = 正在加载新闻…
 news-no-posts = 暂无新闻
+news-search-placeholder = 搜索新闻… (author:用户名 since:YYYY-MM-DD until:YYYY-MM-DD)
+news-searching = 搜索中…
+news-search-no-results = 没有匹配的新闻
 transfers-empty = 无传输
+transfer-history-search-placeholder = 按名称、服务器或文件夹搜索…
+transfer-history-empty = 尚无已完成的传输
+transfer-history-no-matches = 没有与搜索匹配的传输
 news-updated = 已更新
 files-loading = 正在加载文件…
 files-empty = 此目录为空
@@ -309,10 +562,16 @@ files-column-name = 名称
 files-column-size = 大小
 files-column-modified = 更新日期
 files-column-path = 路径
+files-column-rating = 评分
 files-search-placeholder = 搜索文件…
 files-search-breadcrumb = 搜索 - { $query }
 files-searching = 搜索中…
 files-no-results = 未找到文件
+files-highlights = 精选
+files-highlights-recent = 本周新上传
+files-highlights-popular = 最多下载
+files-highlights-empty = 暂无内容
+files-highlights-downloads = { $count } 次下载
 files-search-query-too-short = 搜索查询太短（最少{ $min_length }个字符）
 files-search-query-too-long = 搜索查询太长（最多{ $max_length }个字符）
 files-search-query-invalid = 搜索查询包含无效字符
@@ -323,6 +582,24 @@ files-share = 分享
 files-rename = 重命名
 files-rename-title = 重命名"{ $name }"
 files-rename-placeholder = 新名称
+files-upload-rule-title = "{ $name }"的上传规则
+files-upload-rule-loading = 正在加载…
+files-upload-rule-extensions-placeholder = 任意类型（例如 mp3, flac）
+files-upload-rule-no-limit = 不限制
+files-upload-rule-remove = 移除规则
+files-upload-rule-help = 规则适用于此文件夹及其子文件夹，除非子文件夹有自己的规则。
+files-auto-download-title = 从"{ $name }"自动下载
+files-auto-download-pattern-placeholder = 例如 *.mp3, *.m4a
+files-auto-download-remove = 停止自动下载
+files-auto-download-help = 通过此书签连接期间，此文件夹中名称匹配的新文件会被下载。* 匹配任意文本，? 匹配单个字符，多个模式用逗号分隔。已下载过的文件会被跳过。
+files-upload-rule-hint = 此处上传：{ $limits }
+files-upload-rule-hint-types = 仅限 { $extensions }
+files-upload-rule-hint-file-size = 每个文件最多 { $size }
+files-upload-rule-hint-total-size = 每次上传最多 { $size }
+files-upload-preview-title = 文件已存在
+files-upload-preview-comparing = 正在与服务器上的文件比较…
+files-upload-preview-summary = 新文件 { $new } 个，服务器上已有 { $identical } 个，内容不同 { $conflicts } 个：
+files-upload-preview-blocked = { $count } 个文件在服务器上有同名文件夹，将被跳过
 files-cut = 剪切
 files-copy = 复制
 files-paste = 粘贴
@@ -332,6 +609,13 @@ files-delete-confirm-title = 确认删除
 files-delete-confirm-message = 您确定要删除"{ $name }"吗？
 # File Overwrite Dialog
 files-overwrite-title = 文件已存在
+files-download-to-title = 下载 { $name }
+label-remember-for-server = 为此服务器记住
+label-select-all = 全选
+files-download-select-summary = 已选择 { $count }/{ $total } ({ $size })
+files-download-select-too-many = 最多只能单独选择 { $max } 个文件。请改为下载整个文件夹。
+files-download-duplicate-title = 已下载
+files-download-duplicate-message = “{ $name }”已存在于此计算机的 { $path }。是否要再次下载？
 files-overwrite-message = 目标位置已存在名为"{ $name }"的文件或文件夹。是否要替换？
 
 # File Info Dialog
@@ -347,6 +631,14 @@ files-info-directory = 目录
 files-info-symlink = 符号链接：
 files-info-yes = 是
 files-info-na = 不适用
+files-info-rating = 评分：
+files-info-rating-value = { $average }（{ $count } 个评分）
+files-info-not-rated = 尚未评分
+files-info-your-rating = 我的评分：
+files-info-comments = 评论（{ $count }）
+files-comments-loading = 正在加载评论…
+files-comments-empty = 暂无评论
+files-comment-placeholder = 写评论…
 
 # =============================================================================
 # Directory Name Validation Errors
@@ -365,6 +657,7 @@ err-dir-name-invalid = 文件夹名称包含无效字符
 tab-general = 常规
 tab-chat = 聊天
 tab-network = 网络
+tab-security = 安全
 tab-audio = 音频
 
 # =============================================================================
@@ -387,6 +680,8 @@ chat-prefix-system = [系统]
 chat-prefix-error = [错误]
 chat-prefix-info = [信息]
 chat-prefix-broadcast = [BROADCAST]
+chat-prefix-queued = [PENDING]
+chat-export-subtitle = 于 { $date } 从 { $server } 导出
 
 # =============================================================================
 # Success Messages
@@ -407,11 +702,25 @@ msg-channel-list-header = 可用频道:
 msg-no-channels = 没有可用频道
 msg-channel-member-count = { $count } 个成员
 channel-secret = 私密
+channel-info-topic = 主题
+channel-info-rules = 规则
+channel-info-language = 语言
 msg-broadcast-sent = 广播发送成功
+msg-broadcast-delivered = 广播已送达 { $count } 位用户
+msg-broadcast-scheduled = 广播已安排在 { $time } 发送
+msg-broadcast-acknowledged = { $delivered } 位用户中有 { $count } 位已确认广播
+msg-storage-low = 服务器上{ $volume }的磁盘空间不足：可用 { $free }，低于最低值 { $threshold }
+msg-storage-recovered = 服务器上{ $volume }的磁盘空间已恢复：可用 { $free }
+storage-volume-files = 文件区
+storage-volume-database = 数据库
+msg-friend-added = 已将 { $username } 添加为好友
+msg-friend-removed = 已将 { $username } 从好友中移除
 msg-user-created = 用户已成功创建
 msg-user-created-name = 用户 '{ $username }' 已成功创建
 msg-user-deleted = 用户已成功删除
 msg-user-deleted-name = 用户 '{ $username }' 已成功删除
+msg-user-erased = 用户已删除，个人数据已清除
+msg-user-erased-name = 用户 '{ $username }' 已删除，个人数据已清除
 msg-user-updated = 用户已成功更新
 msg-user-updated-name = 用户 '{ $username }' 已成功更新
 msg-permissions-updated = 您的权限已更新
@@ -426,6 +735,8 @@ msg-welcome-back = 欢迎回来
 msg-status-set = 状态已设置: { $status }
 msg-status-cleared = 状态已清除
 msg-user-is-away = { $nickname } 已离开
+msg-group-member-joined = { $nickname } 加入了对话
+msg-group-member-left = { $nickname } 离开了对话
 msg-user-is-away-status = { $nickname } 已离开: { $status }
 
 
@@ -437,6 +748,14 @@ msg-topic-cleared = { $username } 清除了主题
 msg-topic-set = { $username } 设置了主题：{ $topic }
 msg-secret-set = { $username } 启用了秘密模式
 msg-secret-cleared = { $username } 禁用了秘密模式
+msg-topic-locked = { $username } 锁定了主题
+msg-topic-unlocked = { $username } 解锁了主题
+msg-rules-set = { $username } 更新了频道规则
+msg-rules-cleared = { $username } 清除了频道规则
+msg-language-set = { $username } 将频道语言设置为 { $language }
+msg-language-cleared = { $username } 清除了频道语言
+msg-voice-limit-set = { $username } 将语音上限设为 { $limit }
+msg-voice-limit-cleared = { $username } 取消了语音上限
 msg-server-info-updated = 服务器配置已更新
 msg-topic-display = 主题：{ $topic }
 confirm-delete-user = 确定要删除用户 '{ $username }' 吗？
@@ -456,6 +775,7 @@ err-user-kick-failed = 踢出用户失败
 err-unknown = 未知错误
 err-no-shutdown-handle = 连接错误：无关闭句柄
 err-userlist-failed = 刷新用户列表失败
+err-request-timeout = 服务器未及时响应
 err-port-invalid = 端口必须是有效数字（1-65535）
 
 # Network connection errors
@@ -469,12 +789,16 @@ err-connection-closed = 连接已关闭
 err-could-not-determine-config-dir = 无法确定配置目录
 err-message-too-long = 消息太长（{ $length } 个字符，最多 { $max }）
 err-send-failed = 发送消息失败
+err-checksum-failed = 计算校验和失败
 err-no-chat-permission = 您没有发送消息的权限
+err-chat-window-command = 聊天窗口中只能发送消息和 /me
 err-console-no-send = 使用 /{ $join } 加入频道或 /{ $msg } 向用户发送消息
 err-broadcast-too-long = 广播太长（{ $length } 个字符，最多 { $max }）
 err-broadcast-send-failed = 发送广播失败
+err-broadcast-target-required = 请选择广播的接收者
 err-name-required = 书签名称为必填项
 err-address-required = 服务器地址为必填项
+err-fallback-address-invalid = 无效的备用地址: { $address }
 err-port-required = 端口为必填项
 err-username-required = 用户名为必填项
 err-password-required = 密码为必填项
@@ -492,13 +816,20 @@ err-nickname-too-long = 昵称过长（最多{ $max }个字符）
 err-nickname-invalid = 昵称包含无效字符
 err-password-too-long = 密码过长（最多{ $max }个字符）
 err-passwords-do-not-match = 密码不匹配
+err-wrong-master-passphrase = 主密码短语错误
+err-master-passphrase-empty = 主密码短语不能为空
+err-password-vault = 密码保险库错误：{ $error }
 err-current-password-required = 当前密码是必填的
 err-new-password-required = 新密码是必填的
 err-confirm-password-required = 请确认您的新密码
 err-topic-too-long = 主题过长（{ $length }个字符，最多{ $max }个字符）
+err-entry-message-too-long = 入场消息太长（{ $length } 个字符，最多 { $max } 个）
+err-rules-too-long = 规则太长（{ $length } 个字符，最多 { $max } 个）
 err-avatar-unsupported-type = 不支持的文件类型。请使用PNG、WebP、JPEG或SVG。
 err-avatar-too-large = 头像过大。最大大小为{ $max_kb }KB。
 err-avatar-decode-failed = 无法解码头像。文件可能已损坏。
+err-known-server-export = 导出证书失败: { $error }
+err-config-encryption = 加密设置失败: { $error }
 err-server-name-empty = 服务器名称不能为空
 err-server-name-too-long = 服务器名称太长（最多 { $max } 个字符）
 err-server-name-contains-newlines = 服务器名称不能包含换行符
@@ -519,14 +850,23 @@ err-failed-send-update = 发送更新失败：{ $error }
 # =============================================================================
 
 err-failed-save-config = 保存配置失败：{ $error }
+err-friends-no-bookmark = 好友保存在服务器书签中；请先为此服务器添加书签
+err-friends-too-many = 好友过多（最多 { $max } 个）
+err-friend-exists = { $username } 已经是好友
+err-friend-not-found = { $username } 不是好友
 err-failed-save-settings = 保存设置失败：{ $error }
 err-invalid-port-bookmark = 书签中的端口无效：{ $name }
 err-failed-send-broadcast = 发送广播失败：{ $error }
 err-failed-send-message = 发送消息失败：{ $error }
+err-failed-start-group = 无法开始群组对话：{ $error }
+err-failed-share-snippet = 分享代码片段失败：{ $error }
 err-failed-create-user = 创建用户失败：{ $error }
 err-failed-delete-user = 删除用户失败：{ $error }
 err-failed-update-user = 更新用户失败：{ $error }
 err-failed-update-topic = 更新主题失败：{ $error }
+err-failed-lock-topic = 锁定主题失败：{ $error }
+err-failed-set-language = 设置频道语言失败：{ $error }
+err-failed-update-entry = 更新频道失败：{ $error }
 err-message-too-long-details = { $error }（{ $length }字符，最大{ $max }）
 
 # Network connection errors (with parameters)
@@ -538,6 +878,14 @@ err-tls-handshake-failed = TLS握手失败：{ $error }
 err-failed-send-handshake = 发送握手失败：{ $error }
 err-failed-read-handshake = 读取握手响应失败：{ $error }
 err-handshake-failed = 握手失败：{ $error }
+err-proof-of-work-too-hard = 服务器要求的工作量证明过高（难度 { $difficulty }）
+err-sso-not-offered = 此服务器不提供单点登录
+err-sso-failed = 单点登录失败：{ $error }
+err-sso-browser = 无法打开浏览器：{ $error }
+err-sso-timeout = 等待浏览器完成单点登录超时
+err-sso-state-mismatch = 单点登录响应与请求不匹配
+sso-browser-done = 已登录。您可以关闭此窗口并返回 Nexus。
+sso-browser-failed = 登录失败。您可以关闭此窗口并返回 Nexus。
 err-failed-parse-handshake = 解析握手响应失败：{ $error }
 err-failed-send-login = 发送登录失败：{ $error }
 err-failed-read-login = 读取登录响应失败：{ $error }
@@ -545,6 +893,7 @@ err-failed-parse-login = 解析登录响应失败：{ $error }
 err-failed-create-server-name = 创建服务器名称失败：{ $error }
 err-failed-create-config-dir = 创建配置目录失败：{ $error }
 err-failed-serialize-config = 序列化配置失败：{ $error }
+err-config-locked = 设置已加密并锁定；解锁前无法保存更改
 err-failed-write-config = 写入配置文件失败：{ $error }
 err-failed-read-config-metadata = 读取配置文件元数据失败：{ $error }
 err-failed-set-config-permissions = 设置配置文件权限失败：{ $error }
@@ -554,6 +903,10 @@ err-failed-set-config-permissions = 设置配置文件权限失败：{ $error }
 # =============================================================================
 
 fingerprint-warning = 这可能表示存在安全问题（中间人攻击）或服务器证书已重新生成。仅在信任服务器管理员时才接受。
+fingerprint-new-server-prompt = 这是首次连接此服务器。仅当下方指纹与服务器管理员公布的指纹一致时才信任该证书。
+password-vault-create-description = 没有可用的系统钥匙串。请设置一个主密码短语来加密书签中保存的密码，或跳过以将其保留在配置文件中。
+password-vault-unlock-description = 输入主密码短语以解锁书签中保存的密码。如果跳过，书签将在 Nexus 重新启动前不带密码连接。
+config-unlock-description = 你的设置、书签和聊天记录已加密。输入主密码短语以解锁。如果跳过，Nexus 将使用默认设置启动，并且在重新启动前不会保存任何内容。
 
 # =============================================================================
 # User Info Display
@@ -570,10 +923,12 @@ user-info-account = 账户：{ $username }
 user-info-connected = 已连接：
 user-info-connected-value = { $duration }前
 user-info-connected-value-sessions = { $duration }前（{ $count }个会话）
+user-info-idle = 空闲:
 user-info-features = 功能：
 user-info-features-value = { $features }
 user-info-features-none = 无
 user-info-locale = 语言：
+user-info-client = 客户端:
 user-info-address = 地址：
 user-info-addresses = 地址：
 user-info-created = 创建时间：
@@ -582,6 +937,12 @@ user-info-unknown = 未知
 user-info-loading = 正在加载用户信息…
 user-info-status = 状态:
 user-info-channels = 频道:
+user-info-rank = 等级:
+user-info-logins = 登录:
+user-info-messages = 消息:
+user-info-uploads = 上传:
+user-info-downloaded = 已下载:
+user-info-last-login = 上次登录:
 
 # =============================================================================
 # Time Duration
@@ -605,26 +966,51 @@ err-me-no-target = 使用 /{ $join } 加入频道或 /{ $msg } 向用户发送
 cmd-help-escape-hint = 提示：使用 // 发送以 / 开头的消息
 cmd-message-desc = 向用户发送消息
 cmd-message-usage = 用法：/{ $command } <用户名> <消息>
+cmd-group-desc = 与多位用户开始群组对话
+cmd-group-usage = 用法：/{ $command } <用户名> [<用户名>...]
+cmd-invite-desc = 将用户添加到当前群组对话
+cmd-invite-usage = 用法：/{ $command } <用户名>
 cmd-ping-desc = 测量到服务器的延迟
 cmd-ping-usage = 用法：/{ $command }
+cmd-radio-desc = 向频道的语音听众广播音频
+cmd-radio-usage = 用法：/{ $command }
+err-radio-no-channel = 此命令只能在频道中使用
+err-radio-in-voice = 开始广播前请先退出语音
 ping-result = 响应: { $ms }ms
 cmd-userinfo-desc = 显示用户信息
 cmd-userinfo-usage = 用法：/{ $command } <用户名>
 cmd-kick-desc = 将用户踢出服务器
 cmd-kick-usage = 用法：/{ $command } <用户名> [原因]
+cmd-top-desc = 显示最活跃的用户
+cmd-top-usage = 用法：/{ $command } [logins|messages|uploads|downloaded]
 cmd-topic-desc = 查看或管理聊天主题
-cmd-topic-usage = 用法：/{ $command } [设置|清除] [主题]
+cmd-topic-usage = 用法：/{ $command } [设置|清除|锁定|解锁|历史] [主题]
 cmd-topic-arg-set = 设置
 cmd-topic-arg-clear = 清除
+cmd-topic-arg-lock = 锁定
+cmd-topic-arg-unlock = 解锁
+cmd-topic-arg-history = 历史
 cmd-topic-set-usage = 用法：/{ $command } 设置 <主题>
 cmd-topic-none = 未设置主题
 cmd-broadcast-desc = 向所有用户发送广播
 cmd-broadcast-usage = 用法：/{ $command } <消息>
 cmd-clear-desc = 清除当前标签页的聊天记录
 cmd-clear-usage = 用法：/{ $command }
+cmd-export-desc = 将当前标签页保存为 HTML 文件
+cmd-export-usage = 用法：/{ $command }
+cmd-clip-desc = 列出、播放、添加或删除音效板片段
+cmd-clip-usage = 用法：/{ $command } [列表|播放 <名称>|添加 <名称>|删除 <名称>|<名称>]
+cmd-clip-arg-list = 列表
+cmd-clip-arg-play = 播放
+cmd-clip-arg-add = 添加
+cmd-clip-arg-delete = 删除
 cmd-focus-desc = 聚焦到服务器聊天或用户消息窗口
 cmd-focus-usage = 用法：/{ $command } [用户名]
 cmd-focus-not-found = 未找到用户：{ $name }
+cmd-friend-desc = 列出、添加或移除好友
+cmd-friend-usage = 用法：/{ $command } [list] | add <用户名> | remove <用户名>
+cmd-friend-list-header = 好友：
+cmd-friend-list-empty = 还没有好友。使用 /{ $command } add <用户名> 添加
 cmd-list-desc = 显示已连接/所有用户
 cmd-list-arg-all = 所有
 cmd-list-usage = 用法：/{ $command } [所有]
@@ -634,6 +1020,13 @@ cmd-list-all-no-permission = 您需要 user_edit 或 user_delete 权限才能列
 cmd-list-all-output = 用户：{ $users }（{ $count }位用户）
 cmd-help-usage = 用法：/{ $command } [命令]
 cmd-topic-permission-denied = 您没有编辑主题的权限
+cmd-topic-lock-permission-denied = 您没有锁定主题的权限
+msg-topic-already-locked = 主题已锁定
+msg-topic-already-unlocked = 主题已解锁
+msg-topic-history-header = { $channel } 的主题历史：
+msg-topic-history-set = { $time } { $username }：{ $topic }
+msg-topic-history-cleared = { $time } { $username } 清除了主题
+msg-topic-history-empty = 没有主题更改记录
 cmd-window-desc = 管理聊天标签页
 cmd-window-usage = 用法：/{ $command } [下一个|上一个|关闭 [用户名]]
 cmd-window-arg-next = 下一个
@@ -658,15 +1051,55 @@ cmd-unban-desc = 解除IP封禁
 cmd-unban-usage = 用法: /{ $command } <目标>
 cmd-bans-desc = 列出有效封禁
 cmd-bans-usage = 用法: /{ $command }
+cmd-register-desc = 从访客会话注册账户
+cmd-register-usage = 用法：/{ $command } <用户名> <密码>
 cmd-reindex-desc = 触发文件索引重建
 cmd-reindex-usage = 用法: /{ $command }
+cmd-cleanup-desc = 查找文件区中中断传输的残留
+cmd-cleanup-usage = 用法: /{ $command } [delete]
+cmd-filecheck-desc = 将文件区与搜索索引和文件元数据进行核对
+cmd-filecheck-usage = 用法：/{ $command } [repair]
+cmd-screenshot-desc = 在当前标签页中分享带标注的截图
+cmd-screenshot-usage = 用法：/{ $command }
+err-screenshot-no-target = 截图只能在频道或私信标签页中分享
+err-screenshot-no-folder = 未设置截图文件夹 — 请在“文件”中对上传文件夹选择“在此分享截图”
+err-screenshot-capture = 截图失败：{ $error }
+err-screenshot-save = 无法保存截图：{ $error }
+err-screenshot-upload = 截图上传失败：{ $error }
+err-export-empty = 此标签页中没有可导出的消息
+err-chat-export = 无法导出聊天：{ $error }
+screenshot-title = 分享截图
+screenshot-tool-crop = 裁剪
+screenshot-tool-arrow = 箭头
+screenshot-hint-crop = 拖动框选要保留的部分
+screenshot-hint-arrow = 从箭头尾部拖到箭头尖端
 msg-reindex-triggered = 已触发文件索引重建
+msg-cleanup-nothing = 未发现中断传输的残留
+msg-cleanup-found = 发现中断传输的残留：
+msg-cleanup-deleted = 已删除中断传输的残留：
+msg-cleanup-parts = 孤立的 .part 文件：{ $count }（{ $size }）
+msg-cleanup-temp = 残留的临时文件：{ $count }（{ $size }）
+msg-cleanup-journal = 没有文件的上传日志条目：{ $count }
+msg-cleanup-index = 没有文件的搜索索引条目：{ $count }
+msg-cleanup-hint = 请检查上面的文件，然后运行 /cleanup delete 将其删除
+msg-filecheck-issue = { $kind }：{ $path }
+msg-filecheck-issue-repaired = { $kind }：{ $path }（已修复）
+msg-filecheck-clean = 文件区检查在 { $entries } 个条目中未发现问题
+msg-filecheck-done = 文件区检查在 { $entries } 个条目中发现 { $issues } 个问题，已修复 { $repaired } 个
+msg-filecheck-index-skipped = 搜索索引尚未建立或正等待重建，因此未进行比较
+msg-filecheck-hint = 运行 /filecheck repair 以重建索引并删除缺失路径的元数据
+filecheck-not-indexed = 不在搜索索引中
+filecheck-indexed-but-missing = 在搜索索引中但磁盘上缺失
+filecheck-index-outdated = 搜索索引中的信息已过期
+filecheck-orphaned-activity = 缺失路径的历史记录
+filecheck-orphaned-reviews = 缺失路径的评分或评论
+filecheck-orphaned-upload-rule = 缺失文件夹的上传规则
 cmd-join-desc = 加入或创建频道
 cmd-join-usage = 用法: /{ $command } #频道
 cmd-leave-desc = 离开频道
 cmd-leave-usage = 用法: /{ $command } [#频道]
 cmd-channels-desc = 列出可用频道
-cmd-channels-usage = 用法: /{ $command }
+cmd-channels-usage = 用法: /{ $command } [语言]
 cmd-secret-desc = 切换当前频道的秘密模式
 cmd-secret-usage = 用法: /{ $command } [开|关]
 cmd-secret-arg-on = 开
@@ -679,12 +1112,40 @@ msg-secret-enabled = 频道现在是秘密的
 msg-secret-disabled = 频道现在是公开的
 msg-secret-status-on = 此频道是私密的
 msg-secret-status-off = 此频道是公开的
+cmd-voicelimit-desc = 查看或设置当前频道的语音人数上限
+cmd-voicelimit-usage = 用法：/{ $command } [<人数>|关]
+cmd-vt-desc = 向语音会话发送文本消息
+cmd-vt-usage = 用法：/{ $command } <消息>
+cmd-voicelimit-arg-off = 关
+err-voicelimit-no-channel = 此命令仅在频道中可用
+msg-voice-limit-status = 此频道语音最多允许 { $limit } 人
+msg-voice-limit-status-none = 此频道没有语音上限
+msg-voice-clip-none = 没有音效板片段
+msg-voice-clip-list = 音效板片段（{ $count }）：
+msg-voice-clip-entry = { $name }（{ $seconds } 秒，{ $nickname } 上传）
+msg-voice-clip-uploaded = 片段已添加到音效板
+msg-voice-clip-deleted = 片段已从音效板删除
 cmd-trust-desc = 信任 IP、CIDR 范围或昵称以绕过封禁
 cmd-trust-usage = 用法: /{ $command } <目标> [时长] [原因]
 cmd-untrust-desc = 移除受信任的IP条目
 cmd-untrust-usage = 用法: /{ $command } <目标>
 cmd-trusted-desc = 列出受信任的IP
 cmd-trusted-usage = 用法: /{ $command }
+cmd-ldap-desc = 列出、设置或删除目录组映射
+cmd-ldap-usage = 用法: /{ $command } [list|set <权限|admin|none> <组 DN>|remove <组 DN>]
+cmd-nickname-desc = 为你的账户保留或释放昵称
+cmd-nickname-usage = 用法: /{ $command } [register <昵称> [用户名]|release <昵称>]
+cmd-history-desc = 显示或设置服务器保留私信的天数，或加载更早的消息
+cmd-history-usage = 用法: /{ $command } [<天数>|more]
+cmd-alias-desc = 为命令和消息创建快捷方式
+cmd-alias-usage = 用法: /{ $command } [list|set <名称> <文本>|remove <名称>]
+cmd-alias-list-header = 你的别名：
+cmd-alias-list-empty = 还没有别名。使用 /{ $command } set <名称> <文本> 创建
+cmd-alias-set = /{ $name } 现在执行：{ $text }
+cmd-alias-removed = 已删除别名 /{ $name }
+cmd-alias-not-found = 没有名为 /{ $name } 的别名
+cmd-alias-invalid-name = 别名只能包含字母、数字、- 和 _（最多 { $max } 个字符）
+cmd-alias-builtin = /{ $name } 是内置命令，不能用作别名
 
 # =============================================================================
 # About Panel
@@ -697,9 +1158,25 @@ err-server-image-invalid-format = 服务器图片格式无效（必须是base64
 err-server-image-unsupported-type = 不支持的服务器图片类型（仅支持PNG、WebP、JPEG或SVG）
 err-server-image-decode-failed = 图片解码失败。文件可能已损坏。
 err-failed-read-image = 读取图片失败：{ $error }
-err-news-empty = 帖子必须包含文字或图片
+err-news-empty = 帖子必须包含文字或附件
 err-news-body-too-long = 内容过长（{ $length }个字符，最多{ $max }个）
 err-news-body-invalid-characters = 内容包含无效的控制字符
+err-news-too-many-attachments = 每个帖子最多可以有 { $max } 个附件
+err-news-caption-too-long = 说明过长（最多 { $max } 个字符）
+err-news-caption-invalid-characters = 说明必须为单行且不含控制字符
+err-news-file-path-invalid = 文件路径无效
+err-news-search-date = 无效日期：{ $date }（请使用 YYYY-MM-DD）
+err-file-comment-empty = 评论不能为空
+err-file-comment-too-long = 评论过长（{ $length } 个字符，最多 { $max }）
+err-file-comment-invalid-characters = 评论包含无效的控制字符
+err-upload-rule-invalid-extension = 无效的文件类型"{ $extension }"（请使用字母和数字，如 mp3）
+err-auto-download-pattern-required = 请至少输入一个文件名模式
+err-auto-download-pattern-invalid = 无效的模式"{ $pattern }"（模式匹配文件名，不能包含斜杠）
+err-upload-rule-too-many-extensions = 文件类型过多（最多 { $max } 个）
+err-upload-rule-invalid-size = 无效的大小"{ $value }"（请输入兆字节数）
+err-ldap-group-empty = 组 DN 不能为空
+err-ldap-group-too-long = 组 DN 过长（最多 { $max } 个字符）
+err-ldap-group-invalid-characters = 组 DN 首尾不能有空格，也不能包含控制字符
 err-news-image-decode-failed = 图片解码失败。文件可能已损坏。
 
 # Proxy errors
@@ -707,9 +1184,31 @@ err-proxy-connection-failed = 连接代理失败：{ $error }
 err-proxy-connection-timeout = 代理连接在 { $seconds } 秒后超时
 err-proxy-address-required = 启用代理时需要代理地址
 err-proxy-port-invalid = 代理端口必须在 1 到 65535 之间
-err-news-image-too-large = 图片太大（最大512KB）
+err-news-image-too-large = 图片太大（最大{ $max_kb }KB）
 err-news-image-unsupported-type = 不支持的图片类型（仅支持PNG、WebP、JPEG或SVG）
 err-topic-no-channel = 此命令仅在频道中有效
+err-entry-no-channel = 此命令仅在频道中有效
+cmd-welcome-desc = 查看或设置用户加入频道时显示的消息
+cmd-welcome-usage = 用法：/{ $command } [设置 <消息>|清除]
+cmd-welcome-arg-set = 设置
+cmd-welcome-arg-clear = 清除
+cmd-welcome-none = 未设置入场消息
+msg-entry-message-display = 入场消息：{ $message }
+msg-entry-message-updated = 入场消息已更新
+msg-entry-message-cleared = 入场消息已清除
+cmd-rules-desc = 查看或设置频道规则
+cmd-rules-usage = 用法：/{ $command } [设置 <规则>|清除]
+cmd-rules-arg-set = 设置
+cmd-rules-arg-clear = 清除
+cmd-rules-none = 未设置规则
+msg-rules-header = { $channel } 的规则：
+cmd-language-desc = 查看或设置频道的语言标签
+cmd-language-usage = 用法：/{ $command } [设置 <语言>|清除]
+cmd-language-arg-set = 设置
+cmd-language-arg-clear = 清除
+msg-language-status = 频道语言：{ $language }
+msg-language-none = 未设置语言
+err-language-invalid = 无效的语言标签：{ $language }（例如 zh 或 pt-BR）
 err-join-channel = 加入频道失败: { $error }
 err-leave-channel = 离开频道失败: { $error }
 err-list-channels = 获取频道列表失败: { $error }
@@ -719,6 +1218,8 @@ err-channel-too-long = 频道名称太长（最多{ $max }个字符）
 err-channel-missing-prefix = 频道名称必须以#开头
 err-channel-invalid-characters = 频道名称包含无效字符
 err-leave-no-channel = 不带参数使用/{ $command }时必须在频道中
+err-invite-no-group = 请在群组对话标签页中使用 /{ $command }
+err-group-too-many-members = 群组对话最多可有 { $max } 位成员
 err-not-in-channel = 您不在频道 { $channel } 中
 err-leave-already-pending = 已在等待服务器响应
 err-uri-connection-failed = 连接到 { $host } 失败: { $error }
@@ -732,6 +1233,15 @@ label-port = 端口:
 # Transfer statuses
 transfer-status-queued = 排队中
 transfer-status-connecting = 连接中…
+transfer-status-server-queued = 等待服务器空位 (#{ $position })
+transfer-collision-pending = 文件已存在：{ $path }
+collision-action-renamed = { $count } 个已重命名
+collision-action-overwritten = { $count } 个已覆盖
+collision-action-skipped = { $count } 个已跳过
+transfer-bar-count = { $count } 个传输
+status-bar-users = { $count } 位用户
+status-bar-latency = { $ms } 毫秒
+status-bar-server-time = 服务器时间 { $time } ({ $zone })
 transfer-status-transferring = 传输中
 transfer-status-paused = 已暂停
 transfer-status-completed = 已完成
@@ -790,6 +1300,7 @@ event-broadcast = 广播
 event-chat-message = 聊天消息
 event-chat-mention = 聊天提及
 event-connection-lost = 连接断开
+event-friend-online = 好友上线
 event-news-post = 新闻帖子
 event-permissions-changed = 权限已更改
 event-transfer-complete = 传输完成
@@ -824,6 +1335,17 @@ settings-sound-test = 测试
 settings-notification-test = 测试
 event-settings-show-toast = 显示提示
 settings-toast-test = 测试
+settings-do-not-disturb = 勿扰模式
+settings-quiet-hours = 免打扰时段
+settings-do-not-disturb-hint = 静音通知、提示和声音。未读标记仍会累计，其他人会在你的名字旁看到 🔕。
+settings-notification-rules = 通知规则
+settings-notification-rules-server = 服务器:
+settings-notification-rules-channels = 频道例外
+settings-notification-rules-keywords = 关注的关键词
+settings-notification-rules-no-bookmarks = 将服务器加入书签以设置通知规则。
+channel-notification-all = 所有消息
+channel-notification-mentions = 仅提及
+channel-notification-mute = 静音
 
 # Sound names
 sound-none = 无
@@ -842,6 +1364,8 @@ notification-chat-message = 新聊天消息
 notification-chat-message-from = 来自{$username}的聊天
 notification-connection-lost = 连接断开
 notification-connection-lost-from = 与{$server}的连接已断开
+notification-friend-online = 有好友上线了
+notification-friend-online-name = {$username} 已上线
 notification-download-complete = 下载完成
 notification-download-failed = 下载失败
 notification-news-post = 新的新闻帖子
@@ -895,6 +1419,18 @@ ban-duration-7d = 7天
 ban-duration-30d = 30天
 ban-duration-permanent = 永久
 
+# Broadcast target and delay options
+broadcast-target-label = 发送给：
+broadcast-target-all = 所有人
+broadcast-target-channel = 频道成员
+broadcast-target-permission = 拥有某权限的用户
+broadcast-delay-label = 发送：
+broadcast-delay-now = 立即
+broadcast-delay-5m = 5 分钟后
+broadcast-delay-15m = 15 分钟后
+broadcast-delay-1h = 1 小时后
+broadcast-delay-1d = 1 天后
+
 # =============================================================================
 # Ban System
 # =============================================================================
@@ -944,12 +1480,32 @@ msg-untrusted-success = 信任条目移除成功
 # Trust list messages
 msg-trust-list-header = 受信任的IP：
 msg-trust-list-empty = 无受信任的IP
+msg-ldap-group-list-header = 目录组:
+msg-ldap-group-list-empty = 没有已映射的目录组
+msg-ldap-group-admin = 管理员
+msg-ldap-group-no-permissions = 无权限
+msg-ldap-group-set = 已映射目录组 { $group }
+msg-ldap-group-removed = 已删除目录组 { $group }
+msg-guest-upgraded = 账户 { $username } 已创建。下次请使用它登录。
+msg-nickname-registered = 昵称 { $nickname } 现已为 { $username } 保留。
+msg-nickname-released = 昵称 { $nickname } 已释放。
+msg-history-days = 服务器会保留您的私信 { $days } 天。
+msg-history-off = 服务器不保留您的私信。使用 /history <天数> 开启。
+msg-history-no-older = 没有与 { $nickname } 的更早消息。
+err-history-days-too-long = 消息历史最多可保留 { $max } 天
+err-history-more-tab = 请在私信标签页中使用 /history more
 msg-trust-permanent = 永久
 msg-trust-expired = 已过期
 msg-trust-remaining = 剩余 { $time }
 msg-trust-remaining-days = { $days }天 { $hours }小时
 msg-trust-remaining-hours = { $hours }小时 { $minutes }分钟
 msg-trust-remaining-minutes = { $minutes }分钟
+msg-leaderboard-header = 按{ $stat }排列的最活跃用户：
+msg-leaderboard-empty = 尚无活动记录
+leaderboard-stat-logins = 登录次数
+leaderboard-stat-messages = 消息数
+leaderboard-stat-uploads = 上传数
+leaderboard-stat-downloaded = 下载量
 
 # =============================================================================
 # Voice Chat
@@ -958,6 +1514,16 @@ msg-trust-remaining-minutes = { $minutes }分钟
 # Voice error messages
 err-voice-join = 加入语音聊天失败：{ $error }
 err-voice-leave = 离开语音聊天失败：{ $error }
+err-voice-limit = 设置语音上限失败：{ $error }
+err-voice-clip = 音效板：{ $error }
+err-voice-text = 语音文本：{ $error }
+err-voice-clip-name-empty = 片段名称不能为空
+err-voice-clip-name-too-long = 片段名称过长（最多 { $max } 个字符）
+err-voice-clip-name-invalid = 片段名称只能包含字母、数字、- 和 _
+err-voice-clip-too-large = 片段过大（最大 256KB）
+err-voice-clip-unsupported-type = 不支持的片段格式（仅支持 Ogg Vorbis）
+msg-voice-queued = 语音已满 - 您排在队列第 { $position } 位
+msg-voice-queue-cancelled = 您已被移出语音队列
 err-voice-no-token = 成功加入语音聊天但未收到令牌
 err-voice-already-active = 您已在另一个服务器的语音聊天中
 err-voice-no-permission = 您没有加入语音聊天的权限
@@ -980,7 +1546,12 @@ msg-voice-joined-empty = 已加入 { $target } 的语音聊天
 msg-voice-left = 已离开 { $target } 的语音聊天
 msg-voice-user-joined = { $nickname } 加入了语音聊天
 msg-voice-user-left = { $nickname } 离开了语音聊天
+msg-voice-broadcast-started = { $nickname } 正在语音中广播 - 加入即可收听
+msg-voice-broadcast-ended = 语音广播已结束
+msg-voice-clip-played = { $nickname } 播放了 { $name }
+msg-voice-text = [语音] { $nickname }：{ $message }
 msg-voice-you-left = 您已离开语音聊天
+msg-voice-tcp-relay = 语音 UDP 连接失败 ({ $error }) - 改为通过服务器连接中继语音
 
 # Voice UI
 voice-join-tooltip = 加入语音聊天
@@ -988,6 +1559,8 @@ voice-leave-tooltip = 离开语音聊天
 voice-mute-all-tooltip = 全部静音
 voice-unmute-all-tooltip = 取消静音
 voice-bar-participants = ({ $count })
+voice-bar-queued = （排队：{ $position }）
+voice-bar-listeners = （{ $count } 人收听）
 voice-indicator-tooltip = 语音聊天中
 tooltip-mute = 静音
 tooltip-unmute = 取消静音
@@ -998,6 +1571,12 @@ voice-quality-medium = 中 (32 kbps)
 voice-quality-high = 高 (64 kbps)
 voice-quality-very-high = 极高 (96 kbps)
 
+# Voice connection quality
+voice-connection-good = 良好
+voice-connection-fair = 一般
+voice-connection-poor = 较差
+voice-connection-tooltip = 连接：{ $quality }（丢包 { $loss }%，抖动 { $jitter } 毫秒）
+
 # PTT modes
 ptt-mode-hold = 按住说话
 ptt-mode-toggle = 切换
@@ -1012,6 +1591,8 @@ ptt-delay-500ms = 500毫秒
 audio-output-device = 输出设备
 audio-input-device = 输入设备
 audio-refresh-devices = 刷新设备
+audio-clip-volume = 音效板音量
+audio-voice-text-tts = 朗读语音文本消息
 audio-voice-quality = 语音质量
 audio-ptt-key = 按键说话键
 audio-ptt-mode = PTT 模式
@@ -1033,6 +1614,44 @@ audio-echo-cancellation = 回声消除
 audio-agc = 自动增益控制
 audio-transient-suppression = 键盘噪音消除
 
+# =============================================================================
+# Global Hotkeys
+# =============================================================================
+
+# Settings
+settings-toggle-window-key = 显示/隐藏窗口键
+button-clear-hotkey = 清除
+
+# Shortcut descriptions (shown by the desktop on Wayland)
+hotkey-push-to-talk = 按键说话
+hotkey-toggle-window = 显示或隐藏 Nexus
+
+# Errors
+err-window-hotkey-failed = 显示/隐藏窗口热键注册失败：{ $error }
+err-global-shortcuts-unavailable = 全局快捷键门户不可用，热键仅在 Nexus 获得焦点时有效：{ $error }
+
+# =============================================================================
+# Auto Away
+# =============================================================================
+
+settings-auto-away = 空闲时自动设为离开
+settings-auto-away-minutes = 空闲分钟数
+
+# =============================================================================
+# Connection Health
+# =============================================================================
+
+# Server list indicator
+connection-health-good = 良好
+connection-health-fair = 一般
+connection-health-poor = 较差
+connection-health-tooltip = 连接：{ $quality }
+connection-health-latency = 延迟：{ $ms } 毫秒
+connection-health-jitter = 抖动：{ $ms } 毫秒
+connection-health-last-drop = 上次丢失 ping：{ $time }
+connection-health-no-drops = 没有丢失的 ping
+connection-health-measuring = 正在测量延迟…
+
 # =============================================================================
 # System Tray (Windows/Linux only)
 # =============================================================================
@@ -1058,15 +1677,53 @@ tray-tooltip-voice = Nexus BBS - 语音中: { $target }
 tray-tooltip-voice-muted = Nexus BBS - 语音中 (已静音): { $target }
 tray-tooltip-unread = Nexus BBS - { $count } 个未读对话
 
+# =============================================================================
+# Notification Actions (macOS/Windows only)
+# =============================================================================
+
+# Actions
+notification-reply = 回复
+notification-reply-send = 发送
+notification-reply-placeholder = 回复…
+notification-mark-read = 标记为已读
+
 # =============================================================================
 # Toast Notifications
 # =============================================================================
 
 toast-link-copied = 链接已复制到剪贴板
 toast-copied = 已复制到剪贴板
+toast-checksum-copied = SHA-256 已复制到剪贴板
 toast-download-started = 正在下载 { $filename }
 toast-download-queued = 下载已排队: { $filename }
+toast-downloads-started = 正在下载 { $count } 个文件
+toast-downloads-queued = { $count } 个下载已排队
 toast-upload-started = 正在上传 { $filename }
 toast-upload-queued = 上传已排队: { $filename }
 toast-uploads-started = 正在上传 { $count } 个文件
 toast-uploads-queued = { $count } 个上传已排队
+toast-upload-nothing-new = 没有需要上传的内容 — 服务器上已有这些文件
+toast-upload-watch-started = 正在监视 { $folder } — 新文件将自动上传
+toast-upload-watch-stopped = 已停止监视 { $folder }
+toast-auto-download-started = 正在从 { $folder } 自动下载 { $pattern }
+toast-auto-download-stopped = 已停止从 { $folder } 自动下载
+toast-screenshot-folder-set = 截图将上传到此文件夹
+toast-chat-exported = 聊天已导出
+
+# =============================================================================
+# First-run Tips
+# =============================================================================
+
+tip-welcome-title = 欢迎使用 Nexus
+tip-welcome = 在下方输入服务器地址并连接。添加书签可将服务器连同登录信息保存在左侧列表中，下次只需点击一下即可连接。
+tip-fingerprint = 每个服务器都有一个证书指纹，类似签名。Nexus 会在首次连接时记住它，并在它发生变化时提醒你，因为这可能意味着有人在冒充该服务器。只有在服务器管理员告知你指纹已更改时，才接受新的指纹。
+tip-server-list = 你的书签和已打开的连接在左侧。点击即可切换服务器，拖动书签可调整顺序。
+tip-toolbar = 工具栏可打开文件、新闻、设置等。将鼠标悬停在图标上可查看其功能。
+tip-chat = 在这里与服务器上的所有人聊天。输入 / 查看可用的命令，例如用 /join 加入频道。
+tip-user-list = 当前在线的用户列在右侧。点击名字可以发送消息或查看信息。
+tip-counter = 提示 { $current }/{ $total }
+button-add-bookmark = 添加书签
+button-tip-next = 下一条
+button-tip-done = 完成
+button-tip-got-it = 知道了
+button-skip-tips = 跳过提示
@@ -11,6 +11,13 @@ button-connect = 連線
 button-save = 儲存
 button-create = 建立
 button-edit = 編輯
+button-check-ports = 檢測連接埠
+button-statistics = 統計
+button-dashboard = 儀表板
+button-refresh = 重新整理
+button-doors = 門程式
+button-doors-leave = 離開
+button-doors-back = 返回
 button-update = 更新
 button-accept-new-certificate = 接受新憑證
 button-close = 關閉
@@ -18,9 +25,23 @@ button-choose-avatar = 選擇頭像
 button-clear-avatar = 清除
 button-choose-image = 選擇圖片
 button-clear-image = 清除
+button-add-file = 新增檔案
 button-overwrite = 覆蓋
 button-change-password = 修改密碼
 button-browse = 瀏覽
+button-undo = 復原
+button-add = 新增
+button-remove = 移除
+button-view = 檢視
+button-hide = 隱藏
+button-export = 匯出…
+button-trust-certificate = 信任憑證
+button-skip = 略過
+button-skip-existing = 略過已有檔案
+button-download-anyway = 仍要下載
+button-unlock = 解鎖
+button-view-as-user = 以使用者身分檢視
+button-post-comment = 發佈
 
 # =============================================================================
 # Titles
@@ -35,19 +56,29 @@ title-user-create = 建立使用者
 title-user-edit = 編輯使用者
 title-update-user = 更新使用者
 title-user-management = 使用者管理
+title-permission-audit = 權限稽核
+title-impersonate = 正在以 { $username } 身分檢視
 title-confirm-delete = 確認刪除
 title-connected = 已連線
 title-settings = 設定
 title-bookmarks = 書籤
 title-users = 使用者
+title-friends = 好友
+friend-online = 線上
+friend-offline = 離線
 title-edit-server-info = 編輯伺服器資訊
 title-fingerprint-mismatch = 憑證指紋不符！
+title-fingerprint-new-server = 新伺服器憑證
+title-create-password-vault = 保護已儲存的密碼
+title-unlock-password-vault = 解鎖已儲存的密碼
+title-unlock-config = 解鎖設定
 title-server-info = 伺服器資訊
 title-user-info = 使用者資訊
 title-about = 關於
 title-change-password = 修改密碼
 title-news = 新聞
 title-transfers = 傳輸
+title-transfer-history = 傳輸記錄
 title-news-create = 建立貼文
 title-news-edit = 編輯貼文
 title-channel-members = 成員
@@ -58,10 +89,14 @@ title-channel-members = 成員
 
 placeholder-username = 使用者名稱
 placeholder-password = 密碼
+placeholder-master-passphrase = 主密碼短語
+placeholder-confirm-master-passphrase = 確認主密碼短語
 placeholder-port = 連接埠
 placeholder-server-address = 伺服器位址
 placeholder-server-name = 伺服器名稱
+placeholder-folder-name = 資料夾名稱
 placeholder-username-optional = 使用者名稱（選填）
+placeholder-fallback-addresses-optional = 備用位址（選填，以逗號分隔）
 placeholder-password-optional = 密碼（選填）
 placeholder-password-keep-current = 密碼
 placeholder-current-password = 目前密碼
@@ -70,10 +105,18 @@ placeholder-confirm-password = 確認密碼
 placeholder-message = 輸入訊息…
 placeholder-no-permission = 無權限
 placeholder-broadcast-message = 廣播訊息
+placeholder-broadcast-channel = 選擇頻道
+placeholder-broadcast-permission = 選擇權限
 placeholder-server-description = 伺服器描述
 placeholder-news-body = 新聞內容
+placeholder-news-caption = 說明（選填）
+placeholder-news-file-path = 檔案區中的路徑
 placeholder-nickname-optional = 暱稱（選填）
+placeholder-status-optional = 狀態（選填）
+placeholder-download-folder-optional = 下載資料夾（選填）
 placeholder-proxy-address = 代理伺服器地址
+placeholder-notification-rules-channel = #頻道
+placeholder-notification-rules-keyword = 關鍵字
 placeholder-proxy-port = 9050
 placeholder-proxy-username = 使用者名稱（選填）
 placeholder-proxy-password = 密碼（選填）
@@ -86,13 +129,20 @@ placeholder-auto-join-channels = #頻道1 #頻道2（空格分隔）
 # =============================================================================
 
 label-auto-connect = 自動連線
+label-server-avatar = 頭像:
 label-add-bookmark = 新增書籤
+label-lan-servers = 本地網路
+label-use-sso = 單一登入
 label-admin = 管理員
 label-shared-account = 共享帳戶
 label-enabled = 啟用
+label-erase-personal-data = 同時清除個人資料
+label-broadcast-require-ack = 要求接收者確認
 label-permissions = 權限：
 label-expected-fingerprint = 預期的指紋：
 label-received-fingerprint = 收到的指紋：
+label-certificate-fingerprint = 憑證指紋:
+label-identity-key = 身分金鑰:
 label-theme = 主題
 label-chat-history-retention = 聊天記錄：
 chat-history-forever = 永久
@@ -100,6 +150,10 @@ chat-history-30-days = 30天
 chat-history-14-days = 14天
 chat-history-7-days = 7天
 chat-history-disabled = 停用
+collision-policy-rename = 保留兩者（重新命名）
+collision-policy-overwrite = 覆寫
+collision-policy-skip = 略過
+collision-policy-ask = 詢問
 label-max-scrollback = 最大歷史記錄：
 label-chat-font-size = 字型大小：
 label-show-connection-events = 顯示連線/斷線事件
@@ -107,6 +161,19 @@ label-show-channel-events = 顯示加入/離開事件
 label-show-timestamps = 顯示時間戳記
 label-use-24-hour-time = 使用24小時制
 label-show-seconds = 顯示秒數
+label-split-view = 在聊天旁顯示檔案、新聞和連線監視器
+label-spell-check = 在聊天和新聞中檢查拼字
+label-spell-check-no-dictionary = 找不到你所用語言的字典。請將 .aff 和 .dic 檔案放入 config.json 旁的 dictionaries 資料夾。
+label-send-read-receipts = 傳送私訊已讀回條
+label-user-list-grouping = 使用者清單分組:
+label-user-list-sort = 使用者清單排序:
+user-list-group-none = 無
+user-list-group-admins = 管理員優先
+user-list-group-channel = 依頻道
+user-list-group-status = 依狀態
+user-list-sort-alphabetical = 依字母
+user-list-sort-idle = 閒置時間
+user-list-sort-connect = 連線時間
 label-server-name = 名稱：
 label-server-description = 描述：
 label-server-version = 版本：
@@ -137,6 +204,27 @@ tab-files = 檔案
 tab-channels = 頻道
 label-connections-short = 連線：
 label-transfers-short = 傳輸：
+label-active-transfers-short = 活動：
+label-queue-order-short = 佇列順序：
+label-trusted-weight-short = 信任權重：
+label-nat-mapping-short = 連接埠對應：
+label-nat-external-ip-short = 外部 IP：
+label-nat-not-mapped = 未對應
+connectivity-check-running = 正在檢測連接埠…
+check-service-bbs = BBS
+check-service-transfer = 傳輸
+check-service-websocket = WebSocket
+check-service-transfer-websocket = WebSocket 傳輸
+check-service-voice = 語音
+check-state-open = 開放
+check-state-closed = 關閉
+check-state-filtered = 被過濾
+check-state-untested = 未檢測
+label-check-port = { $service }（{ $protocol } { $port }）：
+label-queue-order-small-first = 小檔案優先
+label-queue-order-arrival = 到達順序
+label-small-files-first = 小檔案優先排隊
+label-unlimited = 無限制
 label-reindex-short = 重建索引：
 label-persistent-short = 常駐：
 label-auto-join-short = 自動加入：
@@ -153,6 +241,19 @@ label-download-location = 下載位置:
 label-queue-transfers = 佇列傳輸
 label-download-limit = 下載限制:
 label-upload-limit = 上傳限制:
+label-auto-open-folder = 下載完成時開啟資料夾
+label-collision-policy = 檔案已存在時：
+label-require-fingerprint-approval = 信任新伺服器的憑證前詢問
+label-known-servers = 已知伺服器
+known-servers-empty = 尚未儲存伺服器憑證
+known-server-first-seen = 首次見到 { $date }
+known-server-first-seen-unknown = 首次見到: 未知
+label-encrypt-config = 使用主密碼短語加密設定、書籤和聊天記錄
+config-encryption-locked-note = 設定已鎖定。請重新啟動 Nexus 並輸入主密碼短語以變更此項。
+label-upload-rule-extensions = 允許的檔案類型：
+label-upload-rule-max-file-size = 最大檔案 (MB)：
+label-upload-rule-max-total-size = 最大上傳 (MB)：
+label-auto-download-pattern = 檔案名稱:
 label-persistent-channels = 常駐頻道:
 label-auto-join-channels = 自動加入頻道:
 label-channels = 頻道
@@ -166,11 +267,14 @@ permission-user_info = 使用者資訊
 permission-chat_send = 聊天傳送
 permission-chat_receive = 聊天接收
 permission-chat_join = 加入頻道
+permission-chat_language = 聊天語言
 permission-chat_create = 建立頻道
 permission-chat_list = 頻道列表
 permission-chat_secret = 頻道私密設定
 permission-chat_topic = 聊天主題
 permission-chat_topic_edit = 編輯聊天主題
+permission-chat_topic_lock = 鎖定聊天主題
+permission-chat_entry_edit = 編輯聊天入場訊息
 permission-user_broadcast = 使用者廣播
 permission-user_create = 建立使用者
 permission-user_delete = 刪除使用者
@@ -181,6 +285,7 @@ permission-news_list = 新聞列表
 permission-news_create = 建立新聞
 permission-news_edit = 編輯新聞
 permission-news_delete = 刪除新聞
+permission-file_comment = 評論檔案
 permission-file_copy = 檔案複製
 permission-file_create_dir = 目錄建立
 permission-file_delete = 刪除檔案
@@ -200,6 +305,11 @@ permission-trust_create = 建立信任
 permission-trust_delete = 刪除信任
 permission-trust_list = 信任清單
 permission-connection_monitor = 連線監視器
+permission-log_view = 檢視日誌
+permission-door_play = 執行門程式
+permission-voice_broadcast = 語音廣播
+permission-voice_clip_upload = 上傳語音片段
+permission-voice_limit = 語音上限
 permission-voice_listen = 語音收聽
 permission-voice_talk = 語音發言
 
@@ -224,19 +334,31 @@ tooltip-files-paste = 貼上
 tooltip-files-search = 搜尋檔案
 tooltip-manage-users = 管理使用者
 tooltip-connection-monitor = 連線監視器
+tooltip-server-log = 伺服器日誌
 tooltip-server-info = 伺服器資訊
 tooltip-about = 關於
 tooltip-transfers = 傳輸
 tooltip-download-all = 全部下載
 tooltip-upload = 上傳
 file-picker-upload-title = 選擇要上傳的檔案
+file-picker-download-to-title = 選擇下載資料夾
+folder-picker-upload-watch-title = 選擇要自動上傳的資料夾
 drop-to-upload = 拖放以上傳
 tooltip-clear-inactive = 清除非活動項
+tooltip-transfer-history = 傳輸記錄
+tooltip-transfer-history-clear = 清除記錄
+tooltip-transfer-history-remove = 從記錄中移除
 tooltip-transfer-pause = 暫停
 tooltip-transfer-resume = 繼續
 tooltip-transfer-cancel = 取消
 tooltip-transfer-remove = 移除
 tooltip-transfer-open-folder = 開啟資料夾
+tooltip-transfer-open-file = 開啟檔案
+tooltip-transfer-reveal = 在檔案管理員中顯示
+tooltip-transfer-copy-checksum = 複製 SHA-256
+tooltip-transfer-collision-rename = 保留兩者（重新命名）
+tooltip-transfer-collision-overwrite = 覆寫現有檔案
+tooltip-transfer-collision-skip = 略過現有檔案
 tooltip-transfer-move-up = 上移
 tooltip-transfer-move-down = 下移
 tooltip-transfer-retry = 重試
@@ -249,16 +371,34 @@ tooltip-disconnect = 斷開連線
 tooltip-edit = 編輯
 tooltip-info = 資訊
 tooltip-message = 訊息
+tooltip-add-friend = 加入好友
+tooltip-remove-friend = 移除好友
 tooltip-kick = 踢出
 tooltip-close = 關閉
+tooltip-retry-send = 重新傳送
+tooltip-cancel-send = 取消傳送
+tooltip-message-sent = 已傳送
+tooltip-message-delivered = 已送達
+tooltip-message-read = 已讀
 tooltip-new-tab = 新分頁
 tooltip-close-tab = 關閉分頁
 tooltip-add-bookmark = 新增書籤
+tooltip-new-folder = 新增資料夾
 tooltip-create-user = 建立使用者
+tooltip-permission-audit = 稽核權限
 tooltip-create-news = 建立貼文
+tooltip-news-search = 搜尋新聞
+tooltip-news-search-clear = 清除搜尋
 tooltip-delete = 刪除
 context-menu-download = 下載
+context-menu-download-to = 下載到…
+context-menu-download-select = 選擇下載…
+context-menu-auto-download = 自動下載…
 context-menu-upload = 上傳
+context-menu-watch-folder = 監看本機資料夾…
+context-menu-screenshot-folder = 在此分享螢幕截圖
+context-menu-unwatch-folder = 停止監看資料夾
+context-menu-upload-rules = 上傳規則…
 context-menu-open = 開啟
 
 # =============================================================================
@@ -266,6 +406,7 @@ context-menu-open = 開啟
 # =============================================================================
 
 panel-connection-monitor = 連線監視器
+panel-server-log = 伺服器日誌
 panel-active-connections = 活躍連線: { $count }
 tab-connections = 連線
 tab-transfers = 傳輸
@@ -280,9 +421,97 @@ menu-info = 資訊
 menu-copy = 複製
 menu-kick = 踢出
 menu-ban = 封鎖
+menu-pop-out = 彈出
+menu-mute-tab = 靜音
+menu-unmute-tab = 取消靜音
+menu-connect-all = 全部連線
+menu-rename = 重新命名
+menu-delete = 刪除
 connection-monitor-loading = 正在載入連線…
 connection-monitor-no-connections = 無連線
 connection-monitor-no-transfers = 無活躍傳輸
+connection-monitor-queued = 排隊中
+connection-monitor-weight = 公平分配權重：×{ $weight }
+connection-monitor-listed = 已被 { $zone } 列入
+connection-monitor-queued-count = { $count } 個排隊中
+placeholder-server-log-module = 依模組篩選
+server-log-loading = 正在載入伺服器日誌…
+server-log-empty = 沒有日誌
+server-log-level-error = 僅錯誤
+server-log-level-warn = 警告及以上
+server-log-level-info = 資訊及以上
+server-log-level-debug = 全部（除錯）
+panel-statistics = 統計
+stats-loading = 正在載入統計…
+panel-snippet = 程式碼片段
+snippet-loading = 正在載入程式碼片段…
+snippet-details = 由 { $author } 分享 · { $expires } 到期
+stats-range-days = 最近 { $days } 天
+stats-chart-max = 最高日：{ $value }
+stats-metric-logins = 登入
+stats-metric-messages = 訊息
+stats-metric-uploads = 上傳
+stats-metric-upload-bytes = 上傳資料量
+stats-metric-downloads = 下載
+stats-metric-download-bytes = 下載資料量
+stats-metric-peak-sessions = 線上使用者峰值
+panel-admin-dashboard = 管理儀表板
+dashboard-loading = 正在載入儀表板…
+dashboard-connections = 連線
+dashboard-sessions = 線上使用者
+dashboard-connection-count = 連線數
+dashboard-transfer-connections = 傳輸連線
+dashboard-transfers = 傳輸
+dashboard-active-transfers = 進行中
+dashboard-queued-transfers = 排隊中
+dashboard-today = 今天
+dashboard-disk = 檔案區
+dashboard-disk-free = 可用空間
+dashboard-disk-free-of = { $total } 中可用 { $free }
+dashboard-disk-unknown = 未知
+dashboard-recent-audit = 最近的稽核事件
+dashboard-audit-empty = 尚無稽核事件
+dashboard-updated = 更新於 { $time }
+panel-doors = 門程式
+doors-loading = 正在載入門程式…
+doors-empty = 此伺服器沒有門程式
+doors-starting = 正在啟動…
+doors-playing-hint = 按鍵會傳送到門程式。使用離開結束。
+doors-exited = 門程式已結束
+doors-exited-code = 門程式以代碼 { $code } 結束
+
+# =============================================================================
+# Quick Switcher
+# =============================================================================
+
+quick-switcher-placeholder = 跳至頻道、訊息、書籤或面板...
+user-list-filter-placeholder = 篩選使用者...
+user-list-section-admins = 管理員
+user-list-section-users = 使用者
+user-list-section-no-channel = 其他
+user-list-section-online = 線上
+user-list-section-away = 離開
+user-list-section-dnd = 勿擾
+user-list-idle = 閒置 { $duration }
+quick-switcher-no-matches = 沒有符合的項目
+quick-switcher-bookmark = 書籤
+
+# =============================================================================
+# Chat Input
+# =============================================================================
+
+chat-paste-prompt = 貼上 { $count } 行？
+chat-paste-too-long-prompt = 內容太長，無法作為一則訊息傳送（最多 { $max }）。仍要傳送嗎？
+button-send-as-messages = 以 { $count } 則訊息傳送
+button-send-as-one-message = 以一則訊息傳送
+button-send-in-parts = 分 { $count } 則傳送
+button-share-as-snippet = 以程式碼片段分享
+button-expand-code = 顯示全部 { $count } 行
+button-collapse-code = 收合
+chat-broadcast-ack-prompt = { $username } 請您確認其廣播
+button-acknowledge = 確認
+button-dismiss = 忽略
+spell-no-suggestions = 沒有建議
 
 # =============================================================================
 # Empty States
@@ -293,11 +522,35 @@ empty-no-connections = 無連線
 empty-no-bookmarks = 無書籤
 empty-no-users = 沒有線上使用者
 empty-no-channel-members = 頻道中沒有成員
+empty-no-matching-users = 沒有符合的使用者
 user-management-loading = 正在載入使用者…
 user-management-no-users = 找不到使用者
+permission-audit-hint = 選擇一個權限以查看誰擁有它，或點擊使用者以查看其能做的一切。
+permission-audit-loading = 載入中…
+permission-audit-no-holders = 沒有帳號擁有此權限
+permission-audit-effective = { $username } 的有效權限
+permission-audit-admin = 管理員擁有所有權限
+permission-audit-no-permissions = 此帳號沒有任何權限
+permission-audit-restricted = 已授予但共用帳號無法使用：
+permission-audit-disabled = 已停用
+impersonate-notice = 此帳號所見內容的唯讀檢視。每次檢視都會記錄在稽核日誌中。
+impersonate-permissions = 權限
+impersonate-files = 檔案（{ $path }）
+impersonate-no-file-access = 此帳號無法瀏覽檔案
+impersonate-empty-folder = 此資料夾是空的
+impersonate-channels = 頻道
+impersonate-no-channel-access = 此帳號無法列出頻道
+impersonate-no-channels = 沒有可見的頻道
+impersonate-secret = 秘密
 news-loading = 正在載入新聞…
 news-no-posts = 目前沒有貼文
+news-search-placeholder = 搜尋新聞… (author:使用者名稱 since:YYYY-MM-DD until:YYYY-MM-DD)
+news-searching = 搜尋中…
+news-search-no-results = 沒有符合的新聞
 transfers-empty = 無傳輸
+transfer-history-search-placeholder = 依名稱、伺服器或資料夾搜尋…
+transfer-history-empty = 尚無已完成的傳輸
+transfer-history-no-matches = 沒有符合搜尋的傳輸
 news-updated = 已更新
 files-loading = 正在載入檔案…
 files-empty = 此目錄為空
@@ -309,10 +562,16 @@ files-column-name = 名稱
 files-column-size = 大小
 files-column-modified = 修改日期
 files-column-path = 路徑
+files-column-rating = 評分
 files-search-placeholder = 搜尋檔案…
 files-search-breadcrumb = 搜尋 - { $query }
 files-searching = 搜尋中…
 files-no-results = 找不到檔案
+files-highlights = 精選
+files-highlights-recent = 本週新上傳
+files-highlights-popular = 最多下載
+files-highlights-empty = 尚無內容
+files-highlights-downloads = { $count } 次下載
 files-search-query-too-short = 搜尋查詢太短（最少{ $min_length }個字元）
 files-search-query-too-long = 搜尋查詢太長（最多{ $max_length }個字元）
 files-search-query-invalid = 搜尋查詢包含無效字元
@@ -323,6 +582,24 @@ files-share = 分享
 files-rename = 重新命名
 files-rename-title = 重新命名「{ $name }」
 files-rename-placeholder = 新名稱
+files-upload-rule-title = 「{ $name }」的上傳規則
+files-upload-rule-loading = 載入中…
+files-upload-rule-extensions-placeholder = 任何類型（例如 mp3, flac）
+files-upload-rule-no-limit = 不限制
+files-upload-rule-remove = 移除規則
+files-upload-rule-help = 規則適用於此資料夾及其子資料夾，除非子資料夾有自己的規則。
+files-auto-download-title = 從「{ $name }」自動下載
+files-auto-download-pattern-placeholder = 例如 *.mp3, *.m4a
+files-auto-download-remove = 停止自動下載
+files-auto-download-help = 透過此書籤連線期間，此資料夾中名稱相符的新檔案會被下載。* 代表任意文字，? 代表單一字元，多個模式請以逗號分隔。已下載過的檔案會被略過。
+files-upload-rule-hint = 此處上傳：{ $limits }
+files-upload-rule-hint-types = 僅限 { $extensions }
+files-upload-rule-hint-file-size = 每個檔案最多 { $size }
+files-upload-rule-hint-total-size = 每次上傳最多 { $size }
+files-upload-preview-title = 檔案已存在
+files-upload-preview-comparing = 正在與伺服器上的檔案比較…
+files-upload-preview-summary = 新檔案 { $new } 個，伺服器上已有 { $identical } 個，內容不同 { $conflicts } 個：
+files-upload-preview-blocked = { $count } 個檔案在伺服器上有同名資料夾，將被略過
 files-cut = 剪下
 files-copy = 複製
 files-paste = 貼上
@@ -332,6 +609,13 @@ files-delete-confirm-title = 確認刪除
 files-delete-confirm-message = 您確定要刪除「{ $name }」嗎？
 # File Overwrite Dialog
 files-overwrite-title = 檔案已存在
+files-download-to-title = 下載 { $name }
+label-remember-for-server = 為此伺服器記住
+label-select-all = 全選
+files-download-select-summary = 已選擇 { $count }/{ $total } ({ $size })
+files-download-select-too-many = 最多只能單獨選擇 { $max } 個檔案。請改為下載整個資料夾。
+files-download-duplicate-title = 已下載
+files-download-duplicate-message = 「{ $name }」已存在於此電腦的 { $path }。是否要再次下載？
 files-overwrite-message = 目的地已存在名為「{ $name }」的檔案或資料夾。是否要取代？
 
 # File Info Dialog
@@ -347,6 +631,14 @@ files-info-directory = 目錄
 files-info-symlink = 符號連結：
 files-info-yes = 是
 files-info-na = 不適用
+files-info-rating = 評分：
+files-info-rating-value = { $average }（{ $count } 個評分）
+files-info-not-rated = 尚未評分
+files-info-your-rating = 我的評分：
+files-info-comments = 評論（{ $count }）
+files-comments-loading = 正在載入評論…
+files-comments-empty = 尚無評論
+files-comment-placeholder = 撰寫評論…
 
 # =============================================================================
 # Directory Name Validation Errors
@@ -365,6 +657,7 @@ err-dir-name-invalid = 資料夾名稱包含無效字元
 tab-general = 一般
 tab-chat = 聊天
 tab-network = 網路
+tab-security = 安全性
 tab-audio = 音訊
 
 # =============================================================================
@@ -386,6 +679,8 @@ chat-prefix-system = [系統]
 chat-prefix-error = [錯誤]
 chat-prefix-info = [資訊]
 chat-prefix-broadcast = [BROADCAST]
+chat-prefix-queued = [PENDING]
+chat-export-subtitle = 於 { $date } 從 { $server } 匯出
 
 # =============================================================================
 # Success Messages
@@ -406,11 +701,25 @@ msg-channel-list-header = 可用頻道:
 msg-no-channels = 沒有可用頻道
 msg-channel-member-count = { $count } 個成員
 channel-secret = 私密
+channel-info-topic = 主題
+channel-info-rules = 規則
+channel-info-language = 語言
 msg-broadcast-sent = 廣播發送成功
+msg-broadcast-delivered = 廣播已送達 { $count } 位使用者
+msg-broadcast-scheduled = 廣播已排定在 { $time } 傳送
+msg-broadcast-acknowledged = { $delivered } 位使用者中有 { $count } 位已確認廣播
+msg-storage-low = 伺服器上{ $volume }的磁碟空間不足：可用 { $free }，低於最低值 { $threshold }
+msg-storage-recovered = 伺服器上{ $volume }的磁碟空間已恢復：可用 { $free }
+storage-volume-files = 檔案區
+storage-volume-database = 資料庫
+msg-friend-added = 已將 { $username } 加入好友
+msg-friend-removed = 已將 { $username } 從好友中移除
 msg-user-created = 使用者已成功建立
 msg-user-created-name = 使用者 '{ $username }' 已成功建立
 msg-user-deleted = 使用者已成功刪除
 msg-user-deleted-name = 使用者 '{ $username }' 已成功刪除
+msg-user-erased = 使用者已刪除，個人資料已清除
+msg-user-erased-name = 使用者 '{ $username }' 已刪除，個人資料已清除
 msg-user-updated = 使用者更新成功
 msg-user-updated-name = 使用者 '{ $username }' 更新成功
 msg-permissions-updated = 您的權限已更新
@@ -425,6 +734,8 @@ msg-welcome-back = 歡迎回來
 msg-status-set = 狀態已設定: { $status }
 msg-status-cleared = 狀態已清除
 msg-user-is-away = { $nickname } 已離開
+msg-group-member-joined = { $nickname } 加入了對話
+msg-group-member-left = { $nickname } 離開了對話
 msg-user-is-away-status = { $nickname } 已離開: { $status }
 
 
@@ -436,6 +747,14 @@ msg-topic-cleared = { $username } 清除了主題
 msg-topic-set = { $username } 設定了主題：{ $topic }
 msg-secret-set = { $username } 啟用了秘密模式
 msg-secret-cleared = { $username } 停用了秘密模式
+msg-topic-locked = { $username } 鎖定了主題
+msg-topic-unlocked = { $username } 解鎖了主題
+msg-rules-set = { $username } 更新了頻道規則
+msg-rules-cleared = { $username } 清除了頻道規則
+msg-language-set = { $username } 將頻道語言設定為 { $language }
+msg-language-cleared = { $username } 清除了頻道語言
+msg-voice-limit-set = { $username } 將語音上限設為 { $limit }
+msg-voice-limit-cleared = { $username } 取消了語音上限
 msg-server-info-updated = 伺服器設定已更新
 msg-topic-display = 主題：{ $topic }
 confirm-delete-user = 確定要刪除使用者 '{ $username }' 嗎？
@@ -455,6 +774,7 @@ err-user-kick-failed = 踢除使用者失敗
 err-unknown = 未知錯誤
 err-no-shutdown-handle = 連線錯誤：無關閉控制代碼
 err-userlist-failed = 重新整理使用者清單失敗
+err-request-timeout = 伺服器未及時回應
 err-port-invalid = 連接埠必須是有效數字（1-65535）
 
 # Network connection errors
@@ -468,12 +788,16 @@ err-connection-closed = 連線已關閉
 err-could-not-determine-config-dir = 無法確定設定目錄
 err-message-too-long = 訊息太長（{ $length } 個字元，最多 { $max }）
 err-send-failed = 傳送訊息失敗
+err-checksum-failed = 計算校驗和失敗
 err-no-chat-permission = 您沒有傳送訊息的權限
+err-chat-window-command = 聊天視窗中只能傳送訊息和 /me
 err-console-no-send = 使用 /{ $join } 加入頻道或 /{ $msg } 向使用者傳送訊息
 err-broadcast-too-long = 廣播太長（{ $length } 個字元，最多 { $max }）
 err-broadcast-send-failed = 傳送廣播失敗
+err-broadcast-target-required = 請選擇廣播的接收者
 err-name-required = 書籤名稱為必填
 err-address-required = 伺服器位址為必填
+err-fallback-address-invalid = 無效的備用位址: { $address }
 err-port-required = 連接埠為必填
 err-username-required = 使用者名稱為必填
 err-password-required = 密碼為必填
@@ -491,13 +815,20 @@ err-nickname-too-long = 暱稱過長（最多{ $max }個字元）
 err-nickname-invalid = 暱稱包含無效字元
 err-password-too-long = 密碼過長（最多{ $max }個字元）
 err-passwords-do-not-match = 密碼不符
+err-wrong-master-passphrase = 主密碼短語錯誤
+err-master-passphrase-empty = 主密碼短語不能為空
+err-password-vault = 密碼保險庫錯誤：{ $error }
 err-current-password-required = 目前密碼是必填的
 err-new-password-required = 新密碼是必填的
 err-confirm-password-required = 請確認您的新密碼
 err-topic-too-long = 主題過長（{ $length }個字元，最多{ $max }個字元）
+err-entry-message-too-long = 入場訊息太長（{ $length } 個字元，最多 { $max } 個）
+err-rules-too-long = 規則太長（{ $length } 個字元，最多 { $max } 個）
 err-avatar-unsupported-type = 不支援的檔案類型。請使用PNG、WebP、JPEG或SVG。
 err-avatar-too-large = 頭像過大。最大大小為{ $max_kb }KB。
 err-avatar-decode-failed = 無法解碼頭像。檔案可能已損壞。
+err-known-server-export = 匯出憑證失敗: { $error }
+err-config-encryption = 加密設定失敗: { $error }
 err-server-name-empty = 伺服器名稱不能為空
 err-server-name-too-long = 伺服器名稱太長（最多 { $max } 個字元）
 err-server-name-contains-newlines = 伺服器名稱不能包含換行符
@@ -518,14 +849,23 @@ err-failed-send-update = 傳送更新失敗：{ $error }
 # =============================================================================
 
 err-failed-save-config = 儲存設定失敗：{ $error }
+err-friends-no-bookmark = 好友儲存在伺服器書籤中；請先為此伺服器加入書籤
+err-friends-too-many = 好友過多（最多 { $max } 位）
+err-friend-exists = { $username } 已經是好友
+err-friend-not-found = { $username } 不是好友
 err-failed-save-settings = 儲存設定失敗：{ $error }
 err-invalid-port-bookmark = 書籤中的連接埠無效：{ $name }
 err-failed-send-broadcast = 傳送廣播失敗：{ $error }
 err-failed-send-message = 傳送訊息失敗：{ $error }
+err-failed-start-group = 無法開始群組對話：{ $error }
+err-failed-share-snippet = 分享程式碼片段失敗：{ $error }
 err-failed-create-user = 建立使用者失敗：{ $error }
 err-failed-delete-user = 刪除使用者失敗：{ $error }
 err-failed-update-user = 更新使用者失敗：{ $error }
 err-failed-update-topic = 更新主題失敗：{ $error }
+err-failed-lock-topic = 鎖定主題失敗：{ $error }
+err-failed-set-language = 設定頻道語言失敗：{ $error }
+err-failed-update-entry = 更新頻道失敗：{ $error }
 err-message-too-long-details = { $error }（{ $length }字元，最大{ $max }）
 
 # Network connection errors (with parameters)
@@ -537,6 +877,14 @@ err-tls-handshake-failed = TLS握手失敗：{ $error }
 err-failed-send-handshake = 傳送握手失敗：{ $error }
 err-failed-read-handshake = 讀取握手回應失敗：{ $error }
 err-handshake-failed = 握手失敗：{ $error }
+err-proof-of-work-too-hard = 伺服器要求的工作量證明過高（難度 { $difficulty }）
+err-sso-not-offered = 此伺服器不提供單一登入
+err-sso-failed = 單一登入失敗：{ $error }
+err-sso-browser = 無法開啟瀏覽器：{ $error }
+err-sso-timeout = 等待瀏覽器完成單一登入逾時
+err-sso-state-mismatch = 單一登入回應與請求不符
+sso-browser-done = 已登入。您可以關閉此視窗並返回 Nexus。
+sso-browser-failed = 登入失敗。您可以關閉此視窗並返回 Nexus。
 err-failed-parse-handshake = 解析握手回應失敗：{ $error }
 err-failed-send-login = 傳送登入失敗：{ $error }
 err-failed-read-login = 讀取登入回應失敗：{ $error }
@@ -544,6 +892,7 @@ err-failed-parse-login = 解析登入回應失敗：{ $error }
 err-failed-create-server-name = 建立伺服器名稱失敗：{ $error }
 err-failed-create-config-dir = 建立設定目錄失敗：{ $error }
 err-failed-serialize-config = 序列化設定失敗：{ $error }
+err-config-locked = 設定已加密並鎖定；解鎖前無法儲存變更
 err-failed-write-config = 寫入設定檔失敗：{ $error }
 err-failed-read-config-metadata = 讀取設定檔中繼資料失敗：{ $error }
 err-failed-set-config-permissions = 設定設定檔權限失敗：{ $error }
@@ -553,6 +902,10 @@ err-failed-set-config-permissions = 設定設定檔權限失敗：{ $error }
 # =============================================================================
 
 fingerprint-warning = 這可能表示存在安全問題（中間人攻擊）或伺服器憑證已重新產生。僅在信任伺服器管理員時才接受。
+fingerprint-new-server-prompt = 這是首次連線到此伺服器。只有在下方指紋與伺服器管理員公布的指紋相符時才信任該憑證。
+password-vault-create-description = 沒有可用的系統鑰匙圈。請設定一個主密碼短語來加密書籤中儲存的密碼，或略過以將其保留在設定檔中。
+password-vault-unlock-description = 輸入主密碼短語以解鎖書籤中儲存的密碼。如果略過，書籤將在 Nexus 重新啟動前不帶密碼連線。
+config-unlock-description = 你的設定、書籤和聊天記錄已加密。輸入主密碼短語以解鎖。如果略過，Nexus 將以預設設定啟動，且在重新啟動前不會儲存任何內容。
 
 # =============================================================================
 # User Info Display
@@ -569,10 +922,12 @@ user-info-account = 帳戶：{ $username }
 user-info-connected = 已連線：
 user-info-connected-value = { $duration }前
 user-info-connected-value-sessions = { $duration }前（{ $count }個工作階段）
+user-info-idle = 閒置:
 user-info-features = 功能：
 user-info-features-value = { $features }
 user-info-features-none = 無
 user-info-locale = 語言：
+user-info-client = 用戶端:
 user-info-address = 位址：
 user-info-addresses = 位址：
 user-info-created = 建立時間：
@@ -581,6 +936,12 @@ user-info-unknown = 未知
 user-info-loading = 正在載入使用者資訊…
 user-info-status = 狀態:
 user-info-channels = 頻道:
+user-info-rank = 等級:
+user-info-logins = 登入:
+user-info-messages = 訊息:
+user-info-uploads = 上傳:
+user-info-downloaded = 已下載:
+user-info-last-login = 上次登入:
 
 # =============================================================================
 # Time Duration
@@ -604,26 +965,51 @@ err-me-no-target = 使用 /{ $join } 加入頻道或 /{ $msg } 向使用者傳
 cmd-help-escape-hint = 提示：使用 // 傳送以 / 開頭的訊息
 cmd-message-desc = 發送訊息給用戶
 cmd-message-usage = 用法：/{ $command } <用戶名> <訊息>
+cmd-group-desc = 與多位使用者開始群組對話
+cmd-group-usage = 用法：/{ $command } <用戶名> [<用戶名>...]
+cmd-invite-desc = 將使用者加入目前的群組對話
+cmd-invite-usage = 用法：/{ $command } <用戶名>
 cmd-ping-desc = 測量到伺服器的延遲
 cmd-ping-usage = 用法：/{ $command }
+cmd-radio-desc = 向頻道的語音聽眾廣播音訊
+cmd-radio-usage = 用法：/{ $command }
+err-radio-no-channel = 此指令只能在頻道中使用
+err-radio-in-voice = 開始廣播前請先退出語音
 ping-result = 回應: { $ms }ms
 cmd-userinfo-desc = 顯示用戶資訊
 cmd-userinfo-usage = 用法：/{ $command } <用戶名>
 cmd-kick-desc = 將用戶踢出伺服器
 cmd-kick-usage = 用法：/{ $command } <用戶名> [原因]
+cmd-top-desc = 顯示最活躍的使用者
+cmd-top-usage = 用法：/{ $command } [logins|messages|uploads|downloaded]
 cmd-topic-desc = 查看或管理聊天主題
-cmd-topic-usage = 用法：/{ $command } [設定|清除] [主題]
+cmd-topic-usage = 用法：/{ $command } [設定|清除|鎖定|解鎖|歷史] [主題]
 cmd-topic-arg-set = 設定
 cmd-topic-arg-clear = 清除
+cmd-topic-arg-lock = 鎖定
+cmd-topic-arg-unlock = 解鎖
+cmd-topic-arg-history = 歷史
 cmd-topic-set-usage = 用法：/{ $command } 設定 <主題>
 cmd-topic-none = 未設定主題
 cmd-broadcast-desc = 向所有用戶發送廣播
 cmd-broadcast-usage = 用法：/{ $command } <訊息>
 cmd-clear-desc = 清除當前分頁的聊天記錄
 cmd-clear-usage = 用法：/{ $command }
+cmd-export-desc = 將目前分頁儲存為 HTML 檔案
+cmd-export-usage = 用法：/{ $command }
+cmd-clip-desc = 列出、播放、新增或刪除音效板片段
+cmd-clip-usage = 用法：/{ $command } [列表|播放 <名稱>|新增 <名稱>|刪除 <名稱>|<名稱>]
+cmd-clip-arg-list = 列表
+cmd-clip-arg-play = 播放
+cmd-clip-arg-add = 新增
+cmd-clip-arg-delete = 刪除
 cmd-focus-desc = 聚焦到伺服器聊天或用戶訊息視窗
 cmd-focus-usage = 用法：/{ $command } [用戶名]
 cmd-focus-not-found = 找不到用戶：{ $name }
+cmd-friend-desc = 列出、加入或移除好友
+cmd-friend-usage = 用法：/{ $command } [list] | add <使用者名稱> | remove <使用者名稱>
+cmd-friend-list-header = 好友：
+cmd-friend-list-empty = 還沒有好友。使用 /{ $command } add <使用者名稱> 加入
 cmd-list-desc = 顯示已連線/所有用戶
 cmd-list-arg-all = 所有
 cmd-list-usage = 用法：/{ $command } [所有]
@@ -633,6 +1019,13 @@ cmd-list-all-no-permission = 您需要 user_edit 或 user_delete 權限才能列
 cmd-list-all-output = 用戶：{ $users }（{ $count }位用戶）
 cmd-help-usage = 用法：/{ $command } [指令]
 cmd-topic-permission-denied = 您沒有編輯主題的權限
+cmd-topic-lock-permission-denied = 您沒有鎖定主題的權限
+msg-topic-already-locked = 主題已鎖定
+msg-topic-already-unlocked = 主題已解鎖
+msg-topic-history-header = { $channel } 的主題歷史：
+msg-topic-history-set = { $time } { $username }：{ $topic }
+msg-topic-history-cleared = { $time } { $username } 清除了主題
+msg-topic-history-empty = 沒有主題變更記錄
 cmd-window-desc = 管理聊天分頁
 cmd-window-usage = 用法：/{ $command } [下一個|上一個|關閉 [用戶名]]
 cmd-window-arg-next = 下一個
@@ -657,15 +1050,55 @@ cmd-unban-desc = 解除IP封鎖
 cmd-unban-usage = 用法: /{ $command } <目標>
 cmd-bans-desc = 列出有效封鎖
 cmd-bans-usage = 用法: /{ $command }
+cmd-register-desc = 從訪客工作階段註冊帳戶
+cmd-register-usage = 用法：/{ $command } <用戶名> <密碼>
 cmd-reindex-desc = 觸發檔案索引重建
 cmd-reindex-usage = 用法: /{ $command }
+cmd-cleanup-desc = 尋找檔案區中中斷傳輸的殘留
+cmd-cleanup-usage = 用法: /{ $command } [delete]
+cmd-filecheck-desc = 將檔案區與搜尋索引和檔案中繼資料進行核對
+cmd-filecheck-usage = 用法：/{ $command } [repair]
+cmd-screenshot-desc = 在目前分頁中分享附註解的螢幕截圖
+cmd-screenshot-usage = 用法：/{ $command }
+err-screenshot-no-target = 螢幕截圖只能在頻道或私訊分頁中分享
+err-screenshot-no-folder = 尚未設定螢幕截圖資料夾 — 請在「檔案」中對上傳資料夾選擇「在此分享螢幕截圖」
+err-screenshot-capture = 螢幕截圖失敗：{ $error }
+err-screenshot-save = 無法儲存螢幕截圖：{ $error }
+err-screenshot-upload = 螢幕截圖上傳失敗：{ $error }
+err-export-empty = 此分頁中沒有可匯出的訊息
+err-chat-export = 無法匯出聊天：{ $error }
+screenshot-title = 分享螢幕截圖
+screenshot-tool-crop = 裁切
+screenshot-tool-arrow = 箭頭
+screenshot-hint-crop = 拖曳框選要保留的部分
+screenshot-hint-arrow = 從箭頭尾端拖曳到箭頭尖端
 msg-reindex-triggered = 已觸發檔案索引重建
+msg-cleanup-nothing = 未發現中斷傳輸的殘留
+msg-cleanup-found = 發現中斷傳輸的殘留：
+msg-cleanup-deleted = 已刪除中斷傳輸的殘留：
+msg-cleanup-parts = 孤立的 .part 檔案：{ $count }（{ $size }）
+msg-cleanup-temp = 殘留的暫存檔案：{ $count }（{ $size }）
+msg-cleanup-journal = 沒有檔案的上傳日誌項目：{ $count }
+msg-cleanup-index = 沒有檔案的搜尋索引項目：{ $count }
+msg-cleanup-hint = 請檢查上面的檔案，然後執行 /cleanup delete 將其刪除
+msg-filecheck-issue = { $kind }：{ $path }
+msg-filecheck-issue-repaired = { $kind }：{ $path }（已修復）
+msg-filecheck-clean = 檔案區檢查在 { $entries } 個項目中未發現問題
+msg-filecheck-done = 檔案區檢查在 { $entries } 個項目中發現 { $issues } 個問題，已修復 { $repaired } 個
+msg-filecheck-index-skipped = 搜尋索引尚未建立或正等待重建，因此未進行比較
+msg-filecheck-hint = 執行 /filecheck repair 以重建索引並刪除缺失路徑的中繼資料
+filecheck-not-indexed = 不在搜尋索引中
+filecheck-indexed-but-missing = 在搜尋索引中但磁碟上缺失
+filecheck-index-outdated = 搜尋索引中的資訊已過期
+filecheck-orphaned-activity = 缺失路徑的歷史記錄
+filecheck-orphaned-reviews = 缺失路徑的評分或留言
+filecheck-orphaned-upload-rule = 缺失資料夾的上傳規則
 cmd-join-desc = 加入或建立頻道
 cmd-join-usage = 用法: /{ $command } #頻道
 cmd-leave-desc = 離開頻道
 cmd-leave-usage = 用法: /{ $command } [#頻道]
 cmd-channels-desc = 列出可用頻道
-cmd-channels-usage = 用法: /{ $command }
+cmd-channels-usage = 用法: /{ $command } [語言]
 cmd-secret-desc = 切換目前頻道的秘密模式
 cmd-secret-usage = 用法: /{ $command } [開|關]
 cmd-secret-arg-on = 開
@@ -678,12 +1111,40 @@ msg-secret-enabled = 頻道現在是秘密的
 msg-secret-disabled = 頻道現在是公開的
 msg-secret-status-on = 此頻道是私密的
 msg-secret-status-off = 此頻道是公開的
+cmd-voicelimit-desc = 查看或設定目前頻道的語音人數上限
+cmd-voicelimit-usage = 用法：/{ $command } [<人數>|關]
+cmd-vt-desc = 向語音工作階段傳送文字訊息
+cmd-vt-usage = 用法：/{ $command } <訊息>
+cmd-voicelimit-arg-off = 關
+err-voicelimit-no-channel = 此指令僅在頻道中可用
+msg-voice-limit-status = 此頻道語音最多允許 { $limit } 人
+msg-voice-limit-status-none = 此頻道沒有語音上限
+msg-voice-clip-none = 沒有音效板片段
+msg-voice-clip-list = 音效板片段（{ $count }）：
+msg-voice-clip-entry = { $name }（{ $seconds } 秒，{ $nickname } 上傳）
+msg-voice-clip-uploaded = 片段已新增到音效板
+msg-voice-clip-deleted = 片段已從音效板刪除
 cmd-trust-desc = 信任 IP、CIDR 範圍或暱稱以繞過封鎖
 cmd-trust-usage = 用法: /{ $command } <目標> [時長] [原因]
 cmd-untrust-desc = 移除受信任的IP項目
 cmd-untrust-usage = 用法: /{ $command } <目標>
 cmd-trusted-desc = 列出受信任的IP
 cmd-trusted-usage = 用法: /{ $command }
+cmd-ldap-desc = 列出、設定或移除目錄群組對應
+cmd-ldap-usage = 用法: /{ $command } [list|set <權限|admin|none> <群組 DN>|remove <群組 DN>]
+cmd-nickname-desc = 為你的帳戶保留或釋放暱稱
+cmd-nickname-usage = 用法: /{ $command } [register <暱稱> [用戶名]|release <暱稱>]
+cmd-history-desc = 顯示或設定伺服器保留私訊的天數，或載入更早的訊息
+cmd-history-usage = 用法: /{ $command } [<天數>|more]
+cmd-alias-desc = 為指令和訊息建立捷徑
+cmd-alias-usage = 用法: /{ $command } [list|set <名稱> <文字>|remove <名稱>]
+cmd-alias-list-header = 你的別名：
+cmd-alias-list-empty = 還沒有別名。使用 /{ $command } set <名稱> <文字> 建立
+cmd-alias-set = /{ $name } 現在執行：{ $text }
+cmd-alias-removed = 已移除別名 /{ $name }
+cmd-alias-not-found = 沒有名為 /{ $name } 的別名
+cmd-alias-invalid-name = 別名只能包含字母、數字、- 和 _（最多 { $max } 個字元）
+cmd-alias-builtin = /{ $name } 是內建指令，不能用作別名
 
 # =============================================================================
 # About Panel
@@ -696,9 +1157,25 @@ err-server-image-invalid-format = 伺服器圖片格式無效（必須是base64
 err-server-image-unsupported-type = 不支援的伺服器圖片類型（僅支援PNG、WebP、JPEG或SVG）
 err-server-image-decode-failed = 圖片解碼失敗。檔案可能已損壞。
 err-failed-read-image = 讀取圖片失敗：{ $error }
-err-news-empty = 貼文必須包含文字或圖片
+err-news-empty = 貼文必須包含文字或附件
 err-news-body-too-long = 內容過長（{ $length }個字元，最多{ $max }個）
 err-news-body-invalid-characters = 內容包含無效的控制字元
+err-news-too-many-attachments = 每則貼文最多可以有 { $max } 個附件
+err-news-caption-too-long = 說明過長（最多 { $max } 個字元）
+err-news-caption-invalid-characters = 說明必須為單行且不含控制字元
+err-news-file-path-invalid = 檔案路徑無效
+err-news-search-date = 無效日期：{ $date }（請使用 YYYY-MM-DD）
+err-file-comment-empty = 評論不能為空
+err-file-comment-too-long = 評論過長（{ $length } 個字元，最多 { $max }）
+err-file-comment-invalid-characters = 評論包含無效的控制字元
+err-upload-rule-invalid-extension = 無效的檔案類型「{ $extension }」（請使用字母和數字，如 mp3）
+err-auto-download-pattern-required = 請至少輸入一個檔案名稱模式
+err-auto-download-pattern-invalid = 無效的模式「{ $pattern }」（模式比對的是檔案名稱，不能包含斜線）
+err-upload-rule-too-many-extensions = 檔案類型過多（最多 { $max } 個）
+err-upload-rule-invalid-size = 無效的大小「{ $value }」（請輸入 MB 數）
+err-ldap-group-empty = 群組 DN 不能為空
+err-ldap-group-too-long = 群組 DN 過長（最多 { $max } 個字元）
+err-ldap-group-invalid-characters = 群組 DN 首尾不能有空格，也不能包含控制字元
 err-news-image-decode-failed = 圖片解碼失敗。檔案可能已損壞。
 
 # Proxy errors
@@ -706,9 +1183,31 @@ err-proxy-connection-failed = 連線代理伺服器失敗：{ $error }
 err-proxy-connection-timeout = 代理連線在 { $seconds } 秒後逾時
 err-proxy-address-required = 啟用代理時需要代理地址
 err-proxy-port-invalid = 代理連接埠必須在 1 到 65535 之間
-err-news-image-too-large = 圖片太大（最大512KB）
+err-news-image-too-large = 圖片太大（最大{ $max_kb }KB）
 err-news-image-unsupported-type = 不支援的圖片類型（僅支援PNG、WebP、JPEG或SVG）
 err-topic-no-channel = 此指令僅在頻道中有效
+err-entry-no-channel = 此指令僅在頻道中有效
+cmd-welcome-desc = 查看或設定使用者加入頻道時顯示的訊息
+cmd-welcome-usage = 用法：/{ $command } [設定 <訊息>|清除]
+cmd-welcome-arg-set = 設定
+cmd-welcome-arg-clear = 清除
+cmd-welcome-none = 未設定入場訊息
+msg-entry-message-display = 入場訊息：{ $message }
+msg-entry-message-updated = 入場訊息已更新
+msg-entry-message-cleared = 入場訊息已清除
+cmd-rules-desc = 查看或設定頻道規則
+cmd-rules-usage = 用法：/{ $command } [設定 <規則>|清除]
+cmd-rules-arg-set = 設定
+cmd-rules-arg-clear = 清除
+cmd-rules-none = 未設定規則
+msg-rules-header = { $channel } 的規則：
+cmd-language-desc = 檢視或設定頻道的語言標籤
+cmd-language-usage = 用法：/{ $command } [設定 <語言>|清除]
+cmd-language-arg-set = 設定
+cmd-language-arg-clear = 清除
+msg-language-status = 頻道語言：{ $language }
+msg-language-none = 未設定語言
+err-language-invalid = 無效的語言標籤：{ $language }（例如 zh 或 pt-BR）
 err-join-channel = 加入頻道失敗: { $error }
 err-leave-channel = 離開頻道失敗: { $error }
 err-list-channels = 取得頻道列表失敗: { $error }
@@ -718,6 +1217,8 @@ err-channel-too-long = 頻道名稱太長（最多{ $max }個字元）
 err-channel-missing-prefix = 頻道名稱必須以#開頭
 err-channel-invalid-characters = 頻道名稱包含無效字元
 err-leave-no-channel = 不帶參數使用/{ $command }時必須在頻道中
+err-invite-no-group = 請在群組對話分頁中使用 /{ $command }
+err-group-too-many-members = 群組對話最多可有 { $max } 位成員
 err-not-in-channel = 您不在頻道 { $channel } 中
 err-leave-already-pending = 已在等待伺服器回應
 err-uri-connection-failed = 連線到 { $host } 失敗: { $error }
@@ -731,6 +1232,15 @@ label-port = 連接埠:
 # Transfer statuses
 transfer-status-queued = 排隊中
 transfer-status-connecting = 連線中…
+transfer-status-server-queued = 等待伺服器空位 (#{ $position })
+transfer-collision-pending = 檔案已存在：{ $path }
+collision-action-renamed = { $count } 個已重新命名
+collision-action-overwritten = { $count } 個已覆寫
+collision-action-skipped = { $count } 個已略過
+transfer-bar-count = { $count } 個傳輸
+status-bar-users = { $count } 位使用者
+status-bar-latency = { $ms } 毫秒
+status-bar-server-time = 伺服器時間 { $time } ({ $zone })
 transfer-status-transferring = 傳輸中
 transfer-status-paused = 已暫停
 transfer-status-completed = 已完成
@@ -789,6 +1299,7 @@ event-broadcast = 廣播
 event-chat-message = 聊天訊息
 event-chat-mention = 聊天提及
 event-connection-lost = 連線中斷
+event-friend-online = 好友上線
 event-news-post = 新聞貼文
 event-permissions-changed = 權限已變更
 event-transfer-complete = 傳輸完成
@@ -823,6 +1334,17 @@ settings-sound-test = 測試
 settings-notification-test = 測試
 event-settings-show-toast = 顯示提示
 settings-toast-test = 測試
+settings-do-not-disturb = 勿擾模式
+settings-quiet-hours = 免打擾時段
+settings-do-not-disturb-hint = 靜音通知、提示和聲音。未讀標記仍會累計，其他人會在你的名稱旁看到 🔕。
+settings-notification-rules = 通知規則
+settings-notification-rules-server = 伺服器:
+settings-notification-rules-channels = 頻道例外
+settings-notification-rules-keywords = 關注的關鍵字
+settings-notification-rules-no-bookmarks = 將伺服器加入書籤以設定通知規則。
+channel-notification-all = 所有訊息
+channel-notification-mentions = 僅提及
+channel-notification-mute = 靜音
 
 # Sound names
 sound-none = 無
@@ -841,6 +1363,8 @@ notification-chat-message = 新聊天訊息
 notification-chat-message-from = 來自{$username}的聊天
 notification-connection-lost = 連線中斷
 notification-connection-lost-from = 與{$server}的連線已中斷
+notification-friend-online = 有好友上線了
+notification-friend-online-name = {$username} 已上線
 notification-download-complete = 下載完成
 notification-download-failed = 下載失敗
 notification-news-post = 新的新聞貼文
@@ -894,6 +1418,18 @@ ban-duration-7d = 7天
 ban-duration-30d = 30天
 ban-duration-permanent = 永久
 
+# Broadcast target and delay options
+broadcast-target-label = 傳送給：
+broadcast-target-all = 所有人
+broadcast-target-channel = 頻道成員
+broadcast-target-permission = 擁有某權限的使用者
+broadcast-delay-label = 傳送：
+broadcast-delay-now = 立即
+broadcast-delay-5m = 5 分鐘後
+broadcast-delay-15m = 15 分鐘後
+broadcast-delay-1h = 1 小時後
+broadcast-delay-1d = 1 天後
+
 # =============================================================================
 # Ban System
 # =============================================================================
@@ -943,12 +1479,32 @@ msg-untrusted-success = 信任項目移除成功
 # Trust list messages
 msg-trust-list-header = 受信任的IP：
 msg-trust-list-empty = 沒有受信任的IP
+msg-ldap-group-list-header = 目錄群組:
+msg-ldap-group-list-empty = 沒有已對應的目錄群組
+msg-ldap-group-admin = 管理員
+msg-ldap-group-no-permissions = 無權限
+msg-ldap-group-set = 已對應目錄群組 { $group }
+msg-ldap-group-removed = 已移除目錄群組 { $group }
+msg-guest-upgraded = 帳戶 { $username } 已建立。下次請使用它登入。
+msg-nickname-registered = 暱稱 { $nickname } 現已為 { $username } 保留。
+msg-nickname-released = 暱稱 { $nickname } 已釋放。
+msg-history-days = 伺服器會保留您的私訊 { $days } 天。
+msg-history-off = 伺服器不保留您的私訊。使用 /history <天數> 開啟。
+msg-history-no-older = 沒有與 { $nickname } 的更早訊息。
+err-history-days-too-long = 訊息記錄最多可保留 { $max } 天
+err-history-more-tab = 請在私訊分頁中使用 /history more
 msg-trust-permanent = 永久
 msg-trust-expired = 已過期
 msg-trust-remaining = 剩餘 { $time }
 msg-trust-remaining-days = { $days }天 { $hours }小時
 msg-trust-remaining-hours = { $hours }小時 { $minutes }分鐘
 msg-trust-remaining-minutes = { $minutes }分鐘
+msg-leaderboard-header = 依{ $stat }排列的最活躍使用者：
+msg-leaderboard-empty = 尚無活動記錄
+leaderboard-stat-logins = 登入次數
+leaderboard-stat-messages = 訊息數
+leaderboard-stat-uploads = 上傳數
+leaderboard-stat-downloaded = 下載量
 
 # =============================================================================
 # Voice Chat
@@ -957,6 +1513,16 @@ msg-trust-remaining-minutes = { $minutes }分鐘
 # Voice error messages
 err-voice-join = 加入語音聊天失敗：{ $error }
 err-voice-leave = 離開語音聊天失敗：{ $error }
+err-voice-limit = 設定語音上限失敗：{ $error }
+err-voice-clip = 音效板：{ $error }
+err-voice-text = 語音文字：{ $error }
+err-voice-clip-name-empty = 片段名稱不能為空
+err-voice-clip-name-too-long = 片段名稱過長（最多 { $max } 個字元）
+err-voice-clip-name-invalid = 片段名稱只能包含字母、數字、- 和 _
+err-voice-clip-too-large = 片段過大（最大 256KB）
+err-voice-clip-unsupported-type = 不支援的片段格式（僅支援 Ogg Vorbis）
+msg-voice-queued = 語音已滿 - 您排在佇列第 { $position } 位
+msg-voice-queue-cancelled = 您已被移出語音佇列
 err-voice-no-token = 成功加入語音聊天但未收到令牌
 err-voice-already-active = 您已在另一個伺服器的語音聊天中
 err-voice-no-permission = 您沒有權限加入語音聊天
@@ -979,7 +1545,12 @@ msg-voice-joined-empty = 已加入 { $target } 的語音聊天
 msg-voice-left = 已離開 { $target } 的語音聊天
 msg-voice-user-joined = { $nickname } 加入了語音聊天
 msg-voice-user-left = { $nickname } 離開了語音聊天
+msg-voice-broadcast-started = { $nickname } 正在語音中廣播 - 加入即可收聽
+msg-voice-broadcast-ended = 語音廣播已結束
+msg-voice-clip-played = { $nickname } 播放了 { $name }
+msg-voice-text = [語音] { $nickname }：{ $message }
 msg-voice-you-left = 您已離開語音聊天
+msg-voice-tcp-relay = 語音 UDP 連線失敗 ({ $error }) - 改為透過伺服器連線中繼語音
 
 # Voice UI
 voice-join-tooltip = 加入語音聊天
@@ -987,6 +1558,8 @@ voice-leave-tooltip = 離開語音聊天
 voice-mute-all-tooltip = 全部靜音
 voice-unmute-all-tooltip = 取消靜音
 voice-bar-participants = ({ $count })
+voice-bar-queued = （排隊：{ $position }）
+voice-bar-listeners = （{ $count } 人收聽）
 voice-indicator-tooltip = 語音聊天中
 tooltip-mute = 靜音
 tooltip-unmute = 取消靜音
@@ -997,6 +1570,12 @@ voice-quality-medium = 中 (32 kbps)
 voice-quality-high = 高 (64 kbps)
 voice-quality-very-high = 極高 (96 kbps)
 
+# Voice connection quality
+voice-connection-good = 良好
+voice-connection-fair = 普通
+voice-connection-poor = 較差
+voice-connection-tooltip = 連線：{ $quality }（封包遺失 { $loss }%，抖動 { $jitter } 毫秒）
+
 # PTT modes
 ptt-mode-hold = 按住說話
 ptt-mode-toggle = 切換
@@ -1011,6 +1590,8 @@ ptt-delay-500ms = 500毫秒
 audio-output-device = 輸出裝置
 audio-input-device = 輸入裝置
 audio-refresh-devices = 重新整理裝置
+audio-clip-volume = 音效板音量
+audio-voice-text-tts = 朗讀語音文字訊息
 audio-voice-quality = 語音品質
 audio-ptt-key = 按鍵說話鍵
 audio-ptt-mode = PTT 模式
@@ -1032,6 +1613,44 @@ audio-echo-cancellation = 回聲消除
 audio-agc = 自動增益控制
 audio-transient-suppression = 鍵盤噪音消除
 
+# =============================================================================
+# Global Hotkeys
+# =============================================================================
+
+# Settings
+settings-toggle-window-key = 顯示/隱藏視窗鍵
+button-clear-hotkey = 清除
+
+# Shortcut descriptions (shown by the desktop on Wayland)
+hotkey-push-to-talk = 按鍵說話
+hotkey-toggle-window = 顯示或隱藏 Nexus
+
+# Errors
+err-window-hotkey-failed = 顯示/隱藏視窗熱鍵註冊失敗：{ $error }
+err-global-shortcuts-unavailable = 全域快捷鍵入口不可用，熱鍵僅在 Nexus 取得焦點時有效：{ $error }
+
+# =============================================================================
+# Auto Away
+# =============================================================================
+
+settings-auto-away = 閒置時自動設為離開
+settings-auto-away-minutes = 閒置分鐘數
+
+# =============================================================================
+# Connection Health
+# =============================================================================
+
+# Server list indicator
+connection-health-good = 良好
+connection-health-fair = 普通
+connection-health-poor = 不佳
+connection-health-tooltip = 連線：{ $quality }
+connection-health-latency = 延遲：{ $ms } 毫秒
+connection-health-jitter = 抖動：{ $ms } 毫秒
+connection-health-last-drop = 上次遺失 ping：{ $time }
+connection-health-no-drops = 沒有遺失的 ping
+connection-health-measuring = 正在測量延遲…
+
 # =============================================================================
 # System Tray (Windows/Linux only)
 # =============================================================================
@@ -1057,15 +1676,53 @@ tray-tooltip-voice = Nexus BBS - 語音中: { $target }
 tray-tooltip-voice-muted = Nexus BBS - 語音中 (已靜音): { $target }
 tray-tooltip-unread = Nexus BBS - { $count } 個未讀對話
 
+# =============================================================================
+# Notification Actions (macOS/Windows only)
+# =============================================================================
+
+# Actions
+notification-reply = 回覆
+notification-reply-send = 傳送
+notification-reply-placeholder = 回覆…
+notification-mark-read = 標示為已讀
+
 # =============================================================================
 # Toast Notifications
 # =============================================================================
 
 toast-link-copied = 連結已複製到剪貼簿
 toast-copied = 已複製到剪貼簿
+toast-checksum-copied = SHA-256 已複製到剪貼簿
 toast-download-started = 正在下載 { $filename }
 toast-download-queued = 下載已排隊: { $filename }
+toast-downloads-started = 正在下載 { $count } 個檔案
+toast-downloads-queued = { $count } 個下載已排隊
 toast-upload-started = 正在上傳 { $filename }
 toast-upload-queued = 上傳已排隊: { $filename }
 toast-uploads-started = 正在上傳 { $count } 個檔案
 toast-uploads-queued = { $count } 個上傳已排隊
+toast-upload-nothing-new = 沒有需要上傳的內容 — 伺服器上已有這些檔案
+toast-upload-watch-started = 正在監看 { $folder } — 新檔案將自動上傳
+toast-upload-watch-stopped = 已停止監看 { $folder }
+toast-auto-download-started = 正在從 { $folder } 自動下載 { $pattern }
+toast-auto-download-stopped = 已停止從 { $folder } 自動下載
+toast-screenshot-folder-set = 螢幕截圖將上傳到此資料夾
+toast-chat-exported = 聊天已匯出
+
+# =============================================================================
+# First-run Tips
+# =============================================================================
+
+tip-welcome-title = 歡迎使用 Nexus
+tip-welcome = 在下方輸入伺服器位址並連線。加入書籤可將伺服器連同登入資訊保留在左側清單中，下次只需點一下即可連線。
+tip-fingerprint = 每個伺服器都有一個憑證指紋，就像簽名一樣。Nexus 會在首次連線時記住它，並在它改變時提醒你，因為這可能表示有人在冒充該伺服器。只有在伺服器管理員告訴你指紋已變更時，才接受新的指紋。
+tip-server-list = 你的書籤和已開啟的連線在左側。點一下即可切換伺服器，拖曳書籤可調整順序。
+tip-toolbar = 工具列可開啟檔案、新聞、設定等。將滑鼠停在圖示上可查看其功能。
+tip-chat = 在這裡與伺服器上的所有人聊天。輸入 / 查看可用的指令，例如用 /join 加入頻道。
+tip-user-list = 目前在線的使用者列在右側。點一下名字可以傳送訊息或查看資訊。
+tip-counter = 提示 { $current }/{ $total }
+button-add-bookmark = 加入書籤
+button-tip-next = 下一則
+button-tip-done = 完成
+button-tip-got-it = 知道了
+button-skip-tips = 略過提示
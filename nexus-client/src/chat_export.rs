@@ -0,0 +1,250 @@
+//! Chat export to standalone HTML
+//!
+//! Renders a chat tab's scrollback as a single HTML file with its styles
+//! inlined, for archiving meetings and events held on a server. Colors come
+//! from the chat colors of the current theme, and a heading marks the start
+//! of each day. Error messages are left out: they're local to this client
+//! (failed commands and the like) and not part of the conversation.
+
+use chrono::{DateTime, Local, NaiveDate};
+use iced::{Color, Theme};
+use nexus_common::protocol::ChatAction;
+
+use crate::i18n::{t, t_args};
+use crate::style::chat;
+use crate::style::{CHAT_ACTION_PREFIX, CHAT_LINE_HEIGHT, CHAT_MESSAGE_SEPARATOR};
+use crate::types::{ChatMessage, MessageType};
+
+/// Date format for day headings and the export date
+const DAY_FORMAT: &str = "%Y-%m-%d";
+
+/// A chat tab ready to be exported
+pub struct ChatExport<'a> {
+    /// Tab label (channel, nickname, or group members)
+    pub title: &'a str,
+    /// Server display name
+    pub server: &'a str,
+    /// The tab's messages, oldest first
+    pub messages: &'a [ChatMessage],
+    /// Theme whose chat colors are used
+    pub theme: &'a Theme,
+    /// Time of day format (see `TimestampSettings::time_format`)
+    pub time_format: &'a str,
+    /// When the export was made
+    pub exported_at: DateTime<Local>,
+}
+
+impl ChatExport<'_> {
+    /// Render the export as an HTML document
+    pub fn to_html(&self) -> String {
+        let title = escape(&format!("{} - {}", self.title, self.server));
+        let subtitle = escape(&t_args(
+            "chat-export-subtitle",
+            &[
+                ("server", self.server),
+                ("date", &self.exported_at.format(DAY_FORMAT).to_string()),
+            ],
+        ));
+
+        let mut html = String::new();
+        html.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+        html.push_str(&format!("<title>{title}</title>\n"));
+        html.push_str(&format!("<style>\n{}</style>\n", self.stylesheet()));
+        html.push_str("</head>\n<body>\n");
+        html.push_str(&format!(
+            "<header>\n<h1>{}</h1>\n<p>{subtitle}</p>\n</header>\n<main>\n",
+            escape(self.title)
+        ));
+
+        let mut day: Option<NaiveDate> = None;
+        for msg in self.messages {
+            if msg.message_type == MessageType::Error {
+                continue;
+            }
+            let timestamp = msg.get_timestamp();
+            if day != Some(timestamp.date_naive()) {
+                day = Some(timestamp.date_naive());
+                html.push_str(&format!("<h2>{}</h2>\n", timestamp.format(DAY_FORMAT)));
+            }
+            html.push_str(&self.message_html(msg, &timestamp));
+        }
+
+        html.push_str("</main>\n</body>\n</html>\n");
+        html
+    }
+
+    /// One message as a line of the export
+    fn message_html(&self, msg: &ChatMessage, timestamp: &DateTime<Local>) -> String {
+        let time = format!(
+            "<span class=\"time\">[{}]</span> ",
+            timestamp.format(self.time_format)
+        );
+        let text = escape(&msg.message);
+
+        let (class, prefix) = match msg.message_type {
+            MessageType::System => ("system", escape(&t("chat-prefix-system"))),
+            MessageType::Info => ("info", escape(&t("chat-prefix-info"))),
+            MessageType::Error => ("error", escape(&t("chat-prefix-error"))),
+            MessageType::Broadcast => (
+                "broadcast",
+                format!(
+                    "{} {}{}",
+                    escape(&t("chat-prefix-broadcast")),
+                    escape(&msg.nickname),
+                    CHAT_MESSAGE_SEPARATOR.trim_end()
+                ),
+            ),
+            MessageType::Chat => {
+                let nick_class = if msg.is_admin {
+                    "nick admin"
+                } else if msg.is_shared {
+                    "nick shared"
+                } else {
+                    "nick"
+                };
+                let class = if msg.highlighted {
+                    "chat highlight"
+                } else {
+                    "chat"
+                };
+                let line = match msg.action {
+                    ChatAction::Normal => format!(
+                        "<span class=\"{nick_class}\">{}</span>{}{text}",
+                        escape(&msg.nickname),
+                        CHAT_MESSAGE_SEPARATOR
+                    ),
+                    ChatAction::Me => format!(
+                        "<em>{}<span class=\"{nick_class}\">{}</span> {text}</em>",
+                        CHAT_ACTION_PREFIX,
+                        escape(&msg.nickname)
+                    ),
+                };
+                return format!("<div class=\"{class}\">{time}{line}</div>\n");
+            }
+        };
+
+        format!("<div class=\"{class}\">{time}{prefix} {text}</div>\n")
+    }
+
+    /// Inline stylesheet using the theme's colors
+    fn stylesheet(&self) -> String {
+        let theme = self.theme;
+        let palette = theme.palette();
+        format!(
+            "body {{ background: {background}; color: {text}; \
+             font-family: system-ui, sans-serif; margin: 2em auto; max-width: 60em; }}\n\
+             header {{ border-bottom: 1px solid {time}; margin-bottom: 1em; }}\n\
+             header p, h2 {{ color: {time}; }}\n\
+             h2 {{ font-size: 1em; margin: 1.5em 0 0.5em; }}\n\
+             main div {{ line-height: {CHAT_LINE_HEIGHT}; white-space: pre-wrap; \
+             overflow-wrap: anywhere; }}\n\
+             .time {{ color: {time}; }}\n\
+             .nick {{ font-weight: bold; }}\n\
+             .admin {{ color: {admin}; }}\n\
+             .shared {{ color: {shared}; }}\n\
+             .highlight {{ color: {highlight}; }}\n\
+             .system {{ color: {system}; }}\n\
+             .info {{ color: {info}; }}\n\
+             .broadcast {{ color: {broadcast}; }}\n",
+            background = css_color(palette.background),
+            text = css_color(chat::text(theme)),
+            time = css_color(chat::timestamp(theme)),
+            admin = css_color(chat::admin(theme)),
+            shared = css_color(chat::shared(theme)),
+            highlight = css_color(chat::highlight(theme)),
+            system = css_color(chat::system(theme)),
+            info = css_color(chat::info(theme)),
+            broadcast = css_color(chat::broadcast(theme)),
+        )
+    }
+}
+
+/// Escape text for use in HTML content and attributes
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// CSS hex notation for a color
+fn css_color(color: Color) -> String {
+    let [r, g, b, _] = color.into_rgba8();
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(hour: u32, day: u32) -> DateTime<Local> {
+        Local.with_ymd_and_hms(2026, 3, day, hour, 30, 0).unwrap()
+    }
+
+    fn export(messages: &[ChatMessage]) -> String {
+        ChatExport {
+            title: "#general",
+            server: "Nexus <test>",
+            messages,
+            theme: &Theme::Light,
+            time_format: "%H:%M",
+            exported_at: at(12, 2),
+        }
+        .to_html()
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(
+            escape("<b>\"Tom\" & 'Jerry'</b>"),
+            "&lt;b&gt;&quot;Tom&quot; &amp; &#39;Jerry&#39;&lt;/b&gt;"
+        );
+    }
+
+    #[test]
+    fn test_css_color() {
+        assert_eq!(css_color(Color::from_rgb(1.0, 0.0, 0.5)), "#ff0080");
+    }
+
+    #[test]
+    fn test_export_messages() {
+        let mut admin = ChatMessage::with_timestamp_and_status(
+            "alice",
+            "<script>",
+            at(9, 1),
+            true,
+            false,
+            ChatAction::Normal,
+        );
+        admin.highlighted = true;
+        let action = ChatMessage::with_timestamp_and_status(
+            "bob",
+            "waves",
+            at(10, 2),
+            false,
+            true,
+            ChatAction::Me,
+        );
+        let html = export(&[admin, action, ChatMessage::error("local failure")]);
+
+        assert!(html.contains("<title>#general - Nexus &lt;test&gt;</title>"));
+        assert!(html.contains(
+            "<div class=\"chat highlight\"><span class=\"time\">[09:30]</span> \
+             <span class=\"nick admin\">alice</span>: &lt;script&gt;</div>"
+        ));
+        assert!(html.contains("<em>*** <span class=\"nick shared\">bob</span> waves</em>"));
+        // A heading for each day, and no local errors
+        assert!(html.contains("<h2>2026-03-01</h2>"));
+        assert!(html.contains("<h2>2026-03-02</h2>"));
+        assert!(!html.contains("local failure"));
+    }
+}
@@ -0,0 +1,293 @@
+//! Chat input recall history and multi-line paste
+//!
+//! Each chat tab remembers what was sent from it, so Up and Down in an
+//! empty or single-line input bring earlier messages and commands back.
+//! Pasting several lines into an empty input asks whether to send them as
+//! separate messages or as one multi-line message. Text longer than the
+//! server's maximum message length is split into parts that fit, after the
+//! same prompt.
+
+/// Most entries remembered per tab
+pub const HISTORY_LIMIT: usize = 100;
+
+/// Most lines that can be sent as separate messages from one paste
+pub const MAX_PASTE_MESSAGES: usize = 20;
+
+/// What was sent from one chat tab, and where Up/Down recall has got to
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InputHistory {
+    /// Sent input, oldest first
+    entries: Vec<String>,
+    /// Entry being shown (None when not recalling)
+    position: Option<usize>,
+    /// Input that was being typed when recall started
+    draft: String,
+}
+
+impl InputHistory {
+    /// Remember sent input and stop recalling
+    ///
+    /// Sending the same thing twice in a row is remembered once.
+    pub fn record(&mut self, input: &str) {
+        self.position = None;
+        self.draft.clear();
+        if input.trim().is_empty() || self.entries.last().is_some_and(|last| last == input) {
+            return;
+        }
+        self.entries.push(input.to_string());
+        if self.entries.len() > HISTORY_LIMIT {
+            self.entries.remove(0);
+        }
+    }
+
+    /// The entry before the one shown (Up)
+    ///
+    /// `current` is kept as the draft when recall starts. Returns None when
+    /// there's nothing older.
+    pub fn previous(&mut self, current: &str) -> Option<String> {
+        let position = match self.position {
+            None if self.entries.is_empty() => return None,
+            None => {
+                self.draft = current.to_string();
+                self.entries.len() - 1
+            }
+            Some(0) => return None,
+            Some(position) => position - 1,
+        };
+        self.position = Some(position);
+        Some(self.entries[position].clone())
+    }
+
+    /// The entry after the one shown, or the draft after the newest (Down)
+    ///
+    /// Returns None when not recalling.
+    pub fn next(&mut self) -> Option<String> {
+        let position = self.position? + 1;
+        if position < self.entries.len() {
+            self.position = Some(position);
+            Some(self.entries[position].clone())
+        } else {
+            self.position = None;
+            Some(std::mem::take(&mut self.draft))
+        }
+    }
+
+    /// Stop recalling without changing the entries
+    pub fn reset(&mut self) {
+        self.position = None;
+        self.draft.clear();
+    }
+}
+
+/// Pasted text that needs the "send as" prompt, with line breaks normalized
+///
+/// Returns None for text with fewer than two non-blank lines, which is
+/// pasted as usual.
+pub fn multiline_paste(text: &str) -> Option<String> {
+    let text = normalize_paste(text);
+    (paste_lines(&text).count() > 1).then_some(text)
+}
+
+/// Pasted text that needs the "send as" prompt, or is too long to send as is
+///
+/// Like [`multiline_paste`], but a single line longer than `max_length`
+/// bytes needs the prompt too.
+pub fn prompted_paste(text: &str, max_length: usize) -> Option<String> {
+    let normalized = normalize_paste(text);
+    if normalized.len() > max_length && !normalized.trim().is_empty() {
+        return Some(normalized);
+    }
+    multiline_paste(text)
+}
+
+/// Non-blank lines of pasted text, each sent as its own message
+pub fn paste_lines(text: &str) -> impl Iterator<Item = &str> {
+    text.lines().filter(|line| !line.trim().is_empty())
+}
+
+/// Messages to send for pasted text, one per line
+///
+/// Lines longer than `max_length` bytes are split into parts.
+pub fn paste_messages(text: &str, max_length: usize) -> Vec<String> {
+    paste_lines(text)
+        .flat_map(|line| split_message(line, max_length))
+        .collect()
+}
+
+/// Split a message into parts of at most `max_length` bytes
+///
+/// Parts end at the last line break that fits, or else the last space,
+/// so words are only cut when there's no other way. The break itself is
+/// dropped, and so are parts with nothing but whitespace.
+pub fn split_message(text: &str, max_length: usize) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut rest = text;
+
+    while rest.len() > max_length {
+        // Longest prefix that fits, ending on a character boundary (at
+        // least one character, whatever the limit)
+        let mut end = max_length;
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        if end == 0 {
+            end = rest.chars().next().map_or(rest.len(), char::len_utf8);
+        }
+        let head = &rest[..end];
+
+        // A space right after the prefix fits as well as one inside it
+        let split = head
+            .rfind('\n')
+            .or_else(|| rest[end..].starts_with(char::is_whitespace).then_some(end))
+            .or_else(|| head.rfind(char::is_whitespace))
+            .filter(|&i| i > 0);
+        let (part, next) = match split {
+            Some(i) => {
+                let width = rest[i..].chars().next().map_or(1, char::len_utf8);
+                (&rest[..i], &rest[i + width..])
+            }
+            None => (head, &rest[end..]),
+        };
+        if !part.trim().is_empty() {
+            parts.push(part.trim_end().to_string());
+        }
+        rest = next.trim_start_matches('\n');
+    }
+
+    if !rest.trim().is_empty() {
+        parts.push(rest.to_string());
+    }
+    parts
+}
+
+/// Pasted text with line breaks normalized and blank lines around it removed
+fn normalize_paste(text: &str) -> String {
+    let text = text.replace("\r\n", "\n").replace('\r', "\n");
+    text.trim_matches('\n').to_string()
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history_recall() {
+        let mut history = InputHistory::default();
+        assert_eq!(history.previous("typing"), None);
+        assert_eq!(history.next(), None);
+
+        history.record("one");
+        history.record("two");
+        history.record("two");
+        history.record("   ");
+        history.record("/join #three");
+
+        assert_eq!(history.previous("typing").as_deref(), Some("/join #three"));
+        assert_eq!(history.previous("/join #three").as_deref(), Some("two"));
+        assert_eq!(history.previous("two").as_deref(), Some("one"));
+        assert_eq!(history.previous("one"), None);
+
+        // Down walks back to the newest, then to what was being typed
+        assert_eq!(history.next().as_deref(), Some("two"));
+        assert_eq!(history.next().as_deref(), Some("/join #three"));
+        assert_eq!(history.next().as_deref(), Some("typing"));
+        assert_eq!(history.next(), None);
+    }
+
+    #[test]
+    fn test_history_record_stops_recall() {
+        let mut history = InputHistory::default();
+        history.record("one");
+        history.record("two");
+        assert_eq!(history.previous("").as_deref(), Some("two"));
+        assert_eq!(history.previous("").as_deref(), Some("one"));
+
+        // Sending starts the next recall from the newest entry
+        history.record("one again");
+        assert_eq!(history.previous("").as_deref(), Some("one again"));
+
+        history.reset();
+        assert_eq!(history.next(), None);
+    }
+
+    #[test]
+    fn test_history_limit() {
+        let mut history = InputHistory::default();
+        for i in 0..HISTORY_LIMIT + 10 {
+            history.record(&i.to_string());
+        }
+
+        let mut oldest = None;
+        while let Some(entry) = history.previous("") {
+            oldest = Some(entry);
+        }
+        assert_eq!(oldest, Some(10.to_string()));
+    }
+
+    #[test]
+    fn test_multiline_paste() {
+        assert_eq!(multiline_paste("just one line"), None);
+        assert_eq!(multiline_paste("one line\n\n"), None);
+        assert_eq!(multiline_paste("\n  \nline\n"), None);
+
+        assert_eq!(
+            multiline_paste("first\r\nsecond\r\n").as_deref(),
+            Some("first\nsecond")
+        );
+        assert_eq!(
+            multiline_paste("\nfirst\n\n  indented\n").as_deref(),
+            Some("first\n\n  indented")
+        );
+
+        let text = multiline_paste("a\n\n b \nc").unwrap();
+        assert_eq!(paste_lines(&text).collect::<Vec<_>>(), ["a", " b ", "c"]);
+    }
+
+    #[test]
+    fn test_prompted_paste() {
+        assert_eq!(prompted_paste("short", 10), None);
+        assert_eq!(
+            prompted_paste("one\r\ntwo", 10).as_deref(),
+            Some("one\ntwo")
+        );
+        // One long line needs the prompt too
+        assert_eq!(
+            prompted_paste("a rather long line\n", 10).as_deref(),
+            Some("a rather long line")
+        );
+        assert_eq!(prompted_paste(&" ".repeat(20), 10), None);
+    }
+
+    #[test]
+    fn test_split_message() {
+        assert_eq!(split_message("fits", 10), ["fits"]);
+        assert!(split_message("   ", 10).is_empty());
+
+        // Line breaks first, then spaces, dropping the break
+        assert_eq!(
+            split_message("one two\nthree four five", 10),
+            ["one two", "three four", "five"]
+        );
+        // Words longer than the limit are cut
+        assert_eq!(split_message("abcdefghij", 4), ["abcd", "efgh", "ij"]);
+        // Never inside a character
+        assert_eq!(split_message("ééé", 3), ["é", "é", "é"]);
+        assert_eq!(split_message("é", 1), ["é"]);
+
+        for part in split_message(&"lorem ipsum ".repeat(100), 50) {
+            assert!(part.len() <= 50);
+        }
+    }
+
+    #[test]
+    fn test_paste_messages() {
+        assert_eq!(
+            paste_messages("short\n\na long line here", 10),
+            ["short", "a long", "line here"]
+        );
+    }
+}
@@ -0,0 +1,214 @@
+//! Fenced code blocks in chat messages
+//!
+//! Chat messages are split into plain text and Markdown-style fenced code
+//! blocks (```` ```rust ```` … ```` ``` ````), so code can be shown in a
+//! box of its own with syntax highlighting instead of as wrapped lines.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use nexus_common::validators::validate_snippet_language;
+
+/// Shortest fence for a Markdown code block
+const MIN_FENCE_LENGTH: usize = 3;
+
+/// A fenced code block in a chat message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CodeBlock<'a> {
+    /// Language from the opening fence (None if missing or not a valid tag)
+    pub language: Option<&'a str>,
+    /// Code between the fences, without a trailing newline
+    pub code: &'a str,
+}
+
+impl CodeBlock<'_> {
+    /// Key for remembering whether the block is expanded
+    ///
+    /// Blocks with the same language and code share a key.
+    pub fn key(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.language.hash(&mut hasher);
+        self.code.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+/// Part of a chat message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessagePart<'a> {
+    /// Plain text (may span several lines)
+    Text(&'a str),
+    /// A fenced code block
+    Code(CodeBlock<'a>),
+}
+
+/// Split a chat message into plain text and fenced code blocks
+///
+/// A fence is a line starting with three or more backticks, and is closed
+/// by a line of at least as many backticks and nothing else. A fence that
+/// is never closed is plain text. A message without code blocks is one
+/// text part, even when empty.
+pub fn split_code_blocks(message: &str) -> Vec<MessagePart<'_>> {
+    let mut parts = Vec::new();
+    let mut text_start = 0;
+    let mut offset = 0;
+    // Fence length, language, start of the fence line, start of the code
+    let mut open: Option<(usize, Option<&str>, usize, usize)> = None;
+
+    for line in message.split_inclusive('\n') {
+        let line_start = offset;
+        offset += line.len();
+        let content = line.trim_end_matches(['\n', '\r']);
+
+        match open {
+            None => {
+                if let Some((fence_length, info)) = opening_fence(content) {
+                    open = Some((fence_length, language(info), line_start, offset));
+                }
+            }
+            Some((fence_length, language, fence_start, code_start)) => {
+                if !is_closing_fence(content, fence_length) {
+                    continue;
+                }
+                if fence_start > text_start {
+                    parts.push(MessagePart::Text(trim_line_ending(
+                        &message[text_start..fence_start],
+                    )));
+                }
+                parts.push(MessagePart::Code(CodeBlock {
+                    language,
+                    code: trim_line_ending(&message[code_start..line_start]),
+                }));
+                text_start = offset;
+                open = None;
+            }
+        }
+    }
+
+    if text_start < message.len() || parts.is_empty() {
+        parts.push(MessagePart::Text(&message[text_start..]));
+    }
+    parts
+}
+
+/// Fence length and info string of an opening fence line
+fn opening_fence(line: &str) -> Option<(usize, &str)> {
+    let line = line.trim_start();
+    let fence_length = line.len() - line.trim_start_matches('`').len();
+    if fence_length < MIN_FENCE_LENGTH {
+        return None;
+    }
+    // Backticks in the info string mean inline code, not a fence
+    let info = &line[fence_length..];
+    (!info.contains('`')).then_some((fence_length, info.trim()))
+}
+
+/// Whether a line closes a fence of `fence_length` backticks
+fn is_closing_fence(line: &str, fence_length: usize) -> bool {
+    let line = line.trim();
+    line.len() >= fence_length && line.chars().all(|ch| ch == '`')
+}
+
+/// Language tag from a fence's info string (its first word, when valid)
+fn language(info: &str) -> Option<&str> {
+    info.split_whitespace()
+        .next()
+        .filter(|language| validate_snippet_language(language).is_ok())
+}
+
+/// Remove one trailing line ending
+fn trim_line_ending(text: &str) -> &str {
+    let text = text.strip_suffix('\n').unwrap_or(text);
+    text.strip_suffix('\r').unwrap_or(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn code<'a>(language: Option<&'a str>, code: &'a str) -> MessagePart<'a> {
+        MessagePart::Code(CodeBlock { language, code })
+    }
+
+    #[test]
+    fn test_plain_text() {
+        assert_eq!(split_code_blocks(""), vec![MessagePart::Text("")]);
+        assert_eq!(
+            split_code_blocks("one\ntwo\n"),
+            vec![MessagePart::Text("one\ntwo\n")]
+        );
+        // Inline code isn't a block
+        assert_eq!(
+            split_code_blocks("```let x = 1;```"),
+            vec![MessagePart::Text("```let x = 1;```")]
+        );
+    }
+
+    #[test]
+    fn test_code_block() {
+        assert_eq!(
+            split_code_blocks("look:\n```rust\nfn main() {}\n```\nnice"),
+            vec![
+                MessagePart::Text("look:"),
+                code(Some("rust"), "fn main() {}"),
+                MessagePart::Text("nice"),
+            ]
+        );
+        assert_eq!(
+            split_code_blocks("```\r\na\r\n\r\nb\r\n```"),
+            vec![code(None, "a\r\n\r\nb")]
+        );
+    }
+
+    #[test]
+    fn test_several_code_blocks() {
+        assert_eq!(
+            split_code_blocks("```sh\nls\n```\n```py\npass\n```\n"),
+            vec![code(Some("sh"), "ls"), code(Some("py"), "pass")]
+        );
+    }
+
+    #[test]
+    fn test_fence_lengths() {
+        // A longer fence can hold a shorter one
+        assert_eq!(
+            split_code_blocks("````md\n```\nx\n```\n````"),
+            vec![code(Some("md"), "```\nx\n```")]
+        );
+        // An unclosed fence is plain text
+        assert_eq!(
+            split_code_blocks("```rust\nfn main() {}"),
+            vec![MessagePart::Text("```rust\nfn main() {}")]
+        );
+    }
+
+    #[test]
+    fn test_language() {
+        assert_eq!(
+            split_code_blocks("```rust title=main.rs\nx\n```"),
+            vec![code(Some("rust"), "x")]
+        );
+        assert_eq!(
+            split_code_blocks("```{rust}\nx\n```"),
+            vec![code(None, "x")]
+        );
+    }
+
+    #[test]
+    fn test_key() {
+        let block = CodeBlock {
+            language: Some("rust"),
+            code: "x",
+        };
+        let same = CodeBlock {
+            language: Some("rust"),
+            code: "x",
+        };
+        let plain = CodeBlock {
+            language: None,
+            code: "x",
+        };
+        assert_eq!(block.key(), same.key());
+        assert_ne!(block.key(), plain.key());
+    }
+}
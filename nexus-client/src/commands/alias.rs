@@ -0,0 +1,193 @@
+//! /alias command implementation - user-defined command shortcuts
+
+use std::collections::BTreeMap;
+
+use iced::Task;
+
+use crate::NexusApp;
+use crate::commands::get_command_info;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message};
+
+/// Longest alias name
+pub const MAX_ALIAS_NAME_LENGTH: usize = 32;
+
+/// Execute the /alias command
+///
+/// Aliases are saved in the client config and work on every server.
+///
+/// Usage: /alias [list]
+///        /alias set <name> <text>
+///        /alias remove <name>
+///
+/// Examples:
+///   /alias set gn /me waves goodnight
+///   /alias set hi Hello everyone!
+///   /alias remove gn
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    let usage = || t_args("cmd-alias-usage", &[("command", invoked_name)]);
+
+    match args.first().map(|arg| arg.to_lowercase()).as_deref() {
+        None | Some("list") if args.len() <= 1 => list_aliases(app, connection_id, invoked_name),
+        Some("set") if args.len() >= 3 => {
+            let name = normalize_name(&args[1]);
+            if let Err(error) = check_name(&name) {
+                return app.add_active_tab_message(connection_id, ChatMessage::error(error));
+            }
+            let text = args[2..].join(" ");
+            let info = t_args("cmd-alias-set", &[("name", &name), ("text", &text)]);
+            app.config.settings.command_aliases.insert(name, text);
+            save_aliases(app, connection_id, info)
+        }
+        Some("remove") if args.len() == 2 => {
+            let name = normalize_name(&args[1]);
+            if app.config.settings.command_aliases.remove(&name).is_none() {
+                let error_msg = t_args("cmd-alias-not-found", &[("name", &name)]);
+                return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+            }
+            let info = t_args("cmd-alias-removed", &[("name", &name)]);
+            save_aliases(app, connection_id, info)
+        }
+        _ => app.add_active_tab_message(connection_id, ChatMessage::error(usage())),
+    }
+}
+
+/// Show every alias and what it runs
+fn list_aliases(app: &mut NexusApp, connection_id: usize, invoked_name: &str) -> Task<Message> {
+    if app.config.settings.command_aliases.is_empty() {
+        let info = t_args("cmd-alias-list-empty", &[("command", invoked_name)]);
+        return app.add_active_tab_message(connection_id, ChatMessage::info(info));
+    }
+
+    let lines: Vec<String> = app
+        .config
+        .settings
+        .command_aliases
+        .iter()
+        .map(|(name, text)| format!("  /{name} → {text}"))
+        .collect();
+
+    let mut tasks = vec![
+        app.add_active_tab_message(connection_id, ChatMessage::info(t("cmd-alias-list-header"))),
+    ];
+    for line in lines {
+        tasks.push(app.add_active_tab_message(connection_id, ChatMessage::info(line)));
+    }
+    Task::batch(tasks)
+}
+
+/// Save the config after an alias change and confirm it in chat
+fn save_aliases(app: &mut NexusApp, connection_id: usize, info: String) -> Task<Message> {
+    if let Err(e) = app.config.save() {
+        let error_msg = t_args("err-failed-save-config", &[("error", &e.to_string())]);
+        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+    }
+    app.add_active_tab_message(connection_id, ChatMessage::info(info))
+}
+
+/// Alias name as stored: lowercase, without a leading `/`
+fn normalize_name(name: &str) -> String {
+    name.strip_prefix('/').unwrap_or(name).to_lowercase()
+}
+
+/// Check an alias name, returning a translated error
+///
+/// Names use letters, digits, `-` and `_`, and can't hide a built-in
+/// command or one of its aliases.
+fn check_name(name: &str) -> Result<(), String> {
+    let valid_chars = name
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '-' || c == '_');
+    if name.is_empty() || name.chars().count() > MAX_ALIAS_NAME_LENGTH || !valid_chars {
+        return Err(t_args(
+            "cmd-alias-invalid-name",
+            &[("max", &MAX_ALIAS_NAME_LENGTH.to_string())],
+        ));
+    }
+    if get_command_info(name).is_some() {
+        return Err(t_args("cmd-alias-builtin", &[("name", name)]));
+    }
+    Ok(())
+}
+
+/// Replace an alias at the start of chat input with what it runs
+///
+/// Anything typed after the alias is added to the end, so with
+/// `gn` → `/me waves goodnight`, `/gn all` becomes `/me waves goodnight all`.
+/// Built-in commands win over aliases, and the result isn't expanded again.
+/// Returns None when the input doesn't start with an alias.
+pub fn expand_alias(input: &str, aliases: &BTreeMap<String, String>) -> Option<String> {
+    let rest = input.strip_prefix('/')?;
+    let (name, args) = rest
+        .split_once(char::is_whitespace)
+        .map_or((rest, ""), |(name, args)| (name, args.trim()));
+
+    let name = name.to_lowercase();
+    if get_command_info(&name).is_some() {
+        return None;
+    }
+    let text = aliases.get(&name)?;
+
+    Some(if args.is_empty() {
+        text.clone()
+    } else {
+        format!("{text} {args}")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn aliases() -> BTreeMap<String, String> {
+        BTreeMap::from([
+            ("gn".to_string(), "/me waves goodnight".to_string()),
+            ("hi".to_string(), "Hello everyone!".to_string()),
+            ("loop".to_string(), "/loop".to_string()),
+            ("help".to_string(), "shadowed".to_string()),
+        ])
+    }
+
+    #[test]
+    fn test_expand_alias() {
+        let aliases = aliases();
+        assert_eq!(
+            expand_alias("/gn", &aliases).as_deref(),
+            Some("/me waves goodnight")
+        );
+        assert_eq!(
+            expand_alias("/GN  all of you ", &aliases).as_deref(),
+            Some("/me waves goodnight all of you")
+        );
+        assert_eq!(
+            expand_alias("/hi", &aliases).as_deref(),
+            Some("Hello everyone!")
+        );
+
+        // Expanded once only
+        assert_eq!(expand_alias("/loop", &aliases).as_deref(), Some("/loop"));
+    }
+
+    #[test]
+    fn test_expand_alias_ignores_other_input() {
+        let aliases = aliases();
+        assert_eq!(expand_alias("gn", &aliases), None);
+        assert_eq!(expand_alias(" /gn", &aliases), None);
+        assert_eq!(expand_alias("//gn", &aliases), None);
+        assert_eq!(expand_alias("/unknown", &aliases), None);
+
+        // Built-in commands come first
+        assert_eq!(expand_alias("/help", &aliases), None);
+    }
+
+    #[test]
+    fn test_normalize_name() {
+        assert_eq!(normalize_name("/GN"), "gn");
+        assert_eq!(normalize_name("brb"), "brb");
+    }
+}
@@ -55,6 +55,8 @@ pub fn execute(
             // Track the request so we can display the status message in the response
             conn.pending_requests
                 .track(message_id, ResponseRouting::AwayResult(message));
+            // A manual away isn't cleared by activity
+            conn.auto_away = false;
         }
         Err(e) => {
             return app.add_active_tab_message(connection_id, ChatMessage::error(e));
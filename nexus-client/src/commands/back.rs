@@ -35,6 +35,7 @@ pub fn execute(
         Ok(message_id) => {
             conn.pending_requests
                 .track(message_id, ResponseRouting::BackResult);
+            conn.auto_away = false;
         }
         Err(e) => {
             return app.add_active_tab_message(connection_id, ChatMessage::error(e));
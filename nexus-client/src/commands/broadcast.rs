@@ -1,7 +1,7 @@
 //! /broadcast command implementation - send broadcast to all users
 
 use iced::Task;
-use nexus_common::protocol::ClientMessage;
+use nexus_common::protocol::{BroadcastTarget, ClientMessage};
 use nexus_common::validators::{self, MessageError};
 
 use crate::NexusApp;
@@ -46,7 +46,12 @@ pub fn execute(
         return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
     }
 
-    let msg = ClientMessage::UserBroadcast { message };
+    let msg = ClientMessage::UserBroadcast {
+        message,
+        target: BroadcastTarget::All,
+        delay: None,
+        require_ack: false,
+    };
 
     if let Err(e) = conn.send(msg) {
         let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
@@ -2,6 +2,7 @@
 
 use iced::Task;
 use nexus_common::protocol::ClientMessage;
+use nexus_common::validators;
 
 use crate::NexusApp;
 use crate::i18n::t_args;
@@ -9,26 +10,33 @@ use crate::types::{ChatMessage, Message};
 
 /// Execute the /channels command
 ///
-/// Lists all available channels on the server.
-/// Usage: /channels
+/// Lists all available channels on the server, optionally only those tagged
+/// with a language (e.g. "en" also lists "en-GB" channels).
+/// Usage: /channels [language]
 pub fn execute(
     app: &mut NexusApp,
     connection_id: usize,
     invoked_name: &str,
     args: &[String],
 ) -> Task<Message> {
-    // /channels takes no arguments
-    if !args.is_empty() {
-        let error_msg = t_args("cmd-channels-usage", &[("command", invoked_name)]);
-        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
-    }
+    // /channels takes at most a language filter
+    let language = match args {
+        [] => None,
+        [language] if validators::validate_channel_language(language).is_ok() => {
+            Some(language.clone())
+        }
+        _ => {
+            let error_msg = t_args("cmd-channels-usage", &[("command", invoked_name)]);
+            return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+        }
+    };
 
     let Some(conn) = app.connections.get(&connection_id) else {
         return Task::none();
     };
 
     // Send ChatList message to server
-    let msg = ClientMessage::ChatList {};
+    let msg = ClientMessage::ChatList { language };
 
     if let Err(e) = conn.send(msg) {
         let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
@@ -0,0 +1,42 @@
+//! /cleanup command implementation - find leftovers of aborted transfers
+
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+
+use crate::NexusApp;
+use crate::i18n::t_args;
+use crate::types::{ChatMessage, Message};
+
+/// Execute the /cleanup command
+///
+/// Asks the server to scan the file area for orphaned `.part` files, leftover
+/// temp files and stale index entries. Without arguments it only reports what
+/// it finds; `delete` removes them. Admin only.
+///
+/// Usage: /cleanup [delete]
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    let delete = match args.first().map(|arg| arg.to_lowercase()).as_deref() {
+        None => false,
+        Some("delete") if args.len() == 1 => true,
+        _ => {
+            let error_msg = t_args("cmd-cleanup-usage", &[("command", invoked_name)]);
+            return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+        }
+    };
+
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    if let Err(e) = conn.send(ClientMessage::FileCleanup { delete }) {
+        let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    Task::none()
+}
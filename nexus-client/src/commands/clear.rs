@@ -50,6 +50,11 @@ pub fn execute(
                 messages.clear();
             }
         }
+        ChatTab::Group(group_id) => {
+            if let Some(group) = conn.groups.get_mut(group_id) {
+                group.messages.clear();
+            }
+        }
     }
 
     // Clear history file for user message tabs (keyed by nickname)
@@ -0,0 +1,95 @@
+//! /clip command implementation - soundboard clips in voice chat
+
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+use nexus_common::validators::{self, VoiceClipNameError};
+
+use crate::NexusApp;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message};
+
+/// Get translated subcommand keywords
+fn get_keywords() -> (String, String, String, String) {
+    (
+        t("cmd-clip-arg-list").to_lowercase(),
+        t("cmd-clip-arg-play").to_lowercase(),
+        t("cmd-clip-arg-add").to_lowercase(),
+        t("cmd-clip-arg-delete").to_lowercase(),
+    )
+}
+
+/// Execute the /clip command
+///
+/// Lists, plays, adds, and deletes soundboard clips. Clips play into the
+/// current voice session for every participant.
+///
+/// Usage:
+///   /clip                 - List the server's clips
+///   /clip list            - List the server's clips
+///   /clip <name>          - Play a clip (same as /clip play <name>)
+///   /clip play <name>     - Play a clip
+///   /clip add <name>      - Pick an Ogg Vorbis file and upload it as a clip
+///   /clip delete <name>   - Delete a clip
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    let (list_keyword, play_keyword, add_keyword, delete_keyword) = get_keywords();
+    let subcommand = args.first().map(|arg| arg.to_lowercase());
+
+    let msg = match (subcommand.as_deref(), args.len()) {
+        (None, _) => ClientMessage::VoiceClipList,
+        (Some(arg), 1) if arg == list_keyword => ClientMessage::VoiceClipList,
+        (Some(arg), 2) if arg == play_keyword => ClientMessage::VoiceClipPlay {
+            name: args[1].clone(),
+        },
+        (Some(arg), 2) if arg == delete_keyword => ClientMessage::VoiceClipDelete {
+            name: args[1].clone(),
+        },
+        (Some(arg), 2) if arg == add_keyword => {
+            return pick_clip_file(app, connection_id, args[1].clone());
+        }
+        (Some(arg), 1) if arg != play_keyword && arg != add_keyword && arg != delete_keyword => {
+            ClientMessage::VoiceClipPlay {
+                name: args[0].clone(),
+            }
+        }
+        _ => {
+            let error_msg = t_args("cmd-clip-usage", &[("command", invoked_name)]);
+            return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+        }
+    };
+
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    if let Err(e) = conn.send(msg) {
+        let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    Task::none()
+}
+
+/// Validate the clip name, then open a file picker for the clip's audio
+///
+/// The picked file is handled by `Message::VoiceClipFileLoaded`, which
+/// uploads it to the server.
+fn pick_clip_file(app: &mut NexusApp, connection_id: usize, name: String) -> Task<Message> {
+    if let Err(e) = validators::validate_voice_clip_name(&name) {
+        let error_msg = match e {
+            VoiceClipNameError::Empty => t("err-voice-clip-name-empty"),
+            VoiceClipNameError::TooLong => t_args(
+                "err-voice-clip-name-too-long",
+                &[("max", &validators::MAX_VOICE_CLIP_NAME_LENGTH.to_string())],
+            ),
+            VoiceClipNameError::InvalidCharacters => t("err-voice-clip-name-invalid"),
+        };
+        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    app.handle_voice_clip_pick_file(connection_id, name)
+}
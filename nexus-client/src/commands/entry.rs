@@ -0,0 +1,59 @@
+//! Shared helpers for the /welcome and /rules commands
+
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+
+use crate::NexusApp;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, ChatTab, Message, ResponseRouting};
+
+/// Get the channel of the active tab, or show an error if it isn't a channel
+pub fn active_channel(app: &mut NexusApp, connection_id: usize) -> Result<String, Task<Message>> {
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Err(Task::none());
+    };
+
+    match &conn.active_chat_tab {
+        ChatTab::Channel(ch) => Ok(ch.clone()),
+        ChatTab::Console | ChatTab::UserMessage(_) | ChatTab::Group(_) => Err(app
+            .add_active_tab_message(connection_id, ChatMessage::error(t("err-entry-no-channel")))),
+    }
+}
+
+/// Send a ChatEntryUpdate request and track it for response routing
+///
+/// `None` leaves a field unchanged, an empty string clears it.
+pub fn send_entry_update(
+    app: &mut NexusApp,
+    connection_id: usize,
+    channel: String,
+    entry_message: Option<String>,
+    rules: Option<String>,
+) -> Task<Message> {
+    let Some(conn) = app.connections.get_mut(&connection_id) else {
+        return Task::none();
+    };
+
+    let msg = ClientMessage::ChatEntryUpdate {
+        channel: channel.clone(),
+        entry_message: entry_message.clone(),
+        rules,
+    };
+
+    match conn.send(msg) {
+        Ok(message_id) => {
+            conn.pending_requests.track(
+                message_id,
+                ResponseRouting::EntryUpdateResult {
+                    channel,
+                    entry_message,
+                },
+            );
+            Task::none()
+        }
+        Err(e) => {
+            let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+            app.add_active_tab_message(connection_id, ChatMessage::error(error_msg))
+        }
+    }
+}
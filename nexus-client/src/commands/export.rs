@@ -0,0 +1,37 @@
+//! /export command implementation - save the current tab as an HTML file
+
+use iced::Task;
+
+use crate::NexusApp;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message};
+
+/// Execute the /export command
+///
+/// Renders the current tab's scrollback to a standalone HTML file with
+/// timestamps and nickname colors, and asks where to save it.
+///
+/// Usage:
+///   /export    - Export the current tab
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    if !args.is_empty() {
+        let error_msg = t_args("cmd-export-usage", &[("command", invoked_name)]);
+        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    if conn.tab_messages(&conn.active_chat_tab).is_empty() {
+        return app
+            .add_active_tab_message(connection_id, ChatMessage::error(t("err-export-empty")));
+    }
+
+    app.export_chat(connection_id)
+}
@@ -0,0 +1,43 @@
+//! /filecheck command implementation - check the file area for inconsistencies
+
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+
+use crate::NexusApp;
+use crate::i18n::t_args;
+use crate::types::{ChatMessage, Message};
+
+/// Execute the /filecheck command
+///
+/// Asks the server to compare the files on disk with the search index and
+/// with the metadata it keeps by path (history, ratings, comments, upload
+/// rules). Without arguments it only reports what it finds; `repair` rebuilds
+/// the index and drops metadata of paths that are gone. Admin only.
+///
+/// Usage: /filecheck [repair]
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    let repair = match args.first().map(|arg| arg.to_lowercase()).as_deref() {
+        None => false,
+        Some("repair") if args.len() == 1 => true,
+        _ => {
+            let error_msg = t_args("cmd-filecheck-usage", &[("command", invoked_name)]);
+            return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+        }
+    };
+
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    if let Err(e) = conn.send(ClientMessage::FileCheck { repair }) {
+        let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    Task::none()
+}
@@ -0,0 +1,81 @@
+//! /friend command implementation - manage the friends list
+
+use iced::Task;
+
+use crate::NexusApp;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message};
+
+/// Execute the /friend command
+///
+/// Friends are saved in the server's bookmark; the Friends section of the
+/// user list shows when they're online.
+///
+/// Usage: /friend [list]
+///        /friend add <username>
+///        /friend remove <username>
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    let usage = || t_args("cmd-friend-usage", &[("command", invoked_name)]);
+
+    let friend = match args.first().map(|arg| arg.to_lowercase()).as_deref() {
+        None | Some("list") if args.len() <= 1 => {
+            return list_friends(app, connection_id, invoked_name);
+        }
+        Some("add") if args.len() == 2 => true,
+        Some("remove") if args.len() == 2 => false,
+        _ => return app.add_active_tab_message(connection_id, ChatMessage::error(usage())),
+    };
+
+    let message = match app.set_friend(connection_id, &args[1], friend) {
+        Ok(info) => ChatMessage::info(info),
+        Err(error) => ChatMessage::error(error),
+    };
+    app.add_active_tab_message(connection_id, message)
+}
+
+/// Show every friend and whether they're online
+fn list_friends(app: &mut NexusApp, connection_id: usize, invoked_name: &str) -> Task<Message> {
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+    let friends = conn
+        .bookmark_id
+        .and_then(|id| app.config.get_bookmark(id))
+        .map(|bookmark| bookmark.friends.clone())
+        .unwrap_or_default();
+
+    if friends.is_empty() {
+        let info = t_args("cmd-friend-list-empty", &[("command", invoked_name)]);
+        return app.add_active_tab_message(connection_id, ChatMessage::info(info));
+    }
+
+    let lines: Vec<String> = friends
+        .iter()
+        .map(|username| {
+            let username_lower = username.to_lowercase();
+            let online = conn.friends.iter().any(|presence| {
+                presence.online && presence.username.to_lowercase() == username_lower
+            });
+            let state = if online {
+                t("friend-online")
+            } else {
+                t("friend-offline")
+            };
+            format!("  {username} ({state})")
+        })
+        .collect();
+
+    let mut tasks = vec![app.add_active_tab_message(
+        connection_id,
+        ChatMessage::info(t("cmd-friend-list-header")),
+    )];
+    for line in lines {
+        tasks.push(app.add_active_tab_message(connection_id, ChatMessage::info(line)));
+    }
+    Task::batch(tasks)
+}
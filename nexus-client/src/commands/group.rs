@@ -0,0 +1,59 @@
+//! /group command implementation - start a group conversation
+
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+use nexus_common::validators::{self, GroupMembersError, NicknameError};
+
+use crate::NexusApp;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message, ResponseRouting};
+
+/// Execute the /group command
+///
+/// Starts a group conversation with one or more users and switches to its
+/// tab once the server confirms.
+/// Usage: /group <nickname> [nickname...]
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    if let Err(e) = validators::validate_group_members(args) {
+        let error_msg = match e {
+            GroupMembersError::Empty => t_args("cmd-group-usage", &[("command", invoked_name)]),
+            GroupMembersError::TooMany => t_args(
+                "err-group-too-many-members",
+                &[("max", &validators::MAX_GROUP_MEMBERS.to_string())],
+            ),
+            GroupMembersError::InvalidNickname(NicknameError::Empty) => t("err-nickname-empty"),
+            GroupMembersError::InvalidNickname(NicknameError::TooLong) => t_args(
+                "err-nickname-too-long",
+                &[("max", &validators::MAX_NICKNAME_LENGTH.to_string())],
+            ),
+            GroupMembersError::InvalidNickname(NicknameError::InvalidCharacters) => {
+                t("err-nickname-invalid")
+            }
+        };
+        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    let Some(conn) = app.connections.get_mut(&connection_id) else {
+        return Task::none();
+    };
+
+    let msg = ClientMessage::GroupMessageCreate {
+        members: args.to_vec(),
+    };
+    match conn.send(msg) {
+        Ok(message_id) => {
+            conn.pending_requests
+                .track(message_id, ResponseRouting::OpenGroupTab(args.to_vec()));
+            Task::none()
+        }
+        Err(e) => {
+            let error_msg = t_args("err-failed-start-group", &[("error", &e.to_string())]);
+            app.add_active_tab_message(connection_id, ChatMessage::error(error_msg))
+        }
+    }
+}
@@ -0,0 +1,74 @@
+//! /history command implementation - server-side user message history
+
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+use nexus_common::validators::{self, MessageHistoryDaysError};
+
+use crate::NexusApp;
+use crate::handlers::message_history::retention_message;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, ChatTab, Message};
+
+/// Execute the /history command
+///
+/// Shows or sets how many days the server keeps your user messages, or
+/// loads older messages into the current user message tab. The server
+/// refuses it for shared account sessions.
+///
+/// Usage: /history            - Show how long messages are kept
+///        /history <days>     - Keep messages for this many days (0 = off)
+///        /history more       - Load older messages in a user message tab
+///
+/// Examples:
+///   /history 30
+///   /history 0
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+    let usage = || t_args("cmd-history-usage", &[("command", invoked_name)]);
+
+    let arg = match args {
+        [] => {
+            let days = conn.message_history_days.unwrap_or(0);
+            return app.add_active_tab_message(connection_id, retention_message(days));
+        }
+        [arg] => arg.to_lowercase(),
+        _ => return app.add_active_tab_message(connection_id, ChatMessage::error(usage())),
+    };
+
+    if arg == "more" {
+        let ChatTab::UserMessage(nickname) = conn.active_chat_tab.clone() else {
+            return app.add_active_tab_message(
+                connection_id,
+                ChatMessage::error(t("err-history-more-tab")),
+            );
+        };
+        return app.request_older_messages(connection_id, &nickname);
+    }
+
+    let Ok(days) = arg.parse::<u32>() else {
+        return app.add_active_tab_message(connection_id, ChatMessage::error(usage()));
+    };
+    if let Err(e) = validators::validate_message_history_days(days) {
+        let error_msg = match e {
+            MessageHistoryDaysError::TooLong => t_args(
+                "err-history-days-too-long",
+                &[("max", &validators::MAX_MESSAGE_HISTORY_DAYS.to_string())],
+            ),
+        };
+        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    if let Err(e) = conn.send(ClientMessage::MessageHistoryRetention { days }) {
+        let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    Task::none()
+}
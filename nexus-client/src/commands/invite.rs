@@ -0,0 +1,64 @@
+//! /invite command implementation - add a user to the current group conversation
+
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+use nexus_common::validators::{self, NicknameError};
+
+use crate::NexusApp;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, ChatTab, Message, ResponseRouting};
+
+/// Execute the /invite command
+///
+/// Adds a user to the group conversation of the active tab. Everyone in the
+/// group sees the new member list.
+/// Usage: /invite <nickname>
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    if args.len() != 1 {
+        let error_msg = t_args("cmd-invite-usage", &[("command", invoked_name)]);
+        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    let Some(conn) = app.connections.get_mut(&connection_id) else {
+        return Task::none();
+    };
+
+    let ChatTab::Group(group_id) = conn.active_chat_tab else {
+        let error_msg = t_args("err-invite-no-group", &[("command", invoked_name)]);
+        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+    };
+
+    let nickname = &args[0];
+    if let Err(e) = validators::validate_nickname(nickname) {
+        let error_msg = match e {
+            NicknameError::Empty => t("err-nickname-empty"),
+            NicknameError::TooLong => t_args(
+                "err-nickname-too-long",
+                &[("max", &validators::MAX_NICKNAME_LENGTH.to_string())],
+            ),
+            NicknameError::InvalidCharacters => t("err-nickname-invalid"),
+        };
+        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    let msg = ClientMessage::GroupMessageInvite {
+        group_id,
+        nickname: nickname.clone(),
+    };
+    match conn.send(msg) {
+        Ok(message_id) => {
+            conn.pending_requests
+                .track(message_id, ResponseRouting::ShowErrorInGroupTab(group_id));
+            Task::none()
+        }
+        Err(e) => {
+            let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+            app.add_active_tab_message(connection_id, ChatMessage::error(error_msg))
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//! /language command implementation - view or set the channel's language tag
+
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+use nexus_common::validators;
+
+use super::entry::active_channel;
+use crate::NexusApp;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message};
+
+/// Execute the /language command
+///
+/// Subcommands:
+/// - `/language` - Show the current channel's language tag
+/// - `/language set <tag>` - Tag the channel with a language (e.g. "en", "pt-BR")
+/// - `/language clear` - Remove the language tag
+///
+/// Requires chat_language permission.
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    let channel = match active_channel(app, connection_id) {
+        Ok(channel) => channel,
+        Err(task) => return task,
+    };
+
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    if args.is_empty() {
+        let language = conn
+            .get_channel_state(&channel)
+            .and_then(|ch| ch.language.clone());
+        let message = match language {
+            Some(language) => t_args("msg-language-status", &[("language", &language)]),
+            None => t("msg-language-none"),
+        };
+        return app.add_active_tab_message(connection_id, ChatMessage::info(message));
+    }
+
+    // Get translated subcommand keywords
+    let set_keyword = t("cmd-language-arg-set").to_lowercase();
+    let clear_keyword = t("cmd-language-arg-clear").to_lowercase();
+    let arg = args[0].to_lowercase();
+
+    let language = if arg == set_keyword && args.len() == 2 {
+        args[1].clone()
+    } else if arg == clear_keyword && args.len() == 1 {
+        String::new()
+    } else {
+        let error_msg = t_args("cmd-language-usage", &[("command", invoked_name)]);
+        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+    };
+
+    if validators::validate_channel_language(&language).is_err() {
+        let error_msg = t_args("err-language-invalid", &[("language", &language)]);
+        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    let msg = ClientMessage::ChatLanguage { channel, language };
+    if let Err(e) = conn.send(msg) {
+        let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    Task::none()
+}
@@ -0,0 +1,127 @@
+//! /ldap command implementation - map directory groups to permissions
+
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+use nexus_common::validators::{self, LdapGroupError};
+
+use crate::NexusApp;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message};
+
+/// Access keyword that makes a group's members admins
+const ADMIN_KEYWORD: &str = "admin";
+
+/// Access keyword for a group that grants no permissions
+const NONE_KEYWORD: &str = "none";
+
+/// Execute the /ldap command
+///
+/// Lists, sets and removes the directory group mappings used by LDAP logins.
+/// Admin only.
+///
+/// Usage: /ldap [list]
+///        /ldap set <permissions|admin|none> <group DN>
+///        /ldap remove <group DN>
+///
+/// Examples:
+///   /ldap set chat_send,file_list CN=Staff,OU=Groups,DC=example,DC=com
+///   /ldap set admin CN=IT Admins,OU=Groups,DC=example,DC=com
+///   /ldap remove CN=Staff,OU=Groups,DC=example,DC=com
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    let usage = || t_args("cmd-ldap-usage", &[("command", invoked_name)]);
+
+    let msg = match args.first().map(|arg| arg.to_lowercase()).as_deref() {
+        None | Some("list") if args.len() <= 1 => ClientMessage::LdapGroupList,
+        Some("set") if args.len() >= 3 => {
+            let group = args[2..].join(" ");
+            if let Err(error) = check_group(&group) {
+                return app.add_active_tab_message(connection_id, ChatMessage::error(error));
+            }
+            let (permissions, is_admin) = parse_access(&args[1]);
+            ClientMessage::LdapGroupSet {
+                group,
+                permissions,
+                is_admin,
+            }
+        }
+        Some("remove") if args.len() >= 2 => {
+            let group = args[1..].join(" ");
+            if let Err(error) = check_group(&group) {
+                return app.add_active_tab_message(connection_id, ChatMessage::error(error));
+            }
+            ClientMessage::LdapGroupDelete { group }
+        }
+        _ => return app.add_active_tab_message(connection_id, ChatMessage::error(usage())),
+    };
+
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    if let Err(e) = conn.send(msg) {
+        let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    Task::none()
+}
+
+/// Validate a group DN, returning a translated error
+fn check_group(group: &str) -> Result<(), String> {
+    validators::validate_ldap_group(group).map_err(|e| match e {
+        LdapGroupError::Empty => t("err-ldap-group-empty"),
+        LdapGroupError::TooLong => t_args(
+            "err-ldap-group-too-long",
+            &[("max", &validators::MAX_LDAP_GROUP_LENGTH.to_string())],
+        ),
+        LdapGroupError::InvalidCharacters => t("err-ldap-group-invalid-characters"),
+    })
+}
+
+/// Parse the access argument of `/ldap set`
+///
+/// A comma-separated list of permissions, where `admin` makes the group an
+/// admin group and `none` grants nothing. The server rejects unknown names.
+fn parse_access(access: &str) -> (Vec<String>, bool) {
+    let mut permissions = Vec::new();
+    let mut is_admin = false;
+    for name in access.split(',').map(str::trim) {
+        if name.eq_ignore_ascii_case(ADMIN_KEYWORD) {
+            is_admin = true;
+        } else if !name.is_empty() && !name.eq_ignore_ascii_case(NONE_KEYWORD) {
+            permissions.push(name.to_lowercase());
+        }
+    }
+    (permissions, is_admin)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_access() {
+        assert_eq!(
+            parse_access("chat_send,File_List"),
+            (
+                vec!["chat_send".to_string(), "file_list".to_string()],
+                false
+            )
+        );
+        assert_eq!(parse_access("admin"), (vec![], true));
+        assert_eq!(
+            parse_access("ADMIN,chat_send"),
+            (vec!["chat_send".to_string()], true)
+        );
+        assert_eq!(parse_access("none"), (vec![], false));
+        assert_eq!(
+            parse_access("chat_send,,"),
+            (vec!["chat_send".to_string()], false)
+        );
+    }
+}
@@ -1,4 +1,4 @@
-//! /leave command implementation - leave a channel
+//! /leave command implementation - leave a channel or group conversation
 
 use iced::Task;
 use nexus_common::protocol::ClientMessage;
@@ -13,7 +13,8 @@ use crate::types::{ChatMessage, ChatTab, Message};
 /// Leaves the current channel or a specified channel.
 /// Usage: /leave [#channel]
 ///
-/// If no channel is specified, leaves the currently active channel.
+/// If no channel is specified, leaves the currently active channel (or group
+/// conversation).
 /// Cannot leave from Console or User Message tabs without specifying a channel.
 pub fn execute(
     app: &mut NexusApp,
@@ -30,6 +31,11 @@ pub fn execute(
         // /leave without argument - leave current channel
         match &conn.active_chat_tab {
             ChatTab::Channel(ch) => ch.clone(),
+            // In a group conversation tab, leave the group
+            ChatTab::Group(group_id) => {
+                let group_id = *group_id;
+                return app.handle_close_group_tab(group_id);
+            }
             ChatTab::Console | ChatTab::UserMessage(_) => {
                 return app.add_active_tab_message(
                     connection_id,
@@ -60,6 +60,7 @@ pub fn execute(
                 message,
                 action: ChatAction::Me,
                 channel: channel.clone(),
+                idempotency_key: None,
             };
             if let Err(e) = conn.send(msg) {
                 return app.add_active_tab_message(connection_id, ChatMessage::error(e));
@@ -71,6 +72,19 @@ pub fn execute(
                 to_nickname: nickname.clone(),
                 message,
                 action: ChatAction::Me,
+                idempotency_key: None,
+            };
+            if let Err(e) = conn.send(msg) {
+                return app.add_active_tab_message(connection_id, ChatMessage::error(e));
+            }
+        }
+        ChatTab::Group(group_id) => {
+            // Send to the group conversation
+            let msg = ClientMessage::GroupMessageSend {
+                group_id: *group_id,
+                message,
+                action: ChatAction::Me,
+                idempotency_key: None,
             };
             if let Err(e) = conn.send(msg) {
                 return app.add_active_tab_message(connection_id, ChatMessage::error(e));
@@ -66,6 +66,7 @@ pub fn execute(
         to_nickname: nickname.clone(),
         message,
         action: ChatAction::Normal,
+        idempotency_key: None,
     };
 
     let message_id = match conn.send(msg) {
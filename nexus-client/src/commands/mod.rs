@@ -6,6 +6,7 @@
 //!
 //! | Command | Aliases | Permission | Description |
 //! |---------|---------|------------|-------------|
+//! | `/alias` | | *none* | List, set, or remove your own command aliases |
 //! | `/away` | `/a` | *none* | Set yourself as away |
 //! | `/back` | `/b` | *none* | Clear away status |
 //! | `/ban` | | `ban_create` | Ban a user by IP, CIDR range, or nickname |
@@ -13,20 +14,37 @@
 //! | `/broadcast` | `/bc` | `user_broadcast` | Send a broadcast to all users |
 //! | `/channels` | `/ch` | `chat_list` | List available channels |
 //! | `/clear` | | *none* | Clear chat history for current tab |
+//! | `/clip` | `/sb` | `voice_listen`, `voice_talk`, or `voice_clip_upload` | List, play, add, or delete soundboard clips |
+//! | `/export` | | *none* | Save the current tab as an HTML file |
 //! | `/focus` | `/f` | *none* | Focus server chat or a user's message tab |
+//! | `/friend` | `/friends` | *none* | List, add, or remove friends |
+//! | `/group` | `/g` | `user_message` | Start a group conversation with several users |
 //! | `/help` | `/h`, `/?` | *none* | Show available commands |
+//! | `/history` | | `user_message` | Show or set how long the server keeps your user messages, or load older ones |
 //! | `/info` | `/i`, `/userinfo`, `/whois` | `user_info` | Show information about a user |
+//! | `/invite` | | `user_message` | Add a user to the current group conversation |
 //! | `/join` | `/j` | `chat_join` | Join or create a channel |
 //! | `/kick` | `/k`, `/userkick` | `user_kick` | Kick a user from the server |
-//! | `/leave` | `/part` | *none* | Leave a channel |
+//! | `/language` | `/lang` | `chat_language` | View or set the channel's language tag |
+//! | `/ldap` | | *admin* | List, set, or remove directory group mappings |
+//! | `/leave` | `/part` | *none* | Leave a channel or group conversation |
 //! | `/list` | `/l`, `/userlist` | `user_list` | Show connected users |
 //! | `/me` | | `chat_send` | Send an action message |
 //! | `/message` | `/m`, `/msg` | `user_message` | Send a message to a user |
+//! | `/nickname` | `/nick` | *none* | Reserve or release nicknames for your account |
 //! | `/ping` | | *none* | Measure latency to server |
+//! | `/radio` | `/dj` | `voice_broadcast` | Broadcast audio to the channel's voice listeners |
+//! | `/register` | | *none* | Register an account from a guest session |
+//! | `/rules` | | `chat_entry_edit` | View or set the channel rules |
+//! | `/screenshot` | `/ss` | `file_upload` | Share an annotated screenshot in the current tab |
 //! | `/sinfo` | `/si`, `/serverinfo` | *none* | Show server information |
 //! | `/status` | `/s` | *none* | Set or clear your status message |
-//! | `/topic` | `/t`, `/chattopic` | `chat_topic` or `chat_topic_edit` | View or manage the chat topic |
+//! | `/top` | `/leaderboard` | `user_info` | Show the most active users |
+//! | `/topic` | `/t`, `/chattopic` | `chat_topic`, `chat_topic_edit` or `chat_topic_lock` | View or manage the chat topic |
 //! | `/unban` | | `ban_delete` | Remove an IP ban |
+//! | `/voicelimit` | `/vl` | `voice_limit` | View or set the channel's voice participant limit |
+//! | `/vt` | `/voicetext` | `voice_listen` or `voice_talk` | Send a text message to your voice session |
+//! | `/welcome` | | `chat_entry_edit` | View or set the channel entry message |
 //! | `/window` | `/w` | *none* | Manage chat tabs (list, close) |
 //!
 //! ## Special Syntax
@@ -34,34 +52,54 @@
 //! - `/` alone is a shortcut for `/help`
 //! - `//text` - Escape sequence, sends `/text` as a regular message
 //! - ` /command` - Leading space prevents command parsing
+//! - `/name` - Runs a user-defined alias (see `/alias`), if `name` isn't a built-in command
 //!
 //! ## Permissions
 //!
-//! Commands may require permissions to execute. If a user doesn't have the required
-//! permission, the command is treated as unknown (same error as non-existent command).
+//! Commands may require permissions to execute, and some are only for admins. If a
+//! user doesn't have the required permission, the command is treated as unknown (same
+//! error as non-existent command).
 //!
 //! Unknown commands display an error in chat and are never sent to the server.
 
+mod alias;
 mod away;
 mod back;
 mod ban;
 mod bans;
 mod broadcast;
 mod channels;
+mod cleanup;
 mod clear;
+mod clip;
 mod duration;
+mod entry;
+mod export;
+mod filecheck;
 mod focus;
+mod friend;
+mod group;
 mod help;
+mod history;
+mod invite;
 mod join;
+mod language;
+mod ldap;
 mod leave;
 mod list;
 mod me;
 mod message;
+mod nickname;
 mod ping;
+mod radio;
+mod register;
 mod reindex;
+mod rules;
+mod screenshot;
 mod secret;
 mod server_info;
 mod status;
+mod top;
 mod topic;
 mod trust;
 mod trusted;
@@ -69,9 +107,12 @@ mod unban;
 mod untrust;
 mod user_info;
 mod user_kick;
+mod voice_limit;
+mod voice_text;
+mod welcome;
 mod window;
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::LazyLock;
 
 use iced::Task;
@@ -81,13 +122,18 @@ use crate::NexusApp;
 use crate::i18n::t_args;
 use crate::types::{ChatMessage, Message};
 use crate::views::constants::{
-    PERMISSION_BAN_CREATE, PERMISSION_BAN_DELETE, PERMISSION_BAN_LIST, PERMISSION_CHAT_JOIN,
-    PERMISSION_CHAT_LIST, PERMISSION_CHAT_SECRET, PERMISSION_CHAT_SEND, PERMISSION_CHAT_TOPIC,
-    PERMISSION_CHAT_TOPIC_EDIT, PERMISSION_FILE_REINDEX, PERMISSION_TRUST_CREATE,
-    PERMISSION_TRUST_DELETE, PERMISSION_TRUST_LIST, PERMISSION_USER_BROADCAST,
-    PERMISSION_USER_INFO, PERMISSION_USER_KICK, PERMISSION_USER_LIST, PERMISSION_USER_MESSAGE,
+    PERMISSION_BAN_CREATE, PERMISSION_BAN_DELETE, PERMISSION_BAN_LIST, PERMISSION_CHAT_ENTRY_EDIT,
+    PERMISSION_CHAT_JOIN, PERMISSION_CHAT_LANGUAGE, PERMISSION_CHAT_LIST, PERMISSION_CHAT_SECRET,
+    PERMISSION_CHAT_SEND, PERMISSION_CHAT_TOPIC, PERMISSION_CHAT_TOPIC_EDIT,
+    PERMISSION_CHAT_TOPIC_LOCK, PERMISSION_FILE_REINDEX, PERMISSION_FILE_UPLOAD,
+    PERMISSION_TRUST_CREATE, PERMISSION_TRUST_DELETE, PERMISSION_TRUST_LIST,
+    PERMISSION_USER_BROADCAST, PERMISSION_USER_INFO, PERMISSION_USER_KICK, PERMISSION_USER_LIST,
+    PERMISSION_USER_MESSAGE, PERMISSION_VOICE_BROADCAST, PERMISSION_VOICE_CLIP_UPLOAD,
+    PERMISSION_VOICE_LIMIT, PERMISSION_VOICE_LISTEN, PERMISSION_VOICE_TALK,
 };
 
+pub use alias::expand_alias;
+
 /// Command handler function type
 /// Parameters: app, connection_id, invoked_name, args
 type CommandHandler = fn(&mut NexusApp, usize, &str, &[String]) -> Task<Message>;
@@ -104,6 +150,8 @@ pub struct CommandInfo {
     pub usage_key: &'static str,
     /// Required permissions (any of these grants access, empty = always available)
     pub permissions: &'static [&'static str],
+    /// Only available to admins, whatever the permissions
+    pub admin_only: bool,
 }
 
 /// Command registration entry - links metadata to handler
@@ -114,6 +162,17 @@ struct CommandRegistration {
 
 /// All registered commands (alphabetical order)
 static COMMANDS: &[CommandRegistration] = &[
+    CommandRegistration {
+        info: CommandInfo {
+            name: "alias",
+            aliases: &[],
+            description_key: "cmd-alias-desc",
+            usage_key: "cmd-alias-usage",
+            permissions: &[],
+            admin_only: false,
+        },
+        handler: alias::execute,
+    },
     CommandRegistration {
         info: CommandInfo {
             name: "away",
@@ -121,6 +180,7 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-away-desc",
             usage_key: "cmd-away-usage",
             permissions: &[],
+            admin_only: false,
         },
         handler: away::execute,
     },
@@ -131,6 +191,7 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-back-desc",
             usage_key: "cmd-back-usage",
             permissions: &[],
+            admin_only: false,
         },
         handler: back::execute,
     },
@@ -141,6 +202,7 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-ban-desc",
             usage_key: "cmd-ban-usage",
             permissions: &[PERMISSION_BAN_CREATE],
+            admin_only: false,
         },
         handler: ban::execute,
     },
@@ -151,6 +213,7 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-bans-desc",
             usage_key: "cmd-bans-usage",
             permissions: &[PERMISSION_BAN_LIST],
+            admin_only: false,
         },
         handler: bans::execute,
     },
@@ -161,6 +224,7 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-broadcast-desc",
             usage_key: "cmd-broadcast-usage",
             permissions: &[PERMISSION_USER_BROADCAST],
+            admin_only: false,
         },
         handler: broadcast::execute,
     },
@@ -171,9 +235,21 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-channels-desc",
             usage_key: "cmd-channels-usage",
             permissions: &[PERMISSION_CHAT_LIST],
+            admin_only: false,
         },
         handler: channels::execute,
     },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "cleanup",
+            aliases: &[],
+            description_key: "cmd-cleanup-desc",
+            usage_key: "cmd-cleanup-usage",
+            permissions: &[],
+            admin_only: true,
+        },
+        handler: cleanup::execute,
+    },
     CommandRegistration {
         info: CommandInfo {
             name: "clear",
@@ -181,9 +257,47 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-clear-desc",
             usage_key: "cmd-clear-usage",
             permissions: &[],
+            admin_only: false,
         },
         handler: clear::execute,
     },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "clip",
+            aliases: &["sb"],
+            description_key: "cmd-clip-desc",
+            usage_key: "cmd-clip-usage",
+            permissions: &[
+                PERMISSION_VOICE_LISTEN,
+                PERMISSION_VOICE_TALK,
+                PERMISSION_VOICE_CLIP_UPLOAD,
+            ],
+            admin_only: false,
+        },
+        handler: clip::execute,
+    },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "export",
+            aliases: &[],
+            description_key: "cmd-export-desc",
+            usage_key: "cmd-export-usage",
+            permissions: &[],
+            admin_only: false,
+        },
+        handler: export::execute,
+    },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "filecheck",
+            aliases: &["fsck"],
+            description_key: "cmd-filecheck-desc",
+            usage_key: "cmd-filecheck-usage",
+            permissions: &[],
+            admin_only: true,
+        },
+        handler: filecheck::execute,
+    },
     CommandRegistration {
         info: CommandInfo {
             name: "focus",
@@ -191,9 +305,32 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-focus-desc",
             usage_key: "cmd-focus-usage",
             permissions: &[],
+            admin_only: false,
         },
         handler: focus::execute,
     },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "friend",
+            aliases: &["friends"],
+            description_key: "cmd-friend-desc",
+            usage_key: "cmd-friend-usage",
+            permissions: &[],
+            admin_only: false,
+        },
+        handler: friend::execute,
+    },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "group",
+            aliases: &["g"],
+            description_key: "cmd-group-desc",
+            usage_key: "cmd-group-usage",
+            permissions: &[PERMISSION_USER_MESSAGE],
+            admin_only: false,
+        },
+        handler: group::execute,
+    },
     CommandRegistration {
         info: CommandInfo {
             name: "help",
@@ -201,9 +338,21 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-help-desc",
             usage_key: "cmd-help-usage",
             permissions: &[],
+            admin_only: false,
         },
         handler: help::execute,
     },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "history",
+            aliases: &[],
+            description_key: "cmd-history-desc",
+            usage_key: "cmd-history-usage",
+            permissions: &[PERMISSION_USER_MESSAGE],
+            admin_only: false,
+        },
+        handler: history::execute,
+    },
     CommandRegistration {
         info: CommandInfo {
             name: "info",
@@ -211,9 +360,21 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-userinfo-desc",
             usage_key: "cmd-userinfo-usage",
             permissions: &[PERMISSION_USER_INFO],
+            admin_only: false,
         },
         handler: user_info::execute,
     },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "invite",
+            aliases: &[],
+            description_key: "cmd-invite-desc",
+            usage_key: "cmd-invite-usage",
+            permissions: &[PERMISSION_USER_MESSAGE],
+            admin_only: false,
+        },
+        handler: invite::execute,
+    },
     CommandRegistration {
         info: CommandInfo {
             name: "join",
@@ -221,6 +382,7 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-join-desc",
             usage_key: "cmd-join-usage",
             permissions: &[PERMISSION_CHAT_JOIN],
+            admin_only: false,
         },
         handler: join::execute,
     },
@@ -231,9 +393,32 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-kick-desc",
             usage_key: "cmd-kick-usage",
             permissions: &[PERMISSION_USER_KICK],
+            admin_only: false,
         },
         handler: user_kick::execute,
     },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "language",
+            aliases: &["lang"],
+            description_key: "cmd-language-desc",
+            usage_key: "cmd-language-usage",
+            permissions: &[PERMISSION_CHAT_LANGUAGE],
+            admin_only: false,
+        },
+        handler: language::execute,
+    },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "ldap",
+            aliases: &[],
+            description_key: "cmd-ldap-desc",
+            usage_key: "cmd-ldap-usage",
+            permissions: &[],
+            admin_only: true,
+        },
+        handler: ldap::execute,
+    },
     CommandRegistration {
         info: CommandInfo {
             name: "leave",
@@ -241,6 +426,7 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-leave-desc",
             usage_key: "cmd-leave-usage",
             permissions: &[],
+            admin_only: false,
         },
         handler: leave::execute,
     },
@@ -251,6 +437,7 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-list-desc",
             usage_key: "cmd-list-usage",
             permissions: &[PERMISSION_USER_LIST],
+            admin_only: false,
         },
         handler: list::execute,
     },
@@ -261,6 +448,7 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-me-desc",
             usage_key: "cmd-me-usage",
             permissions: &[PERMISSION_CHAT_SEND],
+            admin_only: false,
         },
         handler: me::execute,
     },
@@ -271,9 +459,21 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-message-desc",
             usage_key: "cmd-message-usage",
             permissions: &[PERMISSION_USER_MESSAGE],
+            admin_only: false,
         },
         handler: message::execute,
     },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "nickname",
+            aliases: &["nick"],
+            description_key: "cmd-nickname-desc",
+            usage_key: "cmd-nickname-usage",
+            permissions: &[],
+            admin_only: false,
+        },
+        handler: nickname::execute,
+    },
     CommandRegistration {
         info: CommandInfo {
             name: "ping",
@@ -281,9 +481,32 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-ping-desc",
             usage_key: "cmd-ping-usage",
             permissions: &[],
+            admin_only: false,
         },
         handler: ping::execute,
     },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "radio",
+            aliases: &["dj"],
+            description_key: "cmd-radio-desc",
+            usage_key: "cmd-radio-usage",
+            permissions: &[PERMISSION_VOICE_BROADCAST],
+            admin_only: false,
+        },
+        handler: radio::execute,
+    },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "register",
+            aliases: &[],
+            description_key: "cmd-register-desc",
+            usage_key: "cmd-register-usage",
+            permissions: &[],
+            admin_only: false,
+        },
+        handler: register::execute,
+    },
     CommandRegistration {
         info: CommandInfo {
             name: "reindex",
@@ -291,9 +514,32 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-reindex-desc",
             usage_key: "cmd-reindex-usage",
             permissions: &[PERMISSION_FILE_REINDEX],
+            admin_only: false,
         },
         handler: reindex::execute,
     },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "rules",
+            aliases: &[],
+            description_key: "cmd-rules-desc",
+            usage_key: "cmd-rules-usage",
+            permissions: &[PERMISSION_CHAT_ENTRY_EDIT],
+            admin_only: false,
+        },
+        handler: rules::execute,
+    },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "screenshot",
+            aliases: &["ss"],
+            description_key: "cmd-screenshot-desc",
+            usage_key: "cmd-screenshot-usage",
+            permissions: &[PERMISSION_FILE_UPLOAD],
+            admin_only: false,
+        },
+        handler: screenshot::execute,
+    },
     CommandRegistration {
         info: CommandInfo {
             name: "secret",
@@ -301,6 +547,7 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-secret-desc",
             usage_key: "cmd-secret-usage",
             permissions: &[PERMISSION_CHAT_SECRET],
+            admin_only: false,
         },
         handler: secret::execute,
     },
@@ -311,6 +558,7 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-serverinfo-desc",
             usage_key: "cmd-serverinfo-usage",
             permissions: &[],
+            admin_only: false,
         },
         handler: server_info::execute,
     },
@@ -321,16 +569,33 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-status-desc",
             usage_key: "cmd-status-usage",
             permissions: &[],
+            admin_only: false,
         },
         handler: status::execute,
     },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "top",
+            aliases: &["leaderboard"],
+            description_key: "cmd-top-desc",
+            usage_key: "cmd-top-usage",
+            permissions: &[PERMISSION_USER_INFO],
+            admin_only: false,
+        },
+        handler: top::execute,
+    },
     CommandRegistration {
         info: CommandInfo {
             name: "topic",
             aliases: &["t", "chattopic"],
             description_key: "cmd-topic-desc",
             usage_key: "cmd-topic-usage",
-            permissions: &[PERMISSION_CHAT_TOPIC, PERMISSION_CHAT_TOPIC_EDIT],
+            permissions: &[
+                PERMISSION_CHAT_TOPIC,
+                PERMISSION_CHAT_TOPIC_EDIT,
+                PERMISSION_CHAT_TOPIC_LOCK,
+            ],
+            admin_only: false,
         },
         handler: topic::execute,
     },
@@ -341,6 +606,7 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-trust-desc",
             usage_key: "cmd-trust-usage",
             permissions: &[PERMISSION_TRUST_CREATE],
+            admin_only: false,
         },
         handler: trust::execute,
     },
@@ -351,6 +617,7 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-trusted-desc",
             usage_key: "cmd-trusted-usage",
             permissions: &[PERMISSION_TRUST_LIST],
+            admin_only: false,
         },
         handler: trusted::execute,
     },
@@ -361,6 +628,7 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-unban-desc",
             usage_key: "cmd-unban-usage",
             permissions: &[PERMISSION_BAN_DELETE],
+            admin_only: false,
         },
         handler: unban::execute,
     },
@@ -371,9 +639,43 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-untrust-desc",
             usage_key: "cmd-untrust-usage",
             permissions: &[PERMISSION_TRUST_DELETE],
+            admin_only: false,
         },
         handler: untrust::execute,
     },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "voicelimit",
+            aliases: &["vl"],
+            description_key: "cmd-voicelimit-desc",
+            usage_key: "cmd-voicelimit-usage",
+            permissions: &[PERMISSION_VOICE_LIMIT],
+            admin_only: false,
+        },
+        handler: voice_limit::execute,
+    },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "vt",
+            aliases: &["voicetext"],
+            description_key: "cmd-vt-desc",
+            usage_key: "cmd-vt-usage",
+            permissions: &[PERMISSION_VOICE_LISTEN, PERMISSION_VOICE_TALK],
+            admin_only: false,
+        },
+        handler: voice_text::execute,
+    },
+    CommandRegistration {
+        info: CommandInfo {
+            name: "welcome",
+            aliases: &[],
+            description_key: "cmd-welcome-desc",
+            usage_key: "cmd-welcome-usage",
+            permissions: &[PERMISSION_CHAT_ENTRY_EDIT],
+            admin_only: false,
+        },
+        handler: welcome::execute,
+    },
     CommandRegistration {
         info: CommandInfo {
             name: "window",
@@ -381,6 +683,7 @@ static COMMANDS: &[CommandRegistration] = &[
             description_key: "cmd-window-desc",
             usage_key: "cmd-window-usage",
             permissions: &[],
+            admin_only: false,
         },
         handler: window::execute,
     },
@@ -407,22 +710,29 @@ pub fn get_command_info(name: &str) -> Option<&'static CommandInfo> {
         .map(|&index| &COMMANDS[index].info)
 }
 
-/// Complete a command name. Returns matching command names or None if no matches.
+/// Complete a command name or user alias. Returns matching names or None if no matches.
 pub fn complete_command(
     prefix: &str,
     is_admin: bool,
     permissions: &[String],
+    aliases: &BTreeMap<String, String>,
 ) -> Option<Vec<String>> {
     let prefix_lower = prefix.to_lowercase();
-    let matches: Vec<String> = command_names_for_completion(is_admin, permissions)
+    let mut matches: Vec<String> = command_names_for_completion(is_admin, permissions)
         .into_iter()
+        .chain(
+            aliases
+                .keys()
+                .filter(|name| get_command_info(name).is_none())
+                .cloned(),
+        )
         .filter(|cmd| cmd.to_lowercase().starts_with(&prefix_lower))
         .collect();
 
     if matches.is_empty() {
         None
     } else {
-        // Already sorted by command_names_for_completion
+        matches.sort_unstable_by_key(|a| a.to_lowercase());
         Some(matches)
     }
 }
@@ -487,13 +797,14 @@ pub fn last_word(input: &str) -> (usize, &str) {
 pub fn command_names_for_completion(is_admin: bool, permissions: &[String]) -> Vec<String> {
     let mut names = Vec::new();
     for reg in COMMANDS.iter() {
-        let has_permission = reg.info.permissions.is_empty()
-            || is_admin
-            || reg
-                .info
-                .permissions
-                .iter()
-                .any(|req| permissions.iter().any(|p| p == *req));
+        let has_permission = is_admin
+            || (!reg.info.admin_only
+                && (reg.info.permissions.is_empty()
+                    || reg
+                        .info
+                        .permissions
+                        .iter()
+                        .any(|req| permissions.iter().any(|p| p == *req))));
         if has_permission {
             names.push(reg.info.name.to_string());
             for alias in reg.info.aliases {
@@ -511,13 +822,14 @@ pub(crate) fn command_list_for_permissions(
     permissions: &[String],
 ) -> impl Iterator<Item = &'static CommandInfo> {
     COMMANDS.iter().filter_map(move |reg| {
-        let has_permission = reg.info.permissions.is_empty()
-            || is_admin
-            || reg
-                .info
-                .permissions
-                .iter()
-                .any(|req| permissions.iter().any(|p| p == *req));
+        let has_permission = is_admin
+            || (!reg.info.admin_only
+                && (reg.info.permissions.is_empty()
+                    || reg
+                        .info
+                        .permissions
+                        .iter()
+                        .any(|req| permissions.iter().any(|p| p == *req))));
         if has_permission {
             Some(&reg.info)
         } else {
@@ -604,10 +916,9 @@ pub fn execute_command(
         let reg = &COMMANDS[index];
 
         // Check permissions
-        let has_permission = app
-            .connections
-            .get(&connection_id)
-            .is_some_and(|conn| conn.has_any_permission(reg.info.permissions));
+        let has_permission = app.connections.get(&connection_id).is_some_and(|conn| {
+            conn.is_admin || (!reg.info.admin_only && conn.has_any_permission(reg.info.permissions))
+        });
 
         if has_permission {
             return (reg.handler)(app, connection_id, &command.name, &command.args);
@@ -839,6 +1150,8 @@ mod tests {
         // Should not see permission-gated commands
         assert!(!commands.iter().any(|c| c.name == "kick"));
         assert!(!commands.iter().any(|c| c.name == "broadcast"));
+        // Nor admin-only ones
+        assert!(!commands.iter().any(|c| c.name == "ldap"));
     }
 
     #[test]
@@ -1181,7 +1494,7 @@ mod tests {
 
     #[test]
     fn test_complete_command_empty_prefix_admin() {
-        let result = complete_command("", true, &[]);
+        let result = complete_command("", true, &[], &BTreeMap::new());
         assert!(result.is_some());
         let matches = result.unwrap();
         // Admin should see all commands and aliases
@@ -1190,7 +1503,7 @@ mod tests {
 
     #[test]
     fn test_complete_command_empty_prefix_no_perms() {
-        let result = complete_command("", false, &[]);
+        let result = complete_command("", false, &[], &BTreeMap::new());
         assert!(result.is_some());
         let matches = result.unwrap();
         // Should at least see help, clear, etc.
@@ -1200,7 +1513,7 @@ mod tests {
 
     #[test]
     fn test_complete_command_partial_match() {
-        let result = complete_command("he", true, &[]);
+        let result = complete_command("he", true, &[], &BTreeMap::new());
         assert!(result.is_some());
         let matches = result.unwrap();
         assert!(matches.iter().any(|c| c == "help"));
@@ -1208,13 +1521,13 @@ mod tests {
 
     #[test]
     fn test_complete_command_no_match() {
-        let result = complete_command("xyz", true, &[]);
+        let result = complete_command("xyz", true, &[], &BTreeMap::new());
         assert!(result.is_none());
     }
 
     #[test]
     fn test_complete_command_case_insensitive() {
-        let result = complete_command("HE", true, &[]);
+        let result = complete_command("HE", true, &[], &BTreeMap::new());
         assert!(result.is_some());
         let matches = result.unwrap();
         assert!(matches.iter().any(|c| c == "help"));
@@ -1222,7 +1535,7 @@ mod tests {
 
     #[test]
     fn test_complete_command_includes_aliases() {
-        let result = complete_command("", true, &[]);
+        let result = complete_command("", true, &[], &BTreeMap::new());
         assert!(result.is_some());
         let matches = result.unwrap();
         // "h" and "?" are aliases for help
@@ -1232,7 +1545,7 @@ mod tests {
 
     #[test]
     fn test_complete_command_alias_match() {
-        let result = complete_command("h", true, &[]);
+        let result = complete_command("h", true, &[], &BTreeMap::new());
         assert!(result.is_some());
         let matches = result.unwrap();
         // Should match "h" alias and "help" command
@@ -1243,12 +1556,12 @@ mod tests {
     #[test]
     fn test_complete_command_permission_gated() {
         // Without permissions, shouldn't see kick
-        let result = complete_command("ki", false, &[]);
+        let result = complete_command("ki", false, &[], &BTreeMap::new());
         assert!(result.is_none());
 
         // With permission, should see kick
         let perms = vec!["user_kick".to_string()];
-        let result = complete_command("ki", false, &perms);
+        let result = complete_command("ki", false, &perms, &BTreeMap::new());
         assert!(result.is_some());
         let matches = result.unwrap();
         assert!(matches.iter().any(|c| c == "kick"));
@@ -1256,7 +1569,7 @@ mod tests {
 
     #[test]
     fn test_complete_command_sorted() {
-        let result = complete_command("", true, &[]);
+        let result = complete_command("", true, &[], &BTreeMap::new());
         assert!(result.is_some());
         let matches = result.unwrap();
         // Verify sorted order
@@ -1270,6 +1583,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_complete_command_includes_user_aliases() {
+        let aliases = BTreeMap::from([
+            ("gn".to_string(), "/me waves goodnight".to_string()),
+            ("help".to_string(), "shadowed by /help".to_string()),
+        ]);
+        let matches = complete_command("g", false, &[], &aliases).unwrap();
+        assert_eq!(matches, ["gn"]);
+
+        // An alias named like a built-in command isn't listed twice
+        let matches = complete_command("help", true, &[], &aliases).unwrap();
+        assert_eq!(matches, ["help"]);
+    }
+
     // --- command_names_for_completion tests ---
 
     #[test]
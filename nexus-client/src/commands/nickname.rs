@@ -0,0 +1,52 @@
+//! /nickname command implementation - reserve nicknames for your account
+
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+
+use crate::NexusApp;
+use crate::i18n::t_args;
+use crate::types::{ChatMessage, Message};
+
+/// Execute the /nickname command
+///
+/// Reserves nicknames so nobody logging in to a shared account or as a
+/// guest can use them. Admins can reserve one for another account, and
+/// release anyone's. The server refuses it for shared account sessions.
+///
+/// Usage: /nickname register <nickname> [username]
+///        /nickname release <nickname>
+///
+/// Examples:
+///   /nickname register Bobby
+///   /nickname register Ally alice
+///   /nickname release Bobby
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    let usage = || t_args("cmd-nickname-usage", &[("command", invoked_name)]);
+
+    let msg = match args.first().map(|arg| arg.to_lowercase()).as_deref() {
+        Some("register") if matches!(args.len(), 2 | 3) => ClientMessage::NicknameRegister {
+            nickname: args[1].clone(),
+            username: args.get(2).cloned(),
+        },
+        Some("release") if args.len() == 2 => ClientMessage::NicknameRelease {
+            nickname: args[1].clone(),
+        },
+        _ => return app.add_active_tab_message(connection_id, ChatMessage::error(usage())),
+    };
+
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    if let Err(e) = conn.send(msg) {
+        let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    Task::none()
+}
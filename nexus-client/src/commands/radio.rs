@@ -0,0 +1,49 @@
+//! /radio command implementation - broadcast audio to a channel's voice listeners
+
+use iced::Task;
+
+use crate::NexusApp;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, ChatTab, Message};
+
+/// Execute the /radio command
+///
+/// Starts a broadcast in the current channel's voice. The channel's voice
+/// must be empty; everyone who joins it afterwards is a listen-only listener.
+/// Leave voice to end the broadcast.
+///
+/// Usage:
+///   /radio    - Start broadcasting in the current channel
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    if !args.is_empty() {
+        let error_msg = t_args("cmd-radio-usage", &[("command", invoked_name)]);
+        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    // /radio only works on channel tabs, not console or PM
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    let channel = match &conn.active_chat_tab {
+        ChatTab::Channel(ch) => ch.clone(),
+        ChatTab::Console | ChatTab::UserMessage(_) | ChatTab::Group(_) => {
+            return app.add_active_tab_message(
+                connection_id,
+                ChatMessage::error(t("err-radio-no-channel")),
+            );
+        }
+    };
+
+    if conn.voice_session.is_some() {
+        return app
+            .add_active_tab_message(connection_id, ChatMessage::error(t("err-radio-in-voice")));
+    }
+
+    app.request_voice_join(connection_id, channel, true)
+}
@@ -0,0 +1,42 @@
+//! /register command implementation - turn a guest session into an account
+
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+
+use crate::NexusApp;
+use crate::i18n::t_args;
+use crate::types::{ChatMessage, Message};
+
+/// Execute the /register command
+///
+/// Registers a personal account from a guest session. The server refuses it
+/// for other sessions, or if guests may not register.
+///
+/// Usage: /register <username> <password>
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    let [username, password] = args else {
+        let error_msg = t_args("cmd-register-usage", &[("command", invoked_name)]);
+        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+    };
+
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    let msg = ClientMessage::GuestUpgrade {
+        username: username.clone(),
+        password: password.clone(),
+    };
+
+    if let Err(e) = conn.send(msg) {
+        let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    Task::none()
+}
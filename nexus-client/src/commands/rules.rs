@@ -0,0 +1,79 @@
+//! /rules command implementation - view or set the channel rules
+
+use iced::Task;
+use nexus_common::validators::{self, ChatEntryError};
+
+use super::entry::{active_channel, send_entry_update};
+use crate::NexusApp;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message};
+
+/// Execute the /rules command
+///
+/// Subcommands:
+/// - `/rules` - Show the current channel rules
+/// - `/rules set <rules>` - Set the rules shown in the channel info popover
+/// - `/rules clear` - Clear the rules
+///
+/// Requires chat_entry_edit permission.
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    let channel = match active_channel(app, connection_id) {
+        Ok(channel) => channel,
+        Err(task) => return task,
+    };
+
+    if args.is_empty() {
+        let rules = app
+            .connections
+            .get(&connection_id)
+            .and_then(|conn| conn.get_channel_state(&channel))
+            .and_then(|ch| ch.rules.clone());
+        let Some(rules) = rules else {
+            return app
+                .add_active_tab_message(connection_id, ChatMessage::info(t("cmd-rules-none")));
+        };
+
+        let mut output = t_args("msg-rules-header", &[("channel", &channel)]);
+        for line in rules.lines() {
+            output.push('\n');
+            output.push_str("  ");
+            output.push_str(line);
+        }
+        return app.add_active_tab_message(connection_id, ChatMessage::info(output));
+    }
+
+    // Get translated subcommand keywords
+    let set_keyword = t("cmd-rules-arg-set").to_lowercase();
+    let clear_keyword = t("cmd-rules-arg-clear").to_lowercase();
+    let arg = args[0].to_lowercase();
+
+    let rules = if arg == set_keyword && args.len() > 1 {
+        args[1..].join(" ")
+    } else if arg == clear_keyword && args.len() == 1 {
+        String::new()
+    } else {
+        let error_msg = t_args("cmd-rules-usage", &[("command", invoked_name)]);
+        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+    };
+
+    if let Err(e) = validators::validate_chat_rules(&rules) {
+        let error_msg = match e {
+            ChatEntryError::TooLong => t_args(
+                "err-rules-too-long",
+                &[
+                    ("length", &rules.len().to_string()),
+                    ("max", &validators::MAX_CHAT_RULES_LENGTH.to_string()),
+                ],
+            ),
+            ChatEntryError::InvalidCharacters => t("err-message-invalid-characters"),
+        };
+        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    send_entry_update(app, connection_id, channel, None, Some(rules))
+}
@@ -0,0 +1,53 @@
+//! /screenshot command implementation - share a screenshot in the current tab
+
+use iced::Task;
+
+use crate::NexusApp;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, ChatTab, Message};
+
+/// Execute the /screenshot command
+///
+/// Captures the screen and opens the annotation editor. Sending uploads the
+/// image into your folder under the connection's screenshot folder (set with
+/// "Share Screenshots Here" in Files) and posts a link to the current tab.
+///
+/// Usage:
+///   /screenshot    - Capture and share a screenshot
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    if !args.is_empty() {
+        let error_msg = t_args("cmd-screenshot-usage", &[("command", invoked_name)]);
+        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    // Links are posted to a channel or user message tab, never the console
+    if conn.active_chat_tab == ChatTab::Console {
+        return app.add_active_tab_message(
+            connection_id,
+            ChatMessage::error(t("err-screenshot-no-target")),
+        );
+    }
+
+    if app.screenshot_folder_for(conn).is_none() {
+        return app.add_active_tab_message(
+            connection_id,
+            ChatMessage::error(t("err-screenshot-no-folder")),
+        );
+    }
+
+    // One screenshot at a time
+    if conn.screenshot_editor.is_some() {
+        return Task::none();
+    }
+
+    app.start_screenshot_share(connection_id)
+}
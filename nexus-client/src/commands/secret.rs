@@ -32,7 +32,7 @@ pub fn execute(
 
     let channel = match &conn.active_chat_tab {
         ChatTab::Channel(ch) => ch.clone(),
-        ChatTab::Console | ChatTab::UserMessage(_) => {
+        ChatTab::Console | ChatTab::UserMessage(_) | ChatTab::Group(_) => {
             return app.add_active_tab_message(
                 connection_id,
                 ChatMessage::error(t("err-secret-no-channel")),
@@ -0,0 +1,77 @@
+//! /top command implementation - show the most active users
+
+use iced::Task;
+use nexus_common::protocol::{ClientMessage, LeaderboardStat};
+
+use crate::NexusApp;
+use crate::i18n::t_args;
+use crate::types::{ChatMessage, Message, PendingRequests, ResponseRouting};
+
+/// Execute the /top command
+///
+/// Requests the leaderboard for one activity counter (logins by default).
+///
+/// Usage: /top [logins|messages|uploads|downloaded]
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    let stat = match args {
+        [] => Some(LeaderboardStat::Logins),
+        [stat] => parse_stat(stat),
+        _ => None,
+    };
+    let Some(stat) = stat else {
+        let error_msg = t_args("cmd-top-usage", &[("command", invoked_name)]);
+        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+    };
+
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    let message_id = match conn.send(ClientMessage::Leaderboard { stat }) {
+        Ok(id) => id,
+        Err(e) => {
+            let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+            return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+        }
+    };
+
+    // Track the counter so the response shows the right column
+    if let Some(conn) = app.connections.get_mut(&connection_id) {
+        conn.pending_requests
+            .track(message_id, ResponseRouting::LeaderboardResult(stat));
+    }
+
+    Task::none()
+}
+
+/// Parse a counter name (case-insensitive)
+fn parse_stat(name: &str) -> Option<LeaderboardStat> {
+    match name.to_lowercase().as_str() {
+        "logins" => Some(LeaderboardStat::Logins),
+        "messages" => Some(LeaderboardStat::Messages),
+        "uploads" => Some(LeaderboardStat::Uploads),
+        "downloaded" => Some(LeaderboardStat::DownloadBytes),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_stat() {
+        assert_eq!(parse_stat("logins"), Some(LeaderboardStat::Logins));
+        assert_eq!(parse_stat("Uploads"), Some(LeaderboardStat::Uploads));
+        assert_eq!(
+            parse_stat("downloaded"),
+            Some(LeaderboardStat::DownloadBytes)
+        );
+        assert_eq!(parse_stat("posts"), None);
+    }
+}
@@ -7,7 +7,7 @@ use nexus_common::validators::{self, ChatTopicError};
 use crate::NexusApp;
 use crate::i18n::{t, t_args};
 use crate::types::{ChatMessage, ChatTab, Message};
-use crate::views::constants::PERMISSION_CHAT_TOPIC_EDIT;
+use crate::views::constants::{PERMISSION_CHAT_TOPIC_EDIT, PERMISSION_CHAT_TOPIC_LOCK};
 
 /// Execute the /topic command
 ///
@@ -15,6 +15,8 @@ use crate::views::constants::PERMISSION_CHAT_TOPIC_EDIT;
 /// - `/topic` - Show current topic (requires chat_topic permission)
 /// - `/topic set <topic>` - Set the topic (requires chat_topic_edit permission)
 /// - `/topic clear` - Clear the topic (requires chat_topic_edit permission)
+/// - `/topic lock` / `/topic unlock` - Lock or unlock the topic (requires chat_topic_lock permission)
+/// - `/topic history` - Show recent topic changes (requires chat_topic permission)
 pub fn execute(
     app: &mut NexusApp,
     connection_id: usize,
@@ -28,7 +30,7 @@ pub fn execute(
 
     let channel = match &conn.active_chat_tab {
         ChatTab::Channel(ch) => ch.clone(),
-        ChatTab::Console | ChatTab::UserMessage(_) => {
+        ChatTab::Console | ChatTab::UserMessage(_) | ChatTab::Group(_) => {
             return app.add_active_tab_message(
                 connection_id,
                 ChatMessage::error(t("err-topic-no-channel")),
@@ -44,6 +46,9 @@ pub fn execute(
     // Get translated subcommand keywords
     let set_keyword = t("cmd-topic-arg-set").to_lowercase();
     let clear_keyword = t("cmd-topic-arg-clear").to_lowercase();
+    let lock_keyword = t("cmd-topic-arg-lock").to_lowercase();
+    let unlock_keyword = t("cmd-topic-arg-unlock").to_lowercase();
+    let history_keyword = t("cmd-topic-arg-history").to_lowercase();
     let arg = args[0].to_lowercase();
 
     if arg == set_keyword {
@@ -94,6 +99,26 @@ pub fn execute(
         }
 
         set_topic(app, connection_id, &channel, String::new())
+    } else if arg == lock_keyword || arg == unlock_keyword {
+        // /topic lock and /topic unlock take no additional arguments
+        if args.len() > 1 {
+            let error_msg = t_args("cmd-topic-usage", &[("command", invoked_name)]);
+            return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+        }
+
+        lock_topic(app, connection_id, &channel, arg == lock_keyword)
+    } else if arg == history_keyword {
+        // /topic history takes no additional arguments
+        if args.len() > 1 {
+            let error_msg = t_args("cmd-topic-usage", &[("command", invoked_name)]);
+            return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+        }
+
+        send_message(
+            app,
+            connection_id,
+            ClientMessage::ChatTopicHistory { channel },
+        )
     } else {
         // Unknown subcommand - show usage
         let error_msg = t_args("cmd-topic-usage", &[("command", invoked_name)]);
@@ -150,14 +175,64 @@ fn set_topic(
     connection_id: usize,
     channel: &str,
     topic: String,
+) -> Task<Message> {
+    let msg = ClientMessage::ChatTopicUpdate {
+        topic,
+        channel: channel.to_string(),
+    };
+
+    send_message(app, connection_id, msg)
+}
+
+/// Lock or unlock the topic for a channel
+fn lock_topic(
+    app: &mut NexusApp,
+    connection_id: usize,
+    channel: &str,
+    locked: bool,
 ) -> Task<Message> {
     let Some(conn) = app.connections.get(&connection_id) else {
         return Task::none();
     };
 
-    let msg = ClientMessage::ChatTopicUpdate {
-        topic,
+    // Check chat_topic_lock permission
+    if !conn.is_admin
+        && !conn
+            .permissions
+            .iter()
+            .any(|p| p == PERMISSION_CHAT_TOPIC_LOCK)
+    {
+        return app.add_active_tab_message(
+            connection_id,
+            ChatMessage::error(t("cmd-topic-lock-permission-denied")),
+        );
+    }
+
+    // Don't send if no change
+    let currently_locked = conn
+        .get_channel_state(channel)
+        .is_some_and(|ch| ch.topic_locked);
+    if locked == currently_locked {
+        let message = if locked {
+            t("msg-topic-already-locked")
+        } else {
+            t("msg-topic-already-unlocked")
+        };
+        return app.add_active_tab_message(connection_id, ChatMessage::info(message));
+    }
+
+    let msg = ClientMessage::ChatTopicLock {
         channel: channel.to_string(),
+        locked,
+    };
+
+    send_message(app, connection_id, msg)
+}
+
+/// Send a topic request to the server, showing an error if sending fails
+fn send_message(app: &mut NexusApp, connection_id: usize, msg: ClientMessage) -> Task<Message> {
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
     };
 
     if let Err(e) = conn.send(msg) {
@@ -0,0 +1,72 @@
+//! /voicelimit command implementation - view or set a channel's voice participant limit
+
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+
+use crate::NexusApp;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, ChatTab, Message};
+
+/// Execute the /voicelimit command
+///
+/// View or set the voice participant limit on the current channel.
+/// Once the limit is reached, further voice joins wait in a queue.
+///
+/// Usage:
+///   /voicelimit        - Show the current limit
+///   /voicelimit <n>    - Allow at most n users in voice
+///   /voicelimit off    - Remove the limit (same as 0)
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    // /voicelimit only works on channel tabs, not console or PM
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    let channel = match &conn.active_chat_tab {
+        ChatTab::Channel(ch) => ch.clone(),
+        ChatTab::Console | ChatTab::UserMessage(_) | ChatTab::Group(_) => {
+            return app.add_active_tab_message(
+                connection_id,
+                ChatMessage::error(t("err-voicelimit-no-channel")),
+            );
+        }
+    };
+
+    // If no args, show current limit
+    if args.is_empty() {
+        let current_limit = conn
+            .get_channel_state(&channel)
+            .and_then(|ch| ch.voice_limit);
+        let message = match current_limit {
+            Some(limit) => t_args("msg-voice-limit-status", &[("limit", &limit.to_string())]),
+            None => t("msg-voice-limit-status-none"),
+        };
+        return app.add_active_tab_message(connection_id, ChatMessage::info(message));
+    }
+
+    // Parse the limit ("off" removes it)
+    let off_keyword = t("cmd-voicelimit-arg-off").to_lowercase();
+    let limit = if args.len() == 1 && args[0].to_lowercase() == off_keyword {
+        0
+    } else if let [arg] = args
+        && let Ok(limit) = arg.parse::<u32>()
+    {
+        limit
+    } else {
+        let error_msg = t_args("cmd-voicelimit-usage", &[("command", invoked_name)]);
+        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+    };
+
+    let msg = ClientMessage::VoiceLimit { channel, limit };
+    if let Err(e) = conn.send(msg) {
+        let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    Task::none()
+}
@@ -0,0 +1,63 @@
+//! /vt command implementation - text messages to the current voice session
+
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+use nexus_common::validators::{self, VoiceTextError};
+
+use crate::NexusApp;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message};
+
+/// Execute the /vt command
+///
+/// Sends a short text message to everyone in our voice session. It shows
+/// in their voice overlay and can be read aloud by text-to-speech.
+///
+/// Usage: /vt <message>
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    if args.is_empty() {
+        let error_msg = t_args("cmd-vt-usage", &[("command", invoked_name)]);
+        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    let message = args.join(" ");
+
+    if let Err(e) = validators::validate_voice_text(&message) {
+        let error_msg = match e {
+            VoiceTextError::Empty => t("err-message-empty"),
+            VoiceTextError::TooLong => t_args(
+                "err-message-too-long",
+                &[
+                    ("length", &message.chars().count().to_string()),
+                    ("max", &validators::MAX_VOICE_TEXT_LENGTH.to_string()),
+                ],
+            ),
+            VoiceTextError::ContainsNewlines => t("err-message-contains-newlines"),
+            VoiceTextError::InvalidCharacters => t("err-message-invalid-characters"),
+        };
+        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    let Some(conn) = app.connections.get(&connection_id) else {
+        return Task::none();
+    };
+
+    if conn.voice_session.is_none() {
+        return app.add_active_tab_message(
+            connection_id,
+            ChatMessage::error(t("err-voice-not-in-session")),
+        );
+    }
+
+    if let Err(e) = conn.send(ClientMessage::VoiceTextSend { message }) {
+        let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    Task::none()
+}
@@ -0,0 +1,77 @@
+//! /welcome command implementation - view or set the channel entry message
+
+use iced::Task;
+use nexus_common::validators::{self, ChatEntryError};
+
+use super::entry::{active_channel, send_entry_update};
+use crate::NexusApp;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message};
+
+/// Execute the /welcome command
+///
+/// Subcommands:
+/// - `/welcome` - Show the current entry message
+/// - `/welcome set <message>` - Set the message shown to users joining the channel
+/// - `/welcome clear` - Clear the entry message
+///
+/// Requires chat_entry_edit permission.
+pub fn execute(
+    app: &mut NexusApp,
+    connection_id: usize,
+    invoked_name: &str,
+    args: &[String],
+) -> Task<Message> {
+    let channel = match active_channel(app, connection_id) {
+        Ok(channel) => channel,
+        Err(task) => return task,
+    };
+
+    if args.is_empty() {
+        let entry_message = app
+            .connections
+            .get(&connection_id)
+            .and_then(|conn| conn.get_channel_state(&channel))
+            .and_then(|ch| ch.entry_message.clone());
+        let message = match entry_message {
+            Some(entry_message) => {
+                t_args("msg-entry-message-display", &[("message", &entry_message)])
+            }
+            None => t("cmd-welcome-none"),
+        };
+        return app.add_active_tab_message(connection_id, ChatMessage::info(message));
+    }
+
+    // Get translated subcommand keywords
+    let set_keyword = t("cmd-welcome-arg-set").to_lowercase();
+    let clear_keyword = t("cmd-welcome-arg-clear").to_lowercase();
+    let arg = args[0].to_lowercase();
+
+    let entry_message = if arg == set_keyword && args.len() > 1 {
+        args[1..].join(" ")
+    } else if arg == clear_keyword && args.len() == 1 {
+        String::new()
+    } else {
+        let error_msg = t_args("cmd-welcome-usage", &[("command", invoked_name)]);
+        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+    };
+
+    if let Err(e) = validators::validate_chat_entry_message(&entry_message) {
+        let error_msg = match e {
+            ChatEntryError::TooLong => t_args(
+                "err-entry-message-too-long",
+                &[
+                    ("length", &entry_message.len().to_string()),
+                    (
+                        "max",
+                        &validators::MAX_CHAT_ENTRY_MESSAGE_LENGTH.to_string(),
+                    ),
+                ],
+            ),
+            ChatEntryError::InvalidCharacters => t("err-message-invalid-characters"),
+        };
+        return app.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+    }
+
+    send_entry_update(app, connection_id, channel, Some(entry_message), None)
+}
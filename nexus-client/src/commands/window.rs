@@ -93,7 +93,7 @@ fn list_tabs(app: &mut NexusApp, connection_id: usize) -> Task<Message> {
         return Task::none();
     };
 
-    // Build tab list: Console + Channels (join order) + User messages (creation order)
+    // Build tab list: Console + Channels (join order) + User messages and groups (creation order)
     let mut tabs = vec![t("console-tab")];
 
     // Add channel tabs in join order
@@ -106,6 +106,13 @@ fn list_tabs(app: &mut NexusApp, connection_id: usize) -> Task<Message> {
         tabs.push(nickname.clone());
     }
 
+    // Add group conversation tabs in creation order
+    for group_id in &conn.group_tabs {
+        if let Some(group) = conn.groups.get(group_id) {
+            tabs.push(group.label(&conn.nickname));
+        }
+    }
+
     let tab_list = tabs.join(", ");
     let message = t_args(
         "cmd-window-list",
@@ -115,7 +122,7 @@ fn list_tabs(app: &mut NexusApp, connection_id: usize) -> Task<Message> {
     app.add_active_tab_message(connection_id, ChatMessage::info(message))
 }
 
-/// Close the current tab (channel or PM)
+/// Close the current tab (channel, PM, or group)
 fn close_current_tab(app: &mut NexusApp, connection_id: usize) -> Task<Message> {
     let Some(conn) = app.connections.get(&connection_id) else {
         return Task::none();
@@ -139,5 +146,6 @@ fn close_current_tab(app: &mut NexusApp, connection_id: usize) -> Task<Message>
         ChatTab::UserMessage(nickname) => {
             Task::done(Message::CloseUserMessageTab(nickname.clone()))
         }
+        ChatTab::Group(group_id) => Task::done(Message::CloseGroupTab(*group_id)),
     }
 }
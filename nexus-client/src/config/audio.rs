@@ -56,6 +56,9 @@ pub const DEFAULT_PTT_KEY: &str = "`";
 /// System default device identifier
 pub const SYSTEM_DEFAULT_DEVICE: &str = "";
 
+/// Default soundboard clip playback volume
+pub const DEFAULT_CLIP_VOLUME: f32 = 0.8;
+
 // =============================================================================
 // PTT Mode
 // =============================================================================
@@ -316,12 +319,24 @@ pub struct AudioSettings {
     /// Pre-gain applied before all processing for quiet microphones
     #[serde(default)]
     pub mic_boost: MicBoost,
+
+    /// Soundboard clip playback volume (0.0 - 1.0, default: 0.8)
+    #[serde(default = "default_clip_volume")]
+    pub clip_volume: f32,
+
+    /// Read voice text messages aloud (default: false)
+    #[serde(default)]
+    pub voice_text_tts: bool,
 }
 
 fn default_true() -> bool {
     true
 }
 
+fn default_clip_volume() -> f32 {
+    DEFAULT_CLIP_VOLUME
+}
+
 impl Default for AudioSettings {
     fn default() -> Self {
         Self {
@@ -337,6 +352,8 @@ impl Default for AudioSettings {
             agc: true,
             transient_suppression: false,
             mic_boost: MicBoost::default(),
+            clip_volume: DEFAULT_CLIP_VOLUME,
+            voice_text_tts: false,
         }
     }
 }
@@ -385,6 +402,8 @@ mod tests {
         assert!(settings.agc);
         assert!(!settings.transient_suppression);
         assert_eq!(settings.mic_boost, MicBoost::Off);
+        assert_eq!(settings.clip_volume, DEFAULT_CLIP_VOLUME);
+        assert!(!settings.voice_text_tts);
     }
 
     #[test]
@@ -402,6 +421,8 @@ mod tests {
             agc: false,
             transient_suppression: true,
             mic_boost: MicBoost::Plus12dB,
+            clip_volume: 0.5,
+            voice_text_tts: true,
         };
 
         let json = serde_json::to_string(&settings).expect("serialize");
@@ -425,6 +446,8 @@ mod tests {
             deserialized.transient_suppression
         );
         assert_eq!(settings.mic_boost, deserialized.mic_boost);
+        assert_eq!(settings.clip_volume, deserialized.clip_volume);
+        assert_eq!(settings.voice_text_tts, deserialized.voice_text_tts);
     }
 
     #[test]
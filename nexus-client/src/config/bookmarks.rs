@@ -2,7 +2,7 @@
 
 use uuid::Uuid;
 
-use crate::types::ServerBookmark;
+use crate::types::{BookmarkDropTarget, BookmarkGroup, ServerBookmark};
 
 use super::Config;
 
@@ -41,6 +41,82 @@ impl Config {
             *existing = bookmark;
         }
     }
+
+    /// Sort bookmarks by name (case-insensitive)
+    pub fn sort_bookmarks_by_name(&mut self) {
+        self.bookmarks.sort_by_key(|b| b.name.to_lowercase());
+    }
+
+    /// Move a bookmark to a new place in the sidebar
+    ///
+    /// The bookmark joins the folder of the place it's dropped. Does nothing
+    /// if either bookmark doesn't exist.
+    pub fn move_bookmark(&mut self, id: Uuid, target: BookmarkDropTarget) {
+        if target == BookmarkDropTarget::Before(id) {
+            return;
+        }
+        let Some(index) = self.bookmarks.iter().position(|b| b.id == id) else {
+            return;
+        };
+
+        let mut bookmark = self.bookmarks.remove(index);
+        let (group, position) = match target {
+            BookmarkDropTarget::Before(other) => {
+                match self.bookmarks.iter().position(|b| b.id == other) {
+                    Some(position) => (self.bookmarks[position].group, position),
+                    None => {
+                        self.bookmarks.insert(index, bookmark);
+                        return;
+                    }
+                }
+            }
+            BookmarkDropTarget::End(group) => {
+                let position = self
+                    .bookmarks
+                    .iter()
+                    .rposition(|b| b.group == group)
+                    .map_or(self.bookmarks.len(), |last| last + 1);
+                (group, position)
+            }
+        };
+        bookmark.group = group;
+        self.bookmarks.insert(position, bookmark);
+    }
+
+    /// Bookmarks in a folder (None for bookmarks outside any folder), in order
+    pub fn bookmarks_in_group(&self, group: Option<Uuid>) -> impl Iterator<Item = &ServerBookmark> {
+        self.bookmarks.iter().filter(move |b| b.group == group)
+    }
+
+    /// Add a bookmark folder at the end of the list and return its ID
+    pub fn add_bookmark_group(&mut self, name: String) -> Uuid {
+        let id = Uuid::new_v4();
+        self.bookmark_groups.push(BookmarkGroup {
+            id,
+            name,
+            collapsed: false,
+        });
+        id
+    }
+
+    /// Get a mutable bookmark folder by ID
+    ///
+    /// Returns None if no folder with the given ID exists.
+    pub fn get_bookmark_group_mut(&mut self, id: Uuid) -> Option<&mut BookmarkGroup> {
+        self.bookmark_groups.iter_mut().find(|g| g.id == id)
+    }
+
+    /// Delete a bookmark folder by ID
+    ///
+    /// Its bookmarks are kept and move out of the folder.
+    pub fn delete_bookmark_group(&mut self, id: Uuid) {
+        self.bookmark_groups.retain(|g| g.id != id);
+        for bookmark in &mut self.bookmarks {
+            if bookmark.group == Some(id) {
+                bookmark.group = None;
+            }
+        }
+    }
 }
 
 // =============================================================================
@@ -161,4 +237,70 @@ mod tests {
         assert_eq!(config.bookmarks.len(), 1);
         assert_eq!(config.bookmarks[0].name, "Server 1");
     }
+
+    /// Helper to list bookmark names in a folder
+    fn names_in_group(config: &Config, group: Option<Uuid>) -> Vec<&str> {
+        config
+            .bookmarks_in_group(group)
+            .map(|b| b.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_move_bookmark() {
+        let mut config = Config::default();
+        let folder = config.add_bookmark_group("Work".to_string());
+        for name in ["A", "B", "C"] {
+            config.add_bookmark(bookmark(name));
+        }
+        let [a, b, c] = [0, 1, 2].map(|i| config.bookmarks[i].id);
+
+        // Above another bookmark
+        config.move_bookmark(c, BookmarkDropTarget::Before(a));
+        assert_eq!(names_in_group(&config, None), ["C", "A", "B"]);
+
+        // Into an empty folder, then above a bookmark in that folder
+        config.move_bookmark(a, BookmarkDropTarget::End(Some(folder)));
+        config.move_bookmark(b, BookmarkDropTarget::Before(a));
+        assert_eq!(names_in_group(&config, None), ["C"]);
+        assert_eq!(names_in_group(&config, Some(folder)), ["B", "A"]);
+
+        // Back out to the end of the bookmarks outside any folder
+        config.move_bookmark(b, BookmarkDropTarget::End(None));
+        assert_eq!(names_in_group(&config, None), ["C", "B"]);
+        assert_eq!(names_in_group(&config, Some(folder)), ["A"]);
+
+        // Dropping on itself or an unknown bookmark changes nothing
+        let before = names_in_group(&config, None).join(",");
+        config.move_bookmark(c, BookmarkDropTarget::Before(c));
+        config.move_bookmark(c, BookmarkDropTarget::Before(Uuid::new_v4()));
+        assert_eq!(names_in_group(&config, None).join(","), before);
+    }
+
+    #[test]
+    fn test_delete_bookmark_group_keeps_bookmarks() {
+        let mut config = Config::default();
+        let folder = config.add_bookmark_group("Work".to_string());
+        config.add_bookmark(ServerBookmark {
+            group: Some(folder),
+            ..bookmark("A")
+        });
+
+        config.delete_bookmark_group(folder);
+
+        assert!(config.bookmark_groups.is_empty());
+        assert_eq!(names_in_group(&config, None), ["A"]);
+    }
+
+    #[test]
+    fn test_sort_bookmarks_by_name() {
+        let mut config = Config::default();
+        for name in ["beta", "Alpha", "gamma"] {
+            config.add_bookmark(bookmark(name));
+        }
+
+        config.sort_bookmarks_by_name();
+
+        assert_eq!(names_in_group(&config, None), ["Alpha", "beta", "gamma"]);
+    }
 }
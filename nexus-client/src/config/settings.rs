@@ -1,9 +1,12 @@
 //! User preference settings
 
+use std::collections::BTreeMap;
+use std::time::Duration;
+
 use crate::style::{WINDOW_HEIGHT, WINDOW_WIDTH};
 
 use super::audio::AudioSettings;
-use super::events::{EventSettings, EventType};
+use super::events::{EventSettings, EventType, QuietHours};
 use super::theme::ThemePreference;
 
 // =============================================================================
@@ -139,6 +142,270 @@ impl std::fmt::Display for ChatHistoryRetention {
     }
 }
 
+// =============================================================================
+// File Collision Policy
+// =============================================================================
+
+/// What to do when a download's destination file already exists with different content
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+pub enum CollisionPolicy {
+    /// Save under a new name, e.g. "file (1).txt"
+    #[default]
+    Rename,
+    /// Replace the existing file
+    Overwrite,
+    /// Keep the existing file and skip the download
+    Skip,
+    /// Pause the transfer and ask what to do
+    Ask,
+}
+
+impl CollisionPolicy {
+    /// All collision policies for the picker
+    pub const ALL: &'static [CollisionPolicy] = &[
+        CollisionPolicy::Rename,
+        CollisionPolicy::Overwrite,
+        CollisionPolicy::Skip,
+        CollisionPolicy::Ask,
+    ];
+
+    /// Get the translation key for this policy
+    pub fn translation_key(&self) -> &'static str {
+        match self {
+            CollisionPolicy::Rename => "collision-policy-rename",
+            CollisionPolicy::Overwrite => "collision-policy-overwrite",
+            CollisionPolicy::Skip => "collision-policy-skip",
+            CollisionPolicy::Ask => "collision-policy-ask",
+        }
+    }
+}
+
+impl std::fmt::Display for CollisionPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", crate::i18n::t(self.translation_key()))
+    }
+}
+
+// =============================================================================
+// User List
+// =============================================================================
+
+/// How the user list is divided into sections
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+pub enum UserListGrouping {
+    /// One list, no section headers
+    #[default]
+    None,
+    /// Admins, then everyone else
+    AdminsFirst,
+    /// By the channel (of the ones you've joined) each user is in
+    ByChannel,
+    /// Online, away, and do not disturb
+    ByStatus,
+}
+
+impl UserListGrouping {
+    /// All grouping options for the picker
+    pub const ALL: &'static [UserListGrouping] = &[
+        UserListGrouping::None,
+        UserListGrouping::AdminsFirst,
+        UserListGrouping::ByChannel,
+        UserListGrouping::ByStatus,
+    ];
+
+    /// Get the translation key for this grouping
+    pub fn translation_key(&self) -> &'static str {
+        match self {
+            UserListGrouping::None => "user-list-group-none",
+            UserListGrouping::AdminsFirst => "user-list-group-admins",
+            UserListGrouping::ByChannel => "user-list-group-channel",
+            UserListGrouping::ByStatus => "user-list-group-status",
+        }
+    }
+}
+
+impl std::fmt::Display for UserListGrouping {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", crate::i18n::t(self.translation_key()))
+    }
+}
+
+/// How users are ordered within each user list section
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+pub enum UserListSort {
+    /// By nickname (case-insensitive)
+    #[default]
+    Alphabetical,
+    /// Most recently active first
+    IdleTime,
+    /// Longest connected first
+    ConnectTime,
+}
+
+impl UserListSort {
+    /// All sort modes for the picker
+    pub const ALL: &'static [UserListSort] = &[
+        UserListSort::Alphabetical,
+        UserListSort::IdleTime,
+        UserListSort::ConnectTime,
+    ];
+
+    /// Get the translation key for this sort mode
+    pub fn translation_key(&self) -> &'static str {
+        match self {
+            UserListSort::Alphabetical => "user-list-sort-alphabetical",
+            UserListSort::IdleTime => "user-list-sort-idle",
+            UserListSort::ConnectTime => "user-list-sort-connect",
+        }
+    }
+}
+
+impl std::fmt::Display for UserListSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", crate::i18n::t(self.translation_key()))
+    }
+}
+
+/// User list grouping and sort order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+pub struct UserListSettings {
+    /// How the list is divided into sections
+    #[serde(default)]
+    pub grouping: UserListGrouping,
+    /// How users are ordered within each section
+    #[serde(default)]
+    pub sort: UserListSort,
+}
+
+// =============================================================================
+// Auto Away
+// =============================================================================
+
+/// Default idle time before going away automatically (minutes)
+pub const DEFAULT_AUTO_AWAY_MINUTES: u16 = 10;
+
+/// Minimum idle time before going away automatically (minutes)
+pub const AUTO_AWAY_MINUTES_MIN: u16 = 1;
+
+/// Maximum idle time before going away automatically (minutes)
+pub const AUTO_AWAY_MINUTES_MAX: u16 = 240;
+
+/// Automatic away status after a period without keyboard or mouse input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AutoAway {
+    /// Whether to go away automatically
+    #[serde(default)]
+    pub enabled: bool,
+    /// Minutes without input before going away
+    #[serde(default = "default_auto_away_minutes")]
+    pub minutes: u16,
+}
+
+impl Default for AutoAway {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            minutes: DEFAULT_AUTO_AWAY_MINUTES,
+        }
+    }
+}
+
+impl AutoAway {
+    /// Idle time after which the user goes away
+    pub fn threshold(&self) -> Duration {
+        let minutes = self
+            .minutes
+            .clamp(AUTO_AWAY_MINUTES_MIN, AUTO_AWAY_MINUTES_MAX);
+        Duration::from_secs(u64::from(minutes) * 60)
+    }
+}
+
+fn default_auto_away_minutes() -> u16 {
+    DEFAULT_AUTO_AWAY_MINUTES
+}
+
+// =============================================================================
+// First-run Tips
+// =============================================================================
+
+/// A first-run tip, shown until the user dismisses it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum Tip {
+    /// Welcome card above the connection form
+    Welcome,
+    /// Explanation in the certificate fingerprint dialog
+    Fingerprint,
+    /// Server list panel (after the first connection)
+    ServerList,
+    /// Toolbar (after the first connection)
+    Toolbar,
+    /// Chat (after the first connection)
+    Chat,
+    /// User list panel (after the first connection)
+    UserList,
+}
+
+impl Tip {
+    /// All tips, in the order they're shown
+    pub const ALL: &[Tip] = &[
+        Tip::Welcome,
+        Tip::Fingerprint,
+        Tip::ServerList,
+        Tip::Toolbar,
+        Tip::Chat,
+        Tip::UserList,
+    ];
+
+    /// Tips that highlight a panel of the main window once connected
+    pub const PANEL_TIPS: &[Tip] = &[Tip::ServerList, Tip::Toolbar, Tip::Chat, Tip::UserList];
+
+    /// Translation key for the tip's text
+    pub fn translation_key(&self) -> &'static str {
+        match self {
+            Tip::Welcome => "tip-welcome",
+            Tip::Fingerprint => "tip-fingerprint",
+            Tip::ServerList => "tip-server-list",
+            Tip::Toolbar => "tip-toolbar",
+            Tip::Chat => "tip-chat",
+            Tip::UserList => "tip-user-list",
+        }
+    }
+
+    /// First panel tip in `pending`, out of those `visible` allows
+    ///
+    /// Tips for hidden panels wait until the panel is shown.
+    pub fn current_panel_tip(pending: &[Tip], visible: impl Fn(Tip) -> bool) -> Option<Tip> {
+        Self::PANEL_TIPS
+            .iter()
+            .copied()
+            .find(|tip| pending.contains(tip) && visible(*tip))
+    }
+
+    /// Position of a panel tip in the tour (1-based), for "Tip 2 of 4"
+    pub fn panel_position(&self) -> Option<usize> {
+        Self::PANEL_TIPS
+            .iter()
+            .position(|tip| tip == self)
+            .map(|index| index + 1)
+    }
+}
+
+// =============================================================================
+// Split View
+// =============================================================================
+
+/// Default share of the split view given to chat (0.0 - 1.0)
+pub const SPLIT_RATIO_DEFAULT: f32 = 0.5;
+
+/// Smallest share of the split view either side can be dragged to
+pub const SPLIT_RATIO_MIN: f32 = 0.2;
+
+/// Largest share of the split view either side can be dragged to
+pub const SPLIT_RATIO_MAX: f32 = 0.8;
+
+/// Window widths are rounded to this step (pixels) when remembering the split
+pub const SPLIT_RATIO_WIDTH_STEP: f32 = 100.0;
+
 // =============================================================================
 // Constants
 // =============================================================================
@@ -275,6 +542,21 @@ pub struct Settings {
     #[serde(default = "default_upload_limit")]
     pub upload_limit: u8,
 
+    /// Open the containing folder automatically when a download completes
+    #[serde(default)]
+    pub auto_open_folder: bool,
+
+    /// What to do when a download's destination already exists
+    #[serde(default)]
+    pub collision_policy: CollisionPolicy,
+
+    /// Ask before trusting a server's certificate for the first time
+    ///
+    /// When off, the first certificate a bookmarked server presents is
+    /// trusted automatically (Trust On First Use).
+    #[serde(default)]
+    pub require_fingerprint_approval: bool,
+
     /// Event notification settings
     #[serde(default)]
     pub event_settings: EventSettings,
@@ -283,10 +565,38 @@ pub struct Settings {
     #[serde(default)]
     pub selected_event_type: EventType,
 
+    /// Do not disturb (suppresses notifications, toasts, and sounds)
+    #[serde(default)]
+    pub do_not_disturb: bool,
+
+    /// Scheduled do not disturb window
+    #[serde(default)]
+    pub quiet_hours: QuietHours,
+
     /// Maximum scrollback lines per chat tab (0 = unlimited)
     #[serde(default = "default_max_scrollback")]
     pub max_scrollback: usize,
 
+    /// Show the Files, News and Connection Monitor panels beside chat
+    #[serde(default)]
+    pub split_view: bool,
+
+    /// Mark misspelled words in the chat input and news editor
+    #[serde(default = "default_true")]
+    pub spell_check: bool,
+
+    /// Tell senders of user messages when we've read them
+    #[serde(default = "default_true")]
+    pub send_read_receipts: bool,
+
+    /// Chat's share of the split view, by window width (rounded to 100 pixels)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub split_ratios: BTreeMap<u32, f32>,
+
+    /// User-defined command aliases, by name without the `/` (see `/alias`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub command_aliases: BTreeMap<String, String>,
+
     /// Chat history retention policy for user message conversations
     #[serde(default)]
     pub chat_history_retention: ChatHistoryRetention,
@@ -302,6 +612,30 @@ pub struct Settings {
     /// Minimize to tray instead of closing (Windows/Linux only)
     #[serde(default)]
     pub minimize_to_tray: bool,
+
+    /// Global hotkey that shows or hides the window (None = disabled)
+    #[serde(default)]
+    pub toggle_window_key: Option<String>,
+
+    /// Go away automatically after a period without input
+    #[serde(default)]
+    pub auto_away: AutoAway,
+
+    /// User list grouping and sort order
+    #[serde(default)]
+    pub user_list: UserListSettings,
+
+    /// User chose to keep bookmark passwords in the config file instead of
+    /// creating a password vault (only asked when no keychain is available)
+    #[serde(default)]
+    pub password_vault_declined: bool,
+
+    /// First-run tips the user hasn't dismissed yet
+    ///
+    /// A new config starts with every tip; older configs predate the tips and
+    /// start with none, so existing users aren't walked through the tour.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pending_tips: Vec<Tip>,
 }
 
 /// Default value for max_scrollback setting
@@ -334,17 +668,72 @@ impl Default for Settings {
             queue_transfers: default_queue_transfers(),
             download_limit: default_download_limit(),
             upload_limit: default_upload_limit(),
+            auto_open_folder: false,
+            collision_policy: CollisionPolicy::default(),
+            require_fingerprint_approval: false,
             event_settings: EventSettings::default(),
             selected_event_type: EventType::default(),
+            do_not_disturb: false,
+            quiet_hours: QuietHours::default(),
             max_scrollback: default_max_scrollback(),
+            split_view: false,
+            spell_check: default_true(),
+            send_read_receipts: default_true(),
+            split_ratios: BTreeMap::new(),
+            command_aliases: BTreeMap::new(),
             chat_history_retention: ChatHistoryRetention::default(),
             audio: AudioSettings::default(),
             show_tray_icon: false,
             minimize_to_tray: false,
+            toggle_window_key: None,
+            auto_away: AutoAway::default(),
+            user_list: UserListSettings::default(),
+            password_vault_declined: false,
+            pending_tips: Vec::new(),
         }
     }
 }
 
+impl Settings {
+    /// Chat's share of the split view for a window width
+    ///
+    /// Falls back to the ratio saved for the nearest width, so resizing the
+    /// window doesn't reset the divider.
+    pub fn split_ratio(&self, window_width: f32) -> f32 {
+        let width = split_width_key(window_width);
+        self.split_ratios
+            .iter()
+            .min_by_key(|(saved, _)| saved.abs_diff(width))
+            .map(|(_, ratio)| ratio.clamp(SPLIT_RATIO_MIN, SPLIT_RATIO_MAX))
+            .unwrap_or(SPLIT_RATIO_DEFAULT)
+    }
+
+    /// Whether a first-run tip is still waiting to be shown
+    pub fn tip_pending(&self, tip: Tip) -> bool {
+        self.pending_tips.contains(&tip)
+    }
+
+    /// Mark a first-run tip as seen, returning whether it was pending
+    pub fn dismiss_tip(&mut self, tip: Tip) -> bool {
+        let before = self.pending_tips.len();
+        self.pending_tips.retain(|&pending| pending != tip);
+        self.pending_tips.len() != before
+    }
+
+    /// Remember chat's share of the split view for a window width
+    pub fn set_split_ratio(&mut self, window_width: f32, ratio: f32) {
+        self.split_ratios.insert(
+            split_width_key(window_width),
+            ratio.clamp(SPLIT_RATIO_MIN, SPLIT_RATIO_MAX),
+        );
+    }
+}
+
+/// Round a window width to the step split ratios are saved at
+fn split_width_key(window_width: f32) -> u32 {
+    ((window_width / SPLIT_RATIO_WIDTH_STEP).round() * SPLIT_RATIO_WIDTH_STEP) as u32
+}
+
 // =============================================================================
 // Default Functions (for serde)
 // =============================================================================
@@ -369,6 +758,11 @@ impl std::fmt::Debug for Settings {
             .field("nickname", &self.nickname)
             .field("proxy", &self.proxy)
             .field("max_scrollback", &self.max_scrollback)
+            .field("split_view", &self.split_view)
+            .field("spell_check", &self.spell_check)
+            .field("send_read_receipts", &self.send_read_receipts)
+            .field("split_ratios", &self.split_ratios)
+            .field("command_aliases", &self.command_aliases)
             .field("chat_history_retention", &self.chat_history_retention)
             .field("audio", &self.audio)
             .finish()
@@ -441,6 +835,78 @@ mod tests {
         assert!(!settings.queue_transfers);
         assert_eq!(settings.download_limit, DEFAULT_DOWNLOAD_LIMIT);
         assert_eq!(settings.upload_limit, DEFAULT_UPLOAD_LIMIT);
+        assert!(!settings.auto_open_folder);
+        assert_eq!(settings.collision_policy, CollisionPolicy::Rename);
+        assert!(!settings.require_fingerprint_approval);
+        assert!(settings.toggle_window_key.is_none());
+        assert_eq!(settings.auto_away, AutoAway::default());
+        assert!(!settings.split_view);
+        assert!(settings.spell_check);
+        assert!(settings.send_read_receipts);
+        assert!(settings.split_ratios.is_empty());
+        assert_eq!(settings.user_list.grouping, UserListGrouping::None);
+        assert_eq!(settings.user_list.sort, UserListSort::Alphabetical);
+    }
+
+    #[test]
+    fn test_user_list_settings_serialization() {
+        let user_list = UserListSettings {
+            grouping: UserListGrouping::ByStatus,
+            sort: UserListSort::IdleTime,
+        };
+        let json = serde_json::to_string(&user_list).expect("serialize");
+        let deserialized: UserListSettings = serde_json::from_str(&json).expect("deserialize");
+        assert_eq!(deserialized, user_list);
+
+        let partial: UserListSettings =
+            serde_json::from_str(r#"{"grouping":"AdminsFirst"}"#).expect("deserialize");
+        assert_eq!(partial.grouping, UserListGrouping::AdminsFirst);
+        assert_eq!(partial.sort, UserListSort::Alphabetical);
+    }
+
+    #[test]
+    fn test_split_ratio_per_window_width() {
+        let mut settings = Settings::default();
+        assert_eq!(settings.split_ratio(1200.0), SPLIT_RATIO_DEFAULT);
+
+        settings.set_split_ratio(1190.0, 0.6);
+        settings.set_split_ratio(1840.0, 0.35);
+        assert_eq!(
+            settings.split_ratios,
+            BTreeMap::from([(1200, 0.6), (1800, 0.35)])
+        );
+
+        // Nearby widths share a ratio; other widths use the nearest saved one
+        assert_eq!(settings.split_ratio(1230.0), 0.6);
+        assert_eq!(settings.split_ratio(900.0), 0.6);
+        assert_eq!(settings.split_ratio(2400.0), 0.35);
+
+        // Ratios are kept within the draggable range
+        settings.set_split_ratio(1200.0, 0.99);
+        assert_eq!(settings.split_ratio(1200.0), SPLIT_RATIO_MAX);
+
+        let json = serde_json::to_string(&settings).expect("serialize");
+        let deserialized: Settings = serde_json::from_str(&json).expect("deserialize");
+        assert_eq!(deserialized.split_ratios, settings.split_ratios);
+    }
+
+    #[test]
+    fn test_auto_away_threshold() {
+        let auto_away = AutoAway {
+            enabled: true,
+            minutes: 5,
+        };
+        assert_eq!(auto_away.threshold(), Duration::from_secs(300));
+
+        // Out-of-range values from a hand-edited config are clamped
+        let too_short = AutoAway {
+            enabled: true,
+            minutes: 0,
+        };
+        assert_eq!(too_short.threshold(), Duration::from_secs(60));
+
+        let partial: AutoAway = serde_json::from_str(r#"{"enabled":true}"#).expect("deserialize");
+        assert_eq!(partial.minutes, DEFAULT_AUTO_AWAY_MINUTES);
     }
 
     #[test]
@@ -486,4 +952,41 @@ mod tests {
 
         assert_eq!(settings.avatar, deserialized.avatar);
     }
+
+    #[test]
+    fn test_older_settings_have_no_pending_tips() {
+        let settings: Settings = serde_json::from_str("{}").expect("deserialize");
+        assert!(settings.pending_tips.is_empty());
+        assert_eq!(
+            Tip::current_panel_tip(&settings.pending_tips, |_| true),
+            None
+        );
+    }
+
+    #[test]
+    fn test_panel_tips_in_order() {
+        let mut settings = Settings {
+            pending_tips: Tip::ALL.to_vec(),
+            ..Default::default()
+        };
+
+        let current =
+            |settings: &Settings| Tip::current_panel_tip(&settings.pending_tips, |_| true);
+        assert_eq!(current(&settings), Some(Tip::ServerList));
+        assert!(settings.dismiss_tip(Tip::ServerList));
+        assert!(!settings.dismiss_tip(Tip::ServerList));
+        assert_eq!(current(&settings), Some(Tip::Toolbar));
+
+        // Tips for hidden panels are held back
+        assert_eq!(
+            Tip::current_panel_tip(&settings.pending_tips, |tip| tip != Tip::Toolbar),
+            Some(Tip::Chat)
+        );
+        assert_eq!(Tip::Chat.panel_position(), Some(3));
+        assert_eq!(Tip::Welcome.panel_position(), None);
+
+        // The welcome and fingerprint tips aren't part of the panel tour
+        settings.pending_tips = vec![Tip::Welcome, Tip::Fingerprint];
+        assert_eq!(current(&settings), None);
+    }
 }
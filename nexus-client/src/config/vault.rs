@@ -0,0 +1,264 @@
+//! Password vault (encrypted fallback store for bookmark passwords)
+//!
+//! Used when no OS keychain is available. Passwords are kept in a single
+//! file next to the config, encrypted with ChaCha20-Poly1305 under a key
+//! derived from the user's master passphrase with Argon2id.
+//!
+//! File format (JSON):
+//!
+//! ```json
+//! { "version": 1, "salt": "<hex>", "data": "<hex nonce + ciphertext>" }
+//! ```
+//!
+//! The plaintext is a JSON object mapping bookmark IDs to passwords.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use argon2::Argon2;
+use chacha20poly1305::{
+    ChaCha20Poly1305, KeyInit, Nonce,
+    aead::{Aead, OsRng, rand_core::RngCore},
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Current vault file format version
+const VAULT_VERSION: u32 = 1;
+
+/// Salt size for Argon2 key derivation
+pub(super) const SALT_SIZE: usize = 16;
+
+/// Nonce size for ChaCha20-Poly1305 (96 bits / 12 bytes)
+pub(super) const NONCE_SIZE: usize = 12;
+
+/// File permissions for the vault file on Unix (owner read/write only)
+#[cfg(unix)]
+const VAULT_FILE_MODE: u32 = 0o600;
+
+/// Vault file contents as stored on disk
+#[derive(Serialize, Deserialize)]
+struct VaultFile {
+    version: u32,
+    salt: String,
+    data: String,
+}
+
+/// Unlocked password vault
+pub struct Vault {
+    path: PathBuf,
+    salt: [u8; SALT_SIZE],
+    cipher: ChaCha20Poly1305,
+    passwords: HashMap<Uuid, String>,
+}
+
+impl Vault {
+    /// Create a new, empty vault protected by `passphrase`
+    ///
+    /// Nothing is written until [`Vault::save`] is called.
+    pub fn create(path: &Path, passphrase: &str) -> Result<Self, VaultError> {
+        let mut salt = [0u8; SALT_SIZE];
+        OsRng.fill_bytes(&mut salt);
+        Ok(Self {
+            path: path.to_path_buf(),
+            salt,
+            cipher: derive_cipher(passphrase, &salt)?,
+            passwords: HashMap::new(),
+        })
+    }
+
+    /// Open and decrypt an existing vault
+    pub fn open(path: &Path, passphrase: &str) -> Result<Self, VaultError> {
+        let contents = fs::read_to_string(path).map_err(|e| VaultError::Io(e.to_string()))?;
+        let file: VaultFile = serde_json::from_str(&contents).map_err(|_| VaultError::Corrupt)?;
+        if file.version != VAULT_VERSION {
+            return Err(VaultError::Corrupt);
+        }
+
+        let salt: [u8; SALT_SIZE] = hex::decode(&file.salt)
+            .ok()
+            .and_then(|salt| salt.try_into().ok())
+            .ok_or(VaultError::Corrupt)?;
+        let data = hex::decode(&file.data).map_err(|_| VaultError::Corrupt)?;
+        if data.len() < NONCE_SIZE {
+            return Err(VaultError::Corrupt);
+        }
+
+        let cipher = derive_cipher(passphrase, &salt)?;
+        let (nonce, ciphertext) = data.split_at(NONCE_SIZE);
+        let plaintext = cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| VaultError::WrongPassphrase)?;
+        let passwords = serde_json::from_slice(&plaintext).map_err(|_| VaultError::Corrupt)?;
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            salt,
+            cipher,
+            passwords,
+        })
+    }
+
+    /// Password stored for a bookmark
+    pub fn get(&self, id: Uuid) -> Option<&str> {
+        self.passwords.get(&id).map(String::as_str)
+    }
+
+    /// Store a bookmark's password
+    pub fn set(&mut self, id: Uuid, password: &str) {
+        self.passwords.insert(id, password.to_string());
+    }
+
+    /// Remove a bookmark's password
+    pub fn remove(&mut self, id: Uuid) {
+        self.passwords.remove(&id);
+    }
+
+    /// Encrypt and write the vault to disk
+    pub fn save(&self) -> Result<(), VaultError> {
+        let plaintext =
+            serde_json::to_vec(&self.passwords).map_err(|e| VaultError::Io(e.to_string()))?;
+
+        let mut nonce = [0u8; NONCE_SIZE];
+        OsRng.fill_bytes(&mut nonce);
+        let ciphertext = self
+            .cipher
+            .encrypt(Nonce::from_slice(&nonce), plaintext.as_slice())
+            .map_err(|_| VaultError::Crypto)?;
+
+        let mut data = Vec::with_capacity(NONCE_SIZE + ciphertext.len());
+        data.extend_from_slice(&nonce);
+        data.extend_from_slice(&ciphertext);
+
+        let file = VaultFile {
+            version: VAULT_VERSION,
+            salt: hex::encode(self.salt),
+            data: hex::encode(data),
+        };
+        let json =
+            serde_json::to_string_pretty(&file).map_err(|e| VaultError::Io(e.to_string()))?;
+
+        write_private_file(&self.path, json.as_bytes()).map_err(|e| VaultError::Io(e.to_string()))
+    }
+}
+
+/// Derive the vault cipher from a passphrase with Argon2id
+pub(super) fn derive_cipher(passphrase: &str, salt: &[u8]) -> Result<ChaCha20Poly1305, VaultError> {
+    let mut key = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|_| VaultError::Crypto)?;
+    Ok(ChaCha20Poly1305::new(&key.into()))
+}
+
+/// Write a file readable only by the owner (on Unix)
+fn write_private_file(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    // Create empty file and set permissions before writing content
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        fs::File::create(path)?;
+        fs::set_permissions(path, fs::Permissions::from_mode(VAULT_FILE_MODE))?;
+    }
+
+    fs::write(path, contents)
+}
+
+/// Errors that can occur when using the password vault
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VaultError {
+    /// Reading or writing the vault file failed
+    Io(String),
+    /// The vault file is damaged or from an unknown version
+    Corrupt,
+    /// The passphrase doesn't decrypt the vault
+    WrongPassphrase,
+    /// Key derivation or encryption failed
+    Crypto,
+}
+
+impl std::fmt::Display for VaultError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VaultError::Io(e) => write!(f, "{e}"),
+            VaultError::Corrupt => write!(f, "vault file is damaged"),
+            VaultError::WrongPassphrase => write!(f, "wrong passphrase"),
+            VaultError::Crypto => write!(f, "encryption failed"),
+        }
+    }
+}
+
+impl std::error::Error for VaultError {}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn test_save_and_open() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("vault.json");
+        let id = Uuid::new_v4();
+
+        let mut vault = Vault::create(&path, "correct horse").unwrap();
+        vault.set(id, "hunter2");
+        vault.save().unwrap();
+
+        let contents = fs::read_to_string(&path).unwrap();
+        assert!(!contents.contains("hunter2"));
+
+        let opened = Vault::open(&path, "correct horse").unwrap();
+        assert_eq!(opened.get(id), Some("hunter2"));
+    }
+
+    #[test]
+    fn test_wrong_passphrase() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("vault.json");
+
+        Vault::create(&path, "correct horse")
+            .unwrap()
+            .save()
+            .unwrap();
+
+        assert_eq!(
+            Vault::open(&path, "battery staple").err(),
+            Some(VaultError::WrongPassphrase)
+        );
+    }
+
+    #[test]
+    fn test_corrupt_file() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("vault.json");
+        fs::write(&path, "{}").unwrap();
+
+        assert_eq!(
+            Vault::open(&path, "correct horse").err(),
+            Some(VaultError::Corrupt)
+        );
+    }
+
+    #[test]
+    fn test_remove() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("vault.json");
+        let id = Uuid::new_v4();
+
+        let mut vault = Vault::create(&path, "correct horse").unwrap();
+        vault.set(id, "hunter2");
+        vault.remove(id);
+        vault.save().unwrap();
+
+        let opened = Vault::open(&path, "correct horse").unwrap();
+        assert_eq!(opened.get(id), None);
+    }
+}
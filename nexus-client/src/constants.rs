@@ -5,11 +5,76 @@
 /// Application display name (used in window title, notifications, etc.)
 pub const APP_NAME: &str = "Nexus BBS";
 
+/// Windows AppUserModelID (taskbar grouping and toast notification attribution)
+#[cfg(target_os = "windows")]
+pub const APP_USER_MODEL_ID: &str = "NexusBBS.Nexus";
+
 /// Application directory name (used in config directory path)
 pub const APP_DIR_NAME: &str = "nexus";
 
 /// Config file name
 pub const CONFIG_FILE_NAME: &str = "config.json";
 
+/// Password vault file name (encrypted bookmark passwords when no keychain is available)
+pub const VAULT_FILE_NAME: &str = "passwords.vault";
+
+/// Service name for bookmark passwords in the OS keychain
+pub const KEYCHAIN_SERVICE: &str = "Nexus BBS";
+
 /// Transfers file name
 pub const TRANSFERS_FILE_NAME: &str = "transfers.json";
+
+/// Unsent drafts file name (chat input and news editor text)
+pub const DRAFTS_FILE_NAME: &str = "drafts.json";
+
+/// Upload folder watch history file name
+pub const UPLOAD_WATCH_FILE_NAME: &str = "upload_watch.json";
+
+/// Download history file name (files checked for duplicates before downloading)
+pub const DOWNLOAD_HISTORY_FILE_NAME: &str = "download_history.json";
+
+/// Most downloaded files remembered for duplicate detection
+pub const MAX_DOWNLOAD_HISTORY: usize = 5000;
+
+/// Transfer history file name (finished downloads and uploads)
+pub const TRANSFER_HISTORY_FILE_NAME: &str = "transfer_history.json";
+
+/// Most finished transfers kept in the transfer history
+pub const MAX_TRANSFER_HISTORY: usize = 1000;
+
+/// Interval in seconds between scans of watched upload folders
+pub const UPLOAD_WATCH_INTERVAL_SECS: u64 = 5;
+
+/// Interval in seconds between checks of auto-download folders for new files
+///
+/// A fallback: the server reports new files in the folders as they arrive.
+pub const AUTO_DOWNLOAD_INTERVAL_SECS: u64 = 900;
+
+/// Interval in seconds between live refreshes of the connection monitor transfers tab
+pub const CONNECTION_MONITOR_REFRESH_SECS: u64 = 2;
+
+/// Interval in seconds between automatic refreshes of the admin dashboard
+pub const ADMIN_DASHBOARD_REFRESH_SECS: u64 = 10;
+
+/// Interval in seconds between checks for entering or leaving quiet hours
+pub const QUIET_HOURS_CHECK_SECS: u64 = 30;
+
+/// Interval in seconds between idle time checks for automatic away status
+pub const IDLE_CHECK_SECS: u64 = 15;
+
+/// Interval in seconds between status bar redraws while it shows a server clock
+pub const STATUS_BAR_CLOCK_SECS: u64 = 1;
+
+/// Interval in seconds between checks for expired voice text overlay messages
+pub const VOICE_TEXT_CHECK_SECS: u64 = 1;
+
+/// Seconds to wait for a response before a request is given up on
+pub const REQUEST_TIMEOUT_SECS: u64 = 30;
+
+/// Interval in seconds between checks for requests that timed out
+pub const REQUEST_TIMEOUT_CHECK_SECS: u64 = 5;
+
+/// Interval in seconds between background pings for connection health
+///
+/// A ping still unanswered when the next one is due counts as dropped.
+pub const HEALTH_PING_INTERVAL_SECS: u64 = 15;
@@ -0,0 +1,162 @@
+//! Platform helpers for opening files and folders in the desktop environment
+//!
+//! - **Reveal**: open the system file manager with the file selected
+//!   (Finder via `open -R`, Explorer via `/select`, and the freedesktop
+//!   `FileManager1` D-Bus interface on Linux, falling back to opening the
+//!   containing folder when no file manager implements it)
+//! - **Open**: open a file with its default application
+//! - **Checksum**: compute a file's SHA-256 digest for sharing or verification
+
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+
+use sha2::{Digest, Sha256};
+
+/// Read buffer size for checksum computation
+const CHECKSUM_BUFFER_SIZE: usize = 64 * 1024;
+
+/// Characters escaped when building `file://` URIs (everything except unreserved and `/`)
+#[cfg(any(target_os = "linux", test))]
+const FILE_URI_ESCAPE: &percent_encoding::AsciiSet = &percent_encoding::NON_ALPHANUMERIC
+    .remove(b'/')
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+/// Open the folder containing `path`
+pub fn open_folder(path: &Path) {
+    if let Some(parent) = path.parent() {
+        let _ = open::that(parent);
+    }
+}
+
+/// Open `path` with the default application for its type
+pub fn open_file(path: &Path) {
+    let _ = open::that(path);
+}
+
+/// Show `path` selected in the system file manager
+#[cfg(target_os = "macos")]
+pub fn reveal(path: &Path) {
+    let spawned = std::process::Command::new("open")
+        .arg("-R")
+        .arg(path)
+        .spawn();
+    if spawned.is_err() {
+        open_folder(path);
+    }
+}
+
+/// Show `path` selected in the system file manager
+#[cfg(target_os = "windows")]
+pub fn reveal(path: &Path) {
+    use std::os::windows::process::CommandExt;
+
+    // Explorer parses its own command line, so the path must be quoted
+    // as part of the `/select,` argument rather than passed separately.
+    let spawned = std::process::Command::new("explorer")
+        .raw_arg(format!("/select,\"{}\"", path.display()))
+        .spawn();
+    if spawned.is_err() {
+        open_folder(path);
+    }
+}
+
+/// Show `path` selected in the system file manager
+#[cfg(target_os = "linux")]
+pub fn reveal(path: &Path) {
+    let path = path.to_path_buf();
+    let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+        open_folder(&path);
+        return;
+    };
+    runtime.spawn(async move {
+        if show_items(&path).await.is_err() {
+            open_folder(&path);
+        }
+    });
+}
+
+/// Show `path` in the containing folder (no selection support on this platform)
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+pub fn reveal(path: &Path) {
+    open_folder(path);
+}
+
+/// Ask the freedesktop file manager to show and select `path`
+#[cfg(target_os = "linux")]
+async fn show_items(path: &Path) -> zbus::Result<()> {
+    let connection = zbus::Connection::session().await?;
+    let uri = file_uri(path);
+    connection
+        .call_method(
+            Some("org.freedesktop.FileManager1"),
+            "/org/freedesktop/FileManager1",
+            Some("org.freedesktop.FileManager1"),
+            "ShowItems",
+            &(vec![uri.as_str()], ""),
+        )
+        .await?;
+    Ok(())
+}
+
+/// Build a `file://` URI for an absolute path
+#[cfg(any(target_os = "linux", test))]
+fn file_uri(path: &Path) -> String {
+    let path = path.to_string_lossy();
+    format!(
+        "file://{}",
+        percent_encoding::utf8_percent_encode(&path, FILE_URI_ESCAPE)
+    )
+}
+
+/// Compute the SHA-256 digest of a file as lowercase hex
+///
+/// Reads the file in chunks, so this blocks; call it from `spawn_blocking`.
+pub fn sha256_file(path: &Path) -> io::Result<String> {
+    let mut file = File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0u8; CHECKSUM_BUFFER_SIZE];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(hex::encode(hasher.finalize()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    #[test]
+    fn test_sha256_file() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(b"hello world").unwrap();
+
+        let digest = sha256_file(file.path()).unwrap();
+        assert_eq!(
+            digest,
+            "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9"
+        );
+    }
+
+    #[test]
+    fn test_sha256_file_missing() {
+        assert!(sha256_file(Path::new("/nonexistent/nexus-checksum-test")).is_err());
+    }
+
+    #[test]
+    fn test_file_uri_escapes_special_characters() {
+        assert_eq!(
+            file_uri(Path::new("/home/user/My Files/song#1.mp3")),
+            "file:///home/user/My%20Files/song%231.mp3"
+        );
+    }
+}
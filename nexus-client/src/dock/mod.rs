@@ -0,0 +1,144 @@
+//! macOS dock integration
+//!
+//! macOS has no system tray, so the dock icon takes over its job:
+//! - Badge with the number of unread user messages and channel mentions
+//! - Bounce (attention request) when a server broadcast arrives
+//!
+//! Notification Center alerts live in `crate::notifications`.
+//!
+//! The badge is shared with transfer progress (see `crate::taskbar`): while a
+//! transfer runs the percentage is shown, and the unread count returns when
+//! it finishes.
+
+#![cfg(target_os = "macos")]
+
+use std::sync::Mutex;
+
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::{MainThreadMarker, class, msg_send};
+use objc2_foundation::NSString;
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// `NSInformationalRequest` - bounce the dock icon once
+const NS_INFORMATIONAL_REQUEST: isize = 10;
+
+/// Largest count shown on the badge before switching to "99+"
+const MAX_BADGE_COUNT: usize = 99;
+
+// =============================================================================
+// Badge State
+// =============================================================================
+
+/// What the dock badge is currently showing
+struct Badge {
+    /// Transfer progress percentage (None = no transfers running)
+    progress: Option<u8>,
+    /// Unread user messages and channel mentions
+    unread: usize,
+}
+
+/// Badge state, combined into one label on every change
+static BADGE: Mutex<Badge> = Mutex::new(Badge {
+    progress: None,
+    unread: 0,
+});
+
+/// Show transfer progress on the badge, or hide it with `None`
+pub fn set_progress(percent: Option<u8>) {
+    let Ok(mut badge) = BADGE.lock() else {
+        return;
+    };
+    if badge.progress == percent {
+        return;
+    }
+    badge.progress = percent;
+    set_badge_label(badge_label(&badge).as_deref());
+}
+
+/// Show the unread count on the badge (0 clears it)
+pub fn set_unread(count: usize) {
+    let Ok(mut badge) = BADGE.lock() else {
+        return;
+    };
+    if badge.unread == count {
+        return;
+    }
+    badge.unread = count;
+    set_badge_label(badge_label(&badge).as_deref());
+}
+
+/// Bounce the dock icon once
+///
+/// AppKit ignores the request while the app is active.
+pub fn request_attention() {
+    // AppKit must only be touched from the main thread
+    if MainThreadMarker::new().is_none() {
+        return;
+    }
+
+    // SAFETY: we are on the main thread and `sharedApplication` always
+    // returns a valid object once the app has launched.
+    unsafe {
+        let app: *mut AnyObject = msg_send![class!(NSApplication), sharedApplication];
+        if app.is_null() {
+            return;
+        }
+        let _: isize = msg_send![app, requestUserAttention: NS_INFORMATIONAL_REQUEST];
+    }
+}
+
+/// Build the badge label (progress takes precedence over the unread count)
+fn badge_label(badge: &Badge) -> Option<String> {
+    if let Some(percent) = badge.progress {
+        return Some(format!("{percent}%"));
+    }
+    match badge.unread {
+        0 => None,
+        count if count > MAX_BADGE_COUNT => Some(format!("{MAX_BADGE_COUNT}+")),
+        count => Some(count.to_string()),
+    }
+}
+
+/// Set the dock tile badge label (None clears it)
+fn set_badge_label(label: Option<&str>) {
+    // AppKit must only be touched from the main thread
+    if MainThreadMarker::new().is_none() {
+        return;
+    }
+
+    let label: Option<Retained<NSString>> = label.map(NSString::from_str);
+
+    // SAFETY: we are on the main thread; `sharedApplication` and `dockTile`
+    // always return valid objects once the app has launched, and
+    // `setBadgeLabel:` accepts nil to clear the badge.
+    unsafe {
+        let app: *mut AnyObject = msg_send![class!(NSApplication), sharedApplication];
+        if app.is_null() {
+            return;
+        }
+        let dock_tile: *mut AnyObject = msg_send![app, dockTile];
+        if dock_tile.is_null() {
+            return;
+        }
+        let _: () = msg_send![dock_tile, setBadgeLabel: label.as_deref()];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_badge_label() {
+        let label = |progress, unread| badge_label(&Badge { progress, unread });
+        assert_eq!(label(None, 0), None);
+        assert_eq!(label(None, 3), Some("3".to_string()));
+        assert_eq!(label(None, 150), Some("99+".to_string()));
+        assert_eq!(label(Some(42), 3), Some("42%".to_string()));
+        assert_eq!(label(Some(0), 0), Some("0%".to_string()));
+    }
+}
@@ -0,0 +1,238 @@
+//! Unsent draft persistence
+//!
+//! Unsent chat input (one draft per chat tab) and news editor text are kept
+//! on the connection while it's open, and copied to `drafts.json` next to
+//! `config.json` when the connection closes or the app exits. Drafts are
+//! keyed by server and account (`username@address:port`) and by context, so
+//! they come back the next time the same account connects to the same server.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::constants::{APP_DIR_NAME, DRAFTS_FILE_NAME};
+use crate::types::{ChatTab, ConnectionInfo};
+
+/// File permissions for drafts file on Unix (owner read/write only)
+#[cfg(unix)]
+const DRAFTS_FILE_MODE: u32 = 0o600;
+
+/// Where a draft was written
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DraftContext {
+    /// Chat input for a tab
+    Chat(ChatTab),
+    /// News editor for a new post (None) or an edit of an existing post
+    News(Option<i64>),
+}
+
+impl DraftContext {
+    /// Key for this context in the drafts file
+    fn key(&self) -> String {
+        match self {
+            DraftContext::Chat(ChatTab::Console) => "chat:console".to_string(),
+            DraftContext::Chat(ChatTab::Channel(channel)) => format!("chat:channel:{channel}"),
+            DraftContext::Chat(ChatTab::UserMessage(nickname)) => format!("chat:user:{nickname}"),
+            DraftContext::Chat(ChatTab::Group(group_id)) => format!("chat:group:{group_id}"),
+            DraftContext::News(None) => "news:new".to_string(),
+            DraftContext::News(Some(id)) => format!("news:{id}"),
+        }
+    }
+
+    /// Parse a key from the drafts file
+    fn from_key(key: &str) -> Option<Self> {
+        if let Some(tab) = key.strip_prefix("chat:") {
+            let tab = if tab == "console" {
+                ChatTab::Console
+            } else if let Some(channel) = tab.strip_prefix("channel:") {
+                ChatTab::Channel(channel.to_string())
+            } else if let Some(group_id) = tab.strip_prefix("group:") {
+                ChatTab::Group(group_id.parse().ok()?)
+            } else {
+                ChatTab::UserMessage(tab.strip_prefix("user:")?.to_string())
+            };
+            return Some(DraftContext::Chat(tab));
+        }
+
+        match key.strip_prefix("news:")? {
+            "new" => Some(DraftContext::News(None)),
+            id => id.parse().ok().map(|id| DraftContext::News(Some(id))),
+        }
+    }
+}
+
+/// Unsent drafts for one connection, by context
+pub type Drafts = HashMap<DraftContext, String>;
+
+/// Key for a server and account in the drafts file
+pub fn server_key(info: &ConnectionInfo) -> String {
+    format!("{}@{}:{}", info.username, info.address, info.port)
+}
+
+/// Persistent drafts file structure
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct DraftsFile {
+    /// Draft text by context key, per server key
+    drafts: HashMap<String, HashMap<String, String>>,
+}
+
+/// Unsent drafts for all servers
+///
+/// Stored next to `config.json` and shared by all connections.
+#[derive(Debug, Default)]
+pub struct DraftStore {
+    /// Draft text by context key, per server key
+    drafts: HashMap<String, HashMap<String, String>>,
+
+    /// Whether there are unsaved changes
+    dirty: bool,
+}
+
+impl DraftStore {
+    /// Get the platform-specific drafts file path
+    ///
+    /// Returns None if the config directory cannot be determined.
+    pub fn drafts_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join(APP_DIR_NAME).join(DRAFTS_FILE_NAME))
+    }
+
+    /// Load drafts from disk, or return an empty store
+    pub fn load() -> Self {
+        if let Some(path) = Self::drafts_path()
+            && let Ok(contents) = fs::read_to_string(&path)
+            && let Ok(file) = serde_json::from_str::<DraftsFile>(&contents)
+        {
+            return Self {
+                drafts: file.drafts,
+                dirty: false,
+            };
+        }
+
+        Self::default()
+    }
+
+    /// Save drafts to disk if there are unsaved changes
+    ///
+    /// Drafts may hold private messages, so on Unix the file is readable by
+    /// the owner only.
+    pub fn save(&mut self) -> io::Result<()> {
+        if !self.dirty {
+            return Ok(());
+        }
+
+        let path = Self::drafts_path()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no config directory"))?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let file = DraftsFile {
+            drafts: self.drafts.clone(),
+        };
+        let json = serde_json::to_string_pretty(&file).map_err(io::Error::other)?;
+
+        // On Unix, create empty file and set permissions before writing content
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            fs::File::create(&path)?;
+            fs::set_permissions(&path, fs::Permissions::from_mode(DRAFTS_FILE_MODE))?;
+        }
+
+        fs::write(&path, json)?;
+
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Drafts saved for a server
+    pub fn get(&self, server: &str) -> Drafts {
+        self.drafts
+            .get(server)
+            .into_iter()
+            .flatten()
+            .filter_map(|(key, text)| Some((DraftContext::from_key(key)?, text.clone())))
+            .collect()
+    }
+
+    /// Replace the drafts saved for a server (blank drafts are dropped)
+    pub fn set(&mut self, server: &str, drafts: Drafts) {
+        let drafts: HashMap<String, String> = drafts
+            .into_iter()
+            .filter(|(_, text)| !text.trim().is_empty())
+            .map(|(context, text)| (context.key(), text))
+            .collect();
+
+        let changed = if drafts.is_empty() {
+            self.drafts.remove(server).is_some()
+        } else {
+            self.drafts.insert(server.to_string(), drafts.clone()) != Some(drafts)
+        };
+        if changed {
+            self.dirty = true;
+        }
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_context_keys_round_trip() {
+        let contexts = [
+            DraftContext::Chat(ChatTab::Console),
+            DraftContext::Chat(ChatTab::Channel("#general".to_string())),
+            DraftContext::Chat(ChatTab::UserMessage("alice".to_string())),
+            DraftContext::Chat(ChatTab::Group(7)),
+            DraftContext::News(None),
+            DraftContext::News(Some(42)),
+        ];
+        for context in contexts {
+            assert_eq!(DraftContext::from_key(&context.key()), Some(context));
+        }
+
+        assert_eq!(DraftContext::from_key("chat:unknown"), None);
+        assert_eq!(DraftContext::from_key("news:abc"), None);
+    }
+
+    #[test]
+    fn test_drafts_are_per_server() {
+        let mut store = DraftStore::default();
+        let drafts = Drafts::from([
+            (DraftContext::Chat(ChatTab::Console), "hello".to_string()),
+            (DraftContext::News(None), "  ".to_string()),
+        ]);
+
+        store.set("alice@example.com:7500", drafts);
+        assert!(store.dirty);
+
+        // Blank drafts are dropped
+        assert_eq!(
+            store.get("alice@example.com:7500"),
+            Drafts::from([(DraftContext::Chat(ChatTab::Console), "hello".to_string())])
+        );
+        assert!(store.get("bob@example.com:7500").is_empty());
+
+        // Setting the same drafts again isn't a change
+        store.dirty = false;
+        store.set(
+            "alice@example.com:7500",
+            Drafts::from([(DraftContext::Chat(ChatTab::Console), "hello".to_string())]),
+        );
+        assert!(!store.dirty);
+
+        // No drafts left removes the server
+        store.set("alice@example.com:7500", Drafts::new());
+        assert!(store.dirty);
+        assert!(store.drafts.is_empty());
+    }
+}
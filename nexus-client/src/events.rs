@@ -17,11 +17,11 @@ use notify_rust::NotificationHandle;
 // so we hold onto handles until they expire naturally.
 // See: https://gitlab.gnome.org/GNOME/gnome-shell/-/issues/8797
 #[cfg(all(unix, not(target_os = "macos")))]
-pub static NOTIFICATION_HANDLES: Mutex<Vec<(Instant, NotificationHandle)>> = Mutex::new(Vec::new());
+static NOTIFICATION_HANDLES: Mutex<Vec<(Instant, NotificationHandle)>> = Mutex::new(Vec::new());
 
 /// How long to keep notification handles alive (slightly longer than the notification timeout)
 #[cfg(all(unix, not(target_os = "macos")))]
-pub const HANDLE_LIFETIME: Duration = Duration::from_secs(6);
+const HANDLE_LIFETIME: Duration = Duration::from_secs(6);
 
 use iced_toasts::{ToastLevel, toast};
 
@@ -148,7 +148,30 @@ impl EventContext {
 /// state to determine whether a notification, toast, and/or sound should be
 /// triggered. Each channel is handled independently.
 pub fn emit_event(app: &mut NexusApp, event_type: EventType, context: EventContext) {
-    let config = app.config.settings.event_settings.get(event_type).clone();
+    emit_event_as(app, event_type, event_type, context);
+}
+
+/// Emit an event using the delivery settings of another event type
+///
+/// Content and suppression still follow `event_type`. Used for channels set
+/// to notify on all messages, whose chat messages are delivered like mentions.
+pub fn emit_event_as(
+    app: &mut NexusApp,
+    event_type: EventType,
+    settings_from: EventType,
+    context: EventContext,
+) {
+    // Do not disturb silences every channel (unread badges still accumulate)
+    if app.do_not_disturb_active() {
+        return;
+    }
+
+    let config = app
+        .config
+        .settings
+        .event_settings
+        .get(settings_from)
+        .clone();
     let suppressed = !should_show_event(app, event_type, &context);
 
     // Handle desktop notification (skip for self-triggered events)
@@ -160,28 +183,21 @@ pub fn emit_event(app: &mut NexusApp, event_type: EventType, context: EventConte
         let (summary, body) =
             build_event_content(event_type, &context, config.notification_content);
 
-        let mut notification = Notification::new();
-        notification
-            .appname(APP_NAME)
-            .summary(&summary)
-            .body(body.as_deref().unwrap_or(""))
-            .auto_icon()
-            .timeout(notify_rust::Timeout::Milliseconds(5000));
-
-        // On Linux, keep handle alive to prevent GNOME/Cinnamon from dismissing
-        // notifications when the D-Bus connection would otherwise be dropped.
-        #[cfg(all(unix, not(target_os = "macos")))]
-        if let Ok(handle) = notification.show()
-            && let Ok(mut handles) = NOTIFICATION_HANDLES.lock()
-        {
-            let now = Instant::now();
-            handles.retain(|(created, _)| now.duration_since(*created) < HANDLE_LIFETIME);
-            handles.push((now, handle));
+        // On macOS and Windows, prefer native notifications (reply actions,
+        // click to focus). They're unavailable e.g. without a macOS app bundle.
+        #[cfg(any(target_os = "macos", target_os = "windows"))]
+        if crate::notifications::is_available() {
+            crate::notifications::show(
+                &summary,
+                body.as_deref(),
+                notification_target(event_type, &context),
+            );
+        } else {
+            show_desktop_notification(&summary, body.as_deref());
         }
 
-        // On non-Linux platforms, just show and ignore result
-        #[cfg(not(all(unix, not(target_os = "macos"))))]
-        let _ = notification.show();
+        #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+        show_desktop_notification(&summary, body.as_deref());
     }
 
     // Handle toast notification (skip for self-triggered events)
@@ -211,6 +227,52 @@ pub fn emit_event(app: &mut NexusApp, event_type: EventType, context: EventConte
     }
 }
 
+/// Show a desktop notification through `notify-rust`
+pub fn show_desktop_notification(summary: &str, body: Option<&str>) {
+    let mut notification = Notification::new();
+    notification
+        .appname(APP_NAME)
+        .summary(summary)
+        .body(body.unwrap_or(""))
+        .auto_icon()
+        .timeout(notify_rust::Timeout::Milliseconds(5000));
+
+    // On Linux, keep handle alive to prevent GNOME/Cinnamon from dismissing
+    // notifications when the D-Bus connection would otherwise be dropped.
+    #[cfg(all(unix, not(target_os = "macos")))]
+    if let Ok(handle) = notification.show()
+        && let Ok(mut handles) = NOTIFICATION_HANDLES.lock()
+    {
+        let now = Instant::now();
+        handles.retain(|(created, _)| now.duration_since(*created) < HANDLE_LIFETIME);
+        handles.push((now, handle));
+    }
+
+    // On non-Linux platforms, just show and ignore result
+    #[cfg(not(all(unix, not(target_os = "macos"))))]
+    let _ = notification.show();
+}
+
+/// Conversation a native notification belongs to (macOS/Windows)
+///
+/// User messages reply to the sender, channel messages and mentions to the
+/// channel. Other events have no conversation.
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+fn notification_target(
+    event_type: EventType,
+    context: &EventContext,
+) -> Option<crate::notifications::NotificationTarget> {
+    let connection_id = context.connection_id?;
+    let tab = match event_type {
+        EventType::UserMessage => ChatTab::UserMessage(context.username.clone()?),
+        EventType::ChatMessage | EventType::ChatMention => {
+            ChatTab::Channel(context.channel.clone()?)
+        }
+        _ => return None,
+    };
+    Some(crate::notifications::NotificationTarget { connection_id, tab })
+}
+
 /// Build event content for a test notification or toast
 ///
 /// Returns (summary, body) with sample content for the given event type.
@@ -234,6 +296,16 @@ pub fn build_test_event_content(
     build_event_content(event_type, &context, content_level)
 }
 
+/// Whether the event's channel is popped out in a focused chat window
+fn channel_window_focused(app: &NexusApp, context: &EventContext) -> bool {
+    if let Some(conn_id) = context.connection_id
+        && let Some(ref channel) = context.channel
+    {
+        return app.chat_window_focused_on(conn_id, &ChatTab::Channel(channel.clone()));
+    }
+    false
+}
+
 /// Determine if an event should trigger notifications, toasts, or sounds
 ///
 /// This checks whether the event is suppressed based on app state - for example,
@@ -241,6 +313,14 @@ pub fn build_test_event_content(
 fn should_show_event(app: &NexusApp, event_type: EventType, context: &EventContext) -> bool {
     match event_type {
         EventType::UserMessage => {
+            // Don't notify if the user's message tab is popped out in a focused window
+            if let Some(event_conn_id) = context.connection_id
+                && let Some(ref username) = context.username
+                && app
+                    .chat_window_focused_on(event_conn_id, &ChatTab::UserMessage(username.clone()))
+            {
+                return false;
+            }
             // Don't notify if window is focused AND this connection is active AND we're viewing that user's message tab
             if app.window_focused
                 && let Some(event_conn_id) = context.connection_id
@@ -259,6 +339,10 @@ fn should_show_event(app: &NexusApp, event_type: EventType, context: &EventConte
             !app.window_focused
         }
         EventType::ChatMention => {
+            // Don't notify if the channel is popped out in a focused window
+            if channel_window_focused(app, context) {
+                return false;
+            }
             // Don't notify if window is focused AND viewing that specific channel
             if app.window_focused
                 && let Some(event_conn_id) = context.connection_id
@@ -267,7 +351,8 @@ fn should_show_event(app: &NexusApp, event_type: EventType, context: &EventConte
                 && let Some(conn) = app.connections.get(&event_conn_id)
                 && let Some(ref channel) = context.channel
                 && conn.active_chat_tab == ChatTab::Channel(channel.clone())
-                && conn.active_panel == ActivePanel::None
+                && (conn.active_panel == ActivePanel::None
+                    || app.chat_beside_panel(conn.active_panel))
             {
                 return false;
             }
@@ -286,6 +371,10 @@ fn should_show_event(app: &NexusApp, event_type: EventType, context: &EventConte
             true
         }
         EventType::ChatMessage => {
+            // Don't notify if the channel is popped out in a focused window
+            if channel_window_focused(app, context) {
+                return false;
+            }
             // Don't notify if window is focused AND viewing that specific channel
             if app.window_focused
                 && let Some(event_conn_id) = context.connection_id
@@ -294,7 +383,8 @@ fn should_show_event(app: &NexusApp, event_type: EventType, context: &EventConte
                 && let Some(conn) = app.connections.get(&event_conn_id)
                 && let Some(ref channel) = context.channel
                 && conn.active_chat_tab == ChatTab::Channel(channel.clone())
-                && conn.active_panel == ActivePanel::None
+                && (conn.active_panel == ActivePanel::None
+                    || app.chat_beside_panel(conn.active_panel))
             {
                 return false;
             }
@@ -304,6 +394,10 @@ fn should_show_event(app: &NexusApp, event_type: EventType, context: &EventConte
             // Don't notify if window is focused - user will see the disconnection
             !app.window_focused
         }
+        EventType::FriendOnline => {
+            // Always notify - friends are few and watched on purpose
+            true
+        }
         EventType::PermissionsChanged => {
             // Don't notify if window is focused - user will see the change
             !app.window_focused
@@ -386,6 +480,7 @@ fn build_event_content(
         EventType::ChatMessage => build_chat_message_notification(context, content_level),
         EventType::ChatMention => build_chat_mention_notification(context, content_level),
         EventType::ConnectionLost => build_connection_lost_notification(context, content_level),
+        EventType::FriendOnline => build_friend_online_notification(context, content_level),
         EventType::NewsPost => build_news_post_notification(context, content_level),
         EventType::PermissionsChanged => {
             build_permissions_changed_notification(context, content_level)
@@ -733,6 +828,28 @@ fn build_user_connected_notification(
     }
 }
 
+/// Build notification content for friend online events
+fn build_friend_online_notification(
+    context: &EventContext,
+    content_level: NotificationContent,
+) -> (String, Option<String>) {
+    match content_level {
+        NotificationContent::EventOnly => {
+            // "Friend online"
+            (t("notification-friend-online"), None)
+        }
+        NotificationContent::WithContext | NotificationContent::WithPreview => {
+            // "Alice is online"
+            let summary = if let Some(ref username) = context.username {
+                t_args("notification-friend-online-name", &[("username", username)])
+            } else {
+                t("notification-friend-online")
+            };
+            (summary, None)
+        }
+    }
+}
+
 /// Build notification content for user disconnected events
 fn build_user_disconnected_notification(
     context: &EventContext,
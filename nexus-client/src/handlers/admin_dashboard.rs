@@ -0,0 +1,69 @@
+//! Admin dashboard panel handlers
+
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+
+use crate::NexusApp;
+use crate::i18n::t;
+use crate::types::{ActivePanel, Message, PendingRequests, ResponseRouting};
+
+impl NexusApp {
+    /// Show the Admin Dashboard panel and request fresh figures
+    pub fn handle_show_admin_dashboard(&mut self) -> Task<Message> {
+        self.set_active_panel(ActivePanel::AdminDashboard);
+        self.request_admin_dashboard();
+        Task::none()
+    }
+
+    /// Close the Admin Dashboard panel, returning to Server Info where it was opened
+    pub fn handle_close_admin_dashboard(&mut self) -> Task<Message> {
+        self.set_active_panel(ActivePanel::ServerInfo);
+        Task::none()
+    }
+
+    /// Refresh the dashboard now
+    pub fn handle_refresh_admin_dashboard(&mut self) -> Task<Message> {
+        self.request_admin_dashboard();
+        Task::none()
+    }
+
+    /// Periodic refresh while the dashboard is open
+    ///
+    /// Skipped while a previous request is still outstanding so slow servers
+    /// don't accumulate a backlog of requests.
+    pub fn handle_admin_dashboard_tick(&mut self) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get(&conn_id)
+            && conn.admin_dashboard.loading
+        {
+            return Task::none();
+        }
+        self.request_admin_dashboard();
+        Task::none()
+    }
+
+    /// Send a ServerStats query and a one-day StatsRequest for today's activity
+    ///
+    /// Existing figures stay visible until the responses replace them.
+    fn request_admin_dashboard(&mut self) {
+        let Some(conn_id) = self.active_connection else {
+            return;
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return;
+        };
+
+        conn.admin_dashboard.loading = true;
+
+        if let Err(e) = conn.send(ClientMessage::ServerStats) {
+            conn.admin_dashboard.loading = false;
+            conn.admin_dashboard.error = Some(format!("{}: {}", t("err-send-failed"), e));
+            return;
+        }
+
+        if let Ok(message_id) = conn.send(ClientMessage::StatsRequest { days: 1 }) {
+            conn.pending_requests
+                .track(message_id, ResponseRouting::AdminDashboardToday);
+        }
+    }
+}
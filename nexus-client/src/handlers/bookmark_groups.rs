@@ -0,0 +1,141 @@
+//! Bookmark folders and drag-to-reorder in the sidebar
+
+use iced::Task;
+use iced::widget::{Id, operation};
+use uuid::Uuid;
+
+use crate::NexusApp;
+use crate::types::{BookmarkDrag, BookmarkDropTarget, BookmarkGroupEdit, InputId, Message};
+
+impl NexusApp {
+    // ==================== Drag to Reorder ====================
+
+    /// Start dragging a bookmark by its handle
+    pub fn handle_bookmark_drag_started(&mut self, id: Uuid) -> Task<Message> {
+        self.ui_state.bookmark_drag = Some(BookmarkDrag {
+            bookmark: id,
+            target: None,
+        });
+        Task::none()
+    }
+
+    /// Track where the dragged bookmark would drop
+    pub fn handle_bookmark_drag_hovered(&mut self, target: BookmarkDropTarget) -> Task<Message> {
+        if let Some(drag) = &mut self.ui_state.bookmark_drag {
+            drag.target = Some(target);
+        }
+        Task::none()
+    }
+
+    /// Drop the dragged bookmark when the mouse button is released
+    ///
+    /// Released anywhere but over the bookmark list, it stays where it was.
+    pub fn handle_bookmark_drag_released(&mut self) -> Task<Message> {
+        if let Some(BookmarkDrag {
+            bookmark,
+            target: Some(target),
+        }) = self.ui_state.bookmark_drag.take()
+        {
+            self.config.move_bookmark(bookmark, target);
+            let _ = self.config.save();
+        }
+        Task::none()
+    }
+
+    // ==================== Folders ====================
+
+    /// Collapse or expand a folder
+    pub fn handle_bookmark_group_toggled(&mut self, id: Uuid) -> Task<Message> {
+        if let Some(group) = self.config.get_bookmark_group_mut(id) {
+            group.collapsed = !group.collapsed;
+            let _ = self.config.save();
+        }
+        Task::none()
+    }
+
+    /// Connect to every bookmark in a folder that isn't already connected
+    pub fn handle_bookmark_group_connect_all(&mut self, id: Uuid) -> Task<Message> {
+        let ids: Vec<Uuid> = self
+            .config
+            .bookmarks_in_group(Some(id))
+            .map(|bookmark| bookmark.id)
+            .filter(|bookmark_id| {
+                !self
+                    .connections
+                    .values()
+                    .any(|conn| conn.bookmark_id == Some(*bookmark_id))
+            })
+            .collect();
+
+        Task::batch(
+            ids.into_iter()
+                .map(|id| self.handle_connect_to_bookmark(id)),
+        )
+    }
+
+    /// Show the name input for a new folder
+    pub fn handle_bookmark_group_new(&mut self) -> Task<Message> {
+        self.ui_state.bookmark_group_edit = Some(BookmarkGroupEdit {
+            group: None,
+            name: String::new(),
+        });
+        operation::focus(Id::from(InputId::BookmarkGroupName))
+    }
+
+    /// Show the name input for renaming a folder
+    pub fn handle_bookmark_group_rename(&mut self, id: Uuid) -> Task<Message> {
+        let Some(group) = self.config.bookmark_groups.iter().find(|g| g.id == id) else {
+            return Task::none();
+        };
+        self.ui_state.bookmark_group_edit = Some(BookmarkGroupEdit {
+            group: Some(id),
+            name: group.name.clone(),
+        });
+        operation::focus(Id::from(InputId::BookmarkGroupName))
+    }
+
+    /// Delete a folder, moving its bookmarks out of it
+    pub fn handle_bookmark_group_delete(&mut self, id: Uuid) -> Task<Message> {
+        self.config.delete_bookmark_group(id);
+        let _ = self.config.save();
+        Task::none()
+    }
+
+    /// Handle the folder name input changing
+    pub fn handle_bookmark_group_name_changed(&mut self, name: String) -> Task<Message> {
+        if let Some(edit) = &mut self.ui_state.bookmark_group_edit {
+            edit.name = name;
+        }
+        Task::none()
+    }
+
+    /// Create or rename the folder (a blank name cancels)
+    pub fn handle_bookmark_group_name_submitted(&mut self) -> Task<Message> {
+        let Some(edit) = self.ui_state.bookmark_group_edit.take() else {
+            return Task::none();
+        };
+        let name = edit.name.trim().to_string();
+        if name.is_empty() {
+            return Task::none();
+        }
+
+        match edit.group {
+            Some(id) => {
+                if let Some(group) = self.config.get_bookmark_group_mut(id) {
+                    group.name = name;
+                }
+            }
+            None => {
+                self.config.add_bookmark_group(name);
+            }
+        }
+        let _ = self.config.save();
+        Task::none()
+    }
+
+    /// Close the folder name input without saving
+    pub fn handle_bookmark_group_name_cancelled(&mut self) -> Task<Message> {
+        self.ui_state.bookmark_group_edit = None;
+        Task::none()
+    }
+}
@@ -2,12 +2,17 @@
 
 use iced::Task;
 use iced::widget::{Id, operation};
+use nexus_common::protocol::ClientMessage;
+use nexus_common::validators::{self, StatusError};
 use uuid::Uuid;
 
 use crate::NexusApp;
+use crate::config::settings::AVATAR_MAX_SIZE;
 use crate::i18n::{get_locale, t, t_args};
-use crate::network::{ConnectionParams, ProxyConfig};
-use crate::types::{BookmarkEditMode, BookmarkEditState, InputId, Message};
+use crate::image::{ImagePickerError, decode_data_uri_square, pick_image_data_uri};
+use crate::network::{ConnectionParams, ProxyConfig, parse_fallback_address};
+use crate::style::AVATAR_MAX_CACHE_SIZE;
+use crate::types::{BookmarkEditMode, BookmarkEditState, InputId, Message, ResponseRouting};
 
 impl NexusApp {
     // ==================== Form Field Handlers ====================
@@ -20,12 +25,29 @@ impl NexusApp {
         Task::none()
     }
 
+    /// Handle bookmark fallback addresses field change
+    pub fn handle_bookmark_fallback_addresses_changed(
+        &mut self,
+        addresses: String,
+    ) -> Task<Message> {
+        self.bookmark_edit.fallback_addresses = addresses;
+        self.bookmark_edit.error = None;
+        self.focused_field = InputId::BookmarkFallbackAddresses;
+        Task::none()
+    }
+
     /// Handle bookmark auto-connect toggle
     pub fn handle_bookmark_auto_connect_toggled(&mut self, enabled: bool) -> Task<Message> {
         self.bookmark_edit.bookmark.auto_connect = enabled;
         Task::none()
     }
 
+    /// Handle bookmark single sign-on toggle
+    pub fn handle_bookmark_use_sso_toggled(&mut self, enabled: bool) -> Task<Message> {
+        self.bookmark_edit.bookmark.use_sso = enabled;
+        Task::none()
+    }
+
     /// Handle bookmark name field change
     pub fn handle_bookmark_name_changed(&mut self, name: String) -> Task<Message> {
         self.bookmark_edit.bookmark.name = name;
@@ -66,6 +88,67 @@ impl NexusApp {
         Task::none()
     }
 
+    /// Handle bookmark status field change
+    pub fn handle_bookmark_status_changed(&mut self, status: String) -> Task<Message> {
+        self.bookmark_edit.bookmark.status = status;
+        self.bookmark_edit.error = None;
+        self.focused_field = InputId::BookmarkStatus;
+        Task::none()
+    }
+
+    /// Handle bookmark choose avatar button pressed
+    pub fn handle_bookmark_pick_avatar_pressed(&mut self) -> Task<Message> {
+        Task::perform(
+            pick_image_data_uri(AVATAR_MAX_SIZE),
+            Message::BookmarkAvatarLoaded,
+        )
+    }
+
+    /// Handle bookmark avatar file picker result
+    pub fn handle_bookmark_avatar_loaded(
+        &mut self,
+        result: Result<String, ImagePickerError>,
+    ) -> Task<Message> {
+        match result {
+            Ok(data_uri) => {
+                let cached = decode_data_uri_square(&data_uri, AVATAR_MAX_CACHE_SIZE);
+                if cached.is_some() {
+                    self.bookmark_edit.error = None;
+                    self.bookmark_edit.cached_avatar = cached;
+                    self.bookmark_edit.bookmark.avatar = Some(data_uri);
+                } else {
+                    self.bookmark_edit.error = Some(t("err-avatar-decode-failed"));
+                }
+            }
+            Err(ImagePickerError::Cancelled) => {}
+            Err(ImagePickerError::UnsupportedType) => {
+                self.bookmark_edit.error = Some(t("err-avatar-unsupported-type"));
+            }
+            Err(ImagePickerError::TooLarge) => {
+                let max_kb = (AVATAR_MAX_SIZE / 1024).to_string();
+                self.bookmark_edit.error =
+                    Some(t_args("err-avatar-too-large", &[("max_kb", &max_kb)]));
+            }
+        }
+        Task::none()
+    }
+
+    /// Handle bookmark clear avatar button pressed (falls back to the Settings avatar)
+    pub fn handle_bookmark_clear_avatar_pressed(&mut self) -> Task<Message> {
+        self.bookmark_edit.error = None;
+        self.bookmark_edit.cached_avatar = None;
+        self.bookmark_edit.bookmark.avatar = None;
+        Task::none()
+    }
+
+    /// Handle bookmark download folder field change
+    pub fn handle_bookmark_download_folder_changed(&mut self, folder: String) -> Task<Message> {
+        self.bookmark_edit.bookmark.download_folder = folder;
+        self.bookmark_edit.error = None;
+        self.focused_field = InputId::BookmarkDownloadFolder;
+        Task::none()
+    }
+
     // ==================== Dialog Actions ====================
 
     /// Cancel bookmark editing and close the dialog
@@ -82,13 +165,29 @@ impl NexusApp {
             return Task::none();
         }
 
-        let bookmark = self.bookmark_edit.bookmark.clone();
+        let mut bookmark = self.bookmark_edit.bookmark.clone();
+        bookmark.fallback_addresses =
+            split_fallback_addresses(&self.bookmark_edit.fallback_addresses);
 
         match self.bookmark_edit.mode {
             BookmarkEditMode::Add => {
                 self.config.add_bookmark(bookmark);
             }
             BookmarkEditMode::Edit(id) => {
+                // The saved login token may be for the old server or account
+                self.resume_tokens.remove(&id);
+
+                // The upload watch, auto-download rules, screenshot folder,
+                // notification rules, saved workspace, and folder are managed
+                // elsewhere and aren't part of this form
+                if let Some(existing) = self.config.get_bookmark(id) {
+                    bookmark.upload_watch = existing.upload_watch.clone();
+                    bookmark.auto_downloads = existing.auto_downloads.clone();
+                    bookmark.screenshot_folder = existing.screenshot_folder.clone();
+                    bookmark.notification_rules = existing.notification_rules.clone();
+                    bookmark.workspace = existing.workspace.clone();
+                    bookmark.group = existing.group;
+                }
                 self.config.update_bookmark(id, bookmark);
             }
             BookmarkEditMode::None => {}
@@ -128,8 +227,11 @@ impl NexusApp {
             self.bookmark_edit.bookmark.username = conn.connection_info.username.clone();
             self.bookmark_edit.bookmark.password = conn.connection_info.password.clone();
             self.bookmark_edit.bookmark.nickname = conn.connection_info.nickname.clone();
-            self.bookmark_edit.bookmark.certificate_fingerprint =
-                Some(conn.connection_info.certificate_fingerprint.clone());
+            self.bookmark_edit.bookmark.use_sso = conn.connection_info.login_token.is_some();
+            self.bookmark_edit.bookmark.trust_certificate(
+                &conn.connection_info.certificate_fingerprint,
+                conn.connection_info.identity_key.as_deref(),
+            );
         }
 
         self.focused_field = InputId::BookmarkName;
@@ -141,6 +243,11 @@ impl NexusApp {
         if let Some(bookmark) = self.config.get_bookmark(id) {
             self.bookmark_edit.mode = BookmarkEditMode::Edit(id);
             self.bookmark_edit.bookmark = bookmark.clone();
+            self.bookmark_edit.fallback_addresses = bookmark.fallback_addresses.join(", ");
+            self.bookmark_edit.cached_avatar = bookmark
+                .avatar
+                .as_deref()
+                .and_then(|uri| decode_data_uri_square(uri, AVATAR_MAX_CACHE_SIZE));
             self.focused_field = InputId::BookmarkName;
 
             // Move any connection error to the edit dialog (acknowledges and clears it)
@@ -168,8 +275,11 @@ impl NexusApp {
             let port = bookmark.port;
 
             let server_address = bookmark.address.clone();
+            let fallback_addresses = bookmark.fallback_addresses.clone();
             let username = bookmark.username.clone();
             let password = bookmark.password.clone();
+            let use_sso = bookmark.use_sso;
+            let login_token = self.resume_tokens.remove(&id);
             // Use bookmark nickname, falling back to settings default
             let nickname = if bookmark.nickname.is_empty() {
                 self.config.settings.nickname.clone()
@@ -177,7 +287,11 @@ impl NexusApp {
                 Some(bookmark.nickname.clone())
             };
             let locale = get_locale().to_string();
-            let avatar = self.config.settings.avatar.clone();
+            // Use bookmark avatar, falling back to settings default
+            let avatar = bookmark
+                .avatar
+                .clone()
+                .or_else(|| self.config.settings.avatar.clone());
             let display_name = bookmark.name.clone();
 
             // Build proxy config if enabled
@@ -197,6 +311,7 @@ impl NexusApp {
                     crate::network::connect_to_server(ConnectionParams {
                         server_address,
                         port,
+                        fallback_addresses,
                         username,
                         password,
                         nickname,
@@ -204,6 +319,8 @@ impl NexusApp {
                         avatar,
                         connection_id,
                         proxy,
+                        use_sso,
+                        login_token,
                     })
                     .await
                 },
@@ -217,6 +334,29 @@ impl NexusApp {
         Task::none()
     }
 
+    /// Set the bookmark's status message after logging in
+    ///
+    /// Does nothing for connections without a bookmark or with no status set.
+    pub fn apply_bookmark_status(&mut self, connection_id: usize) {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return;
+        };
+        let Some(status) = conn
+            .bookmark_id
+            .and_then(|id| self.config.get_bookmark(id))
+            .map(|bookmark| bookmark.status.trim().to_string())
+            .filter(|status| !status.is_empty())
+        else {
+            return;
+        };
+        if let Ok(message_id) = conn.send(ClientMessage::UserStatus {
+            status: Some(status.clone()),
+        }) {
+            conn.pending_requests
+                .track(message_id, ResponseRouting::StatusResult(Some(status)));
+        }
+    }
+
     /// Delete a bookmark by ID
     pub fn handle_delete_bookmark(&mut self, id: Uuid) -> Task<Message> {
         self.config.delete_bookmark(id);
@@ -227,8 +367,13 @@ impl NexusApp {
             ));
         }
 
-        // Clean up bookmark_errors for deleted bookmark
+        // Clean up bookmark_errors and login token for deleted bookmark
         self.bookmark_errors.remove(&id);
+        self.resume_tokens.remove(&id);
+
+        // Drop the bookmark's upload watch history
+        self.upload_history.forget(id);
+        let _ = self.upload_history.save();
 
         self.bookmark_edit = BookmarkEditState::default();
         // Restore chat scroll position when closing bookmark editor
@@ -242,51 +387,69 @@ impl NexusApp {
     /// Checks which field is actually focused using async operations,
     /// then moves to the next field in sequence.
     pub fn handle_bookmark_edit_tab_pressed(&mut self) -> Task<Message> {
-        // Check focus state of all six bookmark fields in parallel
+        // Check focus state of all nine bookmark fields in parallel
         let check_name = operation::is_focused(Id::from(InputId::BookmarkName));
         let check_address = operation::is_focused(Id::from(InputId::BookmarkAddress));
         let check_port = operation::is_focused(Id::from(InputId::BookmarkPort));
+        let check_fallback_addresses =
+            operation::is_focused(Id::from(InputId::BookmarkFallbackAddresses));
         let check_username = operation::is_focused(Id::from(InputId::BookmarkUsername));
         let check_password = operation::is_focused(Id::from(InputId::BookmarkPassword));
         let check_nickname = operation::is_focused(Id::from(InputId::BookmarkNickname));
+        let check_status = operation::is_focused(Id::from(InputId::BookmarkStatus));
+        let check_download_folder =
+            operation::is_focused(Id::from(InputId::BookmarkDownloadFolder));
 
         // Batch the checks and combine results
         Task::batch([
             check_name.map(|focused| (0, focused)),
             check_address.map(|focused| (1, focused)),
             check_port.map(|focused| (2, focused)),
-            check_username.map(|focused| (3, focused)),
-            check_password.map(|focused| (4, focused)),
-            check_nickname.map(|focused| (5, focused)),
+            check_fallback_addresses.map(|focused| (3, focused)),
+            check_username.map(|focused| (4, focused)),
+            check_password.map(|focused| (5, focused)),
+            check_nickname.map(|focused| (6, focused)),
+            check_status.map(|focused| (7, focused)),
+            check_download_folder.map(|focused| (8, focused)),
         ])
         .collect()
         .map(|results: Vec<(u8, bool)>| {
             let name_focused = results.iter().any(|(i, f)| *i == 0 && *f);
             let address_focused = results.iter().any(|(i, f)| *i == 1 && *f);
             let port_focused = results.iter().any(|(i, f)| *i == 2 && *f);
-            let username_focused = results.iter().any(|(i, f)| *i == 3 && *f);
-            let password_focused = results.iter().any(|(i, f)| *i == 4 && *f);
-            let nickname_focused = results.iter().any(|(i, f)| *i == 5 && *f);
+            let fallback_addresses_focused = results.iter().any(|(i, f)| *i == 3 && *f);
+            let username_focused = results.iter().any(|(i, f)| *i == 4 && *f);
+            let password_focused = results.iter().any(|(i, f)| *i == 5 && *f);
+            let nickname_focused = results.iter().any(|(i, f)| *i == 6 && *f);
+            let status_focused = results.iter().any(|(i, f)| *i == 7 && *f);
+            let download_folder_focused = results.iter().any(|(i, f)| *i == 8 && *f);
             Message::BookmarkEditFocusResult(
                 name_focused,
                 address_focused,
                 port_focused,
+                fallback_addresses_focused,
                 username_focused,
                 password_focused,
                 nickname_focused,
+                status_focused,
+                download_folder_focused,
             )
         })
     }
 
     /// Handle focus check result for bookmark edit Tab navigation
+    #[allow(clippy::too_many_arguments)]
     pub fn handle_bookmark_edit_focus_result(
         &mut self,
         name_focused: bool,
         address_focused: bool,
         port_focused: bool,
+        fallback_addresses_focused: bool,
         username_focused: bool,
         password_focused: bool,
         nickname_focused: bool,
+        status_focused: bool,
+        download_folder_focused: bool,
     ) -> Task<Message> {
         // Determine next field based on which is currently focused
         // Note: Port is skipped because NumberInput handles its own Tab key
@@ -294,14 +457,20 @@ impl NexusApp {
             InputId::BookmarkAddress
         } else if address_focused {
             // Skip BookmarkPort (NumberInput)
-            InputId::BookmarkUsername
+            InputId::BookmarkFallbackAddresses
         } else if port_focused {
+            InputId::BookmarkFallbackAddresses
+        } else if fallback_addresses_focused {
             InputId::BookmarkUsername
         } else if username_focused {
             InputId::BookmarkPassword
         } else if password_focused {
             InputId::BookmarkNickname
         } else if nickname_focused {
+            InputId::BookmarkStatus
+        } else if status_focused {
+            InputId::BookmarkDownloadFolder
+        } else if download_folder_focused {
             // Wrap around to first field
             InputId::BookmarkName
         } else {
@@ -323,7 +492,36 @@ impl NexusApp {
         if self.bookmark_edit.bookmark.address.trim().is_empty() {
             return Some(t("err-address-required"));
         }
+        if let Some(entry) = split_fallback_addresses(&self.bookmark_edit.fallback_addresses)
+            .into_iter()
+            .find(|entry| parse_fallback_address(entry).is_none())
+        {
+            return Some(t_args(
+                "err-fallback-address-invalid",
+                &[("address", &entry)],
+            ));
+        }
+        if let Err(e) = validators::validate_status(self.bookmark_edit.bookmark.status.trim()) {
+            return Some(match e {
+                StatusError::TooLong => t_args(
+                    "err-status-too-long",
+                    &[("max", &validators::MAX_STATUS_LENGTH.to_string())],
+                ),
+                StatusError::ContainsNewlines => t("err-status-contains-newlines"),
+                StatusError::InvalidCharacters => t("err-status-invalid-characters"),
+            });
+        }
 
         None
     }
 }
+
+/// Split the fallback addresses field into its non-empty entries
+fn split_fallback_addresses(input: &str) -> Vec<String> {
+    input
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(str::to_string)
+        .collect()
+}
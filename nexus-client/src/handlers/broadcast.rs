@@ -7,7 +7,10 @@ use nexus_common::validators::{self, MessageError};
 
 use crate::NexusApp;
 use crate::i18n::{t, t_args};
-use crate::types::{ActivePanel, ChatMessage, InputId, Message};
+use crate::types::{
+    ActivePanel, AuditPermission, BroadcastDelay, BroadcastTargetKind, ChatMessage, InputId,
+    Message,
+};
 
 impl NexusApp {
     // ==================== Panel Actions ====================
@@ -58,6 +61,65 @@ impl NexusApp {
         Task::none()
     }
 
+    /// Handle broadcast target change (everyone, a channel, or a permission)
+    pub fn handle_broadcast_target_changed(
+        &mut self,
+        target: BroadcastTargetKind,
+    ) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+        {
+            conn.broadcast_target = target;
+            conn.broadcast_error = None;
+        }
+        Task::none()
+    }
+
+    /// Handle the channel picked for a channel broadcast
+    pub fn handle_broadcast_channel_selected(&mut self, channel: String) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+        {
+            conn.broadcast_channel = Some(channel);
+            conn.broadcast_error = None;
+        }
+        Task::none()
+    }
+
+    /// Handle the permission picked for a permission broadcast
+    pub fn handle_broadcast_permission_selected(
+        &mut self,
+        permission: AuditPermission,
+    ) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+        {
+            conn.broadcast_permission = Some(permission.0);
+            conn.broadcast_error = None;
+        }
+        Task::none()
+    }
+
+    /// Handle broadcast delay change
+    pub fn handle_broadcast_delay_changed(&mut self, delay: BroadcastDelay) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+        {
+            conn.broadcast_delay = delay;
+        }
+        Task::none()
+    }
+
+    /// Handle the require acknowledgement checkbox
+    pub fn handle_broadcast_require_ack_toggled(&mut self, enabled: bool) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+        {
+            conn.broadcast_require_ack = enabled;
+        }
+        Task::none()
+    }
+
     /// Handle validation of broadcast form (called on Enter when message empty)
     pub fn handle_validate_broadcast(&mut self) -> Task<Message> {
         let Some(conn_id) = self.active_connection else {
@@ -103,7 +165,22 @@ impl NexusApp {
             return self.add_broadcast_error(conn_id, error_msg);
         }
 
-        let msg = ClientMessage::UserBroadcast { message };
+        let Some(target) = conn.broadcast_target.to_target(
+            conn.broadcast_channel.as_ref(),
+            conn.broadcast_permission.as_ref(),
+        ) else {
+            if let Some(conn) = self.connections.get_mut(&conn_id) {
+                conn.broadcast_error = Some(t("err-broadcast-target-required"));
+            }
+            return Task::none();
+        };
+
+        let msg = ClientMessage::UserBroadcast {
+            message,
+            target,
+            delay: conn.broadcast_delay.as_duration_string(),
+            require_ack: conn.broadcast_require_ack,
+        };
 
         if let Err(e) = conn.send(msg) {
             let error_msg = format!("{}: {}", t("err-broadcast-send-failed"), e);
@@ -117,6 +194,42 @@ impl NexusApp {
         self.handle_show_chat_view()
     }
 
+    // ==================== Acknowledgement ====================
+
+    /// Acknowledge the oldest broadcast that asked for it
+    pub fn handle_broadcast_acknowledged(&mut self) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+        if conn.broadcast_acks.is_empty() {
+            return Task::none();
+        }
+
+        let ack = conn.broadcast_acks.remove(0);
+        let msg = ClientMessage::BroadcastAck {
+            broadcast_id: ack.broadcast_id,
+        };
+        if let Err(e) = conn.send(msg) {
+            let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+            return self.add_broadcast_error(conn_id, error_msg);
+        }
+        Task::none()
+    }
+
+    /// Close the oldest acknowledgement prompt without acknowledging
+    pub fn handle_broadcast_ack_dismissed(&mut self) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+            && !conn.broadcast_acks.is_empty()
+        {
+            conn.broadcast_acks.remove(0);
+        }
+        Task::none()
+    }
+
     // ==================== Private Helpers ====================
 
     /// Add an error message to the chat for broadcast errors
@@ -0,0 +1,86 @@
+//! Chat export handlers (save a tab's scrollback as HTML)
+
+use chrono::Local;
+use iced::Task;
+use iced_toasts::{ToastLevel, toast};
+use rfd::AsyncFileDialog;
+
+use super::files::sanitize_filename;
+use crate::NexusApp;
+use crate::chat_export::ChatExport;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message};
+use crate::views::TimestampSettings;
+
+impl NexusApp {
+    /// Export a connection's active chat tab to an HTML file
+    ///
+    /// The page is rendered right away, so it holds the messages shown at
+    /// the time of the export, then a save dialog asks where to write it.
+    pub fn export_chat(&mut self, connection_id: usize) -> Task<Message> {
+        let Some(conn) = self.connections.get(&connection_id) else {
+            return Task::none();
+        };
+
+        let tab = &conn.active_chat_tab;
+        let title = conn.tab_label(tab);
+        let exported_at = Local::now();
+        let timestamp_settings = TimestampSettings {
+            show_timestamps: true,
+            use_24_hour_time: self.config.settings.use_24_hour_time,
+            show_seconds: true,
+        };
+        let html = ChatExport {
+            title: &title,
+            server: &conn.display_name,
+            messages: conn.tab_messages(tab),
+            theme: &self.config.settings.theme.to_iced_theme(),
+            time_format: timestamp_settings.time_format(),
+            exported_at,
+        }
+        .to_html();
+        let file_name = format!(
+            "{} {}.html",
+            sanitize_filename(&title, &conn.display_name),
+            exported_at.format("%Y-%m-%d")
+        );
+
+        Task::future(async move {
+            let Some(handle) = AsyncFileDialog::new()
+                .add_filter("HTML", &["html", "htm"])
+                .set_file_name(file_name)
+                .save_file()
+                .await
+            else {
+                // User cancelled - nothing to do
+                return Message::ChatExported(connection_id, Ok(false));
+            };
+            let result = handle
+                .write(html.as_bytes())
+                .await
+                .map(|()| true)
+                .map_err(|e| e.to_string());
+            Message::ChatExported(connection_id, result)
+        })
+    }
+
+    /// Handle chat export finished (Ok(false) when the dialog was cancelled)
+    pub fn handle_chat_exported(
+        &mut self,
+        connection_id: usize,
+        result: Result<bool, String>,
+    ) -> Task<Message> {
+        match result {
+            Ok(true) => {
+                self.toasts
+                    .push(toast(&t("toast-chat-exported")).level(ToastLevel::Success));
+                Task::none()
+            }
+            Ok(false) => Task::none(),
+            Err(error) => self.add_active_tab_message(
+                connection_id,
+                ChatMessage::error(t_args("err-chat-export", &[("error", &error)])),
+            ),
+        }
+    }
+}
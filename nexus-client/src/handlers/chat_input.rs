@@ -0,0 +1,209 @@
+//! Chat input editor, Up/Down recall and multi-line paste
+
+use iced::Task;
+use iced::widget::text_editor::{self, Action, Edit, Motion};
+use nexus_common::protocol::{ChatAction, ClientMessage};
+
+use super::connection::{check_can_send_chat, validate_chat_message};
+use crate::NexusApp;
+use crate::chat_input::{MAX_PASTE_MESSAGES, paste_messages, prompted_paste, split_message};
+use crate::i18n::t;
+use crate::snippet::{SNIPPET_EXPIRY_SECS, snippet_from_paste};
+use crate::types::{ChatTab, InputId, Message, PendingRequests, ResponseRouting};
+
+impl NexusApp {
+    /// Show the active connection's input text in the chat input editor
+    ///
+    /// Runs after every update; the editor is rebuilt (cursor at the end)
+    /// only when the text was changed by something other than typing.
+    pub fn sync_chat_input(&mut self) {
+        let current = self
+            .active_connection
+            .and_then(|id| Some((id, self.connections.get(&id)?.message_input.as_str())));
+        let synced = self
+            .chat_input_synced
+            .as_ref()
+            .map(|(id, text)| (*id, text.as_str()));
+        if current == synced {
+            return;
+        }
+
+        let text = current.map(|(_, text)| text).unwrap_or_default();
+        self.chat_input_content = text_editor::Content::with_text(text);
+        self.chat_input_content
+            .perform(Action::Move(Motion::DocumentEnd));
+        self.chat_input_synced = current.map(|(id, text)| (id, text.to_string()));
+    }
+
+    /// Handle an edit or cursor movement in the chat input
+    ///
+    /// Pasting two or more lines, or more than fits in one message, into an
+    /// empty input on a channel or user message tab asks how to send them
+    /// instead of pasting.
+    pub fn handle_chat_input_action(&mut self, action: Action) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        if let Action::Edit(Edit::Paste(text)) = &action
+            && conn.message_input.is_empty()
+            && conn.active_chat_tab != ChatTab::Console
+            && let Some(text) = prompted_paste(text, conn.max_message_length())
+        {
+            conn.pending_paste = Some(text);
+            return Task::none();
+        }
+
+        let is_edit = action.is_edit();
+        self.chat_input_content.perform(action);
+        if !is_edit {
+            return Task::none();
+        }
+
+        // Reset tab completion when input changes (user typed something)
+        conn.tab_completion = None;
+        conn.message_input = self.chat_input_content.text();
+        self.chat_input_synced = Some((conn_id, conn.message_input.clone()));
+        self.focused_field = InputId::ChatInput;
+        // Typing is captured by the input, so it never reaches the event listener
+        self.note_user_input();
+        Task::none()
+    }
+
+    /// Recall the input sent before the one shown (Up)
+    pub fn handle_chat_input_history_previous(&mut self) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+            && let Some(input) = conn
+                .input_history
+                .entry(conn.active_chat_tab.clone())
+                .or_default()
+                .previous(&conn.message_input)
+        {
+            conn.message_input = input;
+            conn.tab_completion = None;
+        }
+        Task::none()
+    }
+
+    /// Recall the input sent after the one shown, then the unsent draft (Down)
+    pub fn handle_chat_input_history_next(&mut self) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+            && let Some(input) = conn
+                .input_history
+                .get_mut(&conn.active_chat_tab)
+                .and_then(|history| history.next())
+        {
+            conn.message_input = input;
+            conn.tab_completion = None;
+        }
+        Task::none()
+    }
+
+    // ==================== Multi-line Paste ====================
+
+    /// Send each pasted line as its own message (long lines in parts)
+    pub fn handle_paste_as_messages(&mut self) -> Task<Message> {
+        let Some((text, max_length)) = self.take_pending_paste() else {
+            return Task::none();
+        };
+        let mut messages = paste_messages(&text, max_length);
+        messages.truncate(MAX_PASTE_MESSAGES);
+        self.send_pasted(messages)
+    }
+
+    /// Send the pasted lines as one multi-line message, in parts if too long
+    pub fn handle_paste_as_one_message(&mut self) -> Task<Message> {
+        let Some((text, max_length)) = self.take_pending_paste() else {
+            return Task::none();
+        };
+        let mut messages = split_message(&text, max_length);
+        messages.truncate(MAX_PASTE_MESSAGES);
+        self.send_pasted(messages)
+    }
+
+    /// Share the pasted text as a snippet, posting its link to the active
+    /// tab once the server has stored it
+    pub fn handle_paste_as_snippet(&mut self) -> Task<Message> {
+        let Some((text, _)) = self.take_pending_paste() else {
+            return Task::none();
+        };
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        if let Err(error_msg) = check_can_send_chat(conn) {
+            return self.add_chat_error(conn_id, error_msg);
+        }
+
+        let (content, language) = snippet_from_paste(&text);
+        let message = ClientMessage::SnippetCreate {
+            content: content.to_string(),
+            language: language.map(str::to_string),
+            expires_in: SNIPPET_EXPIRY_SECS,
+        };
+        match conn.send(message) {
+            Ok(message_id) => {
+                let tab = conn.active_chat_tab.clone();
+                conn.pending_requests
+                    .track(message_id, ResponseRouting::PostSnippetLink(tab));
+                Task::none()
+            }
+            Err(e) => {
+                let error_msg = format!("{}: {}", t("err-send-failed"), e);
+                self.add_chat_error(conn_id, error_msg)
+            }
+        }
+    }
+
+    /// Close the paste prompt without sending
+    pub fn handle_paste_cancelled(&mut self) -> Task<Message> {
+        self.take_pending_paste();
+        Task::none()
+    }
+
+    /// Take the active connection's pasted text waiting to be sent, with
+    /// the connection's message length limit
+    fn take_pending_paste(&mut self) -> Option<(String, usize)> {
+        let conn_id = self.active_connection?;
+        let conn = self.connections.get_mut(&conn_id)?;
+        let text = conn.pending_paste.take()?;
+        Some((text, conn.max_message_length()))
+    }
+
+    /// Send pasted messages to the active tab
+    ///
+    /// Nothing is sent if any of them is invalid.
+    fn send_pasted(&mut self, messages: Vec<String>) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get(&conn_id) else {
+            return Task::none();
+        };
+
+        if let Err(error_msg) = check_can_send_chat(conn) {
+            return self.add_chat_error(conn_id, error_msg);
+        }
+        if let Some(error_msg) = messages
+            .iter()
+            .find_map(|message| validate_chat_message(message, conn.max_message_length()).err())
+        {
+            return self.add_chat_error(conn_id, error_msg);
+        }
+
+        let tab = conn.active_chat_tab.clone();
+        for message in messages {
+            // If the connection is down, the message waits in the send queue
+            self.send_or_queue_chat_message(conn_id, tab.clone(), message, ChatAction::Normal);
+        }
+        Task::none()
+    }
+}
@@ -0,0 +1,219 @@
+//! Chat tabs popped out into their own windows
+
+use iced::Task;
+use iced::widget::operation;
+use iced::window;
+use nexus_common::protocol::ChatAction;
+
+use super::connection::validate_chat_message;
+use crate::NexusApp;
+use crate::commands::{self, ParseResult};
+use crate::i18n::{t, t_args};
+use crate::style::{
+    CHAT_WINDOW_HEIGHT, CHAT_WINDOW_HEIGHT_MIN, CHAT_WINDOW_WIDTH, CHAT_WINDOW_WIDTH_MIN,
+    WINDOW_TITLE,
+};
+use crate::types::{ChatMessage, ChatTab, ChatWindow, Message};
+use crate::views::constants::{PERMISSION_CHAT_SEND, PERMISSION_USER_MESSAGE};
+
+impl NexusApp {
+    /// Pop a tab of the active connection out into its own window
+    ///
+    /// Focuses the tab's window instead if it's already popped out. The
+    /// Console tab stays in the main window.
+    pub fn handle_pop_out_chat_tab(&mut self, tab: ChatTab) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        if tab == ChatTab::Console {
+            return Task::none();
+        }
+
+        if let Some(id) = self.chat_window_for(conn_id, &tab) {
+            return window::gain_focus(id);
+        }
+
+        let (id, open) = window::open(window::Settings {
+            size: iced::Size::new(CHAT_WINDOW_WIDTH, CHAT_WINDOW_HEIGHT),
+            min_size: Some(iced::Size::new(
+                CHAT_WINDOW_WIDTH_MIN,
+                CHAT_WINDOW_HEIGHT_MIN,
+            )),
+            exit_on_close_request: false,
+            #[cfg(target_os = "linux")]
+            platform_specific: window::settings::PlatformSpecific {
+                application_id: "nexus".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+
+        let chat_window = ChatWindow::new(conn_id, tab);
+        let focus_input = operation::focus(chat_window.input_id.clone());
+        self.chat_windows.insert(id, chat_window);
+
+        open.discard().chain(focus_input)
+    }
+
+    /// Handle a chat window's message input changing
+    pub fn handle_chat_window_input_changed(
+        &mut self,
+        id: window::Id,
+        value: String,
+    ) -> Task<Message> {
+        if let Some(chat_window) = self.chat_windows.get_mut(&id) {
+            chat_window.message_input = value;
+        }
+        Task::none()
+    }
+
+    /// Send the message typed in a chat window to its tab
+    ///
+    /// Only plain messages and `/me` work here; other commands need the
+    /// main window, where they act on the active tab.
+    pub fn handle_chat_window_send_pressed(&mut self, id: window::Id) -> Task<Message> {
+        let Some(chat_window) = self.chat_windows.get(&id) else {
+            return Task::none();
+        };
+        let connection_id = chat_window.connection_id;
+        let tab = chat_window.tab.clone();
+
+        let expanded = commands::expand_alias(
+            &chat_window.message_input,
+            &self.config.settings.command_aliases,
+        );
+        let input = expanded.as_deref().unwrap_or(&chat_window.message_input);
+        let (message, action) = match commands::parse_input(input) {
+            ParseResult::Empty => return Task::none(),
+            ParseResult::Message(message, action) => (message, action),
+            ParseResult::Command(command) if command.name == "me" => {
+                if command.args.is_empty() {
+                    let error_msg = t_args("cmd-me-usage", &[("command", &command.name)]);
+                    return self.add_chat_window_error(connection_id, &tab, error_msg);
+                }
+                (command.args.join(" "), ChatAction::Me)
+            }
+            ParseResult::Command(_) => {
+                let error_msg = t("err-chat-window-command");
+                return self.add_chat_window_error(connection_id, &tab, error_msg);
+            }
+        };
+
+        let Some(conn) = self.connections.get(&connection_id) else {
+            return Task::none();
+        };
+        let has_permission = match &tab {
+            ChatTab::Console => false,
+            ChatTab::Channel(_) => conn.has_permission(PERMISSION_CHAT_SEND),
+            ChatTab::UserMessage(_) => conn.has_permission(PERMISSION_USER_MESSAGE),
+            ChatTab::Group(_) => true,
+        };
+        if !has_permission {
+            return self.add_chat_window_error(connection_id, &tab, t("err-no-chat-permission"));
+        }
+
+        if let Err(error_msg) = validate_chat_message(&message, conn.max_message_length()) {
+            return self.add_chat_window_error(connection_id, &tab, error_msg);
+        }
+
+        if let Some(chat_window) = self.chat_windows.get_mut(&id) {
+            chat_window.message_input.clear();
+        }
+
+        // If the connection is down, the message waits in the send queue
+        self.send_or_queue_chat_message(connection_id, tab, message, action);
+        Task::none()
+    }
+
+    /// Handle a chat window gaining or losing focus
+    pub fn handle_chat_window_focus(&mut self, id: window::Id, focused: bool) -> Task<Message> {
+        if let Some(chat_window) = self.chat_windows.get_mut(&id) {
+            chat_window.focused = focused;
+        }
+        Task::none()
+    }
+
+    /// Close a chat window (the tab stays open in the main window)
+    pub fn close_chat_window(&mut self, id: window::Id) -> Task<Message> {
+        self.chat_windows.remove(&id);
+        window::close(id)
+    }
+
+    /// Close the chat windows of a connection's tab, or of all its tabs
+    pub fn close_chat_windows(
+        &mut self,
+        connection_id: usize,
+        tab: Option<&ChatTab>,
+    ) -> Task<Message> {
+        let ids: Vec<window::Id> = self
+            .chat_windows
+            .iter()
+            .filter(|(_, w)| {
+                w.connection_id == connection_id && tab.is_none_or(|only| &w.tab == only)
+            })
+            .map(|(id, _)| *id)
+            .collect();
+
+        Task::batch(ids.into_iter().map(|id| self.close_chat_window(id)))
+    }
+
+    /// Whether a tab is popped out in a window that has focus
+    pub fn chat_window_focused_on(&self, connection_id: usize, tab: &ChatTab) -> bool {
+        self.chat_windows
+            .values()
+            .any(|w| w.focused && w.connection_id == connection_id && &w.tab == tab)
+    }
+
+    /// Window title: the app name for the main window, the tab and server for chat windows
+    pub fn window_title(&self, id: window::Id) -> String {
+        let Some(chat_window) = self.chat_windows.get(&id) else {
+            return WINDOW_TITLE.to_string();
+        };
+        let conn = self.connections.get(&chat_window.connection_id);
+        let label = match &chat_window.tab {
+            ChatTab::Console => t("console-tab"),
+            ChatTab::Channel(name) | ChatTab::UserMessage(name) => name.clone(),
+            ChatTab::Group(group_id) => conn
+                .and_then(|conn| {
+                    let group = conn.groups.get(group_id)?;
+                    Some(group.label(&conn.nickname))
+                })
+                .unwrap_or_default(),
+        };
+        match conn {
+            Some(conn) => format!("{} - {}", label, conn.display_name),
+            None => label,
+        }
+    }
+
+    /// The window a tab is popped out in, if any
+    fn chat_window_for(&self, connection_id: usize, tab: &ChatTab) -> Option<window::Id> {
+        self.chat_windows
+            .iter()
+            .find(|(_, w)| w.connection_id == connection_id && &w.tab == tab)
+            .map(|(id, _)| *id)
+    }
+
+    /// Show an error in a popped-out tab
+    fn add_chat_window_error(
+        &mut self,
+        connection_id: usize,
+        tab: &ChatTab,
+        message: String,
+    ) -> Task<Message> {
+        match tab {
+            ChatTab::Console => {
+                self.add_console_message(connection_id, ChatMessage::error(message))
+            }
+            ChatTab::Channel(channel) => {
+                self.add_channel_message(connection_id, channel, ChatMessage::error(message))
+            }
+            ChatTab::UserMessage(nickname) => {
+                self.add_user_message(connection_id, nickname, ChatMessage::error(message))
+            }
+            ChatTab::Group(group_id) => {
+                self.add_group_message(connection_id, *group_id, ChatMessage::error(message))
+            }
+        }
+    }
+}
@@ -0,0 +1,28 @@
+//! Chat code block handlers
+
+use iced::Task;
+
+use crate::NexusApp;
+use crate::i18n::t;
+use crate::types::Message;
+
+impl NexusApp {
+    /// Copy a code block's text to the clipboard
+    pub fn handle_copy_code_block(&mut self, code: String) -> Task<Message> {
+        let toast_text = t("toast-copied");
+        iced::clipboard::write(code).chain(Task::done(Message::ShowToast(toast_text)))
+    }
+
+    /// Expand a long code block past its first lines, or collapse it again
+    ///
+    /// Takes the connection ID since the block may be in a popped out chat
+    /// window for a connection other than the active one.
+    pub fn handle_toggle_code_block(&mut self, connection_id: usize, key: u64) -> Task<Message> {
+        if let Some(conn) = self.connections.get_mut(&connection_id)
+            && !conn.expanded_code_blocks.remove(&key)
+        {
+            conn.expanded_code_blocks.insert(key);
+        }
+        Task::none()
+    }
+}
@@ -1,5 +1,7 @@
 //! Connection and chat message handlers
 
+use std::time::Instant;
+
 use iced::Task;
 use iced::widget::{Id, operation, scrollable};
 use nexus_common::protocol::ClientMessage;
@@ -8,11 +10,13 @@ use nexus_common::validators::{self, MessageError};
 use crate::commands::{
     self, ParseResult, complete_channel, complete_command, complete_nickname, last_word,
 };
+use crate::drafts::DraftContext;
 use crate::i18n::{get_locale, t, t_args};
 use crate::network::{ConnectionParams, ProxyConfig};
+use crate::quick_switcher::{QuickSwitchTarget, note_recent};
 use crate::types::{
-    ActivePanel, ChatMessage, ChatTab, InputId, Message, PendingRequests, ResponseRouting,
-    ScrollableId, TabCompletionState,
+    ActivePanel, ChatMessage, ChatTab, InputId, LanServer, Message, PendingRequests,
+    ResponseRouting, ScrollableId, ServerConnection, TabCompletionState,
 };
 use crate::views::constants::{PERMISSION_CHAT_SEND, PERMISSION_USER_MESSAGE};
 use crate::{NexusApp, network};
@@ -20,6 +24,53 @@ use crate::{NexusApp, network};
 /// Threshold for considering scroll position "at bottom" (0.0 = top, 1.0 = bottom)
 const SCROLL_BOTTOM_THRESHOLD: f32 = 0.99;
 
+/// Validate a chat or user message, returning the translated error
+///
+/// `max_length` is the connection's limit (see
+/// `ServerConnection::max_message_length`).
+pub(crate) fn validate_chat_message(message: &str, max_length: usize) -> Result<(), String> {
+    let result = if message.len() > max_length {
+        Err(MessageError::TooLong)
+    } else {
+        validators::validate_multiline_message(message)
+    };
+    result.map_err(|e| match e {
+        MessageError::Empty => t("err-message-empty"),
+        MessageError::TooLong => t_args(
+            "err-message-too-long",
+            &[
+                ("length", &message.len().to_string()),
+                ("max", &max_length.to_string()),
+            ],
+        ),
+        MessageError::ContainsNewlines => t("err-message-contains-newlines"),
+        MessageError::InvalidCharacters => t("err-message-invalid-characters"),
+    })
+}
+
+/// Check that the active tab of a connection takes typed messages
+///
+/// The Console only takes commands, and channels and user messages need
+/// their send permission. Group members can always talk in their groups.
+pub(crate) fn check_can_send_chat(conn: &ServerConnection) -> Result<(), String> {
+    let has_permission = match &conn.active_chat_tab {
+        ChatTab::Console => {
+            return Err(t_args(
+                "err-console-no-send",
+                &[("join", "join"), ("msg", "msg")],
+            ));
+        }
+        ChatTab::Channel(_) => conn.has_permission(PERMISSION_CHAT_SEND),
+        ChatTab::UserMessage(_) => conn.has_permission(PERMISSION_USER_MESSAGE),
+        ChatTab::Group(_) => true,
+    };
+    if has_permission {
+        Ok(())
+    } else {
+        Err(t("err-no-chat-permission"))
+    }
+}
+
 impl NexusApp {
     // ==================== Connection Form Fields ====================
 
@@ -29,6 +80,12 @@ impl NexusApp {
         Task::none()
     }
 
+    /// Handle single sign-on checkbox toggle
+    pub fn handle_use_sso_toggled(&mut self, enabled: bool) -> Task<Message> {
+        self.connection_form.use_sso = enabled;
+        Task::none()
+    }
+
     /// Handle password field change
     pub fn handle_password_changed(&mut self, password: String) -> Task<Message> {
         self.connection_form.password = password;
@@ -81,6 +138,48 @@ impl NexusApp {
 
     /// Handle connect button press
     pub fn handle_connect_pressed(&mut self) -> Task<Message> {
+        self.connect_from_form()
+    }
+
+    /// Handle Connect pressed on a server found on the local network
+    ///
+    /// Fills in the server details and connects with the credentials in the form.
+    pub fn handle_connect_to_lan_server(&mut self, fullname: String) -> Task<Message> {
+        let Some(server) = self
+            .connection_form
+            .lan_servers
+            .iter()
+            .find(|s| s.fullname == fullname)
+            .cloned()
+        else {
+            return Task::none();
+        };
+        self.connection_form.server_name = server.name;
+        self.connection_form.server_address = server.address;
+        self.connection_form.port = server.port;
+        self.connect_from_form()
+    }
+
+    /// Handle LAN discovery (re)starting
+    pub fn handle_lan_discovery_started(&mut self) -> Task<Message> {
+        self.connection_form.lan_servers.clear();
+        Task::none()
+    }
+
+    /// Handle a server found on the local network
+    pub fn handle_lan_server_found(&mut self, server: LanServer) -> Task<Message> {
+        self.connection_form.add_lan_server(server);
+        Task::none()
+    }
+
+    /// Handle a server leaving the local network
+    pub fn handle_lan_server_lost(&mut self, fullname: String) -> Task<Message> {
+        self.connection_form.remove_lan_server(&fullname);
+        Task::none()
+    }
+
+    /// Connect to the server in the connection form
+    fn connect_from_form(&mut self) -> Task<Message> {
         if self.connection_form.is_connecting {
             return Task::none();
         }
@@ -94,6 +193,7 @@ impl NexusApp {
         let server_address = self.connection_form.server_address.clone();
         let username = self.connection_form.username.clone();
         let password = self.connection_form.password.clone();
+        let use_sso = self.connection_form.use_sso;
         // Use nickname from form, falling back to settings default
         let nickname = if self.connection_form.nickname.is_empty() {
             self.config.settings.nickname.clone()
@@ -122,6 +222,7 @@ impl NexusApp {
                 network::connect_to_server(ConnectionParams {
                     server_address,
                     port,
+                    fallback_addresses: Vec::new(),
                     username,
                     password,
                     nickname,
@@ -129,6 +230,8 @@ impl NexusApp {
                     avatar,
                     connection_id,
                     proxy,
+                    use_sso,
+                    login_token: None,
                 })
                 .await
             },
@@ -141,6 +244,14 @@ impl NexusApp {
         // Clean up voice session first (before removing connection)
         self.cleanup_voice_session(connection_id);
 
+        // Keep unsent drafts and the workspace for the next connection to this server
+        self.store_connection_drafts(connection_id);
+        let _ = self.drafts.save();
+        self.store_connection_workspace(connection_id);
+        let _ = self.config.save();
+
+        let close_windows = self.close_chat_windows(connection_id, None);
+
         if let Some(conn) = self.connections.remove(&connection_id) {
             let shutdown_arc = conn.shutdown_handle.clone();
             tokio::spawn(async move {
@@ -170,8 +281,12 @@ impl NexusApp {
             // Update tray icon state (Windows/Linux only)
             #[cfg(not(target_os = "macos"))]
             self.update_tray_state();
+
+            // Drop this connection's unreads from the dock badge (macOS only)
+            #[cfg(target_os = "macos")]
+            self.update_dock_badge();
         }
-        Task::none()
+        close_windows
     }
 
     /// Switch active view to a different connection
@@ -181,11 +296,33 @@ impl NexusApp {
         };
 
         self.active_connection = Some(connection_id);
+        self.restore_workspace_user_list(connection_id);
 
         // Scroll chat and focus input (app-wide panels like Settings/About persist)
         self.scroll_chat_if_visible(true)
     }
 
+    /// Ping every connection for the server list health indicator
+    ///
+    /// A ping still unanswered from the previous tick counts as dropped.
+    pub fn handle_health_ping_tick(&mut self) -> Task<Message> {
+        for conn in self.connections.values_mut() {
+            if let Some(message_id) = conn.health.outstanding.take() {
+                conn.pending_requests.remove(&message_id);
+                conn.health.record_drop();
+            }
+
+            // Capture time before send for accurate latency measurement
+            let sent_time = Instant::now();
+            if let Ok(message_id) = conn.send(ClientMessage::Ping) {
+                conn.pending_requests
+                    .track(message_id, ResponseRouting::HealthPing(sent_time));
+                conn.health.outstanding = Some(message_id);
+            }
+        }
+        Task::none()
+    }
+
     // ==================== Chat Helpers ====================
 
     /// Scroll chat if chat view is visible (no panel overlay).
@@ -193,12 +330,15 @@ impl NexusApp {
     /// Use this for background events (e.g., incoming messages) that shouldn't
     /// close panels or steal focus from panel input fields.
     ///
-    /// If `focus` is true, also focuses the chat input field.
+    /// If `focus` is true, also focuses the chat input field (unless a panel
+    /// is open beside chat in split view).
     pub fn scroll_chat_if_visible(&self, focus: bool) -> Task<Message> {
-        // Don't scroll or steal focus if a panel is open
-        if self.active_panel() != ActivePanel::None {
+        // Don't scroll or steal focus if a panel covers chat
+        let panel = self.active_panel();
+        if panel != ActivePanel::None && !self.chat_beside_panel(panel) {
             return Task::none();
         }
+        let focus = focus && panel == ActivePanel::None;
 
         let scroll_state = self
             .active_connection
@@ -237,8 +377,9 @@ impl NexusApp {
         &mut self,
         viewport: iced::widget::scrollable::Viewport,
     ) -> Task<Message> {
-        // Only track scroll when chat view is active (no panel overlay)
-        if self.active_panel() != ActivePanel::None {
+        // Only track scroll when chat view is visible (no panel overlay)
+        let panel = self.active_panel();
+        if panel != ActivePanel::None && !self.chat_beside_panel(panel) {
             return Task::none();
         }
 
@@ -323,7 +464,8 @@ impl NexusApp {
             conn.unread_tabs.remove(&tab);
             conn.scroll_states.remove(&tab);
 
-            if conn.active_chat_tab == tab {
+            let was_active = conn.active_chat_tab == tab;
+            if was_active {
                 // Move to previous tab in list (user message tabs are at the end)
                 // Fall back to last channel tab, or console if no channels
                 let prev_tab = if let Some(last_channel) = conn.channel_tabs.last() {
@@ -331,30 +473,90 @@ impl NexusApp {
                 } else {
                     ChatTab::Console
                 };
-                conn.active_chat_tab = prev_tab;
-                return self.handle_show_chat_view();
+                conn.set_active_chat_tab(prev_tab);
+            }
+
+            // Drop any unsent draft or queued messages for this tab
+            conn.send_queue.retain(|queued| queued.tab != tab);
+            conn.drafts.remove(&DraftContext::Chat(tab.clone()));
+
+            #[cfg(target_os = "macos")]
+            self.update_dock_badge();
+
+            // Close the tab's window if it was popped out
+            let close_window = self.close_chat_windows(conn_id, Some(&tab));
+
+            if was_active {
+                return Task::batch([close_window, self.handle_show_chat_view()]);
             }
 
             // Even when closing a non-active tab, we need to restore scroll position
             // because Iced may reset the scrollable when the tab bar re-renders
-            return self.scroll_chat_if_visible(false);
+            return Task::batch([close_window, self.scroll_chat_if_visible(false)]);
         }
         Task::none()
     }
 
-    /// Handle chat message input change
+    /// Close a group conversation tab, leaving the group
+    pub fn handle_close_group_tab(&mut self, group_id: u64) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+
+        // If the connection is down, the server has already taken us out
+        if let Some(conn) = self.connections.get_mut(&conn_id) {
+            let _ = conn.send(ClientMessage::GroupMessageLeave { group_id });
+        }
+
+        self.remove_group_tab(conn_id, group_id)
+    }
+
+    /// Remove a group conversation tab and its messages
     ///
-    /// Also resets tab completion state since the input has changed.
-    pub fn handle_message_input_changed(&mut self, input: String) -> Task<Message> {
-        if let Some(conn_id) = self.active_connection
-            && let Some(conn) = self.connections.get_mut(&conn_id)
-        {
-            // Reset tab completion when input changes (user typed something)
-            conn.tab_completion = None;
-            conn.message_input = input;
+    /// Used when we close the tab and when the server says we're no longer a
+    /// member (we left from another session).
+    pub fn remove_group_tab(&mut self, conn_id: usize, group_id: u64) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        conn.group_tabs.retain(|id| *id != group_id);
+        conn.groups.remove(&group_id);
+
+        let tab = ChatTab::Group(group_id);
+        conn.unread_tabs.remove(&tab);
+        conn.scroll_states.remove(&tab);
+
+        let was_active = conn.active_chat_tab == tab;
+        if was_active {
+            // Move to the last user message tab, then channel tab, then console
+            let prev_tab = if let Some(nickname) = conn.user_message_tabs.last() {
+                ChatTab::UserMessage(nickname.clone())
+            } else if let Some(last_channel) = conn.channel_tabs.last() {
+                ChatTab::Channel(last_channel.clone())
+            } else {
+                ChatTab::Console
+            };
+            conn.set_active_chat_tab(prev_tab);
         }
-        self.focused_field = InputId::ChatInput;
-        Task::none()
+
+        // Drop any unsent draft or queued messages for this tab
+        conn.send_queue.retain(|queued| queued.tab != tab);
+        conn.drafts.remove(&DraftContext::Chat(tab.clone()));
+
+        #[cfg(not(target_os = "macos"))]
+        self.update_tray_state();
+
+        #[cfg(target_os = "macos")]
+        self.update_dock_badge();
+
+        // Close the tab's window if it was popped out
+        let close_window = self.close_chat_windows(conn_id, Some(&tab));
+
+        if was_active && self.active_connection == Some(conn_id) {
+            return Task::batch([close_window, self.handle_show_chat_view()]);
+        }
+        Task::batch([close_window, self.scroll_chat_if_visible(false)])
     }
 
     /// Handle Tab key for nickname completion in chat
@@ -396,7 +598,12 @@ impl NexusApp {
 
         // Case 1: Command completion - input is "/" or "/prefix" with no space
         if input.starts_with('/') && !input.contains(' ') {
-            if let Some(matches) = complete_command(&input[1..], conn.is_admin, &conn.permissions) {
+            if let Some(matches) = complete_command(
+                &input[1..],
+                conn.is_admin,
+                &conn.permissions,
+                &self.config.settings.command_aliases,
+            ) {
                 conn.message_input = format!("/{}", matches[0]);
                 conn.tab_completion = Some(TabCompletionState::new(matches, 1)); // 1 to keep the /
                 return operation::move_cursor_to_end(Id::from(InputId::ChatInput));
@@ -449,103 +656,50 @@ impl NexusApp {
         };
 
         let input = conn.message_input.clone();
+        let expanded = commands::expand_alias(&input, &self.config.settings.command_aliases);
 
-        // Parse input for commands
-        match commands::parse_input(&input) {
+        // Parse input for commands (after replacing a user alias)
+        match commands::parse_input(expanded.as_deref().unwrap_or(&input)) {
             ParseResult::Empty => Task::none(),
             ParseResult::Command(command) => {
                 // Clear input and tab completion state, then execute command
                 if let Some(conn) = self.connections.get_mut(&conn_id) {
+                    conn.input_history
+                        .entry(conn.active_chat_tab.clone())
+                        .or_default()
+                        .record(&input);
                     conn.message_input.clear();
                     conn.tab_completion = None;
                 }
                 commands::execute_command(self, conn_id, command)
             }
             ParseResult::Message(message, action) => {
-                // Console tab doesn't allow plain text - show specific error
-                if conn.active_chat_tab == ChatTab::Console {
-                    return self.add_chat_error(
-                        conn_id,
-                        t_args("err-console-no-send", &[("join", "join"), ("msg", "msg")]),
-                    );
-                }
-
-                // Check permission before sending
-                let has_permission = match &conn.active_chat_tab {
-                    ChatTab::Console => unreachable!(), // Handled above
-                    ChatTab::Channel(_) => conn.has_permission(PERMISSION_CHAT_SEND),
-                    ChatTab::UserMessage(_) => conn.has_permission(PERMISSION_USER_MESSAGE),
-                };
-
-                if !has_permission {
-                    return self.add_chat_error(conn_id, t("err-no-chat-permission"));
+                // Console tab doesn't allow plain text, and the other tabs
+                // need their send permission
+                if let Err(error_msg) = check_can_send_chat(conn) {
+                    return self.add_chat_error(conn_id, error_msg);
                 }
 
                 // Validate message content using shared validators
-                if let Err(e) = validators::validate_message(&message) {
-                    let error_msg = match e {
-                        MessageError::Empty => t("err-message-empty"),
-                        MessageError::TooLong => t_args(
-                            "err-message-too-long",
-                            &[
-                                ("length", &message.len().to_string()),
-                                ("max", &validators::MAX_MESSAGE_LENGTH.to_string()),
-                            ],
-                        ),
-                        MessageError::ContainsNewlines => t("err-message-contains-newlines"),
-                        MessageError::InvalidCharacters => t("err-message-invalid-characters"),
-                    };
+                if let Err(error_msg) = validate_chat_message(&message, conn.max_message_length()) {
                     return self.add_chat_error(conn_id, error_msg);
                 }
 
                 // Re-borrow conn after potential mutable borrow above
-                let Some(conn) = self.connections.get(&conn_id) else {
+                let Some(conn) = self.connections.get_mut(&conn_id) else {
                     return Task::none();
                 };
+                let tab = conn.active_chat_tab.clone();
+                conn.input_history
+                    .entry(tab.clone())
+                    .or_default()
+                    .record(&input);
+                conn.message_input.clear();
+                conn.tab_completion = None;
 
-                let (msg, pm_nickname) = match &conn.active_chat_tab {
-                    ChatTab::Console => unreachable!(), // Handled at start of Message branch
-                    ChatTab::Channel(channel) => (
-                        ClientMessage::ChatSend {
-                            message,
-                            action,
-                            channel: channel.clone(),
-                        },
-                        None,
-                    ),
-                    ChatTab::UserMessage(nickname) => (
-                        ClientMessage::UserMessage {
-                            to_nickname: nickname.clone(),
-                            message,
-                            action,
-                        },
-                        Some(nickname.clone()),
-                    ),
-                };
-
-                let send_result = conn.send(msg);
-
-                match send_result {
-                    Ok(message_id) => {
-                        if let Some(conn) = self.connections.get_mut(&conn_id) {
-                            conn.message_input.clear();
-                            conn.tab_completion = None;
-
-                            // Track PM messages so errors go to the correct tab
-                            if let Some(nickname) = pm_nickname {
-                                conn.pending_requests.track(
-                                    message_id,
-                                    ResponseRouting::ShowErrorInMessageTab(nickname),
-                                );
-                            }
-                        }
-                        Task::none()
-                    }
-                    Err(e) => {
-                        let error_msg = format!("{}: {}", t("err-send-failed"), e);
-                        self.add_chat_error(conn_id, error_msg)
-                    }
-                }
+                // If the connection is down, the message waits in the send queue
+                self.send_or_queue_chat_message(conn_id, tab, message, action);
+                Task::none()
             }
         }
     }
@@ -559,13 +713,21 @@ impl NexusApp {
             return Task::none();
         };
 
-        // Check if we're clearing a user message unread (for tray update)
+        // Check if we're clearing a user or group message unread (for tray update)
         #[cfg(not(target_os = "macos"))]
-        let was_unread_user_message =
-            matches!(&tab, ChatTab::UserMessage(_)) && conn.unread_tabs.contains(&tab);
+        let was_unread_user_message = matches!(&tab, ChatTab::UserMessage(_) | ChatTab::Group(_))
+            && conn.unread_tabs.contains(&tab);
 
         conn.unread_tabs.remove(&tab);
-        conn.active_chat_tab = tab;
+        conn.mention_tabs.remove(&tab);
+        conn.set_active_chat_tab(tab.clone());
+        if let ChatTab::UserMessage(nickname) = &tab {
+            self.send_read_receipt(conn_id, nickname);
+        }
+        note_recent(
+            &mut self.ui_state.recent_switches,
+            QuickSwitchTarget::Tab(conn_id, tab),
+        );
 
         // Update tray icon state if we cleared a user message unread (Windows/Linux only)
         #[cfg(not(target_os = "macos"))]
@@ -573,6 +735,9 @@ impl NexusApp {
             self.update_tray_state();
         }
 
+        #[cfg(target_os = "macos")]
+        self.update_dock_badge();
+
         self.handle_show_chat_view()
     }
 
@@ -654,10 +819,14 @@ impl NexusApp {
         operation::focus(Id::from(next_field))
     }
 
-    // ==================== Private Helpers ====================
+    // ==================== Helpers ====================
 
     /// Add an error message to the chat
-    fn add_chat_error(&mut self, connection_id: usize, message: String) -> Task<Message> {
+    pub(crate) fn add_chat_error(
+        &mut self,
+        connection_id: usize,
+        message: String,
+    ) -> Task<Message> {
         self.add_active_tab_message(connection_id, ChatMessage::error(message))
     }
 }
@@ -70,6 +70,20 @@ impl NexusApp {
         Task::none()
     }
 
+    /// Periodic refresh while the transfers tab is open
+    ///
+    /// Skipped while a previous request is still outstanding so slow servers
+    /// don't accumulate a backlog of monitor requests.
+    pub fn handle_connection_monitor_tick(&mut self) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get(&conn_id)
+            && conn.connection_monitor.loading
+        {
+            return Task::none();
+        }
+        self.handle_refresh_connection_monitor()
+    }
+
     /// Handle Connection Monitor response from server
     pub fn handle_connection_monitor_response(
         &mut self,
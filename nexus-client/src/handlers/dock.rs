@@ -0,0 +1,24 @@
+//! Dock badge handlers (macOS only)
+
+use crate::NexusApp;
+use crate::types::ChatTab;
+
+impl NexusApp {
+    /// Update the dock badge with unread user and group messages and channel mentions
+    pub fn update_dock_badge(&self) {
+        let count = self
+            .connections
+            .values()
+            .map(|conn| {
+                conn.unread_tabs
+                    .iter()
+                    .filter(|tab| {
+                        matches!(tab, ChatTab::UserMessage(_) | ChatTab::Group(_))
+                            || conn.mention_tabs.contains(tab)
+                    })
+                    .count()
+            })
+            .sum();
+        crate::dock::set_unread(count);
+    }
+}
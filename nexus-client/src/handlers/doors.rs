@@ -0,0 +1,86 @@
+//! Doors panel handlers
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+
+use crate::NexusApp;
+use crate::i18n::t;
+use crate::types::{ActivePanel, DOOR_COLUMNS, DOOR_ROWS, DoorSession, Message};
+
+impl NexusApp {
+    /// Show the Doors panel and request the door list
+    pub fn handle_show_doors(&mut self) -> Task<Message> {
+        self.set_active_panel(ActivePanel::Doors);
+
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        conn.doors.error = None;
+        conn.doors.loading = true;
+        if let Err(e) = conn.send(ClientMessage::DoorList) {
+            conn.doors.loading = false;
+            conn.doors.error = Some(format!("{}: {}", t("err-send-failed"), e));
+        }
+        Task::none()
+    }
+
+    /// Close the Doors panel, returning to Server Info where it was opened
+    ///
+    /// Leaving the panel ends a running door (see `set_active_panel`).
+    pub fn handle_close_doors(&mut self) -> Task<Message> {
+        self.set_active_panel(ActivePanel::ServerInfo);
+        Task::none()
+    }
+
+    /// Start a door picked from the list
+    pub fn handle_door_open(&mut self, name: String) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        conn.doors.error = None;
+        let message = ClientMessage::DoorOpen {
+            name: name.clone(),
+            cols: DOOR_COLUMNS,
+            rows: DOOR_ROWS,
+        };
+        match conn.send(message) {
+            Ok(_) => conn.doors.session = Some(DoorSession::new(name)),
+            Err(e) => conn.doors.error = Some(format!("{}: {}", t("err-send-failed"), e)),
+        }
+        Task::none()
+    }
+
+    /// Leave the door, ending it if it is still running, and show the list again
+    pub fn handle_door_leave(&mut self) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+            && let Some(session) = conn.doors.session.take()
+            && session.running
+        {
+            let _ = conn.send(ClientMessage::DoorClose);
+        }
+        Task::none()
+    }
+
+    /// Send key presses to the running door
+    pub fn handle_door_input(&mut self, bytes: Vec<u8>) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+            && conn.doors.is_playing()
+        {
+            let data = BASE64.encode(bytes);
+            let _ = conn.send(ClientMessage::DoorData { data });
+        }
+        Task::none()
+    }
+}
@@ -0,0 +1,49 @@
+//! Unsent draft handlers
+
+use crate::NexusApp;
+use crate::drafts;
+
+impl NexusApp {
+    /// Move the open news editor's text into the connection's drafts
+    ///
+    /// Called when the editor goes away without being submitted or
+    /// cancelled, so the text is back the next time the form is opened.
+    pub fn stash_news_draft(&mut self, connection_id: usize) {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return;
+        };
+        let Some(context) = conn.news_management.draft_context() else {
+            return;
+        };
+        let Some(content) = self.news_body_content.get(&connection_id) else {
+            return;
+        };
+
+        let text = content.text();
+        if text.trim().is_empty() {
+            conn.drafts.remove(&context);
+        } else {
+            conn.drafts.insert(context, text);
+        }
+    }
+
+    /// Copy a connection's unsent drafts into the draft store
+    ///
+    /// Call before the connection goes away, then save the store.
+    pub fn store_connection_drafts(&mut self, connection_id: usize) {
+        self.stash_news_draft(connection_id);
+        let Some(conn) = self.connections.get(&connection_id) else {
+            return;
+        };
+
+        let mut connection_drafts = conn.drafts.clone();
+        connection_drafts.insert(
+            drafts::DraftContext::Chat(conn.active_chat_tab.clone()),
+            conn.message_input.clone(),
+        );
+        self.drafts.set(
+            &drafts::server_key(&conn.connection_info),
+            connection_drafts,
+        );
+    }
+}
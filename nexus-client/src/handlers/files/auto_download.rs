@@ -0,0 +1,392 @@
+//! Auto-download rule handlers (new files in remote folders)
+
+use std::collections::HashMap;
+
+use iced::Task;
+use iced::widget::{Id, operation};
+use iced_toasts::{ToastLevel, toast};
+use nexus_common::protocol::{ClientMessage, FileEntry, FileStatEntry};
+
+use crate::NexusApp;
+use crate::i18n::t_args;
+use crate::transfers::{AutoDownloadCandidate, Transfer, TransferDirection, stat_queries};
+use crate::types::{
+    AutoDownloadRule, FilesManagementState, InputId, Message, PendingAutoDownload, PendingRequests,
+    ResponseRouting, ServerConnection,
+};
+use crate::views::constants::PERMISSION_FILE_DOWNLOAD;
+
+impl NexusApp {
+    /// Get the auto-download rules configured on a connection's bookmark
+    ///
+    /// Ad-hoc connections have no bookmark and therefore never auto-download.
+    pub fn auto_download_rules_for(&self, conn: &ServerConnection) -> &[AutoDownloadRule] {
+        conn.bookmark_id
+            .and_then(|id| self.config.get_bookmark(id))
+            .map(|bookmark| bookmark.auto_downloads.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Whether a connection's bookmark still has exactly this rule
+    fn has_auto_download_rule(&self, connection_id: usize, rule: &AutoDownloadRule) -> bool {
+        self.connections
+            .get(&connection_id)
+            .is_some_and(|conn| self.auto_download_rules_for(conn).contains(rule))
+    }
+
+    // ==================== Dialog ====================
+
+    /// Handle "Auto-Download" from a directory's context menu
+    ///
+    /// Opens the dialog, filled from the directory's current rule if it has one.
+    pub fn handle_file_auto_download(&mut self, path: String) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get(&conn_id) else {
+            return Task::none();
+        };
+
+        let root = conn.files_management.active_tab().viewing_root;
+        let rule = self
+            .auto_download_rules_for(conn)
+            .iter()
+            .find(|rule| rule.is_for(&path, root));
+        let pending = PendingAutoDownload::new(path, root, rule);
+
+        if let Some(conn) = self.connections.get_mut(&conn_id) {
+            conn.files_management.active_tab_mut().pending_auto_download = Some(pending);
+        }
+
+        operation::focus(Id::from(InputId::AutoDownloadPattern))
+    }
+
+    /// Handle pattern input change in the Auto-Download dialog
+    pub fn handle_file_auto_download_pattern_changed(&mut self, pattern: String) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+            && let Some(pending) = &mut conn.files_management.active_tab_mut().pending_auto_download
+        {
+            pending.pattern = pattern;
+            pending.error = None;
+        }
+
+        Task::none()
+    }
+
+    /// Handle Save in the Auto-Download dialog - save the rule on the bookmark
+    ///
+    /// A directory has at most one rule, so this replaces any existing one.
+    /// The directory is checked for matching files right away.
+    pub fn handle_file_auto_download_save(&mut self) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+        let Some(bookmark_id) = conn.bookmark_id else {
+            return Task::none();
+        };
+        let tab = conn.files_management.active_tab_mut();
+        let Some(pending) = &mut tab.pending_auto_download else {
+            return Task::none();
+        };
+
+        let rule = match pending.rule() {
+            Ok(rule) => rule,
+            Err(error) => {
+                pending.error = Some(error);
+                return Task::none();
+            }
+        };
+        tab.pending_auto_download = None;
+
+        let Some(bookmark) = self.config.get_bookmark_mut(bookmark_id) else {
+            return Task::none();
+        };
+        bookmark
+            .auto_downloads
+            .retain(|existing| !existing.is_for(&rule.remote_path, rule.remote_root));
+        bookmark.auto_downloads.push(rule.clone());
+        let _ = self.config.save();
+
+        self.toasts.push(
+            toast(&t_args(
+                "toast-auto-download-started",
+                &[
+                    ("folder", &folder_display_name(&rule.remote_path)),
+                    ("pattern", &rule.pattern),
+                ],
+            ))
+            .level(ToastLevel::Success),
+        );
+
+        if conn.has_permission(PERMISSION_FILE_DOWNLOAD) {
+            request_listing(conn, rule);
+        }
+        self.sync_file_watches(conn_id);
+
+        Task::none()
+    }
+
+    /// Handle Stop Auto-Downloading in the Auto-Download dialog - remove the rule
+    pub fn handle_file_auto_download_remove(&mut self) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+        let Some(bookmark_id) = conn.bookmark_id else {
+            return Task::none();
+        };
+        let Some(pending) = conn
+            .files_management
+            .active_tab_mut()
+            .pending_auto_download
+            .take()
+        else {
+            return Task::none();
+        };
+        let Some(bookmark) = self.config.get_bookmark_mut(bookmark_id) else {
+            return Task::none();
+        };
+
+        bookmark
+            .auto_downloads
+            .retain(|rule| !rule.is_for(&pending.path, pending.root));
+        let _ = self.config.save();
+
+        self.toasts.push(
+            toast(&t_args(
+                "toast-auto-download-stopped",
+                &[("folder", &folder_display_name(&pending.path))],
+            ))
+            .level(ToastLevel::Info),
+        );
+        self.sync_file_watches(conn_id);
+
+        Task::none()
+    }
+
+    /// Handle cancel in the Auto-Download dialog (close dialog)
+    pub fn handle_file_auto_download_cancel(&mut self) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+        {
+            conn.files_management.active_tab_mut().pending_auto_download = None;
+        }
+
+        Task::none()
+    }
+
+    // ==================== Checking ====================
+
+    /// Handle the periodic auto-download tick - list every rule's folder
+    pub fn handle_auto_download_tick(&mut self) -> Task<Message> {
+        let connection_ids: Vec<usize> = self.connections.keys().copied().collect();
+        for connection_id in connection_ids {
+            self.check_auto_downloads(connection_id);
+        }
+
+        Task::none()
+    }
+
+    /// List the folders of a connection's auto-download rules
+    ///
+    /// Connections without download permission are skipped.
+    pub fn check_auto_downloads(&mut self, connection_id: usize) {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return;
+        };
+        if !conn.has_permission(PERMISSION_FILE_DOWNLOAD) {
+            return;
+        }
+        let Some(bookmark) = conn.bookmark_id.and_then(|id| self.config.get_bookmark(id)) else {
+            return;
+        };
+
+        for rule in &bookmark.auto_downloads {
+            request_listing(conn, rule.clone());
+        }
+    }
+
+    /// Handle the folder listing for an auto-download rule - look up the
+    /// hashes of matching files that haven't been handled yet
+    ///
+    /// A folder that can't be listed (deleted, say) is tried again on the
+    /// next check.
+    pub fn handle_auto_download_list(
+        &mut self,
+        connection_id: usize,
+        rule: AutoDownloadRule,
+        success: bool,
+        entries: Option<Vec<FileEntry>>,
+    ) -> Task<Message> {
+        // The rule may have been removed or changed while listing
+        if !success || !self.has_auto_download_rule(connection_id, &rule) {
+            return Task::none();
+        }
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+
+        let candidates = conn
+            .auto_download
+            .candidates(&rule, &entries.unwrap_or_default());
+        if candidates.is_empty() {
+            return Task::none();
+        }
+
+        if let Ok(message_id) = conn.send(ClientMessage::FileStat {
+            files: stat_queries(&candidates),
+            root: rule.remote_root,
+        }) {
+            conn.pending_requests.track(
+                message_id,
+                ResponseRouting::AutoDownloadStat { rule, candidates },
+            );
+        }
+
+        Task::none()
+    }
+
+    /// Handle the file lookup for an auto-download folder's new files -
+    /// queue the ones whose content hasn't been downloaded before
+    ///
+    /// Files that are gone or were already downloaded are marked as handled
+    /// without downloading them. A file whose size changed since the listing,
+    /// because it's still being written for example, comes back without a
+    /// hash and is looked at again on the next check.
+    pub fn handle_auto_download_stat(
+        &mut self,
+        connection_id: usize,
+        rule: AutoDownloadRule,
+        candidates: Vec<AutoDownloadCandidate>,
+        success: bool,
+        files: Option<Vec<FileStatEntry>>,
+    ) -> Task<Message> {
+        if !success || !self.has_auto_download_rule(connection_id, &rule) {
+            return Task::none();
+        }
+        let Some(bookmark_id) = self
+            .connections
+            .get(&connection_id)
+            .and_then(|conn| conn.bookmark_id)
+        else {
+            return Task::none();
+        };
+        let download_dir = self.download_dir_for(Some(bookmark_id));
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+
+        let files: HashMap<String, FileStatEntry> = files
+            .unwrap_or_default()
+            .into_iter()
+            .map(|entry| (entry.path.clone(), entry))
+            .collect();
+
+        let mut queued: Vec<String> = Vec::new();
+        for candidate in candidates {
+            // Handled by an overlapping check in the meantime
+            if conn.auto_download.is_handled(rule.remote_root, &candidate) {
+                continue;
+            }
+            let sha256 = match files.get(&candidate.path) {
+                Some(entry) if !entry.is_directory => match &entry.sha256 {
+                    Some(sha256) => sha256,
+                    None => continue,
+                },
+                _ => {
+                    conn.auto_download
+                        .mark_handled(rule.remote_root, &candidate);
+                    continue;
+                }
+            };
+            conn.auto_download
+                .mark_handled(rule.remote_root, &candidate);
+
+            // Queued in an earlier session but not finished yet
+            let already_queued = self.transfer_manager.all().any(|transfer| {
+                transfer.direction == TransferDirection::Download
+                    && transfer.bookmark_id == Some(bookmark_id)
+                    && transfer.remote_root == rule.remote_root
+                    && transfer.remote_path == candidate.path
+            });
+            if already_queued || self.download_history.contains(sha256) {
+                continue;
+            }
+
+            let filename = candidate
+                .path
+                .rsplit('/')
+                .next()
+                .unwrap_or(&candidate.path)
+                .to_string();
+            let queue_position = self.transfer_manager.next_queue_position();
+            let mut transfer = Transfer::new_download(
+                conn.connection_info.clone(),
+                candidate.path.clone(),
+                rule.remote_root,
+                false,
+                download_dir.join(&filename),
+                Some(bookmark_id),
+                queue_position,
+            );
+            transfer.collision_policy = self.config.settings.collision_policy;
+            self.transfer_manager.add(transfer);
+            queued.push(filename);
+        }
+
+        if queued.is_empty() {
+            return Task::none();
+        }
+
+        let _ = self.transfer_manager.save();
+
+        // Same feedback as a manual download (single file: show name, multiple: show count)
+        let is_queued = self.config.settings.queue_transfers;
+        let toast_text = if let [filename] = queued.as_slice() {
+            if is_queued {
+                t_args("toast-download-queued", &[("filename", filename)])
+            } else {
+                t_args("toast-download-started", &[("filename", filename)])
+            }
+        } else {
+            let count_str = queued.len().to_string();
+            if is_queued {
+                t_args("toast-downloads-queued", &[("count", &count_str)])
+            } else {
+                t_args("toast-downloads-started", &[("count", &count_str)])
+            }
+        };
+        self.toasts
+            .push(toast(&toast_text).level(ToastLevel::Success));
+
+        Task::none()
+    }
+}
+
+/// Ask the server for the contents of a rule's folder
+pub(super) fn request_listing(conn: &mut ServerConnection, rule: AutoDownloadRule) {
+    if let Ok(message_id) = conn.send(ClientMessage::FileList {
+        path: rule.remote_path.clone(),
+        root: rule.remote_root,
+        show_hidden: false,
+    }) {
+        conn.pending_requests
+            .track(message_id, ResponseRouting::AutoDownloadList { rule });
+    }
+}
+
+/// Last component of a remote path for display ("/" for the top level)
+fn folder_display_name(path: &str) -> String {
+    path.trim_matches('/')
+        .rsplit('/')
+        .next()
+        .filter(|name| !name.is_empty())
+        .map(FilesManagementState::display_name)
+        .unwrap_or_else(|| "/".to_string())
+}
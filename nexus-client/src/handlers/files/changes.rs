@@ -0,0 +1,143 @@
+//! Server file change notification handlers (refresh open folders)
+
+use nexus_common::protocol::{ClientMessage, FileChangeKind, FileWatchPath};
+use nexus_common::validators::MAX_FILE_WATCH_COUNT;
+
+use super::auto_download::request_listing;
+use crate::NexusApp;
+use crate::types::{PendingRequests, ResponseRouting};
+use crate::views::constants::{
+    PERMISSION_FILE_DOWNLOAD, PERMISSION_FILE_LIST, PERMISSION_FILE_ROOT,
+};
+
+impl NexusApp {
+    /// Ask the server to report changes in the folders a connection shows
+    ///
+    /// Watches the folders open in file tabs and those of the bookmark's
+    /// auto-download rules. Nothing is sent if the list hasn't changed.
+    pub fn sync_file_watches(&mut self, connection_id: usize) {
+        let Some(conn) = self.connections.get(&connection_id) else {
+            return;
+        };
+        if !conn.has_permission(PERMISSION_FILE_LIST) {
+            return;
+        }
+
+        let tabs = conn
+            .files_management
+            .tabs
+            .iter()
+            .filter(|tab| tab.entries.is_some())
+            .map(|tab| FileWatchPath {
+                path: tab.current_path.clone(),
+                root: tab.viewing_root,
+            });
+        let rules = if conn.has_permission(PERMISSION_FILE_DOWNLOAD) {
+            self.auto_download_rules_for(conn)
+        } else {
+            &[]
+        };
+        let rules = rules.iter().map(|rule| FileWatchPath {
+            path: rule.remote_path.clone(),
+            root: rule.remote_root,
+        });
+
+        let can_browse_root = conn.has_permission(PERMISSION_FILE_ROOT);
+        let mut watches: Vec<FileWatchPath> = Vec::new();
+        for watch in tabs.chain(rules) {
+            if (watch.root && !can_browse_root) || watches.contains(&watch) {
+                continue;
+            }
+            watches.push(watch);
+        }
+        watches.truncate(MAX_FILE_WATCH_COUNT);
+
+        if watches == conn.file_watches {
+            return;
+        }
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return;
+        };
+        if conn
+            .send(ClientMessage::FileWatch {
+                directories: watches.clone(),
+            })
+            .is_ok()
+        {
+            conn.file_watches = watches;
+        }
+    }
+
+    /// Handle a change in a watched folder
+    ///
+    /// Tabs showing the folder are listed again, keeping their scroll
+    /// position. Auto-download rules for it check for new files right away
+    /// instead of waiting for the next periodic check.
+    pub fn handle_file_changed(
+        &mut self,
+        connection_id: usize,
+        path: String,
+        root: bool,
+        kind: FileChangeKind,
+    ) {
+        let show_hidden = self.config.settings.show_hidden_files;
+        let rules: Vec<_> = match self.connections.get(&connection_id) {
+            Some(conn)
+                if kind != FileChangeKind::Removed
+                    && conn.has_permission(PERMISSION_FILE_DOWNLOAD) =>
+            {
+                self.auto_download_rules_for(conn)
+                    .iter()
+                    .filter(|rule| rule.is_for(&path, root))
+                    .cloned()
+                    .collect()
+            }
+            _ => Vec::new(),
+        };
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return;
+        };
+
+        let tab_ids: Vec<_> = conn
+            .files_management
+            .tabs
+            .iter()
+            .filter(|tab| {
+                tab.entries.is_some() && tab.current_path == path && tab.viewing_root == root
+            })
+            .map(|tab| tab.id)
+            .collect();
+        for tab_id in tab_ids {
+            // A burst of changes needs only one listing at a time
+            let refreshing = conn.pending_requests.values().any(|routing| {
+                matches!(routing, ResponseRouting::RefreshFileList { tab_id: id, .. } if *id == tab_id)
+            });
+            if refreshing {
+                continue;
+            }
+            if let Ok(message_id) = conn.send(ClientMessage::FileList {
+                path: path.clone(),
+                root,
+                show_hidden,
+            }) {
+                conn.pending_requests.track(
+                    message_id,
+                    ResponseRouting::RefreshFileList {
+                        tab_id,
+                        path: path.clone(),
+                        root,
+                    },
+                );
+            }
+        }
+
+        for rule in rules {
+            let listing = conn.pending_requests.values().any(|routing| {
+                matches!(routing, ResponseRouting::AutoDownloadList { rule: pending } if *pending == rule)
+            });
+            if !listing {
+                request_listing(conn, rule);
+            }
+        }
+    }
+}
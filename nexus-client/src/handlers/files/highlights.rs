@@ -0,0 +1,77 @@
+//! File highlights handlers (new uploads and most downloaded files)
+
+use iced::Task;
+use nexus_common::protocol::FileActivity;
+
+use super::{cancel_search, strip_leading_slash};
+use crate::NexusApp;
+use crate::types::Message;
+
+impl NexusApp {
+    /// Show the highlights in the active tab
+    ///
+    /// Replaces the listing (or search results) with the files uploaded this
+    /// week and the most downloaded files, in the tab's current view mode.
+    pub fn handle_file_show_highlights(&mut self) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        let tab_id = conn.files_management.active_tab_id();
+        cancel_search(conn, tab_id);
+        let tab = conn.files_management.active_tab_mut();
+        tab.clear_search();
+        let viewing_root = tab.viewing_root;
+
+        self.send_highlights_request(conn_id, tab_id, viewing_root)
+    }
+
+    /// Open a highlighted file's folder in a new tab
+    pub fn handle_file_highlight_open(&mut self, file: FileActivity) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get(&conn_id) else {
+            return Task::none();
+        };
+
+        // Use the root context that was active when the highlights were requested
+        let viewing_root = conn
+            .files_management
+            .active_tab()
+            .highlights
+            .as_ref()
+            .is_some_and(|h| h.viewing_root);
+
+        let path = strip_leading_slash(&file.path);
+        let parent = path
+            .rfind('/')
+            .map(|pos| path[..pos].to_string())
+            .unwrap_or_default();
+
+        self.open_directory_in_new_tab(conn_id, parent, viewing_root)
+    }
+
+    /// Download a highlighted file
+    pub fn handle_file_highlight_download(&mut self, file: FileActivity) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get(&conn_id) else {
+            return Task::none();
+        };
+
+        let remote_root = conn
+            .files_management
+            .active_tab()
+            .highlights
+            .as_ref()
+            .is_some_and(|h| h.viewing_root);
+
+        let path = strip_leading_slash(&file.path);
+        self.queue_download_with_root(path.to_string(), false, remote_root)
+    }
+}
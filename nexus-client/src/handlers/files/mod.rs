@@ -7,13 +7,21 @@
 //! - `tabs` — Tab new/switch/close
 //! - `transfers` — Share, download, upload, drag-and-drop
 //! - `search` — Search input/submit/result handlers
+//! - `highlights` — New and most downloaded files
+//! - `watch` — Upload folder watching (auto-upload drop folder)
+//! - `auto_download` — Auto-download rules (new files in remote folders)
+//! - `changes` — Server file change notifications (refresh open folders)
 
+mod auto_download;
+mod changes;
 mod directories;
+mod highlights;
 mod navigation;
 mod operations;
 mod search;
 mod tabs;
 mod transfers;
+mod watch;
 
 use iced::Task;
 use nexus_common::protocol::{ClientMessage, FileSearchResult};
@@ -21,7 +29,10 @@ use nexus_common::validators::{self, DirNameError};
 
 use crate::NexusApp;
 use crate::i18n::t;
-use crate::types::{FileSortColumn, Message, PendingRequests, ResponseRouting, TabId};
+use crate::types::{
+    FileHighlights, FileSortColumn, FileTab, Message, PendingRequests, ResponseRouting,
+    ServerConnection, TabId,
+};
 
 /// Strip leading slash from a path
 ///
@@ -31,6 +42,13 @@ fn strip_leading_slash(path: &str) -> &str {
     path.strip_prefix('/').unwrap_or(path)
 }
 
+/// Cancel a search still running in a tab (one being replaced or abandoned)
+fn cancel_search(conn: &mut ServerConnection, tab_id: TabId) {
+    conn.cancel_requests(|routing| {
+        matches!(routing, ResponseRouting::FileSearchResult { tab_id: id } if *id == tab_id)
+    });
+}
+
 /// Sort search results by the specified column and direction
 ///
 /// For the Name column, directories are always sorted first.
@@ -182,6 +200,7 @@ impl NexusApp {
         let Some(conn) = self.connections.get_mut(&conn_id) else {
             return Task::none();
         };
+        cancel_search(conn, tab_id);
 
         let Some(tab) = conn.files_management.tab_by_id_mut(tab_id) else {
             return Task::none();
@@ -191,6 +210,7 @@ impl NexusApp {
         // Store the viewing_root used for this search (for downloads from results)
         tab.search_query = Some(query.clone());
         tab.search_viewing_root = viewing_root;
+        tab.highlights = None;
         tab.search_loading = true;
         tab.search_results = None;
         tab.search_error = None;
@@ -223,9 +243,105 @@ impl NexusApp {
 
         Task::none()
     }
+
+    /// Send FileRecent and FilePopular requests to the server for a specific tab
+    ///
+    /// Used when showing highlights and to re-request them on refresh or root
+    /// toggle. Clears previous listings and stores both message_ids to detect
+    /// stale responses.
+    fn send_highlights_request(
+        &mut self,
+        conn_id: usize,
+        tab_id: TabId,
+        viewing_root: bool,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        let mut highlights = FileHighlights {
+            viewing_root,
+            ..Default::default()
+        };
+
+        match conn.send(ClientMessage::FileRecent { root: viewing_root }) {
+            Ok(message_id) => {
+                highlights.recent_request = Some(message_id);
+                conn.pending_requests
+                    .track(message_id, ResponseRouting::FileRecentResult { tab_id });
+            }
+            Err(err) => highlights.error = Some(err),
+        }
+
+        if highlights.error.is_none() {
+            match conn.send(ClientMessage::FilePopular { root: viewing_root }) {
+                Ok(message_id) => {
+                    highlights.popular_request = Some(message_id);
+                    conn.pending_requests
+                        .track(message_id, ResponseRouting::FilePopularResult { tab_id });
+                }
+                Err(err) => highlights.error = Some(err),
+            }
+        }
+
+        if let Some(tab) = conn.files_management.tab_by_id_mut(tab_id) {
+            tab.highlights = Some(highlights);
+        }
+
+        Task::none()
+    }
+
+    /// Open a directory in a new tab and switch to it
+    ///
+    /// Used by search results and highlights, which open a file's folder
+    /// rather than replacing their own view.
+    fn open_directory_in_new_tab(
+        &mut self,
+        conn_id: usize,
+        path: String,
+        viewing_root: bool,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        // Create new tab at target path
+        let new_tab = FileTab::new_at_path(path.clone(), viewing_root);
+        let new_tab_id = new_tab.id;
+
+        // Add and switch to the new tab
+        conn.files_management.tabs.push(new_tab);
+        conn.files_management.active_tab = conn.files_management.tabs.len() - 1;
+
+        // Request file list for the new tab
+        let message = ClientMessage::FileList {
+            path,
+            root: viewing_root,
+            show_hidden: self.config.settings.show_hidden_files,
+        };
+
+        match conn.send(message) {
+            Ok(message_id) => {
+                conn.pending_requests.track(
+                    message_id,
+                    ResponseRouting::PopulateFileList {
+                        tab_id: new_tab_id,
+                        uri_target: None,
+                    },
+                );
+            }
+            Err(err) => {
+                if let Some(tab) = conn.files_management.tab_by_id_mut(new_tab_id) {
+                    tab.error = Some(err);
+                }
+            }
+        }
+
+        Task::none()
+    }
 }
 
-fn sanitize_filename(name: &str, fallback: &str) -> String {
+pub(super) fn sanitize_filename(name: &str, fallback: &str) -> String {
     let sanitized: String = name
         .chars()
         .map(|c| match c {
@@ -376,6 +492,7 @@ mod tests {
             size,
             modified: 0,
             is_directory,
+            rating: None,
         }
     }
 
@@ -139,6 +139,14 @@ impl NexusApp {
             return self.send_search_request(conn_id, tab_id, query, viewing_root);
         }
 
+        // If showing highlights, request them again
+        if tab.is_showing_highlights() {
+            let tab_id = tab.id;
+            let viewing_root = tab.viewing_root;
+
+            return self.send_highlights_request(conn_id, tab_id, viewing_root);
+        }
+
         // Normal browsing mode - refresh file list
         let current_path = tab.current_path.clone();
         let viewing_root = tab.viewing_root;
@@ -154,6 +162,7 @@ impl NexusApp {
     ///
     /// Requires file_root permission.
     /// In search mode: re-runs the search with toggled scope.
+    /// When showing highlights: requests them again with toggled scope.
     /// In browsing mode: resets to root directory when toggling.
     pub fn handle_file_toggle_root(&mut self) -> Task<Message> {
         let Some(conn_id) = self.active_connection else {
@@ -174,6 +183,15 @@ impl NexusApp {
             return self.send_search_request(conn_id, tab_id, query, viewing_root);
         }
 
+        // If showing highlights, toggle root and request them again
+        if tab.is_showing_highlights() {
+            tab.viewing_root = !tab.viewing_root;
+            let tab_id = tab.id;
+            let viewing_root = tab.viewing_root;
+
+            return self.send_highlights_request(conn_id, tab_id, viewing_root);
+        }
+
         // Normal browsing mode - toggle and go to root/home
         tab.toggle_root();
         let viewing_root = tab.viewing_root;
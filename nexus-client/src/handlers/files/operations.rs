@@ -1,15 +1,16 @@
-//! File operation handlers (delete, info, rename, clipboard, overwrite, sort)
+//! File operation handlers (delete, info, reviews, rename, upload rules, clipboard, overwrite, sort)
 
 use iced::Task;
 use iced::widget::{Id, operation};
-use nexus_common::protocol::ClientMessage;
-use nexus_common::validators;
+use nexus_common::protocol::{ClientMessage, UploadRule};
+use nexus_common::validators::{self, FileCommentError};
 
 use super::dir_name_error_message;
 use crate::NexusApp;
-use crate::i18n::t;
+use crate::i18n::{t, t_args};
 use crate::types::{
-    ClipboardItem, ClipboardOperation, InputId, Message, PendingRequests, ResponseRouting,
+    ClipboardItem, ClipboardOperation, FileReview, InputId, Message, PendingRequests,
+    PendingUploadRule, ResponseRouting,
 };
 use crate::views::files::build_navigate_path;
 
@@ -100,6 +101,9 @@ impl NexusApp {
         let path = build_navigate_path(current_path, &name);
         let root = tab.viewing_root;
 
+        // Ratings and comments are fetched once the info shows it's a file
+        conn.files_management.active_tab_mut().review = Some(FileReview::new(path.clone(), root));
+
         let tab_id = conn.files_management.active_tab_id();
         match conn.send(ClientMessage::FileInfo { path, root }) {
             Ok(message_id) => {
@@ -124,7 +128,150 @@ impl NexusApp {
             return Task::none();
         };
 
-        conn.files_management.active_tab_mut().pending_info = None;
+        let tab = conn.files_management.active_tab_mut();
+        tab.pending_info = None;
+        tab.review = None;
+
+        Task::none()
+    }
+
+    // ==================== File Reviews ====================
+
+    /// Handle a star clicked in the file info dialog
+    ///
+    /// Clicking the current rating again clears it.
+    pub fn handle_file_rate(&mut self, rating: u8) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        let tab = conn.files_management.active_tab();
+        let Some(review) = &tab.review else {
+            return Task::none();
+        };
+        let current = tab.pending_info.as_ref().and_then(|info| info.my_rating);
+        let rating = if current == Some(rating) { 0 } else { rating };
+        let path = review.path.clone();
+        let root = review.root;
+
+        self.send_file_review_request(
+            conn_id,
+            ClientMessage::FileRate {
+                path: path.clone(),
+                rating,
+                root,
+            },
+            path,
+        )
+    }
+
+    /// Handle comment input change in the file info dialog
+    pub fn handle_file_comment_input_changed(&mut self, body: String) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        if let Some(review) = &mut conn.files_management.active_tab_mut().review {
+            review.comment_input = body;
+            review.error = None;
+        }
+
+        Task::none()
+    }
+
+    /// Handle post comment button in the file info dialog
+    pub fn handle_file_comment_submit(&mut self) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        let Some(review) = &mut conn.files_management.active_tab_mut().review else {
+            return Task::none();
+        };
+
+        let body = review.comment_input.trim().to_string();
+        if let Err(e) = validators::validate_file_comment(&body) {
+            review.error = Some(match e {
+                FileCommentError::Empty => t("err-file-comment-empty"),
+                FileCommentError::TooLong => t_args(
+                    "err-file-comment-too-long",
+                    &[
+                        ("length", &body.len().to_string()),
+                        ("max", &validators::MAX_FILE_COMMENT_LENGTH.to_string()),
+                    ],
+                ),
+                FileCommentError::InvalidCharacters => t("err-file-comment-invalid-characters"),
+            });
+            return Task::none();
+        }
+
+        let path = review.path.clone();
+        let root = review.root;
+
+        self.send_file_review_request(
+            conn_id,
+            ClientMessage::FileCommentCreate {
+                path: path.clone(),
+                body,
+                root,
+            },
+            path,
+        )
+    }
+
+    /// Handle delete clicked on a comment in the file info dialog
+    pub fn handle_file_comment_delete(&mut self, id: i64) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get(&conn_id) else {
+            return Task::none();
+        };
+
+        let Some(review) = &conn.files_management.active_tab().review else {
+            return Task::none();
+        };
+        let path = review.path.clone();
+
+        self.send_file_review_request(conn_id, ClientMessage::FileCommentDelete { id }, path)
+    }
+
+    /// Send a rating or comment request for the active tab's info dialog
+    ///
+    /// Send failures are shown in the dialog.
+    pub fn send_file_review_request(
+        &mut self,
+        conn_id: usize,
+        message: ClientMessage,
+        path: String,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        let tab_id = conn.files_management.active_tab_id();
+        match conn.send(message) {
+            Ok(message_id) => {
+                conn.pending_requests.track(
+                    message_id,
+                    ResponseRouting::FileReviewResult { tab_id, path },
+                );
+            }
+            Err(e) => {
+                if let Some(review) = &mut conn.files_management.active_tab_mut().review {
+                    review.error = Some(format!("{}: {}", t("err-send-failed"), e));
+                }
+            }
+        }
 
         Task::none()
     }
@@ -252,6 +399,170 @@ impl NexusApp {
         Task::none()
     }
 
+    // ==================== Upload Rules ====================
+
+    /// Handle Upload Rules clicked from a directory's context menu
+    ///
+    /// Opens the dialog and loads the rule set on the directory itself.
+    pub fn handle_file_upload_rule_clicked(&mut self, name: String) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        let tab = conn.files_management.active_tab_mut();
+        let path = build_navigate_path(&tab.current_path, &name);
+        let root = tab.viewing_root;
+        tab.pending_upload_rule = Some(PendingUploadRule::new(path.clone(), root));
+
+        let tab_id = conn.files_management.active_tab_id();
+        match conn.send(ClientMessage::UploadRuleGet {
+            path: path.clone(),
+            root,
+        }) {
+            Ok(message_id) => {
+                conn.pending_requests.track(
+                    message_id,
+                    ResponseRouting::FileUploadRuleResult { tab_id, path },
+                );
+            }
+            Err(e) => {
+                if let Some(pending) =
+                    &mut conn.files_management.active_tab_mut().pending_upload_rule
+                {
+                    pending.error = Some(format!("{}: {}", t("err-send-failed"), e));
+                }
+            }
+        }
+
+        operation::focus(Id::from(InputId::UploadRuleExtensions))
+    }
+
+    /// Handle allowed extensions input change in the Upload Rules dialog
+    pub fn handle_file_upload_rule_extensions_changed(&mut self, value: String) -> Task<Message> {
+        self.update_pending_upload_rule(|pending| pending.extensions = value)
+    }
+
+    /// Handle largest file input change in the Upload Rules dialog
+    pub fn handle_file_upload_rule_max_file_size_changed(
+        &mut self,
+        value: String,
+    ) -> Task<Message> {
+        self.update_pending_upload_rule(|pending| pending.max_file_size = value)
+    }
+
+    /// Handle largest upload input change in the Upload Rules dialog
+    pub fn handle_file_upload_rule_max_total_size_changed(
+        &mut self,
+        value: String,
+    ) -> Task<Message> {
+        self.update_pending_upload_rule(|pending| pending.max_total_size = value)
+    }
+
+    /// Handle Save in the Upload Rules dialog
+    ///
+    /// Keeps the dialog open until the server responds. A rule that limits
+    /// nothing removes the directory's rule.
+    pub fn handle_file_upload_rule_save(&mut self) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        let Some(pending) = &mut conn.files_management.active_tab_mut().pending_upload_rule else {
+            return Task::none();
+        };
+
+        let rule = match pending.rule() {
+            Ok(rule) => rule,
+            Err(error) => {
+                pending.error = Some(error);
+                return Task::none();
+            }
+        };
+
+        self.send_upload_rule_set(conn_id, Some(rule).filter(|rule| !rule.is_empty()))
+    }
+
+    /// Handle Remove Rule in the Upload Rules dialog
+    pub fn handle_file_upload_rule_remove(&mut self) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+
+        self.send_upload_rule_set(conn_id, None)
+    }
+
+    /// Handle cancel in the Upload Rules dialog (close dialog)
+    pub fn handle_file_upload_rule_cancel(&mut self) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+        {
+            conn.files_management.active_tab_mut().pending_upload_rule = None;
+        }
+
+        Task::none()
+    }
+
+    /// Edit the active tab's Upload Rules dialog, clearing any error
+    fn update_pending_upload_rule(
+        &mut self,
+        update: impl FnOnce(&mut PendingUploadRule),
+    ) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+            && let Some(pending) = &mut conn.files_management.active_tab_mut().pending_upload_rule
+        {
+            update(pending);
+            pending.error = None;
+        }
+
+        Task::none()
+    }
+
+    /// Send an UploadRuleSet for the active tab's Upload Rules dialog
+    ///
+    /// Send failures are shown in the dialog.
+    fn send_upload_rule_set(&mut self, conn_id: usize, rule: Option<UploadRule>) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        let tab_id = conn.files_management.active_tab_id();
+        let Some(pending) = &mut conn.files_management.active_tab_mut().pending_upload_rule else {
+            return Task::none();
+        };
+        pending.error = None;
+        let path = pending.path.clone();
+        let root = pending.root;
+
+        match conn.send(ClientMessage::UploadRuleSet {
+            path: path.clone(),
+            rule,
+            root,
+        }) {
+            Ok(message_id) => {
+                conn.pending_requests.track(
+                    message_id,
+                    ResponseRouting::FileUploadRuleResult { tab_id, path },
+                );
+            }
+            Err(e) => {
+                if let Some(pending) =
+                    &mut conn.files_management.active_tab_mut().pending_upload_rule
+                {
+                    pending.error = Some(format!("{}: {}", t("err-send-failed"), e));
+                }
+            }
+        }
+
+        Task::none()
+    }
+
     // ==================== Clipboard Operations ====================
 
     /// Handle cut action from context menu
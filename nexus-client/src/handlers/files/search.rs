@@ -4,8 +4,8 @@ use iced::Task;
 use nexus_common::protocol::ClientMessage;
 use nexus_common::validators::{self, SearchQueryError, validate_search_query};
 
-use super::sort_search_results;
 use super::strip_leading_slash;
+use super::{cancel_search, sort_search_results};
 use crate::NexusApp;
 use crate::i18n::{t, t_args};
 use crate::types::{ActivePanel, FileSortColumn, Message, PendingRequests, ResponseRouting};
@@ -65,6 +65,10 @@ impl NexusApp {
             return Task::none();
         };
 
+        // Whatever this submit does, a search still running is abandoned
+        let tab_id = conn.files_management.active_tab_id();
+        cancel_search(conn, tab_id);
+
         let tab = conn.files_management.active_tab_mut();
         let query = tab.search_input.trim().to_string();
 
@@ -115,7 +119,6 @@ impl NexusApp {
             return Task::none();
         }
 
-        let tab_id = tab.id;
         let viewing_root = tab.viewing_root;
 
         // Use helper to send search request (handles loading state and race conditions)
@@ -212,12 +215,10 @@ impl NexusApp {
         &mut self,
         result: nexus_common::protocol::FileSearchResult,
     ) -> Task<Message> {
-        use crate::types::FileTab;
-
         let Some(conn_id) = self.active_connection else {
             return Task::none();
         };
-        let Some(conn) = self.connections.get_mut(&conn_id) else {
+        let Some(conn) = self.connections.get(&conn_id) else {
             return Task::none();
         };
 
@@ -239,39 +240,7 @@ impl NexusApp {
         // Use the root context that was active when the search was performed
         let viewing_root = conn.files_management.active_tab().search_viewing_root;
 
-        // Create new tab at target path
-        let new_tab = FileTab::new_at_path(target_path.clone(), viewing_root);
-        let new_tab_id = new_tab.id;
-
-        // Add and switch to the new tab
-        conn.files_management.tabs.push(new_tab);
-        conn.files_management.active_tab = conn.files_management.tabs.len() - 1;
-
-        // Request file list for the new tab
-        let message = ClientMessage::FileList {
-            path: target_path,
-            root: viewing_root,
-            show_hidden: self.config.settings.show_hidden_files,
-        };
-
-        match conn.send(message) {
-            Ok(message_id) => {
-                conn.pending_requests.track(
-                    message_id,
-                    ResponseRouting::PopulateFileList {
-                        tab_id: new_tab_id,
-                        uri_target: None,
-                    },
-                );
-            }
-            Err(err) => {
-                if let Some(tab) = conn.files_management.tab_by_id_mut(new_tab_id) {
-                    tab.error = Some(err);
-                }
-            }
-        }
-
-        Task::none()
+        self.open_directory_in_new_tab(conn_id, target_path, viewing_root)
     }
 
     /// Handle search results sort column click
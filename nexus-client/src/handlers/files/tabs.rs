@@ -51,23 +51,41 @@ impl NexusApp {
             return Task::none();
         };
 
+        // Stop the server's work on the tab's long requests
+        conn.cancel_requests(|routing| {
+            matches!(
+                routing,
+                ResponseRouting::FileSearchResult { tab_id: tid }
+                    | ResponseRouting::FileInfoResult { tab_id: tid }
+                    | ResponseRouting::FileManifestResult { tab_id: tid }
+                    if *tid == tab_id
+            )
+        });
+
         // Clean up pending requests for this tab
         conn.pending_requests.retain(|_, routing| {
             !matches!(
                 routing,
                 ResponseRouting::PopulateFileList { tab_id: tid, .. }
+                    | ResponseRouting::RefreshFileList { tab_id: tid, .. }
                     | ResponseRouting::FileCreateDirResult { tab_id: tid }
                     | ResponseRouting::FileDeleteResult { tab_id: tid }
                     | ResponseRouting::FileInfoResult { tab_id: tid }
+                    | ResponseRouting::FileManifestResult { tab_id: tid }
+                    | ResponseRouting::FileStatResult { tab_id: tid, .. }
                     | ResponseRouting::FileRenameResult { tab_id: tid }
                     | ResponseRouting::FileMoveResult { tab_id: tid, .. }
                     | ResponseRouting::FileCopyResult { tab_id: tid, .. }
                     | ResponseRouting::FileSearchResult { tab_id: tid }
+                    | ResponseRouting::FilePopularResult { tab_id: tid }
+                    | ResponseRouting::FileRecentResult { tab_id: tid }
+                    | ResponseRouting::FileUploadRuleResult { tab_id: tid, .. }
                     if *tid == tab_id
             )
         });
 
         conn.files_management.close_tab_by_id(tab_id);
+        self.sync_file_watches(conn_id);
         Task::none()
     }
 }
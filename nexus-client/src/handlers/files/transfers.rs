@@ -1,13 +1,23 @@
 //! File transfer handlers (share, download, upload, drag-and-drop)
 
+use std::path::PathBuf;
+
 use iced::Task;
 use iced_toasts::{ToastLevel, toast};
+use nexus_common::protocol::{ClientMessage, FileStatEntry, FileStatQuery};
+use nexus_common::validators::MAX_DOWNLOAD_INCLUDE_COUNT;
+use uuid::Uuid;
 
 use super::sanitize_filename;
 use crate::NexusApp;
+use crate::config::settings::CollisionPolicy;
 use crate::i18n::{t, t_args};
-use crate::types::{FilesManagementState, Message};
-use crate::uri::url_encode_path;
+use crate::transfers::{UploadItem, UploadScan, hash_local_copies, scan_uploads};
+use crate::types::{
+    Message, PendingDownloadCheck, PendingDownloadSelect, PendingDownloadTo, PendingUploadPreview,
+    PlannedUpload, ResponseRouting, TabId,
+};
+use crate::uri::file_link;
 
 impl NexusApp {
     // ==================== Share ====================
@@ -15,8 +25,7 @@ impl NexusApp {
     /// Handle share request - copies nexus:// URL to clipboard
     ///
     /// Builds a deep link URL with the current connection info and file path,
-    /// then copies it to the system clipboard. Folder type suffixes are stripped
-    /// from the path since the server resolves paths without them.
+    /// then copies it to the system clipboard.
     pub fn handle_file_share(&mut self, path: String) -> Task<Message> {
         let Some(conn_id) = self.active_connection else {
             return Task::none();
@@ -25,35 +34,7 @@ impl NexusApp {
             return Task::none();
         };
 
-        // Build raw URL without username
-        let info = &conn.connection_info;
-        let port_suffix = if info.port == nexus_common::DEFAULT_PORT {
-            String::new()
-        } else {
-            format!(":{}", info.port)
-        };
-
-        // IPv6 addresses need brackets in URIs
-        let host = if info.address.parse::<std::net::Ipv6Addr>().is_ok() {
-            format!("[{}]", info.address)
-        } else {
-            info.address.clone()
-        };
-
-        // Strip folder type suffixes from each path segment
-        // Server resolves paths without suffixes (e.g., "uploads" -> "uploads [NEXUS-UL]")
-        let clean_path = path
-            .split('/')
-            .map(FilesManagementState::display_name)
-            .collect::<Vec<_>>()
-            .join("/");
-
-        let url = format!(
-            "nexus://{}{}/files/{}",
-            host,
-            port_suffix,
-            url_encode_path(&clean_path)
-        );
+        let url = file_link(&conn.connection_info, &path);
 
         // Copy to clipboard, then show toast feedback
         let toast_text = t("toast-link-copied");
@@ -113,14 +94,259 @@ impl NexusApp {
             return Task::none();
         };
 
-        // Build local path from download directory + remote filename
-        let download_dir = self
-            .config
-            .settings
-            .download_path
-            .clone()
-            .or_else(crate::config::settings::default_download_path)
-            .unwrap_or_else(|| ".".to_string());
+        let download_dir = self.download_dir_for(conn.bookmark_id);
+        let collision_policy = self.config.settings.collision_policy;
+        self.queue_download_to(
+            remote_path,
+            is_directory,
+            remote_root,
+            download_dir,
+            collision_policy,
+            None,
+        )
+    }
+
+    /// Queue a download transfer into an explicit local folder
+    ///
+    /// `include` limits a directory download to the given files (relative to
+    /// the directory); None downloads everything. A single file is first
+    /// checked for local copies (see [`Self::handle_download_check_stat`]).
+    fn queue_download_to(
+        &mut self,
+        remote_path: String,
+        is_directory: bool,
+        remote_root: bool,
+        download_dir: PathBuf,
+        collision_policy: CollisionPolicy,
+        include: Option<Vec<String>>,
+    ) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        if is_directory {
+            return self.queue_download_now(
+                conn_id,
+                remote_path,
+                true,
+                remote_root,
+                download_dir,
+                collision_policy,
+                include,
+            );
+        }
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        // Ask for the file's size first; only same-sized local files are hashed
+        let check =
+            PendingDownloadCheck::new(remote_path, remote_root, download_dir, collision_policy);
+        let Ok(message_id) = conn.send(ClientMessage::FileStat {
+            files: vec![FileStatQuery {
+                path: check.remote_path.clone(),
+                size: 0,
+            }],
+            root: remote_root,
+        }) else {
+            return self.queue_checked_download(conn_id, check);
+        };
+        let tab = conn.files_management.active_tab_mut();
+        conn.pending_requests.track(
+            message_id,
+            ResponseRouting::DownloadCheckStat {
+                tab_id: tab.id,
+                check_id: check.id,
+            },
+        );
+        tab.pending_download_checks.push(check);
+
+        Task::none()
+    }
+
+    /// Handle a file stat response for a download's duplicate check
+    ///
+    /// The first response gives the file's size, so local files of that size
+    /// are hashed; the second gives its hash. The download is queued as soon
+    /// as no local copy can match, including when the lookup fails.
+    pub fn handle_download_check_stat(
+        &mut self,
+        connection_id: usize,
+        tab_id: TabId,
+        check_id: Uuid,
+        success: bool,
+        files: Option<Vec<FileStatEntry>>,
+    ) -> Task<Message> {
+        let Some(check) = self
+            .connections
+            .get_mut(&connection_id)
+            .and_then(|conn| conn.files_management.tab_by_id_mut(tab_id))
+            .and_then(|tab| tab.download_check_mut(check_id))
+        else {
+            return Task::none();
+        };
+
+        let entry = files
+            .unwrap_or_default()
+            .into_iter()
+            .find(|entry| success && entry.path == check.remote_path && !entry.is_directory);
+
+        match (check.size, entry) {
+            // Size lookup: hash the local files that could be copies
+            (None, Some(entry)) if entry.size > 0 => {
+                let size = entry.size;
+                check.size = Some(size);
+                let download_dir = check.download_dir.clone();
+                let destination = check.destination();
+                let earlier = self.download_history.paths_with_size(size);
+                Task::perform(
+                    hash_local_copies(download_dir, earlier, destination, size),
+                    move |copies| {
+                        Message::FileDownloadScanned(connection_id, tab_id, check_id, copies)
+                    },
+                )
+            }
+            // Hash lookup: ask the user if a local copy matches
+            (Some(_), Some(entry)) => {
+                if let Some(duplicate) = entry
+                    .sha256
+                    .as_deref()
+                    .and_then(|sha256| check.find_duplicate(sha256))
+                {
+                    check.duplicate = Some(duplicate);
+                    return Task::none();
+                }
+                self.queue_checked_download_by_id(connection_id, tab_id, check_id)
+            }
+            _ => self.queue_checked_download_by_id(connection_id, tab_id, check_id),
+        }
+    }
+
+    /// Handle local hashes for a download's duplicate check
+    ///
+    /// Asks the server for the file's hash if any local file has its size.
+    pub fn handle_file_download_scanned(
+        &mut self,
+        connection_id: usize,
+        tab_id: TabId,
+        check_id: Uuid,
+        copies: Vec<(PathBuf, String)>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+        let Some(check) = conn
+            .files_management
+            .tab_by_id_mut(tab_id)
+            .and_then(|tab| tab.download_check_mut(check_id))
+        else {
+            return Task::none();
+        };
+        let Some(size) = check.size.filter(|_| !copies.is_empty()) else {
+            return self.queue_checked_download_by_id(connection_id, tab_id, check_id);
+        };
+        check.copies = copies;
+
+        let request = ClientMessage::FileStat {
+            files: vec![FileStatQuery {
+                path: check.remote_path.clone(),
+                size,
+            }],
+            root: check.remote_root,
+        };
+        match conn.send(request) {
+            Ok(message_id) => {
+                conn.pending_requests.track(
+                    message_id,
+                    ResponseRouting::DownloadCheckStat { tab_id, check_id },
+                );
+                Task::none()
+            }
+            Err(_) => self.queue_checked_download_by_id(connection_id, tab_id, check_id),
+        }
+    }
+
+    /// Handle Download Anyway in the duplicate download dialog
+    pub fn handle_file_download_duplicate_confirm(&mut self) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(tab) = self
+            .connections
+            .get(&conn_id)
+            .map(|conn| conn.files_management.active_tab())
+        else {
+            return Task::none();
+        };
+        let (tab_id, Some(check_id)) = (tab.id, tab.duplicate_download().map(|check| check.id))
+        else {
+            return Task::none();
+        };
+        self.queue_checked_download_by_id(conn_id, tab_id, check_id)
+    }
+
+    /// Handle Skip in the duplicate download dialog - nothing is downloaded
+    pub fn handle_file_download_duplicate_skip(&mut self) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+        {
+            let tab = conn.files_management.active_tab_mut();
+            if let Some(check_id) = tab.duplicate_download().map(|check| check.id) {
+                tab.take_download_check(check_id);
+            }
+        }
+        Task::none()
+    }
+
+    /// Remove a download check from its tab and queue the download
+    fn queue_checked_download_by_id(
+        &mut self,
+        connection_id: usize,
+        tab_id: TabId,
+        check_id: Uuid,
+    ) -> Task<Message> {
+        let Some(check) = self
+            .connections
+            .get_mut(&connection_id)
+            .and_then(|conn| conn.files_management.tab_by_id_mut(tab_id))
+            .and_then(|tab| tab.take_download_check(check_id))
+        else {
+            return Task::none();
+        };
+        self.queue_checked_download(connection_id, check)
+    }
+
+    /// Queue the download a check was made for
+    fn queue_checked_download(
+        &mut self,
+        connection_id: usize,
+        check: PendingDownloadCheck,
+    ) -> Task<Message> {
+        self.queue_download_now(
+            connection_id,
+            check.remote_path,
+            false,
+            check.remote_root,
+            check.download_dir,
+            check.collision_policy,
+            None,
+        )
+    }
+
+    /// Create the transfer for a download and show a toast
+    #[allow(clippy::too_many_arguments)]
+    fn queue_download_now(
+        &mut self,
+        connection_id: usize,
+        remote_path: String,
+        is_directory: bool,
+        remote_root: bool,
+        download_dir: PathBuf,
+        collision_policy: CollisionPolicy,
+        include: Option<Vec<String>>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get(&connection_id) else {
+            return Task::none();
+        };
 
         // Extract filename from remote path
         // For single files: use the filename
@@ -134,7 +360,7 @@ impl NexusApp {
                 &conn.connection_info.server_name,
                 &conn.connection_info.address,
             );
-            let path = std::path::PathBuf::from(&download_dir).join(&safe_name);
+            let path = download_dir.join(&safe_name);
             (path, safe_name)
         } else {
             // Extract last path component for the local filename/folder
@@ -144,13 +370,13 @@ impl NexusApp {
                 .next()
                 .expect("non-empty path")
                 .to_string();
-            let path = std::path::PathBuf::from(&download_dir).join(&filename);
+            let path = download_dir.join(&filename);
             (path, filename)
         };
 
         // Create the transfer
         let queue_position = self.transfer_manager.next_queue_position();
-        let transfer = crate::transfers::Transfer::new_download(
+        let mut transfer = crate::transfers::Transfer::new_download(
             conn.connection_info.clone(),
             remote_path,
             remote_root,
@@ -159,6 +385,8 @@ impl NexusApp {
             conn.bookmark_id,
             queue_position,
         );
+        transfer.collision_policy = collision_policy;
+        transfer.include = include;
 
         // Add to transfer manager
         self.transfer_manager.add(transfer);
@@ -178,6 +406,311 @@ impl NexusApp {
         Task::none()
     }
 
+    /// Resolve the download folder for a connection
+    ///
+    /// Starts from the global download folder and applies the bookmark's
+    /// download folder (subfolder or absolute override) when there is one.
+    pub(super) fn download_dir_for(&self, bookmark_id: Option<Uuid>) -> PathBuf {
+        let default_dir = self
+            .config
+            .settings
+            .download_path
+            .clone()
+            .or_else(crate::config::settings::default_download_path)
+            .unwrap_or_else(|| ".".to_string());
+
+        match bookmark_id.and_then(|id| self.config.get_bookmark(id)) {
+            Some(bookmark) => bookmark.download_dir(&default_dir),
+            None => PathBuf::from(default_dir),
+        }
+    }
+
+    // ==================== Download To ====================
+
+    /// Handle "Download To" request - opens the destination folder dialog
+    ///
+    /// The dialog is pre-filled with the folder the download would normally use.
+    pub fn handle_file_download_to(
+        &mut self,
+        remote_path: String,
+        is_directory: bool,
+    ) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get(&conn_id) else {
+            return Task::none();
+        };
+
+        let bookmark_id = conn.bookmark_id;
+        let remote_root = conn.files_management.active_tab().viewing_root;
+        let folder = self
+            .download_dir_for(bookmark_id)
+            .to_string_lossy()
+            .into_owned();
+
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+        conn.files_management.active_tab_mut().pending_download_to = Some(PendingDownloadTo {
+            remote_path,
+            is_directory,
+            remote_root,
+            folder,
+            remember: false,
+            can_remember: bookmark_id.is_some(),
+            collision_policy: self.config.settings.collision_policy,
+        });
+
+        Task::none()
+    }
+
+    /// Handle Browse button in the Download To dialog - opens folder picker
+    pub fn handle_file_download_to_browse(&mut self) -> Task<Message> {
+        let Some(pending) = self.active_pending_download_to() else {
+            return Task::none();
+        };
+        let initial_dir = pending.folder.clone();
+
+        Task::future(async move {
+            let folder = rfd::AsyncFileDialog::new()
+                .set_title(t("file-picker-download-to-title"))
+                .set_directory(&initial_dir)
+                .pick_folder()
+                .await;
+
+            Message::FileDownloadToFolderSelected(
+                folder.map(|handle| handle.path().to_string_lossy().into_owned()),
+            )
+        })
+    }
+
+    /// Handle folder picker result for the Download To dialog
+    pub fn handle_file_download_to_folder_selected(
+        &mut self,
+        folder: Option<String>,
+    ) -> Task<Message> {
+        // None means the user cancelled the picker - keep the current folder
+        if let Some(folder) = folder
+            && let Some(pending) = self.active_pending_download_to_mut()
+        {
+            pending.folder = folder;
+        }
+        Task::none()
+    }
+
+    /// Handle "Remember for this server" checkbox in the Download To dialog
+    pub fn handle_file_download_to_remember_toggled(&mut self, remember: bool) -> Task<Message> {
+        if let Some(pending) = self.active_pending_download_to_mut() {
+            pending.remember = remember;
+        }
+        Task::none()
+    }
+
+    /// Handle collision policy selection in the Download To dialog
+    pub fn handle_file_download_to_collision_policy_selected(
+        &mut self,
+        policy: CollisionPolicy,
+    ) -> Task<Message> {
+        if let Some(pending) = self.active_pending_download_to_mut() {
+            pending.collision_policy = policy;
+        }
+        Task::none()
+    }
+
+    /// Handle Download button in the Download To dialog
+    ///
+    /// Queues the download into the chosen folder and, if requested, stores the
+    /// folder on the connection's bookmark for future downloads.
+    pub fn handle_file_download_to_confirm(&mut self) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+        let bookmark_id = conn.bookmark_id;
+        let Some(pending) = conn
+            .files_management
+            .active_tab_mut()
+            .pending_download_to
+            .take()
+        else {
+            return Task::none();
+        };
+
+        if pending.remember
+            && let Some(bookmark) = bookmark_id.and_then(|id| self.config.get_bookmark_mut(id))
+        {
+            bookmark.download_folder = pending.folder.clone();
+            let _ = self.config.save();
+        }
+
+        self.queue_download_to(
+            pending.remote_path,
+            pending.is_directory,
+            pending.remote_root,
+            PathBuf::from(pending.folder),
+            pending.collision_policy,
+            None,
+        )
+    }
+
+    /// Handle Cancel button in the Download To dialog
+    pub fn handle_file_download_to_cancel(&mut self) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+        {
+            conn.files_management.active_tab_mut().pending_download_to = None;
+        }
+        Task::none()
+    }
+
+    /// Get the active tab's pending Download To dialog state
+    fn active_pending_download_to(&self) -> Option<&PendingDownloadTo> {
+        let conn = self.connections.get(&self.active_connection?)?;
+        conn.files_management
+            .active_tab()
+            .pending_download_to
+            .as_ref()
+    }
+
+    /// Get the active tab's pending Download To dialog state (mutable)
+    fn active_pending_download_to_mut(&mut self) -> Option<&mut PendingDownloadTo> {
+        let conn = self.connections.get_mut(&self.active_connection?)?;
+        conn.files_management
+            .active_tab_mut()
+            .pending_download_to
+            .as_mut()
+    }
+
+    // ==================== Download Selected ====================
+
+    /// Handle "Download Selected" request - opens the selection dialog
+    ///
+    /// Requests the directory manifest from the server; the dialog shows a
+    /// loading state until the response arrives.
+    pub fn handle_file_download_select(&mut self, remote_path: String) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        let tab_id = conn.files_management.active_tab_id();
+        let remote_root = conn.files_management.active_tab().viewing_root;
+        let mut pending = PendingDownloadSelect::new(remote_path.clone(), remote_root);
+
+        match conn.send(ClientMessage::FileManifest {
+            path: remote_path,
+            root: remote_root,
+        }) {
+            Ok(message_id) => {
+                conn.pending_requests
+                    .track(message_id, ResponseRouting::FileManifestResult { tab_id });
+            }
+            Err(e) => {
+                pending.error = Some(format!("{}: {}", t("err-send-failed"), e));
+            }
+        }
+
+        conn.files_management
+            .active_tab_mut()
+            .pending_download_select = Some(pending);
+
+        Task::none()
+    }
+
+    /// Handle a file or folder checkbox in the Download Selected dialog
+    pub fn handle_file_download_select_toggled(
+        &mut self,
+        path: String,
+        selected: bool,
+    ) -> Task<Message> {
+        if let Some(pending) = self.active_pending_download_select_mut() {
+            pending.set_selected(&path, selected);
+        }
+        Task::none()
+    }
+
+    /// Handle Select All / Select None in the Download Selected dialog
+    pub fn handle_file_download_select_all(&mut self, selected: bool) -> Task<Message> {
+        if let Some(pending) = self.active_pending_download_select_mut() {
+            pending.set_all_selected(selected);
+        }
+        Task::none()
+    }
+
+    /// Handle Download button in the Download Selected dialog
+    ///
+    /// A full selection is queued as a normal directory download so it keeps
+    /// picking up files added later when resumed.
+    pub fn handle_file_download_select_confirm(&mut self) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+        let bookmark_id = conn.bookmark_id;
+        let tab = conn.files_management.active_tab_mut();
+        let Some(pending) = tab.pending_download_select.as_ref() else {
+            return Task::none();
+        };
+
+        // The view disables the button in these cases; guard anyway
+        let include = if pending.all_selected() {
+            None
+        } else {
+            let paths = pending.selected_paths();
+            if paths.is_empty() || paths.len() > MAX_DOWNLOAD_INCLUDE_COUNT {
+                return Task::none();
+            }
+            Some(paths)
+        };
+
+        let Some(pending) = tab.pending_download_select.take() else {
+            return Task::none();
+        };
+        let download_dir = self.download_dir_for(bookmark_id);
+        let collision_policy = self.config.settings.collision_policy;
+        self.queue_download_to(
+            pending.remote_path,
+            true,
+            pending.remote_root,
+            download_dir,
+            collision_policy,
+            include,
+        )
+    }
+
+    /// Handle Cancel button in the Download Selected dialog
+    pub fn handle_file_download_select_cancel(&mut self) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+        {
+            // The folder's manifest may still be loading
+            let tab_id = conn.files_management.active_tab_id();
+            conn.cancel_requests(|routing| {
+                matches!(routing, ResponseRouting::FileManifestResult { tab_id: id } if *id == tab_id)
+            });
+            conn.files_management
+                .active_tab_mut()
+                .pending_download_select = None;
+        }
+        Task::none()
+    }
+
+    /// Get the active tab's pending Download Selected dialog state (mutable)
+    fn active_pending_download_select_mut(&mut self) -> Option<&mut PendingDownloadSelect> {
+        let conn = self.connections.get_mut(&self.active_connection?)?;
+        conn.files_management
+            .active_tab_mut()
+            .pending_download_select
+            .as_mut()
+    }
+
     // ==================== Uploads ====================
 
     /// Handle upload request - opens file picker for multiple files
@@ -213,11 +746,14 @@ impl NexusApp {
         )
     }
 
-    /// Handle file picker result - queue uploads
+    /// Handle file picker result - scan the files to preview the upload
+    ///
+    /// The destinations are checked with `FileStat` once the scan finishes, so
+    /// files already on the server can be skipped or overwritten up front.
     pub fn handle_file_upload_selected(
         &mut self,
         destination: String,
-        paths: Vec<std::path::PathBuf>,
+        paths: Vec<PathBuf>,
     ) -> Task<Message> {
         if paths.is_empty() {
             return Task::none();
@@ -231,48 +767,196 @@ impl NexusApp {
         };
 
         // Get the current viewing mode (root or user area)
-        let remote_root = conn.files_management.active_tab().viewing_root;
+        let tab = conn.files_management.active_tab();
+        let tab_id = tab.id;
+        let remote_root = tab.viewing_root;
+
+        let items: Vec<UploadItem> = paths
+            .into_iter()
+            .map(|path| UploadItem::new(&destination, path))
+            .collect();
+
+        Task::perform(scan_uploads(items), move |scan| {
+            Message::FileUploadScanned(conn_id, tab_id, remote_root, scan)
+        })
+    }
+
+    /// Handle a finished upload scan - ask the server about the destinations
+    ///
+    /// Uploads with too many files to check (or that couldn't be scanned) are
+    /// queued right away, as is everything if the request can't be sent.
+    pub fn handle_file_upload_scanned(
+        &mut self,
+        connection_id: usize,
+        tab_id: TabId,
+        remote_root: bool,
+        scan: UploadScan,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+
+        let files = match scan.files {
+            Some(files) if !files.is_empty() => files,
+            _ => {
+                return self.queue_uploads(
+                    connection_id,
+                    remote_root,
+                    PlannedUpload::all(scan.items),
+                );
+            }
+        };
+
+        let pending = PendingUploadPreview::new(remote_root, scan.items, files);
+        let Ok(message_id) = conn.send(ClientMessage::FileStat {
+            files: pending.queries(),
+            root: remote_root,
+        }) else {
+            return self.queue_uploads(
+                connection_id,
+                remote_root,
+                PlannedUpload::all(pending.items),
+            );
+        };
+        conn.pending_requests.track(
+            message_id,
+            ResponseRouting::FileStatResult {
+                tab_id,
+                preview_id: pending.id,
+            },
+        );
+
+        if let Some(tab) = conn.files_management.tab_by_id_mut(tab_id) {
+            tab.pending_upload_preview = Some(pending);
+        }
+        Task::none()
+    }
+
+    /// Handle local hashes for the upload preview - settle and resolve it
+    pub fn handle_file_upload_hashed(
+        &mut self,
+        connection_id: usize,
+        tab_id: TabId,
+        preview_id: Uuid,
+        hashes: Vec<(usize, Option<String>)>,
+    ) -> Task<Message> {
+        let Some(pending) = self
+            .connections
+            .get_mut(&connection_id)
+            .and_then(|conn| conn.files_management.tab_by_id_mut(tab_id))
+            .and_then(|tab| tab.pending_upload_preview.as_mut())
+            .filter(|pending| pending.id == preview_id)
+        else {
+            return Task::none();
+        };
+
+        pending.set_hashes(hashes);
+        self.resolve_upload_preview(connection_id, tab_id)
+    }
+
+    /// Queue a compared upload preview unless the user has to decide
+    ///
+    /// Without conflicts, new files are queued and identical ones skipped.
+    /// Otherwise the preview stays on the tab and its dialog is shown.
+    pub fn resolve_upload_preview(&mut self, connection_id: usize, tab_id: TabId) -> Task<Message> {
+        let Some(tab) = self
+            .connections
+            .get_mut(&connection_id)
+            .and_then(|conn| conn.files_management.tab_by_id_mut(tab_id))
+        else {
+            return Task::none();
+        };
+        let Some(pending) = &tab.pending_upload_preview else {
+            return Task::none();
+        };
+        if pending.hashing || pending.needs_decision() {
+            return Task::none();
+        }
+
+        let Some(pending) = tab.pending_upload_preview.take() else {
+            return Task::none();
+        };
+        self.queue_uploads(connection_id, pending.remote_root, pending.plan(false))
+    }
+
+    /// Handle Skip Existing / Overwrite in the upload preview dialog
+    pub fn handle_file_upload_preview_confirm(&mut self, overwrite: bool) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+        let tab = conn.files_management.active_tab_mut();
+
+        // The dialog only offers a choice once every file is compared
+        if tab
+            .pending_upload_preview
+            .as_ref()
+            .is_none_or(|pending| pending.statuses.is_none())
+        {
+            return Task::none();
+        }
+        let Some(pending) = tab.pending_upload_preview.take() else {
+            return Task::none();
+        };
+
+        self.queue_uploads(conn_id, pending.remote_root, pending.plan(overwrite))
+    }
+
+    /// Handle Cancel in the upload preview dialog - nothing is uploaded
+    pub fn handle_file_upload_preview_cancel(&mut self) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+        {
+            conn.files_management
+                .active_tab_mut()
+                .pending_upload_preview = None;
+        }
+        Task::none()
+    }
+
+    /// Queue planned uploads on a connection and show a toast
+    pub fn queue_uploads(
+        &mut self,
+        connection_id: usize,
+        remote_root: bool,
+        uploads: Vec<PlannedUpload>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get(&connection_id) else {
+            return Task::none();
+        };
+
+        // Everything was already on the server
+        if uploads.is_empty() {
+            self.toasts
+                .push(toast(&t("toast-upload-nothing-new")).level(ToastLevel::Info));
+            return Task::none();
+        }
 
         let is_queued = self.config.settings.queue_transfers;
-        let upload_count = paths.len();
-        let first_filename = paths
+        let upload_count = uploads.len();
+        let first_filename = uploads
             .first()
-            .and_then(|p| p.file_name())
+            .and_then(|upload| upload.item.local_path.file_name())
             .and_then(|n| n.to_str())
             .unwrap_or("file")
             .to_string();
 
         // Queue each selected file/directory as a separate upload
-        for local_path in paths {
-            let is_directory = local_path.is_dir();
-
-            // For directory uploads, append the directory name to the destination
-            // so the server creates the directory structure (e.g., "/Uploads/MyFolder/")
-            let remote_path = if is_directory {
-                if let Some(dir_name) = local_path.file_name().and_then(|n| n.to_str()) {
-                    if destination.is_empty() || destination == "/" {
-                        format!("/{dir_name}")
-                    } else {
-                        format!("{}/{}", destination.trim_end_matches('/'), dir_name)
-                    }
-                } else {
-                    destination.clone()
-                }
-            } else {
-                destination.clone()
-            };
-
-            // Create the transfer
+        for upload in uploads {
             let queue_position = self.transfer_manager.next_queue_position();
-            let transfer = crate::transfers::Transfer::new_upload(
+            let mut transfer = crate::transfers::Transfer::new_upload(
                 conn.connection_info.clone(),
-                remote_path,
+                upload.item.remote_path,
                 remote_root,
-                is_directory,
-                local_path,
+                upload.item.is_directory,
+                upload.item.local_path,
                 conn.bookmark_id,
                 queue_position,
             );
+            transfer.include = upload.include;
+            transfer.overwrite = upload.overwrite;
 
             // Add to transfer manager
             self.transfer_manager.add(transfer);
@@ -315,7 +999,7 @@ impl NexusApp {
     /// Handle file dropped on window
     ///
     /// If we're in a valid upload context (Files panel active, uploadable folder,
-    /// file_upload permission), queue the dropped file/folder for upload.
+    /// file_upload permission), upload the dropped file/folder.
     pub fn handle_file_drag_dropped(&mut self, path: std::path::PathBuf) -> Task<Message> {
         // Clear dragging state
         self.dragging_files = false;
@@ -332,60 +1016,9 @@ impl NexusApp {
             return Task::none();
         };
 
-        // Get upload destination (current directory)
+        // Upload into the current directory, with the same preview as the picker
         let destination = conn.files_management.active_tab().current_path.clone();
-        let remote_root = conn.files_management.active_tab().viewing_root;
-        let is_directory = path.is_dir();
-        let path_filename = path
-            .file_name()
-            .and_then(|n| n.to_str())
-            .map(|s| s.to_string());
-
-        // For directory uploads, append the directory name to the destination
-        // so the server creates the directory structure (e.g., "/Uploads/MyFolder/")
-        let remote_path = if is_directory {
-            if let Some(dir_name) = path.file_name().and_then(|n| n.to_str()) {
-                if destination.is_empty() || destination == "/" {
-                    format!("/{dir_name}")
-                } else {
-                    format!("{}/{}", destination.trim_end_matches('/'), dir_name)
-                }
-            } else {
-                destination.clone()
-            }
-        } else {
-            destination.clone()
-        };
-
-        // Create the transfer
-        let queue_position = self.transfer_manager.next_queue_position();
-        let transfer = crate::transfers::Transfer::new_upload(
-            conn.connection_info.clone(),
-            remote_path,
-            remote_root,
-            is_directory,
-            path,
-            conn.bookmark_id,
-            queue_position,
-        );
-
-        // Add to transfer manager
-        self.transfer_manager.add(transfer);
-
-        // Save transfers to disk
-        let _ = self.transfer_manager.save();
-
-        // Show toast feedback
-        let filename = path_filename.as_deref().unwrap_or("file");
-        let toast_text = if self.config.settings.queue_transfers {
-            t_args("toast-upload-queued", &[("filename", filename)])
-        } else {
-            t_args("toast-upload-started", &[("filename", filename)])
-        };
-        self.toasts
-            .push(toast(&toast_text).level(ToastLevel::Success));
-
-        Task::none()
+        self.handle_file_upload_selected(destination, vec![path])
     }
 
     /// Handle drag leaving window
@@ -0,0 +1,245 @@
+//! Upload folder watch handlers (auto-upload drop folder)
+
+use std::path::{Path, PathBuf};
+
+use iced::Task;
+use iced_toasts::{ToastLevel, toast};
+
+use crate::NexusApp;
+use crate::i18n::{t, t_args};
+use crate::transfers::{Transfer, UploadWatchState, WatchScan, scan_watch_folder};
+use crate::types::{Message, ServerConnection, UploadWatch};
+use crate::views::constants::PERMISSION_FILE_UPLOAD;
+
+impl NexusApp {
+    /// Get the upload watch configured on a connection's bookmark
+    ///
+    /// Ad-hoc connections have no bookmark and therefore never watch a folder.
+    pub fn upload_watch_for(&self, conn: &ServerConnection) -> Option<&UploadWatch> {
+        conn.bookmark_id
+            .and_then(|id| self.config.get_bookmark(id))
+            .and_then(|bookmark| bookmark.upload_watch.as_ref())
+    }
+
+    /// Handle "Watch Local Folder" - opens a folder picker for the drop folder
+    pub fn handle_file_watch_folder(&mut self, remote_path: String) -> Task<Message> {
+        Task::perform(
+            async move {
+                let folder = rfd::AsyncFileDialog::new()
+                    .set_title(t("folder-picker-upload-watch-title"))
+                    .pick_folder()
+                    .await
+                    .map(|handle| handle.path().to_path_buf());
+                Message::FileWatchFolderSelected(remote_path, folder)
+            },
+            |msg| msg,
+        )
+    }
+
+    /// Handle folder picker result - save the watch on the connection's bookmark
+    ///
+    /// A bookmark watches at most one folder, so this replaces any existing watch.
+    pub fn handle_file_watch_folder_selected(
+        &mut self,
+        remote_path: String,
+        folder: Option<PathBuf>,
+    ) -> Task<Message> {
+        // User cancelled the picker
+        let Some(folder) = folder else {
+            return Task::none();
+        };
+
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+        let Some(bookmark_id) = conn.bookmark_id else {
+            return Task::none();
+        };
+        let Some(bookmark) = self.config.get_bookmark_mut(bookmark_id) else {
+            return Task::none();
+        };
+
+        bookmark.upload_watch = Some(UploadWatch {
+            local_folder: folder.to_string_lossy().into_owned(),
+            remote_path,
+            remote_root: conn.files_management.active_tab().viewing_root,
+        });
+        let _ = self.config.save();
+
+        // Forget scan state from any previously watched folder
+        conn.upload_watch = UploadWatchState::default();
+
+        let folder_name = local_display_name(&folder);
+        self.toasts.push(
+            toast(&t_args(
+                "toast-upload-watch-started",
+                &[("folder", &folder_name)],
+            ))
+            .level(ToastLevel::Success),
+        );
+
+        Task::none()
+    }
+
+    /// Handle "Stop Watching Folder" - remove the watch from the connection's bookmark
+    pub fn handle_file_unwatch_folder(&mut self) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+        let Some(bookmark_id) = conn.bookmark_id else {
+            return Task::none();
+        };
+        let Some(bookmark) = self.config.get_bookmark_mut(bookmark_id) else {
+            return Task::none();
+        };
+
+        let Some(watch) = bookmark.upload_watch.take() else {
+            return Task::none();
+        };
+        let _ = self.config.save();
+
+        conn.upload_watch = UploadWatchState::default();
+
+        let folder_name = local_display_name(Path::new(&watch.local_folder));
+        self.toasts.push(
+            toast(&t_args(
+                "toast-upload-watch-stopped",
+                &[("folder", &folder_name)],
+            ))
+            .level(ToastLevel::Info),
+        );
+
+        Task::none()
+    }
+
+    /// Handle the periodic watch tick - scan every watched folder in the background
+    ///
+    /// Connections without upload permission are skipped, as is any connection
+    /// whose previous scan (which may be hashing large files) is still running.
+    pub fn handle_upload_watch_tick(&mut self) -> Task<Message> {
+        let mut tasks = Vec::new();
+
+        for (&connection_id, conn) in &mut self.connections {
+            let Some(watch) = conn
+                .bookmark_id
+                .and_then(|id| self.config.get_bookmark(id))
+                .and_then(|bookmark| bookmark.upload_watch.as_ref())
+            else {
+                continue;
+            };
+            if conn.upload_watch.scanning || !conn.has_permission(PERMISSION_FILE_UPLOAD) {
+                continue;
+            }
+
+            conn.upload_watch.scanning = true;
+            tasks.push(Task::perform(
+                scan_watch_folder(
+                    PathBuf::from(&watch.local_folder),
+                    conn.upload_watch.seen.clone(),
+                    conn.upload_watch.handled.clone(),
+                ),
+                move |scan| Message::UploadWatchScanned(connection_id, scan),
+            ));
+        }
+
+        Task::batch(tasks)
+    }
+
+    /// Handle a finished folder scan - queue uploads for new content
+    ///
+    /// Files whose hash was already uploaded for this bookmark are marked as
+    /// handled without uploading them again.
+    pub fn handle_upload_watch_scanned(
+        &mut self,
+        connection_id: usize,
+        scan: WatchScan,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+        conn.upload_watch.scanning = false;
+
+        // The watch may have been removed or pointed elsewhere while scanning
+        let Some(bookmark_id) = conn.bookmark_id else {
+            return Task::none();
+        };
+        let Some(watch) = self
+            .config
+            .get_bookmark(bookmark_id)
+            .and_then(|bookmark| bookmark.upload_watch.as_ref())
+        else {
+            return Task::none();
+        };
+        if scan.folder != Path::new(&watch.local_folder) {
+            return Task::none();
+        }
+
+        conn.upload_watch.apply_scan(&scan);
+
+        let mut queued: Vec<PathBuf> = Vec::new();
+        for file in scan.ready {
+            conn.upload_watch
+                .handled
+                .insert(file.path.clone(), file.stamp);
+            if self.upload_history.contains(bookmark_id, &file.sha256) {
+                continue;
+            }
+
+            let queue_position = self.transfer_manager.next_queue_position();
+            let transfer = Transfer::new_upload(
+                conn.connection_info.clone(),
+                watch.remote_path.clone(),
+                watch.remote_root,
+                false,
+                file.path.clone(),
+                Some(bookmark_id),
+                queue_position,
+            );
+            self.transfer_manager.add(transfer);
+            self.upload_history.insert(bookmark_id, file.sha256);
+            queued.push(file.path);
+        }
+
+        if queued.is_empty() {
+            return Task::none();
+        }
+
+        let _ = self.transfer_manager.save();
+        let _ = self.upload_history.save();
+
+        // Same feedback as a manual upload (single file: show name, multiple: show count)
+        let is_queued = self.config.settings.queue_transfers;
+        let toast_text = if let [path] = queued.as_slice() {
+            let filename = local_display_name(path);
+            if is_queued {
+                t_args("toast-upload-queued", &[("filename", &filename)])
+            } else {
+                t_args("toast-upload-started", &[("filename", &filename)])
+            }
+        } else {
+            let count_str = queued.len().to_string();
+            if is_queued {
+                t_args("toast-uploads-queued", &[("count", &count_str)])
+            } else {
+                t_args("toast-uploads-started", &[("count", &count_str)])
+            }
+        };
+        self.toasts
+            .push(toast(&toast_text).level(ToastLevel::Success));
+
+        Task::none()
+    }
+}
+
+/// Last component of a local path for display, falling back to the full path
+fn local_display_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.to_string_lossy().into_owned())
+}
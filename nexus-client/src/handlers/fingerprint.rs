@@ -1,8 +1,10 @@
 //! Certificate fingerprint mismatch handlers
 
 use iced::Task;
+use uuid::Uuid;
 
 use crate::NexusApp;
+use crate::config::settings::Tip;
 use crate::history::rotate_fingerprint;
 use crate::i18n::t;
 use crate::transfers::update_registry_fingerprint;
@@ -11,26 +13,14 @@ use crate::types::Message;
 impl NexusApp {
     /// Accept new certificate fingerprint (update stored fingerprint and complete connection)
     pub fn handle_accept_new_fingerprint(&mut self) -> Task<Message> {
+        self.dismiss_tip(Tip::Fingerprint);
         if let Some(mismatch) = self.fingerprint_mismatch_queue.pop_front() {
-            let new_fingerprint = mismatch.received.clone();
-            let old_fingerprint = mismatch.expected.clone();
-
-            // Rotate history files from old fingerprint to new fingerprint
-            let _ = rotate_fingerprint(&old_fingerprint, &new_fingerprint);
-
-            // Update the stored fingerprint (handle case where bookmark was deleted)
-            if let Some(bookmark) = self.config.get_bookmark_mut(mismatch.bookmark_id) {
-                bookmark.certificate_fingerprint = Some(new_fingerprint.clone());
-                let _ = self.config.save();
-            }
-
-            // Update any queued/paused transfers for this bookmark with the new fingerprint
-            self.transfer_manager
-                .update_fingerprint_for_bookmark(mismatch.bookmark_id, &new_fingerprint);
-            let _ = self.transfer_manager.save();
-
-            // Also update the transfer registry (for transfers waiting to execute)
-            update_registry_fingerprint(mismatch.bookmark_id, &new_fingerprint);
+            self.update_stored_fingerprint(
+                mismatch.bookmark_id,
+                &mismatch.expected,
+                &mismatch.received,
+                mismatch.identity_key.as_deref(),
+            );
 
             // Complete the connection that was pending
             return self.handle_bookmark_connection_result(
@@ -42,8 +32,42 @@ impl NexusApp {
         Task::none()
     }
 
+    /// Replace a bookmark's stored fingerprint (and identity key) with a new one
+    ///
+    /// Also moves history and pending transfers over to the new fingerprint.
+    /// An empty `old_fingerprint` means the server is new, so there's nothing to move.
+    pub fn update_stored_fingerprint(
+        &mut self,
+        bookmark_id: Uuid,
+        old_fingerprint: &str,
+        new_fingerprint: &str,
+        identity_key: Option<&str>,
+    ) {
+        // Update the stored fingerprint (handle case where bookmark was deleted)
+        if let Some(bookmark) = self.config.get_bookmark_mut(bookmark_id) {
+            bookmark.trust_certificate(new_fingerprint, identity_key);
+            let _ = self.config.save();
+        }
+
+        if old_fingerprint.is_empty() {
+            return;
+        }
+
+        // Rotate history files from old fingerprint to new fingerprint
+        let _ = rotate_fingerprint(old_fingerprint, new_fingerprint);
+
+        // Update any queued/paused transfers for this bookmark with the new fingerprint
+        self.transfer_manager
+            .update_fingerprint_for_bookmark(bookmark_id, new_fingerprint);
+        let _ = self.transfer_manager.save();
+
+        // Also update the transfer registry (for transfers waiting to execute)
+        update_registry_fingerprint(bookmark_id, new_fingerprint);
+    }
+
     /// Reject new certificate fingerprint (cancel connection)
     pub fn handle_cancel_fingerprint_mismatch(&mut self) -> Task<Message> {
+        self.dismiss_tip(Tip::Fingerprint);
         self.fingerprint_mismatch_queue.pop_front();
 
         if self.fingerprint_mismatch_queue.is_empty() {
@@ -0,0 +1,118 @@
+//! Friends list
+//!
+//! Friends are usernames saved in the server's bookmark. The client watches
+//! their presence with a `PresenceSubscribe` request, which works without
+//! the user_list permission, and shows them in the Friends section of the
+//! user list. Connections without a bookmark have no friends list.
+
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+use nexus_common::validators::{self, UsernameError};
+
+use crate::NexusApp;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message};
+
+impl NexusApp {
+    /// Send the bookmark's friends list to the server
+    ///
+    /// Skipped when there's nothing to watch and nothing watched yet, so
+    /// connecting to a server without friends sends nothing.
+    pub fn sync_presence_subscriptions(&mut self, connection_id: usize) {
+        let Some(conn) = self.connections.get(&connection_id) else {
+            return;
+        };
+        let usernames = conn
+            .bookmark_id
+            .and_then(|id| self.config.get_bookmark(id))
+            .map(|bookmark| bookmark.friends.clone())
+            .unwrap_or_default();
+        if usernames.is_empty() && conn.friends.is_empty() {
+            return;
+        }
+        let _ = conn.send(ClientMessage::PresenceSubscribe { usernames });
+    }
+
+    /// Add or remove a friend on a connection's bookmark
+    ///
+    /// Saves the config and updates the server's presence subscriptions.
+    /// Returns the message to show, or an error message.
+    pub fn set_friend(
+        &mut self,
+        connection_id: usize,
+        username: &str,
+        friend: bool,
+    ) -> Result<String, String> {
+        if let Err(e) = validators::validate_username(username) {
+            return Err(match e {
+                UsernameError::Empty => t("err-username-empty"),
+                UsernameError::TooLong => t_args(
+                    "err-username-too-long",
+                    &[("max", &validators::MAX_USERNAME_LENGTH.to_string())],
+                ),
+                UsernameError::InvalidCharacters => t("err-username-invalid"),
+            });
+        }
+
+        let Some(bookmark) = self
+            .connections
+            .get(&connection_id)
+            .and_then(|conn| conn.bookmark_id)
+            .and_then(|id| self.config.get_bookmark_mut(id))
+        else {
+            return Err(t("err-friends-no-bookmark"));
+        };
+
+        let info = if friend {
+            if bookmark.friends.len() >= validators::MAX_PRESENCE_SUBSCRIPTIONS {
+                return Err(t_args(
+                    "err-friends-too-many",
+                    &[("max", &validators::MAX_PRESENCE_SUBSCRIPTIONS.to_string())],
+                ));
+            }
+            if !bookmark.add_friend(username) {
+                return Err(t_args("err-friend-exists", &[("username", username)]));
+            }
+            t_args("msg-friend-added", &[("username", username)])
+        } else {
+            if !bookmark.remove_friend(username) {
+                return Err(t_args("err-friend-not-found", &[("username", username)]));
+            }
+            if let Some(conn) = self.connections.get_mut(&connection_id) {
+                let username = username.to_lowercase();
+                conn.friends
+                    .retain(|presence| presence.username.to_lowercase() != username);
+            }
+            t_args("msg-friend-removed", &[("username", username)])
+        };
+
+        if let Err(e) = self.config.save() {
+            return Err(t_args(
+                "err-failed-save-config",
+                &[("error", &e.to_string())],
+            ));
+        }
+
+        self.sync_presence_subscriptions(connection_id);
+        Ok(info)
+    }
+
+    /// Handle the friend button in the user list toolbar (username)
+    pub fn handle_user_friend_toggled(&mut self, username: String) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let is_friend = self
+            .connections
+            .get(&conn_id)
+            .and_then(|conn| conn.bookmark_id)
+            .and_then(|id| self.config.get_bookmark(id))
+            .is_some_and(|bookmark| bookmark.is_friend(&username));
+
+        let message = match self.set_friend(conn_id, &username, !is_friend) {
+            Ok(info) => ChatMessage::info(info),
+            Err(error) => ChatMessage::error(error),
+        };
+        self.add_active_tab_message(conn_id, message)
+    }
+}
@@ -0,0 +1,201 @@
+//! Global hotkey handlers (push-to-talk and show/hide window)
+
+use global_hotkey::{GlobalHotKeyEvent, HotKeyState};
+use iced::Task;
+use iced_toasts::{ToastLevel, toast};
+
+use crate::NexusApp;
+use crate::hotkeys::WindowHotkey;
+#[cfg(target_os = "linux")]
+use crate::hotkeys::portal::{Binding, Shortcut, is_wayland_session};
+#[cfg(target_os = "linux")]
+use crate::i18n::t;
+use crate::i18n::t_args;
+#[cfg(target_os = "linux")]
+use crate::types::ChatMessage;
+use crate::types::Message;
+
+impl NexusApp {
+    /// Whether hotkeys are bound through the Wayland shortcuts portal
+    ///
+    /// False once the portal has failed, so `global-hotkey` is used instead.
+    pub fn portal_shortcuts_active(&self) -> bool {
+        #[cfg(target_os = "linux")]
+        {
+            is_wayland_session() && !self.portal_shortcuts_unavailable
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            false
+        }
+    }
+
+    /// Shortcuts to bind through the portal
+    ///
+    /// Push-to-talk is only bound while in a voice session that transmits.
+    #[cfg(target_os = "linux")]
+    pub fn portal_shortcut_bindings(&self) -> Vec<Binding> {
+        let mut bindings = Vec::new();
+
+        if self
+            .ptt_manager
+            .as_ref()
+            .is_some_and(|ptt| ptt.is_in_voice())
+        {
+            bindings.push(Binding {
+                shortcut: Shortcut::PushToTalk,
+                key: self.config.settings.audio.ptt_key.clone(),
+                description: t("hotkey-push-to-talk"),
+            });
+        }
+
+        if let Some(key) = &self.config.settings.toggle_window_key {
+            bindings.push(Binding {
+                shortcut: Shortcut::ToggleWindow,
+                key: key.clone(),
+                description: t("hotkey-toggle-window"),
+            });
+        }
+
+        bindings
+    }
+
+    /// Register the show/hide window hotkey from settings
+    ///
+    /// Replaces any previous registration. With the Wayland portal the
+    /// subscription binds the key instead, so nothing is registered here.
+    pub fn register_window_hotkey(&mut self) -> Task<Message> {
+        if let Some(hotkey) = &mut self.window_hotkey {
+            hotkey.unregister();
+        }
+
+        let Some(key) = self.config.settings.toggle_window_key.clone() else {
+            return Task::none();
+        };
+        if self.portal_shortcuts_active() {
+            return Task::none();
+        }
+
+        if self.window_hotkey.is_none() {
+            match WindowHotkey::new() {
+                Ok(hotkey) => self.window_hotkey = Some(hotkey),
+                Err(e) => return self.window_hotkey_failed(&e),
+            }
+        }
+
+        if let Some(hotkey) = &mut self.window_hotkey
+            && let Err(e) = hotkey.register(&key)
+        {
+            return self.window_hotkey_failed(&e);
+        }
+
+        Task::none()
+    }
+
+    /// Show a toast for a show/hide window hotkey that couldn't be registered
+    fn window_hotkey_failed(&mut self, error: &str) -> Task<Message> {
+        self.toasts.push(
+            toast(&t_args("err-window-hotkey-failed", &[("error", error)]))
+                .level(ToastLevel::Error),
+        );
+        Task::none()
+    }
+
+    /// Handle a raw event from the global hotkey subscription
+    ///
+    /// Presses of the show/hide window key toggle the window; everything
+    /// else is forwarded to the PTT manager.
+    pub fn handle_global_hotkey_event(&mut self, event: GlobalHotKeyEvent) -> Task<Message> {
+        if self
+            .window_hotkey
+            .as_ref()
+            .is_some_and(|hotkey| hotkey.matches(&event))
+        {
+            if event.state() == HotKeyState::Pressed {
+                return self.toggle_window_from_hotkey();
+            }
+            return Task::none();
+        }
+
+        self.handle_voice_ptt_event(event)
+    }
+
+    /// Handle a press or release of a portal shortcut (Wayland)
+    #[cfg(target_os = "linux")]
+    pub fn handle_global_shortcut(&mut self, shortcut: Shortcut, pressed: bool) -> Task<Message> {
+        match shortcut {
+            Shortcut::PushToTalk => {
+                if let Some(ref mut ptt) = self.ptt_manager
+                    && let Some(state) = ptt.handle_shortcut(pressed)
+                {
+                    return self.handle_voice_ptt_state_changed(state);
+                }
+                Task::none()
+            }
+            Shortcut::ToggleWindow if pressed => self.toggle_window_from_hotkey(),
+            Shortcut::ToggleWindow => Task::none(),
+        }
+    }
+
+    /// Handle the shortcuts portal being unavailable (Wayland)
+    ///
+    /// Falls back to `global-hotkey` for the rest of the session. Its key
+    /// grabs only fire while an X11 window has focus, so the user is told.
+    #[cfg(target_os = "linux")]
+    pub fn handle_global_shortcuts_unavailable(&mut self, error: String) -> Task<Message> {
+        self.portal_shortcuts_unavailable = true;
+        self.toasts.push(
+            toast(&t_args(
+                "err-global-shortcuts-unavailable",
+                &[("error", &error)],
+            ))
+            .level(ToastLevel::Warning),
+        );
+
+        let hotkey_task = self.register_window_hotkey();
+
+        if let Some(connection_id) = self.active_voice_connection
+            && let Some(ref mut ptt) = self.ptt_manager
+            && ptt.is_in_voice()
+            && let Err(e) = ptt.register_hotkey(&self.config.settings.audio.ptt_key)
+        {
+            let message_task = self.add_active_tab_message(
+                connection_id,
+                ChatMessage::error(t_args("err-voice-ptt-failed", &[("error", &e)])),
+            );
+            return Task::batch([hotkey_task, message_task]);
+        }
+
+        hotkey_task
+    }
+
+    /// Show or hide the window from the global hotkey
+    ///
+    /// Hides to the tray when there is one (Windows/Linux). Otherwise the
+    /// window is minimized when it's in front, and brought back to the front
+    /// when it's minimized or behind other windows.
+    fn toggle_window_from_hotkey(&mut self) -> Task<Message> {
+        #[cfg(not(target_os = "macos"))]
+        if self.tray_manager.is_some() || !self.window_visible {
+            return self.toggle_window_visibility();
+        }
+
+        let focused = self.window_focused;
+        iced::window::oldest().then(move |opt_id| {
+            let Some(id) = opt_id else {
+                return Task::none();
+            };
+            iced::window::is_minimized(id).then(move |is_minimized| {
+                if focused && !is_minimized.unwrap_or(false) {
+                    iced::window::minimize(id, true)
+                } else {
+                    Task::batch([
+                        iced::window::minimize(id, false),
+                        iced::window::gain_focus(id),
+                    ])
+                }
+            })
+        })
+    }
+}
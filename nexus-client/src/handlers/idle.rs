@@ -0,0 +1,97 @@
+//! Idle detection and automatic away status
+
+use std::time::{Duration, Instant};
+
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+
+use crate::NexusApp;
+use crate::config::settings::{AUTO_AWAY_MINUTES_MAX, AUTO_AWAY_MINUTES_MIN};
+use crate::idle;
+use crate::types::{Message, PendingRequests, ResponseRouting};
+
+impl NexusApp {
+    /// Handle auto away toggle
+    pub fn handle_auto_away_toggled(&mut self, enabled: bool) -> Task<Message> {
+        self.config.settings.auto_away.enabled = enabled;
+        if !enabled {
+            self.clear_auto_away();
+        }
+        Task::none()
+    }
+
+    /// Handle auto away idle minutes change
+    pub fn handle_auto_away_minutes_changed(&mut self, minutes: u16) -> Task<Message> {
+        self.config.settings.auto_away.minutes =
+            minutes.clamp(AUTO_AWAY_MINUTES_MIN, AUTO_AWAY_MINUTES_MAX);
+        Task::none()
+    }
+
+    /// Handle the periodic idle check by reading the system idle time
+    pub fn handle_idle_tick(&mut self) -> Task<Message> {
+        Task::perform(idle::idle_time(), Message::IdleTimeChecked)
+    }
+
+    /// Go away or come back depending on how long input has been idle
+    ///
+    /// Without a system idle time, only input seen in our own window counts.
+    pub fn handle_idle_time_checked(&mut self, system_idle: Option<Duration>) -> Task<Message> {
+        let auto_away = self.config.settings.auto_away;
+        if !auto_away.enabled {
+            return Task::none();
+        }
+
+        let idle = system_idle.unwrap_or_else(|| self.last_input.elapsed());
+        if idle >= auto_away.threshold() {
+            self.set_auto_away();
+        } else {
+            self.clear_auto_away();
+        }
+        Task::none()
+    }
+
+    /// Record keyboard or mouse input in the window
+    ///
+    /// Comes back right away instead of waiting for the next idle check.
+    pub fn note_user_input(&mut self) {
+        self.last_input = Instant::now();
+        if self.connections.values().any(|conn| conn.auto_away) {
+            self.clear_auto_away();
+        }
+    }
+
+    /// Mark every connection away that isn't already
+    ///
+    /// Connections where the user went away manually are left alone, so
+    /// their status message survives and activity doesn't bring them back.
+    fn set_auto_away(&mut self) {
+        for conn in self.connections.values_mut() {
+            let already_away = conn
+                .online_users
+                .iter()
+                .any(|user| user.nickname == conn.nickname && user.is_away);
+            if conn.auto_away || already_away {
+                continue;
+            }
+            if let Ok(message_id) = conn.send(ClientMessage::UserAway { message: None }) {
+                conn.pending_requests
+                    .track(message_id, ResponseRouting::AutoAwayResult);
+                conn.auto_away = true;
+            }
+        }
+    }
+
+    /// Bring back every connection that went away automatically
+    fn clear_auto_away(&mut self) {
+        for conn in self.connections.values_mut() {
+            if !conn.auto_away {
+                continue;
+            }
+            conn.auto_away = false;
+            if let Ok(message_id) = conn.send(ClientMessage::UserBack) {
+                conn.pending_requests
+                    .track(message_id, ResponseRouting::AutoBackResult);
+            }
+        }
+    }
+}
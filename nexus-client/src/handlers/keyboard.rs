@@ -1,24 +1,44 @@
 //! Keyboard and window event handling
 
 use iced::keyboard::{self, key};
+use iced::mouse;
 use iced::widget::{Id, operation};
 use iced::window;
 use iced::{Event, Task};
 
 use crate::NexusApp;
+use crate::quick_switcher;
 use crate::types::{
     ActivePanel, BookmarkEditMode, ChatTab, InputId, Message, NewsManagementMode,
-    UserManagementMode,
+    UserManagementMode, door_key_bytes,
 };
+use crate::user_list;
 use crate::voice::ptt::build_hotkey_string;
 
 impl NexusApp {
     /// Handle keyboard and window events (Tab, Enter, Escape, F5, file drag-and-drop)
-    pub fn handle_keyboard_event(&mut self, event: Event) -> Task<Message> {
-        // Handle PTT key capture when in settings and capture mode is active
+    ///
+    /// Popped-out chat windows only track their focus; everything else is
+    /// for the main window.
+    pub fn handle_keyboard_event(&mut self, id: window::Id, event: Event) -> Task<Message> {
+        if matches!(event, Event::Keyboard(_) | Event::Mouse(_)) {
+            self.note_user_input();
+        }
+
+        if self.main_window != Some(id) {
+            return match event {
+                Event::Window(window::Event::Focused) => self.handle_chat_window_focus(id, true),
+                Event::Window(window::Event::Unfocused) => self.handle_chat_window_focus(id, false),
+                _ => Task::none(),
+            };
+        }
+
+        // Handle PTT and show/hide window key capture when in settings and
+        // capture mode is active
         if let Some(form) = &self.settings_form
-            && form.ptt_capturing
+            && (form.ptt_capturing || form.window_key_capturing)
         {
+            let capturing_window_key = form.window_key_capturing;
             if let Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) = &event {
                 // Convert Iced key to our PTT key string format
                 let key_string = match key {
@@ -31,6 +51,7 @@ impl NexusApp {
                             // Escape cancels capture mode without changing the key
                             if let Some(form) = &mut self.settings_form {
                                 form.ptt_capturing = false;
+                                form.window_key_capturing = false;
                             }
                             return Task::none();
                         }
@@ -92,6 +113,9 @@ impl NexusApp {
 
                 if let Some(key_str) = key_string {
                     let hotkey_string = build_hotkey_string(modifiers, &key_str);
+                    if capturing_window_key {
+                        return self.update(Message::WindowKeyCaptured(hotkey_string));
+                    }
                     return self.update(Message::AudioPttKeyCaptured(hotkey_string));
                 }
             }
@@ -110,6 +134,9 @@ impl NexusApp {
                 window::Event::Unfocused => {
                     return self.update(Message::WindowUnfocused);
                 }
+                window::Event::Resized(size) => {
+                    return self.update(Message::WindowResized(*size));
+                }
                 window::Event::FileHovered(_) => {
                     return self.update(Message::FileDragHovered);
                 }
@@ -123,6 +150,85 @@ impl NexusApp {
             }
         }
 
+        // Drop a bookmark being dragged in the sidebar
+        if let Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) = event
+            && self.ui_state.bookmark_drag.is_some()
+        {
+            return self.handle_bookmark_drag_released();
+        }
+
+        // While a door is running every key goes to it (Leave button exits)
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key,
+            modifiers,
+            text,
+            ..
+        }) = &event
+            && self.active_panel() == ActivePanel::Doors
+            && let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get(&conn_id)
+            && let Some(session) = &conn.doors.session
+            && session.running
+        {
+            let application_cursor = session.application_cursor();
+            return match door_key_bytes(key, *modifiers, text.as_deref(), application_cursor) {
+                Some(bytes) => self.update(Message::DoorInput(bytes)),
+                None => Task::none(),
+            };
+        }
+
+        // Cmd/Ctrl+K opens or closes the quick switcher
+        if quick_switcher::is_toggle_key(&event) {
+            return self.update(Message::QuickSwitcherToggled);
+        }
+
+        // While the quick switcher is open, arrows move through its matches
+        if self.ui_state.quick_switcher.is_some()
+            && let Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                ..
+            }) = &event
+        {
+            match named {
+                key::Named::ArrowDown => return self.update(Message::QuickSwitcherNext),
+                key::Named::ArrowUp => return self.update(Message::QuickSwitcherPrevious),
+                key::Named::Escape => return self.update(Message::QuickSwitcherClosed),
+                _ => {}
+            }
+        }
+
+        // Cmd/Ctrl+U focuses the user list filter
+        if user_list::is_focus_key(&event) {
+            return self.update(Message::UserListFilterFocus);
+        }
+
+        // While the user list filter has focus, keys act on the selected user
+        // (unless the kick/ban dialog is open, which takes Enter and Escape)
+        if self.focused_field == InputId::UserListFilter
+            && let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get(&conn_id)
+            && conn.disconnect_dialog.is_none()
+            && let Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                modifiers,
+                ..
+            }) = &event
+        {
+            match named {
+                key::Named::ArrowDown => return self.update(Message::UserListSelectNext),
+                key::Named::ArrowUp => return self.update(Message::UserListSelectPrevious),
+                key::Named::Enter if modifiers.command() => {
+                    return self.update(Message::UserListDisconnectSelected);
+                }
+                key::Named::Enter if modifiers.shift() => {
+                    return self.update(Message::UserListInfoSelected);
+                }
+                key::Named::Enter => return self.update(Message::UserListMessageSelected),
+                key::Named::Escape => return self.update(Message::UserListFilterCleared),
+                _ => {}
+            }
+        }
+
         // Handle F5 for refresh in Files panel
         if let Event::Keyboard(keyboard::Event::KeyPressed {
             key: keyboard::Key::Named(key::Named::F5),
@@ -200,8 +306,10 @@ impl NexusApp {
                                 return self.update(Message::UserManagementUpdatePressed);
                             }
                         }
-                        UserManagementMode::List => {
-                            // List mode: No Enter action (use Escape to close)
+                        UserManagementMode::List
+                        | UserManagementMode::PermissionAudit
+                        | UserManagementMode::Impersonate { .. } => {
+                            // List/audit/impersonate mode: No Enter action (use Escape to close)
                         }
                         UserManagementMode::ConfirmDelete { .. } => {
                             // ConfirmDelete: No Enter action (user must click button)
@@ -291,6 +399,27 @@ impl NexusApp {
                 return self.update(Message::DisconnectDialogCancel);
             }
 
+            // Then the screenshot editor (drawn over the panels)
+            if let Some(conn_id) = self.active_connection
+                && let Some(conn) = self.connections.get(&conn_id)
+                && conn.screenshot_editor.is_some()
+            {
+                return self.update(Message::ScreenshotCancel);
+            }
+
+            // Then the multi-line paste prompt above the chat input
+            if let Some(conn_id) = self.active_connection
+                && let Some(conn) = self.connections.get(&conn_id)
+                && conn.pending_paste.is_some()
+            {
+                return self.update(Message::PasteCancelled);
+            }
+
+            // Then the sidebar's folder name input
+            if self.ui_state.bookmark_group_edit.is_some() {
+                return self.update(Message::BookmarkGroupNameCancelled);
+            }
+
             if self.bookmark_edit.mode != BookmarkEditMode::None {
                 // Cancel bookmark edit
                 return self.update(Message::CancelBookmarkEdit);
@@ -334,6 +463,51 @@ impl NexusApp {
                         {
                             return self.update(Message::FileOverwriteCancel);
                         }
+                        // If Download To dialog is showing, cancel it
+                        if let Some(conn_id) = self.active_connection
+                            && let Some(conn) = self.connections.get(&conn_id)
+                            && conn
+                                .files_management
+                                .active_tab()
+                                .pending_download_to
+                                .is_some()
+                        {
+                            return self.update(Message::FileDownloadToCancel);
+                        }
+                        // If the duplicate download dialog is showing, skip the download
+                        if let Some(conn_id) = self.active_connection
+                            && let Some(conn) = self.connections.get(&conn_id)
+                            && conn
+                                .files_management
+                                .active_tab()
+                                .duplicate_download()
+                                .is_some()
+                        {
+                            return self.update(Message::FileDownloadDuplicateSkip);
+                        }
+                        // If the upload preview dialog is showing, cancel it
+                        if let Some(conn_id) = self.active_connection
+                            && let Some(conn) = self.connections.get(&conn_id)
+                            && conn
+                                .files_management
+                                .active_tab()
+                                .pending_upload_preview
+                                .as_ref()
+                                .is_some_and(|pending| pending.is_visible())
+                        {
+                            return self.update(Message::FileUploadPreviewCancel);
+                        }
+                        // If Download Selected dialog is showing, cancel it
+                        if let Some(conn_id) = self.active_connection
+                            && let Some(conn) = self.connections.get(&conn_id)
+                            && conn
+                                .files_management
+                                .active_tab()
+                                .pending_download_select
+                                .is_some()
+                        {
+                            return self.update(Message::FileDownloadSelectCancel);
+                        }
                         // If file info dialog is showing, close it
                         if let Some(conn_id) = self.active_connection
                             && let Some(conn) = self.connections.get(&conn_id)
@@ -341,6 +515,28 @@ impl NexusApp {
                         {
                             return self.update(Message::CloseFileInfo);
                         }
+                        // If the Upload Rules dialog is showing, cancel it
+                        if let Some(conn_id) = self.active_connection
+                            && let Some(conn) = self.connections.get(&conn_id)
+                            && conn
+                                .files_management
+                                .active_tab()
+                                .pending_upload_rule
+                                .is_some()
+                        {
+                            return self.update(Message::FileUploadRuleCancel);
+                        }
+                        // If the Auto-Download dialog is showing, cancel it
+                        if let Some(conn_id) = self.active_connection
+                            && let Some(conn) = self.connections.get(&conn_id)
+                            && conn
+                                .files_management
+                                .active_tab()
+                                .pending_auto_download
+                                .is_some()
+                        {
+                            return self.update(Message::FileAutoDownloadCancel);
+                        }
                         // If rename dialog is showing, cancel it
                         if let Some(conn_id) = self.active_connection
                             && let Some(conn) = self.connections.get(&conn_id)
@@ -378,6 +574,13 @@ impl NexusApp {
                             conn.files_management.active_tab_mut().search_input.clear();
                             return self.update(Message::FileSearchSubmit);
                         }
+                        // If showing highlights, go back to browsing
+                        if let Some(conn_id) = self.active_connection
+                            && let Some(conn) = self.connections.get(&conn_id)
+                            && conn.files_management.active_tab().is_showing_highlights()
+                        {
+                            return self.update(Message::FileNavigateHome);
+                        }
                         // Otherwise close panel
                         return self.update(Message::CancelFiles);
                     }
@@ -385,6 +588,13 @@ impl NexusApp {
                     ActivePanel::ConnectionMonitor => {
                         return self.update(Message::CloseConnectionMonitor);
                     }
+                    ActivePanel::ServerLog => return self.update(Message::CloseServerLog),
+                    ActivePanel::Statistics => return self.update(Message::CloseStatistics),
+                    ActivePanel::Snippet => return self.update(Message::CloseSnippet),
+                    ActivePanel::AdminDashboard => {
+                        return self.update(Message::CloseAdminDashboard);
+                    }
+                    ActivePanel::Doors => return self.update(Message::CloseDoors),
                     ActivePanel::None => {}
                 }
             }
@@ -423,7 +633,8 @@ impl NexusApp {
             return Task::none();
         };
 
-        // Build ordered list of tabs: Console + Channels (join order) + User messages (creation order)
+        // Build ordered list of tabs: Console + Channels (join order) + User messages
+        // and groups (creation order)
         let mut tabs = vec![ChatTab::Console];
         for channel in &conn.channel_tabs {
             tabs.push(ChatTab::Channel(channel.clone()));
@@ -431,6 +642,9 @@ impl NexusApp {
         for nickname in &conn.user_message_tabs {
             tabs.push(ChatTab::UserMessage(nickname.clone()));
         }
+        for group_id in &conn.group_tabs {
+            tabs.push(ChatTab::Group(*group_id));
+        }
 
         // Find current tab index and move to next (with wrap)
         let current_index = tabs
@@ -474,7 +688,8 @@ impl NexusApp {
             return Task::none();
         };
 
-        // Build ordered list of tabs: Console + Channels (join order) + User messages (creation order)
+        // Build ordered list of tabs: Console + Channels (join order) + User messages
+        // and groups (creation order)
         let mut tabs = vec![ChatTab::Console];
         for channel in &conn.channel_tabs {
             tabs.push(ChatTab::Channel(channel.clone()));
@@ -482,6 +697,9 @@ impl NexusApp {
         for nickname in &conn.user_message_tabs {
             tabs.push(ChatTab::UserMessage(nickname.clone()));
         }
+        for group_id in &conn.group_tabs {
+            tabs.push(ChatTab::Group(*group_id));
+        }
 
         // Find current tab index and move to previous (with wrap)
         let current_index = tabs
@@ -517,8 +735,11 @@ impl NexusApp {
                         // Edit mode: Check actual focus and cycle
                         return self.update(Message::UserManagementEditTabPressed);
                     }
-                    UserManagementMode::List | UserManagementMode::ConfirmDelete { .. } => {
-                        // List/ConfirmDelete: No Tab navigation
+                    UserManagementMode::List
+                    | UserManagementMode::PermissionAudit
+                    | UserManagementMode::Impersonate { .. }
+                    | UserManagementMode::ConfirmDelete { .. } => {
+                        // List/PermissionAudit/Impersonate/ConfirmDelete: No Tab navigation
                     }
                 }
             }
@@ -0,0 +1,302 @@
+//! Server-side user message history
+//!
+//! Accounts that turn on message history (`/history <days>`) have the server
+//! keep their user messages, so a new session or another device starts with
+//! the conversation instead of an empty tab. After login the client fetches
+//! the newest messages and merges them into the user message tabs, skipping
+//! any it already has from local history. `/history more` pages back through
+//! one conversation.
+
+use chrono::{Local, TimeZone};
+use iced::Task;
+use nexus_common::framing::MessageId;
+use nexus_common::protocol::{ClientMessage, HistoryMessage};
+
+use crate::NexusApp;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message, MessageType, PendingRequests, ResponseRouting};
+use crate::views::constants::PERMISSION_USER_MESSAGE;
+
+impl NexusApp {
+    /// Fetch the newest stored user messages after login
+    ///
+    /// Skipped without the user_message permission and for shared accounts,
+    /// which keep no history on the server.
+    pub fn sync_message_history(&mut self, connection_id: usize) {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return;
+        };
+        let is_shared =
+            conn.nickname.to_lowercase() != conn.connection_info.username.to_lowercase();
+        if is_shared || !conn.has_permission(PERMISSION_USER_MESSAGE) {
+            return;
+        }
+        let message = ClientMessage::MessageHistory {
+            nickname: None,
+            before: None,
+            limit: None,
+        };
+        if let Ok(message_id) = conn.send(message) {
+            conn.pending_requests
+                .track(message_id, ResponseRouting::MessageHistorySync);
+        }
+    }
+
+    /// Fetch the stored messages before the oldest one shown for a conversation
+    pub fn request_older_messages(
+        &mut self,
+        connection_id: usize,
+        nickname: &str,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+        let message = ClientMessage::MessageHistory {
+            nickname: Some(nickname.to_string()),
+            before: conn
+                .message_history_oldest
+                .get(&nickname.to_lowercase())
+                .copied(),
+            limit: None,
+        };
+        match conn.send(message) {
+            Ok(message_id) => {
+                conn.pending_requests.track(
+                    message_id,
+                    ResponseRouting::MessageHistoryOlder(nickname.to_string()),
+                );
+                Task::none()
+            }
+            Err(e) => {
+                let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+                self.add_active_tab_message(connection_id, ChatMessage::error(error_msg))
+            }
+        }
+    }
+
+    /// Handle stored user messages from the server
+    pub fn handle_message_history_response(
+        &mut self,
+        connection_id: usize,
+        message_id: MessageId,
+        success: bool,
+        error: Option<String>,
+        messages: Option<Vec<HistoryMessage>>,
+        retention_days: Option<u32>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+        let routing = conn.pending_requests.remove(&message_id);
+
+        if !success {
+            // The sync after login fails quietly (e.g. an older server)
+            return match routing {
+                Some(ResponseRouting::MessageHistoryOlder(_)) => self.add_active_tab_message(
+                    connection_id,
+                    ChatMessage::error(error.unwrap_or_default()),
+                ),
+                _ => Task::none(),
+            };
+        }
+
+        if retention_days.is_some() {
+            conn.message_history_days = retention_days;
+        }
+        let added = self.merge_message_history(connection_id, messages.unwrap_or_default());
+
+        match routing {
+            Some(ResponseRouting::MessageHistoryOlder(nickname)) if added == 0 => self
+                .add_active_tab_message(
+                    connection_id,
+                    ChatMessage::info(t_args("msg-history-no-older", &[("nickname", &nickname)])),
+                ),
+            _ => Task::none(),
+        }
+    }
+
+    /// Handle the response to a message history retention change
+    pub fn handle_message_history_retention_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+        days: Option<u32>,
+    ) -> Task<Message> {
+        let message = if success {
+            if let Some(conn) = self.connections.get_mut(&connection_id) {
+                conn.message_history_days = days;
+            }
+            retention_message(days.unwrap_or(0))
+        } else {
+            ChatMessage::error(error.unwrap_or_default())
+        };
+
+        self.add_active_tab_message(connection_id, message)
+    }
+
+    /// Merge stored messages into the user message tabs, creating tabs as needed
+    ///
+    /// Returns how many messages weren't already shown.
+    fn merge_message_history(
+        &mut self,
+        connection_id: usize,
+        messages: Vec<HistoryMessage>,
+    ) -> usize {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return 0;
+        };
+        let own_nickname = conn.nickname.to_lowercase();
+        let mut added = 0;
+
+        // The server sends newest first
+        for history in messages.into_iter().rev() {
+            let other_nickname = if history.from_nickname.to_lowercase() == own_nickname {
+                history.to_nickname.clone()
+            } else {
+                history.from_nickname.clone()
+            };
+            let other_lower = other_nickname.to_lowercase();
+
+            conn.message_history_oldest
+                .entry(other_lower.clone())
+                .and_modify(|id| *id = (*id).min(history.id))
+                .or_insert(history.id);
+
+            // Reuse an existing conversation whatever its case
+            let tab_name = conn
+                .user_messages
+                .keys()
+                .find(|name| name.to_lowercase() == other_lower)
+                .cloned()
+                .unwrap_or(other_nickname);
+
+            let datetime = Local
+                .timestamp_opt(history.timestamp as i64, 0)
+                .single()
+                .unwrap_or_else(Local::now);
+            let chat_msg = ChatMessage::with_timestamp_and_status(
+                history.from_nickname,
+                history.message,
+                datetime,
+                false,
+                false,
+                history.action,
+            );
+            if merge_history_message(
+                conn.user_messages.entry(tab_name.clone()).or_default(),
+                chat_msg,
+            ) {
+                added += 1;
+            }
+
+            if !conn.user_message_tabs.contains(&tab_name) {
+                conn.user_message_tabs.push(tab_name);
+            }
+        }
+
+        added
+    }
+}
+
+/// The message describing a message history retention
+pub fn retention_message(days: u32) -> ChatMessage {
+    if days == 0 {
+        ChatMessage::info(t("msg-history-off"))
+    } else {
+        ChatMessage::info(t_args("msg-history-days", &[("days", &days.to_string())]))
+    }
+}
+
+/// Insert a stored message into a conversation in time order
+///
+/// Skipped if the conversation already shows the same message from the same
+/// sender at the same second (e.g. restored from local history). Returns
+/// whether it was inserted.
+fn merge_history_message(messages: &mut Vec<ChatMessage>, incoming: ChatMessage) -> bool {
+    let Some(time) = incoming.timestamp.map(|t| t.timestamp()) else {
+        return false;
+    };
+    let is_chat = |msg: &ChatMessage| msg.message_type == MessageType::Chat;
+
+    let duplicate = messages.iter().any(|msg| {
+        is_chat(msg)
+            && msg.timestamp.map(|t| t.timestamp()) == Some(time)
+            && msg.nickname.to_lowercase() == incoming.nickname.to_lowercase()
+            && msg.message == incoming.message
+    });
+    if duplicate {
+        return false;
+    }
+
+    // Before the first chat message that's newer
+    let position = messages
+        .iter()
+        .position(|msg| is_chat(msg) && msg.timestamp.is_some_and(|t| t.timestamp() > time))
+        .unwrap_or(messages.len());
+    messages.insert(position, incoming);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use nexus_common::protocol::ChatAction;
+
+    use super::*;
+
+    fn chat(nickname: &str, message: &str, time: i64) -> ChatMessage {
+        ChatMessage::with_timestamp_and_status(
+            nickname,
+            message,
+            Local.timestamp_opt(time, 0).unwrap(),
+            false,
+            false,
+            ChatAction::Normal,
+        )
+    }
+
+    #[test]
+    fn test_merge_history_message_in_time_order() {
+        let mut messages = vec![chat("alice", "first", 100), chat("bob", "third", 300)];
+
+        assert!(merge_history_message(
+            &mut messages,
+            chat("bob", "second", 200)
+        ));
+        assert!(merge_history_message(
+            &mut messages,
+            chat("alice", "fourth", 400)
+        ));
+
+        let texts: Vec<_> = messages.iter().map(|m| m.message.as_str()).collect();
+        assert_eq!(texts, ["first", "second", "third", "fourth"]);
+    }
+
+    #[test]
+    fn test_merge_history_message_skips_duplicates() {
+        let mut messages = vec![chat("alice", "hello", 100)];
+
+        assert!(!merge_history_message(
+            &mut messages,
+            chat("Alice", "hello", 100)
+        ));
+        // Same text at another time is a different message
+        assert!(merge_history_message(
+            &mut messages,
+            chat("alice", "hello", 101)
+        ));
+        assert_eq!(messages.len(), 2);
+    }
+
+    #[test]
+    fn test_merge_history_message_keeps_notices_in_place() {
+        let mut messages = vec![chat("alice", "hi", 100), ChatMessage::info("bob is away")];
+
+        assert!(merge_history_message(
+            &mut messages,
+            chat("bob", "back", 50)
+        ));
+        assert_eq!(messages[0].message, "back");
+        assert_eq!(messages[2].message, "bob is away");
+    }
+}
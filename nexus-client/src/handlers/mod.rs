@@ -1,16 +1,42 @@
 //! Message handlers organized by category
 
+mod admin_dashboard;
+mod bookmark_groups;
 mod bookmarks;
 mod broadcast;
+mod chat_export;
+mod chat_input;
+mod chat_window;
+mod code_block;
 mod connection;
 mod connection_monitor;
+#[cfg(target_os = "macos")]
+mod dock;
+mod doors;
+mod drafts;
 mod files;
 mod fingerprint;
+mod friends;
+mod hotkeys;
+mod idle;
 mod keyboard;
+pub(crate) mod message_history;
 pub(crate) mod network;
 mod news;
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+mod notifications;
+mod password_vault;
+mod quick_switcher;
+mod screenshot;
+mod send_queue;
 mod server_info;
+mod server_log;
 mod settings;
+mod snippet;
+mod spellcheck;
+mod statistics;
+mod tab_mute;
+mod tips;
 mod transfers;
 #[cfg(not(target_os = "macos"))]
 mod tray;
@@ -18,3 +44,4 @@ mod ui;
 mod uri;
 mod user_management;
 mod voice;
+mod workspace;
@@ -1,10 +1,11 @@
 //! Chat utility functions for network handlers
 
+use chrono::{DateTime, Local};
 use iced::Task;
 
 use crate::NexusApp;
 use crate::i18n::t_args;
-use crate::types::{ChatMessage, ChatTab, Message};
+use crate::types::{ChatMessage, ChatTab, Message, ServerConnection};
 
 /// Truncate a message list to respect max_scrollback setting.
 /// Removes oldest messages (from the front) when limit is exceeded.
@@ -17,6 +18,15 @@ fn truncate_scrollback(messages: &mut Vec<ChatMessage>, max_scrollback: usize) {
 }
 
 impl NexusApp {
+    /// The current time by a connection's server clock, in our time zone
+    ///
+    /// See [`ServerConnection::server_now`].
+    fn server_now(&self, connection_id: usize) -> DateTime<Local> {
+        self.connections
+            .get(&connection_id)
+            .map_or_else(Local::now, ServerConnection::server_now)
+    }
+
     /// Add a message to the user's current active tab and auto-scroll
     ///
     /// Used for user-initiated actions like command output and errors.
@@ -40,6 +50,10 @@ impl NexusApp {
                 let nickname = nickname.clone();
                 self.add_user_message(connection_id, &nickname, message)
             }
+            ChatTab::Group(group_id) => {
+                let group_id = *group_id;
+                self.add_group_message(connection_id, group_id, message)
+            }
         }
     }
 
@@ -54,7 +68,7 @@ impl NexusApp {
     ) -> Task<Message> {
         // Set timestamp if not already set
         if message.timestamp.is_none() {
-            message.timestamp = Some(chrono::Local::now());
+            message.timestamp = Some(self.server_now(connection_id));
         }
 
         let Some(conn) = self.connections.get_mut(&connection_id) else {
@@ -90,9 +104,11 @@ impl NexusApp {
     ) -> Task<Message> {
         // Set timestamp if not already set
         if message.timestamp.is_none() {
-            message.timestamp = Some(chrono::Local::now());
+            message.timestamp = Some(self.server_now(connection_id));
         }
 
+        let pm_tab = ChatTab::UserMessage(nickname.to_string());
+        let muted = self.is_chat_tab_muted(connection_id, &pm_tab);
         let Some(conn) = self.connections.get_mut(&connection_id) else {
             return Task::none();
         };
@@ -107,9 +123,8 @@ impl NexusApp {
             conn.user_message_tabs.push(nickname.to_string());
         }
 
-        // Mark user message tab as unread if not currently viewing it
-        let pm_tab = ChatTab::UserMessage(nickname.to_string());
-        if conn.active_chat_tab != pm_tab {
+        // Mark user message tab as unread if not currently viewing it (or muted)
+        if !muted && conn.active_chat_tab != pm_tab {
             conn.unread_tabs.insert(pm_tab);
         }
 
@@ -120,6 +135,44 @@ impl NexusApp {
         Task::none()
     }
 
+    /// Add a message to a group conversation tab and auto-scroll if viewing that tab
+    ///
+    /// Used for group messages and command output in group tabs. Messages for
+    /// a group we're not in are dropped.
+    pub fn add_group_message(
+        &mut self,
+        connection_id: usize,
+        group_id: u64,
+        mut message: ChatMessage,
+    ) -> Task<Message> {
+        // Set timestamp if not already set
+        if message.timestamp.is_none() {
+            message.timestamp = Some(self.server_now(connection_id));
+        }
+
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+        let Some(group) = conn.groups.get_mut(&group_id) else {
+            return Task::none();
+        };
+
+        group.messages.push(message);
+        truncate_scrollback(&mut group.messages, self.config.settings.max_scrollback);
+
+        // Mark group tab as unread if not currently viewing it
+        let group_tab = ChatTab::Group(group_id);
+        if conn.active_chat_tab != group_tab {
+            conn.unread_tabs.insert(group_tab);
+        }
+
+        if self.active_connection == Some(connection_id) {
+            return self.scroll_chat_if_visible(true);
+        }
+
+        Task::none()
+    }
+
     /// Add a message to a specific channel and auto-scroll if viewing that channel
     ///
     /// Used for chat messages received in channels.
@@ -131,9 +184,10 @@ impl NexusApp {
     ) -> Task<Message> {
         // Set timestamp if not already set
         if message.timestamp.is_none() {
-            message.timestamp = Some(chrono::Local::now());
+            message.timestamp = Some(self.server_now(connection_id));
         }
 
+        let muted = self.is_chat_tab_muted(connection_id, &ChatTab::Channel(channel.to_string()));
         let Some(conn) = self.connections.get_mut(&connection_id) else {
             return Task::none();
         };
@@ -155,9 +209,9 @@ impl NexusApp {
             );
         }
 
-        // Mark channel tab as unread if not currently viewing it
+        // Mark channel tab as unread if not currently viewing it (or muted)
         let channel_tab = ChatTab::Channel(conn.get_channel_display_name(channel));
-        if conn.active_chat_tab != channel_tab {
+        if !muted && conn.active_chat_tab != channel_tab {
             conn.unread_tabs.insert(channel_tab);
         }
 
@@ -168,6 +222,24 @@ impl NexusApp {
         Task::none()
     }
 
+    /// Remember an unread mention in a channel tab
+    ///
+    /// Only counts while the tab is unread, i.e. not being viewed. Shown on
+    /// the dock badge on macOS.
+    pub fn mark_channel_mention(&mut self, connection_id: usize, channel: &str) {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return;
+        };
+
+        let channel_tab = ChatTab::Channel(conn.get_channel_display_name(channel));
+        if conn.unread_tabs.contains(&channel_tab) {
+            conn.mention_tabs.insert(channel_tab);
+
+            #[cfg(target_os = "macos")]
+            self.update_dock_badge();
+        }
+    }
+
     /// Add chat topic message to a channel if present and not empty
     pub fn add_topic_message(
         &mut self,
@@ -189,4 +261,22 @@ impl NexusApp {
             let _ = self.add_channel_message(connection_id, channel, ChatMessage::system(message));
         }
     }
+
+    /// Add the channel's entry message, shown once when joining
+    pub fn add_entry_message(
+        &mut self,
+        connection_id: usize,
+        channel: &str,
+        entry_message: Option<String>,
+    ) {
+        if let Some(entry_message) = entry_message
+            && !entry_message.is_empty()
+        {
+            let _ = self.add_channel_message(
+                connection_id,
+                channel,
+                ChatMessage::system(entry_message),
+            );
+        }
+    }
 }
@@ -7,6 +7,8 @@ use uuid::Uuid;
 
 use crate::NexusApp;
 use crate::config::events::EventType;
+use crate::config::settings::Tip;
+use crate::drafts;
 use crate::events::{EventContext, emit_event};
 use crate::history::HistoryManager;
 use crate::i18n::{t, t_args};
@@ -31,6 +33,7 @@ pub struct ConnectionContext {
     pub bookmark_id: Option<Uuid>,
     pub display_name: String,
     pub certificate_fingerprint: String,
+    pub identity_key: Option<String>,
     pub connection_id: usize,
 }
 
@@ -82,6 +85,7 @@ impl NexusApp {
                     bookmark_id,
                     display_name,
                     certificate_fingerprint: conn.connection_info.certificate_fingerprint.clone(),
+                    identity_key: conn.connection_info.identity_key.clone(),
                     connection_id: conn.connection_id,
                 };
 
@@ -116,6 +120,7 @@ impl NexusApp {
                     bookmark_id,
                     display_name,
                     certificate_fingerprint: conn.connection_info.certificate_fingerprint.clone(),
+                    identity_key: conn.connection_info.identity_key.clone(),
                     connection_id: conn.connection_id,
                 };
 
@@ -149,6 +154,7 @@ impl NexusApp {
             .unwrap_or((String::new(), None));
 
         // Emit UserKicked if we received a kick error, otherwise ConnectionLost
+        let was_kicked = pending_kick.is_some();
         if let Some(kick_message) = pending_kick {
             emit_event(
                 self,
@@ -174,7 +180,38 @@ impl NexusApp {
             );
         }
 
+        // Keep unsent drafts and the workspace for the next connection to this server
+        self.store_connection_drafts(connection_id);
+        let _ = self.drafts.save();
+        self.store_connection_workspace(connection_id);
+        let _ = self.config.save();
+
+        let close_windows = self.close_chat_windows(connection_id, None);
+
         if let Some(conn) = self.connections.remove(&connection_id) {
+            // Keep queued messages to send when this server is reconnected,
+            // after the unanswered ones (resent with the same key, the server
+            // skips any it already posted)
+            if !conn.unacked_messages.is_empty() || !conn.send_queue.is_empty() {
+                let unacked = conn
+                    .unacked_messages
+                    .iter()
+                    .map(|unacked| unacked.queued.clone());
+                self.offline_send_queues
+                    .entry(drafts::server_key(&conn.connection_info))
+                    .or_default()
+                    .extend(unacked.chain(conn.send_queue.iter().cloned()));
+            }
+
+            // Keep the login token so reconnecting the bookmark soon doesn't need
+            // the password or another sign-in (a kick revokes it on the server)
+            if !was_kicked
+                && let Some(bookmark_id) = conn.bookmark_id
+                && let Some(token) = conn.connection_info.login_token.clone()
+            {
+                self.resume_tokens.insert(bookmark_id, token);
+            }
+
             // Clean up receiver and signal shutdown in a single spawn
             let registry = crate::network::NETWORK_RECEIVERS.clone();
             let shutdown_arc = conn.shutdown_handle.clone();
@@ -205,8 +242,12 @@ impl NexusApp {
             // Update tray icon state (Windows/Linux only)
             #[cfg(not(target_os = "macos"))]
             self.update_tray_state();
+
+            // Drop this connection's unreads from the dock badge (macOS only)
+            #[cfg(target_os = "macos")]
+            self.update_dock_badge();
         }
-        Task::none()
+        close_windows
     }
 
     // =========================================================================
@@ -221,9 +262,11 @@ impl NexusApp {
         source: ConnectionSource,
     ) -> Task<Message> {
         // Verify and save certificate fingerprint
-        if let Err(mismatch_details) =
-            self.verify_and_save_fingerprint(ctx.bookmark_id, &ctx.certificate_fingerprint)
-        {
+        if let Err(mismatch_details) = self.verify_and_save_fingerprint(
+            ctx.bookmark_id,
+            &ctx.certificate_fingerprint,
+            ctx.identity_key.as_deref(),
+        ) {
             // Clear bookmark connecting lock on fingerprint mismatch
             if let Some(id) = ctx.bookmark_id {
                 self.connecting_bookmarks.remove(&id);
@@ -249,18 +292,34 @@ impl NexusApp {
             return Task::none();
         }
 
+        // Watch the presence of the bookmark's friends
+        self.sync_presence_subscriptions(ctx.connection_id);
+
+        // Fill user message tabs from the server's message history
+        self.sync_message_history(ctx.connection_id);
+
+        // Set the bookmark's status message, if it has one
+        self.apply_bookmark_status(ctx.connection_id);
+
         // Initialize channel state from auto-joined channels
         if let Some(conn) = self.connections.get_mut(&ctx.connection_id) {
             for channel_info in &reg.channels {
                 let channel_lower = channel_info.channel.to_lowercase();
 
                 // Create channel state
-                let channel_state = ChannelState::new(
-                    channel_info.topic.clone(),
-                    channel_info.topic_set_by.clone(),
-                    channel_info.secret,
-                    channel_info.members.clone(),
-                );
+                let channel_state = ChannelState {
+                    topic_locked: channel_info.topic_locked,
+                    entry_message: channel_info.entry_message.clone(),
+                    rules: channel_info.rules.clone(),
+                    language: channel_info.language.clone(),
+                    voice_limit: channel_info.voice_limit,
+                    ..ChannelState::new(
+                        channel_info.topic.clone(),
+                        channel_info.topic_set_by.clone(),
+                        channel_info.secret,
+                        channel_info.members.clone(),
+                    )
+                };
 
                 // Add to channels map and tabs list
                 conn.channels.insert(channel_lower.clone(), channel_state);
@@ -291,7 +350,7 @@ impl NexusApp {
 
             // Set active tab to last joined channel, or stay on Console if no channels
             if let Some(last_channel) = conn.channel_tabs.last() {
-                conn.active_chat_tab = crate::types::ChatTab::Channel(last_channel.clone());
+                conn.set_active_chat_tab(crate::types::ChatTab::Channel(last_channel.clone()));
             }
 
             // Add welcome message to Console with server info
@@ -353,14 +412,33 @@ impl NexusApp {
                 channel_info.topic.clone(),
                 channel_info.topic_set_by.clone(),
             );
+            self.add_entry_message(
+                ctx.connection_id,
+                &channel_info.channel,
+                channel_info.entry_message.clone(),
+            );
         }
 
+        // Put back the tabs and Files panel location from the last session
+        self.restore_workspace(ctx.connection_id);
+
+        // Look for new files in the bookmark's auto-download folders
+        self.check_auto_downloads(ctx.connection_id);
+        self.sync_file_watches(ctx.connection_id);
+
+        // Send messages queued while the connection was down
+        self.flush_send_queue(ctx.connection_id);
+
         // Save as bookmark if checkbox was enabled (form connections only, not already a bookmark)
         if matches!(source, ConnectionSource::Manual)
             && self.connection_form.add_bookmark
             && ctx.bookmark_id.is_none()
         {
-            self.save_new_bookmark(ctx.connection_id, ctx.certificate_fingerprint);
+            self.save_new_bookmark(
+                ctx.connection_id,
+                ctx.certificate_fingerprint,
+                ctx.identity_key,
+            );
         }
 
         // Clear connection form for form connections
@@ -427,7 +505,7 @@ impl NexusApp {
             decode_data_uri_max_width(&server_image, SERVER_IMAGE_MAX_CACHE_WIDTH)
         };
 
-        let server_conn = ServerConnection::new(ServerConnectionParams {
+        let mut server_conn = ServerConnection::new(ServerConnectionParams {
             bookmark_id,
             nickname: nickname.clone(),
             connection_info: conn.connection_info,
@@ -444,16 +522,37 @@ impl NexusApp {
 
             max_connections_per_ip: conn.max_connections_per_ip,
             max_transfers_per_ip: conn.max_transfers_per_ip,
+            max_active_transfers: conn.max_active_transfers,
+            transfer_small_files_first: conn.transfer_small_files_first,
+            trusted_transfer_weight: conn.trusted_transfer_weight,
             file_reindex_interval: conn.file_reindex_interval,
             persistent_channels: conn.persistent_channels,
             auto_join_channels: conn.auto_join_channels,
+            nat: conn.nat,
+            news_image_limits: conn.news_image_limits,
+            max_message_length: conn.max_message_length,
+            server_time: conn.server_time,
             tx: conn.tx,
             shutdown_handle,
         });
 
+        // Restore unsent drafts and queued messages from the last connection to this server
+        let server_key = drafts::server_key(&server_conn.connection_info);
+        server_conn.restore_drafts(self.drafts.get(&server_key));
+        server_conn.send_queue = self
+            .offline_send_queues
+            .remove(&server_key)
+            .unwrap_or_default();
+
         self.connections.insert(connection_id, server_conn);
         self.active_connection = Some(connection_id);
 
+        // The welcome card has done its job once a server is connected
+        self.dismiss_tip(Tip::Welcome);
+
+        // Let others see we're not to be disturbed
+        self.sync_do_not_disturb();
+
         // Get or create shared history manager for this server+account combination
         let base_dir = HistoryManager::build_base_dir(&fingerprint, &username);
         let is_new_manager = !self.history_managers.contains_key(&base_dir);
@@ -575,17 +674,37 @@ impl NexusApp {
     }
 
     /// Save a new bookmark from the current connection form
-    fn save_new_bookmark(&mut self, connection_id: usize, certificate_fingerprint: String) {
+    fn save_new_bookmark(
+        &mut self,
+        connection_id: usize,
+        certificate_fingerprint: String,
+        identity_key: Option<String>,
+    ) {
         let new_bookmark = ServerBookmark {
             id: Uuid::new_v4(),
             name: self.connection_form.server_name.clone(),
             address: self.connection_form.server_address.clone(),
             port: self.connection_form.port,
+            fallback_addresses: Vec::new(),
             username: self.connection_form.username.clone(),
             password: self.connection_form.password.clone(),
+            password_store: None,
             nickname: self.connection_form.nickname.clone(),
+            avatar: None,
+            status: String::new(),
             auto_connect: false,
+            use_sso: self.connection_form.use_sso,
             certificate_fingerprint: Some(certificate_fingerprint),
+            identity_key,
+            certificate_first_seen: Some(chrono::Utc::now().timestamp()),
+            download_folder: String::new(),
+            upload_watch: None,
+            auto_downloads: Vec::new(),
+            screenshot_folder: None,
+            notification_rules: Default::default(),
+            workspace: Default::default(),
+            group: None,
+            friends: Vec::new(),
         };
         let bookmark_id = new_bookmark.id;
         self.config.add_bookmark(new_bookmark);
@@ -8,42 +8,71 @@ use crate::types::{FingerprintMismatch, FingerprintMismatchDetails, Message, Net
 
 impl NexusApp {
     /// Verify certificate fingerprint matches stored value, or save on first connection (TOFU)
+    ///
+    /// With `require_fingerprint_approval` set, a first connection is queued for
+    /// the user to approve like a mismatch, with an empty `expected` fingerprint.
+    ///
+    /// A changed certificate is accepted without asking when the server proved
+    /// the identity key pinned for the bookmark (`identity_key` is only set
+    /// after the signature over the new fingerprint was verified).
     pub fn verify_and_save_fingerprint(
         &mut self,
         bookmark_id: Option<Uuid>,
         fingerprint: &str,
+        identity_key: Option<&str>,
     ) -> Result<(), Box<FingerprintMismatchDetails>> {
         let Some(id) = bookmark_id else {
             // No bookmark - nothing to verify
             return Ok(());
         };
 
+        let require_approval = self.config.settings.require_fingerprint_approval;
         let Some(bookmark) = self.config.get_bookmark_mut(id) else {
             // Invalid bookmark ID - nothing to verify
             return Ok(());
         };
 
         match &bookmark.certificate_fingerprint {
+            None if require_approval => Err(Box::new(FingerprintMismatchDetails {
+                bookmark_id: id,
+                expected: String::new(),
+                received: fingerprint.to_string(),
+                identity_key: identity_key.map(str::to_string),
+                bookmark_name: bookmark.name.clone(),
+                server_address: bookmark.address.clone(),
+                server_port: bookmark.port.to_string(),
+            })),
             None => {
                 // First connection - save fingerprint (Trust On First Use)
-                bookmark.certificate_fingerprint = Some(fingerprint.to_string());
+                bookmark.trust_certificate(fingerprint, identity_key);
                 let _ = self.config.save();
                 Ok(())
             }
+            Some(stored) if stored == fingerprint => {
+                // Trusted certificate - pin (or re-pin) the identity it presents
+                if identity_key.is_some() && bookmark.identity_key.as_deref() != identity_key {
+                    bookmark.identity_key = identity_key.map(str::to_string);
+                    let _ = self.config.save();
+                }
+                Ok(())
+            }
             Some(stored) => {
-                // Verify fingerprint matches
-                if stored == fingerprint {
-                    Ok(())
-                } else {
-                    Err(Box::new(FingerprintMismatchDetails {
-                        bookmark_id: id,
-                        expected: stored.clone(),
-                        received: fingerprint.to_string(),
-                        bookmark_name: bookmark.name.clone(),
-                        server_address: bookmark.address.clone(),
-                        server_port: bookmark.port.to_string(),
-                    }))
+                // New certificate signed by the pinned identity - same server
+                if identity_key.is_some() && bookmark.identity_key.as_deref() == identity_key {
+                    let old_fingerprint = stored.clone();
+                    self.update_stored_fingerprint(id, &old_fingerprint, fingerprint, identity_key);
+                    return Ok(());
                 }
+
+                Err(Box::new(FingerprintMismatchDetails {
+                    bookmark_id: id,
+                    expected: stored.clone(),
+                    received: fingerprint.to_string(),
+                    identity_key: identity_key.map(str::to_string),
+                    bookmark_name: bookmark.name.clone(),
+                    server_address: bookmark.address.clone(),
+                    server_port: bookmark.port.to_string(),
+                }))
             }
         }
     }
@@ -60,6 +89,7 @@ impl NexusApp {
                 bookmark_id: details.bookmark_id,
                 expected: details.expected,
                 received: details.received,
+                identity_key: details.identity_key,
                 bookmark_name: details.bookmark_name,
                 server_address: details.server_address,
                 server_port: details.server_port,
@@ -5,7 +5,9 @@ use iced::Task;
 use crate::NexusApp;
 use crate::config::events::EventType;
 use crate::events::{EventContext, emit_event};
-use crate::types::{ChatMessage, Message};
+use crate::handlers::network::constants::DATETIME_FORMAT;
+use crate::i18n::t_args;
+use crate::types::{ChatMessage, Message, PendingBroadcastAck};
 
 impl NexusApp {
     /// Handle incoming server broadcast message
@@ -13,11 +15,15 @@ impl NexusApp {
     /// The protocol sends `username` for broadcasts. Since shared accounts cannot
     /// broadcast, the sender's username always equals their nickname, so we can
     /// store it directly in the ChatMessage.nickname field for display.
+    ///
+    /// A `broadcast_id` means the sender asked for acknowledgement; unless we
+    /// sent it, a prompt above the chat input offers to acknowledge it.
     pub fn handle_server_broadcast(
         &mut self,
         connection_id: usize,
         username: String,
         message: String,
+        broadcast_id: Option<u64>,
     ) -> Task<Message> {
         // Check if we sent this broadcast (suppress notification but allow sound)
         let is_from_self = self
@@ -36,28 +42,87 @@ impl NexusApp {
                 .with_is_from_self(is_from_self),
         );
 
+        if let Some(broadcast_id) = broadcast_id
+            && !is_from_self
+            && let Some(conn) = self.connections.get_mut(&connection_id)
+        {
+            conn.broadcast_acks.push(PendingBroadcastAck {
+                broadcast_id,
+                username: username.clone(),
+            });
+        }
+
+        // Bounce the dock icon (macOS only, silenced by do not disturb)
+        #[cfg(target_os = "macos")]
+        if !is_from_self && !self.do_not_disturb_active() {
+            crate::dock::request_attention();
+        }
+
         // username == nickname for broadcasters (shared accounts can't broadcast)
         // Show in active tab (channel/PM) with console fallback
         self.add_active_tab_message(connection_id, ChatMessage::broadcast(username, message))
     }
 
     /// Handle user broadcast response (success/failure of sending a broadcast)
+    ///
+    /// On success, reports how many users the broadcast reached, or when the
+    /// server will send it if it was scheduled.
     pub fn handle_user_broadcast_response(
         &mut self,
         connection_id: usize,
         success: bool,
         error: Option<String>,
+        delivered: Option<u32>,
+        send_at: Option<i64>,
     ) -> Task<Message> {
         let Some(conn) = self.connections.get_mut(&connection_id) else {
             return Task::none();
         };
 
-        if success {
-            conn.broadcast_error = None;
-            return self.handle_show_chat_view();
+        if !success {
+            conn.broadcast_error = Some(error.unwrap_or_default());
+            return Task::none();
+        }
+        conn.broadcast_error = None;
+
+        let info = if let Some(send_at) = send_at {
+            chrono::DateTime::from_timestamp(send_at, 0).map(|time| {
+                let time = time.format(DATETIME_FORMAT).to_string();
+                t_args("msg-broadcast-scheduled", &[("time", &time)])
+            })
+        } else {
+            delivered
+                .map(|count| t_args("msg-broadcast-delivered", &[("count", &count.to_string())]))
+        };
+
+        let show_chat = self.handle_show_chat_view();
+        match info {
+            Some(info) => Task::batch([
+                show_chat,
+                self.add_active_tab_message(connection_id, ChatMessage::info(info)),
+            ]),
+            None => show_chat,
         }
+    }
 
-        conn.broadcast_error = Some(error.unwrap_or_default());
-        Task::none()
+    /// Handle a delivery or acknowledgement update for a broadcast we sent
+    pub fn handle_broadcast_status(
+        &mut self,
+        connection_id: usize,
+        delivered: u32,
+        acknowledged: Option<u32>,
+    ) -> Task<Message> {
+        let delivered = delivered.to_string();
+        let info = match acknowledged {
+            Some(acknowledged) => t_args(
+                "msg-broadcast-acknowledged",
+                &[
+                    ("count", &acknowledged.to_string()),
+                    ("delivered", &delivered),
+                ],
+            ),
+            None => t_args("msg-broadcast-delivered", &[("count", &delivered)]),
+        };
+        self.add_active_tab_message(connection_id, ChatMessage::info(info))
     }
 }
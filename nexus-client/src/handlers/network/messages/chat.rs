@@ -1,15 +1,32 @@
 //! Chat message handlers
 
-use chrono::{Local, TimeZone};
+use chrono::{DateTime, Local, TimeZone};
 use iced::Task;
-use nexus_common::protocol::ChatAction;
+use nexus_common::protocol::{ChatAction, ChatTopicChange};
 
 use crate::NexusApp;
-use crate::config::events::EventType;
-use crate::events::{EventContext, emit_event};
+use crate::config::events::{ChannelNotification, EventType};
+use crate::events::{EventContext, emit_event, emit_event_as};
+use crate::handlers::network::constants::DATETIME_FORMAT;
 use crate::i18n::{t, t_args};
 use crate::types::{ChatMessage, ChatTab, Message};
 
+/// Changed fields from a ChatUpdated message (only changed fields are Some)
+pub struct ChatUpdatedData {
+    pub topic: Option<String>,
+    pub topic_set_by: Option<String>,
+    pub secret: Option<bool>,
+    pub secret_set_by: Option<String>,
+    pub voice_limit: Option<u32>,
+    pub voice_limit_set_by: Option<String>,
+    pub topic_locked: Option<bool>,
+    pub topic_locked_set_by: Option<String>,
+    pub rules: Option<String>,
+    pub rules_set_by: Option<String>,
+    pub language: Option<String>,
+    pub language_set_by: Option<String>,
+}
+
 impl NexusApp {
     /// Handle incoming chat message from a channel
     #[allow(clippy::too_many_arguments)]
@@ -24,41 +41,78 @@ impl NexusApp {
         action: ChatAction,
         timestamp: u64,
     ) -> Task<Message> {
-        // Extract mention/self info from connection (drop borrow before emit_event)
-        let (is_from_self, is_mention) = if let Some(conn) = self.connections.get(&connection_id) {
-            let our_nickname_lower = conn.nickname.to_lowercase();
-            let from_self = nickname.to_lowercase() == our_nickname_lower;
-            let mention = !from_self
-                && !our_nickname_lower.is_empty()
-                && contains_word(&message.to_lowercase(), &our_nickname_lower);
-            (from_self, mention)
-        } else {
-            (false, false)
-        };
-
-        // Emit ChatMessage event (with is_from_self flag for sound handling)
-        emit_event(
-            self,
-            EventType::ChatMessage,
-            EventContext::new()
-                .with_connection_id(connection_id)
-                .with_username(&nickname)
-                .with_message(&message)
-                .with_is_from_self(is_from_self)
-                .with_channel(&channel),
-        );
+        // Extract mention/self info and the bookmark's notification rules for this
+        // channel (drop borrow before emit_event)
+        let (is_from_self, is_mention, channel_notification) =
+            if let Some(conn) = self.connections.get(&connection_id) {
+                let rules = conn
+                    .bookmark_id
+                    .and_then(|id| self.config.get_bookmark(id))
+                    .map(|bookmark| &bookmark.notification_rules);
+                let our_nickname_lower = conn.nickname.to_lowercase();
+                let message_lower = message.to_lowercase();
+                let from_self = nickname.to_lowercase() == our_nickname_lower;
+                let keyword = rules.is_some_and(|rules| {
+                    rules
+                        .keywords
+                        .iter()
+                        .any(|keyword| contains_word(&message_lower, &keyword.to_lowercase()))
+                });
+                let mention = !from_self
+                    && (keyword
+                        || (!our_nickname_lower.is_empty()
+                            && contains_word(&message_lower, &our_nickname_lower)));
+                (
+                    from_self,
+                    mention,
+                    rules.and_then(|rules| rules.channel(&channel)),
+                )
+            } else {
+                (false, false, None)
+            };
 
-        // Also emit ChatMention if our nickname is mentioned (only for others' messages)
-        if is_mention {
-            emit_event(
-                self,
-                EventType::ChatMention,
-                EventContext::new()
-                    .with_connection_id(connection_id)
-                    .with_username(&nickname)
-                    .with_message(&message)
-                    .with_channel(&channel),
-            );
+        let context = EventContext::new()
+            .with_connection_id(connection_id)
+            .with_username(&nickname)
+            .with_message(&message)
+            .with_channel(&channel);
+
+        match channel_notification {
+            // Muted channels emit nothing
+            Some(ChannelNotification::Mute) => {}
+            // Only mentions and keyword matches
+            Some(ChannelNotification::MentionsOnly) => {
+                if is_mention {
+                    emit_event(self, EventType::ChatMention, context);
+                }
+            }
+            // Every message from others is delivered like a mention
+            Some(ChannelNotification::All) if !is_from_self => {
+                if is_mention {
+                    emit_event(self, EventType::ChatMention, context);
+                } else {
+                    emit_event_as(
+                        self,
+                        EventType::ChatMessage,
+                        EventType::ChatMention,
+                        context,
+                    );
+                }
+            }
+            Some(ChannelNotification::All) | None => {
+                // Emit ChatMessage event (with is_from_self flag for sound handling)
+                emit_event(
+                    self,
+                    EventType::ChatMessage,
+                    context.clone().with_is_from_self(is_from_self),
+                );
+
+                // Also emit ChatMention if our nickname or a keyword is mentioned
+                // (only for others' messages)
+                if is_mention {
+                    emit_event(self, EventType::ChatMention, context);
+                }
+            }
         }
 
         // Use server timestamp if available, otherwise fall back to local time
@@ -70,13 +124,25 @@ impl NexusApp {
         } else {
             Local::now()
         };
-        let chat_message = ChatMessage::with_timestamp_and_status(
+        if let Some(conn) = self.connections.get_mut(&connection_id) {
+            conn.mark_user_active(&nickname, datetime.timestamp());
+        }
+        let mut chat_message = ChatMessage::with_timestamp_and_status(
             nickname, message, datetime, is_admin, is_shared, action,
         );
-        self.add_channel_message(connection_id, &channel, chat_message)
+        chat_message.highlighted = is_mention;
+        let task = self.add_channel_message(connection_id, &channel, chat_message);
+
+        // Muted channels don't count towards the dock badge either
+        if is_mention && channel_notification != Some(ChannelNotification::Mute) {
+            self.mark_channel_mention(connection_id, &channel);
+        }
+
+        task
     }
 
-    /// Handle channel property change notification (topic, secret mode)
+    /// Handle channel property change notification (topic, topic lock, rules,
+    /// language, secret mode, voice limit)
     ///
     /// Only changed fields are included in the message. Displays appropriate
     /// system messages in the channel for each change.
@@ -84,11 +150,23 @@ impl NexusApp {
         &mut self,
         connection_id: usize,
         channel: String,
-        topic: Option<String>,
-        topic_set_by: Option<String>,
-        secret: Option<bool>,
-        secret_set_by: Option<String>,
+        data: ChatUpdatedData,
     ) -> Task<Message> {
+        let ChatUpdatedData {
+            topic,
+            topic_set_by,
+            secret,
+            secret_set_by,
+            voice_limit,
+            voice_limit_set_by,
+            topic_locked,
+            topic_locked_set_by,
+            rules,
+            rules_set_by,
+            language,
+            language_set_by,
+        } = data;
+
         let Some(conn) = self.connections.get_mut(&connection_id) else {
             return Task::none();
         };
@@ -120,6 +198,57 @@ impl NexusApp {
             }
         }
 
+        // Handle topic lock change
+        if let Some(locked) = topic_locked {
+            let username = topic_locked_set_by.as_deref().unwrap_or("");
+            let message = if locked {
+                t_args("msg-topic-locked", &[("username", username)])
+            } else {
+                t_args("msg-topic-unlocked", &[("username", username)])
+            };
+            messages.push(message);
+
+            // Update channel state
+            if let Some(channel_state) = conn.channels.get_mut(&channel_lower) {
+                channel_state.topic_locked = locked;
+            }
+        }
+
+        // Handle rules change
+        if let Some(new_rules) = rules {
+            let username = rules_set_by.as_deref().unwrap_or("");
+            let message = if new_rules.is_empty() {
+                t_args("msg-rules-cleared", &[("username", username)])
+            } else {
+                t_args("msg-rules-set", &[("username", username)])
+            };
+            messages.push(message);
+
+            // Update channel state
+            if let Some(channel_state) = conn.channels.get_mut(&channel_lower) {
+                channel_state.rules = (!new_rules.is_empty()).then_some(new_rules);
+            }
+        }
+
+        // Handle language change
+        if let Some(new_language) = language {
+            let username = language_set_by.as_deref().unwrap_or("");
+            let message = if new_language.is_empty() {
+                t_args("msg-language-cleared", &[("username", username)])
+            } else {
+                t_args(
+                    "msg-language-set",
+                    &[("username", username), ("language", &new_language)],
+                )
+            };
+            messages.push(message);
+
+            // Update channel state
+            if let Some(channel_state) = conn.channels.get_mut(&channel_lower) {
+                channel_state.language = (!new_language.is_empty()).then_some(new_language);
+            }
+        }
+
         // Handle secret mode change
         if let Some(new_secret) = secret {
             let username = secret_set_by.as_deref().unwrap_or("");
@@ -136,6 +265,25 @@ impl NexusApp {
             }
         }
 
+        // Handle voice limit change (0 = limit removed)
+        if let Some(new_limit) = voice_limit {
+            let username = voice_limit_set_by.as_deref().unwrap_or("");
+            let message = if new_limit == 0 {
+                t_args("msg-voice-limit-cleared", &[("username", username)])
+            } else {
+                t_args(
+                    "msg-voice-limit-set",
+                    &[("username", username), ("limit", &new_limit.to_string())],
+                )
+            };
+            messages.push(message);
+
+            // Update channel state
+            if let Some(channel_state) = conn.channels.get_mut(&channel_lower) {
+                channel_state.voice_limit = (new_limit > 0).then_some(new_limit);
+            }
+        }
+
         // Add system messages to the channel
         let mut task = Task::none();
         for message in messages {
@@ -173,6 +321,103 @@ impl NexusApp {
             _ => self.add_active_tab_message(connection_id, message),
         }
     }
+
+    /// Handle chat topic lock response
+    ///
+    /// On success the ChatUpdated broadcast announces the change, so only
+    /// errors are shown here.
+    pub fn handle_chat_topic_lock_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+    ) -> Task<Message> {
+        if success {
+            return Task::none();
+        }
+
+        let message = ChatMessage::error(t_args(
+            "err-failed-lock-topic",
+            &[("error", &error.unwrap_or_default())],
+        ));
+        self.add_active_tab_message(connection_id, message)
+    }
+
+    /// Handle chat language response (/language)
+    ///
+    /// On success the ChatUpdated broadcast announces the change, so only
+    /// errors are shown here.
+    pub fn handle_chat_language_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+    ) -> Task<Message> {
+        if success {
+            return Task::none();
+        }
+
+        let message = ChatMessage::error(t_args(
+            "err-failed-set-language",
+            &[("error", &error.unwrap_or_default())],
+        ));
+        self.add_active_tab_message(connection_id, message)
+    }
+
+    /// Handle chat topic history response (/topic history)
+    ///
+    /// Lists the channel's recent topic changes, newest first, in the channel tab.
+    pub fn handle_chat_topic_history_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+        channel: Option<String>,
+        changes: Option<Vec<ChatTopicChange>>,
+    ) -> Task<Message> {
+        if !success {
+            let error_msg = error.unwrap_or_else(|| t("err-unknown"));
+            return self.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+        }
+
+        let Some(channel) = channel else {
+            return Task::none();
+        };
+        let changes = changes.unwrap_or_default();
+
+        if changes.is_empty() {
+            return self.add_channel_message(
+                connection_id,
+                &channel,
+                ChatMessage::info(t("msg-topic-history-empty")),
+            );
+        }
+
+        let mut output = t_args("msg-topic-history-header", &[("channel", &channel)]);
+        for change in &changes {
+            let when = i64::try_from(change.changed_at)
+                .ok()
+                .and_then(|secs| DateTime::from_timestamp(secs, 0))
+                .map(|dt| dt.format(DATETIME_FORMAT).to_string())
+                .unwrap_or_default();
+            let username = change.set_by.as_deref().unwrap_or("?");
+            let line = match &change.topic {
+                Some(topic) => t_args(
+                    "msg-topic-history-set",
+                    &[("time", &when), ("username", username), ("topic", topic)],
+                ),
+                None => t_args(
+                    "msg-topic-history-cleared",
+                    &[("time", &when), ("username", username)],
+                ),
+            };
+            output.push('\n');
+            output.push_str("  ");
+            output.push_str(&line);
+        }
+
+        self.add_channel_message(connection_id, &channel, ChatMessage::info(output))
+    }
 }
 
 /// Check if a word appears in text with word boundaries
@@ -184,7 +429,7 @@ impl NexusApp {
 /// Note: For CJK text without spaces/punctuation, this may not match due to
 /// word boundary requirements. This is a known limitation - proper CJK word
 /// segmentation would require a tokenizer.
-fn contains_word(text: &str, word: &str) -> bool {
+pub(super) fn contains_word(text: &str, word: &str) -> bool {
     // Empty word matches nothing (guard against notification spam)
     if word.is_empty() {
         return false;
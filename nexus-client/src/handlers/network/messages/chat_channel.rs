@@ -4,6 +4,7 @@
 //! - ChatJoinResponse - Response to /join command
 //! - ChatLeaveResponse - Response to /leave command
 //! - ChatSecretResponse - Response to /secret command
+//! - ChatEntryUpdateResponse - Response to /welcome and /rules commands
 //! - ChatUserJoined - Notification when another user joins a channel
 //! - ChatUserLeft - Notification when another user leaves a channel
 //! - ChatListResponse - Response to /channels command
@@ -15,6 +16,7 @@ use nexus_common::framing::MessageId;
 
 use crate::NexusApp;
 use crate::config::events::EventType;
+use crate::drafts::DraftContext;
 use crate::events::{EventContext, emit_event};
 use crate::i18n::{t, t_args};
 use crate::types::{ChannelState, ChatMessage, ChatTab, Message, ResponseRouting};
@@ -27,9 +29,19 @@ pub struct ChatJoinResponseData {
     pub topic: Option<String>,
     pub topic_set_by: Option<String>,
     pub secret: Option<bool>,
+    /// Whether the topic is locked
+    pub topic_locked: bool,
+    /// Message shown when joining the channel
+    pub entry_message: Option<String>,
+    /// Channel rules
+    pub rules: Option<String>,
+    /// Channel language tag
+    pub language: Option<String>,
     pub members: Option<Vec<String>>,
     /// Nicknames currently in voice chat (only if we have voice_listen permission)
     pub voiced: Option<Vec<String>>,
+    /// Maximum number of voice participants (None if unlimited)
+    pub voice_limit: Option<u32>,
 }
 
 impl NexusApp {
@@ -37,11 +49,24 @@ impl NexusApp {
     ///
     /// On success: Create channel tab, show topic as first message, set focus to new channel
     /// On error: Show error in console
+    ///
+    /// Channels rejoined from a bookmark's saved workspace go back to their
+    /// saved tab position and only take focus if they were the active tab.
     pub fn handle_chat_join_response(
         &mut self,
         connection_id: usize,
+        message_id: MessageId,
         data: ChatJoinResponseData,
     ) -> Task<Message> {
+        let rejoin = self
+            .connections
+            .get_mut(&connection_id)
+            .and_then(|conn| conn.pending_requests.remove(&message_id))
+            .and_then(|routing| match routing {
+                ResponseRouting::RejoinChannel { activate } => Some(activate),
+                _ => None,
+            });
+
         if !data.success {
             let error_msg = data.error.unwrap_or_else(|| t("err-unknown"));
             return self.add_active_tab_message(
@@ -55,14 +80,16 @@ impl NexusApp {
         };
 
         // Emit event for our own join (is_from_self suppresses notification but allows sound)
-        emit_event(
-            self,
-            EventType::ChatJoin,
-            EventContext::new()
-                .with_connection_id(connection_id)
-                .with_channel(&channel_name)
-                .with_is_from_self(true),
-        );
+        if rejoin.is_none() {
+            emit_event(
+                self,
+                EventType::ChatJoin,
+                EventContext::new()
+                    .with_connection_id(connection_id)
+                    .with_channel(&channel_name)
+                    .with_is_from_self(true),
+            );
+        }
 
         let Some(conn) = self.connections.get_mut(&connection_id) else {
             return Task::none();
@@ -74,18 +101,40 @@ impl NexusApp {
             channel_state.topic = data.topic.clone();
             channel_state.topic_set_by = data.topic_set_by.clone();
             channel_state.secret = data.secret.unwrap_or(false);
+            channel_state.topic_locked = data.topic_locked;
+            channel_state.entry_message = data.entry_message.clone();
+            channel_state.rules = data.rules.clone();
+            channel_state.language = data.language.clone();
             channel_state.members = data.members.clone().unwrap_or_default();
+            channel_state.voice_limit = data.voice_limit;
         } else {
             // Create new channel state
-            let channel_state = ChannelState::new(
-                data.topic.clone(),
-                data.topic_set_by.clone(),
-                data.secret.unwrap_or(false),
-                data.members.unwrap_or_default(),
-            );
+            let channel_state = ChannelState {
+                topic_locked: data.topic_locked,
+                entry_message: data.entry_message.clone(),
+                rules: data.rules.clone(),
+                language: data.language.clone(),
+                voice_limit: data.voice_limit,
+                ..ChannelState::new(
+                    data.topic.clone(),
+                    data.topic_set_by.clone(),
+                    data.secret.unwrap_or(false),
+                    data.members.unwrap_or_default(),
+                )
+            };
             let channel_lower = channel_name.to_lowercase();
             conn.channels.insert(channel_lower.clone(), channel_state);
-            conn.channel_tabs.push(channel_name.clone());
+            let workspace = rejoin
+                .and(conn.bookmark_id)
+                .and_then(|id| self.config.get_bookmark(id))
+                .map(|bookmark| &bookmark.workspace);
+            match workspace {
+                Some(workspace) => {
+                    let pos = workspace.channel_insert_position(&conn.channel_tabs, &channel_name);
+                    conn.channel_tabs.insert(pos, channel_name.clone());
+                }
+                None => conn.channel_tabs.push(channel_name.clone()),
+            }
 
             // Populate voiced nicknames if provided
             if let Some(voiced) = data.voiced {
@@ -110,14 +159,23 @@ impl NexusApp {
         }
 
         // Set active tab to the newly joined channel
-        conn.active_chat_tab = ChatTab::Channel(channel_name.clone());
+        let activate = rejoin.unwrap_or(true);
+        if activate {
+            conn.set_active_chat_tab(ChatTab::Channel(channel_name.clone()));
+        }
 
         // Clear unread marker since we're now viewing this tab
         conn.unread_tabs
             .remove(&ChatTab::Channel(channel_name.clone()));
+        conn.mention_tabs
+            .remove(&ChatTab::Channel(channel_name.clone()));
+
+        #[cfg(target_os = "macos")]
+        self.update_dock_badge();
 
         // Add topic message if present
         self.add_topic_message(connection_id, &channel_name, data.topic, data.topic_set_by);
+        self.add_entry_message(connection_id, &channel_name, data.entry_message);
 
         // Add secret indicator if channel is secret
         if data.secret.unwrap_or(false) {
@@ -128,6 +186,10 @@ impl NexusApp {
             );
         }
 
+        if !activate {
+            return Task::none();
+        }
+
         // Focus chat input
         self.scroll_chat_if_visible(true)
     }
@@ -308,7 +370,7 @@ impl NexusApp {
 
         for channel in &channels {
             // Format: #channel (N members) - Topic
-            // Or: #channel (N members) [secret] - Topic
+            // Or: #channel [en] [secret] (N members) - Topic
             let member_text = t_args(
                 "msg-channel-member-count",
                 &[("count", &channel.member_count.to_string())],
@@ -320,6 +382,12 @@ impl NexusApp {
                 String::new()
             };
 
+            let language_marker = channel
+                .language
+                .as_ref()
+                .map(|language| format!(" [{}]", language))
+                .unwrap_or_default();
+
             let topic_text = channel
                 .topic
                 .as_ref()
@@ -327,8 +395,8 @@ impl NexusApp {
                 .unwrap_or_default();
 
             output.push_str(&format!(
-                "  {}{} ({}){}",
-                channel.name, secret_marker, member_text, topic_text
+                "  {}{}{} ({}){}",
+                channel.name, language_marker, secret_marker, member_text, topic_text
             ));
             output.push('\n');
         }
@@ -388,6 +456,56 @@ impl NexusApp {
         self.add_channel_message(connection_id, &channel, ChatMessage::info(message))
     }
 
+    /// Handle response to ChatEntryUpdate request (/welcome and /rules)
+    ///
+    /// On success: Update the local entry message and show confirmation message
+    /// (rule changes arrive through ChatUpdated)
+    /// On error: Show error in the active tab
+    pub fn handle_chat_entry_update_response(
+        &mut self,
+        connection_id: usize,
+        message_id: MessageId,
+        success: bool,
+        error: Option<String>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+
+        let pending = conn.pending_requests.remove(&message_id);
+
+        if !success {
+            let error_msg = error.unwrap_or_else(|| t("err-unknown"));
+            return self.add_active_tab_message(
+                connection_id,
+                ChatMessage::error(t_args("err-failed-update-entry", &[("error", &error_msg)])),
+            );
+        }
+
+        let Some(ResponseRouting::EntryUpdateResult {
+            channel,
+            entry_message,
+        }) = pending
+        else {
+            return Task::none();
+        };
+
+        let Some(entry_message) = entry_message else {
+            return Task::none();
+        };
+
+        let message = if entry_message.is_empty() {
+            t("msg-entry-message-cleared")
+        } else {
+            t("msg-entry-message-updated")
+        };
+        if let Some(channel_state) = conn.get_channel_state_mut(&channel) {
+            channel_state.entry_message = (!entry_message.is_empty()).then_some(entry_message);
+        }
+
+        self.add_channel_message(connection_id, &channel, ChatMessage::info(message))
+    }
+
     // =========================================================================
     // Helper Functions
     // =========================================================================
@@ -414,9 +532,10 @@ impl NexusApp {
         conn.channel_voiced.remove(&channel_lower);
 
         // Remove from tabs list and unread set
-        if let Some(idx) = tab_index {
-            let removed_name = conn.channel_tabs.remove(idx);
-            conn.unread_tabs.remove(&ChatTab::Channel(removed_name));
+        let removed_tab = tab_index.map(|idx| ChatTab::Channel(conn.channel_tabs.remove(idx)));
+        if let Some(ref removed_tab) = removed_tab {
+            conn.unread_tabs.remove(removed_tab);
+            conn.mention_tabs.remove(removed_tab);
         }
 
         // Remove scroll state for this tab
@@ -440,14 +559,31 @@ impl NexusApp {
                 ChatTab::Console
             };
 
-            conn.active_chat_tab = new_tab;
+            conn.set_active_chat_tab(new_tab);
         }
 
+        // Drop any unsent draft or queued messages for this channel
+        conn.send_queue.retain(|queued| {
+            !matches!(&queued.tab, ChatTab::Channel(c) if c.to_lowercase() == channel_lower)
+        });
+        conn.drafts.retain(|context, _| {
+            !matches!(context, DraftContext::Chat(ChatTab::Channel(c)) if c.to_lowercase() == channel_lower)
+        });
+
+        #[cfg(target_os = "macos")]
+        self.update_dock_badge();
+
+        // Close the channel's window if it was popped out
+        let close_window = match removed_tab {
+            Some(tab) => self.close_chat_windows(connection_id, Some(&tab)),
+            None => Task::none(),
+        };
+
         if self.active_connection == Some(connection_id) {
             // Focus chat input if this was the active tab
-            self.scroll_chat_if_visible(was_active)
+            Task::batch([close_window, self.scroll_chat_if_visible(was_active)])
         } else {
-            Task::none()
+            close_window
         }
     }
 }
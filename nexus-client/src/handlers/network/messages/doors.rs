@@ -0,0 +1,88 @@
+//! Door response handlers
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use iced::Task;
+
+use crate::NexusApp;
+use crate::i18n::t;
+use crate::types::Message;
+
+impl NexusApp {
+    /// Handle DoorListResponse
+    pub fn handle_door_list_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+        doors: Option<Vec<String>>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+
+        let state = &mut conn.doors;
+        state.loading = false;
+        if success {
+            state.doors = doors.unwrap_or_default();
+            state.error = None;
+        } else {
+            state.error = Some(error.unwrap_or_else(|| t("err-unknown")));
+        }
+
+        Task::none()
+    }
+
+    /// Handle DoorOpenResponse
+    pub fn handle_door_open_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+
+        let state = &mut conn.doors;
+        if success {
+            if let Some(session) = &mut state.session {
+                session.opening = false;
+            }
+        } else {
+            // Back to the list with the reason
+            state.session = None;
+            state.error = Some(error.unwrap_or_else(|| t("err-unknown")));
+        }
+
+        Task::none()
+    }
+
+    /// Handle DoorData (door output)
+    pub fn handle_door_data(&mut self, connection_id: usize, data: String) -> Task<Message> {
+        // Output still in flight after leaving a door is dropped
+        if let Some(conn) = self.connections.get(&connection_id)
+            && let Some(session) = &conn.doors.session
+            && let Ok(bytes) = BASE64.decode(data)
+        {
+            session.process(&bytes);
+        }
+        Task::none()
+    }
+
+    /// Handle DoorClosed (the program exited)
+    pub fn handle_door_closed(
+        &mut self,
+        connection_id: usize,
+        exit_code: Option<u32>,
+    ) -> Task<Message> {
+        if let Some(conn) = self.connections.get_mut(&connection_id)
+            && let Some(session) = &mut conn.doors.session
+        {
+            session.running = false;
+            session.opening = false;
+            session.exit_code = exit_code;
+        }
+        Task::none()
+    }
+}
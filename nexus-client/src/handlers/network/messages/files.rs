@@ -7,12 +7,19 @@ use iced::widget::{Id, scrollable};
 use iced::{Task, widget::operation};
 use nexus_common::ErrorKind;
 use nexus_common::framing::MessageId;
-use nexus_common::protocol::{FileEntry, FileInfoDetails, FileSearchResult};
+use nexus_common::protocol::{
+    ClientMessage, FileActivity, FileCheckIssueKind, FileCheckSummary, FileCleanupReport,
+    FileComment, FileEntry, FileInfoDetails, FileManifestEntry, FileRating, FileSearchResult,
+    FileStatEntry, UploadRule,
+};
 
 use crate::NexusApp;
 use crate::handlers::files::sort_search_results;
+use crate::handlers::network::helpers::format_bytes;
+use crate::transfers::hash_upload_files;
 use crate::types::{
-    FilesManagementState, InputId, Message, PendingOverwrite, ResponseRouting, ScrollableId,
+    FileTab, FilesManagementState, InputId, Message, PendingOverwrite, PlannedUpload,
+    ResponseRouting, ScrollableId, ServerConnection, TabId,
 };
 
 /// Data from a FileListResponse message
@@ -22,6 +29,7 @@ pub struct FileListResponseData {
     pub path: Option<String>,
     pub entries: Option<Vec<FileEntry>>,
     pub can_upload: bool,
+    pub upload_rule: Option<UploadRule>,
 }
 
 impl NexusApp {
@@ -42,9 +50,33 @@ impl NexusApp {
         let routing = conn.pending_requests.remove(&message_id);
 
         // Only handle if this was a tracked file list request
-        let (tab_id, uri_target) = match routing {
-            Some(ResponseRouting::PopulateFileList { tab_id, uri_target }) => (tab_id, uri_target),
-            _ => return Task::none(),
+        match routing {
+            Some(ResponseRouting::PopulateFileList { tab_id, uri_target }) => {
+                let task = self.populate_file_list(connection_id, tab_id, uri_target, data);
+                // The tab's directory changed, so watch the new one instead
+                self.sync_file_watches(connection_id);
+                task
+            }
+            Some(ResponseRouting::RefreshFileList { tab_id, path, root }) => {
+                self.refresh_file_list(connection_id, tab_id, &path, root, data)
+            }
+            Some(ResponseRouting::AutoDownloadList { rule }) => {
+                self.handle_auto_download_list(connection_id, rule, data.success, data.entries)
+            }
+            _ => Task::none(),
+        }
+    }
+
+    /// Show a tab's listing after opening the panel or navigating
+    fn populate_file_list(
+        &mut self,
+        connection_id: usize,
+        tab_id: TabId,
+        uri_target: Option<String>,
+        data: FileListResponseData,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
         };
 
         // Check if this response is for the currently active tab (for scroll behavior)
@@ -63,6 +95,7 @@ impl NexusApp {
 
             // Use server-provided can_upload flag for the current directory
             tab.current_dir_can_upload = data.can_upload;
+            tab.current_dir_upload_rule = data.upload_rule;
 
             tab.entries = data.entries;
             tab.error = None;
@@ -131,6 +164,45 @@ impl NexusApp {
         }
     }
 
+    /// Update a tab's listing after a change in its directory
+    ///
+    /// Unlike navigating, this keeps the scroll position. It's dropped if the
+    /// tab has moved to another directory in the meantime.
+    fn refresh_file_list(
+        &mut self,
+        connection_id: usize,
+        tab_id: TabId,
+        path: &str,
+        root: bool,
+        data: FileListResponseData,
+    ) -> Task<Message> {
+        let Some(tab) = self
+            .connections
+            .get_mut(&connection_id)
+            .and_then(|conn| conn.files_management.tab_by_id_mut(tab_id))
+        else {
+            return Task::none();
+        };
+        if tab.current_path != path || tab.viewing_root != root || tab.entries.is_none() {
+            return Task::none();
+        }
+
+        if data.success {
+            tab.current_dir_can_upload = data.can_upload;
+            tab.current_dir_upload_rule = data.upload_rule;
+            tab.entries = data.entries;
+            tab.update_sorted_entries();
+            Task::none()
+        } else {
+            // The directory itself is gone
+            tab.entries = None;
+            tab.sorted_entries = None;
+            tab.error = data.error;
+            self.sync_file_watches(connection_id);
+            Task::none()
+        }
+    }
+
     /// Handle file create directory response
     ///
     /// On success, closes the dialog and refreshes the file list.
@@ -288,17 +360,383 @@ impl NexusApp {
             return Task::none();
         };
 
-        if success {
-            // Show the info dialog
-            tab.pending_info = info;
-        } else {
+        if !success {
             // Show error in the files panel
             tab.error = error;
+            tab.review = None;
+            return Task::none();
+        }
+
+        // Show the info dialog
+        let is_file = info.as_ref().is_some_and(|info| !info.is_directory);
+        tab.pending_info = info;
+        if !is_file {
+            tab.review = None;
+        }
+
+        // Load the file's comments into the dialog
+        let Some(review) = &tab.review else {
+            return Task::none();
+        };
+        let path = review.path.clone();
+        let root = review.root;
+        match conn.send(ClientMessage::FileCommentList {
+            path: path.clone(),
+            root,
+        }) {
+            Ok(message_id) => {
+                conn.pending_requests.track(
+                    message_id,
+                    ResponseRouting::FileReviewResult { tab_id, path },
+                );
+            }
+            Err(e) => {
+                if let Some(review) = conn
+                    .files_management
+                    .tab_by_id_mut(tab_id)
+                    .and_then(|tab| tab.review.as_mut())
+                {
+                    review.comments = Some(Vec::new());
+                    review.error = Some(format!("{}: {}", t("err-send-failed"), e));
+                }
+            }
+        }
+
+        Task::none()
+    }
+
+    /// Handle file rate response
+    ///
+    /// Updates the rating shown in the info dialog.
+    pub fn handle_file_rate_response(
+        &mut self,
+        connection_id: usize,
+        message_id: MessageId,
+        success: bool,
+        error: Option<String>,
+        rating: Option<FileRating>,
+        my_rating: Option<u8>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+        let Some(tab) = reviewed_tab(conn, message_id) else {
+            return Task::none();
+        };
+
+        if success {
+            if let Some(info) = &mut tab.pending_info {
+                info.rating = rating;
+                info.my_rating = my_rating;
+            }
+            set_review_error(tab, None);
+        } else {
+            set_review_error(tab, Some(error.unwrap_or_else(|| t("err-unknown"))));
+        }
+
+        Task::none()
+    }
+
+    /// Handle file comment create response
+    ///
+    /// Appends the new comment and clears the input.
+    pub fn handle_file_comment_create_response(
+        &mut self,
+        connection_id: usize,
+        message_id: MessageId,
+        success: bool,
+        error: Option<String>,
+        comment: Option<FileComment>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+        let Some(tab) = reviewed_tab(conn, message_id) else {
+            return Task::none();
+        };
+
+        if !success {
+            set_review_error(tab, Some(error.unwrap_or_else(|| t("err-unknown"))));
+            return Task::none();
+        }
+
+        if let Some(info) = &mut tab.pending_info {
+            info.comment_count = Some(info.comment_count.unwrap_or(0) + 1);
+        }
+        if let Some(review) = &mut tab.review {
+            review.comment_input.clear();
+            review.error = None;
+            if let (Some(comments), Some(comment)) = (&mut review.comments, comment) {
+                comments.push(comment);
+            }
+        }
+
+        Task::none()
+    }
+
+    /// Handle file comment list response
+    ///
+    /// Fills the comment list of the info dialog.
+    pub fn handle_file_comment_list_response(
+        &mut self,
+        connection_id: usize,
+        message_id: MessageId,
+        success: bool,
+        error: Option<String>,
+        comments: Option<Vec<FileComment>>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+        let Some(review) = reviewed_tab(conn, message_id).and_then(|tab| tab.review.as_mut())
+        else {
+            return Task::none();
+        };
+
+        // Treat None as empty (defensive against malformed server response)
+        review.comments = Some(comments.unwrap_or_default());
+        if !success {
+            review.error = Some(error.unwrap_or_else(|| t("err-unknown")));
+        }
+
+        Task::none()
+    }
+
+    /// Handle file comment delete response
+    ///
+    /// Removes the deleted comment from the info dialog.
+    pub fn handle_file_comment_delete_response(
+        &mut self,
+        connection_id: usize,
+        message_id: MessageId,
+        success: bool,
+        error: Option<String>,
+        id: Option<i64>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+        let Some(tab) = reviewed_tab(conn, message_id) else {
+            return Task::none();
+        };
+
+        if !success {
+            set_review_error(tab, Some(error.unwrap_or_else(|| t("err-unknown"))));
+            return Task::none();
+        }
+
+        let Some(id) = id else {
+            return Task::none();
+        };
+        if let Some(info) = &mut tab.pending_info {
+            info.comment_count = info.comment_count.map(|count| count.saturating_sub(1));
+        }
+        if let Some(review) = &mut tab.review {
+            review.error = None;
+            if let Some(comments) = &mut review.comments {
+                comments.retain(|comment| comment.id != id);
+            }
+        }
+
+        Task::none()
+    }
+
+    /// Handle upload rule get response
+    ///
+    /// Fills the Upload Rules dialog with the directory's current rule, or
+    /// shows the error inside the dialog.
+    pub fn handle_upload_rule_get_response(
+        &mut self,
+        connection_id: usize,
+        message_id: MessageId,
+        success: bool,
+        error: Option<String>,
+        rule: Option<UploadRule>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+        let Some(tab) = upload_rule_tab(conn, message_id) else {
+            return Task::none();
+        };
+        let Some(pending) = &mut tab.pending_upload_rule else {
+            return Task::none();
+        };
+
+        if success {
+            pending.set_rule(rule);
+        } else {
+            pending.error = Some(error.unwrap_or_else(|| t("err-unknown")));
+        }
+
+        Task::none()
+    }
+
+    /// Handle upload rule set response
+    ///
+    /// On success, closes the dialog and refreshes the file list so the
+    /// directory's rule hint is current. On error, shows it in the dialog.
+    pub fn handle_upload_rule_set_response(
+        &mut self,
+        connection_id: usize,
+        message_id: MessageId,
+        success: bool,
+        error: Option<String>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+        let Some(tab) = upload_rule_tab(conn, message_id) else {
+            return Task::none();
+        };
+
+        if !success {
+            if let Some(pending) = &mut tab.pending_upload_rule {
+                pending.error = Some(error.unwrap_or_else(|| t("err-unknown")));
+            }
+            return Task::none();
+        }
+
+        tab.pending_upload_rule = None;
+
+        // Refresh the current directory listing
+        let tab_id = tab.id;
+        let current_path = tab.current_path.clone();
+        let viewing_root = tab.viewing_root;
+        tab.entries = None;
+        tab.error = None;
+
+        let show_hidden = self.config.settings.show_hidden_files;
+        self.send_file_list_request_for_tab(
+            connection_id,
+            tab_id,
+            current_path,
+            viewing_root,
+            show_hidden,
+            None,
+        )
+    }
+
+    /// Handle file manifest response
+    ///
+    /// Fills the Download Selected dialog with the directory's files, or shows
+    /// the error inside the dialog.
+    pub fn handle_file_manifest_response(
+        &mut self,
+        connection_id: usize,
+        message_id: MessageId,
+        success: bool,
+        error: Option<String>,
+        path: Option<String>,
+        files: Option<Vec<FileManifestEntry>>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+
+        // Only handle if this was a tracked manifest request
+        let tab_id = match conn.pending_requests.remove(&message_id) {
+            Some(ResponseRouting::FileManifestResult { tab_id }) => tab_id,
+            _ => return Task::none(),
+        };
+
+        // The tab may have been closed, or the dialog cancelled while loading
+        let Some(pending) = conn
+            .files_management
+            .tab_by_id_mut(tab_id)
+            .and_then(|tab| tab.pending_download_select.as_mut())
+        else {
+            return Task::none();
+        };
+
+        // Ignore a late response for a dialog that was reopened on another folder
+        if success && path.as_deref() != Some(pending.remote_path.as_str()) {
+            return Task::none();
+        }
+
+        if success {
+            pending.set_files(files.unwrap_or_default());
+        } else {
+            pending.error = error;
         }
 
         Task::none()
     }
 
+    /// Handle file stat response for an upload preview or a download's
+    /// duplicate check
+    ///
+    /// Files the server has with the same size are hashed locally before the
+    /// preview is resolved. If the check failed, the upload is queued as
+    /// selected and the server still refuses conflicting files.
+    pub fn handle_file_stat_response(
+        &mut self,
+        connection_id: usize,
+        message_id: MessageId,
+        success: bool,
+        files: Option<Vec<FileStatEntry>>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+
+        // Only handle if this was a tracked stat request
+        let (tab_id, preview_id) = match conn.pending_requests.remove(&message_id) {
+            Some(ResponseRouting::FileStatResult { tab_id, preview_id }) => (tab_id, preview_id),
+            Some(ResponseRouting::DownloadCheckStat { tab_id, check_id }) => {
+                return self.handle_download_check_stat(
+                    connection_id,
+                    tab_id,
+                    check_id,
+                    success,
+                    files,
+                );
+            }
+            Some(ResponseRouting::AutoDownloadStat { rule, candidates }) => {
+                return self.handle_auto_download_stat(
+                    connection_id,
+                    rule,
+                    candidates,
+                    success,
+                    files,
+                );
+            }
+            _ => return Task::none(),
+        };
+
+        // The preview may have been cancelled or replaced by a newer upload
+        let Some(tab) = conn.files_management.tab_by_id_mut(tab_id) else {
+            return Task::none();
+        };
+        if tab
+            .pending_upload_preview
+            .as_ref()
+            .is_none_or(|pending| pending.id != preview_id)
+        {
+            return Task::none();
+        }
+
+        if !success {
+            let Some(pending) = tab.pending_upload_preview.take() else {
+                return Task::none();
+            };
+            let uploads = PlannedUpload::all(pending.items);
+            return self.queue_uploads(connection_id, pending.remote_root, uploads);
+        }
+
+        let Some(pending) = tab.pending_upload_preview.as_mut() else {
+            return Task::none();
+        };
+        let to_hash = pending.set_remote(files.unwrap_or_default());
+        if to_hash.is_empty() {
+            return self.resolve_upload_preview(connection_id, tab_id);
+        }
+
+        Task::perform(hash_upload_files(to_hash), move |hashes| {
+            Message::FileUploadHashed(connection_id, tab_id, preview_id, hashes)
+        })
+    }
+
     /// Handle file rename response
     ///
     /// On success, closes the dialog and refreshes the file list.
@@ -592,6 +1030,155 @@ impl NexusApp {
         }
     }
 
+    /// Handle FileCleanupResponse from server
+    ///
+    /// Lists what the scan found in the active tab, with the paths of the
+    /// orphaned files so they can be checked before deleting.
+    pub fn handle_file_cleanup_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+        report: Option<FileCleanupReport>,
+    ) -> Task<Message> {
+        let report = match (success, report) {
+            (true, Some(report)) => report,
+            _ => {
+                let error_msg = error.unwrap_or_else(|| t("err-unknown"));
+                return self.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+            }
+        };
+
+        let found = report.orphaned_parts > 0
+            || report.temp_files > 0
+            || report.missing_journal_entries > 0
+            || report.missing_index_entries > 0;
+        if !found {
+            return self.add_active_tab_message(
+                connection_id,
+                ChatMessage::info(t("msg-cleanup-nothing")),
+            );
+        }
+
+        let header = if report.deleted {
+            t("msg-cleanup-deleted")
+        } else {
+            t("msg-cleanup-found")
+        };
+        let mut lines = vec![header];
+        if report.orphaned_parts > 0 {
+            lines.push(t_args(
+                "msg-cleanup-parts",
+                &[
+                    ("count", &report.orphaned_parts.to_string()),
+                    ("size", &format_bytes(report.orphaned_bytes)),
+                ],
+            ));
+            lines.extend(report.paths.iter().map(|path| format!("  {path}")));
+        }
+        if report.temp_files > 0 {
+            lines.push(t_args(
+                "msg-cleanup-temp",
+                &[
+                    ("count", &report.temp_files.to_string()),
+                    ("size", &format_bytes(report.temp_bytes)),
+                ],
+            ));
+        }
+        if report.missing_journal_entries > 0 {
+            lines.push(t_args(
+                "msg-cleanup-journal",
+                &[("count", &report.missing_journal_entries.to_string())],
+            ));
+        }
+        if report.missing_index_entries > 0 {
+            lines.push(t_args(
+                "msg-cleanup-index",
+                &[("count", &report.missing_index_entries.to_string())],
+            ));
+        }
+        if !report.deleted {
+            lines.push(t("msg-cleanup-hint"));
+        }
+
+        let tasks: Vec<_> = lines
+            .into_iter()
+            .map(|line| self.add_active_tab_message(connection_id, ChatMessage::info(line)))
+            .collect();
+        Task::batch(tasks)
+    }
+
+    /// Handle FileCheckIssue from server
+    ///
+    /// Each discrepancy arrives on its own while the check runs, so it's
+    /// listed in the active tab as it comes in.
+    pub fn handle_file_check_issue(
+        &mut self,
+        connection_id: usize,
+        kind: FileCheckIssueKind,
+        path: String,
+        repaired: bool,
+    ) -> Task<Message> {
+        let kind_key = match kind {
+            FileCheckIssueKind::NotIndexed => "filecheck-not-indexed",
+            FileCheckIssueKind::IndexedButMissing => "filecheck-indexed-but-missing",
+            FileCheckIssueKind::IndexOutdated => "filecheck-index-outdated",
+            FileCheckIssueKind::OrphanedActivity => "filecheck-orphaned-activity",
+            FileCheckIssueKind::OrphanedReviews => "filecheck-orphaned-reviews",
+            FileCheckIssueKind::OrphanedUploadRule => "filecheck-orphaned-upload-rule",
+        };
+        let key = if repaired {
+            "msg-filecheck-issue-repaired"
+        } else {
+            "msg-filecheck-issue"
+        };
+        let msg = t_args(key, &[("kind", &t(kind_key)), ("path", &path)]);
+        self.add_active_tab_message(connection_id, ChatMessage::info(msg))
+    }
+
+    /// Handle FileCheckResponse from server
+    pub fn handle_file_check_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+        summary: Option<FileCheckSummary>,
+    ) -> Task<Message> {
+        let summary = match (success, summary) {
+            (true, Some(summary)) => summary,
+            _ => {
+                let error_msg = error.unwrap_or_else(|| t("err-unknown"));
+                return self.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+            }
+        };
+
+        let entries = summary.entries.to_string();
+        let mut lines = if summary.issues == 0 {
+            vec![t_args("msg-filecheck-clean", &[("entries", &entries)])]
+        } else {
+            vec![t_args(
+                "msg-filecheck-done",
+                &[
+                    ("issues", &summary.issues.to_string()),
+                    ("entries", &entries),
+                    ("repaired", &summary.repaired.to_string()),
+                ],
+            )]
+        };
+        if !summary.index_checked {
+            lines.push(t("msg-filecheck-index-skipped"));
+        }
+        if summary.issues > 0 && summary.repaired == 0 {
+            lines.push(t("msg-filecheck-hint"));
+        }
+
+        let tasks: Vec<_> = lines
+            .into_iter()
+            .map(|line| self.add_active_tab_message(connection_id, ChatMessage::info(line)))
+            .collect();
+        Task::batch(tasks)
+    }
+
     /// Handle FileSearchResponse from server
     pub fn handle_file_search_response(
         &mut self,
@@ -647,4 +1234,104 @@ impl NexusApp {
 
         Task::none()
     }
+
+    /// Handle FilePopularResponse or FileRecentResponse from server
+    ///
+    /// Fills in the matching listing of the tab's highlights.
+    pub fn handle_file_highlights_response(
+        &mut self,
+        connection_id: usize,
+        message_id: MessageId,
+        success: bool,
+        error: Option<String>,
+        files: Option<Vec<FileActivity>>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+
+        // Only handle if this was a tracked highlights request
+        let (tab_id, popular) = match conn.pending_requests.remove(&message_id) {
+            Some(ResponseRouting::FilePopularResult { tab_id }) => (tab_id, true),
+            Some(ResponseRouting::FileRecentResult { tab_id }) => (tab_id, false),
+            _ => return Task::none(),
+        };
+
+        // Ignore if the tab was closed or has left highlights since the request
+        let Some(highlights) = conn
+            .files_management
+            .tab_by_id_mut(tab_id)
+            .and_then(|tab| tab.highlights.as_mut())
+        else {
+            return Task::none();
+        };
+
+        // Ignore stale responses from before a refresh
+        let current_request = if popular {
+            &mut highlights.popular_request
+        } else {
+            &mut highlights.recent_request
+        };
+        if *current_request != Some(message_id) {
+            return Task::none();
+        }
+        *current_request = None;
+
+        if success {
+            // Treat None as empty (defensive against malformed server response)
+            let files = files.unwrap_or_default();
+            if popular {
+                highlights.popular = Some(files);
+            } else {
+                highlights.recent = Some(files);
+            }
+        } else {
+            highlights.error = Some(error.unwrap_or_else(|| t("err-unknown")));
+        }
+
+        Task::none()
+    }
+}
+
+/// Find the tab whose info dialog a rating or comment response belongs to
+///
+/// Returns `None` if the request wasn't tracked, the tab was closed, or the
+/// dialog now shows a different file.
+fn reviewed_tab(conn: &mut ServerConnection, message_id: MessageId) -> Option<&mut FileTab> {
+    let Some(ResponseRouting::FileReviewResult { tab_id, path }) =
+        conn.pending_requests.remove(&message_id)
+    else {
+        return None;
+    };
+
+    conn.files_management.tab_by_id_mut(tab_id).filter(|tab| {
+        tab.review
+            .as_ref()
+            .is_some_and(|review| review.path == path)
+    })
+}
+
+/// Find the tab whose Upload Rules dialog a response belongs to
+///
+/// Returns `None` if the request wasn't tracked, the tab was closed, or the
+/// dialog was closed or now shows a different directory.
+fn upload_rule_tab(conn: &mut ServerConnection, message_id: MessageId) -> Option<&mut FileTab> {
+    let Some(ResponseRouting::FileUploadRuleResult { tab_id, path }) =
+        conn.pending_requests.remove(&message_id)
+    else {
+        return None;
+    };
+
+    conn.files_management.tab_by_id_mut(tab_id).filter(|tab| {
+        tab.pending_upload_rule
+            .as_ref()
+            .is_some_and(|pending| pending.path == path)
+    })
+}
+
+/// Show (or clear) an error in the info dialog's review section
+fn set_review_error(tab: &mut FileTab, error: Option<String>) {
+    if let Some(review) = &mut tab.review {
+        review.error = error;
+    }
 }
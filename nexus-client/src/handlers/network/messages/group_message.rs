@@ -0,0 +1,214 @@
+//! Group conversation handlers
+
+use chrono::{Local, TimeZone};
+use iced::Task;
+use nexus_common::framing::MessageId;
+use nexus_common::protocol::ChatAction;
+
+use crate::NexusApp;
+use crate::i18n::t_args;
+use crate::types::{ChatMessage, ChatTab, GroupState, Message, ResponseRouting};
+
+/// Parameters for handling an incoming group message
+pub struct GroupMessageParams {
+    pub connection_id: usize,
+    pub group_id: u64,
+    pub from_nickname: String,
+    pub from_admin: bool,
+    pub from_shared: bool,
+    pub message: String,
+    pub action: ChatAction,
+    pub timestamp: u64,
+}
+
+impl NexusApp {
+    /// Handle a group's member list
+    ///
+    /// Opens the tab for a group we were just added to, notes who joined and
+    /// who left, and closes the tab once we're no longer a member.
+    pub fn handle_group_members(
+        &mut self,
+        connection_id: usize,
+        group_id: u64,
+        members: Vec<String>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+
+        let own_lower = conn.nickname.to_lowercase();
+        if !members.iter().any(|m| m.to_lowercase() == own_lower) {
+            if conn.groups.contains_key(&group_id) {
+                return self.remove_group_tab(connection_id, group_id);
+            }
+            return Task::none();
+        }
+
+        if !conn.group_tabs.contains(&group_id) {
+            conn.group_tabs.push(group_id);
+        }
+        let group = conn.groups.entry(group_id).or_default();
+        let previous = std::mem::replace(&mut group.members, members);
+
+        // Nothing to announce for a group we just joined
+        if previous.is_empty() {
+            return Task::none();
+        }
+
+        let is_in = |list: &[String], nickname: &str| {
+            let nickname = nickname.to_lowercase();
+            list.iter().any(|m| m.to_lowercase() == nickname)
+        };
+        let mut notices = Vec::new();
+        for nickname in &group.members {
+            if !is_in(&previous, nickname) {
+                notices.push(t_args(
+                    "msg-group-member-joined",
+                    &[("nickname", nickname.as_str())],
+                ));
+            }
+        }
+        for nickname in &previous {
+            if !is_in(&group.members, nickname) {
+                notices.push(t_args(
+                    "msg-group-member-left",
+                    &[("nickname", nickname.as_str())],
+                ));
+            }
+        }
+
+        let tasks: Vec<_> = notices
+            .into_iter()
+            .map(|notice| {
+                self.add_group_message(connection_id, group_id, ChatMessage::system(notice))
+            })
+            .collect();
+        Task::batch(tasks)
+    }
+
+    /// Handle incoming group message
+    pub fn handle_group_message(&mut self, params: GroupMessageParams) -> Task<Message> {
+        let GroupMessageParams {
+            connection_id,
+            group_id,
+            from_nickname,
+            from_admin,
+            from_shared,
+            message,
+            action,
+            timestamp,
+        } = params;
+
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+        if !conn.groups.contains_key(&group_id) {
+            return Task::none();
+        }
+
+        // Use server timestamp if available, otherwise fall back to local time
+        let datetime = if timestamp > 0 {
+            Local
+                .timestamp_opt(timestamp as i64, 0)
+                .single()
+                .unwrap_or_else(Local::now)
+        } else {
+            Local::now()
+        };
+        conn.mark_user_active(&from_nickname, datetime.timestamp());
+        let chat_msg = ChatMessage::with_timestamp_and_status(
+            from_nickname,
+            message,
+            datetime,
+            from_admin,
+            from_shared,
+            action,
+        );
+
+        let task = self.add_group_message(connection_id, group_id, chat_msg);
+
+        // Update tray icon state (Windows/Linux only)
+        #[cfg(not(target_os = "macos"))]
+        self.update_tray_state();
+
+        // Update dock badge count (macOS only)
+        #[cfg(target_os = "macos")]
+        self.update_dock_badge();
+
+        task
+    }
+
+    /// Handle response to starting a group conversation
+    pub fn handle_group_message_create_response(
+        &mut self,
+        connection_id: usize,
+        message_id: MessageId,
+        success: bool,
+        error: Option<String>,
+        group_id: Option<u64>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+        let routing = conn.pending_requests.remove(&message_id);
+
+        if success {
+            let (Some(group_id), Some(ResponseRouting::OpenGroupTab(requested))) =
+                (group_id, routing)
+            else {
+                return Task::none();
+            };
+
+            // The member list follows; show who we asked for until then
+            if !conn.groups.contains_key(&group_id) {
+                let mut members = vec![conn.nickname.clone()];
+                members.extend(requested);
+                conn.groups.insert(
+                    group_id,
+                    GroupState {
+                        members,
+                        messages: Vec::new(),
+                    },
+                );
+                conn.group_tabs.push(group_id);
+            }
+            return Task::done(Message::SwitchChatTab(ChatTab::Group(group_id)));
+        }
+
+        let error_msg = t_args(
+            "err-failed-start-group",
+            &[("error", &error.unwrap_or_default())],
+        );
+        self.add_active_tab_message(connection_id, ChatMessage::error(error_msg))
+    }
+
+    /// Handle response to sending in, inviting to, or leaving a group
+    ///
+    /// Only failures are shown: in the group's tab if it's still open,
+    /// otherwise in the active tab.
+    pub fn handle_group_response(
+        &mut self,
+        connection_id: usize,
+        message_id: MessageId,
+        success: bool,
+        error: Option<String>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+        let routing = conn.pending_requests.remove(&message_id);
+        if success {
+            return Task::none();
+        }
+
+        let error_msg = ChatMessage::error(error.unwrap_or_default());
+        match routing {
+            Some(ResponseRouting::ShowErrorInGroupTab(group_id))
+                if conn.groups.contains_key(&group_id) =>
+            {
+                self.add_group_message(connection_id, group_id, error_msg)
+            }
+            _ => self.add_active_tab_message(connection_id, error_msg),
+        }
+    }
+}
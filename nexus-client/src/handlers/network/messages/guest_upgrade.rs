@@ -0,0 +1,32 @@
+//! Guest registration response handler
+
+use iced::Task;
+
+use crate::NexusApp;
+use crate::i18n::t_args;
+use crate::types::{ChatMessage, Message};
+
+impl NexusApp {
+    /// Handle guest upgrade response
+    ///
+    /// The session stays a guest session; the new account is used from the
+    /// next login.
+    pub fn handle_guest_upgrade_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+        username: Option<String>,
+    ) -> Task<Message> {
+        let message = if success {
+            ChatMessage::info(t_args(
+                "msg-guest-upgraded",
+                &[("username", &username.unwrap_or_default())],
+            ))
+        } else {
+            ChatMessage::error(error.unwrap_or_default())
+        };
+
+        self.add_active_tab_message(connection_id, message)
+    }
+}
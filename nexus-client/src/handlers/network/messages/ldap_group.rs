@@ -0,0 +1,96 @@
+//! Directory group mapping response handlers
+
+use iced::Task;
+use nexus_common::protocol::LdapGroupInfo;
+
+use crate::NexusApp;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message};
+
+impl NexusApp {
+    /// Handle directory group list response
+    pub fn handle_ldap_group_list_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+        groups: Option<Vec<LdapGroupInfo>>,
+    ) -> Task<Message> {
+        if !success {
+            let message = ChatMessage::error(error.unwrap_or_default());
+            return self.add_active_tab_message(connection_id, message);
+        }
+
+        let groups = groups.unwrap_or_default();
+
+        if groups.is_empty() {
+            let message = ChatMessage::info(t("msg-ldap-group-list-empty"));
+            return self.add_active_tab_message(connection_id, message);
+        }
+
+        let mut tasks = Vec::new();
+        tasks.push(self.add_active_tab_message(
+            connection_id,
+            ChatMessage::info(t("msg-ldap-group-list-header")),
+        ));
+        for group in groups {
+            let formatted = format_group(&group);
+            tasks.push(self.add_active_tab_message(connection_id, ChatMessage::info(formatted)));
+        }
+
+        Task::batch(tasks)
+    }
+
+    /// Handle directory group set response
+    pub fn handle_ldap_group_set_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+        group: Option<String>,
+    ) -> Task<Message> {
+        let message = if success {
+            ChatMessage::info(t_args(
+                "msg-ldap-group-set",
+                &[("group", &group.unwrap_or_default())],
+            ))
+        } else {
+            ChatMessage::error(error.unwrap_or_default())
+        };
+
+        self.add_active_tab_message(connection_id, message)
+    }
+
+    /// Handle directory group delete response
+    pub fn handle_ldap_group_delete_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+        group: Option<String>,
+    ) -> Task<Message> {
+        let message = if success {
+            ChatMessage::info(t_args(
+                "msg-ldap-group-removed",
+                &[("group", &group.unwrap_or_default())],
+            ))
+        } else {
+            ChatMessage::error(error.unwrap_or_default())
+        };
+
+        self.add_active_tab_message(connection_id, message)
+    }
+}
+
+/// Format a single group mapping for display
+fn format_group(group: &LdapGroupInfo) -> String {
+    let access = if group.is_admin {
+        t("msg-ldap-group-admin")
+    } else if group.permissions.is_empty() {
+        t("msg-ldap-group-no-permissions")
+    } else {
+        group.permissions.join(", ")
+    };
+
+    format!("  {} - {}", group.group, access)
+}
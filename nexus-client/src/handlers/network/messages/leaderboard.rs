@@ -0,0 +1,115 @@
+//! Leaderboard response handler
+
+use chrono::Local;
+use iced::Task;
+use nexus_common::framing::MessageId;
+use nexus_common::protocol::{LeaderboardEntry, LeaderboardStat};
+
+use crate::NexusApp;
+use crate::handlers::network::helpers::format_bytes;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message, ResponseRouting};
+
+impl NexusApp {
+    /// Handle leaderboard response (from `/top` command)
+    pub fn handle_leaderboard_response(
+        &mut self,
+        connection_id: usize,
+        message_id: MessageId,
+        success: bool,
+        error: Option<String>,
+        entries: Option<Vec<LeaderboardEntry>>,
+    ) -> Task<Message> {
+        let routing = self
+            .connections
+            .get_mut(&connection_id)
+            .and_then(|conn| conn.pending_requests.remove(&message_id));
+        let Some(ResponseRouting::LeaderboardResult(stat)) = routing else {
+            return Task::none();
+        };
+
+        if !success {
+            let message = ChatMessage::error(error.unwrap_or_default());
+            return self.add_active_tab_message(connection_id, message);
+        }
+
+        let entries = entries.unwrap_or_default();
+        if entries.is_empty() {
+            let message = ChatMessage::info(t("msg-leaderboard-empty"));
+            return self.add_active_tab_message(connection_id, message);
+        }
+
+        let mut lines = vec![t_args(
+            "msg-leaderboard-header",
+            &[("stat", &t(stat_translation_key(stat)))],
+        )];
+        for (index, entry) in entries.iter().enumerate() {
+            lines.push(format_entry(index + 1, entry, stat));
+        }
+
+        // Add each line as a separate chat message with shared timestamp
+        let timestamp = Local::now();
+        let mut task = Task::none();
+        for line in lines {
+            task = self.add_active_tab_message(
+                connection_id,
+                ChatMessage::info_with_timestamp(line, timestamp),
+            );
+        }
+        task
+    }
+}
+
+/// Translation key for a counter's name
+fn stat_translation_key(stat: LeaderboardStat) -> &'static str {
+    match stat {
+        LeaderboardStat::Logins => "leaderboard-stat-logins",
+        LeaderboardStat::Messages => "leaderboard-stat-messages",
+        LeaderboardStat::Uploads => "leaderboard-stat-uploads",
+        LeaderboardStat::DownloadBytes => "leaderboard-stat-downloaded",
+    }
+}
+
+/// Format one leaderboard line: "  1. alice (Elite) - 42"
+fn format_entry(place: usize, entry: &LeaderboardEntry, stat: LeaderboardStat) -> String {
+    let value = stat.value(&entry.stats);
+    let value = if stat == LeaderboardStat::DownloadBytes {
+        format_bytes(value)
+    } else {
+        value.to_string()
+    };
+
+    match &entry.rank {
+        Some(rank) => format!("  {place}. {} ({rank}) - {value}", entry.username),
+        None => format!("  {place}. {} - {value}", entry.username),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nexus_common::protocol::UserStats;
+
+    #[test]
+    fn test_format_entry() {
+        let mut entry = LeaderboardEntry {
+            username: "alice".to_string(),
+            stats: UserStats {
+                logins: 42,
+                download_bytes: 2048,
+                ..Default::default()
+            },
+            rank: None,
+        };
+        assert_eq!(
+            format_entry(1, &entry, LeaderboardStat::Logins),
+            "  1. alice - 42"
+        );
+
+        entry.rank = Some("Elite".to_string());
+        assert_eq!(
+            format_entry(3, &entry, LeaderboardStat::DownloadBytes),
+            "  3. alice (Elite) - 2.0 KB"
+        );
+    }
+}
@@ -4,8 +4,10 @@
 
 use std::time::Instant;
 
+use chat::ChatUpdatedData;
 use chat_channel::ChatJoinResponseData;
 use files::FileListResponseData;
+use group_message::GroupMessageParams;
 use user_message::UserMessageParams;
 
 mod ban_create;
@@ -14,11 +16,23 @@ mod ban_list;
 mod broadcast;
 mod chat;
 mod chat_channel;
+mod doors;
 mod error;
 mod files;
+mod group_message;
+mod guest_upgrade;
+mod ldap_group;
+mod leaderboard;
 mod news;
+mod nickname;
 mod permissions;
+mod presence;
 mod server_info;
+mod server_log;
+mod server_stats;
+mod snippet;
+mod statistics;
+mod storage;
 mod time_format;
 mod trust_create;
 mod trust_delete;
@@ -31,15 +45,15 @@ mod user_message;
 mod user_status;
 mod voice;
 
-pub use user_admin::UserEditResponseData;
+pub use user_admin::{ImpersonateQueryResponseData, UserEditResponseData};
 
 use iced::Task;
 use nexus_common::framing::MessageId;
-use nexus_common::protocol::ServerMessage;
+use nexus_common::protocol::{ServerMessage, ServerTime};
 
 use crate::NexusApp;
 use crate::i18n::t_args;
-use crate::types::{ChatMessage, Message, ResponseRouting};
+use crate::types::{ChatMessage, Message, ResponseRouting, ServerClock};
 
 impl NexusApp {
     /// Handle message received from server
@@ -52,7 +66,10 @@ impl NexusApp {
         msg: ServerMessage,
         receive_timestamp: Option<Instant>,
     ) -> Task<Message> {
-        if self.connections.contains_key(&connection_id) {
+        if let Some(conn) = self.connections.get_mut(&connection_id) {
+            // Any reply to a chat or user message means the server has it
+            conn.unacked_messages
+                .retain(|unacked| unacked.message_id != message_id);
             self.handle_server_message(connection_id, message_id, msg, receive_timestamp)
         } else {
             Task::none()
@@ -92,25 +109,72 @@ impl NexusApp {
                 timestamp,
             ),
 
+            // Only acknowledges the send (handled on arrival); the message
+            // itself shows when it's broadcast
+            ServerMessage::ChatSendResponse { .. } => Task::none(),
+
             ServerMessage::ChatUpdated {
                 channel,
                 topic,
                 topic_set_by,
                 secret,
                 secret_set_by,
+                voice_limit,
+                voice_limit_set_by,
+                topic_locked,
+                topic_locked_set_by,
+                rules,
+                rules_set_by,
+                language,
+                language_set_by,
             } => self.handle_chat_updated(
                 connection_id,
                 channel,
-                topic,
-                topic_set_by,
-                secret,
-                secret_set_by,
+                ChatUpdatedData {
+                    topic,
+                    topic_set_by,
+                    secret,
+                    secret_set_by,
+                    voice_limit,
+                    voice_limit_set_by,
+                    topic_locked,
+                    topic_locked_set_by,
+                    rules,
+                    rules_set_by,
+                    language,
+                    language_set_by,
+                },
             ),
 
             ServerMessage::ChatTopicUpdateResponse { success, error } => {
                 self.handle_chat_topic_update_response(connection_id, success, error)
             }
 
+            ServerMessage::ChatEntryUpdateResponse { success, error } => {
+                self.handle_chat_entry_update_response(connection_id, message_id, success, error)
+            }
+
+            ServerMessage::ChatLanguageResponse { success, error } => {
+                self.handle_chat_language_response(connection_id, success, error)
+            }
+
+            ServerMessage::ChatTopicLockResponse { success, error } => {
+                self.handle_chat_topic_lock_response(connection_id, success, error)
+            }
+
+            ServerMessage::ChatTopicHistoryResponse {
+                success,
+                error,
+                channel,
+                changes,
+            } => self.handle_chat_topic_history_response(
+                connection_id,
+                success,
+                error,
+                channel,
+                changes,
+            ),
+
             ServerMessage::ChatJoinResponse {
                 success,
                 error,
@@ -118,10 +182,16 @@ impl NexusApp {
                 topic,
                 topic_set_by,
                 secret,
+                topic_locked,
                 members,
                 voiced,
+                voice_limit,
+                entry_message,
+                rules,
+                language,
             } => self.handle_chat_join_response(
                 connection_id,
+                message_id,
                 ChatJoinResponseData {
                     success,
                     error,
@@ -129,8 +199,13 @@ impl NexusApp {
                     topic,
                     topic_set_by,
                     secret,
+                    topic_locked,
                     members,
                     voiced,
+                    voice_limit,
+                    entry_message,
+                    rules,
+                    language,
                 },
             ),
 
@@ -164,9 +239,9 @@ impl NexusApp {
                 self.handle_chat_secret_response(connection_id, message_id, success, error)
             }
 
-            ServerMessage::Error { message, command } => {
-                self.handle_error(connection_id, message, command)
-            }
+            ServerMessage::Error {
+                message, command, ..
+            } => self.handle_error(connection_id, message, command),
 
             ServerMessage::PermissionsUpdated {
                 is_admin,
@@ -178,11 +253,28 @@ impl NexusApp {
                 session_id: _,
                 username,
                 message,
-            } => self.handle_server_broadcast(connection_id, username, message),
+                broadcast_id,
+            } => self.handle_server_broadcast(connection_id, username, message, broadcast_id),
 
-            ServerMessage::UserBroadcastResponse { success, error } => {
-                self.handle_user_broadcast_response(connection_id, success, error)
-            }
+            ServerMessage::UserBroadcastResponse {
+                success,
+                error,
+                broadcast_id: _,
+                delivered,
+                send_at,
+            } => self.handle_user_broadcast_response(
+                connection_id,
+                success,
+                error,
+                delivered,
+                send_at,
+            ),
+
+            ServerMessage::BroadcastStatus {
+                broadcast_id: _,
+                delivered,
+                acknowledged,
+            } => self.handle_broadcast_status(connection_id, delivered, acknowledged),
 
             ServerMessage::UserConnected { user } => {
                 self.handle_user_connected(connection_id, user)
@@ -210,6 +302,20 @@ impl NexusApp {
                 success,
                 error,
                 username,
+                false,
+            ),
+
+            ServerMessage::UserEraseResponse {
+                success,
+                error,
+                username,
+            } => self.handle_user_delete_response(
+                connection_id,
+                message_id,
+                success,
+                error,
+                username,
+                true,
             ),
 
             ServerMessage::UserDisconnected {
@@ -217,6 +323,38 @@ impl NexusApp {
                 nickname,
             } => self.handle_user_disconnected(connection_id, session_id, nickname),
 
+            ServerMessage::PermissionAuditResponse {
+                success,
+                error,
+                holders,
+                effective,
+            } => self.handle_permission_audit_response(
+                connection_id,
+                success,
+                error,
+                holders,
+                effective,
+            ),
+
+            ServerMessage::ImpersonateQueryResponse {
+                success,
+                error,
+                effective,
+                path,
+                entries,
+                channels,
+            } => self.handle_impersonate_query_response(
+                connection_id,
+                success,
+                error,
+                ImpersonateQueryResponseData {
+                    effective,
+                    path,
+                    entries,
+                    channels,
+                },
+            ),
+
             ServerMessage::UserEditResponse {
                 success,
                 error,
@@ -265,6 +403,7 @@ impl NexusApp {
                 message,
                 action,
                 timestamp,
+                id,
             } => self.handle_user_message(UserMessageParams {
                 connection_id,
                 from_nickname,
@@ -274,13 +413,64 @@ impl NexusApp {
                 message,
                 action,
                 timestamp,
+                id,
+            }),
+
+            ServerMessage::MessageDelivered { nickname, id } => {
+                self.handle_message_delivered(connection_id, nickname, id)
+            }
+
+            ServerMessage::MessageRead { nickname, id } => {
+                self.handle_message_read(connection_id, nickname, id)
+            }
+
+            ServerMessage::GroupMessage {
+                group_id,
+                from_nickname,
+                from_admin,
+                from_shared,
+                message,
+                action,
+                timestamp,
+            } => self.handle_group_message(GroupMessageParams {
+                connection_id,
+                group_id,
+                from_nickname,
+                from_admin,
+                from_shared,
+                message,
+                action,
+                timestamp,
             }),
 
+            ServerMessage::GroupMembers { group_id, members } => {
+                self.handle_group_members(connection_id, group_id, members)
+            }
+
+            ServerMessage::GroupMessageCreateResponse {
+                success,
+                error,
+                group_id,
+            } => self.handle_group_message_create_response(
+                connection_id,
+                message_id,
+                success,
+                error,
+                group_id,
+            ),
+
+            ServerMessage::GroupMessageSendResponse { success, error, .. }
+            | ServerMessage::GroupMessageInviteResponse { success, error }
+            | ServerMessage::GroupMessageLeaveResponse { success, error } => {
+                self.handle_group_response(connection_id, message_id, success, error)
+            }
+
             ServerMessage::UserMessageResponse {
                 success,
                 error,
                 is_away,
                 status,
+                ..
             } => self.handle_user_message_response(
                 connection_id,
                 message_id,
@@ -290,6 +480,29 @@ impl NexusApp {
                 status,
             ),
 
+            ServerMessage::MessageHistoryResponse {
+                success,
+                error,
+                messages,
+                retention_days,
+                ..
+            } => self.handle_message_history_response(
+                connection_id,
+                message_id,
+                success,
+                error,
+                messages,
+                retention_days,
+            ),
+
+            ServerMessage::MessageHistoryRetentionResponse {
+                success,
+                error,
+                days,
+            } => {
+                self.handle_message_history_retention_response(connection_id, success, error, days)
+            }
+
             ServerMessage::UserUpdated {
                 previous_username,
                 user,
@@ -315,6 +528,25 @@ impl NexusApp {
                 self.handle_server_info_update_response(connection_id, success, error)
             }
 
+            ServerMessage::NatStatusResponse {
+                success,
+                error: _,
+                nat,
+            } => self.handle_nat_status_response(connection_id, success, nat),
+
+            ServerMessage::ConnectivityCheckResponse {
+                success,
+                error,
+                external_ip,
+                ports,
+            } => self.handle_connectivity_check_response(
+                connection_id,
+                success,
+                error,
+                external_ip,
+                ports,
+            ),
+
             ServerMessage::NewsListResponse {
                 success,
                 error,
@@ -349,6 +581,24 @@ impl NexusApp {
                 self.handle_news_delete_response(connection_id, message_id, success, error, id)
             }
 
+            ServerMessage::NewsSearchResponse {
+                success,
+                error,
+                items,
+            } => self.handle_news_search_response(connection_id, message_id, success, error, items),
+
+            ServerMessage::SnippetCreateResponse {
+                success, error, id, ..
+            } => self.handle_snippet_create_response(connection_id, message_id, success, error, id),
+
+            ServerMessage::SnippetGetResponse {
+                success,
+                error,
+                snippet,
+            } => {
+                self.handle_snippet_get_response(connection_id, message_id, success, error, snippet)
+            }
+
             ServerMessage::NewsUpdated { action, id } => {
                 self.handle_news_updated(connection_id, action, id)
             }
@@ -359,6 +609,7 @@ impl NexusApp {
                 path,
                 entries,
                 can_upload,
+                upload_rule,
             } => self.handle_file_list_response(
                 connection_id,
                 message_id,
@@ -368,6 +619,7 @@ impl NexusApp {
                     path,
                     entries,
                     can_upload,
+                    upload_rule,
                 },
             ),
 
@@ -393,6 +645,32 @@ impl NexusApp {
                 info,
             } => self.handle_file_info_response(connection_id, message_id, success, error, info),
 
+            ServerMessage::FileManifestResponse {
+                success,
+                error,
+                path,
+                files,
+            } => self.handle_file_manifest_response(
+                connection_id,
+                message_id,
+                success,
+                error,
+                path,
+                files,
+            ),
+            ServerMessage::FileStatResponse {
+                success,
+                error: _,
+                files,
+            } => self.handle_file_stat_response(connection_id, message_id, success, files),
+
+            ServerMessage::FileChanged {
+                path, root, kind, ..
+            } => {
+                self.handle_file_changed(connection_id, path, root, kind);
+                Task::none()
+            }
+
             ServerMessage::FileRenameResponse { success, error } => {
                 self.handle_file_rename_response(connection_id, message_id, success, error)
             }
@@ -433,6 +711,10 @@ impl NexusApp {
                 self.handle_user_status_response(connection_id, message_id, success, error)
             }
 
+            ServerMessage::UserDndResponse { success, error } => {
+                self.handle_user_dnd_response(connection_id, success, error)
+            }
+
             ServerMessage::BanCreateResponse {
                 success,
                 error,
@@ -477,6 +759,24 @@ impl NexusApp {
                 self.handle_file_reindex_response(connection_id, success, error)
             }
 
+            ServerMessage::FileCleanupResponse {
+                success,
+                error,
+                report,
+            } => self.handle_file_cleanup_response(connection_id, success, error, report),
+
+            ServerMessage::FileCheckIssue {
+                kind,
+                path,
+                repaired,
+            } => self.handle_file_check_issue(connection_id, kind, path, repaired),
+
+            ServerMessage::FileCheckResponse {
+                success,
+                error,
+                summary,
+            } => self.handle_file_check_response(connection_id, success, error, summary),
+
             ServerMessage::ConnectionMonitorResponse {
                 success,
                 error,
@@ -490,6 +790,58 @@ impl NexusApp {
                 transfers,
             }),
 
+            ServerMessage::LogSubscribeResponse {
+                success,
+                error,
+                entries,
+            } => self.handle_log_subscribe_response(connection_id, success, error, entries),
+
+            ServerMessage::LogLine { entry } => self.handle_log_line(connection_id, entry),
+
+            ServerMessage::ServerStatsResponse {
+                success,
+                error,
+                stats,
+            } => self.handle_server_stats_response(connection_id, success, error, stats),
+
+            ServerMessage::StorageAlert {
+                volume,
+                low,
+                free,
+                threshold,
+                ..
+            } => self.handle_storage_alert(connection_id, volume, low, free, threshold),
+
+            ServerMessage::StatsResponse {
+                success,
+                error,
+                days,
+            } => self.handle_stats_response(connection_id, message_id, success, error, days),
+
+            ServerMessage::DoorListResponse {
+                success,
+                error,
+                doors,
+            } => self.handle_door_list_response(connection_id, success, error, doors),
+
+            ServerMessage::DoorOpenResponse { success, error } => {
+                self.handle_door_open_response(connection_id, success, error)
+            }
+
+            ServerMessage::DoorData { data } => self.handle_door_data(connection_id, data),
+
+            ServerMessage::DoorClosed { exit_code } => {
+                self.handle_door_closed(connection_id, exit_code)
+            }
+
+            ServerMessage::LeaderboardResponse {
+                success,
+                error,
+                entries,
+            } => {
+                self.handle_leaderboard_response(connection_id, message_id, success, error, entries)
+            }
+
             ServerMessage::FileSearchResponse {
                 success,
                 error,
@@ -498,11 +850,143 @@ impl NexusApp {
                 self.handle_file_search_response(connection_id, message_id, success, error, results)
             }
 
+            ServerMessage::FilePopularResponse {
+                success,
+                error,
+                files,
+            }
+            | ServerMessage::FileRecentResponse {
+                success,
+                error,
+                files,
+            } => self.handle_file_highlights_response(
+                connection_id,
+                message_id,
+                success,
+                error,
+                files,
+            ),
+
+            ServerMessage::FileRateResponse {
+                success,
+                error,
+                rating,
+                my_rating,
+            } => self.handle_file_rate_response(
+                connection_id,
+                message_id,
+                success,
+                error,
+                rating,
+                my_rating,
+            ),
+
+            ServerMessage::FileCommentCreateResponse {
+                success,
+                error,
+                comment,
+            } => self.handle_file_comment_create_response(
+                connection_id,
+                message_id,
+                success,
+                error,
+                comment,
+            ),
+
+            ServerMessage::FileCommentListResponse {
+                success,
+                error,
+                comments,
+            } => self.handle_file_comment_list_response(
+                connection_id,
+                message_id,
+                success,
+                error,
+                comments,
+            ),
+
+            ServerMessage::FileCommentDeleteResponse { success, error, id } => self
+                .handle_file_comment_delete_response(connection_id, message_id, success, error, id),
+
+            ServerMessage::UploadRuleGetResponse {
+                success,
+                error,
+                rule,
+            } => self.handle_upload_rule_get_response(
+                connection_id,
+                message_id,
+                success,
+                error,
+                rule,
+            ),
+
+            ServerMessage::UploadRuleSetResponse { success, error } => {
+                self.handle_upload_rule_set_response(connection_id, message_id, success, error)
+            }
+
+            ServerMessage::LdapGroupListResponse {
+                success,
+                error,
+                groups,
+            } => self.handle_ldap_group_list_response(connection_id, success, error, groups),
+
+            ServerMessage::LdapGroupSetResponse {
+                success,
+                error,
+                group,
+            } => self.handle_ldap_group_set_response(connection_id, success, error, group),
+
+            ServerMessage::LdapGroupDeleteResponse {
+                success,
+                error,
+                group,
+            } => self.handle_ldap_group_delete_response(connection_id, success, error, group),
+
+            ServerMessage::GuestUpgradeResponse {
+                success,
+                error,
+                username,
+            } => self.handle_guest_upgrade_response(connection_id, success, error, username),
+
+            ServerMessage::NicknameRegisterResponse {
+                success,
+                error,
+                nickname,
+                username,
+            } => self.handle_nickname_register_response(
+                connection_id,
+                success,
+                error,
+                nickname,
+                username,
+            ),
+
+            ServerMessage::NicknameReleaseResponse {
+                success,
+                error,
+                nickname,
+            } => self.handle_nickname_release_response(connection_id, success, error, nickname),
+
+            ServerMessage::PresenceSubscribeResponse {
+                success,
+                error,
+                users,
+            } => self.handle_presence_subscribe_response(connection_id, success, error, users),
+
+            ServerMessage::PresenceUpdate { user } => {
+                self.handle_presence_update(connection_id, user)
+            }
+
             ServerMessage::VoiceJoinResponse {
                 success,
                 token,
                 target,
                 participants,
+                voice_address,
+                voice_port,
+                tcp_relay,
+                listener,
+                listeners,
                 error,
             } => self.handle_voice_join_response(
                 connection_id,
@@ -510,6 +994,11 @@ impl NexusApp {
                 token,
                 target,
                 participants,
+                voice_address,
+                voice_port,
+                tcp_relay,
+                listener,
+                listeners,
                 error,
             ),
 
@@ -517,6 +1006,14 @@ impl NexusApp {
                 self.handle_voice_leave_response(connection_id, success, error)
             }
 
+            ServerMessage::VoiceQueueUpdate { target, position } => {
+                self.handle_voice_queue_update(connection_id, target, position)
+            }
+
+            ServerMessage::VoiceLimitResponse { success, error } => {
+                self.handle_voice_limit_response(connection_id, success, error)
+            }
+
             ServerMessage::VoiceUserJoined { nickname, target } => {
                 self.handle_voice_user_joined(connection_id, nickname, target)
             }
@@ -525,15 +1022,66 @@ impl NexusApp {
                 self.handle_voice_user_left(connection_id, nickname, target)
             }
 
+            ServerMessage::VoiceBroadcastUpdate {
+                target,
+                broadcaster,
+                listeners,
+            } => self.handle_voice_broadcast_update(connection_id, target, broadcaster, listeners),
+
+            ServerMessage::VoiceStats {
+                nickname,
+                loss_percent,
+                jitter_ms,
+            } => self.handle_voice_stats(connection_id, nickname, loss_percent, jitter_ms),
+
+            ServerMessage::VoiceRelay { data } => self.handle_voice_relay(connection_id, data),
+
+            ServerMessage::VoiceClipListResponse {
+                success,
+                error,
+                clips,
+            } => self.handle_voice_clip_list_response(connection_id, success, error, clips),
+
+            ServerMessage::VoiceClipUploadResponse { success, error } => {
+                self.handle_voice_clip_upload_response(connection_id, success, error)
+            }
+
+            ServerMessage::VoiceClipDeleteResponse { success, error } => {
+                self.handle_voice_clip_delete_response(connection_id, success, error)
+            }
+
+            ServerMessage::VoiceClipPlayResponse { success, error } => {
+                self.handle_voice_clip_play_response(connection_id, success, error)
+            }
+
+            ServerMessage::VoiceClipPlayed {
+                nickname,
+                target,
+                name,
+                data,
+            } => self.handle_voice_clip_played(connection_id, nickname, target, name, data),
+
+            ServerMessage::VoiceTextSendResponse { success, error } => {
+                self.handle_voice_text_send_response(connection_id, success, error)
+            }
+
+            ServerMessage::VoiceText {
+                nickname,
+                target,
+                message,
+            } => self.handle_voice_text(connection_id, nickname, target, message),
+
             // Keepalive response - check if this is a response to a /ping command
-            ServerMessage::Pong => self.handle_pong(connection_id, message_id, receive_timestamp),
+            ServerMessage::Pong { time } => {
+                self.handle_pong(connection_id, message_id, time, receive_timestamp)
+            }
 
             // Catch-all for any unhandled message types
             _ => Task::none(),
         }
     }
 
-    /// Handle pong response - check if this is a response to a /ping command
+    /// Handle pong response to a /ping command or a background health ping
     ///
     /// The `receive_timestamp` is captured in the network reader task (tokio-land)
     /// for accurate latency measurement, avoiding Iced's event loop delay.
@@ -541,24 +1089,36 @@ impl NexusApp {
         &mut self,
         connection_id: usize,
         message_id: MessageId,
+        time: Option<ServerTime>,
         receive_timestamp: Option<Instant>,
     ) -> Task<Message> {
         let Some(conn) = self.connections.get_mut(&connection_id) else {
             return Task::none();
         };
 
-        // Check if we have a pending ping from /ping command
-        let Some(ResponseRouting::PingResult(sent_time)) =
-            conn.pending_requests.remove(&message_id)
-        else {
-            // No pending ping - this is just a keepalive response
-            return Task::none();
-        };
+        // Every health ping refreshes the skew, so it follows clock changes
+        // on either side during the session
+        if let Some(time) = time {
+            conn.server_clock = ServerClock::new(time, chrono::Utc::now());
+        }
 
         // Calculate latency using receive timestamp from network task (accurate)
         // or fall back to current time (less accurate, includes Iced event loop delay)
         let receive_time = receive_timestamp.unwrap_or_else(Instant::now);
+
+        let sent_time = match conn.pending_requests.remove(&message_id) {
+            Some(ResponseRouting::PingResult(sent_time)) => sent_time,
+            Some(ResponseRouting::HealthPing(sent_time)) => {
+                conn.health.outstanding = None;
+                conn.health.record(receive_time.duration_since(sent_time));
+                return Task::none();
+            }
+            // No pending ping - this is just a keepalive response
+            _ => return Task::none(),
+        };
+
         let elapsed = receive_time.duration_since(sent_time);
+        conn.health.record(elapsed);
         let ms = elapsed.as_millis();
 
         // Display result in chat
@@ -3,15 +3,18 @@
 use iced::Task;
 use iced::widget::markdown;
 use nexus_common::framing::MessageId;
-use nexus_common::protocol::{NewsAction, NewsItem};
+use nexus_common::protocol::{NewsAction, NewsAttachment, NewsItem};
 
 use crate::NexusApp;
 use crate::config::events::EventType;
+use crate::drafts::DraftContext;
 use crate::events::{EventContext, emit_event};
 use crate::i18n::t;
 use crate::image::decode_data_uri_max_width;
 use crate::style::NEWS_IMAGE_MAX_CACHE_WIDTH;
-use crate::types::{ChatMessage, Message, NewsManagementMode, PendingRequests, ResponseRouting};
+use crate::types::{
+    ChatMessage, Message, NewsManagementMode, PendingRequests, ResponseRouting, ServerConnection,
+};
 
 impl NexusApp {
     /// Handle news list response
@@ -36,21 +39,7 @@ impl NexusApp {
             if let Some(items) = items {
                 // Cache images and markdown for all items
                 for item in &items {
-                    // Cache image if present
-                    if let Some(image_data) = &item.image
-                        && let Some(cached) =
-                            decode_data_uri_max_width(image_data, NEWS_IMAGE_MAX_CACHE_WIDTH)
-                    {
-                        conn.news_image_cache.insert(item.id, cached);
-                    }
-
-                    // Cache parsed markdown if body is present
-                    if let Some(body) = &item.body
-                        && !body.is_empty()
-                    {
-                        let parsed: Vec<markdown::Item> = markdown::parse(body).collect();
-                        conn.news_markdown_cache.insert(item.id, parsed);
-                    }
+                    cache_news_item(conn, item);
                 }
 
                 // If from news panel, populate the list
@@ -68,6 +57,45 @@ impl NexusApp {
         Task::none()
     }
 
+    /// Handle news search response
+    ///
+    /// Shows the results in place of the news list, unless a newer search
+    /// or clearing the search made them stale.
+    pub fn handle_news_search_response(
+        &mut self,
+        connection_id: usize,
+        message_id: MessageId,
+        success: bool,
+        error: Option<String>,
+        items: Option<Vec<NewsItem>>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+
+        // Only handle if this was a tracked news search request
+        let routing = conn.pending_requests.remove(&message_id);
+        if !matches!(routing, Some(ResponseRouting::NewsSearchResult))
+            || conn.news_management.current_search_request != Some(message_id)
+        {
+            return Task::none();
+        }
+        conn.news_management.current_search_request = None;
+
+        if success {
+            // Treat None as empty results (defensive against malformed server response)
+            let items = items.unwrap_or_default();
+            for item in &items {
+                cache_news_item(conn, item);
+            }
+            conn.news_management.search_results = Some(Ok(items));
+        } else {
+            conn.news_management.search_results = Some(Err(error.unwrap_or_default()));
+        }
+
+        Task::none()
+    }
+
     /// Handle news show response
     ///
     /// Used for refreshing a single news item after NewsUpdated broadcast.
@@ -119,6 +147,13 @@ impl NexusApp {
                         return Task::none();
                     };
 
+                    // Update the item in search results (new posts aren't known to match)
+                    if let Some(Ok(results)) = &mut conn.news_management.search_results
+                        && let Some(existing) = results.iter_mut().find(|i| i.id == item.id)
+                    {
+                        *existing = item.clone();
+                    }
+
                     // Update or add the item in the list
                     if let Some(Ok(items)) = &mut conn.news_management.news_items {
                         let mut found = false;
@@ -136,25 +171,8 @@ impl NexusApp {
                         }
                     }
 
-                    // Update image cache
-                    if let Some(image_data) = &item.image
-                        && let Some(cached) =
-                            decode_data_uri_max_width(image_data, NEWS_IMAGE_MAX_CACHE_WIDTH)
-                    {
-                        conn.news_image_cache.insert(item.id, cached);
-                    } else {
-                        conn.news_image_cache.remove(&item.id);
-                    }
-
-                    // Update markdown cache
-                    if let Some(body) = &item.body
-                        && !body.is_empty()
-                    {
-                        let parsed: Vec<markdown::Item> = markdown::parse(body).collect();
-                        conn.news_markdown_cache.insert(item.id, parsed);
-                    } else {
-                        conn.news_markdown_cache.remove(&item.id);
-                    }
+                    // Update image and markdown caches
+                    cache_news_item(conn, &item);
                 }
             }
         } else {
@@ -193,21 +211,8 @@ impl NexusApp {
                 // Add the new item to the list if we got it back
                 if let Some(item) = news {
                     if let Some(conn) = self.connections.get_mut(&connection_id) {
-                        // Cache image if present
-                        if let Some(image_data) = &item.image
-                            && let Some(cached) =
-                                decode_data_uri_max_width(image_data, NEWS_IMAGE_MAX_CACHE_WIDTH)
-                        {
-                            conn.news_image_cache.insert(item.id, cached);
-                        }
-
-                        // Cache parsed markdown if body is present
-                        if let Some(body) = &item.body
-                            && !body.is_empty()
-                        {
-                            let parsed: Vec<markdown::Item> = markdown::parse(body).collect();
-                            conn.news_markdown_cache.insert(item.id, parsed);
-                        }
+                        // Cache images and markdown
+                        cache_news_item(conn, &item);
 
                         // Add to list
                         if let Some(Ok(items)) = &mut conn.news_management.news_items {
@@ -267,11 +272,18 @@ impl NexusApp {
             if let Some(item) = news {
                 // If from news panel, populate the edit form
                 if matches!(routing, Some(ResponseRouting::PopulateNewsEdit)) {
-                    // Set up the image in form state
-                    conn.news_management.enter_edit_mode(item.id, item.image);
+                    // Set up the attachments in form state
+                    conn.news_management
+                        .enter_edit_mode(item.id, item.attachments);
+
+                    // Prefer an unsent draft of this edit over the saved body
+                    let body = conn
+                        .drafts
+                        .remove(&DraftContext::News(Some(item.id)))
+                        .or(item.body);
 
                     // Initialize the text editor content with the body and focus it
-                    return self.init_news_edit_content(connection_id, item.body);
+                    return self.init_news_edit_content(connection_id, body);
                 }
             }
         } else {
@@ -316,29 +328,11 @@ impl NexusApp {
             if matches!(routing, Some(ResponseRouting::NewsUpdateResult)) {
                 if let Some(item) = news {
                     if let Some(conn) = self.connections.get_mut(&connection_id) {
-                        // Update image cache
-                        if let Some(image_data) = &item.image {
-                            if let Some(cached) =
-                                decode_data_uri_max_width(image_data, NEWS_IMAGE_MAX_CACHE_WIDTH)
-                            {
-                                conn.news_image_cache.insert(item.id, cached);
-                            }
-                        } else {
-                            conn.news_image_cache.remove(&item.id);
-                        }
-
-                        // Update markdown cache
-                        if let Some(body) = &item.body
-                            && !body.is_empty()
-                        {
-                            let parsed: Vec<markdown::Item> = markdown::parse(body).collect();
-                            conn.news_markdown_cache.insert(item.id, parsed);
-                        } else {
-                            conn.news_markdown_cache.remove(&item.id);
-                        }
+                        // Update image and markdown caches
+                        cache_news_item(conn, &item);
 
-                        // Update in list
-                        if let Some(Ok(items)) = &mut conn.news_management.news_items {
+                        // Update in list and search results
+                        for items in conn.news_management.loaded_items_mut() {
                             for existing in items.iter_mut() {
                                 if existing.id == item.id {
                                     *existing = item.clone();
@@ -410,15 +404,12 @@ impl NexusApp {
                 conn.news_management.delete_error = None;
 
                 // Remove from list
-                if let Some(Ok(items)) = &mut conn.news_management.news_items {
+                for items in conn.news_management.loaded_items_mut() {
                     items.retain(|item| item.id != deleted_id);
                 }
 
-                // Remove from image cache
-                conn.news_image_cache.remove(&deleted_id);
-
-                // Remove from markdown cache
-                conn.news_markdown_cache.remove(&deleted_id);
+                // Remove from image and markdown caches
+                uncache_news_item(conn, deleted_id);
             }
 
             return task;
@@ -494,18 +485,45 @@ impl NexusApp {
             }
             NewsAction::Deleted => {
                 // Remove from list
-                if let Some(Ok(items)) = &mut conn.news_management.news_items {
+                for items in conn.news_management.loaded_items_mut() {
                     items.retain(|item| item.id != id);
                 }
 
-                // Remove from image cache
-                conn.news_image_cache.remove(&id);
-
-                // Remove from markdown cache
-                conn.news_markdown_cache.remove(&id);
+                // Remove from image and markdown caches
+                uncache_news_item(conn, id);
             }
         }
 
         Task::none()
     }
 }
+
+/// Cache a news item's images and parsed markdown for rendering
+///
+/// Replaces anything cached for the item before, so an edited post doesn't
+/// keep images it no longer has.
+fn cache_news_item(conn: &mut ServerConnection, item: &NewsItem) {
+    uncache_news_item(conn, item.id);
+
+    for (index, attachment) in item.attachments.iter().enumerate() {
+        if let NewsAttachment::Image { data, .. } = attachment
+            && let Some(cached) = decode_data_uri_max_width(data, NEWS_IMAGE_MAX_CACHE_WIDTH)
+        {
+            conn.news_image_cache.insert((item.id, index), cached);
+        }
+    }
+
+    if let Some(body) = &item.body
+        && !body.is_empty()
+    {
+        let parsed: Vec<markdown::Item> = markdown::parse(body).collect();
+        conn.news_markdown_cache.insert(item.id, parsed);
+    }
+}
+
+/// Drop a news item's cached images and markdown
+fn uncache_news_item(conn: &mut ServerConnection, id: i64) {
+    conn.news_image_cache
+        .retain(|&(news_id, _), _| news_id != id);
+    conn.news_markdown_cache.remove(&id);
+}
@@ -0,0 +1,53 @@
+//! Nickname registration response handlers
+
+use iced::Task;
+
+use crate::NexusApp;
+use crate::i18n::t_args;
+use crate::types::{ChatMessage, Message};
+
+impl NexusApp {
+    /// Handle nickname register response
+    pub fn handle_nickname_register_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+        nickname: Option<String>,
+        username: Option<String>,
+    ) -> Task<Message> {
+        let message = if success {
+            ChatMessage::info(t_args(
+                "msg-nickname-registered",
+                &[
+                    ("nickname", &nickname.unwrap_or_default()),
+                    ("username", &username.unwrap_or_default()),
+                ],
+            ))
+        } else {
+            ChatMessage::error(error.unwrap_or_default())
+        };
+
+        self.add_active_tab_message(connection_id, message)
+    }
+
+    /// Handle nickname release response
+    pub fn handle_nickname_release_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+        nickname: Option<String>,
+    ) -> Task<Message> {
+        let message = if success {
+            ChatMessage::info(t_args(
+                "msg-nickname-released",
+                &[("nickname", &nickname.unwrap_or_default())],
+            ))
+        } else {
+            ChatMessage::error(error.unwrap_or_default())
+        };
+
+        self.add_active_tab_message(connection_id, message)
+    }
+}
@@ -73,6 +73,15 @@ impl NexusApp {
             if info.max_transfers_per_ip.is_some() {
                 conn.max_transfers_per_ip = info.max_transfers_per_ip;
             }
+            if info.max_active_transfers.is_some() {
+                conn.max_active_transfers = info.max_active_transfers;
+            }
+            if info.transfer_small_files_first.is_some() {
+                conn.transfer_small_files_first = info.transfer_small_files_first;
+            }
+            if info.trusted_transfer_weight.is_some() {
+                conn.trusted_transfer_weight = info.trusted_transfer_weight;
+            }
             if info.file_reindex_interval.is_some() {
                 conn.file_reindex_interval = info.file_reindex_interval;
             }
@@ -0,0 +1,68 @@
+//! Friends presence handlers
+
+use iced::Task;
+use nexus_common::protocol::PresenceInfo;
+
+use crate::NexusApp;
+use crate::config::events::EventType;
+use crate::events::{EventContext, emit_event};
+use crate::types::{ChatMessage, Message};
+
+impl NexusApp {
+    /// Handle presence subscribe response (current presence of every friend)
+    pub fn handle_presence_subscribe_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+        users: Option<Vec<PresenceInfo>>,
+    ) -> Task<Message> {
+        if !success {
+            return self
+                .add_console_message(connection_id, ChatMessage::error(error.unwrap_or_default()));
+        }
+
+        if let Some(conn) = self.connections.get_mut(&connection_id) {
+            conn.friends = users.unwrap_or_default();
+        }
+        Task::none()
+    }
+
+    /// Handle a friend coming online, going offline, or changing status
+    ///
+    /// Only coming online notifies; the Friends section shows the rest.
+    pub fn handle_presence_update(
+        &mut self,
+        connection_id: usize,
+        user: PresenceInfo,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+
+        let username_lower = user.username.to_lowercase();
+        let Some(friend) = conn
+            .friends
+            .iter_mut()
+            .find(|friend| friend.username.to_lowercase() == username_lower)
+        else {
+            // Removed from the list while the update was on its way
+            return Task::none();
+        };
+
+        let came_online = user.online && !friend.online;
+        let username = user.username.clone();
+        *friend = user;
+
+        if came_online {
+            emit_event(
+                self,
+                EventType::FriendOnline,
+                EventContext::new()
+                    .with_connection_id(connection_id)
+                    .with_username(username),
+            );
+        }
+        Task::none()
+    }
+}
@@ -1,13 +1,13 @@
 //! Server info message handlers
 
 use iced::Task;
-use nexus_common::protocol::ServerInfo;
+use nexus_common::protocol::{NatInfo, PortCheck, ServerInfo};
 
 use crate::NexusApp;
 use crate::i18n::{t, t_args};
 use crate::image::decode_data_uri_max_width;
 use crate::style::SERVER_IMAGE_MAX_CACHE_WIDTH;
-use crate::types::{ChatMessage, Message};
+use crate::types::{ChatMessage, ConnectivityCheckState, Message, ServerClock};
 
 impl NexusApp {
     /// Handle server info updated notification
@@ -41,6 +41,16 @@ impl NexusApp {
         if server_info.max_transfers_per_ip.is_some() {
             conn.max_transfers_per_ip = server_info.max_transfers_per_ip;
         }
+        // Transfer queue settings are only sent to admins
+        if server_info.max_active_transfers.is_some() {
+            conn.max_active_transfers = server_info.max_active_transfers;
+        }
+        if server_info.transfer_small_files_first.is_some() {
+            conn.transfer_small_files_first = server_info.transfer_small_files_first;
+        }
+        if server_info.trusted_transfer_weight.is_some() {
+            conn.trusted_transfer_weight = server_info.trusted_transfer_weight;
+        }
         // file_reindex_interval is only sent to admins
         if server_info.file_reindex_interval.is_some() {
             conn.file_reindex_interval = server_info.file_reindex_interval;
@@ -53,6 +63,19 @@ impl NexusApp {
         if server_info.auto_join_channels.is_some() {
             conn.auto_join_channels = server_info.auto_join_channels;
         }
+        // NAT status is only sent to admins
+        if server_info.nat.is_some() {
+            conn.nat = server_info.nat;
+        }
+        if server_info.news_image_limits.is_some() {
+            conn.news_image_limits = server_info.news_image_limits;
+        }
+        if server_info.max_message_length.is_some() {
+            conn.max_message_length = server_info.max_message_length;
+        }
+        if let Some(time) = server_info.time {
+            conn.server_clock = ServerClock::new(time, chrono::Utc::now());
+        }
         // Update server image and cached version if provided
         if let Some(image) = server_info.image {
             // Decode first using reference, then move (avoids clone)
@@ -102,4 +125,46 @@ impl NexusApp {
             }
         }
     }
+
+    /// Handle NAT status response (requested when an admin opens the panel)
+    ///
+    /// Failures are ignored: the panel keeps showing the status from login.
+    pub fn handle_nat_status_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        nat: Option<NatInfo>,
+    ) -> Task<Message> {
+        if success
+            && let Some(conn) = self.connections.get_mut(&connection_id)
+            && nat.is_some()
+        {
+            conn.nat = nat;
+        }
+        Task::none()
+    }
+
+    /// Handle connectivity check response (shown in the server info panel)
+    pub fn handle_connectivity_check_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+        external_ip: Option<String>,
+        ports: Option<Vec<PortCheck>>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+
+        conn.connectivity_check = if success {
+            ConnectivityCheckState::Done {
+                external_ip,
+                ports: ports.unwrap_or_default(),
+            }
+        } else {
+            ConnectivityCheckState::Failed(error.unwrap_or_default())
+        };
+        Task::none()
+    }
 }
@@ -0,0 +1,60 @@
+//! Server log response handlers
+
+use iced::Task;
+use nexus_common::protocol::{ClientMessage, LogEntry};
+
+use crate::NexusApp;
+use crate::i18n::t;
+use crate::types::{ActivePanel, Message};
+
+impl NexusApp {
+    /// Handle LogSubscribeResponse (recent log history)
+    pub fn handle_log_subscribe_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+        entries: Option<Vec<LogEntry>>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+
+        let state = &mut conn.server_log;
+        state.loading = false;
+        if success {
+            state.entries.clear();
+            for entry in entries.unwrap_or_default() {
+                state.push(entry);
+            }
+            state.subscribed = true;
+            state.error = None;
+        } else {
+            state.subscribed = false;
+            state.error = Some(error.unwrap_or_else(|| t("err-unknown")));
+        }
+
+        Task::none()
+    }
+
+    /// Handle a streamed server log line
+    ///
+    /// If the panel was left without unsubscribing (e.g., another panel was
+    /// opened directly), stop the stream now.
+    pub fn handle_log_line(&mut self, connection_id: usize, entry: LogEntry) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+
+        if conn.active_panel != ActivePanel::ServerLog {
+            if conn.server_log.subscribed {
+                conn.server_log.subscribed = false;
+                let _ = conn.send(ClientMessage::LogUnsubscribe);
+            }
+            return Task::none();
+        }
+
+        conn.server_log.push(entry);
+        Task::none()
+    }
+}
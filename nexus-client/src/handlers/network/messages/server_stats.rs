@@ -0,0 +1,36 @@
+//! Server stats response handler
+
+use chrono::Local;
+use iced::Task;
+use nexus_common::protocol::ServerStatsInfo;
+
+use crate::NexusApp;
+use crate::i18n::t;
+use crate::types::Message;
+
+impl NexusApp {
+    /// Handle ServerStatsResponse (fills the admin dashboard)
+    pub fn handle_server_stats_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+        stats: Option<ServerStatsInfo>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+
+        let state = &mut conn.admin_dashboard;
+        state.loading = false;
+        if success {
+            state.stats = stats;
+            state.error = None;
+            state.updated_at = Some(Local::now());
+        } else {
+            state.error = Some(error.unwrap_or_else(|| t("err-unknown")));
+        }
+
+        Task::none()
+    }
+}
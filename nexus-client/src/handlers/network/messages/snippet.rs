@@ -0,0 +1,80 @@
+//! Snippet response handlers
+
+use iced::Task;
+use nexus_common::framing::MessageId;
+use nexus_common::protocol::{ChatAction, SnippetInfo};
+
+use crate::NexusApp;
+use crate::i18n::t_args;
+use crate::types::{ChatMessage, Message, ResponseRouting};
+use crate::uri::snippet_link;
+
+impl NexusApp {
+    /// Handle response to sharing a snippet
+    ///
+    /// Posts the snippet's link to the tab the text was pasted into. If that
+    /// tab's connection is down, the link waits in the send queue.
+    pub fn handle_snippet_create_response(
+        &mut self,
+        connection_id: usize,
+        message_id: MessageId,
+        success: bool,
+        error: Option<String>,
+        id: Option<String>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+        let Some(ResponseRouting::PostSnippetLink(tab)) = conn.pending_requests.remove(&message_id)
+        else {
+            return Task::none();
+        };
+
+        match id {
+            Some(id) if success => {
+                let link = snippet_link(&conn.connection_info, &id);
+                self.send_or_queue_chat_message(connection_id, tab, link, ChatAction::Normal);
+                Task::none()
+            }
+            _ => {
+                let error_msg = t_args(
+                    "err-failed-share-snippet",
+                    &[("error", &error.unwrap_or_default())],
+                );
+                self.add_active_tab_message(connection_id, ChatMessage::error(error_msg))
+            }
+        }
+    }
+
+    /// Handle a snippet for the Snippet panel
+    ///
+    /// Ignored unless the panel is still waiting for this snippet.
+    pub fn handle_snippet_get_response(
+        &mut self,
+        connection_id: usize,
+        message_id: MessageId,
+        success: bool,
+        error: Option<String>,
+        snippet: Option<SnippetInfo>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+        let Some(ResponseRouting::ShowSnippet(id)) = conn.pending_requests.remove(&message_id)
+        else {
+            return Task::none();
+        };
+        if !conn.snippet_view.loading || conn.snippet_view.id != id {
+            return Task::none();
+        }
+
+        match snippet {
+            Some(snippet) if success => conn.snippet_view.set_snippet(snippet),
+            _ => {
+                conn.snippet_view.loading = false;
+                conn.snippet_view.error = Some(error.unwrap_or_default());
+            }
+        }
+        Task::none()
+    }
+}
@@ -0,0 +1,48 @@
+//! Statistics response handler
+
+use iced::Task;
+use nexus_common::framing::MessageId;
+use nexus_common::protocol::DailyStats;
+
+use crate::NexusApp;
+use crate::i18n::t;
+use crate::types::{Message, ResponseRouting};
+
+impl NexusApp {
+    /// Handle StatsResponse
+    ///
+    /// Fills the admin dashboard's today figures when it asked, otherwise the
+    /// Statistics panel.
+    pub fn handle_stats_response(
+        &mut self,
+        connection_id: usize,
+        message_id: MessageId,
+        success: bool,
+        error: Option<String>,
+        days: Option<Vec<DailyStats>>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+
+        if let Some(ResponseRouting::AdminDashboardToday) =
+            conn.pending_requests.remove(&message_id)
+        {
+            if success {
+                conn.admin_dashboard.today = days.and_then(|days| days.into_iter().last());
+            }
+            return Task::none();
+        }
+
+        let state = &mut conn.statistics;
+        state.loading = false;
+        if success {
+            state.days = days.unwrap_or_default();
+            state.error = None;
+        } else {
+            state.error = Some(error.unwrap_or_else(|| t("err-unknown")));
+        }
+
+        Task::none()
+    }
+}
@@ -0,0 +1,44 @@
+//! Storage alert handler
+
+use iced::Task;
+use nexus_common::protocol::StorageVolume;
+
+use crate::NexusApp;
+use crate::handlers::network::helpers::format_bytes;
+use crate::i18n::{t, t_args};
+use crate::types::{ChatMessage, Message};
+
+impl NexusApp {
+    /// Handle StorageAlert (sent to admins when free space crosses the server's minimum)
+    pub fn handle_storage_alert(
+        &mut self,
+        connection_id: usize,
+        volume: StorageVolume,
+        low: bool,
+        free: u64,
+        threshold: u64,
+    ) -> Task<Message> {
+        let volume = match volume {
+            StorageVolume::Files => t("storage-volume-files"),
+            StorageVolume::Database => t("storage-volume-database"),
+        };
+
+        let message = if low {
+            ChatMessage::error(t_args(
+                "msg-storage-low",
+                &[
+                    ("volume", &volume),
+                    ("free", &format_bytes(free)),
+                    ("threshold", &format_bytes(threshold)),
+                ],
+            ))
+        } else {
+            ChatMessage::system(t_args(
+                "msg-storage-recovered",
+                &[("volume", &volume), ("free", &format_bytes(free))],
+            ))
+        };
+
+        self.add_console_message(connection_id, message)
+    }
+}
@@ -3,11 +3,23 @@
 use iced::Task;
 use iced::widget::{Id, operation};
 use nexus_common::framing::MessageId;
+use nexus_common::protocol::{ChannelInfo, EffectivePermissions, FileEntry, PermissionHolder};
 
 use crate::NexusApp;
 use crate::i18n::{t, t_args};
 use crate::types::InputId;
-use crate::types::{ActivePanel, ChatMessage, Message, ResponseRouting, UserManagementMode};
+use crate::types::{
+    ActivePanel, ChatMessage, Impersonation, Message, PermissionAuditResult, ResponseRouting,
+    UserManagementMode,
+};
+
+/// Data from an ImpersonateQueryResponse message
+pub struct ImpersonateQueryResponseData {
+    pub effective: Option<EffectivePermissions>,
+    pub path: Option<String>,
+    pub entries: Option<Vec<FileEntry>>,
+    pub channels: Option<Vec<ChannelInfo>>,
+}
 
 /// Data from a UserEditResponse message
 pub struct UserEditResponseData {
@@ -78,6 +90,7 @@ impl NexusApp {
     ///
     /// If tracked via ResponseRouting::UserManagementDeleteResult, closes the delete
     /// dialog on success and refreshes the user list, or shows error in dialog on failure.
+    /// `erased` is set for UserEraseResponse, which is handled the same way.
     pub fn handle_user_delete_response(
         &mut self,
         connection_id: usize,
@@ -85,6 +98,7 @@ impl NexusApp {
         success: bool,
         error: Option<String>,
         username: Option<String>,
+        erased: bool,
     ) -> Task<Message> {
         let Some(conn) = self.connections.get_mut(&connection_id) else {
             return Task::none();
@@ -95,10 +109,11 @@ impl NexusApp {
 
         if success {
             // Show success message in chat with username if available
-            let message = if let Some(ref name) = username {
-                t_args("msg-user-deleted-name", &[("username", name)])
-            } else {
-                t("msg-user-deleted")
+            let message = match (&username, erased) {
+                (Some(name), false) => t_args("msg-user-deleted-name", &[("username", name)]),
+                (None, false) => t("msg-user-deleted"),
+                (Some(name), true) => t_args("msg-user-erased-name", &[("username", name)]),
+                (None, true) => t("msg-user-erased"),
             };
             let task = self.add_active_tab_message(connection_id, ChatMessage::system(message));
 
@@ -287,6 +302,70 @@ impl NexusApp {
         self.refresh_user_management_list_for(connection_id)
     }
 
+    /// Handle permission audit response
+    ///
+    /// Shows the holders or effective permissions in the audit view. Ignored
+    /// if the user has left the audit view in the meantime.
+    pub fn handle_permission_audit_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+        holders: Option<Vec<PermissionHolder>>,
+        effective: Option<EffectivePermissions>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+        if conn.user_management.mode != UserManagementMode::PermissionAudit {
+            return Task::none();
+        }
+
+        conn.user_management.audit = Some(if !success {
+            Err(error.unwrap_or_default())
+        } else if let Some(effective) = effective {
+            Ok(PermissionAuditResult::Effective(effective))
+        } else {
+            Ok(PermissionAuditResult::Holders(holders.unwrap_or_default()))
+        });
+
+        Task::none()
+    }
+
+    /// Handle impersonation query response
+    ///
+    /// Shows what the user would see in the impersonation view. Ignored if
+    /// the admin has left that view in the meantime.
+    pub fn handle_impersonate_query_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+        response: ImpersonateQueryResponseData,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+        if !matches!(
+            conn.user_management.mode,
+            UserManagementMode::Impersonate { .. }
+        ) {
+            return Task::none();
+        }
+
+        conn.user_management.impersonation = Some(match (success, response.effective) {
+            (true, Some(effective)) => Ok(Impersonation {
+                effective,
+                path: response.path,
+                entries: response.entries,
+                channels: response.channels,
+            }),
+            _ => Err(error.unwrap_or_default()),
+        });
+
+        Task::none()
+    }
+
     /// Refresh user management list for a specific connection
     fn refresh_user_management_list_for(&mut self, connection_id: usize) -> Task<Message> {
         let Some(conn) = self.connections.get_mut(&connection_id) else {
@@ -55,9 +55,11 @@ impl NexusApp {
                     get_or_create_avatar(&mut conn.avatar_cache, &nickname, user.avatar.as_deref());
                 }
 
-                // Update away/status (latest login wins)
+                // Update away/status/dnd (latest login wins)
                 existing_user.is_away = user.is_away;
                 existing_user.status = user.status.clone();
+                existing_user.is_dnd = user.is_dnd;
+                existing_user.last_active = existing_user.last_active.max(user.last_active);
 
                 false
             } else {
@@ -71,6 +73,9 @@ impl NexusApp {
                     avatar_hash: new_avatar_hash,
                     is_away: user.is_away,
                     status: user.status.clone(),
+                    is_dnd: user.is_dnd,
+                    login_time: user.login_time,
+                    last_active: user.last_active,
                 });
                 sort_user_list(&mut conn.online_users);
 
@@ -100,9 +105,11 @@ impl NexusApp {
                     get_or_create_avatar(&mut conn.avatar_cache, &nickname, user.avatar.as_deref());
                 }
 
-                // Update away/status (latest login wins)
+                // Update away/status/dnd (latest login wins)
                 existing_user.is_away = user.is_away;
                 existing_user.status = user.status.clone();
+                existing_user.is_dnd = user.is_dnd;
+                existing_user.last_active = existing_user.last_active.max(user.last_active);
 
                 false
             } else {
@@ -116,6 +123,9 @@ impl NexusApp {
                     avatar_hash: new_avatar_hash,
                     is_away: user.is_away,
                     status: user.status.clone(),
+                    is_dnd: user.is_dnd,
+                    login_time: user.login_time,
+                    last_active: user.last_active,
                 });
                 sort_user_list(&mut conn.online_users);
 
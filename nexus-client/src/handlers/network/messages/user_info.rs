@@ -7,8 +7,9 @@ use nexus_common::protocol::{UserInfo as ProtocolUserInfo, UserInfoDetailed};
 
 use crate::NexusApp;
 use crate::avatar::{avatar_cache_key, compute_avatar_hash, get_or_create_avatar};
+use crate::drafts::DraftContext;
 use crate::handlers::network::constants::DATETIME_FORMAT;
-use crate::handlers::network::helpers::{format_duration, sort_user_list};
+use crate::handlers::network::helpers::{format_bytes, format_duration, sort_user_list};
 use crate::i18n::{t, t_args};
 use crate::types::{
     ActivePanel, ChatMessage, ChatTab, Message, ResponseRouting, UserInfo as ClientUserInfo,
@@ -104,12 +105,16 @@ impl NexusApp {
         // Build multi-line IRC WHOIS-style output
         let mut lines = Vec::new();
 
-        // Header: display name with 💤 if away (nickname is always populated - equals username for regular accounts)
+        // Header: display name with 💤 if away and 🔕 if not to be disturbed
+        // (nickname is always populated - equals username for regular accounts)
+        let mut header = user.nickname.clone();
         if user.is_away {
-            lines.push(format!("[{} 💤]", user.nickname));
-        } else {
-            lines.push(format!("[{}]", user.nickname));
+            header.push_str(" 💤");
+        }
+        if user.is_dnd {
+            header.push_str(" 🔕");
         }
+        lines.push(format!("[{}]", header));
 
         // Role (only visible to admins)
         if let Some(is_admin) = user.is_admin {
@@ -146,6 +151,18 @@ impl NexusApp {
             connected_value
         ));
 
+        // Idle time since the last chat or user message (once it's been at least a minute)
+        if let Some(last_active) = user.last_active {
+            let idle_secs = now.saturating_sub(last_active);
+            if idle_secs >= 60 {
+                lines.push(format!(
+                    "{INFO_INDENT}{} {}",
+                    t("user-info-idle").to_lowercase(),
+                    format_duration(idle_secs as u64)
+                ));
+            }
+        }
+
         // Status (if set) - away is shown via 💤 in header
         if let Some(status) = &user.status {
             lines.push(format!(
@@ -177,6 +194,15 @@ impl NexusApp {
             user.locale
         ));
 
+        // Client software (if the client sent its name)
+        if let Some(client) = &user.client {
+            lines.push(format!(
+                "{INFO_INDENT}{} {}",
+                t("user-info-client").to_lowercase(),
+                client
+            ));
+        }
+
         // IP Addresses (only visible to admins)
         if let Some(addresses) = user.addresses
             && !addresses.is_empty()
@@ -209,6 +235,38 @@ impl NexusApp {
             ));
         }
 
+        // Rank earned from activity (if the server defines ranks)
+        if let Some(rank) = &user.rank {
+            lines.push(format!(
+                "{INFO_INDENT}{} {}",
+                t("user-info-rank").to_lowercase(),
+                rank
+            ));
+        }
+
+        // Lifetime activity counters (regular accounts only)
+        if let Some(stats) = &user.stats {
+            let counters = [
+                ("user-info-logins", stats.logins.to_string()),
+                ("user-info-messages", stats.messages.to_string()),
+                ("user-info-uploads", stats.uploads.to_string()),
+                ("user-info-downloaded", format_bytes(stats.download_bytes)),
+            ];
+            for (key, value) in counters {
+                lines.push(format!("{INFO_INDENT}{} {}", t(key).to_lowercase(), value));
+            }
+            if let Some(last_login) = stats
+                .last_login_at
+                .and_then(|at| chrono::DateTime::from_timestamp(at, 0))
+            {
+                lines.push(format!(
+                    "{INFO_INDENT}{} {}",
+                    t("user-info-last-login").to_lowercase(),
+                    last_login.format(DATETIME_FORMAT)
+                ));
+            }
+        }
+
         // Account created (last field)
         lines.push(format!(
             "{INFO_INDENT}{} {}",
@@ -292,6 +350,9 @@ impl NexusApp {
                     avatar_hash,
                     is_away: u.is_away,
                     status: u.status,
+                    is_dnd: u.is_dnd,
+                    login_time: u.login_time,
+                    last_active: u.last_active,
                 }
             })
             .collect();
@@ -390,6 +451,10 @@ impl NexusApp {
             existing_user.avatar_hash = new_avatar_hash;
             existing_user.is_away = user.is_away;
             existing_user.status = user.status.clone();
+            existing_user.is_dnd = user.is_dnd;
+            if user.last_active.is_some() {
+                existing_user.last_active = user.last_active;
+            }
 
             // Get new nickname for cache update
             let new_nickname = existing_user.nickname.clone();
@@ -444,7 +509,23 @@ impl NexusApp {
                 conn.unread_tabs.insert(new_tab.clone());
             }
 
-            // Update active_chat_tab if it's for this user
+            // Move an unsent draft and queued messages to the new tab
+            if let Some(draft) = conn.drafts.remove(&DraftContext::Chat(old_tab.clone())) {
+                conn.drafts
+                    .insert(DraftContext::Chat(new_tab.clone()), draft);
+            }
+            for queued in conn.send_queue.iter_mut().filter(|q| q.tab == old_tab) {
+                queued.tab = new_tab.clone();
+            }
+
+            // Follow the rename in the tab's window if it's popped out
+            for chat_window in self.chat_windows.values_mut() {
+                if chat_window.connection_id == connection_id && chat_window.tab == old_tab {
+                    chat_window.tab = new_tab.clone();
+                }
+            }
+
+            // Update active_chat_tab if it's for this user (keeping the input)
             if conn.active_chat_tab == old_tab {
                 conn.active_chat_tab = new_tab;
             }
@@ -3,13 +3,13 @@
 use chrono::{Local, TimeZone};
 use iced::Task;
 use nexus_common::framing::MessageId;
-use nexus_common::protocol::{ChatAction, ServerMessage};
+use nexus_common::protocol::{ChatAction, ClientMessage, ServerMessage};
 
 use crate::NexusApp;
 use crate::config::events::EventType;
 use crate::events::{EventContext, emit_event};
 use crate::i18n::t_args;
-use crate::types::{ChatMessage, ChatTab, Message, ResponseRouting};
+use crate::types::{ChatMessage, ChatTab, DeliveryState, Message, ResponseRouting};
 
 /// Parameters for handling an incoming user message
 pub struct UserMessageParams {
@@ -21,6 +21,7 @@ pub struct UserMessageParams {
     pub message: String,
     pub action: ChatAction,
     pub timestamp: u64,
+    pub id: Option<u64>,
 }
 
 impl NexusApp {
@@ -35,10 +36,11 @@ impl NexusApp {
             message,
             action,
             timestamp,
+            id,
         } = params;
 
         // First pass: get info we need for notification and history (immutable borrow)
-        let (should_notify, other_nickname) = {
+        let (should_notify, other_nickname, muted, is_from_self) = {
             let Some(conn) = self.connections.get(&connection_id) else {
                 return Task::none();
             };
@@ -59,11 +61,14 @@ impl NexusApp {
                 from_nickname.clone()
             };
 
-            (should_notify, other_nickname)
+            let muted = self
+                .is_chat_tab_muted(connection_id, &ChatTab::UserMessage(other_nickname.clone()));
+
+            (should_notify, other_nickname, muted, is_from_self)
         };
 
-        // Emit notification event (only for messages from others)
-        if should_notify {
+        // Emit notification event (only for messages from others, in unmuted tabs)
+        if should_notify && !muted {
             emit_event(
                 self,
                 EventType::UserMessage,
@@ -86,6 +91,7 @@ impl NexusApp {
                 message: message.clone(),
                 action,
                 timestamp,
+                id,
             };
             // Silently ignore save failures - history is non-critical
             let _ = history_manager.add_message(&other_nickname, server_msg);
@@ -106,7 +112,8 @@ impl NexusApp {
         } else {
             Local::now()
         };
-        let chat_msg = ChatMessage::with_timestamp_and_status(
+        conn.mark_user_active(&from_nickname, datetime.timestamp());
+        let mut chat_msg = ChatMessage::with_timestamp_and_status(
             from_nickname,
             message,
             datetime,
@@ -114,6 +121,10 @@ impl NexusApp {
             from_shared,
             action,
         );
+        chat_msg.message_id = id;
+        if is_from_self && id.is_some() {
+            chat_msg.delivery = Some(DeliveryState::Sent);
+        }
         conn.user_messages
             .entry(other_nickname.clone())
             .or_default()
@@ -124,18 +135,126 @@ impl NexusApp {
             conn.user_message_tabs.push(other_nickname.clone());
         }
 
-        // Mark as unread if not currently viewing this tab
-        let pm_tab = ChatTab::UserMessage(other_nickname);
-        if conn.active_chat_tab != pm_tab {
+        // Mark as unread if not currently viewing this tab (muted tabs stay read)
+        let pm_tab = ChatTab::UserMessage(other_nickname.clone());
+        if conn.active_chat_tab == pm_tab {
+            if !is_from_self {
+                self.send_read_receipt(connection_id, &other_nickname);
+            }
+            self.scroll_chat_if_visible(true)
+        } else if muted {
+            Task::none()
+        } else {
             conn.unread_tabs.insert(pm_tab);
 
             // Update tray icon state (Windows/Linux only)
             #[cfg(not(target_os = "macos"))]
             self.update_tray_state();
 
+            // Update dock badge count (macOS only)
+            #[cfg(target_os = "macos")]
+            self.update_dock_badge();
+
             Task::none()
-        } else {
-            self.scroll_chat_if_visible(true)
+        }
+    }
+
+    /// Handle delivery receipt for a user message we sent
+    pub fn handle_message_delivered(
+        &mut self,
+        connection_id: usize,
+        nickname: String,
+        id: u64,
+    ) -> Task<Message> {
+        self.update_delivery(
+            connection_id,
+            &nickname,
+            DeliveryState::Delivered,
+            |message_id| message_id == id,
+        );
+        Task::none()
+    }
+
+    /// Handle read receipt for the user messages we sent, up to an ID
+    pub fn handle_message_read(
+        &mut self,
+        connection_id: usize,
+        nickname: String,
+        id: u64,
+    ) -> Task<Message> {
+        self.update_delivery(
+            connection_id,
+            &nickname,
+            DeliveryState::Read,
+            |message_id| message_id <= id,
+        );
+        Task::none()
+    }
+
+    /// Move our matching messages in a conversation forward to a receipt state
+    ///
+    /// Receipts never move a message backwards (a late delivery receipt leaves
+    /// a read message read).
+    fn update_delivery(
+        &mut self,
+        connection_id: usize,
+        nickname: &str,
+        state: DeliveryState,
+        matches: impl Fn(u64) -> bool,
+    ) {
+        let Some(messages) = self
+            .connections
+            .get_mut(&connection_id)
+            .and_then(|conn| conn.user_messages.get_mut(nickname))
+        else {
+            return;
+        };
+        for msg in messages.iter_mut() {
+            if let (Some(message_id), Some(delivery)) = (msg.message_id, msg.delivery.as_mut())
+                && matches(message_id)
+                && *delivery < state
+            {
+                *delivery = state;
+            }
+        }
+    }
+
+    /// Tell the other party of a user message tab how far we've read
+    ///
+    /// Does nothing when read receipts are off or nothing new arrived since
+    /// the last receipt.
+    pub fn send_read_receipt(&mut self, connection_id: usize, nickname: &str) {
+        if !self.config.settings.send_read_receipts {
+            return;
+        }
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return;
+        };
+
+        // Newest message from them (our own messages carry a delivery state)
+        let Some(newest) = conn.user_messages.get(nickname).and_then(|messages| {
+            messages
+                .iter()
+                .rev()
+                .filter(|msg| msg.delivery.is_none())
+                .find_map(|msg| msg.message_id)
+        }) else {
+            return;
+        };
+        if conn
+            .read_receipts_sent
+            .get(nickname)
+            .is_some_and(|&sent| sent >= newest)
+        {
+            return;
+        }
+
+        let receipt = ClientMessage::MessageMarkRead {
+            nickname: nickname.to_string(),
+            id: newest,
+        };
+        if conn.send(receipt).is_ok() {
+            conn.read_receipts_sent.insert(nickname.to_string(), newest);
         }
     }
 
@@ -1,4 +1,4 @@
-//! Away/back/status response handlers
+//! Away/back/status/dnd response handlers
 
 use iced::Task;
 use nexus_common::framing::MessageId;
@@ -22,6 +22,11 @@ impl NexusApp {
             .get_mut(&connection_id)
             .and_then(|conn| conn.pending_requests.remove(&message_id));
 
+        // Automatic away after idle time happens silently
+        if matches!(routing, Some(ResponseRouting::AutoAwayResult)) {
+            return Task::none();
+        }
+
         if success {
             // Check if we had a status message from the tracked request
             let msg = match routing {
@@ -45,9 +50,14 @@ impl NexusApp {
         success: bool,
         error: Option<String>,
     ) -> Task<Message> {
-        // Remove tracking (we don't need the data, just cleanup)
-        if let Some(conn) = self.connections.get_mut(&connection_id) {
-            conn.pending_requests.remove(&message_id);
+        let routing = self
+            .connections
+            .get_mut(&connection_id)
+            .and_then(|conn| conn.pending_requests.remove(&message_id));
+
+        // Coming back automatically after activity happens silently
+        if matches!(routing, Some(ResponseRouting::AutoBackResult)) {
+            return Task::none();
         }
 
         if success {
@@ -86,4 +96,20 @@ impl NexusApp {
             self.add_active_tab_message(connection_id, ChatMessage::error(error_msg))
         }
     }
+
+    /// Handle response to UserDnd request
+    ///
+    /// Do not disturb is synced in the background, so only failures are shown.
+    pub fn handle_user_dnd_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+    ) -> Task<Message> {
+        if success {
+            return Task::none();
+        }
+        let error_msg = error.unwrap_or_default();
+        self.add_active_tab_message(connection_id, ChatMessage::error(error_msg))
+    }
 }
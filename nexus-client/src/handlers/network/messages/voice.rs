@@ -3,28 +3,54 @@
 //! Handles server messages for voice chat:
 //! - VoiceJoinResponse - Response to VoiceJoin request
 //! - VoiceLeaveResponse - Response to VoiceLeave request
+//! - VoiceQueueUpdate - Position in a full channel's voice join queue
+//! - VoiceLimitResponse - Response to /voicelimit
 //! - VoiceUserJoined - Notification when another user joins voice
 //! - VoiceUserLeft - Notification when another user leaves voice
+//! - VoiceBroadcastUpdate - A channel's broadcast started, ended, or changed listener count
+//! - VoiceStats - Connection statistics for a voice participant
+//! - VoiceRelay - Voice packet relayed over the BBS connection (TCP relay fallback)
+//! - VoiceClipListResponse - Response to /clip list
+//! - VoiceClipUploadResponse - Response to /clip add
+//! - VoiceClipDeleteResponse - Response to /clip delete
+//! - VoiceClipPlayResponse - Response to /clip play
+//! - VoiceClipPlayed - A soundboard clip was played in our voice session
+//! - VoiceTextSendResponse - Response to /vt
+//! - VoiceText - A participant sent a text message to our voice session
 
 use std::net::ToSocketAddrs;
+use std::time::Instant;
 
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
 use iced::Task;
+use nexus_common::protocol::VoiceClipInfo;
 use uuid::Uuid;
 
 use crate::NexusApp;
 use crate::config::events::EventType;
 use crate::events::{EventContext, emit_event};
 use crate::i18n::{t, t_args};
-use crate::types::{ChatMessage, Message, VoiceState};
+use crate::types::{ChatMessage, Message, VoiceParticipantStats, VoiceState, VoiceText};
 use crate::voice::manager::{VoiceSessionConfig, VoiceSessionHandle};
 
 use crate::voice::subscription::register_voice_receiver_sync;
 
+use super::chat::contains_word;
+
 impl NexusApp {
     /// Handle response to VoiceJoin request
     ///
     /// On success: Create voice session with token and participants
     /// On error: Show error in active tab
+    ///
+    /// Voice traffic goes to the advertised voice address/port if the server
+    /// sent one, otherwise to the BBS address/port. If the server offered TCP
+    /// relay, the session falls back to it when the DTLS connection fails.
+    ///
+    /// Listeners of a broadcast get a listen-only session: no microphone and
+    /// no push-to-talk.
+    #[allow(clippy::too_many_arguments)]
     pub fn handle_voice_join_response(
         &mut self,
         connection_id: usize,
@@ -32,6 +58,11 @@ impl NexusApp {
         token: Option<Uuid>,
         target: Option<String>,
         participants: Option<Vec<String>>,
+        voice_address: Option<String>,
+        voice_port: Option<u16>,
+        tcp_relay: Option<bool>,
+        listener: Option<bool>,
+        listeners: Option<u32>,
         error: Option<String>,
     ) -> Task<Message> {
         if !success {
@@ -88,17 +119,26 @@ impl NexusApp {
 
         // Create the voice session
         let participants = participants.unwrap_or_default();
-        conn.voice_session = Some(VoiceState::new(target.clone(), participants));
+        let listen_only = listener == Some(true);
+        let mut session = VoiceState::new(target.clone(), participants);
+        session.listener = listen_only;
+        session.listeners = listeners;
+        conn.voice_session = Some(session);
 
         // Track that this connection has the active voice session
         self.active_voice_connection = Some(connection_id);
 
         // Start the voice DTLS client
-        // Resolve server address to SocketAddr
-        let server_addr = format!(
-            "{}:{}",
-            conn.connection_info.address, conn.connection_info.port
-        );
+        // Resolve voice address to SocketAddr (defaults to the BBS address and port)
+        let voice_host = voice_address.unwrap_or_else(|| conn.connection_info.address.clone());
+        let voice_port = voice_port.unwrap_or(conn.connection_info.port);
+        let server_addr = if voice_host.contains(':') && !voice_host.starts_with('[') {
+            // Bare IPv6 address
+            format!("[{}]:{}", voice_host, voice_port)
+        } else {
+            format!("{}:{}", voice_host, voice_port)
+        };
+        let relay_tx = (tcp_relay == Some(true)).then(|| conn.tx.clone());
         let socket_addr = match server_addr.to_socket_addrs() {
             Ok(mut addrs) => match addrs.next() {
                 Some(addr) => addr,
@@ -121,6 +161,7 @@ impl NexusApp {
         let (handle, event_rx) = VoiceSessionHandle::start(VoiceSessionConfig {
             server_addr: socket_addr,
             token,
+            tcp_relay: relay_tx,
             input_device: self.config.settings.audio.input_device.clone(),
             output_device: self.config.settings.audio.output_device.clone(),
             quality: self.config.settings.audio.voice_quality,
@@ -134,6 +175,7 @@ impl NexusApp {
             },
             ptt_mode: self.config.settings.audio.ptt_mode,
             mic_level: self.mic_level.clone(),
+            listen_only,
         });
 
         // Store the handle
@@ -143,6 +185,14 @@ impl NexusApp {
         // Must be synchronous to avoid race with subscription starting
         register_voice_receiver_sync(connection_id, event_rx);
 
+        // Listeners never transmit, so push-to-talk stays off
+        if listen_only {
+            #[cfg(not(target_os = "macos"))]
+            self.update_tray_state();
+
+            return Task::none();
+        }
+
         // Lazily create PTT manager on first voice join (not at startup).
         // This ensures the native event loop is active when the hotkey system initializes.
         if self.ptt_manager.is_none() {
@@ -157,13 +207,16 @@ impl NexusApp {
             }
         }
 
-        // Register PTT hotkey and enable it for voice
+        // Register PTT hotkey and enable it for voice. Portal shortcuts are
+        // bound by their subscription once PTT is enabled.
+        let use_portal = self.portal_shortcuts_active();
         if let Some(ref mut ptt) = self.ptt_manager {
             // Set mode from settings
             ptt.set_mode(self.config.settings.audio.ptt_mode);
 
             // Register the hotkey and show error if it fails
-            if let Err(e) = ptt.register_hotkey(&self.config.settings.audio.ptt_key) {
+            if !use_portal && let Err(e) = ptt.register_hotkey(&self.config.settings.audio.ptt_key)
+            {
                 // PTT won't work, but voice chat still functions
                 ptt.set_in_voice(true);
                 return self.add_active_tab_message(
@@ -195,10 +248,12 @@ impl NexusApp {
         error: Option<String>,
     ) -> Task<Message> {
         // Get the target before cleanup (for event emission)
+        // Leaving the join queue is not leaving voice, so it gets no event
         let target = self
             .connections
             .get(&connection_id)
             .and_then(|conn| conn.voice_session.as_ref())
+            .filter(|session| session.queue_position.is_none())
             .map(|session| session.target.clone());
 
         // Clear local voice state regardless of success
@@ -229,6 +284,79 @@ impl NexusApp {
         Task::none()
     }
 
+    /// Handle VoiceQueueUpdate - our position in a full channel's voice join queue
+    ///
+    /// The placeholder voice session created when joining is kept while we wait,
+    /// with the queue position shown in the voice bar. The VoiceJoinResponse
+    /// arrives once a slot frees up. A position of None means the server dropped
+    /// us from the queue (e.g., voice permission was revoked).
+    pub fn handle_voice_queue_update(
+        &mut self,
+        connection_id: usize,
+        target: String,
+        position: Option<u32>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+
+        let Some(position) = position else {
+            let was_queued = conn
+                .voice_session
+                .as_ref()
+                .is_some_and(|session| session.queue_position.is_some());
+            if !was_queued {
+                return Task::none();
+            }
+            conn.voice_session = None;
+            return self.add_channel_message(
+                connection_id,
+                &target,
+                ChatMessage::info(t("msg-voice-queue-cancelled")),
+            );
+        };
+
+        let session = conn
+            .voice_session
+            .get_or_insert_with(|| VoiceState::new(target.clone(), Vec::new()));
+        let first_update = session.queue_position.is_none();
+        session.queue_position = Some(position);
+
+        // Announce once; later position changes only update the voice bar
+        if !first_update {
+            return Task::none();
+        }
+        self.add_channel_message(
+            connection_id,
+            &target,
+            ChatMessage::info(t_args(
+                "msg-voice-queued",
+                &[("position", &position.to_string())],
+            )),
+        )
+    }
+
+    /// Handle response to VoiceLimit request (/voicelimit)
+    ///
+    /// The new limit itself arrives via ChatUpdated, which is broadcast to all
+    /// channel members including us.
+    pub fn handle_voice_limit_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+    ) -> Task<Message> {
+        if success {
+            return Task::none();
+        }
+
+        let error_msg = error.unwrap_or_else(|| t("err-unknown"));
+        self.add_active_tab_message(
+            connection_id,
+            ChatMessage::error(t_args("err-voice-limit", &[("error", &error_msg)])),
+        )
+    }
+
     /// Handle VoiceUserJoined - notification when another user joins voice
     ///
     /// Adds the user to our local participants list if we're in the same voice session.
@@ -381,4 +509,337 @@ impl NexusApp {
 
         Task::none()
     }
+
+    /// Handle VoiceBroadcastUpdate - a channel's broadcast started, ended, or
+    /// its listener count changed
+    ///
+    /// The channel remembers its broadcaster so the start and end are announced
+    /// once; count changes only update the voice bar of a session in the channel.
+    pub fn handle_voice_broadcast_update(
+        &mut self,
+        connection_id: usize,
+        target: String,
+        broadcaster: Option<String>,
+        listeners: u32,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+
+        if let Some(ref mut session) = conn.voice_session
+            && session.queue_position.is_none()
+            && session.target.to_lowercase() == target.to_lowercase()
+        {
+            session.listeners = broadcaster.as_ref().map(|_| listeners);
+        }
+
+        let Some(channel) = conn.channels.get_mut(&target.to_lowercase()) else {
+            return Task::none();
+        };
+        if channel.voice_broadcaster == broadcaster {
+            return Task::none();
+        }
+        channel.voice_broadcaster = broadcaster.clone();
+
+        let message = match broadcaster {
+            Some(nickname) => ChatMessage::info(t_args(
+                "msg-voice-broadcast-started",
+                &[("nickname", &nickname)],
+            )),
+            None => ChatMessage::info(t("msg-voice-broadcast-ended")),
+        };
+        self.add_channel_message(connection_id, &target, message)
+    }
+
+    /// Handle VoiceStats - connection statistics for a voice participant
+    ///
+    /// Stores the statistics for the participant's quality indicator. When they
+    /// describe our own audio, they are also passed to the voice session so the
+    /// encoder can adapt its bitrate.
+    pub fn handle_voice_stats(
+        &mut self,
+        connection_id: usize,
+        nickname: String,
+        loss_percent: u8,
+        jitter_ms: u32,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+
+        // Ignore stale reports that arrive while queued or after leaving
+        let Some(ref mut session) = conn.voice_session else {
+            return Task::none();
+        };
+        if session.queue_position.is_some() {
+            return Task::none();
+        }
+
+        session.set_stats(
+            &nickname,
+            VoiceParticipantStats {
+                loss_percent,
+                jitter_ms,
+            },
+        );
+
+        let is_self = conn.nickname.to_lowercase() == nickname.to_lowercase();
+        if is_self
+            && self.active_voice_connection == Some(connection_id)
+            && let Some(ref handle) = self.voice_session_handle
+        {
+            handle.report_network_stats(loss_percent, jitter_ms);
+        }
+
+        Task::none()
+    }
+
+    /// Handle a voice packet relayed over the BBS connection
+    ///
+    /// Only arrives after our voice session fell back to TCP relay; the packet
+    /// is passed on to the voice session for decoding and playback.
+    pub fn handle_voice_relay(&mut self, connection_id: usize, data: String) -> Task<Message> {
+        if self.active_voice_connection == Some(connection_id)
+            && let Some(ref handle) = self.voice_session_handle
+        {
+            handle.relay_received(data);
+        }
+
+        Task::none()
+    }
+
+    /// Handle response to VoiceClipList request (/clip list)
+    pub fn handle_voice_clip_list_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+        clips: Option<Vec<VoiceClipInfo>>,
+    ) -> Task<Message> {
+        if !success {
+            let error_msg = error.unwrap_or_else(|| t("err-unknown"));
+            return self.add_active_tab_message(
+                connection_id,
+                ChatMessage::error(t_args("err-voice-clip", &[("error", &error_msg)])),
+            );
+        }
+
+        let clips = clips.unwrap_or_default();
+        if clips.is_empty() {
+            return self.add_active_tab_message(
+                connection_id,
+                ChatMessage::info(t("msg-voice-clip-none")),
+            );
+        }
+
+        let mut tasks = vec![self.add_active_tab_message(
+            connection_id,
+            ChatMessage::info(t_args(
+                "msg-voice-clip-list",
+                &[("count", &clips.len().to_string())],
+            )),
+        )];
+        for clip in clips {
+            let seconds = format!("{:.1}", clip.duration_ms as f64 / 1000.0);
+            tasks.push(self.add_active_tab_message(
+                connection_id,
+                ChatMessage::info(t_args(
+                    "msg-voice-clip-entry",
+                    &[
+                        ("name", &clip.name),
+                        ("seconds", &seconds),
+                        ("nickname", &clip.uploaded_by),
+                    ],
+                )),
+            ));
+        }
+
+        Task::batch(tasks)
+    }
+
+    /// Handle response to VoiceClipUpload request (/clip add)
+    pub fn handle_voice_clip_upload_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+    ) -> Task<Message> {
+        let message = if success {
+            ChatMessage::info(t("msg-voice-clip-uploaded"))
+        } else {
+            let error_msg = error.unwrap_or_else(|| t("err-unknown"));
+            ChatMessage::error(t_args("err-voice-clip", &[("error", &error_msg)]))
+        };
+        self.add_active_tab_message(connection_id, message)
+    }
+
+    /// Handle response to VoiceClipDelete request (/clip delete)
+    pub fn handle_voice_clip_delete_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+    ) -> Task<Message> {
+        let message = if success {
+            ChatMessage::info(t("msg-voice-clip-deleted"))
+        } else {
+            let error_msg = error.unwrap_or_else(|| t("err-unknown"));
+            ChatMessage::error(t_args("err-voice-clip", &[("error", &error_msg)]))
+        };
+        self.add_active_tab_message(connection_id, message)
+    }
+
+    /// Handle response to VoiceClipPlay request (/clip play)
+    ///
+    /// On success the clip itself arrives via VoiceClipPlayed, which is sent
+    /// to every participant including us.
+    pub fn handle_voice_clip_play_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+    ) -> Task<Message> {
+        if success {
+            return Task::none();
+        }
+
+        let error_msg = error.unwrap_or_else(|| t("err-unknown"));
+        self.add_active_tab_message(
+            connection_id,
+            ChatMessage::error(t_args("err-voice-clip", &[("error", &error_msg)])),
+        )
+    }
+
+    /// Handle VoiceClipPlayed - a participant played a soundboard clip
+    ///
+    /// Plays the clip at the configured clip volume on the voice output device,
+    /// unless we're deafened or have muted the user who played it. A notice is
+    /// shown in the target tab either way.
+    pub fn handle_voice_clip_played(
+        &mut self,
+        connection_id: usize,
+        nickname: String,
+        target: String,
+        name: String,
+        data: String,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get(&connection_id) else {
+            return Task::none();
+        };
+
+        let muted = conn
+            .voice_session
+            .as_ref()
+            .is_some_and(|session| session.is_muted(&nickname));
+
+        if !muted
+            && !self.is_deafened
+            && let Some(audio) = data
+                .split_once(";base64,")
+                .and_then(|(_, encoded)| BASE64.decode(encoded).ok())
+        {
+            crate::sound::play_clip_on_device(
+                audio,
+                self.config.settings.audio.clip_volume,
+                &self.config.settings.audio.output_device,
+            );
+        }
+
+        let message = ChatMessage::system(t_args(
+            "msg-voice-clip-played",
+            &[("nickname", &nickname), ("name", &name)],
+        ));
+
+        // Route to channel or user message tab based on target
+        if target.starts_with('#') {
+            self.add_channel_message(connection_id, &target, message)
+        } else {
+            self.add_user_message(connection_id, &target, message)
+        }
+    }
+
+    /// Handle response to VoiceTextSend request (/vt)
+    ///
+    /// On success the text itself arrives via VoiceText, which is sent to
+    /// every participant including us.
+    pub fn handle_voice_text_send_response(
+        &mut self,
+        connection_id: usize,
+        success: bool,
+        error: Option<String>,
+    ) -> Task<Message> {
+        if success {
+            return Task::none();
+        }
+
+        let error_msg = error.unwrap_or_else(|| t("err-unknown"));
+        self.add_active_tab_message(
+            connection_id,
+            ChatMessage::error(t_args("err-voice-text", &[("error", &error_msg)])),
+        )
+    }
+
+    /// Handle VoiceText - a participant sent a text message to our voice session
+    ///
+    /// Shows the text in the voice overlay and as a notice in the target tab.
+    /// Texts from muted users are ignored. If the text names us, the overlay
+    /// bubble is highlighted and a ChatMention event fires so the configured
+    /// sound and notification apply. Texts from others are read aloud when
+    /// text-to-speech is enabled and we're not deafened.
+    pub fn handle_voice_text(
+        &mut self,
+        connection_id: usize,
+        nickname: String,
+        target: String,
+        message: String,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+        let Some(session) = conn.voice_session.as_mut() else {
+            return Task::none();
+        };
+        if session.is_muted(&nickname) {
+            return Task::none();
+        }
+
+        let our_nickname_lower = conn.nickname.to_lowercase();
+        let is_from_self = nickname.to_lowercase() == our_nickname_lower;
+        let is_mention =
+            !is_from_self && contains_word(&message.to_lowercase(), &our_nickname_lower);
+
+        session.add_text(VoiceText {
+            nickname: nickname.clone(),
+            message: message.clone(),
+            mention: is_mention,
+            received_at: Instant::now(),
+        });
+
+        if is_mention {
+            let mut context = EventContext::new()
+                .with_connection_id(connection_id)
+                .with_username(&nickname)
+                .with_message(&message);
+            if target.starts_with('#') {
+                context = context.with_channel(&target);
+            }
+            emit_event(self, EventType::ChatMention, context);
+        }
+
+        if !is_from_self && !self.is_deafened && self.config.settings.audio.voice_text_tts {
+            crate::tts::speak(&format!("{nickname}: {message}"));
+        }
+
+        let notice = ChatMessage::system(t_args(
+            "msg-voice-text",
+            &[("nickname", &nickname), ("message", &message)],
+        ));
+
+        // Route to channel or user message tab based on target
+        if target.starts_with('#') {
+            self.add_channel_message(connection_id, &target, notice)
+        } else {
+            self.add_user_message(connection_id, &target, notice)
+        }
+    }
 }
@@ -5,11 +5,13 @@
 //! - Message routing
 //! - Server message handling
 //! - Certificate fingerprint verification
+//! - Request timeouts
 
 mod chat;
 mod connection;
 mod fingerprint;
 mod messages;
+mod timeouts;
 
 pub mod constants;
 pub mod helpers;
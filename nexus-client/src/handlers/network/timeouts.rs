@@ -0,0 +1,205 @@
+//! Request timeout handling
+//!
+//! A request the server never answers (a server bug, a dropped frame) would
+//! otherwise leave its routing tracked and its panel loading forever. Once a
+//! request has waited [`REQUEST_TIMEOUT_SECS`], it's given up on: whatever
+//! was waiting for it shows an error, and a late response is ignored like any
+//! other untracked one.
+
+use std::time::{Duration, Instant};
+
+use iced::Task;
+use nexus_common::framing::MessageId;
+
+use crate::NexusApp;
+use crate::constants::REQUEST_TIMEOUT_SECS;
+use crate::i18n::t;
+use crate::types::{ActivePanel, ChatMessage, Message, PendingRequests, ResponseRouting};
+
+impl NexusApp {
+    /// Give up on every connection's requests that waited too long
+    pub fn handle_request_timeout_tick(&mut self) -> Task<Message> {
+        let now = Instant::now();
+        let timeout = Duration::from_secs(REQUEST_TIMEOUT_SECS);
+
+        // Servers without idempotency keys don't answer chat messages
+        for conn in self.connections.values_mut() {
+            conn.unacked_messages
+                .retain(|unacked| now.saturating_duration_since(unacked.sent) < timeout);
+        }
+
+        let timed_out: Vec<(usize, MessageId, ResponseRouting)> = self
+            .connections
+            .iter_mut()
+            .flat_map(|(&connection_id, conn)| {
+                conn.pending_requests
+                    .take_timed_out(now, timeout)
+                    .into_iter()
+                    .map(move |(message_id, routing)| (connection_id, message_id, routing))
+            })
+            .collect();
+
+        let tasks: Vec<Task<Message>> = timed_out
+            .into_iter()
+            .map(|(connection_id, message_id, routing)| {
+                self.handle_request_timeout(connection_id, message_id, routing)
+            })
+            .collect();
+        Task::batch(tasks)
+    }
+
+    /// Show that a request timed out where its response would have shown
+    fn handle_request_timeout(
+        &mut self,
+        connection_id: usize,
+        message_id: MessageId,
+        routing: ResponseRouting,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+        let error = t("err-request-timeout");
+
+        match routing {
+            // Background requests are retried or superseded on their own
+            ResponseRouting::AutoAwayResult
+            | ResponseRouting::AutoBackResult
+            | ResponseRouting::HealthPing(_)
+            | ResponseRouting::NewsShowForRefresh { .. }
+            | ResponseRouting::RefreshFileList { .. }
+            | ResponseRouting::AutoDownloadList { .. }
+            | ResponseRouting::AdminDashboardToday
+            | ResponseRouting::MessageHistorySync
+            | ResponseRouting::RejoinChannel { .. } => {}
+
+            // Lookups whose failure is already handled: answer as if they failed
+            ResponseRouting::FileStatResult { .. }
+            | ResponseRouting::DownloadCheckStat { .. }
+            | ResponseRouting::AutoDownloadStat { .. } => {
+                conn.pending_requests.track(message_id, routing);
+                return self.handle_file_stat_response(connection_id, message_id, false, None);
+            }
+
+            ResponseRouting::PopulateUserInfoPanel(_) => {
+                if conn.active_panel == ActivePanel::UserInfo && conn.user_info_data.is_none() {
+                    conn.user_info_data = Some(Err(error));
+                }
+            }
+            ResponseRouting::PopulateUserManagementList => {
+                conn.user_management.all_users = Some(Err(error));
+            }
+            ResponseRouting::PopulateUserManagementEdit => {
+                conn.user_management.list_error = Some(error);
+            }
+            ResponseRouting::PopulateNewsList => {
+                conn.news_management.news_items = Some(Err(error));
+            }
+            ResponseRouting::PopulateNewsEdit => {
+                conn.news_management.list_error = Some(error);
+            }
+            ResponseRouting::NewsSearchResult => {
+                // Only the current search is waited on
+                if conn.news_management.current_search_request == Some(message_id) {
+                    conn.news_management.current_search_request = None;
+                    conn.news_management.search_results = Some(Err(error));
+                }
+            }
+
+            ResponseRouting::ShowSnippet(id) => {
+                if conn.snippet_view.loading && conn.snippet_view.id == id {
+                    conn.snippet_view.loading = false;
+                    conn.snippet_view.error = Some(error);
+                }
+            }
+
+            ResponseRouting::PopulateFileList { tab_id, .. }
+            | ResponseRouting::FileInfoResult { tab_id } => {
+                if let Some(tab) = conn.files_management.tab_by_id_mut(tab_id) {
+                    tab.error = Some(error);
+                }
+            }
+            ResponseRouting::FileCreateDirResult { tab_id } => {
+                if let Some(tab) = conn.files_management.tab_by_id_mut(tab_id) {
+                    tab.new_directory_error = Some(error);
+                }
+            }
+            ResponseRouting::FileDeleteResult { tab_id } => {
+                if let Some(tab) = conn.files_management.tab_by_id_mut(tab_id) {
+                    tab.delete_error = Some(error);
+                }
+            }
+            ResponseRouting::FileRenameResult { tab_id } => {
+                if let Some(tab) = conn.files_management.tab_by_id_mut(tab_id) {
+                    tab.rename_error = Some(error);
+                }
+            }
+            ResponseRouting::FileReviewResult { tab_id, path } => {
+                if let Some(review) = conn
+                    .files_management
+                    .tab_by_id_mut(tab_id)
+                    .and_then(|tab| tab.review.as_mut())
+                    .filter(|review| review.path == path)
+                {
+                    review.error = Some(error);
+                }
+            }
+            ResponseRouting::FileUploadRuleResult { tab_id, path } => {
+                if let Some(pending) = conn
+                    .files_management
+                    .tab_by_id_mut(tab_id)
+                    .and_then(|tab| tab.pending_upload_rule.as_mut())
+                    .filter(|pending| pending.path == path)
+                {
+                    pending.loading = false;
+                    pending.error = Some(error);
+                }
+            }
+            ResponseRouting::FileManifestResult { tab_id } => {
+                if let Some(pending) = conn
+                    .files_management
+                    .tab_by_id_mut(tab_id)
+                    .and_then(|tab| tab.pending_download_select.as_mut())
+                {
+                    pending.error = Some(error);
+                }
+            }
+            ResponseRouting::FileSearchResult { tab_id } => {
+                // Only the tab's current search is waited on
+                if let Some(tab) = conn.files_management.tab_by_id_mut(tab_id)
+                    && tab.current_search_request == Some(message_id)
+                {
+                    tab.current_search_request = None;
+                    tab.search_loading = false;
+                    tab.search_results = None;
+                    tab.search_error = Some(error);
+                }
+            }
+            ResponseRouting::FilePopularResult { tab_id }
+            | ResponseRouting::FileRecentResult { tab_id } => {
+                if let Some(highlights) = conn
+                    .files_management
+                    .tab_by_id_mut(tab_id)
+                    .and_then(|tab| tab.highlights.as_mut())
+                {
+                    let request = if highlights.popular_request == Some(message_id) {
+                        &mut highlights.popular_request
+                    } else {
+                        &mut highlights.recent_request
+                    };
+                    // Ignore requests from before a refresh
+                    if *request == Some(message_id) {
+                        *request = None;
+                        highlights.error = Some(error);
+                    }
+                }
+            }
+
+            // Commands and panel actions report errors in chat
+            _ => {
+                return self.add_active_tab_message(connection_id, ChatMessage::error(error));
+            }
+        }
+
+        Task::none()
+    }
+}
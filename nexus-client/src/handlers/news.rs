@@ -2,17 +2,23 @@
 
 use iced::Task;
 use iced::widget::{Id, operation, text_editor};
-use nexus_common::protocol::ClientMessage;
-use nexus_common::validators::{self, NewsBodyError};
+use nexus_common::protocol::{ClientMessage, NewsAttachment};
+use nexus_common::validators::{
+    self, NewsBodyError, NewsCaptionError, SearchQueryError, validate_news_search_date,
+    validate_search_query,
+};
 use rfd::AsyncFileDialog;
 
 use crate::NexusApp;
+use crate::drafts::DraftContext;
 use crate::i18n::{t, t_args};
-use crate::image::{ImagePickerError, decode_data_uri_max_width};
-use crate::style::{NEWS_IMAGE_MAX_CACHE_WIDTH, NEWS_IMAGE_MAX_SIZE};
+use crate::image::ImagePickerError;
+use crate::style::NEWS_IMAGE_MAX_SIZE;
 use crate::types::{
-    ActivePanel, InputId, Message, NewsManagementMode, PendingRequests, ResponseRouting,
+    ActivePanel, InputId, Message, NewsFormAttachment, NewsManagementMode, NewsSearch,
+    PendingRequests, ResponseRouting, ServerConnection,
 };
+use crate::uri::NexusPath;
 
 impl NexusApp {
     // ==================== Panel Toggle ====================
@@ -31,6 +37,10 @@ impl NexusApp {
         let Some(conn_id) = self.active_connection else {
             return Task::none();
         };
+
+        // Keep a form left open on another panel as a draft
+        self.stash_news_draft(conn_id);
+
         let Some(conn) = self.connections.get_mut(&conn_id) else {
             return Task::none();
         };
@@ -104,9 +114,12 @@ impl NexusApp {
 
         conn.news_management.enter_create_mode();
 
-        // Initialize empty text editor content
-        self.news_body_content
-            .insert(conn_id, text_editor::Content::new());
+        // Initialize text editor content from the unsent draft, if any
+        let content = match conn.drafts.remove(&DraftContext::News(None)) {
+            Some(draft) => text_editor::Content::with_text(&draft),
+            None => text_editor::Content::new(),
+        };
+        self.news_body_content.insert(conn_id, content);
 
         // Focus the text editor
         operation::focus(Id::from(InputId::NewsBody))
@@ -218,19 +231,21 @@ impl NexusApp {
         Task::none()
     }
 
-    // ==================== Image Handlers ====================
+    // ==================== Attachment Handlers ====================
 
     /// Handle pick image button press
     pub fn handle_news_pick_image_pressed(&mut self) -> Task<Message> {
         // Clear any previous error when starting a new pick
+        let mut svg_max_size = NEWS_IMAGE_MAX_SIZE;
         if let Some(conn_id) = self.active_connection
             && let Some(conn) = self.connections.get_mut(&conn_id)
         {
             conn.news_management.form_error = None;
+            svg_max_size = news_image_max_size(conn);
         }
 
         Task::perform(
-            async {
+            async move {
                 let handle = AsyncFileDialog::new()
                     .add_filter("Images", &["png", "jpg", "jpeg", "webp", "svg"])
                     .pick_file()
@@ -257,8 +272,14 @@ impl NexusApp {
                         // Read file contents
                         let bytes = file.read().await;
 
-                        // Check file size
-                        if bytes.len() > NEWS_IMAGE_MAX_SIZE {
+                        // Check file size (the server scales raster images down to
+                        // its own limit, but stores SVG images as they are)
+                        let max_size = if mime_type == "image/svg+xml" {
+                            svg_max_size
+                        } else {
+                            NEWS_IMAGE_MAX_SIZE
+                        };
+                        if bytes.len() > max_size {
                             return Err(ImagePickerError::TooLarge);
                         }
 
@@ -295,20 +316,28 @@ impl NexusApp {
 
         match result {
             Ok(data_uri) => {
-                let cached = decode_data_uri_max_width(&data_uri, NEWS_IMAGE_MAX_CACHE_WIDTH);
-                if cached.is_some() {
-                    conn.news_management.form_image = data_uri;
-                    conn.news_management.cached_form_image = cached;
-                    conn.news_management.form_error = None;
-                } else {
+                let form_attachment = NewsFormAttachment::new(NewsAttachment::Image {
+                    data: data_uri,
+                    caption: None,
+                });
+                if form_attachment.cached_image.is_none() {
                     conn.news_management.form_error = Some(t("err-news-image-decode-failed"));
+                } else if let Some(error) = too_many_attachments(conn) {
+                    conn.news_management.form_error = Some(error);
+                } else {
+                    conn.news_management.form_attachments.push(form_attachment);
+                    conn.news_management.form_error = None;
                 }
             }
             Err(ImagePickerError::Cancelled) => {
                 // User cancelled, do nothing
             }
             Err(ImagePickerError::TooLarge) => {
-                conn.news_management.form_error = Some(t("err-news-image-too-large"));
+                let max_kb = news_image_max_size(conn) / 1024;
+                conn.news_management.form_error = Some(t_args(
+                    "err-news-image-too-large",
+                    &[("max_kb", &max_kb.to_string())],
+                ));
             }
             Err(ImagePickerError::UnsupportedType) => {
                 conn.news_management.form_error = Some(t("err-news-image-unsupported-type"));
@@ -318,8 +347,8 @@ impl NexusApp {
         Task::none()
     }
 
-    /// Handle clear image button press
-    pub fn handle_news_clear_image_pressed(&mut self) -> Task<Message> {
+    /// Handle file path input change
+    pub fn handle_news_file_path_changed(&mut self, path: String) -> Task<Message> {
         let Some(conn_id) = self.active_connection else {
             return Task::none();
         };
@@ -327,13 +356,100 @@ impl NexusApp {
             return Task::none();
         };
 
-        conn.news_management.form_image.clear();
-        conn.news_management.cached_form_image = None;
+        conn.news_management.form_file_path = path;
+
+        Task::none()
+    }
+
+    /// Handle add file button press
+    ///
+    /// Adds the path in the file path input as a file attachment.
+    pub fn handle_news_add_file_pressed(&mut self) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        let path = conn
+            .news_management
+            .form_file_path
+            .trim()
+            .trim_matches('/')
+            .to_string();
+        if path.is_empty() {
+            return Task::none();
+        }
+
+        if validators::validate_file_path(&path).is_err() {
+            conn.news_management.form_error = Some(t("err-news-file-path-invalid"));
+            return Task::none();
+        }
+
+        if let Some(error) = too_many_attachments(conn) {
+            conn.news_management.form_error = Some(error);
+            return Task::none();
+        }
+
+        conn.news_management
+            .form_attachments
+            .push(NewsFormAttachment::new(NewsAttachment::File {
+                path,
+                caption: None,
+            }));
+        conn.news_management.form_file_path.clear();
         conn.news_management.form_error = None;
 
         Task::none()
     }
 
+    /// Handle attachment caption change
+    pub fn handle_news_attachment_caption_changed(
+        &mut self,
+        index: usize,
+        caption: String,
+    ) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        conn.news_management.set_attachment_caption(index, caption);
+
+        Task::none()
+    }
+
+    /// Handle remove attachment button press
+    pub fn handle_news_remove_attachment(&mut self, index: usize) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        if index < conn.news_management.form_attachments.len() {
+            conn.news_management.form_attachments.remove(index);
+        }
+        conn.news_management.form_error = None;
+
+        Task::none()
+    }
+
+    /// Handle a file attachment clicked in a news post
+    ///
+    /// Opens the Files panel at the attached file.
+    pub fn handle_news_open_file(&mut self, path: String) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+
+        self.navigate_to_path(conn_id, NexusPath::Files { path })
+    }
+
     // ==================== Submit Handler ====================
 
     /// Handle submit button pressed (create or update based on mode)
@@ -355,10 +471,15 @@ impl NexusApp {
             return Task::none();
         };
 
-        let image = conn.news_management.form_image.clone();
+        let attachments: Vec<NewsAttachment> = conn
+            .news_management
+            .form_attachments
+            .iter()
+            .map(|a| a.attachment.clone())
+            .collect();
 
-        // Must have either body or image
-        if body.is_empty() && image.is_empty() {
+        // Must have either body or an attachment
+        if body.is_empty() && attachments.is_empty() {
             conn.news_management.form_error = Some(t("err-news-empty"));
             return Task::none();
         }
@@ -381,12 +502,30 @@ impl NexusApp {
             return Task::none();
         }
 
+        // Validate attachment captions
+        if let Some(e) = attachments
+            .iter()
+            .filter_map(NewsAttachment::caption)
+            .find_map(|caption| validators::validate_news_caption(caption).err())
+        {
+            let error_msg = match e {
+                NewsCaptionError::TooLong => t_args(
+                    "err-news-caption-too-long",
+                    &[("max", &validators::MAX_NEWS_CAPTION_LENGTH.to_string())],
+                ),
+                NewsCaptionError::InvalidCharacters => t("err-news-caption-invalid-characters"),
+            };
+            conn.news_management.form_error = Some(error_msg);
+            return Task::none();
+        }
+
         // Determine if this is create or update based on mode
         match &conn.news_management.mode {
             NewsManagementMode::Create => {
                 let msg = ClientMessage::NewsCreate {
                     body: if body.is_empty() { None } else { Some(body) },
-                    image: if image.is_empty() { None } else { Some(image) },
+                    image: None,
+                    attachments,
                 };
 
                 match conn.send(msg) {
@@ -405,7 +544,8 @@ impl NexusApp {
                 let msg = ClientMessage::NewsUpdate {
                     id,
                     body: if body.is_empty() { None } else { Some(body) },
-                    image: if image.is_empty() { None } else { Some(image) },
+                    image: None,
+                    attachments,
                 };
 
                 match conn.send(msg) {
@@ -427,6 +567,87 @@ impl NexusApp {
         Task::none()
     }
 
+    // ==================== Search ====================
+
+    /// Handle search input text change
+    pub fn handle_news_search_input_changed(&mut self, value: String) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        // Emptying the input keeps the results until the search is submitted
+        conn.news_management.search_input = value;
+        Task::none()
+    }
+
+    /// Handle search submit (Enter or search button)
+    ///
+    /// An empty search shows all news again.
+    pub fn handle_news_search_submit(&mut self) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        // Whatever this submit does, a search still running is abandoned
+        conn.cancel_requests(|routing| matches!(routing, ResponseRouting::NewsSearchResult));
+        conn.news_management.current_search_request = None;
+
+        let input = conn.news_management.search_input.trim().to_string();
+        if input.is_empty() {
+            conn.news_management.clear_search();
+            return Task::none();
+        }
+
+        conn.news_management.search_query = Some(input.clone());
+        let search = NewsSearch::parse(&input);
+        if let Some(error) = search_error(&search) {
+            conn.news_management.search_results = Some(Err(error));
+            return Task::none();
+        }
+
+        // Show the loading state until the results arrive
+        conn.news_management.search_results = None;
+        let message = ClientMessage::NewsSearch {
+            query: search.query,
+            author: search.author,
+            since: search.since,
+            until: search.until,
+        };
+        match conn.send(message) {
+            Ok(message_id) => {
+                conn.news_management.current_search_request = Some(message_id);
+                conn.pending_requests
+                    .track(message_id, ResponseRouting::NewsSearchResult);
+            }
+            Err(e) => {
+                conn.news_management.search_results =
+                    Some(Err(format!("{}: {}", t("err-send-failed"), e)));
+            }
+        }
+
+        Task::none()
+    }
+
+    /// Handle clear search button (show all news again)
+    pub fn handle_news_search_clear(&mut self) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        conn.cancel_requests(|routing| matches!(routing, ResponseRouting::NewsSearchResult));
+        conn.news_management.clear_search();
+        Task::none()
+    }
+
     // ==================== Refresh Handlers ====================
 
     /// Refresh news list for a specific connection
@@ -477,3 +698,51 @@ impl NexusApp {
         operation::focus(Id::from(InputId::NewsBody))
     }
 }
+
+/// Check a news search before sending it, using the shared validators
+fn search_error(search: &NewsSearch) -> Option<String> {
+    // The query may be left out when a filter is given
+    if (!search.query.is_empty() || !search.has_filter())
+        && let Err(error) = validate_search_query(&search.query)
+    {
+        return Some(match error {
+            // Can't happen: the input was checked not to be empty
+            SearchQueryError::Empty => return None,
+            SearchQueryError::TooShort => t_args(
+                "files-search-query-too-short",
+                &[("min_length", &validators::MIN_QUERY_LENGTH.to_string())],
+            ),
+            SearchQueryError::TooLong => t_args(
+                "files-search-query-too-long",
+                &[(
+                    "max_length",
+                    &validators::MAX_SEARCH_QUERY_LENGTH.to_string(),
+                )],
+            ),
+            SearchQueryError::InvalidCharacters => t("files-search-query-invalid"),
+        });
+    }
+
+    search
+        .since
+        .iter()
+        .chain(search.until.iter())
+        .find(|date| validate_news_search_date(date).is_err())
+        .map(|date| t_args("err-news-search-date", &[("date", date)]))
+}
+
+/// Error to show if the form already has the most attachments a post can have
+fn too_many_attachments(conn: &ServerConnection) -> Option<String> {
+    (conn.news_management.form_attachments.len() >= validators::MAX_NEWS_ATTACHMENTS).then(|| {
+        t_args(
+            "err-news-too-many-attachments",
+            &[("max", &validators::MAX_NEWS_ATTACHMENTS.to_string())],
+        )
+    })
+}
+
+/// Largest news image the server stores, in bytes
+fn news_image_max_size(conn: &ServerConnection) -> usize {
+    conn.news_image_limits
+        .map_or(NEWS_IMAGE_MAX_SIZE, |limits| limits.max_size as usize)
+}
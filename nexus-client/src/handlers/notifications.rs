@@ -0,0 +1,133 @@
+//! Notification action handlers (macOS and Windows)
+
+use iced::Task;
+
+use crate::NexusApp;
+use crate::notifications::NotificationTarget;
+use crate::types::{ChatTab, Message};
+
+impl NexusApp {
+    /// Handle a click on a notification
+    ///
+    /// Brings the window to the front and opens the notification's
+    /// conversation, if it has one that still exists.
+    pub fn handle_notification_activated(
+        &mut self,
+        target: Option<NotificationTarget>,
+    ) -> Task<Message> {
+        let focus_task = self.focus_window_from_notification();
+
+        match target.and_then(|target| self.open_notification_target(target)) {
+            Some(tab_task) => Task::batch([focus_task, tab_task]),
+            None => focus_task,
+        }
+    }
+
+    /// Handle a reply typed into a notification
+    ///
+    /// The reply goes through the chat input of the notification's
+    /// conversation, so it's validated and sent like a typed message. Any
+    /// draft in the input is restored once the reply is sent; if sending
+    /// fails, the reply is left in the input to fix and resend.
+    pub fn handle_notification_reply(
+        &mut self,
+        target: NotificationTarget,
+        text: String,
+    ) -> Task<Message> {
+        let connection_id = target.connection_id;
+        let Some(tab_task) = self.open_notification_target(target) else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return Task::none();
+        };
+
+        let draft = std::mem::replace(&mut conn.message_input, text);
+        conn.tab_completion = None;
+        let send_task = self.handle_send_message_pressed();
+
+        if let Some(conn) = self.connections.get_mut(&connection_id)
+            && conn.message_input.is_empty()
+        {
+            conn.message_input = draft;
+        }
+
+        Task::batch([tab_task, send_task])
+    }
+
+    /// Handle "Mark read" on a notification
+    ///
+    /// Clears the conversation's unread marker without switching to it.
+    pub fn handle_notification_mark_read(&mut self, target: NotificationTarget) -> Task<Message> {
+        let Some(conn) = self.connections.get_mut(&target.connection_id) else {
+            return Task::none();
+        };
+
+        let tab = match target.tab {
+            ChatTab::Channel(channel) => ChatTab::Channel(conn.get_channel_display_name(&channel)),
+            tab => tab,
+        };
+        conn.unread_tabs.remove(&tab);
+        conn.mention_tabs.remove(&tab);
+
+        // Update tray icon state (Windows/Linux only)
+        #[cfg(not(target_os = "macos"))]
+        self.update_tray_state();
+
+        // Update dock badge count (macOS only)
+        #[cfg(target_os = "macos")]
+        self.update_dock_badge();
+
+        Task::none()
+    }
+
+    /// Bring the window to the front (showing it if hidden to the tray)
+    fn focus_window_from_notification(&mut self) -> Task<Message> {
+        #[cfg(target_os = "windows")]
+        if !self.window_visible {
+            return self.show_window_if_hidden_to_tray();
+        }
+
+        iced::window::oldest().then(|opt_id| {
+            opt_id
+                .map(|id| {
+                    Task::batch([
+                        iced::window::minimize(id, false),
+                        iced::window::gain_focus(id),
+                    ])
+                })
+                .unwrap_or_else(Task::none)
+        })
+    }
+
+    /// Switch to a notification's connection and chat tab
+    ///
+    /// Returns `None` when the connection is gone or the channel or group was left.
+    /// Closed user message tabs are reopened.
+    fn open_notification_target(&mut self, target: NotificationTarget) -> Option<Task<Message>> {
+        let conn = self.connections.get_mut(&target.connection_id)?;
+
+        let tab = match target.tab {
+            ChatTab::Channel(channel) => {
+                conn.get_channel_state(&channel)?;
+                ChatTab::Channel(conn.get_channel_display_name(&channel))
+            }
+            ChatTab::UserMessage(nickname) => {
+                conn.user_messages.entry(nickname.clone()).or_default();
+                if !conn.user_message_tabs.contains(&nickname) {
+                    conn.user_message_tabs.push(nickname.clone());
+                }
+                ChatTab::UserMessage(nickname)
+            }
+            ChatTab::Group(group_id) => {
+                conn.groups.get(&group_id)?;
+                ChatTab::Group(group_id)
+            }
+            ChatTab::Console => ChatTab::Console,
+        };
+
+        let connection_task = self.handle_switch_to_connection(target.connection_id);
+        let tab_task = self.handle_switch_chat_tab(tab);
+        Some(Task::batch([connection_task, tab_task]))
+    }
+}
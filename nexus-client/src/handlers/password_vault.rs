@@ -0,0 +1,190 @@
+//! Password vault dialog handlers
+
+use iced::Task;
+use iced::widget::{Id, operation};
+
+use crate::NexusApp;
+use crate::autostart;
+use crate::config::Config;
+use crate::config::credentials::{self, PasswordStore};
+use crate::config::encryption;
+use crate::config::vault::VaultError;
+use crate::i18n::{t, t_args};
+use crate::types::{InputId, Message, PasswordVaultDialog, PasswordVaultMode};
+
+/// Which password vault dialog to show at startup, if any
+///
+/// - Unlock config when the config file is encrypted (the vault, if any,
+///   is asked for afterwards)
+/// - Unlock when a vault exists and either holds bookmark passwords or is
+///   needed to store new ones (no keychain)
+/// - Create when there's no keychain and passwords are still in the config
+///   file, unless the user already declined or the config is encrypted
+fn startup_password_vault_mode(config: &Config) -> Option<PasswordVaultMode> {
+    if encryption::locked() {
+        Some(PasswordVaultMode::UnlockConfig)
+    } else if credentials::vault_exists() {
+        let has_vault_passwords = config
+            .bookmarks
+            .iter()
+            .any(|b| b.password_store == Some(PasswordStore::Vault));
+        (has_vault_passwords || !credentials::keychain_available())
+            .then_some(PasswordVaultMode::Unlock)
+    } else if !credentials::keychain_available()
+        && config.has_plaintext_passwords()
+        && !config.settings.password_vault_declined
+        && !encryption::enabled()
+    {
+        Some(PasswordVaultMode::Create)
+    } else {
+        None
+    }
+}
+
+/// User-facing message for a vault error
+fn vault_error_message(error: VaultError) -> String {
+    match error {
+        VaultError::WrongPassphrase => t("err-wrong-master-passphrase"),
+        error => t_args("err-password-vault", &[("error", &error.to_string())]),
+    }
+}
+
+impl NexusApp {
+    /// Dialog to show at startup
+    ///
+    /// When unlocking, bookmark auto-connect (if `auto_connect`) is held
+    /// back until the dialog closes, so bookmarks connect with their passwords.
+    pub fn startup_password_vault_dialog(&self, auto_connect: bool) -> Option<PasswordVaultDialog> {
+        startup_password_vault_mode(&self.config).map(|mode| PasswordVaultDialog {
+            auto_connect: auto_connect && mode != PasswordVaultMode::Create,
+            ..PasswordVaultDialog::new(mode)
+        })
+    }
+
+    /// Handle passphrase input change
+    pub fn handle_password_vault_passphrase_changed(&mut self, value: String) -> Task<Message> {
+        if let Some(dialog) = self.password_vault.as_mut() {
+            dialog.passphrase = value;
+        }
+        Task::none()
+    }
+
+    /// Handle passphrase confirmation input change
+    pub fn handle_password_vault_confirm_changed(&mut self, value: String) -> Task<Message> {
+        if let Some(dialog) = self.password_vault.as_mut() {
+            dialog.confirm = value;
+        }
+        Task::none()
+    }
+
+    /// Create or unlock the vault with the entered passphrase
+    pub fn handle_password_vault_submit(&mut self) -> Task<Message> {
+        let Some(dialog) = self.password_vault.as_mut() else {
+            return Task::none();
+        };
+
+        match dialog.mode {
+            PasswordVaultMode::Create => {
+                if dialog.passphrase.is_empty() {
+                    dialog.error = Some(t("err-master-passphrase-empty"));
+                    return Task::none();
+                }
+                if dialog.passphrase != dialog.confirm {
+                    dialog.error = Some(t("err-passwords-do-not-match"));
+                    return operation::focus(Id::from(InputId::VaultPassphraseConfirm));
+                }
+                if let Err(e) = credentials::create_vault(&dialog.passphrase) {
+                    dialog.error = Some(vault_error_message(e));
+                    return Task::none();
+                }
+
+                // Moves the plaintext passwords into the new vault
+                let _ = self.config.save();
+                self.close_password_vault_dialog()
+            }
+            PasswordVaultMode::Unlock => {
+                if let Err(e) =
+                    credentials::unlock_vault(&dialog.passphrase, &mut self.config.bookmarks)
+                {
+                    dialog.error = Some(vault_error_message(e));
+                    dialog.passphrase.clear();
+                    return operation::focus(Id::from(InputId::VaultPassphrase));
+                }
+
+                // With a keychain available, this moves vault passwords into it
+                if credentials::keychain_available() {
+                    let _ = self.config.save();
+                }
+                self.close_password_vault_dialog()
+            }
+            PasswordVaultMode::UnlockConfig => {
+                let auto_connect = dialog.auto_connect;
+                match Config::unlock(&dialog.passphrase) {
+                    Ok(config) => self.apply_unlocked_config(config, auto_connect),
+                    Err(e) => {
+                        dialog.error = Some(vault_error_message(e));
+                        dialog.passphrase.clear();
+                        operation::focus(Id::from(InputId::VaultPassphrase))
+                    }
+                }
+            }
+        }
+    }
+
+    /// Switch to the config that was just decrypted
+    ///
+    /// Settings that took effect at startup are applied again, then the
+    /// password vault is asked for if the unlocked bookmarks need it.
+    fn apply_unlocked_config(&mut self, config: Config, auto_connect: bool) -> Task<Message> {
+        self.config = config;
+        self.selected_event_type = self.config.settings.selected_event_type;
+        let split_ratio = self
+            .config
+            .settings
+            .split_ratio(self.config.settings.window_width);
+        self.ui_state.split_layout.set_ratio(split_ratio);
+
+        let mut tasks = vec![self.register_window_hotkey()];
+        #[cfg(not(target_os = "macos"))]
+        tasks.push(self.update_tray_from_settings());
+
+        if let Some(next) = self.startup_password_vault_dialog(auto_connect) {
+            self.password_vault = Some(next);
+            tasks.push(operation::focus(Id::from(InputId::VaultPassphrase)));
+        } else {
+            tasks.push(self.close_password_vault_dialog());
+        }
+        Task::batch(tasks)
+    }
+
+    /// Close the dialog without creating or unlocking a vault
+    ///
+    /// Declining to create a vault is remembered; skipping the unlock only
+    /// lasts for this run (bookmarks connect without their saved passwords).
+    /// Skipping the config unlock runs with default settings and no
+    /// bookmarks, and nothing is saved over the encrypted config.
+    pub fn handle_password_vault_skip(&mut self) -> Task<Message> {
+        if self
+            .password_vault
+            .as_ref()
+            .is_some_and(|dialog| dialog.mode == PasswordVaultMode::Create)
+        {
+            self.config.settings.password_vault_declined = true;
+            let _ = self.config.save();
+        }
+        self.close_password_vault_dialog()
+    }
+
+    /// Close the dialog and run auto-connect if it was held back
+    fn close_password_vault_dialog(&mut self) -> Task<Message> {
+        let Some(dialog) = self.password_vault.take() else {
+            return Task::none();
+        };
+
+        let mut tasks = vec![operation::focus(Id::from(InputId::ServerName))];
+        if dialog.auto_connect {
+            tasks.extend(autostart::generate_auto_connect_tasks(&self.config));
+        }
+        Task::batch(tasks)
+    }
+}
@@ -0,0 +1,248 @@
+//! Quick switcher (Ctrl+K) for jumping to any tab, bookmark or panel
+
+use iced::Task;
+use iced::widget::{Id, operation};
+
+use crate::NexusApp;
+use crate::i18n::t;
+use crate::quick_switcher::{QuickSwitchItem, QuickSwitchTarget, note_recent, rank};
+use crate::style::QUICK_SWITCHER_MAX_RESULTS;
+use crate::types::{ActivePanel, ChatTab, InputId, Message, QuickSwitcherState};
+use crate::views::constants::{
+    PERMISSION_CONNECTION_MONITOR, PERMISSION_FILE_LIST, PERMISSION_LOG_VIEW, PERMISSION_NEWS_LIST,
+    PERMISSION_USER_BROADCAST, PERMISSION_USER_CREATE, PERMISSION_USER_DELETE,
+    PERMISSION_USER_EDIT,
+};
+
+/// Panels of a connection, with the permissions that show them (any one will do)
+const CONNECTION_PANELS: &[(ActivePanel, &[&str])] = &[
+    (ActivePanel::Files, &[PERMISSION_FILE_LIST]),
+    (ActivePanel::News, &[PERMISSION_NEWS_LIST]),
+    (ActivePanel::Broadcast, &[PERMISSION_USER_BROADCAST]),
+    (
+        ActivePanel::UserManagement,
+        &[
+            PERMISSION_USER_CREATE,
+            PERMISSION_USER_EDIT,
+            PERMISSION_USER_DELETE,
+        ],
+    ),
+    (
+        ActivePanel::ConnectionMonitor,
+        &[PERMISSION_CONNECTION_MONITOR],
+    ),
+    (ActivePanel::ServerLog, &[PERMISSION_LOG_VIEW]),
+    (ActivePanel::ServerInfo, &[]),
+];
+
+/// Panels that don't belong to a connection
+const APP_PANELS: &[ActivePanel] = &[
+    ActivePanel::Transfers,
+    ActivePanel::Settings,
+    ActivePanel::About,
+];
+
+/// Display name of a panel the quick switcher can open
+fn panel_label(panel: ActivePanel) -> String {
+    t(match panel {
+        ActivePanel::Files => "tooltip-files",
+        ActivePanel::News => "tooltip-news",
+        ActivePanel::Broadcast => "tooltip-broadcast",
+        ActivePanel::UserManagement => "tooltip-manage-users",
+        ActivePanel::ConnectionMonitor => "tooltip-connection-monitor",
+        ActivePanel::ServerLog => "tooltip-server-log",
+        ActivePanel::ServerInfo => "tooltip-server-info",
+        ActivePanel::Transfers => "tooltip-transfers",
+        ActivePanel::Settings => "tooltip-settings",
+        _ => "tooltip-about",
+    })
+}
+
+/// Message that opens a panel from the chat view
+fn open_panel_message(panel: ActivePanel) -> Message {
+    match panel {
+        ActivePanel::Files => Message::ToggleFiles,
+        ActivePanel::News => Message::ToggleNews,
+        ActivePanel::Broadcast => Message::ToggleBroadcast,
+        ActivePanel::UserManagement => Message::ToggleUserManagement,
+        ActivePanel::ConnectionMonitor => Message::ToggleConnectionMonitor,
+        ActivePanel::ServerLog => Message::ToggleServerLog,
+        ActivePanel::ServerInfo => Message::ShowServerInfo,
+        ActivePanel::Transfers => Message::ToggleTransfers,
+        ActivePanel::Settings => Message::ToggleSettings,
+        _ => Message::ShowAbout,
+    }
+}
+
+impl NexusApp {
+    /// Everything the quick switcher can jump to, in listing order
+    ///
+    /// Each connection's tabs and panels (by connection order), then
+    /// bookmarks, then the app-wide panels.
+    fn quick_switch_items(&self) -> Vec<QuickSwitchItem> {
+        let mut items = Vec::new();
+
+        let mut conn_ids: Vec<usize> = self.connections.keys().copied().collect();
+        conn_ids.sort_unstable();
+        for conn_id in conn_ids {
+            let conn = &self.connections[&conn_id];
+            for channel in &conn.channel_tabs {
+                items.push(QuickSwitchItem {
+                    target: QuickSwitchTarget::Tab(conn_id, ChatTab::Channel(channel.clone())),
+                    label: channel.clone(),
+                    detail: conn.display_name.clone(),
+                });
+            }
+            for nickname in &conn.user_message_tabs {
+                items.push(QuickSwitchItem {
+                    target: QuickSwitchTarget::Tab(conn_id, ChatTab::UserMessage(nickname.clone())),
+                    label: nickname.clone(),
+                    detail: conn.display_name.clone(),
+                });
+            }
+            for group_id in &conn.group_tabs {
+                let Some(group) = conn.groups.get(group_id) else {
+                    continue;
+                };
+                items.push(QuickSwitchItem {
+                    target: QuickSwitchTarget::Tab(conn_id, ChatTab::Group(*group_id)),
+                    label: group.label(&conn.nickname),
+                    detail: conn.display_name.clone(),
+                });
+            }
+
+            for (panel, permissions) in CONNECTION_PANELS {
+                if permissions.is_empty() || conn.has_any_permission(permissions) {
+                    items.push(QuickSwitchItem {
+                        target: QuickSwitchTarget::Panel(Some(conn_id), *panel),
+                        label: panel_label(*panel),
+                        detail: conn.display_name.clone(),
+                    });
+                }
+            }
+        }
+
+        for bookmark in &self.config.bookmarks {
+            items.push(QuickSwitchItem {
+                target: QuickSwitchTarget::Bookmark(bookmark.id),
+                label: bookmark.name.clone(),
+                detail: t("quick-switcher-bookmark"),
+            });
+        }
+
+        for panel in APP_PANELS {
+            items.push(QuickSwitchItem {
+                target: QuickSwitchTarget::Panel(None, *panel),
+                label: panel_label(*panel),
+                detail: String::new(),
+            });
+        }
+
+        items
+    }
+
+    /// Quick switcher entries matching the typed query, best first
+    pub fn quick_switch_matches(&self) -> Vec<QuickSwitchItem> {
+        let query = self
+            .ui_state
+            .quick_switcher
+            .as_ref()
+            .map(|state| state.query.as_str())
+            .unwrap_or_default();
+        rank(
+            self.quick_switch_items(),
+            query,
+            &self.ui_state.recent_switches,
+        )
+    }
+
+    /// Open the quick switcher, or close it if it's open
+    pub fn handle_quick_switcher_toggled(&mut self) -> Task<Message> {
+        if self.ui_state.quick_switcher.take().is_some() {
+            return Task::none();
+        }
+        self.ui_state.quick_switcher = Some(QuickSwitcherState::default());
+        operation::focus(Id::from(InputId::QuickSwitcher))
+    }
+
+    /// Handle the quick switcher query changing
+    pub fn handle_quick_switcher_query_changed(&mut self, query: String) -> Task<Message> {
+        if let Some(state) = &mut self.ui_state.quick_switcher {
+            state.query = query;
+            state.selected = 0;
+        }
+        Task::none()
+    }
+
+    /// Highlight the next match (Down arrow)
+    pub fn handle_quick_switcher_next(&mut self) -> Task<Message> {
+        let count = self
+            .quick_switch_matches()
+            .len()
+            .min(QUICK_SWITCHER_MAX_RESULTS);
+        if let Some(state) = &mut self.ui_state.quick_switcher {
+            state.selected = (state.selected + 1).min(count.saturating_sub(1));
+        }
+        Task::none()
+    }
+
+    /// Highlight the previous match (Up arrow)
+    pub fn handle_quick_switcher_previous(&mut self) -> Task<Message> {
+        if let Some(state) = &mut self.ui_state.quick_switcher {
+            state.selected = state.selected.saturating_sub(1);
+        }
+        Task::none()
+    }
+
+    /// Jump to the highlighted match (Enter)
+    pub fn handle_quick_switcher_submitted(&mut self) -> Task<Message> {
+        let selected = self
+            .ui_state
+            .quick_switcher
+            .as_ref()
+            .map_or(0, |state| state.selected);
+        self.handle_quick_switcher_chosen(selected)
+    }
+
+    /// Jump to a match by its place in the list
+    pub fn handle_quick_switcher_chosen(&mut self, index: usize) -> Task<Message> {
+        let Some(item) = self.quick_switch_matches().into_iter().nth(index) else {
+            return Task::none();
+        };
+        self.ui_state.quick_switcher = None;
+        note_recent(&mut self.ui_state.recent_switches, item.target.clone());
+
+        match item.target {
+            QuickSwitchTarget::Tab(conn_id, tab) => {
+                let switch = self.handle_switch_to_connection(conn_id);
+                Task::batch([switch, self.handle_switch_chat_tab(tab)])
+            }
+            QuickSwitchTarget::Bookmark(id) => {
+                match self
+                    .connections
+                    .iter()
+                    .find(|(_, conn)| conn.bookmark_id == Some(id))
+                {
+                    Some((&conn_id, _)) => self.handle_switch_to_connection(conn_id),
+                    None => self.handle_connect_to_bookmark(id),
+                }
+            }
+            QuickSwitchTarget::Panel(conn_id, panel) => {
+                let switch = match conn_id {
+                    Some(conn_id) => self.handle_switch_to_connection(conn_id),
+                    None => Task::none(),
+                };
+                if self.active_panel() == panel {
+                    return switch;
+                }
+                Task::batch([switch, self.update(open_panel_message(panel))])
+            }
+        }
+    }
+
+    /// Close the quick switcher without jumping anywhere
+    pub fn handle_quick_switcher_closed(&mut self) -> Task<Message> {
+        self.ui_state.quick_switcher = None;
+        Task::none()
+    }
+}
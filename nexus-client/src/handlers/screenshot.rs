@@ -0,0 +1,331 @@
+//! Screenshot sharing handlers (capture, annotate, upload, post link)
+//!
+//! Flow: `/screenshot` captures the screen, the annotation editor opens over
+//! the chat, and Send saves a PNG that is uploaded into the user's folder
+//! under the connection's screenshot folder. When the upload completes, a
+//! nexus:// link to the file is posted to the chat tab the screenshot was
+//! taken from.
+
+use std::path::PathBuf;
+
+use iced::{Point, Task};
+use iced_toasts::{ToastLevel, toast};
+use nexus_common::protocol::{ChatAction, ClientMessage};
+use uuid::Uuid;
+
+use crate::NexusApp;
+use crate::i18n::{t, t_args};
+use crate::screenshot::annotate::Tool;
+use crate::screenshot::{self, Screenshot};
+use crate::transfers::Transfer;
+use crate::types::{
+    ChatMessage, ChatTab, Message, ScreenshotEditorState, ScreenshotFolder, ScreenshotShare,
+    ServerConnection,
+};
+use crate::uri::file_link;
+
+impl NexusApp {
+    /// Get the folder shared screenshots are uploaded into for a connection
+    ///
+    /// Bookmarked connections remember it on the bookmark; other connections
+    /// keep it for the session only.
+    pub fn screenshot_folder_for(&self, conn: &ServerConnection) -> Option<&ScreenshotFolder> {
+        match conn.bookmark_id.and_then(|id| self.config.get_bookmark(id)) {
+            Some(bookmark) => bookmark.screenshot_folder.as_ref(),
+            None => conn.screenshot_folder.as_ref(),
+        }
+    }
+
+    /// Handle "Share Screenshots Here" - use a folder for shared screenshots
+    pub fn handle_file_screenshot_folder(&mut self, remote_path: String) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        let folder = ScreenshotFolder {
+            remote_path,
+            remote_root: conn.files_management.active_tab().viewing_root,
+        };
+        match conn
+            .bookmark_id
+            .and_then(|id| self.config.get_bookmark_mut(id))
+        {
+            Some(bookmark) => {
+                bookmark.screenshot_folder = Some(folder);
+                let _ = self.config.save();
+            }
+            None => conn.screenshot_folder = Some(folder),
+        }
+
+        self.toasts
+            .push(toast(&t("toast-screenshot-folder-set")).level(ToastLevel::Success));
+
+        Task::none()
+    }
+
+    /// Capture a screenshot to share in a connection's active chat tab
+    ///
+    /// The caller has already checked that the tab and folder are usable.
+    pub fn start_screenshot_share(&mut self, connection_id: usize) -> Task<Message> {
+        let Some(conn) = self.connections.get(&connection_id) else {
+            return Task::none();
+        };
+        let target = conn.active_chat_tab.clone();
+
+        Task::perform(screenshot::capture(), move |result| {
+            Message::ScreenshotCaptured(connection_id, target.clone(), result)
+        })
+    }
+
+    /// Handle a finished capture - open the annotation editor
+    pub fn handle_screenshot_captured(
+        &mut self,
+        connection_id: usize,
+        target: ChatTab,
+        result: Result<Option<Screenshot>, String>,
+    ) -> Task<Message> {
+        match result {
+            Ok(Some(image)) => {
+                if let Some(conn) = self.connections.get_mut(&connection_id) {
+                    conn.screenshot_editor = Some(ScreenshotEditorState::new(target, image));
+                }
+                Task::none()
+            }
+            // User cancelled the platform picker
+            Ok(None) => Task::none(),
+            Err(error) => self.add_active_tab_message(
+                connection_id,
+                ChatMessage::error(t_args("err-screenshot-capture", &[("error", &error)])),
+            ),
+        }
+    }
+
+    /// Handle annotation tool selection
+    pub fn handle_screenshot_tool_selected(&mut self, tool: Tool) -> Task<Message> {
+        if let Some(editor) = self.active_screenshot_editor_mut() {
+            editor.tool = tool;
+            editor.drag_start = None;
+        }
+        Task::none()
+    }
+
+    /// Handle pointer movement over the preview
+    pub fn handle_screenshot_pointer_moved(&mut self, position: Point) -> Task<Message> {
+        if let Some(editor) = self.active_screenshot_editor_mut() {
+            editor.pointer = position;
+        }
+        Task::none()
+    }
+
+    /// Handle pointer press on the preview - start a crop or arrow
+    pub fn handle_screenshot_pointer_pressed(&mut self) -> Task<Message> {
+        if let Some(editor) = self.active_screenshot_editor_mut()
+            && !editor.sending
+        {
+            editor.begin_drag();
+        }
+        Task::none()
+    }
+
+    /// Handle pointer release (or leaving the preview) - apply the crop or arrow
+    pub fn handle_screenshot_pointer_released(&mut self) -> Task<Message> {
+        if let Some(editor) = self.active_screenshot_editor_mut() {
+            editor.end_drag();
+        }
+        Task::none()
+    }
+
+    /// Handle Undo - revert the most recent edit
+    pub fn handle_screenshot_undo(&mut self) -> Task<Message> {
+        if let Some(editor) = self.active_screenshot_editor_mut()
+            && !editor.sending
+        {
+            editor.undo();
+        }
+        Task::none()
+    }
+
+    /// Handle Cancel - discard the screenshot
+    pub fn handle_screenshot_cancel(&mut self) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+        {
+            conn.screenshot_editor = None;
+        }
+        Task::none()
+    }
+
+    /// Handle Send - write the annotated image to a PNG for upload
+    pub fn handle_screenshot_send(&mut self) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(editor) = self.active_screenshot_editor_mut() else {
+            return Task::none();
+        };
+        if editor.sending {
+            return Task::none();
+        }
+
+        editor.sending = true;
+        editor.error = None;
+        editor.drag_start = None;
+        let image = editor.image.clone();
+
+        Task::perform(screenshot::save_png(image), move |result| {
+            Message::ScreenshotSaved(conn_id, result)
+        })
+    }
+
+    /// Handle a saved screenshot - queue its upload and close the editor
+    ///
+    /// The link is posted by [`Self::post_screenshot_share`] once the upload
+    /// completes.
+    pub fn handle_screenshot_saved(
+        &mut self,
+        connection_id: usize,
+        result: Result<PathBuf, String>,
+    ) -> Task<Message> {
+        let Some(conn) = self.connections.get(&connection_id) else {
+            return Task::none();
+        };
+        // Cancelled while saving
+        let Some(editor) = conn.screenshot_editor.as_ref() else {
+            return Task::none();
+        };
+
+        let folder = self.screenshot_folder_for(conn).cloned();
+        let error = match (&result, &folder) {
+            (Err(error), _) => Some(t_args("err-screenshot-save", &[("error", error)])),
+            (Ok(_), None) => Some(t("err-screenshot-no-folder")),
+            (Ok(_), Some(_)) => None,
+        };
+        let (Ok(local_path), Some(folder), None) = (result, folder, error.clone()) else {
+            if let Some(conn) = self.connections.get_mut(&connection_id)
+                && let Some(editor) = conn.screenshot_editor.as_mut()
+            {
+                editor.sending = false;
+                editor.error = error;
+            }
+            return Task::none();
+        };
+
+        let destination = screenshot_destination(&folder.remote_path, &conn.nickname);
+        let file_name = local_path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let share = ScreenshotShare {
+            connection_id,
+            target: editor.target.clone(),
+            remote_path: format!("{destination}/{file_name}"),
+        };
+
+        let queue_position = self.transfer_manager.next_queue_position();
+        let transfer = Transfer::new_upload(
+            conn.connection_info.clone(),
+            destination,
+            folder.remote_root,
+            false,
+            local_path,
+            conn.bookmark_id,
+            queue_position,
+        );
+        self.screenshot_shares.insert(transfer.id, share);
+        self.transfer_manager.add(transfer);
+        let _ = self.transfer_manager.save();
+
+        if let Some(conn) = self.connections.get_mut(&connection_id) {
+            conn.screenshot_editor = None;
+        }
+
+        self.toasts.push(
+            toast(&t_args("toast-upload-started", &[("filename", &file_name)]))
+                .level(ToastLevel::Success),
+        );
+
+        Task::none()
+    }
+
+    /// Post the link for a completed screenshot upload to its chat tab
+    ///
+    /// Does nothing if the transfer isn't a screenshot share or the
+    /// connection has since closed.
+    pub fn post_screenshot_share(&mut self, transfer_id: Uuid) -> Task<Message> {
+        let Some(share) = self.screenshot_shares.remove(&transfer_id) else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get(&share.connection_id) else {
+            return Task::none();
+        };
+
+        let message = file_link(&conn.connection_info, &share.remote_path);
+        let msg = match share.target {
+            ChatTab::Channel(channel) => ClientMessage::ChatSend {
+                message,
+                action: ChatAction::Normal,
+                channel,
+                idempotency_key: None,
+            },
+            ChatTab::UserMessage(nickname) => ClientMessage::UserMessage {
+                to_nickname: nickname,
+                message,
+                action: ChatAction::Normal,
+                idempotency_key: None,
+            },
+            ChatTab::Group(group_id) => ClientMessage::GroupMessageSend {
+                group_id,
+                message,
+                action: ChatAction::Normal,
+                idempotency_key: None,
+            },
+            ChatTab::Console => return Task::none(),
+        };
+
+        if let Err(e) = conn.send(msg) {
+            let error_msg = format!("{}: {}", t("err-send-failed"), e);
+            return self.add_active_tab_message(share.connection_id, ChatMessage::error(error_msg));
+        }
+        Task::none()
+    }
+
+    /// Get the annotation editor on the active connection
+    fn active_screenshot_editor_mut(&mut self) -> Option<&mut ScreenshotEditorState> {
+        let conn_id = self.active_connection?;
+        self.connections
+            .get_mut(&conn_id)?
+            .screenshot_editor
+            .as_mut()
+    }
+}
+
+/// Remote directory a user's screenshots are uploaded into
+fn screenshot_destination(folder: &str, nickname: &str) -> String {
+    let folder = folder.trim_end_matches('/');
+    if folder.is_empty() {
+        nickname.to_string()
+    } else {
+        format!("{folder}/{nickname}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_screenshot_destination() {
+        assert_eq!(
+            screenshot_destination("Uploads [NEXUS-UL]", "alice"),
+            "Uploads [NEXUS-UL]/alice"
+        );
+        assert_eq!(
+            screenshot_destination("Uploads [NEXUS-UL]/", "alice"),
+            "Uploads [NEXUS-UL]/alice"
+        );
+        assert_eq!(screenshot_destination("", "alice"), "alice");
+    }
+}
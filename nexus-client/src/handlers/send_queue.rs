@@ -0,0 +1,297 @@
+//! Queue for chat and user messages sent while the connection is down
+//!
+//! Every message carries an idempotency key. Messages stay in
+//! `unacked_messages` until the server answers, and go back in the queue if
+//! the connection drops first, so they're resent with the same key and the
+//! server won't post them twice.
+
+use std::time::Instant;
+
+use iced::Task;
+use nexus_common::framing::MessageId;
+use nexus_common::protocol::{ChatAction, ClientMessage};
+use uuid::Uuid;
+
+use crate::NexusApp;
+use crate::i18n::t;
+use crate::types::{
+    ChatTab, Message, QueuedMessage, ResponseRouting, ServerConnection, UnackedMessage,
+};
+
+/// Send a chat, user, or group message typed in a tab
+///
+/// User and group messages are tracked so errors go to their tab.
+pub(crate) fn send_chat_message(
+    conn: &mut ServerConnection,
+    queued: &QueuedMessage,
+) -> Result<MessageId, String> {
+    let message = queued.message.clone();
+    let action = queued.action;
+    let idempotency_key = Some(queued.idempotency_key());
+    let message_id = match &queued.tab {
+        ChatTab::Console => unreachable!(), // Console input is never sent as chat
+        ChatTab::Channel(channel) => conn.send(ClientMessage::ChatSend {
+            message,
+            action,
+            channel: channel.clone(),
+            idempotency_key,
+        })?,
+        ChatTab::UserMessage(nickname) => {
+            let message_id = conn.send(ClientMessage::UserMessage {
+                to_nickname: nickname.clone(),
+                message,
+                action,
+                idempotency_key,
+            })?;
+            conn.pending_requests.track(
+                message_id,
+                ResponseRouting::ShowErrorInMessageTab(nickname.clone()),
+            );
+            message_id
+        }
+        ChatTab::Group(group_id) => {
+            let message_id = conn.send(ClientMessage::GroupMessageSend {
+                group_id: *group_id,
+                message,
+                action,
+                idempotency_key,
+            })?;
+            conn.pending_requests
+                .track(message_id, ResponseRouting::ShowErrorInGroupTab(*group_id));
+            message_id
+        }
+    };
+    conn.unacked_messages.push(UnackedMessage {
+        message_id,
+        sent: Instant::now(),
+        queued: queued.clone(),
+    });
+    Ok(message_id)
+}
+
+/// Send queued messages in order with `send`, stopping at the first that fails
+///
+/// The message that failed and the ones after it stay queued, in order.
+fn flush_queue(
+    queue: &mut Vec<QueuedMessage>,
+    mut send: impl FnMut(&QueuedMessage) -> Result<MessageId, String>,
+) {
+    let sent = queue
+        .iter()
+        .take_while(|queued| send(queued).is_ok())
+        .count();
+    queue.drain(..sent);
+}
+
+/// Send one queued message with `send`, keeping it in place if that fails
+///
+/// Returns None if no queued message has this ID.
+fn retry_queued(
+    queue: &mut Vec<QueuedMessage>,
+    id: Uuid,
+    send: impl FnOnce(&QueuedMessage) -> Result<MessageId, String>,
+) -> Option<Result<MessageId, String>> {
+    let index = queue.iter().position(|queued| queued.id == id)?;
+    let result = send(&queue[index]);
+    if result.is_ok() {
+        queue.remove(index);
+    }
+    Some(result)
+}
+
+/// Drop a queued message without sending it
+fn cancel_queued(queue: &mut Vec<QueuedMessage>, id: Uuid) {
+    queue.retain(|queued| queued.id != id);
+}
+
+impl NexusApp {
+    /// Send a chat or user message, queueing it if the connection is down
+    ///
+    /// Only a message the connection can't take is queued here. One it takes
+    /// waits in `unacked_messages` until the server answers; if the connection
+    /// drops first, the disconnect handler queues it again for the next
+    /// connection to this server.
+    pub fn send_or_queue_chat_message(
+        &mut self,
+        connection_id: usize,
+        tab: ChatTab,
+        message: String,
+        action: ChatAction,
+    ) {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return;
+        };
+
+        let queued = QueuedMessage::new(tab, message, action);
+        if send_chat_message(conn, &queued).is_err() {
+            conn.send_queue.push(queued);
+        }
+    }
+
+    /// Send queued messages in order, stopping at the first that fails
+    pub fn flush_send_queue(&mut self, connection_id: usize) {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return;
+        };
+
+        let mut queue = std::mem::take(&mut conn.send_queue);
+        flush_queue(&mut queue, |queued| send_chat_message(conn, queued));
+        conn.send_queue = queue;
+    }
+
+    /// Handle the retry button on a queued message
+    pub fn handle_queued_message_retry(&mut self, id: Uuid) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        let mut queue = std::mem::take(&mut conn.send_queue);
+        let result = retry_queued(&mut queue, id, |queued| send_chat_message(conn, queued));
+        conn.send_queue = queue;
+
+        match result {
+            Some(Err(e)) => {
+                let error_msg = format!("{}: {}", t("err-send-failed"), e);
+                self.add_chat_error(conn_id, error_msg)
+            }
+            _ => Task::none(),
+        }
+    }
+
+    /// Handle the cancel button on a queued message
+    pub fn handle_queued_message_cancel(&mut self, id: Uuid) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+        {
+            cancel_queued(&mut conn.send_queue, id);
+        }
+        Task::none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn queue_of(messages: &[&str]) -> Vec<QueuedMessage> {
+        messages
+            .iter()
+            .map(|message| {
+                QueuedMessage::new(
+                    ChatTab::Channel("#general".to_string()),
+                    message.to_string(),
+                    ChatAction::Normal,
+                )
+            })
+            .collect()
+    }
+
+    fn texts(queue: &[QueuedMessage]) -> Vec<&str> {
+        queue.iter().map(|queued| queued.message.as_str()).collect()
+    }
+
+    // =========================================================================
+    // flush_queue Tests
+    // =========================================================================
+
+    #[test]
+    fn test_flush_queue_sends_in_order() {
+        let mut queue = queue_of(&["one", "two", "three"]);
+        let mut sent = Vec::new();
+
+        flush_queue(&mut queue, |queued| {
+            sent.push(queued.message.clone());
+            Ok(MessageId::new())
+        });
+
+        assert_eq!(sent, vec!["one", "two", "three"]);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_flush_queue_stops_at_first_failure() {
+        let mut queue = queue_of(&["one", "two", "three"]);
+        let mut attempted = Vec::new();
+
+        flush_queue(&mut queue, |queued| {
+            attempted.push(queued.message.clone());
+            if queued.message == "two" {
+                Err("connection closed".to_string())
+            } else {
+                Ok(MessageId::new())
+            }
+        });
+
+        // Nothing after the failure is tried, and the rest stays in order
+        assert_eq!(attempted, vec!["one", "two"]);
+        assert_eq!(texts(&queue), vec!["two", "three"]);
+    }
+
+    #[test]
+    fn test_flush_queue_keeps_everything_when_first_fails() {
+        let mut queue = queue_of(&["one", "two"]);
+
+        flush_queue(&mut queue, |_| Err("connection closed".to_string()));
+
+        assert_eq!(texts(&queue), vec!["one", "two"]);
+    }
+
+    // =========================================================================
+    // retry_queued / cancel_queued Tests
+    // =========================================================================
+
+    #[test]
+    fn test_retry_queued_removes_sent_message() {
+        let mut queue = queue_of(&["one", "two", "three"]);
+        let id = queue[1].id;
+
+        let result = retry_queued(&mut queue, id, |queued| {
+            assert_eq!(queued.message, "two");
+            Ok(MessageId::new())
+        });
+
+        assert!(matches!(result, Some(Ok(_))));
+        assert_eq!(texts(&queue), vec!["one", "three"]);
+    }
+
+    #[test]
+    fn test_retry_queued_keeps_failed_message_in_place() {
+        let mut queue = queue_of(&["one", "two", "three"]);
+        let id = queue[1].id;
+
+        let result = retry_queued(&mut queue, id, |_| Err("connection closed".to_string()));
+
+        assert_eq!(result, Some(Err("connection closed".to_string())));
+        assert_eq!(texts(&queue), vec!["one", "two", "three"]);
+        assert_eq!(queue[1].id, id);
+    }
+
+    #[test]
+    fn test_retry_queued_unknown_id() {
+        let mut queue = queue_of(&["one"]);
+
+        let result = retry_queued(&mut queue, Uuid::new_v4(), |_| {
+            panic!("nothing should be sent")
+        });
+
+        assert!(result.is_none());
+        assert_eq!(texts(&queue), vec!["one"]);
+    }
+
+    #[test]
+    fn test_cancel_queued() {
+        let mut queue = queue_of(&["one", "two", "three"]);
+        let id = queue[1].id;
+
+        cancel_queued(&mut queue, id);
+        assert_eq!(texts(&queue), vec!["one", "three"]);
+
+        // Cancelling twice or an unknown ID changes nothing
+        cancel_queued(&mut queue, id);
+        cancel_queued(&mut queue, Uuid::new_v4());
+        assert_eq!(texts(&queue), vec!["one", "three"]);
+    }
+}
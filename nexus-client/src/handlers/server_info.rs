@@ -7,14 +7,15 @@ use nexus_common::validators::{
     self, MAX_SERVER_DESCRIPTION_LENGTH, MAX_SERVER_NAME_LENGTH, ServerDescriptionError,
     ServerImageError, ServerNameError,
 };
-use rfd::AsyncFileDialog;
 
 use crate::NexusApp;
 use crate::i18n::{t, t_args};
-use crate::image::{ImagePickerError, decode_data_uri_max_width};
+use crate::image::{ImagePickerError, decode_data_uri_max_width, pick_image_data_uri};
 use crate::style::SERVER_IMAGE_MAX_CACHE_WIDTH;
 use crate::style::SERVER_IMAGE_MAX_SIZE;
-use crate::types::{InputId, Message, ServerInfoEditState, ServerInfoParams, ServerInfoTab};
+use crate::types::{
+    ConnectivityCheckState, InputId, Message, ServerInfoEditState, ServerInfoParams, ServerInfoTab,
+};
 
 impl NexusApp {
     // ==================== Panel Actions ====================
@@ -32,6 +33,24 @@ impl NexusApp {
         Task::none()
     }
 
+    /// Ask the server to test whether its ports are reachable from outside
+    ///
+    /// Only admins see the button. The result replaces the previous one.
+    pub fn handle_connectivity_check_pressed(&mut self) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        conn.connectivity_check = match conn.send(ClientMessage::ConnectivityCheck) {
+            Ok(_) => ConnectivityCheckState::Running,
+            Err(e) => ConnectivityCheckState::Failed(format!("{}: {}", t("err-send-failed"), e)),
+        };
+        Task::none()
+    }
+
     /// Enter server info edit mode
     ///
     /// Creates a new edit state with the current server info values.
@@ -55,6 +74,9 @@ impl NexusApp {
             description: conn.server_description.as_deref(),
             max_connections_per_ip: conn.max_connections_per_ip,
             max_transfers_per_ip: conn.max_transfers_per_ip,
+            max_active_transfers: conn.max_active_transfers,
+            transfer_small_files_first: conn.transfer_small_files_first,
+            trusted_transfer_weight: conn.trusted_transfer_weight,
             image: &conn.server_image,
             file_reindex_interval: conn.file_reindex_interval,
             persistent_channels: conn.persistent_channels.as_deref(),
@@ -154,6 +176,9 @@ impl NexusApp {
             description: conn.server_description.as_deref(),
             max_connections_per_ip: conn.max_connections_per_ip,
             max_transfers_per_ip: conn.max_transfers_per_ip,
+            max_active_transfers: conn.max_active_transfers,
+            transfer_small_files_first: conn.transfer_small_files_first,
+            trusted_transfer_weight: conn.trusted_transfer_weight,
             image: &conn.server_image,
             file_reindex_interval: conn.file_reindex_interval,
             persistent_channels: conn.persistent_channels.as_deref(),
@@ -191,6 +216,27 @@ impl NexusApp {
             None
         };
 
+        let max_active_transfers = if edit_state.max_active_transfers != conn.max_active_transfers {
+            edit_state.max_active_transfers
+        } else {
+            None
+        };
+
+        let transfer_small_files_first = if edit_state.transfer_small_files_first
+            != conn.transfer_small_files_first.unwrap_or(false)
+        {
+            Some(edit_state.transfer_small_files_first)
+        } else {
+            None
+        };
+
+        let trusted_transfer_weight =
+            if edit_state.trusted_transfer_weight != conn.trusted_transfer_weight {
+                edit_state.trusted_transfer_weight
+            } else {
+                None
+            };
+
         let image = if edit_state.image != conn.server_image {
             Some(edit_state.image.clone())
         } else {
@@ -224,6 +270,9 @@ impl NexusApp {
             description,
             max_connections_per_ip,
             max_transfers_per_ip,
+            max_active_transfers,
+            transfer_small_files_first,
+            trusted_transfer_weight,
             image,
             file_reindex_interval,
             persistent_channels,
@@ -300,6 +349,48 @@ impl NexusApp {
         Task::none()
     }
 
+    /// Handle server info max active transfers field change
+    pub fn handle_edit_server_info_max_active_transfers_changed(
+        &mut self,
+        max_active: u32,
+    ) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+            && let Some(edit_state) = &mut conn.server_info_edit
+        {
+            edit_state.max_active_transfers = Some(max_active);
+        }
+        Task::none()
+    }
+
+    /// Handle server info small files first toggle
+    pub fn handle_edit_server_info_small_files_first_toggled(
+        &mut self,
+        enabled: bool,
+    ) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+            && let Some(edit_state) = &mut conn.server_info_edit
+        {
+            edit_state.transfer_small_files_first = enabled;
+        }
+        Task::none()
+    }
+
+    /// Handle server info trusted transfer weight field change
+    pub fn handle_edit_server_info_trusted_transfer_weight_changed(
+        &mut self,
+        weight: u32,
+    ) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+            && let Some(edit_state) = &mut conn.server_info_edit
+        {
+            edit_state.trusted_transfer_weight = Some(weight);
+        }
+        Task::none()
+    }
+
     /// Handle server info file reindex interval field change
     pub fn handle_edit_server_info_file_reindex_interval_changed(
         &mut self,
@@ -357,53 +448,7 @@ impl NexusApp {
         }
 
         Task::perform(
-            async {
-                let handle = AsyncFileDialog::new()
-                    .add_filter("Images", &["png", "jpg", "jpeg", "webp", "svg"])
-                    .pick_file()
-                    .await;
-
-                match handle {
-                    Some(file) => {
-                        let path = file.path();
-                        let extension = path
-                            .extension()
-                            .and_then(|e| e.to_str())
-                            .unwrap_or("")
-                            .to_lowercase();
-
-                        // Determine MIME type from extension
-                        let mime_type = match extension.as_str() {
-                            "png" => "image/png",
-                            "jpg" | "jpeg" => "image/jpeg",
-                            "webp" => "image/webp",
-                            "svg" => "image/svg+xml",
-                            _ => return Err(ImagePickerError::UnsupportedType),
-                        };
-
-                        // Read file contents
-                        let bytes = file.read().await;
-
-                        // Check file size
-                        if bytes.len() > SERVER_IMAGE_MAX_SIZE {
-                            return Err(ImagePickerError::TooLarge);
-                        }
-
-                        // Validate file content matches expected format
-                        if !crate::image::validate_image_bytes(&bytes, mime_type) {
-                            return Err(ImagePickerError::UnsupportedType);
-                        }
-
-                        // Encode as data URI
-                        use base64::Engine;
-                        let base64_data = base64::engine::general_purpose::STANDARD.encode(&bytes);
-                        let data_uri = format!("data:{};base64,{}", mime_type, base64_data);
-
-                        Ok(data_uri)
-                    }
-                    None => Err(ImagePickerError::Cancelled),
-                }
-            },
+            pick_image_data_uri(SERVER_IMAGE_MAX_SIZE),
             Message::EditServerInfoImageLoaded,
         )
     }
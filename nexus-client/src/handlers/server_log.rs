@@ -0,0 +1,64 @@
+//! Server Log panel handlers
+
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+
+use crate::NexusApp;
+use crate::i18n::t;
+use crate::types::{ActivePanel, Message, ServerLogLevel};
+
+impl NexusApp {
+    /// Toggle the Server Log panel
+    ///
+    /// When opening, subscribes to the server log. The server replies with
+    /// recent lines and then streams new ones until the panel is left.
+    pub fn handle_toggle_server_log(&mut self) -> Task<Message> {
+        if self.active_panel() == ActivePanel::ServerLog {
+            return Task::none();
+        }
+
+        self.set_active_panel(ActivePanel::ServerLog);
+
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        conn.server_log.reset();
+        conn.server_log.loading = true;
+
+        if let Err(e) = conn.send(ClientMessage::LogSubscribe) {
+            conn.server_log.loading = false;
+            conn.server_log.error = Some(format!("{}: {}", t("err-send-failed"), e));
+        }
+
+        Task::none()
+    }
+
+    /// Close the Server Log panel (unsubscribes via `set_active_panel`)
+    pub fn handle_close_server_log(&mut self) -> Task<Message> {
+        self.handle_show_chat_view()
+    }
+
+    /// Change the least severe level shown
+    pub fn handle_server_log_level_selected(&mut self, level: ServerLogLevel) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+        {
+            conn.server_log.level = level.0;
+        }
+        Task::none()
+    }
+
+    /// Change the module filter
+    pub fn handle_server_log_module_filter_changed(&mut self, filter: String) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+        {
+            conn.server_log.module_filter = filter;
+        }
+        Task::none()
+    }
+}
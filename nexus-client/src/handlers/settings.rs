@@ -4,26 +4,33 @@ use iced_toasts::{ToastLevel, toast};
 
 use crate::config::audio::PttReleaseDelay;
 
-#[cfg(all(unix, not(target_os = "macos")))]
-use std::time::Instant;
-
+use chrono::{Local, Timelike};
 use iced::Task;
 use iced::widget::{Id, operation};
+use nexus_common::protocol::ClientMessage;
 use nexus_common::voice::VoiceQuality;
 use rfd::AsyncFileDialog;
+use uuid::Uuid;
 
 use crate::NexusApp;
 use crate::config::audio::PttMode;
-use crate::config::events::{EventType, NotificationContent, SoundChoice};
+use crate::config::encryption::{self, KeyChange};
+use crate::config::events::{
+    ChannelNotification, EventType, NotificationContent, NotificationRules, QuietHour, SoundChoice,
+};
 use crate::config::settings::{
-    AVATAR_MAX_SIZE, CHAT_FONT_SIZE_MAX, CHAT_FONT_SIZE_MIN, default_download_path,
+    AVATAR_MAX_SIZE, CHAT_FONT_SIZE_MAX, CHAT_FONT_SIZE_MIN, CollisionPolicy, default_download_path,
 };
 use crate::i18n::{t, t_args};
-use crate::image::{ImagePickerError, decode_data_uri_square};
+use crate::image::{ImagePickerError, decode_data_uri_square, pick_image_data_uri};
 use crate::style::AVATAR_MAX_CACHE_SIZE;
-use crate::types::{ActivePanel, ChatMessage, InputId, Message, SettingsFormState, SettingsTab};
+use crate::types::{
+    ActivePanel, ChatMessage, InputId, Message, ServerBookmark, SettingsFormState, SettingsTab,
+};
 use crate::voice::audio::AudioDevice;
 
+use super::files::sanitize_filename;
+
 impl NexusApp {
     // ==================== Settings Panel ====================
 
@@ -42,6 +49,15 @@ impl NexusApp {
             self.settings_tab,
             self.selected_event_type,
         ));
+
+        // Show the current server's notification rules when it's bookmarked
+        let active_bookmark = self
+            .active_connection
+            .and_then(|id| self.connections.get(&id))
+            .and_then(|conn| conn.bookmark_id);
+        if let (Some(form), Some(id)) = (&mut self.settings_form, active_bookmark) {
+            form.rules_bookmark = Some(id);
+        }
         self.set_active_panel(ActivePanel::Settings);
 
         // Focus the appropriate field for the active tab
@@ -67,6 +83,10 @@ impl NexusApp {
                 // Files tab has no text input fields (only browse button)
                 Task::none()
             }
+            SettingsTab::Security => {
+                // Security tab has no text input fields (only a checkbox and buttons)
+                Task::none()
+            }
             SettingsTab::Events => {
                 // Events tab has no text input fields (only pickers and checkboxes)
                 Task::none()
@@ -81,20 +101,58 @@ impl NexusApp {
     /// Cancel settings panel and restore original config
     pub fn handle_cancel_settings(&mut self) -> Task<Message> {
         // Restore original config from snapshot
+        let window_key = self.config.settings.toggle_window_key.clone();
         if let Some(settings_form) = self.settings_form.take() {
             self.config = settings_form.original_config;
         }
 
+        // Do not disturb may have been toggled while editing
+        self.sync_do_not_disturb();
+
+        // The show/hide window key may have been changed while editing
+        if self.config.settings.toggle_window_key != window_key {
+            let hotkey_task = self.register_window_hotkey();
+            return Task::batch([hotkey_task, self.handle_show_chat_view()]);
+        }
+
         self.handle_show_chat_view()
     }
 
     /// Save settings to disk and close panel
+    ///
+    /// A config encryption change is applied first; if the passphrase is
+    /// missing or doesn't match, the panel stays open with the error.
     pub fn handle_save_settings(&mut self) -> Task<Message> {
+        let key_change = match self.apply_config_encryption() {
+            Ok(key_change) => key_change,
+            Err(error) => {
+                if let Some(form) = &mut self.settings_form {
+                    form.error = Some(error);
+                }
+                return Task::none();
+            }
+        };
+
         // Clear the snapshot (no need to restore)
         self.settings_form = None;
 
-        // Save config to disk
-        if let Err(e) = self.config.save() {
+        // Save config to disk; chat history only moves to a new master key
+        // once the config (and the key's salt) is safely written
+        let saved = self.config.save();
+        if let Some(key_change) = key_change {
+            let switched = if saved.is_ok() {
+                key_change.commit()
+            } else {
+                key_change.roll_back()
+            };
+            if let Err(e) = switched {
+                self.connection_form.error = Some(t_args(
+                    "err-config-encryption",
+                    &[("error", &e.to_string())],
+                ));
+            }
+        }
+        if let Err(e) = saved {
             self.connection_form.error = Some(t_args(
                 "err-failed-save-settings",
                 &[("error", &e.to_string())],
@@ -168,6 +226,24 @@ impl NexusApp {
         Task::none()
     }
 
+    /// Handle user list grouping selection from the picker
+    pub fn handle_user_list_grouping_selected(
+        &mut self,
+        grouping: crate::config::settings::UserListGrouping,
+    ) -> Task<Message> {
+        self.config.settings.user_list.grouping = grouping;
+        Task::none()
+    }
+
+    /// Handle user list sort mode selection from the picker
+    pub fn handle_user_list_sort_selected(
+        &mut self,
+        sort: crate::config::settings::UserListSort,
+    ) -> Task<Message> {
+        self.config.settings.user_list.sort = sort;
+        Task::none()
+    }
+
     // ==================== Timestamps ====================
 
     /// Handle show timestamps toggle
@@ -188,6 +264,31 @@ impl NexusApp {
         Task::none()
     }
 
+    // ==================== Split View ====================
+
+    /// Handle split view toggle
+    pub fn handle_split_view_toggled(&mut self, enabled: bool) -> Task<Message> {
+        self.config.settings.split_view = enabled;
+        Task::none()
+    }
+
+    // ==================== Spell Check ====================
+
+    /// Handle spell check toggle
+    pub fn handle_spell_check_toggled(&mut self, enabled: bool) -> Task<Message> {
+        self.config.settings.spell_check = enabled;
+        self.ui_state.spell_menu = None;
+        Task::none()
+    }
+
+    // ==================== Read Receipts ====================
+
+    /// Handle read receipts toggle
+    pub fn handle_read_receipts_toggled(&mut self, enabled: bool) -> Task<Message> {
+        self.config.settings.send_read_receipts = enabled;
+        Task::none()
+    }
+
     // ==================== Nickname ====================
 
     /// Handle nickname field change in settings panel
@@ -249,6 +350,7 @@ impl NexusApp {
     /// - Chat tab: no focusable fields (only checkboxes/pickers)
     /// - Network tab: address -> username -> password (skips port NumberInput)
     /// - Files tab: no focusable fields (only browse button)
+    /// - Security tab: passphrase <-> confirmation (when setting up encryption)
     pub fn handle_settings_tab_pressed(&mut self) -> Task<Message> {
         match self.settings_tab {
             SettingsTab::General => {
@@ -264,6 +366,23 @@ impl NexusApp {
                 // Files tab has no text input fields, just a browse button
                 Task::none()
             }
+            SettingsTab::Security => {
+                // Passphrase fields are only shown while setting up encryption
+                let showing_passphrase = self
+                    .settings_form
+                    .as_ref()
+                    .is_some_and(|form| form.encrypt_config && !form.config_encrypted);
+                if !showing_passphrase {
+                    return Task::none();
+                }
+                let next_field = if self.focused_field == InputId::ConfigPassphrase {
+                    InputId::ConfigPassphraseConfirm
+                } else {
+                    InputId::ConfigPassphrase
+                };
+                self.focused_field = next_field;
+                operation::focus(Id::from(next_field))
+            }
             SettingsTab::Events => {
                 // Events tab has no text input fields, just pickers and checkboxes
                 Task::none()
@@ -336,58 +455,7 @@ impl NexusApp {
             form.error = None;
         }
 
-        Task::future(async {
-            let file = AsyncFileDialog::new()
-                .add_filter("Images", &["png", "webp", "svg", "jpg", "jpeg"])
-                .pick_file()
-                .await;
-
-            match file {
-                Some(handle) => {
-                    let path = handle.path();
-                    let extension = path
-                        .extension()
-                        .and_then(|e| e.to_str())
-                        .unwrap_or("")
-                        .to_lowercase();
-
-                    // Determine MIME type from extension
-                    let mime_type = match extension.as_str() {
-                        "png" => "image/png",
-                        "webp" => "image/webp",
-                        "svg" => "image/svg+xml",
-                        "jpg" | "jpeg" => "image/jpeg",
-                        _ => {
-                            return Message::AvatarLoaded(Err(ImagePickerError::UnsupportedType));
-                        }
-                    };
-
-                    // Read file contents
-                    let bytes = handle.read().await;
-
-                    // Check size
-                    if bytes.len() > AVATAR_MAX_SIZE {
-                        return Message::AvatarLoaded(Err(ImagePickerError::TooLarge));
-                    }
-
-                    // Validate file content matches expected format
-                    if !crate::image::validate_image_bytes(&bytes, mime_type) {
-                        return Message::AvatarLoaded(Err(ImagePickerError::UnsupportedType));
-                    }
-
-                    // Build data URI
-                    use base64::Engine;
-                    let base64_data = base64::engine::general_purpose::STANDARD.encode(&bytes);
-                    let data_uri = format!("data:{};base64,{}", mime_type, base64_data);
-
-                    Message::AvatarLoaded(Ok(data_uri))
-                }
-                None => {
-                    // User cancelled - no change
-                    Message::AvatarLoaded(Err(ImagePickerError::Cancelled))
-                }
-            }
-        })
+        Task::perform(pick_image_data_uri(AVATAR_MAX_SIZE), Message::AvatarLoaded)
     }
 
     /// Handle avatar loaded from file picker
@@ -501,6 +569,18 @@ impl NexusApp {
         Task::none()
     }
 
+    /// Handle auto-open folder checkbox toggle
+    pub fn handle_auto_open_folder_toggled(&mut self, enabled: bool) -> Task<Message> {
+        self.config.settings.auto_open_folder = enabled;
+        Task::none()
+    }
+
+    /// Handle file collision policy selection
+    pub fn handle_collision_policy_selected(&mut self, policy: CollisionPolicy) -> Task<Message> {
+        self.config.settings.collision_policy = policy;
+        Task::none()
+    }
+
     // =========================================================================
     // Event Settings Handlers
     // =========================================================================
@@ -555,31 +635,14 @@ impl NexusApp {
             let (summary, body) =
                 crate::events::build_test_event_content(event_type, config.notification_content);
 
-            // Show the notification
-            let mut notification = notify_rust::Notification::new();
-            notification
-                .appname("Nexus BBS")
-                .summary(&summary)
-                .body(body.as_deref().unwrap_or(""))
-                .auto_icon()
-                .timeout(notify_rust::Timeout::Milliseconds(5000));
-
-            // On Linux, keep handle alive to prevent GNOME/Cinnamon from dismissing
-            // notifications when the D-Bus connection would otherwise be dropped.
-            #[cfg(all(unix, not(target_os = "macos")))]
-            if let Ok(handle) = notification.show()
-                && let Ok(mut handles) = crate::events::NOTIFICATION_HANDLES.lock()
-            {
-                let now = Instant::now();
-                handles.retain(|(created, _)| {
-                    now.duration_since(*created) < crate::events::HANDLE_LIFETIME
-                });
-                handles.push((now, handle));
+            // Show the notification the same way real events are shown
+            #[cfg(any(target_os = "macos", target_os = "windows"))]
+            if crate::notifications::is_available() {
+                crate::notifications::show(&summary, body.as_deref(), None);
+                return Task::none();
             }
 
-            // On non-Linux platforms, just show and ignore result
-            #[cfg(not(all(unix, not(target_os = "macos"))))]
-            let _ = notification.show();
+            crate::events::show_desktop_notification(&summary, body.as_deref());
         }
         Task::none()
     }
@@ -688,6 +751,310 @@ impl NexusApp {
         Task::none()
     }
 
+    // =========================================================================
+    // Notification Rules Handlers
+    // =========================================================================
+
+    /// Handle bookmark selection for the notification rules editor
+    pub fn handle_notification_rules_bookmark_selected(&mut self, id: Uuid) -> Task<Message> {
+        if let Some(form) = &mut self.settings_form {
+            form.rules_bookmark = Some(id);
+            form.rules_channel_input.clear();
+            form.rules_keyword_input.clear();
+        }
+        Task::none()
+    }
+
+    /// Handle channel override input change
+    pub fn handle_notification_rules_channel_changed(&mut self, channel: String) -> Task<Message> {
+        if let Some(form) = &mut self.settings_form {
+            form.rules_channel_input = channel;
+        }
+        Task::none()
+    }
+
+    /// Handle adding a channel override (starts at mentions only)
+    pub fn handle_notification_rules_channel_add(&mut self) -> Task<Message> {
+        let Some(form) = &mut self.settings_form else {
+            return Task::none();
+        };
+        let Some(rules) = form
+            .rules_bookmark
+            .and_then(|id| self.config.get_bookmark_mut(id))
+            .map(|bookmark| &mut bookmark.notification_rules)
+        else {
+            return Task::none();
+        };
+
+        // Keep an existing override's level
+        let channel = form.rules_channel_input.clone();
+        let level = rules
+            .channel(&channel)
+            .unwrap_or(ChannelNotification::MentionsOnly);
+        if rules.set_channel(&channel, Some(level)) {
+            form.rules_channel_input.clear();
+        }
+        Task::none()
+    }
+
+    /// Handle level selection for a channel override
+    pub fn handle_notification_rules_channel_level_selected(
+        &mut self,
+        channel: String,
+        level: ChannelNotification,
+    ) -> Task<Message> {
+        if let Some(rules) = self.settings_notification_rules_mut() {
+            rules.set_channel(&channel, Some(level));
+        }
+        Task::none()
+    }
+
+    /// Handle removing a channel override
+    pub fn handle_notification_rules_channel_remove(&mut self, channel: String) -> Task<Message> {
+        if let Some(rules) = self.settings_notification_rules_mut() {
+            rules.set_channel(&channel, None);
+        }
+        Task::none()
+    }
+
+    /// Handle keyword input change
+    pub fn handle_notification_rules_keyword_changed(&mut self, keyword: String) -> Task<Message> {
+        if let Some(form) = &mut self.settings_form {
+            form.rules_keyword_input = keyword;
+        }
+        Task::none()
+    }
+
+    /// Handle adding a keyword to the watch list
+    pub fn handle_notification_rules_keyword_add(&mut self) -> Task<Message> {
+        let Some(form) = &mut self.settings_form else {
+            return Task::none();
+        };
+        let Some(rules) = form
+            .rules_bookmark
+            .and_then(|id| self.config.get_bookmark_mut(id))
+            .map(|bookmark| &mut bookmark.notification_rules)
+        else {
+            return Task::none();
+        };
+
+        if rules.add_keyword(&form.rules_keyword_input) {
+            form.rules_keyword_input.clear();
+        }
+        Task::none()
+    }
+
+    /// Handle removing a keyword from the watch list
+    pub fn handle_notification_rules_keyword_remove(&mut self, keyword: String) -> Task<Message> {
+        if let Some(rules) = self.settings_notification_rules_mut() {
+            rules.remove_keyword(&keyword);
+        }
+        Task::none()
+    }
+
+    /// Get the rules of the bookmark selected in the Events tab
+    fn settings_notification_rules_mut(&mut self) -> Option<&mut NotificationRules> {
+        let id = self.settings_form.as_ref()?.rules_bookmark?;
+        self.config
+            .get_bookmark_mut(id)
+            .map(|bookmark| &mut bookmark.notification_rules)
+    }
+
+    // =========================================================================
+    // Known Servers Handlers
+    // =========================================================================
+
+    /// Handle require new certificate approval checkbox toggle
+    pub fn handle_require_fingerprint_approval_toggled(&mut self, enabled: bool) -> Task<Message> {
+        self.config.settings.require_fingerprint_approval = enabled;
+        Task::none()
+    }
+
+    /// Show or hide a known server's certificate details
+    pub fn handle_known_server_details_toggled(&mut self, id: Uuid) -> Task<Message> {
+        if let Some(form) = &mut self.settings_form {
+            form.known_server_expanded = (form.known_server_expanded != Some(id)).then_some(id);
+        }
+        Task::none()
+    }
+
+    /// Forget a known server's certificate
+    ///
+    /// Like other settings, this takes effect on Save. The next connection
+    /// then treats the server as new.
+    pub fn handle_known_server_remove_pressed(&mut self, id: Uuid) -> Task<Message> {
+        if let Some(bookmark) = self.config.get_bookmark_mut(id) {
+            bookmark.forget_certificate();
+        }
+        Task::none()
+    }
+
+    /// Export a known server's certificate details to a text file
+    pub fn handle_known_server_export_pressed(&mut self, id: Uuid) -> Task<Message> {
+        let Some(bookmark) = self.config.get_bookmark(id) else {
+            return Task::none();
+        };
+        let Some(contents) = known_server_export(bookmark) else {
+            return Task::none();
+        };
+        let file_name = format!(
+            "{}.txt",
+            sanitize_filename(&bookmark.name, &bookmark.address)
+        );
+
+        Task::future(async move {
+            let Some(handle) = AsyncFileDialog::new()
+                .set_file_name(file_name)
+                .save_file()
+                .await
+            else {
+                // User cancelled - nothing to do
+                return Message::KnownServerExported(Ok(()));
+            };
+            let result = handle
+                .write(contents.as_bytes())
+                .await
+                .map_err(|e| e.to_string());
+            Message::KnownServerExported(result)
+        })
+    }
+
+    /// Handle certificate export finished
+    pub fn handle_known_server_exported(&mut self, result: Result<(), String>) -> Task<Message> {
+        if let (Err(error), Some(form)) = (result, &mut self.settings_form) {
+            form.error = Some(t_args("err-known-server-export", &[("error", &error)]));
+        }
+        Task::none()
+    }
+
+    // =========================================================================
+    // Config Encryption Handlers
+    // =========================================================================
+
+    /// Handle config encryption checkbox toggle
+    ///
+    /// Nothing changes on disk until Save. Turning it on asks for a new
+    /// master passphrase unless the config is already encrypted.
+    pub fn handle_config_encryption_toggled(&mut self, enabled: bool) -> Task<Message> {
+        let Some(form) = &mut self.settings_form else {
+            return Task::none();
+        };
+        form.encrypt_config = enabled;
+        form.config_passphrase.clear();
+        form.config_passphrase_confirm.clear();
+
+        if enabled && !form.config_encrypted {
+            self.focused_field = InputId::ConfigPassphrase;
+            return operation::focus(Id::from(InputId::ConfigPassphrase));
+        }
+        Task::none()
+    }
+
+    /// Handle config encryption passphrase input change
+    pub fn handle_config_passphrase_changed(&mut self, value: String) -> Task<Message> {
+        self.focused_field = InputId::ConfigPassphrase;
+        if let Some(form) = &mut self.settings_form {
+            form.config_passphrase = value;
+        }
+        Task::none()
+    }
+
+    /// Handle config encryption passphrase confirmation input change
+    pub fn handle_config_passphrase_confirm_changed(&mut self, value: String) -> Task<Message> {
+        self.focused_field = InputId::ConfigPassphraseConfirm;
+        if let Some(form) = &mut self.settings_form {
+            form.config_passphrase_confirm = value;
+        }
+        Task::none()
+    }
+
+    /// Turn config encryption on or off to match the settings form
+    ///
+    /// Returns the key change to commit once the config is saved (None when
+    /// encryption stays as it was), or an error message if the new
+    /// passphrase can't be used.
+    fn apply_config_encryption(&mut self) -> Result<Option<KeyChange>, String> {
+        let Some(form) = &self.settings_form else {
+            return Ok(None);
+        };
+        if form.config_locked || form.encrypt_config == form.config_encrypted {
+            return Ok(None);
+        }
+
+        let key_change = if form.encrypt_config {
+            if form.config_passphrase.is_empty() {
+                return Err(t("err-master-passphrase-empty"));
+            }
+            if form.config_passphrase != form.config_passphrase_confirm {
+                return Err(t("err-passwords-do-not-match"));
+            }
+            encryption::enable(&form.config_passphrase)
+        } else {
+            encryption::disable()
+        };
+        key_change
+            .map(Some)
+            .map_err(|e| t_args("err-config-encryption", &[("error", &e.to_string())]))
+    }
+
+    // =========================================================================
+    // Do Not Disturb Handlers
+    // =========================================================================
+
+    /// Whether do not disturb is on, manually or through quiet hours
+    pub fn do_not_disturb_active(&self) -> bool {
+        let settings = &self.config.settings;
+        settings.do_not_disturb || settings.quiet_hours.contains(Local::now().hour() as u8)
+    }
+
+    /// Advertise the current do not disturb state to servers that haven't seen it
+    pub fn sync_do_not_disturb(&mut self) {
+        let active = self.do_not_disturb_active();
+        for conn in self.connections.values_mut() {
+            if conn.dnd_advertised != active
+                && conn
+                    .send(ClientMessage::UserDnd { enabled: active })
+                    .is_ok()
+            {
+                conn.dnd_advertised = active;
+            }
+        }
+    }
+
+    /// Handle do not disturb toggle
+    pub fn handle_do_not_disturb_toggled(&mut self, enabled: bool) -> Task<Message> {
+        self.config.settings.do_not_disturb = enabled;
+        self.sync_do_not_disturb();
+        Task::none()
+    }
+
+    /// Handle quiet hours schedule toggle
+    pub fn handle_quiet_hours_toggled(&mut self, enabled: bool) -> Task<Message> {
+        self.config.settings.quiet_hours.enabled = enabled;
+        self.sync_do_not_disturb();
+        Task::none()
+    }
+
+    /// Handle quiet hours start selection
+    pub fn handle_quiet_hours_start_selected(&mut self, hour: QuietHour) -> Task<Message> {
+        self.config.settings.quiet_hours.start = hour;
+        self.sync_do_not_disturb();
+        Task::none()
+    }
+
+    /// Handle quiet hours end selection
+    pub fn handle_quiet_hours_end_selected(&mut self, hour: QuietHour) -> Task<Message> {
+        self.config.settings.quiet_hours.end = hour;
+        self.sync_do_not_disturb();
+        Task::none()
+    }
+
+    /// Handle the periodic quiet hours check
+    pub fn handle_quiet_hours_tick(&mut self) -> Task<Message> {
+        self.sync_do_not_disturb();
+        Task::none()
+    }
+
     // =========================================================================
     // Audio Settings Handlers
     // =========================================================================
@@ -762,6 +1129,7 @@ impl NexusApp {
     pub fn handle_audio_ptt_key_capture(&mut self) -> Task<Message> {
         if let Some(form) = &mut self.settings_form {
             form.ptt_capturing = true;
+            form.window_key_capturing = false;
         }
         Task::none()
     }
@@ -773,8 +1141,10 @@ impl NexusApp {
         }
         self.config.settings.audio.ptt_key = key.clone();
 
-        // Re-register hotkey immediately if in voice (applies without rejoin)
-        if let Some(ref mut ptt) = self.ptt_manager
+        // Re-register hotkey immediately if in voice (applies without rejoin).
+        // Portal shortcuts are rebound by their subscription instead.
+        if !self.portal_shortcuts_active()
+            && let Some(ref mut ptt) = self.ptt_manager
             && let Some(connection_id) = self.active_voice_connection
             && let Err(e) = ptt.register_hotkey(&key)
         {
@@ -787,6 +1157,33 @@ impl NexusApp {
         Task::none()
     }
 
+    /// Enter show/hide window key capture mode
+    pub fn handle_window_key_capture(&mut self) -> Task<Message> {
+        if let Some(form) = &mut self.settings_form {
+            form.window_key_capturing = true;
+            form.ptt_capturing = false;
+        }
+        Task::none()
+    }
+
+    /// Handle show/hide window key captured (applies immediately)
+    pub fn handle_window_key_captured(&mut self, key: String) -> Task<Message> {
+        if let Some(form) = &mut self.settings_form {
+            form.window_key_capturing = false;
+        }
+        self.config.settings.toggle_window_key = Some(key);
+        self.register_window_hotkey()
+    }
+
+    /// Handle show/hide window key cleared
+    pub fn handle_window_key_cleared(&mut self) -> Task<Message> {
+        if let Some(form) = &mut self.settings_form {
+            form.window_key_capturing = false;
+        }
+        self.config.settings.toggle_window_key = None;
+        self.register_window_hotkey()
+    }
+
     /// Handle PTT mode selection
     pub fn handle_audio_ptt_mode_selected(&mut self, mode: PttMode) -> Task<Message> {
         self.config.settings.audio.ptt_mode = mode;
@@ -871,3 +1268,24 @@ impl NexusApp {
         Task::none()
     }
 }
+
+/// Plain text description of a bookmark's pinned certificate, for export
+///
+/// Returns None if no certificate is pinned.
+fn known_server_export(bookmark: &ServerBookmark) -> Option<String> {
+    let fingerprint = bookmark.certificate_fingerprint.as_deref()?;
+    let mut contents = format!(
+        "{}\n{}:{}\nSHA-256 fingerprint: {}\n",
+        bookmark.name, bookmark.address, bookmark.port, fingerprint
+    );
+    if let Some(identity_key) = &bookmark.identity_key {
+        contents.push_str(&format!("Identity key: {identity_key}\n"));
+    }
+    if let Some(first_seen) = bookmark
+        .certificate_first_seen
+        .and_then(|timestamp| chrono::DateTime::from_timestamp(timestamp, 0))
+    {
+        contents.push_str(&format!("First seen: {}\n", first_seen.to_rfc3339()));
+    }
+    Some(contents)
+}
@@ -0,0 +1,30 @@
+//! Snippet panel handlers
+
+use iced::Task;
+
+use crate::NexusApp;
+use crate::i18n::t;
+use crate::types::{ActivePanel, Message};
+
+impl NexusApp {
+    /// Close the Snippet panel, returning to chat
+    pub fn handle_close_snippet(&mut self) -> Task<Message> {
+        self.set_active_panel(ActivePanel::None);
+        Task::none()
+    }
+
+    /// Copy the shown snippet's text to the clipboard
+    pub fn handle_copy_snippet(&mut self) -> Task<Message> {
+        let Some(snippet) = self
+            .active_connection
+            .and_then(|conn_id| self.connections.get(&conn_id))
+            .and_then(|conn| conn.snippet_view.snippet.as_ref())
+        else {
+            return Task::none();
+        };
+
+        let toast_text = t("toast-copied");
+        iced::clipboard::write(snippet.content.clone())
+            .chain(Task::done(Message::ShowToast(toast_text)))
+    }
+}
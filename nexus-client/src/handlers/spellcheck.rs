@@ -0,0 +1,108 @@
+//! Spell checking dictionary and right-click suggestions
+
+use std::sync::Arc;
+
+use iced::widget::text_editor::{self, Action, Edit};
+use iced::{Point, Task, Vector};
+
+use crate::NexusApp;
+use crate::spellcheck::{SpellChecker, SpellSettings, words};
+use crate::style::{INPUT_PADDING, NEWS_EDITOR_PADDING};
+use crate::types::{Message, SpellField, SpellMenu};
+
+impl NexusApp {
+    /// Keep the dictionary loaded at startup (None if none was found)
+    pub fn handle_spell_checker_loaded(
+        &mut self,
+        checker: Option<Arc<SpellChecker>>,
+    ) -> Task<Message> {
+        self.spell_checker = checker;
+        self.spell_checker_loaded = true;
+        Task::none()
+    }
+
+    /// Dictionary the editors are checked against
+    ///
+    /// Empty when spell checking is off or no dictionary was found.
+    pub fn spell_settings(&self) -> SpellSettings {
+        SpellSettings(
+            self.config
+                .settings
+                .spell_check
+                .then(|| self.spell_checker.clone())
+                .flatten(),
+        )
+    }
+
+    /// Handle a right-click in a spell-checked editor
+    ///
+    /// Selects the word under the pointer and, if it's misspelled, fills in
+    /// the suggestions menu. `position` is relative to the editor's bounds.
+    pub fn handle_spell_menu_opened(
+        &mut self,
+        field: SpellField,
+        position: Point,
+    ) -> Task<Message> {
+        self.ui_state.spell_menu = None;
+        let Some(checker) = self.spell_settings().0 else {
+            return Task::none();
+        };
+        let padding = match field {
+            SpellField::ChatInput => INPUT_PADDING,
+            SpellField::NewsBody => NEWS_EDITOR_PADDING,
+        };
+        let Some(content) = self.spell_field_content(field) else {
+            return Task::none();
+        };
+
+        content.perform(Action::Click(position - Vector::new(padding, padding)));
+        content.perform(Action::SelectWord);
+        let Some(word) = content.selection() else {
+            return Task::none();
+        };
+
+        // Only words the highlighter would mark (not links, acronyms, ...)
+        let is_word = words(&word).first() == Some(&(0..word.len()));
+        if is_word && !checker.check(&word) {
+            self.ui_state.spell_menu = Some(SpellMenu {
+                field,
+                suggestions: checker.suggest(&word),
+                word,
+            });
+        }
+        Task::none()
+    }
+
+    /// Replace the right-clicked word with a suggestion
+    ///
+    /// Nothing happens if the word is no longer selected (the text or
+    /// selection changed while the menu was open).
+    pub fn handle_spell_suggestion_chosen(&mut self, suggestion: String) -> Task<Message> {
+        let Some(spell_menu) = self.ui_state.spell_menu.take() else {
+            return Task::none();
+        };
+        let Some(content) = self.spell_field_content(spell_menu.field) else {
+            return Task::none();
+        };
+        if content.selection().as_deref() != Some(spell_menu.word.as_str()) {
+            return Task::none();
+        }
+
+        let paste = Action::Edit(Edit::Paste(Arc::new(suggestion)));
+        match spell_menu.field {
+            SpellField::ChatInput => self.handle_chat_input_action(paste),
+            SpellField::NewsBody => self.handle_news_body_action(paste),
+        }
+    }
+
+    /// Editor content for a spell-checked field on the active connection
+    fn spell_field_content(&mut self, field: SpellField) -> Option<&mut text_editor::Content> {
+        match field {
+            SpellField::ChatInput => Some(&mut self.chat_input_content),
+            SpellField::NewsBody => {
+                let conn_id = self.active_connection?;
+                self.news_body_content.get_mut(&conn_id)
+            }
+        }
+    }
+}
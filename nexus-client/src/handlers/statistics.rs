@@ -0,0 +1,63 @@
+//! Statistics panel handlers
+
+use iced::Task;
+use nexus_common::protocol::ClientMessage;
+
+use crate::NexusApp;
+use crate::i18n::t;
+use crate::types::{ActivePanel, Message, StatsMetric, StatsRange};
+
+impl NexusApp {
+    /// Show the Statistics panel and request the selected range
+    pub fn handle_show_statistics(&mut self) -> Task<Message> {
+        self.set_active_panel(ActivePanel::Statistics);
+        self.request_statistics();
+        Task::none()
+    }
+
+    /// Close the Statistics panel, returning to Server Info where it was opened
+    pub fn handle_close_statistics(&mut self) -> Task<Message> {
+        self.set_active_panel(ActivePanel::ServerInfo);
+        Task::none()
+    }
+
+    /// Change the report length and request it
+    pub fn handle_statistics_range_selected(&mut self, range: StatsRange) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+        {
+            conn.statistics.range = range;
+        }
+        self.request_statistics();
+        Task::none()
+    }
+
+    /// Change the metric shown in the chart
+    pub fn handle_statistics_metric_selected(&mut self, metric: StatsMetric) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+        {
+            conn.statistics.metric = metric;
+        }
+        Task::none()
+    }
+
+    /// Send a StatsRequest for the selected range
+    fn request_statistics(&mut self) {
+        let Some(conn_id) = self.active_connection else {
+            return;
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return;
+        };
+
+        let days = conn.statistics.range.0;
+        conn.statistics.error = None;
+        conn.statistics.loading = true;
+
+        if let Err(e) = conn.send(ClientMessage::StatsRequest { days }) {
+            conn.statistics.loading = false;
+            conn.statistics.error = Some(format!("{}: {}", t("err-send-failed"), e));
+        }
+    }
+}
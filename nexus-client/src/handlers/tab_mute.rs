@@ -0,0 +1,62 @@
+//! Chat tab muting
+//!
+//! Muted channel and user message tabs aren't marked unread and don't
+//! notify. Mutes are saved in the bookmark's notification rules, so tabs on
+//! connections without a bookmark can't be muted.
+
+use iced::Task;
+
+use crate::NexusApp;
+use crate::i18n::t_args;
+use crate::types::{ChatMessage, ChatTab, Message};
+
+impl NexusApp {
+    /// Whether a chat tab on a connection is muted
+    pub fn is_chat_tab_muted(&self, connection_id: usize, tab: &ChatTab) -> bool {
+        self.connections
+            .get(&connection_id)
+            .and_then(|conn| conn.bookmark_id)
+            .and_then(|id| self.config.get_bookmark(id))
+            .is_some_and(|bookmark| bookmark.notification_rules.is_tab_muted(tab))
+    }
+
+    /// Handle muting or unmuting a chat tab on the active connection
+    ///
+    /// Muting also clears the tab's unread marker.
+    pub fn handle_toggle_chat_tab_mute(&mut self, tab: ChatTab) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(rules) = self
+            .connections
+            .get(&conn_id)
+            .and_then(|conn| conn.bookmark_id)
+            .and_then(|id| self.config.get_bookmark_mut(id))
+            .map(|bookmark| &mut bookmark.notification_rules)
+        else {
+            return Task::none();
+        };
+
+        let muted = !rules.is_tab_muted(&tab);
+        rules.set_tab_muted(&tab, muted);
+
+        if muted && let Some(conn) = self.connections.get_mut(&conn_id) {
+            conn.unread_tabs.remove(&tab);
+            conn.mention_tabs.remove(&tab);
+
+            // Update tray icon state (Windows/Linux only)
+            #[cfg(not(target_os = "macos"))]
+            self.update_tray_state();
+
+            // Update dock badge count (macOS only)
+            #[cfg(target_os = "macos")]
+            self.update_dock_badge();
+        }
+
+        if let Err(e) = self.config.save() {
+            let error_msg = t_args("err-failed-save-config", &[("error", &e.to_string())]);
+            return self.add_active_tab_message(conn_id, ChatMessage::error(error_msg));
+        }
+        Task::none()
+    }
+}
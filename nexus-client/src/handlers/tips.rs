@@ -0,0 +1,31 @@
+//! First-run tip handlers
+
+use iced::Task;
+
+use crate::NexusApp;
+use crate::config::settings::Tip;
+use crate::types::Message;
+
+impl NexusApp {
+    /// Dismiss one first-run tip (the next panel tip shows in its place)
+    pub fn handle_tip_dismissed(&mut self, tip: Tip) -> Task<Message> {
+        self.dismiss_tip(tip);
+        Task::none()
+    }
+
+    /// Dismiss all remaining first-run tips
+    pub fn handle_tips_skipped(&mut self) -> Task<Message> {
+        if !self.config.settings.pending_tips.is_empty() {
+            self.config.settings.pending_tips.clear();
+            let _ = self.config.save();
+        }
+        Task::none()
+    }
+
+    /// Mark a first-run tip as seen, saving the config if it was pending
+    pub fn dismiss_tip(&mut self, tip: Tip) {
+        if self.config.settings.dismiss_tip(tip) {
+            let _ = self.config.save();
+        }
+    }
+}
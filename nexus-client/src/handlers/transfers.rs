@@ -1,14 +1,19 @@
 //! Transfer message handlers
 
 use iced::Task;
+use iced_toasts::{ToastLevel, toast};
 use uuid::Uuid;
 
 use crate::NexusApp;
 use crate::config::events::EventType;
+use crate::desktop;
 use crate::events::{EventContext, emit_event};
-use crate::i18n::t;
-use crate::transfers::{TransferDirection, TransferEvent, TransferStatus, request_cancel};
-use crate::types::{ActivePanel, Message};
+use crate::i18n::{t, t_args};
+use crate::transfers::{
+    CollisionPolicy, TransferDirection, TransferEvent, TransferRecord, TransferStatus,
+    request_cancel,
+};
+use crate::types::{ActivePanel, ChatMessage, Message};
 
 impl NexusApp {
     /// Handle transfer progress event from executor
@@ -19,6 +24,11 @@ impl NexusApp {
                 self.save_transfers();
             }
 
+            TransferEvent::ServerQueued { id, position } => {
+                // Queue position is transient, no need to save
+                self.transfer_manager.set_server_queued(id, position);
+            }
+
             TransferEvent::Started {
                 id,
                 total_bytes,
@@ -47,12 +57,22 @@ impl NexusApp {
                     current_file,
                 );
                 // Don't save on every progress update - too expensive
+                self.update_taskbar_progress();
             }
 
             TransferEvent::FileCompleted { id: _, path: _ } => {
                 // File completion is already tracked via Progress events
             }
 
+            TransferEvent::FileDownloaded {
+                local_path,
+                size,
+                sha256,
+            } => {
+                // Saved once the transfer completes
+                self.download_history.insert(sha256, local_path, size);
+            }
+
             TransferEvent::Completed { id } => {
                 // Get transfer info before marking complete for notification
                 let transfer_info = self
@@ -60,11 +80,21 @@ impl NexusApp {
                     .get(id)
                     .map(|t| (t.direction, t.remote_path.clone()));
 
+                // Open the download's folder if the user asked for it
+                if self.config.settings.auto_open_folder
+                    && let Some(transfer) = self.transfer_manager.get(id)
+                    && transfer.direction == TransferDirection::Download
+                {
+                    desktop::open_folder(&transfer.local_path);
+                }
+
                 // Check if we should refresh the file list before marking complete
                 let should_refresh = self.should_refresh_after_upload(id);
 
                 self.transfer_manager.complete(id);
                 self.save_transfers();
+                self.record_transfer_history(id);
+                let _ = self.download_history.save();
 
                 // Emit transfer complete notification
                 if let Some((direction, path)) = transfer_info {
@@ -77,10 +107,14 @@ impl NexusApp {
                     );
                 }
 
+                // Post the link if this was a shared screenshot
+                let post_link = self.post_screenshot_share(id);
+
                 // Refresh file list if upload completed to current directory
                 if should_refresh {
-                    return self.update(Message::FileRefresh);
+                    return Task::batch([post_link, self.update(Message::FileRefresh)]);
                 }
+                return post_link;
             }
 
             TransferEvent::Failed {
@@ -96,6 +130,7 @@ impl NexusApp {
 
                 self.transfer_manager.fail(id, error.clone(), error_kind);
                 self.save_transfers();
+                self.record_transfer_history(id);
 
                 // Emit transfer failed notification
                 if let Some((direction, path)) = transfer_info {
@@ -108,6 +143,24 @@ impl NexusApp {
                             .with_is_upload(direction == TransferDirection::Upload),
                     );
                 }
+
+                // Shared screenshots are kept so a retry still posts the link
+                if let Some(connection_id) =
+                    self.screenshot_shares.get(&id).map(|s| s.connection_id)
+                {
+                    let message = t_args("err-screenshot-upload", &[("error", &error)]);
+                    return self.add_active_tab_message(connection_id, ChatMessage::error(message));
+                }
+            }
+
+            TransferEvent::Collision { id, path, action } => {
+                self.transfer_manager.record_collision(id, path, action);
+                self.save_transfers();
+            }
+
+            TransferEvent::CollisionPending { id, path } => {
+                self.transfer_manager.await_collision(id, path);
+                self.save_transfers();
             }
 
             TransferEvent::Paused { id } => {
@@ -195,6 +248,24 @@ impl NexusApp {
         Task::none()
     }
 
+    /// Handle the user's answer to a pending collision
+    ///
+    /// The chosen policy applies to the rest of the transfer, which is then re-queued.
+    pub fn handle_transfer_resolve_collision(
+        &mut self,
+        id: Uuid,
+        policy: CollisionPolicy,
+    ) -> Task<Message> {
+        if let Some(transfer) = self.transfer_manager.get(id)
+            && transfer.pending_collision.is_some()
+        {
+            self.transfer_manager.set_collision_policy(id, policy);
+            self.transfer_manager.queue(id);
+            self.save_transfers();
+        }
+        Task::none()
+    }
+
     /// Handle request to cancel a transfer
     ///
     /// For active transfers: requests cancellation via the flag and marks as failed.
@@ -215,9 +286,11 @@ impl NexusApp {
             self.transfer_manager
                 .fail(id, t("transfer-cancelled"), None);
             self.save_transfers();
+            self.record_transfer_history(id);
         } else if is_queued || is_paused {
             // Not started yet - just remove it
             self.transfer_manager.remove(id);
+            self.screenshot_shares.remove(&id);
             self.save_transfers();
         }
         Task::none()
@@ -230,6 +303,7 @@ impl NexusApp {
             && (transfer.status.is_completed() || transfer.status.is_failed())
         {
             self.transfer_manager.remove(id);
+            self.screenshot_shares.remove(&id);
             self.save_transfers();
         }
         Task::none()
@@ -238,18 +312,70 @@ impl NexusApp {
     /// Handle request to open the folder containing a transfer's local path
     pub fn handle_transfer_open_folder(&mut self, id: Uuid) -> Task<Message> {
         if let Some(transfer) = self.transfer_manager.get(id) {
-            // Get the parent directory of the transfer's local path
-            if let Some(parent) = transfer.local_path.parent() {
-                let _ = open::that(parent);
-            }
+            desktop::open_folder(&transfer.local_path);
         }
         Task::none()
     }
 
+    /// Handle request to reveal a completed transfer in the file manager
+    pub fn handle_transfer_reveal(&mut self, id: Uuid) -> Task<Message> {
+        if let Some(transfer) = self.transfer_manager.get(id) {
+            desktop::reveal(&transfer.local_path);
+        }
+        Task::none()
+    }
+
+    /// Handle request to open a completed transfer with its default application
+    pub fn handle_transfer_open_file(&mut self, id: Uuid) -> Task<Message> {
+        if let Some(transfer) = self.transfer_manager.get(id) {
+            desktop::open_file(&transfer.local_path);
+        }
+        Task::none()
+    }
+
+    /// Handle request to copy a completed transfer's SHA-256 to the clipboard
+    ///
+    /// Hashing runs on a blocking thread so large files don't stall the UI.
+    pub fn handle_transfer_copy_checksum(&mut self, id: Uuid) -> Task<Message> {
+        let Some(transfer) = self.transfer_manager.get(id) else {
+            return Task::none();
+        };
+        let path = transfer.local_path.clone();
+
+        Task::perform(
+            async move {
+                tokio::task::spawn_blocking(move || desktop::sha256_file(&path))
+                    .await
+                    .map_err(|e| e.to_string())?
+                    .map_err(|e| e.to_string())
+            },
+            Message::TransferChecksumComputed,
+        )
+    }
+
+    /// Handle a finished SHA-256 computation
+    pub fn handle_transfer_checksum_computed(
+        &mut self,
+        result: Result<String, String>,
+    ) -> Task<Message> {
+        match result {
+            Ok(digest) => iced::clipboard::write(digest)
+                .chain(Task::done(Message::ShowToast(t("toast-checksum-copied")))),
+            Err(error) => {
+                let text = format!("{}: {}", t("err-checksum-failed"), error);
+                self.toasts.push(toast(&text).level(ToastLevel::Error));
+                Task::none()
+            }
+        }
+    }
+
     /// Handle request to clear all inactive (completed and failed) transfers
     pub fn handle_transfer_clear_inactive(&mut self) -> Task<Message> {
         self.transfer_manager.clear_completed();
         self.transfer_manager.clear_failed();
+        let transfer_manager = &self.transfer_manager;
+        self.screenshot_shares
+            .retain(|id, _| transfer_manager.get(*id).is_some());
         self.save_transfers();
         Task::none()
     }
@@ -282,8 +408,68 @@ impl NexusApp {
         Task::none()
     }
 
-    /// Save transfers to disk (helper to reduce repetition)
+    // ==================== History ====================
+
+    /// Handle the history button in the Transfers panel (switches views)
+    pub fn handle_transfer_history_toggle(&mut self) -> Task<Message> {
+        self.ui_state.show_transfer_history = !self.ui_state.show_transfer_history;
+        self.ui_state.transfer_history_search.clear();
+        Task::none()
+    }
+
+    /// Handle typing in the transfer history search field
+    pub fn handle_transfer_history_search_changed(&mut self, query: String) -> Task<Message> {
+        self.ui_state.transfer_history_search = query;
+        Task::none()
+    }
+
+    /// Handle request to open a finished transfer's location in the file manager
+    pub fn handle_transfer_history_reveal(&mut self, id: Uuid) -> Task<Message> {
+        if let Some(record) = self.transfer_history.get(id) {
+            if record.local_path.exists() {
+                desktop::reveal(&record.local_path);
+            } else {
+                desktop::open_folder(&record.local_path);
+            }
+        }
+        Task::none()
+    }
+
+    /// Handle request to forget a single history record
+    pub fn handle_transfer_history_remove(&mut self, id: Uuid) -> Task<Message> {
+        self.transfer_history.remove(id);
+        let _ = self.transfer_history.save();
+        Task::none()
+    }
+
+    /// Handle request to clear the whole transfer history
+    pub fn handle_transfer_history_clear(&mut self) -> Task<Message> {
+        self.transfer_history.clear();
+        let _ = self.transfer_history.save();
+        Task::none()
+    }
+
+    /// Add a finished transfer to the transfer history
+    fn record_transfer_history(&mut self, id: Uuid) {
+        if let Some(transfer) = self.transfer_manager.get(id) {
+            self.transfer_history
+                .record(TransferRecord::from_transfer(transfer));
+            let _ = self.transfer_history.save();
+        }
+    }
+
+    /// Save transfers to disk and refresh the taskbar progress
     fn save_transfers(&mut self) {
         let _ = self.transfer_manager.save();
+        self.update_taskbar_progress();
+    }
+
+    /// Mirror aggregate transfer progress onto the taskbar button or dock tile
+    ///
+    /// Progress is hidden when nothing is running, and while no active
+    /// transfer has reported its size yet.
+    fn update_taskbar_progress(&mut self) {
+        let progress = self.transfer_manager.summary().progress();
+        self.taskbar.set_progress(progress);
     }
 }
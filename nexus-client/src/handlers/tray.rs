@@ -51,7 +51,7 @@ impl NexusApp {
     }
 
     /// Toggle window visibility (show/hide)
-    fn toggle_window_visibility(&mut self) -> Task<Message> {
+    pub fn toggle_window_visibility(&mut self) -> Task<Message> {
         if self.window_visible {
             // Window is "visible" but might be minimized - check before hiding
             // If minimized, restore it instead of hiding
@@ -262,12 +262,12 @@ impl NexusApp {
         Some(voice_state.target.clone())
     }
 
-    /// Count total unread user message tabs across all connections
+    /// Count total unread user message and group tabs across all connections
     fn count_unread_user_messages(&self) -> usize {
         let mut count = 0;
         for conn in self.connections.values() {
             for tab in &conn.unread_tabs {
-                if matches!(tab, ChatTab::UserMessage(_)) {
+                if matches!(tab, ChatTab::UserMessage(_) | ChatTab::Group(_)) {
                     count += 1;
                 }
             }
@@ -311,7 +311,7 @@ impl NexusApp {
     ///
     /// Used when tray is destroyed or recreation fails to ensure user isn't stuck
     /// with no way to access the application.
-    fn show_window_if_hidden_to_tray(&mut self) -> Task<Message> {
+    pub fn show_window_if_hidden_to_tray(&mut self) -> Task<Message> {
         if !self.window_visible {
             self.window_visible = true;
             let was_maximized = self.window_was_maximized;
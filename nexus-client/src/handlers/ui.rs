@@ -1,9 +1,11 @@
 //! UI panel management and toggles
 
 use iced::Task;
-use iced::widget::markdown;
+use iced::widget::{markdown, pane_grid};
+use nexus_common::protocol::ClientMessage;
 
 use crate::NexusApp;
+use crate::style::WINDOW_WIDTH_MIN;
 use crate::types::{ActivePanel, Message};
 
 impl NexusApp {
@@ -24,10 +26,26 @@ impl NexusApp {
     ///
     /// When connected, stores in the connection (all panels are per-connection).
     /// When not connected, stores in ui_state (only Settings/About make sense).
+    /// Leaving the Server Log panel stops the server log stream.
     pub fn set_active_panel(&mut self, panel: ActivePanel) {
         if let Some(conn_id) = self.active_connection
             && let Some(conn) = self.connections.get_mut(&conn_id)
         {
+            if conn.active_panel == ActivePanel::ServerLog
+                && panel != ActivePanel::ServerLog
+                && (conn.server_log.subscribed || conn.server_log.loading)
+            {
+                conn.server_log.subscribed = false;
+                conn.server_log.loading = false;
+                let _ = conn.send(ClientMessage::LogUnsubscribe);
+            }
+            if conn.active_panel == ActivePanel::Doors
+                && panel != ActivePanel::Doors
+                && let Some(session) = conn.doors.session.take()
+                && session.running
+            {
+                let _ = conn.send(ClientMessage::DoorClose);
+            }
             conn.active_panel = panel;
         } else {
             // Not connected - only Settings/About/None make sense
@@ -79,6 +97,14 @@ impl NexusApp {
         }
 
         self.set_active_panel(ActivePanel::ServerInfo);
+
+        // Refresh the NAT status, which changes without a broadcast
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+            && conn.is_admin
+        {
+            let _ = conn.send(ClientMessage::NatStatus);
+        }
         Task::none()
     }
 
@@ -143,6 +169,42 @@ impl NexusApp {
     /// Toggle user list sidebar visibility
     pub fn handle_toggle_user_list(&mut self) -> Task<Message> {
         self.ui_state.show_user_list = !self.ui_state.show_user_list;
+        self.store_workspace_user_list();
         self.scroll_chat_if_visible(false)
     }
+
+    // ==================== Split View ====================
+
+    /// Whether chat is shown beside a panel rather than under it
+    pub fn chat_beside_panel(&self, panel: ActivePanel) -> bool {
+        self.config.settings.split_view && panel.shows_beside_chat()
+    }
+
+    /// Handle the split view divider being dragged
+    ///
+    /// The position is remembered for the current window width.
+    pub fn handle_split_resized(&mut self, event: pane_grid::ResizeEvent) -> Task<Message> {
+        let settings = &mut self.config.settings;
+        settings.set_split_ratio(settings.window_width, event.ratio);
+        let ratio = settings.split_ratio(settings.window_width);
+        self.ui_state.split_layout.set_ratio(ratio);
+        Task::none()
+    }
+
+    /// Handle the main window being resized
+    ///
+    /// Moves the split view divider to where it was left at the new width.
+    pub fn handle_window_resized(&mut self, size: iced::Size) -> Task<Message> {
+        // Minimized windows report a zero size on some platforms
+        if size.width < WINDOW_WIDTH_MIN {
+            return Task::none();
+        }
+
+        self.config.settings.window_width = size.width;
+        self.config.settings.window_height = size.height;
+
+        let ratio = self.config.settings.split_ratio(size.width);
+        self.ui_state.split_layout.set_ratio(ratio);
+        Task::none()
+    }
 }
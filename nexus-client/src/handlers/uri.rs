@@ -4,10 +4,11 @@ use iced::Task;
 use nexus_common::protocol::ClientMessage;
 
 use crate::NexusApp;
-use crate::i18n::{get_locale, t_args};
+use crate::i18n::{get_locale, t, t_args};
 use crate::network::{ConnectionParams, ProxyConfig};
 use crate::types::{
-    ActivePanel, ChatMessage, ChatTab, Message, NetworkConnection, PendingRequests, ResponseRouting,
+    ActivePanel, ChatMessage, ChatTab, Message, NetworkConnection, PendingRequests,
+    ResponseRouting, SnippetViewState,
 };
 use crate::uri::{NexusPath, NexusUri};
 
@@ -137,6 +138,14 @@ impl NexusApp {
                 }
             };
 
+        // Without credentials in the URI, follow a matching bookmark's single sign-on choice
+        let use_sso = uri.user.is_none()
+            && self.config.bookmarks.iter().any(|b| {
+                b.use_sso
+                    && b.address.to_lowercase() == uri.host.to_lowercase()
+                    && b.port == uri.port
+            });
+
         let locale = get_locale().to_string();
         let avatar = self.config.settings.avatar.clone();
 
@@ -159,6 +168,7 @@ impl NexusApp {
                 crate::network::connect_to_server(ConnectionParams {
                     server_address,
                     port,
+                    fallback_addresses: Vec::new(),
                     username,
                     password,
                     nickname,
@@ -166,6 +176,8 @@ impl NexusApp {
                     avatar,
                     connection_id,
                     proxy,
+                    use_sso,
+                    login_token: None,
                 })
                 .await
             },
@@ -199,7 +211,7 @@ impl NexusApp {
 
                         if already_joined {
                             // Just switch to the tab
-                            conn.active_chat_tab = ChatTab::Channel(target);
+                            conn.set_active_chat_tab(ChatTab::Channel(target));
                         } else {
                             // Need to join the channel
                             if let Ok(_msg_id) = conn.send(ClientMessage::ChatJoin {
@@ -207,7 +219,7 @@ impl NexusApp {
                             }) {
                                 // Tab will be created when server confirms join
                                 // Store intent to switch to it
-                                conn.active_chat_tab = ChatTab::Channel(target);
+                                conn.set_active_chat_tab(ChatTab::Channel(target));
                             }
                         }
                     } else {
@@ -228,7 +240,7 @@ impl NexusApp {
                             conn.user_messages.entry(tab_name.clone()).or_default();
                         }
 
-                        conn.active_chat_tab = ChatTab::UserMessage(tab_name);
+                        conn.set_active_chat_tab(ChatTab::UserMessage(tab_name));
                     }
                 }
                 // If target is None, just show chat panel (don't change active tab)
@@ -316,6 +328,23 @@ impl NexusApp {
                 conn.active_panel = ActivePanel::ServerInfo;
                 Task::none()
             }
+
+            NexusPath::Snippet { id } => {
+                conn.active_panel = ActivePanel::Snippet;
+                conn.snippet_view = SnippetViewState::loading(id.clone());
+
+                match conn.send(ClientMessage::SnippetGet { id: id.clone() }) {
+                    Ok(message_id) => conn
+                        .pending_requests
+                        .track(message_id, ResponseRouting::ShowSnippet(id)),
+                    Err(e) => {
+                        conn.snippet_view.loading = false;
+                        conn.snippet_view.error = Some(format!("{}: {}", t("err-send-failed"), e));
+                    }
+                }
+
+                Task::none()
+            }
         }
     }
 
@@ -370,6 +399,7 @@ impl NexusApp {
                     bookmark_id,
                     display_name,
                     certificate_fingerprint: conn.connection_info.certificate_fingerprint.clone(),
+                    identity_key: conn.connection_info.identity_key.clone(),
                     connection_id,
                 };
 
@@ -9,10 +9,15 @@ use nexus_common::validators::{self, PasswordError, UsernameError};
 use crate::NexusApp;
 use crate::i18n::{t, t_args};
 use crate::types::{
-    ActivePanel, ChatMessage, ChatTab, InputId, Message, PasswordChangeState, PendingRequests,
-    ResponseRouting, UserManagementMode,
+    ActivePanel, AuditPermission, ChatMessage, ChatTab, InputId, Message, PasswordChangeState,
+    PendingRequests, ResponseRouting, UserManagementMode,
 };
-use crate::views::constants::PERMISSION_USER_INFO;
+use crate::user_list::step_selection;
+use crate::views::constants::{
+    PERMISSION_BAN_CREATE, PERMISSION_USER_INFO, PERMISSION_USER_KICK, PERMISSION_USER_LIST,
+    PERMISSION_USER_MESSAGE,
+};
+use crate::views::user_list::visible_users;
 
 impl NexusApp {
     // ==================== Panel Toggle ====================
@@ -91,6 +96,17 @@ impl NexusApp {
                     Task::none()
                 }
             }
+            UserManagementMode::PermissionAudit => {
+                // Return to list mode (the user list is still loaded)
+                conn.user_management.reset_to_list();
+                Task::none()
+            }
+            UserManagementMode::Impersonate { username } => {
+                // Return to the audit view, showing the user's effective permissions
+                let username = username.clone();
+                conn.user_management.mode = UserManagementMode::PermissionAudit;
+                self.send_permission_audit(None, Some(username))
+            }
             UserManagementMode::ConfirmDelete { .. } => {
                 // Should not happen (modal handles its own cancel)
                 conn.user_management.mode = UserManagementMode::List;
@@ -115,6 +131,113 @@ impl NexusApp {
         operation::focus(Id::from(InputId::AdminUsername))
     }
 
+    /// Show the permission audit view
+    pub fn handle_user_management_show_audit(&mut self) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        conn.user_management.enter_audit_mode();
+        Task::none()
+    }
+
+    /// Handle permission picked in the audit view (lists accounts holding it)
+    pub fn handle_user_management_audit_permission_selected(
+        &mut self,
+        permission: AuditPermission,
+    ) -> Task<Message> {
+        let AuditPermission(permission) = permission;
+        self.send_permission_audit(Some(permission), None)
+    }
+
+    /// Handle user clicked in the audit view (shows their effective permissions)
+    pub fn handle_user_management_audit_user_clicked(&mut self, username: String) -> Task<Message> {
+        self.send_permission_audit(None, Some(username))
+    }
+
+    /// Send a PermissionAudit request and show the loading state
+    ///
+    /// An audited permission becomes the one shown in the audit picker; a user
+    /// audit leaves the picker as it was.
+    fn send_permission_audit(
+        &mut self,
+        permission: Option<String>,
+        username: Option<String>,
+    ) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        if permission.is_some() {
+            conn.user_management.audit_permission = permission.clone();
+        }
+        conn.user_management.audit = None;
+
+        if let Err(e) = conn.send(ClientMessage::PermissionAudit {
+            permission,
+            username,
+        }) {
+            conn.user_management.audit = Some(Err(format!("{}: {}", t("err-send-failed"), e)));
+        }
+        Task::none()
+    }
+
+    /// Handle "View as user" in the audit view (switches to impersonation view)
+    pub fn handle_user_management_impersonate(&mut self, username: String) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        conn.user_management
+            .enter_impersonate_mode(username.clone());
+        self.send_impersonate_query(username, None)
+    }
+
+    /// Handle folder clicked in the impersonation view (lists it as the user)
+    pub fn handle_user_management_impersonate_open_folder(
+        &mut self,
+        path: String,
+    ) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+        let UserManagementMode::Impersonate { username } = &conn.user_management.mode else {
+            return Task::none();
+        };
+
+        let username = username.clone();
+        conn.user_management.impersonation = None;
+        self.send_impersonate_query(username, Some(path))
+    }
+
+    /// Send an ImpersonateQuery request (the view shows loading until the response)
+    fn send_impersonate_query(&mut self, username: String, path: Option<String>) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        if let Err(e) = conn.send(ClientMessage::ImpersonateQuery { username, path }) {
+            conn.user_management.impersonation =
+                Some(Err(format!("{}: {}", t("err-send-failed"), e)));
+        }
+        Task::none()
+    }
+
     /// Handle edit button click on a user in the list (or from user info panel)
     ///
     /// Requests user details from server, then transitions to edit mode.
@@ -176,18 +299,21 @@ impl NexusApp {
             return Task::none();
         };
 
-        let username = match &conn.user_management.mode {
-            UserManagementMode::ConfirmDelete { username } => username.clone(),
+        let (username, erase) = match &conn.user_management.mode {
+            UserManagementMode::ConfirmDelete { username, erase } => (username.clone(), *erase),
             _ => return Task::none(),
         };
 
         // Clear any previous error before sending
         conn.user_management.delete_error = None;
 
-        // Send delete request (keep dialog open until response)
-        match conn.send(ClientMessage::UserDelete {
-            username: username.clone(),
-        }) {
+        // Send delete (or erase) request (keep dialog open until response)
+        let message = if erase {
+            ClientMessage::UserErase { username }
+        } else {
+            ClientMessage::UserDelete { username }
+        };
+        match conn.send(message) {
             Ok(message_id) => {
                 conn.pending_requests
                     .track(message_id, ResponseRouting::UserManagementDeleteResult);
@@ -202,6 +328,21 @@ impl NexusApp {
         Task::none()
     }
 
+    /// Handle "Erase personal data" checkbox in delete modal
+    pub fn handle_user_management_erase_toggled(&mut self, checked: bool) -> Task<Message> {
+        let Some(conn_id) = self.active_connection else {
+            return Task::none();
+        };
+        let Some(conn) = self.connections.get_mut(&conn_id) else {
+            return Task::none();
+        };
+
+        if let UserManagementMode::ConfirmDelete { erase, .. } = &mut conn.user_management.mode {
+            *erase = checked;
+        }
+        Task::none()
+    }
+
     /// Handle cancel delete button in modal
     pub fn handle_user_management_cancel_delete(&mut self) -> Task<Message> {
         let Some(conn_id) = self.active_connection else {
@@ -802,6 +943,156 @@ impl NexusApp {
         Task::none()
     }
 
+    // ==================== User List Keyboard ====================
+
+    /// Handle the user list filter text changing
+    ///
+    /// If the selected user no longer matches, the first match is selected
+    /// so Enter always acts on a visible user.
+    pub fn handle_user_list_filter_changed(&mut self, filter: String) -> Task<Message> {
+        self.focused_field = InputId::UserListFilter;
+        let settings = self.config.settings.user_list;
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+        {
+            conn.user_list_filter = filter;
+            let visible = visible_users(conn, settings);
+            let selection_visible = conn
+                .expanded_user
+                .as_ref()
+                .is_some_and(|selected| visible.iter().any(|user| &user.nickname == selected));
+            if !selection_visible {
+                let first = visible.first().map(|user| user.nickname.clone());
+                conn.expanded_user = first;
+            }
+        }
+        Task::none()
+    }
+
+    /// Focus the user list filter (Cmd/Ctrl+U), showing the user list if hidden
+    pub fn handle_user_list_filter_focus(&mut self) -> Task<Message> {
+        let can_view_users = self
+            .active_connection
+            .and_then(|conn_id| self.connections.get(&conn_id))
+            .is_some_and(|conn| conn.has_permission(PERMISSION_USER_LIST));
+        if !can_view_users {
+            return Task::none();
+        }
+
+        if !self.ui_state.show_user_list {
+            self.ui_state.show_user_list = true;
+            self.store_workspace_user_list();
+        }
+        self.focused_field = InputId::UserListFilter;
+        operation::focus(Id::from(InputId::UserListFilter))
+    }
+
+    /// Clear the user list filter and return to the chat input (Escape)
+    pub fn handle_user_list_filter_cleared(&mut self) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+        {
+            conn.user_list_filter.clear();
+        }
+        self.focused_field = InputId::ChatInput;
+        operation::focus(Id::from(InputId::ChatInput))
+    }
+
+    /// Move the user list selection down (`forward`) or up
+    pub fn handle_user_list_select(&mut self, forward: bool) -> Task<Message> {
+        let settings = self.config.settings.user_list;
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+        {
+            let nicknames: Vec<&str> = visible_users(conn, settings)
+                .into_iter()
+                .map(|user| user.nickname.as_str())
+                .collect();
+            let selected = step_selection(&nicknames, conn.expanded_user.as_deref(), forward);
+            if selected.is_some() {
+                conn.expanded_user = selected;
+            }
+        }
+        Task::none()
+    }
+
+    /// Collapse or expand a user list section (header clicked)
+    pub fn handle_user_list_group_toggled(&mut self, key: String) -> Task<Message> {
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get_mut(&conn_id)
+            && !conn.collapsed_user_groups.remove(&key)
+        {
+            conn.collapsed_user_groups.insert(key);
+        }
+        Task::none()
+    }
+
+    /// Open a message tab with the selected user (Enter)
+    ///
+    /// Same rules as the toolbar's message button: not ourselves, and only
+    /// with user_message permission.
+    pub fn handle_user_list_message_selected(&mut self) -> Task<Message> {
+        let Some((nickname, _, is_self)) = self.selected_list_user() else {
+            return Task::none();
+        };
+        if is_self || !self.active_has_any_permission(&[PERMISSION_USER_MESSAGE]) {
+            return Task::none();
+        }
+        self.focused_field = InputId::ChatInput;
+        self.handle_user_message_icon_clicked(nickname)
+    }
+
+    /// Show info for the selected user (Shift+Enter)
+    pub fn handle_user_list_info_selected(&mut self) -> Task<Message> {
+        let Some((nickname, _, _)) = self.selected_list_user() else {
+            return Task::none();
+        };
+        self.focused_field = InputId::ChatInput;
+        self.handle_user_info_icon_clicked(nickname)
+    }
+
+    /// Open the kick/ban dialog for the selected user (Cmd/Ctrl+Enter)
+    ///
+    /// Same rules as the toolbar's disconnect button: not ourselves, not an
+    /// admin, and only with user_kick or ban_create permission.
+    pub fn handle_user_list_disconnect_selected(&mut self) -> Task<Message> {
+        let Some((nickname, is_admin, is_self)) = self.selected_list_user() else {
+            return Task::none();
+        };
+        let can_disconnect =
+            self.active_has_any_permission(&[PERMISSION_USER_KICK, PERMISSION_BAN_CREATE]);
+        if is_self || is_admin || !can_disconnect {
+            return Task::none();
+        }
+        self.focused_field = InputId::ChatInput;
+        self.handle_disconnect_icon_clicked(nickname)
+    }
+
+    /// The user selected in the user list, if it's currently shown
+    ///
+    /// Returns the nickname, whether they're an admin, and whether it's us.
+    fn selected_list_user(&self) -> Option<(String, bool, bool)> {
+        let conn = self.connections.get(&self.active_connection?)?;
+        let selected = conn.expanded_user.as_deref()?;
+        visible_users(conn, self.config.settings.user_list)
+            .into_iter()
+            .find(|user| user.nickname == selected)
+            .map(|user| {
+                (
+                    user.nickname.clone(),
+                    user.is_admin,
+                    user.nickname == conn.nickname,
+                )
+            })
+    }
+
+    /// Whether the active connection has any of the permissions (admins have all)
+    fn active_has_any_permission(&self, permissions: &[&str]) -> bool {
+        self.active_connection
+            .and_then(|conn_id| self.connections.get(&conn_id))
+            .is_some_and(|conn| conn.has_any_permission(permissions))
+    }
+
     /// Handle info icon click on expanded user
     ///
     /// Opens the UserInfo panel and sends a request to the server.
@@ -7,17 +7,21 @@
 //! - VoicePttStateChanged - PTT hotkey pressed/released
 //! - VoicePttReleaseDelayExpired - PTT release delay timer expired
 //! - VoiceUserMute/VoiceUserUnmute - Mute/unmute a user (client-side)
+//! - VoiceClipFileLoaded - Soundboard clip picked for upload (/clip add)
+//! - VoiceTextTick - Expire voice text overlay messages
 
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use crate::network::tls::should_bypass_proxy;
 
 use global_hotkey::GlobalHotKeyEvent;
 use iced::Task;
 use nexus_common::protocol::ClientMessage;
+use nexus_common::validators::MAX_VOICE_CLIP_SIZE;
 
 use crate::NexusApp;
 use crate::i18n::{t, t_args};
+use crate::image::ImagePickerError;
 use crate::types::{ChatMessage, ChatTab, Message, VoiceState};
 use crate::views::constants::{PERMISSION_VOICE_LISTEN, PERMISSION_VOICE_TALK};
 use crate::voice::manager::VoiceEvent;
@@ -33,6 +37,18 @@ impl NexusApp {
             return Task::none();
         };
 
+        self.request_voice_join(connection_id, target, false)
+    }
+
+    /// Send a VoiceJoin request for a channel or user message
+    ///
+    /// With `broadcast`, the join starts a broadcast in the channel (/radio).
+    pub fn request_voice_join(
+        &mut self,
+        connection_id: usize,
+        target: String,
+        broadcast: bool,
+    ) -> Task<Message> {
         // Check if we already have a voice session on another connection
         if let Some(active_voice_conn) = self.active_voice_connection
             && active_voice_conn != connection_id
@@ -85,7 +101,7 @@ impl NexusApp {
         conn.voice_session = Some(VoiceState::new(target.clone(), Vec::new()));
 
         // Send the VoiceJoin request
-        if let Err(e) = conn.send(ClientMessage::VoiceJoin { target }) {
+        if let Err(e) = conn.send(ClientMessage::VoiceJoin { target, broadcast }) {
             // Clear the pending session on send failure
             conn.voice_session = None;
             return self.add_active_tab_message(connection_id, ChatMessage::error(e));
@@ -127,7 +143,7 @@ impl NexusApp {
     /// Returns the appropriate voice target based on the active chat tab:
     /// - Channel tab: Returns the channel name (e.g., "#general")
     /// - UserMessage tab: Returns the other user's nickname
-    /// - Console tab: Returns None (can't join voice from console)
+    /// - Console and group tabs: Returns None (no voice there)
     pub fn get_voice_target_for_current_tab(&self) -> Option<String> {
         let connection_id = self.active_connection?;
         let conn = self.connections.get(&connection_id)?;
@@ -136,7 +152,7 @@ impl NexusApp {
             // Channel name already includes the # prefix
             ChatTab::Channel(channel) => Some(channel.clone()),
             ChatTab::UserMessage(nickname) => Some(nickname.clone()),
-            ChatTab::Console => None,
+            ChatTab::Console | ChatTab::Group(_) => None,
         }
     }
 
@@ -161,7 +177,7 @@ impl NexusApp {
             ChatTab::UserMessage(nickname) => {
                 session.target.to_lowercase() == nickname.to_lowercase()
             }
-            ChatTab::Console => false,
+            ChatTab::Console | ChatTab::Group(_) => false,
         }
     }
 
@@ -181,6 +197,14 @@ impl NexusApp {
                 Task::none()
             }
 
+            VoiceEvent::UsingTcpRelay(error) => {
+                // UDP is blocked - voice continues over the BBS connection
+                self.add_active_tab_message(
+                    connection_id,
+                    ChatMessage::info(t_args("msg-voice-tcp-relay", &[("error", &error)])),
+                )
+            }
+
             VoiceEvent::ConnectionFailed(error) => {
                 // DTLS connection failed - notify server and clean up
                 self.leave_voice_session(connection_id);
@@ -470,6 +494,17 @@ impl NexusApp {
         Task::none()
     }
 
+    /// Handle voice text tick - drop overlay messages that have been shown long enough
+    pub fn handle_voice_text_tick(&mut self) -> Task<Message> {
+        let now = Instant::now();
+        for conn in self.connections.values_mut() {
+            if let Some(session) = conn.voice_session.as_mut() {
+                session.expire_texts(now);
+            }
+        }
+        Task::none()
+    }
+
     /// Handle raw PTT hotkey event from global hotkey subscription
     ///
     /// Forwards the event to the PttManager to determine if it's our hotkey
@@ -488,4 +523,79 @@ impl NexusApp {
 
         Task::none()
     }
+
+    /// Open a file picker for a soundboard clip's audio (/clip add)
+    ///
+    /// The file must be Ogg Vorbis and at most `MAX_VOICE_CLIP_SIZE` bytes;
+    /// the server checks the duration.
+    pub fn handle_voice_clip_pick_file(
+        &mut self,
+        connection_id: usize,
+        name: String,
+    ) -> Task<Message> {
+        Task::perform(
+            async move {
+                let handle = rfd::AsyncFileDialog::new()
+                    .add_filter("Ogg Vorbis", &["ogg"])
+                    .pick_file()
+                    .await;
+
+                let result = match handle {
+                    Some(file) => {
+                        let bytes = file.read().await;
+                        if bytes.len() > MAX_VOICE_CLIP_SIZE {
+                            Err(ImagePickerError::TooLarge)
+                        } else if !bytes.starts_with(b"OggS") {
+                            Err(ImagePickerError::UnsupportedType)
+                        } else {
+                            use base64::Engine;
+                            let base64_data =
+                                base64::engine::general_purpose::STANDARD.encode(&bytes);
+                            Ok(format!("data:audio/ogg;base64,{}", base64_data))
+                        }
+                    }
+                    None => Err(ImagePickerError::Cancelled),
+                };
+
+                Message::VoiceClipFileLoaded(connection_id, name, result)
+            },
+            |msg| msg,
+        )
+    }
+
+    /// Handle a soundboard clip file loaded from the file picker - upload it
+    pub fn handle_voice_clip_file_loaded(
+        &mut self,
+        connection_id: usize,
+        name: String,
+        result: Result<String, ImagePickerError>,
+    ) -> Task<Message> {
+        let data = match result {
+            Ok(data) => data,
+            Err(ImagePickerError::Cancelled) => return Task::none(),
+            Err(ImagePickerError::TooLarge) => {
+                return self.add_active_tab_message(
+                    connection_id,
+                    ChatMessage::error(t("err-voice-clip-too-large")),
+                );
+            }
+            Err(ImagePickerError::UnsupportedType) => {
+                return self.add_active_tab_message(
+                    connection_id,
+                    ChatMessage::error(t("err-voice-clip-unsupported-type")),
+                );
+            }
+        };
+
+        let Some(conn) = self.connections.get(&connection_id) else {
+            return Task::none();
+        };
+
+        if let Err(e) = conn.send(ClientMessage::VoiceClipUpload { name, data }) {
+            let error_msg = t_args("err-failed-send-message", &[("error", &e.to_string())]);
+            return self.add_active_tab_message(connection_id, ChatMessage::error(error_msg));
+        }
+
+        Task::none()
+    }
 }
@@ -0,0 +1,132 @@
+//! Per-bookmark workspace (open tabs, Files panel location, user list)
+//!
+//! A bookmarked connection's workspace is copied into its bookmark when the
+//! connection closes and put back on the next connect, so reconnecting
+//! doesn't reset the tabs and panels.
+
+use nexus_common::protocol::ClientMessage;
+
+use crate::NexusApp;
+use crate::types::{BookmarkWorkspace, ChatTab, PendingRequests, ResponseRouting};
+use crate::views::constants::PERMISSION_FILE_ROOT;
+
+impl NexusApp {
+    /// Copy a bookmarked connection's workspace into its bookmark
+    ///
+    /// Call before the connection goes away, then save the config.
+    pub fn store_connection_workspace(&mut self, connection_id: usize) {
+        let Some(conn) = self.connections.get(&connection_id) else {
+            return;
+        };
+        let Some(bookmark) = conn
+            .bookmark_id
+            .and_then(|id| self.config.get_bookmark_mut(id))
+        else {
+            return;
+        };
+
+        let files = conn.files_management.active_tab();
+        bookmark.workspace = BookmarkWorkspace {
+            channels: conn.channel_tabs.clone(),
+            active_tab: Some(conn.active_chat_tab.clone()),
+            files_path: files.current_path.clone(),
+            files_root: files.viewing_root,
+            files_sort_column: files.sort_column,
+            files_sort_ascending: files.sort_ascending,
+            // Kept up to date as the user list is toggled
+            show_user_list: bookmark.workspace.show_user_list,
+        };
+    }
+
+    /// Restore a bookmark's saved workspace on a newly registered connection
+    ///
+    /// Auto-joined channel tabs go back in their saved order, and channels
+    /// that were open but aren't auto-joined are joined again.
+    pub fn restore_workspace(&mut self, connection_id: usize) {
+        let Some(conn) = self.connections.get_mut(&connection_id) else {
+            return;
+        };
+        let Some(workspace) = conn
+            .bookmark_id
+            .and_then(|id| self.config.get_bookmark(id))
+            .map(|bookmark| bookmark.workspace.clone())
+        else {
+            return;
+        };
+
+        // Files panel location (the root view needs the file_root permission)
+        let can_view_root = conn.has_permission(PERMISSION_FILE_ROOT);
+        let files = conn.files_management.active_tab_mut();
+        files.current_path = workspace.files_path.clone();
+        files.viewing_root = workspace.files_root && can_view_root;
+        files.sort_column = workspace.files_sort_column;
+        files.sort_ascending = workspace.files_sort_ascending;
+
+        workspace.sort_channels(&mut conn.channel_tabs);
+
+        // Rejoin channels that weren't auto-joined; their tabs are placed as
+        // the responses arrive
+        for channel in &workspace.channels {
+            if conn.get_channel_state(channel).is_some() {
+                continue;
+            }
+            let activate = workspace.active_tab.as_ref().is_some_and(
+                |tab| matches!(tab, ChatTab::Channel(name) if name.eq_ignore_ascii_case(channel)),
+            );
+            if let Ok(message_id) = conn.send(ClientMessage::ChatJoin {
+                channel: channel.clone(),
+            }) {
+                conn.pending_requests
+                    .track(message_id, ResponseRouting::RejoinChannel { activate });
+            }
+        }
+
+        // Switch back to the tab that was active, if it's open again
+        let active_tab = match workspace.active_tab {
+            Some(ChatTab::Channel(name)) => conn
+                .channel_tabs
+                .iter()
+                .find(|open| open.eq_ignore_ascii_case(&name))
+                .map(|open| ChatTab::Channel(open.clone())),
+            Some(ChatTab::UserMessage(nickname)) => conn
+                .user_message_tabs
+                .contains(&nickname)
+                .then_some(ChatTab::UserMessage(nickname)),
+            Some(ChatTab::Console) => Some(ChatTab::Console),
+            // Group conversations end with the session
+            Some(ChatTab::Group(_)) | None => None,
+        };
+        if let Some(tab) = active_tab {
+            conn.set_active_chat_tab(tab);
+        }
+
+        if self.active_connection == Some(connection_id) {
+            self.restore_workspace_user_list(connection_id);
+        }
+    }
+
+    /// Show or hide the user list as last left on a bookmarked connection
+    pub fn restore_workspace_user_list(&mut self, connection_id: usize) {
+        if let Some(show_user_list) = self
+            .connections
+            .get(&connection_id)
+            .and_then(|conn| conn.bookmark_id)
+            .and_then(|id| self.config.get_bookmark(id))
+            .and_then(|bookmark| bookmark.workspace.show_user_list)
+        {
+            self.ui_state.show_user_list = show_user_list;
+        }
+    }
+
+    /// Remember the user list visibility for the active connection's bookmark
+    pub fn store_workspace_user_list(&mut self) {
+        if let Some(bookmark) = self
+            .active_connection
+            .and_then(|id| self.connections.get(&id))
+            .and_then(|conn| conn.bookmark_id)
+            .and_then(|id| self.config.get_bookmark_mut(id))
+        {
+            bookmark.workspace.show_user_list = Some(self.ui_state.show_user_list);
+        }
+    }
+}
@@ -9,6 +9,9 @@
 //! certificate fingerprint, which is public information (visible to anyone who
 //! connects to the server). This prevents casual snooping but provides no
 //! protection against attackers who know the fingerprint.
+//!
+//! With config encryption on, the result is encrypted again under the master
+//! key (see [`crate::config::encryption`]).
 
 use chacha20poly1305::{
     ChaCha20Poly1305, KeyInit, Nonce,
@@ -17,6 +20,8 @@ use chacha20poly1305::{
 use hkdf::Hkdf;
 use sha2::Sha256;
 
+use crate::config::encryption;
+
 /// Salt used for HKDF key derivation
 const HKDF_SALT: &[u8] = b"nexus-history-v1";
 
@@ -50,7 +55,8 @@ impl HistoryCrypto {
 
     /// Encrypt plaintext data
     ///
-    /// Returns the nonce prepended to the ciphertext: `[nonce (12 bytes)][ciphertext]`
+    /// Returns the nonce prepended to the ciphertext: `[nonce (12 bytes)][ciphertext]`,
+    /// wrapped under the master key when config encryption is on.
     pub fn encrypt(&self, plaintext: &[u8]) -> Result<Vec<u8>, CryptoError> {
         // Generate random nonce
         let mut nonce_bytes = [0u8; NONCE_SIZE];
@@ -68,13 +74,15 @@ impl HistoryCrypto {
         result.extend_from_slice(&nonce_bytes);
         result.extend_from_slice(&ciphertext);
 
-        Ok(result)
+        encryption::wrap_history(result).map_err(|_| CryptoError::EncryptionFailed)
     }
 
     /// Decrypt data that was encrypted with `encrypt`
     ///
-    /// Expects the nonce to be prepended to the ciphertext: `[nonce (12 bytes)][ciphertext]`
+    /// Expects the nonce to be prepended to the ciphertext: `[nonce (12 bytes)][ciphertext]`,
+    /// optionally wrapped under the master key.
     pub fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, CryptoError> {
+        let data = encryption::unwrap_history(data).map_err(|_| CryptoError::DecryptionFailed)?;
         if data.len() < NONCE_SIZE {
             return Err(CryptoError::InvalidData);
         }
@@ -13,9 +13,10 @@
 //! - No protection against determined attackers who know the server fingerprint
 //!
 //! The goal is to prevent accidental exposure of chat history, not to provide
-//! cryptographic security guarantees.
+//! cryptographic security guarantees. Users who need that can turn on config
+//! encryption, which adds a layer under their master passphrase.
 
 mod crypto;
 mod storage;
 
-pub use storage::{HistoryManager, rotate_fingerprint};
+pub use storage::{HistoryManager, rewrite_all_files, rotate_fingerprint};
@@ -21,6 +21,9 @@ use crate::config::settings::ChatHistoryRetention;
 /// File extension for encrypted history files
 const HISTORY_FILE_EXT: &str = "enc";
 
+/// File extension for history files being written
+const TEMP_FILE_EXT: &str = "tmp";
+
 /// Unix file permissions for history files (owner read/write only)
 #[cfg(unix)]
 const FILE_PERMISSIONS: u32 = 0o600;
@@ -86,10 +89,7 @@ impl HistoryManager {
     /// This can be used as a key to share managers across connections
     /// to the same server+account.
     pub fn build_base_dir(fingerprint: &str, your_username: &str) -> PathBuf {
-        let data_dir = dirs::data_dir()
-            .unwrap_or_else(|| PathBuf::from("."))
-            .join("nexus")
-            .join("history");
+        let data_dir = history_root();
 
         let fingerprint_hash = sha256_hex(fingerprint);
         let username_hash = sha256_hex(your_username);
@@ -276,21 +276,7 @@ impl HistoryManager {
             .encrypt(json.as_bytes())
             .map_err(HistoryError::Crypto)?;
 
-        // On Unix, create empty file and set permissions before writing content
-        // This avoids a race condition where the file is briefly world-readable
-        #[cfg(unix)]
-        {
-            use std::os::unix::fs::PermissionsExt;
-            // Create empty file
-            fs::File::create(&new_path).map_err(HistoryError::Io)?;
-            // Set restrictive permissions while file is empty
-            let _ = fs::set_permissions(&new_path, fs::Permissions::from_mode(FILE_PERMISSIONS));
-        }
-
-        // Write content (file already has correct permissions on Unix)
-        fs::write(&new_path, &encrypted).map_err(HistoryError::Io)?;
-
-        Ok(())
+        write_file(&new_path, &encrypted).map_err(HistoryError::Io)
     }
 
     /// Clear history for a specific conversation
@@ -323,10 +309,7 @@ impl HistoryManager {
 /// # Returns
 /// Number of files successfully rotated
 pub fn rotate_fingerprint(old_fingerprint: &str, new_fingerprint: &str) -> usize {
-    let data_dir = dirs::data_dir()
-        .unwrap_or_else(|| PathBuf::from("."))
-        .join("nexus")
-        .join("history");
+    let data_dir = history_root();
 
     let old_fp_hash = sha256_hex(old_fingerprint);
     let new_fp_hash = sha256_hex(new_fingerprint);
@@ -408,24 +391,7 @@ pub fn rotate_fingerprint(old_fingerprint: &str, new_fingerprint: &str) -> usize
                 };
                 let new_file_path = new_user_dir.join(filename);
 
-                // On Unix, create empty file and set permissions before writing content
-                // This avoids a race condition where the file is briefly world-readable
-                #[cfg(unix)]
-                {
-                    use std::os::unix::fs::PermissionsExt;
-                    // Create empty file
-                    if fs::File::create(&new_file_path).is_err() {
-                        continue;
-                    }
-                    // Set restrictive permissions while file is empty
-                    let _ = fs::set_permissions(
-                        &new_file_path,
-                        fs::Permissions::from_mode(FILE_PERMISSIONS),
-                    );
-                }
-
-                // Write content (file already has correct permissions on Unix)
-                if fs::write(&new_file_path, &new_encrypted).is_ok() {
+                if write_file(&new_file_path, &new_encrypted).is_ok() {
                     // Delete old file
                     let _ = fs::remove_file(&file_path);
                     rotated_count += 1;
@@ -443,6 +409,69 @@ pub fn rotate_fingerprint(old_fingerprint: &str, new_fingerprint: &str) -> usize
     rotated_count
 }
 
+/// Rewrite the contents of every history file on disk
+///
+/// Used when the master key changes (see [`crate::config::encryption`]).
+/// Files that `rewrite` returns None for are left as they are.
+pub fn rewrite_all_files(mut rewrite: impl FnMut(&[u8]) -> Option<Vec<u8>>) {
+    let Ok(server_dirs) = fs::read_dir(history_root()) else {
+        return;
+    };
+
+    for server_entry in server_dirs.flatten() {
+        let Ok(user_dirs) = fs::read_dir(server_entry.path()) else {
+            continue;
+        };
+        for user_entry in user_dirs.flatten() {
+            let Ok(files) = fs::read_dir(user_entry.path()) else {
+                continue;
+            };
+            for file_entry in files.flatten() {
+                let path = file_entry.path();
+                if !path.extension().is_some_and(|ext| ext == HISTORY_FILE_EXT) {
+                    continue;
+                }
+                if let Ok(data) = fs::read(&path)
+                    && let Some(rewritten) = rewrite(&data)
+                {
+                    let _ = write_file(&path, &rewritten);
+                }
+            }
+        }
+    }
+}
+
+/// Write a history file, replacing any existing one in a single step
+///
+/// The data goes to a temporary file next to it first, so an interrupted
+/// write leaves the old file intact instead of a truncated one. On Unix the
+/// temporary file gets restrictive permissions while it's still empty, so
+/// history is never briefly world-readable.
+fn write_file(path: &Path, data: &[u8]) -> io::Result<()> {
+    let temp_path = path.with_extension(TEMP_FILE_EXT);
+
+    fs::File::create(&temp_path)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let _ = fs::set_permissions(&temp_path, fs::Permissions::from_mode(FILE_PERMISSIONS));
+    }
+
+    if let Err(e) = fs::write(&temp_path, data).and_then(|()| fs::rename(&temp_path, path)) {
+        let _ = fs::remove_file(&temp_path);
+        return Err(e);
+    }
+    Ok(())
+}
+
+/// Root directory for all history files
+fn history_root() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("nexus")
+        .join("history")
+}
+
 /// Errors that can occur during history operations
 #[derive(Debug)]
 pub enum HistoryError {
@@ -509,6 +538,7 @@ mod tests {
             message: text.to_string(),
             action: ChatAction::Normal,
             timestamp,
+            id: None,
         }
     }
 
@@ -519,6 +549,25 @@ mod tests {
         assert!(hash.chars().all(|c| c.is_ascii_hexdigit()));
     }
 
+    #[test]
+    fn test_write_file_replaces_contents() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("conversation.enc");
+
+        write_file(&path, b"first").unwrap();
+        write_file(&path, b"second").unwrap();
+
+        assert_eq!(fs::read(&path).unwrap(), b"second");
+        assert!(!path.with_extension(TEMP_FILE_EXT).exists());
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, FILE_PERMISSIONS);
+        }
+    }
+
     #[test]
     fn test_history_manager_new() {
         // Just verify construction doesn't panic
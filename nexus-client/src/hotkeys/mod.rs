@@ -0,0 +1,153 @@
+//! Global hotkeys that work while the window isn't focused
+//!
+//! Hotkeys are registered with the operating system so they fire from any
+//! application (e.g. push-to-talk while a game has focus):
+//!
+//! - **Windows / macOS / X11**: the `global-hotkey` crate
+//! - **Wayland**: the freedesktop `GlobalShortcuts` portal (see [`portal`]).
+//!   Key grabs made through XWayland only fire while an X11 window has focus,
+//!   so the portal is used instead whenever it's available.
+//!
+//! Push-to-talk state is tracked by [`crate::voice::ptt::PttManager`]; this
+//! module holds the show/hide window hotkey and the event plumbing shared by
+//! both.
+
+#[cfg(target_os = "linux")]
+pub mod portal;
+
+use std::pin::Pin;
+use std::time::Duration;
+
+use crossbeam_channel::TryRecvError;
+use global_hotkey::hotkey::HotKey;
+use global_hotkey::{GlobalHotKeyEvent, GlobalHotKeyManager};
+use iced::Subscription;
+use iced::futures::Stream;
+use iced::stream;
+
+use crate::types::Message;
+use crate::voice::ptt::parse_hotkey;
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Channel size for the hotkey event stream
+const HOTKEY_STREAM_CHANNEL_SIZE: usize = 10;
+
+/// Poll interval for checking hotkey events (milliseconds)
+const HOTKEY_POLL_INTERVAL_MS: u64 = 10;
+
+// =============================================================================
+// Window Hotkey
+// =============================================================================
+
+/// Registration of the show/hide window hotkey
+pub struct WindowHotkey {
+    /// The global hotkey manager
+    manager: GlobalHotKeyManager,
+    /// Currently registered hotkey
+    hotkey: Option<HotKey>,
+}
+
+impl WindowHotkey {
+    /// Create a new window hotkey registration
+    ///
+    /// # Returns
+    /// * `Ok(WindowHotkey)` - Ready for use
+    /// * `Err(String)` - Error if hotkey system couldn't be initialized
+    pub fn new() -> Result<Self, String> {
+        let manager = GlobalHotKeyManager::new()
+            .map_err(|e| format!("Failed to initialize hotkey manager: {}", e))?;
+
+        Ok(Self {
+            manager,
+            hotkey: None,
+        })
+    }
+
+    /// Register the hotkey, replacing any previous one
+    ///
+    /// # Arguments
+    /// * `key` - Hotkey string (e.g., "Ctrl+Shift+N", "F9")
+    pub fn register(&mut self, key: &str) -> Result<(), String> {
+        self.unregister();
+
+        let (modifiers, code) = parse_hotkey(key)?;
+        let hotkey = HotKey::new(Some(modifiers), code);
+
+        self.manager
+            .register(hotkey)
+            .map_err(|e| format!("Failed to register hotkey: {}", e))?;
+
+        self.hotkey = Some(hotkey);
+        Ok(())
+    }
+
+    /// Unregister the current hotkey
+    pub fn unregister(&mut self) {
+        if let Some(hotkey) = self.hotkey.take() {
+            let _ = self.manager.unregister(hotkey);
+        }
+    }
+
+    /// Whether a hotkey is currently registered
+    pub fn is_registered(&self) -> bool {
+        self.hotkey.is_some()
+    }
+
+    /// Whether the event belongs to this hotkey
+    pub fn matches(&self, event: &GlobalHotKeyEvent) -> bool {
+        self.hotkey.is_some_and(|hotkey| hotkey.id() == event.id())
+    }
+}
+
+impl Drop for WindowHotkey {
+    fn drop(&mut self) {
+        self.unregister();
+    }
+}
+
+// =============================================================================
+// Hotkey Subscription
+// =============================================================================
+
+/// Subscription for receiving `global-hotkey` events
+///
+/// All `GlobalHotKeyManager`s share one event channel, so a single
+/// subscription forwards every event as `Message::GlobalHotkeyEvent` and the
+/// handler decides whether it's the PTT key or the window key.
+pub fn hotkey_subscription() -> Subscription<Message> {
+    Subscription::run(hotkey_event_stream)
+}
+
+/// Stream that receives global hotkey events
+fn hotkey_event_stream() -> Pin<Box<dyn Stream<Item = Message> + Send>> {
+    Box::pin(stream::channel(
+        HOTKEY_STREAM_CHANNEL_SIZE,
+        |mut output: iced::futures::channel::mpsc::Sender<Message>| async move {
+            use iced::futures::SinkExt;
+
+            // Get the global hotkey event receiver
+            let receiver = GlobalHotKeyEvent::receiver();
+
+            loop {
+                // Use try_recv with a small sleep to avoid busy-waiting
+                // and to keep the stream cancellable
+                match receiver.try_recv() {
+                    Ok(event) => {
+                        let _ = output.send(Message::GlobalHotkeyEvent(event)).await;
+                    }
+                    Err(TryRecvError::Empty) => {
+                        // No event, sleep briefly
+                        tokio::time::sleep(Duration::from_millis(HOTKEY_POLL_INTERVAL_MS)).await;
+                    }
+                    Err(TryRecvError::Disconnected) => {
+                        // Channel closed, exit
+                        break;
+                    }
+                }
+            }
+        },
+    ))
+}
@@ -0,0 +1,401 @@
+//! Wayland global shortcuts using the freedesktop `GlobalShortcuts` portal
+//!
+//! The portal works with sessions: we create one, bind our shortcuts to it
+//! (the desktop may ask the user to confirm or pick different keys), then
+//! receive `Activated`/`Deactivated` signals for as long as our bus
+//! connection stays open. Dropping the subscription closes the connection,
+//! which ends the session.
+//!
+//! Like the screenshot portal, each call answers through a `Response`
+//! signal on a request object whose path we can predict, so we subscribe to
+//! it before making the call.
+
+use std::collections::HashMap;
+use std::pin::Pin;
+
+use global_hotkey::hotkey::{Code, Modifiers};
+use iced::Subscription;
+use iced::futures::{SinkExt, Stream, StreamExt};
+use iced::stream;
+use zbus::zvariant::{ObjectPath, OwnedObjectPath, OwnedValue, Value};
+use zbus::{Connection, Proxy};
+
+use crate::types::Message;
+use crate::voice::ptt::{hotkey_to_string, parse_hotkey};
+
+/// Channel size for the portal event stream
+const PORTAL_STREAM_CHANNEL_SIZE: usize = 10;
+
+/// Portal service name
+const PORTAL_SERVICE: &str = "org.freedesktop.portal.Desktop";
+
+/// Portal object path
+const PORTAL_PATH: &str = "/org/freedesktop/portal/desktop";
+
+/// Global shortcuts portal interface
+const SHORTCUTS_INTERFACE: &str = "org.freedesktop.portal.GlobalShortcuts";
+
+/// Host registry interface (tells the portal which app we are)
+const REGISTRY_INTERFACE: &str = "org.freedesktop.host.portal.Registry";
+
+/// App ID to register as (matches `assets/linux/nexus.desktop`)
+const APP_ID: &str = "nexus";
+
+/// Portal request interface (carries the `Response` signal)
+const REQUEST_INTERFACE: &str = "org.freedesktop.portal.Request";
+
+/// Handle token for the `CreateSession` request
+const CREATE_SESSION_TOKEN: &str = "nexus_shortcuts_create";
+
+/// Handle token for the `BindShortcuts` request
+const BIND_SHORTCUTS_TOKEN: &str = "nexus_shortcuts_bind";
+
+/// Handle token identifying our shortcuts session
+const SESSION_TOKEN: &str = "nexus_shortcuts";
+
+/// An action that can be bound to a global shortcut
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Shortcut {
+    /// Push-to-talk (pressed and released)
+    PushToTalk,
+    /// Show or hide the main window
+    ToggleWindow,
+}
+
+impl Shortcut {
+    /// Stable identifier the desktop remembers the binding under
+    fn id(self) -> &'static str {
+        match self {
+            Shortcut::PushToTalk => "push-to-talk",
+            Shortcut::ToggleWindow => "toggle-window",
+        }
+    }
+
+    /// Look up a shortcut by its identifier
+    fn from_id(id: &str) -> Option<Self> {
+        match id {
+            "push-to-talk" => Some(Shortcut::PushToTalk),
+            "toggle-window" => Some(Shortcut::ToggleWindow),
+            _ => None,
+        }
+    }
+}
+
+/// A shortcut to bind along with its preferred key
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Binding {
+    /// The action
+    pub shortcut: Shortcut,
+    /// Hotkey string in our format (e.g., "Ctrl+Shift+N")
+    pub key: String,
+    /// Description shown by the desktop's shortcut settings
+    pub description: String,
+}
+
+/// Whether this is a Wayland session
+///
+/// `global-hotkey` grabs keys through XWayland there, which only fire while an
+/// X11 window has focus, so the portal is used instead.
+pub fn is_wayland_session() -> bool {
+    std::env::var_os("WAYLAND_DISPLAY").is_some()
+        || std::env::var("XDG_SESSION_TYPE").is_ok_and(|session| session == "wayland")
+}
+
+/// Subscription that binds the given shortcuts through the portal
+///
+/// Emits `Message::GlobalShortcut` for each press and release, or
+/// `Message::GlobalShortcutsUnavailable` if the portal can't be used (so the
+/// caller can fall back to `global-hotkey`). Changing the bindings restarts
+/// the session.
+pub fn shortcuts_subscription(bindings: Vec<Binding>) -> Subscription<Message> {
+    Subscription::run_with(bindings, shortcuts_stream)
+}
+
+/// Stream that runs a shortcuts session
+///
+/// Takes a reference to the bindings for compatibility with Subscription::run_with.
+#[allow(clippy::ptr_arg)] // Required for Subscription::run_with function pointer signature
+fn shortcuts_stream(bindings: &Vec<Binding>) -> Pin<Box<dyn Stream<Item = Message> + Send>> {
+    let bindings = bindings.clone();
+    Box::pin(stream::channel(
+        PORTAL_STREAM_CHANNEL_SIZE,
+        move |mut output: iced::futures::channel::mpsc::Sender<Message>| async move {
+            if let Err(e) = run_session(&bindings, &mut output).await {
+                let _ = output.send(Message::GlobalShortcutsUnavailable(e)).await;
+            }
+        },
+    ))
+}
+
+/// Create a session, bind the shortcuts and forward their signals
+///
+/// Returns `Ok(())` once the UI side stops listening.
+async fn run_session(
+    bindings: &[Binding],
+    output: &mut iced::futures::channel::mpsc::Sender<Message>,
+) -> Result<(), String> {
+    let connection = Connection::session().await.map_err(|e| e.to_string())?;
+    let sender = connection
+        .unique_name()
+        .ok_or_else(|| "no unique bus name".to_string())?
+        .as_str()
+        .trim_start_matches(':')
+        .replace('.', "_");
+    let session_path = format!("{PORTAL_PATH}/session/{sender}/{SESSION_TOKEN}");
+
+    // Unsandboxed apps must register before any other portal call so the
+    // desktop can attribute the shortcuts to us. Older portals don't have the
+    // registry, which is fine to ignore.
+    let registry_options: HashMap<&str, Value<'_>> = HashMap::new();
+    let _ = connection
+        .call_method(
+            Some(PORTAL_SERVICE),
+            PORTAL_PATH,
+            Some(REGISTRY_INTERFACE),
+            "Register",
+            &(APP_ID, registry_options),
+        )
+        .await;
+
+    // Listen for shortcut signals before binding so none are missed
+    let portal = Proxy::new(
+        &connection,
+        PORTAL_SERVICE,
+        PORTAL_PATH,
+        SHORTCUTS_INTERFACE,
+    )
+    .await
+    .map_err(|e| e.to_string())?;
+    let activated = portal
+        .receive_signal("Activated")
+        .await
+        .map_err(|e| e.to_string())?
+        .map(|signal| (signal, true));
+    let deactivated = portal
+        .receive_signal("Deactivated")
+        .await
+        .map_err(|e| e.to_string())?
+        .map(|signal| (signal, false));
+    let mut signals = std::pin::pin!(iced::futures::stream::select(activated, deactivated));
+
+    let mut options: HashMap<&str, Value<'_>> = HashMap::new();
+    options.insert("handle_token", Value::from(CREATE_SESSION_TOKEN));
+    options.insert("session_handle_token", Value::from(SESSION_TOKEN));
+    portal_request(
+        &connection,
+        &sender,
+        CREATE_SESSION_TOKEN,
+        "CreateSession",
+        &(options,),
+    )
+    .await?;
+
+    let mut shortcuts: Vec<(&str, HashMap<&str, Value<'_>>)> = Vec::new();
+    for binding in bindings {
+        let mut properties: HashMap<&str, Value<'_>> = HashMap::new();
+        properties.insert("description", Value::from(binding.description.as_str()));
+        if let Some(trigger) = portal_trigger(&binding.key) {
+            properties.insert("preferred_trigger", Value::from(trigger));
+        }
+        shortcuts.push((binding.shortcut.id(), properties));
+    }
+    let session = ObjectPath::try_from(session_path.as_str()).map_err(|e| e.to_string())?;
+    let mut options: HashMap<&str, Value<'_>> = HashMap::new();
+    options.insert("handle_token", Value::from(BIND_SHORTCUTS_TOKEN));
+    portal_request(
+        &connection,
+        &sender,
+        BIND_SHORTCUTS_TOKEN,
+        "BindShortcuts",
+        &(session, shortcuts, "", options),
+    )
+    .await?;
+
+    while let Some((signal, pressed)) = signals.next().await {
+        let Ok((handle, id, _timestamp, _options)) =
+            signal
+                .body()
+                .deserialize::<(OwnedObjectPath, String, u64, HashMap<String, OwnedValue>)>()
+        else {
+            continue;
+        };
+        if handle.as_str() != session_path {
+            continue;
+        }
+        let Some(shortcut) = Shortcut::from_id(&id) else {
+            continue;
+        };
+        if output
+            .send(Message::GlobalShortcut(shortcut, pressed))
+            .await
+            .is_err()
+        {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// Call a portal method and wait for its `Response`
+///
+/// Returns an error for any response code other than success (including
+/// the user cancelling the desktop's confirmation dialog).
+async fn portal_request<B>(
+    connection: &Connection,
+    sender: &str,
+    token: &str,
+    method: &str,
+    body: &B,
+) -> Result<HashMap<String, OwnedValue>, String>
+where
+    B: serde::Serialize + zbus::zvariant::DynamicType,
+{
+    let request_path = format!("{PORTAL_PATH}/request/{sender}/{token}");
+    let request = Proxy::new(
+        connection,
+        PORTAL_SERVICE,
+        request_path.as_str(),
+        REQUEST_INTERFACE,
+    )
+    .await
+    .map_err(|e| e.to_string())?;
+    let mut responses = request
+        .receive_signal("Response")
+        .await
+        .map_err(|e| e.to_string())?;
+
+    connection
+        .call_method(
+            Some(PORTAL_SERVICE),
+            PORTAL_PATH,
+            Some(SHORTCUTS_INTERFACE),
+            method,
+            body,
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let response = responses
+        .next()
+        .await
+        .ok_or_else(|| "portal closed the request".to_string())?;
+    let (code, results): (u32, HashMap<String, OwnedValue>) =
+        response.body().deserialize().map_err(|e| e.to_string())?;
+
+    if code != 0 {
+        return Err(format!("portal error {code}"));
+    }
+    Ok(results)
+}
+
+/// Convert a hotkey string to the portal's trigger format
+///
+/// The portal uses the XDG shortcuts format: `CTRL`, `ALT`, `SHIFT` and
+/// `LOGO` modifiers joined to an xkb keysym name with `+` (e.g.
+/// `CTRL+SHIFT+n`). Returns `None` if the hotkey can't be parsed, in which
+/// case the desktop lets the user pick a key.
+fn portal_trigger(key: &str) -> Option<String> {
+    let (modifiers, code) = parse_hotkey(key).ok()?;
+
+    let mut parts: Vec<String> = Vec::new();
+    if modifiers.contains(Modifiers::CONTROL) {
+        parts.push("CTRL".to_string());
+    }
+    if modifiers.contains(Modifiers::ALT) {
+        parts.push("ALT".to_string());
+    }
+    if modifiers.contains(Modifiers::SHIFT) {
+        parts.push("SHIFT".to_string());
+    }
+    if modifiers.contains(Modifiers::SUPER) {
+        parts.push("LOGO".to_string());
+    }
+    parts.push(keysym_name(code));
+
+    Some(parts.join("+"))
+}
+
+/// The xkb keysym name for a key code
+fn keysym_name(code: Code) -> String {
+    let name = match code {
+        Code::Backquote => "grave",
+        Code::Minus => "minus",
+        Code::Equal => "equal",
+        Code::BracketLeft => "bracketleft",
+        Code::BracketRight => "bracketright",
+        Code::Backslash => "backslash",
+        Code::Semicolon => "semicolon",
+        Code::Quote => "apostrophe",
+        Code::Comma => "comma",
+        Code::Period => "period",
+        Code::Slash => "slash",
+        Code::Space => "space",
+        Code::Enter => "Return",
+        Code::Tab => "Tab",
+        Code::Escape => "Escape",
+        Code::Backspace => "BackSpace",
+        Code::Delete => "Delete",
+        Code::Insert => "Insert",
+        Code::Home => "Home",
+        Code::End => "End",
+        Code::PageUp => "Page_Up",
+        Code::PageDown => "Page_Down",
+        Code::ArrowUp => "Up",
+        Code::ArrowDown => "Down",
+        Code::ArrowLeft => "Left",
+        Code::ArrowRight => "Right",
+        Code::Numpad0 => "KP_0",
+        Code::Numpad1 => "KP_1",
+        Code::Numpad2 => "KP_2",
+        Code::Numpad3 => "KP_3",
+        Code::Numpad4 => "KP_4",
+        Code::Numpad5 => "KP_5",
+        Code::Numpad6 => "KP_6",
+        Code::Numpad7 => "KP_7",
+        Code::Numpad8 => "KP_8",
+        Code::Numpad9 => "KP_9",
+        Code::NumpadAdd => "KP_Add",
+        Code::NumpadSubtract => "KP_Subtract",
+        Code::NumpadMultiply => "KP_Multiply",
+        Code::NumpadDivide => "KP_Divide",
+        Code::NumpadDecimal => "KP_Decimal",
+        Code::NumpadEnter => "KP_Enter",
+        // Letters are lowercase keysyms; digits and F-keys match our names
+        _ => {
+            let name = hotkey_to_string(Modifiers::empty(), code);
+            return if name.len() == 1 {
+                name.to_lowercase()
+            } else {
+                name
+            };
+        }
+    };
+    name.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shortcut_id_roundtrip() {
+        for shortcut in [Shortcut::PushToTalk, Shortcut::ToggleWindow] {
+            assert_eq!(Shortcut::from_id(shortcut.id()), Some(shortcut));
+        }
+        assert_eq!(Shortcut::from_id("unknown"), None);
+    }
+
+    #[test]
+    fn test_portal_trigger() {
+        assert_eq!(portal_trigger("`").as_deref(), Some("grave"));
+        assert_eq!(portal_trigger("F9").as_deref(), Some("F9"));
+        assert_eq!(
+            portal_trigger("Ctrl+Shift+N").as_deref(),
+            Some("CTRL+SHIFT+n")
+        );
+        assert_eq!(portal_trigger("Alt+Space").as_deref(), Some("ALT+space"));
+        assert_eq!(portal_trigger("Super+5").as_deref(), Some("LOGO+5"));
+        assert_eq!(portal_trigger("Hyper+Q"), None);
+    }
+}
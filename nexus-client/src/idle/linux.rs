@@ -0,0 +1,43 @@
+//! Linux idle time using the GNOME Mutter `IdleMonitor` D-Bus API
+//!
+//! Mutter exposes the idle time on both X11 and Wayland sessions. Other
+//! desktops don't implement the interface, so the call fails and the caller
+//! falls back to input seen in our own window.
+
+use std::time::Duration;
+
+use tokio::sync::OnceCell;
+use zbus::Connection;
+
+/// Mutter idle monitor bus name
+const IDLE_MONITOR_DESTINATION: &str = "org.gnome.Mutter.IdleMonitor";
+
+/// Object path of the monitor for the core (all devices) idle time
+const IDLE_MONITOR_PATH: &str = "/org/gnome/Mutter/IdleMonitor/Core";
+
+/// Mutter idle monitor interface
+const IDLE_MONITOR_INTERFACE: &str = "org.gnome.Mutter.IdleMonitor";
+
+/// Session bus connection (None inside the cell if the bus is unavailable)
+static CONNECTION: OnceCell<Option<Connection>> = OnceCell::const_new();
+
+/// Time since the last input, if the desktop reports it
+pub async fn idle_time() -> Option<Duration> {
+    let connection = CONNECTION
+        .get_or_init(|| async { Connection::session().await.ok() })
+        .await
+        .as_ref()?;
+
+    let reply = connection
+        .call_method(
+            Some(IDLE_MONITOR_DESTINATION),
+            IDLE_MONITOR_PATH,
+            Some(IDLE_MONITOR_INTERFACE),
+            "GetIdletime",
+            &(),
+        )
+        .await
+        .ok()?;
+    let millis: u64 = reply.body().deserialize().ok()?;
+    Some(Duration::from_millis(millis))
+}
@@ -0,0 +1,23 @@
+//! macOS idle time using Quartz event sources
+
+use std::time::Duration;
+
+/// `kCGEventSourceStateCombinedSessionState`
+const COMBINED_SESSION_STATE: i32 = 0;
+
+/// `kCGAnyInputEventType`
+const ANY_INPUT_EVENT_TYPE: u32 = u32::MAX;
+
+#[link(name = "CoreGraphics", kind = "framework")]
+unsafe extern "C" {
+    fn CGEventSourceSecondsSinceLastEventType(state_id: i32, event_type: u32) -> f64;
+}
+
+/// Time since the last input in the current login session
+pub async fn idle_time() -> Option<Duration> {
+    // SAFETY: plain C function taking two enum values, no pointers involved.
+    let seconds = unsafe {
+        CGEventSourceSecondsSinceLastEventType(COMBINED_SESSION_STATE, ANY_INPUT_EVENT_TYPE)
+    };
+    Duration::try_from_secs_f64(seconds).ok()
+}
@@ -0,0 +1,31 @@
+//! System idle time detection
+//!
+//! Reports how long it has been since the last keyboard or mouse input
+//! anywhere on the system, which drives automatic away status:
+//!
+//! - **Windows**: `GetLastInputInfo`
+//! - **macOS**: `CGEventSourceSecondsSinceLastEventType`
+//! - **Linux**: GNOME Mutter `IdleMonitor` over D-Bus
+//!
+//! Returns `None` where the idle time can't be read (other Linux desktops,
+//! other platforms), and callers fall back to input seen in our own window.
+
+#[cfg(target_os = "linux")]
+mod linux;
+#[cfg(target_os = "macos")]
+mod macos;
+#[cfg(target_os = "windows")]
+mod windows;
+
+#[cfg(target_os = "linux")]
+pub use self::linux::idle_time;
+#[cfg(target_os = "macos")]
+pub use self::macos::idle_time;
+#[cfg(target_os = "windows")]
+pub use self::windows::idle_time;
+
+/// Idle time isn't available on this platform
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+pub async fn idle_time() -> Option<std::time::Duration> {
+    None
+}
@@ -0,0 +1,26 @@
+//! Windows idle time using `GetLastInputInfo`
+
+use std::time::Duration;
+
+use windows::Win32::System::SystemInformation::GetTickCount;
+use windows::Win32::UI::Input::KeyboardAndMouse::{GetLastInputInfo, LASTINPUTINFO};
+
+/// Time since the last input in the current session
+pub async fn idle_time() -> Option<Duration> {
+    let mut info = LASTINPUTINFO {
+        cbSize: size_of::<LASTINPUTINFO>() as u32,
+        dwTime: 0,
+    };
+
+    // SAFETY: `info` is a valid LASTINPUTINFO with `cbSize` set as required.
+    if !unsafe { GetLastInputInfo(&mut info) }.as_bool() {
+        return None;
+    }
+
+    // Both values are milliseconds since boot and wrap after ~49 days
+    // SAFETY: GetTickCount has no preconditions.
+    let now = unsafe { GetTickCount() };
+    Some(Duration::from_millis(u64::from(
+        now.wrapping_sub(info.dwTime),
+    )))
+}
@@ -6,6 +6,7 @@
 //! - `decode_data_uri_square()` - Decode with square bounding box constraint (for avatars)
 //! - `decode_data_uri_max_width()` - Decode with max width constraint (for server images)
 //! - `validate_image_bytes()` - Validate image bytes match expected format
+//! - `pick_image_data_uri()` - Pick an image file and encode it as a data URI
 
 use iced::Element;
 use iced::widget::{image, svg};
@@ -97,6 +98,50 @@ pub fn decode_data_uri_max_width(data_uri: &str, max_width: u32) -> Option<Cache
     decode_data_uri_impl(data_uri, ResizeConstraint::MaxWidth(max_width))
 }
 
+/// Open a file picker for an image and encode the chosen file as a data URI
+///
+/// Rejects files larger than `max_size` bytes and files whose contents don't
+/// match their extension.
+pub async fn pick_image_data_uri(max_size: usize) -> Result<String, ImagePickerError> {
+    let Some(file) = rfd::AsyncFileDialog::new()
+        .add_filter("Images", &["png", "webp", "svg", "jpg", "jpeg"])
+        .pick_file()
+        .await
+    else {
+        return Err(ImagePickerError::Cancelled);
+    };
+
+    let extension = file
+        .path()
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+
+    // Determine MIME type from extension
+    let mime_type = match extension.as_str() {
+        "png" => "image/png",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        "jpg" | "jpeg" => "image/jpeg",
+        _ => return Err(ImagePickerError::UnsupportedType),
+    };
+
+    let bytes = file.read().await;
+    if bytes.len() > max_size {
+        return Err(ImagePickerError::TooLarge);
+    }
+
+    // Validate file content matches expected format
+    if !validate_image_bytes(&bytes, mime_type) {
+        return Err(ImagePickerError::UnsupportedType);
+    }
+
+    use base64::Engine;
+    let base64_data = base64::engine::general_purpose::STANDARD.encode(&bytes);
+    Ok(format!("data:{};base64,{}", mime_type, base64_data))
+}
+
 /// Resize constraint for image caching
 enum ResizeConstraint {
     /// Fit within a square bounding box (for avatars)
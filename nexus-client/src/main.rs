@@ -3,22 +3,36 @@
 
 mod autostart;
 mod avatar;
+mod chat_export;
+mod chat_input;
+mod code_block;
 mod commands;
 mod config;
 mod constants;
+mod desktop;
+mod drafts;
 mod events;
 mod fonts;
 mod handlers;
 mod history;
+mod hotkeys;
 mod i18n;
 mod icon;
+mod idle;
 mod image;
 mod network;
+mod quick_switcher;
+mod screenshot;
+mod snippet;
 mod sound;
+mod spellcheck;
 mod style;
+mod taskbar;
 mod transfers;
+mod tts;
 mod types;
 pub mod uri;
+mod user_list;
 mod views;
 mod voice;
 mod widgets;
@@ -26,6 +40,11 @@ mod widgets;
 #[cfg(not(target_os = "macos"))]
 mod tray;
 
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+mod notifications;
+
+#[cfg(target_os = "macos")]
+mod dock;
 #[cfg(target_os = "macos")]
 mod macos_url;
 
@@ -34,23 +53,31 @@ use std::sync::atomic::AtomicU32;
 
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::Mutex;
+use std::time::Instant;
 
 use once_cell::sync::Lazy;
 
 use uuid::Uuid;
 
-use iced::widget::{Id, operation, text_editor};
+use iced::widget::{Id, operation, stack, text_editor};
 use iced::{Element, Subscription, Task, Theme};
 use iced_toasts::{ToastContainer, ToastLevel, toast, toast_container};
 
 use style::toast_style;
 
 use config::events::EventType;
+use config::settings::Tip;
 
-use style::{WINDOW_HEIGHT_MIN, WINDOW_TITLE, WINDOW_WIDTH_MIN};
+use constants::{
+    ADMIN_DASHBOARD_REFRESH_SECS, AUTO_DOWNLOAD_INTERVAL_SECS, CONNECTION_MONITOR_REFRESH_SECS,
+    HEALTH_PING_INTERVAL_SECS, IDLE_CHECK_SECS, QUIET_HOURS_CHECK_SECS, REQUEST_TIMEOUT_CHECK_SECS,
+    STATUS_BAR_CLOCK_SECS, UPLOAD_WATCH_INTERVAL_SECS, VOICE_TEXT_CHECK_SECS,
+};
+use style::{WINDOW_HEIGHT_MIN, WINDOW_WIDTH_MIN};
 use types::{
-    BookmarkEditState, ConnectionFormState, FingerprintMismatch, InputId, Message,
-    ServerConnection, SettingsFormState, SettingsTab, UiState, ViewConfig,
+    ActivePanel, BookmarkEditState, ConnectionFormState, ConnectionMonitorTab, FingerprintMismatch,
+    InputId, Message, PasswordVaultDialog, ServerConnection, SettingsFormState, SettingsTab,
+    UiState, ViewConfig,
 };
 
 /// Startup URI passed via command line (consumed by NexusApp::new)
@@ -209,32 +236,12 @@ pub fn main() -> iced::Result {
         STARTUP_URI.lock().unwrap().replace(uri_str);
     }
 
-    // Load config early to get saved window position/size
-    let config = config::Config::load();
-    let window_size = iced::Size::new(config.settings.window_width, config.settings.window_height);
-    let window_position = match (config.settings.window_x, config.settings.window_y) {
-        (Some(x), Some(y)) => {
-            iced::window::Position::Specific(iced::Point::new(x as f32, y as f32))
-        }
-        _ => iced::window::Position::default(),
-    };
-
-    iced::application(NexusApp::new, NexusApp::update, NexusApp::view)
-        .title(WINDOW_TITLE)
+    // A daemon rather than an application so chat tabs can be popped out into
+    // their own windows; the main window is opened in NexusApp::new
+    iced::daemon(NexusApp::new, NexusApp::update, NexusApp::view)
+        .title(NexusApp::window_title)
         .theme(NexusApp::theme)
         .subscription(NexusApp::subscription)
-        .window(iced::window::Settings {
-            size: window_size,
-            min_size: Some(iced::Size::new(WINDOW_WIDTH_MIN, WINDOW_HEIGHT_MIN)),
-            position: window_position,
-            exit_on_close_request: false,
-            #[cfg(target_os = "linux")]
-            platform_specific: iced::window::settings::PlatformSpecific {
-                application_id: "nexus".to_string(),
-                ..Default::default()
-            },
-            ..Default::default()
-        })
         .font(fonts::SAUCECODE_PRO_MONO)
         .font(fonts::SAUCECODE_PRO_MONO_BOLD)
         .font(fonts::SAUCECODE_PRO_MONO_ITALIC)
@@ -291,14 +298,28 @@ struct NexusApp {
     // -------------------------------------------------------------------------
     /// Certificate fingerprint mismatch queue (for handling multiple mismatches)
     fingerprint_mismatch_queue: VecDeque<FingerprintMismatch>,
+    /// Password vault dialog (create or unlock, shown at startup)
+    password_vault: Option<PasswordVaultDialog>,
     /// Transient per-bookmark connection errors (not persisted to disk)
     bookmark_errors: HashMap<Uuid, String>,
+    /// Login tokens from bookmarks' dropped connections, for reconnecting (not persisted to disk)
+    resume_tokens: HashMap<Uuid, String>,
+    /// Queued chat messages from dropped connections, keyed by server (not persisted to disk)
+    offline_send_queues: HashMap<String, Vec<types::QueuedMessage>>,
 
     // -------------------------------------------------------------------------
     // Text Editor State (not Clone, stored outside ServerConnection)
     // -------------------------------------------------------------------------
     /// News body editor content, keyed by connection_id (used for both create and edit)
     news_body_content: HashMap<usize, text_editor::Content>,
+    /// Chat input editor content, showing the active connection's message input
+    chat_input_content: text_editor::Content,
+    /// Connection and input text the chat input editor last showed
+    chat_input_synced: Option<(usize, String)>,
+    /// Dictionary for the UI language (None until loaded, or if none was found)
+    spell_checker: Option<Arc<spellcheck::SpellChecker>>,
+    /// Whether loading the dictionary has finished
+    spell_checker_loaded: bool,
 
     // -------------------------------------------------------------------------
     // Chat History
@@ -309,11 +330,25 @@ struct NexusApp {
     /// Maps connection_id to its history manager's base_dir key
     connection_history_keys: HashMap<usize, std::path::PathBuf>,
 
+    // -------------------------------------------------------------------------
+    // Drafts
+    // -------------------------------------------------------------------------
+    /// Unsent chat and news drafts saved across restarts (global, keyed by server)
+    drafts: drafts::DraftStore,
+
     // -------------------------------------------------------------------------
     // Transfers
     // -------------------------------------------------------------------------
     /// Transfer manager for file downloads/uploads (global, not per-connection)
     transfer_manager: transfers::TransferManager,
+    /// Hashes of files already uploaded from watched folders
+    upload_history: transfers::UploadHistory,
+    /// Files downloaded earlier, checked for duplicates before downloading
+    download_history: transfers::DownloadHistory,
+    /// Finished transfers, kept after they're cleared from the queue
+    transfer_history: transfers::TransferHistory,
+    /// Screenshot uploads waiting to post their link, keyed by transfer ID
+    screenshot_shares: HashMap<Uuid, types::ScreenshotShare>,
 
     // -------------------------------------------------------------------------
     // Voice
@@ -342,8 +377,15 @@ struct NexusApp {
     // -------------------------------------------------------------------------
     // Window State
     // -------------------------------------------------------------------------
-    /// Whether the application window is currently focused
+    /// Main window (opened at startup)
+    main_window: Option<iced::window::Id>,
+    /// Chat tabs popped out into their own windows
+    chat_windows: HashMap<iced::window::Id, types::ChatWindow>,
+    /// Whether the main window is currently focused
     window_focused: bool,
+    /// Last keyboard or mouse input seen in the window (idle fallback when
+    /// the system idle time isn't available)
+    last_input: Instant,
     /// Whether the application window is currently visible (for minimize to tray)
     #[cfg(not(target_os = "macos"))]
     window_visible: bool,
@@ -351,6 +393,15 @@ struct NexusApp {
     #[cfg(not(target_os = "macos"))]
     window_was_maximized: bool,
 
+    // -------------------------------------------------------------------------
+    // Global Hotkeys
+    // -------------------------------------------------------------------------
+    /// Show/hide window hotkey (None until a key is first registered)
+    window_hotkey: Option<hotkeys::WindowHotkey>,
+    /// Whether the Wayland shortcuts portal failed (fall back to global-hotkey)
+    #[cfg(target_os = "linux")]
+    portal_shortcuts_unavailable: bool,
+
     // -------------------------------------------------------------------------
     // System Tray (Windows/Linux only)
     // -------------------------------------------------------------------------
@@ -358,6 +409,12 @@ struct NexusApp {
     #[cfg(not(target_os = "macos"))]
     tray_manager: Option<tray::TrayManager>,
 
+    // -------------------------------------------------------------------------
+    // Taskbar / Dock Progress
+    // -------------------------------------------------------------------------
+    /// Aggregate transfer progress shown on the taskbar button or dock tile
+    taskbar: taskbar::TaskbarManager,
+
     // -------------------------------------------------------------------------
     // Toasts
     // -------------------------------------------------------------------------
@@ -369,6 +426,10 @@ impl Default for NexusApp {
     fn default() -> Self {
         let config = config::Config::load();
         let transfer_manager = transfers::TransferManager::load();
+        let upload_history = transfers::UploadHistory::load();
+        let download_history = transfers::DownloadHistory::load();
+        let transfer_history = transfers::TransferHistory::load();
+        let drafts = drafts::DraftStore::load();
         let selected_event_type = config.settings.selected_event_type;
         Self {
             // Persistence
@@ -398,25 +459,47 @@ impl Default for NexusApp {
             selected_event_type,
             // Async / Transient
             fingerprint_mismatch_queue: VecDeque::new(),
+            password_vault: None,
             bookmark_errors: HashMap::new(),
+            resume_tokens: HashMap::new(),
+            offline_send_queues: HashMap::new(),
             // Text Editor State
             news_body_content: HashMap::new(),
+            chat_input_content: text_editor::Content::new(),
+            chat_input_synced: None,
+            spell_checker: None,
+            spell_checker_loaded: false,
             // Chat History
             history_managers: HashMap::new(),
             connection_history_keys: HashMap::new(),
+            // Drafts
+            drafts,
             // Transfers
             transfer_manager,
+            upload_history,
+            download_history,
+            transfer_history,
+            screenshot_shares: HashMap::new(),
             // Drag and Drop
             dragging_files: false,
             // Window State
+            main_window: None,
+            chat_windows: HashMap::new(),
             window_focused: true,
+            last_input: Instant::now(),
             #[cfg(not(target_os = "macos"))]
             window_visible: true,
             #[cfg(not(target_os = "macos"))]
             window_was_maximized: false,
+            // Global Hotkeys
+            window_hotkey: None,
+            #[cfg(target_os = "linux")]
+            portal_shortcuts_unavailable: false,
             // System Tray (Windows/Linux only)
             #[cfg(not(target_os = "macos"))]
             tray_manager: None,
+            // Taskbar / Dock Progress
+            taskbar: taskbar::TaskbarManager::new(),
             // Toasts
             toasts: toast_container(Message::ToastDismiss)
                 .timeout(std::time::Duration::from_secs(style::TOAST_TIMEOUT_SECS))
@@ -431,10 +514,7 @@ impl NexusApp {
     /// Called once at startup to set up initial state and generate tasks for
     /// focusing the input field and auto-connecting to bookmarks.
     fn new() -> (Self, Task<Message>) {
-        #[cfg(not(target_os = "macos"))]
         let mut app = Self::default();
-        #[cfg(target_os = "macos")]
-        let app = Self::default();
 
         // Initialize tray icon on startup if setting is enabled (Windows/Linux only)
         #[cfg(not(target_os = "macos"))]
@@ -451,17 +531,74 @@ impl NexusApp {
         #[cfg(target_os = "macos")]
         macos_url::install();
 
+        // Set up native notifications (reply actions, click to focus)
+        #[cfg(any(target_os = "macos", target_os = "windows"))]
+        notifications::install();
+
         // Check for startup URI
         let startup_uri = STARTUP_URI.lock().unwrap().take();
-        let mut tasks: Vec<Task<Message>> = vec![operation::focus(Id::from(InputId::ServerName))];
+
+        // Ask for the master passphrase before bookmarks auto-connect
+        app.password_vault = app.startup_password_vault_dialog(startup_uri.is_none());
+        let focus_input = if app.password_vault.is_some() {
+            InputId::VaultPassphrase
+        } else {
+            InputId::ServerName
+        };
+
+        // Put the split view divider where it was left at this window width
+        let split_ratio = app
+            .config
+            .settings
+            .split_ratio(app.config.settings.window_width);
+        app.ui_state.split_layout.set_ratio(split_ratio);
+
+        // Open the main window at its saved size and position
+        let settings = &app.config.settings;
+        let position = match (settings.window_x, settings.window_y) {
+            (Some(x), Some(y)) => {
+                iced::window::Position::Specific(iced::Point::new(x as f32, y as f32))
+            }
+            _ => iced::window::Position::default(),
+        };
+        let (main_window, open_main_window) = iced::window::open(iced::window::Settings {
+            size: iced::Size::new(settings.window_width, settings.window_height),
+            min_size: Some(iced::Size::new(WINDOW_WIDTH_MIN, WINDOW_HEIGHT_MIN)),
+            position,
+            exit_on_close_request: false,
+            #[cfg(target_os = "linux")]
+            platform_specific: iced::window::settings::PlatformSpecific {
+                application_id: "nexus".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        app.main_window = Some(main_window);
+
+        let mut tasks: Vec<Task<Message>> = vec![
+            open_main_window
+                .discard()
+                .chain(operation::focus(Id::from(focus_input))),
+            // Registered once the event loop runs, like the PTT hotkey
+            Task::done(Message::WindowHotkeyRegister),
+            Task::perform(
+                spellcheck::SpellChecker::load(i18n::get_locale().to_string()),
+                Message::SpellCheckerLoaded,
+            ),
+        ];
 
         if let Some(uri_str) = startup_uri {
             if let Ok(parsed_uri) = uri::parse(&uri_str) {
                 // Queue URI handling as a task
                 tasks.push(Task::done(Message::HandleNexusUri(parsed_uri)));
             }
-        } else {
+        } else if !app
+            .password_vault
+            .as_ref()
+            .is_some_and(|dialog| dialog.auto_connect)
+        {
             // No startup URI - generate auto-connect tasks for bookmarks
+            // (held back while the vault dialog is waiting to unlock)
             let auto_connect_tasks = autostart::generate_auto_connect_tasks(&app.config);
             tasks.extend(auto_connect_tasks);
         }
@@ -471,15 +608,28 @@ impl NexusApp {
 
     /// Process a message and update application state
     ///
-    /// Central message dispatcher that routes messages to their handlers.
+    /// Afterwards the chat input editor picks up any input text the handler
+    /// changed (sending, tab completion, switching tabs or connections).
     fn update(&mut self, message: Message) -> Task<Message> {
+        let task = self.dispatch(message);
+        self.sync_chat_input();
+        task
+    }
+
+    /// Central message dispatcher that routes messages to their handlers
+    fn dispatch(&mut self, message: Message) -> Task<Message> {
         match message {
             // Keyboard and window events
-            Message::Event(event) => self.handle_keyboard_event(event),
+            Message::Event(id, event) => self.handle_keyboard_event(id, event),
             Message::NextChatTab => self.handle_next_chat_tab(),
             Message::PrevChatTab => self.handle_prev_chat_tab(),
             Message::TabPressed => self.handle_tab_navigation(),
             Message::WindowCloseRequested(id) => {
+                // Popped-out chat windows just close
+                if self.chat_windows.contains_key(&id) {
+                    return self.close_chat_window(id);
+                }
+
                 // Check if we should minimize to tray instead of closing (Windows/Linux only)
                 #[cfg(not(target_os = "macos"))]
                 if self.config.settings.minimize_to_tray
@@ -514,8 +664,9 @@ impl NexusApp {
                 self.window_focused = false;
                 Task::none()
             }
+            Message::WindowResized(size) => self.handle_window_resized(size),
             Message::WindowSaveAndClose {
-                id,
+                id: _,
                 width,
                 height,
                 x,
@@ -526,6 +677,14 @@ impl NexusApp {
                 self.config.settings.window_height = height;
                 self.config.settings.window_x = x;
                 self.config.settings.window_y = y;
+
+                // Save unsent drafts and each bookmark's workspace
+                let connection_ids: Vec<usize> = self.connections.keys().copied().collect();
+                for connection_id in connection_ids {
+                    self.store_connection_drafts(connection_id);
+                    self.store_connection_workspace(connection_id);
+                }
+                let _ = self.drafts.save();
                 let _ = self.config.save();
 
                 // Save any pending transfer progress
@@ -541,11 +700,20 @@ impl NexusApp {
                 #[cfg(target_os = "macos")]
                 macos_url::shutdown();
 
-                iced::window::close(id)
+                // Signal the notification response stream to stop as well
+                #[cfg(any(target_os = "macos", target_os = "windows"))]
+                notifications::shutdown();
+
+                // Closing the main window quits, popped-out chat windows included
+                iced::exit()
             }
 
             // Connection management
             Message::ConnectPressed => self.handle_connect_pressed(),
+            Message::ConnectToLanServer(fullname) => self.handle_connect_to_lan_server(fullname),
+            Message::LanDiscoveryStarted => self.handle_lan_discovery_started(),
+            Message::LanServerFound(server) => self.handle_lan_server_found(server),
+            Message::LanServerLost(fullname) => self.handle_lan_server_lost(fullname),
             Message::ConnectToBookmark(id) => self.handle_connect_to_bookmark(id),
             Message::DisconnectFromServer(connection_id) => {
                 self.handle_disconnect_from_server(connection_id)
@@ -553,6 +721,9 @@ impl NexusApp {
             Message::SwitchToConnection(connection_id) => {
                 self.handle_switch_to_connection(connection_id)
             }
+            Message::HealthPingTick => self.handle_health_ping_tick(),
+            Message::RequestTimeoutTick => self.handle_request_timeout_tick(),
+            Message::StatusBarClockTick => Task::none(), // Just triggers re-render
 
             // Connection form fields
             Message::AddBookmarkToggled(enabled) => self.handle_add_bookmark_toggled(enabled),
@@ -562,6 +733,7 @@ impl NexusApp {
             Message::ServerNameChanged(name) => self.handle_server_name_changed(name),
             Message::UsernameChanged(username) => self.handle_username_changed(username),
             Message::NicknameChanged(nickname) => self.handle_nickname_changed(nickname),
+            Message::UseSsoToggled(enabled) => self.handle_use_sso_toggled(enabled),
             Message::ConnectionFormTabPressed => self.handle_connection_form_tab_pressed(),
             Message::ConnectionFormFocusResult(
                 name,
@@ -576,6 +748,9 @@ impl NexusApp {
 
             // Bookmark management
             Message::BookmarkAddressChanged(addr) => self.handle_bookmark_address_changed(addr),
+            Message::BookmarkFallbackAddressesChanged(addresses) => {
+                self.handle_bookmark_fallback_addresses_changed(addresses)
+            }
             Message::BookmarkAutoConnectToggled(enabled) => {
                 self.handle_bookmark_auto_connect_toggled(enabled)
             }
@@ -590,28 +765,106 @@ impl NexusApp {
             Message::BookmarkNicknameChanged(nickname) => {
                 self.handle_bookmark_nickname_changed(nickname)
             }
+            Message::BookmarkStatusChanged(status) => self.handle_bookmark_status_changed(status),
+            Message::BookmarkPickAvatarPressed => self.handle_bookmark_pick_avatar_pressed(),
+            Message::BookmarkAvatarLoaded(result) => self.handle_bookmark_avatar_loaded(result),
+            Message::BookmarkClearAvatarPressed => self.handle_bookmark_clear_avatar_pressed(),
+            Message::BookmarkDownloadFolderChanged(folder) => {
+                self.handle_bookmark_download_folder_changed(folder)
+            }
+            Message::BookmarkUseSsoToggled(enabled) => {
+                self.handle_bookmark_use_sso_toggled(enabled)
+            }
             Message::CancelBookmarkEdit => self.handle_cancel_bookmark_edit(),
             Message::DeleteBookmark(id) => self.handle_delete_bookmark(id),
             Message::SaveBookmark => self.handle_save_bookmark(),
             Message::ShowAddBookmark => self.handle_show_add_bookmark(),
             Message::ShowEditBookmark(id) => self.handle_show_edit_bookmark(id),
+            Message::BookmarkDragStarted(id) => self.handle_bookmark_drag_started(id),
+            Message::BookmarkDragHovered(target) => self.handle_bookmark_drag_hovered(target),
+            Message::BookmarkGroupToggled(id) => self.handle_bookmark_group_toggled(id),
+            Message::BookmarkGroupConnectAll(id) => self.handle_bookmark_group_connect_all(id),
+            Message::BookmarkGroupNew => self.handle_bookmark_group_new(),
+            Message::BookmarkGroupRename(id) => self.handle_bookmark_group_rename(id),
+            Message::BookmarkGroupDelete(id) => self.handle_bookmark_group_delete(id),
+            Message::BookmarkGroupNameChanged(name) => {
+                self.handle_bookmark_group_name_changed(name)
+            }
+            Message::BookmarkGroupNameSubmitted => self.handle_bookmark_group_name_submitted(),
+            Message::BookmarkGroupNameCancelled => self.handle_bookmark_group_name_cancelled(),
             Message::BookmarkEditTabPressed => self.handle_bookmark_edit_tab_pressed(),
-            Message::BookmarkEditFocusResult(name, address, port, username, password, nickname) => {
-                self.handle_bookmark_edit_focus_result(
-                    name, address, port, username, password, nickname,
-                )
-            }
+            Message::BookmarkEditFocusResult(
+                name,
+                address,
+                port,
+                fallback_addresses,
+                username,
+                password,
+                nickname,
+                status,
+                download_folder,
+            ) => self.handle_bookmark_edit_focus_result(
+                name,
+                address,
+                port,
+                fallback_addresses,
+                username,
+                password,
+                nickname,
+                status,
+                download_folder,
+            ),
 
             // Certificate fingerprint
             Message::AcceptNewFingerprint => self.handle_accept_new_fingerprint(),
             Message::CancelFingerprintMismatch => self.handle_cancel_fingerprint_mismatch(),
 
+            // Password vault
+            Message::PasswordVaultPassphraseChanged(value) => {
+                self.handle_password_vault_passphrase_changed(value)
+            }
+            Message::PasswordVaultConfirmChanged(value) => {
+                self.handle_password_vault_confirm_changed(value)
+            }
+            Message::PasswordVaultSubmit => self.handle_password_vault_submit(),
+            Message::PasswordVaultSkip => self.handle_password_vault_skip(),
+
+            // First-run tips
+            Message::TipDismissed(tip) => self.handle_tip_dismissed(tip),
+            Message::TipsSkipped => self.handle_tips_skipped(),
+
             // Chat
-            Message::ChatInputChanged(input) => self.handle_message_input_changed(input),
+            Message::ChatInputAction(action) => self.handle_chat_input_action(action),
+            Message::ChatInputHistoryNext => self.handle_chat_input_history_next(),
+            Message::ChatInputHistoryPrevious => self.handle_chat_input_history_previous(),
+            Message::PasteAsMessages => self.handle_paste_as_messages(),
+            Message::PasteAsOneMessage => self.handle_paste_as_one_message(),
+            Message::PasteAsSnippet => self.handle_paste_as_snippet(),
+            Message::PasteCancelled => self.handle_paste_cancelled(),
+            Message::BroadcastAcknowledged => self.handle_broadcast_acknowledged(),
+            Message::BroadcastAckDismissed => self.handle_broadcast_ack_dismissed(),
             Message::ChatTabComplete => self.handle_chat_tab_complete(),
+            Message::SpellCheckerLoaded(checker) => self.handle_spell_checker_loaded(checker),
+            Message::SpellMenuOpened(field, position) => {
+                self.handle_spell_menu_opened(field, position)
+            }
+            Message::SpellSuggestionChosen(word) => self.handle_spell_suggestion_chosen(word),
             Message::ChatScrolled(viewport) => self.handle_chat_scrolled(viewport),
             Message::CloseChannelTab(channel) => self.handle_close_channel_tab(channel),
             Message::CloseUserMessageTab(nickname) => self.handle_close_user_message_tab(nickname),
+            Message::CloseGroupTab(group_id) => self.handle_close_group_tab(group_id),
+            Message::PopOutChatTab(tab) => self.handle_pop_out_chat_tab(tab),
+            Message::ToggleChatTabMute(tab) => self.handle_toggle_chat_tab_mute(tab),
+            Message::ChatWindowInputChanged(id, value) => {
+                self.handle_chat_window_input_changed(id, value)
+            }
+            Message::ChatWindowSendPressed(id) => self.handle_chat_window_send_pressed(id),
+            Message::QueuedMessageCancel(id) => self.handle_queued_message_cancel(id),
+            Message::QueuedMessageRetry(id) => self.handle_queued_message_retry(id),
+            Message::CopyCodeBlock(code) => self.handle_copy_code_block(code),
+            Message::ToggleCodeBlock(connection_id, key) => {
+                self.handle_toggle_code_block(connection_id, key)
+            }
             Message::SendMessagePressed => self.handle_send_message_pressed(),
             Message::SwitchChatTab(tab) => self.handle_switch_chat_tab(tab),
 
@@ -635,11 +888,34 @@ impl NexusApp {
             Message::UserMessageIconClicked(nickname) => {
                 self.handle_user_message_icon_clicked(nickname)
             }
+            Message::UserListFilterChanged(filter) => self.handle_user_list_filter_changed(filter),
+            Message::UserListFilterFocus => self.handle_user_list_filter_focus(),
+            Message::UserListFilterCleared => self.handle_user_list_filter_cleared(),
+            Message::UserListSelectNext => self.handle_user_list_select(true),
+            Message::UserListSelectPrevious => self.handle_user_list_select(false),
+            Message::UserListMessageSelected => self.handle_user_list_message_selected(),
+            Message::UserListInfoSelected => self.handle_user_list_info_selected(),
+            Message::UserListDisconnectSelected => self.handle_user_list_disconnect_selected(),
+            Message::UserListGroupToggled(key) => self.handle_user_list_group_toggled(key),
+            Message::UserFriendToggled(username) => self.handle_user_friend_toggled(username),
 
             // User management
             Message::CancelUserManagement => self.handle_cancel_user_management(),
             Message::ToggleUserManagement => self.handle_toggle_user_management(),
             Message::UserManagementShowCreate => self.handle_user_management_show_create(),
+            Message::UserManagementShowAudit => self.handle_user_management_show_audit(),
+            Message::UserManagementAuditPermissionSelected(permission) => {
+                self.handle_user_management_audit_permission_selected(permission)
+            }
+            Message::UserManagementAuditUserClicked(username) => {
+                self.handle_user_management_audit_user_clicked(username)
+            }
+            Message::UserManagementImpersonate(username) => {
+                self.handle_user_management_impersonate(username)
+            }
+            Message::UserManagementImpersonateOpenFolder(path) => {
+                self.handle_user_management_impersonate_open_folder(path)
+            }
             Message::UserManagementUsernameChanged(username) => {
                 self.handle_user_management_username_changed(username)
             }
@@ -667,6 +943,9 @@ impl NexusApp {
             }
             Message::UserManagementConfirmDelete => self.handle_user_management_confirm_delete(),
             Message::UserManagementCancelDelete => self.handle_user_management_cancel_delete(),
+            Message::UserManagementEraseToggled(erase) => {
+                self.handle_user_management_erase_toggled(erase)
+            }
             Message::UserManagementEditUsernameChanged(username) => {
                 self.handle_user_management_edit_username_changed(username)
             }
@@ -697,17 +976,40 @@ impl NexusApp {
             }
 
             // Broadcast
+            Message::BroadcastChannelSelected(channel) => {
+                self.handle_broadcast_channel_selected(channel)
+            }
+            Message::BroadcastDelayChanged(delay) => self.handle_broadcast_delay_changed(delay),
             Message::BroadcastMessageChanged(input) => self.handle_broadcast_message_changed(input),
+            Message::BroadcastPermissionSelected(permission) => {
+                self.handle_broadcast_permission_selected(permission)
+            }
+            Message::BroadcastRequireAckToggled(enabled) => {
+                self.handle_broadcast_require_ack_toggled(enabled)
+            }
+            Message::BroadcastTargetChanged(target) => self.handle_broadcast_target_changed(target),
             Message::CancelBroadcast => self.handle_cancel_broadcast(),
             Message::SendBroadcastPressed => self.handle_send_broadcast_pressed(),
             Message::ValidateBroadcast => self.handle_validate_broadcast(),
 
             // UI toggles
             Message::ShowChatView => self.handle_show_chat_view(),
+            Message::SplitResized(event) => self.handle_split_resized(event),
             Message::ToggleBookmarks => self.handle_toggle_bookmarks(),
             Message::ToggleBroadcast => self.handle_toggle_broadcast(),
             Message::ToggleUserList => self.handle_toggle_user_list(),
 
+            // Quick switcher
+            Message::QuickSwitcherToggled => self.handle_quick_switcher_toggled(),
+            Message::QuickSwitcherQueryChanged(query) => {
+                self.handle_quick_switcher_query_changed(query)
+            }
+            Message::QuickSwitcherNext => self.handle_quick_switcher_next(),
+            Message::QuickSwitcherPrevious => self.handle_quick_switcher_previous(),
+            Message::QuickSwitcherSubmitted => self.handle_quick_switcher_submitted(),
+            Message::QuickSwitcherChosen(index) => self.handle_quick_switcher_chosen(index),
+            Message::QuickSwitcherClosed => self.handle_quick_switcher_closed(),
+
             // Settings
             Message::CancelSettings => self.handle_cancel_settings(),
             Message::ChatFontSizeSelected(size) => self.handle_chat_font_size_selected(size),
@@ -715,6 +1017,10 @@ impl NexusApp {
             Message::ChatHistoryRetentionSelected(retention) => {
                 self.handle_chat_history_retention_selected(retention)
             }
+            Message::UserListGroupingSelected(grouping) => {
+                self.handle_user_list_grouping_selected(grouping)
+            }
+            Message::UserListSortSelected(sort) => self.handle_user_list_sort_selected(sort),
             Message::ClearAvatarPressed => self.handle_clear_avatar_pressed(),
             Message::ConnectionNotificationsToggled(enabled) => {
                 self.handle_connection_notifications_toggled(enabled)
@@ -727,6 +1033,9 @@ impl NexusApp {
             Message::SaveSettings => self.handle_save_settings(),
             Message::ShowSecondsToggled(enabled) => self.handle_show_seconds_toggled(enabled),
             Message::ShowTimestampsToggled(enabled) => self.handle_show_timestamps_toggled(enabled),
+            Message::SpellCheckToggled(enabled) => self.handle_spell_check_toggled(enabled),
+            Message::ReadReceiptsToggled(enabled) => self.handle_read_receipts_toggled(enabled),
+            Message::SplitViewToggled(enabled) => self.handle_split_view_toggled(enabled),
             Message::SettingsTabSelected(tab) => self.handle_settings_tab_selected(tab),
             Message::EventTypeSelected(event_type) => self.handle_event_type_selected(event_type),
             Message::ToggleNotificationsEnabled(enabled) => {
@@ -763,6 +1072,37 @@ impl NexusApp {
                 self.handle_event_always_play_sound_toggled(enabled)
             }
             Message::TestSound => self.handle_test_sound(),
+            Message::NotificationRulesBookmarkSelected(id) => {
+                self.handle_notification_rules_bookmark_selected(id)
+            }
+            Message::NotificationRulesChannelChanged(channel) => {
+                self.handle_notification_rules_channel_changed(channel)
+            }
+            Message::NotificationRulesChannelAdd => self.handle_notification_rules_channel_add(),
+            Message::NotificationRulesChannelLevelSelected(channel, level) => {
+                self.handle_notification_rules_channel_level_selected(channel, level)
+            }
+            Message::NotificationRulesChannelRemove(channel) => {
+                self.handle_notification_rules_channel_remove(channel)
+            }
+            Message::NotificationRulesKeywordChanged(keyword) => {
+                self.handle_notification_rules_keyword_changed(keyword)
+            }
+            Message::NotificationRulesKeywordAdd => self.handle_notification_rules_keyword_add(),
+            Message::NotificationRulesKeywordRemove(keyword) => {
+                self.handle_notification_rules_keyword_remove(keyword)
+            }
+            Message::DoNotDisturbToggled(enabled) => self.handle_do_not_disturb_toggled(enabled),
+            Message::QuietHoursToggled(enabled) => self.handle_quiet_hours_toggled(enabled),
+            Message::QuietHoursStartSelected(hour) => self.handle_quiet_hours_start_selected(hour),
+            Message::QuietHoursEndSelected(hour) => self.handle_quiet_hours_end_selected(hour),
+            Message::QuietHoursTick => self.handle_quiet_hours_tick(),
+            Message::AutoAwayToggled(enabled) => self.handle_auto_away_toggled(enabled),
+            Message::AutoAwayMinutesChanged(minutes) => {
+                self.handle_auto_away_minutes_changed(minutes)
+            }
+            Message::IdleTick => self.handle_idle_tick(),
+            Message::IdleTimeChecked(idle) => self.handle_idle_time_checked(idle),
             Message::ThemeSelected(theme) => self.handle_theme_selected(theme),
             Message::SettingsNicknameChanged(nickname) => {
                 self.handle_settings_nickname_changed(nickname)
@@ -783,6 +1123,26 @@ impl NexusApp {
             Message::QueueTransfersToggled(enabled) => self.handle_queue_transfers_toggled(enabled),
             Message::DownloadLimitChanged(limit) => self.handle_download_limit_changed(limit),
             Message::UploadLimitChanged(limit) => self.handle_upload_limit_changed(limit),
+            Message::AutoOpenFolderToggled(enabled) => {
+                self.handle_auto_open_folder_toggled(enabled)
+            }
+            Message::CollisionPolicySelected(policy) => {
+                self.handle_collision_policy_selected(policy)
+            }
+            Message::RequireFingerprintApprovalToggled(enabled) => {
+                self.handle_require_fingerprint_approval_toggled(enabled)
+            }
+            Message::KnownServerDetailsToggled(id) => self.handle_known_server_details_toggled(id),
+            Message::KnownServerRemovePressed(id) => self.handle_known_server_remove_pressed(id),
+            Message::KnownServerExportPressed(id) => self.handle_known_server_export_pressed(id),
+            Message::KnownServerExported(result) => self.handle_known_server_exported(result),
+            Message::ConfigEncryptionToggled(enabled) => {
+                self.handle_config_encryption_toggled(enabled)
+            }
+            Message::ConfigPassphraseChanged(value) => self.handle_config_passphrase_changed(value),
+            Message::ConfigPassphraseConfirmChanged(value) => {
+                self.handle_config_passphrase_confirm_changed(value)
+            }
 
             // About
             Message::CloseAbout => self.handle_close_about(),
@@ -797,6 +1157,7 @@ impl NexusApp {
             Message::ToggleConnectionMonitor => self.handle_toggle_connection_monitor(),
             Message::CloseConnectionMonitor => self.handle_close_connection_monitor(),
             Message::RefreshConnectionMonitor => self.handle_refresh_connection_monitor(),
+            Message::ConnectionMonitorTick => self.handle_connection_monitor_tick(),
             Message::ConnectionMonitorResponse {
                 connection_id,
                 success,
@@ -828,6 +1189,37 @@ impl NexusApp {
                 self.handle_connection_monitor_transfer_sort_by(column)
             }
 
+            // Server Log
+            Message::ToggleServerLog => self.handle_toggle_server_log(),
+            Message::CloseServerLog => self.handle_close_server_log(),
+            Message::ServerLogLevelSelected(level) => self.handle_server_log_level_selected(level),
+            Message::ServerLogModuleFilterChanged(filter) => {
+                self.handle_server_log_module_filter_changed(filter)
+            }
+
+            // Statistics
+            Message::ShowStatistics => self.handle_show_statistics(),
+            Message::ShowAdminDashboard => self.handle_show_admin_dashboard(),
+            Message::CloseAdminDashboard => self.handle_close_admin_dashboard(),
+            Message::RefreshAdminDashboard => self.handle_refresh_admin_dashboard(),
+            Message::AdminDashboardTick => self.handle_admin_dashboard_tick(),
+            Message::CloseStatistics => self.handle_close_statistics(),
+            Message::StatisticsRangeSelected(range) => self.handle_statistics_range_selected(range),
+            Message::StatisticsMetricSelected(metric) => {
+                self.handle_statistics_metric_selected(metric)
+            }
+
+            // Snippets
+            Message::CloseSnippet => self.handle_close_snippet(),
+            Message::CopySnippet => self.handle_copy_snippet(),
+
+            // Doors
+            Message::ShowDoors => self.handle_show_doors(),
+            Message::CloseDoors => self.handle_close_doors(),
+            Message::DoorOpen(name) => self.handle_door_open(name),
+            Message::DoorLeave => self.handle_door_leave(),
+            Message::DoorInput(bytes) => self.handle_door_input(bytes),
+
             // Server info
             Message::CancelEditServerInfo => self.handle_cancel_edit_server_info(),
             Message::ClearServerImagePressed => self.handle_clear_server_image_pressed(),
@@ -844,6 +1236,15 @@ impl NexusApp {
             Message::EditServerInfoMaxTransfersChanged(max_transfers) => {
                 self.handle_edit_server_info_max_transfers_changed(max_transfers)
             }
+            Message::EditServerInfoMaxActiveTransfersChanged(max_active) => {
+                self.handle_edit_server_info_max_active_transfers_changed(max_active)
+            }
+            Message::EditServerInfoSmallFilesFirstToggled(enabled) => {
+                self.handle_edit_server_info_small_files_first_toggled(enabled)
+            }
+            Message::EditServerInfoTrustedTransferWeightChanged(weight) => {
+                self.handle_edit_server_info_trusted_transfer_weight_changed(weight)
+            }
             Message::EditServerInfoFileReindexIntervalChanged(interval) => {
                 self.handle_edit_server_info_file_reindex_interval_changed(interval)
             }
@@ -858,6 +1259,7 @@ impl NexusApp {
             }
             Message::EditServerInfoPressed => self.handle_edit_server_info_pressed(),
             Message::ServerInfoTabChanged(tab) => self.handle_server_info_tab_changed(tab),
+            Message::ConnectivityCheckPressed => self.handle_connectivity_check_pressed(),
             Message::PickServerImagePressed => self.handle_pick_server_image_pressed(),
             Message::ShowServerInfo => self.handle_show_server_info(),
             Message::UpdateServerInfoPressed => self.handle_update_server_info_pressed(),
@@ -912,8 +1314,17 @@ impl NexusApp {
             Message::NewsBodyAction(action) => self.handle_news_body_action(action),
             Message::NewsPickImagePressed => self.handle_news_pick_image_pressed(),
             Message::NewsImageLoaded(result) => self.handle_news_image_loaded(result),
-            Message::NewsClearImagePressed => self.handle_news_clear_image_pressed(),
+            Message::NewsFilePathChanged(path) => self.handle_news_file_path_changed(path),
+            Message::NewsAddFilePressed => self.handle_news_add_file_pressed(),
+            Message::NewsAttachmentCaptionChanged(index, caption) => {
+                self.handle_news_attachment_caption_changed(index, caption)
+            }
+            Message::NewsRemoveAttachment(index) => self.handle_news_remove_attachment(index),
+            Message::NewsOpenFile(path) => self.handle_news_open_file(path),
             Message::NewsSubmitPressed => self.handle_news_submit_pressed(),
+            Message::NewsSearchInputChanged(value) => self.handle_news_search_input_changed(value),
+            Message::NewsSearchSubmit => self.handle_news_search_submit(),
+            Message::NewsSearchClear => self.handle_news_search_clear(),
 
             // Files panel
             Message::ToggleFiles => self.handle_toggle_files(),
@@ -935,10 +1346,27 @@ impl NexusApp {
             Message::FileCancelDelete => self.handle_file_cancel_delete(),
             Message::FileInfoClicked(name) => self.handle_file_info_clicked(name),
             Message::CloseFileInfo => self.handle_close_file_info(),
+            Message::FileRate(rating) => self.handle_file_rate(rating),
+            Message::FileCommentInputChanged(body) => self.handle_file_comment_input_changed(body),
+            Message::FileCommentSubmit => self.handle_file_comment_submit(),
+            Message::FileCommentDelete(id) => self.handle_file_comment_delete(id),
             Message::FileRenameClicked(name) => self.handle_file_rename_clicked(name),
             Message::FileRenameNameChanged(name) => self.handle_file_rename_name_changed(name),
             Message::FileRenameSubmit => self.handle_file_rename_submit(),
             Message::FileRenameCancel => self.handle_file_rename_cancel(),
+            Message::FileUploadRuleClicked(path) => self.handle_file_upload_rule_clicked(path),
+            Message::FileUploadRuleExtensionsChanged(value) => {
+                self.handle_file_upload_rule_extensions_changed(value)
+            }
+            Message::FileUploadRuleMaxFileSizeChanged(value) => {
+                self.handle_file_upload_rule_max_file_size_changed(value)
+            }
+            Message::FileUploadRuleMaxTotalSizeChanged(value) => {
+                self.handle_file_upload_rule_max_total_size_changed(value)
+            }
+            Message::FileUploadRuleSave => self.handle_file_upload_rule_save(),
+            Message::FileUploadRuleRemove => self.handle_file_upload_rule_remove(),
+            Message::FileUploadRuleCancel => self.handle_file_upload_rule_cancel(),
             Message::FileCut(path, name) => self.handle_file_cut(path, name),
             Message::FileCopyToClipboard(path, name) => {
                 self.handle_file_copy_to_clipboard(path, name)
@@ -955,11 +1383,70 @@ impl NexusApp {
             Message::FileShare(path) => self.handle_file_share(path),
             Message::FileDownload(path) => self.handle_file_download(path),
             Message::FileDownloadAll(path) => self.handle_file_download_all(path),
+            Message::FileDownloadTo(path, is_directory) => {
+                self.handle_file_download_to(path, is_directory)
+            }
+            Message::FileDownloadToBrowse => self.handle_file_download_to_browse(),
+            Message::FileDownloadToFolderSelected(folder) => {
+                self.handle_file_download_to_folder_selected(folder)
+            }
+            Message::FileDownloadToRememberToggled(remember) => {
+                self.handle_file_download_to_remember_toggled(remember)
+            }
+            Message::FileDownloadToCollisionPolicySelected(policy) => {
+                self.handle_file_download_to_collision_policy_selected(policy)
+            }
+            Message::FileDownloadToConfirm => self.handle_file_download_to_confirm(),
+            Message::FileDownloadToCancel => self.handle_file_download_to_cancel(),
+            Message::FileDownloadSelect(path) => self.handle_file_download_select(path),
+            Message::FileDownloadSelectToggled(path, selected) => {
+                self.handle_file_download_select_toggled(path, selected)
+            }
+            Message::FileDownloadSelectAll(selected) => {
+                self.handle_file_download_select_all(selected)
+            }
+            Message::FileDownloadSelectConfirm => self.handle_file_download_select_confirm(),
+            Message::FileDownloadSelectCancel => self.handle_file_download_select_cancel(),
+            Message::FileDownloadScanned(connection_id, tab_id, check_id, copies) => {
+                self.handle_file_download_scanned(connection_id, tab_id, check_id, copies)
+            }
+            Message::FileDownloadDuplicateConfirm => self.handle_file_download_duplicate_confirm(),
+            Message::FileDownloadDuplicateSkip => self.handle_file_download_duplicate_skip(),
             Message::FileUpload(destination) => self.handle_file_upload(destination),
             Message::FileUploadCancelled => Task::none(),
             Message::FileUploadSelected(destination, paths) => {
                 self.handle_file_upload_selected(destination, paths)
             }
+            Message::FileUploadScanned(connection_id, tab_id, remote_root, scan) => {
+                self.handle_file_upload_scanned(connection_id, tab_id, remote_root, scan)
+            }
+            Message::FileUploadHashed(connection_id, tab_id, preview_id, hashes) => {
+                self.handle_file_upload_hashed(connection_id, tab_id, preview_id, hashes)
+            }
+            Message::FileUploadPreviewConfirm(overwrite) => {
+                self.handle_file_upload_preview_confirm(overwrite)
+            }
+            Message::FileUploadPreviewCancel => self.handle_file_upload_preview_cancel(),
+            Message::FileWatchFolder(remote_path) => self.handle_file_watch_folder(remote_path),
+            Message::FileWatchFolderSelected(remote_path, folder) => {
+                self.handle_file_watch_folder_selected(remote_path, folder)
+            }
+            Message::FileUnwatchFolder => self.handle_file_unwatch_folder(),
+            Message::FileScreenshotFolder(remote_path) => {
+                self.handle_file_screenshot_folder(remote_path)
+            }
+            Message::UploadWatchTick => self.handle_upload_watch_tick(),
+            Message::UploadWatchScanned(connection_id, scan) => {
+                self.handle_upload_watch_scanned(connection_id, scan)
+            }
+            Message::FileAutoDownload(path) => self.handle_file_auto_download(path),
+            Message::FileAutoDownloadPatternChanged(pattern) => {
+                self.handle_file_auto_download_pattern_changed(pattern)
+            }
+            Message::FileAutoDownloadSave => self.handle_file_auto_download_save(),
+            Message::FileAutoDownloadRemove => self.handle_file_auto_download_remove(),
+            Message::FileAutoDownloadCancel => self.handle_file_auto_download_cancel(),
+            Message::AutoDownloadTick => self.handle_auto_download_tick(),
             Message::FileDragHovered => self.handle_file_drag_hovered(),
             Message::FileDragDropped(path) => self.handle_file_drag_dropped(path),
             Message::FileDragLeft => self.handle_file_drag_left(),
@@ -977,6 +1464,11 @@ impl NexusApp {
             Message::FileSearchResultOpen(result) => self.handle_file_search_result_open(result),
             Message::FileSearchSortBy(column) => self.handle_file_search_sort_by(column),
 
+            // File highlights
+            Message::FileShowHighlights => self.handle_file_show_highlights(),
+            Message::FileHighlightOpen(file) => self.handle_file_highlight_open(file),
+            Message::FileHighlightDownload(file) => self.handle_file_highlight_download(file),
+
             // Transfer management
             Message::TransferProgress(event) => self.handle_transfer_progress(event),
             Message::TransferPause(id) => self.handle_transfer_pause(id),
@@ -984,10 +1476,48 @@ impl NexusApp {
             Message::TransferCancel(id) => self.handle_transfer_cancel(id),
             Message::TransferRemove(id) => self.handle_transfer_remove(id),
             Message::TransferOpenFolder(id) => self.handle_transfer_open_folder(id),
+            Message::TransferReveal(id) => self.handle_transfer_reveal(id),
+            Message::TransferOpenFile(id) => self.handle_transfer_open_file(id),
+            Message::TransferCopyChecksum(id) => self.handle_transfer_copy_checksum(id),
+            Message::TransferResolveCollision(id, policy) => {
+                self.handle_transfer_resolve_collision(id, policy)
+            }
+            Message::TransferChecksumComputed(result) => {
+                self.handle_transfer_checksum_computed(result)
+            }
             Message::TransferClearInactive => self.handle_transfer_clear_inactive(),
             Message::TransferMoveUp(id) => self.handle_transfer_move_up(id),
             Message::TransferMoveDown(id) => self.handle_transfer_move_down(id),
             Message::TransferRetry(id) => self.handle_transfer_retry(id),
+            Message::TransferHistoryToggle => self.handle_transfer_history_toggle(),
+            Message::TransferHistorySearchChanged(query) => {
+                self.handle_transfer_history_search_changed(query)
+            }
+            Message::TransferHistoryReveal(id) => self.handle_transfer_history_reveal(id),
+            Message::TransferHistoryRemove(id) => self.handle_transfer_history_remove(id),
+            Message::TransferHistoryClear => self.handle_transfer_history_clear(),
+
+            // Screenshots
+            Message::ScreenshotCaptured(connection_id, target, result) => {
+                self.handle_screenshot_captured(connection_id, target, result)
+            }
+            Message::ScreenshotToolSelected(tool) => self.handle_screenshot_tool_selected(tool),
+            Message::ScreenshotPointerMoved(position) => {
+                self.handle_screenshot_pointer_moved(position)
+            }
+            Message::ScreenshotPointerPressed => self.handle_screenshot_pointer_pressed(),
+            Message::ScreenshotPointerReleased => self.handle_screenshot_pointer_released(),
+            Message::ScreenshotUndo => self.handle_screenshot_undo(),
+            Message::ScreenshotCancel => self.handle_screenshot_cancel(),
+            Message::ScreenshotSend => self.handle_screenshot_send(),
+            Message::ScreenshotSaved(connection_id, result) => {
+                self.handle_screenshot_saved(connection_id, result)
+            }
+
+            // Chat export
+            Message::ChatExported(connection_id, result) => {
+                self.handle_chat_exported(connection_id, result)
+            }
 
             // Voice
             Message::VoiceJoinPressed(target) => self.handle_voice_join_pressed(target),
@@ -996,7 +1526,6 @@ impl NexusApp {
                 self.handle_voice_session_event(connection_id, event)
             }
             Message::VoicePttStateChanged(state) => self.handle_voice_ptt_state_changed(state),
-            Message::VoicePttEvent(event) => self.handle_voice_ptt_event(event),
             Message::VoicePttReleaseDelayExpired(generation) => {
                 self.handle_voice_ptt_release_delay_expired(generation)
             }
@@ -1004,6 +1533,10 @@ impl NexusApp {
             Message::VoiceUserUnmute(nickname) => self.handle_voice_user_unmute(nickname),
             Message::VoiceDeafenToggle => self.handle_voice_deafen_toggle(),
             Message::VoiceMeterTick => Task::none(), // Just triggers re-render
+            Message::VoiceTextTick => self.handle_voice_text_tick(),
+            Message::VoiceClipFileLoaded(connection_id, name, result) => {
+                self.handle_voice_clip_file_loaded(connection_id, name, result)
+            }
 
             // Audio settings
             Message::AudioRefreshDevices => self.handle_audio_refresh_devices(),
@@ -1055,6 +1588,14 @@ impl NexusApp {
                 self.update_voice_processor_settings();
                 Task::none()
             }
+            Message::AudioClipVolumeChanged(volume) => {
+                self.config.settings.audio.clip_volume = volume;
+                Task::none()
+            }
+            Message::AudioVoiceTextTts(enabled) => {
+                self.config.settings.audio.voice_text_tts = enabled;
+                Task::none()
+            }
 
             // Toasts
             Message::ToastDismiss(id) => {
@@ -1066,6 +1607,21 @@ impl NexusApp {
                 Task::none()
             }
 
+            // Global Hotkeys
+            Message::WindowHotkeyRegister => self.register_window_hotkey(),
+            Message::GlobalHotkeyEvent(event) => self.handle_global_hotkey_event(event),
+            #[cfg(target_os = "linux")]
+            Message::GlobalShortcut(shortcut, pressed) => {
+                self.handle_global_shortcut(shortcut, pressed)
+            }
+            #[cfg(target_os = "linux")]
+            Message::GlobalShortcutsUnavailable(error) => {
+                self.handle_global_shortcuts_unavailable(error)
+            }
+            Message::WindowKeyCapture => self.handle_window_key_capture(),
+            Message::WindowKeyCaptured(key) => self.handle_window_key_captured(key),
+            Message::WindowKeyCleared => self.handle_window_key_cleared(),
+
             // System Tray (Windows/Linux only)
             #[cfg(not(target_os = "macos"))]
             Message::TrayPoll => {
@@ -1119,6 +1675,16 @@ impl NexusApp {
                 self.update_tray_from_settings()
             }
 
+            // Notification actions (macOS/Windows only)
+            #[cfg(any(target_os = "macos", target_os = "windows"))]
+            Message::NotificationActivated(target) => self.handle_notification_activated(target),
+            #[cfg(any(target_os = "macos", target_os = "windows"))]
+            Message::NotificationReply(target, text) => {
+                self.handle_notification_reply(target, text)
+            }
+            #[cfg(any(target_os = "macos", target_os = "windows"))]
+            Message::NotificationMarkRead(target) => self.handle_notification_mark_read(target),
+
             // URI scheme
             Message::HandleNexusUri(uri) => self.handle_nexus_uri(uri),
             Message::UriReceivedFromIpc(uri_str) => {
@@ -1150,8 +1716,18 @@ impl NexusApp {
     /// and network message streams for each active connection.
     fn subscription(&self) -> Subscription<Message> {
         let mut subscriptions = vec![
-            // Keyboard and general events
-            iced::event::listen().map(Message::Event),
+            // Keyboard and general events, with the window they happened in
+            iced::event::listen_with(|event, status, id| match status {
+                iced::event::Status::Ignored => Some(Message::Event(id, event)),
+                // Text inputs swallow Ctrl+K and Ctrl+U, but the quick switcher
+                // and the user list filter open from anywhere
+                iced::event::Status::Captured
+                    if quick_switcher::is_toggle_key(&event) || user_list::is_focus_key(&event) =>
+                {
+                    Some(Message::Event(id, event))
+                }
+                iced::event::Status::Captured => None,
+            }),
             // Window close requests (we handle saving before exit)
             iced::window::close_requests().map(Message::WindowCloseRequested),
             // IPC listener for receiving URIs from other instances
@@ -1162,6 +1738,15 @@ impl NexusApp {
         #[cfg(target_os = "macos")]
         subscriptions.push(Subscription::run(macos_url::url_stream));
 
+        // Listen for clicks, replies and "Mark read" on native notifications
+        #[cfg(any(target_os = "macos", target_os = "windows"))]
+        subscriptions.push(Subscription::run(notifications::response_stream));
+
+        // Look for servers on the local network while the connection form is shown
+        if self.active_connection.is_none() {
+            subscriptions.push(network::lan_discovery_subscription());
+        }
+
         // Subscribe to all active connections
         for conn in self.connections.values() {
             subscriptions.push(Subscription::run_with(
@@ -1268,9 +1853,6 @@ impl NexusApp {
         if let Some(connection_id) = self.active_voice_connection {
             subscriptions.push(voice::subscription::voice_event_subscription(connection_id));
 
-            // Subscribe to PTT hotkey events when in voice
-            subscriptions.push(voice::ptt::ptt_subscription());
-
             // Subscribe to VU meter ticks when transmitting (for UI updates)
             if self.is_local_speaking {
                 subscriptions.push(
@@ -1280,6 +1862,128 @@ impl NexusApp {
             }
         }
 
+        // Expire voice text overlay messages while any are shown
+        if self.connections.values().any(|conn| {
+            conn.voice_session
+                .as_ref()
+                .is_some_and(|session| !session.texts.is_empty())
+        }) {
+            subscriptions.push(
+                iced::time::every(std::time::Duration::from_secs(VOICE_TEXT_CHECK_SECS))
+                    .map(|_| Message::VoiceTextTick),
+            );
+        }
+
+        // Subscribe to global hotkeys (PTT while in voice, show/hide window when set).
+        // On Wayland they're bound through the shortcuts portal instead.
+        if self.portal_shortcuts_active() {
+            #[cfg(target_os = "linux")]
+            {
+                let bindings = self.portal_shortcut_bindings();
+                if !bindings.is_empty() {
+                    subscriptions.push(hotkeys::portal::shortcuts_subscription(bindings));
+                }
+            }
+        } else if self.active_voice_connection.is_some()
+            || self
+                .window_hotkey
+                .as_ref()
+                .is_some_and(|hotkey| hotkey.is_registered())
+        {
+            subscriptions.push(hotkeys::hotkey_subscription());
+        }
+
+        // Ping connections in the background for the server list health indicator
+        if !self.connections.is_empty() {
+            subscriptions.push(
+                iced::time::every(std::time::Duration::from_secs(HEALTH_PING_INTERVAL_SECS))
+                    .map(|_| Message::HealthPingTick),
+            );
+        }
+
+        // Give up on requests the server never answered
+        if !self.connections.is_empty() {
+            subscriptions.push(
+                iced::time::every(std::time::Duration::from_secs(REQUEST_TIMEOUT_CHECK_SECS))
+                    .map(|_| Message::RequestTimeoutTick),
+            );
+        }
+
+        // Keep the server time in the status bar live
+        if self
+            .active_connection
+            .and_then(|conn_id| self.connections.get(&conn_id))
+            .is_some_and(|conn| conn.server_clock.is_some())
+        {
+            subscriptions.push(
+                iced::time::every(std::time::Duration::from_secs(STATUS_BAR_CLOCK_SECS))
+                    .map(|_| Message::StatusBarClockTick),
+            );
+        }
+
+        // Poll the connection monitor while its transfers tab is visible so queue
+        // positions and progress stay live
+        if let Some(conn_id) = self.active_connection
+            && let Some(conn) = self.connections.get(&conn_id)
+            && conn.active_panel == ActivePanel::ConnectionMonitor
+            && conn.connection_monitor.active_tab == ConnectionMonitorTab::Transfers
+        {
+            subscriptions.push(
+                iced::time::every(std::time::Duration::from_secs(
+                    CONNECTION_MONITOR_REFRESH_SECS,
+                ))
+                .map(|_| Message::ConnectionMonitorTick),
+            );
+        }
+
+        // Refresh the admin dashboard while it's open
+        if self.active_panel() == ActivePanel::AdminDashboard {
+            subscriptions.push(
+                iced::time::every(std::time::Duration::from_secs(ADMIN_DASHBOARD_REFRESH_SECS))
+                    .map(|_| Message::AdminDashboardTick),
+            );
+        }
+
+        // Poll watched upload folders while a connection with one is open
+        if self
+            .connections
+            .values()
+            .any(|conn| self.upload_watch_for(conn).is_some())
+        {
+            subscriptions.push(
+                iced::time::every(std::time::Duration::from_secs(UPLOAD_WATCH_INTERVAL_SECS))
+                    .map(|_| Message::UploadWatchTick),
+            );
+        }
+
+        // Check auto-download folders while a connection with rules is open
+        if self
+            .connections
+            .values()
+            .any(|conn| !self.auto_download_rules_for(conn).is_empty())
+        {
+            subscriptions.push(
+                iced::time::every(std::time::Duration::from_secs(AUTO_DOWNLOAD_INTERVAL_SECS))
+                    .map(|_| Message::AutoDownloadTick),
+            );
+        }
+
+        // Check the quiet hours schedule while connected so DND is advertised on time
+        if self.config.settings.quiet_hours.enabled && !self.connections.is_empty() {
+            subscriptions.push(
+                iced::time::every(std::time::Duration::from_secs(QUIET_HOURS_CHECK_SECS))
+                    .map(|_| Message::QuietHoursTick),
+            );
+        }
+
+        // Check for idle time while connected so away status follows activity
+        if self.config.settings.auto_away.enabled && !self.connections.is_empty() {
+            subscriptions.push(
+                iced::time::every(std::time::Duration::from_secs(IDLE_CHECK_SECS))
+                    .map(|_| Message::IdleTick),
+            );
+        }
+
         // Subscribe to tray events when tray is active (Windows/Linux only)
         #[cfg(not(target_os = "macos"))]
         if self.tray_manager.is_some() {
@@ -1289,15 +1993,33 @@ impl NexusApp {
         Subscription::batch(subscriptions)
     }
 
-    /// Render the current application state to the UI
+    /// Render a window
     ///
-    /// Delegates to `views::main_layout()` for all rendering logic.
-    fn view(&self) -> Element<'_, Message> {
+    /// Popped-out chat windows show their tab; the main window delegates to
+    /// `views::main_layout()`.
+    fn view(&self, window: iced::window::Id) -> Element<'_, Message> {
+        if let Some(chat_window) = self.chat_windows.get(&window) {
+            let Some(conn) = self.connections.get(&chat_window.connection_id) else {
+                return iced::widget::Space::new().into();
+            };
+            return views::chat_window_view(
+                window,
+                chat_window,
+                conn,
+                self.theme(window),
+                self.config.settings.chat_font_size,
+                views::TimestampSettings {
+                    show_timestamps: self.config.settings.show_timestamps,
+                    use_24_hour_time: self.config.settings.use_24_hour_time,
+                    show_seconds: self.config.settings.show_seconds,
+                },
+            );
+        }
+
         // Get current connection state
         let active_conn = self
             .active_connection
             .and_then(|id| self.connections.get(&id));
-        let message_input = active_conn.map(|c| c.message_input.as_str()).unwrap_or("");
         let user_management = active_conn.map(|c| &c.user_management);
 
         // Get news body content for current connection
@@ -1350,7 +2072,7 @@ impl NexusApp {
             .unwrap_or_else(voice::audio::AudioDevice::system_default);
 
         let config = ViewConfig {
-            theme: self.theme(),
+            theme: self.theme(window),
             show_connection_events: self.config.settings.show_connection_events,
             show_join_leave_events: self.config.settings.show_join_leave_events,
             chat_history_retention: self.config.settings.chat_history_retention,
@@ -1358,14 +2080,21 @@ impl NexusApp {
             show_timestamps: self.config.settings.show_timestamps,
             use_24_hour_time: self.config.settings.use_24_hour_time,
             show_seconds: self.config.settings.show_seconds,
+            split_view: self.config.settings.split_view,
+            spell_check: self.config.settings.spell_check,
+            spell_settings: self.spell_settings(),
+            spell_dictionary_missing: self.spell_checker_loaded && self.spell_checker.is_none(),
+            send_read_receipts: self.config.settings.send_read_receipts,
+            user_list: self.config.settings.user_list,
             settings_form: self.settings_form.as_ref(),
             connections: &self.connections,
             active_connection: self.active_connection,
             bookmarks: &self.config.bookmarks,
+            bookmark_groups: &self.config.bookmark_groups,
             bookmark_errors: &self.bookmark_errors,
             connection_form: &self.connection_form,
             bookmark_edit: &self.bookmark_edit,
-            message_input,
+            message_input: &self.chat_input_content,
             nickname: self.config.settings.nickname.as_deref().unwrap_or(""),
             user_management,
             ui_state: &self.ui_state,
@@ -1375,15 +2104,21 @@ impl NexusApp {
             download_path: self.config.settings.download_path.as_deref(),
             show_hidden: self.config.settings.show_hidden_files,
             transfer_manager: &self.transfer_manager,
+            transfer_history: &self.transfer_history,
             queue_transfers: self.config.settings.queue_transfers,
             download_limit: self.config.settings.download_limit,
             upload_limit: self.config.settings.upload_limit,
+            auto_open_folder: self.config.settings.auto_open_folder,
+            collision_policy: self.config.settings.collision_policy,
+            require_fingerprint_approval: self.config.settings.require_fingerprint_approval,
             max_scrollback: self.config.settings.max_scrollback,
             show_drop_overlay: self.dragging_files && self.can_accept_file_drop(),
             event_settings: &self.config.settings.event_settings,
             notifications_enabled: self.config.settings.notifications_enabled,
             sound_enabled: self.config.settings.sound_enabled,
             sound_volume: self.config.settings.sound_volume,
+            do_not_disturb: self.config.settings.do_not_disturb,
+            quiet_hours: self.config.settings.quiet_hours,
             voice_target: self.get_voice_target_for_current_tab(),
             // Audio settings
             output_devices,
@@ -1403,25 +2138,51 @@ impl NexusApp {
             agc: self.config.settings.audio.agc,
             transient_suppression: self.config.settings.audio.transient_suppression,
             mic_boost: self.config.settings.audio.mic_boost,
+            clip_volume: self.config.settings.audio.clip_volume,
+            voice_text_tts: self.config.settings.audio.voice_text_tts,
             is_local_speaking: self.is_local_speaking,
             is_deafened: self.is_deafened,
             // System Tray settings
             show_tray_icon: self.config.settings.show_tray_icon,
             minimize_to_tray: self.config.settings.minimize_to_tray,
+            // Global hotkeys
+            toggle_window_key: self.config.settings.toggle_window_key.as_deref(),
+            // Auto away
+            auto_away: self.config.settings.auto_away,
+            // First-run tips
+            pending_tips: &self.config.settings.pending_tips,
         };
 
         let main_view = views::main_layout(config);
 
+        // Quick switcher (Ctrl+K) floats over everything else
+        let main_view = match &self.ui_state.quick_switcher {
+            Some(state) => stack![
+                main_view,
+                views::quick_switcher_view(state, self.quick_switch_matches())
+            ]
+            .into(),
+            None => main_view,
+        };
+
+        // Password vault dialog takes over the window until it's dismissed
+        if let Some(dialog) = &self.password_vault {
+            return views::password_vault_dialog(dialog);
+        }
+
         // Overlay fingerprint mismatch dialog if present (show first in queue)
         if let Some(mismatch) = self.fingerprint_mismatch_queue.front() {
-            return views::fingerprint_mismatch_dialog(mismatch);
+            return views::fingerprint_mismatch_dialog(
+                mismatch,
+                self.config.settings.tip_pending(Tip::Fingerprint),
+            );
         }
 
         // Wrap with toast container for transient notifications
         self.toasts.view(main_view)
     }
 
-    fn theme(&self) -> Theme {
+    fn theme(&self, _window: iced::window::Id) -> Theme {
         self.config.settings.theme.to_iced_theme()
     }
 }
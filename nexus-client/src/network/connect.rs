@@ -1,19 +1,24 @@
 //! Server connection, handshake, and login
 
+use ring::signature::{ED25519, UnparsedPublicKey};
 use tokio::io::BufReader;
 
 use nexus_common::framing::{FrameReader, FrameWriter};
+use nexus_common::identity::identity_statement;
 use nexus_common::io::{read_server_message, send_client_message};
-use nexus_common::protocol::{ClientMessage, ServerMessage};
+use nexus_common::pow::solve_pow;
+use nexus_common::protocol::{ClientMessage, OidcLogin, ServerMessage};
 use nexus_common::{DEFAULT_TRANSFER_PORT, PROTOCOL_VERSION};
 
 use crate::i18n::{DEFAULT_LOCALE, t, t_args};
 use crate::types::{ConnectionInfo, NetworkConnection};
 
 use super::constants::DEFAULT_FEATURES;
+use super::resolve::{Endpoint, candidate_endpoints};
+use super::sso;
 use super::stream::setup_communication_channels;
-use super::tls::establish_connection;
-use super::types::{ConnectionParams, LoginInfo, Reader, Writer};
+use super::tls::{establish_connection, should_bypass_proxy};
+use super::types::{ConnectionParams, HandshakeInfo, LoginInfo, ProxyConfig, Reader, Writer};
 
 /// Connect to server, perform handshake and login
 ///
@@ -21,28 +26,82 @@ use super::types::{ConnectionParams, LoginInfo, Reader, Writer};
 /// then sets up bidirectional communication channels. Returns a NetworkConnection
 /// handle for sending messages to the server.
 pub async fn connect_to_server(params: ConnectionParams) -> Result<NetworkConnection, String> {
-    // Establish TCP connection and get certificate fingerprint
-    // If proxy is configured, tunnel through SOCKS5
-    let (tls_stream, fingerprint) =
-        establish_connection(&params.server_address, params.port, params.proxy.as_ref()).await?;
-
-    let (reader, writer) = tokio::io::split(tls_stream);
-    let buf_reader = BufReader::new(reader);
-    let mut frame_reader = FrameReader::new(buf_reader);
-    let mut frame_writer = FrameWriter::new(writer);
+    // Establish TCP connection (tunnelled through SOCKS5 if a proxy is configured)
+    let (mut frame_reader, mut frame_writer, mut fingerprint, endpoint) =
+        open_first_connection(&params).await?;
 
     // Perform handshake and login
-    perform_handshake(&mut frame_reader, &mut frame_writer).await?;
-    let login_info = perform_login(
-        &mut frame_reader,
-        &mut frame_writer,
-        params.username.clone(),
-        params.password.clone(),
-        params.nickname.clone(),
-        params.locale,
-        params.avatar,
-    )
-    .await?;
+    let mut handshake =
+        perform_handshake(&mut frame_reader, &mut frame_writer, &fingerprint).await?;
+
+    // A login token from the last session logs straight back in. If the server
+    // no longer accepts it, it closes the connection, so start over on a new one
+    let resumed = match params.login_token.clone() {
+        Some(token) => {
+            let pow_nonce = solve_handshake_pow(handshake.pow_challenge.take()).await?;
+            let result = perform_login(
+                &mut frame_reader,
+                &mut frame_writer,
+                params.username.clone(),
+                String::new(),
+                params.nickname.clone(),
+                params.locale.clone(),
+                params.avatar.clone(),
+                pow_nonce,
+                None,
+                Some(token),
+            )
+            .await;
+            if result.is_err() {
+                (frame_reader, frame_writer, fingerprint) =
+                    open_connection(&endpoint, params.proxy.as_ref()).await?;
+                handshake =
+                    perform_handshake(&mut frame_reader, &mut frame_writer, &fingerprint).await?;
+            }
+            result.ok()
+        }
+        None => None,
+    };
+
+    let login_info = match resumed {
+        Some(login_info) => login_info,
+        None => {
+            let oidc = if params.use_sso {
+                let provider = handshake
+                    .oidc
+                    .take()
+                    .ok_or_else(|| t("err-sso-not-offered"))?;
+
+                // The server drops connections that sit idle before login, and the
+                // browser sign-in can take minutes, so sign in first and reconnect
+                drop((frame_reader, frame_writer));
+                let login = sso::sign_in(&provider).await?;
+
+                (frame_reader, frame_writer, fingerprint) =
+                    open_connection(&endpoint, params.proxy.as_ref()).await?;
+                handshake =
+                    perform_handshake(&mut frame_reader, &mut frame_writer, &fingerprint).await?;
+                Some(login)
+            } else {
+                None
+            };
+
+            let pow_nonce = solve_handshake_pow(handshake.pow_challenge.take()).await?;
+            perform_login(
+                &mut frame_reader,
+                &mut frame_writer,
+                params.username.clone(),
+                params.password.clone(),
+                params.nickname.clone(),
+                params.locale,
+                params.avatar,
+                pow_nonce,
+                oidc,
+                None,
+            )
+            .await?
+        }
+    };
 
     // Build connection info from connection params and login response
     // Resolve server_name: prefer server-provided name, fall back to address
@@ -51,15 +110,23 @@ pub async fn connect_to_server(params: ConnectionParams) -> Result<NetworkConnec
         .clone()
         .unwrap_or_else(|| params.server_address.clone());
 
+    // Record where we actually connected, so transfers and voice go there too
     let connection_info = ConnectionInfo {
         server_name,
-        address: params.server_address,
-        port: params.port,
+        address: endpoint.host,
+        port: endpoint.port,
         transfer_port: login_info.transfer_port,
         certificate_fingerprint: fingerprint,
-        username: params.username,
+        identity_key: handshake.identity_key,
+        // Single sign-on accounts are named by the server, not the form
+        username: if params.use_sso {
+            login_info.nickname.clone()
+        } else {
+            params.username
+        },
         password: params.password,
         nickname: params.nickname.unwrap_or_default(),
+        login_token: login_info.login_token.clone(),
     };
 
     // Set up bidirectional communication
@@ -73,10 +140,73 @@ pub async fn connect_to_server(params: ConnectionParams) -> Result<NetworkConnec
     .await
 }
 
+/// Establish the TLS connection to the first endpoint that answers
+///
+/// Tries the server's SRV targets, its address, then its fallback addresses
+/// in order. Returns the endpoint that worked so later reconnects during
+/// login go to the same server, or the last error if none did.
+async fn open_first_connection(
+    params: &ConnectionParams,
+) -> Result<(Reader, Writer, String, Endpoint), String> {
+    let proxied = params.proxy.is_some() && !should_bypass_proxy(&params.server_address);
+    let endpoints = candidate_endpoints(
+        &params.server_address,
+        params.port,
+        &params.fallback_addresses,
+        proxied,
+    )
+    .await;
+
+    let mut last_error = None;
+    for endpoint in endpoints {
+        match open_connection(&endpoint, params.proxy.as_ref()).await {
+            Ok((reader, writer, fingerprint)) => {
+                return Ok((reader, writer, fingerprint, endpoint));
+            }
+            Err(e) => last_error = Some(e),
+        }
+    }
+    Err(last_error.unwrap_or_else(|| {
+        t_args(
+            "err-could-not-resolve",
+            &[("address", &params.server_address)],
+        )
+    }))
+}
+
+/// Establish the TLS connection (through the proxy, if configured)
+///
+/// Returns the framed reader and writer and the certificate fingerprint.
+async fn open_connection(
+    endpoint: &Endpoint,
+    proxy: Option<&ProxyConfig>,
+) -> Result<(Reader, Writer, String), String> {
+    let (tls_stream, fingerprint) =
+        establish_connection(&endpoint.host, endpoint.port, proxy).await?;
+
+    let (reader, writer) = tokio::io::split(tls_stream);
+    let buf_reader = BufReader::new(reader);
+    Ok((
+        FrameReader::new(buf_reader),
+        FrameWriter::new(writer),
+        fingerprint,
+    ))
+}
+
 /// Perform protocol handshake with the server
-async fn perform_handshake(reader: &mut Reader, writer: &mut Writer) -> Result<(), String> {
+///
+/// Returns the server's identity key if it sent one with a valid signature
+/// for the certificate we connected with, the proof-of-work challenge and
+/// difficulty if the server wants one with the login, and the identity
+/// provider if the server offers single sign-on.
+async fn perform_handshake(
+    reader: &mut Reader,
+    writer: &mut Writer,
+    fingerprint: &str,
+) -> Result<HandshakeInfo, String> {
     let handshake = ClientMessage::Handshake {
         version: PROTOCOL_VERSION.to_string(),
+        client: Some(format!("Nexus BBS {}", env!("CARGO_PKG_VERSION"))),
     };
     send_client_message(writer, &handshake)
         .await
@@ -88,7 +218,19 @@ async fn perform_handshake(reader: &mut Reader, writer: &mut Writer) -> Result<(
         .ok_or_else(|| t("err-connection-closed"))?;
 
     match received.message {
-        ServerMessage::HandshakeResponse { success: true, .. } => Ok(()),
+        ServerMessage::HandshakeResponse {
+            success: true,
+            identity_key,
+            identity_signature,
+            pow_challenge,
+            pow_difficulty,
+            oidc,
+            ..
+        } => Ok(HandshakeInfo {
+            identity_key: verify_identity(identity_key, identity_signature.as_deref(), fingerprint),
+            pow_challenge: pow_challenge.zip(pow_difficulty),
+            oidc,
+        }),
         ServerMessage::HandshakeResponse {
             success: false,
             error,
@@ -101,7 +243,52 @@ async fn perform_handshake(reader: &mut Reader, writer: &mut Writer) -> Result<(
     }
 }
 
+/// Check the server's identity signature over the certificate fingerprint
+///
+/// A missing or invalid signature is treated as no identity, so the
+/// connection falls back to plain certificate pinning.
+fn verify_identity(
+    identity_key: Option<String>,
+    identity_signature: Option<&str>,
+    fingerprint: &str,
+) -> Option<String> {
+    let identity_key = identity_key?;
+    let public_key = hex::decode(&identity_key).ok()?;
+    let signature = hex::decode(identity_signature?).ok()?;
+    UnparsedPublicKey::new(&ED25519, public_key)
+        .verify(&identity_statement(fingerprint), &signature)
+        .ok()?;
+    Some(identity_key.to_lowercase())
+}
+
+/// Solve the handshake's proof-of-work challenge, if it sent one
+async fn solve_handshake_pow(
+    pow_challenge: Option<(String, u8)>,
+) -> Result<Option<String>, String> {
+    match pow_challenge {
+        Some((challenge, difficulty)) => {
+            Ok(Some(solve_pow_challenge(challenge, difficulty).await?))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Solve the server's proof-of-work challenge off the async runtime
+async fn solve_pow_challenge(challenge: String, difficulty: u8) -> Result<String, String> {
+    tokio::task::spawn_blocking(move || solve_pow(&challenge, difficulty))
+        .await
+        .ok()
+        .flatten()
+        .ok_or_else(|| {
+            t_args(
+                "err-proof-of-work-too-hard",
+                &[("difficulty", &difficulty.to_string())],
+            )
+        })
+}
+
 /// Perform login and return login info (session ID, admin status, permissions, locale)
+#[allow(clippy::too_many_arguments)]
 async fn perform_login(
     reader: &mut Reader,
     writer: &mut Writer,
@@ -110,6 +297,9 @@ async fn perform_login(
     nickname: Option<String>,
     locale: String,
     avatar: Option<String>,
+    pow_nonce: Option<String>,
+    oidc: Option<OidcLogin>,
+    login_token: Option<String>,
 ) -> Result<LoginInfo, String> {
     let login = ClientMessage::Login {
         username,
@@ -118,6 +308,9 @@ async fn perform_login(
         locale,
         avatar,
         nickname,
+        pow_nonce,
+        oidc,
+        login_token,
     };
     send_client_message(writer, &login)
         .await
@@ -138,6 +331,7 @@ async fn perform_login(
             channels,
             locale,
             nickname,
+            login_token,
             ..
         } => Ok(LoginInfo {
             is_admin: is_admin.unwrap_or(false),
@@ -159,6 +353,15 @@ async fn perform_login(
             max_transfers_per_ip: server_info
                 .as_ref()
                 .and_then(|info| info.max_transfers_per_ip),
+            max_active_transfers: server_info
+                .as_ref()
+                .and_then(|info| info.max_active_transfers),
+            transfer_small_files_first: server_info
+                .as_ref()
+                .and_then(|info| info.transfer_small_files_first),
+            trusted_transfer_weight: server_info
+                .as_ref()
+                .and_then(|info| info.trusted_transfer_weight),
             file_reindex_interval: server_info
                 .as_ref()
                 .and_then(|info| info.file_reindex_interval),
@@ -168,10 +371,17 @@ async fn perform_login(
             auto_join_channels: server_info
                 .as_ref()
                 .and_then(|info| info.auto_join_channels.clone()),
+            nat: server_info.as_ref().and_then(|info| info.nat.clone()),
+            news_image_limits: server_info.as_ref().and_then(|info| info.news_image_limits),
+            max_message_length: server_info
+                .as_ref()
+                .and_then(|info| info.max_message_length),
+            server_time: server_info.as_ref().and_then(|info| info.time.clone()),
             transfer_port: server_info
                 .map(|info| info.transfer_port)
                 .unwrap_or(DEFAULT_TRANSFER_PORT),
             locale: locale.unwrap_or_else(|| DEFAULT_LOCALE.to_string()),
+            login_token,
         }),
         ServerMessage::LoginResponse {
             success: true,
@@ -192,3 +402,42 @@ async fn perform_login(
         _ => Err(t("err-unexpected-login-response")),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ring::signature::{Ed25519KeyPair, KeyPair};
+
+    use super::*;
+
+    const FINGERPRINT: &str = "AA:BB:CC:DD";
+
+    fn signed(fingerprint: &str) -> (String, String) {
+        let key_pair = Ed25519KeyPair::from_seed_unchecked(&[3; 32]).unwrap();
+        let signature = key_pair.sign(&identity_statement(fingerprint));
+        (
+            hex::encode(key_pair.public_key().as_ref()),
+            hex::encode(signature.as_ref()),
+        )
+    }
+
+    #[test]
+    fn test_verify_identity() {
+        let (key, signature) = signed(FINGERPRINT);
+
+        assert_eq!(
+            verify_identity(Some(key.clone()), Some(&signature), FINGERPRINT),
+            Some(key.clone())
+        );
+        // Signature for a different certificate
+        assert_eq!(
+            verify_identity(Some(key.clone()), Some(&signature), "11:22:33:44"),
+            None
+        );
+        assert_eq!(verify_identity(Some(key.clone()), None, FINGERPRINT), None);
+        assert_eq!(verify_identity(None, Some(&signature), FINGERPRINT), None);
+        assert_eq!(
+            verify_identity(Some("zz".to_string()), Some(&signature), FINGERPRINT),
+            None
+        );
+    }
+}
@@ -8,6 +8,12 @@ use ipnet::{Ipv4Net, Ipv6Net};
 /// Connection timeout duration (30 seconds)
 pub const CONNECTION_TIMEOUT: Duration = Duration::from_secs(30);
 
+/// SRV record service and protocol labels prepended to a server's domain
+pub const SRV_SERVICE: &str = "_nexus._tcp";
+
+/// How long to wait for a SRV lookup before connecting to the address itself
+pub const SRV_LOOKUP_TIMEOUT: Duration = Duration::from_secs(5);
+
 /// Buffer size for the Iced stream channel
 pub const STREAM_CHANNEL_SIZE: usize = 100;
 
@@ -31,3 +37,12 @@ pub const IPV6_ULA: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0xfc00, 0, 0, 0,
 /// Most consumer NAT routers drop idle TCP connections after 30-60 minutes.
 /// Sending a ping every 5 minutes keeps the NAT mapping alive.
 pub const PING_INTERVAL: u64 = 300;
+
+/// How long to wait for the browser to finish a single sign-on (5 minutes)
+pub const SSO_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Path the identity provider redirects the browser back to
+pub const SSO_CALLBACK_PATH: &str = "/callback";
+
+/// Scopes requested from the identity provider
+pub const SSO_SCOPE: &str = "openid profile email";
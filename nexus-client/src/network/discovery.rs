@@ -0,0 +1,144 @@
+//! LAN server discovery over mDNS/DNS-SD (Bonjour)
+
+use std::collections::HashSet;
+use std::net::IpAddr;
+
+use iced::Subscription;
+use iced::futures::{SinkExt, Stream};
+use iced::stream;
+use mdns_sd::{ServiceDaemon, ServiceEvent, ServiceInfo};
+use nexus_common::{MDNS_SERVICE_TYPE, MDNS_TXT_NAME};
+
+use crate::types::{LanServer, Message};
+
+use super::constants::STREAM_CHANNEL_SIZE;
+
+/// Browse the local network for servers advertising themselves
+///
+/// Emits `LanDiscoveryStarted` first, then `LanServerFound` and
+/// `LanServerLost` as servers come and go. Browsing stops when the
+/// subscription is dropped.
+pub fn lan_discovery_subscription() -> Subscription<Message> {
+    Subscription::run(lan_discovery_stream)
+}
+
+/// Stream of LAN discovery events
+fn lan_discovery_stream() -> impl Stream<Item = Message> {
+    stream::channel(
+        STREAM_CHANNEL_SIZE,
+        |mut output: iced::futures::channel::mpsc::Sender<Message>| async move {
+            // mDNS can be unavailable (no multicast interface, blocked port);
+            // then there's simply nothing to list
+            let Ok(daemon) = ServiceDaemon::new() else {
+                return;
+            };
+            let Ok(events) = daemon.browse(MDNS_SERVICE_TYPE) else {
+                let _ = daemon.shutdown();
+                return;
+            };
+
+            if output.send(Message::LanDiscoveryStarted).await.is_ok() {
+                while let Ok(event) = events.recv_async().await {
+                    let message = match event {
+                        ServiceEvent::ServiceResolved(info) => match lan_server(&info) {
+                            Some(server) => Message::LanServerFound(server),
+                            None => continue,
+                        },
+                        ServiceEvent::ServiceRemoved(_, fullname) => {
+                            Message::LanServerLost(fullname)
+                        }
+                        _ => continue,
+                    };
+                    if output.send(message).await.is_err() {
+                        break;
+                    }
+                }
+            }
+
+            let _ = daemon.shutdown();
+        },
+    )
+}
+
+/// Build a LAN server entry from a resolved service
+fn lan_server(info: &ServiceInfo) -> Option<LanServer> {
+    let address = pick_address(info.get_addresses())?;
+    let fullname = info.get_fullname().to_string();
+    let name = info
+        .get_property_val_str(MDNS_TXT_NAME)
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .unwrap_or_else(|| instance_name(&fullname));
+
+    Some(LanServer {
+        fullname,
+        name,
+        address: address.to_string(),
+        port: info.get_port(),
+    })
+}
+
+/// Choose the address to connect to: IPv4 first, then routable IPv6
+///
+/// Link-local IPv6 addresses are skipped since they need an interface
+/// scope that mDNS doesn't tell us. The lowest address wins so the
+/// choice doesn't change between announcements.
+fn pick_address(addresses: &HashSet<IpAddr>) -> Option<IpAddr> {
+    let v4 = addresses.iter().filter(|ip| ip.is_ipv4()).min();
+    let v6 = addresses
+        .iter()
+        .filter(|ip| match ip {
+            IpAddr::V6(v6) => !v6.is_unicast_link_local() && !v6.is_loopback(),
+            IpAddr::V4(_) => false,
+        })
+        .min();
+    v4.or(v6).copied()
+}
+
+/// Instance part of a full service name ("My Server._nexus._tcp.local.")
+fn instance_name(fullname: &str) -> String {
+    fullname
+        .strip_suffix(MDNS_SERVICE_TYPE)
+        .map(|instance| instance.trim_end_matches('.'))
+        .unwrap_or(fullname)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pick_address_prefers_ipv4() {
+        let addresses: HashSet<IpAddr> = ["fd00::5", "192.168.1.20", "192.168.1.10"]
+            .iter()
+            .map(|a| a.parse().unwrap())
+            .collect();
+        assert_eq!(
+            pick_address(&addresses),
+            Some("192.168.1.10".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_pick_address_skips_link_local_ipv6() {
+        let addresses: HashSet<IpAddr> = ["fe80::1", "fd00::5"]
+            .iter()
+            .map(|a| a.parse().unwrap())
+            .collect();
+        assert_eq!(pick_address(&addresses), Some("fd00::5".parse().unwrap()));
+
+        let link_local: HashSet<IpAddr> = ["fe80::1".parse().unwrap()].into_iter().collect();
+        assert_eq!(pick_address(&link_local), None);
+    }
+
+    #[test]
+    fn test_instance_name() {
+        assert_eq!(
+            instance_name("nexus-abcdef012345._nexus._tcp.local."),
+            "nexus-abcdef012345"
+        );
+        assert_eq!(instance_name("something.else."), "something.else.");
+    }
+}
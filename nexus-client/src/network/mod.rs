@@ -2,10 +2,15 @@
 
 mod connect;
 mod constants;
+mod discovery;
+mod resolve;
+mod sso;
 mod stream;
 pub mod tls;
 mod types;
 
 pub use connect::connect_to_server;
+pub use discovery::lan_discovery_subscription;
+pub(crate) use resolve::parse_fallback_address;
 pub use stream::{NETWORK_RECEIVERS, ShutdownHandle, network_stream};
 pub use types::{ConnectionParams, ProxyConfig};
@@ -0,0 +1,232 @@
+//! Server address candidates: SRV records and fallback addresses
+
+use std::net::IpAddr;
+
+use hickory_resolver::TokioResolver;
+use nexus_common::DEFAULT_PORT;
+
+use crate::uri::parse_host_port;
+
+use super::constants::{SRV_LOOKUP_TIMEOUT, SRV_SERVICE};
+
+/// A host and port to try connecting to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct Endpoint {
+    pub host: String,
+    pub port: u16,
+}
+
+/// Build the list of endpoints to try, in order
+///
+/// 1. The targets of the address's `_nexus._tcp` SRV record, if it has one
+/// 2. The address and port themselves
+/// 3. The fallback addresses
+///
+/// The SRV lookup is skipped through a proxy so DNS doesn't leak around it.
+pub(super) async fn candidate_endpoints(
+    address: &str,
+    port: u16,
+    fallback_addresses: &[String],
+    proxied: bool,
+) -> Vec<Endpoint> {
+    let mut endpoints = Vec::new();
+
+    if !proxied && let Some(name) = srv_name(address, port) {
+        endpoints.extend(lookup_srv(&name).await);
+    }
+
+    endpoints.push(Endpoint {
+        host: address.to_string(),
+        port,
+    });
+
+    endpoints.extend(fallback_addresses.iter().filter_map(|entry| {
+        parse_fallback_address(entry).map(|(host, port)| Endpoint { host, port })
+    }));
+
+    dedup_endpoints(endpoints)
+}
+
+/// Parse a fallback address (`host`, `host:port`, or `[ipv6]:port`)
+///
+/// The port defaults to 7500, the same as `nexus://` links.
+pub(crate) fn parse_fallback_address(entry: &str) -> Option<(String, u16)> {
+    let entry = entry.trim();
+    if entry.is_empty() {
+        return None;
+    }
+    parse_host_port(entry)
+        .ok()
+        .filter(|(host, _)| !host.is_empty())
+}
+
+/// SRV record name to look up for an address, if any
+///
+/// Only domain names on the default port are looked up: an explicit port
+/// or an IP address means the user already knows where the server is.
+fn srv_name(address: &str, port: u16) -> Option<String> {
+    let host = address.trim().trim_end_matches('.');
+    let bare = host.trim_start_matches('[').trim_end_matches(']');
+    let bare = bare.split('%').next().unwrap_or(bare);
+    if port != DEFAULT_PORT || !host.contains('.') || bare.parse::<IpAddr>().is_ok() {
+        return None;
+    }
+    Some(format!("{SRV_SERVICE}.{host}"))
+}
+
+/// Look up a SRV record and return its targets in order
+///
+/// Any failure (no record, no resolver, timeout) just means no SRV targets.
+async fn lookup_srv(name: &str) -> Vec<Endpoint> {
+    let Ok(builder) = TokioResolver::builder_tokio() else {
+        return Vec::new();
+    };
+    let resolver = builder.build();
+
+    let lookup = match tokio::time::timeout(SRV_LOOKUP_TIMEOUT, resolver.srv_lookup(name)).await {
+        Ok(Ok(lookup)) => lookup,
+        _ => return Vec::new(),
+    };
+
+    order_srv_records(
+        lookup
+            .iter()
+            .map(|srv| {
+                (
+                    srv.priority(),
+                    srv.weight(),
+                    srv.target().to_utf8(),
+                    srv.port(),
+                )
+            })
+            .collect(),
+    )
+}
+
+/// Order SRV records by priority (lowest first), then weight (highest first)
+///
+/// A target of "." means the service is deliberately not available there.
+fn order_srv_records(mut records: Vec<(u16, u16, String, u16)>) -> Vec<Endpoint> {
+    records.sort_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+    records
+        .into_iter()
+        .filter_map(|(_, _, target, port)| {
+            let host = target.trim_end_matches('.');
+            (!host.is_empty()).then(|| Endpoint {
+                host: host.to_string(),
+                port,
+            })
+        })
+        .collect()
+}
+
+/// Remove repeated endpoints, keeping the first of each
+fn dedup_endpoints(endpoints: Vec<Endpoint>) -> Vec<Endpoint> {
+    let mut unique: Vec<Endpoint> = Vec::with_capacity(endpoints.len());
+    for endpoint in endpoints {
+        if !unique
+            .iter()
+            .any(|e| e.port == endpoint.port && e.host.eq_ignore_ascii_case(&endpoint.host))
+        {
+            unique.push(endpoint);
+        }
+    }
+    unique
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn endpoint(host: &str, port: u16) -> Endpoint {
+        Endpoint {
+            host: host.to_string(),
+            port,
+        }
+    }
+
+    #[test]
+    fn test_srv_name_for_domains_on_default_port() {
+        assert_eq!(
+            srv_name("chat.example.com", DEFAULT_PORT),
+            Some("_nexus._tcp.chat.example.com".to_string())
+        );
+        assert_eq!(
+            srv_name("example.com.", DEFAULT_PORT),
+            Some("_nexus._tcp.example.com".to_string())
+        );
+    }
+
+    #[test]
+    fn test_srv_name_skipped() {
+        assert_eq!(srv_name("example.com", 7600), None);
+        assert_eq!(srv_name("localhost", DEFAULT_PORT), None);
+        assert_eq!(srv_name("192.168.1.10", DEFAULT_PORT), None);
+        assert_eq!(srv_name("::1", DEFAULT_PORT), None);
+        assert_eq!(srv_name("[2001:db8::1]", DEFAULT_PORT), None);
+        assert_eq!(srv_name("fe80::1%eth0", DEFAULT_PORT), None);
+        assert_eq!(srv_name("", DEFAULT_PORT), None);
+    }
+
+    #[test]
+    fn test_order_srv_records() {
+        let ordered = order_srv_records(vec![
+            (20, 0, "backup.example.com.".to_string(), 7500),
+            (10, 5, "b.example.com.".to_string(), 7501),
+            (10, 50, "a.example.com.".to_string(), 7502),
+            (30, 0, ".".to_string(), 0),
+        ]);
+        assert_eq!(
+            ordered,
+            vec![
+                endpoint("a.example.com", 7502),
+                endpoint("b.example.com", 7501),
+                endpoint("backup.example.com", 7500),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_fallback_address() {
+        assert_eq!(
+            parse_fallback_address(" backup.example.com "),
+            Some(("backup.example.com".to_string(), DEFAULT_PORT))
+        );
+        assert_eq!(
+            parse_fallback_address("10.0.0.2:7600"),
+            Some(("10.0.0.2".to_string(), 7600))
+        );
+        assert_eq!(
+            parse_fallback_address("[::1]:7600"),
+            Some(("::1".to_string(), 7600))
+        );
+        assert_eq!(parse_fallback_address(""), None);
+        assert_eq!(parse_fallback_address("host:port"), None);
+        assert_eq!(parse_fallback_address(":7500"), None);
+    }
+
+    #[tokio::test]
+    async fn test_candidate_endpoints_order() {
+        // An IP address skips the SRV lookup, so this doesn't touch the network
+        let endpoints = candidate_endpoints(
+            "192.0.2.1",
+            DEFAULT_PORT,
+            &[
+                "backup.example.com".to_string(),
+                "192.0.2.1".to_string(),
+                "bad:port".to_string(),
+                "[2001:db8::2]:7600".to_string(),
+            ],
+            false,
+        )
+        .await;
+        assert_eq!(
+            endpoints,
+            vec![
+                endpoint("192.0.2.1", DEFAULT_PORT),
+                endpoint("backup.example.com", DEFAULT_PORT),
+                endpoint("2001:db8::2", 7600),
+            ]
+        );
+    }
+}
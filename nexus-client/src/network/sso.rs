@@ -0,0 +1,291 @@
+//! Browser sign-in for servers that offer single sign-on
+//!
+//! Runs the authorization code flow with PKCE: the browser is sent to the
+//! provider's authorization endpoint, and the provider redirects it back to a
+//! short-lived listener on the loopback interface with the code. The server
+//! redeems the code itself, so the client only passes it on together with the
+//! PKCE verifier and nonce it was issued for.
+
+use std::net::Ipv4Addr;
+
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use percent_encoding::{NON_ALPHANUMERIC, percent_decode_str, utf8_percent_encode};
+use ring::rand::{SecureRandom, SystemRandom};
+use sha2::{Digest, Sha256};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::time::timeout;
+
+use nexus_common::protocol::{OidcLogin, OidcProvider};
+use nexus_common::validators::OIDC_REDIRECT_PREFIX;
+
+use crate::i18n::{t, t_args};
+
+use super::constants::{SSO_CALLBACK_PATH, SSO_SCOPE, SSO_TIMEOUT};
+
+/// Largest redirect request read from the browser
+const MAX_CALLBACK_REQUEST_SIZE: usize = 8 * 1024;
+
+/// What the provider sent back to the redirect URI
+#[derive(Debug, PartialEq, Eq)]
+enum Callback {
+    /// Sign-in succeeded
+    Code { code: String, state: String },
+    /// Sign-in was refused or cancelled
+    Error(String),
+}
+
+/// Sign in through the browser and return the login to send to the server
+pub(super) async fn sign_in(provider: &OidcProvider) -> Result<OidcLogin, String> {
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))
+        .await
+        .map_err(|e| t_args("err-sso-failed", &[("error", &e.to_string())]))?;
+    let port = listener
+        .local_addr()
+        .map_err(|e| t_args("err-sso-failed", &[("error", &e.to_string())]))?
+        .port();
+
+    let redirect_uri = format!("{OIDC_REDIRECT_PREFIX}{port}{SSO_CALLBACK_PATH}");
+    let code_verifier = random_string()?;
+    let state = random_string()?;
+    let nonce = random_string()?;
+
+    let url = authorization_url(
+        provider,
+        &redirect_uri,
+        &code_challenge(&code_verifier),
+        &state,
+        &nonce,
+    );
+    open::that(&url).map_err(|e| t_args("err-sso-browser", &[("error", &e.to_string())]))?;
+
+    let code = timeout(SSO_TIMEOUT, wait_for_code(&listener, &state))
+        .await
+        .map_err(|_| t("err-sso-timeout"))??;
+
+    Ok(OidcLogin {
+        code,
+        code_verifier,
+        redirect_uri,
+        nonce,
+    })
+}
+
+/// Accept redirects until one carries the sign-in result
+async fn wait_for_code(listener: &TcpListener, state: &str) -> Result<String, String> {
+    loop {
+        let (mut stream, _) = listener
+            .accept()
+            .await
+            .map_err(|e| t_args("err-sso-failed", &[("error", &e.to_string())]))?;
+
+        // Browsers also ask for things like /favicon.ico; ignore those
+        let Some(callback) = read_callback(&mut stream).await else {
+            reply(&mut stream, "404 Not Found", "").await;
+            continue;
+        };
+
+        let result = match callback {
+            Callback::Code { code, state: got } if got == state => Ok(code),
+            Callback::Code { .. } => Err(t("err-sso-state-mismatch")),
+            Callback::Error(error) => Err(t_args("err-sso-failed", &[("error", &error)])),
+        };
+        let page = if result.is_ok() {
+            t("sso-browser-done")
+        } else {
+            t("sso-browser-failed")
+        };
+        reply(&mut stream, "200 OK", &page).await;
+        return result;
+    }
+}
+
+/// Read a redirect request and parse its query (None for any other request)
+async fn read_callback(stream: &mut TcpStream) -> Option<Callback> {
+    let mut request = Vec::new();
+    let mut buf = [0u8; 1024];
+    while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+        let n = stream.read(&mut buf).await.ok()?;
+        if n == 0 || request.len() + n > MAX_CALLBACK_REQUEST_SIZE {
+            return None;
+        }
+        request.extend_from_slice(&buf[..n]);
+    }
+    let request = std::str::from_utf8(&request).ok()?;
+    parse_callback(request.lines().next()?)
+}
+
+/// Parse the request line of a redirect to the callback path
+fn parse_callback(request_line: &str) -> Option<Callback> {
+    let mut parts = request_line.split(' ');
+    if parts.next()? != "GET" {
+        return None;
+    }
+    let (path, query) = parts.next()?.split_once('?')?;
+    if path != SSO_CALLBACK_PATH {
+        return None;
+    }
+
+    let mut code = None;
+    let mut state = None;
+    let mut error = None;
+    let mut description = None;
+    for pair in query.split('&') {
+        let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+        let value = percent_decode_str(&value.replace('+', " "))
+            .decode_utf8_lossy()
+            .into_owned();
+        match name {
+            "code" => code = Some(value),
+            "state" => state = Some(value),
+            "error" => error = Some(value),
+            "error_description" if !value.is_empty() => description = Some(value),
+            _ => {}
+        }
+    }
+
+    match (code, state, error) {
+        (_, _, Some(error)) => Some(Callback::Error(description.unwrap_or(error))),
+        (Some(code), Some(state), None) => Some(Callback::Code { code, state }),
+        _ => None,
+    }
+}
+
+/// Send a small HTML page back to the browser
+async fn reply(stream: &mut TcpStream, status: &str, message: &str) {
+    let body = format!(
+        "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>Nexus</title></head>\
+         <body><p>{message}</p></body></html>"
+    );
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: text/html; charset=utf-8\r\n\
+         Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    let _ = stream.write_all(response.as_bytes()).await;
+    let _ = stream.shutdown().await;
+}
+
+/// Build the URL the browser is sent to
+fn authorization_url(
+    provider: &OidcProvider,
+    redirect_uri: &str,
+    code_challenge: &str,
+    state: &str,
+    nonce: &str,
+) -> String {
+    let params = [
+        ("response_type", "code"),
+        ("client_id", provider.client_id.as_str()),
+        ("redirect_uri", redirect_uri),
+        ("scope", SSO_SCOPE),
+        ("state", state),
+        ("nonce", nonce),
+        ("code_challenge", code_challenge),
+        ("code_challenge_method", "S256"),
+    ];
+    let query = params
+        .iter()
+        .map(|(name, value)| format!("{name}={}", utf8_percent_encode(value, NON_ALPHANUMERIC)))
+        .collect::<Vec<_>>()
+        .join("&");
+
+    let endpoint = &provider.authorization_endpoint;
+    let separator = if endpoint.contains('?') { '&' } else { '?' };
+    format!("{endpoint}{separator}{query}")
+}
+
+/// PKCE S256 code challenge for a verifier
+fn code_challenge(code_verifier: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()))
+}
+
+/// 32 random bytes as base64url (43 characters, usable as a PKCE verifier)
+fn random_string() -> Result<String, String> {
+    let mut bytes = [0u8; 32];
+    SystemRandom::new()
+        .fill(&mut bytes)
+        .map_err(|_| t_args("err-sso-failed", &[("error", "no random source")]))?;
+    Ok(URL_SAFE_NO_PAD.encode(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use nexus_common::validators::validate_oidc_login;
+
+    use super::*;
+
+    #[test]
+    fn test_code_challenge() {
+        // RFC 7636 appendix B
+        assert_eq!(
+            code_challenge("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk"),
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+    }
+
+    #[test]
+    fn test_random_string_is_valid_verifier() {
+        let verifier = random_string().unwrap();
+        assert_ne!(verifier, random_string().unwrap());
+        let redirect_uri = format!("{OIDC_REDIRECT_PREFIX}65535{SSO_CALLBACK_PATH}");
+        assert!(validate_oidc_login("code", &verifier, &redirect_uri, &verifier).is_ok());
+    }
+
+    #[test]
+    fn test_authorization_url() {
+        let provider = OidcProvider {
+            authorization_endpoint: "https://id.example.com/auth".to_string(),
+            client_id: "nexus".to_string(),
+        };
+        let url = authorization_url(
+            &provider,
+            "http://127.0.0.1:5000/callback",
+            "challenge",
+            "st",
+            "nn",
+        );
+        assert_eq!(
+            url,
+            "https://id.example.com/auth?response_type=code&client_id=nexus\
+             &redirect_uri=http%3A%2F%2F127%2E0%2E0%2E1%3A5000%2Fcallback\
+             &scope=openid%20profile%20email&state=st&nonce=nn\
+             &code_challenge=challenge&code_challenge_method=S256"
+        );
+
+        let provider = OidcProvider {
+            authorization_endpoint: "https://id.example.com/auth?tenant=a".to_string(),
+            client_id: "nexus".to_string(),
+        };
+        let url = authorization_url(&provider, "r", "c", "s", "n");
+        assert!(url.starts_with("https://id.example.com/auth?tenant=a&response_type=code"));
+    }
+
+    #[test]
+    fn test_parse_callback() {
+        assert_eq!(
+            parse_callback("GET /callback?code=a%2Fb&state=xyz HTTP/1.1"),
+            Some(Callback::Code {
+                code: "a/b".to_string(),
+                state: "xyz".to_string(),
+            })
+        );
+        assert_eq!(
+            parse_callback(
+                "GET /callback?error=access_denied&error_description=User+cancelled HTTP/1.1"
+            ),
+            Some(Callback::Error("User cancelled".to_string()))
+        );
+        assert_eq!(
+            parse_callback("GET /callback?error=access_denied&state=xyz HTTP/1.1"),
+            Some(Callback::Error("access_denied".to_string()))
+        );
+        assert_eq!(parse_callback("GET /favicon.ico HTTP/1.1"), None);
+        assert_eq!(parse_callback("GET /callback?state=xyz HTTP/1.1"), None);
+        assert_eq!(
+            parse_callback("POST /callback?code=a&state=b HTTP/1.1"),
+            None
+        );
+    }
+}
@@ -90,9 +90,16 @@ pub(super) async fn setup_communication_channels(
         channels: login_info.channels,
         max_connections_per_ip: login_info.max_connections_per_ip,
         max_transfers_per_ip: login_info.max_transfers_per_ip,
+        max_active_transfers: login_info.max_active_transfers,
+        transfer_small_files_first: login_info.transfer_small_files_first,
+        trusted_transfer_weight: login_info.trusted_transfer_weight,
         file_reindex_interval: login_info.file_reindex_interval,
         persistent_channels: login_info.persistent_channels,
         auto_join_channels: login_info.auto_join_channels,
+        nat: login_info.nat,
+        news_image_limits: login_info.news_image_limits,
+        max_message_length: login_info.max_message_length,
+        server_time: login_info.server_time,
         locale: login_info.locale,
         connection_info,
     })
@@ -150,7 +157,7 @@ async fn spawn_reader_task(
             Ok(Some(received)) => {
                 // Timestamp Pong messages for accurate ping latency measurement
                 // This captures the time in tokio-land, before Iced's event loop delay
-                let timestamp = if matches!(received.message, ServerMessage::Pong) {
+                let timestamp = if matches!(received.message, ServerMessage::Pong { .. }) {
                     Some(Instant::now())
                 } else {
                     None
@@ -9,7 +9,7 @@ use tokio_rustls::rustls::ClientConnection;
 use tokio_socks::tcp::Socks5Stream;
 
 use nexus_common::framing::{FrameReader, FrameWriter};
-use nexus_common::protocol::ChannelJoinInfo;
+use nexus_common::protocol::{ChannelJoinInfo, NatInfo, NewsImageLimits, OidcProvider, ServerTime};
 
 /// SOCKS5 proxy configuration for connections
 #[derive(Clone)]
@@ -60,6 +60,8 @@ pub struct ConnectionParams {
     pub server_address: String,
     /// Server port
     pub port: u16,
+    /// Addresses to try in order if the server can't be reached (`host` or `host:port`)
+    pub fallback_addresses: Vec<String>,
     /// Username for authentication
     pub username: String,
     /// Password for authentication
@@ -74,6 +76,10 @@ pub struct ConnectionParams {
     pub connection_id: usize,
     /// Optional SOCKS5 proxy configuration
     pub proxy: Option<ProxyConfig>,
+    /// Sign in through the server's identity provider instead of with a password
+    pub use_sso: bool,
+    /// Login token from the last session, tried before the password or sign-in
+    pub login_token: Option<String>,
 }
 
 /// Type alias for TLS stream over direct TCP connection
@@ -152,6 +158,16 @@ pub type Reader = FrameReader<BufReader<tokio::io::ReadHalf<TlsStream>>>;
 /// Type alias for TLS stream write half with framing
 pub type Writer = FrameWriter<tokio::io::WriteHalf<TlsStream>>;
 
+/// Handshake information returned from the server
+pub struct HandshakeInfo {
+    /// Server identity key, if it sent one with a valid signature
+    pub identity_key: Option<String>,
+    /// Proof-of-work challenge and difficulty, if the server wants one
+    pub pow_challenge: Option<(String, u8)>,
+    /// Identity provider, if the server offers single sign-on
+    pub oidc: Option<OidcProvider>,
+}
+
 /// Login information returned from the server
 pub struct LoginInfo {
     pub is_admin: bool,
@@ -166,11 +182,24 @@ pub struct LoginInfo {
     pub channels: Vec<ChannelJoinInfo>,
     pub max_connections_per_ip: Option<u32>,
     pub max_transfers_per_ip: Option<u32>,
+    pub max_active_transfers: Option<u32>,
+    pub transfer_small_files_first: Option<bool>,
+    pub trusted_transfer_weight: Option<u32>,
     pub file_reindex_interval: Option<u32>,
     /// Persistent channels (space-separated, admin only)
     pub persistent_channels: Option<String>,
     /// Auto-join channels (space-separated, admin only)
     pub auto_join_channels: Option<String>,
+    /// NAT port mapping status (admin only)
+    pub nat: Option<NatInfo>,
+    /// News image limits (None for servers that don't send them)
+    pub news_image_limits: Option<NewsImageLimits>,
+    /// Longest message the server accepts (None for servers that don't send it)
+    pub max_message_length: Option<u32>,
+    /// Server clock at login (None for servers that don't send it)
+    pub server_time: Option<ServerTime>,
     pub transfer_port: u16,
     pub locale: String,
+    /// Token for transfer connections and reconnects
+    pub login_token: Option<String>,
 }
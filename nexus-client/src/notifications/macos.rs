@@ -0,0 +1,256 @@
+//! macOS notifications through `UNUserNotificationCenter`
+//!
+//! Unlike `notify-rust` (which goes through the deprecated
+//! `NSUserNotification` API), the UserNotifications framework supports
+//! actions.
+//!
+//! `UNUserNotificationCenter` raises an exception when the process isn't an
+//! app bundle (e.g. `cargo run`), so [`install`] checks for a bundle
+//! identifier first and [`is_available`] stays false without one.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use block2::{Block, RcBlock};
+use objc2::rc::Retained;
+use objc2::runtime::{AnyObject, Bool};
+use objc2::{AllocAnyThread, class, define_class, msg_send};
+use objc2_foundation::{NSObject, NSObjectProtocol, NSString};
+
+use super::{NotificationTarget, Response, next_id, respond};
+use crate::i18n::t;
+
+#[link(name = "UserNotifications", kind = "framework")]
+unsafe extern "C" {}
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Category for notifications that belong to a conversation
+const CONVERSATION_CATEGORY: &str = "nexus-conversation";
+
+/// Action identifier of the reply text field
+const REPLY_ACTION: &str = "nexus-reply";
+
+/// Action identifier of the "Mark read" button
+const MARK_READ_ACTION: &str = "nexus-mark-read";
+
+/// Action identifier sent when the notification itself is clicked
+const DEFAULT_ACTION: &str = "com.apple.UNNotificationDefaultActionIdentifier";
+
+/// Prefix of notification request identifiers (followed by the notification id)
+const REQUEST_ID_PREFIX: &str = "nexus-";
+
+/// `UNAuthorizationOptionBadge | UNAuthorizationOptionSound | UNAuthorizationOptionAlert`
+const AUTHORIZATION_OPTIONS: usize = 0b111;
+
+/// `UNNotificationPresentationOptionList | UNNotificationPresentationOptionBanner`
+const PRESENTATION_OPTIONS: usize = (1 << 3) | (1 << 4);
+
+/// Set once the notification center delegate is installed
+static AVAILABLE: AtomicBool = AtomicBool::new(false);
+
+// =============================================================================
+// Delegate
+// =============================================================================
+
+define_class!(
+    #[unsafe(super(NSObject))]
+    #[name = "NexusNotificationDelegate"]
+    struct NotificationDelegate;
+
+    unsafe impl NSObjectProtocol for NotificationDelegate {}
+
+    /// `UNUserNotificationCenterDelegate` methods. The center may call these
+    /// on any thread, so they only touch thread-safe state.
+    impl NotificationDelegate {
+        /// Show banners even while Nexus is the active app (the event
+        /// settings already decide when a notification is worth showing)
+        #[unsafe(method(userNotificationCenter:willPresentNotification:withCompletionHandler:))]
+        fn will_present(
+            &self,
+            _center: &AnyObject,
+            _notification: &AnyObject,
+            handler: &Block<dyn Fn(usize)>,
+        ) {
+            handler.call((PRESENTATION_OPTIONS,));
+        }
+
+        #[unsafe(method(userNotificationCenter:didReceiveNotificationResponse:withCompletionHandler:))]
+        fn did_receive_response(
+            &self,
+            _center: &AnyObject,
+            response: &AnyObject,
+            handler: &Block<dyn Fn()>,
+        ) {
+            handle_response(response);
+            handler.call(());
+        }
+    }
+);
+
+impl NotificationDelegate {
+    fn new() -> Retained<Self> {
+        // SAFETY: `Self::alloc()` returns a valid allocated instance of our
+        // NSObject subclass, and `init` always succeeds for it.
+        unsafe { msg_send![Self::alloc(), init] }
+    }
+}
+
+/// Forward a `UNNotificationResponse` to the response stream
+fn handle_response(response: &AnyObject) {
+    // SAFETY: `response` is a `UNNotificationResponse`; `notification`,
+    // `request` and `identifier` are non-null properties of it.
+    let (action, identifier) = unsafe {
+        let action: Retained<NSString> = msg_send![response, actionIdentifier];
+        let notification: *mut AnyObject = msg_send![response, notification];
+        let request: *mut AnyObject = msg_send![notification, request];
+        let identifier: Retained<NSString> = msg_send![request, identifier];
+        (action.to_string(), identifier.to_string())
+    };
+
+    let Some(id) = identifier
+        .strip_prefix(REQUEST_ID_PREFIX)
+        .and_then(|id| id.parse::<u64>().ok())
+    else {
+        return;
+    };
+
+    let response = match action.as_str() {
+        DEFAULT_ACTION => Response::Open,
+        REPLY_ACTION => {
+            // SAFETY: responses to a text input action are
+            // `UNTextInputNotificationResponse`, which has `userText`.
+            let text: Retained<NSString> = unsafe { msg_send![response, userText] };
+            Response::Reply(text.to_string())
+        }
+        MARK_READ_ACTION => Response::MarkRead,
+        _ => return,
+    };
+    respond(id, response);
+}
+
+// =============================================================================
+// Public API
+// =============================================================================
+
+/// Install the notification center delegate and request permission
+///
+/// Must be called from `NexusApp::new()`, after AppKit is initialized. Does
+/// nothing when not running from an app bundle.
+pub fn install() {
+    // SAFETY: `mainBundle` always returns a bundle; `bundleIdentifier` is
+    // nil for bare executables.
+    let bundle_id: Option<Retained<NSString>> = unsafe {
+        let bundle: *mut AnyObject = msg_send![class!(NSBundle), mainBundle];
+        msg_send![bundle, bundleIdentifier]
+    };
+    if bundle_id.is_none() {
+        return;
+    }
+
+    let delegate = NotificationDelegate::new();
+
+    // SAFETY: we are running from a bundle, so `currentNotificationCenter`
+    // returns the shared center. The actions, category and collections are
+    // created with their documented constructors.
+    unsafe {
+        let center: *mut AnyObject =
+            msg_send![class!(UNUserNotificationCenter), currentNotificationCenter];
+        if center.is_null() {
+            return;
+        }
+        let _: () = msg_send![center, setDelegate: &*delegate];
+
+        let completion = RcBlock::new(|_granted: Bool, _error: *mut AnyObject| {});
+        let _: () = msg_send![
+            center,
+            requestAuthorizationWithOptions: AUTHORIZATION_OPTIONS,
+            completionHandler: &*completion
+        ];
+
+        let reply: *mut AnyObject = msg_send![
+            class!(UNTextInputNotificationAction),
+            actionWithIdentifier: &*NSString::from_str(REPLY_ACTION),
+            title: &*NSString::from_str(&t("notification-reply")),
+            options: 0usize,
+            textInputButtonTitle: &*NSString::from_str(&t("notification-reply-send")),
+            textInputPlaceholder: &*NSString::from_str(&t("notification-reply-placeholder"))
+        ];
+        let mark_read: *mut AnyObject = msg_send![
+            class!(UNNotificationAction),
+            actionWithIdentifier: &*NSString::from_str(MARK_READ_ACTION),
+            title: &*NSString::from_str(&t("notification-mark-read")),
+            options: 0usize
+        ];
+        let actions: *mut AnyObject = msg_send![class!(NSMutableArray), array];
+        let _: () = msg_send![actions, addObject: reply];
+        let _: () = msg_send![actions, addObject: mark_read];
+        let intents: *mut AnyObject = msg_send![class!(NSArray), array];
+        let category: *mut AnyObject = msg_send![
+            class!(UNNotificationCategory),
+            categoryWithIdentifier: &*NSString::from_str(CONVERSATION_CATEGORY),
+            actions: actions,
+            intentIdentifiers: intents,
+            options: 0usize
+        ];
+        let categories: *mut AnyObject = msg_send![class!(NSSet), setWithObject: category];
+        let _: () = msg_send![center, setNotificationCategories: categories];
+    }
+
+    // The center only holds a weak reference to its delegate, so leak it to
+    // keep it alive for the rest of the process (see `macos_url::install`).
+    std::mem::forget(delegate);
+    AVAILABLE.store(true, Ordering::Relaxed);
+}
+
+/// Whether native notifications can be shown
+pub fn is_available() -> bool {
+    AVAILABLE.load(Ordering::Relaxed)
+}
+
+/// Show a notification
+///
+/// With a `target`, the notification offers reply and "Mark read" actions and
+/// clicking it returns to that conversation.
+pub fn show(summary: &str, body: Option<&str>, target: Option<NotificationTarget>) {
+    if !is_available() {
+        return;
+    }
+
+    let has_target = target.is_some();
+    let id = next_id(target);
+
+    // SAFETY: `UNMutableNotificationContent` and `UNNotificationRequest` are
+    // created with their documented constructors; a nil trigger delivers the
+    // notification immediately and the completion handler is nullable.
+    unsafe {
+        let content: Retained<AnyObject> = msg_send![class!(UNMutableNotificationContent), new];
+        let _: () = msg_send![&*content, setTitle: &*NSString::from_str(summary)];
+        if let Some(body) = body {
+            let _: () = msg_send![&*content, setBody: &*NSString::from_str(body)];
+        }
+        if has_target {
+            let _: () = msg_send![
+                &*content,
+                setCategoryIdentifier: &*NSString::from_str(CONVERSATION_CATEGORY)
+            ];
+        }
+
+        let identifier = NSString::from_str(&format!("{REQUEST_ID_PREFIX}{id}"));
+        let request: *mut AnyObject = msg_send![
+            class!(UNNotificationRequest),
+            requestWithIdentifier: &*identifier,
+            content: &*content,
+            trigger: std::ptr::null::<AnyObject>()
+        ];
+
+        let center: *mut AnyObject =
+            msg_send![class!(UNUserNotificationCenter), currentNotificationCenter];
+        let _: () = msg_send![
+            center,
+            addNotificationRequest: request,
+            withCompletionHandler: None::<&Block<dyn Fn(*mut AnyObject)>>
+        ];
+    }
+}
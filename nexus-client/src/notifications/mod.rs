@@ -0,0 +1,155 @@
+//! Native desktop notifications with actions
+//!
+//! `notify-rust` shows plain notifications everywhere. On platforms with a
+//! native API that supports actions, notifications are shown through it
+//! instead:
+//!
+//! - **macOS**: `UNUserNotificationCenter` (Notification Center)
+//! - **Windows**: WinRT toasts (Action Center)
+//!
+//! User messages and channel notifications get a reply field, whose text is
+//! sent to the same conversation, and a "Mark read" button. Clicking any
+//! notification brings Nexus to the front.
+//!
+//! Responses arrive on an OS callback thread and are forwarded through a
+//! crossbeam channel consumed by [`response_stream`], the same way
+//! `macos_url` delivers URLs. When the native API isn't usable (e.g. macOS
+//! without an app bundle), [`is_available`] is false and callers fall back to
+//! `notify-rust`.
+
+#![cfg(any(target_os = "macos", target_os = "windows"))]
+
+#[cfg(target_os = "macos")]
+mod macos;
+#[cfg(target_os = "windows")]
+mod windows;
+
+#[cfg(target_os = "macos")]
+pub use self::macos::{install, is_available, show};
+#[cfg(target_os = "windows")]
+pub use self::windows::{install, is_available, show};
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::Duration;
+
+use crossbeam_channel::{Receiver, Sender};
+use once_cell::sync::Lazy;
+
+use crate::types::{ChatTab, Message};
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Maximum number of targets remembered for delivered notifications
+const MAX_TARGETS: usize = 100;
+
+// =============================================================================
+// State
+// =============================================================================
+
+/// Where a notification came from, so a reply or click can return there
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotificationTarget {
+    /// Connection the notification belongs to
+    pub connection_id: usize,
+    /// Chat tab the notification belongs to
+    pub tab: ChatTab,
+}
+
+/// What the user did with a notification
+enum Response {
+    /// Clicked the notification itself
+    Open,
+    /// Sent text from the reply field
+    Reply(String),
+    /// Clicked "Mark read"
+    MarkRead,
+}
+
+/// Counter for notification identifiers
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Targets of delivered notifications, keyed by notification identifier
+static TARGETS: Mutex<BTreeMap<u64, NotificationTarget>> = Mutex::new(BTreeMap::new());
+
+/// Channel for forwarding responses from OS callbacks to the Iced event loop
+static RESPONSE_CHANNEL: Lazy<(Sender<Message>, Receiver<Message>)> =
+    Lazy::new(crossbeam_channel::unbounded);
+
+/// Flag set during app shutdown so the `spawn_blocking` recv loop can exit.
+static SHUTTING_DOWN: AtomicBool = AtomicBool::new(false);
+
+/// Allocate an identifier for a new notification and remember its target
+fn next_id(target: Option<NotificationTarget>) -> u64 {
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    if let Some(target) = target
+        && let Ok(mut targets) = TARGETS.lock()
+    {
+        targets.insert(id, target);
+        while targets.len() > MAX_TARGETS {
+            targets.pop_first();
+        }
+    }
+    id
+}
+
+/// Deliver a response to the notification with the given identifier
+///
+/// Each notification responds at most once, so its target is forgotten here.
+fn respond(id: u64, response: Response) {
+    let target = TARGETS
+        .lock()
+        .ok()
+        .and_then(|mut targets| targets.remove(&id));
+
+    let message = match (response, target) {
+        (Response::Open, target) => Message::NotificationActivated(target),
+        (Response::Reply(text), Some(target)) if !text.trim().is_empty() => {
+            Message::NotificationReply(target, text.trim().to_string())
+        }
+        (Response::MarkRead, Some(target)) => Message::NotificationMarkRead(target),
+        _ => return,
+    };
+    let _ = RESPONSE_CHANNEL.0.send(message);
+}
+
+// =============================================================================
+// Response Stream
+// =============================================================================
+
+/// Signal the response stream to stop so the `spawn_blocking` task can exit
+/// and tokio's runtime drop won't hang.
+///
+/// Must be called before `iced::window::close()`.
+pub fn shutdown() {
+    SHUTTING_DOWN.store(true, Ordering::Relaxed);
+}
+
+/// Async stream that yields clicks, replies and "Mark read" on notifications
+///
+/// Uses `recv_timeout` inside `spawn_blocking` so the blocking thread wakes
+/// periodically and can exit on quit (see `macos_url::url_stream`).
+pub fn response_stream() -> impl iced::futures::Stream<Item = Message> {
+    iced::futures::stream::unfold((), |()| async {
+        let message = tokio::task::spawn_blocking(|| {
+            loop {
+                if SHUTTING_DOWN.load(Ordering::Relaxed) {
+                    return None;
+                }
+                match RESPONSE_CHANNEL.1.recv_timeout(Duration::from_millis(500)) {
+                    Ok(message) => return Some(message),
+                    Err(crossbeam_channel::RecvTimeoutError::Timeout) => continue,
+                    Err(crossbeam_channel::RecvTimeoutError::Disconnected) => return None,
+                }
+            }
+        })
+        .await
+        .ok()
+        .flatten()?;
+
+        Some((message, ()))
+    })
+}
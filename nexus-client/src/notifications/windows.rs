@@ -0,0 +1,269 @@
+//! Windows notifications through WinRT toasts
+//!
+//! Toasts are attributed to our own AppUserModelID instead of the default
+//! PowerShell one that `notify-rust` uses. The ID is registered under
+//! `HKCU\Software\Classes\AppUserModelId` with a display name and icon,
+//! which is what lets an unpackaged app's toasts show the right name and
+//! stay in Action Center after the banner times out.
+//!
+//! Actions are delivered through the toast's `Activated` event while Nexus is
+//! running. Toasts left in Action Center after Nexus quits can still be read
+//! but their buttons do nothing.
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use windows::Data::Xml::Dom::XmlDocument;
+use windows::Foundation::{IReference, TypedEventHandler};
+use windows::UI::Notifications::{
+    ToastActivatedEventArgs, ToastNotification, ToastNotificationManager,
+};
+use windows::Win32::System::Registry::{HKEY_CURRENT_USER, REG_SZ, RegSetKeyValueW};
+use windows::Win32::UI::Shell::SetCurrentProcessExplicitAppUserModelID;
+use windows::core::{HSTRING, IInspectable, Interface, Ref};
+
+use super::{MAX_TARGETS, NotificationTarget, Response, next_id, respond};
+use crate::constants::{APP_DIR_NAME, APP_NAME, APP_USER_MODEL_ID};
+use crate::i18n::t;
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Registry key (under `HKEY_CURRENT_USER`) holding our toast identity
+const APP_ID_REGISTRY_KEY: &str = r"Software\Classes\AppUserModelId";
+
+/// File name of the icon shown on toasts (written to the config directory)
+const TOAST_ICON_FILE_NAME: &str = "toast-icon.png";
+
+/// Id of the reply text box in the toast XML
+const REPLY_INPUT: &str = "reply";
+
+/// Activation argument for clicking the toast itself
+const OPEN_ACTION: &str = "open";
+
+/// Activation argument for the reply button
+const REPLY_ACTION: &str = "reply";
+
+/// Activation argument for the "Mark read" button
+const MARK_READ_ACTION: &str = "mark-read";
+
+/// Set once our AppUserModelID is registered
+static AVAILABLE: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    /// Recently shown toasts, kept alive so their `Activated` handlers fire
+    static TOASTS: RefCell<VecDeque<ToastNotification>> = const { RefCell::new(VecDeque::new()) };
+}
+
+// =============================================================================
+// Public API
+// =============================================================================
+
+/// Register our AppUserModelID for toast attribution
+///
+/// Called from `NexusApp::new()`. The same ID is set on the process so the
+/// taskbar button and toasts are grouped under Nexus.
+pub fn install() {
+    let app_id = HSTRING::from(APP_USER_MODEL_ID);
+
+    // SAFETY: `app_id` is a valid null-terminated wide string for the call.
+    if unsafe { SetCurrentProcessExplicitAppUserModelID(&app_id) }.is_err() {
+        return;
+    }
+
+    let key = HSTRING::from(format!(r"{APP_ID_REGISTRY_KEY}\{APP_USER_MODEL_ID}"));
+    if !set_registry_string(&key, "DisplayName", APP_NAME) {
+        return;
+    }
+    if let Some(icon_path) = write_toast_icon() {
+        set_registry_string(&key, "IconUri", &icon_path);
+    }
+
+    AVAILABLE.store(true, Ordering::Relaxed);
+}
+
+/// Whether native notifications can be shown
+pub fn is_available() -> bool {
+    AVAILABLE.load(Ordering::Relaxed)
+}
+
+/// Show a toast
+///
+/// With a `target`, the toast offers a reply box and a "Mark read" button,
+/// and clicking it returns to that conversation.
+pub fn show(summary: &str, body: Option<&str>, target: Option<NotificationTarget>) {
+    if !is_available() {
+        return;
+    }
+
+    let has_target = target.is_some();
+    let id = next_id(target);
+    let _ = show_toast(&toast_xml(id, summary, body, has_target));
+}
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+/// Create and show a toast, keeping it alive for its `Activated` event
+fn show_toast(xml: &str) -> windows::core::Result<()> {
+    let document = XmlDocument::new()?;
+    document.LoadXml(&HSTRING::from(xml))?;
+
+    let toast = ToastNotification::CreateToastNotification(&document)?;
+    toast.Activated(&TypedEventHandler::new(
+        |_toast: Ref<ToastNotification>, args: Ref<IInspectable>| {
+            let args: ToastActivatedEventArgs = args.ok()?.cast()?;
+            handle_activation(&args);
+            Ok(())
+        },
+    ))?;
+
+    ToastNotificationManager::CreateToastNotifierWithId(&HSTRING::from(APP_USER_MODEL_ID))?
+        .Show(&toast)?;
+
+    TOASTS.with_borrow_mut(|toasts| {
+        toasts.push_back(toast);
+        while toasts.len() > MAX_TARGETS {
+            toasts.pop_front();
+        }
+    });
+    Ok(())
+}
+
+/// Forward a toast activation to the response stream
+fn handle_activation(args: &ToastActivatedEventArgs) {
+    let Ok(arguments) = args.Arguments() else {
+        return;
+    };
+    let Some((action, id)) = parse_arguments(&arguments.to_string()) else {
+        return;
+    };
+
+    let response = match action.as_str() {
+        OPEN_ACTION => Response::Open,
+        REPLY_ACTION => Response::Reply(reply_text(args).unwrap_or_default()),
+        MARK_READ_ACTION => Response::MarkRead,
+        _ => return,
+    };
+    respond(id, response);
+}
+
+/// Read the text typed into the reply box
+fn reply_text(args: &ToastActivatedEventArgs) -> windows::core::Result<String> {
+    let value = args.UserInput()?.Lookup(&HSTRING::from(REPLY_INPUT))?;
+    Ok(value.cast::<IReference<HSTRING>>()?.Value()?.to_string())
+}
+
+/// Build the toast XML
+///
+/// Sounds are silenced because Nexus plays its own event sounds.
+fn toast_xml(id: u64, summary: &str, body: Option<&str>, with_actions: bool) -> String {
+    let mut xml = format!(
+        r#"<toast launch="{OPEN_ACTION}:{id}"><visual><binding template="ToastGeneric"><text>{}</text>"#,
+        xml_escape(summary)
+    );
+    if let Some(body) = body {
+        xml.push_str(&format!("<text>{}</text>", xml_escape(body)));
+    }
+    xml.push_str("</binding></visual>");
+
+    if with_actions {
+        xml.push_str(&format!(
+            concat!(
+                r#"<actions>"#,
+                r#"<input id="{input}" type="text" placeHolderContent="{placeholder}"/>"#,
+                r#"<action content="{send}" arguments="{reply}:{id}" hint-inputId="{input}" activationType="background"/>"#,
+                r#"<action content="{mark_read}" arguments="{mark}:{id}" activationType="background"/>"#,
+                r#"</actions>"#,
+            ),
+            input = REPLY_INPUT,
+            placeholder = xml_escape(&t("notification-reply-placeholder")),
+            send = xml_escape(&t("notification-reply-send")),
+            reply = REPLY_ACTION,
+            mark_read = xml_escape(&t("notification-mark-read")),
+            mark = MARK_READ_ACTION,
+            id = id,
+        ));
+    }
+
+    xml.push_str(r#"<audio silent="true"/></toast>"#);
+    xml
+}
+
+/// Split activation arguments ("action:id") into the action and toast id
+fn parse_arguments(arguments: &str) -> Option<(String, u64)> {
+    let (action, id) = arguments.rsplit_once(':')?;
+    Some((action.to_string(), id.parse().ok()?))
+}
+
+/// Escape text for use in toast XML content and attributes
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Set a string value under `HKEY_CURRENT_USER`, creating the key if needed
+fn set_registry_string(key: &HSTRING, name: &str, value: &str) -> bool {
+    let data: Vec<u16> = value.encode_utf16().chain(std::iter::once(0)).collect();
+
+    // SAFETY: `data` is a null-terminated UTF-16 buffer of the given size in
+    // bytes, and it outlives the call.
+    unsafe {
+        RegSetKeyValueW(
+            HKEY_CURRENT_USER,
+            key,
+            &HSTRING::from(name),
+            REG_SZ.0,
+            Some(data.as_ptr().cast()),
+            (data.len() * size_of::<u16>()) as u32,
+        )
+    }
+    .is_ok()
+}
+
+/// Write the toast icon to the config directory, returning its path
+fn write_toast_icon() -> Option<String> {
+    let dir = dirs::config_dir()?.join(APP_DIR_NAME);
+    std::fs::create_dir_all(&dir).ok()?;
+    let path = dir.join(TOAST_ICON_FILE_NAME);
+    std::fs::write(&path, crate::tray::ICON_NORMAL).ok()?;
+    Some(path.to_string_lossy().into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_arguments() {
+        assert_eq!(parse_arguments("reply:42"), Some(("reply".to_string(), 42)));
+        assert_eq!(
+            parse_arguments("mark-read:7"),
+            Some(("mark-read".to_string(), 7))
+        );
+        assert_eq!(parse_arguments("open"), None);
+        assert_eq!(parse_arguments("open:abc"), None);
+    }
+
+    #[test]
+    fn test_xml_escape() {
+        assert_eq!(
+            xml_escape(r#"<b>"Tom" & 'Jerry'</b>"#),
+            "&lt;b&gt;&quot;Tom&quot; &amp; &apos;Jerry&apos;&lt;/b&gt;"
+        );
+        assert_eq!(xml_escape("plain"), "plain");
+    }
+}
@@ -0,0 +1,200 @@
+//! Quick switcher (Ctrl+K) matching and ranking
+//!
+//! The quick switcher lists every chat tab, bookmark and panel across all open
+//! connections. Typing filters the list with a fuzzy match, and the places
+//! jumped to most recently come first among equally good matches.
+
+use iced::Event;
+use iced::keyboard::{self, Key};
+use uuid::Uuid;
+
+use crate::types::{ActivePanel, ChatTab};
+
+/// Most places remembered for recent-first ordering
+pub const RECENT_LIMIT: usize = 20;
+
+/// Somewhere the quick switcher can jump to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QuickSwitchTarget {
+    /// A chat tab on an open connection
+    Tab(usize, ChatTab),
+    /// A bookmark (switches to its connection, or connects)
+    Bookmark(Uuid),
+    /// A panel on an open connection, or an app-wide panel (None)
+    Panel(Option<usize>, ActivePanel),
+}
+
+/// One entry in the quick switcher list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuickSwitchItem {
+    /// Where choosing the entry goes
+    pub target: QuickSwitchTarget,
+    /// Channel, nickname, bookmark or panel name
+    pub label: String,
+    /// Server the entry belongs to, or what kind of entry it is
+    pub detail: String,
+}
+
+/// Whether an event is the quick switcher shortcut (Cmd/Ctrl+K)
+pub fn is_toggle_key(event: &Event) -> bool {
+    matches!(
+        event,
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: Key::Character(c),
+            modifiers,
+            ..
+        }) if modifiers.command() && c.eq_ignore_ascii_case("k")
+    )
+}
+
+/// Remember a place as the most recently used
+pub fn note_recent(recent: &mut Vec<QuickSwitchTarget>, target: QuickSwitchTarget) {
+    recent.retain(|other| *other != target);
+    recent.insert(0, target);
+    recent.truncate(RECENT_LIMIT);
+}
+
+/// Score how well `query` matches `text`, or None if it doesn't match
+///
+/// Every non-space query character must appear in `text` in order, ignoring
+/// case. Characters that follow the previous match or start a word score
+/// higher, so "gen" prefers "#general" over "#big-engine".
+pub fn fuzzy_score(query: &str, text: &str) -> Option<u32> {
+    let query: Vec<char> = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    let text: Vec<char> = text.chars().flat_map(char::to_lowercase).collect();
+
+    let mut score = 0;
+    let mut next = 0;
+    let mut previous_match = None;
+    for (index, &c) in text.iter().enumerate() {
+        if next == query.len() {
+            break;
+        }
+        if c != query[next] {
+            continue;
+        }
+
+        score += 1;
+        if index > 0 && previous_match == Some(index - 1) {
+            score += 5;
+        }
+        if index == 0 || !text[index - 1].is_alphanumeric() {
+            score += 8;
+        }
+        previous_match = Some(index);
+        next += 1;
+    }
+
+    (next == query.len()).then_some(score)
+}
+
+/// Filter and order entries for a query
+///
+/// Better matches come first, then recently used places, then the order the
+/// entries were listed in. An empty query keeps every entry.
+pub fn rank(
+    items: Vec<QuickSwitchItem>,
+    query: &str,
+    recent: &[QuickSwitchTarget],
+) -> Vec<QuickSwitchItem> {
+    let mut ranked: Vec<(u32, usize, usize, QuickSwitchItem)> = items
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, item)| {
+            let score = fuzzy_score(query, &format!("{} {}", item.label, item.detail))?;
+            let recency = recent
+                .iter()
+                .position(|target| *target == item.target)
+                .unwrap_or(usize::MAX);
+            Some((score, recency, index, item))
+        })
+        .collect();
+
+    ranked.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)).then(a.2.cmp(&b.2)));
+    ranked.into_iter().map(|(_, _, _, item)| item).collect()
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn channel(connection_id: usize, name: &str) -> QuickSwitchItem {
+        QuickSwitchItem {
+            target: QuickSwitchTarget::Tab(connection_id, ChatTab::Channel(name.to_string())),
+            label: name.to_string(),
+            detail: format!("Server {connection_id}"),
+        }
+    }
+
+    fn labels(items: &[QuickSwitchItem]) -> Vec<&str> {
+        items.iter().map(|item| item.label.as_str()).collect()
+    }
+
+    #[test]
+    fn test_fuzzy_score() {
+        assert_eq!(fuzzy_score("", "#general"), Some(0));
+        assert!(fuzzy_score("gnrl", "#general").is_some());
+        assert!(fuzzy_score("GEN", "#general").is_some());
+        assert_eq!(fuzzy_score("lg", "#general"), None);
+        assert_eq!(fuzzy_score("generals", "#general"), None);
+
+        // Consecutive characters at the start of a word score highest
+        let start = fuzzy_score("gen", "#general").unwrap();
+        let middle = fuzzy_score("gen", "#big-engine").unwrap();
+        let scattered = fuzzy_score("gen", "#bigger-lemon").unwrap();
+        assert!(start > middle);
+        assert!(middle > scattered);
+    }
+
+    #[test]
+    fn test_rank_orders_by_match_then_recency() {
+        let items = vec![
+            channel(1, "#general"),
+            channel(1, "#games"),
+            channel(2, "#general"),
+            channel(1, "#help"),
+        ];
+
+        // No query keeps everything, recently used first
+        let recent = vec![items[3].target.clone(), items[2].target.clone()];
+        let ranked = rank(items.clone(), "", &recent);
+        assert_eq!(labels(&ranked), ["#help", "#general", "#general", "#games"]);
+        assert_eq!(ranked[1].detail, "Server 2");
+
+        // Equal matches keep the recent one first; non-matches are dropped
+        let ranked = rank(items.clone(), "general", &recent);
+        assert_eq!(labels(&ranked), ["#general", "#general"]);
+        assert_eq!(ranked[0].detail, "Server 2");
+
+        // The server name can narrow the match
+        let ranked = rank(items, "gen 1", &[]);
+        assert_eq!(labels(&ranked), ["#general"]);
+        assert_eq!(ranked[0].detail, "Server 1");
+    }
+
+    #[test]
+    fn test_note_recent() {
+        let mut recent = Vec::new();
+        for id in 0..RECENT_LIMIT + 5 {
+            note_recent(
+                &mut recent,
+                QuickSwitchTarget::Panel(Some(id), ActivePanel::Files),
+            );
+        }
+        assert_eq!(recent.len(), RECENT_LIMIT);
+
+        // Using a place again moves it to the front without duplicating it
+        let target = QuickSwitchTarget::Panel(Some(10), ActivePanel::Files);
+        note_recent(&mut recent, target.clone());
+        assert_eq!(recent.len(), RECENT_LIMIT);
+        assert_eq!(recent[0], target);
+    }
+}
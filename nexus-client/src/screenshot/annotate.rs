@@ -0,0 +1,175 @@
+//! Screenshot annotation (crop and arrows)
+//!
+//! Edits are applied directly to the pixel buffer in image coordinates; the
+//! editor view maps pointer positions from its scaled preview before calling
+//! in here.
+
+use std::f32::consts::PI;
+
+use image::{Rgba, RgbaImage};
+
+/// Arrow color (a saturated red that stands out on most content)
+const ARROW_COLOR: Rgba<u8> = Rgba([230, 40, 40, 255]);
+
+/// Smallest crop (pixels per side) that is applied; smaller drags are treated as clicks
+const MIN_CROP_SIZE: u32 = 8;
+
+/// Shortest arrow (pixels) that is drawn
+const MIN_ARROW_LENGTH: f32 = 8.0;
+
+/// Minimum arrow line thickness (pixels)
+const MIN_ARROW_THICKNESS: f32 = 3.0;
+
+/// Image size (shorter side) per pixel of arrow thickness
+const ARROW_THICKNESS_DIVISOR: f32 = 200.0;
+
+/// Angle between the arrow shaft and each side of its head
+const ARROW_HEAD_ANGLE: f32 = PI / 6.0;
+
+/// Arrow head length as a multiple of the line thickness
+const ARROW_HEAD_SCALE: f32 = 5.0;
+
+/// Annotation tool selected in the editor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Tool {
+    /// Drag a rectangle to keep only that part of the image
+    #[default]
+    Crop,
+    /// Drag from the tail to the tip of an arrow
+    Arrow,
+}
+
+/// Rectangle spanned by two points, clamped to the image
+///
+/// Returns `(x, y, width, height)` in pixels; the points may be given in
+/// any order.
+pub fn selection(
+    (width, height): (u32, u32),
+    from: (f32, f32),
+    to: (f32, f32),
+) -> (u32, u32, u32, u32) {
+    let clamp = |value: f32, max: u32| value.round().clamp(0.0, max as f32) as u32;
+    let left = clamp(from.0.min(to.0), width);
+    let right = clamp(from.0.max(to.0), width);
+    let top = clamp(from.1.min(to.1), height);
+    let bottom = clamp(from.1.max(to.1), height);
+    (left, top, right - left, bottom - top)
+}
+
+/// Crop to the rectangle spanned by two points
+///
+/// Returns `None` when the rectangle is too small to be intentional.
+pub fn crop(image: &RgbaImage, from: (f32, f32), to: (f32, f32)) -> Option<RgbaImage> {
+    let (x, y, width, height) = selection(image.dimensions(), from, to);
+    if width < MIN_CROP_SIZE || height < MIN_CROP_SIZE {
+        return None;
+    }
+    Some(image::imageops::crop_imm(image, x, y, width, height).to_image())
+}
+
+/// Draw an arrow pointing from `from` to `to`
+///
+/// Returns false (leaving the image untouched) when the arrow is too short.
+pub fn draw_arrow(image: &mut RgbaImage, from: (f32, f32), to: (f32, f32)) -> bool {
+    let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+    let length = dx.hypot(dy);
+    if length < MIN_ARROW_LENGTH {
+        return false;
+    }
+
+    let (width, height) = image.dimensions();
+    let thickness = (width.min(height) as f32 / ARROW_THICKNESS_DIVISOR).max(MIN_ARROW_THICKNESS);
+    draw_line(image, from, to, thickness);
+
+    // Head: two barbs swept back from the tip on either side of the shaft
+    let head_length = (thickness * ARROW_HEAD_SCALE).min(length / 2.0);
+    let back = dy.atan2(dx) + PI;
+    for barb in [back - ARROW_HEAD_ANGLE, back + ARROW_HEAD_ANGLE] {
+        let end = (
+            to.0 + head_length * barb.cos(),
+            to.1 + head_length * barb.sin(),
+        );
+        draw_line(image, to, end, thickness);
+    }
+
+    true
+}
+
+/// Draw a thick line by stamping discs along it
+fn draw_line(image: &mut RgbaImage, from: (f32, f32), to: (f32, f32), thickness: f32) {
+    let radius = thickness / 2.0;
+    let steps = (to.0 - from.0).hypot(to.1 - from.1).ceil().max(1.0) as u32;
+    for step in 0..=steps {
+        let t = step as f32 / steps as f32;
+        let center = (from.0 + (to.0 - from.0) * t, from.1 + (to.1 - from.1) * t);
+        fill_disc(image, center, radius);
+    }
+}
+
+/// Fill the pixels within `radius` of `center`
+fn fill_disc(image: &mut RgbaImage, center: (f32, f32), radius: f32) {
+    let (width, height) = image.dimensions();
+    let min_x = ((center.0 - radius).floor() as i64).max(0);
+    let max_x = ((center.0 + radius).ceil() as i64).min(i64::from(width) - 1);
+    let min_y = ((center.1 - radius).floor() as i64).max(0);
+    let max_y = ((center.1 + radius).ceil() as i64).min(i64::from(height) - 1);
+
+    for y in min_y..=max_y {
+        for x in min_x..=max_x {
+            if (x as f32 - center.0).hypot(y as f32 - center.1) <= radius {
+                image.put_pixel(x as u32, y as u32, ARROW_COLOR);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blank(width: u32, height: u32) -> RgbaImage {
+        RgbaImage::from_pixel(width, height, Rgba([255, 255, 255, 255]))
+    }
+
+    #[test]
+    fn test_selection_normalizes_and_clamps() {
+        assert_eq!(
+            selection((100, 100), (80.0, 70.0), (10.0, 20.0)),
+            (10, 20, 70, 50)
+        );
+        assert_eq!(
+            selection((100, 100), (-5.0, 50.0), (150.0, 120.0)),
+            (0, 50, 100, 50)
+        );
+    }
+
+    #[test]
+    fn test_crop() {
+        let image = blank(100, 100);
+        let cropped = crop(&image, (80.0, 70.0), (10.0, 20.0)).unwrap();
+        assert_eq!(cropped.dimensions(), (70, 50));
+    }
+
+    #[test]
+    fn test_crop_ignores_clicks() {
+        let image = blank(100, 100);
+        assert!(crop(&image, (50.0, 50.0), (52.0, 51.0)).is_none());
+    }
+
+    #[test]
+    fn test_draw_arrow() {
+        let mut image = blank(100, 100);
+        assert!(draw_arrow(&mut image, (10.0, 50.0), (90.0, 50.0)));
+        assert_eq!(*image.get_pixel(10, 50), ARROW_COLOR);
+        assert_eq!(*image.get_pixel(50, 50), ARROW_COLOR);
+        assert_eq!(*image.get_pixel(90, 50), ARROW_COLOR);
+        assert_ne!(*image.get_pixel(5, 5), ARROW_COLOR);
+    }
+
+    #[test]
+    fn test_draw_arrow_ignores_clicks() {
+        let mut image = blank(100, 100);
+        assert!(!draw_arrow(&mut image, (50.0, 50.0), (52.0, 51.0)));
+        assert!(image.pixels().all(|pixel| *pixel != ARROW_COLOR));
+    }
+}
@@ -0,0 +1,121 @@
+//! Linux capture using the freedesktop `Screenshot` portal
+//!
+//! The portal answers asynchronously: `Screenshot` returns a request object
+//! path and the result arrives later as a `Response` signal on that object.
+//! We subscribe to the signal before making the call (the request path is
+//! predictable from our unique bus name and a handle token) so a fast
+//! response can't be missed.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use iced::futures::StreamExt;
+use image::RgbaImage;
+use zbus::zvariant::{OwnedValue, Value};
+use zbus::{Connection, Proxy};
+
+use super::load_image;
+
+/// Portal service name
+const PORTAL_SERVICE: &str = "org.freedesktop.portal.Desktop";
+
+/// Portal object path
+const PORTAL_PATH: &str = "/org/freedesktop/portal/desktop";
+
+/// Screenshot portal interface
+const SCREENSHOT_INTERFACE: &str = "org.freedesktop.portal.Screenshot";
+
+/// Portal request interface (carries the `Response` signal)
+const REQUEST_INTERFACE: &str = "org.freedesktop.portal.Request";
+
+/// Handle token identifying our request
+const HANDLE_TOKEN: &str = "nexus_screenshot";
+
+/// `Response` code for a cancelled request
+const RESPONSE_CANCELLED: u32 = 1;
+
+/// Ask the portal for an interactive screenshot
+pub async fn capture_image() -> Result<Option<RgbaImage>, String> {
+    let connection = Connection::session().await.map_err(|e| e.to_string())?;
+    let sender = connection
+        .unique_name()
+        .ok_or_else(|| "no unique bus name".to_string())?
+        .as_str()
+        .trim_start_matches(':')
+        .replace('.', "_");
+    let request_path = format!("{PORTAL_PATH}/request/{sender}/{HANDLE_TOKEN}");
+
+    let request = Proxy::new(
+        &connection,
+        PORTAL_SERVICE,
+        request_path.as_str(),
+        REQUEST_INTERFACE,
+    )
+    .await
+    .map_err(|e| e.to_string())?;
+    let mut responses = request
+        .receive_signal("Response")
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut options: HashMap<&str, Value<'_>> = HashMap::new();
+    options.insert("handle_token", Value::from(HANDLE_TOKEN));
+    options.insert("interactive", Value::from(true));
+    connection
+        .call_method(
+            Some(PORTAL_SERVICE),
+            PORTAL_PATH,
+            Some(SCREENSHOT_INTERFACE),
+            "Screenshot",
+            &("", options),
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let response = responses
+        .next()
+        .await
+        .ok_or_else(|| "portal closed the request".to_string())?;
+    let (code, mut results): (u32, HashMap<String, OwnedValue>) =
+        response.body().deserialize().map_err(|e| e.to_string())?;
+
+    if code == RESPONSE_CANCELLED {
+        return Ok(None);
+    }
+    if code != 0 {
+        return Err(format!("portal error {code}"));
+    }
+
+    let uri = results
+        .remove("uri")
+        .and_then(|value| String::try_from(value).ok())
+        .ok_or_else(|| "portal returned no image".to_string())?;
+    let path = file_uri_path(&uri).ok_or_else(|| format!("unsupported image location {uri}"))?;
+
+    load_image(path).await.map(Some)
+}
+
+/// Convert a `file://` URI to a local path
+fn file_uri_path(uri: &str) -> Option<PathBuf> {
+    let path = uri.strip_prefix("file://")?;
+    let decoded = percent_encoding::percent_decode_str(path)
+        .decode_utf8()
+        .ok()?;
+    Some(PathBuf::from(decoded.into_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_uri_path() {
+        assert_eq!(
+            file_uri_path("file:///home/user/Pictures/Screenshot%20from%202024.png"),
+            Some(PathBuf::from(
+                "/home/user/Pictures/Screenshot from 2024.png"
+            ))
+        );
+        assert_eq!(file_uri_path("https://example.com/a.png"), None);
+    }
+}
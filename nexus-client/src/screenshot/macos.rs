@@ -0,0 +1,37 @@
+//! macOS capture using the system `screencapture` tool
+//!
+//! Interactive mode lets the user drag out an area or press Space to pick a
+//! window, exactly like the system screenshot shortcut. Pressing Escape
+//! cancels, in which case no file is written.
+
+use image::RgbaImage;
+use tokio::process::Command;
+
+use super::load_image;
+
+/// Run `screencapture` interactively and load the result
+pub async fn capture_image() -> Result<Option<RgbaImage>, String> {
+    let path = std::env::temp_dir().join(format!("nexus-capture-{}.png", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    // -i: interactive selection, -x: no shutter sound
+    let status = Command::new("screencapture")
+        .arg("-i")
+        .arg("-x")
+        .arg(&path)
+        .status()
+        .await
+        .map_err(|e| e.to_string())?;
+    if !status.success() {
+        return Err(format!("screencapture exited with {status}"));
+    }
+
+    // Cancelled selections leave no file behind
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let image = load_image(path.clone()).await;
+    let _ = std::fs::remove_file(&path);
+    image.map(Some)
+}
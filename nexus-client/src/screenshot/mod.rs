@@ -0,0 +1,111 @@
+//! Screen capture for sharing screenshots in chat
+//!
+//! Captures are taken with each platform's own screenshot facility:
+//!
+//! - **Linux**: the freedesktop `Screenshot` portal (works on Wayland and X11;
+//!   the desktop shows its own screen/window/area picker)
+//! - **macOS**: the system `screencapture` tool in interactive mode
+//!   (drag for an area, Space for a window)
+//! - **Windows**: a GDI copy of the whole virtual screen (crop it afterwards)
+//!
+//! The captured image is annotated (see [`annotate`]) and then written to a
+//! PNG file in the cache directory so it can be uploaded like any other file.
+
+pub mod annotate;
+#[cfg(target_os = "linux")]
+mod linux;
+#[cfg(target_os = "macos")]
+mod macos;
+#[cfg(target_os = "windows")]
+mod windows;
+
+use std::path::PathBuf;
+
+use image::RgbaImage;
+
+#[cfg(target_os = "linux")]
+use self::linux::capture_image;
+#[cfg(target_os = "macos")]
+use self::macos::capture_image;
+#[cfg(target_os = "windows")]
+use self::windows::capture_image;
+
+/// Subdirectory of the cache directory that holds screenshots waiting to upload
+const SCREENSHOT_CACHE_DIR: &str = "screenshots";
+
+/// A captured screenshot
+#[derive(Clone)]
+pub struct Screenshot(pub RgbaImage);
+
+// Manual Debug implementation so messages don't dump the pixel buffer
+impl std::fmt::Debug for Screenshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Screenshot")
+            .field(&format!("{}x{}", self.0.width(), self.0.height()))
+            .finish()
+    }
+}
+
+/// Capture a screenshot using the platform's screenshot facility
+///
+/// Returns `Ok(None)` when the user cancels the platform's picker.
+pub async fn capture() -> Result<Option<Screenshot>, String> {
+    capture_image().await.map(|image| image.map(Screenshot))
+}
+
+/// Capture fallback for platforms without a screenshot facility
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+async fn capture_image() -> Result<Option<RgbaImage>, String> {
+    Err("screenshots are not supported on this platform".to_string())
+}
+
+/// Write a screenshot to a timestamped PNG file for upload
+///
+/// Encoding blocks, so this runs on the blocking thread pool.
+pub async fn save_png(screenshot: Screenshot) -> Result<PathBuf, String> {
+    tokio::task::spawn_blocking(move || {
+        let dir = dirs::cache_dir()
+            .ok_or_else(|| "no cache directory".to_string())?
+            .join("nexus")
+            .join(SCREENSHOT_CACHE_DIR);
+        std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+
+        let path = dir.join(file_name(chrono::Local::now()));
+        screenshot.0.save(&path).map_err(|e| e.to_string())?;
+        Ok(path)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Build the file name a screenshot is saved and uploaded as
+fn file_name(time: chrono::DateTime<chrono::Local>) -> String {
+    format!("screenshot-{}.png", time.format("%Y%m%d-%H%M%S"))
+}
+
+/// Load an image file written by a platform capture tool
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+async fn load_image(path: PathBuf) -> Result<RgbaImage, String> {
+    tokio::task::spawn_blocking(move || {
+        image::open(&path)
+            .map(|image| image.to_rgba8())
+            .map_err(|e| e.to_string())
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn test_file_name() {
+        let time = chrono::Local
+            .with_ymd_and_hms(2024, 3, 9, 14, 5, 7)
+            .unwrap();
+        assert_eq!(file_name(time), "screenshot-20240309-140507.png");
+    }
+}
@@ -0,0 +1,114 @@
+//! Windows capture using GDI
+//!
+//! Copies the whole virtual screen (every monitor) into a bitmap. There is
+//! no system picker to hook into, so selecting a window or area is left to
+//! the crop tool in the annotation step.
+
+use std::ffi::c_void;
+
+use image::RgbaImage;
+use windows::Win32::Graphics::Gdi::{
+    BI_RGB, BITMAPINFO, BITMAPINFOHEADER, BitBlt, CreateCompatibleBitmap, CreateCompatibleDC,
+    DIB_RGB_COLORS, DeleteDC, DeleteObject, GetDC, GetDIBits, ReleaseDC, SRCCOPY, SelectObject,
+};
+use windows::Win32::UI::WindowsAndMessaging::{
+    GetSystemMetrics, SM_CXVIRTUALSCREEN, SM_CYVIRTUALSCREEN, SM_XVIRTUALSCREEN, SM_YVIRTUALSCREEN,
+};
+
+/// Capture the virtual screen (GDI calls block, so they run off the UI thread)
+pub async fn capture_image() -> Result<Option<RgbaImage>, String> {
+    tokio::task::spawn_blocking(capture_virtual_screen)
+        .await
+        .map_err(|e| e.to_string())?
+        .map(Some)
+}
+
+/// Copy the virtual screen into an RGBA image
+fn capture_virtual_screen() -> Result<RgbaImage, String> {
+    // SAFETY: GetSystemMetrics has no preconditions.
+    let (x, y, width, height) = unsafe {
+        (
+            GetSystemMetrics(SM_XVIRTUALSCREEN),
+            GetSystemMetrics(SM_YVIRTUALSCREEN),
+            GetSystemMetrics(SM_CXVIRTUALSCREEN),
+            GetSystemMetrics(SM_CYVIRTUALSCREEN),
+        )
+    };
+    if width <= 0 || height <= 0 {
+        return Err("no screen to capture".to_string());
+    }
+
+    let mut pixels = vec![0u8; width as usize * height as usize * 4];
+
+    // SAFETY: every handle is created here, checked, and released before
+    // returning; `pixels` is large enough for a top-down 32-bit DIB of the
+    // requested size.
+    let copied = unsafe {
+        let screen_dc = GetDC(None);
+        if screen_dc.is_invalid() {
+            return Err("cannot access the screen".to_string());
+        }
+        let memory_dc = CreateCompatibleDC(Some(screen_dc));
+        let bitmap = CreateCompatibleBitmap(screen_dc, width, height);
+        let previous = SelectObject(memory_dc, bitmap.into());
+
+        let blitted = BitBlt(
+            memory_dc,
+            0,
+            0,
+            width,
+            height,
+            Some(screen_dc),
+            x,
+            y,
+            SRCCOPY,
+        )
+        .is_ok();
+
+        let mut info = BITMAPINFO {
+            bmiHeader: BITMAPINFOHEADER {
+                biSize: std::mem::size_of::<BITMAPINFOHEADER>() as u32,
+                biWidth: width,
+                // Negative height requests a top-down bitmap
+                biHeight: -height,
+                biPlanes: 1,
+                biBitCount: 32,
+                biCompression: BI_RGB.0,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let lines = if blitted {
+            GetDIBits(
+                memory_dc,
+                bitmap,
+                0,
+                height as u32,
+                Some(pixels.as_mut_ptr().cast::<c_void>()),
+                &mut info,
+                DIB_RGB_COLORS,
+            )
+        } else {
+            0
+        };
+
+        SelectObject(memory_dc, previous);
+        let _ = DeleteObject(bitmap.into());
+        let _ = DeleteDC(memory_dc);
+        ReleaseDC(None, screen_dc);
+
+        lines == height
+    };
+    if !copied {
+        return Err("screen copy failed".to_string());
+    }
+
+    // GDI returns BGRX; convert to opaque RGBA
+    for pixel in pixels.chunks_exact_mut(4) {
+        pixel.swap(0, 2);
+        pixel[3] = u8::MAX;
+    }
+
+    RgbaImage::from_raw(width as u32, height as u32, pixels)
+        .ok_or_else(|| "screen copy failed".to_string())
+}
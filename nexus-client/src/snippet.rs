@@ -0,0 +1,82 @@
+//! Snippets (paste-bin style text shared by link)
+//!
+//! Long pastes can be stored on the server as a snippet, with a `nexus://`
+//! link posted to chat in their place. A paste that is a single fenced code
+//! block (```` ```rust ... ``` ````) is stored without the fence, tagged
+//! with its language so the viewer can highlight it.
+
+use nexus_common::validators::validate_snippet_language;
+
+/// How long shared snippets are kept, in seconds (7 days)
+pub const SNIPPET_EXPIRY_SECS: u32 = 7 * 24 * 60 * 60;
+
+/// Shortest fence for a Markdown code block
+const MIN_FENCE_LENGTH: usize = 3;
+
+/// Split a paste into snippet text and language
+///
+/// Text wrapped in a single code fence loses the fence, and the fence's
+/// info string becomes the language when it's a valid tag. Anything else
+/// is returned as is, without a language.
+pub fn snippet_from_paste(text: &str) -> (&str, Option<&str>) {
+    let trimmed = text.trim();
+    let Some(open) = trimmed.strip_prefix("```") else {
+        return (text, None);
+    };
+    let Some((info, body)) = open.split_once('\n') else {
+        return (text, None);
+    };
+    let Some(content) = body.trim_end().strip_suffix("```") else {
+        return (text, None);
+    };
+    if content.trim().is_empty() {
+        return (text, None);
+    }
+
+    let info = info.trim();
+    let language = (!info.is_empty() && validate_snippet_language(info).is_ok()).then_some(info);
+    (content.strip_suffix('\n').unwrap_or(content), language)
+}
+
+/// Markdown for a snippet as one fenced code block
+///
+/// The fence is longer than any run of backticks in the text, so the
+/// text can't close it early.
+pub fn fenced_code(content: &str, language: Option<&str>) -> String {
+    let longest_run = content.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat((longest_run + 1).max(MIN_FENCE_LENGTH));
+    format!(
+        "{fence}{}\n{}\n{fence}",
+        language.unwrap_or_default(),
+        content.trim_end_matches('\n')
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snippet_from_paste() {
+        assert_eq!(
+            snippet_from_paste("```rust\nfn main() {}\n```\n"),
+            ("fn main() {}", Some("rust"))
+        );
+        assert_eq!(snippet_from_paste("```\na\nb\n```"), ("a\nb", None));
+        // Info strings that aren't a language tag are dropped
+        assert_eq!(
+            snippet_from_paste("```rust title=x\ncode\n```"),
+            ("code", None)
+        );
+        // Not a single fenced block
+        assert_eq!(snippet_from_paste("plain\ntext"), ("plain\ntext", None));
+        assert_eq!(snippet_from_paste("```rust\nopen"), ("```rust\nopen", None));
+        assert_eq!(snippet_from_paste("```\n```"), ("```\n```", None));
+    }
+
+    #[test]
+    fn test_fenced_code() {
+        assert_eq!(fenced_code("a\n", Some("rust")), "```rust\na\n```");
+        assert_eq!(fenced_code("x ``` y", None), "````\nx ``` y\n````");
+    }
+}
@@ -1,8 +1,10 @@
 //! Sound playback for event notifications
 //!
 //! Uses cpal directly for audio output, with lewton for OGG/Vorbis decoding.
-//! Provides a simple queue-based system for playing notification sounds.
+//! Provides a simple queue-based system for playing notification sounds
+//! and soundboard clips.
 
+use std::borrow::Cow;
 use std::io::Cursor;
 use std::sync::Mutex;
 use std::sync::mpsc::{self, Sender};
@@ -33,10 +35,13 @@ const SOUND_POP: &[u8] = include_bytes!("../sounds/pop.ogg");
 // Audio Thread
 // =============================================================================
 
+/// Extra time allowed beyond a sound's length before playback is abandoned
+const PLAYBACK_TIMEOUT_MARGIN_MS: u64 = 2000;
+
 /// Request to play a sound
 struct SoundRequest {
-    /// Sound data to play
-    data: &'static [u8],
+    /// Sound data to play (embedded sound or soundboard clip)
+    data: Cow<'static, [u8]>,
     /// Volume level (0.0 - 1.0)
     volume: f32,
     /// Output device name (empty string = system default)
@@ -84,14 +89,14 @@ fn run_audio_thread(rx: mpsc::Receiver<SoundRequest>) {
 /// Play a sound synchronously (blocks until playback completes)
 fn play_sound_blocking(request: &SoundRequest) -> Result<(), String> {
     // Decode the OGG/Vorbis data
-    let cursor = Cursor::new(request.data);
+    let cursor = Cursor::new(request.data.as_ref());
     let mut reader =
         OggStreamReader::new(cursor).map_err(|e| format!("Failed to decode OGG: {}", e))?;
 
     let sample_rate = reader.ident_hdr.audio_sample_rate;
     let channels = reader.ident_hdr.audio_channels as u16;
 
-    // Collect all samples (sounds are short, so this is fine)
+    // Collect all samples (sounds and clips are short, so this is fine)
     let mut samples: Vec<f32> = Vec::new();
     while let Some(packet) = reader
         .read_dec_packet_itl()
@@ -155,8 +160,10 @@ fn play_sound_blocking(request: &SoundRequest) -> Result<(), String> {
         .play()
         .map_err(|e| format!("Failed to start playback: {}", e))?;
 
-    // Wait for playback to complete (with timeout)
-    let _ = done_rx.recv_timeout(std::time::Duration::from_secs(10));
+    // Wait for playback to complete (with timeout based on the sound's length)
+    let duration_ms = samples_len as u64 * 1000 / (sample_rate as u64 * channels.max(1) as u64);
+    let timeout = std::time::Duration::from_millis(duration_ms + PLAYBACK_TIMEOUT_MARGIN_MS);
+    let _ = done_rx.recv_timeout(timeout);
 
     // Stream is dropped here, stopping playback
     Ok(())
@@ -217,7 +224,26 @@ pub fn play_sound_on_device(sound: &SoundChoice, volume: f32, device_name: &str)
     if let Some(sender) = get_sound_sender() {
         // Send is non-blocking - if channel is full or disconnected, we just ignore
         let _ = sender.send(SoundRequest {
-            data,
+            data: Cow::Borrowed(data),
+            volume,
+            device_name: device_name.to_string(),
+        });
+    }
+}
+
+/// Play a soundboard clip at the given volume (0.0 - 1.0) on the specified output device
+///
+/// Clips are OGG/Vorbis audio received from the server and share the
+/// notification sound queue, so they never overlap each other.
+///
+/// # Arguments
+/// * `data` - OGG/Vorbis audio bytes
+/// * `volume` - Volume level (0.0 - 1.0)
+/// * `device_name` - Output device name, or empty string for system default
+pub fn play_clip_on_device(data: Vec<u8>, volume: f32, device_name: &str) {
+    if let Some(sender) = get_sound_sender() {
+        let _ = sender.send(SoundRequest {
+            data: Cow::Owned(data),
             volume,
             device_name: device_name.to_string(),
         });
@@ -0,0 +1,371 @@
+//! Spell checking for the chat input and news editor
+//!
+//! Words are checked against a Hunspell dictionary for the UI language. No
+//! dictionaries ship with Nexus: they're found in the `dictionaries` folder
+//! next to the config file, then where the system keeps Hunspell and MySpell
+//! dictionaries. Misspelled words are highlighted by a text editor
+//! highlighter, and right-clicking one offers suggestions.
+
+use std::fmt;
+use std::ops::Range;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use iced::advanced::text::highlighter::{self, Highlighter};
+use iced::{Font, Theme};
+
+use crate::constants::APP_DIR_NAME;
+
+/// Folder under the config directory searched for dictionaries first
+const DICTIONARY_DIR_NAME: &str = "dictionaries";
+
+/// Most suggestions offered for a misspelled word
+pub const MAX_SUGGESTIONS: usize = 6;
+
+/// Dictionary region used for UI languages without one
+const DEFAULT_REGIONS: &[(&str, &str)] = &[
+    ("de", "DE"),
+    ("en", "US"),
+    ("es", "ES"),
+    ("fr", "FR"),
+    ("it", "IT"),
+    ("ja", "JP"),
+    ("ko", "KR"),
+    ("nl", "NL"),
+    ("ru", "RU"),
+];
+
+/// A loaded Hunspell dictionary
+pub struct SpellChecker {
+    dictionary: spellbook::Dictionary,
+    /// Dictionary name, such as `en_US`
+    language: String,
+}
+
+impl fmt::Debug for SpellChecker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SpellChecker")
+            .field("language", &self.language)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SpellChecker {
+    /// Load the dictionary for a UI locale (such as `en` or `pt-BR`)
+    ///
+    /// Parsing a dictionary takes a moment, so this runs on the blocking
+    /// thread pool. Returns None when no dictionary for the language is
+    /// installed or it can't be read.
+    pub async fn load(locale: String) -> Option<Arc<Self>> {
+        tokio::task::spawn_blocking(move || {
+            let (language, aff_path, dic_path) = find_dictionary(&locale)?;
+            let aff = std::fs::read_to_string(aff_path).ok()?;
+            let dic = std::fs::read_to_string(dic_path).ok()?;
+            let dictionary = spellbook::Dictionary::new(&aff, &dic).ok()?;
+            Some(Arc::new(Self {
+                dictionary,
+                language,
+            }))
+        })
+        .await
+        .ok()
+        .flatten()
+    }
+
+    /// Dictionary name, such as `en_US`
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    /// Whether a word is spelled correctly
+    pub fn check(&self, word: &str) -> bool {
+        self.dictionary.check(&word.replace('’', "'"))
+    }
+
+    /// Replacements for a misspelled word, best first
+    pub fn suggest(&self, word: &str) -> Vec<String> {
+        let mut suggestions = Vec::new();
+        self.dictionary
+            .suggest(&word.replace('’', "'"), &mut suggestions);
+        suggestions.truncate(MAX_SUGGESTIONS);
+        suggestions
+    }
+}
+
+/// Dictionary names to try for a UI locale, best first
+///
+/// `pt-BR` tries `pt_BR` then `pt`; `de` tries `de_DE` then `de`.
+fn dictionary_names(locale: &str) -> Vec<String> {
+    let (language, region) = match locale.split_once('-') {
+        Some((language, region)) => (language, Some(region)),
+        None => (locale, None),
+    };
+    let region = region.or_else(|| {
+        DEFAULT_REGIONS
+            .iter()
+            .find(|(default_language, _)| *default_language == language)
+            .map(|(_, region)| *region)
+    });
+
+    let mut names = Vec::new();
+    if let Some(region) = region {
+        names.push(format!("{language}_{region}"));
+    }
+    names.push(language.to_string());
+    names
+}
+
+/// Folders searched for `.aff` and `.dic` files, in order
+fn dictionary_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    if let Some(config_dir) = dirs::config_dir() {
+        dirs.push(config_dir.join(APP_DIR_NAME).join(DICTIONARY_DIR_NAME));
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        if let Some(home) = dirs::home_dir() {
+            dirs.push(home.join("Library/Spelling"));
+        }
+        dirs.push(PathBuf::from("/Library/Spelling"));
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    {
+        if let Some(data_dir) = dirs::data_dir() {
+            dirs.push(data_dir.join("hunspell"));
+        }
+        dirs.push(PathBuf::from("/usr/share/hunspell"));
+        dirs.push(PathBuf::from("/usr/share/myspell"));
+        dirs.push(PathBuf::from("/usr/share/myspell/dicts"));
+        dirs.push(PathBuf::from("/usr/local/share/hunspell"));
+    }
+
+    dirs
+}
+
+/// Find the dictionary for a UI locale
+///
+/// Returns the dictionary name and its `.aff` and `.dic` files. When no
+/// exact name matches, any dictionary for the same language will do.
+fn find_dictionary(locale: &str) -> Option<(String, PathBuf, PathBuf)> {
+    let dirs = dictionary_dirs();
+    let files = |dir: &PathBuf, name: &str| {
+        let aff = dir.join(format!("{name}.aff"));
+        let dic = dir.join(format!("{name}.dic"));
+        (aff.is_file() && dic.is_file()).then_some((name.to_string(), aff, dic))
+    };
+
+    let names = dictionary_names(locale);
+    for name in &names {
+        if let Some(found) = dirs.iter().find_map(|dir| files(dir, name)) {
+            return Some(found);
+        }
+    }
+
+    // Another region of the same language (en_GB for en)
+    let prefix = format!("{}_", names.last()?);
+    dirs.iter().find_map(|dir| {
+        let mut names: Vec<String> = std::fs::read_dir(dir)
+            .ok()?
+            .filter_map(|entry| {
+                let name = entry.ok()?.file_name().into_string().ok()?;
+                let name = name.strip_suffix(".dic")?;
+                name.starts_with(&prefix).then(|| name.to_string())
+            })
+            .collect();
+        names.sort_unstable();
+        names.iter().find_map(|name| files(dir, name))
+    })
+}
+
+// =============================================================================
+// Finding Words
+// =============================================================================
+
+/// Whether a character separates the parts of a word like "don't"
+fn is_apostrophe(c: char) -> bool {
+    c == '\'' || c == '’'
+}
+
+/// Whether a whitespace-separated chunk of text is worth checking
+///
+/// Links, email addresses, mentions, channels, commands and anything with
+/// digits or underscores (nicknames, file names, code) are skipped.
+fn is_checkable_chunk(chunk: &str) -> bool {
+    !(chunk.contains("://")
+        || chunk.starts_with("www.")
+        || chunk.starts_with(['#', '/', '@'])
+        || chunk.contains('@')
+        || chunk.contains(|c: char| c.is_ascii_digit() || c == '_'))
+}
+
+/// Byte ranges of the words in a line that are spell checked
+///
+/// Words are runs of letters, with apostrophes allowed between letters.
+/// Single letters and all-capital words (acronyms) are left out.
+pub fn words(line: &str) -> Vec<Range<usize>> {
+    let mut words = Vec::new();
+    let mut chunk_start = 0;
+
+    for piece in line.split_inclusive(char::is_whitespace) {
+        let chunk = piece.trim_end_matches(char::is_whitespace);
+        if is_checkable_chunk(chunk) {
+            let mut start = None;
+            let mut chars = chunk.char_indices().peekable();
+            while let Some((index, c)) = chars.next() {
+                let inner_apostrophe = is_apostrophe(c)
+                    && start.is_some()
+                    && chars.peek().is_some_and(|(_, next)| next.is_alphabetic());
+                if c.is_alphabetic() || inner_apostrophe {
+                    start.get_or_insert(index);
+                } else if let Some(start) = start.take() {
+                    words.push(chunk_start + start..chunk_start + index);
+                }
+            }
+            if let Some(start) = start {
+                words.push(chunk_start + start..chunk_start + chunk.len());
+            }
+        }
+        chunk_start += piece.len();
+    }
+
+    words.retain(|range| {
+        let word = &line[range.clone()];
+        let is_acronym =
+            word.chars().any(char::is_uppercase) && !word.chars().any(char::is_lowercase);
+        word.chars().nth(1).is_some() && !is_acronym
+    });
+    words
+}
+
+/// Byte ranges of the misspelled words in a line
+pub fn misspellings(line: &str, is_correct: impl Fn(&str) -> bool) -> Vec<Range<usize>> {
+    words(line)
+        .into_iter()
+        .filter(|range| !is_correct(&line[range.clone()]))
+        .collect()
+}
+
+// =============================================================================
+// Text Editor Highlighting
+// =============================================================================
+
+/// Highlighter settings: the dictionary to check against, or None when spell
+/// checking is off or no dictionary was found
+#[derive(Debug, Clone, Default)]
+pub struct SpellSettings(pub Option<Arc<SpellChecker>>);
+
+impl PartialEq for SpellSettings {
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+/// A misspelled word in a text editor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Misspelling;
+
+/// Text editor highlighter that marks misspelled words
+pub struct SpellHighlighter {
+    checker: Option<Arc<SpellChecker>>,
+    current_line: usize,
+}
+
+impl Highlighter for SpellHighlighter {
+    type Settings = SpellSettings;
+    type Highlight = Misspelling;
+    type Iterator<'a> = std::vec::IntoIter<(Range<usize>, Misspelling)>;
+
+    fn new(settings: &Self::Settings) -> Self {
+        Self {
+            checker: settings.0.clone(),
+            current_line: 0,
+        }
+    }
+
+    fn update(&mut self, new_settings: &Self::Settings) {
+        self.checker = new_settings.0.clone();
+        self.current_line = 0;
+    }
+
+    fn change_line(&mut self, line: usize) {
+        self.current_line = self.current_line.min(line);
+    }
+
+    fn highlight_line(&mut self, line: &str) -> Self::Iterator<'_> {
+        self.current_line += 1;
+        let Some(checker) = &self.checker else {
+            return Vec::new().into_iter();
+        };
+        misspellings(line, |word| checker.check(word))
+            .into_iter()
+            .map(|range| (range, Misspelling))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    fn current_line(&self) -> usize {
+        self.current_line
+    }
+}
+
+/// Show misspelled words in the theme's danger color
+pub fn misspelling_format(_misspelling: &Misspelling, theme: &Theme) -> highlighter::Format<Font> {
+    highlighter::Format {
+        color: Some(theme.palette().danger),
+        font: None,
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word_list(line: &str) -> Vec<&str> {
+        words(line).into_iter().map(|range| &line[range]).collect()
+    }
+
+    #[test]
+    fn test_words() {
+        assert_eq!(
+            word_list("Hello, wrold! Don't  stop."),
+            ["Hello", "wrold", "Don't", "stop"]
+        );
+        assert_eq!(word_list("naïve café 日本語"), ["naïve", "café", "日本語"]);
+        assert_eq!(word_list("'quoted' rock'n'roll"), ["quoted", "rock'n'roll"]);
+    }
+
+    #[test]
+    fn test_words_skips_non_words() {
+        assert!(words("").is_empty());
+        assert!(words("a I NASA").is_empty());
+        assert!(words("https://example.com/pagee www.exmple.com").is_empty());
+        assert!(words("#generl /joinn @alise alise@example.com").is_empty());
+        assert!(words("user_nam file2.txt 42nd").is_empty());
+    }
+
+    #[test]
+    fn test_misspellings() {
+        let line = "teh quick brwn fox";
+        let known = ["quick", "fox"];
+        let ranges = misspellings(line, |word| known.contains(&word));
+        assert_eq!(ranges, [0..3, 10..14]);
+    }
+
+    #[test]
+    fn test_dictionary_names() {
+        assert_eq!(dictionary_names("en"), ["en_US", "en"]);
+        assert_eq!(dictionary_names("pt-BR"), ["pt_BR", "pt"]);
+        assert_eq!(dictionary_names("zh-TW"), ["zh_TW", "zh"]);
+        assert_eq!(dictionary_names("xx"), ["xx"]);
+    }
+}
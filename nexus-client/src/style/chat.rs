@@ -6,6 +6,7 @@
 //! These colors are intentionally kept separate from the UI palette to allow
 //! fine-grained control over chat message appearance across all themes.
 
+use iced::highlighter;
 use iced::{Color, Theme};
 
 // ============================================================================
@@ -28,10 +29,18 @@ const BROADCAST_DARK: Color = Color::from_rgb(1.0, 0.3, 0.3); // Coral
 const SYSTEM_LIGHT: Color = Color::from_rgb(0.35, 0.35, 0.35); // Dark slate
 const SYSTEM_DARK: Color = Color::from_rgb(0.7, 0.7, 0.7); // Silver
 
+// Highlight - warm amber for messages that mention us or match a keyword
+const HIGHLIGHT_LIGHT: Color = Color::from_rgb(0.7, 0.4, 0.0); // Dark amber
+const HIGHLIGHT_DARK: Color = Color::from_rgb(1.0, 0.75, 0.3); // Light amber
+
 // Shared account users - muted/weak color to distinguish from regular users
 const SHARED_LIGHT: Color = Color::from_rgb(0.5, 0.5, 0.5); // Gray
 const SHARED_DARK: Color = Color::from_rgb(0.55, 0.55, 0.55); // Dim gray
 
+// Code blocks - set off from the chat background, matching the highlighter theme
+const CODE_BACKGROUND_LIGHT: Color = Color::from_rgb(0.96, 0.96, 0.96); // Near white
+const CODE_BACKGROUND_DARK: Color = Color::from_rgb(0.17, 0.19, 0.23); // Ocean slate
+
 // ============================================================================
 // Helper
 // ============================================================================
@@ -94,6 +103,13 @@ pub fn shared(theme: &Theme) -> Color {
     for_theme(theme, SHARED_LIGHT, SHARED_DARK)
 }
 
+/// Highlighted message color
+///
+/// Warm amber for messages that mention us or match a watched keyword.
+pub fn highlight(theme: &Theme) -> Color {
+    for_theme(theme, HIGHLIGHT_LIGHT, HIGHLIGHT_DARK)
+}
+
 /// Info message color ([INFO])
 ///
 /// Uses the theme's primary color for informational messages and command responses.
@@ -107,3 +123,19 @@ pub fn info(theme: &Theme) -> Color {
 pub fn error(theme: &Theme) -> Color {
     theme.palette().danger
 }
+
+/// Code block background color
+///
+/// Matches the background of the syntax highlighting theme below.
+pub fn code_background(theme: &Theme) -> Color {
+    for_theme(theme, CODE_BACKGROUND_LIGHT, CODE_BACKGROUND_DARK)
+}
+
+/// Syntax highlighting theme for code blocks
+pub fn code_theme(theme: &Theme) -> highlighter::Theme {
+    if theme.extended_palette().is_dark {
+        highlighter::Theme::Base16Ocean
+    } else {
+        highlighter::Theme::InspiredGitHub
+    }
+}
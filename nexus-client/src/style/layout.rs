@@ -122,6 +122,9 @@ pub const CONTEXT_MENU_SHADOW_BLUR: f32 = 8.0;
 /// Small padding (general use)
 pub const SMALL_PADDING: f32 = 5.0;
 
+/// Padding inside the copy and expand buttons on chat code blocks (vertical, horizontal)
+pub const CODE_BLOCK_BUTTON_PADDING: [f32; 2] = [2.0, 6.0];
+
 /// Scrollbar padding (right padding for content next to scrollbar)
 pub const SCROLLBAR_PADDING: f32 = 12.0;
 
@@ -159,12 +162,24 @@ pub const TOOLBAR_ICON_SPACING: f32 = 0.0;
 /// Panel spacing (no gap between panels)
 pub const PANEL_SPACING: f32 = 0.0;
 
+/// Gap between chat and the panel beside it in split view
+pub const SPLIT_DIVIDER_WIDTH: f32 = 2.0;
+
+/// Distance either side of the split view divider that still grabs it
+pub const SPLIT_RESIZE_LEEWAY: f32 = 6.0;
+
 /// Server list section spacing
 pub const SERVER_LIST_SECTION_SPACING: f32 = 5.0;
 
 /// Server list item spacing
 pub const SERVER_LIST_ITEM_SPACING: f32 = 3.0;
 
+/// Indent of bookmarks inside a folder in the server list
+pub const SERVER_LIST_FOLDER_INDENT: f32 = 12.0;
+
+/// Width of the drag handle on server list bookmarks
+pub const SERVER_LIST_DRAG_HANDLE_WIDTH: f32 = 16.0;
+
 /// No spacing between button and icon (flush)
 pub const NO_SPACING: f32 = 0.0;
 
@@ -187,6 +202,12 @@ pub const SERVER_LIST_PANEL_WIDTH: f32 = 220.0;
 /// Server list button height
 pub const SERVER_LIST_BUTTON_HEIGHT: f32 = 32.0;
 
+/// Height of the line showing where a dragged bookmark will land
+pub const SERVER_LIST_DROP_INDICATOR_HEIGHT: f32 = 2.0;
+
+/// Tallest the chat input grows to before it scrolls (about eight lines)
+pub const CHAT_INPUT_MAX_HEIGHT: f32 = 180.0;
+
 /// Separator line height
 pub const SEPARATOR_HEIGHT: f32 = 1.0;
 
@@ -248,6 +269,9 @@ pub const NEWS_ACTION_BUTTON_SIZE: f32 = 32.0;
 /// News item action icon size
 pub const NEWS_ACTION_ICON_SIZE: f32 = 18.0;
 
+/// Padding inside the news body editor
+pub const NEWS_EDITOR_PADDING: f32 = CONTENT_PADDING / 2.0;
+
 /// News image preview size in create/edit forms
 pub const NEWS_IMAGE_PREVIEW_SIZE: f32 = 48.0;
 
@@ -269,6 +293,19 @@ pub const NEWS_IMAGE_MAX_CACHE_WIDTH: u32 =
 /// SVGs are not resized (vector graphics scale without quality loss).
 pub const SERVER_IMAGE_MAX_CACHE_WIDTH: u32 = CONTENT_MAX_WIDTH as u32;
 
+// ============================================================================
+// Screenshot Editor Layout
+// ============================================================================
+
+/// Maximum width of the screenshot preview in the annotation editor
+pub const SCREENSHOT_PREVIEW_MAX_WIDTH: f32 = CONTENT_MAX_WIDTH - CONTENT_PADDING * 2.0;
+
+/// Maximum height of the screenshot preview in the annotation editor
+pub const SCREENSHOT_PREVIEW_MAX_HEIGHT: f32 = 420.0;
+
+/// Border width of the crop selection drawn over the preview
+pub const SCREENSHOT_SELECTION_BORDER_WIDTH: f32 = 2.0;
+
 // ============================================================================
 // Transfers Panel Layout
 // ============================================================================
@@ -297,6 +334,15 @@ pub const TRANSFER_PROGRESS_BAR_HEIGHT: f32 = 16.0;
 /// Spacing below progress bar (before stats line)
 pub const TRANSFER_PROGRESS_SPACING: f32 = 4.0;
 
+/// Padding inside the transfer status bar (vertical, horizontal)
+pub const TRANSFER_BAR_PADDING: [f32; 2] = [4.0, 20.0];
+
+/// Width of the aggregate progress bar in the transfer status bar
+pub const TRANSFER_BAR_PROGRESS_WIDTH: f32 = 160.0;
+
+/// Height of the aggregate progress bar in the transfer status bar
+pub const TRANSFER_BAR_PROGRESS_HEIGHT: f32 = 8.0;
+
 // ============================================================================
 // File Browser
 // ============================================================================
@@ -353,6 +399,32 @@ pub const FINGERPRINT_SPACE_BETWEEN_SECTIONS: f32 = 8.0;
 /// Space before button row in fingerprint dialog
 pub const FINGERPRINT_SPACE_BEFORE_BUTTONS: f32 = 10.0;
 
+// ============================================================================
+// Quick Switcher
+// ============================================================================
+
+/// Width of the quick switcher card
+pub const QUICK_SWITCHER_WIDTH: f32 = 480.0;
+
+/// Space between the top of the window and the quick switcher card
+pub const QUICK_SWITCHER_TOP_OFFSET: f32 = 80.0;
+
+/// Most matches listed in the quick switcher
+pub const QUICK_SWITCHER_MAX_RESULTS: usize = 10;
+
+// ============================================================================
+// First-run Tips
+// ============================================================================
+
+/// Width of the outline around the panel a tip is about
+pub const TIP_HIGHLIGHT_WIDTH: f32 = 2.0;
+
+/// Padding inside the tip bar below the panels (vertical, horizontal)
+pub const TIP_BAR_PADDING: [f32; 2] = [6.0, 20.0];
+
+/// Padding inside the welcome card on the connection form
+pub const TIP_CARD_PADDING: f32 = 12.0;
+
 // ============================================================================
 // Chat Message Formatting
 // ============================================================================
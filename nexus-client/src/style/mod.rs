@@ -48,13 +48,15 @@ pub use icons::*;
 pub use layout::*;
 pub use shaping::{shaped_text, shaped_text_wrapped};
 pub use widgets::{
-    alternating_row_style, badge_style, chat_tab_active_style, close_button_on_primary_style,
-    content_background_style, context_menu_container_style, danger_icon_button_style,
-    disabled_icon_button_style, drop_overlay_style, error_text_style, icon_button_with_hover_style,
+    alternating_row_style, badge_style, chart_bar_style, chat_tab_active_style,
+    close_button_on_primary_style, code_block_style, content_background_style,
+    context_menu_container_style, danger_icon_button_style, disabled_icon_button_style,
+    drop_indicator_style, drop_overlay_style, error_text_style, icon_button_with_hover_style,
     list_item_button_style, menu_button_danger_style, menu_button_style, modal_overlay_style,
-    muted_text_style, panel_title, separator_style, sidebar_panel_style, speaking_indicator_style,
+    muted_text_style, panel_title, screenshot_selection_style, separator_style,
+    sidebar_panel_style, speaking_indicator_style, tip_card_style, tip_highlight_style,
     toast_style, toolbar_background_style, toolbar_button_style, tooltip_container_style,
     transparent_icon_button_style, upload_folder_style, user_list_item_button_style,
-    user_toolbar_separator_style, voice_bar_style, voice_deafen_button_style,
+    user_toolbar_separator_style, voice_bar_style, voice_deafen_button_style, warning_text_style,
 };
 pub use window::*;
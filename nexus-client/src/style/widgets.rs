@@ -7,10 +7,10 @@
 use super::shaping::shaped_text;
 use super::ui;
 use super::{
-    CONTEXT_MENU_BORDER_WIDTH, CONTEXT_MENU_SHADOW_BLUR, CONTEXT_MENU_SHADOW_OFFSET,
-    CONTEXT_MENU_SHADOW_OPACITY, STANDARD_BORDER_RADIUS, TITLE_ROW_HEIGHT_WITH_ACTION, TITLE_SIZE,
-    TOAST_BORDER_RADIUS, TOAST_BORDER_WIDTH, TOAST_SHADOW_BLUR, TOAST_SHADOW_OFFSET,
-    TOAST_SHADOW_OPACITY,
+    BORDER_WIDTH, CONTEXT_MENU_BORDER_WIDTH, CONTEXT_MENU_SHADOW_BLUR, CONTEXT_MENU_SHADOW_OFFSET,
+    CONTEXT_MENU_SHADOW_OPACITY, STANDARD_BORDER_RADIUS, TIP_HIGHLIGHT_WIDTH,
+    TITLE_ROW_HEIGHT_WITH_ACTION, TITLE_SIZE, TOAST_BORDER_RADIUS, TOAST_BORDER_WIDTH,
+    TOAST_SHADOW_BLUR, TOAST_SHADOW_OFFSET, TOAST_SHADOW_OPACITY,
 };
 use crate::types::Message;
 use crate::widgets::{MenuButtonStatus, MenuButtonStyle};
@@ -239,6 +239,19 @@ pub fn toolbar_background_style(theme: &Theme) -> container::Style {
     }
 }
 
+/// Code block container style - highlighter background with a subtle border
+pub fn code_block_style(theme: &Theme) -> container::Style {
+    container::Style {
+        background: Some(Background::Color(super::chat::code_background(theme))),
+        border: Border {
+            color: ui::sidebar_border(theme),
+            width: 1.0,
+            radius: STANDARD_BORDER_RADIUS.into(),
+        },
+        ..Default::default()
+    }
+}
+
 /// Tooltip container style - uses Iced's built-in bordered box style
 pub fn tooltip_container_style(theme: &Theme) -> container::Style {
     container::bordered_box(theme)
@@ -322,6 +335,13 @@ pub fn error_text_style(theme: &Theme) -> text::Style {
     }
 }
 
+/// Warning text style - uses the theme's warning color
+pub fn warning_text_style(theme: &Theme) -> text::Style {
+    text::Style {
+        color: Some(theme.extended_palette().warning.base.color),
+    }
+}
+
 /// Muted text style - for section titles and secondary info
 pub fn muted_text_style(theme: &Theme) -> text::Style {
     text::Style {
@@ -352,6 +372,46 @@ pub fn drop_overlay_style(theme: &Theme) -> container::Style {
     }
 }
 
+/// Container style for the crop selection in the screenshot editor
+///
+/// Primary-colored outline over a faint tint so the selected area stays visible.
+pub fn screenshot_selection_style(theme: &Theme) -> container::Style {
+    let primary = theme.extended_palette().primary.base.color;
+    container::Style {
+        background: Some(Background::Color(Color { a: 0.15, ..primary })),
+        border: Border {
+            color: primary,
+            width: super::SCREENSHOT_SELECTION_BORDER_WIDTH,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// First-run tip card style - bordered box outlined in the primary color
+pub fn tip_card_style(theme: &Theme) -> container::Style {
+    container::Style {
+        border: Border {
+            color: theme.extended_palette().primary.base.color,
+            width: BORDER_WIDTH,
+            radius: STANDARD_BORDER_RADIUS.into(),
+        },
+        ..container::bordered_box(theme)
+    }
+}
+
+/// Outline around the panel the current first-run tip is about
+pub fn tip_highlight_style(theme: &Theme) -> container::Style {
+    container::Style {
+        border: Border {
+            color: theme.extended_palette().primary.base.color,
+            width: TIP_HIGHLIGHT_WIDTH,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
 /// Subheading text style - for section headers within panels
 ///
 /// Uses muted color to create visual hierarchy below the main title.
@@ -400,6 +460,26 @@ pub fn badge_style(theme: &Theme) -> container::Style {
     }
 }
 
+/// Drop indicator style - solid primary line showing where a dragged bookmark will land
+pub fn drop_indicator_style(theme: &Theme) -> container::Style {
+    container::Style {
+        background: Some(Background::Color(
+            theme.extended_palette().primary.base.color,
+        )),
+        ..Default::default()
+    }
+}
+
+/// Chart bar style - solid primary color for statistics chart bars
+pub fn chart_bar_style(theme: &Theme) -> container::Style {
+    container::Style {
+        background: Some(Background::Color(
+            theme.extended_palette().primary.base.color,
+        )),
+        ..Default::default()
+    }
+}
+
 /// Voice bar container style - subtle background for the voice status bar
 ///
 /// Uses a slightly tinted background to distinguish the voice bar from the
@@ -20,5 +20,21 @@ pub const WINDOW_WIDTH_MIN: f32 = 800.0;
 /// Minimum window height
 pub const WINDOW_HEIGHT_MIN: f32 = 500.0;
 
-/// Window title (same as APP_NAME - Iced requires &'static str or closure)
+/// Main window title (same as APP_NAME)
 pub const WINDOW_TITLE: &str = APP_NAME;
+
+// ============================================================================
+// Chat Windows
+// ============================================================================
+
+/// Default width of a popped-out chat window
+pub const CHAT_WINDOW_WIDTH: f32 = 600.0;
+
+/// Default height of a popped-out chat window
+pub const CHAT_WINDOW_HEIGHT: f32 = 500.0;
+
+/// Minimum width of a popped-out chat window
+pub const CHAT_WINDOW_WIDTH_MIN: f32 = 320.0;
+
+/// Minimum height of a popped-out chat window
+pub const CHAT_WINDOW_HEIGHT_MIN: f32 = 240.0;
@@ -0,0 +1,74 @@
+//! Linux taskbar progress using the Unity `LauncherEntry` D-Bus API
+//!
+//! The signal is broadcast on the session bus; any dock or launcher that
+//! implements the API (KDE Plasma, Ubuntu Dock, Dash to Dock, Plank) picks it
+//! up by matching the desktop file URI. Launchers that don't implement it
+//! simply ignore the signal.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use tokio::sync::OnceCell;
+use zbus::Connection;
+use zbus::zvariant::Value;
+
+/// Desktop file the launcher entry belongs to (matches `assets/linux/nexus.desktop`)
+const LAUNCHER_APP_URI: &str = "application://nexus.desktop";
+
+/// Object path the update signal is emitted from
+const LAUNCHER_OBJECT_PATH: &str = "/at/greyh/nexus/launcher";
+
+/// Unity launcher entry interface
+const LAUNCHER_INTERFACE: &str = "com.canonical.Unity.LauncherEntry";
+
+/// Signal name for launcher entry updates
+const LAUNCHER_SIGNAL: &str = "Update";
+
+/// Unity launcher entry backend
+pub struct Backend {
+    /// Session bus connection (None inside the cell if the bus is unavailable)
+    connection: Arc<OnceCell<Option<Connection>>>,
+}
+
+impl Backend {
+    /// Create a new backend (the bus connection is opened on first update)
+    pub fn new() -> Self {
+        Self {
+            connection: Arc::new(OnceCell::new()),
+        }
+    }
+
+    /// Emit a launcher entry update with the given percentage
+    pub fn set_progress(&mut self, percent: Option<u8>) {
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+
+        let connection = self.connection.clone();
+        runtime.spawn(async move {
+            let connection = connection
+                .get_or_init(|| async { Connection::session().await.ok() })
+                .await;
+            let Some(connection) = connection else {
+                return;
+            };
+
+            let mut properties: HashMap<&str, Value<'_>> = HashMap::new();
+            properties.insert(
+                "progress",
+                Value::from(f64::from(percent.unwrap_or(0)) / 100.0),
+            );
+            properties.insert("progress-visible", Value::from(percent.is_some()));
+
+            let _ = connection
+                .emit_signal(
+                    None::<&str>,
+                    LAUNCHER_OBJECT_PATH,
+                    LAUNCHER_INTERFACE,
+                    LAUNCHER_SIGNAL,
+                    &(LAUNCHER_APP_URI, properties),
+                )
+                .await;
+        });
+    }
+}
@@ -0,0 +1,20 @@
+//! macOS dock progress using the dock tile badge
+//!
+//! `NSDockTile` has no native progress bar, so the percentage is shown as the
+//! badge label (e.g. "42%") and cleared when transfers finish. The badge is
+//! shared with the unread count, so it's set through [`crate::dock`].
+
+/// Dock tile badge backend
+pub struct Backend;
+
+impl Backend {
+    /// Create a new backend
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Update the dock tile badge label
+    pub fn set_progress(&mut self, percent: Option<u8>) {
+        crate::dock::set_progress(percent);
+    }
+}
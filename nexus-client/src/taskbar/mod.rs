@@ -0,0 +1,106 @@
+//! Taskbar and dock progress integration
+//!
+//! Mirrors the aggregate transfer progress onto the OS-level application icon:
+//!
+//! - **Windows**: `ITaskbarList3` progress on the taskbar button
+//! - **macOS**: Percentage badge on the dock tile
+//! - **Linux**: Unity `LauncherEntry` D-Bus signal (KDE Plasma, Ubuntu Dock,
+//!   Dash to Dock and other launchers that implement the API)
+//!
+//! Other platforms get a no-op backend. Updates are de-duplicated at whole
+//! percent granularity so the frequent `TransferEvent::Progress` stream doesn't
+//! hammer the OS APIs.
+
+#[cfg(target_os = "linux")]
+mod linux;
+#[cfg(target_os = "macos")]
+mod macos;
+#[cfg(target_os = "windows")]
+mod windows;
+
+#[cfg(target_os = "linux")]
+use self::linux::Backend;
+#[cfg(target_os = "macos")]
+use self::macos::Backend;
+#[cfg(target_os = "windows")]
+use self::windows::Backend;
+
+// =============================================================================
+// Taskbar Manager
+// =============================================================================
+
+/// Shows aggregate transfer progress on the taskbar/dock icon
+pub struct TaskbarManager {
+    /// Platform backend
+    backend: Backend,
+    /// Last percentage sent to the backend (None = progress hidden)
+    current: Option<u8>,
+}
+
+impl TaskbarManager {
+    /// Create a new taskbar manager
+    ///
+    /// Platform resources are acquired lazily on the first visible update.
+    pub fn new() -> Self {
+        Self {
+            backend: Backend::new(),
+            current: None,
+        }
+    }
+
+    /// Show progress as a fraction (0.0 to 1.0), or hide it with `None`
+    pub fn set_progress(&mut self, progress: Option<f64>) {
+        let percent = progress.map(progress_percent);
+        if percent == self.current {
+            return;
+        }
+        self.current = percent;
+        self.backend.set_progress(percent);
+    }
+}
+
+impl Default for TaskbarManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Convert a progress fraction to a whole percentage (0-100)
+fn progress_percent(progress: f64) -> u8 {
+    (progress.clamp(0.0, 1.0) * 100.0).floor() as u8
+}
+
+// =============================================================================
+// Fallback Backend
+// =============================================================================
+
+/// No-op backend for platforms without taskbar progress support
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+struct Backend;
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+impl Backend {
+    fn new() -> Self {
+        Self
+    }
+
+    fn set_progress(&mut self, _percent: Option<u8>) {}
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_percent() {
+        assert_eq!(progress_percent(0.0), 0);
+        assert_eq!(progress_percent(0.255), 25);
+        assert_eq!(progress_percent(1.0), 100);
+        assert_eq!(progress_percent(1.5), 100);
+        assert_eq!(progress_percent(-0.5), 0);
+    }
+}
@@ -0,0 +1,96 @@
+//! Windows taskbar progress using `ITaskbarList3`
+//!
+//! The COM object and window handle are resolved lazily on the first update,
+//! once the main window exists. Iced runs `update` on the UI thread, which is
+//! where the taskbar interface must be used.
+
+use windows::Win32::Foundation::HWND;
+use windows::Win32::System::Com::{
+    CLSCTX_INPROC_SERVER, COINIT_APARTMENTTHREADED, CoCreateInstance, CoInitializeEx,
+};
+use windows::Win32::UI::Shell::{ITaskbarList3, TBPF_NOPROGRESS, TBPF_NORMAL, TaskbarList};
+use windows::Win32::UI::WindowsAndMessaging::{FindWindowW, GetWindowThreadProcessId};
+use windows::core::{HSTRING, PCWSTR};
+
+use crate::style::WINDOW_TITLE;
+
+/// Progress total reported to the taskbar (values are whole percentages)
+const PROGRESS_TOTAL: u64 = 100;
+
+/// `ITaskbarList3` backend
+pub struct Backend {
+    /// Taskbar COM interface (created on first use)
+    taskbar: Option<ITaskbarList3>,
+    /// Main window handle (looked up on first use)
+    hwnd: Option<HWND>,
+}
+
+impl Backend {
+    /// Create a new backend (COM objects are created on first update)
+    pub fn new() -> Self {
+        Self {
+            taskbar: None,
+            hwnd: None,
+        }
+    }
+
+    /// Update the taskbar button progress
+    pub fn set_progress(&mut self, percent: Option<u8>) {
+        let Some(hwnd) = self.window() else {
+            return;
+        };
+        let Some(taskbar) = self.taskbar() else {
+            return;
+        };
+
+        // SAFETY: `taskbar` is a live COM interface created on this thread and
+        // `hwnd` belongs to this process.
+        unsafe {
+            match percent {
+                Some(percent) => {
+                    let _ = taskbar.SetProgressState(hwnd, TBPF_NORMAL);
+                    let _ = taskbar.SetProgressValue(hwnd, u64::from(percent), PROGRESS_TOTAL);
+                }
+                None => {
+                    let _ = taskbar.SetProgressState(hwnd, TBPF_NOPROGRESS);
+                }
+            }
+        }
+    }
+
+    /// Get (or create) the taskbar interface
+    fn taskbar(&mut self) -> Option<&ITaskbarList3> {
+        if self.taskbar.is_none() {
+            // SAFETY: standard COM initialization; an already-initialized
+            // apartment (e.g. by winit for drag and drop) is not an error here.
+            let taskbar: ITaskbarList3 = unsafe {
+                let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
+                CoCreateInstance(&TaskbarList, None, CLSCTX_INPROC_SERVER).ok()?
+            };
+            // SAFETY: `HrInit` must be called once before using the interface.
+            unsafe { taskbar.HrInit() }.ok()?;
+            self.taskbar = Some(taskbar);
+        }
+        self.taskbar.as_ref()
+    }
+
+    /// Get (or look up) the main window handle
+    fn window(&mut self) -> Option<HWND> {
+        if self.hwnd.is_none() {
+            self.hwnd = find_main_window();
+        }
+        self.hwnd
+    }
+}
+
+/// Find this process's main window by its title
+fn find_main_window() -> Option<HWND> {
+    let title = HSTRING::from(WINDOW_TITLE);
+    // SAFETY: both arguments are valid null-terminated wide strings (or null).
+    let hwnd = unsafe { FindWindowW(PCWSTR::null(), &title) }.ok()?;
+
+    let mut pid = 0u32;
+    // SAFETY: `hwnd` was just returned by FindWindowW and `pid` is a valid out pointer.
+    unsafe { GetWindowThreadProcessId(hwnd, Some(&mut pid)) };
+    (pid == std::process::id()).then_some(hwnd)
+}
@@ -0,0 +1,211 @@
+//! Auto-download rules
+//!
+//! A bookmark can name remote folders whose new files are downloaded
+//! automatically while connected, each with a file name pattern such as
+//! `*.mp3`. The folders are listed whenever the server reports a change in
+//! one (and every quarter hour as a fallback), and matching files are
+//! looked up with `FileStat` for their SHA-256. A file is only queued when
+//! the download history (see [`DownloadHistory`](super::DownloadHistory))
+//! doesn't have its content yet, so it's fetched once even if it's renamed,
+//! moved, or offered by another server.
+
+use std::collections::HashMap;
+
+use nexus_common::protocol::{FileEntry, FileStatQuery};
+use nexus_common::validators::MAX_FILE_STAT_COUNT;
+
+use super::join_remote_path;
+use crate::types::AutoDownloadRule;
+
+/// A remote file that matched a rule and hasn't been handled yet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AutoDownloadCandidate {
+    /// Path on the server
+    pub path: String,
+    /// File size in bytes
+    pub size: u64,
+    /// Last modified time as Unix timestamp
+    pub modified: i64,
+}
+
+/// Per-connection state for the bookmark's auto-download rules
+#[derive(Debug, Clone, Default)]
+pub struct AutoDownloadState {
+    /// Files already queued or skipped, by root flag and path, with the size
+    /// and modification time they had at the time
+    handled: HashMap<(bool, String), (u64, i64)>,
+}
+
+impl AutoDownloadState {
+    /// Files in a rule's folder listing that match it and haven't been
+    /// handled in their current state, at most one `FileStat` request's worth
+    ///
+    /// A file that changes after it was handled is considered afresh; the
+    /// download history decides whether its new content is fetched.
+    pub fn candidates(
+        &self,
+        rule: &AutoDownloadRule,
+        entries: &[FileEntry],
+    ) -> Vec<AutoDownloadCandidate> {
+        entries
+            .iter()
+            .filter(|entry| entry.dir_type.is_none() && rule.matches(&entry.name))
+            .map(|entry| AutoDownloadCandidate {
+                path: join_remote_path(&rule.remote_path, &entry.name),
+                size: entry.size,
+                modified: entry.modified,
+            })
+            .filter(|candidate| !self.is_handled(rule.remote_root, candidate))
+            .take(MAX_FILE_STAT_COUNT)
+            .collect()
+    }
+
+    /// Whether a file was already handled in this state
+    pub fn is_handled(&self, root: bool, candidate: &AutoDownloadCandidate) -> bool {
+        self.handled.get(&(root, candidate.path.clone()))
+            == Some(&(candidate.size, candidate.modified))
+    }
+
+    /// Remember that a file was queued or skipped
+    pub fn mark_handled(&mut self, root: bool, candidate: &AutoDownloadCandidate) {
+        self.handled.insert(
+            (root, candidate.path.clone()),
+            (candidate.size, candidate.modified),
+        );
+    }
+}
+
+/// `FileStat` queries for candidates, asking for the hash of each at its
+/// listed size
+pub fn stat_queries(candidates: &[AutoDownloadCandidate]) -> Vec<FileStatQuery> {
+    candidates
+        .iter()
+        .map(|candidate| FileStatQuery {
+            path: candidate.path.clone(),
+            size: candidate.size,
+        })
+        .collect()
+}
+
+/// Whether a file name matches any of a comma-separated list of patterns
+///
+/// `*` matches any run of characters and `?` any single character. Matching
+/// ignores case, so `*.mp3` also matches `EPISODE.MP3`.
+pub fn matches_pattern(patterns: &str, name: &str) -> bool {
+    let name: Vec<char> = name.to_lowercase().chars().collect();
+    patterns
+        .split(',')
+        .map(str::trim)
+        .filter(|pattern| !pattern.is_empty())
+        .any(|pattern| {
+            let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+            wildcard_match(&pattern, &name)
+        })
+}
+
+/// Match a name against one wildcard pattern
+///
+/// Backtracks to the most recent `*` on a mismatch, which is enough since a
+/// later `*` can always absorb what an earlier one would have.
+fn wildcard_match(pattern: &[char], name: &[char]) -> bool {
+    let (mut p, mut n) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match star {
+                Some((star_p, star_n)) => {
+                    p = star_p + 1;
+                    n = star_n + 1;
+                    star = Some((star_p, star_n + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file(name: &str, size: u64) -> FileEntry {
+        FileEntry {
+            name: name.to_string(),
+            size,
+            modified: 100,
+            dir_type: None,
+            can_upload: false,
+        }
+    }
+
+    #[test]
+    fn test_matches_pattern() {
+        assert!(matches_pattern("*.mp3", "episode-12.mp3"));
+        assert!(matches_pattern("*.mp3", "EPISODE.MP3"));
+        assert!(matches_pattern("*.m4a, *.mp3", "episode.mp3"));
+        assert!(matches_pattern("episode-??.mp3", "episode-12.mp3"));
+        assert!(matches_pattern("*", "anything"));
+        assert!(matches_pattern("*-*-final*", "show-2024-final.mp3"));
+
+        assert!(!matches_pattern("*.mp3", "episode.mp3.part"));
+        assert!(!matches_pattern("episode-??.mp3", "episode-123.mp3"));
+        assert!(!matches_pattern("", "episode.mp3"));
+        assert!(!matches_pattern(" , ", "episode.mp3"));
+    }
+
+    #[test]
+    fn test_candidates_skip_folders_and_handled_files() {
+        let rule = AutoDownloadRule {
+            remote_path: "/podcasts".to_string(),
+            remote_root: false,
+            pattern: "*.mp3".to_string(),
+        };
+        let mut folder = file("old.mp3", 0);
+        folder.dir_type = Some("default".to_string());
+        let entries = vec![
+            file("one.mp3", 10),
+            file("two.mp3", 20),
+            file("notes.txt", 5),
+            folder,
+        ];
+
+        let mut state = AutoDownloadState::default();
+        let candidates = state.candidates(&rule, &entries);
+        assert_eq!(
+            candidates
+                .iter()
+                .map(|c| c.path.as_str())
+                .collect::<Vec<_>>(),
+            vec!["/podcasts/one.mp3", "/podcasts/two.mp3"]
+        );
+
+        state.mark_handled(false, &candidates[0]);
+        assert_eq!(state.candidates(&rule, &entries).len(), 1);
+
+        // Handled under the file root is a different file
+        let root_rule = AutoDownloadRule {
+            remote_root: true,
+            ..rule.clone()
+        };
+        assert_eq!(state.candidates(&root_rule, &entries).len(), 2);
+
+        // A file that changes is considered again
+        let entries = vec![file("one.mp3", 15)];
+        assert_eq!(state.candidates(&rule, &entries).len(), 1);
+    }
+}
@@ -0,0 +1,238 @@
+//! Duplicate download detection
+//!
+//! Before a single file is downloaded, the client looks for a local copy of
+//! it: a file of the same size in the download folder, or a file it
+//! downloaded before from any server. Candidates are hashed and compared with
+//! the SHA-256 the server reports through `FileStat`; when one matches, the
+//! user is asked whether to download the file anyway.
+//!
+//! Downloaded files are remembered in `download_history.json`, by SHA-256
+//! with their local path and size, up to [`MAX_DOWNLOAD_HISTORY`] entries.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+use nexus_common::hash::compute_sha256_sync;
+use serde::{Deserialize, Serialize};
+
+use crate::constants::{APP_DIR_NAME, DOWNLOAD_HISTORY_FILE_NAME, MAX_DOWNLOAD_HISTORY};
+
+/// A file downloaded earlier
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DownloadRecord {
+    /// Where the file was saved
+    pub path: PathBuf,
+    /// File size in bytes
+    pub size: u64,
+    /// When the download finished (Unix timestamp)
+    pub downloaded_at: i64,
+}
+
+/// Persistent download history file structure
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct DownloadHistoryFile {
+    /// Downloaded files by SHA-256
+    downloaded: HashMap<String, DownloadRecord>,
+}
+
+/// Files downloaded earlier, shared by all connections
+#[derive(Debug, Default)]
+pub struct DownloadHistory {
+    /// Downloaded files by SHA-256
+    downloaded: HashMap<String, DownloadRecord>,
+
+    /// Whether there are unsaved changes
+    dirty: bool,
+}
+
+impl DownloadHistory {
+    /// Get the platform-specific download history file path
+    ///
+    /// Returns None if the config directory cannot be determined.
+    pub fn history_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join(APP_DIR_NAME).join(DOWNLOAD_HISTORY_FILE_NAME))
+    }
+
+    /// Load the download history from disk, or return an empty history
+    pub fn load() -> Self {
+        if let Some(path) = Self::history_path()
+            && let Ok(contents) = fs::read_to_string(&path)
+            && let Ok(file) = serde_json::from_str::<DownloadHistoryFile>(&contents)
+        {
+            return Self {
+                downloaded: file.downloaded,
+                dirty: false,
+            };
+        }
+
+        Self::default()
+    }
+
+    /// Save the download history to disk if it has unsaved changes
+    pub fn save(&mut self) -> io::Result<()> {
+        if !self.dirty {
+            return Ok(());
+        }
+
+        let path = Self::history_path()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no config directory"))?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let file = DownloadHistoryFile {
+            downloaded: self.downloaded.clone(),
+        };
+        let json = serde_json::to_string_pretty(&file).map_err(io::Error::other)?;
+        fs::write(&path, json)?;
+
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Remember a downloaded file, forgetting the oldest once the history is full
+    pub fn insert(&mut self, sha256: String, path: PathBuf, size: u64) {
+        self.downloaded.insert(
+            sha256,
+            DownloadRecord {
+                path,
+                size,
+                downloaded_at: Utc::now().timestamp(),
+            },
+        );
+        while self.downloaded.len() > MAX_DOWNLOAD_HISTORY {
+            let Some(oldest) = self
+                .downloaded
+                .iter()
+                .min_by_key(|(_, record)| record.downloaded_at)
+                .map(|(sha256, _)| sha256.clone())
+            else {
+                break;
+            };
+            self.downloaded.remove(&oldest);
+        }
+        self.dirty = true;
+    }
+
+    /// Whether content with this hash was downloaded before
+    pub fn contains(&self, sha256: &str) -> bool {
+        self.downloaded.contains_key(&sha256.to_ascii_lowercase())
+    }
+
+    /// Paths of earlier downloads with this size
+    pub fn paths_with_size(&self, size: u64) -> Vec<PathBuf> {
+        self.downloaded
+            .values()
+            .filter(|record| record.size == size)
+            .map(|record| record.path.clone())
+            .collect()
+    }
+}
+
+/// Hash the local files that could be copies of a remote file (blocking I/O
+/// runs on a worker thread)
+///
+/// Candidates are the files in `download_dir` (not its subfolders) and the
+/// `earlier` downloads that still exist with exactly `size` bytes, except
+/// `destination`: a download into an identical file is already skipped by
+/// the transfer itself.
+pub async fn hash_local_copies(
+    download_dir: PathBuf,
+    earlier: Vec<PathBuf>,
+    destination: PathBuf,
+    size: u64,
+) -> Vec<(PathBuf, String)> {
+    tokio::task::spawn_blocking(move || {
+        let mut candidates: Vec<PathBuf> = fs::read_dir(&download_dir)
+            .map(|entries| {
+                entries
+                    .filter_map(|entry| entry.ok())
+                    .map(|entry| entry.path())
+                    .collect()
+            })
+            .unwrap_or_default();
+        for path in earlier {
+            if !candidates.contains(&path) {
+                candidates.push(path);
+            }
+        }
+
+        candidates
+            .into_iter()
+            .filter(|path| *path != destination && has_size(path, size))
+            .filter_map(|path| {
+                let sha256 = compute_sha256_sync(&path).ok()?;
+                Some((path, sha256))
+            })
+            .collect()
+    })
+    .await
+    .unwrap_or_default()
+}
+
+/// Whether `path` is a regular file of exactly `size` bytes
+fn has_size(path: &Path, size: u64) -> bool {
+    fs::metadata(path).is_ok_and(|metadata| metadata.is_file() && metadata.len() == size)
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_forgets_oldest_when_full() {
+        let mut history = DownloadHistory::default();
+        for i in 0..MAX_DOWNLOAD_HISTORY {
+            history.downloaded.insert(
+                format!("hash{i}"),
+                DownloadRecord {
+                    path: PathBuf::from(format!("file{i}")),
+                    size: 10,
+                    downloaded_at: i as i64,
+                },
+            );
+        }
+
+        history.insert("new".to_string(), PathBuf::from("new.bin"), 20);
+
+        assert_eq!(history.downloaded.len(), MAX_DOWNLOAD_HISTORY);
+        assert!(!history.downloaded.contains_key("hash0"));
+        assert!(history.contains("new"));
+        assert_eq!(history.paths_with_size(20), vec![PathBuf::from("new.bin")]);
+    }
+
+    #[tokio::test]
+    async fn test_hash_local_copies() {
+        let download_dir = tempfile::tempdir().unwrap();
+        let elsewhere = tempfile::tempdir().unwrap();
+        let copy = download_dir.path().join("renamed.iso");
+        let destination = download_dir.path().join("disk.iso");
+        let earlier = elsewhere.path().join("disk.iso");
+        fs::write(&copy, b"12345").unwrap();
+        fs::write(&destination, b"12345").unwrap();
+        fs::write(download_dir.path().join("other.txt"), b"123").unwrap();
+        fs::write(&earlier, b"54321").unwrap();
+
+        let copies = hash_local_copies(
+            download_dir.path().to_path_buf(),
+            vec![earlier.clone(), elsewhere.path().join("deleted.iso")],
+            destination,
+            5,
+        )
+        .await;
+
+        // Same size only, without the destination or missing earlier downloads
+        let paths: Vec<&PathBuf> = copies.iter().map(|(path, _)| path).collect();
+        assert_eq!(paths.len(), 2);
+        assert!(paths.contains(&&copy));
+        assert!(paths.contains(&&earlier));
+        assert_ne!(copies[0].1, copies[1].1);
+    }
+}
@@ -74,11 +74,30 @@ where
 
 /// Connect to transfer port, verify certificate, and authenticate
 ///
+/// Logs in with the session's login token if there is one, so the password
+/// isn't sent again. If the server no longer accepts the token (its session
+/// ended a while ago), logs in with the password instead.
+///
 /// Returns boxed trait objects for the reader/writer to support both direct
 /// and proxied connections with different underlying stream types.
 pub async fn connect_and_authenticate(
     conn_info: &ConnectionInfo,
     proxy: Option<ProxyConfig>,
+) -> Result<(FrameReader<BufReader<BoxedRead>>, FrameWriter<BoxedWrite>), TransferError> {
+    if let Some(token) = &conn_info.login_token {
+        match connect_and_login(conn_info, proxy.clone(), Some(token.clone())).await {
+            Err(TransferError::AuthenticationFailed) => {}
+            result => return result,
+        }
+    }
+    connect_and_login(conn_info, proxy, None).await
+}
+
+/// Connect to transfer port, verify certificate, and log in with the token or password
+async fn connect_and_login(
+    conn_info: &ConnectionInfo,
+    proxy: Option<ProxyConfig>,
+    login_token: Option<String>,
 ) -> Result<(FrameReader<BufReader<BoxedRead>>, FrameWriter<BoxedWrite>), TransferError> {
     let target_addr = &conn_info.address;
     let target_port = conn_info.transfer_port;
@@ -157,6 +176,7 @@ pub async fn connect_and_authenticate(
     // Perform handshake
     let handshake = ClientMessage::Handshake {
         version: PROTOCOL_VERSION.to_string(),
+        client: None,
     };
     send_client_message(&mut writer, &handshake)
         .await
@@ -174,10 +194,14 @@ pub async fn connect_and_authenticate(
         }
     }
 
-    // Perform login
+    // Perform login (a login token stands in for the password)
     let login = ClientMessage::Login {
         username: conn_info.username.clone(),
-        password: conn_info.password.clone(),
+        password: if login_token.is_some() {
+            String::new()
+        } else {
+            conn_info.password.clone()
+        },
         features: vec![],
         locale: String::new(),
         avatar: None,
@@ -186,6 +210,9 @@ pub async fn connect_and_authenticate(
         } else {
             Some(conn_info.nickname.clone())
         },
+        pow_nonce: None,
+        oidc: None,
+        login_token,
     };
     send_client_message(&mut writer, &login)
         .await
@@ -81,7 +81,21 @@ where
         return Ok((size, None));
     }
 
-    // Check for .part file
+    check_part_file_with_keepalive(part_path, writer, cancel_flag).await
+}
+
+/// Check for an existing .part file only, ignoring any complete file
+///
+/// Used when the complete file is going to be overwritten, so only a previous
+/// partial download of the replacement may be resumed.
+pub async fn check_part_file_with_keepalive<W>(
+    part_path: &Path,
+    writer: &mut FrameWriter<W>,
+    cancel_flag: &Option<Arc<AtomicBool>>,
+) -> Result<(u64, Option<String>), TransferError>
+where
+    W: tokio::io::AsyncWrite + Unpin,
+{
     if let Ok(metadata) = tokio::fs::metadata(part_path).await
         && metadata.is_file()
     {
@@ -24,8 +24,9 @@ mod connection;
 mod file_utils;
 mod streaming;
 
+use std::collections::HashSet;
 use std::io::SeekFrom;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 use std::time::Duration;
@@ -40,15 +41,16 @@ use nexus_common::framing::FrameError;
 use nexus_common::io::send_client_message;
 use nexus_common::protocol::{ClientMessage, ServerMessage};
 
-use super::types::{Transfer, TransferDirection, TransferError};
+use super::types::{CollisionAction, CollisionPolicy, Transfer, TransferDirection, TransferError};
 use crate::i18n::t;
 use crate::network::ProxyConfig;
 
 use connection::connect_and_authenticate;
+pub(super) use file_utils::scan_local_files;
 use file_utils::{
-    check_local_file_with_keepalive, compute_file_sha256, compute_file_sha256_with_keepalive,
-    compute_partial_sha256_with_keepalive, generate_unique_path, is_cancelled, is_safe_path,
-    open_file_for_upload, scan_local_files,
+    check_local_file_with_keepalive, check_part_file_with_keepalive, compute_file_sha256,
+    compute_file_sha256_with_keepalive, compute_partial_sha256_with_keepalive,
+    generate_unique_path, is_cancelled, is_safe_path, open_file_for_upload,
 };
 use streaming::{
     StreamError, read_message_with_timeout, stream_file_to_server,
@@ -84,6 +86,9 @@ pub enum TransferEvent {
     /// Transfer started connecting
     Connecting { id: Uuid },
 
+    /// Transfer is waiting for a free slot on the server (received TransferQueued)
+    ServerQueued { id: Uuid, position: u32 },
+
     /// Transfer started (received FileDownloadResponse)
     Started {
         id: Uuid,
@@ -104,6 +109,13 @@ pub enum TransferEvent {
     #[allow(dead_code)]
     FileCompleted { id: Uuid, path: String },
 
+    /// A downloaded file is complete on disk (remembered for duplicate detection)
+    FileDownloaded {
+        local_path: PathBuf,
+        size: u64,
+        sha256: String,
+    },
+
     /// Transfer completed successfully
     Completed { id: Uuid },
 
@@ -116,6 +128,18 @@ pub enum TransferEvent {
 
     /// Transfer was paused (not yet implemented)
     Paused { id: Uuid },
+
+    /// A destination file already existed and was handled by the collision policy
+    Collision {
+        id: Uuid,
+        path: String,
+        action: CollisionAction,
+    },
+
+    /// A destination file already exists and the user must decide what to do
+    ///
+    /// The executor stops; the transfer is resumed once a policy is chosen.
+    CollisionPending { id: Uuid, path: String },
 }
 
 // =============================================================================
@@ -155,6 +179,81 @@ fn handle_possible_cancellation(
     }
 }
 
+// =============================================================================
+// Collision Helpers
+// =============================================================================
+
+/// Helper to report a collision handled by the transfer's collision policy
+fn send_collision_event(
+    event_tx: &mpsc::UnboundedSender<TransferEvent>,
+    id: Uuid,
+    path: &str,
+    action: CollisionAction,
+) {
+    let _ = event_tx.send(TransferEvent::Collision {
+        id,
+        path: path.to_string(),
+        action,
+    });
+}
+
+/// Check whether a download would collide with an existing local file
+///
+/// Returns true if a complete (non-.part) file exists at `path` whose content
+/// differs from the server's file. An identical file is not a collision; it is
+/// skipped by the normal resume logic. If the existing file can't be hashed,
+/// it is treated as identical when the sizes match so resume logic decides.
+async fn destination_collides(path: &Path, server_size: u64, server_sha256: &str) -> bool {
+    let Ok(metadata) = tokio::fs::metadata(path).await else {
+        return false;
+    };
+    if !metadata.is_file() {
+        return false;
+    }
+    if metadata.len() != server_size {
+        return true;
+    }
+    if server_size == 0 {
+        return false;
+    }
+    match compute_file_sha256(path).await {
+        Ok(existing_hash) => existing_hash != server_sha256,
+        Err(_) => false,
+    }
+}
+
+// =============================================================================
+// Queue Helpers
+// =============================================================================
+
+/// Read the server's response to a transfer request
+///
+/// While the server's active transfer limit is reached it sends `TransferQueued`
+/// messages instead of the response. Each one is forwarded to the UI as a
+/// `ServerQueued` event and resets the idle timeout. Cancellation is checked
+/// between queue updates and reported as `TransferError::Cancelled`.
+async fn read_response_while_queued<R>(
+    reader: &mut nexus_common::framing::FrameReader<tokio::io::BufReader<R>>,
+    id: Uuid,
+    event_tx: &mpsc::UnboundedSender<TransferEvent>,
+    cancel_flag: &Option<Arc<AtomicBool>>,
+) -> Result<ServerMessage, TransferError>
+where
+    R: tokio::io::AsyncRead + Unpin,
+{
+    loop {
+        match read_message_with_timeout(reader, IDLE_TIMEOUT).await? {
+            ServerMessage::TransferQueued { position } => {
+                let _ = event_tx.send(TransferEvent::ServerQueued { id, position });
+                if is_cancelled(cancel_flag) {
+                    return Err(TransferError::Cancelled);
+                }
+            }
+            other => return Ok(other),
+        }
+    }
+}
+
 // =============================================================================
 // Executor
 // =============================================================================
@@ -233,13 +332,17 @@ where
     let download_request = ClientMessage::FileDownload {
         path: transfer.remote_path.clone(),
         root: transfer.remote_root,
+        include: transfer.include.clone(),
     };
     send_client_message(writer, &download_request)
         .await
         .map_err(|_| TransferError::ConnectionError)?;
 
-    // Read FileDownloadResponse
-    let response = read_message_with_timeout(reader, IDLE_TIMEOUT).await?;
+    // Read FileDownloadResponse (the server may queue us first)
+    let response = match read_response_while_queued(reader, id, event_tx, cancel_flag).await {
+        Ok(response) => response,
+        Err(e) => return Err(handle_possible_cancellation(event_tx, id, e)),
+    };
 
     let (total_bytes, file_count, server_transfer_id) = match response {
         ServerMessage::FileDownloadResponse {
@@ -342,35 +445,59 @@ where
         };
 
         // Check if a COMPLETE file exists at the destination with DIFFERENT content.
-        // This is separate from resume logic - we only auto-rename if:
-        // 1. A complete file (not .part) exists at the destination
-        // 2. Its size matches the server's file size (so it's a complete file)
-        // 3. Its hash differs from the server's hash (different content)
-        //
-        // If a .part file exists, that's a partial download - we'll resume it.
-        // If a complete file exists with the SAME hash, we skip the download.
-        let local_file_path = if let Ok(metadata) = tokio::fs::metadata(&local_file_path).await
-            && metadata.is_file()
-            && metadata.len() == file_size
-            && file_size > 0
-        {
-            // Complete file exists - check if it's the same content
-            if let Ok(existing_hash) = compute_file_sha256(&local_file_path).await {
-                if existing_hash != file_sha256 {
-                    // Different file with same size - auto-rename to avoid overwriting
-                    match generate_unique_path(&local_file_path).await {
-                        Ok(path) => path,
-                        Err(_) => {
-                            return Err(send_failed_event(event_tx, id, TransferError::IoError));
-                        }
+        // This is separate from resume logic - a .part file is a partial download
+        // and will be resumed, and a complete file with the SAME content is skipped
+        // by the "already complete" check below. Anything else is a collision,
+        // handled according to the transfer's collision policy.
+        let collides = destination_collides(&local_file_path, file_size, &file_sha256).await;
+
+        let mut overwrite = false;
+        let local_file_path = if collides {
+            match transfer.collision_policy {
+                CollisionPolicy::Rename => match generate_unique_path(&local_file_path).await {
+                    Ok(path) => {
+                        send_collision_event(event_tx, id, &file_path, CollisionAction::Renamed);
+                        path
                     }
-                } else {
-                    // Same file - will be skipped by the "already complete" check below
+                    Err(_) => {
+                        return Err(send_failed_event(event_tx, id, TransferError::IoError));
+                    }
+                },
+                CollisionPolicy::Overwrite => {
+                    send_collision_event(event_tx, id, &file_path, CollisionAction::Overwritten);
+                    overwrite = true;
                     local_file_path
                 }
-            } else {
-                // Couldn't hash existing file - just use original path
-                local_file_path
+                CollisionPolicy::Skip => {
+                    send_collision_event(event_tx, id, &file_path, CollisionAction::Skipped);
+
+                    // Report the server's own size and hash so it skips FileData
+                    let skip_response = ClientMessage::FileStartResponse {
+                        size: file_size,
+                        sha256: Some(file_sha256),
+                    };
+                    send_client_message(writer, &skip_response)
+                        .await
+                        .map_err(|_| TransferError::ConnectionError)?;
+
+                    transferred_bytes += file_size;
+                    files_completed += 1;
+                    let _ = event_tx.send(TransferEvent::Progress {
+                        id,
+                        transferred_bytes,
+                        files_completed,
+                        current_file: None,
+                    });
+                    continue;
+                }
+                CollisionPolicy::Ask => {
+                    // Stop here; the transfer resumes once the user picks a policy
+                    let _ = event_tx.send(TransferEvent::CollisionPending {
+                        id,
+                        path: file_path,
+                    });
+                    return Ok(());
+                }
             }
         } else {
             local_file_path
@@ -380,14 +507,13 @@ where
         // Check for existing partial/complete file for resume
         // Use keepalive version to prevent server timeout during large file hashing
         // Pass cancel_flag to allow cancellation during large file hash computation
-        let (local_size, local_hash) = match check_local_file_with_keepalive(
-            &local_file_path,
-            &part_path,
-            writer,
-            cancel_flag,
-        )
-        .await
-        {
+        // When overwriting, the existing complete file is ignored (only .part counts)
+        let local_state = if overwrite {
+            check_part_file_with_keepalive(&part_path, writer, cancel_flag).await
+        } else {
+            check_local_file_with_keepalive(&local_file_path, &part_path, writer, cancel_flag).await
+        };
+        let (local_size, local_hash) = match local_state {
             Ok(result) => result,
             Err(e) => {
                 return Err(handle_possible_cancellation(event_tx, id, e));
@@ -409,6 +535,11 @@ where
             transferred_bytes += file_size;
             files_completed += 1;
 
+            let _ = event_tx.send(TransferEvent::FileDownloaded {
+                local_path: local_file_path.clone(),
+                size: file_size,
+                sha256: file_sha256.clone(),
+            });
             let _ = event_tx.send(TransferEvent::FileCompleted {
                 id,
                 path: file_path.clone(),
@@ -557,6 +688,12 @@ where
             tokio::fs::rename(&part_path, &local_file_path)
                 .await
                 .map_err(|_| TransferError::IoError)?;
+
+            let _ = event_tx.send(TransferEvent::FileDownloaded {
+                local_path: local_file_path.clone(),
+                size: file_size,
+                sha256: file_sha256.clone(),
+            });
         } else {
             // 0-byte file - just create it
             if let Some(parent) = local_file_path.parent() {
@@ -616,7 +753,13 @@ where
     let id = transfer.id;
 
     // Scan local files (just paths and sizes - SHA-256 computed lazily per file)
-    let files = scan_local_files(&transfer.local_path, transfer.is_directory).await?;
+    let mut files = scan_local_files(&transfer.local_path, transfer.is_directory).await?;
+
+    // Only send the chosen files (e.g. skipping ones the server already has)
+    if let Some(include) = &transfer.include {
+        let include: HashSet<&str> = include.iter().map(String::as_str).collect();
+        files.retain(|f| include.contains(f.relative_path.as_str()));
+    }
 
     if files.is_empty() {
         // Nothing to upload - this is an error (server rejects empty uploads)
@@ -632,13 +775,17 @@ where
         file_count,
         total_size,
         root: transfer.remote_root,
+        overwrite: transfer.overwrite,
     };
     send_client_message(writer, &upload_request)
         .await
         .map_err(|_| TransferError::ConnectionError)?;
 
-    // Read FileUploadResponse
-    let response = read_message_with_timeout(reader, IDLE_TIMEOUT).await?;
+    // Read FileUploadResponse (the server may queue us first)
+    let response = match read_response_while_queued(reader, id, event_tx, cancel_flag).await {
+        Ok(response) => response,
+        Err(e) => return Err(handle_possible_cancellation(event_tx, id, e)),
+    };
 
     let server_transfer_id = match response {
         ServerMessage::FileUploadResponse {
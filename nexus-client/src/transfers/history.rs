@@ -0,0 +1,274 @@
+//! Transfer history
+//!
+//! Finished transfers are remembered in `transfer_history.json`, newest first
+//! and up to [`MAX_TRANSFER_HISTORY`] of them, so they can still be found
+//! after they're cleared from the transfer queue. A transfer that fails and
+//! is later retried keeps a single record with its latest outcome.
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::types::{Transfer, TransferDirection, TransferStatus};
+use crate::constants::{APP_DIR_NAME, MAX_TRANSFER_HISTORY, TRANSFER_HISTORY_FILE_NAME};
+
+/// A finished transfer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransferRecord {
+    /// ID of the transfer this record is for
+    pub id: Uuid,
+    /// Download or upload
+    pub direction: TransferDirection,
+    /// File or folder name
+    pub name: String,
+    /// Path on the server
+    pub remote_path: String,
+    /// Name of the server
+    pub server_name: String,
+    /// Bytes transferred
+    pub size: u64,
+    /// Where the file was saved to or uploaded from
+    pub local_path: PathBuf,
+    /// When the transfer finished (Unix timestamp)
+    pub finished_at: i64,
+    /// Completed or Failed
+    pub status: TransferStatus,
+    /// Error message, for failed transfers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl TransferRecord {
+    /// Create a record for a transfer that just finished
+    pub fn from_transfer(transfer: &Transfer) -> Self {
+        Self {
+            id: transfer.id,
+            direction: transfer.direction,
+            name: transfer.display_name(),
+            remote_path: transfer.remote_path.clone(),
+            server_name: transfer.connection_info.server_name.clone(),
+            size: transfer.transferred_bytes.max(transfer.total_bytes),
+            local_path: transfer.local_path.clone(),
+            finished_at: Utc::now().timestamp(),
+            status: transfer.status,
+            error: transfer.error.clone(),
+        }
+    }
+
+    /// Whether the record matches a search (case-insensitive, on the name,
+    /// paths and server)
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        let local_path = self.local_path.to_string_lossy();
+        query.is_empty()
+            || [
+                self.name.as_str(),
+                self.remote_path.as_str(),
+                self.server_name.as_str(),
+                local_path.as_ref(),
+            ]
+            .iter()
+            .any(|field| field.to_lowercase().contains(&query))
+    }
+}
+
+/// Persistent transfer history file structure
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct TransferHistoryFile {
+    /// Finished transfers, newest first
+    records: Vec<TransferRecord>,
+}
+
+/// Finished transfers, shared by all connections
+#[derive(Debug, Default)]
+pub struct TransferHistory {
+    /// Finished transfers, newest first
+    records: Vec<TransferRecord>,
+
+    /// Whether there are unsaved changes
+    dirty: bool,
+}
+
+impl TransferHistory {
+    /// Get the platform-specific transfer history file path
+    ///
+    /// Returns None if the config directory cannot be determined.
+    pub fn history_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join(APP_DIR_NAME).join(TRANSFER_HISTORY_FILE_NAME))
+    }
+
+    /// Load the transfer history from disk, or return an empty history
+    pub fn load() -> Self {
+        if let Some(path) = Self::history_path()
+            && let Ok(contents) = fs::read_to_string(&path)
+            && let Ok(file) = serde_json::from_str::<TransferHistoryFile>(&contents)
+        {
+            return Self {
+                records: file.records,
+                dirty: false,
+            };
+        }
+
+        Self::default()
+    }
+
+    /// Save the transfer history to disk if it has unsaved changes
+    pub fn save(&mut self) -> io::Result<()> {
+        if !self.dirty {
+            return Ok(());
+        }
+
+        let path = Self::history_path()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no config directory"))?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let file = TransferHistoryFile {
+            records: self.records.clone(),
+        };
+        let json = serde_json::to_string_pretty(&file).map_err(io::Error::other)?;
+        fs::write(&path, json)?;
+
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Record a finished transfer, replacing an earlier record for it
+    pub fn record(&mut self, record: TransferRecord) {
+        self.records.retain(|r| r.id != record.id);
+        self.records.insert(0, record);
+        self.records.truncate(MAX_TRANSFER_HISTORY);
+        self.dirty = true;
+    }
+
+    /// Get a record by transfer ID
+    pub fn get(&self, id: Uuid) -> Option<&TransferRecord> {
+        self.records.iter().find(|r| r.id == id)
+    }
+
+    /// Records matching a search, newest first
+    pub fn search<'a>(&'a self, query: &'a str) -> impl Iterator<Item = &'a TransferRecord> {
+        self.records.iter().filter(move |r| r.matches(query))
+    }
+
+    /// Whether the history is empty
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Forget a single record
+    pub fn remove(&mut self, id: Uuid) {
+        let before = self.records.len();
+        self.records.retain(|r| r.id != id);
+        self.dirty |= self.records.len() != before;
+    }
+
+    /// Forget every record
+    pub fn clear(&mut self) {
+        self.dirty |= !self.records.is_empty();
+        self.records.clear();
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(id: Uuid, name: &str, status: TransferStatus) -> TransferRecord {
+        TransferRecord {
+            id,
+            direction: TransferDirection::Download,
+            name: name.to_string(),
+            remote_path: format!("/Music/{name}"),
+            server_name: "The Lag".to_string(),
+            size: 1024,
+            local_path: PathBuf::from(format!("/downloads/{name}")),
+            finished_at: 0,
+            status,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_record_replaces_retried_transfer() {
+        let mut history = TransferHistory::default();
+        let retried = Uuid::new_v4();
+        history.record(record(retried, "song.mp3", TransferStatus::Failed));
+        history.record(record(
+            Uuid::new_v4(),
+            "album.zip",
+            TransferStatus::Completed,
+        ));
+        history.record(record(retried, "song.mp3", TransferStatus::Completed));
+
+        let names: Vec<&str> = history.search("").map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["song.mp3", "album.zip"]);
+        assert_eq!(
+            history.get(retried).map(|r| r.status),
+            Some(TransferStatus::Completed)
+        );
+    }
+
+    #[test]
+    fn test_record_forgets_oldest_when_full() {
+        let mut history = TransferHistory::default();
+        let oldest = Uuid::new_v4();
+        history.record(record(oldest, "first.bin", TransferStatus::Completed));
+        for i in 0..MAX_TRANSFER_HISTORY {
+            history.record(record(
+                Uuid::new_v4(),
+                &format!("{i}.bin"),
+                TransferStatus::Completed,
+            ));
+        }
+
+        assert_eq!(history.search("").count(), MAX_TRANSFER_HISTORY);
+        assert!(history.get(oldest).is_none());
+    }
+
+    #[test]
+    fn test_search() {
+        let mut history = TransferHistory::default();
+        history.record(record(
+            Uuid::new_v4(),
+            "Song.mp3",
+            TransferStatus::Completed,
+        ));
+        history.record(record(Uuid::new_v4(), "notes.txt", TransferStatus::Failed));
+
+        assert_eq!(history.search("song").count(), 1);
+        assert_eq!(history.search("  MUSIC ").count(), 2);
+        assert_eq!(history.search("the lag").count(), 2);
+        assert_eq!(history.search("/downloads/notes").count(), 1);
+        assert_eq!(history.search("video").count(), 0);
+    }
+
+    #[test]
+    fn test_remove_and_clear() {
+        let mut history = TransferHistory::default();
+        let id = Uuid::new_v4();
+        history.record(record(id, "song.mp3", TransferStatus::Completed));
+        history.record(record(
+            Uuid::new_v4(),
+            "album.zip",
+            TransferStatus::Completed,
+        ));
+        history.dirty = false;
+
+        history.remove(id);
+        assert!(history.dirty);
+        assert!(history.get(id).is_none());
+
+        history.clear();
+        assert!(history.is_empty());
+    }
+}
@@ -8,13 +8,31 @@
 //! - `Transfer` - A single file or directory transfer
 //! - `TransferManager` - Manages all transfers and persistence
 //! - `TransferEvent` - Progress events from the executor
+//! - `UploadHistory` - Hashes of files auto-uploaded from watched folders
+//! - `DownloadHistory` - Files downloaded earlier, for duplicate detection
+//! - `TransferHistory` - Finished transfers, kept after they leave the queue
+//! - `AutoDownloadState` - Files seen in a bookmark's auto-download folders
 
+mod auto_download;
+mod download_history;
 mod executor;
+mod history;
 mod persistence;
 mod subscription;
 mod types;
+mod upload_preview;
+mod watch;
 
+pub use auto_download::{AutoDownloadCandidate, AutoDownloadState, matches_pattern, stat_queries};
+pub use download_history::{DownloadHistory, hash_local_copies};
 pub use executor::TransferEvent;
+pub use history::{TransferHistory, TransferRecord};
 pub use persistence::TransferManager;
 pub use subscription::{request_cancel, transfer_subscription, update_registry_fingerprint};
-pub use types::{Transfer, TransferDirection, TransferStatus};
+pub use types::{
+    CollisionAction, CollisionPolicy, Transfer, TransferDirection, TransferStatus, TransferSummary,
+};
+pub use upload_preview::{
+    UploadFile, UploadItem, UploadScan, hash_upload_files, join_remote_path, scan_uploads,
+};
+pub use watch::{UploadHistory, UploadWatchState, WatchScan, scan_watch_folder};
@@ -11,7 +11,9 @@ use std::path::PathBuf;
 
 use uuid::Uuid;
 
-use super::types::{Transfer, TransferError, TransferStatus};
+use super::types::{
+    CollisionAction, CollisionPolicy, Transfer, TransferError, TransferStatus, TransferSummary,
+};
 use crate::constants::{APP_DIR_NAME, TRANSFERS_FILE_NAME};
 use crate::i18n::{t, t_args};
 
@@ -247,6 +249,7 @@ impl TransferManager {
     pub fn set_connecting(&mut self, id: Uuid) -> bool {
         if let Some(transfer) = self.transfers.get_mut(&id) {
             transfer.status = TransferStatus::Connecting;
+            transfer.server_queue_position = None;
             transfer.start();
             self.dirty = true;
             true
@@ -255,6 +258,20 @@ impl TransferManager {
         }
     }
 
+    /// Record the transfer's position in the server's queue
+    ///
+    /// Only applies while connecting. The position is not persisted, so the
+    /// dirty flag is left untouched.
+    pub fn set_server_queued(&mut self, id: Uuid, position: u32) -> bool {
+        match self.transfers.get_mut(&id) {
+            Some(transfer) if transfer.status == TransferStatus::Connecting => {
+                transfer.server_queue_position = Some(position);
+                true
+            }
+            _ => false,
+        }
+    }
+
     /// Update transfer status to transferring and set metadata from server
     pub fn set_transferring(
         &mut self,
@@ -265,6 +282,7 @@ impl TransferManager {
     ) -> bool {
         if let Some(transfer) = self.transfers.get_mut(&id) {
             transfer.status = TransferStatus::Transferring;
+            transfer.server_queue_position = None;
             transfer.total_bytes = total_bytes;
             transfer.file_count = file_count;
             transfer.server_transfer_id = server_transfer_id;
@@ -308,6 +326,39 @@ impl TransferManager {
         }
     }
 
+    /// Pause a transfer until the user resolves a collision on `path`
+    pub fn await_collision(&mut self, id: Uuid, path: String) -> bool {
+        if let Some(transfer) = self.transfers.get_mut(&id) {
+            transfer.await_collision(path);
+            self.dirty = true;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Record a collision handled by the executor
+    pub fn record_collision(&mut self, id: Uuid, path: String, action: CollisionAction) -> bool {
+        if let Some(transfer) = self.transfers.get_mut(&id) {
+            transfer.record_collision(path, action);
+            self.dirty = true;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Override the collision policy for a single transfer
+    pub fn set_collision_policy(&mut self, id: Uuid, policy: CollisionPolicy) -> bool {
+        if let Some(transfer) = self.transfers.get_mut(&id) {
+            transfer.collision_policy = policy;
+            self.dirty = true;
+            true
+        } else {
+            false
+        }
+    }
+
     /// Queue a transfer for resume
     ///
     /// Assigns the transfer to the end of the queue (highest queue_position + 1).
@@ -440,6 +491,25 @@ impl TransferManager {
         }
     }
 
+    /// Aggregate statistics for queued and active transfers
+    pub fn summary(&self) -> TransferSummary {
+        let mut summary = TransferSummary {
+            count: self.queued().count(),
+            ..Default::default()
+        };
+        for transfer in self.active() {
+            summary.count += 1;
+            if transfer.total_bytes > 0 {
+                summary.transferred_bytes += transfer.transferred_bytes;
+                summary.total_bytes += transfer.total_bytes;
+            }
+            if let Some(speed) = transfer.bytes_per_second() {
+                summary.bytes_per_second += speed;
+            }
+        }
+        summary
+    }
+
     // =========================================================================
     // Test-only helpers
     // =========================================================================
@@ -521,9 +591,11 @@ mod tests {
             port: 7500,
             transfer_port: 7501,
             certificate_fingerprint: "AA:BB:CC:DD".to_string(),
+            identity_key: None,
             username: "alice".to_string(),
             password: "secret".to_string(),
             nickname: String::new(),
+            login_token: None,
         }
     }
 
@@ -619,10 +691,16 @@ mod tests {
         assert!(manager.set_connecting(id));
         assert_eq!(manager.get(id).unwrap().status, TransferStatus::Connecting);
 
+        // Waiting in the server's queue
+        assert!(manager.set_server_queued(id, 2));
+        assert_eq!(manager.get(id).unwrap().server_queue_position, Some(2));
+
         // Connecting -> Transferring
         assert!(manager.set_transferring(id, 1000, 3, Some("abc123".to_string())));
         let t = manager.get(id).unwrap();
         assert_eq!(t.status, TransferStatus::Transferring);
+        assert_eq!(t.server_queue_position, None);
+        assert!(!manager.set_server_queued(id, 1));
         assert_eq!(t.total_bytes, 1000);
         assert_eq!(t.file_count, 3);
         assert_eq!(t.server_transfer_id, Some("abc123".to_string()));
@@ -957,6 +1035,27 @@ mod tests {
         assert!(!manager.move_down(fake_id));
     }
 
+    #[test]
+    fn test_summary() {
+        let mut manager = TransferManager::new();
+        manager.add(test_transfer());
+
+        let active = test_transfer();
+        let active_id = manager.add(active);
+        manager.set_transferring(active_id, 1000, 1, None);
+        manager.update_progress(active_id, 250, 0, None);
+
+        let done = test_transfer();
+        let done_id = manager.add(done);
+        manager.complete(done_id);
+
+        let summary = manager.summary();
+        assert_eq!(summary.count, 2);
+        assert_eq!(summary.transferred_bytes, 250);
+        assert_eq!(summary.total_bytes, 1000);
+        assert_eq!(summary.progress(), Some(0.25));
+    }
+
     #[test]
     fn test_update_fingerprint_for_bookmark() {
         let mut manager = TransferManager::new();
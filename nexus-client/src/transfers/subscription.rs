@@ -217,6 +217,7 @@ pub fn transfer_stream(
                     TransferEvent::Completed { .. }
                         | TransferEvent::Failed { .. }
                         | TransferEvent::Paused { .. }
+                        | TransferEvent::CollisionPending { .. }
                 );
 
                 // Send event to UI
@@ -299,9 +300,11 @@ mod tests {
             port: 7500,
             transfer_port: 7501,
             certificate_fingerprint: "AA:BB:CC".to_string(),
+            identity_key: None,
             username: "testuser".to_string(),
             password: "testpass".to_string(),
             nickname: String::new(),
+            login_token: None,
         };
 
         Transfer::new_download(
@@ -8,6 +8,7 @@ use std::path::PathBuf;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+pub use crate::config::settings::CollisionPolicy;
 pub use crate::types::ConnectionInfo;
 
 // =============================================================================
@@ -146,6 +147,42 @@ impl TransferError {
     }
 }
 
+// =============================================================================
+// Collisions
+// =============================================================================
+
+/// Action taken when a download's destination already existed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CollisionAction {
+    /// Saved under a new, unique name
+    Renamed,
+    /// Replaced the existing file
+    Overwritten,
+    /// Kept the existing file and skipped the download
+    Skipped,
+}
+
+impl CollisionAction {
+    /// Get the translation key for this action
+    pub fn translation_key(&self) -> &'static str {
+        match self {
+            CollisionAction::Renamed => "collision-action-renamed",
+            CollisionAction::Overwritten => "collision-action-overwritten",
+            CollisionAction::Skipped => "collision-action-skipped",
+        }
+    }
+}
+
+/// A single collision handled during a transfer (shown in the transfer log)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CollisionRecord {
+    /// Relative path of the file that collided
+    pub path: String,
+    /// What was done about it
+    pub action: CollisionAction,
+}
+
 // =============================================================================
 // Transfer
 // =============================================================================
@@ -187,6 +224,15 @@ pub struct Transfer {
     #[serde(default)]
     pub is_directory: bool,
 
+    /// For directory transfers, the subset of files to transfer (relative to
+    /// the directory). None transfers everything.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include: Option<Vec<String>>,
+
+    /// For uploads, replace files on the server that have different content
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub overwrite: bool,
+
     /// Local file or directory path
     pub local_path: PathBuf,
 
@@ -235,6 +281,27 @@ pub struct Transfer {
     /// Position in the queue (lower = higher priority, executed first)
     #[serde(default)]
     pub queue_position: u32,
+
+    /// Position in the server's transfer queue while waiting for a free slot
+    ///
+    /// Only meaningful while connecting; not persisted.
+    #[serde(skip)]
+    pub server_queue_position: Option<u32>,
+
+    /// What to do when a downloaded file already exists locally
+    ///
+    /// Copied from the global setting when the transfer is created, unless
+    /// overridden for this transfer.
+    #[serde(default)]
+    pub collision_policy: CollisionPolicy,
+
+    /// Collisions handled so far (the transfer log)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub collisions: Vec<CollisionRecord>,
+
+    /// File waiting for a collision decision (policy is Ask)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pending_collision: Option<String>,
 }
 
 impl Transfer {
@@ -256,6 +323,8 @@ impl Transfer {
             remote_path,
             remote_root,
             is_directory,
+            include: None,
+            overwrite: false,
             local_path,
             total_bytes: 0,
             transferred_bytes: 0,
@@ -270,6 +339,10 @@ impl Transfer {
             completed_at: None,
             current_file: None,
             queue_position,
+            server_queue_position: None,
+            collision_policy: CollisionPolicy::default(),
+            collisions: Vec::new(),
+            pending_collision: None,
         }
     }
 
@@ -291,6 +364,8 @@ impl Transfer {
             remote_path,
             remote_root,
             is_directory,
+            include: None,
+            overwrite: false,
             local_path,
             total_bytes: 0,
             transferred_bytes: 0,
@@ -305,6 +380,10 @@ impl Transfer {
             completed_at: None,
             current_file: None,
             queue_position,
+            server_queue_position: None,
+            collision_policy: CollisionPolicy::default(),
+            collisions: Vec::new(),
+            pending_collision: None,
         }
     }
 
@@ -378,12 +457,36 @@ impl Transfer {
             self.status = TransferStatus::Queued;
             self.error = None;
             self.error_kind = None;
+            self.pending_collision = None;
             // Reset timestamps for the new attempt
             self.started_at = None;
             self.completed_at = None;
         }
     }
 
+    /// Pause the transfer until the user decides how to handle a collision
+    pub fn await_collision(&mut self, path: String) {
+        self.pause();
+        self.pending_collision = Some(path);
+    }
+
+    /// Record how a collision was handled
+    ///
+    /// Replaces any earlier record for the same path so resumed transfers
+    /// don't log the same file twice.
+    pub fn record_collision(&mut self, path: String, action: CollisionAction) {
+        self.collisions.retain(|record| record.path != path);
+        self.collisions.push(CollisionRecord { path, action });
+    }
+
+    /// Count handled collisions with the given action
+    pub fn collision_count(&self, action: CollisionAction) -> usize {
+        self.collisions
+            .iter()
+            .filter(|record| record.action == action)
+            .count()
+    }
+
     /// Mark the transfer as started (called when connection established)
     pub fn start(&mut self) {
         self.started_at = Some(chrono::Utc::now().timestamp());
@@ -409,6 +512,44 @@ impl Transfer {
     }
 }
 
+// =============================================================================
+// Transfer Summary
+// =============================================================================
+
+/// Aggregate statistics across all queued and active transfers
+///
+/// Drives the compact status bar widget and the OS taskbar/dock progress.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TransferSummary {
+    /// Number of transfers that are queued, connecting, or transferring
+    pub count: usize,
+    /// Bytes transferred so far across active transfers with a known size
+    pub transferred_bytes: u64,
+    /// Total size of active transfers with a known size
+    pub total_bytes: u64,
+    /// Combined speed of all active transfers in bytes per second
+    pub bytes_per_second: f64,
+}
+
+impl TransferSummary {
+    /// Overall progress as a fraction (0.0 to 1.0), if any size is known yet
+    pub fn progress(&self) -> Option<f64> {
+        if self.total_bytes == 0 {
+            return None;
+        }
+        Some((self.transferred_bytes as f64 / self.total_bytes as f64).min(1.0))
+    }
+
+    /// Estimated seconds until the known remaining bytes finish
+    pub fn eta_seconds(&self, min_speed: f64) -> Option<i64> {
+        if self.total_bytes == 0 || self.bytes_per_second < min_speed {
+            return None;
+        }
+        let remaining = self.total_bytes.saturating_sub(self.transferred_bytes);
+        Some((remaining as f64 / self.bytes_per_second) as i64)
+    }
+}
+
 // =============================================================================
 // Tests
 // =============================================================================
@@ -424,9 +565,11 @@ mod tests {
             port: 7500,
             transfer_port: 7501,
             certificate_fingerprint: "AA:BB:CC".to_string(),
+            identity_key: None,
             username: "alice".to_string(),
             password: "secret".to_string(),
             nickname: String::new(),
+            login_token: None,
         }
     }
 
@@ -767,6 +910,40 @@ mod tests {
         assert!(transfer.completed_at.is_none());
     }
 
+    #[test]
+    fn test_transfer_collisions() {
+        let conn = test_connection_info();
+        let mut transfer = Transfer::new_download(
+            conn,
+            "/Music".to_string(),
+            false,
+            true,
+            PathBuf::from("/tmp/Music"),
+            None,
+            0,
+        );
+        assert_eq!(transfer.collision_policy, CollisionPolicy::Rename);
+
+        // Waiting for a decision pauses the transfer
+        transfer.status = TransferStatus::Transferring;
+        transfer.await_collision("a.mp3".to_string());
+        assert_eq!(transfer.status, TransferStatus::Paused);
+        assert_eq!(transfer.pending_collision.as_deref(), Some("a.mp3"));
+
+        // Re-queueing clears the pending decision
+        transfer.queue();
+        assert!(transfer.pending_collision.is_none());
+
+        // Recording the same path twice keeps only the latest action
+        transfer.record_collision("a.mp3".to_string(), CollisionAction::Renamed);
+        transfer.record_collision("b.mp3".to_string(), CollisionAction::Skipped);
+        transfer.record_collision("a.mp3".to_string(), CollisionAction::Overwritten);
+        assert_eq!(transfer.collisions.len(), 2);
+        assert_eq!(transfer.collision_count(CollisionAction::Renamed), 0);
+        assert_eq!(transfer.collision_count(CollisionAction::Overwritten), 1);
+        assert_eq!(transfer.collision_count(CollisionAction::Skipped), 1);
+    }
+
     #[test]
     fn test_transfer_elapsed_seconds() {
         let conn = test_connection_info();
@@ -815,4 +992,21 @@ mod tests {
         let speed = transfer.bytes_per_second().unwrap();
         assert!((speed - 1000.0).abs() < 0.01);
     }
+
+    #[test]
+    fn test_transfer_summary_progress_and_eta() {
+        let empty = TransferSummary::default();
+        assert_eq!(empty.progress(), None);
+        assert_eq!(empty.eta_seconds(1.0), None);
+
+        let summary = TransferSummary {
+            count: 1,
+            transferred_bytes: 500,
+            total_bytes: 2000,
+            bytes_per_second: 100.0,
+        };
+        assert_eq!(summary.progress(), Some(0.25));
+        assert_eq!(summary.eta_seconds(1.0), Some(15));
+        assert_eq!(summary.eta_seconds(500.0), None);
+    }
 }
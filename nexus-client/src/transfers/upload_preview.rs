@@ -0,0 +1,161 @@
+//! Upload preview scanning
+//!
+//! Before uploads are queued, the selected files and folders are scanned so
+//! their destinations can be looked up on the server with `FileStat`. Files
+//! the server already has are then skipped, and files that exist there with
+//! different content can be skipped or overwritten up front instead of
+//! failing partway through the transfer.
+
+use std::path::PathBuf;
+
+use nexus_common::hash::compute_sha256;
+use nexus_common::validators::MAX_FILE_STAT_COUNT;
+
+use super::executor::scan_local_files;
+
+/// A selected file or folder, uploaded as one transfer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UploadItem {
+    /// Local file or folder
+    pub local_path: PathBuf,
+    /// Remote directory the transfer uploads into (ends with the folder's
+    /// name for folders, so the server recreates it)
+    pub remote_path: String,
+    /// Whether the local path is a folder
+    pub is_directory: bool,
+}
+
+impl UploadItem {
+    /// Build the item for a local path uploaded into `destination`
+    pub fn new(destination: &str, local_path: PathBuf) -> Self {
+        let is_directory = local_path.is_dir();
+        let remote_path = match local_path.file_name().and_then(|n| n.to_str()) {
+            Some(dir_name) if is_directory => join_remote_path(destination, dir_name),
+            _ => destination.to_string(),
+        };
+        Self {
+            local_path,
+            remote_path,
+            is_directory,
+        }
+    }
+}
+
+/// A local file that would be uploaded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UploadFile {
+    /// Index of the item the file belongs to
+    pub item: usize,
+    /// Path relative to the item's remote path (as sent by the transfer)
+    pub relative_path: String,
+    /// Full remote path of the file (as looked up with `FileStat`)
+    pub remote_path: String,
+    /// Local path of the file
+    pub local_path: PathBuf,
+    /// File size in bytes
+    pub size: u64,
+}
+
+/// Result of scanning the selected files and folders
+#[derive(Debug, Clone)]
+pub struct UploadScan {
+    /// Selected files and folders, in selection order
+    pub items: Vec<UploadItem>,
+    /// Every file to upload, or None when they can't all be checked (too
+    /// many files, or a folder that couldn't be read)
+    pub files: Option<Vec<UploadFile>>,
+}
+
+/// Join a remote directory and a name
+pub fn join_remote_path(dir: &str, name: &str) -> String {
+    if dir.is_empty() || dir == "/" {
+        format!("/{name}")
+    } else {
+        format!("{}/{}", dir.trim_end_matches('/'), name)
+    }
+}
+
+/// Scan the files the given items would upload
+///
+/// Stops early once there are more files than a single `FileStat` request
+/// can check; those uploads are queued without a preview.
+pub async fn scan_uploads(items: Vec<UploadItem>) -> UploadScan {
+    let mut files = Vec::new();
+
+    for (index, item) in items.iter().enumerate() {
+        let Ok(scanned) = scan_local_files(&item.local_path, item.is_directory).await else {
+            return UploadScan { items, files: None };
+        };
+        files.extend(scanned.into_iter().map(|file| UploadFile {
+            item: index,
+            remote_path: join_remote_path(&item.remote_path, &file.relative_path),
+            relative_path: file.relative_path,
+            local_path: file.absolute_path,
+            size: file.size,
+        }));
+        if files.len() > MAX_FILE_STAT_COUNT {
+            return UploadScan { items, files: None };
+        }
+    }
+
+    UploadScan {
+        items,
+        files: Some(files),
+    }
+}
+
+/// Hash local files to compare them with the server's copies
+///
+/// Takes (file index, path) pairs and returns each index with its SHA-256,
+/// or None if the file couldn't be read.
+pub async fn hash_upload_files(files: Vec<(usize, PathBuf)>) -> Vec<(usize, Option<String>)> {
+    let mut hashes = Vec::with_capacity(files.len());
+    for (index, path) in files {
+        hashes.push((index, compute_sha256(&path).await.ok()));
+    }
+    hashes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_join_remote_path() {
+        assert_eq!(join_remote_path("", "a.txt"), "/a.txt");
+        assert_eq!(join_remote_path("/", "a.txt"), "/a.txt");
+        assert_eq!(join_remote_path("/Uploads/", "Music"), "/Uploads/Music");
+        assert_eq!(
+            join_remote_path("/Uploads/Music", "live/set.mp3"),
+            "/Uploads/Music/live/set.mp3"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_scan_uploads() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let album = temp_dir.path().join("Album");
+        std::fs::create_dir_all(album.join("Disc 2")).unwrap();
+        std::fs::write(album.join("Disc 2/track.mp3"), b"12345").unwrap();
+        let cover = temp_dir.path().join("cover.jpg");
+        std::fs::write(&cover, b"123").unwrap();
+
+        let items = vec![
+            UploadItem::new("/Uploads", album),
+            UploadItem::new("/Uploads", cover.clone()),
+        ];
+        assert_eq!(items[0].remote_path, "/Uploads/Album");
+        assert_eq!(items[1].remote_path, "/Uploads");
+
+        let scan = scan_uploads(items).await;
+        let files = scan.files.unwrap();
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].item, 0);
+        assert_eq!(files[0].relative_path, "Disc 2/track.mp3");
+        assert_eq!(files[0].remote_path, "/Uploads/Album/Disc 2/track.mp3");
+        assert_eq!(files[1].item, 1);
+        assert_eq!(files[1].remote_path, "/Uploads/cover.jpg");
+        assert_eq!(files[1].local_path, cover);
+        assert_eq!(files[1].size, 3);
+    }
+}
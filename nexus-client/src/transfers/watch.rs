@@ -0,0 +1,326 @@
+//! Upload folder watching
+//!
+//! A bookmark can name a local drop folder whose new files are uploaded to a
+//! remote directory automatically while connected. The folder is polled rather
+//! than watched through OS notifications: a file is picked up once its size and
+//! modification time are unchanged between two scans, so files that are still
+//! being written (by a scanner or an audio export, say) are never sent half-done.
+//!
+//! Uploaded content is remembered by SHA-256 per bookmark in `upload_watch.json`,
+//! so a file whose content was already uploaded is not sent again, even if it is
+//! renamed, copied back in, or the client is restarted.
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use nexus_common::hash::compute_sha256;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::constants::{APP_DIR_NAME, UPLOAD_WATCH_FILE_NAME};
+
+/// Size and modification time of a file when it was scanned
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileStamp {
+    /// File size in bytes
+    pub size: u64,
+    /// Last modification time, if the platform reports one
+    pub modified: Option<SystemTime>,
+}
+
+/// Per-connection scan state for a watched folder
+#[derive(Debug, Clone, Default)]
+pub struct UploadWatchState {
+    /// Files found by the previous scan
+    pub seen: HashMap<PathBuf, FileStamp>,
+    /// Files already uploaded or skipped, with the stamp they had at the time
+    pub handled: HashMap<PathBuf, FileStamp>,
+    /// Whether a scan is currently running
+    pub scanning: bool,
+}
+
+impl UploadWatchState {
+    /// Record a finished scan
+    ///
+    /// Files that disappeared from the folder are forgotten, so a file that is
+    /// removed and later dropped in again is considered afresh.
+    pub fn apply_scan(&mut self, scan: &WatchScan) {
+        self.scanning = false;
+        self.seen = scan.stamps.clone();
+        self.handled
+            .retain(|path, _| scan.stamps.contains_key(path));
+    }
+}
+
+/// A watched file that is ready to upload
+#[derive(Debug, Clone)]
+pub struct WatchedFile {
+    /// Local path of the file
+    pub path: PathBuf,
+    /// Stamp the file had when it was hashed
+    pub stamp: FileStamp,
+    /// SHA-256 of the file contents
+    pub sha256: String,
+}
+
+/// Result of scanning a watched folder
+#[derive(Debug, Clone, Default)]
+pub struct WatchScan {
+    /// Folder that was scanned
+    pub folder: PathBuf,
+    /// Every regular file in the folder with its current stamp
+    pub stamps: HashMap<PathBuf, FileStamp>,
+    /// Files that are stable and not yet handled, sorted by path
+    pub ready: Vec<WatchedFile>,
+}
+
+/// Whether a file has settled since the previous scan and still needs handling
+fn is_ready(
+    path: &Path,
+    stamp: FileStamp,
+    seen: &HashMap<PathBuf, FileStamp>,
+    handled: &HashMap<PathBuf, FileStamp>,
+) -> bool {
+    seen.get(path) == Some(&stamp) && handled.get(path) != Some(&stamp)
+}
+
+/// Scan a watched folder for files that are ready to upload
+///
+/// Only regular, non-hidden files directly inside `folder` are considered;
+/// subdirectories are ignored. An unreadable folder yields an empty scan.
+pub async fn scan_watch_folder(
+    folder: PathBuf,
+    seen: HashMap<PathBuf, FileStamp>,
+    handled: HashMap<PathBuf, FileStamp>,
+) -> WatchScan {
+    let mut scan = WatchScan {
+        folder: folder.clone(),
+        ..Default::default()
+    };
+
+    let Ok(mut entries) = tokio::fs::read_dir(&folder).await else {
+        return scan;
+    };
+
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        if entry.file_name().to_string_lossy().starts_with('.') {
+            continue;
+        }
+        let path = entry.path();
+        let Ok(metadata) = tokio::fs::metadata(&path).await else {
+            continue;
+        };
+        if !metadata.is_file() {
+            continue;
+        }
+        let stamp = FileStamp {
+            size: metadata.len(),
+            modified: metadata.modified().ok(),
+        };
+        scan.stamps.insert(path, stamp);
+    }
+
+    for (path, stamp) in &scan.stamps {
+        if !is_ready(path, *stamp, &seen, &handled) {
+            continue;
+        }
+        if let Ok(sha256) = compute_sha256(path).await {
+            scan.ready.push(WatchedFile {
+                path: path.clone(),
+                stamp: *stamp,
+                sha256,
+            });
+        }
+    }
+    scan.ready.sort_by(|a, b| a.path.cmp(&b.path));
+
+    scan
+}
+
+/// Persistent upload history file structure
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct UploadWatchFile {
+    /// SHA-256 hashes of uploaded files, per bookmark
+    uploaded: HashMap<Uuid, HashSet<String>>,
+}
+
+/// Hashes of files already uploaded from watched folders
+///
+/// Stored next to `config.json` and shared by all connections.
+#[derive(Debug, Default)]
+pub struct UploadHistory {
+    /// SHA-256 hashes of uploaded files, per bookmark
+    uploaded: HashMap<Uuid, HashSet<String>>,
+
+    /// Whether there are unsaved changes
+    dirty: bool,
+}
+
+impl UploadHistory {
+    /// Get the platform-specific upload history file path
+    ///
+    /// Returns None if the config directory cannot be determined.
+    pub fn history_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join(APP_DIR_NAME).join(UPLOAD_WATCH_FILE_NAME))
+    }
+
+    /// Load the upload history from disk, or return an empty history
+    pub fn load() -> Self {
+        if let Some(path) = Self::history_path()
+            && let Ok(contents) = fs::read_to_string(&path)
+            && let Ok(file) = serde_json::from_str::<UploadWatchFile>(&contents)
+        {
+            return Self {
+                uploaded: file.uploaded,
+                dirty: false,
+            };
+        }
+
+        Self::default()
+    }
+
+    /// Save the upload history to disk if it has unsaved changes
+    pub fn save(&mut self) -> io::Result<()> {
+        if !self.dirty {
+            return Ok(());
+        }
+
+        let path = Self::history_path()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no config directory"))?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let file = UploadWatchFile {
+            uploaded: self.uploaded.clone(),
+        };
+        let json = serde_json::to_string_pretty(&file).map_err(io::Error::other)?;
+        fs::write(&path, json)?;
+
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Whether content with this hash was already uploaded for a bookmark
+    pub fn contains(&self, bookmark_id: Uuid, sha256: &str) -> bool {
+        self.uploaded
+            .get(&bookmark_id)
+            .is_some_and(|hashes| hashes.contains(sha256))
+    }
+
+    /// Remember that content with this hash was uploaded for a bookmark
+    pub fn insert(&mut self, bookmark_id: Uuid, sha256: String) {
+        if self.uploaded.entry(bookmark_id).or_default().insert(sha256) {
+            self.dirty = true;
+        }
+    }
+
+    /// Forget everything uploaded for a bookmark (e.g. when it is deleted)
+    pub fn forget(&mut self, bookmark_id: Uuid) {
+        if self.uploaded.remove(&bookmark_id).is_some() {
+            self.dirty = true;
+        }
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history_is_per_bookmark() {
+        let mut history = UploadHistory::default();
+        let first = Uuid::new_v4();
+        let second = Uuid::new_v4();
+
+        history.insert(first, "abc".to_string());
+
+        assert!(history.contains(first, "abc"));
+        assert!(!history.contains(second, "abc"));
+        assert!(!history.contains(first, "def"));
+        assert!(history.dirty);
+    }
+
+    #[test]
+    fn test_history_forget() {
+        let mut history = UploadHistory::default();
+        let id = Uuid::new_v4();
+        history.insert(id, "abc".to_string());
+        history.dirty = false;
+
+        history.forget(id);
+
+        assert!(!history.contains(id, "abc"));
+        assert!(history.dirty);
+    }
+
+    #[tokio::test]
+    async fn test_scan_waits_for_file_to_settle() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("episode.mp3");
+        fs::write(&file, b"audio").unwrap();
+        fs::write(dir.path().join(".hidden"), b"x").unwrap();
+        fs::create_dir(dir.path().join("sub")).unwrap();
+
+        let mut state = UploadWatchState::default();
+
+        // First sighting: recorded, but not ready yet
+        let scan = scan_watch_folder(
+            dir.path().to_path_buf(),
+            state.seen.clone(),
+            state.handled.clone(),
+        )
+        .await;
+        assert_eq!(scan.stamps.len(), 1);
+        assert!(scan.ready.is_empty());
+        state.apply_scan(&scan);
+
+        // Unchanged since the last scan: ready
+        let scan = scan_watch_folder(
+            dir.path().to_path_buf(),
+            state.seen.clone(),
+            state.handled.clone(),
+        )
+        .await;
+        assert_eq!(scan.ready.len(), 1);
+        assert_eq!(scan.ready[0].path, file);
+        state.apply_scan(&scan);
+        state
+            .handled
+            .insert(scan.ready[0].path.clone(), scan.ready[0].stamp);
+
+        // Already handled: not offered again
+        let scan = scan_watch_folder(
+            dir.path().to_path_buf(),
+            state.seen.clone(),
+            state.handled.clone(),
+        )
+        .await;
+        assert!(scan.ready.is_empty());
+    }
+
+    #[test]
+    fn test_apply_scan_forgets_removed_files() {
+        let stamp = FileStamp {
+            size: 1,
+            modified: None,
+        };
+        let mut state = UploadWatchState {
+            scanning: true,
+            ..Default::default()
+        };
+        state.handled.insert(PathBuf::from("/drop/a.txt"), stamp);
+
+        state.apply_scan(&WatchScan::default());
+
+        assert!(!state.scanning);
+        assert!(state.handled.is_empty());
+    }
+}
@@ -17,7 +17,7 @@
 //!   window visibility. Right-click shows the menu.
 //! - **Windows**: Uses tray-icon (native system tray). Left-click toggles window
 //!   visibility. Right-click shows the menu.
-//! - **macOS**: Not supported - uses dock badges instead (separate feature).
+//! - **macOS**: Not supported - uses dock badges instead (see `crate::dock`).
 
 #![cfg(not(target_os = "macos"))]
 
@@ -27,9 +27,9 @@ mod linux;
 mod windows;
 
 #[cfg(target_os = "linux")]
-pub use linux::{TrayManager, tray_subscription};
+pub use self::linux::{TrayManager, tray_subscription};
 #[cfg(target_os = "windows")]
-pub use windows::{TrayManager, poll_tray_events, tray_subscription};
+pub use self::windows::{TrayManager, poll_tray_events, tray_subscription};
 
 use crate::i18n::{t, t_args};
 
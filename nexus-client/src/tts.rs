@@ -0,0 +1,71 @@
+//! Text-to-speech using the platform's speech synthesizer
+//!
+//! - **macOS**: `say`
+//! - **Windows**: PowerShell with `System.Speech`
+//! - **Linux**: `spd-say` (speech-dispatcher), falling back to `espeak`
+//!
+//! Speech is best-effort: if no synthesizer is installed the text is
+//! silently dropped. The text is never interpolated into a shell command.
+
+#[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
+use std::process::{Command, Stdio};
+
+/// Speak `text` aloud without blocking
+#[cfg(target_os = "macos")]
+pub fn speak(text: &str) {
+    let _ = quiet(Command::new("say").arg(as_operand(text))).spawn();
+}
+
+/// Speak `text` aloud without blocking
+#[cfg(target_os = "windows")]
+pub fn speak(text: &str) {
+    use std::os::windows::process::CommandExt;
+
+    /// Don't flash a console window for the PowerShell process
+    const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+    // Pass the text through the environment so it is never parsed as script
+    let _ = quiet(
+        Command::new("powershell")
+            .args([
+                "-NoProfile",
+                "-NonInteractive",
+                "-Command",
+                "Add-Type -AssemblyName System.Speech; \
+                 (New-Object System.Speech.Synthesis.SpeechSynthesizer).Speak($env:NEXUS_TTS_TEXT)",
+            ])
+            .env("NEXUS_TTS_TEXT", text)
+            .creation_flags(CREATE_NO_WINDOW),
+    )
+    .spawn();
+}
+
+/// Speak `text` aloud without blocking
+#[cfg(target_os = "linux")]
+pub fn speak(text: &str) {
+    let text = as_operand(text);
+    if quiet(Command::new("spd-say").arg(&text)).spawn().is_err() {
+        let _ = quiet(Command::new("espeak").arg(&text)).spawn();
+    }
+}
+
+/// Speech is not supported on this platform
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+pub fn speak(_text: &str) {}
+
+/// Keep text that starts with `-` from being parsed as an option
+///
+/// A leading space is not spoken, and not every synthesizer honours `--`.
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn as_operand(text: &str) -> String {
+    format!(" {text}")
+}
+
+/// Detach the synthesizer's standard streams from ours
+#[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
+fn quiet(command: &mut Command) -> &mut Command {
+    command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+}
@@ -1,9 +1,18 @@
 //! Server bookmark types
 
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
 use nexus_common::DEFAULT_PORT;
 use serde::{Deserialize, Deserializer, Serialize};
 use uuid::Uuid;
 
+use crate::config::credentials::PasswordStore;
+use crate::config::events::NotificationRules;
+use crate::image::CachedImage;
+use crate::transfers::matches_pattern;
+use crate::types::{ChatTab, FileSortColumn};
+
 /// Deserialize port from either a number or a string (for backward compatibility)
 fn deserialize_port<'de, D>(deserializer: D) -> Result<u16, D::Error>
 where
@@ -57,19 +66,229 @@ pub struct ServerBookmark {
     /// Server port number
     #[serde(deserialize_with = "deserialize_port")]
     pub port: u16,
+    /// Addresses to try in order when the server can't be reached (`host` or `host:port`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fallback_addresses: Vec<String>,
     /// Optional username for quick connect
     pub username: String,
     /// Optional password for quick connect
+    ///
+    /// Empty in the config file when the password is in the keychain or vault.
+    #[serde(default)]
     pub password: String,
-    /// Optional nickname for shared account logins
+    /// Where the password is stored when it isn't in the config file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password_store: Option<PasswordStore>,
+    /// Optional nickname for shared account logins (overrides the one in Settings)
     #[serde(default)]
     pub nickname: String,
+    /// Avatar for this server as a data URI (overrides the one in Settings)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<String>,
+    /// Status message set after logging in to this server
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub status: String,
     /// Whether to auto-connect on startup
     #[serde(default)]
     pub auto_connect: bool,
+    /// Whether to sign in through the server's identity provider
+    #[serde(default)]
+    pub use_sso: bool,
     /// Certificate fingerprint (SHA-256) for Trust On First Use
     #[serde(default)]
     pub certificate_fingerprint: Option<String>,
+    /// Server identity key, pinned alongside the certificate fingerprint
+    ///
+    /// Lets the server replace its certificate without a mismatch warning,
+    /// as long as the new certificate is signed by this key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identity_key: Option<String>,
+    /// When the pinned certificate was first trusted (Unix timestamp)
+    ///
+    /// None for fingerprints saved before this was recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub certificate_first_seen: Option<i64>,
+    /// Download folder for this server (absolute, or relative to the global download folder)
+    #[serde(default)]
+    pub download_folder: String,
+    /// Local folder whose new files are uploaded to this server automatically
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub upload_watch: Option<UploadWatch>,
+    /// Remote folders whose new files are downloaded automatically
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub auto_downloads: Vec<AutoDownloadRule>,
+    /// Remote folder shared screenshots are uploaded into
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub screenshot_folder: Option<ScreenshotFolder>,
+    /// Per-channel notification overrides and keyword watch list
+    #[serde(default, skip_serializing_if = "NotificationRules::is_empty")]
+    pub notification_rules: NotificationRules,
+    /// Tabs, Files panel location, and user list visibility from the last session
+    #[serde(default, skip_serializing_if = "BookmarkWorkspace::is_empty")]
+    pub workspace: BookmarkWorkspace,
+    /// Sidebar folder this bookmark is in (None for bookmarks outside any folder)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<Uuid>,
+    /// Usernames whose presence is shown in the Friends section
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub friends: Vec<String>,
+}
+
+/// Folder of bookmarks in the sidebar
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BookmarkGroup {
+    /// Unique identifier for this folder
+    #[serde(default = "Uuid::new_v4")]
+    pub id: Uuid,
+    /// Display name for the folder
+    pub name: String,
+    /// Whether the folder's bookmarks are hidden in the sidebar
+    #[serde(default)]
+    pub collapsed: bool,
+}
+
+/// Where a bookmark dragged in the sidebar is dropped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookmarkDropTarget {
+    /// Just above another bookmark, in that bookmark's folder
+    Before(Uuid),
+    /// At the end of a folder (None for bookmarks outside any folder)
+    End(Option<Uuid>),
+}
+
+/// Workspace left behind by the last connection through a bookmark
+///
+/// Saved when the connection closes and restored on the next connect.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BookmarkWorkspace {
+    /// Open channel tabs, in tab order
+    #[serde(default)]
+    pub channels: Vec<String>,
+    /// Chat tab that was active
+    #[serde(default)]
+    pub active_tab: Option<ChatTab>,
+    /// Directory the Files panel was showing
+    #[serde(default)]
+    pub files_path: String,
+    /// Whether `files_path` is relative to the server's file root
+    #[serde(default)]
+    pub files_root: bool,
+    /// Files panel sort column
+    #[serde(default)]
+    pub files_sort_column: FileSortColumn,
+    /// Files panel sort direction
+    #[serde(default = "default_true")]
+    pub files_sort_ascending: bool,
+    /// Whether the user list was shown (None follows the global setting)
+    #[serde(default)]
+    pub show_user_list: Option<bool>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for BookmarkWorkspace {
+    fn default() -> Self {
+        Self {
+            channels: Vec::new(),
+            active_tab: None,
+            files_path: String::new(),
+            files_root: false,
+            files_sort_column: FileSortColumn::default(),
+            files_sort_ascending: true,
+            show_user_list: None,
+        }
+    }
+}
+
+impl BookmarkWorkspace {
+    /// Whether there's nothing to restore
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Position of a channel in the saved tab order
+    fn channel_index(&self, channel: &str) -> Option<usize> {
+        self.channels
+            .iter()
+            .position(|saved| saved.eq_ignore_ascii_case(channel))
+    }
+
+    /// Put channel tabs back in their saved order
+    ///
+    /// Channels that weren't open last time go after the rest.
+    pub fn sort_channels(&self, channels: &mut [String]) {
+        channels.sort_by_key(|channel| self.channel_index(channel).unwrap_or(usize::MAX));
+    }
+
+    /// Where a rejoined channel's tab goes among the open channel tabs
+    pub fn channel_insert_position(&self, channels: &[String], channel: &str) -> usize {
+        let Some(index) = self.channel_index(channel) else {
+            return channels.len();
+        };
+        channels
+            .iter()
+            .position(|open| {
+                self.channel_index(open)
+                    .is_none_or(|open_index| open_index > index)
+            })
+            .unwrap_or(channels.len())
+    }
+}
+
+/// Remote folder that shared screenshots are uploaded into
+///
+/// Each user's screenshots go into a subfolder named after their nickname.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScreenshotFolder {
+    /// Remote directory (must allow uploads)
+    pub remote_path: String,
+    /// Whether `remote_path` is relative to the server's file root
+    #[serde(default)]
+    pub remote_root: bool,
+}
+
+/// Auto-upload drop folder for a bookmark
+///
+/// New files appearing in `local_folder` are queued as uploads into
+/// `remote_path` while connected through the bookmark.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UploadWatch {
+    /// Absolute path of the local folder to watch
+    pub local_folder: String,
+    /// Remote directory the files are uploaded into
+    pub remote_path: String,
+    /// Whether `remote_path` is relative to the server's file root
+    #[serde(default)]
+    pub remote_root: bool,
+}
+
+/// Auto-download rule for a bookmark
+///
+/// New files in `remote_path` whose names match `pattern` are queued as
+/// downloads into the bookmark's download folder while connected.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AutoDownloadRule {
+    /// Remote directory to check for new files
+    pub remote_path: String,
+    /// Whether `remote_path` is relative to the server's file root
+    #[serde(default)]
+    pub remote_root: bool,
+    /// Comma-separated file name patterns (e.g. `*.mp3, *.m4a`)
+    pub pattern: String,
+}
+
+impl AutoDownloadRule {
+    /// Whether the rule is for this remote directory
+    pub fn is_for(&self, remote_path: &str, remote_root: bool) -> bool {
+        self.remote_path == remote_path && self.remote_root == remote_root
+    }
+
+    /// Whether a file name matches the rule's pattern
+    pub fn matches(&self, name: &str) -> bool {
+        matches_pattern(&self.pattern, name)
+    }
 }
 
 impl Default for ServerBookmark {
@@ -79,11 +298,26 @@ impl Default for ServerBookmark {
             name: String::new(),
             address: String::new(),
             port: DEFAULT_PORT,
+            fallback_addresses: Vec::new(),
             username: String::new(),
             password: String::new(),
+            password_store: None,
             nickname: String::new(),
+            avatar: None,
+            status: String::new(),
             auto_connect: false,
+            use_sso: false,
             certificate_fingerprint: None,
+            identity_key: None,
+            certificate_first_seen: None,
+            download_folder: String::new(),
+            upload_watch: None,
+            auto_downloads: Vec::new(),
+            screenshot_folder: None,
+            notification_rules: NotificationRules::default(),
+            workspace: BookmarkWorkspace::default(),
+            group: None,
+            friends: Vec::new(),
         }
     }
 }
@@ -95,15 +329,88 @@ impl std::fmt::Debug for ServerBookmark {
             .field("name", &self.name)
             .field("address", &self.address)
             .field("port", &self.port)
+            .field("fallback_addresses", &self.fallback_addresses)
             .field("username", &self.username)
             .field("password", &"[REDACTED]")
+            .field("password_store", &self.password_store)
             .field("nickname", &self.nickname)
+            .field("avatar", &self.avatar.as_ref().map(|_| "<data uri>"))
+            .field("status", &self.status)
             .field("auto_connect", &self.auto_connect)
+            .field("use_sso", &self.use_sso)
             .field("certificate_fingerprint", &self.certificate_fingerprint)
+            .field("identity_key", &self.identity_key)
+            .field("certificate_first_seen", &self.certificate_first_seen)
+            .field("download_folder", &self.download_folder)
+            .field("upload_watch", &self.upload_watch)
+            .field("auto_downloads", &self.auto_downloads)
+            .field("screenshot_folder", &self.screenshot_folder)
+            .field("notification_rules", &self.notification_rules)
+            .field("workspace", &self.workspace)
+            .field("group", &self.group)
+            .field("friends", &self.friends)
             .finish()
     }
 }
 
+impl ServerBookmark {
+    /// Resolve where downloads from this server should be saved
+    ///
+    /// An empty `download_folder` uses `default_dir` as-is, a relative one is
+    /// treated as a subfolder of `default_dir`, and an absolute one replaces it.
+    pub fn download_dir(&self, default_dir: &str) -> PathBuf {
+        let folder = self.download_folder.trim();
+        if folder.is_empty() {
+            PathBuf::from(default_dir)
+        } else {
+            Path::new(default_dir).join(folder)
+        }
+    }
+
+    /// Whether a username is on the friends list (case-insensitive)
+    pub fn is_friend(&self, username: &str) -> bool {
+        let username = username.to_lowercase();
+        self.friends.iter().any(|f| f.to_lowercase() == username)
+    }
+
+    /// Add a username to the friends list
+    ///
+    /// Returns false if it was already there (case-insensitive).
+    pub fn add_friend(&mut self, username: &str) -> bool {
+        if self.is_friend(username) {
+            return false;
+        }
+        self.friends.push(username.to_string());
+        true
+    }
+
+    /// Remove a username from the friends list (case-insensitive)
+    ///
+    /// Returns false if it wasn't there.
+    pub fn remove_friend(&mut self, username: &str) -> bool {
+        let username = username.to_lowercase();
+        let before = self.friends.len();
+        self.friends.retain(|f| f.to_lowercase() != username);
+        self.friends.len() != before
+    }
+
+    /// Pin a server certificate (and identity key), recording when it was first seen
+    pub fn trust_certificate(&mut self, fingerprint: &str, identity_key: Option<&str>) {
+        self.certificate_fingerprint = Some(fingerprint.to_string());
+        self.identity_key = identity_key.map(str::to_string);
+        self.certificate_first_seen = Some(Utc::now().timestamp());
+    }
+
+    /// Forget the pinned certificate
+    ///
+    /// The next connection treats the server as new again.
+    pub fn forget_certificate(&mut self) {
+        self.certificate_fingerprint = None;
+        self.identity_key = None;
+        self.certificate_first_seen = None;
+    }
+}
+
 /// State for bookmark editing dialog
 ///
 /// Wraps a ServerBookmark with an editing mode to track whether
@@ -114,6 +421,10 @@ pub struct BookmarkEditState {
     pub mode: BookmarkEditMode,
     /// The bookmark being edited
     pub bookmark: ServerBookmark,
+    /// Fallback addresses as typed (comma-separated, parsed on save)
+    pub fallback_addresses: String,
+    /// Decoded avatar override for the preview
+    pub cached_avatar: Option<CachedImage>,
     /// Error message for bookmark operations
     pub error: Option<String>,
 }
@@ -123,6 +434,8 @@ impl Default for BookmarkEditState {
         Self {
             mode: BookmarkEditMode::None,
             bookmark: ServerBookmark::default(),
+            fallback_addresses: String::new(),
+            cached_avatar: None,
             error: None,
         }
     }
@@ -138,3 +451,96 @@ pub enum BookmarkEditMode {
     /// Editing bookmark with this ID
     Edit(Uuid),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_download_dir_resolution() {
+        let mut bookmark = ServerBookmark::default();
+        assert_eq!(bookmark.download_dir("/dl"), PathBuf::from("/dl"));
+
+        bookmark.download_folder = "Server A".to_string();
+        assert_eq!(bookmark.download_dir("/dl"), PathBuf::from("/dl/Server A"));
+
+        bookmark.download_folder = "/mnt/media".to_string();
+        assert_eq!(bookmark.download_dir("/dl"), PathBuf::from("/mnt/media"));
+    }
+
+    #[test]
+    fn test_friends_list() {
+        let mut bookmark = ServerBookmark::default();
+        assert!(bookmark.add_friend("Alice"));
+        assert!(!bookmark.add_friend("alice"));
+        assert!(bookmark.add_friend("bob"));
+        assert_eq!(bookmark.friends, ["Alice", "bob"]);
+        assert!(bookmark.is_friend("ALICE"));
+
+        assert!(bookmark.remove_friend("alice"));
+        assert!(!bookmark.remove_friend("alice"));
+        assert_eq!(bookmark.friends, ["bob"]);
+    }
+
+    #[test]
+    fn test_workspace_channel_order() {
+        let workspace = BookmarkWorkspace {
+            channels: vec!["#b".to_string(), "#a".to_string(), "#c".to_string()],
+            ..Default::default()
+        };
+
+        // Auto-joined channels go back in saved order, new ones last
+        let mut channels = vec!["#new".to_string(), "#C".to_string(), "#b".to_string()];
+        workspace.sort_channels(&mut channels);
+        assert_eq!(channels, ["#b", "#C", "#new"]);
+
+        // Rejoined channels slot in between, ahead of channels that are new
+        assert_eq!(workspace.channel_insert_position(&channels, "#a"), 1);
+        assert_eq!(workspace.channel_insert_position(&channels, "#b"), 0);
+        assert_eq!(workspace.channel_insert_position(&channels, "#other"), 3);
+        assert_eq!(workspace.channel_insert_position(&[], "#a"), 0);
+    }
+
+    #[test]
+    fn test_identity_overrides_are_optional() {
+        let json = serde_json::to_string(&ServerBookmark::default()).unwrap();
+        assert!(!json.contains("avatar"));
+        assert!(!json.contains("status"));
+
+        let bookmark = ServerBookmark {
+            avatar: Some("data:image/png;base64,iVBORw0KGgo=".to_string()),
+            status: "lurking".to_string(),
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&bookmark).unwrap();
+        let restored: ServerBookmark = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.avatar, bookmark.avatar);
+        assert_eq!(restored.status, "lurking");
+    }
+
+    #[test]
+    fn test_trust_and_forget_certificate() {
+        let mut bookmark = ServerBookmark::default();
+        bookmark.trust_certificate("AA:BB", Some("key"));
+        assert_eq!(bookmark.certificate_fingerprint.as_deref(), Some("AA:BB"));
+        assert_eq!(bookmark.identity_key.as_deref(), Some("key"));
+        assert!(bookmark.certificate_first_seen.is_some());
+
+        bookmark.forget_certificate();
+        assert!(bookmark.certificate_fingerprint.is_none());
+        assert!(bookmark.identity_key.is_none());
+        assert!(bookmark.certificate_first_seen.is_none());
+    }
+
+    #[test]
+    fn test_empty_workspace_is_not_saved() {
+        let json = serde_json::to_string(&ServerBookmark::default()).unwrap();
+        assert!(!json.contains("workspace"));
+
+        let mut bookmark = ServerBookmark::default();
+        bookmark.workspace.active_tab = Some(ChatTab::Channel("#a".to_string()));
+        let json = serde_json::to_string(&bookmark).unwrap();
+        let restored: ServerBookmark = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.workspace, bookmark.workspace);
+    }
+}
@@ -1,4 +1,4 @@
-//! Channel state for multi-channel chat support
+//! Channel and group conversation state for multi-channel chat support
 
 use super::ChatMessage;
 
@@ -11,6 +11,18 @@ pub struct ChannelState {
     pub topic_set_by: Option<String>,
     /// Whether the channel is secret (hidden from /channels list for non-members)
     pub secret: bool,
+    /// Whether the topic is locked (only chat_topic_lock holders may change it)
+    pub topic_locked: bool,
+    /// Message shown when joining the channel (None if not set)
+    pub entry_message: Option<String>,
+    /// Channel rules (None if not set)
+    pub rules: Option<String>,
+    /// Language tag, e.g. "en" (None if not set)
+    pub language: Option<String>,
+    /// Maximum number of voice participants (None if unlimited)
+    pub voice_limit: Option<u32>,
+    /// Nickname of the user broadcasting to the channel's voice (None if not broadcasting)
+    pub voice_broadcaster: Option<String>,
     /// Nicknames of channel members (sorted alphabetically)
     pub members: Vec<String>,
     /// Chat history for this channel
@@ -29,6 +41,12 @@ impl ChannelState {
             topic,
             topic_set_by,
             secret,
+            topic_locked: false,
+            entry_message: None,
+            rules: None,
+            language: None,
+            voice_limit: None,
+            voice_broadcaster: None,
             members,
             messages: Vec::new(),
         }
@@ -71,6 +89,28 @@ impl ChannelState {
     }
 }
 
+/// State for a group conversation (a multi-user direct message)
+#[derive(Debug, Clone, Default)]
+pub struct GroupState {
+    /// Nicknames of the members, including ours (in join order)
+    pub members: Vec<String>,
+    /// Chat history for this conversation
+    pub messages: Vec<ChatMessage>,
+}
+
+impl GroupState {
+    /// Tab label: the other members' nicknames
+    pub fn label(&self, own_nickname: &str) -> String {
+        let own_lower = own_nickname.to_lowercase();
+        self.members
+            .iter()
+            .filter(|m| m.to_lowercase() != own_lower)
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -150,4 +190,14 @@ mod tests {
         assert!(channel.is_member("bob"));
         assert!(!channel.is_member("charlie"));
     }
+
+    #[test]
+    fn test_group_label() {
+        let group = GroupState {
+            members: vec!["alice".to_string(), "Bob".to_string(), "carol".to_string()],
+            messages: Vec::new(),
+        };
+
+        assert_eq!(group.label("bob"), "alice, carol");
+    }
 }
@@ -3,9 +3,14 @@
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
+use chrono::{DateTime, Local, Utc};
 use iced::widget::markdown;
 use nexus_common::framing::MessageId;
-use nexus_common::protocol::{ClientMessage, UserInfoDetailed};
+use nexus_common::protocol::{
+    ClientMessage, FileWatchPath, NatInfo, NewsImageLimits, PresenceInfo, ServerTime,
+    UserInfoDetailed,
+};
+use nexus_common::validators;
 use serde::{Deserialize, Serialize};
 use tokio::sync::{Mutex, mpsc};
 use uuid::Uuid;
@@ -13,11 +18,18 @@ use uuid::Uuid;
 use nexus_common::protocol::ChannelJoinInfo;
 
 use super::{
-    ActivePanel, ChannelState, ChatMessage, ChatTab, ConnectionMonitorState, DisconnectDialogState,
-    FilesManagementState, NewsManagementState, PasswordChangeState, ResponseRouting, ScrollState,
-    ServerInfoEditState, ServerInfoTab, UserInfo, UserManagementState, VoiceState,
+    ActivePanel, AdminDashboardState, BroadcastDelay, BroadcastTargetKind, ChannelState,
+    ChatMessage, ChatTab, ConnectionMonitorState, ConnectivityCheckState, DisconnectDialogState,
+    DoorsState, FilesManagementState, GroupState, NewsManagementState, PasswordChangeState,
+    PendingBroadcastAck, QueuedMessage, ResponseRouting, ScreenshotEditorState, ScreenshotFolder,
+    ScrollState, ServerClock, ServerInfoEditState, ServerInfoTab, ServerLogState, SnippetViewState,
+    StatisticsState, TrackedRequests, UnackedMessage, UserInfo, UserManagementState, VoiceState,
 };
+use crate::chat_input::InputHistory;
+use crate::drafts::{DraftContext, Drafts};
+use crate::i18n::t;
 use crate::image::CachedImage;
+use crate::transfers::{AutoDownloadState, UploadWatchState};
 
 // =============================================================================
 // Connection Credentials
@@ -41,12 +53,18 @@ pub struct ConnectionInfo {
     pub transfer_port: u16,
     /// TLS certificate fingerprint (SHA-256)
     pub certificate_fingerprint: String,
+    /// Server identity key (hex Ed25519 public key), if the server proved one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identity_key: Option<String>,
     /// Username for authentication
     pub username: String,
     /// Password for authentication
     pub password: String,
     /// Nickname for shared accounts (empty string if not used)
     pub nickname: String,
+    /// Token that logs in again without the password (only kept in memory)
+    #[serde(skip)]
+    pub login_token: Option<String>,
 }
 
 impl std::fmt::Debug for ConnectionInfo {
@@ -57,9 +75,14 @@ impl std::fmt::Debug for ConnectionInfo {
             .field("port", &self.port)
             .field("transfer_port", &self.transfer_port)
             .field("certificate_fingerprint", &self.certificate_fingerprint)
+            .field("identity_key", &self.identity_key)
             .field("username", &self.username)
             .field("password", &"[REDACTED]")
             .field("nickname", &self.nickname)
+            .field(
+                "login_token",
+                &self.login_token.as_ref().map(|_| "[REDACTED]"),
+            )
             .finish()
     }
 }
@@ -127,12 +150,26 @@ pub struct ServerConnectionParams {
     pub max_connections_per_ip: Option<u32>,
     /// Max transfers per IP (admin only)
     pub max_transfers_per_ip: Option<u32>,
+    /// Max active transfers server-wide (admin only, 0 = unlimited)
+    pub max_active_transfers: Option<u32>,
+    /// Whether queued transfers favour small files (admin only)
+    pub transfer_small_files_first: Option<bool>,
+    /// Fair-share weight for transfers from trusted IPs (admin only)
+    pub trusted_transfer_weight: Option<u32>,
     /// File reindex interval in minutes (admin only, 0 = disabled)
     pub file_reindex_interval: Option<u32>,
     /// Persistent channels (space-separated, admin only)
     pub persistent_channels: Option<String>,
     /// Auto-join channels (space-separated, admin only)
     pub auto_join_channels: Option<String>,
+    /// NAT port mapping status (admin only)
+    pub nat: Option<NatInfo>,
+    /// News image limits (None for servers that don't send them)
+    pub news_image_limits: Option<NewsImageLimits>,
+    /// Longest message the server accepts (None for servers that don't send it)
+    pub max_message_length: Option<u32>,
+    /// Server clock at login (None for servers that don't send it)
+    pub server_time: Option<ServerTime>,
     /// Command sender channel
     pub tx: CommandSender,
     /// Shutdown handle for graceful disconnect
@@ -188,12 +225,26 @@ pub struct ServerConnection {
     pub max_connections_per_ip: Option<u32>,
     /// Max transfers per IP (admin only, from ServerInfo)
     pub max_transfers_per_ip: Option<u32>,
+    /// Max active transfers server-wide (admin only, from ServerInfo, 0 = unlimited)
+    pub max_active_transfers: Option<u32>,
+    /// Whether queued transfers favour small files (admin only, from ServerInfo)
+    pub transfer_small_files_first: Option<bool>,
+    /// Fair-share weight for transfers from trusted IPs (admin only, from ServerInfo)
+    pub trusted_transfer_weight: Option<u32>,
     /// File reindex interval in minutes (admin only, from ServerInfo, 0 = disabled)
     pub file_reindex_interval: Option<u32>,
     /// Persistent channels (space-separated, admin only)
     pub persistent_channels: Option<String>,
     /// Auto-join channels (space-separated, admin only)
     pub auto_join_channels: Option<String>,
+    /// NAT port mapping status (admin only, from ServerInfo or NatStatusResponse)
+    pub nat: Option<NatInfo>,
+    /// News image limits (from ServerInfo, None for servers that don't send them)
+    pub news_image_limits: Option<NewsImageLimits>,
+    /// Longest message the server accepts (from ServerInfo, None for servers that don't send it)
+    pub max_message_length: Option<u32>,
+    /// Server clock for the status bar (from ServerInfo, None for servers that don't send it)
+    pub server_clock: Option<ServerClock>,
     /// Active chat tab (Console, Channel, or UserMessage)
     pub active_chat_tab: ChatTab,
     /// Console messages (system, error, info, broadcast messages)
@@ -208,29 +259,72 @@ pub struct ServerConnection {
     pub user_message_tabs: Vec<String>,
     /// User message history per user (keyed by nickname)
     pub user_messages: HashMap<String, Vec<ChatMessage>>,
+    /// Days the server keeps our user messages (None until synced, 0 = off)
+    pub message_history_days: Option<u32>,
+    /// Oldest server history ID fetched per conversation (lowercase nickname)
+    pub message_history_oldest: HashMap<String, i64>,
+    /// Newest user message ID we sent a read receipt for, per conversation
+    pub read_receipts_sent: HashMap<String, u64>,
+    /// Group conversation tabs in creation order (group IDs)
+    pub group_tabs: Vec<u64>,
+    /// Group conversation state by group ID
+    pub groups: HashMap<u64, GroupState>,
     /// Pending channel leave request (to prevent double-send)
     pub pending_channel_leave: Option<String>,
     /// Tabs with unread messages (for bold indicator)
     pub unread_tabs: HashSet<ChatTab>,
+    /// Unread channel tabs that mention us (counted on the macOS dock badge)
+    pub mention_tabs: HashSet<ChatTab>,
     /// Currently online users
     pub online_users: Vec<UserInfo>,
     /// Display name of expanded user in user list (None if no user expanded)
     /// For shared accounts this is the nickname, for regular accounts the username.
     pub expanded_user: Option<String>,
+    /// Text typed in the user list filter (empty shows everyone)
+    pub user_list_filter: String,
+    /// Keys of the user list sections collapsed from their headers
+    pub collapsed_user_groups: HashSet<String>,
+    /// Presence of the bookmark's friends, in the order the server reported them
+    pub friends: Vec<PresenceInfo>,
     /// Channel for sending commands to server
     tx: CommandSender,
     /// Handle for graceful shutdown
     pub shutdown_handle: WrappedShutdownHandle,
-    /// Current chat message input
+    /// Current chat message input (for the active chat tab)
     pub message_input: String,
+    /// Sent input per chat tab, for Up/Down recall
+    pub input_history: HashMap<ChatTab, InputHistory>,
+    /// Multi-line text pasted into the empty chat input, waiting for the
+    /// user to choose how to send it
+    pub pending_paste: Option<String>,
+    /// Unsent drafts for inactive chat tabs and closed news editors
+    pub drafts: Drafts,
+    /// Chat and user messages waiting to be sent (oldest first)
+    pub send_queue: Vec<QueuedMessage>,
+    /// Chat and user messages sent but not yet answered (oldest first)
+    pub unacked_messages: Vec<UnackedMessage>,
+    /// Keys of the long code blocks in chat expanded past their first lines
+    pub expanded_code_blocks: HashSet<u64>,
     /// Current broadcast message input
     pub broadcast_message: String,
     /// Scroll state per chat tab (offset and auto-scroll flag)
     pub scroll_states: HashMap<ChatTab, ScrollState>,
     /// Pending requests that need response routing
-    pub pending_requests: HashMap<MessageId, ResponseRouting>,
+    pub pending_requests: TrackedRequests,
     /// Error message for broadcast operations
     pub broadcast_error: Option<String>,
+    /// Who the broadcast goes to
+    pub broadcast_target: BroadcastTargetKind,
+    /// Channel chosen for a channel broadcast
+    pub broadcast_channel: Option<String>,
+    /// Permission chosen for a permission broadcast
+    pub broadcast_permission: Option<String>,
+    /// How long to wait before the server sends the broadcast
+    pub broadcast_delay: BroadcastDelay,
+    /// Whether recipients are asked to acknowledge the broadcast
+    pub broadcast_require_ack: bool,
+    /// Received broadcasts waiting for us to acknowledge them (oldest first)
+    pub broadcast_acks: Vec<PendingBroadcastAck>,
     /// User management panel state
     pub user_management: UserManagementState,
     /// User info panel data (None = loading, Some(Ok) = loaded, Some(Err) = error)
@@ -245,12 +339,14 @@ pub struct ServerConnection {
     pub server_info_edit: Option<ServerInfoEditState>,
     /// Active tab in server info display mode (shown based on available data)
     pub server_info_tab: ServerInfoTab,
+    /// Last connectivity check (admin only, shown in the server info panel)
+    pub connectivity_check: ConnectivityCheckState,
     /// Currently active panel in the main content area (per-connection)
     pub active_panel: ActivePanel,
     /// News management panel state
     pub news_management: NewsManagementState,
-    /// Cached news images for rendering (keyed by news item ID)
-    pub news_image_cache: HashMap<i64, CachedImage>,
+    /// Cached news images for rendering (keyed by news item ID and attachment index)
+    pub news_image_cache: HashMap<(i64, usize), CachedImage>,
     /// Cached parsed markdown for news items (keyed by news item ID)
     pub news_markdown_cache: HashMap<i64, Vec<markdown::Item>>,
     /// Tab completion state for chat input (None when not completing)
@@ -259,6 +355,16 @@ pub struct ServerConnection {
     pub files_management: FilesManagementState,
     /// Connection monitor panel state
     pub connection_monitor: ConnectionMonitorState,
+    /// Server log panel state
+    pub server_log: ServerLogState,
+    /// Statistics panel state
+    pub statistics: StatisticsState,
+    /// Admin dashboard panel state
+    pub admin_dashboard: AdminDashboardState,
+    /// Doors panel state
+    pub doors: DoorsState,
+    /// Snippet viewer panel state
+    pub snippet_view: SnippetViewState,
     /// Pending kick message (set when we receive a kick error, used on disconnect)
     pub pending_kick_message: Option<String>,
     /// Disconnect dialog state (Some when dialog is open)
@@ -268,6 +374,23 @@ pub struct ServerConnection {
     /// Nicknames currently in voice per channel (lowercase channel name -> set of nicknames)
     /// Tracked even when we're not in voice, so we can show voice indicators in user list
     pub channel_voiced: HashMap<String, HashSet<String>>,
+    /// Scan state of the bookmark's watched upload folder
+    pub upload_watch: UploadWatchState,
+    /// Files seen in the bookmark's auto-download folders
+    pub auto_download: AutoDownloadState,
+    /// Directories the server was last asked to report changes in
+    pub file_watches: Vec<FileWatchPath>,
+    /// Screenshot folder for connections without a bookmark (session only)
+    pub screenshot_folder: Option<ScreenshotFolder>,
+    /// Screenshot annotation editor state (Some when the editor is open)
+    pub screenshot_editor: Option<ScreenshotEditorState>,
+    /// Do not disturb state last sent to the server
+    pub dnd_advertised: bool,
+    /// Whether our away status was set automatically after idle time
+    /// (cleared again on activity, unlike a manual /away)
+    pub auto_away: bool,
+    /// Latency statistics from background pings
+    pub health: ConnectionHealth,
 }
 
 impl ServerConnection {
@@ -279,6 +402,28 @@ impl ServerConnection {
         self.is_admin || self.permissions.iter().any(|p| p == permission)
     }
 
+    /// Longest message to send, in bytes
+    ///
+    /// The limit the server advertises, capped at the one this client
+    /// knows; servers that don't advertise one get the latter.
+    pub fn max_message_length(&self) -> usize {
+        self.max_message_length
+            .map_or(validators::MAX_MESSAGE_LENGTH, |max| {
+                (max as usize).min(validators::MAX_MESSAGE_LENGTH)
+            })
+    }
+
+    /// The current time by the server's clock, in our time zone
+    ///
+    /// Used to stamp messages the server didn't, so they line up with the
+    /// server's timestamps even when our clock is off. Falls back to our
+    /// clock for servers that don't send theirs.
+    pub fn server_now(&self) -> DateTime<Local> {
+        self.server_clock
+            .as_ref()
+            .map_or_else(Local::now, |clock| clock.local_time_at(Utc::now()))
+    }
+
     /// Get channel state by name (case-insensitive lookup)
     pub fn get_channel_state(&self, channel: &str) -> Option<&ChannelState> {
         self.channels.get(&channel.to_lowercase())
@@ -302,6 +447,40 @@ impl ServerConnection {
             .unwrap_or_else(|| channel.to_string())
     }
 
+    /// Get the messages shown in a chat tab
+    pub fn tab_messages(&self, tab: &ChatTab) -> &[ChatMessage] {
+        match tab {
+            ChatTab::Console => self.console_messages.as_slice(),
+            ChatTab::Channel(channel) => self
+                .get_channel_state(channel)
+                .map(|ch| ch.messages.as_slice())
+                .unwrap_or(&[]),
+            ChatTab::UserMessage(nickname) => self
+                .user_messages
+                .get(nickname)
+                .map(|v| v.as_slice())
+                .unwrap_or(&[]),
+            ChatTab::Group(group_id) => self
+                .groups
+                .get(group_id)
+                .map(|group| group.messages.as_slice())
+                .unwrap_or(&[]),
+        }
+    }
+
+    /// Get the label of a chat tab (channel, nickname, or group members)
+    pub fn tab_label(&self, tab: &ChatTab) -> String {
+        match tab {
+            ChatTab::Console => t("console-tab"),
+            ChatTab::Channel(name) | ChatTab::UserMessage(name) => name.clone(),
+            ChatTab::Group(group_id) => self
+                .groups
+                .get(group_id)
+                .map(|group| group.label(&self.nickname))
+                .unwrap_or_default(),
+        }
+    }
+
     /// Check if the user has any of the specified permissions
     ///
     /// Returns true if:
@@ -316,6 +495,51 @@ impl ServerConnection {
                 .any(|req| self.permissions.iter().any(|p| p == *req))
     }
 
+    /// Record that an online user just sent a chat or user message
+    ///
+    /// Keeps idle times in the user list current between user list refreshes.
+    pub fn mark_user_active(&mut self, nickname: &str, timestamp: i64) {
+        let nickname_lower = nickname.to_lowercase();
+        if let Some(user) = self
+            .online_users
+            .iter_mut()
+            .find(|user| user.nickname.to_lowercase() == nickname_lower)
+        {
+            user.last_active = user.last_active.max(Some(timestamp));
+        }
+    }
+
+    /// Switch the active chat tab, keeping each tab's unsent input
+    pub fn set_active_chat_tab(&mut self, tab: ChatTab) {
+        if tab == self.active_chat_tab {
+            return;
+        }
+
+        let input = self
+            .drafts
+            .remove(&DraftContext::Chat(tab.clone()))
+            .unwrap_or_default();
+        let previous_input = std::mem::replace(&mut self.message_input, input);
+        let previous_tab = std::mem::replace(&mut self.active_chat_tab, tab);
+        if let Some(history) = self.input_history.get_mut(&previous_tab) {
+            history.reset();
+        }
+        if !previous_input.is_empty() {
+            self.drafts
+                .insert(DraftContext::Chat(previous_tab), previous_input);
+        }
+        self.tab_completion = None;
+        self.pending_paste = None;
+    }
+
+    /// Restore drafts saved from an earlier connection
+    pub fn restore_drafts(&mut self, mut drafts: Drafts) {
+        self.message_input = drafts
+            .remove(&DraftContext::Chat(self.active_chat_tab.clone()))
+            .unwrap_or_default();
+        self.drafts = drafts;
+    }
+
     /// Send a message to the server
     ///
     /// Generates a new message ID and sends the message through the channel.
@@ -328,6 +552,28 @@ impl ServerConnection {
         Ok(message_id)
     }
 
+    /// Stop waiting for the matching requests and tell the server to cancel them
+    ///
+    /// The server stops long operations (searches, file info, manifests) and
+    /// sends nothing back. Anything else still finishes, and its response is
+    /// ignored like any other untracked one.
+    pub fn cancel_requests(&mut self, cancels: impl Fn(&ResponseRouting) -> bool) {
+        let mut cancelled = Vec::new();
+        self.pending_requests.retain(|message_id, routing| {
+            if cancels(routing) {
+                cancelled.push(*message_id);
+                false
+            } else {
+                true
+            }
+        });
+        for message_id in cancelled {
+            let _ = self.send(ClientMessage::Cancel {
+                message_id: message_id.to_string(),
+            });
+        }
+    }
+
     /// Create a new ServerConnection with the given parameters
     pub fn new(params: ServerConnectionParams) -> Self {
         Self {
@@ -346,9 +592,18 @@ impl ServerConnection {
             cached_server_image: params.cached_server_image,
             max_connections_per_ip: params.max_connections_per_ip,
             max_transfers_per_ip: params.max_transfers_per_ip,
+            max_active_transfers: params.max_active_transfers,
+            transfer_small_files_first: params.transfer_small_files_first,
+            trusted_transfer_weight: params.trusted_transfer_weight,
             file_reindex_interval: params.file_reindex_interval,
             persistent_channels: params.persistent_channels,
             auto_join_channels: params.auto_join_channels,
+            nat: params.nat,
+            news_image_limits: params.news_image_limits,
+            max_message_length: params.max_message_length,
+            server_clock: params
+                .server_time
+                .and_then(|time| ServerClock::new(time, Utc::now())),
             active_chat_tab: ChatTab::Console,
             console_messages: Vec::new(),
             channel_tabs: Vec::new(),
@@ -356,17 +611,38 @@ impl ServerConnection {
             known_channels: Vec::new(),
             user_message_tabs: Vec::new(),
             user_messages: HashMap::new(),
+            group_tabs: Vec::new(),
+            groups: HashMap::new(),
+            message_history_days: None,
+            message_history_oldest: HashMap::new(),
+            read_receipts_sent: HashMap::new(),
             pending_channel_leave: None,
             unread_tabs: HashSet::new(),
+            mention_tabs: HashSet::new(),
             online_users: Vec::new(),
             expanded_user: None,
+            user_list_filter: String::new(),
+            collapsed_user_groups: HashSet::new(),
+            friends: Vec::new(),
             tx: params.tx,
             shutdown_handle: params.shutdown_handle,
             message_input: String::new(),
+            input_history: HashMap::new(),
+            pending_paste: None,
+            drafts: Drafts::new(),
+            send_queue: Vec::new(),
+            unacked_messages: Vec::new(),
+            expanded_code_blocks: HashSet::new(),
             broadcast_message: String::new(),
             scroll_states: HashMap::new(),
-            pending_requests: HashMap::new(),
+            pending_requests: TrackedRequests::default(),
             broadcast_error: None,
+            broadcast_target: BroadcastTargetKind::default(),
+            broadcast_channel: None,
+            broadcast_permission: None,
+            broadcast_delay: BroadcastDelay::default(),
+            broadcast_require_ack: false,
+            broadcast_acks: Vec::new(),
             user_management: UserManagementState::default(),
             user_info_data: None,
             user_info_return_panel: None,
@@ -374,6 +650,7 @@ impl ServerConnection {
             avatar_cache: HashMap::new(),
             server_info_edit: None,
             server_info_tab: ServerInfoTab::default(),
+            connectivity_check: ConnectivityCheckState::default(),
             active_panel: ActivePanel::None,
             news_management: NewsManagementState::default(),
             news_image_cache: HashMap::new(),
@@ -381,10 +658,23 @@ impl ServerConnection {
             tab_completion: None,
             files_management: FilesManagementState::default(),
             connection_monitor: ConnectionMonitorState::default(),
+            server_log: ServerLogState::default(),
+            statistics: StatisticsState::default(),
+            admin_dashboard: AdminDashboardState::default(),
+            doors: DoorsState::default(),
+            snippet_view: SnippetViewState::default(),
             pending_kick_message: None,
             disconnect_dialog: None,
             voice_session: None,
             channel_voiced: HashMap::new(),
+            upload_watch: UploadWatchState::default(),
+            auto_download: AutoDownloadState::default(),
+            file_watches: Vec::new(),
+            screenshot_folder: None,
+            screenshot_editor: None,
+            dnd_advertised: false,
+            auto_away: false,
+            health: ConnectionHealth::default(),
         }
     }
 }
@@ -425,12 +715,26 @@ pub struct NetworkConnection {
     pub max_connections_per_ip: Option<u32>,
     /// Max transfers per IP (admin only)
     pub max_transfers_per_ip: Option<u32>,
+    /// Max active transfers server-wide (admin only, 0 = unlimited)
+    pub max_active_transfers: Option<u32>,
+    /// Whether queued transfers favour small files (admin only)
+    pub transfer_small_files_first: Option<bool>,
+    /// Fair-share weight for transfers from trusted IPs (admin only)
+    pub trusted_transfer_weight: Option<u32>,
     /// File reindex interval in minutes (admin only, 0 = disabled)
     pub file_reindex_interval: Option<u32>,
     /// Persistent channels (space-separated, admin only)
     pub persistent_channels: Option<String>,
     /// Auto-join channels (space-separated, admin only)
     pub auto_join_channels: Option<String>,
+    /// NAT port mapping status (admin only)
+    pub nat: Option<NatInfo>,
+    /// News image limits (None for servers that don't send them)
+    pub news_image_limits: Option<NewsImageLimits>,
+    /// Longest message the server accepts (None for servers that don't send it)
+    pub max_message_length: Option<u32>,
+    /// Server clock at login (None for servers that don't send it)
+    pub server_time: Option<ServerTime>,
     /// Locale accepted by the server
     pub locale: String,
     /// Connection info (address, port, auth info)
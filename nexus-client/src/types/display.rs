@@ -1,12 +1,16 @@
 //! Chat and user display types
 
+use std::time::Instant;
+
 use chrono::{DateTime, Local};
+use nexus_common::framing::MessageId;
 use nexus_common::protocol::ChatAction;
+use uuid::Uuid;
 
 /// Chat tab type - represents different chat windows
 ///
 /// Tab order in UI: Console → Channels (join order) → User messages (creation order)
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, serde::Serialize, serde::Deserialize)]
 pub enum ChatTab {
     /// Console tab - always present, cannot be closed
     /// Receives system messages, errors, broadcasts, and command output
@@ -18,6 +22,9 @@ pub enum ChatTab {
     /// User message conversation (1-on-1)
     /// X button hides tab, history preserved, reappears on new message
     UserMessage(String),
+    /// Group conversation (multi-user direct message, by group ID)
+    /// X button sends GroupMessageLeave and closes the tab
+    Group(u64),
 }
 
 /// Scroll state for a chat tab
@@ -54,6 +61,17 @@ pub enum MessageType {
     Broadcast,
 }
 
+/// How far a user message we sent has got (shown as ticks after it)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DeliveryState {
+    /// The server accepted the message
+    Sent,
+    /// The server passed the message on to the recipient
+    Delivered,
+    /// The recipient read the message
+    Read,
+}
+
 /// Chat message for display
 #[derive(Debug, Clone)]
 pub struct ChatMessage {
@@ -76,6 +94,12 @@ pub struct ChatMessage {
     pub is_shared: bool,
     /// Action type for chat messages (Normal or Me)
     pub action: ChatAction,
+    /// Whether the message mentions us or matches a watched keyword
+    pub highlighted: bool,
+    /// Server-assigned ID of a user message (for delivery and read receipts)
+    pub message_id: Option<u64>,
+    /// Receipt state of a user message we sent (None for everything else)
+    pub delivery: Option<DeliveryState>,
 }
 
 impl ChatMessage {
@@ -96,6 +120,9 @@ impl ChatMessage {
             is_admin,
             is_shared,
             action,
+            highlighted: false,
+            message_id: None,
+            delivery: None,
         }
     }
 
@@ -109,6 +136,9 @@ impl ChatMessage {
             is_admin: false,
             is_shared: false,
             action: ChatAction::Normal,
+            highlighted: false,
+            message_id: None,
+            delivery: None,
         }
     }
 
@@ -122,6 +152,9 @@ impl ChatMessage {
             is_admin: false,
             is_shared: false,
             action: ChatAction::Normal,
+            highlighted: false,
+            message_id: None,
+            delivery: None,
         }
     }
 
@@ -135,6 +168,9 @@ impl ChatMessage {
             is_admin: false,
             is_shared: false,
             action: ChatAction::Normal,
+            highlighted: false,
+            message_id: None,
+            delivery: None,
         }
     }
 
@@ -148,6 +184,9 @@ impl ChatMessage {
             is_admin: false,
             is_shared: false,
             action: ChatAction::Normal,
+            highlighted: false,
+            message_id: None,
+            delivery: None,
         }
     }
 
@@ -165,6 +204,9 @@ impl ChatMessage {
             is_admin: false,
             is_shared: false,
             action: ChatAction::Normal,
+            highlighted: false,
+            message_id: None,
+            delivery: None,
         }
     }
 
@@ -174,6 +216,55 @@ impl ChatMessage {
     }
 }
 
+/// Chat or user message waiting to be sent
+///
+/// Messages land here when the connection is down at the time they're sent,
+/// or when it drops before the server answered them (see [`UnackedMessage`]).
+/// They're shown at the end of their tab until they're sent or cancelled.
+#[derive(Debug, Clone)]
+pub struct QueuedMessage {
+    /// Identifies the message for the retry and cancel buttons, and to the
+    /// server as its idempotency key
+    pub id: Uuid,
+    /// Tab the message was typed in (a channel or user message tab)
+    pub tab: ChatTab,
+    /// Message text
+    pub message: String,
+    /// Action type (Normal or Me)
+    pub action: ChatAction,
+}
+
+impl QueuedMessage {
+    /// Create a queued message for a tab
+    pub fn new(tab: ChatTab, message: String, action: ChatAction) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            tab,
+            message,
+            action,
+        }
+    }
+
+    /// Idempotency key the message is sent with (the same on every retry)
+    pub fn idempotency_key(&self) -> String {
+        self.id.simple().to_string()
+    }
+}
+
+/// Chat or user message sent but not yet answered by the server
+///
+/// If the connection drops first, the message is queued again and resent
+/// with the same idempotency key, so the server won't post it twice.
+#[derive(Debug, Clone)]
+pub struct UnackedMessage {
+    /// Request the message was sent as
+    pub message_id: MessageId,
+    /// When it was sent
+    pub sent: Instant,
+    /// The message as it was queued
+    pub queued: QueuedMessage,
+}
+
 /// User information for display
 #[derive(Debug, Clone)]
 pub struct UserInfo {
@@ -198,4 +289,10 @@ pub struct UserInfo {
     pub is_away: bool,
     /// User's status message (if any)
     pub status: Option<String>,
+    /// Whether user is in do not disturb mode
+    pub is_dnd: bool,
+    /// When the user connected (Unix timestamp, earliest session)
+    pub login_time: i64,
+    /// When the user last sent a chat or user message (Unix timestamp, None if unknown)
+    pub last_active: Option<i64>,
 }
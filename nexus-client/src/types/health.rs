@@ -0,0 +1,176 @@
+//! Connection health tracking from background pings
+//!
+//! Each connection pings the server periodically (see `HEALTH_PING_INTERVAL_SECS`)
+//! and keeps a rolling window of round-trip times. The server list shows the
+//! resulting rating as a colored dot next to each connected server.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use chrono::{DateTime, Local};
+use nexus_common::framing::MessageId;
+
+/// Number of round-trip samples kept for the average and jitter
+const HEALTH_WINDOW: usize = 10;
+
+/// Latency at or above which the connection is rated fair
+const FAIR_LATENCY: Duration = Duration::from_millis(150);
+
+/// Latency at or above which the connection is rated poor
+const POOR_LATENCY: Duration = Duration::from_millis(400);
+
+/// Jitter at or above which the connection is rated fair
+const FAIR_JITTER: Duration = Duration::from_millis(30);
+
+/// Jitter at or above which the connection is rated poor
+const POOR_JITTER: Duration = Duration::from_millis(100);
+
+/// Connection health rating
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthLevel {
+    /// Low latency and jitter
+    Good,
+    /// Noticeable latency or jitter
+    Fair,
+    /// High latency or jitter, or the last ping went unanswered
+    Poor,
+}
+
+impl HealthLevel {
+    /// Get the translation key for this rating
+    pub fn translation_key(self) -> &'static str {
+        match self {
+            HealthLevel::Good => "connection-health-good",
+            HealthLevel::Fair => "connection-health-fair",
+            HealthLevel::Poor => "connection-health-poor",
+        }
+    }
+}
+
+/// Rolling latency statistics for a connection
+#[derive(Debug, Clone, Default)]
+pub struct ConnectionHealth {
+    /// Recent round-trip times, oldest first
+    samples: VecDeque<Duration>,
+    /// Background ping waiting for its pong
+    pub outstanding: Option<MessageId>,
+    /// Whether the last background ping went unanswered (cleared by the next pong)
+    dropped: bool,
+    /// When a background ping last went unanswered
+    last_drop: Option<DateTime<Local>>,
+}
+
+impl ConnectionHealth {
+    /// Record the round-trip time of an answered ping
+    pub fn record(&mut self, rtt: Duration) {
+        self.samples.push_back(rtt);
+        while self.samples.len() > HEALTH_WINDOW {
+            self.samples.pop_front();
+        }
+        self.dropped = false;
+    }
+
+    /// Record a ping that went unanswered
+    pub fn record_drop(&mut self) {
+        self.dropped = true;
+        self.last_drop = Some(Local::now());
+    }
+
+    /// Most recent round-trip time
+    pub fn latency(&self) -> Option<Duration> {
+        self.samples.back().copied()
+    }
+
+    /// Mean difference between consecutive round-trip times
+    pub fn jitter(&self) -> Option<Duration> {
+        if self.samples.len() < 2 {
+            return None;
+        }
+        let total: Duration = self
+            .samples
+            .iter()
+            .zip(self.samples.iter().skip(1))
+            .map(|(a, b)| a.abs_diff(*b))
+            .sum();
+        Some(total / (self.samples.len() - 1) as u32)
+    }
+
+    /// When a background ping last went unanswered
+    pub fn last_drop(&self) -> Option<DateTime<Local>> {
+        self.last_drop
+    }
+
+    /// Rate the connection (None until the first ping is answered or dropped)
+    pub fn level(&self) -> Option<HealthLevel> {
+        if self.dropped {
+            return Some(HealthLevel::Poor);
+        }
+        let latency = self.latency()?;
+        let jitter = self.jitter().unwrap_or_default();
+        Some(if latency >= POOR_LATENCY || jitter >= POOR_JITTER {
+            HealthLevel::Poor
+        } else if latency >= FAIR_LATENCY || jitter >= FAIR_JITTER {
+            HealthLevel::Fair
+        } else {
+            HealthLevel::Good
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn health_with(samples: &[u64]) -> ConnectionHealth {
+        let mut health = ConnectionHealth::default();
+        for ms in samples {
+            health.record(Duration::from_millis(*ms));
+        }
+        health
+    }
+
+    #[test]
+    fn test_no_samples() {
+        let health = ConnectionHealth::default();
+        assert_eq!(health.latency(), None);
+        assert_eq!(health.jitter(), None);
+        assert_eq!(health.level(), None);
+    }
+
+    #[test]
+    fn test_latency_and_jitter() {
+        let health = health_with(&[40, 60, 50]);
+        assert_eq!(health.latency(), Some(Duration::from_millis(50)));
+        // |60-40| + |50-60| = 30 over 2 intervals
+        assert_eq!(health.jitter(), Some(Duration::from_millis(15)));
+        assert_eq!(health.level(), Some(HealthLevel::Good));
+    }
+
+    #[test]
+    fn test_window_is_bounded() {
+        let samples: Vec<u64> = (0..25).map(|i| 20 + i).collect();
+        let health = health_with(&samples);
+        assert_eq!(health.samples.len(), HEALTH_WINDOW);
+        assert_eq!(health.latency(), Some(Duration::from_millis(44)));
+    }
+
+    #[test]
+    fn test_levels() {
+        assert_eq!(health_with(&[200]).level(), Some(HealthLevel::Fair));
+        assert_eq!(health_with(&[500]).level(), Some(HealthLevel::Poor));
+        assert_eq!(health_with(&[20, 80, 20]).level(), Some(HealthLevel::Fair));
+        assert_eq!(health_with(&[20, 200, 20]).level(), Some(HealthLevel::Poor));
+    }
+
+    #[test]
+    fn test_drop_until_next_pong() {
+        let mut health = health_with(&[20]);
+        health.record_drop();
+        assert_eq!(health.level(), Some(HealthLevel::Poor));
+        assert!(health.last_drop().is_some());
+
+        health.record(Duration::from_millis(20));
+        assert_eq!(health.level(), Some(HealthLevel::Good));
+        assert!(health.last_drop().is_some());
+    }
+}
@@ -1,19 +1,31 @@
 //! Message types for the Elm-style architecture
 
+use std::sync::Arc;
+
 use iced::Theme;
-use iced::widget::{markdown, text_editor};
+use iced::widget::{markdown, pane_grid, text_editor};
 use iced_toasts::ToastId;
 use uuid::Uuid;
 
 use nexus_common::framing::MessageId;
-use nexus_common::protocol::FileSearchResult;
+use nexus_common::protocol::{FileActivity, FileSearchResult};
 use nexus_common::voice::VoiceQuality;
 
 use super::panel::{FileSortColumn, SettingsTab, TabId};
-use super::{ChatTab, NetworkConnection, ServerMessage};
+use super::{BookmarkDropTarget, ChatTab, LanServer, NetworkConnection, ServerMessage, SpellField};
 use crate::config::audio::{PttMode, PttReleaseDelay};
-use crate::config::events::{EventType, NotificationContent, SoundChoice};
+use crate::config::events::{
+    ChannelNotification, EventType, NotificationContent, QuietHour, SoundChoice,
+};
+use crate::config::settings::Tip;
+#[cfg(target_os = "linux")]
+use crate::hotkeys::portal::Shortcut;
 use crate::image::ImagePickerError;
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+use crate::notifications::NotificationTarget;
+use crate::screenshot::Screenshot;
+use crate::screenshot::annotate::Tool;
+use crate::spellcheck::SpellChecker;
 use crate::transfers::TransferEvent;
 use crate::uri::NexusUri;
 use crate::voice::audio::AudioDevice;
@@ -30,6 +42,8 @@ pub enum Message {
     AddBookmarkToggled(bool),
     /// Bookmark editor: Address field changed
     BookmarkAddressChanged(String),
+    /// Bookmark editor: Fallback addresses field changed
+    BookmarkFallbackAddressesChanged(String),
     /// Bookmark editor: Auto-connect checkbox toggled
     BookmarkAutoConnectToggled(bool),
     /// Network: Bookmark connection attempt completed (with display name)
@@ -48,8 +62,34 @@ pub enum Message {
     BookmarkUsernameChanged(String),
     /// Bookmark editor: Nickname field changed
     BookmarkNicknameChanged(String),
+    /// Bookmark editor: Status field changed
+    BookmarkStatusChanged(String),
+    /// Bookmark editor: Choose avatar button pressed
+    BookmarkPickAvatarPressed,
+    /// Bookmark editor: Avatar file picker completed
+    BookmarkAvatarLoaded(Result<String, ImagePickerError>),
+    /// Bookmark editor: Clear avatar button pressed
+    BookmarkClearAvatarPressed,
+    /// Bookmark editor: Download folder field changed
+    BookmarkDownloadFolderChanged(String),
+    /// Bookmark editor: Single sign-on checkbox toggled
+    BookmarkUseSsoToggled(bool),
+    /// Chat: Acknowledge the oldest broadcast that asked for it
+    BroadcastAcknowledged,
+    /// Chat: Close the acknowledgement prompt without acknowledging
+    BroadcastAckDismissed,
+    /// Broadcast: Channel picked for a channel broadcast
+    BroadcastChannelSelected(String),
+    /// Broadcast: Delay picked
+    BroadcastDelayChanged(crate::types::BroadcastDelay),
     /// Broadcast: Message input changed
     BroadcastMessageChanged(String),
+    /// Broadcast: Permission picked for a permission broadcast
+    BroadcastPermissionSelected(crate::types::AuditPermission),
+    /// Broadcast: Require acknowledgement checkbox toggled
+    BroadcastRequireAckToggled(bool),
+    /// Broadcast: Target picked (everyone, a channel, or a permission)
+    BroadcastTargetChanged(crate::types::BroadcastTargetKind),
     /// User management: Cancel button pressed (return to list or close panel)
     CancelUserManagement,
     /// Bookmark editor: Cancel button pressed
@@ -79,8 +119,8 @@ pub enum Message {
     ChangePasswordFocusResult(bool, bool, bool),
     /// Bookmark edit: Tab pressed, check focus and move to next field
     BookmarkEditTabPressed,
-    /// Bookmark edit: Focus check result for Tab navigation (name, address, port, username, password, nickname)
-    BookmarkEditFocusResult(bool, bool, bool, bool, bool, bool),
+    /// Bookmark edit: Focus check result for Tab navigation (name, address, port, fallback addresses, username, password, nickname, status, download folder)
+    BookmarkEditFocusResult(bool, bool, bool, bool, bool, bool, bool, bool, bool),
     /// Connection form: Tab pressed, check focus and move to next field
     ConnectionFormTabPressed,
     /// Connection form: Focus check result for Tab navigation (name, address, port, username, password, nickname)
@@ -101,18 +141,62 @@ pub enum Message {
     SettingsTabPressed,
     /// Settings panel Network tab: Focus check result for Tab navigation (address, port, username, password)
     SettingsNetworkFocusResult(bool, bool, bool, bool),
-    /// Chat: Message input field changed
-    ChatInputChanged(String),
+    /// Chat: Message input edited, or its cursor moved
+    ChatInputAction(text_editor::Action),
+    /// Chat: Down pressed in a single-line message input (newer sent input)
+    ChatInputHistoryNext,
+    /// Chat: Up pressed in a single-line message input (older sent input)
+    ChatInputHistoryPrevious,
+    /// Chat: Send the pasted lines as separate messages
+    PasteAsMessages,
+    /// Chat: Send the pasted lines as one multi-line message
+    PasteAsOneMessage,
+    /// Chat: Share the pasted text as a snippet and post its link
+    PasteAsSnippet,
+    /// Chat: Close the paste prompt without sending
+    PasteCancelled,
     /// Chat: Tab key pressed for nickname completion
     ChatTabComplete,
+    /// Spell check: Dictionary for the UI language loaded at startup (None if not found)
+    SpellCheckerLoaded(Option<Arc<SpellChecker>>),
+    /// Spell check: Editor right-clicked (position within the editor)
+    SpellMenuOpened(SpellField, iced::Point),
+    /// Spell check: Suggestion chosen for the right-clicked word
+    SpellSuggestionChosen(String),
     /// Chat scrollable: scroll position changed
     ChatScrolled(iced::widget::scrollable::Viewport),
     /// Close a channel tab (sends ChatLeave to server)
     CloseChannelTab(String),
     /// Close a user message tab
     CloseUserMessageTab(String),
+    /// Close a group conversation tab (sends GroupMessageLeave to server)
+    CloseGroupTab(u64),
+    /// Pop a chat tab out into its own window
+    PopOutChatTab(ChatTab),
+    /// Mute or unmute a channel or user message tab
+    ToggleChatTabMute(ChatTab),
+    /// Chat window: Message input field changed
+    ChatWindowInputChanged(iced::window::Id, String),
+    /// Chat window: Send button pressed
+    ChatWindowSendPressed(iced::window::Id),
+    /// Chat: Cancel a queued message
+    QueuedMessageCancel(Uuid),
+    /// Chat: Try sending a queued message again
+    QueuedMessageRetry(Uuid),
+    /// Chat: Copy a code block's text to the clipboard
+    CopyCodeBlock(String),
+    /// Chat: Expand or collapse a long code block (connection ID, block key)
+    ToggleCodeBlock(usize, u64),
     /// Connection form: Connect button pressed
     ConnectPressed,
+    /// Connection form: Connect button pressed on a server found on the local network
+    ConnectToLanServer(String),
+    /// LAN discovery: Browsing started (forget servers from the last browse)
+    LanDiscoveryStarted,
+    /// LAN discovery: A server was found or updated
+    LanServerFound(LanServer),
+    /// LAN discovery: A server left the network (by full service name)
+    LanServerLost(String),
     /// Connect to a bookmark by ID
     ConnectToBookmark(Uuid),
     /// Network: Connection attempt completed
@@ -121,6 +205,12 @@ pub enum Message {
     DeleteBookmark(Uuid),
     /// Disconnect from server by connection_id
     DisconnectFromServer(usize),
+    /// Time to ping every connection for the server list health indicator
+    HealthPingTick,
+    /// Time to give up on requests the server hasn't answered
+    RequestTimeoutTick,
+    /// Status bar clock tick (triggers UI update so the server time stays live)
+    StatusBarClockTick,
 
     /// Server info edit: Description field changed
     EditServerInfoDescriptionChanged(String),
@@ -130,6 +220,12 @@ pub enum Message {
     EditServerInfoMaxConnectionsChanged(u32),
     /// Server info edit: Max transfers per IP field changed
     EditServerInfoMaxTransfersChanged(u32),
+    /// Server info edit: Max active transfers (server-wide) field changed
+    EditServerInfoMaxActiveTransfersChanged(u32),
+    /// Server info edit: Small files first checkbox toggled
+    EditServerInfoSmallFilesFirstToggled(bool),
+    /// Server info edit: Trusted IP transfer weight field changed
+    EditServerInfoTrustedTransferWeightChanged(u32),
     /// Server info edit: File reindex interval field changed
     EditServerInfoFileReindexIntervalChanged(u32),
     /// Server info edit: Name field changed
@@ -142,18 +238,28 @@ pub enum Message {
     EditServerInfoPressed,
     /// Server info display: Tab changed (tabs shown based on available data)
     ServerInfoTabChanged(super::ServerInfoTab),
+    /// Server info display: Check Ports button pressed (admin only)
+    ConnectivityCheckPressed,
     /// Server info edit: Pick image button pressed
     PickServerImagePressed,
     /// Server info edit: Clear image button pressed
     ClearServerImagePressed,
-    /// Keyboard or mouse event
-    Event(iced::Event),
+    /// Keyboard, mouse or window event, and the window it happened in
+    Event(iced::window::Id, iced::Event),
     /// Keyboard: Navigate to next chat tab (Ctrl+Tab)
     NextChatTab,
     /// Network: Error occurred on connection
     NetworkError(usize, String),
     /// Connection form: Password field changed
     PasswordChanged(String),
+    /// Password vault dialog: Confirm passphrase field changed
+    PasswordVaultConfirmChanged(String),
+    /// Password vault dialog: Passphrase field changed
+    PasswordVaultPassphraseChanged(String),
+    /// Password vault dialog: Skip button pressed (keep passwords as they are)
+    PasswordVaultSkip,
+    /// Password vault dialog: Create/Unlock button pressed
+    PasswordVaultSubmit,
     /// Connection form: Port field changed
     PortChanged(u16),
     /// Keyboard: Navigate to previous chat tab (Ctrl+Shift+Tab)
@@ -179,12 +285,52 @@ pub enum Message {
     ShowAddBookmark,
     /// Toolbar: Show chat view
     ShowChatView,
+    /// First-run tips: Next pressed on a tip (or Skip on the welcome card)
+    TipDismissed(Tip),
+    /// First-run tips: Skip Tips pressed
+    TipsSkipped,
     /// Bookmark list: Edit button pressed on bookmark
     ShowEditBookmark(Uuid),
+    /// Bookmark list: Drag handle pressed on a bookmark
+    BookmarkDragStarted(Uuid),
+    /// Bookmark list: Dragged bookmark moved over a place it can drop
+    BookmarkDragHovered(BookmarkDropTarget),
+    /// Bookmark list: Folder header pressed (collapse or expand it)
+    BookmarkGroupToggled(Uuid),
+    /// Bookmark list: Connect All chosen on a folder
+    BookmarkGroupConnectAll(Uuid),
+    /// Bookmark list: New Folder button pressed
+    BookmarkGroupNew,
+    /// Bookmark list: Rename chosen on a folder
+    BookmarkGroupRename(Uuid),
+    /// Bookmark list: Delete chosen on a folder (its bookmarks are kept)
+    BookmarkGroupDelete(Uuid),
+    /// Bookmark list: Folder name input changed
+    BookmarkGroupNameChanged(String),
+    /// Bookmark list: Folder name input submitted
+    BookmarkGroupNameSubmitted,
+    /// Bookmark list: Folder name input cancelled (Escape)
+    BookmarkGroupNameCancelled,
     /// Switch to a different chat tab
     SwitchChatTab(ChatTab),
     /// Switch active view to connection by connection_id
     SwitchToConnection(usize),
+    /// Quick switcher: Opened or closed (Ctrl+K)
+    QuickSwitcherToggled,
+    /// Quick switcher: Search input changed
+    QuickSwitcherQueryChanged(String),
+    /// Quick switcher: Down arrow pressed
+    QuickSwitcherNext,
+    /// Quick switcher: Up arrow pressed
+    QuickSwitcherPrevious,
+    /// Quick switcher: Enter pressed (jump to the highlighted match)
+    QuickSwitcherSubmitted,
+    /// Quick switcher: Match clicked, by its place in the list
+    QuickSwitcherChosen(usize),
+    /// Quick switcher: Closed without jumping (Escape or click outside)
+    QuickSwitcherClosed,
+    /// Split view: Divider dragged between chat and the panel
+    SplitResized(pane_grid::ResizeEvent),
     /// Keyboard: Tab key pressed
     TabPressed,
     /// Toolbar: Toggle bookmarks sidebar
@@ -201,6 +347,10 @@ pub enum Message {
     MaxScrollbackChanged(usize),
     /// Settings panel: Chat history retention selected from picker
     ChatHistoryRetentionSelected(crate::config::settings::ChatHistoryRetention),
+    /// Settings panel: User list grouping selected from picker
+    UserListGroupingSelected(crate::config::settings::UserListGrouping),
+    /// Settings panel: User list sort mode selected from picker
+    UserListSortSelected(crate::config::settings::UserListSort),
     /// Settings panel: Clear avatar button pressed
     ClearAvatarPressed,
     /// Settings panel: Connection notifications checkbox toggled
@@ -217,6 +367,12 @@ pub enum Message {
     ShowSecondsToggled(bool),
     /// Settings panel: Show timestamps checkbox toggled
     ShowTimestampsToggled(bool),
+    /// Settings panel: Spell check checkbox toggled
+    SpellCheckToggled(bool),
+    /// Settings panel: Send read receipts checkbox toggled
+    ReadReceiptsToggled(bool),
+    /// Settings panel: Split view checkbox toggled
+    SplitViewToggled(bool),
     /// Settings panel: Tab selected
     SettingsTabSelected(SettingsTab),
     /// Settings panel (Events tab): Event type selected from picker
@@ -247,6 +403,32 @@ pub enum Message {
     EventAlwaysPlaySoundToggled(bool),
     /// Settings panel (Events tab): Test sound button pressed
     TestSound,
+    /// Settings panel (Events tab): Bookmark selected for notification rules
+    NotificationRulesBookmarkSelected(Uuid),
+    /// Settings panel (Events tab): Channel override input changed
+    NotificationRulesChannelChanged(String),
+    /// Settings panel (Events tab): Add channel override pressed
+    NotificationRulesChannelAdd,
+    /// Settings panel (Events tab): Channel override level selected (channel, level)
+    NotificationRulesChannelLevelSelected(String, ChannelNotification),
+    /// Settings panel (Events tab): Remove channel override pressed
+    NotificationRulesChannelRemove(String),
+    /// Settings panel (Events tab): Keyword input changed
+    NotificationRulesKeywordChanged(String),
+    /// Settings panel (Events tab): Add keyword pressed
+    NotificationRulesKeywordAdd,
+    /// Settings panel (Events tab): Remove keyword pressed
+    NotificationRulesKeywordRemove(String),
+    /// Settings panel (Events tab): Do not disturb toggled
+    DoNotDisturbToggled(bool),
+    /// Settings panel (Events tab): Quiet hours schedule toggled
+    QuietHoursToggled(bool),
+    /// Settings panel (Events tab): Quiet hours start selected
+    QuietHoursStartSelected(QuietHour),
+    /// Settings panel (Events tab): Quiet hours end selected
+    QuietHoursEndSelected(QuietHour),
+    /// Time to check whether quiet hours started or ended
+    QuietHoursTick,
     /// Toolbar: Toggle Settings panel
     ToggleSettings,
     /// Settings panel: Theme selected from picker
@@ -271,6 +453,8 @@ pub enum Message {
     CloseConnectionMonitor,
     /// Connection Monitor panel: Refresh button pressed
     RefreshConnectionMonitor,
+    /// Connection Monitor: Periodic refresh while the transfers tab is open
+    ConnectionMonitorTick,
     /// Connection Monitor panel: Response received from server
     ConnectionMonitorResponse {
         connection_id: usize,
@@ -293,8 +477,46 @@ pub enum Message {
     ConnectionMonitorTabSelected(crate::types::ConnectionMonitorTab),
     /// Connection monitor: Sort transfers by column
     ConnectionMonitorTransferSortBy(crate::types::TransferSortColumn),
+    /// Toolbar: Toggle Server Log panel
+    ToggleServerLog,
+    /// Server Log panel: Close button pressed
+    CloseServerLog,
+    /// Server Log panel: Minimum level selected
+    ServerLogLevelSelected(crate::types::ServerLogLevel),
+    /// Server Log panel: Module filter changed
+    ServerLogModuleFilterChanged(String),
     /// Toolbar: Show Server Info panel
     ShowServerInfo,
+    /// Server info panel: Statistics button pressed
+    ShowStatistics,
+    /// Statistics panel: Close button pressed
+    CloseStatistics,
+    /// Statistics panel: Report length selected
+    StatisticsRangeSelected(crate::types::StatsRange),
+    /// Statistics panel: Chart metric selected
+    StatisticsMetricSelected(crate::types::StatsMetric),
+    /// Snippet panel: Close button pressed
+    CloseSnippet,
+    /// Snippet panel: Copy button pressed
+    CopySnippet,
+    /// Server info panel: Dashboard button pressed
+    ShowAdminDashboard,
+    /// Admin dashboard panel: Close button pressed
+    CloseAdminDashboard,
+    /// Admin dashboard panel: Refresh button pressed
+    RefreshAdminDashboard,
+    /// Admin dashboard panel: Auto-refresh timer fired
+    AdminDashboardTick,
+    /// Server info panel: Doors button pressed
+    ShowDoors,
+    /// Doors panel: Close button pressed
+    CloseDoors,
+    /// Doors panel: Door picked from the list
+    DoorOpen(String),
+    /// Doors panel: Leave button pressed (ends a running door)
+    DoorLeave,
+    /// Doors panel: Key pressed while a door is running (terminal bytes)
+    DoorInput(Vec<u8>),
     /// Settings panel: Use 24-hour time format toggled
     Use24HourTimeToggled(bool),
     /// Toolbar: Toggle user list sidebar
@@ -321,8 +543,20 @@ pub enum Message {
     UserManagementConfirmDelete,
     /// User management: Cancel delete (close modal)
     UserManagementCancelDelete,
+    /// User management: "Erase personal data" toggled in delete modal
+    UserManagementEraseToggled(bool),
     /// User management: Create new user button clicked (switch to create form)
     UserManagementShowCreate,
+    /// User management: Permission audit button clicked (switch to audit view)
+    UserManagementShowAudit,
+    /// User management: Audit view - permission picked (list its holders)
+    UserManagementAuditPermissionSelected(crate::types::AuditPermission),
+    /// User management: Audit view - user clicked (show effective permissions)
+    UserManagementAuditUserClicked(String),
+    /// User management: Audit view - "View as user" clicked (switch to impersonation view)
+    UserManagementImpersonate(String),
+    /// User management: Impersonation view - folder clicked (list it as the user)
+    UserManagementImpersonateOpenFolder(String),
     /// User management: Edit form - new username field changed
     UserManagementEditUsernameChanged(String),
     /// User management: Edit form - new password field changed
@@ -356,8 +590,30 @@ pub enum Message {
     UserListItemClicked(String),
     /// User list: Message icon clicked on expanded user (nickname)
     UserMessageIconClicked(String),
+    /// User list: Filter text changed
+    UserListFilterChanged(String),
+    /// User list: Focus the filter (Cmd/Ctrl+U)
+    UserListFilterFocus,
+    /// User list: Clear the filter and return to the chat input (Escape)
+    UserListFilterCleared,
+    /// User list: Select the next user (Down)
+    UserListSelectNext,
+    /// User list: Select the previous user (Up)
+    UserListSelectPrevious,
+    /// User list: Message the selected user (Enter)
+    UserListMessageSelected,
+    /// User list: Show info for the selected user (Shift+Enter)
+    UserListInfoSelected,
+    /// User list: Kick or ban the selected user (Cmd/Ctrl+Enter)
+    UserListDisconnectSelected,
+    /// User list: Section header clicked (section key)
+    UserListGroupToggled(String),
+    /// User list: Friend icon clicked on expanded user (username)
+    UserFriendToggled(String),
     /// Connection form: Username field changed
     UsernameChanged(String),
+    /// Connection form: Single sign-on checkbox toggled
+    UseSsoToggled(bool),
     /// Broadcast: Validate broadcast form (on Enter when empty)
     ValidateBroadcast,
     /// User management: Validate create user form (on Enter when form incomplete)
@@ -370,6 +626,8 @@ pub enum Message {
     WindowFocused,
     /// Window: Lost focus
     WindowUnfocused,
+    /// Window: Main window resized
+    WindowResized(iced::Size),
     /// Window: Save settings and close (internal - after querying size and position)
     WindowSaveAndClose {
         id: iced::window::Id,
@@ -398,12 +656,26 @@ pub enum Message {
     NewsBodyAction(text_editor::Action),
     /// News: Pick image button pressed (create or edit)
     NewsPickImagePressed,
-    /// News: Image loaded from file picker (create or edit)
+    /// News: Image loaded from file picker, added as an attachment (create or edit)
     NewsImageLoaded(Result<String, ImagePickerError>),
-    /// News: Clear image button pressed (create or edit)
-    NewsClearImagePressed,
+    /// News: File path input changed (create or edit)
+    NewsFilePathChanged(String),
+    /// News: Add file button pressed, adds the file path as an attachment (create or edit)
+    NewsAddFilePressed,
+    /// News: Attachment caption changed (attachment index, caption)
+    NewsAttachmentCaptionChanged(usize, String),
+    /// News: Remove attachment button pressed (attachment index)
+    NewsRemoveAttachment(usize),
+    /// News: File attachment clicked in a post (opens it in the Files panel)
+    NewsOpenFile(String),
     /// News: Submit button pressed (create or edit)
     NewsSubmitPressed,
+    /// News: Search input text changed
+    NewsSearchInputChanged(String),
+    /// News: Search submitted (Enter or search button)
+    NewsSearchSubmit,
+    /// News: Clear search button pressed (show all news again)
+    NewsSearchClear,
 
     // ==================== Files Panel ====================
     /// Toolbar: Toggle Files panel
@@ -440,6 +712,14 @@ pub enum Message {
     FileInfoClicked(String),
     /// Files: Close file info dialog
     CloseFileInfo,
+    /// Files: Star clicked in the file info dialog (rating 1-5, 0 clears)
+    FileRate(u8),
+    /// Files: Comment input changed in the file info dialog
+    FileCommentInputChanged(String),
+    /// Files: Post comment button pressed in the file info dialog
+    FileCommentSubmit,
+    /// Files: Delete clicked on a file comment (comment ID)
+    FileCommentDelete(i64),
     /// Files: Rename clicked from context menu (path to rename)
     FileRenameClicked(String),
     /// Files: Rename name input changed
@@ -448,6 +728,20 @@ pub enum Message {
     FileRenameSubmit,
     /// Files: Rename cancel button pressed (close dialog)
     FileRenameCancel,
+    /// Files: Upload Rules clicked from context menu (directory name, admin only)
+    FileUploadRuleClicked(String),
+    /// Files: Allowed extensions input changed in the Upload Rules dialog
+    FileUploadRuleExtensionsChanged(String),
+    /// Files: Largest file input changed in the Upload Rules dialog (MB)
+    FileUploadRuleMaxFileSizeChanged(String),
+    /// Files: Largest upload input changed in the Upload Rules dialog (MB)
+    FileUploadRuleMaxTotalSizeChanged(String),
+    /// Files: Save button pressed in the Upload Rules dialog
+    FileUploadRuleSave,
+    /// Files: Remove Rule button pressed in the Upload Rules dialog
+    FileUploadRuleRemove,
+    /// Files: Cancel button pressed in the Upload Rules dialog (close dialog)
+    FileUploadRuleCancel,
     /// Files: Cut clicked from context menu (path, name)
     FileCut(String, String),
     /// Files: Copy clicked from context menu (path, name)
@@ -476,12 +770,74 @@ pub enum Message {
     FileDownload(String),
     /// Files: Download directory (from context menu or toolbar)
     FileDownloadAll(String),
+    /// Files: Download to a chosen folder (path, is_directory) - opens the dialog
+    FileDownloadTo(String, bool),
+    /// Files: Download To dialog - Browse button pressed
+    FileDownloadToBrowse,
+    /// Files: Download To dialog - folder picker returned (None if cancelled)
+    FileDownloadToFolderSelected(Option<String>),
+    /// Files: Download To dialog - "Remember for this server" toggled
+    FileDownloadToRememberToggled(bool),
+    /// Files: Download To dialog - collision policy selected for this download
+    FileDownloadToCollisionPolicySelected(crate::config::settings::CollisionPolicy),
+    /// Files: Download To dialog - Download button pressed
+    FileDownloadToConfirm,
+    /// Files: Download To dialog - Cancel button pressed
+    FileDownloadToCancel,
+    /// Files: Download selected files from a directory - opens the selection dialog
+    FileDownloadSelect(String),
+    /// Files: Download Selected dialog - file or folder checkbox toggled (manifest path)
+    FileDownloadSelectToggled(String, bool),
+    /// Files: Download Selected dialog - select all (true) or none (false)
+    FileDownloadSelectAll(bool),
+    /// Files: Download Selected dialog - Download button pressed
+    FileDownloadSelectConfirm,
+    /// Files: Download Selected dialog - Cancel button pressed
+    FileDownloadSelectCancel,
+    /// Files: Local files were hashed for a download's duplicate check (connection_id, tab_id, check ID, paths and hashes)
+    FileDownloadScanned(usize, TabId, Uuid, Vec<(std::path::PathBuf, String)>),
+    /// Files: Duplicate download dialog - Download Anyway button pressed
+    FileDownloadDuplicateConfirm,
+    /// Files: Duplicate download dialog - Skip button pressed
+    FileDownloadDuplicateSkip,
     /// Files: Upload file(s) to a path (opens file picker)
     FileUpload(String),
     /// Files: File picker was cancelled (no-op, keeps panel open)
     FileUploadCancelled,
     /// Files: File picker returned selected files for upload
     FileUploadSelected(String, Vec<std::path::PathBuf>),
+    /// Files: Files selected for upload were scanned (connection_id, tab_id, root mode, scan)
+    FileUploadScanned(usize, TabId, bool, crate::transfers::UploadScan),
+    /// Files: Local files were hashed for the upload preview (connection_id, tab_id, preview ID, hashes by file index)
+    FileUploadHashed(usize, TabId, Uuid, Vec<(usize, Option<String>)>),
+    /// Files: Upload preview - Skip Existing (false) or Overwrite (true) pressed
+    FileUploadPreviewConfirm(bool),
+    /// Files: Upload preview - Cancel button pressed
+    FileUploadPreviewCancel,
+    /// Files: Watch a local folder and auto-upload its new files to a path (opens folder picker)
+    FileWatchFolder(String),
+    /// Files: Folder picker returned (remote path, local folder or None if cancelled)
+    FileWatchFolderSelected(String, Option<std::path::PathBuf>),
+    /// Files: Stop watching the bookmark's local upload folder
+    FileUnwatchFolder,
+    /// Files: Upload shared screenshots into a folder
+    FileScreenshotFolder(String),
+    /// Files: Time to scan watched upload folders
+    UploadWatchTick,
+    /// Files: Watched upload folder scan finished (connection_id, scan result)
+    UploadWatchScanned(usize, crate::transfers::WatchScan),
+    /// Files: Download new files from a folder automatically (opens the Auto-Download dialog)
+    FileAutoDownload(String),
+    /// Files: Auto-Download dialog - file name pattern changed
+    FileAutoDownloadPatternChanged(String),
+    /// Files: Auto-Download dialog - Save button pressed
+    FileAutoDownloadSave,
+    /// Files: Auto-Download dialog - Stop Auto-Downloading button pressed
+    FileAutoDownloadRemove,
+    /// Files: Auto-Download dialog - Cancel button pressed
+    FileAutoDownloadCancel,
+    /// Files: Time to check auto-download folders for new files
+    AutoDownloadTick,
     /// Files: File being dragged over window (drag-and-drop)
     FileDragHovered,
     /// Files: File dropped on window (drag-and-drop)
@@ -505,6 +861,14 @@ pub enum Message {
     /// Files: Search results sort by column clicked
     FileSearchSortBy(FileSortColumn),
 
+    // ==================== File Highlights ====================
+    /// Files: Highlights link clicked (show new and most downloaded files)
+    FileShowHighlights,
+    /// Files: Highlighted file clicked (opens its folder in a new tab)
+    FileHighlightOpen(FileActivity),
+    /// Files: Highlighted file download button clicked
+    FileHighlightDownload(FileActivity),
+
     // ==================== Files Settings ====================
     /// Settings panel: Browse download path button pressed
     BrowseDownloadPathPressed,
@@ -516,6 +880,28 @@ pub enum Message {
     DownloadLimitChanged(u8),
     /// Settings panel: Upload limit changed
     UploadLimitChanged(u8),
+    /// Settings panel: Auto-open folder on download complete toggled
+    AutoOpenFolderToggled(bool),
+    /// Settings panel: File collision policy selected
+    CollisionPolicySelected(crate::config::settings::CollisionPolicy),
+
+    // ==================== Security Settings ====================
+    /// Settings panel: Require approval for new certificates toggled
+    RequireFingerprintApprovalToggled(bool),
+    /// Settings panel: Show or hide a known server's certificate details
+    KnownServerDetailsToggled(Uuid),
+    /// Settings panel: Forget a known server's certificate
+    KnownServerRemovePressed(Uuid),
+    /// Settings panel: Export a known server's certificate to a file
+    KnownServerExportPressed(Uuid),
+    /// Settings panel: Certificate export finished (Err = error message)
+    KnownServerExported(Result<(), String>),
+    /// Settings panel: Config encryption checkbox toggled
+    ConfigEncryptionToggled(bool),
+    /// Settings panel: Config encryption passphrase field changed
+    ConfigPassphraseChanged(String),
+    /// Settings panel: Config encryption passphrase confirmation changed
+    ConfigPassphraseConfirmChanged(String),
 
     // ==================== Proxy Settings ====================
     /// Settings panel: Proxy enabled checkbox toggled
@@ -542,6 +928,16 @@ pub enum Message {
     TransferRemove(Uuid),
     /// Transfer: Open the folder containing a transfer's local path
     TransferOpenFolder(Uuid),
+    /// Reveal a completed transfer's file in the system file manager
+    TransferReveal(Uuid),
+    /// Open a completed transfer's file with the default application
+    TransferOpenFile(Uuid),
+    /// Compute the SHA-256 of a completed transfer's file and copy it
+    TransferCopyChecksum(Uuid),
+    /// SHA-256 computation finished (hex digest or error message)
+    TransferChecksumComputed(Result<String, String>),
+    /// Transfer: Resolve a pending collision with the chosen policy
+    TransferResolveCollision(Uuid, crate::config::settings::CollisionPolicy),
     /// Transfer: Clear all inactive (completed and failed) transfers
     TransferClearInactive,
     /// Transfer: Move a queued transfer up (higher priority)
@@ -550,6 +946,40 @@ pub enum Message {
     TransferMoveDown(Uuid),
     /// Transfer: Retry a failed transfer (re-queue)
     TransferRetry(Uuid),
+    /// Transfer history: Switch the Transfers panel between queue and history
+    TransferHistoryToggle,
+    /// Transfer history: Search input changed
+    TransferHistorySearchChanged(String),
+    /// Transfer history: Open a finished transfer's location in the file manager
+    TransferHistoryReveal(Uuid),
+    /// Transfer history: Forget a single record
+    TransferHistoryRemove(Uuid),
+    /// Transfer history: Forget every record
+    TransferHistoryClear,
+
+    // ==================== Screenshots ====================
+    /// Screenshot: Capture finished (connection_id, target tab, screenshot or None if cancelled)
+    ScreenshotCaptured(usize, ChatTab, Result<Option<Screenshot>, String>),
+    /// Screenshot: Annotation tool selected
+    ScreenshotToolSelected(Tool),
+    /// Screenshot: Pointer moved over the preview (preview coordinates)
+    ScreenshotPointerMoved(iced::Point),
+    /// Screenshot: Pointer pressed on the preview (starts a crop or arrow)
+    ScreenshotPointerPressed,
+    /// Screenshot: Pointer released or left the preview (applies the crop or arrow)
+    ScreenshotPointerReleased,
+    /// Screenshot: Undo the last edit
+    ScreenshotUndo,
+    /// Screenshot: Cancel button pressed
+    ScreenshotCancel,
+    /// Screenshot: Send button pressed (save, upload, then post a link)
+    ScreenshotSend,
+    /// Screenshot: PNG written for upload (connection_id, local path or error)
+    ScreenshotSaved(usize, Result<std::path::PathBuf, String>),
+
+    // ==================== Chat Export ====================
+    /// Chat export: File written (connection_id, false if the dialog was cancelled, or error)
+    ChatExported(usize, Result<bool, String>),
 
     // ==================== Voice ====================
     /// Voice: Join voice for a channel or user message
@@ -558,11 +988,9 @@ pub enum Message {
     VoiceLeavePressed,
     /// Voice: Event from voice session (DTLS connected, speaking, etc.)
     VoiceSessionEvent(usize, VoiceEvent),
-    /// Voice: PTT state changed (called internally from VoicePttEvent handler)
+    /// Voice: PTT state changed (called internally from GlobalHotkeyEvent handler)
     #[allow(dead_code)] // Constructed indirectly via handle_voice_ptt_state_changed
     VoicePttStateChanged(PttState),
-    /// Voice: Raw PTT hotkey event (forwarded from global hotkey subscription)
-    VoicePttEvent(GlobalHotKeyEvent),
     /// Voice: PTT release delay timer expired (time to actually stop transmitting)
     /// Contains generation counter to detect if PTT was pressed again during delay
     VoicePttReleaseDelayExpired(u64),
@@ -574,6 +1002,10 @@ pub enum Message {
     VoiceDeafenToggle,
     /// Voice: VU meter tick (triggers UI update when transmitting)
     VoiceMeterTick,
+    /// Voice: Periodic check to expire voice text overlay messages
+    VoiceTextTick,
+    /// Voice: Soundboard clip file loaded for upload (connection_id, clip name, data URI)
+    VoiceClipFileLoaded(usize, String, Result<String, ImagePickerError>),
 
     // ==================== Audio Settings ====================
     /// Audio: Refresh device list (re-enumerate audio devices)
@@ -613,6 +1045,10 @@ pub enum Message {
     AudioTransientSuppression(bool),
     /// Audio: Change microphone boost level
     AudioMicBoost(crate::config::audio::MicBoost),
+    /// Audio: Soundboard clip volume slider changed
+    AudioClipVolumeChanged(f32),
+    /// Audio: Toggle reading voice text messages aloud
+    AudioVoiceTextTts(bool),
 
     // ==================== Toasts ====================
     /// Toast: Dismiss a toast notification
@@ -620,6 +1056,34 @@ pub enum Message {
     /// Toast: Show a toast notification (triggered after async operations like clipboard write)
     ShowToast(String),
 
+    // ==================== Global Hotkeys ====================
+    /// Hotkeys: Register the show/hide window hotkey from settings (queued at startup)
+    WindowHotkeyRegister,
+    /// Hotkeys: Raw global-hotkey event (PTT or show/hide window)
+    GlobalHotkeyEvent(GlobalHotKeyEvent),
+    /// Hotkeys: Portal shortcut pressed (true) or released (false) (Wayland only)
+    #[cfg(target_os = "linux")]
+    GlobalShortcut(Shortcut, bool),
+    /// Hotkeys: Shortcuts portal unavailable, fall back to global-hotkey (Wayland only)
+    #[cfg(target_os = "linux")]
+    GlobalShortcutsUnavailable(String),
+    /// Settings: Enter show/hide window key capture mode
+    WindowKeyCapture,
+    /// Settings: Show/hide window key captured
+    WindowKeyCaptured(String),
+    /// Settings: Clear the show/hide window key
+    WindowKeyCleared,
+
+    // ==================== Auto Away ====================
+    /// Settings: Auto away toggled
+    AutoAwayToggled(bool),
+    /// Settings: Auto away idle minutes changed
+    AutoAwayMinutesChanged(u16),
+    /// Time to check how long input has been idle
+    IdleTick,
+    /// System idle time read (None = not available on this system)
+    IdleTimeChecked(Option<std::time::Duration>),
+
     // ==================== System Tray (Windows/Linux only) ====================
     /// Tray: Periodic poll for tray events (also pumps GTK on Linux)
     #[cfg(not(target_os = "macos"))]
@@ -661,6 +1125,17 @@ pub enum Message {
     #[cfg(target_os = "linux")]
     TrayServiceClosed,
 
+    // ==================== Notification Actions (macOS/Windows only) ====================
+    /// Notification: Clicked, bring the window forward (and open its conversation)
+    #[cfg(any(target_os = "macos", target_os = "windows"))]
+    NotificationActivated(Option<NotificationTarget>),
+    /// Notification: Reply typed into a notification's reply field
+    #[cfg(any(target_os = "macos", target_os = "windows"))]
+    NotificationReply(NotificationTarget, String),
+    /// Notification: "Mark read" clicked
+    #[cfg(any(target_os = "macos", target_os = "windows"))]
+    NotificationMarkRead(NotificationTarget),
+
     // ==================== URI Scheme ====================
     /// URI: Handle a nexus:// URI (from startup arg or IPC)
     HandleNexusUri(NexusUri),
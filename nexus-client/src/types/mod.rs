@@ -4,33 +4,51 @@ mod bookmark;
 mod channel;
 pub mod connection;
 mod display;
+mod health;
 mod message;
 mod panel;
 mod pending;
+mod server_clock;
 mod ui;
 mod view_config;
 mod voice;
 
 // Re-export types for convenience
-pub use bookmark::{BookmarkEditMode, BookmarkEditState, ServerBookmark};
-pub use channel::ChannelState;
+pub use bookmark::{
+    AutoDownloadRule, BookmarkDropTarget, BookmarkEditMode, BookmarkEditState, BookmarkGroup,
+    BookmarkWorkspace, ScreenshotFolder, ServerBookmark, UploadWatch,
+};
+pub use channel::{ChannelState, GroupState};
 pub use connection::{
     ConnectionInfo, NetworkConnection, ServerConnection, ServerConnectionParams, TabCompletionState,
 };
-pub use display::{ChatMessage, ChatTab, MessageType, ScrollState, UserInfo};
+pub use display::{
+    ChatMessage, ChatTab, DeliveryState, MessageType, QueuedMessage, ScrollState, UnackedMessage,
+    UserInfo,
+};
+pub use health::{ConnectionHealth, HealthLevel};
 pub use message::Message;
 pub use nexus_common::protocol::ServerMessage;
 pub use panel::{
-    BanDuration, ClipboardItem, ClipboardOperation, ConnectionFormState,
-    ConnectionMonitorSortColumn, ConnectionMonitorState, ConnectionMonitorTab, DisconnectAction,
-    DisconnectDialogState, FileSortColumn, FileTab, FilesManagementState, NewsManagementMode,
-    NewsManagementState, PasswordChangeState, PendingOverwrite, ServerInfoEditState,
-    ServerInfoParams, ServerInfoTab, SettingsFormState, SettingsTab, TabId, TransferSortColumn,
-    UserManagementMode, UserManagementState,
+    AdminDashboardState, AuditPermission, BanDuration, BroadcastDelay, BroadcastTargetKind,
+    ClipboardItem, ClipboardOperation, ConnectionFormState, ConnectionMonitorSortColumn,
+    ConnectionMonitorState, ConnectionMonitorTab, ConnectivityCheckState, DOOR_COLUMNS, DOOR_ROWS,
+    DisconnectAction, DisconnectDialogState, DoorSession, DoorsState, FileHighlights, FileReview,
+    FileSortColumn, FileTab, FilesManagementState, Impersonation, LanServer, NewsFormAttachment,
+    NewsManagementMode, NewsManagementState, NewsSearch, PasswordChangeState, PendingAutoDownload,
+    PendingBroadcastAck, PendingDownloadCheck, PendingDownloadSelect, PendingDownloadTo,
+    PendingOverwrite, PendingUploadPreview, PendingUploadRule, PermissionAuditResult,
+    PlannedUpload, ScreenshotEditorState, ScreenshotShare, ServerInfoEditState, ServerInfoParams,
+    ServerInfoTab, ServerLogLevel, ServerLogState, SettingsFormState, SettingsTab,
+    SnippetViewState, StatisticsState, StatsMetric, StatsRange, TabId, TransferSortColumn,
+    UploadFileStatus, UserManagementMode, UserManagementState, door_key_bytes,
 };
-pub use pending::{PendingRequests, ResponseRouting};
+pub use pending::{PendingRequests, ResponseRouting, TrackedRequests};
+pub use server_clock::ServerClock;
 pub use ui::{
-    ActivePanel, FingerprintMismatch, FingerprintMismatchDetails, InputId, ScrollableId, UiState,
+    ActivePanel, BookmarkDrag, BookmarkGroupEdit, ChatWindow, FingerprintMismatch,
+    FingerprintMismatchDetails, InputId, PasswordVaultDialog, PasswordVaultMode,
+    QuickSwitcherState, ScrollableId, SpellField, SpellMenu, SplitLayout, SplitPane, UiState,
 };
 pub use view_config::{ToolbarState, ViewConfig};
-pub use voice::VoiceState;
+pub use voice::{VoiceParticipantStats, VoiceState, VoiceText};
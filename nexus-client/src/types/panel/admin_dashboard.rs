@@ -0,0 +1,19 @@
+//! Admin dashboard panel state
+
+use chrono::{DateTime, Local};
+use nexus_common::protocol::{DailyStats, ServerStatsInfo};
+
+/// State for the admin dashboard panel
+#[derive(Debug, Clone, Default)]
+pub struct AdminDashboardState {
+    /// Server health from the last ServerStatsResponse
+    pub stats: Option<ServerStatsInfo>,
+    /// Today's activity from the last StatsResponse
+    pub today: Option<DailyStats>,
+    /// Waiting for a ServerStatsResponse
+    pub loading: bool,
+    /// Error from the last request
+    pub error: Option<String>,
+    /// When the last ServerStatsResponse arrived
+    pub updated_at: Option<DateTime<Local>>,
+}
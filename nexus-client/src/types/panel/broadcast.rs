@@ -0,0 +1,122 @@
+//! Broadcast panel state (target, delay) and broadcasts awaiting acknowledgement
+
+use nexus_common::protocol::BroadcastTarget;
+
+// =============================================================================
+// Broadcast Composer
+// =============================================================================
+
+/// Who a broadcast goes to
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BroadcastTargetKind {
+    /// Everyone online
+    #[default]
+    All,
+    /// Members of a channel
+    Channel,
+    /// Users with a permission
+    Permission,
+}
+
+impl BroadcastTargetKind {
+    /// Get all target options for the dropdown
+    pub fn all() -> &'static [BroadcastTargetKind] {
+        &[
+            BroadcastTargetKind::All,
+            BroadcastTargetKind::Channel,
+            BroadcastTargetKind::Permission,
+        ]
+    }
+
+    /// Get the translation key for this target
+    pub fn translation_key(&self) -> &'static str {
+        match self {
+            BroadcastTargetKind::All => "broadcast-target-all",
+            BroadcastTargetKind::Channel => "broadcast-target-channel",
+            BroadcastTargetKind::Permission => "broadcast-target-permission",
+        }
+    }
+
+    /// Build the protocol target, or None if the channel or permission isn't chosen yet
+    pub fn to_target(
+        self,
+        channel: Option<&String>,
+        permission: Option<&String>,
+    ) -> Option<BroadcastTarget> {
+        match self {
+            BroadcastTargetKind::All => Some(BroadcastTarget::All),
+            BroadcastTargetKind::Channel => channel.map(|channel| BroadcastTarget::Channel {
+                channel: channel.clone(),
+            }),
+            BroadcastTargetKind::Permission => {
+                permission.map(|permission| BroadcastTarget::Permission {
+                    permission: permission.clone(),
+                })
+            }
+        }
+    }
+}
+
+/// Pre-defined broadcast delay options
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BroadcastDelay {
+    /// Send right away
+    #[default]
+    Now,
+    /// 5 minutes
+    FiveMinutes,
+    /// 15 minutes
+    FifteenMinutes,
+    /// 1 hour
+    OneHour,
+    /// 1 day
+    OneDay,
+}
+
+impl BroadcastDelay {
+    /// Get the duration string to send to the server
+    pub fn as_duration_string(self) -> Option<String> {
+        match self {
+            BroadcastDelay::Now => None,
+            BroadcastDelay::FiveMinutes => Some("5m".to_string()),
+            BroadcastDelay::FifteenMinutes => Some("15m".to_string()),
+            BroadcastDelay::OneHour => Some("1h".to_string()),
+            BroadcastDelay::OneDay => Some("1d".to_string()),
+        }
+    }
+
+    /// Get all delay options for the dropdown
+    pub fn all() -> &'static [BroadcastDelay] {
+        &[
+            BroadcastDelay::Now,
+            BroadcastDelay::FiveMinutes,
+            BroadcastDelay::FifteenMinutes,
+            BroadcastDelay::OneHour,
+            BroadcastDelay::OneDay,
+        ]
+    }
+
+    /// Get the translation key for this delay
+    pub fn translation_key(&self) -> &'static str {
+        match self {
+            BroadcastDelay::Now => "broadcast-delay-now",
+            BroadcastDelay::FiveMinutes => "broadcast-delay-5m",
+            BroadcastDelay::FifteenMinutes => "broadcast-delay-15m",
+            BroadcastDelay::OneHour => "broadcast-delay-1h",
+            BroadcastDelay::OneDay => "broadcast-delay-1d",
+        }
+    }
+}
+
+// =============================================================================
+// Acknowledgement
+// =============================================================================
+
+/// A received broadcast whose sender asked for acknowledgement
+#[derive(Debug, Clone)]
+pub struct PendingBroadcastAck {
+    /// ID to acknowledge with
+    pub broadcast_id: u64,
+    /// Who sent the broadcast
+    pub username: String,
+}
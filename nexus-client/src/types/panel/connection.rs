@@ -6,6 +6,19 @@ use nexus_common::DEFAULT_PORT;
 // Connection Form State
 // =============================================================================
 
+/// A server advertising itself on the local network (mDNS/DNS-SD)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LanServer {
+    /// Full DNS-SD service name, unique per server
+    pub fullname: String,
+    /// Display name from the TXT record
+    pub name: String,
+    /// Address to connect to
+    pub address: String,
+    /// BBS port
+    pub port: u16,
+}
+
 /// Connection form state (not persisted)
 #[derive(Clone)]
 pub struct ConnectionFormState {
@@ -27,6 +40,10 @@ pub struct ConnectionFormState {
     pub is_connecting: bool,
     /// Whether to save this connection as a bookmark on successful connect
     pub add_bookmark: bool,
+    /// Whether to sign in through the server's identity provider
+    pub use_sso: bool,
+    /// Servers found on the local network, sorted by name
+    pub lan_servers: Vec<LanServer>,
 }
 
 impl Default for ConnectionFormState {
@@ -41,6 +58,8 @@ impl Default for ConnectionFormState {
             error: None,
             is_connecting: false,
             add_bookmark: false,
+            use_sso: false,
+            lan_servers: Vec::new(),
         }
     }
 }
@@ -57,11 +76,26 @@ impl std::fmt::Debug for ConnectionFormState {
             .field("error", &self.error)
             .field("is_connecting", &self.is_connecting)
             .field("add_bookmark", &self.add_bookmark)
+            .field("use_sso", &self.use_sso)
+            .field("lan_servers", &self.lan_servers)
             .finish()
     }
 }
 
 impl ConnectionFormState {
+    /// Add or update a server found on the local network
+    pub fn add_lan_server(&mut self, server: LanServer) {
+        self.lan_servers.retain(|s| s.fullname != server.fullname);
+        self.lan_servers.push(server);
+        self.lan_servers
+            .sort_by_key(|s| (s.name.to_lowercase(), s.fullname.clone()));
+    }
+
+    /// Remove a server that left the local network
+    pub fn remove_lan_server(&mut self, fullname: &str) {
+        self.lan_servers.retain(|s| s.fullname != fullname);
+    }
+
     /// Clear all form fields
     pub fn clear(&mut self) {
         self.server_name.clear();
@@ -70,5 +104,6 @@ impl ConnectionFormState {
         self.username.clear();
         self.password.clear();
         self.nickname.clear();
+        self.use_sso = false;
     }
 }
@@ -0,0 +1,273 @@
+//! Doors panel state
+//!
+//! Doors are programs the server runs on a pseudo-terminal. The client keeps
+//! an emulated screen fed with the door's output and turns key presses into
+//! the bytes a terminal would send.
+
+use std::sync::{Arc, Mutex};
+
+use iced::keyboard::{Key, Modifiers, key::Named};
+
+/// Door terminal width
+pub const DOOR_COLUMNS: u16 = 80;
+
+/// Door terminal height
+pub const DOOR_ROWS: u16 = 24;
+
+/// A door shown in the panel
+#[derive(Clone)]
+pub struct DoorSession {
+    /// Door name
+    pub name: String,
+    /// Emulated screen fed with the door's output
+    ///
+    /// Shared so the connection state stays cheap to clone.
+    pub screen: Arc<Mutex<vt100::Parser>>,
+    /// Waiting for the DoorOpenResponse
+    pub opening: bool,
+    /// Program is running (until DoorClosed)
+    pub running: bool,
+    /// Exit code from DoorClosed (None while running or if unknown)
+    pub exit_code: Option<u32>,
+}
+
+impl DoorSession {
+    /// Start showing a door that is being opened
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            screen: Arc::new(Mutex::new(vt100::Parser::new(DOOR_ROWS, DOOR_COLUMNS, 0))),
+            opening: true,
+            running: true,
+            exit_code: None,
+        }
+    }
+
+    /// Feed door output to the screen
+    pub fn process(&self, data: &[u8]) {
+        if let Ok(mut screen) = self.screen.lock() {
+            screen.process(data);
+        }
+    }
+
+    /// Whether the door asked for application cursor keys
+    pub fn application_cursor(&self) -> bool {
+        self.screen
+            .lock()
+            .map(|screen| screen.screen().application_cursor())
+            .unwrap_or(false)
+    }
+}
+
+impl std::fmt::Debug for DoorSession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DoorSession")
+            .field("name", &self.name)
+            .field("opening", &self.opening)
+            .field("running", &self.running)
+            .field("exit_code", &self.exit_code)
+            .finish_non_exhaustive()
+    }
+}
+
+/// State for the doors panel
+#[derive(Debug, Clone, Default)]
+pub struct DoorsState {
+    /// Door names from the last DoorListResponse
+    pub doors: Vec<String>,
+    /// Waiting for a DoorListResponse
+    pub loading: bool,
+    /// Error from the last request
+    pub error: Option<String>,
+    /// Door being played (None shows the door list)
+    pub session: Option<DoorSession>,
+}
+
+impl DoorsState {
+    /// Whether key presses should go to a running door
+    pub fn is_playing(&self) -> bool {
+        self.session.as_ref().is_some_and(|session| session.running)
+    }
+}
+
+/// Bytes a terminal sends for a key press
+///
+/// `text` is the text the key produces (if any). `application_cursor` selects
+/// the cursor key sequences used by full-screen programs. Returns `None` for
+/// keys that send nothing (modifiers alone, Cmd shortcuts).
+pub fn door_key_bytes(
+    key: &Key,
+    modifiers: Modifiers,
+    text: Option<&str>,
+    application_cursor: bool,
+) -> Option<Vec<u8>> {
+    if modifiers.logo() {
+        return None;
+    }
+
+    let bytes: Vec<u8> = match key {
+        Key::Named(named) => {
+            let cursor = |code: u8| {
+                if application_cursor {
+                    vec![0x1b, b'O', code]
+                } else {
+                    vec![0x1b, b'[', code]
+                }
+            };
+            match named {
+                Named::Enter => b"\r".to_vec(),
+                Named::Backspace => vec![0x7f],
+                Named::Tab if modifiers.shift() => b"\x1b[Z".to_vec(),
+                Named::Tab => b"\t".to_vec(),
+                Named::Escape => vec![0x1b],
+                Named::Space if modifiers.control() => vec![0],
+                Named::Space => b" ".to_vec(),
+                Named::ArrowUp => cursor(b'A'),
+                Named::ArrowDown => cursor(b'B'),
+                Named::ArrowRight => cursor(b'C'),
+                Named::ArrowLeft => cursor(b'D'),
+                Named::Home => cursor(b'H'),
+                Named::End => cursor(b'F'),
+                Named::Insert => b"\x1b[2~".to_vec(),
+                Named::Delete => b"\x1b[3~".to_vec(),
+                Named::PageUp => b"\x1b[5~".to_vec(),
+                Named::PageDown => b"\x1b[6~".to_vec(),
+                Named::F1 => b"\x1bOP".to_vec(),
+                Named::F2 => b"\x1bOQ".to_vec(),
+                Named::F3 => b"\x1bOR".to_vec(),
+                Named::F4 => b"\x1bOS".to_vec(),
+                Named::F5 => b"\x1b[15~".to_vec(),
+                Named::F6 => b"\x1b[17~".to_vec(),
+                Named::F7 => b"\x1b[18~".to_vec(),
+                Named::F8 => b"\x1b[19~".to_vec(),
+                Named::F9 => b"\x1b[20~".to_vec(),
+                Named::F10 => b"\x1b[21~".to_vec(),
+                Named::F11 => b"\x1b[23~".to_vec(),
+                Named::F12 => b"\x1b[24~".to_vec(),
+                _ => return None,
+            }
+        }
+        Key::Character(c) if modifiers.control() => {
+            // Ctrl+letter and the other C0 control characters
+            let mut chars = c.chars();
+            let (Some(ch), None) = (chars.next(), chars.next()) else {
+                return None;
+            };
+            match ch.to_ascii_lowercase() {
+                ch @ 'a'..='z' => vec![ch as u8 - b'a' + 1],
+                '@' | '2' => vec![0],
+                '[' | '3' => vec![0x1b],
+                '\\' | '4' => vec![0x1c],
+                ']' | '5' => vec![0x1d],
+                '^' | '6' => vec![0x1e],
+                '_' | '-' | '7' => vec![0x1f],
+                _ => return None,
+            }
+        }
+        _ => text?.as_bytes().to_vec(),
+    };
+
+    if bytes.is_empty() {
+        return None;
+    }
+
+    // Alt sends ESC before the key
+    if modifiers.alt() && bytes[0] != 0x1b {
+        let mut prefixed = vec![0x1b];
+        prefixed.extend(bytes);
+        return Some(prefixed);
+    }
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn character(c: &str) -> Key {
+        Key::Character(c.into())
+    }
+
+    #[test]
+    fn test_text_keys_send_their_text() {
+        assert_eq!(
+            door_key_bytes(&character("a"), Modifiers::empty(), Some("a"), false),
+            Some(b"a".to_vec())
+        );
+        assert_eq!(
+            door_key_bytes(&character("a"), Modifiers::SHIFT, Some("A"), false),
+            Some(b"A".to_vec())
+        );
+        assert_eq!(
+            door_key_bytes(&character("é"), Modifiers::empty(), Some("é"), false),
+            Some("é".as_bytes().to_vec())
+        );
+    }
+
+    #[test]
+    fn test_control_keys() {
+        assert_eq!(
+            door_key_bytes(&character("c"), Modifiers::CTRL, None, false),
+            Some(vec![3])
+        );
+        assert_eq!(
+            door_key_bytes(&character("["), Modifiers::CTRL, None, false),
+            Some(vec![0x1b])
+        );
+        assert_eq!(
+            door_key_bytes(&Key::Named(Named::Enter), Modifiers::empty(), None, false),
+            Some(b"\r".to_vec())
+        );
+        assert_eq!(
+            door_key_bytes(
+                &Key::Named(Named::Backspace),
+                Modifiers::empty(),
+                None,
+                false
+            ),
+            Some(vec![0x7f])
+        );
+    }
+
+    #[test]
+    fn test_cursor_keys_follow_mode() {
+        let up = Key::Named(Named::ArrowUp);
+        assert_eq!(
+            door_key_bytes(&up, Modifiers::empty(), None, false),
+            Some(b"\x1b[A".to_vec())
+        );
+        assert_eq!(
+            door_key_bytes(&up, Modifiers::empty(), None, true),
+            Some(b"\x1bOA".to_vec())
+        );
+    }
+
+    #[test]
+    fn test_alt_prefixes_escape() {
+        assert_eq!(
+            door_key_bytes(&character("x"), Modifiers::ALT, Some("x"), false),
+            Some(b"\x1bx".to_vec())
+        );
+    }
+
+    #[test]
+    fn test_keys_without_bytes() {
+        assert_eq!(
+            door_key_bytes(&Key::Named(Named::Shift), Modifiers::SHIFT, None, false),
+            None
+        );
+        assert_eq!(
+            door_key_bytes(&character("q"), Modifiers::LOGO, Some("q"), false),
+            None
+        );
+    }
+
+    #[test]
+    fn test_process_updates_screen() {
+        let session = DoorSession::new("lord".to_string());
+        session.process(b"Hello\x1b[?1h");
+        assert!(session.application_cursor());
+        let contents = session.screen.lock().unwrap().screen().contents();
+        assert!(contents.starts_with("Hello"));
+    }
+}
@@ -1,17 +1,29 @@
 //! File browser management state
 
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
 use std::sync::atomic::{AtomicU64, Ordering};
 
 use nexus_common::framing::MessageId;
+use nexus_common::protocol::{
+    FileActivity, FileComment, FileManifestEntry, FileStatEntry, FileStatQuery, UploadRule,
+};
+use nexus_common::validators::{self, UploadExtensionsError};
+use uuid::Uuid;
 
-use crate::i18n::t;
+use crate::config::settings::CollisionPolicy;
+use crate::i18n::{t, t_args};
+use crate::transfers::{UploadFile, UploadItem};
+use crate::types::AutoDownloadRule;
 
 // =============================================================================
 // Files Management State
 // =============================================================================
 
 /// Column to sort files by
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Hash, serde::Serialize, serde::Deserialize,
+)]
 pub enum FileSortColumn {
     /// Sort by name (default) - keeps dirs first, sorts within groups
     #[default]
@@ -63,10 +75,632 @@ pub struct PendingOverwrite {
     pub destination_root: bool,
 }
 
+/// Pending "Download To" dialog state
+#[derive(Debug, Clone)]
+pub struct PendingDownloadTo {
+    /// Remote path of the file/directory to download
+    pub remote_path: String,
+    /// Whether the remote path is a directory
+    pub is_directory: bool,
+    /// Root mode the path was selected in
+    pub remote_root: bool,
+    /// Local folder the download will be saved to
+    pub folder: String,
+    /// Whether to remember the folder for this server's bookmark
+    pub remember: bool,
+    /// Whether the connection has a bookmark to remember the folder on
+    pub can_remember: bool,
+    /// What to do if the destination already exists (overrides the setting)
+    pub collision_policy: CollisionPolicy,
+}
+
+/// Pending "Download Selected" dialog state (pick files within a directory)
+#[derive(Debug, Clone)]
+pub struct PendingDownloadSelect {
+    /// Remote path of the directory to download from
+    pub remote_path: String,
+    /// Root mode the path was selected in
+    pub remote_root: bool,
+    /// Files in the directory, sorted by path (None while the manifest loads)
+    pub files: Option<Vec<FileManifestEntry>>,
+    /// Relative paths of the selected files
+    pub selected: HashSet<String>,
+    /// Error loading the manifest
+    pub error: Option<String>,
+}
+
+impl PendingDownloadSelect {
+    /// Create a dialog waiting for the directory manifest
+    pub fn new(remote_path: String, remote_root: bool) -> Self {
+        Self {
+            remote_path,
+            remote_root,
+            files: None,
+            selected: HashSet::new(),
+            error: None,
+        }
+    }
+
+    /// Store the manifest and select every file in it
+    pub fn set_files(&mut self, files: Vec<FileManifestEntry>) {
+        self.selected = files.iter().map(|f| f.path.clone()).collect();
+        self.files = Some(files);
+    }
+
+    /// Select or deselect a file, or every file under a folder
+    ///
+    /// `path` is either a file path or a folder prefix from the manifest
+    /// (e.g. `Jazz` selects `Jazz/tune.mp3` and `Jazz/Live/set.mp3`).
+    pub fn set_selected(&mut self, path: &str, selected: bool) {
+        let Some(files) = &self.files else {
+            return;
+        };
+        for file in files.iter().filter(|f| is_under(&f.path, path)) {
+            if selected {
+                self.selected.insert(file.path.clone());
+            } else {
+                self.selected.remove(&file.path);
+            }
+        }
+    }
+
+    /// Select or deselect every file
+    pub fn set_all_selected(&mut self, selected: bool) {
+        self.selected = match (&self.files, selected) {
+            (Some(files), true) => files.iter().map(|f| f.path.clone()).collect(),
+            _ => HashSet::new(),
+        };
+    }
+
+    /// Whether every file under a folder prefix is selected
+    pub fn is_folder_selected(&self, folder: &str) -> bool {
+        self.files.as_ref().is_some_and(|files| {
+            files
+                .iter()
+                .filter(|f| is_under(&f.path, folder))
+                .all(|f| self.selected.contains(&f.path))
+        })
+    }
+
+    /// Total size in bytes of the selected files
+    pub fn selected_size(&self) -> u64 {
+        self.files.as_ref().map_or(0, |files| {
+            files
+                .iter()
+                .filter(|f| self.selected.contains(&f.path))
+                .fold(0u64, |acc, f| acc.saturating_add(f.size))
+        })
+    }
+
+    /// Whether the whole directory is selected (no include list needed)
+    pub fn all_selected(&self) -> bool {
+        self.files
+            .as_ref()
+            .is_some_and(|files| files.len() == self.selected.len())
+    }
+
+    /// Selected paths in manifest order, for the download's include list
+    pub fn selected_paths(&self) -> Vec<String> {
+        self.files.as_ref().map_or_else(Vec::new, |files| {
+            files
+                .iter()
+                .filter(|f| self.selected.contains(&f.path))
+                .map(|f| f.path.clone())
+                .collect()
+        })
+    }
+}
+
+/// A single-file download being checked against local copies before it's
+/// queued
+///
+/// The server is asked for the file's size, local files of that size are
+/// hashed, and the server is asked again for its hash. Only a match keeps the
+/// check on the tab as a dialog; anything else queues the download.
+#[derive(Debug, Clone)]
+pub struct PendingDownloadCheck {
+    /// Unique ID, so responses for a check that has been answered are ignored
+    pub id: Uuid,
+    /// Remote path of the file to download
+    pub remote_path: String,
+    /// Root mode the path was selected in
+    pub remote_root: bool,
+    /// Local folder the download will be saved to
+    pub download_dir: PathBuf,
+    /// What to do if the destination already exists
+    pub collision_policy: CollisionPolicy,
+    /// Size of the remote file (None until the server reports it)
+    pub size: Option<u64>,
+    /// Local files of that size with their SHA-256
+    pub copies: Vec<(PathBuf, String)>,
+    /// Local copy with the same content (Some once the dialog is shown)
+    pub duplicate: Option<PathBuf>,
+}
+
+impl PendingDownloadCheck {
+    /// Create a check waiting for the remote file's size
+    pub fn new(
+        remote_path: String,
+        remote_root: bool,
+        download_dir: PathBuf,
+        collision_policy: CollisionPolicy,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            remote_path,
+            remote_root,
+            download_dir,
+            collision_policy,
+            size: None,
+            copies: Vec::new(),
+            duplicate: None,
+        }
+    }
+
+    /// File name of the download
+    pub fn name(&self) -> &str {
+        let trimmed = self.remote_path.trim_end_matches('/');
+        trimmed.rsplit('/').next().unwrap_or(trimmed)
+    }
+
+    /// Where the download itself would be saved
+    pub fn destination(&self) -> PathBuf {
+        self.download_dir.join(self.name())
+    }
+
+    /// The local copy whose hash matches the server's, if any
+    pub fn find_duplicate(&self, sha256: &str) -> Option<PathBuf> {
+        self.copies
+            .iter()
+            .find(|(_, hash)| hash.eq_ignore_ascii_case(sha256))
+            .map(|(path, _)| path.clone())
+    }
+}
+
+/// Whether a manifest path is the given file or lies under the given folder
+fn is_under(path: &str, prefix: &str) -> bool {
+    path == prefix
+        || path
+            .strip_prefix(prefix)
+            .is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// Pending "Auto-Download" dialog state (bookmarked connections only)
+#[derive(Debug, Clone, Default)]
+pub struct PendingAutoDownload {
+    /// Directory the rule is for
+    pub path: String,
+    /// Whether the path is relative to the file root
+    pub root: bool,
+    /// File name patterns input (comma separated)
+    pub pattern: String,
+    /// Whether the directory already has a rule
+    pub existing: bool,
+    /// Error from validating the rule
+    pub error: Option<String>,
+}
+
+impl PendingAutoDownload {
+    /// Create a dialog for a directory, filled from its current rule if any
+    pub fn new(path: String, root: bool, rule: Option<&AutoDownloadRule>) -> Self {
+        Self {
+            path,
+            root,
+            pattern: rule.map_or_else(|| "*".to_string(), |rule| rule.pattern.clone()),
+            existing: rule.is_some(),
+            error: None,
+        }
+    }
+
+    /// Build the rule from the input, or a translated validation error
+    ///
+    /// Patterns match file names only, so they can't contain slashes.
+    pub fn rule(&self) -> Result<AutoDownloadRule, String> {
+        let patterns: Vec<&str> = self
+            .pattern
+            .split(',')
+            .map(str::trim)
+            .filter(|pattern| !pattern.is_empty())
+            .collect();
+
+        if patterns.is_empty() {
+            return Err(t("err-auto-download-pattern-required"));
+        }
+        if let Some(pattern) = patterns
+            .iter()
+            .find(|pattern| pattern.contains(['/', '\\']))
+        {
+            return Err(t_args(
+                "err-auto-download-pattern-invalid",
+                &[("pattern", *pattern)],
+            ));
+        }
+
+        Ok(AutoDownloadRule {
+            remote_path: self.path.clone(),
+            remote_root: self.root,
+            pattern: patterns.join(", "),
+        })
+    }
+}
+
+/// Bytes in a megabyte (upload rule sizes are entered in MB)
+const BYTES_PER_MB: f64 = 1024.0 * 1024.0;
+
+/// Pending "Upload Rules" dialog state (admin only)
+///
+/// Sizes are edited as megabyte text and extensions as a comma or space
+/// separated list; `rule()` turns them back into an `UploadRule`.
+#[derive(Debug, Clone, Default)]
+pub struct PendingUploadRule {
+    /// Directory the rule is set on
+    pub path: String,
+    /// Whether the path is relative to the file root
+    pub root: bool,
+    /// Whether the current rule is still loading
+    pub loading: bool,
+    /// Allowed extensions input (empty = any type)
+    pub extensions: String,
+    /// Largest file input in MB (empty = no limit)
+    pub max_file_size: String,
+    /// Largest upload input in MB (empty = no limit)
+    pub max_total_size: String,
+    /// Error from loading, saving or validating the rule
+    pub error: Option<String>,
+}
+
+impl PendingUploadRule {
+    /// Create a dialog waiting for the directory's current rule
+    pub fn new(path: String, root: bool) -> Self {
+        Self {
+            path,
+            root,
+            loading: true,
+            ..Self::default()
+        }
+    }
+
+    /// Fill the inputs from the directory's current rule
+    pub fn set_rule(&mut self, rule: Option<UploadRule>) {
+        let rule = rule.unwrap_or_default();
+        self.extensions = rule.extensions.join(", ");
+        self.max_file_size = rule.max_file_size.map(format_mb).unwrap_or_default();
+        self.max_total_size = rule.max_total_size.map(format_mb).unwrap_or_default();
+        self.loading = false;
+    }
+
+    /// Build the rule from the inputs, or a translated validation error
+    pub fn rule(&self) -> Result<UploadRule, String> {
+        let extensions: Vec<String> = self
+            .extensions
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .map(|extension| extension.trim_start_matches('.'))
+            .filter(|extension| !extension.is_empty())
+            .map(str::to_string)
+            .collect();
+
+        validators::validate_upload_extensions(&extensions).map_err(|e| match e {
+            UploadExtensionsError::TooMany => t_args(
+                "err-upload-rule-too-many-extensions",
+                &[("max", &validators::MAX_UPLOAD_EXTENSIONS.to_string())],
+            ),
+            UploadExtensionsError::Invalid(extension) => t_args(
+                "err-upload-rule-invalid-extension",
+                &[("extension", &extension)],
+            ),
+        })?;
+
+        Ok(UploadRule {
+            extensions,
+            max_file_size: parse_mb(&self.max_file_size)?,
+            max_total_size: parse_mb(&self.max_total_size)?,
+        })
+    }
+}
+
+/// Format a byte count as megabytes for editing (e.g. `1.5`, `100`)
+fn format_mb(bytes: u64) -> String {
+    let mb = (bytes as f64 / BYTES_PER_MB * 100.0).round() / 100.0;
+    mb.to_string()
+}
+
+/// Parse a megabyte input into bytes (empty = no limit)
+fn parse_mb(input: &str) -> Result<Option<u64>, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(None);
+    }
+    match input.parse::<f64>() {
+        Ok(mb) if mb.is_finite() && mb > 0.0 => Ok(Some((mb * BYTES_PER_MB).round() as u64)),
+        _ => Err(t_args("err-upload-rule-invalid-size", &[("value", input)])),
+    }
+}
+
+/// How a file to upload compares with what is at its destination
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UploadFileStatus {
+    /// Nothing is there yet
+    New,
+    /// The server already has the same content (skipped)
+    Identical,
+    /// A file with different content is there
+    Conflict,
+    /// A folder is in the way (the file can't be uploaded)
+    Blocked,
+}
+
+/// An upload to queue once the preview is resolved
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedUpload {
+    /// The selected file or folder
+    pub item: UploadItem,
+    /// Files of a folder to send, when some are skipped
+    pub include: Option<Vec<String>>,
+    /// Whether to replace files on the server with different content
+    pub overwrite: bool,
+}
+
+impl PlannedUpload {
+    /// Upload every item as selected (no preview)
+    pub fn all(items: Vec<UploadItem>) -> Vec<Self> {
+        items
+            .into_iter()
+            .map(|item| Self {
+                item,
+                include: None,
+                overwrite: false,
+            })
+            .collect()
+    }
+}
+
+/// Pending upload preview (files that already exist at the destination)
+///
+/// Created once the selected files are scanned. `set_remote` takes the
+/// `FileStat` response and returns the files that must be hashed locally
+/// (same size as the server's copy); `set_hashes` then settles each file's
+/// status. The dialog is only shown when a decision is needed.
+#[derive(Debug, Clone)]
+pub struct PendingUploadPreview {
+    /// Identifies this preview in async results (a newer upload replaces it)
+    pub id: Uuid,
+    /// Root mode the upload was started in
+    pub remote_root: bool,
+    /// Selected files and folders
+    pub items: Vec<UploadItem>,
+    /// Every file to upload
+    pub files: Vec<UploadFile>,
+    /// What the server has at each file's remote path
+    remote: HashMap<String, FileStatEntry>,
+    /// Whether local files are being hashed for comparison
+    pub hashing: bool,
+    /// Status of each file, in `files` order (None until compared)
+    pub statuses: Option<Vec<UploadFileStatus>>,
+}
+
+impl PendingUploadPreview {
+    /// Create a preview waiting for the server's `FileStat` response
+    pub fn new(remote_root: bool, items: Vec<UploadItem>, files: Vec<UploadFile>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            remote_root,
+            items,
+            files,
+            remote: HashMap::new(),
+            hashing: false,
+            statuses: None,
+        }
+    }
+
+    /// Paths and sizes to look up with `FileStat`
+    pub fn queries(&self) -> Vec<FileStatQuery> {
+        self.files
+            .iter()
+            .map(|file| FileStatQuery {
+                path: file.remote_path.clone(),
+                size: file.size,
+            })
+            .collect()
+    }
+
+    /// Store the server's answer and return the files to hash locally
+    ///
+    /// Only files the server hashed (same size) can be identical. When none
+    /// need hashing the statuses are settled right away.
+    pub fn set_remote(&mut self, entries: Vec<FileStatEntry>) -> Vec<(usize, PathBuf)> {
+        self.remote = entries
+            .into_iter()
+            .map(|entry| (entry.path.clone(), entry))
+            .collect();
+
+        let to_hash: Vec<(usize, PathBuf)> = self
+            .files
+            .iter()
+            .enumerate()
+            .filter(|(_, file)| {
+                self.remote
+                    .get(&file.remote_path)
+                    .is_some_and(|entry| entry.sha256.is_some())
+            })
+            .map(|(index, file)| (index, file.local_path.clone()))
+            .collect();
+
+        if to_hash.is_empty() {
+            self.set_hashes(Vec::new());
+        } else {
+            self.hashing = true;
+        }
+        to_hash
+    }
+
+    /// Compare local hashes with the server's and settle every file's status
+    pub fn set_hashes(&mut self, hashes: Vec<(usize, Option<String>)>) {
+        let hashes: HashMap<usize, String> = hashes
+            .into_iter()
+            .filter_map(|(index, hash)| Some((index, hash?)))
+            .collect();
+
+        let statuses = self
+            .files
+            .iter()
+            .enumerate()
+            .map(|(index, file)| match self.remote.get(&file.remote_path) {
+                None => UploadFileStatus::New,
+                Some(entry) if entry.is_directory => UploadFileStatus::Blocked,
+                Some(entry)
+                    if entry.sha256.is_some() && entry.sha256.as_ref() == hashes.get(&index) =>
+                {
+                    UploadFileStatus::Identical
+                }
+                Some(_) => UploadFileStatus::Conflict,
+            })
+            .collect();
+
+        self.hashing = false;
+        self.statuses = Some(statuses);
+    }
+
+    /// Number of files with the given status
+    pub fn count(&self, status: UploadFileStatus) -> usize {
+        self.statuses.as_ref().map_or(0, |statuses| {
+            statuses.iter().filter(|s| **s == status).count()
+        })
+    }
+
+    /// Remote paths of the files with the given status
+    pub fn paths(&self, status: UploadFileStatus) -> Vec<&str> {
+        let Some(statuses) = &self.statuses else {
+            return Vec::new();
+        };
+        self.files
+            .iter()
+            .zip(statuses)
+            .filter(|(_, s)| **s == status)
+            .map(|(file, _)| file.remote_path.as_str())
+            .collect()
+    }
+
+    /// Whether the user has to choose what to do
+    pub fn needs_decision(&self) -> bool {
+        self.count(UploadFileStatus::Conflict) > 0 || self.count(UploadFileStatus::Blocked) > 0
+    }
+
+    /// Whether the dialog is shown (the server lookup itself is silent)
+    pub fn is_visible(&self) -> bool {
+        self.hashing || self.statuses.is_some()
+    }
+
+    /// Uploads to queue: new files, plus conflicting ones when overwriting
+    ///
+    /// A folder whose files are all skipped is left out; one with only some
+    /// skipped gets an include list. Folders with no files at all are queued
+    /// as they are.
+    pub fn plan(&self, overwrite: bool) -> Vec<PlannedUpload> {
+        let statuses = self.statuses.as_deref().unwrap_or_default();
+
+        self.items
+            .iter()
+            .enumerate()
+            .filter_map(|(index, item)| {
+                let files: Vec<(&UploadFile, UploadFileStatus)> = self
+                    .files
+                    .iter()
+                    .zip(statuses.iter().copied())
+                    .filter(|(file, _)| file.item == index)
+                    .collect();
+                if files.is_empty() {
+                    return Some(PlannedUpload {
+                        item: item.clone(),
+                        include: None,
+                        overwrite: false,
+                    });
+                }
+
+                let send: Vec<&(&UploadFile, UploadFileStatus)> = files
+                    .iter()
+                    .filter(|(_, status)| match status {
+                        UploadFileStatus::New => true,
+                        UploadFileStatus::Conflict => overwrite,
+                        UploadFileStatus::Identical | UploadFileStatus::Blocked => false,
+                    })
+                    .collect();
+                if send.is_empty() {
+                    return None;
+                }
+
+                let include = (send.len() < files.len()).then(|| {
+                    send.iter()
+                        .map(|(file, _)| file.relative_path.clone())
+                        .collect()
+                });
+                let overwrite = send
+                    .iter()
+                    .any(|(_, status)| *status == UploadFileStatus::Conflict);
+
+                Some(PlannedUpload {
+                    item: item.clone(),
+                    include,
+                    overwrite,
+                })
+            })
+            .collect()
+    }
+}
+
 // =============================================================================
 // File Tab ID Generation
 // =============================================================================
 
+/// "New uploads this week" and "Most downloaded" listings for a tab
+#[derive(Debug, Clone, Default)]
+pub struct FileHighlights {
+    /// Recently uploaded files (None = loading)
+    pub recent: Option<Vec<FileActivity>>,
+    /// Most downloaded files (None = loading)
+    pub popular: Option<Vec<FileActivity>>,
+    /// Error from either request
+    pub error: Option<String>,
+    /// Root mode when the listings were requested (for opening and downloading)
+    pub viewing_root: bool,
+    /// Current FileRecent request ID (for ignoring stale responses)
+    pub recent_request: Option<MessageId>,
+    /// Current FilePopular request ID (for ignoring stale responses)
+    pub popular_request: Option<MessageId>,
+}
+
+impl FileHighlights {
+    /// Whether either listing is still loading
+    pub fn is_loading(&self) -> bool {
+        self.error.is_none() && (self.recent.is_none() || self.popular.is_none())
+    }
+}
+
+/// Ratings and comments for the file shown in the info dialog
+#[derive(Debug, Clone, Default)]
+pub struct FileReview {
+    /// Path of the file being reviewed
+    pub path: String,
+    /// Whether the path is relative to the file root
+    pub root: bool,
+    /// Comments, oldest first (None = loading)
+    pub comments: Option<Vec<FileComment>>,
+    /// Comment being written
+    pub comment_input: String,
+    /// Error from the last rating or comment request
+    pub error: Option<String>,
+}
+
+impl FileReview {
+    /// Start reviewing a file (comments load once the info arrives)
+    pub fn new(path: String, root: bool) -> Self {
+        Self {
+            path,
+            root,
+            ..Self::default()
+        }
+    }
+}
+
 /// Global counter for generating unique tab IDs
 static NEXT_TAB_ID: AtomicU64 = AtomicU64::new(1);
 
@@ -96,6 +730,8 @@ pub struct FileTab {
     pub viewing_root: bool,
     /// Whether the current directory allows uploads (from FileListResponse)
     pub current_dir_can_upload: bool,
+    /// Upload rule that applies to the current directory (from FileListResponse)
+    pub current_dir_upload_rule: Option<UploadRule>,
     /// Current sort column
     pub sort_column: FileSortColumn,
     /// Sort ascending (true) or descending (false)
@@ -114,6 +750,8 @@ pub struct FileTab {
     pub delete_error: Option<String>,
     /// File/directory info to display (for info dialog)
     pub pending_info: Option<nexus_common::protocol::FileInfoDetails>,
+    /// Ratings and comments for the file in the info dialog (None for directories)
+    pub review: Option<FileReview>,
     /// Path of file/directory being renamed (for rename dialog)
     pub pending_rename: Option<String>,
     /// New name input for rename dialog
@@ -122,6 +760,18 @@ pub struct FileTab {
     pub rename_error: Option<String>,
     /// Pending overwrite confirmation (when destination exists)
     pub pending_overwrite: Option<PendingOverwrite>,
+    /// Pending "Download To" dialog (destination folder picker)
+    pub pending_download_to: Option<PendingDownloadTo>,
+    /// Pending "Download Selected" dialog (pick files within a directory)
+    pub pending_download_select: Option<PendingDownloadSelect>,
+    /// Pending "Upload Rules" dialog (admin only)
+    pub pending_upload_rule: Option<PendingUploadRule>,
+    /// Pending "Auto-Download" dialog (bookmarked connections only)
+    pub pending_auto_download: Option<PendingAutoDownload>,
+    /// Pending upload preview (files that already exist at the destination)
+    pub pending_upload_preview: Option<PendingUploadPreview>,
+    /// Single-file downloads being checked for local copies
+    pub pending_download_checks: Vec<PendingDownloadCheck>,
     /// Current text in search input field
     pub search_input: String,
     /// Active search query (None = normal browsing, Some = showing search results)
@@ -140,6 +790,8 @@ pub struct FileTab {
     pub search_sort_column: FileSortColumn,
     /// Sort ascending for search results (separate from browsing sort)
     pub search_sort_ascending: bool,
+    /// New and most downloaded files (None = normal browsing, Some = showing highlights)
+    pub highlights: Option<FileHighlights>,
 }
 
 impl Default for FileTab {
@@ -151,6 +803,7 @@ impl Default for FileTab {
             error: None,
             viewing_root: false,
             current_dir_can_upload: false,
+            current_dir_upload_rule: None,
             sort_column: FileSortColumn::Name,
             sort_ascending: true,
             sorted_entries: None,
@@ -160,10 +813,17 @@ impl Default for FileTab {
             pending_delete: None,
             delete_error: None,
             pending_info: None,
+            review: None,
             pending_rename: None,
             rename_name: String::new(),
             rename_error: None,
             pending_overwrite: None,
+            pending_download_to: None,
+            pending_download_select: None,
+            pending_upload_rule: None,
+            pending_auto_download: None,
+            pending_upload_preview: None,
+            pending_download_checks: Vec::new(),
             search_input: String::new(),
             search_query: None,
             search_results: None,
@@ -173,11 +833,35 @@ impl Default for FileTab {
             search_viewing_root: false,
             search_sort_column: FileSortColumn::Name,
             search_sort_ascending: true,
+            highlights: None,
         }
     }
 }
 
 impl FileTab {
+    /// Find a download check by its ID (mutable)
+    pub fn download_check_mut(&mut self, id: Uuid) -> Option<&mut PendingDownloadCheck> {
+        self.pending_download_checks
+            .iter_mut()
+            .find(|check| check.id == id)
+    }
+
+    /// Remove a download check by its ID, returning it
+    pub fn take_download_check(&mut self, id: Uuid) -> Option<PendingDownloadCheck> {
+        let index = self
+            .pending_download_checks
+            .iter()
+            .position(|check| check.id == id)?;
+        Some(self.pending_download_checks.remove(index))
+    }
+
+    /// The first download found to be a duplicate, shown as a dialog
+    pub fn duplicate_download(&self) -> Option<&PendingDownloadCheck> {
+        self.pending_download_checks
+            .iter()
+            .find(|check| check.duplicate.is_some())
+    }
+
     /// Create a new tab copying another tab's location and sort settings
     ///
     /// The new tab will have a new unique ID, the same path, viewing_root,
@@ -190,6 +874,7 @@ impl FileTab {
             error: None,
             viewing_root: other.viewing_root,
             current_dir_can_upload: false,
+            current_dir_upload_rule: None,
             sort_column: other.sort_column,
             sort_ascending: other.sort_ascending,
             sorted_entries: None,
@@ -199,10 +884,17 @@ impl FileTab {
             pending_delete: None,
             delete_error: None,
             pending_info: None,
+            review: None,
             pending_rename: None,
             rename_name: String::new(),
             rename_error: None,
             pending_overwrite: None,
+            pending_download_to: None,
+            pending_download_select: None,
+            pending_upload_rule: None,
+            pending_auto_download: None,
+            pending_upload_preview: None,
+            pending_download_checks: Vec::new(),
             search_input: String::new(),
             search_query: None,
             search_results: None,
@@ -212,6 +904,7 @@ impl FileTab {
             search_viewing_root: false,
             search_sort_column: FileSortColumn::Name,
             search_sort_ascending: true,
+            highlights: None,
         }
     }
 
@@ -226,6 +919,7 @@ impl FileTab {
             error: None,
             viewing_root,
             current_dir_can_upload: false,
+            current_dir_upload_rule: None,
             sort_column: FileSortColumn::Name,
             sort_ascending: true,
             sorted_entries: None,
@@ -235,10 +929,17 @@ impl FileTab {
             pending_delete: None,
             delete_error: None,
             pending_info: None,
+            review: None,
             pending_rename: None,
             rename_name: String::new(),
             rename_error: None,
             pending_overwrite: None,
+            pending_download_to: None,
+            pending_download_select: None,
+            pending_upload_rule: None,
+            pending_auto_download: None,
+            pending_upload_preview: None,
+            pending_download_checks: Vec::new(),
             search_input: String::new(),
             search_query: None,
             search_results: None,
@@ -248,6 +949,7 @@ impl FileTab {
             search_viewing_root: false,
             search_sort_column: FileSortColumn::Name,
             search_sort_ascending: true,
+            highlights: None,
         }
     }
 
@@ -267,10 +969,16 @@ impl FileTab {
         self.search_viewing_root = false;
     }
 
+    /// Check if this tab is showing highlights
+    pub fn is_showing_highlights(&self) -> bool {
+        self.highlights.is_some()
+    }
+
     /// Get the tab display name
     ///
     /// Returns:
     /// - Search query when in search mode (e.g., "report"), truncated if too long
+    /// - "Highlights" when showing highlights
     /// - Last path segment when browsing (e.g., "Documents")
     /// - "Home" or "Root" for empty path
     pub fn tab_name(&self) -> String {
@@ -293,6 +1001,10 @@ impl FileTab {
             return query.clone();
         }
 
+        if self.highlights.is_some() {
+            return t("files-highlights");
+        }
+
         if self.current_path.is_empty() {
             if self.viewing_root {
                 t("files-root")
@@ -320,13 +1032,14 @@ impl FileTab {
         self.error = None;
     }
 
-    /// Navigate to home directory (preserves viewing_root state, clears search)
+    /// Navigate to home directory (preserves viewing_root state, clears search and highlights)
     pub fn navigate_home(&mut self) {
         self.current_path = String::new();
         self.entries = None;
         self.sorted_entries = None;
         self.error = None;
         self.clear_search();
+        self.highlights = None;
     }
 
     /// Toggle between root view and user area view
@@ -337,6 +1050,7 @@ impl FileTab {
         self.sorted_entries = None;
         self.error = None;
         self.current_dir_can_upload = false;
+        self.current_dir_upload_rule = None;
     }
 
     /// Open the new directory dialog
@@ -833,6 +1547,7 @@ mod tests {
             error: None,
             viewing_root: false,
             current_dir_can_upload: false,
+            current_dir_upload_rule: None,
             sort_column: FileSortColumn::Name,
             sort_ascending: true,
             sorted_entries: None,
@@ -842,10 +1557,17 @@ mod tests {
             pending_delete: None,
             delete_error: None,
             pending_info: None,
+            review: None,
             pending_rename: None,
             rename_name: String::new(),
             rename_error: None,
             pending_overwrite: None,
+            pending_download_to: None,
+            pending_download_select: None,
+            pending_upload_rule: None,
+            pending_auto_download: None,
+            pending_upload_preview: None,
+            pending_download_checks: Vec::new(),
             search_input: String::new(),
             search_query: None,
             search_results: None,
@@ -855,6 +1577,7 @@ mod tests {
             search_viewing_root: false,
             search_sort_column: FileSortColumn::Name,
             search_sort_ascending: true,
+            highlights: None,
         };
 
         tab.close_new_directory_dialog();
@@ -864,6 +1587,326 @@ mod tests {
         assert!(tab.new_directory_error.is_none());
     }
 
+    // =========================================================================
+    // PendingDownloadSelect Tests
+    // =========================================================================
+
+    fn manifest_entry(path: &str, size: u64) -> FileManifestEntry {
+        FileManifestEntry {
+            path: path.to_string(),
+            size,
+        }
+    }
+
+    #[test]
+    fn test_download_select_folder_toggle() {
+        let mut pending = PendingDownloadSelect::new("/Music".to_string(), false);
+        pending.set_files(vec![
+            manifest_entry("Jazz/Live/set.mp3", 4),
+            manifest_entry("Jazz/tune.mp3", 2),
+            manifest_entry("JazzFusion.mp3", 8),
+            manifest_entry("song.mp3", 1),
+        ]);
+        assert!(pending.all_selected());
+        assert_eq!(pending.selected_size(), 15);
+
+        // Deselecting a folder must not touch siblings that share the prefix
+        pending.set_selected("Jazz", false);
+        assert!(!pending.is_folder_selected("Jazz"));
+        assert!(pending.selected.contains("JazzFusion.mp3"));
+        assert_eq!(pending.selected_size(), 9);
+
+        pending.set_selected("Jazz/tune.mp3", true);
+        assert!(!pending.is_folder_selected("Jazz"));
+        assert!(!pending.all_selected());
+        assert_eq!(
+            pending.selected_paths(),
+            vec!["Jazz/tune.mp3", "JazzFusion.mp3", "song.mp3"]
+        );
+
+        pending.set_all_selected(false);
+        assert!(pending.selected.is_empty());
+        pending.set_all_selected(true);
+        assert!(pending.all_selected());
+    }
+
+    // =========================================================================
+    // PendingAutoDownload Tests
+    // =========================================================================
+
+    #[test]
+    fn test_pending_auto_download_rule() {
+        let mut pending = PendingAutoDownload::new("podcasts".to_string(), false, None);
+        assert_eq!(pending.pattern, "*");
+        assert!(!pending.existing);
+
+        pending.pattern = " *.mp3 ,, *.m4a ".to_string();
+        let rule = pending.rule().unwrap();
+        assert_eq!(rule.pattern, "*.mp3, *.m4a");
+        assert!(rule.is_for("podcasts", false));
+
+        let pending = PendingAutoDownload::new("podcasts".to_string(), false, Some(&rule));
+        assert_eq!(pending.pattern, "*.mp3, *.m4a");
+        assert!(pending.existing);
+
+        for pattern in ["", " , ", "shows/*.mp3", "*.mp3, ..\\*"] {
+            let pending = PendingAutoDownload {
+                pattern: pattern.to_string(),
+                ..pending.clone()
+            };
+            assert!(pending.rule().is_err(), "{pattern:?} should be rejected");
+        }
+    }
+
+    // =========================================================================
+    // PendingUploadRule Tests
+    // =========================================================================
+
+    #[test]
+    fn test_pending_upload_rule_round_trip() {
+        let mut pending = PendingUploadRule::new("/music".to_string(), false);
+        assert!(pending.loading);
+
+        pending.set_rule(Some(UploadRule {
+            extensions: vec!["mp3".to_string(), "flac".to_string()],
+            max_file_size: Some(1572864),
+            max_total_size: None,
+        }));
+        assert!(!pending.loading);
+        assert_eq!(pending.extensions, "mp3, flac");
+        assert_eq!(pending.max_file_size, "1.5");
+        assert!(pending.max_total_size.is_empty());
+
+        let rule = pending.rule().unwrap();
+        assert_eq!(rule.extensions, vec!["mp3", "flac"]);
+        assert_eq!(rule.max_file_size, Some(1572864));
+        assert_eq!(rule.max_total_size, None);
+    }
+
+    #[test]
+    fn test_pending_upload_rule_parses_inputs() {
+        let mut pending = PendingUploadRule::new("/".to_string(), true);
+        pending.set_rule(None);
+        assert!(pending.rule().unwrap().is_empty());
+
+        pending.extensions = ".jpg  png,,gif".to_string();
+        pending.max_total_size = " 100 ".to_string();
+        let rule = pending.rule().unwrap();
+        assert_eq!(rule.extensions, vec!["jpg", "png", "gif"]);
+        assert_eq!(rule.max_total_size, Some(100 * 1024 * 1024));
+
+        for size in ["0", "-1", "lots", "inf"] {
+            pending.max_total_size = size.to_string();
+            assert!(pending.rule().is_err(), "{size} should be rejected");
+        }
+
+        pending.max_total_size.clear();
+        pending.extensions = "tar.gz".to_string();
+        assert!(pending.rule().is_err());
+    }
+
+    // =========================================================================
+    // PendingDownloadCheck Tests
+    // =========================================================================
+
+    #[test]
+    fn test_download_check_finds_duplicate() {
+        let mut tab = FileTab::default();
+        let mut check = PendingDownloadCheck::new(
+            "/Music/song.mp3".to_string(),
+            false,
+            PathBuf::from("/downloads"),
+            CollisionPolicy::Rename,
+        );
+        assert_eq!(check.name(), "song.mp3");
+        assert_eq!(check.destination(), PathBuf::from("/downloads/song.mp3"));
+
+        check.copies = vec![
+            (PathBuf::from("/downloads/a.mp3"), "aaaa".to_string()),
+            (PathBuf::from("/old/song.mp3"), "bbbb".to_string()),
+        ];
+        assert_eq!(
+            check.find_duplicate("BBBB"),
+            Some(PathBuf::from("/old/song.mp3"))
+        );
+        assert_eq!(check.find_duplicate("cccc"), None);
+
+        // Only checks with a duplicate are shown
+        let id = check.id;
+        tab.pending_download_checks.push(check);
+        assert!(tab.duplicate_download().is_none());
+        tab.download_check_mut(id).unwrap().duplicate = Some(PathBuf::from("/old/song.mp3"));
+        assert_eq!(tab.duplicate_download().map(|check| check.id), Some(id));
+
+        assert!(tab.take_download_check(id).is_some());
+        assert!(tab.take_download_check(id).is_none());
+    }
+
+    // =========================================================================
+    // PendingUploadPreview Tests
+    // =========================================================================
+
+    fn upload_file(item: usize, relative_path: &str, remote_path: &str) -> UploadFile {
+        UploadFile {
+            item,
+            relative_path: relative_path.to_string(),
+            remote_path: remote_path.to_string(),
+            local_path: PathBuf::from(relative_path),
+            size: 5,
+        }
+    }
+
+    fn stat_entry(path: &str, is_directory: bool, sha256: Option<&str>) -> FileStatEntry {
+        FileStatEntry {
+            path: path.to_string(),
+            size: 5,
+            is_directory,
+            sha256: sha256.map(str::to_string),
+        }
+    }
+
+    /// An "Album" folder with four files and a single file next to it
+    fn album_preview() -> PendingUploadPreview {
+        let items = vec![
+            UploadItem {
+                local_path: PathBuf::from("Album"),
+                remote_path: "/Uploads/Album".to_string(),
+                is_directory: true,
+            },
+            UploadItem {
+                local_path: PathBuf::from("cover.jpg"),
+                remote_path: "/Uploads".to_string(),
+                is_directory: false,
+            },
+        ];
+        let files = vec![
+            upload_file(0, "new.mp3", "/Uploads/Album/new.mp3"),
+            upload_file(0, "same.mp3", "/Uploads/Album/same.mp3"),
+            upload_file(0, "changed.mp3", "/Uploads/Album/changed.mp3"),
+            upload_file(0, "live", "/Uploads/Album/live"),
+            upload_file(1, "cover.jpg", "/Uploads/cover.jpg"),
+        ];
+        PendingUploadPreview::new(false, items, files)
+    }
+
+    #[test]
+    fn test_upload_preview_without_matches_needs_no_hashing() {
+        let mut pending = album_preview();
+        assert!(!pending.is_visible());
+        assert_eq!(pending.queries().len(), 5);
+        assert_eq!(pending.queries()[0].path, "/Uploads/Album/new.mp3");
+
+        let to_hash = pending.set_remote(Vec::new());
+        assert!(to_hash.is_empty());
+        assert!(!pending.hashing);
+        assert_eq!(pending.count(UploadFileStatus::New), 5);
+        assert!(!pending.needs_decision());
+
+        let planned = pending.plan(false);
+        assert_eq!(planned.len(), 2);
+        assert!(planned.iter().all(|p| p.include.is_none() && !p.overwrite));
+    }
+
+    #[test]
+    fn test_upload_preview_compares_hashes() {
+        let mut pending = album_preview();
+        let to_hash = pending.set_remote(vec![
+            stat_entry("/Uploads/Album/same.mp3", false, Some("aaaa")),
+            stat_entry("/Uploads/Album/changed.mp3", false, Some("bbbb")),
+            stat_entry("/Uploads/Album/live", true, None),
+            // Different size, so the server didn't hash it
+            stat_entry("/Uploads/cover.jpg", false, None),
+        ]);
+        assert_eq!(
+            to_hash,
+            vec![
+                (1, PathBuf::from("same.mp3")),
+                (2, PathBuf::from("changed.mp3"))
+            ]
+        );
+        assert!(pending.hashing);
+        assert!(pending.is_visible());
+
+        pending.set_hashes(vec![
+            (1, Some("aaaa".to_string())),
+            (2, Some("cccc".to_string())),
+        ]);
+        assert!(!pending.hashing);
+        assert_eq!(
+            pending.statuses,
+            Some(vec![
+                UploadFileStatus::New,
+                UploadFileStatus::Identical,
+                UploadFileStatus::Conflict,
+                UploadFileStatus::Blocked,
+                UploadFileStatus::Conflict,
+            ])
+        );
+        assert!(pending.needs_decision());
+        assert_eq!(
+            pending.paths(UploadFileStatus::Conflict),
+            vec!["/Uploads/Album/changed.mp3", "/Uploads/cover.jpg"]
+        );
+    }
+
+    #[test]
+    fn test_upload_preview_unreadable_file_is_a_conflict() {
+        let mut pending = album_preview();
+        pending.set_remote(vec![stat_entry(
+            "/Uploads/Album/same.mp3",
+            false,
+            Some("aaaa"),
+        )]);
+        pending.set_hashes(vec![(1, None)]);
+        assert_eq!(pending.count(UploadFileStatus::Conflict), 1);
+    }
+
+    #[test]
+    fn test_upload_preview_plan() {
+        let mut pending = album_preview();
+        pending.set_remote(vec![
+            stat_entry("/Uploads/Album/same.mp3", false, Some("aaaa")),
+            stat_entry("/Uploads/Album/changed.mp3", false, Some("bbbb")),
+            stat_entry("/Uploads/Album/live", true, None),
+            stat_entry("/Uploads/cover.jpg", false, None),
+        ]);
+        pending.set_hashes(vec![
+            (1, Some("aaaa".to_string())),
+            (2, Some("cccc".to_string())),
+        ]);
+
+        // Skipping leaves only the new file, and drops the single conflicting file
+        let planned = pending.plan(false);
+        assert_eq!(planned.len(), 1);
+        assert_eq!(planned[0].item.remote_path, "/Uploads/Album");
+        assert_eq!(planned[0].include, Some(vec!["new.mp3".to_string()]));
+        assert!(!planned[0].overwrite);
+
+        // Overwriting also sends the conflicts, but never the blocked file
+        let planned = pending.plan(true);
+        assert_eq!(planned.len(), 2);
+        assert_eq!(
+            planned[0].include,
+            Some(vec!["new.mp3".to_string(), "changed.mp3".to_string()])
+        );
+        assert!(planned[0].overwrite);
+        assert_eq!(planned[1].item.remote_path, "/Uploads");
+        assert_eq!(planned[1].include, None);
+        assert!(planned[1].overwrite);
+    }
+
+    #[test]
+    fn test_upload_preview_plan_keeps_empty_folders() {
+        let items = vec![UploadItem {
+            local_path: PathBuf::from("Empty"),
+            remote_path: "/Empty".to_string(),
+            is_directory: true,
+        }];
+        let mut pending = PendingUploadPreview::new(false, items.clone(), Vec::new());
+        pending.set_remote(Vec::new());
+        assert_eq!(pending.plan(false), PlannedUpload::all(items));
+    }
+
     // =========================================================================
     // FilesManagementState Tab Tests
     // =========================================================================
@@ -1363,6 +2406,36 @@ mod tests {
         assert!(tab.search_query.is_none());
     }
 
+    #[test]
+    fn test_navigate_home_clears_highlights() {
+        let mut tab = FileTab {
+            highlights: Some(FileHighlights::default()),
+            ..Default::default()
+        };
+        assert!(tab.is_showing_highlights());
+
+        tab.navigate_home();
+
+        assert!(!tab.is_showing_highlights());
+    }
+
+    #[test]
+    fn test_highlights_loading_until_both_listings_arrive() {
+        let mut highlights = FileHighlights {
+            recent: Some(vec![]),
+            ..Default::default()
+        };
+        assert!(highlights.is_loading());
+
+        highlights.popular = Some(vec![]);
+        assert!(!highlights.is_loading());
+
+        // An error ends loading even if a listing is missing
+        highlights.popular = None;
+        highlights.error = Some("error".to_string());
+        assert!(!highlights.is_loading());
+    }
+
     #[test]
     fn test_navigate_to_does_not_clear_search() {
         let mut tab = FileTab {
@@ -2,22 +2,36 @@
 //!
 //! Each sub-module contains the state types for a specific panel or feature.
 
+mod admin_dashboard;
+mod broadcast;
 mod connection;
 mod connection_monitor;
 mod disconnect;
+mod doors;
 mod files;
 mod news;
 mod password;
+mod screenshot;
 mod server_info;
+mod server_log;
 mod settings;
+mod snippet;
+mod statistics;
 mod users;
 
+pub use admin_dashboard::*;
+pub use broadcast::*;
 pub use connection::*;
 pub use connection_monitor::*;
 pub use disconnect::*;
+pub use doors::*;
 pub use files::*;
 pub use news::*;
 pub use password::*;
+pub use screenshot::*;
 pub use server_info::*;
+pub use server_log::*;
 pub use settings::*;
+pub use snippet::*;
+pub use statistics::*;
 pub use users::*;
@@ -1,7 +1,9 @@
 //! News management panel state
 
-use nexus_common::protocol::NewsItem;
+use nexus_common::framing::MessageId;
+use nexus_common::protocol::{NewsAttachment, NewsItem};
 
+use crate::drafts::DraftContext;
 use crate::image::{CachedImage, decode_data_uri_max_width};
 use crate::style::NEWS_IMAGE_MAX_CACHE_WIDTH;
 
@@ -29,26 +31,104 @@ pub enum NewsManagementMode {
     },
 }
 
+/// An attachment in the news form
+#[derive(Clone)]
+pub struct NewsFormAttachment {
+    /// Attachment as it will be sent
+    pub attachment: NewsAttachment,
+    /// Cached image for preview (image attachments only)
+    pub cached_image: Option<CachedImage>,
+}
+
+impl NewsFormAttachment {
+    /// Create a form attachment, decoding image data for preview
+    pub fn new(attachment: NewsAttachment) -> Self {
+        let cached_image = match &attachment {
+            NewsAttachment::Image { data, .. } => {
+                decode_data_uri_max_width(data, NEWS_IMAGE_MAX_CACHE_WIDTH)
+            }
+            NewsAttachment::File { .. } => None,
+        };
+        Self {
+            attachment,
+            cached_image,
+        }
+    }
+}
+
+/// A news search parsed from the search input
+///
+/// `author:name`, `since:YYYY-MM-DD` and `until:YYYY-MM-DD` words become
+/// filters; the remaining words are the full-text query.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NewsSearch {
+    /// Full-text query (may be empty when a filter is given)
+    pub query: String,
+    /// Only posts by this user
+    pub author: Option<String>,
+    /// Only posts created on or after this day
+    pub since: Option<String>,
+    /// Only posts created on or before this day
+    pub until: Option<String>,
+}
+
+impl NewsSearch {
+    /// Parse the search input (filter names are case-insensitive)
+    pub fn parse(input: &str) -> Self {
+        let mut search = Self::default();
+        let mut words = Vec::new();
+
+        for word in input.split_whitespace() {
+            let filter = word
+                .split_once(':')
+                .filter(|(_, value)| !value.is_empty())
+                .map(|(name, value)| (name.to_lowercase(), value.to_string()));
+            match filter {
+                Some((name, value)) if name == "author" => search.author = Some(value),
+                Some((name, value)) if name == "since" => search.since = Some(value),
+                Some((name, value)) if name == "until" => search.until = Some(value),
+                _ => words.push(word),
+            }
+        }
+
+        search.query = words.join(" ");
+        search
+    }
+
+    /// Whether any filter is set
+    pub fn has_filter(&self) -> bool {
+        self.author.is_some() || self.since.is_some() || self.until.is_some()
+    }
+}
+
 /// News management panel state (per-connection)
 ///
 /// Note: The body text is stored in `NexusApp.news_body_content` as a `text_editor::Content`
-/// because it's not Clone. Only the image and error state are stored here.
+/// because it's not Clone. Only the attachments and error state are stored here.
 #[derive(Clone)]
 pub struct NewsManagementState {
     /// Current mode (list, create, edit, confirm delete)
     pub mode: NewsManagementMode,
     /// All news items (None = not loaded, Some(Ok) = loaded, Some(Err) = error)
     pub news_items: Option<Result<Vec<NewsItem>, String>>,
-    /// Image data URI for form (used in both create and edit modes)
-    pub form_image: String,
-    /// Cached image for form preview
-    pub cached_form_image: Option<CachedImage>,
+    /// Attachments in the form (used in both create and edit modes)
+    pub form_attachments: Vec<NewsFormAttachment>,
+    /// File area path input for adding a file attachment
+    pub form_file_path: String,
     /// Error message for form (create or edit)
     pub form_error: Option<String>,
     /// Error message for list view
     pub list_error: Option<String>,
     /// Error message for delete confirmation dialog
     pub delete_error: Option<String>,
+    /// Current text in search input field
+    pub search_input: String,
+    /// Active search (None = showing all news, Some = showing search results)
+    pub search_query: Option<String>,
+    /// Search results (None = loading, Some(Ok) = loaded, Some(Err) = error)
+    pub search_results: Option<Result<Vec<NewsItem>, String>>,
+    /// Current active search request ID (for ignoring stale responses)
+    pub current_search_request: Option<MessageId>,
 }
 
 // Manual Debug implementation because CachedImage doesn't implement Debug
@@ -57,13 +137,20 @@ impl std::fmt::Debug for NewsManagementState {
         f.debug_struct("NewsManagementState")
             .field("mode", &self.mode)
             .field("news_items", &self.news_items)
-            .field("form_image", &format!("<{} bytes>", self.form_image.len()))
             .field(
-                "cached_form_image",
-                &self.cached_form_image.as_ref().map(|_| "<cached>"),
+                "form_attachments",
+                &self
+                    .form_attachments
+                    .iter()
+                    .map(|a| &a.attachment)
+                    .collect::<Vec<_>>(),
             )
+            .field("form_file_path", &self.form_file_path)
             .field("form_error", &self.form_error)
             .field("list_error", &self.list_error)
+            .field("search_input", &self.search_input)
+            .field("search_query", &self.search_query)
+            .field("search_results", &self.search_results)
             .finish()
     }
 }
@@ -73,11 +160,15 @@ impl Default for NewsManagementState {
         Self {
             mode: NewsManagementMode::List,
             news_items: None,
-            form_image: String::new(),
-            cached_form_image: None,
+            form_attachments: Vec::new(),
+            form_file_path: String::new(),
             form_error: None,
             list_error: None,
             delete_error: None,
+            search_input: String::new(),
+            search_query: None,
+            search_results: None,
+            current_search_request: None,
         }
     }
 }
@@ -92,8 +183,8 @@ impl NewsManagementState {
 
     /// Clear the form fields (used for both create and edit)
     pub fn clear_form(&mut self) {
-        self.form_image.clear();
-        self.cached_form_image = None;
+        self.form_attachments.clear();
+        self.form_file_path.clear();
         self.form_error = None;
     }
 
@@ -103,22 +194,134 @@ impl NewsManagementState {
         self.mode = NewsManagementMode::Create;
     }
 
-    /// Enter edit mode for a news item (image pre-populated, body handled by text_editor)
-    pub fn enter_edit_mode(&mut self, id: i64, image: Option<String>) {
-        self.form_image = image.clone().unwrap_or_default();
-        self.cached_form_image = if self.form_image.is_empty() {
-            None
-        } else {
-            decode_data_uri_max_width(&self.form_image, NEWS_IMAGE_MAX_CACHE_WIDTH)
-        };
+    /// Enter edit mode for a news item (attachments pre-populated, body handled by text_editor)
+    pub fn enter_edit_mode(&mut self, id: i64, attachments: Vec<NewsAttachment>) {
+        self.form_attachments = attachments
+            .into_iter()
+            .map(NewsFormAttachment::new)
+            .collect();
+        self.form_file_path.clear();
         self.form_error = None;
 
         self.mode = NewsManagementMode::Edit { id };
     }
 
+    /// Set the caption of a form attachment (an empty caption removes it)
+    pub fn set_attachment_caption(&mut self, index: usize, caption: String) {
+        if let Some(form_attachment) = self.form_attachments.get_mut(index) {
+            let (NewsAttachment::Image { caption: slot, .. }
+            | NewsAttachment::File { caption: slot, .. }) = &mut form_attachment.attachment;
+            *slot = (!caption.is_empty()).then_some(caption);
+        }
+    }
+
+    /// Draft context of the open form (None outside create and edit modes)
+    pub fn draft_context(&self) -> Option<DraftContext> {
+        match self.mode {
+            NewsManagementMode::Create => Some(DraftContext::News(None)),
+            NewsManagementMode::Edit { id } => Some(DraftContext::News(Some(id))),
+            NewsManagementMode::List | NewsManagementMode::ConfirmDelete { .. } => None,
+        }
+    }
+
+    /// Check if search results are shown instead of all news
+    pub fn is_searching(&self) -> bool {
+        self.search_query.is_some()
+    }
+
+    /// Clear search state and return to showing all news
+    pub fn clear_search(&mut self) {
+        self.search_input.clear();
+        self.search_query = None;
+        self.search_results = None;
+        self.current_search_request = None;
+    }
+
+    /// Loaded news lists to keep in sync (all news and search results)
+    pub fn loaded_items_mut(&mut self) -> impl Iterator<Item = &mut Vec<NewsItem>> {
+        [&mut self.news_items, &mut self.search_results]
+            .into_iter()
+            .filter_map(|items| match items {
+                Some(Ok(items)) => Some(items),
+                _ => None,
+            })
+    }
+
     /// Enter confirm delete mode for a news item
     pub fn enter_confirm_delete_mode(&mut self, id: i64) {
         self.mode = NewsManagementMode::ConfirmDelete { id };
         self.delete_error = None;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_news_search_parse() {
+        let search = NewsSearch::parse("  server   maintenance ");
+        assert_eq!(search.query, "server maintenance");
+        assert!(!search.has_filter());
+
+        let search = NewsSearch::parse("Author:alice release since:2024-01-01 until:2024-06-30");
+        assert_eq!(
+            search,
+            NewsSearch {
+                query: "release".to_string(),
+                author: Some("alice".to_string()),
+                since: Some("2024-01-01".to_string()),
+                until: Some("2024-06-30".to_string()),
+            }
+        );
+
+        // Unknown or empty filters are searched as words
+        let search = NewsSearch::parse("note: author: time:12:30");
+        assert_eq!(search.query, "note: author: time:12:30");
+        assert!(!search.has_filter());
+    }
+
+    #[test]
+    fn test_news_search_state() {
+        let mut state = NewsManagementState {
+            news_items: Some(Ok(Vec::new())),
+            search_input: "release".to_string(),
+            search_query: Some("release".to_string()),
+            search_results: Some(Ok(Vec::new())),
+            ..Default::default()
+        };
+        assert!(state.is_searching());
+        assert_eq!(state.loaded_items_mut().count(), 2);
+
+        state.clear_search();
+        assert!(!state.is_searching());
+        assert!(state.search_input.is_empty());
+        assert!(state.search_results.is_none());
+        assert_eq!(state.loaded_items_mut().count(), 1);
+    }
+
+    #[test]
+    fn test_set_attachment_caption() {
+        let mut state = NewsManagementState::default();
+        state
+            .form_attachments
+            .push(NewsFormAttachment::new(NewsAttachment::File {
+                path: "Music/set.mp3".to_string(),
+                caption: None,
+            }));
+
+        state.set_attachment_caption(0, "Friday's set".to_string());
+        assert_eq!(
+            state.form_attachments[0].attachment.caption(),
+            Some("Friday's set")
+        );
+
+        // Clearing the input removes the caption
+        state.set_attachment_caption(0, String::new());
+        assert_eq!(state.form_attachments[0].attachment.caption(), None);
+
+        // Out of range indexes are ignored
+        state.set_attachment_caption(1, "ignored".to_string());
+        assert_eq!(state.form_attachments.len(), 1);
+    }
+}
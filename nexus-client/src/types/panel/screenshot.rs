@@ -0,0 +1,212 @@
+//! Screenshot annotation editor state
+
+use iced::Point;
+use iced::widget::image::Handle;
+
+use super::super::ChatTab;
+use crate::screenshot::Screenshot;
+use crate::screenshot::annotate::{self, Tool};
+use crate::style::{SCREENSHOT_PREVIEW_MAX_HEIGHT, SCREENSHOT_PREVIEW_MAX_WIDTH};
+
+// =============================================================================
+// Screenshot Editor State
+// =============================================================================
+
+/// State for the screenshot annotation editor
+///
+/// Pointer positions are kept in preview coordinates (what the mouse area
+/// reports) and mapped to image pixels when an edit is applied.
+#[derive(Clone)]
+pub struct ScreenshotEditorState {
+    /// Chat tab the screenshot will be shared to
+    pub target: ChatTab,
+    /// Current image, including applied edits
+    pub image: Screenshot,
+    /// Preview handle for `image` (rebuilt after every edit)
+    pub handle: Handle,
+    /// Earlier versions of the image, most recent last
+    pub undo: Vec<Screenshot>,
+    /// Selected annotation tool
+    pub tool: Tool,
+    /// Where the current drag started (None when not dragging)
+    pub drag_start: Option<Point>,
+    /// Last pointer position over the preview
+    pub pointer: Point,
+    /// Whether the screenshot is being saved for upload
+    pub sending: bool,
+    /// Error message (if saving failed)
+    pub error: Option<String>,
+}
+
+// Manual Debug implementation so the pixel buffers aren't printed
+impl std::fmt::Debug for ScreenshotEditorState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScreenshotEditorState")
+            .field("target", &self.target)
+            .field("image", &self.image)
+            .field("undo", &self.undo.len())
+            .field("tool", &self.tool)
+            .field("drag_start", &self.drag_start)
+            .field("sending", &self.sending)
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+impl ScreenshotEditorState {
+    /// Open the editor on a fresh capture
+    pub fn new(target: ChatTab, image: Screenshot) -> Self {
+        let handle = preview_handle(&image);
+        Self {
+            target,
+            image,
+            handle,
+            undo: Vec::new(),
+            tool: Tool::default(),
+            drag_start: None,
+            pointer: Point::ORIGIN,
+            sending: false,
+            error: None,
+        }
+    }
+
+    /// Size the preview is drawn at (fits the editor, never upscaled)
+    pub fn preview_size(&self) -> (f32, f32) {
+        let (width, height) = self.image.0.dimensions();
+        let (width, height) = (width as f32, height as f32);
+        let scale = (SCREENSHOT_PREVIEW_MAX_WIDTH / width)
+            .min(SCREENSHOT_PREVIEW_MAX_HEIGHT / height)
+            .min(1.0);
+        (width * scale, height * scale)
+    }
+
+    /// Crop rectangle being dragged, in preview coordinates `(x, y, width, height)`
+    pub fn crop_selection(&self) -> Option<(f32, f32, f32, f32)> {
+        if self.tool != Tool::Crop {
+            return None;
+        }
+        let start = self.drag_start?;
+        let (x, y) = (start.x.min(self.pointer.x), start.y.min(self.pointer.y));
+        Some((
+            x,
+            y,
+            (start.x - self.pointer.x).abs(),
+            (start.y - self.pointer.y).abs(),
+        ))
+    }
+
+    /// Start a drag at the current pointer position
+    pub fn begin_drag(&mut self) {
+        self.drag_start = Some(self.pointer);
+    }
+
+    /// Finish the current drag, applying the selected tool
+    ///
+    /// Returns true if the image changed.
+    pub fn end_drag(&mut self) -> bool {
+        let Some(start) = self.drag_start.take() else {
+            return false;
+        };
+        let from = self.to_image(start);
+        let to = self.to_image(self.pointer);
+
+        let edited = match self.tool {
+            Tool::Crop => annotate::crop(&self.image.0, from, to).map(Screenshot),
+            Tool::Arrow => {
+                let mut image = self.image.clone();
+                annotate::draw_arrow(&mut image.0, from, to).then_some(image)
+            }
+        };
+        let Some(edited) = edited else {
+            return false;
+        };
+
+        self.undo.push(std::mem::replace(&mut self.image, edited));
+        self.handle = preview_handle(&self.image);
+        true
+    }
+
+    /// Revert the most recent edit
+    pub fn undo(&mut self) {
+        if let Some(previous) = self.undo.pop() {
+            self.image = previous;
+            self.handle = preview_handle(&self.image);
+        }
+    }
+
+    /// Map a preview position to image pixels
+    fn to_image(&self, point: Point) -> (f32, f32) {
+        let (preview_width, _) = self.preview_size();
+        let scale = self.image.0.width() as f32 / preview_width;
+        (point.x * scale, point.y * scale)
+    }
+}
+
+/// Build a preview handle for an image
+fn preview_handle(image: &Screenshot) -> Handle {
+    Handle::from_rgba(image.0.width(), image.0.height(), image.0.as_raw().clone())
+}
+
+// =============================================================================
+// Screenshot Share
+// =============================================================================
+
+/// A screenshot upload whose link is posted to chat once the upload completes
+#[derive(Debug, Clone)]
+pub struct ScreenshotShare {
+    /// Connection the screenshot was shared on
+    pub connection_id: usize,
+    /// Chat tab the link is posted to
+    pub target: ChatTab,
+    /// Remote path of the uploaded file
+    pub remote_path: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use ::image::{Rgba, RgbaImage};
+
+    use super::*;
+
+    fn editor(width: u32, height: u32) -> ScreenshotEditorState {
+        let image = RgbaImage::from_pixel(width, height, Rgba([255, 255, 255, 255]));
+        ScreenshotEditorState::new(ChatTab::Console, Screenshot(image))
+    }
+
+    #[test]
+    fn test_preview_size_fits_without_upscaling() {
+        assert_eq!(editor(200, 100).preview_size(), (200.0, 100.0));
+
+        let (width, height) = editor(3200, 1600).preview_size();
+        assert!(width <= SCREENSHOT_PREVIEW_MAX_WIDTH);
+        assert!(height <= SCREENSHOT_PREVIEW_MAX_HEIGHT);
+        assert!((width / height - 2.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_crop_maps_preview_to_image() {
+        let mut state = editor(3200, 1600);
+        let (preview_width, preview_height) = state.preview_size();
+
+        state.pointer = Point::ORIGIN;
+        state.begin_drag();
+        state.pointer = Point::new(preview_width / 2.0, preview_height / 2.0);
+        assert!(state.end_drag());
+        assert_eq!(state.image.0.dimensions(), (1600, 800));
+
+        state.undo();
+        assert_eq!(state.image.0.dimensions(), (3200, 1600));
+        assert!(state.undo.is_empty());
+    }
+
+    #[test]
+    fn test_click_is_not_an_edit() {
+        let mut state = editor(200, 100);
+        state.tool = Tool::Arrow;
+        state.pointer = Point::new(50.0, 50.0);
+        state.begin_drag();
+        assert!(!state.end_drag());
+        assert!(state.undo.is_empty());
+        assert!(state.drag_start.is_none());
+    }
+}
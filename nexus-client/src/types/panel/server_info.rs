@@ -1,5 +1,7 @@
 //! Server info display and edit state
 
+use nexus_common::protocol::PortCheck;
+
 use crate::image::{CachedImage, decode_data_uri_max_width};
 use crate::style::SERVER_IMAGE_MAX_CACHE_WIDTH;
 
@@ -24,6 +26,29 @@ pub enum ServerInfoTab {
     Channels,
 }
 
+// =============================================================================
+// Connectivity Check
+// =============================================================================
+
+/// Connectivity check shown in the server info panel (admin only)
+#[derive(Debug, Clone, Default)]
+pub enum ConnectivityCheckState {
+    /// No check run yet on this connection
+    #[default]
+    Idle,
+    /// Waiting for the server (the check takes a few seconds)
+    Running,
+    /// Check finished
+    Done {
+        /// Address the check service saw
+        external_ip: Option<String>,
+        /// Result for each port
+        ports: Vec<PortCheck>,
+    },
+    /// Check failed (error from the server)
+    Failed(String),
+}
+
 // =============================================================================
 // Server Info Edit State
 // =============================================================================
@@ -36,6 +61,9 @@ pub struct ServerInfoParams<'a> {
     pub description: Option<&'a str>,
     pub max_connections_per_ip: Option<u32>,
     pub max_transfers_per_ip: Option<u32>,
+    pub max_active_transfers: Option<u32>,
+    pub transfer_small_files_first: Option<bool>,
+    pub trusted_transfer_weight: Option<u32>,
     pub image: &'a str,
     pub file_reindex_interval: Option<u32>,
     pub persistent_channels: Option<&'a str>,
@@ -56,6 +84,12 @@ pub struct ServerInfoEditState {
     pub max_connections_per_ip: Option<u32>,
     /// Max transfers per IP (editable, uses NumberInput)
     pub max_transfers_per_ip: Option<u32>,
+    /// Max active transfers server-wide (editable, uses NumberInput, 0 = unlimited)
+    pub max_active_transfers: Option<u32>,
+    /// Whether queued transfers favour small files (editable)
+    pub transfer_small_files_first: bool,
+    /// Fair-share weight for trusted IPs (editable, uses NumberInput, minimum 1)
+    pub trusted_transfer_weight: Option<u32>,
     /// Server image data URI (editable, empty string means no image)
     pub image: String,
     /// File reindex interval in minutes (editable, 0 = disabled)
@@ -78,6 +112,12 @@ impl std::fmt::Debug for ServerInfoEditState {
             .field("description", &self.description)
             .field("max_connections_per_ip", &self.max_connections_per_ip)
             .field("max_transfers_per_ip", &self.max_transfers_per_ip)
+            .field("max_active_transfers", &self.max_active_transfers)
+            .field(
+                "transfer_small_files_first",
+                &self.transfer_small_files_first,
+            )
+            .field("trusted_transfer_weight", &self.trusted_transfer_weight)
             .field("image", &format!("<{} bytes>", self.image.len()))
             .field("file_reindex_interval", &self.file_reindex_interval)
             .field("persistent_channels", &self.persistent_channels)
@@ -106,6 +146,9 @@ impl ServerInfoEditState {
             description: params.description.unwrap_or("").to_string(),
             max_connections_per_ip: params.max_connections_per_ip,
             max_transfers_per_ip: params.max_transfers_per_ip,
+            max_active_transfers: params.max_active_transfers,
+            transfer_small_files_first: params.transfer_small_files_first.unwrap_or(false),
+            trusted_transfer_weight: params.trusted_transfer_weight,
             image: params.image.to_string(),
             file_reindex_interval: params.file_reindex_interval,
             persistent_channels: params.persistent_channels.unwrap_or("").to_string(),
@@ -121,6 +164,11 @@ impl ServerInfoEditState {
         let desc_changed = self.description != original.description.unwrap_or("");
         let max_conn_changed = self.max_connections_per_ip != original.max_connections_per_ip;
         let max_xfer_changed = self.max_transfers_per_ip != original.max_transfers_per_ip;
+        let max_active_changed = self.max_active_transfers != original.max_active_transfers;
+        let small_first_changed =
+            self.transfer_small_files_first != original.transfer_small_files_first.unwrap_or(false);
+        let trusted_weight_changed =
+            self.trusted_transfer_weight != original.trusted_transfer_weight;
         let image_changed = self.image != original.image;
         let reindex_changed = self.file_reindex_interval != original.file_reindex_interval;
         let persistent_changed =
@@ -131,6 +179,9 @@ impl ServerInfoEditState {
             || desc_changed
             || max_conn_changed
             || max_xfer_changed
+            || max_active_changed
+            || small_first_changed
+            || trusted_weight_changed
             || image_changed
             || reindex_changed
             || persistent_changed
@@ -0,0 +1,136 @@
+//! Server log panel state
+
+use std::collections::VecDeque;
+
+use nexus_common::protocol::{LogEntry, LogLevel};
+
+/// Most log lines kept per connection (the oldest are dropped)
+const SERVER_LOG_MAX_ENTRIES: usize = 1000;
+
+/// Minimum level choice in the server log level picker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ServerLogLevel(pub LogLevel);
+
+impl ServerLogLevel {
+    /// All level options for the dropdown, most severe first
+    pub fn all() -> [ServerLogLevel; 4] {
+        [
+            ServerLogLevel(LogLevel::Error),
+            ServerLogLevel(LogLevel::Warn),
+            ServerLogLevel(LogLevel::Info),
+            ServerLogLevel(LogLevel::Debug),
+        ]
+    }
+
+    /// Get the translation key for this level
+    pub fn translation_key(&self) -> &'static str {
+        match self.0 {
+            LogLevel::Error => "server-log-level-error",
+            LogLevel::Warn => "server-log-level-warn",
+            LogLevel::Info => "server-log-level-info",
+            LogLevel::Debug => "server-log-level-debug",
+        }
+    }
+}
+
+/// State for the server log panel
+#[derive(Debug, Clone)]
+pub struct ServerLogState {
+    /// Log lines received so far, oldest first
+    pub entries: VecDeque<LogEntry>,
+    /// Whether the server is streaming log lines to us
+    pub subscribed: bool,
+    /// Waiting for the LogSubscribeResponse
+    pub loading: bool,
+    /// Error from the last subscribe attempt
+    pub error: Option<String>,
+    /// Least severe level shown
+    pub level: LogLevel,
+    /// Only show lines whose module contains this (case-insensitive)
+    pub module_filter: String,
+}
+
+impl Default for ServerLogState {
+    fn default() -> Self {
+        Self {
+            entries: VecDeque::new(),
+            subscribed: false,
+            loading: false,
+            error: None,
+            level: LogLevel::Info,
+            module_filter: String::new(),
+        }
+    }
+}
+
+impl ServerLogState {
+    /// Reset before subscribing (filters are kept)
+    pub fn reset(&mut self) {
+        self.entries.clear();
+        self.subscribed = false;
+        self.loading = false;
+        self.error = None;
+    }
+
+    /// Add a log line, dropping the oldest when full
+    pub fn push(&mut self, entry: LogEntry) {
+        if self.entries.len() == SERVER_LOG_MAX_ENTRIES {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+    }
+
+    /// Log lines passing the level and module filters, oldest first
+    pub fn visible_entries(&self) -> impl Iterator<Item = &LogEntry> {
+        let module_filter = self.module_filter.trim().to_lowercase();
+        self.entries.iter().filter(move |entry| {
+            entry.level <= self.level
+                && (module_filter.is_empty()
+                    || entry.module.to_lowercase().contains(&module_filter))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(level: LogLevel, module: &str) -> LogEntry {
+        LogEntry {
+            timestamp: 0,
+            level,
+            module: module.to_string(),
+            message: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_push_drops_oldest() {
+        let mut state = ServerLogState::default();
+        for i in 0..SERVER_LOG_MAX_ENTRIES + 5 {
+            state.push(LogEntry {
+                timestamp: i as i64,
+                ..entry(LogLevel::Info, "main")
+            });
+        }
+        assert_eq!(state.entries.len(), SERVER_LOG_MAX_ENTRIES);
+        assert_eq!(state.entries.front().map(|e| e.timestamp), Some(5));
+    }
+
+    #[test]
+    fn test_visible_entries_filters_level_and_module() {
+        let mut state = ServerLogState::default();
+        state.push(entry(LogLevel::Error, "handlers::login"));
+        state.push(entry(LogLevel::Debug, "handlers::login"));
+        state.push(entry(LogLevel::Warn, "transfers::upload"));
+
+        // Default level hides debug lines
+        assert_eq!(state.visible_entries().count(), 2);
+
+        state.module_filter = "LOGIN".to_string();
+        assert_eq!(state.visible_entries().count(), 1);
+
+        state.level = LogLevel::Debug;
+        assert_eq!(state.visible_entries().count(), 2);
+    }
+}
@@ -1,6 +1,8 @@
 //! Settings panel form state
 
-use crate::config::Config;
+use crate::config::{Config, encryption};
+use uuid::Uuid;
+
 use crate::config::events::EventType;
 
 use crate::avatar::generate_identicon;
@@ -21,6 +23,8 @@ pub enum SettingsTab {
     Chat,
     /// Network settings (proxy configuration)
     Network,
+    /// Security settings (known server certificates)
+    Security,
     /// Files settings (download location)
     Files,
     /// Event notification settings
@@ -54,6 +58,8 @@ pub struct SettingsFormState {
     pub selected_event_type: EventType,
     /// Whether PTT key capture mode is active
     pub ptt_capturing: bool,
+    /// Whether show/hide window key capture mode is active
+    pub window_key_capturing: bool,
     /// Whether microphone test is active
     pub mic_testing: bool,
     /// Current microphone input level (0.0 - 1.0)
@@ -64,6 +70,24 @@ pub struct SettingsFormState {
     pub output_devices: Vec<crate::voice::audio::AudioDevice>,
     /// Cached input audio devices (populated once when settings opens)
     pub input_devices: Vec<crate::voice::audio::AudioDevice>,
+    /// Bookmark whose notification rules are shown in the Events tab
+    pub rules_bookmark: Option<Uuid>,
+    /// Channel name being entered for a new notification override
+    pub rules_channel_input: String,
+    /// Keyword being entered for the watch list
+    pub rules_keyword_input: String,
+    /// Known server whose certificate details are shown in the Security tab
+    pub known_server_expanded: Option<Uuid>,
+    /// Whether the config should be encrypted (applied on save)
+    pub encrypt_config: bool,
+    /// Whether the config was already encrypted when settings opened
+    pub config_encrypted: bool,
+    /// Whether the encrypted config wasn't unlocked at startup
+    pub config_locked: bool,
+    /// New master passphrase for config encryption
+    pub config_passphrase: String,
+    /// Master passphrase confirmation for config encryption
+    pub config_passphrase_confirm: String,
 }
 
 // Manual Debug implementation because CachedImage doesn't implement Debug
@@ -79,11 +103,21 @@ impl std::fmt::Debug for SettingsFormState {
             .field("default_avatar", &"<cached>")
             .field("selected_event_type", &self.selected_event_type)
             .field("ptt_capturing", &self.ptt_capturing)
+            .field("window_key_capturing", &self.window_key_capturing)
             .field("mic_testing", &self.mic_testing)
             .field("mic_level", &self.mic_level)
             .field("mic_error", &self.mic_error)
             .field("output_devices", &self.output_devices.len())
             .field("input_devices", &self.input_devices.len())
+            .field("rules_bookmark", &self.rules_bookmark)
+            .field("rules_channel_input", &self.rules_channel_input)
+            .field("rules_keyword_input", &self.rules_keyword_input)
+            .field("known_server_expanded", &self.known_server_expanded)
+            .field("encrypt_config", &self.encrypt_config)
+            .field("config_encrypted", &self.config_encrypted)
+            .field("config_locked", &self.config_locked)
+            .field("config_passphrase", &"<redacted>")
+            .field("config_passphrase_confirm", &"<redacted>")
             .finish()
     }
 }
@@ -115,11 +149,21 @@ impl SettingsFormState {
             default_avatar,
             selected_event_type: last_event_type,
             ptt_capturing: false,
+            window_key_capturing: false,
             mic_testing: false,
             mic_level: 0.0,
             mic_error: None,
             output_devices,
             input_devices,
+            rules_bookmark: config.bookmarks.first().map(|bookmark| bookmark.id),
+            rules_channel_input: String::new(),
+            rules_keyword_input: String::new(),
+            known_server_expanded: None,
+            encrypt_config: encryption::enabled() || encryption::locked(),
+            config_encrypted: encryption::enabled(),
+            config_locked: encryption::locked(),
+            config_passphrase: String::new(),
+            config_passphrase_confirm: String::new(),
         }
     }
 }
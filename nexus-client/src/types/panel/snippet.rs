@@ -0,0 +1,41 @@
+//! Snippet viewer panel state
+
+use iced::widget::markdown;
+use nexus_common::protocol::SnippetInfo;
+
+use crate::snippet::fenced_code;
+
+/// State for the snippet viewer panel
+#[derive(Debug, Clone, Default)]
+pub struct SnippetViewState {
+    /// ID of the snippet shown or being fetched
+    pub id: String,
+    /// The snippet, once the SnippetGetResponse arrived
+    pub snippet: Option<SnippetInfo>,
+    /// The snippet as a highlighted code block
+    pub markdown: Vec<markdown::Item>,
+    /// Waiting for a SnippetGetResponse
+    pub loading: bool,
+    /// Error from the last request
+    pub error: Option<String>,
+}
+
+impl SnippetViewState {
+    /// Start fetching a snippet
+    pub fn loading(id: String) -> Self {
+        Self {
+            id,
+            loading: true,
+            ..Self::default()
+        }
+    }
+
+    /// Show a fetched snippet
+    pub fn set_snippet(&mut self, snippet: SnippetInfo) {
+        let code = fenced_code(&snippet.content, snippet.language.as_deref());
+        self.markdown = markdown::parse(&code).collect();
+        self.snippet = Some(snippet);
+        self.loading = false;
+        self.error = None;
+    }
+}
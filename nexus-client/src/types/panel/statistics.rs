@@ -0,0 +1,138 @@
+//! Statistics panel state
+
+use nexus_common::protocol::DailyStats;
+
+/// Number of days shown when the panel opens
+const DEFAULT_STATS_DAYS: u16 = 30;
+
+/// Report length choice in the statistics range picker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatsRange(pub u16);
+
+impl StatsRange {
+    /// All range options for the dropdown, shortest first
+    pub fn all() -> [StatsRange; 4] {
+        [
+            StatsRange(7),
+            StatsRange(30),
+            StatsRange(90),
+            StatsRange(365),
+        ]
+    }
+}
+
+impl Default for StatsRange {
+    fn default() -> Self {
+        StatsRange(DEFAULT_STATS_DAYS)
+    }
+}
+
+/// Which daily value the statistics chart shows
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StatsMetric {
+    #[default]
+    Logins,
+    Messages,
+    Uploads,
+    UploadBytes,
+    Downloads,
+    DownloadBytes,
+    PeakSessions,
+}
+
+impl StatsMetric {
+    /// All metric options for the dropdown
+    pub fn all() -> [StatsMetric; 7] {
+        [
+            StatsMetric::Logins,
+            StatsMetric::Messages,
+            StatsMetric::Uploads,
+            StatsMetric::UploadBytes,
+            StatsMetric::Downloads,
+            StatsMetric::DownloadBytes,
+            StatsMetric::PeakSessions,
+        ]
+    }
+
+    /// Get the translation key for this metric
+    pub fn translation_key(&self) -> &'static str {
+        match self {
+            StatsMetric::Logins => "stats-metric-logins",
+            StatsMetric::Messages => "stats-metric-messages",
+            StatsMetric::Uploads => "stats-metric-uploads",
+            StatsMetric::UploadBytes => "stats-metric-upload-bytes",
+            StatsMetric::Downloads => "stats-metric-downloads",
+            StatsMetric::DownloadBytes => "stats-metric-download-bytes",
+            StatsMetric::PeakSessions => "stats-metric-peak-sessions",
+        }
+    }
+
+    /// Whether values are byte counts (shown as sizes)
+    pub fn is_bytes(&self) -> bool {
+        matches!(self, StatsMetric::UploadBytes | StatsMetric::DownloadBytes)
+    }
+
+    /// This metric's value for a day
+    pub fn value(&self, day: &DailyStats) -> u64 {
+        match self {
+            StatsMetric::Logins => day.logins,
+            StatsMetric::Messages => day.messages,
+            StatsMetric::Uploads => day.uploads,
+            StatsMetric::UploadBytes => day.upload_bytes,
+            StatsMetric::Downloads => day.downloads,
+            StatsMetric::DownloadBytes => day.download_bytes,
+            StatsMetric::PeakSessions => u64::from(day.peak_sessions),
+        }
+    }
+
+    /// This metric over a whole report (peaks take the highest day, the rest are summed)
+    pub fn total(&self, days: &[DailyStats]) -> u64 {
+        let values = days.iter().map(|day| self.value(day));
+        match self {
+            StatsMetric::PeakSessions => values.max().unwrap_or(0),
+            _ => values.sum(),
+        }
+    }
+}
+
+/// State for the statistics panel
+#[derive(Debug, Clone, Default)]
+pub struct StatisticsState {
+    /// Daily totals from the last StatsResponse, oldest first
+    pub days: Vec<DailyStats>,
+    /// Waiting for a StatsResponse
+    pub loading: bool,
+    /// Error from the last request
+    pub error: Option<String>,
+    /// Number of days requested
+    pub range: StatsRange,
+    /// Metric shown in the chart
+    pub metric: StatsMetric,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(logins: u64, peak_sessions: u32) -> DailyStats {
+        DailyStats {
+            logins,
+            peak_sessions,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_total_sums_counts_and_keeps_peak() {
+        let days = [day(3, 5), day(4, 2), day(0, 7)];
+        assert_eq!(StatsMetric::Logins.total(&days), 7);
+        assert_eq!(StatsMetric::PeakSessions.total(&days), 7);
+        assert_eq!(StatsMetric::Messages.total(&days), 0);
+        assert_eq!(StatsMetric::PeakSessions.total(&[]), 0);
+    }
+
+    #[test]
+    fn test_default_range_is_offered() {
+        assert!(StatsRange::all().contains(&StatsRange::default()));
+    }
+}
@@ -1,9 +1,14 @@
 //! User management panel state
 
+use std::fmt;
+
 use nexus_common::ALL_PERMISSIONS;
-use nexus_common::protocol::UserInfo;
+use nexus_common::protocol::{
+    ChannelInfo, EffectivePermissions, FileEntry, PermissionHolder, UserInfo,
+};
 
 use super::super::ActivePanel;
+use crate::i18n::translate_permission;
 
 // =============================================================================
 // User Management State
@@ -57,13 +62,54 @@ pub enum UserManagementMode {
         /// Permissions (editable)
         permissions: Vec<(String, bool)>,
     },
+    /// Auditing who holds a permission, or what a user can do (admin only)
+    PermissionAudit,
+    /// Viewing the server as another user (admin only, read-only)
+    Impersonate {
+        /// Account being viewed
+        username: String,
+    },
     /// Confirming deletion of a user
     ConfirmDelete {
         /// Username to delete
         username: String,
+        /// Erase the user's personal data as well (admin only)
+        erase: bool,
     },
 }
 
+/// Permission choice in the audit picker (shown translated)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditPermission(pub String);
+
+impl fmt::Display for AuditPermission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", translate_permission(&self.0))
+    }
+}
+
+/// Result shown in the permission audit view
+#[derive(Debug, Clone)]
+pub enum PermissionAuditResult {
+    /// Accounts holding the selected permission
+    Holders(Vec<PermissionHolder>),
+    /// Effective permissions of one account
+    Effective(EffectivePermissions),
+}
+
+/// What an account would see, from an ImpersonateQueryResponse
+#[derive(Debug, Clone)]
+pub struct Impersonation {
+    /// Effective permissions of the account
+    pub effective: EffectivePermissions,
+    /// Directory listed (None if the account can't list files)
+    pub path: Option<String>,
+    /// Entries the account would see in `path`
+    pub entries: Option<Vec<FileEntry>>,
+    /// Channels the account would see (None if it can't list channels)
+    pub channels: Option<Vec<ChannelInfo>>,
+}
+
 /// User management panel state (per-connection)
 #[derive(Clone)]
 pub struct UserManagementState {
@@ -93,6 +139,12 @@ pub struct UserManagementState {
     pub list_error: Option<String>,
     /// Error message for delete confirmation dialog
     pub delete_error: Option<String>,
+    /// Permission selected in the audit view
+    pub audit_permission: Option<String>,
+    /// Audit result (None = nothing requested yet or loading)
+    pub audit: Option<Result<PermissionAuditResult, String>>,
+    /// Impersonation result (None = loading)
+    pub impersonation: Option<Result<Impersonation, String>>,
 }
 
 impl Default for UserManagementState {
@@ -114,6 +166,9 @@ impl Default for UserManagementState {
             edit_error: None,
             list_error: None,
             delete_error: None,
+            audit_permission: None,
+            audit: None,
+            impersonation: None,
         }
     }
 }
@@ -134,6 +189,9 @@ impl std::fmt::Debug for UserManagementState {
             .field("edit_error", &self.edit_error)
             .field("list_error", &self.list_error)
             .field("delete_error", &self.delete_error)
+            .field("audit_permission", &self.audit_permission)
+            .field("audit", &self.audit)
+            .field("impersonation", &self.impersonation)
             .finish()
     }
 }
@@ -199,9 +257,25 @@ impl UserManagementState {
         self.edit_error = None;
     }
 
+    /// Enter permission audit mode
+    pub fn enter_audit_mode(&mut self) {
+        self.audit_permission = None;
+        self.audit = None;
+        self.mode = UserManagementMode::PermissionAudit;
+    }
+
+    /// Enter impersonation mode for a user (shows loading until the response)
+    pub fn enter_impersonate_mode(&mut self, username: String) {
+        self.impersonation = None;
+        self.mode = UserManagementMode::Impersonate { username };
+    }
+
     /// Enter confirm delete mode for a user
     pub fn enter_confirm_delete_mode(&mut self, username: String) {
-        self.mode = UserManagementMode::ConfirmDelete { username };
+        self.mode = UserManagementMode::ConfirmDelete {
+            username,
+            erase: false,
+        };
         self.delete_error = None;
     }
 }
@@ -5,13 +5,22 @@
 //! - `/msg` - open a user message tab on successful delivery
 //! - `/info` - display user info in chat
 //! - Info icon click - populate the UserInfo panel
+//! - `/top` - display the leaderboard for the requested counter
 //!
 //! This module provides types to track these requests by message ID so the
-//! response handler knows how to route them.
+//! response handler knows how to route them. Each tracked request also
+//! remembers when it was sent, so one the server never answers can be given
+//! up on instead of leaving its panel loading forever.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
 use nexus_common::framing::MessageId;
+use nexus_common::protocol::LeaderboardStat;
+use uuid::Uuid;
 
-use crate::types::TabId;
+use crate::transfers::AutoDownloadCandidate;
+use crate::types::{AutoDownloadRule, ChatTab, TabId};
 
 /// How to route a response when it arrives
 #[derive(Debug, Clone)]
@@ -22,6 +31,10 @@ pub enum ResponseRouting {
     OpenMessageTab(String),
     /// Show error in user message tab on failure (from user message tab send)
     ShowErrorInMessageTab(String),
+    /// Open group tab on success (from `/group` command, with the members asked for)
+    OpenGroupTab(Vec<String>),
+    /// Show error in group tab on failure (from group tab send and `/invite`)
+    ShowErrorInGroupTab(u64),
     /// Display user info in chat (from `/info` command)
     DisplayUserInfoInChat,
     /// Populate user info panel if nickname matches (from info icon click)
@@ -51,6 +64,16 @@ pub enum ResponseRouting {
     /// News show result for refresh (after NewsUpdated broadcast)
     /// `is_new` indicates if this is a newly created post (for notification)
     NewsShowForRefresh { id: i64, is_new: bool },
+    /// News search result (populate search results in the news panel)
+    NewsSearchResult,
+    /// User message history fetched after login (fills user message tabs)
+    MessageHistorySync,
+    /// Older user messages for a conversation (from `/history more`)
+    MessageHistoryOlder(String),
+    /// Post a new snippet's link to the tab it was pasted into
+    PostSnippetLink(ChatTab),
+    /// Show a snippet if the snippet panel is still waiting for it
+    ShowSnippet(String),
     /// Populate file list (from panel open or navigation)
     /// Contains the target tab ID to update when response arrives
     /// `uri_target` is set when navigating via URI - the target file/folder to find
@@ -58,6 +81,14 @@ pub enum ResponseRouting {
         tab_id: TabId,
         uri_target: Option<String>,
     },
+    /// Listing to refresh a tab after a change in its directory
+    /// Contains the target tab ID and the directory, so responses for a tab
+    /// that has since moved elsewhere are ignored
+    RefreshFileList {
+        tab_id: TabId,
+        path: String,
+        root: bool,
+    },
     /// File create directory result (close dialog on success, show error on failure)
     /// Contains the target tab ID to update when response arrives
     FileCreateDirResult { tab_id: TabId },
@@ -67,6 +98,35 @@ pub enum ResponseRouting {
     /// File info result (display info dialog on success, show error on failure)
     /// Contains the target tab ID to update when response arrives
     FileInfoResult { tab_id: TabId },
+    /// File rating or comment result (updates the info dialog's review)
+    /// Contains the target tab ID and the reviewed path, so responses for a
+    /// file whose dialog has since closed are ignored
+    FileReviewResult { tab_id: TabId, path: String },
+    /// Upload rule load or save result (updates the Upload Rules dialog)
+    /// Contains the target tab ID and the directory path, so responses for a
+    /// dialog that has since closed are ignored
+    FileUploadRuleResult { tab_id: TabId, path: String },
+    /// Directory manifest result (fills the Download Selected dialog)
+    /// Contains the target tab ID to update when response arrives
+    FileManifestResult { tab_id: TabId },
+    /// Upload destination lookup result (resolves the upload preview)
+    /// Contains the target tab ID and the preview's ID, so responses for a
+    /// preview that has since been cancelled or replaced are ignored
+    FileStatResult { tab_id: TabId, preview_id: Uuid },
+    /// Remote file lookup for a download's duplicate check
+    /// Contains the target tab ID and the check's ID, so responses for a
+    /// check that has since been answered are ignored
+    DownloadCheckStat { tab_id: TabId, check_id: Uuid },
+    /// Folder listing for an auto-download rule
+    /// Contains the rule, so responses for a rule that has since been
+    /// changed or removed are ignored
+    AutoDownloadList { rule: AutoDownloadRule },
+    /// Remote file lookup for the new files in an auto-download folder
+    /// Contains the rule and the files that were looked up
+    AutoDownloadStat {
+        rule: AutoDownloadRule,
+        candidates: Vec<AutoDownloadCandidate>,
+    },
     /// File rename result (close dialog on success, show error on failure)
     /// Contains the target tab ID to update when response arrives
     FileRenameResult { tab_id: TabId },
@@ -84,16 +144,39 @@ pub enum ResponseRouting {
     },
     /// File search result (populate search results in tab)
     FileSearchResult { tab_id: TabId },
+    /// Most downloaded files (populate highlights in tab)
+    FilePopularResult { tab_id: TabId },
+    /// Recently uploaded files (populate highlights in tab)
+    FileRecentResult { tab_id: TabId },
     /// Away command result - contains optional status message for display
     AwayResult(Option<String>),
     /// Back command result
     BackResult,
+    /// Automatic away after idle time (no chat feedback)
+    AutoAwayResult,
+    /// Automatic back after activity resumed (no chat feedback)
+    AutoBackResult,
     /// Status command result - contains optional status message for display
     StatusResult(Option<String>),
+    /// Channel rejoined from the bookmark's saved workspace (keeps its saved
+    /// tab position; `activate` if it was the active tab)
+    RejoinChannel { activate: bool },
     /// Secret command result - contains channel name and new secret value
     SecretResult { channel: String, secret: bool },
+    /// Entry message/rules command result - contains channel name and new
+    /// entry message (None if unchanged, Some("") if cleared)
+    EntryUpdateResult {
+        channel: String,
+        entry_message: Option<String>,
+    },
     /// Ping command result - contains send time for latency calculation
     PingResult(std::time::Instant),
+    /// Top command result - contains the counter the leaderboard is sorted by
+    LeaderboardResult(LeaderboardStat),
+    /// Today's activity for the admin dashboard
+    AdminDashboardToday,
+    /// Background health ping - contains send time for latency calculation
+    HealthPing(std::time::Instant),
 }
 
 /// Extension trait for tracking pending requests
@@ -105,16 +188,90 @@ pub trait PendingRequests {
     fn track(&mut self, message_id: MessageId, routing: ResponseRouting);
 }
 
-impl PendingRequests for std::collections::HashMap<MessageId, ResponseRouting> {
+impl PendingRequests for HashMap<MessageId, ResponseRouting> {
     fn track(&mut self, message_id: MessageId, routing: ResponseRouting) {
         self.insert(message_id, routing);
     }
 }
 
+impl ResponseRouting {
+    /// Whether the request is given up on if no response arrives in time
+    ///
+    /// Health pings are excluded since the next ping tick already counts an
+    /// unanswered one as dropped.
+    pub fn can_time_out(&self) -> bool {
+        !matches!(self, Self::HealthPing(_))
+    }
+}
+
+/// Requests waiting for a response, with the time each was sent
+#[derive(Debug, Clone, Default)]
+pub struct TrackedRequests {
+    requests: HashMap<MessageId, (ResponseRouting, Instant)>,
+}
+
+impl TrackedRequests {
+    /// Stop tracking a request, returning how to route its response
+    pub fn remove(&mut self, message_id: &MessageId) -> Option<ResponseRouting> {
+        self.requests.remove(message_id).map(|(routing, _)| routing)
+    }
+
+    /// How to route a request's response, if it's still tracked
+    pub fn get(&self, message_id: &MessageId) -> Option<&ResponseRouting> {
+        self.requests.get(message_id).map(|(routing, _)| routing)
+    }
+
+    /// Routing of every tracked request
+    pub fn values(&self) -> impl Iterator<Item = &ResponseRouting> {
+        self.requests.values().map(|(routing, _)| routing)
+    }
+
+    /// Keep only the requests for which `keep` returns true
+    pub fn retain(&mut self, mut keep: impl FnMut(&MessageId, &mut ResponseRouting) -> bool) {
+        self.requests
+            .retain(|message_id, (routing, _)| keep(message_id, routing));
+    }
+
+    /// Stop tracking requests sent more than `timeout` before `now` that
+    /// can time out, returning them
+    pub fn take_timed_out(
+        &mut self,
+        now: Instant,
+        timeout: Duration,
+    ) -> Vec<(MessageId, ResponseRouting)> {
+        let timed_out: Vec<MessageId> = self
+            .requests
+            .iter()
+            .filter(|(_, (routing, sent))| {
+                routing.can_time_out() && now.saturating_duration_since(*sent) >= timeout
+            })
+            .map(|(message_id, _)| *message_id)
+            .collect();
+
+        timed_out
+            .into_iter()
+            .filter_map(|message_id| {
+                self.remove(&message_id)
+                    .map(|routing| (message_id, routing))
+            })
+            .collect()
+    }
+
+    /// Track a request sent at a given time
+    fn track_at(&mut self, message_id: MessageId, routing: ResponseRouting, sent: Instant) {
+        self.requests.insert(message_id, (routing, sent));
+    }
+}
+
+impl PendingRequests for TrackedRequests {
+    fn track(&mut self, message_id: MessageId, routing: ResponseRouting) {
+        self.track_at(message_id, routing, Instant::now());
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::HashMap;
 
     #[test]
     fn test_track_display_list_in_chat() {
@@ -480,4 +637,40 @@ mod tests {
             Some(ResponseRouting::StatusResult(None))
         ));
     }
+
+    #[test]
+    fn test_take_timed_out() {
+        let mut pending = TrackedRequests::default();
+        let now = Instant::now();
+        let timeout = Duration::from_secs(30);
+        let old = MessageId::new();
+        let recent = MessageId::new();
+        let ping = MessageId::new();
+        pending.track_at(old, ResponseRouting::PopulateNewsList, now);
+        pending.track_at(recent, ResponseRouting::BackResult, now + timeout / 2);
+        pending.track_at(ping, ResponseRouting::HealthPing(now), now);
+
+        let timed_out = pending.take_timed_out(now + timeout, timeout);
+        assert_eq!(timed_out.len(), 1);
+        assert_eq!(timed_out[0].0, old);
+        assert!(matches!(timed_out[0].1, ResponseRouting::PopulateNewsList));
+
+        // Timed out requests are no longer tracked; the others still are
+        assert!(pending.remove(&old).is_none());
+        assert!(pending.get(&recent).is_some());
+        assert!(pending.get(&ping).is_some());
+    }
+
+    #[test]
+    fn test_tracked_requests_retain() {
+        let mut pending = TrackedRequests::default();
+        let keep = MessageId::new();
+        let drop = MessageId::new();
+        pending.track(keep, ResponseRouting::BackResult);
+        pending.track(drop, ResponseRouting::FileSearchResult { tab_id: 1 });
+
+        pending.retain(|_, routing| !matches!(routing, ResponseRouting::FileSearchResult { .. }));
+        assert_eq!(pending.values().count(), 1);
+        assert!(pending.remove(&keep).is_some());
+    }
 }
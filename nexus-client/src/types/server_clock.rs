@@ -0,0 +1,119 @@
+//! Server clock for the status bar and message times
+//!
+//! The server sends its clock in ServerInfo at login, with each
+//! `ServerInfoUpdated`, and in every `Pong`. Only the difference from our
+//! clock is kept, so the server's time can be shown live without asking
+//! again, and times we stamp ourselves line up with the server's even when
+//! our clock is off.
+
+use chrono::{DateTime, FixedOffset, Local, TimeDelta, Utc};
+use nexus_common::protocol::ServerTime;
+use nexus_common::validators;
+
+/// The server's clock, relative to ours
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerClock {
+    /// Seconds the server's clock is ahead of ours (negative if behind)
+    skew: i64,
+    /// The server's time zone
+    offset: FixedOffset,
+    /// IANA name of the server's time zone, if it sent a valid one
+    timezone: Option<String>,
+}
+
+impl ServerClock {
+    /// Clock from a reading the server just sent
+    ///
+    /// Returns None if the time zone offset is out of range.
+    pub fn new(time: ServerTime, now: DateTime<Utc>) -> Option<Self> {
+        Some(Self {
+            skew: time.timestamp - now.timestamp(),
+            offset: FixedOffset::east_opt(time.utc_offset)?,
+            timezone: time
+                .timezone
+                .filter(|name| validators::validate_timezone(name).is_ok()),
+        })
+    }
+
+    /// The server's local time at `now` on our clock
+    pub fn time_at(&self, now: DateTime<Utc>) -> DateTime<FixedOffset> {
+        (now + TimeDelta::seconds(self.skew)).with_timezone(&self.offset)
+    }
+
+    /// `now` on our clock corrected to the server's, in our time zone
+    pub fn local_time_at(&self, now: DateTime<Utc>) -> DateTime<Local> {
+        (now + TimeDelta::seconds(self.skew)).with_timezone(&Local)
+    }
+
+    /// Name of the server's time zone, or its offset from UTC if unnamed
+    /// (e.g. "Europe/Berlin" or "UTC+02:00")
+    pub fn zone_label(&self) -> String {
+        match &self.timezone {
+            Some(name) => name.clone(),
+            None => format!("UTC{}", self.offset),
+        }
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_server_time_follows_our_clock() {
+        let received = DateTime::from_timestamp(1_760_000_000, 0).unwrap();
+        // Server is 90 seconds ahead, two hours east of UTC
+        let time = ServerTime {
+            timestamp: 1_760_000_090,
+            utc_offset: 7200,
+            timezone: None,
+        };
+        let clock = ServerClock::new(time, received).unwrap();
+
+        let later = received + TimeDelta::seconds(60);
+        let server_time = clock.time_at(later);
+        assert_eq!(server_time.timestamp(), 1_760_000_150);
+        assert_eq!(server_time.offset().local_minus_utc(), 7200);
+        assert_eq!(clock.local_time_at(later).timestamp(), 1_760_000_150);
+    }
+
+    #[test]
+    fn test_zone_label() {
+        let time = ServerTime {
+            timestamp: 0,
+            utc_offset: -16_200,
+            timezone: None,
+        };
+        let clock = ServerClock::new(time.clone(), Utc::now()).unwrap();
+        assert_eq!(clock.zone_label(), "UTC-04:30");
+
+        let named = ServerTime {
+            timezone: Some("America/Caracas".to_string()),
+            ..time.clone()
+        };
+        let clock = ServerClock::new(named, Utc::now()).unwrap();
+        assert_eq!(clock.zone_label(), "America/Caracas");
+
+        // A name a client wouldn't display falls back to the offset
+        let invalid = ServerTime {
+            timezone: Some("Caracas time\n".to_string()),
+            ..time
+        };
+        let clock = ServerClock::new(invalid, Utc::now()).unwrap();
+        assert_eq!(clock.zone_label(), "UTC-04:30");
+    }
+
+    #[test]
+    fn test_invalid_offset() {
+        let time = ServerTime {
+            timestamp: 0,
+            utc_offset: 86_400,
+            timezone: None,
+        };
+        assert_eq!(ServerClock::new(time, Utc::now()), None);
+    }
+}
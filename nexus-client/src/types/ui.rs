@@ -1,8 +1,12 @@
 //! UI state and widget identifier types
 
-use iced::widget::Id;
+use iced::widget::{Id, pane_grid};
 use uuid::Uuid;
 
+use super::{BookmarkDropTarget, ChatTab};
+use crate::config::settings::SPLIT_RATIO_DEFAULT;
+use crate::quick_switcher::QuickSwitchTarget;
+
 /// Which panel is currently active in the main content area
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum ActivePanel {
@@ -17,10 +21,20 @@ pub enum ActivePanel {
     ChangePassword,
     /// Connection Monitor panel (view active connections)
     ConnectionMonitor,
+    /// Doors panel (run server programs in a terminal, opened from Server Info)
+    Doors,
+    /// Server Log panel (follow the server log)
+    ServerLog,
     /// Settings panel
     Settings,
     /// Server Info panel
     ServerInfo,
+    /// Statistics panel (daily activity charts, opened from Server Info)
+    Statistics,
+    /// Snippet panel (shows a snippet opened from a link)
+    Snippet,
+    /// Admin Dashboard panel (server health at a glance, opened from Server Info)
+    AdminDashboard,
     /// User Info panel (triggered by info icon click)
     UserInfo,
     /// User Management panel (create, edit, delete users)
@@ -33,6 +47,16 @@ pub enum ActivePanel {
     Transfers,
 }
 
+impl ActivePanel {
+    /// Whether split view shows this panel beside chat instead of over it
+    pub fn shows_beside_chat(self) -> bool {
+        matches!(
+            self,
+            ActivePanel::Files | ActivePanel::News | ActivePanel::ConnectionMonitor
+        )
+    }
+}
+
 /// UI visibility state for toggleable panels
 /// Global UI state that persists across connection changes
 #[derive(Debug, Clone)]
@@ -43,6 +67,22 @@ pub struct UiState {
     pub show_user_list: bool,
     /// App-wide active panel (Settings, About) - takes precedence over connection panels
     pub active_panel: ActivePanel,
+    /// Chat and panel side by side (when split view is enabled)
+    pub split_layout: SplitLayout,
+    /// Bookmark being dragged to a new place in the sidebar
+    pub bookmark_drag: Option<BookmarkDrag>,
+    /// Bookmark folder being named in the sidebar
+    pub bookmark_group_edit: Option<BookmarkGroupEdit>,
+    /// Quick switcher overlay (Ctrl+K), when open
+    pub quick_switcher: Option<QuickSwitcherState>,
+    /// Places jumped to recently, most recent first
+    pub recent_switches: Vec<QuickSwitchTarget>,
+    /// Spelling suggestions for the word last right-clicked in an editor
+    pub spell_menu: Option<SpellMenu>,
+    /// Transfers panel shows the transfer history instead of the queue
+    pub show_transfer_history: bool,
+    /// Search text in the transfer history
+    pub transfer_history_search: String,
 }
 
 impl Default for UiState {
@@ -51,6 +91,124 @@ impl Default for UiState {
             show_bookmarks: true,
             show_user_list: true,
             active_panel: ActivePanel::None,
+            split_layout: SplitLayout::new(SPLIT_RATIO_DEFAULT),
+            bookmark_drag: None,
+            bookmark_group_edit: None,
+            quick_switcher: None,
+            recent_switches: Vec::new(),
+            spell_menu: None,
+            show_transfer_history: false,
+            transfer_history_search: String::new(),
+        }
+    }
+}
+
+/// Text editor that is spell checked
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpellField {
+    /// Chat message input
+    ChatInput,
+    /// News post body (active connection)
+    NewsBody,
+}
+
+/// Spelling suggestions for a right-clicked word
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpellMenu {
+    /// Editor the word is in (selected there)
+    pub field: SpellField,
+    /// Misspelled word
+    pub word: String,
+    /// Replacements, best first (may be empty)
+    pub suggestions: Vec<String>,
+}
+
+/// A bookmark being dragged in the sidebar
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BookmarkDrag {
+    /// Bookmark being dragged
+    pub bookmark: Uuid,
+    /// Where it would drop if released now
+    pub target: Option<BookmarkDropTarget>,
+}
+
+/// A bookmark folder name being typed in the sidebar
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BookmarkGroupEdit {
+    /// Folder being renamed (None for a new folder)
+    pub group: Option<Uuid>,
+    /// Name typed so far
+    pub name: String,
+}
+
+/// The open quick switcher
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QuickSwitcherState {
+    /// Text typed so far
+    pub query: String,
+    /// Index of the highlighted match
+    pub selected: usize,
+}
+
+/// A side of the split view
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplitPane {
+    /// Chat, on the left
+    Chat,
+    /// Files, News or Connection Monitor, on the right
+    Panel,
+}
+
+/// Chat beside a panel, with a draggable divider between them
+#[derive(Debug, Clone)]
+pub struct SplitLayout {
+    /// The two panes
+    pub panes: pane_grid::State<SplitPane>,
+    /// The divider between them
+    pub split: pane_grid::Split,
+}
+
+impl SplitLayout {
+    /// Create a split giving chat `ratio` of the width
+    pub fn new(ratio: f32) -> Self {
+        let (mut panes, chat) = pane_grid::State::new(SplitPane::Chat);
+        let (_, split) = panes
+            .split(pane_grid::Axis::Vertical, chat, SplitPane::Panel)
+            .expect("splitting the only pane succeeds");
+        panes.resize(split, ratio);
+        Self { panes, split }
+    }
+
+    /// Move the divider to give chat `ratio` of the width
+    pub fn set_ratio(&mut self, ratio: f32) {
+        self.panes.resize(self.split, ratio);
+    }
+}
+
+/// A channel or user message tab popped out into its own window
+#[derive(Debug, Clone)]
+pub struct ChatWindow {
+    /// Connection the tab belongs to
+    pub connection_id: usize,
+    /// Tab shown in the window
+    pub tab: ChatTab,
+    /// Message input (separate from the main window's)
+    pub message_input: String,
+    /// Message input widget ID (unique per window)
+    pub input_id: Id,
+    /// Whether the window has focus (suppresses notifications for its tab)
+    pub focused: bool,
+}
+
+impl ChatWindow {
+    /// Create the state for a newly opened chat window
+    pub fn new(connection_id: usize, tab: ChatTab) -> Self {
+        Self {
+            connection_id,
+            tab,
+            message_input: String::new(),
+            input_id: Id::unique(),
+            focused: true,
         }
     }
 }
@@ -67,6 +225,8 @@ pub struct FingerprintMismatchDetails {
     pub expected: String,
     /// Received fingerprint (new)
     pub received: String,
+    /// Verified identity key presented with the new certificate
+    pub identity_key: Option<String>,
     /// Bookmark name for display
     pub bookmark_name: String,
     /// Server address (IP or hostname)
@@ -86,6 +246,8 @@ pub struct FingerprintMismatch {
     pub expected: String,
     /// Received fingerprint (new)
     pub received: String,
+    /// Verified identity key presented with the new certificate
+    pub identity_key: Option<String>,
     /// Bookmark name for display
     pub bookmark_name: String,
     /// Server address (IP or hostname)
@@ -98,6 +260,59 @@ pub struct FingerprintMismatch {
     pub display_name: String,
 }
 
+/// What the password vault dialog asks for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasswordVaultMode {
+    /// Choose a master passphrase for a new vault
+    Create,
+    /// Enter the master passphrase to unlock the existing vault
+    Unlock,
+    /// Enter the master passphrase to unlock the encrypted config
+    UnlockConfig,
+}
+
+/// Password vault dialog state
+///
+/// Shown at startup when the config is encrypted, or when no keychain is available.
+#[derive(Clone)]
+pub struct PasswordVaultDialog {
+    /// Whether the vault is being created or unlocked
+    pub mode: PasswordVaultMode,
+    /// Master passphrase input
+    pub passphrase: String,
+    /// Passphrase confirmation input (create mode only)
+    pub confirm: String,
+    /// Error message to display
+    pub error: Option<String>,
+    /// Whether bookmark auto-connect runs once the dialog closes
+    pub auto_connect: bool,
+}
+
+impl PasswordVaultDialog {
+    /// Create an empty dialog in the given mode
+    pub fn new(mode: PasswordVaultMode) -> Self {
+        Self {
+            mode,
+            passphrase: String::new(),
+            confirm: String::new(),
+            error: None,
+            auto_connect: false,
+        }
+    }
+}
+
+impl std::fmt::Debug for PasswordVaultDialog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PasswordVaultDialog")
+            .field("mode", &self.mode)
+            .field("passphrase", &"[REDACTED]")
+            .field("confirm", &"[REDACTED]")
+            .field("error", &self.error)
+            .field("auto_connect", &self.auto_connect)
+            .finish()
+    }
+}
+
 /// Text input IDs for focus management
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum InputId {
@@ -137,6 +352,10 @@ pub enum InputId {
     EditServerInfoMaxConnections,
     /// Server info edit: Max transfers per IP input
     EditServerInfoMaxTransfers,
+    /// Server info edit: Max active transfers (server-wide) input
+    EditServerInfoMaxActiveTransfers,
+    /// Server info edit: Trusted IP transfer weight input
+    EditServerInfoTrustedTransferWeight,
     /// Server info edit: File reindex interval input
     EditServerInfoFileReindexInterval,
     /// Broadcast panel: Message input
@@ -151,10 +370,20 @@ pub enum InputId {
     ChangePasswordConfirm,
     /// News panel: Body text editor
     NewsBody,
+    /// News panel: Search input
+    NewsSearchInput,
     /// Connection form: Nickname input
     Nickname,
+    /// Bookmark editor: Fallback addresses input
+    BookmarkFallbackAddresses,
     /// Bookmark editor: Nickname input
     BookmarkNickname,
+    /// Bookmark editor: Status input
+    BookmarkStatus,
+    /// Bookmark editor: Download folder input
+    BookmarkDownloadFolder,
+    /// Bookmark list: Folder name input
+    BookmarkGroupName,
     /// Settings panel: Nickname input
     SettingsNickname,
     /// Settings panel: Proxy address input
@@ -169,8 +398,24 @@ pub enum InputId {
     NewDirectoryName,
     /// Files panel: Rename name input
     RenameName,
+    /// Files panel: Upload rule allowed extensions input
+    UploadRuleExtensions,
+    /// Files panel: Auto-download file name pattern input
+    AutoDownloadPattern,
     /// Files panel: Search input
     FileSearchInput,
+    /// Password vault dialog: Master passphrase input
+    VaultPassphrase,
+    /// Password vault dialog: Confirm passphrase input
+    VaultPassphraseConfirm,
+    /// Settings panel: Config encryption passphrase input
+    ConfigPassphrase,
+    /// Settings panel: Config encryption passphrase confirmation input
+    ConfigPassphraseConfirm,
+    /// Quick switcher: Search input
+    QuickSwitcher,
+    /// User list: Filter input
+    UserListFilter,
 }
 
 impl From<InputId> for Id {
@@ -194,6 +439,12 @@ impl From<InputId> for Id {
             InputId::EditServerInfoDescription => "InputId::EditServerInfoDescription",
             InputId::EditServerInfoMaxConnections => "InputId::EditServerInfoMaxConnections",
             InputId::EditServerInfoMaxTransfers => "InputId::EditServerInfoMaxTransfers",
+            InputId::EditServerInfoMaxActiveTransfers => {
+                "InputId::EditServerInfoMaxActiveTransfers"
+            }
+            InputId::EditServerInfoTrustedTransferWeight => {
+                "InputId::EditServerInfoTrustedTransferWeight"
+            }
             InputId::EditServerInfoFileReindexInterval => {
                 "InputId::EditServerInfoFileReindexInterval"
             }
@@ -203,8 +454,13 @@ impl From<InputId> for Id {
             InputId::ChangePasswordNew => "InputId::ChangePasswordNew",
             InputId::ChangePasswordConfirm => "InputId::ChangePasswordConfirm",
             InputId::NewsBody => "InputId::NewsBody",
+            InputId::NewsSearchInput => "InputId::NewsSearchInput",
             InputId::Nickname => "InputId::Nickname",
+            InputId::BookmarkFallbackAddresses => "InputId::BookmarkFallbackAddresses",
             InputId::BookmarkNickname => "InputId::BookmarkNickname",
+            InputId::BookmarkStatus => "InputId::BookmarkStatus",
+            InputId::BookmarkDownloadFolder => "InputId::BookmarkDownloadFolder",
+            InputId::BookmarkGroupName => "InputId::BookmarkGroupName",
             InputId::SettingsNickname => "InputId::SettingsNickname",
             InputId::ProxyAddress => "InputId::ProxyAddress",
             InputId::ProxyPort => "InputId::ProxyPort",
@@ -212,7 +468,15 @@ impl From<InputId> for Id {
             InputId::ProxyPassword => "InputId::ProxyPassword",
             InputId::NewDirectoryName => "InputId::NewDirectoryName",
             InputId::RenameName => "InputId::RenameName",
+            InputId::UploadRuleExtensions => "InputId::UploadRuleExtensions",
+            InputId::AutoDownloadPattern => "InputId::AutoDownloadPattern",
             InputId::FileSearchInput => "InputId::FileSearchInput",
+            InputId::VaultPassphrase => "InputId::VaultPassphrase",
+            InputId::VaultPassphraseConfirm => "InputId::VaultPassphraseConfirm",
+            InputId::ConfigPassphrase => "InputId::ConfigPassphrase",
+            InputId::ConfigPassphraseConfirm => "InputId::ConfigPassphraseConfirm",
+            InputId::QuickSwitcher => "InputId::QuickSwitcher",
+            InputId::UserListFilter => "InputId::UserListFilter",
         })
     }
 }
@@ -8,12 +8,15 @@ use nexus_common::voice::VoiceQuality;
 use uuid::Uuid;
 
 use crate::config::audio::{PttMode, PttReleaseDelay};
-use crate::config::events::EventSettings;
-use crate::config::settings::{ChatHistoryRetention, ProxySettings};
-use crate::transfers::TransferManager;
+use crate::config::events::{EventSettings, QuietHours};
+use crate::config::settings::{
+    AutoAway, ChatHistoryRetention, CollisionPolicy, ProxySettings, Tip, UserListSettings,
+};
+use crate::spellcheck::SpellSettings;
+use crate::transfers::{TransferHistory, TransferManager};
 use crate::types::{
-    ActivePanel, BookmarkEditState, ConnectionFormState, ServerBookmark, ServerConnection,
-    SettingsFormState, UiState, UserManagementState,
+    ActivePanel, BookmarkEditState, BookmarkGroup, ConnectionFormState, ServerBookmark,
+    ServerConnection, SettingsFormState, UiState, UserManagementState,
 };
 use crate::voice::audio::AudioDevice;
 
@@ -46,6 +49,24 @@ pub struct ViewConfig<'a> {
     /// Show seconds in timestamps
     pub show_seconds: bool,
 
+    /// Show the Files, News and Connection Monitor panels beside chat
+    pub split_view: bool,
+
+    /// Mark misspelled words in the chat input and news editor (from config)
+    pub spell_check: bool,
+
+    /// Dictionary the editors are checked against (empty when off or missing)
+    pub spell_settings: SpellSettings,
+
+    /// Whether loading finished without finding a dictionary
+    pub spell_dictionary_missing: bool,
+
+    /// Tell senders of user messages when we've read them (from config)
+    pub send_read_receipts: bool,
+
+    /// User list grouping and sort order
+    pub user_list: UserListSettings,
+
     /// Settings form state (present when settings panel is open)
     pub settings_form: Option<&'a SettingsFormState>,
 
@@ -58,6 +79,9 @@ pub struct ViewConfig<'a> {
     /// Server bookmarks from config
     pub bookmarks: &'a [ServerBookmark],
 
+    /// Bookmark folders from config
+    pub bookmark_groups: &'a [BookmarkGroup],
+
     /// Per-bookmark connection errors (transient)
     pub bookmark_errors: &'a HashMap<Uuid, String>,
 
@@ -67,8 +91,8 @@ pub struct ViewConfig<'a> {
     /// Bookmark add/edit dialog state
     pub bookmark_edit: &'a BookmarkEditState,
 
-    /// Chat message input (from active connection or empty)
-    pub message_input: &'a str,
+    /// Chat message input editor (shows the active connection's input)
+    pub message_input: &'a text_editor::Content,
 
     /// Default nickname for shared account connections (from settings)
     pub nickname: &'a str,
@@ -97,6 +121,9 @@ pub struct ViewConfig<'a> {
     /// Transfer manager for file downloads/uploads (global, not per-connection)
     pub transfer_manager: &'a TransferManager,
 
+    /// Finished transfers, shown in the Transfers panel's history view
+    pub transfer_history: &'a TransferHistory,
+
     /// Whether to queue transfers (limit concurrent transfers per server)
     pub queue_transfers: bool,
 
@@ -106,6 +133,15 @@ pub struct ViewConfig<'a> {
     /// Maximum concurrent uploads per server (0 = unlimited)
     pub upload_limit: u8,
 
+    /// Open the containing folder when a download completes
+    pub auto_open_folder: bool,
+
+    /// What to do when a download's destination already exists
+    pub collision_policy: CollisionPolicy,
+
+    /// Ask before trusting a new server's certificate
+    pub require_fingerprint_approval: bool,
+
     /// Maximum scrollback lines per chat tab (0 = unlimited)
     pub max_scrollback: usize,
 
@@ -124,6 +160,12 @@ pub struct ViewConfig<'a> {
     /// Master volume for sounds (0.0 - 1.0)
     pub sound_volume: f32,
 
+    /// Manual do not disturb toggle
+    pub do_not_disturb: bool,
+
+    /// Scheduled do not disturb window
+    pub quiet_hours: QuietHours,
+
     /// Voice target for the current tab (channel or nickname)
     pub voice_target: Option<String>,
 
@@ -179,6 +221,12 @@ pub struct ViewConfig<'a> {
     /// Microphone boost level
     pub mic_boost: crate::config::audio::MicBoost,
 
+    /// Soundboard clip playback volume (0.0 - 1.0)
+    pub clip_volume: f32,
+
+    /// Read voice text messages aloud
+    pub voice_text_tts: bool,
+
     /// Whether local user is currently transmitting (PTT active)
     pub is_local_speaking: bool,
 
@@ -191,6 +239,15 @@ pub struct ViewConfig<'a> {
 
     /// Minimize to tray instead of closing
     pub minimize_to_tray: bool,
+
+    /// Global hotkey that shows or hides the window
+    pub toggle_window_key: Option<&'a str>,
+
+    /// Automatic away status after idle time
+    pub auto_away: AutoAway,
+
+    /// First-run tips not yet dismissed
+    pub pending_tips: &'a [Tip],
 }
 
 /// Toolbar state configuration
@@ -1,6 +1,44 @@
 //! Voice state types for tracking active voice UI state
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+use nexus_common::voice::VoiceConnectionQuality;
+
+/// Latest connection statistics the server reported for a voice participant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VoiceParticipantStats {
+    /// Percentage of the participant's packets lost on the way to the server
+    pub loss_percent: u8,
+    /// Interarrival jitter in milliseconds
+    pub jitter_ms: u32,
+}
+
+impl VoiceParticipantStats {
+    /// Rate the connection from the reported loss and jitter
+    pub fn quality(&self) -> VoiceConnectionQuality {
+        VoiceConnectionQuality::from_stats(self.loss_percent, self.jitter_ms)
+    }
+}
+
+/// How long a voice text message stays on screen
+pub const VOICE_TEXT_DISPLAY_DURATION: Duration = Duration::from_secs(8);
+
+/// Maximum number of voice text messages shown at once (oldest are dropped)
+pub const MAX_VOICE_TEXTS: usize = 3;
+
+/// A text message sent to the voice session, shown as an overlay bubble
+#[derive(Debug, Clone)]
+pub struct VoiceText {
+    /// Nickname of the sender
+    pub nickname: String,
+    /// Message text
+    pub message: String,
+    /// Whether the message names the local user
+    pub mention: bool,
+    /// When the message arrived (for expiry)
+    pub received_at: Instant,
+}
 
 /// Active voice state for UI display
 ///
@@ -18,6 +56,17 @@ pub struct VoiceState {
     /// Nicknames of users muted by the local user (lowercase for case-insensitive lookup)
     /// This is client-side only - stops playing audio from these users
     pub muted_users: HashSet<String>,
+    /// Position in the channel's voice join queue while waiting for a free slot
+    /// (None once joined)
+    pub queue_position: Option<u32>,
+    /// Latest connection statistics per participant (lowercase nickname keys)
+    pub participant_stats: HashMap<String, VoiceParticipantStats>,
+    /// Whether we are listening to a broadcast (listen-only, never transmits)
+    pub listener: bool,
+    /// Number of listeners while the session is a broadcast (None otherwise)
+    pub listeners: Option<u32>,
+    /// Recent text messages shown above the voice bar, oldest first
+    pub texts: Vec<VoiceText>,
 }
 
 impl VoiceState {
@@ -28,6 +77,11 @@ impl VoiceState {
             participants,
             speaking_users: HashSet::new(),
             muted_users: HashSet::new(),
+            queue_position: None,
+            participant_stats: HashMap::new(),
+            listener: false,
+            listeners: None,
+            texts: Vec::new(),
         }
     }
 
@@ -56,6 +110,7 @@ impl VoiceState {
         // Clear speaking state for the removed user (but keep muted state
         // in case they rejoin - user's mute preference should persist)
         self.speaking_users.remove(&nickname.to_lowercase());
+        self.participant_stats.remove(&nickname.to_lowercase());
     }
 
     /// Get the number of participants
@@ -97,4 +152,69 @@ impl VoiceState {
     pub fn is_muted(&self, nickname: &str) -> bool {
         self.muted_users.contains(&nickname.to_lowercase())
     }
+
+    /// Record the latest connection statistics for a participant
+    pub fn set_stats(&mut self, nickname: &str, stats: VoiceParticipantStats) {
+        self.participant_stats
+            .insert(nickname.to_lowercase(), stats);
+    }
+
+    /// Get the latest connection statistics for a participant, if any were reported
+    pub fn stats(&self, nickname: &str) -> Option<&VoiceParticipantStats> {
+        self.participant_stats.get(&nickname.to_lowercase())
+    }
+
+    /// Show a text message, dropping the oldest if too many are on screen
+    pub fn add_text(&mut self, text: VoiceText) {
+        self.texts.push(text);
+        if self.texts.len() > MAX_VOICE_TEXTS {
+            self.texts.remove(0);
+        }
+    }
+
+    /// Remove text messages that have been shown long enough
+    pub fn expire_texts(&mut self, now: Instant) {
+        self.texts.retain(|text| {
+            now.saturating_duration_since(text.received_at) < VOICE_TEXT_DISPLAY_DURATION
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(message: &str, received_at: Instant) -> VoiceText {
+        VoiceText {
+            nickname: "alice".to_string(),
+            message: message.to_string(),
+            mention: false,
+            received_at,
+        }
+    }
+
+    #[test]
+    fn test_add_text_drops_oldest() {
+        let mut state = VoiceState::new("#general".to_string(), vec![]);
+        let now = Instant::now();
+        for i in 0..=MAX_VOICE_TEXTS {
+            state.add_text(text(&i.to_string(), now));
+        }
+
+        assert_eq!(state.texts.len(), MAX_VOICE_TEXTS);
+        assert_eq!(state.texts[0].message, "1");
+    }
+
+    #[test]
+    fn test_expire_texts() {
+        let mut state = VoiceState::new("#general".to_string(), vec![]);
+        let now = Instant::now();
+        state.add_text(text("old", now));
+        state.add_text(text("new", now + Duration::from_secs(5)));
+
+        state.expire_texts(now + VOICE_TEXT_DISPLAY_DURATION);
+
+        assert_eq!(state.texts.len(), 1);
+        assert_eq!(state.texts[0].message, "new");
+    }
 }
@@ -11,11 +11,14 @@
 //! - `/files/path` - Open Files panel to path
 //! - `/news` - Open News panel
 //! - `/info` - Open Server Info panel
+//! - `/snippet/id` - Show a shared snippet
 
 use std::fmt;
 
 use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, percent_decode_str, percent_encode};
 
+use crate::types::{ConnectionInfo, FilesManagementState};
+
 /// Default BBS port
 const DEFAULT_PORT: u16 = 7500;
 
@@ -103,6 +106,11 @@ pub enum NexusPath {
     News,
     /// Open Server Info panel
     Info,
+    /// Show a shared snippet
+    Snippet {
+        /// Snippet ID (chosen by the server)
+        id: String,
+    },
 }
 
 impl fmt::Display for NexusPath {
@@ -116,6 +124,7 @@ impl fmt::Display for NexusPath {
             NexusPath::Files { path } => write!(f, "/files/{}", path),
             NexusPath::News => write!(f, "/news"),
             NexusPath::Info => write!(f, "/info"),
+            NexusPath::Snippet { id } => write!(f, "/snippet/{}", id),
         }
     }
 }
@@ -210,7 +219,7 @@ fn looks_like_ipv6(s: &str) -> bool {
 }
 
 /// Parse host and port from hostport string, handling IPv6 brackets
-fn parse_host_port(hostport: &str) -> Result<(String, u16), ParseError> {
+pub(crate) fn parse_host_port(hostport: &str) -> Result<(String, u16), ParseError> {
     if hostport.starts_with('[') {
         // IPv6 address in brackets: [::1]:7500 or [::1]
         let end_bracket = hostport
@@ -304,6 +313,10 @@ fn parse_path(path: &str) -> Result<Option<NexusPath>, ParseError> {
         }
         "news" => Ok(Some(NexusPath::News)),
         "info" => Ok(Some(NexusPath::Info)),
+        // A snippet link without an ID is like an unknown path
+        "snippet" if !rest.trim_end_matches('/').is_empty() => Ok(Some(NexusPath::Snippet {
+            id: rest.trim_end_matches('/').to_string(),
+        })),
         _ => {
             // Unknown path type - just connect without intent
             Ok(None)
@@ -345,6 +358,48 @@ pub fn url_encode_path(s: &str) -> String {
     percent_encode(s.as_bytes(), PATH_ENCODE_SET).to_string()
 }
 
+/// Build a nexus:// link to a file or folder on a connection's server
+///
+/// The link carries no credentials. Folder type suffixes are stripped from
+/// each path segment since the server resolves paths without them
+/// (e.g., "uploads" -> "uploads [NEXUS-UL]").
+pub fn file_link(info: &ConnectionInfo, path: &str) -> String {
+    let clean_path = path
+        .split('/')
+        .map(FilesManagementState::display_name)
+        .collect::<Vec<_>>()
+        .join("/");
+
+    format!(
+        "{}/files/{}",
+        server_link(info),
+        url_encode_path(&clean_path)
+    )
+}
+
+/// Build a nexus:// link to a snippet on a connection's server
+pub fn snippet_link(info: &ConnectionInfo, id: &str) -> String {
+    format!("{}/snippet/{}", server_link(info), url_encode_path(id))
+}
+
+/// Build a nexus:// link to a connection's server, without credentials
+fn server_link(info: &ConnectionInfo) -> String {
+    let port_suffix = if info.port == nexus_common::DEFAULT_PORT {
+        String::new()
+    } else {
+        format!(":{}", info.port)
+    };
+
+    // IPv6 addresses need brackets in URIs
+    let host = if info.address.parse::<std::net::Ipv6Addr>().is_ok() {
+        format!("[{}]", info.address)
+    } else {
+        info.address.clone()
+    };
+
+    format!("nexus://{}{}", host, port_suffix)
+}
+
 /// Check if a string looks like a nexus:// URI
 pub fn is_nexus_uri(s: &str) -> bool {
     s.starts_with("nexus://")
@@ -531,6 +586,21 @@ mod tests {
         assert_eq!(uri.path, Some(NexusPath::Info));
     }
 
+    #[test]
+    fn test_parse_snippet() {
+        let uri = parse("nexus://example.com/snippet/aZ09bY18cX").unwrap();
+        assert_eq!(
+            uri.path,
+            Some(NexusPath::Snippet {
+                id: "aZ09bY18cX".to_string()
+            })
+        );
+        assert_eq!(uri.to_string(), "nexus://example.com/snippet/aZ09bY18cX");
+
+        let uri = parse("nexus://example.com/snippet/").unwrap();
+        assert!(uri.path.is_none());
+    }
+
     #[test]
     fn test_parse_url_encoded() {
         let uri = parse("nexus://user%40example@example.com").unwrap();
@@ -874,4 +944,43 @@ mod tests {
             panic!("Expected Files path");
         }
     }
+
+    fn connection_info(address: &str, port: u16) -> ConnectionInfo {
+        ConnectionInfo {
+            server_name: "Test".to_string(),
+            address: address.to_string(),
+            port,
+            transfer_port: port + 1,
+            certificate_fingerprint: String::new(),
+            identity_key: None,
+            username: "alice".to_string(),
+            password: "secret".to_string(),
+            nickname: String::new(),
+            login_token: None,
+        }
+    }
+
+    #[test]
+    fn test_file_link() {
+        let info = connection_info("example.com", DEFAULT_PORT);
+        assert_eq!(
+            file_link(&info, "Uploads [NEXUS-UL]/alice/shot 1.png"),
+            "nexus://example.com/files/Uploads/alice/shot%201.png"
+        );
+
+        let info = connection_info("::1", 8500);
+        assert_eq!(
+            file_link(&info, "readme.txt"),
+            "nexus://[::1]:8500/files/readme.txt"
+        );
+    }
+
+    #[test]
+    fn test_snippet_link() {
+        let info = connection_info("example.com", 8500);
+        assert_eq!(
+            snippet_link(&info, "aZ09bY18cX"),
+            "nexus://example.com:8500/snippet/aZ09bY18cX"
+        );
+    }
 }
@@ -0,0 +1,355 @@
+//! User list filtering and keyboard selection
+//!
+//! The filter box above the user list narrows it to nicknames containing the
+//! typed text. While the filter has focus, the arrow keys move the selection
+//! (the expanded user) and the selected user can be acted on without the
+//! mouse:
+//!
+//! - Enter: open a message tab
+//! - Shift+Enter: show user info
+//! - Cmd/Ctrl+Enter: open the kick/ban dialog
+//! - Escape: clear the filter and return to the chat input
+//!
+//! Cmd/Ctrl+U focuses the filter from anywhere.
+//!
+//! The list can also be split into sections (admins first, by channel, or by
+//! status) and sorted by nickname, idle time, or connect time; see
+//! [`UserListSettings`]. Sections can be collapsed from their headers.
+
+use iced::Event;
+use iced::keyboard::{self, Key};
+
+use crate::config::settings::{UserListGrouping, UserListSettings, UserListSort};
+use crate::types::UserInfo;
+
+/// A section of the grouped user list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UserGroup {
+    /// Everyone (grouping off, shown without a header)
+    All,
+    /// Admins
+    Admins,
+    /// Everyone who isn't an admin
+    Users,
+    /// Members of one of our channels (display name)
+    Channel(String),
+    /// Users in none of our channels
+    NoChannel,
+    /// Users who are neither away nor in do not disturb
+    Online,
+    /// Away users
+    Away,
+    /// Users in do not disturb
+    DoNotDisturb,
+}
+
+impl UserGroup {
+    /// Key the section's collapse state is remembered under
+    pub fn key(&self) -> String {
+        match self {
+            UserGroup::All => "all".to_string(),
+            UserGroup::Admins => "admins".to_string(),
+            UserGroup::Users => "users".to_string(),
+            UserGroup::Channel(name) => format!("channel:{}", name.to_lowercase()),
+            UserGroup::NoChannel => "no-channel".to_string(),
+            UserGroup::Online => "online".to_string(),
+            UserGroup::Away => "away".to_string(),
+            UserGroup::DoNotDisturb => "dnd".to_string(),
+        }
+    }
+}
+
+/// Whether an event is the user list filter shortcut (Cmd/Ctrl+U)
+pub fn is_focus_key(event: &Event) -> bool {
+    matches!(
+        event,
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: Key::Character(c),
+            modifiers,
+            ..
+        }) if modifiers.command() && c.eq_ignore_ascii_case("u")
+    )
+}
+
+/// Whether a nickname matches the filter (case-insensitive substring)
+///
+/// An empty or all-whitespace filter matches everyone.
+pub fn matches_filter(nickname: &str, filter: &str) -> bool {
+    let filter = filter.trim();
+    filter.is_empty() || nickname.to_lowercase().contains(&filter.to_lowercase())
+}
+
+/// Sort users within a section
+///
+/// Idle time puts the most recently active first (users with no known activity
+/// last); connect time puts the longest connected first. Ties fall back to
+/// nickname order.
+pub fn sort_users(users: &mut [&UserInfo], sort: UserListSort) {
+    users.sort_by(|a, b| {
+        let by_time = match sort {
+            UserListSort::Alphabetical => std::cmp::Ordering::Equal,
+            UserListSort::IdleTime => b.last_active.cmp(&a.last_active),
+            UserListSort::ConnectTime => a.login_time.cmp(&b.login_time),
+        };
+        by_time.then_with(|| a.nickname.to_lowercase().cmp(&b.nickname.to_lowercase()))
+    });
+}
+
+/// Split users into sorted sections
+///
+/// `channels` are our channels in tab order with their members' nicknames;
+/// when grouping by channel each user goes under the first one they're in.
+/// Empty sections are left out, and with grouping off everyone is in a single
+/// [`UserGroup::All`] section.
+pub fn group_users<'a>(
+    users: Vec<&'a UserInfo>,
+    settings: UserListSettings,
+    channels: &[(&str, &[String])],
+) -> Vec<(UserGroup, Vec<&'a UserInfo>)> {
+    let mut sections: Vec<(UserGroup, Vec<&UserInfo>)> = match settings.grouping {
+        UserListGrouping::None => vec![(UserGroup::All, Vec::new())],
+        UserListGrouping::AdminsFirst => vec![
+            (UserGroup::Admins, Vec::new()),
+            (UserGroup::Users, Vec::new()),
+        ],
+        UserListGrouping::ByChannel => channels
+            .iter()
+            .map(|(name, _)| (UserGroup::Channel(name.to_string()), Vec::new()))
+            .chain(std::iter::once((UserGroup::NoChannel, Vec::new())))
+            .collect(),
+        UserListGrouping::ByStatus => vec![
+            (UserGroup::Online, Vec::new()),
+            (UserGroup::Away, Vec::new()),
+            (UserGroup::DoNotDisturb, Vec::new()),
+        ],
+    };
+
+    for user in users {
+        let index = match settings.grouping {
+            UserListGrouping::None => 0,
+            UserListGrouping::AdminsFirst => usize::from(!user.is_admin),
+            UserListGrouping::ByChannel => {
+                let nickname_lower = user.nickname.to_lowercase();
+                channels
+                    .iter()
+                    .position(|(_, members)| {
+                        members
+                            .iter()
+                            .any(|member| member.to_lowercase() == nickname_lower)
+                    })
+                    .unwrap_or(channels.len())
+            }
+            UserListGrouping::ByStatus if user.is_dnd => 2,
+            UserListGrouping::ByStatus if user.is_away => 1,
+            UserListGrouping::ByStatus => 0,
+        };
+        sections[index].1.push(user);
+    }
+
+    sections.retain(|(_, users)| !users.is_empty());
+    for (_, users) in &mut sections {
+        sort_users(users, settings.sort);
+    }
+    sections
+}
+
+/// Move the selection one entry down (`forward`) or up the list
+///
+/// With nothing selected, or a selection no longer in the list, down picks
+/// the first entry and up the last. The selection stops at either end.
+pub fn step_selection(nicknames: &[&str], selected: Option<&str>, forward: bool) -> Option<String> {
+    let position = selected.and_then(|selected| nicknames.iter().position(|n| *n == selected));
+    let index = match (position, forward) {
+        (Some(index), true) => (index + 1).min(nicknames.len() - 1),
+        (Some(index), false) => index.saturating_sub(1),
+        (None, true) => 0,
+        (None, false) => nicknames.len().checked_sub(1)?,
+    };
+    nicknames.get(index).map(|nickname| nickname.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USERS: &[&str] = &["alice", "bob", "carol"];
+
+    fn user(nickname: &str, login_time: i64, last_active: Option<i64>) -> UserInfo {
+        UserInfo {
+            username: nickname.to_string(),
+            nickname: nickname.to_string(),
+            is_admin: false,
+            is_shared: false,
+            session_ids: vec![1],
+            avatar_hash: None,
+            is_away: false,
+            status: None,
+            is_dnd: false,
+            login_time,
+            last_active,
+        }
+    }
+
+    fn nicknames(users: &[&UserInfo]) -> Vec<String> {
+        users.iter().map(|user| user.nickname.clone()).collect()
+    }
+
+    fn settings(grouping: UserListGrouping, sort: UserListSort) -> UserListSettings {
+        UserListSettings { grouping, sort }
+    }
+
+    #[test]
+    fn test_sort_users() {
+        let alice = user("alice", 300, Some(400));
+        let bob = user("Bob", 100, None);
+        let carol = user("carol", 200, Some(900));
+        let mut users = vec![&carol, &bob, &alice];
+
+        sort_users(&mut users, UserListSort::Alphabetical);
+        assert_eq!(nicknames(&users), ["alice", "Bob", "carol"]);
+
+        sort_users(&mut users, UserListSort::IdleTime);
+        assert_eq!(nicknames(&users), ["carol", "alice", "Bob"]);
+
+        sort_users(&mut users, UserListSort::ConnectTime);
+        assert_eq!(nicknames(&users), ["Bob", "carol", "alice"]);
+    }
+
+    #[test]
+    fn test_group_users_off() {
+        let alice = user("alice", 0, None);
+        let bob = user("bob", 0, None);
+        let sections = group_users(
+            vec![&bob, &alice],
+            settings(UserListGrouping::None, UserListSort::Alphabetical),
+            &[],
+        );
+        assert_eq!(sections.len(), 1);
+        assert_eq!(sections[0].0, UserGroup::All);
+        assert_eq!(nicknames(&sections[0].1), ["alice", "bob"]);
+
+        let empty = group_users(Vec::new(), UserListSettings::default(), &[]);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_group_users_admins_first() {
+        let mut alice = user("alice", 0, None);
+        alice.is_admin = true;
+        let bob = user("bob", 0, None);
+        let sections = group_users(
+            vec![&bob, &alice],
+            settings(UserListGrouping::AdminsFirst, UserListSort::Alphabetical),
+            &[],
+        );
+        assert_eq!(sections[0].0, UserGroup::Admins);
+        assert_eq!(nicknames(&sections[0].1), ["alice"]);
+        assert_eq!(sections[1].0, UserGroup::Users);
+        assert_eq!(nicknames(&sections[1].1), ["bob"]);
+    }
+
+    #[test]
+    fn test_group_users_by_channel() {
+        let alice = user("alice", 0, None);
+        let bob = user("bob", 0, None);
+        let carol = user("carol", 0, None);
+        let general = vec!["Alice".to_string(), "bob".to_string()];
+        let dev = vec!["bob".to_string()];
+        let empty: Vec<String> = Vec::new();
+        let sections = group_users(
+            vec![&alice, &bob, &carol],
+            settings(UserListGrouping::ByChannel, UserListSort::Alphabetical),
+            &[("#dev", &dev), ("#empty", &empty), ("#general", &general)],
+        );
+
+        // Each user is under the first channel they're in; empty sections are left out
+        let groups: Vec<&UserGroup> = sections.iter().map(|(group, _)| group).collect();
+        assert_eq!(
+            groups,
+            [
+                &UserGroup::Channel("#dev".to_string()),
+                &UserGroup::Channel("#general".to_string()),
+                &UserGroup::NoChannel,
+            ]
+        );
+        assert_eq!(nicknames(&sections[0].1), ["bob"]);
+        assert_eq!(nicknames(&sections[1].1), ["alice"]);
+        assert_eq!(nicknames(&sections[2].1), ["carol"]);
+    }
+
+    #[test]
+    fn test_group_users_by_status() {
+        let alice = user("alice", 0, None);
+        let mut bob = user("bob", 0, None);
+        bob.is_away = true;
+        let mut carol = user("carol", 0, None);
+        carol.is_away = true;
+        carol.is_dnd = true;
+        let sections = group_users(
+            vec![&carol, &bob, &alice],
+            settings(UserListGrouping::ByStatus, UserListSort::Alphabetical),
+            &[],
+        );
+        assert_eq!(sections[0].0, UserGroup::Online);
+        assert_eq!(nicknames(&sections[0].1), ["alice"]);
+        assert_eq!(sections[1].0, UserGroup::Away);
+        assert_eq!(nicknames(&sections[1].1), ["bob"]);
+        assert_eq!(sections[2].0, UserGroup::DoNotDisturb);
+        assert_eq!(nicknames(&sections[2].1), ["carol"]);
+    }
+
+    #[test]
+    fn test_user_group_keys_are_distinct() {
+        assert_eq!(
+            UserGroup::Channel("#General".to_string()).key(),
+            UserGroup::Channel("#general".to_string()).key()
+        );
+        assert_ne!(UserGroup::NoChannel.key(), UserGroup::Users.key());
+    }
+
+    #[test]
+    fn test_matches_filter() {
+        assert!(matches_filter("Alice", ""));
+        assert!(matches_filter("Alice", "  "));
+        assert!(matches_filter("Alice", "LIC"));
+        assert!(matches_filter("Alice", " ali "));
+        assert!(!matches_filter("Alice", "bob"));
+    }
+
+    #[test]
+    fn test_step_selection_from_nothing() {
+        assert_eq!(step_selection(USERS, None, true).as_deref(), Some("alice"));
+        assert_eq!(step_selection(USERS, None, false).as_deref(), Some("carol"));
+        assert_eq!(step_selection(&[], None, true), None);
+        assert_eq!(step_selection(&[], None, false), None);
+    }
+
+    #[test]
+    fn test_step_selection_moves_and_stops_at_ends() {
+        assert_eq!(
+            step_selection(USERS, Some("alice"), true).as_deref(),
+            Some("bob")
+        );
+        assert_eq!(
+            step_selection(USERS, Some("bob"), false).as_deref(),
+            Some("alice")
+        );
+        assert_eq!(
+            step_selection(USERS, Some("carol"), true).as_deref(),
+            Some("carol")
+        );
+        assert_eq!(
+            step_selection(USERS, Some("alice"), false).as_deref(),
+            Some("alice")
+        );
+    }
+
+    #[test]
+    fn test_step_selection_filtered_out() {
+        // The selected user no longer matches the filter
+        assert_eq!(
+            step_selection(&["bob", "carol"], Some("alice"), true).as_deref(),
+            Some("bob")
+        );
+    }
+}
@@ -0,0 +1,218 @@
+//! Admin dashboard panel view
+//!
+//! Shows connection counts, transfer activity, today's totals, free space in
+//! the file area and the newest audit events on one screen. The panel
+//! refreshes itself while it's open.
+
+use chrono::{DateTime, Local};
+use iced::widget::button as btn;
+use iced::widget::{Column, Space, button, column, row};
+use iced::{Center, Element, Fill};
+use nexus_common::protocol::{AuditEntry, DailyStats, ServerStatsInfo};
+
+use super::layout::scrollable_panel;
+use crate::handlers::network::helpers::format_bytes;
+use crate::i18n::{t, t_args};
+use crate::style::{
+    BUTTON_PADDING, CONTENT_MAX_WIDTH, CONTENT_PADDING, ELEMENT_SPACING, SPACER_SIZE_MEDIUM,
+    SPACER_SIZE_SMALL, SUBHEADING_SIZE, TEXT_SIZE, error_text_style, muted_text_style, panel_title,
+    shaped_text, shaped_text_wrapped,
+};
+use crate::types::{AdminDashboardState, Message};
+
+/// Section heading
+fn heading(key: &str) -> Element<'static, Message> {
+    shaped_text(t(key)).size(SUBHEADING_SIZE).into()
+}
+
+/// One labelled figure
+fn figure(key: &str, value: String) -> Element<'static, Message> {
+    row![
+        shaped_text(t(key))
+            .size(TEXT_SIZE)
+            .style(muted_text_style)
+            .width(Fill),
+        shaped_text(value).size(TEXT_SIZE),
+    ]
+    .spacing(ELEMENT_SPACING)
+    .into()
+}
+
+/// A section: heading followed by its figures
+fn section(key: &str, figures: Vec<Element<'static, Message>>) -> Element<'static, Message> {
+    Column::with_children(std::iter::once(heading(key)).chain(figures))
+        .spacing(SPACER_SIZE_SMALL)
+        .width(Fill)
+        .into()
+}
+
+/// Sessions and connections
+fn connections(stats: &ServerStatsInfo) -> Element<'static, Message> {
+    section(
+        "dashboard-connections",
+        vec![
+            figure("dashboard-sessions", stats.sessions.to_string()),
+            figure("dashboard-connection-count", stats.connections.to_string()),
+            figure(
+                "dashboard-transfer-connections",
+                stats.transfer_connections.to_string(),
+            ),
+        ],
+    )
+}
+
+/// Running and waiting transfers
+fn transfers(stats: &ServerStatsInfo) -> Element<'static, Message> {
+    section(
+        "dashboard-transfers",
+        vec![
+            figure(
+                "dashboard-active-transfers",
+                stats.active_transfers.to_string(),
+            ),
+            figure(
+                "dashboard-queued-transfers",
+                stats.queued_transfers.to_string(),
+            ),
+        ],
+    )
+}
+
+/// Today's totals so far
+fn today(day: &DailyStats) -> Element<'static, Message> {
+    section(
+        "dashboard-today",
+        vec![
+            figure("stats-metric-logins", day.logins.to_string()),
+            figure("stats-metric-messages", day.messages.to_string()),
+            figure("stats-metric-uploads", day.uploads.to_string()),
+            figure("stats-metric-downloads", day.downloads.to_string()),
+            figure(
+                "stats-metric-download-bytes",
+                format_bytes(day.download_bytes),
+            ),
+            figure("stats-metric-peak-sessions", day.peak_sessions.to_string()),
+        ],
+    )
+}
+
+/// Free space in the file area, when the server could read it
+fn disk(stats: &ServerStatsInfo) -> Element<'static, Message> {
+    let value = match (stats.disk_free, stats.disk_total) {
+        (Some(free), Some(total)) => t_args(
+            "dashboard-disk-free-of",
+            &[
+                ("free", &format_bytes(free)),
+                ("total", &format_bytes(total)),
+            ],
+        ),
+        (Some(free), None) => format_bytes(free),
+        _ => t("dashboard-disk-unknown"),
+    };
+    section("dashboard-disk", vec![figure("dashboard-disk-free", value)])
+}
+
+/// One audit event: time, who did it, and what
+fn audit_line(entry: &AuditEntry) -> Element<'static, Message> {
+    let time = DateTime::from_timestamp(entry.created_at, 0)
+        .map(|time| {
+            time.with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string()
+        })
+        .unwrap_or_default();
+
+    let mut what = [&entry.actor, &entry.action, &entry.target]
+        .into_iter()
+        .filter(|part| !part.is_empty())
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join(" ");
+    if let Some(details) = &entry.details {
+        what.push_str(&format!(" ({details})"));
+    }
+
+    row![
+        shaped_text(time).size(TEXT_SIZE).style(muted_text_style),
+        shaped_text_wrapped(what).size(TEXT_SIZE).width(Fill),
+    ]
+    .spacing(ELEMENT_SPACING)
+    .into()
+}
+
+/// Newest audit events first
+fn recent_audit(entries: &[AuditEntry]) -> Element<'static, Message> {
+    let lines: Vec<Element<'static, Message>> = if entries.is_empty() {
+        vec![
+            shaped_text(t("dashboard-audit-empty"))
+                .size(TEXT_SIZE)
+                .style(muted_text_style)
+                .into(),
+        ]
+    } else {
+        entries.iter().map(audit_line).collect()
+    };
+    section("dashboard-recent-audit", lines)
+}
+
+/// Admin dashboard panel
+pub fn admin_dashboard_view(state: &AdminDashboardState) -> Element<'static, Message> {
+    let content: Element<'static, Message> = if let Some(error) = &state.error {
+        shaped_text_wrapped(error.clone())
+            .size(TEXT_SIZE)
+            .style(error_text_style)
+            .into()
+    } else if let Some(stats) = &state.stats {
+        let mut sections = column![connections(stats), transfers(stats)]
+            .spacing(SPACER_SIZE_MEDIUM)
+            .width(Fill);
+        if let Some(day) = &state.today {
+            sections = sections.push(today(day));
+        }
+        sections
+            .push(disk(stats))
+            .push(recent_audit(&stats.recent_audit))
+            .into()
+    } else {
+        shaped_text(t("dashboard-loading"))
+            .size(TEXT_SIZE)
+            .style(muted_text_style)
+            .into()
+    };
+
+    let updated = state
+        .updated_at
+        .map(|time| {
+            t_args(
+                "dashboard-updated",
+                &[("time", &time.format("%H:%M:%S").to_string())],
+            )
+        })
+        .unwrap_or_default();
+
+    let buttons = row![
+        shaped_text(updated).size(TEXT_SIZE).style(muted_text_style),
+        Space::new().width(Fill),
+        button(shaped_text(t("button-refresh")).size(TEXT_SIZE))
+            .on_press_maybe((!state.loading).then_some(Message::RefreshAdminDashboard))
+            .padding(BUTTON_PADDING)
+            .style(btn::secondary),
+        button(shaped_text(t("button-close")).size(TEXT_SIZE))
+            .on_press(Message::CloseAdminDashboard)
+            .padding(BUTTON_PADDING),
+    ]
+    .spacing(ELEMENT_SPACING)
+    .align_y(Center);
+
+    let form = column![
+        panel_title(t("panel-admin-dashboard")),
+        content,
+        Space::new().height(SPACER_SIZE_MEDIUM),
+        buttons,
+    ]
+    .spacing(ELEMENT_SPACING)
+    .padding(CONTENT_PADDING)
+    .max_width(CONTENT_MAX_WIDTH);
+
+    scrollable_panel(form)
+}
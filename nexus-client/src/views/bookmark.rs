@@ -8,9 +8,9 @@ use iced_aw::NumberInput;
 use super::layout::scrollable_panel;
 use crate::i18n::t;
 use crate::style::{
-    BUTTON_PADDING, CONTENT_MAX_WIDTH, CONTENT_PADDING, ELEMENT_SPACING, INPUT_PADDING,
-    SPACER_SIZE_MEDIUM, SPACER_SIZE_SMALL, TEXT_SIZE, error_text_style, panel_title, shaped_text,
-    shaped_text_wrapped,
+    AVATAR_PREVIEW_SIZE, BUTTON_PADDING, CONTENT_MAX_WIDTH, CONTENT_PADDING, ELEMENT_SPACING,
+    INPUT_PADDING, SPACER_SIZE_MEDIUM, SPACER_SIZE_SMALL, TEXT_SIZE, error_text_style, panel_title,
+    shaped_text, shaped_text_wrapped,
 };
 use crate::types::{BookmarkEditMode, BookmarkEditState, InputId, Message};
 
@@ -18,6 +18,45 @@ use crate::types::{BookmarkEditMode, BookmarkEditState, InputId, Message};
 // Bookmark Edit View
 // ============================================================================
 
+/// Avatar override row: preview plus choose/clear buttons
+///
+/// An empty preview means the avatar from Settings is used.
+fn bookmark_avatar_row(state: &BookmarkEditState) -> Element<'_, Message> {
+    let avatar_preview: Element<'_, Message> = match &state.cached_avatar {
+        Some(avatar) => avatar.render(AVATAR_PREVIEW_SIZE),
+        None => Space::new()
+            .width(AVATAR_PREVIEW_SIZE)
+            .height(AVATAR_PREVIEW_SIZE)
+            .into(),
+    };
+
+    let pick_button = button(shaped_text(t("button-choose-avatar")).size(TEXT_SIZE))
+        .on_press(Message::BookmarkPickAvatarPressed)
+        .padding(BUTTON_PADDING)
+        .style(btn::secondary);
+
+    let clear_button = button(shaped_text(t("button-clear-avatar")).size(TEXT_SIZE))
+        .on_press_maybe(
+            state
+                .bookmark
+                .avatar
+                .is_some()
+                .then_some(Message::BookmarkClearAvatarPressed),
+        )
+        .padding(BUTTON_PADDING)
+        .style(btn::secondary);
+
+    row![
+        shaped_text(t("label-server-avatar")).size(TEXT_SIZE),
+        avatar_preview,
+        pick_button,
+        clear_button,
+    ]
+    .spacing(ELEMENT_SPACING)
+    .align_y(Center)
+    .into()
+}
+
 /// Displays form for adding or editing a server bookmark
 ///
 /// Shows validated input fields for server connection details with optional
@@ -92,6 +131,16 @@ pub fn bookmark_edit_view(state: &BookmarkEditState) -> Element<'_, Message> {
                 .align_y(Center)
                 .into()
         },
+        text_input(
+            &t("placeholder-fallback-addresses-optional"),
+            &state.fallback_addresses,
+        )
+        .on_input(Message::BookmarkFallbackAddressesChanged)
+        .on_submit(submit_action.clone())
+        .id(Id::from(InputId::BookmarkFallbackAddresses))
+        .padding(INPUT_PADDING)
+        .size(TEXT_SIZE)
+        .into(),
         text_input(
             &t("placeholder-username-optional"),
             &state.bookmark.username,
@@ -118,11 +167,29 @@ pub fn bookmark_edit_view(state: &BookmarkEditState) -> Element<'_, Message> {
             &state.bookmark.nickname,
         )
         .on_input(Message::BookmarkNicknameChanged)
-        .on_submit(submit_action)
+        .on_submit(submit_action.clone())
         .id(Id::from(InputId::BookmarkNickname))
         .padding(INPUT_PADDING)
         .size(TEXT_SIZE)
         .into(),
+        text_input(&t("placeholder-status-optional"), &state.bookmark.status)
+            .on_input(Message::BookmarkStatusChanged)
+            .on_submit(submit_action.clone())
+            .id(Id::from(InputId::BookmarkStatus))
+            .padding(INPUT_PADDING)
+            .size(TEXT_SIZE)
+            .into(),
+        bookmark_avatar_row(state),
+        text_input(
+            &t("placeholder-download-folder-optional"),
+            &state.bookmark.download_folder,
+        )
+        .on_input(Message::BookmarkDownloadFolderChanged)
+        .on_submit(submit_action)
+        .id(Id::from(InputId::BookmarkDownloadFolder))
+        .padding(INPUT_PADDING)
+        .size(TEXT_SIZE)
+        .into(),
         Space::new().height(SPACER_SIZE_SMALL).into(),
         checkbox(state.bookmark.auto_connect)
             .label(t("label-auto-connect"))
@@ -130,6 +197,12 @@ pub fn bookmark_edit_view(state: &BookmarkEditState) -> Element<'_, Message> {
             .size(TEXT_SIZE)
             .text_shaping(text::Shaping::Advanced)
             .into(),
+        checkbox(state.bookmark.use_sso)
+            .label(t("label-use-sso"))
+            .on_toggle(Message::BookmarkUseSsoToggled)
+            .size(TEXT_SIZE)
+            .text_shaping(text::Shaping::Advanced)
+            .into(),
         Space::new().height(SPACER_SIZE_MEDIUM).into(),
         {
             let mut buttons: Vec<Element<'_, Message>> = vec![
@@ -1,17 +1,20 @@
 //! Broadcast message panel view
 
 use iced::widget::button as btn;
-use iced::widget::{Id, Space, button, row, text_input};
+use iced::widget::{Id, Space, button, checkbox, column, pick_list, row, text, text_input};
 use iced::{Center, Element, Fill};
+use nexus_common::ALL_PERMISSIONS;
 
 use super::layout::scrollable_panel;
 use crate::i18n::t;
 use crate::style::{
     BUTTON_PADDING, CONTENT_MAX_WIDTH, CONTENT_PADDING, ELEMENT_SPACING, INPUT_PADDING,
     MONOSPACE_FONT, SPACER_SIZE_MEDIUM, SPACER_SIZE_SMALL, TEXT_SIZE, error_text_style,
-    panel_title, shaped_text, shaped_text_wrapped,
+    muted_text_style, panel_title, shaped_text, shaped_text_wrapped,
+};
+use crate::types::{
+    AuditPermission, BroadcastDelay, BroadcastTargetKind, InputId, Message, ServerConnection,
 };
-use crate::types::{InputId, Message, ServerConnection};
 
 // ============================================================================
 // Broadcast View
@@ -19,7 +22,8 @@ use crate::types::{InputId, Message, ServerConnection};
 
 /// Render the broadcast panel
 ///
-/// Shows a form for composing and sending broadcast messages to all connected users.
+/// Shows a form for composing a broadcast, choosing who gets it and when,
+/// and whether recipients are asked to acknowledge it.
 pub fn broadcast_view(conn: &ServerConnection) -> Element<'_, Message> {
     let title = panel_title(t("title-broadcast-message"));
 
@@ -40,6 +44,72 @@ pub fn broadcast_view(conn: &ServerConnection) -> Element<'_, Message> {
         .size(TEXT_SIZE)
         .font(MONOSPACE_FONT);
 
+    let target_label = shaped_text(t("broadcast-target-label"))
+        .size(TEXT_SIZE)
+        .style(muted_text_style);
+    let target_picker = pick_list(
+        BroadcastTargetKind::all(),
+        Some(conn.broadcast_target),
+        Message::BroadcastTargetChanged,
+    )
+    .text_size(TEXT_SIZE)
+    .padding(INPUT_PADDING)
+    .width(Fill);
+
+    let mut target_row = column![target_label, target_picker].spacing(SPACER_SIZE_SMALL);
+    match conn.broadcast_target {
+        BroadcastTargetKind::All => {}
+        BroadcastTargetKind::Channel => {
+            target_row = target_row.push(
+                pick_list(
+                    conn.known_channels.as_slice(),
+                    conn.broadcast_channel.as_ref(),
+                    Message::BroadcastChannelSelected,
+                )
+                .placeholder(t("placeholder-broadcast-channel"))
+                .text_size(TEXT_SIZE)
+                .padding(INPUT_PADDING)
+                .width(Fill),
+            );
+        }
+        BroadcastTargetKind::Permission => {
+            let options: Vec<AuditPermission> = ALL_PERMISSIONS
+                .iter()
+                .map(|permission| AuditPermission(permission.to_string()))
+                .collect();
+            target_row = target_row.push(
+                pick_list(
+                    options,
+                    conn.broadcast_permission.clone().map(AuditPermission),
+                    Message::BroadcastPermissionSelected,
+                )
+                .placeholder(t("placeholder-broadcast-permission"))
+                .text_size(TEXT_SIZE)
+                .padding(INPUT_PADDING)
+                .width(Fill),
+            );
+        }
+    }
+
+    let delay_label = shaped_text(t("broadcast-delay-label"))
+        .size(TEXT_SIZE)
+        .style(muted_text_style);
+    let delay_picker = pick_list(
+        BroadcastDelay::all(),
+        Some(conn.broadcast_delay),
+        Message::BroadcastDelayChanged,
+    )
+    .text_size(TEXT_SIZE)
+    .padding(INPUT_PADDING)
+    .width(Fill);
+    let delay_row = column![delay_label, delay_picker].spacing(SPACER_SIZE_SMALL);
+
+    let require_ack_checkbox = checkbox(conn.broadcast_require_ack)
+        .label(t("label-broadcast-require-ack"))
+        .on_toggle(Message::BroadcastRequireAckToggled)
+        .size(TEXT_SIZE)
+        .text_shaping(text::Shaping::Advanced);
+
     let buttons = row![
         Space::new().width(Fill),
         button(shaped_text(t("button-cancel")).size(TEXT_SIZE))
@@ -75,6 +145,10 @@ pub fn broadcast_view(conn: &ServerConnection) -> Element<'_, Message> {
 
     form_items.extend([
         message_input.into(),
+        Space::new().height(SPACER_SIZE_SMALL).into(),
+        target_row.into(),
+        delay_row.into(),
+        require_ack_checkbox.into(),
         Space::new().height(SPACER_SIZE_MEDIUM).into(),
         buttons.into(),
     ]);
@@ -86,3 +160,15 @@ pub fn broadcast_view(conn: &ServerConnection) -> Element<'_, Message> {
 
     scrollable_panel(form)
 }
+
+impl std::fmt::Display for BroadcastTargetKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", t(self.translation_key()))
+    }
+}
+
+impl std::fmt::Display for BroadcastDelay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", t(self.translation_key()))
+    }
+}
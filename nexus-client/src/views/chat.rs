@@ -1,29 +1,54 @@
 //! Chat interface for active server connections
 
+use iced::advanced::text::Highlighter as _;
+use iced::highlighter::{self, Highlighter};
 use iced::widget::scrollable::{Direction, Scrollbar};
-use iced::widget::text::Wrapping;
+use iced::widget::text::{Span, Wrapping};
 use iced::widget::{
-    Column, button, column, container, rich_text, row, scrollable, span, text::Rich, tooltip,
+    Column, Space, button, column, container, rich_text, row, scrollable, span, text::Rich,
+    text_editor, text_input, tooltip,
 };
 use iced::{Color, Element, Fill, Font, Theme};
 use linkify::{LinkFinder, LinkKind};
 use nexus_common::protocol::ChatAction;
+use nexus_common::validators::MAX_SNIPPET_LENGTH;
 use once_cell::sync::Lazy;
 
-use crate::i18n::t;
+use crate::chat_input::{MAX_PASTE_MESSAGES, paste_lines, paste_messages, split_message};
+use crate::code_block::{CodeBlock, MessagePart, split_code_blocks};
+use crate::config::events::NotificationRules;
+use crate::i18n::{t, t_args};
+use crate::spellcheck::SpellSettings;
 use crate::style::{
     BOLD_FONT, CHAT_ACTION_PREFIX, CHAT_LINE_HEIGHT, CHAT_MESSAGE_SEPARATOR, CHAT_MESSAGE_SIZE,
-    CHAT_SPACING, CLOSE_BUTTON_PADDING, INPUT_PADDING, MONOSPACE_FONT, MONOSPACE_ITALIC_FONT,
-    SMALL_PADDING, SMALL_SPACING, TAB_CONTENT_PADDING, TOOLTIP_BACKGROUND_PADDING, TOOLTIP_GAP,
-    TOOLTIP_PADDING, TOOLTIP_TEXT_SIZE, chat, chat_tab_active_style, close_button_on_primary_style,
-    content_background_style, shaped_text, tooltip_container_style,
+    CHAT_SPACING, CLOSE_BUTTON_PADDING, CODE_BLOCK_BUTTON_PADDING, CONTEXT_MENU_ITEM_PADDING,
+    CONTEXT_MENU_MIN_WIDTH, CONTEXT_MENU_PADDING, INPUT_PADDING, MONOSPACE_FONT,
+    MONOSPACE_ITALIC_FONT, SMALL_PADDING, SMALL_SPACING, TAB_CONTENT_PADDING, TEXT_SIZE,
+    TOOLTIP_BACKGROUND_PADDING, TOOLTIP_GAP, TOOLTIP_PADDING, TOOLTIP_TEXT_SIZE, chat,
+    chat_tab_active_style, close_button_on_primary_style, code_block_style,
+    content_background_style, context_menu_container_style, menu_button_style, shaped_text,
+    tooltip_container_style, transparent_icon_button_style,
+};
+use crate::types::{
+    ChatTab, ChatWindow, DeliveryState, Message, MessageType, PendingBroadcastAck, QueuedMessage,
+    ScrollableId, ServerConnection, SpellMenu,
 };
-use crate::types::{ChatTab, Message, MessageType, ScrollableId, ServerConnection};
 use crate::views::constants::{PERMISSION_VOICE_LISTEN, PERMISSION_VOICE_TALK};
-use crate::views::voice::{build_input_row_with_voice, build_voice_bar};
+use crate::views::voice::{build_input_row_with_voice, build_voice_bar, build_voice_text_overlay};
+use crate::widgets::{LazyContextMenu, MenuButton};
 
 const CONSOLE_TAB_TOOLTIP_KEY: &str = "console-tab";
 
+/// Maximum width of the channel info popover shown when hovering a channel tab
+const CHANNEL_INFO_TOOLTIP_MAX_WIDTH: f32 = 400.0;
+
+/// Code blocks with more lines than this show only their first lines
+/// until expanded
+const COLLAPSED_CODE_BLOCK_LINES: usize = 15;
+
+/// Spaces shown for each tab in a code block
+const CODE_BLOCK_TAB: &str = "    ";
+
 // ============================================================================
 // Timestamp Settings
 // ============================================================================
@@ -46,14 +71,17 @@ impl TimestampSettings {
             return None;
         }
 
-        let format = match (self.use_24_hour_time, self.show_seconds) {
+        Some(timestamp.format(self.time_format()).to_string())
+    }
+
+    /// Time of day format string (12 or 24 hour, with or without seconds)
+    pub fn time_format(&self) -> &'static str {
+        match (self.use_24_hour_time, self.show_seconds) {
             (true, true) => "%H:%M:%S",
             (true, false) => "%H:%M",
             (false, true) => "%I:%M:%S",
             (false, false) => "%I:%M",
-        };
-
-        Some(timestamp.format(format).to_string())
+        }
     }
 }
 
@@ -182,82 +210,152 @@ fn styled_message<'a>(
 // ============================================================================
 
 /// Create a tab button with appropriate styling and unread indicator
+///
+/// Channel and user message tabs get a context menu to pop them out and,
+/// when `muted` is known (the connection has a bookmark), to mute them.
 fn create_tab_button(
     tab: ChatTab,
     label: String,
     is_active: bool,
     has_unread: bool,
+    muted: Option<bool>,
 ) -> Element<'static, Message> {
-    if is_active {
-        create_active_tab_button(tab, label)
+    let is_muted = muted == Some(true);
+    let tab_button = if is_active {
+        create_active_tab_button(tab.clone(), label, is_muted)
     } else {
-        create_inactive_tab_button(tab, label, has_unread)
+        create_inactive_tab_button(tab.clone(), label, has_unread, is_muted)
+    };
+
+    if tab == ChatTab::Console {
+        return tab_button;
     }
+
+    LazyContextMenu::new(tab_button, move || {
+        build_tab_context_menu(tab.clone(), muted)
+    })
+    .into()
 }
 
-/// Create an active tab button (with close button for channel and user message tabs)
-fn create_active_tab_button(tab: ChatTab, label: String) -> Element<'static, Message> {
-    match &tab {
-        ChatTab::Channel(channel) => {
-            // Channel tabs include a close button
-            let channel_clone = channel.clone();
-            let close_button = tooltip(
-                button(crate::icon::close().size(CHAT_MESSAGE_SIZE))
-                    .on_press(Message::CloseChannelTab(channel_clone))
-                    .padding(CLOSE_BUTTON_PADDING)
-                    .style(close_button_on_primary_style()),
-                container(
-                    shaped_text(format!("{} {}", t("tooltip-close"), channel))
-                        .size(TOOLTIP_TEXT_SIZE),
-                )
-                .padding(TOOLTIP_BACKGROUND_PADDING)
-                .style(tooltip_container_style),
-                tooltip::Position::Bottom,
-            )
-            .gap(TOOLTIP_GAP)
-            .padding(TOOLTIP_PADDING);
+/// Build the context menu for a channel or user message tab
+fn build_tab_context_menu(tab: ChatTab, muted: Option<bool>) -> Element<'static, Message> {
+    let menu_item = |label: String, message: Message| {
+        MenuButton::new(shaped_text(label).size(TEXT_SIZE))
+            .padding(CONTEXT_MENU_ITEM_PADDING)
+            .width(Fill)
+            .style(menu_button_style)
+            .on_press(message)
+    };
 
-            let tab_content = row![shaped_text(label).size(CHAT_MESSAGE_SIZE), close_button]
-                .spacing(SMALL_SPACING)
-                .align_y(iced::Alignment::Center);
+    let mut items = Column::new().push(menu_item(
+        t("menu-pop-out"),
+        Message::PopOutChatTab(tab.clone()),
+    ));
+    if let Some(muted) = muted {
+        let label = if muted {
+            "menu-unmute-tab"
+        } else {
+            "menu-mute-tab"
+        };
+        items = items.push(menu_item(t(label), Message::ToggleChatTabMute(tab)));
+    }
 
-            button(tab_content)
-                .on_press(Message::SwitchChatTab(tab))
-                .padding(TAB_CONTENT_PADDING)
-                .style(chat_tab_active_style())
-                .into()
-        }
-        ChatTab::UserMessage(nickname) => {
-            // User message tabs include a close button
-            let nickname_clone = nickname.clone();
-            let close_button = tooltip(
-                button(crate::icon::close().size(CHAT_MESSAGE_SIZE))
-                    .on_press(Message::CloseUserMessageTab(nickname_clone))
-                    .padding(CLOSE_BUTTON_PADDING)
-                    .style(close_button_on_primary_style()),
-                container(
-                    shaped_text(format!("{} {}", t("tooltip-close"), nickname))
-                        .size(TOOLTIP_TEXT_SIZE),
-                )
-                .padding(TOOLTIP_BACKGROUND_PADDING)
-                .style(tooltip_container_style),
-                tooltip::Position::Bottom,
-            )
-            .gap(TOOLTIP_GAP)
-            .padding(TOOLTIP_PADDING);
+    container(items)
+        .width(CONTEXT_MENU_MIN_WIDTH)
+        .padding(CONTEXT_MENU_PADDING)
+        .style(context_menu_container_style)
+        .into()
+}
+
+/// Tab label, after a muted icon if the tab is muted
+fn tab_label(label: iced::widget::Text<'static>, is_muted: bool) -> Element<'static, Message> {
+    if !is_muted {
+        return label.into();
+    }
+    row![crate::icon::volume_off().size(CHAT_MESSAGE_SIZE), label]
+        .spacing(SMALL_SPACING)
+        .align_y(iced::Alignment::Center)
+        .into()
+}
 
-            let tab_content = row![shaped_text(label).size(CHAT_MESSAGE_SIZE), close_button]
-                .spacing(SMALL_SPACING)
-                .align_y(iced::Alignment::Center);
+/// Create an active tab button (with close button for channel, user message, and group tabs)
+fn create_active_tab_button(
+    tab: ChatTab,
+    label: String,
+    is_muted: bool,
+) -> Element<'static, Message> {
+    let close_message = match &tab {
+        ChatTab::Console => return create_console_tab_button(tab, true),
+        ChatTab::Channel(channel) => Message::CloseChannelTab(channel.clone()),
+        ChatTab::UserMessage(nickname) => Message::CloseUserMessageTab(nickname.clone()),
+        ChatTab::Group(group_id) => Message::CloseGroupTab(*group_id),
+    };
 
-            button(tab_content)
-                .on_press(Message::SwitchChatTab(tab))
-                .padding(TAB_CONTENT_PADDING)
-                .style(chat_tab_active_style())
-                .into()
-        }
-        ChatTab::Console => create_console_tab_button(tab, true),
+    let close_button = tooltip(
+        button(crate::icon::close().size(CHAT_MESSAGE_SIZE))
+            .on_press(close_message)
+            .padding(CLOSE_BUTTON_PADDING)
+            .style(close_button_on_primary_style()),
+        container(shaped_text(format!("{} {}", t("tooltip-close"), label)).size(TOOLTIP_TEXT_SIZE))
+            .padding(TOOLTIP_BACKGROUND_PADDING)
+            .style(tooltip_container_style),
+        tooltip::Position::Bottom,
+    )
+    .gap(TOOLTIP_GAP)
+    .padding(TOOLTIP_PADDING);
+
+    let tab_content = row![
+        tab_label(shaped_text(label).size(CHAT_MESSAGE_SIZE), is_muted),
+        close_button
+    ]
+    .spacing(SMALL_SPACING)
+    .align_y(iced::Alignment::Center);
+
+    button(tab_content)
+        .on_press(Message::SwitchChatTab(tab))
+        .padding(TAB_CONTENT_PADDING)
+        .style(chat_tab_active_style())
+        .into()
+}
+
+/// Topic, language and rules of a channel for its tab's info popover, if any are set
+fn channel_info_text(conn: &ServerConnection, channel: &str) -> Option<String> {
+    let channel_state = conn.get_channel_state(channel)?;
+
+    let mut sections = Vec::new();
+    if let Some(topic) = channel_state
+        .topic
+        .as_deref()
+        .filter(|topic| !topic.is_empty())
+    {
+        sections.push(format!("{}\n{}", t("channel-info-topic"), topic));
+    }
+    if let Some(language) = channel_state.language.as_deref() {
+        sections.push(format!("{}\n{}", t("channel-info-language"), language));
     }
+    if let Some(rules) = channel_state.rules.as_deref() {
+        sections.push(format!("{}\n{}", t("channel-info-rules"), rules));
+    }
+
+    (!sections.is_empty()).then(|| sections.join("\n\n"))
+}
+
+/// Wrap a channel tab in a popover showing the channel's topic and rules
+fn channel_info_tooltip(
+    tab_button: Element<'static, Message>,
+    info: String,
+) -> Element<'static, Message> {
+    tooltip(
+        tab_button,
+        container(shaped_text(info).size(TOOLTIP_TEXT_SIZE))
+            .max_width(CHANNEL_INFO_TOOLTIP_MAX_WIDTH)
+            .padding(TOOLTIP_BACKGROUND_PADDING)
+            .style(tooltip_container_style),
+        tooltip::Position::Bottom,
+    )
+    .gap(TOOLTIP_GAP)
+    .padding(TOOLTIP_PADDING)
+    .into()
 }
 
 /// Create a console tab button (icon-only with tooltip)
@@ -291,6 +389,7 @@ fn create_inactive_tab_button(
     tab: ChatTab,
     label: String,
     has_unread: bool,
+    is_muted: bool,
 ) -> Element<'static, Message> {
     match &tab {
         ChatTab::Console => create_console_tab_button(tab, false),
@@ -302,7 +401,7 @@ fn create_inactive_tab_button(
                 shaped_text(label).size(CHAT_MESSAGE_SIZE)
             };
 
-            button(tab_text)
+            button(tab_label(tab_text, is_muted))
                 .on_press(Message::SwitchChatTab(tab))
                 .style(iced::widget::button::secondary)
                 .padding(INPUT_PADDING)
@@ -335,6 +434,8 @@ struct MessageRenderContext<'a> {
     font_size: f32,
     /// Action type for chat messages (Normal or Me)
     action: ChatAction,
+    /// Whether the message mentions us or matches a watched keyword
+    highlighted: bool,
 }
 
 /// Build a rich text element for a single message line
@@ -419,7 +520,11 @@ fn render_message_line(ctx: MessageRenderContext<'_>) -> Element<'static, Messag
             } else {
                 chat::text(ctx.theme)
             };
-            let text_color = chat::text(ctx.theme);
+            let text_color = if ctx.highlighted {
+                chat::highlight(ctx.theme)
+            } else {
+                chat::text(ctx.theme)
+            };
 
             // Handle action messages (/me)
             let (prefix, is_action) = match ctx.action {
@@ -442,56 +547,422 @@ fn render_message_line(ctx: MessageRenderContext<'_>) -> Element<'static, Messag
     }
 }
 
+/// Build the receipt ticks for a user message we sent, with a tooltip
+fn render_delivery_mark(
+    state: DeliveryState,
+    theme: &Theme,
+    font_size: f32,
+) -> Element<'static, Message> {
+    let (mark, label, color) = match state {
+        DeliveryState::Sent => ("✓", t("tooltip-message-sent"), chat::timestamp(theme)),
+        DeliveryState::Delivered => ("✓✓", t("tooltip-message-delivered"), chat::timestamp(theme)),
+        DeliveryState::Read => ("✓✓", t("tooltip-message-read"), theme.palette().primary),
+    };
+
+    tooltip(
+        shaped_text(mark).size(font_size).color(color),
+        container(shaped_text(label).size(TOOLTIP_TEXT_SIZE))
+            .padding(TOOLTIP_BACKGROUND_PADDING)
+            .style(tooltip_container_style),
+        tooltip::Position::Top,
+    )
+    .gap(TOOLTIP_GAP)
+    .padding(TOOLTIP_PADDING)
+    .into()
+}
+
+// ============================================================================
+// Code Blocks
+// ============================================================================
+
+/// Build a fenced code block from a chat message
+///
+/// `header` is the sender's line shown above the block. The code is
+/// highlighted for its language and scrolls sideways instead of wrapping.
+/// Blocks longer than `COLLAPSED_CODE_BLOCK_LINES` show only their first
+/// lines until expanded.
+fn render_code_block(
+    block: CodeBlock<'_>,
+    header: Element<'static, Message>,
+    connection_id: usize,
+    expanded: bool,
+    theme: &Theme,
+    font_size: f32,
+) -> Element<'static, Message> {
+    let line_count = block.code.lines().count();
+    let collapsed = line_count > COLLAPSED_CODE_BLOCK_LINES && !expanded;
+
+    let mut header_row = row![header]
+        .spacing(SMALL_SPACING)
+        .align_y(iced::Alignment::Center);
+    if line_count > COLLAPSED_CODE_BLOCK_LINES {
+        let label = if collapsed {
+            t_args("button-expand-code", &[("count", &line_count.to_string())])
+        } else {
+            t("button-collapse-code")
+        };
+        header_row = header_row.push(code_block_button(
+            label,
+            Message::ToggleCodeBlock(connection_id, block.key()),
+        ));
+    }
+    header_row = header_row.push(code_block_button(
+        t("menu-copy"),
+        Message::CopyCodeBlock(block.code.to_string()),
+    ));
+
+    let shown_lines = if collapsed {
+        COLLAPSED_CODE_BLOCK_LINES
+    } else {
+        line_count
+    };
+    let mut spans = highlighted_code(block, shown_lines, theme);
+    if collapsed {
+        spans.push(span("\n…".to_string()).color(chat::timestamp(theme)));
+    }
+    let code: Rich<'static, String, Message> = rich_text(spans)
+        .size(font_size)
+        .line_height(CHAT_LINE_HEIGHT)
+        .font(MONOSPACE_FONT)
+        .wrapping(Wrapping::None);
+
+    let code_box = container(
+        scrollable(code)
+            .direction(Direction::Horizontal(Scrollbar::default()))
+            .width(Fill),
+    )
+    .padding(SMALL_PADDING)
+    .width(Fill)
+    .style(code_block_style);
+
+    column![header_row, code_box].spacing(CHAT_SPACING).into()
+}
+
+/// Syntax highlighted spans for the first `lines` lines of a code block
+///
+/// Languages the highlighter doesn't know are shown as plain text.
+fn highlighted_code(
+    block: CodeBlock<'_>,
+    lines: usize,
+    theme: &Theme,
+) -> Vec<Span<'static, String, Font>> {
+    let mut highlighter = Highlighter::new(&highlighter::Settings {
+        theme: chat::code_theme(theme),
+        token: block.language.unwrap_or_default().to_string(),
+    });
+    let text_color = chat::text(theme);
+
+    let mut spans = Vec::new();
+    for (index, line) in block.code.lines().take(lines).enumerate() {
+        if index > 0 {
+            spans.push(span("\n".to_string()));
+        }
+        let line = line.replace('\t', CODE_BLOCK_TAB);
+
+        // Text between highlighted ranges keeps the plain text color
+        let mut end = 0;
+        for (range, highlight) in highlighter.highlight_line(&line) {
+            let Some(text) = line.get(range.clone()).filter(|_| range.start >= end) else {
+                continue;
+            };
+            if range.start > end {
+                spans.push(span(line[end..range.start].to_string()).color(text_color));
+            }
+            let format = highlight.to_format();
+            let font = format.font.map_or(MONOSPACE_FONT, |font| Font {
+                weight: font.weight,
+                style: font.style,
+                ..MONOSPACE_FONT
+            });
+            spans.push(
+                span(text.to_string())
+                    .color(format.color.unwrap_or(text_color))
+                    .font(font),
+            );
+            end = range.end;
+        }
+        if end < line.len() {
+            spans.push(span(line[end..].to_string()).color(text_color));
+        }
+    }
+    spans
+}
+
+/// Create a small button shown on a code block's header line
+fn code_block_button(label: String, message: Message) -> Element<'static, Message> {
+    button(shaped_text(label).size(TOOLTIP_TEXT_SIZE))
+        .on_press(message)
+        .padding(CODE_BLOCK_BUTTON_PADDING)
+        .style(iced::widget::button::secondary)
+        .into()
+}
+
+/// Build a line for a queued message, with retry and cancel buttons
+fn render_queued_message(
+    queued: &QueuedMessage,
+    nickname: &str,
+    theme: &Theme,
+    font_size: f32,
+) -> Element<'static, Message> {
+    let color = chat::timestamp(theme);
+    let queued_prefix = t("chat-prefix-queued");
+    let (prefix, is_action) = match queued.action {
+        ChatAction::Normal => (
+            format!("{queued_prefix} {nickname}{CHAT_MESSAGE_SEPARATOR}"),
+            false,
+        ),
+        ChatAction::Me => (
+            format!("{queued_prefix} {CHAT_ACTION_PREFIX}{nickname} "),
+            true,
+        ),
+    };
+
+    let style = MessageStyle {
+        timestamp_color: color,
+        prefix_color: color,
+        content_color: color,
+        link_color: theme.palette().primary,
+        font_size,
+        italic: is_action,
+    };
+    let line = styled_message(None, prefix, &queued.message, &style);
+
+    let retry = queued_message_button(
+        crate::icon::refresh(),
+        t("tooltip-retry-send"),
+        Message::QueuedMessageRetry(queued.id),
+        font_size,
+    );
+    let cancel = queued_message_button(
+        crate::icon::close(),
+        t("tooltip-cancel-send"),
+        Message::QueuedMessageCancel(queued.id),
+        font_size,
+    );
+
+    row![line, retry, cancel]
+        .spacing(SMALL_SPACING)
+        .align_y(iced::Alignment::Center)
+        .into()
+}
+
+/// Create a small icon button with a tooltip for a queued message
+fn queued_message_button(
+    icon: iced::widget::Text<'static>,
+    label: String,
+    message: Message,
+    font_size: f32,
+) -> Element<'static, Message> {
+    tooltip(
+        button(icon.size(font_size))
+            .on_press(message)
+            .padding(CLOSE_BUTTON_PADDING)
+            .style(transparent_icon_button_style),
+        container(shaped_text(label).size(TOOLTIP_TEXT_SIZE))
+            .padding(TOOLTIP_BACKGROUND_PADDING)
+            .style(tooltip_container_style),
+        tooltip::Position::Top,
+    )
+    .gap(TOOLTIP_GAP)
+    .padding(TOOLTIP_PADDING)
+    .into()
+}
+
+/// Ask how to send text with several lines, or too long for one message,
+/// pasted into the empty input
+///
+/// Text longer than `max_length` is sent in parts. Each way of sending is
+/// only offered when it takes up to `MAX_PASTE_MESSAGES` messages. Text
+/// that fits in a snippet can be shared as one, with a link posted instead.
+fn build_paste_prompt(
+    pasted: &str,
+    max_length: usize,
+    font_size: f32,
+) -> Element<'static, Message> {
+    let lines = paste_lines(pasted).count();
+    let messages = paste_messages(pasted, max_length).len();
+    let parts = split_message(pasted, max_length).len();
+
+    let prompt_text = if pasted.len() > max_length {
+        t_args(
+            "chat-paste-too-long-prompt",
+            &[("max", &max_length.to_string())],
+        )
+    } else {
+        t_args("chat-paste-prompt", &[("count", &lines.to_string())])
+    };
+    let mut prompt = row![
+        shaped_text(prompt_text).size(font_size),
+        Space::new().width(Fill),
+    ]
+    .spacing(SMALL_SPACING)
+    .align_y(iced::Alignment::Center);
+
+    if lines > 1 && messages <= MAX_PASTE_MESSAGES {
+        prompt = prompt.push(
+            button(
+                shaped_text(t_args(
+                    "button-send-as-messages",
+                    &[("count", &messages.to_string())],
+                ))
+                .size(font_size),
+            )
+            .on_press(Message::PasteAsMessages)
+            .padding(INPUT_PADDING),
+        );
+    }
+
+    if parts <= 1 {
+        prompt = prompt.push(
+            button(shaped_text(t("button-send-as-one-message")).size(font_size))
+                .on_press(Message::PasteAsOneMessage)
+                .padding(INPUT_PADDING),
+        );
+    } else if parts <= MAX_PASTE_MESSAGES {
+        prompt = prompt.push(
+            button(
+                shaped_text(t_args(
+                    "button-send-in-parts",
+                    &[("count", &parts.to_string())],
+                ))
+                .size(font_size),
+            )
+            .on_press(Message::PasteAsOneMessage)
+            .padding(INPUT_PADDING),
+        );
+    }
+
+    if pasted.len() <= MAX_SNIPPET_LENGTH {
+        prompt = prompt.push(
+            button(shaped_text(t("button-share-as-snippet")).size(font_size))
+                .on_press(Message::PasteAsSnippet)
+                .padding(INPUT_PADDING),
+        );
+    }
+
+    prompt
+        .push(
+            button(shaped_text(t("button-cancel")).size(font_size))
+                .on_press(Message::PasteCancelled)
+                .padding(INPUT_PADDING)
+                .style(iced::widget::button::secondary),
+        )
+        .into()
+}
+
+/// Ask to acknowledge the oldest broadcast whose sender asked for it
+fn build_broadcast_ack_prompt(
+    ack: &PendingBroadcastAck,
+    font_size: f32,
+) -> Element<'static, Message> {
+    row![
+        shaped_text(t_args(
+            "chat-broadcast-ack-prompt",
+            &[("username", &ack.username)]
+        ))
+        .size(font_size),
+        Space::new().width(Fill),
+        button(shaped_text(t("button-acknowledge")).size(font_size))
+            .on_press(Message::BroadcastAcknowledged)
+            .padding(INPUT_PADDING),
+        button(shaped_text(t("button-dismiss")).size(font_size))
+            .on_press(Message::BroadcastAckDismissed)
+            .padding(INPUT_PADDING)
+            .style(iced::widget::button::secondary),
+    ]
+    .spacing(SMALL_SPACING)
+    .align_y(iced::Alignment::Center)
+    .into()
+}
+
 // ============================================================================
 // Message List
 // ============================================================================
 
-/// Build the message list column for the active chat tab
+/// Build the message list column for a chat tab
 fn build_message_list<'a>(
     conn: &'a ServerConnection,
+    tab: &ChatTab,
     theme: &Theme,
     font_size: f32,
     timestamp_settings: TimestampSettings,
 ) -> Column<'a, Message> {
-    let messages = match &conn.active_chat_tab {
-        ChatTab::Console => conn.console_messages.as_slice(),
-        ChatTab::Channel(channel) => {
-            let channel_lower = channel.to_lowercase();
-            conn.channels
-                .get(&channel_lower)
-                .map(|ch| ch.messages.as_slice())
-                .unwrap_or(&[])
-        }
-        ChatTab::UserMessage(nickname) => conn
-            .user_messages
-            .get(nickname)
-            .map(|v| v.as_slice())
-            .unwrap_or(&[]),
-    };
+    let messages = conn.tab_messages(tab);
 
     let mut chat_column = Column::new().spacing(CHAT_SPACING).padding(INPUT_PADDING);
 
     for msg in messages {
         let time_str = timestamp_settings.format(&msg.get_timestamp());
 
-        // Split message into lines to prevent spoofing via embedded newlines
-        // Each line is displayed with the same timestamp/username prefix
-        for line in msg.message.split('\n') {
-            let display = render_message_line(MessageRenderContext {
-                time_str: time_str.clone(),
-                nickname: &msg.nickname,
-                line,
-                message_type: msg.message_type,
-                theme,
-                is_admin: msg.is_admin,
-                is_shared: msg.is_shared,
-                font_size,
-                action: msg.action,
-            });
-            chat_column = chat_column.push(display);
+        // Only chat from users gets code blocks; everything else is text
+        let parts = match msg.message_type {
+            MessageType::Chat => split_code_blocks(&msg.message),
+            _ => vec![MessagePart::Text(&msg.message)],
+        };
+
+        let mut parts = parts.into_iter().peekable();
+        while let Some(part) = parts.next() {
+            let last_part = parts.peek().is_none();
+
+            // Split text into lines to prevent spoofing via embedded newlines
+            // Each line (and each code block's header line) is displayed with
+            // the same timestamp/username prefix
+            let (lines, block) = match part {
+                MessagePart::Text(text) => (text.split('\n').collect::<Vec<_>>(), None),
+                MessagePart::Code(block) => (vec![block.language.unwrap_or_default()], Some(block)),
+            };
+
+            let line_count = lines.len();
+            for (index, line) in lines.into_iter().enumerate() {
+                let mut display = render_message_line(MessageRenderContext {
+                    time_str: time_str.clone(),
+                    nickname: &msg.nickname,
+                    line,
+                    message_type: msg.message_type,
+                    theme,
+                    is_admin: msg.is_admin,
+                    is_shared: msg.is_shared,
+                    font_size,
+                    action: msg.action,
+                    highlighted: msg.highlighted,
+                });
+
+                // Receipt ticks follow the last line of our own user messages
+                if let Some(state) = msg.delivery
+                    && last_part
+                    && index + 1 == line_count
+                {
+                    display = row![display, render_delivery_mark(state, theme, font_size)]
+                        .spacing(SMALL_SPACING)
+                        .align_y(iced::Alignment::Center)
+                        .into();
+                }
+
+                chat_column = chat_column.push(match block {
+                    Some(block) => render_code_block(
+                        block,
+                        display,
+                        conn.connection_id,
+                        conn.expanded_code_blocks.contains(&block.key()),
+                        theme,
+                        font_size,
+                    ),
+                    None => display,
+                });
+            }
         }
     }
 
+    // Messages waiting to be sent go last
+    for queued in conn.send_queue.iter().filter(|queued| &queued.tab == tab) {
+        chat_column = chat_column.push(render_queued_message(
+            queued,
+            &conn.nickname,
+            theme,
+            font_size,
+        ));
+    }
+
     chat_column
 }
 
@@ -499,8 +970,14 @@ fn build_message_list<'a>(
 // Tab Bar
 // ============================================================================
 
-/// Build the tab bar with Console, channel, and user message tabs
-fn build_tab_bar(conn: &ServerConnection) -> (iced::widget::Row<'static, Message>, bool) {
+/// Build the tab bar with Console, channel, user message, and group tabs
+///
+/// `notification_rules` are the connection's bookmark's (None without one).
+fn build_tab_bar(
+    conn: &ServerConnection,
+    notification_rules: Option<&NotificationRules>,
+) -> (iced::widget::Row<'static, Message>, bool) {
+    let muted = |tab: &ChatTab| notification_rules.map(|rules| rules.is_tab_muted(tab));
     let mut tab_row = row![].spacing(SMALL_SPACING);
 
     // Console tab (always present, cannot be closed)
@@ -511,6 +988,7 @@ fn build_tab_bar(conn: &ServerConnection) -> (iced::widget::Row<'static, Message
         t("console-tab"),
         is_console_active,
         console_has_unread,
+        None,
     );
     tab_row = tab_row.push(console_tab_button);
 
@@ -519,8 +997,19 @@ fn build_tab_bar(conn: &ServerConnection) -> (iced::widget::Row<'static, Message
         let channel_tab = ChatTab::Channel(channel.clone());
         let is_active = conn.active_chat_tab == channel_tab;
         let has_unread = conn.unread_tabs.contains(&channel_tab);
-        let channel_tab_button =
-            create_tab_button(channel_tab, channel.clone(), is_active, has_unread);
+        let is_muted = muted(&channel_tab);
+        let channel_tab_button = create_tab_button(
+            channel_tab,
+            channel.clone(),
+            is_active,
+            has_unread,
+            is_muted,
+        );
+        // Hovering a channel tab shows its topic and rules
+        let channel_tab_button = match channel_info_text(conn, channel) {
+            Some(info) => channel_info_tooltip(channel_tab_button, info),
+            None => channel_tab_button,
+        };
         tab_row = tab_row.push(channel_tab_button);
     }
 
@@ -531,12 +1020,34 @@ fn build_tab_bar(conn: &ServerConnection) -> (iced::widget::Row<'static, Message
         let pm_tab = ChatTab::UserMessage(nickname.clone());
         let is_active = conn.active_chat_tab == pm_tab;
         let has_unread = conn.unread_tabs.contains(&pm_tab);
-        let pm_tab_button = create_tab_button(pm_tab, nickname.clone(), is_active, has_unread);
+        let is_muted = muted(&pm_tab);
+        let pm_tab_button =
+            create_tab_button(pm_tab, nickname.clone(), is_active, has_unread, is_muted);
         tab_row = tab_row.push(pm_tab_button);
     }
 
-    // Has closeable tabs if there are channels or PMs
-    let has_closeable_tabs = !conn.channel_tabs.is_empty() || has_pm_tabs;
+    // Group conversation tabs (in creation order, never muted)
+    for (group_id, group) in conn
+        .group_tabs
+        .iter()
+        .filter_map(|id| conn.groups.get(id).map(|group| (*id, group)))
+    {
+        let group_tab = ChatTab::Group(group_id);
+        let is_active = conn.active_chat_tab == group_tab;
+        let has_unread = conn.unread_tabs.contains(&group_tab);
+        let group_tab_button = create_tab_button(
+            group_tab,
+            group.label(&conn.nickname),
+            is_active,
+            has_unread,
+            None,
+        );
+        tab_row = tab_row.push(group_tab_button);
+    }
+
+    // Has closeable tabs if there are channels, PMs, or groups
+    let has_closeable_tabs =
+        !conn.channel_tabs.is_empty() || has_pm_tabs || !conn.group_tabs.is_empty();
 
     (tab_row, has_closeable_tabs)
 }
@@ -564,7 +1075,10 @@ fn build_tab_bar(conn: &ServerConnection) -> (iced::widget::Row<'static, Message
 #[allow(clippy::too_many_arguments)]
 pub fn chat_view<'a>(
     conn: &'a ServerConnection,
-    message_input: &'a str,
+    notification_rules: Option<&NotificationRules>,
+    message_input: &'a text_editor::Content,
+    spell_settings: &SpellSettings,
+    spell_menu: Option<&'a SpellMenu>,
     theme: Theme,
     chat_font_size: u8,
     timestamp_settings: TimestampSettings,
@@ -576,11 +1090,17 @@ pub fn chat_view<'a>(
     let font_size = chat_font_size as f32;
 
     // Build tab bar
-    let (tab_row, has_closeable_tabs) = build_tab_bar(conn);
+    let (tab_row, has_closeable_tabs) = build_tab_bar(conn, notification_rules);
     let tab_bar = tab_row.wrap();
 
     // Build message list
-    let chat_column = build_message_list(conn, &theme, font_size, timestamp_settings);
+    let chat_column = build_message_list(
+        conn,
+        &conn.active_chat_tab,
+        &theme,
+        font_size,
+        timestamp_settings,
+    );
 
     let chat_scrollable = scrollable(chat_column)
         .id(ScrollableId::ChatMessages)
@@ -596,22 +1116,41 @@ pub fn chat_view<'a>(
     // Build input row with voice button
     let input_row = build_input_row_with_voice(
         message_input,
+        spell_settings,
+        spell_menu,
         font_size,
         conn,
         has_voice_permission,
         voice_target,
     );
 
-    // Build the bottom section (voice bar + input row)
-    let bottom_section = if let Some(ref session) = conn.voice_session {
+    // Build the bottom section (voice bar + prompts + input row)
+    let mut bottom_section = Column::new().spacing(SMALL_SPACING).width(Fill);
+    if let Some(ref session) = conn.voice_session {
+        // Show recent voice texts above the voice bar
+        if let Some(overlay) = build_voice_text_overlay(session, &theme) {
+            bottom_section = bottom_section.push(overlay);
+        }
         // Show voice bar above input when in a voice session
-        let voice_bar = build_voice_bar(session, is_local_speaking, is_deafened, mic_level, &theme);
-        column![voice_bar, input_row]
-            .spacing(SMALL_SPACING)
-            .width(Fill)
-    } else {
-        column![input_row].width(Fill)
-    };
+        bottom_section = bottom_section.push(build_voice_bar(
+            session,
+            is_local_speaking,
+            is_deafened,
+            mic_level,
+            &theme,
+        ));
+    }
+    if let Some(ack) = conn.broadcast_acks.first() {
+        bottom_section = bottom_section.push(build_broadcast_ack_prompt(ack, font_size));
+    }
+    if let Some(pasted) = &conn.pending_paste {
+        bottom_section = bottom_section.push(build_paste_prompt(
+            pasted,
+            conn.max_message_length(),
+            font_size,
+        ));
+    }
+    let bottom_section = bottom_section.push(input_row);
 
     // Chat content with background
     let chat_content = container(
@@ -636,3 +1175,62 @@ pub fn chat_view<'a>(
         chat_content.into()
     }
 }
+
+// ============================================================================
+// Chat Window
+// ============================================================================
+
+/// Displays a chat tab popped out into its own window
+///
+/// The message list sticks to the newest message unless scrolled up, and
+/// scrolls independently of the main window.
+pub fn chat_window_view<'a>(
+    id: iced::window::Id,
+    chat_window: &'a ChatWindow,
+    conn: &'a ServerConnection,
+    theme: Theme,
+    chat_font_size: u8,
+    timestamp_settings: TimestampSettings,
+) -> Element<'a, Message> {
+    let font_size = chat_font_size as f32;
+
+    let chat_column = build_message_list(
+        conn,
+        &chat_window.tab,
+        &theme,
+        font_size,
+        timestamp_settings,
+    );
+    let chat_scrollable = scrollable(chat_column)
+        .anchor_bottom()
+        .direction(Direction::Vertical(Scrollbar::default()))
+        .width(Fill)
+        .height(Fill);
+
+    let text_field = text_input(&t("placeholder-message"), &chat_window.message_input)
+        .on_input(move |value| Message::ChatWindowInputChanged(id, value))
+        .on_submit(Message::ChatWindowSendPressed(id))
+        .id(chat_window.input_id.clone())
+        .padding(INPUT_PADDING)
+        .size(font_size)
+        .font(MONOSPACE_FONT)
+        .width(Fill);
+
+    let send_button = button(shaped_text(t("button-send")).size(font_size))
+        .on_press(Message::ChatWindowSendPressed(id))
+        .padding(INPUT_PADDING);
+
+    let input_row = row![text_field, send_button]
+        .spacing(SMALL_SPACING)
+        .width(Fill);
+
+    container(
+        column![chat_scrollable, input_row]
+            .spacing(SMALL_SPACING)
+            .padding(SMALL_PADDING),
+    )
+    .width(Fill)
+    .height(Fill)
+    .style(content_background_style)
+    .into()
+}
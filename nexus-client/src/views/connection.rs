@@ -1,15 +1,18 @@
 //! Connection form for new server connections
 
-use iced::widget::{Id, Space, button, checkbox, column, row, text, text_input};
+use iced::widget::button as btn;
+use iced::widget::{Id, Space, button, checkbox, column, container, row, text, text_input};
 use iced::{Center, Element, Fill};
 use iced_aw::NumberInput;
 
 use super::layout::scrollable_panel;
+use crate::config::settings::Tip;
 use crate::i18n::t;
 use crate::style::{
     BUTTON_PADDING, CONTENT_MAX_WIDTH, CONTENT_PADDING, ELEMENT_SPACING, INPUT_PADDING,
-    SPACER_SIZE_MEDIUM, SPACER_SIZE_SMALL, TEXT_SIZE, error_text_style, panel_title, shaped_text,
-    shaped_text_wrapped,
+    SPACER_SIZE_MEDIUM, SPACER_SIZE_SMALL, SUBHEADING_SIZE, TEXT_SIZE, TIP_CARD_PADDING,
+    error_text_style, muted_text_style, panel_title, shaped_text, shaped_text_wrapped,
+    tip_card_style,
 };
 use crate::types::{ConnectionFormState, InputId, Message};
 
@@ -22,7 +25,12 @@ use crate::types::{ConnectionFormState, InputId, Message};
 /// Shows validated input fields for connecting to a new server. Server name is
 /// optional, but address, port, and username are required. Password can be empty
 /// for servers that don't require authentication.
-pub fn connection_form_view(form: &ConnectionFormState) -> Element<'_, Message> {
+///
+/// `show_welcome` puts the first-run welcome card above the form.
+pub fn connection_form_view(
+    form: &ConnectionFormState,
+    show_welcome: bool,
+) -> Element<'_, Message> {
     // Validate required fields (username and password are optional)
     // Port is always valid since it's a u16
     let can_connect = !form.server_name.trim().is_empty() && !form.server_address.trim().is_empty();
@@ -90,7 +98,11 @@ pub fn connection_form_view(form: &ConnectionFormState) -> Element<'_, Message>
         button(shaped_text(t("button-connect")).size(TEXT_SIZE)).padding(BUTTON_PADDING)
     };
 
-    let mut column_items: Vec<Element<'_, Message>> = vec![title.into()];
+    let mut column_items: Vec<Element<'_, Message>> = Vec::new();
+    if show_welcome {
+        column_items.push(welcome_card());
+    }
+    column_items.push(title.into());
 
     // Show error if present (at top for visibility)
     if let Some(error) = &form.error {
@@ -115,6 +127,12 @@ pub fn connection_form_view(form: &ConnectionFormState) -> Element<'_, Message>
         password_input.into(),
         nickname_input.into(),
         Space::new().height(SPACER_SIZE_SMALL).into(),
+        checkbox(form.use_sso)
+            .label(t("label-use-sso"))
+            .on_toggle(Message::UseSsoToggled)
+            .size(TEXT_SIZE)
+            .text_shaping(text::Shaping::Advanced)
+            .into(),
         checkbox(form.add_bookmark)
             .label(t("label-add-bookmark"))
             .on_toggle(Message::AddBookmarkToggled)
@@ -127,6 +145,34 @@ pub fn connection_form_view(form: &ConnectionFormState) -> Element<'_, Message>
             .into(),
     ]);
 
+    // Servers advertising themselves on the local network
+    if !form.lan_servers.is_empty() {
+        column_items.push(Space::new().height(SPACER_SIZE_MEDIUM).into());
+        column_items.push(shaped_text(t("label-lan-servers")).size(TEXT_SIZE).into());
+        for server in &form.lan_servers {
+            let connect = button(shaped_text(t("button-connect")).size(TEXT_SIZE))
+                .on_press_maybe(
+                    (!form.is_connecting)
+                        .then(|| Message::ConnectToLanServer(server.fullname.clone())),
+                )
+                .padding(BUTTON_PADDING)
+                .style(btn::secondary);
+            column_items.push(
+                row![
+                    shaped_text(&server.name).size(TEXT_SIZE),
+                    shaped_text(format!("{}:{}", server.address, server.port))
+                        .size(TEXT_SIZE)
+                        .style(muted_text_style),
+                    Space::new().width(Fill),
+                    connect,
+                ]
+                .spacing(ELEMENT_SPACING)
+                .align_y(Center)
+                .into(),
+            );
+        }
+    }
+
     let content = column(column_items)
         .spacing(ELEMENT_SPACING)
         .padding(CONTENT_PADDING)
@@ -134,3 +180,38 @@ pub fn connection_form_view(form: &ConnectionFormState) -> Element<'_, Message>
 
     scrollable_panel(content)
 }
+
+/// First-run card explaining how to get started and what fingerprints are for
+fn welcome_card<'a>() -> Element<'a, Message> {
+    let buttons = row![
+        Space::new().width(Fill),
+        button(shaped_text(t("button-skip-tips")).size(TEXT_SIZE))
+            .on_press(Message::TipsSkipped)
+            .padding(BUTTON_PADDING)
+            .style(btn::secondary),
+        button(shaped_text(t("button-tip-got-it")).size(TEXT_SIZE))
+            .on_press(Message::TipDismissed(Tip::Welcome))
+            .padding(BUTTON_PADDING)
+            .style(btn::secondary),
+        button(shaped_text(t("button-add-bookmark")).size(TEXT_SIZE))
+            .on_press(Message::ShowAddBookmark)
+            .padding(BUTTON_PADDING),
+    ]
+    .spacing(ELEMENT_SPACING);
+
+    container(
+        column![
+            shaped_text(t("tip-welcome-title")).size(SUBHEADING_SIZE),
+            shaped_text_wrapped(t(Tip::Welcome.translation_key())).size(TEXT_SIZE),
+            shaped_text_wrapped(t(Tip::Fingerprint.translation_key()))
+                .size(TEXT_SIZE)
+                .style(muted_text_style),
+            buttons,
+        ]
+        .spacing(ELEMENT_SPACING),
+    )
+    .padding(TIP_CARD_PADDING)
+    .width(Fill)
+    .style(tip_card_style)
+    .into()
+}
@@ -2,6 +2,7 @@
 //!
 //! Displays tabs for active connections and file transfers.
 //! Connections tab shows nickname, username, IP address, and connection time.
+//! IP addresses on a DNS blocklist are highlighted, with the zone in a tooltip.
 //! Transfers tab shows user, direction, path, progress, and time.
 //! Supports right-click context menu for actions (permission-gated).
 
@@ -15,7 +16,7 @@ use iced_aw::{TabLabel, Tabs};
 use nexus_common::protocol::{ConnectionInfo, TransferInfo};
 
 use super::constants::{PERMISSION_BAN_CREATE, PERMISSION_USER_INFO, PERMISSION_USER_KICK};
-use crate::i18n::t;
+use crate::i18n::{t, t_args};
 use crate::icon;
 use crate::style::{
     CONTENT_MAX_WIDTH, CONTENT_PADDING, CONTEXT_MENU_ITEM_PADDING, CONTEXT_MENU_MIN_WIDTH,
@@ -27,6 +28,7 @@ use crate::style::{
     content_background_style, context_menu_container_style, error_text_style,
     menu_button_danger_style, menu_button_style, muted_text_style, separator_style, shaped_text,
     shaped_text_wrapped, tooltip_container_style, transparent_icon_button_style,
+    warning_text_style,
 };
 use crate::types::{
     ConnectionMonitorSortColumn, ConnectionMonitorState, ConnectionMonitorTab, Message,
@@ -67,6 +69,7 @@ impl Hash for ConnectionTableDeps {
             conn.login_time.hash(state);
             conn.is_admin.hash(state);
             conn.is_shared.hash(state);
+            conn.listed_in.hash(state);
         }
         self.sort_column.hash(state);
         self.sort_ascending.hash(state);
@@ -99,6 +102,8 @@ impl Hash for TransferTableDeps {
             transfer.started_at.hash(state);
             transfer.is_admin.hash(state);
             transfer.is_shared.hash(state);
+            transfer.queued.hash(state);
+            transfer.weight.hash(state);
         }
         self.sort_column.hash(state);
         self.sort_ascending.hash(state);
@@ -162,6 +167,30 @@ fn format_progress_tooltip(bytes_transferred: u64, total_size: u64) -> String {
     )
 }
 
+/// Format the progress cell, showing the queue state for transfers waiting on a slot
+fn format_transfer_progress(transfer: &TransferInfo) -> String {
+    if transfer.queued {
+        t("connection-monitor-queued")
+    } else {
+        format_progress_percent(transfer.bytes_transferred, transfer.total_size)
+    }
+}
+
+/// Format the progress tooltip, adding the fair-share weight when it is boosted
+fn format_transfer_tooltip(transfer: &TransferInfo) -> String {
+    let progress = format_progress_tooltip(transfer.bytes_transferred, transfer.total_size);
+    if transfer.weight > 1 {
+        let weight = transfer.weight.to_string();
+        format!(
+            "{}\n{}",
+            progress,
+            t_args("connection-monitor-weight", &[("weight", &weight)])
+        )
+    } else {
+        progress
+    }
+}
+
 /// Build a context menu with Info, Copy, Kick, Ban actions for connections
 ///
 /// Menu structure:
@@ -468,11 +497,25 @@ fn lazy_connection_table(deps: ConnectionTableDeps) -> Element<'static, Message>
             let ip_for_value = conn.ip.clone();
             let is_admin_row = conn.is_admin;
 
-            let content: Element<'static, Message> = shaped_text(conn.ip)
+            let ip_text = shaped_text(conn.ip)
                 .size(TEXT_SIZE)
-                .style(muted_text_style)
-                .wrapping(Wrapping::WordOrGlyph)
-                .into();
+                .wrapping(Wrapping::WordOrGlyph);
+            let content: Element<'static, Message> = match conn.listed_in {
+                Some(zone) => tooltip(
+                    ip_text.style(warning_text_style),
+                    container(
+                        shaped_text(t_args("connection-monitor-listed", &[("zone", &zone)]))
+                            .size(TOOLTIP_TEXT_SIZE),
+                    )
+                    .padding(TOOLTIP_BACKGROUND_PADDING)
+                    .style(tooltip_container_style),
+                    tooltip::Position::Top,
+                )
+                .gap(TOOLTIP_GAP)
+                .padding(TOOLTIP_PADDING)
+                .into(),
+                None => ip_text.style(muted_text_style).into(),
+            };
 
             LazyContextMenu::new(content, move || {
                 build_connection_context_menu(
@@ -803,10 +846,8 @@ fn lazy_transfer_table(deps: TransferTableDeps) -> Element<'static, Message> {
 
         // Progress column (percentage with tooltip showing bytes)
         let progress_column = table::column(progress_header, move |transfer: TransferInfo| {
-            let progress_str =
-                format_progress_percent(transfer.bytes_transferred, transfer.total_size);
-            let tooltip_str =
-                format_progress_tooltip(transfer.bytes_transferred, transfer.total_size);
+            let progress_str = format_transfer_progress(&transfer);
+            let tooltip_str = format_transfer_tooltip(&transfer);
 
             let content = shaped_text(progress_str)
                 .size(TEXT_SIZE)
@@ -1122,7 +1163,19 @@ pub fn connection_monitor_view<'a>(
         _ => "…".to_string(),
     };
     let transfers_count = match &state.transfers {
-        Some(Ok(transfers)) => transfers.len().to_string(),
+        Some(Ok(transfers)) => {
+            let queued = transfers.iter().filter(|transfer| transfer.queued).count();
+            if queued > 0 {
+                let queued = queued.to_string();
+                format!(
+                    "{}, {}",
+                    transfers.len(),
+                    t_args("connection-monitor-queued-count", &[("count", &queued)])
+                )
+            } else {
+                transfers.len().to_string()
+            }
+        }
         _ => "…".to_string(),
     };
     let connections_label = format!("{} ({})", t("tab-connections"), connections_count);
@@ -22,9 +22,18 @@ pub(crate) const PERMISSION_CHAT_TOPIC: &str = "chat_topic";
 /// Permission to edit chat topic
 pub(crate) const PERMISSION_CHAT_TOPIC_EDIT: &str = "chat_topic_edit";
 
+/// Permission to lock chat topic
+pub(crate) const PERMISSION_CHAT_TOPIC_LOCK: &str = "chat_topic_lock";
+
+/// Permission to set channel entry messages and rules
+pub(crate) const PERMISSION_CHAT_ENTRY_EDIT: &str = "chat_entry_edit";
+
 /// Permission to join existing channels
 pub(crate) const PERMISSION_CHAT_JOIN: &str = "chat_join";
 
+/// Permission to set the language tag of channels
+pub(crate) const PERMISSION_CHAT_LANGUAGE: &str = "chat_language";
+
 /// Permission to toggle channel secret mode
 pub(crate) const PERMISSION_CHAT_SECRET: &str = "chat_secret";
 
@@ -106,6 +115,9 @@ pub(crate) const PERMISSION_FILE_UPLOAD: &str = "file_upload";
 /// Permission to search files
 pub(crate) const PERMISSION_FILE_SEARCH: &str = "file_search";
 
+/// Permission to rate and comment on files
+pub(crate) const PERMISSION_FILE_COMMENT: &str = "file_comment";
+
 /// Permission to trigger file index rebuild
 pub(crate) const PERMISSION_FILE_REINDEX: &str = "file_reindex";
 
@@ -134,8 +146,25 @@ pub(crate) const PERMISSION_TRUST_LIST: &str = "trust_list";
 /// Permission to view active connections
 pub(crate) const PERMISSION_CONNECTION_MONITOR: &str = "connection_monitor";
 
+/// Permission to follow the server log
+pub(crate) const PERMISSION_LOG_VIEW: &str = "log_view";
+
+// === Door Permissions ===
+
+/// Permission to run door programs
+pub(crate) const PERMISSION_DOOR_PLAY: &str = "door_play";
+
 // === Voice Permissions ===
 
+/// Permission to broadcast audio to a channel's listeners
+pub(crate) const PERMISSION_VOICE_BROADCAST: &str = "voice_broadcast";
+
+/// Permission to upload soundboard clips and delete your own
+pub(crate) const PERMISSION_VOICE_CLIP_UPLOAD: &str = "voice_clip_upload";
+
+/// Permission to set the voice participant limit of a channel
+pub(crate) const PERMISSION_VOICE_LIMIT: &str = "voice_limit";
+
 /// Permission to receive audio from others in voice chat
 pub(crate) const PERMISSION_VOICE_LISTEN: &str = "voice_listen";
 
@@ -0,0 +1,281 @@
+//! Doors panel view
+//!
+//! Lists the server's doors and shows the terminal of the one being played.
+//! The screen is drawn from the emulated terminal as rows of colored spans;
+//! key presses are forwarded by the keyboard handler while a door runs.
+
+use iced::widget::text::Span;
+use iced::widget::{Column, Space, button, column, container, rich_text, row, span};
+use iced::{Center, Color, Element, Fill, Font, Theme};
+
+use super::layout::scrollable_panel;
+use crate::i18n::{t, t_args};
+use crate::style::{
+    BUTTON_PADDING, CONTENT_MAX_WIDTH, CONTENT_PADDING, ELEMENT_SPACING, MONOSPACE_FONT,
+    SPACER_SIZE_MEDIUM, SPACER_SIZE_SMALL, TEXT_SIZE, content_background_style, error_text_style,
+    list_item_button_style, muted_text_style, panel_title, shaped_text, shaped_text_wrapped,
+};
+use crate::types::{DoorSession, DoorsState, Message};
+
+/// Standard xterm colors for the 16 basic color indexes
+///
+/// Door output picks its own colors, so these can't come from the theme.
+const ANSI_COLORS: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// Color for a 256-color index (16 basic colors, 6x6x6 cube, grayscale ramp)
+fn indexed_color(index: u8) -> Color {
+    let (r, g, b) = match index {
+        0..=15 => ANSI_COLORS[index as usize],
+        16..=231 => {
+            let level = |n: u8| if n == 0 { 0 } else { 55 + n * 40 };
+            let n = index - 16;
+            (level(n / 36), level((n / 6) % 6), level(n % 6))
+        }
+        _ => {
+            let gray = 8 + (index - 232) * 10;
+            (gray, gray, gray)
+        }
+    };
+    Color::from_rgb8(r, g, b)
+}
+
+/// Resolve a cell color, falling back to the theme for the default color
+///
+/// Bold text in one of the 8 basic colors is drawn bright, as classic BBS
+/// terminals do.
+fn cell_color(color: vt100::Color, default: Color, bold: bool) -> Color {
+    match color {
+        vt100::Color::Default => default,
+        vt100::Color::Idx(index) if bold && index < 8 => indexed_color(index + 8),
+        vt100::Color::Idx(index) => indexed_color(index),
+        vt100::Color::Rgb(r, g, b) => Color::from_rgb8(r, g, b),
+    }
+}
+
+/// Draw the emulated screen as one rich text row per terminal line
+fn terminal<'a>(session: &DoorSession, theme: &Theme) -> Element<'a, Message> {
+    let palette = theme.palette();
+    let Ok(parser) = session.screen.lock() else {
+        return Space::new().into();
+    };
+    let screen = parser.screen();
+    let (rows, cols) = screen.size();
+    let (cursor_row, cursor_col) = screen.cursor_position();
+    let show_cursor = session.running && !screen.hide_cursor();
+
+    let mut lines: Vec<Element<'a, Message>> = Vec::with_capacity(rows as usize);
+    for row in 0..rows {
+        let mut spans: Vec<Span<'a, (), Font>> = Vec::new();
+        let mut run = String::new();
+        let mut run_colors: Option<(Color, Color)> = None;
+
+        for col in 0..cols {
+            let Some(cell) = screen.cell(row, col) else {
+                continue;
+            };
+            if cell.is_wide_continuation() {
+                continue;
+            }
+
+            let mut fg = cell_color(cell.fgcolor(), palette.text, cell.bold());
+            let mut bg = cell_color(cell.bgcolor(), palette.background, false);
+            let is_cursor = show_cursor && row == cursor_row && col == cursor_col;
+            if cell.inverse() != is_cursor {
+                std::mem::swap(&mut fg, &mut bg);
+            }
+
+            if run_colors.is_some_and(|colors| colors != (fg, bg)) {
+                spans.push(colored_span(std::mem::take(&mut run), run_colors));
+            }
+            run_colors = Some((fg, bg));
+
+            let contents = cell.contents();
+            if contents.is_empty() {
+                run.push(' ');
+            } else {
+                run.push_str(&contents);
+            }
+        }
+        spans.push(colored_span(run, run_colors));
+
+        lines.push(
+            rich_text(spans)
+                .size(TEXT_SIZE)
+                .font(MONOSPACE_FONT)
+                .wrapping(iced::widget::text::Wrapping::None)
+                .into(),
+        );
+    }
+
+    container(Column::with_children(lines))
+        .padding(SPACER_SIZE_SMALL)
+        .style(content_background_style)
+        .into()
+}
+
+/// One run of cells sharing the same colors
+fn colored_span<'a>(text: String, colors: Option<(Color, Color)>) -> Span<'a, (), Font> {
+    match colors {
+        Some((fg, bg)) => span(text).color(fg).background(bg),
+        None => span(text),
+    }
+}
+
+/// Status line under the door's name
+fn session_status(session: &DoorSession) -> String {
+    if session.opening {
+        t("doors-starting")
+    } else if session.running {
+        t("doors-playing-hint")
+    } else {
+        match session.exit_code {
+            Some(code) => t_args("doors-exited-code", &[("code", &code.to_string())]),
+            None => t("doors-exited"),
+        }
+    }
+}
+
+/// A running (or just finished) door
+fn session_view<'a>(session: &DoorSession, theme: &Theme) -> Element<'a, Message> {
+    let leave_label = if session.running {
+        t("button-doors-leave")
+    } else {
+        t("button-doors-back")
+    };
+
+    let header = row![
+        column![
+            shaped_text(session.name.clone()).size(TEXT_SIZE),
+            shaped_text(session_status(session))
+                .size(TEXT_SIZE)
+                .style(muted_text_style),
+        ]
+        .spacing(SPACER_SIZE_SMALL),
+        Space::new().width(Fill),
+        button(shaped_text(leave_label).size(TEXT_SIZE))
+            .on_press(Message::DoorLeave)
+            .padding(BUTTON_PADDING),
+    ]
+    .spacing(ELEMENT_SPACING)
+    .align_y(Center);
+
+    // Wider than other panels: the terminal needs its full width
+    let content = column![header, terminal(session, theme)]
+        .spacing(SPACER_SIZE_MEDIUM)
+        .padding(CONTENT_PADDING);
+
+    container(content)
+        .width(Fill)
+        .height(Fill)
+        .center(Fill)
+        .style(content_background_style)
+        .into()
+}
+
+/// Doors panel
+pub fn doors_view<'a>(state: &'a DoorsState, theme: &Theme) -> Element<'a, Message> {
+    if let Some(session) = &state.session {
+        return session_view(session, theme);
+    }
+
+    let content: Element<'a, Message> = if state.loading {
+        shaped_text(t("doors-loading"))
+            .size(TEXT_SIZE)
+            .style(muted_text_style)
+            .into()
+    } else if state.doors.is_empty() {
+        shaped_text(t("doors-empty"))
+            .size(TEXT_SIZE)
+            .style(muted_text_style)
+            .into()
+    } else {
+        Column::with_children(state.doors.iter().map(|name| {
+            button(shaped_text(name).size(TEXT_SIZE))
+                .on_press(Message::DoorOpen(name.clone()))
+                .width(Fill)
+                .padding(BUTTON_PADDING)
+                .style(list_item_button_style(false, false))
+                .into()
+        }))
+        .spacing(SPACER_SIZE_SMALL)
+        .width(Fill)
+        .into()
+    };
+
+    let buttons = row![
+        Space::new().width(Fill),
+        button(shaped_text(t("button-close")).size(TEXT_SIZE))
+            .on_press(Message::CloseDoors)
+            .padding(BUTTON_PADDING),
+    ]
+    .spacing(ELEMENT_SPACING);
+
+    let mut form = column![panel_title(t("panel-doors"))];
+
+    // Errors (including a door that failed to start) show above the list
+    if let Some(error) = &state.error {
+        form = form.push(
+            shaped_text_wrapped(error)
+                .size(TEXT_SIZE)
+                .style(error_text_style),
+        );
+    }
+
+    let form = form
+        .push(content)
+        .push(Space::new().height(SPACER_SIZE_MEDIUM))
+        .push(buttons)
+        .spacing(ELEMENT_SPACING)
+        .padding(CONTENT_PADDING)
+        .max_width(CONTENT_MAX_WIDTH);
+
+    scrollable_panel(form)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_indexed_color_ranges() {
+        assert_eq!(indexed_color(1), Color::from_rgb8(205, 0, 0));
+        // Cube corners
+        assert_eq!(indexed_color(16), Color::from_rgb8(0, 0, 0));
+        assert_eq!(indexed_color(231), Color::from_rgb8(255, 255, 255));
+        assert_eq!(indexed_color(196), Color::from_rgb8(255, 0, 0));
+        // Grayscale ramp
+        assert_eq!(indexed_color(232), Color::from_rgb8(8, 8, 8));
+        assert_eq!(indexed_color(255), Color::from_rgb8(238, 238, 238));
+    }
+
+    #[test]
+    fn test_bold_basic_colors_are_bright() {
+        let default = Color::WHITE;
+        assert_eq!(
+            cell_color(vt100::Color::Idx(1), default, true),
+            indexed_color(9)
+        );
+        assert_eq!(
+            cell_color(vt100::Color::Idx(9), default, true),
+            indexed_color(9)
+        );
+        assert_eq!(cell_color(vt100::Color::Default, default, true), default);
+    }
+}
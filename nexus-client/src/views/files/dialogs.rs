@@ -1,21 +1,30 @@
-//! File operation dialogs (delete, overwrite, info, new directory, rename)
+//! File operation dialogs (delete, overwrite, download to, download selected, duplicate download, auto-download, info, new directory, rename, upload rules, upload preview)
 
 use iced::widget::button as btn;
 use iced::widget::text::Wrapping;
-use iced::widget::{Space, button, column, row, text_input};
+use iced::widget::{Space, button, checkbox, column, pick_list, row, text, text_input};
 use iced::{Center, Element, Fill};
-use nexus_common::protocol::FileInfoDetails;
+use nexus_common::protocol::{FileComment, FileInfoDetails, FileRating};
+use nexus_common::validators::MAX_DOWNLOAD_INCLUDE_COUNT;
 
 use super::super::layout::scrollable_panel;
+use super::FilePermissions;
 use super::helpers::{file_icon_for_extension, format_size, format_timestamp};
-use crate::i18n::t;
+use crate::config::settings::CollisionPolicy;
+use crate::i18n::{t, t_args};
 use crate::icon;
 use crate::style::{
-    BUTTON_PADDING, CONTENT_MAX_WIDTH, CONTENT_PADDING, ELEMENT_SPACING, FILE_INFO_ICON_SIZE,
-    FILE_INFO_ICON_SPACING, INPUT_PADDING, SPACER_SIZE_MEDIUM, SPACER_SIZE_SMALL, TEXT_SIZE,
-    TITLE_SIZE, error_text_style, panel_title, shaped_text, shaped_text_wrapped,
+    BUTTON_PADDING, CHECKBOX_INDENT, CONTENT_MAX_WIDTH, CONTENT_PADDING, ELEMENT_SPACING,
+    FILE_INFO_ICON_SIZE, FILE_INFO_ICON_SPACING, FILE_LIST_ICON_SIZE, FILE_LIST_ICON_SPACING,
+    ICON_BUTTON_PADDING, INPUT_PADDING, NO_SPACING, SPACER_SIZE_MEDIUM, SPACER_SIZE_SMALL,
+    TEXT_SIZE, TITLE_SIZE, error_text_style, muted_text_style, panel_title, shaped_text,
+    shaped_text_wrapped, transparent_icon_button_style,
+};
+use crate::types::{
+    FileReview, FilesManagementState, InputId, Message, PendingAutoDownload, PendingDownloadCheck,
+    PendingDownloadSelect, PendingDownloadTo, PendingUploadPreview, PendingUploadRule,
+    UploadFileStatus,
 };
-use crate::types::{FilesManagementState, InputId, Message};
 
 pub(super) fn delete_confirm_dialog<'a>(
     path: &str,
@@ -124,8 +133,336 @@ pub(super) fn overwrite_confirm_dialog<'a>(
     scrollable_panel(form)
 }
 
+/// Build the dialog asking whether to download a file that's already on disk
+pub(super) fn download_duplicate_dialog<'a>(
+    pending: &PendingDownloadCheck,
+) -> Element<'a, Message> {
+    let title = panel_title(t("files-download-duplicate-title"));
+
+    let display_name = FilesManagementState::display_name(pending.name());
+    let local_path = pending
+        .duplicate
+        .as_ref()
+        .map(|path| path.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let message = t_args(
+        "files-download-duplicate-message",
+        &[("name", &display_name), ("path", &local_path)],
+    );
+
+    let buttons = row![
+        Space::new().width(Fill),
+        button(shaped_text(t("button-skip")).size(TEXT_SIZE))
+            .on_press(Message::FileDownloadDuplicateSkip)
+            .padding(BUTTON_PADDING)
+            .style(btn::secondary),
+        button(shaped_text(t("button-download-anyway")).size(TEXT_SIZE))
+            .on_press(Message::FileDownloadDuplicateConfirm)
+            .padding(BUTTON_PADDING)
+            .style(btn::primary),
+    ]
+    .spacing(ELEMENT_SPACING);
+
+    let form = column![
+        title,
+        Space::new().height(SPACER_SIZE_MEDIUM),
+        shaped_text_wrapped(&message)
+            .size(TEXT_SIZE)
+            .width(Fill)
+            .align_x(Center),
+        Space::new().height(SPACER_SIZE_MEDIUM),
+        buttons,
+    ]
+    .spacing(ELEMENT_SPACING)
+    .padding(CONTENT_PADDING)
+    .max_width(CONTENT_MAX_WIDTH);
+
+    scrollable_panel(form)
+}
+
+/// Build the "Download To" dialog (destination folder picker)
+pub(super) fn download_to_dialog<'a>(pending: &PendingDownloadTo) -> Element<'a, Message> {
+    let name = pending
+        .remote_path
+        .trim_matches('/')
+        .rsplit('/')
+        .next()
+        .unwrap_or_default();
+    let display_name = FilesManagementState::display_name(name);
+    let title = panel_title(crate::i18n::t_args(
+        "files-download-to-title",
+        &[("name", &display_name)],
+    ));
+
+    let folder_row = row![
+        shaped_text(t("label-download-location")).size(TEXT_SIZE),
+        shaped_text_wrapped(pending.folder.clone())
+            .size(TEXT_SIZE)
+            .width(Fill),
+        button(shaped_text(t("button-browse")).size(TEXT_SIZE))
+            .on_press(Message::FileDownloadToBrowse)
+            .padding(BUTTON_PADDING)
+            .style(btn::secondary),
+    ]
+    .spacing(ELEMENT_SPACING)
+    .align_y(Center);
+
+    let collision_row = row![
+        shaped_text(t("label-collision-policy")).size(TEXT_SIZE),
+        pick_list(
+            CollisionPolicy::ALL,
+            Some(pending.collision_policy),
+            Message::FileDownloadToCollisionPolicySelected,
+        )
+        .text_size(TEXT_SIZE),
+    ]
+    .spacing(ELEMENT_SPACING)
+    .align_y(Center);
+
+    let buttons = row![
+        Space::new().width(Fill),
+        button(shaped_text(t("button-cancel")).size(TEXT_SIZE))
+            .on_press(Message::FileDownloadToCancel)
+            .padding(BUTTON_PADDING)
+            .style(btn::secondary),
+        button(shaped_text(t("context-menu-download")).size(TEXT_SIZE))
+            .on_press(Message::FileDownloadToConfirm)
+            .padding(BUTTON_PADDING),
+    ]
+    .spacing(ELEMENT_SPACING);
+
+    let mut form_items: Vec<Element<'_, Message>> = vec![
+        title.into(),
+        Space::new().height(SPACER_SIZE_MEDIUM).into(),
+        folder_row.into(),
+        collision_row.into(),
+    ];
+
+    // Only offer to remember the folder when there is a bookmark to store it on
+    if pending.can_remember {
+        form_items.push(
+            checkbox(pending.remember)
+                .label(t("label-remember-for-server"))
+                .on_toggle(Message::FileDownloadToRememberToggled)
+                .size(TEXT_SIZE)
+                .text_shaping(text::Shaping::Advanced)
+                .into(),
+        );
+    }
+
+    form_items.extend([
+        Space::new().height(SPACER_SIZE_MEDIUM).into(),
+        buttons.into(),
+    ]);
+
+    let form = iced::widget::Column::with_children(form_items)
+        .spacing(ELEMENT_SPACING)
+        .padding(CONTENT_PADDING)
+        .max_width(CONTENT_MAX_WIDTH);
+
+    scrollable_panel(form)
+}
+
+/// Build the "Download Selected" dialog (checkbox tree of a directory's files)
+pub(super) fn download_select_dialog<'a>(pending: &PendingDownloadSelect) -> Element<'a, Message> {
+    let name = pending
+        .remote_path
+        .trim_matches('/')
+        .rsplit('/')
+        .next()
+        .unwrap_or_default();
+    let display_name = FilesManagementState::display_name(name);
+    let title = panel_title(crate::i18n::t_args(
+        "files-download-to-title",
+        &[("name", &display_name)],
+    ));
+
+    let mut form_items: Vec<Element<'_, Message>> = vec![title.into()];
+
+    let Some(files) = &pending.files else {
+        // Still loading, or the manifest request failed
+        let status: Element<'_, Message> = match &pending.error {
+            Some(err) => shaped_text_wrapped(err.clone())
+                .size(TEXT_SIZE)
+                .width(Fill)
+                .align_x(Center)
+                .style(error_text_style)
+                .into(),
+            None => shaped_text(t("files-loading"))
+                .size(TEXT_SIZE)
+                .width(Fill)
+                .align_x(Center)
+                .into(),
+        };
+        form_items.extend([
+            Space::new().height(SPACER_SIZE_MEDIUM).into(),
+            status,
+            Space::new().height(SPACER_SIZE_MEDIUM).into(),
+            row![
+                Space::new().width(Fill),
+                button(shaped_text(t("button-cancel")).size(TEXT_SIZE))
+                    .on_press(Message::FileDownloadSelectCancel)
+                    .padding(BUTTON_PADDING)
+                    .style(btn::secondary),
+            ]
+            .into(),
+        ]);
+        let form = iced::widget::Column::with_children(form_items)
+            .spacing(ELEMENT_SPACING)
+            .padding(CONTENT_PADDING)
+            .max_width(CONTENT_MAX_WIDTH);
+        return scrollable_panel(form);
+    };
+
+    let all_selected = pending.all_selected();
+    let select_row = row![
+        checkbox(all_selected)
+            .label(t("label-select-all"))
+            .on_toggle(Message::FileDownloadSelectAll)
+            .size(TEXT_SIZE)
+            .text_shaping(text::Shaping::Advanced),
+        Space::new().width(Fill),
+        shaped_text(crate::i18n::t_args(
+            "files-download-select-summary",
+            &[
+                ("count", &pending.selected.len().to_string()),
+                ("total", &files.len().to_string()),
+                ("size", &format_size(pending.selected_size())),
+            ],
+        ))
+        .size(TEXT_SIZE),
+    ]
+    .spacing(ELEMENT_SPACING)
+    .align_y(Center);
+    form_items.extend([
+        Space::new().height(SPACER_SIZE_SMALL).into(),
+        select_row.into(),
+    ]);
+
+    // Files arrive sorted by path, so each folder's contents follow its first
+    // appearance; emit a folder row whenever the path enters a new folder.
+    let mut current_folders: Vec<&str> = Vec::new();
+    for file in files {
+        let parts: Vec<&str> = file.path.split('/').collect();
+        let (file_name, folders) = parts.split_last().expect("split yields at least one part");
+
+        let shared = current_folders
+            .iter()
+            .zip(folders.iter())
+            .take_while(|(a, b)| a == b)
+            .count();
+        current_folders.truncate(shared);
+
+        for (depth, folder) in folders.iter().enumerate().skip(shared) {
+            current_folders.push(*folder);
+            let prefix = folders[..=depth].join("/");
+            form_items.push(download_select_row(
+                depth,
+                icon::folder().size(FILE_LIST_ICON_SIZE).into(),
+                folder,
+                None,
+                pending.is_folder_selected(&prefix),
+                prefix,
+            ));
+        }
+
+        form_items.push(download_select_row(
+            folders.len(),
+            file_icon_for_extension(file_name)
+                .size(FILE_LIST_ICON_SIZE)
+                .into(),
+            file_name,
+            Some(file.size),
+            pending.selected.contains(&file.path),
+            file.path.clone(),
+        ));
+    }
+
+    let selected_count = pending.selected.len();
+    let too_many = !all_selected && selected_count > MAX_DOWNLOAD_INCLUDE_COUNT;
+    if too_many {
+        form_items.push(
+            shaped_text_wrapped(crate::i18n::t_args(
+                "files-download-select-too-many",
+                &[("max", &MAX_DOWNLOAD_INCLUDE_COUNT.to_string())],
+            ))
+            .size(TEXT_SIZE)
+            .width(Fill)
+            .align_x(Center)
+            .style(error_text_style)
+            .into(),
+        );
+    }
+
+    let download_button =
+        button(shaped_text(t("context-menu-download")).size(TEXT_SIZE)).padding(BUTTON_PADDING);
+    let download_button = if selected_count > 0 && !too_many {
+        download_button.on_press(Message::FileDownloadSelectConfirm)
+    } else {
+        download_button
+    };
+
+    form_items.extend([
+        Space::new().height(SPACER_SIZE_MEDIUM).into(),
+        row![
+            Space::new().width(Fill),
+            button(shaped_text(t("button-cancel")).size(TEXT_SIZE))
+                .on_press(Message::FileDownloadSelectCancel)
+                .padding(BUTTON_PADDING)
+                .style(btn::secondary),
+            download_button,
+        ]
+        .spacing(ELEMENT_SPACING)
+        .into(),
+    ]);
+
+    let form = iced::widget::Column::with_children(form_items)
+        .spacing(ELEMENT_SPACING)
+        .padding(CONTENT_PADDING)
+        .max_width(CONTENT_MAX_WIDTH);
+
+    scrollable_panel(form)
+}
+
+/// Build one row of the Download Selected tree (folder rows have no size)
+fn download_select_row<'a>(
+    depth: usize,
+    icon: Element<'a, Message>,
+    name: &str,
+    size: Option<u64>,
+    selected: bool,
+    path: String,
+) -> Element<'a, Message> {
+    let mut item = row![
+        Space::new().width(CHECKBOX_INDENT * depth as f32),
+        checkbox(selected)
+            .on_toggle(move |checked| Message::FileDownloadSelectToggled(path.clone(), checked))
+            .size(TEXT_SIZE),
+        icon,
+        shaped_text(FilesManagementState::display_name(name))
+            .size(TEXT_SIZE)
+            .width(Fill),
+    ]
+    .spacing(FILE_LIST_ICON_SPACING)
+    .align_y(Center);
+
+    if let Some(size) = size {
+        item = item.push(shaped_text(format_size(size)).size(TEXT_SIZE));
+    }
+
+    item.into()
+}
+
 /// Build the file info dialog
-pub(super) fn file_info_dialog(info: &FileInfoDetails) -> Element<'_, Message> {
+///
+/// Files also show their rating and comments (`review`), with stars and a
+/// comment input for users with the file_comment permission.
+pub(super) fn file_info_dialog<'a>(
+    info: &'a FileInfoDetails,
+    review: Option<&'a FileReview>,
+    perms: FilePermissions,
+    username: &str,
+) -> Element<'a, Message> {
     let mut content = column![].spacing(ELEMENT_SPACING);
 
     // Header: Icon + Name side by side (like user info)
@@ -214,6 +551,18 @@ pub(super) fn file_info_dialog(info: &FileInfoDetails) -> Element<'_, Message> {
         content = content.push(sha_row);
     }
 
+    if let Some(review) = review {
+        content = content.push(info_row(
+            t("files-info-rating"),
+            rating_summary(info.rating),
+        ));
+        if perms.file_comment {
+            content = content.push(rating_stars(info.my_rating));
+        }
+        content = content.push(Space::new().height(SPACER_SIZE_MEDIUM));
+        content = content.push(review_comments(info, review, perms, username));
+    }
+
     content = content.push(Space::new().height(SPACER_SIZE_MEDIUM));
 
     // Close button
@@ -234,6 +583,146 @@ pub(super) fn file_info_dialog(info: &FileInfoDetails) -> Element<'_, Message> {
     scrollable_panel(form)
 }
 
+/// Average rating and number of ratings (e.g. "4.5 (12 ratings)")
+fn rating_summary(rating: Option<FileRating>) -> String {
+    match rating {
+        Some(rating) if rating.count > 0 => t_args(
+            "files-info-rating-value",
+            &[
+                ("average", &format!("{:.1}", rating.average())),
+                ("count", &rating.count.to_string()),
+            ],
+        ),
+        _ => t("files-info-not-rated"),
+    }
+}
+
+/// The user's own rating as five clickable stars
+///
+/// Clicking the current rating again clears it (handled in the update).
+fn rating_stars<'a>(my_rating: Option<u8>) -> Element<'a, Message> {
+    let my_rating = my_rating.unwrap_or(0);
+    let mut stars = row![].spacing(NO_SPACING).align_y(Center);
+    for star in 1..=5u8 {
+        let symbol = if star <= my_rating { "★" } else { "☆" };
+        stars = stars.push(
+            button(shaped_text(symbol).size(TITLE_SIZE))
+                .on_press(Message::FileRate(star))
+                .padding(ICON_BUTTON_PADDING)
+                .style(transparent_icon_button_style),
+        );
+    }
+
+    row![
+        shaped_text(t("files-info-your-rating")).size(TEXT_SIZE),
+        Space::new().width(ELEMENT_SPACING),
+        stars,
+    ]
+    .align_y(Center)
+    .into()
+}
+
+/// Comment list, comment input, and any review error
+fn review_comments<'a>(
+    info: &FileInfoDetails,
+    review: &'a FileReview,
+    perms: FilePermissions,
+    username: &str,
+) -> Element<'a, Message> {
+    let count = info.comment_count.unwrap_or(0);
+    let mut section = column![
+        shaped_text(t_args(
+            "files-info-comments",
+            &[("count", &count.to_string())]
+        ))
+        .size(TEXT_SIZE)
+    ]
+    .spacing(ELEMENT_SPACING);
+
+    match &review.comments {
+        None => {
+            section = section.push(
+                shaped_text(t("files-comments-loading"))
+                    .size(TEXT_SIZE)
+                    .style(muted_text_style),
+            );
+        }
+        Some(comments) if comments.is_empty() => {
+            section = section.push(
+                shaped_text(t("files-comments-empty"))
+                    .size(TEXT_SIZE)
+                    .style(muted_text_style),
+            );
+        }
+        Some(comments) => {
+            for comment in comments {
+                // Authors delete their own comments; file_delete moderates
+                let can_delete =
+                    perms.file_delete || comment.author.to_lowercase() == username.to_lowercase();
+                section = section.push(comment_item(comment, can_delete));
+            }
+        }
+    }
+
+    if perms.file_comment {
+        let can_post = !review.comment_input.trim().is_empty();
+        let comment_input = text_input(&t("files-comment-placeholder"), &review.comment_input)
+            .on_input(Message::FileCommentInputChanged)
+            .on_submit(Message::FileCommentSubmit)
+            .padding(INPUT_PADDING)
+            .size(TEXT_SIZE);
+        let post_button = if can_post {
+            button(shaped_text(t("button-post-comment")).size(TEXT_SIZE))
+                .on_press(Message::FileCommentSubmit)
+                .padding(BUTTON_PADDING)
+        } else {
+            button(shaped_text(t("button-post-comment")).size(TEXT_SIZE)).padding(BUTTON_PADDING)
+        };
+
+        section = section.push(
+            row![comment_input, post_button]
+                .spacing(ELEMENT_SPACING)
+                .align_y(Center),
+        );
+    }
+
+    if let Some(error) = &review.error {
+        section = section.push(
+            shaped_text_wrapped(error)
+                .size(TEXT_SIZE)
+                .style(error_text_style),
+        );
+    }
+
+    section.into()
+}
+
+/// A single comment: author, time, and body
+fn comment_item<'a>(comment: &'a FileComment, can_delete: bool) -> Element<'a, Message> {
+    let mut header = row![
+        shaped_text(&comment.author).size(TEXT_SIZE),
+        shaped_text(format_timestamp(comment.created_at))
+            .size(TEXT_SIZE)
+            .style(muted_text_style),
+        Space::new().width(Fill),
+    ]
+    .spacing(ELEMENT_SPACING)
+    .align_y(Center);
+
+    if can_delete {
+        header = header.push(
+            button(icon::trash().size(TEXT_SIZE))
+                .on_press(Message::FileCommentDelete(comment.id))
+                .padding(ICON_BUTTON_PADDING)
+                .style(transparent_icon_button_style),
+        );
+    }
+
+    column![header, shaped_text_wrapped(&comment.body).size(TEXT_SIZE)]
+        .spacing(NO_SPACING)
+        .into()
+}
+
 /// Build a single info row with label and value (matches user info style)
 fn info_row<'a>(label: String, value: String) -> iced::widget::Row<'a, Message> {
     row![
@@ -371,3 +860,311 @@ pub(super) fn rename_dialog<'a>(
 
     scrollable_panel(form)
 }
+
+/// Upload Rules dialog for a directory (admin only)
+///
+/// Empty inputs mean no limit; a rule that limits nothing is removed.
+pub(super) fn upload_rule_dialog<'a>(pending: &PendingUploadRule) -> Element<'a, Message> {
+    let name = pending
+        .path
+        .trim_matches('/')
+        .rsplit('/')
+        .next()
+        .filter(|name| !name.is_empty())
+        .map(FilesManagementState::display_name)
+        .unwrap_or_else(|| "/".to_string());
+    let title = panel_title(t_args("files-upload-rule-title", &[("name", &name)]));
+
+    let input = |placeholder: &str, value: &str| {
+        text_input(placeholder, value)
+            .on_submit(Message::FileUploadRuleSave)
+            .padding(INPUT_PADDING)
+            .size(TEXT_SIZE)
+    };
+    let (extensions, max_file_size, max_total_size) = if pending.loading {
+        (
+            input(&t("files-upload-rule-loading"), ""),
+            input("", ""),
+            input("", ""),
+        )
+    } else {
+        (
+            input(
+                &t("files-upload-rule-extensions-placeholder"),
+                &pending.extensions,
+            )
+            .on_input(Message::FileUploadRuleExtensionsChanged),
+            input(&t("files-upload-rule-no-limit"), &pending.max_file_size)
+                .on_input(Message::FileUploadRuleMaxFileSizeChanged),
+            input(&t("files-upload-rule-no-limit"), &pending.max_total_size)
+                .on_input(Message::FileUploadRuleMaxTotalSizeChanged),
+        )
+    };
+
+    let buttons = row![
+        Space::new().width(Fill),
+        button(shaped_text(t("button-cancel")).size(TEXT_SIZE))
+            .on_press(Message::FileUploadRuleCancel)
+            .padding(BUTTON_PADDING)
+            .style(btn::secondary),
+        if pending.loading {
+            button(shaped_text(t("files-upload-rule-remove")).size(TEXT_SIZE))
+                .padding(BUTTON_PADDING)
+                .style(btn::danger)
+        } else {
+            button(shaped_text(t("files-upload-rule-remove")).size(TEXT_SIZE))
+                .on_press(Message::FileUploadRuleRemove)
+                .padding(BUTTON_PADDING)
+                .style(btn::danger)
+        },
+        if pending.loading {
+            button(shaped_text(t("button-save")).size(TEXT_SIZE)).padding(BUTTON_PADDING)
+        } else {
+            button(shaped_text(t("button-save")).size(TEXT_SIZE))
+                .on_press(Message::FileUploadRuleSave)
+                .padding(BUTTON_PADDING)
+        },
+    ]
+    .spacing(ELEMENT_SPACING);
+
+    let mut form_items: Vec<Element<'_, Message>> = vec![title.into()];
+
+    // Show error if present
+    if let Some(err) = &pending.error {
+        form_items.push(
+            shaped_text_wrapped(err.clone())
+                .size(TEXT_SIZE)
+                .width(Fill)
+                .align_x(Center)
+                .style(error_text_style)
+                .into(),
+        );
+        form_items.push(Space::new().height(SPACER_SIZE_SMALL).into());
+    } else {
+        form_items.push(Space::new().height(SPACER_SIZE_MEDIUM).into());
+    }
+
+    form_items.extend([
+        shaped_text(t("label-upload-rule-extensions"))
+            .size(TEXT_SIZE)
+            .into(),
+        extensions.id(InputId::UploadRuleExtensions).into(),
+        shaped_text(t("label-upload-rule-max-file-size"))
+            .size(TEXT_SIZE)
+            .into(),
+        max_file_size.into(),
+        shaped_text(t("label-upload-rule-max-total-size"))
+            .size(TEXT_SIZE)
+            .into(),
+        max_total_size.into(),
+        shaped_text_wrapped(t("files-upload-rule-help"))
+            .size(TEXT_SIZE)
+            .style(muted_text_style)
+            .into(),
+        Space::new().height(SPACER_SIZE_MEDIUM).into(),
+        buttons.into(),
+    ]);
+
+    let form = iced::widget::Column::with_children(form_items)
+        .spacing(ELEMENT_SPACING)
+        .padding(CONTENT_PADDING)
+        .max_width(CONTENT_MAX_WIDTH);
+
+    scrollable_panel(form)
+}
+
+/// Auto-Download dialog for a directory (bookmarked connections only)
+pub(super) fn auto_download_dialog<'a>(pending: &PendingAutoDownload) -> Element<'a, Message> {
+    let name = pending
+        .path
+        .trim_matches('/')
+        .rsplit('/')
+        .next()
+        .filter(|name| !name.is_empty())
+        .map(FilesManagementState::display_name)
+        .unwrap_or_else(|| "/".to_string());
+    let title = panel_title(t_args("files-auto-download-title", &[("name", &name)]));
+
+    let pattern = text_input(
+        &t("files-auto-download-pattern-placeholder"),
+        &pending.pattern,
+    )
+    .on_input(Message::FileAutoDownloadPatternChanged)
+    .on_submit(Message::FileAutoDownloadSave)
+    .id(InputId::AutoDownloadPattern)
+    .padding(INPUT_PADDING)
+    .size(TEXT_SIZE);
+
+    let mut buttons = row![
+        Space::new().width(Fill),
+        button(shaped_text(t("button-cancel")).size(TEXT_SIZE))
+            .on_press(Message::FileAutoDownloadCancel)
+            .padding(BUTTON_PADDING)
+            .style(btn::secondary),
+    ]
+    .spacing(ELEMENT_SPACING);
+    if pending.existing {
+        buttons = buttons.push(
+            button(shaped_text(t("files-auto-download-remove")).size(TEXT_SIZE))
+                .on_press(Message::FileAutoDownloadRemove)
+                .padding(BUTTON_PADDING)
+                .style(btn::danger),
+        );
+    }
+    buttons = buttons.push(
+        button(shaped_text(t("button-save")).size(TEXT_SIZE))
+            .on_press(Message::FileAutoDownloadSave)
+            .padding(BUTTON_PADDING),
+    );
+
+    let mut form_items: Vec<Element<'_, Message>> = vec![title.into()];
+
+    // Show error if present
+    if let Some(err) = &pending.error {
+        form_items.push(
+            shaped_text_wrapped(err.clone())
+                .size(TEXT_SIZE)
+                .width(Fill)
+                .align_x(Center)
+                .style(error_text_style)
+                .into(),
+        );
+        form_items.push(Space::new().height(SPACER_SIZE_SMALL).into());
+    } else {
+        form_items.push(Space::new().height(SPACER_SIZE_MEDIUM).into());
+    }
+
+    form_items.extend([
+        shaped_text(t("label-auto-download-pattern"))
+            .size(TEXT_SIZE)
+            .into(),
+        pattern.into(),
+        shaped_text_wrapped(t("files-auto-download-help"))
+            .size(TEXT_SIZE)
+            .style(muted_text_style)
+            .into(),
+        Space::new().height(SPACER_SIZE_MEDIUM).into(),
+        buttons.into(),
+    ]);
+
+    let form = iced::widget::Column::with_children(form_items)
+        .spacing(ELEMENT_SPACING)
+        .padding(CONTENT_PADDING)
+        .max_width(CONTENT_MAX_WIDTH);
+
+    scrollable_panel(form)
+}
+
+/// Build the upload preview dialog (files that already exist at the destination)
+///
+/// Shows a comparing state while local files are hashed, then lists the
+/// files that differ from the server's copies. Files the server already has
+/// are always skipped; Overwrite needs the file_delete permission.
+pub(super) fn upload_preview_dialog<'a>(
+    pending: &PendingUploadPreview,
+    has_file_delete: bool,
+) -> Element<'a, Message> {
+    let title = panel_title(t("files-upload-preview-title"));
+    let cancel_button = button(shaped_text(t("button-cancel")).size(TEXT_SIZE))
+        .on_press(Message::FileUploadPreviewCancel)
+        .padding(BUTTON_PADDING)
+        .style(btn::secondary);
+
+    let mut form_items: Vec<Element<'_, Message>> =
+        vec![title.into(), Space::new().height(SPACER_SIZE_MEDIUM).into()];
+
+    if pending.statuses.is_none() {
+        form_items.extend([
+            shaped_text(t("files-upload-preview-comparing"))
+                .size(TEXT_SIZE)
+                .width(Fill)
+                .align_x(Center)
+                .into(),
+            Space::new().height(SPACER_SIZE_MEDIUM).into(),
+            row![Space::new().width(Fill), cancel_button].into(),
+        ]);
+        let form = iced::widget::Column::with_children(form_items)
+            .spacing(ELEMENT_SPACING)
+            .padding(CONTENT_PADDING)
+            .max_width(CONTENT_MAX_WIDTH);
+        return scrollable_panel(form);
+    }
+
+    let conflicts = pending.count(UploadFileStatus::Conflict);
+    let blocked = pending.count(UploadFileStatus::Blocked);
+    form_items.push(
+        shaped_text_wrapped(t_args(
+            "files-upload-preview-summary",
+            &[
+                ("new", &pending.count(UploadFileStatus::New).to_string()),
+                (
+                    "identical",
+                    &pending.count(UploadFileStatus::Identical).to_string(),
+                ),
+                ("conflicts", &conflicts.to_string()),
+            ],
+        ))
+        .size(TEXT_SIZE)
+        .width(Fill)
+        .align_x(Center)
+        .into(),
+    );
+
+    for path in pending.paths(UploadFileStatus::Conflict) {
+        let name = path.rsplit('/').next().unwrap_or(path);
+        form_items.push(
+            row![
+                file_icon_for_extension(name).size(FILE_LIST_ICON_SIZE),
+                shaped_text(path.to_string()).size(TEXT_SIZE).width(Fill),
+            ]
+            .spacing(FILE_LIST_ICON_SPACING)
+            .align_y(Center)
+            .into(),
+        );
+    }
+
+    if blocked > 0 {
+        form_items.push(
+            shaped_text_wrapped(t_args(
+                "files-upload-preview-blocked",
+                &[("count", &blocked.to_string())],
+            ))
+            .size(TEXT_SIZE)
+            .width(Fill)
+            .align_x(Center)
+            .style(error_text_style)
+            .into(),
+        );
+    }
+
+    let mut buttons = row![
+        Space::new().width(Fill),
+        cancel_button,
+        button(shaped_text(t("button-skip-existing")).size(TEXT_SIZE))
+            .on_press(Message::FileUploadPreviewConfirm(false))
+            .padding(BUTTON_PADDING),
+    ]
+    .spacing(ELEMENT_SPACING);
+
+    // Replacing files is as destructive as deleting them
+    if has_file_delete && conflicts > 0 {
+        buttons = buttons.push(
+            button(shaped_text(t("button-overwrite")).size(TEXT_SIZE))
+                .on_press(Message::FileUploadPreviewConfirm(true))
+                .padding(BUTTON_PADDING)
+                .style(btn::danger),
+        );
+    }
+
+    form_items.extend([
+        Space::new().height(SPACER_SIZE_MEDIUM).into(),
+        buttons.into(),
+    ]);
+
+    let form = iced::widget::Column::with_children(form_items)
+        .spacing(ELEMENT_SPACING)
+        .padding(CONTENT_PADDING)
+        .max_width(CONTENT_MAX_WIDTH);
+
+    scrollable_panel(form)
+}
@@ -0,0 +1,240 @@
+//! Highlights view (new uploads this week and most downloaded files)
+
+use iced::widget::text::Wrapping;
+use iced::widget::{Column, Space, button, column, container, row, table, tooltip};
+use iced::{Center, Element, Fill, Right};
+use nexus_common::protocol::FileActivity;
+
+use super::FilePermissions;
+use super::helpers::{file_icon_for_extension, format_size, format_timestamp};
+use super::search::parent_path;
+use crate::i18n::{t, t_args};
+use crate::icon;
+use crate::style::{
+    FILE_DATE_COLUMN_WIDTH, FILE_LIST_ICON_SIZE, FILE_LIST_ICON_SPACING, FILE_SIZE_COLUMN_WIDTH,
+    FILE_TOOLBAR_BUTTON_PADDING, FILE_TOOLBAR_ICON_SIZE, NO_SPACING, SEPARATOR_HEIGHT,
+    SPACER_SIZE_MEDIUM, SPACER_SIZE_SMALL, SUBHEADING_SIZE, TEXT_SIZE, TOOLTIP_BACKGROUND_PADDING,
+    TOOLTIP_GAP, TOOLTIP_PADDING, TOOLTIP_TEXT_SIZE, error_text_style, muted_text_style,
+    shaped_text, shaped_text_wrapped, tooltip_container_style, transparent_icon_button_style,
+};
+use crate::types::{FileHighlights, Message};
+
+/// Which highlights listing a table shows
+#[derive(Clone, Copy)]
+enum Listing {
+    Recent,
+    Popular,
+}
+
+/// Build the highlights breadcrumb (shows "Highlights")
+pub(super) fn highlights_breadcrumb<'a>() -> Element<'a, Message> {
+    container(
+        shaped_text(t("files-highlights"))
+            .size(TEXT_SIZE)
+            .style(muted_text_style),
+    )
+    .padding([SPACER_SIZE_SMALL, NO_SPACING])
+    .into()
+}
+
+/// Build the link that switches a tab to its highlights
+pub(super) fn highlights_link<'a>() -> Element<'a, Message> {
+    container(
+        button(
+            shaped_text(t("files-highlights"))
+                .size(TEXT_SIZE)
+                .style(muted_text_style)
+                .wrapping(Wrapping::None),
+        )
+        .padding(NO_SPACING)
+        .style(transparent_icon_button_style)
+        .on_press(Message::FileShowHighlights),
+    )
+    .padding([SPACER_SIZE_SMALL, NO_SPACING])
+    .into()
+}
+
+/// Build the highlights content (both listings, or loading/error state)
+pub(super) fn highlights_content<'a>(
+    highlights: &FileHighlights,
+    perms: FilePermissions,
+) -> Element<'a, Message> {
+    if let Some(error) = &highlights.error {
+        return container(
+            shaped_text_wrapped(error.clone())
+                .size(TEXT_SIZE)
+                .style(error_text_style),
+        )
+        .width(Fill)
+        .center_x(Fill)
+        .padding(SPACER_SIZE_SMALL)
+        .into();
+    }
+
+    let (Some(recent), Some(popular)) = (&highlights.recent, &highlights.popular) else {
+        return container(
+            shaped_text(t("files-loading"))
+                .size(TEXT_SIZE)
+                .style(muted_text_style),
+        )
+        .width(Fill)
+        .center_x(Fill)
+        .padding(SPACER_SIZE_SMALL)
+        .into();
+    };
+
+    column![
+        section(t("files-highlights-recent"), Listing::Recent, recent, perms),
+        Space::new().height(SPACER_SIZE_MEDIUM),
+        section(
+            t("files-highlights-popular"),
+            Listing::Popular,
+            popular,
+            perms
+        ),
+    ]
+    .width(Fill)
+    .into()
+}
+
+/// One listing with its heading
+fn section<'a>(
+    title: String,
+    listing: Listing,
+    files: &[FileActivity],
+    perms: FilePermissions,
+) -> Element<'a, Message> {
+    let heading = shaped_text(title).size(SUBHEADING_SIZE);
+
+    let body: Element<'a, Message> = if files.is_empty() {
+        shaped_text(t("files-highlights-empty"))
+            .size(TEXT_SIZE)
+            .style(muted_text_style)
+            .into()
+    } else {
+        files_table(listing, files, perms)
+    };
+
+    Column::with_children([heading.into(), body])
+        .spacing(SPACER_SIZE_SMALL)
+        .padding([NO_SPACING, SPACER_SIZE_SMALL])
+        .width(Fill)
+        .into()
+}
+
+/// Table of highlighted files
+///
+/// Clicking a name opens the file's folder in a new tab.
+fn files_table<'a>(
+    listing: Listing,
+    files: &[FileActivity],
+    perms: FilePermissions,
+) -> Element<'a, Message> {
+    let name_column = table::column(
+        Space::new(),
+        |file: FileActivity| -> Element<'static, Message> {
+            let name_content = row![
+                file_icon_for_extension(&file.name).size(FILE_LIST_ICON_SIZE),
+                Space::new().width(FILE_LIST_ICON_SPACING),
+                shaped_text(file.name.clone())
+                    .size(TEXT_SIZE)
+                    .wrapping(Wrapping::WordOrGlyph),
+            ]
+            .align_y(Center);
+
+            button(name_content)
+                .padding(NO_SPACING)
+                .style(transparent_icon_button_style)
+                .on_press(Message::FileHighlightOpen(file))
+                .into()
+        },
+    )
+    .width(Fill);
+
+    // Path column - shows parent directory
+    let path_column = table::column(
+        Space::new(),
+        |file: FileActivity| -> Element<'static, Message> {
+            let display_path = parent_path(&file.path);
+            let display = if display_path.is_empty() {
+                "/".to_string()
+            } else {
+                display_path
+            };
+
+            shaped_text(display)
+                .size(TEXT_SIZE)
+                .style(muted_text_style)
+                .wrapping(Wrapping::WordOrGlyph)
+                .into()
+        },
+    )
+    .width(FILE_SIZE_COLUMN_WIDTH * 2.0);
+
+    // Detail column - upload date for new files, download count for popular ones
+    let detail_column = table::column(
+        Space::new(),
+        move |file: FileActivity| -> Element<'static, Message> {
+            let detail = match listing {
+                Listing::Recent => format_timestamp(file.uploaded_at.unwrap_or(0)),
+                Listing::Popular => t_args(
+                    "files-highlights-downloads",
+                    &[("count", &file.downloads.to_string())],
+                ),
+            };
+            shaped_text(detail)
+                .size(TEXT_SIZE)
+                .style(muted_text_style)
+                .into()
+        },
+    )
+    .width(FILE_DATE_COLUMN_WIDTH)
+    .align_x(Right);
+
+    let size_column = table::column(
+        Space::new(),
+        |file: FileActivity| -> Element<'static, Message> {
+            shaped_text(format_size(file.size))
+                .size(TEXT_SIZE)
+                .style(muted_text_style)
+                .into()
+        },
+    )
+    .width(FILE_SIZE_COLUMN_WIDTH)
+    .align_x(Right);
+
+    let mut columns = vec![name_column, path_column, detail_column, size_column];
+
+    // Download button (if permission)
+    if perms.file_download {
+        columns.push(
+            table::column(
+                Space::new(),
+                |file: FileActivity| -> Element<'static, Message> {
+                    tooltip(
+                        button(icon::download().size(FILE_TOOLBAR_ICON_SIZE))
+                            .padding(FILE_TOOLBAR_BUTTON_PADDING)
+                            .style(transparent_icon_button_style)
+                            .on_press(Message::FileHighlightDownload(file)),
+                        container(shaped_text(t("context-menu-download")).size(TOOLTIP_TEXT_SIZE))
+                            .padding(TOOLTIP_BACKGROUND_PADDING)
+                            .style(tooltip_container_style),
+                        tooltip::Position::Left,
+                    )
+                    .gap(TOOLTIP_GAP)
+                    .padding(TOOLTIP_PADDING)
+                    .into()
+                },
+            )
+            .align_x(Right),
+        );
+    }
+
+    table(columns, files.to_vec())
+        .width(Fill)
+        .padding_x(SPACER_SIZE_SMALL)
+        .padding_y(SPACER_SIZE_SMALL)
+        .separator_x(NO_SPACING)
+        .separator_y(SEPARATOR_HEIGHT)
+        .into()
+}
@@ -119,7 +119,8 @@ pub(super) fn lazy_file_table(deps: FileTableDeps) -> Element<'static, Message>
                 || row.perms.file_move
                 || row.perms.file_copy
                 || row.perms.file_download
-                || row.perms.file_upload;
+                || row.perms.file_upload
+                || row.perms.upload_rules;
 
             if has_any_permission {
                 LazyContextMenu::new(row_element, move || {
@@ -130,6 +131,8 @@ pub(super) fn lazy_file_table(deps: FileTableDeps) -> Element<'static, Message>
                         row.entry.can_upload,
                         row.perms,
                         row.has_clipboard,
+                        row.can_watch,
+                        row.is_watched,
                     )
                 })
                 .into()
@@ -240,6 +243,7 @@ pub(super) fn lazy_file_table(deps: FileTableDeps) -> Element<'static, Message>
 }
 
 /// Build context menu for lazy file table (takes owned data)
+#[allow(clippy::too_many_arguments)]
 fn build_lazy_context_menu(
     entry_path: &str,
     entry_name: &str,
@@ -247,6 +251,8 @@ fn build_lazy_context_menu(
     can_upload: bool,
     perms: FilePermissions,
     has_clipboard: bool,
+    can_watch: bool,
+    is_watched: bool,
 ) -> Element<'static, Message> {
     let mut menu_items: Vec<Element<'_, Message>> = vec![];
     let mut has_clipboard_section = false;
@@ -266,6 +272,36 @@ fn build_lazy_context_menu(
                 .on_press(download_message)
                 .into(),
         );
+        menu_items.push(
+            MenuButton::new(shaped_text(t("context-menu-download-to")).size(TEXT_SIZE))
+                .padding(CONTEXT_MENU_ITEM_PADDING)
+                .width(Fill)
+                .style(menu_button_style)
+                .on_press(Message::FileDownloadTo(entry_path.to_string(), is_dir))
+                .into(),
+        );
+        if is_dir {
+            menu_items.push(
+                MenuButton::new(shaped_text(t("context-menu-download-select")).size(TEXT_SIZE))
+                    .padding(CONTEXT_MENU_ITEM_PADDING)
+                    .width(Fill)
+                    .style(menu_button_style)
+                    .on_press(Message::FileDownloadSelect(entry_path.to_string()))
+                    .into(),
+            );
+
+            // Download new files automatically (bookmarked connections only)
+            if can_watch {
+                menu_items.push(
+                    MenuButton::new(shaped_text(t("context-menu-auto-download")).size(TEXT_SIZE))
+                        .padding(CONTEXT_MENU_ITEM_PADDING)
+                        .width(Fill)
+                        .style(menu_button_style)
+                        .on_press(Message::FileAutoDownload(entry_path.to_string()))
+                        .into(),
+                );
+            }
+        }
     }
 
     // Upload
@@ -278,6 +314,37 @@ fn build_lazy_context_menu(
                 .on_press(Message::FileUpload(entry_path.to_string()))
                 .into(),
         );
+
+        // Watch a local drop folder (bookmarked connections only)
+        if is_watched {
+            menu_items.push(
+                MenuButton::new(shaped_text(t("context-menu-unwatch-folder")).size(TEXT_SIZE))
+                    .padding(CONTEXT_MENU_ITEM_PADDING)
+                    .width(Fill)
+                    .style(menu_button_style)
+                    .on_press(Message::FileUnwatchFolder)
+                    .into(),
+            );
+        } else if can_watch {
+            menu_items.push(
+                MenuButton::new(shaped_text(t("context-menu-watch-folder")).size(TEXT_SIZE))
+                    .padding(CONTEXT_MENU_ITEM_PADDING)
+                    .width(Fill)
+                    .style(menu_button_style)
+                    .on_press(Message::FileWatchFolder(entry_path.to_string()))
+                    .into(),
+            );
+        }
+
+        // Use as the destination for /screenshot shares
+        menu_items.push(
+            MenuButton::new(shaped_text(t("context-menu-screenshot-folder")).size(TEXT_SIZE))
+                .padding(CONTEXT_MENU_ITEM_PADDING)
+                .width(Fill)
+                .style(menu_button_style)
+                .on_press(Message::FileScreenshotFolder(entry_path.to_string()))
+                .into(),
+        );
     }
 
     // Share (always available - no special permission needed)
@@ -348,7 +415,9 @@ fn build_lazy_context_menu(
     }
 
     // Normal actions separator
-    if has_clipboard_section && (perms.file_info || perms.file_rename) {
+    if has_clipboard_section
+        && (perms.file_info || perms.file_rename || (is_dir && perms.upload_rules))
+    {
         menu_items.push(
             container(Space::new())
                 .width(Fill)
@@ -382,6 +451,18 @@ fn build_lazy_context_menu(
         );
     }
 
+    // Upload Rules (admin only, directories only)
+    if is_dir && perms.upload_rules {
+        menu_items.push(
+            MenuButton::new(shaped_text(t("context-menu-upload-rules")).size(TEXT_SIZE))
+                .padding(CONTEXT_MENU_ITEM_PADDING)
+                .width(Fill)
+                .style(menu_button_style)
+                .on_press(Message::FileUploadRuleClicked(entry_name.to_string()))
+                .into(),
+        );
+    }
+
     // Delete separator (Share is always present, so there's always content before Delete)
     if perms.file_delete {
         menu_items.push(
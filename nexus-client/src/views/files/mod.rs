@@ -4,12 +4,14 @@
 //! - `helpers` — Icons, formatting, breadcrumb parsing
 //! - `toolbar` — Toolbar buttons, breadcrumb bar, search input
 //! - `search` — Search results table and context menu
-//! - `dialogs` — Delete, overwrite, info, new directory, rename dialogs
+//! - `highlights` — New uploads and most downloaded files
+//! - `dialogs` — Delete, overwrite, info, new directory, rename, upload rules dialogs
 //! - `listing` — File listing table and context menu
 //! - `tabs` — Tab bar for multi-tab file browsing
 
 mod dialogs;
 mod helpers;
+mod highlights;
 mod listing;
 mod search;
 mod tabs;
@@ -20,10 +22,12 @@ use std::hash::Hash;
 pub use helpers::build_navigate_path;
 
 use dialogs::{
-    delete_confirm_dialog, file_info_dialog, new_directory_dialog, overwrite_confirm_dialog,
-    rename_dialog,
+    auto_download_dialog, delete_confirm_dialog, download_duplicate_dialog, download_select_dialog,
+    download_to_dialog, file_info_dialog, new_directory_dialog, overwrite_confirm_dialog,
+    rename_dialog, upload_preview_dialog, upload_rule_dialog,
 };
-use helpers::build_navigate_path as build_path;
+use helpers::{build_navigate_path as build_path, format_size};
+use highlights::{highlights_breadcrumb, highlights_content, highlights_link};
 use listing::lazy_file_table;
 use search::lazy_search_results_table;
 use tabs::build_file_tab_bar;
@@ -31,9 +35,9 @@ use toolbar::{breadcrumb_bar, search_breadcrumb, search_input_row, toolbar};
 
 use iced::widget::{Space, button, column, container, row, scrollable, stack, tooltip};
 use iced::{Center, Element, Fill, alignment};
-use nexus_common::protocol::{FileEntry, FileSearchResult};
+use nexus_common::protocol::{FileEntry, FileSearchResult, UploadRule};
 
-use crate::i18n::t;
+use crate::i18n::{t, t_args};
 use crate::icon;
 use crate::style::{
     CONTENT_MAX_WIDTH, CONTENT_PADDING, DROP_OVERLAY_ICON_SIZE, ICON_BUTTON_PADDING, NO_SPACING,
@@ -44,7 +48,7 @@ use crate::style::{
     transparent_icon_button_style,
 };
 use crate::types::{
-    ClipboardOperation, FileSortColumn, FilesManagementState, Message, ScrollableId,
+    ClipboardOperation, FileSortColumn, FilesManagementState, Message, ScrollableId, UploadWatch,
 };
 
 /// File permission flags for view rendering
@@ -60,6 +64,9 @@ pub struct FilePermissions {
     pub file_download: bool,
     pub file_upload: bool,
     pub file_search: bool,
+    pub file_comment: bool,
+    /// Admin only: edit a directory's upload rule
+    pub upload_rules: bool,
 }
 
 /// State needed to render the files toolbar
@@ -96,6 +103,10 @@ struct FileRowData {
     perms: FilePermissions,
     /// Whether clipboard has content (for paste option)
     has_clipboard: bool,
+    /// Whether folders can be watched for auto-upload or auto-download (bookmarked connection)
+    can_watch: bool,
+    /// Whether this entry is the bookmark's watched upload destination
+    is_watched: bool,
 }
 
 impl Hash for FileRowData {
@@ -109,6 +120,8 @@ impl Hash for FileRowData {
         self.is_cut.hash(state);
         self.perms.hash(state);
         self.has_clipboard.hash(state);
+        self.can_watch.hash(state);
+        self.is_watched.hash(state);
     }
 }
 
@@ -152,11 +165,18 @@ struct SearchResultsDeps {
 /// * `files_management` - Current files panel state
 /// * `perms` - File permission flags for the current user
 /// * `show_hidden` - Whether to show hidden files (from config)
+/// * `show_drop_overlay` - Whether to show the drag-and-drop overlay
+/// * `can_watch` - Whether folders can be watched for auto-upload or auto-download
+/// * `upload_watch` - The bookmark's current upload watch, if any
+/// * `username` - Current user's username (for deleting own comments)
 pub fn files_view<'a>(
     files_management: &'a FilesManagementState,
     perms: FilePermissions,
     show_hidden: bool,
     show_drop_overlay: bool,
+    can_watch: bool,
+    upload_watch: Option<&UploadWatch>,
+    username: &str,
 ) -> Element<'a, Message> {
     let tab = files_management.active_tab();
 
@@ -165,14 +185,46 @@ pub fn files_view<'a>(
         return overwrite_confirm_dialog(&pending.name, perms.file_delete);
     }
 
+    // If the Download To dialog is pending, show that
+    if let Some(pending) = &tab.pending_download_to {
+        return download_to_dialog(pending);
+    }
+
+    // If a download is already on disk, ask whether to fetch it again
+    if let Some(pending) = tab.duplicate_download() {
+        return download_duplicate_dialog(pending);
+    }
+
+    // If an upload needs a decision about existing files, show that
+    if let Some(pending) = &tab.pending_upload_preview
+        && pending.is_visible()
+    {
+        return upload_preview_dialog(pending, perms.file_delete);
+    }
+
+    // If the Download Selected dialog is pending, show that
+    if let Some(pending) = &tab.pending_download_select {
+        return download_select_dialog(pending);
+    }
+
     // If rename dialog is pending, show that
     if let Some(path) = &tab.pending_rename {
         return rename_dialog(path, &tab.rename_name, tab.rename_error.as_ref());
     }
 
+    // If the Upload Rules dialog is pending, show that
+    if let Some(pending) = &tab.pending_upload_rule {
+        return upload_rule_dialog(pending);
+    }
+
+    // If the Auto-Download dialog is pending, show that
+    if let Some(pending) = &tab.pending_auto_download {
+        return auto_download_dialog(pending);
+    }
+
     // If file info is pending, show that dialog
     if let Some(info) = &tab.pending_info {
-        return file_info_dialog(info);
+        return file_info_dialog(info, tab.review.as_ref(), perms, username);
     }
 
     // If delete confirmation is pending, show that dialog
@@ -234,11 +286,19 @@ pub fn files_view<'a>(
     // Check if in search mode
     let is_searching = tab.is_searching();
 
-    // Breadcrumb navigation (or search breadcrumb when searching)
+    // Breadcrumb navigation (or search/highlights breadcrumb instead of a path)
+    // While browsing, a link to the highlights sits at the right
     let breadcrumbs: Element<'_, Message> = if let Some(query) = &tab.search_query {
         search_breadcrumb(query)
+    } else if tab.is_showing_highlights() {
+        highlights_breadcrumb()
     } else {
-        breadcrumb_bar(&tab.current_path, viewing_root)
+        row![
+            container(breadcrumb_bar(&tab.current_path, viewing_root)).width(Fill),
+            highlights_link(),
+        ]
+        .spacing(SPACER_SIZE_SMALL)
+        .into()
     };
 
     // Determine if user can create directories here
@@ -264,7 +324,8 @@ pub fn files_view<'a>(
         can_upload: tab.current_dir_can_upload,
         current_path: &tab.current_path,
         is_loading,
-        is_searching,
+        // Highlights have no current directory either
+        is_searching: is_searching || tab.is_showing_highlights(),
     };
     let toolbar = toolbar(&toolbar_state);
 
@@ -275,8 +336,10 @@ pub fn files_view<'a>(
         None
     };
 
-    // Content area - different handling for search mode vs normal browsing
-    let content: Element<'a, Message> = if is_searching {
+    // Content area - different handling for search mode, highlights and normal browsing
+    let content: Element<'a, Message> = if let Some(highlights) = &tab.highlights {
+        highlights_content(highlights, perms)
+    } else if is_searching {
         // Search mode content
         if tab.search_loading {
             // Searching state
@@ -363,6 +426,10 @@ pub fn files_view<'a>(
                             is_cut,
                             perms,
                             has_clipboard: files_management.clipboard.is_some(),
+                            can_watch,
+                            is_watched: upload_watch.is_some_and(|w| {
+                                w.remote_path == path && w.remote_root == tab.viewing_root
+                            }),
                         }
                     })
                     .collect();
@@ -412,6 +479,18 @@ pub fn files_view<'a>(
         );
     }
 
+    // Show what the current directory accepts when it has an upload rule
+    if !is_searching
+        && !tab.is_showing_highlights()
+        && let Some(rule) = &tab.current_dir_upload_rule
+    {
+        form_column = form_column.push(
+            shaped_text_wrapped(upload_rule_hint(rule))
+                .size(TEXT_SIZE)
+                .style(muted_text_style),
+        );
+    }
+
     // Add search row if user has permission
     if let Some(search) = search_row {
         form_column = form_column.push(search);
@@ -471,3 +550,27 @@ pub fn files_view<'a>(
         main_content.into()
     }
 }
+
+/// One-line summary of the upload rule for the current directory
+fn upload_rule_hint(rule: &UploadRule) -> String {
+    let mut limits = Vec::new();
+    if !rule.extensions.is_empty() {
+        limits.push(t_args(
+            "files-upload-rule-hint-types",
+            &[("extensions", &rule.extensions.join(", "))],
+        ));
+    }
+    if let Some(max) = rule.max_file_size {
+        limits.push(t_args(
+            "files-upload-rule-hint-file-size",
+            &[("size", &format_size(max))],
+        ));
+    }
+    if let Some(max) = rule.max_total_size {
+        limits.push(t_args(
+            "files-upload-rule-hint-total-size",
+            &[("size", &format_size(max))],
+        ));
+    }
+    t_args("files-upload-rule-hint", &[("limits", &limits.join(" · "))])
+}
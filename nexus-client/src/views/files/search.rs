@@ -226,6 +226,27 @@ pub(super) fn lazy_search_results_table(deps: SearchResultsDeps) -> Element<'sta
         .width(FILE_SIZE_COLUMN_WIDTH)
         .align_x(Right);
 
+        // Rating column (average of user ratings, not sortable)
+        let rating_header: Element<'static, Message> = shaped_text(t("files-column-rating"))
+            .size(TEXT_SIZE)
+            .style(muted_text_style)
+            .into();
+        let rating_column = table::column(
+            rating_header,
+            |result: FileSearchResult| -> Element<'static, Message> {
+                let rating_text = match result.rating {
+                    Some(rating) if rating.count > 0 => format!("★ {:.1}", rating.average()),
+                    _ => String::from("—"),
+                };
+                shaped_text(rating_text)
+                    .size(TEXT_SIZE)
+                    .style(muted_text_style)
+                    .into()
+            },
+        )
+        .width(FILE_SIZE_COLUMN_WIDTH)
+        .align_x(Right);
+
         // Modified column header (clickable for sorting)
         let modified_header_content: Element<'static, Message> =
             if deps.sort_column == FileSortColumn::Modified {
@@ -272,7 +293,13 @@ pub(super) fn lazy_search_results_table(deps: SearchResultsDeps) -> Element<'sta
         .width(FILE_DATE_COLUMN_WIDTH)
         .align_x(Right);
 
-        let columns = [name_column, path_column, size_column, modified_column];
+        let columns = [
+            name_column,
+            path_column,
+            size_column,
+            rating_column,
+            modified_column,
+        ];
 
         table(columns, deps.results.clone())
             .width(Fill)
@@ -1,7 +1,7 @@
-//! Certificate fingerprint mismatch dialog view
+//! Certificate fingerprint mismatch and new server approval dialog view
 
 use iced::widget::button as btn;
-use iced::widget::{Space, button, column, row};
+use iced::widget::{Column, Space, button, row};
 use iced::{Element, Length};
 
 use super::layout::scrollable_modal;
@@ -11,7 +11,7 @@ use crate::style::{
     FINGERPRINT_SPACE_AFTER_LABEL, FINGERPRINT_SPACE_AFTER_SERVER_INFO,
     FINGERPRINT_SPACE_AFTER_TITLE, FINGERPRINT_SPACE_AFTER_WARNING,
     FINGERPRINT_SPACE_BEFORE_BUTTONS, FINGERPRINT_SPACE_BETWEEN_SECTIONS, MONOSPACE_FONT,
-    TEXT_SIZE, panel_title, shaped_text, shaped_text_wrapped,
+    TEXT_SIZE, muted_text_style, panel_title, shaped_text, shaped_text_wrapped,
 };
 use crate::types::{FingerprintMismatch, Message};
 
@@ -20,7 +20,7 @@ use crate::types::{FingerprintMismatch, Message};
 // ============================================================================
 
 /// Format a colon-separated fingerprint into two lines for readability
-fn format_fingerprint_multiline(fingerprint: &str) -> String {
+pub(super) fn format_fingerprint_multiline(fingerprint: &str) -> String {
     let parts: Vec<&str> = fingerprint.split(':').collect();
     let mid = parts.len() / 2;
     format!("{}\n{}", parts[..mid].join(":"), parts[mid..].join(":"))
@@ -31,8 +31,21 @@ fn format_fingerprint_multiline(fingerprint: &str) -> String {
 // ============================================================================
 
 /// Create the fingerprint mismatch warning dialog
-pub fn fingerprint_mismatch_dialog<'a>(mismatch: &'a FingerprintMismatch) -> Element<'a, Message> {
-    let title = panel_title(t("title-fingerprint-mismatch"));
+///
+/// An empty expected fingerprint means a new server awaiting approval
+/// (`require_fingerprint_approval`), so there's nothing to compare against.
+/// `show_tip` adds a first-run explanation of what fingerprints are for.
+pub fn fingerprint_mismatch_dialog<'a>(
+    mismatch: &'a FingerprintMismatch,
+    show_tip: bool,
+) -> Element<'a, Message> {
+    let is_new_server = mismatch.expected.is_empty();
+
+    let title = panel_title(if is_new_server {
+        t("title-fingerprint-new-server")
+    } else {
+        t("title-fingerprint-mismatch")
+    });
 
     let server_line = shaped_text(format!(
         "{} - [{}]:{}",
@@ -40,7 +53,12 @@ pub fn fingerprint_mismatch_dialog<'a>(mismatch: &'a FingerprintMismatch) -> Ele
     ))
     .size(TEXT_SIZE);
 
-    let warning = shaped_text_wrapped(t("fingerprint-warning")).size(TEXT_SIZE);
+    let warning = shaped_text_wrapped(if is_new_server {
+        t("fingerprint-new-server-prompt")
+    } else {
+        t("fingerprint-warning")
+    })
+    .size(TEXT_SIZE);
 
     let expected_label = shaped_text(t("label-expected-fingerprint")).size(TEXT_SIZE);
     let expected_value = shaped_text(format_fingerprint_multiline(&mismatch.expected))
@@ -53,14 +71,22 @@ pub fn fingerprint_mismatch_dialog<'a>(mismatch: &'a FingerprintMismatch) -> Ele
         .font(MONOSPACE_FONT);
 
     let accept_button = button(
-        shaped_text(t("button-accept-new-certificate"))
-            .size(TEXT_SIZE)
-            .width(Length::Fill)
-            .center(),
+        shaped_text(if is_new_server {
+            t("button-trust-certificate")
+        } else {
+            t("button-accept-new-certificate")
+        })
+        .size(TEXT_SIZE)
+        .width(Length::Fill)
+        .center(),
     )
     .on_press(Message::AcceptNewFingerprint)
     .padding(BUTTON_PADDING)
-    .style(btn::danger);
+    .style(if is_new_server {
+        btn::primary
+    } else {
+        btn::danger
+    });
 
     let cancel_button = button(
         shaped_text(t("button-cancel"))
@@ -79,26 +105,47 @@ pub fn fingerprint_mismatch_dialog<'a>(mismatch: &'a FingerprintMismatch) -> Ele
     ]
     .spacing(ELEMENT_SPACING);
 
-    let dialog = column![
-        title,
-        Space::new().height(FINGERPRINT_SPACE_AFTER_TITLE),
-        server_line,
-        Space::new().height(FINGERPRINT_SPACE_AFTER_SERVER_INFO),
-        warning,
-        Space::new().height(FINGERPRINT_SPACE_AFTER_WARNING),
-        expected_label,
-        Space::new().height(FINGERPRINT_SPACE_AFTER_LABEL),
-        expected_value,
-        Space::new().height(FINGERPRINT_SPACE_BETWEEN_SECTIONS),
-        received_label,
-        Space::new().height(FINGERPRINT_SPACE_AFTER_LABEL),
-        received_value,
-        Space::new().height(FINGERPRINT_SPACE_BEFORE_BUTTONS),
-        button_row,
-    ]
-    .spacing(ELEMENT_SPACING)
-    .padding(CONTENT_PADDING)
-    .max_width(CONTENT_MAX_WIDTH);
+    let mut items: Vec<Element<'a, Message>> = vec![
+        title.into(),
+        Space::new().height(FINGERPRINT_SPACE_AFTER_TITLE).into(),
+        server_line.into(),
+        Space::new()
+            .height(FINGERPRINT_SPACE_AFTER_SERVER_INFO)
+            .into(),
+        warning.into(),
+        Space::new().height(FINGERPRINT_SPACE_AFTER_WARNING).into(),
+    ];
+    if show_tip {
+        items.extend([
+            shaped_text_wrapped(t("tip-fingerprint"))
+                .size(TEXT_SIZE)
+                .style(muted_text_style)
+                .into(),
+            Space::new().height(FINGERPRINT_SPACE_AFTER_WARNING).into(),
+        ]);
+    }
+    if !is_new_server {
+        items.extend([
+            expected_label.into(),
+            Space::new().height(FINGERPRINT_SPACE_AFTER_LABEL).into(),
+            expected_value.into(),
+            Space::new()
+                .height(FINGERPRINT_SPACE_BETWEEN_SECTIONS)
+                .into(),
+        ]);
+    }
+    items.extend([
+        received_label.into(),
+        Space::new().height(FINGERPRINT_SPACE_AFTER_LABEL).into(),
+        received_value.into(),
+        Space::new().height(FINGERPRINT_SPACE_BEFORE_BUTTONS).into(),
+        button_row.into(),
+    ]);
+
+    let dialog = Column::with_children(items)
+        .spacing(ELEMENT_SPACING)
+        .padding(CONTENT_PADDING)
+        .max_width(CONTENT_MAX_WIDTH);
 
     scrollable_modal(dialog)
 }
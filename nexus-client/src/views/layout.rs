@@ -1,7 +1,11 @@
 //! Main application layout and toolbar
 
+use std::cell::Cell;
+
+use iced::widget::button as btn;
 use iced::widget::{
-    Column, Space, button, column, container, row, scrollable, stack, text_editor, tooltip,
+    Column, PaneGrid, Space, button, column, container, pane_grid, row, scrollable, stack,
+    text_editor, tooltip,
 };
 use iced::{Center, Element, Fill};
 use nexus_common::voice::VoiceQuality;
@@ -9,36 +13,47 @@ use nexus_common::voice::VoiceQuality;
 use crate::config::audio::{PttMode, PttReleaseDelay};
 use crate::voice::audio::AudioDevice;
 
+use super::admin_dashboard::admin_dashboard_view;
 use super::connection_monitor::connection_monitor_view;
 use super::constants::{
-    PERMISSION_CONNECTION_MONITOR, PERMISSION_FILE_COPY, PERMISSION_FILE_CREATE_DIR,
-    PERMISSION_FILE_DELETE, PERMISSION_FILE_DOWNLOAD, PERMISSION_FILE_INFO, PERMISSION_FILE_LIST,
-    PERMISSION_FILE_MOVE, PERMISSION_FILE_RENAME, PERMISSION_FILE_ROOT, PERMISSION_FILE_SEARCH,
-    PERMISSION_FILE_UPLOAD, PERMISSION_NEWS_LIST, PERMISSION_USER_BROADCAST,
-    PERMISSION_USER_CREATE, PERMISSION_USER_DELETE, PERMISSION_USER_EDIT, PERMISSION_USER_LIST,
+    PERMISSION_CONNECTION_MONITOR, PERMISSION_DOOR_PLAY, PERMISSION_FILE_COMMENT,
+    PERMISSION_FILE_COPY, PERMISSION_FILE_CREATE_DIR, PERMISSION_FILE_DELETE,
+    PERMISSION_FILE_DOWNLOAD, PERMISSION_FILE_INFO, PERMISSION_FILE_LIST, PERMISSION_FILE_MOVE,
+    PERMISSION_FILE_RENAME, PERMISSION_FILE_ROOT, PERMISSION_FILE_SEARCH, PERMISSION_FILE_UPLOAD,
+    PERMISSION_LOG_VIEW, PERMISSION_NEWS_LIST, PERMISSION_USER_BROADCAST, PERMISSION_USER_CREATE,
+    PERMISSION_USER_DELETE, PERMISSION_USER_EDIT, PERMISSION_USER_LIST,
 };
 use super::disconnect_dialog::disconnect_dialog_view;
+use super::doors::doors_view;
 use super::files::{FilePermissions, files_view};
 use super::news::news_view;
+use super::screenshot::screenshot_editor_view;
 use super::server_info::{ServerInfoData, server_info_view};
+use super::server_log::server_log_view;
+use super::snippet::snippet_view;
+use super::statistics::statistics_view;
+use super::status_bar::status_bar;
 use super::transfers::transfers_view;
 use super::user_info::{password_change_view, user_info_view};
-use crate::config::events::EventSettings;
-use crate::config::settings::ProxySettings;
-use crate::i18n::t;
+use crate::config::events::{EventSettings, NotificationRules, QuietHours};
+use crate::config::settings::{AutoAway, CollisionPolicy, ProxySettings, Tip, UserListSettings};
+use crate::i18n::{t, t_args};
 use crate::icon;
+use crate::spellcheck::SpellSettings;
 use crate::style::{
     BADGE_FONT_SIZE, BADGE_HEIGHT, BADGE_PADDING_HORIZONTAL, BADGE_SIZE, BORDER_WIDTH,
-    EMPTY_VIEW_SIZE, PANEL_SPACING, TOOLBAR_ICON_SIZE, TOOLBAR_ICON_SPACING,
-    TOOLBAR_PADDING_HORIZONTAL, TOOLBAR_PADDING_VERTICAL, TOOLBAR_SPACING, TOOLBAR_TITLE_SIZE,
-    TOOLTIP_BACKGROUND_PADDING, TOOLTIP_GAP, TOOLTIP_PADDING, TOOLTIP_TEXT_SIZE, badge_style,
-    content_background_style, disabled_icon_button_style, modal_overlay_style, muted_text_style,
-    separator_style, shaped_text, toolbar_background_style, toolbar_button_style,
-    tooltip_container_style, transparent_icon_button_style,
+    BUTTON_PADDING, ELEMENT_SPACING, EMPTY_VIEW_SIZE, PANEL_SPACING, SPLIT_DIVIDER_WIDTH,
+    SPLIT_RESIZE_LEEWAY, TEXT_SIZE, TIP_BAR_PADDING, TIP_HIGHLIGHT_WIDTH, TOOLBAR_ICON_SIZE,
+    TOOLBAR_ICON_SPACING, TOOLBAR_PADDING_HORIZONTAL, TOOLBAR_PADDING_VERTICAL, TOOLBAR_SPACING,
+    TOOLBAR_TITLE_SIZE, TOOLTIP_BACKGROUND_PADDING, TOOLTIP_GAP, TOOLTIP_PADDING,
+    TOOLTIP_TEXT_SIZE, badge_style, content_background_style, disabled_icon_button_style,
+    modal_overlay_style, muted_text_style, separator_style, shaped_text, shaped_text_wrapped,
+    tip_highlight_style, toolbar_background_style, toolbar_button_style, tooltip_container_style,
+    transparent_icon_button_style,
 };
 use crate::types::{
-    ActivePanel, BookmarkEditMode, Message, ServerConnection, SettingsFormState, ToolbarState,
-    UserManagementState, ViewConfig,
+    ActivePanel, BookmarkEditMode, Message, ServerBookmark, ServerConnection, SettingsFormState,
+    SpellMenu, SplitLayout, SplitPane, ToolbarState, UploadWatch, UserManagementState, ViewConfig,
 };
 
 // ============================================================================
@@ -49,8 +64,8 @@ use crate::types::{
 struct ServerContentContext<'a> {
     /// Active server connection
     conn: &'a ServerConnection,
-    /// Current message input text
-    message_input: &'a str,
+    /// Chat message input editor
+    message_input: &'a text_editor::Content,
     /// User management panel state
     user_management: &'a UserManagementState,
     /// Currently active panel
@@ -69,10 +84,24 @@ struct ServerContentContext<'a> {
     chat_font_size: u8,
     /// Timestamp display settings
     timestamp_settings: TimestampSettings,
+    /// Chat and panel layout (when split view is enabled)
+    split_layout: Option<&'a SplitLayout>,
     /// Settings form state (when settings panel is open)
     settings_form: Option<&'a SettingsFormState>,
     /// News body editor content
     news_body_content: Option<&'a text_editor::Content>,
+    /// Whether spell checking is on
+    spell_check: bool,
+    /// Dictionary the editors are checked against (empty when off or missing)
+    spell_settings: SpellSettings,
+    /// Whether loading finished without finding a dictionary
+    spell_dictionary_missing: bool,
+    /// Whether read receipts are sent
+    send_read_receipts: bool,
+    /// User list grouping and sort order
+    user_list: UserListSettings,
+    /// Spelling suggestions for the word last right-clicked
+    spell_menu: Option<&'a SpellMenu>,
     /// Default nickname for shared accounts
     nickname: &'a str,
     /// SOCKS5 proxy settings
@@ -83,14 +112,30 @@ struct ServerContentContext<'a> {
     show_hidden: bool,
     /// Transfer manager for file downloads/uploads
     transfer_manager: &'a crate::transfers::TransferManager,
+    /// Finished transfers (Transfers panel history view)
+    transfer_history: &'a crate::transfers::TransferHistory,
+    /// Global UI state (Transfers panel view and history search)
+    ui_state: &'a crate::types::UiState,
     /// Whether to queue transfers (limit concurrent transfers per server)
     queue_transfers: bool,
     /// Maximum concurrent downloads per server (0 = unlimited)
     download_limit: u8,
     /// Maximum concurrent uploads per server (0 = unlimited)
     pub upload_limit: u8,
+    /// Open the containing folder when a download completes
+    auto_open_folder: bool,
+    /// What to do when a download's destination already exists
+    collision_policy: CollisionPolicy,
+    /// Ask before trusting a new server's certificate
+    require_fingerprint_approval: bool,
     /// Whether to show the drag-and-drop overlay
     pub show_drop_overlay: bool,
+    /// Upload watch on the connection's bookmark
+    pub upload_watch: Option<&'a UploadWatch>,
+    /// Notification rules on the connection's bookmark (for tab mutes)
+    pub notification_rules: Option<&'a NotificationRules>,
+    /// Saved bookmarks (for per-server notification rules)
+    pub bookmarks: &'a [ServerBookmark],
     /// Event notification settings
     pub event_settings: &'a EventSettings,
     /// Global toggle for desktop notifications
@@ -99,6 +144,10 @@ struct ServerContentContext<'a> {
     pub sound_enabled: bool,
     /// Master volume for sounds (0.0 - 1.0)
     pub sound_volume: f32,
+    /// Manual do not disturb toggle
+    pub do_not_disturb: bool,
+    /// Scheduled do not disturb window
+    pub quiet_hours: QuietHours,
     /// Voice target for the current tab (channel or nickname)
     pub voice_target: Option<String>,
     // ==================== Audio Settings ====================
@@ -136,6 +185,10 @@ struct ServerContentContext<'a> {
     pub transient_suppression: bool,
     /// Microphone boost level
     pub mic_boost: crate::config::audio::MicBoost,
+    /// Soundboard clip playback volume (0.0 - 1.0)
+    pub clip_volume: f32,
+    /// Read voice text messages aloud
+    pub voice_text_tts: bool,
     /// Whether local user is currently transmitting (PTT active)
     pub is_local_speaking: bool,
     /// Whether local user has deafened (muted all incoming voice audio)
@@ -145,6 +198,10 @@ struct ServerContentContext<'a> {
     pub show_tray_icon: bool,
     /// Minimize to tray instead of closing
     pub minimize_to_tray: bool,
+    /// Global hotkey that shows or hides the window
+    pub toggle_window_key: Option<&'a str>,
+    /// Automatic away status after idle time
+    pub auto_away: AutoAway,
 }
 
 // ============================================================================
@@ -234,11 +291,11 @@ pub fn main_layout<'a>(config: ViewConfig<'a>) -> Element<'a, Message> {
         .map(|conn| (conn.is_admin, conn.permissions.as_slice()))
         .unwrap_or((false, &[]));
 
-    // Check if user has permission to view user list
+    // Check if user can view the user list panel (permission, or friends to show)
     let can_view_user_list = config
         .active_connection
         .and_then(|id| config.connections.get(&id))
-        .is_some_and(|conn| conn.has_permission(PERMISSION_USER_LIST));
+        .is_some_and(|conn| conn.has_permission(PERMISSION_USER_LIST) || !conn.friends.is_empty());
 
     // Get server name from active connection
     let server_name = config
@@ -263,13 +320,33 @@ pub fn main_layout<'a>(config: ViewConfig<'a>) -> Element<'a, Message> {
         transfer_count,
     });
 
+    // First-run tip about one of the panels, once connected (panels that
+    // aren't showing wait their turn)
+    let panel_tip = config
+        .active_connection
+        .filter(|id| config.connections.contains_key(id))
+        .and_then(|_| {
+            Tip::current_panel_tip(config.pending_tips, |tip| match tip {
+                Tip::ServerList => config.ui_state.show_bookmarks,
+                Tip::Chat => {
+                    config.active_panel == ActivePanel::None
+                        && config.bookmark_edit.mode == BookmarkEditMode::None
+                }
+                Tip::UserList => config.ui_state.show_user_list && can_view_user_list,
+                Tip::Toolbar | Tip::Welcome | Tip::Fingerprint => true,
+            })
+        });
+    let toolbar = tip_highlight(toolbar, panel_tip == Some(Tip::Toolbar));
+
     // Left panel: Server list (use hidden_panel when not visible to preserve layout stability)
     let server_list = if config.ui_state.show_bookmarks {
         server_list_panel(
             config.bookmarks,
+            config.bookmark_groups,
             config.connections,
             config.active_connection,
             config.bookmark_errors,
+            config.ui_state,
         )
     } else {
         hidden_panel()
@@ -300,21 +377,44 @@ pub fn main_layout<'a>(config: ViewConfig<'a>) -> Element<'a, Message> {
                     use_24_hour_time: config.use_24_hour_time,
                     show_seconds: config.show_seconds,
                 },
+                split_layout: config.split_view.then_some(&config.ui_state.split_layout),
                 settings_form: config.settings_form,
                 news_body_content: config.news_body_content,
+                spell_check: config.spell_check,
+                spell_settings: config.spell_settings.clone(),
+                spell_dictionary_missing: config.spell_dictionary_missing,
+                send_read_receipts: config.send_read_receipts,
+                user_list: config.user_list,
+                spell_menu: config.ui_state.spell_menu.as_ref(),
                 nickname: config.nickname,
                 proxy: config.proxy,
                 download_path: config.download_path,
                 show_hidden: config.show_hidden,
                 transfer_manager: config.transfer_manager,
+                transfer_history: config.transfer_history,
+                ui_state: config.ui_state,
                 queue_transfers: config.queue_transfers,
                 download_limit: config.download_limit,
                 upload_limit: config.upload_limit,
+                auto_open_folder: config.auto_open_folder,
+                collision_policy: config.collision_policy,
+                require_fingerprint_approval: config.require_fingerprint_approval,
                 show_drop_overlay: config.show_drop_overlay,
+                upload_watch: conn
+                    .bookmark_id
+                    .and_then(|id| config.bookmarks.iter().find(|b| b.id == id))
+                    .and_then(|b| b.upload_watch.as_ref()),
+                notification_rules: conn
+                    .bookmark_id
+                    .and_then(|id| config.bookmarks.iter().find(|b| b.id == id))
+                    .map(|b| &b.notification_rules),
+                bookmarks: config.bookmarks,
                 event_settings: config.event_settings,
                 notifications_enabled: config.notifications_enabled,
                 sound_enabled: config.sound_enabled,
                 sound_volume: config.sound_volume,
+                do_not_disturb: config.do_not_disturb,
+                quiet_hours: config.quiet_hours,
                 voice_target: config.voice_target.clone(),
                 output_devices: config.output_devices,
                 selected_output_device: config.selected_output_device.clone(),
@@ -333,17 +433,23 @@ pub fn main_layout<'a>(config: ViewConfig<'a>) -> Element<'a, Message> {
                 agc: config.agc,
                 transient_suppression: config.transient_suppression,
                 mic_boost: config.mic_boost,
+                clip_volume: config.clip_volume,
+                voice_text_tts: config.voice_text_tts,
                 is_local_speaking: config.is_local_speaking,
                 is_deafened: config.is_deafened,
                 show_tray_icon: config.show_tray_icon,
                 minimize_to_tray: config.minimize_to_tray,
+                toggle_window_key: config.toggle_window_key,
+                auto_away: config.auto_away,
             })
         } else if config.active_connection.is_some() {
             // Connection exists but couldn't get all required state
             empty_content_view()
         } else {
             // Not connected - show connection form, with Settings/About overlay if active
-            let conn_form = connection_form_view(config.connection_form);
+            let show_welcome =
+                config.pending_tips.contains(&Tip::Welcome) && config.bookmarks.is_empty();
+            let conn_form = connection_form_view(config.connection_form, show_welcome);
             match config.active_panel {
                 ActivePanel::Settings => stack![
                     conn_form,
@@ -359,6 +465,11 @@ pub fn main_layout<'a>(config: ViewConfig<'a>) -> Element<'a, Message> {
                             use_24_hour_time: config.use_24_hour_time,
                             show_seconds: config.show_seconds,
                         },
+                        split_view: config.split_view,
+                        spell_check: config.spell_check,
+                        spell_dictionary_missing: config.spell_dictionary_missing,
+                        send_read_receipts: config.send_read_receipts,
+                        user_list: config.user_list,
                         settings_form: config.settings_form,
                         nickname: config.nickname,
                         proxy: config.proxy,
@@ -366,7 +477,11 @@ pub fn main_layout<'a>(config: ViewConfig<'a>) -> Element<'a, Message> {
                         queue_transfers: config.queue_transfers,
                         download_limit: config.download_limit,
                         upload_limit: config.upload_limit,
+                        auto_open_folder: config.auto_open_folder,
+                        collision_policy: config.collision_policy,
+                        require_fingerprint_approval: config.require_fingerprint_approval,
                         event_settings: config.event_settings,
+                        bookmarks: config.bookmarks,
                         selected_event_type: config
                             .settings_form
                             .map(|f| f.selected_event_type)
@@ -374,6 +489,8 @@ pub fn main_layout<'a>(config: ViewConfig<'a>) -> Element<'a, Message> {
                         notifications_enabled: config.notifications_enabled,
                         sound_enabled: config.sound_enabled,
                         sound_volume: config.sound_volume,
+                        do_not_disturb: config.do_not_disturb,
+                        quiet_hours: config.quiet_hours,
                         output_devices: config.output_devices,
                         selected_output_device: config.selected_output_device.clone(),
                         input_devices: config.input_devices,
@@ -391,8 +508,12 @@ pub fn main_layout<'a>(config: ViewConfig<'a>) -> Element<'a, Message> {
                         agc: config.agc,
                         transient_suppression: config.transient_suppression,
                         mic_boost: config.mic_boost,
+                        clip_volume: config.clip_volume,
+                        voice_text_tts: config.voice_text_tts,
                         show_tray_icon: config.show_tray_icon,
                         minimize_to_tray: config.minimize_to_tray,
+                        toggle_window_key: config.toggle_window_key,
+                        auto_away: config.auto_away,
                     })
                 ]
                 .width(Fill)
@@ -402,12 +523,17 @@ pub fn main_layout<'a>(config: ViewConfig<'a>) -> Element<'a, Message> {
                     .width(Fill)
                     .height(Fill)
                     .into(),
-                ActivePanel::Transfers => {
-                    stack![conn_form, transfers_view(config.transfer_manager)]
-                        .width(Fill)
-                        .height(Fill)
-                        .into()
-                }
+                ActivePanel::Transfers => stack![
+                    conn_form,
+                    transfers_view(
+                        config.transfer_manager,
+                        config.transfer_history,
+                        config.ui_state
+                    )
+                ]
+                .width(Fill)
+                .height(Fill)
+                .into(),
                 _ => conn_form,
             }
         };
@@ -418,23 +544,101 @@ pub fn main_layout<'a>(config: ViewConfig<'a>) -> Element<'a, Message> {
             .into()
     };
 
-    // Right panel: User list (only when connected, visible, and user has permission)
+    // Right panel: User list (only when connected, visible, and user has permission or friends)
     let user_list = if config.ui_state.show_user_list && can_view_user_list {
         config
             .active_connection
             .and_then(|conn_id| config.connections.get(&conn_id))
-            .map(|conn| user_list_panel(conn, &config.theme))
+            .map(|conn| user_list_panel(conn, config.user_list, &config.theme))
             .unwrap_or_else(hidden_panel)
     } else {
         hidden_panel()
     };
 
     // Three-panel layout (always same structure to preserve scroll state)
-    let content = row![server_list, main_content, user_list]
-        .spacing(PANEL_SPACING)
-        .height(Fill);
+    let content = row![
+        tip_highlight(server_list, panel_tip == Some(Tip::ServerList)),
+        tip_highlight(main_content, panel_tip == Some(Tip::Chat)),
+        tip_highlight(user_list, panel_tip == Some(Tip::UserList)),
+    ]
+    .spacing(PANEL_SPACING)
+    .height(Fill);
 
-    column![toolbar, content].into()
+    // Tip bar below the panels (after them, so showing it doesn't disturb their state)
+    let content: Element<'a, Message> = match panel_tip {
+        Some(tip) => column![content, separator(), tip_bar(tip)].into(),
+        None => column![content].into(),
+    };
+
+    // Bottom status bar with the active server's status and transfer progress
+    let active_conn = config
+        .active_connection
+        .and_then(|conn_id| config.connections.get(&conn_id));
+    let timestamp_settings = TimestampSettings {
+        show_timestamps: config.show_timestamps,
+        use_24_hour_time: config.use_24_hour_time,
+        show_seconds: config.show_seconds,
+    };
+    match status_bar(active_conn, config.transfer_manager, timestamp_settings) {
+        Some(status_bar) => column![toolbar, content, separator(), status_bar].into(),
+        None => column![toolbar, content].into(),
+    }
+}
+
+/// Outline a panel while the current first-run tip is about it
+///
+/// Always wrapped, so the widget tree (and scroll state) is the same either way.
+fn tip_highlight<'a>(panel: Element<'a, Message>, highlighted: bool) -> Element<'a, Message> {
+    let wrapper = container(panel);
+    if highlighted {
+        wrapper
+            .padding(TIP_HIGHLIGHT_WIDTH)
+            .style(tip_highlight_style)
+            .into()
+    } else {
+        wrapper.into()
+    }
+}
+
+/// Bar explaining the highlighted panel, with buttons to move on or skip the tips
+fn tip_bar<'a>(tip: Tip) -> Element<'a, Message> {
+    let position = tip.panel_position().unwrap_or(1);
+    let total = Tip::PANEL_TIPS.len();
+    let next_label = if position == total {
+        t("button-tip-done")
+    } else {
+        t("button-tip-next")
+    };
+
+    container(
+        row![
+            shaped_text(t_args(
+                "tip-counter",
+                &[
+                    ("current", &position.to_string()),
+                    ("total", &total.to_string()),
+                ],
+            ))
+            .size(TEXT_SIZE)
+            .style(muted_text_style),
+            shaped_text_wrapped(t(tip.translation_key()))
+                .size(TEXT_SIZE)
+                .width(Fill),
+            button(shaped_text(t("button-skip-tips")).size(TEXT_SIZE))
+                .on_press(Message::TipsSkipped)
+                .padding(BUTTON_PADDING)
+                .style(btn::secondary),
+            button(shaped_text(next_label).size(TEXT_SIZE))
+                .on_press(Message::TipDismissed(tip))
+                .padding(BUTTON_PADDING),
+        ]
+        .spacing(ELEMENT_SPACING)
+        .align_y(Center),
+    )
+    .padding(TIP_BAR_PADDING)
+    .width(Fill)
+    .style(toolbar_background_style)
+    .into()
 }
 
 /// Build the top toolbar with buttons and toggles
@@ -456,6 +660,7 @@ fn build_toolbar(state: ToolbarState) -> Element<'static, Message> {
         PERMISSION_USER_DELETE,
     ]);
     let has_connection_monitor = state.has_permission(PERMISSION_CONNECTION_MONITOR);
+    let has_log_view = state.has_permission(PERMISSION_LOG_VIEW);
 
     let toolbar = container(
         row![
@@ -621,6 +826,31 @@ fn build_toolbar(state: ToolbarState) -> Element<'static, Message> {
                     .gap(TOOLTIP_GAP)
                     .padding(TOOLTIP_PADDING)
                 },
+                // Server Log button
+                if state.is_connected && has_log_view {
+                    tooltip(
+                        button(icon::terminal().size(TOOLBAR_ICON_SIZE))
+                            .on_press(Message::ToggleServerLog)
+                            .style(toolbar_button_style(active_panel == ActivePanel::ServerLog)),
+                        container(shaped_text(t("tooltip-server-log")).size(TOOLTIP_TEXT_SIZE))
+                            .padding(TOOLTIP_BACKGROUND_PADDING)
+                            .style(tooltip_container_style),
+                        tooltip::Position::Bottom,
+                    )
+                    .gap(TOOLTIP_GAP)
+                    .padding(TOOLTIP_PADDING)
+                } else {
+                    tooltip(
+                        button(icon::terminal().size(TOOLBAR_ICON_SIZE))
+                            .style(disabled_icon_button_style),
+                        container(shaped_text(t("tooltip-server-log")).size(TOOLTIP_TEXT_SIZE))
+                            .padding(TOOLTIP_BACKGROUND_PADDING)
+                            .style(tooltip_container_style),
+                        tooltip::Position::Bottom,
+                    )
+                    .gap(TOOLTIP_GAP)
+                    .padding(TOOLTIP_PADDING)
+                },
                 // Server Info button
                 if state.is_connected {
                     tooltip(
@@ -817,11 +1047,15 @@ fn build_toolbar(state: ToolbarState) -> Element<'static, Message> {
 ///
 /// Always renders chat view at the bottom layer to preserve scroll position,
 /// then overlays broadcast or user management panels on top when active.
+/// In split view, Files, News and Connection Monitor go beside chat instead.
 fn server_content_view<'a>(ctx: ServerContentContext<'a>) -> Element<'a, Message> {
     // Always render chat view as the base layer to preserve scroll position
     let chat = chat_view(
         ctx.conn,
+        ctx.notification_rules,
         ctx.message_input,
+        &ctx.spell_settings,
+        ctx.spell_menu,
         ctx.theme.clone(),
         ctx.chat_font_size,
         ctx.timestamp_settings,
@@ -857,6 +1091,11 @@ fn server_content_view<'a>(ctx: ServerContentContext<'a>) -> Element<'a, Message
                 max_scrollback: ctx.max_scrollback,
                 chat_font_size: ctx.chat_font_size,
                 timestamp_settings: ctx.timestamp_settings,
+                split_view: ctx.split_layout.is_some(),
+                spell_check: ctx.spell_check,
+                spell_dictionary_missing: ctx.spell_dictionary_missing,
+                send_read_receipts: ctx.send_read_receipts,
+                user_list: ctx.user_list,
                 settings_form: ctx.settings_form,
                 nickname: ctx.nickname,
                 proxy: ctx.proxy,
@@ -864,7 +1103,11 @@ fn server_content_view<'a>(ctx: ServerContentContext<'a>) -> Element<'a, Message
                 queue_transfers: ctx.queue_transfers,
                 download_limit: ctx.download_limit,
                 upload_limit: ctx.upload_limit,
+                auto_open_folder: ctx.auto_open_folder,
+                collision_policy: ctx.collision_policy,
+                require_fingerprint_approval: ctx.require_fingerprint_approval,
                 event_settings: ctx.event_settings,
+                bookmarks: ctx.bookmarks,
                 selected_event_type: ctx
                     .settings_form
                     .map(|f| f.selected_event_type)
@@ -872,6 +1115,8 @@ fn server_content_view<'a>(ctx: ServerContentContext<'a>) -> Element<'a, Message
                 notifications_enabled: ctx.notifications_enabled,
                 sound_enabled: ctx.sound_enabled,
                 sound_volume: ctx.sound_volume,
+                do_not_disturb: ctx.do_not_disturb,
+                quiet_hours: ctx.quiet_hours,
                 output_devices: ctx.output_devices,
                 selected_output_device: ctx.selected_output_device.clone(),
                 input_devices: ctx.input_devices,
@@ -889,8 +1134,12 @@ fn server_content_view<'a>(ctx: ServerContentContext<'a>) -> Element<'a, Message
                 agc: ctx.agc,
                 transient_suppression: ctx.transient_suppression,
                 mic_boost: ctx.mic_boost,
+                clip_volume: ctx.clip_volume,
+                voice_text_tts: ctx.voice_text_tts,
                 show_tray_icon: ctx.show_tray_icon,
                 minimize_to_tray: ctx.minimize_to_tray,
+                toggle_window_key: ctx.toggle_window_key,
+                auto_away: ctx.auto_away,
             })
         ]
         .width(Fill)
@@ -903,11 +1152,17 @@ fn server_content_view<'a>(ctx: ServerContentContext<'a>) -> Element<'a, Message
                 version: ctx.conn.server_version.clone(),
                 max_connections_per_ip: ctx.conn.max_connections_per_ip,
                 max_transfers_per_ip: ctx.conn.max_transfers_per_ip,
+                max_active_transfers: ctx.conn.max_active_transfers,
+                transfer_small_files_first: ctx.conn.transfer_small_files_first,
+                trusted_transfer_weight: ctx.conn.trusted_transfer_weight,
                 file_reindex_interval: ctx.conn.file_reindex_interval,
                 persistent_channels: ctx.conn.persistent_channels.clone(),
                 auto_join_channels: ctx.conn.auto_join_channels.clone(),
+                nat: ctx.conn.nat.clone(),
+                connectivity_check: &ctx.conn.connectivity_check,
                 cached_server_image: ctx.conn.cached_server_image.as_ref(),
                 is_admin: ctx.conn.is_admin,
+                can_play_doors: ctx.conn.has_permission(PERMISSION_DOOR_PLAY),
                 active_tab: ctx.conn.server_info_tab,
                 edit_state: ctx.conn.server_info_edit.as_ref(),
             };
@@ -927,18 +1182,18 @@ fn server_content_view<'a>(ctx: ServerContentContext<'a>) -> Element<'a, Message
         .width(Fill)
         .height(Fill)
         .into(),
-        ActivePanel::News => stack![
+        ActivePanel::News => chat_with_panel(
             chat,
             news_view(
                 ctx.conn,
                 &ctx.conn.news_management,
                 &ctx.theme,
                 ctx.news_body_content,
-            )
-        ]
-        .width(Fill)
-        .height(Fill)
-        .into(),
+                &ctx.spell_settings,
+                ctx.spell_menu,
+            ),
+            ctx.split_layout,
+        ),
         ActivePanel::Files => {
             let perms = FilePermissions {
                 file_root: ctx.conn.has_permission(PERMISSION_FILE_ROOT),
@@ -951,31 +1206,55 @@ fn server_content_view<'a>(ctx: ServerContentContext<'a>) -> Element<'a, Message
                 file_download: ctx.conn.has_permission(PERMISSION_FILE_DOWNLOAD),
                 file_upload: ctx.conn.has_permission(PERMISSION_FILE_UPLOAD),
                 file_search: ctx.conn.has_permission(PERMISSION_FILE_SEARCH),
+                file_comment: ctx.conn.has_permission(PERMISSION_FILE_COMMENT),
+                upload_rules: ctx.conn.is_admin,
             };
-            stack![
+            chat_with_panel(
                 chat,
                 files_view(
                     &ctx.conn.files_management,
                     perms,
                     ctx.show_hidden,
-                    ctx.show_drop_overlay
-                )
-            ]
-            .width(Fill)
-            .height(Fill)
-            .into()
+                    ctx.show_drop_overlay,
+                    ctx.conn.bookmark_id.is_some(),
+                    ctx.upload_watch,
+                    &ctx.conn.connection_info.username,
+                ),
+                ctx.split_layout,
+            )
         }
-        ActivePanel::Transfers => stack![chat, transfers_view(ctx.transfer_manager)]
-            .width(Fill)
-            .height(Fill)
-            .into(),
-        ActivePanel::ConnectionMonitor => stack![
+        ActivePanel::Transfers => stack![
             chat,
-            connection_monitor_view(ctx.conn, &ctx.conn.connection_monitor, ctx.theme.clone())
+            transfers_view(ctx.transfer_manager, ctx.transfer_history, ctx.ui_state)
         ]
         .width(Fill)
         .height(Fill)
         .into(),
+        ActivePanel::ConnectionMonitor => chat_with_panel(
+            chat,
+            connection_monitor_view(ctx.conn, &ctx.conn.connection_monitor, ctx.theme.clone()),
+            ctx.split_layout,
+        ),
+        ActivePanel::ServerLog => stack![chat, server_log_view(&ctx.conn.server_log)]
+            .width(Fill)
+            .height(Fill)
+            .into(),
+        ActivePanel::AdminDashboard => {
+            stack![chat, admin_dashboard_view(&ctx.conn.admin_dashboard)]
+                .width(Fill)
+                .height(Fill)
+                .into()
+        }
+        ActivePanel::Statistics => stack![chat, statistics_view(&ctx.conn.statistics)]
+            .width(Fill)
+            .height(Fill)
+            .into(),
+        ActivePanel::Snippet => stack![chat, snippet_view(&ctx.conn.snippet_view, &ctx.theme)]
+            .width(Fill)
+            .height(Fill)
+            .into(),
+        // Drawn without chat underneath so a focused chat input can't take the door's keys
+        ActivePanel::Doors => doors_view(&ctx.conn.doors, &ctx.theme),
         ActivePanel::None => chat,
     };
 
@@ -985,11 +1264,45 @@ fn server_content_view<'a>(ctx: ServerContentContext<'a>) -> Element<'a, Message
             .width(Fill)
             .height(Fill)
             .into()
+    } else if let Some(ref editor) = ctx.conn.screenshot_editor {
+        stack![main_content, screenshot_editor_view(editor)]
+            .width(Fill)
+            .height(Fill)
+            .into()
     } else {
         main_content
     }
 }
 
+/// Show a panel over chat, or beside it when split view is enabled
+///
+/// In split view the divider between chat and the panel can be dragged.
+fn chat_with_panel<'a>(
+    chat: Element<'a, Message>,
+    panel: Element<'a, Message>,
+    split_layout: Option<&'a SplitLayout>,
+) -> Element<'a, Message> {
+    let Some(split_layout) = split_layout else {
+        return stack![chat, panel].width(Fill).height(Fill).into();
+    };
+
+    // Each pane's content is taken once, while the grid is built
+    let chat = Cell::new(Some(chat));
+    let panel = Cell::new(Some(panel));
+    PaneGrid::new(&split_layout.panes, move |_, pane, _| {
+        let content = match pane {
+            SplitPane::Chat => chat.take(),
+            SplitPane::Panel => panel.take(),
+        };
+        pane_grid::Content::new(content.unwrap_or_else(|| Space::new().into()))
+    })
+    .spacing(SPLIT_DIVIDER_WIDTH)
+    .on_resize(SPLIT_RESIZE_LEEWAY, Message::SplitResized)
+    .width(Fill)
+    .height(Fill)
+    .into()
+}
+
 /// Empty content view when no server is selected
 ///
 /// Displays a centered message prompting the user to select a server.
@@ -1,6 +1,7 @@
 //! UI view rendering components
 
 mod about;
+mod admin_dashboard;
 mod bookmark;
 mod broadcast;
 mod chat;
@@ -8,19 +9,31 @@ mod connection;
 mod connection_monitor;
 pub(crate) mod constants;
 mod disconnect_dialog;
+mod doors;
 pub(crate) mod files;
 mod fingerprint;
 mod layout;
 mod news;
+mod password_vault;
+mod quick_switcher;
+mod screenshot;
 mod server_info;
 mod server_list;
+mod server_log;
 mod settings;
+mod snippet;
+mod spellcheck;
+mod statistics;
+mod status_bar;
 pub(crate) mod transfers;
 mod user_info;
-mod user_list;
+pub(crate) mod user_list;
 mod users;
 pub(crate) mod voice;
 
-// Re-export the main layout function and fingerprint dialog (public API)
+// Re-export the main layout function and startup dialogs (public API)
+pub use chat::{TimestampSettings, chat_window_view};
 pub use fingerprint::fingerprint_mismatch_dialog;
 pub use layout::main_layout;
+pub use password_vault::password_vault_dialog;
+pub use quick_switcher::quick_switcher_view;
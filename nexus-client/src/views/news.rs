@@ -8,27 +8,33 @@ use iced::widget::button as btn;
 use iced::widget::markdown;
 use iced::widget::{
     Column, Row, Space, button, column, container, image, row, scrollable, svg, text_editor,
-    tooltip,
+    text_input, tooltip,
 };
 use iced::{Center, Element, Fill, Length, Theme, alignment};
-use nexus_common::protocol::NewsItem;
+use nexus_common::protocol::{NewsAttachment, NewsItem};
+use nexus_common::validators::MAX_NEWS_ATTACHMENTS;
 
 use super::constants::{PERMISSION_NEWS_CREATE, PERMISSION_NEWS_DELETE, PERMISSION_NEWS_EDIT};
 use super::layout::scrollable_panel;
+use super::spellcheck::with_spell_menu;
 use crate::i18n::t;
 use crate::icon;
 use crate::image::CachedImage;
+use crate::spellcheck::{SpellHighlighter, SpellSettings, misspelling_format};
 use crate::style::{
     BUTTON_PADDING, CONTENT_MAX_WIDTH, CONTENT_PADDING, ELEMENT_SPACING, ICON_BUTTON_PADDING,
     INPUT_PADDING, NEWS_ACTION_BUTTON_SIZE, NEWS_ACTION_ICON_SIZE, NEWS_EDITOR_LINE_HEIGHT,
-    NEWS_IMAGE_PREVIEW_SIZE, NEWS_ITEM_SPACING, NO_SPACING, SCROLLBAR_PADDING,
-    SIDEBAR_ACTION_ICON_SIZE, SPACER_SIZE_MEDIUM, SPACER_SIZE_SMALL, TEXT_SIZE, TITLE_SIZE,
-    TOOLTIP_BACKGROUND_PADDING, TOOLTIP_GAP, TOOLTIP_PADDING, TOOLTIP_TEXT_SIZE,
+    NEWS_EDITOR_PADDING, NEWS_IMAGE_PREVIEW_SIZE, NEWS_ITEM_SPACING, NO_SPACING, SCROLLBAR_PADDING,
+    SIDEBAR_ACTION_ICON_SIZE, SMALL_SPACING, SPACER_SIZE_MEDIUM, SPACER_SIZE_SMALL, TEXT_SIZE,
+    TITLE_SIZE, TOOLTIP_BACKGROUND_PADDING, TOOLTIP_GAP, TOOLTIP_PADDING, TOOLTIP_TEXT_SIZE,
     alternating_row_style, chat, content_background_style, danger_icon_button_style,
     error_text_style, muted_text_style, panel_title, shaped_text, shaped_text_wrapped,
     tooltip_container_style, transparent_icon_button_style,
 };
-use crate::types::{InputId, Message, NewsManagementMode, NewsManagementState, ServerConnection};
+use crate::types::{
+    InputId, Message, NewsFormAttachment, NewsManagementMode, NewsManagementState,
+    ServerConnection, SpellField, SpellMenu,
+};
 
 // ============================================================================
 // Helper Functions
@@ -123,6 +129,52 @@ fn render_cached_image_preview<'a>(cached: &CachedImage) -> Element<'a, Message>
     }
 }
 
+/// Button that opens an attached file in the Files panel
+fn file_attachment_button(path: &str) -> button::Button<'_, Message> {
+    button(
+        row![
+            icon::file().size(TEXT_SIZE),
+            shaped_text(path).size(TEXT_SIZE)
+        ]
+        .spacing(SMALL_SPACING)
+        .align_y(Center),
+    )
+    .on_press(Message::NewsOpenFile(path.to_string()))
+    .padding(BUTTON_PADDING)
+    .style(btn::secondary)
+}
+
+/// Render a news post attachment with its caption
+fn attachment_view<'a>(
+    news_id: i64,
+    index: usize,
+    attachment: &'a NewsAttachment,
+    news_image_cache: &'a HashMap<(i64, usize), CachedImage>,
+) -> Element<'a, Message> {
+    let mut attachment_col = Column::new().spacing(SMALL_SPACING);
+
+    match attachment {
+        NewsAttachment::Image { .. } => {
+            if let Some(cached) = news_image_cache.get(&(news_id, index)) {
+                attachment_col = attachment_col.push(render_cached_image(cached));
+            }
+        }
+        NewsAttachment::File { path, .. } => {
+            attachment_col = attachment_col.push(file_attachment_button(path));
+        }
+    }
+
+    if let Some(caption) = attachment.caption() {
+        attachment_col = attachment_col.push(
+            shaped_text_wrapped(caption)
+                .size(TEXT_SIZE)
+                .style(muted_text_style),
+        );
+    }
+
+    attachment_col.into()
+}
+
 // ============================================================================
 // List View
 // ============================================================================
@@ -132,57 +184,61 @@ fn list_view<'a>(
     conn: &'a ServerConnection,
     news_management: &'a NewsManagementState,
     theme: &Theme,
-    news_image_cache: &'a HashMap<i64, CachedImage>,
+    news_image_cache: &'a HashMap<(i64, usize), CachedImage>,
 ) -> Element<'a, Message> {
     // Check permissions
     let can_create = conn.has_permission(PERMISSION_NEWS_CREATE);
 
-    // Build scrollable content (news list or status message)
-    let scroll_content_inner: Element<'a, Message> = match &news_management.news_items {
-        None => {
-            // Loading state
-            shaped_text(t("news-loading"))
-                .size(TEXT_SIZE)
-                .width(Fill)
-                .align_x(Center)
-                .style(muted_text_style)
-                .into()
-        }
-        Some(Err(error)) => {
-            // Error state
-            shaped_text_wrapped(error)
-                .size(TEXT_SIZE)
-                .width(Fill)
-                .align_x(Center)
-                .style(error_text_style)
-                .into()
-        }
-        Some(Ok(items)) => {
-            if items.is_empty() {
-                shaped_text(t("news-no-posts"))
+    // Build scrollable content (search results, news list or status message)
+    let scroll_content_inner: Element<'a, Message> = if news_management.is_searching() {
+        search_results_view(conn, news_management, theme, news_image_cache)
+    } else {
+        match &news_management.news_items {
+            None => {
+                // Loading state
+                shaped_text(t("news-loading"))
                     .size(TEXT_SIZE)
                     .width(Fill)
                     .align_x(Center)
                     .style(muted_text_style)
                     .into()
-            } else {
-                // Build news item rows (newest first for display)
-                let mut news_rows = Column::new().spacing(NEWS_ITEM_SPACING);
-
-                // Reverse to show newest first (server returns oldest first)
-                for (index, item) in items.iter().rev().enumerate() {
-                    let news_row = build_news_item_row(
-                        item,
-                        conn,
-                        theme,
-                        index,
-                        news_image_cache,
-                        &conn.news_markdown_cache,
-                    );
-                    news_rows = news_rows.push(news_row);
+            }
+            Some(Err(error)) => {
+                // Error state
+                shaped_text_wrapped(error)
+                    .size(TEXT_SIZE)
+                    .width(Fill)
+                    .align_x(Center)
+                    .style(error_text_style)
+                    .into()
+            }
+            Some(Ok(items)) => {
+                if items.is_empty() {
+                    shaped_text(t("news-no-posts"))
+                        .size(TEXT_SIZE)
+                        .width(Fill)
+                        .align_x(Center)
+                        .style(muted_text_style)
+                        .into()
+                } else {
+                    // Build news item rows (newest first for display)
+                    let mut news_rows = Column::new().spacing(NEWS_ITEM_SPACING);
+
+                    // Reverse to show newest first (server returns oldest first)
+                    for (index, item) in items.iter().rev().enumerate() {
+                        let news_row = build_news_item_row(
+                            item,
+                            conn,
+                            theme,
+                            index,
+                            news_image_cache,
+                            &conn.news_markdown_cache,
+                        );
+                        news_rows = news_rows.push(news_row);
+                    }
+
+                    news_rows.width(Fill).into()
                 }
-
-                news_rows.width(Fill).into()
             }
         }
     };
@@ -263,6 +319,13 @@ fn list_view<'a>(
         Space::new().width(SCROLLBAR_PADDING),
     ];
 
+    // Search input, padded like the list below it
+    let search_row = row![
+        Space::new().width(SCROLLBAR_PADDING),
+        search_input_row(news_management),
+        Space::new().width(SCROLLBAR_PADDING),
+    ];
+
     // Build the form with max_width constraint
     let form = column![
         title_row,
@@ -271,6 +334,7 @@ fn list_view<'a>(
         } else {
             Element::from(Space::new().height(SPACER_SIZE_SMALL))
         },
+        search_row,
         container(scrollable(padded_scroll_content)).height(Fill),
     ]
     .spacing(ELEMENT_SPACING)
@@ -295,13 +359,103 @@ fn list_view<'a>(
         .into()
 }
 
+/// Build the search input row (search button, and clear button while searching)
+fn search_input_row(news_management: &NewsManagementState) -> Element<'_, Message> {
+    let input = text_input(&t("news-search-placeholder"), &news_management.search_input)
+        .id(InputId::NewsSearchInput)
+        .on_input(Message::NewsSearchInputChanged)
+        .on_submit(Message::NewsSearchSubmit)
+        .padding(INPUT_PADDING)
+        .size(TEXT_SIZE)
+        .width(Fill);
+
+    let icon_button = |icon: iced::widget::Text<'static>, message: Message, tip: String| {
+        tooltip(
+            button(icon.size(SIDEBAR_ACTION_ICON_SIZE))
+                .on_press(message)
+                .padding(ICON_BUTTON_PADDING)
+                .style(transparent_icon_button_style),
+            container(shaped_text(tip).size(TOOLTIP_TEXT_SIZE))
+                .padding(TOOLTIP_BACKGROUND_PADDING)
+                .style(tooltip_container_style),
+            tooltip::Position::Bottom,
+        )
+        .gap(TOOLTIP_GAP)
+        .padding(TOOLTIP_PADDING)
+    };
+
+    let mut search_row = row![
+        input,
+        icon_button(
+            icon::search(),
+            Message::NewsSearchSubmit,
+            t("tooltip-news-search")
+        ),
+    ]
+    .spacing(SMALL_SPACING)
+    .align_y(Center);
+
+    if news_management.is_searching() {
+        search_row = search_row.push(icon_button(
+            icon::close(),
+            Message::NewsSearchClear,
+            t("tooltip-news-search-clear"),
+        ));
+    }
+
+    search_row.into()
+}
+
+/// Build the search results (newest first, as the server sends them)
+fn search_results_view<'a>(
+    conn: &'a ServerConnection,
+    news_management: &'a NewsManagementState,
+    theme: &Theme,
+    news_image_cache: &'a HashMap<(i64, usize), CachedImage>,
+) -> Element<'a, Message> {
+    match &news_management.search_results {
+        None => shaped_text(t("news-searching"))
+            .size(TEXT_SIZE)
+            .width(Fill)
+            .align_x(Center)
+            .style(muted_text_style)
+            .into(),
+        Some(Err(error)) => shaped_text_wrapped(error)
+            .size(TEXT_SIZE)
+            .width(Fill)
+            .align_x(Center)
+            .style(error_text_style)
+            .into(),
+        Some(Ok(items)) if items.is_empty() => shaped_text(t("news-search-no-results"))
+            .size(TEXT_SIZE)
+            .width(Fill)
+            .align_x(Center)
+            .style(muted_text_style)
+            .into(),
+        Some(Ok(items)) => {
+            let mut news_rows = Column::new().spacing(NEWS_ITEM_SPACING);
+            for (index, item) in items.iter().enumerate() {
+                news_rows = news_rows.push(build_news_item_row(
+                    item,
+                    conn,
+                    theme,
+                    index,
+                    news_image_cache,
+                    &conn.news_markdown_cache,
+                ));
+            }
+            news_rows.width(Fill).into()
+        }
+    }
+}
+
 /// Build a single news item row
 fn build_news_item_row<'a>(
     item: &'a NewsItem,
     conn: &'a ServerConnection,
     theme: &Theme,
     index: usize,
-    news_image_cache: &'a HashMap<i64, CachedImage>,
+    news_image_cache: &'a HashMap<(i64, usize), CachedImage>,
     news_markdown_cache: &'a HashMap<i64, Vec<markdown::Item>>,
 ) -> Element<'a, Message> {
     let admin_color = chat::admin(theme);
@@ -386,11 +540,14 @@ fn build_news_item_row<'a>(
         .padding(INPUT_PADDING)
         .push(header_group);
 
-    // Add image if present (from cache)
-    if item.image.is_some()
-        && let Some(cached) = news_image_cache.get(&item.id)
-    {
-        content_col = content_col.push(render_cached_image(cached));
+    // Add attachments in order (images from cache)
+    for (attachment_index, attachment) in item.attachments.iter().enumerate() {
+        content_col = content_col.push(attachment_view(
+            item.id,
+            attachment_index,
+            attachment,
+            news_image_cache,
+        ));
     }
 
     // Add body as markdown if present (from cache)
@@ -423,6 +580,8 @@ fn build_news_item_row<'a>(
 fn form_view<'a>(
     news_management: &'a NewsManagementState,
     body_content: Option<&'a text_editor::Content>,
+    spell_settings: &SpellSettings,
+    spell_menu: Option<&'a SpellMenu>,
     is_edit: bool,
 ) -> Element<'a, Message> {
     let title = panel_title(if is_edit {
@@ -431,21 +590,22 @@ fn form_view<'a>(
         t("title-news-create")
     });
 
-    // Check if we have content (body from editor or image)
+    // Check if we have content (body from editor or attachments)
     let body_text = body_content.map(|c| c.text()).unwrap_or_default();
-    let has_content = !body_text.trim().is_empty() || !news_management.form_image.is_empty();
+    let has_content = !body_text.trim().is_empty() || !news_management.form_attachments.is_empty();
 
-    // Body text editor
+    // Body text editor (misspelled words marked when spell checking is on)
     let body_editor: Element<'a, Message> = if let Some(content) = body_content {
-        text_editor(content)
+        let editor = text_editor(content)
             .id(Id::from(InputId::NewsBody))
             .placeholder(t("placeholder-news-body"))
             .on_action(Message::NewsBodyAction)
-            .padding(CONTENT_PADDING / 2.0)
+            .padding(NEWS_EDITOR_PADDING)
             .size(TEXT_SIZE)
             .line_height(NEWS_EDITOR_LINE_HEIGHT)
             .height(Length::Fixed(150.0))
-            .into()
+            .highlight_with::<SpellHighlighter>(spell_settings.clone(), misspelling_format);
+        with_spell_menu(editor, SpellField::NewsBody, spell_settings, spell_menu)
     } else {
         // Fallback if no content (shouldn't happen in practice)
         shaped_text(t("news-loading"))
@@ -454,36 +614,51 @@ fn form_view<'a>(
             .into()
     };
 
-    // Image picker
+    // Attachment list
+    let attachment_rows = news_management
+        .form_attachments
+        .iter()
+        .enumerate()
+        .map(|(index, form_attachment)| form_attachment_row(index, form_attachment));
+    let attachment_list = Column::with_children(attachment_rows).spacing(ELEMENT_SPACING);
+
+    // Add image / add file controls (disabled once the post is full)
+    let can_add = news_management.form_attachments.len() < MAX_NEWS_ATTACHMENTS;
+    let file_path = news_management.form_file_path.trim();
+
     let pick_image_button = button(shaped_text(t("button-choose-image")).size(TEXT_SIZE))
-        .on_press(Message::NewsPickImagePressed)
         .padding(BUTTON_PADDING)
         .style(btn::secondary);
-
-    let clear_image_button = if !news_management.form_image.is_empty() {
-        button(shaped_text(t("button-clear-image")).size(TEXT_SIZE))
-            .on_press(Message::NewsClearImagePressed)
-            .padding(BUTTON_PADDING)
-            .style(btn::secondary)
+    let pick_image_button = if can_add {
+        pick_image_button.on_press(Message::NewsPickImagePressed)
     } else {
-        button(shaped_text(t("button-clear-image")).size(TEXT_SIZE))
-            .padding(BUTTON_PADDING)
-            .style(btn::secondary)
+        pick_image_button
     };
 
-    let image_buttons = row![pick_image_button, clear_image_button].spacing(ELEMENT_SPACING);
+    let file_path_input = text_input(
+        &t("placeholder-news-file-path"),
+        &news_management.form_file_path,
+    )
+    .on_input(Message::NewsFilePathChanged)
+    .on_submit(Message::NewsAddFilePressed)
+    .padding(INPUT_PADDING)
+    .size(TEXT_SIZE)
+    .width(Fill);
 
-    // Image preview if present
-    let image_row: Element<'a, Message> = if let Some(cached) = &news_management.cached_form_image {
-        let image_preview = render_cached_image_preview(cached);
-        row![image_preview, image_buttons]
-            .spacing(ELEMENT_SPACING)
-            .align_y(Center)
-            .into()
+    let add_file_button = button(shaped_text(t("button-add-file")).size(TEXT_SIZE))
+        .padding(BUTTON_PADDING)
+        .style(btn::secondary);
+    let add_file_button = if can_add && !file_path.is_empty() {
+        add_file_button.on_press(Message::NewsAddFilePressed)
     } else {
-        image_buttons.into()
+        add_file_button
     };
 
+    let add_row: Element<'a, Message> = row![pick_image_button, file_path_input, add_file_button]
+        .spacing(ELEMENT_SPACING)
+        .align_y(Center)
+        .into();
+
     // Submit button (Create or Save)
     let submit_button = if has_content {
         button(
@@ -530,8 +705,12 @@ fn form_view<'a>(
         items.push(Space::new().height(SPACER_SIZE_MEDIUM).into());
     }
 
+    if !news_management.form_attachments.is_empty() {
+        items.push(attachment_list.into());
+    }
+
     items.extend([
-        image_row,
+        add_row,
         Space::new().height(SPACER_SIZE_SMALL).into(),
         body_editor,
         Space::new().height(SPACER_SIZE_MEDIUM).into(),
@@ -548,6 +727,44 @@ fn form_view<'a>(
     scrollable_panel(form)
 }
 
+/// Build a form row for an attachment (preview, caption input, remove button)
+fn form_attachment_row(index: usize, form_attachment: &NewsFormAttachment) -> Element<'_, Message> {
+    let caption_input = text_input(
+        &t("placeholder-news-caption"),
+        form_attachment.attachment.caption().unwrap_or_default(),
+    )
+    .on_input(move |caption| Message::NewsAttachmentCaptionChanged(index, caption))
+    .padding(INPUT_PADDING)
+    .size(TEXT_SIZE)
+    .width(Fill);
+
+    let details: Element<'_, Message> = match &form_attachment.attachment {
+        NewsAttachment::Image { .. } => caption_input.into(),
+        NewsAttachment::File { path, .. } => column![
+            row![
+                icon::file().size(TEXT_SIZE),
+                shaped_text(path).size(TEXT_SIZE)
+            ]
+            .spacing(SMALL_SPACING)
+            .align_y(Center),
+            caption_input,
+        ]
+        .spacing(SMALL_SPACING)
+        .into(),
+    };
+
+    let remove_button = button(shaped_text(t("button-remove")).size(TEXT_SIZE))
+        .on_press(Message::NewsRemoveAttachment(index))
+        .padding(BUTTON_PADDING)
+        .style(btn::secondary);
+
+    let mut attachment_row = Row::new().spacing(ELEMENT_SPACING).align_y(Center);
+    if let Some(cached) = &form_attachment.cached_image {
+        attachment_row = attachment_row.push(render_cached_image_preview(cached));
+    }
+    attachment_row.push(details).push(remove_button).into()
+}
+
 // ============================================================================
 // Delete Confirmation Modal
 // ============================================================================
@@ -620,11 +837,25 @@ pub fn news_view<'a>(
     news_management: &'a NewsManagementState,
     theme: &Theme,
     body_content: Option<&'a text_editor::Content>,
+    spell_settings: &SpellSettings,
+    spell_menu: Option<&'a SpellMenu>,
 ) -> Element<'a, Message> {
     match &news_management.mode {
         NewsManagementMode::List => list_view(conn, news_management, theme, &conn.news_image_cache),
-        NewsManagementMode::Create => form_view(news_management, body_content, false),
-        NewsManagementMode::Edit { .. } => form_view(news_management, body_content, true),
+        NewsManagementMode::Create => form_view(
+            news_management,
+            body_content,
+            spell_settings,
+            spell_menu,
+            false,
+        ),
+        NewsManagementMode::Edit { .. } => form_view(
+            news_management,
+            body_content,
+            spell_settings,
+            spell_menu,
+            true,
+        ),
         NewsManagementMode::ConfirmDelete { .. } => {
             confirm_delete_modal(news_management.delete_error.as_ref())
         }
@@ -0,0 +1,110 @@
+//! Password vault dialog view (create or unlock the master passphrase)
+
+use iced::widget::button as btn;
+use iced::widget::{Column, Id, Space, button, row, text_input};
+use iced::{Center, Element, Fill, Length};
+
+use super::layout::scrollable_modal;
+use crate::i18n::t;
+use crate::style::{
+    BUTTON_PADDING, CONTENT_MAX_WIDTH, CONTENT_PADDING, ELEMENT_SPACING, INPUT_PADDING,
+    SPACER_SIZE_MEDIUM, SPACER_SIZE_SMALL, TEXT_SIZE, error_text_style, panel_title, shaped_text,
+    shaped_text_wrapped,
+};
+use crate::types::{InputId, Message, PasswordVaultDialog, PasswordVaultMode};
+
+/// Create the password vault dialog
+pub fn password_vault_dialog(dialog: &PasswordVaultDialog) -> Element<'_, Message> {
+    let (title, description, submit_label) = match dialog.mode {
+        PasswordVaultMode::Create => (
+            t("title-create-password-vault"),
+            t("password-vault-create-description"),
+            t("button-create"),
+        ),
+        PasswordVaultMode::Unlock => (
+            t("title-unlock-password-vault"),
+            t("password-vault-unlock-description"),
+            t("button-unlock"),
+        ),
+        PasswordVaultMode::UnlockConfig => (
+            t("title-unlock-config"),
+            t("config-unlock-description"),
+            t("button-unlock"),
+        ),
+    };
+
+    let mut items: Vec<Element<'_, Message>> = vec![
+        panel_title(title).into(),
+        Space::new().height(SPACER_SIZE_SMALL).into(),
+        shaped_text_wrapped(description).size(TEXT_SIZE).into(),
+    ];
+
+    if let Some(error) = &dialog.error {
+        items.push(
+            shaped_text_wrapped(error)
+                .size(TEXT_SIZE)
+                .width(Fill)
+                .align_x(Center)
+                .style(error_text_style)
+                .into(),
+        );
+    }
+    items.push(Space::new().height(SPACER_SIZE_SMALL).into());
+
+    items.push(
+        text_input(&t("placeholder-master-passphrase"), &dialog.passphrase)
+            .on_input(Message::PasswordVaultPassphraseChanged)
+            .on_submit(Message::PasswordVaultSubmit)
+            .id(Id::from(InputId::VaultPassphrase))
+            .secure(true)
+            .padding(INPUT_PADDING)
+            .size(TEXT_SIZE)
+            .into(),
+    );
+
+    if dialog.mode == PasswordVaultMode::Create {
+        items.push(
+            text_input(&t("placeholder-confirm-master-passphrase"), &dialog.confirm)
+                .on_input(Message::PasswordVaultConfirmChanged)
+                .on_submit(Message::PasswordVaultSubmit)
+                .id(Id::from(InputId::VaultPassphraseConfirm))
+                .secure(true)
+                .padding(INPUT_PADDING)
+                .size(TEXT_SIZE)
+                .into(),
+        );
+    }
+
+    let skip_button = button(
+        shaped_text(t("button-skip"))
+            .size(TEXT_SIZE)
+            .width(Length::Fill)
+            .center(),
+    )
+    .on_press(Message::PasswordVaultSkip)
+    .padding(BUTTON_PADDING)
+    .style(btn::secondary);
+
+    let submit_button = button(
+        shaped_text(submit_label)
+            .size(TEXT_SIZE)
+            .width(Length::Fill)
+            .center(),
+    )
+    .on_press(Message::PasswordVaultSubmit)
+    .padding(BUTTON_PADDING);
+
+    items.push(Space::new().height(SPACER_SIZE_MEDIUM).into());
+    items.push(
+        row![Space::new().width(Length::Fill), skip_button, submit_button]
+            .spacing(ELEMENT_SPACING)
+            .into(),
+    );
+
+    let dialog = Column::with_children(items)
+        .spacing(ELEMENT_SPACING)
+        .padding(CONTENT_PADDING)
+        .max_width(CONTENT_MAX_WIDTH);
+
+    scrollable_modal(dialog)
+}
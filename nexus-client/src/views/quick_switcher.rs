@@ -0,0 +1,112 @@
+//! Quick switcher overlay (Ctrl+K)
+
+use iced::widget::text::Wrapping;
+use iced::widget::{Column, Id, Space, button, container, mouse_area, opaque, row, text_input};
+use iced::{Center, Element, Fill, Padding};
+
+use crate::i18n::t;
+use crate::icon;
+use crate::quick_switcher::{QuickSwitchItem, QuickSwitchTarget};
+use crate::style::{
+    CONTEXT_MENU_ITEM_PADDING, CONTEXT_MENU_PADDING, DETAIL_TEXT_SIZE, ELEMENT_SPACING,
+    INPUT_PADDING, QUICK_SWITCHER_MAX_RESULTS, QUICK_SWITCHER_TOP_OFFSET, QUICK_SWITCHER_WIDTH,
+    TEXT_SIZE, context_menu_container_style, list_item_button_style, modal_overlay_style,
+    muted_text_style, shaped_text,
+};
+use crate::types::{ActivePanel, ChatTab, InputId, Message, QuickSwitcherState};
+
+/// Icon shown beside a quick switcher entry
+fn target_icon<'a>(target: &QuickSwitchTarget) -> iced::widget::Text<'a> {
+    match target {
+        QuickSwitchTarget::Tab(_, ChatTab::UserMessage(_) | ChatTab::Group(_)) => icon::message(),
+        QuickSwitchTarget::Tab(..) => icon::chat(),
+        QuickSwitchTarget::Bookmark(_) => icon::bookmark(),
+        QuickSwitchTarget::Panel(_, panel) => match panel {
+            ActivePanel::Files => icon::folder(),
+            ActivePanel::News => icon::newspaper(),
+            ActivePanel::Broadcast => icon::megaphone(),
+            ActivePanel::UserManagement => icon::users(),
+            ActivePanel::ConnectionMonitor => icon::desktop(),
+            ActivePanel::ServerLog => icon::terminal(),
+            ActivePanel::ServerInfo => icon::server(),
+            ActivePanel::Transfers => icon::exchange(),
+            ActivePanel::Settings => icon::cog(),
+            _ => icon::info_circled(),
+        },
+    }
+}
+
+/// One match in the list
+fn match_row(index: usize, item: QuickSwitchItem, selected: bool) -> Element<'static, Message> {
+    let content = row![
+        target_icon(&item.target).size(TEXT_SIZE),
+        shaped_text(item.label)
+            .size(TEXT_SIZE)
+            .wrapping(Wrapping::None),
+        Space::new().width(Fill),
+        shaped_text(item.detail)
+            .size(DETAIL_TEXT_SIZE)
+            .wrapping(Wrapping::None)
+            .style(muted_text_style),
+    ]
+    .spacing(ELEMENT_SPACING)
+    .align_y(Center);
+
+    button(content)
+        .on_press(Message::QuickSwitcherChosen(index))
+        .padding(CONTEXT_MENU_ITEM_PADDING)
+        .width(Fill)
+        .style(list_item_button_style(selected, false))
+        .into()
+}
+
+/// Quick switcher drawn over the window; clicking outside it closes it
+pub fn quick_switcher_view(
+    state: &QuickSwitcherState,
+    matches: Vec<QuickSwitchItem>,
+) -> Element<'static, Message> {
+    let input = text_input(&t("quick-switcher-placeholder"), &state.query)
+        .on_input(Message::QuickSwitcherQueryChanged)
+        .on_submit(Message::QuickSwitcherSubmitted)
+        .id(Id::from(InputId::QuickSwitcher))
+        .padding(INPUT_PADDING)
+        .size(TEXT_SIZE);
+
+    let mut list = Column::new().push(input).spacing(CONTEXT_MENU_PADDING);
+    if matches.is_empty() {
+        list = list.push(
+            container(
+                shaped_text(t("quick-switcher-no-matches"))
+                    .size(TEXT_SIZE)
+                    .style(muted_text_style),
+            )
+            .padding(CONTEXT_MENU_ITEM_PADDING),
+        );
+    }
+    for (index, item) in matches
+        .into_iter()
+        .take(QUICK_SWITCHER_MAX_RESULTS)
+        .enumerate()
+    {
+        list = list.push(match_row(index, item, index == state.selected));
+    }
+
+    let card = container(list)
+        .width(QUICK_SWITCHER_WIDTH)
+        .padding(CONTEXT_MENU_PADDING)
+        .style(context_menu_container_style);
+
+    let backdrop = container(opaque(card))
+        .width(Fill)
+        .height(Fill)
+        .center_x(Fill)
+        .padding(Padding {
+            top: QUICK_SWITCHER_TOP_OFFSET,
+            right: 0.0,
+            bottom: 0.0,
+            left: 0.0,
+        })
+        .style(modal_overlay_style);
+
+    opaque(mouse_area(backdrop).on_press(Message::QuickSwitcherClosed))
+}
@@ -0,0 +1,151 @@
+//! Screenshot annotation editor view
+//!
+//! Modal overlay shown after a capture. The preview is scaled to fit the
+//! editor; dragging over it crops or draws an arrow depending on the selected
+//! tool, and Send uploads the result and posts a link to the chat tab.
+
+use iced::widget::{Column, Space, button, column, container, image, mouse_area, row, stack};
+use iced::{Center, Element, Fill, mouse};
+
+use super::layout::scrollable_panel;
+use crate::i18n::t;
+use crate::screenshot::annotate::Tool;
+use crate::style::{
+    BUTTON_PADDING, CONTENT_MAX_WIDTH, CONTENT_PADDING, ELEMENT_SPACING, SPACER_SIZE_MEDIUM,
+    SPACER_SIZE_SMALL, TEXT_SIZE, error_text_style, muted_text_style, panel_title,
+    screenshot_selection_style, shaped_text, shaped_text_wrapped,
+};
+use crate::types::{Message, ScreenshotEditorState};
+
+// ============================================================================
+// Main View Function
+// ============================================================================
+
+/// Displays the screenshot annotation editor
+pub fn screenshot_editor_view(state: &ScreenshotEditorState) -> Element<'_, Message> {
+    let mut form_items: Vec<Element<'_, Message>> = vec![
+        panel_title(t("screenshot-title")).into(),
+        Space::new().height(SPACER_SIZE_MEDIUM).into(),
+    ];
+
+    // Tool selection
+    let tool_row = row![
+        tool_button(state, Tool::Crop, t("screenshot-tool-crop")),
+        tool_button(state, Tool::Arrow, t("screenshot-tool-arrow")),
+    ]
+    .spacing(ELEMENT_SPACING);
+    form_items.push(tool_row.into());
+
+    let hint = match state.tool {
+        Tool::Crop => t("screenshot-hint-crop"),
+        Tool::Arrow => t("screenshot-hint-arrow"),
+    };
+    form_items.push(
+        shaped_text_wrapped(hint)
+            .size(TEXT_SIZE)
+            .style(muted_text_style)
+            .into(),
+    );
+    form_items.push(Space::new().height(SPACER_SIZE_SMALL).into());
+
+    form_items.push(container(preview(state)).width(Fill).align_x(Center).into());
+    form_items.push(Space::new().height(SPACER_SIZE_MEDIUM).into());
+
+    // Error message (if any)
+    if let Some(ref error) = state.error {
+        form_items.push(
+            shaped_text_wrapped(error)
+                .size(TEXT_SIZE)
+                .width(Fill)
+                .align_x(Center)
+                .style(error_text_style)
+                .into(),
+        );
+        form_items.push(Space::new().height(SPACER_SIZE_SMALL).into());
+    }
+
+    // Buttons (disabled while the image is being saved)
+    let undo_button = button(shaped_text(t("button-undo")).size(TEXT_SIZE))
+        .padding(BUTTON_PADDING)
+        .style(button::secondary);
+    let undo_button = if !state.undo.is_empty() && !state.sending {
+        undo_button.on_press(Message::ScreenshotUndo)
+    } else {
+        undo_button
+    };
+
+    let cancel_button = button(shaped_text(t("button-cancel")).size(TEXT_SIZE))
+        .on_press(Message::ScreenshotCancel)
+        .padding(BUTTON_PADDING)
+        .style(button::secondary);
+
+    let send_button = button(shaped_text(t("button-send")).size(TEXT_SIZE)).padding(BUTTON_PADDING);
+    let send_button = if state.sending {
+        send_button
+    } else {
+        send_button.on_press(Message::ScreenshotSend)
+    };
+
+    let button_row = row![
+        undo_button,
+        Space::new().width(Fill),
+        cancel_button,
+        send_button
+    ]
+    .spacing(ELEMENT_SPACING);
+
+    form_items.push(button_row.into());
+
+    let form = Column::with_children(form_items)
+        .spacing(ELEMENT_SPACING)
+        .padding(CONTENT_PADDING)
+        .max_width(CONTENT_MAX_WIDTH);
+
+    scrollable_panel(form)
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Tool toggle button (highlighted when selected)
+fn tool_button(state: &ScreenshotEditorState, tool: Tool, label: String) -> Element<'_, Message> {
+    let style = if state.tool == tool {
+        button::primary
+    } else {
+        button::secondary
+    };
+    button(shaped_text(label).size(TEXT_SIZE))
+        .on_press(Message::ScreenshotToolSelected(tool))
+        .padding(BUTTON_PADDING)
+        .style(style)
+        .into()
+}
+
+/// Scaled preview with pointer tracking and the crop selection overlay
+fn preview(state: &ScreenshotEditorState) -> Element<'_, Message> {
+    let (width, height) = state.preview_size();
+
+    let mut layers = stack![image(state.handle.clone()).width(width).height(height)]
+        .width(width)
+        .height(height);
+
+    if let Some((x, y, selection_width, selection_height)) = state.crop_selection() {
+        let selection = container(Space::new())
+            .width(selection_width)
+            .height(selection_height)
+            .style(screenshot_selection_style);
+        layers = layers.push(column![
+            Space::new().height(y),
+            row![Space::new().width(x), selection]
+        ]);
+    }
+
+    mouse_area(layers)
+        .on_move(Message::ScreenshotPointerMoved)
+        .on_press(Message::ScreenshotPointerPressed)
+        .on_release(Message::ScreenshotPointerReleased)
+        .on_exit(Message::ScreenshotPointerReleased)
+        .interaction(mouse::Interaction::Crosshair)
+        .into()
+}
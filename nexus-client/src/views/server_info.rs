@@ -1,9 +1,10 @@
 //! Server info panel view
 
 use iced::widget::button as btn;
-use iced::widget::{Id, Space, button, container, image, row, svg, text_input};
+use iced::widget::{Id, Space, button, checkbox, container, image, row, svg, text, text_input};
 use iced::{Center, Element, Fill, Length};
 use iced_aw::{NumberInput, TabLabel, Tabs};
+use nexus_common::protocol::{CheckedService, NatInfo, NatMethod, PortCheck, PortReachability};
 
 use super::layout::scrollable_panel;
 use crate::i18n::{t, t_args};
@@ -14,7 +15,7 @@ use crate::style::{
     TAB_LABEL_PADDING, TEXT_SIZE, error_text_style, muted_text_style, panel_title, shaped_text,
     shaped_text_wrapped,
 };
-use crate::types::{InputId, Message, ServerInfoEditState, ServerInfoTab};
+use crate::types::{ConnectivityCheckState, InputId, Message, ServerInfoEditState, ServerInfoTab};
 
 /// Data needed to render the server info panel
 pub struct ServerInfoData<'a> {
@@ -28,22 +29,81 @@ pub struct ServerInfoData<'a> {
     pub max_connections_per_ip: Option<u32>,
     /// Max transfers per IP (all users)
     pub max_transfers_per_ip: Option<u32>,
+    /// Max active transfers server-wide (admin only, 0 = unlimited)
+    pub max_active_transfers: Option<u32>,
+    /// Whether queued transfers favour small files (admin only)
+    pub transfer_small_files_first: Option<bool>,
+    /// Fair-share weight for transfers from trusted IPs (admin only)
+    pub trusted_transfer_weight: Option<u32>,
     /// File reindex interval in minutes (admins + file_reindex permission, 0 = disabled)
     pub file_reindex_interval: Option<u32>,
     /// Persistent channels (space-separated, admin only)
     pub persistent_channels: Option<String>,
     /// Auto-join channels (space-separated, users with chat_join permission + admins)
     pub auto_join_channels: Option<String>,
+    /// NAT port mapping status (admin only, None if UPnP is disabled)
+    pub nat: Option<NatInfo>,
+    /// Last connectivity check (admin only)
+    pub connectivity_check: &'a ConnectivityCheckState,
     /// Cached server image for display (None if no image set)
     pub cached_server_image: Option<&'a CachedImage>,
     /// Whether the current user is an admin
     pub is_admin: bool,
+    /// Whether the current user can run doors
+    pub can_play_doors: bool,
     /// Active tab in display mode (shown based on available data)
     pub active_tab: ServerInfoTab,
     /// Edit state (Some when in edit mode)
     pub edit_state: Option<&'a ServerInfoEditState>,
 }
 
+/// Port mapping method name (protocol names aren't translated)
+fn nat_method_name(method: NatMethod) -> &'static str {
+    match method {
+        NatMethod::Upnp => "UPnP",
+        NatMethod::Pcp => "PCP",
+        NatMethod::NatPmp => "NAT-PMP",
+    }
+}
+
+/// One row of a connectivity check result (`BBS (TCP 7500): Open`)
+fn port_check_row(port: &PortCheck) -> Element<'static, Message> {
+    let (service_key, protocol) = match port.service {
+        CheckedService::Bbs => ("check-service-bbs", "TCP"),
+        CheckedService::Transfer => ("check-service-transfer", "TCP"),
+        CheckedService::Websocket => ("check-service-websocket", "TCP"),
+        CheckedService::TransferWebsocket => ("check-service-transfer-websocket", "TCP"),
+        CheckedService::Voice => ("check-service-voice", "UDP"),
+    };
+    let label = t_args(
+        "label-check-port",
+        &[
+            ("service", &t(service_key)),
+            ("protocol", protocol),
+            ("port", &port.port.to_string()),
+        ],
+    );
+    let state = match port.state {
+        PortReachability::Open => shaped_text(t("check-state-open")).size(TEXT_SIZE),
+        PortReachability::Closed => shaped_text(t("check-state-closed"))
+            .size(TEXT_SIZE)
+            .style(error_text_style),
+        PortReachability::Filtered => shaped_text(t("check-state-filtered"))
+            .size(TEXT_SIZE)
+            .style(error_text_style),
+        PortReachability::Untested => shaped_text(t("check-state-untested"))
+            .size(TEXT_SIZE)
+            .style(muted_text_style),
+    };
+    row![
+        shaped_text(label).size(TEXT_SIZE),
+        Space::new().width(ELEMENT_SPACING),
+        state,
+    ]
+    .align_y(Center)
+    .into()
+}
+
 /// Render the server info panel
 ///
 /// Displays server information received during login.
@@ -104,7 +164,10 @@ fn server_info_display_view(data: &ServerInfoData<'_>) -> Element<'static, Messa
     }
 
     // Determine which tabs to show based on available data
-    let has_limits = data.max_connections_per_ip.is_some() || data.max_transfers_per_ip.is_some();
+    let has_limits = data.max_connections_per_ip.is_some()
+        || data.max_transfers_per_ip.is_some()
+        || data.max_active_transfers.is_some()
+        || data.nat.is_some();
     let has_files = data.file_reindex_interval.is_some();
     let has_channels = data.persistent_channels.is_some() || data.auto_join_channels.is_some();
 
@@ -139,6 +202,127 @@ fn server_info_display_view(data: &ServerInfoData<'_>) -> Element<'static, Messa
                     .into(),
                 );
             }
+            if let Some(max_active) = data.max_active_transfers {
+                let value = if max_active == 0 {
+                    t("label-unlimited")
+                } else {
+                    max_active.to_string()
+                };
+                content_items.push(
+                    row![
+                        shaped_text(t("label-active-transfers-short")).size(TEXT_SIZE),
+                        Space::new().width(ELEMENT_SPACING),
+                        shaped_text(value).size(TEXT_SIZE),
+                    ]
+                    .align_y(Center)
+                    .into(),
+                );
+            }
+            if let Some(small_first) = data.transfer_small_files_first {
+                let value = if small_first {
+                    t("label-queue-order-small-first")
+                } else {
+                    t("label-queue-order-arrival")
+                };
+                content_items.push(
+                    row![
+                        shaped_text(t("label-queue-order-short")).size(TEXT_SIZE),
+                        Space::new().width(ELEMENT_SPACING),
+                        shaped_text(value).size(TEXT_SIZE),
+                    ]
+                    .align_y(Center)
+                    .into(),
+                );
+            }
+            if let Some(weight) = data.trusted_transfer_weight {
+                content_items.push(
+                    row![
+                        shaped_text(t("label-trusted-weight-short")).size(TEXT_SIZE),
+                        Space::new().width(ELEMENT_SPACING),
+                        shaped_text(format!("×{weight}")).size(TEXT_SIZE),
+                    ]
+                    .align_y(Center)
+                    .into(),
+                );
+            }
+            if let Some(nat) = &data.nat {
+                let value = match nat.method {
+                    Some(method) if nat.mapped => nat_method_name(method).to_string(),
+                    _ => t("label-nat-not-mapped"),
+                };
+                content_items.push(
+                    row![
+                        shaped_text(t("label-nat-mapping-short")).size(TEXT_SIZE),
+                        Space::new().width(ELEMENT_SPACING),
+                        shaped_text(value).size(TEXT_SIZE),
+                    ]
+                    .align_y(Center)
+                    .into(),
+                );
+                if let Some(external_ip) = &nat.external_ip {
+                    content_items.push(
+                        row![
+                            shaped_text(t("label-nat-external-ip-short")).size(TEXT_SIZE),
+                            Space::new().width(ELEMENT_SPACING),
+                            shaped_text(external_ip.clone()).size(TEXT_SIZE),
+                        ]
+                        .align_y(Center)
+                        .into(),
+                    );
+                }
+                if let Some(error) = &nat.error {
+                    content_items.push(
+                        shaped_text_wrapped(error.clone())
+                            .size(TEXT_SIZE)
+                            .width(Fill)
+                            .style(error_text_style)
+                            .into(),
+                    );
+                }
+            }
+            if data.is_admin {
+                let running = matches!(data.connectivity_check, ConnectivityCheckState::Running);
+                let check_button = button(shaped_text(t("button-check-ports")).size(TEXT_SIZE))
+                    .padding(BUTTON_PADDING)
+                    .style(btn::secondary);
+                let check_button = if running {
+                    check_button
+                } else {
+                    check_button.on_press(Message::ConnectivityCheckPressed)
+                };
+                content_items.push(Space::new().height(SPACER_SIZE_SMALL).into());
+                content_items.push(check_button.into());
+                match data.connectivity_check {
+                    ConnectivityCheckState::Idle => {}
+                    ConnectivityCheckState::Running => content_items.push(
+                        shaped_text(t("connectivity-check-running"))
+                            .size(TEXT_SIZE)
+                            .style(muted_text_style)
+                            .into(),
+                    ),
+                    ConnectivityCheckState::Done { external_ip, ports } => {
+                        if let Some(external_ip) = external_ip {
+                            content_items.push(
+                                row![
+                                    shaped_text(t("label-nat-external-ip-short")).size(TEXT_SIZE),
+                                    Space::new().width(ELEMENT_SPACING),
+                                    shaped_text(external_ip.clone()).size(TEXT_SIZE),
+                                ]
+                                .align_y(Center)
+                                .into(),
+                            );
+                        }
+                        content_items.extend(ports.iter().map(port_check_row));
+                    }
+                    ConnectivityCheckState::Failed(error) => content_items.push(
+                        shaped_text_wrapped(error.clone())
+                            .size(TEXT_SIZE)
+                            .width(Fill)
+                            .style(error_text_style)
+                            .into(),
+                    ),
+                }
+            }
             iced::widget::Column::with_children(content_items)
                 .spacing(ELEMENT_SPACING)
                 .into()
@@ -252,28 +436,43 @@ fn server_info_display_view(data: &ServerInfoData<'_>) -> Element<'static, Messa
 
     items.push(Space::new().height(SPACER_SIZE_MEDIUM).into());
 
-    // Buttons: Edit (admin only, secondary) and Close (primary)
-    let buttons = if data.is_admin {
-        row![
-            Space::new().width(Fill),
-            button(shaped_text(t("button-edit")).size(TEXT_SIZE))
-                .on_press(Message::EditServerInfoPressed)
+    // Buttons: Doors (door_play), Statistics and Edit (admin only) are secondary,
+    // Close is primary
+    let mut buttons = row![Space::new().width(Fill)].spacing(ELEMENT_SPACING);
+    if data.can_play_doors {
+        buttons = buttons.push(
+            button(shaped_text(t("button-doors")).size(TEXT_SIZE))
+                .on_press(Message::ShowDoors)
                 .padding(BUTTON_PADDING)
                 .style(btn::secondary),
-            button(shaped_text(t("button-close")).size(TEXT_SIZE))
-                .on_press(Message::CloseServerInfo)
-                .padding(BUTTON_PADDING),
-        ]
-        .spacing(ELEMENT_SPACING)
-    } else {
-        row![
-            Space::new().width(Fill),
-            button(shaped_text(t("button-close")).size(TEXT_SIZE))
-                .on_press(Message::CloseServerInfo)
-                .padding(BUTTON_PADDING),
-        ]
-        .spacing(ELEMENT_SPACING)
-    };
+        );
+    }
+    if data.is_admin {
+        buttons = buttons
+            .push(
+                button(shaped_text(t("button-dashboard")).size(TEXT_SIZE))
+                    .on_press(Message::ShowAdminDashboard)
+                    .padding(BUTTON_PADDING)
+                    .style(btn::secondary),
+            )
+            .push(
+                button(shaped_text(t("button-statistics")).size(TEXT_SIZE))
+                    .on_press(Message::ShowStatistics)
+                    .padding(BUTTON_PADDING)
+                    .style(btn::secondary),
+            )
+            .push(
+                button(shaped_text(t("button-edit")).size(TEXT_SIZE))
+                    .on_press(Message::EditServerInfoPressed)
+                    .padding(BUTTON_PADDING)
+                    .style(btn::secondary),
+            );
+    }
+    let buttons = buttons.push(
+        button(shaped_text(t("button-close")).size(TEXT_SIZE))
+            .on_press(Message::CloseServerInfo)
+            .padding(BUTTON_PADDING),
+    );
 
     items.push(buttons.into());
 
@@ -445,6 +644,59 @@ fn server_info_edit_view(edit_state: &ServerInfoEditState) -> Element<'static, M
         .into(),
     );
 
+    // Server-wide active transfer limit and queue ordering
+    let active_label = shaped_text(t("label-active-transfers-short")).size(TEXT_SIZE);
+    let max_active_value = edit_state.max_active_transfers.unwrap_or(0);
+    let max_active_input: Element<'static, Message> = NumberInput::new(
+        &max_active_value,
+        0..=u32::MAX,
+        Message::EditServerInfoMaxActiveTransfersChanged,
+    )
+    .id(Id::from(InputId::EditServerInfoMaxActiveTransfers))
+    .padding(INPUT_PADDING)
+    .into();
+    let small_first_checkbox: Element<'static, Message> =
+        checkbox(edit_state.transfer_small_files_first)
+            .label(t("label-small-files-first"))
+            .on_toggle(Message::EditServerInfoSmallFilesFirstToggled)
+            .size(TEXT_SIZE)
+            .text_shaping(text::Shaping::Advanced)
+            .into();
+
+    form_items.push(
+        row![
+            active_label,
+            Space::new().width(ELEMENT_SPACING),
+            max_active_input,
+            Space::new().width(SPACER_SIZE_LARGE),
+            small_first_checkbox,
+        ]
+        .align_y(Center)
+        .into(),
+    );
+
+    // Fair-share weight for trusted IPs
+    let trusted_weight_label = shaped_text(t("label-trusted-weight-short")).size(TEXT_SIZE);
+    let trusted_weight_value = edit_state.trusted_transfer_weight.unwrap_or(1);
+    let trusted_weight_input: Element<'static, Message> = NumberInput::new(
+        &trusted_weight_value,
+        1..=u32::MAX,
+        Message::EditServerInfoTrustedTransferWeightChanged,
+    )
+    .id(Id::from(InputId::EditServerInfoTrustedTransferWeight))
+    .padding(INPUT_PADDING)
+    .into();
+
+    form_items.push(
+        row![
+            trusted_weight_label,
+            Space::new().width(ELEMENT_SPACING),
+            trusted_weight_input,
+        ]
+        .align_y(Center)
+        .into(),
+    );
+
     form_items.push(Space::new().height(SPACER_SIZE_MEDIUM).into());
 
     // Files subheading
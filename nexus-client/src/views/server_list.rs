@@ -3,23 +3,34 @@
 use std::collections::HashMap;
 
 use iced::widget::text::Wrapping;
-use iced::widget::{Column, Space, button, column, container, row, scrollable, tooltip};
-use iced::{Element, Fill, alignment};
+use iced::widget::{
+    Column, Id, Space, button, column, container, mouse_area, row, scrollable, text, text_input,
+    tooltip,
+};
+use iced::{Element, Fill, Theme, alignment, mouse};
 use uuid::Uuid;
 
-use crate::i18n::t;
+use crate::i18n::{t, t_args};
 use crate::icon;
 use crate::style::{
-    CONTENT_PADDING, ICON_BUTTON_PADDING, INPUT_PADDING, NO_SPACING, PANEL_SPACING,
-    SCROLLBAR_PADDING, SECTION_TITLE_SIZE, SEPARATOR_HEIGHT, SERVER_LIST_BUTTON_HEIGHT,
-    SERVER_LIST_DISCONNECT_ICON_SIZE, SERVER_LIST_ITEM_SPACING, SERVER_LIST_PANEL_WIDTH,
-    SERVER_LIST_SECTION_SPACING, SERVER_LIST_SMALL_TEXT_SIZE, SERVER_LIST_TEXT_SIZE,
-    SIDEBAR_ACTION_ICON_SIZE, TOOLTIP_BACKGROUND_PADDING, TOOLTIP_GAP, TOOLTIP_PADDING,
-    TOOLTIP_TEXT_SIZE, alternating_row_style, danger_icon_button_style, list_item_button_style,
+    CONTENT_PADDING, CONTEXT_MENU_ITEM_PADDING, CONTEXT_MENU_MIN_WIDTH, CONTEXT_MENU_PADDING,
+    CONTEXT_MENU_SEPARATOR_HEIGHT, CONTEXT_MENU_SEPARATOR_MARGIN, ICON_BUTTON_PADDING,
+    INPUT_PADDING, NO_SPACING, PANEL_SPACING, SCROLLBAR_PADDING, SECTION_TITLE_SIZE,
+    SEPARATOR_HEIGHT, SERVER_LIST_BUTTON_HEIGHT, SERVER_LIST_DISCONNECT_ICON_SIZE,
+    SERVER_LIST_DRAG_HANDLE_WIDTH, SERVER_LIST_DROP_INDICATOR_HEIGHT, SERVER_LIST_FOLDER_INDENT,
+    SERVER_LIST_ITEM_SPACING, SERVER_LIST_PANEL_WIDTH, SERVER_LIST_SECTION_SPACING,
+    SERVER_LIST_SMALL_TEXT_SIZE, SERVER_LIST_TEXT_SIZE, SIDEBAR_ACTION_ICON_SIZE, TEXT_SIZE,
+    TOOLTIP_BACKGROUND_PADDING, TOOLTIP_GAP, TOOLTIP_PADDING, TOOLTIP_TEXT_SIZE,
+    alternating_row_style, context_menu_container_style, danger_icon_button_style,
+    drop_indicator_style, list_item_button_style, menu_button_danger_style, menu_button_style,
     muted_text_style, separator_style, shaped_text, sidebar_panel_style, tooltip_container_style,
     transparent_icon_button_style,
 };
-use crate::types::{Message, ServerBookmark, ServerConnection};
+use crate::types::{
+    BookmarkDropTarget, BookmarkGroup, BookmarkGroupEdit, ConnectionHealth, HealthLevel, InputId,
+    Message, ServerBookmark, ServerConnection, UiState,
+};
+use crate::widgets::{LazyContextMenu, MenuButton};
 
 // ============================================================================
 // Helper Functions
@@ -49,6 +60,55 @@ fn transparent_edit_button(
         .style(transparent_icon_button_style)
 }
 
+/// Colored dot showing the connection health (muted until the first ping)
+fn health_dot<'a>(level: Option<HealthLevel>) -> Element<'a, Message> {
+    shaped_text("●")
+        .size(SERVER_LIST_SMALL_TEXT_SIZE)
+        .style(move |theme: &Theme| {
+            let palette = theme.extended_palette();
+            let color = match level {
+                Some(HealthLevel::Good) => palette.success.base.color,
+                Some(HealthLevel::Fair) => palette.warning.base.color,
+                Some(HealthLevel::Poor) => palette.danger.base.color,
+                None => return muted_text_style(theme),
+            };
+            text::Style { color: Some(color) }
+        })
+        .into()
+}
+
+/// Tooltip text for the health dot (rating, latency, jitter and last drop)
+fn health_tooltip(health: &ConnectionHealth) -> String {
+    let Some(level) = health.level() else {
+        return t("connection-health-measuring");
+    };
+
+    let mut lines = vec![t_args(
+        "connection-health-tooltip",
+        &[("quality", &t(level.translation_key()))],
+    )];
+    if let Some(latency) = health.latency() {
+        lines.push(t_args(
+            "connection-health-latency",
+            &[("ms", &latency.as_millis().to_string())],
+        ));
+    }
+    if let Some(jitter) = health.jitter() {
+        lines.push(t_args(
+            "connection-health-jitter",
+            &[("ms", &jitter.as_millis().to_string())],
+        ));
+    }
+    lines.push(match health.last_drop() {
+        Some(time) => t_args(
+            "connection-health-last-drop",
+            &[("time", &time.format("%H:%M:%S").to_string())],
+        ),
+        None => t("connection-health-no-drops"),
+    });
+    lines.join("\n")
+}
+
 /// Create a horizontal separator line
 fn separator<'a>() -> Element<'a, Message> {
     container(Space::new().width(Fill).height(SEPARATOR_HEIGHT))
@@ -87,16 +147,29 @@ fn connected_servers_section<'a>(
         for (index, (conn_id, conn)) in conn_list.iter().enumerate() {
             let is_active = active_connection == Some(**conn_id);
 
-            // Transparent button with hover effect and primary color for active
-            let btn = button(
+            // Transparent button with hover effect and primary color for active,
+            // led by the health dot (tooltip shows latency details)
+            let label = row![
+                health_dot(conn.health.level()),
                 shaped_text(&conn.display_name)
                     .size(SERVER_LIST_TEXT_SIZE)
                     .wrapping(Wrapping::WordOrGlyph),
+            ]
+            .spacing(SERVER_LIST_ITEM_SPACING)
+            .align_y(alignment::Vertical::Center);
+            let btn = tooltip(
+                button(label)
+                    .width(Fill)
+                    .padding(INPUT_PADDING)
+                    .on_press(Message::SwitchToConnection(**conn_id))
+                    .style(list_item_button_style(is_active, false)),
+                container(shaped_text(health_tooltip(&conn.health)).size(TOOLTIP_TEXT_SIZE))
+                    .padding(TOOLTIP_BACKGROUND_PADDING)
+                    .style(tooltip_container_style),
+                tooltip::Position::Bottom,
             )
-            .width(Fill)
-            .padding(INPUT_PADDING)
-            .on_press(Message::SwitchToConnection(**conn_id))
-            .style(list_item_button_style(is_active, false));
+            .gap(TOOLTIP_GAP)
+            .padding(TOOLTIP_PADDING);
 
             // Disconnect button (transparent icon button with hover effect)
             let disconnect_btn = tooltip(
@@ -132,87 +205,348 @@ fn connected_servers_section<'a>(
 // Bookmarks Section
 // ============================================================================
 
+/// What bookmark rows need besides the bookmark itself
+struct BookmarkRowContext<'a> {
+    connections: &'a HashMap<usize, ServerConnection>,
+    bookmark_errors: &'a HashMap<Uuid, String>,
+    /// Whether a bookmark is being dragged
+    dragging: bool,
+    /// Where the dragged bookmark would land if dropped now
+    drop_target: Option<BookmarkDropTarget>,
+}
+
+/// Line showing where a dragged bookmark will land
+fn drop_indicator<'a>() -> Element<'a, Message> {
+    container(
+        Space::new()
+            .width(Fill)
+            .height(SERVER_LIST_DROP_INDICATOR_HEIGHT),
+    )
+    .width(Fill)
+    .height(SERVER_LIST_DROP_INDICATOR_HEIGHT)
+    .style(drop_indicator_style)
+    .into()
+}
+
+/// Make content a drop target while a bookmark is being dragged
+fn drop_target<'a>(
+    content: impl Into<Element<'a, Message>>,
+    target: BookmarkDropTarget,
+    dragging: bool,
+) -> Element<'a, Message> {
+    let area = mouse_area(content);
+    if dragging {
+        area.on_enter(Message::BookmarkDragHovered(target)).into()
+    } else {
+        area.into()
+    }
+}
+
+/// Build a bookmark row: drag handle, name (connect or switch) and edit button
+fn bookmark_row<'a>(
+    bookmark: &'a ServerBookmark,
+    ctx: &BookmarkRowContext<'a>,
+    is_even: bool,
+    indent: f32,
+) -> Element<'a, Message> {
+    let bookmark_id = bookmark.id;
+
+    // Check if this bookmark is currently connected
+    let is_connected = ctx
+        .connections
+        .iter()
+        .any(|(_, conn)| conn.bookmark_id == Some(bookmark_id));
+
+    // Check if this bookmark has an error
+    let has_error = ctx.bookmark_errors.contains_key(&bookmark_id);
+
+    // Determine message based on whether bookmark is currently connected
+    let bookmark_message = if let Some(conn_id) = ctx
+        .connections
+        .iter()
+        .find(|(_, conn)| conn.bookmark_id == Some(bookmark_id))
+        .map(|(id, _)| *id)
+    {
+        // Bookmark is connected - switch to it
+        Message::SwitchToConnection(conn_id)
+    } else {
+        // Not connected - connect to it
+        Message::ConnectToBookmark(bookmark_id)
+    };
+
+    // Drag handle for moving the bookmark within and between folders
+    let handle = mouse_area(
+        container(
+            shaped_text("≡")
+                .size(SERVER_LIST_TEXT_SIZE)
+                .style(muted_text_style),
+        )
+        .width(SERVER_LIST_DRAG_HANDLE_WIDTH)
+        .align_x(alignment::Horizontal::Center),
+    )
+    .on_press(Message::BookmarkDragStarted(bookmark_id))
+    .interaction(mouse::Interaction::Grab);
+
+    // Transparent button with hover effect
+    // Show in danger color if there's an error, primary color if connected, normal otherwise
+    let btn = button(
+        shaped_text(&bookmark.name)
+            .size(SERVER_LIST_TEXT_SIZE)
+            .wrapping(Wrapping::WordOrGlyph),
+    )
+    .width(Fill)
+    .padding(INPUT_PADDING)
+    .on_press(bookmark_message)
+    .style(list_item_button_style(is_connected, has_error));
+
+    // Action button (transparent icon button with hover effect)
+    let edit_btn = tooltip(
+        transparent_edit_button(icon::cog(), Message::ShowEditBookmark(bookmark_id)),
+        container(shaped_text(t("tooltip-edit")).size(TOOLTIP_TEXT_SIZE))
+            .padding(TOOLTIP_BACKGROUND_PADDING)
+            .style(tooltip_container_style),
+        tooltip::Position::Right,
+    )
+    .gap(TOOLTIP_GAP)
+    .padding(TOOLTIP_PADDING);
+
+    let bookmark_row = row![Space::new().width(indent), handle, btn, edit_btn]
+        .spacing(NO_SPACING)
+        .align_y(alignment::Vertical::Center);
+
+    // Alternating row backgrounds
+    container(bookmark_row)
+        .width(Fill)
+        .style(alternating_row_style(is_even))
+        .into()
+}
+
+/// Add the bookmarks of one folder (or outside any folder) to the list
+///
+/// Hovering a row while dragging places the dragged bookmark above it, and
+/// the indicator line shows where it will land.
+fn push_bookmark_rows<'a>(
+    mut list: Column<'a, Message>,
+    bookmarks: impl Iterator<Item = &'a ServerBookmark>,
+    group: Option<Uuid>,
+    ctx: &BookmarkRowContext<'a>,
+    row_index: &mut usize,
+) -> Column<'a, Message> {
+    let indent = if group.is_some() {
+        SERVER_LIST_FOLDER_INDENT
+    } else {
+        0.0
+    };
+
+    for bookmark in bookmarks {
+        let target = BookmarkDropTarget::Before(bookmark.id);
+        if ctx.drop_target == Some(target) {
+            list = list.push(drop_indicator());
+        }
+        let row = bookmark_row(bookmark, ctx, *row_index % 2 == 0, indent);
+        list = list.push(drop_target(row, target, ctx.dragging));
+        *row_index += 1;
+    }
+
+    if ctx.drop_target == Some(BookmarkDropTarget::End(group)) {
+        list = list.push(drop_indicator());
+    }
+    list
+}
+
+/// Build a folder header: collapse toggle with the folder's name and bookmark count
+///
+/// Right-click offers Connect All, Rename and Delete.
+fn group_header<'a>(group: &'a BookmarkGroup, count: usize) -> Element<'a, Message> {
+    let chevron = if group.collapsed {
+        icon::expand_right()
+    } else {
+        icon::down_dir()
+    };
+
+    let label = row![
+        chevron
+            .size(SERVER_LIST_SMALL_TEXT_SIZE)
+            .style(muted_text_style),
+        icon::folder().size(SERVER_LIST_TEXT_SIZE),
+        shaped_text(&group.name)
+            .size(SERVER_LIST_TEXT_SIZE)
+            .wrapping(Wrapping::WordOrGlyph)
+            .width(Fill),
+        shaped_text(count.to_string())
+            .size(SERVER_LIST_SMALL_TEXT_SIZE)
+            .style(muted_text_style),
+    ]
+    .spacing(SERVER_LIST_ITEM_SPACING)
+    .align_y(alignment::Vertical::Center);
+
+    let header = button(label)
+        .width(Fill)
+        .padding(INPUT_PADDING)
+        .on_press(Message::BookmarkGroupToggled(group.id))
+        .style(list_item_button_style(false, false));
+
+    let group_id = group.id;
+    LazyContextMenu::new(header, move || group_context_menu(group_id)).into()
+}
+
+/// Build the right-click menu for a folder header
+fn group_context_menu<'a>(group_id: Uuid) -> Element<'a, Message> {
+    let menu_items: Vec<Element<'a, Message>> = vec![
+        MenuButton::new(shaped_text(t("menu-connect-all")).size(TEXT_SIZE))
+            .padding(CONTEXT_MENU_ITEM_PADDING)
+            .width(Fill)
+            .style(menu_button_style)
+            .on_press(Message::BookmarkGroupConnectAll(group_id))
+            .into(),
+        MenuButton::new(shaped_text(t("menu-rename")).size(TEXT_SIZE))
+            .padding(CONTEXT_MENU_ITEM_PADDING)
+            .width(Fill)
+            .style(menu_button_style)
+            .on_press(Message::BookmarkGroupRename(group_id))
+            .into(),
+        container(Space::new())
+            .width(Fill)
+            .height(CONTEXT_MENU_SEPARATOR_HEIGHT)
+            .style(separator_style)
+            .into(),
+        // Deleting a folder keeps its bookmarks
+        MenuButton::new(shaped_text(t("menu-delete")).size(TEXT_SIZE))
+            .padding(CONTEXT_MENU_ITEM_PADDING)
+            .width(Fill)
+            .style(menu_button_danger_style)
+            .on_press(Message::BookmarkGroupDelete(group_id))
+            .into(),
+    ];
+
+    container(Column::with_children(menu_items).spacing(CONTEXT_MENU_SEPARATOR_MARGIN))
+        .width(CONTEXT_MENU_MIN_WIDTH)
+        .padding(CONTEXT_MENU_PADDING)
+        .style(context_menu_container_style)
+        .into()
+}
+
+/// Name input shown in place of a folder header (or below the folders for a new one)
+fn group_name_input(edit: &BookmarkGroupEdit) -> Element<'_, Message> {
+    text_input(&t("placeholder-folder-name"), &edit.name)
+        .on_input(Message::BookmarkGroupNameChanged)
+        .on_submit(Message::BookmarkGroupNameSubmitted)
+        .id(Id::from(InputId::BookmarkGroupName))
+        .padding(INPUT_PADDING)
+        .size(SERVER_LIST_TEXT_SIZE)
+        .into()
+}
+
+/// Icon button at the bottom of the bookmarks section
+fn sidebar_action_button<'a>(
+    icon: iced::widget::Text<'a>,
+    message: Message,
+    tooltip_text: String,
+) -> Element<'a, Message> {
+    let action_icon = container(icon.size(SIDEBAR_ACTION_ICON_SIZE))
+        .width(SIDEBAR_ACTION_ICON_SIZE)
+        .height(SIDEBAR_ACTION_ICON_SIZE)
+        .align_x(alignment::Horizontal::Center)
+        .align_y(alignment::Vertical::Center);
+
+    tooltip(
+        button(action_icon)
+            .on_press(message)
+            .padding(ICON_BUTTON_PADDING)
+            .style(transparent_icon_button_style),
+        container(shaped_text(tooltip_text).size(TOOLTIP_TEXT_SIZE))
+            .padding(TOOLTIP_BACKGROUND_PADDING)
+            .style(tooltip_container_style),
+        tooltip::Position::Top,
+    )
+    .gap(TOOLTIP_GAP)
+    .padding(TOOLTIP_PADDING)
+    .into()
+}
+
 /// Build the bookmarks section
+///
+/// Folders come first, each followed by its bookmarks unless collapsed, then
+/// the bookmarks outside any folder. Bookmarks keep the order they were
+/// dragged into.
 fn bookmarks_section<'a>(
     bookmarks: &'a [ServerBookmark],
+    bookmark_groups: &'a [BookmarkGroup],
     connections: &'a HashMap<usize, ServerConnection>,
     bookmark_errors: &'a HashMap<Uuid, String>,
+    ui_state: &'a UiState,
 ) -> Column<'a, Message> {
     let bookmarks_title = shaped_text(t("title-bookmarks"))
         .size(SECTION_TITLE_SIZE)
         .style(muted_text_style);
 
     let mut bookmarks_column = Column::new().spacing(SERVER_LIST_ITEM_SPACING);
+    let group_edit = ui_state.bookmark_group_edit.as_ref();
 
-    if bookmarks.is_empty() {
+    if bookmarks.is_empty() && bookmark_groups.is_empty() && group_edit.is_none() {
         bookmarks_column = bookmarks_column.push(
             shaped_text(t("empty-no-bookmarks"))
                 .size(SERVER_LIST_SMALL_TEXT_SIZE)
                 .style(muted_text_style),
         );
     } else {
-        // Sort bookmarks alphabetically by name (case-insensitive)
-        let mut sorted_bookmarks: Vec<_> = bookmarks.iter().collect();
-        sorted_bookmarks.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
-
-        for (index, bookmark) in sorted_bookmarks.into_iter().enumerate() {
-            let bookmark_id = bookmark.id;
-
-            // Check if this bookmark is currently connected
-            let is_connected = connections
+        let ctx = BookmarkRowContext {
+            connections,
+            bookmark_errors,
+            dragging: ui_state.bookmark_drag.is_some(),
+            drop_target: ui_state.bookmark_drag.as_ref().and_then(|drag| drag.target),
+        };
+        let mut row_index = 0;
+
+        for group in bookmark_groups {
+            let group_id = group.id;
+            let in_group = bookmarks
                 .iter()
-                .any(|(_, conn)| conn.bookmark_id == Some(bookmark_id));
+                .filter(move |bookmark| bookmark.group == Some(group_id));
 
-            // Check if this bookmark has an error
-            let has_error = bookmark_errors.contains_key(&bookmark_id);
-
-            // Determine message based on whether bookmark is currently connected
-            let bookmark_message = if let Some(conn_id) = connections
-                .iter()
-                .find(|(_, conn)| conn.bookmark_id == Some(bookmark_id))
-                .map(|(id, _)| *id)
-            {
-                // Bookmark is connected - switch to it
-                Message::SwitchToConnection(conn_id)
-            } else {
-                // Not connected - connect to it
-                Message::ConnectToBookmark(bookmark_id)
+            // Hovering the header while dragging drops at the end of the folder
+            let header = match group_edit {
+                Some(edit) if edit.group == Some(group_id) => group_name_input(edit),
+                _ => group_header(group, in_group.clone().count()),
             };
+            bookmarks_column = bookmarks_column.push(drop_target(
+                header,
+                BookmarkDropTarget::End(Some(group_id)),
+                ctx.dragging,
+            ));
+
+            let collapsed = group.collapsed;
+            bookmarks_column = push_bookmark_rows(
+                bookmarks_column,
+                in_group.filter(move |_| !collapsed),
+                Some(group_id),
+                &ctx,
+                &mut row_index,
+            );
+        }
 
-            // Transparent button with hover effect
-            // Show in danger color if there's an error, primary color if connected, normal otherwise
-            let btn = button(
-                shaped_text(&bookmark.name)
-                    .size(SERVER_LIST_TEXT_SIZE)
-                    .wrapping(Wrapping::WordOrGlyph),
-            )
-            .width(Fill)
-            .padding(INPUT_PADDING)
-            .on_press(bookmark_message)
-            .style(list_item_button_style(is_connected, has_error));
-
-            // Action button (transparent icon button with hover effect)
-            let edit_btn = tooltip(
-                transparent_edit_button(icon::cog(), Message::ShowEditBookmark(bookmark_id)),
-                container(shaped_text(t("tooltip-edit")).size(TOOLTIP_TEXT_SIZE))
-                    .padding(TOOLTIP_BACKGROUND_PADDING)
-                    .style(tooltip_container_style),
-                tooltip::Position::Right,
-            )
-            .gap(TOOLTIP_GAP)
-            .padding(TOOLTIP_PADDING);
-
-            let bookmark_row = row![btn, edit_btn]
-                .spacing(NO_SPACING)
-                .align_y(alignment::Vertical::Center);
-
-            // Alternating row backgrounds
-            let is_even = index % 2 == 0;
-            let row_container = container(bookmark_row)
-                .width(Fill)
-                .style(alternating_row_style(is_even));
+        if let Some(edit) = group_edit
+            && edit.group.is_none()
+        {
+            bookmarks_column = bookmarks_column.push(group_name_input(edit));
+        }
 
-            bookmarks_column = bookmarks_column.push(row_container);
+        // Bookmarks whose folder no longer exists are listed with the rest
+        let ungrouped = bookmarks.iter().filter(|bookmark| {
+            bookmark
+                .group
+                .is_none_or(|id| !bookmark_groups.iter().any(|group| group.id == id))
+        });
+        bookmarks_column =
+            push_bookmark_rows(bookmarks_column, ungrouped, None, &ctx, &mut row_index);
+
+        // While dragging, the space below the list drops at the very end
+        if ctx.dragging {
+            bookmarks_column = bookmarks_column.push(drop_target(
+                Space::new().width(Fill).height(SERVER_LIST_BUTTON_HEIGHT),
+                BookmarkDropTarget::End(None),
+                true,
+            ));
         }
     }
 
@@ -226,30 +560,25 @@ fn bookmarks_section<'a>(
         })
         .width(Fill);
 
-    // Add bookmark button
-    let add_icon = container(icon::bookmark().size(SIDEBAR_ACTION_ICON_SIZE))
-        .width(SIDEBAR_ACTION_ICON_SIZE)
-        .height(SIDEBAR_ACTION_ICON_SIZE)
-        .align_x(alignment::Horizontal::Center)
-        .align_y(alignment::Vertical::Center);
-
-    let add_btn = tooltip(
-        button(add_icon)
-            .on_press(Message::ShowAddBookmark)
-            .padding(ICON_BUTTON_PADDING)
-            .style(transparent_icon_button_style),
-        container(shaped_text(t("tooltip-add-bookmark")).size(TOOLTIP_TEXT_SIZE))
-            .padding(TOOLTIP_BACKGROUND_PADDING)
-            .style(tooltip_container_style),
-        tooltip::Position::Top,
-    )
-    .gap(TOOLTIP_GAP)
-    .padding(TOOLTIP_PADDING);
+    // Add bookmark and new folder buttons
+    let actions = row![
+        sidebar_action_button(
+            icon::bookmark(),
+            Message::ShowAddBookmark,
+            t("tooltip-add-bookmark")
+        ),
+        sidebar_action_button(
+            icon::folder(),
+            Message::BookmarkGroupNew,
+            t("tooltip-new-folder")
+        ),
+    ]
+    .spacing(NO_SPACING);
 
     column![
         bookmarks_title,
         scrollable(bookmarks_column).height(Fill),
-        Element::from(add_btn),
+        actions,
     ]
     .spacing(SERVER_LIST_SECTION_SPACING)
     .padding(iced::Padding {
@@ -268,17 +597,26 @@ fn bookmarks_section<'a>(
 ///
 /// Shows two sections: connected servers (top) and bookmarks (bottom).
 /// Connected servers can be switched between or disconnected. Bookmarks can be
-/// connected to, edited, or deleted. A separator divides the two sections.
+/// connected to, edited, deleted, dragged into order, or grouped in folders.
+/// A separator divides the two sections.
 pub fn server_list_panel<'a>(
     bookmarks: &'a [ServerBookmark],
+    bookmark_groups: &'a [BookmarkGroup],
     connections: &'a HashMap<usize, ServerConnection>,
     active_connection: Option<usize>,
     bookmark_errors: &'a HashMap<Uuid, String>,
+    ui_state: &'a UiState,
 ) -> Element<'a, Message> {
     let main_column = column![
         connected_servers_section(connections, active_connection),
         separator(),
-        bookmarks_section(bookmarks, connections, bookmark_errors),
+        bookmarks_section(
+            bookmarks,
+            bookmark_groups,
+            connections,
+            bookmark_errors,
+            ui_state
+        ),
     ]
     .spacing(PANEL_SPACING);
 
@@ -0,0 +1,157 @@
+//! Server Log panel view
+//!
+//! Shows recent and live server log lines, newest at the bottom, with a
+//! minimum level picker and a module filter.
+
+use chrono::{Local, TimeZone};
+use iced::widget::{Column, Space, column, container, pick_list, row, scrollable, text_input};
+use iced::{Center, Element, Fill};
+use nexus_common::protocol::{LogEntry, LogLevel};
+
+use crate::i18n::t;
+use crate::style::{
+    CONTENT_MAX_WIDTH, CONTENT_PADDING, ELEMENT_SPACING, INPUT_PADDING, MONOSPACE_FONT,
+    SCROLLBAR_PADDING, SPACER_SIZE_MEDIUM, SPACER_SIZE_SMALL, TEXT_SIZE, content_background_style,
+    error_text_style, muted_text_style, panel_title, shaped_text, shaped_text_wrapped,
+    warning_text_style,
+};
+use crate::types::{Message, ServerLogLevel, ServerLogState};
+
+/// Width of the level picker
+const LEVEL_PICKER_WIDTH: f32 = 160.0;
+
+/// Width of the level tag column (fits "DEBUG")
+const LEVEL_COLUMN_WIDTH: f32 = 50.0;
+
+impl std::fmt::Display for ServerLogLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", t(self.translation_key()))
+    }
+}
+
+/// Level tag shown in each line (log levels aren't translated, like the server's output)
+fn level_tag(level: LogLevel) -> &'static str {
+    match level {
+        LogLevel::Error => "ERROR",
+        LogLevel::Warn => "WARN",
+        LogLevel::Info => "INFO",
+        LogLevel::Debug => "DEBUG",
+    }
+}
+
+/// Format a Unix timestamp as local time of day
+fn format_time(timestamp: i64) -> String {
+    Local
+        .timestamp_opt(timestamp, 0)
+        .single()
+        .map(|time| time.format("%H:%M:%S").to_string())
+        .unwrap_or_default()
+}
+
+/// A single log line
+fn log_line(entry: &LogEntry) -> Element<'_, Message> {
+    let level = shaped_text(level_tag(entry.level))
+        .size(TEXT_SIZE)
+        .font(MONOSPACE_FONT)
+        .width(LEVEL_COLUMN_WIDTH);
+    let level = match entry.level {
+        LogLevel::Error => level.style(error_text_style),
+        LogLevel::Warn => level.style(warning_text_style),
+        LogLevel::Info => level,
+        LogLevel::Debug => level.style(muted_text_style),
+    };
+
+    row![
+        shaped_text(format_time(entry.timestamp))
+            .size(TEXT_SIZE)
+            .font(MONOSPACE_FONT)
+            .style(muted_text_style),
+        level,
+        shaped_text(&entry.module)
+            .size(TEXT_SIZE)
+            .style(muted_text_style),
+        shaped_text_wrapped(&entry.message)
+            .size(TEXT_SIZE)
+            .width(Fill),
+    ]
+    .spacing(ELEMENT_SPACING)
+    .into()
+}
+
+/// Centered status text (loading, empty)
+fn status_text<'a>(text: String) -> Element<'a, Message> {
+    container(shaped_text(text).size(TEXT_SIZE).style(muted_text_style))
+        .width(Fill)
+        .center_x(Fill)
+        .padding(SPACER_SIZE_SMALL)
+        .into()
+}
+
+/// Server Log panel
+pub fn server_log_view(state: &ServerLogState) -> Element<'_, Message> {
+    let level_picker = pick_list(
+        ServerLogLevel::all(),
+        Some(ServerLogLevel(state.level)),
+        Message::ServerLogLevelSelected,
+    )
+    .text_size(TEXT_SIZE)
+    .padding(INPUT_PADDING)
+    .width(LEVEL_PICKER_WIDTH);
+
+    let module_input = text_input(&t("placeholder-server-log-module"), &state.module_filter)
+        .on_input(Message::ServerLogModuleFilterChanged)
+        .padding(INPUT_PADDING)
+        .size(TEXT_SIZE)
+        .width(Fill);
+
+    let filters = row![level_picker, module_input]
+        .spacing(ELEMENT_SPACING)
+        .align_y(Center);
+
+    let content: Element<'_, Message> = if let Some(error) = &state.error {
+        container(
+            shaped_text_wrapped(error)
+                .size(TEXT_SIZE)
+                .style(error_text_style),
+        )
+        .width(Fill)
+        .center_x(Fill)
+        .padding(SPACER_SIZE_SMALL)
+        .into()
+    } else if state.loading {
+        status_text(t("server-log-loading"))
+    } else {
+        let lines: Vec<Element<'_, Message>> = state.visible_entries().map(log_line).collect();
+        if lines.is_empty() {
+            status_text(t("server-log-empty"))
+        } else {
+            // Anchored to the bottom so new lines stay in view
+            scrollable(
+                Column::with_children(lines)
+                    .spacing(SPACER_SIZE_SMALL)
+                    .padding([0.0, SCROLLBAR_PADDING]),
+            )
+            .anchor_bottom()
+            .height(Fill)
+            .into()
+        }
+    };
+
+    let form = column![
+        panel_title(t("panel-server-log")),
+        filters,
+        Space::new().height(SPACER_SIZE_SMALL),
+        container(content).height(Fill),
+    ]
+    .spacing(SPACER_SIZE_MEDIUM)
+    .align_x(Center)
+    .padding(CONTENT_PADDING)
+    .max_width(CONTENT_MAX_WIDTH)
+    .height(Fill);
+
+    container(container(form).width(Fill).center_x(Fill))
+        .width(Fill)
+        .height(Fill)
+        .style(content_background_style)
+        .into()
+}
@@ -3,12 +3,13 @@
 use iced::Element;
 use iced::Fill;
 use iced::widget::button as btn;
-use iced::widget::{Column, Space, button, checkbox, pick_list, row};
+use iced::widget::{Column, Space, button, checkbox, pick_list, row, slider};
 
 use super::AudioTabData;
 use crate::config::audio::{
     LocalizedVoiceQuality, MicBoost, NoiseSuppressionLevel, PttMode, PttReleaseDelay,
 };
+use crate::config::settings::{SOUND_VOLUME_MAX, SOUND_VOLUME_MIN};
 use crate::i18n::t;
 use crate::style::{
     BUTTON_PADDING, ELEMENT_SPACING, INPUT_PADDING, SPACER_SIZE_MEDIUM, SPACER_SIZE_SMALL,
@@ -71,6 +72,39 @@ pub(super) fn audio_tab_content(data: AudioTabData<'_>) -> Element<'_, Message>
 
     items.push(Space::new().height(SPACER_SIZE_MEDIUM).into());
 
+    // Soundboard clip volume slider with label and percentage
+    let clip_volume_percent = (data.clip_volume * 100.0).round() as u8;
+    let clip_volume_label = shaped_text(t("audio-clip-volume")).size(TEXT_SIZE);
+    let clip_volume_value = shaped_text(format!("{}%", clip_volume_percent)).size(TEXT_SIZE);
+    let clip_volume_slider = slider(
+        SOUND_VOLUME_MIN..=SOUND_VOLUME_MAX,
+        data.clip_volume,
+        Message::AudioClipVolumeChanged,
+    )
+    .step(0.01);
+
+    let clip_volume_row = row![
+        clip_volume_label,
+        Space::new().width(ELEMENT_SPACING),
+        clip_volume_slider,
+        Space::new().width(ELEMENT_SPACING),
+        clip_volume_value,
+    ]
+    .spacing(ELEMENT_SPACING)
+    .align_y(iced::Alignment::Center);
+    items.push(clip_volume_row.into());
+
+    items.push(Space::new().height(SPACER_SIZE_SMALL).into());
+
+    // Read voice text messages aloud
+    let voice_text_tts_checkbox = checkbox(data.voice_text_tts)
+        .label(t("audio-voice-text-tts"))
+        .on_toggle(Message::AudioVoiceTextTts)
+        .text_size(TEXT_SIZE);
+    items.push(voice_text_tts_checkbox.into());
+
+    items.push(Space::new().height(SPACER_SIZE_MEDIUM).into());
+
     // Voice quality picker
     let quality_label = shaped_text(t("audio-voice-quality")).size(TEXT_SIZE);
     let quality_options = LocalizedVoiceQuality::all();
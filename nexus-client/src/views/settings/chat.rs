@@ -1,18 +1,24 @@
-//! Chat settings tab (history, font size, timestamps, notifications)
+//! Chat settings tab (history, font size, timestamps, notifications, split view,
+//! spell checking, read receipts, user list)
 
 use iced::widget::{Column, Space, checkbox, pick_list, row};
 use iced::{Center, Element, Fill};
 use iced_aw::NumberInput;
 
-use crate::config::settings::{CHAT_FONT_SIZES, ChatHistoryRetention};
+use crate::config::settings::{
+    CHAT_FONT_SIZES, ChatHistoryRetention, UserListGrouping, UserListSettings, UserListSort,
+};
 use crate::i18n::t;
 use crate::style::{
-    CHECKBOX_INDENT, ELEMENT_SPACING, INPUT_PADDING, SPACER_SIZE_MEDIUM, TEXT_SIZE, shaped_text,
+    CHECKBOX_INDENT, ELEMENT_SPACING, INPUT_PADDING, SPACER_SIZE_MEDIUM, TEXT_SIZE,
+    muted_text_style, shaped_text, shaped_text_wrapped,
 };
 use crate::types::Message;
 use crate::views::chat::TimestampSettings;
 
-/// Build the Chat tab content (font size, notifications, timestamps)
+/// Build the Chat tab content (font size, notifications, timestamps, split view,
+/// spell checking, read receipts, user list grouping and sorting)
+#[allow(clippy::too_many_arguments)]
 pub(super) fn chat_tab_content(
     chat_history_retention: ChatHistoryRetention,
     max_scrollback: usize,
@@ -20,6 +26,11 @@ pub(super) fn chat_tab_content(
     show_connection_events: bool,
     show_join_leave_events: bool,
     timestamp_settings: TimestampSettings,
+    split_view: bool,
+    spell_check: bool,
+    spell_dictionary_missing: bool,
+    send_read_receipts: bool,
+    user_list: UserListSettings,
 ) -> Element<'static, Message> {
     let mut items: Vec<Element<'_, Message>> = Vec::new();
 
@@ -115,6 +126,59 @@ pub(super) fn chat_tab_content(
     let seconds_row = row![Space::new().width(CHECKBOX_INDENT), seconds_checkbox];
     items.push(seconds_row.into());
 
+    // Split view checkbox
+    let split_view_checkbox = checkbox(split_view)
+        .label(t("label-split-view"))
+        .on_toggle(Message::SplitViewToggled)
+        .text_size(TEXT_SIZE);
+    items.push(split_view_checkbox.into());
+
+    // Spell check checkbox, with a note when there's no dictionary to use
+    let spell_check_checkbox = checkbox(spell_check)
+        .label(t("label-spell-check"))
+        .on_toggle(Message::SpellCheckToggled)
+        .text_size(TEXT_SIZE);
+    items.push(spell_check_checkbox.into());
+    if spell_check && spell_dictionary_missing {
+        let note = shaped_text_wrapped(t("label-spell-check-no-dictionary"))
+            .size(TEXT_SIZE)
+            .style(muted_text_style);
+        items.push(row![Space::new().width(CHECKBOX_INDENT), note].into());
+    }
+
+    // Read receipts checkbox
+    let read_receipts_checkbox = checkbox(send_read_receipts)
+        .label(t("label-send-read-receipts"))
+        .on_toggle(Message::ReadReceiptsToggled)
+        .text_size(TEXT_SIZE);
+    items.push(read_receipts_checkbox.into());
+
+    // User list grouping picker row
+    let grouping_label = shaped_text(t("label-user-list-grouping")).size(TEXT_SIZE);
+    let grouping_picker = pick_list(
+        UserListGrouping::ALL,
+        Some(user_list.grouping),
+        Message::UserListGroupingSelected,
+    )
+    .text_size(TEXT_SIZE);
+    let grouping_row = row![grouping_label, grouping_picker]
+        .spacing(ELEMENT_SPACING)
+        .align_y(Center);
+    items.push(grouping_row.into());
+
+    // User list sort picker row
+    let sort_label = shaped_text(t("label-user-list-sort")).size(TEXT_SIZE);
+    let sort_picker = pick_list(
+        UserListSort::ALL,
+        Some(user_list.sort),
+        Message::UserListSortSelected,
+    )
+    .text_size(TEXT_SIZE);
+    let sort_row = row![sort_label, sort_picker]
+        .spacing(ELEMENT_SPACING)
+        .align_y(Center);
+    items.push(sort_row.into());
+
     Column::with_children(items)
         .spacing(ELEMENT_SPACING)
         .width(Fill)
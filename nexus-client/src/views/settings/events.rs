@@ -1,19 +1,38 @@
 //! Events settings tab (notifications, toasts, sounds per event type)
 
+use std::fmt;
+
 use iced::Element;
 use iced::Fill;
 use iced::widget::button as btn;
-use iced::widget::{Column, Space, button, checkbox, pick_list, row, slider};
+use iced::widget::{Column, Space, button, checkbox, pick_list, row, slider, text_input};
+use uuid::Uuid;
 
-use crate::config::events::{EventSettings, EventType, NotificationContent, SoundChoice};
+use super::{DoNotDisturbData, NotificationRulesData};
+use crate::config::events::{
+    ChannelNotification, EventSettings, EventType, NotificationContent, QuietHour, SoundChoice,
+};
 use crate::config::settings::{SOUND_VOLUME_MAX, SOUND_VOLUME_MIN};
 use crate::i18n::t;
 use crate::style::{
     ELEMENT_SPACING, INPUT_PADDING, SPACER_SIZE_LARGE, SPACER_SIZE_MEDIUM, SPACER_SIZE_SMALL,
-    TEXT_SIZE, shaped_text,
+    TEXT_SIZE, muted_text_style, shaped_text,
 };
 use crate::types::Message;
 
+/// Bookmark option for the notification rules picker
+#[derive(Debug, Clone, PartialEq)]
+struct BookmarkChoice {
+    id: Uuid,
+    name: String,
+}
+
+impl fmt::Display for BookmarkChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
 /// Build the Events tab content
 pub(super) fn events_tab_content<'a>(
     event_settings: &'a EventSettings,
@@ -21,6 +40,8 @@ pub(super) fn events_tab_content<'a>(
     notifications_enabled: bool,
     sound_enabled: bool,
     sound_volume: f32,
+    dnd: DoNotDisturbData,
+    rules: NotificationRulesData<'a>,
 ) -> Element<'a, Message> {
     let mut items: Vec<Element<'_, Message>> = Vec::new();
 
@@ -75,6 +96,9 @@ pub(super) fn events_tab_content<'a>(
 
     items.push(volume_row.into());
 
+    items.push(Space::new().height(SPACER_SIZE_SMALL).into());
+    do_not_disturb_section(&mut items, dnd);
+
     items.push(Space::new().height(SPACER_SIZE_MEDIUM).into());
 
     // Event type picker with label on same row
@@ -276,8 +300,227 @@ pub(super) fn events_tab_content<'a>(
     .align_y(iced::Alignment::Center);
     items.push(sound_row.into());
 
+    items.push(Space::new().height(SPACER_SIZE_MEDIUM).into());
+    notification_rules_section(&mut items, rules);
+
     Column::with_children(items)
         .spacing(ELEMENT_SPACING)
         .width(Fill)
         .into()
 }
+
+/// Manual do not disturb toggle and quiet hours schedule
+fn do_not_disturb_section(items: &mut Vec<Element<'_, Message>>, data: DoNotDisturbData) {
+    let dnd_checkbox = checkbox(data.enabled)
+        .label(t("settings-do-not-disturb"))
+        .on_toggle(Message::DoNotDisturbToggled)
+        .text_size(TEXT_SIZE)
+        .spacing(ELEMENT_SPACING);
+    items.push(dnd_checkbox.into());
+
+    // Quiet hours checkbox with start and end pickers (disabled when off)
+    let quiet_hours = data.quiet_hours;
+    let quiet_hours_checkbox = checkbox(quiet_hours.enabled)
+        .label(t("settings-quiet-hours"))
+        .on_toggle(Message::QuietHoursToggled)
+        .text_size(TEXT_SIZE)
+        .spacing(ELEMENT_SPACING);
+
+    let start_picker = if quiet_hours.enabled {
+        pick_list(
+            QuietHour::all(),
+            Some(quiet_hours.start),
+            Message::QuietHoursStartSelected,
+        )
+        .text_size(TEXT_SIZE)
+    } else {
+        pick_list(QuietHour::all(), Some(quiet_hours.start), move |_| {
+            Message::QuietHoursStartSelected(quiet_hours.start)
+        })
+        .text_size(TEXT_SIZE)
+    };
+
+    let end_picker = if quiet_hours.enabled {
+        pick_list(
+            QuietHour::all(),
+            Some(quiet_hours.end),
+            Message::QuietHoursEndSelected,
+        )
+        .text_size(TEXT_SIZE)
+    } else {
+        pick_list(QuietHour::all(), Some(quiet_hours.end), move |_| {
+            Message::QuietHoursEndSelected(quiet_hours.end)
+        })
+        .text_size(TEXT_SIZE)
+    };
+
+    let quiet_hours_row = row![
+        quiet_hours_checkbox,
+        Space::new().width(SPACER_SIZE_LARGE),
+        start_picker,
+        shaped_text("–").size(TEXT_SIZE),
+        end_picker,
+    ]
+    .spacing(ELEMENT_SPACING)
+    .align_y(iced::Alignment::Center);
+    items.push(quiet_hours_row.into());
+
+    items.push(
+        shaped_text(t("settings-do-not-disturb-hint"))
+            .size(TEXT_SIZE)
+            .style(muted_text_style)
+            .into(),
+    );
+}
+
+/// Per-server channel overrides and keyword watch list
+fn notification_rules_section<'a>(
+    items: &mut Vec<Element<'a, Message>>,
+    data: NotificationRulesData<'a>,
+) {
+    items.push(
+        shaped_text(t("settings-notification-rules"))
+            .size(TEXT_SIZE)
+            .into(),
+    );
+
+    if data.bookmarks.is_empty() {
+        items.push(
+            shaped_text(t("settings-notification-rules-no-bookmarks"))
+                .size(TEXT_SIZE)
+                .style(muted_text_style)
+                .into(),
+        );
+        return;
+    }
+
+    // Server picker
+    let choices: Vec<BookmarkChoice> = data
+        .bookmarks
+        .iter()
+        .map(|bookmark| BookmarkChoice {
+            id: bookmark.id,
+            name: bookmark.name.clone(),
+        })
+        .collect();
+    let selected_bookmark = data
+        .selected
+        .and_then(|id| data.bookmarks.iter().find(|bookmark| bookmark.id == id));
+    let selected_choice = choices
+        .iter()
+        .find(|choice| Some(choice.id) == data.selected)
+        .cloned();
+    let server_picker = pick_list(choices, selected_choice, |choice| {
+        Message::NotificationRulesBookmarkSelected(choice.id)
+    })
+    .text_size(TEXT_SIZE);
+    let server_row = row![
+        shaped_text(t("settings-notification-rules-server")).size(TEXT_SIZE),
+        Space::new().width(ELEMENT_SPACING),
+        server_picker,
+    ]
+    .align_y(iced::Alignment::Center);
+    items.push(server_row.into());
+
+    let Some(bookmark) = selected_bookmark else {
+        return;
+    };
+    let rules = &bookmark.notification_rules;
+
+    items.push(Space::new().height(SPACER_SIZE_SMALL).into());
+
+    // Channel overrides
+    items.push(
+        shaped_text(t("settings-notification-rules-channels"))
+            .size(TEXT_SIZE)
+            .into(),
+    );
+    for (channel, level) in &rules.channels {
+        let channel_name = channel.clone();
+        let level_picker = pick_list(ChannelNotification::all().to_vec(), Some(*level), {
+            let channel = channel.clone();
+            move |level| Message::NotificationRulesChannelLevelSelected(channel.clone(), level)
+        })
+        .text_size(TEXT_SIZE);
+        let remove_button = button(shaped_text(t("button-remove")).size(TEXT_SIZE))
+            .on_press(Message::NotificationRulesChannelRemove(channel_name))
+            .padding(INPUT_PADDING)
+            .style(btn::secondary);
+        let channel_row = row![
+            shaped_text(channel.clone()).size(TEXT_SIZE).width(Fill),
+            level_picker,
+            remove_button,
+        ]
+        .spacing(ELEMENT_SPACING)
+        .align_y(iced::Alignment::Center);
+        items.push(channel_row.into());
+    }
+
+    let channel_input = text_input(
+        &t("placeholder-notification-rules-channel"),
+        data.channel_input,
+    )
+    .on_input(Message::NotificationRulesChannelChanged)
+    .on_submit(Message::NotificationRulesChannelAdd)
+    .padding(INPUT_PADDING)
+    .size(TEXT_SIZE);
+    let add_channel_button = button(shaped_text(t("button-add")).size(TEXT_SIZE))
+        .padding(INPUT_PADDING)
+        .style(btn::secondary);
+    let add_channel_button = if data.channel_input.trim().is_empty() {
+        add_channel_button
+    } else {
+        add_channel_button.on_press(Message::NotificationRulesChannelAdd)
+    };
+    items.push(
+        row![channel_input, add_channel_button]
+            .spacing(ELEMENT_SPACING)
+            .align_y(iced::Alignment::Center)
+            .into(),
+    );
+
+    items.push(Space::new().height(SPACER_SIZE_SMALL).into());
+
+    // Keyword watch list
+    items.push(
+        shaped_text(t("settings-notification-rules-keywords"))
+            .size(TEXT_SIZE)
+            .into(),
+    );
+    for keyword in &rules.keywords {
+        let remove_button = button(shaped_text(t("button-remove")).size(TEXT_SIZE))
+            .on_press(Message::NotificationRulesKeywordRemove(keyword.clone()))
+            .padding(INPUT_PADDING)
+            .style(btn::secondary);
+        let keyword_row = row![
+            shaped_text(keyword.clone()).size(TEXT_SIZE).width(Fill),
+            remove_button,
+        ]
+        .spacing(ELEMENT_SPACING)
+        .align_y(iced::Alignment::Center);
+        items.push(keyword_row.into());
+    }
+
+    let keyword_input = text_input(
+        &t("placeholder-notification-rules-keyword"),
+        data.keyword_input,
+    )
+    .on_input(Message::NotificationRulesKeywordChanged)
+    .on_submit(Message::NotificationRulesKeywordAdd)
+    .padding(INPUT_PADDING)
+    .size(TEXT_SIZE);
+    let add_keyword_button = button(shaped_text(t("button-add")).size(TEXT_SIZE))
+        .padding(INPUT_PADDING)
+        .style(btn::secondary);
+    let add_keyword_button = if data.keyword_input.trim().is_empty() {
+        add_keyword_button
+    } else {
+        add_keyword_button.on_press(Message::NotificationRulesKeywordAdd)
+    };
+    items.push(
+        row![keyword_input, add_keyword_button]
+            .spacing(ELEMENT_SPACING)
+            .align_y(iced::Alignment::Center)
+            .into(),
+    );
+}
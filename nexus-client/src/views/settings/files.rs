@@ -1,11 +1,11 @@
-//! Files settings tab (download location, transfer queue)
+//! Files settings tab (download location, transfer queue, collisions, completion actions)
 
 use iced::widget::button as btn;
-use iced::widget::{Column, Space, button, checkbox, container, row};
+use iced::widget::{Column, Space, button, checkbox, container, pick_list, row};
 use iced::{Center, Element, Fill};
 use iced_aw::NumberInput;
 
-use crate::config::settings::default_download_path;
+use crate::config::settings::{CollisionPolicy, default_download_path};
 use crate::i18n::t;
 use crate::style::{
     BUTTON_PADDING, ELEMENT_SPACING, INPUT_PADDING, PATH_DISPLAY_PADDING, SPACER_SIZE_MEDIUM,
@@ -19,6 +19,8 @@ pub(super) fn files_tab_content(
     queue_transfers: bool,
     download_limit: u8,
     upload_limit: u8,
+    auto_open_folder: bool,
+    collision_policy: CollisionPolicy,
 ) -> Element<'static, Message> {
     let mut items: Vec<Element<'_, Message>> = Vec::new();
 
@@ -96,6 +98,29 @@ pub(super) fn files_tab_content(
         .align_y(Center);
     items.push(upload_limit_row.into());
 
+    // Collision policy picker
+    let collision_label = shaped_text(t("label-collision-policy")).size(TEXT_SIZE);
+    let collision_picker = pick_list(
+        CollisionPolicy::ALL,
+        Some(collision_policy),
+        Message::CollisionPolicySelected,
+    )
+    .text_size(TEXT_SIZE);
+    let collision_row = row![collision_label, collision_picker]
+        .spacing(ELEMENT_SPACING)
+        .align_y(Center);
+    items.push(collision_row.into());
+
+    // Spacer before completion actions
+    items.push(Space::new().height(SPACER_SIZE_SMALL).into());
+
+    // Auto-open folder checkbox
+    let auto_open_checkbox = checkbox(auto_open_folder)
+        .label(t("label-auto-open-folder"))
+        .on_toggle(Message::AutoOpenFolderToggled)
+        .text_size(TEXT_SIZE);
+    items.push(auto_open_checkbox.into());
+
     Column::with_children(items)
         .spacing(ELEMENT_SPACING)
         .width(Fill)
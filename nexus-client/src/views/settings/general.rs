@@ -1,31 +1,33 @@
-//! General settings tab (theme, avatar, nickname, tray settings)
+//! General settings tab (theme, avatar, nickname, hotkey, auto away, tray settings)
 
 use iced::widget::button as btn;
-#[cfg(not(target_os = "macos"))]
-use iced::widget::checkbox;
-use iced::widget::{Column, Id, Space, button, pick_list, row, text_input};
+use iced::widget::{Column, Id, Space, button, checkbox, pick_list, row, text_input};
 use iced::{Center, Element, Fill};
+use iced_aw::NumberInput;
 
+use crate::config::settings::{AUTO_AWAY_MINUTES_MAX, AUTO_AWAY_MINUTES_MIN, AutoAway};
 use crate::config::theme::all_themes;
 use crate::i18n::t;
 use crate::image::CachedImage;
 use crate::style::{
-    AVATAR_PREVIEW_SIZE, BUTTON_PADDING, ELEMENT_SPACING, INPUT_PADDING, SPACER_SIZE_MEDIUM,
-    TEXT_SIZE, shaped_text,
+    AVATAR_PREVIEW_SIZE, BUTTON_PADDING, CHECKBOX_INDENT, ELEMENT_SPACING, INPUT_PADDING,
+    SPACER_SIZE_MEDIUM, SPACER_SIZE_SMALL, TEXT_SIZE, shaped_text,
 };
-#[cfg(not(target_os = "macos"))]
-use crate::style::{CHECKBOX_INDENT, SPACER_SIZE_SMALL};
 use crate::types::{InputId, Message};
+use crate::voice::ptt::{hotkey_to_string, parse_hotkey};
 use iced::Theme;
 
-/// Build the General tab content (theme, avatar, nickname, tray settings)
+use super::{TrayData, WindowKeyData};
+
+/// Build the General tab content (theme, avatar, nickname, hotkey, auto away, tray settings)
 pub(super) fn general_tab_content<'a>(
     current_theme: Theme,
     avatar: Option<&'a CachedImage>,
     default_avatar: Option<&'a CachedImage>,
     nickname: &'a str,
-    show_tray_icon: bool,
-    minimize_to_tray: bool,
+    tray: TrayData,
+    window_key: WindowKeyData<'a>,
+    auto_away: AutoAway,
 ) -> Element<'a, Message> {
     let mut items: Vec<Element<'_, Message>> = Vec::new();
 
@@ -84,6 +86,72 @@ pub(super) fn general_tab_content<'a>(
         .size(TEXT_SIZE);
     items.push(nickname_input.into());
 
+    // Show/hide window hotkey (global, works while another app is focused)
+    items.push(Space::new().height(SPACER_SIZE_SMALL).into());
+
+    let window_key_label = shaped_text(t("settings-toggle-window-key")).size(TEXT_SIZE);
+    let window_key_display = if window_key.capturing {
+        t("audio-ptt-key-hint")
+    } else {
+        match window_key.key {
+            // Re-format for platform-aware display (e.g., "Cmd" on macOS)
+            Some(key) => match parse_hotkey(key) {
+                Ok((modifiers, code)) => hotkey_to_string(modifiers, code),
+                Err(_) => key.to_string(),
+            },
+            None => t("label-none"),
+        }
+    };
+    let window_key_button = button(shaped_text(window_key_display).size(TEXT_SIZE))
+        .on_press(Message::WindowKeyCapture)
+        .padding(INPUT_PADDING)
+        .style(btn::secondary);
+
+    let clear_window_key_button = button(shaped_text(t("button-clear-hotkey")).size(TEXT_SIZE))
+        .padding(INPUT_PADDING)
+        .style(btn::secondary);
+    let clear_window_key_button = if window_key.key.is_some() {
+        clear_window_key_button.on_press(Message::WindowKeyCleared)
+    } else {
+        clear_window_key_button
+    };
+
+    let window_key_row = row![window_key_label, window_key_button, clear_window_key_button]
+        .spacing(ELEMENT_SPACING)
+        .align_y(Center);
+    items.push(window_key_row.into());
+
+    // Auto away after idle time
+    items.push(Space::new().height(SPACER_SIZE_SMALL).into());
+
+    let auto_away_checkbox = checkbox(auto_away.enabled)
+        .label(t("settings-auto-away"))
+        .on_toggle(Message::AutoAwayToggled)
+        .text_size(TEXT_SIZE);
+    items.push(auto_away_checkbox.into());
+
+    // Idle minutes (disabled when auto away is off)
+    let auto_away_minutes_label = shaped_text(t("settings-auto-away-minutes")).size(TEXT_SIZE);
+    let auto_away_minutes_input = NumberInput::new(
+        &auto_away.minutes,
+        AUTO_AWAY_MINUTES_MIN..=AUTO_AWAY_MINUTES_MAX,
+        Message::AutoAwayMinutesChanged,
+    )
+    .padding(INPUT_PADDING);
+    let auto_away_minutes_input = if auto_away.enabled {
+        auto_away_minutes_input
+    } else {
+        auto_away_minutes_input.on_input_maybe(None::<fn(u16) -> Message>)
+    };
+    let auto_away_minutes_row = row![
+        Space::new().width(CHECKBOX_INDENT),
+        auto_away_minutes_label,
+        auto_away_minutes_input
+    ]
+    .spacing(ELEMENT_SPACING)
+    .align_y(Center);
+    items.push(auto_away_minutes_row.into());
+
     // System tray settings (Windows/Linux only)
     #[cfg(not(target_os = "macos"))]
     {
@@ -91,17 +159,17 @@ pub(super) fn general_tab_content<'a>(
         items.push(Space::new().height(SPACER_SIZE_SMALL).into());
 
         // Show tray icon checkbox
-        let tray_icon_checkbox = checkbox(show_tray_icon)
+        let tray_icon_checkbox = checkbox(tray.show_icon)
             .label(t("settings-show-tray-icon"))
             .on_toggle(Message::ShowTrayIconToggled)
             .text_size(TEXT_SIZE);
         items.push(tray_icon_checkbox.into());
 
         // Minimize to tray checkbox (only enabled when tray icon is shown)
-        let minimize_checkbox = checkbox(minimize_to_tray)
+        let minimize_checkbox = checkbox(tray.minimize)
             .label(t("settings-minimize-to-tray"))
             .text_size(TEXT_SIZE);
-        let minimize_checkbox = if show_tray_icon {
+        let minimize_checkbox = if tray.show_icon {
             minimize_checkbox.on_toggle(Message::MinimizeToTrayToggled)
         } else {
             minimize_checkbox
@@ -112,10 +180,7 @@ pub(super) fn general_tab_content<'a>(
 
     // Suppress unused variable warnings on macOS
     #[cfg(target_os = "macos")]
-    {
-        let _ = show_tray_icon;
-        let _ = minimize_to_tray;
-    }
+    let _ = tray;
 
     Column::with_children(items)
         .spacing(ELEMENT_SPACING)
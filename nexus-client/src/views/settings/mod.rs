@@ -8,16 +8,20 @@ mod events;
 mod files;
 mod general;
 mod network;
+mod security;
 
 use iced::widget::{Column, Space, button, button as btn, container, row, scrollable};
 use iced::{Center, Element, Fill, Theme};
 use iced_aw::TabLabel;
 use iced_aw::Tabs;
 use nexus_common::voice::VoiceQuality;
+use uuid::Uuid;
 
 use crate::config::audio::{PttMode, PttReleaseDelay};
-use crate::config::events::{EventSettings, EventType};
-use crate::config::settings::{ChatHistoryRetention, ProxySettings};
+use crate::config::events::{EventSettings, EventType, QuietHours};
+use crate::config::settings::{
+    AutoAway, ChatHistoryRetention, CollisionPolicy, ProxySettings, UserListSettings,
+};
 use crate::i18n::t;
 use crate::image::CachedImage;
 use crate::style::{
@@ -25,7 +29,7 @@ use crate::style::{
     SPACER_SIZE_SMALL, TAB_LABEL_PADDING, TEXT_SIZE, content_background_style, error_text_style,
     panel_title, shaped_text, shaped_text_wrapped,
 };
-use crate::types::{Message, SettingsFormState, SettingsTab};
+use crate::types::{Message, ServerBookmark, SettingsFormState, SettingsTab};
 use crate::voice::audio::AudioDevice;
 
 use super::chat::TimestampSettings;
@@ -66,10 +70,50 @@ pub struct AudioTabData<'a> {
     pub transient_suppression: bool,
     /// Microphone boost level
     pub mic_boost: crate::config::audio::MicBoost,
+    /// Soundboard clip playback volume (0.0 - 1.0)
+    pub clip_volume: f32,
+    /// Read voice text messages aloud
+    pub voice_text_tts: bool,
     /// Current theme (for VU meter rendering)
     pub theme: Theme,
 }
 
+/// Data needed to render the show/hide window hotkey on the General tab
+pub struct WindowKeyData<'a> {
+    /// Current hotkey (None = disabled)
+    pub key: Option<&'a str>,
+    /// Whether key capture mode is active
+    pub capturing: bool,
+}
+
+/// Data needed to render the system tray settings on the General tab
+pub struct TrayData {
+    /// Whether to show the tray icon (Windows/Linux only)
+    pub show_icon: bool,
+    /// Whether to minimize to tray instead of closing (Windows/Linux only)
+    pub minimize: bool,
+}
+
+/// Data needed to render the do not disturb section of the Events tab
+pub struct DoNotDisturbData {
+    /// Manual do not disturb toggle
+    pub enabled: bool,
+    /// Scheduled do not disturb window
+    pub quiet_hours: QuietHours,
+}
+
+/// Data needed to render the notification rules section of the Events tab
+pub struct NotificationRulesData<'a> {
+    /// Saved bookmarks whose rules can be edited
+    pub bookmarks: &'a [ServerBookmark],
+    /// Bookmark whose rules are shown
+    pub selected: Option<Uuid>,
+    /// Channel name being entered for a new override
+    pub channel_input: &'a str,
+    /// Keyword being entered for the watch list
+    pub keyword_input: &'a str,
+}
+
 /// All data needed to render the settings panel
 pub struct SettingsViewData<'a> {
     /// Current theme
@@ -86,6 +130,16 @@ pub struct SettingsViewData<'a> {
     pub chat_font_size: u8,
     /// Timestamp display settings
     pub timestamp_settings: TimestampSettings,
+    /// Whether panels are shown beside chat
+    pub split_view: bool,
+    /// Whether spell checking is on
+    pub spell_check: bool,
+    /// Whether loading finished without finding a dictionary
+    pub spell_dictionary_missing: bool,
+    /// Whether read receipts are sent
+    pub send_read_receipts: bool,
+    /// User list grouping and sort order
+    pub user_list: UserListSettings,
     /// Settings form state (present when panel is open)
     pub settings_form: Option<&'a SettingsFormState>,
     /// Default nickname for shared accounts
@@ -100,8 +154,16 @@ pub struct SettingsViewData<'a> {
     pub download_limit: u8,
     /// Max concurrent uploads per server (0 = unlimited)
     pub upload_limit: u8,
+    /// Open the containing folder when a download completes
+    pub auto_open_folder: bool,
+    /// What to do when a download's destination already exists
+    pub collision_policy: CollisionPolicy,
+    /// Ask before trusting a new server's certificate
+    pub require_fingerprint_approval: bool,
     /// Event notification settings
     pub event_settings: &'a EventSettings,
+    /// Saved bookmarks (for per-server notification rules and known servers)
+    pub bookmarks: &'a [ServerBookmark],
     /// Currently selected event type in Events tab
     pub selected_event_type: EventType,
     /// Global notifications enabled
@@ -110,6 +172,10 @@ pub struct SettingsViewData<'a> {
     pub sound_enabled: bool,
     /// Master sound volume (0.0 - 1.0)
     pub sound_volume: f32,
+    /// Manual do not disturb toggle
+    pub do_not_disturb: bool,
+    /// Scheduled do not disturb window
+    pub quiet_hours: QuietHours,
     /// Available output audio devices
     pub output_devices: &'a [AudioDevice],
     /// Currently selected output device
@@ -144,10 +210,18 @@ pub struct SettingsViewData<'a> {
     pub transient_suppression: bool,
     /// Microphone boost level
     pub mic_boost: crate::config::audio::MicBoost,
+    /// Soundboard clip playback volume (0.0 - 1.0)
+    pub clip_volume: f32,
+    /// Read voice text messages aloud
+    pub voice_text_tts: bool,
     /// Whether to show tray icon setting (Windows/Linux only)
     pub show_tray_icon: bool,
     /// Whether to minimize to tray setting (Windows/Linux only)
     pub minimize_to_tray: bool,
+    /// Global hotkey that shows or hides the window
+    pub toggle_window_key: Option<&'a str>,
+    /// Automatic away status after idle time
+    pub auto_away: AutoAway,
 }
 
 // ============================================================================
@@ -157,10 +231,11 @@ pub struct SettingsViewData<'a> {
 /// Render the settings panel with tabbed layout
 ///
 /// Shows application settings organized into tabs:
-/// - General: Theme, avatar, nickname
+/// - General: Theme, avatar, nickname, show/hide window hotkey, tray
 /// - Chat: Font size, timestamps, notifications
 /// - Files: Download location
 /// - Network: Proxy configuration
+/// - Security: New certificate approval, known server fingerprints
 /// - Events: Notification, toast, and sound settings per event
 /// - Audio: Voice chat devices and push-to-talk settings
 ///
@@ -191,8 +266,15 @@ pub fn settings_view<'a>(data: SettingsViewData<'a>) -> Element<'a, Message> {
         avatar,
         default_avatar,
         data.nickname,
-        data.show_tray_icon,
-        data.minimize_to_tray,
+        TrayData {
+            show_icon: data.show_tray_icon,
+            minimize: data.minimize_to_tray,
+        },
+        WindowKeyData {
+            key: data.toggle_window_key,
+            capturing: data.settings_form.is_some_and(|f| f.window_key_capturing),
+        },
+        data.auto_away,
     );
     let chat_content = chat::chat_tab_content(
         data.chat_history_retention,
@@ -201,14 +283,27 @@ pub fn settings_view<'a>(data: SettingsViewData<'a>) -> Element<'a, Message> {
         data.show_connection_events,
         data.show_join_leave_events,
         data.timestamp_settings,
+        data.split_view,
+        data.spell_check,
+        data.spell_dictionary_missing,
+        data.send_read_receipts,
+        data.user_list,
     );
     let network_content = network::network_tab_content(data.proxy);
 
+    let security_content = security::security_tab_content(
+        data.require_fingerprint_approval,
+        data.bookmarks,
+        data.settings_form,
+    );
+
     let files_content = files::files_tab_content(
         data.download_path,
         data.queue_transfers,
         data.download_limit,
         data.upload_limit,
+        data.auto_open_folder,
+        data.collision_policy,
     );
 
     let events_content = events::events_tab_content(
@@ -217,6 +312,22 @@ pub fn settings_view<'a>(data: SettingsViewData<'a>) -> Element<'a, Message> {
         data.notifications_enabled,
         data.sound_enabled,
         data.sound_volume,
+        DoNotDisturbData {
+            enabled: data.do_not_disturb,
+            quiet_hours: data.quiet_hours,
+        },
+        NotificationRulesData {
+            bookmarks: data.bookmarks,
+            selected: data.settings_form.and_then(|f| f.rules_bookmark),
+            channel_input: data
+                .settings_form
+                .map(|f| f.rules_channel_input.as_str())
+                .unwrap_or_default(),
+            keyword_input: data
+                .settings_form
+                .map(|f| f.rules_keyword_input.as_str())
+                .unwrap_or_default(),
+        },
     );
 
     let audio_content = audio::audio_tab_content(AudioTabData {
@@ -237,6 +348,8 @@ pub fn settings_view<'a>(data: SettingsViewData<'a>) -> Element<'a, Message> {
         agc: data.agc,
         transient_suppression: data.transient_suppression,
         mic_boost: data.mic_boost,
+        clip_volume: data.clip_volume,
+        voice_text_tts: data.voice_text_tts,
         theme,
     });
 
@@ -262,6 +375,11 @@ pub fn settings_view<'a>(data: SettingsViewData<'a>) -> Element<'a, Message> {
             TabLabel::Text(t("tab-network")),
             network_content,
         )
+        .push(
+            SettingsTab::Security,
+            TabLabel::Text(t("tab-security")),
+            security_content,
+        )
         .push(
             SettingsTab::Events,
             TabLabel::Text(t("settings-tab-events")),
@@ -0,0 +1,199 @@
+//! Security settings tab (new certificate approval, config encryption,
+//! known server fingerprints)
+
+use chrono::{Local, TimeZone};
+use iced::widget::button as btn;
+use iced::widget::{Column, Id, Space, button, checkbox, column, row, text_input};
+use iced::{Center, Element, Fill};
+
+use crate::i18n::{t, t_args};
+use crate::style::{
+    ELEMENT_SPACING, INPUT_PADDING, MONOSPACE_FONT, SPACER_SIZE_MEDIUM, SPACER_SIZE_SMALL,
+    TEXT_SIZE, muted_text_style, shaped_text, shaped_text_wrapped,
+};
+use crate::types::{InputId, Message, ServerBookmark, SettingsFormState};
+
+use super::super::fingerprint::format_fingerprint_multiline;
+
+/// Build the Security tab content (approval and encryption settings, known servers list)
+pub(super) fn security_tab_content<'a>(
+    require_fingerprint_approval: bool,
+    bookmarks: &'a [ServerBookmark],
+    form: Option<&'a SettingsFormState>,
+) -> Element<'a, Message> {
+    let mut items: Vec<Element<'a, Message>> = Vec::new();
+
+    // Space between tab bar and first content
+    items.push(Space::new().height(SPACER_SIZE_MEDIUM).into());
+
+    let approval_checkbox = checkbox(require_fingerprint_approval)
+        .label(t("label-require-fingerprint-approval"))
+        .on_toggle(Message::RequireFingerprintApprovalToggled)
+        .text_size(TEXT_SIZE);
+    items.push(approval_checkbox.into());
+
+    if let Some(form) = form {
+        encryption_items(&mut items, form);
+    }
+
+    items.push(Space::new().height(SPACER_SIZE_SMALL).into());
+    items.push(shaped_text(t("label-known-servers")).size(TEXT_SIZE).into());
+
+    let mut known = bookmarks
+        .iter()
+        .filter(|bookmark| bookmark.certificate_fingerprint.is_some())
+        .peekable();
+    if known.peek().is_none() {
+        items.push(
+            shaped_text(t("known-servers-empty"))
+                .size(TEXT_SIZE)
+                .style(muted_text_style)
+                .into(),
+        );
+    }
+
+    let expanded = form.and_then(|f| f.known_server_expanded);
+    for bookmark in known {
+        known_server_rows(&mut items, bookmark, expanded == Some(bookmark.id));
+    }
+
+    Column::with_children(items)
+        .spacing(ELEMENT_SPACING)
+        .width(Fill)
+        .into()
+}
+
+/// Add the config encryption checkbox, plus passphrase fields when turning it on
+///
+/// The checkbox is disabled while the encrypted config is locked.
+fn encryption_items<'a>(items: &mut Vec<Element<'a, Message>>, form: &'a SettingsFormState) {
+    let encrypt_checkbox = checkbox(form.encrypt_config)
+        .label(t("label-encrypt-config"))
+        .text_size(TEXT_SIZE);
+    if form.config_locked {
+        items.push(encrypt_checkbox.into());
+        items.push(
+            shaped_text_wrapped(t("config-encryption-locked-note"))
+                .size(TEXT_SIZE)
+                .style(muted_text_style)
+                .into(),
+        );
+        return;
+    }
+    items.push(
+        encrypt_checkbox
+            .on_toggle(Message::ConfigEncryptionToggled)
+            .into(),
+    );
+
+    if !form.encrypt_config || form.config_encrypted {
+        return;
+    }
+    items.push(
+        text_input(&t("placeholder-master-passphrase"), &form.config_passphrase)
+            .on_input(Message::ConfigPassphraseChanged)
+            .on_submit(Message::SaveSettings)
+            .id(Id::from(InputId::ConfigPassphrase))
+            .secure(true)
+            .padding(INPUT_PADDING)
+            .size(TEXT_SIZE)
+            .into(),
+    );
+    items.push(
+        text_input(
+            &t("placeholder-confirm-master-passphrase"),
+            &form.config_passphrase_confirm,
+        )
+        .on_input(Message::ConfigPassphraseConfirmChanged)
+        .on_submit(Message::SaveSettings)
+        .id(Id::from(InputId::ConfigPassphraseConfirm))
+        .secure(true)
+        .padding(INPUT_PADDING)
+        .size(TEXT_SIZE)
+        .into(),
+    );
+}
+
+/// Add a known server's summary row, plus its certificate details when expanded
+fn known_server_rows<'a>(
+    items: &mut Vec<Element<'a, Message>>,
+    bookmark: &'a ServerBookmark,
+    is_expanded: bool,
+) {
+    let Some(fingerprint) = bookmark.certificate_fingerprint.as_deref() else {
+        return;
+    };
+
+    let first_seen = match bookmark.certificate_first_seen.and_then(format_date) {
+        Some(date) => t_args("known-server-first-seen", &[("date", &date)]),
+        None => t("known-server-first-seen-unknown"),
+    };
+    let summary = column![
+        shaped_text(&bookmark.name).size(TEXT_SIZE),
+        shaped_text(format!(
+            "{}:{} · {}",
+            bookmark.address, bookmark.port, first_seen
+        ))
+        .size(TEXT_SIZE)
+        .style(muted_text_style),
+    ]
+    .width(Fill);
+
+    let view_label = if is_expanded {
+        t("button-hide")
+    } else {
+        t("button-view")
+    };
+    let view_button = button(shaped_text(view_label).size(TEXT_SIZE))
+        .on_press(Message::KnownServerDetailsToggled(bookmark.id))
+        .padding(INPUT_PADDING)
+        .style(btn::secondary);
+    let export_button = button(shaped_text(t("button-export")).size(TEXT_SIZE))
+        .on_press(Message::KnownServerExportPressed(bookmark.id))
+        .padding(INPUT_PADDING)
+        .style(btn::secondary);
+    let remove_button = button(shaped_text(t("button-remove")).size(TEXT_SIZE))
+        .on_press(Message::KnownServerRemovePressed(bookmark.id))
+        .padding(INPUT_PADDING)
+        .style(btn::danger);
+
+    items.push(
+        row![summary, view_button, export_button, remove_button]
+            .spacing(ELEMENT_SPACING)
+            .align_y(Center)
+            .into(),
+    );
+
+    if !is_expanded {
+        return;
+    }
+
+    items.push(
+        shaped_text(t("label-certificate-fingerprint"))
+            .size(TEXT_SIZE)
+            .into(),
+    );
+    items.push(
+        shaped_text(format_fingerprint_multiline(fingerprint))
+            .size(TEXT_SIZE)
+            .font(MONOSPACE_FONT)
+            .into(),
+    );
+    if let Some(identity_key) = &bookmark.identity_key {
+        items.push(shaped_text(t("label-identity-key")).size(TEXT_SIZE).into());
+        items.push(
+            shaped_text_wrapped(identity_key)
+                .size(TEXT_SIZE)
+                .font(MONOSPACE_FONT)
+                .into(),
+        );
+    }
+}
+
+/// Format a Unix timestamp as a local date
+fn format_date(timestamp: i64) -> Option<String> {
+    Local
+        .timestamp_opt(timestamp, 0)
+        .single()
+        .map(|time| time.format("%b %d, %Y").to_string())
+}
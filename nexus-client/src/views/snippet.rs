@@ -0,0 +1,84 @@
+//! Snippet panel view
+//!
+//! Shows a snippet opened from a `nexus://.../snippet/<id>` link as a
+//! highlighted code block, with who shared it and when it expires.
+
+use chrono::{Local, TimeZone};
+use iced::widget::{Space, button, column, container, markdown, row};
+use iced::{Element, Fill, Theme};
+
+use super::layout::scrollable_panel;
+use crate::i18n::{t, t_args};
+use crate::style::{
+    BUTTON_PADDING, CONTENT_MAX_WIDTH, CONTENT_PADDING, ELEMENT_SPACING, SPACER_SIZE_MEDIUM,
+    SPACER_SIZE_SMALL, TEXT_SIZE, error_text_style, muted_text_style, panel_title, shaped_text,
+    shaped_text_wrapped,
+};
+use crate::types::{Message, SnippetViewState};
+
+/// Date format for when a snippet expires
+const EXPIRY_FORMAT: &str = "%b %d, %Y %H:%M";
+
+/// Snippet panel
+pub fn snippet_view<'a>(state: &'a SnippetViewState, theme: &Theme) -> Element<'a, Message> {
+    let content: Element<'a, Message> = if let Some(error) = &state.error {
+        shaped_text_wrapped(error.clone())
+            .size(TEXT_SIZE)
+            .style(error_text_style)
+            .into()
+    } else if let Some(snippet) = &state.snippet {
+        let expires = Local
+            .timestamp_opt(snippet.expires_at, 0)
+            .single()
+            .map(|time| time.format(EXPIRY_FORMAT).to_string())
+            .unwrap_or_default();
+        let mut details = t_args(
+            "snippet-details",
+            &[("author", &snippet.author), ("expires", &expires)],
+        );
+        if let Some(language) = &snippet.language {
+            details.push_str(" · ");
+            details.push_str(language);
+        }
+
+        let md_settings = markdown::Settings::with_text_size(TEXT_SIZE, theme);
+        column![
+            shaped_text_wrapped(details)
+                .size(TEXT_SIZE)
+                .style(muted_text_style),
+            container(markdown::view(&state.markdown, md_settings).map(Message::OpenUrl))
+                .width(Fill),
+        ]
+        .spacing(SPACER_SIZE_SMALL)
+        .width(Fill)
+        .into()
+    } else {
+        shaped_text(t("snippet-loading"))
+            .size(TEXT_SIZE)
+            .style(muted_text_style)
+            .into()
+    };
+
+    let buttons = row![
+        Space::new().width(Fill),
+        button(shaped_text(t("menu-copy")).size(TEXT_SIZE))
+            .on_press_maybe(state.snippet.is_some().then_some(Message::CopySnippet))
+            .padding(BUTTON_PADDING),
+        button(shaped_text(t("button-close")).size(TEXT_SIZE))
+            .on_press(Message::CloseSnippet)
+            .padding(BUTTON_PADDING),
+    ]
+    .spacing(ELEMENT_SPACING);
+
+    let form = column![
+        panel_title(t("panel-snippet")),
+        content,
+        Space::new().height(SPACER_SIZE_MEDIUM),
+        buttons,
+    ]
+    .spacing(ELEMENT_SPACING)
+    .padding(CONTENT_PADDING)
+    .max_width(CONTENT_MAX_WIDTH);
+
+    scrollable_panel(form)
+}
@@ -0,0 +1,74 @@
+//! Spelling suggestions menu for spell-checked text editors
+
+use iced::widget::{Column, Space, container};
+use iced::{Element, Fill};
+
+use crate::i18n::t;
+use crate::spellcheck::SpellSettings;
+use crate::style::{
+    CONTEXT_MENU_ITEM_PADDING, CONTEXT_MENU_MIN_WIDTH, CONTEXT_MENU_PADDING, TEXT_SIZE,
+    context_menu_container_style, menu_button_style, muted_text_style, shaped_text,
+};
+use crate::types::{Message, SpellField, SpellMenu};
+use crate::widgets::{LazyContextMenu, MenuButton};
+
+/// Add a right-click spelling suggestions menu to a text editor
+///
+/// Right-clicking selects the word under the pointer and, if it's
+/// misspelled, lists replacements for it. Without a dictionary the editor
+/// is returned unchanged.
+pub fn with_spell_menu<'a>(
+    editor: impl Into<Element<'a, Message>>,
+    field: SpellField,
+    spell_settings: &SpellSettings,
+    spell_menu: Option<&'a SpellMenu>,
+) -> Element<'a, Message> {
+    if spell_settings.0.is_none() {
+        return editor.into();
+    }
+
+    let spell_menu = spell_menu.filter(|menu| menu.field == field);
+    LazyContextMenu::new(editor, move || suggestions_menu(spell_menu))
+        .on_open(move |position| Message::SpellMenuOpened(field, position))
+        .into()
+}
+
+/// Menu of replacements for the right-clicked word
+///
+/// Empty when the word is spelled correctly, so nothing is shown.
+fn suggestions_menu(spell_menu: Option<&SpellMenu>) -> Element<'static, Message> {
+    let Some(spell_menu) = spell_menu else {
+        return Space::new().into();
+    };
+
+    let items: Vec<Element<'static, Message>> = if spell_menu.suggestions.is_empty() {
+        vec![
+            container(
+                shaped_text(t("spell-no-suggestions"))
+                    .size(TEXT_SIZE)
+                    .style(muted_text_style),
+            )
+            .padding(CONTEXT_MENU_ITEM_PADDING)
+            .into(),
+        ]
+    } else {
+        spell_menu
+            .suggestions
+            .iter()
+            .map(|suggestion| {
+                MenuButton::new(shaped_text(suggestion.clone()).size(TEXT_SIZE))
+                    .padding(CONTEXT_MENU_ITEM_PADDING)
+                    .width(Fill)
+                    .style(menu_button_style)
+                    .on_press(Message::SpellSuggestionChosen(suggestion.clone()))
+                    .into()
+            })
+            .collect()
+    };
+
+    container(Column::with_children(items))
+        .width(CONTEXT_MENU_MIN_WIDTH)
+        .padding(CONTEXT_MENU_PADDING)
+        .style(context_menu_container_style)
+        .into()
+}
@@ -0,0 +1,230 @@
+//! Statistics panel view
+//!
+//! Shows a bar chart of one daily metric over the selected range, with the
+//! range totals underneath.
+
+use iced::widget::{Column, Row, Space, button, column, container, pick_list, row, tooltip};
+use iced::{Center, Element, Fill};
+use nexus_common::protocol::DailyStats;
+
+use super::layout::scrollable_panel;
+use crate::i18n::{t, t_args};
+use crate::style::{
+    BUTTON_PADDING, CONTENT_MAX_WIDTH, CONTENT_PADDING, ELEMENT_SPACING, INPUT_PADDING,
+    SPACER_SIZE_MEDIUM, SPACER_SIZE_SMALL, TEXT_SIZE, TOOLTIP_BACKGROUND_PADDING, TOOLTIP_GAP,
+    TOOLTIP_PADDING, TOOLTIP_TEXT_SIZE, chart_bar_style, error_text_style, muted_text_style,
+    panel_title, shaped_text, shaped_text_wrapped, tooltip_container_style,
+};
+use crate::types::{Message, StatisticsState, StatsMetric, StatsRange};
+
+/// Height of the chart's bar area
+const CHART_HEIGHT: f32 = 180.0;
+
+/// Space between chart bars
+const CHART_BAR_SPACING: f32 = 2.0;
+
+/// Width of the range and metric pickers
+const PICKER_WIDTH: f32 = 200.0;
+
+impl std::fmt::Display for StatsRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            t_args("stats-range-days", &[("days", &self.0.to_string())])
+        )
+    }
+}
+
+impl std::fmt::Display for StatsMetric {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", t(self.translation_key()))
+    }
+}
+
+/// Format bytes as human-readable size
+fn format_bytes(bytes: u64) -> String {
+    const KB: u64 = 1024;
+    const MB: u64 = KB * 1024;
+    const GB: u64 = MB * 1024;
+
+    if bytes >= GB {
+        format!("{:.1} GB", bytes as f64 / GB as f64)
+    } else if bytes >= MB {
+        format!("{:.1} MB", bytes as f64 / MB as f64)
+    } else if bytes >= KB {
+        format!("{:.1} KB", bytes as f64 / KB as f64)
+    } else {
+        format!("{} B", bytes)
+    }
+}
+
+/// Format a metric value (sizes for byte metrics, plain numbers otherwise)
+fn format_value(metric: StatsMetric, value: u64) -> String {
+    if metric.is_bytes() {
+        format_bytes(value)
+    } else {
+        value.to_string()
+    }
+}
+
+/// One chart bar, scaled against the range's highest value
+fn chart_bar(day: &DailyStats, metric: StatsMetric, max: u64) -> Element<'static, Message> {
+    let value = metric.value(day);
+    let height = if max == 0 {
+        0.0
+    } else {
+        // Days with any activity stay visible
+        (CHART_HEIGHT * value as f32 / max as f32).max(if value > 0 { 1.0 } else { 0.0 })
+    };
+
+    let bar = column![
+        Space::new().height(Fill),
+        container(Space::new())
+            .width(Fill)
+            .height(height)
+            .style(chart_bar_style),
+    ]
+    .width(Fill)
+    .height(CHART_HEIGHT);
+
+    let label = format!("{}: {}", day.date, format_value(metric, value));
+    tooltip(
+        bar,
+        container(shaped_text(label).size(TOOLTIP_TEXT_SIZE))
+            .padding(TOOLTIP_BACKGROUND_PADDING)
+            .style(tooltip_container_style),
+        tooltip::Position::Top,
+    )
+    .gap(TOOLTIP_GAP)
+    .padding(TOOLTIP_PADDING)
+    .into()
+}
+
+/// Bar chart of the selected metric, with the scale and date range as labels
+fn chart(state: &StatisticsState) -> Element<'static, Message> {
+    let metric = state.metric;
+    let max = state
+        .days
+        .iter()
+        .map(|day| metric.value(day))
+        .max()
+        .unwrap_or(0);
+
+    let bars = Row::with_children(state.days.iter().map(|day| chart_bar(day, metric, max)))
+        .spacing(CHART_BAR_SPACING)
+        .width(Fill);
+
+    let first = state.days.first().map(|day| day.date.clone());
+    let last = state.days.last().map(|day| day.date.clone());
+    let dates = row![
+        shaped_text(first.unwrap_or_default())
+            .size(TEXT_SIZE)
+            .style(muted_text_style),
+        Space::new().width(Fill),
+        shaped_text(last.unwrap_or_default())
+            .size(TEXT_SIZE)
+            .style(muted_text_style),
+    ];
+
+    column![
+        shaped_text(t_args(
+            "stats-chart-max",
+            &[("value", &format_value(metric, max))]
+        ))
+        .size(TEXT_SIZE)
+        .style(muted_text_style),
+        bars,
+        dates,
+    ]
+    .spacing(SPACER_SIZE_SMALL)
+    .width(Fill)
+    .into()
+}
+
+/// Range totals for every metric
+fn totals(days: &[DailyStats]) -> Element<'static, Message> {
+    let rows = StatsMetric::all().into_iter().map(|metric| {
+        row![
+            shaped_text(t(metric.translation_key()))
+                .size(TEXT_SIZE)
+                .style(muted_text_style)
+                .width(Fill),
+            shaped_text(format_value(metric, metric.total(days))).size(TEXT_SIZE),
+        ]
+        .spacing(ELEMENT_SPACING)
+        .into()
+    });
+
+    Column::with_children(rows)
+        .spacing(SPACER_SIZE_SMALL)
+        .width(Fill)
+        .into()
+}
+
+/// Statistics panel
+pub fn statistics_view(state: &StatisticsState) -> Element<'static, Message> {
+    let range_picker = pick_list(
+        StatsRange::all(),
+        Some(state.range),
+        Message::StatisticsRangeSelected,
+    )
+    .text_size(TEXT_SIZE)
+    .padding(INPUT_PADDING)
+    .width(PICKER_WIDTH);
+
+    let metric_picker = pick_list(
+        StatsMetric::all(),
+        Some(state.metric),
+        Message::StatisticsMetricSelected,
+    )
+    .text_size(TEXT_SIZE)
+    .padding(INPUT_PADDING)
+    .width(PICKER_WIDTH);
+
+    let pickers = row![range_picker, Space::new().width(Fill), metric_picker]
+        .spacing(ELEMENT_SPACING)
+        .align_y(Center);
+
+    let content: Element<'static, Message> = if let Some(error) = &state.error {
+        shaped_text_wrapped(error.clone())
+            .size(TEXT_SIZE)
+            .style(error_text_style)
+            .into()
+    } else if state.loading {
+        shaped_text(t("stats-loading"))
+            .size(TEXT_SIZE)
+            .style(muted_text_style)
+            .into()
+    } else {
+        column![
+            chart(state),
+            Space::new().height(SPACER_SIZE_MEDIUM),
+            totals(&state.days),
+        ]
+        .width(Fill)
+        .into()
+    };
+
+    let buttons = row![
+        Space::new().width(Fill),
+        button(shaped_text(t("button-close")).size(TEXT_SIZE))
+            .on_press(Message::CloseStatistics)
+            .padding(BUTTON_PADDING),
+    ]
+    .spacing(ELEMENT_SPACING);
+
+    let form = column![
+        panel_title(t("panel-statistics")),
+        pickers,
+        Space::new().height(SPACER_SIZE_SMALL),
+        content,
+        Space::new().height(SPACER_SIZE_MEDIUM),
+        buttons,
+    ]
+    .spacing(ELEMENT_SPACING)
+    .padding(CONTENT_PADDING)
+    .max_width(CONTENT_MAX_WIDTH);
+
+    scrollable_panel(form)
+}
@@ -0,0 +1,101 @@
+//! Status bar along the bottom of the main window
+//!
+//! Shows the active server's user count, our latency to it and its clock on
+//! the left, and the compact transfers indicator on the right.
+
+use chrono::Utc;
+use iced::widget::{Space, container, row};
+use iced::{Center, Element, Fill};
+
+use super::chat::TimestampSettings;
+use super::constants::PERMISSION_USER_LIST;
+use super::transfers::{BULLET_SEPARATOR, transfer_status};
+use crate::i18n::t_args;
+use crate::icon;
+use crate::style::{
+    DETAIL_TEXT_SIZE, ELEMENT_SPACING, TEXT_SIZE, TRANSFER_BAR_PADDING, muted_text_style,
+    shaped_text, toolbar_background_style,
+};
+use crate::transfers::TransferManager;
+use crate::types::{Message, ServerConnection};
+
+/// Build the status bar
+///
+/// Returns `None` when there's no active connection to report on and
+/// nothing is transferring.
+pub fn status_bar<'a>(
+    conn: Option<&ServerConnection>,
+    transfer_manager: &TransferManager,
+    timestamp_settings: TimestampSettings,
+) -> Option<Element<'a, Message>> {
+    let server = conn.and_then(|conn| server_status(conn, timestamp_settings));
+    let transfers = transfer_status(transfer_manager);
+    if server.is_none() && transfers.is_none() {
+        return None;
+    }
+
+    let mut bar = row![].align_y(Center);
+    if let Some(server) = server {
+        bar = bar.push(server);
+    }
+    bar = bar.push(transfers.unwrap_or_else(|| Space::new().width(Fill).into()));
+
+    Some(
+        container(bar)
+            .width(Fill)
+            .style(toolbar_background_style)
+            .into(),
+    )
+}
+
+/// User count, latency and server time for the active connection
+///
+/// Each part is left out until it's known (the user count needs the
+/// user_list permission, latency the first background ping, and the clock
+/// a server that sends it).
+fn server_status<'a>(
+    conn: &ServerConnection,
+    timestamp_settings: TimestampSettings,
+) -> Option<Element<'a, Message>> {
+    let mut parts = Vec::new();
+    if conn.has_permission(PERMISSION_USER_LIST) {
+        parts.push(t_args(
+            "status-bar-users",
+            &[("count", &conn.online_users.len().to_string())],
+        ));
+    }
+    if let Some(latency) = conn.health.latency() {
+        parts.push(t_args(
+            "status-bar-latency",
+            &[("ms", &latency.as_millis().to_string())],
+        ));
+    }
+    if let Some(clock) = &conn.server_clock {
+        let time = clock
+            .time_at(Utc::now())
+            .format(timestamp_settings.time_format())
+            .to_string();
+        parts.push(t_args(
+            "status-bar-server-time",
+            &[("time", &time), ("zone", &clock.zone_label())],
+        ));
+    }
+    if parts.is_empty() {
+        return None;
+    }
+
+    Some(
+        container(
+            row![
+                icon::server().size(TEXT_SIZE),
+                shaped_text(parts.join(BULLET_SEPARATOR))
+                    .size(DETAIL_TEXT_SIZE)
+                    .style(muted_text_style),
+            ]
+            .spacing(ELEMENT_SPACING)
+            .align_y(Center),
+        )
+        .padding(TRANSFER_BAR_PADDING)
+        .into(),
+    )
+}
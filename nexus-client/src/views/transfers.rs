@@ -2,13 +2,15 @@
 //!
 //! A global panel showing all file transfers across all connections.
 //! Transfers persist across application restarts for resume support.
+//! The panel can switch to the transfer history, which keeps finished
+//! transfers after they're cleared from the queue.
 
 // ============================================================================
 // Constants
 // ============================================================================
 
 /// Bullet separator for status line (e.g., "Server • Status • Speed")
-const BULLET_SEPARATOR: &str = " • ";
+pub(super) const BULLET_SEPARATOR: &str = " • ";
 
 /// Em dash separator for error messages (e.g., "Status — Error message")
 const EM_DASH_SEPARATOR: &str = " — ";
@@ -35,9 +37,10 @@ const MIN_SPEED_FOR_ETA: f64 = 1.0;
 // Imports
 // ============================================================================
 
+use chrono::{Local, TimeZone, Utc};
 use iced::alignment;
 use iced::widget::{
-    Column, Space, button, column, container, progress_bar, row, scrollable, tooltip,
+    Column, Space, button, column, container, progress_bar, row, scrollable, text_input, tooltip,
 };
 use iced::{Center, Element, Fill};
 
@@ -45,17 +48,21 @@ use crate::i18n::{t, t_args};
 use crate::icon;
 use crate::style::{
     CONTENT_MAX_WIDTH, CONTENT_PADDING, DETAIL_TEXT_SIZE, ELEMENT_SPACING, ICON_BUTTON_PADDING,
-    SCROLLBAR_PADDING, SIDEBAR_ACTION_ICON_SIZE, SMALL_SPACING, SPACER_SIZE_SMALL, TEXT_SIZE,
-    TITLE_SIZE, TOOLTIP_BACKGROUND_PADDING, TOOLTIP_GAP, TOOLTIP_PADDING, TOOLTIP_TEXT_SIZE,
-    TRANSFER_ACTION_BUTTON_SIZE, TRANSFER_ACTION_ICON_SIZE, TRANSFER_ICON_SIZE,
-    TRANSFER_INFO_SPACING, TRANSFER_ITEM_SPACING, TRANSFER_PROGRESS_BAR_HEIGHT,
+    INPUT_PADDING, SCROLLBAR_PADDING, SIDEBAR_ACTION_ICON_SIZE, SMALL_SPACING, SPACER_SIZE_SMALL,
+    TEXT_SIZE, TITLE_SIZE, TOOLTIP_BACKGROUND_PADDING, TOOLTIP_GAP, TOOLTIP_PADDING,
+    TOOLTIP_TEXT_SIZE, TRANSFER_ACTION_BUTTON_SIZE, TRANSFER_ACTION_ICON_SIZE,
+    TRANSFER_BAR_PADDING, TRANSFER_BAR_PROGRESS_HEIGHT, TRANSFER_BAR_PROGRESS_WIDTH,
+    TRANSFER_ICON_SIZE, TRANSFER_INFO_SPACING, TRANSFER_ITEM_SPACING, TRANSFER_PROGRESS_BAR_HEIGHT,
     TRANSFER_PROGRESS_SPACING, TRANSFER_ROW_PADDING, alternating_row_style,
     content_background_style, danger_icon_button_style, disabled_icon_button_style,
     error_text_style, muted_text_style, shaped_text, tooltip_container_style,
     transparent_icon_button_style,
 };
-use crate::transfers::{Transfer, TransferDirection, TransferManager, TransferStatus};
-use crate::types::Message;
+use crate::transfers::{
+    CollisionAction, CollisionPolicy, Transfer, TransferDirection, TransferHistory,
+    TransferManager, TransferRecord, TransferStatus,
+};
+use crate::types::{Message, UiState};
 
 // ============================================================================
 // Helper Functions
@@ -170,6 +177,122 @@ fn danger_action_button_with_tooltip<'a>(
     .into()
 }
 
+/// Format a Unix timestamp as local date and time (e.g., "Jan 15, 2025 10:30")
+fn format_finished_at(timestamp: i64) -> String {
+    Utc.timestamp_opt(timestamp, 0)
+        .single()
+        .map(|time| {
+            time.with_timezone(&Local)
+                .format("%b %d, %Y %H:%M")
+                .to_string()
+        })
+        .unwrap_or_default()
+}
+
+/// Build an icon button for the panel title row (disabled without a message)
+fn title_icon_button<'a>(
+    icon: iced::widget::Text<'a>,
+    message: Option<Message>,
+    tooltip_key: &str,
+    danger: bool,
+) -> Element<'a, Message> {
+    let icon = container(icon.size(SIDEBAR_ACTION_ICON_SIZE))
+        .width(SIDEBAR_ACTION_ICON_SIZE)
+        .height(SIDEBAR_ACTION_ICON_SIZE)
+        .align_x(alignment::Horizontal::Center)
+        .align_y(alignment::Vertical::Center);
+
+    let btn = button(icon).padding(ICON_BUTTON_PADDING);
+    let btn = match message {
+        Some(message) if danger => btn.on_press(message).style(danger_icon_button_style),
+        Some(message) => btn.on_press(message).style(transparent_icon_button_style),
+        None => btn.style(disabled_icon_button_style),
+    };
+
+    tooltip(
+        btn,
+        container(shaped_text(t(tooltip_key)).size(TOOLTIP_TEXT_SIZE))
+            .padding(TOOLTIP_BACKGROUND_PADDING)
+            .style(tooltip_container_style),
+        tooltip::Position::Top,
+    )
+    .gap(TOOLTIP_GAP)
+    .padding(TOOLTIP_PADDING)
+    .into()
+}
+
+/// Build the panel title row: a button on each side of the centered title
+fn panel_title_row<'a>(
+    title: String,
+    left: Element<'a, Message>,
+    right: Element<'a, Message>,
+) -> Element<'a, Message> {
+    row![
+        Space::new().width(SCROLLBAR_PADDING),
+        left,
+        shaped_text(title)
+            .size(TITLE_SIZE)
+            .width(Fill)
+            .align_x(Center),
+        right,
+        Space::new().width(SCROLLBAR_PADDING),
+    ]
+    .align_y(Center)
+    .into()
+}
+
+/// Build the collision summary line (e.g., "2 renamed • 1 skipped")
+///
+/// Hovering the line shows each affected file and what was done with it.
+/// Returns None if the transfer hasn't hit any collisions.
+fn collision_log_row<'a>(transfer: &Transfer) -> Option<Element<'a, Message>> {
+    if transfer.collisions.is_empty() {
+        return None;
+    }
+
+    let summary = [
+        CollisionAction::Renamed,
+        CollisionAction::Overwritten,
+        CollisionAction::Skipped,
+    ]
+    .into_iter()
+    .filter_map(|action| {
+        let count = transfer.collision_count(action);
+        (count > 0).then(|| t_args(action.translation_key(), &[("count", &count.to_string())]))
+    })
+    .collect::<Vec<_>>()
+    .join(BULLET_SEPARATOR);
+
+    let details = transfer
+        .collisions
+        .iter()
+        .map(|record| {
+            format!(
+                "{}{}{}",
+                record.path,
+                EM_DASH_SEPARATOR,
+                t_args(record.action.translation_key(), &[("count", "1")])
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    Some(
+        tooltip(
+            shaped_text(summary)
+                .size(DETAIL_TEXT_SIZE)
+                .style(muted_text_style),
+            container(shaped_text(details).size(TOOLTIP_TEXT_SIZE))
+                .padding(TOOLTIP_BACKGROUND_PADDING)
+                .style(tooltip_container_style),
+            tooltip::Position::Top,
+        )
+        .gap(TOOLTIP_GAP)
+        .padding(TOOLTIP_PADDING)
+        .into(),
+    )
+}
+
 /// Build a single transfer row
 ///
 /// Layout:
@@ -247,11 +370,21 @@ fn build_transfer_row<'a>(
         ]
         .spacing(SMALL_SPACING)
         .into(),
-        TransferStatus::Paused => row![
+        TransferStatus::Paused if transfer.pending_collision.is_some() => row![
             action_button_with_tooltip(
-                icon::play(),
-                Message::TransferResume(id),
-                "tooltip-transfer-resume"
+                icon::edit(),
+                Message::TransferResolveCollision(id, CollisionPolicy::Rename),
+                "tooltip-transfer-collision-rename"
+            ),
+            action_button_with_tooltip(
+                icon::download(),
+                Message::TransferResolveCollision(id, CollisionPolicy::Overwrite),
+                "tooltip-transfer-collision-overwrite"
+            ),
+            action_button_with_tooltip(
+                icon::expand_right(),
+                Message::TransferResolveCollision(id, CollisionPolicy::Skip),
+                "tooltip-transfer-collision-skip"
             ),
             danger_action_button_with_tooltip(
                 icon::close(),
@@ -261,20 +394,52 @@ fn build_transfer_row<'a>(
         ]
         .spacing(SMALL_SPACING)
         .into(),
-        TransferStatus::Completed => row![
+        TransferStatus::Paused => row![
             action_button_with_tooltip(
-                icon::folder(),
-                Message::TransferOpenFolder(id),
-                "tooltip-transfer-open-folder"
+                icon::play(),
+                Message::TransferResume(id),
+                "tooltip-transfer-resume"
             ),
             danger_action_button_with_tooltip(
                 icon::close(),
-                Message::TransferRemove(id),
-                "tooltip-transfer-remove"
+                Message::TransferCancel(id),
+                "tooltip-transfer-cancel"
             ),
         ]
         .spacing(SMALL_SPACING)
         .into(),
+        TransferStatus::Completed => {
+            let mut action_row = row![
+                action_button_with_tooltip(
+                    icon::file(),
+                    Message::TransferOpenFile(id),
+                    "tooltip-transfer-open-file"
+                ),
+                action_button_with_tooltip(
+                    icon::folder(),
+                    Message::TransferReveal(id),
+                    "tooltip-transfer-reveal"
+                ),
+            ]
+            .spacing(SMALL_SPACING);
+
+            // Checksums only make sense for single files
+            if !transfer.is_directory {
+                action_row = action_row.push(action_button_with_tooltip(
+                    icon::paste(),
+                    Message::TransferCopyChecksum(id),
+                    "tooltip-transfer-copy-checksum",
+                ));
+            }
+
+            action_row
+                .push(danger_action_button_with_tooltip(
+                    icon::close(),
+                    Message::TransferRemove(id),
+                    "tooltip-transfer-remove",
+                ))
+                .into()
+        }
         TransferStatus::Failed => row![
             action_button_with_tooltip(
                 icon::refresh(),
@@ -342,6 +507,25 @@ fn build_transfer_row<'a>(
             }
             line
         }
+        TransferStatus::Connecting if transfer.server_queue_position.is_some() => {
+            let position = transfer.server_queue_position.unwrap_or_default();
+            [
+                transfer.connection_info.server_name.clone(),
+                t_args(
+                    "transfer-status-server-queued",
+                    &[("position", &position.to_string())],
+                ),
+            ]
+            .join(BULLET_SEPARATOR)
+        }
+        TransferStatus::Paused if transfer.pending_collision.is_some() => {
+            let path = transfer.pending_collision.as_deref().unwrap_or_default();
+            [
+                transfer.connection_info.server_name.clone(),
+                t_args("transfer-collision-pending", &[("path", path)]),
+            ]
+            .join(BULLET_SEPARATOR)
+        }
         TransferStatus::Completed => {
             let mut line = transfer.connection_info.server_name.clone();
             let status = if let Some(elapsed) = transfer.elapsed_seconds() {
@@ -393,6 +577,9 @@ fn build_transfer_row<'a>(
             .into()
     };
 
+    // Transfer log: collisions handled by the collision policy
+    let collision_row = collision_log_row(transfer);
+
     // Progress bar row (full width, with spacing before and after)
     let progress = transfer.progress_percent() / 100.0;
     let progress_row: Element<'a, Message> = column![
@@ -403,11 +590,14 @@ fn build_transfer_row<'a>(
     ]
     .into();
 
-    // Combine all rows into a column: Item → Bar → Stats
-    let content = column![title_row, progress_row, status_row,]
+    // Combine all rows into a column: Item → Bar → Stats (→ Collisions)
+    let mut content = column![title_row, progress_row, status_row]
         .spacing(TRANSFER_INFO_SPACING)
         .padding(TRANSFER_ROW_PADDING)
         .width(Fill);
+    if let Some(collision_row) = collision_row {
+        content = content.push(collision_row);
+    }
 
     container(content)
         .width(Fill)
@@ -415,63 +605,112 @@ fn build_transfer_row<'a>(
         .into()
 }
 
+// ============================================================================
+// Status Bar
+// ============================================================================
+
+/// Compact transfers indicator for the status bar
+///
+/// Shows the number of queued and active transfers, their combined speed,
+/// estimated time remaining and overall progress. Clicking it opens the
+/// Transfers panel. Returns `None` when nothing is queued or running.
+pub fn transfer_status<'a>(manager: &TransferManager) -> Option<Element<'a, Message>> {
+    let summary = manager.summary();
+    if summary.count == 0 {
+        return None;
+    }
+
+    let mut line = t_args(
+        "transfer-bar-count",
+        &[("count", &summary.count.to_string())],
+    );
+    if summary.bytes_per_second >= MIN_SPEED_FOR_ETA {
+        line.push_str(BULLET_SEPARATOR);
+        line.push_str(&format!(
+            "{}/s",
+            format_bytes(summary.bytes_per_second as u64)
+        ));
+    }
+    if let Some(eta) = summary.eta_seconds(MIN_SPEED_FOR_ETA) {
+        line.push_str(BULLET_SEPARATOR);
+        line.push_str(&format!("~{}", format_duration(eta)));
+    }
+
+    let mut bar_row = row![
+        icon::exchange().size(TEXT_SIZE),
+        container(
+            shaped_text(line)
+                .size(DETAIL_TEXT_SIZE)
+                .style(muted_text_style)
+        )
+        .width(Fill),
+    ]
+    .spacing(ELEMENT_SPACING)
+    .align_y(Center);
+
+    if let Some(progress) = summary.progress() {
+        bar_row = bar_row.push(
+            progress_bar(0.0..=1.0, progress as f32)
+                .length(TRANSFER_BAR_PROGRESS_WIDTH)
+                .girth(TRANSFER_BAR_PROGRESS_HEIGHT),
+        );
+    }
+
+    let bar_button = tooltip(
+        button(bar_row)
+            .on_press(Message::ToggleTransfers)
+            .padding(TRANSFER_BAR_PADDING)
+            .width(Fill)
+            .style(transparent_icon_button_style),
+        container(shaped_text(t("tooltip-transfers")).size(TOOLTIP_TEXT_SIZE))
+            .padding(TOOLTIP_BACKGROUND_PADDING)
+            .style(tooltip_container_style),
+        tooltip::Position::Top,
+    )
+    .gap(TOOLTIP_GAP)
+    .padding(TOOLTIP_PADDING);
+
+    Some(bar_button.into())
+}
+
 // ============================================================================
 // Main View Function
 // ============================================================================
 
 /// Displays the transfers panel
 ///
-/// Shows a list of all transfers (active, queued, paused, completed, failed).
+/// Shows a list of all transfers (active, queued, paused, completed, failed),
+/// or the transfer history when the user switched to it.
 /// Provides action buttons for pause/resume/cancel/remove operations.
-pub fn transfers_view<'a>(manager: &'a TransferManager) -> Element<'a, Message> {
+pub fn transfers_view<'a>(
+    manager: &'a TransferManager,
+    history: &'a TransferHistory,
+    ui_state: &'a UiState,
+) -> Element<'a, Message> {
+    if ui_state.show_transfer_history {
+        return transfer_history_view(history, &ui_state.transfer_history_search);
+    }
+
     // Check if there are any inactive (completed or failed) transfers to clear
     let has_inactive = manager.completed().next().is_some() || manager.failed().next().is_some();
 
-    // Clear Inactive button (in title row) - always visible, disabled when no inactive transfers
-    let clear_inactive_btn: Element<'a, Message> = {
-        let trash_icon = container(icon::trash().size(SIDEBAR_ACTION_ICON_SIZE))
-            .width(SIDEBAR_ACTION_ICON_SIZE)
-            .height(SIDEBAR_ACTION_ICON_SIZE)
-            .align_x(alignment::Horizontal::Center)
-            .align_y(alignment::Vertical::Center);
-
-        let btn = button(trash_icon).padding(ICON_BUTTON_PADDING);
-
-        let btn = if has_inactive {
-            btn.on_press(Message::TransferClearInactive)
-                .style(danger_icon_button_style)
-        } else {
-            btn.style(disabled_icon_button_style)
-        };
-
-        tooltip(
-            btn,
-            container(shaped_text(t("tooltip-clear-inactive")).size(TOOLTIP_TEXT_SIZE))
-                .padding(TOOLTIP_BACKGROUND_PADDING)
-                .style(tooltip_container_style),
-            tooltip::Position::Top,
-        )
-        .gap(TOOLTIP_GAP)
-        .padding(TOOLTIP_PADDING)
-        .into()
-    };
-
-    // Title row with clear inactive button on the right
-    // We add an invisible spacer on the left to balance the button width for proper centering
-    let button_width =
-        SIDEBAR_ACTION_ICON_SIZE + ICON_BUTTON_PADDING.left + ICON_BUTTON_PADDING.right;
-    let title_row: Element<'a, Message> = row![
-        Space::new().width(SCROLLBAR_PADDING),
-        Space::new().width(button_width), // Balance the button on the right
-        shaped_text(t("title-transfers"))
-            .size(TITLE_SIZE)
-            .width(Fill)
-            .align_x(Center),
-        clear_inactive_btn,
-        Space::new().width(SCROLLBAR_PADDING),
-    ]
-    .align_y(Center)
-    .into();
+    // History button on the left, Clear Inactive on the right (disabled when
+    // there are no inactive transfers)
+    let title_row = panel_title_row(
+        t("title-transfers"),
+        title_icon_button(
+            icon::file_text(),
+            Some(Message::TransferHistoryToggle),
+            "tooltip-transfer-history",
+            false,
+        ),
+        title_icon_button(
+            icon::trash(),
+            has_inactive.then_some(Message::TransferClearInactive),
+            "tooltip-clear-inactive",
+            true,
+        ),
+    );
 
     // Build transfer list
     let transfers: Vec<&Transfer> = manager.all().collect();
@@ -537,6 +776,14 @@ pub fn transfers_view<'a>(manager: &'a TransferManager) -> Element<'a, Message>
         rows.width(Fill).into()
     };
 
+    panel(column![title_row], scroll_content)
+}
+
+/// Wrap a panel's header and scrollable list in the centered panel layout
+fn panel<'a>(
+    header: Column<'a, Message>,
+    scroll_content: Element<'a, Message>,
+) -> Element<'a, Message> {
     // Scrollable content with symmetric padding for scrollbar space
     let padded_scroll_content = row![
         Space::new().width(SCROLLBAR_PADDING),
@@ -546,7 +793,7 @@ pub fn transfers_view<'a>(manager: &'a TransferManager) -> Element<'a, Message>
 
     // Build the form with max_width constraint (matching news panel)
     let form = column![
-        title_row,
+        header.spacing(ELEMENT_SPACING),
         Space::new().height(SPACER_SIZE_SMALL),
         container(scrollable(padded_scroll_content)).height(Fill),
     ]
@@ -572,6 +819,137 @@ pub fn transfers_view<'a>(manager: &'a TransferManager) -> Element<'a, Message>
         .into()
 }
 
+// ============================================================================
+// Transfer History
+// ============================================================================
+
+/// Build a single transfer history row
+///
+/// Layout:
+/// ```text
+/// ┌─────────────────────────────────────────────────────────────────────┐
+/// │ ↓  Transfer Name                                           📁  ✕    │
+/// │    Server • Completed • 1.0 GB • Jan 15, 2025 10:30                │
+/// │    /home/user/Downloads/Transfer Name                              │
+/// └─────────────────────────────────────────────────────────────────────┘
+/// ```
+fn build_history_row<'a>(record: &TransferRecord, index: usize) -> Element<'a, Message> {
+    let direction_icon = match record.direction {
+        TransferDirection::Download => icon::download(),
+        TransferDirection::Upload => icon::upload(),
+    };
+
+    let actions = row![
+        action_button_with_tooltip(
+            icon::folder(),
+            Message::TransferHistoryReveal(record.id),
+            "tooltip-transfer-reveal"
+        ),
+        danger_action_button_with_tooltip(
+            icon::close(),
+            Message::TransferHistoryRemove(record.id),
+            "tooltip-transfer-history-remove"
+        ),
+    ]
+    .spacing(SMALL_SPACING);
+
+    let title_row = row![
+        direction_icon.size(TRANSFER_ICON_SIZE),
+        container(shaped_text(record.name.clone()).size(TEXT_SIZE)).width(Fill),
+        actions,
+    ]
+    .spacing(ELEMENT_SPACING)
+    .align_y(Center);
+
+    let mut status_line = [
+        record.server_name.clone(),
+        status_text(record.status),
+        format_bytes(record.size),
+        format_finished_at(record.finished_at),
+    ]
+    .join(BULLET_SEPARATOR);
+    let status_row = if record.status == TransferStatus::Failed {
+        if let Some(error) = record.error.as_deref().filter(|e| !e.is_empty()) {
+            status_line.push_str(EM_DASH_SEPARATOR);
+            status_line.push_str(error);
+        }
+        shaped_text(status_line)
+            .size(DETAIL_TEXT_SIZE)
+            .style(error_text_style)
+    } else {
+        shaped_text(status_line)
+            .size(DETAIL_TEXT_SIZE)
+            .style(muted_text_style)
+    };
+
+    let location_row = shaped_text(record.local_path.to_string_lossy().into_owned())
+        .size(DETAIL_TEXT_SIZE)
+        .style(muted_text_style);
+
+    container(
+        column![title_row, status_row, location_row]
+            .spacing(TRANSFER_INFO_SPACING)
+            .padding(TRANSFER_ROW_PADDING)
+            .width(Fill),
+    )
+    .width(Fill)
+    .style(alternating_row_style(index.is_multiple_of(2)))
+    .into()
+}
+
+/// Displays the transfer history (finished transfers, newest first)
+fn transfer_history_view<'a>(history: &'a TransferHistory, search: &str) -> Element<'a, Message> {
+    // Back to the queue on the left, Clear History on the right
+    let title_row = panel_title_row(
+        t("title-transfer-history"),
+        title_icon_button(
+            icon::exchange(),
+            Some(Message::TransferHistoryToggle),
+            "tooltip-transfers",
+            false,
+        ),
+        title_icon_button(
+            icon::trash(),
+            (!history.is_empty()).then_some(Message::TransferHistoryClear),
+            "tooltip-transfer-history-clear",
+            true,
+        ),
+    );
+
+    let search_row = row![
+        Space::new().width(SCROLLBAR_PADDING),
+        text_input(&t("transfer-history-search-placeholder"), search)
+            .on_input(Message::TransferHistorySearchChanged)
+            .padding(INPUT_PADDING)
+            .size(TEXT_SIZE)
+            .width(Fill),
+        Space::new().width(SCROLLBAR_PADDING),
+    ];
+
+    let records: Vec<&TransferRecord> = history.search(search).collect();
+    let scroll_content: Element<'a, Message> = if records.is_empty() {
+        let key = if history.is_empty() {
+            "transfer-history-empty"
+        } else {
+            "transfer-history-no-matches"
+        };
+        shaped_text(t(key))
+            .size(TEXT_SIZE)
+            .width(Fill)
+            .align_x(Center)
+            .style(muted_text_style)
+            .into()
+    } else {
+        let mut rows = Column::new().spacing(TRANSFER_ITEM_SPACING);
+        for (index, record) in records.into_iter().enumerate() {
+            rows = rows.push(build_history_row(record, index));
+        }
+        rows.width(Fill).into()
+    };
+
+    panel(column![title_row, search_row], scroll_content)
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -11,7 +11,7 @@ use super::constants::PERMISSION_USER_EDIT;
 use super::layout::scrollable_panel;
 use crate::avatar::{avatar_cache_key, generate_identicon};
 use crate::handlers::network::constants::DATETIME_FORMAT;
-use crate::handlers::network::helpers::format_duration;
+use crate::handlers::network::helpers::{format_bytes, format_duration};
 use crate::i18n::{t, t_args};
 use crate::image::CachedImage;
 use crate::style::{
@@ -247,12 +247,14 @@ fn build_user_info_content<'a>(
             generate_identicon(nickname).render(USER_INFO_AVATAR_SIZE)
         };
 
-    // Build nickname display with 💤 if away
-    let nickname_display = if user.is_away {
-        format!("{} 💤", nickname)
-    } else {
-        nickname.clone()
-    };
+    // Build nickname display with 💤 if away and 🔕 if not to be disturbed
+    let mut nickname_display = nickname.clone();
+    if user.is_away {
+        nickname_display.push_str(" 💤");
+    }
+    if user.is_dnd {
+        nickname_display.push_str(" 🔕");
+    }
 
     // Apply color: admin = red, shared = muted, regular = default
     let nickname_text = if is_admin {
@@ -311,6 +313,15 @@ fn build_user_info_content<'a>(
     };
     content = content.push(info_row(t("user-info-connected"), connected_value, None));
 
+    // Idle time since the last chat or user message (once it's been at least a minute)
+    if let Some(last_active) = user.last_active {
+        let idle_secs = now.saturating_sub(last_active);
+        if idle_secs >= 60 {
+            let idle_value = format_duration(idle_secs as u64);
+            content = content.push(info_row(t("user-info-idle"), idle_value, None));
+        }
+    }
+
     // Features (sorted alphabetically for consistent display)
     let features_value = if user.features.is_empty() {
         t("user-info-features-none")
@@ -327,6 +338,11 @@ fn build_user_info_content<'a>(
     // Locale
     content = content.push(info_row(t("user-info-locale"), user.locale.clone(), None));
 
+    // Client software (if the client sent its name)
+    if let Some(client) = &user.client {
+        content = content.push(info_row(t("user-info-client"), client.clone(), None));
+    }
+
     // IP Addresses (only shown if field is present - admin viewers only)
     if let Some(addresses) = &user.addresses
         && !addresses.is_empty()
@@ -355,6 +371,31 @@ fn build_user_info_content<'a>(
         content = content.push(info_row(t("user-info-channels"), channels_value, None));
     }
 
+    // Rank earned from activity (if the server defines ranks)
+    if let Some(rank) = &user.rank {
+        content = content.push(info_row(t("user-info-rank"), rank.clone(), None));
+    }
+
+    // Lifetime activity counters (regular accounts only)
+    if let Some(stats) = &user.stats {
+        let counters = [
+            ("user-info-logins", stats.logins.to_string()),
+            ("user-info-messages", stats.messages.to_string()),
+            ("user-info-uploads", stats.uploads.to_string()),
+            ("user-info-downloaded", format_bytes(stats.download_bytes)),
+        ];
+        for (key, value) in counters {
+            content = content.push(info_row(t(key), value, None));
+        }
+        if let Some(last_login) = stats
+            .last_login_at
+            .and_then(|at| chrono::DateTime::from_timestamp(at, 0))
+        {
+            let last_login = last_login.format(DATETIME_FORMAT).to_string();
+            content = content.push(info_row(t("user-info-last-login"), last_login, None));
+        }
+    }
+
     // Account created
     let created = chrono::DateTime::from_timestamp(user.created_at, 0)
         .map(|dt| dt.format(DATETIME_FORMAT).to_string())
@@ -1,24 +1,39 @@
 //! User list panel (right sidebar)
 //!
+//! Starts with the Friends section when the bookmark has friends, showing
+//! whether each is online (shown even without the user_list permission).
+//!
 //! Shows contextual user list based on the active chat tab:
 //! - Console tab: All online users
 //! - Channel tab: Only channel members
 //! - User message tab: You + the other user (or just you if they're offline)
 //!
+//! A filter box above the list narrows it by nickname; see `crate::user_list`
+//! for its keyboard shortcuts. The list can be split into collapsible sections
+//! and sorted as chosen in Settings > Chat.
+//!
 //! Voice indicators:
 //! - Headphones icon: User is in voice (same session as current user)
 //! - Speaker icon with highlight: User is currently speaking
+//! - Colored dot: Connection quality reported by the server (green/yellow/red)
 //! - Mute button: Client-side mute (stops hearing that user)
 
-use iced::widget::{Column, Row, Space, button, column, container, row, scrollable, tooltip};
+use iced::widget::{
+    Column, Row, Space, button, column, container, row, scrollable, text_input, tooltip,
+};
 use iced::{Center, Color, Element, Fill, Theme};
+use nexus_common::voice::VoiceConnectionQuality;
+
+use nexus_common::protocol::PresenceInfo;
 
 use super::constants::{
-    PERMISSION_BAN_CREATE, PERMISSION_USER_INFO, PERMISSION_USER_KICK, PERMISSION_USER_MESSAGE,
-    PERMISSION_VOICE_LISTEN,
+    PERMISSION_BAN_CREATE, PERMISSION_USER_INFO, PERMISSION_USER_KICK, PERMISSION_USER_LIST,
+    PERMISSION_USER_MESSAGE, PERMISSION_VOICE_LISTEN,
 };
 use crate::avatar::{avatar_cache_key, generate_identicon};
-use crate::i18n::t;
+use crate::config::settings::{UserListGrouping, UserListSettings};
+use crate::handlers::network::helpers::format_duration;
+use crate::i18n::{t, t_args};
 use crate::icon;
 use crate::style::{
     CONTENT_PADDING, ICON_BUTTON_PADDING, INPUT_PADDING, NO_SPACING, SCROLLBAR_PADDING,
@@ -31,7 +46,8 @@ use crate::style::{
     user_list_item_button_style, user_toolbar_separator_style,
 };
 use crate::types::ActivePanel;
-use crate::types::{ChatTab, Message, ServerConnection, UserInfo};
+use crate::types::{ChatTab, InputId, Message, ServerConnection, UserInfo, VoiceParticipantStats};
+use crate::user_list::{UserGroup, group_users, matches_filter};
 
 // ============================================================================
 // Helper Functions
@@ -156,9 +172,82 @@ fn get_contextual_users(conn: &ServerConnection) -> Vec<&UserInfo> {
                 })
                 .collect()
         }
+        ChatTab::Group(group_id) => {
+            // Show the group's online members
+            let Some(group) = conn.groups.get(group_id) else {
+                return Vec::new();
+            };
+            conn.online_users
+                .iter()
+                .filter(|user| {
+                    let nickname_lower = user.nickname.to_lowercase();
+                    group
+                        .members
+                        .iter()
+                        .any(|m| m.to_lowercase() == nickname_lower)
+                })
+                .collect()
+        }
     }
 }
 
+/// Get our channels with their members, for grouping by channel
+///
+/// In a channel tab that's just the channel; otherwise every joined channel in
+/// tab order.
+fn grouping_channels(conn: &ServerConnection) -> Vec<(&str, &[String])> {
+    let channel_names: Vec<&String> = match &conn.active_chat_tab {
+        ChatTab::Channel(name) => vec![name],
+        _ => conn.channel_tabs.iter().collect(),
+    };
+    channel_names
+        .into_iter()
+        .filter_map(|name| {
+            conn.get_channel_state(name)
+                .map(|state| (name.as_str(), state.members.as_slice()))
+        })
+        .collect()
+}
+
+/// Get the list's sections: the contextual users that match the filter,
+/// grouped and sorted by the user list settings
+pub fn user_sections(
+    conn: &ServerConnection,
+    settings: UserListSettings,
+) -> Vec<(UserGroup, Vec<&UserInfo>)> {
+    let users = get_contextual_users(conn)
+        .into_iter()
+        .filter(|user| matches_filter(&user.nickname, &conn.user_list_filter))
+        .collect();
+    group_users(users, settings, &grouping_channels(conn))
+}
+
+/// Get the users shown in the list, in display order (collapsed sections left out)
+pub fn visible_users(conn: &ServerConnection, settings: UserListSettings) -> Vec<&UserInfo> {
+    user_sections(conn, settings)
+        .into_iter()
+        .filter(|(group, _)| !conn.collapsed_user_groups.contains(&group.key()))
+        .flat_map(|(_, users)| users)
+        .collect()
+}
+
+/// Seconds since a user last chatted, when it's been at least a minute
+///
+/// `now` is a timestamp by the server's clock, which stamped `last_active`.
+fn idle_seconds(user: &UserInfo, now: i64) -> Option<u64> {
+    let last_active = user.last_active?;
+    let idle = now.saturating_sub(last_active);
+    u64::try_from(idle).ok().filter(|idle| *idle >= 60)
+}
+
+/// Check whether an account is on the connection's friends list (case-insensitive)
+fn is_friend(conn: &ServerConnection, username: &str) -> bool {
+    let username_lower = username.to_lowercase();
+    conn.friends
+        .iter()
+        .any(|friend| friend.username.to_lowercase() == username_lower)
+}
+
 /// Get the title for the user list based on the active view
 fn get_user_list_title(conn: &ServerConnection) -> String {
     // When a panel is open, show generic "Users" title
@@ -168,7 +257,9 @@ fn get_user_list_title(conn: &ServerConnection) -> String {
 
     match &conn.active_chat_tab {
         ChatTab::Console => t("title-users"),
-        ChatTab::Channel(_) | ChatTab::UserMessage(_) => t("title-channel-members"),
+        ChatTab::Channel(_) | ChatTab::UserMessage(_) | ChatTab::Group(_) => {
+            t("title-channel-members")
+        }
     }
 }
 
@@ -180,10 +271,12 @@ fn get_user_list_title(conn: &ServerConnection) -> String {
 ///
 /// - `nickname`: The user's nickname (always populated; equals username for regular accounts).
 ///   Used for all actions: info, PM, kick - server looks up by nickname.
+/// - `target_is_shared`: Shared account sessions can't be friends (presence is per account).
 fn create_user_toolbar<'a>(
     nickname: &'a str,
     current_nickname: &'a str,
     target_is_admin: bool,
+    target_is_shared: bool,
     conn: &ServerConnection,
     theme: &Theme,
 ) -> Row<'a, Message> {
@@ -248,6 +341,24 @@ fn create_user_toolbar<'a>(
         toolbar_row = toolbar_row.push(with_tooltip(message_button, t("tooltip-message")));
     }
 
+    // Friend button (only for other regular accounts, on bookmarked connections)
+    if !is_self && !target_is_shared && conn.bookmark_id.is_some() {
+        let is_friend = is_friend(conn, nickname);
+        let friend_button = enabled_icon_button(
+            icon_container(icon::user_plus()),
+            Message::UserFriendToggled(nickname_owned.clone()),
+            primary_color,
+            // Show in primary color when already a friend
+            if is_friend { primary_color } else { icon_color },
+        );
+        let tooltip_key = if is_friend {
+            "tooltip-remove-friend"
+        } else {
+            "tooltip-add-friend"
+        };
+        toolbar_row = toolbar_row.push(with_tooltip(friend_button, t(tooltip_key)));
+    }
+
     // Mute/Unmute button (only show if not self, user is in voice with us, and we have voice_listen)
     if !is_self && is_in_voice_with_us && has_voice_listen {
         let nickname_for_mute = nickname_owned.clone();
@@ -307,20 +418,201 @@ fn create_user_toolbar<'a>(
 /// Only one user can be expanded at a time.
 /// Admin users are shown in red (using the chat admin color).
 ///
-/// Note: This panel is only shown when the user has `user_list` permission.
-/// Permission checking is done at the layout level.
+/// Note: This panel is only shown when the user has `user_list` permission
+/// or friends to show. Permission checking is done at the layout level.
 ///
-/// Build tooltip text for a user, including away/status information
-fn build_user_tooltip(nickname: &str, is_away: bool, status: Option<&str>) -> String {
-    match (is_away, status) {
-        (true, Some(status_msg)) => format!("{} 💤\n{}", nickname, status_msg),
-        (true, None) => format!("{} 💤", nickname),
-        (false, Some(status_msg)) => format!("{}\n{}", nickname, status_msg),
-        (false, None) => nickname.to_string(),
+/// Build tooltip text for a user, including away/dnd/status information
+fn build_user_tooltip(nickname: &str, is_away: bool, is_dnd: bool, status: Option<&str>) -> String {
+    let mut header = nickname.to_string();
+    if is_away {
+        header.push_str(" 💤");
+    }
+    if is_dnd {
+        header.push_str(" 🔕");
+    }
+    match status {
+        Some(status_msg) => format!("{}\n{}", header, status_msg),
+        None => header,
     }
 }
 
-pub fn user_list_panel<'a>(conn: &'a ServerConnection, theme: &Theme) -> Element<'a, Message> {
+/// Describe a voice participant's connection quality for the tooltip
+fn voice_stats_summary(stats: &VoiceParticipantStats) -> String {
+    t_args(
+        "voice-connection-tooltip",
+        &[
+            ("quality", &t(stats.quality().translation_key())),
+            ("loss", &stats.loss_percent.to_string()),
+            ("jitter", &stats.jitter_ms.to_string()),
+        ],
+    )
+}
+
+/// Build one Friends section row
+///
+/// The dot is green when online, yellow when away, and muted when offline.
+/// Clicking an online friend opens a message tab when permitted.
+fn friend_row<'a>(
+    friend: &'a PresenceInfo,
+    is_even: bool,
+    can_message: bool,
+    theme: &Theme,
+) -> Element<'a, Message> {
+    let palette = theme.extended_palette();
+    let dot_color = if !friend.online {
+        palette.background.strong.color
+    } else if friend.is_away {
+        palette.warning.base.color
+    } else {
+        palette.success.base.color
+    };
+
+    let name = if friend.online {
+        shaped_text(&friend.username).size(USER_LIST_TEXT_SIZE)
+    } else {
+        shaped_text(&friend.username)
+            .size(USER_LIST_TEXT_SIZE)
+            .style(muted_text_style)
+    };
+    let friend_row = row![
+        shaped_text("●")
+            .size(USER_LIST_SMALL_TEXT_SIZE)
+            .color(dot_color),
+        name,
+    ]
+    .spacing(USER_LIST_AVATAR_SPACING)
+    .align_y(Center);
+
+    let mut friend_button = button(container(friend_row).width(Fill))
+        .width(Fill)
+        .padding(INPUT_PADDING)
+        .style(user_list_item_button_style(false, chat::admin(theme)));
+    if friend.online && can_message {
+        friend_button =
+            friend_button.on_press(Message::UserMessageIconClicked(friend.username.clone()));
+    }
+
+    let tooltip_text = if friend.online {
+        build_user_tooltip(
+            &friend.username,
+            friend.is_away,
+            false,
+            friend.status.as_deref(),
+        )
+    } else {
+        format!("{}\n{}", friend.username, t("friend-offline"))
+    };
+
+    let friend_with_tooltip = tooltip(
+        friend_button,
+        container(shaped_text(tooltip_text).size(TOOLTIP_TEXT_SIZE))
+            .padding(TOOLTIP_BACKGROUND_PADDING)
+            .style(tooltip_container_style),
+        tooltip::Position::Left,
+    )
+    .gap(TOOLTIP_GAP)
+    .padding(TOOLTIP_PADDING);
+
+    container(friend_with_tooltip)
+        .width(Fill)
+        .style(alternating_row_style(is_even))
+        .into()
+}
+
+/// Build the Friends section (title + rows), online friends first
+///
+/// Returns None when the connection has no friends to show.
+fn friends_section<'a>(conn: &'a ServerConnection, theme: &Theme) -> Option<Column<'a, Message>> {
+    if conn.friends.is_empty() {
+        return None;
+    }
+
+    let mut friends: Vec<&PresenceInfo> = conn.friends.iter().collect();
+    friends.sort_by_key(|friend| !friend.online);
+
+    let can_message = conn.has_permission(PERMISSION_USER_MESSAGE);
+    let mut friends_column = Column::new().spacing(USER_LIST_ITEM_SPACING);
+    for (index, friend) in friends.into_iter().enumerate() {
+        friends_column =
+            friends_column.push(friend_row(friend, index % 2 == 0, can_message, theme));
+    }
+
+    Some(
+        column![
+            shaped_text(t("title-friends"))
+                .size(USER_LIST_TITLE_SIZE)
+                .style(muted_text_style),
+            friends_column,
+        ]
+        .spacing(USER_LIST_SPACING),
+    )
+}
+
+/// Build a section header: collapse toggle with the section's name and user count
+fn section_header<'a>(
+    group: &UserGroup,
+    count: usize,
+    collapsed: bool,
+    theme: &Theme,
+) -> Element<'a, Message> {
+    let chevron = if collapsed {
+        icon::expand_right()
+    } else {
+        icon::down_dir()
+    };
+    let name = match group {
+        UserGroup::All => String::new(),
+        UserGroup::Admins => t("user-list-section-admins"),
+        UserGroup::Users => t("user-list-section-users"),
+        UserGroup::Channel(name) => name.clone(),
+        UserGroup::NoChannel => t("user-list-section-no-channel"),
+        UserGroup::Online => t("user-list-section-online"),
+        UserGroup::Away => t("user-list-section-away"),
+        UserGroup::DoNotDisturb => t("user-list-section-dnd"),
+    };
+
+    let label = row![
+        chevron
+            .size(USER_LIST_SMALL_TEXT_SIZE)
+            .style(muted_text_style),
+        shaped_text(name)
+            .size(USER_LIST_SMALL_TEXT_SIZE)
+            .style(muted_text_style)
+            .width(Fill),
+        shaped_text(count.to_string())
+            .size(USER_LIST_SMALL_TEXT_SIZE)
+            .style(muted_text_style),
+    ]
+    .spacing(USER_LIST_AVATAR_SPACING)
+    .align_y(Center);
+
+    button(label)
+        .width(Fill)
+        .padding(INPUT_PADDING)
+        .on_press(Message::UserListGroupToggled(group.key()))
+        .style(user_list_item_button_style(false, chat::admin(theme)))
+        .into()
+}
+
+/// Add right padding to make room for the scrollbar
+fn with_scrollbar_padding<'a>(
+    content: impl Into<Element<'a, Message>>,
+) -> iced::widget::Container<'a, Message> {
+    container(content)
+        .padding(iced::Padding {
+            top: 0.0,
+            right: SCROLLBAR_PADDING,
+            bottom: 0.0,
+            left: 0.0,
+        })
+        .width(Fill)
+}
+
+pub fn user_list_panel<'a>(
+    conn: &'a ServerConnection,
+    settings: UserListSettings,
+    theme: &Theme,
+) -> Element<'a, Message> {
     // Use server-confirmed nickname for self-detection
     let current_nickname = &conn.nickname;
 
@@ -329,17 +621,31 @@ pub fn user_list_panel<'a>(conn: &'a ServerConnection, theme: &Theme) -> Element
         .size(USER_LIST_TITLE_SIZE)
         .style(muted_text_style);
 
-    // Get contextual user list based on active tab
-    let users = get_contextual_users(conn);
+    // Filter box (type to narrow the list, arrows and Enter act on it)
+    let filter_input = text_input(&t("user-list-filter-placeholder"), &conn.user_list_filter)
+        .id(InputId::UserListFilter)
+        .on_input(Message::UserListFilterChanged)
+        .padding(INPUT_PADDING)
+        .size(USER_LIST_TEXT_SIZE)
+        .width(Fill);
+
+    // Get contextual user list based on active tab, narrowed by the filter and
+    // split into sections (one headerless section when grouping is off)
+    let sections = user_sections(conn, settings);
+    let grouped = settings.grouping != UserListGrouping::None;
 
     let mut users_column = Column::new().spacing(USER_LIST_ITEM_SPACING);
 
-    if users.is_empty() {
-        // Show appropriate empty message based on tab type
-        let empty_message = match &conn.active_chat_tab {
-            ChatTab::Console => t("empty-no-users"),
-            ChatTab::Channel(_) => t("empty-no-channel-members"),
-            ChatTab::UserMessage(_) => t("empty-no-users"),
+    if sections.is_empty() {
+        // Show appropriate empty message based on the filter and tab type
+        let empty_message = if !conn.user_list_filter.trim().is_empty() {
+            t("empty-no-matching-users")
+        } else {
+            match &conn.active_chat_tab {
+                ChatTab::Console => t("empty-no-users"),
+                ChatTab::Channel(_) => t("empty-no-channel-members"),
+                ChatTab::UserMessage(_) | ChatTab::Group(_) => t("empty-no-users"),
+            }
         };
         users_column = users_column.push(
             shaped_text(empty_message)
@@ -347,20 +653,31 @@ pub fn user_list_panel<'a>(conn: &'a ServerConnection, theme: &Theme) -> Element
                 .style(muted_text_style),
         );
     } else {
-        for (index, user) in users.iter().enumerate() {
-            let is_expanded = conn.expanded_user.as_deref() == Some(user.nickname.as_str());
-            let is_even = index % 2 == 0;
-
-            // Username button with avatar
-            let user_is_admin = user.is_admin;
-            let user_is_shared = user.is_shared;
-            let nickname_clone = user.nickname.clone();
-            let nickname = &user.nickname;
-
-            // Get cached avatar (should already be populated by handlers)
-            // Avatar cache is keyed by nickname (always populated; equals username for regular accounts)
-            let avatar_element: Element<'_, Message> =
-                if let Some(cached_avatar) = conn.avatar_cache.get(&avatar_cache_key(nickname)) {
+        for (group, users) in sections {
+            let collapsed = conn.collapsed_user_groups.contains(&group.key());
+            if grouped {
+                users_column =
+                    users_column.push(section_header(&group, users.len(), collapsed, theme));
+            }
+            if collapsed {
+                continue;
+            }
+
+            for (index, user) in users.iter().enumerate() {
+                let is_expanded = conn.expanded_user.as_deref() == Some(user.nickname.as_str());
+                let is_even = index % 2 == 0;
+
+                // Username button with avatar
+                let user_is_admin = user.is_admin;
+                let user_is_shared = user.is_shared;
+                let nickname_clone = user.nickname.clone();
+                let nickname = &user.nickname;
+
+                // Get cached avatar (should already be populated by handlers)
+                // Avatar cache is keyed by nickname (always populated; equals username for regular accounts)
+                let avatar_element: Element<'_, Message> = if let Some(cached_avatar) =
+                    conn.avatar_cache.get(&avatar_cache_key(nickname))
+                {
                     cached_avatar.render(USER_LIST_AVATAR_SIZE)
                 } else {
                     // Fallback: generate identicon if not in cache (shouldn't happen normally)
@@ -368,150 +685,199 @@ pub fn user_list_panel<'a>(conn: &'a ServerConnection, theme: &Theme) -> Element
                     generate_identicon(nickname).render(USER_LIST_AVATAR_SIZE)
                 };
 
-            // Row with avatar and nickname (always populated; equals username for regular accounts)
-            // Apply appropriate color: admin = red, shared = muted, regular = default
-            let nickname_text = if user_is_admin {
-                shaped_text(nickname)
-                    .size(USER_LIST_TEXT_SIZE)
-                    .color(chat::admin(theme))
-            } else if user_is_shared {
-                shaped_text(nickname)
-                    .size(USER_LIST_TEXT_SIZE)
-                    .color(chat::shared(theme))
-            } else {
-                shaped_text(nickname).size(USER_LIST_TEXT_SIZE)
-            };
-
-            // Check if user is in voice for the current tab
-            // Only show voice indicators when viewing the channel/target we're in voice for
-            let nickname_lower = nickname.to_lowercase();
-            let current_tab_target = match &conn.active_chat_tab {
-                ChatTab::Channel(name) => Some(name.to_lowercase()),
-                ChatTab::UserMessage(name) => Some(name.to_lowercase()),
-                ChatTab::Console => None,
-            };
+                // Row with avatar and nickname (always populated; equals username for regular accounts)
+                // Apply appropriate color: admin = red, shared = muted, regular = default
+                let nickname_text = if user_is_admin {
+                    shaped_text(nickname)
+                        .size(USER_LIST_TEXT_SIZE)
+                        .color(chat::admin(theme))
+                } else if user_is_shared {
+                    shaped_text(nickname)
+                        .size(USER_LIST_TEXT_SIZE)
+                        .color(chat::shared(theme))
+                } else {
+                    shaped_text(nickname).size(USER_LIST_TEXT_SIZE)
+                };
 
-            let is_in_voice = if let Some(ref session) = conn.voice_session {
-                // We're in voice - only show indicators if viewing the same target
-                let session_target = session.target.to_lowercase();
-                current_tab_target
-                    .as_ref()
-                    .is_some_and(|tab| *tab == session_target)
-                    && session
-                        .participants
-                        .iter()
-                        .any(|p| p.to_lowercase() == nickname_lower)
-            } else if let ChatTab::Channel(channel_name) = &conn.active_chat_tab {
-                // Not in voice, but viewing a channel - check channel_voiced
-                conn.channel_voiced
-                    .get(&channel_name.to_lowercase())
-                    .map(|users| users.contains(&nickname_lower))
-                    .unwrap_or(false)
-            } else {
-                false
-            };
+                // Check if user is in voice for the current tab
+                // Only show voice indicators when viewing the channel/target we're in voice for
+                let nickname_lower = nickname.to_lowercase();
+                let current_tab_target = match &conn.active_chat_tab {
+                    ChatTab::Channel(name) => Some(name.to_lowercase()),
+                    ChatTab::UserMessage(name) => Some(name.to_lowercase()),
+                    ChatTab::Console | ChatTab::Group(_) => None,
+                };
 
-            let is_speaking = conn.voice_session.as_ref().is_some_and(|s| {
-                // Only show speaking indicator if viewing the same target as our voice session
-                let session_target = s.target.to_lowercase();
-                current_tab_target
-                    .as_ref()
-                    .is_some_and(|tab| *tab == session_target)
-                    && s.is_speaking(nickname)
-            });
-
-            // Build user row with avatar, nickname, and optional voice icon
-            let mut user_row = Row::new().spacing(USER_LIST_AVATAR_SPACING).align_y(Center);
-
-            user_row = user_row.push(avatar_element);
-            user_row = user_row.push(nickname_text);
-
-            // Add voice indicator at end if in voice
-            if is_in_voice {
-                let voice_icon = if is_speaking {
-                    // Speaking - show mic icon with highlight (green)
-                    container(icon::mic().size(USER_LIST_SMALL_TEXT_SIZE))
-                        .style(crate::style::speaking_indicator_style)
+                let is_in_voice = if let Some(ref session) = conn.voice_session {
+                    // We're in voice - only show indicators if viewing the same target
+                    let session_target = session.target.to_lowercase();
+                    current_tab_target
+                        .as_ref()
+                        .is_some_and(|tab| *tab == session_target)
+                        && session
+                            .participants
+                            .iter()
+                            .any(|p| p.to_lowercase() == nickname_lower)
+                } else if let ChatTab::Channel(channel_name) = &conn.active_chat_tab {
+                    // Not in voice, but viewing a channel - check channel_voiced
+                    conn.channel_voiced
+                        .get(&channel_name.to_lowercase())
+                        .map(|users| users.contains(&nickname_lower))
+                        .unwrap_or(false)
                 } else {
-                    // In voice but not speaking - show headphones (muted color, same for all users)
-                    container(
-                        icon::headphones()
-                            .size(USER_LIST_SMALL_TEXT_SIZE)
-                            .style(muted_text_style),
-                    )
+                    false
                 };
-                user_row = user_row.push(voice_icon);
-            }
 
-            let user_button = button(container(user_row).width(Fill))
-                .on_press(Message::UserListItemClicked(nickname_clone))
-                .width(Fill)
-                .padding(INPUT_PADDING)
-                .style(user_list_item_button_style(
-                    user_is_admin,
-                    chat::admin(theme),
-                ));
-
-            // Tooltip: show nickname with away/status if set
-            let tooltip_text = build_user_tooltip(nickname, user.is_away, user.status.as_deref());
-
-            // Wrap button in tooltip showing full name (useful when truncated)
-            let user_button_with_tooltip = tooltip(
-                user_button,
-                container(shaped_text(tooltip_text).size(TOOLTIP_TEXT_SIZE))
-                    .padding(TOOLTIP_BACKGROUND_PADDING)
-                    .style(tooltip_container_style),
-                tooltip::Position::Left,
-            )
-            .gap(TOOLTIP_GAP)
-            .padding(TOOLTIP_PADDING);
-
-            // Create item column (username + optional toolbar)
-            let mut item_column = Column::new().spacing(NO_SPACING);
-
-            // Username button with tooltip
-            item_column = item_column.push(user_button_with_tooltip);
-
-            // Add toolbar if expanded
-            if is_expanded {
-                // Primary color separator line
-                item_column = item_column.push(toolbar_separator());
-
-                // Toolbar
-                let toolbar = create_user_toolbar(
-                    &user.nickname,
-                    current_nickname,
-                    user.is_admin,
-                    conn,
-                    theme,
-                );
-                let toolbar_row = container(toolbar)
+                let is_speaking = conn.voice_session.as_ref().is_some_and(|s| {
+                    // Only show speaking indicator if viewing the same target as our voice session
+                    let session_target = s.target.to_lowercase();
+                    current_tab_target
+                        .as_ref()
+                        .is_some_and(|tab| *tab == session_target)
+                        && s.is_speaking(nickname)
+                });
+
+                // Latest connection statistics, only for our own voice session
+                let voice_stats = conn
+                    .voice_session
+                    .as_ref()
+                    .filter(|s| {
+                        current_tab_target
+                            .as_ref()
+                            .is_some_and(|tab| *tab == s.target.to_lowercase())
+                    })
+                    .and_then(|s| s.stats(nickname))
+                    .copied();
+
+                // Build user row with avatar, nickname, and optional voice icon
+                let mut user_row = Row::new().spacing(USER_LIST_AVATAR_SPACING).align_y(Center);
+
+                user_row = user_row.push(avatar_element);
+                user_row = user_row.push(nickname_text);
+
+                // Add voice indicator at end if in voice
+                if is_in_voice {
+                    let voice_icon = if is_speaking {
+                        // Speaking - show mic icon with highlight (green)
+                        container(icon::mic().size(USER_LIST_SMALL_TEXT_SIZE))
+                            .style(crate::style::speaking_indicator_style)
+                    } else {
+                        // In voice but not speaking - show headphones (muted color, same for all users)
+                        container(
+                            icon::headphones()
+                                .size(USER_LIST_SMALL_TEXT_SIZE)
+                                .style(muted_text_style),
+                        )
+                    };
+                    user_row = user_row.push(voice_icon);
+
+                    if let Some(stats) = voice_stats {
+                        let palette = theme.extended_palette();
+                        let quality_color = match stats.quality() {
+                            VoiceConnectionQuality::Good => palette.success.base.color,
+                            VoiceConnectionQuality::Fair => palette.warning.base.color,
+                            VoiceConnectionQuality::Poor => palette.danger.base.color,
+                        };
+                        user_row = user_row.push(
+                            shaped_text("●")
+                                .size(USER_LIST_SMALL_TEXT_SIZE)
+                                .color(quality_color),
+                        );
+                    }
+                }
+
+                let user_button = button(container(user_row).width(Fill))
+                    .on_press(Message::UserListItemClicked(nickname_clone))
                     .width(Fill)
-                    .padding(TOOLBAR_CONTAINER_PADDING);
-                item_column = item_column.push(toolbar_row);
-            }
-
-            // Wrap entire item (username + toolbar) in container with alternating background
-            let item_container = container(item_column)
-                .width(Fill)
-                .style(alternating_row_style(is_even));
+                    .padding(INPUT_PADDING)
+                    .style(user_list_item_button_style(
+                        user_is_admin,
+                        chat::admin(theme),
+                    ));
+
+                // Tooltip: show nickname with away/dnd/status if set
+                let mut tooltip_text =
+                    build_user_tooltip(nickname, user.is_away, user.is_dnd, user.status.as_deref());
+                if let Some(stats) = voice_stats {
+                    tooltip_text.push('\n');
+                    tooltip_text.push_str(&voice_stats_summary(&stats));
+                }
+                if let Some(idle) = idle_seconds(user, conn.server_now().timestamp()) {
+                    tooltip_text.push('\n');
+                    tooltip_text.push_str(&t_args(
+                        "user-list-idle",
+                        &[("duration", &format_duration(idle))],
+                    ));
+                }
+
+                // Wrap button in tooltip showing full name (useful when truncated)
+                let user_button_with_tooltip = tooltip(
+                    user_button,
+                    container(shaped_text(tooltip_text).size(TOOLTIP_TEXT_SIZE))
+                        .padding(TOOLTIP_BACKGROUND_PADDING)
+                        .style(tooltip_container_style),
+                    tooltip::Position::Left,
+                )
+                .gap(TOOLTIP_GAP)
+                .padding(TOOLTIP_PADDING);
+
+                // Create item column (username + optional toolbar)
+                let mut item_column = Column::new().spacing(NO_SPACING);
+
+                // Username button with tooltip
+                item_column = item_column.push(user_button_with_tooltip);
+
+                // Add toolbar if expanded
+                if is_expanded {
+                    // Primary color separator line
+                    item_column = item_column.push(toolbar_separator());
+
+                    // Toolbar
+                    let toolbar = create_user_toolbar(
+                        &user.nickname,
+                        current_nickname,
+                        user.is_admin,
+                        user.is_shared,
+                        conn,
+                        theme,
+                    );
+                    let toolbar_row = container(toolbar)
+                        .width(Fill)
+                        .padding(TOOLBAR_CONTAINER_PADDING);
+                    item_column = item_column.push(toolbar_row);
+                }
+
+                // Wrap entire item (username + toolbar) in container with alternating background
+                let item_container = container(item_column)
+                    .width(Fill)
+                    .style(alternating_row_style(is_even));
 
-            users_column = users_column.push(item_container);
+                users_column = users_column.push(item_container);
+            }
         }
     }
 
-    // Add right padding to make room for scrollbar
-    let users_column = container(users_column)
-        .padding(iced::Padding {
-            top: 0.0,
-            right: SCROLLBAR_PADDING,
-            bottom: 0.0,
-            left: 0.0,
-        })
-        .width(Fill);
-
-    let panel = column![title, scrollable(users_column).height(Fill),]
+    // With friends, the whole list scrolls together; the users part needs
+    // the user_list permission (friends alone can open the panel)
+    let can_view_users = conn.has_permission(PERMISSION_USER_LIST);
+    let list: Element<'_, Message> = match friends_section(conn, theme) {
+        Some(friends) => {
+            let mut list = column![friends].spacing(USER_LIST_SPACING);
+            if can_view_users {
+                list = list.push(title).push(filter_input).push(users_column);
+            }
+            scrollable(with_scrollbar_padding(list)).height(Fill).into()
+        }
+        None => column![
+            title,
+            with_scrollbar_padding(filter_input),
+            scrollable(with_scrollbar_padding(users_column)).height(Fill),
+        ]
         .spacing(USER_LIST_SPACING)
+        .into(),
+    };
+
+    let panel = column![list]
         .padding(iced::Padding {
             top: CONTENT_PADDING,
             right: CONTENT_PADDING - SCROLLBAR_PADDING,
@@ -2,13 +2,17 @@
 
 use iced::widget::button as btn;
 use iced::widget::{
-    Column, Id, Row, Space, button, checkbox, column, container, row, scrollable, text, text_input,
-    tooltip,
+    Column, Id, Row, Space, button, checkbox, column, container, pick_list, row, scrollable, text,
+    text_input, tooltip,
 };
 use iced::{Center, Element, Fill, Theme, alignment};
-use nexus_common::is_shared_account_permission;
+use nexus_common::protocol::{
+    ChannelInfo, EffectivePermissions, PermissionHolder, PermissionSource,
+};
+use nexus_common::{ALL_PERMISSIONS, is_guest_account_permission, is_shared_account_permission};
 
 use super::constants::{PERMISSION_USER_CREATE, PERMISSION_USER_DELETE, PERMISSION_USER_EDIT};
+use super::files::build_navigate_path;
 use super::layout::scrollable_panel;
 use crate::i18n::{t, translate_permission};
 use crate::icon;
@@ -19,11 +23,14 @@ use crate::style::{
     SIDEBAR_ACTION_ICON_SIZE, SPACER_SIZE_MEDIUM, SPACER_SIZE_SMALL, TEXT_SIZE, TITLE_SIZE,
     TOOLTIP_BACKGROUND_PADDING, TOOLTIP_GAP, TOOLTIP_PADDING, TOOLTIP_TEXT_SIZE,
     alternating_row_style, chat, content_background_style, danger_icon_button_style,
-    error_text_style, muted_text_style, panel_title, shaped_text, shaped_text_wrapped,
-    tooltip_container_style, transparent_icon_button_style,
+    error_text_style, list_item_button_style, muted_text_style, panel_title, shaped_text,
+    shaped_text_wrapped, tooltip_container_style, transparent_icon_button_style,
 };
 use crate::types::InputId;
-use crate::types::{Message, ServerConnection, UserManagementMode, UserManagementState};
+use crate::types::{
+    AuditPermission, Impersonation, Message, PermissionAuditResult, ServerConnection,
+    UserManagementMode, UserManagementState,
+};
 
 // ============================================================================
 // Edit User Context
@@ -87,10 +94,12 @@ fn danger_delete_button(
 /// Build permission checkboxes split into two columns
 ///
 /// When `is_shared` is true, permissions not in `SHARED_ACCOUNT_PERMISSIONS` are disabled.
+/// When `is_guest` is true, permissions not in `GUEST_ACCOUNT_PERMISSIONS` are disabled too.
 fn build_permission_columns<'a, F>(
     permissions: &'a [(String, bool)],
     conn: &'a ServerConnection,
     is_shared: bool,
+    is_guest: bool,
     on_toggle: F,
 ) -> Element<'a, Message>
 where
@@ -107,11 +116,12 @@ where
         // Check if this permission is allowed for the current user
         let user_can_toggle = conn.has_permission(permission);
 
-        // Check if this permission is forbidden for shared accounts
-        let forbidden_for_shared = is_shared && !is_shared_account_permission(permission);
+        // Check if this permission is forbidden for shared accounts or the guest account
+        let forbidden_for_shared = (is_shared && !is_shared_account_permission(permission))
+            || (is_guest && !is_guest_account_permission(permission));
 
         let checkbox_widget = if user_can_toggle && !forbidden_for_shared {
-            // Can toggle: user has permission and it's not forbidden for shared accounts
+            // Can toggle: user has permission and it's not forbidden for this account
             checkbox(*enabled)
                 .label(display_name)
                 .on_toggle(move |checked| on_toggle_clone(perm_name.clone(), checked))
@@ -300,24 +310,56 @@ fn list_view<'a>(
         None
     };
 
-    // Title row with create button on the right
-    // Use matching spacer on left to keep title centered when button is present
-    let title_row: Element<'a, Message> = if let Some(create_btn) = create_btn {
-        container(
-            row![
-                Space::new().width(
-                    SIDEBAR_ACTION_ICON_SIZE + ICON_BUTTON_PADDING.left + ICON_BUTTON_PADDING.right
-                ),
-                shaped_text(t("title-user-management"))
-                    .size(TITLE_SIZE)
-                    .width(Fill)
-                    .align_x(Center),
-                create_btn,
-            ]
-            .align_y(Center),
+    // Permission audit button (admin only)
+    let audit_btn: Option<Element<'a, Message>> = if conn.is_admin {
+        let audit_icon = container(icon::search().size(SIDEBAR_ACTION_ICON_SIZE))
+            .width(SIDEBAR_ACTION_ICON_SIZE)
+            .height(SIDEBAR_ACTION_ICON_SIZE)
+            .align_x(alignment::Horizontal::Center)
+            .align_y(alignment::Vertical::Center);
+
+        Some(
+            tooltip(
+                button(audit_icon)
+                    .on_press(Message::UserManagementShowAudit)
+                    .padding(ICON_BUTTON_PADDING)
+                    .style(transparent_icon_button_style),
+                container(shaped_text(t("tooltip-permission-audit")).size(TOOLTIP_TEXT_SIZE))
+                    .padding(TOOLTIP_BACKGROUND_PADDING)
+                    .style(tooltip_container_style),
+                tooltip::Position::Top,
+            )
+            .gap(TOOLTIP_GAP)
+            .padding(TOOLTIP_PADDING)
+            .into(),
         )
-        .width(CONTENT_MAX_WIDTH - CONTENT_PADDING * 2.0)
-        .into()
+    } else {
+        None
+    };
+
+    let header_buttons: Vec<Element<'a, Message>> =
+        audit_btn.into_iter().chain(create_btn).collect();
+
+    // Title row with header buttons on the right
+    // Use matching spacer on left to keep title centered when buttons are present
+    let title_row: Element<'a, Message> = if !header_buttons.is_empty() {
+        let button_width =
+            SIDEBAR_ACTION_ICON_SIZE + ICON_BUTTON_PADDING.left + ICON_BUTTON_PADDING.right;
+        let mut title_items: Vec<Element<'a, Message>> = vec![
+            Space::new()
+                .width(button_width * header_buttons.len() as f32)
+                .into(),
+            shaped_text(t("title-user-management"))
+                .size(TITLE_SIZE)
+                .width(Fill)
+                .align_x(Center)
+                .into(),
+        ];
+        title_items.extend(header_buttons);
+
+        container(Row::with_children(title_items).align_y(Center))
+            .width(CONTENT_MAX_WIDTH - CONTENT_PADDING * 2.0)
+            .into()
     } else {
         container(
             shaped_text(t("title-user-management"))
@@ -467,6 +509,7 @@ fn create_view<'a>(
         &user_management.permissions,
         conn,
         user_management.is_shared,
+        false,
         Message::UserManagementPermissionToggled,
     );
 
@@ -616,6 +659,7 @@ fn edit_view<'a>(ctx: EditUserContext<'a>) -> Element<'a, Message> {
         ctx.permissions,
         ctx.conn,
         ctx.is_shared,
+        ctx.is_guest,
         Message::UserManagementEditPermissionToggled,
     );
 
@@ -677,7 +721,12 @@ fn edit_view<'a>(ctx: EditUserContext<'a>) -> Element<'a, Message> {
 // ============================================================================
 
 /// Build the delete confirmation modal
-fn confirm_delete_modal<'a>(username: &'a str, error: Option<&'a String>) -> Element<'a, Message> {
+fn confirm_delete_modal<'a>(
+    username: &'a str,
+    erase: bool,
+    can_erase: bool,
+    error: Option<&'a String>,
+) -> Element<'a, Message> {
     let title = panel_title(t("title-confirm-delete"));
 
     let message = shaped_text_wrapped(t_args("confirm-delete-user", &[("username", username)]))
@@ -712,8 +761,21 @@ fn confirm_delete_modal<'a>(username: &'a str, error: Option<&'a String>) -> Ele
         form_items.push(Space::new().height(SPACER_SIZE_MEDIUM).into());
     }
 
+    form_items.push(message.into());
+
+    // Erasing personal data is admin-only
+    if can_erase {
+        form_items.push(
+            checkbox(erase)
+                .label(t("label-erase-personal-data"))
+                .on_toggle(Message::UserManagementEraseToggled)
+                .size(TEXT_SIZE)
+                .text_shaping(text::Shaping::Advanced)
+                .into(),
+        );
+    }
+
     form_items.extend([
-        message.into(),
         Space::new().height(SPACER_SIZE_MEDIUM).into(),
         row![Space::new().width(Fill), cancel_button, confirm_button]
             .spacing(ELEMENT_SPACING)
@@ -728,6 +790,504 @@ fn confirm_delete_modal<'a>(username: &'a str, error: Option<&'a String>) -> Ele
     scrollable_panel(form)
 }
 
+// ============================================================================
+// Permission Audit View
+// ============================================================================
+
+/// Build a row for the audit lists: label, muted tags, and an audit button
+fn audit_row<'a>(
+    label: Element<'a, Message>,
+    tags: Vec<String>,
+    on_audit: Message,
+    index: usize,
+) -> Element<'a, Message> {
+    let mut row_content = Row::new()
+        .spacing(ELEMENT_SPACING)
+        .align_y(alignment::Vertical::Center)
+        .push(label);
+
+    if !tags.is_empty() {
+        row_content = row_content.push(
+            shaped_text(tags.join(", "))
+                .size(TEXT_SIZE)
+                .style(muted_text_style),
+        );
+    }
+
+    let audit_btn = tooltip(
+        transparent_edit_button(icon::search(), on_audit),
+        container(shaped_text(t("tooltip-permission-audit")).size(TOOLTIP_TEXT_SIZE))
+            .padding(TOOLTIP_BACKGROUND_PADDING)
+            .style(tooltip_container_style),
+        tooltip::Position::Top,
+    )
+    .gap(TOOLTIP_GAP)
+    .padding(TOOLTIP_PADDING);
+
+    let audit_row = row![
+        container(row_content)
+            .width(Fill)
+            .height(SERVER_LIST_BUTTON_HEIGHT)
+            .padding(INPUT_PADDING)
+            .align_y(alignment::Vertical::Center),
+        audit_btn,
+    ]
+    .align_y(alignment::Vertical::Center);
+
+    container(audit_row)
+        .width(Fill)
+        .style(alternating_row_style(index % 2 == 0))
+        .into()
+}
+
+/// Build the holders list for the selected permission
+fn holders_list<'a>(holders: &'a [PermissionHolder], theme: &Theme) -> Element<'a, Message> {
+    if holders.is_empty() {
+        return shaped_text(t("permission-audit-no-holders"))
+            .size(TEXT_SIZE)
+            .width(Fill)
+            .align_x(Center)
+            .style(muted_text_style)
+            .into();
+    }
+
+    let rows = holders.iter().enumerate().map(|(index, holder)| {
+        let is_admin = holder.source == PermissionSource::Admin;
+        let username = if is_admin {
+            shaped_text(&holder.username)
+                .size(SERVER_LIST_TEXT_SIZE)
+                .color(chat::admin(theme))
+        } else {
+            shaped_text(&holder.username).size(SERVER_LIST_TEXT_SIZE)
+        };
+
+        let mut tags = Vec::new();
+        if is_admin {
+            tags.push(t("label-admin"));
+        }
+        if holder.is_shared {
+            tags.push(t("label-shared-account"));
+        }
+        if !holder.enabled {
+            tags.push(t("permission-audit-disabled"));
+        }
+
+        audit_row(
+            username.into(),
+            tags,
+            Message::UserManagementAuditUserClicked(holder.username.clone()),
+            index,
+        )
+    });
+
+    Column::with_children(rows)
+        .spacing(SERVER_LIST_ITEM_SPACING)
+        .width(Fill)
+        .into()
+}
+
+/// Build the effective permissions of one account
+fn effective_list(effective: &EffectivePermissions) -> Element<'_, Message> {
+    let mut items: Vec<Element<'_, Message>> = vec![
+        shaped_text_wrapped(t_args(
+            "permission-audit-effective",
+            &[("username", &effective.username)],
+        ))
+        .size(TEXT_SIZE)
+        .width(Fill)
+        .into(),
+    ];
+
+    let mut tags = Vec::new();
+    if effective.is_admin {
+        tags.push(t("permission-audit-admin"));
+    }
+    if effective.is_shared {
+        tags.push(t("label-shared-account"));
+    }
+    if !effective.enabled {
+        tags.push(t("permission-audit-disabled"));
+    }
+    if !tags.is_empty() {
+        items.push(
+            shaped_text_wrapped(tags.join(", "))
+                .size(TEXT_SIZE)
+                .width(Fill)
+                .style(muted_text_style)
+                .into(),
+        );
+    }
+
+    if effective.permissions.is_empty() {
+        items.push(
+            shaped_text(t("permission-audit-no-permissions"))
+                .size(TEXT_SIZE)
+                .width(Fill)
+                .align_x(Center)
+                .style(muted_text_style)
+                .into(),
+        );
+    } else {
+        let rows = effective
+            .permissions
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                audit_row(
+                    shaped_text(translate_permission(&entry.permission))
+                        .size(SERVER_LIST_TEXT_SIZE)
+                        .into(),
+                    Vec::new(),
+                    Message::UserManagementAuditPermissionSelected(AuditPermission(
+                        entry.permission.clone(),
+                    )),
+                    index,
+                )
+            });
+        items.push(
+            Column::with_children(rows)
+                .spacing(SERVER_LIST_ITEM_SPACING)
+                .width(Fill)
+                .into(),
+        );
+    }
+
+    // Stored permissions a shared account can't use
+    if !effective.restricted.is_empty() {
+        let restricted: Vec<String> = effective
+            .restricted
+            .iter()
+            .map(String::as_str)
+            .map(translate_permission)
+            .collect();
+        items.push(Space::new().height(SPACER_SIZE_SMALL).into());
+        items.push(
+            shaped_text_wrapped(t("permission-audit-restricted"))
+                .size(TEXT_SIZE)
+                .width(Fill)
+                .into(),
+        );
+        items.push(
+            shaped_text_wrapped(restricted.join(", "))
+                .size(TEXT_SIZE)
+                .width(Fill)
+                .style(error_text_style)
+                .into(),
+        );
+    }
+
+    let view_as_button = button(shaped_text(t("button-view-as-user")).size(TEXT_SIZE))
+        .on_press(Message::UserManagementImpersonate(
+            effective.username.clone(),
+        ))
+        .padding(BUTTON_PADDING)
+        .style(btn::secondary);
+    items.push(row![Space::new().width(Fill), view_as_button].into());
+
+    Column::with_children(items)
+        .spacing(ELEMENT_SPACING)
+        .width(Fill)
+        .into()
+}
+
+/// Build the permission audit view
+///
+/// The picker lists who holds a permission; clicking a holder shows their
+/// effective permissions, and clicking a permission there lists its holders.
+fn audit_view<'a>(user_management: &'a UserManagementState, theme: &Theme) -> Element<'a, Message> {
+    let title = panel_title(t("title-permission-audit"));
+
+    let options: Vec<AuditPermission> = ALL_PERMISSIONS
+        .iter()
+        .map(|permission| AuditPermission(permission.to_string()))
+        .collect();
+    let picker = pick_list(
+        options,
+        user_management
+            .audit_permission
+            .clone()
+            .map(AuditPermission),
+        Message::UserManagementAuditPermissionSelected,
+    )
+    .text_size(TEXT_SIZE)
+    .width(Fill);
+
+    let content: Element<'a, Message> = match &user_management.audit {
+        None if user_management.audit_permission.is_none() => {
+            shaped_text_wrapped(t("permission-audit-hint"))
+                .size(TEXT_SIZE)
+                .width(Fill)
+                .align_x(Center)
+                .style(muted_text_style)
+                .into()
+        }
+        None => shaped_text(t("permission-audit-loading"))
+            .size(TEXT_SIZE)
+            .width(Fill)
+            .align_x(Center)
+            .style(muted_text_style)
+            .into(),
+        Some(Err(error)) => shaped_text_wrapped(error)
+            .size(TEXT_SIZE)
+            .width(Fill)
+            .align_x(Center)
+            .style(error_text_style)
+            .into(),
+        Some(Ok(PermissionAuditResult::Holders(holders))) => holders_list(holders, theme),
+        Some(Ok(PermissionAuditResult::Effective(effective))) => effective_list(effective),
+    };
+
+    let close_button = button(shaped_text(t("button-close")).size(TEXT_SIZE))
+        .on_press(Message::CancelUserManagement)
+        .padding(BUTTON_PADDING)
+        .style(btn::secondary);
+
+    let form = Column::with_children([
+        title.into(),
+        Space::new().height(SPACER_SIZE_SMALL).into(),
+        picker.into(),
+        content,
+        Space::new().height(SPACER_SIZE_MEDIUM).into(),
+        row![Space::new().width(Fill), close_button]
+            .spacing(ELEMENT_SPACING)
+            .into(),
+    ])
+    .spacing(ELEMENT_SPACING)
+    .padding(CONTENT_PADDING)
+    .max_width(CONTENT_MAX_WIDTH);
+
+    scrollable_panel(form)
+}
+
+// ============================================================================
+// Impersonation View
+// ============================================================================
+
+/// Build a muted placeholder line (empty list, no access)
+fn muted_line<'a>(message: String) -> Element<'a, Message> {
+    shaped_text_wrapped(message)
+        .size(TEXT_SIZE)
+        .width(Fill)
+        .style(muted_text_style)
+        .into()
+}
+
+/// Build a row for the impersonation file list
+///
+/// Folders open as the user on click; files are display only.
+fn impersonate_file_row<'a>(
+    icon: iced::widget::Text<'a>,
+    name: &'a str,
+    open: Option<Message>,
+    index: usize,
+) -> Element<'a, Message> {
+    let content = row![
+        icon.size(SERVER_LIST_TEXT_SIZE),
+        shaped_text(name).size(SERVER_LIST_TEXT_SIZE),
+    ]
+    .spacing(ELEMENT_SPACING)
+    .align_y(alignment::Vertical::Center);
+
+    let entry: Element<'a, Message> = match open {
+        Some(message) => button(content)
+            .on_press(message)
+            .width(Fill)
+            .padding(INPUT_PADDING)
+            .style(list_item_button_style(false, false))
+            .into(),
+        None => container(content).width(Fill).padding(INPUT_PADDING).into(),
+    };
+
+    container(entry)
+        .width(Fill)
+        .style(alternating_row_style(index % 2 == 0))
+        .into()
+}
+
+/// Build the file section: the listed folder as the user sees it
+fn impersonate_files(impersonation: &Impersonation) -> Vec<Element<'_, Message>> {
+    let (Some(path), Some(entries)) = (&impersonation.path, &impersonation.entries) else {
+        return vec![muted_line(t("impersonate-no-file-access"))];
+    };
+
+    let mut items = vec![
+        shaped_text_wrapped(t_args("impersonate-files", &[("path", path.as_str())]))
+            .size(TEXT_SIZE)
+            .width(Fill)
+            .into(),
+    ];
+
+    let mut rows = Vec::new();
+    let current = path.trim_end_matches('/');
+    if !current.is_empty() {
+        let parent = current
+            .rfind('/')
+            .map(|pos| current[..pos].to_string())
+            .unwrap_or_default();
+        rows.push(impersonate_file_row(
+            icon::up_dir(),
+            "..",
+            Some(Message::UserManagementImpersonateOpenFolder(parent)),
+            rows.len(),
+        ));
+    }
+    for entry in entries {
+        let (icon, open) = if entry.dir_type.is_some() {
+            let target = build_navigate_path(current, &entry.name);
+            (
+                icon::folder(),
+                Some(Message::UserManagementImpersonateOpenFolder(target)),
+            )
+        } else {
+            (icon::file(), None)
+        };
+        rows.push(impersonate_file_row(icon, &entry.name, open, rows.len()));
+    }
+
+    if entries.is_empty() {
+        items.extend(rows);
+        items.push(muted_line(t("impersonate-empty-folder")));
+    } else {
+        items.push(
+            Column::with_children(rows)
+                .spacing(SERVER_LIST_ITEM_SPACING)
+                .width(Fill)
+                .into(),
+        );
+    }
+    items
+}
+
+/// Build a row for the impersonation channel list
+fn impersonate_channel_row(channel: &ChannelInfo, index: usize) -> Element<'_, Message> {
+    let mut row_content = Row::new()
+        .spacing(ELEMENT_SPACING)
+        .align_y(alignment::Vertical::Center)
+        .push(shaped_text(&channel.name).size(SERVER_LIST_TEXT_SIZE));
+    if channel.secret {
+        row_content = row_content.push(
+            shaped_text(t("impersonate-secret"))
+                .size(TEXT_SIZE)
+                .style(muted_text_style),
+        );
+    }
+
+    container(row_content)
+        .width(Fill)
+        .padding(INPUT_PADDING)
+        .style(alternating_row_style(index % 2 == 0))
+        .into()
+}
+
+/// Build the channel section: the channel list as the user sees it
+fn impersonate_channels(impersonation: &Impersonation) -> Vec<Element<'_, Message>> {
+    let mut items = vec![
+        shaped_text(t("impersonate-channels"))
+            .size(TEXT_SIZE)
+            .width(Fill)
+            .into(),
+    ];
+
+    match &impersonation.channels {
+        None => items.push(muted_line(t("impersonate-no-channel-access"))),
+        Some(channels) if channels.is_empty() => {
+            items.push(muted_line(t("impersonate-no-channels")))
+        }
+        Some(channels) => {
+            let rows = channels
+                .iter()
+                .enumerate()
+                .map(|(index, channel)| impersonate_channel_row(channel, index));
+            items.push(
+                Column::with_children(rows)
+                    .spacing(SERVER_LIST_ITEM_SPACING)
+                    .width(Fill)
+                    .into(),
+            );
+        }
+    }
+    items
+}
+
+/// Build the impersonation view (admin only, read-only)
+///
+/// Shows the account's permissions, its file area and its channel list as
+/// the server would show them to that account.
+fn impersonate_view<'a>(
+    username: &'a str,
+    user_management: &'a UserManagementState,
+) -> Element<'a, Message> {
+    let mut items: Vec<Element<'a, Message>> = vec![
+        panel_title(t_args("title-impersonate", &[("username", username)])).into(),
+        Space::new().height(SPACER_SIZE_SMALL).into(),
+        muted_line(t("impersonate-notice")),
+    ];
+
+    match &user_management.impersonation {
+        None => items.push(
+            shaped_text(t("permission-audit-loading"))
+                .size(TEXT_SIZE)
+                .width(Fill)
+                .align_x(Center)
+                .style(muted_text_style)
+                .into(),
+        ),
+        Some(Err(error)) => items.push(
+            shaped_text_wrapped(error)
+                .size(TEXT_SIZE)
+                .width(Fill)
+                .align_x(Center)
+                .style(error_text_style)
+                .into(),
+        ),
+        Some(Ok(impersonation)) => {
+            let effective = &impersonation.effective;
+            items.push(
+                shaped_text(t("impersonate-permissions"))
+                    .size(TEXT_SIZE)
+                    .width(Fill)
+                    .into(),
+            );
+            items.push(if effective.is_admin {
+                muted_line(t("permission-audit-admin"))
+            } else if effective.permissions.is_empty() {
+                muted_line(t("permission-audit-no-permissions"))
+            } else {
+                let permissions: Vec<String> = effective
+                    .permissions
+                    .iter()
+                    .map(|entry| translate_permission(&entry.permission))
+                    .collect();
+                muted_line(permissions.join(", "))
+            });
+
+            items.push(Space::new().height(SPACER_SIZE_SMALL).into());
+            items.extend(impersonate_files(impersonation));
+            items.push(Space::new().height(SPACER_SIZE_SMALL).into());
+            items.extend(impersonate_channels(impersonation));
+        }
+    }
+
+    let back_button = button(shaped_text(t("button-close")).size(TEXT_SIZE))
+        .on_press(Message::CancelUserManagement)
+        .padding(BUTTON_PADDING)
+        .style(btn::secondary);
+
+    items.push(Space::new().height(SPACER_SIZE_MEDIUM).into());
+    items.push(
+        row![Space::new().width(Fill), back_button]
+            .spacing(ELEMENT_SPACING)
+            .into(),
+    );
+
+    let form = Column::with_children(items)
+        .spacing(ELEMENT_SPACING)
+        .padding(CONTENT_PADDING)
+        .max_width(CONTENT_MAX_WIDTH);
+
+    scrollable_panel(form)
+}
+
 // ============================================================================
 // Helper for t_args
 // ============================================================================
@@ -742,10 +1302,12 @@ fn t_args(key: &str, args: &[(&str, &str)]) -> String {
 
 /// Displays the user management panel
 ///
-/// Shows one of four views based on mode:
+/// Shows one of six views based on mode:
 /// - List: Shows all users with edit/delete buttons
 /// - Create: Form to create a new user
 /// - Edit: Form to edit an existing user
+/// - PermissionAudit: Who holds a permission / what a user can do (admin only)
+/// - Impersonate: The server as another user sees it (admin only, read-only)
 /// - ConfirmDelete: Modal to confirm user deletion
 pub fn users_view<'a>(
     conn: &'a ServerConnection,
@@ -777,8 +1339,13 @@ pub fn users_view<'a>(
             enabled: *enabled,
             permissions,
         }),
-        UserManagementMode::ConfirmDelete { username } => {
-            confirm_delete_modal(username, user_management.delete_error.as_ref())
-        }
+        UserManagementMode::PermissionAudit => audit_view(user_management, theme),
+        UserManagementMode::Impersonate { username } => impersonate_view(username, user_management),
+        UserManagementMode::ConfirmDelete { username, erase } => confirm_delete_modal(
+            username,
+            *erase,
+            conn.is_admin,
+            user_management.delete_error.as_ref(),
+        ),
     }
 }
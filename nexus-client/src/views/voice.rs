@@ -2,19 +2,24 @@
 //!
 //! This module provides UI elements for voice chat:
 //! - Voice bar: Shows above the input when in a voice session
+//! - Voice text overlay: Recent /vt messages shown above the voice bar
 //! - Voice button: Join/leave toggle in the input row
 
-use iced::widget::{Row, Space, button, container, row, tooltip};
-use iced::{Background, Border, Element, Fill, Theme};
+use iced::keyboard::{self, key};
+use iced::widget::text_editor::{Binding, KeyPress};
+use iced::widget::{Column, Row, Space, button, container, row, text_editor, tooltip};
+use iced::{Background, Border, Element, Fill, Length, Theme};
 
 use crate::i18n::{t, t_args};
 use crate::icon;
+use crate::spellcheck::{SpellHighlighter, SpellSettings, misspelling_format};
 use crate::style::{
-    INPUT_PADDING, SMALL_SPACING, TOOLTIP_BACKGROUND_PADDING, TOOLTIP_GAP, TOOLTIP_PADDING,
-    TOOLTIP_TEXT_SIZE, shaped_text, speaking_indicator_style, tooltip_container_style,
-    voice_bar_style, voice_deafen_button_style,
+    CHAT_INPUT_MAX_HEIGHT, INPUT_PADDING, SMALL_SPACING, TOOLTIP_BACKGROUND_PADDING, TOOLTIP_GAP,
+    TOOLTIP_PADDING, TOOLTIP_TEXT_SIZE, chat, shaped_text, shaped_text_wrapped,
+    speaking_indicator_style, tooltip_container_style, voice_bar_style, voice_deafen_button_style,
 };
-use crate::types::{Message, ServerConnection, VoiceState};
+use crate::types::{Message, ServerConnection, SpellField, SpellMenu, VoiceState};
+use crate::views::spellcheck::with_spell_menu;
 
 // =============================================================================
 // Constants
@@ -68,7 +73,7 @@ pub const VU_METER_RED_THRESHOLD: f32 = 0.8;
 /// Shows:
 /// - Headphones icon
 /// - Target name (channel or other user)
-/// - Participant count
+/// - Participant count (or queue position while waiting for a slot)
 /// - Speaking indicators (who's currently talking)
 /// - Local speaking indicator (if transmitting)
 /// - Deafen toggle button
@@ -94,10 +99,22 @@ pub fn build_voice_bar(
     bar_row = bar_row.push(headphones_icon);
     bar_row = bar_row.push(target_text);
 
-    let count_text = shaped_text(t_args(
-        "voice-bar-participants",
-        &[("count", &count.to_string())],
-    ))
+    // While waiting in a full channel's queue, show the position instead;
+    // a broadcast shows its listener count
+    let count_text = match (session.queue_position, session.listeners) {
+        (Some(position), _) => shaped_text(t_args(
+            "voice-bar-queued",
+            &[("position", &position.to_string())],
+        )),
+        (None, Some(listeners)) => shaped_text(t_args(
+            "voice-bar-listeners",
+            &[("count", &listeners.to_string())],
+        )),
+        (None, None) => shaped_text(t_args(
+            "voice-bar-participants",
+            &[("count", &count.to_string())],
+        )),
+    }
     .size(VOICE_BAR_FONT_SIZE);
     bar_row = bar_row.push(count_text);
 
@@ -189,6 +206,48 @@ pub fn build_voice_bar(
         .into()
 }
 
+// =============================================================================
+// Voice Text Overlay
+// =============================================================================
+
+/// Build the overlay of recent voice text messages, oldest first
+///
+/// Returns `None` when there are no texts to show. Texts that name the local
+/// user use the chat highlight color.
+pub fn build_voice_text_overlay(
+    session: &VoiceState,
+    theme: &Theme,
+) -> Option<Element<'static, Message>> {
+    if session.texts.is_empty() {
+        return None;
+    }
+
+    let mut column = Column::new().spacing(SMALL_SPACING).width(Fill);
+    for voice_text in &session.texts {
+        let color = if voice_text.mention {
+            chat::highlight(theme)
+        } else {
+            chat::text(theme)
+        };
+        let bubble = row![
+            shaped_text(format!("{}:", voice_text.nickname))
+                .size(VOICE_BAR_FONT_SIZE)
+                .color(chat::text(theme)),
+            shaped_text_wrapped(voice_text.message.as_str())
+                .size(VOICE_BAR_FONT_SIZE)
+                .color(color),
+        ]
+        .spacing(VOICE_BAR_SPACING);
+        column = column.push(
+            container(bubble)
+                .padding(VOICE_BAR_PADDING)
+                .style(voice_bar_style),
+        );
+    }
+
+    Some(column.into())
+}
+
 // =============================================================================
 // VU Meter
 // =============================================================================
@@ -319,30 +378,72 @@ pub fn build_voice_button<'a>(
 // Voice Input Row
 // =============================================================================
 
+/// Keys the chat input handles itself
+///
+/// Enter sends and Shift+Enter starts a new line. Up and Down recall sent
+/// input unless the input already has several lines to move between. Tab
+/// and Escape are left for the keyboard handler (completion, closing).
+fn chat_input_binding(key_press: KeyPress, multiline: bool) -> Option<Binding<Message>> {
+    if !matches!(key_press.status, text_editor::Status::Focused { .. }) {
+        return None;
+    }
+
+    match key_press.key.as_ref() {
+        keyboard::Key::Named(key::Named::Enter) if key_press.modifiers.shift() => {
+            Some(Binding::Enter)
+        }
+        keyboard::Key::Named(key::Named::Enter) => {
+            Some(Binding::Custom(Message::SendMessagePressed))
+        }
+        keyboard::Key::Named(key::Named::ArrowUp) if !multiline => {
+            Some(Binding::Custom(Message::ChatInputHistoryPrevious))
+        }
+        keyboard::Key::Named(key::Named::ArrowDown) if !multiline => {
+            Some(Binding::Custom(Message::ChatInputHistoryNext))
+        }
+        keyboard::Key::Named(key::Named::Tab | key::Named::Escape) => None,
+        _ => Binding::from_key_press(key_press),
+    }
+}
+
 /// Build the input row with voice button
 ///
 /// This extends the standard input row with a voice join/leave button.
+/// The input grows with each line up to `CHAT_INPUT_MAX_HEIGHT`, and
+/// misspelled words are marked when spell checking is on.
 pub fn build_input_row_with_voice<'a>(
-    message_input: &'a str,
+    message_input: &'a text_editor::Content,
+    spell_settings: &SpellSettings,
+    spell_menu: Option<&'a SpellMenu>,
     font_size: f32,
     conn: &'a ServerConnection,
     has_voice_permission: bool,
     voice_target: Option<String>,
 ) -> Row<'a, Message> {
     use iced::widget::Id;
-    use iced::widget::text_input;
 
     use crate::style::MONOSPACE_FONT;
     use crate::types::InputId;
 
-    let text_field = text_input(&t("placeholder-message"), message_input)
-        .on_input(Message::ChatInputChanged)
-        .on_submit(Message::SendMessagePressed)
+    let multiline = conn.message_input.contains('\n');
+    let text_field = text_editor(message_input)
         .id(Id::from(InputId::ChatInput))
+        .placeholder(t("placeholder-message"))
+        .on_action(Message::ChatInputAction)
+        .key_binding(move |key_press| chat_input_binding(key_press, multiline))
         .padding(INPUT_PADDING)
         .size(font_size)
         .font(MONOSPACE_FONT)
-        .width(Fill);
+        .height(Length::Shrink)
+        .max_height(CHAT_INPUT_MAX_HEIGHT)
+        .width(Fill)
+        .highlight_with::<SpellHighlighter>(spell_settings.clone(), misspelling_format);
+    let text_field = with_spell_menu(
+        text_field,
+        SpellField::ChatInput,
+        spell_settings,
+        spell_menu,
+    );
 
     let send_button = button(shaped_text(t("button-send")).size(font_size))
         .on_press(Message::SendMessagePressed)
@@ -352,5 +453,6 @@ pub fn build_input_row_with_voice<'a>(
 
     row![voice_button, text_field, send_button]
         .spacing(SMALL_SPACING)
+        .align_y(iced::Alignment::End)
         .width(Fill)
 }
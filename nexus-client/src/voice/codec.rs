@@ -7,7 +7,8 @@
 use std::collections::HashMap;
 
 use nexus_common::voice::{
-    VOICE_CHANNELS, VOICE_SAMPLE_RATE, VOICE_SAMPLES_PER_FRAME, VoiceQuality,
+    VOICE_CHANNELS, VOICE_SAMPLE_RATE, VOICE_SAMPLES_PER_FRAME, VoiceConnectionQuality,
+    VoiceQuality,
 };
 use opus::{Application, Channels, Decoder, Encoder};
 
@@ -20,6 +21,9 @@ use opus::{Application, Channels, Decoder, Encoder};
 /// We allow extra headroom for packet overhead
 const MAX_ENCODED_FRAME_SIZE: usize = 512;
 
+/// Consecutive good VoiceStats reports needed before stepping the bitrate back up
+const BITRATE_RAISE_AFTER_GOOD_REPORTS: u32 = 3;
+
 // =============================================================================
 // Voice Encoder
 // =============================================================================
@@ -84,6 +88,18 @@ impl VoiceEncoder {
             .map_err(|e| format!("Failed to set bitrate: {}", e))
     }
 
+    /// Tell the encoder how much packet loss to expect
+    ///
+    /// Opus spends more of the bitrate on in-band FEC as expected loss rises.
+    ///
+    /// # Arguments
+    /// * `loss_percent` - Expected packet loss (0-100)
+    pub fn set_expected_loss(&mut self, loss_percent: u8) -> Result<(), String> {
+        self.encoder
+            .set_packet_loss_perc(i32::from(loss_percent.min(100)))
+            .map_err(|e| format!("Failed to set expected packet loss: {}", e))
+    }
+
     /// Encode a frame of audio samples
     ///
     /// # Arguments
@@ -114,6 +130,73 @@ impl VoiceEncoder {
     }
 }
 
+// =============================================================================
+// Bitrate Adapter
+// =============================================================================
+
+/// Adapts the encoder bitrate to the connection statistics reported by the server
+///
+/// The configured quality is a ceiling. A poor report steps the bitrate down one
+/// quality level at once; it only steps back up after several good reports in a
+/// row, so a flaky connection does not flip back and forth.
+#[derive(Debug, Clone)]
+pub struct BitrateAdapter {
+    /// Quality chosen in settings (never exceeded)
+    ceiling: VoiceQuality,
+    /// Quality the encoder is currently using
+    current: VoiceQuality,
+    /// Good reports received since the last change
+    good_reports: u32,
+}
+
+impl BitrateAdapter {
+    /// Create an adapter starting at the configured quality
+    pub fn new(ceiling: VoiceQuality) -> Self {
+        Self {
+            ceiling,
+            current: ceiling,
+            good_reports: 0,
+        }
+    }
+
+    /// Change the configured quality, starting over from it
+    pub fn set_ceiling(&mut self, ceiling: VoiceQuality) {
+        self.ceiling = ceiling;
+        self.current = ceiling;
+        self.good_reports = 0;
+    }
+
+    /// Process a VoiceStats report for our own audio
+    ///
+    /// Returns the new quality if the encoder bitrate should change.
+    pub fn on_report(&mut self, loss_percent: u8, jitter_ms: u32) -> Option<VoiceQuality> {
+        match VoiceConnectionQuality::from_stats(loss_percent, jitter_ms) {
+            VoiceConnectionQuality::Poor => {
+                self.good_reports = 0;
+                let lower = self.current.lower()?;
+                self.current = lower;
+                Some(lower)
+            }
+            VoiceConnectionQuality::Fair => {
+                self.good_reports = 0;
+                None
+            }
+            VoiceConnectionQuality::Good => {
+                if self.current == self.ceiling {
+                    return None;
+                }
+                self.good_reports += 1;
+                if self.good_reports < BITRATE_RAISE_AFTER_GOOD_REPORTS {
+                    return None;
+                }
+                self.good_reports = 0;
+                self.current = self.current.higher()?;
+                Some(self.current)
+            }
+        }
+    }
+}
+
 // =============================================================================
 // Voice Decoder
 // =============================================================================
@@ -413,4 +496,52 @@ mod tests {
         assert!(concealed.is_ok());
         assert_eq!(concealed.unwrap().len(), VOICE_SAMPLES_PER_FRAME as usize);
     }
+
+    #[test]
+    fn test_encoder_set_expected_loss() {
+        let mut encoder = VoiceEncoder::new(VoiceQuality::High).unwrap();
+        assert!(encoder.set_expected_loss(15).is_ok());
+        assert!(encoder.set_expected_loss(0).is_ok());
+    }
+
+    #[test]
+    fn test_bitrate_adapter_steps_down_on_poor_reports() {
+        let mut adapter = BitrateAdapter::new(VoiceQuality::High);
+
+        assert_eq!(adapter.on_report(20, 0), Some(VoiceQuality::Medium));
+        assert_eq!(adapter.on_report(20, 0), Some(VoiceQuality::Low));
+        // Already at the lowest quality
+        assert_eq!(adapter.on_report(20, 0), None);
+    }
+
+    #[test]
+    fn test_bitrate_adapter_recovers_to_ceiling() {
+        let mut adapter = BitrateAdapter::new(VoiceQuality::Medium);
+        assert_eq!(adapter.on_report(20, 0), Some(VoiceQuality::Low));
+
+        // A fair report resets the good streak
+        assert_eq!(adapter.on_report(0, 0), None);
+        assert_eq!(adapter.on_report(5, 0), None);
+
+        for _ in 1..BITRATE_RAISE_AFTER_GOOD_REPORTS {
+            assert_eq!(adapter.on_report(0, 0), None);
+        }
+        assert_eq!(adapter.on_report(0, 0), Some(VoiceQuality::Medium));
+
+        // Never exceeds the configured quality
+        for _ in 0..10 {
+            assert_eq!(adapter.on_report(0, 0), None);
+        }
+    }
+
+    #[test]
+    fn test_bitrate_adapter_set_ceiling() {
+        let mut adapter = BitrateAdapter::new(VoiceQuality::VeryHigh);
+        adapter.on_report(20, 0);
+
+        // Starts over from the new quality
+        adapter.set_ceiling(VoiceQuality::Medium);
+        assert_eq!(adapter.on_report(20, 0), Some(VoiceQuality::Low));
+        assert_eq!(adapter.on_report(20, 0), None);
+    }
 }
@@ -18,6 +18,9 @@ use nexus_common::voice::{
     RelayedVoicePacket, VOICE_KEEPALIVE_INTERVAL_SECS, VoiceMessageType, VoicePacket,
 };
 
+use super::relay::run_voice_relay;
+use crate::types::connection::CommandSender;
+
 // =============================================================================
 // Constants
 // =============================================================================
@@ -291,6 +294,8 @@ impl webrtc_util::Conn for TokioUdpConn {
 pub enum VoiceDtlsEvent {
     /// Successfully connected to voice server
     Connected,
+    /// DTLS connection failed; voice is relayed over the BBS connection instead
+    UsingTcpRelay(String),
     /// Received a voice packet from another user
     VoiceReceived {
         sender: String,
@@ -317,20 +322,48 @@ pub enum VoiceDtlsCommand {
     SendSpeakingStarted,
     /// Send speaking stopped
     SendSpeakingStopped,
+    /// Packet received from the server in a VoiceRelay message (TCP relay only)
+    RelayReceived(String),
     /// Disconnect
     Disconnect,
 }
 
+/// Convert a relayed packet into the event it represents
+///
+/// Returns `None` for keepalives, which carry nothing for the voice manager.
+pub(super) fn relayed_packet_event(packet: RelayedVoicePacket) -> Option<VoiceDtlsEvent> {
+    match packet.msg_type {
+        VoiceMessageType::VoiceData => Some(VoiceDtlsEvent::VoiceReceived {
+            sender: packet.sender,
+            sequence: packet.sequence,
+            timestamp: packet.timestamp,
+            payload: packet.payload,
+        }),
+        VoiceMessageType::SpeakingStarted => Some(VoiceDtlsEvent::SpeakingStarted {
+            sender: packet.sender,
+        }),
+        VoiceMessageType::SpeakingStopped => Some(VoiceDtlsEvent::SpeakingStopped {
+            sender: packet.sender,
+        }),
+        VoiceMessageType::Keepalive => None,
+    }
+}
+
 /// Run the voice DTLS client as a background task
 ///
+/// If the DTLS connection can't be established and the server offered TCP
+/// relay, falls back to relaying voice over the BBS connection.
+///
 /// # Arguments
 /// * `server_addr` - Server address to connect to
 /// * `token` - Voice session token
+/// * `tcp_relay` - Sender for the BBS connection, if the server offered TCP relay
 /// * `event_tx` - Channel to send events
 /// * `command_rx` - Channel to receive commands
 pub async fn run_voice_client(
     server_addr: SocketAddr,
     token: Uuid,
+    tcp_relay: Option<CommandSender>,
     event_tx: mpsc::UnboundedSender<VoiceDtlsEvent>,
     mut command_rx: mpsc::UnboundedReceiver<VoiceDtlsCommand>,
 ) {
@@ -338,7 +371,12 @@ pub async fn run_voice_client(
     let mut client = match VoiceDtlsClient::connect(server_addr, token).await {
         Ok(c) => c,
         Err(e) => {
-            let _ = event_tx.send(VoiceDtlsEvent::Error(e));
+            if let Some(relay_tx) = tcp_relay {
+                let _ = event_tx.send(VoiceDtlsEvent::UsingTcpRelay(e));
+                run_voice_relay(token, relay_tx, event_tx, command_rx).await;
+            } else {
+                let _ = event_tx.send(VoiceDtlsEvent::Error(e));
+            }
             return;
         }
     };
@@ -375,6 +413,9 @@ pub async fn run_voice_client(
                             let _ = event_tx.send(VoiceDtlsEvent::Error(e));
                         }
                     }
+                    Some(VoiceDtlsCommand::RelayReceived(_)) => {
+                        // Not relaying over TCP - ignore
+                    }
                     Some(VoiceDtlsCommand::Disconnect) | None => {
                         let _ = client.close().await;
                         let _ = event_tx.send(VoiceDtlsEvent::Disconnected);
@@ -387,20 +428,9 @@ pub async fn run_voice_client(
             result = client.recv_timeout(recv_timeout) => {
                 match result {
                     Ok(Some(packet)) => {
-                        let event = match packet.msg_type {
-                            VoiceMessageType::VoiceData => VoiceDtlsEvent::VoiceReceived {
-                                sender: packet.sender,
-                                sequence: packet.sequence,
-                                timestamp: packet.timestamp,
-                                payload: packet.payload,
-                            },
-                            VoiceMessageType::SpeakingStarted => VoiceDtlsEvent::SpeakingStarted {
-                                sender: packet.sender,
-                            },
-                            VoiceMessageType::SpeakingStopped => VoiceDtlsEvent::SpeakingStopped {
-                                sender: packet.sender,
-                            },
-                            VoiceMessageType::Keepalive => continue, // Ignore keepalives
+                        // Ignore keepalives
+                        let Some(event) = relayed_packet_event(packet) else {
+                            continue;
                         };
                         if event_tx.send(event).is_err() {
                             return;
@@ -442,6 +472,7 @@ mod tests {
     fn test_voice_dtls_event_variants() {
         // Just verify the enum variants compile
         let _ = VoiceDtlsEvent::Connected;
+        let _ = VoiceDtlsEvent::UsingTcpRelay("test".to_string());
         let _ = VoiceDtlsEvent::VoiceReceived {
             sender: "test".to_string(),
             sequence: 0,
@@ -464,6 +495,7 @@ mod tests {
         let _ = VoiceDtlsCommand::SendVoice(vec![]);
         let _ = VoiceDtlsCommand::SendSpeakingStarted;
         let _ = VoiceDtlsCommand::SendSpeakingStopped;
+        let _ = VoiceDtlsCommand::RelayReceived(String::new());
         let _ = VoiceDtlsCommand::Disconnect;
     }
 }
@@ -16,9 +16,10 @@ use uuid::Uuid;
 use nexus_common::voice::{VOICE_SAMPLES_PER_FRAME, VoiceQuality};
 
 use crate::config::audio::PttMode;
+use crate::types::connection::CommandSender;
 
 use super::audio::{AudioCapture, AudioMixer};
-use super::codec::{DecoderPool, VoiceEncoder};
+use super::codec::{BitrateAdapter, DecoderPool, VoiceEncoder};
 use super::dtls::{VoiceDtlsCommand, VoiceDtlsEvent, run_voice_client};
 use super::jitter::JitterBufferPool;
 use super::processor::{AudioProcessor, AudioProcessorSettings};
@@ -33,6 +34,8 @@ pub struct VoiceSessionConfig {
     pub server_addr: SocketAddr,
     /// Voice session token from VoiceJoinResponse
     pub token: Uuid,
+    /// Sender for the BBS connection, if the server offered TCP relay as a fallback
+    pub tcp_relay: Option<CommandSender>,
     /// Input device name (empty for default)
     pub input_device: String,
     /// Output device name (empty for default)
@@ -45,6 +48,8 @@ pub struct VoiceSessionConfig {
     pub ptt_mode: PttMode,
     /// Shared mic level for VU meter display (f32 stored as bits, written by manager)
     pub mic_level: Arc<AtomicU32>,
+    /// Listen to a broadcast without transmitting (no capture, encoder or processor)
+    pub listen_only: bool,
 }
 
 // =============================================================================
@@ -85,6 +90,8 @@ fn calculate_rms_level(samples: &[f32]) -> f32 {
 pub enum VoiceEvent {
     /// DTLS connection established
     Connected,
+    /// DTLS connection failed, voice is relayed over the BBS connection instead
+    UsingTcpRelay(String),
     /// DTLS connection failed
     ConnectionFailed(String),
     /// DTLS connection lost
@@ -118,6 +125,10 @@ pub enum VoiceCommand {
     SetDeafened(bool),
     /// Update voice quality (bitrate) dynamically
     SetQuality(VoiceQuality),
+    /// Connection statistics the server reported for our own audio
+    NetworkStats { loss_percent: u8, jitter_ms: u32 },
+    /// Voice packet the server relayed over the BBS connection (base64)
+    RelayReceived(String),
     /// Update audio processor settings
     SetProcessorSettings(AudioProcessorSettings),
     /// Clean up resources for a user who left voice
@@ -156,6 +167,7 @@ async fn run_voice_session(
     let dtls_handle = tokio::spawn(run_voice_client(
         config.server_addr,
         config.token,
+        config.tcp_relay.clone(),
         dtls_event_tx,
         dtls_command_rx,
     ));
@@ -169,6 +181,9 @@ async fn run_voice_session(
                         let _ = event_tx.send(VoiceEvent::Connected);
                         break true;
                     }
+                    Some(VoiceDtlsEvent::UsingTcpRelay(e)) => {
+                        let _ = event_tx.send(VoiceEvent::UsingTcpRelay(e));
+                    }
                     Some(VoiceDtlsEvent::Error(e)) => {
                         let _ = event_tx.send(VoiceEvent::ConnectionFailed(e));
                         break false;
@@ -194,14 +209,18 @@ async fn run_voice_session(
         return;
     }
 
-    // Initialize audio components
-    let capture = match AudioCapture::new(&config.input_device) {
-        Ok(c) => c,
-        Err(e) => {
-            let _ = event_tx.send(VoiceEvent::AudioError(format!("Input device error: {}", e)));
-            let _ = dtls_command_tx.send(VoiceDtlsCommand::Disconnect);
-            dtls_handle.abort();
-            return;
+    // Initialize audio components (listeners never open the microphone)
+    let capture = if config.listen_only {
+        None
+    } else {
+        match AudioCapture::new(&config.input_device) {
+            Ok(c) => Some(c),
+            Err(e) => {
+                let _ = event_tx.send(VoiceEvent::AudioError(format!("Input device error: {}", e)));
+                let _ = dtls_command_tx.send(VoiceDtlsCommand::Disconnect);
+                dtls_handle.abort();
+                return;
+            }
         }
     };
 
@@ -230,25 +249,37 @@ async fn run_voice_session(
     }
 
     // Initialize codec
-    let mut encoder = match VoiceEncoder::new(config.quality) {
-        Ok(e) => e,
-        Err(e) => {
-            let _ = event_tx.send(VoiceEvent::AudioError(format!("Encoder error: {}", e)));
-            let _ = dtls_command_tx.send(VoiceDtlsCommand::Disconnect);
-            dtls_handle.abort();
-            return;
+    let mut encoder = if config.listen_only {
+        None
+    } else {
+        match VoiceEncoder::new(config.quality) {
+            Ok(e) => Some(e),
+            Err(e) => {
+                let _ = event_tx.send(VoiceEvent::AudioError(format!("Encoder error: {}", e)));
+                let _ = dtls_command_tx.send(VoiceDtlsCommand::Disconnect);
+                dtls_handle.abort();
+                return;
+            }
         }
     };
 
+    // The configured quality is the ceiling; the adapter lowers it on a bad connection
+    let mut bitrate_adapter = BitrateAdapter::new(config.quality);
+
     let mut decoder_pool = DecoderPool::new();
     let mut jitter_pool = JitterBufferPool::new();
 
     // Initialize audio processor for noise suppression, echo cancellation, and AGC
-    let mut processor = match AudioProcessor::new(config.processor_settings) {
-        Ok(p) => Some(p),
-        Err(e) => {
-            let _ = event_tx.send(VoiceEvent::AudioProcessorDisabled(e));
-            None
+    // (only the microphone signal needs processing)
+    let mut processor = if config.listen_only {
+        None
+    } else {
+        match AudioProcessor::new(config.processor_settings) {
+            Ok(p) => Some(p),
+            Err(e) => {
+                let _ = event_tx.send(VoiceEvent::AudioProcessorDisabled(e));
+                None
+            }
         }
     };
 
@@ -271,7 +302,7 @@ async fn run_voice_session(
             // Process audio at regular intervals
             _ = audio_interval.tick() => {
                 // Check for audio device errors
-                if let Some(err) = capture.check_error() {
+                if let Some(err) = capture.as_ref().and_then(|c| c.check_error()) {
                     let _ = event_tx.send(VoiceEvent::AudioError(err));
                     let _ = dtls_command_tx.send(VoiceDtlsCommand::Disconnect);
                     break;
@@ -283,7 +314,9 @@ async fn run_voice_session(
                 }
 
                 // If transmitting, capture and send audio
-                if transmitting && capture.is_active()
+                if transmitting
+                    && let Some(ref capture) = capture
+                    && capture.is_active()
                     && let Some(mut samples) = capture.take_frame()
                 {
                     // Apply audio processing (noise suppression, AGC) to capture
@@ -301,7 +334,9 @@ async fn run_voice_session(
                     // reflects what others actually hear (post-AGC/NS)
                     let level = calculate_rms_level(&samples);
                     config.mic_level.store(level.to_bits(), Ordering::Relaxed);
-                    if let Ok(encoded) = encoder.encode(&samples) {
+                    if let Some(ref mut encoder) = encoder
+                        && let Ok(encoded) = encoder.encode(&samples)
+                    {
                         let _ = dtls_command_tx.send(VoiceDtlsCommand::SendVoice(encoded));
                     }
                 } else if transmitting {
@@ -374,7 +409,7 @@ async fn run_voice_session(
                         let _ = event_tx.send(VoiceEvent::Disconnected(None));
                         break;
                     }
-                    Some(VoiceDtlsEvent::Connected) => {
+                    Some(VoiceDtlsEvent::Connected) | Some(VoiceDtlsEvent::UsingTcpRelay(_)) => {
                         // Already handled above
                     }
                     None => {
@@ -388,7 +423,7 @@ async fn run_voice_session(
             cmd = command_rx.recv() => {
                 match cmd {
                     Some(VoiceCommand::StartTransmitting) => {
-                        if !transmitting {
+                        if !transmitting && let Some(ref capture) = capture {
                             transmitting = true;
                             // Hint to transient suppressor that PTT key was pressed
                             if let Some(ref proc) = processor {
@@ -409,7 +444,9 @@ async fn run_voice_session(
                             if let Some(ref proc) = processor {
                                 proc.set_stream_key_pressed(false);
                             }
-                            capture.stop();
+                            if let Some(ref capture) = capture {
+                                capture.stop();
+                            }
                             // Clear mic level when stopping
                             config.mic_level.store(0f32.to_bits(), Ordering::Relaxed);
                             let _ = dtls_command_tx.send(VoiceDtlsCommand::SendSpeakingStopped);
@@ -443,10 +480,27 @@ async fn run_voice_session(
                         }
                     }
                     Some(VoiceCommand::SetQuality(quality)) => {
-                        if let Err(e) = encoder.set_quality(quality) {
+                        bitrate_adapter.set_ceiling(quality);
+                        if let Some(ref mut encoder) = encoder
+                            && let Err(e) = encoder.set_quality(quality)
+                        {
                             let _ = event_tx.send(VoiceEvent::QualityChangeFailed(e));
                         }
                     }
+                    Some(VoiceCommand::NetworkStats { loss_percent, jitter_ms }) => {
+                        // Let Opus tune its FEC to the observed loss
+                        if let Some(ref mut encoder) = encoder {
+                            let _ = encoder.set_expected_loss(loss_percent);
+                            if let Some(quality) = bitrate_adapter.on_report(loss_percent, jitter_ms)
+                                && let Err(e) = encoder.set_quality(quality)
+                            {
+                                let _ = event_tx.send(VoiceEvent::QualityChangeFailed(e));
+                            }
+                        }
+                    }
+                    Some(VoiceCommand::RelayReceived(data)) => {
+                        let _ = dtls_command_tx.send(VoiceDtlsCommand::RelayReceived(data));
+                    }
                     Some(VoiceCommand::SetProcessorSettings(settings)) => {
                         if let Some(ref mut proc) = processor {
                             proc.update_settings(settings);
@@ -455,7 +509,9 @@ async fn run_voice_session(
                     Some(VoiceCommand::Stop) | None => {
                         // Clean shutdown
                         if transmitting {
-                            capture.stop();
+                            if let Some(ref capture) = capture {
+                                capture.stop();
+                            }
                             let _ = dtls_command_tx.send(VoiceDtlsCommand::SendSpeakingStopped);
                         }
                         let _ = dtls_command_tx.send(VoiceDtlsCommand::Disconnect);
@@ -554,6 +610,24 @@ impl VoiceSessionHandle {
         let _ = self.command_tx.send(VoiceCommand::SetQuality(quality));
     }
 
+    /// Report the connection statistics the server observed for our audio
+    ///
+    /// The voice session lowers or raises the encoder bitrate (never above the
+    /// configured quality) based on packet loss and jitter.
+    pub fn report_network_stats(&self, loss_percent: u8, jitter_ms: u32) {
+        let _ = self.command_tx.send(VoiceCommand::NetworkStats {
+            loss_percent,
+            jitter_ms,
+        });
+    }
+
+    /// Pass on a voice packet the server relayed over the BBS connection
+    ///
+    /// Only used when the session fell back to TCP relay; ignored otherwise.
+    pub fn relay_received(&self, data: String) {
+        let _ = self.command_tx.send(VoiceCommand::RelayReceived(data));
+    }
+
     /// Update audio processor settings dynamically
     ///
     /// Can be called while in a voice session to toggle noise suppression,
@@ -606,6 +680,7 @@ mod tests {
     fn test_voice_event_variants() {
         // Verify enum variants compile
         let _ = VoiceEvent::Connected;
+        let _ = VoiceEvent::UsingTcpRelay("test".to_string());
         let _ = VoiceEvent::ConnectionFailed("test".to_string());
         let _ = VoiceEvent::Disconnected(Some("test".to_string()));
         let _ = VoiceEvent::SpeakingStarted("Alice".to_string());
@@ -624,6 +699,11 @@ mod tests {
         let _ = VoiceCommand::MuteUser("Alice".to_string());
         let _ = VoiceCommand::UnmuteUser("Alice".to_string());
         let _ = VoiceCommand::UserLeft("Alice".to_string());
+        let _ = VoiceCommand::NetworkStats {
+            loss_percent: 5,
+            jitter_ms: 20,
+        };
+        let _ = VoiceCommand::RelayReceived(String::new());
         let _ = VoiceCommand::Stop;
     }
 }
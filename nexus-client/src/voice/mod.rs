@@ -4,7 +4,7 @@
 //! - Audio device enumeration and selection
 //! - Microphone capture and speaker playback
 //! - Opus encoding/decoding
-//! - DTLS connection to server
+//! - DTLS connection to server, with a TCP relay fallback when UDP is blocked
 //! - Jitter buffering for smooth playback
 //! - Push-to-talk handling
 //! - Microphone testing for settings
@@ -17,5 +17,6 @@ pub mod manager;
 pub mod mic_test;
 pub mod processor;
 pub mod ptt;
+pub mod relay;
 pub mod resample;
 pub mod subscription;
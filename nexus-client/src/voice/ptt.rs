@@ -1,33 +1,21 @@
 //! Push-to-talk (PTT) handling
 //!
 //! Provides global hotkey support for voice chat push-to-talk functionality
-//! using the global-hotkey crate.
+//! using the global-hotkey crate. Events arrive through
+//! [`crate::hotkeys::hotkey_subscription`], or as portal shortcuts on Wayland.
 
-use std::pin::Pin;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::time::Duration;
 
-use crossbeam_channel::TryRecvError;
 use global_hotkey::hotkey::{Code, HotKey, Modifiers};
 use global_hotkey::{GlobalHotKeyEvent, GlobalHotKeyManager};
-use iced::Subscription;
-use iced::futures::Stream;
-use iced::stream;
 
 use crate::config::audio::PttMode;
-use crate::types::Message;
 
 // =============================================================================
 // Constants
 // =============================================================================
 
-/// Channel size for PTT event stream
-const PTT_STREAM_CHANNEL_SIZE: usize = 10;
-
-/// Poll interval for checking hotkey events (milliseconds)
-const PTT_POLL_INTERVAL_MS: u64 = 10;
-
 /// Hotkey string separator (e.g., "Ctrl+Space")
 pub const HOTKEY_SEPARATOR: &str = "+";
 
@@ -172,6 +160,31 @@ impl PttManager {
         }
     }
 
+    /// Handle a press or release of the PTT shortcut bound through the
+    /// Wayland portal
+    ///
+    /// # Returns
+    /// * `Some(PttState)` - State changed, caller should act on it
+    /// * `None` - Not in voice or no state change
+    #[cfg(target_os = "linux")]
+    pub fn handle_shortcut(&mut self, pressed: bool) -> Option<PttState> {
+        if !self.in_voice {
+            return None;
+        }
+
+        if pressed {
+            self.handle_press()
+        } else {
+            self.handle_release()
+        }
+    }
+
+    /// Whether we're in a voice session that transmits
+    #[cfg(target_os = "linux")]
+    pub fn is_in_voice(&self) -> bool {
+        self.in_voice
+    }
+
     /// Handle key press
     fn handle_press(&mut self) -> Option<PttState> {
         match self.mode {
@@ -219,55 +232,6 @@ impl Drop for PttManager {
     }
 }
 
-// =============================================================================
-// PTT Subscription
-// =============================================================================
-
-/// Subscription for receiving global hotkey events
-///
-/// This subscription listens for global hotkey events and emits
-/// `Message::VoicePttStateChanged` when the PTT key is pressed or released.
-///
-/// Note: The PttManager must be created and have a hotkey registered
-/// before events will be received. The subscription itself doesn't
-/// manage the PttManager - it just forwards events to be handled.
-pub fn ptt_subscription() -> Subscription<Message> {
-    Subscription::run(ptt_event_stream)
-}
-
-/// Stream that receives global hotkey events
-fn ptt_event_stream() -> Pin<Box<dyn Stream<Item = Message> + Send>> {
-    Box::pin(stream::channel(
-        PTT_STREAM_CHANNEL_SIZE,
-        |mut output: iced::futures::channel::mpsc::Sender<Message>| async move {
-            use iced::futures::SinkExt;
-
-            // Get the global hotkey event receiver
-            let receiver = GlobalHotKeyEvent::receiver();
-
-            loop {
-                // Use try_recv with a small sleep to avoid busy-waiting
-                // and to keep the stream cancellable
-                match receiver.try_recv() {
-                    Ok(event) => {
-                        // We don't have access to the PttManager here, so we send
-                        // a raw event. The handler will check if it's our hotkey.
-                        let _ = output.send(Message::VoicePttEvent(event)).await;
-                    }
-                    Err(TryRecvError::Empty) => {
-                        // No event, sleep briefly
-                        tokio::time::sleep(Duration::from_millis(PTT_POLL_INTERVAL_MS)).await;
-                    }
-                    Err(TryRecvError::Disconnected) => {
-                        // Channel closed, exit
-                        break;
-                    }
-                }
-            }
-        },
-    ))
-}
-
 // =============================================================================
 // Key Code Parsing
 // =============================================================================
@@ -0,0 +1,211 @@
+//! TCP relay transport for voice chat
+//!
+//! Fallback for networks that block UDP. Voice packets are base64-encoded and
+//! carried over the existing BBS connection as `VoiceRelay` messages. The relay
+//! speaks the same event/command protocol as the DTLS client, so the voice
+//! manager works the same regardless of transport.
+
+use std::time::Duration;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+use nexus_common::framing::MessageId;
+use nexus_common::protocol::ClientMessage;
+use nexus_common::voice::{
+    RelayedVoicePacket, VOICE_KEEPALIVE_INTERVAL_SECS, VOICE_SAMPLES_PER_FRAME, VoicePacket,
+};
+
+use super::dtls::{VoiceDtlsCommand, VoiceDtlsEvent, relayed_packet_event};
+use crate::types::connection::CommandSender;
+
+// =============================================================================
+// Voice Relay Client
+// =============================================================================
+
+/// Voice client that sends packets over the BBS connection
+struct VoiceRelayClient {
+    /// Sender for the BBS connection
+    relay_tx: CommandSender,
+    /// Voice session token for authentication
+    token: Uuid,
+    /// Current sequence number for outgoing packets
+    sequence: u32,
+    /// Current timestamp for outgoing packets (in samples)
+    timestamp: u32,
+}
+
+impl VoiceRelayClient {
+    /// Send a voice data packet
+    fn send_voice_data(&mut self, opus_data: Vec<u8>) -> Result<(), String> {
+        let packet = VoicePacket::voice_data(self.token, self.sequence, self.timestamp, opus_data);
+
+        self.send_packet(&packet)?;
+
+        // Increment sequence and timestamp
+        self.sequence = self.sequence.wrapping_add(1);
+        self.timestamp = self.timestamp.wrapping_add(VOICE_SAMPLES_PER_FRAME);
+
+        Ok(())
+    }
+
+    /// Send a packet as a VoiceRelay message
+    fn send_packet(&self, packet: &VoicePacket) -> Result<(), String> {
+        let message = ClientMessage::VoiceRelay {
+            data: BASE64.encode(packet.to_bytes()),
+        };
+        self.relay_tx
+            .send((MessageId::new(), message))
+            .map_err(|_| "Server connection closed".to_string())
+    }
+}
+
+/// Decode the data of a VoiceRelay message from the server
+fn decode_relayed_packet(data: &str) -> Option<RelayedVoicePacket> {
+    let bytes = BASE64.decode(data).ok()?;
+    RelayedVoicePacket::from_bytes(&bytes)
+}
+
+// =============================================================================
+// Voice Relay Runner
+// =============================================================================
+
+/// Run the TCP relay transport as a background task
+///
+/// Packets received from the server are passed in as `VoiceDtlsCommand::RelayReceived`.
+///
+/// # Arguments
+/// * `token` - Voice session token
+/// * `relay_tx` - Sender for the BBS connection
+/// * `event_tx` - Channel to send events
+/// * `command_rx` - Channel to receive commands
+pub async fn run_voice_relay(
+    token: Uuid,
+    relay_tx: CommandSender,
+    event_tx: mpsc::UnboundedSender<VoiceDtlsEvent>,
+    mut command_rx: mpsc::UnboundedReceiver<VoiceDtlsCommand>,
+) {
+    let mut client = VoiceRelayClient {
+        relay_tx,
+        token,
+        sequence: 0,
+        timestamp: 0,
+    };
+
+    // The first keepalive switches the server-side session to TCP relay
+    if let Err(e) = client.send_packet(&VoicePacket::keepalive(token, 0)) {
+        let _ = event_tx.send(VoiceDtlsEvent::Error(e));
+        return;
+    }
+
+    if event_tx.send(VoiceDtlsEvent::Connected).is_err() {
+        return;
+    }
+
+    let mut keepalive_interval =
+        tokio::time::interval(Duration::from_secs(VOICE_KEEPALIVE_INTERVAL_SECS));
+    // The first tick completes immediately, and a keepalive was just sent
+    keepalive_interval.tick().await;
+
+    loop {
+        tokio::select! {
+            cmd = command_rx.recv() => {
+                let result = match cmd {
+                    Some(VoiceDtlsCommand::SendVoice(data)) => client.send_voice_data(data),
+                    Some(VoiceDtlsCommand::SendSpeakingStarted) => client
+                        .send_packet(&VoicePacket::speaking_started(token, client.sequence)),
+                    Some(VoiceDtlsCommand::SendSpeakingStopped) => client
+                        .send_packet(&VoicePacket::speaking_stopped(token, client.sequence)),
+                    Some(VoiceDtlsCommand::RelayReceived(data)) => {
+                        if let Some(event) = decode_relayed_packet(&data)
+                            .and_then(relayed_packet_event)
+                            && event_tx.send(event).is_err()
+                        {
+                            return;
+                        }
+                        Ok(())
+                    }
+                    Some(VoiceDtlsCommand::Disconnect) | None => {
+                        let _ = event_tx.send(VoiceDtlsEvent::Disconnected);
+                        return;
+                    }
+                };
+
+                if let Err(e) = result {
+                    // The BBS connection is gone, so the voice session is too
+                    let _ = event_tx.send(VoiceDtlsEvent::Error(e));
+                    let _ = event_tx.send(VoiceDtlsEvent::Disconnected);
+                    return;
+                }
+            }
+
+            _ = keepalive_interval.tick() => {
+                if let Err(e) = client.send_packet(&VoicePacket::keepalive(token, client.sequence)) {
+                    let _ = event_tx.send(VoiceDtlsEvent::Error(e));
+                    let _ = event_tx.send(VoiceDtlsEvent::Disconnected);
+                    return;
+                }
+            }
+        }
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nexus_common::voice::VoiceMessageType;
+
+    #[test]
+    fn test_relay_client_sends_voice_relay() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let token = Uuid::new_v4();
+        let mut client = VoiceRelayClient {
+            relay_tx: tx,
+            token,
+            sequence: 0,
+            timestamp: 0,
+        };
+
+        client.send_voice_data(vec![1, 2, 3]).unwrap();
+        client.send_voice_data(vec![4, 5, 6]).unwrap();
+
+        let (_, first) = rx.try_recv().unwrap();
+        let (_, second) = rx.try_recv().unwrap();
+        let decode = |message: ClientMessage| match message {
+            ClientMessage::VoiceRelay { data } => {
+                VoicePacket::from_bytes(&BASE64.decode(data).unwrap()).unwrap()
+            }
+            other => panic!("Expected VoiceRelay, got {:?}", other),
+        };
+
+        let first = decode(first);
+        assert_eq!(first.token, token);
+        assert_eq!(first.msg_type, VoiceMessageType::VoiceData);
+        assert_eq!(first.payload, vec![1, 2, 3]);
+
+        let second = decode(second);
+        assert_eq!(second.sequence, first.sequence + 1);
+        assert_eq!(second.timestamp, first.timestamp + VOICE_SAMPLES_PER_FRAME);
+    }
+
+    #[test]
+    fn test_decode_relayed_packet() {
+        let token = Uuid::new_v4();
+        let packet = VoicePacket::voice_data(token, 7, 480, vec![9; 20]);
+        let relayed = RelayedVoicePacket::from_voice_packet(&packet, "alice".to_string());
+        let data = BASE64.encode(relayed.to_bytes());
+
+        let decoded = decode_relayed_packet(&data).unwrap();
+        assert_eq!(decoded.sender, "alice");
+        assert_eq!(decoded.sequence, 7);
+        assert_eq!(decoded.payload, vec![9; 20]);
+
+        assert!(decode_relayed_packet("not base64!").is_none());
+    }
+}
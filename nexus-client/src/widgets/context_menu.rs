@@ -80,6 +80,9 @@ where
     overlay: Overlay,
     /// The style class.
     class: Theme::Class<'a>,
+    /// Message published when the menu opens, given the cursor position
+    /// relative to the underlay.
+    on_open: Option<Box<dyn Fn(Point) -> Message + 'a>>,
 }
 
 impl<'a, Overlay, Message, Theme, Renderer> LazyContextMenu<'a, Overlay, Message, Theme, Renderer>
@@ -101,9 +104,20 @@ where
             underlay: underlay.into(),
             overlay,
             class: Theme::default(),
+            on_open: None,
         }
     }
 
+    /// Sets the message published when the menu opens.
+    ///
+    /// The closure receives the cursor position relative to the underlay, so
+    /// the view can prepare the menu content for what was right-clicked.
+    #[must_use]
+    pub fn on_open(mut self, on_open: impl Fn(Point) -> Message + 'a) -> Self {
+        self.on_open = Some(Box::new(on_open));
+        self
+    }
+
     /// Sets the style of the [`LazyContextMenu`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
@@ -235,6 +249,9 @@ where
                 s.show = true;
                 // Reset overlay tree so it gets rebuilt with fresh content
                 s.overlay_tree = None;
+                if let Some(on_open) = &self.on_open {
+                    shell.publish(on_open(cursor.position_in(bounds).unwrap_or_default()));
+                }
                 shell.capture_event();
             }
         }
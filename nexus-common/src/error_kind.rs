@@ -37,6 +37,15 @@ pub const ERROR_KIND_HASH_MISMATCH: &str = "hash_mismatch";
 /// Error kind string: upload conflict (another upload to same file in progress)
 pub const ERROR_KIND_CONFLICT: &str = "conflict";
 
+/// Error kind string: upload breaks the destination's upload rule
+pub const ERROR_KIND_UPLOAD_RULE: &str = "upload_rule";
+
+/// Error kind string: not enough free space on the server for the upload
+pub const ERROR_KIND_INSUFFICIENT_STORAGE: &str = "insufficient_storage";
+
+/// Error kind string: message longer than the server's maximum message length
+pub const ERROR_KIND_MESSAGE_TOO_LONG: &str = "message_too_long";
+
 // =============================================================================
 // Enum
 // =============================================================================
@@ -93,6 +102,24 @@ pub enum ErrorKind {
     ///
     /// Another upload to the same file is already in progress.
     Conflict,
+
+    /// Upload rule violation
+    ///
+    /// The file type or size isn't allowed by the destination directory's
+    /// upload rule (advertised in `FileListResponse`).
+    UploadRule,
+
+    /// Insufficient storage
+    ///
+    /// The upload would leave less free space on the server than its
+    /// configured minimum. Retrying later may succeed once space is freed.
+    InsufficientStorage,
+
+    /// Message too long
+    ///
+    /// A chat, user, or group message is longer than the maximum message
+    /// length (advertised in `ServerInfo`). Client may split it and resend.
+    MessageTooLong,
 }
 
 impl ErrorKind {
@@ -109,6 +136,9 @@ impl ErrorKind {
             Self::ProtocolError => ERROR_KIND_PROTOCOL_ERROR,
             Self::HashMismatch => ERROR_KIND_HASH_MISMATCH,
             Self::Conflict => ERROR_KIND_CONFLICT,
+            Self::UploadRule => ERROR_KIND_UPLOAD_RULE,
+            Self::InsufficientStorage => ERROR_KIND_INSUFFICIENT_STORAGE,
+            Self::MessageTooLong => ERROR_KIND_MESSAGE_TOO_LONG,
         }
     }
 
@@ -125,6 +155,9 @@ impl ErrorKind {
             ERROR_KIND_PROTOCOL_ERROR => Some(Self::ProtocolError),
             ERROR_KIND_HASH_MISMATCH => Some(Self::HashMismatch),
             ERROR_KIND_CONFLICT => Some(Self::Conflict),
+            ERROR_KIND_UPLOAD_RULE => Some(Self::UploadRule),
+            ERROR_KIND_INSUFFICIENT_STORAGE => Some(Self::InsufficientStorage),
+            ERROR_KIND_MESSAGE_TOO_LONG => Some(Self::MessageTooLong),
             _ => None,
         }
     }
@@ -157,6 +190,12 @@ mod tests {
         assert_eq!(ErrorKind::ProtocolError.as_str(), "protocol_error");
         assert_eq!(ErrorKind::HashMismatch.as_str(), "hash_mismatch");
         assert_eq!(ErrorKind::Conflict.as_str(), "conflict");
+        assert_eq!(ErrorKind::UploadRule.as_str(), "upload_rule");
+        assert_eq!(
+            ErrorKind::InsufficientStorage.as_str(),
+            "insufficient_storage"
+        );
+        assert_eq!(ErrorKind::MessageTooLong.as_str(), "message_too_long");
     }
 
     #[test]
@@ -179,6 +218,15 @@ mod tests {
             Some(ErrorKind::HashMismatch)
         );
         assert_eq!(ErrorKind::parse("conflict"), Some(ErrorKind::Conflict));
+        assert_eq!(ErrorKind::parse("upload_rule"), Some(ErrorKind::UploadRule));
+        assert_eq!(
+            ErrorKind::parse("insufficient_storage"),
+            Some(ErrorKind::InsufficientStorage)
+        );
+        assert_eq!(
+            ErrorKind::parse("message_too_long"),
+            Some(ErrorKind::MessageTooLong)
+        );
         assert_eq!(ErrorKind::parse("unknown"), None);
         assert_eq!(ErrorKind::parse(""), None);
     }
@@ -209,6 +257,9 @@ mod tests {
             ErrorKind::ProtocolError,
             ErrorKind::HashMismatch,
             ErrorKind::Conflict,
+            ErrorKind::UploadRule,
+            ErrorKind::InsufficientStorage,
+            ErrorKind::MessageTooLong,
         ] {
             assert_eq!(ErrorKind::parse(kind.as_str()), Some(kind));
         }
@@ -226,5 +277,14 @@ mod tests {
         assert_eq!(ERROR_KIND_PROTOCOL_ERROR, ErrorKind::ProtocolError.as_str());
         assert_eq!(ERROR_KIND_HASH_MISMATCH, ErrorKind::HashMismatch.as_str());
         assert_eq!(ERROR_KIND_CONFLICT, ErrorKind::Conflict.as_str());
+        assert_eq!(ERROR_KIND_UPLOAD_RULE, ErrorKind::UploadRule.as_str());
+        assert_eq!(
+            ERROR_KIND_INSUFFICIENT_STORAGE,
+            ErrorKind::InsufficientStorage.as_str()
+        );
+        assert_eq!(
+            ERROR_KIND_MESSAGE_TOO_LONG,
+            ErrorKind::MessageTooLong.as_str()
+        );
     }
 }
@@ -3,19 +3,33 @@
 use std::collections::HashMap;
 use std::sync::LazyLock;
 
-use crate::PERMISSIONS_COUNT;
+use crate::framing::MSG_ID_LENGTH;
+use crate::identity::{IDENTITY_KEY_LENGTH, IDENTITY_SIGNATURE_LENGTH};
+use crate::pow::{MAX_POW_NONCE_LENGTH, POW_CHALLENGE_LENGTH};
 use crate::validators::{
-    MAX_AUTO_JOIN_CHANNELS_LENGTH, MAX_AVATAR_DATA_URI_LENGTH, MAX_BAN_REASON_LENGTH,
-    MAX_CHANNEL_LENGTH, MAX_CHANNELS_PER_USER, MAX_CHAT_TOPIC_LENGTH, MAX_COMMAND_LENGTH,
-    MAX_DIR_NAME_LENGTH, MAX_DURATION_LENGTH, MAX_ERROR_KIND_LENGTH, MAX_ERROR_LENGTH,
-    MAX_FEATURE_LENGTH, MAX_FEATURES_COUNT, MAX_FILE_PATH_LENGTH, MAX_LOCALE_LENGTH,
-    MAX_MESSAGE_LENGTH, MAX_NEWS_ACTION_LENGTH, MAX_NEWS_BODY_LENGTH,
-    MAX_NEWS_IMAGE_DATA_URI_LENGTH, MAX_NICKNAME_LENGTH, MAX_PASSWORD_LENGTH,
-    MAX_PERMISSION_LENGTH, MAX_PERSISTENT_CHANNELS_LENGTH, MAX_SEARCH_QUERY_LENGTH,
-    MAX_SERVER_DESCRIPTION_LENGTH, MAX_SERVER_IMAGE_DATA_URI_LENGTH, MAX_SERVER_NAME_LENGTH,
-    MAX_STATUS_LENGTH, MAX_TARGET_LENGTH, MAX_TRUST_REASON_LENGTH, MAX_USERNAME_LENGTH,
-    MAX_VERSION_LENGTH, SHA256_HEX_LENGTH, TRANSFER_ID_LENGTH,
+    LOGIN_TOKEN_LENGTH, MAX_AUTO_JOIN_CHANNELS_LENGTH, MAX_AVATAR_DATA_URI_LENGTH,
+    MAX_BAN_REASON_LENGTH, MAX_CHANNEL_LANGUAGE_LENGTH, MAX_CHANNEL_LENGTH, MAX_CHANNELS_PER_USER,
+    MAX_CHAT_ENTRY_MESSAGE_LENGTH, MAX_CHAT_RULES_LENGTH, MAX_CHAT_TOPIC_HISTORY,
+    MAX_CHAT_TOPIC_LENGTH, MAX_CLIENT_NAME_LENGTH, MAX_CODE_VERIFIER_LENGTH, MAX_COMMAND_LENGTH,
+    MAX_DIR_NAME_LENGTH, MAX_DOOR_NAME_LENGTH, MAX_DOWNLOAD_INCLUDE_COUNT, MAX_DURATION_LENGTH,
+    MAX_ERROR_KIND_LENGTH, MAX_ERROR_LENGTH, MAX_FEATURE_LENGTH, MAX_FEATURES_COUNT,
+    MAX_FILE_COMMENT_LENGTH, MAX_FILE_PATH_LENGTH, MAX_FILE_STAT_COUNT, MAX_FILE_WATCH_COUNT,
+    MAX_GROUP_MEMBERS, MAX_IDEMPOTENCY_KEY_LENGTH, MAX_LDAP_GROUP_LENGTH, MAX_LOCALE_LENGTH,
+    MAX_MESSAGE_LENGTH, MAX_NEWS_ACTION_LENGTH, MAX_NEWS_ATTACHMENT_KIND_LENGTH,
+    MAX_NEWS_ATTACHMENTS, MAX_NEWS_BODY_LENGTH, MAX_NEWS_CAPTION_LENGTH,
+    MAX_NEWS_IMAGE_DATA_URI_LENGTH, MAX_NICKNAME_LENGTH, MAX_OIDC_CLIENT_ID_LENGTH,
+    MAX_OIDC_CODE_LENGTH, MAX_OIDC_ENDPOINT_LENGTH, MAX_OIDC_NONCE_LENGTH,
+    MAX_OIDC_REDIRECT_URI_LENGTH, MAX_PASSWORD_LENGTH, MAX_PERMISSION_LENGTH,
+    MAX_PERSISTENT_CHANNELS_LENGTH, MAX_PRESENCE_SUBSCRIPTIONS, MAX_RANK_NAME_LENGTH,
+    MAX_SEARCH_QUERY_LENGTH, MAX_SERVER_DESCRIPTION_LENGTH, MAX_SERVER_IMAGE_DATA_URI_LENGTH,
+    MAX_SERVER_NAME_LENGTH, MAX_SNIPPET_LANGUAGE_LENGTH, MAX_SNIPPET_LENGTH, MAX_STATUS_LENGTH,
+    MAX_TARGET_LENGTH, MAX_TIMEZONE_LENGTH, MAX_TRUST_REASON_LENGTH, MAX_UPLOAD_EXTENSION_LENGTH,
+    MAX_UPLOAD_EXTENSIONS, MAX_USERNAME_LENGTH, MAX_VERSION_LENGTH, MAX_VOICE_CLIP_DATA_URI_LENGTH,
+    MAX_VOICE_CLIP_NAME_LENGTH, MAX_VOICE_TEXT_LENGTH, NEWS_SEARCH_DATE_LENGTH, SHA256_HEX_LENGTH,
+    SNIPPET_ID_LENGTH, TRANSFER_ID_LENGTH,
 };
+use crate::voice::{MAX_VOICE_PAYLOAD, RelayedVoicePacket};
+use crate::{MAX_DOOR_DATA_SIZE, PERMISSIONS_COUNT};
 
 // =============================================================================
 // JSON Size Helper Constants
@@ -142,6 +156,16 @@ const fn json_string_array_field(key: &str, count: usize, max_elem_len: usize) -
     key.len() + 5 + count * (max_elem_len + 3)
 }
 
+/// Size of an array field with u16 elements: `,"key":[65535,65535,...]`
+const fn json_u16_array_field(key: &str, count: usize) -> usize {
+    // Empty array case: ,"key":[] = key.len() + 6
+    if count == 0 {
+        return key.len() + 6;
+    }
+    // Non-empty: key.len() + 6 + count * MAX_JSON_U16 + (count - 1) commas
+    key.len() + 5 + count * (MAX_JSON_U16 + 1)
+}
+
 /// Size of an object field header: `,"key":{`
 ///
 /// Does not include the closing `}`. Use with nested object calculations.
@@ -168,6 +192,11 @@ const fn json_first_i64_field(key: &str) -> usize {
     key.len() + 3 + MAX_JSON_I64
 }
 
+/// Size of the first u32 field in a nested object (no leading comma): `"key":4294967295`
+const fn json_first_u32_field(key: &str) -> usize {
+    key.len() + 3 + MAX_JSON_U32
+}
+
 // =============================================================================
 // Local field size constants (not in validators - specific to protocol limits)
 // =============================================================================
@@ -187,6 +216,42 @@ const MAX_RESPONSE_IPS: usize = 8;
 /// Maximum IPv6 address length (including scope)
 const MAX_IP_LENGTH: usize = 45;
 
+/// Maximum NatMethod variant name length ("nat-pmp")
+const MAX_NAT_METHOD_LENGTH: usize = 7;
+
+/// Maximum number of forwarded TCP ports (BBS, transfer and two WebSocket ports)
+const MAX_NAT_TCP_PORTS: usize = 4;
+
+/// Maximum number of forwarded UDP ports (voice)
+const MAX_NAT_UDP_PORTS: usize = 1;
+
+/// Maximum CheckedService variant name length ("transfer-websocket")
+const MAX_CHECKED_SERVICE_LENGTH: usize = 18;
+
+/// Maximum PortReachability variant name length ("filtered", "untested")
+const MAX_PORT_REACHABILITY_LENGTH: usize = 8;
+
+/// Maximum number of ports in a connectivity check (four TCP ports and voice)
+const MAX_CHECKED_PORTS: usize = 5;
+
+/// Maximum number of days in a statistics report
+const MAX_STATS_DAYS: usize = 365;
+
+/// Length of a statistics date ("2026-01-17")
+const STATS_DATE_LENGTH: usize = 10;
+
+/// Maximum number of accounts on a leaderboard
+const MAX_LEADERBOARD_ENTRIES: usize = 20;
+
+/// Maximum LeaderboardStat variant name length ("download_bytes")
+const MAX_LEADERBOARD_STAT_LENGTH: usize = 14;
+
+/// Maximum StorageVolume variant name length ("database")
+const MAX_STORAGE_VOLUME_LENGTH: usize = 8;
+
+/// Maximum BroadcastTarget variant name length ("permission")
+const MAX_BROADCAST_TARGET_KIND_LENGTH: usize = 10;
+
 /// Maximum MIME type length (e.g., "application/octet-stream")
 const MAX_MIME_TYPE: usize = 128;
 
@@ -204,29 +269,47 @@ const MAX_ADDRESSES: usize = 10;
 /// JSON escaping could double quote chars in worst case, so ~9000 is safe
 const MAX_CREATED_DIR_PATH: usize = 4352;
 
+/// Maximum snippet text length in JSON
+/// Code is full of newlines, tabs and quotes, each escaped as two characters
+const MAX_SNIPPET_JSON_LENGTH: usize = 2 * MAX_SNIPPET_LENGTH;
+
 // =============================================================================
 // Self-documenting message size calculations using JSON helpers
 // =============================================================================
 
-/// Login: {"type":"Login","username":"...32...","password":"...256...","features":["...64..."],"locale":"...10...","avatar":"...176000...","nickname":"...32..."}
+/// OidcLogin nested object size:
+/// {"code":"...2048...","code_verifier":"...128...","redirect_uri":"...64...","nonce":"...128..."}
+const OIDC_LOGIN_SIZE: usize = json_first_string_field("code", MAX_OIDC_CODE_LENGTH)
+    + json_string_field("code_verifier", MAX_CODE_VERIFIER_LENGTH)
+    + json_string_field("redirect_uri", MAX_OIDC_REDIRECT_URI_LENGTH)
+    + json_string_field("nonce", MAX_OIDC_NONCE_LENGTH)
+    + 2; // {} braces
+
+/// Login: {"type":"Login","username":"...32...","password":"...256...","features":["...64..."],"locale":"...10...","avatar":"...176000...","nickname":"...32...","pow_nonce":"...20...","oidc":{...},"login_token":"...64..."}
 const LOGIN_SIZE: usize = json_type_base("Login")
     + json_string_field("username", MAX_USERNAME_LENGTH)
     + json_string_field("password", MAX_PASSWORD_LENGTH)
     + json_string_array_field("features", MAX_FEATURES_COUNT, MAX_FEATURE_LENGTH)
     + json_string_field("locale", MAX_LOCALE_LENGTH)
     + json_string_field("avatar", MAX_AVATAR_DATA_URI_LENGTH)
-    + json_string_field("nickname", MAX_NICKNAME_LENGTH);
+    + json_string_field("nickname", MAX_NICKNAME_LENGTH)
+    + json_string_field("pow_nonce", MAX_POW_NONCE_LENGTH)
+    + json_object_field_start("oidc")
+    + OIDC_LOGIN_SIZE
+    + json_close()
+    + json_string_field("login_token", LOGIN_TOKEN_LENGTH);
 
 // -----------------------------------------------------------------------------
 // Client messages - Chat
 // -----------------------------------------------------------------------------
 
-/// ChatSend: {"type":"ChatSend","message":"...1024...","action":"Normal","channel":"...32..."}
+/// ChatSend: {"type":"ChatSend","message":"...1024...","action":"Normal","channel":"...32...","idempotency_key":"...64..."}
 /// Note: action is skipped when Normal (default), but we calculate worst case
 const CHAT_SEND_SIZE: usize = json_type_base("ChatSend")
     + json_string_field("message", MAX_MESSAGE_LENGTH)
     + json_enum_field("action", MAX_ACTION_VARIANT)
-    + json_string_field("channel", MAX_CHANNEL_LENGTH);
+    + json_string_field("channel", MAX_CHANNEL_LENGTH)
+    + json_string_field("idempotency_key", MAX_IDEMPOTENCY_KEY_LENGTH);
 
 /// ChatTopicUpdate: {"type":"ChatTopicUpdate","topic":"...256...","channel":"...32..."}
 const CHAT_TOPIC_UPDATE_SIZE: usize = json_type_base("ChatTopicUpdate")
@@ -241,34 +324,83 @@ const CHAT_JOIN_SIZE: usize =
 const CHAT_LEAVE_SIZE: usize =
     json_type_base("ChatLeave") + json_string_field("channel", MAX_CHANNEL_LENGTH);
 
-/// ChatList: {"type":"ChatList"}
-const CHAT_LIST_SIZE: usize = json_type_base("ChatList");
+/// ChatList: {"type":"ChatList","language":"...16..."}
+const CHAT_LIST_SIZE: usize =
+    json_type_base("ChatList") + json_string_field("language", MAX_CHANNEL_LANGUAGE_LENGTH);
 
 /// ChatSecret: {"type":"ChatSecret","channel":"...32...","secret":false}
 const CHAT_SECRET_SIZE: usize = json_type_base("ChatSecret")
     + json_string_field("channel", MAX_CHANNEL_LENGTH)
     + json_bool_field("secret");
 
+/// ChatTopicLock: {"type":"ChatTopicLock","channel":"...32...","locked":false}
+const CHAT_TOPIC_LOCK_SIZE: usize = json_type_base("ChatTopicLock")
+    + json_string_field("channel", MAX_CHANNEL_LENGTH)
+    + json_bool_field("locked");
+
+/// ChatTopicHistory: {"type":"ChatTopicHistory","channel":"...32..."}
+const CHAT_TOPIC_HISTORY_SIZE: usize =
+    json_type_base("ChatTopicHistory") + json_string_field("channel", MAX_CHANNEL_LENGTH);
+
+/// ChatEntryUpdate: {"type":"ChatEntryUpdate","channel":"...32...","entry_message":"...1024...","rules":"...4096..."}
+const CHAT_ENTRY_UPDATE_SIZE: usize = json_type_base("ChatEntryUpdate")
+    + json_string_field("channel", MAX_CHANNEL_LENGTH)
+    + json_string_field("entry_message", MAX_CHAT_ENTRY_MESSAGE_LENGTH)
+    + json_string_field("rules", MAX_CHAT_RULES_LENGTH);
+
+/// ChatLanguage: {"type":"ChatLanguage","channel":"...32...","language":"...16..."}
+const CHAT_LANGUAGE_SIZE: usize = json_type_base("ChatLanguage")
+    + json_string_field("channel", MAX_CHANNEL_LENGTH)
+    + json_string_field("language", MAX_CHANNEL_LANGUAGE_LENGTH);
+
 // -----------------------------------------------------------------------------
 // Client messages - Basic
 // -----------------------------------------------------------------------------
 
-/// Handshake: {"type":"Handshake","version":"...32..."}
-const HANDSHAKE_SIZE: usize =
-    json_type_base("Handshake") + json_string_field("version", MAX_VERSION_LENGTH);
+/// Handshake: {"type":"Handshake","version":"...32...","client":"...64..."}
+const HANDSHAKE_SIZE: usize = json_type_base("Handshake")
+    + json_string_field("version", MAX_VERSION_LENGTH)
+    + json_string_field("client", MAX_CLIENT_NAME_LENGTH);
+
+/// BroadcastTarget nested object: ,"target":{"kind":"permission","permission":"...32..."}
+/// (a channel target has a shorter key and a name of the same maximum length)
+const BROADCAST_TARGET_SIZE: usize = json_object_field_start("target")
+    + json_first_string_field("kind", MAX_BROADCAST_TARGET_KIND_LENGTH)
+    + json_string_field("permission", MAX_PERMISSION_LENGTH)
+    + json_close();
+
+/// UserBroadcast: {"type":"UserBroadcast","message":"...1024...","target":{...},"delay":"...10...","require_ack":false}
+const USER_BROADCAST_SIZE: usize = json_type_base("UserBroadcast")
+    + json_string_field("message", MAX_MESSAGE_LENGTH)
+    + BROADCAST_TARGET_SIZE
+    + json_string_field("delay", MAX_DURATION_LENGTH)
+    + json_bool_field("require_ack");
 
-/// UserBroadcast: {"type":"UserBroadcast","message":"...1024..."}
-const USER_BROADCAST_SIZE: usize =
-    json_type_base("UserBroadcast") + json_string_field("message", MAX_MESSAGE_LENGTH);
+/// BroadcastAck: {"type":"BroadcastAck","broadcast_id":18446744073709551615}
+const BROADCAST_ACK_SIZE: usize = json_type_base("BroadcastAck") + json_u64_field("broadcast_id");
 
 /// UserDelete: {"type":"UserDelete","username":"...32..."}
 const USER_DELETE_SIZE: usize =
     json_type_base("UserDelete") + json_string_field("username", MAX_USERNAME_LENGTH);
 
+/// UserErase: {"type":"UserErase","username":"...32..."}
+const USER_ERASE_SIZE: usize =
+    json_type_base("UserErase") + json_string_field("username", MAX_USERNAME_LENGTH);
+
 /// UserEdit: {"type":"UserEdit","username":"...32..."}
 const USER_EDIT_SIZE: usize =
     json_type_base("UserEdit") + json_string_field("username", MAX_USERNAME_LENGTH);
 
+/// PermissionAudit: {"type":"PermissionAudit","permission":"...32...","username":"...32..."}
+const PERMISSION_AUDIT_SIZE: usize = json_type_base("PermissionAudit")
+    + json_string_field("permission", MAX_PERMISSION_LENGTH)
+    + json_string_field("username", MAX_USERNAME_LENGTH);
+
+/// ImpersonateQuery: {"type":"ImpersonateQuery","username":"...32...","path":"...4096..."}
+const IMPERSONATE_QUERY_SIZE: usize = json_type_base("ImpersonateQuery")
+    + json_string_field("username", MAX_USERNAME_LENGTH)
+    + json_string_field("path", MAX_FILE_PATH_LENGTH);
+
 /// UserInfo: {"type":"UserInfo","nickname":"...64..."}
 const USER_INFO_SIZE: usize =
     json_type_base("UserInfo") + json_string_field("nickname", MAX_NICKNAME_LENGTH);
@@ -292,6 +424,44 @@ const USER_BACK_SIZE: usize = json_type_base("UserBack");
 const USER_STATUS_SIZE: usize =
     json_type_base("UserStatus") + json_string_field("status", MAX_STATUS_LENGTH);
 
+/// UserDnd: {"type":"UserDnd","enabled":false}
+const USER_DND_SIZE: usize = json_type_base("UserDnd") + json_bool_field("enabled");
+
+/// MessageHistory: {"type":"MessageHistory","nickname":"...64...","before":-9223372036854775808,"limit":4294967295}
+const MESSAGE_HISTORY_SIZE: usize = json_type_base("MessageHistory")
+    + json_string_field("nickname", MAX_NICKNAME_LENGTH)
+    + json_i64_field("before")
+    + json_u32_field("limit");
+
+/// MessageHistoryRetention: {"type":"MessageHistoryRetention","days":4294967295}
+const MESSAGE_HISTORY_RETENTION_SIZE: usize =
+    json_type_base("MessageHistoryRetention") + json_u32_field("days");
+
+/// MessageMarkRead: {"type":"MessageMarkRead","nickname":"...64...","id":18446744073709551615}
+const MESSAGE_MARK_READ_SIZE: usize = json_type_base("MessageMarkRead")
+    + json_string_field("nickname", MAX_NICKNAME_LENGTH)
+    + json_u64_field("id");
+
+/// GroupMessageCreate: {"type":"GroupMessageCreate","members":["...64...",...]}
+const GROUP_MESSAGE_CREATE_SIZE: usize = json_type_base("GroupMessageCreate")
+    + json_string_array_field("members", MAX_GROUP_MEMBERS, MAX_NICKNAME_LENGTH);
+
+/// GroupMessageSend: {"type":"GroupMessageSend","group_id":18446744073709551615,"message":"...1024...","action":"Normal","idempotency_key":"...64..."}
+const GROUP_MESSAGE_SEND_SIZE: usize = json_type_base("GroupMessageSend")
+    + json_u64_field("group_id")
+    + json_string_field("message", MAX_MESSAGE_LENGTH)
+    + json_enum_field("action", MAX_ACTION_VARIANT)
+    + json_string_field("idempotency_key", MAX_IDEMPOTENCY_KEY_LENGTH);
+
+/// GroupMessageInvite: {"type":"GroupMessageInvite","group_id":18446744073709551615,"nickname":"...64..."}
+const GROUP_MESSAGE_INVITE_SIZE: usize = json_type_base("GroupMessageInvite")
+    + json_u64_field("group_id")
+    + json_string_field("nickname", MAX_NICKNAME_LENGTH);
+
+/// GroupMessageLeave: {"type":"GroupMessageLeave","group_id":18446744073709551615}
+const GROUP_MESSAGE_LEAVE_SIZE: usize =
+    json_type_base("GroupMessageLeave") + json_u64_field("group_id");
+
 // -----------------------------------------------------------------------------
 // Client messages - Ban/Trust
 // -----------------------------------------------------------------------------
@@ -325,6 +495,44 @@ const TRUST_LIST_SIZE: usize = json_type_base("TrustList");
 /// ConnectionMonitor: {"type":"ConnectionMonitor"}
 const CONNECTION_MONITOR_SIZE: usize = json_type_base("ConnectionMonitor");
 
+/// LogSubscribe: {"type":"LogSubscribe"}
+const LOG_SUBSCRIBE_SIZE: usize = json_type_base("LogSubscribe");
+
+/// LogUnsubscribe: {"type":"LogUnsubscribe"}
+const LOG_UNSUBSCRIBE_SIZE: usize = json_type_base("LogUnsubscribe");
+
+/// NatStatus: {"type":"NatStatus"}
+const NAT_STATUS_SIZE: usize = json_type_base("NatStatus");
+
+/// ConnectivityCheck: {"type":"ConnectivityCheck"}
+const CONNECTIVITY_CHECK_SIZE: usize = json_type_base("ConnectivityCheck");
+
+/// ServerStats: {"type":"ServerStats"}
+const SERVER_STATS_SIZE: usize = json_type_base("ServerStats");
+
+/// StatsRequest: {"type":"StatsRequest","days":u16}
+const STATS_REQUEST_SIZE: usize = json_type_base("StatsRequest") + json_u16_field("days");
+
+/// Leaderboard: {"type":"Leaderboard","stat":"download_bytes"}
+const LEADERBOARD_SIZE: usize =
+    json_type_base("Leaderboard") + json_enum_field("stat", MAX_LEADERBOARD_STAT_LENGTH);
+
+// -----------------------------------------------------------------------------
+// Client messages - Doors
+// -----------------------------------------------------------------------------
+
+/// DoorList: {"type":"DoorList"}
+const DOOR_LIST_SIZE: usize = json_type_base("DoorList");
+
+/// DoorOpen: {"type":"DoorOpen","name":"...32...","cols":u16,"rows":u16}
+const DOOR_OPEN_SIZE: usize = json_type_base("DoorOpen")
+    + json_string_field("name", MAX_DOOR_NAME_LENGTH)
+    + json_u16_field("cols")
+    + json_u16_field("rows");
+
+/// DoorClose: {"type":"DoorClose"}
+const DOOR_CLOSE_SIZE: usize = json_type_base("DoorClose");
+
 // -----------------------------------------------------------------------------
 // Client messages - News
 // -----------------------------------------------------------------------------
@@ -335,23 +543,55 @@ const NEWS_LIST_SIZE: usize = json_type_base("NewsList");
 /// NewsShow: {"type":"NewsShow","id":-9223372036854775808}
 const NEWS_SHOW_SIZE: usize = json_type_base("NewsShow") + json_i64_field("id");
 
-/// NewsCreate: {"type":"NewsCreate","body":"...4096...","image":"...700000..."}
+/// NewsAttachment nested object size (an image is larger than a file reference):
+/// {"kind":"image","data":"...700000...","caption":"...256..."}
+const NEWS_ATTACHMENT_SIZE: usize =
+    json_first_string_field("kind", MAX_NEWS_ATTACHMENT_KIND_LENGTH)
+        + json_string_field("data", MAX_NEWS_IMAGE_DATA_URI_LENGTH)
+        + json_string_field("caption", MAX_NEWS_CAPTION_LENGTH)
+        + 2; // {} braces
+
+/// News attachment list: ,"attachments":[{...},...]
+const NEWS_ATTACHMENTS_FIELD_SIZE: usize = json_object_field_start("attachments") // ,"attachments":[ is the same size as ,"attachments":{
+    + (MAX_NEWS_ATTACHMENTS * (NEWS_ATTACHMENT_SIZE + 1)) // +1 for comma between elements
+    + json_close();
+
+/// NewsCreate: {"type":"NewsCreate","body":"...4096...","image":"...700000...","attachments":[{...},...]}
 const NEWS_CREATE_SIZE: usize = json_type_base("NewsCreate")
     + json_string_field("body", MAX_NEWS_BODY_LENGTH)
-    + json_string_field("image", MAX_NEWS_IMAGE_DATA_URI_LENGTH);
+    + json_string_field("image", MAX_NEWS_IMAGE_DATA_URI_LENGTH)
+    + NEWS_ATTACHMENTS_FIELD_SIZE;
 
 /// NewsEdit: {"type":"NewsEdit","id":-9223372036854775808}
 const NEWS_EDIT_SIZE: usize = json_type_base("NewsEdit") + json_i64_field("id");
 
-/// NewsUpdate: {"type":"NewsUpdate","id":-9223372036854775808,"body":"...4096...","image":"...700000..."}
+/// NewsUpdate: {"type":"NewsUpdate","id":-9223372036854775808,"body":"...4096...","image":"...700000...","attachments":[{...},...]}
 const NEWS_UPDATE_SIZE: usize = json_type_base("NewsUpdate")
     + json_i64_field("id")
     + json_string_field("body", MAX_NEWS_BODY_LENGTH)
-    + json_string_field("image", MAX_NEWS_IMAGE_DATA_URI_LENGTH);
+    + json_string_field("image", MAX_NEWS_IMAGE_DATA_URI_LENGTH)
+    + NEWS_ATTACHMENTS_FIELD_SIZE;
 
 /// NewsDelete: {"type":"NewsDelete","id":-9223372036854775808}
 const NEWS_DELETE_SIZE: usize = json_type_base("NewsDelete") + json_i64_field("id");
 
+/// NewsSearch: {"type":"NewsSearch","query":"...256...","author":"...32...","since":"YYYY-MM-DD","until":"YYYY-MM-DD"}
+const NEWS_SEARCH_SIZE: usize = json_type_base("NewsSearch")
+    + json_string_field("query", MAX_SEARCH_QUERY_LENGTH)
+    + json_string_field("author", MAX_USERNAME_LENGTH)
+    + json_string_field("since", NEWS_SEARCH_DATE_LENGTH)
+    + json_string_field("until", NEWS_SEARCH_DATE_LENGTH);
+
+/// SnippetCreate: {"type":"SnippetCreate","content":"...65536...","language":"...32...","expires_in":4294967295}
+const SNIPPET_CREATE_SIZE: usize = json_type_base("SnippetCreate")
+    + json_string_field("content", MAX_SNIPPET_JSON_LENGTH)
+    + json_string_field("language", MAX_SNIPPET_LANGUAGE_LENGTH)
+    + json_u32_field("expires_in");
+
+/// SnippetGet: {"type":"SnippetGet","id":"...10..."}
+const SNIPPET_GET_SIZE: usize =
+    json_type_base("SnippetGet") + json_string_field("id", SNIPPET_ID_LENGTH);
+
 // -----------------------------------------------------------------------------
 // Client messages - Files
 // -----------------------------------------------------------------------------
@@ -378,6 +618,36 @@ const FILE_INFO_SIZE: usize = json_type_base("FileInfo")
     + json_string_field("path", MAX_FILE_PATH_LENGTH)
     + json_bool_field("root");
 
+/// FileManifest: {"type":"FileManifest","path":"...4096...","root":false}
+const FILE_MANIFEST_SIZE: usize = json_type_base("FileManifest")
+    + json_string_field("path", MAX_FILE_PATH_LENGTH)
+    + json_bool_field("root");
+
+/// One FileStat entry: {"path":"...4096...","size":18446744073709551615}
+const FILE_STAT_QUERY_SIZE: usize = 1
+    + json_first_string_field("path", MAX_FILE_PATH_LENGTH)
+    + json_u64_field("size")
+    + json_close();
+
+/// FileStat: {"type":"FileStat","files":[{...},...],"root":false}
+const FILE_STAT_SIZE: usize = json_type_base("FileStat")
+    + "files".len()
+    + 5
+    + MAX_FILE_STAT_COUNT * (FILE_STAT_QUERY_SIZE + 1)
+    + json_bool_field("root");
+
+/// One FileWatch entry: {"path":"...4096...","root":false}
+const FILE_WATCH_PATH_SIZE: usize = 1
+    + json_first_string_field("path", MAX_FILE_PATH_LENGTH)
+    + json_bool_field("root")
+    + json_close();
+
+/// FileWatch: {"type":"FileWatch","directories":[{...},...]}
+const FILE_WATCH_SIZE: usize = json_type_base("FileWatch")
+    + "directories".len()
+    + 5
+    + MAX_FILE_WATCH_COUNT * (FILE_WATCH_PATH_SIZE + 1);
+
 /// FileRename: {"type":"FileRename","path":"...4096...","new_name":"...255...","root":false}
 const FILE_RENAME_SIZE: usize = json_type_base("FileRename")
     + json_string_field("path", MAX_FILE_PATH_LENGTH)
@@ -400,17 +670,23 @@ const FILE_COPY_SIZE: usize = json_type_base("FileCopy")
     + json_bool_field("source_root")
     + json_bool_field("destination_root");
 
-/// FileDownload: {"type":"FileDownload","path":"...4096...","root":false}
+/// FileDownload: {"type":"FileDownload","path":"...4096...","root":false,"include":["...4096...",...]}
 const FILE_DOWNLOAD_SIZE: usize = json_type_base("FileDownload")
     + json_string_field("path", MAX_FILE_PATH_LENGTH)
-    + json_bool_field("root");
+    + json_bool_field("root")
+    + json_string_array_field("include", MAX_DOWNLOAD_INCLUDE_COUNT, MAX_FILE_PATH_LENGTH);
 
-/// FileUpload: {"type":"FileUpload","destination":"...4096...","file_count":18446744073709551615,"total_size":18446744073709551615,"root":false}
+/// FileUpload: {"type":"FileUpload","destination":"...4096...","file_count":18446744073709551615,"total_size":18446744073709551615,"root":false,"overwrite":false}
 const FILE_UPLOAD_SIZE: usize = json_type_base("FileUpload")
     + json_string_field("destination", MAX_FILE_PATH_LENGTH)
     + json_u64_field("file_count")
     + json_u64_field("total_size")
-    + json_bool_field("root");
+    + json_bool_field("root")
+    + json_bool_field("overwrite");
+
+/// ServerExport: {"type":"ServerExport","include_ip_rules":false}
+const SERVER_EXPORT_SIZE: usize =
+    json_type_base("ServerExport") + json_bool_field("include_ip_rules");
 
 /// FileSearch: {"type":"FileSearch","query":"...256...","root":false}
 const FILE_SEARCH_SIZE: usize = json_type_base("FileSearch")
@@ -420,23 +696,140 @@ const FILE_SEARCH_SIZE: usize = json_type_base("FileSearch")
 /// FileReindex: {"type":"FileReindex"}
 const FILE_REINDEX_SIZE: usize = json_type_base("FileReindex");
 
+/// FileCleanup: {"type":"FileCleanup","delete":false}
+const FILE_CLEANUP_SIZE: usize = json_type_base("FileCleanup") + json_bool_field("delete");
+
+/// FileCheck: {"type":"FileCheck","repair":false}
+const FILE_CHECK_SIZE: usize = json_type_base("FileCheck") + json_bool_field("repair");
+
+/// FilePopular: {"type":"FilePopular","root":false}
+const FILE_POPULAR_SIZE: usize = json_type_base("FilePopular") + json_bool_field("root");
+
+/// FileRecent: {"type":"FileRecent","root":false}
+const FILE_RECENT_SIZE: usize = json_type_base("FileRecent") + json_bool_field("root");
+
+/// FileRate: {"type":"FileRate","path":"...4352...","rating":255,"root":false}
+const FILE_RATE_SIZE: usize = json_type_base("FileRate")
+    + json_string_field("path", MAX_FILE_PATH_LENGTH)
+    + json_u16_field("rating")
+    + json_bool_field("root");
+
+/// FileCommentCreate: {"type":"FileCommentCreate","path":"...4352...","body":"...1024...","root":false}
+const FILE_COMMENT_CREATE_SIZE: usize = json_type_base("FileCommentCreate")
+    + json_string_field("path", MAX_FILE_PATH_LENGTH)
+    + json_string_field("body", MAX_FILE_COMMENT_LENGTH)
+    + json_bool_field("root");
+
+/// FileCommentList: {"type":"FileCommentList","path":"...4352...","root":false}
+const FILE_COMMENT_LIST_SIZE: usize = json_type_base("FileCommentList")
+    + json_string_field("path", MAX_FILE_PATH_LENGTH)
+    + json_bool_field("root");
+
+/// FileCommentDelete: {"type":"FileCommentDelete","id":-9223372036854775808}
+const FILE_COMMENT_DELETE_SIZE: usize = json_type_base("FileCommentDelete") + json_i64_field("id");
+
+/// UploadRule nested object size:
+/// {"extensions":["...16...",...],"max_file_size":u64,"max_total_size":u64}
+const UPLOAD_RULE_SIZE: usize = json_string_array_field(
+    "extensions",
+    MAX_UPLOAD_EXTENSIONS,
+    MAX_UPLOAD_EXTENSION_LENGTH,
+) + json_u64_field("max_file_size")
+    + json_u64_field("max_total_size")
+    + 2; // {} braces (the array size counts a leading comma the first field doesn't have)
+
+/// UploadRuleGet: {"type":"UploadRuleGet","path":"...4352...","root":false}
+const UPLOAD_RULE_GET_SIZE: usize = json_type_base("UploadRuleGet")
+    + json_string_field("path", MAX_FILE_PATH_LENGTH)
+    + json_bool_field("root");
+
+/// UploadRuleSet: {"type":"UploadRuleSet","path":"...4352...","rule":{...},"root":false}
+const UPLOAD_RULE_SET_SIZE: usize = json_type_base("UploadRuleSet")
+    + json_string_field("path", MAX_FILE_PATH_LENGTH)
+    + json_object_field_start("rule")
+    + UPLOAD_RULE_SIZE
+    + json_close()
+    + json_bool_field("root");
+
+/// LdapGroupList: {"type":"LdapGroupList"}
+const LDAP_GROUP_LIST_SIZE: usize = json_type_base("LdapGroupList");
+
+/// LdapGroupSet: {"type":"LdapGroupSet","group":"...512...","permissions":[...],"is_admin":false}
+const LDAP_GROUP_SET_SIZE: usize = json_type_base("LdapGroupSet")
+    + json_string_field("group", MAX_LDAP_GROUP_LENGTH)
+    + json_string_array_field("permissions", PERMISSIONS_COUNT, MAX_PERMISSION_LENGTH)
+    + json_bool_field("is_admin");
+
+/// LdapGroupDelete: {"type":"LdapGroupDelete","group":"...512..."}
+const LDAP_GROUP_DELETE_SIZE: usize =
+    json_type_base("LdapGroupDelete") + json_string_field("group", MAX_LDAP_GROUP_LENGTH);
+
+/// GuestUpgrade: {"type":"GuestUpgrade","username":"...32...","password":"...256..."}
+const GUEST_UPGRADE_SIZE: usize = json_type_base("GuestUpgrade")
+    + json_string_field("username", MAX_USERNAME_LENGTH)
+    + json_string_field("password", MAX_PASSWORD_LENGTH);
+
+/// NicknameRegister: {"type":"NicknameRegister","nickname":"...32...","username":"...32..."}
+const NICKNAME_REGISTER_SIZE: usize = json_type_base("NicknameRegister")
+    + json_string_field("nickname", MAX_NICKNAME_LENGTH)
+    + json_string_field("username", MAX_USERNAME_LENGTH);
+
+/// NicknameRelease: {"type":"NicknameRelease","nickname":"...32..."}
+const NICKNAME_RELEASE_SIZE: usize =
+    json_type_base("NicknameRelease") + json_string_field("nickname", MAX_NICKNAME_LENGTH);
+
+/// PresenceSubscribe: {"type":"PresenceSubscribe","usernames":["...32...",...]}
+const PRESENCE_SUBSCRIBE_SIZE: usize = json_type_base("PresenceSubscribe")
+    + json_string_array_field("usernames", MAX_PRESENCE_SUBSCRIPTIONS, MAX_USERNAME_LENGTH);
+
 // -----------------------------------------------------------------------------
 // Voice client messages
 // -----------------------------------------------------------------------------
 
-/// VoiceJoin: {"type":"VoiceJoin","target":"...32..."}
+/// VoiceJoin: {"type":"VoiceJoin","target":"...32...","broadcast":false}
 /// Target is either "#channel" (max 32) or "nickname" (max 32)
-const VOICE_JOIN_SIZE: usize =
-    json_type_base("VoiceJoin") + json_string_field("target", MAX_CHANNEL_LENGTH);
+const VOICE_JOIN_SIZE: usize = json_type_base("VoiceJoin")
+    + json_string_field("target", MAX_CHANNEL_LENGTH)
+    + json_bool_field("broadcast");
 
 /// VoiceLeave: {"type":"VoiceLeave"}
 const VOICE_LEAVE_SIZE: usize = json_type_base("VoiceLeave");
 
+/// VoiceLimit: {"type":"VoiceLimit","channel":"...32...","limit":4294967295}
+const VOICE_LIMIT_SIZE: usize = json_type_base("VoiceLimit")
+    + json_string_field("channel", MAX_CHANNEL_LENGTH)
+    + json_u32_field("limit");
+
+/// VoiceClipList: {"type":"VoiceClipList"}
+const VOICE_CLIP_LIST_SIZE: usize = json_type_base("VoiceClipList");
+
+/// VoiceClipUpload: {"type":"VoiceClipUpload","name":"...32...","data":"...350000..."}
+const VOICE_CLIP_UPLOAD_SIZE: usize = json_type_base("VoiceClipUpload")
+    + json_string_field("name", MAX_VOICE_CLIP_NAME_LENGTH)
+    + json_string_field("data", MAX_VOICE_CLIP_DATA_URI_LENGTH);
+
+/// VoiceClipDelete: {"type":"VoiceClipDelete","name":"...32..."}
+const VOICE_CLIP_DELETE_SIZE: usize =
+    json_type_base("VoiceClipDelete") + json_string_field("name", MAX_VOICE_CLIP_NAME_LENGTH);
+
+/// VoiceClipPlay: {"type":"VoiceClipPlay","name":"...32..."}
+const VOICE_CLIP_PLAY_SIZE: usize =
+    json_type_base("VoiceClipPlay") + json_string_field("name", MAX_VOICE_CLIP_NAME_LENGTH);
+
+/// VoiceTextSend: {"type":"VoiceTextSend","message":"...256..."}
+const VOICE_TEXT_SEND_SIZE: usize =
+    json_type_base("VoiceTextSend") + json_string_field("message", MAX_VOICE_TEXT_LENGTH);
+
 /// Ping: {"type":"Ping"}
 const PING_SIZE: usize = json_type_base("Ping");
 
-/// Pong: {"type":"Pong"}
-const PONG_SIZE: usize = json_type_base("Pong");
+/// Pong: {"type":"Pong","time":{...}}
+const PONG_SIZE: usize =
+    json_type_base("Pong") + json_object_field_start("time") + SERVER_TIME_SIZE + json_close();
+
+/// Cancel: {"type":"Cancel","message_id":"...12..."}
+const CANCEL_SIZE: usize =
+    json_type_base("Cancel") + json_string_field("message_id", MSG_ID_LENGTH);
 
 // -----------------------------------------------------------------------------
 // Transfer messages (shared between client and server)
@@ -463,6 +856,9 @@ const TRANSFER_COMPLETE_SIZE: usize = json_type_base("TransferComplete")
     + json_string_field("error", MAX_ERROR_LENGTH)
     + json_string_field("error_kind", MAX_ERROR_KIND_LENGTH);
 
+/// TransferQueued: {"type":"TransferQueued","position":4294967295}
+const TRANSFER_QUEUED_SIZE: usize = json_type_base("TransferQueued") + json_u32_field("position");
+
 // -----------------------------------------------------------------------------
 // Server messages - Voice
 // -----------------------------------------------------------------------------
@@ -473,11 +869,19 @@ const MAX_VOICE_PARTICIPANTS: usize = 100;
 /// UUID string length when serialized (e.g., "550e8400-e29b-41d4-a716-446655440000")
 const UUID_STRING_LENGTH: usize = 36;
 
-/// VoiceJoinResponse: {"type":"VoiceJoinResponse","success":false,"token":"...36...","participants":["...64...",...100...],"error":"...2048..."}
+/// Maximum advertised voice address length (a DNS hostname)
+const MAX_VOICE_ADDRESS_LENGTH: usize = 253;
+
+/// VoiceJoinResponse: {"type":"VoiceJoinResponse","success":false,"token":"...36...","participants":["...64...",...100...],"voice_address":"...253...","voice_port":65535,"tcp_relay":false,"listener":false,"listeners":4294967295,"error":"...2048..."}
 const VOICE_JOIN_RESPONSE_SIZE: usize = json_type_base("VoiceJoinResponse")
     + json_bool_field("success")
     + json_string_field("token", UUID_STRING_LENGTH)
     + json_string_array_field("participants", MAX_VOICE_PARTICIPANTS, MAX_NICKNAME_LENGTH)
+    + json_string_field("voice_address", MAX_VOICE_ADDRESS_LENGTH)
+    + json_u16_field("voice_port")
+    + json_bool_field("tcp_relay")
+    + json_bool_field("listener")
+    + json_u32_field("listeners")
     + json_string_field("error", MAX_ERROR_LENGTH);
 
 /// VoiceLeaveResponse: {"type":"VoiceLeaveResponse","success":false,"error":"...2048..."}
@@ -485,16 +889,96 @@ const VOICE_LEAVE_RESPONSE_SIZE: usize = json_type_base("VoiceLeaveResponse")
     + json_bool_field("success")
     + json_string_field("error", MAX_ERROR_LENGTH);
 
+/// VoiceLimitResponse: {"type":"VoiceLimitResponse","success":false,"error":"...2048..."}
+const VOICE_LIMIT_RESPONSE_SIZE: usize = json_type_base("VoiceLimitResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH);
+
+/// VoiceQueueUpdate: {"type":"VoiceQueueUpdate","target":"...32...","position":4294967295}
+const VOICE_QUEUE_UPDATE_SIZE: usize = json_type_base("VoiceQueueUpdate")
+    + json_string_field("target", MAX_CHANNEL_LENGTH)
+    + json_u32_field("position");
+
 /// VoiceUserJoined: {"type":"VoiceUserJoined","nickname":"...32...","target":"...32..."}
 const VOICE_USER_JOINED_SIZE: usize = json_type_base("VoiceUserJoined")
     + json_string_field("nickname", MAX_NICKNAME_LENGTH)
     + json_string_field("target", MAX_CHANNEL_LENGTH);
 
+/// VoiceBroadcastUpdate: {"type":"VoiceBroadcastUpdate","target":"...32...","broadcaster":"...32...","listeners":4294967295}
+const VOICE_BROADCAST_UPDATE_SIZE: usize = json_type_base("VoiceBroadcastUpdate")
+    + json_string_field("target", MAX_CHANNEL_LENGTH)
+    + json_string_field("broadcaster", MAX_NICKNAME_LENGTH)
+    + json_u32_field("listeners");
+
 /// VoiceUserLeft: {"type":"VoiceUserLeft","nickname":"...32...","target":"...32..."}
 const VOICE_USER_LEFT_SIZE: usize = json_type_base("VoiceUserLeft")
     + json_string_field("nickname", MAX_NICKNAME_LENGTH)
     + json_string_field("target", MAX_CHANNEL_LENGTH);
 
+/// VoiceStats: {"type":"VoiceStats","nickname":"...32...","loss_percent":100,"jitter_ms":4294967295}
+/// (loss_percent is a u8, bounded here by the u16 field size)
+const VOICE_STATS_SIZE: usize = json_type_base("VoiceStats")
+    + json_string_field("nickname", MAX_NICKNAME_LENGTH)
+    + json_u16_field("loss_percent")
+    + json_u32_field("jitter_ms");
+
+/// VoiceClipUploadResponse: {"type":"VoiceClipUploadResponse","success":false,"error":"...2048..."}
+const VOICE_CLIP_UPLOAD_RESPONSE_SIZE: usize = json_type_base("VoiceClipUploadResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH);
+
+/// VoiceClipDeleteResponse: {"type":"VoiceClipDeleteResponse","success":false,"error":"...2048..."}
+const VOICE_CLIP_DELETE_RESPONSE_SIZE: usize = json_type_base("VoiceClipDeleteResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH);
+
+/// VoiceClipPlayResponse: {"type":"VoiceClipPlayResponse","success":false,"error":"...2048..."}
+const VOICE_CLIP_PLAY_RESPONSE_SIZE: usize = json_type_base("VoiceClipPlayResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH);
+
+/// VoiceClipPlayed: {"type":"VoiceClipPlayed","nickname":"...32...","target":"...32...","name":"...32...","data":"...350000..."}
+const VOICE_CLIP_PLAYED_SIZE: usize = json_type_base("VoiceClipPlayed")
+    + json_string_field("nickname", MAX_NICKNAME_LENGTH)
+    + json_string_field("target", MAX_CHANNEL_LENGTH)
+    + json_string_field("name", MAX_VOICE_CLIP_NAME_LENGTH)
+    + json_string_field("data", MAX_VOICE_CLIP_DATA_URI_LENGTH);
+
+/// VoiceTextSendResponse: {"type":"VoiceTextSendResponse","success":false,"error":"...2048..."}
+const VOICE_TEXT_SEND_RESPONSE_SIZE: usize = json_type_base("VoiceTextSendResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH);
+
+/// VoiceText: {"type":"VoiceText","nickname":"...32...","target":"...32...","message":"...256..."}
+const VOICE_TEXT_SIZE: usize = json_type_base("VoiceText")
+    + json_string_field("nickname", MAX_NICKNAME_LENGTH)
+    + json_string_field("target", MAX_CHANNEL_LENGTH)
+    + json_string_field("message", MAX_VOICE_TEXT_LENGTH);
+
+// -----------------------------------------------------------------------------
+// Voice relay (shared between client and server)
+// -----------------------------------------------------------------------------
+
+/// Largest binary packet carried by VoiceRelay: a relayed packet from the server
+/// (type + sender_len + sender + sequence + timestamp + payload). Client packets
+/// (MAX_VOICE_PACKET_SIZE) are always smaller.
+const MAX_VOICE_RELAY_PACKET_SIZE: usize =
+    2 + RelayedVoicePacket::MAX_SENDER_LEN + 8 + MAX_VOICE_PAYLOAD;
+
+/// Base64-encoded length of MAX_VOICE_RELAY_PACKET_SIZE bytes (padded)
+const MAX_VOICE_RELAY_DATA_LENGTH: usize = MAX_VOICE_RELAY_PACKET_SIZE.div_ceil(3) * 4;
+
+/// VoiceRelay: {"type":"VoiceRelay","data":"...1432..."}
+const VOICE_RELAY_SIZE: usize =
+    json_type_base("VoiceRelay") + json_string_field("data", MAX_VOICE_RELAY_DATA_LENGTH);
+
+/// Base64-encoded length of MAX_DOOR_DATA_SIZE bytes (padded)
+const MAX_DOOR_DATA_LENGTH: usize = MAX_DOOR_DATA_SIZE.div_ceil(3) * 4;
+
+/// DoorData: {"type":"DoorData","data":"...5464..."}
+const DOOR_DATA_SIZE: usize =
+    json_type_base("DoorData") + json_string_field("data", MAX_DOOR_DATA_LENGTH);
+
 // -----------------------------------------------------------------------------
 // Server messages - Chat
 // -----------------------------------------------------------------------------
@@ -510,13 +994,21 @@ const CHAT_MESSAGE_SIZE: usize = json_type_base("ChatMessage")
     + json_string_field("channel", MAX_CHANNEL_LENGTH)
     + json_u64_field("timestamp");
 
-/// ChatUpdated: {"type":"ChatUpdated","channel":"...32...","topic":"...256...","topic_set_by":"...64...","secret":false,"secret_set_by":"...64..."}
+/// ChatUpdated: {"type":"ChatUpdated","channel":"...32...","topic":"...256...","topic_set_by":"...64...","secret":false,"secret_set_by":"...64...","voice_limit":4294967295,"voice_limit_set_by":"...64...","topic_locked":false,"topic_locked_set_by":"...64...","rules":"...4096...","rules_set_by":"...64...","language":"...16...","language_set_by":"...64..."}
 const CHAT_UPDATED_SIZE: usize = json_type_base("ChatUpdated")
     + json_string_field("channel", MAX_CHANNEL_LENGTH)
     + json_string_field("topic", MAX_CHAT_TOPIC_LENGTH)
     + json_string_field("topic_set_by", MAX_NICKNAME_LENGTH)
     + json_bool_field("secret")
-    + json_string_field("secret_set_by", MAX_NICKNAME_LENGTH);
+    + json_string_field("secret_set_by", MAX_NICKNAME_LENGTH)
+    + json_u32_field("voice_limit")
+    + json_string_field("voice_limit_set_by", MAX_NICKNAME_LENGTH)
+    + json_bool_field("topic_locked")
+    + json_string_field("topic_locked_set_by", MAX_NICKNAME_LENGTH)
+    + json_string_field("rules", MAX_CHAT_RULES_LENGTH)
+    + json_string_field("rules_set_by", MAX_NICKNAME_LENGTH)
+    + json_string_field("language", MAX_CHANNEL_LANGUAGE_LENGTH)
+    + json_string_field("language_set_by", MAX_NICKNAME_LENGTH);
 
 /// ChatUserJoined: {"type":"ChatUserJoined","channel":"...32...","nickname":"...64...","is_admin":false,"is_shared":false}
 const CHAT_USER_JOINED_SIZE: usize = json_type_base("ChatUserJoined")
@@ -535,6 +1027,37 @@ const CHAT_SECRET_RESPONSE_SIZE: usize = json_type_base("ChatSecretResponse")
     + json_bool_field("success")
     + json_string_field("error", MAX_ERROR_LENGTH);
 
+/// ChatTopicLockResponse: {"type":"ChatTopicLockResponse","success":false,"error":"...2048..."}
+const CHAT_TOPIC_LOCK_RESPONSE_SIZE: usize = json_type_base("ChatTopicLockResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH);
+
+/// ChatEntryUpdateResponse: {"type":"ChatEntryUpdateResponse","success":false,"error":"...2048..."}
+const CHAT_ENTRY_UPDATE_RESPONSE_SIZE: usize = json_type_base("ChatEntryUpdateResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH);
+
+/// ChatLanguageResponse: {"type":"ChatLanguageResponse","success":false,"error":"...2048..."}
+const CHAT_LANGUAGE_RESPONSE_SIZE: usize = json_type_base("ChatLanguageResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH);
+
+/// One topic history entry: {"topic":"...256...","old_topic":"...256...","set_by":"...64...","changed_at":18446744073709551615}
+const CHAT_TOPIC_CHANGE_SIZE: usize = json_first_string_field("topic", MAX_CHAT_TOPIC_LENGTH)
+    + json_string_field("old_topic", MAX_CHAT_TOPIC_LENGTH)
+    + json_string_field("set_by", MAX_NICKNAME_LENGTH)
+    + json_u64_field("changed_at")
+    + 2; // {} braces
+
+/// ChatTopicHistoryResponse: {"type":"ChatTopicHistoryResponse","success":false,"error":"...2048...","channel":"...32...","changes":[{...},...]}
+const CHAT_TOPIC_HISTORY_RESPONSE_SIZE: usize = json_type_base("ChatTopicHistoryResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH)
+    + json_string_field("channel", MAX_CHANNEL_LENGTH)
+    + json_object_field_start("changes") // ,"changes":[ is the same size as ,"changes":{
+    + (MAX_CHAT_TOPIC_HISTORY * (CHAT_TOPIC_CHANGE_SIZE + 1)) // +1 for comma between elements
+    + json_close();
+
 /// ChatLeaveResponse: {"type":"ChatLeaveResponse","channel":"...32...","error":"...2048..."}
 const CHAT_LEAVE_RESPONSE_SIZE: usize = json_type_base("ChatLeaveResponse")
     + json_string_field("channel", MAX_CHANNEL_LENGTH)
@@ -549,32 +1072,71 @@ const CHAT_JOIN_RESPONSE_SIZE: usize = json_type_base("ChatJoinResponse")
     + json_string_field("topic_set_by", MAX_NICKNAME_LENGTH)
     + json_bool_field("secret")
     + json_string_array_field("members", MAX_CHANNEL_MEMBERS, MAX_NICKNAME_LENGTH)
-    + json_string_array_field("voiced", MAX_CHANNEL_MEMBERS, MAX_NICKNAME_LENGTH);
+    + json_string_array_field("voiced", MAX_CHANNEL_MEMBERS, MAX_NICKNAME_LENGTH)
+    + json_u32_field("voice_limit")
+    + json_bool_field("topic_locked")
+    + json_string_field("entry_message", MAX_CHAT_ENTRY_MESSAGE_LENGTH)
+    + json_string_field("rules", MAX_CHAT_RULES_LENGTH)
+    + json_string_field("language", MAX_CHANNEL_LANGUAGE_LENGTH);
 
 // -----------------------------------------------------------------------------
 // Server messages - Simple responses (success + error pattern)
 // -----------------------------------------------------------------------------
 
+/// ChatSendResponse: {"type":"ChatSendResponse","success":false}
+const CHAT_SEND_RESPONSE_SIZE: usize =
+    json_type_base("ChatSendResponse") + json_bool_field("success");
+
 /// ChatTopicUpdateResponse: {"type":"ChatTopicUpdateResponse","success":false,"error":"...2048..."}
 const CHAT_TOPIC_UPDATE_RESPONSE_SIZE: usize = json_type_base("ChatTopicUpdateResponse")
     + json_bool_field("success")
     + json_string_field("error", MAX_ERROR_LENGTH);
 
-/// HandshakeResponse: {"type":"HandshakeResponse","success":false,"version":"...32...","error":"...2048..."}
+/// OidcProvider nested object size: {"authorization_endpoint":"...512...","client_id":"...256..."}
+const OIDC_PROVIDER_SIZE: usize =
+    json_first_string_field("authorization_endpoint", MAX_OIDC_ENDPOINT_LENGTH)
+        + json_string_field("client_id", MAX_OIDC_CLIENT_ID_LENGTH)
+        + 2; // {} braces
+
+/// HandshakeResponse: {"type":"HandshakeResponse","success":false,"version":"...32...","error":"...2048...","identity_key":"...64...","identity_signature":"...128...","pow_challenge":"...32...","pow_difficulty":255,"oidc":{...}}
 const HANDSHAKE_RESPONSE_SIZE: usize = json_type_base("HandshakeResponse")
     + json_bool_field("success")
     + json_string_field("version", MAX_VERSION_LENGTH)
-    + json_string_field("error", MAX_ERROR_LENGTH);
+    + json_string_field("error", MAX_ERROR_LENGTH)
+    + json_string_field("identity_key", IDENTITY_KEY_LENGTH)
+    + json_string_field("identity_signature", IDENTITY_SIGNATURE_LENGTH)
+    + json_string_field("pow_challenge", POW_CHALLENGE_LENGTH)
+    + json_u16_field("pow_difficulty")
+    + json_object_field_start("oidc")
+    + OIDC_PROVIDER_SIZE
+    + json_close();
 
 /// ServerInfoUpdateResponse: {"type":"ServerInfoUpdateResponse","success":false,"error":"...2048..."}
 const SERVER_INFO_UPDATE_RESPONSE_SIZE: usize = json_type_base("ServerInfoUpdateResponse")
     + json_bool_field("success")
     + json_string_field("error", MAX_ERROR_LENGTH);
 
-/// UserBroadcastResponse: {"type":"UserBroadcastResponse","success":false,"error":"...2048..."}
+/// UserBroadcastResponse: {"type":"UserBroadcastResponse","success":false,"error":"...2048...","broadcast_id":18446744073709551615,"delivered":4294967295,"send_at":-9223372036854775808}
 const USER_BROADCAST_RESPONSE_SIZE: usize = json_type_base("UserBroadcastResponse")
     + json_bool_field("success")
-    + json_string_field("error", MAX_ERROR_LENGTH);
+    + json_string_field("error", MAX_ERROR_LENGTH)
+    + json_u64_field("broadcast_id")
+    + json_u32_field("delivered")
+    + json_i64_field("send_at");
+
+/// BroadcastStatus: {"type":"BroadcastStatus","broadcast_id":18446744073709551615,"delivered":4294967295,"acknowledged":4294967295}
+const BROADCAST_STATUS_SIZE: usize = json_type_base("BroadcastStatus")
+    + json_u64_field("broadcast_id")
+    + json_u32_field("delivered")
+    + json_u32_field("acknowledged");
+
+/// StorageAlert: {"type":"StorageAlert","volume":"database","low":false,"free":18446744073709551615,"total":18446744073709551615,"threshold":18446744073709551615}
+const STORAGE_ALERT_SIZE: usize = json_type_base("StorageAlert")
+    + json_enum_field("volume", MAX_STORAGE_VOLUME_LENGTH)
+    + json_bool_field("low")
+    + json_u64_field("free")
+    + json_u64_field("total")
+    + json_u64_field("threshold");
 
 /// UserCreateResponse: {"type":"UserCreateResponse","success":false,"error":"...2048...","username":"...32..."}
 const USER_CREATE_RESPONSE_SIZE: usize = json_type_base("UserCreateResponse")
@@ -588,6 +1150,12 @@ const USER_DELETE_RESPONSE_SIZE: usize = json_type_base("UserDeleteResponse")
     + json_string_field("error", MAX_ERROR_LENGTH)
     + json_string_field("username", MAX_USERNAME_LENGTH);
 
+/// UserEraseResponse: {"type":"UserEraseResponse","success":false,"error":"...2048...","username":"...32..."}
+const USER_ERASE_RESPONSE_SIZE: usize = json_type_base("UserEraseResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH)
+    + json_string_field("username", MAX_USERNAME_LENGTH);
+
 /// UserUpdateResponse: {"type":"UserUpdateResponse","success":false,"error":"...2048...","username":"...32..."}
 const USER_UPDATE_RESPONSE_SIZE: usize = json_type_base("UserUpdateResponse")
     + json_bool_field("success")
@@ -628,29 +1196,33 @@ const TRUST_DELETE_RESPONSE_SIZE: usize = json_type_base("TrustDeleteResponse")
     + json_string_array_field("ips", MAX_RESPONSE_IPS, MAX_IP_LENGTH)
     + json_string_field("nickname", MAX_NICKNAME_LENGTH);
 
-/// UserMessageResponse: {"type":"UserMessageResponse","success":false,"error":"...2048...","is_away":false,"status":"...128..."}
+/// UserMessageResponse: {"type":"UserMessageResponse","success":false,"error":"...2048...","error_kind":"...16...","is_away":false,"status":"...128..."}
 const USER_MESSAGE_RESPONSE_SIZE: usize = json_type_base("UserMessageResponse")
     + json_bool_field("success")
     + json_string_field("error", MAX_ERROR_LENGTH)
+    + json_string_field("error_kind", MAX_ERROR_KIND_LENGTH)
     + json_bool_field("is_away")
     + json_string_field("status", MAX_STATUS_LENGTH);
 
-/// Error: {"type":"Error","message":"...2048...","command":"...32..."}
+/// Error: {"type":"Error","message":"...2048...","command":"...32...","error_kind":"...16..."}
 const ERROR_SIZE: usize = json_type_base("Error")
     + json_string_field("message", MAX_ERROR_LENGTH)
-    + json_string_field("command", MAX_COMMAND_LENGTH);
+    + json_string_field("command", MAX_COMMAND_LENGTH)
+    + json_string_field("error_kind", MAX_ERROR_KIND_LENGTH);
 
-/// ServerBroadcast: {"type":"ServerBroadcast","message":"...1024...","from_nickname":"...64..."}
+/// ServerBroadcast: {"type":"ServerBroadcast","session_id":4294967295,"username":"...32...","message":"...1024...","broadcast_id":18446744073709551615}
 const SERVER_BROADCAST_SIZE: usize = json_type_base("ServerBroadcast")
+    + json_u32_field("session_id")
+    + json_string_field("username", MAX_USERNAME_LENGTH)
     + json_string_field("message", MAX_MESSAGE_LENGTH)
-    + json_string_field("from_nickname", MAX_NICKNAME_LENGTH);
+    + json_u64_field("broadcast_id");
 
 /// UserDisconnected: {"type":"UserDisconnected","session_id":4294967295,"nickname":"...64..."}
 const USER_DISCONNECTED_SIZE: usize = json_type_base("UserDisconnected")
     + json_u32_field("session_id")
     + json_string_field("nickname", MAX_NICKNAME_LENGTH);
 
-/// UserMessage (server): {"type":"UserMessage","from_nickname":"...64...","from_admin":false,"from_shared":false,"to_nickname":"...64...","message":"...1024...","action":"Normal","timestamp":18446744073709551615}
+/// UserMessage (server): {"type":"UserMessage","from_nickname":"...64...","from_admin":false,"from_shared":false,"to_nickname":"...64...","message":"...1024...","action":"Normal","timestamp":18446744073709551615,"id":18446744073709551615}
 const USER_MESSAGE_SIZE: usize = json_type_base("UserMessage")
     + json_string_field("from_nickname", MAX_NICKNAME_LENGTH)
     + json_bool_field("from_admin")
@@ -658,7 +1230,8 @@ const USER_MESSAGE_SIZE: usize = json_type_base("UserMessage")
     + json_string_field("to_nickname", MAX_NICKNAME_LENGTH)
     + json_string_field("message", MAX_MESSAGE_LENGTH)
     + json_enum_field("action", MAX_ACTION_VARIANT)
-    + json_u64_field("timestamp");
+    + json_u64_field("timestamp")
+    + json_u64_field("id");
 
 /// UserAwayResponse: {"type":"UserAwayResponse","success":false,"error":"...2048..."}
 const USER_AWAY_RESPONSE_SIZE: usize = json_type_base("UserAwayResponse")
@@ -675,6 +1248,65 @@ const USER_STATUS_RESPONSE_SIZE: usize = json_type_base("UserStatusResponse")
     + json_bool_field("success")
     + json_string_field("error", MAX_ERROR_LENGTH);
 
+/// UserDndResponse: {"type":"UserDndResponse","success":false,"error":"...2048..."}
+const USER_DND_RESPONSE_SIZE: usize = json_type_base("UserDndResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH);
+
+/// MessageHistoryRetentionResponse: {"type":"MessageHistoryRetentionResponse","success":false,"error":"...2048...","days":4294967295}
+const MESSAGE_HISTORY_RETENTION_RESPONSE_SIZE: usize =
+    json_type_base("MessageHistoryRetentionResponse")
+        + json_bool_field("success")
+        + json_string_field("error", MAX_ERROR_LENGTH)
+        + json_u32_field("days");
+
+/// MessageDelivered: {"type":"MessageDelivered","nickname":"...64...","id":18446744073709551615}
+const MESSAGE_DELIVERED_SIZE: usize = json_type_base("MessageDelivered")
+    + json_string_field("nickname", MAX_NICKNAME_LENGTH)
+    + json_u64_field("id");
+
+/// MessageRead: {"type":"MessageRead","nickname":"...64...","id":18446744073709551615}
+const MESSAGE_READ_SIZE: usize = json_type_base("MessageRead")
+    + json_string_field("nickname", MAX_NICKNAME_LENGTH)
+    + json_u64_field("id");
+
+/// GroupMessageCreateResponse: {"type":"GroupMessageCreateResponse","success":false,"error":"...2048...","group_id":18446744073709551615}
+const GROUP_MESSAGE_CREATE_RESPONSE_SIZE: usize = json_type_base("GroupMessageCreateResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH)
+    + json_u64_field("group_id");
+
+/// GroupMessageSendResponse: {"type":"GroupMessageSendResponse","success":false,"error":"...2048...","error_kind":"...16..."}
+const GROUP_MESSAGE_SEND_RESPONSE_SIZE: usize = json_type_base("GroupMessageSendResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH)
+    + json_string_field("error_kind", MAX_ERROR_KIND_LENGTH);
+
+/// GroupMessageInviteResponse: {"type":"GroupMessageInviteResponse","success":false,"error":"...2048..."}
+const GROUP_MESSAGE_INVITE_RESPONSE_SIZE: usize = json_type_base("GroupMessageInviteResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH);
+
+/// GroupMessageLeaveResponse: {"type":"GroupMessageLeaveResponse","success":false,"error":"...2048..."}
+const GROUP_MESSAGE_LEAVE_RESPONSE_SIZE: usize = json_type_base("GroupMessageLeaveResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH);
+
+/// GroupMessage: {"type":"GroupMessage","group_id":18446744073709551615,"from_nickname":"...64...","from_admin":false,"from_shared":false,"message":"...1024...","action":"Normal","timestamp":18446744073709551615}
+const GROUP_MESSAGE_SIZE: usize = json_type_base("GroupMessage")
+    + json_u64_field("group_id")
+    + json_string_field("from_nickname", MAX_NICKNAME_LENGTH)
+    + json_bool_field("from_admin")
+    + json_bool_field("from_shared")
+    + json_string_field("message", MAX_MESSAGE_LENGTH)
+    + json_enum_field("action", MAX_ACTION_VARIANT)
+    + json_u64_field("timestamp");
+
+/// GroupMembers: {"type":"GroupMembers","group_id":18446744073709551615,"members":["...64...",...]}
+const GROUP_MEMBERS_SIZE: usize = json_type_base("GroupMembers")
+    + json_u64_field("group_id")
+    + json_string_array_field("members", MAX_GROUP_MEMBERS, MAX_NICKNAME_LENGTH);
+
 /// NewsDeleteResponse: {"type":"NewsDeleteResponse","success":false,"error":"...2048..."}
 const NEWS_DELETE_RESPONSE_SIZE: usize = json_type_base("NewsDeleteResponse")
     + json_bool_field("success")
@@ -685,6 +1317,26 @@ const NEWS_UPDATED_SIZE: usize = json_type_base("NewsUpdated")
     + json_enum_field("action", MAX_NEWS_ACTION_LENGTH)
     + json_i64_field("id");
 
+/// SnippetCreateResponse: {"type":"SnippetCreateResponse","success":false,"error":"...2048...","id":"...10...","expires_at":-9223372036854775808}
+const SNIPPET_CREATE_RESPONSE_SIZE: usize = json_type_base("SnippetCreateResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH)
+    + json_string_field("id", SNIPPET_ID_LENGTH)
+    + json_i64_field("expires_at");
+
+/// SnippetGetResponse: {"type":"SnippetGetResponse","success":false,"error":"...2048...","snippet":{...}}
+const SNIPPET_GET_RESPONSE_SIZE: usize = json_type_base("SnippetGetResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH)
+    + json_object_field_start("snippet")
+    + json_first_string_field("id", SNIPPET_ID_LENGTH)
+    + json_string_field("author", MAX_NICKNAME_LENGTH)
+    + json_string_field("language", MAX_SNIPPET_LANGUAGE_LENGTH)
+    + json_string_field("content", MAX_SNIPPET_JSON_LENGTH)
+    + json_i64_field("created_at")
+    + json_i64_field("expires_at")
+    + json_close();
+
 /// FileDeleteResponse: {"type":"FileDeleteResponse","success":false,"error":"...2048..."}
 const FILE_DELETE_RESPONSE_SIZE: usize = json_type_base("FileDeleteResponse")
     + json_bool_field("success")
@@ -725,24 +1377,145 @@ const FILE_REINDEX_RESPONSE_SIZE: usize = json_type_base("FileReindexResponse")
     + json_bool_field("success")
     + json_string_field("error", MAX_ERROR_LENGTH);
 
-/// FileCreateDirResponse: {"type":"FileCreateDirResponse","success":false,"error":"...2048...","path":"...4352..."}
-const FILE_CREATE_DIR_RESPONSE_SIZE: usize = json_type_base("FileCreateDirResponse")
+/// FileWatchResponse: {"type":"FileWatchResponse","success":false,"error":"...2048..."}
+const FILE_WATCH_RESPONSE_SIZE: usize = json_type_base("FileWatchResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH);
+
+/// FileCheckResponse: {"type":"FileCheckResponse","success":false,"error":"...2048...",
+/// "summary":{"entries":u32,"issues":u32,"repaired":u32,"index_checked":false}}
+const FILE_CHECK_RESPONSE_SIZE: usize = json_type_base("FileCheckResponse")
     + json_bool_field("success")
     + json_string_field("error", MAX_ERROR_LENGTH)
-    + json_string_field("path", MAX_CREATED_DIR_PATH);
+    + json_object_field_start("summary")
+    + json_first_u32_field("entries")
+    + json_u32_field("issues")
+    + json_u32_field("repaired")
+    + json_bool_field("index_checked")
+    + json_close();
 
-/// UserInfo struct size (nested object in responses):
-/// {"username":"...32...","nickname":"...32...","login_time":i64,"is_admin":false,"is_shared":false,"session_ids":[u32,...],"locale":"...10...","avatar":"...176000...","is_away":false,"status":"...128..."}
-const USER_INFO_STRUCT_SIZE: usize = json_first_string_field("username", MAX_USERNAME_LENGTH)
-    + json_string_field("nickname", MAX_NICKNAME_LENGTH)
-    + json_i64_field("login_time")
-    + json_bool_field("is_admin")
-    + json_bool_field("is_shared")
-    + json_string_array_field("session_ids", MAX_SESSION_IDS, 10) // u32 as string ~10 digits
-    + json_string_field("locale", MAX_LOCALE_LENGTH)
-    + json_string_field("avatar", MAX_AVATAR_DATA_URI_LENGTH)
-    + json_bool_field("is_away")
+/// FileRating nested object size: {"count":4294967295,"total":4294967295}
+const FILE_RATING_SIZE: usize = "count".len() + 3 + MAX_JSON_U32 + json_u32_field("total") + 2; // {} braces
+
+/// FileRateResponse: {"type":"FileRateResponse","success":false,"error":"...2048...","rating":{...},"my_rating":255}
+const FILE_RATE_RESPONSE_SIZE: usize = json_type_base("FileRateResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH)
+    + json_object_field_start("rating")
+    + FILE_RATING_SIZE
+    + json_close()
+    + json_u16_field("my_rating");
+
+/// FileComment nested object size:
+/// {"id":i64,"author":"...32...","body":"...1024...","created_at":i64}
+const FILE_COMMENT_ITEM_SIZE: usize = json_first_i64_field("id")
+    + json_string_field("author", MAX_USERNAME_LENGTH)
+    + json_string_field("body", MAX_FILE_COMMENT_LENGTH)
+    + json_i64_field("created_at")
+    + 2; // {} braces
+
+/// FileCommentCreateResponse: {"type":"FileCommentCreateResponse","success":false,"error":"...2048...","comment":{...}}
+const FILE_COMMENT_CREATE_RESPONSE_SIZE: usize = json_type_base("FileCommentCreateResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH)
+    + json_object_field_start("comment")
+    + FILE_COMMENT_ITEM_SIZE
+    + json_close();
+
+/// FileCommentDeleteResponse: {"type":"FileCommentDeleteResponse","success":false,"error":"...2048...","id":i64}
+const FILE_COMMENT_DELETE_RESPONSE_SIZE: usize = json_type_base("FileCommentDeleteResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH)
+    + json_i64_field("id");
+
+/// UploadRuleGetResponse: {"type":"UploadRuleGetResponse","success":false,"error":"...2048...","rule":{...}}
+const UPLOAD_RULE_GET_RESPONSE_SIZE: usize = json_type_base("UploadRuleGetResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH)
+    + json_object_field_start("rule")
+    + UPLOAD_RULE_SIZE
+    + json_close();
+
+/// UploadRuleSetResponse: {"type":"UploadRuleSetResponse","success":false,"error":"...2048..."}
+const UPLOAD_RULE_SET_RESPONSE_SIZE: usize = json_type_base("UploadRuleSetResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH);
+
+/// LdapGroupSetResponse: {"type":"LdapGroupSetResponse","success":false,"error":"...2048...","group":"...512..."}
+const LDAP_GROUP_SET_RESPONSE_SIZE: usize = json_type_base("LdapGroupSetResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH)
+    + json_string_field("group", MAX_LDAP_GROUP_LENGTH);
+
+/// LdapGroupDeleteResponse: {"type":"LdapGroupDeleteResponse","success":false,"error":"...2048...","group":"...512..."}
+const LDAP_GROUP_DELETE_RESPONSE_SIZE: usize = json_type_base("LdapGroupDeleteResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH)
+    + json_string_field("group", MAX_LDAP_GROUP_LENGTH);
+
+/// GuestUpgradeResponse: {"type":"GuestUpgradeResponse","success":false,"error":"...2048...","username":"...32..."}
+const GUEST_UPGRADE_RESPONSE_SIZE: usize = json_type_base("GuestUpgradeResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH)
+    + json_string_field("username", MAX_USERNAME_LENGTH);
+
+/// NicknameRegisterResponse: {"type":"NicknameRegisterResponse","success":false,"error":"...2048...","nickname":"...32...","username":"...32..."}
+const NICKNAME_REGISTER_RESPONSE_SIZE: usize = json_type_base("NicknameRegisterResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH)
+    + json_string_field("nickname", MAX_NICKNAME_LENGTH)
+    + json_string_field("username", MAX_USERNAME_LENGTH);
+
+/// NicknameReleaseResponse: {"type":"NicknameReleaseResponse","success":false,"error":"...2048...","nickname":"...32..."}
+const NICKNAME_RELEASE_RESPONSE_SIZE: usize = json_type_base("NicknameReleaseResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH)
+    + json_string_field("nickname", MAX_NICKNAME_LENGTH);
+
+/// PresenceInfo nested object size:
+/// {"username":"...32...","online":false,"is_away":false,"status":"...128..."}
+const PRESENCE_INFO_SIZE: usize = json_first_string_field("username", MAX_USERNAME_LENGTH)
+    + json_bool_field("online")
+    + json_bool_field("is_away")
+    + json_string_field("status", MAX_STATUS_LENGTH)
+    + 2; // {} braces
+
+/// PresenceSubscribeResponse: {"type":"PresenceSubscribeResponse","success":false,"error":"...2048...","users":[{...},...]}
+const PRESENCE_SUBSCRIBE_RESPONSE_SIZE: usize = json_type_base("PresenceSubscribeResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH)
+    + json_object_field_start("users") // ,"users":[ is the same size as ,"users":{
+    + (MAX_PRESENCE_SUBSCRIPTIONS * (PRESENCE_INFO_SIZE + 1)) // +1 for comma between elements
+    + json_close();
+
+/// PresenceUpdate: {"type":"PresenceUpdate","user":{...}}
+const PRESENCE_UPDATE_SIZE: usize = json_type_base("PresenceUpdate")
+    + json_object_field_start("user")
+    + PRESENCE_INFO_SIZE
+    - 2 // {} braces are counted by the object field start and close
+    + json_close();
+
+/// FileCreateDirResponse: {"type":"FileCreateDirResponse","success":false,"error":"...2048...","path":"...4352..."}
+const FILE_CREATE_DIR_RESPONSE_SIZE: usize = json_type_base("FileCreateDirResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH)
+    + json_string_field("path", MAX_CREATED_DIR_PATH);
+
+/// UserInfo struct size (nested object in responses):
+/// {"username":"...32...","nickname":"...32...","login_time":i64,"is_admin":false,"is_shared":false,"session_ids":[u32,...],"locale":"...10...","avatar":"...176000...","is_away":false,"status":"...128...","is_dnd":false,"last_active":i64,"client":"...64..."}
+const USER_INFO_STRUCT_SIZE: usize = json_first_string_field("username", MAX_USERNAME_LENGTH)
+    + json_string_field("nickname", MAX_NICKNAME_LENGTH)
+    + json_i64_field("login_time")
+    + json_bool_field("is_admin")
+    + json_bool_field("is_shared")
+    + json_string_array_field("session_ids", MAX_SESSION_IDS, 10) // u32 as string ~10 digits
+    + json_string_field("locale", MAX_LOCALE_LENGTH)
+    + json_string_field("avatar", MAX_AVATAR_DATA_URI_LENGTH)
+    + json_bool_field("is_away")
     + json_string_field("status", MAX_STATUS_LENGTH)
+    + json_bool_field("is_dnd")
+    + json_i64_field("last_active")
+    + json_string_field("client", MAX_CLIENT_NAME_LENGTH)
     + 2; // {} braces
 
 /// UserConnected: {"type":"UserConnected","user":{...}}
@@ -758,8 +1531,17 @@ const USER_UPDATED_SIZE: usize = json_type_base("UserUpdated")
     + USER_INFO_STRUCT_SIZE
     + json_close();
 
+/// UserStats nested object size:
+/// {"logins":u64,"messages":u64,"uploads":u64,"download_bytes":u64}
+/// (the first field is counted with a leading comma, so this is one byte generous)
+const USER_STATS_SIZE: usize = json_u64_field("logins")
+    + json_u64_field("messages")
+    + json_u64_field("uploads")
+    + json_u64_field("download_bytes")
+    + 2; // {} braces
+
 /// UserInfoDetailed struct size (nested object in UserInfoResponse):
-/// Has more fields than UserInfo: features, created_at, addresses, channels
+/// Has more fields than UserInfo: features, created_at, addresses, channels, stats, rank
 const USER_INFO_DETAILED_SIZE: usize = json_first_string_field("username", MAX_USERNAME_LENGTH)
     + json_string_field("nickname", MAX_NICKNAME_LENGTH)
     + json_i64_field("login_time")
@@ -773,7 +1555,14 @@ const USER_INFO_DETAILED_SIZE: usize = json_first_string_field("username", MAX_U
     + json_string_array_field("addresses", MAX_ADDRESSES, MAX_IP_LENGTH)
     + json_bool_field("is_away")
     + json_string_field("status", MAX_STATUS_LENGTH)
+    + json_bool_field("is_dnd")
     + json_string_array_field("channels", MAX_CHANNELS_PER_USER, MAX_CHANNEL_LENGTH)
+    + json_object_field_start("stats")
+    + USER_STATS_SIZE
+    + json_close()
+    + json_string_field("rank", MAX_RANK_NAME_LENGTH)
+    + json_i64_field("last_active")
+    + json_string_field("client", MAX_CLIENT_NAME_LENGTH)
     + 2; // {} braces
 
 /// UserInfoResponse: {"type":"UserInfoResponse","success":false,"error":"...2048...","user":{...}}
@@ -784,27 +1573,135 @@ const USER_INFO_RESPONSE_SIZE: usize = json_type_base("UserInfoResponse")
     + USER_INFO_DETAILED_SIZE
     + json_close();
 
+/// NatInfo nested object size:
+/// {"method":"nat-pmp","external_ip":"...45...","mapped":false,"tcp_ports":[u16,...],"udp_ports":[u16],"checked_at":i64,"error":"...2048..."}
+const NAT_INFO_SIZE: usize = json_first_string_field("method", MAX_NAT_METHOD_LENGTH)
+    + json_string_field("external_ip", MAX_IP_LENGTH)
+    + json_bool_field("mapped")
+    + json_u16_array_field("tcp_ports", MAX_NAT_TCP_PORTS)
+    + json_u16_array_field("udp_ports", MAX_NAT_UDP_PORTS)
+    + json_i64_field("checked_at")
+    + json_string_field("error", MAX_ERROR_LENGTH)
+    + 2; // {} braces
+
+/// NatStatusResponse: {"type":"NatStatusResponse","success":false,"error":"...2048...","nat":{...}}
+const NAT_STATUS_RESPONSE_SIZE: usize = json_type_base("NatStatusResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH)
+    + json_object_field_start("nat")
+    + NAT_INFO_SIZE
+    + json_close();
+
+/// PortCheck nested object size:
+/// {"service":"transfer-websocket","port":u16,"state":"untested"}
+const PORT_CHECK_SIZE: usize = json_first_string_field("service", MAX_CHECKED_SERVICE_LENGTH)
+    + json_u16_field("port")
+    + json_enum_field("state", MAX_PORT_REACHABILITY_LENGTH)
+    + 2; // {} braces
+
+/// ConnectivityCheckResponse: {"type":"ConnectivityCheckResponse","success":false,"error":"...2048...","external_ip":"...45...","ports":[{...},...]}
+const CONNECTIVITY_CHECK_RESPONSE_SIZE: usize = json_type_base("ConnectivityCheckResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH)
+    + json_string_field("external_ip", MAX_IP_LENGTH)
+    + json_object_field_start("ports") // ,"ports":[ is the same size as ,"ports":{
+    + (MAX_CHECKED_PORTS * (PORT_CHECK_SIZE + 1)) // +1 for comma between elements
+    + json_close();
+
+/// DailyStats nested object size:
+/// {"date":"2026-01-17","logins":u64,"messages":u64,"uploads":u64,"upload_bytes":u64,"downloads":u64,"download_bytes":u64,"peak_sessions":u32}
+const DAILY_STATS_SIZE: usize = json_first_string_field("date", STATS_DATE_LENGTH)
+    + json_u64_field("logins")
+    + json_u64_field("messages")
+    + json_u64_field("uploads")
+    + json_u64_field("upload_bytes")
+    + json_u64_field("downloads")
+    + json_u64_field("download_bytes")
+    + json_u32_field("peak_sessions")
+    + 2; // {} braces
+
+/// StatsResponse: {"type":"StatsResponse","success":false,"error":"...2048...","days":[{...},...]}
+const STATS_RESPONSE_SIZE: usize = json_type_base("StatsResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH)
+    + json_object_field_start("days") // ,"days":[ is the same size as ,"days":{
+    + (MAX_STATS_DAYS * (DAILY_STATS_SIZE + 1)) // +1 for comma between elements
+    + json_close();
+
+/// LeaderboardEntry nested object size:
+/// {"username":"...32...","stats":{...},"rank":"...32..."}
+const LEADERBOARD_ENTRY_SIZE: usize = json_first_string_field("username", MAX_USERNAME_LENGTH)
+    + json_object_field_start("stats")
+    + USER_STATS_SIZE
+    + json_close()
+    + json_string_field("rank", MAX_RANK_NAME_LENGTH)
+    + 2; // {} braces
+
+/// LeaderboardResponse: {"type":"LeaderboardResponse","success":false,"error":"...2048...","entries":[{...},...]}
+const LEADERBOARD_RESPONSE_SIZE: usize = json_type_base("LeaderboardResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH)
+    + json_object_field_start("entries") // ,"entries":[ is the same size as ,"entries":{
+    + (MAX_LEADERBOARD_ENTRIES * (LEADERBOARD_ENTRY_SIZE + 1)) // +1 for comma between elements
+    + json_close();
+
+/// DoorOpenResponse: {"type":"DoorOpenResponse","success":false,"error":"...2048..."}
+const DOOR_OPEN_RESPONSE_SIZE: usize = json_type_base("DoorOpenResponse")
+    + json_bool_field("success")
+    + json_string_field("error", MAX_ERROR_LENGTH);
+
+/// DoorClosed: {"type":"DoorClosed","exit_code":u32}
+const DOOR_CLOSED_SIZE: usize = json_type_base("DoorClosed") + json_u32_field("exit_code");
+
+/// NewsImageLimits nested object size:
+/// {"max_size":u32,"max_dimension":u32}
+const NEWS_IMAGE_LIMITS_SIZE: usize =
+    json_first_u32_field("max_size") + json_u32_field("max_dimension") + 2; // {} braces
+
+/// ServerTime nested object size:
+/// {"timestamp":i64,"utc_offset":i32,"timezone":"...64..."}
+const SERVER_TIME_SIZE: usize = json_first_i64_field("timestamp")
+    + json_i64_field("utc_offset")
+    + json_string_field("timezone", MAX_TIMEZONE_LENGTH)
+    + 2; // {} braces
+
 /// ServerInfo struct size (nested object in responses):
-/// {"name":"...64...","description":"...256...","version":"...32...","max_connections_per_ip":u32,"max_transfers_per_ip":u32,"image":"...700000...","transfer_port":u16,"transfer_websocket_port":u16,"file_reindex_interval":u32,"persistent_channels":"...512...","auto_join_channels":"...512..."}
+/// {"name":"...64...","description":"...256...","version":"...32...","max_connections_per_ip":u32,"max_transfers_per_ip":u32,"max_active_transfers":u32,"transfer_small_files_first":false,"trusted_transfer_weight":u32,"image":"...700000...","transfer_port":u16,"transfer_websocket_port":u16,"file_reindex_interval":u32,"persistent_channels":"...512...","auto_join_channels":"...512...","nat":{...},"news_image_limits":{...},"max_message_length":u32,"time":{...}}
 const SERVER_INFO_STRUCT_SIZE: usize = json_first_string_field("name", MAX_SERVER_NAME_LENGTH)
     + json_string_field("description", MAX_SERVER_DESCRIPTION_LENGTH)
     + json_string_field("version", MAX_VERSION_LENGTH)
     + json_u32_field("max_connections_per_ip")
     + json_u32_field("max_transfers_per_ip")
+    + json_u32_field("max_active_transfers")
+    + json_bool_field("transfer_small_files_first")
+    + json_u32_field("trusted_transfer_weight")
     + json_string_field("image", MAX_SERVER_IMAGE_DATA_URI_LENGTH)
     + json_u16_field("transfer_port")
     + json_u16_field("transfer_websocket_port")
     + json_u32_field("file_reindex_interval")
     + json_string_field("persistent_channels", MAX_PERSISTENT_CHANNELS_LENGTH)
     + json_string_field("auto_join_channels", MAX_AUTO_JOIN_CHANNELS_LENGTH)
+    + json_object_field_start("nat")
+    + NAT_INFO_SIZE
+    + json_close()
+    + json_object_field_start("news_image_limits")
+    + NEWS_IMAGE_LIMITS_SIZE
+    + json_close()
+    + json_u32_field("max_message_length")
+    + json_object_field_start("time")
+    + SERVER_TIME_SIZE
+    + json_close()
     + 2; // {} braces
 
-/// ServerInfoUpdate: {"type":"ServerInfoUpdate","name":"...64...","description":"...256...","max_connections_per_ip":u32,"max_transfers_per_ip":u32,"image":"...700000...","file_reindex_interval":u32,"persistent_channels":"...512...","auto_join_channels":"...512..."}
+/// ServerInfoUpdate: {"type":"ServerInfoUpdate","name":"...64...","description":"...256...","max_connections_per_ip":u32,"max_transfers_per_ip":u32,"max_active_transfers":u32,"transfer_small_files_first":false,"trusted_transfer_weight":u32,"image":"...700000...","file_reindex_interval":u32,"persistent_channels":"...512...","auto_join_channels":"...512..."}
 const SERVER_INFO_UPDATE_SIZE: usize = json_type_base("ServerInfoUpdate")
     + json_string_field("name", MAX_SERVER_NAME_LENGTH)
     + json_string_field("description", MAX_SERVER_DESCRIPTION_LENGTH)
     + json_u32_field("max_connections_per_ip")
     + json_u32_field("max_transfers_per_ip")
+    + json_u32_field("max_active_transfers")
+    + json_bool_field("transfer_small_files_first")
+    + json_u32_field("trusted_transfer_weight")
     + json_string_field("image", MAX_SERVER_IMAGE_DATA_URI_LENGTH)
     + json_u32_field("file_reindex_interval")
     + json_string_field("persistent_channels", MAX_PERSISTENT_CHANNELS_LENGTH)
@@ -817,10 +1714,10 @@ const SERVER_INFO_UPDATED_SIZE: usize = json_type_base("ServerInfoUpdated")
     + json_close();
 
 /// NewsItem nested object size:
-/// {"id":i64,"body":"...4096...","image":"...700000...","author":"...32...","author_is_admin":false,"created_at":"...30...","updated_at":"...30..."}
+/// {"id":i64,"body":"...4096...","attachments":[{...},...],"author":"...32...","author_is_admin":false,"created_at":"...30...","updated_at":"...30..."}
 const NEWS_ITEM_SIZE: usize = json_first_i64_field("id")
     + json_string_field("body", MAX_NEWS_BODY_LENGTH)
-    + json_string_field("image", MAX_NEWS_IMAGE_DATA_URI_LENGTH)
+    + NEWS_ATTACHMENTS_FIELD_SIZE
     + json_string_field("author", MAX_NICKNAME_LENGTH)
     + json_bool_field("author_is_admin")
     + json_string_field("created_at", MAX_TIMESTAMP)
@@ -929,9 +1826,14 @@ const CHANNEL_JOIN_INFO_SIZE: usize = json_first_string_field("channel", MAX_CHA
     + json_bool_field("secret")
     + json_string_array_field("members", MAX_CHANNEL_MEMBERS, MAX_NICKNAME_LENGTH)
     + json_string_array_field("voiced", MAX_CHANNEL_MEMBERS, MAX_NICKNAME_LENGTH)
+    + json_u32_field("voice_limit")
+    + json_bool_field("topic_locked")
+    + json_string_field("entry_message", MAX_CHAT_ENTRY_MESSAGE_LENGTH)
+    + json_string_field("rules", MAX_CHAT_RULES_LENGTH)
+    + json_string_field("language", MAX_CHANNEL_LANGUAGE_LENGTH)
     + 2; // {} braces
 
-/// LoginResponse: {"type":"LoginResponse","success":false,"error":"...2048...","session_id":u32,"is_admin":false,"permissions":["...32...",...],"server_info":{...},"locale":"...10...","channels":[{...},...]}
+/// LoginResponse: {"type":"LoginResponse","success":false,"error":"...2048...","session_id":u32,"is_admin":false,"permissions":["...32...",...],"server_info":{...},"locale":"...10...","channels":[{...},...],"login_token":"...64..."}
 const LOGIN_RESPONSE_SIZE: usize = json_type_base("LoginResponse")
     + json_bool_field("success")
     + json_string_field("error", MAX_ERROR_LENGTH)
@@ -945,7 +1847,8 @@ const LOGIN_RESPONSE_SIZE: usize = json_type_base("LoginResponse")
     // channels array: up to MAX_AUTO_JOIN_CHANNELS_LENGTH channels with members
     + json_object_field_start("channels") - 1 // -1 because array uses [ not {
     + (MAX_AUTO_JOIN_CHANNELS_LENGTH * (CHANNEL_JOIN_INFO_SIZE + 1)) // +1 for comma between elements
-    + json_close();
+    + json_close()
+    + json_string_field("login_token", LOGIN_TOKEN_LENGTH);
 
 /// PermissionsUpdated: {"type":"PermissionsUpdated","is_admin":false,"permissions":["...32...",...],"server_info":{...}}
 const PERMISSIONS_UPDATED_SIZE: usize = json_type_base("PermissionsUpdated")
@@ -995,14 +1898,25 @@ static MESSAGE_TYPE_LIMITS: LazyLock<HashMap<&'static str, u64>> = LazyLock::new
     m.insert("ChatLeave", pad_limit(CHAT_LEAVE_SIZE as u64));
     m.insert("ChatList", pad_limit(CHAT_LIST_SIZE as u64));
     m.insert("ChatSecret", pad_limit(CHAT_SECRET_SIZE as u64));
+    m.insert("ChatTopicLock", pad_limit(CHAT_TOPIC_LOCK_SIZE as u64));
+    m.insert(
+        "ChatTopicHistory",
+        pad_limit(CHAT_TOPIC_HISTORY_SIZE as u64),
+    );
+    m.insert("ChatEntryUpdate", pad_limit(CHAT_ENTRY_UPDATE_SIZE as u64));
+    m.insert("ChatLanguage", pad_limit(CHAT_LANGUAGE_SIZE as u64));
 
     // Client messages - Basic (self-documenting via const calculations)
     m.insert("Handshake", pad_limit(HANDSHAKE_SIZE as u64));
     m.insert("Login", pad_limit(LOGIN_SIZE as u64));
     m.insert("UserBroadcast", pad_limit(USER_BROADCAST_SIZE as u64));
+    m.insert("BroadcastAck", pad_limit(BROADCAST_ACK_SIZE as u64));
     m.insert("UserCreate", pad_limit(USER_CREATE_SIZE as u64));
     m.insert("UserDelete", pad_limit(USER_DELETE_SIZE as u64));
+    m.insert("UserErase", pad_limit(USER_ERASE_SIZE as u64));
     m.insert("UserEdit", pad_limit(USER_EDIT_SIZE as u64));
+    m.insert("PermissionAudit", pad_limit(PERMISSION_AUDIT_SIZE as u64));
+    m.insert("ImpersonateQuery", pad_limit(IMPERSONATE_QUERY_SIZE as u64));
     m.insert("UserInfo", pad_limit(USER_INFO_SIZE as u64));
     m.insert("UserKick", pad_limit(USER_KICK_SIZE as u64));
     m.insert("UserList", pad_limit(USER_LIST_SIZE as u64));
@@ -1010,6 +1924,29 @@ static MESSAGE_TYPE_LIMITS: LazyLock<HashMap<&'static str, u64>> = LazyLock::new
     m.insert("UserAway", pad_limit(USER_AWAY_SIZE as u64));
     m.insert("UserBack", pad_limit(USER_BACK_SIZE as u64));
     m.insert("UserStatus", pad_limit(USER_STATUS_SIZE as u64));
+    m.insert("UserDnd", pad_limit(USER_DND_SIZE as u64));
+    m.insert("MessageHistory", pad_limit(MESSAGE_HISTORY_SIZE as u64));
+    m.insert(
+        "MessageHistoryRetention",
+        pad_limit(MESSAGE_HISTORY_RETENTION_SIZE as u64),
+    );
+    m.insert("MessageMarkRead", pad_limit(MESSAGE_MARK_READ_SIZE as u64));
+    m.insert(
+        "GroupMessageCreate",
+        pad_limit(GROUP_MESSAGE_CREATE_SIZE as u64),
+    );
+    m.insert(
+        "GroupMessageSend",
+        pad_limit(GROUP_MESSAGE_SEND_SIZE as u64),
+    );
+    m.insert(
+        "GroupMessageInvite",
+        pad_limit(GROUP_MESSAGE_INVITE_SIZE as u64),
+    );
+    m.insert(
+        "GroupMessageLeave",
+        pad_limit(GROUP_MESSAGE_LEAVE_SIZE as u64),
+    );
     m.insert(
         "ServerInfoUpdate",
         pad_limit(SERVER_INFO_UPDATE_SIZE as u64),
@@ -1031,6 +1968,27 @@ static MESSAGE_TYPE_LIMITS: LazyLock<HashMap<&'static str, u64>> = LazyLock::new
         pad_limit(CONNECTION_MONITOR_SIZE as u64),
     );
 
+    // Server log client messages
+    m.insert("LogSubscribe", pad_limit(LOG_SUBSCRIBE_SIZE as u64));
+    m.insert("LogUnsubscribe", pad_limit(LOG_UNSUBSCRIBE_SIZE as u64));
+
+    // Port forwarding client message
+    m.insert("NatStatus", pad_limit(NAT_STATUS_SIZE as u64));
+    m.insert(
+        "ConnectivityCheck",
+        pad_limit(CONNECTIVITY_CHECK_SIZE as u64),
+    );
+
+    // Statistics client messages
+    m.insert("ServerStats", pad_limit(SERVER_STATS_SIZE as u64));
+    m.insert("StatsRequest", pad_limit(STATS_REQUEST_SIZE as u64));
+    m.insert("Leaderboard", pad_limit(LEADERBOARD_SIZE as u64));
+
+    // Door client messages
+    m.insert("DoorList", pad_limit(DOOR_LIST_SIZE as u64));
+    m.insert("DoorOpen", pad_limit(DOOR_OPEN_SIZE as u64));
+    m.insert("DoorClose", pad_limit(DOOR_CLOSE_SIZE as u64));
+
     // News client messages (self-documenting via const calculations)
     m.insert("NewsList", pad_limit(NEWS_LIST_SIZE as u64));
     m.insert("NewsShow", pad_limit(NEWS_SHOW_SIZE as u64));
@@ -1038,31 +1996,75 @@ static MESSAGE_TYPE_LIMITS: LazyLock<HashMap<&'static str, u64>> = LazyLock::new
     m.insert("NewsEdit", pad_limit(NEWS_EDIT_SIZE as u64));
     m.insert("NewsUpdate", pad_limit(NEWS_UPDATE_SIZE as u64));
     m.insert("NewsDelete", pad_limit(NEWS_DELETE_SIZE as u64));
+    m.insert("NewsSearch", pad_limit(NEWS_SEARCH_SIZE as u64));
+    m.insert("SnippetCreate", pad_limit(SNIPPET_CREATE_SIZE as u64));
+    m.insert("SnippetGet", pad_limit(SNIPPET_GET_SIZE as u64));
 
     // File client messages (self-documenting via const calculations)
     m.insert("FileList", pad_limit(FILE_LIST_SIZE as u64));
     m.insert("FileCreateDir", pad_limit(FILE_CREATE_DIR_SIZE as u64));
     m.insert("FileDelete", pad_limit(FILE_DELETE_SIZE as u64));
     m.insert("FileInfo", pad_limit(FILE_INFO_SIZE as u64));
+    m.insert("FileManifest", pad_limit(FILE_MANIFEST_SIZE as u64));
+    m.insert("FileStat", pad_limit(FILE_STAT_SIZE as u64));
+    m.insert("FileWatch", pad_limit(FILE_WATCH_SIZE as u64));
     m.insert("FileRename", pad_limit(FILE_RENAME_SIZE as u64));
     m.insert("FileMove", pad_limit(FILE_MOVE_SIZE as u64));
     m.insert("FileCopy", pad_limit(FILE_COPY_SIZE as u64));
     m.insert("FileDownload", pad_limit(FILE_DOWNLOAD_SIZE as u64));
     m.insert("FileUpload", pad_limit(FILE_UPLOAD_SIZE as u64));
+    m.insert("ServerExport", pad_limit(SERVER_EXPORT_SIZE as u64));
     m.insert("FileSearch", pad_limit(FILE_SEARCH_SIZE as u64));
     m.insert("FileReindex", pad_limit(FILE_REINDEX_SIZE as u64));
+    m.insert("FileCleanup", pad_limit(FILE_CLEANUP_SIZE as u64));
+    m.insert("FileCheck", pad_limit(FILE_CHECK_SIZE as u64));
+    m.insert("FilePopular", pad_limit(FILE_POPULAR_SIZE as u64));
+    m.insert("FileRecent", pad_limit(FILE_RECENT_SIZE as u64));
+    m.insert("FileRate", pad_limit(FILE_RATE_SIZE as u64));
+    m.insert(
+        "FileCommentCreate",
+        pad_limit(FILE_COMMENT_CREATE_SIZE as u64),
+    );
+    m.insert("FileCommentList", pad_limit(FILE_COMMENT_LIST_SIZE as u64));
+    m.insert(
+        "FileCommentDelete",
+        pad_limit(FILE_COMMENT_DELETE_SIZE as u64),
+    );
+    m.insert("UploadRuleGet", pad_limit(UPLOAD_RULE_GET_SIZE as u64));
+    m.insert("UploadRuleSet", pad_limit(UPLOAD_RULE_SET_SIZE as u64));
+    m.insert("LdapGroupList", pad_limit(LDAP_GROUP_LIST_SIZE as u64));
+    m.insert("LdapGroupSet", pad_limit(LDAP_GROUP_SET_SIZE as u64));
+    m.insert("LdapGroupDelete", pad_limit(LDAP_GROUP_DELETE_SIZE as u64));
+    m.insert("GuestUpgrade", pad_limit(GUEST_UPGRADE_SIZE as u64));
+    m.insert("NicknameRegister", pad_limit(NICKNAME_REGISTER_SIZE as u64));
+    m.insert("NicknameRelease", pad_limit(NICKNAME_RELEASE_SIZE as u64));
+    m.insert(
+        "PresenceSubscribe",
+        pad_limit(PRESENCE_SUBSCRIBE_SIZE as u64),
+    );
 
     // Voice client messages (self-documenting via const calculations)
     m.insert("VoiceJoin", pad_limit(VOICE_JOIN_SIZE as u64));
     m.insert("VoiceLeave", pad_limit(VOICE_LEAVE_SIZE as u64));
+    m.insert("VoiceLimit", pad_limit(VOICE_LIMIT_SIZE as u64));
+    m.insert("VoiceClipList", pad_limit(VOICE_CLIP_LIST_SIZE as u64));
+    m.insert("VoiceClipUpload", pad_limit(VOICE_CLIP_UPLOAD_SIZE as u64));
+    m.insert("VoiceClipDelete", pad_limit(VOICE_CLIP_DELETE_SIZE as u64));
+    m.insert("VoiceClipPlay", pad_limit(VOICE_CLIP_PLAY_SIZE as u64));
+    m.insert("VoiceTextSend", pad_limit(VOICE_TEXT_SEND_SIZE as u64));
 
     // Keepalive messages
     m.insert("Ping", pad_limit(PING_SIZE as u64));
     m.insert("Pong", pad_limit(PONG_SIZE as u64));
+    m.insert("Cancel", pad_limit(CANCEL_SIZE as u64));
 
     // Server messages - Chat (self-documenting via const calculations)
     m.insert("ChatMessage", pad_limit(CHAT_MESSAGE_SIZE as u64));
     m.insert("ChatUpdated", pad_limit(CHAT_UPDATED_SIZE as u64));
+    m.insert(
+        "ChatSendResponse",
+        pad_limit(CHAT_SEND_RESPONSE_SIZE as u64),
+    );
     m.insert(
         "ChatTopicUpdateResponse",
         pad_limit(CHAT_TOPIC_UPDATE_RESPONSE_SIZE as u64),
@@ -1080,6 +2082,22 @@ static MESSAGE_TYPE_LIMITS: LazyLock<HashMap<&'static str, u64>> = LazyLock::new
         "ChatSecretResponse",
         pad_limit(CHAT_SECRET_RESPONSE_SIZE as u64),
     );
+    m.insert(
+        "ChatTopicLockResponse",
+        pad_limit(CHAT_TOPIC_LOCK_RESPONSE_SIZE as u64),
+    );
+    m.insert(
+        "ChatTopicHistoryResponse",
+        pad_limit(CHAT_TOPIC_HISTORY_RESPONSE_SIZE as u64),
+    );
+    m.insert(
+        "ChatEntryUpdateResponse",
+        pad_limit(CHAT_ENTRY_UPDATE_RESPONSE_SIZE as u64),
+    );
+    m.insert(
+        "ChatLanguageResponse",
+        pad_limit(CHAT_LANGUAGE_RESPONSE_SIZE as u64),
+    );
     m.insert("ChatUserJoined", pad_limit(CHAT_USER_JOINED_SIZE as u64));
     m.insert("ChatUserLeft", pad_limit(CHAT_USER_LEFT_SIZE as u64));
     m.insert("Error", pad_limit(ERROR_SIZE as u64));
@@ -1110,6 +2128,10 @@ static MESSAGE_TYPE_LIMITS: LazyLock<HashMap<&'static str, u64>> = LazyLock::new
         "UserDeleteResponse",
         pad_limit(USER_DELETE_RESPONSE_SIZE as u64),
     );
+    m.insert(
+        "UserEraseResponse",
+        pad_limit(USER_ERASE_RESPONSE_SIZE as u64),
+    );
     m.insert("UserDisconnected", pad_limit(USER_DISCONNECTED_SIZE as u64));
     m.insert(
         "UserEditResponse",
@@ -1119,6 +2141,7 @@ static MESSAGE_TYPE_LIMITS: LazyLock<HashMap<&'static str, u64>> = LazyLock::new
         "UserBroadcastResponse",
         pad_limit(USER_BROADCAST_RESPONSE_SIZE as u64),
     );
+    m.insert("BroadcastStatus", pad_limit(BROADCAST_STATUS_SIZE as u64));
     m.insert(
         "UserInfoResponse",
         pad_limit(USER_INFO_RESPONSE_SIZE as u64),
@@ -1128,6 +2151,8 @@ static MESSAGE_TYPE_LIMITS: LazyLock<HashMap<&'static str, u64>> = LazyLock::new
         pad_limit(USER_KICK_RESPONSE_SIZE as u64),
     );
     m.insert("UserListResponse", 0); // unlimited (server-trusted)
+    m.insert("PermissionAuditResponse", 0); // unlimited (server-trusted, lists accounts)
+    m.insert("ImpersonateQueryResponse", 0); // unlimited (server-trusted, lists files)
     m.insert("UserMessage", pad_limit(USER_MESSAGE_SIZE as u64));
     m.insert(
         "UserMessageResponse",
@@ -1146,6 +2171,32 @@ static MESSAGE_TYPE_LIMITS: LazyLock<HashMap<&'static str, u64>> = LazyLock::new
         "UserStatusResponse",
         pad_limit(USER_STATUS_RESPONSE_SIZE as u64),
     );
+    m.insert("UserDndResponse", pad_limit(USER_DND_RESPONSE_SIZE as u64));
+    m.insert("MessageHistoryResponse", 0); // unlimited (server-trusted, can have many messages)
+    m.insert(
+        "MessageHistoryRetentionResponse",
+        pad_limit(MESSAGE_HISTORY_RETENTION_RESPONSE_SIZE as u64),
+    );
+    m.insert("MessageDelivered", pad_limit(MESSAGE_DELIVERED_SIZE as u64));
+    m.insert("MessageRead", pad_limit(MESSAGE_READ_SIZE as u64));
+    m.insert(
+        "GroupMessageCreateResponse",
+        pad_limit(GROUP_MESSAGE_CREATE_RESPONSE_SIZE as u64),
+    );
+    m.insert(
+        "GroupMessageSendResponse",
+        pad_limit(GROUP_MESSAGE_SEND_RESPONSE_SIZE as u64),
+    );
+    m.insert(
+        "GroupMessageInviteResponse",
+        pad_limit(GROUP_MESSAGE_INVITE_RESPONSE_SIZE as u64),
+    );
+    m.insert(
+        "GroupMessageLeaveResponse",
+        pad_limit(GROUP_MESSAGE_LEAVE_RESPONSE_SIZE as u64),
+    );
+    m.insert("GroupMessage", pad_limit(GROUP_MESSAGE_SIZE as u64));
+    m.insert("GroupMembers", pad_limit(GROUP_MEMBERS_SIZE as u64));
     m.insert(
         "UserUpdateResponse",
         pad_limit(USER_UPDATE_RESPONSE_SIZE as u64),
@@ -1176,6 +2227,37 @@ static MESSAGE_TYPE_LIMITS: LazyLock<HashMap<&'static str, u64>> = LazyLock::new
     // Connection monitor server message
     m.insert("ConnectionMonitorResponse", 0); // unlimited (server-trusted, can have many connections)
 
+    // Server log server messages
+    m.insert("LogSubscribeResponse", 0); // unlimited (server-trusted, recent log history)
+    m.insert("LogLine", 0); // unlimited (server-trusted, log messages can quote paths and input)
+
+    // Port forwarding server message
+    m.insert(
+        "NatStatusResponse",
+        pad_limit(NAT_STATUS_RESPONSE_SIZE as u64),
+    );
+    m.insert(
+        "ConnectivityCheckResponse",
+        pad_limit(CONNECTIVITY_CHECK_RESPONSE_SIZE as u64),
+    );
+
+    // Statistics server messages
+    m.insert("ServerStatsResponse", 0); // unlimited (server-trusted, audit details are free text)
+    m.insert("StorageAlert", pad_limit(STORAGE_ALERT_SIZE as u64));
+    m.insert("StatsResponse", pad_limit(STATS_RESPONSE_SIZE as u64));
+    m.insert(
+        "LeaderboardResponse",
+        pad_limit(LEADERBOARD_RESPONSE_SIZE as u64),
+    );
+
+    // Door server messages
+    m.insert("DoorListResponse", 0); // unlimited (server-trusted, operator-configured list)
+    m.insert(
+        "DoorOpenResponse",
+        pad_limit(DOOR_OPEN_RESPONSE_SIZE as u64),
+    );
+    m.insert("DoorClosed", pad_limit(DOOR_CLOSED_SIZE as u64));
+
     // News server messages (self-documenting via const calculations)
     m.insert("NewsListResponse", 0); // unlimited (server-trusted, can have many items)
     m.insert(
@@ -1199,6 +2281,15 @@ static MESSAGE_TYPE_LIMITS: LazyLock<HashMap<&'static str, u64>> = LazyLock::new
         pad_limit(NEWS_DELETE_RESPONSE_SIZE as u64),
     );
     m.insert("NewsUpdated", pad_limit(NEWS_UPDATED_SIZE as u64));
+    m.insert("NewsSearchResponse", 0); // unlimited (server-trusted, can have many items)
+    m.insert(
+        "SnippetCreateResponse",
+        pad_limit(SNIPPET_CREATE_RESPONSE_SIZE as u64),
+    );
+    m.insert(
+        "SnippetGetResponse",
+        pad_limit(SNIPPET_GET_RESPONSE_SIZE as u64),
+    );
 
     // File server messages
     m.insert("FileListResponse", 0); // unlimited (server-trusted, can have many entries)
@@ -1214,6 +2305,13 @@ static MESSAGE_TYPE_LIMITS: LazyLock<HashMap<&'static str, u64>> = LazyLock::new
         "FileInfoResponse",
         pad_limit(FILE_INFO_RESPONSE_SIZE as u64),
     );
+    m.insert("FileManifestResponse", 0); // unlimited (server-trusted, can have many files)
+    m.insert("FileStatResponse", 0); // unlimited (server-trusted, can have many files)
+    m.insert(
+        "FileWatchResponse",
+        pad_limit(FILE_WATCH_RESPONSE_SIZE as u64),
+    );
+    m.insert("FileChanged", 0); // unlimited (server-trusted, names come from disk)
     m.insert(
         "FileRenameResponse",
         pad_limit(FILE_RENAME_RESPONSE_SIZE as u64),
@@ -1239,6 +2337,61 @@ static MESSAGE_TYPE_LIMITS: LazyLock<HashMap<&'static str, u64>> = LazyLock::new
         "FileReindexResponse",
         pad_limit(FILE_REINDEX_RESPONSE_SIZE as u64),
     );
+    m.insert("FileCleanupResponse", 0); // unlimited (server-trusted)
+    m.insert("FileCheckIssue", 0); // unlimited (server-trusted, paths come from disk)
+    m.insert(
+        "FileCheckResponse",
+        pad_limit(FILE_CHECK_RESPONSE_SIZE as u64),
+    );
+    m.insert("FilePopularResponse", 0); // unlimited (server-trusted)
+    m.insert("FileRecentResponse", 0); // unlimited (server-trusted)
+    m.insert(
+        "FileRateResponse",
+        pad_limit(FILE_RATE_RESPONSE_SIZE as u64),
+    );
+    m.insert(
+        "FileCommentCreateResponse",
+        pad_limit(FILE_COMMENT_CREATE_RESPONSE_SIZE as u64),
+    );
+    m.insert("FileCommentListResponse", 0); // unlimited (server-trusted, can have many comments)
+    m.insert(
+        "FileCommentDeleteResponse",
+        pad_limit(FILE_COMMENT_DELETE_RESPONSE_SIZE as u64),
+    );
+    m.insert(
+        "UploadRuleGetResponse",
+        pad_limit(UPLOAD_RULE_GET_RESPONSE_SIZE as u64),
+    );
+    m.insert(
+        "UploadRuleSetResponse",
+        pad_limit(UPLOAD_RULE_SET_RESPONSE_SIZE as u64),
+    );
+    m.insert("LdapGroupListResponse", 0); // unlimited (server-trusted, can have many groups)
+    m.insert(
+        "LdapGroupSetResponse",
+        pad_limit(LDAP_GROUP_SET_RESPONSE_SIZE as u64),
+    );
+    m.insert(
+        "LdapGroupDeleteResponse",
+        pad_limit(LDAP_GROUP_DELETE_RESPONSE_SIZE as u64),
+    );
+    m.insert(
+        "GuestUpgradeResponse",
+        pad_limit(GUEST_UPGRADE_RESPONSE_SIZE as u64),
+    );
+    m.insert(
+        "NicknameRegisterResponse",
+        pad_limit(NICKNAME_REGISTER_RESPONSE_SIZE as u64),
+    );
+    m.insert(
+        "NicknameReleaseResponse",
+        pad_limit(NICKNAME_RELEASE_RESPONSE_SIZE as u64),
+    );
+    m.insert(
+        "PresenceSubscribeResponse",
+        pad_limit(PRESENCE_SUBSCRIBE_RESPONSE_SIZE as u64),
+    );
+    m.insert("PresenceUpdate", pad_limit(PRESENCE_UPDATE_SIZE as u64));
 
     // Voice server messages (self-documenting via const calculations)
     m.insert(
@@ -1249,8 +2402,46 @@ static MESSAGE_TYPE_LIMITS: LazyLock<HashMap<&'static str, u64>> = LazyLock::new
         "VoiceLeaveResponse",
         pad_limit(VOICE_LEAVE_RESPONSE_SIZE as u64),
     );
+    m.insert(
+        "VoiceLimitResponse",
+        pad_limit(VOICE_LIMIT_RESPONSE_SIZE as u64),
+    );
+    m.insert(
+        "VoiceQueueUpdate",
+        pad_limit(VOICE_QUEUE_UPDATE_SIZE as u64),
+    );
     m.insert("VoiceUserJoined", pad_limit(VOICE_USER_JOINED_SIZE as u64));
     m.insert("VoiceUserLeft", pad_limit(VOICE_USER_LEFT_SIZE as u64));
+    m.insert(
+        "VoiceBroadcastUpdate",
+        pad_limit(VOICE_BROADCAST_UPDATE_SIZE as u64),
+    );
+    m.insert("VoiceStats", pad_limit(VOICE_STATS_SIZE as u64));
+    m.insert("VoiceClipListResponse", 0); // unlimited (server-trusted, can have many clips)
+    m.insert(
+        "VoiceClipUploadResponse",
+        pad_limit(VOICE_CLIP_UPLOAD_RESPONSE_SIZE as u64),
+    );
+    m.insert(
+        "VoiceClipDeleteResponse",
+        pad_limit(VOICE_CLIP_DELETE_RESPONSE_SIZE as u64),
+    );
+    m.insert(
+        "VoiceClipPlayResponse",
+        pad_limit(VOICE_CLIP_PLAY_RESPONSE_SIZE as u64),
+    );
+    m.insert("VoiceClipPlayed", pad_limit(VOICE_CLIP_PLAYED_SIZE as u64));
+    m.insert(
+        "VoiceTextSendResponse",
+        pad_limit(VOICE_TEXT_SEND_RESPONSE_SIZE as u64),
+    );
+    m.insert("VoiceText", pad_limit(VOICE_TEXT_SIZE as u64));
+
+    // Voice relay (shared type name - same limit for both directions)
+    m.insert("VoiceRelay", pad_limit(VOICE_RELAY_SIZE as u64));
+
+    // Door terminal data (shared type name - same limit for both directions)
+    m.insert("DoorData", pad_limit(DOOR_DATA_SIZE as u64));
 
     // Transfer messages (self-documenting via const calculations)
     m.insert("FileStart", pad_limit(FILE_START_SIZE as u64));
@@ -1261,6 +2452,7 @@ static MESSAGE_TYPE_LIMITS: LazyLock<HashMap<&'static str, u64>> = LazyLock::new
     m.insert("FileData", 0); // unlimited - streaming binary data
     m.insert("TransferComplete", pad_limit(TRANSFER_COMPLETE_SIZE as u64));
     m.insert("FileHashing", pad_limit(FILE_HASHING_SIZE as u64));
+    m.insert("TransferQueued", pad_limit(TRANSFER_QUEUED_SIZE as u64));
 
     m
 });
@@ -1295,8 +2487,12 @@ pub fn known_message_types() -> Vec<&'static str> {
 mod tests {
     use super::*;
     use crate::protocol::{
-        ChannelJoinInfo, ChatAction, ClientMessage, ServerInfo, ServerMessage, UserInfo,
-        UserInfoDetailed,
+        BroadcastTarget, ChannelJoinInfo, ChatAction, ChatTopicChange, CheckedService,
+        ClientMessage, DailyStats, FileCheckSummary, FileComment, FileRating, FileStatQuery,
+        FileWatchPath, LeaderboardEntry, LeaderboardStat, NatInfo, NatMethod, NewsAttachment,
+        NewsImageLimits, NewsItem, OidcLogin, OidcProvider, PortCheck, PortReachability,
+        PresenceInfo, ServerInfo, ServerMessage, ServerTime, SnippetInfo, StorageVolume,
+        UploadRule, UserInfo, UserInfoDetailed, UserStats,
     };
     use crate::validators::{
         MAX_AVATAR_DATA_URI_LENGTH, MAX_BAN_REASON_LENGTH, MAX_CHANNEL_LENGTH,
@@ -1304,9 +2500,10 @@ mod tests {
         MAX_FILE_PATH_LENGTH, MAX_LOCALE_LENGTH, MAX_MESSAGE_LENGTH, MAX_NICKNAME_LENGTH,
         MAX_PASSWORD_LENGTH, MAX_PERMISSION_LENGTH, MAX_PERSISTENT_CHANNELS_LENGTH,
         MAX_SEARCH_QUERY_LENGTH, MAX_SERVER_DESCRIPTION_LENGTH, MAX_SERVER_IMAGE_DATA_URI_LENGTH,
-        MAX_SERVER_NAME_LENGTH, MAX_STATUS_LENGTH, MAX_TRUST_REASON_LENGTH, MAX_USERNAME_LENGTH,
-        MAX_VERSION_LENGTH,
+        MAX_SERVER_NAME_LENGTH, MAX_STATUS_LENGTH, MAX_TIMEZONE_LENGTH, MAX_TRUST_REASON_LENGTH,
+        MAX_USERNAME_LENGTH, MAX_VERSION_LENGTH,
     };
+    use uuid::Uuid;
 
     /// Helper to get serialized JSON size of a message
     fn json_size<T: serde::Serialize>(msg: &T) -> usize {
@@ -1318,6 +2515,28 @@ mod tests {
         "x".repeat(len)
     }
 
+    /// Helper to create a NatInfo with every field at its maximum size
+    fn max_nat_info() -> NatInfo {
+        NatInfo {
+            method: Some(NatMethod::NatPmp),
+            external_ip: Some(str_of_len(MAX_IP_LENGTH)),
+            mapped: false,
+            tcp_ports: vec![u16::MAX; MAX_NAT_TCP_PORTS],
+            udp_ports: vec![u16::MAX; MAX_NAT_UDP_PORTS],
+            checked_at: i64::MIN,
+            error: Some(str_of_len(MAX_ERROR_LENGTH)),
+        }
+    }
+
+    /// Helper to create a ServerTime with every field at its maximum size
+    fn max_server_time() -> ServerTime {
+        ServerTime {
+            timestamp: i64::MIN,
+            utc_offset: i32::MIN,
+            timezone: Some(str_of_len(MAX_TIMEZONE_LENGTH)),
+        }
+    }
+
     // =========================================================================
     // JSON Helper Function Tests
     // =========================================================================
@@ -1582,6 +2801,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_json_u16_array_field() {
+        #[derive(serde::Serialize)]
+        struct TestMsg {
+            #[serde(rename = "type")]
+            type_name: &'static str,
+            ports: Vec<u16>,
+        }
+
+        let base = json_type_base("Test");
+        for count in [0, 1, 4] {
+            let msg = TestMsg {
+                type_name: "Test",
+                ports: vec![u16::MAX; count],
+            };
+            let json = serde_json::to_string(&msg).unwrap();
+            assert_eq!(
+                json_u16_array_field("ports", count),
+                json.len() - base,
+                "{count} elements: json={json}"
+            );
+        }
+    }
+
     #[test]
     fn test_json_object_field() {
         #[derive(serde::Serialize)]
@@ -1732,9 +2975,9 @@ mod tests {
         //
         // Note: Some type names are shared between client and server enums
         // (UserMessage, FileStart, FileStartResponse, FileData, FileHashing), so they're only counted once in the HashMap.
-        const CLIENT_MESSAGE_COUNT: usize = 52; // Added 6 News + 7 File + 6 Transfer + 3 Away/Status + 3 Ban + 3 Trust + 2 FileSearch + 4 Chat channel + 1 ConnectionMonitor + 2 Voice client messages + 1 Ping
-        const SERVER_MESSAGE_COUNT: usize = 67; // Added 7 News + 8 File + 7 Transfer + 3 Away/Status + 3 Ban + 3 Trust + 2 FileSearch + 6 Chat channel + 1 ConnectionMonitor + 4 Voice server messages + 1 Pong
-        const SHARED_MESSAGE_COUNT: usize = 5; // UserMessage, FileStart, FileStartResponse, FileData, FileHashing
+        const CLIENT_MESSAGE_COUNT: usize = 111; // Added 7 News + 8 File + 7 Transfer + 4 Away/Status/Dnd + 3 Ban + 3 Trust + 2 FileSearch + 4 Chat channel + 1 ConnectionMonitor + 9 Voice client messages + 1 Ping + 4 Door + 4 FileComment + 2 UploadRule + 1 FileStat + 3 LdapGroup + 1 GuestUpgrade + 2 Nickname + 1 Presence + 1 BroadcastAck + 1 ServerStats + 1 FileCleanup + 1 FileCheck + 1 FileWatch + 1 Cancel + 2 ChatTopic + 1 ChatEntry + 2 MessageHistory + 1 MessageMarkRead + 4 GroupMessage + 2 Snippet + 1 ChatLanguage
+        const SERVER_MESSAGE_COUNT: usize = 138; // Added 8 News + 9 File + 8 Transfer + 4 Away/Status/Dnd + 3 Ban + 3 Trust + 2 FileSearch + 6 Chat channel + 1 ConnectionMonitor + 16 Voice server messages + 1 Pong + 4 Door + 4 FileComment + 2 UploadRule + 1 FileStat + 3 LdapGroup + 1 GuestUpgrade + 2 Nickname + 2 Presence + 1 BroadcastStatus + 1 ServerStats + 1 StorageAlert + 1 FileCleanup + 2 FileCheck + 2 FileWatch + 2 ChatTopic + 1 ChatEntry + 2 MessageHistory + 2 Message receipts + 6 GroupMessage + 2 Snippet + 1 ChatSendResponse + 1 ChatLanguage
+        const SHARED_MESSAGE_COUNT: usize = 7; // UserMessage, FileStart, FileStartResponse, FileData, FileHashing, VoiceRelay, DoorData
         const TOTAL_MESSAGE_COUNT: usize =
             CLIENT_MESSAGE_COUNT + SERVER_MESSAGE_COUNT - SHARED_MESSAGE_COUNT;
 
@@ -1765,6 +3008,8 @@ mod tests {
             "FileStart",   // True mirror - identical fields
             "FileStartResponse", // True mirror - identical fields
             "FileData",    // True mirror - no fields (raw bytes)
+            "VoiceRelay",  // True mirror - identical fields (base64 voice packet)
+            "DoorData",    // True mirror - identical fields (base64 terminal bytes)
         ];
 
         for type_name in &shared_type_names {
@@ -1778,7 +3023,7 @@ mod tests {
         // Verify count matches SHARED_MESSAGE_COUNT constant
         assert_eq!(
             shared_type_names.len(),
-            4,
+            6,
             "Update SHARED_MESSAGE_COUNT if shared type names change"
         );
     }
@@ -1793,6 +3038,7 @@ mod tests {
             message: str_of_len(MAX_MESSAGE_LENGTH),
             action: ChatAction::Normal,
             channel: str_of_len(MAX_CHANNEL_LENGTH),
+            idempotency_key: Some(str_of_len(MAX_IDEMPOTENCY_KEY_LENGTH)),
         };
         assert!(
             json_size(&msg) <= max_payload_for_type("ChatSend") as usize,
@@ -1848,7 +3094,9 @@ mod tests {
 
     #[test]
     fn test_limit_chat_list() {
-        let msg = ClientMessage::ChatList {};
+        let msg = ClientMessage::ChatList {
+            language: Some(str_of_len(MAX_CHANNEL_LANGUAGE_LENGTH)),
+        };
         assert!(
             json_size(&msg) <= max_payload_for_type("ChatList") as usize,
             "{} size {} exceeds limit {}",
@@ -1874,35 +3122,114 @@ mod tests {
     }
 
     #[test]
-    fn test_limit_handshake() {
-        let msg = ClientMessage::Handshake {
-            version: str_of_len(MAX_VERSION_LENGTH),
+    fn test_limit_chat_topic_lock() {
+        let msg = ClientMessage::ChatTopicLock {
+            channel: str_of_len(MAX_CHANNEL_LENGTH),
+            locked: false,
         };
         assert!(
-            json_size(&msg) <= max_payload_for_type("Handshake") as usize,
+            json_size(&msg) <= max_payload_for_type("ChatTopicLock") as usize,
             "{} size {} exceeds limit {}",
-            "Handshake",
+            "ChatTopicLock",
             json_size(&msg),
-            max_payload_for_type("Handshake")
+            max_payload_for_type("ChatTopicLock")
         );
     }
 
     #[test]
-    fn test_limit_login() {
-        let msg = ClientMessage::Login {
-            username: str_of_len(MAX_USERNAME_LENGTH),
-            password: str_of_len(MAX_PASSWORD_LENGTH),
-            features: (0..MAX_FEATURES_COUNT)
-                .map(|_| str_of_len(MAX_FEATURE_LENGTH))
-                .collect(),
-            locale: str_of_len(MAX_LOCALE_LENGTH),
-            avatar: Some(str_of_len(MAX_AVATAR_DATA_URI_LENGTH)),
-            nickname: Some(str_of_len(MAX_NICKNAME_LENGTH)),
+    fn test_limit_chat_entry_update() {
+        let msg = ClientMessage::ChatEntryUpdate {
+            channel: str_of_len(MAX_CHANNEL_LENGTH),
+            entry_message: Some(str_of_len(MAX_CHAT_ENTRY_MESSAGE_LENGTH)),
+            rules: Some(str_of_len(MAX_CHAT_RULES_LENGTH)),
         };
         assert!(
-            json_size(&msg) <= max_payload_for_type("Login") as usize,
+            json_size(&msg) <= max_payload_for_type("ChatEntryUpdate") as usize,
             "{} size {} exceeds limit {}",
-            "Login",
+            "ChatEntryUpdate",
+            json_size(&msg),
+            max_payload_for_type("ChatEntryUpdate")
+        );
+    }
+
+    #[test]
+    fn test_limit_chat_language() {
+        let msg = ClientMessage::ChatLanguage {
+            channel: str_of_len(MAX_CHANNEL_LENGTH),
+            language: str_of_len(MAX_CHANNEL_LANGUAGE_LENGTH),
+        };
+        assert!(
+            json_size(&msg) <= max_payload_for_type("ChatLanguage") as usize,
+            "{} size {} exceeds limit {}",
+            "ChatLanguage",
+            json_size(&msg),
+            max_payload_for_type("ChatLanguage")
+        );
+    }
+
+    #[test]
+    fn test_limit_chat_topic_history_response() {
+        let change = ChatTopicChange {
+            topic: Some(str_of_len(MAX_CHAT_TOPIC_LENGTH)),
+            old_topic: Some(str_of_len(MAX_CHAT_TOPIC_LENGTH)),
+            set_by: Some(str_of_len(MAX_NICKNAME_LENGTH)),
+            changed_at: u64::MAX,
+        };
+        let msg = ServerMessage::ChatTopicHistoryResponse {
+            success: false,
+            error: Some(str_of_len(MAX_ERROR_LENGTH)),
+            channel: Some(str_of_len(MAX_CHANNEL_LENGTH)),
+            changes: Some(vec![change; MAX_CHAT_TOPIC_HISTORY]),
+        };
+        let size = json_size(&msg);
+        let limit = max_payload_for_type("ChatTopicHistoryResponse") as usize;
+        assert!(
+            size <= limit,
+            "ChatTopicHistoryResponse size {} exceeds limit {}",
+            size,
+            limit
+        );
+    }
+
+    #[test]
+    fn test_limit_handshake() {
+        let msg = ClientMessage::Handshake {
+            version: str_of_len(MAX_VERSION_LENGTH),
+            client: Some(str_of_len(MAX_CLIENT_NAME_LENGTH)),
+        };
+        assert!(
+            json_size(&msg) <= max_payload_for_type("Handshake") as usize,
+            "{} size {} exceeds limit {}",
+            "Handshake",
+            json_size(&msg),
+            max_payload_for_type("Handshake")
+        );
+    }
+
+    #[test]
+    fn test_limit_login() {
+        let msg = ClientMessage::Login {
+            username: str_of_len(MAX_USERNAME_LENGTH),
+            password: str_of_len(MAX_PASSWORD_LENGTH),
+            features: (0..MAX_FEATURES_COUNT)
+                .map(|_| str_of_len(MAX_FEATURE_LENGTH))
+                .collect(),
+            locale: str_of_len(MAX_LOCALE_LENGTH),
+            avatar: Some(str_of_len(MAX_AVATAR_DATA_URI_LENGTH)),
+            nickname: Some(str_of_len(MAX_NICKNAME_LENGTH)),
+            pow_nonce: Some(str_of_len(MAX_POW_NONCE_LENGTH)),
+            oidc: Some(OidcLogin {
+                code: str_of_len(MAX_OIDC_CODE_LENGTH),
+                code_verifier: str_of_len(MAX_CODE_VERIFIER_LENGTH),
+                redirect_uri: str_of_len(MAX_OIDC_REDIRECT_URI_LENGTH),
+                nonce: str_of_len(MAX_OIDC_NONCE_LENGTH),
+            }),
+            login_token: Some(str_of_len(LOGIN_TOKEN_LENGTH)),
+        };
+        assert!(
+            json_size(&msg) <= max_payload_for_type("Login") as usize,
+            "{} size {} exceeds limit {}",
+            "Login",
             json_size(&msg),
             max_payload_for_type("Login")
         );
@@ -1912,6 +3239,11 @@ mod tests {
     fn test_limit_user_broadcast() {
         let msg = ClientMessage::UserBroadcast {
             message: str_of_len(MAX_MESSAGE_LENGTH),
+            target: BroadcastTarget::Permission {
+                permission: str_of_len(MAX_PERMISSION_LENGTH),
+            },
+            delay: Some(str_of_len(MAX_DURATION_LENGTH)),
+            require_ack: true,
         };
         assert!(
             json_size(&msg) <= max_payload_for_type("UserBroadcast") as usize,
@@ -1957,6 +3289,104 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_limit_user_erase() {
+        let msg = ClientMessage::UserErase {
+            username: str_of_len(MAX_USERNAME_LENGTH),
+        };
+        assert!(
+            json_size(&msg) <= max_payload_for_type("UserErase") as usize,
+            "{} size {} exceeds limit {}",
+            "UserErase",
+            json_size(&msg),
+            max_payload_for_type("UserErase")
+        );
+    }
+
+    #[test]
+    fn test_limit_permission_audit() {
+        let msg = ClientMessage::PermissionAudit {
+            permission: Some(str_of_len(MAX_PERMISSION_LENGTH)),
+            username: Some(str_of_len(MAX_USERNAME_LENGTH)),
+        };
+        assert!(
+            json_size(&msg) <= max_payload_for_type("PermissionAudit") as usize,
+            "{} size {} exceeds limit {}",
+            "PermissionAudit",
+            json_size(&msg),
+            max_payload_for_type("PermissionAudit")
+        );
+    }
+
+    #[test]
+    fn test_limit_impersonate_query() {
+        let msg = ClientMessage::ImpersonateQuery {
+            username: str_of_len(MAX_USERNAME_LENGTH),
+            path: Some(str_of_len(MAX_FILE_PATH_LENGTH)),
+        };
+        assert!(
+            json_size(&msg) <= max_payload_for_type("ImpersonateQuery") as usize,
+            "{} size {} exceeds limit {}",
+            "ImpersonateQuery",
+            json_size(&msg),
+            max_payload_for_type("ImpersonateQuery")
+        );
+    }
+
+    #[test]
+    fn test_limit_nat_status() {
+        let msg = ClientMessage::NatStatus;
+        assert!(
+            json_size(&msg) <= max_payload_for_type("NatStatus") as usize,
+            "{} size {} exceeds limit {}",
+            "NatStatus",
+            json_size(&msg),
+            max_payload_for_type("NatStatus")
+        );
+    }
+
+    #[test]
+    fn test_limit_server_stats() {
+        let msg = ClientMessage::ServerStats;
+        assert!(
+            json_size(&msg) <= max_payload_for_type("ServerStats") as usize,
+            "{} size {} exceeds limit {}",
+            "ServerStats",
+            json_size(&msg),
+            max_payload_for_type("ServerStats")
+        );
+        // Audit entries can be long, so the response is unlimited
+        assert_eq!(max_payload_for_type("ServerStatsResponse"), 0);
+    }
+
+    #[test]
+    fn test_limit_connectivity_check() {
+        let msg = ClientMessage::ConnectivityCheck;
+        assert!(
+            json_size(&msg) <= max_payload_for_type("ConnectivityCheck") as usize,
+            "{} size {} exceeds limit {}",
+            "ConnectivityCheck",
+            json_size(&msg),
+            max_payload_for_type("ConnectivityCheck")
+        );
+    }
+
+    #[test]
+    fn test_limit_log_subscribe() {
+        for (msg, name) in [
+            (ClientMessage::LogSubscribe, "LogSubscribe"),
+            (ClientMessage::LogUnsubscribe, "LogUnsubscribe"),
+        ] {
+            assert!(
+                json_size(&msg) <= max_payload_for_type(name) as usize,
+                "{} size {} exceeds limit {}",
+                name,
+                json_size(&msg),
+                max_payload_for_type(name)
+            );
+        }
+    }
+
     #[test]
     fn test_limit_user_edit() {
         let msg = ClientMessage::UserEdit {
@@ -2019,6 +3449,7 @@ mod tests {
             to_nickname: str_of_len(MAX_NICKNAME_LENGTH),
             message: str_of_len(MAX_MESSAGE_LENGTH),
             action: ChatAction::Normal,
+            idempotency_key: Some(str_of_len(MAX_IDEMPOTENCY_KEY_LENGTH)),
         };
         // Client variant is smaller than server variant, so it fits within the limit
         assert!(json_size(&msg) <= max_payload_for_type("UserMessage") as usize);
@@ -2088,6 +3519,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_limit_user_dnd() {
+        let msg = ClientMessage::UserDnd { enabled: false };
+        assert!(
+            json_size(&msg) <= max_payload_for_type("UserDnd") as usize,
+            "{} size {} exceeds limit {}",
+            "UserDnd",
+            json_size(&msg),
+            max_payload_for_type("UserDnd")
+        );
+    }
+
     #[test]
     fn test_limit_ban_create() {
         // Max size: target (32 nickname) + duration (10) + reason (2048) + overhead
@@ -2141,6 +3584,9 @@ mod tests {
             description: Some(str_of_len(MAX_SERVER_DESCRIPTION_LENGTH)),
             max_connections_per_ip: Some(u32::MAX),
             max_transfers_per_ip: Some(u32::MAX),
+            max_active_transfers: Some(u32::MAX),
+            transfer_small_files_first: Some(false),
+            trusted_transfer_weight: Some(u32::MAX),
             image: Some(str_of_len(MAX_SERVER_IMAGE_DATA_URI_LENGTH)),
             file_reindex_interval: Some(u32::MAX),
             persistent_channels: Some(str_of_len(MAX_PERSISTENT_CHANNELS_LENGTH)),
@@ -2196,6 +3642,186 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_limit_voice_limit() {
+        let msg = ClientMessage::VoiceLimit {
+            channel: str_of_len(MAX_CHANNEL_LENGTH),
+            limit: u32::MAX,
+        };
+        let size = json_size(&msg);
+        let limit = max_payload_for_type("VoiceLimit") as usize;
+        assert!(
+            size <= limit,
+            "VoiceLimit size {} exceeds limit {}",
+            size,
+            limit
+        );
+    }
+
+    #[test]
+    fn test_limit_voice_queue_update() {
+        let msg = ServerMessage::VoiceQueueUpdate {
+            target: str_of_len(MAX_CHANNEL_LENGTH),
+            position: Some(u32::MAX),
+        };
+        let size = json_size(&msg);
+        let limit = max_payload_for_type("VoiceQueueUpdate") as usize;
+        assert!(
+            size <= limit,
+            "VoiceQueueUpdate size {} exceeds limit {}",
+            size,
+            limit
+        );
+    }
+
+    #[test]
+    fn test_limit_voice_broadcast_update() {
+        let msg = ServerMessage::VoiceBroadcastUpdate {
+            target: str_of_len(MAX_CHANNEL_LENGTH),
+            broadcaster: Some(str_of_len(MAX_NICKNAME_LENGTH)),
+            listeners: u32::MAX,
+        };
+        let size = json_size(&msg);
+        let limit = max_payload_for_type("VoiceBroadcastUpdate") as usize;
+        assert!(
+            size <= limit,
+            "VoiceBroadcastUpdate size {} exceeds limit {}",
+            size,
+            limit
+        );
+    }
+
+    #[test]
+    fn test_limit_voice_stats() {
+        let msg = ServerMessage::VoiceStats {
+            nickname: str_of_len(MAX_NICKNAME_LENGTH),
+            loss_percent: u8::MAX,
+            jitter_ms: u32::MAX,
+        };
+        let size = json_size(&msg);
+        let limit = max_payload_for_type("VoiceStats") as usize;
+        assert!(
+            size <= limit,
+            "VoiceStats size {} exceeds limit {}",
+            size,
+            limit
+        );
+    }
+
+    #[test]
+    fn test_limit_voice_join_response() {
+        let msg = ServerMessage::VoiceJoinResponse {
+            success: false,
+            token: Some(Uuid::nil()),
+            target: Some(str_of_len(MAX_CHANNEL_LENGTH)),
+            participants: Some(vec![
+                str_of_len(MAX_NICKNAME_LENGTH);
+                MAX_VOICE_PARTICIPANTS
+            ]),
+            voice_address: Some(str_of_len(MAX_VOICE_ADDRESS_LENGTH)),
+            voice_port: Some(u16::MAX),
+            tcp_relay: Some(false),
+            listener: Some(false),
+            listeners: Some(u32::MAX),
+            error: Some(str_of_len(MAX_ERROR_LENGTH)),
+        };
+        let size = json_size(&msg);
+        let limit = max_payload_for_type("VoiceJoinResponse") as usize;
+        assert!(
+            size <= limit,
+            "VoiceJoinResponse size {} exceeds limit {}",
+            size,
+            limit
+        );
+    }
+
+    #[test]
+    fn test_limit_voice_relay() {
+        let data = str_of_len(MAX_VOICE_RELAY_DATA_LENGTH);
+        let limit = max_payload_for_type("VoiceRelay") as usize;
+
+        let client = ClientMessage::VoiceRelay { data: data.clone() };
+        let size = json_size(&client);
+        assert!(
+            size <= limit,
+            "VoiceRelay (client) size {} exceeds limit {}",
+            size,
+            limit
+        );
+
+        let server = ServerMessage::VoiceRelay { data };
+        let size = json_size(&server);
+        assert!(
+            size <= limit,
+            "VoiceRelay (server) size {} exceeds limit {}",
+            size,
+            limit
+        );
+    }
+
+    #[test]
+    fn test_limit_voice_clip_upload() {
+        let msg = ClientMessage::VoiceClipUpload {
+            name: str_of_len(MAX_VOICE_CLIP_NAME_LENGTH),
+            data: str_of_len(MAX_VOICE_CLIP_DATA_URI_LENGTH),
+        };
+        let size = json_size(&msg);
+        let limit = max_payload_for_type("VoiceClipUpload") as usize;
+        assert!(
+            size <= limit,
+            "VoiceClipUpload size {} exceeds limit {}",
+            size,
+            limit
+        );
+    }
+
+    #[test]
+    fn test_limit_voice_clip_played() {
+        let msg = ServerMessage::VoiceClipPlayed {
+            nickname: str_of_len(MAX_NICKNAME_LENGTH),
+            target: str_of_len(MAX_CHANNEL_LENGTH),
+            name: str_of_len(MAX_VOICE_CLIP_NAME_LENGTH),
+            data: str_of_len(MAX_VOICE_CLIP_DATA_URI_LENGTH),
+        };
+        let size = json_size(&msg);
+        let limit = max_payload_for_type("VoiceClipPlayed") as usize;
+        assert!(
+            size <= limit,
+            "VoiceClipPlayed size {} exceeds limit {}",
+            size,
+            limit
+        );
+    }
+
+    #[test]
+    fn test_limit_voice_text() {
+        let msg = ClientMessage::VoiceTextSend {
+            message: str_of_len(MAX_VOICE_TEXT_LENGTH),
+        };
+        let size = json_size(&msg);
+        let limit = max_payload_for_type("VoiceTextSend") as usize;
+        assert!(
+            size <= limit,
+            "VoiceTextSend size {} exceeds limit {}",
+            size,
+            limit
+        );
+
+        let msg = ServerMessage::VoiceText {
+            nickname: str_of_len(MAX_NICKNAME_LENGTH),
+            target: str_of_len(MAX_CHANNEL_LENGTH),
+            message: str_of_len(MAX_VOICE_TEXT_LENGTH),
+        };
+        let size = json_size(&msg);
+        let limit = max_payload_for_type("VoiceText") as usize;
+        assert!(
+            size <= limit,
+            "VoiceText size {} exceeds limit {}",
+            size,
+            limit
+        );
+    }
+
     #[test]
     fn test_limit_chat_updated() {
         // Test with all fields populated (max size)
@@ -2206,6 +3832,14 @@ mod tests {
             topic_set_by: Some(str_of_len(MAX_NICKNAME_LENGTH)),
             secret: Some(false),
             secret_set_by: Some(str_of_len(MAX_NICKNAME_LENGTH)),
+            voice_limit: Some(u32::MAX),
+            voice_limit_set_by: Some(str_of_len(MAX_NICKNAME_LENGTH)),
+            topic_locked: Some(false),
+            topic_locked_set_by: Some(str_of_len(MAX_NICKNAME_LENGTH)),
+            rules: Some(str_of_len(MAX_CHAT_RULES_LENGTH)),
+            rules_set_by: Some(str_of_len(MAX_NICKNAME_LENGTH)),
+            language: Some(str_of_len(MAX_CHANNEL_LANGUAGE_LENGTH)),
+            language_set_by: Some(str_of_len(MAX_NICKNAME_LENGTH)),
         };
         let size = json_size(&msg);
         let limit = max_payload_for_type("ChatUpdated") as usize;
@@ -2228,6 +3862,11 @@ mod tests {
             secret: Some(false),
             members: Some(vec![str_of_len(MAX_NICKNAME_LENGTH); 50]),
             voiced: Some(vec![str_of_len(MAX_NICKNAME_LENGTH); 50]),
+            voice_limit: Some(u32::MAX),
+            topic_locked: true,
+            entry_message: Some(str_of_len(MAX_CHAT_ENTRY_MESSAGE_LENGTH)),
+            rules: Some(str_of_len(MAX_CHAT_RULES_LENGTH)),
+            language: Some(str_of_len(MAX_CHANNEL_LANGUAGE_LENGTH)),
         };
         let size = json_size(&msg);
         let limit = max_payload_for_type("ChatJoinResponse") as usize;
@@ -2248,6 +3887,11 @@ mod tests {
             secret: None,
             members: None,
             voiced: None,
+            voice_limit: None,
+            topic_locked: false,
+            entry_message: None,
+            rules: None,
+            language: None,
         };
         let error_size = json_size(&error_msg);
         assert!(
@@ -2271,6 +3915,11 @@ mod tests {
             members: Some(vec![str_of_len(MAX_NICKNAME_LENGTH); MAX_CHANNEL_MEMBERS]),
             // Voiced array - same size as members for worst case
             voiced: Some(vec![str_of_len(MAX_NICKNAME_LENGTH); MAX_CHANNEL_MEMBERS]),
+            voice_limit: Some(u32::MAX),
+            topic_locked: true,
+            entry_message: Some(str_of_len(MAX_CHAT_ENTRY_MESSAGE_LENGTH)),
+            rules: Some(str_of_len(MAX_CHAT_RULES_LENGTH)),
+            language: Some(str_of_len(MAX_CHANNEL_LANGUAGE_LENGTH)),
         };
         let size = json_size(&msg);
         let limit = max_payload_for_type("ChatJoinResponse") as usize;
@@ -2402,6 +4051,7 @@ mod tests {
         let msg = ServerMessage::Error {
             message: str_of_len(2048),
             command: Some(str_of_len(64)),
+            error_kind: Some(str_of_len(MAX_ERROR_KIND_LENGTH)),
         };
         assert!(
             json_size(&msg) <= max_payload_for_type("Error") as usize,
@@ -2418,6 +4068,14 @@ mod tests {
             success: false,
             version: Some(str_of_len(MAX_VERSION_LENGTH)),
             error: Some(str_of_len(256)),
+            identity_key: Some(str_of_len(IDENTITY_KEY_LENGTH)),
+            identity_signature: Some(str_of_len(IDENTITY_SIGNATURE_LENGTH)),
+            pow_challenge: Some(str_of_len(POW_CHALLENGE_LENGTH)),
+            pow_difficulty: Some(u8::MAX),
+            oidc: Some(OidcProvider {
+                authorization_endpoint: str_of_len(MAX_OIDC_ENDPOINT_LENGTH),
+                client_id: str_of_len(MAX_OIDC_CLIENT_ID_LENGTH),
+            }),
         };
         assert!(
             json_size(&msg) <= max_payload_for_type("HandshakeResponse") as usize,
@@ -2438,6 +4096,11 @@ mod tests {
             secret: false,
             members: (0..50).map(|_| str_of_len(MAX_NICKNAME_LENGTH)).collect(),
             voiced: Some((0..50).map(|_| str_of_len(MAX_NICKNAME_LENGTH)).collect()),
+            voice_limit: Some(u32::MAX),
+            topic_locked: true,
+            entry_message: Some(str_of_len(MAX_CHAT_ENTRY_MESSAGE_LENGTH)),
+            rules: Some(str_of_len(MAX_CHAT_RULES_LENGTH)),
+            language: Some(str_of_len(MAX_CHANNEL_LANGUAGE_LENGTH)),
         };
         let channels: Vec<ChannelJoinInfo> = (0..10).map(|_| channel_info.clone()).collect();
 
@@ -2457,16 +4120,27 @@ mod tests {
                 version: Some(str_of_len(MAX_VERSION_LENGTH)),
                 max_connections_per_ip: Some(u32::MAX),
                 max_transfers_per_ip: Some(u32::MAX),
+                max_active_transfers: Some(u32::MAX),
+                transfer_small_files_first: Some(false),
+                trusted_transfer_weight: Some(u32::MAX),
                 image: Some(str_of_len(MAX_SERVER_IMAGE_DATA_URI_LENGTH)),
                 transfer_port: u16::MAX,
                 transfer_websocket_port: Some(u16::MAX),
                 file_reindex_interval: Some(u32::MAX),
                 persistent_channels: Some(str_of_len(MAX_PERSISTENT_CHANNELS_LENGTH)),
                 auto_join_channels: Some(str_of_len(MAX_PERSISTENT_CHANNELS_LENGTH)),
+                nat: Some(max_nat_info()),
+                news_image_limits: Some(NewsImageLimits {
+                    max_size: u32::MAX,
+                    max_dimension: u32::MAX,
+                }),
+                max_message_length: Some(u32::MAX),
+                time: Some(max_server_time()),
             }),
             locale: Some(str_of_len(MAX_LOCALE_LENGTH)),
             channels: Some(channels),
             nickname: Some(str_of_len(MAX_NICKNAME_LENGTH)),
+            login_token: Some(str_of_len(LOGIN_TOKEN_LENGTH)),
         };
         let size = json_size(&msg);
         let limit = max_payload_for_type("LoginResponse") as usize;
@@ -2491,12 +4165,22 @@ mod tests {
                 version: Some(str_of_len(MAX_VERSION_LENGTH)),
                 max_connections_per_ip: Some(u32::MAX),
                 max_transfers_per_ip: Some(u32::MAX),
+                max_active_transfers: Some(u32::MAX),
+                transfer_small_files_first: Some(false),
+                trusted_transfer_weight: Some(u32::MAX),
                 image: Some(str_of_len(MAX_SERVER_IMAGE_DATA_URI_LENGTH)),
                 transfer_port: u16::MAX,
                 transfer_websocket_port: Some(u16::MAX),
                 file_reindex_interval: Some(u32::MAX),
                 persistent_channels: Some(str_of_len(MAX_PERSISTENT_CHANNELS_LENGTH)),
                 auto_join_channels: Some(str_of_len(MAX_PERSISTENT_CHANNELS_LENGTH)),
+                nat: Some(max_nat_info()),
+                news_image_limits: Some(NewsImageLimits {
+                    max_size: u32::MAX,
+                    max_dimension: u32::MAX,
+                }),
+                max_message_length: Some(u32::MAX),
+                time: Some(max_server_time()),
             }),
         };
         let size = json_size(&msg);
@@ -2518,12 +4202,22 @@ mod tests {
                 version: Some(str_of_len(MAX_VERSION_LENGTH)),
                 max_connections_per_ip: Some(u32::MAX),
                 max_transfers_per_ip: Some(u32::MAX),
+                max_active_transfers: Some(u32::MAX),
+                transfer_small_files_first: Some(false),
+                trusted_transfer_weight: Some(u32::MAX),
                 image: Some(str_of_len(MAX_SERVER_IMAGE_DATA_URI_LENGTH)),
                 transfer_port: u16::MAX,
                 transfer_websocket_port: Some(u16::MAX),
                 file_reindex_interval: Some(u32::MAX),
                 persistent_channels: Some(str_of_len(MAX_PERSISTENT_CHANNELS_LENGTH)),
                 auto_join_channels: Some(str_of_len(MAX_PERSISTENT_CHANNELS_LENGTH)),
+                nat: Some(max_nat_info()),
+                news_image_limits: Some(NewsImageLimits {
+                    max_size: u32::MAX,
+                    max_dimension: u32::MAX,
+                }),
+                max_message_length: Some(u32::MAX),
+                time: Some(max_server_time()),
             },
         };
         assert!(
@@ -2535,6 +4229,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_limit_pong() {
+        let msg = ServerMessage::Pong {
+            time: Some(max_server_time()),
+        };
+        assert!(
+            json_size(&msg) <= max_payload_for_type("Pong") as usize,
+            "{} size {} exceeds limit {}",
+            "Pong",
+            json_size(&msg),
+            max_payload_for_type("Pong")
+        );
+    }
+
     #[test]
     fn test_limit_server_info_update_response() {
         let msg = ServerMessage::ServerInfoUpdateResponse {
@@ -2556,6 +4264,7 @@ mod tests {
             session_id: u32::MAX,
             username: str_of_len(MAX_USERNAME_LENGTH),
             message: str_of_len(MAX_MESSAGE_LENGTH),
+            broadcast_id: Some(u64::MAX),
         };
         assert!(
             json_size(&msg) <= max_payload_for_type("ServerBroadcast") as usize,
@@ -2580,6 +4289,9 @@ mod tests {
                 avatar: Some(str_of_len(MAX_AVATAR_DATA_URI_LENGTH)),
                 is_away: false,
                 status: Some(str_of_len(MAX_STATUS_LENGTH)),
+                is_dnd: false,
+                last_active: Some(i64::MAX),
+                client: Some(str_of_len(MAX_CLIENT_NAME_LENGTH)),
             },
         };
         assert!(
@@ -2623,6 +4335,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_limit_user_erase_response() {
+        let msg = ServerMessage::UserEraseResponse {
+            success: false,
+            error: Some(str_of_len(512)),
+            username: Some(str_of_len(MAX_USERNAME_LENGTH)),
+        };
+        assert!(
+            json_size(&msg) <= max_payload_for_type("UserEraseResponse") as usize,
+            "{} size {} exceeds limit {}",
+            "UserEraseResponse",
+            json_size(&msg),
+            max_payload_for_type("UserEraseResponse")
+        );
+    }
+
     #[test]
     fn test_limit_user_disconnected() {
         let msg = ServerMessage::UserDisconnected {
@@ -2667,6 +4395,9 @@ mod tests {
         let msg = ServerMessage::UserBroadcastResponse {
             success: false,
             error: Some(str_of_len(512)),
+            broadcast_id: Some(u64::MAX),
+            delivered: Some(u32::MAX),
+            send_at: Some(i64::MIN),
         };
         assert!(
             json_size(&msg) <= max_payload_for_type("UserBroadcastResponse") as usize,
@@ -2678,12 +4409,39 @@ mod tests {
     }
 
     #[test]
-    fn test_limit_user_info_response() {
-        let msg = ServerMessage::UserInfoResponse {
-            success: false,
-            error: None,
-            user: Some(UserInfoDetailed {
-                username: str_of_len(MAX_USERNAME_LENGTH),
+    fn test_limit_storage_alert() {
+        let msg = ServerMessage::StorageAlert {
+            volume: StorageVolume::Database,
+            low: false,
+            free: u64::MAX,
+            total: u64::MAX,
+            threshold: u64::MAX,
+        };
+        assert!(json_size(&msg) <= max_payload_for_type("StorageAlert") as usize);
+    }
+
+    #[test]
+    fn test_limit_broadcast_ack_and_status() {
+        let ack = ClientMessage::BroadcastAck {
+            broadcast_id: u64::MAX,
+        };
+        assert!(json_size(&ack) <= max_payload_for_type("BroadcastAck") as usize);
+
+        let status = ServerMessage::BroadcastStatus {
+            broadcast_id: u64::MAX,
+            delivered: u32::MAX,
+            acknowledged: Some(u32::MAX),
+        };
+        assert!(json_size(&status) <= max_payload_for_type("BroadcastStatus") as usize);
+    }
+
+    #[test]
+    fn test_limit_user_info_response() {
+        let msg = ServerMessage::UserInfoResponse {
+            success: false,
+            error: None,
+            user: Some(UserInfoDetailed {
+                username: str_of_len(MAX_USERNAME_LENGTH),
                 nickname: str_of_len(MAX_NICKNAME_LENGTH),
                 login_time: i64::MAX,
                 is_shared: false,
@@ -2698,7 +4456,12 @@ mod tests {
                 addresses: Some(vec![str_of_len(45); 10]),
                 is_away: false,
                 status: Some(str_of_len(MAX_STATUS_LENGTH)),
+                is_dnd: false,
                 channels: Some((0..100).map(|_| str_of_len(MAX_CHANNEL_LENGTH)).collect()),
+                stats: Some(max_user_stats()),
+                rank: Some(str_of_len(MAX_RANK_NAME_LENGTH)),
+                last_active: Some(i64::MAX),
+                client: Some(str_of_len(MAX_CLIENT_NAME_LENGTH)),
             }),
         };
         assert!(
@@ -2734,6 +4497,225 @@ mod tests {
         assert_eq!(max_payload_for_type("UserListResponse"), 0);
     }
 
+    #[test]
+    fn test_limit_permission_audit_response() {
+        // PermissionAuditResponse is unlimited (0) since it can list every account
+        assert_eq!(max_payload_for_type("PermissionAuditResponse"), 0);
+    }
+
+    #[test]
+    fn test_limit_impersonate_query_response() {
+        // ImpersonateQueryResponse is unlimited (0) since it can list a whole directory
+        assert_eq!(max_payload_for_type("ImpersonateQueryResponse"), 0);
+    }
+
+    #[test]
+    fn test_limit_nat_status_response() {
+        let msg = ServerMessage::NatStatusResponse {
+            success: false,
+            error: Some(str_of_len(MAX_ERROR_LENGTH)),
+            nat: Some(max_nat_info()),
+        };
+        assert!(
+            json_size(&msg) <= max_payload_for_type("NatStatusResponse") as usize,
+            "{} size {} exceeds limit {}",
+            "NatStatusResponse",
+            json_size(&msg),
+            max_payload_for_type("NatStatusResponse")
+        );
+    }
+
+    #[test]
+    fn test_limit_connectivity_check_response() {
+        let port = PortCheck {
+            service: CheckedService::TransferWebsocket,
+            port: u16::MAX,
+            state: PortReachability::Untested,
+        };
+        let msg = ServerMessage::ConnectivityCheckResponse {
+            success: false,
+            error: Some(str_of_len(MAX_ERROR_LENGTH)),
+            external_ip: Some(str_of_len(MAX_IP_LENGTH)),
+            ports: Some(vec![port; MAX_CHECKED_PORTS]),
+        };
+        assert!(
+            json_size(&msg) <= max_payload_for_type("ConnectivityCheckResponse") as usize,
+            "{} size {} exceeds limit {}",
+            "ConnectivityCheckResponse",
+            json_size(&msg),
+            max_payload_for_type("ConnectivityCheckResponse")
+        );
+    }
+
+    #[test]
+    fn test_limit_stats_request() {
+        let msg = ClientMessage::StatsRequest { days: u16::MAX };
+        assert!(
+            json_size(&msg) <= max_payload_for_type("StatsRequest") as usize,
+            "{} size {} exceeds limit {}",
+            "StatsRequest",
+            json_size(&msg),
+            max_payload_for_type("StatsRequest")
+        );
+    }
+
+    #[test]
+    fn test_limit_stats_response() {
+        let day = DailyStats {
+            date: str_of_len(STATS_DATE_LENGTH),
+            logins: u64::MAX,
+            messages: u64::MAX,
+            uploads: u64::MAX,
+            upload_bytes: u64::MAX,
+            downloads: u64::MAX,
+            download_bytes: u64::MAX,
+            peak_sessions: u32::MAX,
+        };
+        let msg = ServerMessage::StatsResponse {
+            success: false,
+            error: Some(str_of_len(MAX_ERROR_LENGTH)),
+            days: Some(vec![day; MAX_STATS_DAYS]),
+        };
+        assert!(
+            json_size(&msg) <= max_payload_for_type("StatsResponse") as usize,
+            "{} size {} exceeds limit {}",
+            "StatsResponse",
+            json_size(&msg),
+            max_payload_for_type("StatsResponse")
+        );
+    }
+
+    fn max_user_stats() -> UserStats {
+        UserStats {
+            logins: u64::MAX,
+            messages: u64::MAX,
+            uploads: u64::MAX,
+            download_bytes: u64::MAX,
+            last_login_at: Some(i64::MIN),
+        }
+    }
+
+    #[test]
+    fn test_limit_leaderboard() {
+        let msg = ClientMessage::Leaderboard {
+            stat: LeaderboardStat::DownloadBytes,
+        };
+        assert!(
+            json_size(&msg) <= max_payload_for_type("Leaderboard") as usize,
+            "{} size {} exceeds limit {}",
+            "Leaderboard",
+            json_size(&msg),
+            max_payload_for_type("Leaderboard")
+        );
+    }
+
+    #[test]
+    fn test_limit_leaderboard_response() {
+        let entry = LeaderboardEntry {
+            username: str_of_len(MAX_USERNAME_LENGTH),
+            stats: max_user_stats(),
+            rank: Some(str_of_len(MAX_RANK_NAME_LENGTH)),
+        };
+        let msg = ServerMessage::LeaderboardResponse {
+            success: false,
+            error: Some(str_of_len(MAX_ERROR_LENGTH)),
+            entries: Some(vec![entry; MAX_LEADERBOARD_ENTRIES]),
+        };
+        assert!(
+            json_size(&msg) <= max_payload_for_type("LeaderboardResponse") as usize,
+            "{} size {} exceeds limit {}",
+            "LeaderboardResponse",
+            json_size(&msg),
+            max_payload_for_type("LeaderboardResponse")
+        );
+    }
+
+    #[test]
+    fn test_limit_door_client_messages() {
+        let msg = ClientMessage::DoorOpen {
+            name: str_of_len(MAX_DOOR_NAME_LENGTH),
+            cols: u16::MAX,
+            rows: u16::MAX,
+        };
+        assert!(
+            json_size(&msg) <= max_payload_for_type("DoorOpen") as usize,
+            "{} size {} exceeds limit {}",
+            "DoorOpen",
+            json_size(&msg),
+            max_payload_for_type("DoorOpen")
+        );
+
+        for (msg, name) in [
+            (ClientMessage::DoorList, "DoorList"),
+            (ClientMessage::DoorClose, "DoorClose"),
+        ] {
+            assert!(
+                json_size(&msg) <= max_payload_for_type(name) as usize,
+                "{} size {} exceeds limit {}",
+                name,
+                json_size(&msg),
+                max_payload_for_type(name)
+            );
+        }
+    }
+
+    #[test]
+    fn test_limit_door_data() {
+        let data = str_of_len(MAX_DOOR_DATA_LENGTH);
+        let limit = max_payload_for_type("DoorData") as usize;
+
+        let client = ClientMessage::DoorData { data: data.clone() };
+        let size = json_size(&client);
+        assert!(
+            size <= limit,
+            "DoorData (client) size {} exceeds limit {}",
+            size,
+            limit
+        );
+
+        let server = ServerMessage::DoorData { data };
+        let size = json_size(&server);
+        assert!(
+            size <= limit,
+            "DoorData (server) size {} exceeds limit {}",
+            size,
+            limit
+        );
+    }
+
+    #[test]
+    fn test_limit_door_server_messages() {
+        let msg = ServerMessage::DoorOpenResponse {
+            success: false,
+            error: Some(str_of_len(MAX_ERROR_LENGTH)),
+        };
+        assert!(
+            json_size(&msg) <= max_payload_for_type("DoorOpenResponse") as usize,
+            "{} size {} exceeds limit {}",
+            "DoorOpenResponse",
+            json_size(&msg),
+            max_payload_for_type("DoorOpenResponse")
+        );
+
+        let msg = ServerMessage::DoorClosed {
+            exit_code: Some(u32::MAX),
+        };
+        assert!(
+            json_size(&msg) <= max_payload_for_type("DoorClosed") as usize,
+            "{} size {} exceeds limit {}",
+            "DoorClosed",
+            json_size(&msg),
+            max_payload_for_type("DoorClosed")
+        );
+    }
+
+    #[test]
+    fn test_limit_log_server_messages() {
+        // Log history and log lines are unlimited (0) since messages can be long
+        assert_eq!(max_payload_for_type("LogSubscribeResponse"), 0);
+        assert_eq!(max_payload_for_type("LogLine"), 0);
+    }
+
     #[test]
     fn test_limit_user_message_server() {
         let msg = ServerMessage::UserMessage {
@@ -2744,6 +4726,7 @@ mod tests {
             message: str_of_len(MAX_MESSAGE_LENGTH),
             action: ChatAction::Normal,
             timestamp: u64::MAX,
+            id: Some(u64::MAX),
         };
         assert!(
             json_size(&msg) <= max_payload_for_type("UserMessage") as usize,
@@ -2759,6 +4742,7 @@ mod tests {
         let msg = ServerMessage::UserMessageResponse {
             success: false,
             error: Some(str_of_len(512)),
+            error_kind: Some(str_of_len(MAX_ERROR_KIND_LENGTH)),
             is_away: Some(false),
             status: Some(str_of_len(MAX_STATUS_LENGTH)),
         };
@@ -2786,6 +4770,9 @@ mod tests {
                 avatar: Some(str_of_len(MAX_AVATAR_DATA_URI_LENGTH)),
                 is_away: false,
                 status: Some(str_of_len(MAX_STATUS_LENGTH)),
+                is_dnd: false,
+                last_active: Some(i64::MAX),
+                client: Some(str_of_len(MAX_CLIENT_NAME_LENGTH)),
             },
         };
         assert!(
@@ -2858,6 +4845,117 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_limit_message_history() {
+        let msg = ClientMessage::MessageHistory {
+            nickname: Some(str_of_len(MAX_NICKNAME_LENGTH)),
+            before: Some(i64::MIN),
+            limit: Some(u32::MAX),
+        };
+        assert!(json_size(&msg) <= max_payload_for_type("MessageHistory") as usize);
+
+        let msg = ClientMessage::MessageHistoryRetention { days: u32::MAX };
+        assert!(json_size(&msg) <= max_payload_for_type("MessageHistoryRetention") as usize);
+
+        let msg = ServerMessage::MessageHistoryRetentionResponse {
+            success: false,
+            error: Some(str_of_len(MAX_ERROR_LENGTH)),
+            days: Some(u32::MAX),
+        };
+        assert!(
+            json_size(&msg) <= max_payload_for_type("MessageHistoryRetentionResponse") as usize
+        );
+    }
+
+    #[test]
+    fn test_limit_message_receipts() {
+        let msg = ClientMessage::MessageMarkRead {
+            nickname: str_of_len(MAX_NICKNAME_LENGTH),
+            id: u64::MAX,
+        };
+        assert!(json_size(&msg) <= max_payload_for_type("MessageMarkRead") as usize);
+
+        let msg = ServerMessage::MessageDelivered {
+            nickname: str_of_len(MAX_NICKNAME_LENGTH),
+            id: u64::MAX,
+        };
+        assert!(json_size(&msg) <= max_payload_for_type("MessageDelivered") as usize);
+
+        let msg = ServerMessage::MessageRead {
+            nickname: str_of_len(MAX_NICKNAME_LENGTH),
+            id: u64::MAX,
+        };
+        assert!(json_size(&msg) <= max_payload_for_type("MessageRead") as usize);
+    }
+
+    #[test]
+    fn test_limit_group_messages() {
+        let members: Vec<String> = (0..MAX_GROUP_MEMBERS)
+            .map(|_| str_of_len(MAX_NICKNAME_LENGTH))
+            .collect();
+
+        let msg = ClientMessage::GroupMessageCreate {
+            members: members.clone(),
+        };
+        assert!(json_size(&msg) <= max_payload_for_type("GroupMessageCreate") as usize);
+
+        let msg = ClientMessage::GroupMessageSend {
+            group_id: u64::MAX,
+            message: str_of_len(MAX_MESSAGE_LENGTH),
+            action: ChatAction::Normal,
+            idempotency_key: Some(str_of_len(MAX_IDEMPOTENCY_KEY_LENGTH)),
+        };
+        assert!(json_size(&msg) <= max_payload_for_type("GroupMessageSend") as usize);
+
+        let msg = ClientMessage::GroupMessageInvite {
+            group_id: u64::MAX,
+            nickname: str_of_len(MAX_NICKNAME_LENGTH),
+        };
+        assert!(json_size(&msg) <= max_payload_for_type("GroupMessageInvite") as usize);
+
+        let msg = ClientMessage::GroupMessageLeave { group_id: u64::MAX };
+        assert!(json_size(&msg) <= max_payload_for_type("GroupMessageLeave") as usize);
+
+        let msg = ServerMessage::GroupMessageCreateResponse {
+            success: false,
+            error: Some(str_of_len(MAX_ERROR_LENGTH)),
+            group_id: Some(u64::MAX),
+        };
+        assert!(json_size(&msg) <= max_payload_for_type("GroupMessageCreateResponse") as usize);
+
+        let msg = ServerMessage::GroupMessage {
+            group_id: u64::MAX,
+            from_nickname: str_of_len(MAX_NICKNAME_LENGTH),
+            from_admin: false,
+            from_shared: false,
+            message: str_of_len(MAX_MESSAGE_LENGTH),
+            action: ChatAction::Normal,
+            timestamp: u64::MAX,
+        };
+        assert!(json_size(&msg) <= max_payload_for_type("GroupMessage") as usize);
+
+        let msg = ServerMessage::GroupMembers {
+            group_id: u64::MAX,
+            members,
+        };
+        assert!(json_size(&msg) <= max_payload_for_type("GroupMembers") as usize);
+    }
+
+    #[test]
+    fn test_limit_user_dnd_response() {
+        let msg = ServerMessage::UserDndResponse {
+            success: false,
+            error: Some(str_of_len(2048)),
+        };
+        assert!(
+            json_size(&msg) <= max_payload_for_type("UserDndResponse") as usize,
+            "{} size {} exceeds limit {}",
+            "UserDndResponse",
+            json_size(&msg),
+            max_payload_for_type("UserDndResponse")
+        );
+    }
+
     #[test]
     fn test_limit_ban_create_response() {
         // Max size: success + error (2048) + ips array + nickname (32) + overhead
@@ -3029,6 +5127,529 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_limit_file_cleanup() {
+        let msg = ClientMessage::FileCleanup { delete: false };
+        let size = json_size(&msg);
+        let limit = max_payload_for_type("FileCleanup") as usize;
+        assert!(
+            size <= limit,
+            "FileCleanup size {} exceeds limit {}",
+            size,
+            limit
+        );
+        // Lists found paths, so the response is unlimited
+        assert_eq!(max_payload_for_type("FileCleanupResponse"), 0);
+    }
+
+    #[test]
+    fn test_limit_file_check() {
+        let msg = ClientMessage::FileCheck { repair: false };
+        let size = json_size(&msg);
+        let limit = max_payload_for_type("FileCheck") as usize;
+        assert!(
+            size <= limit,
+            "FileCheck size {} exceeds limit {}",
+            size,
+            limit
+        );
+
+        let msg = ServerMessage::FileCheckResponse {
+            success: false,
+            error: Some(str_of_len(MAX_ERROR_LENGTH)),
+            summary: Some(FileCheckSummary {
+                entries: u32::MAX,
+                issues: u32::MAX,
+                repaired: u32::MAX,
+                index_checked: false,
+            }),
+        };
+        let size = json_size(&msg);
+        let limit = max_payload_for_type("FileCheckResponse") as usize;
+        assert!(
+            size <= limit,
+            "FileCheckResponse size {} exceeds limit {}",
+            size,
+            limit
+        );
+        // Streams paths found on disk, so issues are unlimited
+        assert_eq!(max_payload_for_type("FileCheckIssue"), 0);
+    }
+
+    #[test]
+    fn test_limit_file_popular() {
+        let msg = ClientMessage::FilePopular { root: false };
+        let size = json_size(&msg);
+        let limit = max_payload_for_type("FilePopular") as usize;
+        assert!(
+            size <= limit,
+            "FilePopular size {} exceeds limit {}",
+            size,
+            limit
+        );
+    }
+
+    #[test]
+    fn test_limit_file_recent() {
+        let msg = ClientMessage::FileRecent { root: false };
+        let size = json_size(&msg);
+        let limit = max_payload_for_type("FileRecent") as usize;
+        assert!(
+            size <= limit,
+            "FileRecent size {} exceeds limit {}",
+            size,
+            limit
+        );
+    }
+
+    #[test]
+    fn test_limit_file_comment_client_messages() {
+        let messages = [
+            (
+                ClientMessage::FileRate {
+                    path: str_of_len(MAX_FILE_PATH_LENGTH),
+                    rating: u8::MAX,
+                    root: false,
+                },
+                "FileRate",
+            ),
+            (
+                ClientMessage::FileCommentCreate {
+                    path: str_of_len(MAX_FILE_PATH_LENGTH),
+                    body: str_of_len(MAX_FILE_COMMENT_LENGTH),
+                    root: false,
+                },
+                "FileCommentCreate",
+            ),
+            (
+                ClientMessage::FileCommentList {
+                    path: str_of_len(MAX_FILE_PATH_LENGTH),
+                    root: false,
+                },
+                "FileCommentList",
+            ),
+            (
+                ClientMessage::FileCommentDelete { id: i64::MIN },
+                "FileCommentDelete",
+            ),
+        ];
+        for (msg, name) in messages {
+            assert!(
+                json_size(&msg) <= max_payload_for_type(name) as usize,
+                "{} size {} exceeds limit {}",
+                name,
+                json_size(&msg),
+                max_payload_for_type(name)
+            );
+        }
+    }
+
+    #[test]
+    fn test_limit_file_comment_server_messages() {
+        let messages = [
+            (
+                ServerMessage::FileRateResponse {
+                    success: false,
+                    error: Some(str_of_len(MAX_ERROR_LENGTH)),
+                    rating: Some(FileRating {
+                        count: u32::MAX,
+                        total: u32::MAX,
+                    }),
+                    my_rating: Some(u8::MAX),
+                },
+                "FileRateResponse",
+            ),
+            (
+                ServerMessage::FileCommentCreateResponse {
+                    success: false,
+                    error: Some(str_of_len(MAX_ERROR_LENGTH)),
+                    comment: Some(FileComment {
+                        id: i64::MIN,
+                        author: str_of_len(MAX_USERNAME_LENGTH),
+                        body: str_of_len(MAX_FILE_COMMENT_LENGTH),
+                        created_at: i64::MIN,
+                    }),
+                },
+                "FileCommentCreateResponse",
+            ),
+            (
+                ServerMessage::FileCommentDeleteResponse {
+                    success: false,
+                    error: Some(str_of_len(MAX_ERROR_LENGTH)),
+                    id: Some(i64::MIN),
+                },
+                "FileCommentDeleteResponse",
+            ),
+        ];
+        for (msg, name) in messages {
+            assert!(
+                json_size(&msg) <= max_payload_for_type(name) as usize,
+                "{} size {} exceeds limit {}",
+                name,
+                json_size(&msg),
+                max_payload_for_type(name)
+            );
+        }
+
+        // Comment lists can be long
+        assert_eq!(max_payload_for_type("FileCommentListResponse"), 0);
+    }
+
+    #[test]
+    fn test_limit_upload_rule_messages() {
+        let rule = UploadRule {
+            extensions: vec![str_of_len(MAX_UPLOAD_EXTENSION_LENGTH); MAX_UPLOAD_EXTENSIONS],
+            max_file_size: Some(u64::MAX),
+            max_total_size: Some(u64::MAX),
+        };
+        let client_messages = [
+            (
+                ClientMessage::UploadRuleGet {
+                    path: str_of_len(MAX_FILE_PATH_LENGTH),
+                    root: false,
+                },
+                "UploadRuleGet",
+            ),
+            (
+                ClientMessage::UploadRuleSet {
+                    path: str_of_len(MAX_FILE_PATH_LENGTH),
+                    rule: Some(rule.clone()),
+                    root: false,
+                },
+                "UploadRuleSet",
+            ),
+        ];
+        for (msg, name) in client_messages {
+            assert!(
+                json_size(&msg) <= max_payload_for_type(name) as usize,
+                "{} size {} exceeds limit {}",
+                name,
+                json_size(&msg),
+                max_payload_for_type(name)
+            );
+        }
+
+        let server_messages = [
+            (
+                ServerMessage::UploadRuleGetResponse {
+                    success: false,
+                    error: Some(str_of_len(MAX_ERROR_LENGTH)),
+                    rule: Some(rule),
+                },
+                "UploadRuleGetResponse",
+            ),
+            (
+                ServerMessage::UploadRuleSetResponse {
+                    success: false,
+                    error: Some(str_of_len(MAX_ERROR_LENGTH)),
+                },
+                "UploadRuleSetResponse",
+            ),
+        ];
+        for (msg, name) in server_messages {
+            assert!(
+                json_size(&msg) <= max_payload_for_type(name) as usize,
+                "{} size {} exceeds limit {}",
+                name,
+                json_size(&msg),
+                max_payload_for_type(name)
+            );
+        }
+    }
+
+    #[test]
+    fn test_limit_news_messages() {
+        let attachments = vec![
+            NewsAttachment::Image {
+                data: str_of_len(MAX_NEWS_IMAGE_DATA_URI_LENGTH),
+                caption: Some(str_of_len(MAX_NEWS_CAPTION_LENGTH)),
+            };
+            MAX_NEWS_ATTACHMENTS
+        ];
+        let client_messages = [
+            (
+                ClientMessage::NewsCreate {
+                    body: Some(str_of_len(MAX_NEWS_BODY_LENGTH)),
+                    image: Some(str_of_len(MAX_NEWS_IMAGE_DATA_URI_LENGTH)),
+                    attachments: attachments.clone(),
+                },
+                "NewsCreate",
+            ),
+            (
+                ClientMessage::NewsUpdate {
+                    id: i64::MIN,
+                    body: Some(str_of_len(MAX_NEWS_BODY_LENGTH)),
+                    image: Some(str_of_len(MAX_NEWS_IMAGE_DATA_URI_LENGTH)),
+                    attachments: attachments.clone(),
+                },
+                "NewsUpdate",
+            ),
+            (
+                ClientMessage::NewsSearch {
+                    query: str_of_len(MAX_SEARCH_QUERY_LENGTH),
+                    author: Some(str_of_len(MAX_USERNAME_LENGTH)),
+                    since: Some(str_of_len(NEWS_SEARCH_DATE_LENGTH)),
+                    until: Some(str_of_len(NEWS_SEARCH_DATE_LENGTH)),
+                },
+                "NewsSearch",
+            ),
+        ];
+        for (msg, name) in client_messages {
+            assert!(
+                json_size(&msg) <= max_payload_for_type(name) as usize,
+                "{} size {} exceeds limit {}",
+                name,
+                json_size(&msg),
+                max_payload_for_type(name)
+            );
+        }
+
+        let news = NewsItem {
+            id: i64::MIN,
+            body: Some(str_of_len(MAX_NEWS_BODY_LENGTH)),
+            attachments,
+            author: str_of_len(MAX_NICKNAME_LENGTH),
+            author_is_admin: false,
+            created_at: str_of_len(MAX_TIMESTAMP),
+            updated_at: Some(str_of_len(MAX_TIMESTAMP)),
+        };
+        let msg = ServerMessage::NewsShowResponse {
+            success: false,
+            error: Some(str_of_len(MAX_ERROR_LENGTH)),
+            news: Some(news),
+        };
+        assert!(
+            json_size(&msg) <= max_payload_for_type("NewsShowResponse") as usize,
+            "{} size {} exceeds limit {}",
+            "NewsShowResponse",
+            json_size(&msg),
+            max_payload_for_type("NewsShowResponse")
+        );
+    }
+
+    #[test]
+    fn test_limit_snippet_messages() {
+        // Newlines are escaped in JSON, doubling the text's size
+        let content = "\n".repeat(MAX_SNIPPET_LENGTH);
+        let msg = ClientMessage::SnippetCreate {
+            content: content.clone(),
+            language: Some(str_of_len(MAX_SNIPPET_LANGUAGE_LENGTH)),
+            expires_in: u32::MAX,
+        };
+        assert!(json_size(&msg) <= max_payload_for_type("SnippetCreate") as usize);
+
+        let msg = ServerMessage::SnippetGetResponse {
+            success: false,
+            error: Some(str_of_len(MAX_ERROR_LENGTH)),
+            snippet: Some(SnippetInfo {
+                id: str_of_len(SNIPPET_ID_LENGTH),
+                author: str_of_len(MAX_NICKNAME_LENGTH),
+                language: Some(str_of_len(MAX_SNIPPET_LANGUAGE_LENGTH)),
+                content,
+                created_at: i64::MIN,
+                expires_at: i64::MIN,
+            }),
+        };
+        assert!(json_size(&msg) <= max_payload_for_type("SnippetGetResponse") as usize);
+    }
+
+    #[test]
+    fn test_limit_ldap_group_messages() {
+        let group = str_of_len(MAX_LDAP_GROUP_LENGTH);
+        let client_messages = [
+            (ClientMessage::LdapGroupList, "LdapGroupList"),
+            (
+                ClientMessage::LdapGroupSet {
+                    group: group.clone(),
+                    permissions: vec![str_of_len(MAX_PERMISSION_LENGTH); PERMISSIONS_COUNT],
+                    is_admin: true,
+                },
+                "LdapGroupSet",
+            ),
+            (
+                ClientMessage::LdapGroupDelete {
+                    group: group.clone(),
+                },
+                "LdapGroupDelete",
+            ),
+        ];
+        for (msg, name) in client_messages {
+            assert!(
+                json_size(&msg) <= max_payload_for_type(name) as usize,
+                "{} size {} exceeds limit {}",
+                name,
+                json_size(&msg),
+                max_payload_for_type(name)
+            );
+        }
+
+        let server_messages = [
+            (
+                ServerMessage::LdapGroupSetResponse {
+                    success: false,
+                    error: Some(str_of_len(MAX_ERROR_LENGTH)),
+                    group: Some(group.clone()),
+                },
+                "LdapGroupSetResponse",
+            ),
+            (
+                ServerMessage::LdapGroupDeleteResponse {
+                    success: false,
+                    error: Some(str_of_len(MAX_ERROR_LENGTH)),
+                    group: Some(group),
+                },
+                "LdapGroupDeleteResponse",
+            ),
+        ];
+        for (msg, name) in server_messages {
+            assert!(
+                json_size(&msg) <= max_payload_for_type(name) as usize,
+                "{} size {} exceeds limit {}",
+                name,
+                json_size(&msg),
+                max_payload_for_type(name)
+            );
+        }
+
+        // Group lists can be long
+        assert_eq!(max_payload_for_type("LdapGroupListResponse"), 0);
+    }
+
+    #[test]
+    fn test_limit_guest_upgrade_messages() {
+        let msg = ClientMessage::GuestUpgrade {
+            username: str_of_len(MAX_USERNAME_LENGTH),
+            password: str_of_len(MAX_PASSWORD_LENGTH),
+        };
+        assert!(
+            json_size(&msg) <= max_payload_for_type("GuestUpgrade") as usize,
+            "GuestUpgrade size {} exceeds limit {}",
+            json_size(&msg),
+            max_payload_for_type("GuestUpgrade")
+        );
+
+        let msg = ServerMessage::GuestUpgradeResponse {
+            success: false,
+            error: Some(str_of_len(MAX_ERROR_LENGTH)),
+            username: Some(str_of_len(MAX_USERNAME_LENGTH)),
+        };
+        assert!(
+            json_size(&msg) <= max_payload_for_type("GuestUpgradeResponse") as usize,
+            "GuestUpgradeResponse size {} exceeds limit {}",
+            json_size(&msg),
+            max_payload_for_type("GuestUpgradeResponse")
+        );
+    }
+
+    #[test]
+    fn test_limit_nickname_messages() {
+        let nickname = str_of_len(MAX_NICKNAME_LENGTH);
+        let client_messages = [
+            (
+                ClientMessage::NicknameRegister {
+                    nickname: nickname.clone(),
+                    username: Some(str_of_len(MAX_USERNAME_LENGTH)),
+                },
+                "NicknameRegister",
+            ),
+            (
+                ClientMessage::NicknameRelease {
+                    nickname: nickname.clone(),
+                },
+                "NicknameRelease",
+            ),
+        ];
+        for (msg, name) in client_messages {
+            assert!(
+                json_size(&msg) <= max_payload_for_type(name) as usize,
+                "{} size {} exceeds limit {}",
+                name,
+                json_size(&msg),
+                max_payload_for_type(name)
+            );
+        }
+
+        let server_messages = [
+            (
+                ServerMessage::NicknameRegisterResponse {
+                    success: false,
+                    error: Some(str_of_len(MAX_ERROR_LENGTH)),
+                    nickname: Some(nickname.clone()),
+                    username: Some(str_of_len(MAX_USERNAME_LENGTH)),
+                },
+                "NicknameRegisterResponse",
+            ),
+            (
+                ServerMessage::NicknameReleaseResponse {
+                    success: false,
+                    error: Some(str_of_len(MAX_ERROR_LENGTH)),
+                    nickname: Some(nickname),
+                },
+                "NicknameReleaseResponse",
+            ),
+        ];
+        for (msg, name) in server_messages {
+            assert!(
+                json_size(&msg) <= max_payload_for_type(name) as usize,
+                "{} size {} exceeds limit {}",
+                name,
+                json_size(&msg),
+                max_payload_for_type(name)
+            );
+        }
+    }
+
+    #[test]
+    fn test_limit_presence_messages() {
+        let usernames: Vec<String> = (0..MAX_PRESENCE_SUBSCRIPTIONS)
+            .map(|_| str_of_len(MAX_USERNAME_LENGTH))
+            .collect();
+        let msg = ClientMessage::PresenceSubscribe { usernames };
+        assert!(
+            json_size(&msg) <= max_payload_for_type("PresenceSubscribe") as usize,
+            "PresenceSubscribe size {} exceeds limit {}",
+            json_size(&msg),
+            max_payload_for_type("PresenceSubscribe")
+        );
+
+        let presence = PresenceInfo {
+            username: str_of_len(MAX_USERNAME_LENGTH),
+            online: false,
+            is_away: false,
+            status: Some(str_of_len(MAX_STATUS_LENGTH)),
+        };
+        let server_messages = [
+            (
+                ServerMessage::PresenceSubscribeResponse {
+                    success: false,
+                    error: Some(str_of_len(MAX_ERROR_LENGTH)),
+                    users: Some(vec![presence.clone(); MAX_PRESENCE_SUBSCRIPTIONS]),
+                },
+                "PresenceSubscribeResponse",
+            ),
+            (
+                ServerMessage::PresenceUpdate { user: presence },
+                "PresenceUpdate",
+            ),
+        ];
+        for (msg, name) in server_messages {
+            assert!(
+                json_size(&msg) <= max_payload_for_type(name) as usize,
+                "{} size {} exceeds limit {}",
+                name,
+                json_size(&msg),
+                max_payload_for_type(name)
+            );
+        }
+    }
+
+    #[test]
+    fn test_limit_file_activity_responses() {
+        // Like FileSearchResponse, these can have long paths
+        assert_eq!(max_payload_for_type("FilePopularResponse"), 0);
+        assert_eq!(max_payload_for_type("FileRecentResponse"), 0);
+    }
+
     #[test]
     fn test_limit_file_search_response() {
         // FileSearchResponse is unlimited (0) since it can have many results with long paths
@@ -3080,6 +5701,11 @@ mod tests {
         let msg = ClientMessage::FileDownload {
             path: str_of_len(MAX_FILE_PATH_LENGTH),
             root: false,
+            include: Some(
+                (0..MAX_DOWNLOAD_INCLUDE_COUNT)
+                    .map(|_| str_of_len(MAX_FILE_PATH_LENGTH))
+                    .collect(),
+            ),
         };
         assert!(
             json_size(&msg) <= max_payload_for_type("FileDownload") as usize,
@@ -3090,6 +5716,104 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_limit_server_export() {
+        let msg = ClientMessage::ServerExport {
+            include_ip_rules: false,
+        };
+        assert!(
+            json_size(&msg) <= max_payload_for_type("ServerExport") as usize,
+            "{} size {} exceeds limit {}",
+            "ServerExport",
+            json_size(&msg),
+            max_payload_for_type("ServerExport")
+        );
+    }
+
+    #[test]
+    fn test_limit_file_manifest() {
+        let msg = ClientMessage::FileManifest {
+            path: str_of_len(MAX_FILE_PATH_LENGTH),
+            root: true,
+        };
+        assert!(
+            json_size(&msg) <= max_payload_for_type("FileManifest") as usize,
+            "{} size {} exceeds limit {}",
+            "FileManifest",
+            json_size(&msg),
+            max_payload_for_type("FileManifest")
+        );
+        assert_eq!(max_payload_for_type("FileManifestResponse"), 0);
+    }
+
+    #[test]
+    fn test_limit_file_stat() {
+        let msg = ClientMessage::FileStat {
+            files: (0..MAX_FILE_STAT_COUNT)
+                .map(|_| FileStatQuery {
+                    path: str_of_len(MAX_FILE_PATH_LENGTH),
+                    size: u64::MAX,
+                })
+                .collect(),
+            root: false,
+        };
+        assert!(
+            json_size(&msg) <= max_payload_for_type("FileStat") as usize,
+            "{} size {} exceeds limit {}",
+            "FileStat",
+            json_size(&msg),
+            max_payload_for_type("FileStat")
+        );
+        assert_eq!(max_payload_for_type("FileStatResponse"), 0);
+    }
+
+    #[test]
+    fn test_limit_cancel() {
+        let msg = ClientMessage::Cancel {
+            message_id: str_of_len(MSG_ID_LENGTH),
+        };
+        assert!(
+            json_size(&msg) <= max_payload_for_type("Cancel") as usize,
+            "{} size {} exceeds limit {}",
+            "Cancel",
+            json_size(&msg),
+            max_payload_for_type("Cancel")
+        );
+    }
+
+    #[test]
+    fn test_limit_file_watch() {
+        let msg = ClientMessage::FileWatch {
+            directories: (0..MAX_FILE_WATCH_COUNT)
+                .map(|_| FileWatchPath {
+                    path: str_of_len(MAX_FILE_PATH_LENGTH),
+                    root: false,
+                })
+                .collect(),
+        };
+        assert!(
+            json_size(&msg) <= max_payload_for_type("FileWatch") as usize,
+            "{} size {} exceeds limit {}",
+            "FileWatch",
+            json_size(&msg),
+            max_payload_for_type("FileWatch")
+        );
+
+        let msg = ServerMessage::FileWatchResponse {
+            success: false,
+            error: Some(str_of_len(MAX_ERROR_LENGTH)),
+        };
+        assert!(
+            json_size(&msg) <= max_payload_for_type("FileWatchResponse") as usize,
+            "{} size {} exceeds limit {}",
+            "FileWatchResponse",
+            json_size(&msg),
+            max_payload_for_type("FileWatchResponse")
+        );
+        // Carries names found on disk, so it's unlimited
+        assert_eq!(max_payload_for_type("FileChanged"), 0);
+    }
+
     #[test]
     fn test_limit_file_start_response() {
         // Max size: u64 + 64 char sha256 + overhead
@@ -3142,6 +5866,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_limit_transfer_queued() {
+        let msg = ServerMessage::TransferQueued { position: u32::MAX };
+        let size = json_size(&msg);
+        let limit = max_payload_for_type("TransferQueued") as usize;
+        assert!(
+            size <= limit,
+            "TransferQueued size {} exceeds limit {}",
+            size,
+            limit
+        );
+    }
+
     #[test]
     fn test_limit_file_data_unlimited() {
         // FileData has unlimited payload (streaming binary)
@@ -1,6 +1,8 @@
 //! Frame reader for parsing protocol messages from a stream
 
+use std::collections::HashMap;
 use std::io;
+use std::sync::Arc;
 use std::time::Duration;
 
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
@@ -45,12 +47,28 @@ pub struct FrameHeader {
 /// Reads protocol frames from an async reader
 pub struct FrameReader<R> {
     reader: R,
+    /// Tighter payload limits for some message types (by type name)
+    payload_limits: Option<Arc<HashMap<String, u64>>>,
 }
 
 impl<R> FrameReader<R> {
     /// Create a new frame reader
     pub fn new(reader: R) -> Self {
-        Self { reader }
+        Self {
+            reader,
+            payload_limits: None,
+        }
+    }
+
+    /// Tighten the payload limit for some message types
+    ///
+    /// A limit can only lower the protocol's own limit for its type (see
+    /// [`max_payload_for_type`]), never raise it. Frames over the limit are
+    /// rejected with [`FrameError::PayloadLengthExceedsTypeMax`] before their
+    /// payload is read.
+    pub fn with_payload_limits(mut self, limits: Arc<HashMap<String, u64>>) -> Self {
+        self.payload_limits = Some(limits);
+        self
     }
 
     /// Get a reference to the underlying reader
@@ -334,7 +352,7 @@ impl<R: AsyncReadExt + Unpin> FrameReader<R> {
             )
             .await?;
         // Validate payload length against per-type maximum (0 = unlimited)
-        let max_for_type = max_payload_for_type(&message_type);
+        let max_for_type = self.max_payload(&message_type);
         if max_for_type > 0 && payload_length > max_for_type {
             return Err(FrameError::PayloadLengthExceedsTypeMax {
                 message_type,
@@ -350,6 +368,20 @@ impl<R: AsyncReadExt + Unpin> FrameReader<R> {
         }))
     }
 
+    /// Maximum payload for a message type, with any tighter limit applied (0 = unlimited)
+    fn max_payload(&self, message_type: &str) -> u64 {
+        let max = max_payload_for_type(message_type);
+        match self
+            .payload_limits
+            .as_ref()
+            .and_then(|limits| limits.get(message_type))
+        {
+            Some(&limit) if max == 0 => limit,
+            Some(&limit) => max.min(limit),
+            None => max,
+        }
+    }
+
     /// Complete reading a frame after the first byte has been received
     async fn read_frame_after_first_byte(
         &mut self,
@@ -572,7 +604,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_frame_reader_payload_exceeds_type_max() {
-        // ChatSend has a base limit of 1119 bytes, padded 20% to 1342
+        // ChatSend has a base limit of 1204 bytes, padded 20% to 1444
         // Create a payload that claims to be 2000 bytes (well over limit)
         let data = b"NX|8|ChatSend|a1b2c3d4e5f6|2000|";
         let cursor = Cursor::new(data.as_slice());
@@ -585,9 +617,49 @@ mod tests {
             Err(FrameError::PayloadLengthExceedsTypeMax {
                 message_type,
                 length: 2000,
-                max: 1342  // 1119 * 1.2 = 1342
+                max: 1444  // 1204 * 1.2 = 1444
+            }) if message_type == "ChatSend"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_frame_reader_payload_limits() {
+        let limits = Arc::new(HashMap::from([
+            ("ChatSend".to_string(), 100),
+            ("Handshake".to_string(), 1000),
+        ]));
+
+        // A tighter limit applies
+        let data = b"NX|8|ChatSend|a1b2c3d4e5f6|101|";
+        let cursor = Cursor::new(data.as_slice());
+        let mut reader =
+            FrameReader::new(BufReader::new(cursor)).with_payload_limits(limits.clone());
+        let result = reader.read_frame().await;
+        assert!(matches!(
+            result,
+            Err(FrameError::PayloadLengthExceedsTypeMax {
+                message_type,
+                length: 101,
+                max: 100
             }) if message_type == "ChatSend"
         ));
+
+        // A looser limit doesn't raise the protocol's own
+        let data = b"NX|9|Handshake|a1b2c3d4e5f6|170|";
+        let cursor = Cursor::new(data.as_slice());
+        let mut reader =
+            FrameReader::new(BufReader::new(cursor)).with_payload_limits(limits.clone());
+        let result = reader.read_frame().await;
+        assert!(matches!(
+            result,
+            Err(FrameError::PayloadLengthExceedsTypeMax { max: 169, .. })
+        ));
+
+        // Types without a limit are unaffected
+        let data = b"NX|8|UserList|a1b2c3d4e5f6|2|{}\n";
+        let cursor = Cursor::new(data.as_slice());
+        let mut reader = FrameReader::new(BufReader::new(cursor)).with_payload_limits(limits);
+        assert!(reader.read_frame().await.unwrap().is_some());
     }
 
     #[tokio::test]
@@ -615,27 +687,27 @@ mod tests {
 
     #[tokio::test]
     async fn test_frame_reader_payload_at_type_limit() {
-        // Handshake has a base limit of 65 bytes, padded 20% to 78
-        // Create exactly 78 bytes of payload (at the padded limit)
-        let payload = format!("{{\"version\":\"{}\"}}", "x".repeat(78 - 14));
-        assert_eq!(payload.len(), 78);
-        let data = format!("NX|9|Handshake|a1b2c3d4e5f6|78|{}\n", payload);
+        // Handshake has a base limit of 141 bytes, padded 20% to 169
+        // Create exactly 169 bytes of payload (at the padded limit)
+        let payload = format!("{{\"version\":\"{}\"}}", "x".repeat(169 - 14));
+        assert_eq!(payload.len(), 169);
+        let data = format!("NX|9|Handshake|a1b2c3d4e5f6|169|{}\n", payload);
 
         let cursor = Cursor::new(data.as_bytes());
         let buf_reader = BufReader::new(cursor);
         let mut reader = FrameReader::new(buf_reader);
 
         let frame = reader.read_frame().await.unwrap().unwrap();
-        assert_eq!(frame.payload.len(), 78);
+        assert_eq!(frame.payload.len(), 169);
     }
 
     #[tokio::test]
     async fn test_frame_reader_payload_one_over_type_limit() {
-        // Handshake has a base limit of 65 bytes, padded 20% to 78
-        // Create 79 bytes of payload (one over padded limit)
-        let payload = format!("{{\"version\":\"{}\"}}", "x".repeat(79 - 14));
-        assert_eq!(payload.len(), 79);
-        let data = format!("NX|9|Handshake|a1b2c3d4e5f6|79|{}\n", payload);
+        // Handshake has a base limit of 141 bytes, padded 20% to 169
+        // Create 170 bytes of payload (one over padded limit)
+        let payload = format!("{{\"version\":\"{}\"}}", "x".repeat(170 - 14));
+        assert_eq!(payload.len(), 170);
+        let data = format!("NX|9|Handshake|a1b2c3d4e5f6|170|{}\n", payload);
 
         let cursor = Cursor::new(data.as_bytes());
         let buf_reader = BufReader::new(cursor);
@@ -646,8 +718,8 @@ mod tests {
             result,
             Err(FrameError::PayloadLengthExceedsTypeMax {
                 message_type,
-                length: 79,
-                max: 78  // 65 * 1.2 = 78
+                length: 170,
+                max: 169  // 141 * 1.2 = 169
             }) if message_type == "Handshake"
         ));
     }
@@ -56,8 +56,18 @@ impl<W: AsyncWriteExt + Unpin> FrameWriter<W> {
     ///
     /// Returns an error if an I/O error occurs.
     pub async fn write_frame(&mut self, frame: &RawFrame) -> Result<(), FrameError> {
-        let bytes = frame.to_bytes();
-        self.writer.write_all(&bytes).await?;
+        self.write_encoded(&frame.to_bytes()).await
+    }
+
+    /// Write a frame that is already encoded (see [`RawFrame::to_bytes`])
+    ///
+    /// Lets a broadcast be encoded once and written to every recipient.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an I/O error occurs.
+    pub async fn write_encoded(&mut self, frame: &[u8]) -> Result<(), FrameError> {
+        self.writer.write_all(frame).await?;
         self.writer.flush().await?;
         Ok(())
     }
@@ -170,6 +180,21 @@ mod tests {
         assert_eq!(buffer, expected.as_bytes());
     }
 
+    #[tokio::test]
+    async fn test_frame_writer_encoded() {
+        let mut buffer = Vec::new();
+        let cursor = Cursor::new(&mut buffer);
+        let id = MessageId::new();
+        let frame = RawFrame::new(id, "ChatSend".to_string(), b"{\"message\":\"Hi\"}".to_vec());
+
+        {
+            let mut writer = FrameWriter::new(cursor);
+            writer.write_encoded(&frame.to_bytes()).await.unwrap();
+        }
+
+        assert_eq!(buffer, frame.to_bytes());
+    }
+
     #[tokio::test]
     async fn test_frame_writer_convenience_method() {
         let mut buffer = Vec::new();
@@ -0,0 +1,46 @@
+//! Server identity statements
+//!
+//! Besides its TLS certificate, a server has a long-term Ed25519 identity key.
+//! During the handshake it sends the public key and a signature over a
+//! statement naming its current certificate fingerprint. A client that pinned
+//! the identity key can then accept a rotated certificate without asking the
+//! user, while a man-in-the-middle (who cannot sign for the pinned key) is
+//! still caught by the certificate fingerprint check.
+
+/// Length of a hex-encoded Ed25519 public key
+pub const IDENTITY_KEY_LENGTH: usize = 64;
+
+/// Length of a hex-encoded Ed25519 signature
+pub const IDENTITY_SIGNATURE_LENGTH: usize = 128;
+
+/// Prefix of the signed statement (versioned so the format can change)
+const IDENTITY_STATEMENT_PREFIX: &str = "nexus-identity-v1:";
+
+/// Build the statement a server signs to bind a certificate to its identity key
+///
+/// The fingerprint is the SHA-256 of the certificate, as hex. Colons and case
+/// are ignored, so `AA:BB:...` and `aabb...` give the same statement.
+#[must_use]
+pub fn identity_statement(certificate_fingerprint: &str) -> Vec<u8> {
+    let normalized: String = certificate_fingerprint
+        .chars()
+        .filter(|c| *c != ':')
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    format!("{IDENTITY_STATEMENT_PREFIX}{normalized}").into_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identity_statement() {
+        assert_eq!(
+            identity_statement("AA:BB:0c"),
+            b"nexus-identity-v1:AABB0C".to_vec()
+        );
+        assert_eq!(identity_statement("aabb0c"), identity_statement("AA:BB:0C"));
+        assert_ne!(identity_statement("AA:BB"), identity_statement("AA:BC"));
+    }
+}
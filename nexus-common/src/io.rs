@@ -4,6 +4,7 @@
 //! (`ClientMessage`, `ServerMessage`) and the wire format (framing).
 
 use std::io;
+use std::sync::Arc;
 use std::time::Duration;
 
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
@@ -104,6 +105,31 @@ where
     writer.write_frame(&frame).await.map_err(Into::into)
 }
 
+/// Encode a `ServerMessage` as a complete frame
+///
+/// Broadcasts are encoded once and the frame is shared by every recipient
+/// (see `send_encoded_frame`), instead of serializing the message per
+/// recipient. Every recipient sees the same message ID.
+pub fn encode_server_message(
+    message: &ServerMessage,
+    message_id: MessageId,
+) -> io::Result<Arc<[u8]>> {
+    let message_type = server_message_type(message);
+    let payload =
+        serde_json::to_vec(message).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    let frame = RawFrame::new(message_id, message_type.to_string(), payload);
+    Ok(frame.to_bytes().into())
+}
+
+/// Send a frame encoded by `encode_server_message`
+pub async fn send_encoded_frame<W>(writer: &mut FrameWriter<W>, frame: &[u8]) -> io::Result<()>
+where
+    W: AsyncWriteExt + Unpin,
+{
+    writer.write_encoded(frame).await.map_err(Into::into)
+}
+
 // =============================================================================
 // Message Receiving
 // =============================================================================
@@ -288,12 +314,20 @@ pub fn client_message_type(message: &ClientMessage) -> &'static str {
         ClientMessage::ChatLeave { .. } => "ChatLeave",
         ClientMessage::ChatList { .. } => "ChatList",
         ClientMessage::ChatSecret { .. } => "ChatSecret",
+        ClientMessage::ChatTopicLock { .. } => "ChatTopicLock",
+        ClientMessage::ChatTopicHistory { .. } => "ChatTopicHistory",
+        ClientMessage::ChatEntryUpdate { .. } => "ChatEntryUpdate",
+        ClientMessage::ChatLanguage { .. } => "ChatLanguage",
         ClientMessage::Handshake { .. } => "Handshake",
         ClientMessage::Login { .. } => "Login",
         ClientMessage::UserBroadcast { .. } => "UserBroadcast",
+        ClientMessage::BroadcastAck { .. } => "BroadcastAck",
         ClientMessage::UserCreate { .. } => "UserCreate",
         ClientMessage::UserDelete { .. } => "UserDelete",
+        ClientMessage::UserErase { .. } => "UserErase",
         ClientMessage::UserEdit { .. } => "UserEdit",
+        ClientMessage::PermissionAudit { .. } => "PermissionAudit",
+        ClientMessage::ImpersonateQuery { .. } => "ImpersonateQuery",
         ClientMessage::UserInfo { .. } => "UserInfo",
         ClientMessage::UserKick { .. } => "UserKick",
         ClientMessage::UserList { .. } => "UserList",
@@ -302,6 +336,14 @@ pub fn client_message_type(message: &ClientMessage) -> &'static str {
         ClientMessage::UserAway { .. } => "UserAway",
         ClientMessage::UserBack => "UserBack",
         ClientMessage::UserStatus { .. } => "UserStatus",
+        ClientMessage::UserDnd { .. } => "UserDnd",
+        ClientMessage::MessageHistory { .. } => "MessageHistory",
+        ClientMessage::MessageHistoryRetention { .. } => "MessageHistoryRetention",
+        ClientMessage::MessageMarkRead { .. } => "MessageMarkRead",
+        ClientMessage::GroupMessageCreate { .. } => "GroupMessageCreate",
+        ClientMessage::GroupMessageSend { .. } => "GroupMessageSend",
+        ClientMessage::GroupMessageInvite { .. } => "GroupMessageInvite",
+        ClientMessage::GroupMessageLeave { .. } => "GroupMessageLeave",
         ClientMessage::ServerInfoUpdate { .. } => "ServerInfoUpdate",
         ClientMessage::NewsList => "NewsList",
         ClientMessage::NewsShow { .. } => "NewsShow",
@@ -309,16 +351,23 @@ pub fn client_message_type(message: &ClientMessage) -> &'static str {
         ClientMessage::NewsEdit { .. } => "NewsEdit",
         ClientMessage::NewsUpdate { .. } => "NewsUpdate",
         ClientMessage::NewsDelete { .. } => "NewsDelete",
+        ClientMessage::NewsSearch { .. } => "NewsSearch",
+        ClientMessage::SnippetCreate { .. } => "SnippetCreate",
+        ClientMessage::SnippetGet { .. } => "SnippetGet",
         ClientMessage::FileList { .. } => "FileList",
         ClientMessage::FileCreateDir { .. } => "FileCreateDir",
         ClientMessage::FileDelete { .. } => "FileDelete",
         ClientMessage::FileInfo { .. } => "FileInfo",
+        ClientMessage::FileManifest { .. } => "FileManifest",
+        ClientMessage::FileStat { .. } => "FileStat",
+        ClientMessage::FileWatch { .. } => "FileWatch",
         ClientMessage::FileRename { .. } => "FileRename",
         ClientMessage::FileMove { .. } => "FileMove",
         ClientMessage::FileCopy { .. } => "FileCopy",
         ClientMessage::FileDownload { .. } => "FileDownload",
         ClientMessage::FileStartResponse { .. } => "FileStartResponse",
         ClientMessage::FileUpload { .. } => "FileUpload",
+        ClientMessage::ServerExport { .. } => "ServerExport",
         ClientMessage::FileStart { .. } => "FileStart",
         ClientMessage::FileData => "FileData",
         ClientMessage::FileHashing { .. } => "FileHashing",
@@ -329,11 +378,47 @@ pub fn client_message_type(message: &ClientMessage) -> &'static str {
         ClientMessage::TrustDelete { .. } => "TrustDelete",
         ClientMessage::TrustList => "TrustList",
         ClientMessage::ConnectionMonitor => "ConnectionMonitor",
+        ClientMessage::LogSubscribe => "LogSubscribe",
+        ClientMessage::LogUnsubscribe => "LogUnsubscribe",
+        ClientMessage::NatStatus => "NatStatus",
+        ClientMessage::ServerStats => "ServerStats",
+        ClientMessage::ConnectivityCheck => "ConnectivityCheck",
+        ClientMessage::StatsRequest { .. } => "StatsRequest",
+        ClientMessage::Leaderboard { .. } => "Leaderboard",
+        ClientMessage::DoorList => "DoorList",
+        ClientMessage::DoorOpen { .. } => "DoorOpen",
+        ClientMessage::DoorData { .. } => "DoorData",
+        ClientMessage::DoorClose => "DoorClose",
         ClientMessage::FileSearch { .. } => "FileSearch",
         ClientMessage::FileReindex => "FileReindex",
+        ClientMessage::FileCleanup { .. } => "FileCleanup",
+        ClientMessage::FileCheck { .. } => "FileCheck",
+        ClientMessage::FilePopular { .. } => "FilePopular",
+        ClientMessage::FileRecent { .. } => "FileRecent",
+        ClientMessage::FileRate { .. } => "FileRate",
+        ClientMessage::FileCommentCreate { .. } => "FileCommentCreate",
+        ClientMessage::FileCommentList { .. } => "FileCommentList",
+        ClientMessage::FileCommentDelete { .. } => "FileCommentDelete",
+        ClientMessage::UploadRuleGet { .. } => "UploadRuleGet",
+        ClientMessage::UploadRuleSet { .. } => "UploadRuleSet",
+        ClientMessage::LdapGroupList => "LdapGroupList",
+        ClientMessage::LdapGroupSet { .. } => "LdapGroupSet",
+        ClientMessage::LdapGroupDelete { .. } => "LdapGroupDelete",
+        ClientMessage::GuestUpgrade { .. } => "GuestUpgrade",
+        ClientMessage::NicknameRegister { .. } => "NicknameRegister",
+        ClientMessage::NicknameRelease { .. } => "NicknameRelease",
+        ClientMessage::PresenceSubscribe { .. } => "PresenceSubscribe",
         ClientMessage::VoiceJoin { .. } => "VoiceJoin",
         ClientMessage::VoiceLeave => "VoiceLeave",
+        ClientMessage::VoiceLimit { .. } => "VoiceLimit",
+        ClientMessage::VoiceRelay { .. } => "VoiceRelay",
+        ClientMessage::VoiceClipList => "VoiceClipList",
+        ClientMessage::VoiceClipUpload { .. } => "VoiceClipUpload",
+        ClientMessage::VoiceClipDelete { .. } => "VoiceClipDelete",
+        ClientMessage::VoiceClipPlay { .. } => "VoiceClipPlay",
+        ClientMessage::VoiceTextSend { .. } => "VoiceTextSend",
         ClientMessage::Ping => "Ping",
+        ClientMessage::Cancel { .. } => "Cancel",
     }
 }
 
@@ -343,11 +428,16 @@ pub fn server_message_type(message: &ServerMessage) -> &'static str {
     match message {
         ServerMessage::ChatMessage { .. } => "ChatMessage",
         ServerMessage::ChatUpdated { .. } => "ChatUpdated",
+        ServerMessage::ChatSendResponse { .. } => "ChatSendResponse",
         ServerMessage::ChatTopicUpdateResponse { .. } => "ChatTopicUpdateResponse",
         ServerMessage::ChatJoinResponse { .. } => "ChatJoinResponse",
         ServerMessage::ChatLeaveResponse { .. } => "ChatLeaveResponse",
         ServerMessage::ChatListResponse { .. } => "ChatListResponse",
         ServerMessage::ChatSecretResponse { .. } => "ChatSecretResponse",
+        ServerMessage::ChatTopicLockResponse { .. } => "ChatTopicLockResponse",
+        ServerMessage::ChatTopicHistoryResponse { .. } => "ChatTopicHistoryResponse",
+        ServerMessage::ChatEntryUpdateResponse { .. } => "ChatEntryUpdateResponse",
+        ServerMessage::ChatLanguageResponse { .. } => "ChatLanguageResponse",
         ServerMessage::ChatUserJoined { .. } => "ChatUserJoined",
         ServerMessage::ChatUserLeft { .. } => "ChatUserLeft",
         ServerMessage::Error { .. } => "Error",
@@ -356,10 +446,14 @@ pub fn server_message_type(message: &ServerMessage) -> &'static str {
         ServerMessage::PermissionsUpdated { .. } => "PermissionsUpdated",
         ServerMessage::ServerBroadcast { .. } => "ServerBroadcast",
         ServerMessage::UserBroadcastResponse { .. } => "UserBroadcastResponse",
+        ServerMessage::BroadcastStatus { .. } => "BroadcastStatus",
         ServerMessage::UserConnected { .. } => "UserConnected",
         ServerMessage::UserCreateResponse { .. } => "UserCreateResponse",
         ServerMessage::UserDeleteResponse { .. } => "UserDeleteResponse",
+        ServerMessage::UserEraseResponse { .. } => "UserEraseResponse",
         ServerMessage::UserDisconnected { .. } => "UserDisconnected",
+        ServerMessage::PermissionAuditResponse { .. } => "PermissionAuditResponse",
+        ServerMessage::ImpersonateQueryResponse { .. } => "ImpersonateQueryResponse",
         ServerMessage::UserEditResponse { .. } => "UserEditResponse",
         ServerMessage::UserInfoResponse { .. } => "UserInfoResponse",
         ServerMessage::UserKickResponse { .. } => "UserKickResponse",
@@ -370,6 +464,17 @@ pub fn server_message_type(message: &ServerMessage) -> &'static str {
         ServerMessage::UserAwayResponse { .. } => "UserAwayResponse",
         ServerMessage::UserBackResponse { .. } => "UserBackResponse",
         ServerMessage::UserStatusResponse { .. } => "UserStatusResponse",
+        ServerMessage::UserDndResponse { .. } => "UserDndResponse",
+        ServerMessage::MessageHistoryResponse { .. } => "MessageHistoryResponse",
+        ServerMessage::MessageHistoryRetentionResponse { .. } => "MessageHistoryRetentionResponse",
+        ServerMessage::MessageDelivered { .. } => "MessageDelivered",
+        ServerMessage::MessageRead { .. } => "MessageRead",
+        ServerMessage::GroupMessageCreateResponse { .. } => "GroupMessageCreateResponse",
+        ServerMessage::GroupMessageSendResponse { .. } => "GroupMessageSendResponse",
+        ServerMessage::GroupMessageInviteResponse { .. } => "GroupMessageInviteResponse",
+        ServerMessage::GroupMessageLeaveResponse { .. } => "GroupMessageLeaveResponse",
+        ServerMessage::GroupMessage { .. } => "GroupMessage",
+        ServerMessage::GroupMembers { .. } => "GroupMembers",
         ServerMessage::UserUpdateResponse { .. } => "UserUpdateResponse",
         ServerMessage::ServerInfoUpdated { .. } => "ServerInfoUpdated",
         ServerMessage::ServerInfoUpdateResponse { .. } => "ServerInfoUpdateResponse",
@@ -380,10 +485,17 @@ pub fn server_message_type(message: &ServerMessage) -> &'static str {
         ServerMessage::NewsUpdateResponse { .. } => "NewsUpdateResponse",
         ServerMessage::NewsDeleteResponse { .. } => "NewsDeleteResponse",
         ServerMessage::NewsUpdated { .. } => "NewsUpdated",
+        ServerMessage::NewsSearchResponse { .. } => "NewsSearchResponse",
+        ServerMessage::SnippetCreateResponse { .. } => "SnippetCreateResponse",
+        ServerMessage::SnippetGetResponse { .. } => "SnippetGetResponse",
         ServerMessage::FileListResponse { .. } => "FileListResponse",
         ServerMessage::FileCreateDirResponse { .. } => "FileCreateDirResponse",
         ServerMessage::FileDeleteResponse { .. } => "FileDeleteResponse",
         ServerMessage::FileInfoResponse { .. } => "FileInfoResponse",
+        ServerMessage::FileManifestResponse { .. } => "FileManifestResponse",
+        ServerMessage::FileStatResponse { .. } => "FileStatResponse",
+        ServerMessage::FileWatchResponse { .. } => "FileWatchResponse",
+        ServerMessage::FileChanged { .. } => "FileChanged",
         ServerMessage::FileRenameResponse { .. } => "FileRenameResponse",
         ServerMessage::FileMoveResponse { .. } => "FileMoveResponse",
         ServerMessage::FileCopyResponse { .. } => "FileCopyResponse",
@@ -394,6 +506,7 @@ pub fn server_message_type(message: &ServerMessage) -> &'static str {
         ServerMessage::FileStartResponse { .. } => "FileStartResponse",
         ServerMessage::TransferComplete { .. } => "TransferComplete",
         ServerMessage::FileHashing { .. } => "FileHashing",
+        ServerMessage::TransferQueued { .. } => "TransferQueued",
         ServerMessage::BanCreateResponse { .. } => "BanCreateResponse",
         ServerMessage::BanDeleteResponse { .. } => "BanDeleteResponse",
         ServerMessage::BanListResponse { .. } => "BanListResponse",
@@ -401,13 +514,56 @@ pub fn server_message_type(message: &ServerMessage) -> &'static str {
         ServerMessage::TrustDeleteResponse { .. } => "TrustDeleteResponse",
         ServerMessage::TrustListResponse { .. } => "TrustListResponse",
         ServerMessage::ConnectionMonitorResponse { .. } => "ConnectionMonitorResponse",
+        ServerMessage::LogSubscribeResponse { .. } => "LogSubscribeResponse",
+        ServerMessage::LogLine { .. } => "LogLine",
+        ServerMessage::NatStatusResponse { .. } => "NatStatusResponse",
+        ServerMessage::ServerStatsResponse { .. } => "ServerStatsResponse",
+        ServerMessage::StorageAlert { .. } => "StorageAlert",
+        ServerMessage::ConnectivityCheckResponse { .. } => "ConnectivityCheckResponse",
+        ServerMessage::StatsResponse { .. } => "StatsResponse",
+        ServerMessage::LeaderboardResponse { .. } => "LeaderboardResponse",
+        ServerMessage::DoorListResponse { .. } => "DoorListResponse",
+        ServerMessage::DoorOpenResponse { .. } => "DoorOpenResponse",
+        ServerMessage::DoorData { .. } => "DoorData",
+        ServerMessage::DoorClosed { .. } => "DoorClosed",
         ServerMessage::FileSearchResponse { .. } => "FileSearchResponse",
         ServerMessage::FileReindexResponse { .. } => "FileReindexResponse",
+        ServerMessage::FileCleanupResponse { .. } => "FileCleanupResponse",
+        ServerMessage::FileCheckIssue { .. } => "FileCheckIssue",
+        ServerMessage::FileCheckResponse { .. } => "FileCheckResponse",
+        ServerMessage::FilePopularResponse { .. } => "FilePopularResponse",
+        ServerMessage::FileRecentResponse { .. } => "FileRecentResponse",
+        ServerMessage::FileRateResponse { .. } => "FileRateResponse",
+        ServerMessage::FileCommentCreateResponse { .. } => "FileCommentCreateResponse",
+        ServerMessage::FileCommentListResponse { .. } => "FileCommentListResponse",
+        ServerMessage::FileCommentDeleteResponse { .. } => "FileCommentDeleteResponse",
+        ServerMessage::UploadRuleGetResponse { .. } => "UploadRuleGetResponse",
+        ServerMessage::UploadRuleSetResponse { .. } => "UploadRuleSetResponse",
+        ServerMessage::LdapGroupListResponse { .. } => "LdapGroupListResponse",
+        ServerMessage::LdapGroupSetResponse { .. } => "LdapGroupSetResponse",
+        ServerMessage::LdapGroupDeleteResponse { .. } => "LdapGroupDeleteResponse",
+        ServerMessage::GuestUpgradeResponse { .. } => "GuestUpgradeResponse",
+        ServerMessage::NicknameRegisterResponse { .. } => "NicknameRegisterResponse",
+        ServerMessage::NicknameReleaseResponse { .. } => "NicknameReleaseResponse",
+        ServerMessage::PresenceSubscribeResponse { .. } => "PresenceSubscribeResponse",
+        ServerMessage::PresenceUpdate { .. } => "PresenceUpdate",
         ServerMessage::VoiceJoinResponse { .. } => "VoiceJoinResponse",
         ServerMessage::VoiceLeaveResponse { .. } => "VoiceLeaveResponse",
+        ServerMessage::VoiceLimitResponse { .. } => "VoiceLimitResponse",
+        ServerMessage::VoiceQueueUpdate { .. } => "VoiceQueueUpdate",
         ServerMessage::VoiceUserJoined { .. } => "VoiceUserJoined",
+        ServerMessage::VoiceBroadcastUpdate { .. } => "VoiceBroadcastUpdate",
         ServerMessage::VoiceUserLeft { .. } => "VoiceUserLeft",
-        ServerMessage::Pong => "Pong",
+        ServerMessage::VoiceRelay { .. } => "VoiceRelay",
+        ServerMessage::VoiceStats { .. } => "VoiceStats",
+        ServerMessage::VoiceClipListResponse { .. } => "VoiceClipListResponse",
+        ServerMessage::VoiceClipUploadResponse { .. } => "VoiceClipUploadResponse",
+        ServerMessage::VoiceClipDeleteResponse { .. } => "VoiceClipDeleteResponse",
+        ServerMessage::VoiceClipPlayResponse { .. } => "VoiceClipPlayResponse",
+        ServerMessage::VoiceClipPlayed { .. } => "VoiceClipPlayed",
+        ServerMessage::VoiceTextSendResponse { .. } => "VoiceTextSendResponse",
+        ServerMessage::VoiceText { .. } => "VoiceText",
+        ServerMessage::Pong { .. } => "Pong",
     }
 }
 
@@ -430,12 +586,14 @@ mod tests {
                 message: "hi".to_string(),
                 action: ChatAction::Normal,
                 channel: DEFAULT_CHANNEL.to_string(),
+                idempotency_key: None,
             }),
             "ChatSend"
         );
         assert_eq!(
             client_message_type(&ClientMessage::Handshake {
-                version: "0.4.0".to_string()
+                version: "0.4.0".to_string(),
+                client: None,
             }),
             "Handshake"
         );
@@ -464,6 +622,7 @@ mod tests {
             server_message_type(&ServerMessage::Error {
                 message: "error".to_string(),
                 command: None,
+                error_kind: None,
             }),
             "Error"
         );
@@ -475,6 +634,7 @@ mod tests {
             message: "Hello, world!".to_string(),
             action: ChatAction::Normal,
             channel: DEFAULT_CHANNEL.to_string(),
+            idempotency_key: None,
         };
 
         // Write the message
@@ -499,6 +659,51 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_send_encoded_server_message() {
+        let message = ServerMessage::ChatMessage {
+            session_id: 42,
+            nickname: "alice".to_string(),
+            is_admin: false,
+            is_shared: false,
+            message: "Hi there!".to_string(),
+            action: ChatAction::Normal,
+            channel: DEFAULT_CHANNEL.to_string(),
+            timestamp: 0,
+        };
+        let message_id = MessageId::new();
+        let frame = encode_server_message(&message, message_id).unwrap();
+
+        // The shared frame matches what sending the message writes
+        let mut direct = Vec::new();
+        send_server_message_with_id(
+            &mut FrameWriter::new(Cursor::new(&mut direct)),
+            &message,
+            message_id,
+        )
+        .await
+        .unwrap();
+        assert_eq!(&*frame, direct.as_slice());
+
+        // Write it twice, as for two recipients, and read both back
+        let mut buffer = Vec::new();
+        {
+            let mut writer = FrameWriter::new(Cursor::new(&mut buffer));
+            send_encoded_frame(&mut writer, &frame).await.unwrap();
+            send_encoded_frame(&mut writer, &frame).await.unwrap();
+        }
+
+        let mut reader = FrameReader::new(BufReader::new(Cursor::new(buffer)));
+        for _ in 0..2 {
+            let received = read_server_message(&mut reader).await.unwrap().unwrap();
+            assert_eq!(received.message_id, message_id);
+            match received.message {
+                ServerMessage::ChatMessage { message, .. } => assert_eq!(message, "Hi there!"),
+                _ => panic!("Wrong message type"),
+            }
+        }
+    }
+
     #[tokio::test]
     async fn test_send_and_receive_server_message() {
         use crate::validators::DEFAULT_CHANNEL;
@@ -551,6 +756,7 @@ mod tests {
     async fn test_message_id_correlation() {
         let message = ClientMessage::Handshake {
             version: "0.4.0".to_string(),
+            client: None,
         };
 
         // Write the message and capture the ID
@@ -577,6 +783,11 @@ mod tests {
             success: true,
             version: Some("0.4.0".to_string()),
             error: None,
+            identity_key: None,
+            identity_signature: None,
+            pow_challenge: None,
+            pow_difficulty: None,
+            oidc: None,
         };
         let specific_id = MessageId::new();
 
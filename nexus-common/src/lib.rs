@@ -5,7 +5,9 @@
 mod error_kind;
 pub mod framing;
 pub mod hash;
+pub mod identity;
 pub mod io;
+pub mod pow;
 pub mod protocol;
 pub mod time;
 pub mod validators;
@@ -13,9 +15,10 @@ pub mod version;
 pub mod voice;
 
 pub use error_kind::{
-    ERROR_KIND_CONFLICT, ERROR_KIND_EXISTS, ERROR_KIND_HASH_MISMATCH, ERROR_KIND_INVALID,
-    ERROR_KIND_INVALID_PATH, ERROR_KIND_IO_ERROR, ERROR_KIND_NOT_FOUND, ERROR_KIND_PERMISSION,
-    ERROR_KIND_PROTOCOL_ERROR, ErrorKind,
+    ERROR_KIND_CONFLICT, ERROR_KIND_EXISTS, ERROR_KIND_HASH_MISMATCH,
+    ERROR_KIND_INSUFFICIENT_STORAGE, ERROR_KIND_INVALID, ERROR_KIND_INVALID_PATH,
+    ERROR_KIND_IO_ERROR, ERROR_KIND_MESSAGE_TOO_LONG, ERROR_KIND_NOT_FOUND, ERROR_KIND_PERMISSION,
+    ERROR_KIND_PROTOCOL_ERROR, ERROR_KIND_UPLOAD_RULE, ErrorKind,
 };
 
 /// Version information for the Nexus protocol
@@ -33,9 +36,21 @@ pub const DEFAULT_WEBSOCKET_PORT: u16 = 7502;
 /// Default port for WebSocket file transfers
 pub const DEFAULT_TRANSFER_WEBSOCKET_PORT: u16 = 7503;
 
+/// DNS-SD service type servers advertise on the local network (mDNS)
+pub const MDNS_SERVICE_TYPE: &str = "_nexus._tcp.local.";
+
+/// mDNS TXT record key holding the server's display name
+pub const MDNS_TXT_NAME: &str = "name";
+
+/// mDNS TXT record key holding the server's software version
+pub const MDNS_TXT_VERSION: &str = "version";
+
 /// Buffer size for SHA-256 hashing operations (1MB for fewer syscalls)
 pub const HASH_BUFFER_SIZE: usize = 1024 * 1024;
 
+/// Most terminal bytes carried by one DoorData message (before base64 encoding)
+pub const MAX_DOOR_DATA_SIZE: usize = 4096;
+
 /// Default port as a string for form fields and display.
 ///
 /// This is the string representation of [`DEFAULT_PORT`], provided as a constant
@@ -52,13 +67,18 @@ pub const DEFAULT_PORT_STR: &str = "7500";
 /// - `ban_delete`: Remove IP bans
 /// - `ban_list`: View list of active bans
 /// - `chat_create`: Create new chat channels
+/// - `chat_entry_edit`: Set channel entry messages and rules
 /// - `chat_join`: Join existing chat channels
+/// - `chat_language`: Set the language tag of channels
 /// - `chat_list`: View list of available channels
 /// - `chat_receive`: Receive chat messages in chat channels
 /// - `chat_secret`: Toggle secret mode on channels
 /// - `chat_send`: Send chat messages to chat channels
 /// - `chat_topic`: View the server topic
 /// - `chat_topic_edit`: Edit the server topic
+/// - `chat_topic_lock`: Lock channel topics, and change locked topics
+/// - `door_play`: Run the server's door programs
+/// - `file_comment`: Rate and comment on files
 /// - `file_copy`: Copy files and directories
 /// - `file_create_dir`: Create directories anywhere in file area
 /// - `file_delete`: Delete files and empty directories
@@ -69,6 +89,7 @@ pub const DEFAULT_PORT_STR: &str = "7500";
 /// - `file_rename`: Rename files and directories
 /// - `file_root`: Browse entire file area from root (for admins/file managers)
 /// - `file_upload`: Upload files to upload/dropbox folders
+/// - `log_view`: Follow the server log
 /// - `news_create`: Create news posts
 /// - `news_delete`: Delete any news post (without: only own posts)
 /// - `news_edit`: Edit any news post (without: only own posts)
@@ -84,6 +105,9 @@ pub const DEFAULT_PORT_STR: &str = "7500";
 /// - `user_kick`: Kick/disconnect users
 /// - `user_list`: View the list of connected users
 /// - `user_message`: Send user messages
+/// - `voice_broadcast`: Broadcast audio to a channel's listeners
+/// - `voice_clip_upload`: Upload and delete own soundboard clips
+/// - `voice_limit`: Set the voice participant limit of a channel
 /// - `voice_listen`: Receive audio from others in voice chat
 /// - `voice_talk`: Transmit audio in voice chat
 pub const ALL_PERMISSIONS: &[&str] = &[
@@ -91,14 +115,19 @@ pub const ALL_PERMISSIONS: &[&str] = &[
     "ban_delete",
     "ban_list",
     "chat_create",
+    "chat_entry_edit",
     "chat_join",
+    "chat_language",
     "chat_list",
     "chat_receive",
     "chat_secret",
     "chat_send",
     "chat_topic",
     "chat_topic_edit",
+    "chat_topic_lock",
     "connection_monitor",
+    "door_play",
+    "file_comment",
     "file_copy",
     "file_create_dir",
     "file_delete",
@@ -111,6 +140,7 @@ pub const ALL_PERMISSIONS: &[&str] = &[
     "file_root",
     "file_search",
     "file_upload",
+    "log_view",
     "news_create",
     "news_delete",
     "news_edit",
@@ -126,6 +156,9 @@ pub const ALL_PERMISSIONS: &[&str] = &[
     "user_kick",
     "user_list",
     "user_message",
+    "voice_broadcast",
+    "voice_clip_upload",
+    "voice_limit",
     "voice_listen",
     "voice_talk",
 ];
@@ -202,6 +235,56 @@ pub fn is_shared_account_permission(permission: &str) -> bool {
     SHARED_ACCOUNT_PERMISSIONS.contains(&permission)
 }
 
+/// Permissions that can be granted to the guest account.
+///
+/// The guest account is a shared account anyone can log in to without a
+/// password, so it gets a narrower set: guests can read and chat in public,
+/// but can't message users privately, talk in voice, upload files or see the
+/// moderation lists.
+///
+/// Allowed permissions:
+/// - `chat_join`: Join existing chat channels
+/// - `chat_list`: View list of available channels
+/// - `chat_receive`: Receive chat messages in chat channels
+/// - `chat_send`: Send chat messages to chat channels
+/// - `chat_topic`: View the server topic (but not edit)
+/// - `file_download`: Download files from file area
+/// - `file_info`: View detailed file/directory information
+/// - `file_list`: Browse files and directories (read-only)
+/// - `file_search`: Search files in the file area
+/// - `news_list`: View news posts (but not create/edit/delete)
+/// - `user_info`: View detailed user information
+/// - `user_list`: View the list of connected users
+/// - `voice_listen`: Receive audio from others in voice chat
+pub const GUEST_ACCOUNT_PERMISSIONS: &[&str] = &[
+    "chat_join",
+    "chat_list",
+    "chat_receive",
+    "chat_send",
+    "chat_topic",
+    "file_download",
+    "file_info",
+    "file_list",
+    "file_search",
+    "news_list",
+    "user_info",
+    "user_list",
+    "voice_listen",
+];
+
+/// Check if a permission is allowed for the guest account
+///
+/// # Arguments
+///
+/// * `permission` - The permission string to check (e.g., "chat_send")
+///
+/// # Returns
+///
+/// `true` if the permission is allowed for the guest account, `false` otherwise.
+pub fn is_guest_account_permission(permission: &str) -> bool {
+    GUEST_ACCOUNT_PERMISSIONS.contains(&permission)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -246,8 +329,8 @@ mod tests {
 
     #[test]
     fn test_all_permissions_count() {
-        // Verify we have the expected number of permissions (41)
-        assert_eq!(ALL_PERMISSIONS.len(), 41);
+        // Verify we have the expected number of permissions (49)
+        assert_eq!(ALL_PERMISSIONS.len(), 50);
     }
 
     #[test]
@@ -287,6 +370,9 @@ mod tests {
         assert!(!is_shared_account_permission("user_kick"));
         assert!(!is_shared_account_permission("user_broadcast"));
         assert!(!is_shared_account_permission("chat_topic_edit"));
+        assert!(!is_shared_account_permission("chat_topic_lock"));
+        assert!(!is_shared_account_permission("chat_entry_edit"));
+        assert!(!is_shared_account_permission("chat_language"));
         assert!(!is_shared_account_permission("news_create"));
         assert!(!is_shared_account_permission("news_edit"));
         assert!(!is_shared_account_permission("news_delete"));
@@ -296,6 +382,7 @@ mod tests {
         assert!(!is_shared_account_permission("file_rename"));
         assert!(!is_shared_account_permission("file_delete"));
         assert!(!is_shared_account_permission("file_create_dir"));
+        assert!(!is_shared_account_permission("file_comment"));
 
         // Invalid permissions
         assert!(!is_shared_account_permission("invalid"));
@@ -322,6 +409,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_is_guest_account_permission() {
+        assert!(is_guest_account_permission("chat_send"));
+        assert!(is_guest_account_permission("file_download"));
+        assert!(is_guest_account_permission("voice_listen"));
+
+        // Allowed for other shared accounts, but not for guests
+        assert!(!is_guest_account_permission("user_message"));
+        assert!(!is_guest_account_permission("voice_talk"));
+        assert!(!is_guest_account_permission("file_upload"));
+        assert!(!is_guest_account_permission("ban_list"));
+
+        assert!(!is_guest_account_permission("user_create"));
+        assert!(!is_guest_account_permission(""));
+    }
+
+    #[test]
+    fn test_guest_account_permissions_sorted_and_shared() {
+        let mut sorted = GUEST_ACCOUNT_PERMISSIONS.to_vec();
+        sorted.sort();
+        assert_eq!(GUEST_ACCOUNT_PERMISSIONS, sorted.as_slice());
+
+        // The guest account is a shared account, so its set can only be narrower
+        for perm in GUEST_ACCOUNT_PERMISSIONS {
+            assert!(
+                is_shared_account_permission(perm),
+                "GUEST_ACCOUNT_PERMISSIONS contains '{}' which shared accounts can't have",
+                perm
+            );
+        }
+    }
+
     #[test]
     fn test_all_permissions_sorted() {
         // Verify permissions are in alphabetical order
@@ -0,0 +1,111 @@
+//! Login proof of work
+//!
+//! A server can ask a client from a poorly reputed address to pay for its
+//! login with CPU time. The handshake response carries a random challenge and
+//! a difficulty; the client searches for a nonce whose hash has at least that
+//! many leading zero bits and sends it with its login. Checking a nonce costs
+//! the server a single hash.
+
+use rand::RngExt;
+use sha2::{Digest, Sha256};
+
+/// Length of a hex-encoded challenge
+pub const POW_CHALLENGE_LENGTH: usize = 32;
+
+/// Maximum length of a nonce (decimal `u64`)
+pub const MAX_POW_NONCE_LENGTH: usize = 20;
+
+/// Highest difficulty a client will attempt (each bit doubles the work)
+pub const MAX_POW_DIFFICULTY: u8 = 28;
+
+/// Prefix of the hashed input (versioned so the format can change)
+const POW_PREFIX: &str = "nexus-pow-v1:";
+
+/// Generate a new random challenge
+#[must_use]
+pub fn new_pow_challenge() -> String {
+    let bytes: [u8; POW_CHALLENGE_LENGTH / 2] = rand::rng().random();
+    hex::encode(bytes)
+}
+
+/// Check that `nonce` solves `challenge` at `difficulty`
+#[must_use]
+pub fn verify_pow(challenge: &str, difficulty: u8, nonce: &str) -> bool {
+    nonce.len() <= MAX_POW_NONCE_LENGTH
+        && nonce.parse::<u64>().is_ok()
+        && leading_zero_bits(&pow_hash(challenge, nonce)) >= u32::from(difficulty)
+}
+
+/// Find a nonce solving `challenge` at `difficulty`
+///
+/// This is CPU-bound (about 2^difficulty hashes), so async callers should run
+/// it with `spawn_blocking`. Returns `None` if the difficulty is above
+/// [`MAX_POW_DIFFICULTY`].
+#[must_use]
+pub fn solve_pow(challenge: &str, difficulty: u8) -> Option<String> {
+    if difficulty > MAX_POW_DIFFICULTY {
+        return None;
+    }
+    (0..u64::MAX)
+        .map(|nonce| nonce.to_string())
+        .find(|nonce| leading_zero_bits(&pow_hash(challenge, nonce)) >= u32::from(difficulty))
+}
+
+/// Hash of a challenge and nonce
+fn pow_hash(challenge: &str, nonce: &str) -> [u8; 32] {
+    Sha256::digest(format!("{POW_PREFIX}{challenge}:{nonce}")).into()
+}
+
+/// Number of leading zero bits in a hash
+fn leading_zero_bits(hash: &[u8]) -> u32 {
+    let mut bits = 0;
+    for byte in hash {
+        bits += byte.leading_zeros();
+        if *byte != 0 {
+            break;
+        }
+    }
+    bits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_leading_zero_bits() {
+        assert_eq!(leading_zero_bits(&[0xFF]), 0);
+        assert_eq!(leading_zero_bits(&[0x00, 0x10]), 11);
+        assert_eq!(leading_zero_bits(&[0x00, 0x00]), 16);
+    }
+
+    #[test]
+    fn test_new_pow_challenge() {
+        let challenge = new_pow_challenge();
+        assert_eq!(challenge.len(), POW_CHALLENGE_LENGTH);
+        assert!(challenge.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_ne!(challenge, new_pow_challenge());
+    }
+
+    #[test]
+    fn test_solve_and_verify() {
+        let challenge = new_pow_challenge();
+        let nonce = solve_pow(&challenge, 12).expect("difficulty is allowed");
+        assert!(verify_pow(&challenge, 12, &nonce));
+
+        // Bound to the challenge
+        assert!(!verify_pow(&new_pow_challenge(), 24, &nonce));
+    }
+
+    #[test]
+    fn test_verify_rejects_malformed_nonce() {
+        assert!(verify_pow("abc", 0, "42"));
+        assert!(!verify_pow("abc", 0, "not a number"));
+        assert!(!verify_pow("abc", 0, &"1".repeat(MAX_POW_NONCE_LENGTH + 1)));
+    }
+
+    #[test]
+    fn test_solve_refuses_excessive_difficulty() {
+        assert!(solve_pow("abc", MAX_POW_DIFFICULTY + 1).is_none());
+    }
+}
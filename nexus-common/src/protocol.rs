@@ -36,6 +36,10 @@ pub enum ClientMessage {
         #[serde(default, skip_serializing_if = "is_normal_action")]
         action: ChatAction,
         channel: String,
+        /// Identifies the message so sending it again (after a dropped
+        /// connection) doesn't post it twice
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        idempotency_key: Option<String>,
     },
     ChatTopicUpdate {
         topic: String,
@@ -49,15 +53,45 @@ pub enum ClientMessage {
     ChatLeave {
         channel: String,
     },
-    /// List available channels
-    ChatList {},
+    /// List available channels (optionally only those tagged with a language)
+    ChatList {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        language: Option<String>,
+    },
     /// Set channel secret mode
     ChatSecret {
         channel: String,
         secret: bool,
     },
+    /// Lock or unlock a channel's topic (a locked topic can only be changed by
+    /// users with the chat_topic_lock permission)
+    ChatTopicLock {
+        channel: String,
+        locked: bool,
+    },
+    /// List a channel's recent topic changes
+    ChatTopicHistory {
+        channel: String,
+    },
+    /// Set a channel's entry message and/or rules
+    /// (None = not changed, Some("") = cleared)
+    ChatEntryUpdate {
+        channel: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        entry_message: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        rules: Option<String>,
+    },
+    /// Set a channel's language tag (e.g. "en", "pt-BR"; "" = cleared)
+    ChatLanguage {
+        channel: String,
+        language: String,
+    },
     Handshake {
         version: String,
+        /// Client software name and version (e.g. "Nexus BBS 0.5.0"), shown in user info
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        client: Option<String>,
     },
     Login {
         username: String,
@@ -69,9 +103,31 @@ pub enum ClientMessage {
         avatar: Option<String>,
         #[serde(default, skip_serializing_if = "Option::is_none")]
         nickname: Option<String>,
+        /// Proof-of-work nonce, when the handshake asked for one (see [`crate::pow`])
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pow_nonce: Option<String>,
+        /// Single sign-on result to log in with instead of a password
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        oidc: Option<OidcLogin>,
+        /// Token from an earlier single sign-on login (transfer connections)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        login_token: Option<String>,
     },
     UserBroadcast {
         message: String,
+        /// Who receives the broadcast (default: everyone)
+        #[serde(default, skip_serializing_if = "BroadcastTarget::is_all")]
+        target: BroadcastTarget,
+        /// Delay before sending (e.g. "10m", "4h", "7d"; default: send now)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        delay: Option<String>,
+        /// Ask recipients to acknowledge the broadcast
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        require_ack: bool,
+    },
+    /// Acknowledge a broadcast that asked for it
+    BroadcastAck {
+        broadcast_id: u64,
     },
     UserCreate {
         username: String,
@@ -85,9 +141,29 @@ pub enum ClientMessage {
     UserDelete {
         username: String,
     },
+    /// Delete an account and scrub its personal data (admin only)
+    UserErase {
+        username: String,
+    },
     UserEdit {
         username: String,
     },
+    /// Audit permissions (admin only)
+    ///
+    /// Set `permission` to list the accounts holding it, or `username` to get
+    /// that account's effective permissions. Exactly one must be set.
+    PermissionAudit {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        permission: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        username: Option<String>,
+    },
+    ImpersonateQuery {
+        username: String,
+        /// Directory to list in the user's file area (default: area root)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        path: Option<String>,
+    },
     UserInfo {
         nickname: String,
     },
@@ -106,6 +182,10 @@ pub enum ClientMessage {
         message: String,
         #[serde(default, skip_serializing_if = "is_normal_action")]
         action: ChatAction,
+        /// Identifies the message so sending it again (after a dropped
+        /// connection) doesn't post it twice
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        idempotency_key: Option<String>,
     },
     UserUpdate {
         username: String,
@@ -136,6 +216,58 @@ pub enum ClientMessage {
         #[serde(skip_serializing_if = "Option::is_none")]
         status: Option<String>,
     },
+    /// Set or clear do not disturb for this session
+    UserDnd {
+        enabled: bool,
+    },
+    /// Fetch this account's stored direct messages, newest first
+    MessageHistory {
+        /// Conversation partner (None for recent messages in all conversations)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        nickname: Option<String>,
+        /// Only messages older than this history ID (for paging back)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        before: Option<i64>,
+        /// Most messages to return (capped by the server)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        limit: Option<u32>,
+    },
+    /// Set how many days the server keeps this account's direct messages (0 = off)
+    MessageHistoryRetention {
+        days: u32,
+    },
+    /// Tell a sender their direct messages have been read, up to an ID
+    MessageMarkRead {
+        /// Who sent the messages
+        nickname: String,
+        /// ID of the newest message read
+        id: u64,
+    },
+    /// Start a group conversation with other online users
+    GroupMessageCreate {
+        /// Nicknames to talk to (the creator is added automatically)
+        members: Vec<String>,
+    },
+    /// Send a message to a group conversation's members
+    GroupMessageSend {
+        group_id: u64,
+        message: String,
+        #[serde(default, skip_serializing_if = "is_normal_action")]
+        action: ChatAction,
+        /// Identifies the message so sending it again (after a dropped
+        /// connection) doesn't post it twice
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        idempotency_key: Option<String>,
+    },
+    /// Add an online user to a group conversation
+    GroupMessageInvite {
+        group_id: u64,
+        nickname: String,
+    },
+    /// Leave a group conversation
+    GroupMessageLeave {
+        group_id: u64,
+    },
     ServerInfoUpdate {
         #[serde(skip_serializing_if = "Option::is_none")]
         name: Option<String>,
@@ -145,6 +277,15 @@ pub enum ClientMessage {
         max_connections_per_ip: Option<u32>,
         #[serde(skip_serializing_if = "Option::is_none")]
         max_transfers_per_ip: Option<u32>,
+        /// Max simultaneous active transfers server-wide (0 = unlimited)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        max_active_transfers: Option<u32>,
+        /// Whether queued transfers with smaller total size start first
+        #[serde(skip_serializing_if = "Option::is_none")]
+        transfer_small_files_first: Option<bool>,
+        /// Fair-share weight for transfers from trusted IPs (1 = same as everyone else)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        trusted_transfer_weight: Option<u32>,
         #[serde(skip_serializing_if = "Option::is_none")]
         image: Option<String>,
         /// File reindex interval in minutes (0 to disable automatic reindexing)
@@ -164,8 +305,12 @@ pub enum ClientMessage {
     NewsCreate {
         #[serde(skip_serializing_if = "Option::is_none")]
         body: Option<String>,
+        /// Single image from clients that predate attachments, stored as the
+        /// first attachment
         #[serde(skip_serializing_if = "Option::is_none")]
         image: Option<String>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        attachments: Vec<NewsAttachment>,
     },
     NewsEdit {
         id: i64,
@@ -174,12 +319,48 @@ pub enum ClientMessage {
         id: i64,
         #[serde(skip_serializing_if = "Option::is_none")]
         body: Option<String>,
+        /// Single image from clients that predate attachments, stored as the
+        /// first attachment
         #[serde(skip_serializing_if = "Option::is_none")]
         image: Option<String>,
+        /// Replaces the post's attachments
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        attachments: Vec<NewsAttachment>,
     },
     NewsDelete {
         id: i64,
     },
+    /// Search news posts (newest first)
+    ///
+    /// At least one of the query and filters must be given.
+    NewsSearch {
+        /// Words to find in post bodies (all must match, as word prefixes)
+        #[serde(default, skip_serializing_if = "String::is_empty")]
+        query: String,
+        /// Only posts by this author (username, case-insensitive)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        author: Option<String>,
+        /// Only posts from this day on (`YYYY-MM-DD`, UTC)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        since: Option<String>,
+        /// Only posts up to and including this day (`YYYY-MM-DD`, UTC)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        until: Option<String>,
+    },
+    /// Store text as a snippet, shared with a nexus://host/snippet/<id> link
+    /// (requires chat_send or user_message permission)
+    SnippetCreate {
+        content: String,
+        /// Language for syntax highlighting (extension or name, e.g. `rs`)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        language: Option<String>,
+        /// How long the server keeps the snippet, in seconds
+        expires_in: u32,
+    },
+    /// Fetch a snippet by id
+    SnippetGet {
+        id: String,
+    },
     FileList {
         path: String,
         /// If true, browse from file root instead of user's area (requires file_root permission)
@@ -212,6 +393,28 @@ pub enum ClientMessage {
         #[serde(default)]
         root: bool,
     },
+    /// Request the recursive list of files a directory download would transfer
+    FileManifest {
+        /// Path to the directory
+        path: String,
+        /// If true, path is relative to file root instead of user's area (requires file_root permission)
+        #[serde(default)]
+        root: bool,
+    },
+    /// Look up a batch of paths, e.g. the destinations of files about to be uploaded
+    FileStat {
+        /// Paths to look up, with the size of the local file for each
+        files: Vec<FileStatQuery>,
+        /// If true, paths are relative to file root instead of user's area (requires file_root permission)
+        #[serde(default)]
+        root: bool,
+    },
+    /// Watch directories for changes (replaces the previously watched ones)
+    ///
+    /// An empty list stops watching. Changes arrive as `FileChanged` messages.
+    FileWatch {
+        directories: Vec<FileWatchPath>,
+    },
     FileRename {
         /// Current path of the file or directory to rename
         path: String,
@@ -258,6 +461,10 @@ pub enum ClientMessage {
         /// If true, path is relative to file root instead of user's area (requires file_root permission)
         #[serde(default)]
         root: bool,
+        /// For directory downloads, only transfer these files (paths relative to the
+        /// directory, as returned by FileManifest). None transfers everything.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        include: Option<Vec<String>>,
     },
     /// Client response to FileStart - reports local file state for resume (downloads)
     FileStartResponse {
@@ -278,6 +485,19 @@ pub enum ClientMessage {
         /// If true, destination is relative to file root instead of user's area
         #[serde(default)]
         root: bool,
+        /// If true, replace existing files with different content instead of failing
+        /// (requires file_delete permission)
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        overwrite: bool,
+    },
+    /// Request a server export archive (port 7501 only, admin only)
+    ///
+    /// The archive is sent like a single-file download: FileDownloadResponse,
+    /// then FileStart/FileData for one JSON file, then TransferComplete.
+    ServerExport {
+        /// If true, include IP bans and trusts in the archive
+        #[serde(default)]
+        include_ip_rules: bool,
     },
     /// Client announces a file to upload (port 7501 only, mirrors ServerMessage::FileStart)
     FileStart {
@@ -334,6 +554,45 @@ pub enum ClientMessage {
     TrustList,
     /// Request list of active connections (admin/connection_monitor permission)
     ConnectionMonitor,
+    /// Start following the server log (admin/log_view permission)
+    LogSubscribe,
+    /// Stop following the server log
+    LogUnsubscribe,
+    /// Request the server's port forwarding state (admin only)
+    NatStatus,
+    /// Test whether the server's ports are reachable from outside (admin only)
+    ConnectivityCheck,
+    /// Request a snapshot of the server's health (admin only)
+    ServerStats,
+    /// Request daily activity statistics (admin only)
+    StatsRequest {
+        /// Number of days to report, ending today (clamped to 1-365)
+        days: u16,
+    },
+    /// Request the accounts with the highest activity counters
+    Leaderboard {
+        /// Counter to rank accounts by (default: logins)
+        #[serde(default)]
+        stat: LeaderboardStat,
+    },
+    /// Request the doors (external programs) the server offers
+    DoorList,
+    /// Start a door in a terminal of the given size (requires door_play permission)
+    DoorOpen {
+        /// Door name from DoorListResponse
+        name: String,
+        /// Terminal width in columns
+        cols: u16,
+        /// Terminal height in rows
+        rows: u16,
+    },
+    /// Keyboard input for the running door
+    DoorData {
+        /// Base64-encoded terminal bytes (at most `MAX_DOOR_DATA_SIZE` before encoding)
+        data: String,
+    },
+    /// End the running door
+    DoorClose,
     /// Search files in the file area
     FileSearch {
         /// Search query (minimum 3 characters, literal match, case-insensitive)
@@ -344,15 +603,175 @@ pub enum ClientMessage {
     },
     /// Request a file index rebuild (admin command)
     FileReindex,
+    /// Look for leftovers of aborted transfers in the file area (admin only)
+    FileCleanup {
+        /// Delete what the scan finds instead of only reporting it
+        #[serde(default)]
+        delete: bool,
+    },
+    /// Check the disk, search index and file metadata against each other (admin only)
+    FileCheck {
+        /// Repair what can be repaired instead of only reporting it
+        #[serde(default)]
+        repair: bool,
+    },
+    /// List the most downloaded files in the file area
+    FilePopular {
+        /// If true, list the entire file root instead of user's area (requires file_root permission)
+        #[serde(default)]
+        root: bool,
+    },
+    /// List files uploaded in the last week
+    FileRecent {
+        /// If true, list the entire file root instead of user's area (requires file_root permission)
+        #[serde(default)]
+        root: bool,
+    },
+    /// Rate a file from 1 to 5 stars (requires file_comment permission)
+    FileRate {
+        /// Path to the file
+        path: String,
+        /// Stars from 1 to 5, or 0 to clear your rating
+        rating: u8,
+        /// If true, path is relative to file root instead of user's area (requires file_root permission)
+        #[serde(default)]
+        root: bool,
+    },
+    /// Comment on a file (requires file_comment permission)
+    FileCommentCreate {
+        /// Path to the file
+        path: String,
+        /// Comment text (newlines allowed)
+        body: String,
+        /// If true, path is relative to file root instead of user's area (requires file_root permission)
+        #[serde(default)]
+        root: bool,
+    },
+    /// List the comments on a file (requires file_info permission)
+    FileCommentList {
+        /// Path to the file
+        path: String,
+        /// If true, path is relative to file root instead of user's area (requires file_root permission)
+        #[serde(default)]
+        root: bool,
+    },
+    /// Delete a file comment (own comments, or any with file_delete permission)
+    FileCommentDelete {
+        id: i64,
+    },
+    /// Get the upload rule set on a directory (admin only)
+    UploadRuleGet {
+        /// Path to the directory
+        path: String,
+        /// If true, path is relative to file root instead of user's area
+        #[serde(default)]
+        root: bool,
+    },
+    /// Set or remove the upload rule of a directory (admin only)
+    UploadRuleSet {
+        /// Path to the directory
+        path: String,
+        /// New rule (None or an empty rule removes it)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        rule: Option<UploadRule>,
+        /// If true, path is relative to file root instead of user's area
+        #[serde(default)]
+        root: bool,
+    },
+    /// Request the directory groups mapped to permissions (admin only)
+    LdapGroupList,
+    /// Map a directory group to permissions, replacing its mapping (admin only)
+    LdapGroupSet {
+        /// Distinguished name of the group
+        group: String,
+        /// Permissions members get
+        #[serde(default)]
+        permissions: Vec<String>,
+        /// Whether members are admins
+        #[serde(default)]
+        is_admin: bool,
+    },
+    /// Remove a directory group's mapping (admin only)
+    LdapGroupDelete {
+        /// Distinguished name of the group
+        group: String,
+    },
+    /// Create a personal account from a guest session
+    GuestUpgrade {
+        username: String,
+        password: String,
+    },
+    /// Reserve a nickname so shared account sessions can't use it
+    NicknameRegister {
+        nickname: String,
+        /// Account to reserve it for (admins only; defaults to the sender)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        username: Option<String>,
+    },
+    /// Release a reserved nickname (admins can release anyone's)
+    NicknameRelease {
+        nickname: String,
+    },
+    /// Watch the presence of specific accounts (replaces the previous list)
+    ///
+    /// An empty list stops watching. Works without the user_list permission.
+    PresenceSubscribe {
+        usernames: Vec<String>,
+    },
     /// Join voice chat for a channel or user message
     VoiceJoin {
         /// Target channel (e.g., "#general") or nickname for user message voice
         target: String,
+        /// Start a broadcast in the channel (requires voice_broadcast permission)
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        broadcast: bool,
     },
-    /// Leave current voice session
+    /// Leave current voice session (or the voice join queue)
     VoiceLeave,
+    /// Set the voice participant limit for a channel (0 = unlimited)
+    VoiceLimit {
+        channel: String,
+        limit: u32,
+    },
+    /// Voice packet sent over the BBS connection instead of UDP
+    ///
+    /// Only used when the server offered `tcp_relay` in VoiceJoinResponse and
+    /// the client could not reach the voice port over UDP.
+    VoiceRelay {
+        /// Base64-encoded `VoicePacket` (same bytes as a UDP voice packet)
+        data: String,
+    },
+    /// Request the server's soundboard clip library
+    VoiceClipList,
+    /// Add a clip to the soundboard library (requires voice_clip_upload permission)
+    VoiceClipUpload {
+        /// Clip name (letters, digits, `-` and `_`)
+        name: String,
+        /// Ogg Vorbis audio as a data URI (`data:audio/ogg;base64,...`)
+        data: String,
+    },
+    /// Remove a clip from the soundboard library (own clips, or any clip for admins)
+    VoiceClipDelete {
+        name: String,
+    },
+    /// Play a soundboard clip to everyone in the current voice session
+    VoiceClipPlay {
+        name: String,
+    },
+    /// Send a short text message to everyone in the current voice session
+    VoiceTextSend {
+        message: String,
+    },
     /// Keepalive ping (client sends periodically to prevent NAT timeout)
     Ping,
+    /// Stop working on an earlier request (no response)
+    ///
+    /// `message_id` is the frame message ID of the request. Long operations
+    /// (FileSearch, FileInfo, FileManifest) stop and send no response when
+    /// they're cancelled while running; other requests are unaffected.
+    Cancel {
+        message_id: String,
+    },
 }
 
 /// Helper for skip_serializing_if on ChatAction
@@ -377,6 +796,305 @@ pub struct ConnectionInfo {
     pub is_admin: bool,
     /// Whether this is a shared account
     pub is_shared: bool,
+    /// DNS blocklist zone listing the remote IP (None if not listed or not checked)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub listed_in: Option<String>,
+}
+
+/// Why an account holds a permission (used in PermissionAuditResponse)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PermissionSource {
+    /// Admins hold every permission
+    Admin,
+    /// Granted directly on the account
+    Granted,
+}
+
+/// Account holding an audited permission (used in PermissionAuditResponse)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PermissionHolder {
+    /// Account username
+    pub username: String,
+    /// Where the permission comes from
+    pub source: PermissionSource,
+    /// Whether this is a shared account
+    pub is_shared: bool,
+    /// Whether the account can log in
+    pub enabled: bool,
+}
+
+/// A permission an account can use (used in EffectivePermissions)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EffectivePermission {
+    /// Permission name (e.g., "file_delete")
+    pub permission: String,
+    /// Where the permission comes from
+    pub source: PermissionSource,
+}
+
+/// Everything an account can do (used in PermissionAuditResponse)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EffectivePermissions {
+    /// Account username
+    pub username: String,
+    /// Whether the user is an admin
+    pub is_admin: bool,
+    /// Whether this is a shared account
+    pub is_shared: bool,
+    /// Whether the account can log in
+    pub enabled: bool,
+    /// Permissions in effect (sorted by name)
+    pub permissions: Vec<EffectivePermission>,
+    /// Permissions stored on the account but not in effect because shared
+    /// accounts can't use them
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub restricted: Vec<String>,
+}
+
+/// Severity of a server log line, most severe first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+}
+
+/// A server log line (used in LogSubscribeResponse and LogLine)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogEntry {
+    /// Unix timestamp when the line was logged
+    pub timestamp: i64,
+    /// Severity
+    pub level: LogLevel,
+    /// Server module that logged the line (e.g., "handlers::login")
+    pub module: String,
+    /// Log message
+    pub message: String,
+}
+
+/// How the server's ports are forwarded on the router
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum NatMethod {
+    /// UPnP Internet Gateway Device
+    Upnp,
+    /// Port Control Protocol (RFC 6887)
+    Pcp,
+    /// NAT Port Mapping Protocol (RFC 6886)
+    NatPmp,
+}
+
+/// Limits the server applies to news images (used in ServerInfo)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NewsImageLimits {
+    /// Largest image the server stores, in bytes (before base64 encoding)
+    pub max_size: u32,
+    /// Longest side raster images are scaled down to (0 = not scaled)
+    pub max_dimension: u32,
+}
+
+/// Server clock reading (used in ServerInfo and Pong)
+///
+/// Lets clients show the server's local time, corrected for clock skew.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ServerTime {
+    /// Unix timestamp (seconds) when the message was sent
+    pub timestamp: i64,
+    /// Offset of the server's time zone from UTC, in seconds
+    pub utc_offset: i32,
+    /// IANA name of the server's time zone (e.g. "Europe/Berlin"), if known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+}
+
+/// Port forwarding state of a server started with `--upnp`
+/// (used in ServerInfo and NatStatusResponse, admin only)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NatInfo {
+    /// How the ports are forwarded (None if no gateway was found)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<NatMethod>,
+    /// External IPv4 address last reported by the gateway
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_ip: Option<String>,
+    /// Whether the port mappings are currently in place
+    pub mapped: bool,
+    /// TCP ports forwarded (BBS, transfer and WebSocket ports)
+    pub tcp_ports: Vec<u16>,
+    /// UDP ports forwarded (voice)
+    pub udp_ports: Vec<u16>,
+    /// Unix timestamp of the last check
+    pub checked_at: i64,
+    /// Error from the last failed check
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Server port tested by a connectivity check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CheckedService {
+    /// Main BBS port (TCP)
+    Bbs,
+    /// File transfer port (TCP)
+    Transfer,
+    /// WebSocket BBS port (TCP)
+    Websocket,
+    /// WebSocket file transfer port (TCP)
+    TransferWebsocket,
+    /// Voice port (UDP)
+    Voice,
+}
+
+/// What the check service saw when connecting back to a port
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PortReachability {
+    /// The connection (or datagram) got through
+    Open,
+    /// The connection was refused (nothing listening where the port leads, or a firewall)
+    Closed,
+    /// No answer (not forwarded, or dropped by a firewall)
+    Filtered,
+    /// Not tested (UDP can't be tested while the server is using the port)
+    Untested,
+}
+
+/// Result for one port of a connectivity check (used in ConnectivityCheckResponse)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PortCheck {
+    /// Which server port this is
+    pub service: CheckedService,
+    /// Port number
+    pub port: u16,
+    /// What the check service saw
+    pub state: PortReachability,
+}
+
+/// Volume the server watches for free space (used in StorageAlert)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageVolume {
+    /// The disk holding the file area
+    Files,
+    /// The disk holding the database
+    Database,
+}
+
+/// Snapshot of the server's health (used in ServerStatsResponse)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ServerStatsInfo {
+    /// Logged-in sessions
+    pub sessions: u32,
+    /// Open BBS connections, including ones that haven't logged in yet
+    pub connections: u32,
+    /// Open file transfer connections
+    pub transfer_connections: u32,
+    /// Transfers sending or receiving data
+    pub active_transfers: u32,
+    /// Transfers waiting for a free slot
+    pub queued_transfers: u32,
+    /// Bytes free on the file area's disk (None without a file area)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disk_free: Option<u64>,
+    /// Size of the file area's disk in bytes (None without a file area)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disk_total: Option<u64>,
+    /// Most recent audit log entries, newest first
+    #[serde(default)]
+    pub recent_audit: Vec<AuditEntry>,
+}
+
+/// An audit log entry (used in ServerStatsInfo)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// What was done (e.g. `user_erase`)
+    pub action: String,
+    /// What it was done to
+    pub target: String,
+    /// Username of who did it
+    pub actor: String,
+    /// Extra information
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+    /// Unix timestamp
+    pub created_at: i64,
+}
+
+/// Activity totals for one UTC day (used in StatsResponse)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DailyStats {
+    /// Day in `YYYY-MM-DD` format (UTC)
+    pub date: String,
+    /// Successful logins
+    pub logins: u64,
+    /// Chat and private messages sent
+    pub messages: u64,
+    /// Completed uploads
+    pub uploads: u64,
+    /// Bytes received in uploads
+    pub upload_bytes: u64,
+    /// Completed downloads
+    pub downloads: u64,
+    /// Bytes sent in downloads
+    pub download_bytes: u64,
+    /// Most sessions online at the same time
+    pub peak_sessions: u32,
+}
+
+/// Lifetime activity counters for one account
+/// (used in UserInfoDetailed and LeaderboardEntry)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct UserStats {
+    /// Successful logins
+    pub logins: u64,
+    /// Chat and private messages sent
+    pub messages: u64,
+    /// Completed uploads
+    pub uploads: u64,
+    /// Bytes sent in completed downloads
+    pub download_bytes: u64,
+    /// Unix timestamp of the most recent login (None if never logged in)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_login_at: Option<i64>,
+}
+
+/// Counter a leaderboard ranks accounts by
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LeaderboardStat {
+    #[default]
+    Logins,
+    Messages,
+    Uploads,
+    DownloadBytes,
+}
+
+impl LeaderboardStat {
+    /// This counter's value in a set of stats
+    pub fn value(&self, stats: &UserStats) -> u64 {
+        match self {
+            LeaderboardStat::Logins => stats.logins,
+            LeaderboardStat::Messages => stats.messages,
+            LeaderboardStat::Uploads => stats.uploads,
+            LeaderboardStat::DownloadBytes => stats.download_bytes,
+        }
+    }
+}
+
+/// An account on the leaderboard (used in LeaderboardResponse)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LeaderboardEntry {
+    /// Account username
+    pub username: String,
+    /// The account's counters
+    pub stats: UserStats,
+    /// Rank earned from the counters, if the server defines ranks
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rank: Option<String>,
 }
 
 /// Information about an active file transfer (used in ConnectionMonitorResponse)
@@ -404,6 +1122,17 @@ pub struct TransferInfo {
     pub bytes_transferred: u64,
     /// Unix timestamp when transfer started
     pub started_at: i64,
+    /// Whether the transfer is waiting in the server queue for an active slot
+    #[serde(default)]
+    pub queued: bool,
+    /// Fair-share weight used by the transfer scheduler (1 = normal)
+    #[serde(default = "default_transfer_weight")]
+    pub weight: u32,
+}
+
+/// Default fair-share weight for transfers (older servers don't send it)
+fn default_transfer_weight() -> u32 {
+    1
 }
 
 /// Server response messages
@@ -425,8 +1154,8 @@ pub enum ServerMessage {
         #[serde(default)]
         timestamp: u64,
     },
-    /// Broadcast when channel properties change (topic, secret mode)
-    /// Only changed fields are included
+    /// Broadcast when channel properties change (topic, secret mode, voice limit,
+    /// topic lock, rules, language). Only changed fields are included
     ChatUpdated {
         channel: String,
         /// New topic (None = not changed, Some("") = cleared)
@@ -441,6 +1170,38 @@ pub enum ServerMessage {
         /// Who changed secret mode
         #[serde(skip_serializing_if = "Option::is_none")]
         secret_set_by: Option<String>,
+        /// New voice participant limit (None = not changed, Some(0) = unlimited)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        voice_limit: Option<u32>,
+        /// Who changed the voice limit
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        voice_limit_set_by: Option<String>,
+        /// New topic lock (None = not changed)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        topic_locked: Option<bool>,
+        /// Who locked or unlocked the topic
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        topic_locked_set_by: Option<String>,
+        /// New rules (None = not changed, Some("") = cleared)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        rules: Option<String>,
+        /// Who changed the rules
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        rules_set_by: Option<String>,
+        /// New language tag (None = not changed, Some("") = cleared)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        language: Option<String>,
+        /// Who changed the language tag
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        language_set_by: Option<String>,
+    },
+    /// Response to a ChatSend with an idempotency key, once the message is
+    /// sent (or was already sent with that key)
+    ///
+    /// Errors are sent as `Error` with `command` "ChatSend", as for ChatSend
+    /// without a key.
+    ChatSendResponse {
+        success: bool,
     },
     ChatTopicUpdateResponse {
         success: bool,
@@ -471,6 +1232,21 @@ pub enum ServerMessage {
         /// Nicknames currently in voice chat (only on success, only if requester has voice_listen)
         #[serde(skip_serializing_if = "Option::is_none")]
         voiced: Option<Vec<String>>,
+        /// Voice participant limit (only on success, only if the channel has one)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        voice_limit: Option<u32>,
+        /// Whether the topic is locked
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        topic_locked: bool,
+        /// Message shown to users joining the channel (only if the channel has one)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        entry_message: Option<String>,
+        /// Channel rules (only if the channel has them)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        rules: Option<String>,
+        /// Channel language tag (only if the channel has one)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        language: Option<String>,
     },
     /// Response to ChatLeave request
     ChatLeaveResponse {
@@ -494,23 +1270,56 @@ pub enum ServerMessage {
         #[serde(skip_serializing_if = "Option::is_none")]
         error: Option<String>,
     },
-    /// Broadcast when a user joins a channel
-    ChatUserJoined {
-        channel: String,
-        nickname: String,
-        is_admin: bool,
-        is_shared: bool,
-    },
-    /// Broadcast when a user leaves a channel
-    ChatUserLeft {
-        channel: String,
-        nickname: String,
+    /// Response to ChatTopicLock request
+    ChatTopicLockResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// Response to ChatTopicHistory request
+    ChatTopicHistoryResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        /// Channel name (only on success)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        channel: Option<String>,
+        /// Topic changes, newest first (only on success)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        changes: Option<Vec<ChatTopicChange>>,
+    },
+    /// Response to ChatEntryUpdate request
+    ChatEntryUpdateResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// Response to ChatLanguage request
+    ChatLanguageResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// Broadcast when a user joins a channel
+    ChatUserJoined {
+        channel: String,
+        nickname: String,
+        is_admin: bool,
+        is_shared: bool,
+    },
+    /// Broadcast when a user leaves a channel
+    ChatUserLeft {
+        channel: String,
+        nickname: String,
     },
 
     Error {
         message: String,
         #[serde(skip_serializing_if = "Option::is_none")]
         command: Option<String>,
+        /// Machine-readable error kind: "message_too_long"
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        error_kind: Option<String>,
     },
     HandshakeResponse {
         success: bool,
@@ -518,6 +1327,21 @@ pub enum ServerMessage {
         version: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         error: Option<String>,
+        /// Server identity public key (hex Ed25519, see [`crate::identity`])
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        identity_key: Option<String>,
+        /// Identity key signature over the TLS certificate fingerprint (hex)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        identity_signature: Option<String>,
+        /// Proof-of-work challenge the login must solve (see [`crate::pow`])
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pow_challenge: Option<String>,
+        /// Required leading zero bits for the proof of work
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pow_difficulty: Option<u8>,
+        /// Identity provider for single sign-on, when the server has one
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        oidc: Option<OidcProvider>,
     },
     LoginResponse {
         success: bool,
@@ -539,11 +1363,17 @@ pub enum ServerMessage {
         /// Server-confirmed nickname (equals username for regular accounts)
         #[serde(skip_serializing_if = "Option::is_none")]
         nickname: Option<String>,
+        /// Token for logging in transfer connections after single sign-on
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        login_token: Option<String>,
     },
     ServerBroadcast {
         session_id: u32,
         username: String,
         message: String,
+        /// Set when the sender asked for acknowledgement (answer with BroadcastAck)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        broadcast_id: Option<u64>,
     },
     UserConnected {
         user: UserInfo,
@@ -562,6 +1392,13 @@ pub enum ServerMessage {
         #[serde(skip_serializing_if = "Option::is_none")]
         username: Option<String>,
     },
+    UserEraseResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        username: Option<String>,
+    },
     UserEditResponse {
         success: bool,
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -581,6 +1418,34 @@ pub enum ServerMessage {
         session_id: u32,
         nickname: String,
     },
+    PermissionAuditResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        /// Accounts holding the requested permission (sorted by username)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        holders: Option<Vec<PermissionHolder>>,
+        /// Effective permissions of the requested account
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        effective: Option<EffectivePermissions>,
+    },
+    ImpersonateQueryResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        /// Effective permissions of the account
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        effective: Option<EffectivePermissions>,
+        /// Directory that was listed (None if the user can't list files)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        path: Option<String>,
+        /// Entries the user would see in `path`
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        entries: Option<Vec<FileEntry>>,
+        /// Channels the user would see (None if the user can't list channels)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        channels: Option<Vec<ChannelInfo>>,
+    },
     PermissionsUpdated {
         is_admin: bool,
         permissions: Vec<String>,
@@ -599,6 +1464,26 @@ pub enum ServerMessage {
         success: bool,
         #[serde(skip_serializing_if = "Option::is_none")]
         error: Option<String>,
+        /// ID for matching later BroadcastStatus messages (only on success)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        broadcast_id: Option<u64>,
+        /// Number of users the broadcast reached (sent right away)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        delivered: Option<u32>,
+        /// When the broadcast will be sent (scheduled, Unix timestamp)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        send_at: Option<i64>,
+    },
+    /// Delivery and acknowledgement counts of a broadcast, sent to its sender
+    ///
+    /// Sent when a scheduled broadcast goes out and whenever a recipient
+    /// acknowledges one.
+    BroadcastStatus {
+        broadcast_id: u64,
+        delivered: u32,
+        /// Recipients that acknowledged (only if acknowledgement was asked for)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        acknowledged: Option<u32>,
     },
     UserInfoResponse {
         success: bool,
@@ -633,11 +1518,17 @@ pub enum ServerMessage {
         /// Unix timestamp (seconds since epoch)
         #[serde(default)]
         timestamp: u64,
+        /// Server-assigned ID that delivery and read receipts refer to
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<u64>,
     },
     UserMessageResponse {
         success: bool,
         #[serde(skip_serializing_if = "Option::is_none")]
         error: Option<String>,
+        /// Machine-readable error kind: "message_too_long"
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        error_kind: Option<String>,
         /// Recipient's away status (if away when message sent)
         #[serde(default, skip_serializing_if = "Option::is_none")]
         is_away: Option<bool>,
@@ -667,6 +1558,100 @@ pub enum ServerMessage {
         #[serde(skip_serializing_if = "Option::is_none")]
         error: Option<String>,
     },
+    /// Response to UserDnd request
+    UserDndResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// Response to MessageHistory request
+    MessageHistoryResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        /// Stored messages, newest first
+        #[serde(skip_serializing_if = "Option::is_none")]
+        messages: Option<Vec<HistoryMessage>>,
+        /// Whether older messages remain (request again with `before`)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        has_more: Option<bool>,
+        /// Account's retention in days (0 = history off)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        retention_days: Option<u32>,
+    },
+    /// Response to MessageHistoryRetention request
+    MessageHistoryRetentionResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        /// Retention now in effect
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        days: Option<u32>,
+    },
+    /// A direct message this user sent reached the recipient
+    MessageDelivered {
+        /// Recipient
+        nickname: String,
+        /// ID of the delivered message
+        id: u64,
+    },
+    /// The recipient read this user's direct messages, up to an ID
+    MessageRead {
+        /// Recipient
+        nickname: String,
+        /// ID of the newest message read
+        id: u64,
+    },
+    /// Response to GroupMessageCreate request
+    GroupMessageCreateResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        group_id: Option<u64>,
+    },
+    /// Response to GroupMessageSend request
+    GroupMessageSendResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        /// Machine-readable error kind: "message_too_long"
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        error_kind: Option<String>,
+    },
+    /// Response to GroupMessageInvite request
+    GroupMessageInviteResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// Response to GroupMessageLeave request
+    GroupMessageLeaveResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// A message in a group conversation, sent to every member
+    GroupMessage {
+        group_id: u64,
+        from_nickname: String,
+        from_admin: bool,
+        #[serde(default)]
+        from_shared: bool,
+        message: String,
+        #[serde(default, skip_serializing_if = "is_normal_action")]
+        action: ChatAction,
+        /// Unix timestamp (seconds since epoch)
+        #[serde(default)]
+        timestamp: u64,
+    },
+    /// A group conversation's members, sent to every member when they change
+    /// (including to users who were just added)
+    GroupMembers {
+        group_id: u64,
+        /// Nicknames in the order they joined
+        members: Vec<String>,
+    },
     UserUpdateResponse {
         success: bool,
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -720,6 +1705,35 @@ pub enum ServerMessage {
         action: NewsAction,
         id: i64,
     },
+    /// Response to NewsSearch request
+    NewsSearchResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        /// Matching posts, newest first (max 100)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        items: Option<Vec<NewsItem>>,
+    },
+    /// Response to SnippetCreate request
+    SnippetCreateResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        /// Id of the new snippet
+        #[serde(skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        /// Unix timestamp the snippet is deleted at
+        #[serde(skip_serializing_if = "Option::is_none")]
+        expires_at: Option<i64>,
+    },
+    /// Response to SnippetGet request
+    SnippetGetResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        snippet: Option<SnippetInfo>,
+    },
     FileListResponse {
         success: bool,
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -731,6 +1745,9 @@ pub enum ServerMessage {
         /// Whether the current directory allows uploads (for UI to enable "New Directory" button)
         #[serde(default, skip_serializing_if = "std::ops::Not::not")]
         can_upload: bool,
+        /// Upload rule that applies to the current directory (only when uploads are allowed)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        upload_rule: Option<UploadRule>,
     },
     FileCreateDirResponse {
         success: bool,
@@ -752,6 +1769,44 @@ pub enum ServerMessage {
         #[serde(skip_serializing_if = "Option::is_none")]
         info: Option<FileInfoDetails>,
     },
+    FileManifestResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        /// Directory path the manifest was built for (echoed from the request)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        path: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        files: Option<Vec<FileManifestEntry>>,
+    },
+    FileStatResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        /// The requested paths that exist (missing paths are left out)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        files: Option<Vec<FileStatEntry>>,
+    },
+    /// Response to FileWatch request
+    FileWatchResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// An entry in a watched directory was added, removed or renamed
+    FileChanged {
+        /// The watched directory, as given in the FileWatch request
+        path: String,
+        /// Whether the directory is relative to the file root
+        #[serde(default)]
+        root: bool,
+        kind: FileChangeKind,
+        /// Name of the entry (the old name for a rename)
+        name: String,
+        /// New name of a renamed entry
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        new_name: Option<String>,
+    },
     FileRenameResponse {
         success: bool,
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -836,6 +1891,15 @@ pub enum ServerMessage {
         /// File being hashed (for logging/debugging)
         file: String,
     },
+    /// Transfer is waiting for a free server slot (transfer port only)
+    ///
+    /// Sent before FileDownloadResponse/FileUploadResponse when the server's
+    /// active transfer limit is reached. Re-sent when the position changes and
+    /// periodically as a keepalive.
+    TransferQueued {
+        /// 1-based position in the server's transfer queue
+        position: u32,
+    },
     /// Response to BanCreate request
     BanCreateResponse {
         success: bool,
@@ -910,6 +1974,107 @@ pub enum ServerMessage {
         #[serde(skip_serializing_if = "Option::is_none")]
         transfers: Option<Vec<TransferInfo>>,
     },
+    /// Response to LogSubscribe request
+    LogSubscribeResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        /// Recent log lines, oldest first
+        #[serde(skip_serializing_if = "Option::is_none")]
+        entries: Option<Vec<LogEntry>>,
+    },
+    /// A new server log line (sent to log subscribers)
+    LogLine {
+        entry: LogEntry,
+    },
+    /// Response to NatStatus request
+    NatStatusResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        /// Port forwarding state (None if the server wasn't started with `--upnp`)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        nat: Option<NatInfo>,
+    },
+    /// Response to ConnectivityCheck request
+    ConnectivityCheckResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        /// Address the check service saw the server connect from
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        external_ip: Option<String>,
+        /// Result for each port (present on success)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        ports: Option<Vec<PortCheck>>,
+    },
+    /// Response to ServerStats
+    ServerStatsResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        /// Health snapshot (present on success)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        stats: Option<ServerStatsInfo>,
+    },
+    /// Free space on a volume crossed the server's minimum (sent to admins)
+    ///
+    /// `low` is true when free space fell below `threshold` and false when it
+    /// recovered. Uploads are refused while the file area's volume is low.
+    StorageAlert {
+        volume: StorageVolume,
+        low: bool,
+        /// Bytes free on the volume
+        free: u64,
+        /// Size of the volume in bytes
+        total: u64,
+        /// Minimum free space the server keeps, in bytes
+        threshold: u64,
+    },
+    /// Response to StatsRequest
+    StatsResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        /// One entry per day, oldest first (present on success)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        days: Option<Vec<DailyStats>>,
+    },
+    /// Response to Leaderboard
+    LeaderboardResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        /// Accounts, highest counter first (present on success, max 20)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        entries: Option<Vec<LeaderboardEntry>>,
+    },
+    /// Response to DoorList
+    DoorListResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        /// Door names in configuration order (present on success)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        doors: Option<Vec<String>>,
+    },
+    /// Response to DoorOpen
+    DoorOpenResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// Terminal output from the running door
+    DoorData {
+        /// Base64-encoded terminal bytes (at most `MAX_DOOR_DATA_SIZE` before encoding)
+        data: String,
+    },
+    /// The running door ended (exited, or was closed by the client)
+    DoorClosed {
+        /// Program exit code, if it exited on its own
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        exit_code: Option<u32>,
+    },
     /// Response to FileSearch request
     FileSearchResponse {
         success: bool,
@@ -925,45 +2090,338 @@ pub enum ServerMessage {
         #[serde(skip_serializing_if = "Option::is_none")]
         error: Option<String>,
     },
-    /// Response to VoiceJoin request
-    VoiceJoinResponse {
+    /// Response to FileCleanup request
+    FileCleanupResponse {
         success: bool,
-        /// Voice token for UDP authentication (only on success)
         #[serde(skip_serializing_if = "Option::is_none")]
-        token: Option<Uuid>,
-        /// Target (same as requested, or the other user's nickname for user message voice)
+        error: Option<String>,
+        /// What the scan found (present on success)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        report: Option<FileCleanupReport>,
+    },
+    /// A discrepancy found by FileCheck (sent as found, before FileCheckResponse)
+    FileCheckIssue {
+        kind: FileCheckIssueKind,
+        /// Path relative to the file root
+        path: String,
+        /// Whether it was repaired
+        #[serde(default)]
+        repaired: bool,
+    },
+    /// Response to FileCheck request, sent once the check is done
+    FileCheckResponse {
+        success: bool,
         #[serde(skip_serializing_if = "Option::is_none")]
-        target: Option<String>,
-        /// Nicknames of users already in this voice session
+        error: Option<String>,
+        /// Totals (present on success)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        summary: Option<FileCheckSummary>,
+    },
+    /// Response to FilePopular request
+    FilePopularResponse {
+        success: bool,
         #[serde(skip_serializing_if = "Option::is_none")]
-        participants: Option<Vec<String>>,
+        error: Option<String>,
+        /// Most downloaded files first (max 20)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        files: Option<Vec<FileActivity>>,
+    },
+    /// Response to FileRecent request
+    FileRecentResponse {
+        success: bool,
         #[serde(skip_serializing_if = "Option::is_none")]
         error: Option<String>,
+        /// Newest uploads first (max 20)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        files: Option<Vec<FileActivity>>,
     },
-    /// Response to VoiceLeave request
-    VoiceLeaveResponse {
+    /// Response to FileRate request
+    FileRateResponse {
         success: bool,
         #[serde(skip_serializing_if = "Option::is_none")]
         error: Option<String>,
+        /// The file's rating after the change (None once nobody has rated it)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        rating: Option<FileRating>,
+        /// Your rating after the change (None once cleared)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        my_rating: Option<u8>,
     },
-    /// Notification that a user joined voice
-    VoiceUserJoined {
-        /// Nickname of the user who joined
-        nickname: String,
-        /// Target channel or the other user's nickname for user message voice
-        target: String,
+    /// Response to FileCommentCreate request
+    FileCommentCreateResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        /// The new comment (present on success)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        comment: Option<FileComment>,
     },
-    /// Notification that a user left voice
-    VoiceUserLeft {
-        /// Nickname of the user who left
-        nickname: String,
-        /// Target channel or the other user's nickname for user message voice
-        target: String,
+    /// Response to FileCommentList request
+    FileCommentListResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        /// Comments, oldest first (present on success)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        comments: Option<Vec<FileComment>>,
     },
-    /// Keepalive pong (server response to client Ping)
-    Pong,
-}
-
+    /// Response to FileCommentDelete request
+    FileCommentDeleteResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        id: Option<i64>,
+    },
+    /// Response to UploadRuleGet request
+    UploadRuleGetResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        /// Rule set on the directory itself (None if it has none)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        rule: Option<UploadRule>,
+    },
+    /// Response to UploadRuleSet request
+    UploadRuleSetResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// Response to LdapGroupList request
+    LdapGroupListResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        groups: Option<Vec<LdapGroupInfo>>,
+    },
+    /// Response to LdapGroupSet request
+    LdapGroupSetResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        /// The group that was mapped (for success message)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        group: Option<String>,
+    },
+    /// Response to LdapGroupDelete request
+    LdapGroupDeleteResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        /// The group whose mapping was removed (for success message)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        group: Option<String>,
+    },
+    /// Response to GuestUpgrade request
+    GuestUpgradeResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        /// The account that was created (for success message)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        username: Option<String>,
+    },
+    /// Response to NicknameRegister request
+    NicknameRegisterResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        /// The nickname that was reserved (for success message)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        nickname: Option<String>,
+        /// The account it was reserved for
+        #[serde(skip_serializing_if = "Option::is_none")]
+        username: Option<String>,
+    },
+    /// Response to NicknameRelease request
+    NicknameReleaseResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        /// The nickname that was released (for success message)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        nickname: Option<String>,
+    },
+    /// Response to PresenceSubscribe request
+    PresenceSubscribeResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        /// Current presence of each watched account (only on success)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        users: Option<Vec<PresenceInfo>>,
+    },
+    /// A watched account came online, went offline, or changed its status
+    PresenceUpdate {
+        user: PresenceInfo,
+    },
+    /// Response to VoiceJoin request
+    VoiceJoinResponse {
+        success: bool,
+        /// Voice token for UDP authentication (only on success)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        token: Option<Uuid>,
+        /// Target (same as requested, or the other user's nickname for user message voice)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        target: Option<String>,
+        /// Nicknames of users already in this voice session
+        #[serde(skip_serializing_if = "Option::is_none")]
+        participants: Option<Vec<String>>,
+        /// Host to send UDP voice traffic to (only if it differs from the BBS host)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        voice_address: Option<String>,
+        /// UDP port for voice traffic (only if it differs from the BBS port)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        voice_port: Option<u16>,
+        /// Whether the server relays voice over the BBS connection when UDP is blocked
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        tcp_relay: Option<bool>,
+        /// Whether the session is listen-only (joined a channel's broadcast)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        listener: Option<bool>,
+        /// Number of listeners if the channel is broadcasting
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        listeners: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// Response to VoiceLeave request
+    VoiceLeaveResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// Response to VoiceLimit request
+    VoiceLimitResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// Position in a channel's voice join queue
+    ///
+    /// Sent instead of `VoiceJoinResponse` when the channel's voice is full, and
+    /// again whenever the position changes. When a slot frees up the server sends
+    /// a regular `VoiceJoinResponse`; `position: None` means the user was removed
+    /// from the queue without joining.
+    VoiceQueueUpdate {
+        /// Channel the user is waiting to join voice in
+        target: String,
+        /// 1-based position in the queue (None = no longer queued)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        position: Option<u32>,
+    },
+    /// Notification that a user joined voice
+    VoiceUserJoined {
+        /// Nickname of the user who joined
+        nickname: String,
+        /// Target channel or the other user's nickname for user message voice
+        target: String,
+    },
+    /// Notification that a user left voice
+    VoiceUserLeft {
+        /// Nickname of the user who left
+        nickname: String,
+        /// Target channel or the other user's nickname for user message voice
+        target: String,
+    },
+    /// A channel's voice broadcast started, ended, or its listener count changed
+    ///
+    /// Sent to channel members with voice_listen permission.
+    VoiceBroadcastUpdate {
+        /// Channel being broadcast to
+        target: String,
+        /// Nickname of the broadcaster (None = the broadcast ended)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        broadcaster: Option<String>,
+        /// Number of users listening to the broadcast
+        listeners: u32,
+    },
+    /// Voice packet relayed over the BBS connection (TCP relay mode only)
+    VoiceRelay {
+        /// Base64-encoded `RelayedVoicePacket` (same bytes as a relayed UDP packet)
+        data: String,
+    },
+    /// Connection statistics for a voice participant
+    ///
+    /// Sent periodically to everyone in the same voice session, covering the
+    /// audio the server received from `nickname` since the previous report.
+    /// Clients use their own entry to adapt their encoder bitrate.
+    VoiceStats {
+        /// Nickname of the participant the statistics describe
+        nickname: String,
+        /// Percentage of packets lost on the way to the server (0-100)
+        loss_percent: u8,
+        /// Interarrival jitter in milliseconds
+        jitter_ms: u32,
+    },
+    /// Response to VoiceClipList request
+    VoiceClipListResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        /// Clips in the library, sorted by name (only on success)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        clips: Option<Vec<VoiceClipInfo>>,
+    },
+    /// Response to VoiceClipUpload request
+    VoiceClipUploadResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// Response to VoiceClipDelete request
+    VoiceClipDeleteResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// Response to VoiceClipPlay request
+    VoiceClipPlayResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// A soundboard clip was played in the voice session
+    ///
+    /// Sent to every participant of the session, including the user who
+    /// played it.
+    VoiceClipPlayed {
+        /// Nickname of the user who played the clip
+        nickname: String,
+        /// Target channel or the other user's nickname for user message voice
+        target: String,
+        /// Clip name
+        name: String,
+        /// Ogg Vorbis audio as a data URI (`data:audio/ogg;base64,...`)
+        data: String,
+    },
+    /// Response to VoiceTextSend request
+    VoiceTextSendResponse {
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// A text message was sent to the voice session
+    ///
+    /// Sent to every participant of the session, including the sender.
+    VoiceText {
+        /// Nickname of the user who sent the message
+        nickname: String,
+        /// Target channel or the other user's nickname for user message voice
+        target: String,
+        message: String,
+    },
+    /// Keepalive pong (server response to client Ping)
+    Pong {
+        /// The server's clock when it answered, so clients can keep their
+        /// skew estimate current
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        time: Option<ServerTime>,
+    },
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ServerInfo {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -976,6 +2434,15 @@ pub struct ServerInfo {
     pub max_connections_per_ip: Option<u32>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub max_transfers_per_ip: Option<u32>,
+    /// Max simultaneous active transfers server-wide (0 = unlimited, admin only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_active_transfers: Option<u32>,
+    /// Whether queued transfers with smaller total size start first (admin only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transfer_small_files_first: Option<bool>,
+    /// Fair-share weight for transfers from trusted IPs (admin only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trusted_transfer_weight: Option<u32>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub image: Option<String>,
     /// Port for file transfers (typically 7501)
@@ -992,6 +2459,18 @@ pub struct ServerInfo {
     /// Auto-join channels (space-separated, admin only)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub auto_join_channels: Option<String>,
+    /// Port forwarding state (admin only, only with `--upnp`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nat: Option<NatInfo>,
+    /// News image limits, so clients can check an image before posting it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub news_image_limits: Option<NewsImageLimits>,
+    /// Longest chat, user, or group message the server accepts, in bytes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_message_length: Option<u32>,
+    /// Server clock when the message was sent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<ServerTime>,
 }
 
 /// Channel info returned when joining a channel (in LoginResponse or ChatJoinResponse)
@@ -1007,6 +2486,37 @@ pub struct ChannelJoinInfo {
     /// Nicknames currently in voice chat (only if requester has voice_listen permission)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub voiced: Option<Vec<String>>,
+    /// Voice participant limit (only if the channel has one)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub voice_limit: Option<u32>,
+    /// Whether the topic is locked
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub topic_locked: bool,
+    /// Message shown to users joining the channel (only if the channel has one)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entry_message: Option<String>,
+    /// Channel rules (only if the channel has them)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rules: Option<String>,
+    /// Channel language tag (only if the channel has one)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+}
+
+/// One change of a channel's topic (for topic history)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChatTopicChange {
+    /// New topic (None = cleared)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub topic: Option<String>,
+    /// Topic before the change (None = there was none)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub old_topic: Option<String>,
+    /// Nickname of who changed it (None if the account was erased)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub set_by: Option<String>,
+    /// Unix timestamp (seconds since epoch)
+    pub changed_at: u64,
 }
 
 /// Channel info for channel lists
@@ -1017,6 +2527,9 @@ pub struct ChannelInfo {
     pub topic: Option<String>,
     pub member_count: u32,
     pub secret: bool,
+    /// Language tag (only if the channel has one)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
 }
 
 /// User info for lists. `nickname` is the display name (== username for regular accounts).
@@ -1036,6 +2549,47 @@ pub struct UserInfo {
     pub is_away: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<String>,
+    #[serde(default)]
+    pub is_dnd: bool,
+    /// Unix timestamp of the user's last chat or user message (for idle time)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_active: Option<i64>,
+    /// Client software of the user's latest session, if it sent one in the handshake
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client: Option<String>,
+}
+
+/// Who receives a broadcast (used in UserBroadcast)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum BroadcastTarget {
+    /// Every connected user
+    #[default]
+    All,
+    /// Members of a channel
+    Channel { channel: String },
+    /// Users with a permission (admins have every permission)
+    Permission { permission: String },
+}
+
+impl BroadcastTarget {
+    /// Whether the broadcast goes to everyone
+    pub fn is_all(&self) -> bool {
+        matches!(self, BroadcastTarget::All)
+    }
+}
+
+/// Presence of a watched account (used in PresenceSubscribeResponse and PresenceUpdate)
+///
+/// Shared account sessions don't count towards an account's presence.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PresenceInfo {
+    pub username: String,
+    pub online: bool,
+    #[serde(default)]
+    pub is_away: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -1045,13 +2599,83 @@ pub enum NewsAction {
     Deleted,
 }
 
+/// An attachment on a news post (used in NewsCreate, NewsUpdate and NewsItem)
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum NewsAttachment {
+    /// Image stored with the post
+    Image {
+        /// Image as a data URI
+        data: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        caption: Option<String>,
+    },
+    /// Reference to a file in the file area
+    File {
+        /// Path relative to the viewer's file area, like a `nexus://` files link
+        path: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        caption: Option<String>,
+    },
+}
+
+impl NewsAttachment {
+    /// Caption shown with the attachment, if any
+    pub fn caption(&self) -> Option<&str> {
+        match self {
+            NewsAttachment::Image { caption, .. } | NewsAttachment::File { caption, .. } => {
+                caption.as_deref()
+            }
+        }
+    }
+}
+
+// Manual Debug implementation so logs don't carry whole images
+impl std::fmt::Debug for NewsAttachment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NewsAttachment::Image { data, caption } => {
+                let mut s = f.debug_struct("Image");
+                if data.len() > 100 {
+                    s.field(
+                        "data",
+                        &format!("{}... ({} bytes)", &data[..100], data.len()),
+                    );
+                } else {
+                    s.field("data", data);
+                }
+                s.field("caption", caption).finish()
+            }
+            NewsAttachment::File { path, caption } => f
+                .debug_struct("File")
+                .field("path", path)
+                .field("caption", caption)
+                .finish(),
+        }
+    }
+}
+
+/// A direct message kept in the server's message history
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryMessage {
+    /// History ID (increases with each stored message)
+    pub id: i64,
+    pub from_nickname: String,
+    pub to_nickname: String,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "is_normal_action")]
+    pub action: ChatAction,
+    /// Unix timestamp (seconds since epoch)
+    pub timestamp: u64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NewsItem {
     pub id: i64,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub body: Option<String>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub image: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<NewsAttachment>,
     pub author: String,
     pub author_is_admin: bool,
     pub created_at: String,
@@ -1099,6 +2723,22 @@ pub struct TrustInfo {
     pub expires_at: Option<i64>,
 }
 
+/// A directory group mapped to permissions
+///
+/// Users who log in with directory credentials get the union of the
+/// permissions of the mapped groups they belong to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LdapGroupInfo {
+    /// Distinguished name of the group
+    pub group: String,
+    /// Permissions members get
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub permissions: Vec<String>,
+    /// Whether members are admins
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_admin: bool,
+}
+
 /// File search result entry
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct FileSearchResult {
@@ -1112,52 +2752,331 @@ pub struct FileSearchResult {
     pub modified: i64,
     /// True if this is a directory
     pub is_directory: bool,
+    /// Users' rating of the file (None if nobody has rated it)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rating: Option<FileRating>,
 }
 
-/// File entry in a directory listing
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub struct FileEntry {
-    /// Filesystem name (including any suffix like ` [NEXUS-UL]`; client strips for display)
-    pub name: String,
-    /// File size in bytes (0 for directories)
-    pub size: u64,
-    /// Last modified time as Unix timestamp
-    pub modified: i64,
-    /// Directory type (None = file, Some = directory with type)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub dir_type: Option<String>,
-    /// True if uploads are allowed at this location
-    pub can_upload: bool,
+/// Leftovers of aborted transfers found in the file area (used in FileCleanupResponse)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileCleanupReport {
+    /// `.part` files no upload will resume
+    pub orphaned_parts: u32,
+    /// Bytes held by the orphaned `.part` files
+    pub orphaned_bytes: u64,
+    /// Temporary files left by an interrupted index rebuild
+    pub temp_files: u32,
+    /// Bytes held by the temporary files
+    pub temp_bytes: u64,
+    /// Upload journal entries whose `.part` file is gone
+    pub missing_journal_entries: u32,
+    /// Search index entries whose file is gone
+    pub missing_index_entries: u32,
+    /// Paths of the first orphaned `.part` files, relative to the file root
+    #[serde(default)]
+    pub paths: Vec<String>,
+    /// Whether the leftovers were deleted (false for a report only)
+    pub deleted: bool,
 }
 
-/// Detailed file/directory information returned by FileInfo
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct FileInfoDetails {
-    /// File or directory name
-    pub name: String,
-    /// File size in bytes (0 for directories)
-    pub size: u64,
-    /// Creation time as Unix timestamp (None if filesystem doesn't support it)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<i64>,
-    /// Last modified time as Unix timestamp
-    pub modified: i64,
-    /// True if this is a directory
-    pub is_directory: bool,
-    /// True if this is a symbolic link
-    pub is_symlink: bool,
-    /// MIME type (None for directories)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub mime_type: Option<String>,
-    /// Number of items inside (directories only)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub item_count: Option<u64>,
-    /// SHA-256 hash of file contents (files only, None for directories)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub sha256: Option<String>,
+/// Kind of discrepancy found by FileCheck
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileCheckIssueKind {
+    /// On disk but not in the search index
+    NotIndexed,
+    /// In the search index but not on disk
+    IndexedButMissing,
+    /// The search index has a different size or type for it than the disk
+    IndexOutdated,
+    /// Download and upload history kept for a path that's gone
+    OrphanedActivity,
+    /// Ratings or comments kept for a path that's gone
+    OrphanedReviews,
+    /// Upload rule kept for a folder that's gone
+    OrphanedUploadRule,
 }
 
-/// Detailed user info. `nickname` is the display name (== username for regular accounts).
+/// Totals of a FileCheck (used in FileCheckResponse)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileCheckSummary {
+    /// Files and folders found on disk
+    pub entries: u32,
+    /// Discrepancies found, including any past the ones sent as FileCheckIssue
+    pub issues: u32,
+    /// Discrepancies repaired
+    pub repaired: u32,
+    /// Whether the search index was compared (false while it's being rebuilt
+    /// or before it's first built)
+    pub index_checked: bool,
+}
+
+/// Aggregate of the star ratings users gave a file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FileRating {
+    /// Number of users who rated the file
+    pub count: u32,
+    /// Sum of their ratings (1 to 5 stars each)
+    pub total: u32,
+}
+
+impl FileRating {
+    /// Average stars (0.0 when nobody has rated)
+    pub fn average(&self) -> f32 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.total as f32 / self.count as f32
+        }
+    }
+}
+
+/// Limits on what can be uploaded into a directory
+///
+/// A rule set on a directory also covers its subdirectories, unless one of
+/// them has its own rule.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct UploadRule {
+    /// Allowed file extensions, lowercase without the dot (empty = any type)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extensions: Vec<String>,
+    /// Largest file allowed, in bytes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_file_size: Option<u64>,
+    /// Largest upload allowed (all files together), in bytes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_total_size: Option<u64>,
+}
+
+impl UploadRule {
+    /// Whether the rule doesn't limit anything
+    pub fn is_empty(&self) -> bool {
+        self.extensions.is_empty() && self.max_file_size.is_none() && self.max_total_size.is_none()
+    }
+
+    /// Whether a file name has one of the allowed extensions
+    ///
+    /// Matching ignores case. Files without an extension only pass when
+    /// any type is allowed.
+    pub fn allows_extension(&self, file_name: &str) -> bool {
+        if self.extensions.is_empty() {
+            return true;
+        }
+        let Some((_, extension)) = file_name.rsplit_once('.') else {
+            return false;
+        };
+        self.extensions
+            .iter()
+            .any(|allowed| allowed.eq_ignore_ascii_case(extension))
+    }
+
+    /// Whether a single file of this size is allowed
+    pub fn allows_file_size(&self, size: u64) -> bool {
+        self.max_file_size.is_none_or(|max| size <= max)
+    }
+
+    /// Whether an upload of this total size is allowed
+    pub fn allows_total_size(&self, size: u64) -> bool {
+        self.max_total_size.is_none_or(|max| size <= max)
+    }
+}
+
+/// A comment a user left on a file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileComment {
+    pub id: i64,
+    /// Username of the author
+    pub author: String,
+    pub body: String,
+    /// Creation time as Unix timestamp
+    pub created_at: i64,
+}
+
+/// File in a most downloaded or recent uploads listing
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileActivity {
+    /// Full path relative to user's root (e.g., "/Documents/report.pdf")
+    pub path: String,
+    /// Filename only (e.g., "report.pdf")
+    pub name: String,
+    /// File size in bytes
+    pub size: u64,
+    /// Number of completed downloads
+    pub downloads: u64,
+    /// Upload time as Unix timestamp (None if the file wasn't uploaded through the server)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uploaded_at: Option<i64>,
+}
+
+/// File entry in a directory listing
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FileEntry {
+    /// Filesystem name (including any suffix like ` [NEXUS-UL]`; client strips for display)
+    pub name: String,
+    /// File size in bytes (0 for directories)
+    pub size: u64,
+    /// Last modified time as Unix timestamp
+    pub modified: i64,
+    /// Directory type (None = file, Some = directory with type)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dir_type: Option<String>,
+    /// True if uploads are allowed at this location
+    pub can_upload: bool,
+}
+
+/// Detailed file/directory information returned by FileInfo
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileInfoDetails {
+    /// File or directory name
+    pub name: String,
+    /// File size in bytes (0 for directories)
+    pub size: u64,
+    /// Creation time as Unix timestamp (None if filesystem doesn't support it)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<i64>,
+    /// Last modified time as Unix timestamp
+    pub modified: i64,
+    /// True if this is a directory
+    pub is_directory: bool,
+    /// True if this is a symbolic link
+    pub is_symlink: bool,
+    /// MIME type (None for directories)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+    /// Number of items inside (directories only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_count: Option<u64>,
+    /// SHA-256 hash of file contents (files only, None for directories)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+    /// Users' rating of the file (files only, None if nobody has rated it)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rating: Option<FileRating>,
+    /// Your own rating of the file (None if you haven't rated it)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub my_rating: Option<u8>,
+    /// Number of comments on the file (files only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_count: Option<u64>,
+}
+
+/// A single file in a directory download manifest
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileManifestEntry {
+    /// Path relative to the manifest directory, using `/` separators
+    pub path: String,
+    /// File size in bytes
+    pub size: u64,
+}
+
+/// A path to look up in a FileStat request
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileStatQuery {
+    /// Path to look up (same form as FileList paths)
+    pub path: String,
+    /// Size of the local file, so the server only hashes files that could match
+    pub size: u64,
+}
+
+/// A directory to watch in a FileWatch request
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FileWatchPath {
+    /// Directory path (same form as FileList paths)
+    pub path: String,
+    /// If true, the path is relative to file root instead of user's area (requires file_root permission)
+    #[serde(default)]
+    pub root: bool,
+}
+
+/// What happened to an entry in a watched directory (used in FileChanged)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileChangeKind {
+    /// A file or directory appeared, or a file was replaced by an upload
+    Added,
+    /// A file or directory is gone
+    Removed,
+    /// A file or directory was renamed within the directory
+    Renamed,
+}
+
+/// An existing path from a FileStat response
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileStatEntry {
+    /// Path as given in the request
+    pub path: String,
+    /// Size in bytes (0 for directories)
+    pub size: u64,
+    /// True if the path is a directory
+    pub is_directory: bool,
+    /// SHA-256 of the file, only when its size equals the queried size
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+}
+
+/// OpenID Connect identity provider advertised in the handshake
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OidcProvider {
+    /// Where the client sends the browser to sign in
+    pub authorization_endpoint: String,
+    /// Client ID registered with the provider for this server
+    pub client_id: String,
+}
+
+/// Result of a browser sign-in, redeemed by the server at the provider
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OidcLogin {
+    /// Authorization code from the redirect
+    pub code: String,
+    /// PKCE code verifier the challenge was derived from
+    pub code_verifier: String,
+    /// Redirect URI the code was issued for
+    pub redirect_uri: String,
+    /// Nonce the ID token must carry
+    pub nonce: String,
+}
+
+impl std::fmt::Debug for OidcLogin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OidcLogin")
+            .field("code", &"<REDACTED>")
+            .field("code_verifier", &"<REDACTED>")
+            .field("redirect_uri", &self.redirect_uri)
+            .field("nonce", &self.nonce)
+            .finish()
+    }
+}
+
+/// Soundboard clip in the server's library (used in VoiceClipListResponse)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VoiceClipInfo {
+    /// Clip name
+    pub name: String,
+    /// Username of the account that uploaded the clip
+    pub uploaded_by: String,
+    /// Size of the audio in bytes
+    pub size: u64,
+    /// Playback length in milliseconds
+    pub duration_ms: u64,
+}
+
+/// Text snippet stored on the server (used in SnippetGetResponse)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnippetInfo {
+    pub id: String,
+    /// Display name of the user who shared the snippet
+    pub author: String,
+    /// Language for syntax highlighting (None for plain text)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    pub content: String,
+    /// Unix timestamp the snippet was shared at
+    pub created_at: i64,
+    /// Unix timestamp the snippet is deleted at
+    pub expires_at: i64,
+}
+
+/// Detailed user info. `nickname` is the display name (== username for regular accounts).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserInfoDetailed {
     pub username: String,
@@ -1179,9 +3098,23 @@ pub struct UserInfoDetailed {
     pub is_away: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<String>,
+    #[serde(default)]
+    pub is_dnd: bool,
     /// Channels the user is currently in (secret channels only visible to admins)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub channels: Option<Vec<String>>,
+    /// Lifetime activity counters (None for shared accounts)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stats: Option<UserStats>,
+    /// Rank earned from the counters, if the server defines ranks
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rank: Option<String>,
+    /// Unix timestamp of the user's last chat or user message (for idle time)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_active: Option<i64>,
+    /// Client software of the user's latest session, if it sent one in the handshake
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client: Option<String>,
 }
 
 impl std::fmt::Debug for ClientMessage {
@@ -1191,11 +3124,13 @@ impl std::fmt::Debug for ClientMessage {
                 message,
                 action,
                 channel,
+                idempotency_key,
             } => f
                 .debug_struct("ChatSend")
                 .field("message", message)
                 .field("action", action)
                 .field("channel", channel)
+                .field("idempotency_key", idempotency_key)
                 .finish(),
             ClientMessage::ChatTopicUpdate { topic, channel } => f
                 .debug_struct("ChatTopicUpdate")
@@ -1210,15 +3145,43 @@ impl std::fmt::Debug for ClientMessage {
                 .debug_struct("ChatLeave")
                 .field("channel", channel)
                 .finish(),
-            ClientMessage::ChatList {} => f.debug_struct("ChatList").finish(),
+            ClientMessage::ChatList { language } => f
+                .debug_struct("ChatList")
+                .field("language", language)
+                .finish(),
             ClientMessage::ChatSecret { channel, secret } => f
                 .debug_struct("ChatSecret")
                 .field("channel", channel)
                 .field("secret", secret)
                 .finish(),
-            ClientMessage::Handshake { version } => f
+            ClientMessage::ChatTopicLock { channel, locked } => f
+                .debug_struct("ChatTopicLock")
+                .field("channel", channel)
+                .field("locked", locked)
+                .finish(),
+            ClientMessage::ChatTopicHistory { channel } => f
+                .debug_struct("ChatTopicHistory")
+                .field("channel", channel)
+                .finish(),
+            ClientMessage::ChatEntryUpdate {
+                channel,
+                entry_message,
+                rules,
+            } => f
+                .debug_struct("ChatEntryUpdate")
+                .field("channel", channel)
+                .field("entry_message", entry_message)
+                .field("rules", rules)
+                .finish(),
+            ClientMessage::ChatLanguage { channel, language } => f
+                .debug_struct("ChatLanguage")
+                .field("channel", channel)
+                .field("language", language)
+                .finish(),
+            ClientMessage::Handshake { version, client } => f
                 .debug_struct("Handshake")
                 .field("version", version)
+                .field("client", client)
                 .finish(),
             ClientMessage::Login {
                 username,
@@ -1227,6 +3190,9 @@ impl std::fmt::Debug for ClientMessage {
                 locale,
                 avatar,
                 nickname,
+                pow_nonce,
+                oidc,
+                login_token,
             } => f
                 .debug_struct("Login")
                 .field("username", username)
@@ -1244,10 +3210,25 @@ impl std::fmt::Debug for ClientMessage {
                     }),
                 )
                 .field("nickname", nickname)
+                .field("pow_nonce", pow_nonce)
+                .field("oidc", oidc)
+                .field("login_token", &login_token.as_ref().map(|_| "<REDACTED>"))
                 .finish(),
-            ClientMessage::UserBroadcast { message } => f
+            ClientMessage::UserBroadcast {
+                message,
+                target,
+                delay,
+                require_ack,
+            } => f
                 .debug_struct("UserBroadcast")
                 .field("message", message)
+                .field("target", target)
+                .field("delay", delay)
+                .field("require_ack", require_ack)
+                .finish(),
+            ClientMessage::BroadcastAck { broadcast_id } => f
+                .debug_struct("BroadcastAck")
+                .field("broadcast_id", broadcast_id)
                 .finish(),
             ClientMessage::UserCreate {
                 username,
@@ -1267,10 +3248,27 @@ impl std::fmt::Debug for ClientMessage {
                 .debug_struct("UserDelete")
                 .field("username", username)
                 .finish(),
+            ClientMessage::UserErase { username } => f
+                .debug_struct("UserErase")
+                .field("username", username)
+                .finish(),
             ClientMessage::UserEdit { username } => f
                 .debug_struct("UserEdit")
                 .field("username", username)
                 .finish(),
+            ClientMessage::PermissionAudit {
+                permission,
+                username,
+            } => f
+                .debug_struct("PermissionAudit")
+                .field("permission", permission)
+                .field("username", username)
+                .finish(),
+            ClientMessage::ImpersonateQuery { username, path } => f
+                .debug_struct("ImpersonateQuery")
+                .field("username", username)
+                .field("path", path)
+                .finish(),
             ClientMessage::UserInfo { nickname } => f
                 .debug_struct("UserInfo")
                 .field("nickname", nickname)
@@ -1287,11 +3285,13 @@ impl std::fmt::Debug for ClientMessage {
                 to_nickname,
                 message,
                 action,
+                idempotency_key,
             } => f
                 .debug_struct("UserMessage")
                 .field("to_nickname", to_nickname)
                 .field("message", message)
                 .field("action", action)
+                .field("idempotency_key", idempotency_key)
                 .finish(),
             ClientMessage::UserUpdate {
                 username,
@@ -1319,12 +3319,62 @@ impl std::fmt::Debug for ClientMessage {
                 .debug_struct("UserStatus")
                 .field("status", status)
                 .finish(),
+            ClientMessage::UserDnd { enabled } => {
+                f.debug_struct("UserDnd").field("enabled", enabled).finish()
+            }
+            ClientMessage::MessageHistory {
+                nickname,
+                before,
+                limit,
+            } => f
+                .debug_struct("MessageHistory")
+                .field("nickname", nickname)
+                .field("before", before)
+                .field("limit", limit)
+                .finish(),
+            ClientMessage::MessageHistoryRetention { days } => f
+                .debug_struct("MessageHistoryRetention")
+                .field("days", days)
+                .finish(),
+            ClientMessage::MessageMarkRead { nickname, id } => f
+                .debug_struct("MessageMarkRead")
+                .field("nickname", nickname)
+                .field("id", id)
+                .finish(),
+            ClientMessage::GroupMessageCreate { members } => f
+                .debug_struct("GroupMessageCreate")
+                .field("members", members)
+                .finish(),
+            ClientMessage::GroupMessageSend {
+                group_id,
+                message,
+                action,
+                idempotency_key,
+            } => f
+                .debug_struct("GroupMessageSend")
+                .field("group_id", group_id)
+                .field("message", message)
+                .field("action", action)
+                .field("idempotency_key", idempotency_key)
+                .finish(),
+            ClientMessage::GroupMessageInvite { group_id, nickname } => f
+                .debug_struct("GroupMessageInvite")
+                .field("group_id", group_id)
+                .field("nickname", nickname)
+                .finish(),
+            ClientMessage::GroupMessageLeave { group_id } => f
+                .debug_struct("GroupMessageLeave")
+                .field("group_id", group_id)
+                .finish(),
             ClientMessage::ServerInfoUpdate {
                 name,
                 description,
                 file_reindex_interval,
                 max_connections_per_ip,
                 max_transfers_per_ip,
+                max_active_transfers,
+                transfer_small_files_first,
+                trusted_transfer_weight,
                 image,
                 persistent_channels,
                 auto_join_channels,
@@ -1334,6 +3384,9 @@ impl std::fmt::Debug for ClientMessage {
                     .field("description", description)
                     .field("max_connections_per_ip", max_connections_per_ip)
                     .field("max_transfers_per_ip", max_transfers_per_ip)
+                    .field("max_active_transfers", max_active_transfers)
+                    .field("transfer_small_files_first", transfer_small_files_first)
+                    .field("trusted_transfer_weight", trusted_transfer_weight)
                     .field("file_reindex_interval", file_reindex_interval)
                     .field("persistent_channels", persistent_channels)
                     .field("auto_join_channels", auto_join_channels);
@@ -1353,7 +3406,11 @@ impl std::fmt::Debug for ClientMessage {
             }
             ClientMessage::NewsList => f.debug_struct("NewsList").finish(),
             ClientMessage::NewsShow { id } => f.debug_struct("NewsShow").field("id", id).finish(),
-            ClientMessage::NewsCreate { body, image } => {
+            ClientMessage::NewsCreate {
+                body,
+                image,
+                attachments,
+            } => {
                 let mut s = f.debug_struct("NewsCreate");
                 s.field("body", body);
                 if let Some(img) = image {
@@ -1368,10 +3425,16 @@ impl std::fmt::Debug for ClientMessage {
                 } else {
                     s.field("image", &None::<String>);
                 }
+                s.field("attachments", attachments);
                 s.finish()
             }
             ClientMessage::NewsEdit { id } => f.debug_struct("NewsEdit").field("id", id).finish(),
-            ClientMessage::NewsUpdate { id, body, image } => {
+            ClientMessage::NewsUpdate {
+                id,
+                body,
+                image,
+                attachments,
+            } => {
                 let mut s = f.debug_struct("NewsUpdate");
                 s.field("id", id).field("body", body);
                 if let Some(img) = image {
@@ -1386,11 +3449,37 @@ impl std::fmt::Debug for ClientMessage {
                 } else {
                     s.field("image", &None::<String>);
                 }
+                s.field("attachments", attachments);
                 s.finish()
             }
             ClientMessage::NewsDelete { id } => {
                 f.debug_struct("NewsDelete").field("id", id).finish()
             }
+            ClientMessage::NewsSearch {
+                query,
+                author,
+                since,
+                until,
+            } => f
+                .debug_struct("NewsSearch")
+                .field("query", query)
+                .field("author", author)
+                .field("since", since)
+                .field("until", until)
+                .finish(),
+            ClientMessage::SnippetCreate {
+                content,
+                language,
+                expires_in,
+            } => f
+                .debug_struct("SnippetCreate")
+                .field("content_len", &content.len())
+                .field("language", language)
+                .field("expires_in", expires_in)
+                .finish(),
+            ClientMessage::SnippetGet { id } => {
+                f.debug_struct("SnippetGet").field("id", id).finish()
+            }
             ClientMessage::FileList {
                 path,
                 root,
@@ -1417,6 +3506,20 @@ impl std::fmt::Debug for ClientMessage {
                 .field("path", path)
                 .field("root", root)
                 .finish(),
+            ClientMessage::FileManifest { path, root } => f
+                .debug_struct("FileManifest")
+                .field("path", path)
+                .field("root", root)
+                .finish(),
+            ClientMessage::FileStat { files, root } => f
+                .debug_struct("FileStat")
+                .field("files", files)
+                .field("root", root)
+                .finish(),
+            ClientMessage::FileWatch { directories } => f
+                .debug_struct("FileWatch")
+                .field("directories", directories)
+                .finish(),
             ClientMessage::FileRename {
                 path,
                 new_name,
@@ -1455,10 +3558,15 @@ impl std::fmt::Debug for ClientMessage {
                 .field("source_root", source_root)
                 .field("destination_root", destination_root)
                 .finish(),
-            ClientMessage::FileDownload { path, root } => f
+            ClientMessage::FileDownload {
+                path,
+                root,
+                include,
+            } => f
                 .debug_struct("FileDownload")
                 .field("path", path)
                 .field("root", root)
+                .field("include", include)
                 .finish(),
             ClientMessage::FileStartResponse { size, sha256 } => f
                 .debug_struct("FileStartResponse")
@@ -1470,12 +3578,18 @@ impl std::fmt::Debug for ClientMessage {
                 file_count,
                 total_size,
                 root,
+                overwrite,
             } => f
                 .debug_struct("FileUpload")
                 .field("destination", destination)
                 .field("file_count", file_count)
                 .field("total_size", total_size)
                 .field("root", root)
+                .field("overwrite", overwrite)
+                .finish(),
+            ClientMessage::ServerExport { include_ip_rules } => f
+                .debug_struct("ServerExport")
+                .field("include_ip_rules", include_ip_rules)
                 .finish(),
             ClientMessage::FileStart { path, size, sha256 } => f
                 .debug_struct("FileStart")
@@ -1517,17 +3631,149 @@ impl std::fmt::Debug for ClientMessage {
                 .finish(),
             ClientMessage::TrustList => f.debug_struct("TrustList").finish(),
             ClientMessage::ConnectionMonitor => f.debug_struct("ConnectionMonitor").finish(),
+            ClientMessage::LogSubscribe => f.debug_struct("LogSubscribe").finish(),
+            ClientMessage::NatStatus => f.debug_struct("NatStatus").finish(),
+            ClientMessage::ServerStats => f.debug_struct("ServerStats").finish(),
+            ClientMessage::ConnectivityCheck => f.debug_struct("ConnectivityCheck").finish(),
+            ClientMessage::StatsRequest { days } => {
+                f.debug_struct("StatsRequest").field("days", days).finish()
+            }
+            ClientMessage::Leaderboard { stat } => {
+                f.debug_struct("Leaderboard").field("stat", stat).finish()
+            }
+            ClientMessage::DoorList => f.debug_struct("DoorList").finish(),
+            ClientMessage::DoorOpen { name, cols, rows } => f
+                .debug_struct("DoorOpen")
+                .field("name", name)
+                .field("cols", cols)
+                .field("rows", rows)
+                .finish(),
+            ClientMessage::DoorData { data } => f
+                .debug_struct("DoorData")
+                .field("data_len", &data.len())
+                .finish(),
+            ClientMessage::DoorClose => f.debug_struct("DoorClose").finish(),
+            ClientMessage::LogUnsubscribe => f.debug_struct("LogUnsubscribe").finish(),
             ClientMessage::FileSearch { query, root } => f
                 .debug_struct("FileSearch")
                 .field("query", query)
                 .field("root", root)
                 .finish(),
             ClientMessage::FileReindex => f.debug_struct("FileReindex").finish(),
-            ClientMessage::VoiceJoin { target } => {
-                f.debug_struct("VoiceJoin").field("target", target).finish()
+            ClientMessage::FileCleanup { delete } => f
+                .debug_struct("FileCleanup")
+                .field("delete", delete)
+                .finish(),
+            ClientMessage::FileCheck { repair } => {
+                f.debug_struct("FileCheck").field("repair", repair).finish()
+            }
+            ClientMessage::FilePopular { root } => {
+                f.debug_struct("FilePopular").field("root", root).finish()
+            }
+            ClientMessage::FileRecent { root } => {
+                f.debug_struct("FileRecent").field("root", root).finish()
+            }
+            ClientMessage::FileRate { path, rating, root } => f
+                .debug_struct("FileRate")
+                .field("path", path)
+                .field("rating", rating)
+                .field("root", root)
+                .finish(),
+            ClientMessage::FileCommentCreate { path, body, root } => f
+                .debug_struct("FileCommentCreate")
+                .field("path", path)
+                .field("body", body)
+                .field("root", root)
+                .finish(),
+            ClientMessage::FileCommentList { path, root } => f
+                .debug_struct("FileCommentList")
+                .field("path", path)
+                .field("root", root)
+                .finish(),
+            ClientMessage::FileCommentDelete { id } => {
+                f.debug_struct("FileCommentDelete").field("id", id).finish()
             }
+            ClientMessage::UploadRuleGet { path, root } => f
+                .debug_struct("UploadRuleGet")
+                .field("path", path)
+                .field("root", root)
+                .finish(),
+            ClientMessage::UploadRuleSet { path, rule, root } => f
+                .debug_struct("UploadRuleSet")
+                .field("path", path)
+                .field("rule", rule)
+                .field("root", root)
+                .finish(),
+            ClientMessage::LdapGroupList => f.debug_struct("LdapGroupList").finish(),
+            ClientMessage::LdapGroupSet {
+                group,
+                permissions,
+                is_admin,
+            } => f
+                .debug_struct("LdapGroupSet")
+                .field("group", group)
+                .field("permissions", permissions)
+                .field("is_admin", is_admin)
+                .finish(),
+            ClientMessage::LdapGroupDelete { group } => f
+                .debug_struct("LdapGroupDelete")
+                .field("group", group)
+                .finish(),
+            ClientMessage::GuestUpgrade { username, .. } => f
+                .debug_struct("GuestUpgrade")
+                .field("username", username)
+                .field("password", &"<REDACTED>")
+                .finish(),
+            ClientMessage::NicknameRegister { nickname, username } => f
+                .debug_struct("NicknameRegister")
+                .field("nickname", nickname)
+                .field("username", username)
+                .finish(),
+            ClientMessage::NicknameRelease { nickname } => f
+                .debug_struct("NicknameRelease")
+                .field("nickname", nickname)
+                .finish(),
+            ClientMessage::PresenceSubscribe { usernames } => f
+                .debug_struct("PresenceSubscribe")
+                .field("usernames", usernames)
+                .finish(),
+            ClientMessage::VoiceJoin { target, broadcast } => f
+                .debug_struct("VoiceJoin")
+                .field("target", target)
+                .field("broadcast", broadcast)
+                .finish(),
             ClientMessage::VoiceLeave => f.debug_struct("VoiceLeave").finish(),
+            ClientMessage::VoiceLimit { channel, limit } => f
+                .debug_struct("VoiceLimit")
+                .field("channel", channel)
+                .field("limit", limit)
+                .finish(),
+            ClientMessage::VoiceRelay { data } => f
+                .debug_struct("VoiceRelay")
+                .field("data_len", &data.len())
+                .finish(),
+            ClientMessage::VoiceClipList => f.debug_struct("VoiceClipList").finish(),
+            ClientMessage::VoiceClipUpload { name, data } => f
+                .debug_struct("VoiceClipUpload")
+                .field("name", name)
+                .field("data_len", &data.len())
+                .finish(),
+            ClientMessage::VoiceClipDelete { name } => f
+                .debug_struct("VoiceClipDelete")
+                .field("name", name)
+                .finish(),
+            ClientMessage::VoiceClipPlay { name } => {
+                f.debug_struct("VoiceClipPlay").field("name", name).finish()
+            }
+            ClientMessage::VoiceTextSend { message } => f
+                .debug_struct("VoiceTextSend")
+                .field("message", message)
+                .finish(),
             ClientMessage::Ping => f.debug_struct("Ping").finish(),
+            ClientMessage::Cancel { message_id } => f
+                .debug_struct("Cancel")
+                .field("message_id", message_id)
+                .finish(),
         }
     }
 }
@@ -1545,6 +3791,9 @@ mod tests {
             locale: "en".to_string(),
             avatar: None,
             nickname: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
         };
         let json = serde_json::to_string(&msg).unwrap();
         assert!(json.contains("\"type\":\"Login\""));
@@ -1566,6 +3815,9 @@ mod tests {
                 locale,
                 avatar,
                 nickname,
+                pow_nonce,
+                oidc,
+                login_token,
             } => {
                 assert_eq!(username, "alice");
                 assert_eq!(password, "secret");
@@ -1573,6 +3825,9 @@ mod tests {
                 assert_eq!(locale, "en");
                 assert!(avatar.is_none());
                 assert!(nickname.is_none());
+                assert!(pow_nonce.is_none());
+                assert!(oidc.is_none());
+                assert!(login_token.is_none());
             }
             _ => panic!("Expected Login message"),
         }
@@ -1587,6 +3842,9 @@ mod tests {
             locale: "en".to_string(),
             avatar: None,
             nickname: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
         };
         let debug_output = format!("{:?}", msg);
         assert!(debug_output.contains("alice"));
@@ -1596,17 +3854,55 @@ mod tests {
     }
 
     #[test]
-    fn test_serialize_login_response() {
-        let msg = ServerMessage::LoginResponse {
-            success: true,
-            session_id: Some(12345),
-            is_admin: Some(false),
-            permissions: Some(vec!["user_list".to_string()]),
-            server_info: None,
-            locale: Some("en".to_string()),
-            channels: None,
+    fn test_debug_redacts_guest_upgrade_password() {
+        let msg = ClientMessage::GuestUpgrade {
+            username: "alice".to_string(),
+            password: "super_secret_password".to_string(),
+        };
+        let debug_output = format!("{:?}", msg);
+        assert!(debug_output.contains("alice"));
+        assert!(!debug_output.contains("super_secret_password"));
+        assert!(debug_output.contains("REDACTED"));
+    }
+
+    #[test]
+    fn test_debug_redacts_sso_secrets() {
+        let msg = ClientMessage::Login {
+            username: String::new(),
+            password: String::new(),
+            features: vec![],
+            locale: "en".to_string(),
+            avatar: None,
+            nickname: None,
+            pow_nonce: None,
+            oidc: Some(OidcLogin {
+                code: "secret_auth_code".to_string(),
+                code_verifier: "secret_code_verifier".to_string(),
+                redirect_uri: "http://127.0.0.1:51234/callback".to_string(),
+                nonce: "n0nce".to_string(),
+            }),
+            login_token: Some("secret_login_token".to_string()),
+        };
+        let debug_output = format!("{:?}", msg);
+        assert!(!debug_output.contains("secret_auth_code"));
+        assert!(!debug_output.contains("secret_code_verifier"));
+        assert!(!debug_output.contains("secret_login_token"));
+        assert!(debug_output.contains("http://127.0.0.1:51234/callback"));
+    }
+
+    #[test]
+    fn test_serialize_login_response() {
+        let msg = ServerMessage::LoginResponse {
+            success: true,
+            session_id: Some(12345),
+            is_admin: Some(false),
+            permissions: Some(vec!["user_list".to_string()]),
+            server_info: None,
+            locale: Some("en".to_string()),
+            channels: None,
             nickname: Some("testuser".to_string()),
             error: None,
+            login_token: None,
         };
         let json = serde_json::to_string(&msg).unwrap();
         assert!(json.contains("\"type\":\"LoginResponse\""));
@@ -1626,6 +3922,7 @@ mod tests {
             channels: None,
             nickname: None,
             error: Some("Invalid credentials".to_string()),
+            login_token: None,
         };
         let json = serde_json::to_string(&msg).unwrap();
         assert!(json.contains("\"success\":false"));
@@ -1644,6 +3941,7 @@ mod tests {
             channels: None,
             nickname: Some("admin".to_string()),
             error: None,
+            login_token: None,
         };
         let json = serde_json::to_string(&msg).unwrap();
         assert!(json.contains("\"type\":\"LoginResponse\""));
@@ -1664,6 +3962,7 @@ mod tests {
             channels: None,
             nickname: Some("regularuser".to_string()),
             error: None,
+            login_token: None,
         };
         let json = serde_json::to_string(&msg).unwrap();
         assert!(json.contains("\"type\":\"LoginResponse\""));
@@ -1683,6 +3982,9 @@ mod tests {
             locale: "en".to_string(),
             avatar: Some(avatar_data.clone()),
             nickname: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
         };
         let json = serde_json::to_string(&msg).unwrap();
         assert!(json.contains("\"avatar\""));
@@ -1715,6 +4017,9 @@ mod tests {
             avatar: Some(avatar_data.clone()),
             is_away: false,
             status: None,
+            is_dnd: false,
+            last_active: None,
+            client: None,
         };
         let json = serde_json::to_string(&user_info).unwrap();
         assert!(json.contains("\"avatar\""));
@@ -1734,11 +4039,44 @@ mod tests {
             avatar: None,
             is_away: false,
             status: None,
+            is_dnd: false,
+            last_active: None,
+            client: None,
         };
         let json = serde_json::to_string(&user_info).unwrap();
         assert!(!json.contains("\"avatar\""));
     }
 
+    #[test]
+    fn test_user_info_last_active_optional() {
+        // Older servers don't send last_active
+        let json = r#"{"username":"alice","nickname":"alice","login_time":1234567890,"is_admin":false,"session_ids":[1],"locale":"en"}"#;
+        let user_info: UserInfo = serde_json::from_str(json).unwrap();
+        assert_eq!(user_info.last_active, None);
+
+        let user_info = UserInfo {
+            last_active: Some(1234567999),
+            ..user_info
+        };
+        let json = serde_json::to_string(&user_info).unwrap();
+        assert!(json.contains("\"last_active\":1234567999"));
+    }
+
+    #[test]
+    fn test_handshake_client_optional() {
+        // Older clients don't send a client name
+        let json = r#"{"type":"Handshake","version":"0.5.0"}"#;
+        let msg: ClientMessage = serde_json::from_str(json).unwrap();
+        assert!(matches!(msg, ClientMessage::Handshake { client: None, .. }));
+
+        let msg = ClientMessage::Handshake {
+            version: "0.5.0".to_string(),
+            client: Some("Nexus BBS 0.5.0".to_string()),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains("\"client\":\"Nexus BBS 0.5.0\""));
+    }
+
     #[test]
     fn test_serialize_user_info_detailed_with_avatar() {
         let avatar_data = "data:image/png;base64,iVBORw0KGgo=".to_string();
@@ -1756,7 +4094,12 @@ mod tests {
             addresses: None,
             is_away: false,
             status: None,
+            is_dnd: false,
             channels: None,
+            stats: None,
+            rank: None,
+            last_active: None,
+            client: None,
         };
         let json = serde_json::to_string(&user_info).unwrap();
         assert!(json.contains("\"avatar\""));
@@ -1773,6 +4116,9 @@ mod tests {
             locale: "en".to_string(),
             avatar: Some(large_avatar.clone()),
             nickname: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
         };
         let debug_output = format!("{:?}", msg);
         assert!(debug_output.contains("..."));
@@ -1780,6 +4126,267 @@ mod tests {
         assert!(!debug_output.contains(&large_avatar));
     }
 
+    #[test]
+    fn test_serialize_news_attachments() {
+        let msg = ClientMessage::NewsCreate {
+            body: None,
+            image: None,
+            attachments: vec![
+                NewsAttachment::Image {
+                    data: "data:image/png;base64,iVBORw0KGgo=".to_string(),
+                    caption: Some("Lobby".to_string()),
+                },
+                NewsAttachment::File {
+                    path: "Music/set.mp3".to_string(),
+                    caption: None,
+                },
+            ],
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"NewsCreate","attachments":[{"kind":"image","data":"data:image/png;base64,iVBORw0KGgo=","caption":"Lobby"},{"kind":"file","path":"Music/set.mp3"}]}"#
+        );
+        let parsed: ClientMessage = serde_json::from_str(&json).unwrap();
+        match parsed {
+            ClientMessage::NewsCreate { attachments, .. } => {
+                assert_eq!(attachments.len(), 2);
+                assert_eq!(attachments[0].caption(), Some("Lobby"));
+                assert_eq!(attachments[1].caption(), None);
+            }
+            _ => panic!("Wrong message type"),
+        }
+    }
+
+    #[test]
+    fn test_deserialize_news_create_without_attachments() {
+        // Clients that predate attachments send a single image
+        let json = r#"{"type":"NewsCreate","body":"Hi","image":"data:image/png;base64,AAAA"}"#;
+        match serde_json::from_str::<ClientMessage>(json).unwrap() {
+            ClientMessage::NewsCreate {
+                image, attachments, ..
+            } => {
+                assert_eq!(image.as_deref(), Some("data:image/png;base64,AAAA"));
+                assert!(attachments.is_empty());
+            }
+            _ => panic!("Wrong message type"),
+        }
+    }
+
+    #[test]
+    fn test_debug_news_attachment_truncates_large_image() {
+        let large_image = format!("data:image/png;base64,{}", "A".repeat(1000));
+        let msg = ClientMessage::NewsUpdate {
+            id: 1,
+            body: None,
+            image: None,
+            attachments: vec![NewsAttachment::Image {
+                data: large_image.clone(),
+                caption: None,
+            }],
+        };
+        let debug_output = format!("{:?}", msg);
+        assert!(debug_output.contains("bytes"));
+        assert!(!debug_output.contains(&large_image));
+    }
+
+    #[test]
+    fn test_serialize_news_search() {
+        let msg = ClientMessage::NewsSearch {
+            query: "release notes".to_string(),
+            author: Some("alice".to_string()),
+            since: Some("2024-01-01".to_string()),
+            until: None,
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"NewsSearch","query":"release notes","author":"alice","since":"2024-01-01"}"#
+        );
+
+        // Filters only: the query can be left out
+        let json = r#"{"type":"NewsSearch","author":"bob"}"#;
+        match serde_json::from_str::<ClientMessage>(json).unwrap() {
+            ClientMessage::NewsSearch {
+                query,
+                author,
+                since,
+                until,
+            } => {
+                assert!(query.is_empty());
+                assert_eq!(author.as_deref(), Some("bob"));
+                assert!(since.is_none());
+                assert!(until.is_none());
+            }
+            _ => panic!("Expected NewsSearch"),
+        }
+    }
+
+    #[test]
+    fn test_serialize_snippet_create() {
+        let msg = ClientMessage::SnippetCreate {
+            content: "fn main() {}\n".to_string(),
+            language: Some("rs".to_string()),
+            expires_in: 86400,
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"SnippetCreate","content":"fn main() {}\n","language":"rs","expires_in":86400}"#
+        );
+
+        // Logs get the length, not the text
+        let debug_output = format!("{msg:?}");
+        assert!(debug_output.contains("content_len: 13"));
+        assert!(!debug_output.contains("fn main"));
+    }
+
+    #[test]
+    fn test_deserialize_snippet_get_response() {
+        let json = r#"{"type":"SnippetGetResponse","success":true,"snippet":{"id":"aZ09bY18cX","author":"alice","content":"hello","created_at":1700000000,"expires_at":1700086400}}"#;
+        match serde_json::from_str::<ServerMessage>(json).unwrap() {
+            ServerMessage::SnippetGetResponse {
+                success,
+                error,
+                snippet,
+            } => {
+                assert!(success);
+                assert!(error.is_none());
+                let snippet = snippet.unwrap();
+                assert_eq!(snippet.author, "alice");
+                assert!(snippet.language.is_none());
+                assert_eq!(snippet.expires_at, 1700086400);
+            }
+            _ => panic!("Expected SnippetGetResponse"),
+        }
+    }
+
+    #[test]
+    fn test_serialize_message_history() {
+        let msg = ClientMessage::MessageHistory {
+            nickname: Some("alice".to_string()),
+            before: Some(42),
+            limit: None,
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"MessageHistory","nickname":"alice","before":42}"#
+        );
+
+        let msg = ServerMessage::MessageHistoryResponse {
+            success: true,
+            error: None,
+            messages: Some(vec![HistoryMessage {
+                id: 41,
+                from_nickname: "alice".to_string(),
+                to_nickname: "bob".to_string(),
+                message: "waves".to_string(),
+                action: ChatAction::Me,
+                timestamp: 1_700_000_000,
+            }]),
+            has_more: Some(false),
+            retention_days: Some(30),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        match serde_json::from_str::<ServerMessage>(&json).unwrap() {
+            ServerMessage::MessageHistoryResponse {
+                messages,
+                retention_days,
+                ..
+            } => {
+                let messages = messages.unwrap();
+                assert_eq!(messages[0].action, ChatAction::Me);
+                assert_eq!(messages[0].to_nickname, "bob");
+                assert_eq!(retention_days, Some(30));
+            }
+            _ => panic!("Expected MessageHistoryResponse"),
+        }
+    }
+
+    #[test]
+    fn test_serialize_message_receipts() {
+        let msg = ClientMessage::MessageMarkRead {
+            nickname: "alice".to_string(),
+            id: 12,
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"MessageMarkRead","nickname":"alice","id":12}"#
+        );
+
+        let msg = ServerMessage::MessageRead {
+            nickname: "bob".to_string(),
+            id: 12,
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(json, r#"{"type":"MessageRead","nickname":"bob","id":12}"#);
+
+        // Messages from older servers carry no ID
+        let json = r#"{"type":"UserMessage","from_nickname":"bob","from_admin":false,"to_nickname":"alice","message":"hi"}"#;
+        match serde_json::from_str::<ServerMessage>(json).unwrap() {
+            ServerMessage::UserMessage { id, .. } => assert_eq!(id, None),
+            _ => panic!("Expected UserMessage"),
+        }
+    }
+
+    #[test]
+    fn test_serialize_idempotency_key() {
+        let msg = ClientMessage::ChatSend {
+            message: "hi".to_string(),
+            action: ChatAction::Normal,
+            channel: "#nexus".to_string(),
+            idempotency_key: Some("67e5504410b1426f9247bb680e5fe0c8".to_string()),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(
+            json,
+            r##"{"type":"ChatSend","message":"hi","channel":"#nexus","idempotency_key":"67e5504410b1426f9247bb680e5fe0c8"}"##
+        );
+
+        // Messages from older clients carry no key
+        let json = r#"{"type":"UserMessage","to_nickname":"bob","message":"hi"}"#;
+        match serde_json::from_str::<ClientMessage>(json).unwrap() {
+            ClientMessage::UserMessage {
+                idempotency_key, ..
+            } => assert_eq!(idempotency_key, None),
+            _ => panic!("Expected UserMessage"),
+        }
+
+        let msg = ServerMessage::ChatSendResponse { success: true };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(json, r#"{"type":"ChatSendResponse","success":true}"#);
+    }
+
+    #[test]
+    fn test_serialize_group_messages() {
+        let msg = ClientMessage::GroupMessageSend {
+            group_id: 3,
+            message: "hi all".to_string(),
+            action: ChatAction::Normal,
+            idempotency_key: None,
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"GroupMessageSend","group_id":3,"message":"hi all"}"#
+        );
+
+        let msg = ServerMessage::GroupMembers {
+            group_id: 3,
+            members: vec!["alice".to_string(), "bob".to_string()],
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        match serde_json::from_str::<ServerMessage>(&json).unwrap() {
+            ServerMessage::GroupMembers { group_id, members } => {
+                assert_eq!(group_id, 3);
+                assert_eq!(members, vec!["alice", "bob"]);
+            }
+            _ => panic!("Expected GroupMembers"),
+        }
+    }
+
     #[test]
     fn test_serialize_login_with_nickname() {
         let msg = ClientMessage::Login {
@@ -1789,6 +4396,9 @@ mod tests {
             locale: "en".to_string(),
             avatar: None,
             nickname: Some("Nick1".to_string()),
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
         };
         let json = serde_json::to_string(&msg).unwrap();
         assert!(json.contains("\"nickname\":\"Nick1\""));
@@ -1803,6 +4413,9 @@ mod tests {
             locale: "en".to_string(),
             avatar: None,
             nickname: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
         };
         let json = serde_json::to_string(&msg).unwrap();
         assert!(!json.contains("\"nickname\""));
@@ -1831,33 +4444,416 @@ mod tests {
             permissions: vec!["chat_send".to_string()],
         };
         let json = serde_json::to_string(&msg).unwrap();
-        assert!(json.contains("\"type\":\"UserCreate\""));
-        assert!(json.contains("\"is_shared\":true"));
-        assert!(json.contains("\"is_admin\":false"));
+        assert!(json.contains("\"type\":\"UserCreate\""));
+        assert!(json.contains("\"is_shared\":true"));
+        assert!(json.contains("\"is_admin\":false"));
+    }
+
+    #[test]
+    fn test_deserialize_user_create_with_is_shared() {
+        let json = r#"{"type":"UserCreate","username":"shared_acct","password":"secret","is_admin":false,"is_shared":true,"enabled":true,"permissions":["chat_send"]}"#;
+        let msg: ClientMessage = serde_json::from_str(json).unwrap();
+        match msg {
+            ClientMessage::UserCreate { is_shared, .. } => {
+                assert!(is_shared);
+            }
+            _ => panic!("Expected UserCreate message"),
+        }
+    }
+
+    #[test]
+    fn test_deserialize_user_create_defaults_is_shared_false() {
+        let json = r#"{"type":"UserCreate","username":"alice","password":"secret","is_admin":false,"enabled":true,"permissions":[]}"#;
+        let msg: ClientMessage = serde_json::from_str(json).unwrap();
+        match msg {
+            ClientMessage::UserCreate { is_shared, .. } => {
+                assert!(!is_shared);
+            }
+            _ => panic!("Expected UserCreate message"),
+        }
+    }
+
+    #[test]
+    fn test_deserialize_permission_audit_by_permission() {
+        let json = r#"{"type":"PermissionAudit","permission":"file_delete"}"#;
+        let msg: ClientMessage = serde_json::from_str(json).unwrap();
+        match msg {
+            ClientMessage::PermissionAudit {
+                permission,
+                username,
+            } => {
+                assert_eq!(permission.as_deref(), Some("file_delete"));
+                assert!(username.is_none());
+            }
+            _ => panic!("Expected PermissionAudit message"),
+        }
+    }
+
+    #[test]
+    fn test_serialize_permission_audit_response_effective() {
+        let msg = ServerMessage::PermissionAuditResponse {
+            success: true,
+            error: None,
+            holders: None,
+            effective: Some(EffectivePermissions {
+                username: "shared_acct".to_string(),
+                is_admin: false,
+                is_shared: true,
+                enabled: true,
+                permissions: vec![EffectivePermission {
+                    permission: "chat_send".to_string(),
+                    source: PermissionSource::Granted,
+                }],
+                restricted: vec!["file_delete".to_string()],
+            }),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains("\"type\":\"PermissionAuditResponse\""));
+        assert!(json.contains("\"source\":\"granted\""));
+        assert!(json.contains("\"restricted\":[\"file_delete\"]"));
+        assert!(!json.contains("\"holders\""));
+    }
+
+    #[test]
+    fn test_deserialize_impersonate_query_without_path() {
+        let json = r#"{"type":"ImpersonateQuery","username":"alice"}"#;
+        let msg: ClientMessage = serde_json::from_str(json).unwrap();
+        match msg {
+            ClientMessage::ImpersonateQuery { username, path } => {
+                assert_eq!(username, "alice");
+                assert!(path.is_none());
+            }
+            _ => panic!("Expected ImpersonateQuery message"),
+        }
+    }
+
+    #[test]
+    fn test_deserialize_nickname_register_without_username() {
+        let json = r#"{"type":"NicknameRegister","nickname":"Alice"}"#;
+        let msg: ClientMessage = serde_json::from_str(json).unwrap();
+        match msg {
+            ClientMessage::NicknameRegister { nickname, username } => {
+                assert_eq!(nickname, "Alice");
+                assert!(username.is_none());
+            }
+            _ => panic!("Expected NicknameRegister message"),
+        }
+
+        let msg = ClientMessage::NicknameRegister {
+            nickname: "Alice".to_string(),
+            username: None,
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(!json.contains("\"username\""));
+    }
+
+    #[test]
+    fn test_serialize_impersonate_query_response_without_files() {
+        let msg = ServerMessage::ImpersonateQueryResponse {
+            success: true,
+            error: None,
+            effective: None,
+            path: None,
+            entries: None,
+            channels: Some(vec![ChannelInfo {
+                name: "#general".to_string(),
+                topic: None,
+                member_count: 2,
+                secret: false,
+                language: None,
+            }]),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains("\"type\":\"ImpersonateQueryResponse\""));
+        assert!(json.contains("\"channels\":[{\"name\":\"#general\""));
+        assert!(!json.contains("\"entries\""));
+        assert!(!json.contains("\"path\""));
+    }
+
+    #[test]
+    fn test_serialize_log_line() {
+        let msg = ServerMessage::LogLine {
+            entry: LogEntry {
+                timestamp: 1718234567,
+                level: LogLevel::Warn,
+                module: "handlers::login".to_string(),
+                message: "Invalid login attempt".to_string(),
+            },
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains("\"type\":\"LogLine\""));
+        assert!(json.contains("\"level\":\"warn\""));
+        assert!(json.contains("\"module\":\"handlers::login\""));
+    }
+
+    #[test]
+    fn test_serialize_nat_status_response() {
+        let msg = ServerMessage::NatStatusResponse {
+            success: true,
+            error: None,
+            nat: Some(NatInfo {
+                method: Some(NatMethod::NatPmp),
+                external_ip: Some("203.0.113.7".to_string()),
+                mapped: true,
+                tcp_ports: vec![7500, 7501],
+                udp_ports: vec![7500],
+                checked_at: 1718234567,
+                error: None,
+            }),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains("\"type\":\"NatStatusResponse\""));
+        assert!(json.contains("\"method\":\"nat-pmp\""));
+        assert!(json.contains("\"tcp_ports\":[7500,7501]"));
+        assert!(!json.contains("\"error\""));
+
+        let parsed: ServerMessage = serde_json::from_str(&json).unwrap();
+        match parsed {
+            ServerMessage::NatStatusResponse { nat: Some(nat), .. } => {
+                assert_eq!(nat.method, Some(NatMethod::NatPmp));
+                assert!(nat.mapped);
+            }
+            _ => panic!("Expected NatStatusResponse"),
+        }
+    }
+
+    #[test]
+    fn test_serialize_connectivity_check_response() {
+        let msg = ServerMessage::ConnectivityCheckResponse {
+            success: true,
+            error: None,
+            external_ip: Some("203.0.113.7".to_string()),
+            ports: Some(vec![
+                PortCheck {
+                    service: CheckedService::TransferWebsocket,
+                    port: 7503,
+                    state: PortReachability::Filtered,
+                },
+                PortCheck {
+                    service: CheckedService::Voice,
+                    port: 7500,
+                    state: PortReachability::Untested,
+                },
+            ]),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains("\"type\":\"ConnectivityCheckResponse\""));
+        assert!(json.contains("\"service\":\"transfer-websocket\""));
+        assert!(json.contains("\"state\":\"untested\""));
+        assert!(!json.contains("\"error\""));
+
+        let parsed: ServerMessage = serde_json::from_str(&json).unwrap();
+        match parsed {
+            ServerMessage::ConnectivityCheckResponse {
+                ports: Some(ports), ..
+            } => {
+                assert_eq!(ports.len(), 2);
+                assert_eq!(ports[0].state, PortReachability::Filtered);
+            }
+            _ => panic!("Expected ConnectivityCheckResponse"),
+        }
+    }
+
+    #[test]
+    fn test_serialize_stats_response() {
+        let msg = ServerMessage::StatsResponse {
+            success: true,
+            error: None,
+            days: Some(vec![DailyStats {
+                date: "2026-01-17".to_string(),
+                logins: 12,
+                messages: 340,
+                uploads: 2,
+                upload_bytes: 1_048_576,
+                downloads: 5,
+                download_bytes: 73_400_320,
+                peak_sessions: 7,
+            }]),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains("\"type\":\"StatsResponse\""));
+        assert!(json.contains("\"date\":\"2026-01-17\""));
+        assert!(json.contains("\"peak_sessions\":7"));
+        assert!(!json.contains("\"error\""));
+
+        let parsed: ServerMessage = serde_json::from_str(&json).unwrap();
+        match parsed {
+            ServerMessage::StatsResponse {
+                days: Some(days), ..
+            } => {
+                assert_eq!(days.len(), 1);
+                assert_eq!(days[0].download_bytes, 73_400_320);
+            }
+            _ => panic!("Expected StatsResponse"),
+        }
+    }
+
+    #[test]
+    fn test_serialize_server_stats_response() {
+        let msg = ServerMessage::ServerStatsResponse {
+            success: true,
+            error: None,
+            stats: Some(ServerStatsInfo {
+                sessions: 4,
+                connections: 5,
+                active_transfers: 1,
+                disk_free: Some(1_000_000),
+                disk_total: Some(8_000_000),
+                recent_audit: vec![AuditEntry {
+                    action: "user_erase".to_string(),
+                    target: "bob".to_string(),
+                    actor: "admin".to_string(),
+                    details: None,
+                    created_at: 1_760_000_000,
+                }],
+                ..Default::default()
+            }),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains("\"type\":\"ServerStatsResponse\""));
+        assert!(json.contains("\"disk_free\":1000000"));
+        assert!(!json.contains("\"details\""));
+
+        let parsed: ServerMessage = serde_json::from_str(&json).unwrap();
+        match parsed {
+            ServerMessage::ServerStatsResponse {
+                stats: Some(stats), ..
+            } => {
+                assert_eq!(stats.sessions, 4);
+                assert_eq!(stats.recent_audit[0].target, "bob");
+            }
+            _ => panic!("Expected ServerStatsResponse"),
+        }
+
+        // Without a file area the disk fields are left out
+        let json = r#"{"sessions":1,"connections":1,"transfer_connections":0,"active_transfers":0,"queued_transfers":0}"#;
+        let stats: ServerStatsInfo = serde_json::from_str(json).unwrap();
+        assert_eq!(stats.disk_free, None);
+        assert!(stats.recent_audit.is_empty());
+    }
+
+    #[test]
+    fn test_serialize_storage_alert() {
+        let msg = ServerMessage::StorageAlert {
+            volume: StorageVolume::Files,
+            low: true,
+            free: 500,
+            total: 8_000,
+            threshold: 1_000,
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains("\"type\":\"StorageAlert\""));
+        assert!(json.contains("\"volume\":\"files\""));
+
+        let parsed: ServerMessage = serde_json::from_str(&json).unwrap();
+        match parsed {
+            ServerMessage::StorageAlert {
+                volume, low, free, ..
+            } => {
+                assert_eq!(volume, StorageVolume::Files);
+                assert!(low);
+                assert_eq!(free, 500);
+            }
+            _ => panic!("Expected StorageAlert"),
+        }
+    }
+
+    #[test]
+    fn test_deserialize_leaderboard_defaults_to_logins() {
+        let json = r#"{"type":"Leaderboard"}"#;
+        let msg: ClientMessage = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            msg,
+            ClientMessage::Leaderboard {
+                stat: LeaderboardStat::Logins
+            }
+        ));
+
+        let json = r#"{"type":"Leaderboard","stat":"download_bytes"}"#;
+        let msg: ClientMessage = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            msg,
+            ClientMessage::Leaderboard {
+                stat: LeaderboardStat::DownloadBytes
+            }
+        ));
+    }
+
+    #[test]
+    fn test_serialize_leaderboard_response() {
+        let msg = ServerMessage::LeaderboardResponse {
+            success: true,
+            error: None,
+            entries: Some(vec![LeaderboardEntry {
+                username: "alice".to_string(),
+                stats: UserStats {
+                    logins: 42,
+                    messages: 1000,
+                    uploads: 3,
+                    download_bytes: 5_242_880,
+                    last_login_at: None,
+                },
+                rank: None,
+            }]),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains("\"type\":\"LeaderboardResponse\""));
+        assert!(json.contains("\"logins\":42"));
+        assert!(!json.contains("\"rank\""));
+
+        let parsed: ServerMessage = serde_json::from_str(&json).unwrap();
+        match parsed {
+            ServerMessage::LeaderboardResponse {
+                entries: Some(entries),
+                ..
+            } => {
+                assert_eq!(entries.len(), 1);
+                assert_eq!(LeaderboardStat::Messages.value(&entries[0].stats), 1000);
+            }
+            _ => panic!("Expected LeaderboardResponse"),
+        }
+    }
+
+    #[test]
+    fn test_serialize_door_open() {
+        let msg = ClientMessage::DoorOpen {
+            name: "lord".to_string(),
+            cols: 80,
+            rows: 24,
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains("\"type\":\"DoorOpen\""));
+        assert!(json.contains("\"cols\":80"));
+
+        // Keyboard input isn't logged
+        let msg = ClientMessage::DoorData {
+            data: "cGFzc3dvcmQ=".to_string(),
+        };
+        let debug = format!("{:?}", msg);
+        assert!(!debug.contains("cGFzc3dvcmQ="));
+        assert!(debug.contains("data_len: 12"));
     }
 
     #[test]
-    fn test_deserialize_user_create_with_is_shared() {
-        let json = r#"{"type":"UserCreate","username":"shared_acct","password":"secret","is_admin":false,"is_shared":true,"enabled":true,"permissions":["chat_send"]}"#;
-        let msg: ClientMessage = serde_json::from_str(json).unwrap();
-        match msg {
-            ClientMessage::UserCreate { is_shared, .. } => {
-                assert!(is_shared);
-            }
-            _ => panic!("Expected UserCreate message"),
-        }
+    fn test_deserialize_door_closed() {
+        let json = r#"{"type":"DoorClosed"}"#;
+        let msg: ServerMessage = serde_json::from_str(json).unwrap();
+        assert!(matches!(msg, ServerMessage::DoorClosed { exit_code: None }));
+
+        let json = r#"{"type":"DoorClosed","exit_code":1}"#;
+        let msg: ServerMessage = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            msg,
+            ServerMessage::DoorClosed { exit_code: Some(1) }
+        ));
     }
 
     #[test]
-    fn test_deserialize_user_create_defaults_is_shared_false() {
-        let json = r#"{"type":"UserCreate","username":"alice","password":"secret","is_admin":false,"enabled":true,"permissions":[]}"#;
-        let msg: ClientMessage = serde_json::from_str(json).unwrap();
-        match msg {
-            ClientMessage::UserCreate { is_shared, .. } => {
-                assert!(!is_shared);
-            }
-            _ => panic!("Expected UserCreate message"),
-        }
+    fn test_log_level_ordering() {
+        // Most severe first, so "at least Warn" is `level <= LogLevel::Warn`
+        assert!(LogLevel::Error < LogLevel::Warn);
+        assert!(LogLevel::Warn < LogLevel::Info);
+        assert!(LogLevel::Info < LogLevel::Debug);
     }
 
     #[test]
@@ -1884,6 +4880,9 @@ mod tests {
         let user_info = UserInfo {
             is_away: false,
             status: None,
+            is_dnd: false,
+            last_active: None,
+            client: None,
             username: "shared_acct".to_string(),
             nickname: "Nick1".to_string(),
             login_time: 1234567890,
@@ -1904,6 +4903,9 @@ mod tests {
         let user_info = UserInfo {
             is_away: false,
             status: None,
+            is_dnd: false,
+            last_active: None,
+            client: None,
             username: "alice".to_string(),
             nickname: "alice".to_string(),
             login_time: 1234567890,
@@ -1924,6 +4926,7 @@ mod tests {
         let user_info = UserInfoDetailed {
             is_away: false,
             status: None,
+            is_dnd: false,
             username: "shared_acct".to_string(),
             nickname: "Nick1".to_string(),
             login_time: 1234567890,
@@ -1936,6 +4939,10 @@ mod tests {
             is_admin: Some(false),
             addresses: None,
             channels: None,
+            stats: None,
+            rank: None,
+            last_active: None,
+            client: None,
         };
         let json = serde_json::to_string(&user_info).unwrap();
         assert!(json.contains("\"username\":\"shared_acct\""));
@@ -1985,11 +4992,13 @@ mod tests {
             message: "Hello!".to_string(),
             action: ChatAction::Normal,
             timestamp: 1718234567,
+            id: Some(7),
         };
         let json = serde_json::to_string(&msg).unwrap();
         assert!(json.contains("\"type\":\"UserMessage\""));
         assert!(json.contains("\"from_nickname\":\"Nick1\""));
         assert!(json.contains("\"to_nickname\":\"alice\""));
+        assert!(json.contains("\"id\":7"));
     }
 
     #[test]
@@ -2055,6 +5064,7 @@ mod tests {
             to_nickname: "Nick1".to_string(),
             message: "Hello!".to_string(),
             action: ChatAction::Normal,
+            idempotency_key: None,
         };
         let json = serde_json::to_string(&msg).unwrap();
         assert!(json.contains("\"type\":\"UserMessage\""));
@@ -2202,11 +5212,24 @@ mod tests {
         let msg = ClientMessage::FileDownload {
             path: "/Games".to_string(),
             root: false,
+            include: None,
         };
         let json = serde_json::to_string(&msg).unwrap();
         assert!(json.contains("\"type\":\"FileDownload\""));
         assert!(json.contains("\"path\":\"/Games\""));
         assert!(json.contains("\"root\":false"));
+        assert!(!json.contains("include"));
+    }
+
+    #[test]
+    fn test_serialize_file_download_with_include() {
+        let msg = ClientMessage::FileDownload {
+            path: "/Games".to_string(),
+            root: false,
+            include: Some(vec!["doom.zip".to_string(), "maps/e1m1.wad".to_string()]),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains("\"include\":[\"doom.zip\",\"maps/e1m1.wad\"]"));
     }
 
     #[test]
@@ -2214,7 +5237,7 @@ mod tests {
         let json = r#"{"type":"FileDownload","path":"/Games","root":true}"#;
         let msg: ClientMessage = serde_json::from_str(json).unwrap();
         match msg {
-            ClientMessage::FileDownload { path, root } => {
+            ClientMessage::FileDownload { path, root, .. } => {
                 assert_eq!(path, "/Games");
                 assert!(root);
             }
@@ -2227,14 +5250,102 @@ mod tests {
         let json = r#"{"type":"FileDownload","path":"/Games"}"#;
         let msg: ClientMessage = serde_json::from_str(json).unwrap();
         match msg {
-            ClientMessage::FileDownload { path, root } => {
+            ClientMessage::FileDownload {
+                path,
+                root,
+                include,
+            } => {
                 assert_eq!(path, "/Games");
                 assert!(!root);
+                assert!(include.is_none());
             }
             _ => panic!("Expected FileDownload"),
         }
     }
 
+    #[test]
+    fn test_deserialize_server_export_defaults() {
+        let json = r#"{"type":"ServerExport"}"#;
+        let msg: ClientMessage = serde_json::from_str(json).unwrap();
+        match msg {
+            ClientMessage::ServerExport { include_ip_rules } => assert!(!include_ip_rules),
+            _ => panic!("Expected ServerExport"),
+        }
+    }
+
+    #[test]
+    fn test_serialize_file_manifest_response() {
+        let msg = ServerMessage::FileManifestResponse {
+            success: true,
+            error: None,
+            path: Some("/Games".to_string()),
+            files: Some(vec![FileManifestEntry {
+                path: "maps/e1m1.wad".to_string(),
+                size: 1024,
+            }]),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains("\"type\":\"FileManifestResponse\""));
+        assert!(json.contains("\"files\":[{\"path\":\"maps/e1m1.wad\",\"size\":1024}]"));
+        assert!(!json.contains("error"));
+
+        let decoded: ServerMessage = serde_json::from_str(&json).unwrap();
+        match decoded {
+            ServerMessage::FileManifestResponse { files, .. } => {
+                assert_eq!(files.unwrap()[0].size, 1024);
+            }
+            _ => panic!("Expected FileManifestResponse"),
+        }
+    }
+
+    #[test]
+    fn test_serialize_file_stat_roundtrip() {
+        let msg = ClientMessage::FileStat {
+            files: vec![FileStatQuery {
+                path: "/Uploads/song.mp3".to_string(),
+                size: 2048,
+            }],
+            root: false,
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains("\"type\":\"FileStat\""));
+        assert!(json.contains("\"files\":[{\"path\":\"/Uploads/song.mp3\",\"size\":2048}]"));
+
+        let response = ServerMessage::FileStatResponse {
+            success: true,
+            error: None,
+            files: Some(vec![
+                FileStatEntry {
+                    path: "/Uploads/song.mp3".to_string(),
+                    size: 2048,
+                    is_directory: false,
+                    sha256: Some("ab".repeat(32)),
+                },
+                FileStatEntry {
+                    path: "/Uploads/album".to_string(),
+                    size: 0,
+                    is_directory: true,
+                    sha256: None,
+                },
+            ]),
+        };
+        let json = serde_json::to_string(&response).unwrap();
+        assert!(json.contains("\"type\":\"FileStatResponse\""));
+        assert_eq!(json.matches("sha256").count(), 1);
+        assert!(!json.contains("error"));
+
+        let decoded: ServerMessage = serde_json::from_str(&json).unwrap();
+        match decoded {
+            ServerMessage::FileStatResponse { files, .. } => {
+                let files = files.unwrap();
+                assert_eq!(files.len(), 2);
+                assert!(files[1].is_directory);
+                assert_eq!(files[1].sha256, None);
+            }
+            _ => panic!("Expected FileStatResponse"),
+        }
+    }
+
     #[test]
     fn test_serialize_file_download_response_success() {
         let msg = ServerMessage::FileDownloadResponse {
@@ -2410,6 +5521,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_serialize_transfer_queued() {
+        let msg = ServerMessage::TransferQueued { position: 3 };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(json, r#"{"type":"TransferQueued","position":3}"#);
+
+        let parsed: ServerMessage = serde_json::from_str(&json).unwrap();
+        match parsed {
+            ServerMessage::TransferQueued { position } => assert_eq!(position, 3),
+            _ => panic!("Expected TransferQueued"),
+        }
+    }
+
+    #[test]
+    fn test_deserialize_transfer_info_defaults_scheduler_fields() {
+        // Older servers don't send queued/weight
+        let json = r#"{"nickname":"alice","username":"alice","ip":"192.168.1.1","port":7501,"is_admin":false,"is_shared":false,"direction":"download","path":"/file.zip","total_size":100,"bytes_transferred":0,"started_at":0}"#;
+        let info: TransferInfo = serde_json::from_str(json).unwrap();
+        assert!(!info.queued);
+        assert_eq!(info.weight, 1);
+    }
+
     #[test]
     fn test_deserialize_file_download_response() {
         let json = r#"{"type":"FileDownloadResponse","success":true,"size":1048576,"file_count":10,"transfer_id":"aabbccdd"}"#;
@@ -2442,12 +5575,26 @@ mod tests {
             version: Some("0.5.0".to_string()),
             max_connections_per_ip: Some(5),
             max_transfers_per_ip: Some(3),
+            max_active_transfers: None,
+            transfer_small_files_first: None,
+            trusted_transfer_weight: None,
             image: None,
             transfer_port: 7501,
             transfer_websocket_port: Some(7503),
             file_reindex_interval: Some(5),
             persistent_channels: None,
             auto_join_channels: None,
+            nat: None,
+            news_image_limits: Some(NewsImageLimits {
+                max_size: 262_144,
+                max_dimension: 1600,
+            }),
+            max_message_length: Some(1024),
+            time: Some(ServerTime {
+                timestamp: 1_760_000_000,
+                utc_offset: 7200,
+                timezone: Some("Europe/Berlin".to_string()),
+            }),
         };
         let json = serde_json::to_string(&info).unwrap();
         assert!(json.contains("\"max_transfers_per_ip\":3"));
@@ -2459,6 +5606,42 @@ mod tests {
         assert_eq!(parsed.max_transfers_per_ip, Some(3));
         assert_eq!(parsed.transfer_port, 7501);
         assert_eq!(parsed.transfer_websocket_port, Some(7503));
+        assert_eq!(parsed.max_message_length, Some(1024));
+        assert_eq!(
+            parsed.news_image_limits,
+            Some(NewsImageLimits {
+                max_size: 262_144,
+                max_dimension: 1600,
+            })
+        );
+        assert_eq!(
+            parsed.time,
+            Some(ServerTime {
+                timestamp: 1_760_000_000,
+                utc_offset: 7200,
+                timezone: Some("Europe/Berlin".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn test_pong_time() {
+        let pong = ServerMessage::Pong {
+            time: Some(ServerTime {
+                timestamp: 1_760_000_000,
+                utc_offset: -18_000,
+                timezone: None,
+            }),
+        };
+        let json = serde_json::to_string(&pong).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"Pong","time":{"timestamp":1760000000,"utc_offset":-18000}}"#
+        );
+
+        // Servers before the clock was added send a bare Pong
+        let parsed: ServerMessage = serde_json::from_str(r#"{"type":"Pong"}"#).unwrap();
+        assert!(matches!(parsed, ServerMessage::Pong { time: None }));
     }
 
     #[test]
@@ -2484,6 +5667,7 @@ mod tests {
             file_count: 5,
             total_size: 1048576,
             root: false,
+            overwrite: false,
         };
         let json = serde_json::to_string(&msg).unwrap();
         assert!(json.contains("\"type\":\"FileUpload\""));
@@ -2491,6 +5675,7 @@ mod tests {
         assert!(json.contains("\"file_count\":5"));
         assert!(json.contains("\"total_size\":1048576"));
         assert!(!json.contains("\"root\":true"));
+        assert!(!json.contains("overwrite"));
     }
 
     #[test]
@@ -2503,6 +5688,7 @@ mod tests {
                 file_count,
                 total_size,
                 root,
+                ..
             } => {
                 assert_eq!(destination, "/My Uploads");
                 assert_eq!(file_count, 10);
@@ -2520,8 +5706,11 @@ mod tests {
             r#"{"type":"FileUpload","destination":"/Uploads","file_count":1,"total_size":100}"#;
         let msg: ClientMessage = serde_json::from_str(json).unwrap();
         match msg {
-            ClientMessage::FileUpload { root, .. } => {
+            ClientMessage::FileUpload {
+                root, overwrite, ..
+            } => {
                 assert!(!root);
+                assert!(!overwrite);
             }
             _ => panic!("Expected FileUpload"),
         }
@@ -2647,6 +5836,247 @@ mod tests {
         assert!(matches!(msg, ClientMessage::FileReindex));
     }
 
+    #[test]
+    fn test_deserialize_file_cleanup_defaults_to_report() {
+        let json = r#"{"type":"FileCleanup"}"#;
+        let msg: ClientMessage = serde_json::from_str(json).unwrap();
+        assert!(matches!(msg, ClientMessage::FileCleanup { delete: false }));
+    }
+
+    #[test]
+    fn test_serialize_chat_topic_lock() {
+        let msg = ClientMessage::ChatTopicLock {
+            channel: "#general".to_string(),
+            locked: true,
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(
+            json,
+            r##"{"type":"ChatTopicLock","channel":"#general","locked":true}"##
+        );
+    }
+
+    #[test]
+    fn test_serialize_chat_entry_update() {
+        let msg = ClientMessage::ChatEntryUpdate {
+            channel: "#general".to_string(),
+            entry_message: Some("Welcome!".to_string()),
+            rules: None,
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(
+            json,
+            r##"{"type":"ChatEntryUpdate","channel":"#general","entry_message":"Welcome!"}"##
+        );
+
+        let decoded: ClientMessage =
+            serde_json::from_str(r##"{"type":"ChatEntryUpdate","channel":"#general","rules":""}"##)
+                .unwrap();
+        let ClientMessage::ChatEntryUpdate {
+            entry_message,
+            rules,
+            ..
+        } = decoded
+        else {
+            panic!("expected ChatEntryUpdate");
+        };
+        assert_eq!(entry_message, None);
+        assert_eq!(rules.as_deref(), Some(""));
+    }
+
+    #[test]
+    fn test_serialize_chat_language() {
+        let msg = ClientMessage::ChatLanguage {
+            channel: "#general".to_string(),
+            language: "pt-BR".to_string(),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(
+            json,
+            r##"{"type":"ChatLanguage","channel":"#general","language":"pt-BR"}"##
+        );
+    }
+
+    #[test]
+    fn test_chat_list_language() {
+        let msg = ClientMessage::ChatList { language: None };
+        assert_eq!(
+            serde_json::to_string(&msg).unwrap(),
+            r#"{"type":"ChatList"}"#
+        );
+
+        let decoded: ClientMessage =
+            serde_json::from_str(r#"{"type":"ChatList","language":"de"}"#).unwrap();
+        let ClientMessage::ChatList { language } = decoded else {
+            panic!("expected ChatList");
+        };
+        assert_eq!(language.as_deref(), Some("de"));
+    }
+
+    #[test]
+    fn test_serialize_voice_text() {
+        let msg = ClientMessage::VoiceTextSend {
+            message: "your mic is muted".to_string(),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"VoiceTextSend","message":"your mic is muted"}"#
+        );
+
+        let msg = ServerMessage::VoiceText {
+            nickname: "alice".to_string(),
+            target: "#general".to_string(),
+            message: "your mic is muted".to_string(),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(
+            json,
+            r##"{"type":"VoiceText","nickname":"alice","target":"#general","message":"your mic is muted"}"##
+        );
+    }
+
+    #[test]
+    fn test_chat_topic_history_response_roundtrip() {
+        let msg = ServerMessage::ChatTopicHistoryResponse {
+            success: true,
+            error: None,
+            channel: Some("#general".to_string()),
+            changes: Some(vec![
+                ChatTopicChange {
+                    topic: None,
+                    old_topic: Some("Welcome".to_string()),
+                    set_by: Some("alice".to_string()),
+                    changed_at: 1_700_000_100,
+                },
+                ChatTopicChange {
+                    topic: Some("Welcome".to_string()),
+                    old_topic: None,
+                    set_by: None,
+                    changed_at: 1_700_000_000,
+                },
+            ]),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(
+            json.contains(r#"{"old_topic":"Welcome","set_by":"alice","changed_at":1700000100}"#)
+        );
+        assert!(json.contains(r#"{"topic":"Welcome","changed_at":1700000000}"#));
+
+        let decoded: ServerMessage = serde_json::from_str(&json).unwrap();
+        let ServerMessage::ChatTopicHistoryResponse { changes, .. } = decoded else {
+            panic!("expected ChatTopicHistoryResponse");
+        };
+        assert_eq!(changes.unwrap()[1].topic.as_deref(), Some("Welcome"));
+    }
+
+    #[test]
+    fn test_serialize_cancel() {
+        let msg = ClientMessage::Cancel {
+            message_id: "0123456789ab".to_string(),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(json, r#"{"type":"Cancel","message_id":"0123456789ab"}"#);
+    }
+
+    #[test]
+    fn test_serialize_file_watch_messages() {
+        let msg = ClientMessage::FileWatch {
+            directories: vec![FileWatchPath {
+                path: "/Podcasts".to_string(),
+                root: false,
+            }],
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"FileWatch","directories":[{"path":"/Podcasts","root":false}]}"#
+        );
+
+        let msg = ServerMessage::FileChanged {
+            path: "/Podcasts".to_string(),
+            root: false,
+            kind: FileChangeKind::Renamed,
+            name: "draft.mp3".to_string(),
+            new_name: Some("episode-12.mp3".to_string()),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains("\"kind\":\"renamed\""));
+        assert!(json.contains("\"new_name\":\"episode-12.mp3\""));
+
+        let json = r#"{"type":"FileChanged","path":"","kind":"added","name":"song.mp3"}"#;
+        match serde_json::from_str::<ServerMessage>(json).unwrap() {
+            ServerMessage::FileChanged {
+                root,
+                kind,
+                new_name,
+                ..
+            } => {
+                assert!(!root);
+                assert_eq!(kind, FileChangeKind::Added);
+                assert!(new_name.is_none());
+            }
+            other => panic!("Expected FileChanged, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_serialize_file_check_messages() {
+        let msg = ClientMessage::FileCheck { repair: true };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(json, r#"{"type":"FileCheck","repair":true}"#);
+
+        let msg = ServerMessage::FileCheckIssue {
+            kind: FileCheckIssueKind::OrphanedUploadRule,
+            path: "/shared/Gone [NEXUS-UL]".to_string(),
+            repaired: false,
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains("\"kind\":\"orphaned_upload_rule\""));
+
+        let json = r#"{"type":"FileCheckResponse","success":true,"summary":{"entries":12,"issues":1,"repaired":0,"index_checked":true}}"#;
+        match serde_json::from_str::<ServerMessage>(json).unwrap() {
+            ServerMessage::FileCheckResponse {
+                summary: Some(summary),
+                ..
+            } => {
+                assert_eq!(summary.entries, 12);
+                assert!(summary.index_checked);
+            }
+            other => panic!("Expected FileCheckResponse, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_serialize_file_cleanup_response() {
+        let msg = ServerMessage::FileCleanupResponse {
+            success: true,
+            error: None,
+            report: Some(FileCleanupReport {
+                orphaned_parts: 1,
+                orphaned_bytes: 4096,
+                paths: vec!["/shared/song.mp3.part".to_string()],
+                ..Default::default()
+            }),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains("\"type\":\"FileCleanupResponse\""));
+        assert!(json.contains("\"orphaned_bytes\":4096"));
+        assert!(json.contains("\"deleted\":false"));
+
+        let parsed: ServerMessage = serde_json::from_str(&json).unwrap();
+        match parsed {
+            ServerMessage::FileCleanupResponse {
+                report: Some(report),
+                ..
+            } => {
+                assert_eq!(report.orphaned_parts, 1);
+                assert_eq!(report.paths, vec!["/shared/song.mp3.part"]);
+            }
+            _ => panic!("Expected FileCleanupResponse"),
+        }
+    }
+
     #[test]
     fn test_serialize_file_search_response_success() {
         let msg = ServerMessage::FileSearchResponse {
@@ -2658,6 +6088,7 @@ mod tests {
                 size: 12345,
                 modified: 1700000000,
                 is_directory: false,
+                rating: None,
             }]),
         };
         let json = serde_json::to_string(&msg).unwrap();
@@ -2706,6 +6137,184 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_file_popular_root_defaults_to_false() {
+        let json = r#"{"type":"FilePopular"}"#;
+        let msg: ClientMessage = serde_json::from_str(json).unwrap();
+        assert!(matches!(msg, ClientMessage::FilePopular { root: false }));
+    }
+
+    #[test]
+    fn test_serialize_file_recent_response() {
+        let msg = ServerMessage::FileRecentResponse {
+            success: true,
+            error: None,
+            files: Some(vec![FileActivity {
+                path: "/Music/song.mp3".to_string(),
+                name: "song.mp3".to_string(),
+                size: 4096,
+                downloads: 3,
+                uploaded_at: Some(1700000000),
+            }]),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains("\"type\":\"FileRecentResponse\""));
+        assert!(json.contains("\"uploaded_at\":1700000000"));
+
+        let decoded: ServerMessage = serde_json::from_str(&json).unwrap();
+        match decoded {
+            ServerMessage::FileRecentResponse { files, .. } => {
+                let files = files.unwrap();
+                assert_eq!(files[0].path, "/Music/song.mp3");
+                assert_eq!(files[0].downloads, 3);
+            }
+            _ => panic!("Expected FileRecentResponse"),
+        }
+    }
+
+    #[test]
+    fn test_serialize_file_rate() {
+        let msg = ClientMessage::FileRate {
+            path: "/Music/song.mp3".to_string(),
+            rating: 4,
+            root: false,
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains("\"type\":\"FileRate\""));
+        assert!(json.contains("\"rating\":4"));
+
+        let json = r#"{"type":"FileCommentList","path":"/Music/song.mp3"}"#;
+        let msg: ClientMessage = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            msg,
+            ClientMessage::FileCommentList { root: false, .. }
+        ));
+    }
+
+    #[test]
+    fn test_file_rating_average() {
+        let rating = FileRating {
+            count: 3,
+            total: 11,
+        };
+        assert!((rating.average() - 11.0 / 3.0).abs() < f32::EPSILON);
+        assert_eq!(FileRating { count: 0, total: 0 }.average(), 0.0);
+    }
+
+    #[test]
+    fn test_file_search_result_rating_optional() {
+        // Results from older servers have no rating
+        let json = r#"{"path":"/a.txt","name":"a.txt","size":1,"modified":0,"is_directory":false}"#;
+        let result: FileSearchResult = serde_json::from_str(json).unwrap();
+        assert_eq!(result.rating, None);
+
+        let result = FileSearchResult {
+            rating: Some(FileRating { count: 2, total: 9 }),
+            ..result
+        };
+        let json = serde_json::to_string(&result).unwrap();
+        assert!(json.contains("\"rating\":{\"count\":2,\"total\":9}"));
+    }
+
+    #[test]
+    fn test_deserialize_file_comment_list_response() {
+        let json = r#"{"type":"FileCommentListResponse","success":true,"comments":[{"id":7,"author":"alice","body":"Nice","created_at":1700000000}]}"#;
+        let msg: ServerMessage = serde_json::from_str(json).unwrap();
+        match msg {
+            ServerMessage::FileCommentListResponse {
+                success, comments, ..
+            } => {
+                assert!(success);
+                let comments = comments.unwrap();
+                assert_eq!(comments.len(), 1);
+                assert_eq!(comments[0].author, "alice");
+                assert_eq!(comments[0].id, 7);
+            }
+            _ => panic!("Expected FileCommentListResponse"),
+        }
+    }
+
+    #[test]
+    fn test_upload_rule_allows() {
+        let rule = UploadRule {
+            extensions: vec!["mp3".to_string(), "flac".to_string()],
+            max_file_size: Some(100),
+            max_total_size: Some(1000),
+        };
+        assert!(rule.allows_extension("song.mp3"));
+        assert!(rule.allows_extension("Song.FLAC"));
+        assert!(!rule.allows_extension("song.mp3.exe"));
+        assert!(!rule.allows_extension("README"));
+        assert!(rule.allows_file_size(100));
+        assert!(!rule.allows_file_size(101));
+        assert!(rule.allows_total_size(1000));
+        assert!(!rule.allows_total_size(1001));
+
+        let any = UploadRule::default();
+        assert!(any.is_empty());
+        assert!(any.allows_extension("README"));
+        assert!(any.allows_file_size(u64::MAX));
+    }
+
+    #[test]
+    fn test_file_list_response_upload_rule_optional() {
+        // Listings from older servers have no upload rule
+        let json = r#"{"type":"FileListResponse","success":true,"path":"/","entries":[],"can_upload":true}"#;
+        let msg: ServerMessage = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            msg,
+            ServerMessage::FileListResponse {
+                upload_rule: None,
+                ..
+            }
+        ));
+
+        let msg = ServerMessage::UploadRuleGetResponse {
+            success: true,
+            error: None,
+            rule: Some(UploadRule {
+                extensions: vec!["zip".to_string()],
+                max_file_size: None,
+                max_total_size: Some(5),
+            }),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains("\"rule\":{\"extensions\":[\"zip\"],\"max_total_size\":5}"));
+    }
+
+    #[test]
+    fn test_ldap_group_messages() {
+        let msg = ServerMessage::LdapGroupListResponse {
+            success: true,
+            error: None,
+            groups: Some(vec![
+                LdapGroupInfo {
+                    group: "CN=Staff,DC=example,DC=com".to_string(),
+                    permissions: vec!["chat_send".to_string()],
+                    is_admin: false,
+                },
+                LdapGroupInfo {
+                    group: "CN=IT,DC=example,DC=com".to_string(),
+                    permissions: vec![],
+                    is_admin: true,
+                },
+            ]),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains(
+            "{\"group\":\"CN=Staff,DC=example,DC=com\",\"permissions\":[\"chat_send\"]}"
+        ));
+        assert!(json.contains("{\"group\":\"CN=IT,DC=example,DC=com\",\"is_admin\":true}"));
+
+        // Permissions and the admin flag are optional
+        let json = r#"{"type":"LdapGroupSet","group":"CN=Staff"}"#;
+        let msg: ClientMessage = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            msg,
+            ClientMessage::LdapGroupSet { permissions, is_admin: false, .. } if permissions.is_empty()
+        ));
+    }
+
     #[test]
     fn test_serialize_file_reindex_response_success() {
         let msg = ServerMessage::FileReindexResponse {
@@ -2823,6 +6432,7 @@ mod tests {
             to_nickname: "alice".to_string(),
             message: "Hello!".to_string(),
             action: ChatAction::Normal,
+            idempotency_key: None,
         })
         .unwrap();
         let server_json = serde_json::to_string(&ServerMessage::UserMessage {
@@ -2833,6 +6443,7 @@ mod tests {
             message: "Hello!".to_string(),
             action: ChatAction::Normal,
             timestamp: 1718234567,
+            id: None,
         })
         .unwrap();
 
@@ -0,0 +1,142 @@
+//! Channel language tag validation
+//!
+//! Validates the language tag operators give a channel (e.g. "en", "pt-BR"),
+//! shown in the channel list and used to filter it.
+
+/// Maximum length for channel language tags in characters
+///
+/// Long enough for a language, script and region ("zh-Hant-TW").
+pub const MAX_CHANNEL_LANGUAGE_LENGTH: usize = 16;
+
+/// Longest subtag of a language tag
+const MAX_SUBTAG_LENGTH: usize = 8;
+
+/// Shortest primary language subtag ("en")
+const MIN_PRIMARY_SUBTAG_LENGTH: usize = 2;
+
+/// Validation error for channel language tags
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChannelLanguageError {
+    /// Tag exceeds maximum length
+    TooLong,
+    /// Tag isn't a language, optionally followed by `-` separated subtags
+    InvalidFormat,
+}
+
+/// Validate a channel language tag
+///
+/// Checks:
+/// - Does not exceed maximum length (16 characters)
+/// - Starts with a language of 2 to 8 ASCII letters
+/// - Any further subtags (script, region) are 1 to 8 ASCII letters or digits,
+///   separated by `-`
+///
+/// Note: An empty tag is allowed (to clear the channel's language).
+///
+/// # Errors
+///
+/// Returns a `ChannelLanguageError` variant describing the validation failure.
+pub fn validate_channel_language(language: &str) -> Result<(), ChannelLanguageError> {
+    if language.is_empty() {
+        return Ok(());
+    }
+
+    if language.len() > MAX_CHANNEL_LANGUAGE_LENGTH {
+        return Err(ChannelLanguageError::TooLong);
+    }
+
+    let mut subtags = language.split('-');
+    let primary = subtags.next().unwrap_or_default();
+    if primary.len() < MIN_PRIMARY_SUBTAG_LENGTH
+        || primary.len() > MAX_SUBTAG_LENGTH
+        || !primary.chars().all(|ch| ch.is_ascii_alphabetic())
+    {
+        return Err(ChannelLanguageError::InvalidFormat);
+    }
+
+    for subtag in subtags {
+        if subtag.is_empty()
+            || subtag.len() > MAX_SUBTAG_LENGTH
+            || !subtag.chars().all(|ch| ch.is_ascii_alphanumeric())
+        {
+            return Err(ChannelLanguageError::InvalidFormat);
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether a channel's language tag matches a language filter
+///
+/// Both are compared case-insensitively, and a filter matches the tag itself
+/// and any more specific tag, so "en" matches "en" and "en-GB" but "en-GB"
+/// doesn't match "en" or "en-US".
+pub fn channel_language_matches(language: &str, filter: &str) -> bool {
+    let Some(rest) = language.get(..filter.len()) else {
+        return false;
+    };
+    rest.eq_ignore_ascii_case(filter)
+        && (language.len() == filter.len() || language.as_bytes()[filter.len()] == b'-')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_languages() {
+        assert!(validate_channel_language("en").is_ok());
+        assert!(validate_channel_language("de").is_ok());
+        assert!(validate_channel_language("pt-BR").is_ok());
+        assert!(validate_channel_language("zh-Hant-TW").is_ok());
+        assert!(validate_channel_language("es-419").is_ok());
+        assert!(validate_channel_language("fil").is_ok());
+    }
+
+    #[test]
+    fn test_empty_allowed() {
+        assert!(validate_channel_language("").is_ok());
+    }
+
+    #[test]
+    fn test_too_long() {
+        assert_eq!(
+            validate_channel_language("en-aaaaaaaa-bbbbb"),
+            Err(ChannelLanguageError::TooLong)
+        );
+    }
+
+    #[test]
+    fn test_invalid_format() {
+        for language in [
+            "e",
+            "en-",
+            "-en",
+            "en--US",
+            "en_US",
+            "en US",
+            "12",
+            "en-US!",
+            "deutsch1",
+            "日本",
+            "en-abcdefghi",
+        ] {
+            assert_eq!(
+                validate_channel_language(language),
+                Err(ChannelLanguageError::InvalidFormat),
+                "{language}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_matches() {
+        assert!(channel_language_matches("en", "en"));
+        assert!(channel_language_matches("en-GB", "en"));
+        assert!(channel_language_matches("EN-gb", "en-GB"));
+        assert!(!channel_language_matches("en", "en-GB"));
+        assert!(!channel_language_matches("en-US", "en-GB"));
+        assert!(!channel_language_matches("eng", "en"));
+        assert!(!channel_language_matches("de", "en"));
+    }
+}
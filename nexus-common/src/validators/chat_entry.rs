@@ -0,0 +1,108 @@
+//! Channel entry message and rules validation
+//!
+//! Validates the entry message shown to users joining a channel and the
+//! channel's rules text. Unlike topics, both allow newlines and tabs.
+
+/// Maximum length for channel entry messages in bytes
+pub const MAX_CHAT_ENTRY_MESSAGE_LENGTH: usize = 1024;
+
+/// Maximum length for channel rules in bytes
+pub const MAX_CHAT_RULES_LENGTH: usize = 4096;
+
+/// Validation error for channel entry messages and rules
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChatEntryError {
+    /// Text exceeds maximum length
+    TooLong,
+    /// Text contains invalid control characters (not newline/tab)
+    InvalidCharacters,
+}
+
+/// Validate a channel entry message
+///
+/// Checks:
+/// - Does not exceed maximum length (1024 characters)
+/// - No control characters except newlines (\n, \r) and tabs (\t)
+///
+/// Note: Empty entry messages are allowed (to clear the entry message).
+///
+/// # Errors
+///
+/// Returns a `ChatEntryError` variant describing the validation failure.
+pub fn validate_chat_entry_message(message: &str) -> Result<(), ChatEntryError> {
+    validate_text(message, MAX_CHAT_ENTRY_MESSAGE_LENGTH)
+}
+
+/// Validate channel rules
+///
+/// Checks:
+/// - Does not exceed maximum length (4096 characters)
+/// - No control characters except newlines (\n, \r) and tabs (\t)
+///
+/// Note: Empty rules are allowed (to clear the rules).
+///
+/// # Errors
+///
+/// Returns a `ChatEntryError` variant describing the validation failure.
+pub fn validate_chat_rules(rules: &str) -> Result<(), ChatEntryError> {
+    validate_text(rules, MAX_CHAT_RULES_LENGTH)
+}
+
+fn validate_text(text: &str, max_length: usize) -> Result<(), ChatEntryError> {
+    if text.len() > max_length {
+        return Err(ChatEntryError::TooLong);
+    }
+
+    for ch in text.chars() {
+        if ch.is_control() && ch != '\n' && ch != '\r' && ch != '\t' {
+            return Err(ChatEntryError::InvalidCharacters);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_entry_messages() {
+        assert!(validate_chat_entry_message("Welcome to #general!").is_ok());
+        assert!(validate_chat_entry_message("Line one\nLine two\tindented").is_ok());
+        assert!(validate_chat_entry_message(&"a".repeat(MAX_CHAT_ENTRY_MESSAGE_LENGTH)).is_ok());
+        // Unicode
+        assert!(validate_chat_entry_message("ようこそ 🎉").is_ok());
+    }
+
+    #[test]
+    fn test_empty_allowed() {
+        assert!(validate_chat_entry_message("").is_ok());
+        assert!(validate_chat_rules("").is_ok());
+    }
+
+    #[test]
+    fn test_too_long() {
+        assert_eq!(
+            validate_chat_entry_message(&"a".repeat(MAX_CHAT_ENTRY_MESSAGE_LENGTH + 1)),
+            Err(ChatEntryError::TooLong)
+        );
+        assert!(validate_chat_rules(&"a".repeat(MAX_CHAT_RULES_LENGTH)).is_ok());
+        assert_eq!(
+            validate_chat_rules(&"a".repeat(MAX_CHAT_RULES_LENGTH + 1)),
+            Err(ChatEntryError::TooLong)
+        );
+    }
+
+    #[test]
+    fn test_invalid_characters() {
+        assert_eq!(
+            validate_chat_entry_message("Hello\0World"),
+            Err(ChatEntryError::InvalidCharacters)
+        );
+        assert_eq!(
+            validate_chat_rules("1. Be nice\x1b[31m"),
+            Err(ChatEntryError::InvalidCharacters)
+        );
+    }
+}
@@ -5,6 +5,9 @@
 /// Maximum length for topics in bytes
 pub const MAX_CHAT_TOPIC_LENGTH: usize = 256;
 
+/// Maximum number of topic changes kept per channel
+pub const MAX_CHAT_TOPIC_HISTORY: usize = 50;
+
 /// Validation error for topics
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ChatTopicError {
@@ -0,0 +1,79 @@
+//! Client name validation
+//!
+//! Validates the client software identifier sent during the handshake
+//! (e.g. "Nexus BBS 0.5.0"), shown to other users for debugging.
+
+/// Maximum length for client names in bytes
+pub const MAX_CLIENT_NAME_LENGTH: usize = 64;
+
+/// Validation error for client names
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClientNameError {
+    /// Client name is empty
+    Empty,
+    /// Client name exceeds maximum length
+    TooLong,
+    /// Client name contains control characters
+    InvalidCharacters,
+}
+
+/// Validate a client name
+///
+/// Checks:
+/// - Not empty
+/// - Does not exceed maximum length (64 bytes)
+/// - No control characters
+///
+/// # Errors
+///
+/// Returns a `ClientNameError` variant describing the validation failure.
+pub fn validate_client_name(name: &str) -> Result<(), ClientNameError> {
+    if name.is_empty() {
+        return Err(ClientNameError::Empty);
+    }
+    if name.len() > MAX_CLIENT_NAME_LENGTH {
+        return Err(ClientNameError::TooLong);
+    }
+    if name.chars().any(char::is_control) {
+        return Err(ClientNameError::InvalidCharacters);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_names() {
+        assert!(validate_client_name("Nexus BBS 0.5.0").is_ok());
+        assert!(validate_client_name("nexus-cli/1.2").is_ok());
+        assert!(validate_client_name(&"a".repeat(MAX_CLIENT_NAME_LENGTH)).is_ok());
+        assert!(validate_client_name("ネクサス 1.0").is_ok());
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(validate_client_name(""), Err(ClientNameError::Empty));
+    }
+
+    #[test]
+    fn test_too_long() {
+        assert_eq!(
+            validate_client_name(&"a".repeat(MAX_CLIENT_NAME_LENGTH + 1)),
+            Err(ClientNameError::TooLong)
+        );
+    }
+
+    #[test]
+    fn test_control_characters() {
+        assert_eq!(
+            validate_client_name("Nexus\n0.5"),
+            Err(ClientNameError::InvalidCharacters)
+        );
+        assert_eq!(
+            validate_client_name("Nexus\x1b[31m"),
+            Err(ClientNameError::InvalidCharacters)
+        );
+    }
+}
@@ -0,0 +1,81 @@
+//! Door name validation
+//!
+//! Validates the names of operator-configured doors (external programs users
+//! can run in a terminal).
+
+/// Maximum length for door names in bytes
+pub const MAX_DOOR_NAME_LENGTH: usize = 32;
+
+/// Validation error for door names
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DoorNameError {
+    /// Door name is empty or only whitespace
+    Empty,
+    /// Door name exceeds maximum length
+    TooLong,
+    /// Door name contains an equals sign or control characters
+    InvalidCharacters,
+}
+
+/// Validate a door name
+///
+/// Checks:
+/// - Not empty or whitespace-only
+/// - Does not exceed maximum length (32 bytes)
+/// - No equals signs (they separate the name from its command) or control characters
+///
+/// # Errors
+///
+/// Returns a `DoorNameError` variant describing the validation failure.
+pub fn validate_door_name(name: &str) -> Result<(), DoorNameError> {
+    if name.trim().is_empty() {
+        return Err(DoorNameError::Empty);
+    }
+    if name.len() > MAX_DOOR_NAME_LENGTH {
+        return Err(DoorNameError::TooLong);
+    }
+    if name.chars().any(|ch| ch == '=' || ch.is_control()) {
+        return Err(DoorNameError::InvalidCharacters);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_names() {
+        assert!(validate_door_name("lord").is_ok());
+        assert!(validate_door_name("Trade Wars 2002").is_ok());
+        assert!(validate_door_name(&"a".repeat(MAX_DOOR_NAME_LENGTH)).is_ok());
+        // Unicode
+        assert!(validate_door_name("囲碁").is_ok());
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(validate_door_name(""), Err(DoorNameError::Empty));
+        assert_eq!(validate_door_name("   "), Err(DoorNameError::Empty));
+    }
+
+    #[test]
+    fn test_too_long() {
+        assert_eq!(
+            validate_door_name(&"a".repeat(MAX_DOOR_NAME_LENGTH + 1)),
+            Err(DoorNameError::TooLong)
+        );
+    }
+
+    #[test]
+    fn test_invalid_characters() {
+        assert_eq!(
+            validate_door_name("lord=old"),
+            Err(DoorNameError::InvalidCharacters)
+        );
+        assert_eq!(
+            validate_door_name("lord\n"),
+            Err(DoorNameError::InvalidCharacters)
+        );
+    }
+}
@@ -0,0 +1,95 @@
+//! Download include list validation
+//!
+//! Validates the optional list of files a client selects when downloading
+//! only part of a directory.
+
+use super::file_path::{FilePathError, validate_file_path};
+
+/// Maximum number of entries in a download include list
+pub const MAX_DOWNLOAD_INCLUDE_COUNT: usize = 1024;
+
+/// Validation error for download include lists
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DownloadIncludeError {
+    /// The list is empty (nothing to download)
+    Empty,
+    /// Too many entries in the list
+    TooMany,
+    /// An entry is not a valid file path
+    InvalidPath(FilePathError),
+}
+
+/// Validate a download include list
+///
+/// Checks:
+/// - Not empty (a selection with nothing in it is not a download)
+/// - Does not exceed maximum count (1024 entries)
+/// - Each entry passes `validate_file_path`
+///
+/// Entries are only ever matched against the server's own directory scan,
+/// so they are never resolved on the filesystem directly.
+///
+/// # Errors
+///
+/// Returns a `DownloadIncludeError` variant describing the validation failure.
+pub fn validate_download_include(include: &[String]) -> Result<(), DownloadIncludeError> {
+    if include.is_empty() {
+        return Err(DownloadIncludeError::Empty);
+    }
+    if include.len() > MAX_DOWNLOAD_INCLUDE_COUNT {
+        return Err(DownloadIncludeError::TooMany);
+    }
+    for path in include {
+        validate_file_path(path).map_err(DownloadIncludeError::InvalidPath)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_include() {
+        let include = vec!["song.mp3".to_string(), "Jazz/tune.mp3".to_string()];
+        assert!(validate_download_include(&include).is_ok());
+    }
+
+    #[test]
+    fn test_max_include() {
+        let include: Vec<String> = (0..MAX_DOWNLOAD_INCLUDE_COUNT)
+            .map(|i| format!("file{i}.txt"))
+            .collect();
+        assert!(validate_download_include(&include).is_ok());
+    }
+
+    #[test]
+    fn test_empty_include() {
+        assert_eq!(
+            validate_download_include(&[]),
+            Err(DownloadIncludeError::Empty)
+        );
+    }
+
+    #[test]
+    fn test_too_many_include() {
+        let include: Vec<String> = (0..MAX_DOWNLOAD_INCLUDE_COUNT + 1)
+            .map(|i| format!("file{i}.txt"))
+            .collect();
+        assert_eq!(
+            validate_download_include(&include),
+            Err(DownloadIncludeError::TooMany)
+        );
+    }
+
+    #[test]
+    fn test_invalid_include_path() {
+        let include = vec!["ok.txt".to_string(), "bad\0.txt".to_string()];
+        assert_eq!(
+            validate_download_include(&include),
+            Err(DownloadIncludeError::InvalidPath(
+                FilePathError::ContainsNull
+            ))
+        );
+    }
+}
@@ -0,0 +1,84 @@
+//! File comment validation
+//!
+//! Validates the text of comments users leave on files. Like news bodies,
+//! comments may span several lines.
+
+/// Maximum length for file comments in bytes
+pub const MAX_FILE_COMMENT_LENGTH: usize = 1024;
+
+/// Validation error for file comments
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileCommentError {
+    /// Comment is empty or only whitespace
+    Empty,
+    /// Comment exceeds maximum length
+    TooLong,
+    /// Comment contains invalid control characters (not newline/tab)
+    InvalidCharacters,
+}
+
+/// Validate a file comment
+///
+/// Checks:
+/// - Not empty or whitespace-only
+/// - Does not exceed maximum length (1024 bytes)
+/// - No control characters except newlines (\n, \r) and tabs (\t)
+///
+/// # Errors
+///
+/// Returns a `FileCommentError` variant describing the validation failure.
+pub fn validate_file_comment(body: &str) -> Result<(), FileCommentError> {
+    if body.trim().is_empty() {
+        return Err(FileCommentError::Empty);
+    }
+    if body.len() > MAX_FILE_COMMENT_LENGTH {
+        return Err(FileCommentError::TooLong);
+    }
+    if body
+        .chars()
+        .any(|ch| ch.is_control() && ch != '\n' && ch != '\r' && ch != '\t')
+    {
+        return Err(FileCommentError::InvalidCharacters);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_comments() {
+        assert!(validate_file_comment("Great scan, thanks!").is_ok());
+        assert!(validate_file_comment("Track 3 skips.\nOtherwise fine.").is_ok());
+        assert!(validate_file_comment(&"a".repeat(MAX_FILE_COMMENT_LENGTH)).is_ok());
+        // Unicode
+        assert!(validate_file_comment("素晴らしい").is_ok());
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(validate_file_comment(""), Err(FileCommentError::Empty));
+        assert_eq!(validate_file_comment(" \n\t"), Err(FileCommentError::Empty));
+    }
+
+    #[test]
+    fn test_too_long() {
+        assert_eq!(
+            validate_file_comment(&"a".repeat(MAX_FILE_COMMENT_LENGTH + 1)),
+            Err(FileCommentError::TooLong)
+        );
+    }
+
+    #[test]
+    fn test_invalid_characters() {
+        assert_eq!(
+            validate_file_comment("bad\0comment"),
+            Err(FileCommentError::InvalidCharacters)
+        );
+        assert_eq!(
+            validate_file_comment("bell\x07"),
+            Err(FileCommentError::InvalidCharacters)
+        );
+    }
+}
@@ -0,0 +1,86 @@
+//! File stat path list validation
+//!
+//! Validates the paths a client asks about in a `FileStat` request, such as
+//! the destinations of files it is about to upload.
+
+use super::file_path::{FilePathError, validate_file_path};
+
+/// Maximum number of paths in a single FileStat request
+pub const MAX_FILE_STAT_COUNT: usize = 1024;
+
+/// Validation error for file stat path lists
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileStatError {
+    /// The list is empty (nothing to look up)
+    Empty,
+    /// Too many paths in the list
+    TooMany,
+    /// A path is not a valid file path
+    InvalidPath(FilePathError),
+}
+
+/// Validate the paths of a FileStat request
+///
+/// Checks:
+/// - Not empty
+/// - Does not exceed maximum count (1024 paths)
+/// - Each path passes `validate_file_path`
+///
+/// # Errors
+///
+/// Returns a `FileStatError` variant describing the validation failure.
+pub fn validate_file_stat_paths<'a>(
+    paths: impl IntoIterator<Item = &'a str>,
+) -> Result<(), FileStatError> {
+    let mut count = 0;
+    for path in paths {
+        count += 1;
+        if count > MAX_FILE_STAT_COUNT {
+            return Err(FileStatError::TooMany);
+        }
+        validate_file_path(path).map_err(FileStatError::InvalidPath)?;
+    }
+    if count == 0 {
+        return Err(FileStatError::Empty);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_paths() {
+        assert!(validate_file_stat_paths(["song.mp3", "/Uploads/Jazz/tune.mp3"]).is_ok());
+
+        let paths: Vec<String> = (0..MAX_FILE_STAT_COUNT)
+            .map(|i| format!("file{i}.txt"))
+            .collect();
+        assert!(validate_file_stat_paths(paths.iter().map(String::as_str)).is_ok());
+    }
+
+    #[test]
+    fn test_empty_list() {
+        assert_eq!(validate_file_stat_paths([]), Err(FileStatError::Empty));
+    }
+
+    #[test]
+    fn test_too_many_paths() {
+        let paths: Vec<String> = (0..MAX_FILE_STAT_COUNT + 1)
+            .map(|i| format!("file{i}.txt"))
+            .collect();
+        assert_eq!(
+            validate_file_stat_paths(paths.iter().map(String::as_str)),
+            Err(FileStatError::TooMany)
+        );
+    }
+
+    #[test]
+    fn test_invalid_path() {
+        assert_eq!(
+            validate_file_stat_paths(["ok.txt", "bad\0.txt"]),
+            Err(FileStatError::InvalidPath(FilePathError::ContainsNull))
+        );
+    }
+}
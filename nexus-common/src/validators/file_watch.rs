@@ -0,0 +1,75 @@
+//! File watch directory list validation
+//!
+//! Validates the directories a client watches with a `FileWatch` request.
+
+use super::file_path::{FilePathError, validate_file_path};
+
+/// Maximum number of directories a session can watch
+pub const MAX_FILE_WATCH_COUNT: usize = 32;
+
+/// Validation error for file watch directory lists
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileWatchError {
+    /// Too many directories in the list
+    TooMany,
+    /// A path is not a valid file path
+    InvalidPath(FilePathError),
+}
+
+/// Validate the directory paths of a FileWatch request
+///
+/// Checks:
+/// - Does not exceed maximum count (32 directories)
+/// - Each path passes `validate_file_path`
+///
+/// An empty list is valid (it stops watching).
+///
+/// # Errors
+///
+/// Returns a `FileWatchError` variant describing the validation failure.
+pub fn validate_file_watch_paths<'a>(
+    paths: impl IntoIterator<Item = &'a str>,
+) -> Result<(), FileWatchError> {
+    for (count, path) in paths.into_iter().enumerate() {
+        if count >= MAX_FILE_WATCH_COUNT {
+            return Err(FileWatchError::TooMany);
+        }
+        validate_file_path(path).map_err(FileWatchError::InvalidPath)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_paths() {
+        assert!(validate_file_watch_paths([]).is_ok());
+        assert!(validate_file_watch_paths(["", "/Podcasts/Weekly"]).is_ok());
+
+        let paths: Vec<String> = (0..MAX_FILE_WATCH_COUNT)
+            .map(|i| format!("folder{i}"))
+            .collect();
+        assert!(validate_file_watch_paths(paths.iter().map(String::as_str)).is_ok());
+    }
+
+    #[test]
+    fn test_too_many_paths() {
+        let paths: Vec<String> = (0..MAX_FILE_WATCH_COUNT + 1)
+            .map(|i| format!("folder{i}"))
+            .collect();
+        assert_eq!(
+            validate_file_watch_paths(paths.iter().map(String::as_str)),
+            Err(FileWatchError::TooMany)
+        );
+    }
+
+    #[test]
+    fn test_invalid_path() {
+        assert_eq!(
+            validate_file_watch_paths(["ok", "bad\0"]),
+            Err(FileWatchError::InvalidPath(FilePathError::ContainsNull))
+        );
+    }
+}
@@ -0,0 +1,85 @@
+//! Group conversation member list validation
+//!
+//! Validates the users a client names when it starts a group conversation
+//! (multi-user direct messages). The creator is not in the list.
+
+use super::nickname::{NicknameError, validate_nickname};
+
+/// Most users in one group conversation, including its creator
+pub const MAX_GROUP_MEMBERS: usize = 16;
+
+/// Most group conversations one user can have started and still going
+pub const MAX_GROUPS_PER_USER: usize = 10;
+
+/// Validation error for group conversation member lists
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GroupMembersError {
+    /// No one to talk to
+    Empty,
+    /// More members than a group can have
+    TooMany,
+    /// An entry is not a valid nickname
+    InvalidNickname(NicknameError),
+}
+
+/// Validate the member list of a new group conversation
+///
+/// Checks:
+/// - Not empty
+/// - Leaves room for the creator (at most 15 entries)
+/// - Each entry passes `validate_nickname`
+///
+/// Repeated nicknames are allowed; the server only adds each user once.
+///
+/// # Errors
+///
+/// Returns a `GroupMembersError` variant describing the validation failure.
+pub fn validate_group_members(members: &[String]) -> Result<(), GroupMembersError> {
+    if members.is_empty() {
+        return Err(GroupMembersError::Empty);
+    }
+    if members.len() >= MAX_GROUP_MEMBERS {
+        return Err(GroupMembersError::TooMany);
+    }
+    for nickname in members {
+        validate_nickname(nickname).map_err(GroupMembersError::InvalidNickname)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_members() {
+        let members = vec!["alice".to_string(), "bob".to_string()];
+        assert!(validate_group_members(&members).is_ok());
+
+        let members: Vec<String> = (1..MAX_GROUP_MEMBERS).map(|i| format!("user{i}")).collect();
+        assert!(validate_group_members(&members).is_ok());
+    }
+
+    #[test]
+    fn test_empty_members() {
+        assert_eq!(validate_group_members(&[]), Err(GroupMembersError::Empty));
+    }
+
+    #[test]
+    fn test_too_many_members() {
+        let members: Vec<String> = (0..MAX_GROUP_MEMBERS).map(|i| format!("user{i}")).collect();
+        assert_eq!(
+            validate_group_members(&members),
+            Err(GroupMembersError::TooMany)
+        );
+    }
+
+    #[test]
+    fn test_invalid_nickname() {
+        let members = vec!["alice".to_string(), String::new()];
+        assert_eq!(
+            validate_group_members(&members),
+            Err(GroupMembersError::InvalidNickname(NicknameError::Empty))
+        );
+    }
+}
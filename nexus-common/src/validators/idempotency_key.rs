@@ -0,0 +1,80 @@
+//! Idempotency key validation
+//!
+//! Validates the keys clients attach to chat, user and group messages so a
+//! message sent again after a dropped connection isn't posted twice.
+
+/// Maximum length for idempotency keys in characters
+pub const MAX_IDEMPOTENCY_KEY_LENGTH: usize = 64;
+
+/// Validation error for idempotency keys
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdempotencyKeyError {
+    /// Key is empty
+    Empty,
+    /// Key exceeds maximum length
+    TooLong,
+    /// Key contains characters other than ASCII letters, digits, `-` and `_`
+    InvalidCharacters,
+}
+
+/// Validate an idempotency key
+///
+/// Checks:
+/// - Not empty
+/// - Does not exceed maximum length (64 characters)
+/// - Only ASCII letters, digits, `-` and `_` (a UUID fits)
+///
+/// # Errors
+///
+/// Returns an `IdempotencyKeyError` variant describing the validation failure.
+pub fn validate_idempotency_key(key: &str) -> Result<(), IdempotencyKeyError> {
+    if key.is_empty() {
+        return Err(IdempotencyKeyError::Empty);
+    }
+
+    if key.len() > MAX_IDEMPOTENCY_KEY_LENGTH {
+        return Err(IdempotencyKeyError::TooLong);
+    }
+
+    if !key
+        .chars()
+        .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_')
+    {
+        return Err(IdempotencyKeyError::InvalidCharacters);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_key() {
+        assert!(validate_idempotency_key("67e55044-10b1-426f-9247-bb680e5fe0c8").is_ok());
+        assert!(validate_idempotency_key("67e5504410b1426f9247bb680e5fe0c8").is_ok());
+        assert!(validate_idempotency_key("a_1").is_ok());
+        assert!(validate_idempotency_key(&"k".repeat(MAX_IDEMPOTENCY_KEY_LENGTH)).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_key() {
+        assert_eq!(
+            validate_idempotency_key(""),
+            Err(IdempotencyKeyError::Empty)
+        );
+        assert_eq!(
+            validate_idempotency_key(&"k".repeat(MAX_IDEMPOTENCY_KEY_LENGTH + 1)),
+            Err(IdempotencyKeyError::TooLong)
+        );
+        assert_eq!(
+            validate_idempotency_key("key with spaces"),
+            Err(IdempotencyKeyError::InvalidCharacters)
+        );
+        assert_eq!(
+            validate_idempotency_key("ключ"),
+            Err(IdempotencyKeyError::InvalidCharacters)
+        );
+    }
+}
@@ -0,0 +1,87 @@
+//! Directory group validation
+//!
+//! Validates the distinguished names of directory groups that are mapped to
+//! Nexus permissions (e.g. `CN=Staff,OU=Groups,DC=example,DC=com`).
+
+/// Maximum length for directory group names in bytes
+pub const MAX_LDAP_GROUP_LENGTH: usize = 512;
+
+/// Validation error for directory group names
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LdapGroupError {
+    /// Group name is empty or only whitespace
+    Empty,
+    /// Group name exceeds maximum length
+    TooLong,
+    /// Group name has surrounding whitespace or control characters
+    InvalidCharacters,
+}
+
+/// Validate a directory group name
+///
+/// Checks:
+/// - Not empty or whitespace-only
+/// - Does not exceed maximum length (512 bytes)
+/// - No leading or trailing whitespace (directories ignore it, so two
+///   mappings could name the same group) and no control characters
+///
+/// # Errors
+///
+/// Returns a `LdapGroupError` variant describing the validation failure.
+pub fn validate_ldap_group(group: &str) -> Result<(), LdapGroupError> {
+    if group.trim().is_empty() {
+        return Err(LdapGroupError::Empty);
+    }
+    if group.len() > MAX_LDAP_GROUP_LENGTH {
+        return Err(LdapGroupError::TooLong);
+    }
+    if group.trim() != group || group.chars().any(char::is_control) {
+        return Err(LdapGroupError::InvalidCharacters);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_groups() {
+        assert!(validate_ldap_group("CN=Staff,OU=Groups,DC=example,DC=com").is_ok());
+        assert!(validate_ldap_group("cn=BBS Users,ou=groups,dc=example,dc=org").is_ok());
+        assert!(validate_ldap_group("CN=Smith\\, John,DC=example").is_ok());
+        assert!(validate_ldap_group(&"a".repeat(MAX_LDAP_GROUP_LENGTH)).is_ok());
+        // Unicode
+        assert!(validate_ldap_group("CN=Équipe,DC=example,DC=fr").is_ok());
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(validate_ldap_group(""), Err(LdapGroupError::Empty));
+        assert_eq!(validate_ldap_group("  "), Err(LdapGroupError::Empty));
+    }
+
+    #[test]
+    fn test_too_long() {
+        assert_eq!(
+            validate_ldap_group(&"a".repeat(MAX_LDAP_GROUP_LENGTH + 1)),
+            Err(LdapGroupError::TooLong)
+        );
+    }
+
+    #[test]
+    fn test_invalid_characters() {
+        assert_eq!(
+            validate_ldap_group(" CN=Staff"),
+            Err(LdapGroupError::InvalidCharacters)
+        );
+        assert_eq!(
+            validate_ldap_group("CN=Staff\n"),
+            Err(LdapGroupError::InvalidCharacters)
+        );
+        assert_eq!(
+            validate_ldap_group("CN=Sta\0ff"),
+            Err(LdapGroupError::InvalidCharacters)
+        );
+    }
+}
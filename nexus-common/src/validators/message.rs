@@ -1,6 +1,7 @@
 //! Message validation
 //!
-//! Validates chat messages, broadcasts, and user messages.
+//! Validates chat messages, broadcasts, and user messages. Chat and user
+//! messages may span several lines; broadcasts are a single line.
 
 /// Maximum length for messages (chat, broadcast, user messages) in bytes
 pub const MAX_MESSAGE_LENGTH: usize = 1024;
@@ -18,7 +19,7 @@ pub enum MessageError {
     InvalidCharacters,
 }
 
-/// Validate a message (chat, broadcast, user message)
+/// Validate a single-line message (broadcast, or chat sent by command)
 ///
 /// Checks:
 /// - Not empty or whitespace-only
@@ -29,6 +30,22 @@ pub enum MessageError {
 ///
 /// Returns a `MessageError` variant describing the validation failure.
 pub fn validate_message(message: &str) -> Result<(), MessageError> {
+    validate(message, false)
+}
+
+/// Validate a chat or user message, which may span several lines
+///
+/// Same checks as [`validate_message`], except that `\n` line breaks are
+/// allowed. Carriage returns are still invalid.
+///
+/// # Errors
+///
+/// Returns a `MessageError` variant describing the validation failure.
+pub fn validate_multiline_message(message: &str) -> Result<(), MessageError> {
+    validate(message, true)
+}
+
+fn validate(message: &str, allow_line_breaks: bool) -> Result<(), MessageError> {
     if message.trim().is_empty() {
         return Err(MessageError::Empty);
     }
@@ -36,11 +53,11 @@ pub fn validate_message(message: &str) -> Result<(), MessageError> {
         return Err(MessageError::TooLong);
     }
     for ch in message.chars() {
-        if ch.is_control() {
-            if ch == '\n' || ch == '\r' {
-                return Err(MessageError::ContainsNewlines);
-            }
-            return Err(MessageError::InvalidCharacters);
+        match ch {
+            '\n' if allow_line_breaks => {}
+            '\n' | '\r' if !allow_line_breaks => return Err(MessageError::ContainsNewlines),
+            _ if ch.is_control() => return Err(MessageError::InvalidCharacters),
+            _ => {}
         }
     }
     Ok(())
@@ -88,6 +105,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_multiline_messages() {
+        assert!(validate_multiline_message("Hello\nWorld").is_ok());
+        assert!(validate_multiline_message("one\n\nthree").is_ok());
+        assert!(validate_multiline_message("Hello, world!").is_ok());
+        assert_eq!(validate_multiline_message("\n\n"), Err(MessageError::Empty));
+        assert_eq!(
+            validate_multiline_message(&"a\n".repeat(MAX_MESSAGE_LENGTH / 2 + 1)),
+            Err(MessageError::TooLong)
+        );
+        // Only \n line breaks
+        assert_eq!(
+            validate_multiline_message("Hello\r\nWorld"),
+            Err(MessageError::InvalidCharacters)
+        );
+        assert_eq!(
+            validate_multiline_message("Hello\tWorld"),
+            Err(MessageError::InvalidCharacters)
+        );
+    }
+
     #[test]
     fn test_valid_characters() {
         // ASCII graphic characters
@@ -0,0 +1,54 @@
+//! Direct message history validation
+//!
+//! Validates the per-account retention for direct message history kept on
+//! the server.
+
+/// Longest an account can have its direct messages kept, in days
+pub const MAX_MESSAGE_HISTORY_DAYS: u32 = 365;
+
+/// Most messages returned by one MessageHistory request
+pub const MAX_MESSAGE_HISTORY_PAGE: u32 = 200;
+
+/// Validation error for message history retention
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageHistoryDaysError {
+    /// Retention exceeds the maximum
+    TooLong,
+}
+
+/// Validate a message history retention in days
+///
+/// Checks:
+/// - Does not exceed the maximum (365 days)
+///
+/// Note: 0 is allowed (turns history off).
+///
+/// # Errors
+///
+/// Returns a `MessageHistoryDaysError` variant describing the validation failure.
+pub fn validate_message_history_days(days: u32) -> Result<(), MessageHistoryDaysError> {
+    if days > MAX_MESSAGE_HISTORY_DAYS {
+        return Err(MessageHistoryDaysError::TooLong);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_days() {
+        assert!(validate_message_history_days(0).is_ok());
+        assert!(validate_message_history_days(30).is_ok());
+        assert!(validate_message_history_days(MAX_MESSAGE_HISTORY_DAYS).is_ok());
+    }
+
+    #[test]
+    fn test_too_long() {
+        assert_eq!(
+            validate_message_history_days(MAX_MESSAGE_HISTORY_DAYS + 1),
+            Err(MessageHistoryDaysError::TooLong)
+        );
+    }
+}
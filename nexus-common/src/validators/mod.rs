@@ -7,32 +7,54 @@
 mod avatar;
 mod ban_reason;
 mod channel;
+mod channel_language;
 mod channel_list;
+mod chat_entry;
 mod chat_topic;
+mod client_name;
 mod data_uri;
 mod dir_name;
+mod door_name;
+mod download_include;
 mod duration;
 mod error;
 mod features;
+mod file_comment;
 mod file_path;
+mod file_stat;
+mod file_watch;
+mod group_message;
+mod idempotency_key;
 mod ip_rule_reason;
+mod ldap_group;
 mod locale;
 mod message;
+mod message_history;
+mod news_attachment;
 mod news_body;
 mod news_image;
+mod news_search;
 mod nickname;
+mod oidc;
 mod password;
 mod permissions;
+mod presence;
+mod rank_name;
 mod search_query;
 mod server_description;
 mod server_image;
 mod server_name;
 mod sha256;
+mod snippet;
 mod status;
 mod target;
+mod timezone;
 mod trust_reason;
+mod upload_extensions;
 mod username;
 mod version;
+mod voice_clip;
+mod voice_text;
 
 pub use avatar::{AvatarError, MAX_AVATAR_DATA_URI_LENGTH, validate_avatar};
 pub use ban_reason::{BanReasonError, MAX_BAN_REASON_LENGTH, validate_ban_reason};
@@ -40,31 +62,77 @@ pub use channel::{
     CHANNEL_PREFIX, ChannelError, DEFAULT_CHANNEL, MAX_CHANNEL_LENGTH, MAX_CHANNELS_PER_USER,
     MIN_CHANNEL_LENGTH, validate_channel,
 };
+pub use channel_language::{
+    ChannelLanguageError, MAX_CHANNEL_LANGUAGE_LENGTH, channel_language_matches,
+    validate_channel_language,
+};
 pub use channel_list::{
     AutoJoinChannelsError, ChannelListError, MAX_AUTO_JOIN_CHANNELS_LENGTH,
     MAX_CHANNEL_LIST_LENGTH, MAX_PERSISTENT_CHANNELS_LENGTH, PersistentChannelsError,
     validate_auto_join_channels, validate_channel_list, validate_persistent_channels,
 };
-pub use chat_topic::{ChatTopicError, MAX_CHAT_TOPIC_LENGTH, validate_chat_topic};
+pub use chat_entry::{
+    ChatEntryError, MAX_CHAT_ENTRY_MESSAGE_LENGTH, MAX_CHAT_RULES_LENGTH,
+    validate_chat_entry_message, validate_chat_rules,
+};
+pub use chat_topic::{
+    ChatTopicError, MAX_CHAT_TOPIC_HISTORY, MAX_CHAT_TOPIC_LENGTH, validate_chat_topic,
+};
+pub use client_name::{ClientNameError, MAX_CLIENT_NAME_LENGTH, validate_client_name};
 pub use data_uri::{ALLOWED_IMAGE_MIME_TYPES, DataUriError, validate_image_data_uri};
 pub use dir_name::{DirNameError, MAX_DIR_NAME_LENGTH, validate_dir_name};
+pub use door_name::{DoorNameError, MAX_DOOR_NAME_LENGTH, validate_door_name};
+pub use download_include::{
+    DownloadIncludeError, MAX_DOWNLOAD_INCLUDE_COUNT, validate_download_include,
+};
 pub use duration::{DurationError, MAX_DURATION_LENGTH, validate_duration};
 pub use error::{
     MAX_COMMAND_LENGTH, MAX_ERROR_KIND_LENGTH, MAX_ERROR_LENGTH, MAX_NEWS_ACTION_LENGTH,
     TRANSFER_ID_LENGTH,
 };
 pub use features::{FeaturesError, MAX_FEATURE_LENGTH, MAX_FEATURES_COUNT, validate_features};
+pub use file_comment::{FileCommentError, MAX_FILE_COMMENT_LENGTH, validate_file_comment};
 pub use file_path::{FilePathError, MAX_FILE_PATH_LENGTH, validate_file_path};
+pub use file_stat::{FileStatError, MAX_FILE_STAT_COUNT, validate_file_stat_paths};
+pub use file_watch::{FileWatchError, MAX_FILE_WATCH_COUNT, validate_file_watch_paths};
+pub use group_message::{
+    GroupMembersError, MAX_GROUP_MEMBERS, MAX_GROUPS_PER_USER, validate_group_members,
+};
+pub use idempotency_key::{
+    IdempotencyKeyError, MAX_IDEMPOTENCY_KEY_LENGTH, validate_idempotency_key,
+};
 pub use ip_rule_reason::{IpRuleReasonError, MAX_IP_RULE_REASON_LENGTH, validate_ip_rule_reason};
+pub use ldap_group::{LdapGroupError, MAX_LDAP_GROUP_LENGTH, validate_ldap_group};
 pub use locale::{LocaleError, MAX_LOCALE_LENGTH, validate_locale};
-pub use message::{MAX_MESSAGE_LENGTH, MessageError, validate_message};
+pub use message::{MAX_MESSAGE_LENGTH, MessageError, validate_message, validate_multiline_message};
+pub use message_history::{
+    MAX_MESSAGE_HISTORY_DAYS, MAX_MESSAGE_HISTORY_PAGE, MessageHistoryDaysError,
+    validate_message_history_days,
+};
+pub use news_attachment::{
+    MAX_NEWS_ATTACHMENT_KIND_LENGTH, MAX_NEWS_ATTACHMENTS, MAX_NEWS_CAPTION_LENGTH,
+    NewsCaptionError, validate_news_caption,
+};
 pub use news_body::{MAX_NEWS_BODY_LENGTH, NewsBodyError, validate_news_body};
-pub use news_image::{MAX_NEWS_IMAGE_DATA_URI_LENGTH, NewsImageError, validate_news_image};
+pub use news_image::{
+    MAX_NEWS_IMAGE_DATA_URI_LENGTH, MAX_NEWS_IMAGE_SIZE, NewsImageError, validate_news_image,
+};
+pub use news_search::{NEWS_SEARCH_DATE_LENGTH, NewsSearchDateError, validate_news_search_date};
 pub use nickname::{MAX_NICKNAME_LENGTH, NicknameError, validate_nickname};
+pub use oidc::{
+    LOGIN_TOKEN_LENGTH, LoginTokenError, MAX_CODE_VERIFIER_LENGTH, MAX_OIDC_CLIENT_ID_LENGTH,
+    MAX_OIDC_CODE_LENGTH, MAX_OIDC_ENDPOINT_LENGTH, MAX_OIDC_NONCE_LENGTH,
+    MAX_OIDC_REDIRECT_URI_LENGTH, MIN_CODE_VERIFIER_LENGTH, OIDC_REDIRECT_PREFIX, OidcLoginError,
+    validate_login_token, validate_oidc_login,
+};
 pub use password::{
     MAX_PASSWORD_LENGTH, PasswordError, validate_password, validate_password_input,
 };
 pub use permissions::{MAX_PERMISSION_LENGTH, PermissionsError, validate_permissions};
+pub use presence::{
+    MAX_PRESENCE_SUBSCRIPTIONS, PresenceSubscribeError, validate_presence_subscriptions,
+};
+pub use rank_name::{MAX_RANK_NAME_LENGTH, RankNameError, validate_rank_name};
 pub use search_query::{
     MAX_SEARCH_QUERY_LENGTH, MIN_PRIMARY_TERM_LENGTH, MIN_QUERY_LENGTH, MIN_TERM_LENGTH,
     SearchQueryError, extract_search_terms, validate_search_query,
@@ -75,8 +143,25 @@ pub use server_description::{
 pub use server_image::{MAX_SERVER_IMAGE_DATA_URI_LENGTH, ServerImageError, validate_server_image};
 pub use server_name::{MAX_SERVER_NAME_LENGTH, ServerNameError, validate_server_name};
 pub use sha256::{SHA256_HEX_LENGTH, Sha256Error, validate_sha256};
+pub use snippet::{
+    MAX_SNIPPET_EXPIRY_SECS, MAX_SNIPPET_LANGUAGE_LENGTH, MAX_SNIPPET_LENGTH,
+    MIN_SNIPPET_EXPIRY_SECS, SNIPPET_ID_LENGTH, SnippetError, SnippetExpiryError, SnippetIdError,
+    SnippetLanguageError, validate_snippet, validate_snippet_expiry, validate_snippet_id,
+    validate_snippet_language,
+};
 pub use status::{MAX_STATUS_LENGTH, StatusError, validate_status};
 pub use target::{MAX_TARGET_LENGTH, TargetError, validate_target};
+pub use timezone::{MAX_TIMEZONE_LENGTH, TimezoneError, validate_timezone};
 pub use trust_reason::{MAX_TRUST_REASON_LENGTH, TrustReasonError, validate_trust_reason};
+pub use upload_extensions::{
+    MAX_UPLOAD_EXTENSION_LENGTH, MAX_UPLOAD_EXTENSIONS, UploadExtensionsError,
+    validate_upload_extensions,
+};
 pub use username::{MAX_USERNAME_LENGTH, UsernameError, validate_username};
 pub use version::{MAX_VERSION_LENGTH, VersionError, validate_version};
+pub use voice_clip::{
+    ALLOWED_VOICE_CLIP_MIME_TYPES, MAX_VOICE_CLIP_DATA_URI_LENGTH, MAX_VOICE_CLIP_NAME_LENGTH,
+    MAX_VOICE_CLIP_SIZE, VoiceClipDataError, VoiceClipNameError, validate_voice_clip_data,
+    validate_voice_clip_name,
+};
+pub use voice_text::{MAX_VOICE_TEXT_LENGTH, VoiceTextError, validate_voice_text};
@@ -0,0 +1,83 @@
+//! News attachment validation
+//!
+//! A news post carries a list of attachments: images stored with the post, or
+//! references to files in the file area. Each attachment may have a one-line
+//! caption. Image data is checked with `validate_news_image` and file
+//! references with `validate_file_path`.
+
+/// Maximum number of attachments on a news post
+pub const MAX_NEWS_ATTACHMENTS: usize = 8;
+
+/// Maximum length for news attachment captions in bytes
+pub const MAX_NEWS_CAPTION_LENGTH: usize = 256;
+
+/// Maximum length for news attachment kind names (bytes)
+///
+/// Kinds: "image" (5 chars), "file" (4 chars).
+pub const MAX_NEWS_ATTACHMENT_KIND_LENGTH: usize = 5;
+
+/// Validation error for news attachment captions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NewsCaptionError {
+    /// Caption exceeds maximum length
+    TooLong,
+    /// Caption contains control characters (captions are a single line)
+    InvalidCharacters,
+}
+
+/// Validate a news attachment caption
+///
+/// Checks:
+/// - Does not exceed maximum length (256 bytes)
+/// - No control characters, including newlines
+///
+/// # Errors
+///
+/// Returns a `NewsCaptionError` variant describing the validation failure.
+pub fn validate_news_caption(caption: &str) -> Result<(), NewsCaptionError> {
+    if caption.len() > MAX_NEWS_CAPTION_LENGTH {
+        return Err(NewsCaptionError::TooLong);
+    }
+    if caption.chars().any(char::is_control) {
+        return Err(NewsCaptionError::InvalidCharacters);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_captions() {
+        assert!(validate_news_caption("").is_ok());
+        assert!(validate_news_caption("The new lobby, from the stage").is_ok());
+        assert!(validate_news_caption(&"a".repeat(MAX_NEWS_CAPTION_LENGTH)).is_ok());
+        // Unicode
+        assert!(validate_news_caption("新しいロビー").is_ok());
+    }
+
+    #[test]
+    fn test_too_long() {
+        assert_eq!(
+            validate_news_caption(&"a".repeat(MAX_NEWS_CAPTION_LENGTH + 1)),
+            Err(NewsCaptionError::TooLong)
+        );
+    }
+
+    #[test]
+    fn test_invalid_characters() {
+        assert_eq!(
+            validate_news_caption("two\nlines"),
+            Err(NewsCaptionError::InvalidCharacters)
+        );
+        assert_eq!(
+            validate_news_caption("tab\there"),
+            Err(NewsCaptionError::InvalidCharacters)
+        );
+        assert_eq!(
+            validate_news_caption("bad\0caption"),
+            Err(NewsCaptionError::InvalidCharacters)
+        );
+    }
+}
@@ -21,8 +21,8 @@ pub enum NewsBodyError {
 /// - Does not exceed maximum length (4096 characters)
 /// - No control characters except newlines (\n, \r) and tabs (\t)
 ///
-/// Note: Empty body is allowed (news can be image-only).
-/// The requirement for at least body OR image is enforced at the handler level.
+/// Note: Empty body is allowed (news can be attachments only).
+/// The requirement for at least body OR an attachment is enforced at the handler level.
 ///
 /// # Errors
 ///
@@ -4,6 +4,10 @@
 
 use super::data_uri::{ALLOWED_IMAGE_MIME_TYPES, DataUriError, validate_image_data_uri};
 
+/// Maximum size of a news image in bytes (512KB, before base64 encoding).
+/// Servers may store images under a lower limit.
+pub const MAX_NEWS_IMAGE_SIZE: usize = 512 * 1024;
+
 /// Maximum length of news image data URI (512KB binary + base64 overhead + prefix).
 /// Same as server image limit.
 pub const MAX_NEWS_IMAGE_DATA_URI_LENGTH: usize = 700_000;
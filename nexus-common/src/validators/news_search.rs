@@ -0,0 +1,115 @@
+//! News search date validation
+//!
+//! Validates the date filters of a news search. Dates are calendar days in
+//! `YYYY-MM-DD` form (UTC, like news timestamps).
+
+/// Length of a news search date (`YYYY-MM-DD`)
+pub const NEWS_SEARCH_DATE_LENGTH: usize = 10;
+
+/// Validation error for news search dates
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NewsSearchDateError {
+    /// Not in `YYYY-MM-DD` form
+    InvalidFormat,
+    /// Month or day out of range (e.g., 2024-02-30)
+    InvalidDate,
+}
+
+/// Validate a news search date
+///
+/// Checks:
+/// - Exactly `YYYY-MM-DD` (ASCII digits, dashes in place)
+/// - Month 01-12 and a day that exists in that month
+///
+/// # Errors
+///
+/// Returns a `NewsSearchDateError` variant describing the validation failure.
+pub fn validate_news_search_date(date: &str) -> Result<(), NewsSearchDateError> {
+    let bytes = date.as_bytes();
+    if !date.is_ascii()
+        || bytes.len() != NEWS_SEARCH_DATE_LENGTH
+        || bytes[4] != b'-'
+        || bytes[7] != b'-'
+    {
+        return Err(NewsSearchDateError::InvalidFormat);
+    }
+
+    let number = |range: std::ops::Range<usize>| -> Result<u32, NewsSearchDateError> {
+        let part = &date[range];
+        if !part.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(NewsSearchDateError::InvalidFormat);
+        }
+        part.parse().map_err(|_| NewsSearchDateError::InvalidFormat)
+    };
+    let year = number(0..4)?;
+    let month = number(5..7)?;
+    let day = number(8..10)?;
+
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap => 29,
+        2 => 28,
+        _ => return Err(NewsSearchDateError::InvalidDate),
+    };
+    if day == 0 || day > days_in_month {
+        return Err(NewsSearchDateError::InvalidDate);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_dates() {
+        assert!(validate_news_search_date("2024-01-01").is_ok());
+        assert!(validate_news_search_date("2024-12-31").is_ok());
+        assert!(validate_news_search_date("2024-02-29").is_ok());
+        assert!(validate_news_search_date("2000-02-29").is_ok());
+    }
+
+    #[test]
+    fn test_invalid_format() {
+        for date in [
+            "",
+            "2024-1-01",
+            "2024/01/01",
+            "20240101",
+            "2024-01-01 ",
+            "2024-0a-01",
+        ] {
+            assert_eq!(
+                validate_news_search_date(date),
+                Err(NewsSearchDateError::InvalidFormat),
+                "{date}"
+            );
+        }
+        // Same byte length, but not ASCII
+        assert_eq!(
+            validate_news_search_date("2024-é-01"),
+            Err(NewsSearchDateError::InvalidFormat)
+        );
+    }
+
+    #[test]
+    fn test_invalid_dates() {
+        for date in [
+            "2024-00-10",
+            "2024-13-01",
+            "2024-04-31",
+            "2023-02-29",
+            "1900-02-29",
+            "2024-01-00",
+        ] {
+            assert_eq!(
+                validate_news_search_date(date),
+                Err(NewsSearchDateError::InvalidDate),
+                "{date}"
+            );
+        }
+    }
+}
@@ -0,0 +1,237 @@
+//! Single sign-on login validation
+//!
+//! Validates the OpenID Connect fields a client sends in place of a
+//! password, and the login tokens issued after a single sign-on login.
+
+/// Maximum length for an authorization code
+pub const MAX_OIDC_CODE_LENGTH: usize = 2048;
+
+/// Minimum length for a PKCE code verifier (RFC 7636)
+pub const MIN_CODE_VERIFIER_LENGTH: usize = 43;
+
+/// Maximum length for a PKCE code verifier (RFC 7636)
+pub const MAX_CODE_VERIFIER_LENGTH: usize = 128;
+
+/// Maximum length for a sign-in nonce
+pub const MAX_OIDC_NONCE_LENGTH: usize = 128;
+
+/// Maximum length for a redirect URI
+pub const MAX_OIDC_REDIRECT_URI_LENGTH: usize = 64;
+
+/// Redirect URIs must point at the client's loopback listener (RFC 8252)
+pub const OIDC_REDIRECT_PREFIX: &str = "http://127.0.0.1:";
+
+/// Maximum length for a provider's authorization endpoint
+pub const MAX_OIDC_ENDPOINT_LENGTH: usize = 512;
+
+/// Maximum length for a provider client ID
+pub const MAX_OIDC_CLIENT_ID_LENGTH: usize = 256;
+
+/// Length of a login token (64 hex characters)
+pub const LOGIN_TOKEN_LENGTH: usize = 64;
+
+/// Validation error for a single sign-on login
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OidcLoginError {
+    /// Authorization code is empty, too long or not printable ASCII
+    InvalidCode,
+    /// Code verifier has the wrong length or characters
+    InvalidCodeVerifier,
+    /// Redirect URI is not a loopback address with a port
+    InvalidRedirectUri,
+    /// Nonce is empty, too long or not printable ASCII
+    InvalidNonce,
+}
+
+/// Validation error for a login token
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoginTokenError {
+    /// Token has wrong length (must be exactly 64 characters)
+    InvalidLength,
+    /// Token contains non-hexadecimal or uppercase characters
+    InvalidCharacters,
+}
+
+/// Whether a string is non-empty visible ASCII no longer than `max`
+fn is_printable(value: &str, max: usize) -> bool {
+    !value.is_empty() && value.len() <= max && value.bytes().all(|b| b.is_ascii_graphic())
+}
+
+/// Whether a string is a valid PKCE code verifier
+///
+/// Only the unreserved characters `A-Z a-z 0-9 - . _ ~` are allowed.
+fn is_code_verifier(verifier: &str) -> bool {
+    (MIN_CODE_VERIFIER_LENGTH..=MAX_CODE_VERIFIER_LENGTH).contains(&verifier.len())
+        && verifier
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~'))
+}
+
+/// Whether a string is a loopback redirect URI such as `http://127.0.0.1:5000/callback`
+fn is_loopback_redirect(uri: &str) -> bool {
+    if uri.len() > MAX_OIDC_REDIRECT_URI_LENGTH {
+        return false;
+    }
+    let Some(rest) = uri.strip_prefix(OIDC_REDIRECT_PREFIX) else {
+        return false;
+    };
+    let Some((port, path)) = rest.split_once('/') else {
+        return false;
+    };
+    port.parse::<u16>().is_ok_and(|port| port != 0)
+        && path
+            .bytes()
+            .all(|b| b.is_ascii_graphic() && !matches!(b, b'?' | b'#'))
+}
+
+/// Validate the fields of a single sign-on login
+///
+/// Checks:
+/// - Code and nonce are non-empty printable ASCII within their limits
+/// - Code verifier is 43-128 unreserved characters
+/// - Redirect URI is `http://127.0.0.1:<port>/<path>`
+///
+/// # Errors
+///
+/// Returns an `OidcLoginError` variant describing the first invalid field.
+pub fn validate_oidc_login(
+    code: &str,
+    code_verifier: &str,
+    redirect_uri: &str,
+    nonce: &str,
+) -> Result<(), OidcLoginError> {
+    if !is_printable(code, MAX_OIDC_CODE_LENGTH) {
+        return Err(OidcLoginError::InvalidCode);
+    }
+    if !is_code_verifier(code_verifier) {
+        return Err(OidcLoginError::InvalidCodeVerifier);
+    }
+    if !is_loopback_redirect(redirect_uri) {
+        return Err(OidcLoginError::InvalidRedirectUri);
+    }
+    if !is_printable(nonce, MAX_OIDC_NONCE_LENGTH) {
+        return Err(OidcLoginError::InvalidNonce);
+    }
+    Ok(())
+}
+
+/// Validate a login token
+///
+/// Checks:
+/// - Exactly 64 characters long
+/// - Only lowercase hexadecimal characters (0-9, a-f)
+///
+/// # Errors
+///
+/// Returns a `LoginTokenError` variant describing the validation failure.
+pub fn validate_login_token(token: &str) -> Result<(), LoginTokenError> {
+    if token.len() != LOGIN_TOKEN_LENGTH {
+        return Err(LoginTokenError::InvalidLength);
+    }
+    if !token
+        .bytes()
+        .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b))
+    {
+        return Err(LoginTokenError::InvalidCharacters);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VERIFIER: &str = "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk";
+    const REDIRECT: &str = "http://127.0.0.1:51234/callback";
+
+    #[test]
+    fn test_valid_login() {
+        assert!(
+            validate_oidc_login("SplxlOBeZQQYbYS6WxSbIA", VERIFIER, REDIRECT, "n-0S6_WzA2Mj")
+                .is_ok()
+        );
+        assert!(validate_oidc_login("a", &"~".repeat(128), "http://127.0.0.1:1/", "n").is_ok());
+    }
+
+    #[test]
+    fn test_invalid_code() {
+        assert_eq!(
+            validate_oidc_login("", VERIFIER, REDIRECT, "nonce"),
+            Err(OidcLoginError::InvalidCode)
+        );
+        assert_eq!(
+            validate_oidc_login("has space", VERIFIER, REDIRECT, "nonce"),
+            Err(OidcLoginError::InvalidCode)
+        );
+        let long = "a".repeat(MAX_OIDC_CODE_LENGTH + 1);
+        assert_eq!(
+            validate_oidc_login(&long, VERIFIER, REDIRECT, "nonce"),
+            Err(OidcLoginError::InvalidCode)
+        );
+    }
+
+    #[test]
+    fn test_invalid_code_verifier() {
+        for verifier in [
+            "short",
+            &"a".repeat(MAX_CODE_VERIFIER_LENGTH + 1),
+            "dBjftJeZ4CVP+mB92K27uhbUJU1p1r/wW1gFWFOEjXk",
+        ] {
+            assert_eq!(
+                validate_oidc_login("code", verifier, REDIRECT, "nonce"),
+                Err(OidcLoginError::InvalidCodeVerifier)
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_redirect_uri() {
+        for uri in [
+            "https://127.0.0.1:5000/callback",
+            "http://localhost:5000/callback",
+            "http://127.0.0.1.evil.com:5000/callback",
+            "http://127.0.0.1/callback",
+            "http://127.0.0.1:0/callback",
+            "http://127.0.0.1:70000/callback",
+            "http://127.0.0.1:5000",
+            "http://127.0.0.1:5000/callback?x=1",
+            "http://127.0.0.1:5000/call back",
+        ] {
+            assert_eq!(
+                validate_oidc_login("code", VERIFIER, uri, "nonce"),
+                Err(OidcLoginError::InvalidRedirectUri),
+                "{uri}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_nonce() {
+        assert_eq!(
+            validate_oidc_login("code", VERIFIER, REDIRECT, ""),
+            Err(OidcLoginError::InvalidNonce)
+        );
+        let long = "n".repeat(MAX_OIDC_NONCE_LENGTH + 1);
+        assert_eq!(
+            validate_oidc_login("code", VERIFIER, REDIRECT, &long),
+            Err(OidcLoginError::InvalidNonce)
+        );
+    }
+
+    #[test]
+    fn test_login_token() {
+        assert!(validate_login_token(&"0123456789abcdef".repeat(4)).is_ok());
+        assert_eq!(
+            validate_login_token("abc"),
+            Err(LoginTokenError::InvalidLength)
+        );
+        assert_eq!(
+            validate_login_token(&"ABCDEF0123456789".repeat(4)),
+            Err(LoginTokenError::InvalidCharacters)
+        );
+        assert_eq!(
+            validate_login_token(&"g".repeat(LOGIN_TOKEN_LENGTH)),
+            Err(LoginTokenError::InvalidCharacters)
+        );
+    }
+}
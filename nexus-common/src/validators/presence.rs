@@ -0,0 +1,76 @@
+//! Presence subscription list validation
+//!
+//! Validates the usernames a client watches with a `PresenceSubscribe`
+//! request (its friends list).
+
+use super::username::{UsernameError, validate_username};
+
+/// Maximum number of usernames a session can watch
+pub const MAX_PRESENCE_SUBSCRIPTIONS: usize = 100;
+
+/// Validation error for presence subscription lists
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PresenceSubscribeError {
+    /// Too many usernames in the list
+    TooMany,
+    /// A username is not a valid username
+    InvalidUsername(UsernameError),
+}
+
+/// Validate the usernames of a PresenceSubscribe request
+///
+/// Checks:
+/// - Does not exceed maximum count (100 usernames)
+/// - Each username passes `validate_username`
+///
+/// An empty list is valid (it drops all subscriptions).
+///
+/// # Errors
+///
+/// Returns a `PresenceSubscribeError` variant describing the validation failure.
+pub fn validate_presence_subscriptions(usernames: &[String]) -> Result<(), PresenceSubscribeError> {
+    if usernames.len() > MAX_PRESENCE_SUBSCRIPTIONS {
+        return Err(PresenceSubscribeError::TooMany);
+    }
+    for username in usernames {
+        validate_username(username).map_err(PresenceSubscribeError::InvalidUsername)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_subscriptions() {
+        assert!(validate_presence_subscriptions(&[]).is_ok());
+        assert!(validate_presence_subscriptions(&["alice".to_string(), "bob".to_string()]).is_ok());
+
+        let usernames: Vec<String> = (0..MAX_PRESENCE_SUBSCRIPTIONS)
+            .map(|i| format!("user{i}"))
+            .collect();
+        assert!(validate_presence_subscriptions(&usernames).is_ok());
+    }
+
+    #[test]
+    fn test_too_many_subscriptions() {
+        let usernames: Vec<String> = (0..MAX_PRESENCE_SUBSCRIPTIONS + 1)
+            .map(|i| format!("user{i}"))
+            .collect();
+        assert_eq!(
+            validate_presence_subscriptions(&usernames),
+            Err(PresenceSubscribeError::TooMany)
+        );
+    }
+
+    #[test]
+    fn test_invalid_username() {
+        assert_eq!(
+            validate_presence_subscriptions(&["alice".to_string(), String::new()]),
+            Err(PresenceSubscribeError::InvalidUsername(
+                UsernameError::Empty
+            ))
+        );
+    }
+}
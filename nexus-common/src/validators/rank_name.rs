@@ -0,0 +1,85 @@
+//! Rank name validation
+//!
+//! Validates the names of operator-defined ranks shown in user info and the
+//! leaderboard.
+
+/// Maximum length for rank names in bytes
+pub const MAX_RANK_NAME_LENGTH: usize = 32;
+
+/// Validation error for rank names
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RankNameError {
+    /// Rank name is empty or only whitespace
+    Empty,
+    /// Rank name exceeds maximum length
+    TooLong,
+    /// Rank name contains a colon or control characters
+    InvalidCharacters,
+}
+
+/// Validate a rank name
+///
+/// Checks:
+/// - Not empty or whitespace-only
+/// - Does not exceed maximum length (32 bytes)
+/// - No colons (they separate the name from its thresholds) or control characters
+///
+/// # Errors
+///
+/// Returns a `RankNameError` variant describing the validation failure.
+pub fn validate_rank_name(name: &str) -> Result<(), RankNameError> {
+    if name.trim().is_empty() {
+        return Err(RankNameError::Empty);
+    }
+    if name.len() > MAX_RANK_NAME_LENGTH {
+        return Err(RankNameError::TooLong);
+    }
+    if name.chars().any(|ch| ch == ':' || ch.is_control()) {
+        return Err(RankNameError::InvalidCharacters);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_names() {
+        assert!(validate_rank_name("Newbie").is_ok());
+        assert!(validate_rank_name("Elite Uploader").is_ok());
+        assert!(validate_rank_name(&"a".repeat(MAX_RANK_NAME_LENGTH)).is_ok());
+        // Unicode
+        assert!(validate_rank_name("常連").is_ok());
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(validate_rank_name(""), Err(RankNameError::Empty));
+        assert_eq!(validate_rank_name("   "), Err(RankNameError::Empty));
+    }
+
+    #[test]
+    fn test_too_long() {
+        assert_eq!(
+            validate_rank_name(&"a".repeat(MAX_RANK_NAME_LENGTH + 1)),
+            Err(RankNameError::TooLong)
+        );
+    }
+
+    #[test]
+    fn test_invalid_characters() {
+        assert_eq!(
+            validate_rank_name("Elite:Plus"),
+            Err(RankNameError::InvalidCharacters)
+        );
+        assert_eq!(
+            validate_rank_name("Elite\nPlus"),
+            Err(RankNameError::InvalidCharacters)
+        );
+        assert_eq!(
+            validate_rank_name("Elite\tPlus"),
+            Err(RankNameError::InvalidCharacters)
+        );
+    }
+}
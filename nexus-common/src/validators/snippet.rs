@@ -0,0 +1,216 @@
+//! Snippet validation (paste-bin style text shared by link)
+//!
+//! Validates the text, language tag, id and expiry of snippets stored on
+//! the server. Like news bodies, snippet text allows newlines and tabs.
+
+/// Maximum length for snippet text in bytes (64KB)
+pub const MAX_SNIPPET_LENGTH: usize = 64 * 1024;
+
+/// Maximum length for a snippet's language tag in characters
+pub const MAX_SNIPPET_LANGUAGE_LENGTH: usize = 32;
+
+/// Length of snippet ids (letters and digits, chosen by the server)
+pub const SNIPPET_ID_LENGTH: usize = 10;
+
+/// Shortest time a snippet can be kept, in seconds (1 minute)
+pub const MIN_SNIPPET_EXPIRY_SECS: u32 = 60;
+
+/// Longest time a snippet can be kept, in seconds (30 days)
+pub const MAX_SNIPPET_EXPIRY_SECS: u32 = 30 * 24 * 60 * 60;
+
+/// Validation error for snippet text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SnippetError {
+    /// Text is empty or whitespace only
+    Empty,
+    /// Text exceeds maximum length
+    TooLong,
+    /// Text contains invalid control characters (not newline/tab)
+    InvalidCharacters,
+}
+
+/// Validation error for snippet language tags
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SnippetLanguageError {
+    /// Tag exceeds maximum length
+    TooLong,
+    /// Tag contains characters other than ASCII letters, digits, `+`, `#`,
+    /// `-`, `.` and `_`
+    InvalidCharacters,
+}
+
+/// Validation error for snippet ids
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SnippetIdError {
+    /// Id doesn't have the expected length or characters
+    Invalid,
+}
+
+/// Validation error for snippet expiry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SnippetExpiryError {
+    /// Expiry is below the minimum
+    TooShort,
+    /// Expiry exceeds the maximum
+    TooLong,
+}
+
+/// Validate snippet text
+///
+/// Checks:
+/// - Not empty or whitespace only
+/// - Does not exceed maximum length (64KB)
+/// - No control characters except newlines (\n, \r) and tabs (\t)
+///
+/// # Errors
+///
+/// Returns a `SnippetError` variant describing the validation failure.
+pub fn validate_snippet(content: &str) -> Result<(), SnippetError> {
+    if content.trim().is_empty() {
+        return Err(SnippetError::Empty);
+    }
+
+    if content.len() > MAX_SNIPPET_LENGTH {
+        return Err(SnippetError::TooLong);
+    }
+
+    for ch in content.chars() {
+        if ch.is_control() && ch != '\n' && ch != '\r' && ch != '\t' {
+            return Err(SnippetError::InvalidCharacters);
+        }
+    }
+
+    Ok(())
+}
+
+/// Validate a snippet language tag (e.g. `rust`, `c++`, `c#`)
+///
+/// Tags are file extensions or language names used to pick syntax
+/// highlighting. Empty tags are handled by the caller (no highlighting).
+///
+/// # Errors
+///
+/// Returns a `SnippetLanguageError` variant describing the validation failure.
+pub fn validate_snippet_language(language: &str) -> Result<(), SnippetLanguageError> {
+    if language.chars().count() > MAX_SNIPPET_LANGUAGE_LENGTH {
+        return Err(SnippetLanguageError::TooLong);
+    }
+
+    if !language
+        .chars()
+        .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '+' | '#' | '-' | '.' | '_'))
+    {
+        return Err(SnippetLanguageError::InvalidCharacters);
+    }
+
+    Ok(())
+}
+
+/// Validate a snippet id
+///
+/// # Errors
+///
+/// Returns `SnippetIdError::Invalid` unless the id is `SNIPPET_ID_LENGTH`
+/// ASCII letters and digits.
+pub fn validate_snippet_id(id: &str) -> Result<(), SnippetIdError> {
+    if id.len() != SNIPPET_ID_LENGTH || !id.chars().all(|ch| ch.is_ascii_alphanumeric()) {
+        return Err(SnippetIdError::Invalid);
+    }
+    Ok(())
+}
+
+/// Validate how long a snippet is kept, in seconds
+///
+/// # Errors
+///
+/// Returns a `SnippetExpiryError` variant describing the validation failure.
+pub fn validate_snippet_expiry(expires_in: u32) -> Result<(), SnippetExpiryError> {
+    if expires_in < MIN_SNIPPET_EXPIRY_SECS {
+        return Err(SnippetExpiryError::TooShort);
+    }
+    if expires_in > MAX_SNIPPET_EXPIRY_SECS {
+        return Err(SnippetExpiryError::TooLong);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_snippet() {
+        assert!(validate_snippet("fn main() {}").is_ok());
+        assert!(validate_snippet("line 1\r\n\tline 2\n").is_ok());
+        assert!(validate_snippet(&"a".repeat(MAX_SNIPPET_LENGTH)).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_snippet() {
+        assert_eq!(validate_snippet(""), Err(SnippetError::Empty));
+        assert_eq!(validate_snippet(" \n\t"), Err(SnippetError::Empty));
+        assert_eq!(
+            validate_snippet(&"a".repeat(MAX_SNIPPET_LENGTH + 1)),
+            Err(SnippetError::TooLong)
+        );
+        assert_eq!(
+            validate_snippet("bell\x07"),
+            Err(SnippetError::InvalidCharacters)
+        );
+    }
+
+    #[test]
+    fn test_snippet_language() {
+        for language in [
+            "",
+            "rust",
+            "c++",
+            "c#",
+            "objective-c",
+            "d.ts",
+            "shell_session",
+        ] {
+            assert!(validate_snippet_language(language).is_ok(), "{language}");
+        }
+        assert_eq!(
+            validate_snippet_language(&"a".repeat(MAX_SNIPPET_LANGUAGE_LENGTH + 1)),
+            Err(SnippetLanguageError::TooLong)
+        );
+        assert_eq!(
+            validate_snippet_language("rust lang"),
+            Err(SnippetLanguageError::InvalidCharacters)
+        );
+        assert_eq!(
+            validate_snippet_language("日本"),
+            Err(SnippetLanguageError::InvalidCharacters)
+        );
+    }
+
+    #[test]
+    fn test_snippet_id() {
+        assert!(validate_snippet_id("aZ09bY18cX").is_ok());
+        assert_eq!(validate_snippet_id(""), Err(SnippetIdError::Invalid));
+        assert_eq!(
+            validate_snippet_id("aZ09bY18c"),
+            Err(SnippetIdError::Invalid)
+        );
+        assert_eq!(
+            validate_snippet_id("aZ09bY18c/"),
+            Err(SnippetIdError::Invalid)
+        );
+    }
+
+    #[test]
+    fn test_snippet_expiry() {
+        assert!(validate_snippet_expiry(MIN_SNIPPET_EXPIRY_SECS).is_ok());
+        assert!(validate_snippet_expiry(MAX_SNIPPET_EXPIRY_SECS).is_ok());
+        assert_eq!(
+            validate_snippet_expiry(0),
+            Err(SnippetExpiryError::TooShort)
+        );
+        assert_eq!(
+            validate_snippet_expiry(MAX_SNIPPET_EXPIRY_SECS + 1),
+            Err(SnippetExpiryError::TooLong)
+        );
+    }
+}
@@ -0,0 +1,95 @@
+//! Time zone name validation
+//!
+//! Validates the IANA time zone name a server reports with its clock
+//! (e.g. "Europe/Berlin").
+
+/// Maximum length for time zone names in characters
+///
+/// The longest IANA names are around 30 characters, so 64 gives headroom.
+pub const MAX_TIMEZONE_LENGTH: usize = 64;
+
+/// Validation error for time zone names
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimezoneError {
+    /// Name is empty
+    Empty,
+    /// Name exceeds maximum length
+    TooLong,
+    /// Name contains characters other than ASCII letters, digits, `/`, `_`, `-` and `+`
+    InvalidCharacters,
+}
+
+/// Validate a time zone name
+///
+/// Checks:
+/// - Not empty
+/// - Does not exceed maximum length (64 characters)
+/// - Only ASCII letters, digits, `/`, `_`, `-` and `+` (as in IANA names)
+///
+/// The name isn't looked up, so any well-formed name passes.
+///
+/// # Errors
+///
+/// Returns a `TimezoneError` variant describing the validation failure.
+pub fn validate_timezone(name: &str) -> Result<(), TimezoneError> {
+    if name.is_empty() {
+        return Err(TimezoneError::Empty);
+    }
+
+    if name.len() > MAX_TIMEZONE_LENGTH {
+        return Err(TimezoneError::TooLong);
+    }
+
+    if !name
+        .chars()
+        .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '/' | '_' | '-' | '+'))
+    {
+        return Err(TimezoneError::InvalidCharacters);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_timezones() {
+        assert!(validate_timezone("UTC").is_ok());
+        assert!(validate_timezone("Europe/Berlin").is_ok());
+        assert!(validate_timezone("America/Argentina/Buenos_Aires").is_ok());
+        assert!(validate_timezone("America/Port-au-Prince").is_ok());
+        assert!(validate_timezone("Etc/GMT+5").is_ok());
+        assert!(validate_timezone(&"a".repeat(MAX_TIMEZONE_LENGTH)).is_ok());
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(validate_timezone(""), Err(TimezoneError::Empty));
+    }
+
+    #[test]
+    fn test_too_long() {
+        assert_eq!(
+            validate_timezone(&"a".repeat(MAX_TIMEZONE_LENGTH + 1)),
+            Err(TimezoneError::TooLong)
+        );
+    }
+
+    #[test]
+    fn test_invalid_characters() {
+        assert_eq!(
+            validate_timezone("Europe/Berlin "),
+            Err(TimezoneError::InvalidCharacters)
+        );
+        assert_eq!(
+            validate_timezone("Europe\nBerlin"),
+            Err(TimezoneError::InvalidCharacters)
+        );
+        assert_eq!(
+            validate_timezone("Europe/Zürich"),
+            Err(TimezoneError::InvalidCharacters)
+        );
+    }
+}
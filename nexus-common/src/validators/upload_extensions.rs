@@ -0,0 +1,86 @@
+//! Upload rule extension list validation
+//!
+//! Validates the file extensions an upload rule allows into a directory.
+//! Extensions are given without the leading dot (`mp3`, not `.mp3`).
+
+/// Maximum number of extensions in an upload rule
+pub const MAX_UPLOAD_EXTENSIONS: usize = 32;
+
+/// Maximum length of a single extension in characters
+pub const MAX_UPLOAD_EXTENSION_LENGTH: usize = 16;
+
+/// Validation error for upload rule extension lists
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UploadExtensionsError {
+    /// Too many extensions in the list
+    TooMany,
+    /// An extension is empty, too long, or not ASCII letters and digits
+    Invalid(String),
+}
+
+/// Validate an upload rule's extension list
+///
+/// Checks:
+/// - At most 32 extensions (an empty list allows any file type)
+/// - Each extension is 1-16 ASCII letters or digits, without a leading dot
+///
+/// # Errors
+///
+/// Returns an `UploadExtensionsError` variant describing the validation failure.
+pub fn validate_upload_extensions(extensions: &[String]) -> Result<(), UploadExtensionsError> {
+    if extensions.len() > MAX_UPLOAD_EXTENSIONS {
+        return Err(UploadExtensionsError::TooMany);
+    }
+    for extension in extensions {
+        if extension.is_empty()
+            || extension.len() > MAX_UPLOAD_EXTENSION_LENGTH
+            || !extension.chars().all(|ch| ch.is_ascii_alphanumeric())
+        {
+            return Err(UploadExtensionsError::Invalid(extension.clone()));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(extensions: &[&str]) -> Vec<String> {
+        extensions.iter().map(|ext| ext.to_string()).collect()
+    }
+
+    #[test]
+    fn test_valid_extensions() {
+        assert!(validate_upload_extensions(&[]).is_ok());
+        assert!(validate_upload_extensions(&list(&["mp3", "FLAC", "7z"])).is_ok());
+        assert!(validate_upload_extensions(&list(&["a".repeat(16).as_str()])).is_ok());
+    }
+
+    #[test]
+    fn test_too_many_extensions() {
+        let extensions: Vec<String> = (0..MAX_UPLOAD_EXTENSIONS)
+            .map(|i| format!("e{i}"))
+            .collect();
+        assert!(validate_upload_extensions(&extensions).is_ok());
+
+        let extensions: Vec<String> = (0..=MAX_UPLOAD_EXTENSIONS)
+            .map(|i| format!("e{i}"))
+            .collect();
+        assert_eq!(
+            validate_upload_extensions(&extensions),
+            Err(UploadExtensionsError::TooMany)
+        );
+    }
+
+    #[test]
+    fn test_invalid_extensions() {
+        for bad in ["", ".mp3", "tar.gz", "mp 3", "ñ", &"a".repeat(17)] {
+            assert_eq!(
+                validate_upload_extensions(&list(&["mp3", bad])),
+                Err(UploadExtensionsError::Invalid(bad.to_string())),
+                "{bad:?} should be rejected"
+            );
+        }
+    }
+}
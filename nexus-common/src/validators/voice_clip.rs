@@ -0,0 +1,150 @@
+//! Voice clip validation (soundboard clip names and audio data URIs)
+
+use super::data_uri::{DataUriError, validate_image_data_uri};
+
+/// Maximum length for voice clip names in characters
+pub const MAX_VOICE_CLIP_NAME_LENGTH: usize = 32;
+
+/// Maximum size of a voice clip's decoded audio in bytes (256KB)
+pub const MAX_VOICE_CLIP_SIZE: usize = 256 * 1024;
+
+/// Maximum length of a voice clip data URI (256KB binary + base64 overhead + prefix)
+pub const MAX_VOICE_CLIP_DATA_URI_LENGTH: usize = 350_000;
+
+/// Allowed MIME types for voice clips (Ogg Vorbis only)
+pub const ALLOWED_VOICE_CLIP_MIME_TYPES: &[&str] = &["audio/ogg"];
+
+/// Validation error for voice clip names
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VoiceClipNameError {
+    /// Name is empty
+    Empty,
+    /// Name exceeds maximum length
+    TooLong,
+    /// Name contains characters other than letters, digits, `-` and `_`
+    InvalidCharacters,
+}
+
+/// Validation error for voice clip audio data
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VoiceClipDataError {
+    TooLarge,
+    InvalidFormat,
+    UnsupportedType,
+}
+
+impl From<DataUriError> for VoiceClipDataError {
+    fn from(err: DataUriError) -> Self {
+        match err {
+            DataUriError::TooLarge => VoiceClipDataError::TooLarge,
+            DataUriError::InvalidFormat => VoiceClipDataError::InvalidFormat,
+            DataUriError::UnsupportedType => VoiceClipDataError::UnsupportedType,
+        }
+    }
+}
+
+/// Validate a voice clip name
+///
+/// Clip names are typed as command arguments, so they are limited to
+/// letters, digits, `-` and `_` (no whitespace).
+///
+/// # Errors
+///
+/// Returns a `VoiceClipNameError` variant describing the validation failure.
+pub fn validate_voice_clip_name(name: &str) -> Result<(), VoiceClipNameError> {
+    if name.is_empty() {
+        return Err(VoiceClipNameError::Empty);
+    }
+
+    if name.chars().count() > MAX_VOICE_CLIP_NAME_LENGTH {
+        return Err(VoiceClipNameError::TooLong);
+    }
+
+    if !name
+        .chars()
+        .all(|ch| ch.is_alphanumeric() || ch == '-' || ch == '_')
+    {
+        return Err(VoiceClipNameError::InvalidCharacters);
+    }
+
+    Ok(())
+}
+
+/// Validate a voice clip data URI (`data:audio/ogg;base64,...`)
+///
+/// Only checks the envelope; the server decodes the audio to enforce
+/// `MAX_VOICE_CLIP_SIZE` and the duration limit.
+///
+/// # Examples
+///
+/// ```
+/// use nexus_common::validators::{validate_voice_clip_data, VoiceClipDataError};
+///
+/// assert!(validate_voice_clip_data("data:audio/ogg;base64,T2dnUw==").is_ok());
+/// assert_eq!(
+///     validate_voice_clip_data("data:audio/mpeg;base64,SUQz"),
+///     Err(VoiceClipDataError::UnsupportedType)
+/// );
+/// ```
+pub fn validate_voice_clip_data(data: &str) -> Result<(), VoiceClipDataError> {
+    validate_image_data_uri(
+        data,
+        MAX_VOICE_CLIP_DATA_URI_LENGTH,
+        ALLOWED_VOICE_CLIP_MIME_TYPES,
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_names() {
+        for name in ["airhorn", "rim-shot", "sad_trombone", "a", "Clip2", "ñandú"] {
+            assert!(validate_voice_clip_name(name).is_ok(), "{name}");
+        }
+        let at_limit = "a".repeat(MAX_VOICE_CLIP_NAME_LENGTH);
+        assert!(validate_voice_clip_name(&at_limit).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_names() {
+        assert_eq!(validate_voice_clip_name(""), Err(VoiceClipNameError::Empty));
+        assert_eq!(
+            validate_voice_clip_name(&"a".repeat(MAX_VOICE_CLIP_NAME_LENGTH + 1)),
+            Err(VoiceClipNameError::TooLong)
+        );
+        for name in ["air horn", "clip.ogg", "../clip", "clip\n", "#clip"] {
+            assert_eq!(
+                validate_voice_clip_name(name),
+                Err(VoiceClipNameError::InvalidCharacters),
+                "{name:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_data() {
+        assert!(validate_voice_clip_data("data:audio/ogg;base64,").is_ok());
+        assert_eq!(
+            validate_voice_clip_data("audio/ogg;base64,abc"),
+            Err(VoiceClipDataError::InvalidFormat)
+        );
+        assert_eq!(
+            validate_voice_clip_data("data:image/png;base64,abc"),
+            Err(VoiceClipDataError::UnsupportedType)
+        );
+
+        let prefix = "data:audio/ogg;base64,";
+        let over_limit = format!(
+            "{}{}",
+            prefix,
+            "A".repeat(MAX_VOICE_CLIP_DATA_URI_LENGTH - prefix.len() + 1)
+        );
+        assert_eq!(
+            validate_voice_clip_data(&over_limit),
+            Err(VoiceClipDataError::TooLarge)
+        );
+    }
+}
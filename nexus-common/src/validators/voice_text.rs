@@ -0,0 +1,99 @@
+//! Voice text message validation
+//!
+//! Validates the short text messages shown as overlay bubbles to everyone in
+//! a voice session.
+
+/// Maximum length for voice text messages in bytes
+pub const MAX_VOICE_TEXT_LENGTH: usize = 256;
+
+/// Validation error for voice text messages
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VoiceTextError {
+    /// Message is empty or whitespace-only
+    Empty,
+    /// Message exceeds maximum length
+    TooLong,
+    /// Message contains newline characters
+    ContainsNewlines,
+    /// Message contains invalid characters
+    InvalidCharacters,
+}
+
+/// Validate a voice text message
+///
+/// Checks:
+/// - Not empty or whitespace-only
+/// - Does not exceed maximum length (256 bytes)
+/// - No control characters (newlines reported separately)
+///
+/// # Errors
+///
+/// Returns a `VoiceTextError` variant describing the validation failure.
+pub fn validate_voice_text(message: &str) -> Result<(), VoiceTextError> {
+    if message.trim().is_empty() {
+        return Err(VoiceTextError::Empty);
+    }
+    if message.len() > MAX_VOICE_TEXT_LENGTH {
+        return Err(VoiceTextError::TooLong);
+    }
+    for ch in message.chars() {
+        if ch.is_control() {
+            if ch == '\n' || ch == '\r' {
+                return Err(VoiceTextError::ContainsNewlines);
+            }
+            return Err(VoiceTextError::InvalidCharacters);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_messages() {
+        assert!(validate_voice_text("your mic is muted").is_ok());
+        assert!(validate_voice_text(&"a".repeat(MAX_VOICE_TEXT_LENGTH)).is_ok());
+        // Unicode and emoji
+        assert!(validate_voice_text("マイクがミュートです 🎤").is_ok());
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(validate_voice_text(""), Err(VoiceTextError::Empty));
+        assert_eq!(validate_voice_text("   "), Err(VoiceTextError::Empty));
+    }
+
+    #[test]
+    fn test_too_long() {
+        assert_eq!(
+            validate_voice_text(&"a".repeat(MAX_VOICE_TEXT_LENGTH + 1)),
+            Err(VoiceTextError::TooLong)
+        );
+    }
+
+    #[test]
+    fn test_newlines() {
+        assert_eq!(
+            validate_voice_text("Line1\nLine2"),
+            Err(VoiceTextError::ContainsNewlines)
+        );
+        assert_eq!(
+            validate_voice_text("Line1\rLine2"),
+            Err(VoiceTextError::ContainsNewlines)
+        );
+    }
+
+    #[test]
+    fn test_invalid_characters() {
+        assert_eq!(
+            validate_voice_text("Hello\0World"),
+            Err(VoiceTextError::InvalidCharacters)
+        );
+        assert_eq!(
+            validate_voice_text("Hello\tWorld"),
+            Err(VoiceTextError::InvalidCharacters)
+        );
+    }
+}
@@ -4,6 +4,7 @@
 //! Voice packets are sent at ~50 packets/second with Opus-encoded audio.
 
 use std::fmt;
+use std::time::Instant;
 
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -52,6 +53,28 @@ pub const STEREO_CHANNELS: u16 = 2;
 /// Minimum jitter buffer size in milliseconds
 pub const JITTER_BUFFER_MS: u32 = 20;
 
+/// Interval between VoiceStats reports sent by the server (5 seconds)
+pub const VOICE_STATS_INTERVAL_SECS: u64 = 5;
+
+/// Packet loss (percent) at or above which a connection is rated fair
+pub const VOICE_FAIR_LOSS_PERCENT: u8 = 3;
+
+/// Packet loss (percent) at or above which a connection is rated poor
+pub const VOICE_POOR_LOSS_PERCENT: u8 = 10;
+
+/// Jitter (milliseconds) at or above which a connection is rated fair
+pub const VOICE_FAIR_JITTER_MS: u32 = 30;
+
+/// Jitter (milliseconds) at or above which a connection is rated poor
+pub const VOICE_POOR_JITTER_MS: u32 = 60;
+
+/// Arrival gaps longer than this (beyond the packets' own timestamp spacing)
+/// are treated as a pause in speech rather than jitter
+const STATS_TALK_SPURT_GAP_MS: f64 = 500.0;
+
+/// Maximum playback length of a soundboard voice clip (10 seconds)
+pub const MAX_VOICE_CLIP_DURATION_MS: u64 = 10_000;
+
 /// Voice quality presets (Opus bitrate in bits per second)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub enum VoiceQuality {
@@ -82,6 +105,26 @@ impl VoiceQuality {
         ]
     }
 
+    /// Get the next lower quality level (None if already the lowest)
+    pub fn lower(self) -> Option<VoiceQuality> {
+        match self {
+            VoiceQuality::Low => None,
+            VoiceQuality::Medium => Some(VoiceQuality::Low),
+            VoiceQuality::High => Some(VoiceQuality::Medium),
+            VoiceQuality::VeryHigh => Some(VoiceQuality::High),
+        }
+    }
+
+    /// Get the next higher quality level (None if already the highest)
+    pub fn higher(self) -> Option<VoiceQuality> {
+        match self {
+            VoiceQuality::Low => Some(VoiceQuality::Medium),
+            VoiceQuality::Medium => Some(VoiceQuality::High),
+            VoiceQuality::High => Some(VoiceQuality::VeryHigh),
+            VoiceQuality::VeryHigh => None,
+        }
+    }
+
     /// Get the translation key for this quality level
     pub fn translation_key(self) -> &'static str {
         match self {
@@ -415,10 +458,201 @@ impl RelayedVoicePacket {
     }
 }
 
+/// Connection quality rating derived from packet loss and jitter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoiceConnectionQuality {
+    /// Little or no loss and jitter
+    Good,
+    /// Noticeable loss or jitter
+    Fair,
+    /// Heavy loss or jitter, audio is likely breaking up
+    Poor,
+}
+
+impl VoiceConnectionQuality {
+    /// Rate a connection from its packet loss (percent) and jitter (milliseconds)
+    pub fn from_stats(loss_percent: u8, jitter_ms: u32) -> Self {
+        if loss_percent >= VOICE_POOR_LOSS_PERCENT || jitter_ms >= VOICE_POOR_JITTER_MS {
+            VoiceConnectionQuality::Poor
+        } else if loss_percent >= VOICE_FAIR_LOSS_PERCENT || jitter_ms >= VOICE_FAIR_JITTER_MS {
+            VoiceConnectionQuality::Fair
+        } else {
+            VoiceConnectionQuality::Good
+        }
+    }
+
+    /// Get the translation key for this rating
+    pub fn translation_key(self) -> &'static str {
+        match self {
+            VoiceConnectionQuality::Good => "voice-connection-good",
+            VoiceConnectionQuality::Fair => "voice-connection-fair",
+            VoiceConnectionQuality::Poor => "voice-connection-poor",
+        }
+    }
+}
+
+/// Loss and jitter observed on a voice stream during one report interval
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VoiceStreamReport {
+    /// Percentage of expected packets that never arrived (0-100)
+    pub loss_percent: u8,
+    /// Interarrival jitter in milliseconds
+    pub jitter_ms: u32,
+}
+
+/// Receive-side statistics for a single voice stream
+///
+/// Loss is counted from gaps in sequence numbers; jitter is the smoothed
+/// interarrival jitter from RFC 3550 (section 6.4.1), using the packets'
+/// 48kHz timestamps as the send clock. Senders only advance their sequence
+/// number for audio frames, so pauses in speech do not count as loss.
+#[derive(Debug, Clone, Default)]
+pub struct VoiceStreamStats {
+    /// Highest sequence number reported in the previous interval
+    base_sequence: Option<u32>,
+    /// Highest sequence number received so far
+    highest_sequence: Option<u32>,
+    /// Packets received in the current interval
+    received: u32,
+    /// Arrival time and timestamp of the previous packet
+    last_arrival: Option<(Instant, u32)>,
+    /// Smoothed jitter estimate in milliseconds
+    jitter_ms: f64,
+}
+
+impl VoiceStreamStats {
+    /// Create empty statistics
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record an audio packet
+    pub fn record(&mut self, sequence: u32, timestamp: u32, arrival: Instant) {
+        // The first packet ever seen starts the count just before itself
+        if self.base_sequence.is_none() {
+            self.base_sequence = Some(sequence.wrapping_sub(1));
+        }
+
+        self.received = self.received.saturating_add(1);
+        match self.highest_sequence {
+            Some(highest) if (sequence.wrapping_sub(highest) as i32) <= 0 => {}
+            _ => self.highest_sequence = Some(sequence),
+        }
+
+        if let Some((last_time, last_timestamp)) = self.last_arrival {
+            // Packets can arrive out of order, so the elapsed time may be negative
+            let elapsed_ms = if arrival >= last_time {
+                (arrival - last_time).as_secs_f64() * 1000.0
+            } else {
+                -(last_time - arrival).as_secs_f64() * 1000.0
+            };
+            let sent_ms = timestamp.wrapping_sub(last_timestamp) as i32 as f64 * 1000.0
+                / VOICE_SAMPLE_RATE as f64;
+            let deviation = (elapsed_ms - sent_ms).abs();
+            if deviation < STATS_TALK_SPURT_GAP_MS {
+                self.jitter_ms += (deviation - self.jitter_ms) / 16.0;
+            }
+        }
+        self.last_arrival = Some((arrival, timestamp));
+    }
+
+    /// Finish the current interval and return its loss and jitter
+    ///
+    /// Returns `None` if no packets arrived during the interval.
+    pub fn take_report(&mut self) -> Option<VoiceStreamReport> {
+        if self.received == 0 {
+            return None;
+        }
+        let (Some(base), Some(highest)) = (self.base_sequence, self.highest_sequence) else {
+            return None;
+        };
+
+        let expected = highest.wrapping_sub(base);
+        let lost = expected.saturating_sub(self.received);
+        let loss_percent = if expected == 0 {
+            0
+        } else {
+            (u64::from(lost) * 100 / u64::from(expected)).min(100) as u8
+        };
+
+        self.base_sequence = Some(highest);
+        self.received = 0;
+
+        Some(VoiceStreamReport {
+            loss_percent,
+            jitter_ms: self.jitter_ms.round() as u32,
+        })
+    }
+}
+
+/// Ogg page header size before the segment table
+const OGG_PAGE_HEADER_SIZE: usize = 27;
+
+/// Duration of an Ogg Vorbis stream in milliseconds
+///
+/// Reads the sample rate from the Vorbis identification header and the final
+/// granule position (total samples per channel) from the last page of the
+/// same logical stream. No audio is decoded.
+///
+/// Returns `None` if the data is not a well-formed Ogg Vorbis stream.
+pub fn ogg_vorbis_duration_ms(bytes: &[u8]) -> Option<u64> {
+    let mut offset = 0;
+    let mut serial = None;
+    let mut sample_rate = 0u32;
+    let mut last_granule = None;
+
+    while offset < bytes.len() {
+        let header = bytes.get(offset..offset + OGG_PAGE_HEADER_SIZE)?;
+        if &header[0..4] != b"OggS" || header[4] != 0 {
+            return None;
+        }
+
+        let granule = i64::from_le_bytes(header[6..14].try_into().ok()?);
+        let page_serial = u32::from_le_bytes(header[14..18].try_into().ok()?);
+        let segment_count = header[26] as usize;
+        let segments_start = offset + OGG_PAGE_HEADER_SIZE;
+        let segments = bytes.get(segments_start..segments_start + segment_count)?;
+        let data_start = segments_start + segment_count;
+        let data_len: usize = segments.iter().map(|&len| len as usize).sum();
+        let data = bytes.get(data_start..data_start + data_len)?;
+
+        match serial {
+            None => {
+                // The first page carries the identification header:
+                // type (1) + "vorbis" (6) + version (4) + channels (1) + rate (4)
+                if data.len() < 16 || data[0] != 0x01 || &data[1..7] != b"vorbis" {
+                    return None;
+                }
+                sample_rate = u32::from_le_bytes(data[12..16].try_into().ok()?);
+                if sample_rate == 0 {
+                    return None;
+                }
+                serial = Some(page_serial);
+            }
+            Some(serial) if serial == page_serial && granule >= 0 => {
+                last_granule = Some(granule as u64);
+            }
+            Some(_) => {}
+        }
+
+        offset = data_start + data_len;
+    }
+
+    Some(last_granule?.saturating_mul(1000) / u64::from(sample_rate))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_voice_quality_steps() {
+        assert_eq!(VoiceQuality::Low.lower(), None);
+        assert_eq!(VoiceQuality::High.lower(), Some(VoiceQuality::Medium));
+        assert_eq!(VoiceQuality::Medium.higher(), Some(VoiceQuality::High));
+        assert_eq!(VoiceQuality::VeryHigh.higher(), None);
+    }
+
     #[test]
     fn test_voice_quality_bitrate() {
         assert_eq!(VoiceQuality::Low.bitrate(), 16000);
@@ -622,4 +856,163 @@ mod tests {
         // Verify header size (type + token + seq + ts = 1 + 16 + 4 + 4)
         assert_eq!(VOICE_HEADER_SIZE, 25);
     }
+
+    #[test]
+    fn test_connection_quality_from_stats() {
+        assert_eq!(
+            VoiceConnectionQuality::from_stats(0, 5),
+            VoiceConnectionQuality::Good
+        );
+        assert_eq!(
+            VoiceConnectionQuality::from_stats(VOICE_FAIR_LOSS_PERCENT, 5),
+            VoiceConnectionQuality::Fair
+        );
+        assert_eq!(
+            VoiceConnectionQuality::from_stats(0, VOICE_FAIR_JITTER_MS),
+            VoiceConnectionQuality::Fair
+        );
+        assert_eq!(
+            VoiceConnectionQuality::from_stats(VOICE_POOR_LOSS_PERCENT, 0),
+            VoiceConnectionQuality::Poor
+        );
+        assert_eq!(
+            VoiceConnectionQuality::from_stats(1, VOICE_POOR_JITTER_MS),
+            VoiceConnectionQuality::Poor
+        );
+    }
+
+    #[test]
+    fn test_stream_stats_loss() {
+        let mut stats = VoiceStreamStats::new();
+        let start = Instant::now();
+
+        // 20 packets expected, 5 missing (every fourth)
+        for seq in 1..=20u32 {
+            if seq % 4 != 2 {
+                let arrival = start + std::time::Duration::from_millis(u64::from(seq) * 10);
+                stats.record(seq, seq * VOICE_SAMPLES_PER_FRAME, arrival);
+            }
+        }
+
+        let report = stats.take_report().expect("packets were received");
+        assert_eq!(report.loss_percent, 25);
+        assert_eq!(report.jitter_ms, 0);
+
+        // Nothing received since the last report
+        assert!(stats.take_report().is_none());
+    }
+
+    #[test]
+    fn test_stream_stats_no_loss_across_intervals() {
+        let mut stats = VoiceStreamStats::new();
+        let start = Instant::now();
+
+        for seq in 0..10u32 {
+            stats.record(seq, seq * VOICE_SAMPLES_PER_FRAME, start);
+        }
+        stats.take_report();
+        for seq in 10..20u32 {
+            stats.record(seq, seq * VOICE_SAMPLES_PER_FRAME, start);
+        }
+
+        let report = stats.take_report().expect("packets were received");
+        assert_eq!(report.loss_percent, 0);
+    }
+
+    #[test]
+    fn test_stream_stats_jitter_ignores_speech_pauses() {
+        let mut stats = VoiceStreamStats::new();
+        let start = Instant::now();
+
+        // Two talk spurts with consecutive sequence numbers but a long silence between
+        stats.record(0, 0, start);
+        stats.record(
+            1,
+            VOICE_SAMPLES_PER_FRAME,
+            start + std::time::Duration::from_secs(3),
+        );
+
+        let report = stats.take_report().expect("packets were received");
+        assert_eq!(report.loss_percent, 0);
+        assert_eq!(report.jitter_ms, 0);
+    }
+
+    #[test]
+    fn test_stream_stats_jitter() {
+        let mut stats = VoiceStreamStats::new();
+        let start = Instant::now();
+
+        // Packets sent every 10ms but arriving alternately 0ms and 40ms late
+        for seq in 0..200u32 {
+            let delay = if seq % 2 == 0 { 0 } else { 40 };
+            let arrival = start + std::time::Duration::from_millis(u64::from(seq) * 10 + delay);
+            stats.record(seq, seq * VOICE_SAMPLES_PER_FRAME, arrival);
+        }
+
+        let report = stats.take_report().expect("packets were received");
+        assert!(
+            (35..=40).contains(&report.jitter_ms),
+            "jitter was {}",
+            report.jitter_ms
+        );
+    }
+
+    /// Build an Ogg page with a single segment-laced packet
+    fn ogg_page(serial: u32, granule: i64, data: &[u8]) -> Vec<u8> {
+        let mut page = b"OggS".to_vec();
+        page.push(0); // version
+        page.push(0); // header type
+        page.extend_from_slice(&granule.to_le_bytes());
+        page.extend_from_slice(&serial.to_le_bytes());
+        page.extend_from_slice(&0u32.to_le_bytes()); // sequence
+        page.extend_from_slice(&0u32.to_le_bytes()); // checksum (not verified)
+        let mut segments = vec![255u8; data.len() / 255];
+        segments.push((data.len() % 255) as u8);
+        page.push(segments.len() as u8);
+        page.extend_from_slice(&segments);
+        page.extend_from_slice(data);
+        page
+    }
+
+    fn vorbis_id_header(sample_rate: u32) -> Vec<u8> {
+        let mut data = vec![0x01];
+        data.extend_from_slice(b"vorbis");
+        data.extend_from_slice(&0u32.to_le_bytes()); // version
+        data.push(2); // channels
+        data.extend_from_slice(&sample_rate.to_le_bytes());
+        data.extend_from_slice(&[0; 14]); // bitrates, block sizes, framing
+        data
+    }
+
+    #[test]
+    fn test_ogg_vorbis_duration() {
+        let mut stream = ogg_page(7, 0, &vorbis_id_header(44100));
+        stream.extend(ogg_page(7, -1, &[0; 300]));
+        stream.extend(ogg_page(9, 999_999, &[0; 10])); // other logical stream
+        stream.extend(ogg_page(7, 44100, &[0; 100]));
+        stream.extend(ogg_page(7, 110_250, &[0; 100]));
+
+        assert_eq!(ogg_vorbis_duration_ms(&stream), Some(2500));
+    }
+
+    #[test]
+    fn test_ogg_vorbis_duration_invalid() {
+        // Not Ogg at all
+        assert_eq!(ogg_vorbis_duration_ms(b"ID3\x04 not ogg"), None);
+        assert_eq!(ogg_vorbis_duration_ms(&[]), None);
+
+        // Ogg, but not Vorbis
+        let opus = ogg_page(1, 0, b"OpusHead\x01\x02\x00\x00\x80\xbb\x00\x00");
+        assert_eq!(ogg_vorbis_duration_ms(&opus), None);
+
+        // Identification header only, no further pages
+        let headers = ogg_page(1, 0, &vorbis_id_header(48000));
+        assert_eq!(ogg_vorbis_duration_ms(&headers), None);
+
+        // Truncated page
+        let mut truncated = headers.clone();
+        truncated.extend(ogg_page(1, 48000, &[0; 100]));
+        truncated.truncate(truncated.len() - 10);
+        assert_eq!(ogg_vorbis_duration_ms(&truncated), None);
+    }
 }
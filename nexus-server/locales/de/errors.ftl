@@ -11,6 +11,13 @@ err-nickname-not-online = Benutzer „{ $nickname }" ist nicht online
 err-nickname-required = Spitzname für gemeinsame Konten erforderlich
 err-nickname-too-long = Spitzname ist zu lang (max. { $max_length } Zeichen)
 
+# Fehler bei der Spitznamen-Registrierung
+err-nickname-registered = Spitzname ist für einen anderen Benutzer registriert
+err-nickname-already-registered = Spitzname „{ $nickname }" ist bereits registriert
+err-nickname-not-registered = Spitzname „{ $nickname }" ist nicht registriert
+err-nickname-register-limit = Sie können höchstens { $max } Spitznamen registrieren
+err-nickname-register-shared = Gemeinsame Konten können keine Spitznamen registrieren
+
 # Abwesenheitsnachricht-Fehler
 err-status-too-long = Abwesenheitsnachricht ist zu lang (max. { $max_length } Zeichen)
 err-status-contains-newlines = Abwesenheitsnachricht darf keine Zeilenumbrüche enthalten
@@ -28,6 +35,10 @@ err-guest-disabled = Gastzugang ist auf diesem Server nicht aktiviert
 err-cannot-rename-guest = Das Gastkonto kann nicht umbenannt werden
 err-cannot-change-guest-password = Das Passwort des Gastkontos kann nicht geändert werden
 err-cannot-delete-guest = Das Gastkonto kann nicht gelöscht werden
+err-guest-invalid-permissions = Das Gastkonto kann diese Berechtigungen nicht haben: { $permissions }
+err-guest-limit = Von deiner Adresse sind zu viele Gäste verbunden (max. { $max })
+err-guest-upgrade-disabled = Gäste können auf diesem Server keine Konten erstellen
+err-guest-upgrade-not-guest = Nur Gäste können ein Konto registrieren
 
 # Avatar-Validierungsfehler
 err-avatar-invalid-format = Ungültiges Avatar-Format (muss eine Data-URI mit Base64-Kodierung sein)
@@ -36,10 +47,17 @@ err-avatar-unsupported-type = Nicht unterstützter Avatar-Typ (nur PNG, WebP ode
 err-authentication = Authentifizierungsfehler
 err-invalid-credentials = Ungültiger Benutzername oder Passwort
 err-handshake-required = Handshake erforderlich
+err-proof-of-work-required = Arbeitsnachweis erforderlich
 err-already-logged-in = Bereits angemeldet
 err-handshake-already-completed = Handshake bereits abgeschlossen
 err-account-deleted = Ihr Konto wurde gelöscht
 err-account-disabled-by-admin = Konto vom Administrator deaktiviert
+err-sso-unavailable = Single Sign-On ist auf diesem Server derzeit nicht verfügbar
+err-sso-failed = Single Sign-On fehlgeschlagen, bitte versuche es erneut
+err-login-token-expired = Deine Anmeldesitzung ist abgelaufen, bitte melde dich erneut an
+err-sso-username-unavailable = Ein Konto namens „{ $username }" kann nicht erstellt werden: Der Name ist vergeben oder nicht erlaubt. Bitte wende dich an einen Administrator.
+err-ldap-unavailable = Der Verzeichnisserver ist gerade nicht erreichbar, bitte versuche es später erneut
+err-ldap-no-group = Dein Verzeichniskonto ist in keiner Gruppe mit Zugang zu diesem Server
 
 # Berechtigungs- und Zugriffsfehler
 err-permission-denied = Zugriff verweigert
@@ -58,6 +76,8 @@ err-channel-not-found = Kanal '{ $channel }' nicht gefunden
 err-channel-already-member = Sie sind bereits Mitglied von Kanal '{ $channel }'
 err-channel-limit-exceeded = Sie können nicht mehr als { $max } Kanälen beitreten
 err-channel-list-invalid = Ungültiger Kanal '{ $channel }': { $reason }
+err-channel-language-too-long = Das Sprachkürzel ist zu lang (maximal { $max_length } Zeichen)
+err-channel-language-invalid = Das Sprachkürzel muss ein Sprachcode wie "en" oder "pt-BR" sein
 
 # Datenbankfehler
 err-database = Datenbankfehler
@@ -79,11 +99,28 @@ err-cannot-kick-admin = Administrator-Benutzer können nicht hinausgeworfen werd
 err-cannot-delete-admin = Nur Administratoren können Administrator-Benutzer löschen
 err-cannot-edit-admin = Nur Administratoren können Administrator-Benutzer bearbeiten
 err-cannot-message-self = Sie können sich nicht selbst eine Nachricht senden
+err-message-history-days-too-long = Der Nachrichtenverlauf kann höchstens { $max } Tage aufbewahrt werden
+err-message-history-shared = Geteilte Konten können keinen Nachrichtenverlauf speichern
+err-group-no-members = Nennen Sie mindestens einen Benutzer für das Gespräch
+err-group-too-many-members = Ein Gruppengespräch kann höchstens { $max } Mitglieder haben
+err-group-limit = Du kannst höchstens { $max } selbst gestartete Gruppengespräche gleichzeitig haben
+err-group-not-found = Gruppengespräch nicht gefunden
+err-group-already-member = { $nickname } ist bereits in diesem Gespräch
+err-snippet-empty = Das Snippet darf nicht leer sein
+err-snippet-too-long = Das Snippet ist zu lang (maximal { $max_length } Bytes)
+err-snippet-invalid-characters = Das Snippet enthält ungültige Zeichen
+err-snippet-language-invalid = Ungültige Snippet-Sprache: { $language }
+err-snippet-expiry-invalid = Snippets können 1 Minute bis { $max_days } Tage aufbewahrt werden
+err-snippet-not-found = Snippet nicht gefunden (es ist eventuell abgelaufen)
+err-snippet-limit = Du kannst höchstens { $max } Snippets gleichzeitig teilen
 err-cannot-disable-last-admin = Der letzte Administrator kann nicht deaktiviert werden
 
 # Chat-Themenfehler
 err-topic-contains-newlines = Das Thema darf keine Zeilenumbrüche enthalten
 err-topic-invalid-characters = Das Thema enthält ungültige Zeichen
+err-topic-locked = Das Thema dieses Kanals ist gesperrt
+err-entry-message-invalid-characters = Die Eintrittsnachricht enthält ungültige Zeichen
+err-rules-invalid-characters = Die Regeln enthalten ungültige Zeichen
 
 # Versionsvalidierungsfehler
 err-version-empty = Die Version darf nicht leer sein
@@ -110,6 +147,7 @@ err-features-invalid-characters = Der Feature-Name enthält ungültige Zeichen
 err-message-empty = Die Nachricht darf nicht leer sein
 err-message-contains-newlines = Die Nachricht darf keine Zeilenumbrüche enthalten
 err-message-invalid-characters = Die Nachricht enthält ungültige Zeichen
+err-idempotency-key-invalid = Ungültiger Idempotenzschlüssel der Nachricht
 
 # Benutzernamen-Validierungsfehler
 err-username-empty = Der Benutzername darf nicht leer sein
@@ -119,9 +157,13 @@ err-username-invalid = Der Benutzername enthält ungültige Zeichen (Buchstaben,
 err-unknown-permission = Unbekannte Berechtigung: '{ $permission }'
 
 # Dynamische Fehlermeldungen (mit Parametern)
+err-broadcast-invalid-delay = Ungültiges Verzögerungsformat (verwende 10m, 4h oder 7d)
 err-broadcast-too-long = Nachricht zu lang (maximal { $max_length } Zeichen)
+err-broadcast-too-many-scheduled = Zu viele geplante Rundsendungen (maximal { $max_count })
 err-chat-too-long = Nachricht zu lang (maximal { $max_length } Zeichen)
 err-topic-too-long = Das Thema darf { $max_length } Zeichen nicht überschreiten
+err-entry-message-too-long = Die Eintrittsnachricht darf { $max_length } Zeichen nicht überschreiten
+err-rules-too-long = Die Regeln dürfen { $max_length } Zeichen nicht überschreiten
 err-kicked-by = Sie wurden von { $username } hinausgeworfen
 err-kicked-by-reason = Sie wurden von { $username } hinausgeworfen: { $reason }
 err-username-exists = Der Benutzername „{ $username }" existiert bereits
@@ -158,12 +200,16 @@ err-server-image-unsupported-type = Nicht unterstützter Serverbild-Typ (nur PNG
 err-news-not-found = News-Eintrag #{ $id } nicht gefunden
 err-news-body-too-long = News-Text ist zu lang (maximal { $max_length } Zeichen)
 err-news-body-invalid-characters = News-Text enthält ungültige Zeichen
-err-news-image-too-large = News-Bild ist zu groß (maximal 512KB)
+err-news-image-too-large = News-Bild ist zu groß (maximal { $max_kb }KB)
 err-news-image-invalid-format = Ungültiges News-Bild-Format (muss eine Data-URI mit Base64-Kodierung sein)
 err-news-image-unsupported-type = Nicht unterstützter News-Bild-Typ (nur PNG, WebP, JPEG oder SVG)
-err-news-empty-content = Nachricht muss entweder Textinhalt oder ein Bild enthalten
+err-news-too-many-attachments = News-Beiträge können höchstens { $max } Anhänge haben
+err-news-caption-too-long = Anhang-Beschriftung ist zu lang (max. { $max_length } Zeichen)
+err-news-caption-invalid-characters = Anhang-Beschriftung enthält ungültige Zeichen
+err-news-empty-content = Nachricht muss entweder Textinhalt oder einen Anhang enthalten
 err-cannot-edit-admin-news = Nur Administratoren können von Administratoren erstellte Nachrichten bearbeiten
 err-cannot-delete-admin-news = Nur Administratoren können von Administratoren erstellte Nachrichten löschen
+err-news-search-date-invalid = Ungültiges Datum: { $date } (Format JJJJ-MM-TT)
 
 # File Area Errors
 err-file-path-too-long = Dateipfad ist zu lang (maximal { $max_length } Zeichen)
@@ -200,6 +246,7 @@ err-transfer-file-failed = Übertragung von { $path } fehlgeschlagen: { $error }
 # Upload Errors
 err-upload-destination-not-allowed = Zielordner erlaubt keine Uploads
 err-upload-write-failed = Datei konnte nicht geschrieben werden
+err-upload-insufficient-storage = Auf dem Server ist nicht genug freier Speicher für diesen Upload
 err-upload-hash-mismatch = Dateiprüfung fehlgeschlagen - Hash stimmt nicht überein
 err-upload-path-invalid = Ungültiger Dateipfad beim Upload
 err-upload-conflict = Ein anderer Upload zu diesem Dateinamen läuft oder wurde unterbrochen. Bitte versuchen Sie einen anderen Dateinamen.
@@ -228,6 +275,47 @@ err-search-query-too-short = Suchanfrage ist zu kurz (mindestens { $min_length }
 err-search-query-too-long = Suchanfrage ist zu lang (maximal { $max_length } Zeichen)
 err-search-query-invalid = Suchanfrage enthält ungültige Zeichen
 err-search-failed = Suche fehlgeschlagen
+
+# Connectivity Check Errors
+err-connectivity-check-not-configured = Verbindungstests sind auf diesem Server nicht eingerichtet (mit --check-service starten)
+err-connectivity-check-failed = Verbindungstest fehlgeschlagen: { $reason }
+
+# Door Errors
+err-door-not-found = Tür '{ $name }' nicht gefunden
+err-door-failed = Tür '{ $name }' konnte nicht gestartet werden
+
+# File Review Errors
+err-file-rating-invalid = Die Bewertung muss zwischen 1 und 5 Sternen liegen
+err-file-review-directory = Nur Dateien können bewertet und kommentiert werden
+err-file-comment-empty = Kommentar darf nicht leer sein
+err-file-comment-too-long = Kommentar ist zu lang (max. { $max_length } Zeichen)
+err-file-comment-invalid-characters = Kommentar enthält ungültige Zeichen
+err-file-comment-not-found = Kommentar #{ $id } nicht gefunden
+
+# Upload-Regel-Fehler
+err-upload-rule-extension = '{ $name }' ist hier kein erlaubter Dateityp (erlaubt: { $extensions })
+err-upload-rule-file-size = '{ $name }' ist größer, als dieser Ordner erlaubt ({ $max_size })
+err-upload-rule-total-size = Upload ist größer, als dieser Ordner erlaubt ({ $max_size })
+err-upload-rule-too-many-extensions = Zu viele Dateitypen (max. { $max_count })
+err-upload-rule-invalid-extension = Ungültiger Dateityp '{ $extension }' (Buchstaben und Ziffern ohne Punkt verwenden)
+err-upload-rule-not-directory = Upload-Regeln können nur für Ordner festgelegt werden
+
+# Verzeichnisgruppen-Fehler
+err-ldap-group-empty = Gruppe darf nicht leer sein
+err-ldap-group-too-long = Gruppe ist zu lang (max. { $max_length } Zeichen)
+err-ldap-group-invalid-characters = Gruppe enthält ungültige Zeichen
+err-ldap-group-not-found = Keine Zuordnung für Gruppe „{ $group }“
+
+# Dateiprüfungsfehler
+err-file-stat-empty = Keine Dateien zum Prüfen
+err-file-stat-too-many = Zu viele Dateien auf einmal zu prüfen (max. { $max_count })
+
+# File Watch Errors
+err-file-watch-too-many = Zu viele beobachtete Ordner (max. { $max_count })
+
+# Presence Errors
+err-presence-too-many = Zu viele beobachtete Benutzer (max. { $max_count })
+
 # Trust System Errors
 err-trust-invalid-target = Ungültiges Ziel (verwenden Sie Nickname, IP-Adresse oder CIDR-Bereich)
 err-trust-invalid-duration = Ungültiges Dauerformat (verwenden Sie 10m, 4h, 7d oder 0 für permanent)
@@ -240,3 +328,14 @@ err-voice-not-joined = Sie sind nicht in einer Voice-Sitzung
 err-voice-not-channel-member = Sie müssen Mitglied von { $channel } sein, um Voice beizutreten
 err-voice-target-not-online = { $nickname } ist nicht online
 err-voice-invalid-target = Ungültiges Voice-Ziel
+err-voice-broadcast-channel-only = Übertragungen sind nur in Kanälen möglich
+err-voice-broadcast-busy = In { $channel } wird bereits gesprochen; eine Übertragung braucht einen leeren Sprachkanal
+err-voice-clip-name-empty = Clip-Name darf nicht leer sein
+err-voice-clip-name-too-long = Clip-Name ist zu lang (maximal { $max_length } Zeichen)
+err-voice-clip-name-invalid = Clip-Name darf nur Buchstaben, Ziffern, - und _ enthalten
+err-voice-clip-too-large = Clip ist zu groß (maximal 256KB)
+err-voice-clip-invalid-format = Clip muss eine Ogg-Vorbis-Audiodatei sein
+err-voice-clip-too-long = Clip ist zu lang (maximal { $max_seconds } Sekunden)
+err-voice-clip-exists = Ein Clip namens '{ $name }' existiert bereits
+err-voice-clip-not-found = Clip '{ $name }' nicht gefunden
+err-voice-clip-library-full = Die Clip-Bibliothek ist voll (maximal { $max_clips } Clips)
@@ -11,6 +11,13 @@ err-nickname-not-online = User '{ $nickname }' is not online
 err-nickname-required = Nickname required for shared accounts
 err-nickname-too-long = Nickname is too long (max { $max_length } characters)
 
+# Nickname Registration Errors
+err-nickname-registered = Nickname is registered to another user
+err-nickname-already-registered = Nickname '{ $nickname }' is already registered
+err-nickname-not-registered = Nickname '{ $nickname }' is not registered
+err-nickname-register-limit = You can register at most { $max } nicknames
+err-nickname-register-shared = Shared accounts cannot register nicknames
+
 # Away Message Errors
 err-status-too-long = Status message is too long (max { $max_length } characters)
 err-status-contains-newlines = Status message cannot contain newlines
@@ -28,6 +35,10 @@ err-guest-disabled = Guest access is not enabled on this server
 err-cannot-rename-guest = The guest account cannot be renamed
 err-cannot-change-guest-password = The guest account password cannot be changed
 err-cannot-delete-guest = The guest account cannot be deleted
+err-guest-invalid-permissions = The guest account cannot have these permissions: { $permissions }
+err-guest-limit = Too many guests are connected from your address (max { $max })
+err-guest-upgrade-disabled = Guests cannot create accounts on this server
+err-guest-upgrade-not-guest = Only guests can register an account
 
 # Avatar Validation Errors
 err-avatar-invalid-format = Invalid avatar format (must be a data URI with base64 encoding)
@@ -36,10 +47,17 @@ err-avatar-unsupported-type = Unsupported avatar type (PNG, WebP, or SVG only)
 err-authentication = Authentication error
 err-invalid-credentials = Invalid username or password
 err-handshake-required = Handshake required
+err-proof-of-work-required = Proof of work required
 err-already-logged-in = Already logged in
 err-handshake-already-completed = Handshake already completed
 err-account-deleted = Your account has been deleted
 err-account-disabled-by-admin = Account disabled by admin
+err-sso-unavailable = Single sign-on is not available on this server right now
+err-sso-failed = Single sign-on failed, please try again
+err-login-token-expired = Your login session has expired, please log in again
+err-sso-username-unavailable = Can't create an account named '{ $username }': the name is taken or not allowed. Ask an admin for help.
+err-ldap-unavailable = The directory server can't be reached right now, please try again later
+err-ldap-no-group = Your directory account isn't in any group with access to this server
 
 # Permission & Access Errors
 err-permission-denied = Permission denied
@@ -68,11 +86,28 @@ err-cannot-kick-admin = Cannot kick admin users
 err-cannot-delete-admin = Only admins can delete admin users
 err-cannot-edit-admin = Only admins can edit admin users
 err-cannot-message-self = You cannot message yourself
+err-message-history-days-too-long = Message history can be kept for at most { $max } days
+err-message-history-shared = Shared accounts cannot keep message history
+err-group-no-members = Name at least one user to talk to
+err-group-too-many-members = A group conversation can have at most { $max } members
+err-group-limit = You can have at most { $max } group conversations you started going at once
+err-group-not-found = Group conversation not found
+err-group-already-member = { $nickname } is already in this conversation
+err-snippet-empty = Snippet cannot be empty
+err-snippet-too-long = Snippet is too long (max { $max_length } bytes)
+err-snippet-invalid-characters = Snippet contains invalid characters
+err-snippet-language-invalid = Invalid snippet language: { $language }
+err-snippet-expiry-invalid = Snippets can be kept for 1 minute to { $max_days } days
+err-snippet-not-found = Snippet not found (it may have expired)
+err-snippet-limit = You can share at most { $max } snippets at a time
 err-cannot-disable-last-admin = Cannot disable the last admin
 
 # Chat Topic Errors
 err-topic-contains-newlines = Topic cannot contain newlines
 err-topic-invalid-characters = Topic contains invalid characters
+err-topic-locked = This channel's topic is locked
+err-entry-message-invalid-characters = Entry message contains invalid characters
+err-rules-invalid-characters = Rules contain invalid characters
 
 # Channel Errors
 err-channel-name-empty = Channel name cannot be empty
@@ -84,6 +119,8 @@ err-channel-not-found = Channel '{ $channel }' not found
 err-channel-already-member = You are already a member of channel '{ $channel }'
 err-channel-limit-exceeded = You cannot join more than { $max } channels
 err-channel-list-invalid = Invalid channel '{ $channel }': { $reason }
+err-channel-language-too-long = Language tag is too long (max { $max_length } characters)
+err-channel-language-invalid = Language tag must be a language code such as "en" or "pt-BR"
 
 # Version Validation Errors
 err-version-empty = Version cannot be empty
@@ -115,6 +152,7 @@ err-permissions-invalid-characters = Permission name contains invalid characters
 err-message-empty = Message cannot be empty
 err-message-contains-newlines = Message cannot contain newlines
 err-message-invalid-characters = Message contains invalid characters
+err-idempotency-key-invalid = Invalid message idempotency key
 
 # Username Validation Errors
 err-username-empty = Username cannot be empty
@@ -124,9 +162,13 @@ err-username-invalid = Username contains invalid characters (letters, numbers, a
 err-unknown-permission = Unknown permission: '{ $permission }'
 
 # Dynamic Error Messages (with parameters)
+err-broadcast-invalid-delay = Invalid delay format (use 10m, 4h, or 7d)
 err-broadcast-too-long = Message too long (max { $max_length } characters)
+err-broadcast-too-many-scheduled = Too many scheduled broadcasts (max { $max_count })
 err-chat-too-long = Message too long (max { $max_length } characters)
 err-topic-too-long = Topic cannot exceed { $max_length } characters
+err-entry-message-too-long = Entry message cannot exceed { $max_length } characters
+err-rules-too-long = Rules cannot exceed { $max_length } characters
 err-version-major-mismatch = Incompatible protocol version: server is version { $server_major }.x, client is version { $client_major }.x
 err-version-client-too-new = Client version { $client_version } is newer than server version { $server_version }. Please update the server or use an older client.
 err-kicked-by = You have been kicked by { $username }
@@ -157,12 +199,16 @@ err-no-fields-to-update = No fields to update
 err-news-not-found = News item #{ $id } not found
 err-news-body-too-long = News body is too long (max { $max_length } characters)
 err-news-body-invalid-characters = News body contains invalid characters
-err-news-image-too-large = News image is too large (max 512KB)
+err-news-image-too-large = News image is too large (max { $max_kb }KB)
 err-news-image-invalid-format = Invalid news image format (must be a data URI with base64 encoding)
 err-news-image-unsupported-type = Unsupported news image type (PNG, WebP, JPEG, or SVG only)
-err-news-empty-content = News must have either text content or an image
+err-news-too-many-attachments = News posts can have at most { $max } attachments
+err-news-caption-too-long = Attachment caption is too long (max { $max_length } characters)
+err-news-caption-invalid-characters = Attachment caption contains invalid characters
+err-news-empty-content = News must have either text content or an attachment
 err-cannot-edit-admin-news = Only admins can edit news posted by admins
 err-cannot-delete-admin-news = Only admins can delete news posted by admins
+err-news-search-date-invalid = Invalid date: { $date } (use YYYY-MM-DD)
 
 # File Area Errors
 err-file-path-too-long = File path is too long (max { $max_length } characters)
@@ -198,6 +244,7 @@ err-transfer-file-failed = Failed to transfer { $path }: { $error }
 # Upload Errors
 err-upload-destination-not-allowed = Destination folder does not allow uploads
 err-upload-write-failed = Failed to write file
+err-upload-insufficient-storage = Not enough free space on the server for this upload
 err-upload-hash-mismatch = File verification failed - hash mismatch
 err-upload-path-invalid = Invalid file path in upload
 err-upload-conflict = Another upload to this filename is in progress or was interrupted. Please try a different filename.
@@ -231,6 +278,46 @@ err-search-query-too-long = Search query is too long (max { $max_length } charac
 err-search-query-invalid = Search query contains invalid characters
 err-search-failed = Search failed
 
+# Connectivity Check Errors
+err-connectivity-check-not-configured = Connectivity checks are not configured on this server (start it with --check-service)
+err-connectivity-check-failed = Connectivity check failed: { $reason }
+
+# Door Errors
+err-door-not-found = Door '{ $name }' not found
+err-door-failed = Door '{ $name }' could not be started
+
+# File Review Errors
+err-file-rating-invalid = Rating must be from 1 to 5 stars
+err-file-review-directory = Only files can be rated and commented on
+err-file-comment-empty = Comment cannot be empty
+err-file-comment-too-long = Comment is too long (max { $max_length } characters)
+err-file-comment-invalid-characters = Comment contains invalid characters
+err-file-comment-not-found = Comment #{ $id } not found
+
+# Upload Rule Errors
+err-upload-rule-extension = '{ $name }' is not an allowed file type here (allowed: { $extensions })
+err-upload-rule-file-size = '{ $name }' is larger than this folder allows ({ $max_size })
+err-upload-rule-total-size = Upload is larger than this folder allows ({ $max_size })
+err-upload-rule-too-many-extensions = Too many file types (max { $max_count })
+err-upload-rule-invalid-extension = Invalid file type '{ $extension }' (use letters and digits, without the dot)
+err-upload-rule-not-directory = Upload rules can only be set on folders
+
+# Directory Group Errors
+err-ldap-group-empty = Group cannot be empty
+err-ldap-group-too-long = Group is too long (max { $max_length } characters)
+err-ldap-group-invalid-characters = Group contains invalid characters
+err-ldap-group-not-found = No mapping for group '{ $group }'
+
+# File Stat Errors
+err-file-stat-empty = No files to check
+err-file-stat-too-many = Too many files to check at once (max { $max_count })
+
+# File Watch Errors
+err-file-watch-too-many = Too many folders to watch (max { $max_count })
+
+# Presence Errors
+err-presence-too-many = Too many users to watch (max { $max_count })
+
 # Voice Errors
 err-voice-listen-required = You need voice_listen permission to join voice
 err-voice-already-joined = You are already in a voice session
@@ -238,3 +325,14 @@ err-voice-not-joined = You are not in a voice session
 err-voice-not-channel-member = You must be a member of { $channel } to join voice
 err-voice-target-not-online = { $nickname } is not online
 err-voice-invalid-target = Invalid voice target
+err-voice-broadcast-channel-only = Broadcasts are only possible in channels
+err-voice-broadcast-busy = Voice in { $channel } is in use; a broadcast needs an empty voice channel
+err-voice-clip-name-empty = Clip name cannot be empty
+err-voice-clip-name-too-long = Clip name is too long (max { $max_length } characters)
+err-voice-clip-name-invalid = Clip name can only contain letters, numbers, - and _
+err-voice-clip-too-large = Clip is too large (max 256KB)
+err-voice-clip-invalid-format = Clip must be an Ogg Vorbis audio file
+err-voice-clip-too-long = Clip is too long (max { $max_seconds } seconds)
+err-voice-clip-exists = A clip named '{ $name }' already exists
+err-voice-clip-not-found = Clip '{ $name }' not found
+err-voice-clip-library-full = The clip library is full (max { $max_clips } clips)
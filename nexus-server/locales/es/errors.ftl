@@ -11,6 +11,13 @@ err-nickname-not-online = El usuario '{ $nickname }' no está en línea
 err-nickname-required = Se requiere apodo para cuentas compartidas
 err-nickname-too-long = El apodo es demasiado largo (máx. { $max_length } caracteres)
 
+# Errores de registro de apodos
+err-nickname-registered = El apodo está registrado a nombre de otro usuario
+err-nickname-already-registered = El apodo '{ $nickname }' ya está registrado
+err-nickname-not-registered = El apodo '{ $nickname }' no está registrado
+err-nickname-register-limit = Puedes registrar como máximo { $max } apodos
+err-nickname-register-shared = Las cuentas compartidas no pueden registrar apodos
+
 # Errores de mensaje de ausencia
 err-status-too-long = El mensaje de ausencia es demasiado largo (máx. { $max_length } caracteres)
 err-status-contains-newlines = El mensaje de ausencia no puede contener saltos de línea
@@ -28,6 +35,10 @@ err-guest-disabled = El acceso de invitado no está habilitado en este servidor
 err-cannot-rename-guest = La cuenta de invitado no puede ser renombrada
 err-cannot-change-guest-password = La contraseña de la cuenta de invitado no puede ser cambiada
 err-cannot-delete-guest = La cuenta de invitado no puede ser eliminada
+err-guest-invalid-permissions = La cuenta de invitado no puede tener estos permisos: { $permissions }
+err-guest-limit = Hay demasiados invitados conectados desde tu dirección (máx. { $max })
+err-guest-upgrade-disabled = Los invitados no pueden crear cuentas en este servidor
+err-guest-upgrade-not-guest = Solo los invitados pueden registrar una cuenta
 
 # Errores de Validación de Avatar
 err-avatar-invalid-format = Formato de avatar no válido (debe ser una URI de datos con codificación base64)
@@ -36,10 +47,17 @@ err-avatar-unsupported-type = Tipo de avatar no compatible (solo PNG, WebP o SVG
 err-authentication = Error de autenticación
 err-invalid-credentials = Usuario o contraseña inválidos
 err-handshake-required = Se requiere handshake
+err-proof-of-work-required = Se requiere prueba de trabajo
 err-already-logged-in = Ya ha iniciado sesión
 err-handshake-already-completed = Handshake ya completado
 err-account-deleted = Su cuenta ha sido eliminada
 err-account-disabled-by-admin = Cuenta deshabilitada por el administrador
+err-sso-unavailable = El inicio de sesión único no está disponible en este servidor ahora mismo
+err-sso-failed = El inicio de sesión único ha fallado, inténtalo de nuevo
+err-login-token-expired = Tu sesión ha caducado, vuelve a iniciar sesión
+err-sso-username-unavailable = No se puede crear una cuenta llamada '{ $username }': el nombre está en uso o no está permitido. Pide ayuda a un administrador.
+err-ldap-unavailable = No se puede contactar con el servidor de directorio ahora mismo, inténtalo más tarde
+err-ldap-no-group = Tu cuenta del directorio no está en ningún grupo con acceso a este servidor
 
 # Permission & Access Errors
 # Errores de permisos y acceso
@@ -59,6 +77,8 @@ err-channel-not-found = Canal '{ $channel }' no encontrado
 err-channel-already-member = Ya eres miembro del canal '{ $channel }'
 err-channel-limit-exceeded = No puedes unirte a más de { $max } canales
 err-channel-list-invalid = Canal inválido '{ $channel }': { $reason }
+err-channel-language-too-long = La etiqueta de idioma es demasiado larga (máximo { $max_length } caracteres)
+err-channel-language-invalid = La etiqueta de idioma debe ser un código de idioma como "en" o "pt-BR"
 
 # Errores de base de datos
 err-database = Error de base de datos
@@ -80,11 +100,28 @@ err-cannot-kick-admin = No se puede expulsar a usuarios administradores
 err-cannot-delete-admin = Solo los administradores pueden eliminar usuarios administradores
 err-cannot-edit-admin = Solo los administradores pueden editar usuarios administradores
 err-cannot-message-self = No puedes enviarte mensajes a ti mismo
+err-message-history-days-too-long = El historial de mensajes se puede conservar como máximo { $max } días
+err-message-history-shared = Las cuentas compartidas no pueden guardar historial de mensajes
+err-group-no-members = Indica al menos un usuario con quien hablar
+err-group-too-many-members = Una conversación de grupo puede tener como máximo { $max } miembros
+err-group-limit = Puedes tener como máximo { $max } conversaciones de grupo iniciadas por ti a la vez
+err-group-not-found = Conversación de grupo no encontrada
+err-group-already-member = { $nickname } ya está en esta conversación
+err-snippet-empty = El fragmento no puede estar vacío
+err-snippet-too-long = El fragmento es demasiado largo (máximo { $max_length } bytes)
+err-snippet-invalid-characters = El fragmento contiene caracteres no válidos
+err-snippet-language-invalid = Lenguaje de fragmento no válido: { $language }
+err-snippet-expiry-invalid = Los fragmentos se pueden guardar de 1 minuto a { $max_days } días
+err-snippet-not-found = Fragmento no encontrado (puede haber caducado)
+err-snippet-limit = Puedes compartir como máximo { $max } fragmentos a la vez
 err-cannot-disable-last-admin = No se puede deshabilitar al último administrador
 
 # Errores de tema de chat
 err-topic-contains-newlines = El tema no puede contener saltos de línea
 err-topic-invalid-characters = El tema contiene caracteres inválidos
+err-topic-locked = El tema de este canal está bloqueado
+err-entry-message-invalid-characters = El mensaje de entrada contiene caracteres inválidos
+err-rules-invalid-characters = Las reglas contienen caracteres inválidos
 
 # Errores de validación de versión
 err-version-empty = La versión no puede estar vacía
@@ -109,6 +146,7 @@ err-features-invalid-characters = El nombre de la característica contiene carac
 err-message-empty = El mensaje no puede estar vacío
 err-message-contains-newlines = El mensaje no puede contener saltos de línea
 err-message-invalid-characters = El mensaje contiene caracteres inválidos
+err-idempotency-key-invalid = Clave de idempotencia del mensaje no válida
 
 # Errores de validación de nombre de usuario
 err-username-empty = El nombre de usuario no puede estar vacío
@@ -118,9 +156,13 @@ err-username-invalid = El nombre de usuario contiene caracteres inválidos (se p
 err-unknown-permission = Permiso desconocido: '{ $permission }'
 
 # Mensajes de error dinámicos (con parámetros)
+err-broadcast-invalid-delay = Formato de retraso no válido (usa 10m, 4h o 7d)
 err-broadcast-too-long = Mensaje demasiado largo (máx. { $max_length } caracteres)
+err-broadcast-too-many-scheduled = Demasiadas difusiones programadas (máx. { $max_count })
 err-chat-too-long = Mensaje demasiado largo (máx. { $max_length } caracteres)
 err-topic-too-long = El tema no puede exceder { $max_length } caracteres
+err-entry-message-too-long = El mensaje de entrada no puede exceder { $max_length } caracteres
+err-rules-too-long = Las reglas no pueden exceder { $max_length } caracteres
 err-version-major-mismatch = Versión de protocolo incompatible: el servidor es versión { $server_major }.x, el cliente es versión { $client_major }.x
 err-version-client-too-new = La versión del cliente { $client_version } es más nueva que la versión del servidor { $server_version }. Por favor actualice el servidor o use un cliente más antiguo.
 err-kicked-by = Has sido expulsado por { $username }
@@ -159,12 +201,16 @@ err-server-image-unsupported-type = Tipo de imagen del servidor no compatible (s
 err-news-not-found = Noticia #{ $id } no encontrada
 err-news-body-too-long = El contenido de la noticia es demasiado largo (máx. { $max_length } caracteres)
 err-news-body-invalid-characters = El contenido de la noticia contiene caracteres inválidos
-err-news-image-too-large = La imagen de la noticia es demasiado grande (máx. 512KB)
+err-news-image-too-large = La imagen de la noticia es demasiado grande (máx. { $max_kb }KB)
 err-news-image-invalid-format = Formato de imagen de noticia inválido (debe ser una URI de datos con codificación base64)
 err-news-image-unsupported-type = Tipo de imagen de noticia no compatible (solo PNG, WebP, JPEG o SVG)
-err-news-empty-content = La noticia debe tener contenido de texto o una imagen
+err-news-too-many-attachments = Las noticias pueden tener como máximo { $max } adjuntos
+err-news-caption-too-long = El pie del adjunto es demasiado largo (máx. { $max_length } caracteres)
+err-news-caption-invalid-characters = El pie del adjunto contiene caracteres no válidos
+err-news-empty-content = La noticia debe tener contenido de texto o un adjunto
 err-cannot-edit-admin-news = Solo los administradores pueden editar noticias publicadas por administradores
 err-cannot-delete-admin-news = Solo los administradores pueden eliminar noticias publicadas por administradores
+err-news-search-date-invalid = Fecha no válida: { $date } (usa AAAA-MM-DD)
 
 # File Area Errors
 err-file-path-too-long = La ruta del archivo es demasiado larga (máximo { $max_length } caracteres)
@@ -201,6 +247,7 @@ err-transfer-file-failed = Error al transferir { $path }: { $error }
 # Upload Errors
 err-upload-destination-not-allowed = La carpeta de destino no permite subidas
 err-upload-write-failed = Error al escribir el archivo
+err-upload-insufficient-storage = No hay suficiente espacio libre en el servidor para esta subida
 err-upload-hash-mismatch = Verificación del archivo fallida - hash no coincide
 err-upload-path-invalid = Ruta de archivo inválida en la subida
 err-upload-conflict = Otra subida a este nombre de archivo está en progreso o fue interrumpida. Por favor, intente con un nombre de archivo diferente.
@@ -229,6 +276,47 @@ err-search-query-too-short = La búsqueda es muy corta (mínimo { $min_length }
 err-search-query-too-long = La búsqueda es muy larga (máximo { $max_length } caracteres)
 err-search-query-invalid = La búsqueda contiene caracteres inválidos
 err-search-failed = La búsqueda falló
+
+# Connectivity Check Errors
+err-connectivity-check-not-configured = Las pruebas de conectividad no están configuradas en este servidor (inícielo con --check-service)
+err-connectivity-check-failed = La prueba de conectividad falló: { $reason }
+
+# Door Errors
+err-door-not-found = No se encontró la puerta '{ $name }'
+err-door-failed = No se pudo iniciar la puerta '{ $name }'
+
+# File Review Errors
+err-file-rating-invalid = La valoración debe ser de 1 a 5 estrellas
+err-file-review-directory = Solo se pueden valorar y comentar archivos
+err-file-comment-empty = El comentario no puede estar vacío
+err-file-comment-too-long = El comentario es demasiado largo (máx. { $max_length } caracteres)
+err-file-comment-invalid-characters = El comentario contiene caracteres no válidos
+err-file-comment-not-found = No se encontró el comentario #{ $id }
+
+# Errores de reglas de subida
+err-upload-rule-extension = '{ $name }' no es un tipo de archivo permitido aquí (permitidos: { $extensions })
+err-upload-rule-file-size = '{ $name }' supera el tamaño que permite esta carpeta ({ $max_size })
+err-upload-rule-total-size = La subida supera el tamaño que permite esta carpeta ({ $max_size })
+err-upload-rule-too-many-extensions = Demasiados tipos de archivo (máx. { $max_count })
+err-upload-rule-invalid-extension = Tipo de archivo no válido '{ $extension }' (usa letras y dígitos, sin el punto)
+err-upload-rule-not-directory = Las reglas de subida solo se pueden establecer en carpetas
+
+# Errores de grupos del directorio
+err-ldap-group-empty = El grupo no puede estar vacío
+err-ldap-group-too-long = El grupo es demasiado largo (máx. { $max_length } caracteres)
+err-ldap-group-invalid-characters = El grupo contiene caracteres no válidos
+err-ldap-group-not-found = No hay ninguna asignación para el grupo '{ $group }'
+
+# Errores de comprobación de archivos
+err-file-stat-empty = No hay archivos que comprobar
+err-file-stat-too-many = Demasiados archivos para comprobar a la vez (máx. { $max_count })
+
+# File Watch Errors
+err-file-watch-too-many = Demasiadas carpetas para vigilar (máx. { $max_count })
+
+# Presence Errors
+err-presence-too-many = Demasiados usuarios para seguir (máx. { $max_count })
+
 # Trust System Errors
 err-trust-invalid-target = Objetivo inválido (use apodo, dirección IP o rango CIDR)
 err-trust-invalid-duration = Formato de duración inválido (use 10m, 4h, 7d, o 0 para permanente)
@@ -241,3 +329,14 @@ err-voice-not-joined = No estás en una sesión de voz
 err-voice-not-channel-member = Debes ser miembro de { $channel } para unirte a voz
 err-voice-target-not-online = { $nickname } no está conectado
 err-voice-invalid-target = Destino de voz inválido
+err-voice-broadcast-channel-only = Las emisiones solo son posibles en canales
+err-voice-broadcast-busy = La voz de { $channel } está en uso; una emisión necesita un canal de voz vacío
+err-voice-clip-name-empty = El nombre del clip no puede estar vacío
+err-voice-clip-name-too-long = El nombre del clip es demasiado largo (máx. { $max_length } caracteres)
+err-voice-clip-name-invalid = El nombre del clip solo puede contener letras, números, - y _
+err-voice-clip-too-large = El clip es demasiado grande (máx. 256KB)
+err-voice-clip-invalid-format = El clip debe ser un archivo de audio Ogg Vorbis
+err-voice-clip-too-long = El clip es demasiado largo (máx. { $max_seconds } segundos)
+err-voice-clip-exists = Ya existe un clip llamado '{ $name }'
+err-voice-clip-not-found = Clip '{ $name }' no encontrado
+err-voice-clip-library-full = La biblioteca de clips está llena (máx. { $max_clips } clips)
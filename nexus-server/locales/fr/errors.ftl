@@ -11,6 +11,13 @@ err-nickname-not-online = L'utilisateur « { $nickname } » n'est pas en ligne
 err-nickname-required = Pseudonyme requis pour les comptes partagés
 err-nickname-too-long = Le pseudonyme est trop long (max. { $max_length } caractères)
 
+# Erreurs d'enregistrement de pseudonyme
+err-nickname-registered = Ce pseudonyme est enregistré par un autre utilisateur
+err-nickname-already-registered = Le pseudonyme « { $nickname } » est déjà enregistré
+err-nickname-not-registered = Le pseudonyme « { $nickname } » n'est pas enregistré
+err-nickname-register-limit = Vous pouvez enregistrer au plus { $max } pseudonymes
+err-nickname-register-shared = Les comptes partagés ne peuvent pas enregistrer de pseudonymes
+
 # Erreurs de message d'absence
 err-status-too-long = Le message d'absence est trop long (max. { $max_length } caractères)
 err-status-contains-newlines = Le message d'absence ne peut pas contenir de sauts de ligne
@@ -28,6 +35,10 @@ err-guest-disabled = L'accès invité n'est pas activé sur ce serveur
 err-cannot-rename-guest = Le compte invité ne peut pas être renommé
 err-cannot-change-guest-password = Le mot de passe du compte invité ne peut pas être modifié
 err-cannot-delete-guest = Le compte invité ne peut pas être supprimé
+err-guest-invalid-permissions = Le compte invité ne peut pas avoir ces permissions : { $permissions }
+err-guest-limit = Trop d'invités sont connectés depuis votre adresse (max { $max })
+err-guest-upgrade-disabled = Les invités ne peuvent pas créer de compte sur ce serveur
+err-guest-upgrade-not-guest = Seuls les invités peuvent enregistrer un compte
 
 # Erreurs de Validation d'Avatar
 err-avatar-invalid-format = Format d'avatar invalide (doit être une URI de données avec encodage base64)
@@ -36,10 +47,17 @@ err-avatar-unsupported-type = Type d'avatar non pris en charge (PNG, WebP ou SVG
 err-authentication = Erreur d'authentification
 err-invalid-credentials = Nom d'utilisateur ou mot de passe invalide
 err-handshake-required = Handshake requis
+err-proof-of-work-required = Preuve de travail requise
 err-already-logged-in = Déjà connecté
 err-handshake-already-completed = Handshake déjà effectué
 err-account-deleted = Votre compte a été supprimé
 err-account-disabled-by-admin = Compte désactivé par l'administrateur
+err-sso-unavailable = L'authentification unique n'est pas disponible sur ce serveur pour le moment
+err-sso-failed = L'authentification unique a échoué, veuillez réessayer
+err-login-token-expired = Votre session a expiré, veuillez vous reconnecter
+err-sso-username-unavailable = Impossible de créer un compte nommé « { $username } » : le nom est déjà pris ou n'est pas autorisé. Demandez de l'aide à un administrateur.
+err-ldap-unavailable = Le serveur d'annuaire est injoignable pour le moment, veuillez réessayer plus tard
+err-ldap-no-group = Votre compte d'annuaire n'appartient à aucun groupe ayant accès à ce serveur
 
 # Erreurs de permission et d'accès
 err-permission-denied = Permission refusée
@@ -58,6 +76,8 @@ err-channel-not-found = Canal '{ $channel }' non trouvé
 err-channel-already-member = Vous êtes déjà membre du canal '{ $channel }'
 err-channel-limit-exceeded = Vous ne pouvez pas rejoindre plus de { $max } canaux
 err-channel-list-invalid = Canal invalide '{ $channel }' : { $reason }
+err-channel-language-too-long = L'étiquette de langue est trop longue (max { $max_length } caractères)
+err-channel-language-invalid = L'étiquette de langue doit être un code de langue comme "en" ou "pt-BR"
 
 # Erreurs de base de données
 err-database = Erreur de base de données
@@ -79,11 +99,28 @@ err-cannot-kick-admin = Impossible d'expulser les utilisateurs administrateurs
 err-cannot-delete-admin = Seuls les administrateurs peuvent supprimer des utilisateurs administrateurs
 err-cannot-edit-admin = Seuls les administrateurs peuvent modifier des utilisateurs administrateurs
 err-cannot-message-self = Vous ne pouvez pas vous envoyer de message
+err-message-history-days-too-long = L'historique des messages peut être conservé au plus { $max } jours
+err-message-history-shared = Les comptes partagés ne peuvent pas conserver d'historique des messages
+err-group-no-members = Indiquez au moins un utilisateur avec qui parler
+err-group-too-many-members = Une conversation de groupe peut avoir au plus { $max } membres
+err-group-limit = Vous pouvez avoir au plus { $max } conversations de groupe lancées par vous en même temps
+err-group-not-found = Conversation de groupe introuvable
+err-group-already-member = { $nickname } est déjà dans cette conversation
+err-snippet-empty = L'extrait ne peut pas être vide
+err-snippet-too-long = L'extrait est trop long (max { $max_length } octets)
+err-snippet-invalid-characters = L'extrait contient des caractères non valides
+err-snippet-language-invalid = Langage d'extrait non valide : { $language }
+err-snippet-expiry-invalid = Les extraits peuvent être conservés de 1 minute à { $max_days } jours
+err-snippet-not-found = Extrait introuvable (il a peut-être expiré)
+err-snippet-limit = Vous pouvez partager au plus { $max } extraits à la fois
 err-cannot-disable-last-admin = Impossible de désactiver le dernier administrateur
 
 # Erreurs de sujet de discussion
 err-topic-contains-newlines = Le sujet ne peut pas contenir de sauts de ligne
 err-topic-invalid-characters = Le sujet contient des caractères invalides
+err-topic-locked = Le sujet de ce canal est verrouillé
+err-entry-message-invalid-characters = Le message d'entrée contient des caractères invalides
+err-rules-invalid-characters = Les règles contiennent des caractères invalides
 
 # Erreurs de validation de version
 err-version-empty = La version ne peut pas être vide
@@ -108,6 +145,7 @@ err-features-invalid-characters = Le nom de la fonctionnalité contient des cara
 err-message-empty = Le message ne peut pas être vide
 err-message-contains-newlines = Le message ne peut pas contenir de sauts de ligne
 err-message-invalid-characters = Le message contient des caractères invalides
+err-idempotency-key-invalid = Clé d'idempotence du message non valide
 
 # Erreurs de validation du nom d'utilisateur
 err-username-empty = Le nom d'utilisateur ne peut pas être vide
@@ -117,9 +155,13 @@ err-username-invalid = Le nom d'utilisateur contient des caractères invalides (
 err-unknown-permission = Permission inconnue : '{ $permission }'
 
 # Messages d'erreur dynamiques (avec paramètres)
+err-broadcast-invalid-delay = Format de délai invalide (utilisez 10m, 4h ou 7d)
 err-broadcast-too-long = Message trop long (maximum { $max_length } caractères)
+err-broadcast-too-many-scheduled = Trop de diffusions planifiées (maximum { $max_count })
 err-chat-too-long = Message trop long (maximum { $max_length } caractères)
 err-topic-too-long = Le sujet ne peut pas dépasser { $max_length } caractères
+err-entry-message-too-long = Le message d'entrée ne peut pas dépasser { $max_length } caractères
+err-rules-too-long = Les règles ne peuvent pas dépasser { $max_length } caractères
 err-version-major-mismatch = Version de protocole incompatible : le serveur est en version { $server_major }.x, le client est en version { $client_major }.x
 err-version-client-too-new = La version du client { $client_version } est plus récente que la version du serveur { $server_version }. Veuillez mettre à jour le serveur ou utiliser un client plus ancien.
 err-kicked-by = Vous avez été expulsé par { $username }
@@ -158,12 +200,16 @@ err-server-image-unsupported-type = Type d'image du serveur non pris en charge (
 err-news-not-found = Article #{ $id } introuvable
 err-news-body-too-long = Le contenu de l'article est trop long (maximum { $max_length } caractères)
 err-news-body-invalid-characters = Le contenu de l'article contient des caractères invalides
-err-news-image-too-large = L'image de l'article est trop grande (maximum 512 Ko)
+err-news-image-too-large = L'image de l'article est trop grande (maximum { $max_kb } Ko)
 err-news-image-invalid-format = Format d'image de l'article invalide (doit être une URI de données avec encodage base64)
 err-news-image-unsupported-type = Type d'image de l'article non pris en charge (PNG, WebP, JPEG ou SVG uniquement)
-err-news-empty-content = La news doit avoir du contenu texte ou une image
+err-news-too-many-attachments = Une news peut avoir au plus { $max } pièces jointes
+err-news-caption-too-long = La légende de la pièce jointe est trop longue (max { $max_length } caractères)
+err-news-caption-invalid-characters = La légende de la pièce jointe contient des caractères invalides
+err-news-empty-content = La news doit avoir du contenu texte ou une pièce jointe
 err-cannot-edit-admin-news = Seuls les administrateurs peuvent modifier les news publiées par des administrateurs
 err-cannot-delete-admin-news = Seuls les administrateurs peuvent supprimer les news publiées par des administrateurs
+err-news-search-date-invalid = Date invalide : { $date } (format AAAA-MM-JJ)
 
 # File Area Errors
 err-file-path-too-long = Le chemin du fichier est trop long (maximum { $max_length } caractères)
@@ -200,6 +246,7 @@ err-transfer-file-failed = Échec du transfert de { $path } : { $error }
 # Upload Errors
 err-upload-destination-not-allowed = Le dossier de destination n'autorise pas les téléversements
 err-upload-write-failed = Échec de l'écriture du fichier
+err-upload-insufficient-storage = Pas assez d'espace libre sur le serveur pour cet envoi
 err-upload-hash-mismatch = Vérification du fichier échouée - hachage non concordant
 err-upload-path-invalid = Chemin de fichier invalide dans le téléversement
 err-upload-conflict = Un autre téléversement vers ce nom de fichier est en cours ou a été interrompu. Veuillez essayer un autre nom de fichier.
@@ -228,6 +275,47 @@ err-search-query-too-short = La requête de recherche est trop courte (min { $mi
 err-search-query-too-long = La requête de recherche est trop longue (max { $max_length } caractères)
 err-search-query-invalid = La requête de recherche contient des caractères invalides
 err-search-failed = La recherche a échoué
+
+# Connectivity Check Errors
+err-connectivity-check-not-configured = Les tests de connectivité ne sont pas configurés sur ce serveur (démarrez-le avec --check-service)
+err-connectivity-check-failed = Le test de connectivité a échoué : { $reason }
+
+# Door Errors
+err-door-not-found = Porte '{ $name }' introuvable
+err-door-failed = Impossible de lancer la porte '{ $name }'
+
+# File Review Errors
+err-file-rating-invalid = La note doit être comprise entre 1 et 5 étoiles
+err-file-review-directory = Seuls les fichiers peuvent être notés et commentés
+err-file-comment-empty = Le commentaire ne peut pas être vide
+err-file-comment-too-long = Le commentaire est trop long (max { $max_length } caractères)
+err-file-comment-invalid-characters = Le commentaire contient des caractères invalides
+err-file-comment-not-found = Commentaire #{ $id } introuvable
+
+# Erreurs de règles d'envoi
+err-upload-rule-extension = '{ $name }' n'est pas un type de fichier autorisé ici (autorisés : { $extensions })
+err-upload-rule-file-size = '{ $name }' dépasse la taille autorisée par ce dossier ({ $max_size })
+err-upload-rule-total-size = L'envoi dépasse la taille autorisée par ce dossier ({ $max_size })
+err-upload-rule-too-many-extensions = Trop de types de fichiers (max { $max_count })
+err-upload-rule-invalid-extension = Type de fichier invalide '{ $extension }' (lettres et chiffres, sans le point)
+err-upload-rule-not-directory = Les règles d'envoi ne peuvent être définies que sur des dossiers
+
+# Erreurs de groupes d'annuaire
+err-ldap-group-empty = Le groupe ne peut pas être vide
+err-ldap-group-too-long = Le groupe est trop long (max { $max_length } caractères)
+err-ldap-group-invalid-characters = Le groupe contient des caractères invalides
+err-ldap-group-not-found = Aucune correspondance pour le groupe '{ $group }'
+
+# Erreurs de vérification de fichiers
+err-file-stat-empty = Aucun fichier à vérifier
+err-file-stat-too-many = Trop de fichiers à vérifier en une fois (max { $max_count })
+
+# File Watch Errors
+err-file-watch-too-many = Trop de dossiers à surveiller (max { $max_count })
+
+# Presence Errors
+err-presence-too-many = Trop d'utilisateurs à suivre (max { $max_count })
+
 # Trust System Errors
 err-trust-invalid-target = Cible invalide (utilisez un pseudo, une adresse IP ou une plage CIDR)
 err-trust-invalid-duration = Format de durée invalide (utilisez 10m, 4h, 7d, ou 0 pour permanent)
@@ -240,3 +328,14 @@ err-voice-not-joined = Vous n'êtes pas dans une session vocale
 err-voice-not-channel-member = Vous devez être membre de { $channel } pour rejoindre le vocal
 err-voice-target-not-online = { $nickname } n'est pas en ligne
 err-voice-invalid-target = Cible vocale invalide
+err-voice-broadcast-channel-only = Les diffusions ne sont possibles que dans les salons
+err-voice-broadcast-busy = La voix de { $channel } est occupée ; une diffusion nécessite un salon vocal vide
+err-voice-clip-name-empty = Le nom du clip ne peut pas être vide
+err-voice-clip-name-too-long = Le nom du clip est trop long (maximum { $max_length } caractères)
+err-voice-clip-name-invalid = Le nom du clip ne peut contenir que des lettres, des chiffres, - et _
+err-voice-clip-too-large = Le clip est trop volumineux (maximum 256 Ko)
+err-voice-clip-invalid-format = Le clip doit être un fichier audio Ogg Vorbis
+err-voice-clip-too-long = Le clip est trop long (maximum { $max_seconds } secondes)
+err-voice-clip-exists = Un clip nommé '{ $name }' existe déjà
+err-voice-clip-not-found = Clip '{ $name }' introuvable
+err-voice-clip-library-full = La bibliothèque de clips est pleine (maximum { $max_clips } clips)
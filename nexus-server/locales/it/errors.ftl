@@ -11,6 +11,13 @@ err-nickname-not-online = L'utente "{ $nickname }" non è online
 err-nickname-required = Soprannome richiesto per account condivisi
 err-nickname-too-long = Il soprannome è troppo lungo (max { $max_length } caratteri)
 
+# Errori di registrazione dei soprannomi
+err-nickname-registered = Il soprannome è registrato da un altro utente
+err-nickname-already-registered = Il soprannome "{ $nickname }" è già registrato
+err-nickname-not-registered = Il soprannome "{ $nickname }" non è registrato
+err-nickname-register-limit = Puoi registrare al massimo { $max } soprannomi
+err-nickname-register-shared = Gli account condivisi non possono registrare soprannomi
+
 # Errori messaggio di assenza
 err-status-too-long = Il messaggio di assenza è troppo lungo (max { $max_length } caratteri)
 err-status-contains-newlines = Il messaggio di assenza non può contenere interruzioni di riga
@@ -28,6 +35,10 @@ err-guest-disabled = L'accesso ospite non è abilitato su questo server
 err-cannot-rename-guest = L'account ospite non può essere rinominato
 err-cannot-change-guest-password = La password dell'account ospite non può essere modificata
 err-cannot-delete-guest = L'account ospite non può essere eliminato
+err-guest-invalid-permissions = L'account ospite non può avere questi permessi: { $permissions }
+err-guest-limit = Troppi ospiti sono connessi dal tuo indirizzo (max { $max })
+err-guest-upgrade-disabled = Gli ospiti non possono creare account su questo server
+err-guest-upgrade-not-guest = Solo gli ospiti possono registrare un account
 
 # Errori di Validazione Avatar
 err-avatar-invalid-format = Formato avatar non valido (deve essere un URI di dati con codifica base64)
@@ -36,10 +47,17 @@ err-avatar-unsupported-type = Tipo di avatar non supportato (solo PNG, WebP o SV
 err-authentication = Errore di autenticazione
 err-invalid-credentials = Nome utente o password non validi
 err-handshake-required = Handshake richiesto
+err-proof-of-work-required = Prova di lavoro richiesta
 err-already-logged-in = Già connesso
 err-handshake-already-completed = Handshake già completato
 err-account-deleted = Il tuo account è stato eliminato
 err-account-disabled-by-admin = Account disabilitato dall'amministratore
+err-sso-unavailable = Il single sign-on non è al momento disponibile su questo server
+err-sso-failed = Single sign-on non riuscito, riprova
+err-login-token-expired = La sessione di accesso è scaduta, accedi di nuovo
+err-sso-username-unavailable = Impossibile creare un account chiamato "{ $username }": il nome è già in uso o non è consentito. Chiedi aiuto a un amministratore.
+err-ldap-unavailable = Il server di directory non è raggiungibile al momento, riprova più tardi
+err-ldap-no-group = Il tuo account della directory non è in nessun gruppo con accesso a questo server
 
 # Errori di permesso e accesso
 err-permission-denied = Permesso negato
@@ -58,6 +76,8 @@ err-channel-not-found = Canale '{ $channel }' non trovato
 err-channel-already-member = Sei già membro del canale '{ $channel }'
 err-channel-limit-exceeded = Non puoi unirti a più di { $max } canali
 err-channel-list-invalid = Canale non valido '{ $channel }': { $reason }
+err-channel-language-too-long = Il tag della lingua è troppo lungo (max { $max_length } caratteri)
+err-channel-language-invalid = Il tag della lingua deve essere un codice lingua come "en" o "pt-BR"
 
 # Errori del database
 err-database = Errore del database
@@ -79,11 +99,28 @@ err-cannot-kick-admin = Impossibile espellere utenti amministratori
 err-cannot-delete-admin = Solo gli amministratori possono eliminare utenti amministratori
 err-cannot-edit-admin = Solo gli amministratori possono modificare utenti amministratori
 err-cannot-message-self = Non puoi inviare messaggi a te stesso
+err-message-history-days-too-long = La cronologia dei messaggi può essere conservata al massimo { $max } giorni
+err-message-history-shared = Gli account condivisi non possono conservare la cronologia dei messaggi
+err-group-no-members = Indica almeno un utente con cui parlare
+err-group-too-many-members = Una conversazione di gruppo può avere al massimo { $max } membri
+err-group-limit = Puoi avere al massimo { $max } conversazioni di gruppo avviate da te contemporaneamente
+err-group-not-found = Conversazione di gruppo non trovata
+err-group-already-member = { $nickname } è già in questa conversazione
+err-snippet-empty = Lo snippet non può essere vuoto
+err-snippet-too-long = Lo snippet è troppo lungo (max { $max_length } byte)
+err-snippet-invalid-characters = Lo snippet contiene caratteri non validi
+err-snippet-language-invalid = Linguaggio dello snippet non valido: { $language }
+err-snippet-expiry-invalid = Gli snippet possono essere conservati da 1 minuto a { $max_days } giorni
+err-snippet-not-found = Snippet non trovato (potrebbe essere scaduto)
+err-snippet-limit = Puoi condividere al massimo { $max } snippet alla volta
 err-cannot-disable-last-admin = Impossibile disabilitare l'ultimo amministratore
 
 # Errori argomento chat
 err-topic-contains-newlines = L'argomento non può contenere interruzioni di riga
 err-topic-invalid-characters = L'argomento contiene caratteri non validi
+err-topic-locked = L'argomento di questo canale è bloccato
+err-entry-message-invalid-characters = Il messaggio di ingresso contiene caratteri non validi
+err-rules-invalid-characters = Le regole contengono caratteri non validi
 
 # Errori di validazione versione
 err-version-empty = La versione non può essere vuota
@@ -108,6 +145,7 @@ err-features-invalid-characters = Il nome della funzionalità contiene caratteri
 err-message-empty = Il messaggio non può essere vuoto
 err-message-contains-newlines = Il messaggio non può contenere interruzioni di riga
 err-message-invalid-characters = Il messaggio contiene caratteri non validi
+err-idempotency-key-invalid = Chiave di idempotenza del messaggio non valida
 
 # Errori di validazione nome utente
 err-username-empty = Il nome utente non può essere vuoto
@@ -117,9 +155,13 @@ err-username-invalid = Il nome utente contiene caratteri non validi (lettere, nu
 err-unknown-permission = Permesso sconosciuto: '{ $permission }'
 
 # Messaggi di errore dinamici (con parametri)
+err-broadcast-invalid-delay = Formato del ritardo non valido (usa 10m, 4h o 7d)
 err-broadcast-too-long = Messaggio troppo lungo (massimo { $max_length } caratteri)
+err-broadcast-too-many-scheduled = Troppe trasmissioni programmate (massimo { $max_count })
 err-chat-too-long = Messaggio troppo lungo (massimo { $max_length } caratteri)
 err-topic-too-long = L'argomento non può superare { $max_length } caratteri
+err-entry-message-too-long = Il messaggio di ingresso non può superare { $max_length } caratteri
+err-rules-too-long = Le regole non possono superare { $max_length } caratteri
 err-version-major-mismatch = Versione del protocollo incompatibile: il server è versione { $server_major }.x, il client è versione { $client_major }.x
 err-version-client-too-new = La versione del client { $client_version } è più recente della versione del server { $server_version }. Aggiorna il server o usa un client più vecchio.
 err-kicked-by = Sei stato espulso da { $username }
@@ -158,12 +200,16 @@ err-server-image-unsupported-type = Tipo di immagine del server non supportato (
 err-news-not-found = Notizia #{ $id } non trovata
 err-news-body-too-long = Il testo della notizia è troppo lungo (massimo { $max_length } caratteri)
 err-news-body-invalid-characters = Il testo della notizia contiene caratteri non validi
-err-news-image-too-large = L'immagine della notizia è troppo grande (massimo 512KB)
+err-news-image-too-large = L'immagine della notizia è troppo grande (massimo { $max_kb }KB)
 err-news-image-invalid-format = Formato immagine della notizia non valido (deve essere un URI di dati con codifica base64)
 err-news-image-unsupported-type = Tipo di immagine della notizia non supportato (solo PNG, WebP, JPEG o SVG)
-err-news-empty-content = La notizia deve avere contenuto testuale o un'immagine
+err-news-too-many-attachments = Le notizie possono avere al massimo { $max } allegati
+err-news-caption-too-long = La didascalia dell'allegato è troppo lunga (max { $max_length } caratteri)
+err-news-caption-invalid-characters = La didascalia dell'allegato contiene caratteri non validi
+err-news-empty-content = La notizia deve avere contenuto testuale o un allegato
 err-cannot-edit-admin-news = Solo gli amministratori possono modificare le notizie pubblicate dagli amministratori
 err-cannot-delete-admin-news = Solo gli amministratori possono eliminare le notizie pubblicate dagli amministratori
+err-news-search-date-invalid = Data non valida: { $date } (usa AAAA-MM-GG)
 
 # File Area Errors
 err-file-path-too-long = Il percorso del file è troppo lungo (massimo { $max_length } caratteri)
@@ -200,6 +246,7 @@ err-transfer-file-failed = Impossibile trasferire { $path }: { $error }
 # Upload Errors
 err-upload-destination-not-allowed = La cartella di destinazione non consente caricamenti
 err-upload-write-failed = Impossibile scrivere il file
+err-upload-insufficient-storage = Spazio libero insufficiente sul server per questo caricamento
 err-upload-hash-mismatch = Verifica del file fallita - hash non corrispondente
 err-upload-path-invalid = Percorso file non valido nel caricamento
 err-upload-conflict = Un altro caricamento con questo nome file è in corso o è stato interrotto. Prova con un nome file diverso.
@@ -228,6 +275,47 @@ err-search-query-too-short = La query di ricerca è troppo corta (min { $min_len
 err-search-query-too-long = La query di ricerca è troppo lunga (max { $max_length } caratteri)
 err-search-query-invalid = La query di ricerca contiene caratteri non validi
 err-search-failed = Ricerca fallita
+
+# Connectivity Check Errors
+err-connectivity-check-not-configured = I test di connettività non sono configurati su questo server (avvialo con --check-service)
+err-connectivity-check-failed = Test di connettività fallito: { $reason }
+
+# Door Errors
+err-door-not-found = Porta '{ $name }' non trovata
+err-door-failed = Impossibile avviare la porta '{ $name }'
+
+# File Review Errors
+err-file-rating-invalid = La valutazione deve essere da 1 a 5 stelle
+err-file-review-directory = Solo i file possono essere valutati e commentati
+err-file-comment-empty = Il commento non può essere vuoto
+err-file-comment-too-long = Il commento è troppo lungo (max { $max_length } caratteri)
+err-file-comment-invalid-characters = Il commento contiene caratteri non validi
+err-file-comment-not-found = Commento #{ $id } non trovato
+
+# Errori delle regole di caricamento
+err-upload-rule-extension = '{ $name }' non è un tipo di file consentito qui (consentiti: { $extensions })
+err-upload-rule-file-size = '{ $name }' supera la dimensione consentita da questa cartella ({ $max_size })
+err-upload-rule-total-size = Il caricamento supera la dimensione consentita da questa cartella ({ $max_size })
+err-upload-rule-too-many-extensions = Troppi tipi di file (max { $max_count })
+err-upload-rule-invalid-extension = Tipo di file non valido '{ $extension }' (usa lettere e cifre, senza il punto)
+err-upload-rule-not-directory = Le regole di caricamento possono essere impostate solo sulle cartelle
+
+# Errori dei gruppi della directory
+err-ldap-group-empty = Il gruppo non può essere vuoto
+err-ldap-group-too-long = Il gruppo è troppo lungo (max { $max_length } caratteri)
+err-ldap-group-invalid-characters = Il gruppo contiene caratteri non validi
+err-ldap-group-not-found = Nessuna associazione per il gruppo '{ $group }'
+
+# Errori di controllo dei file
+err-file-stat-empty = Nessun file da controllare
+err-file-stat-too-many = Troppi file da controllare in una volta (max { $max_count })
+
+# File Watch Errors
+err-file-watch-too-many = Troppe cartelle da monitorare (max { $max_count })
+
+# Presence Errors
+err-presence-too-many = Troppi utenti da seguire (max { $max_count })
+
 # Trust System Errors
 err-trust-invalid-target = Destinazione non valida (usa nickname, indirizzo IP o intervallo CIDR)
 err-trust-invalid-duration = Formato durata non valido (usa 10m, 4h, 7d, o 0 per permanente)
@@ -240,3 +328,14 @@ err-voice-not-joined = Non sei in una sessione vocale
 err-voice-not-channel-member = Devi essere membro di { $channel } per unirti alla voce
 err-voice-target-not-online = { $nickname } non è online
 err-voice-invalid-target = Destinazione vocale non valida
+err-voice-broadcast-channel-only = Le trasmissioni sono possibili solo nei canali
+err-voice-broadcast-busy = La voce di { $channel } è in uso; una trasmissione richiede un canale vocale vuoto
+err-voice-clip-name-empty = Il nome della clip non può essere vuoto
+err-voice-clip-name-too-long = Il nome della clip è troppo lungo (massimo { $max_length } caratteri)
+err-voice-clip-name-invalid = Il nome della clip può contenere solo lettere, numeri, - e _
+err-voice-clip-too-large = La clip è troppo grande (massimo 256KB)
+err-voice-clip-invalid-format = La clip deve essere un file audio Ogg Vorbis
+err-voice-clip-too-long = La clip è troppo lunga (massimo { $max_seconds } secondi)
+err-voice-clip-exists = Esiste già una clip chiamata '{ $name }'
+err-voice-clip-not-found = Clip '{ $name }' non trovata
+err-voice-clip-library-full = La libreria delle clip è piena (massimo { $max_clips } clip)
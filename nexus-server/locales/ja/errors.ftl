@@ -11,6 +11,13 @@ err-nickname-not-online = ユーザー「{ $nickname }」はオンラインで
 err-nickname-required = 共有アカウントにはニックネームが必要です
 err-nickname-too-long = ニックネームが長すぎます（最大{ $max_length }文字）
 
+# ニックネーム登録エラー
+err-nickname-registered = このニックネームは別のユーザーが登録しています
+err-nickname-already-registered = ニックネーム「{ $nickname }」はすでに登録されています
+err-nickname-not-registered = ニックネーム「{ $nickname }」は登録されていません
+err-nickname-register-limit = 登録できるニックネームは最大{ $max }個です
+err-nickname-register-shared = 共有アカウントはニックネームを登録できません
+
 # 離席メッセージエラー
 err-status-too-long = 離席メッセージが長すぎます（最大{ $max_length }文字）
 err-status-contains-newlines = 離席メッセージに改行を含めることはできません
@@ -28,6 +35,10 @@ err-guest-disabled = このサーバーではゲストアクセスが有効に
 err-cannot-rename-guest = ゲストアカウントの名前は変更できません
 err-cannot-change-guest-password = ゲストアカウントのパスワードは変更できません
 err-cannot-delete-guest = ゲストアカウントは削除できません
+err-guest-invalid-permissions = ゲストアカウントはこれらの権限を持つことができません: { $permissions }
+err-guest-limit = あなたのアドレスから接続しているゲストが多すぎます（最大 { $max }）
+err-guest-upgrade-disabled = このサーバーではゲストはアカウントを作成できません
+err-guest-upgrade-not-guest = アカウントを登録できるのはゲストのみです
 
 # アバター検証エラー
 err-avatar-invalid-format = アバター形式が無効です（base64エンコードのデータURIである必要があります）
@@ -36,10 +47,17 @@ err-avatar-unsupported-type = サポートされていないアバタータイ
 err-authentication = 認証エラー
 err-invalid-credentials = ユーザー名またはパスワードが無効です
 err-handshake-required = ハンドシェイクが必要です
+err-proof-of-work-required = プルーフ・オブ・ワークが必要です
 err-already-logged-in = 既にログインしています
 err-handshake-already-completed = ハンドシェイクは既に完了しています
 err-account-deleted = アカウントが削除されました
 err-account-disabled-by-admin = 管理者によってアカウントが無効化されました
+err-sso-unavailable = このサーバーでは現在シングルサインオンを利用できません
+err-sso-failed = シングルサインオンに失敗しました。もう一度お試しください
+err-login-token-expired = ログインセッションの有効期限が切れました。もう一度ログインしてください
+err-sso-username-unavailable = 「{ $username }」という名前のアカウントを作成できません。名前が使用済みか許可されていません。管理者にお問い合わせください。
+err-ldap-unavailable = 現在ディレクトリサーバーに接続できません。後でもう一度お試しください
+err-ldap-no-group = あなたのディレクトリアカウントは、このサーバーにアクセスできるグループに所属していません
 
 # 権限とアクセスのエラー
 err-permission-denied = 権限がありません
@@ -58,6 +76,8 @@ err-channel-not-found = チャンネル '{ $channel }' が見つかりません
 err-channel-already-member = すでにチャンネル '{ $channel }' のメンバーです
 err-channel-limit-exceeded = { $max } 個以上のチャンネルに参加することはできません
 err-channel-list-invalid = 無効なチャンネル '{ $channel }': { $reason }
+err-channel-language-too-long = 言語タグが長すぎます（最大{ $max_length }文字）
+err-channel-language-invalid = 言語タグは "en" や "pt-BR" のような言語コードである必要があります
 
 # データベースエラー
 err-database = データベースエラー
@@ -79,11 +99,28 @@ err-cannot-kick-admin = 管理者ユーザーをキックできません
 err-cannot-delete-admin = 管理者ユーザーを削除できるのは管理者のみです
 err-cannot-edit-admin = 管理者ユーザーを編集できるのは管理者のみです
 err-cannot-message-self = 自分自身にメッセージを送ることはできません
+err-message-history-days-too-long = メッセージ履歴の保存期間は最大 { $max } 日です
+err-message-history-shared = 共有アカウントはメッセージ履歴を保存できません
+err-group-no-members = 会話する相手を少なくとも1人指定してください
+err-group-too-many-members = グループ会話のメンバーは最大 { $max } 人です
+err-group-limit = 自分で開始したグループ会話は同時に最大 { $max } 件までです
+err-group-not-found = グループ会話が見つかりません
+err-group-already-member = { $nickname } はすでにこの会話に参加しています
+err-snippet-empty = スニペットを空にすることはできません
+err-snippet-too-long = スニペットが長すぎます（最大{ $max_length }バイト）
+err-snippet-invalid-characters = スニペットに無効な文字が含まれています
+err-snippet-language-invalid = 無効なスニペットの言語: { $language }
+err-snippet-expiry-invalid = スニペットの保存期間は1分から{ $max_days }日までです
+err-snippet-not-found = スニペットが見つかりません（期限切れの可能性があります）
+err-snippet-limit = 同時に共有できるスニペットは最大{ $max }件です
 err-cannot-disable-last-admin = 最後の管理者を無効化できません
 
 # チャットトピックのエラー
 err-topic-contains-newlines = トピックに改行を含めることはできません
 err-topic-invalid-characters = トピックに無効な文字が含まれています
+err-topic-locked = このチャンネルのトピックはロックされています
+err-entry-message-invalid-characters = 入室メッセージに無効な文字が含まれています
+err-rules-invalid-characters = ルールに無効な文字が含まれています
 
 # バージョン検証のエラー
 err-version-empty = バージョンを空にすることはできません
@@ -108,6 +145,7 @@ err-features-invalid-characters = 機能名に無効な文字が含まれてい
 err-message-empty = メッセージを空にすることはできません
 err-message-contains-newlines = メッセージに改行を含めることはできません
 err-message-invalid-characters = メッセージに無効な文字が含まれています
+err-idempotency-key-invalid = メッセージの冪等キーが無効です
 
 # ユーザー名検証のエラー
 err-username-empty = ユーザー名を空にすることはできません
@@ -117,9 +155,13 @@ err-username-invalid = ユーザー名に無効な文字が含まれています
 err-unknown-permission = 不明な権限: '{ $permission }'
 
 # 動的エラーメッセージ（パラメータ付き）
+err-broadcast-invalid-delay = 遅延の形式が無効です（10m、4h、7d を使用）
 err-broadcast-too-long = メッセージが長すぎます（最大{ $max_length }文字）
+err-broadcast-too-many-scheduled = 予約済みのブロードキャストが多すぎます（最大{ $max_count }件）
 err-chat-too-long = メッセージが長すぎます（最大{ $max_length }文字）
 err-topic-too-long = トピックは{ $max_length }文字を超えることはできません
+err-entry-message-too-long = 入室メッセージは{ $max_length }文字を超えることはできません
+err-rules-too-long = ルールは{ $max_length }文字を超えることはできません
 err-version-major-mismatch = 互換性のないプロトコルバージョン：サーバーはバージョン{ $server_major }.x、クライアントはバージョン{ $client_major }.x
 err-version-client-too-new = クライアントバージョン{ $client_version }はサーバーバージョン{ $server_version }より新しいです。サーバーを更新するか、古いクライアントを使用してください。
 err-kicked-by = { $username }によってキックされました
@@ -158,12 +200,16 @@ err-server-image-unsupported-type = サポートされていないサーバー
 err-news-not-found = ニュース #{ $id } が見つかりません
 err-news-body-too-long = ニュース本文が長すぎます（最大{ $max_length }文字）
 err-news-body-invalid-characters = ニュース本文に無効な文字が含まれています
-err-news-image-too-large = ニュース画像が大きすぎます（最大512KB）
+err-news-image-too-large = ニュース画像が大きすぎます（最大{ $max_kb }KB）
 err-news-image-invalid-format = ニュース画像の形式が無効です（base64エンコードのデータURIである必要があります）
 err-news-image-unsupported-type = サポートされていないニュース画像タイプです（PNG、WebP、JPEG、SVGのみ）
-err-news-empty-content = ニュースにはテキストまたは画像が必要です
+err-news-too-many-attachments = ニュースの添付ファイルは最大{ $max }個までです
+err-news-caption-too-long = 添付ファイルのキャプションが長すぎます（最大{ $max_length }文字）
+err-news-caption-invalid-characters = 添付ファイルのキャプションに無効な文字が含まれています
+err-news-empty-content = ニュースにはテキストまたは添付ファイルが必要です
 err-cannot-edit-admin-news = 管理者が投稿したニュースを編集できるのは管理者のみです
 err-cannot-delete-admin-news = 管理者が投稿したニュースを削除できるのは管理者のみです
+err-news-search-date-invalid = 無効な日付: { $date } (YYYY-MM-DD 形式で指定してください)
 
 # ファイルエリアエラー
 err-file-path-too-long = ファイルパスが長すぎます（最大{ $max_length }文字）
@@ -200,6 +246,7 @@ err-transfer-file-failed = { $path } の転送に失敗しました: { $error }
 # Upload Errors
 err-upload-destination-not-allowed = 宛先フォルダはアップロードを許可していません
 err-upload-write-failed = ファイルの書き込みに失敗しました
+err-upload-insufficient-storage = サーバーにこのアップロードに必要な空き容量がありません
 err-upload-hash-mismatch = ファイル検証に失敗しました - ハッシュが一致しません
 err-upload-path-invalid = アップロードのファイルパスが無効です
 err-upload-conflict = このファイル名への別のアップロードが進行中または中断されています。別のファイル名をお試しください。
@@ -228,6 +275,47 @@ err-search-query-too-short = 検索クエリが短すぎます（最小{ $min_le
 err-search-query-too-long = 検索クエリが長すぎます（最大{ $max_length }文字）
 err-search-query-invalid = 検索クエリに無効な文字が含まれています
 err-search-failed = 検索に失敗しました
+
+# Connectivity Check Errors
+err-connectivity-check-not-configured = このサーバーでは接続テストが設定されていません（--check-service を付けて起動してください）
+err-connectivity-check-failed = 接続テストに失敗しました: { $reason }
+
+# Door Errors
+err-door-not-found = ドア '{ $name }' が見つかりません
+err-door-failed = ドア '{ $name }' を起動できませんでした
+
+# File Review Errors
+err-file-rating-invalid = 評価は1〜5つ星で指定してください
+err-file-review-directory = 評価とコメントはファイルにのみ付けられます
+err-file-comment-empty = コメントを空にすることはできません
+err-file-comment-too-long = コメントが長すぎます（最大 { $max_length } 文字）
+err-file-comment-invalid-characters = コメントに無効な文字が含まれています
+err-file-comment-not-found = コメント #{ $id } が見つかりません
+
+# アップロードルールのエラー
+err-upload-rule-extension = '{ $name }' はここでは許可されていないファイル形式です（許可: { $extensions }）
+err-upload-rule-file-size = '{ $name }' はこのフォルダの上限 ({ $max_size }) を超えています
+err-upload-rule-total-size = アップロードがこのフォルダの上限 ({ $max_size }) を超えています
+err-upload-rule-too-many-extensions = ファイル形式が多すぎます（最大 { $max_count }）
+err-upload-rule-invalid-extension = 無効なファイル形式 '{ $extension }'（ドットなしの英数字を使用してください）
+err-upload-rule-not-directory = アップロードルールはフォルダにのみ設定できます
+
+# ディレクトリグループのエラー
+err-ldap-group-empty = グループを空にすることはできません
+err-ldap-group-too-long = グループが長すぎます（最大 { $max_length } 文字）
+err-ldap-group-invalid-characters = グループに無効な文字が含まれています
+err-ldap-group-not-found = グループ '{ $group }' の割り当てはありません
+
+# ファイル確認のエラー
+err-file-stat-empty = 確認するファイルがありません
+err-file-stat-too-many = 一度に確認できるファイルが多すぎます（最大 { $max_count }）
+
+# File Watch Errors
+err-file-watch-too-many = 監視するフォルダーが多すぎます（最大 { $max_count }）
+
+# Presence Errors
+err-presence-too-many = 監視するユーザーが多すぎます（最大 { $max_count }）
+
 # Trust System Errors
 err-trust-invalid-target = 無効なターゲットです（ニックネーム、IPアドレス、またはCIDRレンジを使用してください）
 err-trust-invalid-duration = 無効な期間形式です（10m、4h、7d、または永続の場合は0を使用してください）
@@ -240,3 +328,14 @@ err-voice-not-joined = ボイスセッションに参加していません
 err-voice-not-channel-member = ボイスに参加するには{ $channel }のメンバーである必要があります
 err-voice-target-not-online = { $nickname }はオンラインではありません
 err-voice-invalid-target = 無効なボイス対象です
+err-voice-broadcast-channel-only = 配信はチャンネルでのみ可能です
+err-voice-broadcast-busy = { $channel } のボイスは使用中です。配信には空のボイスチャンネルが必要です
+err-voice-clip-name-empty = クリップ名を空にすることはできません
+err-voice-clip-name-too-long = クリップ名が長すぎます（最大{ $max_length }文字）
+err-voice-clip-name-invalid = クリップ名には英数字、- と _ のみ使用できます
+err-voice-clip-too-large = クリップが大きすぎます（最大256KB）
+err-voice-clip-invalid-format = クリップは Ogg Vorbis 音声ファイルである必要があります
+err-voice-clip-too-long = クリップが長すぎます（最大{ $max_seconds }秒）
+err-voice-clip-exists = '{ $name }' という名前のクリップは既に存在します
+err-voice-clip-not-found = クリップ '{ $name }' が見つかりません
+err-voice-clip-library-full = クリップライブラリがいっぱいです（最大{ $max_clips }件）
@@ -11,6 +11,13 @@ err-nickname-not-online = 사용자 "{ $nickname }"이(가) 온라인 상태가
 err-nickname-required = 공유 계정에는 닉네임이 필요합니다
 err-nickname-too-long = 닉네임이 너무 깁니다 (최대 { $max_length }자)
 
+# 닉네임 등록 오류
+err-nickname-registered = 다른 사용자가 등록한 닉네임입니다
+err-nickname-already-registered = 닉네임 "{ $nickname }"은(는) 이미 등록되어 있습니다
+err-nickname-not-registered = 닉네임 "{ $nickname }"은(는) 등록되어 있지 않습니다
+err-nickname-register-limit = 닉네임은 최대 { $max }개까지 등록할 수 있습니다
+err-nickname-register-shared = 공유 계정은 닉네임을 등록할 수 없습니다
+
 # 부재 메시지 오류
 err-status-too-long = 부재 메시지가 너무 깁니다 (최대 { $max_length }자)
 err-status-contains-newlines = 부재 메시지에는 줄 바꿈을 포함할 수 없습니다
@@ -28,6 +35,10 @@ err-guest-disabled = 이 서버에서는 게스트 접속이 활성화되지 않
 err-cannot-rename-guest = 게스트 계정의 이름은 변경할 수 없습니다
 err-cannot-change-guest-password = 게스트 계정의 비밀번호는 변경할 수 없습니다
 err-cannot-delete-guest = 게스트 계정은 삭제할 수 없습니다
+err-guest-invalid-permissions = 게스트 계정은 다음 권한을 가질 수 없습니다: { $permissions }
+err-guest-limit = 주소에서 접속한 게스트가 너무 많습니다 (최대 { $max })
+err-guest-upgrade-disabled = 이 서버에서는 게스트가 계정을 만들 수 없습니다
+err-guest-upgrade-not-guest = 게스트만 계정을 등록할 수 있습니다
 
 # 아바타 유효성 검사 오류
 err-avatar-invalid-format = 아바타 형식이 잘못되었습니다 (base64 인코딩된 데이터 URI여야 합니다)
@@ -36,10 +47,17 @@ err-avatar-unsupported-type = 지원되지 않는 아바타 유형입니다 (PNG
 err-authentication = 인증 오류
 err-invalid-credentials = 잘못된 사용자 이름 또는 비밀번호
 err-handshake-required = 핸드셰이크 필요
+err-proof-of-work-required = 작업 증명이 필요합니다
 err-already-logged-in = 이미 로그인됨
 err-handshake-already-completed = 핸드셰이크가 이미 완료됨
 err-account-deleted = 계정이 삭제되었습니다
 err-account-disabled-by-admin = 관리자가 계정을 비활성화했습니다
+err-sso-unavailable = 현재 이 서버에서는 싱글 사인온을 사용할 수 없습니다
+err-sso-failed = 싱글 사인온에 실패했습니다. 다시 시도하세요
+err-login-token-expired = 로그인 세션이 만료되었습니다. 다시 로그인하세요
+err-sso-username-unavailable = "{ $username }" 이름으로 계정을 만들 수 없습니다. 이름이 이미 사용 중이거나 허용되지 않습니다. 관리자에게 문의하세요.
+err-ldap-unavailable = 지금은 디렉터리 서버에 연결할 수 없습니다. 나중에 다시 시도하세요
+err-ldap-no-group = 디렉터리 계정이 이 서버에 접근할 수 있는 그룹에 속해 있지 않습니다
 
 # 권한 및 액세스 오류
 err-permission-denied = 권한이 거부됨
@@ -58,6 +76,8 @@ err-channel-not-found = '{ $channel }' 채널을 찾을 수 없습니다
 err-channel-already-member = 이미 '{ $channel }' 채널의 멤버입니다
 err-channel-limit-exceeded = { $max }개 이상의 채널에 참가할 수 없습니다
 err-channel-list-invalid = 잘못된 채널 '{ $channel }': { $reason }
+err-channel-language-too-long = 언어 태그가 너무 깁니다 (최대 { $max_length }자)
+err-channel-language-invalid = 언어 태그는 "en" 또는 "pt-BR" 같은 언어 코드여야 합니다
 
 # 데이터베이스 오류
 err-database = 데이터베이스 오류
@@ -79,11 +99,28 @@ err-cannot-kick-admin = 관리자 사용자를 추방할 수 없습니다
 err-cannot-delete-admin = 관리자만 관리자 사용자를 삭제할 수 있습니다
 err-cannot-edit-admin = 관리자만 관리자 사용자를 편집할 수 있습니다
 err-cannot-message-self = 자기 자신에게 메시지를 보낼 수 없습니다
+err-message-history-days-too-long = 메시지 기록은 최대 { $max }일까지 보관할 수 있습니다
+err-message-history-shared = 공유 계정은 메시지 기록을 보관할 수 없습니다
+err-group-no-members = 대화할 사용자를 한 명 이상 지정하세요
+err-group-too-many-members = 그룹 대화는 최대 { $max }명까지 참여할 수 있습니다
+err-group-limit = 직접 시작한 그룹 대화는 동시에 최대 { $max }개까지 가질 수 있습니다
+err-group-not-found = 그룹 대화를 찾을 수 없습니다
+err-group-already-member = { $nickname }님은 이미 이 대화에 참여 중입니다
+err-snippet-empty = 스니펫은 비워 둘 수 없습니다
+err-snippet-too-long = 스니펫이 너무 깁니다 (최대 { $max_length }바이트)
+err-snippet-invalid-characters = 스니펫에 잘못된 문자가 포함되어 있습니다
+err-snippet-language-invalid = 잘못된 스니펫 언어: { $language }
+err-snippet-expiry-invalid = 스니펫은 1분에서 { $max_days }일까지 보관할 수 있습니다
+err-snippet-not-found = 스니펫을 찾을 수 없습니다 (만료되었을 수 있습니다)
+err-snippet-limit = 한 번에 최대 { $max }개의 스니펫을 공유할 수 있습니다
 err-cannot-disable-last-admin = 마지막 관리자를 비활성화할 수 없습니다
 
 # 채팅 주제 오류
 err-topic-contains-newlines = 주제에 줄 바꿈을 포함할 수 없습니다
 err-topic-invalid-characters = 주제에 잘못된 문자가 포함되어 있습니다
+err-topic-locked = 이 채널의 주제는 잠겨 있습니다
+err-entry-message-invalid-characters = 입장 메시지에 잘못된 문자가 포함되어 있습니다
+err-rules-invalid-characters = 규칙에 잘못된 문자가 포함되어 있습니다
 
 # 버전 검증 오류
 err-version-empty = 버전은 비어 있을 수 없습니다
@@ -108,6 +145,7 @@ err-features-invalid-characters = 기능 이름에 잘못된 문자가 포함되
 err-message-empty = 메시지는 비어 있을 수 없습니다
 err-message-contains-newlines = 메시지에 줄 바꿈을 포함할 수 없습니다
 err-message-invalid-characters = 메시지에 잘못된 문자가 포함되어 있습니다
+err-idempotency-key-invalid = 메시지의 멱등성 키가 잘못되었습니다
 
 # 사용자 이름 검증 오류
 err-username-empty = 사용자 이름은 비어 있을 수 없습니다
@@ -117,9 +155,13 @@ err-username-invalid = 사용자 이름에 잘못된 문자가 포함되어 있
 err-unknown-permission = 알 수 없는 권한: '{ $permission }'
 
 # 동적 오류 메시지 (매개변수 포함)
+err-broadcast-invalid-delay = 잘못된 지연 형식입니다 (10m, 4h, 7d 사용)
 err-broadcast-too-long = 메시지가 너무 깁니다 (최대 { $max_length }자)
+err-broadcast-too-many-scheduled = 예약된 브로드캐스트가 너무 많습니다 (최대 { $max_count }개)
 err-chat-too-long = 메시지가 너무 깁니다 (최대 { $max_length }자)
 err-topic-too-long = 주제는 { $max_length }자를 초과할 수 없습니다
+err-entry-message-too-long = 입장 메시지는 { $max_length }자를 초과할 수 없습니다
+err-rules-too-long = 규칙은 { $max_length }자를 초과할 수 없습니다
 err-version-major-mismatch = 호환되지 않는 프로토콜 버전: 서버는 버전 { $server_major }.x, 클라이언트는 버전 { $client_major }.x입니다
 err-version-client-too-new = 클라이언트 버전 { $client_version }이(가) 서버 버전 { $server_version }보다 최신입니다. 서버를 업데이트하거나 이전 클라이언트를 사용하세요.
 err-kicked-by = { $username }에게 추방당했습니다
@@ -158,12 +200,16 @@ err-server-image-unsupported-type = 지원되지 않는 서버 이미지 유형
 err-news-not-found = 뉴스 #{ $id }을(를) 찾을 수 없습니다
 err-news-body-too-long = 뉴스 내용이 너무 깁니다 (최대 { $max_length }자)
 err-news-body-invalid-characters = 뉴스 내용에 잘못된 문자가 포함되어 있습니다
-err-news-image-too-large = 뉴스 이미지가 너무 큽니다 (최대 512KB)
+err-news-image-too-large = 뉴스 이미지가 너무 큽니다 (최대 { $max_kb }KB)
 err-news-image-invalid-format = 뉴스 이미지 형식이 잘못되었습니다 (base64 인코딩된 데이터 URI여야 합니다)
 err-news-image-unsupported-type = 지원되지 않는 뉴스 이미지 유형입니다 (PNG, WebP, JPEG 또는 SVG만 지원)
-err-news-empty-content = 뉴스에는 텍스트 또는 이미지가 있어야 합니다
+err-news-too-many-attachments = 뉴스 게시물의 첨부 파일은 최대 { $max }개입니다
+err-news-caption-too-long = 첨부 파일 캡션이 너무 깁니다 (최대 { $max_length }자)
+err-news-caption-invalid-characters = 첨부 파일 캡션에 잘못된 문자가 포함되어 있습니다
+err-news-empty-content = 뉴스에는 텍스트 또는 첨부 파일이 있어야 합니다
 err-cannot-edit-admin-news = 관리자가 게시한 뉴스는 관리자만 수정할 수 있습니다
 err-cannot-delete-admin-news = 관리자가 게시한 뉴스는 관리자만 삭제할 수 있습니다
+err-news-search-date-invalid = 잘못된 날짜: { $date } (YYYY-MM-DD 형식 사용)
 
 # 파일 영역 오류
 err-file-path-too-long = 파일 경로가 너무 깁니다 (최대 { $max_length }자)
@@ -200,6 +246,7 @@ err-transfer-file-failed = { $path } 전송 실패: { $error }
 # Upload Errors
 err-upload-destination-not-allowed = 대상 폴더에서 업로드가 허용되지 않습니다
 err-upload-write-failed = 파일 쓰기에 실패했습니다
+err-upload-insufficient-storage = 서버에 이 업로드를 위한 여유 공간이 부족합니다
 err-upload-hash-mismatch = 파일 확인 실패 - 해시가 일치하지 않습니다
 err-upload-path-invalid = 업로드에 잘못된 파일 경로가 있습니다
 err-upload-conflict = 이 파일 이름에 대한 다른 업로드가 진행 중이거나 중단되었습니다. 다른 파일 이름을 시도해 주세요.
@@ -228,6 +275,47 @@ err-search-query-too-short = 검색어가 너무 짧습니다 (최소 { $min_len
 err-search-query-too-long = 검색어가 너무 깁니다 (최대 { $max_length }자)
 err-search-query-invalid = 검색어에 잘못된 문자가 포함되어 있습니다
 err-search-failed = 검색에 실패했습니다
+
+# Connectivity Check Errors
+err-connectivity-check-not-configured = 이 서버에는 연결 테스트가 설정되어 있지 않습니다 (--check-service로 시작하세요)
+err-connectivity-check-failed = 연결 테스트에 실패했습니다: { $reason }
+
+# Door Errors
+err-door-not-found = 도어 '{ $name }'을(를) 찾을 수 없습니다
+err-door-failed = 도어 '{ $name }'을(를) 시작할 수 없습니다
+
+# File Review Errors
+err-file-rating-invalid = 평점은 별 1~5개여야 합니다
+err-file-review-directory = 파일에만 평점과 댓글을 남길 수 있습니다
+err-file-comment-empty = 댓글은 비워 둘 수 없습니다
+err-file-comment-too-long = 댓글이 너무 깁니다 (최대 { $max_length }자)
+err-file-comment-invalid-characters = 댓글에 잘못된 문자가 포함되어 있습니다
+err-file-comment-not-found = 댓글 #{ $id }을(를) 찾을 수 없습니다
+
+# 업로드 규칙 오류
+err-upload-rule-extension = '{ $name }'은(는) 여기에서 허용되지 않는 파일 형식입니다 (허용: { $extensions })
+err-upload-rule-file-size = '{ $name }'이(가) 이 폴더의 허용 크기({ $max_size })보다 큽니다
+err-upload-rule-total-size = 업로드가 이 폴더의 허용 크기({ $max_size })보다 큽니다
+err-upload-rule-too-many-extensions = 파일 형식이 너무 많습니다 (최대 { $max_count }개)
+err-upload-rule-invalid-extension = 잘못된 파일 형식 '{ $extension }' (점 없이 문자와 숫자만 사용하세요)
+err-upload-rule-not-directory = 업로드 규칙은 폴더에만 설정할 수 있습니다
+
+# 디렉터리 그룹 오류
+err-ldap-group-empty = 그룹은 비워 둘 수 없습니다
+err-ldap-group-too-long = 그룹이 너무 깁니다 (최대 { $max_length }자)
+err-ldap-group-invalid-characters = 그룹에 잘못된 문자가 포함되어 있습니다
+err-ldap-group-not-found = '{ $group }' 그룹에 대한 매핑이 없습니다
+
+# 파일 확인 오류
+err-file-stat-empty = 확인할 파일이 없습니다
+err-file-stat-too-many = 한 번에 확인할 파일이 너무 많습니다 (최대 { $max_count })
+
+# File Watch Errors
+err-file-watch-too-many = 지켜볼 폴더가 너무 많습니다 (최대 { $max_count })
+
+# Presence Errors
+err-presence-too-many = 지켜볼 사용자가 너무 많습니다 (최대 { $max_count })
+
 # Trust System Errors
 err-trust-invalid-target = 잘못된 대상입니다 (닉네임, IP 주소 또는 CIDR 범위를 사용하세요)
 err-trust-invalid-duration = 잘못된 기간 형식입니다 (10m, 4h, 7d 또는 영구적으로 0을 사용하세요)
@@ -240,3 +328,14 @@ err-voice-not-joined = 음성 세션에 참여하고 있지 않습니다
 err-voice-not-channel-member = 음성에 참여하려면 { $channel }의 멤버여야 합니다
 err-voice-target-not-online = { $nickname }님이 온라인이 아닙니다
 err-voice-invalid-target = 잘못된 음성 대상입니다
+err-voice-broadcast-channel-only = 방송은 채널에서만 가능합니다
+err-voice-broadcast-busy = { $channel }의 음성이 사용 중입니다. 방송하려면 빈 음성 채널이 필요합니다
+err-voice-clip-name-empty = 클립 이름은 비워 둘 수 없습니다
+err-voice-clip-name-too-long = 클립 이름이 너무 깁니다 (최대 { $max_length }자)
+err-voice-clip-name-invalid = 클립 이름에는 문자, 숫자, - 및 _만 사용할 수 있습니다
+err-voice-clip-too-large = 클립이 너무 큽니다 (최대 256KB)
+err-voice-clip-invalid-format = 클립은 Ogg Vorbis 오디오 파일이어야 합니다
+err-voice-clip-too-long = 클립이 너무 깁니다 (최대 { $max_seconds }초)
+err-voice-clip-exists = '{ $name }' 클립이 이미 존재합니다
+err-voice-clip-not-found = '{ $name }' 클립을 찾을 수 없습니다
+err-voice-clip-library-full = 클립 라이브러리가 가득 찼습니다 (최대 { $max_clips }개)
@@ -11,6 +11,13 @@ err-nickname-not-online = Gebruiker "{ $nickname }" is niet online
 err-nickname-required = Bijnaam vereist voor gedeelde accounts
 err-nickname-too-long = Bijnaam is te lang (max. { $max_length } tekens)
 
+# Fouten bij registratie van bijnamen
+err-nickname-registered = Bijnaam is geregistreerd door een andere gebruiker
+err-nickname-already-registered = Bijnaam "{ $nickname }" is al geregistreerd
+err-nickname-not-registered = Bijnaam "{ $nickname }" is niet geregistreerd
+err-nickname-register-limit = Je kunt maximaal { $max } bijnamen registreren
+err-nickname-register-shared = Gedeelde accounts kunnen geen bijnamen registreren
+
 # Afwezigheidsbericht-fouten
 err-status-too-long = Afwezigheidsbericht is te lang (max. { $max_length } tekens)
 err-status-contains-newlines = Afwezigheidsbericht mag geen regelovergangen bevatten
@@ -28,6 +35,10 @@ err-guest-disabled = Gasttoegang is niet ingeschakeld op deze server
 err-cannot-rename-guest = Het gastaccount kan niet worden hernoemd
 err-cannot-change-guest-password = Het wachtwoord van het gastaccount kan niet worden gewijzigd
 err-cannot-delete-guest = Het gastaccount kan niet worden verwijderd
+err-guest-invalid-permissions = Het gastaccount kan deze rechten niet hebben: { $permissions }
+err-guest-limit = Er zijn te veel gasten verbonden vanaf je adres (max { $max })
+err-guest-upgrade-disabled = Gasten kunnen op deze server geen accounts aanmaken
+err-guest-upgrade-not-guest = Alleen gasten kunnen een account registreren
 
 # Avatar Validatiefouten
 err-avatar-invalid-format = Ongeldig avatar-formaat (moet een data-URI zijn met base64-codering)
@@ -36,10 +47,17 @@ err-avatar-unsupported-type = Niet-ondersteund avatar-type (alleen PNG, WebP of
 err-authentication = Authenticatiefout
 err-invalid-credentials = Ongeldige gebruikersnaam of wachtwoord
 err-handshake-required = Handshake vereist
+err-proof-of-work-required = Proof-of-work vereist
 err-already-logged-in = Al ingelogd
 err-handshake-already-completed = Handshake al voltooid
 err-account-deleted = Uw account is verwijderd
 err-account-disabled-by-admin = Account uitgeschakeld door beheerder
+err-sso-unavailable = Single sign-on is momenteel niet beschikbaar op deze server
+err-sso-failed = Single sign-on mislukt, probeer het opnieuw
+err-login-token-expired = Je aanmeldsessie is verlopen, meld je opnieuw aan
+err-sso-username-unavailable = Kan geen account met de naam '{ $username }' aanmaken: de naam is al in gebruik of niet toegestaan. Vraag een beheerder om hulp.
+err-ldap-unavailable = De directoryserver is nu niet bereikbaar, probeer het later opnieuw
+err-ldap-no-group = Je directory-account zit niet in een groep met toegang tot deze server
 
 # Permissie- en toegangsfouten
 err-permission-denied = Toestemming geweigerd
@@ -58,6 +76,8 @@ err-channel-not-found = Kanaal '{ $channel }' niet gevonden
 err-channel-already-member = U bent al lid van kanaal '{ $channel }'
 err-channel-limit-exceeded = U kunt niet deelnemen aan meer dan { $max } kanalen
 err-channel-list-invalid = Ongeldig kanaal '{ $channel }': { $reason }
+err-channel-language-too-long = Taallabel is te lang (max { $max_length } tekens)
+err-channel-language-invalid = Taallabel moet een taalcode zijn, zoals "en" of "pt-BR"
 
 # Databasefouten
 err-database = Databasefout
@@ -79,11 +99,28 @@ err-cannot-kick-admin = Kan beheerdergebruikers niet verwijderen
 err-cannot-delete-admin = Alleen beheerders kunnen beheerdergebruikers verwijderen
 err-cannot-edit-admin = Alleen beheerders kunnen beheerdergebruikers bewerken
 err-cannot-message-self = U kunt geen berichten naar uzelf sturen
+err-message-history-days-too-long = Berichtgeschiedenis kan maximaal { $max } dagen worden bewaard
+err-message-history-shared = Gedeelde accounts kunnen geen berichtgeschiedenis bewaren
+err-group-no-members = Noem minstens één gebruiker om mee te praten
+err-group-too-many-members = Een groepsgesprek kan maximaal { $max } leden hebben
+err-group-limit = Je kunt maximaal { $max } zelf gestarte groepsgesprekken tegelijk hebben
+err-group-not-found = Groepsgesprek niet gevonden
+err-group-already-member = { $nickname } zit al in dit gesprek
+err-snippet-empty = Snippet mag niet leeg zijn
+err-snippet-too-long = Snippet is te lang (max { $max_length } bytes)
+err-snippet-invalid-characters = Snippet bevat ongeldige tekens
+err-snippet-language-invalid = Ongeldige snippettaal: { $language }
+err-snippet-expiry-invalid = Snippets kunnen 1 minuut tot { $max_days } dagen bewaard worden
+err-snippet-not-found = Snippet niet gevonden (mogelijk verlopen)
+err-snippet-limit = Je kunt maximaal { $max } snippets tegelijk delen
 err-cannot-disable-last-admin = Kan de laatste beheerder niet uitschakelen
 
 # Chatonderwerpfouten
 err-topic-contains-newlines = Het onderwerp mag geen regeleinden bevatten
 err-topic-invalid-characters = Het onderwerp bevat ongeldige tekens
+err-topic-locked = Het onderwerp van dit kanaal is vergrendeld
+err-entry-message-invalid-characters = Het welkomstbericht bevat ongeldige tekens
+err-rules-invalid-characters = De regels bevatten ongeldige tekens
 
 # Versievalidatiefouten
 err-version-empty = De versie mag niet leeg zijn
@@ -108,6 +145,7 @@ err-features-invalid-characters = De functienaam bevat ongeldige tekens
 err-message-empty = Het bericht mag niet leeg zijn
 err-message-contains-newlines = Het bericht mag geen regeleinden bevatten
 err-message-invalid-characters = Het bericht bevat ongeldige tekens
+err-idempotency-key-invalid = Ongeldige idempotentiesleutel voor bericht
 
 # Gebruikersnaamvalidatiefouten
 err-username-empty = De gebruikersnaam mag niet leeg zijn
@@ -117,9 +155,13 @@ err-username-invalid = De gebruikersnaam bevat ongeldige tekens (letters, cijfer
 err-unknown-permission = Onbekende machtiging: '{ $permission }'
 
 # Dynamische foutmeldingen (met parameters)
+err-broadcast-invalid-delay = Ongeldige vertraging (gebruik 10m, 4h of 7d)
 err-broadcast-too-long = Bericht te lang (maximaal { $max_length } tekens)
+err-broadcast-too-many-scheduled = Te veel geplande uitzendingen (maximaal { $max_count })
 err-chat-too-long = Bericht te lang (maximaal { $max_length } tekens)
 err-topic-too-long = Het onderwerp mag niet meer dan { $max_length } tekens bevatten
+err-entry-message-too-long = Het welkomstbericht mag niet meer dan { $max_length } tekens bevatten
+err-rules-too-long = De regels mogen niet meer dan { $max_length } tekens bevatten
 err-version-major-mismatch = Incompatibele protocolversie: server is versie { $server_major }.x, client is versie { $client_major }.x
 err-version-client-too-new = Clientversie { $client_version } is nieuwer dan serverversie { $server_version }. Werk de server bij of gebruik een oudere client.
 err-kicked-by = U bent verwijderd door { $username }
@@ -158,12 +200,16 @@ err-server-image-unsupported-type = Niet-ondersteund serverafbeeldingstype (alle
 err-news-not-found = Nieuwsbericht #{ $id } niet gevonden
 err-news-body-too-long = Nieuwstekst is te lang (maximaal { $max_length } tekens)
 err-news-body-invalid-characters = Nieuwstekst bevat ongeldige tekens
-err-news-image-too-large = Nieuwsafbeelding is te groot (maximaal 512KB)
+err-news-image-too-large = Nieuwsafbeelding is te groot (maximaal { $max_kb }KB)
 err-news-image-invalid-format = Ongeldig nieuwsafbeeldingsformaat (moet een data-URI met base64-codering zijn)
 err-news-image-unsupported-type = Niet-ondersteund nieuwsafbeeldingstype (alleen PNG, WebP, JPEG of SVG)
-err-news-empty-content = Nieuws moet tekstinhoud of een afbeelding bevatten
+err-news-too-many-attachments = Nieuwsberichten kunnen maximaal { $max } bijlagen hebben
+err-news-caption-too-long = Bijschrift van bijlage is te lang (max { $max_length } tekens)
+err-news-caption-invalid-characters = Bijschrift van bijlage bevat ongeldige tekens
+err-news-empty-content = Nieuws moet tekstinhoud of een bijlage bevatten
 err-cannot-edit-admin-news = Alleen beheerders kunnen nieuws bewerken dat door beheerders is geplaatst
 err-cannot-delete-admin-news = Alleen beheerders kunnen nieuws verwijderen dat door beheerders is geplaatst
+err-news-search-date-invalid = Ongeldige datum: { $date } (gebruik JJJJ-MM-DD)
 
 # File Area Errors
 err-file-path-too-long = Bestandspad is te lang (maximaal { $max_length } tekens)
@@ -200,6 +246,7 @@ err-transfer-file-failed = Overdracht van { $path } mislukt: { $error }
 # Upload Errors
 err-upload-destination-not-allowed = Bestemmingsmap staat geen uploads toe
 err-upload-write-failed = Kan bestand niet schrijven
+err-upload-insufficient-storage = Niet genoeg vrije ruimte op de server voor deze upload
 err-upload-hash-mismatch = Bestandsverificatie mislukt - hash komt niet overeen
 err-upload-path-invalid = Ongeldig bestandspad in upload
 err-upload-conflict = Een andere upload naar deze bestandsnaam is bezig of werd onderbroken. Probeer een andere bestandsnaam.
@@ -228,6 +275,47 @@ err-search-query-too-short = Zoekopdracht is te kort (minimaal { $min_length } t
 err-search-query-too-long = Zoekopdracht is te lang (maximaal { $max_length } tekens)
 err-search-query-invalid = Zoekopdracht bevat ongeldige tekens
 err-search-failed = Zoekopdracht mislukt
+
+# Connectivity Check Errors
+err-connectivity-check-not-configured = Verbindingstests zijn niet ingesteld op deze server (start met --check-service)
+err-connectivity-check-failed = Verbindingstest mislukt: { $reason }
+
+# Door Errors
+err-door-not-found = Deur '{ $name }' niet gevonden
+err-door-failed = Deur '{ $name }' kon niet worden gestart
+
+# File Review Errors
+err-file-rating-invalid = Beoordeling moet 1 tot 5 sterren zijn
+err-file-review-directory = Alleen bestanden kunnen worden beoordeeld en becommentarieerd
+err-file-comment-empty = Opmerking mag niet leeg zijn
+err-file-comment-too-long = Opmerking is te lang (max { $max_length } tekens)
+err-file-comment-invalid-characters = Opmerking bevat ongeldige tekens
+err-file-comment-not-found = Opmerking #{ $id } niet gevonden
+
+# Uploadregelfouten
+err-upload-rule-extension = '{ $name }' is hier geen toegestaan bestandstype (toegestaan: { $extensions })
+err-upload-rule-file-size = '{ $name }' is groter dan deze map toestaat ({ $max_size })
+err-upload-rule-total-size = Upload is groter dan deze map toestaat ({ $max_size })
+err-upload-rule-too-many-extensions = Te veel bestandstypen (max { $max_count })
+err-upload-rule-invalid-extension = Ongeldig bestandstype '{ $extension }' (gebruik letters en cijfers, zonder punt)
+err-upload-rule-not-directory = Uploadregels kunnen alleen op mappen worden ingesteld
+
+# Directorygroepfouten
+err-ldap-group-empty = Groep mag niet leeg zijn
+err-ldap-group-too-long = Groep is te lang (max { $max_length } tekens)
+err-ldap-group-invalid-characters = Groep bevat ongeldige tekens
+err-ldap-group-not-found = Geen koppeling voor groep '{ $group }'
+
+# Bestandscontrolefouten
+err-file-stat-empty = Geen bestanden om te controleren
+err-file-stat-too-many = Te veel bestanden om in één keer te controleren (max. { $max_count })
+
+# File Watch Errors
+err-file-watch-too-many = Te veel mappen om in de gaten te houden (max. { $max_count })
+
+# Presence Errors
+err-presence-too-many = Te veel gebruikers om te volgen (max. { $max_count })
+
 # Trust System Errors
 err-trust-invalid-target = Ongeldig doel (gebruik nickname, IP-adres of CIDR-bereik)
 err-trust-invalid-duration = Ongeldig duurformaat (gebruik 10m, 4h, 7d, of 0 voor permanent)
@@ -240,3 +328,14 @@ err-voice-not-joined = Je bent niet in een spraaksessie
 err-voice-not-channel-member = Je moet lid zijn van { $channel } om deel te nemen aan spraak
 err-voice-target-not-online = { $nickname } is niet online
 err-voice-invalid-target = Ongeldig spraakdoel
+err-voice-broadcast-channel-only = Uitzendingen zijn alleen mogelijk in kanalen
+err-voice-broadcast-busy = Spraak in { $channel } is in gebruik; een uitzending vereist een leeg spraakkanaal
+err-voice-clip-name-empty = Clipnaam mag niet leeg zijn
+err-voice-clip-name-too-long = Clipnaam is te lang (maximaal { $max_length } tekens)
+err-voice-clip-name-invalid = Clipnaam mag alleen letters, cijfers, - en _ bevatten
+err-voice-clip-too-large = Clip is te groot (maximaal 256KB)
+err-voice-clip-invalid-format = Clip moet een Ogg Vorbis-audiobestand zijn
+err-voice-clip-too-long = Clip is te lang (maximaal { $max_seconds } seconden)
+err-voice-clip-exists = Er bestaat al een clip met de naam '{ $name }'
+err-voice-clip-not-found = Clip '{ $name }' niet gevonden
+err-voice-clip-library-full = De clipbibliotheek is vol (maximaal { $max_clips } clips)
@@ -11,6 +11,13 @@ err-nickname-not-online = O usuário "{ $nickname }" não está online
 err-nickname-required = Apelido obrigatório para contas compartilhadas
 err-nickname-too-long = O apelido é muito longo (máx. { $max_length } caracteres)
 
+# Erros de registro de apelido
+err-nickname-registered = O apelido está registrado por outro usuário
+err-nickname-already-registered = O apelido "{ $nickname }" já está registrado
+err-nickname-not-registered = O apelido "{ $nickname }" não está registrado
+err-nickname-register-limit = Você pode registrar no máximo { $max } apelidos
+err-nickname-register-shared = Contas compartilhadas não podem registrar apelidos
+
 # Erros de mensagem de ausência
 err-status-too-long = A mensagem de ausência é muito longa (máx. { $max_length } caracteres)
 err-status-contains-newlines = A mensagem de ausência não pode conter quebras de linha
@@ -28,6 +35,10 @@ err-guest-disabled = O acesso de convidado não está habilitado neste servidor
 err-cannot-rename-guest = A conta de convidado não pode ser renomeada
 err-cannot-change-guest-password = A senha da conta de convidado não pode ser alterada
 err-cannot-delete-guest = A conta de convidado não pode ser excluída
+err-guest-invalid-permissions = A conta de convidado não pode ter estas permissões: { $permissions }
+err-guest-limit = Há convidados demais conectados a partir do seu endereço (máx. { $max })
+err-guest-upgrade-disabled = Convidados não podem criar contas neste servidor
+err-guest-upgrade-not-guest = Apenas convidados podem registrar uma conta
 
 # Erros de Validação de Avatar
 err-avatar-invalid-format = Formato de avatar inválido (deve ser uma URI de dados com codificação base64)
@@ -36,10 +47,17 @@ err-avatar-unsupported-type = Tipo de avatar não suportado (apenas PNG, WebP ou
 err-authentication = Erro de autenticação
 err-invalid-credentials = Nome de usuário ou senha inválidos
 err-handshake-required = Handshake necessário
+err-proof-of-work-required = Prova de trabalho necessária
 err-already-logged-in = Já conectado
 err-handshake-already-completed = Handshake já concluído
 err-account-deleted = Sua conta foi excluída
 err-account-disabled-by-admin = Conta desativada pelo administrador
+err-sso-unavailable = O login único não está disponível neste servidor no momento
+err-sso-failed = O login único falhou, tente novamente
+err-login-token-expired = Sua sessão expirou, entre novamente
+err-sso-username-unavailable = Não é possível criar uma conta chamada "{ $username }": o nome já está em uso ou não é permitido. Peça ajuda a um administrador.
+err-ldap-unavailable = Não é possível acessar o servidor de diretório agora, tente novamente mais tarde
+err-ldap-no-group = Sua conta do diretório não está em nenhum grupo com acesso a este servidor
 
 # Erros de permissão e acesso
 err-permission-denied = Permissão negada
@@ -58,6 +76,8 @@ err-channel-not-found = Canal '{ $channel }' não encontrado
 err-channel-already-member = Você já é membro do canal '{ $channel }'
 err-channel-limit-exceeded = Você não pode participar de mais de { $max } canais
 err-channel-list-invalid = Canal inválido '{ $channel }': { $reason }
+err-channel-language-too-long = A etiqueta de idioma é muito longa (máx. { $max_length } caracteres)
+err-channel-language-invalid = A etiqueta de idioma deve ser um código de idioma como "en" ou "pt-BR"
 
 # Erros de banco de dados
 err-database = Erro de banco de dados
@@ -79,11 +99,28 @@ err-cannot-kick-admin = Não é possível expulsar usuários administradores
 err-cannot-delete-admin = Apenas administradores podem excluir usuários administradores
 err-cannot-edit-admin = Apenas administradores podem editar usuários administradores
 err-cannot-message-self = Você não pode enviar mensagem para si mesmo
+err-message-history-days-too-long = O histórico de mensagens pode ser mantido por no máximo { $max } dias
+err-message-history-shared = Contas compartilhadas não podem manter histórico de mensagens
+err-group-no-members = Indique pelo menos um usuário para conversar
+err-group-too-many-members = Uma conversa em grupo pode ter no máximo { $max } membros
+err-group-limit = Você pode ter no máximo { $max } conversas em grupo iniciadas por você ao mesmo tempo
+err-group-not-found = Conversa em grupo não encontrada
+err-group-already-member = { $nickname } já está nesta conversa
+err-snippet-empty = O snippet não pode estar vazio
+err-snippet-too-long = O snippet é muito longo (máx. { $max_length } bytes)
+err-snippet-invalid-characters = O snippet contém caracteres inválidos
+err-snippet-language-invalid = Linguagem de snippet inválida: { $language }
+err-snippet-expiry-invalid = Snippets podem ser mantidos de 1 minuto a { $max_days } dias
+err-snippet-not-found = Snippet não encontrado (pode ter expirado)
+err-snippet-limit = Você pode compartilhar no máximo { $max } snippets por vez
 err-cannot-disable-last-admin = Não é possível desabilitar o último administrador
 
 # Erros de tópico de chat
 err-topic-contains-newlines = O tópico não pode conter quebras de linha
 err-topic-invalid-characters = O tópico contém caracteres inválidos
+err-topic-locked = O tópico deste canal está bloqueado
+err-entry-message-invalid-characters = A mensagem de entrada contém caracteres inválidos
+err-rules-invalid-characters = As regras contêm caracteres inválidos
 
 # Erros de validação de versão
 err-version-empty = A versão não pode estar vazia
@@ -108,6 +145,7 @@ err-features-invalid-characters = O nome do recurso contém caracteres inválido
 err-message-empty = A mensagem não pode estar vazia
 err-message-contains-newlines = A mensagem não pode conter quebras de linha
 err-message-invalid-characters = A mensagem contém caracteres inválidos
+err-idempotency-key-invalid = Chave de idempotência da mensagem inválida
 
 # Erros de validação de nome de usuário
 err-username-empty = O nome de usuário não pode estar vazio
@@ -117,9 +155,13 @@ err-username-invalid = O nome de usuário contém caracteres inválidos (letras,
 err-unknown-permission = Permissão desconhecida: '{ $permission }'
 
 # Mensagens de erro dinâmicas (com parâmetros)
+err-broadcast-invalid-delay = Formato de atraso inválido (use 10m, 4h ou 7d)
 err-broadcast-too-long = Mensagem muito longa (máximo { $max_length } caracteres)
+err-broadcast-too-many-scheduled = Transmissões agendadas demais (máximo { $max_count })
 err-chat-too-long = Mensagem muito longa (máximo { $max_length } caracteres)
 err-topic-too-long = O tópico não pode exceder { $max_length } caracteres
+err-entry-message-too-long = A mensagem de entrada não pode exceder { $max_length } caracteres
+err-rules-too-long = As regras não podem exceder { $max_length } caracteres
 err-version-major-mismatch = Versão de protocolo incompatível: o servidor é versão { $server_major }.x, o cliente é versão { $client_major }.x
 err-version-client-too-new = A versão do cliente { $client_version } é mais recente que a versão do servidor { $server_version }. Por favor, atualize o servidor ou use um cliente mais antigo.
 err-kicked-by = Você foi expulso por { $username }
@@ -158,12 +200,16 @@ err-server-image-unsupported-type = Tipo de imagem do servidor não suportado (a
 err-news-not-found = Notícia #{ $id } não encontrada
 err-news-body-too-long = O conteúdo da notícia é muito longo (máximo { $max_length } caracteres)
 err-news-body-invalid-characters = O conteúdo da notícia contém caracteres inválidos
-err-news-image-too-large = A imagem da notícia é muito grande (máximo 512KB)
+err-news-image-too-large = A imagem da notícia é muito grande (máximo { $max_kb }KB)
 err-news-image-invalid-format = Formato de imagem da notícia inválido (deve ser uma URI de dados com codificação base64)
 err-news-image-unsupported-type = Tipo de imagem da notícia não suportado (apenas PNG, WebP, JPEG ou SVG)
-err-news-empty-content = Notícia deve ter conteúdo de texto ou uma imagem
+err-news-too-many-attachments = Notícias podem ter no máximo { $max } anexos
+err-news-caption-too-long = Legenda do anexo é muito longa (máx. { $max_length } caracteres)
+err-news-caption-invalid-characters = Legenda do anexo contém caracteres inválidos
+err-news-empty-content = Notícia deve ter conteúdo de texto ou um anexo
 err-cannot-edit-admin-news = Apenas administradores podem editar notícias publicadas por administradores
 err-cannot-delete-admin-news = Apenas administradores podem excluir notícias publicadas por administradores
+err-news-search-date-invalid = Data inválida: { $date } (use AAAA-MM-DD)
 
 # File Area Errors
 err-file-path-too-long = Caminho do arquivo é muito longo (máximo { $max_length } caracteres)
@@ -200,6 +246,7 @@ err-transfer-file-failed = Falha ao transferir { $path }: { $error }
 # Upload Errors
 err-upload-destination-not-allowed = A pasta de destino não permite uploads
 err-upload-write-failed = Falha ao gravar o arquivo
+err-upload-insufficient-storage = Não há espaço livre suficiente no servidor para este envio
 err-upload-hash-mismatch = Verificação do arquivo falhou - hash não corresponde
 err-upload-path-invalid = Caminho de arquivo inválido no upload
 err-upload-conflict = Outro upload para este nome de arquivo está em andamento ou foi interrompido. Por favor, tente um nome de arquivo diferente.
@@ -228,6 +275,47 @@ err-search-query-too-short = A busca é muito curta (mínimo { $min_length } car
 err-search-query-too-long = A busca é muito longa (máximo { $max_length } caracteres)
 err-search-query-invalid = A busca contém caracteres inválidos
 err-search-failed = A busca falhou
+
+# Connectivity Check Errors
+err-connectivity-check-not-configured = Os testes de conectividade não estão configurados neste servidor (inicie-o com --check-service)
+err-connectivity-check-failed = O teste de conectividade falhou: { $reason }
+
+# Door Errors
+err-door-not-found = Porta '{ $name }' não encontrada
+err-door-failed = Não foi possível iniciar a porta '{ $name }'
+
+# File Review Errors
+err-file-rating-invalid = A avaliação deve ser de 1 a 5 estrelas
+err-file-review-directory = Apenas arquivos podem ser avaliados e comentados
+err-file-comment-empty = O comentário não pode estar vazio
+err-file-comment-too-long = O comentário é muito longo (máx. { $max_length } caracteres)
+err-file-comment-invalid-characters = O comentário contém caracteres inválidos
+err-file-comment-not-found = Comentário #{ $id } não encontrado
+
+# Erros de regras de envio
+err-upload-rule-extension = '{ $name }' não é um tipo de arquivo permitido aqui (permitidos: { $extensions })
+err-upload-rule-file-size = '{ $name }' é maior do que esta pasta permite ({ $max_size })
+err-upload-rule-total-size = O envio é maior do que esta pasta permite ({ $max_size })
+err-upload-rule-too-many-extensions = Tipos de arquivo demais (máx. { $max_count })
+err-upload-rule-invalid-extension = Tipo de arquivo inválido '{ $extension }' (use letras e dígitos, sem o ponto)
+err-upload-rule-not-directory = Regras de envio só podem ser definidas em pastas
+
+# Erros de grupos do diretório
+err-ldap-group-empty = O grupo não pode estar vazio
+err-ldap-group-too-long = O grupo é muito longo (máx. { $max_length } caracteres)
+err-ldap-group-invalid-characters = O grupo contém caracteres inválidos
+err-ldap-group-not-found = Nenhum mapeamento para o grupo '{ $group }'
+
+# Erros de verificação de arquivos
+err-file-stat-empty = Nenhum arquivo para verificar
+err-file-stat-too-many = Arquivos demais para verificar de uma vez (máx. { $max_count })
+
+# File Watch Errors
+err-file-watch-too-many = Pastas demais para acompanhar (máx. { $max_count })
+
+# Presence Errors
+err-presence-too-many = Usuários demais para acompanhar (máx. { $max_count })
+
 # Trust System Errors
 err-trust-invalid-target = Alvo inválido (use apelido, endereço IP ou faixa CIDR)
 err-trust-invalid-duration = Formato de duração inválido (use 10m, 4h, 7d, ou 0 para permanente)
@@ -240,3 +328,14 @@ err-voice-not-joined = Você não está em uma sessão de voz
 err-voice-not-channel-member = Você precisa ser membro de { $channel } para entrar no chat de voz
 err-voice-target-not-online = { $nickname } não está online
 err-voice-invalid-target = Destino de voz inválido
+err-voice-broadcast-channel-only = Transmissões só são possíveis em canais
+err-voice-broadcast-busy = A voz de { $channel } está em uso; uma transmissão precisa de um canal de voz vazio
+err-voice-clip-name-empty = O nome do clipe não pode estar vazio
+err-voice-clip-name-too-long = O nome do clipe é muito longo (máximo { $max_length } caracteres)
+err-voice-clip-name-invalid = O nome do clipe só pode conter letras, números, - e _
+err-voice-clip-too-large = O clipe é muito grande (máximo 256KB)
+err-voice-clip-invalid-format = O clipe deve ser um arquivo de áudio Ogg Vorbis
+err-voice-clip-too-long = O clipe é muito longo (máximo { $max_seconds } segundos)
+err-voice-clip-exists = Já existe um clipe chamado '{ $name }'
+err-voice-clip-not-found = Clipe '{ $name }' não encontrado
+err-voice-clip-library-full = A biblioteca de clipes está cheia (máximo { $max_clips } clipes)
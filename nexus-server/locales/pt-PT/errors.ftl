@@ -11,6 +11,13 @@ err-nickname-not-online = O utilizador "{ $nickname }" não está online
 err-nickname-required = Alcunha obrigatória para contas partilhadas
 err-nickname-too-long = A alcunha é demasiado longa (máx. { $max_length } caracteres)
 
+# Erros de registo de alcunha
+err-nickname-registered = A alcunha está registada por outro utilizador
+err-nickname-already-registered = A alcunha "{ $nickname }" já está registada
+err-nickname-not-registered = A alcunha "{ $nickname }" não está registada
+err-nickname-register-limit = Pode registar no máximo { $max } alcunhas
+err-nickname-register-shared = As contas partilhadas não podem registar alcunhas
+
 # Erros de mensagem de ausência
 err-status-too-long = A mensagem de ausência é demasiado longa (máx. { $max_length } caracteres)
 err-status-contains-newlines = A mensagem de ausência não pode conter quebras de linha
@@ -28,6 +35,10 @@ err-guest-disabled = O acesso de convidado não está activado neste servidor
 err-cannot-rename-guest = A conta de convidado não pode ser renomeada
 err-cannot-change-guest-password = A palavra-passe da conta de convidado não pode ser alterada
 err-cannot-delete-guest = A conta de convidado não pode ser eliminada
+err-guest-invalid-permissions = A conta de convidado não pode ter estas permissões: { $permissions }
+err-guest-limit = Há demasiados convidados ligados a partir do seu endereço (máx. { $max })
+err-guest-upgrade-disabled = Os convidados não podem criar contas neste servidor
+err-guest-upgrade-not-guest = Apenas convidados podem registar uma conta
 
 # Erros de Validação de Avatar
 err-avatar-invalid-format = Formato de avatar inválido (deve ser uma URI de dados com codificação base64)
@@ -36,10 +47,17 @@ err-avatar-unsupported-type = Tipo de avatar não suportado (apenas PNG, WebP ou
 err-authentication = Erro de autenticação
 err-invalid-credentials = Nome de utilizador ou palavra-passe inválidos
 err-handshake-required = Handshake necessário
+err-proof-of-work-required = Prova de trabalho necessária
 err-already-logged-in = Sessão já iniciada
 err-handshake-already-completed = Handshake já concluído
 err-account-deleted = A sua conta foi eliminada
 err-account-disabled-by-admin = Conta desativada pelo administrador
+err-sso-unavailable = O início de sessão único não está disponível neste servidor de momento
+err-sso-failed = O início de sessão único falhou, tente novamente
+err-login-token-expired = A sua sessão expirou, inicie sessão novamente
+err-sso-username-unavailable = Não é possível criar uma conta chamada "{ $username }": o nome já está a ser utilizado ou não é permitido. Peça ajuda a um administrador.
+err-ldap-unavailable = Não é possível contactar o servidor de diretório neste momento, tente novamente mais tarde
+err-ldap-no-group = A sua conta do diretório não pertence a nenhum grupo com acesso a este servidor
 
 # Erros de permissão e acesso
 err-permission-denied = Permissão negada
@@ -58,6 +76,8 @@ err-channel-not-found = Canal '{ $channel }' não encontrado
 err-channel-already-member = Já é membro do canal '{ $channel }'
 err-channel-limit-exceeded = Não pode participar em mais de { $max } canais
 err-channel-list-invalid = Canal inválido '{ $channel }': { $reason }
+err-channel-language-too-long = A etiqueta de idioma é demasiado longa (máx. { $max_length } caracteres)
+err-channel-language-invalid = A etiqueta de idioma deve ser um código de idioma como "en" ou "pt-BR"
 
 # Erros de base de dados
 err-database = Erro de base de dados
@@ -79,11 +99,28 @@ err-cannot-kick-admin = Não é possível expulsar utilizadores administradores
 err-cannot-delete-admin = Apenas administradores podem eliminar utilizadores administradores
 err-cannot-edit-admin = Apenas administradores podem editar utilizadores administradores
 err-cannot-message-self = Não pode enviar mensagens a si mesmo
+err-message-history-days-too-long = O histórico de mensagens pode ser guardado no máximo { $max } dias
+err-message-history-shared = As contas partilhadas não podem guardar histórico de mensagens
+err-group-no-members = Indique pelo menos um utilizador para conversar
+err-group-too-many-members = Uma conversa de grupo pode ter no máximo { $max } membros
+err-group-limit = Pode ter no máximo { $max } conversas de grupo iniciadas por si ao mesmo tempo
+err-group-not-found = Conversa de grupo não encontrada
+err-group-already-member = { $nickname } já está nesta conversa
+err-snippet-empty = O snippet não pode estar vazio
+err-snippet-too-long = O snippet é demasiado longo (máx. { $max_length } bytes)
+err-snippet-invalid-characters = O snippet contém caracteres inválidos
+err-snippet-language-invalid = Linguagem de snippet inválida: { $language }
+err-snippet-expiry-invalid = Os snippets podem ser mantidos de 1 minuto a { $max_days } dias
+err-snippet-not-found = Snippet não encontrado (pode ter expirado)
+err-snippet-limit = Pode partilhar no máximo { $max } snippets de cada vez
 err-cannot-disable-last-admin = Não é possível desativar o último administrador
 
 # Erros de tópico de chat
 err-topic-contains-newlines = O tópico não pode conter quebras de linha
 err-topic-invalid-characters = O tópico contém caracteres inválidos
+err-topic-locked = O tópico deste canal está bloqueado
+err-entry-message-invalid-characters = A mensagem de entrada contém caracteres inválidos
+err-rules-invalid-characters = As regras contêm caracteres inválidos
 
 # Erros de validação de versão
 err-version-empty = A versão não pode estar vazia
@@ -108,6 +145,7 @@ err-features-invalid-characters = O nome da funcionalidade contém caracteres in
 err-message-empty = A mensagem não pode estar vazia
 err-message-contains-newlines = A mensagem não pode conter quebras de linha
 err-message-invalid-characters = A mensagem contém caracteres inválidos
+err-idempotency-key-invalid = Chave de idempotência da mensagem inválida
 
 # Erros de validação de nome de utilizador
 err-username-empty = O nome de utilizador não pode estar vazio
@@ -117,9 +155,13 @@ err-username-invalid = O nome de utilizador contém caracteres inválidos (letra
 err-unknown-permission = Permissão desconhecida: '{ $permission }'
 
 # Mensagens de erro dinâmicas (com parâmetros)
+err-broadcast-invalid-delay = Formato de atraso inválido (use 10m, 4h ou 7d)
 err-broadcast-too-long = Mensagem demasiado longa (máximo { $max_length } caracteres)
+err-broadcast-too-many-scheduled = Demasiadas difusões agendadas (máximo { $max_count })
 err-chat-too-long = Mensagem demasiado longa (máximo { $max_length } caracteres)
 err-topic-too-long = O tópico não pode exceder { $max_length } caracteres
+err-entry-message-too-long = A mensagem de entrada não pode exceder { $max_length } caracteres
+err-rules-too-long = As regras não podem exceder { $max_length } caracteres
 err-version-major-mismatch = Versão de protocolo incompatível: o servidor é versão { $server_major }.x, o cliente é versão { $client_major }.x
 err-version-client-too-new = A versão do cliente { $client_version } é mais recente que a versão do servidor { $server_version }. Por favor atualize o servidor ou use um cliente mais antigo.
 err-kicked-by = Foi expulso por { $username }
@@ -158,12 +200,16 @@ err-server-image-unsupported-type = Tipo de imagem do servidor não suportado (a
 err-news-not-found = Notícia #{ $id } não encontrada
 err-news-body-too-long = O conteúdo da notícia é demasiado longo (máximo { $max_length } caracteres)
 err-news-body-invalid-characters = O conteúdo da notícia contém caracteres inválidos
-err-news-image-too-large = A imagem da notícia é demasiado grande (máximo 512KB)
+err-news-image-too-large = A imagem da notícia é demasiado grande (máximo { $max_kb }KB)
 err-news-image-invalid-format = Formato de imagem da notícia inválido (deve ser um URI de dados com codificação base64)
 err-news-image-unsupported-type = Tipo de imagem da notícia não suportado (apenas PNG, WebP, JPEG ou SVG)
-err-news-empty-content = A notícia deve ter conteúdo de texto ou uma imagem
+err-news-too-many-attachments = As notícias podem ter no máximo { $max } anexos
+err-news-caption-too-long = A legenda do anexo é demasiado longa (máx. { $max_length } caracteres)
+err-news-caption-invalid-characters = A legenda do anexo contém caracteres inválidos
+err-news-empty-content = A notícia deve ter conteúdo de texto ou um anexo
 err-cannot-edit-admin-news = Apenas administradores podem editar notícias publicadas por administradores
 err-cannot-delete-admin-news = Apenas administradores podem eliminar notícias publicadas por administradores
+err-news-search-date-invalid = Data inválida: { $date } (utilize AAAA-MM-DD)
 
 # File Area Errors
 err-file-path-too-long = Caminho do ficheiro é demasiado longo (máximo { $max_length } caracteres)
@@ -200,6 +246,7 @@ err-transfer-file-failed = Falha ao transferir { $path }: { $error }
 # Upload Errors
 err-upload-destination-not-allowed = A pasta de destino não permite carregamentos
 err-upload-write-failed = Falha ao escrever o ficheiro
+err-upload-insufficient-storage = Não há espaço livre suficiente no servidor para este envio
 err-upload-hash-mismatch = Verificação do ficheiro falhou - hash não coincide
 err-upload-path-invalid = Caminho de ficheiro inválido no carregamento
 err-upload-conflict = Outro carregamento para este nome de ficheiro está em curso ou foi interrompido. Por favor, tente um nome de ficheiro diferente.
@@ -228,6 +275,47 @@ err-search-query-too-short = A consulta de pesquisa é demasiado curta (mín { $
 err-search-query-too-long = A consulta de pesquisa é demasiado longa (máx { $max_length } caracteres)
 err-search-query-invalid = A consulta de pesquisa contém caracteres inválidos
 err-search-failed = A pesquisa falhou
+
+# Connectivity Check Errors
+err-connectivity-check-not-configured = Os testes de conectividade não estão configurados neste servidor (inicie-o com --check-service)
+err-connectivity-check-failed = O teste de conectividade falhou: { $reason }
+
+# Door Errors
+err-door-not-found = Porta '{ $name }' não encontrada
+err-door-failed = Não foi possível iniciar a porta '{ $name }'
+
+# File Review Errors
+err-file-rating-invalid = A avaliação deve ser de 1 a 5 estrelas
+err-file-review-directory = Apenas ficheiros podem ser avaliados e comentados
+err-file-comment-empty = O comentário não pode estar vazio
+err-file-comment-too-long = O comentário é demasiado longo (máx. { $max_length } caracteres)
+err-file-comment-invalid-characters = O comentário contém caracteres inválidos
+err-file-comment-not-found = Comentário #{ $id } não encontrado
+
+# Erros de regras de envio
+err-upload-rule-extension = '{ $name }' não é um tipo de ficheiro permitido aqui (permitidos: { $extensions })
+err-upload-rule-file-size = '{ $name }' é maior do que esta pasta permite ({ $max_size })
+err-upload-rule-total-size = O envio é maior do que esta pasta permite ({ $max_size })
+err-upload-rule-too-many-extensions = Demasiados tipos de ficheiro (máx. { $max_count })
+err-upload-rule-invalid-extension = Tipo de ficheiro inválido '{ $extension }' (use letras e dígitos, sem o ponto)
+err-upload-rule-not-directory = As regras de envio só podem ser definidas em pastas
+
+# Erros de grupos do diretório
+err-ldap-group-empty = O grupo não pode estar vazio
+err-ldap-group-too-long = O grupo é demasiado longo (máx. { $max_length } caracteres)
+err-ldap-group-invalid-characters = O grupo contém caracteres inválidos
+err-ldap-group-not-found = Nenhum mapeamento para o grupo '{ $group }'
+
+# Erros de verificação de ficheiros
+err-file-stat-empty = Nenhum ficheiro para verificar
+err-file-stat-too-many = Demasiados ficheiros para verificar de uma vez (máx. { $max_count })
+
+# File Watch Errors
+err-file-watch-too-many = Demasiadas pastas para acompanhar (máx. { $max_count })
+
+# Presence Errors
+err-presence-too-many = Demasiados utilizadores para acompanhar (máx. { $max_count })
+
 # Trust System Errors
 err-trust-invalid-target = Alvo inválido (utilize alcunha, endereço IP ou intervalo CIDR)
 err-trust-invalid-duration = Formato de duração inválido (utilize 10m, 4h, 7d, ou 0 para permanente)
@@ -240,3 +328,14 @@ err-voice-not-joined = Não está numa sessão de voz
 err-voice-not-channel-member = Tem de ser membro de { $channel } para entrar no chat de voz
 err-voice-target-not-online = { $nickname } não está online
 err-voice-invalid-target = Destino de voz inválido
+err-voice-broadcast-channel-only = As transmissões só são possíveis em canais
+err-voice-broadcast-busy = A voz de { $channel } está em uso; uma transmissão precisa de um canal de voz vazio
+err-voice-clip-name-empty = O nome do clip não pode estar vazio
+err-voice-clip-name-too-long = O nome do clip é demasiado longo (máximo { $max_length } caracteres)
+err-voice-clip-name-invalid = O nome do clip só pode conter letras, números, - e _
+err-voice-clip-too-large = O clip é demasiado grande (máximo 256KB)
+err-voice-clip-invalid-format = O clip deve ser um ficheiro de áudio Ogg Vorbis
+err-voice-clip-too-long = O clip é demasiado longo (máximo { $max_seconds } segundos)
+err-voice-clip-exists = Já existe um clip chamado '{ $name }'
+err-voice-clip-not-found = Clip '{ $name }' não encontrado
+err-voice-clip-library-full = A biblioteca de clips está cheia (máximo { $max_clips } clips)
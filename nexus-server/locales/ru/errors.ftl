@@ -11,6 +11,13 @@ err-nickname-not-online = Пользователь "{ $nickname }" не в се
 err-nickname-required = Псевдоним обязателен для общих учетных записей
 err-nickname-too-long = Псевдоним слишком длинный (макс. { $max_length } символов)
 
+# Ошибки регистрации псевдонимов
+err-nickname-registered = Псевдоним зарегистрирован другим пользователем
+err-nickname-already-registered = Псевдоним "{ $nickname }" уже зарегистрирован
+err-nickname-not-registered = Псевдоним "{ $nickname }" не зарегистрирован
+err-nickname-register-limit = Можно зарегистрировать не более { $max } псевдонимов
+err-nickname-register-shared = Общие учетные записи не могут регистрировать псевдонимы
+
 # Ошибки сообщения об отсутствии
 err-status-too-long = Сообщение об отсутствии слишком длинное (макс. { $max_length } символов)
 err-status-contains-newlines = Сообщение об отсутствии не может содержать переносы строк
@@ -28,6 +35,10 @@ err-guest-disabled = Гостевой доступ не включен на эт
 err-cannot-rename-guest = Гостевую учетную запись нельзя переименовать
 err-cannot-change-guest-password = Пароль гостевой учетной записи нельзя изменить
 err-cannot-delete-guest = Гостевую учетную запись нельзя удалить
+err-guest-invalid-permissions = Гостевая учетная запись не может иметь эти разрешения: { $permissions }
+err-guest-limit = С вашего адреса подключено слишком много гостей (макс. { $max })
+err-guest-upgrade-disabled = Гости не могут создавать учетные записи на этом сервере
+err-guest-upgrade-not-guest = Зарегистрировать учетную запись могут только гости
 
 # Ошибки валидации аватара
 err-avatar-invalid-format = Недопустимый формат аватара (должен быть data URI с кодировкой base64)
@@ -36,10 +47,17 @@ err-avatar-unsupported-type = Неподдерживаемый тип авата
 err-authentication = Ошибка аутентификации
 err-invalid-credentials = Неверное имя пользователя или пароль
 err-handshake-required = Требуется рукопожатие
+err-proof-of-work-required = Требуется доказательство работы
 err-already-logged-in = Вы уже вошли в систему
 err-handshake-already-completed = Рукопожатие уже выполнено
 err-account-deleted = Ваша учетная запись удалена
 err-account-disabled-by-admin = Учетная запись отключена администратором
+err-sso-unavailable = Единый вход сейчас недоступен на этом сервере
+err-sso-failed = Не удалось выполнить единый вход, попробуйте ещё раз
+err-login-token-expired = Сеанс входа истёк, войдите снова
+err-sso-username-unavailable = Невозможно создать учетную запись "{ $username }": имя занято или недопустимо. Обратитесь к администратору.
+err-ldap-unavailable = Сервер каталога сейчас недоступен, попробуйте позже
+err-ldap-no-group = Ваша учётная запись каталога не входит ни в одну группу с доступом к этому серверу
 
 # Ошибки прав доступа
 err-permission-denied = Доступ запрещен
@@ -58,6 +76,8 @@ err-channel-not-found = Канал '{ $channel }' не найден
 err-channel-already-member = Вы уже являетесь участником канала '{ $channel }'
 err-channel-limit-exceeded = Вы не можете присоединиться более чем к { $max } каналам
 err-channel-list-invalid = Недопустимый канал '{ $channel }': { $reason }
+err-channel-language-too-long = Метка языка слишком длинная (максимум { $max_length } символов)
+err-channel-language-invalid = Метка языка должна быть кодом языка, например "en" или "pt-BR"
 
 # Ошибки базы данных
 err-database = Ошибка базы данных
@@ -79,11 +99,28 @@ err-cannot-kick-admin = Невозможно выгнать пользовате
 err-cannot-delete-admin = Только администраторы могут удалять пользователей-администраторов
 err-cannot-edit-admin = Только администраторы могут редактировать пользователей-администраторов
 err-cannot-message-self = Вы не можете отправить сообщение себе
+err-message-history-days-too-long = История сообщений может храниться не более { $max } дн.
+err-message-history-shared = Общие учётные записи не могут хранить историю сообщений
+err-group-no-members = Укажите хотя бы одного собеседника
+err-group-too-many-members = В групповой беседе может быть не более { $max } участников
+err-group-limit = Одновременно может идти не более { $max } начатых вами групповых бесед
+err-group-not-found = Групповая беседа не найдена
+err-group-already-member = { $nickname } уже участвует в этой беседе
+err-snippet-empty = Сниппет не может быть пустым
+err-snippet-too-long = Сниппет слишком длинный (максимум { $max_length } байт)
+err-snippet-invalid-characters = Сниппет содержит недопустимые символы
+err-snippet-language-invalid = Недопустимый язык сниппета: { $language }
+err-snippet-expiry-invalid = Сниппеты можно хранить от 1 минуты до { $max_days } дней
+err-snippet-not-found = Сниппет не найден (возможно, срок его хранения истёк)
+err-snippet-limit = Одновременно можно делиться не более чем { $max } сниппетами
 err-cannot-disable-last-admin = Невозможно отключить последнего администратора
 
 # Ошибки темы чата
 err-topic-contains-newlines = Тема не может содержать переносы строк
 err-topic-invalid-characters = Тема содержит недопустимые символы
+err-topic-locked = Тема этого канала заблокирована
+err-entry-message-invalid-characters = Приветственное сообщение содержит недопустимые символы
+err-rules-invalid-characters = Правила содержат недопустимые символы
 
 # Ошибки проверки версии
 err-version-empty = Версия не может быть пустой
@@ -108,6 +145,7 @@ err-features-invalid-characters = Название функции содержи
 err-message-empty = Сообщение не может быть пустым
 err-message-contains-newlines = Сообщение не может содержать переносы строк
 err-message-invalid-characters = Сообщение содержит недопустимые символы
+err-idempotency-key-invalid = Недопустимый ключ идемпотентности сообщения
 
 # Ошибки проверки имени пользователя
 err-username-empty = Имя пользователя не может быть пустым
@@ -117,9 +155,13 @@ err-username-invalid = Имя пользователя содержит недо
 err-unknown-permission = Неизвестное разрешение: '{ $permission }'
 
 # Динамические сообщения об ошибках (с параметрами)
+err-broadcast-invalid-delay = Неверный формат задержки (используйте 10m, 4h или 7d)
 err-broadcast-too-long = Сообщение слишком длинное (максимум { $max_length } символов)
+err-broadcast-too-many-scheduled = Слишком много запланированных рассылок (максимум { $max_count })
 err-chat-too-long = Сообщение слишком длинное (максимум { $max_length } символов)
 err-topic-too-long = Тема не может превышать { $max_length } символов
+err-entry-message-too-long = Приветственное сообщение не может превышать { $max_length } символов
+err-rules-too-long = Правила не могут превышать { $max_length } символов
 err-version-major-mismatch = Несовместимая версия протокола: сервер версии { $server_major }.x, клиент версии { $client_major }.x
 err-version-client-too-new = Версия клиента { $client_version } новее версии сервера { $server_version }. Пожалуйста, обновите сервер или используйте более старый клиент.
 err-kicked-by = Вы были выгнаны пользователем { $username }
@@ -158,12 +200,16 @@ err-server-image-unsupported-type = Неподдерживаемый тип из
 err-news-not-found = Новость #{ $id } не найдена
 err-news-body-too-long = Текст новости слишком длинный (максимум { $max_length } символов)
 err-news-body-invalid-characters = Текст новости содержит недопустимые символы
-err-news-image-too-large = Изображение новости слишком большое (максимум 512КБ)
+err-news-image-too-large = Изображение новости слишком большое (максимум { $max_kb }КБ)
 err-news-image-invalid-format = Недопустимый формат изображения новости (должен быть data URI с кодировкой base64)
 err-news-image-unsupported-type = Неподдерживаемый тип изображения новости (только PNG, WebP, JPEG или SVG)
-err-news-empty-content = Новость должна содержать текст или изображение
+err-news-too-many-attachments = Новость может содержать не более { $max } вложений
+err-news-caption-too-long = Подпись к вложению слишком длинная (макс. { $max_length } символов)
+err-news-caption-invalid-characters = Подпись к вложению содержит недопустимые символы
+err-news-empty-content = Новость должна содержать текст или вложение
 err-cannot-edit-admin-news = Только администраторы могут редактировать новости, опубликованные администраторами
 err-cannot-delete-admin-news = Только администраторы могут удалять новости, опубликованные администраторами
+err-news-search-date-invalid = Неверная дата: { $date } (используйте ГГГГ-ММ-ДД)
 
 # Ошибки файловой области
 err-file-path-too-long = Путь к файлу слишком длинный (максимум { $max_length } символов)
@@ -200,6 +246,7 @@ err-transfer-file-failed = Не удалось передать { $path }: { $er
 # Upload Errors
 err-upload-destination-not-allowed = Папка назначения не разрешает загрузки
 err-upload-write-failed = Не удалось записать файл
+err-upload-insufficient-storage = На сервере недостаточно свободного места для этой загрузки
 err-upload-hash-mismatch = Проверка файла не удалась - хеш не совпадает
 err-upload-path-invalid = Недопустимый путь к файлу при загрузке
 err-upload-conflict = Другая загрузка с этим именем файла выполняется или была прервана. Пожалуйста, попробуйте другое имя файла.
@@ -228,6 +275,47 @@ err-search-query-too-short = Поисковый запрос слишком ко
 err-search-query-too-long = Поисковый запрос слишком длинный (максимум { $max_length } символов)
 err-search-query-invalid = Поисковый запрос содержит недопустимые символы
 err-search-failed = Ошибка поиска
+
+# Connectivity Check Errors
+err-connectivity-check-not-configured = Проверка доступности не настроена на этом сервере (запустите его с --check-service)
+err-connectivity-check-failed = Проверка доступности не удалась: { $reason }
+
+# Door Errors
+err-door-not-found = Дверь '{ $name }' не найдена
+err-door-failed = Не удалось запустить дверь '{ $name }'
+
+# File Review Errors
+err-file-rating-invalid = Оценка должна быть от 1 до 5 звёзд
+err-file-review-directory = Оценивать и комментировать можно только файлы
+err-file-comment-empty = Комментарий не может быть пустым
+err-file-comment-too-long = Комментарий слишком длинный (макс. { $max_length } символов)
+err-file-comment-invalid-characters = Комментарий содержит недопустимые символы
+err-file-comment-not-found = Комментарий #{ $id } не найден
+
+# Ошибки правил загрузки
+err-upload-rule-extension = '{ $name }' — недопустимый здесь тип файла (разрешены: { $extensions })
+err-upload-rule-file-size = '{ $name }' больше, чем разрешено в этой папке ({ $max_size })
+err-upload-rule-total-size = Загрузка больше, чем разрешено в этой папке ({ $max_size })
+err-upload-rule-too-many-extensions = Слишком много типов файлов (макс. { $max_count })
+err-upload-rule-invalid-extension = Недопустимый тип файла '{ $extension }' (используйте буквы и цифры, без точки)
+err-upload-rule-not-directory = Правила загрузки можно задавать только для папок
+
+# Ошибки групп каталога
+err-ldap-group-empty = Группа не может быть пустой
+err-ldap-group-too-long = Слишком длинная группа (макс. { $max_length } символов)
+err-ldap-group-invalid-characters = Группа содержит недопустимые символы
+err-ldap-group-not-found = Для группы '{ $group }' нет сопоставления
+
+# Ошибки проверки файлов
+err-file-stat-empty = Нет файлов для проверки
+err-file-stat-too-many = Слишком много файлов для проверки за раз (макс. { $max_count })
+
+# File Watch Errors
+err-file-watch-too-many = Слишком много отслеживаемых папок (макс. { $max_count })
+
+# Presence Errors
+err-presence-too-many = Слишком много отслеживаемых пользователей (макс. { $max_count })
+
 # Trust System Errors
 err-trust-invalid-target = Недопустимая цель (используйте никнейм, IP-адрес или диапазон CIDR)
 err-trust-invalid-duration = Недопустимый формат продолжительности (используйте 10m, 4h, 7d или 0 для постоянного)
@@ -240,3 +328,14 @@ err-voice-not-joined = Вы не находитесь в голосовой се
 err-voice-not-channel-member = Для подключения к голосовому чату вы должны быть участником { $channel }
 err-voice-target-not-online = { $nickname } не в сети
 err-voice-invalid-target = Недопустимая цель голосового чата
+err-voice-broadcast-channel-only = Трансляции возможны только в каналах
+err-voice-broadcast-busy = Голосовой чат { $channel } занят; для трансляции нужен пустой голосовой канал
+err-voice-clip-name-empty = Название клипа не может быть пустым
+err-voice-clip-name-too-long = Название клипа слишком длинное (максимум { $max_length } символов)
+err-voice-clip-name-invalid = Название клипа может содержать только буквы, цифры, - и _
+err-voice-clip-too-large = Клип слишком большой (максимум 256КБ)
+err-voice-clip-invalid-format = Клип должен быть аудиофайлом Ogg Vorbis
+err-voice-clip-too-long = Клип слишком длинный (максимум { $max_seconds } секунд)
+err-voice-clip-exists = Клип с названием '{ $name }' уже существует
+err-voice-clip-not-found = Клип '{ $name }' не найден
+err-voice-clip-library-full = Библиотека клипов заполнена (максимум { $max_clips } клипов)
@@ -11,6 +11,13 @@ err-nickname-not-online = 用户"{ $nickname }"不在线
 err-nickname-required = 共享账户需要昵称
 err-nickname-too-long = 昵称太长（最多{ $max_length }个字符）
 
+# 昵称注册错误
+err-nickname-registered = 该昵称已被其他用户注册
+err-nickname-already-registered = 昵称"{ $nickname }"已被注册
+err-nickname-not-registered = 昵称"{ $nickname }"未注册
+err-nickname-register-limit = 最多只能注册 { $max } 个昵称
+err-nickname-register-shared = 共享账户不能注册昵称
+
 # 离开消息错误
 err-status-too-long = 离开消息太长（最多{ $max_length }个字符）
 err-status-contains-newlines = 离开消息不能包含换行符
@@ -28,6 +35,10 @@ err-guest-disabled = 此服务器未启用访客访问
 err-cannot-rename-guest = 访客账户不能被重命名
 err-cannot-change-guest-password = 访客账户的密码不能被更改
 err-cannot-delete-guest = 访客账户不能被删除
+err-guest-invalid-permissions = 访客账户不能拥有这些权限：{ $permissions }
+err-guest-limit = 从您的地址连接的访客过多（最多 { $max } 个）
+err-guest-upgrade-disabled = 此服务器不允许访客创建账户
+err-guest-upgrade-not-guest = 只有访客可以注册账户
 
 # 头像验证错误
 err-avatar-invalid-format = 头像格式无效（必须是base64编码的数据URI）
@@ -36,10 +47,17 @@ err-avatar-unsupported-type = 不支持的头像类型（仅支持PNG、WebP或S
 err-authentication = 身份验证错误
 err-invalid-credentials = 用户名或密码无效
 err-handshake-required = 需要握手
+err-proof-of-work-required = 需要工作量证明
 err-already-logged-in = 已经登录
 err-handshake-already-completed = 握手已完成
 err-account-deleted = 您的账户已被删除
 err-account-disabled-by-admin = 账户已被管理员禁用
+err-sso-unavailable = 此服务器当前无法使用单点登录
+err-sso-failed = 单点登录失败，请重试
+err-login-token-expired = 登录会话已过期，请重新登录
+err-sso-username-unavailable = 无法创建名为“{ $username }”的账户：该名称已被占用或不被允许。请联系管理员。
+err-ldap-unavailable = 目前无法连接目录服务器，请稍后再试
+err-ldap-no-group = 你的目录账户不属于任何可访问此服务器的组
 
 # 权限和访问错误
 err-permission-denied = 权限被拒绝
@@ -58,6 +76,8 @@ err-channel-not-found = 未找到频道 '{ $channel }'
 err-channel-already-member = 您已经是频道 '{ $channel }' 的成员
 err-channel-limit-exceeded = 您不能加入超过 { $max } 个频道
 err-channel-list-invalid = 无效频道 '{ $channel }': { $reason }
+err-channel-language-too-long = 语言标签太长（最多 { $max_length } 个字符）
+err-channel-language-invalid = 语言标签必须是语言代码，例如 "en" 或 "pt-BR"
 
 # 数据库错误
 err-database = 数据库错误
@@ -79,11 +99,28 @@ err-cannot-kick-admin = 无法踢出管理员用户
 err-cannot-delete-admin = 只有管理员才能删除管理员用户
 err-cannot-edit-admin = 只有管理员才能编辑管理员用户
 err-cannot-message-self = 您无法给自己发消息
+err-message-history-days-too-long = 消息历史最多可保留 { $max } 天
+err-message-history-shared = 共享账户无法保留消息历史
+err-group-no-members = 请至少指定一位对话用户
+err-group-too-many-members = 群组对话最多可有 { $max } 位成员
+err-group-limit = 您最多可以同时进行 { $max } 个自己发起的群组对话
+err-group-not-found = 找不到群组对话
+err-group-already-member = { $nickname } 已在此对话中
+err-snippet-empty = 代码片段不能为空
+err-snippet-too-long = 代码片段太长（最多 { $max_length } 字节）
+err-snippet-invalid-characters = 代码片段包含无效字符
+err-snippet-language-invalid = 无效的代码片段语言：{ $language }
+err-snippet-expiry-invalid = 代码片段可保留 1 分钟到 { $max_days } 天
+err-snippet-not-found = 未找到代码片段（可能已过期）
+err-snippet-limit = 你最多可以同时分享 { $max } 个代码片段
 err-cannot-disable-last-admin = 无法禁用最后一个管理员
 
 # 聊天主题错误
 err-topic-contains-newlines = 主题不能包含换行符
 err-topic-invalid-characters = 主题包含无效字符
+err-topic-locked = 此频道的主题已锁定
+err-entry-message-invalid-characters = 入场消息包含无效字符
+err-rules-invalid-characters = 规则包含无效字符
 
 # 版本验证错误
 err-version-empty = 版本不能为空
@@ -108,6 +145,7 @@ err-features-invalid-characters = 功能名称包含无效字符
 err-message-empty = 消息不能为空
 err-message-contains-newlines = 消息不能包含换行符
 err-message-invalid-characters = 消息包含无效字符
+err-idempotency-key-invalid = 消息的幂等键无效
 
 # 用户名验证错误
 err-username-empty = 用户名不能为空
@@ -117,9 +155,13 @@ err-username-invalid = 用户名包含无效字符（允许字母、数字和符
 err-unknown-permission = 未知权限: '{ $permission }'
 
 # 动态错误消息（带参数）
+err-broadcast-invalid-delay = 延迟格式无效（请使用 10m、4h 或 7d）
 err-broadcast-too-long = 消息太长（最多{ $max_length }个字符）
+err-broadcast-too-many-scheduled = 计划的广播过多（最多{ $max_count }条）
 err-chat-too-long = 消息太长（最多{ $max_length }个字符）
 err-topic-too-long = 主题不能超过{ $max_length }个字符
+err-entry-message-too-long = 入场消息不能超过{ $max_length }个字符
+err-rules-too-long = 规则不能超过{ $max_length }个字符
 err-version-major-mismatch = 不兼容的协议版本：服务器是版本{ $server_major }.x，客户端是版本{ $client_major }.x
 err-version-client-too-new = 客户端版本{ $client_version }比服务器版本{ $server_version }更新。请更新服务器或使用旧版客户端。
 err-kicked-by = 您已被{ $username }踢出
@@ -158,12 +200,16 @@ err-server-image-unsupported-type = 不支持的服务器图片类型（仅支
 err-news-not-found = 新闻 #{ $id } 未找到
 err-news-body-too-long = 新闻内容太长（最多{ $max_length }个字符）
 err-news-body-invalid-characters = 新闻内容包含无效字符
-err-news-image-too-large = 新闻图片太大（最大512KB）
+err-news-image-too-large = 新闻图片太大（最大{ $max_kb }KB）
 err-news-image-invalid-format = 新闻图片格式无效（必须是base64编码的数据URI）
 err-news-image-unsupported-type = 不支持的新闻图片类型（仅支持PNG、WebP、JPEG或SVG）
-err-news-empty-content = 新闻必须包含文字内容或图片
+err-news-too-many-attachments = 新闻最多只能有 { $max } 个附件
+err-news-caption-too-long = 附件说明过长（最多 { $max_length } 个字符）
+err-news-caption-invalid-characters = 附件说明包含无效字符
+err-news-empty-content = 新闻必须包含文字内容或附件
 err-cannot-edit-admin-news = 只有管理员可以编辑管理员发布的新闻
 err-cannot-delete-admin-news = 只有管理员可以删除管理员发布的新闻
+err-news-search-date-invalid = 无效日期: { $date } (请使用 YYYY-MM-DD)
 
 # 文件区域错误
 err-file-path-too-long = 文件路径过长（最多{ $max_length }个字符）
@@ -200,6 +246,7 @@ err-transfer-file-failed = 传输 { $path } 失败: { $error }
 # Upload Errors
 err-upload-destination-not-allowed = 目标文件夹不允许上传
 err-upload-write-failed = 文件写入失败
+err-upload-insufficient-storage = 服务器上没有足够的可用空间进行此上传
 err-upload-hash-mismatch = 文件验证失败 - 哈希值不匹配
 err-upload-path-invalid = 上传中的文件路径无效
 err-upload-conflict = 另一个上传到此文件名的操作正在进行中或已中断。请尝试使用其他文件名。
@@ -228,6 +275,47 @@ err-search-query-too-short = 搜索查询过短（最少 { $min_length } 个字
 err-search-query-too-long = 搜索查询过长（最多 { $max_length } 个字符）
 err-search-query-invalid = 搜索查询包含无效字符
 err-search-failed = 搜索失败
+
+# Connectivity Check Errors
+err-connectivity-check-not-configured = 此服务器未配置连通性检测（请使用 --check-service 启动）
+err-connectivity-check-failed = 连通性检测失败：{ $reason }
+
+# Door Errors
+err-door-not-found = 未找到门程序 '{ $name }'
+err-door-failed = 无法启动门程序 '{ $name }'
+
+# File Review Errors
+err-file-rating-invalid = 评分必须为 1 到 5 星
+err-file-review-directory = 只能对文件评分和评论
+err-file-comment-empty = 评论不能为空
+err-file-comment-too-long = 评论过长（最多 { $max_length } 个字符）
+err-file-comment-invalid-characters = 评论包含无效字符
+err-file-comment-not-found = 未找到评论 #{ $id }
+
+# 上传规则错误
+err-upload-rule-extension = 此处不允许 '{ $name }' 的文件类型（允许：{ $extensions }）
+err-upload-rule-file-size = '{ $name }' 超过了此文件夹允许的大小（{ $max_size }）
+err-upload-rule-total-size = 上传超过了此文件夹允许的大小（{ $max_size }）
+err-upload-rule-too-many-extensions = 文件类型过多（最多 { $max_count } 个）
+err-upload-rule-invalid-extension = 无效的文件类型 '{ $extension }'（请使用字母和数字，不含点）
+err-upload-rule-not-directory = 上传规则只能设置在文件夹上
+
+# 目录组错误
+err-ldap-group-empty = 组不能为空
+err-ldap-group-too-long = 组过长（最多 { $max_length } 个字符）
+err-ldap-group-invalid-characters = 组包含无效字符
+err-ldap-group-not-found = 组“{ $group }”没有映射
+
+# 文件检查错误
+err-file-stat-empty = 没有要检查的文件
+err-file-stat-too-many = 一次检查的文件过多（最多 { $max_count } 个）
+
+# File Watch Errors
+err-file-watch-too-many = 监视的文件夹过多（最多 { $max_count } 个）
+
+# Presence Errors
+err-presence-too-many = 关注的用户过多（最多 { $max_count } 个）
+
 # Trust System Errors
 err-trust-invalid-target = 无效的目标（请使用昵称、IP地址或CIDR范围）
 err-trust-invalid-duration = 无效的持续时间格式（使用 10m、4h、7d 或 0 表示永久）
@@ -240,3 +328,14 @@ err-voice-not-joined = 您不在语音会话中
 err-voice-not-channel-member = 您必须是 { $channel } 的成员才能加入语音
 err-voice-target-not-online = { $nickname } 不在线
 err-voice-invalid-target = 无效的语音目标
+err-voice-broadcast-channel-only = 只能在频道中进行广播
+err-voice-broadcast-busy = { $channel } 的语音正在使用中；广播需要一个空的语音频道
+err-voice-clip-name-empty = 片段名称不能为空
+err-voice-clip-name-too-long = 片段名称太长（最多{ $max_length }个字符）
+err-voice-clip-name-invalid = 片段名称只能包含字母、数字、- 和 _
+err-voice-clip-too-large = 片段太大（最大256KB）
+err-voice-clip-invalid-format = 片段必须是 Ogg Vorbis 音频文件
+err-voice-clip-too-long = 片段太长（最长{ $max_seconds }秒）
+err-voice-clip-exists = 名为 '{ $name }' 的片段已存在
+err-voice-clip-not-found = 未找到片段 '{ $name }'
+err-voice-clip-library-full = 片段库已满（最多{ $max_clips }个片段）
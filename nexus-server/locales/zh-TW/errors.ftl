@@ -11,6 +11,13 @@ err-nickname-not-online = 使用者「{ $nickname }」不在線上
 err-nickname-required = 共享帳戶需要暱稱
 err-nickname-too-long = 暱稱太長（最多{ $max_length }個字元）
 
+# 暱稱註冊錯誤
+err-nickname-registered = 該暱稱已被其他使用者註冊
+err-nickname-already-registered = 暱稱「{ $nickname }」已被註冊
+err-nickname-not-registered = 暱稱「{ $nickname }」未註冊
+err-nickname-register-limit = 最多只能註冊 { $max } 個暱稱
+err-nickname-register-shared = 共享帳戶不能註冊暱稱
+
 # 離開訊息錯誤
 err-status-too-long = 離開訊息太長（最多{ $max_length }個字元）
 err-status-contains-newlines = 離開訊息不能包含換行符
@@ -28,6 +35,10 @@ err-guest-disabled = 此伺服器未啟用訪客存取
 err-cannot-rename-guest = 訪客帳戶無法重新命名
 err-cannot-change-guest-password = 訪客帳戶的密碼無法變更
 err-cannot-delete-guest = 訪客帳戶無法刪除
+err-guest-invalid-permissions = 訪客帳戶不能擁有這些權限：{ $permissions }
+err-guest-limit = 從您的位址連線的訪客過多（最多 { $max } 個）
+err-guest-upgrade-disabled = 此伺服器不允許訪客建立帳戶
+err-guest-upgrade-not-guest = 只有訪客可以註冊帳戶
 
 # 頭像驗證錯誤
 err-avatar-invalid-format = 頭像格式無效（必須是base64編碼的資料URI）
@@ -36,10 +47,17 @@ err-avatar-unsupported-type = 不支援的頭像類型（僅支援PNG、WebP或S
 err-authentication = 身份驗證錯誤
 err-invalid-credentials = 使用者名稱或密碼無效
 err-handshake-required = 需要握手
+err-proof-of-work-required = 需要工作量證明
 err-already-logged-in = 已經登入
 err-handshake-already-completed = 握手已完成
 err-account-deleted = 您的帳戶已被刪除
 err-account-disabled-by-admin = 帳戶已被管理員停用
+err-sso-unavailable = 此伺服器目前無法使用單一登入
+err-sso-failed = 單一登入失敗，請再試一次
+err-login-token-expired = 登入工作階段已過期，請重新登入
+err-sso-username-unavailable = 無法建立名為「{ $username }」的帳戶：該名稱已被使用或不被允許。請聯絡管理員。
+err-ldap-unavailable = 目前無法連線到目錄伺服器，請稍後再試
+err-ldap-no-group = 你的目錄帳號不屬於任何可存取此伺服器的群組
 
 # 權限和存取錯誤
 err-permission-denied = 權限被拒絕
@@ -58,6 +76,8 @@ err-channel-not-found = 未找到頻道 '{ $channel }'
 err-channel-already-member = 您已經是頻道 '{ $channel }' 的成員
 err-channel-limit-exceeded = 您不能加入超過 { $max } 個頻道
 err-channel-list-invalid = 無效頻道 '{ $channel }': { $reason }
+err-channel-language-too-long = 語言標籤太長（最多 { $max_length } 個字元）
+err-channel-language-invalid = 語言標籤必須是語言代碼，例如 "en" 或 "pt-BR"
 
 # 資料庫錯誤
 err-database = 資料庫錯誤
@@ -79,11 +99,28 @@ err-cannot-kick-admin = 無法踢除管理員用戶
 err-cannot-delete-admin = 只有管理員才能刪除管理員用戶
 err-cannot-edit-admin = 只有管理員才能編輯管理員用戶
 err-cannot-message-self = 您不能給自己發送訊息
+err-message-history-days-too-long = 訊息記錄最多可保留 { $max } 天
+err-message-history-shared = 共用帳號無法保留訊息記錄
+err-group-no-members = 請至少指定一位對話使用者
+err-group-too-many-members = 群組對話最多可有 { $max } 位成員
+err-group-limit = 您最多可以同時進行 { $max } 個自己發起的群組對話
+err-group-not-found = 找不到群組對話
+err-group-already-member = { $nickname } 已在此對話中
+err-snippet-empty = 程式碼片段不能為空
+err-snippet-too-long = 程式碼片段太長（最多 { $max_length } 位元組）
+err-snippet-invalid-characters = 程式碼片段包含無效字元
+err-snippet-language-invalid = 無效的程式碼片段語言：{ $language }
+err-snippet-expiry-invalid = 程式碼片段可保留 1 分鐘到 { $max_days } 天
+err-snippet-not-found = 找不到程式碼片段（可能已過期）
+err-snippet-limit = 你最多可以同時分享 { $max } 個程式碼片段
 err-cannot-disable-last-admin = 無法停用最後一位管理員
 
 # 聊天主題錯誤
 err-topic-contains-newlines = 主題不能包含換行符號
 err-topic-invalid-characters = 主題包含無效字元
+err-topic-locked = 此頻道的主題已鎖定
+err-entry-message-invalid-characters = 入場訊息包含無效字元
+err-rules-invalid-characters = 規則包含無效字元
 
 # 版本驗證錯誤
 err-version-empty = 版本不能為空
@@ -108,6 +145,7 @@ err-features-invalid-characters = 功能名稱包含無效字元
 err-message-empty = 訊息不能為空
 err-message-contains-newlines = 訊息不能包含換行符號
 err-message-invalid-characters = 訊息包含無效字元
+err-idempotency-key-invalid = 訊息的冪等金鑰無效
 
 # 使用者名稱驗證錯誤
 err-username-empty = 使用者名稱不能為空
@@ -117,9 +155,13 @@ err-username-invalid = 使用者名稱包含無效字元（允許字母、數字
 err-unknown-permission = 未知權限: '{ $permission }'
 
 # 動態錯誤訊息（帶參數）
+err-broadcast-invalid-delay = 延遲格式無效（請使用 10m、4h 或 7d）
 err-broadcast-too-long = 訊息太長（最多{ $max_length }個字元）
+err-broadcast-too-many-scheduled = 排程的廣播過多（最多{ $max_count }則）
 err-chat-too-long = 訊息太長（最多{ $max_length }個字元）
 err-topic-too-long = 主題不能超過{ $max_length }個字元
+err-entry-message-too-long = 入場訊息不能超過{ $max_length }個字元
+err-rules-too-long = 規則不能超過{ $max_length }個字元
 err-version-major-mismatch = 不相容的協定版本：伺服器是版本{ $server_major }.x，客戶端是版本{ $client_major }.x
 err-version-client-too-new = 客戶端版本{ $client_version }比伺服器版本{ $server_version }更新。請更新伺服器或使用較舊的客戶端。
 err-kicked-by = 您已被{ $username }踢出
@@ -158,12 +200,16 @@ err-server-image-unsupported-type = 不支援的伺服器圖片類型（僅支
 err-news-not-found = 找不到新聞 #{ $id }
 err-news-body-too-long = 新聞內容太長（最多{ $max_length }個字元）
 err-news-body-invalid-characters = 新聞內容包含無效字元
-err-news-image-too-large = 新聞圖片太大（最大512KB）
+err-news-image-too-large = 新聞圖片太大（最大{ $max_kb }KB）
 err-news-image-invalid-format = 新聞圖片格式無效（必須是base64編碼的資料URI）
 err-news-image-unsupported-type = 不支援的新聞圖片類型（僅支援PNG、WebP、JPEG或SVG）
-err-news-empty-content = 新聞必須包含文字內容或圖片
+err-news-too-many-attachments = 新聞最多只能有 { $max } 個附件
+err-news-caption-too-long = 附件說明過長（最多 { $max_length } 個字元）
+err-news-caption-invalid-characters = 附件說明包含無效字元
+err-news-empty-content = 新聞必須包含文字內容或附件
 err-cannot-edit-admin-news = 只有管理員可以編輯管理員發布的新聞
 err-cannot-delete-admin-news = 只有管理員可以刪除管理員發布的新聞
+err-news-search-date-invalid = 無效日期: { $date } (請使用 YYYY-MM-DD)
 
 # 檔案區域錯誤
 err-file-path-too-long = 檔案路徑過長（最多{ $max_length }個字元）
@@ -200,6 +246,7 @@ err-transfer-file-failed = 傳輸 { $path } 失敗: { $error }
 # Upload Errors
 err-upload-destination-not-allowed = 目標資料夾不允許上傳
 err-upload-write-failed = 無法寫入檔案
+err-upload-insufficient-storage = 伺服器上沒有足夠的可用空間進行此上傳
 err-upload-hash-mismatch = 檔案驗證失敗 - 雜湊值不符
 err-upload-path-invalid = 上傳中的檔案路徑無效
 err-upload-conflict = 另一個上傳到此檔案名稱的操作正在進行中或已中斷。請嘗試使用其他檔案名稱。
@@ -228,6 +275,47 @@ err-search-query-too-short = 搜尋查詢太短（最少 { $min_length } 個字
 err-search-query-too-long = 搜尋查詢太長（最多 { $max_length } 個字元）
 err-search-query-invalid = 搜尋查詢包含無效字元
 err-search-failed = 搜尋失敗
+
+# Connectivity Check Errors
+err-connectivity-check-not-configured = 此伺服器未設定連線檢測（請使用 --check-service 啟動）
+err-connectivity-check-failed = 連線檢測失敗：{ $reason }
+
+# Door Errors
+err-door-not-found = 找不到門程式 '{ $name }'
+err-door-failed = 無法啟動門程式 '{ $name }'
+
+# File Review Errors
+err-file-rating-invalid = 評分必須為 1 到 5 星
+err-file-review-directory = 只能對檔案評分和留言
+err-file-comment-empty = 留言不能為空
+err-file-comment-too-long = 留言過長（最多 { $max_length } 個字元）
+err-file-comment-invalid-characters = 留言包含無效字元
+err-file-comment-not-found = 找不到留言 #{ $id }
+
+# 上傳規則錯誤
+err-upload-rule-extension = 此處不允許 '{ $name }' 的檔案類型（允許：{ $extensions }）
+err-upload-rule-file-size = '{ $name }' 超過了此資料夾允許的大小（{ $max_size }）
+err-upload-rule-total-size = 上傳超過了此資料夾允許的大小（{ $max_size }）
+err-upload-rule-too-many-extensions = 檔案類型過多（最多 { $max_count } 個）
+err-upload-rule-invalid-extension = 無效的檔案類型 '{ $extension }'（請使用字母和數字，不含點）
+err-upload-rule-not-directory = 上傳規則只能設定在資料夾上
+
+# 目錄群組錯誤
+err-ldap-group-empty = 群組不能為空
+err-ldap-group-too-long = 群組過長（最多 { $max_length } 個字元）
+err-ldap-group-invalid-characters = 群組包含無效字元
+err-ldap-group-not-found = 群組「{ $group }」沒有對應
+
+# 檔案檢查錯誤
+err-file-stat-empty = 沒有要檢查的檔案
+err-file-stat-too-many = 一次檢查的檔案過多（最多 { $max_count } 個）
+
+# File Watch Errors
+err-file-watch-too-many = 監視的資料夾過多（最多 { $max_count } 個）
+
+# Presence Errors
+err-presence-too-many = 關注的使用者過多（最多 { $max_count } 個）
+
 # Trust System Errors
 err-trust-invalid-target = 無效的目標（請使用暱稱、IP 位址或 CIDR 範圍）
 err-trust-invalid-duration = 無效的期限格式（請使用 10m、4h、7d，或 0 表示永久）
@@ -240,3 +328,14 @@ err-voice-not-joined = 您不在語音會話中
 err-voice-not-channel-member = 您必須是 { $channel } 的成員才能加入語音
 err-voice-target-not-online = { $nickname } 不在線上
 err-voice-invalid-target = 無效的語音目標
+err-voice-broadcast-channel-only = 只能在頻道中進行廣播
+err-voice-broadcast-busy = { $channel } 的語音正在使用中；廣播需要一個空的語音頻道
+err-voice-clip-name-empty = 片段名稱不能為空
+err-voice-clip-name-too-long = 片段名稱太長（最多{ $max_length }個字元）
+err-voice-clip-name-invalid = 片段名稱只能包含字母、數字、- 和 _
+err-voice-clip-too-large = 片段太大（最大256KB）
+err-voice-clip-invalid-format = 片段必須是 Ogg Vorbis 音訊檔案
+err-voice-clip-too-long = 片段太長（最長{ $max_seconds }秒）
+err-voice-clip-exists = 名為 '{ $name }' 的片段已存在
+err-voice-clip-not-found = 找不到片段 '{ $name }'
+err-voice-clip-library-full = 片段庫已滿（最多{ $max_clips }個片段）
@@ -0,0 +1,5 @@
+-- Add transfer queue configuration
+-- max_active_transfers caps simultaneous transfers server-wide (0 = unlimited)
+-- transfer_small_files_first lets smaller queued transfers start before larger ones
+INSERT INTO config (key, value) VALUES ('max_active_transfers', '0');
+INSERT INTO config (key, value) VALUES ('transfer_small_files_first', 'false');
@@ -0,0 +1,4 @@
+-- Add fair-share weight for transfers from trusted IPs
+-- The transfer scheduler divides a user's running transfers by this weight when
+-- deciding who starts next, so trusted IPs get a proportionally larger share
+INSERT INTO config (key, value) VALUES ('trusted_transfer_weight', '2');
@@ -0,0 +1,3 @@
+-- Add voice participant limit to persistent channel settings
+-- 0 means unlimited; joins beyond the limit wait in a queue
+ALTER TABLE channel_settings ADD COLUMN voice_limit INTEGER NOT NULL DEFAULT 0;
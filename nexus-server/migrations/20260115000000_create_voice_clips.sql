@@ -0,0 +1,13 @@
+-- Create voice_clips table for the voice soundboard library
+CREATE TABLE IF NOT EXISTS voice_clips (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL UNIQUE COLLATE NOCASE,
+    data TEXT NOT NULL,
+    size INTEGER NOT NULL,
+    duration_ms INTEGER NOT NULL,
+    uploader_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    created_at TEXT NOT NULL
+);
+
+-- Index for cascade deletes
+CREATE INDEX IF NOT EXISTS idx_voice_clips_uploader_id ON voice_clips(uploader_id);
@@ -0,0 +1,10 @@
+-- Create audit_log table for administrative actions that must leave a trace
+-- (e.g. account erasure, where the user's own data is gone afterwards)
+CREATE TABLE IF NOT EXISTS audit_log (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    action TEXT NOT NULL,
+    target TEXT NOT NULL,
+    actor TEXT NOT NULL,
+    details TEXT,
+    created_at INTEGER NOT NULL
+);
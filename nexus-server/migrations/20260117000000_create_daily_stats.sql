@@ -0,0 +1,12 @@
+-- Create daily_stats table for server activity statistics
+-- (one row per UTC day, counters are added by the server every minute)
+CREATE TABLE IF NOT EXISTS daily_stats (
+    day TEXT PRIMARY KEY,
+    logins INTEGER NOT NULL DEFAULT 0,
+    messages INTEGER NOT NULL DEFAULT 0,
+    uploads INTEGER NOT NULL DEFAULT 0,
+    upload_bytes INTEGER NOT NULL DEFAULT 0,
+    downloads INTEGER NOT NULL DEFAULT 0,
+    download_bytes INTEGER NOT NULL DEFAULT 0,
+    peak_sessions INTEGER NOT NULL DEFAULT 0
+);
@@ -0,0 +1,10 @@
+-- Create file_activity table for the most downloaded and recent uploads listings
+-- (one row per file, keyed by its path relative to the file root)
+CREATE TABLE IF NOT EXISTS file_activity (
+    path TEXT PRIMARY KEY,
+    downloads INTEGER NOT NULL DEFAULT 0,
+    uploaded_at INTEGER
+);
+
+CREATE INDEX IF NOT EXISTS idx_file_activity_downloads ON file_activity(downloads);
+CREATE INDEX IF NOT EXISTS idx_file_activity_uploaded_at ON file_activity(uploaded_at);
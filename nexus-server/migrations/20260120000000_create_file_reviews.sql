@@ -0,0 +1,20 @@
+-- Create file_ratings and file_comments tables for users' reviews of files
+-- (keyed by the file's path relative to the file root, like file_activity)
+CREATE TABLE IF NOT EXISTS file_ratings (
+    path TEXT NOT NULL,
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    rating INTEGER NOT NULL CHECK (rating BETWEEN 1 AND 5),
+    PRIMARY KEY (path, user_id)
+);
+
+CREATE TABLE IF NOT EXISTS file_comments (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    path TEXT NOT NULL,
+    author_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    body TEXT NOT NULL,
+    created_at INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_file_ratings_user_id ON file_ratings(user_id);
+CREATE INDEX IF NOT EXISTS idx_file_comments_path ON file_comments(path);
+CREATE INDEX IF NOT EXISTS idx_file_comments_author_id ON file_comments(author_id);
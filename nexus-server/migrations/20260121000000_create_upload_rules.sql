@@ -0,0 +1,9 @@
+-- Create upload_rules table for per-directory upload limits
+-- (keyed by the directory's path relative to the file root, like file_activity;
+-- extensions are a comma-separated list, empty for any type)
+CREATE TABLE IF NOT EXISTS upload_rules (
+    path TEXT PRIMARY KEY,
+    extensions TEXT NOT NULL DEFAULT '',
+    max_file_size INTEGER,
+    max_total_size INTEGER
+);
@@ -0,0 +1,12 @@
+-- Create upload_journal table for uploads in progress
+-- (keyed by the .part file's path relative to the file root, like file_activity;
+-- a row lives from the first byte written until the file is verified or removed)
+CREATE TABLE IF NOT EXISTS upload_journal (
+    part_path TEXT PRIMARY KEY,
+    target_path TEXT NOT NULL,
+    username TEXT NOT NULL,
+    sha256 TEXT NOT NULL,
+    size INTEGER NOT NULL,
+    bytes_received INTEGER NOT NULL DEFAULT 0,
+    updated_at INTEGER NOT NULL
+);
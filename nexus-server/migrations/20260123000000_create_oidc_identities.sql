@@ -0,0 +1,9 @@
+-- Create oidc_identities table linking identity provider subjects to accounts
+-- (a subject is only unique within its issuer; the link goes with the account)
+CREATE TABLE IF NOT EXISTS oidc_identities (
+    issuer TEXT NOT NULL,
+    subject TEXT NOT NULL,
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    created_at INTEGER NOT NULL,
+    PRIMARY KEY (issuer, subject)
+);
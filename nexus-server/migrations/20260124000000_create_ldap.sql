@@ -0,0 +1,14 @@
+-- Create ldap_groups table mapping directory groups to permissions
+-- (group DNs compare case-insensitively; permissions are a comma-separated list)
+CREATE TABLE IF NOT EXISTS ldap_groups (
+    group_dn TEXT PRIMARY KEY COLLATE NOCASE,
+    permissions TEXT NOT NULL DEFAULT '',
+    is_admin INTEGER NOT NULL DEFAULT 0
+);
+
+-- Create ldap_accounts table marking accounts created through the directory
+-- (such accounts only ever log in with their directory password)
+CREATE TABLE IF NOT EXISTS ldap_accounts (
+    user_id INTEGER PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+    dn TEXT NOT NULL
+);
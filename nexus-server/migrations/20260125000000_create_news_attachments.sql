@@ -0,0 +1,44 @@
+-- Move news images into a news_attachments table so a post can carry several
+-- attachments: images (data URIs) or references to files in the file area.
+-- The news table is rebuilt without its image column and without the CHECK
+-- constraint requiring a body or image (a post may now be attachments only;
+-- the server still rejects empty posts).
+ALTER TABLE news RENAME TO news_old;
+
+CREATE TABLE news (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    body TEXT,
+    author_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    created_at TEXT NOT NULL,
+    updated_at TEXT
+);
+
+INSERT INTO news (id, body, author_id, created_at, updated_at)
+SELECT id, body, author_id, created_at, updated_at FROM news_old;
+
+-- Keep the ID sequence so IDs of deleted posts aren't handed out again
+DELETE FROM sqlite_sequence WHERE name = 'news';
+INSERT INTO sqlite_sequence (name, seq)
+SELECT 'news', seq FROM sqlite_sequence WHERE name = 'news_old';
+
+-- Attachments in display order (kind is 'image' with a data URI in data,
+-- or 'file' with a file area path in data)
+CREATE TABLE IF NOT EXISTS news_attachments (
+    news_id INTEGER NOT NULL REFERENCES news(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    kind TEXT NOT NULL CHECK (kind IN ('image', 'file')),
+    data TEXT NOT NULL,
+    caption TEXT,
+    PRIMARY KEY (news_id, position)
+);
+
+INSERT INTO news_attachments (news_id, position, kind, data)
+SELECT id, 0, 'image', image FROM news_old WHERE image IS NOT NULL;
+
+DROP TABLE news_old;
+
+-- Index for faster author lookups and cascade deletes
+CREATE INDEX IF NOT EXISTS idx_news_author_id ON news(author_id);
+
+-- Index for ordering by creation time
+CREATE INDEX IF NOT EXISTS idx_news_created_at ON news(created_at);
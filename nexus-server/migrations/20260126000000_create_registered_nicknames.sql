@@ -0,0 +1,11 @@
+-- Create registered_nicknames table for nicknames reserved by accounts
+-- (shared account sessions can't use them; nicknames compare case-insensitively
+-- like usernames, and the reservation goes with the account)
+CREATE TABLE IF NOT EXISTS registered_nicknames (
+    nickname TEXT PRIMARY KEY COLLATE NOCASE,
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    created_at INTEGER NOT NULL
+);
+
+-- Index for counting an account's nicknames
+CREATE INDEX IF NOT EXISTS idx_registered_nicknames_user_id ON registered_nicknames(user_id);
@@ -0,0 +1,17 @@
+-- Add topic lock to persistent channel settings
+-- A locked topic can only be changed by users with chat_topic_lock
+ALTER TABLE channel_settings ADD COLUMN topic_locked INTEGER NOT NULL DEFAULT 0;
+
+-- Create channel_topic_history table for the topic changes of persistent channels
+-- (empty strings mean no topic / erased account, like channel_settings)
+CREATE TABLE IF NOT EXISTS channel_topic_history (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    channel TEXT NOT NULL COLLATE NOCASE,
+    topic TEXT NOT NULL DEFAULT '',
+    old_topic TEXT NOT NULL DEFAULT '',
+    set_by TEXT NOT NULL DEFAULT '',
+    changed_at INTEGER NOT NULL
+);
+
+-- Index for listing a channel's changes, newest first
+CREATE INDEX IF NOT EXISTS idx_channel_topic_history_channel ON channel_topic_history(channel, id);
@@ -0,0 +1,4 @@
+-- Add entry message and rules to persistent channel settings
+-- (empty strings mean none, like topic)
+ALTER TABLE channel_settings ADD COLUMN entry_message TEXT NOT NULL DEFAULT '';
+ALTER TABLE channel_settings ADD COLUMN rules TEXT NOT NULL DEFAULT '';
@@ -0,0 +1,25 @@
+-- Full-text index over news bodies for NewsSearch.
+-- An external content table: the text lives in news, the index only holds
+-- tokens, and triggers keep it in step with inserts, edits and deletes.
+CREATE VIRTUAL TABLE IF NOT EXISTS news_fts USING fts5(
+    body,
+    content = 'news',
+    content_rowid = 'id',
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+-- Index the posts that already exist
+INSERT INTO news_fts (news_fts) VALUES ('rebuild');
+
+CREATE TRIGGER IF NOT EXISTS news_fts_insert AFTER INSERT ON news BEGIN
+    INSERT INTO news_fts (rowid, body) VALUES (new.id, new.body);
+END;
+
+CREATE TRIGGER IF NOT EXISTS news_fts_delete AFTER DELETE ON news BEGIN
+    INSERT INTO news_fts (news_fts, rowid, body) VALUES ('delete', old.id, old.body);
+END;
+
+CREATE TRIGGER IF NOT EXISTS news_fts_update AFTER UPDATE OF body ON news BEGIN
+    INSERT INTO news_fts (news_fts, rowid, body) VALUES ('delete', old.id, old.body);
+    INSERT INTO news_fts (rowid, body) VALUES (new.id, new.body);
+END;
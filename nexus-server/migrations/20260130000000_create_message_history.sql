@@ -0,0 +1,28 @@
+-- Per-account direct message history
+--
+-- Each party that keeps history gets its own copy of a message, so one
+-- account's retention never affects another's. Message text is encrypted
+-- with the server's history key (history.key beside the database); the
+-- nickname columns stay readable so conversations can be listed.
+
+-- Days to keep the account's direct messages (0 = don't keep them)
+ALTER TABLE users ADD COLUMN message_history_days INTEGER NOT NULL DEFAULT 0;
+
+CREATE TABLE IF NOT EXISTS message_history (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    owner_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    peer_nickname TEXT NOT NULL COLLATE NOCASE,
+    from_nickname TEXT NOT NULL,
+    to_nickname TEXT NOT NULL,
+    is_action INTEGER NOT NULL DEFAULT 0,
+    nonce BLOB NOT NULL,
+    body BLOB NOT NULL,
+    created_at INTEGER NOT NULL
+);
+
+-- Index for paging through one conversation, or all of an account's
+CREATE INDEX IF NOT EXISTS idx_message_history_owner_peer
+    ON message_history(owner_id, peer_nickname, id);
+
+-- Index for expiring old messages
+CREATE INDEX IF NOT EXISTS idx_message_history_created_at ON message_history(created_at);
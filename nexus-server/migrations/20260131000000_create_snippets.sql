@@ -0,0 +1,20 @@
+-- Text snippets shared by link instead of pasted into chat
+--
+-- Ids are random letters and digits chosen by the server. Snippets are
+-- deleted once they expire, and with the account that shared them.
+
+CREATE TABLE IF NOT EXISTS snippets (
+    id TEXT PRIMARY KEY NOT NULL,
+    author_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    author_nickname TEXT NOT NULL,
+    language TEXT,
+    content TEXT NOT NULL,
+    created_at INTEGER NOT NULL,
+    expires_at INTEGER NOT NULL
+);
+
+-- Index for counting an account's snippets
+CREATE INDEX IF NOT EXISTS idx_snippets_author_id ON snippets(author_id);
+
+-- Index for deleting expired snippets
+CREATE INDEX IF NOT EXISTS idx_snippets_expires_at ON snippets(expires_at);
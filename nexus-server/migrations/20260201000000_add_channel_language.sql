@@ -0,0 +1,3 @@
+-- Add a language tag (e.g. "en", "pt-BR") to persistent channel settings
+-- (empty string means none, like topic)
+ALTER TABLE channel_settings ADD COLUMN language TEXT NOT NULL DEFAULT '';
@@ -0,0 +1,3 @@
+-- Add the time of each account's most recent login to its activity counters
+-- (Unix timestamp, NULL until the account logs in; written with the counters)
+ALTER TABLE user_stats ADD COLUMN last_login_at INTEGER;
@@ -1,12 +1,24 @@
 //! Command-line argument parsing
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use nexus_common::{
     DEFAULT_PORT, DEFAULT_TRANSFER_PORT, DEFAULT_TRANSFER_WEBSOCKET_PORT, DEFAULT_WEBSOCKET_PORT,
 };
 use std::net::IpAddr;
 use std::path::PathBuf;
 
+use crate::constants::{
+    DEFAULT_LDAP_USER_FILTER, DEFAULT_MAX_BYTES_PER_MINUTE, DEFAULT_MAX_GUESTS_PER_IP,
+    DEFAULT_MAX_MESSAGES_PER_SECOND, DEFAULT_MIN_FREE_SPACE, DEFAULT_NEWS_IMAGE_MAX_DIMENSION,
+    DEFAULT_OIDC_USERNAME_CLAIM, DEFAULT_STALE_UPLOAD_DAYS, DEFAULT_WEBDAV_PORT,
+};
+use crate::db::tuning::{JournalMode, Synchronous};
+use crate::doors::Door;
+use crate::protocol_limits::{self, FloodAction, FrameSizeLimit};
+use crate::ranks::Rank;
+use crate::reputation::ReputationPolicy;
+use crate::scanner::ScannerMitigation;
+
 /// Get default database path help text for current platform
 fn default_database_help() -> String {
     #[cfg(target_os = "linux")]
@@ -43,18 +55,29 @@ fn default_file_root_help() -> String {
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 pub struct Args {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// IP address to bind to (IPv4 or IPv6)
-    #[arg(short, long, default_value = "0.0.0.0")]
+    #[arg(short, long, default_value = "0.0.0.0", global = true)]
     pub bind: IpAddr,
 
     /// Port to listen on
-    #[arg(short, long, default_value_t = DEFAULT_PORT)]
+    #[arg(short, long, default_value_t = DEFAULT_PORT, global = true)]
     pub port: u16,
 
     /// Database file path (overrides platform default)
     #[arg(short, long, help = default_database_help())]
     pub database: Option<PathBuf>,
 
+    /// SQLite journal mode
+    #[arg(long, value_enum, default_value = "wal")]
+    pub db_journal_mode: JournalMode,
+
+    /// How often SQLite waits for writes to reach the disk
+    #[arg(long, value_enum, default_value = "normal")]
+    pub db_synchronous: Synchronous,
+
     /// File area root directory (overrides platform default)
     #[arg(short = 'f', long = "file-root", help = default_file_root_help())]
     pub file_root: Option<PathBuf>,
@@ -67,19 +90,209 @@ pub struct Args {
     #[arg(long, default_value = "false")]
     pub upnp: bool,
 
+    /// Advertise the server on the local network via mDNS/DNS-SD (Bonjour)
+    #[arg(long, default_value = "false")]
+    pub mdns: bool,
+
     /// Port for file transfers
-    #[arg(short = 't', long, default_value_t = DEFAULT_TRANSFER_PORT)]
+    #[arg(short = 't', long, default_value_t = DEFAULT_TRANSFER_PORT, global = true)]
     pub transfer_port: u16,
 
     /// Enable WebSocket support (ports 7502/7503 by default)
-    #[arg(long, default_value = "false")]
+    #[arg(long, default_value = "false", global = true)]
     pub websocket: bool,
 
     /// Port for WebSocket BBS connections (requires --websocket)
-    #[arg(long, default_value_t = DEFAULT_WEBSOCKET_PORT)]
+    #[arg(long, default_value_t = DEFAULT_WEBSOCKET_PORT, global = true)]
     pub websocket_port: u16,
 
     /// Port for WebSocket file transfers (requires --websocket)
-    #[arg(long, default_value_t = DEFAULT_TRANSFER_WEBSOCKET_PORT)]
+    #[arg(long, default_value_t = DEFAULT_TRANSFER_WEBSOCKET_PORT, global = true)]
     pub transfer_websocket_port: u16,
+
+    /// Enable the read-only WebDAV gateway to the file area (port 7504 by default)
+    #[arg(long, default_value = "false")]
+    pub webdav: bool,
+
+    /// Port for WebDAV connections (requires --webdav)
+    #[arg(long, default_value_t = DEFAULT_WEBDAV_PORT)]
+    pub webdav_port: u16,
+
+    /// OpenID Connect issuer URL for single sign-on (requires --oidc-client-id)
+    #[arg(long, value_name = "URL", requires = "oidc_client_id")]
+    pub oidc_issuer: Option<String>,
+
+    /// Client ID registered with the identity provider
+    #[arg(long, value_name = "ID", requires = "oidc_issuer")]
+    pub oidc_client_id: Option<String>,
+
+    /// Client secret, if the identity provider issued one
+    ///
+    /// Prefer the environment variable or --oidc-client-secret-file: command
+    /// lines are visible to other users of the machine.
+    #[arg(
+        long,
+        value_name = "SECRET",
+        env = "NEXUS_OIDC_CLIENT_SECRET",
+        hide_env_values = true
+    )]
+    pub oidc_client_secret: Option<String>,
+
+    /// File holding the client secret (instead of --oidc-client-secret)
+    #[arg(long, value_name = "FILE", conflicts_with = "oidc_client_secret")]
+    pub oidc_client_secret_file: Option<PathBuf>,
+
+    /// ID token claim that names new single sign-on accounts
+    #[arg(long, value_name = "CLAIM", default_value = DEFAULT_OIDC_USERNAME_CLAIM)]
+    pub oidc_username_claim: String,
+
+    /// Account whose permissions new single sign-on accounts get (none by default)
+    #[arg(long, value_name = "USERNAME")]
+    pub oidc_role_account: Option<String>,
+
+    /// LDAP or Active Directory server for directory logins, as ldaps://host or
+    /// ldap://host (requires --ldap-bind-dn and --ldap-search-base)
+    #[arg(long, value_name = "URL", requires_all = ["ldap_bind_dn", "ldap_search_base"])]
+    pub ldap_url: Option<String>,
+
+    /// DN to bind as, with {username} in it (e.g. uid={username},ou=people,dc=example,dc=com,
+    /// or {username}@example.com for Active Directory)
+    #[arg(long, value_name = "DN", requires = "ldap_url")]
+    pub ldap_bind_dn: Option<String>,
+
+    /// Where to search for user entries (e.g. dc=example,dc=com)
+    #[arg(long, value_name = "DN", requires = "ldap_url")]
+    pub ldap_search_base: Option<String>,
+
+    /// Filter that finds a user's entry, with {username} in it
+    #[arg(long, value_name = "FILTER", default_value = DEFAULT_LDAP_USER_FILTER)]
+    pub ldap_user_filter: String,
+
+    /// PEM file with CA certificates to trust for ldaps:// (e.g. an internal CA)
+    #[arg(long, value_name = "FILE", requires = "ldap_url")]
+    pub ldap_ca_cert: Option<String>,
+
+    /// Maximum guest sessions per IP address (0 = unlimited)
+    #[arg(long, value_name = "N", default_value_t = DEFAULT_MAX_GUESTS_PER_IP)]
+    pub max_guests_per_ip: u32,
+
+    /// Let guests register their own account, with this account's permissions
+    #[arg(long, value_name = "USERNAME")]
+    pub guest_upgrade_role: Option<String>,
+
+    /// Host clients should send voice traffic to, if it differs from the BBS host
+    /// (e.g. behind NAT or a UDP proxy)
+    #[arg(long, value_name = "HOST")]
+    pub voice_advertise_address: Option<String>,
+
+    /// Port clients should send voice traffic to, if it differs from the BBS port
+    #[arg(long, value_name = "PORT")]
+    pub voice_advertise_port: Option<u16>,
+
+    /// Let clients whose UDP is blocked relay voice over their TCP connection
+    #[arg(long, default_value = "false")]
+    pub voice_tcp_relay: bool,
+
+    /// Delete news posts older than this many days (checked hourly)
+    #[arg(long, value_name = "DAYS", value_parser = clap::value_parser!(u32).range(1..))]
+    pub news_retention_days: Option<u32>,
+
+    /// Largest news image to store, with an optional K or M suffix (at most 512K);
+    /// bigger images are scaled down to fit
+    #[arg(
+        long,
+        value_name = "BYTES",
+        default_value = "512K",
+        value_parser = protocol_limits::parse_byte_count
+    )]
+    pub news_image_max_size: u64,
+
+    /// Scale news images down so neither side is longer than this many pixels
+    /// (0 = no limit)
+    #[arg(long, value_name = "N", default_value_t = DEFAULT_NEWS_IMAGE_MAX_DIMENSION)]
+    pub news_image_max_dimension: u32,
+
+    /// Delete unfinished uploads left untouched for this many days (checked hourly)
+    #[arg(long, value_name = "DAYS", default_value_t = DEFAULT_STALE_UPLOAD_DAYS, value_parser = clap::value_parser!(u32).range(1..))]
+    pub stale_upload_days: u32,
+
+    /// Free space to keep on the file area and database disks, with an optional
+    /// K, M, G or T suffix; uploads that would go below it are refused (0 = off)
+    #[arg(
+        long,
+        value_name = "BYTES",
+        default_value = DEFAULT_MIN_FREE_SPACE,
+        value_parser = protocol_limits::parse_byte_count
+    )]
+    pub min_free_space: u64,
+
+    /// https:// URL to post a JSON alert to when free space drops below
+    /// --min-free-space or recovers
+    #[arg(long, value_name = "URL")]
+    pub storage_webhook: Option<String>,
+
+    /// DNS blocklist zone to check connecting addresses against (repeatable)
+    #[arg(long = "dnsbl", value_name = "ZONE")]
+    pub dnsbl_zones: Vec<String>,
+
+    /// What to do with connections from addresses on a DNS blocklist
+    #[arg(long, value_enum, default_value = "tarpit")]
+    pub dnsbl_policy: ReputationPolicy,
+
+    /// How to handle port scanners and other non-TLS probes on the TCP ports
+    #[arg(long, value_enum, default_value = "off")]
+    pub scanner_mitigation: ScannerMitigation,
+
+    /// Lower the largest payload clients may send for a message type (repeatable),
+    /// e.g. "ChatSend=4K"
+    #[arg(long = "max-frame-size", value_name = "TYPE=BYTES")]
+    pub max_frame_sizes: Vec<FrameSizeLimit>,
+
+    /// Messages a connection may send per second (0 = unlimited)
+    #[arg(long, value_name = "N", default_value_t = DEFAULT_MAX_MESSAGES_PER_SECOND)]
+    pub max_messages_per_second: u32,
+
+    /// Bytes a connection may send per minute, with an optional K, M or G suffix
+    /// (0 = unlimited)
+    #[arg(
+        long,
+        value_name = "BYTES",
+        default_value_t = DEFAULT_MAX_BYTES_PER_MINUTE,
+        value_parser = protocol_limits::parse_byte_count
+    )]
+    pub max_bytes_per_minute: u64,
+
+    /// What to do with connections over the message or byte rate limit
+    #[arg(long, value_enum, default_value = "throttle")]
+    pub flood_action: FloodAction,
+
+    /// Check service used to test whether the ports are reachable from outside
+    /// (a nexusd started with --check-responder-port)
+    #[arg(long, value_name = "HOST:PORT", global = true)]
+    pub check_service: Option<String>,
+
+    /// Answer connectivity checks from other servers on this TCP port
+    #[arg(long, value_name = "PORT")]
+    pub check_responder_port: Option<u16>,
+
+    /// Rank earned from account activity, lowest first (repeatable),
+    /// e.g. "Elite:uploads=50,downloaded=10G"
+    #[arg(long = "rank", value_name = "NAME[:STAT=N,...]")]
+    pub ranks: Vec<Rank>,
+
+    /// External program users can run in a terminal (repeatable),
+    /// e.g. "LORD=/opt/doors/lord/start.sh"
+    #[arg(long = "door", value_name = "NAME=COMMAND")]
+    pub doors: Vec<Door>,
+
+    /// Import a server export archive into a fresh database, then exit
+    #[arg(long, value_name = "FILE")]
+    pub import: Option<PathBuf>,
+}
+
+/// Commands run instead of the server
+#[derive(Subcommand, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    /// Test whether the ports are reachable from outside, then exit
+    Check,
 }
@@ -0,0 +1,297 @@
+//! Targeted, scheduled and acknowledged broadcasts
+//!
+//! A broadcast goes to everyone, to a channel's members, or to users with a
+//! permission. The sender's own session always gets a copy, but sessions of
+//! the sender's account don't count as recipients.
+//!
+//! - A broadcast with a delay waits here until the scheduler task sends it;
+//!   the sender then gets a `BroadcastStatus` with the delivery count.
+//! - When the sender asks for acknowledgement, recipients answer with
+//!   `BroadcastAck` and the sender gets a `BroadcastStatus` for each one, for
+//!   [`BROADCAST_ACK_TRACKING_SECS`] after the broadcast went out.
+//!
+//! Both only live in memory, so a server restart drops scheduled broadcasts.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use chrono::Utc;
+use nexus_common::protocol::{BroadcastTarget, ServerMessage};
+
+use crate::channels::ChannelManager;
+use crate::constants::{BROADCAST_ACK_TRACKING_SECS, MAX_SCHEDULED_BROADCASTS};
+use crate::db::Permission;
+use crate::users::UserManager;
+
+/// A broadcast to send, now or when it is due
+#[derive(Debug, Clone)]
+pub struct Broadcast {
+    /// ID the sender matches `BroadcastStatus` messages with
+    pub id: u64,
+    /// Session that sent the broadcast
+    pub sender_session_id: u32,
+    /// Account that sent the broadcast (gets the status updates)
+    pub sender_username: String,
+    pub message: String,
+    pub target: BroadcastTarget,
+    /// Whether recipients are asked to acknowledge it
+    pub require_ack: bool,
+}
+
+/// Acknowledgements of a sent broadcast
+struct AckTracker {
+    /// Account that sent the broadcast
+    sender_username: String,
+    /// Sessions the broadcast reached
+    recipients: HashSet<u32>,
+    /// Sessions that acknowledged it
+    acknowledged: HashSet<u32>,
+    /// When the broadcast went out
+    sent_at: i64,
+}
+
+/// Scheduled broadcasts and acknowledgement counts
+///
+/// Cheap to clone; clones share the same broadcasts.
+#[derive(Clone, Default)]
+pub struct BroadcastManager {
+    /// Next broadcast ID
+    next_id: Arc<AtomicU64>,
+    /// Broadcasts waiting to be sent, with their send time
+    scheduled: Arc<Mutex<Vec<(i64, Broadcast)>>>,
+    /// Broadcasts whose acknowledgements are counted, by broadcast ID
+    acks: Arc<Mutex<HashMap<u64, AckTracker>>>,
+}
+
+impl BroadcastManager {
+    /// Create a manager with nothing scheduled
+    pub fn new() -> Self {
+        Self {
+            next_id: Arc::new(AtomicU64::new(1)),
+            ..Self::default()
+        }
+    }
+
+    /// Lock the scheduled broadcasts
+    fn scheduled(&self) -> MutexGuard<'_, Vec<(i64, Broadcast)>> {
+        self.scheduled
+            .lock()
+            .expect("scheduled broadcast lock poisoned")
+    }
+
+    /// Lock the acknowledgement table
+    fn acks(&self) -> MutexGuard<'_, HashMap<u64, AckTracker>> {
+        self.acks.lock().expect("broadcast ack lock poisoned")
+    }
+
+    /// Allocate an ID for a new broadcast
+    pub fn next_id(&self) -> u64 {
+        self.next_id.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// Queue a broadcast to be sent at `send_at` (Unix timestamp)
+    ///
+    /// Returns false if [`MAX_SCHEDULED_BROADCASTS`] are already waiting.
+    pub fn schedule(&self, send_at: i64, broadcast: Broadcast) -> bool {
+        let mut scheduled = self.scheduled();
+        if scheduled.len() >= MAX_SCHEDULED_BROADCASTS {
+            return false;
+        }
+        scheduled.push((send_at, broadcast));
+        true
+    }
+
+    /// Remove and return the scheduled broadcasts that are due at `now`
+    fn take_due(&self, now: i64) -> Vec<Broadcast> {
+        let mut scheduled = self.scheduled();
+        let (due, waiting): (Vec<_>, Vec<_>) = scheduled
+            .drain(..)
+            .partition(|(send_at, _)| *send_at <= now);
+        *scheduled = waiting;
+        due.into_iter().map(|(_, broadcast)| broadcast).collect()
+    }
+
+    /// Send a broadcast to its target, returning how many sessions it reached
+    ///
+    /// Starts counting acknowledgements if the broadcast asks for them.
+    pub async fn deliver(
+        &self,
+        broadcast: &Broadcast,
+        user_manager: &UserManager,
+        channel_manager: &ChannelManager,
+    ) -> u32 {
+        let members: HashSet<u32> = match &broadcast.target {
+            BroadcastTarget::Channel { channel } => channel_manager
+                .get_members(channel)
+                .await
+                .unwrap_or_default()
+                .into_iter()
+                .collect(),
+            _ => HashSet::new(),
+        };
+        let permission = match &broadcast.target {
+            BroadcastTarget::Permission { permission } => Permission::parse(permission),
+            _ => None,
+        };
+
+        let sender_lower = broadcast.sender_username.to_lowercase();
+        let mut recipients = HashSet::new();
+        user_manager
+            .broadcast_where(
+                ServerMessage::ServerBroadcast {
+                    session_id: broadcast.sender_session_id,
+                    username: broadcast.sender_username.clone(),
+                    message: broadcast.message.clone(),
+                    broadcast_id: broadcast.require_ack.then_some(broadcast.id),
+                },
+                |user| {
+                    let targeted = match &broadcast.target {
+                        BroadcastTarget::All => true,
+                        BroadcastTarget::Channel { .. } => members.contains(&user.session_id),
+                        BroadcastTarget::Permission { .. } => {
+                            permission.is_some_and(|permission| user.has_permission(permission))
+                        }
+                    };
+                    if targeted && user.username.to_lowercase() != sender_lower {
+                        recipients.insert(user.session_id);
+                    }
+                    targeted || user.session_id == broadcast.sender_session_id
+                },
+            )
+            .await;
+
+        let delivered = recipients.len() as u32;
+        if broadcast.require_ack {
+            let now = Utc::now().timestamp();
+            let mut acks = self.acks();
+            acks.retain(|_, tracker| tracker.sent_at > now - BROADCAST_ACK_TRACKING_SECS);
+            acks.insert(
+                broadcast.id,
+                AckTracker {
+                    sender_username: broadcast.sender_username.clone(),
+                    recipients,
+                    acknowledged: HashSet::new(),
+                    sent_at: now,
+                },
+            );
+        }
+        delivered
+    }
+
+    /// Send the scheduled broadcasts that are due
+    ///
+    /// Each sender is told how many sessions their broadcast reached.
+    pub async fn send_due(&self, user_manager: &UserManager, channel_manager: &ChannelManager) {
+        for broadcast in self.take_due(Utc::now().timestamp()) {
+            let delivered = self
+                .deliver(&broadcast, user_manager, channel_manager)
+                .await;
+            let status = ServerMessage::BroadcastStatus {
+                broadcast_id: broadcast.id,
+                delivered,
+                acknowledged: broadcast.require_ack.then_some(0),
+            };
+            user_manager
+                .broadcast_to_username(&broadcast.sender_username, &status)
+                .await;
+        }
+    }
+
+    /// Record a recipient's acknowledgement of a broadcast
+    ///
+    /// Returns the sender's username and the updated status to send them, or
+    /// None if the broadcast isn't tracked, didn't reach this session, or was
+    /// already acknowledged by it.
+    pub fn acknowledge(
+        &self,
+        broadcast_id: u64,
+        session_id: u32,
+    ) -> Option<(String, ServerMessage)> {
+        let mut acks = self.acks();
+        let tracker = acks.get_mut(&broadcast_id)?;
+        if tracker.sent_at <= Utc::now().timestamp() - BROADCAST_ACK_TRACKING_SECS
+            || !tracker.recipients.contains(&session_id)
+            || !tracker.acknowledged.insert(session_id)
+        {
+            return None;
+        }
+
+        Some((
+            tracker.sender_username.clone(),
+            ServerMessage::BroadcastStatus {
+                broadcast_id,
+                delivered: tracker.recipients.len() as u32,
+                acknowledged: Some(tracker.acknowledged.len() as u32),
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_broadcast(broadcasts: &BroadcastManager, require_ack: bool) -> Broadcast {
+        Broadcast {
+            id: broadcasts.next_id(),
+            sender_session_id: 1,
+            sender_username: "alice".to_string(),
+            message: "Hello".to_string(),
+            target: BroadcastTarget::All,
+            require_ack,
+        }
+    }
+
+    #[test]
+    fn test_take_due_keeps_later_broadcasts() {
+        let broadcasts = BroadcastManager::new();
+        let due = test_broadcast(&broadcasts, false);
+        let waiting = test_broadcast(&broadcasts, false);
+        assert!(broadcasts.schedule(100, due.clone()));
+        assert!(broadcasts.schedule(105, waiting.clone()));
+
+        let taken: Vec<u64> = broadcasts.take_due(100).iter().map(|b| b.id).collect();
+        assert_eq!(taken, vec![due.id]);
+
+        let taken: Vec<u64> = broadcasts.take_due(105).iter().map(|b| b.id).collect();
+        assert_eq!(taken, vec![waiting.id]);
+        assert!(broadcasts.take_due(i64::MAX).is_empty());
+    }
+
+    #[test]
+    fn test_acknowledge_counts_each_recipient_once() {
+        let broadcasts = BroadcastManager::new();
+        let broadcast_id = broadcasts.next_id();
+        broadcasts.acks().insert(
+            broadcast_id,
+            AckTracker {
+                sender_username: "alice".to_string(),
+                recipients: HashSet::from([2, 3]),
+                acknowledged: HashSet::new(),
+                sent_at: Utc::now().timestamp(),
+            },
+        );
+
+        match broadcasts.acknowledge(broadcast_id, 2) {
+            Some((
+                sender,
+                ServerMessage::BroadcastStatus {
+                    delivered,
+                    acknowledged,
+                    ..
+                },
+            )) => {
+                assert_eq!(sender, "alice");
+                assert_eq!(delivered, 2);
+                assert_eq!(acknowledged, Some(1));
+            }
+            other => panic!("expected BroadcastStatus, got {other:?}"),
+        }
+
+        // Twice from the same session, from a session it didn't reach, or unknown ID
+        assert!(broadcasts.acknowledge(broadcast_id, 2).is_none());
+        assert!(broadcasts.acknowledge(broadcast_id, 4).is_none());
+        assert!(broadcasts.acknowledge(broadcasts.next_id(), 2).is_none());
+    }
+}
@@ -12,10 +12,12 @@ use std::sync::Arc;
 
 use tokio::sync::RwLock;
 
-use nexus_common::validators::MAX_CHANNELS_PER_USER;
+use nexus_common::protocol::ChatTopicChange;
+use nexus_common::validators::{MAX_CHANNELS_PER_USER, MAX_CHAT_TOPIC_HISTORY};
 
 use super::types::{Channel, ChannelListInfo, JoinError, JoinResult, LeaveResult};
 use crate::db::ChannelDb;
+use crate::handlers::current_timestamp;
 use crate::users::UserManager;
 
 /// Manages all chat channels
@@ -126,6 +128,11 @@ impl ChannelManager {
                 topic: ch.topic.clone(),
                 topic_set_by: ch.topic_set_by.clone(),
                 secret: ch.secret,
+                voice_limit: ch.voice_limit,
+                topic_locked: ch.topic_locked,
+                entry_message: ch.entry_message.clone(),
+                rules: ch.rules.clone(),
+                language: ch.language.clone(),
                 member_session_ids: ch.members.iter().copied().collect(),
             });
         }
@@ -150,6 +157,11 @@ impl ChannelManager {
             topic: channel.topic.clone(),
             topic_set_by: channel.topic_set_by.clone(),
             secret: channel.secret,
+            voice_limit: channel.voice_limit,
+            topic_locked: channel.topic_locked,
+            entry_message: channel.entry_message.clone(),
+            rules: channel.rules.clone(),
+            language: channel.language.clone(),
             member_session_ids: channel.members.iter().copied().collect(),
         })
     }
@@ -218,21 +230,35 @@ impl ChannelManager {
     ///
     /// Secret channels are hidden unless the session is a member or is_admin is true.
     pub async fn list(&self, session_id: u32, is_admin: bool) -> Vec<ChannelListInfo> {
+        self.list_for_sessions(&[session_id], is_admin).await
+    }
+
+    /// List channels visible to any of a user's sessions
+    ///
+    /// Same rules as `list()`; used to see the channel list as another user
+    /// would (who may be logged in several times, or not at all).
+    pub async fn list_for_sessions(
+        &self,
+        session_ids: &[u32],
+        is_admin: bool,
+    ) -> Vec<ChannelListInfo> {
         // Collect the channels we want to list while holding the channels lock,
         // then do async user lookups after releasing it.
-        let channels_to_list: Vec<(String, Option<String>, bool, Vec<u32>)> = {
+        type ListedChannel = (String, Option<String>, bool, Option<String>, Vec<u32>);
+        let channels_to_list: Vec<ListedChannel> = {
             let channels = self.channels.read().await;
             channels
                 .values()
                 .filter(|ch| {
                     // Show non-secret channels, or secret channels if admin or member
-                    !ch.secret || is_admin || ch.has_member(session_id)
+                    !ch.secret || is_admin || session_ids.iter().any(|&sid| ch.has_member(sid))
                 })
                 .map(|ch| {
                     (
                         ch.name.clone(),
                         ch.topic.clone(),
                         ch.secret,
+                        ch.language.clone(),
                         ch.members.iter().copied().collect(),
                     )
                 })
@@ -241,7 +267,7 @@ impl ChannelManager {
 
         let mut results = Vec::with_capacity(channels_to_list.len());
 
-        for (name, topic, secret, member_session_ids) in channels_to_list {
+        for (name, topic, secret, language, member_session_ids) in channels_to_list {
             // Member counts are nicknames (deduped), not sessions.
             // We don't store nicknames in ChannelManager, so count unique nicknames by
             // deriving them from the joined sessions when we build the listing.
@@ -259,6 +285,7 @@ impl ChannelManager {
                 topic,
                 member_count: seen.len() as u32,
                 secret,
+                language,
             });
         }
 
@@ -323,6 +350,232 @@ impl ChannelManager {
         Ok(true)
     }
 
+    /// Change the topic for a channel and record the change in its topic history
+    ///
+    /// Like `set_topic`, but the change is also added to the channel's history
+    /// under `changed_by` (a nickname). Only the newest `MAX_CHAT_TOPIC_HISTORY`
+    /// changes are kept.
+    ///
+    /// Returns Ok(true) if channel exists and was updated, Ok(false) if channel doesn't exist.
+    /// Returns Err on database error (only possible for persistent channels).
+    pub async fn change_topic(
+        &self,
+        channel_name: &str,
+        topic: Option<String>,
+        set_by: Option<String>,
+        changed_by: &str,
+    ) -> io::Result<bool> {
+        let key = channel_name.to_lowercase();
+        let mut channels = self.channels.write().await;
+
+        let Some(channel) = channels.get_mut(&key) else {
+            return Ok(false);
+        };
+
+        let change = ChatTopicChange {
+            topic: topic.clone(),
+            old_topic: std::mem::replace(&mut channel.topic, topic.clone()),
+            set_by: Some(changed_by.to_string()),
+            changed_at: current_timestamp() as u64,
+        };
+        channel.topic_set_by = set_by.clone();
+
+        // Ephemeral channels keep their history in memory
+        let persistent = self.persistent_channels.read().await;
+        if !persistent.contains(&key) {
+            channel.topic_history.push_front(change);
+            channel.topic_history.truncate(MAX_CHAT_TOPIC_HISTORY);
+            return Ok(true);
+        }
+
+        drop(channels); // Release lock before async DB calls
+        let topic_str = topic.as_deref().unwrap_or("");
+        let set_by_str = set_by.as_deref().unwrap_or("");
+        self.db
+            .set_topic(channel_name, topic_str, set_by_str)
+            .await?;
+        self.db
+            .add_topic_change(channel_name, &change, MAX_CHAT_TOPIC_HISTORY)
+            .await?;
+
+        Ok(true)
+    }
+
+    /// Get the recent topic changes of a channel, newest first
+    ///
+    /// Returns Ok(None) if the channel doesn't exist.
+    /// Returns Err on database error (only possible for persistent channels).
+    pub async fn topic_history(
+        &self,
+        channel_name: &str,
+    ) -> io::Result<Option<Vec<ChatTopicChange>>> {
+        let key = channel_name.to_lowercase();
+        let channels = self.channels.read().await;
+
+        let Some(channel) = channels.get(&key) else {
+            return Ok(None);
+        };
+
+        let persistent = self.persistent_channels.read().await;
+        if !persistent.contains(&key) {
+            return Ok(Some(channel.topic_history.iter().cloned().collect()));
+        }
+
+        drop(channels); // Release lock before async DB call
+        self.db.get_topic_history(channel_name).await.map(Some)
+    }
+
+    /// Lock or unlock the topic for a channel
+    ///
+    /// Returns Ok(true) if channel exists and was updated, Ok(false) if channel doesn't exist.
+    /// Returns Err on database error (only possible for persistent channels).
+    pub async fn set_topic_locked(&self, channel_name: &str, locked: bool) -> io::Result<bool> {
+        let key = channel_name.to_lowercase();
+        let mut channels = self.channels.write().await;
+
+        let Some(channel) = channels.get_mut(&key) else {
+            return Ok(false);
+        };
+
+        channel.topic_locked = locked;
+
+        // Persist to database for persistent channels
+        let persistent = self.persistent_channels.read().await;
+        if persistent.contains(&key) {
+            drop(channels); // Release lock before async DB call
+            self.db.set_topic_locked(channel_name, locked).await?;
+        }
+
+        Ok(true)
+    }
+
+    /// Set the entry message for a channel (None = no entry message)
+    ///
+    /// Returns Ok(true) if channel exists and was updated, Ok(false) if channel doesn't exist.
+    /// Returns Err on database error (only possible for persistent channels).
+    pub async fn set_entry_message(
+        &self,
+        channel_name: &str,
+        entry_message: Option<String>,
+    ) -> io::Result<bool> {
+        let key = channel_name.to_lowercase();
+        let mut channels = self.channels.write().await;
+
+        let Some(channel) = channels.get_mut(&key) else {
+            return Ok(false);
+        };
+
+        let entry_message_str = entry_message.clone().unwrap_or_default();
+        channel.entry_message = entry_message;
+
+        // Persist to database for persistent channels
+        let persistent = self.persistent_channels.read().await;
+        if persistent.contains(&key) {
+            drop(channels); // Release lock before async DB call
+            self.db
+                .set_entry_message(channel_name, &entry_message_str)
+                .await?;
+        }
+
+        Ok(true)
+    }
+
+    /// Set the rules for a channel (None = no rules)
+    ///
+    /// Returns Ok(true) if channel exists and was updated, Ok(false) if channel doesn't exist.
+    /// Returns Err on database error (only possible for persistent channels).
+    pub async fn set_rules(&self, channel_name: &str, rules: Option<String>) -> io::Result<bool> {
+        let key = channel_name.to_lowercase();
+        let mut channels = self.channels.write().await;
+
+        let Some(channel) = channels.get_mut(&key) else {
+            return Ok(false);
+        };
+
+        let rules_str = rules.clone().unwrap_or_default();
+        channel.rules = rules;
+
+        // Persist to database for persistent channels
+        let persistent = self.persistent_channels.read().await;
+        if persistent.contains(&key) {
+            drop(channels); // Release lock before async DB call
+            self.db.set_rules(channel_name, &rules_str).await?;
+        }
+
+        Ok(true)
+    }
+
+    /// Set the language tag for a channel (None = no language)
+    ///
+    /// Returns Ok(true) if channel exists and was updated, Ok(false) if channel doesn't exist.
+    /// Returns Err on database error (only possible for persistent channels).
+    pub async fn set_language(
+        &self,
+        channel_name: &str,
+        language: Option<String>,
+    ) -> io::Result<bool> {
+        let key = channel_name.to_lowercase();
+        let mut channels = self.channels.write().await;
+
+        let Some(channel) = channels.get_mut(&key) else {
+            return Ok(false);
+        };
+
+        let language_str = language.clone().unwrap_or_default();
+        channel.language = language;
+
+        // Persist to database for persistent channels
+        let persistent = self.persistent_channels.read().await;
+        if persistent.contains(&key) {
+            drop(channels); // Release lock before async DB call
+            self.db.set_language(channel_name, &language_str).await?;
+        }
+
+        Ok(true)
+    }
+
+    /// Check if a channel's topic is locked
+    ///
+    /// Returns false if the channel doesn't exist.
+    pub async fn is_topic_locked(&self, channel_name: &str) -> bool {
+        let key = channel_name.to_lowercase();
+        let channels = self.channels.read().await;
+        channels.get(&key).is_some_and(|ch| ch.topic_locked)
+    }
+
+    /// Set the voice participant limit for a channel (0 = unlimited)
+    ///
+    /// Returns Ok(true) if channel exists and was updated, Ok(false) if channel doesn't exist.
+    /// Returns Err on database error (only possible for persistent channels).
+    pub async fn set_voice_limit(&self, channel_name: &str, voice_limit: u32) -> io::Result<bool> {
+        let key = channel_name.to_lowercase();
+        let mut channels = self.channels.write().await;
+
+        let Some(channel) = channels.get_mut(&key) else {
+            return Ok(false);
+        };
+
+        channel.voice_limit = voice_limit;
+
+        // Persist to database for persistent channels
+        let persistent = self.persistent_channels.read().await;
+        if persistent.contains(&key) {
+            drop(channels); // Release lock before async DB call
+            self.db.set_voice_limit(channel_name, voice_limit).await?;
+        }
+
+        Ok(true)
+    }
+
+    /// Get the voice participant limit for a channel
+    ///
+    /// Returns None if the channel doesn't exist, Some(0) if it is unlimited.
+    pub async fn get_voice_limit(&self, channel_name: &str) -> Option<u32> {
+        let key = channel_name.to_lowercase();
+        let channels = self.channels.read().await;
+        channels.get(&key).map(|ch| ch.voice_limit)
+    }
+
     /// Check if a session is a member of a channel
     pub async fn is_member(&self, channel_name: &str, session_id: u32) -> bool {
         let key = channel_name.to_lowercase();
@@ -340,6 +593,18 @@ impl ChannelManager {
         channels.contains_key(&key)
     }
 
+    /// Check if a channel exists and a session may see it
+    ///
+    /// Secret channels are only visible to their members and admins.
+    pub async fn is_visible(&self, channel_name: &str, session_id: u32, is_admin: bool) -> bool {
+        let key = channel_name.to_lowercase();
+        let channels = self.channels.read().await;
+
+        channels
+            .get(&key)
+            .is_some_and(|ch| is_admin || !ch.secret || ch.has_member(session_id))
+    }
+
     /// Get member session IDs for a channel
     ///
     /// Returns None if the channel doesn't exist.
@@ -372,6 +637,33 @@ impl ChannelManager {
         result
     }
 
+    /// Forget who set channel topics, for channels whose topic was set by `nickname`
+    ///
+    /// Used when an account is erased. The topics themselves are kept, and so
+    /// are their topic history entries, without the nickname.
+    pub async fn clear_topic_setter(&self, nickname: &str) -> io::Result<()> {
+        let matching: Vec<(String, Option<String>)> = {
+            let mut channels = self.channels.write().await;
+            for change in channels
+                .values_mut()
+                .flat_map(|ch| ch.topic_history.iter_mut())
+                .filter(|change| change.set_by.as_deref() == Some(nickname))
+            {
+                change.set_by = None;
+            }
+            channels
+                .values()
+                .filter(|ch| ch.topic_set_by.as_deref() == Some(nickname))
+                .map(|ch| (ch.name.clone(), ch.topic.clone()))
+                .collect()
+        };
+
+        for (name, topic) in matching {
+            self.set_topic(&name, topic, None).await?;
+        }
+        self.db.clear_topic_history_setter(nickname).await
+    }
+
     /// Get channel info (for checking secret status, etc.)
     #[cfg(test)]
     pub async fn get_channel(&self, channel_name: &str) -> Option<Channel> {
@@ -413,6 +705,8 @@ mod tests {
                 Some("Get help here".to_string()),
                 Some("admin".to_string()),
                 false,
+                0,
+                false,
             ),
         ];
 
@@ -610,6 +904,24 @@ mod tests {
         assert_eq!(list.len(), 2);
     }
 
+    #[tokio::test]
+    async fn test_list_for_sessions_includes_secret_channels_of_any_session() {
+        let manager = create_test_manager().await;
+
+        manager.join("#public", 1).await.unwrap();
+        manager.join("#secret", 2).await.unwrap();
+        manager.set_secret("#secret", true).await.unwrap();
+
+        // Sessions 1 and 2 belong to the same user
+        let list = manager.list_for_sessions(&[1, 2], false).await;
+        assert_eq!(list.len(), 2);
+
+        // A user with no sessions only sees non-secret channels
+        let list = manager.list_for_sessions(&[], false).await;
+        assert_eq!(list.len(), 1);
+        assert_eq!(list[0].name, "#public");
+    }
+
     #[tokio::test]
     async fn test_set_secret() {
         let manager = create_test_manager().await;
@@ -623,6 +935,22 @@ mod tests {
         assert!(channel.secret);
     }
 
+    #[tokio::test]
+    async fn test_set_voice_limit() {
+        let manager = create_test_manager().await;
+
+        manager.join("#general", 1).await.unwrap();
+        assert_eq!(manager.get_voice_limit("#general").await, Some(0));
+
+        let result = manager.set_voice_limit("#General", 4).await.unwrap();
+        assert!(result);
+        assert_eq!(manager.get_voice_limit("#general").await, Some(4));
+
+        let result = manager.set_voice_limit("#nonexistent", 4).await.unwrap();
+        assert!(!result);
+        assert_eq!(manager.get_voice_limit("#nonexistent").await, None);
+    }
+
     #[tokio::test]
     async fn test_set_topic() {
         let manager = create_test_manager().await;
@@ -643,6 +971,151 @@ mod tests {
         assert_eq!(channel.topic_set_by, Some("admin".to_string()));
     }
 
+    #[tokio::test]
+    async fn test_change_topic_records_history() {
+        let manager = create_test_manager().await;
+
+        manager.join("#general", 1).await.unwrap();
+        manager
+            .change_topic("#general", Some("One".to_string()), None, "alice")
+            .await
+            .unwrap();
+        manager
+            .change_topic("#General", None, None, "bob")
+            .await
+            .unwrap();
+
+        let history = manager.topic_history("#general").await.unwrap().unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].topic, None);
+        assert_eq!(history[0].old_topic, Some("One".to_string()));
+        assert_eq!(history[0].set_by, Some("bob".to_string()));
+        assert_eq!(history[1].old_topic, None);
+
+        for n in 0..MAX_CHAT_TOPIC_HISTORY {
+            manager
+                .change_topic("#general", Some(n.to_string()), None, "alice")
+                .await
+                .unwrap();
+        }
+        let history = manager.topic_history("#general").await.unwrap().unwrap();
+        assert_eq!(history.len(), MAX_CHAT_TOPIC_HISTORY);
+
+        assert!(
+            manager
+                .topic_history("#nonexistent")
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_topic_locked() {
+        let manager = create_test_manager().await;
+
+        manager.join("#general", 1).await.unwrap();
+        assert!(!manager.is_topic_locked("#general").await);
+
+        assert!(manager.set_topic_locked("#General", true).await.unwrap());
+        assert!(manager.is_topic_locked("#general").await);
+        assert!(manager.join("#general", 1).await.unwrap().topic_locked);
+
+        assert!(
+            !manager
+                .set_topic_locked("#nonexistent", true)
+                .await
+                .unwrap()
+        );
+        assert!(!manager.is_topic_locked("#nonexistent").await);
+    }
+
+    #[tokio::test]
+    async fn test_set_entry_message_and_rules() {
+        let manager = create_test_manager().await;
+
+        manager.join("#general", 1).await.unwrap();
+        assert!(
+            manager
+                .set_entry_message("#General", Some("Welcome!".to_string()))
+                .await
+                .unwrap()
+        );
+        assert!(
+            manager
+                .set_rules("#general", Some("Be nice".to_string()))
+                .await
+                .unwrap()
+        );
+
+        let result = manager.join("#general", 2).await.unwrap();
+        assert_eq!(result.entry_message, Some("Welcome!".to_string()));
+        assert_eq!(result.rules, Some("Be nice".to_string()));
+
+        manager.set_rules("#general", None).await.unwrap();
+        let result = manager.join("#general", 3).await.unwrap();
+        assert_eq!(result.rules, None);
+
+        assert!(
+            !manager
+                .set_entry_message("#nonexistent", None)
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_language() {
+        let manager = create_test_manager().await;
+
+        manager.join("#general", 1).await.unwrap();
+        assert!(
+            manager
+                .set_language("#General", Some("de".to_string()))
+                .await
+                .unwrap()
+        );
+
+        let list = manager.list(1, false).await;
+        assert_eq!(list[0].language, Some("de".to_string()));
+        let result = manager.join("#general", 2).await.unwrap();
+        assert_eq!(result.language, Some("de".to_string()));
+
+        manager.set_language("#general", None).await.unwrap();
+        let list = manager.list(1, false).await;
+        assert_eq!(list[0].language, None);
+
+        assert!(!manager.set_language("#nonexistent", None).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_clear_topic_setter() {
+        let manager = create_test_manager().await;
+
+        manager.join("#general", 1).await.unwrap();
+        manager.join("#other", 1).await.unwrap();
+        manager
+            .set_topic(
+                "#general",
+                Some("Hi".to_string()),
+                Some("alice".to_string()),
+            )
+            .await
+            .unwrap();
+        manager
+            .set_topic("#other", Some("Yo".to_string()), Some("bob".to_string()))
+            .await
+            .unwrap();
+
+        manager.clear_topic_setter("alice").await.unwrap();
+
+        let general = manager.get_channel("#general").await.unwrap();
+        assert_eq!(general.topic, Some("Hi".to_string()));
+        assert_eq!(general.topic_set_by, None);
+        let other = manager.get_channel("#other").await.unwrap();
+        assert_eq!(other.topic_set_by, Some("bob".to_string()));
+    }
+
     #[tokio::test]
     async fn test_is_member() {
         let manager = create_test_manager().await;
@@ -2,7 +2,9 @@
 //!
 //! This module contains the core data structures used by the channel manager.
 
-use std::collections::HashSet;
+use std::collections::{HashSet, VecDeque};
+
+use nexus_common::protocol::ChatTopicChange;
 
 /// State for a single channel
 #[derive(Debug, Clone)]
@@ -15,6 +17,19 @@ pub struct Channel {
     pub topic_set_by: Option<String>,
     /// Whether the channel is secret (hidden from non-members)
     pub secret: bool,
+    /// Maximum number of voice participants (0 = unlimited)
+    pub voice_limit: u32,
+    /// Whether only users with chat_topic_lock may change the topic
+    pub topic_locked: bool,
+    /// Message shown to users joining the channel
+    pub entry_message: Option<String>,
+    /// Channel rules
+    pub rules: Option<String>,
+    /// Language tag shown in the channel list (e.g. "en")
+    pub language: Option<String>,
+    /// Recent topic changes, newest first (ephemeral channels only; persistent
+    /// channels keep theirs in the database)
+    pub topic_history: VecDeque<ChatTopicChange>,
     /// Session IDs of members in this channel
     pub members: HashSet<u32>,
 }
@@ -27,6 +42,12 @@ impl Channel {
             topic: None,
             topic_set_by: None,
             secret: false,
+            voice_limit: 0,
+            topic_locked: false,
+            entry_message: None,
+            rules: None,
+            language: None,
+            topic_history: VecDeque::new(),
             members: HashSet::new(),
         }
     }
@@ -37,12 +58,20 @@ impl Channel {
         topic: Option<String>,
         topic_set_by: Option<String>,
         secret: bool,
+        voice_limit: u32,
+        topic_locked: bool,
     ) -> Self {
         Self {
             name,
             topic,
             topic_set_by,
             secret,
+            voice_limit,
+            topic_locked,
+            entry_message: None,
+            rules: None,
+            language: None,
+            topic_history: VecDeque::new(),
             members: HashSet::new(),
         }
     }
@@ -86,6 +115,16 @@ pub struct JoinResult {
     pub topic_set_by: Option<String>,
     /// Whether the channel is secret
     pub secret: bool,
+    /// Maximum number of voice participants (0 = unlimited)
+    pub voice_limit: u32,
+    /// Whether the topic is locked
+    pub topic_locked: bool,
+    /// Message shown to users joining the channel
+    pub entry_message: Option<String>,
+    /// Channel rules
+    pub rules: Option<String>,
+    /// Channel language tag
+    pub language: Option<String>,
     /// Current member session IDs (for looking up nicknames)
     pub member_session_ids: Vec<u32>,
 }
@@ -104,4 +143,5 @@ pub struct ChannelListInfo {
     pub topic: Option<String>,
     pub member_count: u32,
     pub secret: bool,
+    pub language: Option<String>,
 }
@@ -1,34 +1,57 @@
 //! Client connection handling
 
+use std::collections::VecDeque;
 use std::io;
 use std::net::SocketAddr;
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, RwLock};
 
-use tokio::io::{AsyncWriteExt, BufReader};
+use tokio::io::{AsyncRead, AsyncWriteExt, BufReader};
 use tokio::net::TcpStream;
 use tokio::sync::mpsc;
+use tokio::task::AbortHandle;
 use tokio_rustls::TlsAcceptor;
 
 use nexus_common::framing::{FrameError, FrameReader, FrameWriter, MessageId};
 use nexus_common::io::{
-    read_client_message_with_full_timeout, read_client_message_with_timeout,
-    send_server_message_with_id,
+    ReceivedClientMessage, read_client_message_with_full_timeout, read_client_message_with_timeout,
+    send_encoded_frame, send_server_message_with_id,
 };
-use nexus_common::protocol::{ClientMessage, ServerMessage};
+use nexus_common::pow::new_pow_challenge;
+use nexus_common::protocol::{ClientMessage, NewsImageLimits, ServerMessage};
 
+use crate::broadcasts::BroadcastManager;
 use crate::channels::ChannelManager;
 use crate::connection_tracker::ConnectionTracker;
 use crate::constants::*;
 use crate::db::Database;
+use crate::db::audit::AUDIT_ACTION_PROTOCOL_LIMIT;
+use crate::doors::{DoorSession, Doors};
 use crate::files::FileIndex;
+use crate::groups::GroupManager;
+use crate::guests::GuestConfig;
 use crate::handlers::{
-    self, HandlerContext, err_invalid_message_format, err_message_not_supported,
+    self, CancelToken, HandlerContext, err_invalid_message_format, err_message_not_supported,
 };
+use crate::idempotency::IdempotencyKeys;
+use crate::identity::ServerIdentity;
 use crate::ip_rule_cache::IpRuleCache;
+use crate::ldap::Ldap;
+use crate::logging::ServerLog;
+use crate::login_tokens::LoginTokens;
+use crate::mdns::Mdns;
+use crate::oidc::Oidc;
+use crate::outbound::{self, OutboundMetrics, Outgoing};
+use crate::protocol_limits::{CountingReader, FloodAction, ProtocolLimits, RateMeter};
+use crate::ranks::Ranks;
+use crate::reachability::CheckConfig;
+use crate::reputation::{ReputationPolicy, TARPIT_DELAY};
+use crate::stats::StatsCollector;
 use crate::transfers::TransferRegistry;
+use crate::upnp::NatStatus;
 use crate::users::UserManager;
-use crate::voice::{VoiceRegistry, send_voice_leave_notifications};
+use crate::voice::{VoiceRegistry, leave_voice_queue, send_voice_leave_notifications};
 
 /// Parameters for handling a connection
 pub struct ConnectionParams {
@@ -45,6 +68,47 @@ pub struct ConnectionParams {
     pub channel_manager: ChannelManager,
     pub transfer_registry: Arc<TransferRegistry>,
     pub voice_registry: VoiceRegistry,
+    pub idempotency_keys: IdempotencyKeys,
+    pub group_manager: GroupManager,
+    pub login_tokens: LoginTokens,
+    pub broadcast_manager: BroadcastManager,
+    pub server_log: ServerLog,
+    pub stats: Arc<StatsCollector>,
+    /// Counters for every connection's outbound queue
+    pub outbound_metrics: Arc<OutboundMetrics>,
+    /// Single sign-on provider (disabled without `--oidc-issuer`)
+    pub oidc: Oidc,
+    /// Door programs from `--door`
+    pub doors: Doors,
+    /// Directory logins (disabled without `--ldap-url`)
+    pub ldap: Ldap,
+    /// Guest limits and self-registration settings
+    pub guest_config: GuestConfig,
+    /// Size limits news images are stored under
+    pub news_image_limits: NewsImageLimits,
+    /// Ranks from `--rank`
+    pub ranks: Ranks,
+    /// Connectivity check settings (None without `--check-service`)
+    pub check_config: Option<CheckConfig>,
+    /// Port forwarding state (empty without `--upnp`)
+    pub nat_status: NatStatus,
+    /// LAN advertisement (inactive without `--mdns`)
+    pub mdns: Mdns,
+    /// Frame size and rate limits
+    pub protocol_limits: ProtocolLimits,
+    pub identity: Arc<ServerIdentity>,
+    /// Blocklist policy for this connection (None if the address isn't listed)
+    pub reputation_policy: Option<ReputationPolicy>,
+}
+
+/// Outcome of reading one client message
+type ReadResult = Result<Option<ReceivedClientMessage>, FrameError>;
+
+/// A message held back because the connection went over a rate limit
+struct Throttled {
+    /// When the rate limit's window ends
+    until: tokio::time::Instant,
+    message: ReceivedClientMessage,
 }
 
 /// Connection state for a single client
@@ -52,14 +116,42 @@ struct ConnectionState {
     session_id: Option<u32>,
     handshake_complete: bool,
     locale: String,
+    identity: Arc<ServerIdentity>,
+    /// Task streaming server log lines (while subscribed)
+    log_stream: Option<AbortHandle>,
+    /// Door program running for this connection
+    door: Option<DoorSession>,
+    /// Delay responses until login (blocklisted address, tarpit policy)
+    tarpit: bool,
+    /// Proof-of-work challenge the login must solve (blocklisted address, pow policy)
+    pow_challenge: Option<String>,
+    /// Client software name sent in the handshake
+    client: Option<String>,
+    /// Message and byte rate limits (None if both are off)
+    rate_meter: Option<RateMeter>,
+    /// Whether going over a rate limit has been recorded in the audit log
+    rate_limit_audited: bool,
 }
 
 impl ConnectionState {
-    fn new() -> Self {
+    fn new(
+        identity: Arc<ServerIdentity>,
+        reputation_policy: Option<ReputationPolicy>,
+        rate_meter: Option<RateMeter>,
+    ) -> Self {
         Self {
             session_id: None,
             handshake_complete: false,
             locale: DEFAULT_LOCALE.to_string(),
+            identity,
+            log_stream: None,
+            door: None,
+            tarpit: reputation_policy == Some(ReputationPolicy::Tarpit),
+            pow_challenge: (reputation_policy == Some(ReputationPolicy::Pow))
+                .then(new_pow_challenge),
+            client: None,
+            rate_meter,
+            rate_limit_audited: false,
         }
     }
 }
@@ -98,43 +190,110 @@ where
         channel_manager,
         transfer_registry,
         voice_registry,
+        idempotency_keys,
+        group_manager,
+        login_tokens,
+        broadcast_manager,
+        server_log,
+        stats,
+        outbound_metrics,
+        oidc,
+        doors,
+        ldap,
+        guest_config,
+        news_image_limits,
+        ranks,
+        check_config,
+        nat_status,
+        mdns,
+        protocol_limits,
+        identity,
+        reputation_policy,
     } = params;
 
     let (reader, writer) = tokio::io::split(socket);
-    let buf_reader = BufReader::new(reader);
+    let bytes_read = Arc::new(AtomicU64::new(0));
+    let buf_reader = BufReader::new(CountingReader::new(reader, bytes_read.clone()));
     let mut frame_reader = FrameReader::new(buf_reader);
+    if let Some(limits) = protocol_limits.frame_sizes() {
+        frame_reader = frame_reader.with_payload_limits(limits);
+    }
     let mut frame_writer = FrameWriter::new(writer);
 
     // Create channel for receiving server messages to send to this client
-    let (tx, mut rx) = mpsc::unbounded_channel::<(ServerMessage, Option<MessageId>)>();
+    let (tx, mut rx) = outbound::channel(outbound_metrics);
 
     // Connection state
-    let mut conn_state = ConnectionState::new();
+    let mut conn_state =
+        ConnectionState::new(identity, reputation_policy, protocol_limits.rate_meter());
+
+    // Read on a separate task so a Cancel can arrive while a handler runs
+    let authenticated = Arc::new(AtomicBool::new(false));
+    let (incoming_tx, mut incoming) = mpsc::channel(1);
+    let reader_task = tokio::spawn(read_client_messages(
+        frame_reader,
+        authenticated.clone(),
+        incoming_tx,
+    ));
+    // Messages read while a handler ran, handled in order once it finishes
+    let mut read_ahead: VecDeque<ReadResult> = VecDeque::new();
+    // Message waiting out a rate limit; outgoing messages keep flowing meanwhile
+    let mut throttled: Option<Throttled> = None;
 
     // Main loop - handle both incoming messages and outgoing events
     // Uses tokio::select! to handle both reading from client and sending to client concurrently
     loop {
-        // Choose read function based on authentication state:
-        // - Before login: use full timeout (30s idle + 60s frame) to prevent resource exhaustion
-        // - After login: allow idle connections (only 60s frame timeout once data arrives)
         let is_authenticated = conn_state.session_id.is_some();
+        authenticated.store(is_authenticated, Ordering::Relaxed);
 
         tokio::select! {
             // Handle incoming client messages
-            result = async {
-                if is_authenticated {
-                    // Authenticated users can idle indefinitely
-                    read_client_message_with_timeout(&mut frame_reader).await
-                } else {
-                    // Unauthenticated connections must send data within 30 seconds
-                    read_client_message_with_full_timeout(&mut frame_reader, None, None).await
-                }
-            } => {
+            (result, metered) = next_client_message(&mut throttled, &mut read_ahead, &mut incoming) => {
                 match result {
                     Ok(Some(received)) => {
+                        // Throttle or drop connections over a rate limit
+                        if !metered
+                            && let Some(meter) = conn_state.rate_meter.as_mut()
+                            && let Some((limit, wait)) = meter.record(
+                                tokio::time::Instant::now(),
+                                bytes_read.load(Ordering::Relaxed),
+                            )
+                        {
+                            let action = protocol_limits.flood_action();
+                            if !conn_state.rate_limit_audited {
+                                conn_state.rate_limit_audited = true;
+                                let details = format!("{limit} ({})", action.as_str());
+                                audit_protocol_limit(
+                                    &db,
+                                    &user_manager,
+                                    conn_state.session_id,
+                                    peer_addr,
+                                    &details,
+                                )
+                                .await;
+                            }
+                            match action {
+                                FloodAction::Throttle => {
+                                    throttled = Some(Throttled {
+                                        until: tokio::time::Instant::now() + wait,
+                                        message: received,
+                                    });
+                                    continue;
+                                }
+                                FloodAction::Disconnect => break,
+                            }
+                        }
+
+                        // Slow down blocklisted clients until they log in
+                        if conn_state.tarpit && !is_authenticated {
+                            tokio::time::sleep(TARPIT_DELAY).await;
+                        }
+
                         // Handle the message
                         // Clone locale to avoid borrow checker conflict
                         let locale = conn_state.locale.clone();
+                        let request_id = received.message_id;
+                        let cancel = CancelToken::default();
 
                         let mut ctx = HandlerContext {
                             writer: &mut frame_writer,
@@ -154,14 +313,47 @@ where
                             channel_manager: &channel_manager,
                             transfer_registry: transfer_registry.clone(),
                             voice_registry: &voice_registry,
+                            idempotency_keys: &idempotency_keys,
+                            group_manager: &group_manager,
+                            login_tokens: &login_tokens,
+                            broadcast_manager: &broadcast_manager,
+                            server_log: &server_log,
+                            stats: &stats,
+                            oidc: &oidc,
+                            doors: &doors,
+                            ldap: &ldap,
+                            guest_config: &guest_config,
+                            news_image_limits: &news_image_limits,
+                            ranks: &ranks,
+                            check_config: &check_config,
+                            nat_status: &nat_status,
+                            mdns: &mdns,
+                            cancel: cancel.clone(),
                         };
 
-                        if let Err(e) = handle_client_message(
+                        let handling = handle_client_message(
                             received.message,
                             &mut conn_state,
                             &mut ctx,
-                        ).await {
-                            eprintln!("{}{}", ERR_HANDLING_MESSAGE, e);
+                        );
+                        tokio::pin!(handling);
+
+                        // Keep reading while the handler runs, to catch a Cancel for it
+                        let result = loop {
+                            tokio::select! {
+                                result = &mut handling => break result,
+                                Some(next) = incoming.recv(), if read_ahead.len() < MAX_READ_AHEAD_MESSAGES => {
+                                    match next {
+                                        Ok(Some(next)) if cancels(&next.message, request_id) => {
+                                            cancel.cancel();
+                                        }
+                                        next => read_ahead.push_back(next),
+                                    }
+                                }
+                            }
+                        };
+                        if let Err(e) = result {
+                            log_error!("{}{}", ERR_HANDLING_MESSAGE, e);
                             break;
                         }
                     }
@@ -178,13 +370,26 @@ where
                         );
 
                         if !is_common_error || debug {
-                            eprintln!("{}{}: {}", ERR_PARSE_MESSAGE, peer_addr, e);
+                            log_debug!("{}{}: {}", ERR_PARSE_MESSAGE, peer_addr, e);
+                        }
+
+                        // Oversized frames are cut off before their payload is read
+                        if matches!(e, FrameError::PayloadLengthExceedsTypeMax { .. }) {
+                            audit_protocol_limit(
+                                &db,
+                                &user_manager,
+                                conn_state.session_id,
+                                peer_addr,
+                                &e.to_string(),
+                            )
+                            .await;
                         }
 
                         // Try to send error before disconnecting
                         let error_msg = ServerMessage::Error {
                             message: err_invalid_message_format(&conn_state.locale),
                             command: None,
+                            error_kind: None,
                         };
                         let _ = send_server_message_with_id(
                             &mut frame_writer,
@@ -197,17 +402,31 @@ where
             }
 
             // Handle outgoing server messages/events
-            msg = rx.recv() => {
+            msg = rx.recv_outgoing() => {
                 match msg {
-                    Some((msg, msg_id)) => {
+                    Some(Outgoing::Message(msg, msg_id)) => {
                         // Use provided message ID or generate a new one
                         let id = msg_id.unwrap_or_else(MessageId::new);
                         if send_server_message_with_id(&mut frame_writer, &msg, id).await.is_err() {
                             break;
                         }
                     }
+                    Some(Outgoing::Frame(frame)) => {
+                        // Broadcast encoded once for all recipients
+                        if send_encoded_frame(&mut frame_writer, frame.bytes()).await.is_err() {
+                            break;
+                        }
+                    }
                     None => {
-                        // Channel closed (user was removed from manager) - disconnect
+                        // Channel closed (user was removed from manager, or the
+                        // client fell too far behind) - disconnect
+                        if rx.overflowed() {
+                            log_warn!(
+                                "Disconnecting {}: outbound queue full ({} messages)",
+                                peer_addr,
+                                OUTBOUND_QUEUE_CAPACITY
+                            );
+                        }
                         break;
                     }
                 }
@@ -217,6 +436,13 @@ where
 
     // Shutdown the writer gracefully
     let _ = frame_writer.get_mut().shutdown().await;
+    reader_task.abort();
+
+    // Stop streaming the server log
+    handlers::handle_log_unsubscribe(&mut conn_state.log_stream);
+
+    // Kill any running door
+    handlers::handle_door_close(&mut conn_state.door);
 
     // Remove user on disconnect and broadcast to other clients
     if let Some(id) = conn_state.session_id {
@@ -249,21 +475,140 @@ where
             // Remove from voice session and notify remaining participants
             if let Some(info) = voice_registry.remove_by_session_id(id).await {
                 // Note: we don't notify the leaving user here since they're disconnecting
-                send_voice_leave_notifications(&info, None, &user_manager, &channel_manager).await;
+                send_voice_leave_notifications(
+                    &info,
+                    None,
+                    &voice_registry,
+                    &user_manager,
+                    &channel_manager,
+                )
+                .await;
             }
+
+            // Stop waiting for a voice slot
+            leave_voice_queue(id, &voice_registry, &user_manager).await;
         }
 
         // Now remove from UserManager and broadcast UserDisconnected
-        if let Some(user) = user_manager.remove_user_and_broadcast(id).await
-            && debug
-        {
-            println!("User '{}' disconnected", user.username);
+        if let Some(user) = user_manager.remove_user_and_broadcast(id).await {
+            if debug {
+                log_debug!("User '{}' disconnected", user.username);
+            }
+
+            // The nickname leaves its group conversations with its last session
+            if user_manager
+                .get_session_by_nickname(&user.nickname)
+                .await
+                .is_none()
+            {
+                group_manager.leave_all(&user.nickname, &user_manager).await;
+            }
         }
+
+        // The session's login token stays valid for a little while so the client
+        // can reconnect (kicks and bans have already revoked it)
+        login_tokens.end_session(id);
     }
 
     Ok(())
 }
 
+/// Record a connection going over a protocol limit in the audit log
+async fn audit_protocol_limit(
+    db: &Database,
+    user_manager: &UserManager,
+    session_id: Option<u32>,
+    peer_addr: SocketAddr,
+    details: &str,
+) {
+    log_warn!(
+        "Connection from {} over protocol limit: {}",
+        peer_addr,
+        details
+    );
+
+    // Connections that haven't logged in yet are recorded without an actor
+    let actor = match session_id {
+        Some(id) => user_manager
+            .get_user_by_session_id(id)
+            .await
+            .map(|user| user.username),
+        None => None,
+    }
+    .unwrap_or_default();
+
+    // Queued rather than written here, so a flood doesn't wait on the database
+    db.audit.record_later(
+        AUDIT_ACTION_PROTOCOL_LIMIT,
+        &peer_addr.ip().to_string(),
+        &actor,
+        Some(details),
+    );
+}
+
+/// Read client messages until the connection closes or the main loop stops
+/// listening
+///
+/// Before login, the first byte of each message must arrive within the idle
+/// timeout (30s idle + 60s frame) to prevent resource exhaustion; after login
+/// connections may idle indefinitely (only the 60s frame timeout applies once
+/// data arrives). Since reading runs ahead of handling, a login that finished
+/// while waiting is noticed when the idle timeout expires.
+async fn read_client_messages<R>(
+    mut frame_reader: FrameReader<R>,
+    authenticated: Arc<AtomicBool>,
+    incoming: mpsc::Sender<ReadResult>,
+) where
+    R: AsyncRead + Unpin,
+{
+    loop {
+        let result = if authenticated.load(Ordering::Relaxed) {
+            read_client_message_with_timeout(&mut frame_reader).await
+        } else {
+            match read_client_message_with_full_timeout(&mut frame_reader, None, None).await {
+                Err(FrameError::IdleTimeout) if authenticated.load(Ordering::Relaxed) => continue,
+                result => result,
+            }
+        };
+
+        let last = !matches!(result, Ok(Some(_)));
+        if incoming.send(result).await.is_err() || last {
+            break;
+        }
+    }
+}
+
+/// Next client message to handle: one held back by a rate limit once its
+/// window ends, one read ahead while a handler ran, or the next one read
+///
+/// Also returns whether the message was already counted against the rate
+/// limits. Nothing else is read while a message is held back, so the reader
+/// stops once its channel is full.
+async fn next_client_message(
+    throttled: &mut Option<Throttled>,
+    read_ahead: &mut VecDeque<ReadResult>,
+    incoming: &mut mpsc::Receiver<ReadResult>,
+) -> (ReadResult, bool) {
+    if let Some(until) = throttled.as_ref().map(|held| held.until) {
+        tokio::time::sleep_until(until).await;
+        if let Some(held) = throttled.take() {
+            return (Ok(Some(held.message)), true);
+        }
+    }
+
+    let result = match read_ahead.pop_front() {
+        Some(result) => result,
+        // The reader only stops after sending its last result
+        None => incoming.recv().await.unwrap_or(Ok(None)),
+    };
+    (result, false)
+}
+
+/// Whether a message cancels the request with the given message ID
+fn cancels(message: &ClientMessage, request_id: MessageId) -> bool {
+    matches!(message, ClientMessage::Cancel { message_id } if message_id == request_id.as_str())
+}
+
 /// Handle a message from the client
 async fn handle_client_message<W>(
     msg: ClientMessage,
@@ -278,9 +623,17 @@ where
             message,
             action,
             channel,
+            idempotency_key,
         } => {
-            handlers::handle_chat_send(message, action, channel, conn_state.session_id, ctx)
-                .await?;
+            handlers::handle_chat_send(
+                message,
+                action,
+                channel,
+                idempotency_key,
+                conn_state.session_id,
+                ctx,
+            )
+            .await?;
         }
         ClientMessage::ChatTopicUpdate { topic, channel } => {
             handlers::handle_chat_topic_update(topic, channel, conn_state.session_id, ctx).await?;
@@ -291,14 +644,45 @@ where
         ClientMessage::ChatLeave { channel } => {
             handlers::handle_chat_leave(channel, conn_state.session_id, ctx).await?;
         }
-        ClientMessage::ChatList {} => {
-            handlers::handle_chat_list(conn_state.session_id, ctx).await?;
+        ClientMessage::ChatList { language } => {
+            handlers::handle_chat_list(language, conn_state.session_id, ctx).await?;
         }
         ClientMessage::ChatSecret { channel, secret } => {
             handlers::handle_chat_secret(channel, secret, conn_state.session_id, ctx).await?;
         }
-        ClientMessage::Handshake { version } => {
-            handlers::handle_handshake(version, &mut conn_state.handshake_complete, ctx).await?;
+        ClientMessage::ChatTopicLock { channel, locked } => {
+            handlers::handle_chat_topic_lock(channel, locked, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::ChatTopicHistory { channel } => {
+            handlers::handle_chat_topic_history(channel, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::ChatEntryUpdate {
+            channel,
+            entry_message,
+            rules,
+        } => {
+            handlers::handle_chat_entry_update(
+                channel,
+                entry_message,
+                rules,
+                conn_state.session_id,
+                ctx,
+            )
+            .await?;
+        }
+        ClientMessage::ChatLanguage { channel, language } => {
+            handlers::handle_chat_language(channel, language, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::Handshake { version, client } => {
+            conn_state.client = client;
+            handlers::handle_handshake(
+                version,
+                &mut conn_state.handshake_complete,
+                &conn_state.identity,
+                conn_state.pow_challenge.as_deref(),
+                ctx,
+            )
+            .await?;
         }
         ClientMessage::Login {
             username,
@@ -307,6 +691,9 @@ where
             locale,
             avatar,
             nickname,
+            pow_nonce,
+            oidc,
+            login_token,
         } => {
             let request = handlers::LoginRequest {
                 username,
@@ -316,14 +703,33 @@ where
                 avatar,
                 nickname,
                 handshake_complete: conn_state.handshake_complete,
+                pow_challenge: conn_state.pow_challenge.clone(),
+                pow_nonce,
+                oidc,
+                login_token,
+                client: conn_state.client.clone(),
             };
             handlers::handle_login(request, &mut conn_state.session_id, ctx).await?;
 
             // Update connection locale after successful login
             conn_state.locale = locale;
         }
-        ClientMessage::UserBroadcast { message } => {
-            handlers::handle_user_broadcast(message, conn_state.session_id, ctx).await?;
+        ClientMessage::UserBroadcast {
+            message,
+            target,
+            delay,
+            require_ack,
+        } => {
+            let request = handlers::UserBroadcastRequest {
+                message,
+                target,
+                delay,
+                require_ack,
+            };
+            handlers::handle_user_broadcast(request, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::BroadcastAck { broadcast_id } => {
+            handlers::handle_broadcast_ack(broadcast_id, conn_state.session_id, ctx).await?;
         }
         ClientMessage::UserCreate {
             username,
@@ -346,9 +752,22 @@ where
         ClientMessage::UserDelete { username } => {
             handlers::handle_user_delete(username, conn_state.session_id, ctx).await?;
         }
+        ClientMessage::UserErase { username } => {
+            handlers::handle_user_erase(username, conn_state.session_id, ctx).await?;
+        }
         ClientMessage::UserEdit { username } => {
             handlers::handle_user_edit(username, conn_state.session_id, ctx).await?;
         }
+        ClientMessage::PermissionAudit {
+            permission,
+            username,
+        } => {
+            handlers::handle_permission_audit(permission, username, conn_state.session_id, ctx)
+                .await?;
+        }
+        ClientMessage::ImpersonateQuery { username, path } => {
+            handlers::handle_impersonate_query(username, path, conn_state.session_id, ctx).await?;
+        }
         ClientMessage::UserInfo { nickname } => {
             handlers::handle_user_info(nickname, conn_state.session_id, ctx).await?;
         }
@@ -362,10 +781,44 @@ where
             to_nickname,
             message,
             action,
+            idempotency_key,
+        } => {
+            handlers::handle_user_message(
+                to_nickname,
+                message,
+                action,
+                idempotency_key,
+                conn_state.session_id,
+                ctx,
+            )
+            .await?;
+        }
+        ClientMessage::GroupMessageCreate { members } => {
+            handlers::handle_group_message_create(members, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::GroupMessageSend {
+            group_id,
+            message,
+            action,
+            idempotency_key,
         } => {
-            handlers::handle_user_message(to_nickname, message, action, conn_state.session_id, ctx)
+            handlers::handle_group_message_send(
+                group_id,
+                message,
+                action,
+                idempotency_key,
+                conn_state.session_id,
+                ctx,
+            )
+            .await?;
+        }
+        ClientMessage::GroupMessageInvite { group_id, nickname } => {
+            handlers::handle_group_message_invite(group_id, nickname, conn_state.session_id, ctx)
                 .await?;
         }
+        ClientMessage::GroupMessageLeave { group_id } => {
+            handlers::handle_group_message_leave(group_id, conn_state.session_id, ctx).await?;
+        }
         ClientMessage::UserUpdate {
             username,
             current_password,
@@ -396,11 +849,31 @@ where
         ClientMessage::UserStatus { status } => {
             handlers::handle_user_status(status, conn_state.session_id, ctx).await?;
         }
+        ClientMessage::UserDnd { enabled } => {
+            handlers::handle_user_dnd(enabled, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::MessageHistory {
+            nickname,
+            before,
+            limit,
+        } => {
+            handlers::handle_message_history(nickname, before, limit, conn_state.session_id, ctx)
+                .await?;
+        }
+        ClientMessage::MessageHistoryRetention { days } => {
+            handlers::handle_message_history_retention(days, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::MessageMarkRead { nickname, id } => {
+            handlers::handle_message_mark_read(nickname, id, conn_state.session_id, ctx).await?;
+        }
         ClientMessage::ServerInfoUpdate {
             name,
             description,
             max_connections_per_ip,
             max_transfers_per_ip,
+            max_active_transfers,
+            transfer_small_files_first,
+            trusted_transfer_weight,
             image,
             file_reindex_interval,
             persistent_channels,
@@ -411,6 +884,9 @@ where
                 description,
                 max_connections_per_ip,
                 max_transfers_per_ip,
+                max_active_transfers,
+                transfer_small_files_first,
+                trusted_transfer_weight,
                 image,
                 file_reindex_interval,
                 persistent_channels,
@@ -425,18 +901,55 @@ where
         ClientMessage::NewsShow { id } => {
             handlers::handle_news_show(id, conn_state.session_id, ctx).await?;
         }
-        ClientMessage::NewsCreate { body, image } => {
-            handlers::handle_news_create(body, image, conn_state.session_id, ctx).await?;
+        ClientMessage::NewsCreate {
+            body,
+            image,
+            attachments,
+        } => {
+            handlers::handle_news_create(body, image, attachments, conn_state.session_id, ctx)
+                .await?;
         }
         ClientMessage::NewsEdit { id } => {
             handlers::handle_news_edit(id, conn_state.session_id, ctx).await?;
         }
-        ClientMessage::NewsUpdate { id, body, image } => {
-            handlers::handle_news_update(id, body, image, conn_state.session_id, ctx).await?;
+        ClientMessage::NewsUpdate {
+            id,
+            body,
+            image,
+            attachments,
+        } => {
+            handlers::handle_news_update(id, body, image, attachments, conn_state.session_id, ctx)
+                .await?;
         }
         ClientMessage::NewsDelete { id } => {
             handlers::handle_news_delete(id, conn_state.session_id, ctx).await?;
         }
+        ClientMessage::NewsSearch {
+            query,
+            author,
+            since,
+            until,
+        } => {
+            handlers::handle_news_search(query, author, since, until, conn_state.session_id, ctx)
+                .await?;
+        }
+        ClientMessage::SnippetCreate {
+            content,
+            language,
+            expires_in,
+        } => {
+            handlers::handle_snippet_create(
+                content,
+                language,
+                expires_in,
+                conn_state.session_id,
+                ctx,
+            )
+            .await?;
+        }
+        ClientMessage::SnippetGet { id } => {
+            handlers::handle_snippet_get(id, conn_state.session_id, ctx).await?;
+        }
         ClientMessage::FileList {
             path,
             root,
@@ -453,6 +966,15 @@ where
         ClientMessage::FileInfo { path, root } => {
             handlers::handle_file_info(path, root, conn_state.session_id, ctx).await?;
         }
+        ClientMessage::FileManifest { path, root } => {
+            handlers::handle_file_manifest(path, root, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::FileStat { files, root } => {
+            handlers::handle_file_stat(files, root, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::FileWatch { directories } => {
+            handlers::handle_file_watch(directories, conn_state.session_id, ctx).await?;
+        }
         ClientMessage::FileRename {
             path,
             new_name,
@@ -499,11 +1021,12 @@ where
         ClientMessage::FileDownload { .. }
         | ClientMessage::FileStartResponse { .. }
         | ClientMessage::FileUpload { .. }
+        | ClientMessage::ServerExport { .. }
         | ClientMessage::FileStart { .. }
         | ClientMessage::FileData
         | ClientMessage::FileHashing { .. } => {
             // These messages are only valid on the transfer port (7501), not the main BBS port
-            eprintln!(
+            log_warn!(
                 "Transfer message received on main port from {}",
                 ctx.peer_addr
             );
@@ -542,22 +1065,296 @@ where
         ClientMessage::ConnectionMonitor => {
             handlers::handle_connection_monitor(conn_state.session_id, ctx).await?;
         }
+        ClientMessage::NatStatus => {
+            handlers::handle_nat_status(conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::ServerStats => {
+            handlers::handle_server_stats(conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::ConnectivityCheck => {
+            handlers::handle_connectivity_check(conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::StatsRequest { days } => {
+            handlers::handle_stats_request(days, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::Leaderboard { stat } => {
+            handlers::handle_leaderboard(stat, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::LogSubscribe => {
+            handlers::handle_log_subscribe(conn_state.session_id, &mut conn_state.log_stream, ctx)
+                .await?;
+        }
+        ClientMessage::LogUnsubscribe => {
+            handlers::handle_log_unsubscribe(&mut conn_state.log_stream);
+        }
+        ClientMessage::DoorList => {
+            handlers::handle_door_list(conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::DoorOpen { name, cols, rows } => {
+            handlers::handle_door_open(
+                name,
+                cols,
+                rows,
+                conn_state.session_id,
+                &mut conn_state.door,
+                ctx,
+            )
+            .await?;
+        }
+        ClientMessage::DoorData { data } => {
+            handlers::handle_door_data(data, conn_state.session_id, &conn_state.door, ctx).await?;
+        }
+        ClientMessage::DoorClose => {
+            handlers::handle_door_close(&mut conn_state.door);
+        }
         ClientMessage::FileSearch { query, root } => {
             handlers::handle_file_search(query, root, conn_state.session_id, ctx).await?;
         }
         ClientMessage::FileReindex => {
             handlers::handle_file_reindex(conn_state.session_id, ctx).await?;
         }
-        ClientMessage::VoiceJoin { target } => {
-            handlers::handle_voice_join(target, conn_state.session_id, ctx).await?;
+        ClientMessage::FileCleanup { delete } => {
+            handlers::handle_file_cleanup(delete, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::FileCheck { repair } => {
+            handlers::handle_file_check(repair, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::FilePopular { root } => {
+            handlers::handle_file_popular(root, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::FileRecent { root } => {
+            handlers::handle_file_recent(root, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::FileRate { path, rating, root } => {
+            handlers::handle_file_rate(path, rating, root, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::FileCommentCreate { path, body, root } => {
+            handlers::handle_file_comment_create(path, body, root, conn_state.session_id, ctx)
+                .await?;
+        }
+        ClientMessage::FileCommentList { path, root } => {
+            handlers::handle_file_comment_list(path, root, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::FileCommentDelete { id } => {
+            handlers::handle_file_comment_delete(id, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::UploadRuleGet { path, root } => {
+            handlers::handle_upload_rule_get(path, root, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::UploadRuleSet { path, rule, root } => {
+            handlers::handle_upload_rule_set(path, rule, root, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::LdapGroupList => {
+            handlers::handle_ldap_group_list(conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::LdapGroupSet {
+            group,
+            permissions,
+            is_admin,
+        } => {
+            handlers::handle_ldap_group_set(
+                group,
+                permissions,
+                is_admin,
+                conn_state.session_id,
+                ctx,
+            )
+            .await?;
+        }
+        ClientMessage::LdapGroupDelete { group } => {
+            handlers::handle_ldap_group_delete(group, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::GuestUpgrade { username, password } => {
+            handlers::handle_guest_upgrade(username, password, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::NicknameRegister { nickname, username } => {
+            handlers::handle_nickname_register(nickname, username, conn_state.session_id, ctx)
+                .await?;
+        }
+        ClientMessage::NicknameRelease { nickname } => {
+            handlers::handle_nickname_release(nickname, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::PresenceSubscribe { usernames } => {
+            handlers::handle_presence_subscribe(usernames, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::VoiceJoin { target, broadcast } => {
+            handlers::handle_voice_join(target, broadcast, conn_state.session_id, ctx).await?;
         }
         ClientMessage::VoiceLeave => {
             handlers::handle_voice_leave(conn_state.session_id, ctx).await?;
         }
+        ClientMessage::VoiceLimit { channel, limit } => {
+            handlers::handle_voice_limit(channel, limit, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::VoiceRelay { data } => {
+            handlers::handle_voice_relay(data, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::VoiceClipList => {
+            handlers::handle_voice_clip_list(conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::VoiceClipUpload { name, data } => {
+            handlers::handle_voice_clip_upload(name, data, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::VoiceClipDelete { name } => {
+            handlers::handle_voice_clip_delete(name, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::VoiceClipPlay { name } => {
+            handlers::handle_voice_clip_play(name, conn_state.session_id, ctx).await?;
+        }
+        ClientMessage::VoiceTextSend { message } => {
+            handlers::handle_voice_text_send(message, conn_state.session_id, ctx).await?;
+        }
         ClientMessage::Ping => {
-            ctx.send_message(&ServerMessage::Pong).await?;
+            let pong = ServerMessage::Pong {
+                time: Some(handlers::server_time()),
+            };
+            ctx.send_message(&pong).await?;
+        }
+        ClientMessage::Cancel { .. } => {
+            // A Cancel for a running request is taken while it runs (see the
+            // main loop), so this one's request has already finished
         }
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use nexus_common::PROTOCOL_VERSION;
+    use nexus_common::io::{read_server_message, send_client_message};
+
+    use super::*;
+    use crate::db::Permissions;
+    use crate::handlers::testing::{create_test_context, get_cached_password_hash};
+
+    #[tokio::test]
+    async fn test_throttled_connection_receives_broadcasts() {
+        let test_ctx = create_test_context().await;
+        test_ctx
+            .db
+            .users
+            .create_user(
+                "alice",
+                &get_cached_password_hash("password"),
+                false,
+                false,
+                true,
+                &Permissions::new(),
+            )
+            .await
+            .unwrap();
+        let user_manager = test_ctx.user_manager.clone();
+
+        let (client, server) = tokio::io::duplex(1 << 20);
+        let params = ConnectionParams {
+            peer_addr: test_ctx.peer_addr,
+            user_manager: test_ctx.user_manager.clone(),
+            db: test_ctx.db.clone(),
+            debug: false,
+            file_root: None,
+            transfer_port: nexus_common::DEFAULT_TRANSFER_PORT,
+            transfer_websocket_port: None,
+            connection_tracker: test_ctx.connection_tracker.clone(),
+            ip_rule_cache: test_ctx.ip_rule_cache.clone(),
+            file_index: test_ctx.file_index.clone(),
+            channel_manager: test_ctx.channel_manager.clone(),
+            transfer_registry: test_ctx.transfer_registry.clone(),
+            voice_registry: test_ctx.voice_registry.clone(),
+            idempotency_keys: test_ctx.idempotency_keys.clone(),
+            group_manager: test_ctx.group_manager.clone(),
+            login_tokens: test_ctx.login_tokens.clone(),
+            broadcast_manager: test_ctx.broadcast_manager.clone(),
+            server_log: test_ctx.server_log.clone(),
+            stats: test_ctx.stats.clone(),
+            outbound_metrics: Default::default(),
+            oidc: test_ctx.oidc.clone(),
+            doors: test_ctx.doors.clone(),
+            ldap: test_ctx.ldap.clone(),
+            guest_config: test_ctx.guest_config.clone(),
+            news_image_limits: test_ctx.news_image_limits,
+            ranks: test_ctx.ranks.clone(),
+            check_config: test_ctx.check_config.clone(),
+            nat_status: test_ctx.nat_status.clone(),
+            mdns: test_ctx.mdns.clone(),
+            protocol_limits: ProtocolLimits::default(),
+            identity: Arc::new(ServerIdentity {
+                public_key: String::new(),
+                signature: String::new(),
+            }),
+            reputation_policy: None,
+        };
+        tokio::spawn(handle_connection_inner(server, params));
+
+        let (reader, writer) = tokio::io::split(client);
+        let mut reader = FrameReader::new(BufReader::new(reader));
+        let mut writer = FrameWriter::new(writer);
+
+        let handshake = ClientMessage::Handshake {
+            version: PROTOCOL_VERSION.to_string(),
+            client: None,
+        };
+        send_client_message(&mut writer, &handshake).await.unwrap();
+        let login = ClientMessage::Login {
+            username: "alice".to_string(),
+            password: "password".to_string(),
+            features: vec![],
+            locale: DEFAULT_LOCALE.to_string(),
+            avatar: None,
+            nickname: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+        };
+        send_client_message(&mut writer, &login).await.unwrap();
+        loop {
+            let received = read_server_message(&mut reader).await.unwrap().unwrap();
+            if let ServerMessage::LoginResponse { success, .. } = received.message {
+                assert!(success);
+                break;
+            }
+        }
+
+        // Start a fresh message window, then go one message over the limit
+        tokio::time::sleep(Duration::from_millis(1100)).await;
+        for _ in 0..=DEFAULT_MAX_MESSAGES_PER_SECOND {
+            send_client_message(&mut writer, &ClientMessage::Ping)
+                .await
+                .unwrap();
+        }
+
+        // The last Ping waits out the window, but the broadcast goes out now
+        let result = tokio::time::timeout(Duration::from_secs(5), async {
+            let mut pongs = 0;
+            let mut broadcast_sent = false;
+            loop {
+                let received = read_server_message(&mut reader).await.unwrap().unwrap();
+                match received.message {
+                    ServerMessage::Pong { .. } => {
+                        pongs += 1;
+                        if pongs == DEFAULT_MAX_MESSAGES_PER_SECOND && !broadcast_sent {
+                            broadcast_sent = true;
+                            user_manager
+                                .broadcast(ServerMessage::ServerBroadcast {
+                                    session_id: 0,
+                                    username: "admin".to_string(),
+                                    message: "hello".to_string(),
+                                    broadcast_id: None,
+                                })
+                                .await;
+                        }
+                    }
+                    ServerMessage::ServerBroadcast { message, .. } => return (message, pongs),
+                    _ => {}
+                }
+            }
+        })
+        .await;
+
+        let (message, pongs) = result.expect("broadcast not received while throttled");
+        assert_eq!(message, "hello");
+        assert_eq!(pongs, DEFAULT_MAX_MESSAGES_PER_SECOND);
+    }
+}
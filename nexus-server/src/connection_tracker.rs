@@ -3,11 +3,20 @@
 //! This module provides connection limiting per IP address to prevent
 //! resource exhaustion attacks. It tracks main BBS connections,
 //! file transfer connections, and voice connections with separate limits.
+//! It also keeps short-term penalties for addresses caught probing the
+//! server (see [`crate::scanner`]).
 
 use std::collections::HashMap;
 use std::net::IpAddr;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::ip_rule_cache::normalize_ip;
+use crate::scanner::ScannerMitigation;
+
+/// Maximum number of penalized addresses tracked at once
+const MAX_PENALTIES: usize = 10_000;
 
 /// Tracks active connections per IP address for both main and transfer connections
 ///
@@ -25,6 +34,10 @@ pub struct ConnectionTracker {
     transfer_connections: Arc<Mutex<HashMap<IpAddr, usize>>>,
     /// Maximum transfer connections allowed per IP (0 = unlimited)
     max_transfers_per_ip: AtomicUsize,
+    /// How scanner probes are handled
+    scanner_mitigation: ScannerMitigation,
+    /// Addresses refused at accept, with when the penalty ends
+    penalties: Mutex<HashMap<IpAddr, Instant>>,
 }
 
 impl ConnectionTracker {
@@ -38,6 +51,57 @@ impl ConnectionTracker {
             max_connections_per_ip: AtomicUsize::new(max_connections_per_ip),
             transfer_connections: Arc::new(Mutex::new(HashMap::new())),
             max_transfers_per_ip: AtomicUsize::new(max_transfers_per_ip),
+            scanner_mitigation: ScannerMitigation::Off,
+            penalties: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Set how scanner probes are handled
+    #[must_use]
+    pub fn with_scanner_mitigation(mut self, mode: ScannerMitigation) -> Self {
+        self.scanner_mitigation = mode;
+        self
+    }
+
+    /// How scanner probes are handled
+    pub fn scanner_mitigation(&self) -> ScannerMitigation {
+        self.scanner_mitigation
+    }
+
+    /// Refuse connections from an address for `duration`
+    ///
+    /// Does nothing when scanner mitigation is off. When the table is full,
+    /// expired penalties are dropped first; if it is still full, the new
+    /// penalty is skipped rather than evicting an active one.
+    pub fn penalize(&self, ip: IpAddr, duration: Duration) {
+        if self.scanner_mitigation == ScannerMitigation::Off {
+            return;
+        }
+        let now = Instant::now();
+        let mut penalties = self.penalties.lock().expect("penalty tracker lock");
+        if penalties.len() >= MAX_PENALTIES {
+            penalties.retain(|_, until| *until > now);
+            if penalties.len() >= MAX_PENALTIES {
+                return;
+            }
+        }
+        penalties.insert(normalize_ip(ip), now + duration);
+    }
+
+    /// Whether an address is currently penalized
+    pub fn is_penalized(&self, ip: IpAddr) -> bool {
+        if self.scanner_mitigation == ScannerMitigation::Off {
+            return false;
+        }
+        let ip = normalize_ip(ip);
+        let mut penalties = self.penalties.lock().expect("penalty tracker lock");
+        match penalties.get(&ip) {
+            Some(until) if *until > Instant::now() => true,
+            Some(_) => {
+                penalties.remove(&ip);
+                false
+            }
+            None => false,
         }
     }
 
@@ -105,6 +169,21 @@ impl ConnectionTracker {
             connections: self.transfer_connections.clone(),
         })
     }
+
+    /// Get the total number of active main connections across all IPs
+    pub fn total_connections(&self) -> usize {
+        let connections = self.connections.lock().expect("connection tracker lock");
+        connections.values().sum()
+    }
+
+    /// Get the total number of active transfer connections across all IPs
+    pub fn total_transfers(&self) -> usize {
+        let connections = self
+            .transfer_connections
+            .lock()
+            .expect("transfer tracker lock");
+        connections.values().sum()
+    }
 }
 
 /// RAII guard that releases a main connection slot when dropped
@@ -181,21 +260,6 @@ mod tests {
                 .expect("transfer tracker lock");
             connections.get(&ip).copied().unwrap_or(0)
         }
-
-        /// Get the total number of active main connections across all IPs
-        fn total_connections(&self) -> usize {
-            let connections = self.connections.lock().expect("connection tracker lock");
-            connections.values().sum()
-        }
-
-        /// Get the total number of active transfer connections across all IPs
-        fn total_transfers(&self) -> usize {
-            let connections = self
-                .transfer_connections
-                .lock()
-                .expect("transfer tracker lock");
-            connections.values().sum()
-        }
     }
 
     // =========================================================================
@@ -503,4 +567,52 @@ mod tests {
         assert_eq!(tracker.max_connections_per_ip(), 5);
         assert_eq!(tracker.max_transfers_per_ip(), 3);
     }
+
+    // =========================================================================
+    // Scanner penalty tests
+    // =========================================================================
+
+    #[test]
+    fn test_penalize_and_expire() {
+        let tracker = ConnectionTracker::new(0, 0).with_scanner_mitigation(ScannerMitigation::Drop);
+        let ip1 = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1));
+        let ip2 = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 2));
+
+        tracker.penalize(ip1, Duration::from_secs(60));
+        assert!(tracker.is_penalized(ip1));
+        assert!(!tracker.is_penalized(ip2));
+
+        // IPv4-mapped IPv6 is the same address
+        let mapped: IpAddr = "::ffff:192.168.1.1".parse().unwrap();
+        assert!(tracker.is_penalized(mapped));
+
+        // Expired penalties are cleared on lookup
+        tracker.penalize(ip2, Duration::ZERO);
+        assert!(!tracker.is_penalized(ip2));
+        assert!(!tracker.penalties.lock().unwrap().contains_key(&ip2));
+    }
+
+    #[test]
+    fn test_penalize_ignored_when_mitigation_off() {
+        let tracker = ConnectionTracker::new(0, 0);
+        let ip = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1));
+
+        tracker.penalize(ip, Duration::from_secs(60));
+        assert!(!tracker.is_penalized(ip));
+        assert!(tracker.penalties.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_penalties_are_capped() {
+        let tracker = ConnectionTracker::new(0, 0).with_scanner_mitigation(ScannerMitigation::Drip);
+        for i in 0..MAX_PENALTIES as u32 {
+            tracker.penalize(IpAddr::V4(Ipv4Addr::from(i)), Duration::from_secs(60));
+        }
+
+        // A full table of active penalties isn't evicted
+        let extra = IpAddr::V4(Ipv4Addr::new(203, 0, 113, 1));
+        tracker.penalize(extra, Duration::from_secs(60));
+        assert!(!tracker.is_penalized(extra));
+        assert!(tracker.is_penalized(IpAddr::V4(Ipv4Addr::from(0))));
+    }
 }
@@ -44,6 +44,145 @@ pub const CONFIG_KEY_MAX_TRANSFERS_PER_IP: &str = "max_transfers_per_ip";
 /// Default maximum file transfer connections per IP address (matches migration default)
 pub const DEFAULT_MAX_TRANSFERS_PER_IP: usize = 3;
 
+// =============================================================================
+// Protocol Limits
+// =============================================================================
+
+/// Default messages a connection may send per second (0 means unlimited)
+pub const DEFAULT_MAX_MESSAGES_PER_SECOND: u32 = 100;
+
+/// Default bytes a connection may send per minute (0 means unlimited)
+pub const DEFAULT_MAX_BYTES_PER_MINUTE: u64 = 32 * 1024 * 1024;
+
+/// Messages queued for a connection before chat is dropped or the client is
+/// disconnected for falling behind (see outbound.rs)
+pub const OUTBOUND_QUEUE_CAPACITY: usize = 1024;
+
+/// Messages read ahead while a handler runs (to catch a `Cancel` for it)
+/// before reading pauses until the handler finishes
+pub const MAX_READ_AHEAD_MESSAGES: usize = 32;
+
+// =============================================================================
+// Login Tokens
+// =============================================================================
+
+/// Seconds a login token keeps working after its session ends (for reconnects)
+pub const LOGIN_TOKEN_GRACE_SECS: i64 = 5 * 60;
+
+// =============================================================================
+// Idempotency Keys
+// =============================================================================
+
+/// Seconds a message's idempotency key is remembered (repeats are not posted)
+pub const IDEMPOTENCY_WINDOW_SECS: i64 = 5 * 60;
+
+/// Most idempotency keys remembered at once (the oldest are forgotten first)
+pub const MAX_IDEMPOTENCY_KEYS: usize = 10_000;
+
+// =============================================================================
+// Broadcasts
+// =============================================================================
+
+/// Maximum number of broadcasts waiting to be sent at once
+pub const MAX_SCHEDULED_BROADCASTS: usize = 100;
+
+/// Seconds acknowledgements of a broadcast are counted after it is sent
+pub const BROADCAST_ACK_TRACKING_SECS: i64 = 7 * 24 * 60 * 60;
+
+/// Seconds between checks for scheduled broadcasts that are due
+pub const BROADCAST_SCHEDULE_CHECK_SECS: u64 = 5;
+
+// =============================================================================
+// Storage Monitoring
+// =============================================================================
+
+/// Default free space kept on the file area and database disks (1 GiB)
+pub const DEFAULT_MIN_FREE_SPACE: &str = "1G";
+
+/// Seconds between free space checks of the file area and database disks
+pub const STORAGE_CHECK_INTERVAL_SECS: u64 = 60;
+
+// =============================================================================
+// Server Stats
+// =============================================================================
+
+/// Audit log entries included in a ServerStatsResponse
+pub const SERVER_STATS_AUDIT_ENTRIES: usize = 20;
+
+// =============================================================================
+// Guest Access
+// =============================================================================
+
+/// Default maximum guest sessions per IP address (0 means unlimited)
+pub const DEFAULT_MAX_GUESTS_PER_IP: u32 = 3;
+
+/// Prefix of the nicknames given to guests who don't pick one
+pub const GUEST_NICKNAME_PREFIX: &str = "Guest";
+
+/// Attempts at finding a free automatic guest nickname before giving up
+pub const GUEST_NICKNAME_ATTEMPTS: usize = 10;
+
+// =============================================================================
+// Registered Nicknames
+// =============================================================================
+
+/// Most nicknames an account can reserve for itself (admins can add more)
+pub const MAX_REGISTERED_NICKNAMES: u32 = 5;
+
+// =============================================================================
+// WebDAV Gateway
+// =============================================================================
+
+/// Default port for the read-only WebDAV gateway (requires --webdav)
+pub const DEFAULT_WEBDAV_PORT: u16 = 7504;
+
+// =============================================================================
+// Single Sign-On
+// =============================================================================
+
+/// ID token claim used as the username of new single sign-on accounts
+pub const DEFAULT_OIDC_USERNAME_CLAIM: &str = "preferred_username";
+
+/// Seconds between attempts to fetch the provider configuration until one succeeds
+pub const OIDC_DISCOVERY_RETRY_SECS: u64 = 60;
+
+/// Seconds between refreshes of the provider configuration
+pub const OIDC_DISCOVERY_REFRESH_SECS: u64 = 6 * 3600;
+
+// =============================================================================
+// Directory (LDAP) Logins
+// =============================================================================
+
+/// Filter that finds a user's directory entry (OpenLDAP or Active Directory)
+pub const DEFAULT_LDAP_USER_FILTER: &str = "(|(uid={username})(sAMAccountName={username}))";
+
+/// Timeout for each login checked against the directory
+pub const LDAP_REQUEST_TIMEOUT_SECS: u64 = 10;
+
+// =============================================================================
+// Transfer Queue Configuration
+// =============================================================================
+
+/// Configuration key for the server-wide active transfer limit in the database
+pub const CONFIG_KEY_MAX_ACTIVE_TRANSFERS: &str = "max_active_transfers";
+
+/// Default maximum simultaneous active transfers (matches migration default)
+/// A value of 0 means unlimited (transfers are never queued).
+pub const DEFAULT_MAX_ACTIVE_TRANSFERS: usize = 0;
+
+/// Configuration key for the small-files-first queue policy in the database
+pub const CONFIG_KEY_TRANSFER_SMALL_FILES_FIRST: &str = "transfer_small_files_first";
+
+/// Default small-files-first queue policy (matches migration default)
+pub const DEFAULT_TRANSFER_SMALL_FILES_FIRST: bool = false;
+
+/// Configuration key for the fair-share weight of trusted IPs in the database
+pub const CONFIG_KEY_TRUSTED_TRANSFER_WEIGHT: &str = "trusted_transfer_weight";
+
+/// Default fair-share weight for transfers from trusted IPs (matches migration default)
+/// Regular transfers always have weight 1.
+pub const DEFAULT_TRUSTED_TRANSFER_WEIGHT: u32 = 2;
+
 // =============================================================================
 // File Reindex Configuration
 // =============================================================================
@@ -55,6 +194,69 @@ pub const CONFIG_KEY_FILE_REINDEX_INTERVAL: &str = "file_reindex_interval";
 /// A value of 0 disables automatic reindexing.
 pub const DEFAULT_FILE_REINDEX_INTERVAL: u32 = 5;
 
+// =============================================================================
+// File Area Cleanup
+// =============================================================================
+
+/// Default days an unfinished upload is kept for resuming
+pub const DEFAULT_STALE_UPLOAD_DAYS: u32 = 7;
+
+/// Seconds between report-only scans for leftovers of aborted transfers
+pub const FILE_CLEANUP_SCAN_SECS: u64 = 86400;
+
+/// Most orphaned file paths listed in a cleanup report
+pub const FILE_CLEANUP_REPORT_PATHS: usize = 20;
+
+/// Most discrepancies a file area check sends; the rest are only counted
+pub const FILE_CHECK_MAX_ISSUES: usize = 500;
+
+// =============================================================================
+// Voice Clips
+// =============================================================================
+
+/// Maximum number of clips in the server's soundboard library
+pub const MAX_VOICE_CLIPS: usize = 100;
+
+// =============================================================================
+// News Images
+// =============================================================================
+
+/// Default longest side of a stored news image in pixels (0 means unscaled)
+pub const DEFAULT_NEWS_IMAGE_MAX_DIMENSION: u32 = 1600;
+
+/// Smallest longest side a news image is scaled down to before it's rejected
+pub const MIN_NEWS_IMAGE_DIMENSION: u32 = 64;
+
+/// JPEG quality for re-encoded news images
+pub const NEWS_IMAGE_JPEG_QUALITY: u8 = 85;
+
+/// Largest width or height of a news image the server will decode
+pub const MAX_NEWS_IMAGE_DECODE_DIMENSION: u32 = 16_384;
+
+/// Most memory the server will allocate to decode a news image
+pub const MAX_NEWS_IMAGE_DECODE_ALLOC: u64 = 256 * 1024 * 1024;
+
+// =============================================================================
+// News Search
+// =============================================================================
+
+/// Most posts a news search returns
+pub const MAX_NEWS_SEARCH_RESULTS: usize = 100;
+
+// =============================================================================
+// Direct Message History
+// =============================================================================
+
+/// Messages per MessageHistory page when the client doesn't ask for a size
+pub const DEFAULT_MESSAGE_HISTORY_PAGE: u32 = 50;
+
+// =============================================================================
+// Snippets
+// =============================================================================
+
+/// Most unexpired snippets an account can have at once
+pub const MAX_SNIPPETS_PER_USER: i64 = 100;
+
 // =============================================================================
 // Database Validation Errors (defense-in-depth, operator-facing)
 // =============================================================================
@@ -154,11 +356,18 @@ pub const DEFAULT_AUTO_JOIN_CHANNELS: &str = nexus_common::validators::DEFAULT_C
 /// - Resource usage (SQLite has limitations on concurrent writes)
 /// - Typical BBS workload (small to medium number of simultaneous users)
 ///
-/// SQLite uses WAL mode which allows multiple readers + one writer concurrently,
+/// The default WAL journal mode allows multiple readers + one writer concurrently,
 /// so 5 connections provides good throughput for read-heavy workloads while
 /// keeping resource usage reasonable.
 pub const MAX_DB_CONNECTIONS: u32 = 5;
 
+// =============================================================================
+// Outgoing HTTPS Requests
+// =============================================================================
+
+/// Timeout for each outgoing HTTPS request (identity provider, storage webhook)
+pub const HTTPS_REQUEST_TIMEOUT_SECS: u64 = 10;
+
 // =============================================================================
 // TLS Configuration
 // =============================================================================
@@ -169,6 +378,12 @@ pub const CERT_FILENAME: &str = "server.crt";
 /// TLS private key file name
 pub const KEY_FILENAME: &str = "server.key";
 
+/// Server identity key file name (Ed25519, PKCS#8 PEM)
+pub const IDENTITY_KEY_FILENAME: &str = "identity.key";
+
+/// Direct message history key file name (hex, beside the database)
+pub const MESSAGE_HISTORY_KEY_FILENAME: &str = "history.key";
+
 /// TLS certificate common name
 pub const TLS_CERT_COMMON_NAME: &str = "Nexus BBS Server";
 
@@ -203,6 +418,21 @@ pub const MSG_WS_LISTENING: &str = "WebSocket port: ";
 /// WebSocket transfer port listening display
 pub const MSG_WS_TRANSFER_LISTENING: &str = "WebSocket transfer port: ";
 
+/// WebDAV port listening display
+pub const MSG_WEBDAV_LISTENING: &str = "WebDAV port: ";
+
+/// Single sign-on issuer display
+pub const MSG_OIDC_ISSUER: &str = "Single sign-on: ";
+
+/// Directory login display
+pub const MSG_LDAP_URL: &str = "Directory logins: ";
+
+/// Frame size limits display
+pub const MSG_FRAME_SIZE_LIMITS: &str = "Frame size limits: ";
+
+/// Guest registration role display
+pub const MSG_GUEST_UPGRADE_ROLE: &str = "Guest registration: ";
+
 /// Voice UDP port listening display
 pub const MSG_VOICE_LISTENING: &str = "Voice UDP port: ";
 
@@ -218,6 +448,21 @@ pub const MSG_CERT_GENERATED: &str = "Certificate generated: ";
 /// Private key file generated message
 pub const MSG_KEY_GENERATED: &str = "Private key generated: ";
 
+/// Server identity key display
+pub const MSG_IDENTITY_KEY: &str = "Server identity key: ";
+
+/// Server identity key file generated message
+pub const MSG_IDENTITY_KEY_GENERATED: &str = "Identity key generated: ";
+
+/// Message history key file generated message
+pub const MSG_MESSAGE_HISTORY_KEY_GENERATED: &str = "Message history key generated: ";
+
+/// DNS blocklist display
+pub const MSG_DNSBL: &str = "DNS blocklists: ";
+
+/// Scanner mitigation display
+pub const MSG_SCANNER_MITIGATION: &str = "Scanner mitigation: ";
+
 /// Shutdown signal received message
 pub const MSG_SHUTDOWN_RECEIVED: &str = "\nShutdown signal received";
 
@@ -243,6 +488,9 @@ pub const ERR_NO_DATA_DIR: &str = "Unable to determine data directory for your p
 /// TLS initialization error
 pub const ERR_TLS_INIT: &str = "Failed to initialize TLS: ";
 
+/// Server identity initialization error
+pub const ERR_IDENTITY_INIT: &str = "Failed to initialize server identity: ";
+
 /// Server bind error
 pub const ERR_BIND_FAILED: &str = "Failed to bind to ";
 
@@ -316,6 +564,47 @@ pub const ERR_WRITE_KEY_FILE: &str = "Failed to write private key file: ";
 #[cfg(unix)]
 pub const ERR_SET_KEY_PERMISSIONS: &str = "Failed to set key permissions: ";
 
+// =============================================================================
+// Server Identity Errors (operator-facing)
+// =============================================================================
+
+/// Identity key generation error
+pub const ERR_GENERATE_IDENTITY_KEY: &str = "Failed to generate identity key";
+
+/// Identity key file read error
+pub const ERR_READ_IDENTITY_KEY: &str = "Failed to read identity key file: ";
+
+/// Identity key parse error
+pub const ERR_PARSE_IDENTITY_KEY: &str = "Failed to parse identity key: ";
+
+/// Identity key file write error
+pub const ERR_WRITE_IDENTITY_KEY: &str = "Failed to write identity key file: ";
+
+// =============================================================================
+// Message History Errors (operator-facing)
+// =============================================================================
+
+/// Message history key initialization error
+pub const ERR_MESSAGE_HISTORY_INIT: &str = "Failed to initialize message history key: ";
+
+/// Message history key generation error
+pub const ERR_GENERATE_MESSAGE_HISTORY_KEY: &str = "Failed to generate message history key";
+
+/// Message history key file read error
+pub const ERR_READ_MESSAGE_HISTORY_KEY: &str = "Failed to read message history key file: ";
+
+/// Message history key parse error
+pub const ERR_PARSE_MESSAGE_HISTORY_KEY: &str = "Failed to parse message history key: ";
+
+/// Message history key length error detail
+pub const ERR_MESSAGE_HISTORY_KEY_LENGTH: &str = "expected 32 bytes";
+
+/// Message history key file write error
+pub const ERR_WRITE_MESSAGE_HISTORY_KEY: &str = "Failed to write message history key file: ";
+
+/// Message encryption error
+pub const ERR_MESSAGE_HISTORY_ENCRYPT: &str = "Failed to encrypt message for history";
+
 // =============================================================================
 // TLS Certificate Loading Errors (operator-facing)
 // =============================================================================
@@ -377,6 +666,153 @@ pub const WARN_UPNP_REDISCOVER_FAILED: &str = "Warning: Failed to rediscover UPn
 /// UPnP mapping removal failure warning
 pub const WARN_UPNP_REMOVE_MAPPING_FAILED: &str = "Warning: Failed to remove UPnP port mapping: ";
 
+/// External IP address change message
+pub const MSG_UPNP_EXTERNAL_IP_CHANGED: &str = "External IP address changed: ";
+
+// =============================================================================
+// mDNS Messages (operator-facing)
+// =============================================================================
+
+/// mDNS advertising started message
+pub const MSG_MDNS_ADVERTISING: &str = "mDNS: advertising as ";
+
+/// mDNS setup failure warning
+pub const MSG_MDNS_WARNING: &str = "Warning: mDNS advertising failed: ";
+
+/// mDNS disabled continuation message
+pub const MSG_MDNS_CONTINUE: &str = "Server will continue without LAN discovery.";
+
+/// mDNS record update failure warning
+pub const WARN_MDNS_UPDATE_FAILED: &str = "Warning: Failed to update mDNS advertisement: ";
+
+/// External IP check failure warning
+pub const WARN_UPNP_EXTERNAL_IP_CHECK_FAILED: &str = "Warning: Failed to check external IP: ";
+
+// =============================================================================
+// Connectivity Check Messages (operator-facing)
+// =============================================================================
+
+/// Check service connection error
+pub const ERR_CHECK_SERVICE_CONNECT: &str = "Failed to reach check service: ";
+
+/// Check service timeout error
+pub const ERR_CHECK_SERVICE_TIMEOUT: &str = "Check service did not answer in time";
+
+/// Check service malformed response error
+pub const ERR_CHECK_SERVICE_INVALID_RESPONSE: &str = "Invalid response from check service";
+
+/// Check service refused the request
+pub const ERR_CHECK_SERVICE_REFUSED: &str = "Check service refused the request: ";
+
+/// `nexusd check` without a check service
+pub const ERR_CHECK_NO_SERVICE: &str =
+    "nexusd check needs a check service (--check-service HOST:PORT)";
+
+/// Connectivity check failure
+pub const ERR_CHECK_FAILED: &str = "Connectivity check failed: ";
+
+/// Connectivity check start message
+pub const MSG_CHECK_RUNNING: &str = "Checking connectivity via ";
+
+/// Address the check service saw
+pub const MSG_CHECK_EXTERNAL_IP: &str = "External address: ";
+
+/// Check service display (startup)
+pub const MSG_CHECK_SERVICE: &str = "Connectivity check service: ";
+
+/// Check responder display (startup)
+pub const MSG_CHECK_RESPONDER: &str = "Check responder port: ";
+
+/// Configured ranks display (startup)
+pub const MSG_RANKS: &str = "Ranks: ";
+
+/// Configured doors display (startup)
+pub const MSG_DOORS: &str = "Doors: ";
+
+/// Unfinished uploads display (startup)
+pub const MSG_UNFINISHED_UPLOADS: &str = "Unfinished uploads: ";
+
+// =============================================================================
+// Server Import Messages (operator-facing)
+// =============================================================================
+
+/// Import archive read error
+pub const ERR_IMPORT_READ: &str = "Failed to read import archive: ";
+
+/// Import failure error
+pub const ERR_IMPORT: &str = "Import failed: ";
+
+/// Import completed message
+pub const MSG_IMPORT_COMPLETE: &str = "Import complete: ";
+
+/// Header for the temporary passwords of imported accounts
+pub const MSG_IMPORT_PASSWORDS: &str =
+    "Temporary passwords for imported accounts (change them after logging in):";
+
+// =============================================================================
+// Data Retention (operator-facing)
+// =============================================================================
+
+/// Seconds between news retention checks
+pub const NEWS_RETENTION_CHECK_SECS: u64 = 3600;
+
+/// News retention failure error
+pub const ERR_NEWS_RETENTION: &str = "Failed to delete expired news: ";
+
+/// Seconds between direct message history retention checks
+pub const MESSAGE_HISTORY_RETENTION_CHECK_SECS: u64 = 3600;
+
+/// Direct message history retention failure error
+pub const ERR_MESSAGE_HISTORY_RETENTION: &str = "Failed to delete expired message history: ";
+
+/// Direct message history flush failure error
+pub const ERR_MESSAGE_HISTORY_FLUSH: &str = "Failed to save message history: ";
+
+/// Seconds between expired snippet cleanups
+pub const SNIPPET_CLEANUP_CHECK_SECS: u64 = 3600;
+
+/// Expired snippet cleanup failure error
+pub const ERR_SNIPPET_CLEANUP: &str = "Failed to delete expired snippets: ";
+
+/// Seconds between upload journal sweeps
+pub const UPLOAD_JOURNAL_SWEEP_SECS: u64 = 3600;
+
+/// Upload journal sweep failure error
+pub const ERR_UPLOAD_JOURNAL_SWEEP: &str = "Failed to clean up unfinished uploads: ";
+
+/// File area cleanup scan failure error
+pub const ERR_FILE_CLEANUP: &str = "Failed to scan the file area for leftovers: ";
+
+/// Invalid single sign-on settings
+pub const ERR_OIDC_CONFIG: &str = "Invalid single sign-on settings: ";
+
+/// Failed to read the single sign-on client secret file
+pub const ERR_OIDC_SECRET_FILE: &str = "Failed to read single sign-on client secret file: ";
+
+/// Invalid disk space monitoring settings
+pub const ERR_STORAGE_CONFIG: &str = "Invalid storage settings: ";
+
+/// Invalid directory login settings
+pub const ERR_LDAP_CONFIG: &str = "Invalid directory login settings: ";
+
+/// Directory logins over an unencrypted connection
+pub const WARN_LDAP_PLAINTEXT: &str =
+    "Warning: the directory URL is ldap://, so passwords are sent unencrypted; use ldaps://";
+
+// =============================================================================
+// Statistics (operator-facing)
+// =============================================================================
+
+/// Statistics flush failure error
+pub const ERR_STATS_FLUSH: &str = "Failed to save statistics: ";
+
+// =============================================================================
+// Audit Log (operator-facing)
+// =============================================================================
+
+/// Audit log flush failure error
+pub const ERR_AUDIT_FLUSH: &str = "Failed to save audit log: ";
+
 // =============================================================================
 // UPnP Error Messages (operator-facing)
 // =============================================================================
@@ -426,6 +862,18 @@ pub const ERR_UPNP_IPV6_EXPECTED_IPV4: &str = "Local address is IPv6, expected I
 /// Local address retrieval error
 pub const ERR_UPNP_GET_LOCAL_ADDRESS: &str = "Failed to get local address: ";
 
+/// NAT-PMP/PCP gateway didn't answer
+pub const ERR_PCP_NO_RESPONSE: &str = "No NAT-PMP/PCP response from gateway ";
+
+/// NAT-PMP/PCP response couldn't be parsed
+pub const ERR_PCP_INVALID_RESPONSE: &str = "Invalid NAT-PMP/PCP response";
+
+/// NAT-PMP/PCP request refused by the gateway
+pub const ERR_PCP_RESULT_CODE: &str = "NAT-PMP/PCP request refused with result code ";
+
+/// Gateway mapped a different external port than requested
+pub const ERR_PCP_PORT_MISMATCH: &str = "Gateway assigned a different external port: ";
+
 // =============================================================================
 // Internationalization Configuration and Error Messages (operator-facing)
 // =============================================================================
@@ -0,0 +1,781 @@
+//! Server export archives
+//!
+//! An archive is a single JSON document holding what an operator needs to
+//! move a server to a fresh database: server settings, channel settings,
+//! user accounts, news posts, voice clips and (optionally) IP bans and trusts.
+//!
+//! Binary content (news images and voice clip audio) is stored once in the
+//! `attachments` object as data URIs and referenced by key, so the rest of
+//! the document stays readable.
+//!
+//! Password hashes are never exported. Imported accounts get a random
+//! temporary password, which the import reports to the operator. Chat
+//! messages are never stored by the server, so there is no chat history to
+//! export.
+
+use std::collections::HashMap;
+
+use chrono::Utc;
+use nexus_common::protocol::NewsAttachment;
+use rand::RngExt;
+use rand::distr::Alphanumeric;
+use serde_json::{Map, Value, json};
+
+use super::channels::ChannelSettings;
+use super::sql::GUEST_USERNAME;
+use super::{Database, Permission, Permissions, hash_password};
+
+/// Archive format identifier
+pub const ARCHIVE_FORMAT: &str = "nexus-export";
+
+/// Archive format version (bump when the layout changes incompatibly)
+pub const ARCHIVE_VERSION: u64 = 1;
+
+/// Length of the temporary passwords given to imported accounts
+const TEMP_PASSWORD_LENGTH: usize = 16;
+
+/// Counts of what an import created
+#[derive(Debug, Default)]
+pub struct ImportSummary {
+    pub users: usize,
+    pub channels: usize,
+    pub news: usize,
+    pub voice_clips: usize,
+    pub ip_rules: usize,
+    /// Temporary password for each imported account as (username, password)
+    pub passwords: Vec<(String, String)>,
+}
+
+impl std::fmt::Display for ImportSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} user(s), {} channel(s), {} news post(s), {} voice clip(s), {} IP rule(s)",
+            self.users, self.channels, self.news, self.voice_clips, self.ip_rules
+        )
+    }
+}
+
+// =============================================================================
+// Export
+// =============================================================================
+
+/// Build an export archive of the whole server
+pub async fn export_archive(db: &Database, include_ip_rules: bool) -> Result<Value, String> {
+    let mut attachments = Map::new();
+
+    let config = json!({
+        "server_name": db.config.get_server_name().await,
+        "server_description": db.config.get_server_description().await,
+        "server_image": db.config.get_server_image().await,
+        "max_connections_per_ip": db.config.get_max_connections_per_ip().await,
+        "max_transfers_per_ip": db.config.get_max_transfers_per_ip().await,
+        "max_active_transfers": db.config.get_max_active_transfers().await,
+        "transfer_small_files_first": db.config.get_transfer_small_files_first().await,
+        "trusted_transfer_weight": db.config.get_trusted_transfer_weight().await,
+        "file_reindex_interval": db.config.get_file_reindex_interval().await,
+        "persistent_channels": db.config.get_persistent_channels().await,
+        "auto_join_channels": db.config.get_auto_join_channels().await,
+    });
+
+    let mut users = Vec::new();
+    let mut guest = Value::Null;
+    for account in db.users.get_all_users().await.map_err(|e| e.to_string())? {
+        let permissions = permission_names(
+            &db.users
+                .get_user_permissions(account.id)
+                .await
+                .map_err(|e| e.to_string())?,
+        );
+        if account.username.eq_ignore_ascii_case(GUEST_USERNAME) {
+            guest = json!({
+                "enabled": account.enabled,
+                "permissions": permissions,
+            });
+            continue;
+        }
+        users.push(json!({
+            "username": account.username,
+            "is_admin": account.is_admin,
+            "is_shared": account.is_shared,
+            "enabled": account.enabled,
+            "created_at": account.created_at,
+            "permissions": permissions,
+        }));
+    }
+
+    let channels: Vec<Value> = db
+        .channels
+        .get_all_channel_settings()
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .map(|settings| {
+            json!({
+                "name": settings.name,
+                "topic": settings.topic,
+                "topic_set_by": settings.topic_set_by,
+                "secret": settings.secret,
+                "voice_limit": settings.voice_limit,
+                "topic_locked": settings.topic_locked,
+                "entry_message": settings.entry_message,
+                "rules": settings.rules,
+                "language": settings.language,
+            })
+        })
+        .collect();
+
+    // Oldest first, so an import recreates posts in their original order
+    let mut news = Vec::new();
+    for record in db
+        .news
+        .get_all_news()
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .rev()
+    {
+        let post_attachments: Vec<Value> = record
+            .attachments
+            .into_iter()
+            .enumerate()
+            .map(|(index, attachment)| match attachment {
+                NewsAttachment::Image { data, caption } => {
+                    let key = format!("news-{}-{}", record.id, index);
+                    attachments.insert(key.clone(), Value::String(data));
+                    json!({"kind": "image", "image": key, "caption": caption})
+                }
+                NewsAttachment::File { path, caption } => {
+                    json!({"kind": "file", "path": path, "caption": caption})
+                }
+            })
+            .collect();
+        news.push(json!({
+            "body": record.body,
+            "attachments": post_attachments,
+            "author": record.author_username,
+            "created_at": record.created_at,
+            "updated_at": record.updated_at,
+        }));
+    }
+
+    let mut voice_clips = Vec::new();
+    for (index, clip) in db
+        .voice_clips
+        .get_all_clips()
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .enumerate()
+    {
+        let Some((_, data)) = db
+            .voice_clips
+            .get_clip_data(&clip.name)
+            .await
+            .map_err(|e| e.to_string())?
+        else {
+            // Deleted since the list was read
+            continue;
+        };
+        let key = format!("clip-{index}");
+        attachments.insert(key.clone(), Value::String(data));
+        voice_clips.push(json!({
+            "name": clip.name,
+            "audio": key,
+            "size": clip.size,
+            "duration_ms": clip.duration_ms,
+            "uploader": clip.uploader_username,
+            "created_at": clip.created_at,
+        }));
+    }
+
+    let mut archive = json!({
+        "format": ARCHIVE_FORMAT,
+        "version": ARCHIVE_VERSION,
+        "exported_at": Utc::now().to_rfc3339(),
+        "config": config,
+        "guest": guest,
+        "users": users,
+        "channels": channels,
+        "news": news,
+        "voice_clips": voice_clips,
+        "attachments": attachments,
+    });
+
+    if include_ip_rules {
+        let bans = db
+            .bans
+            .list_active_bans()
+            .await
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(|ban| {
+                ip_rule_json(
+                    &ban.ip_address,
+                    &ban.nickname,
+                    &ban.reason,
+                    &ban.created_by,
+                    ban.expires_at,
+                )
+            })
+            .collect::<Vec<_>>();
+        let trusts = db
+            .trusts
+            .list_active_trusts()
+            .await
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(|trust| {
+                ip_rule_json(
+                    &trust.ip_address,
+                    &trust.nickname,
+                    &trust.reason,
+                    &trust.created_by,
+                    trust.expires_at,
+                )
+            })
+            .collect::<Vec<_>>();
+        archive["bans"] = Value::Array(bans);
+        archive["trusts"] = Value::Array(trusts);
+    }
+
+    Ok(archive)
+}
+
+/// Sorted permission names, for stable output
+fn permission_names(permissions: &Permissions) -> Vec<String> {
+    let mut names: Vec<String> = permissions
+        .to_vec()
+        .iter()
+        .map(|p| p.as_str().to_string())
+        .collect();
+    names.sort();
+    names
+}
+
+/// JSON for one IP ban or trust
+fn ip_rule_json(
+    ip_address: &str,
+    nickname: &Option<String>,
+    reason: &Option<String>,
+    created_by: &str,
+    expires_at: Option<i64>,
+) -> Value {
+    json!({
+        "ip_address": ip_address,
+        "nickname": nickname,
+        "reason": reason,
+        "created_by": created_by,
+        "expires_at": expires_at,
+    })
+}
+
+// =============================================================================
+// Import
+// =============================================================================
+
+/// Import an export archive into a fresh database
+///
+/// Refuses to run if the database already has accounts other than guest, so
+/// an import can never merge into (or overwrite) a live server. The import is
+/// not transactional: if it fails part way, delete the database and retry.
+///
+/// `fast` is passed to `hash_password` (tests only).
+pub async fn import_archive(
+    db: &Database,
+    archive: &Value,
+    fast: bool,
+) -> Result<ImportSummary, String> {
+    if archive["format"].as_str() != Some(ARCHIVE_FORMAT) {
+        return Err("not a Nexus export archive".to_string());
+    }
+    match archive["version"].as_u64() {
+        Some(ARCHIVE_VERSION) => {}
+        Some(version) => return Err(format!("unsupported archive version {version}")),
+        None => return Err("archive has no version".to_string()),
+    }
+
+    let existing = db
+        .users
+        .count_non_guest_users()
+        .await
+        .map_err(|e| e.to_string())?;
+    if existing > 0 {
+        return Err(
+            "the database already has user accounts; import into a fresh database".to_string(),
+        );
+    }
+
+    let mut summary = ImportSummary::default();
+
+    import_config(db, &archive["config"]).await?;
+
+    // Users, remembering their new ids for news and clip authorship
+    let mut user_ids: HashMap<String, i64> = HashMap::new();
+    for user in array(archive, "users") {
+        let Some(username) = user["username"].as_str() else {
+            continue;
+        };
+        let password = temporary_password();
+        let hashed = hash_password(&password, fast).map_err(|e| e.to_string())?;
+        let account = db
+            .users
+            .create_user(
+                username,
+                &hashed,
+                user["is_admin"].as_bool().unwrap_or(false),
+                user["is_shared"].as_bool().unwrap_or(false),
+                user["enabled"].as_bool().unwrap_or(true),
+                &parse_permissions(&user["permissions"]),
+            )
+            .await
+            .map_err(|e| format!("user {username}: {e}"))?;
+        user_ids.insert(username.to_lowercase(), account.id);
+        summary.passwords.push((username.to_string(), password));
+        summary.users += 1;
+    }
+
+    let guest = &archive["guest"];
+    if guest.is_object() {
+        db.users
+            .update_user(
+                GUEST_USERNAME,
+                None,
+                None,
+                None,
+                guest["enabled"].as_bool(),
+                Some(&parse_permissions(&guest["permissions"])),
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+
+    for channel in array(archive, "channels") {
+        let Some(name) = channel["name"].as_str() else {
+            continue;
+        };
+        db.channels
+            .upsert_channel_settings(&ChannelSettings {
+                name: name.to_string(),
+                topic: channel["topic"].as_str().unwrap_or_default().to_string(),
+                topic_set_by: channel["topic_set_by"]
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_string(),
+                secret: channel["secret"].as_bool().unwrap_or(false),
+                voice_limit: channel["voice_limit"]
+                    .as_u64()
+                    .and_then(|v| u32::try_from(v).ok())
+                    .unwrap_or(0),
+                topic_locked: channel["topic_locked"].as_bool().unwrap_or(false),
+                entry_message: channel["entry_message"]
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_string(),
+                rules: channel["rules"].as_str().unwrap_or_default().to_string(),
+                language: channel["language"].as_str().unwrap_or_default().to_string(),
+            })
+            .await
+            .map_err(|e| format!("channel {name}: {e}"))?;
+        summary.channels += 1;
+    }
+
+    let attachments = &archive["attachments"];
+    for post in array(archive, "news") {
+        // Posts by accounts missing from the archive can't be attributed
+        let Some(author_id) = author_id(&user_ids, &post["author"]) else {
+            continue;
+        };
+        let Some(created_at) = post["created_at"].as_str() else {
+            continue;
+        };
+        let post_attachments = news_attachments(post, attachments);
+        let body = post["body"].as_str();
+        if body.is_none() && post_attachments.is_empty() {
+            continue;
+        }
+        db.news
+            .import_news(
+                body,
+                &post_attachments,
+                author_id,
+                created_at,
+                post["updated_at"].as_str(),
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+        summary.news += 1;
+    }
+
+    for clip in array(archive, "voice_clips") {
+        let (Some(name), Some(uploader_id), Some(data)) = (
+            clip["name"].as_str(),
+            author_id(&user_ids, &clip["uploader"]),
+            clip["audio"]
+                .as_str()
+                .and_then(|key| attachments[key].as_str()),
+        ) else {
+            continue;
+        };
+        db.voice_clips
+            .create_clip(
+                name,
+                data,
+                clip["size"].as_i64().unwrap_or_default(),
+                clip["duration_ms"].as_i64().unwrap_or_default(),
+                uploader_id,
+                i64::MAX,
+            )
+            .await
+            .map_err(|e| format!("voice clip {name}: {e}"))?;
+        summary.voice_clips += 1;
+    }
+
+    for ban in array(archive, "bans") {
+        let Some(ip_address) = ban["ip_address"].as_str() else {
+            continue;
+        };
+        db.bans
+            .create_or_update_ban(
+                ip_address,
+                ban["nickname"].as_str(),
+                ban["reason"].as_str(),
+                ban["created_by"].as_str().unwrap_or_default(),
+                ban["expires_at"].as_i64(),
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+        summary.ip_rules += 1;
+    }
+
+    for trust in array(archive, "trusts") {
+        let Some(ip_address) = trust["ip_address"].as_str() else {
+            continue;
+        };
+        db.trusts
+            .create_or_update_trust(
+                ip_address,
+                trust["nickname"].as_str(),
+                trust["reason"].as_str(),
+                trust["created_by"].as_str().unwrap_or_default(),
+                trust["expires_at"].as_i64(),
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+        summary.ip_rules += 1;
+    }
+
+    Ok(summary)
+}
+
+/// Restore server settings (missing entries keep their defaults)
+async fn import_config(db: &Database, config: &Value) -> Result<(), String> {
+    let u32_field = |key: &str| config[key].as_u64().and_then(|v| u32::try_from(v).ok());
+
+    if let Some(name) = config["server_name"].as_str() {
+        db.config
+            .set_server_name(name)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+    if let Some(description) = config["server_description"].as_str() {
+        db.config
+            .set_server_description(description)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+    if let Some(image) = config["server_image"].as_str() {
+        db.config
+            .set_server_image(image)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+    if let Some(value) = u32_field("max_connections_per_ip") {
+        db.config
+            .set_max_connections_per_ip(value)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+    if let Some(value) = u32_field("max_transfers_per_ip") {
+        db.config
+            .set_max_transfers_per_ip(value)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+    if let Some(value) = u32_field("max_active_transfers") {
+        db.config
+            .set_max_active_transfers(value)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+    if let Some(value) = config["transfer_small_files_first"].as_bool() {
+        db.config
+            .set_transfer_small_files_first(value)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+    if let Some(value) = u32_field("trusted_transfer_weight") {
+        db.config
+            .set_trusted_transfer_weight(value)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+    if let Some(value) = u32_field("file_reindex_interval") {
+        db.config
+            .set_file_reindex_interval(value)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+    if let Some(value) = config["persistent_channels"].as_str() {
+        db.config
+            .set_persistent_channels(value)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+    if let Some(value) = config["auto_join_channels"].as_str() {
+        db.config
+            .set_auto_join_channels(value)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// A news post's attachments, resolving image keys in `attachments`
+///
+/// Archives written before posts had several attachments carry a single
+/// `image` key instead.
+fn news_attachments(post: &Value, attachments: &Value) -> Vec<NewsAttachment> {
+    let image = |key: &Value| {
+        key.as_str()
+            .and_then(|key| attachments[key].as_str())
+            .map(str::to_string)
+    };
+    if let Some(data) = image(&post["image"]) {
+        return vec![NewsAttachment::Image {
+            data,
+            caption: None,
+        }];
+    }
+
+    array(post, "attachments")
+        .filter_map(|attachment| {
+            let caption = attachment["caption"].as_str().map(str::to_string);
+            match attachment["kind"].as_str()? {
+                "image" => Some(NewsAttachment::Image {
+                    data: image(&attachment["image"])?,
+                    caption,
+                }),
+                "file" => Some(NewsAttachment::File {
+                    path: attachment["path"].as_str()?.to_string(),
+                    caption,
+                }),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Elements of a top-level array (empty if missing)
+fn array<'a>(archive: &'a Value, key: &str) -> impl Iterator<Item = &'a Value> {
+    archive[key].as_array().into_iter().flatten()
+}
+
+/// Parse permission names, ignoring ones this server doesn't know
+fn parse_permissions(names: &Value) -> Permissions {
+    let mut permissions = Permissions::new();
+    for name in names.as_array().into_iter().flatten() {
+        if let Some(permission) = name.as_str().and_then(Permission::parse) {
+            permissions.add(permission);
+        }
+    }
+    permissions
+}
+
+/// Look up the new id of an imported account by username
+fn author_id(user_ids: &HashMap<String, i64>, username: &Value) -> Option<i64> {
+    username
+        .as_str()
+        .and_then(|name| user_ids.get(&name.to_lowercase()).copied())
+}
+
+/// Generate a random temporary password for an imported account
+fn temporary_password() -> String {
+    rand::rng()
+        .sample_iter(Alphanumeric)
+        .take(TEMP_PASSWORD_LENGTH)
+        .map(char::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::testing::create_test_db;
+
+    async fn test_db() -> Database {
+        Database::new(create_test_db().await)
+    }
+
+    #[tokio::test]
+    async fn test_export_has_no_password_hashes() {
+        let db = test_db().await;
+        let hashed = hash_password("secret-password", true).unwrap();
+        db.users
+            .create_user("alice", &hashed, true, false, true, &Permissions::new())
+            .await
+            .unwrap();
+
+        let archive = export_archive(&db, false).await.unwrap();
+        let text = archive.to_string();
+        assert!(!text.contains(&hashed));
+        assert!(!text.contains("secret-password"));
+        assert_eq!(archive["users"][0]["username"], "alice");
+        assert!(archive.get("bans").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_export_import_round_trip() {
+        let source = test_db().await;
+        let hashed = hash_password("secret-password", true).unwrap();
+        let alice = source
+            .users
+            .create_user("alice", &hashed, true, false, true, &Permissions::new())
+            .await
+            .unwrap();
+        let mut perms = Permissions::new();
+        perms.add(Permission::ChatSend);
+        source
+            .users
+            .create_user("bob", &hashed, false, false, false, &perms)
+            .await
+            .unwrap();
+        source.config.set_server_name("Old Server").await.unwrap();
+        source
+            .channels
+            .upsert_channel_settings(&ChannelSettings {
+                name: "#lobby".to_string(),
+                topic: "Welcome".to_string(),
+                topic_set_by: "alice".to_string(),
+                secret: true,
+                voice_limit: 4,
+                topic_locked: true,
+                entry_message: "Hi there".to_string(),
+                rules: "Be nice".to_string(),
+                language: "de".to_string(),
+            })
+            .await
+            .unwrap();
+        let news_attachments = vec![
+            NewsAttachment::Image {
+                data: "data:image/png;base64,AAAA".to_string(),
+                caption: Some("Lobby".to_string()),
+            },
+            NewsAttachment::File {
+                path: "Music/set.mp3".to_string(),
+                caption: None,
+            },
+        ];
+        source
+            .news
+            .create_news(Some("first"), &news_attachments, alice.id)
+            .await
+            .unwrap();
+        source
+            .news
+            .create_news(Some("second"), &[], alice.id)
+            .await
+            .unwrap();
+        source
+            .bans
+            .create_or_update_ban("192.0.2.1", None, Some("spam"), "alice", None)
+            .await
+            .unwrap();
+
+        let archive = export_archive(&source, true).await.unwrap();
+
+        let target = test_db().await;
+        let summary = import_archive(&target, &archive, true).await.unwrap();
+        assert_eq!(summary.users, 2);
+        assert_eq!(summary.channels, 1);
+        assert_eq!(summary.news, 2);
+        assert_eq!(summary.ip_rules, 1);
+        assert_eq!(summary.passwords.len(), 2);
+
+        assert_eq!(target.config.get_server_name().await, "Old Server");
+
+        let bob = target
+            .users
+            .get_user_by_username("bob")
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!bob.enabled);
+        assert!(
+            target
+                .users
+                .has_permission(bob.id, Permission::ChatSend)
+                .await
+                .unwrap()
+        );
+
+        let channel = target
+            .channels
+            .get_channel_settings("#lobby")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(channel.topic, "Welcome");
+        assert!(channel.secret);
+        assert_eq!(channel.voice_limit, 4);
+        assert!(channel.topic_locked);
+        assert_eq!(channel.entry_message, "Hi there");
+        assert_eq!(channel.rules, "Be nice");
+        assert_eq!(channel.language, "de");
+
+        // Newest first, with the image restored from the attachments
+        let news = target.news.get_all_news().await.unwrap();
+        assert_eq!(news[0].body.as_deref(), Some("second"));
+        assert_eq!(news[1].attachments, news_attachments);
+        assert_eq!(news[1].author_username, "alice");
+
+        assert!(target.bans.is_ip_banned("192.0.2.1").await.unwrap());
+    }
+
+    #[test]
+    fn test_news_attachments_from_single_image_archive() {
+        let attachments = json!({"news-1": "data:image/png;base64,AAAA"});
+        let post = json!({"body": "first", "image": "news-1"});
+        assert_eq!(
+            news_attachments(&post, &attachments),
+            vec![NewsAttachment::Image {
+                data: "data:image/png;base64,AAAA".to_string(),
+                caption: None,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_import_requires_fresh_database() {
+        let source = test_db().await;
+        let archive = export_archive(&source, false).await.unwrap();
+
+        let target = test_db().await;
+        let hashed = hash_password("secret-password", true).unwrap();
+        target
+            .users
+            .create_user("alice", &hashed, true, false, true, &Permissions::new())
+            .await
+            .unwrap();
+
+        assert!(import_archive(&target, &archive, true).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_import_rejects_other_documents() {
+        let db = test_db().await;
+        assert!(import_archive(&db, &json!({}), true).await.is_err());
+        let future = json!({"format": ARCHIVE_FORMAT, "version": ARCHIVE_VERSION + 1});
+        assert!(import_archive(&db, &future, true).await.is_err());
+    }
+}
@@ -0,0 +1,294 @@
+//! Audit log database operations
+//!
+//! The audit log records administrative actions that must leave a trace even
+//! when the data they touched is gone, such as account erasure, or that
+//! expose another user's view of the server, such as impersonation queries.
+//! Connections cut off or throttled by the protocol limits are recorded too.
+//!
+//! Protocol limit entries arrive in bursts during a flood, so they're queued
+//! with [`AuditDb::record_later`] and written together in one transaction
+//! every [`AUDIT_FLUSH_INTERVAL`] (and at shutdown) instead of holding up the
+//! connection on a database write each.
+
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use nexus_common::protocol::AuditEntry;
+use sqlx::sqlite::SqlitePool;
+
+use crate::db::sql;
+
+/// Audit action recorded when an account is erased
+pub const AUDIT_ACTION_USER_ERASE: &str = "user_erase";
+
+/// Audit action recorded when an admin views the server as another user
+pub const AUDIT_ACTION_IMPERSONATE_QUERY: &str = "impersonate_query";
+
+/// Audit action recorded when an admin deletes leftovers of aborted transfers
+pub const AUDIT_ACTION_FILE_CLEANUP: &str = "file_cleanup";
+
+/// Audit action recorded when an admin repairs what a file area check found
+pub const AUDIT_ACTION_FILE_CHECK: &str = "file_check";
+
+/// Audit action recorded when a connection goes over a protocol limit
+pub const AUDIT_ACTION_PROTOCOL_LIMIT: &str = "protocol_limit";
+
+/// How often queued entries are written to the database
+pub const AUDIT_FLUSH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Most entries waiting to be written; more are dropped until the next flush
+const MAX_PENDING_ENTRIES: usize = 10_000;
+
+/// An audit log entry waiting to be written
+#[derive(Debug)]
+struct PendingEntry {
+    action: &'static str,
+    target: String,
+    actor: String,
+    details: Option<String>,
+    created_at: i64,
+}
+
+/// An audit log entry from the database
+#[cfg(test)]
+#[derive(Debug, Clone)]
+pub struct AuditRecord {
+    pub action: String,
+    pub target: String,
+    pub actor: String,
+    pub details: Option<String>,
+    pub created_at: i64,
+}
+
+/// Database access for audit log operations
+#[derive(Clone)]
+pub struct AuditDb {
+    pool: SqlitePool,
+    /// Entries queued by `record_later`, oldest first
+    pending: Arc<Mutex<Vec<PendingEntry>>>,
+}
+
+impl AuditDb {
+    /// Create a new AuditDb instance
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            pool,
+            pending: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Record an action in the audit log
+    pub async fn record(
+        &self,
+        action: &str,
+        target: &str,
+        actor: &str,
+        details: Option<&str>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(sql::SQL_INSERT_AUDIT_LOG)
+            .bind(action)
+            .bind(target)
+            .bind(actor)
+            .bind(details)
+            .bind(now())
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Queue an action to be recorded with the next flush
+    ///
+    /// The entry keeps the time it was queued. If the queue is full (the
+    /// database has been failing for a while), the entry is dropped.
+    pub fn record_later(
+        &self,
+        action: &'static str,
+        target: &str,
+        actor: &str,
+        details: Option<&str>,
+    ) {
+        let mut pending = self.lock_pending();
+        if pending.len() < MAX_PENDING_ENTRIES {
+            pending.push(PendingEntry {
+                action,
+                target: target.to_string(),
+                actor: actor.to_string(),
+                details: details.map(str::to_string),
+                created_at: now(),
+            });
+        }
+    }
+
+    /// Write the queued entries in one transaction
+    ///
+    /// Entries that couldn't be written are kept for the next flush.
+    pub async fn flush(&self) -> Result<(), sqlx::Error> {
+        let entries = std::mem::take(&mut *self.lock_pending());
+        if entries.is_empty() {
+            return Ok(());
+        }
+
+        let result = self.write(&entries).await;
+        if result.is_err() {
+            // Put them back ahead of anything queued since
+            let mut pending = self.lock_pending();
+            let newer = std::mem::replace(&mut *pending, entries);
+            let room = MAX_PENDING_ENTRIES.saturating_sub(pending.len());
+            pending.extend(newer.into_iter().take(room));
+        }
+        result
+    }
+
+    async fn write(&self, entries: &[PendingEntry]) -> Result<(), sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+        for entry in entries {
+            sqlx::query(sql::SQL_INSERT_AUDIT_LOG)
+                .bind(entry.action)
+                .bind(&entry.target)
+                .bind(&entry.actor)
+                .bind(&entry.details)
+                .bind(entry.created_at)
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await
+    }
+
+    fn lock_pending(&self) -> MutexGuard<'_, Vec<PendingEntry>> {
+        self.pending.lock().expect("audit queue lock poisoned")
+    }
+
+    /// Get the most recent audit log entries, newest first
+    ///
+    /// Entries queued by `record_later` show up once they're flushed.
+    pub async fn recent(&self, limit: usize) -> Result<Vec<AuditEntry>, sqlx::Error> {
+        let rows: Vec<(String, String, String, Option<String>, i64)> =
+            sqlx::query_as(sql::SQL_SELECT_RECENT_AUDIT_LOG)
+                .bind(limit as i64)
+                .fetch_all(&self.pool)
+                .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(action, target, actor, details, created_at)| AuditEntry {
+                action,
+                target,
+                actor,
+                details,
+                created_at,
+            })
+            .collect())
+    }
+
+    /// Get all audit log entries, oldest first
+    #[cfg(test)]
+    pub async fn list(&self) -> Result<Vec<AuditRecord>, sqlx::Error> {
+        let rows: Vec<(String, String, String, Option<String>, i64)> =
+            sqlx::query_as(sql::SQL_SELECT_AUDIT_LOG)
+                .fetch_all(&self.pool)
+                .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| AuditRecord {
+                action: row.0,
+                target: row.1,
+                actor: row.2,
+                details: row.3,
+                created_at: row.4,
+            })
+            .collect())
+    }
+}
+
+/// Current Unix time in seconds
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system time before Unix epoch")
+        .as_secs() as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::testing::create_test_db;
+
+    #[tokio::test]
+    async fn test_record_and_list() {
+        let pool = create_test_db().await;
+        let audit_db = AuditDb::new(pool);
+
+        audit_db
+            .record(AUDIT_ACTION_USER_ERASE, "alice", "admin", Some("2 files"))
+            .await
+            .unwrap();
+        audit_db
+            .record(AUDIT_ACTION_USER_ERASE, "bob", "admin", None)
+            .await
+            .unwrap();
+
+        let entries = audit_db.list().await.unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].action, AUDIT_ACTION_USER_ERASE);
+        assert_eq!(entries[0].target, "alice");
+        assert_eq!(entries[0].actor, "admin");
+        assert_eq!(entries[0].details.as_deref(), Some("2 files"));
+        assert!(entries[0].created_at > 0);
+        assert_eq!(entries[1].target, "bob");
+        assert_eq!(entries[1].details, None);
+    }
+
+    #[tokio::test]
+    async fn test_recent_is_newest_first() {
+        let pool = create_test_db().await;
+        let audit_db = AuditDb::new(pool);
+
+        for target in ["alice", "bob", "carol"] {
+            audit_db
+                .record(AUDIT_ACTION_USER_ERASE, target, "admin", None)
+                .await
+                .unwrap();
+        }
+
+        let entries = audit_db.recent(2).await.unwrap();
+        let targets: Vec<&str> = entries.iter().map(|entry| entry.target.as_str()).collect();
+        assert_eq!(targets, ["carol", "bob"]);
+    }
+
+    #[tokio::test]
+    async fn test_record_later_waits_for_flush() {
+        let pool = create_test_db().await;
+        let audit_db = AuditDb::new(pool);
+
+        audit_db.record_later(AUDIT_ACTION_PROTOCOL_LIMIT, "10.0.0.1", "", Some("flood"));
+        audit_db.record_later(AUDIT_ACTION_PROTOCOL_LIMIT, "10.0.0.2", "bob", None);
+        assert!(audit_db.list().await.unwrap().is_empty());
+
+        audit_db.flush().await.unwrap();
+        let entries = audit_db.list().await.unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].target, "10.0.0.1");
+        assert_eq!(entries[0].details.as_deref(), Some("flood"));
+        assert_eq!(entries[1].actor, "bob");
+
+        // Nothing left to write
+        audit_db.flush().await.unwrap();
+        assert_eq!(audit_db.list().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_failed_flush_keeps_entries() {
+        let pool = create_test_db().await;
+        pool.close().await;
+        let audit_db = AuditDb::new(pool);
+
+        audit_db.record_later(AUDIT_ACTION_PROTOCOL_LIMIT, "10.0.0.1", "", None);
+        assert!(audit_db.flush().await.is_err());
+        audit_db.record_later(AUDIT_ACTION_PROTOCOL_LIMIT, "10.0.0.2", "", None);
+
+        let pending = audit_db.lock_pending();
+        assert_eq!(pending.len(), 2);
+        assert_eq!(pending[0].target, "10.0.0.1");
+    }
+}
@@ -193,6 +193,23 @@ impl BanDb {
         Ok(ips)
     }
 
+    /// Remove an erased account from all bans
+    ///
+    /// Clears matching nickname annotations and replaces the creator name with
+    /// `placeholder`. The bans themselves stay in effect.
+    pub async fn erase_user(&self, username: &str, placeholder: &str) -> Result<(), sqlx::Error> {
+        sqlx::query(sql::SQL_ERASE_BAN_NICKNAME)
+            .bind(username)
+            .execute(&self.pool)
+            .await?;
+        sqlx::query(sql::SQL_ERASE_BAN_CREATOR)
+            .bind(placeholder)
+            .bind(username)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
     /// Check if any bans exist with a given nickname annotation
     pub async fn has_bans_for_nickname(&self, nickname: &str) -> Result<bool, sqlx::Error> {
         let row: (i64,) = sqlx::query_as(sql::SQL_COUNT_BANS_BY_NICKNAME)
@@ -7,6 +7,8 @@ use std::io;
 
 use sqlx::SqlitePool;
 
+use nexus_common::protocol::ChatTopicChange;
+
 /// Channel settings from database
 #[derive(Debug, Clone)]
 pub struct ChannelSettings {
@@ -14,6 +16,56 @@ pub struct ChannelSettings {
     pub topic: String,
     pub topic_set_by: String,
     pub secret: bool,
+    /// Maximum voice participants (0 = unlimited)
+    pub voice_limit: u32,
+    /// Whether only users with chat_topic_lock may change the topic
+    pub topic_locked: bool,
+    /// Message shown to users joining the channel (empty = none)
+    pub entry_message: String,
+    /// Channel rules (empty = none)
+    pub rules: String,
+    /// Language tag, e.g. "en" (empty = none)
+    pub language: String,
+}
+
+/// Row of the channel_settings table, in column order
+type ChannelSettingsRow = (
+    String,
+    String,
+    String,
+    i32,
+    i64,
+    i32,
+    String,
+    String,
+    String,
+);
+
+impl ChannelSettings {
+    fn from_row(row: ChannelSettingsRow) -> Self {
+        let (
+            name,
+            topic,
+            topic_set_by,
+            secret,
+            voice_limit,
+            topic_locked,
+            entry_message,
+            rules,
+            language,
+        ) = row;
+        Self {
+            name,
+            topic,
+            topic_set_by,
+            secret: secret != 0,
+            voice_limit: voice_limit as u32,
+            topic_locked: topic_locked != 0,
+            entry_message,
+            rules,
+            language,
+        }
+    }
 }
 
 /// Database interface for channel settings
@@ -32,44 +84,29 @@ impl ChannelDb {
     ///
     /// Returns None if the channel doesn't exist in the database.
     pub async fn get_channel_settings(&self, name: &str) -> io::Result<Option<ChannelSettings>> {
-        let result = sqlx::query_as::<_, (String, String, String, i32)>(
-            "SELECT name, topic, topic_set_by, secret FROM channel_settings WHERE LOWER(name) = LOWER(?)",
+        let result = sqlx::query_as::<_, ChannelSettingsRow>(
+            "SELECT name, topic, topic_set_by, secret, voice_limit, topic_locked, entry_message, rules, language FROM channel_settings WHERE LOWER(name) = LOWER(?)",
         )
         .bind(name)
         .fetch_optional(&self.pool)
         .await
         .map_err(|e| io::Error::other(e.to_string()))?;
 
-        Ok(
-            result.map(|(name, topic, topic_set_by, secret)| ChannelSettings {
-                name,
-                topic,
-                topic_set_by,
-                secret: secret != 0,
-            }),
-        )
+        Ok(result.map(ChannelSettings::from_row))
     }
 
     /// Get all channel settings
     ///
     /// Returns settings for all persistent channels.
     pub async fn get_all_channel_settings(&self) -> io::Result<Vec<ChannelSettings>> {
-        let results = sqlx::query_as::<_, (String, String, String, i32)>(
-            "SELECT name, topic, topic_set_by, secret FROM channel_settings",
+        let results = sqlx::query_as::<_, ChannelSettingsRow>(
+            "SELECT name, topic, topic_set_by, secret, voice_limit, topic_locked, entry_message, rules, language FROM channel_settings",
         )
         .fetch_all(&self.pool)
         .await
         .map_err(|e| io::Error::other(e.to_string()))?;
 
-        Ok(results
-            .into_iter()
-            .map(|(name, topic, topic_set_by, secret)| ChannelSettings {
-                name,
-                topic,
-                topic_set_by,
-                secret: secret != 0,
-            })
-            .collect())
+        Ok(results.into_iter().map(ChannelSettings::from_row).collect())
     }
 
     /// Create or update channel settings
@@ -77,17 +114,27 @@ impl ChannelDb {
     /// Uses upsert semantics - creates if doesn't exist, updates if it does.
     pub async fn upsert_channel_settings(&self, settings: &ChannelSettings) -> io::Result<()> {
         sqlx::query(
-            "INSERT INTO channel_settings (name, topic, topic_set_by, secret)
-             VALUES (?, ?, ?, ?)
+            "INSERT INTO channel_settings (name, topic, topic_set_by, secret, voice_limit, topic_locked, entry_message, rules, language)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
              ON CONFLICT(name) DO UPDATE SET
                 topic = excluded.topic,
                 topic_set_by = excluded.topic_set_by,
-                secret = excluded.secret",
+                secret = excluded.secret,
+                voice_limit = excluded.voice_limit,
+                topic_locked = excluded.topic_locked,
+                entry_message = excluded.entry_message,
+                rules = excluded.rules,
+                language = excluded.language",
         )
         .bind(&settings.name)
         .bind(&settings.topic)
         .bind(&settings.topic_set_by)
         .bind(settings.secret as i32)
+        .bind(i64::from(settings.voice_limit))
+        .bind(settings.topic_locked as i32)
+        .bind(&settings.entry_message)
+        .bind(&settings.rules)
+        .bind(&settings.language)
         .execute(&self.pool)
         .await
         .map_err(|e| io::Error::other(e.to_string()))?;
@@ -122,9 +169,151 @@ impl ChannelDb {
         Ok(())
     }
 
+    /// Update only the voice participant limit for a channel
+    pub async fn set_voice_limit(&self, name: &str, voice_limit: u32) -> io::Result<()> {
+        sqlx::query("UPDATE channel_settings SET voice_limit = ? WHERE LOWER(name) = LOWER(?)")
+            .bind(i64::from(voice_limit))
+            .bind(name)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Update only the topic lock for a channel
+    pub async fn set_topic_locked(&self, name: &str, locked: bool) -> io::Result<()> {
+        sqlx::query("UPDATE channel_settings SET topic_locked = ? WHERE LOWER(name) = LOWER(?)")
+            .bind(locked as i32)
+            .bind(name)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Update only the entry message for a channel
+    pub async fn set_entry_message(&self, name: &str, entry_message: &str) -> io::Result<()> {
+        sqlx::query("UPDATE channel_settings SET entry_message = ? WHERE LOWER(name) = LOWER(?)")
+            .bind(entry_message)
+            .bind(name)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Update only the rules for a channel
+    pub async fn set_rules(&self, name: &str, rules: &str) -> io::Result<()> {
+        sqlx::query("UPDATE channel_settings SET rules = ? WHERE LOWER(name) = LOWER(?)")
+            .bind(rules)
+            .bind(name)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Update only the language tag for a channel
+    pub async fn set_language(&self, name: &str, language: &str) -> io::Result<()> {
+        sqlx::query("UPDATE channel_settings SET language = ? WHERE LOWER(name) = LOWER(?)")
+            .bind(language)
+            .bind(name)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Record a topic change, keeping only the newest `keep` changes of the channel
+    pub async fn add_topic_change(
+        &self,
+        name: &str,
+        change: &ChatTopicChange,
+        keep: usize,
+    ) -> io::Result<()> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        sqlx::query(
+            "INSERT INTO channel_topic_history (channel, topic, old_topic, set_by, changed_at)
+             VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(name)
+        .bind(change.topic.as_deref().unwrap_or(""))
+        .bind(change.old_topic.as_deref().unwrap_or(""))
+        .bind(change.set_by.as_deref().unwrap_or(""))
+        .bind(change.changed_at as i64)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| io::Error::other(e.to_string()))?;
+
+        sqlx::query(
+            "DELETE FROM channel_topic_history WHERE channel = ? AND id NOT IN (
+                SELECT id FROM channel_topic_history WHERE channel = ? ORDER BY id DESC LIMIT ?
+             )",
+        )
+        .bind(name)
+        .bind(name)
+        .bind(keep as i64)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| io::Error::other(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Get a channel's topic changes, newest first
+    pub async fn get_topic_history(&self, name: &str) -> io::Result<Vec<ChatTopicChange>> {
+        let rows = sqlx::query_as::<_, (String, String, String, i64)>(
+            "SELECT topic, old_topic, set_by, changed_at FROM channel_topic_history
+             WHERE channel = ? ORDER BY id DESC",
+        )
+        .bind(name)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| io::Error::other(e.to_string()))?;
+
+        let non_empty = |value: String| (!value.is_empty()).then_some(value);
+        Ok(rows
+            .into_iter()
+            .map(|(topic, old_topic, set_by, changed_at)| ChatTopicChange {
+                topic: non_empty(topic),
+                old_topic: non_empty(old_topic),
+                set_by: non_empty(set_by),
+                changed_at: changed_at as u64,
+            })
+            .collect())
+    }
+
+    /// Forget who made topic changes, for changes made by `nickname`
+    ///
+    /// Used when an account is erased.
+    pub async fn clear_topic_history_setter(&self, nickname: &str) -> io::Result<()> {
+        sqlx::query("UPDATE channel_topic_history SET set_by = '' WHERE set_by = ?")
+            .bind(nickname)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
     /// Delete channel settings
     ///
     /// Used when a channel is removed from the persistent channels list.
+    /// Its topic history goes with it.
     pub async fn delete_channel_settings(&self, name: &str) -> io::Result<()> {
         sqlx::query("DELETE FROM channel_settings WHERE LOWER(name) = LOWER(?)")
             .bind(name)
@@ -132,6 +321,12 @@ impl ChannelDb {
             .await
             .map_err(|e| io::Error::other(e.to_string()))?;
 
+        sqlx::query("DELETE FROM channel_topic_history WHERE channel = ?")
+            .bind(name)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
         Ok(())
     }
 
@@ -177,6 +372,7 @@ mod tests {
         assert_eq!(settings.topic, "");
         assert_eq!(settings.topic_set_by, "");
         assert!(!settings.secret);
+        assert_eq!(settings.voice_limit, 0);
     }
 
     #[tokio::test]
@@ -202,6 +398,11 @@ mod tests {
             topic: "General chat".to_string(),
             topic_set_by: "admin".to_string(),
             secret: false,
+            voice_limit: 0,
+            topic_locked: false,
+            entry_message: String::new(),
+            rules: String::new(),
+            language: String::new(),
         };
 
         db.upsert_channel_settings(&settings).await.unwrap();
@@ -224,6 +425,11 @@ mod tests {
             topic: "Welcome to Nexus!".to_string(),
             topic_set_by: "admin".to_string(),
             secret: true,
+            voice_limit: 0,
+            topic_locked: false,
+            entry_message: String::new(),
+            rules: String::new(),
+            language: String::new(),
         };
 
         db.upsert_channel_settings(&settings).await.unwrap();
@@ -280,6 +486,82 @@ mod tests {
         assert!(!result.secret);
     }
 
+    #[tokio::test]
+    async fn test_set_voice_limit() {
+        let pool = create_test_db().await;
+        let db = ChannelDb::new(pool);
+
+        db.set_voice_limit(DEFAULT_CHANNEL, 8).await.unwrap();
+
+        let result = db
+            .get_channel_settings(DEFAULT_CHANNEL)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(result.voice_limit, 8);
+    }
+
+    #[tokio::test]
+    async fn test_set_topic_locked() {
+        let pool = create_test_db().await;
+        let db = ChannelDb::new(pool);
+
+        db.set_topic_locked(DEFAULT_CHANNEL, true).await.unwrap();
+
+        let result = db
+            .get_channel_settings(DEFAULT_CHANNEL)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(result.topic_locked);
+    }
+
+    #[tokio::test]
+    async fn test_set_language() {
+        let pool = create_test_db().await;
+        let db = ChannelDb::new(pool);
+
+        db.set_language(DEFAULT_CHANNEL, "pt-BR").await.unwrap();
+
+        let result = db
+            .get_channel_settings(DEFAULT_CHANNEL)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(result.language, "pt-BR");
+    }
+
+    #[tokio::test]
+    async fn test_topic_history_keeps_newest() {
+        let pool = create_test_db().await;
+        let db = ChannelDb::new(pool);
+
+        for n in 0..5u64 {
+            let change = ChatTopicChange {
+                topic: Some(format!("Topic {n}")),
+                old_topic: n.checked_sub(1).map(|old| format!("Topic {old}")),
+                set_by: Some("alice".to_string()),
+                changed_at: 1_700_000_000 + n,
+            };
+            db.add_topic_change(DEFAULT_CHANNEL, &change, 3)
+                .await
+                .unwrap();
+        }
+
+        // Channel names compare case-insensitively
+        let history = db
+            .get_topic_history(&DEFAULT_CHANNEL.to_uppercase())
+            .await
+            .unwrap();
+        let topics: Vec<_> = history.iter().map(|c| c.topic.as_deref()).collect();
+        assert_eq!(topics, [Some("Topic 4"), Some("Topic 3"), Some("Topic 2")]);
+        assert_eq!(history[0].old_topic.as_deref(), Some("Topic 3"));
+
+        db.clear_topic_history_setter("alice").await.unwrap();
+        let history = db.get_topic_history(DEFAULT_CHANNEL).await.unwrap();
+        assert!(history.iter().all(|c| c.set_by.is_none()));
+    }
+
     #[tokio::test]
     async fn test_delete_channel_settings() {
         let pool = create_test_db().await;
@@ -291,6 +573,11 @@ mod tests {
             topic: "".to_string(),
             topic_set_by: "".to_string(),
             secret: false,
+            voice_limit: 0,
+            topic_locked: false,
+            entry_message: String::new(),
+            rules: String::new(),
+            language: String::new(),
         };
         db.upsert_channel_settings(&settings).await.unwrap();
 
@@ -315,6 +602,11 @@ mod tests {
             topic: "General".to_string(),
             topic_set_by: "admin".to_string(),
             secret: false,
+            voice_limit: 0,
+            topic_locked: false,
+            entry_message: String::new(),
+            rules: String::new(),
+            language: String::new(),
         };
         db.upsert_channel_settings(&settings).await.unwrap();
 
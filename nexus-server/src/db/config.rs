@@ -12,11 +12,13 @@ use sqlx::SqlitePool;
 use super::sql::{SQL_GET_CONFIG, SQL_SET_CONFIG};
 use crate::constants::{
     CONFIG_KEY_AUTO_JOIN_CHANNELS, CONFIG_KEY_FILE_REINDEX_INTERVAL,
-    CONFIG_KEY_MAX_CONNECTIONS_PER_IP, CONFIG_KEY_MAX_TRANSFERS_PER_IP,
-    CONFIG_KEY_PERSISTENT_CHANNELS, CONFIG_KEY_SERVER_DESCRIPTION, CONFIG_KEY_SERVER_IMAGE,
-    CONFIG_KEY_SERVER_NAME, DEFAULT_AUTO_JOIN_CHANNELS, DEFAULT_FILE_REINDEX_INTERVAL,
-    DEFAULT_MAX_CONNECTIONS_PER_IP, DEFAULT_MAX_TRANSFERS_PER_IP, DEFAULT_PERSISTENT_CHANNELS,
-    DEFAULT_SERVER_DESCRIPTION, DEFAULT_SERVER_IMAGE, DEFAULT_SERVER_NAME,
+    CONFIG_KEY_MAX_ACTIVE_TRANSFERS, CONFIG_KEY_MAX_CONNECTIONS_PER_IP,
+    CONFIG_KEY_MAX_TRANSFERS_PER_IP, CONFIG_KEY_PERSISTENT_CHANNELS, CONFIG_KEY_SERVER_DESCRIPTION,
+    CONFIG_KEY_SERVER_IMAGE, CONFIG_KEY_SERVER_NAME, CONFIG_KEY_TRANSFER_SMALL_FILES_FIRST,
+    CONFIG_KEY_TRUSTED_TRANSFER_WEIGHT, DEFAULT_AUTO_JOIN_CHANNELS, DEFAULT_FILE_REINDEX_INTERVAL,
+    DEFAULT_MAX_ACTIVE_TRANSFERS, DEFAULT_MAX_CONNECTIONS_PER_IP, DEFAULT_MAX_TRANSFERS_PER_IP,
+    DEFAULT_PERSISTENT_CHANNELS, DEFAULT_SERVER_DESCRIPTION, DEFAULT_SERVER_IMAGE,
+    DEFAULT_SERVER_NAME, DEFAULT_TRANSFER_SMALL_FILES_FIRST, DEFAULT_TRUSTED_TRANSFER_WEIGHT,
     ERR_SERVER_DESC_INVALID_CHARS, ERR_SERVER_DESC_NEWLINES, ERR_SERVER_DESC_TOO_LONG,
     ERR_SERVER_IMAGE_INVALID_FORMAT, ERR_SERVER_IMAGE_TOO_LARGE, ERR_SERVER_IMAGE_UNSUPPORTED_TYPE,
     ERR_SERVER_NAME_EMPTY, ERR_SERVER_NAME_INVALID_CHARS, ERR_SERVER_NAME_NEWLINES,
@@ -97,6 +99,100 @@ impl ConfigDb {
         Ok(())
     }
 
+    /// Get the maximum simultaneous active transfers (server-wide)
+    ///
+    /// Returns the configured value, or 0 (the default, unlimited) if not found or invalid.
+    pub async fn get_max_active_transfers(&self) -> usize {
+        sqlx::query_scalar::<_, String>(SQL_GET_CONFIG)
+            .bind(CONFIG_KEY_MAX_ACTIVE_TRANSFERS)
+            .fetch_one(&self.pool)
+            .await
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_MAX_ACTIVE_TRANSFERS)
+    }
+
+    /// Set the maximum simultaneous active transfers (server-wide)
+    ///
+    /// A value of 0 means unlimited; transfers beyond the limit are queued.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub async fn set_max_active_transfers(&self, value: u32) -> io::Result<()> {
+        sqlx::query(SQL_SET_CONFIG)
+            .bind(value.to_string())
+            .bind(CONFIG_KEY_MAX_ACTIVE_TRANSFERS)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Get whether queued transfers with smaller total size start first
+    ///
+    /// Returns the configured value, or false (the default) if not found or invalid.
+    pub async fn get_transfer_small_files_first(&self) -> bool {
+        sqlx::query_scalar::<_, String>(SQL_GET_CONFIG)
+            .bind(CONFIG_KEY_TRANSFER_SMALL_FILES_FIRST)
+            .fetch_one(&self.pool)
+            .await
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_TRANSFER_SMALL_FILES_FIRST)
+    }
+
+    /// Set whether queued transfers with smaller total size start first
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub async fn set_transfer_small_files_first(&self, value: bool) -> io::Result<()> {
+        sqlx::query(SQL_SET_CONFIG)
+            .bind(value.to_string())
+            .bind(CONFIG_KEY_TRANSFER_SMALL_FILES_FIRST)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Get the fair-share weight for transfers from trusted IPs
+    ///
+    /// Returns the configured value, or 2 (the default) if not found or invalid.
+    /// Values below 1 are treated as invalid.
+    pub async fn get_trusted_transfer_weight(&self) -> u32 {
+        sqlx::query_scalar::<_, String>(SQL_GET_CONFIG)
+            .bind(CONFIG_KEY_TRUSTED_TRANSFER_WEIGHT)
+            .fetch_one(&self.pool)
+            .await
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .filter(|&weight| weight >= 1)
+            .unwrap_or(DEFAULT_TRUSTED_TRANSFER_WEIGHT)
+    }
+
+    /// Set the fair-share weight for transfers from trusted IPs
+    ///
+    /// A weight of 1 gives trusted IPs no advantage; values below 1 are stored
+    /// as 1.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub async fn set_trusted_transfer_weight(&self, value: u32) -> io::Result<()> {
+        sqlx::query(SQL_SET_CONFIG)
+            .bind(value.max(1).to_string())
+            .bind(CONFIG_KEY_TRUSTED_TRANSFER_WEIGHT)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        Ok(())
+    }
+
     /// Get the server name
     ///
     /// Returns the configured value, or "Nexus BBS" (the default) if not found.
@@ -409,6 +505,63 @@ mod tests {
         assert_eq!(limit, 0);
     }
 
+    #[tokio::test]
+    async fn test_get_max_active_transfers_default() {
+        let pool = create_test_db().await;
+        let config_db = ConfigDb::new(pool);
+
+        // Migration sets default to 0 (unlimited)
+        let limit = config_db.get_max_active_transfers().await;
+        assert_eq!(limit, 0);
+    }
+
+    #[tokio::test]
+    async fn test_set_max_active_transfers() {
+        let pool = create_test_db().await;
+        let config_db = ConfigDb::new(pool);
+
+        config_db.set_max_active_transfers(8).await.unwrap();
+        let limit = config_db.get_max_active_transfers().await;
+        assert_eq!(limit, 8);
+    }
+
+    #[tokio::test]
+    async fn test_transfer_small_files_first() {
+        let pool = create_test_db().await;
+        let config_db = ConfigDb::new(pool);
+
+        // Migration sets default to false
+        assert!(!config_db.get_transfer_small_files_first().await);
+
+        config_db
+            .set_transfer_small_files_first(true)
+            .await
+            .unwrap();
+        assert!(config_db.get_transfer_small_files_first().await);
+
+        config_db
+            .set_transfer_small_files_first(false)
+            .await
+            .unwrap();
+        assert!(!config_db.get_transfer_small_files_first().await);
+    }
+
+    #[tokio::test]
+    async fn test_trusted_transfer_weight() {
+        let pool = create_test_db().await;
+        let config_db = ConfigDb::new(pool);
+
+        // Migration sets default to 2
+        assert_eq!(config_db.get_trusted_transfer_weight().await, 2);
+
+        config_db.set_trusted_transfer_weight(5).await.unwrap();
+        assert_eq!(config_db.get_trusted_transfer_weight().await, 5);
+
+        // Zero would stall the scheduler, so it is stored as 1
+        config_db.set_trusted_transfer_weight(0).await.unwrap();
+        assert_eq!(config_db.get_trusted_transfer_weight().await, 1);
+    }
+
     #[tokio::test]
     async fn test_get_server_name_default() {
         let pool = create_test_db().await;
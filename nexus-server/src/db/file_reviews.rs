@@ -0,0 +1,436 @@
+//! File rating and comment database operations
+//!
+//! Reviews are keyed by the file's path relative to the file root, like file
+//! activity, so file operations that move or delete files keep them in sync.
+
+use std::collections::HashMap;
+
+use chrono::Utc;
+use nexus_common::protocol::FileRating;
+use sqlx::sqlite::SqlitePool;
+
+use crate::db::sql;
+
+/// A comment on a file from the database
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileCommentRecord {
+    pub id: i64,
+    /// Path relative to the file root (e.g. "/shared/song.mp3")
+    pub path: String,
+    pub author_id: i64,
+    pub author_username: String,
+    pub body: String,
+    /// Unix timestamp
+    pub created_at: i64,
+}
+
+/// Row type for comment queries with author join
+type FileCommentRow = (i64, String, i64, String, String, i64);
+
+impl From<FileCommentRow> for FileCommentRecord {
+    fn from(row: FileCommentRow) -> Self {
+        Self {
+            id: row.0,
+            path: row.1,
+            author_id: row.2,
+            author_username: row.3,
+            body: row.4,
+            created_at: row.5,
+        }
+    }
+}
+
+/// Build a rating from a count and sum (None when nobody has rated)
+fn rating_from_row(count: i64, total: i64) -> Option<FileRating> {
+    (count > 0).then_some(FileRating {
+        count: count as u32,
+        total: total as u32,
+    })
+}
+
+/// Database access for file ratings and comments
+#[derive(Clone)]
+pub struct FileReviewDb {
+    pool: SqlitePool,
+}
+
+impl FileReviewDb {
+    /// Create a new FileReviewDb instance
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Set a user's rating of a file (1 to 5), or clear it with `None`
+    pub async fn set_rating(
+        &self,
+        path: &str,
+        user_id: i64,
+        rating: Option<u8>,
+    ) -> Result<(), sqlx::Error> {
+        match rating {
+            Some(rating) => {
+                sqlx::query(sql::SQL_SET_FILE_RATING)
+                    .bind(path)
+                    .bind(user_id)
+                    .bind(i64::from(rating))
+                    .execute(&self.pool)
+                    .await?;
+            }
+            None => {
+                sqlx::query(sql::SQL_DELETE_FILE_RATING)
+                    .bind(path)
+                    .bind(user_id)
+                    .execute(&self.pool)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Get a file's rating (None if nobody has rated it)
+    pub async fn rating(&self, path: &str) -> Result<Option<FileRating>, sqlx::Error> {
+        let (count, total): (i64, i64) = sqlx::query_as(sql::SQL_SELECT_FILE_RATING)
+            .bind(path)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(rating_from_row(count, total))
+    }
+
+    /// Get a user's own rating of a file
+    pub async fn user_rating(&self, path: &str, user_id: i64) -> Result<Option<u8>, sqlx::Error> {
+        let row: Option<(i64,)> = sqlx::query_as(sql::SQL_SELECT_USER_FILE_RATING)
+            .bind(path)
+            .bind(user_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(|(rating,)| rating as u8))
+    }
+
+    /// Get the ratings of several files at once, keyed by path
+    ///
+    /// Files nobody has rated are left out.
+    pub async fn ratings(
+        &self,
+        paths: &[String],
+    ) -> Result<HashMap<String, FileRating>, sqlx::Error> {
+        if paths.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let paths = serde_json::to_string(paths).map_err(|e| sqlx::Error::Encode(e.into()))?;
+        let rows: Vec<(String, i64, i64)> = sqlx::query_as(sql::SQL_SELECT_FILE_RATINGS)
+            .bind(paths)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(path, count, total)| Some((path, rating_from_row(count, total)?)))
+            .collect())
+    }
+
+    /// Add a comment to a file
+    ///
+    /// Returns the created comment.
+    pub async fn add_comment(
+        &self,
+        path: &str,
+        author_id: i64,
+        body: &str,
+    ) -> Result<FileCommentRecord, sqlx::Error> {
+        let result = sqlx::query(sql::SQL_INSERT_FILE_COMMENT)
+            .bind(path)
+            .bind(author_id)
+            .bind(body)
+            .bind(Utc::now().timestamp())
+            .execute(&self.pool)
+            .await?;
+
+        self.get_comment(result.last_insert_rowid())
+            .await?
+            .ok_or(sqlx::Error::RowNotFound)
+    }
+
+    /// Get a file's comments, oldest first
+    pub async fn comments(&self, path: &str) -> Result<Vec<FileCommentRecord>, sqlx::Error> {
+        let rows: Vec<FileCommentRow> = sqlx::query_as(sql::SQL_SELECT_FILE_COMMENTS)
+            .bind(path)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rows.into_iter().map(FileCommentRecord::from).collect())
+    }
+
+    /// Get a single comment by ID
+    pub async fn get_comment(&self, id: i64) -> Result<Option<FileCommentRecord>, sqlx::Error> {
+        let row: Option<FileCommentRow> = sqlx::query_as(sql::SQL_SELECT_FILE_COMMENT_BY_ID)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(FileCommentRecord::from))
+    }
+
+    /// Count the comments on a file
+    pub async fn comment_count(&self, path: &str) -> Result<u64, sqlx::Error> {
+        let (count,): (i64,) = sqlx::query_as(sql::SQL_COUNT_FILE_COMMENTS)
+            .bind(path)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(count as u64)
+    }
+
+    /// Delete a comment
+    ///
+    /// Returns true if the comment was deleted, false if it didn't exist.
+    pub async fn delete_comment(&self, id: i64) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(sql::SQL_DELETE_FILE_COMMENT)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Move the reviews of a file, or of everything under a directory, to a new path
+    ///
+    /// Reviews left at the destination by a file that no longer exists there
+    /// are dropped first.
+    pub async fn move_reviews(&self, from: &str, to: &str) -> Result<(), sqlx::Error> {
+        let from_dir = format!("{from}/");
+        let to_dir = format!("{to}/");
+        let mut tx = self.pool.begin().await?;
+
+        for query in [
+            sql::SQL_DELETE_FILE_RATINGS_UNDER,
+            sql::SQL_DELETE_FILE_COMMENTS_UNDER,
+        ] {
+            sqlx::query(query)
+                .bind(to)
+                .bind(&to_dir)
+                .bind(&to_dir)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        for query in [sql::SQL_MOVE_FILE_RATINGS, sql::SQL_MOVE_FILE_COMMENTS] {
+            sqlx::query(query)
+                .bind(to)
+                .bind(from)
+                .bind(from)
+                .bind(&from_dir)
+                .bind(&from_dir)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        tx.commit().await
+    }
+
+    /// Get every path that has ratings or comments
+    pub async fn reviewed_paths(&self) -> Result<Vec<String>, sqlx::Error> {
+        sqlx::query_scalar(sql::SQL_SELECT_REVIEWED_PATHS)
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    /// Delete the reviews of a file, or of everything under a directory
+    pub async fn delete_reviews(&self, path: &str) -> Result<(), sqlx::Error> {
+        let dir = format!("{path}/");
+        for query in [
+            sql::SQL_DELETE_FILE_RATINGS_UNDER,
+            sql::SQL_DELETE_FILE_COMMENTS_UNDER,
+        ] {
+            sqlx::query(query)
+                .bind(path)
+                .bind(&dir)
+                .bind(&dir)
+                .execute(&self.pool)
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::testing::create_test_db;
+    use crate::db::{Permissions, UserDb};
+
+    async fn create_user(pool: &SqlitePool, username: &str) -> i64 {
+        UserDb::new(pool.clone())
+            .create_user(username, "hash", false, false, true, &Permissions::new())
+            .await
+            .unwrap()
+            .id
+    }
+
+    #[tokio::test]
+    async fn test_ratings_aggregate_and_clear() {
+        let pool = create_test_db().await;
+        let reviews = FileReviewDb::new(pool.clone());
+        let alice = create_user(&pool, "alice").await;
+        let bob = create_user(&pool, "bob").await;
+
+        assert_eq!(reviews.rating("/shared/a.txt").await.unwrap(), None);
+
+        reviews
+            .set_rating("/shared/a.txt", alice, Some(5))
+            .await
+            .unwrap();
+        reviews
+            .set_rating("/shared/a.txt", bob, Some(2))
+            .await
+            .unwrap();
+        // Rating again replaces the user's earlier rating
+        reviews
+            .set_rating("/shared/a.txt", bob, Some(3))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            reviews.rating("/shared/a.txt").await.unwrap(),
+            Some(FileRating { count: 2, total: 8 })
+        );
+        assert_eq!(
+            reviews.user_rating("/shared/a.txt", bob).await.unwrap(),
+            Some(3)
+        );
+
+        reviews
+            .set_rating("/shared/a.txt", bob, None)
+            .await
+            .unwrap();
+        assert_eq!(
+            reviews.rating("/shared/a.txt").await.unwrap(),
+            Some(FileRating { count: 1, total: 5 })
+        );
+        assert_eq!(
+            reviews.user_rating("/shared/a.txt", bob).await.unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ratings_batch() {
+        let pool = create_test_db().await;
+        let reviews = FileReviewDb::new(pool.clone());
+        let alice = create_user(&pool, "alice").await;
+
+        reviews.set_rating("/a.txt", alice, Some(4)).await.unwrap();
+        reviews.set_rating("/c.txt", alice, Some(1)).await.unwrap();
+
+        let ratings = reviews
+            .ratings(&["/a.txt".to_string(), "/b.txt".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(ratings.len(), 1);
+        assert_eq!(ratings["/a.txt"], FileRating { count: 1, total: 4 });
+
+        assert!(reviews.ratings(&[]).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_comments() {
+        let pool = create_test_db().await;
+        let reviews = FileReviewDb::new(pool.clone());
+        let alice = create_user(&pool, "alice").await;
+
+        let first = reviews
+            .add_comment("/shared/a.txt", alice, "First")
+            .await
+            .unwrap();
+        assert_eq!(first.author_username, "alice");
+        reviews
+            .add_comment("/shared/a.txt", alice, "Second")
+            .await
+            .unwrap();
+        reviews
+            .add_comment("/shared/b.txt", alice, "Other file")
+            .await
+            .unwrap();
+
+        let comments = reviews.comments("/shared/a.txt").await.unwrap();
+        let bodies: Vec<&str> = comments.iter().map(|c| c.body.as_str()).collect();
+        assert_eq!(bodies, vec!["First", "Second"]);
+        assert_eq!(reviews.comment_count("/shared/a.txt").await.unwrap(), 2);
+
+        assert!(reviews.delete_comment(first.id).await.unwrap());
+        assert!(!reviews.delete_comment(first.id).await.unwrap());
+        assert_eq!(reviews.get_comment(first.id).await.unwrap(), None);
+        assert_eq!(reviews.comment_count("/shared/a.txt").await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_move_and_delete_reviews() {
+        let pool = create_test_db().await;
+        let reviews = FileReviewDb::new(pool.clone());
+        let alice = create_user(&pool, "alice").await;
+
+        reviews
+            .set_rating("/shared/music/a.mp3", alice, Some(5))
+            .await
+            .unwrap();
+        reviews
+            .add_comment("/shared/music/a.mp3", alice, "Great")
+            .await
+            .unwrap();
+        reviews
+            .add_comment("/shared/musical.txt", alice, "Unrelated")
+            .await
+            .unwrap();
+        // Stale review at the destination
+        reviews
+            .add_comment("/shared/songs/a.mp3", alice, "Stale")
+            .await
+            .unwrap();
+
+        reviews
+            .move_reviews("/shared/music", "/shared/songs")
+            .await
+            .unwrap();
+
+        let moved = reviews.comments("/shared/songs/a.mp3").await.unwrap();
+        let bodies: Vec<&str> = moved.iter().map(|c| c.body.as_str()).collect();
+        assert_eq!(bodies, vec!["Great"]);
+        assert_eq!(
+            reviews.rating("/shared/songs/a.mp3").await.unwrap(),
+            Some(FileRating { count: 1, total: 5 })
+        );
+        // Only the directory's contents move, not paths that merely share its name
+        assert_eq!(
+            reviews.comment_count("/shared/musical.txt").await.unwrap(),
+            1
+        );
+
+        reviews.delete_reviews("/shared/songs").await.unwrap();
+        assert_eq!(
+            reviews.comment_count("/shared/songs/a.mp3").await.unwrap(),
+            0
+        );
+        assert_eq!(reviews.rating("/shared/songs/a.mp3").await.unwrap(), None);
+        assert_eq!(
+            reviews.comment_count("/shared/musical.txt").await.unwrap(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reviews_removed_with_account() {
+        let pool = create_test_db().await;
+        let reviews = FileReviewDb::new(pool.clone());
+        let alice = create_user(&pool, "alice").await;
+
+        reviews.set_rating("/a.txt", alice, Some(4)).await.unwrap();
+        reviews.add_comment("/a.txt", alice, "Hi").await.unwrap();
+
+        UserDb::new(pool.clone()).delete_user(alice).await.unwrap();
+
+        assert_eq!(reviews.rating("/a.txt").await.unwrap(), None);
+        assert_eq!(reviews.comment_count("/a.txt").await.unwrap(), 0);
+    }
+}
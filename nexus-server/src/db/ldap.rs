@@ -0,0 +1,169 @@
+//! Directory group mapping and account database operations
+//!
+//! Admins map directory groups to permissions; users who log in with
+//! directory credentials get the combined permissions of the mapped groups
+//! they are members of. Accounts created through the directory are recorded
+//! with their entry's DN, so later logins are checked against the directory
+//! and never against a local password. Deleting the account removes the
+//! record.
+
+use nexus_common::protocol::LdapGroupInfo;
+use sqlx::sqlite::SqlitePool;
+
+use crate::db::sql;
+
+/// Row type for directory group queries
+type LdapGroupRow = (String, String, bool);
+
+/// Database access for directory groups and accounts
+#[derive(Clone)]
+pub struct LdapDb {
+    pool: SqlitePool,
+}
+
+impl LdapDb {
+    /// Create a new LdapDb instance
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Get all group mappings, sorted by group
+    pub async fn list_groups(&self) -> Result<Vec<LdapGroupInfo>, sqlx::Error> {
+        let rows: Vec<LdapGroupRow> = sqlx::query_as(sql::SQL_SELECT_LDAP_GROUPS)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(group, permissions, is_admin)| LdapGroupInfo {
+                group,
+                permissions: permissions
+                    .split(',')
+                    .filter(|permission| !permission.is_empty())
+                    .map(str::to_string)
+                    .collect(),
+                is_admin,
+            })
+            .collect())
+    }
+
+    /// Map a group, replacing any mapping it already has
+    pub async fn set_group(
+        &self,
+        group: &str,
+        permissions: &[String],
+        is_admin: bool,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(sql::SQL_SET_LDAP_GROUP)
+            .bind(group)
+            .bind(permissions.join(","))
+            .bind(is_admin)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Remove a group's mapping
+    ///
+    /// Returns false if the group wasn't mapped.
+    pub async fn delete_group(&self, group: &str) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(sql::SQL_DELETE_LDAP_GROUP)
+            .bind(group)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Record an account as created through the directory
+    pub async fn link(&self, user_id: i64, dn: &str) -> Result<(), sqlx::Error> {
+        sqlx::query(sql::SQL_SET_LDAP_ACCOUNT)
+            .bind(user_id)
+            .bind(dn)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Get the directory DN of an account (None for local accounts)
+    pub async fn get_dn(&self, user_id: i64) -> Result<Option<String>, sqlx::Error> {
+        let row: Option<(String,)> = sqlx::query_as(sql::SQL_SELECT_LDAP_ACCOUNT)
+            .bind(user_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(|(dn,)| dn))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::testing::create_test_db;
+    use crate::db::{Permissions, UserDb};
+
+    #[tokio::test]
+    async fn test_group_mappings() {
+        let ldap = LdapDb::new(create_test_db().await);
+        assert!(ldap.list_groups().await.unwrap().is_empty());
+
+        ldap.set_group(
+            "CN=Staff,DC=example",
+            &["chat_send".to_string(), "file_list".to_string()],
+            false,
+        )
+        .await
+        .unwrap();
+        ldap.set_group("CN=IT,DC=example", &[], true).await.unwrap();
+
+        let groups = ldap.list_groups().await.unwrap();
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].group, "CN=IT,DC=example");
+        assert!(groups[0].is_admin);
+        assert!(groups[0].permissions.is_empty());
+        assert_eq!(groups[1].permissions, vec!["chat_send", "file_list"]);
+
+        // Setting a group again replaces its mapping, whatever the case
+        ldap.set_group("cn=staff,dc=example", &["news_list".to_string()], false)
+            .await
+            .unwrap();
+        let groups = ldap.list_groups().await.unwrap();
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[1].group, "cn=staff,dc=example");
+        assert_eq!(groups[1].permissions, vec!["news_list"]);
+
+        assert!(ldap.delete_group("CN=STAFF,DC=EXAMPLE").await.unwrap());
+        assert!(!ldap.delete_group("CN=STAFF,DC=EXAMPLE").await.unwrap());
+        assert_eq!(ldap.list_groups().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_accounts() {
+        let pool = create_test_db().await;
+        let users = UserDb::new(pool.clone());
+        let ldap = LdapDb::new(pool);
+        let alice = users
+            .create_user("alice", "hash", false, false, true, &Permissions::new())
+            .await
+            .unwrap();
+
+        assert_eq!(ldap.get_dn(alice.id).await.unwrap(), None);
+        ldap.link(alice.id, "uid=alice,dc=example").await.unwrap();
+        assert_eq!(
+            ldap.get_dn(alice.id).await.unwrap().as_deref(),
+            Some("uid=alice,dc=example")
+        );
+
+        // The entry may move within the directory
+        ldap.link(alice.id, "uid=alice,ou=people,dc=example")
+            .await
+            .unwrap();
+        assert_eq!(
+            ldap.get_dn(alice.id).await.unwrap().as_deref(),
+            Some("uid=alice,ou=people,dc=example")
+        );
+
+        // Deleting the account removes its record
+        users.delete_user(alice.id).await.unwrap();
+        assert_eq!(ldap.get_dn(alice.id).await.unwrap(), None);
+    }
+}
@@ -0,0 +1,627 @@
+//! Direct message history database operations
+//!
+//! Accounts can opt in to keeping their direct messages on the server for a
+//! number of days, so they can read them from any device. Each party that
+//! keeps history gets its own copy: turning history off or lowering the
+//! retention only affects your own copy, never the other party's.
+//!
+//! Message text is encrypted with ChaCha20-Poly1305 under a key kept beside
+//! the database (`history.key`), bound to the owning account. A stolen
+//! database alone does not reveal anyone's messages; losing the key file
+//! makes the stored history unreadable.
+//!
+//! A busy conversation would otherwise cost a commit per message, so copies
+//! are queued with [`MessageHistoryDb::store_later`] and written together in
+//! one transaction every [`MESSAGE_HISTORY_FLUSH_INTERVAL`] (and at shutdown).
+//! Reads and deletes write the queue first, so they always see every message.
+
+use std::fs;
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use chrono::Utc;
+use ring::aead::{Aad, CHACHA20_POLY1305, LessSafeKey, NONCE_LEN, Nonce, UnboundKey};
+use ring::rand::{SecureRandom, SystemRandom};
+use sqlx::sqlite::SqlitePool;
+
+use nexus_common::protocol::{ChatAction, HistoryMessage};
+
+use crate::constants::*;
+use crate::db::sql;
+use crate::identity::write_private_file;
+
+/// Length of the message history key in bytes
+const MESSAGE_HISTORY_KEY_LENGTH: usize = 32;
+
+/// How often queued messages are written to the database
+pub const MESSAGE_HISTORY_FLUSH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Most messages waiting to be written; more are dropped until the next flush
+const MAX_PENDING_MESSAGES: usize = 10_000;
+
+/// An encrypted direct message copy waiting to be written
+#[derive(Debug)]
+struct PendingMessage {
+    owner_id: i64,
+    peer: String,
+    from_nickname: String,
+    to_nickname: String,
+    is_action: bool,
+    nonce: [u8; NONCE_LEN],
+    body: Vec<u8>,
+    created_at: i64,
+}
+
+/// A page of stored direct messages
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryPage {
+    /// Messages, newest first
+    pub messages: Vec<HistoryMessage>,
+    /// Whether older messages remain
+    pub has_more: bool,
+}
+
+/// Database access for direct message history
+#[derive(Clone)]
+pub struct MessageHistoryDb {
+    pool: SqlitePool,
+    key: Arc<LessSafeKey>,
+    /// Copies queued by `store_later`, oldest first
+    pending: Arc<Mutex<Vec<PendingMessage>>>,
+}
+
+impl MessageHistoryDb {
+    /// Create a new MessageHistoryDb instance that encrypts with `key`
+    pub fn new(pool: SqlitePool, key: LessSafeKey) -> Self {
+        Self {
+            pool,
+            key: Arc::new(key),
+            pending: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Encrypt with `key` instead of the current key
+    pub fn with_key(self, key: LessSafeKey) -> Self {
+        Self::new(self.pool, key)
+    }
+
+    /// Days an account keeps its direct messages (0 = off)
+    pub async fn retention_days(&self, user_id: i64) -> Result<u32, sqlx::Error> {
+        let row: Option<(i64,)> = sqlx::query_as(sql::SQL_SELECT_MESSAGE_HISTORY_DAYS)
+            .bind(user_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(row.map_or(0, |(days,)| days.max(0) as u32))
+    }
+
+    /// Set how many days an account keeps its direct messages
+    ///
+    /// Turning history off deletes everything stored for the account;
+    /// lowering the retention deletes messages older than the new limit.
+    pub async fn set_retention_days(&self, user_id: i64, days: u32) -> Result<(), sqlx::Error> {
+        self.flush().await?;
+        let mut tx = self.pool.begin().await?;
+        sqlx::query(sql::SQL_UPDATE_MESSAGE_HISTORY_DAYS)
+            .bind(i64::from(days))
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+        if days == 0 {
+            sqlx::query(sql::SQL_DELETE_MESSAGE_HISTORY_FOR_OWNER)
+                .bind(user_id)
+                .execute(&mut *tx)
+                .await?;
+        } else {
+            sqlx::query(sql::SQL_DELETE_EXPIRED_MESSAGE_HISTORY)
+                .bind(Utc::now().timestamp())
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await
+    }
+
+    /// Store a copy of a direct message for `owner_id`
+    ///
+    /// `peer` is the other party in the conversation. Does nothing when the
+    /// account has history off. Returns whether a copy was stored.
+    #[cfg(test)]
+    pub async fn store(
+        &self,
+        owner_id: i64,
+        peer: &str,
+        from_nickname: &str,
+        to_nickname: &str,
+        message: &str,
+        action: ChatAction,
+    ) -> Result<bool, sqlx::Error> {
+        let (nonce, body) = self.seal(owner_id, message)?;
+        let result = sqlx::query(sql::SQL_INSERT_MESSAGE_HISTORY)
+            .bind(owner_id)
+            .bind(peer)
+            .bind(from_nickname)
+            .bind(to_nickname)
+            .bind(action == ChatAction::Me)
+            .bind(&nonce[..])
+            .bind(body)
+            .bind(Utc::now().timestamp())
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Queue a copy of a direct message for `owner_id` to be stored with the
+    /// next flush
+    ///
+    /// The message is encrypted now and keeps the time it was queued. Nothing
+    /// is stored when the account has history off at flush time. If the queue
+    /// is full (the database has been failing for a while), the copy is
+    /// dropped.
+    pub fn store_later(
+        &self,
+        owner_id: i64,
+        peer: &str,
+        from_nickname: &str,
+        to_nickname: &str,
+        message: &str,
+        action: ChatAction,
+    ) -> Result<(), sqlx::Error> {
+        let (nonce, body) = self.seal(owner_id, message)?;
+        let mut pending = self.lock_pending();
+        if pending.len() < MAX_PENDING_MESSAGES {
+            pending.push(PendingMessage {
+                owner_id,
+                peer: peer.to_string(),
+                from_nickname: from_nickname.to_string(),
+                to_nickname: to_nickname.to_string(),
+                is_action: action == ChatAction::Me,
+                nonce,
+                body,
+                created_at: Utc::now().timestamp(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Write the queued copies in one transaction
+    ///
+    /// Copies that couldn't be written are kept for the next flush.
+    pub async fn flush(&self) -> Result<(), sqlx::Error> {
+        let messages = std::mem::take(&mut *self.lock_pending());
+        if messages.is_empty() {
+            return Ok(());
+        }
+
+        let result = self.write(&messages).await;
+        if result.is_err() {
+            // Put them back ahead of anything queued since
+            let mut pending = self.lock_pending();
+            let newer = std::mem::replace(&mut *pending, messages);
+            let room = MAX_PENDING_MESSAGES.saturating_sub(pending.len());
+            pending.extend(newer.into_iter().take(room));
+        }
+        result
+    }
+
+    /// Insert copies taken from the queue
+    async fn write(&self, messages: &[PendingMessage]) -> Result<(), sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+        for message in messages {
+            sqlx::query(sql::SQL_INSERT_MESSAGE_HISTORY)
+                .bind(message.owner_id)
+                .bind(&message.peer)
+                .bind(&message.from_nickname)
+                .bind(&message.to_nickname)
+                .bind(message.is_action)
+                .bind(&message.nonce[..])
+                .bind(&message.body)
+                .bind(message.created_at)
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await
+    }
+
+    fn lock_pending(&self) -> MutexGuard<'_, Vec<PendingMessage>> {
+        self.pending
+            .lock()
+            .expect("message history queue lock poisoned")
+    }
+
+    /// Fetch a page of an account's stored direct messages, newest first
+    ///
+    /// `peer` limits the page to one conversation (case-insensitive).
+    /// `before` continues from an earlier page: only messages with a lower
+    /// ID are returned. Messages that fail to decrypt (e.g. after the key
+    /// file was replaced) are skipped.
+    pub async fn fetch(
+        &self,
+        owner_id: i64,
+        peer: Option<&str>,
+        before: Option<i64>,
+        limit: u32,
+    ) -> Result<HistoryPage, sqlx::Error> {
+        self.flush().await?;
+        type Row = (i64, String, String, bool, Vec<u8>, Vec<u8>, i64);
+        let rows: Vec<Row> = sqlx::query_as(sql::SQL_SELECT_MESSAGE_HISTORY)
+            .bind(owner_id)
+            .bind(peer)
+            .bind(before)
+            .bind(i64::from(limit) + 1)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let has_more = rows.len() > limit as usize;
+        let messages = rows
+            .into_iter()
+            .take(limit as usize)
+            .filter_map(
+                |(id, from_nickname, to_nickname, is_action, nonce, body, created_at)| {
+                    let message = self.open(owner_id, &nonce, body)?;
+                    Some(HistoryMessage {
+                        id,
+                        from_nickname,
+                        to_nickname,
+                        message,
+                        action: if is_action {
+                            ChatAction::Me
+                        } else {
+                            ChatAction::Normal
+                        },
+                        timestamp: created_at.max(0) as u64,
+                    })
+                },
+            )
+            .collect();
+
+        Ok(HistoryPage { messages, has_more })
+    }
+
+    /// Delete every stored conversation with `nickname`, from all accounts
+    ///
+    /// Returns the number of messages deleted.
+    pub async fn delete_with_peer(&self, nickname: &str) -> Result<u64, sqlx::Error> {
+        self.flush().await?;
+        let result = sqlx::query(sql::SQL_DELETE_MESSAGE_HISTORY_WITH_PEER)
+            .bind(nickname)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// Delete stored messages older than their account's retention
+    ///
+    /// Returns the number of messages deleted.
+    pub async fn delete_expired(&self) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(sql::SQL_DELETE_EXPIRED_MESSAGE_HISTORY)
+            .bind(Utc::now().timestamp())
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// Encrypt a message for `owner_id`, returning the nonce and ciphertext
+    fn seal(
+        &self,
+        owner_id: i64,
+        message: &str,
+    ) -> Result<([u8; NONCE_LEN], Vec<u8>), sqlx::Error> {
+        let mut nonce = [0u8; NONCE_LEN];
+        SystemRandom::new()
+            .fill(&mut nonce)
+            .map_err(|_| sqlx::Error::Protocol(ERR_MESSAGE_HISTORY_ENCRYPT.to_string()))?;
+        let mut body = message.as_bytes().to_vec();
+        self.key
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(owner_id.to_le_bytes()),
+                &mut body,
+            )
+            .map_err(|_| sqlx::Error::Protocol(ERR_MESSAGE_HISTORY_ENCRYPT.to_string()))?;
+        Ok((nonce, body))
+    }
+
+    /// Decrypt a message stored for `owner_id`
+    fn open(&self, owner_id: i64, nonce: &[u8], mut body: Vec<u8>) -> Option<String> {
+        let nonce = Nonce::try_assume_unique_for_key(nonce).ok()?;
+        let plain = self
+            .key
+            .open_in_place(nonce, Aad::from(owner_id.to_le_bytes()), &mut body)
+            .ok()?;
+        String::from_utf8(plain.to_vec()).ok()
+    }
+}
+
+/// Generate a random message history key that is never saved
+///
+/// Used when no key file is configured (e.g. tests): history stored with it
+/// is unreadable after a restart.
+pub fn ephemeral_message_key() -> LessSafeKey {
+    let mut bytes = [0u8; MESSAGE_HISTORY_KEY_LENGTH];
+    SystemRandom::new()
+        .fill(&mut bytes)
+        .expect("system random source unavailable");
+    message_key(&bytes).expect("key has the right length")
+}
+
+/// Load the message history key from `dir`, generating it on first run
+pub fn load_or_generate_message_key(dir: &Path) -> Result<LessSafeKey, String> {
+    let key_path = dir.join(MESSAGE_HISTORY_KEY_FILENAME);
+
+    let bytes = if key_path.exists() {
+        let key_hex = fs::read_to_string(&key_path)
+            .map_err(|e| format!("{}{}", ERR_READ_MESSAGE_HISTORY_KEY, e))?;
+        hex::decode(key_hex.trim())
+            .map_err(|e| format!("{}{}", ERR_PARSE_MESSAGE_HISTORY_KEY, e))?
+    } else {
+        let mut bytes = vec![0u8; MESSAGE_HISTORY_KEY_LENGTH];
+        SystemRandom::new()
+            .fill(&mut bytes)
+            .map_err(|_| ERR_GENERATE_MESSAGE_HISTORY_KEY.to_string())?;
+        write_private_file(&key_path, hex::encode(&bytes).as_bytes())
+            .map_err(|e| format!("{}{}", ERR_WRITE_MESSAGE_HISTORY_KEY, e))?;
+        println!(
+            "{}{}",
+            MSG_MESSAGE_HISTORY_KEY_GENERATED,
+            key_path.display()
+        );
+        bytes
+    };
+
+    message_key(&bytes).ok_or_else(|| {
+        format!(
+            "{}{}",
+            ERR_PARSE_MESSAGE_HISTORY_KEY, ERR_MESSAGE_HISTORY_KEY_LENGTH
+        )
+    })
+}
+
+/// Build a ChaCha20-Poly1305 key from raw bytes
+fn message_key(bytes: &[u8]) -> Option<LessSafeKey> {
+    UnboundKey::new(&CHACHA20_POLY1305, bytes)
+        .ok()
+        .map(LessSafeKey::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::db::testing::create_test_db;
+    use crate::db::{Permissions, UserDb};
+
+    async fn setup() -> (UserDb, MessageHistoryDb, i64, i64) {
+        let pool = create_test_db().await;
+        let users = UserDb::new(pool.clone());
+        let history = MessageHistoryDb::new(pool, ephemeral_message_key());
+        let alice = users
+            .create_user("alice", "hash", false, false, true, &Permissions::new())
+            .await
+            .unwrap();
+        let bob = users
+            .create_user("bob", "hash", false, false, true, &Permissions::new())
+            .await
+            .unwrap();
+        (users, history, alice.id, bob.id)
+    }
+
+    #[tokio::test]
+    async fn test_store_requires_opt_in() {
+        let (_users, history, alice, _bob) = setup().await;
+
+        assert_eq!(history.retention_days(alice).await.unwrap(), 0);
+        let stored = history
+            .store(alice, "bob", "alice", "bob", "hi", ChatAction::Normal)
+            .await
+            .unwrap();
+        assert!(!stored);
+        assert!(
+            history
+                .fetch(alice, None, None, 10)
+                .await
+                .unwrap()
+                .messages
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_store_and_fetch_pages() {
+        let (_users, history, alice, _bob) = setup().await;
+        history.set_retention_days(alice, 30).await.unwrap();
+        assert_eq!(history.retention_days(alice).await.unwrap(), 30);
+
+        for text in ["one", "two", "three"] {
+            assert!(
+                history
+                    .store(alice, "bob", "alice", "bob", text, ChatAction::Normal)
+                    .await
+                    .unwrap()
+            );
+        }
+        history
+            .store(alice, "Carol", "Carol", "alice", "waves", ChatAction::Me)
+            .await
+            .unwrap();
+
+        // Newest first, across conversations
+        let page = history.fetch(alice, None, None, 2).await.unwrap();
+        assert!(page.has_more);
+        assert_eq!(page.messages[0].message, "waves");
+        assert_eq!(page.messages[0].action, ChatAction::Me);
+        assert_eq!(page.messages[1].message, "three");
+
+        // One conversation (case-insensitive), continuing from a page
+        let page = history.fetch(alice, Some("BOB"), None, 2).await.unwrap();
+        assert_eq!(page.messages.len(), 2);
+        let older = history
+            .fetch(alice, Some("bob"), Some(page.messages[1].id), 2)
+            .await
+            .unwrap();
+        assert!(!older.has_more);
+        assert_eq!(older.messages.len(), 1);
+        assert_eq!(older.messages[0].message, "one");
+        assert_eq!(older.messages[0].from_nickname, "alice");
+        assert_eq!(older.messages[0].to_nickname, "bob");
+    }
+
+    #[tokio::test]
+    async fn test_store_later_is_written_before_reads() {
+        let (_users, history, alice, bob) = setup().await;
+        history.set_retention_days(alice, 30).await.unwrap();
+
+        history
+            .store_later(alice, "bob", "alice", "bob", "queued", ChatAction::Normal)
+            .unwrap();
+        // Bob has history off, so his copy is dropped at flush time
+        history
+            .store_later(bob, "alice", "alice", "bob", "queued", ChatAction::Normal)
+            .unwrap();
+        let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM message_history")
+            .fetch_one(&history.pool)
+            .await
+            .unwrap();
+        assert_eq!(count, 0);
+
+        let page = history.fetch(alice, None, None, 10).await.unwrap();
+        assert_eq!(page.messages.len(), 1);
+        assert_eq!(page.messages[0].message, "queued");
+        assert!(
+            history
+                .fetch(bob, None, None, 10)
+                .await
+                .unwrap()
+                .messages
+                .is_empty()
+        );
+
+        // Queued copies are covered by deletes too
+        history
+            .store_later(alice, "bob", "bob", "alice", "reply", ChatAction::Normal)
+            .unwrap();
+        assert_eq!(history.delete_with_peer("bob").await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_text_is_encrypted_per_owner() {
+        let (_users, history, alice, bob) = setup().await;
+        history.set_retention_days(alice, 7).await.unwrap();
+        history.set_retention_days(bob, 7).await.unwrap();
+        history
+            .store(alice, "bob", "alice", "bob", "secret", ChatAction::Normal)
+            .await
+            .unwrap();
+
+        let (nonce, body): (Vec<u8>, Vec<u8>) =
+            sqlx::query_as("SELECT nonce, body FROM message_history")
+                .fetch_one(&history.pool)
+                .await
+                .unwrap();
+        assert!(!body.windows(6).any(|w| w == b"secret"));
+
+        // A copy moved to another account does not decrypt
+        assert_eq!(
+            history.open(alice, &nonce, body.clone()).as_deref(),
+            Some("secret")
+        );
+        assert_eq!(history.open(bob, &nonce, body), None);
+    }
+
+    #[tokio::test]
+    async fn test_turning_off_deletes_own_copies() {
+        let (_users, history, alice, bob) = setup().await;
+        history.set_retention_days(alice, 7).await.unwrap();
+        history.set_retention_days(bob, 7).await.unwrap();
+        history
+            .store(alice, "bob", "alice", "bob", "hi", ChatAction::Normal)
+            .await
+            .unwrap();
+        history
+            .store(bob, "alice", "alice", "bob", "hi", ChatAction::Normal)
+            .await
+            .unwrap();
+
+        history.set_retention_days(alice, 0).await.unwrap();
+        assert!(
+            history
+                .fetch(alice, None, None, 10)
+                .await
+                .unwrap()
+                .messages
+                .is_empty()
+        );
+        assert_eq!(
+            history
+                .fetch(bob, None, None, 10)
+                .await
+                .unwrap()
+                .messages
+                .len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_expired_and_with_peer() {
+        let (_users, history, alice, bob) = setup().await;
+        history.set_retention_days(alice, 1).await.unwrap();
+        history.set_retention_days(bob, 1).await.unwrap();
+        history
+            .store(alice, "bob", "alice", "bob", "old", ChatAction::Normal)
+            .await
+            .unwrap();
+        history
+            .store(bob, "alice", "alice", "bob", "old", ChatAction::Normal)
+            .await
+            .unwrap();
+        sqlx::query(
+            "UPDATE message_history SET created_at = created_at - 2 * 86400 WHERE owner_id = ?",
+        )
+        .bind(alice)
+        .execute(&history.pool)
+        .await
+        .unwrap();
+
+        assert_eq!(history.delete_expired().await.unwrap(), 1);
+        assert_eq!(
+            history
+                .fetch(bob, None, None, 10)
+                .await
+                .unwrap()
+                .messages
+                .len(),
+            1
+        );
+
+        assert_eq!(history.delete_with_peer("ALICE").await.unwrap(), 1);
+        assert!(
+            history
+                .fetch(bob, None, None, 10)
+                .await
+                .unwrap()
+                .messages
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_key_file_round_trip() {
+        let dir = TempDir::new().unwrap();
+        let key = load_or_generate_message_key(dir.path()).unwrap();
+        let again = load_or_generate_message_key(dir.path()).unwrap();
+
+        let mut body = b"hello".to_vec();
+        let nonce = [7u8; NONCE_LEN];
+        key.seal_in_place_append_tag(Nonce::assume_unique_for_key(nonce), Aad::empty(), &mut body)
+            .unwrap();
+        let plain = again
+            .open_in_place(Nonce::assume_unique_for_key(nonce), Aad::empty(), &mut body)
+            .unwrap();
+        assert_eq!(plain, b"hello");
+
+        // A truncated key file is rejected
+        fs::write(dir.path().join(MESSAGE_HISTORY_KEY_FILENAME), "abcd").unwrap();
+        assert!(load_or_generate_message_key(dir.path()).is_err());
+    }
+}
@@ -1,31 +1,58 @@
 //! Database module for persistent storage
 
+pub mod archive;
+pub mod audit;
 pub mod bans;
 pub mod channels;
 pub mod config;
+pub mod file_reviews;
+pub mod ldap;
+pub mod message_history;
 pub mod news;
+pub mod nicknames;
+pub mod oidc_identities;
 pub mod password;
 pub mod permissions;
+pub mod snippets;
 pub mod sql;
+pub mod stats;
 pub mod trusts;
+pub mod tuning;
+pub mod upload_journal;
+pub mod upload_rules;
 pub mod users;
+pub mod voice_clips;
 
 #[cfg(test)]
 pub mod testing;
 
+pub use audit::AuditDb;
 pub use bans::BanDb;
 pub use channels::ChannelDb;
 pub use config::ConfigDb;
+pub use file_reviews::FileReviewDb;
+pub use ldap::LdapDb;
+pub use message_history::MessageHistoryDb;
 pub use news::NewsDb;
+pub use nicknames::NicknameDb;
+pub use oidc_identities::OidcIdentityDb;
 pub use password::{hash_password, verify_password};
 pub use permissions::{Permission, Permissions};
+pub use snippets::SnippetDb;
+pub use stats::StatsDb;
 pub use trusts::TrustDb;
+pub use tuning::DbTuning;
+pub use upload_journal::UploadJournalDb;
+pub use upload_rules::UploadRuleDb;
 pub use users::UserDb;
+pub use voice_clips::VoiceClipDb;
 
 use std::path::{Path, PathBuf};
 
 use sqlx::sqlite::{SqlitePool, SqlitePoolOptions};
 
+use tuning::connect_options;
+
 use crate::constants::*;
 
 /// Combined database access for all database operations
@@ -37,10 +64,24 @@ pub struct Database {
     pub bans: BanDb,
     pub trusts: TrustDb,
     pub channels: ChannelDb,
+    pub voice_clips: VoiceClipDb,
+    pub audit: AuditDb,
+    pub stats: StatsDb,
+    pub file_reviews: FileReviewDb,
+    pub upload_rules: UploadRuleDb,
+    pub upload_journal: UploadJournalDb,
+    pub oidc_identities: OidcIdentityDb,
+    pub ldap: LdapDb,
+    pub nicknames: NicknameDb,
+    pub message_history: MessageHistoryDb,
+    pub snippets: SnippetDb,
 }
 
 impl Database {
     /// Create a new Database instance from a connection pool
+    ///
+    /// Direct message history uses a throwaway key; the server sets the
+    /// saved one with [`Database::with_message_key`].
     pub fn new(pool: SqlitePool) -> Self {
         Self {
             users: UserDb::new(pool.clone()),
@@ -48,9 +89,26 @@ impl Database {
             news: NewsDb::new(pool.clone()),
             bans: BanDb::new(pool.clone()),
             trusts: TrustDb::new(pool.clone()),
-            channels: ChannelDb::new(pool),
+            channels: ChannelDb::new(pool.clone()),
+            voice_clips: VoiceClipDb::new(pool.clone()),
+            audit: AuditDb::new(pool.clone()),
+            stats: StatsDb::new(pool.clone()),
+            file_reviews: FileReviewDb::new(pool.clone()),
+            upload_rules: UploadRuleDb::new(pool.clone()),
+            upload_journal: UploadJournalDb::new(pool.clone()),
+            oidc_identities: OidcIdentityDb::new(pool.clone()),
+            ldap: LdapDb::new(pool.clone()),
+            nicknames: NicknameDb::new(pool.clone()),
+            snippets: SnippetDb::new(pool.clone()),
+            message_history: MessageHistoryDb::new(pool, message_history::ephemeral_message_key()),
         }
     }
+
+    /// Encrypt direct message history with `key`
+    pub fn with_message_key(mut self, key: ring::aead::LessSafeKey) -> Self {
+        self.message_history = self.message_history.with_key(key);
+        self
+    }
 }
 
 /// Get the default database path for the platform
@@ -70,21 +128,19 @@ pub fn default_database_path() -> Result<PathBuf, String> {
 }
 
 /// Initialize the database connection pool and run migrations
-pub async fn init_db(database_path: &Path) -> Result<SqlitePool, sqlx::Error> {
+pub async fn init_db(database_path: &Path, tuning: DbTuning) -> Result<SqlitePool, sqlx::Error> {
     // Create parent directories if they don't exist
     if let Some(parent) = database_path.parent() {
         std::fs::create_dir_all(parent).map_err(|e| {
-            eprintln!("{}{}", ERR_CREATE_DB_DIR, e);
+            log_error!("{}{}", ERR_CREATE_DB_DIR, e);
             sqlx::Error::Io(e)
         })?;
     }
 
-    let database_url = format!("sqlite://{}?mode=rwc", database_path.display());
-
     // Create connection pool
     let pool = SqlitePoolOptions::new()
         .max_connections(MAX_DB_CONNECTIONS)
-        .connect(&database_url)
+        .connect_with(connect_options(database_path, tuning))
         .await?;
 
     // Run migrations
@@ -1,16 +1,25 @@
 //! News database operations
 
-use chrono::Utc;
-use sqlx::sqlite::SqlitePool;
+use std::collections::HashMap;
+
+use chrono::{Duration, Utc};
+use nexus_common::protocol::NewsAttachment;
+use sqlx::sqlite::{SqliteConnection, SqlitePool};
 
 use crate::db::sql;
 
+/// Attachment kind stored for images
+const KIND_IMAGE: &str = "image";
+
+/// Attachment kind stored for file references
+const KIND_FILE: &str = "file";
+
 /// A news item from the database
 #[derive(Debug, Clone)]
 pub struct NewsRecord {
     pub id: i64,
     pub body: Option<String>,
-    pub image: Option<String>,
+    pub attachments: Vec<NewsAttachment>,
     pub author_id: i64,
     pub author_username: String,
     pub author_is_admin: bool,
@@ -22,7 +31,6 @@ pub struct NewsRecord {
 type NewsRow = (
     i64,
     Option<String>,
-    Option<String>,
     i64,
     String,
     bool,
@@ -30,21 +38,73 @@ type NewsRow = (
     Option<String>,
 );
 
-impl From<NewsRow> for NewsRecord {
-    fn from(row: NewsRow) -> Self {
+/// Row type for attachment queries: (news_id, kind, data, caption)
+type AttachmentRow = (i64, String, String, Option<String>);
+
+impl NewsRecord {
+    fn from_row(row: NewsRow, attachments: Vec<NewsAttachment>) -> Self {
         Self {
             id: row.0,
             body: row.1,
-            image: row.2,
-            author_id: row.3,
-            author_username: row.4,
-            author_is_admin: row.5,
-            created_at: row.6,
-            updated_at: row.7,
+            attachments,
+            author_id: row.2,
+            author_username: row.3,
+            author_is_admin: row.4,
+            created_at: row.5,
+            updated_at: row.6,
         }
     }
 }
 
+/// Convert an attachment row (None for a kind this build doesn't know)
+fn attachment_from_row((_, kind, data, caption): AttachmentRow) -> Option<NewsAttachment> {
+    match kind.as_str() {
+        KIND_IMAGE => Some(NewsAttachment::Image { data, caption }),
+        KIND_FILE => Some(NewsAttachment::File {
+            path: data,
+            caption,
+        }),
+        _ => None,
+    }
+}
+
+/// Build an FTS5 query matching posts that contain every word (as a prefix)
+///
+/// Words are quoted so FTS syntax (`"`, `*`, `-`, `OR`, `NEAR`, ...) is
+/// matched literally. Words without letters or digits can't match anything
+/// and are dropped; None means no word is left.
+fn fts_query(query: &str) -> Option<String> {
+    let terms: Vec<String> = query
+        .split_whitespace()
+        .filter(|term| term.chars().any(char::is_alphanumeric))
+        .map(|term| format!("\"{}\"*", term.replace('"', "\"\"")))
+        .collect();
+    (!terms.is_empty()).then(|| terms.join(" "))
+}
+
+/// Store a post's attachments in order (empty captions are stored as NULL)
+async fn insert_attachments(
+    conn: &mut SqliteConnection,
+    news_id: i64,
+    attachments: &[NewsAttachment],
+) -> Result<(), sqlx::Error> {
+    for (position, attachment) in attachments.iter().enumerate() {
+        let (kind, data) = match attachment {
+            NewsAttachment::Image { data, .. } => (KIND_IMAGE, data),
+            NewsAttachment::File { path, .. } => (KIND_FILE, path),
+        };
+        sqlx::query(sql::SQL_INSERT_NEWS_ATTACHMENT)
+            .bind(news_id)
+            .bind(position as i64)
+            .bind(kind)
+            .bind(data)
+            .bind(attachment.caption().filter(|s| !s.is_empty()))
+            .execute(&mut *conn)
+            .await?;
+    }
+    Ok(())
+}
+
 /// Database access for news operations
 #[derive(Clone)]
 pub struct NewsDb {
@@ -62,8 +122,26 @@ impl NewsDb {
         let rows: Vec<NewsRow> = sqlx::query_as(sql::SQL_SELECT_ALL_NEWS)
             .fetch_all(&self.pool)
             .await?;
+        let attachment_rows: Vec<AttachmentRow> =
+            sqlx::query_as(sql::SQL_SELECT_ALL_NEWS_ATTACHMENTS)
+                .fetch_all(&self.pool)
+                .await?;
+
+        let mut attachments: HashMap<i64, Vec<NewsAttachment>> = HashMap::new();
+        for row in attachment_rows {
+            let news_id = row.0;
+            if let Some(attachment) = attachment_from_row(row) {
+                attachments.entry(news_id).or_default().push(attachment);
+            }
+        }
 
-        Ok(rows.into_iter().map(NewsRecord::from).collect())
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let post_attachments = attachments.remove(&row.0).unwrap_or_default();
+                NewsRecord::from_row(row, post_attachments)
+            })
+            .collect())
     }
 
     /// Get a single news item by ID
@@ -72,8 +150,65 @@ impl NewsDb {
             .bind(id)
             .fetch_optional(&self.pool)
             .await?;
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        let attachment_rows: Vec<AttachmentRow> = sqlx::query_as(sql::SQL_SELECT_NEWS_ATTACHMENTS)
+            .bind(id)
+            .fetch_all(&self.pool)
+            .await?;
+        let attachments = attachment_rows
+            .into_iter()
+            .filter_map(attachment_from_row)
+            .collect();
 
-        Ok(row.map(NewsRecord::from))
+        Ok(Some(NewsRecord::from_row(row, attachments)))
+    }
+
+    /// Search news items, newest first
+    ///
+    /// - `query`: words that must all appear in the body (empty for any post)
+    /// - `author`: author username, case-insensitive
+    /// - `since`/`before`: creation time range (RFC 3339 timestamps or
+    ///   `YYYY-MM-DD` dates); `since` is inclusive, `before` exclusive
+    pub async fn search_news(
+        &self,
+        query: &str,
+        author: Option<&str>,
+        since: Option<&str>,
+        before: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<NewsRecord>, sqlx::Error> {
+        let fts = fts_query(query);
+        if fts.is_none() && !query.trim().is_empty() {
+            // Only punctuation: nothing can match
+            return Ok(Vec::new());
+        }
+
+        let rows: Vec<NewsRow> = sqlx::query_as(sql::SQL_SEARCH_NEWS)
+            .bind(fts)
+            .bind(author)
+            .bind(since)
+            .bind(before)
+            .bind(limit as i64)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut records = Vec::with_capacity(rows.len());
+        for row in rows {
+            let attachment_rows: Vec<AttachmentRow> =
+                sqlx::query_as(sql::SQL_SELECT_NEWS_ATTACHMENTS)
+                    .bind(row.0)
+                    .fetch_all(&self.pool)
+                    .await?;
+            let attachments = attachment_rows
+                .into_iter()
+                .filter_map(attachment_from_row)
+                .collect();
+            records.push(NewsRecord::from_row(row, attachments));
+        }
+        Ok(records)
     }
 
     /// Create a new news item
@@ -82,24 +217,26 @@ impl NewsDb {
     pub async fn create_news(
         &self,
         body: Option<&str>,
-        image: Option<&str>,
+        attachments: &[NewsAttachment],
         author_id: i64,
     ) -> Result<NewsRecord, sqlx::Error> {
         let now = Utc::now().to_rfc3339();
 
         // Normalize empty strings to None
         let body = body.filter(|s| !s.is_empty());
-        let image = image.filter(|s| !s.is_empty());
+
+        let mut tx = self.pool.begin().await?;
 
         let result = sqlx::query(sql::SQL_INSERT_NEWS)
             .bind(body)
-            .bind(image)
             .bind(author_id)
             .bind(&now)
-            .execute(&self.pool)
+            .execute(&mut *tx)
             .await?;
 
         let id = result.last_insert_rowid();
+        insert_attachments(&mut tx, id, attachments).await?;
+        tx.commit().await?;
 
         // Fetch the created record with author info
         self.get_news_by_id(id)
@@ -107,38 +244,71 @@ impl NewsDb {
             .ok_or_else(|| sqlx::Error::RowNotFound)
     }
 
-    /// Update a news item
+    /// Insert a news item from a server export archive
+    ///
+    /// Unlike `create_news`, the original timestamps are kept so imported
+    /// posts stay in their original order.
+    pub async fn import_news(
+        &self,
+        body: Option<&str>,
+        attachments: &[NewsAttachment],
+        author_id: i64,
+        created_at: &str,
+        updated_at: Option<&str>,
+    ) -> Result<(), sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let result = sqlx::query(sql::SQL_IMPORT_NEWS)
+            .bind(body.filter(|s| !s.is_empty()))
+            .bind(author_id)
+            .bind(created_at)
+            .bind(updated_at)
+            .execute(&mut *tx)
+            .await?;
+
+        insert_attachments(&mut tx, result.last_insert_rowid(), attachments).await?;
+        tx.commit().await
+    }
+
+    /// Update a news item, replacing its attachments
     ///
     /// Returns the updated news record.
     pub async fn update_news(
         &self,
         id: i64,
         body: Option<&str>,
-        image: Option<&str>,
+        attachments: &[NewsAttachment],
     ) -> Result<Option<NewsRecord>, sqlx::Error> {
         let now = Utc::now().to_rfc3339();
 
         // Normalize empty strings to None
         let body = body.filter(|s| !s.is_empty());
-        let image = image.filter(|s| !s.is_empty());
+
+        let mut tx = self.pool.begin().await?;
 
         let result = sqlx::query(sql::SQL_UPDATE_NEWS)
             .bind(body)
-            .bind(image)
             .bind(&now)
             .bind(id)
-            .execute(&self.pool)
+            .execute(&mut *tx)
             .await?;
 
         if result.rows_affected() == 0 {
             return Ok(None);
         }
 
+        sqlx::query(sql::SQL_DELETE_NEWS_ATTACHMENTS)
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+        insert_attachments(&mut tx, id, attachments).await?;
+        tx.commit().await?;
+
         // Fetch the updated record with author info
         self.get_news_by_id(id).await
     }
 
-    /// Delete a news item
+    /// Delete a news item (its attachments go with it)
     ///
     /// Returns true if the item was deleted, false if it didn't exist.
     pub async fn delete_news(&self, id: i64) -> Result<bool, sqlx::Error> {
@@ -149,6 +319,20 @@ impl NewsDb {
 
         Ok(result.rows_affected() > 0)
     }
+
+    /// Delete news items older than the given number of days
+    ///
+    /// Returns the number of items deleted.
+    pub async fn delete_news_older_than(&self, days: u32) -> Result<u64, sqlx::Error> {
+        let cutoff = (Utc::now() - Duration::days(i64::from(days))).to_rfc3339();
+
+        let result = sqlx::query(sql::SQL_DELETE_NEWS_BEFORE)
+            .bind(cutoff)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
 }
 
 #[cfg(test)]
@@ -157,6 +341,13 @@ mod tests {
     use crate::db::Permissions;
     use crate::db::testing::create_test_db;
 
+    fn image(data: &str) -> NewsAttachment {
+        NewsAttachment::Image {
+            data: data.to_string(),
+            caption: None,
+        }
+    }
+
     #[tokio::test]
     async fn test_create_news_with_body() {
         let pool = create_test_db().await;
@@ -171,12 +362,12 @@ mod tests {
 
         // Create news with body only
         let news = news_db
-            .create_news(Some("# Hello\n\nThis is news!"), None, user.id)
+            .create_news(Some("# Hello\n\nThis is news!"), &[], user.id)
             .await
             .unwrap();
 
         assert_eq!(news.body, Some("# Hello\n\nThis is news!".to_string()));
-        assert!(news.image.is_none());
+        assert!(news.attachments.is_empty());
         assert_eq!(news.author_username, "alice");
         assert!(!news.author_is_admin);
         assert!(news.updated_at.is_none());
@@ -194,12 +385,15 @@ mod tests {
             .unwrap();
 
         let news = news_db
-            .create_news(None, Some("data:image/png;base64,abc123"), user.id)
+            .create_news(None, &[image("data:image/png;base64,abc123")], user.id)
             .await
             .unwrap();
 
         assert!(news.body.is_none());
-        assert_eq!(news.image, Some("data:image/png;base64,abc123".to_string()));
+        assert_eq!(
+            news.attachments,
+            vec![image("data:image/png;base64,abc123")]
+        );
         assert_eq!(news.author_username, "bob");
         assert!(news.author_is_admin);
     }
@@ -218,14 +412,61 @@ mod tests {
         let news = news_db
             .create_news(
                 Some("Check out this image!"),
-                Some("data:image/png;base64,xyz"),
+                &[image("data:image/png;base64,xyz")],
                 user.id,
             )
             .await
             .unwrap();
 
         assert_eq!(news.body, Some("Check out this image!".to_string()));
-        assert_eq!(news.image, Some("data:image/png;base64,xyz".to_string()));
+        assert_eq!(news.attachments, vec![image("data:image/png;base64,xyz")]);
+    }
+
+    #[tokio::test]
+    async fn test_attachments_keep_order_and_captions() {
+        let pool = create_test_db().await;
+        let news_db = NewsDb::new(pool.clone());
+        let users_db = crate::db::UserDb::new(pool.clone());
+
+        let user = users_db
+            .create_user("alice", "hash", false, false, true, &Permissions::new())
+            .await
+            .unwrap();
+
+        let attachments = vec![
+            NewsAttachment::File {
+                path: "Music/set.mp3".to_string(),
+                caption: Some("Last night's set".to_string()),
+            },
+            image("data:image/png;base64,one"),
+            NewsAttachment::Image {
+                data: "data:image/png;base64,two".to_string(),
+                caption: Some("The crowd".to_string()),
+            },
+        ];
+        let first = news_db
+            .create_news(None, &attachments, user.id)
+            .await
+            .unwrap();
+        let second = news_db
+            .create_news(None, &[image("data:image/png;base64,other")], user.id)
+            .await
+            .unwrap();
+        assert_eq!(first.attachments, attachments);
+
+        // The list groups attachments by post
+        let all_news = news_db.get_all_news().await.unwrap();
+        assert_eq!(all_news[0].id, second.id);
+        assert_eq!(all_news[0].attachments, second.attachments);
+        assert_eq!(all_news[1].attachments, attachments);
+
+        // Deleting a post deletes its attachments
+        news_db.delete_news(first.id).await.unwrap();
+        let (remaining,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM news_attachments")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(remaining, 1);
     }
 
     #[tokio::test]
@@ -241,15 +482,15 @@ mod tests {
 
         // Create multiple news items
         let news1 = news_db
-            .create_news(Some("First post"), None, user.id)
+            .create_news(Some("First post"), &[], user.id)
             .await
             .unwrap();
         let news2 = news_db
-            .create_news(Some("Second post"), None, user.id)
+            .create_news(Some("Second post"), &[], user.id)
             .await
             .unwrap();
         let news3 = news_db
-            .create_news(Some("Third post"), None, user.id)
+            .create_news(Some("Third post"), &[], user.id)
             .await
             .unwrap();
 
@@ -274,7 +515,7 @@ mod tests {
             .unwrap();
 
         let created = news_db
-            .create_news(Some("Test post"), None, user.id)
+            .create_news(Some("Test post"), &[], user.id)
             .await
             .unwrap();
 
@@ -301,7 +542,11 @@ mod tests {
             .unwrap();
 
         let created = news_db
-            .create_news(Some("Original"), None, user.id)
+            .create_news(
+                Some("Original"),
+                &[image("data:image/png;base64,old")],
+                user.id,
+            )
             .await
             .unwrap();
 
@@ -311,14 +556,18 @@ mod tests {
             .update_news(
                 created.id,
                 Some("Updated content"),
-                Some("data:image/png;base64,new"),
+                &[image("data:image/png;base64,new")],
             )
             .await
             .unwrap()
             .unwrap();
 
         assert_eq!(updated.body, Some("Updated content".to_string()));
-        assert_eq!(updated.image, Some("data:image/png;base64,new".to_string()));
+        // Attachments are replaced, not appended to
+        assert_eq!(
+            updated.attachments,
+            vec![image("data:image/png;base64,new")]
+        );
         assert!(updated.updated_at.is_some());
     }
 
@@ -328,7 +577,7 @@ mod tests {
         let news_db = NewsDb::new(pool.clone());
 
         let result = news_db
-            .update_news(99999, Some("Content"), None)
+            .update_news(99999, Some("Content"), &[])
             .await
             .unwrap();
 
@@ -347,7 +596,7 @@ mod tests {
             .unwrap();
 
         let news = news_db
-            .create_news(Some("To be deleted"), None, user.id)
+            .create_news(Some("To be deleted"), &[], user.id)
             .await
             .unwrap();
 
@@ -375,7 +624,7 @@ mod tests {
             .unwrap();
 
         let news = news_db
-            .create_news(Some("User's post"), None, user.id)
+            .create_news(Some("User's post"), &[], user.id)
             .await
             .unwrap();
 
@@ -401,21 +650,183 @@ mod tests {
         // Create with empty string body (should be normalized to None)
         // but with valid image
         let news = news_db
-            .create_news(Some(""), Some("data:image/png;base64,abc"), user.id)
+            .create_news(Some(""), &[image("data:image/png;base64,abc")], user.id)
             .await
             .unwrap();
 
         assert!(news.body.is_none());
-        assert!(news.image.is_some());
+        assert_eq!(news.attachments.len(), 1);
 
-        // Update to clear image but set body
+        // Update to clear attachments but set body
         let updated = news_db
-            .update_news(news.id, Some("New body"), Some(""))
+            .update_news(news.id, Some("New body"), &[])
             .await
             .unwrap()
             .unwrap();
 
         assert_eq!(updated.body, Some("New body".to_string()));
-        assert!(updated.image.is_none());
+        assert!(updated.attachments.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_delete_news_older_than() {
+        let pool = create_test_db().await;
+        let news_db = NewsDb::new(pool.clone());
+        let users_db = crate::db::UserDb::new(pool.clone());
+
+        let user = users_db
+            .create_user("alice", "hash", false, false, true, &Permissions::new())
+            .await
+            .unwrap();
+
+        let old = (Utc::now() - Duration::days(40)).to_rfc3339();
+        news_db
+            .import_news(Some("Old news"), &[], user.id, &old, None)
+            .await
+            .unwrap();
+        let recent = news_db
+            .create_news(Some("Recent news"), &[], user.id)
+            .await
+            .unwrap();
+
+        assert_eq!(news_db.delete_news_older_than(30).await.unwrap(), 1);
+
+        let remaining = news_db.get_all_news().await.unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].id, recent.id);
+    }
+
+    #[test]
+    fn test_fts_query_quotes_terms() {
+        assert_eq!(
+            fts_query("server release"),
+            Some(r#""server"* "release"*"#.to_string())
+        );
+        assert_eq!(
+            fts_query(r#"say "hi" OR -x"#),
+            Some(r#""say"* """hi"""* "OR"* "-x"*"#.to_string())
+        );
+        assert_eq!(fts_query("  "), None);
+        assert_eq!(fts_query(r#"* - ""#), None);
+    }
+
+    #[tokio::test]
+    async fn test_search_news() {
+        let pool = create_test_db().await;
+        let news_db = NewsDb::new(pool.clone());
+        let users_db = crate::db::UserDb::new(pool.clone());
+
+        let alice = users_db
+            .create_user("alice", "hash", false, false, true, &Permissions::new())
+            .await
+            .unwrap();
+        let bob = users_db
+            .create_user("bob", "hash", false, false, true, &Permissions::new())
+            .await
+            .unwrap();
+
+        news_db
+            .import_news(
+                Some("Server maintenance tonight"),
+                &[],
+                alice.id,
+                "2023-06-01T12:00:00+00:00",
+                None,
+            )
+            .await
+            .unwrap();
+        let release = news_db
+            .create_news(Some("New release: faster *transfers*"), &[], bob.id)
+            .await
+            .unwrap();
+        let maintenance = news_db
+            .create_news(Some("More maintenance, sorry"), &[], alice.id)
+            .await
+            .unwrap();
+
+        // Words match as prefixes, newest first
+        let found = news_db
+            .search_news("mainten", None, None, None, 100)
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].id, maintenance.id);
+
+        // Every word must appear
+        let found = news_db
+            .search_news("release transfers", None, None, None, 100)
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].id, release.id);
+        let found = news_db
+            .search_news("release tonight", None, None, None, 100)
+            .await
+            .unwrap();
+        assert!(found.is_empty());
+
+        // Author and date filters, with or without words
+        let found = news_db
+            .search_news("", Some("ALICE"), None, None, 100)
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 2);
+        let found = news_db
+            .search_news("maintenance", None, None, Some("2024-01-01"), 100)
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].body.as_deref(), Some("Server maintenance tonight"));
+        let found = news_db
+            .search_news("", None, Some("2024-01-01"), None, 1)
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].id, maintenance.id);
+    }
+
+    #[tokio::test]
+    async fn test_search_follows_edits_and_deletes() {
+        let pool = create_test_db().await;
+        let news_db = NewsDb::new(pool.clone());
+        let users_db = crate::db::UserDb::new(pool.clone());
+
+        let user = users_db
+            .create_user("alice", "hash", false, false, true, &Permissions::new())
+            .await
+            .unwrap();
+        let news = news_db
+            .create_news(Some("Original words"), &[], user.id)
+            .await
+            .unwrap();
+
+        news_db
+            .update_news(news.id, Some("Replacement text"), &[])
+            .await
+            .unwrap();
+        let found = news_db
+            .search_news("original", None, None, None, 100)
+            .await
+            .unwrap();
+        assert!(found.is_empty());
+        let found = news_db
+            .search_news("replacement", None, None, None, 100)
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 1);
+
+        news_db.delete_news(news.id).await.unwrap();
+        let found = news_db
+            .search_news("replacement", None, None, None, 100)
+            .await
+            .unwrap();
+        assert!(found.is_empty());
+
+        // Punctuation only can't match anything
+        let found = news_db
+            .search_news("***", None, None, None, 100)
+            .await
+            .unwrap();
+        assert!(found.is_empty());
     }
 }
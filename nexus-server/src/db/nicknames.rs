@@ -0,0 +1,155 @@
+//! Registered nickname database operations
+//!
+//! Accounts can reserve nicknames besides their username, so that nobody
+//! logging in to a shared account (or as a guest) can pose as them under a
+//! familiar name. Nicknames compare case-insensitively. Deleting the account
+//! releases its nicknames.
+
+use chrono::Utc;
+use sqlx::sqlite::SqlitePool;
+
+use crate::db::sql;
+
+/// A reserved nickname and the account it belongs to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegisteredNickname {
+    /// Nickname as it was registered
+    pub nickname: String,
+    /// Account ID
+    pub user_id: i64,
+    /// Account username
+    pub username: String,
+}
+
+/// Database access for registered nicknames
+#[derive(Clone)]
+pub struct NicknameDb {
+    pool: SqlitePool,
+}
+
+impl NicknameDb {
+    /// Create a new NicknameDb instance
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Reserve a nickname for an account
+    ///
+    /// Fails if the nickname is already reserved, whatever its case.
+    pub async fn register(&self, nickname: &str, user_id: i64) -> Result<(), sqlx::Error> {
+        sqlx::query(sql::SQL_INSERT_REGISTERED_NICKNAME)
+            .bind(nickname)
+            .bind(user_id)
+            .bind(Utc::now().timestamp())
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Get who a nickname is reserved for, if anyone (case-insensitive)
+    pub async fn get(&self, nickname: &str) -> Result<Option<RegisteredNickname>, sqlx::Error> {
+        let row: Option<(String, i64, String)> =
+            sqlx::query_as(sql::SQL_SELECT_REGISTERED_NICKNAME)
+                .bind(nickname)
+                .fetch_optional(&self.pool)
+                .await?;
+
+        Ok(row.map(|(nickname, user_id, username)| RegisteredNickname {
+            nickname,
+            user_id,
+            username,
+        }))
+    }
+
+    /// Whether a nickname is reserved (case-insensitive)
+    pub async fn is_registered(&self, nickname: &str) -> Result<bool, sqlx::Error> {
+        Ok(self.get(nickname).await?.is_some())
+    }
+
+    /// Number of nicknames reserved for an account
+    pub async fn count_for_user(&self, user_id: i64) -> Result<u32, sqlx::Error> {
+        let (count,): (i64,) = sqlx::query_as(sql::SQL_COUNT_REGISTERED_NICKNAMES)
+            .bind(user_id)
+            .fetch_one(&self.pool)
+            .await?;
+        Ok(count as u32)
+    }
+
+    /// Release a reserved nickname
+    ///
+    /// Returns whether it was reserved.
+    pub async fn release(&self, nickname: &str) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(sql::SQL_DELETE_REGISTERED_NICKNAME)
+            .bind(nickname)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::testing::create_test_db;
+    use crate::db::{Permissions, UserDb};
+
+    #[tokio::test]
+    async fn test_register_and_lookup() {
+        let pool = create_test_db().await;
+        let users = UserDb::new(pool.clone());
+        let nicknames = NicknameDb::new(pool);
+        let alice = users
+            .create_user("alice", "hash", false, false, true, &Permissions::new())
+            .await
+            .unwrap();
+
+        assert_eq!(nicknames.get("Ally").await.unwrap(), None);
+        nicknames.register("Ally", alice.id).await.unwrap();
+
+        // Lookups ignore case and report the registered spelling
+        let registered = nicknames.get("ALLY").await.unwrap().unwrap();
+        assert_eq!(registered.nickname, "Ally");
+        assert_eq!(registered.user_id, alice.id);
+        assert_eq!(registered.username, "alice");
+        assert!(nicknames.is_registered("ally").await.unwrap());
+        assert_eq!(nicknames.count_for_user(alice.id).await.unwrap(), 1);
+
+        // A nickname can only be reserved once, whatever its case
+        assert!(nicknames.register("aLLy", alice.id).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_release() {
+        let pool = create_test_db().await;
+        let users = UserDb::new(pool.clone());
+        let nicknames = NicknameDb::new(pool);
+        let alice = users
+            .create_user("alice", "hash", false, false, true, &Permissions::new())
+            .await
+            .unwrap();
+
+        nicknames.register("Ally", alice.id).await.unwrap();
+        assert!(nicknames.release("ally").await.unwrap());
+        assert!(!nicknames.release("ally").await.unwrap());
+        assert!(!nicknames.is_registered("Ally").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_deleting_account_releases_nicknames() {
+        let pool = create_test_db().await;
+        let users = UserDb::new(pool.clone());
+        let nicknames = NicknameDb::new(pool);
+        let alice = users
+            .create_user("alice", "hash", false, false, true, &Permissions::new())
+            .await
+            .unwrap();
+
+        nicknames.register("Ally", alice.id).await.unwrap();
+        nicknames.register("Al", alice.id).await.unwrap();
+        assert_eq!(nicknames.count_for_user(alice.id).await.unwrap(), 2);
+
+        users.delete_user(alice.id).await.unwrap();
+        assert!(!nicknames.is_registered("Ally").await.unwrap());
+        assert!(!nicknames.is_registered("Al").await.unwrap());
+    }
+}
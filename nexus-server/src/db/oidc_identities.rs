@@ -0,0 +1,115 @@
+//! Single sign-on identity database operations
+//!
+//! An identity provider names each person by an issuer and a subject
+//! identifier that never changes, unlike their username or email. The first
+//! single sign-on login links that pair to a Nexus account; later logins
+//! find the account through the link. Deleting the account removes it.
+
+use chrono::Utc;
+use sqlx::sqlite::SqlitePool;
+
+use crate::db::sql;
+
+/// Database access for single sign-on identities
+#[derive(Clone)]
+pub struct OidcIdentityDb {
+    pool: SqlitePool,
+}
+
+impl OidcIdentityDb {
+    /// Create a new OidcIdentityDb instance
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Link a subject to an account
+    pub async fn link(&self, issuer: &str, subject: &str, user_id: i64) -> Result<(), sqlx::Error> {
+        sqlx::query(sql::SQL_INSERT_OIDC_IDENTITY)
+            .bind(issuer)
+            .bind(subject)
+            .bind(user_id)
+            .bind(Utc::now().timestamp())
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Get the account a subject is linked to
+    pub async fn get_user_id(
+        &self,
+        issuer: &str,
+        subject: &str,
+    ) -> Result<Option<i64>, sqlx::Error> {
+        let row: Option<(i64,)> = sqlx::query_as(sql::SQL_SELECT_OIDC_IDENTITY)
+            .bind(issuer)
+            .bind(subject)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(|(user_id,)| user_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::testing::create_test_db;
+    use crate::db::{Permissions, UserDb};
+
+    #[tokio::test]
+    async fn test_link_and_lookup() {
+        let pool = create_test_db().await;
+        let users = UserDb::new(pool.clone());
+        let identities = OidcIdentityDb::new(pool);
+        let alice = users
+            .create_user("alice", "hash", false, false, true, &Permissions::new())
+            .await
+            .unwrap();
+
+        let issuer = "https://id.example.com";
+        assert_eq!(
+            identities
+                .get_user_id(issuer, "248289761001")
+                .await
+                .unwrap(),
+            None
+        );
+
+        identities
+            .link(issuer, "248289761001", alice.id)
+            .await
+            .unwrap();
+        assert_eq!(
+            identities
+                .get_user_id(issuer, "248289761001")
+                .await
+                .unwrap(),
+            Some(alice.id)
+        );
+
+        // Subjects are only unique within their issuer
+        assert_eq!(
+            identities
+                .get_user_id("https://other.example.com", "248289761001")
+                .await
+                .unwrap(),
+            None
+        );
+        assert!(
+            identities
+                .link(issuer, "248289761001", alice.id)
+                .await
+                .is_err()
+        );
+
+        // Deleting the account removes the link
+        users.delete_user(alice.id).await.unwrap();
+        assert_eq!(
+            identities
+                .get_user_id(issuer, "248289761001")
+                .await
+                .unwrap(),
+            None
+        );
+    }
+}
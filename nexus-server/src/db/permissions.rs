@@ -1,8 +1,13 @@
 //! Permission system for user authorization
 
 use std::collections::HashSet;
+
+use nexus_common::{is_guest_account_permission, is_shared_account_permission};
 use strum::AsRefStr;
 
+use super::sql::GUEST_USERNAME;
+use super::users::UserAccount;
+
 /// Permission types for user actions
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, AsRefStr)]
 #[strum(serialize_all = "snake_case")]
@@ -21,6 +26,10 @@ pub enum Permission {
     TrustList,
     /// Permission to view active connections
     ConnectionMonitor,
+    /// Permission to follow the server log
+    LogView,
+    /// Permission to run door programs
+    DoorPlay,
     /// Permission to use UserList command
     UserList,
     /// Permission to use UserInfo command
@@ -41,6 +50,12 @@ pub enum Permission {
     ChatTopic,
     /// Permission to edit chat topic
     ChatTopicEdit,
+    /// Permission to lock channel topics and change locked topics
+    ChatTopicLock,
+    /// Permission to set channel entry messages and rules
+    ChatEntryEdit,
+    /// Permission to set the language tag of channels
+    ChatLanguage,
     /// Permission to send broadcast messages
     UserBroadcast,
     /// Permission to create users
@@ -85,6 +100,14 @@ pub enum Permission {
     FileMove,
     /// Permission to copy files and directories
     FileCopy,
+    /// Permission to rate and comment on files
+    FileComment,
+    /// Permission to broadcast audio to a channel's listeners
+    VoiceBroadcast,
+    /// Permission to upload and delete own soundboard clips
+    VoiceClipUpload,
+    /// Permission to set the voice participant limit of a channel
+    VoiceLimit,
     /// Permission to receive audio from others in voice chat
     VoiceListen,
     /// Permission to transmit audio in voice chat
@@ -116,6 +139,8 @@ impl Permission {
             "trust_delete" => Some(Permission::TrustDelete),
             "trust_list" => Some(Permission::TrustList),
             "connection_monitor" => Some(Permission::ConnectionMonitor),
+            "log_view" => Some(Permission::LogView),
+            "door_play" => Some(Permission::DoorPlay),
             "user_list" => Some(Permission::UserList),
             "user_info" => Some(Permission::UserInfo),
             "chat_send" => Some(Permission::ChatSend),
@@ -126,6 +151,9 @@ impl Permission {
             "chat_secret" => Some(Permission::ChatSecret),
             "chat_topic" => Some(Permission::ChatTopic),
             "chat_topic_edit" => Some(Permission::ChatTopicEdit),
+            "chat_topic_lock" => Some(Permission::ChatTopicLock),
+            "chat_entry_edit" => Some(Permission::ChatEntryEdit),
+            "chat_language" => Some(Permission::ChatLanguage),
             "user_broadcast" => Some(Permission::UserBroadcast),
             "user_create" => Some(Permission::UserCreate),
             "user_delete" => Some(Permission::UserDelete),
@@ -136,6 +164,7 @@ impl Permission {
             "news_create" => Some(Permission::NewsCreate),
             "news_edit" => Some(Permission::NewsEdit),
             "news_delete" => Some(Permission::NewsDelete),
+            "file_comment" => Some(Permission::FileComment),
             "file_copy" => Some(Permission::FileCopy),
             "file_create_dir" => Some(Permission::FileCreateDir),
             "file_delete" => Some(Permission::FileDelete),
@@ -148,6 +177,9 @@ impl Permission {
             "file_move" => Some(Permission::FileMove),
             "file_rename" => Some(Permission::FileRename),
             "file_root" => Some(Permission::FileRoot),
+            "voice_broadcast" => Some(Permission::VoiceBroadcast),
+            "voice_clip_upload" => Some(Permission::VoiceClipUpload),
+            "voice_limit" => Some(Permission::VoiceLimit),
             "voice_listen" => Some(Permission::VoiceListen),
             "voice_talk" => Some(Permission::VoiceTalk),
             _ => None,
@@ -209,6 +241,52 @@ impl Permissions {
     pub fn add(&mut self, permission: Permission) {
         self.permissions.insert(permission);
     }
+
+    /// Drop permissions that shared accounts can't use
+    ///
+    /// Returns the removed permissions. Creating and updating accounts
+    /// already refuses them, but older databases may still hold some.
+    pub fn restrict_to_shared(&mut self) -> Vec<Permission> {
+        self.restrict(is_shared_account_permission)
+    }
+
+    /// Drop permissions that the guest account can't use
+    ///
+    /// Returns the removed permissions. The guest account is held to a
+    /// narrower set than other shared accounts.
+    pub fn restrict_to_guest(&mut self) -> Vec<Permission> {
+        self.restrict(is_guest_account_permission)
+    }
+
+    /// Drop permissions that an account can't use
+    ///
+    /// Shared accounts are restricted to the shared set and the guest account
+    /// to the guest set; regular accounts keep everything. Returns the removed
+    /// permissions.
+    pub fn restrict_for_account(&mut self, account: &UserAccount) -> Vec<Permission> {
+        let mut restricted = Vec::new();
+        if account.is_shared {
+            restricted.extend(self.restrict_to_shared());
+        }
+        if account.username.eq_ignore_ascii_case(GUEST_USERNAME) {
+            restricted.extend(self.restrict_to_guest());
+        }
+        restricted
+    }
+
+    /// Drop permissions that `allowed` rejects, returning them
+    fn restrict(&mut self, allowed: fn(&str) -> bool) -> Vec<Permission> {
+        let restricted: Vec<Permission> = self
+            .permissions
+            .iter()
+            .copied()
+            .filter(|p| !allowed(p.as_str()))
+            .collect();
+        for permission in &restricted {
+            self.permissions.remove(permission);
+        }
+        restricted
+    }
 }
 
 impl Default for Permissions {
@@ -238,6 +316,9 @@ mod tests {
         assert_eq!(Permission::ChatReceive.as_str(), "chat_receive");
         assert_eq!(Permission::ChatTopic.as_str(), "chat_topic");
         assert_eq!(Permission::ChatTopicEdit.as_str(), "chat_topic_edit");
+        assert_eq!(Permission::ChatTopicLock.as_str(), "chat_topic_lock");
+        assert_eq!(Permission::ChatEntryEdit.as_str(), "chat_entry_edit");
+        assert_eq!(Permission::ChatLanguage.as_str(), "chat_language");
         assert_eq!(Permission::UserBroadcast.as_str(), "user_broadcast");
         assert_eq!(Permission::UserCreate.as_str(), "user_create");
         assert_eq!(Permission::UserDelete.as_str(), "user_delete");
@@ -249,6 +330,7 @@ mod tests {
         assert_eq!(Permission::NewsEdit.as_str(), "news_edit");
         assert_eq!(Permission::NewsDelete.as_str(), "news_delete");
         assert_eq!(Permission::FileCopy.as_str(), "file_copy");
+        assert_eq!(Permission::FileComment.as_str(), "file_comment");
         assert_eq!(Permission::FileCreateDir.as_str(), "file_create_dir");
         assert_eq!(Permission::FileDelete.as_str(), "file_delete");
         assert_eq!(Permission::FileDownload.as_str(), "file_download");
@@ -260,6 +342,8 @@ mod tests {
         assert_eq!(Permission::FileMove.as_str(), "file_move");
         assert_eq!(Permission::FileRename.as_str(), "file_rename");
         assert_eq!(Permission::FileRoot.as_str(), "file_root");
+        assert_eq!(Permission::LogView.as_str(), "log_view");
+        assert_eq!(Permission::DoorPlay.as_str(), "door_play");
         assert_eq!(Permission::TrustCreate.as_str(), "trust_create");
         assert_eq!(Permission::TrustDelete.as_str(), "trust_delete");
         assert_eq!(Permission::TrustList.as_str(), "trust_list");
@@ -289,6 +373,18 @@ mod tests {
             Permission::parse("chat_topic_edit"),
             Some(Permission::ChatTopicEdit)
         );
+        assert_eq!(
+            Permission::parse("chat_topic_lock"),
+            Some(Permission::ChatTopicLock)
+        );
+        assert_eq!(
+            Permission::parse("chat_entry_edit"),
+            Some(Permission::ChatEntryEdit)
+        );
+        assert_eq!(
+            Permission::parse("chat_language"),
+            Some(Permission::ChatLanguage)
+        );
         assert_eq!(
             Permission::parse("user_broadcast"),
             Some(Permission::UserBroadcast)
@@ -318,6 +414,10 @@ mod tests {
             Some(Permission::NewsDelete)
         );
         assert_eq!(Permission::parse("file_copy"), Some(Permission::FileCopy));
+        assert_eq!(
+            Permission::parse("file_comment"),
+            Some(Permission::FileComment)
+        );
         assert_eq!(
             Permission::parse("file_create_dir"),
             Some(Permission::FileCreateDir)
@@ -350,6 +450,8 @@ mod tests {
             Some(Permission::FileRename)
         );
         assert_eq!(Permission::parse("file_root"), Some(Permission::FileRoot));
+        assert_eq!(Permission::parse("log_view"), Some(Permission::LogView));
+        assert_eq!(Permission::parse("door_play"), Some(Permission::DoorPlay));
         assert_eq!(
             Permission::parse("trust_create"),
             Some(Permission::TrustCreate)
@@ -414,6 +516,70 @@ mod tests {
         assert!(vec.contains(&Permission::UserInfo));
     }
 
+    #[test]
+    fn test_permissions_restrict_to_shared() {
+        let mut perms = Permissions::new();
+        perms.add(Permission::ChatSend);
+        perms.add(Permission::FileDelete);
+
+        let restricted = perms.restrict_to_shared();
+        assert_eq!(restricted, vec![Permission::FileDelete]);
+        assert_eq!(perms.to_vec(), vec![Permission::ChatSend]);
+    }
+
+    #[test]
+    fn test_permissions_restrict_to_guest() {
+        let mut perms = Permissions::new();
+        perms.add(Permission::ChatSend);
+        perms.add(Permission::UserMessage);
+
+        // Shared accounts may message users, the guest account may not
+        let restricted = perms.restrict_to_guest();
+        assert_eq!(restricted, vec![Permission::UserMessage]);
+        assert_eq!(perms.to_vec(), vec![Permission::ChatSend]);
+    }
+
+    #[test]
+    fn test_permissions_restrict_for_account() {
+        let account = |username: &str, is_shared: bool| UserAccount {
+            id: 1,
+            username: username.to_string(),
+            hashed_password: String::new(),
+            is_admin: false,
+            is_shared,
+            enabled: true,
+            created_at: 0,
+        };
+        let all = || {
+            let mut perms = Permissions::new();
+            perms.add(Permission::ChatSend);
+            perms.add(Permission::UserMessage);
+            perms.add(Permission::FileDelete);
+            perms
+        };
+
+        let mut perms = all();
+        assert!(
+            perms
+                .restrict_for_account(&account("alice", false))
+                .is_empty()
+        );
+        assert_eq!(perms.permissions.len(), 3);
+
+        let mut perms = all();
+        let restricted = perms.restrict_for_account(&account("kiosk", true));
+        assert_eq!(restricted, vec![Permission::FileDelete]);
+
+        let mut perms = all();
+        let mut restricted = perms.restrict_for_account(&account("Guest", true));
+        restricted.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        assert_eq!(
+            restricted,
+            vec![Permission::FileDelete, Permission::UserMessage]
+        );
+        assert_eq!(perms.to_vec(), vec![Permission::ChatSend]);
+    }
+
     #[test]
     fn test_permission_enum_matches_all_permissions() {
         // Verify that every permission in ALL_PERMISSIONS can be parsed
@@ -431,14 +597,19 @@ mod tests {
             Permission::BanDelete,
             Permission::BanList,
             Permission::ChatCreate,
+            Permission::ChatEntryEdit,
             Permission::ChatJoin,
+            Permission::ChatLanguage,
             Permission::ChatList,
             Permission::ChatReceive,
             Permission::ChatSecret,
             Permission::ChatSend,
             Permission::ChatTopic,
             Permission::ChatTopicEdit,
+            Permission::ChatTopicLock,
             Permission::ConnectionMonitor,
+            Permission::DoorPlay,
+            Permission::FileComment,
             Permission::FileCopy,
             Permission::FileCreateDir,
             Permission::FileDelete,
@@ -451,6 +622,7 @@ mod tests {
             Permission::FileReindex,
             Permission::FileRename,
             Permission::FileRoot,
+            Permission::LogView,
             Permission::NewsCreate,
             Permission::NewsDelete,
             Permission::NewsEdit,
@@ -466,6 +638,9 @@ mod tests {
             Permission::UserKick,
             Permission::UserList,
             Permission::UserMessage,
+            Permission::VoiceBroadcast,
+            Permission::VoiceClipUpload,
+            Permission::VoiceLimit,
             Permission::VoiceListen,
             Permission::VoiceTalk,
         ];
@@ -0,0 +1,194 @@
+//! Snippet (paste-bin style text shared by link) database operations
+//!
+//! Snippets keep long pastes out of chat: the text is stored here and a
+//! `nexus://` link is posted instead. Each snippet expires after a time
+//! chosen by its author and is deleted by a periodic cleanup.
+
+use chrono::Utc;
+use nexus_common::protocol::SnippetInfo;
+use nexus_common::validators::SNIPPET_ID_LENGTH;
+use rand::RngExt;
+use rand::distr::Alphanumeric;
+use sqlx::sqlite::SqlitePool;
+
+use crate::db::sql;
+
+/// Row type for snippet queries
+type SnippetRow = (String, String, Option<String>, String, i64, i64);
+
+/// Database access for snippet operations
+#[derive(Clone)]
+pub struct SnippetDb {
+    pool: SqlitePool,
+}
+
+impl SnippetDb {
+    /// Create a new SnippetDb instance
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Store a snippet that expires `expires_in` seconds from now
+    ///
+    /// Returns the new snippet's ID and expiry time, or None if the author
+    /// already has `max_snippets` unexpired snippets.
+    pub async fn create(
+        &self,
+        author_id: i64,
+        author_nickname: &str,
+        language: Option<&str>,
+        content: &str,
+        expires_in: u32,
+        max_snippets: i64,
+    ) -> Result<Option<(String, i64)>, sqlx::Error> {
+        let id = snippet_id();
+        let now = Utc::now().timestamp();
+        let expires_at = now + i64::from(expires_in);
+
+        let result = sqlx::query(sql::SQL_INSERT_SNIPPET)
+            .bind(&id)
+            .bind(author_id)
+            .bind(author_nickname)
+            .bind(language)
+            .bind(content)
+            .bind(now)
+            .bind(expires_at)
+            .bind(max_snippets)
+            .execute(&self.pool)
+            .await?;
+
+        Ok((result.rows_affected() > 0).then_some((id, expires_at)))
+    }
+
+    /// Get an unexpired snippet by ID
+    pub async fn get(&self, id: &str) -> Result<Option<SnippetInfo>, sqlx::Error> {
+        let row: Option<SnippetRow> = sqlx::query_as(sql::SQL_SELECT_SNIPPET)
+            .bind(id)
+            .bind(Utc::now().timestamp())
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(
+            |(id, author, language, content, created_at, expires_at)| SnippetInfo {
+                id,
+                author,
+                language,
+                content,
+                created_at,
+                expires_at,
+            },
+        ))
+    }
+
+    /// Delete expired snippets, returning how many were deleted
+    pub async fn delete_expired(&self) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(sql::SQL_DELETE_EXPIRED_SNIPPETS)
+            .bind(Utc::now().timestamp())
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+}
+
+/// Generate a random snippet ID
+fn snippet_id() -> String {
+    rand::rng()
+        .sample_iter(Alphanumeric)
+        .take(SNIPPET_ID_LENGTH)
+        .map(char::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Permissions;
+    use crate::db::testing::create_test_db;
+    use nexus_common::validators::validate_snippet_id;
+
+    async fn create_author(pool: &SqlitePool) -> i64 {
+        crate::db::UserDb::new(pool.clone())
+            .create_user("alice", "hash", false, false, true, &Permissions::new())
+            .await
+            .unwrap()
+            .id
+    }
+
+    #[tokio::test]
+    async fn test_create_and_get_snippet() {
+        let pool = create_test_db().await;
+        let snippets_db = SnippetDb::new(pool.clone());
+        let author_id = create_author(&pool).await;
+
+        let (id, expires_at) = snippets_db
+            .create(author_id, "Alice", Some("rust"), "fn main() {}", 3600, 10)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(validate_snippet_id(&id).is_ok());
+
+        let snippet = snippets_db.get(&id).await.unwrap().unwrap();
+        assert_eq!(snippet.id, id);
+        assert_eq!(snippet.author, "Alice");
+        assert_eq!(snippet.language.as_deref(), Some("rust"));
+        assert_eq!(snippet.content, "fn main() {}");
+        assert_eq!(snippet.expires_at, expires_at);
+        assert_eq!(snippet.expires_at - snippet.created_at, 3600);
+
+        assert!(snippets_db.get("missing123").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_snippet_limit() {
+        let pool = create_test_db().await;
+        let snippets_db = SnippetDb::new(pool.clone());
+        let author_id = create_author(&pool).await;
+
+        for _ in 0..2 {
+            assert!(
+                snippets_db
+                    .create(author_id, "alice", None, "text", 60, 2)
+                    .await
+                    .unwrap()
+                    .is_some()
+            );
+        }
+        assert!(
+            snippets_db
+                .create(author_id, "alice", None, "text", 60, 2)
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_expired_snippets() {
+        let pool = create_test_db().await;
+        let snippets_db = SnippetDb::new(pool.clone());
+        let author_id = create_author(&pool).await;
+
+        let (id, _) = snippets_db
+            .create(author_id, "alice", None, "text", 60, 10)
+            .await
+            .unwrap()
+            .unwrap();
+        sqlx::query("UPDATE snippets SET expires_at = expires_at - 120")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        // Expired snippets can't be read or count towards the limit
+        assert!(snippets_db.get(&id).await.unwrap().is_none());
+        assert!(
+            snippets_db
+                .create(author_id, "alice", None, "text", 60, 1)
+                .await
+                .unwrap()
+                .is_some()
+        );
+
+        assert_eq!(snippets_db.delete_expired().await.unwrap(), 1);
+        assert_eq!(snippets_db.delete_expired().await.unwrap(), 0);
+    }
+}
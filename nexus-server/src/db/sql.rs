@@ -77,6 +77,17 @@ pub const SQL_SELECT_USER_BY_ID: &str = "SELECT id, username, password_hash, is_
 /// Results are sorted alphabetically by username (case-insensitive).
 pub const SQL_SELECT_ALL_USERS: &str = "SELECT id, username, password_hash, is_admin, is_shared, enabled, created_at FROM users ORDER BY LOWER(username)";
 
+/// Select users holding a permission (admins hold every permission)
+///
+/// **Parameters:**
+/// 1. `permission: &str` - Permission name (snake_case)
+///
+/// **Returns:** Multiple rows of `(id, username, password_hash, is_admin, is_shared, enabled, created_at)`
+///
+/// **Note:** Used by `PermissionAudit`. Shared accounts are returned even if
+/// they can't use the permission; the caller filters them out.
+pub const SQL_SELECT_USERS_WITH_PERMISSION: &str = "SELECT id, username, password_hash, is_admin, is_shared, enabled, created_at FROM users WHERE is_admin = 1 OR id IN (SELECT user_id FROM user_permissions WHERE permission = ?) ORDER BY LOWER(username)";
+
 /// Check if a username exists (case-insensitive)
 ///
 /// **Parameters:**
@@ -227,7 +238,7 @@ pub const SQL_DELETE_USER_ATOMIC: &str = "DELETE FROM users
 ///
 /// **Parameters:** None
 ///
-/// **Returns:** Multiple rows of `(id, body, image, author_id, author_username, author_is_admin, created_at, updated_at)`
+/// **Returns:** Multiple rows of `(id, body, author_id, author_username, author_is_admin, created_at, updated_at)`
 ///
 /// **Note:** Joins with users table to get author information.
 /// Results are sorted by created_at descending (newest first).
@@ -235,7 +246,6 @@ pub const SQL_SELECT_ALL_NEWS: &str = "
     SELECT 
         n.id,
         n.body,
-        n.image,
         n.author_id,
         u.username as author_username,
         u.is_admin as author_is_admin,
@@ -250,14 +260,13 @@ pub const SQL_SELECT_ALL_NEWS: &str = "
 /// **Parameters:**
 /// 1. `id: i64` - News item ID
 ///
-/// **Returns:** `(id, body, image, author_id, author_username, author_is_admin, created_at, updated_at)`
+/// **Returns:** `(id, body, author_id, author_username, author_is_admin, created_at, updated_at)`
 ///
 /// **Note:** Joins with users table to get author information.
 pub const SQL_SELECT_NEWS_BY_ID: &str = "
     SELECT 
         n.id,
         n.body,
-        n.image,
         n.author_id,
         u.username as author_username,
         u.is_admin as author_is_admin,
@@ -267,41 +276,131 @@ pub const SQL_SELECT_NEWS_BY_ID: &str = "
     JOIN users u ON n.author_id = u.id
     WHERE n.id = ?";
 
+/// Search news items, newest first
+///
+/// **Parameters:**
+/// 1. `match: Option<&str>` - FTS5 query over post bodies (NULL for any)
+/// 2. `author: Option<&str>` - Author username, case-insensitive (NULL for any)
+/// 3. `since: Option<&str>` - Earliest creation timestamp, inclusive (NULL for any)
+/// 4. `before: Option<&str>` - Creation timestamp cutoff, exclusive (NULL for any)
+/// 5. `limit: i64` - Most items to return
+///
+/// **Returns:** Multiple rows of `(id, body, author_id, author_username, author_is_admin, created_at, updated_at)`
+///
+/// **Note:** Timestamps are RFC 3339 in UTC, so dates (`YYYY-MM-DD`) compare
+/// correctly as strings.
+pub const SQL_SEARCH_NEWS: &str = "
+    SELECT 
+        n.id,
+        n.body,
+        n.author_id,
+        u.username as author_username,
+        u.is_admin as author_is_admin,
+        n.created_at,
+        n.updated_at
+    FROM news n
+    JOIN users u ON n.author_id = u.id
+    WHERE (?1 IS NULL OR n.id IN (SELECT rowid FROM news_fts WHERE news_fts MATCH ?1))
+      AND (?2 IS NULL OR LOWER(u.username) = LOWER(?2))
+      AND (?3 IS NULL OR n.created_at >= ?3)
+      AND (?4 IS NULL OR n.created_at < ?4)
+    ORDER BY n.created_at DESC
+    LIMIT ?5";
+
 /// Insert a new news item
 ///
 /// **Parameters:**
 /// 1. `body: Option<&str>` - Markdown body text (nullable)
-/// 2. `image: Option<&str>` - Image data URI (nullable)
-/// 3. `author_id: i64` - Author's user ID
-/// 4. `created_at: &str` - ISO 8601 timestamp
+/// 2. `author_id: i64` - Author's user ID
+/// 3. `created_at: &str` - ISO 8601 timestamp
 ///
 /// **Returns:** `last_insert_rowid()` - The new news item's ID
 ///
-/// **Note:** At least one of body or image must be non-null (enforced by CHECK constraint).
+/// **Note:** The handler requires a body or at least one attachment.
 pub const SQL_INSERT_NEWS: &str = "
-    INSERT INTO news (body, image, author_id, created_at)
+    INSERT INTO news (body, author_id, created_at)
+    VALUES (?, ?, ?)";
+
+/// Insert an imported news item, keeping its original timestamps
+///
+/// **Parameters:**
+/// 1. `body: Option<&str>` - Markdown body text (nullable)
+/// 2. `author_id: i64` - Author's user ID
+/// 3. `created_at: &str` - ISO 8601 timestamp
+/// 4. `updated_at: Option<&str>` - ISO 8601 timestamp (nullable)
+///
+/// **Returns:** `last_insert_rowid()` - The new news item's ID
+pub const SQL_IMPORT_NEWS: &str = "
+    INSERT INTO news (body, author_id, created_at, updated_at)
     VALUES (?, ?, ?, ?)";
 
 /// Update a news item
 ///
 /// **Parameters:**
 /// 1. `body: Option<&str>` - New markdown body text (nullable)
-/// 2. `image: Option<&str>` - New image data URI (nullable)
-/// 3. `updated_at: &str` - ISO 8601 timestamp
-/// 4. `id: i64` - News item ID
-///
-/// **Note:** At least one of body or image must be non-null (enforced by CHECK constraint).
+/// 2. `updated_at: &str` - ISO 8601 timestamp
+/// 3. `id: i64` - News item ID
 pub const SQL_UPDATE_NEWS: &str = "
     UPDATE news
-    SET body = ?, image = ?, updated_at = ?
+    SET body = ?, updated_at = ?
     WHERE id = ?";
 
+/// Select the attachments of every news item
+///
+/// **Parameters:** None
+///
+/// **Returns:** Multiple rows of `(news_id, kind, data, caption)`
+///
+/// **Note:** Sorted by post, then in display order.
+pub const SQL_SELECT_ALL_NEWS_ATTACHMENTS: &str = "
+    SELECT news_id, kind, data, caption
+    FROM news_attachments
+    ORDER BY news_id, position";
+
+/// Select the attachments of a news item
+///
+/// **Parameters:**
+/// 1. `news_id: i64` - News item ID
+///
+/// **Returns:** Multiple rows of `(news_id, kind, data, caption)` in display order
+pub const SQL_SELECT_NEWS_ATTACHMENTS: &str = "
+    SELECT news_id, kind, data, caption
+    FROM news_attachments
+    WHERE news_id = ?
+    ORDER BY position";
+
+/// Insert a news attachment
+///
+/// **Parameters:**
+/// 1. `news_id: i64` - News item ID
+/// 2. `position: i64` - Display order (0-based)
+/// 3. `kind: &str` - `image` or `file`
+/// 4. `data: &str` - Image data URI or file area path
+/// 5. `caption: Option<&str>` - Caption (nullable)
+pub const SQL_INSERT_NEWS_ATTACHMENT: &str = "
+    INSERT INTO news_attachments (news_id, position, kind, data, caption)
+    VALUES (?, ?, ?, ?, ?)";
+
+/// Delete the attachments of a news item (before storing new ones)
+///
+/// **Parameters:**
+/// 1. `news_id: i64` - News item ID
+pub const SQL_DELETE_NEWS_ATTACHMENTS: &str = "DELETE FROM news_attachments WHERE news_id = ?";
+
 /// Delete a news item
 ///
 /// **Parameters:**
 /// 1. `id: i64` - News item ID
 pub const SQL_DELETE_NEWS: &str = "DELETE FROM news WHERE id = ?";
 
+/// Delete news items posted before a cutoff (retention)
+///
+/// **Parameters:**
+/// 1. `cutoff: &str` - ISO 8601 timestamp; older items are deleted
+///
+/// **Note:** Timestamps are RFC 3339 in UTC, so they compare as strings.
+pub const SQL_DELETE_NEWS_BEFORE: &str = "DELETE FROM news WHERE created_at < ?";
+
 // ========================================================================
 // IP Ban Query Operations
 // ========================================================================
@@ -412,6 +511,19 @@ pub const SQL_DELETE_EXPIRED_BANS: &str = "
     DELETE FROM ip_bans
     WHERE expires_at IS NOT NULL AND expires_at <= ?";
 
+/// Remove a nickname annotation from all bans (account erasure)
+///
+/// **Parameters:**
+/// 1. `nickname: &str` - Nickname to remove
+pub const SQL_ERASE_BAN_NICKNAME: &str = "UPDATE ip_bans SET nickname = NULL WHERE nickname = ?";
+
+/// Replace the creator of all bans created by a user (account erasure)
+///
+/// **Parameters:**
+/// 1. `placeholder: &str` - Replacement creator name
+/// 2. `created_by: &str` - Username to replace
+pub const SQL_ERASE_BAN_CREATOR: &str = "UPDATE ip_bans SET created_by = ? WHERE created_by = ?";
+
 // =============================================================================
 // IP Trusted
 // =============================================================================
@@ -507,3 +619,829 @@ pub const SQL_SELECT_ACTIVE_TRUSTS: &str = "
 pub const SQL_DELETE_EXPIRED_TRUSTS: &str = "
     DELETE FROM ip_trusted
     WHERE expires_at IS NOT NULL AND expires_at <= ?";
+
+/// Remove a nickname annotation from all trusted IP entries (account erasure)
+///
+/// **Parameters:**
+/// 1. `nickname: &str` - Nickname to remove
+pub const SQL_ERASE_TRUST_NICKNAME: &str =
+    "UPDATE ip_trusted SET nickname = NULL WHERE nickname = ?";
+
+/// Replace the creator of all trusted IP entries created by a user (account erasure)
+///
+/// **Parameters:**
+/// 1. `placeholder: &str` - Replacement creator name
+/// 2. `created_by: &str` - Username to replace
+pub const SQL_ERASE_TRUST_CREATOR: &str =
+    "UPDATE ip_trusted SET created_by = ? WHERE created_by = ?";
+
+// =============================================================================
+// Voice Clips
+// =============================================================================
+
+/// Select all voice clips (without audio data) ordered by name
+///
+/// **Parameters:** None
+///
+/// **Returns:** Multiple rows of `(name, size, duration_ms, uploader_id, uploader_username, created_at)`
+///
+/// **Note:** Joins with users table to get the uploader's username.
+pub const SQL_SELECT_ALL_VOICE_CLIPS: &str = "
+    SELECT c.name, c.size, c.duration_ms, c.uploader_id, u.username, c.created_at
+    FROM voice_clips c
+    JOIN users u ON c.uploader_id = u.id
+    ORDER BY c.name COLLATE NOCASE";
+
+/// Select a single voice clip (without audio data) by name (case-insensitive)
+///
+/// **Parameters:**
+/// 1. `name: &str` - Clip name
+///
+/// **Returns:** `(name, size, duration_ms, uploader_id, uploader_username, created_at)`
+pub const SQL_SELECT_VOICE_CLIP: &str = "
+    SELECT c.name, c.size, c.duration_ms, c.uploader_id, u.username, c.created_at
+    FROM voice_clips c
+    JOIN users u ON c.uploader_id = u.id
+    WHERE c.name = ?";
+
+/// Select a voice clip's audio data by name (case-insensitive)
+///
+/// **Parameters:**
+/// 1. `name: &str` - Clip name
+///
+/// **Returns:** `(name, data)` - The stored name and its data URI
+pub const SQL_SELECT_VOICE_CLIP_DATA: &str = "SELECT name, data FROM voice_clips WHERE name = ?";
+
+/// Insert a voice clip if the library is below its size limit
+///
+/// **Parameters:**
+/// 1. `name: &str` - Clip name
+/// 2. `data: &str` - Ogg Vorbis data URI
+/// 3. `size: i64` - Decoded audio size in bytes
+/// 4. `duration_ms: i64` - Playback length in milliseconds
+/// 5. `uploader_id: i64` - Uploader's user ID
+/// 6. `created_at: &str` - ISO 8601 timestamp
+/// 7. `max_clips: i64` - Maximum number of clips in the library
+///
+/// **Note:** Inserts nothing (0 rows affected) if the library is full.
+/// The name must not already exist (enforced by UNIQUE constraint).
+pub const SQL_INSERT_VOICE_CLIP: &str = "
+    INSERT INTO voice_clips (name, data, size, duration_ms, uploader_id, created_at)
+    SELECT ?, ?, ?, ?, ?, ?
+    WHERE (SELECT COUNT(*) FROM voice_clips) < ?";
+
+/// Delete a voice clip by name (case-insensitive)
+///
+/// **Parameters:**
+/// 1. `name: &str` - Clip name
+pub const SQL_DELETE_VOICE_CLIP: &str = "DELETE FROM voice_clips WHERE name = ?";
+
+// =============================================================================
+// Audit Log
+// =============================================================================
+
+/// Insert an audit log entry
+///
+/// **Parameters:**
+/// 1. `action: &str` - What was done (e.g. "user_erase")
+/// 2. `target: &str` - What it was done to
+/// 3. `actor: &str` - Username of who did it
+/// 4. `details: Option<&str>` - Extra information (nullable)
+/// 5. `created_at: i64` - Unix timestamp
+pub const SQL_INSERT_AUDIT_LOG: &str = "
+    INSERT INTO audit_log (action, target, actor, details, created_at)
+    VALUES (?, ?, ?, ?, ?)";
+
+/// Select all audit log entries, oldest first
+///
+/// **Returns:** Multiple rows of `(action, target, actor, details, created_at)`
+pub const SQL_SELECT_AUDIT_LOG: &str = "
+    SELECT action, target, actor, details, created_at
+    FROM audit_log
+    ORDER BY id";
+
+/// Select the most recent audit log entries, newest first
+///
+/// **Parameters:**
+/// 1. `limit: i64` - Most entries to return
+///
+/// **Returns:** Multiple rows of `(action, target, actor, details, created_at)`
+pub const SQL_SELECT_RECENT_AUDIT_LOG: &str = "
+    SELECT action, target, actor, details, created_at
+    FROM audit_log
+    ORDER BY id DESC
+    LIMIT ?";
+
+// =============================================================================
+// Daily Statistics
+// =============================================================================
+
+/// Add counters to a day's statistics (upsert)
+///
+/// **Parameters:**
+/// 1. `day: &str` - UTC day in `YYYY-MM-DD` format
+/// 2. `logins: i64` - Logins to add
+/// 3. `messages: i64` - Messages to add
+/// 4. `uploads: i64` - Completed uploads to add
+/// 5. `upload_bytes: i64` - Uploaded bytes to add
+/// 6. `downloads: i64` - Completed downloads to add
+/// 7. `download_bytes: i64` - Downloaded bytes to add
+/// 8. `peak_sessions: i64` - Peak concurrent sessions (the higher value is kept)
+pub const SQL_ADD_DAILY_STATS: &str = "
+    INSERT INTO daily_stats (day, logins, messages, uploads, upload_bytes, downloads, download_bytes, peak_sessions)
+    VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+    ON CONFLICT(day) DO UPDATE SET
+        logins = logins + excluded.logins,
+        messages = messages + excluded.messages,
+        uploads = uploads + excluded.uploads,
+        upload_bytes = upload_bytes + excluded.upload_bytes,
+        downloads = downloads + excluded.downloads,
+        download_bytes = download_bytes + excluded.download_bytes,
+        peak_sessions = MAX(peak_sessions, excluded.peak_sessions)";
+
+/// Select statistics for days on or after a given day, oldest first
+///
+/// **Parameters:**
+/// 1. `since: &str` - First UTC day in `YYYY-MM-DD` format
+///
+/// **Returns:** Multiple rows of `(day, logins, messages, uploads, upload_bytes, downloads, download_bytes, peak_sessions)`
+pub const SQL_SELECT_DAILY_STATS_SINCE: &str = "
+    SELECT day, logins, messages, uploads, upload_bytes, downloads, download_bytes, peak_sessions
+    FROM daily_stats
+    WHERE day >= ?
+    ORDER BY day";
+
+// =============================================================================
+// File Activity
+// =============================================================================
+
+/// Add downloads to a file and optionally record its upload (upsert)
+///
+/// A new upload time means a new file at the path, so the stored download
+/// count is replaced instead of added to.
+///
+/// **Parameters:**
+/// 1. `path: &str` - Path relative to the file root (e.g. "/shared/song.mp3")
+/// 2. `downloads: i64` - Completed downloads to add
+/// 3. `uploaded_at: Option<i64>` - Unix timestamp of the upload (nullable)
+pub const SQL_ADD_FILE_ACTIVITY: &str = "
+    INSERT INTO file_activity (path, downloads, uploaded_at)
+    VALUES (?, ?, ?)
+    ON CONFLICT(path) DO UPDATE SET
+        downloads = CASE WHEN excluded.uploaded_at IS NULL
+            THEN downloads + excluded.downloads
+            ELSE excluded.downloads END,
+        uploaded_at = COALESCE(excluded.uploaded_at, uploaded_at)";
+
+/// Select the most downloaded files under a prefix
+///
+/// **Parameters:**
+/// 1. `prefix: &str` - Path prefix ending in `/` (e.g. "/shared/")
+/// 2. `prefix: &str` - Same prefix again
+/// 3. `limit: i64` - Maximum rows
+///
+/// **Returns:** Multiple rows of `(path, downloads, uploaded_at)`
+pub const SQL_SELECT_POPULAR_FILES: &str = "
+    SELECT path, downloads, uploaded_at
+    FROM file_activity
+    WHERE downloads > 0 AND substr(path, 1, length(?)) = ?
+    ORDER BY downloads DESC, path
+    LIMIT ?";
+
+/// Select files uploaded on or after a time under a prefix, newest first
+///
+/// **Parameters:**
+/// 1. `since: i64` - Unix timestamp
+/// 2. `prefix: &str` - Path prefix ending in `/` (e.g. "/shared/")
+/// 3. `prefix: &str` - Same prefix again
+/// 4. `limit: i64` - Maximum rows
+///
+/// **Returns:** Multiple rows of `(path, downloads, uploaded_at)`
+pub const SQL_SELECT_RECENT_FILES: &str = "
+    SELECT path, downloads, uploaded_at
+    FROM file_activity
+    WHERE uploaded_at >= ? AND substr(path, 1, length(?)) = ?
+    ORDER BY uploaded_at DESC, path
+    LIMIT ?";
+
+/// Move the activity of a file, or of everything in a directory, to a new path
+///
+/// Rows already at the destination are replaced.
+///
+/// **Parameters:**
+/// 1. `to: &str` - New path
+/// 2. `from: &str` - Old path
+/// 3. `from: &str` - Old path again
+/// 4. `from_dir: &str` - Old path followed by `/`
+/// 5. `from_dir: &str` - Same again
+pub const SQL_MOVE_FILE_ACTIVITY: &str = "
+    UPDATE OR REPLACE file_activity
+    SET path = ? || substr(path, length(?) + 1)
+    WHERE path = ? OR substr(path, 1, length(?)) = ?";
+
+/// Select every path that has activity recorded
+///
+/// **Returns:** Multiple rows of `(path)`
+pub const SQL_SELECT_FILE_ACTIVITY_PATHS: &str = "SELECT path FROM file_activity ORDER BY path";
+
+/// Delete the activity of a file
+///
+/// **Parameters:**
+/// 1. `path: &str` - Path relative to the file root
+pub const SQL_DELETE_FILE_ACTIVITY: &str = "DELETE FROM file_activity WHERE path = ?";
+
+// =============================================================================
+// File Reviews
+// =============================================================================
+
+/// Set a user's rating of a file (upsert)
+///
+/// **Parameters:**
+/// 1. `path: &str` - Path relative to the file root (e.g. "/shared/song.mp3")
+/// 2. `user_id: i64` - Rating user's ID
+/// 3. `rating: i64` - Stars from 1 to 5
+pub const SQL_SET_FILE_RATING: &str = "
+    INSERT INTO file_ratings (path, user_id, rating)
+    VALUES (?, ?, ?)
+    ON CONFLICT(path, user_id) DO UPDATE SET rating = excluded.rating";
+
+/// Remove a user's rating of a file
+///
+/// **Parameters:**
+/// 1. `path: &str` - Path relative to the file root
+/// 2. `user_id: i64` - Rating user's ID
+pub const SQL_DELETE_FILE_RATING: &str = "DELETE FROM file_ratings WHERE path = ? AND user_id = ?";
+
+/// Select the number and sum of a file's ratings
+///
+/// **Parameters:**
+/// 1. `path: &str` - Path relative to the file root
+///
+/// **Returns:** Single row of `(count: i64, total: i64)` (0 and 0 when unrated)
+pub const SQL_SELECT_FILE_RATING: &str = "
+    SELECT COUNT(*), COALESCE(SUM(rating), 0)
+    FROM file_ratings
+    WHERE path = ?";
+
+/// Select a user's rating of a file
+///
+/// **Parameters:**
+/// 1. `path: &str` - Path relative to the file root
+/// 2. `user_id: i64` - Rating user's ID
+///
+/// **Returns:** `(rating: i64)` or None
+pub const SQL_SELECT_USER_FILE_RATING: &str =
+    "SELECT rating FROM file_ratings WHERE path = ? AND user_id = ?";
+
+/// Select the number and sum of ratings for several files at once
+///
+/// **Parameters:**
+/// 1. `paths: &str` - JSON array of paths relative to the file root
+///
+/// **Returns:** Multiple rows of `(path, count, total)`, one per rated file
+pub const SQL_SELECT_FILE_RATINGS: &str = "
+    SELECT path, COUNT(*), SUM(rating)
+    FROM file_ratings
+    WHERE path IN (SELECT value FROM json_each(?))
+    GROUP BY path";
+
+/// Insert a comment on a file
+///
+/// **Parameters:**
+/// 1. `path: &str` - Path relative to the file root
+/// 2. `author_id: i64` - Author's user ID
+/// 3. `body: &str` - Comment text
+/// 4. `created_at: i64` - Unix timestamp
+pub const SQL_INSERT_FILE_COMMENT: &str =
+    "INSERT INTO file_comments (path, author_id, body, created_at) VALUES (?, ?, ?, ?)";
+
+/// Select a file's comments with their authors, oldest first
+///
+/// **Parameters:**
+/// 1. `path: &str` - Path relative to the file root
+///
+/// **Returns:** Multiple rows of `(id, path, author_id, author_username, body, created_at)`
+pub const SQL_SELECT_FILE_COMMENTS: &str = "
+    SELECT c.id, c.path, c.author_id, u.username, c.body, c.created_at
+    FROM file_comments c
+    JOIN users u ON u.id = c.author_id
+    WHERE c.path = ?
+    ORDER BY c.created_at, c.id";
+
+/// Select a comment with its author
+///
+/// **Parameters:**
+/// 1. `id: i64` - Comment ID
+///
+/// **Returns:** Single row of `(id, path, author_id, author_username, body, created_at)` or None
+pub const SQL_SELECT_FILE_COMMENT_BY_ID: &str = "
+    SELECT c.id, c.path, c.author_id, u.username, c.body, c.created_at
+    FROM file_comments c
+    JOIN users u ON u.id = c.author_id
+    WHERE c.id = ?";
+
+/// Count a file's comments
+///
+/// **Parameters:**
+/// 1. `path: &str` - Path relative to the file root
+///
+/// **Returns:** `(count: i64)`
+pub const SQL_COUNT_FILE_COMMENTS: &str = "SELECT COUNT(*) FROM file_comments WHERE path = ?";
+
+/// Delete a comment
+///
+/// **Parameters:**
+/// 1. `id: i64` - Comment ID
+pub const SQL_DELETE_FILE_COMMENT: &str = "DELETE FROM file_comments WHERE id = ?";
+
+/// Delete the ratings of a file, or of everything in a directory
+///
+/// **Parameters:**
+/// 1. `path: &str` - Path relative to the file root
+/// 2. `dir: &str` - Path followed by `/`
+/// 3. `dir: &str` - Same again
+pub const SQL_DELETE_FILE_RATINGS_UNDER: &str =
+    "DELETE FROM file_ratings WHERE path = ? OR substr(path, 1, length(?)) = ?";
+
+/// Delete the comments on a file, or on everything in a directory
+///
+/// **Parameters:**
+/// 1. `path: &str` - Path relative to the file root
+/// 2. `dir: &str` - Path followed by `/`
+/// 3. `dir: &str` - Same again
+pub const SQL_DELETE_FILE_COMMENTS_UNDER: &str =
+    "DELETE FROM file_comments WHERE path = ? OR substr(path, 1, length(?)) = ?";
+
+/// Move the ratings of a file, or of everything in a directory, to a new path
+///
+/// **Parameters:**
+/// 1. `to: &str` - New path
+/// 2. `from: &str` - Old path
+/// 3. `from: &str` - Old path again
+/// 4. `from_dir: &str` - Old path followed by `/`
+/// 5. `from_dir: &str` - Same again
+pub const SQL_MOVE_FILE_RATINGS: &str = "
+    UPDATE OR REPLACE file_ratings
+    SET path = ? || substr(path, length(?) + 1)
+    WHERE path = ? OR substr(path, 1, length(?)) = ?";
+
+/// Move the comments on a file, or on everything in a directory, to a new path
+///
+/// **Parameters:** Same as [`SQL_MOVE_FILE_RATINGS`]
+pub const SQL_MOVE_FILE_COMMENTS: &str = "
+    UPDATE file_comments
+    SET path = ? || substr(path, length(?) + 1)
+    WHERE path = ? OR substr(path, 1, length(?)) = ?";
+
+/// Select every path that has ratings or comments
+///
+/// **Returns:** Multiple rows of `(path)`
+pub const SQL_SELECT_REVIEWED_PATHS: &str = "
+    SELECT path FROM file_ratings
+    UNION
+    SELECT path FROM file_comments
+    ORDER BY path";
+
+// =============================================================================
+// Upload Rules
+// =============================================================================
+
+/// Set a directory's upload rule (upsert)
+///
+/// **Parameters:**
+/// 1. `path: &str` - Directory path relative to the file root (e.g. "/shared/music")
+/// 2. `extensions: &str` - Comma-separated allowed extensions (empty for any type)
+/// 3. `max_file_size: Option<i64>` - Largest file in bytes
+/// 4. `max_total_size: Option<i64>` - Largest upload in bytes
+pub const SQL_SET_UPLOAD_RULE: &str = "
+    INSERT INTO upload_rules (path, extensions, max_file_size, max_total_size)
+    VALUES (?, ?, ?, ?)
+    ON CONFLICT(path) DO UPDATE SET
+        extensions = excluded.extensions,
+        max_file_size = excluded.max_file_size,
+        max_total_size = excluded.max_total_size";
+
+/// Remove a directory's upload rule
+///
+/// **Parameters:**
+/// 1. `path: &str` - Directory path relative to the file root
+pub const SQL_DELETE_UPLOAD_RULE: &str = "DELETE FROM upload_rules WHERE path = ?";
+
+/// Select the rule set on a directory itself
+///
+/// **Parameters:**
+/// 1. `path: &str` - Directory path relative to the file root
+///
+/// **Returns:** `(extensions, max_file_size, max_total_size)` or None
+pub const SQL_SELECT_UPLOAD_RULE: &str =
+    "SELECT extensions, max_file_size, max_total_size FROM upload_rules WHERE path = ?";
+
+/// Select the rule of the nearest directory (the path itself or an ancestor) that has one
+///
+/// **Parameters:**
+/// 1. `path: &str` - Directory path relative to the file root
+/// 2. `path: &str` - Same again
+///
+/// **Returns:** `(extensions, max_file_size, max_total_size)` or None
+pub const SQL_SELECT_EFFECTIVE_UPLOAD_RULE: &str = "
+    SELECT extensions, max_file_size, max_total_size
+    FROM upload_rules
+    WHERE path = ? OR path = '/' OR substr(?, 1, length(path) + 1) = path || '/'
+    ORDER BY length(path) DESC
+    LIMIT 1";
+
+/// Delete the rules of a directory and everything in it
+///
+/// **Parameters:**
+/// 1. `path: &str` - Path relative to the file root
+/// 2. `dir: &str` - Path followed by `/`
+/// 3. `dir: &str` - Same again
+pub const SQL_DELETE_UPLOAD_RULES_UNDER: &str =
+    "DELETE FROM upload_rules WHERE path = ? OR substr(path, 1, length(?)) = ?";
+
+/// Move the rules of a directory and everything in it to a new path
+///
+/// **Parameters:**
+/// 1. `to: &str` - New path
+/// 2. `from: &str` - Old path
+/// 3. `from: &str` - Old path again
+/// 4. `from_dir: &str` - Old path followed by `/`
+/// 5. `from_dir: &str` - Same again
+pub const SQL_MOVE_UPLOAD_RULES: &str = "
+    UPDATE OR REPLACE upload_rules
+    SET path = ? || substr(path, length(?) + 1)
+    WHERE path = ? OR substr(path, 1, length(?)) = ?";
+
+/// Select every directory that has a rule
+///
+/// **Returns:** Multiple rows of `(path)`
+pub const SQL_SELECT_UPLOAD_RULE_PATHS: &str = "SELECT path FROM upload_rules ORDER BY path";
+
+// =============================================================================
+// User Statistics
+// =============================================================================
+
+/// Add activity counters to an account's totals (upsert)
+///
+/// Nothing is written if the account was deleted in the meantime.
+///
+/// **Parameters:**
+/// 1. `user_id: i64` - Account ID
+/// 2. `logins: i64` - Logins to add
+/// 3. `messages: i64` - Messages to add
+/// 4. `uploads: i64` - Completed uploads to add
+/// 5. `download_bytes: i64` - Downloaded bytes to add
+/// 6. `last_login_at: Option<i64>` - Time of the latest login (the later one is kept)
+/// 7. `user_id: i64` - Account ID again
+pub const SQL_ADD_USER_STATS: &str = "
+    INSERT INTO user_stats (user_id, logins, messages, uploads, download_bytes, last_login_at)
+    SELECT ?, ?, ?, ?, ?, ?
+    WHERE EXISTS (SELECT 1 FROM users WHERE id = ?)
+    ON CONFLICT(user_id) DO UPDATE SET
+        logins = logins + excluded.logins,
+        messages = messages + excluded.messages,
+        uploads = uploads + excluded.uploads,
+        download_bytes = download_bytes + excluded.download_bytes,
+        last_login_at = COALESCE(MAX(last_login_at, excluded.last_login_at), last_login_at, excluded.last_login_at)";
+
+/// Select an account's activity counters
+///
+/// **Parameters:**
+/// 1. `user_id: i64` - Account ID
+///
+/// **Returns:** Single row of `(logins, messages, uploads, download_bytes, last_login_at)` or None
+pub const SQL_SELECT_USER_STATS: &str = "
+    SELECT logins, messages, uploads, download_bytes, last_login_at
+    FROM user_stats
+    WHERE user_id = ?";
+
+/// Select the regular accounts with the highest value of one counter
+///
+/// **Parameters:**
+/// 1. `stat: &str` - Counter column (`logins`, `messages`, `uploads` or `download_bytes`)
+/// 2. `stat: &str` - Same counter again
+/// 3. `limit: i64` - Maximum rows
+///
+/// **Returns:** Multiple rows of `(username, logins, messages, uploads, download_bytes)`
+pub const SQL_SELECT_LEADERBOARD: &str = "
+    SELECT u.username, s.logins, s.messages, s.uploads, s.download_bytes
+    FROM user_stats s
+    JOIN users u ON u.id = s.user_id
+    WHERE u.is_shared = 0
+      AND CASE ? WHEN 'messages' THEN s.messages WHEN 'uploads' THEN s.uploads
+          WHEN 'download_bytes' THEN s.download_bytes ELSE s.logins END > 0
+    ORDER BY CASE ? WHEN 'messages' THEN s.messages WHEN 'uploads' THEN s.uploads
+          WHEN 'download_bytes' THEN s.download_bytes ELSE s.logins END DESC, u.username COLLATE NOCASE
+    LIMIT ?";
+
+// =============================================================================
+// Upload Journal
+// =============================================================================
+
+/// Record an upload in progress, replacing any earlier entry for its .part file
+///
+/// **Parameters:**
+/// 1. `part_path: &str` - .part file path relative to the file root
+/// 2. `target_path: &str` - Final file path relative to the file root
+/// 3. `username: &str` - Uploading account
+/// 4. `sha256: &str` - Expected SHA-256 of the complete file
+/// 5. `size: i64` - Expected size of the complete file
+/// 6. `bytes_received: i64` - Bytes already in the .part file
+/// 7. `updated_at: i64` - Unix timestamp
+pub const SQL_RECORD_UPLOAD: &str = "
+    INSERT INTO upload_journal (part_path, target_path, username, sha256, size, bytes_received, updated_at)
+    VALUES (?, ?, ?, ?, ?, ?, ?)
+    ON CONFLICT(part_path) DO UPDATE SET
+        target_path = excluded.target_path,
+        username = excluded.username,
+        sha256 = excluded.sha256,
+        size = excluded.size,
+        bytes_received = excluded.bytes_received,
+        updated_at = excluded.updated_at";
+
+/// Update the bytes received for an upload in progress
+///
+/// **Parameters:**
+/// 1. `bytes_received: i64` - Bytes in the .part file
+/// 2. `updated_at: i64` - Unix timestamp
+/// 3. `part_path: &str` - .part file path relative to the file root
+pub const SQL_UPDATE_UPLOAD_PROGRESS: &str =
+    "UPDATE upload_journal SET bytes_received = ?, updated_at = ? WHERE part_path = ?";
+
+/// Correct the bytes received for an upload without touching its timestamp
+///
+/// **Parameters:**
+/// 1. `bytes_received: i64` - Bytes in the .part file
+/// 2. `part_path: &str` - .part file path relative to the file root
+pub const SQL_CORRECT_UPLOAD_BYTES: &str =
+    "UPDATE upload_journal SET bytes_received = ? WHERE part_path = ?";
+
+/// Remove an upload from the journal
+///
+/// **Parameters:**
+/// 1. `part_path: &str` - .part file path relative to the file root
+pub const SQL_DELETE_UPLOAD: &str = "DELETE FROM upload_journal WHERE part_path = ?";
+
+/// Select the journal entry for a .part file
+///
+/// **Parameters:**
+/// 1. `part_path: &str` - .part file path relative to the file root
+///
+/// **Returns:** `(part_path, target_path, username, sha256, size, bytes_received, updated_at)` or None
+pub const SQL_SELECT_UPLOAD: &str = "
+    SELECT part_path, target_path, username, sha256, size, bytes_received, updated_at
+    FROM upload_journal
+    WHERE part_path = ?";
+
+/// Select every upload in the journal
+///
+/// **Returns:** Multiple rows of `(part_path, target_path, username, sha256, size, bytes_received, updated_at)`
+pub const SQL_SELECT_UPLOADS: &str = "
+    SELECT part_path, target_path, username, sha256, size, bytes_received, updated_at
+    FROM upload_journal
+    ORDER BY part_path";
+
+/// Replace the uploader of all journaled uploads by a user (account erasure)
+///
+/// **Parameters:**
+/// 1. `placeholder: &str` - Replacement username
+/// 2. `username: &str` - Username to replace
+pub const SQL_ERASE_UPLOAD_USERNAME: &str =
+    "UPDATE upload_journal SET username = ? WHERE username = ?";
+
+// =============================================================================
+// Single Sign-On Identities
+// =============================================================================
+
+/// Link an identity provider subject to an account
+///
+/// **Parameters:**
+/// 1. `issuer: &str` - Identity provider issuer URL
+/// 2. `subject: &str` - Subject identifier at that provider
+/// 3. `user_id: i64` - Account the subject logs in as
+/// 4. `created_at: i64` - Unix timestamp
+pub const SQL_INSERT_OIDC_IDENTITY: &str =
+    "INSERT INTO oidc_identities (issuer, subject, user_id, created_at) VALUES (?, ?, ?, ?)";
+
+/// Select the account a subject is linked to
+///
+/// **Parameters:**
+/// 1. `issuer: &str` - Identity provider issuer URL
+/// 2. `subject: &str` - Subject identifier at that provider
+///
+/// **Returns:** `user_id` or None
+pub const SQL_SELECT_OIDC_IDENTITY: &str =
+    "SELECT user_id FROM oidc_identities WHERE issuer = ? AND subject = ?";
+
+// =============================================================================
+// Directory Groups and Accounts
+// =============================================================================
+
+/// Select all directory group mappings
+///
+/// **Returns:** Multiple rows of `(group_dn, permissions, is_admin)`
+pub const SQL_SELECT_LDAP_GROUPS: &str =
+    "SELECT group_dn, permissions, is_admin FROM ldap_groups ORDER BY group_dn";
+
+/// Insert or replace a directory group mapping
+///
+/// **Parameters:**
+/// 1. `group_dn: &str` - Distinguished name of the group
+/// 2. `permissions: &str` - Comma-separated permission names
+/// 3. `is_admin: bool` - Whether members are admins
+pub const SQL_SET_LDAP_GROUP: &str = "
+    INSERT INTO ldap_groups (group_dn, permissions, is_admin) VALUES (?, ?, ?)
+    ON CONFLICT(group_dn) DO UPDATE SET
+        group_dn = excluded.group_dn,
+        permissions = excluded.permissions,
+        is_admin = excluded.is_admin";
+
+/// Delete a directory group mapping
+///
+/// **Parameters:**
+/// 1. `group_dn: &str` - Distinguished name of the group
+pub const SQL_DELETE_LDAP_GROUP: &str = "DELETE FROM ldap_groups WHERE group_dn = ?";
+
+/// Mark an account as created through the directory, with its entry's DN
+///
+/// **Parameters:**
+/// 1. `user_id: i64` - Account ID
+/// 2. `dn: &str` - Distinguished name of the user's entry
+pub const SQL_SET_LDAP_ACCOUNT: &str = "
+    INSERT INTO ldap_accounts (user_id, dn) VALUES (?, ?)
+    ON CONFLICT(user_id) DO UPDATE SET dn = excluded.dn";
+
+/// Select the directory DN of an account
+///
+/// **Parameters:**
+/// 1. `user_id: i64` - Account ID
+///
+/// **Returns:** `dn` or None for local accounts
+pub const SQL_SELECT_LDAP_ACCOUNT: &str = "SELECT dn FROM ldap_accounts WHERE user_id = ?";
+
+// =============================================================================
+// Registered Nicknames
+// =============================================================================
+
+/// Reserve a nickname for an account
+///
+/// **Parameters:**
+/// 1. `nickname: &str` - Nickname to reserve
+/// 2. `user_id: i64` - Account it's reserved for
+/// 3. `created_at: i64` - Unix timestamp
+pub const SQL_INSERT_REGISTERED_NICKNAME: &str =
+    "INSERT INTO registered_nicknames (nickname, user_id, created_at) VALUES (?, ?, ?)";
+
+/// Select the account a nickname is reserved for (case-insensitive)
+///
+/// **Parameters:**
+/// 1. `nickname: &str` - Nickname to look up
+///
+/// **Returns:** `(nickname, user_id, username)` or None
+pub const SQL_SELECT_REGISTERED_NICKNAME: &str = "
+    SELECT r.nickname, r.user_id, u.username
+    FROM registered_nicknames r
+    JOIN users u ON u.id = r.user_id
+    WHERE r.nickname = ?";
+
+/// Count the nicknames reserved for an account
+///
+/// **Parameters:**
+/// 1. `user_id: i64` - Account ID
+///
+/// **Returns:** `(count: i64)`
+pub const SQL_COUNT_REGISTERED_NICKNAMES: &str =
+    "SELECT COUNT(*) FROM registered_nicknames WHERE user_id = ?";
+
+/// Release a reserved nickname (case-insensitive)
+///
+/// **Parameters:**
+/// 1. `nickname: &str` - Nickname to release
+pub const SQL_DELETE_REGISTERED_NICKNAME: &str =
+    "DELETE FROM registered_nicknames WHERE nickname = ?";
+
+// =============================================================================
+// Direct Message History
+// =============================================================================
+
+/// Select how many days an account keeps its direct messages
+///
+/// **Parameters:**
+/// 1. `user_id: i64` - Account ID
+///
+/// **Returns:** `(message_history_days: i64)` or None
+pub const SQL_SELECT_MESSAGE_HISTORY_DAYS: &str =
+    "SELECT message_history_days FROM users WHERE id = ?";
+
+/// Set how many days an account keeps its direct messages
+///
+/// **Parameters:**
+/// 1. `days: i64` - Days to keep (0 = off)
+/// 2. `user_id: i64` - Account ID
+pub const SQL_UPDATE_MESSAGE_HISTORY_DAYS: &str =
+    "UPDATE users SET message_history_days = ? WHERE id = ?";
+
+/// Store a direct message for an account, if the account keeps history
+///
+/// **Parameters:**
+/// 1. `owner_id: i64` - Account the copy belongs to
+/// 2. `peer_nickname: &str` - The other party
+/// 3. `from_nickname: &str` - Sender
+/// 4. `to_nickname: &str` - Recipient
+/// 5. `is_action: bool` - Whether it's a /me action
+/// 6. `nonce: &[u8]` - Encryption nonce
+/// 7. `body: &[u8]` - Encrypted message text
+/// 8. `created_at: i64` - Unix timestamp
+///
+/// **Note:** Inserts nothing when the account has history off.
+pub const SQL_INSERT_MESSAGE_HISTORY: &str = "
+    INSERT INTO message_history
+        (owner_id, peer_nickname, from_nickname, to_nickname, is_action, nonce, body, created_at)
+    SELECT id, ?2, ?3, ?4, ?5, ?6, ?7, ?8
+    FROM users
+    WHERE id = ?1 AND message_history_days > 0";
+
+/// Select an account's stored direct messages, newest first
+///
+/// **Parameters:**
+/// 1. `owner_id: i64` - Account ID
+/// 2. `peer_nickname: Option<&str>` - Only this conversation, case-insensitive (NULL for all)
+/// 3. `before: Option<i64>` - Only messages with a lower ID (NULL for the newest)
+/// 4. `limit: i64` - Most messages to return
+///
+/// **Returns:** Multiple rows of `(id, from_nickname, to_nickname, is_action, nonce, body, created_at)`
+pub const SQL_SELECT_MESSAGE_HISTORY: &str = "
+    SELECT id, from_nickname, to_nickname, is_action, nonce, body, created_at
+    FROM message_history
+    WHERE owner_id = ?1
+      AND (?2 IS NULL OR peer_nickname = ?2)
+      AND (?3 IS NULL OR id < ?3)
+    ORDER BY id DESC
+    LIMIT ?4";
+
+/// Delete all of an account's stored direct messages
+///
+/// **Parameters:**
+/// 1. `owner_id: i64` - Account ID
+pub const SQL_DELETE_MESSAGE_HISTORY_FOR_OWNER: &str =
+    "DELETE FROM message_history WHERE owner_id = ?";
+
+/// Delete every account's stored conversations with a nickname (case-insensitive)
+///
+/// **Parameters:**
+/// 1. `peer_nickname: &str` - The other party
+pub const SQL_DELETE_MESSAGE_HISTORY_WITH_PEER: &str =
+    "DELETE FROM message_history WHERE peer_nickname = ?";
+
+/// Delete stored direct messages older than their account's retention
+///
+/// **Parameters:**
+/// 1. `now: i64` - Current Unix timestamp
+pub const SQL_DELETE_EXPIRED_MESSAGE_HISTORY: &str = "
+    DELETE FROM message_history
+    WHERE id IN (
+        SELECT m.id
+        FROM message_history m
+        JOIN users u ON u.id = m.owner_id
+        WHERE m.created_at < ?1 - u.message_history_days * 86400
+    )";
+
+// =============================================================================
+// Snippets
+// =============================================================================
+
+/// Insert a snippet if its author is below their snippet limit
+///
+/// **Parameters:**
+/// 1. `id: &str` - Random snippet ID
+/// 2. `author_id: i64` - Author's account ID
+/// 3. `author_nickname: &str` - Author's display name
+/// 4. `language: Option<&str>` - Language tag for highlighting
+/// 5. `content: &str` - Snippet text
+/// 6. `created_at: i64` - Unix timestamp
+/// 7. `expires_at: i64` - Unix timestamp
+/// 8. `max_snippets: i64` - Most unexpired snippets per account
+///
+/// **Note:** Inserts nothing (0 rows affected) if the author is at the limit.
+pub const SQL_INSERT_SNIPPET: &str = "
+    INSERT INTO snippets (id, author_id, author_nickname, language, content, created_at, expires_at)
+    SELECT ?1, ?2, ?3, ?4, ?5, ?6, ?7
+    WHERE (SELECT COUNT(*) FROM snippets WHERE author_id = ?2 AND expires_at > ?6) < ?8";
+
+/// Select an unexpired snippet by ID
+///
+/// **Parameters:**
+/// 1. `id: &str` - Snippet ID
+/// 2. `now: i64` - Current Unix timestamp
+///
+/// **Returns:** `(id, author_nickname, language, content, created_at, expires_at)` or None
+pub const SQL_SELECT_SNIPPET: &str = "
+    SELECT id, author_nickname, language, content, created_at, expires_at
+    FROM snippets
+    WHERE id = ?1 AND expires_at > ?2";
+
+/// Delete expired snippets
+///
+/// **Parameters:**
+/// 1. `now: i64` - Current Unix timestamp
+pub const SQL_DELETE_EXPIRED_SNIPPETS: &str = "DELETE FROM snippets WHERE expires_at <= ?";
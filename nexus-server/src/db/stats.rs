@@ -0,0 +1,521 @@
+//! Daily statistics and file activity database operations
+//!
+//! Activity counters are aggregated in memory by [`crate::stats`] and added to
+//! one row per UTC day, so the table only grows by a row a day. Per-file
+//! download counts and upload times are kept the same way, one row per file,
+//! and lifetime activity counters one row per account.
+
+use nexus_common::protocol::{DailyStats, LeaderboardStat, UserStats};
+use sqlx::sqlite::SqlitePool;
+
+use crate::db::sql;
+
+/// Row type for daily statistics queries
+type DailyStatsRow = (String, i64, i64, i64, i64, i64, i64, i64);
+
+/// A file's download count and upload time from the database
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileActivityRecord {
+    /// Path relative to the file root (e.g. "/shared/song.mp3")
+    pub path: String,
+    pub downloads: u64,
+    pub uploaded_at: Option<i64>,
+}
+
+/// Row type for file activity queries
+type FileActivityRow = (String, i64, Option<i64>);
+
+impl From<FileActivityRow> for FileActivityRecord {
+    fn from(row: FileActivityRow) -> Self {
+        Self {
+            path: row.0,
+            downloads: row.1 as u64,
+            uploaded_at: row.2,
+        }
+    }
+}
+
+/// An account's activity counters from the database
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserStatsRecord {
+    pub username: String,
+    pub stats: UserStats,
+}
+
+/// Row type for user statistics queries
+type UserStatsRow = (i64, i64, i64, i64, Option<i64>);
+
+/// Row type for leaderboard queries
+type LeaderboardRow = (String, i64, i64, i64, i64);
+
+impl From<LeaderboardRow> for UserStatsRecord {
+    fn from(row: LeaderboardRow) -> Self {
+        Self {
+            username: row.0,
+            stats: user_stats_from_row((row.1, row.2, row.3, row.4, None)),
+        }
+    }
+}
+
+/// Convert a database row to the protocol type
+fn user_stats_from_row(row: UserStatsRow) -> UserStats {
+    UserStats {
+        logins: row.0 as u64,
+        messages: row.1 as u64,
+        uploads: row.2 as u64,
+        download_bytes: row.3 as u64,
+        last_login_at: row.4,
+    }
+}
+
+/// Column name of a leaderboard counter
+fn stat_column(stat: LeaderboardStat) -> &'static str {
+    match stat {
+        LeaderboardStat::Logins => "logins",
+        LeaderboardStat::Messages => "messages",
+        LeaderboardStat::Uploads => "uploads",
+        LeaderboardStat::DownloadBytes => "download_bytes",
+    }
+}
+
+/// Convert a database row to the protocol type
+fn daily_stats_from_row(row: DailyStatsRow) -> DailyStats {
+    DailyStats {
+        date: row.0,
+        logins: row.1 as u64,
+        messages: row.2 as u64,
+        uploads: row.3 as u64,
+        upload_bytes: row.4 as u64,
+        downloads: row.5 as u64,
+        download_bytes: row.6 as u64,
+        peak_sessions: row.7 as u32,
+    }
+}
+
+/// Database access for daily statistics
+#[derive(Clone)]
+pub struct StatsDb {
+    pool: SqlitePool,
+}
+
+impl StatsDb {
+    /// Create a new StatsDb instance
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Add daily, per-file and per-account counters in one transaction
+    ///
+    /// Either everything is added or nothing is, so a failed flush can be
+    /// retried with the same counters, and a flush costs one commit however
+    /// many rows it touches.
+    ///
+    /// - Day counts are summed with what is already stored; for peak sessions
+    ///   the higher value is kept.
+    /// - File downloads are summed; recording an upload (`uploaded_at` set)
+    ///   resets the file's download count to `downloads`.
+    /// - Account counts are summed; counters for accounts that no longer exist
+    ///   are dropped.
+    pub async fn add_batch(
+        &self,
+        days: &[DailyStats],
+        files: &[FileActivityRecord],
+        users: &[(i64, UserStats)],
+    ) -> Result<(), sqlx::Error> {
+        if days.is_empty() && files.is_empty() && users.is_empty() {
+            return Ok(());
+        }
+
+        let mut tx = self.pool.begin().await?;
+
+        for stats in days {
+            sqlx::query(sql::SQL_ADD_DAILY_STATS)
+                .bind(stats.date.as_str())
+                .bind(stats.logins as i64)
+                .bind(stats.messages as i64)
+                .bind(stats.uploads as i64)
+                .bind(stats.upload_bytes as i64)
+                .bind(stats.downloads as i64)
+                .bind(stats.download_bytes as i64)
+                .bind(i64::from(stats.peak_sessions))
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        for file in files {
+            sqlx::query(sql::SQL_ADD_FILE_ACTIVITY)
+                .bind(file.path.as_str())
+                .bind(file.downloads as i64)
+                .bind(file.uploaded_at)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        for &(user_id, stats) in users {
+            sqlx::query(sql::SQL_ADD_USER_STATS)
+                .bind(user_id)
+                .bind(stats.logins as i64)
+                .bind(stats.messages as i64)
+                .bind(stats.uploads as i64)
+                .bind(stats.download_bytes as i64)
+                .bind(stats.last_login_at)
+                .bind(user_id)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        tx.commit().await
+    }
+
+    /// Add counters to a day's totals
+    #[cfg(test)]
+    pub async fn add(&self, stats: &DailyStats) -> Result<(), sqlx::Error> {
+        self.add_batch(std::slice::from_ref(stats), &[], &[]).await
+    }
+
+    /// Get the totals for days on or after `since` (`YYYY-MM-DD`), oldest first
+    ///
+    /// Days without activity have no row and are not returned.
+    pub async fn list_since(&self, since: &str) -> Result<Vec<DailyStats>, sqlx::Error> {
+        let rows: Vec<DailyStatsRow> = sqlx::query_as(sql::SQL_SELECT_DAILY_STATS_SINCE)
+            .bind(since)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rows.into_iter().map(daily_stats_from_row).collect())
+    }
+
+    /// Add downloads to a file, and record its upload if `uploaded_at` is set
+    #[cfg(test)]
+    pub async fn add_file_activity(
+        &self,
+        path: &str,
+        downloads: u64,
+        uploaded_at: Option<i64>,
+    ) -> Result<(), sqlx::Error> {
+        let file = FileActivityRecord {
+            path: path.to_string(),
+            downloads,
+            uploaded_at,
+        };
+        self.add_batch(&[], &[file], &[]).await
+    }
+
+    /// Get the most downloaded files whose path starts with `prefix`
+    pub async fn popular_files(
+        &self,
+        prefix: &str,
+        limit: usize,
+    ) -> Result<Vec<FileActivityRecord>, sqlx::Error> {
+        let rows: Vec<FileActivityRow> = sqlx::query_as(sql::SQL_SELECT_POPULAR_FILES)
+            .bind(prefix)
+            .bind(prefix)
+            .bind(limit as i64)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rows.into_iter().map(FileActivityRecord::from).collect())
+    }
+
+    /// Get files uploaded since `since` whose path starts with `prefix`, newest first
+    pub async fn recent_files(
+        &self,
+        prefix: &str,
+        since: i64,
+        limit: usize,
+    ) -> Result<Vec<FileActivityRecord>, sqlx::Error> {
+        let rows: Vec<FileActivityRow> = sqlx::query_as(sql::SQL_SELECT_RECENT_FILES)
+            .bind(since)
+            .bind(prefix)
+            .bind(prefix)
+            .bind(limit as i64)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rows.into_iter().map(FileActivityRecord::from).collect())
+    }
+
+    /// Move the activity of a file, or of everything under a directory, to a new path
+    pub async fn move_file_activity(&self, from: &str, to: &str) -> Result<(), sqlx::Error> {
+        let from_dir = format!("{from}/");
+        sqlx::query(sql::SQL_MOVE_FILE_ACTIVITY)
+            .bind(to)
+            .bind(from)
+            .bind(from)
+            .bind(&from_dir)
+            .bind(&from_dir)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Get every path that has activity recorded
+    pub async fn file_activity_paths(&self) -> Result<Vec<String>, sqlx::Error> {
+        sqlx::query_scalar(sql::SQL_SELECT_FILE_ACTIVITY_PATHS)
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    /// Delete the activity of a file
+    pub async fn delete_file_activity(&self, path: &str) -> Result<(), sqlx::Error> {
+        sqlx::query(sql::SQL_DELETE_FILE_ACTIVITY)
+            .bind(path)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Add counters to an account's totals
+    #[cfg(test)]
+    pub async fn add_user_stats(&self, user_id: i64, stats: &UserStats) -> Result<(), sqlx::Error> {
+        self.add_batch(&[], &[], &[(user_id, *stats)]).await
+    }
+
+    /// Get an account's totals (zeros if it has no activity yet)
+    pub async fn get_user_stats(&self, user_id: i64) -> Result<UserStats, sqlx::Error> {
+        let row: Option<UserStatsRow> = sqlx::query_as(sql::SQL_SELECT_USER_STATS)
+            .bind(user_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(user_stats_from_row).unwrap_or_default())
+    }
+
+    /// Get the regular accounts with the highest value of `stat`
+    ///
+    /// Shared accounts and accounts with a zero count are left out.
+    pub async fn leaderboard(
+        &self,
+        stat: LeaderboardStat,
+        limit: usize,
+    ) -> Result<Vec<UserStatsRecord>, sqlx::Error> {
+        let column = stat_column(stat);
+        let rows: Vec<LeaderboardRow> = sqlx::query_as(sql::SQL_SELECT_LEADERBOARD)
+            .bind(column)
+            .bind(column)
+            .bind(limit as i64)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rows.into_iter().map(UserStatsRecord::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::testing::create_test_db;
+    use crate::db::{Permissions, UserDb};
+
+    fn day(date: &str, logins: u64, peak_sessions: u32) -> DailyStats {
+        DailyStats {
+            date: date.to_string(),
+            logins,
+            peak_sessions,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_add_sums_counters_and_keeps_peak() {
+        let pool = create_test_db().await;
+        let stats_db = StatsDb::new(pool);
+
+        let mut first = day("2026-01-17", 3, 5);
+        first.upload_bytes = 1000;
+        stats_db.add(&first).await.unwrap();
+
+        let mut second = day("2026-01-17", 2, 4);
+        second.upload_bytes = 500;
+        second.messages = 7;
+        stats_db.add(&second).await.unwrap();
+
+        let days = stats_db.list_since("2026-01-17").await.unwrap();
+        assert_eq!(days.len(), 1);
+        assert_eq!(days[0].logins, 5);
+        assert_eq!(days[0].messages, 7);
+        assert_eq!(days[0].upload_bytes, 1500);
+        // The lower peak from the second flush doesn't replace the first
+        assert_eq!(days[0].peak_sessions, 5);
+    }
+
+    #[tokio::test]
+    async fn test_list_since_filters_and_orders() {
+        let pool = create_test_db().await;
+        let stats_db = StatsDb::new(pool);
+
+        stats_db.add(&day("2026-01-19", 1, 1)).await.unwrap();
+        stats_db.add(&day("2026-01-10", 1, 1)).await.unwrap();
+        stats_db.add(&day("2026-01-18", 1, 1)).await.unwrap();
+
+        let days = stats_db.list_since("2026-01-15").await.unwrap();
+        let dates: Vec<&str> = days.iter().map(|d| d.date.as_str()).collect();
+        assert_eq!(dates, vec!["2026-01-18", "2026-01-19"]);
+    }
+
+    #[tokio::test]
+    async fn test_file_activity_upload_resets_downloads() {
+        let pool = create_test_db().await;
+        let stats_db = StatsDb::new(pool);
+
+        stats_db
+            .add_file_activity("/shared/a.txt", 0, Some(1000))
+            .await
+            .unwrap();
+        stats_db
+            .add_file_activity("/shared/a.txt", 3, None)
+            .await
+            .unwrap();
+        stats_db
+            .add_file_activity("/shared/a.txt", 2, None)
+            .await
+            .unwrap();
+
+        let popular = stats_db.popular_files("/", 10).await.unwrap();
+        assert_eq!(popular[0].downloads, 5);
+        assert_eq!(popular[0].uploaded_at, Some(1000));
+
+        // A new file at the same path starts over
+        stats_db
+            .add_file_activity("/shared/a.txt", 1, Some(2000))
+            .await
+            .unwrap();
+        let popular = stats_db.popular_files("/", 10).await.unwrap();
+        assert_eq!(popular[0].downloads, 1);
+        assert_eq!(popular[0].uploaded_at, Some(2000));
+    }
+
+    #[tokio::test]
+    async fn test_file_activity_filters_by_prefix() {
+        let pool = create_test_db().await;
+        let stats_db = StatsDb::new(pool);
+
+        stats_db
+            .add_file_activity("/shared/a.txt", 1, Some(1000))
+            .await
+            .unwrap();
+        stats_db
+            .add_file_activity("/shared2/b.txt", 5, Some(3000))
+            .await
+            .unwrap();
+        stats_db
+            .add_file_activity("/users/alice/c.txt", 9, Some(2000))
+            .await
+            .unwrap();
+
+        let popular = stats_db.popular_files("/", 10).await.unwrap();
+        let paths: Vec<&str> = popular.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec!["/users/alice/c.txt", "/shared2/b.txt", "/shared/a.txt"]
+        );
+
+        let popular = stats_db.popular_files("/shared/", 10).await.unwrap();
+        assert_eq!(popular.len(), 1);
+        assert_eq!(popular[0].path, "/shared/a.txt");
+
+        let recent = stats_db.recent_files("/", 1500, 10).await.unwrap();
+        let paths: Vec<&str> = recent.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, vec!["/shared2/b.txt", "/users/alice/c.txt"]);
+    }
+
+    #[tokio::test]
+    async fn test_move_file_activity() {
+        let pool = create_test_db().await;
+        let stats_db = StatsDb::new(pool);
+
+        stats_db
+            .add_file_activity("/shared/music/a.mp3", 4, None)
+            .await
+            .unwrap();
+        stats_db
+            .add_file_activity("/shared/musical.txt", 2, None)
+            .await
+            .unwrap();
+
+        stats_db
+            .move_file_activity("/shared/music", "/shared/songs")
+            .await
+            .unwrap();
+
+        let popular = stats_db.popular_files("/", 10).await.unwrap();
+        let paths: Vec<&str> = popular.iter().map(|f| f.path.as_str()).collect();
+        // Only the directory's contents move, not paths that merely share its name
+        assert_eq!(paths, vec!["/shared/songs/a.mp3", "/shared/musical.txt"]);
+    }
+
+    #[tokio::test]
+    async fn test_user_stats_and_leaderboard() {
+        let pool = create_test_db().await;
+        let users = UserDb::new(pool.clone());
+        let stats_db = StatsDb::new(pool);
+        let perms = Permissions::new();
+
+        let alice = users
+            .create_user("alice", "hash", false, false, true, &perms)
+            .await
+            .unwrap();
+        let bob = users
+            .create_user("bob", "hash", false, false, true, &perms)
+            .await
+            .unwrap();
+        let shared = users
+            .create_user("lobby", "hash", false, true, true, &perms)
+            .await
+            .unwrap();
+
+        let counters = |logins, messages| UserStats {
+            logins,
+            messages,
+            ..Default::default()
+        };
+        stats_db
+            .add_user_stats(alice.id, &counters(2, 5))
+            .await
+            .unwrap();
+        stats_db
+            .add_user_stats(alice.id, &counters(1, 0))
+            .await
+            .unwrap();
+        stats_db
+            .add_user_stats(bob.id, &counters(7, 0))
+            .await
+            .unwrap();
+        stats_db
+            .add_user_stats(shared.id, &counters(50, 50))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            stats_db.get_user_stats(alice.id).await.unwrap(),
+            counters(3, 5)
+        );
+
+        let top = stats_db
+            .leaderboard(LeaderboardStat::Logins, 10)
+            .await
+            .unwrap();
+        let names: Vec<&str> = top.iter().map(|r| r.username.as_str()).collect();
+        // Shared accounts are left out
+        assert_eq!(names, vec!["bob", "alice"]);
+
+        // Accounts without messages are left out
+        let top = stats_db
+            .leaderboard(LeaderboardStat::Messages, 10)
+            .await
+            .unwrap();
+        assert_eq!(top.len(), 1);
+        assert_eq!(top[0].username, "alice");
+
+        // Counters for deleted accounts are dropped
+        users.delete_user(bob.id).await.unwrap();
+        stats_db
+            .add_user_stats(bob.id, &counters(1, 0))
+            .await
+            .unwrap();
+        assert_eq!(
+            stats_db.get_user_stats(bob.id).await.unwrap(),
+            UserStats::default()
+        );
+    }
+}
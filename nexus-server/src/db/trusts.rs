@@ -192,6 +192,23 @@ impl TrustDb {
         Ok(ips)
     }
 
+    /// Remove an erased account from all trusts
+    ///
+    /// Clears matching nickname annotations and replaces the creator name with
+    /// `placeholder`. The trusts themselves stay in effect.
+    pub async fn erase_user(&self, username: &str, placeholder: &str) -> Result<(), sqlx::Error> {
+        sqlx::query(sql::SQL_ERASE_TRUST_NICKNAME)
+            .bind(username)
+            .execute(&self.pool)
+            .await?;
+        sqlx::query(sql::SQL_ERASE_TRUST_CREATOR)
+            .bind(placeholder)
+            .bind(username)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
     /// Check if any trusts exist with a given nickname annotation
     pub async fn has_trusts_for_nickname(&self, nickname: &str) -> Result<bool, sqlx::Error> {
         let row: (i64,) = sqlx::query_as(sql::SQL_COUNT_TRUSTS_BY_NICKNAME)
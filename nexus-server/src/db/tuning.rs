@@ -0,0 +1,143 @@
+//! SQLite tuning options
+//!
+//! By default the database runs in write-ahead log (WAL) mode with
+//! `synchronous=NORMAL`: readers don't wait on the writer, and a commit is
+//! appended to the log without waiting for an fsync (the log is synced at
+//! checkpoints). The database can't be corrupted by a crash in this mode, but
+//! a power loss may roll back the last few commits. `--db-synchronous full`
+//! trades write latency for syncing every commit.
+//!
+//! Recent commits sit in the `-wal` file beside the database until the next
+//! checkpoint, so a copy of the database file alone taken while the server
+//! runs can miss them. Back up with the server stopped or with SQLite's
+//! `.backup` command, or use `--db-journal-mode delete` for a rollback journal.
+
+use std::path::{Path, PathBuf};
+
+use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqliteSynchronous};
+
+/// SQLite journal mode (`--db-journal-mode`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum JournalMode {
+    /// Write-ahead log: readers and the writer don't block each other
+    Wal,
+    /// Rollback journal, deleted after each transaction
+    Delete,
+    /// Rollback journal, truncated after each transaction
+    Truncate,
+    /// Rollback journal, kept and zeroed after each transaction
+    Persist,
+}
+
+impl From<JournalMode> for SqliteJournalMode {
+    fn from(mode: JournalMode) -> Self {
+        match mode {
+            JournalMode::Wal => SqliteJournalMode::Wal,
+            JournalMode::Delete => SqliteJournalMode::Delete,
+            JournalMode::Truncate => SqliteJournalMode::Truncate,
+            JournalMode::Persist => SqliteJournalMode::Persist,
+        }
+    }
+}
+
+/// How often SQLite waits for writes to reach the disk (`--db-synchronous`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Synchronous {
+    /// Never sync; a power loss or OS crash can corrupt the database
+    Off,
+    /// Sync at checkpoints (WAL) or at critical moments (rollback journal)
+    Normal,
+    /// Sync every commit
+    Full,
+    /// Sync every commit and the journal's directory
+    Extra,
+}
+
+impl From<Synchronous> for SqliteSynchronous {
+    fn from(level: Synchronous) -> Self {
+        match level {
+            Synchronous::Off => SqliteSynchronous::Off,
+            Synchronous::Normal => SqliteSynchronous::Normal,
+            Synchronous::Full => SqliteSynchronous::Full,
+            Synchronous::Extra => SqliteSynchronous::Extra,
+        }
+    }
+}
+
+/// SQLite settings from the command line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DbTuning {
+    pub journal_mode: JournalMode,
+    pub synchronous: Synchronous,
+}
+
+impl Default for DbTuning {
+    fn default() -> Self {
+        Self {
+            journal_mode: JournalMode::Wal,
+            synchronous: Synchronous::Normal,
+        }
+    }
+}
+
+/// Connection options for a database file, created if missing
+pub fn connect_options(database_path: &Path, tuning: DbTuning) -> SqliteConnectOptions {
+    SqliteConnectOptions::new()
+        .filename(database_path)
+        .create_if_missing(true)
+        .journal_mode(tuning.journal_mode.into())
+        .synchronous(tuning.synchronous.into())
+}
+
+/// The database file and the WAL files SQLite keeps beside it, if present
+#[cfg_attr(not(unix), allow(dead_code))] // Only used to set Unix file permissions
+pub fn database_files(database_path: &Path) -> Vec<PathBuf> {
+    let mut files = vec![database_path.to_path_buf()];
+    for suffix in ["-wal", "-shm"] {
+        let mut name = database_path.as_os_str().to_os_string();
+        name.push(suffix);
+        let path = PathBuf::from(name);
+        if path.exists() {
+            files.push(path);
+        }
+    }
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    #[tokio::test]
+    async fn test_connect_options_apply_tuning() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nexus.db");
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect_with(connect_options(&path, DbTuning::default()))
+            .await
+            .unwrap();
+
+        let (journal_mode,): (String,) = sqlx::query_as("PRAGMA journal_mode")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(journal_mode, "wal");
+        // NORMAL
+        let (synchronous,): (i64,) = sqlx::query_as("PRAGMA synchronous")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(synchronous, 1);
+
+        // The log is in use once something has been written
+        sqlx::query("CREATE TABLE t (x INTEGER)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let files = database_files(&path);
+        assert_eq!(files[0], path);
+        assert!(files.iter().any(|file| file.ends_with("nexus.db-wal")));
+    }
+}
@@ -0,0 +1,223 @@
+//! Upload journal database operations
+//!
+//! Uploads are written to a `.part` file and renamed once their hash is
+//! verified. The journal records each `.part` file while its upload is in
+//! progress, keyed by its path relative to the file root like file activity,
+//! so the server still knows what a leftover file belongs to after a restart.
+
+use chrono::Utc;
+use sqlx::sqlite::SqlitePool;
+
+use crate::db::sql;
+
+/// An upload in progress from the database
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalEntry {
+    /// .part file path relative to the file root (e.g. "/shared/song.mp3.part")
+    pub part_path: String,
+    /// Final file path relative to the file root
+    pub target_path: String,
+    pub username: String,
+    /// Expected SHA-256 of the complete file
+    pub sha256: String,
+    /// Expected size of the complete file
+    pub size: u64,
+    /// Bytes in the .part file when last recorded
+    pub bytes_received: u64,
+    /// Unix timestamp of the last update
+    pub updated_at: i64,
+}
+
+/// Row type for journal queries
+type JournalRow = (String, String, String, String, i64, i64, i64);
+
+impl From<JournalRow> for JournalEntry {
+    fn from(row: JournalRow) -> Self {
+        Self {
+            part_path: row.0,
+            target_path: row.1,
+            username: row.2,
+            sha256: row.3,
+            size: row.4 as u64,
+            bytes_received: row.5 as u64,
+            updated_at: row.6,
+        }
+    }
+}
+
+/// Database access for the upload journal
+#[derive(Clone)]
+pub struct UploadJournalDb {
+    pool: SqlitePool,
+}
+
+impl UploadJournalDb {
+    /// Create a new UploadJournalDb instance
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Record an upload in progress (replaces any earlier entry for the file)
+    pub async fn record(
+        &self,
+        part_path: &str,
+        target_path: &str,
+        username: &str,
+        sha256: &str,
+        size: u64,
+        bytes_received: u64,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(sql::SQL_RECORD_UPLOAD)
+            .bind(part_path)
+            .bind(target_path)
+            .bind(username)
+            .bind(sha256)
+            .bind(size as i64)
+            .bind(bytes_received as i64)
+            .bind(Utc::now().timestamp())
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Update the bytes received for an upload in progress
+    pub async fn set_progress(
+        &self,
+        part_path: &str,
+        bytes_received: u64,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(sql::SQL_UPDATE_UPLOAD_PROGRESS)
+            .bind(bytes_received as i64)
+            .bind(Utc::now().timestamp())
+            .bind(part_path)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Correct the bytes received to what is on disk (not upload activity,
+    /// so the entry doesn't look any more recent)
+    pub async fn correct_bytes(
+        &self,
+        part_path: &str,
+        bytes_received: u64,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(sql::SQL_CORRECT_UPLOAD_BYTES)
+            .bind(bytes_received as i64)
+            .bind(part_path)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Remove an upload from the journal (finished or abandoned)
+    pub async fn remove(&self, part_path: &str) -> Result<(), sqlx::Error> {
+        sqlx::query(sql::SQL_DELETE_UPLOAD)
+            .bind(part_path)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Get the entry for a .part file
+    pub async fn get(&self, part_path: &str) -> Result<Option<JournalEntry>, sqlx::Error> {
+        let row: Option<JournalRow> = sqlx::query_as(sql::SQL_SELECT_UPLOAD)
+            .bind(part_path)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(JournalEntry::from))
+    }
+
+    /// List every upload in the journal
+    pub async fn list(&self) -> Result<Vec<JournalEntry>, sqlx::Error> {
+        let rows: Vec<JournalRow> = sqlx::query_as(sql::SQL_SELECT_UPLOADS)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rows.into_iter().map(JournalEntry::from).collect())
+    }
+
+    /// Remove an erased account from the journal
+    ///
+    /// Replaces the uploader name with `placeholder`. The entries stay so
+    /// their .part files are still cleaned up.
+    pub async fn erase_user(&self, username: &str, placeholder: &str) -> Result<(), sqlx::Error> {
+        sqlx::query(sql::SQL_ERASE_UPLOAD_USERNAME)
+            .bind(placeholder)
+            .bind(username)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::testing::create_test_db;
+
+    #[tokio::test]
+    async fn test_record_progress_and_remove() {
+        let journal = UploadJournalDb::new(create_test_db().await);
+        let part = "/shared/song.mp3.part";
+
+        assert_eq!(journal.get(part).await.unwrap(), None);
+
+        journal
+            .record(part, "/shared/song.mp3", "alice", "abc123", 1000, 0)
+            .await
+            .unwrap();
+        let entry = journal.get(part).await.unwrap().unwrap();
+        assert_eq!(entry.target_path, "/shared/song.mp3");
+        assert_eq!(entry.username, "alice");
+        assert_eq!(entry.sha256, "abc123");
+        assert_eq!(entry.size, 1000);
+        assert_eq!(entry.bytes_received, 0);
+
+        journal.set_progress(part, 400).await.unwrap();
+        assert_eq!(
+            journal.get(part).await.unwrap().unwrap().bytes_received,
+            400
+        );
+
+        let updated_at = journal.get(part).await.unwrap().unwrap().updated_at;
+        journal.correct_bytes(part, 500).await.unwrap();
+        let entry = journal.get(part).await.unwrap().unwrap();
+        assert_eq!(entry.bytes_received, 500);
+        assert_eq!(entry.updated_at, updated_at);
+
+        // Recording again replaces the entry (a new upload of different content)
+        journal
+            .record(part, "/shared/song.mp3", "bob", "def456", 2000, 0)
+            .await
+            .unwrap();
+        let entries = journal.list().await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].username, "bob");
+        assert_eq!(entries[0].size, 2000);
+
+        journal.remove(part).await.unwrap();
+        assert!(journal.list().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_erase_user() {
+        let journal = UploadJournalDb::new(create_test_db().await);
+        journal
+            .record("/a.part", "/a", "alice", "abc123", 1000, 0)
+            .await
+            .unwrap();
+        journal
+            .record("/b.part", "/b", "bob", "def456", 2000, 0)
+            .await
+            .unwrap();
+
+        journal.erase_user("alice", "[erased]").await.unwrap();
+
+        let entries = journal.list().await.unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].username, "[erased]");
+        assert_eq!(entries[1].username, "bob");
+    }
+}
@@ -0,0 +1,234 @@
+//! Upload rule database operations
+//!
+//! Rules are keyed by the directory's path relative to the file root, like
+//! file activity, so file operations that move or delete directories keep
+//! them in sync. A rule covers its directory and everything below it, unless
+//! a subdirectory has a rule of its own.
+
+use nexus_common::protocol::UploadRule;
+use sqlx::sqlite::SqlitePool;
+
+use crate::db::sql;
+
+/// Row type for upload rule queries
+type UploadRuleRow = (String, Option<i64>, Option<i64>);
+
+/// Build a rule from a database row
+fn rule_from_row(row: UploadRuleRow) -> UploadRule {
+    let (extensions, max_file_size, max_total_size) = row;
+    UploadRule {
+        extensions: extensions
+            .split(',')
+            .filter(|extension| !extension.is_empty())
+            .map(str::to_string)
+            .collect(),
+        max_file_size: max_file_size.map(|size| size as u64),
+        max_total_size: max_total_size.map(|size| size as u64),
+    }
+}
+
+/// Database access for upload rules
+#[derive(Clone)]
+pub struct UploadRuleDb {
+    pool: SqlitePool,
+}
+
+impl UploadRuleDb {
+    /// Create a new UploadRuleDb instance
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Get the rule set on a directory itself
+    pub async fn get(&self, path: &str) -> Result<Option<UploadRule>, sqlx::Error> {
+        let row: Option<UploadRuleRow> = sqlx::query_as(sql::SQL_SELECT_UPLOAD_RULE)
+            .bind(path)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(rule_from_row))
+    }
+
+    /// Get the rule that applies to a directory
+    ///
+    /// This is the directory's own rule, or else the rule of its nearest
+    /// ancestor that has one.
+    pub async fn effective(&self, path: &str) -> Result<Option<UploadRule>, sqlx::Error> {
+        let row: Option<UploadRuleRow> = sqlx::query_as(sql::SQL_SELECT_EFFECTIVE_UPLOAD_RULE)
+            .bind(path)
+            .bind(path)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(rule_from_row))
+    }
+
+    /// Set a directory's rule, or remove it with `None` (or an empty rule)
+    pub async fn set(&self, path: &str, rule: Option<&UploadRule>) -> Result<(), sqlx::Error> {
+        match rule.filter(|rule| !rule.is_empty()) {
+            Some(rule) => {
+                sqlx::query(sql::SQL_SET_UPLOAD_RULE)
+                    .bind(path)
+                    .bind(rule.extensions.join(","))
+                    .bind(rule.max_file_size.map(|size| size as i64))
+                    .bind(rule.max_total_size.map(|size| size as i64))
+                    .execute(&self.pool)
+                    .await?;
+            }
+            None => {
+                sqlx::query(sql::SQL_DELETE_UPLOAD_RULE)
+                    .bind(path)
+                    .execute(&self.pool)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Move the rules of a directory and everything in it to a new path
+    ///
+    /// Rules left at the destination by a directory that no longer exists
+    /// there are dropped first.
+    pub async fn move_rules(&self, from: &str, to: &str) -> Result<(), sqlx::Error> {
+        let from_dir = format!("{from}/");
+        let to_dir = format!("{to}/");
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query(sql::SQL_DELETE_UPLOAD_RULES_UNDER)
+            .bind(to)
+            .bind(&to_dir)
+            .bind(&to_dir)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query(sql::SQL_MOVE_UPLOAD_RULES)
+            .bind(to)
+            .bind(from)
+            .bind(from)
+            .bind(&from_dir)
+            .bind(&from_dir)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await
+    }
+
+    /// Get every directory that has a rule
+    pub async fn rule_paths(&self) -> Result<Vec<String>, sqlx::Error> {
+        sqlx::query_scalar(sql::SQL_SELECT_UPLOAD_RULE_PATHS)
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    /// Delete the rules of a directory and everything in it
+    pub async fn delete_rules(&self, path: &str) -> Result<(), sqlx::Error> {
+        let dir = format!("{path}/");
+        sqlx::query(sql::SQL_DELETE_UPLOAD_RULES_UNDER)
+            .bind(path)
+            .bind(&dir)
+            .bind(&dir)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::testing::create_test_db;
+
+    fn rule(extensions: &[&str], max_file_size: Option<u64>) -> UploadRule {
+        UploadRule {
+            extensions: extensions.iter().map(|e| e.to_string()).collect(),
+            max_file_size,
+            max_total_size: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_set_get_and_remove() {
+        let rules = UploadRuleDb::new(create_test_db().await);
+        let music = rule(&["mp3", "flac"], Some(1024));
+
+        assert_eq!(rules.get("/shared/music").await.unwrap(), None);
+
+        rules.set("/shared/music", Some(&music)).await.unwrap();
+        assert_eq!(rules.get("/shared/music").await.unwrap(), Some(music));
+
+        // Setting again replaces the rule
+        let any_size = rule(&[], Some(5));
+        rules.set("/shared/music", Some(&any_size)).await.unwrap();
+        assert_eq!(rules.get("/shared/music").await.unwrap(), Some(any_size));
+
+        // An empty rule removes it
+        rules
+            .set("/shared/music", Some(&UploadRule::default()))
+            .await
+            .unwrap();
+        assert_eq!(rules.get("/shared/music").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_effective_uses_nearest_ancestor() {
+        let rules = UploadRuleDb::new(create_test_db().await);
+        let everywhere = rule(&[], Some(100));
+        let music = rule(&["mp3"], None);
+
+        rules.set("/", Some(&everywhere)).await.unwrap();
+        rules.set("/shared/music", Some(&music)).await.unwrap();
+
+        assert_eq!(
+            rules.effective("/shared/music").await.unwrap(),
+            Some(music.clone())
+        );
+        assert_eq!(
+            rules.effective("/shared/music/live").await.unwrap(),
+            Some(music)
+        );
+        // Only the directory's contents are covered, not paths that merely share its name
+        assert_eq!(
+            rules.effective("/shared/musical").await.unwrap(),
+            Some(everywhere.clone())
+        );
+        assert_eq!(rules.effective("/").await.unwrap(), Some(everywhere));
+
+        rules.set("/", None).await.unwrap();
+        assert_eq!(rules.effective("/shared/musical").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_move_and_delete_rules() {
+        let rules = UploadRuleDb::new(create_test_db().await);
+        let music = rule(&["mp3"], None);
+        let live = rule(&["flac"], None);
+
+        rules.set("/shared/music", Some(&music)).await.unwrap();
+        rules.set("/shared/music/live", Some(&live)).await.unwrap();
+        rules.set("/shared/musical", Some(&music)).await.unwrap();
+        // Stale rule at the destination
+        rules.set("/shared/songs/old", Some(&live)).await.unwrap();
+
+        rules
+            .move_rules("/shared/music", "/shared/songs")
+            .await
+            .unwrap();
+
+        assert_eq!(rules.get("/shared/music").await.unwrap(), None);
+        assert_eq!(
+            rules.get("/shared/songs").await.unwrap(),
+            Some(music.clone())
+        );
+        assert_eq!(rules.get("/shared/songs/live").await.unwrap(), Some(live));
+        assert_eq!(rules.get("/shared/songs/old").await.unwrap(), None);
+        assert_eq!(
+            rules.get("/shared/musical").await.unwrap(),
+            Some(music.clone())
+        );
+
+        rules.delete_rules("/shared/songs").await.unwrap();
+        assert_eq!(rules.get("/shared/songs").await.unwrap(), None);
+        assert_eq!(rules.get("/shared/songs/live").await.unwrap(), None);
+        assert_eq!(rules.get("/shared/musical").await.unwrap(), Some(music));
+    }
+}
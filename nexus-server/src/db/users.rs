@@ -142,6 +142,14 @@ impl UserDb {
         Ok(count > 0)
     }
 
+    /// Count user accounts other than the guest account
+    pub async fn count_non_guest_users(&self) -> Result<i64, sqlx::Error> {
+        let count: (i64,) = sqlx::query_as(SQL_COUNT_NON_GUEST_USERS)
+            .fetch_one(&self.pool)
+            .await?;
+        Ok(count.0)
+    }
+
     /// Get all users from the database (sorted alphabetically by username)
     ///
     /// Used by the `/list all` command for user management.
@@ -169,6 +177,35 @@ impl UserDb {
             .collect())
     }
 
+    /// Get users holding a permission, admins included (sorted by username)
+    pub async fn get_users_with_permission(
+        &self,
+        permission: Permission,
+    ) -> Result<Vec<UserAccount>, sqlx::Error> {
+        let rows: Vec<(i64, String, String, bool, bool, bool, i64)> =
+            sqlx::query_as(SQL_SELECT_USERS_WITH_PERMISSION)
+                .bind(permission.as_str())
+                .fetch_all(&self.pool)
+                .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(
+                |(id, username, hashed_password, is_admin, is_shared, enabled, created_at)| {
+                    UserAccount {
+                        id,
+                        username,
+                        hashed_password,
+                        is_admin,
+                        is_shared,
+                        enabled,
+                        created_at,
+                    }
+                },
+            )
+            .collect())
+    }
+
     // ========================================================================
     // Permission Methods
     // ========================================================================
@@ -0,0 +1,248 @@
+//! Voice clip (soundboard) database operations
+
+use chrono::Utc;
+use sqlx::sqlite::SqlitePool;
+
+use crate::db::sql;
+
+/// A voice clip from the database (without its audio data)
+#[derive(Debug, Clone)]
+pub struct VoiceClipRecord {
+    pub name: String,
+    pub size: i64,
+    pub duration_ms: i64,
+    pub uploader_id: i64,
+    pub uploader_username: String,
+    pub created_at: String,
+}
+
+/// Row type for voice clip queries with uploader join
+type VoiceClipRow = (String, i64, i64, i64, String, String);
+
+impl From<VoiceClipRow> for VoiceClipRecord {
+    fn from(row: VoiceClipRow) -> Self {
+        Self {
+            name: row.0,
+            size: row.1,
+            duration_ms: row.2,
+            uploader_id: row.3,
+            uploader_username: row.4,
+            created_at: row.5,
+        }
+    }
+}
+
+/// Database access for voice clip operations
+#[derive(Clone)]
+pub struct VoiceClipDb {
+    pool: SqlitePool,
+}
+
+impl VoiceClipDb {
+    /// Create a new VoiceClipDb instance
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Get all voice clips ordered by name
+    pub async fn get_all_clips(&self) -> Result<Vec<VoiceClipRecord>, sqlx::Error> {
+        let rows: Vec<VoiceClipRow> = sqlx::query_as(sql::SQL_SELECT_ALL_VOICE_CLIPS)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rows.into_iter().map(VoiceClipRecord::from).collect())
+    }
+
+    /// Get a single voice clip by name (case-insensitive)
+    pub async fn get_clip(&self, name: &str) -> Result<Option<VoiceClipRecord>, sqlx::Error> {
+        let row: Option<VoiceClipRow> = sqlx::query_as(sql::SQL_SELECT_VOICE_CLIP)
+            .bind(name)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(VoiceClipRecord::from))
+    }
+
+    /// Get a voice clip's stored name and audio data URI by name (case-insensitive)
+    pub async fn get_clip_data(&self, name: &str) -> Result<Option<(String, String)>, sqlx::Error> {
+        sqlx::query_as(sql::SQL_SELECT_VOICE_CLIP_DATA)
+            .bind(name)
+            .fetch_optional(&self.pool)
+            .await
+    }
+
+    /// Add a voice clip to the library
+    ///
+    /// Returns false if the library already holds `max_clips` clips.
+    /// Fails with a database error if the name is already taken.
+    pub async fn create_clip(
+        &self,
+        name: &str,
+        data: &str,
+        size: i64,
+        duration_ms: i64,
+        uploader_id: i64,
+        max_clips: i64,
+    ) -> Result<bool, sqlx::Error> {
+        let now = Utc::now().to_rfc3339();
+
+        let result = sqlx::query(sql::SQL_INSERT_VOICE_CLIP)
+            .bind(name)
+            .bind(data)
+            .bind(size)
+            .bind(duration_ms)
+            .bind(uploader_id)
+            .bind(&now)
+            .bind(max_clips)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Delete a voice clip by name (case-insensitive)
+    ///
+    /// Returns true if the clip was deleted, false if it didn't exist.
+    pub async fn delete_clip(&self, name: &str) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(sql::SQL_DELETE_VOICE_CLIP)
+            .bind(name)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Permissions;
+    use crate::db::testing::create_test_db;
+
+    const CLIP_DATA: &str = "data:audio/ogg;base64,T2dnUw==";
+
+    #[tokio::test]
+    async fn test_create_and_list_clips() {
+        let pool = create_test_db().await;
+        let clips_db = VoiceClipDb::new(pool.clone());
+        let users_db = crate::db::UserDb::new(pool.clone());
+
+        let user = users_db
+            .create_user("alice", "hash", false, false, true, &Permissions::new())
+            .await
+            .unwrap();
+
+        assert!(
+            clips_db
+                .create_clip("rimshot", CLIP_DATA, 4, 1500, user.id, 10)
+                .await
+                .unwrap()
+        );
+        assert!(
+            clips_db
+                .create_clip("Airhorn", CLIP_DATA, 4, 2000, user.id, 10)
+                .await
+                .unwrap()
+        );
+
+        let clips = clips_db.get_all_clips().await.unwrap();
+        assert_eq!(clips.len(), 2);
+        // Ordered by name, case-insensitive
+        assert_eq!(clips[0].name, "Airhorn");
+        assert_eq!(clips[0].duration_ms, 2000);
+        assert_eq!(clips[0].uploader_username, "alice");
+        assert_eq!(clips[1].name, "rimshot");
+
+        // Lookups are case-insensitive and return the stored name
+        let clip = clips_db.get_clip("AIRHORN").await.unwrap().unwrap();
+        assert_eq!(clip.name, "Airhorn");
+        assert_eq!(clip.uploader_id, user.id);
+        let (name, data) = clips_db.get_clip_data("airhorn").await.unwrap().unwrap();
+        assert_eq!(name, "Airhorn");
+        assert_eq!(data, CLIP_DATA);
+        assert!(clips_db.get_clip("missing").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_name_rejected() {
+        let pool = create_test_db().await;
+        let clips_db = VoiceClipDb::new(pool.clone());
+        let users_db = crate::db::UserDb::new(pool.clone());
+
+        let user = users_db
+            .create_user("alice", "hash", false, false, true, &Permissions::new())
+            .await
+            .unwrap();
+
+        clips_db
+            .create_clip("airhorn", CLIP_DATA, 4, 1000, user.id, 10)
+            .await
+            .unwrap();
+        assert!(
+            clips_db
+                .create_clip("AirHorn", CLIP_DATA, 4, 1000, user.id, 10)
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_library_limit() {
+        let pool = create_test_db().await;
+        let clips_db = VoiceClipDb::new(pool.clone());
+        let users_db = crate::db::UserDb::new(pool.clone());
+
+        let user = users_db
+            .create_user("alice", "hash", false, false, true, &Permissions::new())
+            .await
+            .unwrap();
+
+        assert!(
+            clips_db
+                .create_clip("one", CLIP_DATA, 4, 1000, user.id, 2)
+                .await
+                .unwrap()
+        );
+        assert!(
+            clips_db
+                .create_clip("two", CLIP_DATA, 4, 1000, user.id, 2)
+                .await
+                .unwrap()
+        );
+        assert!(
+            !clips_db
+                .create_clip("three", CLIP_DATA, 4, 1000, user.id, 2)
+                .await
+                .unwrap()
+        );
+        assert_eq!(clips_db.get_all_clips().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_delete_clip_and_cascade() {
+        let pool = create_test_db().await;
+        let clips_db = VoiceClipDb::new(pool.clone());
+        let users_db = crate::db::UserDb::new(pool.clone());
+
+        let user = users_db
+            .create_user("alice", "hash", false, false, true, &Permissions::new())
+            .await
+            .unwrap();
+
+        clips_db
+            .create_clip("airhorn", CLIP_DATA, 4, 1000, user.id, 10)
+            .await
+            .unwrap();
+        clips_db
+            .create_clip("rimshot", CLIP_DATA, 4, 1000, user.id, 10)
+            .await
+            .unwrap();
+
+        assert!(clips_db.delete_clip("AIRHORN").await.unwrap());
+        assert!(!clips_db.delete_clip("airhorn").await.unwrap());
+
+        // Deleting the uploader removes their clips
+        users_db.delete_user(user.id).await.unwrap();
+        assert!(clips_db.get_all_clips().await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,336 @@
+//! Door programs
+//!
+//! Doors are external programs users run in a terminal from the client, like
+//! the door games of classic BBSes (or any modern TUI app). Each `--door`
+//! gives a name and the command to run:
+//!
+//! ```text
+//! --door "LORD=/opt/doors/lord/start.sh" --door "NetHack=/usr/games/nethack -u guest"
+//! ```
+//!
+//! The command is split on whitespace (use a wrapper script for anything
+//! fancier) and runs on its own pseudo-terminal, in the program's directory,
+//! with `TERM=xterm-256color` plus `NEXUS_USERNAME`, `NEXUS_NICKNAME` and
+//! `NEXUS_DOOR` set so doors can keep per-user state. Apart from `PATH`,
+//! `HOME` and `LANG` nothing is inherited from the server's environment, so
+//! secrets passed to the server that way don't reach door programs. Output is streamed to
+//! the client in `DoorData` messages and keyboard input comes back the same way.
+
+use std::io::{self, Read, Write};
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::mpsc as std_mpsc;
+use std::thread;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use portable_pty::{ChildKiller, CommandBuilder, PtySize, native_pty_system};
+use tokio::sync::{mpsc, oneshot};
+use tokio::task::AbortHandle;
+
+use nexus_common::MAX_DOOR_DATA_SIZE;
+use nexus_common::protocol::ServerMessage;
+use nexus_common::validators::{DoorNameError, validate_door_name};
+
+use crate::outbound::OutboundSender;
+
+/// Largest door terminal width
+pub const MAX_DOOR_COLUMNS: u16 = 300;
+
+/// Largest door terminal height
+pub const MAX_DOOR_ROWS: u16 = 100;
+
+/// Output chunks queued between the terminal reader and the connection
+const DOOR_OUTPUT_QUEUE: usize = 64;
+
+/// Terminal type advertised to door programs
+const DOOR_TERM: &str = "xterm-256color";
+
+/// Server environment variables door programs inherit
+///
+/// Windows programs fail to start without `SystemRoot`; it's never set elsewhere.
+const DOOR_INHERITED_ENV: &[&str] = &["PATH", "HOME", "LANG", "SystemRoot"];
+
+/// A door and the command that runs it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Door {
+    /// Name shown to users
+    pub name: String,
+    /// Program to run
+    pub program: String,
+    /// Arguments passed to the program
+    pub args: Vec<String>,
+}
+
+impl FromStr for Door {
+    type Err = String;
+
+    /// Parse `NAME=COMMAND [ARGS...]`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, command) = s
+            .split_once('=')
+            .ok_or_else(|| format!("expected NAME=COMMAND, got '{}'", s.trim()))?;
+        let name = name.trim();
+
+        validate_door_name(name).map_err(|e| match e {
+            DoorNameError::Empty => "door name cannot be empty".to_string(),
+            DoorNameError::TooLong => format!("door name '{name}' is too long"),
+            DoorNameError::InvalidCharacters => {
+                format!("door name '{name}' contains invalid characters")
+            }
+        })?;
+
+        let mut words = command.split_whitespace().map(str::to_string);
+        let program = words
+            .next()
+            .ok_or_else(|| format!("door '{name}' has no command"))?;
+
+        Ok(Door {
+            name: name.to_string(),
+            program,
+            args: words.collect(),
+        })
+    }
+}
+
+/// Doors from `--door`, in command line order
+///
+/// Cheap to clone; clones share the list.
+#[derive(Debug, Clone, Default)]
+pub struct Doors {
+    doors: Arc<Vec<Door>>,
+}
+
+impl Doors {
+    /// The doors users can run
+    pub fn new(doors: Vec<Door>) -> Self {
+        Self {
+            doors: Arc::new(doors),
+        }
+    }
+
+    /// Names of the doors, in command line order
+    pub fn names(&self) -> Vec<String> {
+        self.doors.iter().map(|door| door.name.clone()).collect()
+    }
+
+    /// Find a door by name
+    pub fn find(&self, name: &str) -> Option<&Door> {
+        self.doors.iter().find(|door| door.name == name)
+    }
+}
+
+/// Who is running a door (passed to the program in its environment)
+pub struct DoorUser<'a> {
+    /// Account username
+    pub username: &'a str,
+    /// Display name (differs from the username for shared accounts)
+    pub nickname: &'a str,
+}
+
+/// A running door attached to one connection
+///
+/// Dropping the session kills the program. Output is forwarded to the
+/// connection as `DoorData` until the program exits, followed by `DoorClosed`.
+pub struct DoorSession {
+    /// Door name (for logging)
+    pub name: String,
+    /// Keyboard input for the writer thread
+    input: std_mpsc::Sender<Vec<u8>>,
+    /// Kills the program when the session is dropped
+    killer: Box<dyn ChildKiller + Send + Sync>,
+    /// Task forwarding output to the connection
+    output: AbortHandle,
+}
+
+impl DoorSession {
+    /// Start a door on a new pseudo-terminal of the given size
+    ///
+    /// Blocking terminal reads, writes and the wait for the program run on
+    /// their own threads; a task forwards output through `tx`.
+    pub fn start(
+        door: &Door,
+        cols: u16,
+        rows: u16,
+        user: DoorUser<'_>,
+        tx: &OutboundSender,
+    ) -> io::Result<Self> {
+        let pair = native_pty_system()
+            .openpty(PtySize {
+                rows,
+                cols,
+                pixel_width: 0,
+                pixel_height: 0,
+            })
+            .map_err(io::Error::other)?;
+
+        let mut command = CommandBuilder::new(&door.program);
+        command.args(&door.args);
+        command.env_clear();
+        for name in DOOR_INHERITED_ENV {
+            if let Some(value) = std::env::var_os(name) {
+                command.env(name, value);
+            }
+        }
+        if let Some(dir) = Path::new(&door.program).parent()
+            && !dir.as_os_str().is_empty()
+        {
+            command.cwd(dir);
+        }
+        command.env("TERM", DOOR_TERM);
+        command.env("COLUMNS", cols.to_string());
+        command.env("LINES", rows.to_string());
+        command.env("NEXUS_USERNAME", user.username);
+        command.env("NEXUS_NICKNAME", user.nickname);
+        command.env("NEXUS_DOOR", &door.name);
+
+        let mut child = pair
+            .slave
+            .spawn_command(command)
+            .map_err(io::Error::other)?;
+        drop(pair.slave);
+
+        let killer = child.clone_killer();
+        let mut reader = pair.master.try_clone_reader().map_err(io::Error::other)?;
+        let mut writer = pair.master.take_writer().map_err(io::Error::other)?;
+        let master = pair.master;
+
+        // Writes block while the program isn't reading input
+        let (input, input_rx) = std_mpsc::channel::<Vec<u8>>();
+        thread::spawn(move || {
+            for data in input_rx {
+                if writer
+                    .write_all(&data)
+                    .and_then(|_| writer.flush())
+                    .is_err()
+                {
+                    break;
+                }
+            }
+        });
+
+        let (output_tx, mut output_rx) = mpsc::channel::<Vec<u8>>(DOOR_OUTPUT_QUEUE);
+        thread::spawn(move || {
+            let mut buf = vec![0u8; MAX_DOOR_DATA_SIZE];
+            loop {
+                match reader.read(&mut buf) {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => {
+                        if output_tx.blocking_send(buf[..n].to_vec()).is_err() {
+                            break;
+                        }
+                    }
+                }
+            }
+        });
+
+        // Closing the terminal once the program exits ends the reader on
+        // every platform (Windows keeps it open otherwise)
+        let (exit_tx, exit_rx) = oneshot::channel();
+        thread::spawn(move || {
+            let status = child.wait();
+            drop(master);
+            let _ = exit_tx.send(status.ok().map(|status| status.exit_code()));
+        });
+
+        let tx = tx.clone();
+        let task = tokio::spawn(async move {
+            while let Some(chunk) = output_rx.recv().await {
+                let data = BASE64.encode(&chunk);
+                if tx
+                    .send_wait((ServerMessage::DoorData { data }, None))
+                    .await
+                    .is_err()
+                {
+                    return;
+                }
+            }
+            let exit_code = exit_rx.await.ok().flatten();
+            let _ = tx.send((ServerMessage::DoorClosed { exit_code }, None));
+        });
+
+        Ok(Self {
+            name: door.name.clone(),
+            input,
+            killer,
+            output: task.abort_handle(),
+        })
+    }
+
+    /// Queue keyboard input for the program
+    pub fn write(&self, data: Vec<u8>) {
+        let _ = self.input.send(data);
+    }
+}
+
+impl Drop for DoorSession {
+    fn drop(&mut self) {
+        self.output.abort();
+        let _ = self.killer.kill();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::outbound;
+
+    #[test]
+    fn test_parse_door() {
+        let door: Door = "NetHack=/usr/games/nethack  -u guest".parse().unwrap();
+        assert_eq!(door.name, "NetHack");
+        assert_eq!(door.program, "/usr/games/nethack");
+        assert_eq!(door.args, vec!["-u", "guest"]);
+
+        let door: Door = " Trade Wars = ./tw2002".parse().unwrap();
+        assert_eq!(door.name, "Trade Wars");
+        assert_eq!(door.program, "./tw2002");
+        assert!(door.args.is_empty());
+    }
+
+    #[test]
+    fn test_parse_door_errors() {
+        assert!("lord".parse::<Door>().is_err());
+        assert!("=/opt/lord".parse::<Door>().is_err());
+        assert!("lord=".parse::<Door>().is_err());
+        assert!("lord=   ".parse::<Door>().is_err());
+        assert!(
+            format!("{}=/opt/lord", "a".repeat(33))
+                .parse::<Door>()
+                .is_err()
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_door_session_streams_output_then_closes() {
+        let door: Door = "echo=/bin/echo hello door".parse().unwrap();
+        let (tx, mut rx) = outbound::channel(Default::default());
+        let user = DoorUser {
+            username: "alice",
+            nickname: "alice",
+        };
+
+        let _session = DoorSession::start(&door, 80, 24, user, &tx).unwrap();
+
+        let mut output = Vec::new();
+        let exit_code = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            while let Some((message, _)) = rx.recv().await {
+                match message {
+                    ServerMessage::DoorData { data } => {
+                        output.extend(BASE64.decode(data).unwrap());
+                    }
+                    ServerMessage::DoorClosed { exit_code } => return exit_code,
+                    other => panic!("Unexpected message: {:?}", other),
+                }
+            }
+            None
+        })
+        .await
+        .expect("door should exit");
+
+        assert!(String::from_utf8_lossy(&output).contains("hello door"));
+        assert_eq!(exit_code, Some(0));
+    }
+}
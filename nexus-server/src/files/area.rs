@@ -5,6 +5,9 @@
 
 use std::path::{Path, PathBuf};
 
+use walkdir::WalkDir;
+
+use super::folder_type::{FolderType, parse_folder_type};
 use crate::constants::{FILES_SHARED_DIR, FILES_USERS_DIR};
 
 /// Resolve the file area root for a specific user.
@@ -54,6 +57,47 @@ pub fn resolve_user_area(root: &Path, username: &str) -> PathBuf {
     }
 }
 
+/// Find the folders that belong to a user, for account erasure.
+///
+/// Returns the user's personal folder (if it exists) and every user drop box
+/// addressed to them (`[NEXUS-DB-username]`, case-insensitive) anywhere in
+/// the file area. Folders inside a returned folder are not listed separately.
+#[must_use]
+pub fn find_user_folders(root: &Path, username: &str) -> Vec<PathBuf> {
+    let mut folders = Vec::new();
+
+    let user_dir = root.join(FILES_USERS_DIR).join(username);
+    if user_dir.is_dir() {
+        folders.push(user_dir.clone());
+    }
+
+    let username = username.to_lowercase();
+    let mut walker = WalkDir::new(root).min_depth(1).into_iter();
+    while let Some(entry) = walker.next() {
+        let Ok(entry) = entry else {
+            continue;
+        };
+        if !entry.file_type().is_dir() {
+            continue;
+        }
+        if entry.path() == user_dir {
+            walker.skip_current_dir();
+            continue;
+        }
+        let Some(name) = entry.file_name().to_str() else {
+            continue;
+        };
+        if let FolderType::UserDropBox(owner) = parse_folder_type(name)
+            && owner.to_lowercase() == username
+        {
+            folders.push(entry.path().to_path_buf());
+            walker.skip_current_dir();
+        }
+    }
+
+    folders
+}
+
 #[cfg(test)]
 mod tests {
     use std::fs;
@@ -143,4 +187,27 @@ mod tests {
 
         assert_eq!(area, unicode_dir);
     }
+
+    #[test]
+    fn test_find_user_folders() {
+        let temp = setup_test_root();
+        let root = temp.path();
+
+        let alice_dir = root.join(FILES_USERS_DIR).join("alice");
+        let dropbox = root
+            .join(FILES_SHARED_DIR)
+            .join("For Alice [NEXUS-DB-ALICE]");
+        let other = root.join(FILES_SHARED_DIR).join("For Bob [NEXUS-DB-bob]");
+        fs::create_dir_all(alice_dir.join("Inbox [NEXUS-DB-alice]")).unwrap();
+        fs::create_dir_all(&dropbox).unwrap();
+        fs::create_dir_all(&other).unwrap();
+
+        let mut folders = find_user_folders(root, "alice");
+        folders.sort();
+
+        let mut expected = vec![dropbox, alice_dir];
+        expected.sort();
+        assert_eq!(folders, expected);
+        assert!(find_user_folders(root, "carol").is_empty());
+    }
 }
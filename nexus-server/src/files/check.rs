@@ -0,0 +1,331 @@
+//! File area consistency check
+//!
+//! The server keeps three views of the file area that drift apart when files
+//! change behind its back, such as through the shell or a restored backup:
+//!
+//! - the disk itself
+//! - the search index (see [`FileIndex`])
+//! - what the database keeps by path: download and upload history, ratings
+//!   and comments, and upload rules
+//!
+//! A check compares them and, when asked, repairs what it can: index issues
+//! start a rebuild and metadata for paths that are gone is deleted. Files on
+//! disk are never touched.
+
+use std::collections::{HashMap, HashSet};
+use std::io::ErrorKind;
+use std::path::Path;
+use std::sync::Arc;
+
+use nexus_common::protocol::{FileCheckIssueKind, FileCheckSummary, FileSearchResult};
+
+use crate::constants::FILE_CHECK_MAX_ISSUES;
+use crate::db::Database;
+use crate::files::FileIndex;
+
+/// A discrepancy found by a check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileCheckIssue {
+    pub kind: FileCheckIssueKind,
+    /// Path relative to the file root (e.g. "/shared/song.mp3")
+    pub path: String,
+    pub repaired: bool,
+}
+
+/// Result of a check
+#[derive(Debug, Default)]
+pub struct FileCheckReport {
+    pub summary: FileCheckSummary,
+    /// The first [`FILE_CHECK_MAX_ISSUES`] discrepancies, in the order found
+    pub issues: Vec<FileCheckIssue>,
+}
+
+impl FileCheckReport {
+    /// Count a discrepancy, keeping it if there's room
+    fn add(&mut self, kind: FileCheckIssueKind, path: &str, repaired: bool) {
+        self.summary.issues += 1;
+        if repaired {
+            self.summary.repaired += 1;
+        }
+        if self.issues.len() < FILE_CHECK_MAX_ISSUES {
+            self.issues.push(FileCheckIssue {
+                kind,
+                path: path.to_string(),
+                repaired,
+            });
+        }
+    }
+}
+
+/// Compare the disk with the index, returning index issues by path
+fn compare_index(
+    disk: &[FileSearchResult],
+    indexed: Vec<FileSearchResult>,
+) -> Vec<(FileCheckIssueKind, String)> {
+    let mut indexed: HashMap<String, FileSearchResult> = indexed
+        .into_iter()
+        .map(|entry| (entry.path.clone(), entry))
+        .collect();
+
+    let mut issues = Vec::new();
+    for entry in disk {
+        match indexed.remove(&entry.path) {
+            None => issues.push((FileCheckIssueKind::NotIndexed, entry.path.clone())),
+            Some(old) => {
+                // A folder's modified time changes with its contents, so only
+                // its type is compared
+                let outdated = old.is_directory != entry.is_directory
+                    || (!entry.is_directory
+                        && (old.size != entry.size || old.modified != entry.modified));
+                if outdated {
+                    issues.push((FileCheckIssueKind::IndexOutdated, entry.path.clone()));
+                }
+            }
+        }
+    }
+
+    let mut missing: Vec<String> = indexed.into_keys().collect();
+    missing.sort();
+    issues.extend(
+        missing
+            .into_iter()
+            .map(|path| (FileCheckIssueKind::IndexedButMissing, path)),
+    );
+    issues
+}
+
+/// Whether a path recorded in the database is gone from the file area
+///
+/// Anything but a clear "not found" counts as present, so a folder the server
+/// can't read never loses its metadata.
+async fn is_gone(file_root: &Path, path: &str, on_disk: &HashSet<&str>) -> bool {
+    if path == "/" || on_disk.contains(path) {
+        return false;
+    }
+    let full_path = file_root.join(path.trim_start_matches('/'));
+    matches!(tokio::fs::metadata(&full_path).await, Err(e) if e.kind() == ErrorKind::NotFound)
+}
+
+/// Check the disk, the search index and the metadata against each other,
+/// repairing what can be repaired if `repair` is set
+///
+/// The index is only compared when it exists and no rebuild is due or
+/// running, since until then it's expected to lag behind the disk.
+pub async fn check_file_area(
+    db: &Database,
+    file_index: &Arc<FileIndex>,
+    file_root: &Path,
+    repair: bool,
+) -> Result<FileCheckReport, sqlx::Error> {
+    let mut report = FileCheckReport::default();
+
+    let index = Arc::clone(file_index);
+    let (disk, indexed) = tokio::task::spawn_blocking(move || {
+        let indexed = if index.is_dirty() {
+            None
+        } else {
+            index.entries()
+        };
+        (index.disk_entries(), indexed)
+    })
+    .await
+    .unwrap_or_default();
+    report.summary.entries = disk.len() as u32;
+
+    // Search index
+    if let Some(indexed) = indexed {
+        report.summary.index_checked = true;
+        let issues = compare_index(&disk, indexed);
+        let rebuilding = repair && !issues.is_empty() && file_index.trigger_reindex();
+        for (kind, path) in issues {
+            report.add(kind, &path, rebuilding);
+        }
+    }
+
+    let on_disk: HashSet<&str> = disk.iter().map(|entry| entry.path.as_str()).collect();
+
+    // Download and upload history
+    for path in db.stats.file_activity_paths().await? {
+        if is_gone(file_root, &path, &on_disk).await {
+            if repair {
+                db.stats.delete_file_activity(&path).await?;
+            }
+            report.add(FileCheckIssueKind::OrphanedActivity, &path, repair);
+        }
+    }
+
+    // Ratings and comments
+    for path in db.file_reviews.reviewed_paths().await? {
+        if is_gone(file_root, &path, &on_disk).await {
+            if repair {
+                db.file_reviews.delete_reviews(&path).await?;
+            }
+            report.add(FileCheckIssueKind::OrphanedReviews, &path, repair);
+        }
+    }
+
+    // Upload rules
+    for path in db.upload_rules.rule_paths().await? {
+        if is_gone(file_root, &path, &on_disk).await {
+            if repair {
+                db.upload_rules.delete_rules(&path).await?;
+            }
+            report.add(FileCheckIssueKind::OrphanedUploadRule, &path, repair);
+        }
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::testing::create_test_db;
+    use crate::db::{Permissions, UserDb};
+    use nexus_common::protocol::UploadRule;
+    use tempfile::TempDir;
+
+    fn entry(path: &str, size: u64, modified: i64, is_directory: bool) -> FileSearchResult {
+        FileSearchResult {
+            path: path.to_string(),
+            name: path.rsplit('/').next().unwrap().to_string(),
+            size,
+            modified,
+            is_directory,
+            rating: None,
+        }
+    }
+
+    #[test]
+    fn test_compare_index() {
+        let disk = vec![
+            entry("/shared", 0, 200, true),
+            entry("/shared/same.txt", 4, 100, false),
+            entry("/shared/grown.txt", 8, 100, false),
+            entry("/shared/new.txt", 1, 100, false),
+        ];
+        let indexed = vec![
+            // A folder's modified time is allowed to differ
+            entry("/shared", 0, 100, true),
+            entry("/shared/same.txt", 4, 100, false),
+            entry("/shared/grown.txt", 4, 100, false),
+            entry("/shared/gone.txt", 1, 100, false),
+        ];
+
+        assert_eq!(
+            compare_index(&disk, indexed),
+            vec![
+                (
+                    FileCheckIssueKind::IndexOutdated,
+                    "/shared/grown.txt".to_string()
+                ),
+                (
+                    FileCheckIssueKind::NotIndexed,
+                    "/shared/new.txt".to_string()
+                ),
+                (
+                    FileCheckIssueKind::IndexedButMissing,
+                    "/shared/gone.txt".to_string()
+                ),
+            ]
+        );
+    }
+
+    /// A file area with one file, and metadata for it and for paths that
+    /// are gone
+    async fn setup() -> (Database, Arc<FileIndex>, TempDir, TempDir) {
+        let pool = create_test_db().await;
+        let db = Database::new(pool.clone());
+        let data_dir = TempDir::new().unwrap();
+        let file_root = TempDir::new().unwrap();
+        std::fs::create_dir(file_root.path().join("shared")).unwrap();
+        std::fs::write(file_root.path().join("shared/song.mp3"), b"song").unwrap();
+
+        let user_id = UserDb::new(pool)
+            .create_user("alice", "hash", false, false, true, &Permissions::new())
+            .await
+            .unwrap()
+            .id;
+
+        for path in ["/shared/song.mp3", "/shared/gone.mp3"] {
+            db.stats.add_file_activity(path, 1, None).await.unwrap();
+            db.file_reviews
+                .set_rating(path, user_id, Some(5))
+                .await
+                .unwrap();
+        }
+        let rule = UploadRule {
+            extensions: vec!["mp3".to_string()],
+            max_file_size: None,
+            max_total_size: None,
+        };
+        for path in ["/shared", "/shared/Gone [NEXUS-UL]"] {
+            db.upload_rules.set(path, Some(&rule)).await.unwrap();
+        }
+
+        let file_index = Arc::new(FileIndex::new(data_dir.path(), file_root.path()));
+        (db, file_index, data_dir, file_root)
+    }
+
+    #[tokio::test]
+    async fn test_check_reports_orphaned_metadata() {
+        let (db, file_index, _data_dir, file_root) = setup().await;
+
+        let report = check_file_area(&db, &file_index, file_root.path(), false)
+            .await
+            .unwrap();
+        assert_eq!(report.summary.entries, 2);
+        // No index has been built yet
+        assert!(!report.summary.index_checked);
+        assert_eq!(report.summary.issues, 3);
+        assert_eq!(report.summary.repaired, 0);
+        assert_eq!(
+            report.issues,
+            vec![
+                FileCheckIssue {
+                    kind: FileCheckIssueKind::OrphanedActivity,
+                    path: "/shared/gone.mp3".to_string(),
+                    repaired: false,
+                },
+                FileCheckIssue {
+                    kind: FileCheckIssueKind::OrphanedReviews,
+                    path: "/shared/gone.mp3".to_string(),
+                    repaired: false,
+                },
+                FileCheckIssue {
+                    kind: FileCheckIssueKind::OrphanedUploadRule,
+                    path: "/shared/Gone [NEXUS-UL]".to_string(),
+                    repaired: false,
+                },
+            ]
+        );
+        assert_eq!(db.stats.file_activity_paths().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_check_repairs_orphaned_metadata() {
+        let (db, file_index, _data_dir, file_root) = setup().await;
+
+        let report = check_file_area(&db, &file_index, file_root.path(), true)
+            .await
+            .unwrap();
+        assert_eq!(report.summary.issues, 3);
+        assert_eq!(report.summary.repaired, 3);
+
+        assert_eq!(
+            db.stats.file_activity_paths().await.unwrap(),
+            vec!["/shared/song.mp3"]
+        );
+        assert_eq!(
+            db.file_reviews.reviewed_paths().await.unwrap(),
+            vec!["/shared/song.mp3"]
+        );
+        assert_eq!(db.upload_rules.rule_paths().await.unwrap(), vec!["/shared"]);
+        assert!(file_root.path().join("shared/song.mp3").exists());
+
+        let report = check_file_area(&db, &file_index, file_root.path(), false)
+            .await
+            .unwrap();
+        assert_eq!(report.summary.issues, 0);
+    }
+}
@@ -18,6 +18,12 @@
 //!
 //! The index state (`dirty`, `reindexing`) uses `AtomicBool` for lock-free access.
 //! Only one reindex can run at a time - concurrent requests are ignored.
+//!
+//! ## Change Notifications
+//!
+//! Changes reported with `notify_change` or `notify_rename` mark the index
+//! dirty and are broadcast to `subscribe_changes` receivers (see
+//! [`watch`](super::watch)).
 
 use std::fs::{self, File};
 use std::io::BufReader;
@@ -30,11 +36,14 @@ use csv::{ReaderBuilder, WriterBuilder};
 use grep_regex::RegexMatcher;
 use grep_searcher::Searcher;
 use grep_searcher::sinks::UTF8;
+use tokio::sync::broadcast;
 use walkdir::WalkDir;
 
-use nexus_common::protocol::FileSearchResult;
+use nexus_common::protocol::{FileChangeKind, FileSearchResult};
 use nexus_common::validators::extract_search_terms;
 
+use super::watch::{FILE_CHANGE_CAPACITY, FileChange};
+
 /// Maximum number of search results to return
 pub const MAX_SEARCH_RESULTS: usize = 100;
 
@@ -56,6 +65,8 @@ pub struct FileIndex {
     dirty: AtomicBool,
     /// Whether a reindex is currently in progress
     reindexing: AtomicBool,
+    /// Changes to directory entries, for sessions watching the directory
+    changes: broadcast::Sender<FileChange>,
 }
 
 impl FileIndex {
@@ -70,6 +81,7 @@ impl FileIndex {
             file_root: file_root.to_path_buf(),
             dirty: AtomicBool::new(false),
             reindexing: AtomicBool::new(false),
+            changes: broadcast::channel(FILE_CHANGE_CAPACITY).0,
         }
     }
 
@@ -80,6 +92,32 @@ impl FileIndex {
         self.dirty.store(true, Ordering::SeqCst);
     }
 
+    /// Report that the entry at `path` was added or removed
+    ///
+    /// Marks the index dirty and tells watchers of the entry's directory.
+    pub fn notify_change(&self, path: &Path, kind: FileChangeKind) {
+        self.mark_dirty();
+        if let Some(change) = FileChange::new(path, kind) {
+            // No receivers just means nobody is watching
+            let _ = self.changes.send(change);
+        }
+    }
+
+    /// Report that the entry at `from` was renamed to `to` (same directory)
+    ///
+    /// Marks the index dirty and tells watchers of the entry's directory.
+    pub fn notify_rename(&self, from: &Path, to: &Path) {
+        self.mark_dirty();
+        if let Some(change) = FileChange::renamed(from, to) {
+            let _ = self.changes.send(change);
+        }
+    }
+
+    /// Receive the changes reported from now on
+    pub fn subscribe_changes(&self) -> broadcast::Receiver<FileChange> {
+        self.changes.subscribe()
+    }
+
     /// Check if the index is dirty
     pub fn is_dirty(&self) -> bool {
         self.dirty.load(Ordering::SeqCst)
@@ -95,6 +133,51 @@ impl FileIndex {
         self.index_path.exists()
     }
 
+    /// Size of a temp file left by a rebuild that never finished, if any
+    ///
+    /// Deletes the file when `delete` is set. A rebuild writing the file right
+    /// now isn't a leftover, so this returns `None` while one is running.
+    pub fn leftover_temp_file(&self, delete: bool) -> Option<u64> {
+        // Hold the reindexing flag so a rebuild can't start while we look
+        if self.reindexing.swap(true, Ordering::SeqCst) {
+            return None;
+        }
+
+        let size = fs::metadata(&self.temp_path).ok().map(|m| m.len());
+        if size.is_some()
+            && delete
+            && let Err(e) = fs::remove_file(&self.temp_path)
+        {
+            log_error!("Failed to delete leftover temp index: {}", e);
+        }
+
+        self.reindexing.store(false, Ordering::SeqCst);
+        size
+    }
+
+    /// Count index entries whose file or folder no longer exists
+    ///
+    /// Reads the whole index and checks every path, so it's meant for
+    /// maintenance scans rather than requests.
+    pub fn missing_entries(&self) -> usize {
+        let Ok(file) = File::open(&self.index_path) else {
+            return 0;
+        };
+        let mut reader = ReaderBuilder::new()
+            .has_headers(false)
+            .from_reader(BufReader::new(file));
+
+        reader
+            .records()
+            .filter_map(|record| record.ok())
+            .filter(|record| {
+                record
+                    .get(0)
+                    .is_some_and(|path| !self.file_root.join(path.trim_start_matches('/')).exists())
+            })
+            .count()
+    }
+
     /// Trigger a reindex if not already running
     ///
     /// Returns `true` if reindex was started, `false` if one is already running.
@@ -116,10 +199,10 @@ impl FileIndex {
         tokio::task::spawn_blocking(move || {
             match index.build_index() {
                 Ok(count) => {
-                    eprintln!("File index rebuilt: {} entries", count);
+                    log_info!("File index rebuilt: {} entries", count);
                 }
                 Err(e) => {
-                    eprintln!("Failed to build file index: {}", e);
+                    log_error!("Failed to build file index: {}", e);
                     // Mark dirty again so we retry
                     index.mark_dirty();
                 }
@@ -154,56 +237,16 @@ impl FileIndex {
 
         let mut count = 0;
 
-        // Walk the file area
-        for entry in WalkDir::new(&self.file_root)
-            .min_depth(1) // Skip the root itself
-            .follow_links(true) // Follow symlinks (admin-trusted)
-            .into_iter()
-            .filter_map(|e| e.ok())
-        {
-            let path = entry.path();
-
-            // Get metadata (follows symlinks)
-            let metadata = match fs::metadata(path) {
-                Ok(m) => m,
-                Err(_) => continue, // Skip files we can't stat
-            };
-
-            // Get size (0 for directories)
-            let size = if metadata.is_file() {
-                metadata.len()
-            } else {
-                0
-            };
-
-            // Get modified time as Unix timestamp
-            let modified = metadata
-                .modified()
-                .unwrap_or(SystemTime::UNIX_EPOCH)
-                .duration_since(SystemTime::UNIX_EPOCH)
-                .map(|d| d.as_secs() as i64)
-                .unwrap_or(0);
-
-            // Get path relative to file_root
-            let relative_path = match path.strip_prefix(&self.file_root) {
-                Ok(p) => p,
-                Err(_) => continue,
-            };
-
-            // Convert to forward slashes and add leading /
-            let path_str = format!("/{}", relative_path.to_string_lossy().replace('\\', "/"));
-
-            // Get filename
-            let name = entry.file_name().to_string_lossy().into_owned();
-            let size_str = size.to_string();
-            let modified_str = modified.to_string();
-            let is_dir_str = if metadata.is_dir() { "1" } else { "0" };
+        for entry in self.walk() {
+            let size_str = entry.size.to_string();
+            let modified_str = entry.modified.to_string();
+            let is_dir_str = if entry.is_directory { "1" } else { "0" };
 
             // Write CSV record: path, name, size, modified, is_directory
             writer
                 .write_record([
-                    path_str.as_str(),
-                    name.as_str(),
+                    entry.path.as_str(),
+                    entry.name.as_str(),
                     size_str.as_str(),
                     modified_str.as_str(),
                     is_dir_str,
@@ -226,6 +269,84 @@ impl FileIndex {
         Ok(count)
     }
 
+    /// Walk the file area, yielding what the index records for each entry
+    fn walk(&self) -> impl Iterator<Item = FileSearchResult> + '_ {
+        WalkDir::new(&self.file_root)
+            .min_depth(1) // Skip the root itself
+            .follow_links(true) // Follow symlinks (admin-trusted)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter_map(|entry| {
+                let path = entry.path();
+
+                // Get metadata (follows symlinks), skipping files we can't stat
+                let metadata = fs::metadata(path).ok()?;
+
+                // Get size (0 for directories)
+                let size = if metadata.is_file() {
+                    metadata.len()
+                } else {
+                    0
+                };
+
+                // Get modified time as Unix timestamp
+                let modified = metadata
+                    .modified()
+                    .unwrap_or(SystemTime::UNIX_EPOCH)
+                    .duration_since(SystemTime::UNIX_EPOCH)
+                    .map(|d| d.as_secs() as i64)
+                    .unwrap_or(0);
+
+                // Get path relative to file_root
+                let relative_path = path.strip_prefix(&self.file_root).ok()?;
+
+                Some(FileSearchResult {
+                    // Convert to forward slashes and add leading /
+                    path: format!("/{}", relative_path.to_string_lossy().replace('\\', "/")),
+                    name: entry.file_name().to_string_lossy().into_owned(),
+                    size,
+                    modified,
+                    is_directory: metadata.is_dir(),
+                    rating: None,
+                })
+            })
+    }
+
+    /// Every file and folder on disk, as a rebuild would index them (blocking)
+    pub fn disk_entries(&self) -> Vec<FileSearchResult> {
+        self.walk().collect()
+    }
+
+    /// Every entry in the index (blocking)
+    ///
+    /// Returns `None` if there's no index yet or a rebuild is replacing it.
+    pub fn entries(&self) -> Option<Vec<FileSearchResult>> {
+        if self.is_reindexing() {
+            return None;
+        }
+        let file = File::open(&self.index_path).ok()?;
+        let mut reader = ReaderBuilder::new()
+            .has_headers(false)
+            .from_reader(BufReader::new(file));
+
+        Some(
+            reader
+                .records()
+                .filter_map(|record| record.ok())
+                .filter_map(|record| {
+                    Some(FileSearchResult {
+                        path: record.get(0)?.to_string(),
+                        name: record.get(1)?.to_string(),
+                        size: record.get(2)?.parse().ok()?,
+                        modified: record.get(3)?.parse().ok()?,
+                        is_directory: record.get(4)? == "1",
+                        rating: None,
+                    })
+                })
+                .collect(),
+        )
+    }
+
     /// Search the index for matching files
     ///
     /// Returns up to `MAX_SEARCH_RESULTS` matching entries.
@@ -237,6 +358,18 @@ impl FileIndex {
         &self,
         query: &str,
         area_prefix: Option<&str>,
+    ) -> Result<Vec<FileSearchResult>, String> {
+        self.search_until(query, area_prefix, || false)
+    }
+
+    /// Search the index, giving up early once `stop` returns true
+    ///
+    /// Returns the results found so far when stopped (see [`Self::search`]).
+    pub fn search_until(
+        &self,
+        query: &str,
+        area_prefix: Option<&str>,
+        stop: impl Fn() -> bool,
     ) -> Result<Vec<FileSearchResult>, String> {
         // If index doesn't exist, return empty results
         if !self.index_path.exists() {
@@ -268,8 +401,8 @@ impl FileIndex {
             &matcher,
             &self.index_path,
             UTF8(|_line_num, line| {
-                // Stop if we have enough results
-                if results.len() >= MAX_SEARCH_RESULTS {
+                // Stop if we have enough results or were asked to
+                if results.len() >= MAX_SEARCH_RESULTS || stop() {
                     return Ok(false);
                 }
 
@@ -299,12 +432,12 @@ impl FileIndex {
 
         if let Err(e) = search_result {
             // Index may be corrupted - delete it and mark dirty for rebuild
-            eprintln!(
+            log_error!(
                 "Search failed (index may be corrupted): {}. Deleting index for rebuild.",
                 e
             );
             if let Err(del_err) = fs::remove_file(&self.index_path) {
-                eprintln!("Failed to delete corrupted index: {}", del_err);
+                log_error!("Failed to delete corrupted index: {}", del_err);
             }
             self.mark_dirty();
             return Ok(vec![]);
@@ -335,6 +468,7 @@ fn parse_csv_line(line: &str) -> Option<FileSearchResult> {
             size,
             modified,
             is_directory,
+            rating: None,
         })
     } else {
         None
@@ -412,6 +546,21 @@ mod tests {
         assert!(index.is_dirty());
     }
 
+    #[test]
+    fn test_file_index_notify_change() {
+        let temp_dir = TempDir::new().unwrap();
+        let index = FileIndex::new(temp_dir.path(), temp_dir.path());
+        let mut changes = index.subscribe_changes();
+
+        index.notify_change(&temp_dir.path().join("song.mp3"), FileChangeKind::Added);
+        assert!(index.is_dirty());
+
+        let change = changes.try_recv().unwrap();
+        assert_eq!(change.dir, temp_dir.path().canonicalize().unwrap());
+        assert_eq!(change.kind, FileChangeKind::Added);
+        assert_eq!(change.name, "song.mp3");
+    }
+
     #[test]
     fn test_build_index_empty_dir() {
         let temp_dir = TempDir::new().unwrap();
@@ -450,6 +599,69 @@ mod tests {
         assert!(index.exists());
     }
 
+    #[test]
+    fn test_missing_entries() {
+        let temp_dir = TempDir::new().unwrap();
+        let data_dir = temp_dir.path().join("data");
+        let file_root = temp_dir.path().join("files");
+
+        fs::create_dir_all(&data_dir).unwrap();
+        fs::create_dir_all(file_root.join("shared")).unwrap();
+        fs::write(file_root.join("shared/kept.txt"), "kept").unwrap();
+        fs::write(file_root.join("shared/gone.txt"), "gone").unwrap();
+
+        let index = FileIndex::new(&data_dir, &file_root);
+        index.build_index().unwrap();
+        assert_eq!(index.missing_entries(), 0);
+
+        fs::remove_file(file_root.join("shared/gone.txt")).unwrap();
+        assert_eq!(index.missing_entries(), 1);
+    }
+
+    #[test]
+    fn test_entries_match_disk_after_build() {
+        let temp_dir = TempDir::new().unwrap();
+        let data_dir = temp_dir.path().join("data");
+        let file_root = temp_dir.path().join("files");
+
+        fs::create_dir_all(&data_dir).unwrap();
+        fs::create_dir_all(file_root.join("shared")).unwrap();
+        fs::write(file_root.join("shared/a, b.txt"), "12345").unwrap();
+
+        let index = FileIndex::new(&data_dir, &file_root);
+        assert!(index.entries().is_none());
+
+        index.build_index().unwrap();
+        let mut disk = index.disk_entries();
+        let mut indexed = index.entries().unwrap();
+        disk.sort_by(|a, b| a.path.cmp(&b.path));
+        indexed.sort_by(|a, b| a.path.cmp(&b.path));
+        assert_eq!(disk, indexed);
+        assert_eq!(indexed[1].path, "/shared/a, b.txt");
+        assert_eq!(indexed[1].size, 5);
+    }
+
+    #[test]
+    fn test_leftover_temp_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let index = FileIndex::new(temp_dir.path(), temp_dir.path());
+        assert_eq!(index.leftover_temp_file(true), None);
+
+        let temp_path = temp_dir.path().join(INDEX_TEMP_FILE_NAME);
+        fs::write(&temp_path, "partial").unwrap();
+
+        // Left alone while a rebuild could be writing it
+        index.reindexing.store(true, Ordering::SeqCst);
+        assert_eq!(index.leftover_temp_file(true), None);
+        index.reindexing.store(false, Ordering::SeqCst);
+
+        assert_eq!(index.leftover_temp_file(false), Some(7));
+        assert!(temp_path.exists());
+        assert_eq!(index.leftover_temp_file(true), Some(7));
+        assert!(!temp_path.exists());
+        assert!(!index.is_reindexing());
+    }
+
     #[test]
     fn test_build_index_with_special_chars() {
         let temp_dir = TempDir::new().unwrap();
@@ -583,6 +795,25 @@ mod tests {
         assert_eq!(results.len(), MAX_SEARCH_RESULTS);
     }
 
+    #[test]
+    fn test_search_until_stops_early() {
+        let temp_dir = TempDir::new().unwrap();
+        let data_dir = temp_dir.path().join("data");
+        let file_root = temp_dir.path().join("files");
+
+        fs::create_dir_all(&data_dir).unwrap();
+        fs::create_dir_all(file_root.join("shared")).unwrap();
+        for i in 0..10 {
+            fs::write(file_root.join(format!("shared/file{}.txt", i)), "content").unwrap();
+        }
+
+        let index = FileIndex::new(&data_dir, &file_root);
+        index.build_index().unwrap();
+
+        let results = index.search_until("file", None, || true).unwrap();
+        assert!(results.is_empty());
+    }
+
     #[test]
     fn test_search_literal_special_chars() {
         let temp_dir = TempDir::new().unwrap();
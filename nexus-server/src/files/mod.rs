@@ -18,12 +18,14 @@ use crate::constants::{
 };
 
 pub mod area;
+pub mod check;
 pub mod folder_type;
 pub mod index;
 pub mod operations;
 pub mod path;
+pub mod watch;
 
-pub use area::resolve_user_area;
+pub use area::{find_user_folders, resolve_user_area};
 pub use folder_type::{FolderType, parse_folder_type};
 pub use index::{FileIndex, MAX_SEARCH_RESULTS};
 pub use operations::{
@@ -34,6 +36,7 @@ pub use path::{
     FileError, allows_upload, build_and_validate_candidate_path, build_candidate_path,
     normalize_client_path, resolve_new_path, resolve_path, validate_and_build_candidate_path,
 };
+pub use watch::{FileChange, WatchedDirectory};
 
 /// Get the default file root path for the platform
 ///
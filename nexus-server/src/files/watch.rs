@@ -0,0 +1,131 @@
+//! Change notifications for watched directories
+//!
+//! Handlers that add, remove or rename entries in the file area report it to
+//! the [`FileIndex`](super::FileIndex), which marks the index dirty and
+//! broadcasts a [`FileChange`]. A server task hands each change to the
+//! sessions watching the directory it happened in (see `FileWatch`).
+//!
+//! Directories are compared by canonical path, so a change reaches every
+//! watcher of the directory whether they browse the file root or their own
+//! area.
+
+use std::path::{Path, PathBuf};
+
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use nexus_common::protocol::FileChangeKind;
+
+use crate::users::UserManager;
+
+/// Number of changes buffered for the notification task before the oldest
+/// are dropped
+pub const FILE_CHANGE_CAPACITY: usize = 1024;
+
+/// A change to an entry of a directory in the file area
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileChange {
+    /// Directory the entry is in (canonical)
+    pub dir: PathBuf,
+    pub kind: FileChangeKind,
+    /// Name of the entry (the old name for a rename)
+    pub name: String,
+    /// New name of a renamed entry
+    pub new_name: Option<String>,
+}
+
+impl FileChange {
+    /// A change to the entry at `path`
+    ///
+    /// Returns None if the path has no name or its directory no longer exists.
+    pub fn new(path: &Path, kind: FileChangeKind) -> Option<Self> {
+        let (dir, name) = split_entry(path)?;
+        Some(Self {
+            dir,
+            kind,
+            name,
+            new_name: None,
+        })
+    }
+
+    /// A rename from `from` to `to` within the same directory
+    ///
+    /// Returns None if either path has no name or the directory no longer exists.
+    pub fn renamed(from: &Path, to: &Path) -> Option<Self> {
+        let (dir, name) = split_entry(from)?;
+        let new_name = to.file_name()?.to_str()?.to_string();
+        Some(Self {
+            dir,
+            kind: FileChangeKind::Renamed,
+            name,
+            new_name: Some(new_name),
+        })
+    }
+}
+
+/// A directory a session watches
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WatchedDirectory {
+    /// Canonical path of the directory
+    pub dir: PathBuf,
+    /// Path as the client gave it, echoed back in `FileChanged`
+    pub path: String,
+    /// Whether the path is relative to the file root
+    pub root: bool,
+}
+
+/// Hand reported changes to the sessions watching their directory (runs forever)
+///
+/// Changes dropped because this task fell behind are skipped; watchers catch
+/// up with the next change they get.
+pub async fn run_notifier(mut changes: broadcast::Receiver<FileChange>, user_manager: UserManager) {
+    loop {
+        match changes.recv().await {
+            Ok(change) => user_manager.notify_file_change(&change).await,
+            Err(RecvError::Lagged(_)) => continue,
+            Err(RecvError::Closed) => break,
+        }
+    }
+    std::future::pending::<()>().await;
+}
+
+/// Split a path into its canonical directory and entry name
+fn split_entry(path: &Path) -> Option<(PathBuf, String)> {
+    let name = path.file_name()?.to_str()?.to_string();
+    let dir = path.parent()?.canonicalize().ok()?;
+    Some((dir, name))
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn test_change_uses_canonical_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path().join("Podcasts");
+        std::fs::create_dir(&dir).unwrap();
+
+        let change = FileChange::new(
+            &dir.join("..").join("Podcasts").join("episode.mp3"),
+            FileChangeKind::Added,
+        )
+        .unwrap();
+        assert_eq!(change.dir, dir.canonicalize().unwrap());
+        assert_eq!(change.name, "episode.mp3");
+        assert!(change.new_name.is_none());
+
+        let change = FileChange::renamed(&dir.join("draft.mp3"), &dir.join("episode.mp3")).unwrap();
+        assert_eq!(change.kind, FileChangeKind::Renamed);
+        assert_eq!(change.name, "draft.mp3");
+        assert_eq!(change.new_name.as_deref(), Some("episode.mp3"));
+    }
+
+    #[test]
+    fn test_change_in_missing_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("gone").join("episode.mp3");
+        assert!(FileChange::new(&path, FileChangeKind::Removed).is_none());
+    }
+}
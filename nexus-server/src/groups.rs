@@ -0,0 +1,318 @@
+//! Group conversations (multi-user direct messages)
+//!
+//! A group is a quick conversation between a few online users, without the
+//! ceremony of a channel. Its members are nicknames, routed like user
+//! messages: every session of a member's nickname gets the group's messages.
+//!
+//! - Members are added when the group starts or when a member invites them,
+//!   and leave on request or when the last session of their nickname
+//!   disconnects. Every change sends the new member list to all members.
+//! - A group ends when its last member leaves.
+//! - A nickname can have at most [`MAX_GROUPS_PER_USER`] groups it started
+//!   going at once, even after leaving them.
+//!
+//! Groups only live in memory, so a server restart ends them all.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use nexus_common::protocol::ServerMessage;
+use nexus_common::validators::{MAX_GROUP_MEMBERS, MAX_GROUPS_PER_USER};
+
+use crate::users::UserManager;
+
+/// Why a group couldn't be started
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CreateGroupError {
+    /// The creator already started [`MAX_GROUPS_PER_USER`] groups that are going
+    TooMany,
+}
+
+/// Why a member couldn't be added to a group
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AddMemberError {
+    /// The group doesn't exist, or the inviter isn't in it
+    NotFound,
+    /// The nickname is already a member
+    AlreadyMember,
+    /// The group has [`MAX_GROUP_MEMBERS`] members
+    Full,
+}
+
+/// One group conversation
+struct Group {
+    /// Lowercased nickname of the member who started it
+    creator: String,
+    /// Nicknames in join order
+    members: Vec<String>,
+}
+
+/// Groups by ID, and the ID for the next one
+struct GroupTable {
+    next_id: u64,
+    groups: HashMap<u64, Group>,
+}
+
+impl Default for GroupTable {
+    fn default() -> Self {
+        Self {
+            next_id: 1,
+            groups: HashMap::new(),
+        }
+    }
+}
+
+/// Whether a member list contains a nickname (case-insensitive)
+fn contains(members: &[String], nickname: &str) -> bool {
+    let nickname = nickname.to_lowercase();
+    members
+        .iter()
+        .any(|member| member.to_lowercase() == nickname)
+}
+
+/// Group conversations going on the server
+///
+/// Cheap to clone; clones share the same groups.
+#[derive(Clone, Default)]
+pub struct GroupManager {
+    table: Arc<Mutex<GroupTable>>,
+}
+
+impl GroupManager {
+    /// Create a manager with no groups
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Lock the group table
+    fn lock(&self) -> MutexGuard<'_, GroupTable> {
+        self.table.lock().expect("group lock poisoned")
+    }
+
+    /// Start a group with these members, returning its ID
+    ///
+    /// The first member is the creator. The caller has checked the member
+    /// count and removed repeats.
+    pub fn create(&self, members: Vec<String>) -> Result<u64, CreateGroupError> {
+        let creator = members
+            .first()
+            .map(|nickname| nickname.to_lowercase())
+            .unwrap_or_default();
+        let mut table = self.lock();
+        let started = table
+            .groups
+            .values()
+            .filter(|group| group.creator == creator)
+            .count();
+        if started >= MAX_GROUPS_PER_USER {
+            return Err(CreateGroupError::TooMany);
+        }
+
+        let group_id = table.next_id;
+        table.next_id += 1;
+        table.groups.insert(group_id, Group { creator, members });
+        Ok(group_id)
+    }
+
+    /// A group's members, if `nickname` is one of them
+    pub fn members_for(&self, group_id: u64, nickname: &str) -> Option<Vec<String>> {
+        self.lock()
+            .groups
+            .get(&group_id)
+            .filter(|group| contains(&group.members, nickname))
+            .map(|group| group.members.clone())
+    }
+
+    /// Add `nickname` to a group that `inviter` is in, returning the new members
+    pub fn add_member(
+        &self,
+        group_id: u64,
+        inviter: &str,
+        nickname: &str,
+    ) -> Result<Vec<String>, AddMemberError> {
+        let mut table = self.lock();
+        let members = table
+            .groups
+            .get_mut(&group_id)
+            .map(|group| &mut group.members)
+            .filter(|members| contains(members, inviter))
+            .ok_or(AddMemberError::NotFound)?;
+        if contains(members, nickname) {
+            return Err(AddMemberError::AlreadyMember);
+        }
+        if members.len() >= MAX_GROUP_MEMBERS {
+            return Err(AddMemberError::Full);
+        }
+        members.push(nickname.to_string());
+        Ok(members.clone())
+    }
+
+    /// Remove `nickname` from a group, returning the members left
+    ///
+    /// Returns None if the group doesn't exist or `nickname` isn't in it. The
+    /// group ends when no members are left.
+    pub fn remove_member(&self, group_id: u64, nickname: &str) -> Option<Vec<String>> {
+        let mut table = self.lock();
+        let members = &mut table.groups.get_mut(&group_id)?.members;
+        let nickname = nickname.to_lowercase();
+        let index = members
+            .iter()
+            .position(|member| member.to_lowercase() == nickname)?;
+        members.remove(index);
+        let remaining = members.clone();
+        if remaining.is_empty() {
+            table.groups.remove(&group_id);
+        }
+        Some(remaining)
+    }
+
+    /// Remove `nickname` from every group, returning each group left with its
+    /// remaining members (groups that ended are not included)
+    fn remove_from_all(&self, nickname: &str) -> Vec<(u64, Vec<String>)> {
+        let nickname = nickname.to_lowercase();
+        let mut left = Vec::new();
+        self.lock().groups.retain(|&group_id, group| {
+            let before = group.members.len();
+            group
+                .members
+                .retain(|member| member.to_lowercase() != nickname);
+            if group.members.len() < before && !group.members.is_empty() {
+                left.push((group_id, group.members.clone()));
+            }
+            !group.members.is_empty()
+        });
+        left
+    }
+
+    /// Take a nickname out of all its groups after its last session ended,
+    /// telling the other members
+    pub async fn leave_all(&self, nickname: &str, user_manager: &UserManager) {
+        for (group_id, members) in self.remove_from_all(nickname) {
+            send_members(group_id, &members, user_manager).await;
+        }
+    }
+}
+
+/// Send a group's member list to every member
+pub async fn send_members(group_id: u64, members: &[String], user_manager: &UserManager) {
+    let update = ServerMessage::GroupMembers {
+        group_id,
+        members: members.to_vec(),
+    };
+    for member in members {
+        user_manager.broadcast_to_nickname(member, &update).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nicknames(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_membership() {
+        let groups = GroupManager::new();
+        let group_id = groups.create(nicknames(&["alice", "bob"])).unwrap();
+
+        assert!(groups.members_for(group_id, "Alice").is_some());
+        assert!(groups.members_for(group_id, "carol").is_none());
+
+        // Only members can invite, and only once per nickname
+        assert_eq!(
+            groups.add_member(group_id, "carol", "dave"),
+            Err(AddMemberError::NotFound)
+        );
+        assert_eq!(
+            groups.add_member(group_id, "alice", "BOB"),
+            Err(AddMemberError::AlreadyMember)
+        );
+        assert_eq!(
+            groups.add_member(group_id, "alice", "carol"),
+            Ok(nicknames(&["alice", "bob", "carol"]))
+        );
+
+        assert_eq!(
+            groups.remove_member(group_id, "bob"),
+            Some(nicknames(&["alice", "carol"]))
+        );
+        assert_eq!(groups.remove_member(group_id, "bob"), None);
+    }
+
+    #[test]
+    fn test_group_is_full() {
+        let groups = GroupManager::new();
+        let members: Vec<String> = (0..MAX_GROUP_MEMBERS).map(|i| format!("user{i}")).collect();
+        let group_id = groups.create(members).unwrap();
+
+        assert_eq!(
+            groups.add_member(group_id, "user0", "late"),
+            Err(AddMemberError::Full)
+        );
+    }
+
+    #[test]
+    fn test_group_ends_when_empty() {
+        let groups = GroupManager::new();
+        let group_id = groups.create(nicknames(&["alice", "bob"])).unwrap();
+
+        assert_eq!(
+            groups.remove_member(group_id, "alice"),
+            Some(nicknames(&["bob"]))
+        );
+        assert_eq!(groups.remove_member(group_id, "bob"), Some(Vec::new()));
+        assert!(groups.members_for(group_id, "bob").is_none());
+    }
+
+    #[test]
+    fn test_remove_from_all() {
+        let groups = GroupManager::new();
+        let first = groups.create(nicknames(&["gone", "stays"])).unwrap();
+        let second = groups.create(nicknames(&["gone"])).unwrap();
+
+        let left = groups.remove_from_all("GONE");
+
+        assert!(left.contains(&(first, nicknames(&["stays"]))));
+        assert!(!left.iter().any(|(group_id, _)| *group_id == second));
+        assert!(groups.members_for(second, "gone").is_none());
+    }
+
+    #[test]
+    fn test_groups_per_user_limit() {
+        let groups = GroupManager::new();
+        let mut started = Vec::new();
+        for _ in 0..MAX_GROUPS_PER_USER {
+            started.push(groups.create(nicknames(&["alice", "bob"])).unwrap());
+        }
+        assert_eq!(
+            groups.create(nicknames(&["Alice", "carol"])),
+            Err(CreateGroupError::TooMany)
+        );
+
+        // Other members can still start their own
+        assert!(groups.create(nicknames(&["bob", "alice"])).is_ok());
+
+        // Leaving doesn't free a slot while the group goes on
+        groups.remove_member(started[0], "alice");
+        assert_eq!(
+            groups.create(nicknames(&["alice", "carol"])),
+            Err(CreateGroupError::TooMany)
+        );
+
+        // A group that ended does
+        groups.remove_member(started[0], "bob");
+        assert!(groups.create(nicknames(&["alice", "carol"])).is_ok());
+    }
+
+    #[test]
+    fn test_managers_are_separate() {
+        let first = GroupManager::new();
+        let second = GroupManager::new();
+        let group_id = first.create(nicknames(&["alice", "bob"])).unwrap();
+
+        assert!(second.members_for(group_id, "alice").is_none());
+        assert!(first.clone().members_for(group_id, "alice").is_some());
+    }
+}
@@ -0,0 +1,157 @@
+//! Guest access
+//!
+//! The built-in `guest` account lets people in without a password once an
+//! admin enables it. Guests share one account, so the server keeps them on a
+//! tight leash:
+//!
+//! - Their permissions are limited to [`GUEST_ACCOUNT_PERMISSIONS`], whatever
+//!   the account has been granted.
+//! - Guests who don't pick a nickname get one like `Guest4821`.
+//! - Only `--max-guests-per-ip` guests may be logged in from one address.
+//!
+//! With `--guest-upgrade-role`, a guest can register a personal account from
+//! their session. The new account gets the permissions of the role account,
+//! like accounts created through single sign-on.
+//!
+//! [`GUEST_ACCOUNT_PERMISSIONS`]: nexus_common::GUEST_ACCOUNT_PERMISSIONS
+
+use std::net::IpAddr;
+
+use rand::RngExt;
+
+use crate::constants::{DEFAULT_MAX_GUESTS_PER_IP, GUEST_NICKNAME_ATTEMPTS, GUEST_NICKNAME_PREFIX};
+use crate::db::sql::GUEST_USERNAME;
+use crate::db::users::UserAccount;
+use crate::db::{Database, Permissions};
+use crate::users::UserManager;
+
+/// Guest settings from the command line
+#[derive(Debug, Clone)]
+pub struct GuestConfig {
+    /// Maximum guest sessions per IP address (0 means unlimited)
+    pub max_per_ip: u32,
+    /// Account whose permissions registered guests get (registration is off if unset)
+    pub upgrade_role: Option<String>,
+}
+
+impl Default for GuestConfig {
+    fn default() -> Self {
+        Self {
+            max_per_ip: DEFAULT_MAX_GUESTS_PER_IP,
+            upgrade_role: None,
+        }
+    }
+}
+
+/// Whether a username is the guest account
+pub fn is_guest(username: &str) -> bool {
+    username.eq_ignore_ascii_case(GUEST_USERNAME)
+}
+
+/// Number of guest sessions logged in from an address
+pub async fn sessions_from(user_manager: &UserManager, ip: IpAddr) -> usize {
+    user_manager
+        .get_sessions_by_username(GUEST_USERNAME)
+        .await
+        .iter()
+        .filter(|session| session.address.ip() == ip)
+        .count()
+}
+
+/// Pick a free nickname for a guest who didn't choose one
+///
+/// Returns `None` if every attempt hit a nickname in use, an account name or
+/// a reserved nickname.
+pub async fn pick_nickname(
+    db: &Database,
+    user_manager: &UserManager,
+) -> Result<Option<String>, sqlx::Error> {
+    for _ in 0..GUEST_NICKNAME_ATTEMPTS {
+        let nickname = random_nickname();
+        if !user_manager.is_nickname_in_use(&nickname).await
+            && !db.users.username_exists(&nickname).await?
+            && !db.nicknames.is_registered(&nickname).await?
+        {
+            return Ok(Some(nickname));
+        }
+    }
+    Ok(None)
+}
+
+/// A nickname like `Guest4821`
+fn random_nickname() -> String {
+    let number: u32 = rand::rng().random_range(1000..10000);
+    format!("{GUEST_NICKNAME_PREFIX}{number}")
+}
+
+/// Create the personal account a guest registered
+///
+/// The account is a regular, enabled, non-admin account with the role
+/// account's permissions (none if the role account doesn't exist).
+pub async fn create_account(
+    db: &Database,
+    username: &str,
+    hashed_password: &str,
+    role: &str,
+) -> Result<UserAccount, sqlx::Error> {
+    let permissions = match db.users.get_user_by_username(role).await? {
+        Some(role) => db.users.get_user_permissions(role.id).await?,
+        None => {
+            log_warn!("Guest registration role account '{}' does not exist", role);
+            Permissions::new()
+        }
+    };
+
+    db.users
+        .create_user(username, hashed_password, false, false, true, &permissions)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Permission;
+    use crate::db::testing::create_test_db;
+
+    #[test]
+    fn test_is_guest() {
+        assert!(is_guest("guest"));
+        assert!(is_guest("Guest"));
+        assert!(!is_guest("guests"));
+        assert!(!is_guest(""));
+    }
+
+    #[test]
+    fn test_random_nickname() {
+        let nickname = random_nickname();
+        assert!(nickname.starts_with(GUEST_NICKNAME_PREFIX));
+        assert_eq!(nickname.len(), GUEST_NICKNAME_PREFIX.len() + 4);
+        assert!(nexus_common::validators::validate_nickname(&nickname).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_create_account_copies_role_permissions() {
+        let db = Database::new(create_test_db().await);
+        let mut role_permissions = Permissions::new();
+        role_permissions.add(Permission::ChatSend);
+        role_permissions.add(Permission::FileUpload);
+        db.users
+            .create_user("member", "hash", false, false, false, &role_permissions)
+            .await
+            .unwrap();
+
+        let account = create_account(&db, "alice", "hash", "member")
+            .await
+            .unwrap();
+        assert!(!account.is_admin);
+        assert!(!account.is_shared);
+        assert!(account.enabled);
+        let permissions = db.users.get_user_permissions(account.id).await.unwrap();
+        assert_eq!(permissions.permissions, role_permissions.permissions);
+
+        // A missing role account grants nothing
+        let account = create_account(&db, "bob", "hash", "nobody").await.unwrap();
+        let permissions = db.users.get_user_permissions(account.id).await.unwrap();
+        assert!(permissions.permissions.is_empty());
+    }
+}
@@ -18,6 +18,7 @@ use super::{
 };
 use crate::db::Permission;
 use crate::ip_rule_cache::parse_ip_or_cidr;
+use crate::login_tokens::LoginTokens;
 use crate::users::UserManager;
 use crate::users::manager::DisconnectedSession;
 
@@ -39,7 +40,7 @@ where
 {
     // Verify authentication first
     let Some(session_id) = session_id else {
-        eprintln!("BanCreate request from {} without login", ctx.peer_addr);
+        log_warn!("BanCreate request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("BanCreate"))
             .await;
@@ -104,9 +105,10 @@ where
 
     // Check ban_create permission
     if !requesting_user.has_permission(Permission::BanCreate) {
-        eprintln!(
+        log_warn!(
             "BanCreate from {} (user: {}) without permission",
-            ctx.peer_addr, requesting_user.username
+            ctx.peer_addr,
+            requesting_user.username
         );
         let response = ServerMessage::BanCreateResponse {
             success: false,
@@ -145,9 +147,10 @@ where
                 return ctx.send_message(&response).await;
             }
             Err(TargetResolutionError::IsAdmin) => {
-                eprintln!(
+                log_warn!(
                     "BanCreate from {} (user: {}) attempted to ban admin by nickname",
-                    ctx.peer_addr, requesting_user.username
+                    ctx.peer_addr,
+                    requesting_user.username
                 );
                 let response = ServerMessage::BanCreateResponse {
                     success: false,
@@ -175,9 +178,11 @@ where
         if let Some(net) = parse_ip_or_cidr(&targets_to_ban[0])
             && ctx.user_manager.is_admin_connected_in_range(&net).await
         {
-            eprintln!(
+            log_warn!(
                 "BanCreate from {} (user: {}) attempted to ban CIDR {} with admin connected",
-                ctx.peer_addr, requesting_user.username, targets_to_ban[0]
+                ctx.peer_addr,
+                requesting_user.username,
+                targets_to_ban[0]
             );
             let response = ServerMessage::BanCreateResponse {
                 success: false,
@@ -191,9 +196,11 @@ where
         // For single IPs, check each one
         for ip in &targets_to_ban {
             if ctx.user_manager.is_admin_connected_from_ip(ip).await {
-                eprintln!(
+                log_warn!(
                     "BanCreate from {} (user: {}) attempted to ban IP {} with admin connected",
-                    ctx.peer_addr, requesting_user.username, ip
+                    ctx.peer_addr,
+                    requesting_user.username,
+                    ip
                 );
                 let response = ServerMessage::BanCreateResponse {
                     success: false,
@@ -248,7 +255,7 @@ where
                 banned_targets.push(target_str.clone());
             }
             Err(e) => {
-                eprintln!("BanCreate database error for {}: {}", target_str, e);
+                log_error!("BanCreate database error for {}: {}", target_str, e);
                 let response = ServerMessage::BanCreateResponse {
                     success: false,
                     error: Some(err_database(ctx.locale)),
@@ -329,7 +336,7 @@ where
                 )
                 .await;
 
-            broadcast_disconnections(ctx.user_manager, disconnected).await;
+            broadcast_disconnections(ctx.user_manager, ctx.login_tokens, disconnected).await;
 
             // Also disconnect active file transfers from IPs in the CIDR range
             ctx.transfer_registry.disconnect_matching(|ip| {
@@ -360,7 +367,7 @@ where
                 )
                 .await;
 
-            broadcast_disconnections(ctx.user_manager, disconnected).await;
+            broadcast_disconnections(ctx.user_manager, ctx.login_tokens, disconnected).await;
         }
 
         // Also disconnect active file transfers from the banned IPs
@@ -386,12 +393,16 @@ where
     ctx.send_message(&response).await
 }
 
-/// Broadcast UserDisconnected for each removed session
+/// Revoke login tokens and broadcast UserDisconnected for each removed session
 async fn broadcast_disconnections(
     user_manager: &UserManager,
+    login_tokens: &LoginTokens,
     disconnected: Vec<DisconnectedSession>,
 ) {
     for session in disconnected {
+        // Banned sessions can't log back in with their login tokens
+        login_tokens.revoke_session(session.session_id);
+
         user_manager
             .broadcast_user_event(
                 ServerMessage::UserDisconnected {
@@ -484,6 +495,7 @@ fn build_ban_disconnect_message(locale: &str, expires_at: Option<i64>) -> Server
     ServerMessage::Error {
         message,
         command: Some("BanCreate".to_string()),
+        error_kind: None,
     }
 }
 
@@ -31,7 +31,7 @@ where
 {
     // Verify authentication first
     let Some(session_id) = session_id else {
-        eprintln!("BanDelete request from {} without login", ctx.peer_addr);
+        log_warn!("BanDelete request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("BanDelete"))
             .await;
@@ -64,9 +64,10 @@ where
 
     // Check ban_delete permission
     if !requesting_user.has_permission(Permission::BanDelete) {
-        eprintln!(
+        log_warn!(
             "BanDelete from {} (user: {}) without permission",
-            ctx.peer_addr, requesting_user.username
+            ctx.peer_addr,
+            requesting_user.username
         );
         let response = ServerMessage::BanDeleteResponse {
             success: false,
@@ -107,7 +108,7 @@ where
                 return ctx.send_message(&response).await;
             }
             Err(e) => {
-                eprintln!("BanDelete database error for nickname {}: {}", target, e);
+                log_error!("BanDelete database error for nickname {}: {}", target, e);
                 let response = ServerMessage::BanDeleteResponse {
                     success: false,
                     error: Some(super::err_database(ctx.locale)),
@@ -143,7 +144,7 @@ where
                     all_deleted.extend(deleted);
                 }
                 Err(e) => {
-                    eprintln!("BanDelete database error for CIDR {}: {}", target, e);
+                    log_error!("BanDelete database error for CIDR {}: {}", target, e);
                     let response = ServerMessage::BanDeleteResponse {
                         success: false,
                         error: Some(super::err_database(ctx.locale)),
@@ -217,7 +218,7 @@ where
                 return ctx.send_message(&response).await;
             }
             Err(e) => {
-                eprintln!("BanDelete database error for IP {}: {}", target, e);
+                log_error!("BanDelete database error for IP {}: {}", target, e);
                 let response = ServerMessage::BanDeleteResponse {
                     success: false,
                     error: Some(super::err_database(ctx.locale)),
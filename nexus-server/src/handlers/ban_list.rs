@@ -21,7 +21,7 @@ where
 {
     // Verify authentication first
     let Some(session_id) = session_id else {
-        eprintln!("BanList request from {} without login", ctx.peer_addr);
+        log_warn!("BanList request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("BanList"))
             .await;
@@ -39,9 +39,10 @@ where
 
     // Check ban_list permission
     if !requesting_user.has_permission(Permission::BanList) {
-        eprintln!(
+        log_warn!(
             "BanList from {} (user: {}) without permission",
-            ctx.peer_addr, requesting_user.username
+            ctx.peer_addr,
+            requesting_user.username
         );
         let response = ServerMessage::BanListResponse {
             success: false,
@@ -74,7 +75,7 @@ where
             ctx.send_message(&response).await
         }
         Err(e) => {
-            eprintln!("BanList database error: {}", e);
+            log_error!("BanList database error: {}", e);
             let response = ServerMessage::BanListResponse {
                 success: false,
                 error: Some(super::err_database(ctx.locale)),
@@ -5,30 +5,51 @@ use std::io;
 
 use tokio::io::AsyncWrite;
 
-use nexus_common::protocol::ServerMessage;
+use nexus_common::protocol::{BroadcastTarget, ServerMessage};
 use nexus_common::validators::{self, MessageError};
 
+use super::duration::parse_duration;
 use super::{
-    HandlerContext, err_authentication, err_broadcast_too_long, err_message_contains_newlines,
+    HandlerContext, err_authentication, err_broadcast_invalid_delay, err_broadcast_too_long,
+    err_broadcast_too_many_scheduled, err_channel_not_found, err_message_contains_newlines,
     err_message_empty, err_message_invalid_characters, err_not_logged_in, err_permission_denied,
+    err_unknown_permission,
 };
+use crate::broadcasts::Broadcast;
+use crate::constants::MAX_SCHEDULED_BROADCASTS;
 use crate::db::Permission;
 
+/// Broadcast request parameters
+pub struct UserBroadcastRequest {
+    pub message: String,
+    pub target: BroadcastTarget,
+    pub delay: Option<String>,
+    pub require_ack: bool,
+}
+
 /// Handle a broadcast request from the client
 ///
-/// Broadcasts a message to all connected users including the sender.
-/// Also sends a UserBroadcastResponse to the sender indicating success or failure.
+/// Sends the message to the target users (and the sender), or schedules it
+/// when a delay is given. Also sends a UserBroadcastResponse to the sender
+/// indicating success or failure, with the delivery count or send time.
 pub async fn handle_user_broadcast<W>(
-    message: String,
+    request: UserBroadcastRequest,
     session_id: Option<u32>,
     ctx: &mut HandlerContext<'_, W>,
 ) -> io::Result<()>
 where
     W: AsyncWrite + Unpin,
 {
+    let UserBroadcastRequest {
+        message,
+        target,
+        delay,
+        require_ack,
+    } = request;
+
     // Verify authentication first (before revealing validation errors to unauthenticated users)
     let Some(id) = session_id else {
-        eprintln!("UserBroadcast from {} without login", ctx.peer_addr);
+        log_warn!("UserBroadcast from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("UserBroadcast"))
             .await;
@@ -61,37 +82,109 @@ where
 
     // Check permission (uses cached permissions, admin bypass built-in)
     if !user.has_permission(Permission::UserBroadcast) {
-        eprintln!(
+        log_warn!(
             "UserBroadcast from {} (user: {}) without permission",
-            ctx.peer_addr, user.username
+            ctx.peer_addr,
+            user.username
         );
         return ctx
             .send_error(&err_permission_denied(ctx.locale), Some("UserBroadcast"))
             .await;
     }
 
-    // Send broadcast to all users
-    ctx.user_manager
-        .broadcast(ServerMessage::ServerBroadcast {
-            session_id: id,
-            username: user.username.clone(),
-            message,
-        })
+    // Check the target (secret channels only for members and admins)
+    let target_error = match &target {
+        BroadcastTarget::All => None,
+        BroadcastTarget::Channel { channel } => (!ctx
+            .channel_manager
+            .is_visible(channel, id, user.is_admin)
+            .await)
+            .then(|| err_channel_not_found(ctx.locale, channel)),
+        BroadcastTarget::Permission { permission } => Permission::parse(permission)
+            .is_none()
+            .then(|| err_unknown_permission(ctx.locale, permission)),
+    };
+    if let Some(error) = target_error {
+        return ctx.send_message(&broadcast_error(error)).await;
+    }
+
+    let Ok(send_at) = parse_duration(&delay) else {
+        return ctx
+            .send_message(&broadcast_error(err_broadcast_invalid_delay(ctx.locale)))
+            .await;
+    };
+
+    let broadcast = Broadcast {
+        id: ctx.broadcast_manager.next_id(),
+        sender_session_id: id,
+        sender_username: user.username.clone(),
+        message,
+        target,
+        require_ack,
+    };
+
+    // Scheduled: the sender gets a BroadcastStatus once it goes out
+    if let Some(send_at) = send_at {
+        let broadcast_id = broadcast.id;
+        if !ctx.broadcast_manager.schedule(send_at, broadcast) {
+            let error = err_broadcast_too_many_scheduled(ctx.locale, MAX_SCHEDULED_BROADCASTS);
+            return ctx.send_message(&broadcast_error(error)).await;
+        }
+        return ctx
+            .send_message(&ServerMessage::UserBroadcastResponse {
+                success: true,
+                error: None,
+                broadcast_id: Some(broadcast_id),
+                delivered: None,
+                send_at: Some(send_at),
+            })
+            .await;
+    }
+
+    let delivered = ctx
+        .broadcast_manager
+        .deliver(&broadcast, ctx.user_manager, ctx.channel_manager)
         .await;
 
     // Send success response to the sender
     ctx.send_message(&ServerMessage::UserBroadcastResponse {
         success: true,
         error: None,
+        broadcast_id: Some(broadcast.id),
+        delivered: Some(delivered),
+        send_at: None,
     })
     .await
 }
 
+/// Build a failed UserBroadcastResponse
+fn broadcast_error(error: String) -> ServerMessage {
+    ServerMessage::UserBroadcastResponse {
+        success: false,
+        error: Some(error),
+        broadcast_id: None,
+        delivered: None,
+        send_at: None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::db;
-    use crate::handlers::testing::{create_test_context, login_user};
+    use crate::handlers::testing::{
+        DEFAULT_TEST_LOCALE, create_test_context, login_user, read_server_message,
+    };
+
+    /// A broadcast to everyone, sent right away
+    fn to_all(message: &str) -> UserBroadcastRequest {
+        UserBroadcastRequest {
+            message: message.to_string(),
+            target: BroadcastTarget::All,
+            delay: None,
+            require_ack: false,
+        }
+    }
 
     #[tokio::test]
     async fn test_broadcast_requires_login() {
@@ -100,7 +193,7 @@ mod tests {
 
         // Try to broadcast without login
         let result = handle_user_broadcast(
-            "Hello everyone".to_string(),
+            to_all("Hello everyone"),
             session_id,
             &mut test_ctx.handler_context(),
         )
@@ -119,8 +212,12 @@ mod tests {
         let long_message = "a".repeat(validators::MAX_MESSAGE_LENGTH + 1);
 
         // Try to send too-long message
-        let result =
-            handle_user_broadcast(long_message, session_id, &mut test_ctx.handler_context()).await;
+        let result = handle_user_broadcast(
+            to_all(&long_message),
+            session_id,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
 
         // Should fail
         assert!(
@@ -148,7 +245,7 @@ mod tests {
 
         // Should succeed
         let result = handle_user_broadcast(
-            max_message,
+            to_all(&max_message),
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -175,7 +272,7 @@ mod tests {
 
         // Try to send empty message
         let result = handle_user_broadcast(
-            "".to_string(),
+            to_all(""),
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -186,7 +283,7 @@ mod tests {
 
         // Try to send whitespace-only message
         let result = handle_user_broadcast(
-            "   ".to_string(),
+            to_all("   "),
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -208,7 +305,7 @@ mod tests {
 
         // Try to broadcast without permission
         let result = handle_user_broadcast(
-            "Important announcement!".to_string(),
+            to_all("Important announcement!"),
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -237,7 +334,7 @@ mod tests {
 
         // Send valid broadcast message
         let result = handle_user_broadcast(
-            "Important announcement!".to_string(),
+            to_all("Important announcement!"),
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -256,7 +353,7 @@ mod tests {
 
         // Try to broadcast with invalid session
         let result = handle_user_broadcast(
-            "Hello everyone".to_string(),
+            to_all("Hello everyone"),
             invalid_session_id,
             &mut test_ctx.handler_context(),
         )
@@ -279,7 +376,7 @@ mod tests {
 
         // Admin should be able to broadcast
         let result = handle_user_broadcast(
-            "Admin announcement!".to_string(),
+            to_all("Admin announcement!"),
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -291,4 +388,153 @@ mod tests {
             "Admin should be able to broadcast without explicit permission"
         );
     }
+
+    #[tokio::test]
+    async fn test_broadcast_to_permission_counts_recipients() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[db::Permission::UserBroadcast],
+            false,
+        )
+        .await;
+        login_user(
+            &mut test_ctx,
+            "bob",
+            "password",
+            &[db::Permission::ChatTopic],
+            false,
+        )
+        .await;
+        login_user(&mut test_ctx, "carol", "password", &[], false).await;
+
+        let request = UserBroadcastRequest {
+            target: BroadcastTarget::Permission {
+                permission: "chat_topic".to_string(),
+            },
+            require_ack: true,
+            ..to_all("Topic editors, please check #general")
+        };
+        handle_user_broadcast(request, Some(session_id), &mut test_ctx.handler_context())
+            .await
+            .unwrap();
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::UserBroadcastResponse {
+                success,
+                broadcast_id,
+                delivered,
+                send_at,
+                ..
+            } => {
+                assert!(success);
+                assert!(broadcast_id.is_some());
+                // Only bob; the sender doesn't count
+                assert_eq!(delivered, Some(1));
+                assert_eq!(send_at, None);
+            }
+            response => panic!("Expected UserBroadcastResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_invalid_targets() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[db::Permission::UserBroadcast],
+            false,
+        )
+        .await;
+
+        let requests = [
+            (
+                BroadcastTarget::Permission {
+                    permission: "no_such_permission".to_string(),
+                },
+                err_unknown_permission(DEFAULT_TEST_LOCALE, "no_such_permission"),
+            ),
+            (
+                BroadcastTarget::Channel {
+                    channel: "#nowhere".to_string(),
+                },
+                err_channel_not_found(DEFAULT_TEST_LOCALE, "#nowhere"),
+            ),
+        ];
+        for (target, expected) in requests {
+            let request = UserBroadcastRequest {
+                target,
+                ..to_all("Hello")
+            };
+            handle_user_broadcast(request, Some(session_id), &mut test_ctx.handler_context())
+                .await
+                .unwrap();
+
+            match read_server_message(&mut test_ctx).await {
+                ServerMessage::UserBroadcastResponse { success, error, .. } => {
+                    assert!(!success);
+                    assert_eq!(error, Some(expected));
+                }
+                response => panic!("Expected UserBroadcastResponse, got {:?}", response),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_scheduled() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[db::Permission::UserBroadcast],
+            false,
+        )
+        .await;
+
+        let request = UserBroadcastRequest {
+            delay: Some("10m".to_string()),
+            ..to_all("Maintenance starts now")
+        };
+        handle_user_broadcast(request, Some(session_id), &mut test_ctx.handler_context())
+            .await
+            .unwrap();
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::UserBroadcastResponse {
+                success,
+                delivered,
+                send_at,
+                ..
+            } => {
+                assert!(success);
+                assert_eq!(delivered, None);
+                assert!(send_at.is_some_and(|at| at > chrono::Utc::now().timestamp()));
+            }
+            response => panic!("Expected UserBroadcastResponse, got {:?}", response),
+        }
+
+        let request = UserBroadcastRequest {
+            delay: Some("soon".to_string()),
+            ..to_all("Maintenance starts now")
+        };
+        handle_user_broadcast(request, Some(session_id), &mut test_ctx.handler_context())
+            .await
+            .unwrap();
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::UserBroadcastResponse { success, error, .. } => {
+                assert!(!success);
+                assert_eq!(
+                    error,
+                    Some(err_broadcast_invalid_delay(DEFAULT_TEST_LOCALE))
+                );
+            }
+            response => panic!("Expected UserBroadcastResponse, got {:?}", response),
+        }
+    }
 }
@@ -0,0 +1,62 @@
+//! Handler for BroadcastAck command
+//!
+//! A recipient acknowledges a broadcast that asked for it. The sender gets a
+//! `BroadcastStatus` with the updated count; the recipient gets no response.
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use super::{HandlerContext, err_not_logged_in};
+
+/// Handle BroadcastAck command
+///
+/// Acknowledgements of broadcasts that aren't tracked (unknown, too old, or
+/// not sent to this session) and repeated acknowledgements are ignored.
+pub async fn handle_broadcast_ack<W>(
+    broadcast_id: u64,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(session_id) = session_id else {
+        log_warn!("BroadcastAck request from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("BroadcastAck"))
+            .await;
+    };
+
+    if let Some((sender, status)) = ctx.broadcast_manager.acknowledge(broadcast_id, session_id) {
+        ctx.user_manager
+            .broadcast_to_username(&sender, &status)
+            .await;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::testing::create_test_context;
+
+    #[tokio::test]
+    async fn test_broadcast_ack_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_broadcast_ack(1, None, &mut test_ctx.handler_context()).await;
+
+        assert!(result.is_err(), "BroadcastAck should require login");
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_ack_unknown_broadcast_is_ignored() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_broadcast_ack(u64::MAX, Some(1), &mut test_ctx.handler_context()).await;
+
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,77 @@
+//! Cooperative cancellation of a running request (see `Cancel`)
+//!
+//! The connection keeps reading while a handler runs, and a `Cancel` naming
+//! the running request trips its token. Long handlers race their work
+//! against [`CancelToken::cancelled`] (or poll [`CancelToken::is_cancelled`]
+//! on a blocking thread) and return without a response once it trips.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use tokio::sync::Notify;
+
+/// Cancellation flag for one request
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    state: Arc<CancelState>,
+}
+
+#[derive(Debug, Default)]
+struct CancelState {
+    cancelled: AtomicBool,
+    notify: Notify,
+}
+
+impl CancelToken {
+    /// Ask the request's handler to stop
+    pub fn cancel(&self) {
+        self.state.cancelled.store(true, Ordering::Release);
+        self.state.notify.notify_waiters();
+    }
+
+    /// Whether the request was cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.state.cancelled.load(Ordering::Acquire)
+    }
+
+    /// Wait until the request is cancelled (forever if it never is)
+    pub async fn cancelled(&self) {
+        loop {
+            // Register before checking so a cancel in between isn't missed
+            let notified = self.state.notify.notified();
+            if self.is_cancelled() {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_cancel_wakes_waiters() {
+        let token = CancelToken::default();
+        assert!(!token.is_cancelled());
+
+        let waiter = tokio::spawn({
+            let token = token.clone();
+            async move { token.cancelled().await }
+        });
+        tokio::task::yield_now().await;
+        token.cancel();
+
+        tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .expect("waiter should wake")
+            .unwrap();
+        assert!(token.is_cancelled());
+
+        // Waiting after the fact returns right away
+        token.cancelled().await;
+    }
+}
@@ -6,22 +6,26 @@ use std::time::{SystemTime, UNIX_EPOCH};
 
 use tokio::io::AsyncWrite;
 
+use nexus_common::ErrorKind;
 use nexus_common::protocol::{ChatAction, ServerMessage};
 use nexus_common::validators::{self, MessageError};
 
 use super::{
     HandlerContext, channel_error_to_message, err_authentication, err_channel_not_found,
-    err_chat_feature_not_enabled, err_chat_too_long, err_message_contains_newlines,
-    err_message_empty, err_message_invalid_characters, err_not_logged_in, err_permission_denied,
+    err_chat_feature_not_enabled, err_chat_too_long, err_idempotency_key_invalid,
+    err_message_contains_newlines, err_message_empty, err_message_invalid_characters,
+    err_not_logged_in, err_permission_denied,
 };
 use crate::constants::FEATURE_CHAT;
 use crate::db::Permission;
+use crate::outbound::SharedFrame;
 
 /// Handle a chat send request from the client
 pub async fn handle_chat_send<W>(
     message: String,
     action: ChatAction,
     channel: String,
+    idempotency_key: Option<String>,
     session_id: Option<u32>,
     ctx: &mut HandlerContext<'_, W>,
 ) -> io::Result<()>
@@ -30,17 +34,26 @@ where
 {
     // Verify authentication first (before revealing validation errors to unauthenticated users)
     let Some(id) = session_id else {
-        eprintln!("ChatSend from {} without login", ctx.peer_addr);
+        log_warn!("ChatSend from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("ChatSend"))
             .await;
     };
 
     // Validate message content
-    if let Err(e) = validators::validate_message(&message) {
+    if let Err(e) = validators::validate_multiline_message(&message) {
         let error_msg = match e {
             MessageError::Empty => err_message_empty(ctx.locale),
-            MessageError::TooLong => err_chat_too_long(ctx.locale, validators::MAX_MESSAGE_LENGTH),
+            // Clients may split a message that's too long and send it again,
+            // so this one doesn't disconnect
+            MessageError::TooLong => {
+                let error = ServerMessage::Error {
+                    message: err_chat_too_long(ctx.locale, validators::MAX_MESSAGE_LENGTH),
+                    command: Some("ChatSend".to_string()),
+                    error_kind: Some(ErrorKind::MessageTooLong.into()),
+                };
+                return ctx.send_message(&error).await;
+            }
             MessageError::ContainsNewlines => err_message_contains_newlines(ctx.locale),
             MessageError::InvalidCharacters => err_message_invalid_characters(ctx.locale),
         };
@@ -49,6 +62,14 @@ where
             .await;
     }
 
+    if let Some(key) = &idempotency_key
+        && validators::validate_idempotency_key(key).is_err()
+    {
+        return ctx
+            .send_error_and_disconnect(&err_idempotency_key_invalid(ctx.locale), Some("ChatSend"))
+            .await;
+    }
+
     // Validate channel name
     if let Err(e) = validators::validate_channel(&channel) {
         return ctx
@@ -75,9 +96,10 @@ where
 
     // Check permission (uses cached permissions, admin bypass built-in)
     if !user.has_permission(Permission::ChatSend) {
-        eprintln!(
+        log_warn!(
             "ChatSend from {} (user: {}) without permission",
-            ctx.peer_addr, user.username
+            ctx.peer_addr,
+            user.username
         );
         return ctx
             .send_error(&err_permission_denied(ctx.locale), Some("ChatSend"))
@@ -96,6 +118,16 @@ where
             .await;
     }
 
+    // A message sent again after a dropped connection is acknowledged, not
+    // posted twice
+    if let Some(key) = &idempotency_key
+        && !ctx.idempotency_keys.first_use(&user.nickname, key)
+    {
+        return ctx
+            .send_message(&ServerMessage::ChatSendResponse { success: true })
+            .await;
+    }
+
     // Get channel members for routing
     let members = ctx
         .channel_manager
@@ -108,7 +140,7 @@ where
         .duration_since(UNIX_EPOCH)
         .map(|d| d.as_secs())
         .unwrap_or(0);
-    let chat_message = ServerMessage::ChatMessage {
+    let chat_message = SharedFrame::new(ServerMessage::ChatMessage {
         session_id: id,
         nickname: user.nickname.clone(),
         is_admin: user.is_admin,
@@ -117,9 +149,13 @@ where
         action,
         channel,
         timestamp,
-    };
+    })?;
+
+    ctx.stats.record_message(user.db_user_id);
+    ctx.user_manager.touch_activity(id).await;
 
     // Send message to all channel members who have the chat feature and ChatReceive permission
+    // (encoded once above, every member's queue shares the frame)
     for member_session_id in members {
         if let Some(member) = ctx
             .user_manager
@@ -129,12 +165,18 @@ where
             // Check if member has chat feature and receive permission
             if member.has_feature(FEATURE_CHAT) && member.has_permission(Permission::ChatReceive) {
                 ctx.user_manager
-                    .send_to_session(member_session_id, chat_message.clone())
+                    .send_frame_to_session(member_session_id, &chat_message)
                     .await;
             }
         }
     }
 
+    // Only clients that sent a key wait for this
+    if idempotency_key.is_some() {
+        ctx.send_message(&ServerMessage::ChatSendResponse { success: true })
+            .await?;
+    }
+
     Ok(())
 }
 
@@ -156,6 +198,7 @@ mod tests {
             "Hello".to_string(),
             ChatAction::Normal,
             "#general".to_string(),
+            None,
             session_id,
             &mut test_ctx.handler_context(),
         )
@@ -178,16 +221,31 @@ mod tests {
             long_message,
             ChatAction::Normal,
             "#general".to_string(),
+            None,
             session_id,
             &mut test_ctx.handler_context(),
         )
         .await;
 
-        // Should fail
+        // Should be rejected without disconnecting
         assert!(
-            result.is_err(),
-            "Message over MAX_MESSAGE_LENGTH should be rejected"
+            result.is_ok(),
+            "Message over MAX_MESSAGE_LENGTH should not disconnect"
         );
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::Error {
+                command,
+                error_kind,
+                ..
+            } => {
+                assert_eq!(command.as_deref(), Some("ChatSend"));
+                assert_eq!(
+                    error_kind.as_deref(),
+                    Some(ErrorKind::MessageTooLong.as_str())
+                );
+            }
+            _ => panic!("Expected Error"),
+        }
     }
 
     #[tokio::test]
@@ -220,6 +278,7 @@ mod tests {
             max_message,
             ChatAction::Normal,
             "#general".to_string(),
+            None,
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -250,6 +309,7 @@ mod tests {
             "".to_string(),
             ChatAction::Normal,
             "#general".to_string(),
+            None,
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -263,6 +323,7 @@ mod tests {
             "   ".to_string(),
             ChatAction::Normal,
             "#general".to_string(),
+            None,
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -295,6 +356,7 @@ mod tests {
             "Hello\nWorld".to_string(),
             ChatAction::Normal,
             "#general".to_string(),
+            None,
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -308,6 +370,7 @@ mod tests {
             "Hello\rWorld".to_string(),
             ChatAction::Normal,
             "#general".to_string(),
+            None,
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -324,6 +387,7 @@ mod tests {
             "Hello\r\nWorld".to_string(),
             ChatAction::Normal,
             "#general".to_string(),
+            None,
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -360,6 +424,7 @@ mod tests {
             "Hello".to_string(),
             ChatAction::Normal,
             "#general".to_string(),
+            None,
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -392,6 +457,7 @@ mod tests {
             "Hello".to_string(),
             ChatAction::Normal,
             "#general".to_string(),
+            None,
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -428,6 +494,7 @@ mod tests {
             "Hello, world!".to_string(),
             ChatAction::Normal,
             "#general".to_string(),
+            None,
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -437,6 +504,66 @@ mod tests {
         assert!(result.is_ok(), "Valid chat message should succeed");
     }
 
+    #[tokio::test]
+    async fn test_chat_idempotency_key() {
+        let mut test_ctx = create_test_context().await;
+
+        let session_id = login_user_with_features(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[db::Permission::ChatSend, db::Permission::ChatReceive],
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+        test_ctx
+            .channel_manager
+            .join("#general", session_id)
+            .await
+            .unwrap();
+        while test_ctx.rx.try_recv().is_ok() {}
+
+        // Sent, then sent again after a dropped connection
+        for _ in 0..2 {
+            handle_chat_send(
+                "Hello, world!".to_string(),
+                ChatAction::Normal,
+                "#general".to_string(),
+                Some("chat-retry-1".to_string()),
+                Some(session_id),
+                &mut test_ctx.handler_context(),
+            )
+            .await
+            .unwrap();
+            match read_server_message(&mut test_ctx).await {
+                ServerMessage::ChatSendResponse { success } => assert!(success),
+                other => panic!("Expected ChatSendResponse, got: {other:?}"),
+            }
+        }
+
+        // Posted once
+        let mut posted = 0;
+        while let Ok((msg, _)) = test_ctx.rx.try_recv() {
+            if matches!(msg, ServerMessage::ChatMessage { .. }) {
+                posted += 1;
+            }
+        }
+        assert_eq!(posted, 1);
+
+        // Malformed keys are rejected
+        let result = handle_chat_send(
+            "Hello, world!".to_string(),
+            ChatAction::Normal,
+            "#general".to_string(),
+            Some("not a key".to_string()),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_err(), "Malformed key should disconnect");
+    }
+
     #[tokio::test]
     async fn test_chat_invalid_session() {
         let mut test_ctx = create_test_context().await;
@@ -449,6 +576,7 @@ mod tests {
             "Hello".to_string(),
             ChatAction::Normal,
             "#general".to_string(),
+            None,
             invalid_session_id,
             &mut test_ctx.handler_context(),
         )
@@ -489,6 +617,7 @@ mod tests {
             "Admin message!".to_string(),
             ChatAction::Normal,
             "#general".to_string(),
+            None,
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -528,6 +657,7 @@ mod tests {
             "Hello".to_string(),
             ChatAction::Normal,
             "#general".to_string(),
+            None,
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -557,6 +687,7 @@ mod tests {
             "Hello".to_string(),
             ChatAction::Normal,
             "#nonexistent".to_string(),
+            None,
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -593,6 +724,7 @@ mod tests {
             "Hello channel!".to_string(),
             ChatAction::Normal,
             "#general".to_string(),
+            None,
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -622,6 +754,7 @@ mod tests {
             "Hello".to_string(),
             ChatAction::Normal,
             "".to_string(), // Empty channel name
+            None,
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -632,7 +765,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert!(message.to_lowercase().contains("channel")); // Error about channel
                 assert_eq!(command, Some("ChatSend".to_string()));
             }
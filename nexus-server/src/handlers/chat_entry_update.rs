@@ -0,0 +1,374 @@
+//! Handler for ChatEntryUpdate command - set a channel's entry message and rules
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+use nexus_common::validators::{self, ChatEntryError};
+
+use super::{
+    HandlerContext, channel_error_to_message, err_authentication, err_channel_not_found,
+    err_chat_feature_not_enabled, err_database, err_entry_message_invalid_characters,
+    err_entry_message_too_long, err_not_logged_in, err_permission_denied,
+    err_rules_invalid_characters, err_rules_too_long,
+};
+use crate::constants::FEATURE_CHAT;
+use crate::db::Permission;
+
+/// Helper to create an error response
+fn error_response(error_msg: String) -> ServerMessage {
+    ServerMessage::ChatEntryUpdateResponse {
+        success: false,
+        error: Some(error_msg),
+    }
+}
+
+/// Handle ChatEntryUpdate command - set a channel's entry message and/or rules
+///
+/// `None` leaves a field unchanged, an empty string clears it. The entry
+/// message is only sent to joining users; rule changes are broadcast to
+/// channel members with ChatUpdated.
+pub async fn handle_chat_entry_update<W>(
+    channel: String,
+    entry_message: Option<String>,
+    rules: Option<String>,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first
+    let Some(session_id) = session_id else {
+        log_warn!(
+            "ChatEntryUpdate request from {} without login",
+            ctx.peer_addr
+        );
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("ChatEntryUpdate"))
+            .await;
+    };
+
+    // Validate channel name
+    if let Err(e) = validators::validate_channel(&channel) {
+        return ctx
+            .send_message(&error_response(channel_error_to_message(e, ctx.locale)))
+            .await;
+    }
+
+    // Validate entry message and rules
+    if let Some(entry_message) = &entry_message
+        && let Err(e) = validators::validate_chat_entry_message(entry_message)
+    {
+        let error_msg = match e {
+            ChatEntryError::TooLong => {
+                err_entry_message_too_long(ctx.locale, validators::MAX_CHAT_ENTRY_MESSAGE_LENGTH)
+            }
+            ChatEntryError::InvalidCharacters => err_entry_message_invalid_characters(ctx.locale),
+        };
+        return ctx.send_message(&error_response(error_msg)).await;
+    }
+    if let Some(rules) = &rules
+        && let Err(e) = validators::validate_chat_rules(rules)
+    {
+        let error_msg = match e {
+            ChatEntryError::TooLong => {
+                err_rules_too_long(ctx.locale, validators::MAX_CHAT_RULES_LENGTH)
+            }
+            ChatEntryError::InvalidCharacters => err_rules_invalid_characters(ctx.locale),
+        };
+        return ctx.send_message(&error_response(error_msg)).await;
+    }
+
+    // Get user from session
+    let user = match ctx.user_manager.get_user_by_session_id(session_id).await {
+        Some(u) => u,
+        None => {
+            return ctx
+                .send_error_and_disconnect(&err_authentication(ctx.locale), Some("ChatEntryUpdate"))
+                .await;
+        }
+    };
+
+    // Check chat feature
+    if !user.has_feature(FEATURE_CHAT) {
+        return ctx
+            .send_message(&error_response(err_chat_feature_not_enabled(ctx.locale)))
+            .await;
+    }
+
+    // Check ChatEntryEdit permission
+    if !user.has_permission(Permission::ChatEntryEdit) {
+        log_warn!(
+            "ChatEntryUpdate from {} (user: {}) without permission",
+            ctx.peer_addr,
+            user.username
+        );
+        return ctx
+            .send_message(&error_response(err_permission_denied(ctx.locale)))
+            .await;
+    }
+
+    // Verify user is a member of the channel
+    // For security, always return "not found" to non-members to avoid leaking
+    // existence of secret channels
+    if !ctx.channel_manager.is_member(&channel, session_id).await {
+        return ctx
+            .send_message(&error_response(err_channel_not_found(ctx.locale, &channel)))
+            .await;
+    }
+
+    // Apply the changes (ChannelManager handles persistence for persistent channels)
+    let non_empty = |value: String| (!value.is_empty()).then_some(value);
+    let mut result = Ok(true);
+    if let Some(entry_message) = entry_message {
+        result = ctx
+            .channel_manager
+            .set_entry_message(&channel, non_empty(entry_message))
+            .await;
+    }
+    if let (Ok(true), Some(rules)) = (&result, &rules) {
+        result = ctx
+            .channel_manager
+            .set_rules(&channel, non_empty(rules.clone()))
+            .await;
+    }
+    match result {
+        Ok(true) => {} // Success, channel exists
+        Ok(false) => {
+            // Channel doesn't exist (race condition - was deleted after membership check)
+            return ctx
+                .send_message(&error_response(err_channel_not_found(ctx.locale, &channel)))
+                .await;
+        }
+        Err(e) => {
+            log_error!("Database error setting channel entry message: {}", e);
+            return ctx
+                .send_message(&error_response(err_database(ctx.locale)))
+                .await;
+        }
+    }
+
+    // Send success response to the requester
+    let response = ServerMessage::ChatEntryUpdateResponse {
+        success: true,
+        error: None,
+    };
+    ctx.send_message(&response).await?;
+
+    // Broadcast rule changes to all channel members
+    let Some(rules) = rules else {
+        return Ok(());
+    };
+
+    let members = ctx
+        .channel_manager
+        .get_members(&channel)
+        .await
+        .unwrap_or_default();
+
+    let update_message = ServerMessage::ChatUpdated {
+        channel: channel.clone(),
+        topic: None,
+        topic_set_by: None,
+        secret: None,
+        secret_set_by: None,
+        voice_limit: None,
+        voice_limit_set_by: None,
+        topic_locked: None,
+        topic_locked_set_by: None,
+        rules: Some(rules),
+        rules_set_by: Some(user.nickname.clone()),
+        language: None,
+        language_set_by: None,
+    };
+
+    for member_session_id in members {
+        if let Some(member) = ctx
+            .user_manager
+            .get_user_by_session_id(member_session_id)
+            .await
+        {
+            // Only send to members with chat feature
+            if member.has_feature(FEATURE_CHAT) {
+                ctx.user_manager
+                    .send_to_session(member_session_id, update_message.clone())
+                    .await;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::chat_join::handle_chat_join;
+    use crate::handlers::testing::{
+        create_test_context, login_user_with_features, read_server_message,
+    };
+
+    #[tokio::test]
+    async fn test_chat_entry_update_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_chat_entry_update(
+            "#general".to_string(),
+            Some("Welcome!".to_string()),
+            None,
+            None,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_err(), "Should disconnect unauthenticated user");
+    }
+
+    #[tokio::test]
+    async fn test_chat_entry_update_requires_permission() {
+        let mut test_ctx = create_test_context().await;
+
+        let session_id = login_user_with_features(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::ChatJoin, Permission::ChatCreate],
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+
+        let result = handle_chat_entry_update(
+            "#general".to_string(),
+            Some("Welcome!".to_string()),
+            None,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::ChatEntryUpdateResponse { success, error } => {
+                assert!(!success);
+                assert!(error.is_some());
+            }
+            _ => panic!("Expected ChatEntryUpdateResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chat_entry_update_rejects_long_rules() {
+        let mut test_ctx = create_test_context().await;
+
+        let session_id = login_user_with_features(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::ChatEntryEdit],
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+
+        let result = handle_chat_entry_update(
+            "#general".to_string(),
+            None,
+            Some("a".repeat(validators::MAX_CHAT_RULES_LENGTH + 1)),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::ChatEntryUpdateResponse { success, error } => {
+                assert!(!success);
+                assert!(error.is_some());
+            }
+            _ => panic!("Expected ChatEntryUpdateResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chat_entry_update_shown_on_join() {
+        let mut test_ctx = create_test_context().await;
+
+        let op_session = login_user_with_features(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[
+                Permission::ChatJoin,
+                Permission::ChatCreate,
+                Permission::ChatEntryEdit,
+            ],
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+        let user_session = login_user_with_features(
+            &mut test_ctx,
+            "bob",
+            "password",
+            &[Permission::ChatJoin],
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+
+        let _ = handle_chat_join(
+            "#general".to_string(),
+            Some(op_session),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        let _ = read_server_message(&mut test_ctx).await; // ChatJoinResponse
+
+        let result = handle_chat_entry_update(
+            "#general".to_string(),
+            Some("Welcome to #general!".to_string()),
+            Some("1. Be nice".to_string()),
+            Some(op_session),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::ChatEntryUpdateResponse { success, error } => {
+                assert!(success);
+                assert!(error.is_none());
+            }
+            _ => panic!("Expected ChatEntryUpdateResponse, got {:?}", response),
+        }
+
+        let result = handle_chat_join(
+            "#general".to_string(),
+            Some(user_session),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::ChatJoinResponse {
+                success,
+                entry_message,
+                rules,
+                ..
+            } => {
+                assert!(success);
+                assert_eq!(entry_message, Some("Welcome to #general!".to_string()));
+                assert_eq!(rules, Some("1. Be nice".to_string()));
+            }
+            _ => panic!("Expected ChatJoinResponse, got {:?}", response),
+        }
+    }
+}
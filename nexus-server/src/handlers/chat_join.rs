@@ -15,6 +15,7 @@ use crate::channels::JoinError;
 use crate::constants::FEATURE_CHAT;
 use crate::db::Permission;
 use crate::i18n::t;
+use crate::voice::voice_broadcast_update;
 
 /// Error message for missing ChatCreate permission when creating a channel
 fn err_permission_denied_chat_create(locale: &str) -> String {
@@ -32,6 +33,11 @@ fn error_response(error_msg: String) -> ServerMessage {
         secret: None,
         members: None,
         voiced: None,
+        voice_limit: None,
+        topic_locked: false,
+        entry_message: None,
+        rules: None,
+        language: None,
     }
 }
 
@@ -46,7 +52,7 @@ where
 {
     // Verify authentication first
     let Some(session_id) = session_id else {
-        eprintln!("ChatJoin request from {} without login", ctx.peer_addr);
+        log_warn!("ChatJoin request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("ChatJoin"))
             .await;
@@ -78,9 +84,10 @@ where
 
     // Check ChatJoin permission (required for both joining and creating)
     if !user.has_permission(Permission::ChatJoin) {
-        eprintln!(
+        log_warn!(
             "ChatJoin from {} (user: {}) without permission",
-            ctx.peer_addr, user.username
+            ctx.peer_addr,
+            user.username
         );
         return ctx
             .send_message(&error_response(err_permission_denied(ctx.locale)))
@@ -94,9 +101,10 @@ where
     // only ChatJoin, not ChatCreate). No privilege escalation is possible.
     let channel_exists = ctx.channel_manager.exists(&channel).await;
     if !channel_exists && !user.has_permission(Permission::ChatCreate) {
-        eprintln!(
+        log_warn!(
             "ChatJoin from {} (user: {}) trying to create channel without ChatCreate permission",
-            ctx.peer_addr, user.username
+            ctx.peer_addr,
+            user.username
         );
         return ctx
             .send_message(&error_response(err_permission_denied_chat_create(
@@ -159,16 +167,20 @@ where
         }
     }
 
-    // Get voiced nicknames if user has voice_listen permission
-    let voiced = if user.has_permission(Permission::VoiceListen) {
+    // Get voiced nicknames (and any broadcast) if user has voice_listen permission
+    let (voiced, broadcast_update) = if user.has_permission(Permission::VoiceListen) {
         let participants = ctx.voice_registry.get_participants(&channel).await;
+        let broadcast_update = match ctx.voice_registry.get_broadcaster(&channel).await {
+            Some(_) => Some(voice_broadcast_update(&channel, ctx.voice_registry).await),
+            None => None,
+        };
         if participants.is_empty() {
-            None
+            (None, broadcast_update)
         } else {
-            Some(participants)
+            (Some(participants), broadcast_update)
         }
     } else {
-        None
+        (None, None)
     };
 
     // Send success response with full channel data
@@ -181,8 +193,20 @@ where
         secret: Some(result.secret),
         members: Some(member_nicknames),
         voiced,
+        voice_limit: (result.voice_limit > 0).then_some(result.voice_limit),
+        topic_locked: result.topic_locked,
+        entry_message: result.entry_message,
+        rules: result.rules,
+        language: result.language,
     };
-    ctx.send_message(&response).await
+    ctx.send_message(&response).await?;
+
+    // Let the new member know the channel's voice is a broadcast
+    if let Some(update) = broadcast_update {
+        ctx.send_message(&update).await?;
+    }
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -654,6 +678,7 @@ mod tests {
                 nickname: username.to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .expect("Failed to add second session")
@@ -690,6 +715,7 @@ mod tests {
                 nickname: nickname.to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .expect("Failed to add shared session")
@@ -0,0 +1,318 @@
+//! Handler for ChatLanguage command - set a channel's language tag
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+use nexus_common::validators::{self, ChannelLanguageError};
+
+use super::{
+    HandlerContext, channel_error_to_message, err_authentication, err_channel_language_invalid,
+    err_channel_language_too_long, err_channel_not_found, err_chat_feature_not_enabled,
+    err_database, err_not_logged_in, err_permission_denied,
+};
+use crate::constants::FEATURE_CHAT;
+use crate::db::Permission;
+
+/// Helper to create an error response
+fn error_response(error_msg: String) -> ServerMessage {
+    ServerMessage::ChatLanguageResponse {
+        success: false,
+        error: Some(error_msg),
+    }
+}
+
+/// Handle ChatLanguage command - set a channel's language tag
+///
+/// The tag is shown in the channel list and can be used to filter it. An
+/// empty string clears it.
+pub async fn handle_chat_language<W>(
+    channel: String,
+    language: String,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first
+    let Some(session_id) = session_id else {
+        log_warn!("ChatLanguage request from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("ChatLanguage"))
+            .await;
+    };
+
+    // Validate channel name
+    if let Err(e) = validators::validate_channel(&channel) {
+        return ctx
+            .send_message(&error_response(channel_error_to_message(e, ctx.locale)))
+            .await;
+    }
+
+    // Validate language tag
+    if let Err(e) = validators::validate_channel_language(&language) {
+        let error_msg = match e {
+            ChannelLanguageError::TooLong => {
+                err_channel_language_too_long(ctx.locale, validators::MAX_CHANNEL_LANGUAGE_LENGTH)
+            }
+            ChannelLanguageError::InvalidFormat => err_channel_language_invalid(ctx.locale),
+        };
+        return ctx.send_message(&error_response(error_msg)).await;
+    }
+
+    // Get user from session
+    let user = match ctx.user_manager.get_user_by_session_id(session_id).await {
+        Some(u) => u,
+        None => {
+            return ctx
+                .send_error_and_disconnect(&err_authentication(ctx.locale), Some("ChatLanguage"))
+                .await;
+        }
+    };
+
+    // Check chat feature
+    if !user.has_feature(FEATURE_CHAT) {
+        return ctx
+            .send_message(&error_response(err_chat_feature_not_enabled(ctx.locale)))
+            .await;
+    }
+
+    // Check ChatLanguage permission
+    if !user.has_permission(Permission::ChatLanguage) {
+        log_warn!(
+            "ChatLanguage from {} (user: {}) without permission",
+            ctx.peer_addr,
+            user.username
+        );
+        return ctx
+            .send_message(&error_response(err_permission_denied(ctx.locale)))
+            .await;
+    }
+
+    // Verify user is a member of the channel
+    // For security, always return "not found" to non-members to avoid leaking
+    // existence of secret channels
+    if !ctx.channel_manager.is_member(&channel, session_id).await {
+        return ctx
+            .send_message(&error_response(err_channel_not_found(ctx.locale, &channel)))
+            .await;
+    }
+
+    // Set the language (ChannelManager handles persistence for persistent channels)
+    let new_language = (!language.is_empty()).then(|| language.clone());
+    match ctx
+        .channel_manager
+        .set_language(&channel, new_language)
+        .await
+    {
+        Ok(true) => {} // Success, channel exists
+        Ok(false) => {
+            // Channel doesn't exist (race condition - was deleted after membership check)
+            return ctx
+                .send_message(&error_response(err_channel_not_found(ctx.locale, &channel)))
+                .await;
+        }
+        Err(e) => {
+            log_error!("Database error setting channel language: {}", e);
+            return ctx
+                .send_message(&error_response(err_database(ctx.locale)))
+                .await;
+        }
+    }
+
+    // Send success response to the requester
+    let response = ServerMessage::ChatLanguageResponse {
+        success: true,
+        error: None,
+    };
+    ctx.send_message(&response).await?;
+
+    // Broadcast ChatUpdated to all channel members
+    let members = ctx
+        .channel_manager
+        .get_members(&channel)
+        .await
+        .unwrap_or_default();
+
+    let update_message = ServerMessage::ChatUpdated {
+        channel: channel.clone(),
+        topic: None,
+        topic_set_by: None,
+        secret: None,
+        secret_set_by: None,
+        voice_limit: None,
+        voice_limit_set_by: None,
+        topic_locked: None,
+        topic_locked_set_by: None,
+        rules: None,
+        rules_set_by: None,
+        language: Some(language),
+        language_set_by: Some(user.nickname.clone()),
+    };
+
+    for member_session_id in members {
+        if let Some(member) = ctx
+            .user_manager
+            .get_user_by_session_id(member_session_id)
+            .await
+        {
+            // Only send to members with chat feature
+            if member.has_feature(FEATURE_CHAT) {
+                ctx.user_manager
+                    .send_to_session(member_session_id, update_message.clone())
+                    .await;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::chat_join::handle_chat_join;
+    use crate::handlers::testing::{
+        DEFAULT_TEST_LOCALE, create_test_context, login_user_with_features, read_server_message,
+    };
+
+    #[tokio::test]
+    async fn test_chat_language_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_chat_language(
+            "#general".to_string(),
+            "en".to_string(),
+            None,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_err(), "Should disconnect unauthenticated user");
+    }
+
+    #[tokio::test]
+    async fn test_chat_language_requires_permission() {
+        let mut test_ctx = create_test_context().await;
+
+        // Login user WITHOUT ChatLanguage permission but WITH chat feature
+        let session_id = login_user_with_features(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::ChatEntryEdit],
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+
+        let result = handle_chat_language(
+            "#general".to_string(),
+            "en".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::ChatLanguageResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_permission_denied(DEFAULT_TEST_LOCALE)));
+            }
+            _ => panic!("Expected ChatLanguageResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chat_language_rejects_invalid_tag() {
+        let mut test_ctx = create_test_context().await;
+
+        let session_id = login_user_with_features(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::ChatLanguage],
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+
+        let result = handle_chat_language(
+            "#general".to_string(),
+            "English (UK)".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::ChatLanguageResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(
+                    error,
+                    Some(err_channel_language_invalid(DEFAULT_TEST_LOCALE))
+                );
+            }
+            _ => panic!("Expected ChatLanguageResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chat_language_set_and_clear() {
+        let mut test_ctx = create_test_context().await;
+
+        let session_id = login_user_with_features(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[
+                Permission::ChatJoin,
+                Permission::ChatCreate,
+                Permission::ChatLanguage,
+            ],
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+
+        let _ = handle_chat_join(
+            "#general".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        let _ = read_server_message(&mut test_ctx).await; // ChatJoinResponse
+
+        for (language, expected) in [("pt-BR", Some("pt-BR".to_string())), ("", None)] {
+            let result = handle_chat_language(
+                "#general".to_string(),
+                language.to_string(),
+                Some(session_id),
+                &mut test_ctx.handler_context(),
+            )
+            .await;
+            assert!(result.is_ok());
+
+            let response = read_server_message(&mut test_ctx).await;
+            match response {
+                ServerMessage::ChatLanguageResponse { success, error } => {
+                    assert!(success);
+                    assert!(error.is_none());
+                }
+                _ => panic!("Expected ChatLanguageResponse, got {:?}", response),
+            }
+
+            let list = test_ctx.channel_manager.list(session_id, false).await;
+            let general = list.iter().find(|c| c.name == "#general").unwrap();
+            assert_eq!(general.language, expected);
+        }
+    }
+}
@@ -11,7 +11,7 @@ use super::{
     HandlerContext, channel_error_to_message, err_authentication, err_channel_not_found,
     err_chat_feature_not_enabled, err_not_logged_in,
 };
-use crate::voice::send_voice_leave_notifications;
+use crate::voice::{leave_voice_queue, send_voice_leave_notifications};
 
 use crate::constants::FEATURE_CHAT;
 
@@ -26,7 +26,7 @@ where
 {
     // Verify authentication first
     let Some(session_id) = session_id else {
-        eprintln!("ChatLeave request from {} without login", ctx.peer_addr);
+        log_warn!("ChatLeave request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("ChatLeave"))
             .await;
@@ -73,6 +73,7 @@ where
             send_voice_leave_notifications(
                 &info,
                 Some(&user.tx),
+                ctx.voice_registry,
                 ctx.user_manager,
                 ctx.channel_manager,
             )
@@ -80,6 +81,22 @@ where
         }
     }
 
+    // Likewise stop waiting for a voice slot in this channel
+    if ctx
+        .voice_registry
+        .queued_session_ids(&channel)
+        .await
+        .contains(&session_id)
+        && let Some(queued_channel) =
+            leave_voice_queue(session_id, ctx.voice_registry, ctx.user_manager).await
+    {
+        let cancelled = ServerMessage::VoiceQueueUpdate {
+            target: queued_channel,
+            position: None,
+        };
+        let _ = user.tx.send((cancelled, None));
+    }
+
     // Leave the channel
     // For security/consistency, return "not found" if not a member to avoid
     // leaking existence of secret channels
@@ -301,6 +318,7 @@ mod tests {
                 nickname: username.to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .expect("Failed to add second session")
@@ -337,6 +355,7 @@ mod tests {
                 nickname: nickname.to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .expect("Failed to add shared session")
@@ -5,16 +5,22 @@ use std::io;
 use tokio::io::AsyncWrite;
 
 use nexus_common::protocol::{ChannelInfo, ServerMessage};
+use nexus_common::validators::{self, ChannelLanguageError};
 
 use super::{
-    HandlerContext, err_authentication, err_chat_feature_not_enabled, err_not_logged_in,
+    HandlerContext, err_authentication, err_channel_language_invalid,
+    err_channel_language_too_long, err_chat_feature_not_enabled, err_not_logged_in,
     err_permission_denied,
 };
 use crate::constants::FEATURE_CHAT;
 use crate::db::Permission;
 
 /// Handle ChatList command - list available channels
+///
+/// With a language, only channels tagged with that language (or a more
+/// specific form of it, e.g. "en-GB" for "en") are listed.
 pub async fn handle_chat_list<W>(
+    language: Option<String>,
     session_id: Option<u32>,
     ctx: &mut HandlerContext<'_, W>,
 ) -> io::Result<()>
@@ -23,12 +29,31 @@ where
 {
     // Verify authentication first
     let Some(session_id) = session_id else {
-        eprintln!("ChatList request from {} without login", ctx.peer_addr);
+        log_warn!("ChatList request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("ChatList"))
             .await;
     };
 
+    // Validate language filter (empty = no filter)
+    let language = language.filter(|language| !language.is_empty());
+    if let Some(language) = &language
+        && let Err(e) = validators::validate_channel_language(language)
+    {
+        let error = match e {
+            ChannelLanguageError::TooLong => {
+                err_channel_language_too_long(ctx.locale, validators::MAX_CHANNEL_LANGUAGE_LENGTH)
+            }
+            ChannelLanguageError::InvalidFormat => err_channel_language_invalid(ctx.locale),
+        };
+        let response = ServerMessage::ChatListResponse {
+            success: false,
+            error: Some(error),
+            channels: None,
+        };
+        return ctx.send_message(&response).await;
+    }
+
     // Get user from session
     let user = match ctx.user_manager.get_user_by_session_id(session_id).await {
         Some(u) => u,
@@ -51,9 +76,10 @@ where
 
     // Check ChatList permission
     if !user.has_permission(Permission::ChatList) {
-        eprintln!(
+        log_warn!(
             "ChatList from {} (user: {}) without permission",
-            ctx.peer_addr, user.username
+            ctx.peer_addr,
+            user.username
         );
         let response = ServerMessage::ChatListResponse {
             success: false,
@@ -69,11 +95,19 @@ where
     // Convert to protocol ChannelInfo
     let channels: Vec<ChannelInfo> = channel_list
         .into_iter()
+        .filter(|info| match (&language, &info.language) {
+            (None, _) => true,
+            (Some(filter), Some(language)) => {
+                validators::channel_language_matches(language, filter)
+            }
+            (Some(_), None) => false,
+        })
         .map(|info| ChannelInfo {
             name: info.name,
             topic: info.topic,
             member_count: info.member_count,
             secret: info.secret,
+            language: info.language,
         })
         .collect();
 
@@ -97,7 +131,7 @@ mod tests {
     async fn test_chat_list_requires_login() {
         let mut test_ctx = create_test_context().await;
 
-        let result = handle_chat_list(None, &mut test_ctx.handler_context()).await;
+        let result = handle_chat_list(None, None, &mut test_ctx.handler_context()).await;
 
         assert!(result.is_err(), "Should disconnect unauthenticated user");
     }
@@ -117,7 +151,8 @@ mod tests {
         )
         .await;
 
-        let result = handle_chat_list(Some(session_id), &mut test_ctx.handler_context()).await;
+        let result =
+            handle_chat_list(None, Some(session_id), &mut test_ctx.handler_context()).await;
 
         assert!(result.is_ok());
 
@@ -146,7 +181,8 @@ mod tests {
         )
         .await;
 
-        let result = handle_chat_list(Some(session_id), &mut test_ctx.handler_context()).await;
+        let result =
+            handle_chat_list(None, Some(session_id), &mut test_ctx.handler_context()).await;
 
         assert!(result.is_ok());
 
@@ -184,7 +220,8 @@ mod tests {
         )
         .await;
 
-        let result = handle_chat_list(Some(session_id), &mut test_ctx.handler_context()).await;
+        let result =
+            handle_chat_list(None, Some(session_id), &mut test_ctx.handler_context()).await;
 
         assert!(result.is_ok());
 
@@ -256,7 +293,8 @@ mod tests {
         .await;
 
         // User 2 should not see #secret in list
-        let result = handle_chat_list(Some(session_id2), &mut test_ctx.handler_context()).await;
+        let result =
+            handle_chat_list(None, Some(session_id2), &mut test_ctx.handler_context()).await;
         assert!(result.is_ok());
 
         let response = read_server_message(&mut test_ctx).await;
@@ -269,7 +307,8 @@ mod tests {
         }
 
         // User 1 should see #secret (is member)
-        let result = handle_chat_list(Some(session_id1), &mut test_ctx.handler_context()).await;
+        let result =
+            handle_chat_list(None, Some(session_id1), &mut test_ctx.handler_context()).await;
         assert!(result.is_ok());
 
         let response = read_server_message(&mut test_ctx).await;
@@ -281,4 +320,85 @@ mod tests {
             _ => panic!("Expected ChatListResponse, got {:?}", response),
         }
     }
+
+    #[tokio::test]
+    async fn test_chat_list_language_filter() {
+        use crate::handlers::chat_join::handle_chat_join;
+
+        let mut test_ctx = create_test_context().await;
+
+        let session_id = login_user_with_features(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[
+                Permission::ChatJoin,
+                Permission::ChatCreate,
+                Permission::ChatList,
+            ],
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+
+        for channel in ["#english", "#british", "#deutsch", "#untagged"] {
+            let _ = handle_chat_join(
+                channel.to_string(),
+                Some(session_id),
+                &mut test_ctx.handler_context(),
+            )
+            .await;
+            let _ = read_server_message(&mut test_ctx).await; // ChatJoinResponse
+        }
+        for (channel, language) in [
+            ("#english", "en"),
+            ("#british", "en-GB"),
+            ("#deutsch", "de"),
+        ] {
+            test_ctx
+                .channel_manager
+                .set_language(channel, Some(language.to_string()))
+                .await
+                .unwrap();
+        }
+
+        let result = handle_chat_list(
+            Some("EN".to_string()),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::ChatListResponse { channels, .. } => {
+                let mut names: Vec<String> =
+                    channels.unwrap().into_iter().map(|c| c.name).collect();
+                names.sort();
+                assert_eq!(names, vec!["#british", "#english"]);
+            }
+            _ => panic!("Expected ChatListResponse, got {:?}", response),
+        }
+
+        // An invalid filter is rejected
+        let result = handle_chat_list(
+            Some("en_US".to_string()),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::ChatListResponse {
+                success, channels, ..
+            } => {
+                assert!(!success);
+                assert!(channels.is_none());
+            }
+            _ => panic!("Expected ChatListResponse, got {:?}", response),
+        }
+    }
 }
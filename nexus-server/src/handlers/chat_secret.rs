@@ -26,7 +26,7 @@ where
 {
     // Verify authentication first
     let Some(session_id) = session_id else {
-        eprintln!("ChatSecret request from {} without login", ctx.peer_addr);
+        log_warn!("ChatSecret request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("ChatSecret"))
             .await;
@@ -62,9 +62,10 @@ where
 
     // Check ChatSecret permission
     if !user.has_permission(Permission::ChatSecret) {
-        eprintln!(
+        log_warn!(
             "ChatSecret from {} (user: {}) without permission",
-            ctx.peer_addr, user.username
+            ctx.peer_addr,
+            user.username
         );
         let response = ServerMessage::ChatSecretResponse {
             success: false,
@@ -96,7 +97,7 @@ where
             return ctx.send_message(&response).await;
         }
         Err(e) => {
-            eprintln!("Database error setting channel secret mode: {}", e);
+            log_error!("Database error setting channel secret mode: {}", e);
             let response = ServerMessage::ChatSecretResponse {
                 success: false,
                 error: Some(err_database(ctx.locale)),
@@ -125,6 +126,14 @@ where
         topic_set_by: None,
         secret: Some(secret),
         secret_set_by: Some(user.nickname.clone()),
+        voice_limit: None,
+        voice_limit_set_by: None,
+        topic_locked: None,
+        topic_locked_set_by: None,
+        rules: None,
+        rules_set_by: None,
+        language: None,
+        language_set_by: None,
     };
 
     for member_session_id in members {
@@ -0,0 +1,247 @@
+//! Handler for ChatTopicHistory command - list a channel's recent topic changes
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+use nexus_common::validators;
+
+use super::{
+    HandlerContext, channel_error_to_message, err_authentication, err_channel_not_found,
+    err_chat_feature_not_enabled, err_database, err_not_logged_in, err_permission_denied,
+};
+use crate::constants::FEATURE_CHAT;
+use crate::db::Permission;
+
+/// Helper to create an error response with all data fields set to None
+fn error_response(error_msg: String) -> ServerMessage {
+    ServerMessage::ChatTopicHistoryResponse {
+        success: false,
+        error: Some(error_msg),
+        channel: None,
+        changes: None,
+    }
+}
+
+/// Handle ChatTopicHistory command - list a channel's recent topic changes
+pub async fn handle_chat_topic_history<W>(
+    channel: String,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first
+    let Some(session_id) = session_id else {
+        log_warn!(
+            "ChatTopicHistory request from {} without login",
+            ctx.peer_addr
+        );
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("ChatTopicHistory"))
+            .await;
+    };
+
+    // Validate channel name
+    if let Err(e) = validators::validate_channel(&channel) {
+        return ctx
+            .send_message(&error_response(channel_error_to_message(e, ctx.locale)))
+            .await;
+    }
+
+    // Get user from session
+    let user = match ctx.user_manager.get_user_by_session_id(session_id).await {
+        Some(u) => u,
+        None => {
+            return ctx
+                .send_error_and_disconnect(
+                    &err_authentication(ctx.locale),
+                    Some("ChatTopicHistory"),
+                )
+                .await;
+        }
+    };
+
+    // Check chat feature
+    if !user.has_feature(FEATURE_CHAT) {
+        return ctx
+            .send_message(&error_response(err_chat_feature_not_enabled(ctx.locale)))
+            .await;
+    }
+
+    // Check ChatTopic permission (seeing past topics is seeing topics)
+    if !user.has_permission(Permission::ChatTopic) {
+        log_warn!(
+            "ChatTopicHistory from {} (user: {}) without permission",
+            ctx.peer_addr,
+            user.username
+        );
+        return ctx
+            .send_message(&error_response(err_permission_denied(ctx.locale)))
+            .await;
+    }
+
+    // Verify user is a member of the channel
+    // For security, always return "not found" to non-members to avoid leaking
+    // existence of secret channels
+    if !ctx.channel_manager.is_member(&channel, session_id).await {
+        return ctx
+            .send_message(&error_response(err_channel_not_found(ctx.locale, &channel)))
+            .await;
+    }
+
+    let changes = match ctx.channel_manager.topic_history(&channel).await {
+        Ok(Some(changes)) => changes,
+        Ok(None) => {
+            // Channel doesn't exist (race condition - was deleted after membership check)
+            return ctx
+                .send_message(&error_response(err_channel_not_found(ctx.locale, &channel)))
+                .await;
+        }
+        Err(e) => {
+            log_error!("Database error getting channel topic history: {}", e);
+            return ctx
+                .send_message(&error_response(err_database(ctx.locale)))
+                .await;
+        }
+    };
+
+    ctx.send_message(&ServerMessage::ChatTopicHistoryResponse {
+        success: true,
+        error: None,
+        channel: Some(channel),
+        changes: Some(changes),
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::chat_join::handle_chat_join;
+    use crate::handlers::chat_topic_update::handle_chat_topic_update;
+    use crate::handlers::testing::{
+        create_test_context, login_user_with_features, read_server_message,
+    };
+
+    #[tokio::test]
+    async fn test_chat_topic_history_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_chat_topic_history(
+            "#general".to_string(),
+            None,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_err(), "Should disconnect unauthenticated user");
+    }
+
+    #[tokio::test]
+    async fn test_chat_topic_history_requires_membership() {
+        let mut test_ctx = create_test_context().await;
+
+        let session_id = login_user_with_features(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::ChatTopic],
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+
+        let result = handle_chat_topic_history(
+            "#general".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::ChatTopicHistoryResponse {
+                success,
+                error,
+                changes,
+                ..
+            } => {
+                assert!(!success);
+                assert!(error.is_some());
+                assert!(changes.is_none());
+            }
+            _ => panic!("Expected ChatTopicHistoryResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chat_topic_history_lists_changes() {
+        let mut test_ctx = create_test_context().await;
+
+        let session_id = login_user_with_features(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[
+                Permission::ChatJoin,
+                Permission::ChatCreate,
+                Permission::ChatTopic,
+                Permission::ChatTopicEdit,
+            ],
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+
+        let _ = handle_chat_join(
+            "#general".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        let _ = read_server_message(&mut test_ctx).await; // ChatJoinResponse
+
+        for topic in ["First", "Second"] {
+            let _ = handle_chat_topic_update(
+                topic.to_string(),
+                "#general".to_string(),
+                Some(session_id),
+                &mut test_ctx.handler_context(),
+            )
+            .await;
+            let _ = read_server_message(&mut test_ctx).await; // ChatTopicUpdateResponse
+        }
+
+        let result = handle_chat_topic_history(
+            "#general".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::ChatTopicHistoryResponse {
+                success,
+                channel,
+                changes,
+                ..
+            } => {
+                assert!(success);
+                assert_eq!(channel, Some("#general".to_string()));
+                let changes = changes.unwrap();
+                assert_eq!(changes.len(), 2);
+                assert_eq!(changes[0].topic.as_deref(), Some("Second"));
+                assert_eq!(changes[0].old_topic.as_deref(), Some("First"));
+                assert_eq!(changes[0].set_by.as_deref(), Some("alice"));
+                assert_eq!(changes[1].old_topic, None);
+            }
+            _ => panic!("Expected ChatTopicHistoryResponse, got {:?}", response),
+        }
+    }
+}
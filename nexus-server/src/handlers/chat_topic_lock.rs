@@ -0,0 +1,350 @@
+//! Handler for ChatTopicLock command - lock or unlock a channel's topic
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+use nexus_common::validators;
+
+use super::{
+    HandlerContext, channel_error_to_message, err_authentication, err_channel_not_found,
+    err_chat_feature_not_enabled, err_database, err_not_logged_in, err_permission_denied,
+};
+use crate::constants::FEATURE_CHAT;
+use crate::db::Permission;
+
+/// Handle ChatTopicLock command - lock or unlock a channel's topic
+///
+/// While the topic is locked, only users with ChatTopicLock (and admins) may
+/// change it.
+pub async fn handle_chat_topic_lock<W>(
+    channel: String,
+    locked: bool,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first
+    let Some(session_id) = session_id else {
+        log_warn!("ChatTopicLock request from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("ChatTopicLock"))
+            .await;
+    };
+
+    // Validate channel name
+    if let Err(e) = validators::validate_channel(&channel) {
+        let response = ServerMessage::ChatTopicLockResponse {
+            success: false,
+            error: Some(channel_error_to_message(e, ctx.locale)),
+        };
+        return ctx.send_message(&response).await;
+    }
+
+    // Get user from session
+    let user = match ctx.user_manager.get_user_by_session_id(session_id).await {
+        Some(u) => u,
+        None => {
+            return ctx
+                .send_error_and_disconnect(&err_authentication(ctx.locale), Some("ChatTopicLock"))
+                .await;
+        }
+    };
+
+    // Check chat feature
+    if !user.has_feature(FEATURE_CHAT) {
+        let response = ServerMessage::ChatTopicLockResponse {
+            success: false,
+            error: Some(err_chat_feature_not_enabled(ctx.locale)),
+        };
+        return ctx.send_message(&response).await;
+    }
+
+    // Check ChatTopicLock permission
+    if !user.has_permission(Permission::ChatTopicLock) {
+        log_warn!(
+            "ChatTopicLock from {} (user: {}) without permission",
+            ctx.peer_addr,
+            user.username
+        );
+        let response = ServerMessage::ChatTopicLockResponse {
+            success: false,
+            error: Some(err_permission_denied(ctx.locale)),
+        };
+        return ctx.send_message(&response).await;
+    }
+
+    // Verify user is a member of the channel
+    // For security, always return "not found" to non-members to avoid leaking
+    // existence of secret channels
+    if !ctx.channel_manager.is_member(&channel, session_id).await {
+        let response = ServerMessage::ChatTopicLockResponse {
+            success: false,
+            error: Some(err_channel_not_found(ctx.locale, &channel)),
+        };
+        return ctx.send_message(&response).await;
+    }
+
+    // Set the lock (ChannelManager handles persistence for persistent channels)
+    match ctx.channel_manager.set_topic_locked(&channel, locked).await {
+        Ok(true) => {} // Success, channel exists
+        Ok(false) => {
+            // Channel doesn't exist (race condition - was deleted after membership check)
+            let response = ServerMessage::ChatTopicLockResponse {
+                success: false,
+                error: Some(err_channel_not_found(ctx.locale, &channel)),
+            };
+            return ctx.send_message(&response).await;
+        }
+        Err(e) => {
+            log_error!("Database error setting channel topic lock: {}", e);
+            let response = ServerMessage::ChatTopicLockResponse {
+                success: false,
+                error: Some(err_database(ctx.locale)),
+            };
+            return ctx.send_message(&response).await;
+        }
+    }
+
+    // Send success response to the requester
+    let response = ServerMessage::ChatTopicLockResponse {
+        success: true,
+        error: None,
+    };
+    ctx.send_message(&response).await?;
+
+    // Broadcast ChatUpdated to all channel members
+    let members = ctx
+        .channel_manager
+        .get_members(&channel)
+        .await
+        .unwrap_or_default();
+
+    let update_message = ServerMessage::ChatUpdated {
+        channel: channel.clone(),
+        topic: None,
+        topic_set_by: None,
+        secret: None,
+        secret_set_by: None,
+        voice_limit: None,
+        voice_limit_set_by: None,
+        topic_locked: Some(locked),
+        topic_locked_set_by: Some(user.nickname.clone()),
+        rules: None,
+        rules_set_by: None,
+        language: None,
+        language_set_by: None,
+    };
+
+    for member_session_id in members {
+        if let Some(member) = ctx
+            .user_manager
+            .get_user_by_session_id(member_session_id)
+            .await
+        {
+            // Only send to members with chat feature
+            if member.has_feature(FEATURE_CHAT) {
+                ctx.user_manager
+                    .send_to_session(member_session_id, update_message.clone())
+                    .await;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::chat_join::handle_chat_join;
+    use crate::handlers::chat_topic_update::handle_chat_topic_update;
+    use crate::handlers::err_topic_locked;
+    use crate::handlers::testing::{
+        DEFAULT_TEST_LOCALE, create_test_context, login_user, login_user_with_features,
+        read_server_message,
+    };
+
+    #[tokio::test]
+    async fn test_chat_topic_lock_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_chat_topic_lock(
+            "#general".to_string(),
+            true,
+            None,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_err(), "Should disconnect unauthenticated user");
+    }
+
+    #[tokio::test]
+    async fn test_chat_topic_lock_requires_permission() {
+        let mut test_ctx = create_test_context().await;
+
+        // Login user WITHOUT ChatTopicLock permission but WITH chat feature
+        let session_id = login_user_with_features(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::ChatTopicEdit],
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+
+        let result = handle_chat_topic_lock(
+            "#general".to_string(),
+            true,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::ChatTopicLockResponse { success, error } => {
+                assert!(!success);
+                assert!(error.is_some());
+            }
+            _ => panic!("Expected ChatTopicLockResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chat_topic_lock_requires_feature() {
+        let mut test_ctx = create_test_context().await;
+
+        // Login user WITH ChatTopicLock permission but WITHOUT chat feature
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::ChatTopicLock],
+            false,
+        )
+        .await;
+
+        let result = handle_chat_topic_lock(
+            "#general".to_string(),
+            true,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::ChatTopicLockResponse { success, error } => {
+                assert!(!success);
+                assert!(error.is_some());
+            }
+            _ => panic!("Expected ChatTopicLockResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chat_topic_lock_blocks_topic_edit() {
+        let mut test_ctx = create_test_context().await;
+
+        let op_session = login_user_with_features(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[
+                Permission::ChatJoin,
+                Permission::ChatCreate,
+                Permission::ChatTopicEdit,
+                Permission::ChatTopicLock,
+            ],
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+        let user_session = login_user_with_features(
+            &mut test_ctx,
+            "bob",
+            "password",
+            &[Permission::ChatJoin, Permission::ChatTopicEdit],
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+
+        for session_id in [op_session, user_session] {
+            let _ = handle_chat_join(
+                "#general".to_string(),
+                Some(session_id),
+                &mut test_ctx.handler_context(),
+            )
+            .await;
+            let _ = read_server_message(&mut test_ctx).await; // ChatJoinResponse
+        }
+
+        // Lock the topic
+        let result = handle_chat_topic_lock(
+            "#general".to_string(),
+            true,
+            Some(op_session),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::ChatTopicLockResponse { success, error } => {
+                assert!(success);
+                assert!(error.is_none());
+            }
+            _ => panic!("Expected ChatTopicLockResponse, got {:?}", response),
+        }
+        assert!(test_ctx.channel_manager.is_topic_locked("#general").await);
+
+        // A user with only ChatTopicEdit can no longer change the topic
+        let result = handle_chat_topic_update(
+            "New topic".to_string(),
+            "#general".to_string(),
+            Some(user_session),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
+                assert_eq!(message, err_topic_locked(DEFAULT_TEST_LOCALE));
+                assert_eq!(command, Some("ChatTopicUpdate".to_string()));
+            }
+            _ => panic!("Expected Error, got {:?}", response),
+        }
+
+        // The lock holder still can
+        let result = handle_chat_topic_update(
+            "New topic".to_string(),
+            "#general".to_string(),
+            Some(op_session),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::ChatTopicUpdateResponse { success, .. } => assert!(success),
+            _ => panic!("Expected ChatTopicUpdateResponse, got {:?}", response),
+        }
+    }
+}
@@ -10,7 +10,8 @@ use nexus_common::validators::{self, ChatTopicError};
 use super::{
     HandlerContext, channel_error_to_message, err_authentication, err_channel_not_found,
     err_chat_feature_not_enabled, err_database, err_not_logged_in, err_permission_denied,
-    err_topic_contains_newlines, err_topic_invalid_characters, err_topic_too_long,
+    err_topic_contains_newlines, err_topic_invalid_characters, err_topic_locked,
+    err_topic_too_long,
 };
 use crate::constants::FEATURE_CHAT;
 use crate::db::Permission;
@@ -27,7 +28,7 @@ where
 {
     // Verify authentication first (before revealing validation errors to unauthenticated users)
     let Some(id) = session_id else {
-        eprintln!("ChatTopicUpdate from {} without login", ctx.peer_addr);
+        log_warn!("ChatTopicUpdate from {} without login", ctx.peer_addr);
         return ctx
             .send_error(&err_not_logged_in(ctx.locale), Some("ChatTopicUpdate"))
             .await;
@@ -77,9 +78,10 @@ where
 
     // Check ChatTopicEdit permission (uses cached permissions, admin bypass built-in)
     if !user.has_permission(Permission::ChatTopicEdit) {
-        eprintln!(
+        log_warn!(
             "ChatTopicUpdate from {} (user: {}) without permission",
-            ctx.peer_addr, user.username
+            ctx.peer_addr,
+            user.username
         );
         return ctx
             .send_error(&err_permission_denied(ctx.locale), Some("ChatTopicUpdate"))
@@ -98,7 +100,17 @@ where
             .await;
     }
 
+    // A locked topic can only be changed with ChatTopicLock (admin bypass built-in)
+    if !user.has_permission(Permission::ChatTopicLock)
+        && ctx.channel_manager.is_topic_locked(&channel).await
+    {
+        return ctx
+            .send_error(&err_topic_locked(ctx.locale), Some("ChatTopicUpdate"))
+            .await;
+    }
+
     // Update topic in channel manager (handles persistence for persistent channels)
+    // and record the change in the channel's topic history
     let (topic_value, set_by) = if topic.is_empty() {
         (None, None)
     } else {
@@ -107,7 +119,7 @@ where
 
     match ctx
         .channel_manager
-        .set_topic(&channel, topic_value, set_by)
+        .change_topic(&channel, topic_value, set_by, &user.nickname)
         .await
     {
         Ok(true) => {} // Success, channel exists
@@ -121,7 +133,7 @@ where
                 .await;
         }
         Err(e) => {
-            eprintln!("Database error setting topic: {}", e);
+            log_error!("Database error setting topic: {}", e);
             return ctx
                 .send_error(&err_database(ctx.locale), Some("ChatTopicUpdate"))
                 .await;
@@ -142,6 +154,14 @@ where
         topic_set_by: Some(user.nickname.clone()),
         secret: None,
         secret_set_by: None,
+        voice_limit: None,
+        voice_limit_set_by: None,
+        topic_locked: None,
+        topic_locked_set_by: None,
+        rules: None,
+        rules_set_by: None,
+        language: None,
+        language_set_by: None,
     };
 
     // Broadcast ChatUpdated to all channel members with chat feature and ChatTopic permission
@@ -195,7 +215,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert_eq!(message, err_not_logged_in(DEFAULT_TEST_LOCALE));
                 assert_eq!(command, Some("ChatTopicUpdate".to_string()));
             }
@@ -229,7 +251,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert!(message.to_lowercase().contains("channel")); // Error about channel
                 assert_eq!(command, Some("ChatTopicUpdate".to_string()));
             }
@@ -270,7 +294,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert_eq!(message, err_chat_feature_not_enabled(DEFAULT_TEST_LOCALE));
                 assert_eq!(command, Some("ChatTopicUpdate".to_string()));
             }
@@ -312,7 +338,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert_eq!(message, err_permission_denied(DEFAULT_TEST_LOCALE));
                 assert_eq!(command, Some("ChatTopicUpdate".to_string()));
             }
@@ -357,7 +385,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert!(
                     message.contains("256"),
                     "Error should mention max length: {}",
@@ -496,7 +526,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert_eq!(message, err_topic_contains_newlines(DEFAULT_TEST_LOCALE));
                 assert_eq!(command, Some("ChatTopicUpdate".to_string()));
             }
@@ -573,7 +605,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert_eq!(
                     message,
                     err_channel_not_found(DEFAULT_TEST_LOCALE, "#nonexistent")
@@ -614,7 +648,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert_eq!(
                     message,
                     err_channel_not_found(DEFAULT_TEST_LOCALE, "#general")
@@ -21,7 +21,7 @@ where
 {
     // Verify authentication first
     let Some(session_id) = session_id else {
-        eprintln!(
+        log_warn!(
             "ConnectionMonitor request from {} without login",
             ctx.peer_addr
         );
@@ -45,9 +45,10 @@ where
 
     // Check connection_monitor permission
     if !requesting_user.has_permission(Permission::ConnectionMonitor) {
-        eprintln!(
+        log_warn!(
             "ConnectionMonitor from {} (user: {}) without permission",
-            ctx.peer_addr, requesting_user.username
+            ctx.peer_addr,
+            requesting_user.username
         );
         let response = ServerMessage::ConnectionMonitorResponse {
             success: false,
@@ -61,18 +62,25 @@ where
     // Get all active sessions from user manager
     let sessions = ctx.user_manager.get_all_users().await;
 
-    let mut connections: Vec<ConnectionInfo> = sessions
-        .into_iter()
-        .map(|s| ConnectionInfo {
-            nickname: s.nickname,
-            username: s.username,
-            ip: s.address.ip().to_string(),
-            port: s.address.port(),
-            login_time: s.login_time,
-            is_admin: s.is_admin,
-            is_shared: s.is_shared,
-        })
-        .collect();
+    let mut connections: Vec<ConnectionInfo> = {
+        let ip_rule_cache = ctx
+            .ip_rule_cache
+            .read()
+            .expect("ip rule cache lock poisoned");
+        sessions
+            .into_iter()
+            .map(|s| ConnectionInfo {
+                listed_in: ip_rule_cache.cached_reputation(s.address.ip()).flatten(),
+                nickname: s.nickname,
+                username: s.username,
+                ip: s.address.ip().to_string(),
+                port: s.address.port(),
+                login_time: s.login_time,
+                is_admin: s.is_admin,
+                is_shared: s.is_shared,
+            })
+            .collect()
+    };
 
     // Sort alphabetically by nickname
     connections.sort_by(|a, b| a.nickname.to_lowercase().cmp(&b.nickname.to_lowercase()));
@@ -165,6 +173,31 @@ mod tests {
             assert_eq!(admin_conn.nickname, "admin");
             assert!(admin_conn.is_admin);
             assert!(!admin_conn.is_shared);
+            assert!(admin_conn.listed_in.is_none());
+        } else {
+            panic!("Expected ConnectionMonitorResponse, got: {:?}", response);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_connection_monitor_shows_blocklist_listing() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        test_ctx.ip_rule_cache.write().unwrap().set_reputation(
+            test_ctx.peer_addr.ip(),
+            Some("dnsbl.example".to_string()),
+            crate::handlers::current_timestamp() + 3600,
+        );
+
+        let result =
+            handle_connection_monitor(Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx).await;
+        if let ServerMessage::ConnectionMonitorResponse { connections, .. } = response {
+            let connections = connections.unwrap();
+            assert_eq!(connections[0].listed_in.as_deref(), Some("dnsbl.example"));
         } else {
             panic!("Expected ConnectionMonitorResponse, got: {:?}", response);
         }
@@ -0,0 +1,159 @@
+//! Handler for ConnectivityCheck command
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+
+use super::{
+    HandlerContext, err_authentication, err_connectivity_check_failed,
+    err_connectivity_check_not_configured, err_not_logged_in, err_permission_denied,
+};
+use crate::reachability;
+
+/// Handle ConnectivityCheck command
+///
+/// Asks the configured check service to connect back to the server's ports
+/// (admin only). The check takes a few seconds, so it runs in the background
+/// and the response is queued on the connection's channel when it's done.
+pub async fn handle_connectivity_check<W>(
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first
+    let Some(session_id) = session_id else {
+        log_warn!(
+            "ConnectivityCheck request from {} without login",
+            ctx.peer_addr
+        );
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("ConnectivityCheck"))
+            .await;
+    };
+
+    // Get requesting user from session
+    let requesting_user = match ctx.user_manager.get_user_by_session_id(session_id).await {
+        Some(user) => user,
+        None => {
+            return ctx
+                .send_error_and_disconnect(
+                    &err_authentication(ctx.locale),
+                    Some("ConnectivityCheck"),
+                )
+                .await;
+        }
+    };
+
+    // Network diagnostics are admin only, like the port forwarding state
+    if !requesting_user.is_admin {
+        log_warn!(
+            "ConnectivityCheck from {} (user: {}) without permission",
+            ctx.peer_addr,
+            requesting_user.username
+        );
+        return ctx
+            .send_message(&failure(err_permission_denied(ctx.locale)))
+            .await;
+    }
+
+    let Some(config) = ctx.check_config.clone() else {
+        return ctx
+            .send_message(&failure(err_connectivity_check_not_configured(ctx.locale)))
+            .await;
+    };
+
+    let tx = ctx.tx.clone();
+    let message_id = ctx.message_id;
+    let locale = ctx.locale.to_string();
+    tokio::spawn(async move {
+        let response =
+            match reachability::run_check(&config, std::net::Ipv4Addr::UNSPECIFIED.into(), false)
+                .await
+            {
+                Ok(report) => ServerMessage::ConnectivityCheckResponse {
+                    success: true,
+                    error: None,
+                    external_ip: report.external_ip,
+                    ports: Some(report.ports),
+                },
+                Err(e) => failure(err_connectivity_check_failed(&locale, &e)),
+            };
+        // The client may have disconnected in the meantime
+        let _ = tx.send((response, Some(message_id)));
+    });
+
+    Ok(())
+}
+
+/// Build a failed ConnectivityCheckResponse
+fn failure(error: String) -> ServerMessage {
+    ServerMessage::ConnectivityCheckResponse {
+        success: false,
+        error: Some(error),
+        external_ip: None,
+        ports: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::testing::{create_test_context, login_user, read_server_message};
+
+    #[tokio::test]
+    async fn test_connectivity_check_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_connectivity_check(None, &mut test_ctx.handler_context()).await;
+
+        assert!(result.is_err(), "ConnectivityCheck should require login");
+    }
+
+    #[tokio::test]
+    async fn test_connectivity_check_requires_admin() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let result =
+            handle_connectivity_check(Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok(), "Should send error response, not disconnect");
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::ConnectivityCheckResponse {
+                success,
+                error,
+                ports,
+                ..
+            } => {
+                assert!(!success);
+                assert!(error.is_some());
+                assert!(ports.is_none());
+            }
+            _ => panic!("Expected ConnectivityCheckResponse, got: {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_connectivity_check_not_configured() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        let result =
+            handle_connectivity_check(Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::ConnectivityCheckResponse { success, error, .. } => {
+                assert!(!success);
+                assert!(error.unwrap().contains("--check-service"));
+            }
+            _ => panic!("Expected ConnectivityCheckResponse, got: {:?}", response),
+        }
+    }
+}
@@ -0,0 +1,354 @@
+//! Handlers for DoorList, DoorOpen, DoorData and DoorClose commands
+
+use std::io;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+
+use super::{
+    HandlerContext, err_authentication, err_door_failed, err_door_not_found, err_not_logged_in,
+    err_permission_denied,
+};
+use crate::db::Permission;
+use crate::doors::{DoorSession, DoorUser, MAX_DOOR_COLUMNS, MAX_DOOR_ROWS};
+
+/// Handle DoorList command
+///
+/// Returns the names of the doors configured with `--door`.
+pub async fn handle_door_list<W>(
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first
+    let Some(session_id) = session_id else {
+        log_warn!("DoorList request from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("DoorList"))
+            .await;
+    };
+
+    // Get requesting user from session
+    let requesting_user = match ctx.user_manager.get_user_by_session_id(session_id).await {
+        Some(user) => user,
+        None => {
+            return ctx
+                .send_error_and_disconnect(&err_authentication(ctx.locale), Some("DoorList"))
+                .await;
+        }
+    };
+
+    // Check door_play permission
+    if !requesting_user.has_permission(Permission::DoorPlay) {
+        log_warn!(
+            "DoorList from {} (user: {}) without permission",
+            ctx.peer_addr,
+            requesting_user.username
+        );
+        let response = ServerMessage::DoorListResponse {
+            success: false,
+            error: Some(err_permission_denied(ctx.locale)),
+            doors: None,
+        };
+        return ctx.send_message(&response).await;
+    }
+
+    let response = ServerMessage::DoorListResponse {
+        success: true,
+        error: None,
+        doors: Some(ctx.doors.names()),
+    };
+    ctx.send_message(&response).await
+}
+
+/// Handle DoorOpen command
+///
+/// Starts the named door on a pseudo-terminal of the requested size (clamped
+/// to the supported range). Its output is streamed as `DoorData` until it
+/// exits, followed by `DoorClosed`. `door` holds this connection's running
+/// door; opening another one ends it first.
+pub async fn handle_door_open<W>(
+    name: String,
+    cols: u16,
+    rows: u16,
+    session_id: Option<u32>,
+    door: &mut Option<DoorSession>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first
+    let Some(session_id) = session_id else {
+        log_warn!("DoorOpen request from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("DoorOpen"))
+            .await;
+    };
+
+    // Get requesting user from session
+    let requesting_user = match ctx.user_manager.get_user_by_session_id(session_id).await {
+        Some(user) => user,
+        None => {
+            return ctx
+                .send_error_and_disconnect(&err_authentication(ctx.locale), Some("DoorOpen"))
+                .await;
+        }
+    };
+
+    // Check door_play permission
+    if !requesting_user.has_permission(Permission::DoorPlay) {
+        log_warn!(
+            "DoorOpen from {} (user: {}) without permission",
+            ctx.peer_addr,
+            requesting_user.username
+        );
+        return ctx
+            .send_message(&failure(err_permission_denied(ctx.locale)))
+            .await;
+    }
+
+    let Some(config) = ctx.doors.find(&name) else {
+        return ctx
+            .send_message(&failure(err_door_not_found(ctx.locale, &name)))
+            .await;
+    };
+
+    // End the previous door before starting another
+    door.take();
+
+    let user = DoorUser {
+        username: &requesting_user.username,
+        nickname: &requesting_user.nickname,
+    };
+    let cols = cols.clamp(1, MAX_DOOR_COLUMNS);
+    let rows = rows.clamp(1, MAX_DOOR_ROWS);
+    match DoorSession::start(config, cols, rows, user, ctx.tx) {
+        Ok(session) => {
+            log_info!(
+                "User '{}' opened door '{}' ({}x{})",
+                requesting_user.username,
+                config.name,
+                cols,
+                rows
+            );
+            *door = Some(session);
+            let response = ServerMessage::DoorOpenResponse {
+                success: true,
+                error: None,
+            };
+            ctx.send_message(&response).await
+        }
+        Err(e) => {
+            log_error!("Failed to start door '{}': {}", config.name, e);
+            ctx.send_message(&failure(err_door_failed(ctx.locale, &config.name)))
+                .await
+        }
+    }
+}
+
+/// Handle DoorData command
+///
+/// Passes keyboard input to the running door. There is no response; input
+/// without a running door and undecodable data are dropped.
+pub async fn handle_door_data<W>(
+    data: String,
+    session_id: Option<u32>,
+    door: &Option<DoorSession>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first
+    if session_id.is_none() {
+        log_warn!("DoorData request from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("DoorData"))
+            .await;
+    }
+
+    if let Some(session) = door
+        && let Ok(bytes) = BASE64.decode(data)
+    {
+        session.write(bytes);
+    }
+    Ok(())
+}
+
+/// Handle DoorClose command
+///
+/// Ends the running door. There is no response.
+pub fn handle_door_close(door: &mut Option<DoorSession>) {
+    if let Some(session) = door.take() {
+        log_debug!("Door '{}' closed by client", session.name);
+    }
+}
+
+/// Build a failed DoorOpenResponse
+fn failure(error: String) -> ServerMessage {
+    ServerMessage::DoorOpenResponse {
+        success: false,
+        error: Some(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::doors::{Door, Doors};
+    use crate::handlers::testing::{create_test_context, login_user, read_server_message};
+
+    /// The doors used by these tests
+    fn test_doors() -> Doors {
+        let door: Door = "echo=/bin/echo door test".parse().unwrap();
+        Doors::new(vec![door])
+    }
+
+    #[tokio::test]
+    async fn test_door_list_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_door_list(None, &mut test_ctx.handler_context()).await;
+
+        assert!(result.is_err(), "Should disconnect when not logged in");
+    }
+
+    #[tokio::test]
+    async fn test_door_list_requires_permission() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let result = handle_door_list(Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::DoorListResponse {
+                success,
+                error,
+                doors,
+            } => {
+                assert!(!success);
+                assert!(error.is_some());
+                assert!(doors.is_none());
+            }
+            _ => panic!("Expected DoorListResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_door_list_returns_configured_doors() {
+        let mut test_ctx = create_test_context().await;
+        test_ctx.doors = test_doors();
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::DoorPlay],
+            false,
+        )
+        .await;
+
+        let result = handle_door_list(Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::DoorListResponse { success, doors, .. } => {
+                assert!(success);
+                assert_eq!(doors, Some(vec!["echo".to_string()]));
+            }
+            _ => panic!("Expected DoorListResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_door_open_unknown_door() {
+        let mut test_ctx = create_test_context().await;
+        test_ctx.doors = test_doors();
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+        let mut door = None;
+
+        let result = handle_door_open(
+            "missing".to_string(),
+            80,
+            24,
+            Some(session_id),
+            &mut door,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+        assert!(door.is_none());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::DoorOpenResponse { success, error } => {
+                assert!(!success);
+                assert!(error.unwrap().contains("missing"));
+            }
+            _ => panic!("Expected DoorOpenResponse, got {:?}", response),
+        }
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_door_open_streams_output() {
+        let mut test_ctx = create_test_context().await;
+        test_ctx.doors = test_doors();
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::DoorPlay],
+            false,
+        )
+        .await;
+        let mut door = None;
+
+        let result = handle_door_open(
+            "echo".to_string(),
+            80,
+            24,
+            Some(session_id),
+            &mut door,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+        assert!(door.is_some());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::DoorOpenResponse { success, .. } => assert!(success),
+            _ => panic!("Expected DoorOpenResponse, got {:?}", response),
+        }
+
+        // Output and the exit are streamed through the connection's queue
+        let mut output = Vec::new();
+        let closed = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            while let Some((message, _)) = test_ctx.rx.recv().await {
+                match message {
+                    ServerMessage::DoorData { data } => {
+                        output.extend(BASE64.decode(data).unwrap());
+                    }
+                    ServerMessage::DoorClosed { exit_code } => return exit_code == Some(0),
+                    _ => {}
+                }
+            }
+            false
+        })
+        .await;
+        assert_eq!(closed, Ok(true));
+        assert!(String::from_utf8_lossy(&output).contains("door test"));
+
+        handle_door_close(&mut door);
+        assert!(door.is_none());
+    }
+}
@@ -36,6 +36,11 @@ pub fn err_nickname_required(locale: &str) -> String {
     t(locale, "err-nickname-required")
 }
 
+/// Get translated "nickname registered" error (reserved by another account)
+pub fn err_nickname_registered(locale: &str) -> String {
+    t(locale, "err-nickname-registered")
+}
+
 /// Get translated "nickname too long" error
 pub fn err_nickname_too_long(locale: &str, max_length: usize) -> String {
     t_args(
@@ -45,6 +50,42 @@ pub fn err_nickname_too_long(locale: &str, max_length: usize) -> String {
     )
 }
 
+// ========================================================================
+// Nickname Registration Errors
+// ========================================================================
+
+/// Get translated "nickname already registered" error
+pub fn err_nickname_already_registered(locale: &str, nickname: &str) -> String {
+    t_args(
+        locale,
+        "err-nickname-already-registered",
+        &[("nickname", nickname)],
+    )
+}
+
+/// Get translated "nickname not registered" error
+pub fn err_nickname_not_registered(locale: &str, nickname: &str) -> String {
+    t_args(
+        locale,
+        "err-nickname-not-registered",
+        &[("nickname", nickname)],
+    )
+}
+
+/// Get translated "nickname register limit" error
+pub fn err_nickname_register_limit(locale: &str, max: u32) -> String {
+    t_args(
+        locale,
+        "err-nickname-register-limit",
+        &[("max", &max.to_string())],
+    )
+}
+
+/// Get translated "nickname register shared" error
+pub fn err_nickname_register_shared(locale: &str) -> String {
+    t(locale, "err-nickname-register-shared")
+}
+
 // ========================================================================
 // Shared Account Errors
 // ========================================================================
@@ -115,6 +156,30 @@ pub fn err_cannot_delete_guest(locale: &str) -> String {
     t(locale, "err-cannot-delete-guest")
 }
 
+/// Get translated "guest invalid permissions" error
+pub fn err_guest_invalid_permissions(locale: &str, permissions: &str) -> String {
+    t_args(
+        locale,
+        "err-guest-invalid-permissions",
+        &[("permissions", permissions)],
+    )
+}
+
+/// Get translated "guest limit" error
+pub fn err_guest_limit(locale: &str, max: u32) -> String {
+    t_args(locale, "err-guest-limit", &[("max", &max.to_string())])
+}
+
+/// Get translated "guest upgrade disabled" error
+pub fn err_guest_upgrade_disabled(locale: &str) -> String {
+    t(locale, "err-guest-upgrade-disabled")
+}
+
+/// Get translated "guest upgrade not guest" error
+pub fn err_guest_upgrade_not_guest(locale: &str) -> String {
+    t(locale, "err-guest-upgrade-not-guest")
+}
+
 // ========================================================================
 // Account & Session Errors
 // ========================================================================
@@ -139,6 +204,40 @@ pub fn err_already_logged_in(locale: &str) -> String {
     t(locale, "err-already-logged-in")
 }
 
+/// Get translated "single sign-on unavailable" error
+pub fn err_sso_unavailable(locale: &str) -> String {
+    t(locale, "err-sso-unavailable")
+}
+
+/// Get translated "single sign-on failed" error
+pub fn err_sso_failed(locale: &str) -> String {
+    t(locale, "err-sso-failed")
+}
+
+/// Get translated "login token expired" error
+pub fn err_login_token_expired(locale: &str) -> String {
+    t(locale, "err-login-token-expired")
+}
+
+/// Get translated "single sign-on username unavailable" error
+pub fn err_sso_username_unavailable(locale: &str, username: &str) -> String {
+    t_args(
+        locale,
+        "err-sso-username-unavailable",
+        &[("username", username)],
+    )
+}
+
+/// Get translated "directory unavailable" error
+pub fn err_ldap_unavailable(locale: &str) -> String {
+    t(locale, "err-ldap-unavailable")
+}
+
+/// Get translated "not in any mapped directory group" error
+pub fn err_ldap_no_group(locale: &str) -> String {
+    t(locale, "err-ldap-no-group")
+}
+
 /// Get translated "authentication" error
 pub fn err_authentication(locale: &str) -> String {
     t(locale, "err-authentication")
@@ -163,6 +262,20 @@ pub fn err_avatar_unsupported_type(locale: &str) -> String {
     t(locale, "err-avatar-unsupported-type")
 }
 
+/// Get translated "broadcast invalid delay" error (invalid delay format)
+pub fn err_broadcast_invalid_delay(locale: &str) -> String {
+    t(locale, "err-broadcast-invalid-delay")
+}
+
+/// Get translated "too many scheduled broadcasts" error
+pub fn err_broadcast_too_many_scheduled(locale: &str, max_count: usize) -> String {
+    t_args(
+        locale,
+        "err-broadcast-too-many-scheduled",
+        &[("max_count", &max_count.to_string())],
+    )
+}
+
 /// Get translated "broadcast too long" error
 pub fn err_broadcast_too_long(locale: &str, max_length: usize) -> String {
     t_args(
@@ -237,6 +350,104 @@ pub fn err_cannot_message_self(locale: &str) -> String {
     t(locale, "err-cannot-message-self")
 }
 
+/// Get translated "message history retention too long" error
+pub fn err_message_history_days_too_long(locale: &str, max_days: u32) -> String {
+    t_args(
+        locale,
+        "err-message-history-days-too-long",
+        &[("max", &max_days.to_string())],
+    )
+}
+
+/// Get translated "shared accounts have no message history" error
+pub fn err_message_history_shared(locale: &str) -> String {
+    t(locale, "err-message-history-shared")
+}
+
+/// Get translated "name at least one user for a group conversation" error
+pub fn err_group_no_members(locale: &str) -> String {
+    t(locale, "err-group-no-members")
+}
+
+/// Get translated "too many members in a group conversation" error
+pub fn err_group_too_many_members(locale: &str, max_members: usize) -> String {
+    t_args(
+        locale,
+        "err-group-too-many-members",
+        &[("max", &max_members.to_string())],
+    )
+}
+
+/// Get translated "too many group conversations started" error
+pub fn err_group_limit(locale: &str, max_groups: usize) -> String {
+    t_args(
+        locale,
+        "err-group-limit",
+        &[("max", &max_groups.to_string())],
+    )
+}
+
+/// Get translated "group conversation not found" error
+pub fn err_group_not_found(locale: &str) -> String {
+    t(locale, "err-group-not-found")
+}
+
+/// Get translated "already in this group conversation" error
+pub fn err_group_already_member(locale: &str, nickname: &str) -> String {
+    t_args(
+        locale,
+        "err-group-already-member",
+        &[("nickname", nickname)],
+    )
+}
+
+/// Get translated "snippet is empty" error
+pub fn err_snippet_empty(locale: &str) -> String {
+    t(locale, "err-snippet-empty")
+}
+
+/// Get translated "snippet too long" error
+pub fn err_snippet_too_long(locale: &str, max_length: usize) -> String {
+    t_args(
+        locale,
+        "err-snippet-too-long",
+        &[("max_length", &max_length.to_string())],
+    )
+}
+
+/// Get translated "snippet invalid characters" error
+pub fn err_snippet_invalid_characters(locale: &str) -> String {
+    t(locale, "err-snippet-invalid-characters")
+}
+
+/// Get translated "invalid snippet language" error
+pub fn err_snippet_language_invalid(locale: &str, language: &str) -> String {
+    t_args(
+        locale,
+        "err-snippet-language-invalid",
+        &[("language", language)],
+    )
+}
+
+/// Get translated "snippet expiry out of range" error
+pub fn err_snippet_expiry_invalid(locale: &str, max_days: u32) -> String {
+    t_args(
+        locale,
+        "err-snippet-expiry-invalid",
+        &[("max_days", &max_days.to_string())],
+    )
+}
+
+/// Get translated "snippet not found" error
+pub fn err_snippet_not_found(locale: &str) -> String {
+    t(locale, "err-snippet-not-found")
+}
+
+/// Get translated "too many snippets" error
+pub fn err_snippet_limit(locale: &str, max: i64) -> String {
+    t_args(locale, "err-snippet-limit", &[("max", &max.to_string())])
+}
+
 /// Get translated "chat feature not enabled" error
 pub fn err_chat_feature_not_enabled(locale: &str) -> String {
     t(locale, "err-chat-feature-not-enabled")
@@ -384,6 +595,11 @@ pub fn err_handshake_required(locale: &str) -> String {
     t(locale, "err-handshake-required")
 }
 
+/// Get translated "proof of work required" error
+pub fn err_proof_of_work_required(locale: &str) -> String {
+    t(locale, "err-proof-of-work-required")
+}
+
 /// Get translated "invalid credentials" error
 pub fn err_invalid_credentials(locale: &str) -> String {
     t(locale, "err-invalid-credentials")
@@ -442,6 +658,11 @@ pub fn err_message_invalid_characters(locale: &str) -> String {
     t(locale, "err-message-invalid-characters")
 }
 
+/// Get translated "invalid idempotency key" error
+pub fn err_idempotency_key_invalid(locale: &str) -> String {
+    t(locale, "err-idempotency-key-invalid")
+}
+
 /// Get translated "not logged in" error
 pub fn err_not_logged_in(locale: &str) -> String {
     t(locale, "err-not-logged-in")
@@ -509,6 +730,11 @@ pub fn err_topic_invalid_characters(locale: &str) -> String {
     t(locale, "err-topic-invalid-characters")
 }
 
+/// Get translated "topic locked" error
+pub fn err_topic_locked(locale: &str) -> String {
+    t(locale, "err-topic-locked")
+}
+
 /// Get translated "topic too long" error
 pub fn err_topic_too_long(locale: &str, max_length: usize) -> String {
     t_args(
@@ -518,6 +744,48 @@ pub fn err_topic_too_long(locale: &str, max_length: usize) -> String {
     )
 }
 
+/// Get translated "entry message too long" error
+pub fn err_entry_message_too_long(locale: &str, max_length: usize) -> String {
+    t_args(
+        locale,
+        "err-entry-message-too-long",
+        &[("max_length", &max_length.to_string())],
+    )
+}
+
+/// Get translated "entry message invalid characters" error
+pub fn err_entry_message_invalid_characters(locale: &str) -> String {
+    t(locale, "err-entry-message-invalid-characters")
+}
+
+/// Get translated "rules too long" error
+pub fn err_rules_too_long(locale: &str, max_length: usize) -> String {
+    t_args(
+        locale,
+        "err-rules-too-long",
+        &[("max_length", &max_length.to_string())],
+    )
+}
+
+/// Get translated "rules invalid characters" error
+pub fn err_rules_invalid_characters(locale: &str) -> String {
+    t(locale, "err-rules-invalid-characters")
+}
+
+/// Get translated "channel language too long" error
+pub fn err_channel_language_too_long(locale: &str, max_length: usize) -> String {
+    t_args(
+        locale,
+        "err-channel-language-too-long",
+        &[("max_length", &max_length.to_string())],
+    )
+}
+
+/// Get translated "channel language invalid" error
+pub fn err_channel_language_invalid(locale: &str) -> String {
+    t(locale, "err-channel-language-invalid")
+}
+
 /// Get translated "unknown permission" error
 pub fn err_unknown_permission(locale: &str, permission: &str) -> String {
     t_args(
@@ -701,8 +969,12 @@ pub fn err_news_body_invalid_characters(locale: &str) -> String {
 }
 
 /// Get translated "news image too large" error
-pub fn err_news_image_too_large(locale: &str) -> String {
-    t(locale, "err-news-image-too-large")
+pub fn err_news_image_too_large(locale: &str, max_kb: u32) -> String {
+    t_args(
+        locale,
+        "err-news-image-too-large",
+        &[("max_kb", &max_kb.to_string())],
+    )
 }
 
 /// Get translated "news image invalid format" error
@@ -715,7 +987,30 @@ pub fn err_news_image_unsupported_type(locale: &str) -> String {
     t(locale, "err-news-image-unsupported-type")
 }
 
-/// Get translated "news empty content" error (neither body nor image provided)
+/// Get translated "news too many attachments" error
+pub fn err_news_too_many_attachments(locale: &str, max: usize) -> String {
+    t_args(
+        locale,
+        "err-news-too-many-attachments",
+        &[("max", &max.to_string())],
+    )
+}
+
+/// Get translated "news caption too long" error
+pub fn err_news_caption_too_long(locale: &str, max_length: usize) -> String {
+    t_args(
+        locale,
+        "err-news-caption-too-long",
+        &[("max_length", &max_length.to_string())],
+    )
+}
+
+/// Get translated "news caption invalid characters" error
+pub fn err_news_caption_invalid_characters(locale: &str) -> String {
+    t(locale, "err-news-caption-invalid-characters")
+}
+
+/// Get translated "news empty content" error (neither body nor attachments provided)
 pub fn err_news_empty_content(locale: &str) -> String {
     t(locale, "err-news-empty-content")
 }
@@ -730,6 +1025,11 @@ pub fn err_cannot_delete_admin_news(locale: &str) -> String {
     t(locale, "err-cannot-delete-admin-news")
 }
 
+/// Get translated "news search date invalid" error
+pub fn err_news_search_date_invalid(locale: &str, date: &str) -> String {
+    t_args(locale, "err-news-search-date-invalid", &[("date", date)])
+}
+
 // =============================================================================
 // File Area Errors
 // =============================================================================
@@ -895,6 +1195,11 @@ pub fn err_upload_write_failed(locale: &str) -> String {
     t(locale, "err-upload-write-failed")
 }
 
+/// Get translated "not enough free space for the upload" error
+pub fn err_upload_insufficient_storage(locale: &str) -> String {
+    t(locale, "err-upload-insufficient-storage")
+}
+
 /// Get translated "upload hash mismatch" error
 pub fn err_upload_hash_mismatch(locale: &str) -> String {
     t(locale, "err-upload-hash-mismatch")
@@ -1057,6 +1362,223 @@ pub fn err_search_failed(locale: &str) -> String {
     t(locale, "err-search-failed")
 }
 
+// ========================================================================
+// Connectivity Check Errors
+// ========================================================================
+
+/// Get translated "connectivity checks not configured" error
+pub fn err_connectivity_check_not_configured(locale: &str) -> String {
+    t(locale, "err-connectivity-check-not-configured")
+}
+
+/// Get translated "connectivity check failed" error
+pub fn err_connectivity_check_failed(locale: &str, reason: &str) -> String {
+    t_args(
+        locale,
+        "err-connectivity-check-failed",
+        &[("reason", reason)],
+    )
+}
+
+// ========================================================================
+// Door Errors
+// ========================================================================
+
+/// Get translated "door not found" error
+pub fn err_door_not_found(locale: &str, name: &str) -> String {
+    t_args(locale, "err-door-not-found", &[("name", name)])
+}
+
+/// Get translated "door failed to start" error
+pub fn err_door_failed(locale: &str, name: &str) -> String {
+    t_args(locale, "err-door-failed", &[("name", name)])
+}
+
+// ========================================================================
+// File Review Errors
+// ========================================================================
+
+/// Get translated "invalid file rating" error
+pub fn err_file_rating_invalid(locale: &str) -> String {
+    t(locale, "err-file-rating-invalid")
+}
+
+/// Get translated "directories can't be reviewed" error
+pub fn err_file_review_directory(locale: &str) -> String {
+    t(locale, "err-file-review-directory")
+}
+
+/// Get translated "file comment empty" error
+pub fn err_file_comment_empty(locale: &str) -> String {
+    t(locale, "err-file-comment-empty")
+}
+
+/// Get translated "file comment too long" error
+pub fn err_file_comment_too_long(locale: &str, max_length: usize) -> String {
+    t_args(
+        locale,
+        "err-file-comment-too-long",
+        &[("max_length", &max_length.to_string())],
+    )
+}
+
+/// Get translated "file comment invalid characters" error
+pub fn err_file_comment_invalid_characters(locale: &str) -> String {
+    t(locale, "err-file-comment-invalid-characters")
+}
+
+/// Get translated "file comment not found" error
+pub fn err_file_comment_not_found(locale: &str, id: i64) -> String {
+    t_args(
+        locale,
+        "err-file-comment-not-found",
+        &[("id", &id.to_string())],
+    )
+}
+
+// ========================================================================
+// Upload Rule Errors
+// ========================================================================
+
+/// Format a size limit for upload rule errors (human-readable)
+fn format_limit(size: u64) -> String {
+    const KB: u64 = 1024;
+    const MB: u64 = KB * 1024;
+    const GB: u64 = MB * 1024;
+
+    if size >= GB {
+        format!("{:.1} GB", size as f64 / GB as f64)
+    } else if size >= MB {
+        format!("{:.1} MB", size as f64 / MB as f64)
+    } else if size >= KB {
+        format!("{:.1} KB", size as f64 / KB as f64)
+    } else {
+        format!("{size} B")
+    }
+}
+
+/// Get translated "file type not allowed here" error
+pub fn err_upload_rule_extension(locale: &str, name: &str, extensions: &[String]) -> String {
+    t_args(
+        locale,
+        "err-upload-rule-extension",
+        &[("name", name), ("extensions", &extensions.join(", "))],
+    )
+}
+
+/// Get translated "file larger than the folder allows" error
+pub fn err_upload_rule_file_size(locale: &str, name: &str, max_size: u64) -> String {
+    t_args(
+        locale,
+        "err-upload-rule-file-size",
+        &[("name", name), ("max_size", &format_limit(max_size))],
+    )
+}
+
+/// Get translated "upload larger than the folder allows" error
+pub fn err_upload_rule_total_size(locale: &str, max_size: u64) -> String {
+    t_args(
+        locale,
+        "err-upload-rule-total-size",
+        &[("max_size", &format_limit(max_size))],
+    )
+}
+
+/// Get translated "too many upload rule extensions" error
+pub fn err_upload_rule_too_many_extensions(locale: &str, max_count: usize) -> String {
+    t_args(
+        locale,
+        "err-upload-rule-too-many-extensions",
+        &[("max_count", &max_count.to_string())],
+    )
+}
+
+/// Get translated "invalid upload rule extension" error
+pub fn err_upload_rule_invalid_extension(locale: &str, extension: &str) -> String {
+    t_args(
+        locale,
+        "err-upload-rule-invalid-extension",
+        &[("extension", extension)],
+    )
+}
+
+/// Get translated "upload rules are set on directories" error
+pub fn err_upload_rule_not_directory(locale: &str) -> String {
+    t(locale, "err-upload-rule-not-directory")
+}
+
+// ========================================================================
+// Directory Group Errors
+// ========================================================================
+
+/// Get translated "group empty" error
+pub fn err_ldap_group_empty(locale: &str) -> String {
+    t(locale, "err-ldap-group-empty")
+}
+
+/// Get translated "group too long" error
+pub fn err_ldap_group_too_long(locale: &str, max_length: usize) -> String {
+    t_args(
+        locale,
+        "err-ldap-group-too-long",
+        &[("max_length", &max_length.to_string())],
+    )
+}
+
+/// Get translated "group invalid characters" error
+pub fn err_ldap_group_invalid_characters(locale: &str) -> String {
+    t(locale, "err-ldap-group-invalid-characters")
+}
+
+/// Get translated "group not mapped" error
+pub fn err_ldap_group_not_found(locale: &str, group: &str) -> String {
+    t_args(locale, "err-ldap-group-not-found", &[("group", group)])
+}
+
+// ========================================================================
+// File Stat Errors
+// ========================================================================
+
+/// Get translated "no files to check" error
+pub fn err_file_stat_empty(locale: &str) -> String {
+    t(locale, "err-file-stat-empty")
+}
+
+/// Get translated "too many files to check" error
+pub fn err_file_stat_too_many(locale: &str, max_count: usize) -> String {
+    t_args(
+        locale,
+        "err-file-stat-too-many",
+        &[("max_count", &max_count.to_string())],
+    )
+}
+
+// ========================================================================
+// File Watch Errors
+// ========================================================================
+
+/// Get translated "too many watched folders" error
+pub fn err_file_watch_too_many(locale: &str, max_count: usize) -> String {
+    t_args(
+        locale,
+        "err-file-watch-too-many",
+        &[("max_count", &max_count.to_string())],
+    )
+}
+
+// ========================================================================
+// Presence Errors
+// ========================================================================
+
+/// Get translated "too many watched users" error
+pub fn err_presence_too_many(locale: &str, max_count: usize) -> String {
+    t_args(
+        locale,
+        "err-presence-too-many",
+        &[("max_count", &max_count.to_string())],
+    )
+}
+
 // ========================================================================
 // Voice Errors
 // ========================================================================
@@ -1098,3 +1620,70 @@ pub fn err_voice_target_not_online(locale: &str, nickname: &str) -> String {
 pub fn err_voice_invalid_target(locale: &str) -> String {
     t(locale, "err-voice-invalid-target")
 }
+
+/// Get translated "broadcasts only in channels" error
+pub fn err_voice_broadcast_channel_only(locale: &str) -> String {
+    t(locale, "err-voice-broadcast-channel-only")
+}
+
+/// Get translated "voice in use, cannot broadcast" error
+pub fn err_voice_broadcast_busy(locale: &str, channel: &str) -> String {
+    t_args(locale, "err-voice-broadcast-busy", &[("channel", channel)])
+}
+
+/// Get translated "clip name empty" error
+pub fn err_voice_clip_name_empty(locale: &str) -> String {
+    t(locale, "err-voice-clip-name-empty")
+}
+
+/// Get translated "clip name too long" error
+pub fn err_voice_clip_name_too_long(locale: &str, max_length: usize) -> String {
+    t_args(
+        locale,
+        "err-voice-clip-name-too-long",
+        &[("max_length", &max_length.to_string())],
+    )
+}
+
+/// Get translated "clip name invalid characters" error
+pub fn err_voice_clip_name_invalid(locale: &str) -> String {
+    t(locale, "err-voice-clip-name-invalid")
+}
+
+/// Get translated "clip too large" error
+pub fn err_voice_clip_too_large(locale: &str) -> String {
+    t(locale, "err-voice-clip-too-large")
+}
+
+/// Get translated "clip must be Ogg Vorbis" error
+pub fn err_voice_clip_invalid_format(locale: &str) -> String {
+    t(locale, "err-voice-clip-invalid-format")
+}
+
+/// Get translated "clip too long" error
+pub fn err_voice_clip_too_long(locale: &str, max_seconds: u64) -> String {
+    t_args(
+        locale,
+        "err-voice-clip-too-long",
+        &[("max_seconds", &max_seconds.to_string())],
+    )
+}
+
+/// Get translated "clip already exists" error
+pub fn err_voice_clip_exists(locale: &str, name: &str) -> String {
+    t_args(locale, "err-voice-clip-exists", &[("name", name)])
+}
+
+/// Get translated "clip not found" error
+pub fn err_voice_clip_not_found(locale: &str, name: &str) -> String {
+    t_args(locale, "err-voice-clip-not-found", &[("name", name)])
+}
+
+/// Get translated "clip library full" error
+pub fn err_voice_clip_library_full(locale: &str, max_clips: usize) -> String {
+    t_args(
+        locale,
+        "err-voice-clip-library-full",
+        &[("max_clips", &max_clips.to_string())],
+    )
+}
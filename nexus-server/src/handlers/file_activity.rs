@@ -0,0 +1,387 @@
+//! Handlers for FilePopular and FileRecent commands
+//!
+//! Both list files from the per-file download counts and upload times kept by
+//! [`crate::stats`], limited to the user's area like FileSearch.
+
+use std::io;
+use std::path::Path;
+
+use chrono::Utc;
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::{FileActivity, ServerMessage};
+
+use super::{
+    HandlerContext, err_authentication, err_database, err_not_logged_in, err_permission_denied,
+};
+use crate::db::Permission;
+use crate::db::stats::FileActivityRecord;
+use crate::files::resolve_user_area;
+use crate::stats::file_key;
+use crate::transfers::can_access_for_download;
+
+/// Maximum number of files in a listing
+const MAX_FILE_ACTIVITY_RESULTS: usize = 20;
+
+/// Rows read from the database per listing (deleted and hidden files are skipped)
+const FILE_ACTIVITY_CANDIDATES: usize = 100;
+
+/// How far back FileRecent looks for uploads (one week)
+const RECENT_UPLOAD_SECS: i64 = 7 * 24 * 60 * 60;
+
+/// Which listing was requested
+#[derive(Clone, Copy)]
+enum Listing {
+    Popular,
+    Recent,
+}
+
+impl Listing {
+    fn command(self) -> &'static str {
+        match self {
+            Listing::Popular => "FilePopular",
+            Listing::Recent => "FileRecent",
+        }
+    }
+
+    fn response(
+        self,
+        success: bool,
+        error: Option<String>,
+        files: Option<Vec<FileActivity>>,
+    ) -> ServerMessage {
+        match self {
+            Listing::Popular => ServerMessage::FilePopularResponse {
+                success,
+                error,
+                files,
+            },
+            Listing::Recent => ServerMessage::FileRecentResponse {
+                success,
+                error,
+                files,
+            },
+        }
+    }
+}
+
+/// Handle FilePopular command
+///
+/// Lists the most downloaded files in the user's area.
+pub async fn handle_file_popular<W>(
+    root: bool,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    handle_file_activity(Listing::Popular, root, session_id, ctx).await
+}
+
+/// Handle FileRecent command
+///
+/// Lists files uploaded to the user's area in the last week, newest first.
+pub async fn handle_file_recent<W>(
+    root: bool,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    handle_file_activity(Listing::Recent, root, session_id, ctx).await
+}
+
+async fn handle_file_activity<W>(
+    listing: Listing,
+    root: bool,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let command = listing.command();
+
+    // Verify authentication first
+    let Some(session_id) = session_id else {
+        log_warn!("{} request from {} without login", command, ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some(command))
+            .await;
+    };
+
+    // Get requesting user from session
+    let requesting_user = match ctx.user_manager.get_user_by_session_id(session_id).await {
+        Some(user) => user,
+        None => {
+            return ctx
+                .send_error_and_disconnect(&err_authentication(ctx.locale), Some(command))
+                .await;
+        }
+    };
+
+    // Listing files needs file_list, and file_root to look beyond the user's area
+    if !requesting_user.has_permission(Permission::FileList)
+        || (root && !requesting_user.has_permission(Permission::FileRoot))
+    {
+        log_warn!(
+            "{} from {} (user: {}) without permission",
+            command,
+            ctx.peer_addr,
+            requesting_user.username
+        );
+        let response = listing.response(false, Some(err_permission_denied(ctx.locale)), None);
+        return ctx.send_message(&response).await;
+    }
+
+    let Some(file_root) = ctx.file_root else {
+        return ctx
+            .send_message(&listing.response(true, None, Some(vec![])))
+            .await;
+    };
+
+    // Stored paths are relative to the file root, e.g. "/shared/song.mp3"
+    let area_root = if root {
+        file_root.to_path_buf()
+    } else {
+        resolve_user_area(file_root, &requesting_user.username)
+    };
+    let mut prefix = file_key(file_root, &area_root).unwrap_or_else(|| "/".to_string());
+    if !prefix.ends_with('/') {
+        prefix.push('/');
+    }
+
+    let result = match listing {
+        Listing::Popular => {
+            ctx.stats
+                .popular_files(&ctx.db.stats, &prefix, FILE_ACTIVITY_CANDIDATES)
+                .await
+        }
+        Listing::Recent => {
+            let since = Utc::now().timestamp() - RECENT_UPLOAD_SECS;
+            ctx.stats
+                .recent_files(&ctx.db.stats, &prefix, since, FILE_ACTIVITY_CANDIDATES)
+                .await
+        }
+    };
+
+    let records = match result {
+        Ok(records) => records,
+        Err(e) => {
+            log_error!("{} database error: {}", command, e);
+            let response = listing.response(false, Some(err_database(ctx.locale)), None);
+            return ctx.send_message(&response).await;
+        }
+    };
+
+    let mut files = Vec::new();
+    for record in records {
+        if files.len() >= MAX_FILE_ACTIVITY_RESULTS {
+            break;
+        }
+        if let Some(file) = visible_file(
+            file_root,
+            &prefix,
+            record,
+            &requesting_user.username,
+            requesting_user.is_admin,
+        )
+        .await
+        {
+            files.push(file);
+        }
+    }
+
+    ctx.send_message(&listing.response(true, None, Some(files)))
+        .await
+}
+
+/// Build a listing entry for a stored file, or `None` if the user shouldn't see it
+///
+/// Files that were deleted, are hidden (dotfiles) or are inside a dropbox the
+/// user can't read are skipped. The path is made relative to the user's area.
+async fn visible_file(
+    file_root: &Path,
+    prefix: &str,
+    record: FileActivityRecord,
+    username: &str,
+    is_admin: bool,
+) -> Option<FileActivity> {
+    let absolute = file_root.join(record.path.trim_start_matches('/'));
+    let name = absolute.file_name()?.to_str()?.to_string();
+    if name.starts_with('.') || !can_access_for_download(&absolute, username, is_admin) {
+        return None;
+    }
+
+    let metadata = tokio::fs::metadata(&absolute).await.ok()?;
+    if !metadata.is_file() {
+        return None;
+    }
+
+    let path = format!("/{}", record.path.strip_prefix(prefix)?);
+    Some(FileActivity {
+        path,
+        name,
+        size: metadata.len(),
+        downloads: record.downloads,
+        uploaded_at: record.uploaded_at,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::handlers::testing::{
+        create_test_context, login_user, read_server_message, setup_file_area_full,
+    };
+
+    #[tokio::test]
+    async fn test_file_popular_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_file_popular(false, None, &mut test_ctx.handler_context()).await;
+
+        assert!(result.is_err(), "FilePopular should require login");
+    }
+
+    #[tokio::test]
+    async fn test_file_recent_root_requires_file_root_permission() {
+        let mut test_ctx = create_test_context().await;
+        let _file_area = setup_file_area_full(&mut test_ctx);
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::FileList],
+            false,
+        )
+        .await;
+
+        let result =
+            handle_file_recent(true, Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok(), "Should send error response, not disconnect");
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::FileRecentResponse {
+                success,
+                error,
+                files,
+            } => {
+                assert!(!success);
+                assert!(error.is_some());
+                assert!(files.is_none());
+            }
+            _ => panic!("Expected FileRecentResponse, got: {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_file_popular_skips_dropbox_and_deleted_files() {
+        let mut test_ctx = create_test_context().await;
+        let file_area = setup_file_area_full(&mut test_ctx);
+        let root = file_area.path();
+        fs::write(root.join("shared/popular-song.mp3"), "song").unwrap();
+        fs::write(
+            root.join("shared/Submissions [NEXUS-DB]/popular-secret.txt"),
+            "secret",
+        )
+        .unwrap();
+
+        let stats_db = &test_ctx.db.stats;
+        stats_db
+            .add_file_activity("/shared/popular-song.mp3", 3, None)
+            .await
+            .unwrap();
+        stats_db
+            .add_file_activity("/shared/Submissions [NEXUS-DB]/popular-secret.txt", 9, None)
+            .await
+            .unwrap();
+        stats_db
+            .add_file_activity("/shared/popular-deleted.txt", 5, None)
+            .await
+            .unwrap();
+
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::FileList],
+            false,
+        )
+        .await;
+
+        let result =
+            handle_file_popular(false, Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::FilePopularResponse { success, files, .. } => {
+                assert!(success);
+                let files = files.unwrap();
+                let names: Vec<&str> = files
+                    .iter()
+                    .filter(|f| f.name.starts_with("popular-"))
+                    .map(|f| f.path.as_str())
+                    .collect();
+                // Paths are relative to the user's area (shared/)
+                assert_eq!(names, vec!["/popular-song.mp3"]);
+                let song = files.iter().find(|f| f.name == "popular-song.mp3").unwrap();
+                assert_eq!(song.downloads, 3);
+                assert_eq!(song.size, 4);
+            }
+            _ => panic!("Expected FilePopularResponse, got: {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_file_recent_lists_new_uploads() {
+        let mut test_ctx = create_test_context().await;
+        let file_area = setup_file_area_full(&mut test_ctx);
+        let root = file_area.path();
+        fs::write(root.join("shared/recent-new.txt"), "new").unwrap();
+        fs::write(root.join("shared/recent-old.txt"), "old").unwrap();
+
+        let now = Utc::now().timestamp();
+        let stats_db = &test_ctx.db.stats;
+        stats_db
+            .add_file_activity("/shared/recent-new.txt", 0, Some(now))
+            .await
+            .unwrap();
+        stats_db
+            .add_file_activity(
+                "/shared/recent-old.txt",
+                0,
+                Some(now - RECENT_UPLOAD_SECS - 60),
+            )
+            .await
+            .unwrap();
+
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        let result =
+            handle_file_recent(true, Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::FileRecentResponse { success, files, .. } => {
+                assert!(success);
+                let paths: Vec<String> = files
+                    .unwrap()
+                    .into_iter()
+                    .filter(|f| f.name.starts_with("recent-"))
+                    .map(|f| f.path)
+                    .collect();
+                // Root listings keep the full path
+                assert_eq!(paths, vec!["/shared/recent-new.txt"]);
+            }
+            _ => panic!("Expected FileRecentResponse, got: {:?}", response),
+        }
+    }
+}
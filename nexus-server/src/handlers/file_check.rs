@@ -0,0 +1,208 @@
+//! Handler for FileCheck command
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+
+use super::{
+    HandlerContext, err_authentication, err_database, err_file_area_not_configured,
+    err_not_logged_in, err_permission_denied,
+};
+use crate::db::audit::AUDIT_ACTION_FILE_CHECK;
+use crate::files::check;
+
+/// Handle FileCheck command
+///
+/// Compares the disk, search index and file metadata (admin only), sending
+/// each discrepancy as a `FileCheckIssue` before the `FileCheckResponse`.
+/// Repairs are recorded in the audit log.
+pub async fn handle_file_check<W>(
+    repair: bool,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first
+    let Some(session_id) = session_id else {
+        log_warn!("FileCheck request from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("FileCheck"))
+            .await;
+    };
+
+    // Get requesting user from session
+    let requesting_user = match ctx.user_manager.get_user_by_session_id(session_id).await {
+        Some(user) => user,
+        None => {
+            return ctx
+                .send_error_and_disconnect(&err_authentication(ctx.locale), Some("FileCheck"))
+                .await;
+        }
+    };
+
+    // Covers every user's files and deletes metadata, so it's admin only
+    if !requesting_user.is_admin {
+        log_warn!(
+            "FileCheck from {} (user: {}) without permission",
+            ctx.peer_addr,
+            requesting_user.username
+        );
+        return ctx
+            .send_message(&failure(err_permission_denied(ctx.locale)))
+            .await;
+    }
+
+    let Some(file_root) = ctx.file_root else {
+        return ctx
+            .send_message(&failure(err_file_area_not_configured(ctx.locale)))
+            .await;
+    };
+
+    let report = match check::check_file_area(ctx.db, &ctx.file_index, file_root, repair).await {
+        Ok(report) => report,
+        Err(e) => {
+            log_error!("FileCheck database error: {}", e);
+            return ctx.send_message(&failure(err_database(ctx.locale))).await;
+        }
+    };
+
+    if repair && report.summary.repaired > 0 {
+        let details = format!(
+            "issues: {}, repaired: {}",
+            report.summary.issues, report.summary.repaired
+        );
+        if let Err(e) = ctx
+            .db
+            .audit
+            .record(
+                AUDIT_ACTION_FILE_CHECK,
+                "/",
+                &requesting_user.username,
+                Some(&details),
+            )
+            .await
+        {
+            log_error!("Failed to record file check in audit log: {}", e);
+        }
+    }
+
+    for issue in report.issues {
+        let message = ServerMessage::FileCheckIssue {
+            kind: issue.kind,
+            path: issue.path,
+            repaired: issue.repaired,
+        };
+        ctx.send_message(&message).await?;
+    }
+
+    let response = ServerMessage::FileCheckResponse {
+        success: true,
+        error: None,
+        summary: Some(report.summary),
+    };
+    ctx.send_message(&response).await
+}
+
+/// Build a failed FileCheckResponse
+fn failure(error: String) -> ServerMessage {
+    ServerMessage::FileCheckResponse {
+        success: false,
+        error: Some(error),
+        summary: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::testing::{
+        create_test_context, login_user, read_server_message, setup_file_area_basic,
+    };
+    use nexus_common::protocol::FileCheckIssueKind;
+
+    #[tokio::test]
+    async fn test_file_check_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_file_check(false, None, &mut test_ctx.handler_context()).await;
+
+        assert!(result.is_err(), "FileCheck should require login");
+    }
+
+    #[tokio::test]
+    async fn test_file_check_requires_admin() {
+        let mut test_ctx = create_test_context().await;
+        let _temp_dir = setup_file_area_basic(&mut test_ctx);
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let result =
+            handle_file_check(true, Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok(), "Should send error response, not disconnect");
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::FileCheckResponse {
+                success, summary, ..
+            } => {
+                assert!(!success);
+                assert!(summary.is_none());
+            }
+            _ => panic!("Expected FileCheckResponse, got: {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_file_check_streams_issues_and_audits_repair() {
+        let mut test_ctx = create_test_context().await;
+        let _temp_dir = setup_file_area_basic(&mut test_ctx);
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        // History of a file that was deleted outside the server
+        test_ctx
+            .db
+            .stats
+            .add_file_activity("/shared/removed.zip", 3, None)
+            .await
+            .unwrap();
+
+        let result =
+            handle_file_check(true, Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::FileCheckIssue {
+                kind,
+                path,
+                repaired,
+            } => {
+                assert_eq!(kind, FileCheckIssueKind::OrphanedActivity);
+                assert_eq!(path, "/shared/removed.zip");
+                assert!(repaired);
+            }
+            _ => panic!("Expected FileCheckIssue, got: {:?}", response),
+        }
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::FileCheckResponse {
+                success,
+                error,
+                summary: Some(summary),
+            } => {
+                assert!(success, "Expected success, got error: {:?}", error);
+                assert_eq!(summary.issues, 1);
+                assert_eq!(summary.repaired, 1);
+            }
+            _ => panic!("Expected FileCheckResponse, got: {:?}", response),
+        }
+
+        let audit = test_ctx.db.audit.recent(10).await.unwrap();
+        assert_eq!(audit[0].action, AUDIT_ACTION_FILE_CHECK);
+        assert_eq!(audit[0].actor, "admin");
+    }
+}
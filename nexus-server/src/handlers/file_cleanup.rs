@@ -0,0 +1,197 @@
+//! Handler for FileCleanup command
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+
+use super::{
+    HandlerContext, err_authentication, err_database, err_file_area_not_configured,
+    err_not_logged_in, err_permission_denied,
+};
+use crate::db::audit::AUDIT_ACTION_FILE_CLEANUP;
+use crate::transfers::cleanup;
+
+/// Handle FileCleanup command
+///
+/// Scans the file area for leftovers of aborted transfers (admin only) and
+/// deletes them if asked. Deleting is recorded in the audit log.
+pub async fn handle_file_cleanup<W>(
+    delete: bool,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first
+    let Some(session_id) = session_id else {
+        log_warn!("FileCleanup request from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("FileCleanup"))
+            .await;
+    };
+
+    // Get requesting user from session
+    let requesting_user = match ctx.user_manager.get_user_by_session_id(session_id).await {
+        Some(user) => user,
+        None => {
+            return ctx
+                .send_error_and_disconnect(&err_authentication(ctx.locale), Some("FileCleanup"))
+                .await;
+        }
+    };
+
+    // Deleting files no permission covers is admin only
+    if !requesting_user.is_admin {
+        log_warn!(
+            "FileCleanup from {} (user: {}) without permission",
+            ctx.peer_addr,
+            requesting_user.username
+        );
+        return ctx
+            .send_message(&failure(err_permission_denied(ctx.locale)))
+            .await;
+    }
+
+    let Some(file_root) = ctx.file_root else {
+        return ctx
+            .send_message(&failure(err_file_area_not_configured(ctx.locale)))
+            .await;
+    };
+
+    let report = match cleanup::scan_file_area(
+        &ctx.db.upload_journal,
+        ctx.transfer_registry.part_claims(),
+        &ctx.file_index,
+        file_root,
+        ctx.transfer_registry.stale_upload_age(),
+        delete,
+    )
+    .await
+    {
+        Ok(report) => report,
+        Err(e) => {
+            log_error!("FileCleanup database error: {}", e);
+            return ctx.send_message(&failure(err_database(ctx.locale))).await;
+        }
+    };
+
+    if delete {
+        let details = format!(
+            "part files: {} ({} bytes), temp files: {}",
+            report.orphaned_parts, report.orphaned_bytes, report.temp_files
+        );
+        if let Err(e) = ctx
+            .db
+            .audit
+            .record(
+                AUDIT_ACTION_FILE_CLEANUP,
+                "/",
+                &requesting_user.username,
+                Some(&details),
+            )
+            .await
+        {
+            log_error!("Failed to record file cleanup in audit log: {}", e);
+        }
+    }
+
+    let response = ServerMessage::FileCleanupResponse {
+        success: true,
+        error: None,
+        report: Some(report),
+    };
+    ctx.send_message(&response).await
+}
+
+/// Build a failed FileCleanupResponse
+fn failure(error: String) -> ServerMessage {
+    ServerMessage::FileCleanupResponse {
+        success: false,
+        error: Some(error),
+        report: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::testing::{
+        create_test_context, login_user, read_server_message, setup_file_area_basic,
+    };
+
+    #[tokio::test]
+    async fn test_file_cleanup_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_file_cleanup(false, None, &mut test_ctx.handler_context()).await;
+
+        assert!(result.is_err(), "FileCleanup should require login");
+    }
+
+    #[tokio::test]
+    async fn test_file_cleanup_requires_admin() {
+        let mut test_ctx = create_test_context().await;
+        let _temp_dir = setup_file_area_basic(&mut test_ctx);
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let result =
+            handle_file_cleanup(true, Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok(), "Should send error response, not disconnect");
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::FileCleanupResponse {
+                success, report, ..
+            } => {
+                assert!(!success);
+                assert!(report.is_none());
+            }
+            _ => panic!("Expected FileCleanupResponse, got: {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_file_cleanup_delete_is_audited() {
+        let mut test_ctx = create_test_context().await;
+        let temp_dir = setup_file_area_basic(&mut test_ctx);
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        // A leftover from months ago, with no journal entry
+        let orphan = temp_dir.path().join("shared/old.zip.part");
+        std::fs::write(&orphan, b"12345").unwrap();
+        let old = std::time::SystemTime::now() - std::time::Duration::from_secs(90 * 86400);
+        std::fs::File::options()
+            .write(true)
+            .open(&orphan)
+            .unwrap()
+            .set_modified(old)
+            .unwrap();
+
+        let result =
+            handle_file_cleanup(true, Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::FileCleanupResponse {
+                success,
+                error,
+                report: Some(report),
+            } => {
+                assert!(success, "Expected success, got error: {:?}", error);
+                assert!(report.deleted);
+                assert_eq!(report.orphaned_parts, 1);
+                assert_eq!(report.paths, vec!["/shared/old.zip.part"]);
+            }
+            _ => panic!("Expected FileCleanupResponse, got: {:?}", response),
+        }
+        assert!(!orphan.exists());
+
+        let audit = test_ctx.db.audit.recent(10).await.unwrap();
+        assert_eq!(audit[0].action, AUDIT_ACTION_FILE_CLEANUP);
+        assert_eq!(audit[0].actor, "admin");
+    }
+}
@@ -5,7 +5,7 @@ use std::io;
 use tokio::io::AsyncWrite;
 
 use nexus_common::ErrorKind;
-use nexus_common::protocol::ServerMessage;
+use nexus_common::protocol::{FileChangeKind, ServerMessage};
 use nexus_common::validators::{self, FilePathError};
 
 use super::{
@@ -34,7 +34,7 @@ where
 {
     // Verify authentication first
     let Some(requesting_session_id) = session_id else {
-        eprintln!("FileCopy request from {} without login", ctx.peer_addr);
+        log_warn!("FileCopy request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("FileCopy"))
             .await;
@@ -71,9 +71,10 @@ where
 
     // Check FileCopy permission
     if !requesting_user.has_permission(Permission::FileCopy) {
-        eprintln!(
+        log_warn!(
             "FileCopy from {} (user: {}) without permission",
-            ctx.peer_addr, requesting_user.username
+            ctx.peer_addr,
+            requesting_user.username
         );
         let response = ServerMessage::FileCopyResponse {
             success: false,
@@ -85,9 +86,10 @@ where
 
     // Check FileRoot permission if either root flag is set
     if (source_root || destination_root) && !requesting_user.has_permission(Permission::FileRoot) {
-        eprintln!(
+        log_warn!(
             "FileCopy (root) from {} (user: {}) without file_root permission",
-            ctx.peer_addr, requesting_user.username
+            ctx.peer_addr,
+            requesting_user.username
         );
         let response = ServerMessage::FileCopyResponse {
             success: false,
@@ -99,9 +101,10 @@ where
 
     // Check FileDelete permission if overwrite is requested
     if overwrite && !requesting_user.has_permission(Permission::FileDelete) {
-        eprintln!(
+        log_warn!(
             "FileCopy (overwrite) from {} (user: {}) without file_delete permission",
-            ctx.peer_addr, requesting_user.username
+            ctx.peer_addr,
+            requesting_user.username
         );
         let response = ServerMessage::FileCopyResponse {
             success: false,
@@ -336,9 +339,11 @@ where
 
         // Remove existing target for overwrite (async to avoid blocking runtime)
         if let Err(e) = remove_path_async(&target_path).await {
-            eprintln!(
+            log_error!(
                 "FileCopy failed to remove existing target for {} (user: {}): {}",
-                ctx.peer_addr, requesting_user.username, e
+                ctx.peer_addr,
+                requesting_user.username,
+                e
             );
             let response = ServerMessage::FileCopyResponse {
                 success: false,
@@ -352,8 +357,9 @@ where
     // Perform the copy (async to avoid blocking runtime for large directories)
     match copy_path_recursive_async(&resolved_source, &target_path).await {
         Ok(()) => {
-            // Mark file index as dirty so it gets rebuilt
-            ctx.file_index.mark_dirty();
+            // Mark file index as dirty so it gets rebuilt, and tell watchers
+            ctx.file_index
+                .notify_change(&target_path, FileChangeKind::Added);
 
             let response = ServerMessage::FileCopyResponse {
                 success: true,
@@ -363,9 +369,11 @@ where
             ctx.send_message(&response).await
         }
         Err(e) => {
-            eprintln!(
+            log_error!(
                 "FileCopy failed for {} (user: {}): {}",
-                ctx.peer_addr, requesting_user.username, e
+                ctx.peer_addr,
+                requesting_user.username,
+                e
             );
             let response = ServerMessage::FileCopyResponse {
                 success: false,
@@ -4,7 +4,7 @@ use std::io;
 
 use tokio::io::AsyncWrite;
 
-use nexus_common::protocol::ServerMessage;
+use nexus_common::protocol::{FileChangeKind, ServerMessage};
 use nexus_common::validators::{self, DirNameError, FilePathError};
 
 use super::{
@@ -32,7 +32,7 @@ where
 {
     // Verify authentication first
     let Some(requesting_session_id) = session_id else {
-        eprintln!("FileCreateDir request from {} without login", ctx.peer_addr);
+        log_warn!("FileCreateDir request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("FileCreateDir"))
             .await;
@@ -69,9 +69,10 @@ where
 
     // Check FileRoot permission if root browsing requested
     if root && !requesting_user.has_permission(Permission::FileRoot) {
-        eprintln!(
+        log_warn!(
             "FileCreateDir (root) from {} (user: {}) without file_root permission",
-            ctx.peer_addr, requesting_user.username
+            ctx.peer_addr,
+            requesting_user.username
         );
         let response = ServerMessage::FileCreateDirResponse {
             success: false,
@@ -202,9 +203,11 @@ where
     let parent_allows_upload = allows_upload(&area_root, &parent_resolved);
 
     if !has_create_permission && !parent_allows_upload {
-        eprintln!(
+        log_warn!(
             "FileCreateDir from {} (user: {}) without permission (path: {})",
-            ctx.peer_addr, requesting_user.username, path
+            ctx.peer_addr,
+            requesting_user.username,
+            path
         );
         let response = ServerMessage::FileCreateDirResponse {
             success: false,
@@ -229,9 +232,11 @@ where
 
     // Create the directory
     if let Err(e) = std::fs::create_dir(&new_dir_path) {
-        eprintln!(
+        log_error!(
             "FileCreateDir failed for {} (user: {}): {}",
-            ctx.peer_addr, requesting_user.username, e
+            ctx.peer_addr,
+            requesting_user.username,
+            e
         );
         let response = ServerMessage::FileCreateDirResponse {
             success: false,
@@ -241,6 +246,10 @@ where
         return ctx.send_message(&response).await;
     }
 
+    // The index lists directories too; tell watchers of the parent
+    ctx.file_index
+        .notify_change(&new_dir_path, FileChangeKind::Added);
+
     // Build the response path (relative to user's view)
     let normalized_path = normalize_client_path(&path);
     let response_path = if normalized_path.is_empty() {
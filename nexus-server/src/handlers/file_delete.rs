@@ -4,7 +4,7 @@ use std::io;
 
 use tokio::io::AsyncWrite;
 
-use nexus_common::protocol::ServerMessage;
+use nexus_common::protocol::{FileChangeKind, ServerMessage};
 use nexus_common::validators::{self, FilePathError};
 
 use super::{
@@ -14,6 +14,7 @@ use super::{
 use crate::db::Permission;
 use crate::files::path::PathError;
 use crate::files::{build_and_validate_candidate_path, resolve_path, resolve_user_area};
+use crate::stats::file_key;
 
 /// Handle a file delete request
 pub async fn handle_file_delete<W>(
@@ -27,7 +28,7 @@ where
 {
     // Verify authentication first
     let Some(requesting_session_id) = session_id else {
-        eprintln!("FileDelete request from {} without login", ctx.peer_addr);
+        log_warn!("FileDelete request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("FileDelete"))
             .await;
@@ -62,9 +63,10 @@ where
 
     // Check FileDelete permission
     if !requesting_user.has_permission(Permission::FileDelete) {
-        eprintln!(
+        log_warn!(
             "FileDelete from {} (user: {}) without permission",
-            ctx.peer_addr, requesting_user.username
+            ctx.peer_addr,
+            requesting_user.username
         );
         let response = ServerMessage::FileDeleteResponse {
             success: false,
@@ -75,9 +77,10 @@ where
 
     // Check FileRoot permission if root browsing requested
     if root && !requesting_user.has_permission(Permission::FileRoot) {
-        eprintln!(
+        log_warn!(
             "FileDelete (root) from {} (user: {}) without file_root permission",
-            ctx.peer_addr, requesting_user.username
+            ctx.peer_addr,
+            requesting_user.username
         );
         let response = ServerMessage::FileDeleteResponse {
             success: false,
@@ -212,8 +215,19 @@ where
 
     match result {
         Ok(()) => {
-            // Mark file index as dirty so it gets rebuilt
-            ctx.file_index.mark_dirty();
+            // Mark file index as dirty so it gets rebuilt, and tell watchers
+            ctx.file_index
+                .notify_change(&path_to_delete, FileChangeKind::Removed);
+
+            // Ratings, comments and upload rules go with the file
+            if let Some(key) = file_key(file_root, &path_to_delete) {
+                if let Err(e) = ctx.db.file_reviews.delete_reviews(&key).await {
+                    log_error!("FileDelete failed to delete file reviews: {}", e);
+                }
+                if let Err(e) = ctx.db.upload_rules.delete_rules(&key).await {
+                    log_error!("FileDelete failed to delete upload rules: {}", e);
+                }
+            }
 
             let response = ServerMessage::FileDeleteResponse {
                 success: true,
@@ -226,9 +240,11 @@ where
             let error_msg = if is_dir && e.kind() == std::io::ErrorKind::DirectoryNotEmpty {
                 err_dir_not_empty(ctx.locale)
             } else {
-                eprintln!(
+                log_error!(
                     "FileDelete failed for {} (user: {}): {}",
-                    ctx.peer_addr, requesting_user.username, e
+                    ctx.peer_addr,
+                    requesting_user.username,
+                    e
                 );
                 err_delete_failed(ctx.locale)
             };
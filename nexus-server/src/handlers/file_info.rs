@@ -14,6 +14,7 @@ use super::{
 };
 use crate::db::Permission;
 use crate::files::{build_and_validate_candidate_path, resolve_path, resolve_user_area};
+use crate::stats::file_key;
 
 /// Count items in a directory (non-recursive) - async version
 ///
@@ -123,7 +124,7 @@ where
 {
     // Verify authentication first
     let Some(requesting_session_id) = session_id else {
-        eprintln!("FileInfo request from {} without login", ctx.peer_addr);
+        log_warn!("FileInfo request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("FileInfo"))
             .await;
@@ -160,9 +161,10 @@ where
 
     // Check FileInfo permission
     if !requesting_user.has_permission(Permission::FileInfo) {
-        eprintln!(
+        log_warn!(
             "FileInfo from {} (user: {}) without permission",
-            ctx.peer_addr, requesting_user.username
+            ctx.peer_addr,
+            requesting_user.username
         );
         let response = ServerMessage::FileInfoResponse {
             success: false,
@@ -174,9 +176,10 @@ where
 
     // Check FileRoot permission if root browsing requested
     if root && !requesting_user.has_permission(Permission::FileRoot) {
-        eprintln!(
+        log_warn!(
             "FileInfo (root) from {} (user: {}) without file_root permission",
-            ctx.peer_addr, requesting_user.username
+            ctx.peer_addr,
+            requesting_user.username
         );
         let response = ServerMessage::FileInfoResponse {
             success: false,
@@ -295,27 +298,62 @@ where
         .unwrap_or("")
         .to_string();
 
-    // MIME type (only for files) - async to avoid blocking on magic byte detection
-    let mime_type = if is_directory {
-        None
-    } else {
-        detect_mime_type_async(&resolved).await
-    };
+    let details = async {
+        // MIME type (only for files) - async to avoid blocking on magic byte detection
+        let mime_type = if is_directory {
+            None
+        } else {
+            detect_mime_type_async(&resolved).await
+        };
 
-    // Item count (only for directories) - async for large directories
-    let item_count = if is_directory {
-        count_directory_items_async(&resolved).await
-    } else {
-        None
-    };
+        // Item count (only for directories) - async for large directories
+        let item_count = if is_directory {
+            count_directory_items_async(&resolved).await
+        } else {
+            None
+        };
 
-    // SHA-256 hash (only for files) - async for large files
-    let sha256 = if is_directory {
-        None
-    } else {
-        compute_sha256_async(&resolved).await
+        // SHA-256 hash (only for files) - async for large files
+        let sha256 = if is_directory {
+            None
+        } else {
+            compute_sha256_async(&resolved).await
+        };
+
+        (mime_type, item_count, sha256)
+    };
+    let (mime_type, item_count, sha256) = tokio::select! {
+        details = details => details,
+        // The client no longer wants the info
+        _ = ctx.cancel.cancelled() => return Ok(()),
     };
 
+    // Users' ratings and comments (only files can be reviewed)
+    let mut rating = None;
+    let mut my_rating = None;
+    let mut comment_count = None;
+    if !is_directory && let Some(key) = file_key(file_root, &resolved) {
+        let reviews = &ctx.db.file_reviews;
+        let result = async {
+            Ok::<_, sqlx::Error>((
+                reviews.rating(&key).await?,
+                reviews
+                    .user_rating(&key, requesting_user.db_user_id)
+                    .await?,
+                reviews.comment_count(&key).await?,
+            ))
+        }
+        .await;
+        match result {
+            Ok((file_rating, user_rating, comments)) => {
+                rating = file_rating;
+                my_rating = user_rating;
+                comment_count = Some(comments);
+            }
+            Err(e) => log_error!("FileInfo failed to read reviews: {}", e),
+        }
+    }
+
     let info = FileInfoDetails {
         name,
         size,
@@ -326,6 +364,9 @@ where
         mime_type,
         item_count,
         sha256,
+        rating,
+        my_rating,
+        comment_count,
     };
 
     let response = ServerMessage::FileInfoResponse {
@@ -20,12 +20,13 @@ use crate::files::{
     FolderType, allows_upload, build_and_validate_candidate_path, parse_folder_type, resolve_path,
     resolve_user_area,
 };
+use crate::stats::file_key;
 
 /// Read directory entries synchronously (called from spawn_blocking)
 ///
 /// Returns None if the directory cannot be read, Some(entries) otherwise.
 /// Entries are sorted with directories first, then by name.
-fn read_directory_entries(
+pub(crate) fn read_directory_entries(
     resolved: &Path,
     area_root: &Path,
     show_hidden: bool,
@@ -126,7 +127,7 @@ where
 {
     // Verify authentication first
     let Some(requesting_session_id) = session_id else {
-        eprintln!("FileList request from {} without login", ctx.peer_addr);
+        log_warn!("FileList request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("FileList"))
             .await;
@@ -147,6 +148,7 @@ where
                 path: None,
                 entries: None,
                 can_upload: false,
+                upload_rule: None,
             };
             return ctx.send_message(&response).await;
         }
@@ -161,15 +163,17 @@ where
             path: None,
             entries: None,
             can_upload: false,
+            upload_rule: None,
         };
         return ctx.send_message(&response).await;
     };
 
     // Check FileList permission
     if !requesting_user.has_permission(Permission::FileList) {
-        eprintln!(
+        log_warn!(
             "FileList from {} (user: {}) without permission",
-            ctx.peer_addr, requesting_user.username
+            ctx.peer_addr,
+            requesting_user.username
         );
         let response = ServerMessage::FileListResponse {
             success: false,
@@ -177,15 +181,17 @@ where
             path: None,
             entries: None,
             can_upload: false,
+            upload_rule: None,
         };
         return ctx.send_message(&response).await;
     }
 
     // Check FileRoot permission if root browsing requested
     if root && !requesting_user.has_permission(Permission::FileRoot) {
-        eprintln!(
+        log_warn!(
             "FileList (root) from {} (user: {}) without file_root permission",
-            ctx.peer_addr, requesting_user.username
+            ctx.peer_addr,
+            requesting_user.username
         );
         let response = ServerMessage::FileListResponse {
             success: false,
@@ -193,6 +199,7 @@ where
             path: None,
             entries: None,
             can_upload: false,
+            upload_rule: None,
         };
         return ctx.send_message(&response).await;
     }
@@ -213,6 +220,7 @@ where
             path: None,
             entries: None,
             can_upload: false,
+            upload_rule: None,
         };
         return ctx.send_message(&response).await;
     }
@@ -235,6 +243,7 @@ where
                 path: Some(path),
                 entries: Some(Vec::new()),
                 can_upload: false,
+                upload_rule: None,
             };
             return ctx.send_message(&response).await;
         }
@@ -250,6 +259,7 @@ where
                 path: None,
                 entries: None,
                 can_upload: false,
+                upload_rule: None,
             };
             return ctx.send_message(&response).await;
         }
@@ -263,6 +273,7 @@ where
                 path: None,
                 entries: None,
                 can_upload: false,
+                upload_rule: None,
             };
             return ctx.send_message(&response).await;
         }
@@ -273,6 +284,7 @@ where
                 path: None,
                 entries: None,
                 can_upload: false,
+                upload_rule: None,
             };
             return ctx.send_message(&response).await;
         }
@@ -286,6 +298,7 @@ where
             path: None,
             entries: None,
             can_upload: false,
+            upload_rule: None,
         };
         return ctx.send_message(&response).await;
     }
@@ -293,6 +306,20 @@ where
     // Check if the current directory allows uploads (for the New Directory button)
     let current_dir_can_upload = allows_upload(&area_root, &resolved);
 
+    // Send the upload rule that applies here so clients can check files before uploading
+    let upload_rule = match file_key(file_root, &resolved) {
+        Some(key) if current_dir_can_upload => ctx
+            .db
+            .upload_rules
+            .effective(&key)
+            .await
+            .unwrap_or_else(|e| {
+                log_error!("FileList upload rule database error: {}", e);
+                None
+            }),
+        _ => None,
+    };
+
     // Check if we're inside a dropbox - if unauthorized, return empty listing
     // This check is done once before the loop for efficiency
     let is_admin = requesting_user.is_admin;
@@ -305,6 +332,7 @@ where
             path: Some(path),
             entries: Some(Vec::new()),
             can_upload: current_dir_can_upload,
+            upload_rule,
         };
         return ctx.send_message(&response).await;
     }
@@ -327,6 +355,7 @@ where
                 path: None,
                 entries: None,
                 can_upload: false,
+                upload_rule: None,
             };
             return ctx.send_message(&response).await;
         }
@@ -338,13 +367,14 @@ where
         path: Some(path),
         entries: Some(entries),
         can_upload: current_dir_can_upload,
+        upload_rule,
     };
 
     ctx.send_message(&response).await
 }
 
 /// Check if we should hide entries because we're inside a dropbox
-fn should_hide_entry(
+pub(crate) fn should_hide_entry(
     current_dir: &std::path::Path,
     area_root: &std::path::Path,
     is_admin: bool,
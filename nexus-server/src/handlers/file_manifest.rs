@@ -0,0 +1,340 @@
+//! FileManifest message handler - Lists every file a directory download would transfer
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::{FileManifestEntry, ServerMessage};
+use nexus_common::validators::{self, FilePathError};
+
+use super::{
+    HandlerContext, err_file_not_directory, err_file_not_found, err_file_path_invalid,
+    err_file_path_too_long, err_not_logged_in, err_permission_denied, err_transfer_read_failed,
+};
+use crate::db::Permission;
+use crate::files::{build_and_validate_candidate_path, resolve_path, resolve_user_area};
+use crate::transfers::{can_access_for_download, scan_files_for_transfer};
+
+/// Build an error response for a manifest request
+fn error_response(error: String) -> ServerMessage {
+    ServerMessage::FileManifestResponse {
+        success: false,
+        error: Some(error),
+        path: None,
+        files: None,
+    }
+}
+
+/// Handle a file manifest request
+///
+/// Uses the same scan as a directory download on the transfer port, so the
+/// manifest matches what `FileDownload` would send, including dropbox filtering.
+pub async fn handle_file_manifest<W>(
+    path: String,
+    root: bool,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first
+    let Some(requesting_session_id) = session_id else {
+        log_warn!("FileManifest request from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("FileManifest"))
+            .await;
+    };
+
+    // Get requesting user from session
+    let Some(requesting_user) = ctx
+        .user_manager
+        .get_user_by_session_id(requesting_session_id)
+        .await
+    else {
+        // Session not found - likely a race condition, not a security event
+        return ctx
+            .send_message(&error_response(err_not_logged_in(ctx.locale)))
+            .await;
+    };
+
+    // Check file root (cheap check, should always be set in production)
+    let Some(file_root) = ctx.file_root else {
+        return ctx
+            .send_message(&error_response(err_file_not_found(ctx.locale)))
+            .await;
+    };
+
+    // The manifest describes a download, so it needs download permission
+    if !requesting_user.has_permission(Permission::FileDownload) {
+        log_warn!(
+            "FileManifest from {} (user: {}) without permission",
+            ctx.peer_addr,
+            requesting_user.username
+        );
+        return ctx
+            .send_message(&error_response(err_permission_denied(ctx.locale)))
+            .await;
+    }
+
+    // Check FileRoot permission if root browsing requested
+    if root && !requesting_user.has_permission(Permission::FileRoot) {
+        log_warn!(
+            "FileManifest (root) from {} (user: {}) without file_root permission",
+            ctx.peer_addr,
+            requesting_user.username
+        );
+        return ctx
+            .send_message(&error_response(err_permission_denied(ctx.locale)))
+            .await;
+    }
+
+    // Validate path
+    if let Err(e) = validators::validate_file_path(&path) {
+        let error_msg = match e {
+            FilePathError::TooLong => {
+                err_file_path_too_long(ctx.locale, validators::MAX_FILE_PATH_LENGTH)
+            }
+            FilePathError::ContainsNull
+            | FilePathError::InvalidCharacters
+            | FilePathError::ContainsWindowsDrive => err_file_path_invalid(ctx.locale),
+        };
+        return ctx.send_message(&error_response(error_msg)).await;
+    }
+
+    // Resolve area root - either file root (if root browsing) or user's area
+    let area_root_path = if root {
+        file_root.to_path_buf()
+    } else {
+        resolve_user_area(file_root, &requesting_user.username)
+    };
+
+    // Canonicalize area_root (it might not exist yet for new users)
+    let Ok(area_root) = area_root_path.canonicalize() else {
+        return ctx
+            .send_message(&error_response(err_file_not_found(ctx.locale)))
+            .await;
+    };
+
+    // Build, validate and resolve the path (follows symlinks, checks it's within area)
+    let Ok(candidate) = build_and_validate_candidate_path(&area_root, &path) else {
+        return ctx
+            .send_message(&error_response(err_file_path_invalid(ctx.locale)))
+            .await;
+    };
+    let Ok(resolved) = resolve_path(&area_root, &candidate) else {
+        return ctx
+            .send_message(&error_response(err_file_not_found(ctx.locale)))
+            .await;
+    };
+
+    if !resolved.is_dir() {
+        return ctx
+            .send_message(&error_response(err_file_not_directory(ctx.locale)))
+            .await;
+    }
+
+    // Dropbox rules apply exactly as they do for the download itself
+    if !can_access_for_download(
+        &resolved,
+        &requesting_user.username,
+        requesting_user.is_admin,
+    ) {
+        return ctx
+            .send_message(&error_response(err_permission_denied(ctx.locale)))
+            .await;
+    }
+
+    let scan = scan_files_for_transfer(
+        &resolved,
+        &requesting_user.username,
+        requesting_user.is_admin,
+        ctx.debug,
+    );
+    let scanned = tokio::select! {
+        scanned = scan => scanned,
+        // The client no longer wants the manifest; dropping the scan stops it
+        _ = ctx.cancel.cancelled() => return Ok(()),
+    };
+    let files = match scanned {
+        Ok(files) => files,
+        Err(e) => {
+            if ctx.debug {
+                log_debug!("Failed to scan manifest for {}: {e}", ctx.peer_addr);
+            }
+            return ctx
+                .send_message(&error_response(err_transfer_read_failed(ctx.locale)))
+                .await;
+        }
+    };
+
+    let mut files: Vec<FileManifestEntry> = files
+        .into_iter()
+        .map(|f| FileManifestEntry {
+            path: f.relative_path,
+            size: f.size,
+        })
+        .collect();
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+
+    let response = ServerMessage::FileManifestResponse {
+        success: true,
+        error: None,
+        path: Some(path),
+        files: Some(files),
+    };
+    ctx.send_message(&response).await
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::handlers::testing::{
+        create_test_context, login_user, read_server_message, setup_file_area_basic,
+    };
+
+    #[tokio::test]
+    async fn test_file_manifest_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_file_manifest(
+            "Music".to_string(),
+            false,
+            None,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_file_manifest_requires_download_permission() {
+        let mut test_ctx = create_test_context().await;
+        let _file_area = setup_file_area_basic(&mut test_ctx);
+
+        let session_id = login_user(
+            &mut test_ctx,
+            "testuser",
+            "pass",
+            &[Permission::FileList],
+            false,
+        )
+        .await;
+
+        let result = handle_file_manifest(
+            "Music".to_string(),
+            false,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_ok());
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::FileManifestResponse { success, error, .. } => {
+                assert!(!success);
+                assert!(error.is_some());
+            }
+            _ => panic!("Expected FileManifestResponse"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_file_manifest_lists_files_recursively() {
+        let mut test_ctx = create_test_context().await;
+        let file_area = setup_file_area_basic(&mut test_ctx);
+
+        let music = file_area.path().join("shared/Music");
+        fs::create_dir_all(music.join("Jazz")).unwrap();
+        fs::write(music.join("song.mp3"), b"12345").unwrap();
+        fs::write(music.join("Jazz/tune.mp3"), b"123").unwrap();
+        // Dropbox contents are hidden from non-admins, just like the download
+        fs::create_dir_all(music.join("Inbox [NEXUS-DB]")).unwrap();
+        fs::write(music.join("Inbox [NEXUS-DB]/secret.txt"), b"x").unwrap();
+
+        let session_id = login_user(
+            &mut test_ctx,
+            "testuser",
+            "pass",
+            &[Permission::FileDownload],
+            false,
+        )
+        .await;
+
+        let result = handle_file_manifest(
+            "Music".to_string(),
+            false,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_ok());
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::FileManifestResponse {
+                success,
+                path,
+                files,
+                ..
+            } => {
+                assert!(success);
+                assert_eq!(path.as_deref(), Some("Music"));
+                let files = files.expect("Expected files");
+                assert_eq!(
+                    files,
+                    vec![
+                        FileManifestEntry {
+                            path: "Jazz/tune.mp3".to_string(),
+                            size: 3,
+                        },
+                        FileManifestEntry {
+                            path: "song.mp3".to_string(),
+                            size: 5,
+                        },
+                    ]
+                );
+            }
+            _ => panic!("Expected FileManifestResponse"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_file_manifest_rejects_file() {
+        let mut test_ctx = create_test_context().await;
+        let file_area = setup_file_area_basic(&mut test_ctx);
+        fs::write(file_area.path().join("shared/readme.txt"), b"hi").unwrap();
+
+        let session_id = login_user(
+            &mut test_ctx,
+            "testuser",
+            "pass",
+            &[Permission::FileDownload],
+            false,
+        )
+        .await;
+
+        let result = handle_file_manifest(
+            "readme.txt".to_string(),
+            false,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_ok());
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::FileManifestResponse { success, files, .. } => {
+                assert!(!success);
+                assert!(files.is_none());
+            }
+            _ => panic!("Expected FileManifestResponse"),
+        }
+    }
+}
@@ -5,7 +5,7 @@ use std::io;
 use tokio::io::AsyncWrite;
 
 use nexus_common::ErrorKind;
-use nexus_common::protocol::ServerMessage;
+use nexus_common::protocol::{FileChangeKind, ServerMessage};
 use nexus_common::validators::{self, FilePathError};
 
 use super::{
@@ -18,6 +18,7 @@ use crate::files::{
     build_and_validate_candidate_path, is_subpath, remove_path_async, rename_path_async,
     resolve_path, resolve_user_area,
 };
+use crate::stats::file_key;
 
 /// Handle a file move request
 pub async fn handle_file_move<W>(
@@ -34,7 +35,7 @@ where
 {
     // Verify authentication first
     let Some(requesting_session_id) = session_id else {
-        eprintln!("FileMove request from {} without login", ctx.peer_addr);
+        log_warn!("FileMove request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("FileMove"))
             .await;
@@ -71,9 +72,10 @@ where
 
     // Check FileMove permission
     if !requesting_user.has_permission(Permission::FileMove) {
-        eprintln!(
+        log_warn!(
             "FileMove from {} (user: {}) without permission",
-            ctx.peer_addr, requesting_user.username
+            ctx.peer_addr,
+            requesting_user.username
         );
         let response = ServerMessage::FileMoveResponse {
             success: false,
@@ -85,9 +87,10 @@ where
 
     // Check FileRoot permission if either root flag is set
     if (source_root || destination_root) && !requesting_user.has_permission(Permission::FileRoot) {
-        eprintln!(
+        log_warn!(
             "FileMove (root) from {} (user: {}) without file_root permission",
-            ctx.peer_addr, requesting_user.username
+            ctx.peer_addr,
+            requesting_user.username
         );
         let response = ServerMessage::FileMoveResponse {
             success: false,
@@ -99,9 +102,10 @@ where
 
     // Check FileDelete permission if overwrite is requested
     if overwrite && !requesting_user.has_permission(Permission::FileDelete) {
-        eprintln!(
+        log_warn!(
             "FileMove (overwrite) from {} (user: {}) without file_delete permission",
-            ctx.peer_addr, requesting_user.username
+            ctx.peer_addr,
+            requesting_user.username
         );
         let response = ServerMessage::FileMoveResponse {
             success: false,
@@ -336,9 +340,11 @@ where
 
         // Remove existing target for overwrite (async to avoid blocking runtime)
         if let Err(e) = remove_path_async(&target_path).await {
-            eprintln!(
+            log_error!(
                 "FileMove failed to remove existing target for {} (user: {}): {}",
-                ctx.peer_addr, requesting_user.username, e
+                ctx.peer_addr,
+                requesting_user.username,
+                e
             );
             let response = ServerMessage::FileMoveResponse {
                 success: false,
@@ -353,8 +359,33 @@ where
     // Uses async wrapper to avoid blocking the runtime
     match rename_path_async(&resolved_source, &target_path).await {
         Ok(()) => {
-            // Mark file index as dirty so it gets rebuilt
-            ctx.file_index.mark_dirty();
+            // Mark file index as dirty so it gets rebuilt, and tell watchers
+            ctx.file_index
+                .notify_change(&resolved_source, FileChangeKind::Removed);
+            ctx.file_index
+                .notify_change(&target_path, FileChangeKind::Added);
+
+            // Keep download counts and upload times with the file
+            if let Err(e) = ctx
+                .stats
+                .move_files(&ctx.db.stats, file_root, &resolved_source, &target_path)
+                .await
+            {
+                log_error!("FileMove failed to move file activity: {}", e);
+            }
+
+            // Ratings, comments and upload rules follow the file too
+            if let (Some(from), Some(to)) = (
+                file_key(file_root, &resolved_source),
+                file_key(file_root, &target_path),
+            ) {
+                if let Err(e) = ctx.db.file_reviews.move_reviews(&from, &to).await {
+                    log_error!("FileMove failed to move file reviews: {}", e);
+                }
+                if let Err(e) = ctx.db.upload_rules.move_rules(&from, &to).await {
+                    log_error!("FileMove failed to move upload rules: {}", e);
+                }
+            }
 
             let response = ServerMessage::FileMoveResponse {
                 success: true,
@@ -364,9 +395,11 @@ where
             ctx.send_message(&response).await
         }
         Err(e) => {
-            eprintln!(
+            log_error!(
                 "FileMove failed for {} (user: {}): {}",
-                ctx.peer_addr, requesting_user.username, e
+                ctx.peer_addr,
+                requesting_user.username,
+                e
             );
             let response = ServerMessage::FileMoveResponse {
                 success: false,
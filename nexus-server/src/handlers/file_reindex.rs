@@ -19,7 +19,7 @@ where
 {
     // Verify authentication first
     let Some(session_id) = session_id else {
-        eprintln!("FileReindex request from {} without login", ctx.peer_addr);
+        log_warn!("FileReindex request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("FileReindex"))
             .await;
@@ -37,9 +37,10 @@ where
 
     // Check file_reindex permission
     if !requesting_user.has_permission(Permission::FileReindex) {
-        eprintln!(
+        log_warn!(
             "FileReindex from {} (user: {}) without permission",
-            ctx.peer_addr, requesting_user.username
+            ctx.peer_addr,
+            requesting_user.username
         );
         let response = ServerMessage::FileReindexResponse {
             success: false,
@@ -53,14 +54,16 @@ where
 
     if ctx.debug {
         if started {
-            eprintln!(
+            log_debug!(
                 "FileReindex triggered by {} (user: {})",
-                ctx.peer_addr, requesting_user.username
+                ctx.peer_addr,
+                requesting_user.username
             );
         } else {
-            eprintln!(
+            log_debug!(
                 "FileReindex already in progress, requested by {} (user: {})",
-                ctx.peer_addr, requesting_user.username
+                ctx.peer_addr,
+                requesting_user.username
             );
         }
     }
@@ -16,6 +16,7 @@ use crate::db::Permission;
 use crate::files::{
     build_and_validate_candidate_path, rename_path_async, resolve_path, resolve_user_area,
 };
+use crate::stats::file_key;
 
 /// Handle a file rename request
 pub async fn handle_file_rename<W>(
@@ -30,7 +31,7 @@ where
 {
     // Verify authentication first
     let Some(requesting_session_id) = session_id else {
-        eprintln!("FileRename request from {} without login", ctx.peer_addr);
+        log_warn!("FileRename request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("FileRename"))
             .await;
@@ -65,9 +66,10 @@ where
 
     // Check FileRename permission
     if !requesting_user.has_permission(Permission::FileRename) {
-        eprintln!(
+        log_warn!(
             "FileRename from {} (user: {}) without permission",
-            ctx.peer_addr, requesting_user.username
+            ctx.peer_addr,
+            requesting_user.username
         );
         let response = ServerMessage::FileRenameResponse {
             success: false,
@@ -78,9 +80,10 @@ where
 
     // Check FileRoot permission if root browsing requested
     if root && !requesting_user.has_permission(Permission::FileRoot) {
-        eprintln!(
+        log_warn!(
             "FileRename (root) from {} (user: {}) without file_root permission",
-            ctx.peer_addr, requesting_user.username
+            ctx.peer_addr,
+            requesting_user.username
         );
         let response = ServerMessage::FileRenameResponse {
             success: false,
@@ -229,8 +232,30 @@ where
     // Perform the rename (async to avoid blocking runtime)
     match rename_path_async(&source_path, &target_path).await {
         Ok(()) => {
-            // Mark file index as dirty so it gets rebuilt
-            ctx.file_index.mark_dirty();
+            // Mark file index as dirty so it gets rebuilt, and tell watchers
+            ctx.file_index.notify_rename(&source_path, &target_path);
+
+            // Keep download counts and upload times with the file
+            if let Err(e) = ctx
+                .stats
+                .move_files(&ctx.db.stats, file_root, &source_path, &target_path)
+                .await
+            {
+                log_error!("FileRename failed to move file activity: {}", e);
+            }
+
+            // Ratings, comments and upload rules follow the file too
+            if let (Some(from), Some(to)) = (
+                file_key(file_root, &source_path),
+                file_key(file_root, &target_path),
+            ) {
+                if let Err(e) = ctx.db.file_reviews.move_reviews(&from, &to).await {
+                    log_error!("FileRename failed to move file reviews: {}", e);
+                }
+                if let Err(e) = ctx.db.upload_rules.move_rules(&from, &to).await {
+                    log_error!("FileRename failed to move upload rules: {}", e);
+                }
+            }
 
             let response = ServerMessage::FileRenameResponse {
                 success: true,
@@ -239,9 +264,11 @@ where
             ctx.send_message(&response).await
         }
         Err(e) => {
-            eprintln!(
+            log_error!(
                 "FileRename failed for {} (user: {}): {}",
-                ctx.peer_addr, requesting_user.username, e
+                ctx.peer_addr,
+                requesting_user.username,
+                e
             );
             let response = ServerMessage::FileRenameResponse {
                 success: false,
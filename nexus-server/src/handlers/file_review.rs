@@ -0,0 +1,654 @@
+//! Handlers for FileRate, FileCommentCreate, FileCommentList and FileCommentDelete commands
+//!
+//! Users with file_comment permission can rate files (1 to 5 stars) and
+//! comment on them. Comments are shown to everyone with file_info permission;
+//! authors can delete their own, and users with file_delete permission can
+//! delete any (moderation).
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::{FileComment, ServerMessage};
+use nexus_common::validators::{self, FileCommentError, FilePathError};
+
+use super::{
+    HandlerContext, err_authentication, err_database, err_file_comment_empty,
+    err_file_comment_invalid_characters, err_file_comment_not_found, err_file_comment_too_long,
+    err_file_not_found, err_file_path_invalid, err_file_path_too_long, err_file_rating_invalid,
+    err_file_review_directory, err_not_logged_in, err_permission_denied,
+};
+use crate::db::Permission;
+use crate::db::file_reviews::FileCommentRecord;
+use crate::files::{build_and_validate_candidate_path, resolve_path, resolve_user_area};
+use crate::stats::file_key;
+use crate::users::user::UserSession;
+
+/// Highest star rating
+const MAX_FILE_RATING: u8 = 5;
+
+impl From<FileCommentRecord> for FileComment {
+    fn from(record: FileCommentRecord) -> Self {
+        Self {
+            id: record.id,
+            author: record.author_username,
+            body: record.body,
+            created_at: record.created_at,
+        }
+    }
+}
+
+/// Handle FileRate command
+///
+/// Sets (or with rating 0 clears) the user's rating of a file and returns the
+/// file's new aggregate rating.
+pub async fn handle_file_rate<W>(
+    path: String,
+    rating: u8,
+    root: bool,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let failure = |error: String| ServerMessage::FileRateResponse {
+        success: false,
+        error: Some(error),
+        rating: None,
+        my_rating: None,
+    };
+
+    let Some(user) = review_user("FileRate", session_id, ctx).await? else {
+        return Ok(());
+    };
+
+    if !user.has_permission(Permission::FileComment) {
+        log_warn!(
+            "FileRate from {} (user: {}) without permission",
+            ctx.peer_addr,
+            user.username
+        );
+        return ctx
+            .send_message(&failure(err_permission_denied(ctx.locale)))
+            .await;
+    }
+
+    if rating > MAX_FILE_RATING {
+        return ctx
+            .send_message(&failure(err_file_rating_invalid(ctx.locale)))
+            .await;
+    }
+
+    let key = match review_target(&path, root, &user, ctx) {
+        Ok(key) => key,
+        Err(error) => return ctx.send_message(&failure(error)).await,
+    };
+
+    let my_rating = (rating > 0).then_some(rating);
+    let result = async {
+        ctx.db
+            .file_reviews
+            .set_rating(&key, user.db_user_id, my_rating)
+            .await?;
+        ctx.db.file_reviews.rating(&key).await
+    }
+    .await;
+
+    let response = match result {
+        Ok(rating) => ServerMessage::FileRateResponse {
+            success: true,
+            error: None,
+            rating,
+            my_rating,
+        },
+        Err(e) => {
+            log_error!("FileRate database error: {}", e);
+            failure(err_database(ctx.locale))
+        }
+    };
+    ctx.send_message(&response).await
+}
+
+/// Handle FileCommentCreate command
+pub async fn handle_file_comment_create<W>(
+    path: String,
+    body: String,
+    root: bool,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let failure = |error: String| ServerMessage::FileCommentCreateResponse {
+        success: false,
+        error: Some(error),
+        comment: None,
+    };
+
+    let Some(user) = review_user("FileCommentCreate", session_id, ctx).await? else {
+        return Ok(());
+    };
+
+    if !user.has_permission(Permission::FileComment) {
+        log_warn!(
+            "FileCommentCreate from {} (user: {}) without permission",
+            ctx.peer_addr,
+            user.username
+        );
+        return ctx
+            .send_message(&failure(err_permission_denied(ctx.locale)))
+            .await;
+    }
+
+    if let Err(e) = validators::validate_file_comment(&body) {
+        let error = match e {
+            FileCommentError::Empty => err_file_comment_empty(ctx.locale),
+            FileCommentError::TooLong => {
+                err_file_comment_too_long(ctx.locale, validators::MAX_FILE_COMMENT_LENGTH)
+            }
+            FileCommentError::InvalidCharacters => err_file_comment_invalid_characters(ctx.locale),
+        };
+        return ctx.send_message(&failure(error)).await;
+    }
+
+    let key = match review_target(&path, root, &user, ctx) {
+        Ok(key) => key,
+        Err(error) => return ctx.send_message(&failure(error)).await,
+    };
+
+    let response = match ctx
+        .db
+        .file_reviews
+        .add_comment(&key, user.db_user_id, &body)
+        .await
+    {
+        Ok(record) => ServerMessage::FileCommentCreateResponse {
+            success: true,
+            error: None,
+            comment: Some(record.into()),
+        },
+        Err(e) => {
+            log_error!("FileCommentCreate database error: {}", e);
+            failure(err_database(ctx.locale))
+        }
+    };
+    ctx.send_message(&response).await
+}
+
+/// Handle FileCommentList command
+pub async fn handle_file_comment_list<W>(
+    path: String,
+    root: bool,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let failure = |error: String| ServerMessage::FileCommentListResponse {
+        success: false,
+        error: Some(error),
+        comments: None,
+    };
+
+    let Some(user) = review_user("FileCommentList", session_id, ctx).await? else {
+        return Ok(());
+    };
+
+    // Comments are part of a file's details
+    if !user.has_permission(Permission::FileInfo) {
+        log_warn!(
+            "FileCommentList from {} (user: {}) without permission",
+            ctx.peer_addr,
+            user.username
+        );
+        return ctx
+            .send_message(&failure(err_permission_denied(ctx.locale)))
+            .await;
+    }
+
+    let key = match review_target(&path, root, &user, ctx) {
+        Ok(key) => key,
+        Err(error) => return ctx.send_message(&failure(error)).await,
+    };
+
+    let response = match ctx.db.file_reviews.comments(&key).await {
+        Ok(records) => ServerMessage::FileCommentListResponse {
+            success: true,
+            error: None,
+            comments: Some(records.into_iter().map(FileComment::from).collect()),
+        },
+        Err(e) => {
+            log_error!("FileCommentList database error: {}", e);
+            failure(err_database(ctx.locale))
+        }
+    };
+    ctx.send_message(&response).await
+}
+
+/// Handle FileCommentDelete command
+///
+/// Authors can delete their own comments; file_delete permission allows
+/// deleting anyone's.
+pub async fn handle_file_comment_delete<W>(
+    id: i64,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let failure = |error: String| ServerMessage::FileCommentDeleteResponse {
+        success: false,
+        error: Some(error),
+        id: None,
+    };
+
+    let Some(user) = review_user("FileCommentDelete", session_id, ctx).await? else {
+        return Ok(());
+    };
+
+    let comment = match ctx.db.file_reviews.get_comment(id).await {
+        Ok(Some(comment)) => comment,
+        Ok(None) => {
+            return ctx
+                .send_message(&failure(err_file_comment_not_found(ctx.locale, id)))
+                .await;
+        }
+        Err(e) => {
+            log_error!("FileCommentDelete database error: {}", e);
+            return ctx.send_message(&failure(err_database(ctx.locale))).await;
+        }
+    };
+
+    let is_author = comment.author_id == user.db_user_id;
+    if !is_author && !user.has_permission(Permission::FileDelete) {
+        log_warn!(
+            "FileCommentDelete from {} (user: {}) without permission for comment #{}",
+            ctx.peer_addr,
+            user.username,
+            id
+        );
+        return ctx
+            .send_message(&failure(err_permission_denied(ctx.locale)))
+            .await;
+    }
+
+    let response = match ctx.db.file_reviews.delete_comment(id).await {
+        Ok(true) => {
+            if !is_author {
+                log_info!(
+                    "User '{}' deleted {}'s comment on {}",
+                    user.username,
+                    comment.author_username,
+                    comment.path
+                );
+            }
+            ServerMessage::FileCommentDeleteResponse {
+                success: true,
+                error: None,
+                id: Some(id),
+            }
+        }
+        // Race condition - comment was already deleted
+        Ok(false) => failure(err_file_comment_not_found(ctx.locale, id)),
+        Err(e) => {
+            log_error!("FileCommentDelete database error: {}", e);
+            failure(err_database(ctx.locale))
+        }
+    };
+    ctx.send_message(&response).await
+}
+
+/// Get the logged-in user sending a review command
+///
+/// Disconnects (and returns `None`) when there is no valid session.
+async fn review_user<W>(
+    command: &str,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<Option<UserSession>>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(session_id) = session_id else {
+        log_warn!("{} request from {} without login", command, ctx.peer_addr);
+        ctx.send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some(command))
+            .await?;
+        return Ok(None);
+    };
+
+    match ctx.user_manager.get_user_by_session_id(session_id).await {
+        Some(user) => Ok(Some(user)),
+        None => {
+            ctx.send_error_and_disconnect(&err_authentication(ctx.locale), Some(command))
+                .await?;
+            Ok(None)
+        }
+    }
+}
+
+/// Resolve a file in the user's area (or the file root) to its review key
+///
+/// Returns the path relative to the file root, or a translated error for
+/// invalid paths, missing files and directories.
+fn review_target<W>(
+    path: &str,
+    root: bool,
+    user: &UserSession,
+    ctx: &HandlerContext<'_, W>,
+) -> Result<String, String> {
+    let Some(file_root) = ctx.file_root else {
+        return Err(err_file_not_found(ctx.locale));
+    };
+
+    if root && !user.has_permission(Permission::FileRoot) {
+        return Err(err_permission_denied(ctx.locale));
+    }
+
+    validators::validate_file_path(path).map_err(|e| match e {
+        FilePathError::TooLong => {
+            err_file_path_too_long(ctx.locale, validators::MAX_FILE_PATH_LENGTH)
+        }
+        FilePathError::ContainsNull
+        | FilePathError::InvalidCharacters
+        | FilePathError::ContainsWindowsDrive => err_file_path_invalid(ctx.locale),
+    })?;
+
+    let area_root = if root {
+        file_root.to_path_buf()
+    } else {
+        resolve_user_area(file_root, &user.username)
+    };
+    let area_root = area_root
+        .canonicalize()
+        .map_err(|_| err_file_not_found(ctx.locale))?;
+
+    let candidate = build_and_validate_candidate_path(&area_root, path)
+        .map_err(|_| err_file_path_invalid(ctx.locale))?;
+    let resolved =
+        resolve_path(&area_root, &candidate).map_err(|_| err_file_not_found(ctx.locale))?;
+
+    if resolved.is_dir() {
+        return Err(err_file_review_directory(ctx.locale));
+    }
+    if !resolved.is_file() {
+        return Err(err_file_not_found(ctx.locale));
+    }
+
+    file_key(file_root, &resolved).ok_or_else(|| err_file_not_found(ctx.locale))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::testing::{
+        create_test_context, login_user, read_server_message, setup_file_area_basic,
+    };
+
+    #[tokio::test]
+    async fn test_file_rate_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_file_rate(
+            "/a.txt".to_string(),
+            5,
+            false,
+            None,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_err(), "Should disconnect when not logged in");
+    }
+
+    #[tokio::test]
+    async fn test_file_rate_requires_permission() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let result = handle_file_rate(
+            "/a.txt".to_string(),
+            5,
+            false,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::FileRateResponse { success, error, .. } => {
+                assert!(!success);
+                assert!(error.is_some());
+            }
+            response => panic!("Expected FileRateResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_file_rate_and_clear() {
+        let mut test_ctx = create_test_context().await;
+        let temp_dir = setup_file_area_basic(&mut test_ctx);
+        std::fs::write(temp_dir.path().join("shared/song.mp3"), b"content").unwrap();
+        let path = "/song.mp3".to_string();
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::FileComment],
+            false,
+        )
+        .await;
+
+        handle_file_rate(
+            path.clone(),
+            4,
+            false,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::FileRateResponse {
+                success,
+                rating,
+                my_rating,
+                ..
+            } => {
+                assert!(success);
+                assert_eq!(rating.map(|r| (r.count, r.total)), Some((1, 4)));
+                assert_eq!(my_rating, Some(4));
+            }
+            response => panic!("Expected FileRateResponse, got {:?}", response),
+        }
+
+        handle_file_rate(
+            path,
+            0,
+            false,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::FileRateResponse {
+                success,
+                rating,
+                my_rating,
+                ..
+            } => {
+                assert!(success);
+                assert_eq!(rating, None);
+                assert_eq!(my_rating, None);
+            }
+            response => panic!("Expected FileRateResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_file_rate_rejects_invalid_rating_and_directories() {
+        let mut test_ctx = create_test_context().await;
+        let temp_dir = setup_file_area_basic(&mut test_ctx);
+        std::fs::write(temp_dir.path().join("shared/song.mp3"), b"content").unwrap();
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::FileComment],
+            false,
+        )
+        .await;
+
+        for (path, rating) in [("/song.mp3", 6), ("/", 3)] {
+            handle_file_rate(
+                path.to_string(),
+                rating,
+                false,
+                Some(session_id),
+                &mut test_ctx.handler_context(),
+            )
+            .await
+            .unwrap();
+            match read_server_message(&mut test_ctx).await {
+                ServerMessage::FileRateResponse { success, error, .. } => {
+                    assert!(!success, "{path} rated {rating} should fail");
+                    assert!(error.is_some());
+                }
+                response => panic!("Expected FileRateResponse, got {:?}", response),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_file_comment_create_list_and_moderate() {
+        let mut test_ctx = create_test_context().await;
+        let temp_dir = setup_file_area_basic(&mut test_ctx);
+        std::fs::write(temp_dir.path().join("shared/scan.pdf"), b"content").unwrap();
+        let path = "/scan.pdf".to_string();
+        let alice = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::FileComment, Permission::FileInfo],
+            false,
+        )
+        .await;
+        let bob = login_user(
+            &mut test_ctx,
+            "bob",
+            "password",
+            &[Permission::FileComment],
+            false,
+        )
+        .await;
+        let moderator = login_user(
+            &mut test_ctx,
+            "carol",
+            "password",
+            &[Permission::FileDelete],
+            false,
+        )
+        .await;
+
+        handle_file_comment_create(
+            path.clone(),
+            "Missing page 3".to_string(),
+            false,
+            Some(alice),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        let id = match read_server_message(&mut test_ctx).await {
+            ServerMessage::FileCommentCreateResponse {
+                success, comment, ..
+            } => {
+                assert!(success);
+                let comment = comment.unwrap();
+                assert_eq!(comment.author, "alice");
+                comment.id
+            }
+            response => panic!("Expected FileCommentCreateResponse, got {:?}", response),
+        };
+
+        handle_file_comment_list(
+            path.clone(),
+            false,
+            Some(alice),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::FileCommentListResponse { comments, .. } => {
+                let comments = comments.unwrap();
+                assert_eq!(comments.len(), 1);
+                assert_eq!(comments[0].body, "Missing page 3");
+            }
+            response => panic!("Expected FileCommentListResponse, got {:?}", response),
+        }
+
+        // Other users can't delete the comment
+        handle_file_comment_delete(id, Some(bob), &mut test_ctx.handler_context())
+            .await
+            .unwrap();
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::FileCommentDeleteResponse { success, .. } => assert!(!success),
+            response => panic!("Expected FileCommentDeleteResponse, got {:?}", response),
+        }
+
+        // Users with file_delete can
+        handle_file_comment_delete(id, Some(moderator), &mut test_ctx.handler_context())
+            .await
+            .unwrap();
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::FileCommentDeleteResponse {
+                success,
+                id: deleted,
+                ..
+            } => {
+                assert!(success);
+                assert_eq!(deleted, Some(id));
+            }
+            response => panic!("Expected FileCommentDeleteResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_file_comment_create_rejects_empty() {
+        let mut test_ctx = create_test_context().await;
+        let temp_dir = setup_file_area_basic(&mut test_ctx);
+        std::fs::write(temp_dir.path().join("shared/scan.pdf"), b"content").unwrap();
+        let path = "/scan.pdf".to_string();
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::FileComment],
+            false,
+        )
+        .await;
+
+        handle_file_comment_create(
+            path,
+            "   ".to_string(),
+            false,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::FileCommentCreateResponse { success, error, .. } => {
+                assert!(!success);
+                assert!(error.is_some());
+            }
+            response => panic!("Expected FileCommentCreateResponse, got {:?}", response),
+        }
+    }
+}
@@ -28,7 +28,7 @@ where
 {
     // Verify authentication first
     let Some(session_id) = session_id else {
-        eprintln!("FileSearch request from {} without login", ctx.peer_addr);
+        log_warn!("FileSearch request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("FileSearch"))
             .await;
@@ -46,9 +46,10 @@ where
 
     // Check file_search permission
     if !requesting_user.has_permission(Permission::FileSearch) {
-        eprintln!(
+        log_warn!(
             "FileSearch from {} (user: {}) without permission",
-            ctx.peer_addr, requesting_user.username
+            ctx.peer_addr,
+            requesting_user.username
         );
         let response = ServerMessage::FileSearchResponse {
             success: false,
@@ -60,9 +61,10 @@ where
 
     // Check file_root permission if root flag is set
     if root && !requesting_user.has_permission(Permission::FileRoot) {
-        eprintln!(
+        log_warn!(
             "FileSearch with root from {} (user: {}) without file_root permission",
-            ctx.peer_addr, requesting_user.username
+            ctx.peer_addr,
+            requesting_user.username
         );
         let response = ServerMessage::FileSearchResponse {
             success: false,
@@ -121,15 +123,23 @@ where
     let file_index = Arc::clone(&ctx.file_index);
     let query_clone = query.clone();
     let area_prefix_clone = area_prefix.clone();
+    let cancel = ctx.cancel.clone();
     let search_result = tokio::task::spawn_blocking(move || {
-        file_index.search(&query_clone, area_prefix_clone.as_deref())
+        file_index.search_until(&query_clone, area_prefix_clone.as_deref(), || {
+            cancel.is_cancelled()
+        })
     })
     .await;
 
+    // The client no longer wants the results
+    if ctx.cancel.is_cancelled() {
+        return Ok(());
+    }
+
     let mut results = match search_result {
         Ok(Ok(results)) => results,
         Ok(Err(e)) => {
-            eprintln!("FileSearch error from {}: {}", ctx.peer_addr, e);
+            log_error!("FileSearch error from {}: {}", ctx.peer_addr, e);
             let response = ServerMessage::FileSearchResponse {
                 success: false,
                 error: Some(err_search_failed(ctx.locale)),
@@ -138,7 +148,7 @@ where
             return ctx.send_message(&response).await;
         }
         Err(e) => {
-            eprintln!("FileSearch task panicked from {}: {}", ctx.peer_addr, e);
+            log_error!("FileSearch task panicked from {}: {}", ctx.peer_addr, e);
             let response = ServerMessage::FileSearchResponse {
                 success: false,
                 error: Some(err_search_failed(ctx.locale)),
@@ -148,6 +158,21 @@ where
         }
     };
 
+    // Attach users' ratings (keyed by the same root-relative paths as the index)
+    let paths: Vec<String> = results
+        .iter()
+        .filter(|result| !result.is_directory)
+        .map(|result| result.path.clone())
+        .collect();
+    match ctx.db.file_reviews.ratings(&paths).await {
+        Ok(mut ratings) => {
+            for result in &mut results {
+                result.rating = ratings.remove(&result.path);
+            }
+        }
+        Err(e) => log_error!("FileSearch failed to read ratings: {}", e),
+    }
+
     // Strip area prefix from result paths so client sees virtual paths
     // e.g., "/shared/Documents/file.txt" -> "/Documents/file.txt"
     if let Some(prefix) = &area_prefix {
@@ -177,7 +202,9 @@ where
 mod tests {
     use super::*;
     use crate::db::Permission;
-    use crate::handlers::testing::{create_test_context, login_user, read_server_message};
+    use crate::handlers::testing::{
+        create_test_context, login_user, read_server_message, setup_file_area_basic,
+    };
 
     #[tokio::test]
     async fn test_file_search_requires_login() {
@@ -512,4 +539,36 @@ mod tests {
             _ => panic!("Expected FileSearchResponse, got: {:?}", response),
         }
     }
+
+    #[tokio::test]
+    async fn test_file_search_cancelled_sends_nothing() {
+        let mut test_ctx = create_test_context().await;
+        let _file_area = setup_file_area_basic(&mut test_ctx);
+
+        let session_id = login_user(
+            &mut test_ctx,
+            "user",
+            "password",
+            &[Permission::FileSearch],
+            false,
+        )
+        .await;
+
+        let mut ctx = test_ctx.handler_context();
+        ctx.cancel.cancel();
+        let result =
+            handle_file_search("test".to_string(), false, Some(session_id), &mut ctx).await;
+        assert!(result.is_ok());
+
+        // The next message is the one sent after the handler, not a response
+        ctx.send_message(&ServerMessage::Pong { time: None })
+            .await
+            .unwrap();
+        let response = read_server_message(&mut test_ctx).await;
+        assert!(
+            matches!(response, ServerMessage::Pong { .. }),
+            "Expected no FileSearchResponse, got: {:?}",
+            response
+        );
+    }
 }
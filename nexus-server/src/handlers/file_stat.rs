@@ -0,0 +1,312 @@
+//! FileStat message handler - Looks up a batch of paths before an upload
+//!
+//! Clients check where their files would land so they can preview conflicts
+//! instead of discovering them partway through a transfer. Paths that don't
+//! exist are left out of the response, and a file is only hashed when its
+//! size matches the local one (otherwise it can't be identical anyway).
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::{FileStatEntry, FileStatQuery, ServerMessage};
+use nexus_common::validators::{self, FilePathError, FileStatError};
+
+use super::{
+    HandlerContext, err_file_not_found, err_file_path_invalid, err_file_path_too_long,
+    err_file_stat_empty, err_file_stat_too_many, err_not_logged_in, err_permission_denied,
+};
+use crate::db::Permission;
+use crate::files::{build_and_validate_candidate_path, resolve_path, resolve_user_area};
+use crate::transfers::can_access_for_download;
+
+/// Build an error response for a stat request
+fn error_response(error: String) -> ServerMessage {
+    ServerMessage::FileStatResponse {
+        success: false,
+        error: Some(error),
+        files: None,
+    }
+}
+
+/// Handle a file stat request
+pub async fn handle_file_stat<W>(
+    files: Vec<FileStatQuery>,
+    root: bool,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first
+    let Some(requesting_session_id) = session_id else {
+        log_warn!("FileStat request from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("FileStat"))
+            .await;
+    };
+
+    // Get requesting user from session
+    let Some(requesting_user) = ctx
+        .user_manager
+        .get_user_by_session_id(requesting_session_id)
+        .await
+    else {
+        // Session not found - likely a race condition, not a security event
+        return ctx
+            .send_message(&error_response(err_not_logged_in(ctx.locale)))
+            .await;
+    };
+
+    // Check file root (cheap check, should always be set in production)
+    let Some(file_root) = ctx.file_root else {
+        return ctx
+            .send_message(&error_response(err_file_not_found(ctx.locale)))
+            .await;
+    };
+
+    // Looking paths up reveals no more than a listing would
+    if !requesting_user.has_permission(Permission::FileList) {
+        log_warn!(
+            "FileStat from {} (user: {}) without permission",
+            ctx.peer_addr,
+            requesting_user.username
+        );
+        return ctx
+            .send_message(&error_response(err_permission_denied(ctx.locale)))
+            .await;
+    }
+
+    // Check FileRoot permission if root browsing requested
+    if root && !requesting_user.has_permission(Permission::FileRoot) {
+        log_warn!(
+            "FileStat (root) from {} (user: {}) without file_root permission",
+            ctx.peer_addr,
+            requesting_user.username
+        );
+        return ctx
+            .send_message(&error_response(err_permission_denied(ctx.locale)))
+            .await;
+    }
+
+    // Validate the path list
+    if let Err(e) = validators::validate_file_stat_paths(files.iter().map(|f| f.path.as_str())) {
+        let error_msg = match e {
+            FileStatError::Empty => err_file_stat_empty(ctx.locale),
+            FileStatError::TooMany => {
+                err_file_stat_too_many(ctx.locale, validators::MAX_FILE_STAT_COUNT)
+            }
+            FileStatError::InvalidPath(FilePathError::TooLong) => {
+                err_file_path_too_long(ctx.locale, validators::MAX_FILE_PATH_LENGTH)
+            }
+            FileStatError::InvalidPath(
+                FilePathError::ContainsNull
+                | FilePathError::InvalidCharacters
+                | FilePathError::ContainsWindowsDrive,
+            ) => err_file_path_invalid(ctx.locale),
+        };
+        return ctx.send_message(&error_response(error_msg)).await;
+    }
+
+    // Resolve area root - either file root (if root browsing) or user's area
+    let area_root_path = if root {
+        file_root.to_path_buf()
+    } else {
+        resolve_user_area(file_root, &requesting_user.username)
+    };
+
+    // Nothing exists yet in an area that hasn't been created
+    let Ok(area_root) = area_root_path.canonicalize() else {
+        let response = ServerMessage::FileStatResponse {
+            success: true,
+            error: None,
+            files: Some(Vec::new()),
+        };
+        return ctx.send_message(&response).await;
+    };
+
+    let mut entries = Vec::new();
+    for query in files {
+        // Invalid or missing paths are simply not there
+        let Ok(candidate) = build_and_validate_candidate_path(&area_root, &query.path) else {
+            continue;
+        };
+        let Ok(resolved) = resolve_path(&area_root, &candidate) else {
+            continue;
+        };
+
+        // Dropbox contents stay hidden from users who can't download them
+        if !can_access_for_download(
+            &resolved,
+            &requesting_user.username,
+            requesting_user.is_admin,
+        ) {
+            continue;
+        }
+
+        let Ok(metadata) = tokio::fs::metadata(&resolved).await else {
+            continue;
+        };
+
+        let entry = if metadata.is_dir() {
+            FileStatEntry {
+                path: query.path,
+                size: 0,
+                is_directory: true,
+                sha256: None,
+            }
+        } else {
+            let size = metadata.len();
+            let sha256 = if size == query.size {
+                nexus_common::hash::compute_sha256(&resolved).await.ok()
+            } else {
+                None
+            };
+            FileStatEntry {
+                path: query.path,
+                size,
+                is_directory: false,
+                sha256,
+            }
+        };
+        entries.push(entry);
+    }
+
+    let response = ServerMessage::FileStatResponse {
+        success: true,
+        error: None,
+        files: Some(entries),
+    };
+    ctx.send_message(&response).await
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::handlers::testing::{
+        create_test_context, login_user, read_server_message, setup_file_area_basic,
+    };
+
+    fn query(path: &str, size: u64) -> FileStatQuery {
+        FileStatQuery {
+            path: path.to_string(),
+            size,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_file_stat_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_file_stat(
+            vec![query("song.mp3", 5)],
+            false,
+            None,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_file_stat_rejects_empty_list() {
+        let mut test_ctx = create_test_context().await;
+        let _file_area = setup_file_area_basic(&mut test_ctx);
+        let session_id = login_user(
+            &mut test_ctx,
+            "testuser",
+            "pass",
+            &[Permission::FileList],
+            false,
+        )
+        .await;
+
+        handle_file_stat(
+            Vec::new(),
+            false,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::FileStatResponse { success, error, .. } => {
+                assert!(!success);
+                assert!(error.is_some());
+            }
+            _ => panic!("Expected FileStatResponse"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_file_stat_reports_existing_paths() {
+        let mut test_ctx = create_test_context().await;
+        let file_area = setup_file_area_basic(&mut test_ctx);
+
+        let shared = file_area.path().join("shared");
+        fs::create_dir_all(shared.join("Music")).unwrap();
+        fs::write(shared.join("Music/song.mp3"), b"12345").unwrap();
+        fs::write(shared.join("Music/other.mp3"), b"123").unwrap();
+        // Dropbox contents are hidden from non-admins
+        fs::create_dir_all(shared.join("Inbox [NEXUS-DB]")).unwrap();
+        fs::write(shared.join("Inbox [NEXUS-DB]/secret.txt"), b"x").unwrap();
+
+        let session_id = login_user(
+            &mut test_ctx,
+            "testuser",
+            "pass",
+            &[Permission::FileList],
+            false,
+        )
+        .await;
+
+        handle_file_stat(
+            vec![
+                query("Music/song.mp3", 5),
+                query("Music/other.mp3", 10),
+                query("Music", 0),
+                query("Music/missing.mp3", 1),
+                query("Inbox [NEXUS-DB]/secret.txt", 1),
+            ],
+            false,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::FileStatResponse { success, files, .. } => {
+                assert!(success);
+                let files = files.unwrap();
+                assert_eq!(files.len(), 3);
+
+                // Same size, so it was hashed
+                assert_eq!(files[0].path, "Music/song.mp3");
+                assert_eq!(files[0].size, 5);
+                assert_eq!(
+                    files[0].sha256.as_deref(),
+                    Some("5994471abb01112afcc18159f6cc74b4f511b99806da59b3caf5a9c173cacfc5")
+                );
+
+                // Different size, no hash needed
+                assert_eq!(files[1].path, "Music/other.mp3");
+                assert_eq!(files[1].size, 3);
+                assert!(files[1].sha256.is_none());
+
+                assert_eq!(files[2].path, "Music");
+                assert!(files[2].is_directory);
+            }
+            _ => panic!("Expected FileStatResponse"),
+        }
+    }
+}
@@ -0,0 +1,330 @@
+//! FileWatch message handler - Watches directories for changes
+//!
+//! Replaces the directories a session watches. Entries added to, removed
+//! from or renamed in one are reported with `FileChanged` messages until the
+//! session watches something else (an empty list stops watching). Watching
+//! reveals no more than listing the directory would, so it needs the same
+//! permissions. Directories that don't exist, or that the user can't list
+//! (dropbox contents), are skipped rather than failing the whole request, since
+//! a client often watches folders that were just deleted.
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::{FileWatchPath, ServerMessage};
+use nexus_common::validators::{self, FilePathError, FileWatchError};
+
+use super::{
+    HandlerContext, err_authentication, err_file_not_found, err_file_path_invalid,
+    err_file_path_too_long, err_file_watch_too_many, err_not_logged_in, err_permission_denied,
+    should_hide_entry,
+};
+use crate::db::Permission;
+use crate::files::{
+    WatchedDirectory, build_and_validate_candidate_path, resolve_path, resolve_user_area,
+};
+
+/// Build an error response for a watch request
+fn error_response(error: String) -> ServerMessage {
+    ServerMessage::FileWatchResponse {
+        success: false,
+        error: Some(error),
+    }
+}
+
+/// Handle a file watch request
+pub async fn handle_file_watch<W>(
+    directories: Vec<FileWatchPath>,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first
+    let Some(requesting_session_id) = session_id else {
+        log_warn!("FileWatch request from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("FileWatch"))
+            .await;
+    };
+
+    // Get requesting user from session
+    let Some(requesting_user) = ctx
+        .user_manager
+        .get_user_by_session_id(requesting_session_id)
+        .await
+    else {
+        // Session not found - likely a race condition, not a security event
+        return ctx
+            .send_message(&error_response(err_not_logged_in(ctx.locale)))
+            .await;
+    };
+
+    // Validate the directory list
+    if let Err(e) =
+        validators::validate_file_watch_paths(directories.iter().map(|d| d.path.as_str()))
+    {
+        let error_msg = match e {
+            FileWatchError::TooMany => {
+                err_file_watch_too_many(ctx.locale, validators::MAX_FILE_WATCH_COUNT)
+            }
+            FileWatchError::InvalidPath(FilePathError::TooLong) => {
+                err_file_path_too_long(ctx.locale, validators::MAX_FILE_PATH_LENGTH)
+            }
+            FileWatchError::InvalidPath(
+                FilePathError::ContainsNull
+                | FilePathError::InvalidCharacters
+                | FilePathError::ContainsWindowsDrive,
+            ) => err_file_path_invalid(ctx.locale),
+        };
+        return ctx.send_message(&error_response(error_msg)).await;
+    }
+
+    // Stopping needs no file area or permissions
+    let mut watches = Vec::with_capacity(directories.len());
+    if !directories.is_empty() {
+        // Check file root (cheap check, should always be set in production)
+        let Some(file_root) = ctx.file_root else {
+            return ctx
+                .send_message(&error_response(err_file_not_found(ctx.locale)))
+                .await;
+        };
+
+        let can_browse_root = requesting_user.has_permission(Permission::FileRoot);
+        if !requesting_user.has_permission(Permission::FileList)
+            || (directories.iter().any(|d| d.root) && !can_browse_root)
+        {
+            log_warn!(
+                "FileWatch from {} (user: {}) without permission",
+                ctx.peer_addr,
+                requesting_user.username
+            );
+            return ctx
+                .send_message(&error_response(err_permission_denied(ctx.locale)))
+                .await;
+        }
+
+        for directory in directories {
+            // Resolve area root - either file root or user's area
+            let area_root_path = if directory.root {
+                file_root.to_path_buf()
+            } else {
+                resolve_user_area(file_root, &requesting_user.username)
+            };
+            let Ok(area_root) = area_root_path.canonicalize() else {
+                continue;
+            };
+            let Ok(candidate) = build_and_validate_candidate_path(&area_root, &directory.path)
+            else {
+                continue;
+            };
+            let Ok(resolved) = resolve_path(&area_root, &candidate) else {
+                continue;
+            };
+            if !resolved.is_dir()
+                || should_hide_entry(
+                    &resolved,
+                    &area_root,
+                    requesting_user.is_admin,
+                    &requesting_user.username,
+                )
+            {
+                continue;
+            }
+
+            watches.push(WatchedDirectory {
+                dir: resolved,
+                path: directory.path,
+                root: directory.root,
+            });
+        }
+    }
+
+    if !ctx
+        .user_manager
+        .set_file_watches(requesting_session_id, watches)
+        .await
+    {
+        return ctx
+            .send_error_and_disconnect(&err_authentication(ctx.locale), Some("FileWatch"))
+            .await;
+    }
+
+    ctx.send_message(&ServerMessage::FileWatchResponse {
+        success: true,
+        error: None,
+    })
+    .await
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::handlers::testing::{
+        DEFAULT_TEST_LOCALE, create_test_context, login_user, read_server_message,
+        setup_file_area_full,
+    };
+
+    fn dir(path: &str, root: bool) -> FileWatchPath {
+        FileWatchPath {
+            path: path.to_string(),
+            root,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_file_watch_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_file_watch(
+            vec![dir("Documents", false)],
+            None,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_file_watch_requires_root_permission() {
+        let mut test_ctx = create_test_context().await;
+        let _file_area = setup_file_area_full(&mut test_ctx);
+        let session_id = login_user(
+            &mut test_ctx,
+            "testuser",
+            "pass",
+            &[Permission::FileList],
+            false,
+        )
+        .await;
+
+        handle_file_watch(
+            vec![dir("shared", true)],
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::FileWatchResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_permission_denied(DEFAULT_TEST_LOCALE)));
+            }
+            response => panic!("Expected FileWatchResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_file_watch_skips_missing_and_hidden_directories() {
+        let mut test_ctx = create_test_context().await;
+        let file_area = setup_file_area_full(&mut test_ctx);
+        let session_id = login_user(
+            &mut test_ctx,
+            "testuser",
+            "pass",
+            &[Permission::FileList],
+            false,
+        )
+        .await;
+        fs::create_dir_all(file_area.path().join("shared/Submissions [NEXUS-DB]/inner")).unwrap();
+
+        handle_file_watch(
+            vec![
+                dir("Documents", false),
+                dir("Missing", false),
+                dir("readme.txt", false),
+                dir("Submissions [NEXUS-DB]/inner", false),
+            ],
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::FileWatchResponse { success, .. } => assert!(success),
+            response => panic!("Expected FileWatchResponse, got {:?}", response),
+        }
+
+        let session = test_ctx
+            .user_manager
+            .get_user_by_session_id(session_id)
+            .await
+            .unwrap();
+        assert_eq!(session.file_watches.len(), 1);
+        assert_eq!(session.file_watches[0].path, "Documents");
+        assert_eq!(
+            session.file_watches[0].dir,
+            file_area
+                .path()
+                .join("shared/Documents")
+                .canonicalize()
+                .unwrap()
+        );
+
+        // An empty list stops watching
+        handle_file_watch(
+            Vec::new(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        read_server_message(&mut test_ctx).await;
+        let session = test_ctx
+            .user_manager
+            .get_user_by_session_id(session_id)
+            .await
+            .unwrap();
+        assert!(session.file_watches.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_file_watch_too_many() {
+        let mut test_ctx = create_test_context().await;
+        let _file_area = setup_file_area_full(&mut test_ctx);
+        let session_id = login_user(
+            &mut test_ctx,
+            "testuser",
+            "pass",
+            &[Permission::FileList],
+            false,
+        )
+        .await;
+
+        let directories = (0..=validators::MAX_FILE_WATCH_COUNT)
+            .map(|i| dir(&format!("folder{i}"), false))
+            .collect();
+        handle_file_watch(
+            directories,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::FileWatchResponse { success, error } => {
+                assert!(!success);
+                assert_eq!(
+                    error,
+                    Some(err_file_watch_too_many(
+                        DEFAULT_TEST_LOCALE,
+                        validators::MAX_FILE_WATCH_COUNT
+                    ))
+                );
+            }
+            response => panic!("Expected FileWatchResponse, got {:?}", response),
+        }
+    }
+}
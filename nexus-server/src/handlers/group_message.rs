@@ -0,0 +1,627 @@
+//! Handlers for group conversation commands
+//!
+//! Groups are multi-user direct messages; see [`crate::groups`] for how
+//! membership works. Starting a group and inviting to it need the
+//! UserMessage permission, like sending a direct message does.
+
+use std::io;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::ErrorKind;
+use nexus_common::protocol::{ChatAction, ServerMessage};
+use nexus_common::validators::{
+    self, GroupMembersError, MAX_GROUP_MEMBERS, MAX_GROUPS_PER_USER, MessageError, NicknameError,
+};
+
+use super::{
+    HandlerContext, err_authentication, err_cannot_message_self, err_chat_too_long,
+    err_group_already_member, err_group_limit, err_group_no_members, err_group_not_found,
+    err_group_too_many_members, err_idempotency_key_invalid, err_message_contains_newlines,
+    err_message_empty, err_message_invalid_characters, err_nickname_empty, err_nickname_invalid,
+    err_nickname_not_online, err_nickname_too_long, err_not_logged_in, err_permission_denied,
+};
+use crate::db::Permission;
+use crate::groups::{self, AddMemberError, CreateGroupError};
+
+/// Handle GroupMessageCreate command
+pub async fn handle_group_message_create<W>(
+    members: Vec<String>,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(session_id) = session_id else {
+        log_warn!(
+            "GroupMessageCreate request from {} without login",
+            ctx.peer_addr
+        );
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("GroupMessageCreate"))
+            .await;
+    };
+
+    if let Err(e) = validators::validate_group_members(&members) {
+        let error_msg = match e {
+            GroupMembersError::Empty => err_group_no_members(ctx.locale),
+            GroupMembersError::TooMany => err_group_too_many_members(ctx.locale, MAX_GROUP_MEMBERS),
+            GroupMembersError::InvalidNickname(e) => nickname_error(ctx.locale, e),
+        };
+        return send_create_error(ctx, error_msg).await;
+    }
+
+    let Some(user) = ctx.user_manager.get_user_by_session_id(session_id).await else {
+        return ctx
+            .send_error_and_disconnect(&err_authentication(ctx.locale), Some("GroupMessageCreate"))
+            .await;
+    };
+
+    if !user.has_permission(Permission::UserMessage) {
+        log_warn!(
+            "GroupMessageCreate from {} (user: {}) without permission",
+            ctx.peer_addr,
+            user.username
+        );
+        return send_create_error(ctx, err_permission_denied(ctx.locale)).await;
+    }
+
+    // The creator comes first, then each other member once, as displayed
+    let mut group_members = vec![user.nickname.clone()];
+    for nickname in &members {
+        if nickname.to_lowercase() == user.nickname.to_lowercase() {
+            return send_create_error(ctx, err_cannot_message_self(ctx.locale)).await;
+        }
+        let Some(target) = ctx.user_manager.get_session_by_nickname(nickname).await else {
+            return send_create_error(ctx, err_nickname_not_online(ctx.locale, nickname)).await;
+        };
+        if !group_members
+            .iter()
+            .any(|member| member.to_lowercase() == target.nickname.to_lowercase())
+        {
+            group_members.push(target.nickname);
+        }
+    }
+
+    let group_id = match ctx.group_manager.create(group_members.clone()) {
+        Ok(group_id) => group_id,
+        Err(CreateGroupError::TooMany) => {
+            return send_create_error(ctx, err_group_limit(ctx.locale, MAX_GROUPS_PER_USER)).await;
+        }
+    };
+
+    let response = ServerMessage::GroupMessageCreateResponse {
+        success: true,
+        error: None,
+        group_id: Some(group_id),
+    };
+    ctx.send_message(&response).await?;
+
+    groups::send_members(group_id, &group_members, ctx.user_manager).await;
+    Ok(())
+}
+
+/// Handle GroupMessageSend command
+pub async fn handle_group_message_send<W>(
+    group_id: u64,
+    message: String,
+    action: ChatAction,
+    idempotency_key: Option<String>,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(session_id) = session_id else {
+        log_warn!(
+            "GroupMessageSend request from {} without login",
+            ctx.peer_addr
+        );
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("GroupMessageSend"))
+            .await;
+    };
+
+    if let Err(e) = validators::validate_multiline_message(&message) {
+        // Clients may split a message that's too long and send it again
+        let error_kind = (e == MessageError::TooLong).then(|| ErrorKind::MessageTooLong.into());
+        let error_msg = match e {
+            MessageError::Empty => err_message_empty(ctx.locale),
+            MessageError::TooLong => err_chat_too_long(ctx.locale, validators::MAX_MESSAGE_LENGTH),
+            MessageError::ContainsNewlines => err_message_contains_newlines(ctx.locale),
+            MessageError::InvalidCharacters => err_message_invalid_characters(ctx.locale),
+        };
+        let response = ServerMessage::GroupMessageSendResponse {
+            success: false,
+            error: Some(error_msg),
+            error_kind,
+        };
+        return ctx.send_message(&response).await;
+    }
+
+    if let Some(key) = &idempotency_key
+        && validators::validate_idempotency_key(key).is_err()
+    {
+        let response = ServerMessage::GroupMessageSendResponse {
+            success: false,
+            error: Some(err_idempotency_key_invalid(ctx.locale)),
+            error_kind: None,
+        };
+        return ctx.send_message(&response).await;
+    }
+
+    let Some(user) = ctx.user_manager.get_user_by_session_id(session_id).await else {
+        return ctx
+            .send_error_and_disconnect(&err_authentication(ctx.locale), Some("GroupMessageSend"))
+            .await;
+    };
+
+    // Members may always talk in a group they're in
+    let Some(members) = ctx.group_manager.members_for(group_id, &user.nickname) else {
+        let response = ServerMessage::GroupMessageSendResponse {
+            success: false,
+            error: Some(err_group_not_found(ctx.locale)),
+            error_kind: None,
+        };
+        return ctx.send_message(&response).await;
+    };
+
+    // A message sent again after a dropped connection is acknowledged, not
+    // delivered twice
+    if let Some(key) = &idempotency_key
+        && !ctx.idempotency_keys.first_use(&user.nickname, key)
+    {
+        let response = ServerMessage::GroupMessageSendResponse {
+            success: true,
+            error: None,
+            error_kind: None,
+        };
+        return ctx.send_message(&response).await;
+    }
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let broadcast = ServerMessage::GroupMessage {
+        group_id,
+        from_nickname: user.nickname.clone(),
+        from_admin: user.is_admin,
+        from_shared: user.is_shared,
+        message,
+        action,
+        timestamp,
+    };
+    for member in &members {
+        ctx.user_manager
+            .broadcast_to_nickname(member, &broadcast)
+            .await;
+    }
+    ctx.stats.record_message(user.db_user_id);
+    ctx.user_manager.touch_activity(session_id).await;
+
+    // Queued after the broadcasts, like UserMessageResponse
+    let response = ServerMessage::GroupMessageSendResponse {
+        success: true,
+        error: None,
+        error_kind: None,
+    };
+    ctx.send_message_via_channel(&response)
+}
+
+/// Handle GroupMessageInvite command
+pub async fn handle_group_message_invite<W>(
+    group_id: u64,
+    nickname: String,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(session_id) = session_id else {
+        log_warn!(
+            "GroupMessageInvite request from {} without login",
+            ctx.peer_addr
+        );
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("GroupMessageInvite"))
+            .await;
+    };
+
+    if let Err(e) = validators::validate_nickname(&nickname) {
+        return send_invite_error(ctx, nickname_error(ctx.locale, e)).await;
+    }
+
+    let Some(user) = ctx.user_manager.get_user_by_session_id(session_id).await else {
+        return ctx
+            .send_error_and_disconnect(&err_authentication(ctx.locale), Some("GroupMessageInvite"))
+            .await;
+    };
+
+    if !user.has_permission(Permission::UserMessage) {
+        log_warn!(
+            "GroupMessageInvite from {} (user: {}) without permission",
+            ctx.peer_addr,
+            user.username
+        );
+        return send_invite_error(ctx, err_permission_denied(ctx.locale)).await;
+    }
+
+    let Some(target) = ctx.user_manager.get_session_by_nickname(&nickname).await else {
+        return send_invite_error(ctx, err_nickname_not_online(ctx.locale, &nickname)).await;
+    };
+
+    let members = match ctx
+        .group_manager
+        .add_member(group_id, &user.nickname, &target.nickname)
+    {
+        Ok(members) => members,
+        Err(e) => {
+            let error_msg = match e {
+                AddMemberError::NotFound => err_group_not_found(ctx.locale),
+                AddMemberError::AlreadyMember => {
+                    err_group_already_member(ctx.locale, &target.nickname)
+                }
+                AddMemberError::Full => err_group_too_many_members(ctx.locale, MAX_GROUP_MEMBERS),
+            };
+            return send_invite_error(ctx, error_msg).await;
+        }
+    };
+
+    let response = ServerMessage::GroupMessageInviteResponse {
+        success: true,
+        error: None,
+    };
+    ctx.send_message(&response).await?;
+
+    groups::send_members(group_id, &members, ctx.user_manager).await;
+    Ok(())
+}
+
+/// Handle GroupMessageLeave command
+pub async fn handle_group_message_leave<W>(
+    group_id: u64,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(session_id) = session_id else {
+        log_warn!(
+            "GroupMessageLeave request from {} without login",
+            ctx.peer_addr
+        );
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("GroupMessageLeave"))
+            .await;
+    };
+
+    let Some(user) = ctx.user_manager.get_user_by_session_id(session_id).await else {
+        return ctx
+            .send_error_and_disconnect(&err_authentication(ctx.locale), Some("GroupMessageLeave"))
+            .await;
+    };
+
+    let Some(remaining) = ctx.group_manager.remove_member(group_id, &user.nickname) else {
+        let response = ServerMessage::GroupMessageLeaveResponse {
+            success: false,
+            error: Some(err_group_not_found(ctx.locale)),
+        };
+        return ctx.send_message(&response).await;
+    };
+
+    let response = ServerMessage::GroupMessageLeaveResponse {
+        success: true,
+        error: None,
+    };
+    ctx.send_message(&response).await?;
+
+    // The remaining members see the new list; the leaver's other sessions
+    // see a list without them and close the conversation
+    groups::send_members(group_id, &remaining, ctx.user_manager).await;
+    let update = ServerMessage::GroupMembers {
+        group_id,
+        members: remaining,
+    };
+    ctx.user_manager
+        .broadcast_to_nickname(&user.nickname, &update)
+        .await;
+    Ok(())
+}
+
+/// Translate a nickname validation error
+fn nickname_error(locale: &str, error: NicknameError) -> String {
+    match error {
+        NicknameError::Empty => err_nickname_empty(locale),
+        NicknameError::TooLong => err_nickname_too_long(locale, validators::MAX_NICKNAME_LENGTH),
+        NicknameError::InvalidCharacters => err_nickname_invalid(locale),
+    }
+}
+
+/// Send a failed GroupMessageCreateResponse
+async fn send_create_error<W>(ctx: &mut HandlerContext<'_, W>, error: String) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let response = ServerMessage::GroupMessageCreateResponse {
+        success: false,
+        error: Some(error),
+        group_id: None,
+    };
+    ctx.send_message(&response).await
+}
+
+/// Send a failed GroupMessageInviteResponse
+async fn send_invite_error<W>(ctx: &mut HandlerContext<'_, W>, error: String) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let response = ServerMessage::GroupMessageInviteResponse {
+        success: false,
+        error: Some(error),
+    };
+    ctx.send_message(&response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::testing::{
+        DEFAULT_TEST_LOCALE, TestContext, create_test_context, login_user, read_channel_response,
+        read_server_message,
+    };
+
+    /// Start a group as `session_id`, returning the response fields
+    async fn create(
+        test_ctx: &mut TestContext,
+        session_id: u32,
+        members: &[&str],
+    ) -> (bool, Option<String>, Option<u64>) {
+        handle_group_message_create(
+            members.iter().map(|m| m.to_string()).collect(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        match read_server_message(test_ctx).await {
+            ServerMessage::GroupMessageCreateResponse {
+                success,
+                error,
+                group_id,
+            } => (success, error, group_id),
+            _ => panic!("Expected GroupMessageCreateResponse"),
+        }
+    }
+
+    /// The latest member list update in the channel
+    ///
+    /// Every member gets one, and all test sessions share the channel, so
+    /// this drains it.
+    fn read_members(test_ctx: &mut TestContext) -> Vec<String> {
+        let mut latest = None;
+        while let Ok((msg, _)) = test_ctx.rx.try_recv() {
+            if let ServerMessage::GroupMembers { members, .. } = msg {
+                latest = Some(members);
+            }
+        }
+        latest.expect("Expected GroupMembers")
+    }
+
+    #[tokio::test]
+    async fn test_group_create_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_group_message_create(
+            vec!["bob".to_string()],
+            None,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_group_create_checks_members() {
+        let mut test_ctx = create_test_context().await;
+        let alice = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::UserMessage],
+            false,
+        )
+        .await;
+
+        let (success, error, _) = create(&mut test_ctx, alice, &[]).await;
+        assert!(!success);
+        assert_eq!(error, Some(err_group_no_members(DEFAULT_TEST_LOCALE)));
+
+        let (success, error, _) = create(&mut test_ctx, alice, &["Alice"]).await;
+        assert!(!success);
+        assert_eq!(error, Some(err_cannot_message_self(DEFAULT_TEST_LOCALE)));
+
+        let (success, error, _) = create(&mut test_ctx, alice, &["ghost"]).await;
+        assert!(!success);
+        assert_eq!(
+            error,
+            Some(err_nickname_not_online(DEFAULT_TEST_LOCALE, "ghost"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_group_create_requires_permission() {
+        let mut test_ctx = create_test_context().await;
+        let alice = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+        let _bob = login_user(&mut test_ctx, "bob", "password", &[], false).await;
+
+        let (success, error, _) = create(&mut test_ctx, alice, &["bob"]).await;
+
+        assert!(!success);
+        assert_eq!(error, Some(err_permission_denied(DEFAULT_TEST_LOCALE)));
+    }
+
+    #[tokio::test]
+    async fn test_group_create_limit() {
+        let mut test_ctx = create_test_context().await;
+        let alice = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::UserMessage],
+            false,
+        )
+        .await;
+        let _bob = login_user(&mut test_ctx, "bob", "password", &[], false).await;
+
+        for _ in 0..MAX_GROUPS_PER_USER {
+            let (success, _, _) = create(&mut test_ctx, alice, &["bob"]).await;
+            assert!(success);
+        }
+
+        let (success, error, group_id) = create(&mut test_ctx, alice, &["bob"]).await;
+        assert!(!success);
+        assert_eq!(
+            error,
+            Some(err_group_limit(DEFAULT_TEST_LOCALE, MAX_GROUPS_PER_USER))
+        );
+        assert!(group_id.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_group_conversation() {
+        let mut test_ctx = create_test_context().await;
+        let alice = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::UserMessage],
+            false,
+        )
+        .await;
+        let bob = login_user(&mut test_ctx, "bob", "password", &[], false).await;
+        let _carol = login_user(&mut test_ctx, "carol", "password", &[], false).await;
+
+        // Repeats are dropped and nicknames are shown as the users have them
+        let (success, _, group_id) = create(&mut test_ctx, alice, &["BOB", "bob"]).await;
+        assert!(success);
+        let group_id = group_id.unwrap();
+        assert_eq!(read_members(&mut test_ctx), vec!["alice", "bob"]);
+
+        // Any member can talk, even without the UserMessage permission
+        handle_group_message_send(
+            group_id,
+            "hi all".to_string(),
+            ChatAction::Normal,
+            None,
+            Some(bob),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        match read_channel_response(&mut test_ctx, |msg| {
+            matches!(msg, ServerMessage::GroupMessage { .. })
+        }) {
+            ServerMessage::GroupMessage {
+                group_id: id,
+                from_nickname,
+                message,
+                ..
+            } => {
+                assert_eq!(id, group_id);
+                assert_eq!(from_nickname, "bob");
+                assert_eq!(message, "hi all");
+            }
+            _ => panic!("Expected GroupMessage"),
+        }
+
+        handle_group_message_invite(
+            group_id,
+            "carol".to_string(),
+            Some(alice),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        assert!(matches!(
+            read_server_message(&mut test_ctx).await,
+            ServerMessage::GroupMessageInviteResponse { success: true, .. }
+        ));
+        assert_eq!(read_members(&mut test_ctx), vec!["alice", "bob", "carol"]);
+
+        handle_group_message_leave(group_id, Some(bob), &mut test_ctx.handler_context())
+            .await
+            .unwrap();
+        assert!(matches!(
+            read_server_message(&mut test_ctx).await,
+            ServerMessage::GroupMessageLeaveResponse { success: true, .. }
+        ));
+        assert_eq!(read_members(&mut test_ctx), vec!["alice", "carol"]);
+
+        // Once out, bob can't talk there anymore
+        handle_group_message_send(
+            group_id,
+            "still here?".to_string(),
+            ChatAction::Normal,
+            None,
+            Some(bob),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::GroupMessageSendResponse { success, error, .. } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_group_not_found(DEFAULT_TEST_LOCALE)));
+            }
+            _ => panic!("Expected GroupMessageSendResponse"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_group_invite_errors() {
+        let mut test_ctx = create_test_context().await;
+        let alice = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::UserMessage],
+            false,
+        )
+        .await;
+        let _bob = login_user(&mut test_ctx, "bob", "password", &[], false).await;
+
+        let (_, _, group_id) = create(&mut test_ctx, alice, &["bob"]).await;
+        let group_id = group_id.unwrap();
+
+        for (nickname, expected) in [
+            ("bob", err_group_already_member(DEFAULT_TEST_LOCALE, "bob")),
+            (
+                "ghost",
+                err_nickname_not_online(DEFAULT_TEST_LOCALE, "ghost"),
+            ),
+        ] {
+            handle_group_message_invite(
+                group_id,
+                nickname.to_string(),
+                Some(alice),
+                &mut test_ctx.handler_context(),
+            )
+            .await
+            .unwrap();
+            match read_server_message(&mut test_ctx).await {
+                ServerMessage::GroupMessageInviteResponse { success, error } => {
+                    assert!(!success);
+                    assert_eq!(error, Some(expected));
+                }
+                _ => panic!("Expected GroupMessageInviteResponse"),
+            }
+        }
+    }
+}
@@ -0,0 +1,270 @@
+//! Handler for GuestUpgrade command
+//!
+//! Lets a guest register a personal account from their session, when the
+//! server has a guest registration role (`--guest-upgrade-role`). The new
+//! account gets the role account's permissions. The guest stays logged in as
+//! a guest and uses the new account from their next login.
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+use nexus_common::validators::{self, PasswordError, UsernameError};
+
+use super::{
+    HandlerContext, err_authentication, err_database, err_guest_upgrade_disabled,
+    err_guest_upgrade_not_guest, err_not_logged_in, err_password_empty, err_password_too_long,
+    err_username_empty, err_username_exists, err_username_invalid, err_username_too_long,
+};
+use crate::db::hash_password;
+use crate::guests;
+
+/// Handle GuestUpgrade command
+pub async fn handle_guest_upgrade<W>(
+    username: String,
+    password: String,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let failure = |error: String| ServerMessage::GuestUpgradeResponse {
+        success: false,
+        error: Some(error),
+        username: None,
+    };
+
+    let Some(session_id) = session_id else {
+        log_warn!("GuestUpgrade request from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("GuestUpgrade"))
+            .await;
+    };
+
+    let Some(user) = ctx.user_manager.get_user_by_session_id(session_id).await else {
+        return ctx
+            .send_error_and_disconnect(&err_authentication(ctx.locale), Some("GuestUpgrade"))
+            .await;
+    };
+
+    if !guests::is_guest(&user.username) {
+        return ctx
+            .send_message(&failure(err_guest_upgrade_not_guest(ctx.locale)))
+            .await;
+    }
+
+    let Some(role) = ctx.guest_config.upgrade_role.as_deref() else {
+        return ctx
+            .send_message(&failure(err_guest_upgrade_disabled(ctx.locale)))
+            .await;
+    };
+
+    if let Err(e) = validators::validate_username(&username) {
+        let error = match e {
+            UsernameError::Empty => err_username_empty(ctx.locale),
+            UsernameError::TooLong => {
+                err_username_too_long(ctx.locale, validators::MAX_USERNAME_LENGTH)
+            }
+            UsernameError::InvalidCharacters => err_username_invalid(ctx.locale),
+        };
+        return ctx.send_message(&failure(error)).await;
+    }
+
+    if let Err(e) = validators::validate_password(&password) {
+        let error = match e {
+            PasswordError::Empty => err_password_empty(ctx.locale),
+            PasswordError::TooLong => {
+                err_password_too_long(ctx.locale, validators::MAX_PASSWORD_LENGTH)
+            }
+        };
+        return ctx.send_message(&failure(error)).await;
+    }
+
+    // The guest account itself exists, so this also refuses "guest"
+    match ctx.db.users.username_exists(&username).await {
+        Ok(false) => {}
+        Ok(true) => {
+            return ctx
+                .send_message(&failure(err_username_exists(ctx.locale, &username)))
+                .await;
+        }
+        Err(e) => {
+            log_error!("GuestUpgrade database error: {}", e);
+            return ctx.send_message(&failure(err_database(ctx.locale))).await;
+        }
+    }
+
+    // Nor can it take a nickname someone reserved
+    match ctx.db.nicknames.is_registered(&username).await {
+        Ok(false) => {}
+        Ok(true) => {
+            return ctx
+                .send_message(&failure(err_username_exists(ctx.locale, &username)))
+                .await;
+        }
+        Err(e) => {
+            log_error!("GuestUpgrade database error: {}", e);
+            return ctx.send_message(&failure(err_database(ctx.locale))).await;
+        }
+    }
+
+    let hashed_password = match hash_password(&password, false) {
+        Ok(hash) => hash,
+        Err(e) => {
+            log_error!("Password hashing error: {}", e);
+            return ctx.send_message(&failure(err_database(ctx.locale))).await;
+        }
+    };
+
+    let response = match guests::create_account(ctx.db, &username, &hashed_password, role).await {
+        Ok(account) => {
+            log_info!(
+                "Guest {} from {} registered account '{}'",
+                user.nickname,
+                ctx.peer_addr,
+                account.username
+            );
+            ServerMessage::GuestUpgradeResponse {
+                success: true,
+                error: None,
+                username: Some(account.username),
+            }
+        }
+        Err(e) => {
+            log_error!("GuestUpgrade database error: {}", e);
+            failure(err_database(ctx.locale))
+        }
+    };
+    ctx.send_message(&response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::sql::GUEST_USERNAME;
+    use crate::handlers::testing::{
+        DEFAULT_TEST_LOCALE, TestContext, create_test_context, login_user, read_server_message,
+    };
+    use crate::users::user::NewSessionParams;
+
+    /// Add a guest session, returning its session ID
+    async fn add_guest(test_ctx: &mut TestContext) -> u32 {
+        test_ctx
+            .user_manager
+            .add_user(NewSessionParams {
+                session_id: 0,
+                db_user_id: 0,
+                username: GUEST_USERNAME.to_string(),
+                is_admin: false,
+                is_shared: true,
+                permissions: std::collections::HashSet::new(),
+                address: test_ctx.peer_addr,
+                created_at: 0,
+                tx: test_ctx.tx.clone(),
+                features: vec![],
+                locale: DEFAULT_TEST_LOCALE.to_string(),
+                avatar: None,
+                nickname: "Guest1234".to_string(),
+                is_away: false,
+                status: None,
+                client: None,
+            })
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_guest_upgrade_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_guest_upgrade(
+            "alice".to_string(),
+            "password".to_string(),
+            None,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_err(), "Should disconnect when not logged in");
+    }
+
+    #[tokio::test]
+    async fn test_guest_upgrade_requires_guest() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "bob", "password", &[], false).await;
+
+        handle_guest_upgrade(
+            "alice".to_string(),
+            "password".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::GuestUpgradeResponse { success, error, .. } => {
+                assert!(!success);
+                assert_eq!(
+                    error,
+                    Some(err_guest_upgrade_not_guest(DEFAULT_TEST_LOCALE))
+                );
+            }
+            response => panic!("Expected GuestUpgradeResponse, got {:?}", response),
+        }
+        assert!(!test_ctx.db.users.username_exists("alice").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_guest_upgrade_disabled_by_default() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = add_guest(&mut test_ctx).await;
+
+        handle_guest_upgrade(
+            "alice".to_string(),
+            "password".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::GuestUpgradeResponse { success, error, .. } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_guest_upgrade_disabled(DEFAULT_TEST_LOCALE)));
+            }
+            response => panic!("Expected GuestUpgradeResponse, got {:?}", response),
+        }
+        assert!(!test_ctx.db.users.username_exists("alice").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_guest_upgrade_creates_account() {
+        let mut test_ctx = create_test_context().await;
+        test_ctx.guest_config.upgrade_role = Some("members".to_string());
+        let session_id = add_guest(&mut test_ctx).await;
+
+        handle_guest_upgrade(
+            "alice".to_string(),
+            "password".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::GuestUpgradeResponse {
+                success, username, ..
+            } => {
+                assert!(success);
+                assert_eq!(username.as_deref(), Some("alice"));
+            }
+            response => panic!("Expected GuestUpgradeResponse, got {:?}", response),
+        }
+        assert!(test_ctx.db.users.username_exists("alice").await.unwrap());
+    }
+}
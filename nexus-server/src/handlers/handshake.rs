@@ -8,15 +8,24 @@ use nexus_common::protocol::ServerMessage;
 use nexus_common::validators::{self, VersionError};
 use nexus_common::version::{self, CompatibilityResult};
 
+use crate::identity::ServerIdentity;
+use crate::reputation::POW_DIFFICULTY;
+
 use super::{
     HandlerContext, err_handshake_already_completed, err_version_client_too_new, err_version_empty,
     err_version_invalid_semver, err_version_major_mismatch, err_version_too_long,
 };
 
 /// Handle a handshake request from the client
+///
+/// A successful response carries the server identity, so the client can
+/// check it against the key it pinned for this server, and the connection's
+/// proof-of-work challenge if its login must include one.
 pub async fn handle_handshake<W>(
     version: String,
     handshake_complete: &mut bool,
+    identity: &ServerIdentity,
+    pow_challenge: Option<&str>,
     ctx: &mut HandlerContext<'_, W>,
 ) -> io::Result<()>
 where
@@ -26,11 +35,16 @@ where
 
     // Check for duplicate handshake
     if *handshake_complete {
-        eprintln!("Duplicate handshake attempt from {}", ctx.peer_addr);
+        log_warn!("Duplicate handshake attempt from {}", ctx.peer_addr);
         let response = ServerMessage::HandshakeResponse {
             success: false,
             version: Some(server_version_str.to_string()),
             error: Some(err_handshake_already_completed(ctx.locale)),
+            identity_key: None,
+            identity_signature: None,
+            pow_challenge: None,
+            pow_difficulty: None,
+            oidc: None,
         };
         ctx.send_message(&response).await?;
         return Err(io::Error::other("Duplicate handshake"));
@@ -51,6 +65,11 @@ where
                 success: false,
                 version: Some(server_version_str.to_string()),
                 error: Some(error_msg),
+                identity_key: None,
+                identity_signature: None,
+                pow_challenge: None,
+                pow_difficulty: None,
+                oidc: None,
             };
             ctx.send_message(&response).await?;
             return Err(io::Error::other("Invalid version string"));
@@ -66,6 +85,11 @@ where
                 success: true,
                 version: Some(server_version_str.to_string()),
                 error: None,
+                identity_key: Some(identity.public_key.clone()),
+                identity_signature: Some(identity.signature.clone()),
+                pow_challenge: pow_challenge.map(str::to_string),
+                pow_difficulty: pow_challenge.map(|_| POW_DIFFICULTY),
+                oidc: ctx.oidc.provider(),
             };
             ctx.send_message(&response).await
         }
@@ -73,9 +97,11 @@ where
             server_major,
             client_major,
         } => {
-            eprintln!(
+            log_warn!(
                 "Handshake from {} failed: major version mismatch (client: {}, server: {})",
-                ctx.peer_addr, client_major, server_major
+                ctx.peer_addr,
+                client_major,
+                server_major
             );
             let response = ServerMessage::HandshakeResponse {
                 success: false,
@@ -85,6 +111,11 @@ where
                     server_major,
                     client_major,
                 )),
+                identity_key: None,
+                identity_signature: None,
+                pow_challenge: None,
+                pow_difficulty: None,
+                oidc: None,
             };
             ctx.send_message(&response).await?;
             Err(io::Error::other("Major version mismatch"))
@@ -93,9 +124,11 @@ where
             server_minor,
             client_minor,
         } => {
-            eprintln!(
+            log_warn!(
                 "Handshake from {} failed: client minor version {} is newer than server minor version {}",
-                ctx.peer_addr, client_minor, server_minor
+                ctx.peer_addr,
+                client_minor,
+                server_minor
             );
             let response = ServerMessage::HandshakeResponse {
                 success: false,
@@ -105,6 +138,11 @@ where
                     server_version_str,
                     &version,
                 )),
+                identity_key: None,
+                identity_signature: None,
+                pow_challenge: None,
+                pow_difficulty: None,
+                oidc: None,
             };
             ctx.send_message(&response).await?;
             Err(io::Error::other("Client version too new"))
@@ -117,6 +155,15 @@ mod tests {
     use super::*;
     use crate::handlers::testing::{create_test_context, read_server_message};
     use nexus_common::version;
+    use ring::signature::Ed25519KeyPair;
+
+    /// Fixed Ed25519 seed so identities from separate calls compare equal
+    const TEST_IDENTITY_SEED: [u8; 32] = [7; 32];
+
+    fn test_identity() -> ServerIdentity {
+        let key_pair = Ed25519KeyPair::from_seed_unchecked(&TEST_IDENTITY_SEED).unwrap();
+        ServerIdentity::new(&key_pair, "AA:BB:CC:DD")
+    }
 
     #[tokio::test]
     async fn test_successful_handshake() {
@@ -128,6 +175,8 @@ mod tests {
         let result = handle_handshake(
             version,
             &mut handshake_complete,
+            &test_identity(),
+            None,
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -148,15 +197,59 @@ mod tests {
                 success,
                 version,
                 error,
+                identity_key,
+                identity_signature,
+                pow_challenge,
+                pow_difficulty,
+                oidc,
             } => {
                 assert!(success, "Response should indicate success");
                 assert_eq!(version, Some(nexus_common::PROTOCOL_VERSION.to_string()));
                 assert!(error.is_none(), "Error should be None on success");
+                let identity = test_identity();
+                assert_eq!(identity_key, Some(identity.public_key));
+                assert_eq!(identity_signature, Some(identity.signature));
+                assert!(pow_challenge.is_none());
+                assert!(pow_difficulty.is_none());
+                assert!(oidc.is_none(), "Single sign-on is not configured");
             }
             _ => panic!("Expected HandshakeResponse"),
         }
     }
 
+    #[tokio::test]
+    async fn test_handshake_sends_pow_challenge() {
+        let mut test_ctx = create_test_context().await;
+        let mut handshake_complete = false;
+
+        let result = handle_handshake(
+            nexus_common::PROTOCOL_VERSION.to_string(),
+            &mut handshake_complete,
+            &test_identity(),
+            Some("00112233445566778899aabbccddeeff"),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::HandshakeResponse {
+                success,
+                pow_challenge,
+                pow_difficulty,
+                ..
+            } => {
+                assert!(success);
+                assert_eq!(
+                    pow_challenge.as_deref(),
+                    Some("00112233445566778899aabbccddeeff")
+                );
+                assert_eq!(pow_difficulty, Some(POW_DIFFICULTY));
+            }
+            other => panic!("Expected HandshakeResponse, got {:?}", other),
+        }
+    }
+
     #[tokio::test]
     async fn test_compatible_older_minor_version() {
         let mut test_ctx = create_test_context().await;
@@ -170,6 +263,8 @@ mod tests {
             let result = handle_handshake(
                 client_version,
                 &mut handshake_complete,
+                &test_identity(),
+                None,
                 &mut test_ctx.handler_context(),
             )
             .await;
@@ -193,6 +288,8 @@ mod tests {
         let result = handle_handshake(
             client_version,
             &mut handshake_complete,
+            &test_identity(),
+            None,
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -215,6 +312,8 @@ mod tests {
         let result = handle_handshake(
             client_version,
             &mut handshake_complete,
+            &test_identity(),
+            None,
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -258,6 +357,8 @@ mod tests {
         let result = handle_handshake(
             client_version.clone(),
             &mut handshake_complete,
+            &test_identity(),
+            None,
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -294,6 +395,8 @@ mod tests {
         let result = handle_handshake(
             "not-valid-semver".to_string(),
             &mut handshake_complete,
+            &test_identity(),
+            None,
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -332,6 +435,8 @@ mod tests {
         let result1 = handle_handshake(
             version.clone(),
             &mut handshake_complete,
+            &test_identity(),
+            None,
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -345,6 +450,8 @@ mod tests {
         let result2 = handle_handshake(
             version,
             &mut handshake_complete,
+            &test_identity(),
+            None,
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -371,6 +478,8 @@ mod tests {
         let result = handle_handshake(
             client_version,
             &mut handshake_complete,
+            &test_identity(),
+            None,
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -0,0 +1,514 @@
+//! Handler for ImpersonateQuery command
+//!
+//! Lets an admin see the server "as user X" without logging in as them:
+//! the account's effective permissions, what it would see in its file area,
+//! and which channels it would see in the channel list. Read-only, and
+//! every query is recorded in the audit log before anything is returned.
+
+use std::io;
+use std::path::Path;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::{ChannelInfo, EffectivePermissions, FileEntry, ServerMessage};
+use nexus_common::validators::{self, FilePathError, UsernameError};
+
+use super::file_list::{read_directory_entries, should_hide_entry};
+use super::permission_audit::effective_permissions;
+#[cfg(test)]
+use super::testing::DEFAULT_TEST_LOCALE;
+use super::{
+    HandlerContext, err_authentication, err_database, err_file_not_directory, err_file_not_found,
+    err_file_path_invalid, err_file_path_too_long, err_not_logged_in, err_permission_denied,
+    err_user_not_found, err_username_empty, err_username_invalid, err_username_too_long,
+};
+use crate::db::Permission;
+use crate::db::audit::AUDIT_ACTION_IMPERSONATE_QUERY;
+use crate::files::path::PathError;
+use crate::files::{build_and_validate_candidate_path, resolve_path, resolve_user_area};
+
+/// Handle ImpersonateQuery command
+pub async fn handle_impersonate_query<W>(
+    username: String,
+    path: Option<String>,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first (before revealing validation errors to unauthenticated users)
+    let Some(session_id) = session_id else {
+        log_warn!(
+            "ImpersonateQuery request from {} without login",
+            ctx.peer_addr
+        );
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("ImpersonateQuery"))
+            .await;
+    };
+
+    let requesting_user_session = match ctx.user_manager.get_user_by_session_id(session_id).await {
+        Some(user) => user,
+        None => {
+            return ctx
+                .send_error_and_disconnect(
+                    &err_authentication(ctx.locale),
+                    Some("ImpersonateQuery"),
+                )
+                .await;
+        }
+    };
+
+    // Impersonation is admin-only (it reveals another user's files and channels)
+    if !requesting_user_session.is_admin {
+        log_warn!(
+            "ImpersonateQuery from {} (user: {}) without permission",
+            ctx.peer_addr,
+            requesting_user_session.username
+        );
+        return send_failure(err_permission_denied(ctx.locale), ctx).await;
+    }
+
+    if let Err(e) = validators::validate_username(&username) {
+        let error_msg = match e {
+            UsernameError::Empty => err_username_empty(ctx.locale),
+            UsernameError::TooLong => {
+                err_username_too_long(ctx.locale, validators::MAX_USERNAME_LENGTH)
+            }
+            UsernameError::InvalidCharacters => err_username_invalid(ctx.locale),
+        };
+        return send_failure(error_msg, ctx).await;
+    }
+
+    let path = path.unwrap_or_else(|| "/".to_string());
+    if let Err(e) = validators::validate_file_path(&path) {
+        let error_msg = match e {
+            FilePathError::TooLong => {
+                err_file_path_too_long(ctx.locale, validators::MAX_FILE_PATH_LENGTH)
+            }
+            FilePathError::ContainsNull
+            | FilePathError::InvalidCharacters
+            | FilePathError::ContainsWindowsDrive => err_file_path_invalid(ctx.locale),
+        };
+        return send_failure(error_msg, ctx).await;
+    }
+
+    let account = match ctx.db.users.get_user_by_username(&username).await {
+        Ok(Some(account)) => account,
+        Ok(None) => {
+            return send_failure(err_user_not_found(ctx.locale, &username), ctx).await;
+        }
+        Err(e) => {
+            log_error!("Database error getting user {}: {}", username, e);
+            return send_failure(err_database(ctx.locale), ctx).await;
+        }
+    };
+
+    let effective = match effective_permissions(&ctx.db.users, account).await {
+        Ok(effective) => effective,
+        Err(e) => {
+            log_error!("Database error getting permissions of {}: {}", username, e);
+            return send_failure(err_database(ctx.locale), ctx).await;
+        }
+    };
+
+    // Files, as the user's FileList would return them
+    let entries = match ctx.file_root {
+        Some(file_root) if has_effective(&effective, Permission::FileList) => {
+            match list_user_files(file_root, &effective, &path).await {
+                Ok(entries) => Some(entries),
+                Err(error) => {
+                    let error_msg = match error {
+                        ListError::NotFound => err_file_not_found(ctx.locale),
+                        ListError::NotDirectory => err_file_not_directory(ctx.locale),
+                        ListError::InvalidPath => err_file_path_invalid(ctx.locale),
+                    };
+                    return send_failure(error_msg, ctx).await;
+                }
+            }
+        }
+        _ => None,
+    };
+
+    // Channels, as the user's ChatList would return them from any of their sessions
+    let channels = if has_effective(&effective, Permission::ChatList) {
+        let session_ids = ctx
+            .user_manager
+            .get_session_ids_for_user(&effective.username)
+            .await;
+        let channels = ctx
+            .channel_manager
+            .list_for_sessions(&session_ids, effective.is_admin)
+            .await
+            .into_iter()
+            .map(|info| ChannelInfo {
+                name: info.name,
+                topic: info.topic,
+                member_count: info.member_count,
+                secret: info.secret,
+                language: info.language,
+            })
+            .collect();
+        Some(channels)
+    } else {
+        None
+    };
+
+    // Nothing is returned unless the query made it into the audit log
+    let details = format!("path: {path}");
+    if let Err(e) = ctx
+        .db
+        .audit
+        .record(
+            AUDIT_ACTION_IMPERSONATE_QUERY,
+            &effective.username,
+            &requesting_user_session.username,
+            Some(&details),
+        )
+        .await
+    {
+        log_error!(
+            "Failed to record impersonation of {} in audit log: {}",
+            effective.username,
+            e
+        );
+        return send_failure(err_database(ctx.locale), ctx).await;
+    }
+
+    if ctx.debug {
+        log_debug!(
+            "{} viewed {} as {} ({})",
+            requesting_user_session.username,
+            path,
+            effective.username,
+            ctx.peer_addr
+        );
+    }
+
+    let response = ServerMessage::ImpersonateQueryResponse {
+        success: true,
+        error: None,
+        path: entries.as_ref().map(|_| path),
+        entries,
+        channels,
+        effective: Some(effective),
+    };
+    ctx.send_message(&response).await
+}
+
+/// Why a directory couldn't be listed
+enum ListError {
+    NotFound,
+    NotDirectory,
+    InvalidPath,
+}
+
+/// Whether the account can use a permission
+fn has_effective(effective: &EffectivePermissions, permission: Permission) -> bool {
+    effective
+        .permissions
+        .iter()
+        .any(|p| p.permission == permission.as_str())
+}
+
+/// List a directory of the user's file area with the user's visibility rules
+///
+/// Hidden files are left out, as in the client's default view.
+async fn list_user_files(
+    file_root: &Path,
+    effective: &EffectivePermissions,
+    path: &str,
+) -> Result<Vec<FileEntry>, ListError> {
+    // The user's area might not exist yet - same empty listing FileList gives
+    let Ok(area_root) = resolve_user_area(file_root, &effective.username).canonicalize() else {
+        return Ok(Vec::new());
+    };
+
+    let candidate =
+        build_and_validate_candidate_path(&area_root, path).map_err(|_| ListError::InvalidPath)?;
+    let resolved = resolve_path(&area_root, &candidate).map_err(|e| match e {
+        PathError::NotFound => ListError::NotFound,
+        _ => ListError::InvalidPath,
+    })?;
+
+    if !resolved.is_dir() {
+        return Err(ListError::NotDirectory);
+    }
+
+    if should_hide_entry(
+        &resolved,
+        &area_root,
+        effective.is_admin,
+        &effective.username,
+    ) {
+        return Ok(Vec::new());
+    }
+
+    tokio::task::spawn_blocking(move || read_directory_entries(&resolved, &area_root, false))
+        .await
+        .ok()
+        .flatten()
+        .ok_or(ListError::NotFound)
+}
+
+/// Send a failed ImpersonateQueryResponse
+async fn send_failure<W>(error: String, ctx: &mut HandlerContext<'_, W>) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let response = ServerMessage::ImpersonateQueryResponse {
+        success: false,
+        error: Some(error),
+        effective: None,
+        path: None,
+        entries: None,
+        channels: None,
+    };
+    ctx.send_message(&response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::db;
+    use crate::handlers::testing::{
+        create_test_context, login_user, read_server_message, setup_file_area_full,
+    };
+
+    #[tokio::test]
+    async fn test_impersonate_query_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_impersonate_query(
+            "alice".to_string(),
+            None,
+            None,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_err(), "ImpersonateQuery should require login");
+    }
+
+    #[tokio::test]
+    async fn test_impersonate_query_requires_admin() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[db::Permission::UserEdit],
+            false,
+        )
+        .await;
+
+        let result = handle_impersonate_query(
+            "alice".to_string(),
+            None,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::ImpersonateQueryResponse { success, error, .. } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_permission_denied(DEFAULT_TEST_LOCALE)));
+            }
+            other => panic!("Expected ImpersonateQueryResponse, got {:?}", other),
+        }
+        assert!(test_ctx.db.audit.list().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_impersonate_query_user_not_found() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        let result = handle_impersonate_query(
+            "nobody".to_string(),
+            None,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::ImpersonateQueryResponse { success, error, .. } => {
+                assert!(!success);
+                assert_eq!(
+                    error,
+                    Some(err_user_not_found(DEFAULT_TEST_LOCALE, "nobody"))
+                );
+            }
+            other => panic!("Expected ImpersonateQueryResponse, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_impersonate_query_lists_files_as_user() {
+        let mut test_ctx = create_test_context().await;
+        let file_area = setup_file_area_full(&mut test_ctx);
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+        login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[db::Permission::FileList],
+            false,
+        )
+        .await;
+        fs::write(
+            file_area.path().join("shared/Submissions [NEXUS-DB]/a.txt"),
+            "x",
+        )
+        .unwrap();
+
+        let result = handle_impersonate_query(
+            "alice".to_string(),
+            Some("/Submissions [NEXUS-DB]".to_string()),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::ImpersonateQueryResponse {
+                success,
+                path,
+                entries,
+                channels,
+                effective,
+                ..
+            } => {
+                assert!(success);
+                assert_eq!(path.as_deref(), Some("/Submissions [NEXUS-DB]"));
+                // Dropbox contents are hidden from non-admins
+                assert_eq!(entries, Some(Vec::new()));
+                // No chat_list permission
+                assert!(channels.is_none());
+                assert_eq!(effective.unwrap().username, "alice");
+            }
+            other => panic!("Expected ImpersonateQueryResponse, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_impersonate_query_without_file_list_permission() {
+        let mut test_ctx = create_test_context().await;
+        let _file_area = setup_file_area_full(&mut test_ctx);
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+        login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[db::Permission::ChatList],
+            false,
+        )
+        .await;
+
+        let result = handle_impersonate_query(
+            "alice".to_string(),
+            None,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::ImpersonateQueryResponse {
+                success,
+                path,
+                entries,
+                channels,
+                ..
+            } => {
+                assert!(success);
+                assert!(path.is_none());
+                assert!(entries.is_none());
+                assert!(channels.is_some());
+            }
+            other => panic!("Expected ImpersonateQueryResponse, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_impersonate_query_hides_secret_channels() {
+        let mut test_ctx = create_test_context().await;
+        let admin_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+        let alice_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[db::Permission::ChatList],
+            false,
+        )
+        .await;
+        test_ctx
+            .channel_manager
+            .join("#mine", alice_id)
+            .await
+            .unwrap();
+        test_ctx
+            .channel_manager
+            .join("#theirs", admin_id)
+            .await
+            .unwrap();
+        for channel in ["#mine", "#theirs"] {
+            test_ctx
+                .channel_manager
+                .set_secret(channel, true)
+                .await
+                .unwrap();
+        }
+
+        let result = handle_impersonate_query(
+            "alice".to_string(),
+            None,
+            Some(admin_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::ImpersonateQueryResponse { channels, .. } => {
+                let names: Vec<String> = channels.unwrap().into_iter().map(|c| c.name).collect();
+                assert!(names.contains(&"#mine".to_string()));
+                assert!(!names.contains(&"#theirs".to_string()));
+            }
+            other => panic!("Expected ImpersonateQueryResponse, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_impersonate_query_is_audited() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+        login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let result = handle_impersonate_query(
+            "alice".to_string(),
+            Some("/docs".to_string()),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+        let _ = read_server_message(&mut test_ctx).await;
+
+        let audit = test_ctx.db.audit.list().await.unwrap();
+        assert_eq!(audit.len(), 1);
+        assert_eq!(audit[0].action, AUDIT_ACTION_IMPERSONATE_QUERY);
+        assert_eq!(audit[0].target, "alice");
+        assert_eq!(audit[0].actor, "admin");
+        assert_eq!(audit[0].details.as_deref(), Some("path: /docs"));
+    }
+}
@@ -0,0 +1,395 @@
+//! Handlers for LdapGroupList, LdapGroupSet and LdapGroupDelete commands
+//!
+//! Admins map directory groups to permissions. Users who log in with
+//! directory credentials get the combined permissions of the mapped groups
+//! they are members of, and are admins if any of those groups is an admin
+//! group. Mappings can be set up before directory logins are turned on.
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+use nexus_common::validators::{self, LdapGroupError, PermissionsError};
+
+use super::{
+    HandlerContext, err_authentication, err_database, err_ldap_group_empty,
+    err_ldap_group_invalid_characters, err_ldap_group_not_found, err_ldap_group_too_long,
+    err_not_logged_in, err_permission_denied, err_permissions_contains_newlines,
+    err_permissions_empty_permission, err_permissions_invalid_characters,
+    err_permissions_permission_too_long, err_permissions_too_many, err_unknown_permission,
+};
+use crate::db::Permission;
+use crate::users::user::UserSession;
+
+/// Handle LdapGroupList command
+pub async fn handle_ldap_group_list<W>(
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let failure = |error: String| ServerMessage::LdapGroupListResponse {
+        success: false,
+        error: Some(error),
+        groups: None,
+    };
+
+    let Some(user) = admin_user("LdapGroupList", session_id, ctx).await? else {
+        return Ok(());
+    };
+    if !user.is_admin {
+        return ctx
+            .send_message(&failure(err_permission_denied(ctx.locale)))
+            .await;
+    }
+
+    let response = match ctx.db.ldap.list_groups().await {
+        Ok(groups) => ServerMessage::LdapGroupListResponse {
+            success: true,
+            error: None,
+            groups: Some(groups),
+        },
+        Err(e) => {
+            log_error!("LdapGroupList database error: {}", e);
+            failure(err_database(ctx.locale))
+        }
+    };
+    ctx.send_message(&response).await
+}
+
+/// Handle LdapGroupSet command
+///
+/// Replaces the group's mapping. Permissions are stored without duplicates;
+/// admin groups keep theirs, which only matter if the flag is cleared later.
+pub async fn handle_ldap_group_set<W>(
+    group: String,
+    permissions: Vec<String>,
+    is_admin: bool,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let failure = |error: String| ServerMessage::LdapGroupSetResponse {
+        success: false,
+        error: Some(error),
+        group: None,
+    };
+
+    let Some(user) = admin_user("LdapGroupSet", session_id, ctx).await? else {
+        return Ok(());
+    };
+    if !user.is_admin {
+        return ctx
+            .send_message(&failure(err_permission_denied(ctx.locale)))
+            .await;
+    }
+
+    if let Err(error) = check_group(&group, ctx.locale) {
+        return ctx.send_message(&failure(error)).await;
+    }
+
+    if let Err(e) = validators::validate_permissions(&permissions) {
+        let error = match e {
+            PermissionsError::TooMany => {
+                err_permissions_too_many(ctx.locale, nexus_common::PERMISSIONS_COUNT)
+            }
+            PermissionsError::EmptyPermission => err_permissions_empty_permission(ctx.locale),
+            PermissionsError::PermissionTooLong => {
+                err_permissions_permission_too_long(ctx.locale, validators::MAX_PERMISSION_LENGTH)
+            }
+            PermissionsError::ContainsNewlines => err_permissions_contains_newlines(ctx.locale),
+            PermissionsError::InvalidCharacters => err_permissions_invalid_characters(ctx.locale),
+        };
+        return ctx.send_message(&failure(error)).await;
+    }
+
+    let mut names: Vec<String> = Vec::with_capacity(permissions.len());
+    for permission in &permissions {
+        match Permission::parse(permission) {
+            Some(parsed) => {
+                let name = parsed.as_str().to_string();
+                if !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+            None => {
+                return ctx
+                    .send_message(&failure(err_unknown_permission(ctx.locale, permission)))
+                    .await;
+            }
+        }
+    }
+
+    let response = match ctx.db.ldap.set_group(&group, &names, is_admin).await {
+        Ok(()) => {
+            log_info!(
+                "{} mapped directory group {} (admin: {}, permissions: {})",
+                user.username,
+                group,
+                is_admin,
+                names.join(", ")
+            );
+            ServerMessage::LdapGroupSetResponse {
+                success: true,
+                error: None,
+                group: Some(group),
+            }
+        }
+        Err(e) => {
+            log_error!("LdapGroupSet database error: {}", e);
+            failure(err_database(ctx.locale))
+        }
+    };
+    ctx.send_message(&response).await
+}
+
+/// Handle LdapGroupDelete command
+///
+/// Members keep their accounts; they lose the group's permissions the next
+/// time they log in.
+pub async fn handle_ldap_group_delete<W>(
+    group: String,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let failure = |error: String| ServerMessage::LdapGroupDeleteResponse {
+        success: false,
+        error: Some(error),
+        group: None,
+    };
+
+    let Some(user) = admin_user("LdapGroupDelete", session_id, ctx).await? else {
+        return Ok(());
+    };
+    if !user.is_admin {
+        return ctx
+            .send_message(&failure(err_permission_denied(ctx.locale)))
+            .await;
+    }
+
+    if let Err(error) = check_group(&group, ctx.locale) {
+        return ctx.send_message(&failure(error)).await;
+    }
+
+    let response = match ctx.db.ldap.delete_group(&group).await {
+        Ok(true) => {
+            log_info!("{} removed directory group {}", user.username, group);
+            ServerMessage::LdapGroupDeleteResponse {
+                success: true,
+                error: None,
+                group: Some(group),
+            }
+        }
+        Ok(false) => failure(err_ldap_group_not_found(ctx.locale, &group)),
+        Err(e) => {
+            log_error!("LdapGroupDelete database error: {}", e);
+            failure(err_database(ctx.locale))
+        }
+    };
+    ctx.send_message(&response).await
+}
+
+/// Validate a group name, returning a translated error
+fn check_group(group: &str, locale: &str) -> Result<(), String> {
+    validators::validate_ldap_group(group).map_err(|e| match e {
+        LdapGroupError::Empty => err_ldap_group_empty(locale),
+        LdapGroupError::TooLong => {
+            err_ldap_group_too_long(locale, validators::MAX_LDAP_GROUP_LENGTH)
+        }
+        LdapGroupError::InvalidCharacters => err_ldap_group_invalid_characters(locale),
+    })
+}
+
+/// Get the logged-in user sending a directory group command
+///
+/// Disconnects (and returns `None`) when there is no valid session. Logs
+/// attempts by non-admins, which the caller refuses.
+async fn admin_user<W>(
+    command: &str,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<Option<UserSession>>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(session_id) = session_id else {
+        log_warn!("{} request from {} without login", command, ctx.peer_addr);
+        ctx.send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some(command))
+            .await?;
+        return Ok(None);
+    };
+
+    match ctx.user_manager.get_user_by_session_id(session_id).await {
+        Some(user) => {
+            if !user.is_admin {
+                log_warn!(
+                    "{} from {} (user: {}) without permission",
+                    command,
+                    ctx.peer_addr,
+                    user.username
+                );
+            }
+            Ok(Some(user))
+        }
+        None => {
+            ctx.send_error_and_disconnect(&err_authentication(ctx.locale), Some(command))
+                .await?;
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::testing::{create_test_context, login_user, read_server_message};
+
+    #[tokio::test]
+    async fn test_ldap_group_list_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_ldap_group_list(None, &mut test_ctx.handler_context()).await;
+
+        assert!(result.is_err(), "Should disconnect when not logged in");
+    }
+
+    #[tokio::test]
+    async fn test_ldap_group_set_requires_admin() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::UserEdit, Permission::UserCreate],
+            false,
+        )
+        .await;
+
+        handle_ldap_group_set(
+            "CN=IT,DC=example".to_string(),
+            vec![],
+            true,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::LdapGroupSetResponse { success, error, .. } => {
+                assert!(!success);
+                assert!(error.is_some());
+            }
+            response => panic!("Expected LdapGroupSetResponse, got {:?}", response),
+        }
+        assert!(test_ctx.db.ldap.list_groups().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_ldap_group_set_list_and_delete() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        handle_ldap_group_set(
+            "CN=Staff,DC=example".to_string(),
+            vec![
+                "chat_send".to_string(),
+                "file_list".to_string(),
+                "chat_send".to_string(),
+            ],
+            false,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::LdapGroupSetResponse { success, group, .. } => {
+                assert!(success);
+                assert_eq!(group.as_deref(), Some("CN=Staff,DC=example"));
+            }
+            response => panic!("Expected LdapGroupSetResponse, got {:?}", response),
+        }
+
+        handle_ldap_group_list(Some(session_id), &mut test_ctx.handler_context())
+            .await
+            .unwrap();
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::LdapGroupListResponse {
+                success, groups, ..
+            } => {
+                assert!(success);
+                let groups = groups.unwrap();
+                assert_eq!(groups.len(), 1);
+                assert_eq!(groups[0].permissions, vec!["chat_send", "file_list"]);
+                assert!(!groups[0].is_admin);
+            }
+            response => panic!("Expected LdapGroupListResponse, got {:?}", response),
+        }
+
+        handle_ldap_group_delete(
+            "cn=staff,dc=example".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::LdapGroupDeleteResponse { success, .. } => assert!(success),
+            response => panic!("Expected LdapGroupDeleteResponse, got {:?}", response),
+        }
+
+        // Deleting it again fails
+        handle_ldap_group_delete(
+            "CN=Staff,DC=example".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::LdapGroupDeleteResponse { success, error, .. } => {
+                assert!(!success);
+                assert!(error.unwrap().contains("CN=Staff,DC=example"));
+            }
+            response => panic!("Expected LdapGroupDeleteResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ldap_group_set_rejects_bad_input() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        for (group, permissions) in [
+            ("", vec![]),
+            (" CN=Staff", vec![]),
+            ("CN=Staff", vec!["no_such_permission".to_string()]),
+        ] {
+            handle_ldap_group_set(
+                group.to_string(),
+                permissions,
+                false,
+                Some(session_id),
+                &mut test_ctx.handler_context(),
+            )
+            .await
+            .unwrap();
+            match read_server_message(&mut test_ctx).await {
+                ServerMessage::LdapGroupSetResponse { success, error, .. } => {
+                    assert!(!success, "{group:?} should fail");
+                    assert!(error.is_some());
+                }
+                response => panic!("Expected LdapGroupSetResponse, got {:?}", response),
+            }
+        }
+        assert!(test_ctx.db.ldap.list_groups().await.unwrap().is_empty());
+    }
+}
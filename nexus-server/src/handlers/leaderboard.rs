@@ -0,0 +1,198 @@
+//! Handler for Leaderboard command
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::{LeaderboardEntry, LeaderboardStat, ServerMessage};
+
+use super::{
+    HandlerContext, err_authentication, err_database, err_not_logged_in, err_permission_denied,
+};
+use crate::db::Permission;
+
+/// Maximum number of accounts on the leaderboard
+const MAX_LEADERBOARD_ENTRIES: usize = 20;
+
+/// Handle Leaderboard command
+///
+/// Returns the regular accounts with the highest value of `stat`. Viewing the
+/// leaderboard needs the same permission as viewing user info.
+pub async fn handle_leaderboard<W>(
+    stat: LeaderboardStat,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first
+    let Some(session_id) = session_id else {
+        log_warn!("Leaderboard from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("Leaderboard"))
+            .await;
+    };
+
+    // Get requesting user from session
+    let requesting_user = match ctx.user_manager.get_user_by_session_id(session_id).await {
+        Some(user) => user,
+        None => {
+            return ctx
+                .send_error_and_disconnect(&err_authentication(ctx.locale), Some("Leaderboard"))
+                .await;
+        }
+    };
+
+    if !requesting_user.has_permission(Permission::UserInfo) {
+        log_warn!(
+            "Leaderboard from {} (user: {}) without permission",
+            ctx.peer_addr,
+            requesting_user.username
+        );
+        return ctx
+            .send_message(&failure(err_permission_denied(ctx.locale)))
+            .await;
+    }
+
+    match ctx
+        .stats
+        .leaderboard(&ctx.db.stats, stat, MAX_LEADERBOARD_ENTRIES)
+        .await
+    {
+        Ok(records) => {
+            let entries = records
+                .into_iter()
+                .map(|record| LeaderboardEntry {
+                    rank: ctx.ranks.rank_for(&record.stats),
+                    username: record.username,
+                    stats: record.stats,
+                })
+                .collect();
+            let response = ServerMessage::LeaderboardResponse {
+                success: true,
+                error: None,
+                entries: Some(entries),
+            };
+            ctx.send_message(&response).await
+        }
+        Err(e) => {
+            log_error!("Leaderboard database error: {}", e);
+            ctx.send_message(&failure(err_database(ctx.locale))).await
+        }
+    }
+}
+
+/// Build a failed LeaderboardResponse
+fn failure(error: String) -> ServerMessage {
+    ServerMessage::LeaderboardResponse {
+        success: false,
+        error: Some(error),
+        entries: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nexus_common::protocol::UserStats;
+
+    use super::*;
+    use crate::handlers::testing::{create_test_context, login_user, read_server_message};
+
+    #[tokio::test]
+    async fn test_leaderboard_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_leaderboard(
+            LeaderboardStat::Logins,
+            None,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_err(), "Leaderboard should require login");
+    }
+
+    #[tokio::test]
+    async fn test_leaderboard_requires_permission() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let result = handle_leaderboard(
+            LeaderboardStat::Logins,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok(), "Should send error response, not disconnect");
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::LeaderboardResponse {
+                success,
+                error,
+                entries,
+            } => {
+                assert!(!success);
+                assert!(error.is_some());
+                assert!(entries.is_none());
+            }
+            _ => panic!("Expected LeaderboardResponse, got: {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_leaderboard_lists_accounts() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::UserInfo],
+            false,
+        )
+        .await;
+        let user_id = test_ctx
+            .db
+            .users
+            .get_user_by_username("alice")
+            .await
+            .unwrap()
+            .unwrap()
+            .id;
+        test_ctx
+            .db
+            .stats
+            .add_user_stats(
+                user_id,
+                &UserStats {
+                    uploads: 4,
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        let result = handle_leaderboard(
+            LeaderboardStat::Uploads,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::LeaderboardResponse {
+                success, entries, ..
+            } => {
+                assert!(success);
+                let entries = entries.unwrap();
+                let alice = entries.iter().find(|e| e.username == "alice").unwrap();
+                // Other tests share the global collector, so only a lower bound is certain
+                assert!(alice.stats.uploads >= 4);
+            }
+            _ => panic!("Expected LeaderboardResponse, got: {:?}", response),
+        }
+    }
+}
@@ -0,0 +1,247 @@
+//! Handlers for LogSubscribe and LogUnsubscribe commands
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::task::AbortHandle;
+
+use nexus_common::protocol::ServerMessage;
+
+use super::{HandlerContext, err_authentication, err_not_logged_in, err_permission_denied};
+use crate::db::Permission;
+
+/// Handle LogSubscribe command
+///
+/// Sends the recent log history, then streams new log lines to this
+/// connection until it unsubscribes, disconnects or loses `log_view`.
+/// `log_stream` holds the streaming task (a repeated subscribe replaces it).
+pub async fn handle_log_subscribe<W>(
+    session_id: Option<u32>,
+    log_stream: &mut Option<AbortHandle>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first
+    let Some(session_id) = session_id else {
+        log_warn!("LogSubscribe request from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("LogSubscribe"))
+            .await;
+    };
+
+    // Get requesting user from session
+    let requesting_user = match ctx.user_manager.get_user_by_session_id(session_id).await {
+        Some(user) => user,
+        None => {
+            return ctx
+                .send_error_and_disconnect(&err_authentication(ctx.locale), Some("LogSubscribe"))
+                .await;
+        }
+    };
+
+    // Check log_view permission
+    if !requesting_user.has_permission(Permission::LogView) {
+        log_warn!(
+            "LogSubscribe from {} (user: {}) without permission",
+            ctx.peer_addr,
+            requesting_user.username
+        );
+        let response = ServerMessage::LogSubscribeResponse {
+            success: false,
+            error: Some(err_permission_denied(ctx.locale)),
+            entries: None,
+        };
+        return ctx.send_message(&response).await;
+    }
+
+    if let Some(previous) = log_stream.take() {
+        previous.abort();
+    }
+
+    let (entries, mut receiver) = ctx.server_log.subscribe();
+    let response = ServerMessage::LogSubscribeResponse {
+        success: true,
+        error: None,
+        entries: Some(entries),
+    };
+    ctx.send_message(&response).await?;
+
+    // Forward new lines through the connection's outgoing queue. This task
+    // must not log itself, or every line would produce another one.
+    let tx = ctx.tx.clone();
+    let user_manager = ctx.user_manager.clone();
+    let task = tokio::spawn(async move {
+        loop {
+            let entry = match receiver.recv().await {
+                Ok(entry) => entry,
+                // Too slow to keep up - skip the missed lines
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => break,
+            };
+            if user_manager
+                .has_permission(session_id, Permission::LogView)
+                .await
+                != Some(true)
+            {
+                break;
+            }
+            if tx.send((ServerMessage::LogLine { entry }, None)).is_err() {
+                break;
+            }
+        }
+    });
+    *log_stream = Some(task.abort_handle());
+
+    Ok(())
+}
+
+/// Handle LogUnsubscribe command
+///
+/// Stops streaming log lines. There is no response.
+pub fn handle_log_unsubscribe(log_stream: &mut Option<AbortHandle>) {
+    if let Some(task) = log_stream.take() {
+        task.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::testing::{create_test_context, login_user, read_server_message};
+    use nexus_common::protocol::LogLevel;
+
+    #[tokio::test]
+    async fn test_log_subscribe_requires_login() {
+        let mut test_ctx = create_test_context().await;
+        let mut log_stream = None;
+
+        let result =
+            handle_log_subscribe(None, &mut log_stream, &mut test_ctx.handler_context()).await;
+
+        assert!(result.is_err(), "Should disconnect when not logged in");
+        assert!(log_stream.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_log_subscribe_requires_permission() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+        let mut log_stream = None;
+
+        let result = handle_log_subscribe(
+            Some(session_id),
+            &mut log_stream,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+        assert!(log_stream.is_none());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::LogSubscribeResponse {
+                success,
+                error,
+                entries,
+            } => {
+                assert!(!success);
+                assert!(error.is_some());
+                assert!(entries.is_none());
+            }
+            _ => panic!("Expected LogSubscribeResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_log_subscribe_with_permission_sends_history() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::LogView],
+            false,
+        )
+        .await;
+        let mut log_stream = None;
+
+        test_ctx.server_log.record(
+            LogLevel::Info,
+            module_path!(),
+            "log subscribe history test".to_string(),
+        );
+
+        let result = handle_log_subscribe(
+            Some(session_id),
+            &mut log_stream,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+        assert!(log_stream.is_some());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::LogSubscribeResponse {
+                success, entries, ..
+            } => {
+                assert!(success);
+                let entries = entries.expect("history should be sent");
+                assert!(
+                    entries
+                        .iter()
+                        .any(|e| e.message == "log subscribe history test")
+                );
+            }
+            _ => panic!("Expected LogSubscribeResponse, got {:?}", response),
+        }
+
+        // New lines are streamed through the connection's queue
+        test_ctx.server_log.record(
+            LogLevel::Info,
+            module_path!(),
+            "log subscribe live test".to_string(),
+        );
+        let streamed = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            while let Some((message, _)) = test_ctx.rx.recv().await {
+                if let ServerMessage::LogLine { entry } = message
+                    && entry.message == "log subscribe live test"
+                {
+                    return true;
+                }
+            }
+            false
+        })
+        .await;
+        assert_eq!(streamed, Ok(true));
+
+        handle_log_unsubscribe(&mut log_stream);
+        assert!(log_stream.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_log_subscribe_admin_has_permission() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+        let mut log_stream = None;
+
+        let result = handle_log_subscribe(
+            Some(session_id),
+            &mut log_stream,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::LogSubscribeResponse { success, .. } => assert!(success),
+            _ => panic!("Expected LogSubscribeResponse, got {:?}", response),
+        }
+
+        handle_log_unsubscribe(&mut log_stream);
+    }
+}
@@ -4,7 +4,8 @@ use std::io;
 
 use tokio::io::AsyncWrite;
 
-use nexus_common::protocol::{ChannelJoinInfo, ServerInfo, ServerMessage, UserInfo};
+use nexus_common::pow::verify_pow;
+use nexus_common::protocol::{ChannelJoinInfo, OidcLogin, ServerInfo, ServerMessage, UserInfo};
 use nexus_common::validators::{
     self, AvatarError, FeaturesError, LocaleError, NicknameError, PasswordError, UsernameError,
 };
@@ -14,14 +15,21 @@ use super::{
     err_authentication, err_avatar_invalid_format, err_avatar_too_large,
     err_avatar_unsupported_type, err_database, err_failed_to_create_user,
     err_features_empty_feature, err_features_feature_too_long, err_features_invalid_characters,
-    err_features_too_many, err_guest_disabled, err_handshake_required, err_invalid_credentials,
-    err_locale_invalid_characters, err_locale_too_long, err_nickname_empty, err_nickname_in_use,
-    err_nickname_invalid, err_nickname_is_username, err_nickname_required, err_nickname_too_long,
-    err_password_too_long, err_username_empty, err_username_invalid, err_username_too_long,
+    err_features_too_many, err_guest_disabled, err_guest_limit, err_handshake_required,
+    err_invalid_credentials, err_ldap_no_group, err_ldap_unavailable,
+    err_locale_invalid_characters, err_locale_too_long, err_login_token_expired,
+    err_nickname_empty, err_nickname_in_use, err_nickname_invalid, err_nickname_is_username,
+    err_nickname_registered, err_nickname_required, err_nickname_too_long, err_password_too_long,
+    err_proof_of_work_required, err_sso_failed, err_sso_unavailable, err_sso_username_unavailable,
+    err_username_empty, err_username_invalid, err_username_too_long, server_time,
 };
 use crate::constants::FEATURE_CHAT;
 use crate::db::sql::GUEST_USERNAME;
 use crate::db::{self, Permission};
+use crate::guests;
+use crate::ldap::LdapError;
+use crate::oidc::SsoError;
+use crate::reputation::POW_DIFFICULTY;
 use crate::users::manager::AddUserError;
 use crate::users::user::NewSessionParams;
 
@@ -34,6 +42,16 @@ pub struct LoginRequest {
     pub avatar: Option<String>,
     pub nickname: Option<String>,
     pub handshake_complete: bool,
+    /// Challenge sent in the handshake, if the login must solve one
+    pub pow_challenge: Option<String>,
+    /// Client's solution to `pow_challenge`
+    pub pow_nonce: Option<String>,
+    /// Single sign-on result, instead of a password
+    pub oidc: Option<OidcLogin>,
+    /// Token from an earlier single sign-on login, instead of a password
+    pub login_token: Option<String>,
+    /// Client software name sent in the handshake
+    pub client: Option<String>,
 }
 
 /// Handle a login request from the client
@@ -53,6 +71,11 @@ where
         avatar,
         nickname,
         handshake_complete,
+        pow_challenge,
+        pow_nonce,
+        oidc: oidc_login,
+        login_token,
+        client,
     } = request;
 
     // Normalize empty username to "guest" for guest login
@@ -64,15 +87,30 @@ where
 
     // Verify handshake completed
     if !handshake_complete {
-        eprintln!("Login attempt from {} without handshake", ctx.peer_addr);
+        log_warn!("Login attempt from {} without handshake", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_handshake_required(&locale), Some("Login"))
             .await;
     }
 
+    // Verify proof of work (blocklisted address, pow policy)
+    if let Some(challenge) = &pow_challenge
+        && !pow_nonce
+            .as_deref()
+            .is_some_and(|nonce| verify_pow(challenge, POW_DIFFICULTY, nonce))
+    {
+        log_warn!(
+            "Login attempt from {} without a valid proof of work",
+            ctx.peer_addr
+        );
+        return ctx
+            .send_error_and_disconnect(&err_proof_of_work_required(&locale), Some("Login"))
+            .await;
+    }
+
     // Check for duplicate login on same connection
     if session_id.is_some() {
-        eprintln!("Duplicate login attempt from {}", ctx.peer_addr);
+        log_warn!("Duplicate login attempt from {}", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_already_logged_in(&locale), Some("Login"))
             .await;
@@ -146,19 +184,121 @@ where
             .await;
     }
 
-    // Look up user account in database
-    let account = match ctx.db.users.get_user_by_username(&username).await {
-        Ok(acc) => acc,
-        Err(e) => {
-            eprintln!("Database error looking up user {}: {}", username, e);
+    // The client name is only informational, so a malformed one is dropped
+    // rather than failing the login
+    let client = client.filter(|name| validators::validate_client_name(name).is_ok());
+
+    // Single sign-on (or an earlier login's token): the identity provider or
+    // the token vouches for the account, so there is no password to check
+    let sso_account = if let Some(oidc_login) = &oidc_login {
+        if validators::validate_oidc_login(
+            &oidc_login.code,
+            &oidc_login.code_verifier,
+            &oidc_login.redirect_uri,
+            &oidc_login.nonce,
+        )
+        .is_err()
+        {
             return ctx
-                .send_error_and_disconnect(&err_database(&locale), Some("Login"))
+                .send_error_and_disconnect(&err_sso_failed(&locale), Some("Login"))
                 .await;
         }
+        match ctx.oidc.login(ctx.db, oidc_login).await {
+            Ok(account) => Some(account),
+            Err(e) => {
+                log_warn!("Single sign-on login from {} failed: {}", ctx.peer_addr, e);
+                let error_msg = match e {
+                    SsoError::Unavailable => err_sso_unavailable(&locale),
+                    SsoError::Failed(_) => err_sso_failed(&locale),
+                    SsoError::UsernameUnavailable(name) => {
+                        err_sso_username_unavailable(&locale, &name)
+                    }
+                    SsoError::AccountDisabled(name) => err_account_disabled(&locale, &name),
+                    SsoError::Database(_) => err_database(&locale),
+                };
+                return ctx
+                    .send_error_and_disconnect(&error_msg, Some("Login"))
+                    .await;
+            }
+        }
+    } else if let Some(token) = &login_token {
+        let account = if validators::validate_login_token(token).is_ok() {
+            match ctx.login_tokens.account(ctx.db, token).await {
+                Ok(account) => account,
+                Err(e) => {
+                    log_error!("Database error looking up login token: {}", e);
+                    return ctx
+                        .send_error_and_disconnect(&err_database(&locale), Some("Login"))
+                        .await;
+                }
+            }
+        } else {
+            None
+        };
+        match account {
+            Some(account) if account.enabled => Some(account),
+            Some(account) => {
+                return ctx
+                    .send_error_and_disconnect(
+                        &err_account_disabled(&locale, &account.username),
+                        Some("Login"),
+                    )
+                    .await;
+            }
+            None => {
+                return ctx
+                    .send_error_and_disconnect(&err_login_token_expired(&locale), Some("Login"))
+                    .await;
+            }
+        }
+    } else {
+        None
+    };
+    let is_sso_login = sso_account.is_some();
+
+    // Directory logins: accounts created through the directory, and usernames
+    // with no account yet, are checked against the directory first
+    let ldap_account = if is_sso_login {
+        None
+    } else {
+        match ctx.ldap.login(ctx.db, &username, &password).await {
+            Ok(account) => account,
+            Err(e) => {
+                log_warn!("Directory login from {} failed: {}", ctx.peer_addr, e);
+                let error_msg = match e {
+                    LdapError::Unavailable(_) => err_ldap_unavailable(&locale),
+                    LdapError::InvalidCredentials => err_invalid_credentials(&locale),
+                    LdapError::NotInMappedGroup(_) => err_ldap_no_group(&locale),
+                    LdapError::AccountDisabled(name) => err_account_disabled(&locale, &name),
+                    LdapError::Database(_) => err_database(&locale),
+                };
+                return ctx
+                    .send_error_and_disconnect(&error_msg, Some("Login"))
+                    .await;
+            }
+        }
+    };
+
+    // Look up user account in database (single sign-on and directory logins
+    // already have it)
+    let account = if is_sso_login || ldap_account.is_some() {
+        None
+    } else {
+        match ctx.db.users.get_user_by_username(&username).await {
+            Ok(acc) => acc,
+            Err(e) => {
+                log_error!("Database error looking up user {}: {}", username, e);
+                return ctx
+                    .send_error_and_disconnect(&err_database(&locale), Some("Login"))
+                    .await;
+            }
+        }
     };
 
     // Authenticate user or create first admin
-    let authenticated_account = if let Some(account) = account {
+    let authenticated_account = if let Some(account) = sso_account.or(ldap_account) {
+        account
+    } else if let Some(account) = account {
         // User exists - verify password
         // Special case: guest account has empty password hash - password must be empty
         let password_valid = if account.hashed_password.is_empty() {
@@ -169,7 +309,7 @@ where
             match db::verify_password(&password, &account.hashed_password) {
                 Ok(valid) => valid,
                 Err(e) => {
-                    eprintln!("Password verification error for {}: {}", username, e);
+                    log_error!("Password verification error for {}: {}", username, e);
                     return ctx
                         .send_error_and_disconnect(&err_authentication(&locale), Some("Login"))
                         .await;
@@ -180,9 +320,10 @@ where
         if password_valid {
             // Password is correct - check if account is enabled
             if !account.enabled {
-                eprintln!(
+                log_warn!(
                     "Login from {} for disabled account: {}",
-                    ctx.peer_addr, username
+                    ctx.peer_addr,
+                    username
                 );
                 // Use user-friendly error for guest account
                 let error_msg = if username.to_lowercase() == GUEST_USERNAME {
@@ -196,9 +337,10 @@ where
             }
             account
         } else {
-            eprintln!(
+            log_warn!(
                 "Login from {} failed: invalid credentials for {}",
-                ctx.peer_addr, username
+                ctx.peer_addr,
+                username
             );
             return ctx
                 .send_error_and_disconnect(&err_invalid_credentials(&locale), Some("Login"))
@@ -209,7 +351,7 @@ where
         let hashed_password = match db::hash_password(&password, false) {
             Ok(hash) => hash,
             Err(e) => {
-                eprintln!("Failed to hash password for {}: {}", username, e);
+                log_error!("Failed to hash password for {}: {}", username, e);
                 return ctx
                     .send_error_and_disconnect(
                         &err_failed_to_create_user(&locale, &username),
@@ -227,9 +369,10 @@ where
             .await
         {
             Ok(Some(account)) => {
-                println!(
+                log_info!(
                     "Created first user (admin): '{}' from {}",
-                    username, ctx.peer_addr
+                    username,
+                    ctx.peer_addr
                 );
                 account
             }
@@ -241,7 +384,7 @@ where
                     .await;
             }
             Err(e) => {
-                eprintln!("Failed to create first user {}: {}", username, e);
+                log_error!("Failed to create first user {}: {}", username, e);
                 return ctx
                     .send_error_and_disconnect(
                         &err_failed_to_create_user(&locale, &username),
@@ -252,15 +395,48 @@ where
         }
     };
 
+    // Limit how many guests can be logged in from one address
+    let is_guest = guests::is_guest(&authenticated_account.username);
+    let max_guests = ctx.guest_config.max_per_ip;
+    if is_guest
+        && max_guests > 0
+        && guests::sessions_from(ctx.user_manager, ctx.peer_addr.ip()).await >= max_guests as usize
+    {
+        log_warn!(
+            "Guest login from {} refused: guest limit reached",
+            ctx.peer_addr
+        );
+        return ctx
+            .send_error_and_disconnect(&err_guest_limit(&locale, max_guests), Some("Login"))
+            .await;
+    }
+
     // Handle nickname for shared accounts
     // For shared accounts: nickname is required and must be unique
+    // (guests who don't pick one get a free "GuestNNNN" nickname)
     // For regular accounts: nickname is silently ignored
     let validated_nickname = if authenticated_account.is_shared {
-        // Shared account - nickname is required
-        let Some(nickname) = nickname else {
-            return ctx
-                .send_error_and_disconnect(&err_nickname_required(&locale), Some("Login"))
-                .await;
+        let nickname = match nickname {
+            Some(nickname) => nickname,
+            None if is_guest => match guests::pick_nickname(ctx.db, ctx.user_manager).await {
+                Ok(Some(nickname)) => nickname,
+                Ok(None) => {
+                    return ctx
+                        .send_error_and_disconnect(&err_nickname_in_use(&locale), Some("Login"))
+                        .await;
+                }
+                Err(e) => {
+                    log_error!("Database error picking a guest nickname: {}", e);
+                    return ctx
+                        .send_error_and_disconnect(&err_database(&locale), Some("Login"))
+                        .await;
+                }
+            },
+            None => {
+                return ctx
+                    .send_error_and_disconnect(&err_nickname_required(&locale), Some("Login"))
+                    .await;
+            }
         };
 
         // Validate nickname format
@@ -286,7 +462,23 @@ where
             }
             Ok(false) => {}
             Err(e) => {
-                eprintln!("Database error checking nickname uniqueness: {}", e);
+                log_error!("Database error checking nickname uniqueness: {}", e);
+                return ctx
+                    .send_error_and_disconnect(&err_database(&locale), Some("Login"))
+                    .await;
+            }
+        }
+
+        // Check if an account has reserved the nickname (case-insensitive)
+        match ctx.db.nicknames.is_registered(&nickname).await {
+            Ok(true) => {
+                return ctx
+                    .send_error_and_disconnect(&err_nickname_registered(&locale), Some("Login"))
+                    .await;
+            }
+            Ok(false) => {}
+            Err(e) => {
+                log_error!("Database error checking registered nicknames: {}", e);
                 return ctx
                     .send_error_and_disconnect(&err_database(&locale), Some("Login"))
                     .await;
@@ -317,11 +509,15 @@ where
             .get_user_permissions(authenticated_account.id)
             .await
         {
-            Ok(perms) => perms.permissions,
+            Ok(mut perms) => {
+                perms.restrict_for_account(&authenticated_account);
+                perms.permissions
+            }
             Err(e) => {
-                eprintln!(
+                log_error!(
                     "Error fetching permissions for {}: {}",
-                    authenticated_account.username, e
+                    authenticated_account.username,
+                    e
                 );
                 std::collections::HashSet::new()
             }
@@ -386,6 +582,7 @@ where
                 .unwrap_or_else(|| authenticated_account.username.clone()),
             is_away: inherited_is_away,
             status: inherited_status,
+            client: client.clone(),
         })
         .await
     {
@@ -397,6 +594,9 @@ where
         }
     };
     *session_id = Some(id);
+    ctx.stats.record_login(authenticated_account.id);
+    ctx.stats
+        .record_sessions(ctx.user_manager.session_count().await);
 
     // Auto-join channels configured by admin
     // We join the user and collect channel info to include in LoginResponse.
@@ -487,6 +687,11 @@ where
             secret: result.secret,
             members: member_nicknames,
             voiced,
+            voice_limit: (result.voice_limit > 0).then_some(result.voice_limit),
+            topic_locked: result.topic_locked,
+            entry_message: result.entry_message,
+            rules: result.rules,
+            language: result.language,
         });
     }
 
@@ -527,6 +732,25 @@ where
         None
     };
 
+    // Transfer queue settings only visible to admins
+    let (max_active_transfers, transfer_small_files_first, trusted_transfer_weight) =
+        if authenticated_account.is_admin {
+            (
+                Some(ctx.db.config.get_max_active_transfers().await as u32),
+                Some(ctx.db.config.get_transfer_small_files_first().await),
+                Some(ctx.db.config.get_trusted_transfer_weight().await),
+            )
+        } else {
+            (None, None, None)
+        };
+
+    // Port forwarding state only visible to admins
+    let nat = if authenticated_account.is_admin {
+        ctx.nat_status.get()
+    } else {
+        None
+    };
+
     // Auto-join channels only visible to users who can use chat
     let auto_join_channels = if can_auto_join {
         Some(auto_join_config)
@@ -540,12 +764,19 @@ where
         version: Some(env!("CARGO_PKG_VERSION").to_string()),
         max_connections_per_ip,
         max_transfers_per_ip,
+        max_active_transfers,
+        transfer_small_files_first,
+        trusted_transfer_weight,
         image: Some(image),
         transfer_port: ctx.transfer_port,
         transfer_websocket_port: ctx.transfer_websocket_port,
         file_reindex_interval,
         persistent_channels,
         auto_join_channels,
+        nat,
+        news_image_limits: Some(*ctx.news_image_limits),
+        max_message_length: Some(validators::MAX_MESSAGE_LENGTH as u32),
+        time: Some(server_time()),
     });
 
     // Build channels field for LoginResponse (only if user joined any channels)
@@ -559,6 +790,9 @@ where
     // Use DB-canonical username (not client-provided) to ensure consistent casing
     let nickname = validated_nickname.unwrap_or_else(|| authenticated_account.username.clone());
 
+    // A token for transfer connections and reconnects, so the password isn't sent again
+    let login_token = Some(ctx.login_tokens.issue(authenticated_account.id, id));
+
     let response = ServerMessage::LoginResponse {
         success: true,
         session_id: Some(id),
@@ -569,13 +803,15 @@ where
         channels,
         nickname: Some(nickname.clone()),
         error: None,
+        login_token,
     };
     ctx.send_message(&response).await?;
 
     if ctx.debug {
-        println!(
+        log_debug!(
             "User '{}' logged in from {}",
-            authenticated_account.username, ctx.peer_addr
+            authenticated_account.username,
+            ctx.peer_addr
         );
     }
 
@@ -592,6 +828,9 @@ where
         avatar,
         is_away: false,
         status: None,
+        is_dnd: false,
+        last_active: Some(current_timestamp()),
+        client,
     };
     ctx.user_manager
         .broadcast_user_event(
@@ -600,6 +839,12 @@ where
         )
         .await;
 
+    if !authenticated_account.is_shared {
+        ctx.user_manager
+            .notify_presence(&authenticated_account.username)
+            .await;
+    }
+
     Ok(())
 }
 
@@ -607,8 +852,8 @@ where
 mod tests {
     use super::*;
     use crate::handlers::testing::{
-        DEFAULT_TEST_LOCALE, create_test_context, get_cached_password_hash, read_login_response,
-        read_server_message,
+        DEFAULT_TEST_LOCALE, TestContext, create_test_context, get_cached_password_hash,
+        read_login_response, read_server_message,
     };
 
     #[tokio::test]
@@ -626,6 +871,11 @@ mod tests {
             avatar: None,
             nickname: None,
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -634,6 +884,71 @@ mod tests {
         assert!(session_id.is_none(), "Session ID should remain None");
     }
 
+    #[tokio::test]
+    async fn test_login_requires_proof_of_work() {
+        let mut test_ctx = create_test_context().await;
+
+        // Missing and wrong nonces are both rejected
+        for pow_nonce in [None, Some("not a nonce".to_string())] {
+            let mut session_id = None;
+            let request = LoginRequest {
+                username: "alice".to_string(),
+                password: "password".to_string(),
+                features: vec![],
+                locale: DEFAULT_TEST_LOCALE.to_string(),
+                avatar: None,
+                nickname: None,
+                handshake_complete: true,
+                pow_challenge: Some("00112233445566778899aabbccddeeff".to_string()),
+                pow_nonce,
+                oidc: None,
+                login_token: None,
+                client: None,
+            };
+            let result =
+                handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
+
+            assert!(result.is_err(), "Login should fail without proof of work");
+            assert!(session_id.is_none(), "Session ID should remain None");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_login_stores_valid_client_name() {
+        // A well-formed name is kept, a malformed one is dropped without failing the login
+        for (client, expected) in [
+            ("Nexus BBS 0.5.0", Some("Nexus BBS 0.5.0")),
+            ("Nexus\u{1b}[31m", None),
+        ] {
+            let mut test_ctx = create_test_context().await;
+            let mut session_id = None;
+            let request = LoginRequest {
+                username: "alice".to_string(),
+                password: "password123".to_string(),
+                features: vec![],
+                locale: DEFAULT_TEST_LOCALE.to_string(),
+                avatar: None,
+                nickname: None,
+                handshake_complete: true,
+                pow_challenge: None,
+                pow_nonce: None,
+                oidc: None,
+                login_token: None,
+                client: Some(client.to_string()),
+            };
+            let result =
+                handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
+            assert!(result.is_ok(), "Login should succeed");
+
+            let session = test_ctx
+                .user_manager
+                .get_user_by_session_id(session_id.expect("Session ID should be set"))
+                .await
+                .unwrap();
+            assert_eq!(session.client.as_deref(), expected);
+        }
+    }
+
     #[tokio::test]
     async fn test_first_login_creates_admin() {
         let mut test_ctx = create_test_context().await;
@@ -649,6 +964,11 @@ mod tests {
             avatar: None,
             nickname: None,
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -728,6 +1048,11 @@ mod tests {
             avatar: None,
             nickname: None,
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -747,10 +1072,15 @@ mod tests {
                 is_admin,
                 permissions,
                 error,
+                login_token,
                 ..
             } => {
                 assert!(success, "Login should succeed");
                 assert!(session_id.is_some(), "Should return session ID");
+                assert!(
+                    login_token.is_some(),
+                    "Password login should get a login token"
+                );
                 assert_eq!(
                     is_admin,
                     Some(false),
@@ -798,6 +1128,11 @@ mod tests {
             avatar: None,
             nickname: None,
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -839,6 +1174,11 @@ mod tests {
             avatar: None,
             nickname: None,
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -902,6 +1242,11 @@ mod tests {
             avatar: None,
             nickname: None,
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -987,6 +1332,11 @@ mod tests {
             avatar: None,
             nickname: None,
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result1 =
             handle_login(request1, &mut session_id, &mut test_ctx.handler_context()).await;
@@ -1003,6 +1353,11 @@ mod tests {
             avatar: None,
             nickname: None,
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result2 =
             handle_login(request2, &mut session_id, &mut test_ctx.handler_context()).await;
@@ -1037,6 +1392,8 @@ mod tests {
                 Some("Test server topic".to_string()),
                 Some("admin".to_string()),
                 false,
+                0,
+                false,
             )])
             .await;
 
@@ -1059,6 +1416,11 @@ mod tests {
             avatar: None,
             nickname: None,
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -1152,6 +1514,11 @@ mod tests {
             avatar: None,
             nickname: None,
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -1244,6 +1611,11 @@ mod tests {
             avatar: None,
             nickname: None,
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -1317,6 +1689,11 @@ mod tests {
             avatar: None,
             nickname: None,
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -1390,6 +1767,11 @@ mod tests {
             avatar: None,
             nickname: None,
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -1447,6 +1829,8 @@ mod tests {
                 Some("Admin can see this".to_string()),
                 Some("admin".to_string()),
                 false,
+                0,
+                false,
             )])
             .await;
 
@@ -1469,6 +1853,11 @@ mod tests {
             avatar: None,
             nickname: None,
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -1553,6 +1942,11 @@ mod tests {
             avatar: None,
             nickname: None,
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -1609,6 +2003,11 @@ mod tests {
             avatar: None,
             nickname: None,
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -1664,6 +2063,11 @@ mod tests {
             avatar: None,
             nickname: None,
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -1707,6 +2111,11 @@ mod tests {
             avatar: Some(valid_avatar),
             nickname: None,
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -1741,6 +2150,11 @@ mod tests {
             avatar: Some(too_large_avatar),
             nickname: None,
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -1778,6 +2192,11 @@ mod tests {
             avatar: Some(invalid_avatar),
             nickname: None,
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -1819,6 +2238,11 @@ mod tests {
             avatar: Some(unsupported_avatar),
             nickname: None,
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -1859,6 +2283,11 @@ mod tests {
             avatar: None,
             nickname: None,
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -1920,6 +2349,11 @@ mod tests {
             avatar: None,
             nickname: Some("Alice".to_string()),
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -1984,6 +2418,11 @@ mod tests {
             avatar: None,
             nickname: None,
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -2009,6 +2448,11 @@ mod tests {
             avatar: None,
             nickname: Some("SomeNickname".to_string()), // Should be ignored
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -2072,6 +2516,11 @@ mod tests {
             avatar: None,
             nickname: Some("alice".to_string()), // Collides with existing username
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -2123,6 +2572,11 @@ mod tests {
             avatar: None,
             nickname: Some("ALICE".to_string()), // Collides case-insensitively
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -2133,6 +2587,69 @@ mod tests {
         assert!(session_id.is_none(), "Session ID should not be set");
     }
 
+    #[tokio::test]
+    async fn test_login_shared_account_nickname_registered() {
+        let mut test_ctx = create_test_context().await;
+        let handshake_complete = true;
+
+        let password = "password123";
+        let hashed = get_cached_password_hash(password);
+
+        // Create regular user "alice" who reserved "Ally"
+        let alice = test_ctx
+            .db
+            .users
+            .create_first_user_if_none_exist("alice", &hashed)
+            .await
+            .expect("admin creation should succeed")
+            .expect("alice should be created");
+        test_ctx
+            .db
+            .nicknames
+            .register("Ally", alice.id)
+            .await
+            .expect("nickname registration should succeed");
+
+        // Create shared account
+        test_ctx
+            .db
+            .users
+            .create_user(
+                "shared_acct",
+                &hashed,
+                false,
+                true,
+                true,
+                &db::Permissions::new(),
+            )
+            .await
+            .expect("shared account creation should succeed");
+
+        // Try to login with the reserved nickname (different case)
+        let mut session_id = None;
+        let request = LoginRequest {
+            username: "shared_acct".to_string(),
+            password: password.to_string(),
+            features: vec![],
+            locale: DEFAULT_TEST_LOCALE.to_string(),
+            avatar: None,
+            nickname: Some("ALLY".to_string()),
+            handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
+        };
+        let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
+
+        assert!(
+            result.is_err(),
+            "Login with a registered nickname should fail"
+        );
+        assert!(session_id.is_none(), "Session ID should not be set");
+    }
+
     #[tokio::test]
     async fn test_login_shared_account_nickname_collision_with_active_session() {
         let mut test_ctx = create_test_context().await;
@@ -2174,6 +2691,11 @@ mod tests {
             avatar: None,
             nickname: Some("Bob".to_string()),
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result1 =
             handle_login(request1, &mut session_id1, &mut test_ctx.handler_context()).await;
@@ -2193,6 +2715,11 @@ mod tests {
             avatar: None,
             nickname: Some("Bob".to_string()), // Same nickname as active session
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result2 =
             handle_login(request2, &mut session_id2, &mut test_ctx.handler_context()).await;
@@ -2248,6 +2775,11 @@ mod tests {
             avatar: None,
             nickname: Some("Alice".to_string()),
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result1 =
             handle_login(request1, &mut session_id1, &mut test_ctx.handler_context()).await;
@@ -2267,6 +2799,11 @@ mod tests {
             avatar: None,
             nickname: Some("Bob".to_string()), // Different nickname
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result2 =
             handle_login(request2, &mut session_id2, &mut test_ctx.handler_context()).await;
@@ -2332,6 +2869,11 @@ mod tests {
             avatar: None,
             nickname: Some("".to_string()), // Empty nickname
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -2378,6 +2920,11 @@ mod tests {
             avatar: None,
             nickname: Some("a".repeat(validators::MAX_NICKNAME_LENGTH + 1)), // Too long
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -2424,6 +2971,11 @@ mod tests {
             avatar: None,
             nickname: Some("Alice Smith".to_string()), // Space not allowed
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -2459,6 +3011,11 @@ mod tests {
             avatar: None,
             nickname: None,
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let alice_result = handle_login(
             alice_request,
@@ -2497,6 +3054,11 @@ mod tests {
             avatar: None,
             nickname: Some("alice".to_string()),
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -2541,6 +3103,11 @@ mod tests {
             avatar: None,
             nickname: Some("GuestUser".to_string()),
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -2589,6 +3156,11 @@ mod tests {
             avatar: None,
             nickname: Some("AnotherGuest".to_string()),
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -2626,6 +3198,11 @@ mod tests {
             avatar: None,
             nickname: Some("BadGuest".to_string()),
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -2650,6 +3227,11 @@ mod tests {
             avatar: None,
             nickname: Some("DisabledGuest".to_string()),
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -2657,27 +3239,15 @@ mod tests {
         assert!(session_id.is_none(), "Session ID should not be set");
     }
 
-    #[tokio::test]
-    async fn test_guest_login_requires_nickname() {
-        let mut test_ctx = create_test_context().await;
-        let handshake_complete = true;
-
-        // Enable guest account
+    /// Log in as the guest account (enabled first), returning the session ID
+    async fn login_guest(test_ctx: &mut TestContext, nickname: Option<&str>) -> Option<u32> {
         test_ctx
             .db
             .users
-            .update_user(
-                "guest",
-                None,
-                None,
-                None,
-                Some(true), // enabled
-                None,
-            )
+            .update_user("guest", None, None, None, Some(true), None)
             .await
             .unwrap();
 
-        // Try to login as guest without nickname
         let mut session_id = None;
         let request = LoginRequest {
             username: String::new(),
@@ -2685,13 +3255,91 @@ mod tests {
             features: vec![],
             locale: DEFAULT_TEST_LOCALE.to_string(),
             avatar: None,
-            nickname: None, // No nickname
-            handshake_complete,
+            nickname: nickname.map(str::to_string),
+            handshake_complete: true,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
-        let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
+        let _ = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
+        session_id
+    }
 
-        assert!(result.is_err(), "Guest login without nickname should fail");
-        assert!(session_id.is_none(), "Session ID should not be set");
+    #[tokio::test]
+    async fn test_guest_login_without_nickname_gets_one() {
+        let mut test_ctx = create_test_context().await;
+
+        let session_id = login_guest(&mut test_ctx, None)
+            .await
+            .expect("Guest login without nickname should succeed");
+
+        let response = read_login_response(&mut test_ctx).await;
+        let nickname = match response {
+            ServerMessage::LoginResponse {
+                success, nickname, ..
+            } => {
+                assert!(success, "Login should succeed");
+                nickname.expect("Response should carry the assigned nickname")
+            }
+            _ => panic!("Expected LoginResponse"),
+        };
+        assert!(nickname.starts_with(crate::constants::GUEST_NICKNAME_PREFIX));
+
+        let user = test_ctx
+            .user_manager
+            .get_user_by_session_id(session_id)
+            .await
+            .unwrap();
+        assert_eq!(user.nickname, nickname);
+    }
+
+    #[tokio::test]
+    async fn test_guest_login_limited_per_ip() {
+        let mut test_ctx = create_test_context().await;
+
+        for i in 0..crate::constants::DEFAULT_MAX_GUESTS_PER_IP {
+            let nickname = format!("Visitor{i}");
+            assert!(
+                login_guest(&mut test_ctx, Some(&nickname)).await.is_some(),
+                "Guest {i} should be let in"
+            );
+        }
+
+        assert!(
+            login_guest(&mut test_ctx, Some("OneTooMany"))
+                .await
+                .is_none(),
+            "Guest over the per-IP limit should be refused"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_guest_login_restricts_permissions() {
+        let mut test_ctx = create_test_context().await;
+
+        // Allowed for shared accounts in general, but not for guests
+        let mut perms = db::Permissions::new();
+        perms.add(Permission::ChatSend);
+        perms.add(Permission::UserMessage);
+        test_ctx
+            .db
+            .users
+            .update_user("guest", None, None, None, None, Some(&perms))
+            .await
+            .unwrap();
+
+        let session_id = login_guest(&mut test_ctx, Some("Visitor"))
+            .await
+            .expect("Guest login should succeed");
+        let user = test_ctx
+            .user_manager
+            .get_user_by_session_id(session_id)
+            .await
+            .unwrap();
+        assert!(user.has_permission(Permission::ChatSend));
+        assert!(!user.has_permission(Permission::UserMessage));
     }
 
     #[tokio::test]
@@ -2724,6 +3372,11 @@ mod tests {
             avatar: None,
             nickname: Some("CaseTest".to_string()),
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -2761,6 +3414,11 @@ mod tests {
             avatar: None,
             nickname: Some("SharedGuest".to_string()),
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -2791,6 +3449,11 @@ mod tests {
             avatar: None,
             nickname: None,
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -2853,6 +3516,7 @@ mod tests {
                 nickname: "alice".to_string(),
                 is_away: true,
                 status: Some("grabbing lunch".to_string()),
+                client: None,
             })
             .await
             .expect("Failed to add first session");
@@ -2867,6 +3531,11 @@ mod tests {
             avatar: None,
             nickname: None,
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -2937,6 +3606,7 @@ mod tests {
                 nickname: "user_one".to_string(),
                 is_away: true,
                 status: Some("away message".to_string()),
+                client: None,
             })
             .await
             .expect("Failed to add first session");
@@ -2951,6 +3621,11 @@ mod tests {
             avatar: None,
             nickname: Some("user_two".to_string()),
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -3020,6 +3695,7 @@ mod tests {
                 nickname: "alice".to_string(),
                 is_away: true,
                 status: Some("old status".to_string()),
+                client: None,
             })
             .await
             .expect("Failed to add first session");
@@ -3046,6 +3722,7 @@ mod tests {
                 nickname: "alice".to_string(),
                 is_away: false,
                 status: Some("new status".to_string()),
+                client: None,
             })
             .await
             .expect("Failed to add second session");
@@ -3060,6 +3737,11 @@ mod tests {
             avatar: None,
             nickname: None,
             handshake_complete,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
 
@@ -3135,6 +3817,11 @@ mod tests {
             avatar: None,
             nickname: None,
             handshake_complete: true,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(
             alice_request,
@@ -3168,6 +3855,11 @@ mod tests {
             avatar: None,
             nickname: None,
             handshake_complete: true,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let result = handle_login(
             bob_request,
@@ -3209,4 +3901,124 @@ mod tests {
             "Alice should have received ChatUserJoined for Bob when Bob auto-joined the channel"
         );
     }
+
+    #[tokio::test]
+    async fn test_login_with_login_token() {
+        let mut test_ctx = create_test_context().await;
+        let account = test_ctx
+            .db
+            .users
+            .create_user(
+                "carol",
+                &get_cached_password_hash("unknown"),
+                false,
+                false,
+                true,
+                &db::Permissions::new(),
+            )
+            .await
+            .unwrap();
+        let token = test_ctx.login_tokens.issue(account.id, 1);
+
+        let mut session_id = None;
+        let request = LoginRequest {
+            username: String::new(),
+            password: String::new(),
+            features: vec![],
+            locale: DEFAULT_TEST_LOCALE.to_string(),
+            avatar: None,
+            nickname: None,
+            handshake_complete: true,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: Some(token.clone()),
+            client: None,
+        };
+        let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok(), "Login with a valid token should succeed");
+
+        match read_login_response(&mut test_ctx).await {
+            ServerMessage::LoginResponse {
+                success,
+                nickname,
+                login_token,
+                ..
+            } => {
+                assert!(success);
+                assert_eq!(nickname, Some("carol".to_string()));
+                // A fresh token is issued for the new session
+                let new_token = login_token.expect("Token login should get a new token");
+                assert_ne!(new_token, token);
+            }
+            _ => panic!("Expected LoginResponse"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_login_with_unknown_token_fails() {
+        let mut test_ctx = create_test_context().await;
+        let mut session_id = None;
+        let request = LoginRequest {
+            username: String::new(),
+            password: String::new(),
+            features: vec![],
+            locale: DEFAULT_TEST_LOCALE.to_string(),
+            avatar: None,
+            nickname: None,
+            handshake_complete: true,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: Some("ab".repeat(32)),
+            client: None,
+        };
+        let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
+        assert!(result.is_err(), "Login with an unknown token should fail");
+        assert!(session_id.is_none());
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::Error { message, .. } => {
+                assert_eq!(message, err_login_token_expired(DEFAULT_TEST_LOCALE));
+            }
+            _ => panic!("Expected Error message"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sso_login_without_provider_fails() {
+        let mut test_ctx = create_test_context().await;
+        let mut session_id = None;
+        let request = LoginRequest {
+            username: String::new(),
+            password: String::new(),
+            features: vec![],
+            locale: DEFAULT_TEST_LOCALE.to_string(),
+            avatar: None,
+            nickname: None,
+            handshake_complete: true,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: Some(OidcLogin {
+                code: "SplxlOBeZQQYbYS6WxSbIA".to_string(),
+                code_verifier: "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk".to_string(),
+                redirect_uri: "http://127.0.0.1:51234/callback".to_string(),
+                nonce: "n-0S6_WzA2Mj".to_string(),
+            }),
+            login_token: None,
+            client: None,
+        };
+        let result = handle_login(request, &mut session_id, &mut test_ctx.handler_context()).await;
+        assert!(
+            result.is_err(),
+            "Single sign-on should fail when it is not configured"
+        );
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::Error { message, .. } => {
+                assert_eq!(message, err_sso_unavailable(DEFAULT_TEST_LOCALE));
+            }
+            _ => panic!("Expected Error message"),
+        }
+    }
 }
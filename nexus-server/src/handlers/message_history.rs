@@ -0,0 +1,393 @@
+//! MessageHistory and MessageHistoryRetention message handlers
+//!
+//! Accounts that opt in keep their direct messages on the server (see
+//! [`crate::db::message_history`]). Clients fetch them after login to fill
+//! their conversations, and page back with `before`.
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+use nexus_common::validators::{self, MessageHistoryDaysError, validate_message_history_days};
+
+#[cfg(test)]
+use super::testing::DEFAULT_TEST_LOCALE;
+use super::{
+    HandlerContext, err_database, err_message_history_days_too_long, err_message_history_shared,
+    err_not_logged_in, err_permission_denied,
+};
+use crate::constants::DEFAULT_MESSAGE_HISTORY_PAGE;
+use crate::db::Permission;
+
+/// Handle a request for stored direct messages
+///
+/// Uses the UserMessage permission: anyone who can send direct messages can
+/// read back their own.
+pub async fn handle_message_history<W>(
+    nickname: Option<String>,
+    before: Option<i64>,
+    limit: Option<u32>,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first
+    let Some(requesting_session_id) = session_id else {
+        log_warn!(
+            "MessageHistory request from {} without login",
+            ctx.peer_addr
+        );
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("MessageHistory"))
+            .await;
+    };
+
+    // Get requesting user from session
+    let Some(requesting_user) = ctx
+        .user_manager
+        .get_user_by_session_id(requesting_session_id)
+        .await
+    else {
+        // Session not found - likely a race condition, not a security event
+        return send_history_error(ctx, err_not_logged_in(ctx.locale)).await;
+    };
+
+    // Check UserMessage permission
+    if !requesting_user.has_permission(Permission::UserMessage) {
+        log_warn!(
+            "MessageHistory from {} (user: {}) without permission",
+            ctx.peer_addr,
+            requesting_user.username
+        );
+        return send_history_error(ctx, err_permission_denied(ctx.locale)).await;
+    }
+
+    // Shared accounts are used by many people; they keep no history
+    if requesting_user.is_shared {
+        return send_history_error(ctx, err_message_history_shared(ctx.locale)).await;
+    }
+
+    let nickname = nickname
+        .map(|n| n.trim().to_string())
+        .filter(|n| !n.is_empty());
+    let limit = limit
+        .unwrap_or(DEFAULT_MESSAGE_HISTORY_PAGE)
+        .clamp(1, validators::MAX_MESSAGE_HISTORY_PAGE);
+
+    let history = &ctx.db.message_history;
+    let result = match history.retention_days(requesting_user.db_user_id).await {
+        Ok(days) => history
+            .fetch(
+                requesting_user.db_user_id,
+                nickname.as_deref(),
+                before,
+                limit,
+            )
+            .await
+            .map(|page| (days, page)),
+        Err(e) => Err(e),
+    };
+    let (retention_days, page) = match result {
+        Ok(result) => result,
+        Err(e) => {
+            log_error!("Database error fetching message history: {}", e);
+            return ctx
+                .send_error_and_disconnect(&err_database(ctx.locale), Some("MessageHistory"))
+                .await;
+        }
+    };
+
+    let response = ServerMessage::MessageHistoryResponse {
+        success: true,
+        error: None,
+        messages: Some(page.messages),
+        has_more: Some(page.has_more),
+        retention_days: Some(retention_days),
+    };
+    ctx.send_message(&response).await
+}
+
+/// Handle a change to how long this account's direct messages are kept
+pub async fn handle_message_history_retention<W>(
+    days: u32,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first
+    let Some(requesting_session_id) = session_id else {
+        log_warn!(
+            "MessageHistoryRetention request from {} without login",
+            ctx.peer_addr
+        );
+        return ctx
+            .send_error_and_disconnect(
+                &err_not_logged_in(ctx.locale),
+                Some("MessageHistoryRetention"),
+            )
+            .await;
+    };
+
+    // Get requesting user from session
+    let Some(requesting_user) = ctx
+        .user_manager
+        .get_user_by_session_id(requesting_session_id)
+        .await
+    else {
+        // Session not found - likely a race condition, not a security event
+        return send_retention_error(ctx, err_not_logged_in(ctx.locale)).await;
+    };
+
+    // Check UserMessage permission
+    if !requesting_user.has_permission(Permission::UserMessage) {
+        log_warn!(
+            "MessageHistoryRetention from {} (user: {}) without permission",
+            ctx.peer_addr,
+            requesting_user.username
+        );
+        return send_retention_error(ctx, err_permission_denied(ctx.locale)).await;
+    }
+
+    if requesting_user.is_shared {
+        return send_retention_error(ctx, err_message_history_shared(ctx.locale)).await;
+    }
+
+    if let Err(e) = validate_message_history_days(days) {
+        let error_msg = match e {
+            MessageHistoryDaysError::TooLong => {
+                err_message_history_days_too_long(ctx.locale, validators::MAX_MESSAGE_HISTORY_DAYS)
+            }
+        };
+        return send_retention_error(ctx, error_msg).await;
+    }
+
+    if let Err(e) = ctx
+        .db
+        .message_history
+        .set_retention_days(requesting_user.db_user_id, days)
+        .await
+    {
+        log_error!("Database error setting message history retention: {}", e);
+        return ctx
+            .send_error_and_disconnect(&err_database(ctx.locale), Some("MessageHistoryRetention"))
+            .await;
+    }
+
+    let response = ServerMessage::MessageHistoryRetentionResponse {
+        success: true,
+        error: None,
+        days: Some(days),
+    };
+    ctx.send_message(&response).await
+}
+
+/// Send a failed MessageHistoryResponse
+async fn send_history_error<W>(ctx: &mut HandlerContext<'_, W>, error: String) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let response = ServerMessage::MessageHistoryResponse {
+        success: false,
+        error: Some(error),
+        messages: None,
+        has_more: None,
+        retention_days: None,
+    };
+    ctx.send_message(&response).await
+}
+
+/// Send a failed MessageHistoryRetentionResponse
+async fn send_retention_error<W>(ctx: &mut HandlerContext<'_, W>, error: String) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let response = ServerMessage::MessageHistoryRetentionResponse {
+        success: false,
+        error: Some(error),
+        days: None,
+    };
+    ctx.send_message(&response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use nexus_common::protocol::{ChatAction, HistoryMessage};
+
+    use super::*;
+    use crate::handlers::testing::{
+        TestContext, create_test_context, login_shared_user, login_user, read_server_message,
+    };
+
+    /// Fetch history and return the response fields
+    async fn fetch(
+        test_ctx: &mut TestContext,
+        session_id: u32,
+        nickname: Option<&str>,
+    ) -> (
+        bool,
+        Option<String>,
+        Option<Vec<HistoryMessage>>,
+        Option<u32>,
+    ) {
+        handle_message_history(
+            nickname.map(str::to_string),
+            None,
+            None,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        match read_server_message(test_ctx).await {
+            ServerMessage::MessageHistoryResponse {
+                success,
+                error,
+                messages,
+                retention_days,
+                ..
+            } => (success, error, messages, retention_days),
+            _ => panic!("Expected MessageHistoryResponse"),
+        }
+    }
+
+    /// Set retention and return the response fields
+    async fn set_retention(
+        test_ctx: &mut TestContext,
+        session_id: u32,
+        days: u32,
+    ) -> (bool, Option<String>) {
+        handle_message_history_retention(days, Some(session_id), &mut test_ctx.handler_context())
+            .await
+            .unwrap();
+        match read_server_message(test_ctx).await {
+            ServerMessage::MessageHistoryRetentionResponse { success, error, .. } => {
+                (success, error)
+            }
+            _ => panic!("Expected MessageHistoryRetentionResponse"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_message_history_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result =
+            handle_message_history(None, None, None, None, &mut test_ctx.handler_context()).await;
+        assert!(result.is_err());
+
+        let result =
+            handle_message_history_retention(30, None, &mut test_ctx.handler_context()).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_message_history_requires_permission() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let (success, error, _, _) = fetch(&mut test_ctx, session_id, None).await;
+        assert!(!success);
+        assert_eq!(error, Some(err_permission_denied(DEFAULT_TEST_LOCALE)));
+
+        let (success, error) = set_retention(&mut test_ctx, session_id, 30).await;
+        assert!(!success);
+        assert_eq!(error, Some(err_permission_denied(DEFAULT_TEST_LOCALE)));
+    }
+
+    #[tokio::test]
+    async fn test_message_history_shared_account_rejected() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_shared_user(
+            &mut test_ctx,
+            "shared",
+            "password",
+            "Guest1",
+            &[Permission::UserMessage],
+        )
+        .await;
+
+        let (success, error, _, _) = fetch(&mut test_ctx, session_id, None).await;
+        assert!(!success);
+        assert_eq!(error, Some(err_message_history_shared(DEFAULT_TEST_LOCALE)));
+    }
+
+    #[tokio::test]
+    async fn test_message_history_retention_validated() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::UserMessage],
+            false,
+        )
+        .await;
+
+        let (success, error) = set_retention(
+            &mut test_ctx,
+            session_id,
+            validators::MAX_MESSAGE_HISTORY_DAYS + 1,
+        )
+        .await;
+        assert!(!success);
+        assert_eq!(
+            error,
+            Some(err_message_history_days_too_long(
+                DEFAULT_TEST_LOCALE,
+                validators::MAX_MESSAGE_HISTORY_DAYS
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_message_history_returns_stored_messages() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::UserMessage],
+            false,
+        )
+        .await;
+
+        // History is off until turned on
+        let (success, _, messages, days) = fetch(&mut test_ctx, session_id, None).await;
+        assert!(success);
+        assert!(messages.unwrap().is_empty());
+        assert_eq!(days, Some(0));
+
+        let (success, _) = set_retention(&mut test_ctx, session_id, 30).await;
+        assert!(success);
+
+        let alice = test_ctx
+            .db
+            .users
+            .get_user_by_username("alice")
+            .await
+            .unwrap()
+            .unwrap();
+        test_ctx
+            .db
+            .message_history
+            .store(alice.id, "bob", "bob", "alice", "hello", ChatAction::Normal)
+            .await
+            .unwrap();
+
+        let (success, _, messages, days) = fetch(&mut test_ctx, session_id, Some("Bob")).await;
+        assert!(success);
+        assert_eq!(days, Some(30));
+        let messages = messages.unwrap();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].message, "hello");
+        assert_eq!(messages[0].from_nickname, "bob");
+
+        let (_, _, messages, _) = fetch(&mut test_ctx, session_id, Some("carol")).await;
+        assert!(messages.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,135 @@
+//! Handler for MessageMarkRead command
+//!
+//! A recipient tells the sender of direct messages how far they have read.
+//! The sender's sessions get a `MessageRead`; the recipient gets no response.
+//! Clients with read receipts turned off never send it.
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+use nexus_common::validators;
+
+use super::{HandlerContext, err_not_logged_in};
+
+/// Handle MessageMarkRead command
+///
+/// Receipts for senders who are offline, invalid nicknames and receipts to
+/// oneself are ignored.
+pub async fn handle_message_mark_read<W>(
+    nickname: String,
+    id: u64,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(session_id) = session_id else {
+        log_warn!(
+            "MessageMarkRead request from {} without login",
+            ctx.peer_addr
+        );
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("MessageMarkRead"))
+            .await;
+    };
+
+    if validators::validate_nickname(&nickname).is_err() {
+        return Ok(());
+    }
+
+    let Some(reader) = ctx.user_manager.get_user_by_session_id(session_id).await else {
+        return Ok(());
+    };
+    if reader.nickname.to_lowercase() == nickname.to_lowercase() {
+        return Ok(());
+    }
+
+    let Some(sender) = ctx.user_manager.get_session_by_nickname(&nickname).await else {
+        return Ok(());
+    };
+
+    let receipt = ServerMessage::MessageRead {
+        nickname: reader.nickname,
+        id,
+    };
+    ctx.user_manager
+        .broadcast_to_nickname(&sender.nickname, &receipt)
+        .await;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Permission;
+    use crate::handlers::testing::{create_test_context, login_user, read_channel_response};
+
+    #[tokio::test]
+    async fn test_message_mark_read_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_message_mark_read(
+            "alice".to_string(),
+            1,
+            None,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_err(), "MessageMarkRead should require login");
+    }
+
+    #[tokio::test]
+    async fn test_message_mark_read_forwards_to_sender() {
+        let mut test_ctx = create_test_context().await;
+        login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::UserMessage],
+            false,
+        )
+        .await;
+        let bob = login_user(&mut test_ctx, "bob", "password", &[], false).await;
+
+        handle_message_mark_read(
+            "alice".to_string(),
+            42,
+            Some(bob),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        let receipt = read_channel_response(&mut test_ctx, |msg| {
+            matches!(msg, ServerMessage::MessageRead { .. })
+        });
+        match receipt {
+            ServerMessage::MessageRead { nickname, id } => {
+                assert_eq!(nickname, "bob");
+                assert_eq!(id, 42);
+            }
+            _ => panic!("Expected MessageRead"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_message_mark_read_offline_sender_is_ignored() {
+        let mut test_ctx = create_test_context().await;
+        let bob = login_user(&mut test_ctx, "bob", "password", &[], false).await;
+
+        let result = handle_message_mark_read(
+            "alice".to_string(),
+            42,
+            Some(bob),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_ok());
+    }
+}
@@ -4,49 +4,91 @@ mod ban_create;
 mod ban_delete;
 mod ban_list;
 mod broadcast;
+mod broadcast_ack;
+mod cancel;
 mod chat;
+mod chat_entry_update;
 mod chat_join;
+mod chat_language;
 mod chat_leave;
 mod chat_list;
 mod chat_secret;
+mod chat_topic_history;
+mod chat_topic_lock;
 mod chat_topic_update;
 mod connection_monitor;
+mod connectivity_check;
+mod door;
 pub(crate) mod duration;
 pub mod errors;
+mod file_activity;
+mod file_check;
+mod file_cleanup;
 mod file_copy;
 mod file_create_dir;
 mod file_delete;
 mod file_info;
 mod file_list;
+mod file_manifest;
 mod file_move;
 mod file_reindex;
 mod file_rename;
+mod file_review;
 mod file_search;
+mod file_stat;
+mod file_watch;
+mod group_message;
+mod guest_upgrade;
 mod handshake;
+mod impersonate_query;
+mod ldap_group;
+mod leaderboard;
+mod log_subscribe;
 mod login;
+mod message_history;
+mod message_read;
+mod nat_status;
+mod news_attachments;
 mod news_create;
 mod news_delete;
 mod news_edit;
 mod news_list;
+mod news_search;
 mod news_show;
 mod news_update;
+mod nickname;
+mod permission_audit;
+mod presence_subscribe;
 mod server_info_update;
+mod server_stats;
+mod snippet;
+mod stats_request;
 mod trust_create;
 mod trust_delete;
 mod trust_list;
+mod upload_rule;
 mod user_away;
 mod user_back;
 mod user_create;
 mod user_delete;
+mod user_dnd;
 mod user_edit;
+mod user_erase;
 mod user_info;
 mod user_kick;
 mod user_list;
 mod user_message;
 mod user_status;
 mod user_update;
+mod voice_clip_delete;
+mod voice_clip_list;
+mod voice_clip_play;
+mod voice_clip_upload;
 mod voice_join;
 mod voice_leave;
+mod voice_limit;
+mod voice_relay;
+mod voice_text_send;
 
 #[cfg(test)]
 pub mod testing;
@@ -54,77 +96,140 @@ pub mod testing;
 pub use ban_create::handle_ban_create;
 pub use ban_delete::handle_ban_delete;
 pub use ban_list::handle_ban_list;
-pub use broadcast::handle_user_broadcast;
+pub use broadcast::{UserBroadcastRequest, handle_user_broadcast};
+pub use broadcast_ack::handle_broadcast_ack;
+pub use cancel::CancelToken;
 pub use chat::handle_chat_send;
+pub use chat_entry_update::handle_chat_entry_update;
 pub use chat_join::handle_chat_join;
+pub use chat_language::handle_chat_language;
 pub use chat_leave::handle_chat_leave;
 pub use chat_list::handle_chat_list;
 pub use chat_secret::handle_chat_secret;
+pub use chat_topic_history::handle_chat_topic_history;
+pub use chat_topic_lock::handle_chat_topic_lock;
 pub use chat_topic_update::handle_chat_topic_update;
 pub use connection_monitor::handle_connection_monitor;
+pub use connectivity_check::handle_connectivity_check;
+pub use door::{handle_door_close, handle_door_data, handle_door_list, handle_door_open};
 pub use errors::*;
+pub use file_activity::{handle_file_popular, handle_file_recent};
+pub use file_check::handle_file_check;
+pub use file_cleanup::handle_file_cleanup;
 pub use file_copy::handle_file_copy;
 pub use file_create_dir::handle_file_create_dir;
 pub use file_delete::handle_file_delete;
 pub use file_info::handle_file_info;
 pub use file_list::handle_file_list;
+pub(crate) use file_list::{read_directory_entries, should_hide_entry};
+pub use file_manifest::handle_file_manifest;
 pub use file_move::handle_file_move;
 pub use file_reindex::handle_file_reindex;
 pub use file_rename::handle_file_rename;
+pub use file_review::{
+    handle_file_comment_create, handle_file_comment_delete, handle_file_comment_list,
+    handle_file_rate,
+};
 pub use file_search::handle_file_search;
+pub use file_stat::handle_file_stat;
+pub use file_watch::handle_file_watch;
+pub use group_message::{
+    handle_group_message_create, handle_group_message_invite, handle_group_message_leave,
+    handle_group_message_send,
+};
+pub use guest_upgrade::handle_guest_upgrade;
 pub use handshake::handle_handshake;
+pub use impersonate_query::handle_impersonate_query;
+pub use ldap_group::{handle_ldap_group_delete, handle_ldap_group_list, handle_ldap_group_set};
+pub use leaderboard::handle_leaderboard;
+pub use log_subscribe::{handle_log_subscribe, handle_log_unsubscribe};
 pub use login::{LoginRequest, handle_login};
+pub use message_history::{handle_message_history, handle_message_history_retention};
+pub use message_read::handle_message_mark_read;
+pub use nat_status::handle_nat_status;
 pub use news_create::handle_news_create;
 pub use news_delete::handle_news_delete;
 pub use news_edit::handle_news_edit;
 pub use news_list::handle_news_list;
+pub use news_search::handle_news_search;
 pub use news_show::handle_news_show;
 pub use news_update::handle_news_update;
+pub use nickname::{handle_nickname_register, handle_nickname_release};
+pub use permission_audit::handle_permission_audit;
+pub use presence_subscribe::handle_presence_subscribe;
 pub use server_info_update::{ServerInfoUpdateRequest, handle_server_info_update};
+pub use server_stats::handle_server_stats;
+pub use snippet::{handle_snippet_create, handle_snippet_get};
+pub use stats_request::handle_stats_request;
 pub use trust_create::handle_trust_create;
 pub use trust_delete::handle_trust_delete;
 pub use trust_list::handle_trust_list;
+pub use upload_rule::{handle_upload_rule_get, handle_upload_rule_set};
 pub use user_away::handle_user_away;
 pub use user_back::handle_user_back;
 pub use user_create::{UserCreateRequest, handle_user_create};
 pub use user_delete::handle_user_delete;
+pub use user_dnd::handle_user_dnd;
 pub use user_edit::handle_user_edit;
+pub use user_erase::handle_user_erase;
 pub use user_info::handle_user_info;
 pub use user_kick::handle_user_kick;
 pub use user_list::handle_user_list;
 pub use user_message::handle_user_message;
 pub use user_status::handle_user_status;
 pub use user_update::{UserUpdateRequest, handle_user_update};
+pub use voice_clip_delete::handle_voice_clip_delete;
+pub use voice_clip_list::handle_voice_clip_list;
+pub use voice_clip_play::handle_voice_clip_play;
+pub use voice_clip_upload::handle_voice_clip_upload;
 pub use voice_join::handle_voice_join;
 pub use voice_leave::handle_voice_leave;
+pub use voice_limit::handle_voice_limit;
+pub use voice_relay::handle_voice_relay;
+pub use voice_text_send::handle_voice_text_send;
 
 use std::io;
 use std::net::SocketAddr;
 use std::path::Path;
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, LazyLock, RwLock};
 
 use crate::constants::ERR_CHANNEL_CLOSED;
+use crate::doors::Doors;
+use crate::guests::GuestConfig;
+use crate::ldap::Ldap;
+use crate::mdns::Mdns;
+use crate::oidc::Oidc;
+use crate::ranks::Ranks;
+use crate::reachability::CheckConfig;
+use crate::upnp::NatStatus;
 
 use tokio::io::AsyncWrite;
-use tokio::sync::mpsc;
 
 use nexus_common::framing::{FrameWriter, MessageId};
 use nexus_common::io::send_server_message_with_id;
-use nexus_common::protocol::ServerMessage;
+use nexus_common::protocol::{NewsImageLimits, ServerMessage, ServerTime};
+use nexus_common::validators;
 
 use std::net::IpAddr;
 
 use ipnet::IpNet;
 
+use crate::broadcasts::BroadcastManager;
 use crate::channels::ChannelManager;
 use crate::connection_tracker::ConnectionTracker;
 use crate::db::Database;
 use crate::files::FileIndex;
+use crate::groups::GroupManager;
+use crate::idempotency::IdempotencyKeys;
 use crate::ip_rule_cache::IpRuleCache;
+use crate::logging::ServerLog;
+use crate::login_tokens::LoginTokens;
+use crate::outbound::OutboundSender;
+use crate::stats::StatsCollector;
 use crate::transfers::TransferRegistry;
 use crate::users::UserManager;
 use crate::users::user::UserSession;
-use crate::voice::{VoiceRegistry, send_voice_leave_notifications};
+use crate::voice::{VoiceRegistry, leave_voice_queue, send_voice_leave_notifications};
 
 /// Context passed to all handlers with shared resources
 pub struct HandlerContext<'a, W> {
@@ -132,7 +237,7 @@ pub struct HandlerContext<'a, W> {
     pub peer_addr: SocketAddr,
     pub user_manager: &'a UserManager,
     pub db: &'a Database,
-    pub tx: &'a mpsc::UnboundedSender<(ServerMessage, Option<MessageId>)>,
+    pub tx: &'a OutboundSender,
     pub debug: bool,
     pub locale: &'a str,
     /// Message ID from the incoming request (for response correlation)
@@ -155,6 +260,38 @@ pub struct HandlerContext<'a, W> {
     pub transfer_registry: Arc<TransferRegistry>,
     /// Voice registry for managing active voice sessions
     pub voice_registry: &'a VoiceRegistry,
+    /// Recently used message idempotency keys (repeats aren't posted twice)
+    pub idempotency_keys: &'a IdempotencyKeys,
+    /// Group conversations (multi-user direct messages)
+    pub group_manager: &'a GroupManager,
+    /// Login tokens for session resumption
+    pub login_tokens: &'a LoginTokens,
+    /// Scheduled broadcasts and acknowledgement counts
+    pub broadcast_manager: &'a BroadcastManager,
+    /// Server log history and live feed (for admins following the log)
+    pub server_log: &'a ServerLog,
+    /// Activity statistics not yet written to the database
+    pub stats: &'a StatsCollector,
+    /// Single sign-on provider (disabled without `--oidc-issuer`)
+    pub oidc: &'a Oidc,
+    /// Door programs from `--door`
+    pub doors: &'a Doors,
+    /// Directory logins (disabled without `--ldap-url`)
+    pub ldap: &'a Ldap,
+    /// Guest limits and self-registration settings
+    pub guest_config: &'a GuestConfig,
+    /// Size limits news images are stored under
+    pub news_image_limits: &'a NewsImageLimits,
+    /// Ranks from `--rank`
+    pub ranks: &'a Ranks,
+    /// Connectivity check settings (None without `--check-service`)
+    pub check_config: &'a Option<CheckConfig>,
+    /// Port forwarding state (empty without `--upnp`)
+    pub nat_status: &'a NatStatus,
+    /// LAN advertisement (inactive without `--mdns`)
+    pub mdns: &'a Mdns,
+    /// Tripped when the client cancels this request (see `Cancel`)
+    pub cancel: CancelToken,
 }
 
 impl<'a, W: AsyncWrite + Unpin> HandlerContext<'a, W> {
@@ -179,6 +316,7 @@ impl<'a, W: AsyncWrite + Unpin> HandlerContext<'a, W> {
         let error_msg = ServerMessage::Error {
             message: message.to_string(),
             command: command.map(|s| s.to_string()),
+            error_kind: None,
         };
         self.send_message(&error_msg).await
     }
@@ -202,6 +340,25 @@ pub fn current_timestamp() -> i64 {
         .as_secs() as i64
 }
 
+/// IANA name of the server's time zone, looked up once
+///
+/// None if the system doesn't say or the name isn't one a client would accept.
+static TIMEZONE: LazyLock<Option<String>> = LazyLock::new(|| {
+    iana_time_zone::get_timezone()
+        .ok()
+        .filter(|name| validators::validate_timezone(name).is_ok())
+});
+
+/// Server clock reading for ServerInfo and Pong (now, in the server's time zone)
+pub fn server_time() -> ServerTime {
+    let now = chrono::Local::now();
+    ServerTime {
+        timestamp: now.timestamp(),
+        utc_offset: now.offset().local_minus_utc(),
+        timezone: TIMEZONE.clone(),
+    }
+}
+
 /// Remove a user from voice (if in voice) and then from UserManager, broadcasting appropriate messages.
 ///
 /// This helper ensures voice cleanup happens before user removal, which is needed when
@@ -219,9 +376,19 @@ pub async fn remove_user_with_voice_cleanup(
     // Remove from voice session and notify remaining participants
     if let Some(info) = voice_registry.remove_by_session_id(session_id).await {
         // Notify the leaving user and broadcast to remaining participants
-        send_voice_leave_notifications(&info, Some(&user.tx), user_manager, channel_manager).await;
+        send_voice_leave_notifications(
+            &info,
+            Some(&user.tx),
+            voice_registry,
+            user_manager,
+            channel_manager,
+        )
+        .await;
     }
 
+    // Stop waiting for a voice slot
+    leave_voice_queue(session_id, voice_registry, user_manager).await;
+
     // Now remove from UserManager and broadcast UserDisconnected
     user_manager.remove_user_and_broadcast(session_id).await
 }
@@ -303,6 +470,15 @@ async fn cleanup_voice_for_session(
 ) {
     if let Some(info) = voice_registry.remove_by_session_id(user.session_id).await {
         // Notify the leaving user and broadcast to remaining participants
-        send_voice_leave_notifications(&info, Some(&user.tx), user_manager, channel_manager).await;
+        send_voice_leave_notifications(
+            &info,
+            Some(&user.tx),
+            voice_registry,
+            user_manager,
+            channel_manager,
+        )
+        .await;
     }
+
+    leave_voice_queue(user.session_id, voice_registry, user_manager).await;
 }
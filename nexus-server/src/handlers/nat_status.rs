@@ -0,0 +1,116 @@
+//! Handler for NatStatus command
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+
+use super::{HandlerContext, err_authentication, err_not_logged_in, err_permission_denied};
+
+/// Handle NatStatus command
+///
+/// Returns the current port forwarding state (admin only). `nat` is `None`
+/// when the server wasn't started with `--upnp`.
+pub async fn handle_nat_status<W>(
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first
+    let Some(session_id) = session_id else {
+        log_warn!("NatStatus request from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("NatStatus"))
+            .await;
+    };
+
+    // Get requesting user from session
+    let requesting_user = match ctx.user_manager.get_user_by_session_id(session_id).await {
+        Some(user) => user,
+        None => {
+            return ctx
+                .send_error_and_disconnect(&err_authentication(ctx.locale), Some("NatStatus"))
+                .await;
+        }
+    };
+
+    // Port forwarding state is admin only, like the rest of the network settings
+    if !requesting_user.is_admin {
+        log_warn!(
+            "NatStatus from {} (user: {}) without permission",
+            ctx.peer_addr,
+            requesting_user.username
+        );
+        let response = ServerMessage::NatStatusResponse {
+            success: false,
+            error: Some(err_permission_denied(ctx.locale)),
+            nat: None,
+        };
+        return ctx.send_message(&response).await;
+    }
+
+    let response = ServerMessage::NatStatusResponse {
+        success: true,
+        error: None,
+        nat: ctx.nat_status.get(),
+    };
+    ctx.send_message(&response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::testing::{create_test_context, login_user, read_server_message};
+
+    #[tokio::test]
+    async fn test_nat_status_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_nat_status(None, &mut test_ctx.handler_context()).await;
+
+        assert!(result.is_err(), "NatStatus should require login");
+    }
+
+    #[tokio::test]
+    async fn test_nat_status_requires_admin() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let result = handle_nat_status(Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok(), "Should send error response, not disconnect");
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::NatStatusResponse {
+                success,
+                error,
+                nat,
+            } => {
+                assert!(!success);
+                assert!(error.is_some());
+                assert!(nat.is_none());
+            }
+            _ => panic!("Expected NatStatusResponse, got: {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_nat_status_admin_succeeds() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        let result = handle_nat_status(Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::NatStatusResponse { success, error, .. } => {
+                assert!(success, "Expected success, got error: {:?}", error);
+            }
+            _ => panic!("Expected NatStatusResponse, got: {:?}", response),
+        }
+    }
+}
@@ -0,0 +1,181 @@
+//! News attachment checks shared by the NewsCreate and NewsUpdate handlers
+
+use nexus_common::protocol::{NewsAttachment, NewsImageLimits};
+use nexus_common::validators::{self, FilePathError, NewsCaptionError, NewsImageError};
+
+use super::{
+    err_file_path_invalid, err_file_path_too_long, err_news_caption_invalid_characters,
+    err_news_caption_too_long, err_news_image_invalid_format, err_news_image_too_large,
+    err_news_image_unsupported_type, err_news_too_many_attachments,
+};
+use crate::news_images;
+
+/// Validate a post's attachments and bring its images within `image_limits`
+///
+/// The single `image` sent by clients that predate attachments becomes the
+/// first attachment. Blank captions are dropped. Returns the attachments to
+/// store, or the translated error to send back.
+pub async fn prepare_attachments(
+    image: Option<String>,
+    attachments: Vec<NewsAttachment>,
+    image_limits: NewsImageLimits,
+    locale: &str,
+) -> Result<Vec<NewsAttachment>, String> {
+    let legacy_image = image
+        .filter(|data| !data.is_empty())
+        .map(|data| NewsAttachment::Image {
+            data,
+            caption: None,
+        });
+    let attachments: Vec<NewsAttachment> = legacy_image.into_iter().chain(attachments).collect();
+    if attachments.len() > validators::MAX_NEWS_ATTACHMENTS {
+        return Err(err_news_too_many_attachments(
+            locale,
+            validators::MAX_NEWS_ATTACHMENTS,
+        ));
+    }
+
+    let mut prepared = Vec::with_capacity(attachments.len());
+    for attachment in attachments {
+        if let Some(caption) = attachment.caption()
+            && let Err(e) = validators::validate_news_caption(caption)
+        {
+            return Err(match e {
+                NewsCaptionError::TooLong => {
+                    err_news_caption_too_long(locale, validators::MAX_NEWS_CAPTION_LENGTH)
+                }
+                NewsCaptionError::InvalidCharacters => err_news_caption_invalid_characters(locale),
+            });
+        }
+
+        prepared.push(match attachment {
+            NewsAttachment::Image { data, caption } => {
+                let data = news_images::prepare(data, image_limits)
+                    .await
+                    .map_err(|e| match e {
+                        NewsImageError::TooLarge => {
+                            err_news_image_too_large(locale, image_limits.max_size / 1024)
+                        }
+                        NewsImageError::InvalidFormat => err_news_image_invalid_format(locale),
+                        NewsImageError::UnsupportedType => err_news_image_unsupported_type(locale),
+                    })?;
+                NewsAttachment::Image {
+                    data,
+                    caption: non_blank(caption),
+                }
+            }
+            NewsAttachment::File { path, caption } => {
+                if path.trim().is_empty() {
+                    return Err(err_file_path_invalid(locale));
+                }
+                validators::validate_file_path(&path).map_err(|e| match e {
+                    FilePathError::TooLong => {
+                        err_file_path_too_long(locale, validators::MAX_FILE_PATH_LENGTH)
+                    }
+                    FilePathError::ContainsNull
+                    | FilePathError::InvalidCharacters
+                    | FilePathError::ContainsWindowsDrive => err_file_path_invalid(locale),
+                })?;
+                NewsAttachment::File {
+                    path,
+                    caption: non_blank(caption),
+                }
+            }
+        });
+    }
+    Ok(prepared)
+}
+
+/// A caption, or None if it's blank
+fn non_blank(caption: Option<String>) -> Option<String> {
+    caption.filter(|caption| !caption.trim().is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::testing::{DEFAULT_TEST_LOCALE, TEST_PNG_DATA_URI};
+
+    fn file(path: &str, caption: Option<&str>) -> NewsAttachment {
+        NewsAttachment::File {
+            path: path.to_string(),
+            caption: caption.map(str::to_string),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_legacy_image_comes_first() {
+        let prepared = prepare_attachments(
+            Some(TEST_PNG_DATA_URI.to_string()),
+            vec![file("Music/set.mp3", Some("  "))],
+            news_images::default_limits(),
+            DEFAULT_TEST_LOCALE,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            prepared,
+            vec![
+                NewsAttachment::Image {
+                    data: TEST_PNG_DATA_URI.to_string(),
+                    caption: None,
+                },
+                // Blank captions are dropped
+                file("Music/set.mp3", None),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_too_many_attachments() {
+        let attachments = vec![file("a.txt", None); validators::MAX_NEWS_ATTACHMENTS + 1];
+        assert_eq!(
+            prepare_attachments(
+                None,
+                attachments,
+                news_images::default_limits(),
+                DEFAULT_TEST_LOCALE
+            )
+            .await,
+            Err(err_news_too_many_attachments(
+                DEFAULT_TEST_LOCALE,
+                validators::MAX_NEWS_ATTACHMENTS
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_invalid_attachments() {
+        let cases = [
+            (
+                file("a.txt", Some("two\nlines")),
+                err_news_caption_invalid_characters(DEFAULT_TEST_LOCALE),
+            ),
+            (file("", None), err_file_path_invalid(DEFAULT_TEST_LOCALE)),
+            (
+                file("C:/Windows", None),
+                err_file_path_invalid(DEFAULT_TEST_LOCALE),
+            ),
+            (
+                NewsAttachment::Image {
+                    data: "data:image/gif;base64,R0lGODlh".to_string(),
+                    caption: None,
+                },
+                err_news_image_unsupported_type(DEFAULT_TEST_LOCALE),
+            ),
+        ];
+        for (attachment, error) in cases {
+            assert_eq!(
+                prepare_attachments(
+                    None,
+                    vec![attachment],
+                    news_images::default_limits(),
+                    DEFAULT_TEST_LOCALE
+                )
+                .await,
+                Err(error)
+            );
+        }
+    }
+}
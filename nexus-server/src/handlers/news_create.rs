@@ -4,15 +4,15 @@ use std::io;
 
 use tokio::io::AsyncWrite;
 
-use nexus_common::protocol::{NewsAction, NewsItem, ServerMessage};
-use nexus_common::validators::{self, NewsBodyError, NewsImageError};
+use nexus_common::protocol::{NewsAction, NewsAttachment, NewsItem, ServerMessage};
+use nexus_common::validators::{self, NewsBodyError};
 
+use super::news_attachments::prepare_attachments;
 #[cfg(test)]
-use super::testing::DEFAULT_TEST_LOCALE;
+use super::testing::{DEFAULT_TEST_LOCALE, TEST_PNG_DATA_URI};
 use super::{
     HandlerContext, err_database, err_news_body_invalid_characters, err_news_body_too_long,
-    err_news_empty_content, err_news_image_invalid_format, err_news_image_too_large,
-    err_news_image_unsupported_type, err_not_logged_in, err_permission_denied,
+    err_news_empty_content, err_not_logged_in, err_permission_denied,
 };
 use crate::constants::FEATURE_NEWS;
 use crate::db::Permission;
@@ -21,6 +21,7 @@ use crate::db::Permission;
 pub async fn handle_news_create<W>(
     body: Option<String>,
     image: Option<String>,
+    attachments: Vec<NewsAttachment>,
     session_id: Option<u32>,
     ctx: &mut HandlerContext<'_, W>,
 ) -> io::Result<()>
@@ -29,7 +30,7 @@ where
 {
     // Verify authentication first
     let Some(requesting_session_id) = session_id else {
-        eprintln!("NewsCreate request from {} without login", ctx.peer_addr);
+        log_warn!("NewsCreate request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("NewsCreate"))
             .await;
@@ -55,9 +56,10 @@ where
 
     // Check NewsCreate permission
     if !requesting_user.has_permission(Permission::NewsCreate) {
-        eprintln!(
+        log_warn!(
             "NewsCreate from {} (user: {}) without permission",
-            ctx.peer_addr, requesting_user.username
+            ctx.peer_addr,
+            requesting_user.username
         );
         let response = ServerMessage::NewsCreateResponse {
             success: false,
@@ -71,8 +73,8 @@ where
     let body = body.filter(|s| !s.trim().is_empty());
     let image = image.filter(|s| !s.is_empty());
 
-    // Validate that at least one of body or image is provided
-    if body.is_none() && image.is_none() {
+    // Validate that a body or at least one attachment is provided
+    if body.is_none() && image.is_none() && attachments.is_empty() {
         let response = ServerMessage::NewsCreateResponse {
             success: false,
             error: Some(err_news_empty_content(ctx.locale)),
@@ -99,37 +101,30 @@ where
         return ctx.send_message(&response).await;
     }
 
-    // Validate image if provided
-    if let Some(ref image_data) = image
-        && let Err(e) = validators::validate_news_image(image_data)
-    {
-        let error_msg = match e {
-            NewsImageError::TooLarge => err_news_image_too_large(ctx.locale),
-            NewsImageError::InvalidFormat => err_news_image_invalid_format(ctx.locale),
-            NewsImageError::UnsupportedType => err_news_image_unsupported_type(ctx.locale),
-        };
-        let response = ServerMessage::NewsCreateResponse {
-            success: false,
-            error: Some(error_msg),
-            news: None,
+    // Validate attachments, scaling images down to the server's limits
+    let attachments =
+        match prepare_attachments(image, attachments, *ctx.news_image_limits, ctx.locale).await {
+            Ok(attachments) => attachments,
+            Err(error_msg) => {
+                let response = ServerMessage::NewsCreateResponse {
+                    success: false,
+                    error: Some(error_msg),
+                    news: None,
+                };
+                return ctx.send_message(&response).await;
+            }
         };
-        return ctx.send_message(&response).await;
-    }
 
     // Create news in database
     let news_record = match ctx
         .db
         .news
-        .create_news(
-            body.as_deref(),
-            image.as_deref(),
-            requesting_user.db_user_id,
-        )
+        .create_news(body.as_deref(), &attachments, requesting_user.db_user_id)
         .await
     {
         Ok(record) => record,
         Err(e) => {
-            eprintln!("Database error creating news: {}", e);
+            log_error!("Database error creating news: {}", e);
             return ctx
                 .send_error_and_disconnect(&err_database(ctx.locale), Some("NewsCreate"))
                 .await;
@@ -140,7 +135,7 @@ where
     let news = NewsItem {
         id: news_record.id,
         body: news_record.body,
-        image: news_record.image,
+        attachments: news_record.attachments,
         author: news_record.author_username,
         author_is_admin: news_record.author_is_admin,
         created_at: news_record.created_at,
@@ -172,6 +167,10 @@ mod tests {
     use super::*;
     use crate::db;
     use crate::handlers::testing::{create_test_context, login_user, read_server_message};
+    use crate::handlers::{
+        err_news_image_invalid_format, err_news_image_unsupported_type,
+        err_news_too_many_attachments,
+    };
 
     #[tokio::test]
     async fn test_news_create_requires_login() {
@@ -180,6 +179,7 @@ mod tests {
         let result = handle_news_create(
             Some("Test post".to_string()),
             None,
+            vec![],
             None,
             &mut test_ctx.handler_context(),
         )
@@ -198,6 +198,7 @@ mod tests {
         let result = handle_news_create(
             Some("Test post".to_string()),
             None,
+            vec![],
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -231,6 +232,7 @@ mod tests {
         let result = handle_news_create(
             None,
             None,
+            vec![],
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -265,6 +267,7 @@ mod tests {
         let result = handle_news_create(
             Some("".to_string()),
             Some("".to_string()),
+            vec![],
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -298,6 +301,7 @@ mod tests {
         let result = handle_news_create(
             Some("# Hello\n\nThis is news!".to_string()),
             None,
+            vec![],
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -315,7 +319,7 @@ mod tests {
                 assert!(error.is_none());
                 let news = news.unwrap();
                 assert_eq!(news.body, Some("# Hello\n\nThis is news!".to_string()));
-                assert!(news.image.is_none());
+                assert!(news.attachments.is_empty());
                 assert_eq!(news.author, "alice");
                 assert!(!news.author_is_admin);
                 assert!(news.updated_at.is_none());
@@ -340,7 +344,8 @@ mod tests {
 
         let result = handle_news_create(
             None,
-            Some("data:image/png;base64,iVBORw0KGgo=".to_string()),
+            Some(TEST_PNG_DATA_URI.to_string()),
+            vec![],
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -359,8 +364,11 @@ mod tests {
                 let news = news.unwrap();
                 assert!(news.body.is_none());
                 assert_eq!(
-                    news.image,
-                    Some("data:image/png;base64,iVBORw0KGgo=".to_string())
+                    news.attachments,
+                    vec![NewsAttachment::Image {
+                        data: TEST_PNG_DATA_URI.to_string(),
+                        caption: None,
+                    }]
                 );
             }
             _ => panic!("Expected NewsCreateResponse"),
@@ -383,7 +391,8 @@ mod tests {
 
         let result = handle_news_create(
             Some("Check out this image!".to_string()),
-            Some("data:image/png;base64,iVBORw0KGgo=".to_string()),
+            Some(TEST_PNG_DATA_URI.to_string()),
+            vec![],
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -402,8 +411,105 @@ mod tests {
                 let news = news.unwrap();
                 assert_eq!(news.body, Some("Check out this image!".to_string()));
                 assert_eq!(
-                    news.image,
-                    Some("data:image/png;base64,iVBORw0KGgo=".to_string())
+                    news.attachments,
+                    vec![NewsAttachment::Image {
+                        data: TEST_PNG_DATA_URI.to_string(),
+                        caption: None,
+                    }]
+                );
+            }
+            _ => panic!("Expected NewsCreateResponse"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_news_create_with_attachments() {
+        let mut test_ctx = create_test_context().await;
+
+        // Login as user with NewsCreate permission
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[db::Permission::NewsCreate],
+            false,
+        )
+        .await;
+
+        let attachments = vec![
+            NewsAttachment::File {
+                path: "Music/set.mp3".to_string(),
+                caption: Some("Last night's set".to_string()),
+            },
+            NewsAttachment::Image {
+                data: TEST_PNG_DATA_URI.to_string(),
+                caption: Some("The crowd".to_string()),
+            },
+        ];
+        let result = handle_news_create(
+            None,
+            None,
+            attachments.clone(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_ok());
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::NewsCreateResponse { success, news, .. } => {
+                assert!(success);
+                let news = news.unwrap();
+                assert!(news.body.is_none());
+                assert_eq!(news.attachments, attachments);
+            }
+            _ => panic!("Expected NewsCreateResponse"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_news_create_too_many_attachments() {
+        let mut test_ctx = create_test_context().await;
+
+        // Login as user with NewsCreate permission
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[db::Permission::NewsCreate],
+            false,
+        )
+        .await;
+
+        // The legacy image counts toward the limit
+        let attachments = vec![
+            NewsAttachment::File {
+                path: "a.txt".to_string(),
+                caption: None,
+            };
+            validators::MAX_NEWS_ATTACHMENTS
+        ];
+        let result = handle_news_create(
+            None,
+            Some(TEST_PNG_DATA_URI.to_string()),
+            attachments,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_ok());
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::NewsCreateResponse { success, error, .. } => {
+                assert!(!success);
+                assert_eq!(
+                    error,
+                    Some(err_news_too_many_attachments(
+                        DEFAULT_TEST_LOCALE,
+                        validators::MAX_NEWS_ATTACHMENTS
+                    ))
                 );
             }
             _ => panic!("Expected NewsCreateResponse"),
@@ -428,6 +534,7 @@ mod tests {
         let result = handle_news_create(
             Some(long_body),
             None,
+            vec![],
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -467,6 +574,7 @@ mod tests {
         let result = handle_news_create(
             None,
             Some("not a data uri".to_string()),
+            vec![],
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -503,6 +611,7 @@ mod tests {
         let result = handle_news_create(
             None,
             Some("data:image/gif;base64,R0lGODlh".to_string()),
+            vec![],
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -532,6 +641,7 @@ mod tests {
         let result = handle_news_create(
             Some("Admin news".to_string()),
             None,
+            vec![],
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
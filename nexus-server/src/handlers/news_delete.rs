@@ -26,7 +26,7 @@ where
 {
     // Verify authentication first
     let Some(requesting_session_id) = session_id else {
-        eprintln!("NewsDelete request from {} without login", ctx.peer_addr);
+        log_warn!("NewsDelete request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("NewsDelete"))
             .await;
@@ -62,7 +62,7 @@ where
             return ctx.send_message(&response).await;
         }
         Err(e) => {
-            eprintln!("Database error getting news: {}", e);
+            log_error!("Database error getting news: {}", e);
             return ctx
                 .send_error_and_disconnect(&err_database(ctx.locale), Some("NewsDelete"))
                 .await;
@@ -74,9 +74,11 @@ where
     let has_delete_permission = requesting_user.has_permission(Permission::NewsDelete);
 
     if !is_author && !has_delete_permission {
-        eprintln!(
+        log_warn!(
             "NewsDelete from {} (user: {}) without permission for news #{}",
-            ctx.peer_addr, requesting_user.username, id
+            ctx.peer_addr,
+            requesting_user.username,
+            id
         );
         let response = ServerMessage::NewsDeleteResponse {
             success: false,
@@ -88,9 +90,11 @@ where
 
     // Check admin protection: non-admins cannot delete admin posts
     if existing_news.author_is_admin && !requesting_user.is_admin {
-        eprintln!(
+        log_warn!(
             "NewsDelete from {} (user: {}) trying to delete admin news #{}",
-            ctx.peer_addr, requesting_user.username, id
+            ctx.peer_addr,
+            requesting_user.username,
+            id
         );
         let response = ServerMessage::NewsDeleteResponse {
             success: false,
@@ -113,7 +117,7 @@ where
             return ctx.send_message(&response).await;
         }
         Err(e) => {
-            eprintln!("Database error deleting news: {}", e);
+            log_error!("Database error deleting news: {}", e);
             return ctx
                 .send_error_and_disconnect(&err_database(ctx.locale), Some("NewsDelete"))
                 .await;
@@ -201,7 +205,7 @@ mod tests {
         let created = test_ctx
             .db
             .news
-            .create_news(Some("My post"), None, user.id)
+            .create_news(Some("My post"), &[], user.id)
             .await
             .unwrap();
 
@@ -253,7 +257,7 @@ mod tests {
         let created = test_ctx
             .db
             .news
-            .create_news(Some("Author's post"), None, author.id)
+            .create_news(Some("Author's post"), &[], author.id)
             .await
             .unwrap();
 
@@ -307,7 +311,7 @@ mod tests {
         let created = test_ctx
             .db
             .news
-            .create_news(Some("Author's post"), None, author.id)
+            .create_news(Some("Author's post"), &[], author.id)
             .await
             .unwrap();
 
@@ -358,7 +362,7 @@ mod tests {
         let created = test_ctx
             .db
             .news
-            .create_news(Some("Admin's post"), None, admin.id)
+            .create_news(Some("Admin's post"), &[], admin.id)
             .await
             .unwrap();
 
@@ -415,7 +419,7 @@ mod tests {
         let created = test_ctx
             .db
             .news
-            .create_news(Some("Admin1's post"), None, admin1.id)
+            .create_news(Some("Admin1's post"), &[], admin1.id)
             .await
             .unwrap();
 
@@ -466,7 +470,7 @@ mod tests {
         let created = test_ctx
             .db
             .news
-            .create_news(Some("User's post"), None, user.id)
+            .create_news(Some("User's post"), &[], user.id)
             .await
             .unwrap();
 
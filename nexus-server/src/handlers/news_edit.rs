@@ -25,7 +25,7 @@ where
 {
     // Verify authentication first
     let Some(requesting_session_id) = session_id else {
-        eprintln!("NewsEdit request from {} without login", ctx.peer_addr);
+        log_warn!("NewsEdit request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("NewsEdit"))
             .await;
@@ -61,7 +61,7 @@ where
             return ctx.send_message(&response).await;
         }
         Err(e) => {
-            eprintln!("Database error getting news: {}", e);
+            log_error!("Database error getting news: {}", e);
             return ctx
                 .send_error_and_disconnect(&err_database(ctx.locale), Some("NewsEdit"))
                 .await;
@@ -73,9 +73,11 @@ where
     let has_edit_permission = requesting_user.has_permission(Permission::NewsEdit);
 
     if !is_author && !has_edit_permission {
-        eprintln!(
+        log_warn!(
             "NewsEdit from {} (user: {}) without permission for news #{}",
-            ctx.peer_addr, requesting_user.username, id
+            ctx.peer_addr,
+            requesting_user.username,
+            id
         );
         let response = ServerMessage::NewsEditResponse {
             success: false,
@@ -87,9 +89,11 @@ where
 
     // Check admin protection: non-admins cannot edit admin posts
     if news_record.author_is_admin && !requesting_user.is_admin {
-        eprintln!(
+        log_warn!(
             "NewsEdit from {} (user: {}) trying to edit admin news #{}",
-            ctx.peer_addr, requesting_user.username, id
+            ctx.peer_addr,
+            requesting_user.username,
+            id
         );
         let response = ServerMessage::NewsEditResponse {
             success: false,
@@ -103,7 +107,7 @@ where
     let news = NewsItem {
         id: news_record.id,
         body: news_record.body,
-        image: news_record.image,
+        attachments: news_record.attachments,
         author: news_record.author_username,
         author_is_admin: news_record.author_is_admin,
         created_at: news_record.created_at,
@@ -121,6 +125,8 @@ where
 
 #[cfg(test)]
 mod tests {
+    use nexus_common::protocol::NewsAttachment;
+
     use super::*;
     use crate::db;
     use crate::handlers::testing::{create_test_context, login_user, read_server_message};
@@ -182,7 +188,7 @@ mod tests {
         let created = test_ctx
             .db
             .news
-            .create_news(Some("My post"), None, user.id)
+            .create_news(Some("My post"), &[], user.id)
             .await
             .unwrap();
 
@@ -236,7 +242,7 @@ mod tests {
         let created = test_ctx
             .db
             .news
-            .create_news(Some("Author's post"), None, author.id)
+            .create_news(Some("Author's post"), &[], author.id)
             .await
             .unwrap();
 
@@ -286,7 +292,7 @@ mod tests {
         let created = test_ctx
             .db
             .news
-            .create_news(Some("Author's post"), None, author.id)
+            .create_news(Some("Author's post"), &[], author.id)
             .await
             .unwrap();
 
@@ -342,7 +348,7 @@ mod tests {
         let created = test_ctx
             .db
             .news
-            .create_news(Some("Admin's post"), None, admin.id)
+            .create_news(Some("Admin's post"), &[], admin.id)
             .await
             .unwrap();
 
@@ -392,7 +398,7 @@ mod tests {
         let created = test_ctx
             .db
             .news
-            .create_news(Some("Admin1's post"), None, admin1.id)
+            .create_news(Some("Admin1's post"), &[], admin1.id)
             .await
             .unwrap();
 
@@ -445,21 +451,24 @@ mod tests {
             .news
             .create_news(
                 Some("# News\n\nWith markdown!"),
-                Some("data:image/png;base64,abc123"),
+                &[NewsAttachment::Image {
+                    data: "data:image/png;base64,abc123".to_string(),
+                    caption: None,
+                }],
                 admin.id,
             )
             .await
             .unwrap();
 
         // Update it to set updated_at
+        let xyz = NewsAttachment::File {
+            path: "Pictures/xyz.png".to_string(),
+            caption: Some("xyz".to_string()),
+        };
         test_ctx
             .db
             .news
-            .update_news(
-                created.id,
-                Some("# Updated"),
-                Some("data:image/png;base64,xyz"),
-            )
+            .update_news(created.id, Some("# Updated"), &[xyz.clone()])
             .await
             .unwrap();
 
@@ -482,7 +491,7 @@ mod tests {
                 assert!(error.is_none());
                 let news = news.unwrap();
                 assert_eq!(news.body, Some("# Updated".to_string()));
-                assert_eq!(news.image, Some("data:image/png;base64,xyz".to_string()));
+                assert_eq!(news.attachments, vec![xyz]);
                 assert!(news.updated_at.is_some());
             }
             _ => panic!("Expected NewsEditResponse"),
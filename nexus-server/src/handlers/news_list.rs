@@ -21,7 +21,7 @@ where
 {
     // Verify authentication first
     let Some(requesting_session_id) = session_id else {
-        eprintln!("NewsList request from {} without login", ctx.peer_addr);
+        log_warn!("NewsList request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("NewsList"))
             .await;
@@ -47,9 +47,10 @@ where
 
     // Check NewsList permission
     if !requesting_user.has_permission(Permission::NewsList) {
-        eprintln!(
+        log_warn!(
             "NewsList from {} (user: {}) without permission",
-            ctx.peer_addr, requesting_user.username
+            ctx.peer_addr,
+            requesting_user.username
         );
         let response = ServerMessage::NewsListResponse {
             success: false,
@@ -63,7 +64,7 @@ where
     let news_records = match ctx.db.news.get_all_news().await {
         Ok(records) => records,
         Err(e) => {
-            eprintln!("Database error getting news: {}", e);
+            log_error!("Database error getting news: {}", e);
             return ctx
                 .send_error_and_disconnect(&err_database(ctx.locale), Some("NewsList"))
                 .await;
@@ -76,7 +77,7 @@ where
         .map(|record| NewsItem {
             id: record.id,
             body: record.body,
-            image: record.image,
+            attachments: record.attachments,
             author: record.author_username,
             author_is_admin: record.author_is_admin,
             created_at: record.created_at,
@@ -187,19 +188,19 @@ mod tests {
         test_ctx
             .db
             .news
-            .create_news(Some("First post"), None, user.id)
+            .create_news(Some("First post"), &[], user.id)
             .await
             .unwrap();
         test_ctx
             .db
             .news
-            .create_news(Some("Second post"), None, user.id)
+            .create_news(Some("Second post"), &[], user.id)
             .await
             .unwrap();
         test_ctx
             .db
             .news
-            .create_news(Some("Third post"), None, user.id)
+            .create_news(Some("Third post"), &[], user.id)
             .await
             .unwrap();
 
@@ -282,13 +283,13 @@ mod tests {
         test_ctx
             .db
             .news
-            .create_news(Some("Admin post"), None, admin.id)
+            .create_news(Some("Admin post"), &[], admin.id)
             .await
             .unwrap();
         test_ctx
             .db
             .news
-            .create_news(Some("User post"), None, user.id)
+            .create_news(Some("User post"), &[], user.id)
             .await
             .unwrap();
 
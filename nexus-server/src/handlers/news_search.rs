@@ -0,0 +1,331 @@
+//! NewsSearch message handler - Full-text search over news posts
+
+use std::io;
+
+use chrono::{Days, NaiveDate};
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::{NewsItem, ServerMessage};
+use nexus_common::validators::{
+    self, SearchQueryError, validate_news_search_date, validate_search_query,
+};
+
+#[cfg(test)]
+use super::testing::DEFAULT_TEST_LOCALE;
+use super::{
+    HandlerContext, err_database, err_news_search_date_invalid, err_not_logged_in,
+    err_permission_denied, err_search_query_empty, err_search_query_invalid,
+    err_search_query_too_long, err_search_query_too_short,
+};
+use crate::constants::MAX_NEWS_SEARCH_RESULTS;
+use crate::db::Permission;
+
+/// Handle a news search request
+///
+/// Uses the NewsList permission: anyone who can read the news can search it.
+pub async fn handle_news_search<W>(
+    query: String,
+    author: Option<String>,
+    since: Option<String>,
+    until: Option<String>,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first
+    let Some(requesting_session_id) = session_id else {
+        log_warn!("NewsSearch request from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("NewsSearch"))
+            .await;
+    };
+
+    // Get requesting user from session
+    let requesting_user = match ctx
+        .user_manager
+        .get_user_by_session_id(requesting_session_id)
+        .await
+    {
+        Some(u) => u,
+        None => {
+            // Session not found - likely a race condition, not a security event
+            return send_error(ctx, err_not_logged_in(ctx.locale)).await;
+        }
+    };
+
+    // Check NewsList permission
+    if !requesting_user.has_permission(Permission::NewsList) {
+        log_warn!(
+            "NewsSearch from {} (user: {}) without permission",
+            ctx.peer_addr,
+            requesting_user.username
+        );
+        return send_error(ctx, err_permission_denied(ctx.locale)).await;
+    }
+
+    // Normalize empty filters to None
+    let author = author
+        .map(|a| a.trim().to_string())
+        .filter(|a| !a.is_empty());
+    let since = since.filter(|d| !d.is_empty());
+    let until = until.filter(|d| !d.is_empty());
+
+    // Validate the query (it may be left out when a filter is given)
+    let query = query.trim();
+    let has_filter = author.is_some() || since.is_some() || until.is_some();
+    if (!query.is_empty() || !has_filter)
+        && let Err(e) = validate_search_query(query)
+    {
+        let error_msg = match e {
+            SearchQueryError::Empty => err_search_query_empty(ctx.locale),
+            SearchQueryError::TooShort => {
+                err_search_query_too_short(ctx.locale, validators::MIN_QUERY_LENGTH)
+            }
+            SearchQueryError::TooLong => {
+                err_search_query_too_long(ctx.locale, validators::MAX_SEARCH_QUERY_LENGTH)
+            }
+            SearchQueryError::InvalidCharacters => err_search_query_invalid(ctx.locale),
+        };
+        return send_error(ctx, error_msg).await;
+    }
+
+    // Validate dates; the end date includes the whole day
+    for date in since.iter().chain(until.iter()) {
+        if validate_news_search_date(date).is_err() {
+            return send_error(ctx, err_news_search_date_invalid(ctx.locale, date)).await;
+        }
+    }
+    let before = until.as_deref().and_then(day_after);
+
+    let news_records = match ctx
+        .db
+        .news
+        .search_news(
+            query,
+            author.as_deref(),
+            since.as_deref(),
+            before.as_deref(),
+            MAX_NEWS_SEARCH_RESULTS,
+        )
+        .await
+    {
+        Ok(records) => records,
+        Err(e) => {
+            log_error!("Database error searching news: {}", e);
+            return ctx
+                .send_error_and_disconnect(&err_database(ctx.locale), Some("NewsSearch"))
+                .await;
+        }
+    };
+
+    // Convert to protocol format
+    let items: Vec<NewsItem> = news_records
+        .into_iter()
+        .map(|record| NewsItem {
+            id: record.id,
+            body: record.body,
+            attachments: record.attachments,
+            author: record.author_username,
+            author_is_admin: record.author_is_admin,
+            created_at: record.created_at,
+            updated_at: record.updated_at,
+        })
+        .collect();
+
+    let response = ServerMessage::NewsSearchResponse {
+        success: true,
+        error: None,
+        items: Some(items),
+    };
+    ctx.send_message(&response).await
+}
+
+/// Send a failed NewsSearchResponse
+async fn send_error<W>(ctx: &mut HandlerContext<'_, W>, error: String) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let response = ServerMessage::NewsSearchResponse {
+        success: false,
+        error: Some(error),
+        items: None,
+    };
+    ctx.send_message(&response).await
+}
+
+/// The day after a `YYYY-MM-DD` date, in the same form
+fn day_after(date: &str) -> Option<String> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .ok()?
+        .checked_add_days(Days::new(1))
+        .map(|day| day.format("%Y-%m-%d").to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db;
+    use crate::handlers::testing::{
+        TestContext, create_test_context, login_user, read_server_message,
+    };
+
+    /// Run a search and return the response fields
+    async fn search(
+        test_ctx: &mut TestContext,
+        session_id: u32,
+        query: &str,
+        author: Option<&str>,
+        until: Option<&str>,
+    ) -> (bool, Option<String>, Option<Vec<NewsItem>>) {
+        handle_news_search(
+            query.to_string(),
+            author.map(str::to_string),
+            None,
+            until.map(str::to_string),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        match read_server_message(test_ctx).await {
+            ServerMessage::NewsSearchResponse {
+                success,
+                error,
+                items,
+            } => (success, error, items),
+            _ => panic!("Expected NewsSearchResponse"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_news_search_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_news_search(
+            "release".to_string(),
+            None,
+            None,
+            None,
+            None,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_news_search_requires_permission() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let (success, error, _) = search(&mut test_ctx, session_id, "release", None, None).await;
+
+        assert!(!success);
+        assert_eq!(error, Some(err_permission_denied(DEFAULT_TEST_LOCALE)));
+    }
+
+    #[tokio::test]
+    async fn test_news_search_validates_input() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[db::Permission::NewsList],
+            false,
+        )
+        .await;
+
+        // Nothing to search for
+        let (success, error, _) = search(&mut test_ctx, session_id, "  ", None, None).await;
+        assert!(!success);
+        assert_eq!(error, Some(err_search_query_empty(DEFAULT_TEST_LOCALE)));
+
+        // Bad date
+        let (success, error, _) =
+            search(&mut test_ctx, session_id, "", None, Some("2024-02-30")).await;
+        assert!(!success);
+        assert_eq!(
+            error,
+            Some(err_news_search_date_invalid(
+                DEFAULT_TEST_LOCALE,
+                "2024-02-30"
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_news_search_finds_posts() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[db::Permission::NewsList],
+            false,
+        )
+        .await;
+        let user = test_ctx
+            .db
+            .users
+            .get_user_by_username("alice")
+            .await
+            .unwrap()
+            .unwrap();
+
+        test_ctx
+            .db
+            .news
+            .import_news(
+                Some("Welcome to the board"),
+                &[],
+                user.id,
+                "2023-12-31T23:00:00+00:00",
+                None,
+            )
+            .await
+            .unwrap();
+        test_ctx
+            .db
+            .news
+            .create_news(Some("Welcome back, everyone"), &[], user.id)
+            .await
+            .unwrap();
+
+        let (success, _, items) = search(&mut test_ctx, session_id, "welcome", None, None).await;
+        assert!(success);
+        let items = items.unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].body.as_deref(), Some("Welcome back, everyone"));
+        assert_eq!(items[0].author, "alice");
+
+        // The end date includes posts from that whole day
+        let (success, _, items) = search(
+            &mut test_ctx,
+            session_id,
+            "welcome",
+            None,
+            Some("2023-12-31"),
+        )
+        .await;
+        assert!(success);
+        let items = items.unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].body.as_deref(), Some("Welcome to the board"));
+
+        // Filters alone are enough
+        let (success, _, items) = search(&mut test_ctx, session_id, "", Some("bob"), None).await;
+        assert!(success);
+        assert!(items.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_day_after() {
+        assert_eq!(day_after("2024-02-28").as_deref(), Some("2024-02-29"));
+        assert_eq!(day_after("2023-12-31").as_deref(), Some("2024-01-01"));
+        assert_eq!(day_after("bad"), None);
+    }
+}
@@ -24,7 +24,7 @@ where
 {
     // Verify authentication first
     let Some(requesting_session_id) = session_id else {
-        eprintln!("NewsShow request from {} without login", ctx.peer_addr);
+        log_warn!("NewsShow request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("NewsShow"))
             .await;
@@ -50,9 +50,10 @@ where
 
     // Check NewsList permission (required to view news)
     if !requesting_user.has_permission(Permission::NewsList) {
-        eprintln!(
+        log_warn!(
             "NewsShow from {} (user: {}) without permission",
-            ctx.peer_addr, requesting_user.username
+            ctx.peer_addr,
+            requesting_user.username
         );
         let response = ServerMessage::NewsShowResponse {
             success: false,
@@ -74,7 +75,7 @@ where
             return ctx.send_message(&response).await;
         }
         Err(e) => {
-            eprintln!("Database error getting news: {}", e);
+            log_error!("Database error getting news: {}", e);
             return ctx
                 .send_error_and_disconnect(&err_database(ctx.locale), Some("NewsShow"))
                 .await;
@@ -85,7 +86,7 @@ where
     let news = NewsItem {
         id: news_record.id,
         body: news_record.body,
-        image: news_record.image,
+        attachments: news_record.attachments,
         author: news_record.author_username,
         author_is_admin: news_record.author_is_admin,
         created_at: news_record.created_at,
@@ -103,6 +104,8 @@ where
 
 #[cfg(test)]
 mod tests {
+    use nexus_common::protocol::NewsAttachment;
+
     use super::*;
     use crate::db;
     use crate::handlers::testing::{create_test_context, login_user, read_server_message};
@@ -188,14 +191,14 @@ mod tests {
             .unwrap();
 
         // Create a news item
+        let image = NewsAttachment::Image {
+            data: "data:image/png;base64,abc".to_string(),
+            caption: Some("abc".to_string()),
+        };
         let created = test_ctx
             .db
             .news
-            .create_news(
-                Some("Test news post"),
-                Some("data:image/png;base64,abc"),
-                user.id,
-            )
+            .create_news(Some("Test news post"), &[image.clone()], user.id)
             .await
             .unwrap();
 
@@ -219,7 +222,7 @@ mod tests {
                 let news = news.unwrap();
                 assert_eq!(news.id, created.id);
                 assert_eq!(news.body, Some("Test news post".to_string()));
-                assert_eq!(news.image, Some("data:image/png;base64,abc".to_string()));
+                assert_eq!(news.attachments, vec![image]);
                 assert_eq!(news.author, "alice");
                 assert!(!news.author_is_admin);
                 assert!(news.updated_at.is_none());
@@ -248,7 +251,7 @@ mod tests {
         let created = test_ctx
             .db
             .news
-            .create_news(Some("Admin news"), None, admin.id)
+            .create_news(Some("Admin news"), &[], admin.id)
             .await
             .unwrap();
 
@@ -299,7 +302,7 @@ mod tests {
         let created = test_ctx
             .db
             .news
-            .create_news(Some("Original"), None, user.id)
+            .create_news(Some("Original"), &[], user.id)
             .await
             .unwrap();
 
@@ -307,7 +310,7 @@ mod tests {
         test_ctx
             .db
             .news
-            .update_news(created.id, Some("Updated"), None)
+            .update_news(created.id, Some("Updated"), &[])
             .await
             .unwrap();
 
@@ -4,16 +4,16 @@ use std::io;
 
 use tokio::io::AsyncWrite;
 
-use nexus_common::protocol::{NewsAction, NewsItem, ServerMessage};
-use nexus_common::validators::{self, NewsBodyError, NewsImageError};
+use nexus_common::protocol::{NewsAction, NewsAttachment, NewsItem, ServerMessage};
+use nexus_common::validators::{self, NewsBodyError};
 
+use super::news_attachments::prepare_attachments;
 #[cfg(test)]
-use super::testing::DEFAULT_TEST_LOCALE;
+use super::testing::{DEFAULT_TEST_LOCALE, TEST_PNG_DATA_URI};
 use super::{
     HandlerContext, err_cannot_edit_admin_news, err_database, err_news_body_invalid_characters,
-    err_news_body_too_long, err_news_empty_content, err_news_image_invalid_format,
-    err_news_image_too_large, err_news_image_unsupported_type, err_news_not_found,
-    err_not_logged_in, err_permission_denied,
+    err_news_body_too_long, err_news_empty_content, err_news_not_found, err_not_logged_in,
+    err_permission_denied,
 };
 use crate::constants::FEATURE_NEWS;
 use crate::db::Permission;
@@ -23,6 +23,7 @@ pub async fn handle_news_update<W>(
     id: i64,
     body: Option<String>,
     image: Option<String>,
+    attachments: Vec<NewsAttachment>,
     session_id: Option<u32>,
     ctx: &mut HandlerContext<'_, W>,
 ) -> io::Result<()>
@@ -31,7 +32,7 @@ where
 {
     // Verify authentication first
     let Some(requesting_session_id) = session_id else {
-        eprintln!("NewsUpdate request from {} without login", ctx.peer_addr);
+        log_warn!("NewsUpdate request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("NewsUpdate"))
             .await;
@@ -59,8 +60,8 @@ where
     let body = body.filter(|s| !s.trim().is_empty());
     let image = image.filter(|s| !s.is_empty());
 
-    // Validate that at least one of body or image is provided
-    if body.is_none() && image.is_none() {
+    // Validate that a body or at least one attachment is provided
+    if body.is_none() && image.is_none() && attachments.is_empty() {
         let response = ServerMessage::NewsUpdateResponse {
             success: false,
             error: Some(err_news_empty_content(ctx.locale)),
@@ -87,22 +88,19 @@ where
         return ctx.send_message(&response).await;
     }
 
-    // Validate image if provided
-    if let Some(ref image_data) = image
-        && let Err(e) = validators::validate_news_image(image_data)
-    {
-        let error_msg = match e {
-            NewsImageError::TooLarge => err_news_image_too_large(ctx.locale),
-            NewsImageError::InvalidFormat => err_news_image_invalid_format(ctx.locale),
-            NewsImageError::UnsupportedType => err_news_image_unsupported_type(ctx.locale),
-        };
-        let response = ServerMessage::NewsUpdateResponse {
-            success: false,
-            error: Some(error_msg),
-            news: None,
+    // Validate attachments, scaling images down to the server's limits
+    let attachments =
+        match prepare_attachments(image, attachments, *ctx.news_image_limits, ctx.locale).await {
+            Ok(attachments) => attachments,
+            Err(error_msg) => {
+                let response = ServerMessage::NewsUpdateResponse {
+                    success: false,
+                    error: Some(error_msg),
+                    news: None,
+                };
+                return ctx.send_message(&response).await;
+            }
         };
-        return ctx.send_message(&response).await;
-    }
 
     // Fetch existing news item to check authorship and admin status
     let existing_news = match ctx.db.news.get_news_by_id(id).await {
@@ -116,7 +114,7 @@ where
             return ctx.send_message(&response).await;
         }
         Err(e) => {
-            eprintln!("Database error getting news: {}", e);
+            log_error!("Database error getting news: {}", e);
             return ctx
                 .send_error_and_disconnect(&err_database(ctx.locale), Some("NewsUpdate"))
                 .await;
@@ -128,9 +126,11 @@ where
     let has_edit_permission = requesting_user.has_permission(Permission::NewsEdit);
 
     if !is_author && !has_edit_permission {
-        eprintln!(
+        log_warn!(
             "NewsUpdate from {} (user: {}) without permission for news #{}",
-            ctx.peer_addr, requesting_user.username, id
+            ctx.peer_addr,
+            requesting_user.username,
+            id
         );
         let response = ServerMessage::NewsUpdateResponse {
             success: false,
@@ -142,9 +142,11 @@ where
 
     // Check admin protection: non-admins cannot edit admin posts
     if existing_news.author_is_admin && !requesting_user.is_admin {
-        eprintln!(
+        log_warn!(
             "NewsUpdate from {} (user: {}) trying to edit admin news #{}",
-            ctx.peer_addr, requesting_user.username, id
+            ctx.peer_addr,
+            requesting_user.username,
+            id
         );
         let response = ServerMessage::NewsUpdateResponse {
             success: false,
@@ -158,7 +160,7 @@ where
     let news_record = match ctx
         .db
         .news
-        .update_news(id, body.as_deref(), image.as_deref())
+        .update_news(id, body.as_deref(), &attachments)
         .await
     {
         Ok(Some(record)) => record,
@@ -172,7 +174,7 @@ where
             return ctx.send_message(&response).await;
         }
         Err(e) => {
-            eprintln!("Database error updating news: {}", e);
+            log_error!("Database error updating news: {}", e);
             return ctx
                 .send_error_and_disconnect(&err_database(ctx.locale), Some("NewsUpdate"))
                 .await;
@@ -183,7 +185,7 @@ where
     let news = NewsItem {
         id: news_record.id,
         body: news_record.body,
-        image: news_record.image,
+        attachments: news_record.attachments,
         author: news_record.author_username,
         author_is_admin: news_record.author_is_admin,
         created_at: news_record.created_at,
@@ -214,6 +216,7 @@ where
 mod tests {
     use super::*;
     use crate::db;
+    use crate::handlers::err_news_image_invalid_format;
     use crate::handlers::testing::{create_test_context, login_user, read_server_message};
 
     #[tokio::test]
@@ -224,6 +227,7 @@ mod tests {
             1,
             Some("Updated".to_string()),
             None,
+            vec![],
             None,
             &mut test_ctx.handler_context(),
         )
@@ -243,6 +247,7 @@ mod tests {
             99999,
             Some("Updated".to_string()),
             None,
+            vec![],
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -277,7 +282,7 @@ mod tests {
         let created = test_ctx
             .db
             .news
-            .create_news(Some("Original"), None, admin.id)
+            .create_news(Some("Original"), &[], admin.id)
             .await
             .unwrap();
 
@@ -285,6 +290,7 @@ mod tests {
             created.id,
             None,
             None,
+            vec![],
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -326,7 +332,7 @@ mod tests {
         let created = test_ctx
             .db
             .news
-            .create_news(Some("Original"), None, user.id)
+            .create_news(Some("Original"), &[], user.id)
             .await
             .unwrap();
 
@@ -334,6 +340,7 @@ mod tests {
             created.id,
             Some("Updated by author".to_string()),
             None,
+            vec![],
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -382,7 +389,7 @@ mod tests {
         let created = test_ctx
             .db
             .news
-            .create_news(Some("Author's post"), None, author.id)
+            .create_news(Some("Author's post"), &[], author.id)
             .await
             .unwrap();
 
@@ -393,6 +400,7 @@ mod tests {
             created.id,
             Some("Hacked!".to_string()),
             None,
+            vec![],
             Some(other_session),
             &mut test_ctx.handler_context(),
         )
@@ -434,7 +442,7 @@ mod tests {
         let created = test_ctx
             .db
             .news
-            .create_news(Some("Author's post"), None, author.id)
+            .create_news(Some("Author's post"), &[], author.id)
             .await
             .unwrap();
 
@@ -452,6 +460,7 @@ mod tests {
             created.id,
             Some("Edited by editor".to_string()),
             None,
+            vec![],
             Some(editor_session),
             &mut test_ctx.handler_context(),
         )
@@ -494,7 +503,7 @@ mod tests {
         let created = test_ctx
             .db
             .news
-            .create_news(Some("Admin's post"), None, admin.id)
+            .create_news(Some("Admin's post"), &[], admin.id)
             .await
             .unwrap();
 
@@ -512,6 +521,7 @@ mod tests {
             created.id,
             Some("Trying to edit admin post".to_string()),
             None,
+            vec![],
             Some(editor_session),
             &mut test_ctx.handler_context(),
         )
@@ -546,7 +556,7 @@ mod tests {
         let created = test_ctx
             .db
             .news
-            .create_news(Some("Admin1's post"), None, admin1.id)
+            .create_news(Some("Admin1's post"), &[], admin1.id)
             .await
             .unwrap();
 
@@ -557,6 +567,7 @@ mod tests {
             created.id,
             Some("Edited by admin2".to_string()),
             None,
+            vec![],
             Some(admin2_session),
             &mut test_ctx.handler_context(),
         )
@@ -597,7 +608,7 @@ mod tests {
         let created = test_ctx
             .db
             .news
-            .create_news(Some("Original"), None, admin.id)
+            .create_news(Some("Original"), &[], admin.id)
             .await
             .unwrap();
 
@@ -606,6 +617,7 @@ mod tests {
             created.id,
             Some(long_body),
             None,
+            vec![],
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -646,7 +658,7 @@ mod tests {
         let created = test_ctx
             .db
             .news
-            .create_news(Some("Original"), None, admin.id)
+            .create_news(Some("Original"), &[], admin.id)
             .await
             .unwrap();
 
@@ -654,6 +666,7 @@ mod tests {
             created.id,
             None,
             Some("not a data uri".to_string()),
+            vec![],
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -691,14 +704,15 @@ mod tests {
         let created = test_ctx
             .db
             .news
-            .create_news(Some("Original"), None, admin.id)
+            .create_news(Some("Original"), &[], admin.id)
             .await
             .unwrap();
 
         let result = handle_news_update(
             created.id,
             Some("Updated with image".to_string()),
-            Some("data:image/png;base64,iVBORw0KGgo=".to_string()),
+            Some(TEST_PNG_DATA_URI.to_string()),
+            vec![],
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -717,8 +731,11 @@ mod tests {
                 let news = news.unwrap();
                 assert_eq!(news.body, Some("Updated with image".to_string()));
                 assert_eq!(
-                    news.image,
-                    Some("data:image/png;base64,iVBORw0KGgo=".to_string())
+                    news.attachments,
+                    vec![NewsAttachment::Image {
+                        data: TEST_PNG_DATA_URI.to_string(),
+                        caption: None,
+                    }]
                 );
             }
             _ => panic!("Expected NewsUpdateResponse"),
@@ -0,0 +1,598 @@
+//! Handlers for NicknameRegister and NicknameRelease commands
+//!
+//! Regular accounts can reserve up to `MAX_REGISTERED_NICKNAMES` nicknames,
+//! which shared account and guest sessions then can't log in with. Admins
+//! can reserve nicknames for any regular account, without the limit, and
+//! release anyone's. Sessions already using a nickname keep it until they
+//! log out.
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+use nexus_common::validators::{self, NicknameError};
+
+use super::{
+    HandlerContext, err_authentication, err_database, err_nickname_already_registered,
+    err_nickname_empty, err_nickname_invalid, err_nickname_is_username,
+    err_nickname_not_registered, err_nickname_register_limit, err_nickname_register_shared,
+    err_nickname_registered, err_nickname_too_long, err_not_logged_in, err_permission_denied,
+    err_user_not_found,
+};
+use crate::constants::MAX_REGISTERED_NICKNAMES;
+
+/// Translated error for a nickname that fails validation, if it does
+fn nickname_error(nickname: &str, locale: &str) -> Option<String> {
+    validators::validate_nickname(nickname)
+        .err()
+        .map(|e| match e {
+            NicknameError::Empty => err_nickname_empty(locale),
+            NicknameError::TooLong => {
+                err_nickname_too_long(locale, validators::MAX_NICKNAME_LENGTH)
+            }
+            NicknameError::InvalidCharacters => err_nickname_invalid(locale),
+        })
+}
+
+/// Handle NicknameRegister command
+///
+/// `username` picks the account to reserve the nickname for (admins only);
+/// without it the nickname is reserved for the requesting account.
+pub async fn handle_nickname_register<W>(
+    nickname: String,
+    username: Option<String>,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let failure = |error: String| ServerMessage::NicknameRegisterResponse {
+        success: false,
+        error: Some(error),
+        nickname: None,
+        username: None,
+    };
+
+    let Some(session_id) = session_id else {
+        log_warn!(
+            "NicknameRegister request from {} without login",
+            ctx.peer_addr
+        );
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("NicknameRegister"))
+            .await;
+    };
+
+    let Some(user) = ctx.user_manager.get_user_by_session_id(session_id).await else {
+        return ctx
+            .send_error_and_disconnect(&err_authentication(ctx.locale), Some("NicknameRegister"))
+            .await;
+    };
+
+    if let Some(error) = nickname_error(&nickname, ctx.locale) {
+        return ctx.send_message(&failure(error)).await;
+    }
+
+    // Only admins can reserve nicknames for someone else
+    let target = username.unwrap_or_else(|| user.username.clone());
+    let for_self = target.to_lowercase() == user.username.to_lowercase();
+    if !for_self && !user.is_admin {
+        return ctx
+            .send_message(&failure(err_permission_denied(ctx.locale)))
+            .await;
+    }
+
+    let account = match ctx.db.users.get_user_by_username(&target).await {
+        Ok(Some(account)) => account,
+        Ok(None) => {
+            return ctx
+                .send_message(&failure(err_user_not_found(ctx.locale, &target)))
+                .await;
+        }
+        Err(e) => {
+            log_error!("NicknameRegister database error: {}", e);
+            return ctx.send_message(&failure(err_database(ctx.locale))).await;
+        }
+    };
+
+    // Shared accounts are the ones nicknames are reserved against
+    if account.is_shared {
+        return ctx
+            .send_message(&failure(err_nickname_register_shared(ctx.locale)))
+            .await;
+    }
+
+    // Usernames are already off limits to shared sessions
+    match ctx.db.users.username_exists(&nickname).await {
+        Ok(false) => {}
+        Ok(true) => {
+            return ctx
+                .send_message(&failure(err_nickname_is_username(ctx.locale)))
+                .await;
+        }
+        Err(e) => {
+            log_error!("NicknameRegister database error: {}", e);
+            return ctx.send_message(&failure(err_database(ctx.locale))).await;
+        }
+    }
+
+    match ctx.db.nicknames.get(&nickname).await {
+        Ok(None) => {}
+        Ok(Some(registered)) => {
+            return ctx
+                .send_message(&failure(err_nickname_already_registered(
+                    ctx.locale,
+                    &registered.nickname,
+                )))
+                .await;
+        }
+        Err(e) => {
+            log_error!("NicknameRegister database error: {}", e);
+            return ctx.send_message(&failure(err_database(ctx.locale))).await;
+        }
+    }
+
+    if !user.is_admin {
+        match ctx.db.nicknames.count_for_user(account.id).await {
+            Ok(count) if count < MAX_REGISTERED_NICKNAMES => {}
+            Ok(_) => {
+                return ctx
+                    .send_message(&failure(err_nickname_register_limit(
+                        ctx.locale,
+                        MAX_REGISTERED_NICKNAMES,
+                    )))
+                    .await;
+            }
+            Err(e) => {
+                log_error!("NicknameRegister database error: {}", e);
+                return ctx.send_message(&failure(err_database(ctx.locale))).await;
+            }
+        }
+    }
+
+    let response = match ctx.db.nicknames.register(&nickname, account.id).await {
+        Ok(()) => {
+            log_info!(
+                "{} registered nickname '{}' for '{}'",
+                user.username,
+                nickname,
+                account.username
+            );
+            ServerMessage::NicknameRegisterResponse {
+                success: true,
+                error: None,
+                nickname: Some(nickname),
+                username: Some(account.username),
+            }
+        }
+        Err(e) => {
+            log_error!("NicknameRegister database error: {}", e);
+            failure(err_database(ctx.locale))
+        }
+    };
+    ctx.send_message(&response).await
+}
+
+/// Handle NicknameRelease command
+///
+/// Accounts can release their own nicknames; admins can release anyone's.
+pub async fn handle_nickname_release<W>(
+    nickname: String,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let failure = |error: String| ServerMessage::NicknameReleaseResponse {
+        success: false,
+        error: Some(error),
+        nickname: None,
+    };
+
+    let Some(session_id) = session_id else {
+        log_warn!(
+            "NicknameRelease request from {} without login",
+            ctx.peer_addr
+        );
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("NicknameRelease"))
+            .await;
+    };
+
+    let Some(user) = ctx.user_manager.get_user_by_session_id(session_id).await else {
+        return ctx
+            .send_error_and_disconnect(&err_authentication(ctx.locale), Some("NicknameRelease"))
+            .await;
+    };
+
+    if let Some(error) = nickname_error(&nickname, ctx.locale) {
+        return ctx.send_message(&failure(error)).await;
+    }
+
+    let registered = match ctx.db.nicknames.get(&nickname).await {
+        Ok(Some(registered)) => registered,
+        Ok(None) => {
+            return ctx
+                .send_message(&failure(err_nickname_not_registered(ctx.locale, &nickname)))
+                .await;
+        }
+        Err(e) => {
+            log_error!("NicknameRelease database error: {}", e);
+            return ctx.send_message(&failure(err_database(ctx.locale))).await;
+        }
+    };
+
+    if registered.user_id != user.db_user_id && !user.is_admin {
+        return ctx
+            .send_message(&failure(err_nickname_registered(ctx.locale)))
+            .await;
+    }
+
+    let response = match ctx.db.nicknames.release(&registered.nickname).await {
+        Ok(_) => {
+            log_info!(
+                "{} released nickname '{}' of '{}'",
+                user.username,
+                registered.nickname,
+                registered.username
+            );
+            ServerMessage::NicknameReleaseResponse {
+                success: true,
+                error: None,
+                nickname: Some(registered.nickname),
+            }
+        }
+        Err(e) => {
+            log_error!("NicknameRelease database error: {}", e);
+            failure(err_database(ctx.locale))
+        }
+    };
+    ctx.send_message(&response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Permissions;
+    use crate::handlers::testing::{
+        DEFAULT_TEST_LOCALE, create_test_context, login_user, read_server_message,
+    };
+
+    #[tokio::test]
+    async fn test_nickname_register_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_nickname_register(
+            "Bobby".to_string(),
+            None,
+            None,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_err(), "Should disconnect when not logged in");
+    }
+
+    #[tokio::test]
+    async fn test_nickname_register_for_self() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "bob", "password", &[], false).await;
+
+        handle_nickname_register(
+            "Bobby".to_string(),
+            None,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::NicknameRegisterResponse {
+                success,
+                nickname,
+                username,
+                ..
+            } => {
+                assert!(success);
+                assert_eq!(nickname.as_deref(), Some("Bobby"));
+                assert_eq!(username.as_deref(), Some("bob"));
+            }
+            response => panic!("Expected NicknameRegisterResponse, got {:?}", response),
+        }
+        assert!(test_ctx.db.nicknames.is_registered("bobby").await.unwrap());
+
+        // Reserving it again fails, whatever the case
+        handle_nickname_register(
+            "BOBBY".to_string(),
+            None,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::NicknameRegisterResponse { success, error, .. } => {
+                assert!(!success);
+                assert_eq!(
+                    error,
+                    Some(err_nickname_already_registered(
+                        DEFAULT_TEST_LOCALE,
+                        "Bobby"
+                    ))
+                );
+            }
+            response => panic!("Expected NicknameRegisterResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_nickname_register_limit() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "bob", "password", &[], false).await;
+
+        for i in 0..=MAX_REGISTERED_NICKNAMES {
+            handle_nickname_register(
+                format!("Bobby{i}"),
+                None,
+                Some(session_id),
+                &mut test_ctx.handler_context(),
+            )
+            .await
+            .unwrap();
+
+            match read_server_message(&mut test_ctx).await {
+                ServerMessage::NicknameRegisterResponse { success, error, .. } => {
+                    if i < MAX_REGISTERED_NICKNAMES {
+                        assert!(success);
+                    } else {
+                        assert!(!success);
+                        assert_eq!(
+                            error,
+                            Some(err_nickname_register_limit(
+                                DEFAULT_TEST_LOCALE,
+                                MAX_REGISTERED_NICKNAMES
+                            ))
+                        );
+                    }
+                }
+                response => panic!("Expected NicknameRegisterResponse, got {:?}", response),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_nickname_register_rejects_username() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "bob", "password", &[], false).await;
+
+        handle_nickname_register(
+            "BOB".to_string(),
+            None,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::NicknameRegisterResponse { success, error, .. } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_nickname_is_username(DEFAULT_TEST_LOCALE)));
+            }
+            response => panic!("Expected NicknameRegisterResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_nickname_register_for_other_requires_admin() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "bob", "password", &[], false).await;
+        test_ctx
+            .db
+            .users
+            .create_user("alice", "hash", false, false, true, &Permissions::new())
+            .await
+            .unwrap();
+
+        handle_nickname_register(
+            "Ally".to_string(),
+            Some("alice".to_string()),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::NicknameRegisterResponse { success, error, .. } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_permission_denied(DEFAULT_TEST_LOCALE)));
+            }
+            response => panic!("Expected NicknameRegisterResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_admin_registers_for_other() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+        test_ctx
+            .db
+            .users
+            .create_user("alice", "hash", false, false, true, &Permissions::new())
+            .await
+            .unwrap();
+        test_ctx
+            .db
+            .users
+            .create_user("lobby", "", false, true, true, &Permissions::new())
+            .await
+            .unwrap();
+
+        handle_nickname_register(
+            "Ally".to_string(),
+            Some("alice".to_string()),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::NicknameRegisterResponse {
+                success, username, ..
+            } => {
+                assert!(success);
+                assert_eq!(username.as_deref(), Some("alice"));
+            }
+            response => panic!("Expected NicknameRegisterResponse, got {:?}", response),
+        }
+
+        // Shared accounts can't reserve nicknames
+        handle_nickname_register(
+            "Lobbyist".to_string(),
+            Some("lobby".to_string()),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::NicknameRegisterResponse { success, error, .. } => {
+                assert!(!success);
+                assert_eq!(
+                    error,
+                    Some(err_nickname_register_shared(DEFAULT_TEST_LOCALE))
+                );
+            }
+            response => panic!("Expected NicknameRegisterResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_nickname_release() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "bob", "password", &[], false).await;
+        let alice = test_ctx
+            .db
+            .users
+            .create_user("alice", "hash", false, false, true, &Permissions::new())
+            .await
+            .unwrap();
+        test_ctx
+            .db
+            .nicknames
+            .register("Ally", alice.id)
+            .await
+            .unwrap();
+
+        // Someone else's nickname
+        handle_nickname_release(
+            "ally".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::NicknameReleaseResponse { success, error, .. } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_nickname_registered(DEFAULT_TEST_LOCALE)));
+            }
+            response => panic!("Expected NicknameReleaseResponse, got {:?}", response),
+        }
+        assert!(test_ctx.db.nicknames.is_registered("Ally").await.unwrap());
+
+        // Their own nickname
+        let bob = test_ctx
+            .db
+            .users
+            .get_user_by_username("bob")
+            .await
+            .unwrap()
+            .unwrap();
+        test_ctx
+            .db
+            .nicknames
+            .register("Bobby", bob.id)
+            .await
+            .unwrap();
+
+        handle_nickname_release(
+            "bobby".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::NicknameReleaseResponse {
+                success, nickname, ..
+            } => {
+                assert!(success);
+                assert_eq!(nickname.as_deref(), Some("Bobby"));
+            }
+            response => panic!("Expected NicknameReleaseResponse, got {:?}", response),
+        }
+        assert!(!test_ctx.db.nicknames.is_registered("Bobby").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_admin_releases_any_nickname() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+        let alice = test_ctx
+            .db
+            .users
+            .create_user("alice", "hash", false, false, true, &Permissions::new())
+            .await
+            .unwrap();
+        test_ctx
+            .db
+            .nicknames
+            .register("Ally", alice.id)
+            .await
+            .unwrap();
+
+        handle_nickname_release(
+            "Ally".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::NicknameReleaseResponse { success, .. } => assert!(success),
+            response => panic!("Expected NicknameReleaseResponse, got {:?}", response),
+        }
+
+        // Nothing left to release
+        handle_nickname_release(
+            "Ally".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::NicknameReleaseResponse { success, error, .. } => {
+                assert!(!success);
+                assert_eq!(
+                    error,
+                    Some(err_nickname_not_registered(DEFAULT_TEST_LOCALE, "Ally"))
+                );
+            }
+            response => panic!("Expected NicknameReleaseResponse, got {:?}", response),
+        }
+    }
+}
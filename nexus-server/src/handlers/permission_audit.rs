@@ -0,0 +1,480 @@
+//! Handler for PermissionAudit command
+//!
+//! Answers "who can do X?" and "what can user Y do?" for admins. Both
+//! questions go through the same rules the server enforces at login:
+//! admins hold every permission, shared accounts only keep the permissions
+//! allowed for shared accounts, and the guest account only those allowed for
+//! guests.
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::{
+    EffectivePermission, EffectivePermissions, PermissionHolder, PermissionSource, ServerMessage,
+};
+use nexus_common::validators::{self, UsernameError};
+use nexus_common::{ALL_PERMISSIONS, is_guest_account_permission, is_shared_account_permission};
+
+#[cfg(test)]
+use super::testing::DEFAULT_TEST_LOCALE;
+use super::{
+    HandlerContext, err_authentication, err_database, err_invalid_message_format,
+    err_not_logged_in, err_permission_denied, err_unknown_permission, err_user_not_found,
+    err_username_empty, err_username_invalid, err_username_too_long,
+};
+use crate::db::sql::GUEST_USERNAME;
+use crate::db::users::UserAccount;
+use crate::db::{Permission, Permissions, UserDb};
+
+/// Handle PermissionAudit command
+pub async fn handle_permission_audit<W>(
+    permission: Option<String>,
+    username: Option<String>,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first (before revealing validation errors to unauthenticated users)
+    let Some(session_id) = session_id else {
+        log_warn!(
+            "PermissionAudit request from {} without login",
+            ctx.peer_addr
+        );
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("PermissionAudit"))
+            .await;
+    };
+
+    let requesting_user_session = match ctx.user_manager.get_user_by_session_id(session_id).await {
+        Some(user) => user,
+        None => {
+            return ctx
+                .send_error_and_disconnect(&err_authentication(ctx.locale), Some("PermissionAudit"))
+                .await;
+        }
+    };
+
+    // Auditing is admin-only (it reveals every account's permissions)
+    if !requesting_user_session.is_admin {
+        log_warn!(
+            "PermissionAudit from {} (user: {}) without permission",
+            ctx.peer_addr,
+            requesting_user_session.username
+        );
+        return send_failure(err_permission_denied(ctx.locale), ctx).await;
+    }
+
+    match (permission, username) {
+        (Some(permission), None) => audit_permission(&permission, ctx).await,
+        (None, Some(username)) => audit_user(&username, ctx).await,
+        _ => send_failure(err_invalid_message_format(ctx.locale), ctx).await,
+    }
+}
+
+/// List the accounts that can use a permission
+async fn audit_permission<W>(permission: &str, ctx: &mut HandlerContext<'_, W>) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(parsed) = Permission::parse(permission) else {
+        return send_failure(err_unknown_permission(ctx.locale, permission), ctx).await;
+    };
+
+    let accounts = match ctx.db.users.get_users_with_permission(parsed).await {
+        Ok(accounts) => accounts,
+        Err(e) => {
+            log_error!("Database error auditing permission {}: {}", permission, e);
+            return send_failure(err_database(ctx.locale), ctx).await;
+        }
+    };
+
+    let holders = accounts
+        .into_iter()
+        .filter_map(|account| {
+            let source = permission_source(&account, parsed)?;
+            Some(PermissionHolder {
+                username: account.username,
+                source,
+                is_shared: account.is_shared,
+                enabled: account.enabled,
+            })
+        })
+        .collect();
+
+    let response = ServerMessage::PermissionAuditResponse {
+        success: true,
+        error: None,
+        holders: Some(holders),
+        effective: None,
+    };
+    ctx.send_message(&response).await
+}
+
+/// Work out everything an account can do
+async fn audit_user<W>(username: &str, ctx: &mut HandlerContext<'_, W>) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    if let Err(e) = validators::validate_username(username) {
+        let error_msg = match e {
+            UsernameError::Empty => err_username_empty(ctx.locale),
+            UsernameError::TooLong => {
+                err_username_too_long(ctx.locale, validators::MAX_USERNAME_LENGTH)
+            }
+            UsernameError::InvalidCharacters => err_username_invalid(ctx.locale),
+        };
+        return send_failure(error_msg, ctx).await;
+    }
+
+    let account = match ctx.db.users.get_user_by_username(username).await {
+        Ok(Some(account)) => account,
+        Ok(None) => {
+            return send_failure(err_user_not_found(ctx.locale, username), ctx).await;
+        }
+        Err(e) => {
+            log_error!("Database error getting user {}: {}", username, e);
+            return send_failure(err_database(ctx.locale), ctx).await;
+        }
+    };
+
+    let effective = match effective_permissions(&ctx.db.users, account).await {
+        Ok(effective) => effective,
+        Err(e) => {
+            log_error!("Database error getting permissions of {}: {}", username, e);
+            return send_failure(err_database(ctx.locale), ctx).await;
+        }
+    };
+
+    let response = ServerMessage::PermissionAuditResponse {
+        success: true,
+        error: None,
+        holders: None,
+        effective: Some(effective),
+    };
+    ctx.send_message(&response).await
+}
+
+/// Work out the permissions an account can use and why
+///
+/// Also used by ImpersonateQuery, so both answer with the same rules.
+pub(super) async fn effective_permissions(
+    users: &UserDb,
+    account: UserAccount,
+) -> Result<EffectivePermissions, sqlx::Error> {
+    let mut granted = if account.is_admin {
+        Permissions::new()
+    } else {
+        users.get_user_permissions(account.id).await?
+    };
+
+    let mut restricted: Vec<String> = granted
+        .restrict_for_account(&account)
+        .iter()
+        .map(|p| p.as_str().to_string())
+        .collect();
+    restricted.sort();
+
+    let permissions = ALL_PERMISSIONS
+        .iter()
+        .filter_map(|name| {
+            let permission = Permission::parse(name)?;
+            let source = if account.is_admin {
+                PermissionSource::Admin
+            } else if granted.permissions.contains(&permission) {
+                PermissionSource::Granted
+            } else {
+                return None;
+            };
+            Some(EffectivePermission {
+                permission: name.to_string(),
+                source,
+            })
+        })
+        .collect();
+
+    Ok(EffectivePermissions {
+        username: account.username,
+        is_admin: account.is_admin,
+        is_shared: account.is_shared,
+        enabled: account.enabled,
+        permissions,
+        restricted,
+    })
+}
+
+/// Why `account` holds `permission`, or `None` if it can't use it
+///
+/// `account` comes from `get_users_with_permission`, so non-admins hold the
+/// permission directly; shared accounts and the guest account still lose the
+/// ones they can't use.
+fn permission_source(account: &UserAccount, permission: Permission) -> Option<PermissionSource> {
+    if account.is_admin {
+        Some(PermissionSource::Admin)
+    } else if (account.is_shared && !is_shared_account_permission(permission.as_str()))
+        || (account.username.eq_ignore_ascii_case(GUEST_USERNAME)
+            && !is_guest_account_permission(permission.as_str()))
+    {
+        None
+    } else {
+        Some(PermissionSource::Granted)
+    }
+}
+
+/// Send a failed PermissionAuditResponse
+async fn send_failure<W>(error: String, ctx: &mut HandlerContext<'_, W>) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let response = ServerMessage::PermissionAuditResponse {
+        success: false,
+        error: Some(error),
+        holders: None,
+        effective: None,
+    };
+    ctx.send_message(&response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db;
+    use crate::handlers::testing::{create_test_context, login_user, read_server_message};
+
+    #[tokio::test]
+    async fn test_permission_audit_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_permission_audit(
+            Some("file_delete".to_string()),
+            None,
+            None,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_err(), "PermissionAudit should require login");
+    }
+
+    #[tokio::test]
+    async fn test_permission_audit_requires_admin() {
+        let mut test_ctx = create_test_context().await;
+        let user_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[db::Permission::UserEdit],
+            false,
+        )
+        .await;
+
+        let result = handle_permission_audit(
+            Some("file_delete".to_string()),
+            None,
+            Some(user_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::PermissionAuditResponse { success, error, .. } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_permission_denied(DEFAULT_TEST_LOCALE)));
+            }
+            _ => panic!("Expected PermissionAuditResponse"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_permission_audit_unknown_permission() {
+        let mut test_ctx = create_test_context().await;
+        let admin_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        let result = handle_permission_audit(
+            Some("fly".to_string()),
+            None,
+            Some(admin_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::PermissionAuditResponse { success, error, .. } => {
+                assert!(!success);
+                assert_eq!(
+                    error,
+                    Some(err_unknown_permission(DEFAULT_TEST_LOCALE, "fly"))
+                );
+            }
+            _ => panic!("Expected PermissionAuditResponse"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_permission_audit_lists_holders() {
+        let mut test_ctx = create_test_context().await;
+        let admin_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        let mut delete = db::Permissions::new();
+        delete.add(db::Permission::FileDelete);
+        test_ctx
+            .db
+            .users
+            .create_user("bob", "hash", false, false, false, &delete)
+            .await
+            .unwrap();
+        // Shared account holding a permission it can't use (older database)
+        test_ctx
+            .db
+            .users
+            .create_user("lobby", "hash", false, true, true, &delete)
+            .await
+            .unwrap();
+        test_ctx
+            .db
+            .users
+            .create_user("carol", "hash", false, false, true, &db::Permissions::new())
+            .await
+            .unwrap();
+
+        let result = handle_permission_audit(
+            Some("file_delete".to_string()),
+            None,
+            Some(admin_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::PermissionAuditResponse {
+                success, holders, ..
+            } => {
+                assert!(success);
+                let holders = holders.expect("holders should be present");
+                assert_eq!(
+                    holders,
+                    vec![
+                        PermissionHolder {
+                            username: "admin".to_string(),
+                            source: PermissionSource::Admin,
+                            is_shared: false,
+                            enabled: true,
+                        },
+                        PermissionHolder {
+                            username: "bob".to_string(),
+                            source: PermissionSource::Granted,
+                            is_shared: false,
+                            enabled: false,
+                        },
+                    ]
+                );
+            }
+            _ => panic!("Expected PermissionAuditResponse"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_permission_audit_effective_shared_account() {
+        let mut test_ctx = create_test_context().await;
+        let admin_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        let mut perms = db::Permissions::new();
+        perms.add(db::Permission::ChatSend);
+        perms.add(db::Permission::FileDelete);
+        test_ctx
+            .db
+            .users
+            .create_user("lobby", "hash", false, true, true, &perms)
+            .await
+            .unwrap();
+
+        let result = handle_permission_audit(
+            None,
+            Some("LOBBY".to_string()),
+            Some(admin_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::PermissionAuditResponse {
+                success, effective, ..
+            } => {
+                assert!(success);
+                let effective = effective.expect("effective permissions should be present");
+                assert_eq!(effective.username, "lobby");
+                assert!(effective.is_shared);
+                assert_eq!(
+                    effective.permissions,
+                    vec![EffectivePermission {
+                        permission: "chat_send".to_string(),
+                        source: PermissionSource::Granted,
+                    }]
+                );
+                assert_eq!(effective.restricted, vec!["file_delete".to_string()]);
+            }
+            _ => panic!("Expected PermissionAuditResponse"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_permission_audit_effective_admin() {
+        let mut test_ctx = create_test_context().await;
+        let admin_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        let result = handle_permission_audit(
+            None,
+            Some("admin".to_string()),
+            Some(admin_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::PermissionAuditResponse {
+                success, effective, ..
+            } => {
+                assert!(success);
+                let effective = effective.expect("effective permissions should be present");
+                assert_eq!(effective.permissions.len(), ALL_PERMISSIONS.len());
+                assert!(
+                    effective
+                        .permissions
+                        .iter()
+                        .all(|p| p.source == PermissionSource::Admin)
+                );
+            }
+            _ => panic!("Expected PermissionAuditResponse"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_permission_audit_requires_exactly_one_target() {
+        let mut test_ctx = create_test_context().await;
+        let admin_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        let result = handle_permission_audit(
+            Some("file_delete".to_string()),
+            Some("admin".to_string()),
+            Some(admin_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::PermissionAuditResponse { success, error, .. } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_invalid_message_format(DEFAULT_TEST_LOCALE)));
+            }
+            _ => panic!("Expected PermissionAuditResponse"),
+        }
+    }
+}
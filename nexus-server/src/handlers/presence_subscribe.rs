@@ -0,0 +1,172 @@
+//! Handler for PresenceSubscribe command
+//!
+//! Lets a session watch specific accounts (a friends list). The response
+//! carries their current presence, and `PresenceUpdate` messages follow as
+//! they come online, go offline, or change their away status. Unlike the
+//! user list, this needs no permission: it only reveals accounts the client
+//! already knows the username of.
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+use nexus_common::validators::{self, PresenceSubscribeError, UsernameError};
+
+use super::{
+    HandlerContext, err_authentication, err_not_logged_in, err_presence_too_many,
+    err_username_empty, err_username_invalid, err_username_too_long,
+};
+
+/// Handle PresenceSubscribe command
+///
+/// Replaces the session's watched usernames; an empty list stops watching.
+pub async fn handle_presence_subscribe<W>(
+    usernames: Vec<String>,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(session_id) = session_id else {
+        log_warn!(
+            "PresenceSubscribe request from {} without login",
+            ctx.peer_addr
+        );
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("PresenceSubscribe"))
+            .await;
+    };
+
+    if let Err(e) = validators::validate_presence_subscriptions(&usernames) {
+        let error = match e {
+            PresenceSubscribeError::TooMany => {
+                err_presence_too_many(ctx.locale, validators::MAX_PRESENCE_SUBSCRIPTIONS)
+            }
+            PresenceSubscribeError::InvalidUsername(UsernameError::Empty) => {
+                err_username_empty(ctx.locale)
+            }
+            PresenceSubscribeError::InvalidUsername(UsernameError::TooLong) => {
+                err_username_too_long(ctx.locale, validators::MAX_USERNAME_LENGTH)
+            }
+            PresenceSubscribeError::InvalidUsername(UsernameError::InvalidCharacters) => {
+                err_username_invalid(ctx.locale)
+            }
+        };
+        return ctx
+            .send_message(&ServerMessage::PresenceSubscribeResponse {
+                success: false,
+                error: Some(error),
+                users: None,
+            })
+            .await;
+    }
+
+    if !ctx
+        .user_manager
+        .set_presence_subscriptions(session_id, &usernames)
+        .await
+    {
+        return ctx
+            .send_error_and_disconnect(&err_authentication(ctx.locale), Some("PresenceSubscribe"))
+            .await;
+    }
+
+    let mut users = Vec::with_capacity(usernames.len());
+    for username in &usernames {
+        users.push(ctx.user_manager.get_presence(username).await);
+    }
+
+    ctx.send_message(&ServerMessage::PresenceSubscribeResponse {
+        success: true,
+        error: None,
+        users: Some(users),
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::testing::{
+        DEFAULT_TEST_LOCALE, create_test_context, login_user, read_server_message,
+    };
+
+    #[tokio::test]
+    async fn test_presence_subscribe_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_presence_subscribe(
+            vec!["alice".to_string()],
+            None,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_err(), "Should disconnect when not logged in");
+    }
+
+    #[tokio::test]
+    async fn test_presence_subscribe_reports_current_presence() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "bob", "password", &[], false).await;
+        login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        handle_presence_subscribe(
+            vec!["ALICE".to_string(), "carol".to_string()],
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::PresenceSubscribeResponse { success, users, .. } => {
+                assert!(success);
+                let users = users.expect("users on success");
+                assert_eq!(users.len(), 2);
+                assert_eq!(users[0].username, "alice");
+                assert!(users[0].online);
+                assert_eq!(users[1].username, "carol");
+                assert!(!users[1].online);
+            }
+            response => panic!("Expected PresenceSubscribeResponse, got {:?}", response),
+        }
+
+        let session = test_ctx
+            .user_manager
+            .get_user_by_session_id(session_id)
+            .await
+            .unwrap();
+        assert!(session.presence_subscriptions.contains("alice"));
+        assert!(session.presence_subscriptions.contains("carol"));
+    }
+
+    #[tokio::test]
+    async fn test_presence_subscribe_too_many() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "bob", "password", &[], false).await;
+
+        let usernames = (0..=validators::MAX_PRESENCE_SUBSCRIPTIONS)
+            .map(|i| format!("user{i}"))
+            .collect();
+        handle_presence_subscribe(usernames, Some(session_id), &mut test_ctx.handler_context())
+            .await
+            .unwrap();
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::PresenceSubscribeResponse { success, error, .. } => {
+                assert!(!success);
+                assert_eq!(
+                    error,
+                    Some(err_presence_too_many(
+                        DEFAULT_TEST_LOCALE,
+                        validators::MAX_PRESENCE_SUBSCRIPTIONS
+                    ))
+                );
+            }
+            response => panic!("Expected PresenceSubscribeResponse, got {:?}", response),
+        }
+    }
+}
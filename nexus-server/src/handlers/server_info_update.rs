@@ -27,6 +27,9 @@ pub struct ServerInfoUpdateRequest {
     pub description: Option<String>,
     pub max_connections_per_ip: Option<u32>,
     pub max_transfers_per_ip: Option<u32>,
+    pub max_active_transfers: Option<u32>,
+    pub transfer_small_files_first: Option<bool>,
+    pub trusted_transfer_weight: Option<u32>,
     pub image: Option<String>,
     pub file_reindex_interval: Option<u32>,
     pub persistent_channels: Option<String>,
@@ -47,6 +50,9 @@ where
         description,
         max_connections_per_ip,
         max_transfers_per_ip,
+        max_active_transfers,
+        transfer_small_files_first,
+        trusted_transfer_weight,
         image,
         file_reindex_interval,
         persistent_channels,
@@ -56,7 +62,7 @@ where
 
     // Verify authentication first (before revealing validation errors to unauthenticated users)
     let Some(id) = session_id else {
-        eprintln!("ServerInfoUpdate from {} without login", ctx.peer_addr);
+        log_warn!("ServerInfoUpdate from {} without login", ctx.peer_addr);
         return ctx
             .send_error(&err_not_logged_in(ctx.locale), Some("ServerInfoUpdate"))
             .await;
@@ -74,9 +80,10 @@ where
 
     // Admin-only - check if user is admin (before validation to not reveal validation rules)
     if !user.is_admin {
-        eprintln!(
+        log_warn!(
             "ServerInfoUpdate from {} (user: {}) without admin",
-            ctx.peer_addr, user.username
+            ctx.peer_addr,
+            user.username
         );
         return ctx
             .send_error(&err_admin_required(ctx.locale), Some("ServerInfoUpdate"))
@@ -88,6 +95,9 @@ where
         && description.is_none()
         && max_connections_per_ip.is_none()
         && max_transfers_per_ip.is_none()
+        && max_active_transfers.is_none()
+        && transfer_small_files_first.is_none()
+        && trusted_transfer_weight.is_none()
         && image.is_none()
         && file_reindex_interval.is_none()
         && persistent_channels.is_none()
@@ -135,7 +145,8 @@ where
         return ctx.send_error(&error_msg, Some("ServerInfoUpdate")).await;
     }
 
-    // Note: max_connections_per_ip and max_transfers_per_ip allow 0 (meaning unlimited)
+    // Note: max_connections_per_ip, max_transfers_per_ip and max_active_transfers allow 0
+    // (meaning unlimited)
     // No validation needed beyond Option<u32> type checking
 
     // Validate image if provided (empty string is allowed to clear image)
@@ -179,16 +190,21 @@ where
     if let Some(ref n) = name
         && let Err(e) = ctx.db.config.set_server_name(n).await
     {
-        eprintln!("Database error setting server name: {}", e);
+        log_error!("Database error setting server name: {}", e);
         return ctx
             .send_error(&err_database(ctx.locale), Some("ServerInfoUpdate"))
             .await;
     }
 
+    // Keep the LAN advertisement's name current
+    if let Some(ref n) = name {
+        ctx.mdns.rename(n);
+    }
+
     if let Some(ref d) = description
         && let Err(e) = ctx.db.config.set_server_description(d).await
     {
-        eprintln!("Database error setting server description: {}", e);
+        log_error!("Database error setting server description: {}", e);
         return ctx
             .send_error(&err_database(ctx.locale), Some("ServerInfoUpdate"))
             .await;
@@ -196,7 +212,7 @@ where
 
     if let Some(max_conn) = max_connections_per_ip {
         if let Err(e) = ctx.db.config.set_max_connections_per_ip(max_conn).await {
-            eprintln!("Database error setting max_connections_per_ip: {}", e);
+            log_error!("Database error setting max_connections_per_ip: {}", e);
             return ctx
                 .send_error(&err_database(ctx.locale), Some("ServerInfoUpdate"))
                 .await;
@@ -208,7 +224,7 @@ where
 
     if let Some(max_xfer) = max_transfers_per_ip {
         if let Err(e) = ctx.db.config.set_max_transfers_per_ip(max_xfer).await {
-            eprintln!("Database error setting max_transfers_per_ip: {}", e);
+            log_error!("Database error setting max_transfers_per_ip: {}", e);
             return ctx
                 .send_error(&err_database(ctx.locale), Some("ServerInfoUpdate"))
                 .await;
@@ -218,10 +234,48 @@ where
             .set_max_transfers_per_ip(max_xfer as usize);
     }
 
+    if let Some(max_active) = max_active_transfers {
+        if let Err(e) = ctx.db.config.set_max_active_transfers(max_active).await {
+            log_error!("Database error setting max_active_transfers: {}", e);
+            return ctx
+                .send_error(&err_database(ctx.locale), Some("ServerInfoUpdate"))
+                .await;
+        }
+        // Update the transfer queue limit dynamically (may start queued transfers)
+        ctx.transfer_registry
+            .set_max_active_transfers(max_active as usize);
+    }
+
+    if let Some(small_first) = transfer_small_files_first {
+        if let Err(e) = ctx
+            .db
+            .config
+            .set_transfer_small_files_first(small_first)
+            .await
+        {
+            log_error!("Database error setting transfer_small_files_first: {}", e);
+            return ctx
+                .send_error(&err_database(ctx.locale), Some("ServerInfoUpdate"))
+                .await;
+        }
+        // Update the transfer queue ordering dynamically
+        ctx.transfer_registry.set_small_files_first(small_first);
+    }
+
+    if let Some(weight) = trusted_transfer_weight
+        && let Err(e) = ctx.db.config.set_trusted_transfer_weight(weight).await
+    {
+        log_error!("Database error setting trusted_transfer_weight: {}", e);
+        return ctx
+            .send_error(&err_database(ctx.locale), Some("ServerInfoUpdate"))
+            .await;
+    }
+    // Note: The weight is read when each transfer registers, so no runtime update needed
+
     if let Some(ref img) = image
         && let Err(e) = ctx.db.config.set_server_image(img).await
     {
-        eprintln!("Database error setting server image: {}", e);
+        log_error!("Database error setting server image: {}", e);
         return ctx
             .send_error(&err_database(ctx.locale), Some("ServerInfoUpdate"))
             .await;
@@ -230,7 +284,7 @@ where
     if let Some(interval) = file_reindex_interval
         && let Err(e) = ctx.db.config.set_file_reindex_interval(interval).await
     {
-        eprintln!("Database error setting file_reindex_interval: {}", e);
+        log_error!("Database error setting file_reindex_interval: {}", e);
         return ctx
             .send_error(&err_database(ctx.locale), Some("ServerInfoUpdate"))
             .await;
@@ -241,7 +295,7 @@ where
     if let Some(ref channels_str) = persistent_channels {
         // Save to config
         if let Err(e) = ctx.db.config.set_persistent_channels(channels_str).await {
-            eprintln!("Database error setting persistent_channels: {}", e);
+            log_error!("Database error setting persistent_channels: {}", e);
             return ctx
                 .send_error(&err_database(ctx.locale), Some("ServerInfoUpdate"))
                 .await;
@@ -272,10 +326,15 @@ where
                         topic: String::new(),
                         topic_set_by: String::new(),
                         secret: false,
+                        voice_limit: 0,
+                        topic_locked: false,
+                        entry_message: String::new(),
+                        rules: String::new(),
+                        language: String::new(),
                     })
                     .await
             {
-                eprintln!("Failed to create channel settings for {}: {}", name, e);
+                log_error!("Failed to create channel settings for {}: {}", name, e);
             }
         }
 
@@ -291,9 +350,10 @@ where
                     .delete_channel_settings(&settings.name)
                     .await
             {
-                eprintln!(
+                log_error!(
                     "Failed to delete channel settings for {}: {}",
-                    settings.name, e
+                    settings.name,
+                    e
                 );
             }
         }
@@ -309,12 +369,20 @@ where
                     } else {
                         (Some(settings.topic), Some(settings.topic_set_by))
                     };
-                    channels_to_init.push(crate::channels::Channel::with_settings(
-                        name.clone(),
-                        topic,
-                        topic_set_by,
-                        settings.secret,
-                    ));
+                    channels_to_init.push(crate::channels::Channel {
+                        entry_message: (!settings.entry_message.is_empty())
+                            .then_some(settings.entry_message),
+                        rules: (!settings.rules.is_empty()).then_some(settings.rules),
+                        language: (!settings.language.is_empty()).then_some(settings.language),
+                        ..crate::channels::Channel::with_settings(
+                            name.clone(),
+                            topic,
+                            topic_set_by,
+                            settings.secret,
+                            settings.voice_limit,
+                            settings.topic_locked,
+                        )
+                    });
                 }
                 _ => {
                     channels_to_init.push(crate::channels::Channel::new(name.clone()));
@@ -332,7 +400,7 @@ where
     if let Some(ref channels_str) = auto_join_channels
         && let Err(e) = ctx.db.config.set_auto_join_channels(channels_str).await
     {
-        eprintln!("Database error setting auto_join_channels: {}", e);
+        log_error!("Database error setting auto_join_channels: {}", e);
         return ctx
             .send_error(&err_database(ctx.locale), Some("ServerInfoUpdate"))
             .await;
@@ -343,6 +411,9 @@ where
     let current_description = ctx.db.config.get_server_description().await;
     let current_max_connections = ctx.db.config.get_max_connections_per_ip().await as u32;
     let current_max_transfers = ctx.db.config.get_max_transfers_per_ip().await as u32;
+    let current_max_active_transfers = ctx.db.config.get_max_active_transfers().await as u32;
+    let current_transfer_small_files_first = ctx.db.config.get_transfer_small_files_first().await;
+    let current_trusted_transfer_weight = ctx.db.config.get_trusted_transfer_weight().await;
     let current_image = ctx.db.config.get_server_image().await;
     let current_file_reindex_interval = ctx.db.config.get_file_reindex_interval().await;
     let current_persistent_channels = ctx.db.config.get_persistent_channels().await;
@@ -357,12 +428,17 @@ where
             version: server_version,
             max_connections_per_ip: current_max_connections,
             max_transfers_per_ip: current_max_transfers,
+            max_active_transfers: current_max_active_transfers,
+            transfer_small_files_first: current_transfer_small_files_first,
+            trusted_transfer_weight: current_trusted_transfer_weight,
             image: current_image,
             transfer_port: ctx.transfer_port,
             transfer_websocket_port: ctx.transfer_websocket_port,
             file_reindex_interval: current_file_reindex_interval,
             persistent_channels: current_persistent_channels,
             auto_join_channels: current_auto_join_channels,
+            news_image_limits: *ctx.news_image_limits,
+            nat: ctx.nat_status.get(),
         })
         .await;
 
@@ -390,6 +466,9 @@ mod tests {
             description: None,
             max_connections_per_ip: None,
             max_transfers_per_ip: None,
+            max_active_transfers: None,
+            transfer_small_files_first: None,
+            trusted_transfer_weight: None,
             image: None,
             file_reindex_interval: None,
             persistent_channels: None,
@@ -402,7 +481,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert_eq!(message, err_not_logged_in(DEFAULT_TEST_LOCALE));
                 assert_eq!(command, Some("ServerInfoUpdate".to_string()));
             }
@@ -422,6 +503,9 @@ mod tests {
             description: None,
             max_connections_per_ip: None,
             max_transfers_per_ip: None,
+            max_active_transfers: None,
+            transfer_small_files_first: None,
+            trusted_transfer_weight: None,
             image: None,
             file_reindex_interval: None,
             persistent_channels: None,
@@ -434,7 +518,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert_eq!(message, err_admin_required(DEFAULT_TEST_LOCALE));
                 assert_eq!(command, Some("ServerInfoUpdate".to_string()));
             }
@@ -454,6 +540,9 @@ mod tests {
             description: None,
             max_connections_per_ip: None,
             max_transfers_per_ip: None,
+            max_active_transfers: None,
+            transfer_small_files_first: None,
+            trusted_transfer_weight: None,
             image: None,
             file_reindex_interval: None,
             persistent_channels: None,
@@ -466,7 +555,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert_eq!(message, err_no_fields_to_update(DEFAULT_TEST_LOCALE));
                 assert_eq!(command, Some("ServerInfoUpdate".to_string()));
             }
@@ -486,6 +577,9 @@ mod tests {
             description: None,
             max_connections_per_ip: None,
             max_transfers_per_ip: None,
+            max_active_transfers: None,
+            transfer_small_files_first: None,
+            trusted_transfer_weight: None,
             image: None,
             file_reindex_interval: None,
             persistent_channels: None,
@@ -498,7 +592,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert_eq!(message, err_server_name_empty(DEFAULT_TEST_LOCALE));
                 assert_eq!(command, Some("ServerInfoUpdate".to_string()));
             }
@@ -519,6 +615,9 @@ mod tests {
             description: None,
             max_connections_per_ip: None,
             max_transfers_per_ip: None,
+            max_active_transfers: None,
+            transfer_small_files_first: None,
+            trusted_transfer_weight: None,
             image: None,
             file_reindex_interval: None,
             persistent_channels: None,
@@ -531,7 +630,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert!(message.contains(&validators::MAX_SERVER_NAME_LENGTH.to_string()));
                 assert_eq!(command, Some("ServerInfoUpdate".to_string()));
             }
@@ -552,6 +653,9 @@ mod tests {
             description: Some(long_desc),
             max_connections_per_ip: None,
             max_transfers_per_ip: None,
+            max_active_transfers: None,
+            transfer_small_files_first: None,
+            trusted_transfer_weight: None,
             image: None,
             file_reindex_interval: None,
             persistent_channels: None,
@@ -564,7 +668,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert!(message.contains(&validators::MAX_SERVER_DESCRIPTION_LENGTH.to_string()));
                 assert_eq!(command, Some("ServerInfoUpdate".to_string()));
             }
@@ -585,6 +691,9 @@ mod tests {
             description: None,
             max_connections_per_ip: Some(0),
             max_transfers_per_ip: None,
+            max_active_transfers: None,
+            transfer_small_files_first: None,
+            trusted_transfer_weight: None,
             image: None,
             file_reindex_interval: None,
             persistent_channels: None,
@@ -621,6 +730,9 @@ mod tests {
             description: None,
             max_connections_per_ip: None,
             max_transfers_per_ip: None,
+            max_active_transfers: None,
+            transfer_small_files_first: None,
+            trusted_transfer_weight: None,
             image: None,
             file_reindex_interval: None,
             persistent_channels: None,
@@ -657,6 +769,9 @@ mod tests {
             description: Some("Welcome to my server!".to_string()),
             max_connections_per_ip: None,
             max_transfers_per_ip: None,
+            max_active_transfers: None,
+            transfer_small_files_first: None,
+            trusted_transfer_weight: None,
             image: None,
             file_reindex_interval: None,
             persistent_channels: None,
@@ -693,6 +808,9 @@ mod tests {
             description: None,
             max_connections_per_ip: Some(10),
             max_transfers_per_ip: None,
+            max_active_transfers: None,
+            transfer_small_files_first: None,
+            trusted_transfer_weight: None,
             image: None,
             file_reindex_interval: None,
             persistent_channels: None,
@@ -717,6 +835,46 @@ mod tests {
         assert_eq!(saved_max, 10);
     }
 
+    #[tokio::test]
+    async fn test_server_info_update_transfer_queue_success() {
+        let mut test_ctx = create_test_context().await;
+
+        // Login as admin
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        let request = ServerInfoUpdateRequest {
+            name: None,
+            description: None,
+            max_connections_per_ip: None,
+            max_transfers_per_ip: None,
+            max_active_transfers: Some(4),
+            transfer_small_files_first: Some(true),
+            trusted_transfer_weight: Some(3),
+            image: None,
+            file_reindex_interval: None,
+            persistent_channels: None,
+            auto_join_channels: None,
+            session_id: Some(session_id),
+        };
+        let result = handle_server_info_update(request, &mut test_ctx.handler_context()).await;
+
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::ServerInfoUpdateResponse { success, error } => {
+                assert!(success);
+                assert!(error.is_none());
+            }
+            _ => panic!("Expected ServerInfoUpdateResponse, got {:?}", response),
+        }
+
+        // Verify queue settings were saved
+        assert_eq!(test_ctx.db.config.get_max_active_transfers().await, 4);
+        assert!(test_ctx.db.config.get_transfer_small_files_first().await);
+        assert_eq!(test_ctx.db.config.get_trusted_transfer_weight().await, 3);
+    }
+
     #[tokio::test]
     async fn test_server_info_update_all_fields_success() {
         let mut test_ctx = create_test_context().await;
@@ -729,6 +887,9 @@ mod tests {
             description: Some("All fields updated".to_string()),
             max_connections_per_ip: Some(15),
             max_transfers_per_ip: None,
+            max_active_transfers: None,
+            transfer_small_files_first: None,
+            trusted_transfer_weight: None,
             image: None,
             file_reindex_interval: None,
             persistent_channels: None,
@@ -780,6 +941,9 @@ mod tests {
             description: Some("".to_string()),
             max_connections_per_ip: None,
             max_transfers_per_ip: None,
+            max_active_transfers: None,
+            transfer_small_files_first: None,
+            trusted_transfer_weight: None,
             image: None,
             file_reindex_interval: None,
             persistent_channels: None,
@@ -823,6 +987,9 @@ mod tests {
             description: None,
             max_connections_per_ip: None,
             max_transfers_per_ip: None,
+            max_active_transfers: None,
+            transfer_small_files_first: None,
+            trusted_transfer_weight: None,
             image: Some(test_image.to_string()),
             file_reindex_interval: None,
             persistent_channels: None,
@@ -868,6 +1035,9 @@ mod tests {
             description: None,
             max_connections_per_ip: None,
             max_transfers_per_ip: None,
+            max_active_transfers: None,
+            transfer_small_files_first: None,
+            trusted_transfer_weight: None,
             image: Some("".to_string()),
             file_reindex_interval: None,
             persistent_channels: None,
@@ -909,6 +1079,9 @@ mod tests {
             description: None,
             max_connections_per_ip: None,
             max_transfers_per_ip: None,
+            max_active_transfers: None,
+            transfer_small_files_first: None,
+            trusted_transfer_weight: None,
             image: Some(large_image),
             file_reindex_interval: None,
             persistent_channels: None,
@@ -921,7 +1094,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert_eq!(message, err_server_image_too_large(DEFAULT_TEST_LOCALE));
                 assert_eq!(command, Some("ServerInfoUpdate".to_string()));
             }
@@ -944,6 +1119,9 @@ mod tests {
             description: None,
             max_connections_per_ip: None,
             max_transfers_per_ip: None,
+            max_active_transfers: None,
+            transfer_small_files_first: None,
+            trusted_transfer_weight: None,
             image: Some(invalid_image.to_string()),
             file_reindex_interval: None,
             persistent_channels: None,
@@ -956,7 +1134,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert_eq!(
                     message,
                     err_server_image_invalid_format(DEFAULT_TEST_LOCALE)
@@ -982,6 +1162,9 @@ mod tests {
             description: None,
             max_connections_per_ip: None,
             max_transfers_per_ip: None,
+            max_active_transfers: None,
+            transfer_small_files_first: None,
+            trusted_transfer_weight: None,
             image: Some(unsupported_image.to_string()),
             file_reindex_interval: None,
             persistent_channels: None,
@@ -994,7 +1177,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert_eq!(
                     message,
                     err_server_image_unsupported_type(DEFAULT_TEST_LOCALE)
@@ -1021,6 +1206,9 @@ mod tests {
             description: None,
             max_connections_per_ip: None,
             max_transfers_per_ip: None,
+            max_active_transfers: None,
+            transfer_small_files_first: None,
+            trusted_transfer_weight: None,
             image: None,
             file_reindex_interval: Some(10),
             persistent_channels: None,
@@ -1058,6 +1246,9 @@ mod tests {
             description: None,
             max_connections_per_ip: None,
             max_transfers_per_ip: None,
+            max_active_transfers: None,
+            transfer_small_files_first: None,
+            trusted_transfer_weight: None,
             image: None,
             file_reindex_interval: Some(0),
             persistent_channels: None,
@@ -1094,6 +1285,9 @@ mod tests {
             description: None,
             max_connections_per_ip: None,
             max_transfers_per_ip: None,
+            max_active_transfers: None,
+            transfer_small_files_first: None,
+            trusted_transfer_weight: None,
             image: None,
             file_reindex_interval: None,
             persistent_channels: Some("#general #support".to_string()),
@@ -1131,6 +1325,9 @@ mod tests {
             description: None,
             max_connections_per_ip: None,
             max_transfers_per_ip: None,
+            max_active_transfers: None,
+            transfer_small_files_first: None,
+            trusted_transfer_weight: None,
             image: None,
             file_reindex_interval: None,
             persistent_channels: Some("#valid general".to_string()),
@@ -1143,7 +1340,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert!(
                     message.contains("general"),
                     "Error should mention the invalid channel"
@@ -1166,6 +1365,9 @@ mod tests {
             description: None,
             max_connections_per_ip: None,
             max_transfers_per_ip: None,
+            max_active_transfers: None,
+            transfer_small_files_first: None,
+            trusted_transfer_weight: None,
             image: None,
             file_reindex_interval: None,
             persistent_channels: None,
@@ -1203,6 +1405,9 @@ mod tests {
             description: None,
             max_connections_per_ip: None,
             max_transfers_per_ip: None,
+            max_active_transfers: None,
+            transfer_small_files_first: None,
+            trusted_transfer_weight: None,
             image: None,
             file_reindex_interval: None,
             persistent_channels: None,
@@ -1215,7 +1420,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 assert!(
                     message.contains("#"),
                     "Error should mention the invalid channel"
@@ -1240,6 +1447,9 @@ mod tests {
             description: None,
             max_connections_per_ip: None,
             max_transfers_per_ip: None,
+            max_active_transfers: None,
+            transfer_small_files_first: None,
+            trusted_transfer_weight: None,
             image: None,
             file_reindex_interval: None,
             persistent_channels: Some("#my channel".to_string()),
@@ -1252,7 +1462,9 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx).await;
         match response {
-            ServerMessage::Error { message, command } => {
+            ServerMessage::Error {
+                message, command, ..
+            } => {
                 // "channel" will fail because it doesn't start with #
                 assert!(
                     message.contains("channel"),
@@ -1277,6 +1489,9 @@ mod tests {
             description: None,
             max_connections_per_ip: None,
             max_transfers_per_ip: None,
+            max_active_transfers: None,
+            transfer_small_files_first: None,
+            trusted_transfer_weight: None,
             image: None,
             file_reindex_interval: None,
             persistent_channels: Some("".to_string()),
@@ -0,0 +1,163 @@
+//! Handler for ServerStats command
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::{ServerMessage, ServerStatsInfo};
+
+use super::{
+    HandlerContext, err_authentication, err_database, err_not_logged_in, err_permission_denied,
+};
+use crate::constants::SERVER_STATS_AUDIT_ENTRIES;
+use crate::storage;
+
+/// Handle ServerStats command
+///
+/// Returns a snapshot of the server's health (admin only): connection and
+/// transfer counts, free space on the file area's disk, and the most recent
+/// audit log entries.
+pub async fn handle_server_stats<W>(
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first
+    let Some(session_id) = session_id else {
+        log_warn!("ServerStats request from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("ServerStats"))
+            .await;
+    };
+
+    // Get requesting user from session
+    let requesting_user = match ctx.user_manager.get_user_by_session_id(session_id).await {
+        Some(user) => user,
+        None => {
+            return ctx
+                .send_error_and_disconnect(&err_authentication(ctx.locale), Some("ServerStats"))
+                .await;
+        }
+    };
+
+    // Server health is admin only, like the statistics
+    if !requesting_user.is_admin {
+        log_warn!(
+            "ServerStats from {} (user: {}) without permission",
+            ctx.peer_addr,
+            requesting_user.username
+        );
+        return ctx
+            .send_message(&failure(err_permission_denied(ctx.locale)))
+            .await;
+    }
+
+    let recent_audit = match ctx.db.audit.recent(SERVER_STATS_AUDIT_ENTRIES).await {
+        Ok(entries) => entries,
+        Err(e) => {
+            log_error!("ServerStats database error: {}", e);
+            return ctx.send_message(&failure(err_database(ctx.locale))).await;
+        }
+    };
+
+    let (disk_free, disk_total) = ctx.file_root.map(storage::disk_space).unwrap_or_default();
+
+    let stats = ServerStatsInfo {
+        sessions: ctx.user_manager.session_count().await as u32,
+        connections: ctx.connection_tracker.total_connections() as u32,
+        transfer_connections: ctx.connection_tracker.total_transfers() as u32,
+        active_transfers: ctx.transfer_registry.active_count() as u32,
+        queued_transfers: ctx.transfer_registry.queued_count() as u32,
+        disk_free,
+        disk_total,
+        recent_audit,
+    };
+
+    let response = ServerMessage::ServerStatsResponse {
+        success: true,
+        error: None,
+        stats: Some(stats),
+    };
+    ctx.send_message(&response).await
+}
+
+/// Build a failed ServerStatsResponse
+fn failure(error: String) -> ServerMessage {
+    ServerMessage::ServerStatsResponse {
+        success: false,
+        error: Some(error),
+        stats: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::audit::AUDIT_ACTION_USER_ERASE;
+    use crate::handlers::testing::{create_test_context, login_user, read_server_message};
+
+    #[tokio::test]
+    async fn test_server_stats_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_server_stats(None, &mut test_ctx.handler_context()).await;
+
+        assert!(result.is_err(), "ServerStats should require login");
+    }
+
+    #[tokio::test]
+    async fn test_server_stats_requires_admin() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let result = handle_server_stats(Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok(), "Should send error response, not disconnect");
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::ServerStatsResponse {
+                success,
+                error,
+                stats,
+            } => {
+                assert!(!success);
+                assert!(error.is_some());
+                assert!(stats.is_none());
+            }
+            _ => panic!("Expected ServerStatsResponse, got: {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_server_stats_admin_succeeds() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+        login_user(&mut test_ctx, "alice", "password", &[], false).await;
+        test_ctx
+            .db
+            .audit
+            .record(AUDIT_ACTION_USER_ERASE, "bob", "admin", None)
+            .await
+            .unwrap();
+
+        let result = handle_server_stats(Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::ServerStatsResponse {
+                success,
+                error,
+                stats: Some(stats),
+            } => {
+                assert!(success, "Expected success, got error: {:?}", error);
+                assert_eq!(stats.sessions, 2);
+                assert_eq!(stats.recent_audit.len(), 1);
+                assert_eq!(stats.recent_audit[0].target, "bob");
+            }
+            _ => panic!("Expected ServerStatsResponse, got: {:?}", response),
+        }
+    }
+}
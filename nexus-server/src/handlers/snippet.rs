@@ -0,0 +1,356 @@
+//! Handlers for snippets (paste-bin style text shared by link)
+//!
+//! Sharing a snippet needs ChatSend or UserMessage: snippets are a way to
+//! post long text without flooding a conversation. Anyone logged in can
+//! read a snippet whose link they were given.
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+use nexus_common::validators::{self, MAX_SNIPPET_EXPIRY_SECS, MAX_SNIPPET_LENGTH, SnippetError};
+
+#[cfg(test)]
+use super::testing::DEFAULT_TEST_LOCALE;
+use super::{
+    HandlerContext, err_database, err_not_logged_in, err_permission_denied, err_snippet_empty,
+    err_snippet_expiry_invalid, err_snippet_invalid_characters, err_snippet_language_invalid,
+    err_snippet_limit, err_snippet_not_found, err_snippet_too_long,
+};
+use crate::constants::MAX_SNIPPETS_PER_USER;
+use crate::db::Permission;
+
+/// Seconds in a day, for the expiry error message
+const SECS_PER_DAY: u32 = 24 * 60 * 60;
+
+/// Handle SnippetCreate command
+pub async fn handle_snippet_create<W>(
+    content: String,
+    language: Option<String>,
+    expires_in: u32,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(session_id) = session_id else {
+        log_warn!("SnippetCreate request from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("SnippetCreate"))
+            .await;
+    };
+
+    let Some(user) = ctx.user_manager.get_user_by_session_id(session_id).await else {
+        // Session not found - likely a race condition, not a security event
+        return send_create_error(ctx, err_not_logged_in(ctx.locale)).await;
+    };
+
+    if !user.has_permission(Permission::ChatSend) && !user.has_permission(Permission::UserMessage) {
+        log_warn!(
+            "SnippetCreate from {} (user: {}) without permission",
+            ctx.peer_addr,
+            user.username
+        );
+        return send_create_error(ctx, err_permission_denied(ctx.locale)).await;
+    }
+
+    if let Err(e) = validators::validate_snippet(&content) {
+        let error_msg = match e {
+            SnippetError::Empty => err_snippet_empty(ctx.locale),
+            SnippetError::TooLong => err_snippet_too_long(ctx.locale, MAX_SNIPPET_LENGTH),
+            SnippetError::InvalidCharacters => err_snippet_invalid_characters(ctx.locale),
+        };
+        return send_create_error(ctx, error_msg).await;
+    }
+
+    // An empty language means no highlighting
+    let language = language
+        .map(|l| l.trim().to_lowercase())
+        .filter(|l| !l.is_empty());
+    if let Some(language) = &language
+        && validators::validate_snippet_language(language).is_err()
+    {
+        return send_create_error(ctx, err_snippet_language_invalid(ctx.locale, language)).await;
+    }
+
+    if validators::validate_snippet_expiry(expires_in).is_err() {
+        let error_msg =
+            err_snippet_expiry_invalid(ctx.locale, MAX_SNIPPET_EXPIRY_SECS / SECS_PER_DAY);
+        return send_create_error(ctx, error_msg).await;
+    }
+
+    let created = match ctx
+        .db
+        .snippets
+        .create(
+            user.db_user_id,
+            &user.nickname,
+            language.as_deref(),
+            &content,
+            expires_in,
+            MAX_SNIPPETS_PER_USER,
+        )
+        .await
+    {
+        Ok(created) => created,
+        Err(e) => {
+            log_error!("Database error creating snippet: {}", e);
+            return ctx
+                .send_error_and_disconnect(&err_database(ctx.locale), Some("SnippetCreate"))
+                .await;
+        }
+    };
+
+    let Some((id, expires_at)) = created else {
+        return send_create_error(ctx, err_snippet_limit(ctx.locale, MAX_SNIPPETS_PER_USER)).await;
+    };
+
+    let response = ServerMessage::SnippetCreateResponse {
+        success: true,
+        error: None,
+        id: Some(id),
+        expires_at: Some(expires_at),
+    };
+    ctx.send_message(&response).await
+}
+
+/// Handle SnippetGet command
+pub async fn handle_snippet_get<W>(
+    id: String,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    if session_id.is_none() {
+        log_warn!("SnippetGet request from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("SnippetGet"))
+            .await;
+    }
+
+    // Malformed ids can't exist; treat them like expired ones
+    if validators::validate_snippet_id(&id).is_err() {
+        return send_get_error(ctx, err_snippet_not_found(ctx.locale)).await;
+    }
+
+    let snippet = match ctx.db.snippets.get(&id).await {
+        Ok(Some(snippet)) => snippet,
+        Ok(None) => return send_get_error(ctx, err_snippet_not_found(ctx.locale)).await,
+        Err(e) => {
+            log_error!("Database error getting snippet: {}", e);
+            return ctx
+                .send_error_and_disconnect(&err_database(ctx.locale), Some("SnippetGet"))
+                .await;
+        }
+    };
+
+    let response = ServerMessage::SnippetGetResponse {
+        success: true,
+        error: None,
+        snippet: Some(snippet),
+    };
+    ctx.send_message(&response).await
+}
+
+/// Send a failed SnippetCreateResponse
+async fn send_create_error<W>(ctx: &mut HandlerContext<'_, W>, error: String) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let response = ServerMessage::SnippetCreateResponse {
+        success: false,
+        error: Some(error),
+        id: None,
+        expires_at: None,
+    };
+    ctx.send_message(&response).await
+}
+
+/// Send a failed SnippetGetResponse
+async fn send_get_error<W>(ctx: &mut HandlerContext<'_, W>, error: String) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let response = ServerMessage::SnippetGetResponse {
+        success: false,
+        error: Some(error),
+        snippet: None,
+    };
+    ctx.send_message(&response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db;
+    use crate::handlers::testing::{
+        TestContext, create_test_context, login_user, read_server_message,
+    };
+
+    /// Share a snippet and return the response fields
+    async fn create(
+        test_ctx: &mut TestContext,
+        session_id: u32,
+        content: &str,
+        language: Option<&str>,
+        expires_in: u32,
+    ) -> (bool, Option<String>, Option<String>) {
+        handle_snippet_create(
+            content.to_string(),
+            language.map(str::to_string),
+            expires_in,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        match read_server_message(test_ctx).await {
+            ServerMessage::SnippetCreateResponse {
+                success, error, id, ..
+            } => (success, error, id),
+            _ => panic!("Expected SnippetCreateResponse"),
+        }
+    }
+
+    /// Read a snippet and return the response
+    async fn get(test_ctx: &mut TestContext, session_id: u32, id: &str) -> ServerMessage {
+        handle_snippet_get(
+            id.to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        read_server_message(test_ctx).await
+    }
+
+    #[tokio::test]
+    async fn test_snippet_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_snippet_create(
+            "text".to_string(),
+            None,
+            3600,
+            None,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_err());
+
+        let mut test_ctx = create_test_context().await;
+        let result = handle_snippet_get(
+            "aZ09bY18cX".to_string(),
+            None,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_snippet_create_requires_permission() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let (success, error, _) = create(&mut test_ctx, session_id, "text", None, 3600).await;
+
+        assert!(!success);
+        assert_eq!(error, Some(err_permission_denied(DEFAULT_TEST_LOCALE)));
+    }
+
+    #[tokio::test]
+    async fn test_snippet_create_validates_input() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[db::Permission::UserMessage],
+            false,
+        )
+        .await;
+
+        let (success, error, _) = create(&mut test_ctx, session_id, " \n", None, 3600).await;
+        assert!(!success);
+        assert_eq!(error, Some(err_snippet_empty(DEFAULT_TEST_LOCALE)));
+
+        let (success, error, _) =
+            create(&mut test_ctx, session_id, "text", Some("rust lang"), 3600).await;
+        assert!(!success);
+        assert_eq!(
+            error,
+            Some(err_snippet_language_invalid(
+                DEFAULT_TEST_LOCALE,
+                "rust lang"
+            ))
+        );
+
+        let (success, error, _) = create(&mut test_ctx, session_id, "text", None, 0).await;
+        assert!(!success);
+        assert_eq!(
+            error,
+            Some(err_snippet_expiry_invalid(DEFAULT_TEST_LOCALE, 30))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_snippet_create_and_get() {
+        let mut test_ctx = create_test_context().await;
+        let alice = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[db::Permission::ChatSend],
+            false,
+        )
+        .await;
+
+        let (success, error, id) = create(
+            &mut test_ctx,
+            alice,
+            "fn main() {\n    println!(\"hi\");\n}\n",
+            Some(" Rust "),
+            3600,
+        )
+        .await;
+        assert!(success, "{error:?}");
+        let id = id.unwrap();
+
+        // Reading needs no permission
+        let bob = login_user(&mut test_ctx, "bob", "password", &[], false).await;
+        match get(&mut test_ctx, bob, &id).await {
+            ServerMessage::SnippetGetResponse {
+                success: true,
+                snippet: Some(snippet),
+                ..
+            } => {
+                assert_eq!(snippet.id, id);
+                assert_eq!(snippet.author, "alice");
+                assert_eq!(snippet.language.as_deref(), Some("rust"));
+                assert!(snippet.content.starts_with("fn main()"));
+            }
+            other => panic!("Expected SnippetGetResponse, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_snippet_get_not_found() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        for id in ["aZ09bY18cX", "../../etc"] {
+            match get(&mut test_ctx, session_id, id).await {
+                ServerMessage::SnippetGetResponse { success, error, .. } => {
+                    assert!(!success);
+                    assert_eq!(error, Some(err_snippet_not_found(DEFAULT_TEST_LOCALE)));
+                }
+                other => panic!("Expected SnippetGetResponse, got {other:?}"),
+            }
+        }
+    }
+}
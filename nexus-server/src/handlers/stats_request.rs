@@ -0,0 +1,147 @@
+//! Handler for StatsRequest command
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+
+use super::{
+    HandlerContext, err_authentication, err_database, err_not_logged_in, err_permission_denied,
+};
+
+/// Handle StatsRequest command
+///
+/// Returns the daily activity totals for the last `days` days (admin only).
+pub async fn handle_stats_request<W>(
+    days: u16,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first
+    let Some(session_id) = session_id else {
+        log_warn!("StatsRequest from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("StatsRequest"))
+            .await;
+    };
+
+    // Get requesting user from session
+    let requesting_user = match ctx.user_manager.get_user_by_session_id(session_id).await {
+        Some(user) => user,
+        None => {
+            return ctx
+                .send_error_and_disconnect(&err_authentication(ctx.locale), Some("StatsRequest"))
+                .await;
+        }
+    };
+
+    // Statistics are admin only
+    if !requesting_user.is_admin {
+        log_warn!(
+            "StatsRequest from {} (user: {}) without permission",
+            ctx.peer_addr,
+            requesting_user.username
+        );
+        return ctx
+            .send_message(&failure(err_permission_denied(ctx.locale)))
+            .await;
+    }
+
+    match ctx.stats.report(&ctx.db.stats, days).await {
+        Ok(days) => {
+            let response = ServerMessage::StatsResponse {
+                success: true,
+                error: None,
+                days: Some(days),
+            };
+            ctx.send_message(&response).await
+        }
+        Err(e) => {
+            log_error!("StatsRequest database error: {}", e);
+            ctx.send_message(&failure(err_database(ctx.locale))).await
+        }
+    }
+}
+
+/// Build a failed StatsResponse
+fn failure(error: String) -> ServerMessage {
+    ServerMessage::StatsResponse {
+        success: false,
+        error: Some(error),
+        days: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::testing::{create_test_context, login_user, read_server_message};
+
+    #[tokio::test]
+    async fn test_stats_request_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_stats_request(7, None, &mut test_ctx.handler_context()).await;
+
+        assert!(result.is_err(), "StatsRequest should require login");
+    }
+
+    #[tokio::test]
+    async fn test_stats_request_requires_admin() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let result =
+            handle_stats_request(7, Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok(), "Should send error response, not disconnect");
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::StatsResponse {
+                success,
+                error,
+                days,
+            } => {
+                assert!(!success);
+                assert!(error.is_some());
+                assert!(days.is_none());
+            }
+            _ => panic!("Expected StatsResponse, got: {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stats_request_as_admin() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+        // The login handler notes the session count after adding a session
+        test_ctx
+            .stats
+            .record_sessions(test_ctx.user_manager.session_count().await);
+
+        let result =
+            handle_stats_request(30, Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::StatsResponse {
+                success,
+                error,
+                days,
+            } => {
+                assert!(success);
+                assert!(error.is_none());
+                let days = days.unwrap();
+                assert_eq!(days.len(), 30);
+                // The admin's own session counts toward today's peak
+                assert_eq!(days[29].peak_sessions, 1);
+            }
+            _ => panic!("Expected StatsResponse, got: {:?}", response),
+        }
+    }
+}
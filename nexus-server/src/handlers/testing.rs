@@ -3,6 +3,9 @@
 /// Default locale for tests
 pub const DEFAULT_TEST_LOCALE: &str = "en";
 
+/// 1x1 transparent PNG, for tests that need an image the server can decode
+pub const TEST_PNG_DATA_URI: &str = "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAAC0lEQVR4nGNgAAIAAAUAAXpeqz8AAAAASUVORK5CYII=";
+
 use std::collections::HashMap;
 use std::fs;
 use std::net::SocketAddr;
@@ -13,19 +16,34 @@ use tempfile::TempDir;
 
 use tokio::io::BufReader;
 use tokio::net::{TcpListener, TcpStream};
-use tokio::sync::mpsc;
 
 use nexus_common::framing::{FrameReader, FrameWriter, MessageId};
 use nexus_common::io::read_server_message as io_read_server_message;
-use nexus_common::protocol::ServerMessage;
+use nexus_common::protocol::{NewsImageLimits, ServerMessage};
 
-use super::HandlerContext;
+use super::{CancelToken, HandlerContext};
+use crate::broadcasts::BroadcastManager;
 use crate::channels::ChannelManager;
 use crate::connection_tracker::ConnectionTracker;
 use crate::db::Database;
+use crate::doors::Doors;
 use crate::files::FileIndex;
+use crate::groups::GroupManager;
+use crate::guests::GuestConfig;
+use crate::idempotency::IdempotencyKeys;
 use crate::ip_rule_cache::IpRuleCache;
+use crate::ldap::Ldap;
+use crate::logging::ServerLog;
+use crate::login_tokens::LoginTokens;
+use crate::mdns::Mdns;
+use crate::news_images;
+use crate::oidc::Oidc;
+use crate::outbound::{self, OutboundReceiver, OutboundSender};
+use crate::ranks::Ranks;
+use crate::reachability::CheckConfig;
+use crate::stats::StatsCollector;
 use crate::transfers::TransferRegistry;
+use crate::upnp::NatStatus;
 use crate::users::UserManager;
 use crate::users::user::NewSessionParams;
 use crate::voice::VoiceRegistry;
@@ -90,9 +108,9 @@ pub struct TestContext {
     pub frame_writer: FrameWriter<TestWriteHalf>,
     pub user_manager: UserManager,
     pub db: Database,
-    pub tx: mpsc::UnboundedSender<(ServerMessage, Option<MessageId>)>,
+    pub tx: OutboundSender,
     pub peer_addr: SocketAddr,
-    pub rx: mpsc::UnboundedReceiver<(ServerMessage, Option<MessageId>)>,
+    pub rx: OutboundReceiver,
     pub message_id: MessageId,
     pub file_root: Option<&'static Path>,
     pub connection_tracker: Arc<ConnectionTracker>,
@@ -101,6 +119,21 @@ pub struct TestContext {
     pub channel_manager: ChannelManager,
     pub transfer_registry: Arc<TransferRegistry>,
     pub voice_registry: VoiceRegistry,
+    pub idempotency_keys: IdempotencyKeys,
+    pub group_manager: GroupManager,
+    pub login_tokens: LoginTokens,
+    pub broadcast_manager: BroadcastManager,
+    pub server_log: ServerLog,
+    pub stats: Arc<StatsCollector>,
+    pub oidc: Oidc,
+    pub doors: Doors,
+    pub ldap: Ldap,
+    pub guest_config: GuestConfig,
+    pub news_image_limits: NewsImageLimits,
+    pub ranks: Ranks,
+    pub check_config: Option<CheckConfig>,
+    pub nat_status: NatStatus,
+    pub mdns: Mdns,
     /// Keep temp dir alive for tests that use file areas
     #[allow(dead_code)]
     temp_dir: TempDir,
@@ -127,6 +160,22 @@ impl TestContext {
             channel_manager: &self.channel_manager,
             transfer_registry: self.transfer_registry.clone(),
             voice_registry: &self.voice_registry,
+            idempotency_keys: &self.idempotency_keys,
+            group_manager: &self.group_manager,
+            login_tokens: &self.login_tokens,
+            broadcast_manager: &self.broadcast_manager,
+            server_log: &self.server_log,
+            stats: &self.stats,
+            oidc: &self.oidc,
+            doors: &self.doors,
+            ldap: &self.ldap,
+            guest_config: &self.guest_config,
+            news_image_limits: &self.news_image_limits,
+            ranks: &self.ranks,
+            check_config: &self.check_config,
+            nat_status: &self.nat_status,
+            mdns: &self.mdns,
+            cancel: CancelToken::default(),
         }
     }
 }
@@ -171,7 +220,7 @@ pub async fn create_test_context() -> TestContext {
     let frame_reader = FrameReader::new(buf_reader);
 
     // Create message channel (keep receiver alive to prevent channel closure)
-    let (tx, rx) = mpsc::unbounded_channel();
+    let (tx, rx) = outbound::channel(Default::default());
 
     // Create a default message ID for tests (must be valid hex characters)
     let message_id = MessageId::from_bytes(b"000000000000").expect("valid hex test message ID");
@@ -195,6 +244,51 @@ pub async fn create_test_context() -> TestContext {
     // Create voice registry for tests
     let voice_registry = VoiceRegistry::new();
 
+    // Create idempotency key table for tests
+    let idempotency_keys = IdempotencyKeys::new();
+
+    // Create group manager for tests
+    let group_manager = GroupManager::new();
+
+    // Create login token table for tests
+    let login_tokens = LoginTokens::new();
+
+    // Create broadcast manager for tests
+    let broadcast_manager = BroadcastManager::new();
+
+    // Create server log for tests
+    let server_log = ServerLog::new();
+
+    // Create statistics collector for tests
+    let stats = Arc::new(StatsCollector::new());
+
+    // Create single sign-on handle for tests (disabled)
+    let oidc = Oidc::default();
+
+    // Create door list for tests (empty)
+    let doors = Doors::default();
+
+    // Create directory login handle for tests (disabled)
+    let ldap = Ldap::default();
+
+    // Create guest settings for tests (defaults)
+    let guest_config = GuestConfig::default();
+
+    // Create news image limits for tests (defaults)
+    let news_image_limits = news_images::default_limits();
+
+    // Create ranks for tests (none)
+    let ranks = Ranks::default();
+
+    // Create connectivity check settings for tests (none)
+    let check_config = None;
+
+    // Create port forwarding state for tests (empty)
+    let nat_status = NatStatus::default();
+
+    // Create an inactive LAN advertisement for tests
+    let mdns = Mdns::default();
+
     TestContext {
         frame_reader,
         frame_writer,
@@ -211,6 +305,21 @@ pub async fn create_test_context() -> TestContext {
         channel_manager,
         transfer_registry,
         voice_registry,
+        idempotency_keys,
+        group_manager,
+        login_tokens,
+        broadcast_manager,
+        server_log,
+        stats,
+        oidc,
+        doors,
+        ldap,
+        guest_config,
+        news_image_limits,
+        ranks,
+        check_config,
+        nat_status,
+        mdns,
         temp_dir,
     }
 }
@@ -278,6 +387,7 @@ pub async fn login_user_from_ip(
             nickname: username.to_string(), // Regular account: nickname == username
             is_away: false,
             status: None,
+            client: None,
         })
         .await
         .expect("Failed to add user to UserManager")
@@ -330,6 +440,7 @@ pub async fn login_user_with_features(
             nickname: username.to_string(), // Regular account: nickname == username
             is_away: false,
             status: None,
+            client: None,
         })
         .await
         .expect("Failed to add user to UserManager")
@@ -381,6 +492,7 @@ pub async fn login_shared_user(
             nickname: nickname.to_string(), // Shared account: custom nickname
             is_away: false,
             status: None,
+            client: None,
         })
         .await
         .expect("Failed to add shared user to UserManager")
@@ -547,3 +659,47 @@ pub fn setup_file_area_full(test_ctx: &mut TestContext) -> TempDir {
 
     temp_dir
 }
+
+// ========================================================================
+// Voice Clip Helpers
+// ========================================================================
+
+/// Build a minimal Ogg Vorbis stream lasting `duration_ms` at 48kHz
+///
+/// Only the page headers and identification header are real; the stream
+/// is just enough for the server's duration check, not for decoding.
+fn voice_clip_ogg(duration_ms: u64) -> Vec<u8> {
+    fn page(granule: i64, data: &[u8]) -> Vec<u8> {
+        let mut page = b"OggS".to_vec();
+        page.extend_from_slice(&[0, 0]); // version, header type
+        page.extend_from_slice(&granule.to_le_bytes());
+        page.extend_from_slice(&1u32.to_le_bytes()); // serial
+        page.extend_from_slice(&[0; 8]); // sequence, checksum
+        page.push(1);
+        page.push(data.len() as u8);
+        page.extend_from_slice(data);
+        page
+    }
+
+    let mut id_header = vec![0x01];
+    id_header.extend_from_slice(b"vorbis");
+    id_header.extend_from_slice(&0u32.to_le_bytes()); // version
+    id_header.push(1); // channels
+    id_header.extend_from_slice(&48000u32.to_le_bytes());
+    id_header.extend_from_slice(&[0; 14]);
+
+    let mut stream = page(0, &id_header);
+    stream.extend(page((duration_ms * 48) as i64, &[0; 64]));
+    stream
+}
+
+/// Build a voice clip data URI lasting `duration_ms` (see `voice_clip_ogg`)
+pub fn voice_clip_data_uri(duration_ms: u64) -> String {
+    use base64::Engine;
+    use base64::engine::general_purpose::STANDARD as BASE64;
+
+    format!(
+        "data:audio/ogg;base64,{}",
+        BASE64.encode(voice_clip_ogg(duration_ms))
+    )
+}
@@ -36,7 +36,7 @@ where
 {
     // Verify authentication first
     let Some(session_id) = session_id else {
-        eprintln!("TrustCreate request from {} without login", ctx.peer_addr);
+        log_warn!("TrustCreate request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("TrustCreate"))
             .await;
@@ -101,9 +101,10 @@ where
 
     // Check trust_create permission
     if !requesting_user.has_permission(Permission::TrustCreate) {
-        eprintln!(
+        log_warn!(
             "TrustCreate from {} (user: {}) without permission",
-            ctx.peer_addr, requesting_user.username
+            ctx.peer_addr,
+            requesting_user.username
         );
         let response = ServerMessage::TrustCreateResponse {
             success: false,
@@ -162,7 +163,7 @@ where
                 trusted_targets.push(target_str.clone());
             }
             Err(e) => {
-                eprintln!("TrustCreate database error for {}: {}", target_str, e);
+                log_error!("TrustCreate database error for {}: {}", target_str, e);
                 let response = ServerMessage::TrustCreateResponse {
                     success: false,
                     error: Some(super::err_database(ctx.locale)),
@@ -31,7 +31,7 @@ where
 {
     // Verify authentication first
     let Some(session_id) = session_id else {
-        eprintln!("TrustDelete request from {} without login", ctx.peer_addr);
+        log_warn!("TrustDelete request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("TrustDelete"))
             .await;
@@ -64,9 +64,10 @@ where
 
     // Check trust_delete permission
     if !requesting_user.has_permission(Permission::TrustDelete) {
-        eprintln!(
+        log_warn!(
             "TrustDelete from {} (user: {}) without permission",
-            ctx.peer_addr, requesting_user.username
+            ctx.peer_addr,
+            requesting_user.username
         );
         let response = ServerMessage::TrustDeleteResponse {
             success: false,
@@ -107,7 +108,7 @@ where
                 return ctx.send_message(&response).await;
             }
             Err(e) => {
-                eprintln!("TrustDelete database error for nickname {}: {}", target, e);
+                log_error!("TrustDelete database error for nickname {}: {}", target, e);
                 let response = ServerMessage::TrustDeleteResponse {
                     success: false,
                     error: Some(super::err_database(ctx.locale)),
@@ -143,7 +144,7 @@ where
                     all_deleted.extend(deleted);
                 }
                 Err(e) => {
-                    eprintln!("TrustDelete database error for CIDR {}: {}", target, e);
+                    log_error!("TrustDelete database error for CIDR {}: {}", target, e);
                     let response = ServerMessage::TrustDeleteResponse {
                         success: false,
                         error: Some(super::err_database(ctx.locale)),
@@ -217,7 +218,7 @@ where
                 return ctx.send_message(&response).await;
             }
             Err(e) => {
-                eprintln!("TrustDelete database error for IP {}: {}", target, e);
+                log_error!("TrustDelete database error for IP {}: {}", target, e);
                 let response = ServerMessage::TrustDeleteResponse {
                     success: false,
                     error: Some(super::err_database(ctx.locale)),
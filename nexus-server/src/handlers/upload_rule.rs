@@ -0,0 +1,399 @@
+//! Handlers for UploadRuleGet and UploadRuleSet commands
+//!
+//! Admins can limit what gets uploaded into a directory: the allowed file
+//! extensions, the largest single file and the largest upload. A rule covers
+//! the directory and everything below it, unless a subdirectory has its own.
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::{ServerMessage, UploadRule};
+use nexus_common::validators::{self, FilePathError, UploadExtensionsError};
+
+use super::{
+    HandlerContext, err_authentication, err_database, err_file_not_found, err_file_path_invalid,
+    err_file_path_too_long, err_not_logged_in, err_permission_denied,
+    err_upload_rule_invalid_extension, err_upload_rule_not_directory,
+    err_upload_rule_too_many_extensions,
+};
+use crate::db::Permission;
+use crate::files::{build_and_validate_candidate_path, resolve_path, resolve_user_area};
+use crate::stats::file_key;
+use crate::users::user::UserSession;
+
+/// Handle UploadRuleGet command
+///
+/// Returns the rule set on the directory itself (not one inherited from an
+/// ancestor), so admins edit exactly what is stored.
+pub async fn handle_upload_rule_get<W>(
+    path: String,
+    root: bool,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let failure = |error: String| ServerMessage::UploadRuleGetResponse {
+        success: false,
+        error: Some(error),
+        rule: None,
+    };
+
+    let Some(user) = rule_user("UploadRuleGet", session_id, ctx).await? else {
+        return Ok(());
+    };
+
+    // Upload rules are admin only
+    if !user.is_admin {
+        log_warn!(
+            "UploadRuleGet from {} (user: {}) without permission",
+            ctx.peer_addr,
+            user.username
+        );
+        return ctx
+            .send_message(&failure(err_permission_denied(ctx.locale)))
+            .await;
+    }
+
+    let key = match rule_target(&path, root, &user, ctx) {
+        Ok(key) => key,
+        Err(error) => return ctx.send_message(&failure(error)).await,
+    };
+
+    let response = match ctx.db.upload_rules.get(&key).await {
+        Ok(rule) => ServerMessage::UploadRuleGetResponse {
+            success: true,
+            error: None,
+            rule,
+        },
+        Err(e) => {
+            log_error!("UploadRuleGet database error: {}", e);
+            failure(err_database(ctx.locale))
+        }
+    };
+    ctx.send_message(&response).await
+}
+
+/// Handle UploadRuleSet command
+///
+/// Replaces the directory's rule, or removes it when the rule is missing or
+/// limits nothing. Extensions are stored lowercase without duplicates.
+pub async fn handle_upload_rule_set<W>(
+    path: String,
+    rule: Option<UploadRule>,
+    root: bool,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let failure = |error: String| ServerMessage::UploadRuleSetResponse {
+        success: false,
+        error: Some(error),
+    };
+
+    let Some(user) = rule_user("UploadRuleSet", session_id, ctx).await? else {
+        return Ok(());
+    };
+
+    // Upload rules are admin only
+    if !user.is_admin {
+        log_warn!(
+            "UploadRuleSet from {} (user: {}) without permission",
+            ctx.peer_addr,
+            user.username
+        );
+        return ctx
+            .send_message(&failure(err_permission_denied(ctx.locale)))
+            .await;
+    }
+
+    let rule = match rule {
+        Some(mut rule) => {
+            if let Err(e) = validators::validate_upload_extensions(&rule.extensions) {
+                let error = match e {
+                    UploadExtensionsError::TooMany => err_upload_rule_too_many_extensions(
+                        ctx.locale,
+                        validators::MAX_UPLOAD_EXTENSIONS,
+                    ),
+                    UploadExtensionsError::Invalid(extension) => {
+                        err_upload_rule_invalid_extension(ctx.locale, &extension)
+                    }
+                };
+                return ctx.send_message(&failure(error)).await;
+            }
+            rule.extensions = normalize_extensions(&rule.extensions);
+            Some(rule)
+        }
+        None => None,
+    };
+
+    let key = match rule_target(&path, root, &user, ctx) {
+        Ok(key) => key,
+        Err(error) => return ctx.send_message(&failure(error)).await,
+    };
+
+    let response = match ctx.db.upload_rules.set(&key, rule.as_ref()).await {
+        Ok(()) => ServerMessage::UploadRuleSetResponse {
+            success: true,
+            error: None,
+        },
+        Err(e) => {
+            log_error!("UploadRuleSet database error: {}", e);
+            failure(err_database(ctx.locale))
+        }
+    };
+    ctx.send_message(&response).await
+}
+
+/// Lowercase extensions and drop duplicates, keeping the first occurrence
+fn normalize_extensions(extensions: &[String]) -> Vec<String> {
+    let mut normalized: Vec<String> = Vec::with_capacity(extensions.len());
+    for extension in extensions {
+        let extension = extension.to_ascii_lowercase();
+        if !normalized.contains(&extension) {
+            normalized.push(extension);
+        }
+    }
+    normalized
+}
+
+/// Get the logged-in user sending an upload rule command
+///
+/// Disconnects (and returns `None`) when there is no valid session.
+async fn rule_user<W>(
+    command: &str,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<Option<UserSession>>
+where
+    W: AsyncWrite + Unpin,
+{
+    let Some(session_id) = session_id else {
+        log_warn!("{} request from {} without login", command, ctx.peer_addr);
+        ctx.send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some(command))
+            .await?;
+        return Ok(None);
+    };
+
+    match ctx.user_manager.get_user_by_session_id(session_id).await {
+        Some(user) => Ok(Some(user)),
+        None => {
+            ctx.send_error_and_disconnect(&err_authentication(ctx.locale), Some(command))
+                .await?;
+            Ok(None)
+        }
+    }
+}
+
+/// Resolve a directory in the admin's area (or the file root) to its rule key
+///
+/// Returns the path relative to the file root, or a translated error for
+/// invalid paths, missing directories and files.
+fn rule_target<W>(
+    path: &str,
+    root: bool,
+    user: &UserSession,
+    ctx: &HandlerContext<'_, W>,
+) -> Result<String, String> {
+    let Some(file_root) = ctx.file_root else {
+        return Err(err_file_not_found(ctx.locale));
+    };
+
+    if root && !user.has_permission(Permission::FileRoot) {
+        return Err(err_permission_denied(ctx.locale));
+    }
+
+    validators::validate_file_path(path).map_err(|e| match e {
+        FilePathError::TooLong => {
+            err_file_path_too_long(ctx.locale, validators::MAX_FILE_PATH_LENGTH)
+        }
+        FilePathError::ContainsNull
+        | FilePathError::InvalidCharacters
+        | FilePathError::ContainsWindowsDrive => err_file_path_invalid(ctx.locale),
+    })?;
+
+    let area_root = if root {
+        file_root.to_path_buf()
+    } else {
+        resolve_user_area(file_root, &user.username)
+    };
+    let area_root = area_root
+        .canonicalize()
+        .map_err(|_| err_file_not_found(ctx.locale))?;
+
+    let candidate = build_and_validate_candidate_path(&area_root, path)
+        .map_err(|_| err_file_path_invalid(ctx.locale))?;
+    let resolved =
+        resolve_path(&area_root, &candidate).map_err(|_| err_file_not_found(ctx.locale))?;
+
+    if !resolved.is_dir() {
+        return Err(err_upload_rule_not_directory(ctx.locale));
+    }
+
+    file_key(file_root, &resolved).ok_or_else(|| err_file_not_found(ctx.locale))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::testing::{
+        create_test_context, login_user, read_server_message, setup_file_area_basic,
+    };
+
+    fn music_rule() -> UploadRule {
+        UploadRule {
+            extensions: vec!["MP3".to_string(), "flac".to_string(), "mp3".to_string()],
+            max_file_size: Some(1024),
+            max_total_size: None,
+        }
+    }
+
+    #[test]
+    fn test_normalize_extensions() {
+        assert_eq!(
+            normalize_extensions(&music_rule().extensions),
+            vec!["mp3", "flac"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_upload_rule_get_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_upload_rule_get(
+            "/".to_string(),
+            false,
+            None,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_err(), "Should disconnect when not logged in");
+    }
+
+    #[tokio::test]
+    async fn test_upload_rule_set_requires_admin() {
+        let mut test_ctx = create_test_context().await;
+        let _temp_dir = setup_file_area_basic(&mut test_ctx);
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::FileUpload],
+            false,
+        )
+        .await;
+
+        handle_upload_rule_set(
+            "/".to_string(),
+            Some(music_rule()),
+            false,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::UploadRuleSetResponse { success, error } => {
+                assert!(!success);
+                assert!(error.is_some());
+            }
+            response => panic!("Expected UploadRuleSetResponse, got {:?}", response),
+        }
+        assert_eq!(test_ctx.db.upload_rules.get("/shared").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_upload_rule_set_get_and_remove() {
+        let mut test_ctx = create_test_context().await;
+        let temp_dir = setup_file_area_basic(&mut test_ctx);
+        std::fs::create_dir(temp_dir.path().join("shared/music")).unwrap();
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        handle_upload_rule_set(
+            "/music".to_string(),
+            Some(music_rule()),
+            false,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::UploadRuleSetResponse { success, .. } => assert!(success),
+            response => panic!("Expected UploadRuleSetResponse, got {:?}", response),
+        }
+
+        handle_upload_rule_get(
+            "/music".to_string(),
+            false,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::UploadRuleGetResponse { success, rule, .. } => {
+                assert!(success);
+                let rule = rule.unwrap();
+                assert_eq!(rule.extensions, vec!["mp3", "flac"]);
+                assert_eq!(rule.max_file_size, Some(1024));
+            }
+            response => panic!("Expected UploadRuleGetResponse, got {:?}", response),
+        }
+
+        handle_upload_rule_set(
+            "/music".to_string(),
+            None,
+            false,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::UploadRuleSetResponse { success, .. } => assert!(success),
+            response => panic!("Expected UploadRuleSetResponse, got {:?}", response),
+        }
+        assert_eq!(
+            test_ctx.db.upload_rules.get("/shared/music").await.unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_upload_rule_set_rejects_files_and_bad_extensions() {
+        let mut test_ctx = create_test_context().await;
+        let temp_dir = setup_file_area_basic(&mut test_ctx);
+        std::fs::write(temp_dir.path().join("shared/song.mp3"), b"content").unwrap();
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        let bad_extension = UploadRule {
+            extensions: vec![".mp3".to_string()],
+            ..UploadRule::default()
+        };
+        for (path, rule) in [("/song.mp3", music_rule()), ("/", bad_extension)] {
+            handle_upload_rule_set(
+                path.to_string(),
+                Some(rule),
+                false,
+                Some(session_id),
+                &mut test_ctx.handler_context(),
+            )
+            .await
+            .unwrap();
+            match read_server_message(&mut test_ctx).await {
+                ServerMessage::UploadRuleSetResponse { success, error } => {
+                    assert!(!success, "{path} should fail");
+                    assert!(error.is_some());
+                }
+                response => panic!("Expected UploadRuleSetResponse, got {:?}", response),
+            }
+        }
+    }
+}
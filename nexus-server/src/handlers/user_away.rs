@@ -24,7 +24,7 @@ where
 {
     // Verify authentication first
     let Some(session_id) = session_id else {
-        eprintln!("UserAway request from {} without login", ctx.peer_addr);
+        log_warn!("UserAway request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("UserAway"))
             .await;
@@ -19,7 +19,7 @@ where
 {
     // Verify authentication first
     let Some(session_id) = session_id else {
-        eprintln!("UserBack request from {} without login", ctx.peer_addr);
+        log_warn!("UserBack request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("UserBack"))
             .await;
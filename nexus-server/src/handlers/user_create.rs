@@ -51,7 +51,7 @@ where
 
     // Verify authentication first (before revealing validation errors to unauthenticated users)
     let Some(requesting_session_id) = session_id else {
-        eprintln!("UserCreate request from {} without login", ctx.peer_addr);
+        log_warn!("UserCreate request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("UserCreate"))
             .await;
@@ -127,9 +127,10 @@ where
 
     // Check UserCreate permission (uses cached permissions, admin bypass built-in)
     if !requesting_user.has_permission(Permission::UserCreate) {
-        eprintln!(
+        log_warn!(
             "UserCreate from {} (user: {}) without permission",
-            ctx.peer_addr, requesting_user.username
+            ctx.peer_addr,
+            requesting_user.username
         );
         return ctx
             .send_error(&err_permission_denied(ctx.locale), Some("UserCreate"))
@@ -193,9 +194,11 @@ where
         // Non-admins can only grant permissions they have
         // Check permission delegation authority (uses cached permissions, admin bypass built-in)
         if !requesting_user.has_permission(perm) {
-            eprintln!(
+            log_warn!(
                 "UserCreate from {} (user: {}) trying to grant permission they don't have: {}",
-                ctx.peer_addr, requesting_user.username, perm_str
+                ctx.peer_addr,
+                requesting_user.username,
+                perm_str
             );
             return ctx
                 .send_error(&err_permission_denied(ctx.locale), Some("UserCreate"))
@@ -220,7 +223,7 @@ where
             // Username doesn't exist, proceed with creation
         }
         Err(e) => {
-            eprintln!("Database error checking username: {}", e);
+            log_error!("Database error checking username: {}", e);
             return ctx
                 .send_error_and_disconnect(&err_database(ctx.locale), Some("UserCreate"))
                 .await;
@@ -231,7 +234,7 @@ where
     let password_hash = match hash_password(&password, false) {
         Ok(hash) => hash,
         Err(e) => {
-            eprintln!("Password hashing error: {}", e);
+            log_error!("Password hashing error: {}", e);
             return ctx
                 .send_error_and_disconnect(&err_database(ctx.locale), Some("UserCreate"))
                 .await;
@@ -262,7 +265,7 @@ where
             ctx.send_message(&response).await
         }
         Err(e) => {
-            eprintln!("Database error creating user: {}", e);
+            log_error!("Database error creating user: {}", e);
             return ctx
                 .send_error_and_disconnect(&err_database(ctx.locale), Some("UserCreate"))
                 .await;
@@ -435,6 +438,7 @@ mod tests {
                 nickname: "admin".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .expect("Failed to add user");
@@ -761,6 +765,7 @@ mod tests {
                 nickname: "creator".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .expect("Failed to add user");
@@ -836,6 +841,7 @@ mod tests {
                 nickname: "creator".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .expect("Failed to add user");
@@ -29,7 +29,7 @@ where
 {
     // Verify authentication first (before revealing validation errors to unauthenticated users)
     let Some(session_id) = session_id else {
-        eprintln!("UserDelete request from {} without login", ctx.peer_addr);
+        log_warn!("UserDelete request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("UserDelete"))
             .await;
@@ -84,9 +84,10 @@ where
 
     // Check UserDelete permission (uses cached permissions, admin bypass built-in)
     if !requesting_user_session.has_permission(Permission::UserDelete) {
-        eprintln!(
+        log_warn!(
             "UserDelete from {} (user: {}) without permission",
-            ctx.peer_addr, requesting_user_session.username
+            ctx.peer_addr,
+            requesting_user_session.username
         );
         let response = ServerMessage::UserDeleteResponse {
             success: false,
@@ -108,7 +109,7 @@ where
             return ctx.send_message(&response).await;
         }
         Err(e) => {
-            eprintln!("Database error getting target user: {}", e);
+            log_error!("Database error getting target user: {}", e);
             return ctx
                 .send_error_and_disconnect(&err_database(ctx.locale), Some("UserDelete"))
                 .await;
@@ -117,9 +118,10 @@ where
 
     // Prevent non-admins from deleting admin users
     if target_user.is_admin && !requesting_user_session.is_admin {
-        eprintln!(
+        log_warn!(
             "UserDelete from {} (user: {}) trying to delete admin user",
-            ctx.peer_addr, requesting_user_session.username
+            ctx.peer_addr,
+            requesting_user_session.username
         );
         let response = ServerMessage::UserDeleteResponse {
             success: false,
@@ -129,6 +131,9 @@ where
         return ctx.send_message(&response).await;
     }
 
+    // The account's login tokens go with it
+    ctx.login_tokens.revoke_account(target_user.id, None);
+
     // Handle online user disconnection (all sessions)
     let online_users = ctx
         .user_manager
@@ -140,6 +145,7 @@ where
         let disconnect_msg = ServerMessage::Error {
             message: err_account_deleted(&online_user.locale),
             command: None,
+            error_kind: None,
         };
         let _ = online_user.tx.send((disconnect_msg, None));
 
@@ -178,7 +184,7 @@ where
             }
         }
         Err(e) => {
-            eprintln!("Database error deleting user: {}", e);
+            log_error!("Database error deleting user: {}", e);
             ctx.send_error_and_disconnect(&err_database(ctx.locale), Some("UserDelete"))
                 .await
         }
@@ -190,10 +196,9 @@ mod tests {
     use super::*;
     use crate::db;
     use crate::handlers::testing::{create_test_context, login_user, read_server_message};
+    use crate::outbound;
     use crate::users::user::NewSessionParams;
 
-    use tokio::sync::mpsc;
-
     #[tokio::test]
     async fn test_userdelete_requires_login() {
         let mut test_ctx = create_test_context().await;
@@ -538,7 +543,7 @@ mod tests {
             .unwrap();
 
         // Add online_user to UserManager (they're online)
-        let (online_tx, _online_rx) = mpsc::unbounded_channel();
+        let (online_tx, _online_rx) = outbound::channel(Default::default());
         let online_session_id = test_ctx
             .user_manager
             .add_user(NewSessionParams {
@@ -557,6 +562,7 @@ mod tests {
                 nickname: "online_user".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .expect("Failed to add user");
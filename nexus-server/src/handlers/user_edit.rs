@@ -27,7 +27,7 @@ where
 {
     // Verify authentication first (before revealing validation errors to unauthenticated users)
     let Some(requesting_session_id) = session_id else {
-        eprintln!("UserEdit request from {} without login", ctx.peer_addr);
+        log_warn!("UserEdit request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("UserEdit"))
             .await;
@@ -84,9 +84,10 @@ where
 
     // Check UserEdit permission (uses cached permissions, admin bypass built-in)
     if !requesting_user.has_permission(Permission::UserEdit) {
-        eprintln!(
+        log_warn!(
             "UserEdit from {} (user: {}) without permission",
-            ctx.peer_addr, requesting_user.username
+            ctx.peer_addr,
+            requesting_user.username
         );
         let response = ServerMessage::UserEditResponse {
             success: false,
@@ -116,7 +117,7 @@ where
             return ctx.send_message(&response).await;
         }
         Err(e) => {
-            eprintln!("Database error getting user: {}", e);
+            log_error!("Database error getting user: {}", e);
             return ctx
                 .send_error_and_disconnect(&err_database(ctx.locale), Some("UserEdit"))
                 .await;
@@ -125,9 +126,10 @@ where
 
     // Prevent non-admins from viewing admin user details for editing
     if target_user.is_admin && !requesting_user.is_admin {
-        eprintln!(
+        log_warn!(
             "UserEdit from {} (user: {}) trying to edit admin user",
-            ctx.peer_addr, requesting_user.username
+            ctx.peer_addr,
+            requesting_user.username
         );
         let response = ServerMessage::UserEditResponse {
             success: false,
@@ -145,7 +147,7 @@ where
     let user_permissions = match ctx.db.users.get_user_permissions(target_user.id).await {
         Ok(perms) => perms,
         Err(e) => {
-            eprintln!("Database error getting permissions: {}", e);
+            log_error!("Database error getting permissions: {}", e);
             return ctx
                 .send_error_and_disconnect(&err_database(ctx.locale), Some("UserEdit"))
                 .await;
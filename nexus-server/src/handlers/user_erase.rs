@@ -0,0 +1,461 @@
+//! Handler for UserErase command
+//!
+//! Erasing an account deletes it like UserDelete, then removes what the
+//! server still holds about the user: their name on bans, trusts, channel
+//! topics and the upload journal, their personal folder and drop boxes, and
+//! other accounts' stored direct messages with them. News posts, voice clips,
+//! snippets and the user's own message history go with the account (cascade
+//! delete). Chat and login history are never stored, so there is nothing
+//! further to remove. The erasure itself is recorded in the audit log.
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::{FileChangeKind, ServerMessage};
+use nexus_common::validators::{self, UsernameError};
+
+#[cfg(test)]
+use super::testing::DEFAULT_TEST_LOCALE;
+use super::{
+    HandlerContext, err_account_deleted, err_authentication, err_cannot_delete_guest,
+    err_cannot_delete_last_admin, err_cannot_delete_self, err_database, err_not_logged_in,
+    err_permission_denied, err_user_not_found, err_username_empty, err_username_invalid,
+    err_username_too_long, remove_user_with_voice_cleanup,
+};
+use crate::db::audit::AUDIT_ACTION_USER_ERASE;
+use crate::db::sql::GUEST_USERNAME;
+use crate::files::{find_user_folders, remove_path_async};
+
+/// Name left in place of an erased user on bans, trusts and journaled uploads
+const ERASED_USER_PLACEHOLDER: &str = "[erased]";
+
+/// Handle UserErase command
+pub async fn handle_user_erase<W>(
+    target_username: String,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first (before revealing validation errors to unauthenticated users)
+    let Some(session_id) = session_id else {
+        log_warn!("UserErase request from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("UserErase"))
+            .await;
+    };
+
+    // Validate username format
+    if let Err(e) = validators::validate_username(&target_username) {
+        let error_msg = match e {
+            UsernameError::Empty => err_username_empty(ctx.locale),
+            UsernameError::TooLong => {
+                err_username_too_long(ctx.locale, validators::MAX_USERNAME_LENGTH)
+            }
+            UsernameError::InvalidCharacters => err_username_invalid(ctx.locale),
+        };
+        return send_failure(error_msg, ctx).await;
+    }
+
+    // Get requesting user from session
+    let requesting_user_session = match ctx.user_manager.get_user_by_session_id(session_id).await {
+        Some(user) => user,
+        None => {
+            return ctx
+                .send_error_and_disconnect(&err_authentication(ctx.locale), Some("UserErase"))
+                .await;
+        }
+    };
+
+    // Erasure is admin-only
+    if !requesting_user_session.is_admin {
+        log_warn!(
+            "UserErase from {} (user: {}) without permission",
+            ctx.peer_addr,
+            requesting_user_session.username
+        );
+        return send_failure(err_permission_denied(ctx.locale), ctx).await;
+    }
+
+    if target_username.to_lowercase() == requesting_user_session.username.to_lowercase() {
+        return send_failure(err_cannot_delete_self(ctx.locale), ctx).await;
+    }
+
+    if target_username.to_lowercase() == GUEST_USERNAME {
+        return send_failure(err_cannot_delete_guest(ctx.locale), ctx).await;
+    }
+
+    // Look up target user in database
+    let target_user = match ctx.db.users.get_user_by_username(&target_username).await {
+        Ok(Some(user)) => user,
+        Ok(None) => {
+            return send_failure(err_user_not_found(ctx.locale, &target_username), ctx).await;
+        }
+        Err(e) => {
+            log_error!("Database error getting target user: {}", e);
+            return ctx
+                .send_error_and_disconnect(&err_database(ctx.locale), Some("UserErase"))
+                .await;
+        }
+    };
+
+    // The account's login tokens go with it
+    ctx.login_tokens.revoke_account(target_user.id, None);
+
+    // Handle online user disconnection (all sessions)
+    let online_users = ctx
+        .user_manager
+        .get_sessions_by_username(&target_user.username)
+        .await;
+
+    for online_user in online_users {
+        let disconnect_msg = ServerMessage::Error {
+            message: err_account_deleted(&online_user.locale),
+            command: None,
+            error_kind: None,
+        };
+        let _ = online_user.tx.send((disconnect_msg, None));
+
+        let session_id = online_user.session_id;
+        remove_user_with_voice_cleanup(
+            ctx.user_manager,
+            ctx.voice_registry,
+            ctx.channel_manager,
+            session_id,
+            &online_user,
+        )
+        .await;
+    }
+
+    // Delete the account first (atomic last-admin protection); news and
+    // voice clips are removed with it
+    match ctx.db.users.delete_user(target_user.id).await {
+        Ok(true) => {}
+        Ok(false) => {
+            return send_failure(err_cannot_delete_last_admin(ctx.locale), ctx).await;
+        }
+        Err(e) => {
+            log_error!("Database error erasing user: {}", e);
+            return ctx
+                .send_error_and_disconnect(&err_database(ctx.locale), Some("UserErase"))
+                .await;
+        }
+    }
+
+    let username = target_user.username;
+
+    // Remove the user's name from records that outlive the account
+    let scrubbed = async {
+        ctx.db
+            .bans
+            .erase_user(&username, ERASED_USER_PLACEHOLDER)
+            .await
+            .map_err(|e| e.to_string())?;
+        ctx.db
+            .trusts
+            .erase_user(&username, ERASED_USER_PLACEHOLDER)
+            .await
+            .map_err(|e| e.to_string())?;
+        ctx.db
+            .upload_journal
+            .erase_user(&username, ERASED_USER_PLACEHOLDER)
+            .await
+            .map_err(|e| e.to_string())?;
+        ctx.db
+            .message_history
+            .delete_with_peer(&username)
+            .await
+            .map_err(|e| e.to_string())?;
+        ctx.channel_manager
+            .clear_topic_setter(&username)
+            .await
+            .map_err(|e| e.to_string())
+    }
+    .await;
+    if let Err(e) = scrubbed {
+        log_error!("Database error erasing records of {}: {}", username, e);
+        return send_failure(err_database(ctx.locale), ctx).await;
+    }
+
+    // Remove the user's personal folder and drop boxes
+    let mut removed_folders = 0;
+    if let Some(file_root) = ctx.file_root {
+        for folder in find_user_folders(file_root, &username) {
+            match remove_path_async(&folder).await {
+                Ok(()) => {
+                    removed_folders += 1;
+                    ctx.file_index
+                        .notify_change(&folder, FileChangeKind::Removed);
+                }
+                Err(e) => log_error!("Failed to remove {}: {}", folder.display(), e),
+            }
+        }
+    }
+
+    let details = format!("folders removed: {removed_folders}");
+    if let Err(e) = ctx
+        .db
+        .audit
+        .record(
+            AUDIT_ACTION_USER_ERASE,
+            &username,
+            &requesting_user_session.username,
+            Some(&details),
+        )
+        .await
+    {
+        log_error!(
+            "Failed to record erasure of {} in audit log: {}",
+            username,
+            e
+        );
+    }
+
+    if ctx.debug {
+        log_debug!(
+            "User {} erased by {} ({})",
+            username,
+            requesting_user_session.username,
+            details
+        );
+    }
+
+    // Use the database-preserved username casing, not the input
+    let response = ServerMessage::UserEraseResponse {
+        success: true,
+        error: None,
+        username: Some(username),
+    };
+    ctx.send_message(&response).await
+}
+
+/// Send a failed UserEraseResponse
+async fn send_failure<W>(error: String, ctx: &mut HandlerContext<'_, W>) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let response = ServerMessage::UserEraseResponse {
+        success: false,
+        error: Some(error),
+        username: None,
+    };
+    ctx.send_message(&response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use nexus_common::protocol::ChatAction;
+
+    use super::*;
+    use crate::db;
+    use crate::handlers::testing::{create_test_context, login_user, read_server_message};
+
+    #[tokio::test]
+    async fn test_usererase_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result =
+            handle_user_erase("alice".to_string(), None, &mut test_ctx.handler_context()).await;
+
+        assert!(result.is_err(), "UserErase should require login");
+    }
+
+    #[tokio::test]
+    async fn test_usererase_requires_admin() {
+        let mut test_ctx = create_test_context().await;
+
+        // Even with UserDelete permission, erasure is admin-only
+        let user_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[db::Permission::UserDelete],
+            false,
+        )
+        .await;
+        test_ctx
+            .db
+            .users
+            .create_user("bob", "hash", false, false, true, &db::Permissions::new())
+            .await
+            .unwrap();
+
+        let result = handle_user_erase(
+            "bob".to_string(),
+            Some(user_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::UserEraseResponse { success, error, .. } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_permission_denied(DEFAULT_TEST_LOCALE)));
+            }
+            _ => panic!("Expected UserEraseResponse"),
+        }
+
+        let still_exists = test_ctx.db.users.get_user_by_username("bob").await.unwrap();
+        assert!(still_exists.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_usererase_cannot_erase_self() {
+        let mut test_ctx = create_test_context().await;
+        let admin_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        let result = handle_user_erase(
+            "admin".to_string(),
+            Some(admin_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::UserEraseResponse { success, error, .. } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_cannot_delete_self(DEFAULT_TEST_LOCALE)));
+            }
+            _ => panic!("Expected UserEraseResponse"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_usererase_scrubs_records() {
+        let mut test_ctx = create_test_context().await;
+        let admin_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        let bob = test_ctx
+            .db
+            .users
+            .create_user("Bob", "hash", false, false, true, &db::Permissions::new())
+            .await
+            .unwrap();
+        test_ctx
+            .db
+            .news
+            .create_news(Some("Hello"), &[], bob.id)
+            .await
+            .unwrap();
+        test_ctx
+            .db
+            .bans
+            .create_or_update_ban("10.0.0.1", Some("Bob"), None, "admin", None)
+            .await
+            .unwrap();
+        test_ctx
+            .db
+            .bans
+            .create_or_update_ban("10.0.0.2", Some("mallory"), None, "Bob", None)
+            .await
+            .unwrap();
+        test_ctx.channel_manager.join("#general", 1).await.unwrap();
+        test_ctx
+            .channel_manager
+            .set_topic("#general", Some("Hi".to_string()), Some("Bob".to_string()))
+            .await
+            .unwrap();
+        let admin = test_ctx
+            .db
+            .users
+            .get_user_by_username("admin")
+            .await
+            .unwrap()
+            .unwrap();
+        test_ctx
+            .db
+            .upload_journal
+            .record(
+                "/Bob/song.mp3.part",
+                "/Bob/song.mp3",
+                "Bob",
+                "abc123",
+                1000,
+                0,
+            )
+            .await
+            .unwrap();
+        let history = &test_ctx.db.message_history;
+        history.set_retention_days(admin.id, 30).await.unwrap();
+        history
+            .store(admin.id, "Bob", "Bob", "admin", "hi", ChatAction::Normal)
+            .await
+            .unwrap();
+
+        let result = handle_user_erase(
+            "bob".to_string(),
+            Some(admin_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::UserEraseResponse {
+                success,
+                error,
+                username,
+            } => {
+                assert!(success);
+                assert!(error.is_none());
+                assert_eq!(username, Some("Bob".to_string()));
+            }
+            _ => panic!("Expected UserEraseResponse"),
+        }
+
+        assert!(
+            test_ctx
+                .db
+                .users
+                .get_user_by_id(bob.id)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(test_ctx.db.news.get_all_news().await.unwrap().is_empty());
+        let page = test_ctx
+            .db
+            .message_history
+            .fetch(admin.id, None, None, 10)
+            .await
+            .unwrap();
+        assert!(page.messages.is_empty());
+
+        let banned = test_ctx
+            .db
+            .bans
+            .get_ban_by_ip("10.0.0.1")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(banned.nickname, None);
+        let created = test_ctx
+            .db
+            .bans
+            .get_ban_by_ip("10.0.0.2")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(created.created_by, ERASED_USER_PLACEHOLDER);
+
+        let journal = test_ctx.db.upload_journal.list().await.unwrap();
+        assert_eq!(journal[0].username, ERASED_USER_PLACEHOLDER);
+
+        let channel = test_ctx
+            .channel_manager
+            .get_channel("#general")
+            .await
+            .unwrap();
+        assert_eq!(channel.topic_set_by, None);
+
+        let audit = test_ctx.db.audit.list().await.unwrap();
+        assert_eq!(audit.len(), 1);
+        assert_eq!(audit[0].action, AUDIT_ACTION_USER_ERASE);
+        assert_eq!(audit[0].target, "Bob");
+        assert_eq!(audit[0].actor, "admin");
+    }
+}
@@ -30,7 +30,7 @@ where
 {
     // Verify authentication first (before revealing validation errors to unauthenticated users)
     let Some(id) = session_id else {
-        eprintln!("UserInfo request from {} without login", ctx.peer_addr);
+        log_warn!("UserInfo request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("UserInfo"))
             .await;
@@ -65,9 +65,10 @@ where
 
     // Check UserInfo permission (uses cached permissions, admin bypass built-in)
     if !requesting_user.has_permission(Permission::UserInfo) {
-        eprintln!(
+        log_warn!(
             "UserInfo from {} (user: {}) without permission",
-            ctx.peer_addr, requesting_user.username
+            ctx.peer_addr,
+            requesting_user.username
         );
         return ctx
             .send_error(&err_permission_denied(ctx.locale), Some("UserInfo"))
@@ -132,12 +133,19 @@ where
         .max_by_key(|s| s.login_time)
         .and_then(|s| s.avatar.clone());
 
-    // Get away status from most recent login session ("latest login wins")
-    let (is_away, status) = target_sessions
+    // Get away and do not disturb status from most recent login session ("latest login wins")
+    let (is_away, status, is_dnd) = target_sessions
         .iter()
         .max_by_key(|s| s.login_time)
-        .map(|s| (s.is_away, s.status.clone()))
-        .unwrap_or((false, None));
+        .map(|s| (s.is_away, s.status.clone(), s.is_dnd))
+        .unwrap_or((false, None, false));
+
+    // Client software from most recent login session, idle time from the most recent activity
+    let client = target_sessions
+        .iter()
+        .max_by_key(|s| s.login_time)
+        .and_then(|s| s.client.clone());
+    let last_active = target_sessions.iter().map(|s| s.last_active).max();
 
     // Get nickname (display name) for the user from the session
     // (nickname is always populated - equals username for regular accounts)
@@ -170,6 +178,28 @@ where
         Some(channels)
     };
 
+    // Lifetime activity counters and the rank they earn
+    // (shared accounts are used by many people, so they have neither)
+    let user_stats = if target_account.is_shared {
+        None
+    } else {
+        match ctx.stats.user_stats(&ctx.db.stats, target_account.id).await {
+            Ok(user_stats) => Some(user_stats),
+            Err(e) => {
+                log_error!("UserInfo database error: {}", e);
+                let response = ServerMessage::UserInfoResponse {
+                    success: false,
+                    error: Some(err_database(ctx.locale)),
+                    user: None,
+                };
+                return ctx.send_message(&response).await;
+            }
+        }
+    };
+    let rank = user_stats
+        .as_ref()
+        .and_then(|stats| ctx.ranks.rank_for(stats));
+
     // Use the actual username from the database (preserves original casing)
     let actual_username = target_account.username.clone();
 
@@ -193,7 +223,12 @@ where
             addresses: Some(addresses),
             is_away,
             status,
+            is_dnd,
             channels,
+            stats: user_stats,
+            rank,
+            last_active,
+            client,
         }
     } else {
         // Non-admin gets all fields except addresses
@@ -211,7 +246,12 @@ where
             addresses: None,
             is_away,
             status,
+            is_dnd,
             channels,
+            stats: user_stats,
+            rank,
+            last_active,
+            client,
         }
     };
 
@@ -285,6 +325,7 @@ mod tests {
                 nickname: "alice".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .expect("Failed to add user");
@@ -344,6 +385,7 @@ mod tests {
                 nickname: "alice".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .expect("Failed to add user");
@@ -443,6 +485,7 @@ mod tests {
                 nickname: "requester".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .expect("Failed to add user");
@@ -466,6 +509,7 @@ mod tests {
                 nickname: "target".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .expect("Failed to add user");
@@ -571,6 +615,7 @@ mod tests {
                 nickname: "admin".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .expect("Failed to add user");
@@ -594,6 +639,7 @@ mod tests {
                 nickname: "target".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .expect("Failed to add user");
@@ -710,6 +756,7 @@ mod tests {
                 nickname: "admin1".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .expect("Failed to add user");
@@ -733,6 +780,7 @@ mod tests {
                 nickname: "admin2".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .expect("Failed to add user");
@@ -873,6 +921,7 @@ mod tests {
                 nickname: "alice".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .expect("Failed to add user");
@@ -947,6 +996,7 @@ mod tests {
                 nickname: "alice".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .expect("Failed to add user");
@@ -973,6 +1023,7 @@ mod tests {
                 nickname: "alice".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .expect("Failed to add user");
@@ -1032,6 +1083,71 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_userinfo_includes_client_and_last_active() {
+        let mut test_ctx = create_test_context().await;
+
+        let admin_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        let hashed = get_cached_password_hash("password");
+        let account = test_ctx
+            .db
+            .users
+            .create_user(
+                "alice",
+                &hashed,
+                false,
+                false,
+                true,
+                &crate::db::Permissions::new(),
+            )
+            .await
+            .unwrap();
+
+        let session_id = test_ctx
+            .user_manager
+            .add_user(NewSessionParams {
+                session_id: 100,
+                db_user_id: account.id,
+                username: "alice".to_string(),
+                address: test_ctx.peer_addr,
+                created_at: account.created_at,
+                is_admin: false,
+                is_shared: false,
+                permissions: std::collections::HashSet::new(),
+                tx: test_ctx.tx.clone(),
+                features: vec![],
+                locale: "en".to_string(),
+                avatar: None,
+                nickname: "alice".to_string(),
+                is_away: false,
+                status: None,
+                client: Some("Nexus BBS 0.5.0".to_string()),
+            })
+            .await
+            .expect("Failed to add user");
+        test_ctx.user_manager.touch_activity(session_id).await;
+
+        let result = handle_user_info(
+            "alice".to_string(),
+            Some(admin_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let response_msg = read_server_message(&mut test_ctx).await;
+        match response_msg {
+            ServerMessage::UserInfoResponse { user, .. } => {
+                let user_info = user.unwrap();
+                assert_eq!(user_info.client.as_deref(), Some("Nexus BBS 0.5.0"));
+                let last_active = user_info.last_active.expect("last_active should be set");
+                assert!(last_active >= user_info.login_time);
+            }
+            _ => panic!("Expected UserInfoResponse"),
+        }
+    }
+
     // ========================================================================
     // Shared Account Tests
     // ========================================================================
@@ -1070,6 +1186,11 @@ mod tests {
             avatar: None,
             nickname: Some("Nick1".to_string()),
             handshake_complete: true,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let _ = handle_login(
             login_request,
@@ -1105,6 +1226,8 @@ mod tests {
                 );
                 assert!(user_info.is_shared, "Should be marked as shared");
                 assert_eq!(user_info.session_ids.len(), 1, "Should have one session");
+                assert!(user_info.stats.is_none(), "Shared account has no counters");
+                assert!(user_info.rank.is_none(), "Shared account has no rank");
             }
             _ => panic!("Expected UserInfoResponse"),
         }
@@ -1144,6 +1267,11 @@ mod tests {
             avatar: None,
             nickname: Some("Nick1".to_string()),
             handshake_complete: true,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let _ = handle_login(
             login_request,
@@ -1207,6 +1335,10 @@ mod tests {
                     user_info.nickname, user_info.username,
                     "Regular account should have nickname == username"
                 );
+                assert!(
+                    user_info.stats.is_some(),
+                    "Regular account should include activity counters"
+                );
             }
             _ => panic!("Expected UserInfoResponse"),
         }
@@ -27,7 +27,7 @@ where
 {
     // Verify authentication first (before revealing validation errors to unauthenticated users)
     let Some(session_id) = session_id else {
-        eprintln!("UserKick request from {} without login", ctx.peer_addr);
+        log_warn!("UserKick request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("UserKick"))
             .await;
@@ -75,9 +75,10 @@ where
 
     // Check UserKick permission (uses cached permissions, admin bypass built-in)
     if !requesting_user_session.has_permission(Permission::UserKick) {
-        eprintln!(
+        log_warn!(
             "UserKick from {} (user: {}) without permission",
-            ctx.peer_addr, requesting_user_session.username
+            ctx.peer_addr,
+            requesting_user_session.username
         );
         let response = ServerMessage::UserKickResponse {
             success: false,
@@ -107,7 +108,7 @@ where
     let target_user_db = match ctx.db.users.get_user_by_username(&db_lookup_username).await {
         Ok(user) => user,
         Err(e) => {
-            eprintln!("Database error getting target user: {}", e);
+            log_error!("Database error getting target user: {}", e);
             return ctx
                 .send_error_and_disconnect(&err_database(ctx.locale), Some("UserKick"))
                 .await;
@@ -148,11 +149,15 @@ where
         let kick_msg = ServerMessage::Error {
             message: kick_message,
             command: Some("UserKick".to_string()),
+            error_kind: None,
         };
         let _ = user.tx.send((kick_msg, None));
 
-        // Remove from voice (if in voice) and UserManager, broadcast disconnection
+        // A kicked session can't log back in with its login token
         let target_session_id = user.session_id;
+        ctx.login_tokens.revoke_session(target_session_id);
+
+        // Remove from voice (if in voice) and UserManager, broadcast disconnection
         remove_user_with_voice_cleanup(
             ctx.user_manager,
             ctx.voice_registry,
@@ -553,6 +558,11 @@ mod tests {
             avatar: None,
             nickname: Some("Nick1".to_string()),
             handshake_complete: true,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let _ = handle_login(
             login_request,
@@ -630,6 +640,11 @@ mod tests {
             avatar: None,
             nickname: Some("Nick1".to_string()),
             handshake_complete: true,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let _ = handle_login(
             login_request,
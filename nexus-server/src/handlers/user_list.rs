@@ -4,8 +4,9 @@ use std::collections::HashMap;
 use std::io;
 
 /// Aggregated user data for deduplication
-/// Fields: (login_time, is_admin, is_shared, session_ids, locale, avatar, latest_session_login_time, is_away, status)
-/// Note: avatar, is_away, and status all use "latest login wins" - tracked via latest_session_login_time
+/// Fields: (login_time, is_admin, is_shared, session_ids, locale, avatar, latest_session_login_time, is_away, status, is_dnd, last_active, client)
+/// Note: avatar, is_away, status, is_dnd, and client all use "latest login wins" - tracked via latest_session_login_time;
+/// last_active is the most recent activity on any session
 type UserAggregateData = (
     i64,
     bool,
@@ -16,6 +17,9 @@ type UserAggregateData = (
     i64,
     bool,
     Option<String>,
+    bool,
+    i64,
+    Option<String>,
 );
 
 use tokio::io::AsyncWrite;
@@ -42,7 +46,7 @@ where
 {
     // Verify authentication first
     let Some(id) = session_id else {
-        eprintln!("UserList request from {} without login", ctx.peer_addr);
+        log_warn!("UserList request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("UserList"))
             .await;
@@ -70,9 +74,11 @@ where
     };
 
     if !has_permission {
-        eprintln!(
+        log_warn!(
             "UserList (all={}) from {} (user: {}) without permission",
-            all, ctx.peer_addr, requesting_user.username
+            all,
+            ctx.peer_addr,
+            requesting_user.username
         );
         return ctx
             .send_error(&err_permission_denied(ctx.locale), Some("UserList"))
@@ -86,7 +92,7 @@ where
         let db_users = match ctx.db.users.get_all_users().await {
             Ok(users) => users,
             Err(e) => {
-                eprintln!("Failed to fetch all users from database: {}", e);
+                log_error!("Failed to fetch all users from database: {}", e);
                 return ctx
                     .send_error(&err_database(ctx.locale), Some("UserList"))
                     .await;
@@ -107,6 +113,9 @@ where
                 avatar: None,
                 is_away: false,
                 status: None,
+                is_dnd: false,
+                last_active: None,
+                client: None,
             })
             .collect();
 
@@ -145,6 +154,9 @@ where
                 avatar: user.avatar.clone(),
                 is_away: user.is_away,
                 status: user.status.clone(),
+                is_dnd: user.is_dnd,
+                last_active: Some(user.last_active),
+                client: user.client.clone(),
             });
         } else {
             // Regular accounts: deduplicate by username and aggregate sessions
@@ -163,16 +175,22 @@ where
                         latest_session_login_time,
                         is_away,
                         status,
+                        is_dnd,
+                        last_active,
+                        client,
                     )| {
                         // Keep earliest login time for display
                         *login_time = (*login_time).min(user.login_time);
                         session_ids.push(user.session_id);
-                        // Avatar, away status, and status message: latest login wins
+                        *last_active = (*last_active).max(user.last_active);
+                        // Avatar, away status, status message, do not disturb, and client: latest login wins
                         if user.login_time > *latest_session_login_time {
                             *avatar = user.avatar.clone();
                             *latest_session_login_time = user.login_time;
                             *is_away = user.is_away;
                             *status = user.status.clone();
+                            *is_dnd = user.is_dnd;
+                            *client = user.client.clone();
                         }
                     },
                 )
@@ -186,6 +204,9 @@ where
                     user.login_time, // Track login time for avatar selection
                     user.is_away,
                     user.status.clone(),
+                    user.is_dnd,
+                    user.last_active,
+                    user.client.clone(),
                 ));
         }
     }
@@ -196,7 +217,20 @@ where
         .map(
             |(
                 username,
-                (login_time, is_admin, is_shared, session_ids, locale, avatar, _, is_away, status),
+                (
+                    login_time,
+                    is_admin,
+                    is_shared,
+                    session_ids,
+                    locale,
+                    avatar,
+                    _,
+                    is_away,
+                    status,
+                    is_dnd,
+                    last_active,
+                    client,
+                ),
             )| {
                 UserInfo {
                     // For regular accounts, nickname == username
@@ -210,6 +244,9 @@ where
                     avatar,
                     is_away,
                     status,
+                    is_dnd,
+                    last_active: Some(last_active),
+                    client,
                 }
             },
         )
@@ -410,6 +447,7 @@ mod tests {
                 nickname: "alice".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .expect("Failed to add user");
@@ -475,6 +513,7 @@ mod tests {
                 nickname: "alice".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .expect("Failed to add user");
@@ -501,6 +540,7 @@ mod tests {
                 nickname: "bob".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .expect("Failed to add user");
@@ -563,6 +603,7 @@ mod tests {
                 nickname: "alice".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .expect("Failed to add user");
@@ -583,6 +624,65 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_userlist_includes_last_active_and_client() {
+        use crate::handlers::testing::read_server_message;
+        use crate::users::user::NewSessionParams;
+
+        let mut test_ctx = create_test_context().await;
+
+        let password = "password";
+        let hashed = get_cached_password_hash(password);
+        let mut perms = db::Permissions::new();
+        perms.permissions.insert(db::Permission::UserList);
+        let account = test_ctx
+            .db
+            .users
+            .create_user("alice", &hashed, false, false, true, &perms)
+            .await
+            .unwrap();
+
+        let session_id = test_ctx
+            .user_manager
+            .add_user(NewSessionParams {
+                session_id: 1,
+                db_user_id: account.id,
+                username: "alice".to_string(),
+                address: test_ctx.peer_addr,
+                created_at: account.created_at,
+                is_admin: false,
+                is_shared: false,
+                permissions: perms.permissions.clone(),
+                tx: test_ctx.tx.clone(),
+                features: vec![],
+                locale: "en".to_string(),
+                avatar: None,
+                nickname: "alice".to_string(),
+                is_away: false,
+                status: None,
+                client: Some("Nexus BBS 0.5.0".to_string()),
+            })
+            .await
+            .expect("Failed to add user");
+        test_ctx.user_manager.touch_activity(session_id).await;
+
+        let result =
+            handle_user_list(false, Some(session_id), &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok());
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::UserListResponse { users, .. } => {
+                let users = users.unwrap();
+                assert_eq!(users.len(), 1);
+                let last_active = users[0].last_active.expect("last_active should be set");
+                assert!(last_active >= users[0].login_time);
+                assert_eq!(users[0].client.as_deref(), Some("Nexus BBS 0.5.0"));
+            }
+            _ => panic!("Expected UserListResponse"),
+        }
+    }
+
     // =========================================================================
     // /list all tests
     // =========================================================================
@@ -1073,6 +1173,7 @@ mod tests {
                 nickname: "alice".to_string(),
                 is_away: true,
                 status: Some("old status".to_string()),
+                client: None,
             })
             .await
             .expect("Failed to add first session");
@@ -1099,6 +1200,7 @@ mod tests {
                 nickname: "alice".to_string(),
                 is_away: false,
                 status: Some("new status".to_string()),
+                client: None,
             })
             .await
             .expect("Failed to add second session");
@@ -1165,6 +1267,7 @@ mod tests {
                 nickname: "user_one".to_string(),
                 is_away: true,
                 status: Some("user one away".to_string()),
+                client: None,
             })
             .await
             .expect("Failed to add first session");
@@ -1188,6 +1291,7 @@ mod tests {
                 nickname: "user_two".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .expect("Failed to add second session");
@@ -1,26 +1,33 @@
 //! Handler for UserMessage command
 
 use std::io;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use tokio::io::AsyncWrite;
 
+use nexus_common::ErrorKind;
 use nexus_common::protocol::{ChatAction, ServerMessage};
 use nexus_common::validators::{self, MessageError, NicknameError};
 
 use super::{
     HandlerContext, err_authentication, err_cannot_message_self, err_chat_too_long,
-    err_message_contains_newlines, err_message_empty, err_message_invalid_characters,
-    err_nickname_empty, err_nickname_invalid, err_nickname_not_online, err_nickname_too_long,
-    err_not_logged_in, err_permission_denied,
+    err_idempotency_key_invalid, err_message_contains_newlines, err_message_empty,
+    err_message_invalid_characters, err_nickname_empty, err_nickname_invalid,
+    err_nickname_not_online, err_nickname_too_long, err_not_logged_in, err_permission_denied,
 };
-use crate::db::Permission;
+use crate::db::{MessageHistoryDb, Permission};
+use crate::users::user::UserSession;
+
+/// Next message ID (delivery and read receipts refer to it)
+static NEXT_MESSAGE_ID: AtomicU64 = AtomicU64::new(1);
 
 /// Handle UserMessage command
 pub async fn handle_user_message<W>(
     to_nickname: String,
     message: String,
     action: ChatAction,
+    idempotency_key: Option<String>,
     session_id: Option<u32>,
     ctx: &mut HandlerContext<'_, W>,
 ) -> io::Result<()>
@@ -29,7 +36,7 @@ where
 {
     // Verify authentication first (before revealing validation errors to unauthenticated users)
     let Some(session_id) = session_id else {
-        eprintln!("UserMessage request from {} without login", ctx.peer_addr);
+        log_warn!("UserMessage request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("UserMessage"))
             .await;
@@ -47,6 +54,7 @@ where
         let response = ServerMessage::UserMessageResponse {
             success: false,
             error: Some(error_msg),
+            error_kind: None,
             is_away: None,
             status: None,
         };
@@ -54,7 +62,9 @@ where
     }
 
     // Validate message content
-    if let Err(e) = validators::validate_message(&message) {
+    if let Err(e) = validators::validate_multiline_message(&message) {
+        // Clients may split a message that's too long and send it again
+        let error_kind = (e == MessageError::TooLong).then(|| ErrorKind::MessageTooLong.into());
         let error_msg = match e {
             MessageError::Empty => err_message_empty(ctx.locale),
             MessageError::TooLong => err_chat_too_long(ctx.locale, validators::MAX_MESSAGE_LENGTH),
@@ -64,6 +74,20 @@ where
         let response = ServerMessage::UserMessageResponse {
             success: false,
             error: Some(error_msg),
+            error_kind,
+            is_away: None,
+            status: None,
+        };
+        return ctx.send_message(&response).await;
+    }
+
+    if let Some(key) = &idempotency_key
+        && validators::validate_idempotency_key(key).is_err()
+    {
+        let response = ServerMessage::UserMessageResponse {
+            success: false,
+            error: Some(err_idempotency_key_invalid(ctx.locale)),
+            error_kind: None,
             is_away: None,
             status: None,
         };
@@ -88,6 +112,7 @@ where
         let response = ServerMessage::UserMessageResponse {
             success: false,
             error: Some(err_cannot_message_self(ctx.locale)),
+            error_kind: None,
             is_away: None,
             status: None,
         };
@@ -96,13 +121,15 @@ where
 
     // Check UserMessage permission (uses cached permissions, admin bypass built-in)
     if !requesting_user_session.has_permission(Permission::UserMessage) {
-        eprintln!(
+        log_warn!(
             "UserMessage from {} (user: {}) without permission",
-            ctx.peer_addr, requesting_user_session.username
+            ctx.peer_addr,
+            requesting_user_session.username
         );
         let response = ServerMessage::UserMessageResponse {
             success: false,
             error: Some(err_permission_denied(ctx.locale)),
+            error_kind: None,
             is_away: None,
             status: None,
         };
@@ -117,6 +144,7 @@ where
             let response = ServerMessage::UserMessageResponse {
                 success: false,
                 error: Some(err_nickname_not_online(ctx.locale, &to_nickname)),
+                error_kind: None,
                 is_away: None,
                 status: None,
             };
@@ -124,19 +152,38 @@ where
         }
     };
 
+    // A message sent again after a dropped connection is acknowledged, not
+    // delivered twice
+    if let Some(key) = &idempotency_key
+        && !ctx
+            .idempotency_keys
+            .first_use(&requesting_user_session.nickname, key)
+    {
+        let response = ServerMessage::UserMessageResponse {
+            success: true,
+            error: None,
+            error_kind: None,
+            is_away: None,
+            status: None,
+        };
+        return ctx.send_message(&response).await;
+    }
+
     // Build the message to broadcast
     let timestamp = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .map(|d| d.as_secs())
         .unwrap_or(0);
+    let message_id = NEXT_MESSAGE_ID.fetch_add(1, Ordering::Relaxed);
     let broadcast = ServerMessage::UserMessage {
         from_nickname: requesting_user_session.nickname.clone(),
         from_admin: requesting_user_session.is_admin,
         from_shared: requesting_user_session.is_shared,
         to_nickname: target_session.nickname.clone(),
-        message,
+        message: message.clone(),
         action,
         timestamp,
+        id: Some(message_id),
     };
 
     // Send to sender's session(s) by nickname
@@ -151,12 +198,33 @@ where
         .broadcast_to_nickname(&target_session.nickname, &broadcast)
         .await;
 
+    // The message is on its way to the receiver: tell the sender
+    let delivered = ServerMessage::MessageDelivered {
+        nickname: target_session.nickname.clone(),
+        id: message_id,
+    };
+    ctx.user_manager
+        .broadcast_to_nickname(&requesting_user_session.nickname, &delivered)
+        .await;
+    ctx.stats.record_message(requesting_user_session.db_user_id);
+    ctx.user_manager.touch_activity(session_id).await;
+
+    // Keep a copy for each party that has message history on
+    store_history(
+        &ctx.db.message_history,
+        &requesting_user_session,
+        &target_session,
+        &message,
+        action,
+    );
+
     // Send success response to sender via channel AFTER message broadcasts
     // Using the channel ensures proper ordering - the response will be queued
     // after the UserMessage broadcast, so the away notice appears after the message
     let response = ServerMessage::UserMessageResponse {
         success: true,
         error: None,
+        error_kind: None,
         is_away: if target_session.is_away {
             Some(true)
         } else {
@@ -171,6 +239,34 @@ where
     ctx.send_message_via_channel(&response)
 }
 
+/// Queue a delivered message for the history of each party that keeps it
+///
+/// Shared accounts have no history of their own. Failures are logged and
+/// never affect delivery.
+fn store_history(
+    history: &MessageHistoryDb,
+    sender: &UserSession,
+    target: &UserSession,
+    message: &str,
+    action: ChatAction,
+) {
+    for (owner, peer) in [(sender, target), (target, sender)] {
+        if owner.is_shared {
+            continue;
+        }
+        if let Err(e) = history.store_later(
+            owner.db_user_id,
+            &peer.nickname,
+            &sender.nickname,
+            &target.nickname,
+            message,
+            action,
+        ) {
+            log_error!("Error queueing message history: {}", e);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -189,6 +285,7 @@ mod tests {
             "hello".to_string(),
             ChatAction::Normal,
             None,
+            None,
             &mut test_ctx.handler_context(),
         )
         .await;
@@ -218,6 +315,7 @@ mod tests {
             "target".to_string(),
             "hello".to_string(),
             ChatAction::Normal,
+            None,
             Some(1), // sender's session_id
             &mut test_ctx.handler_context(),
         )
@@ -254,6 +352,7 @@ mod tests {
             "target".to_string(),
             "   ".to_string(),
             ChatAction::Normal,
+            None,
             Some(1), // sender's session_id
             &mut test_ctx.handler_context(),
         )
@@ -290,6 +389,7 @@ mod tests {
             "target".to_string(),
             long_message,
             ChatAction::Normal,
+            None,
             Some(1), // sender's session_id
             &mut test_ctx.handler_context(),
         )
@@ -299,9 +399,18 @@ mod tests {
 
         let response = read_server_message(&mut test_ctx).await;
         match response {
-            ServerMessage::UserMessageResponse { success, error, .. } => {
+            ServerMessage::UserMessageResponse {
+                success,
+                error,
+                error_kind,
+                ..
+            } => {
                 assert!(!success);
                 assert!(error.unwrap().contains("too long"));
+                assert_eq!(
+                    error_kind.as_deref(),
+                    Some(ErrorKind::MessageTooLong.as_str())
+                );
             }
             _ => panic!("Expected UserMessageResponse"),
         }
@@ -324,6 +433,7 @@ mod tests {
             "sender".to_string(),
             "hello".to_string(),
             ChatAction::Normal,
+            None,
             Some(1), // sender's session_id
             &mut test_ctx.handler_context(),
         )
@@ -358,6 +468,7 @@ mod tests {
             "nonexistent".to_string(),
             "hello".to_string(),
             ChatAction::Normal,
+            None,
             Some(1), // sender's session_id
             &mut test_ctx.handler_context(),
         )
@@ -403,6 +514,7 @@ mod tests {
             "target".to_string(),
             "hello".to_string(),
             ChatAction::Normal,
+            None,
             Some(1), // sender's session_id
             &mut test_ctx.handler_context(),
         )
@@ -449,6 +561,7 @@ mod tests {
             "target".to_string(),
             "hello world".to_string(),
             ChatAction::Normal,
+            None,
             Some(1), // sender's session_id
             &mut test_ctx.handler_context(),
         )
@@ -470,6 +583,169 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_usermessage_reports_delivery() {
+        let mut test_ctx = create_test_context().await;
+        let sender_id = login_user(
+            &mut test_ctx,
+            "sender",
+            "pass123",
+            &[Permission::UserMessage],
+            false,
+        )
+        .await;
+        let _target_id = login_user(&mut test_ctx, "target", "pass456", &[], false).await;
+
+        handle_user_message(
+            "target".to_string(),
+            "hello".to_string(),
+            ChatAction::Normal,
+            None,
+            Some(sender_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        let message_id = match read_channel_response(&mut test_ctx, |msg| {
+            matches!(msg, ServerMessage::UserMessage { .. })
+        }) {
+            ServerMessage::UserMessage { id, .. } => id.expect("message should have an ID"),
+            _ => panic!("Expected UserMessage"),
+        };
+        match read_channel_response(&mut test_ctx, |msg| {
+            matches!(msg, ServerMessage::MessageDelivered { .. })
+        }) {
+            ServerMessage::MessageDelivered { nickname, id } => {
+                assert_eq!(nickname, "target");
+                assert_eq!(id, message_id);
+            }
+            _ => panic!("Expected MessageDelivered"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_usermessage_idempotency_key() {
+        let mut test_ctx = create_test_context().await;
+
+        let sender_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "pass123",
+            &[Permission::UserMessage],
+            false,
+        )
+        .await;
+        let _target_id = login_user(&mut test_ctx, "target", "pass456", &[], false).await;
+        while test_ctx.rx.try_recv().is_ok() {}
+
+        // Sent, then sent again after a dropped connection
+        for _ in 0..2 {
+            handle_user_message(
+                "target".to_string(),
+                "hello".to_string(),
+                ChatAction::Normal,
+                Some("pm-retry-1".to_string()),
+                Some(sender_id),
+                &mut test_ctx.handler_context(),
+            )
+            .await
+            .unwrap();
+        }
+
+        // Delivered once
+        let mut delivered = 0;
+        while let Ok((msg, _)) = test_ctx.rx.try_recv() {
+            if matches!(msg, ServerMessage::MessageDelivered { .. }) {
+                delivered += 1;
+            }
+        }
+        assert_eq!(delivered, 1);
+
+        // The repeat is still acknowledged
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::UserMessageResponse { success, error, .. } => {
+                assert!(success);
+                assert!(error.is_none());
+            }
+            other => panic!("Expected UserMessageResponse, got: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_usermessage_stored_for_parties_with_history() {
+        let mut test_ctx = create_test_context().await;
+        let _sender_id = login_user(
+            &mut test_ctx,
+            "sender",
+            "pass123",
+            &[Permission::UserMessage],
+            false,
+        )
+        .await;
+        let _target_id = login_user(
+            &mut test_ctx,
+            "target",
+            "pass456",
+            &[Permission::UserMessage],
+            false,
+        )
+        .await;
+
+        // Only the sender keeps history
+        let sender = test_ctx
+            .db
+            .users
+            .get_user_by_username("sender")
+            .await
+            .unwrap()
+            .unwrap();
+        let target = test_ctx
+            .db
+            .users
+            .get_user_by_username("target")
+            .await
+            .unwrap()
+            .unwrap();
+        test_ctx
+            .db
+            .message_history
+            .set_retention_days(sender.id, 7)
+            .await
+            .unwrap();
+
+        let result = handle_user_message(
+            "target".to_string(),
+            "hello world".to_string(),
+            ChatAction::Me,
+            None,
+            Some(1), // sender's session_id
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let page = test_ctx
+            .db
+            .message_history
+            .fetch(sender.id, Some("target"), None, 10)
+            .await
+            .unwrap();
+        assert_eq!(page.messages.len(), 1);
+        assert_eq!(page.messages[0].message, "hello world");
+        assert_eq!(page.messages[0].action, ChatAction::Me);
+        assert_eq!(page.messages[0].from_nickname, "sender");
+        assert_eq!(page.messages[0].to_nickname, "target");
+
+        let page = test_ctx
+            .db
+            .message_history
+            .fetch(target.id, None, None, 10)
+            .await
+            .unwrap();
+        assert!(page.messages.is_empty());
+    }
+
     #[tokio::test]
     async fn test_usermessage_admin_has_permission() {
         let mut test_ctx = create_test_context().await;
@@ -492,6 +768,7 @@ mod tests {
             "target".to_string(),
             "admin message".to_string(),
             ChatAction::Normal,
+            None,
             Some(1), // admin's session_id
             &mut test_ctx.handler_context(),
         )
@@ -552,6 +829,11 @@ mod tests {
             avatar: None,
             nickname: Some("Nick1".to_string()),
             handshake_complete: true,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let _ = handle_login(
             login_request,
@@ -566,6 +848,7 @@ mod tests {
             "Nick1".to_string(),
             "Hello Nick1!".to_string(),
             ChatAction::Normal,
+            None,
             Some(admin_id),
             &mut test_ctx.handler_context(),
         )
@@ -620,6 +903,11 @@ mod tests {
             avatar: None,
             nickname: Some("Nick1".to_string()),
             handshake_complete: true,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let _ = handle_login(
             login_request,
@@ -636,6 +924,7 @@ mod tests {
             "Nick1".to_string(),
             "Message to myself".to_string(),
             ChatAction::Normal,
+            None,
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -683,6 +972,11 @@ mod tests {
             avatar: None,
             nickname: Some("Sender".to_string()),
             handshake_complete: true,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let _ = handle_login(
             login_request,
@@ -699,6 +993,7 @@ mod tests {
             "target".to_string(),
             "Hello from shared!".to_string(),
             ChatAction::Normal,
+            None,
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -751,6 +1046,7 @@ mod tests {
             "Nick1".to_string(),
             "hello".to_string(),
             ChatAction::Normal,
+            None,
             Some(1), // sender's session_id
             &mut test_ctx.handler_context(),
         )
@@ -28,7 +28,7 @@ where
 {
     // Verify authentication first
     let Some(session_id) = session_id else {
-        eprintln!("UserStatus request from {} without login", ctx.peer_addr);
+        log_warn!("UserStatus request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("UserStatus"))
             .await;
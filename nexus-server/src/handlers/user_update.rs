@@ -4,9 +4,9 @@ use std::io;
 
 use tokio::io::AsyncWrite;
 
-use nexus_common::is_shared_account_permission;
 use nexus_common::protocol::{ServerInfo, ServerMessage, UserInfo};
 use nexus_common::validators::{self, PasswordError, PermissionsError, UsernameError};
+use nexus_common::{is_guest_account_permission, is_shared_account_permission};
 
 use crate::constants::DEFAULT_LOCALE;
 
@@ -26,7 +26,7 @@ use super::{
 };
 use crate::db::sql::GUEST_USERNAME;
 use crate::db::{Permission, Permissions, hash_password, verify_password};
-use crate::voice::send_voice_leave_notifications;
+use crate::voice::{leave_voice_queue, send_voice_leave_notifications};
 
 /// User update request parameters
 pub struct UserUpdateRequest {
@@ -50,7 +50,7 @@ where
 {
     // Verify authentication first (before revealing validation errors to unauthenticated users)
     let Some(requesting_session_id) = request.session_id else {
-        eprintln!("UserUpdate request from {} without login", ctx.peer_addr);
+        log_warn!("UserUpdate request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("UserUpdate"))
             .await;
@@ -138,7 +138,7 @@ where
                 return ctx.send_message(&response).await;
             }
             Err(e) => {
-                eprintln!("Database error getting user: {}", e);
+                log_error!("Database error getting user: {}", e);
                 return ctx
                     .send_error_and_disconnect(&err_database(ctx.locale), Some("UserUpdate"))
                     .await;
@@ -157,7 +157,7 @@ where
                 return ctx.send_message(&response).await;
             }
             Err(e) => {
-                eprintln!("Error verifying password: {}", e);
+                log_error!("Error verifying password: {}", e);
                 return ctx
                     .send_error_and_disconnect(&err_database(ctx.locale), Some("UserUpdate"))
                     .await;
@@ -166,9 +166,10 @@ where
     } else {
         // Editing another user: check UserEdit permission
         if !requesting_user.has_permission(Permission::UserEdit) {
-            eprintln!(
+            log_warn!(
                 "UserUpdate from {} (user: {}) without permission",
-                ctx.peer_addr, requesting_user.username
+                ctx.peer_addr,
+                requesting_user.username
             );
             return ctx
                 .send_error(&err_permission_denied(ctx.locale), Some("UserUpdate"))
@@ -180,9 +181,10 @@ where
         if !requesting_user.is_admin {
             match ctx.db.users.get_user_by_username(&request.username).await {
                 Ok(Some(target_user)) if target_user.is_admin => {
-                    eprintln!(
+                    log_warn!(
                         "UserUpdate from {} (user: {}) trying to edit admin user",
-                        ctx.peer_addr, requesting_user.username
+                        ctx.peer_addr,
+                        requesting_user.username
                     );
                     let response = ServerMessage::UserUpdateResponse {
                         success: false,
@@ -201,7 +203,7 @@ where
                     return ctx.send_message(&response).await;
                 }
                 Err(e) => {
-                    eprintln!("Database error getting target user: {}", e);
+                    log_error!("Database error getting target user: {}", e);
                     return ctx
                         .send_error_and_disconnect(&err_database(ctx.locale), Some("UserUpdate"))
                         .await;
@@ -278,7 +280,7 @@ where
             return ctx.send_message(&response).await;
         }
         Err(e) => {
-            eprintln!("Database error getting target user: {}", e);
+            log_error!("Database error getting target user: {}", e);
             return ctx
                 .send_error_and_disconnect(&err_database(ctx.locale), Some("UserUpdate"))
                 .await;
@@ -310,6 +312,29 @@ where
             }
         }
 
+        // The guest account is held to a narrower set than other shared accounts
+        if let Some(ref account) = target_user_account
+            && account.username.eq_ignore_ascii_case(GUEST_USERNAME)
+        {
+            let forbidden: Vec<&str> = perm_strings
+                .iter()
+                .map(|s| s.as_str())
+                .filter(|p| !is_guest_account_permission(p))
+                .collect();
+
+            if !forbidden.is_empty() {
+                let response = ServerMessage::UserUpdateResponse {
+                    success: false,
+                    error: Some(err_guest_invalid_permissions(
+                        ctx.locale,
+                        &forbidden.join(", "),
+                    )),
+                    username: None,
+                };
+                return ctx.send_message(&response).await;
+            }
+        }
+
         // Validate permissions format first
         if let Err(e) = validators::validate_permissions(perm_strings) {
             let error_msg = match e {
@@ -339,9 +364,11 @@ where
             if let Some(perm) = Permission::parse(perm_str) {
                 // Check permission delegation authority (uses cached permissions, admin bypass built-in)
                 if !requesting_user.has_permission(perm) {
-                    eprintln!(
+                    log_warn!(
                         "UserUpdate from {} (user: {}) trying to set permission they don't have: {}",
-                        ctx.peer_addr, requesting_user.username, perm_str
+                        ctx.peer_addr,
+                        requesting_user.username,
+                        perm_str
                     );
                     return ctx
                         .send_error(&err_permission_denied(ctx.locale), Some("UserUpdate"))
@@ -350,7 +377,7 @@ where
 
                 perms.permissions.insert(perm);
             } else {
-                eprintln!("Warning: unknown permission '{}'", perm_str);
+                log_warn!("Warning: unknown permission '{}'", perm_str);
             }
         }
 
@@ -416,7 +443,7 @@ where
             match hash_password(password, false) {
                 Ok(hash) => Some(hash),
                 Err(e) => {
-                    eprintln!("Database error updating user {}: {}", request.username, e);
+                    log_error!("Database error updating user {}: {}", request.username, e);
                     return ctx
                         .send_error_and_disconnect(&err_database(ctx.locale), Some("UserUpdate"))
                         .await;
@@ -487,6 +514,12 @@ where
             if let Ok(Some(updated_account)) =
                 ctx.db.users.get_user_by_username(&final_username).await
             {
+                // A new password or a disabled account ends token logins on other sessions
+                if requested_password_hash.is_some() || request.requested_enabled == Some(false) {
+                    ctx.login_tokens
+                        .revoke_account(updated_account.id, Some(requesting_session_id));
+                }
+
                 // Get the final permissions
                 if let Ok(final_permissions) =
                     ctx.db.users.get_user_permissions(updated_account.id).await
@@ -570,11 +603,29 @@ where
                                     send_voice_leave_notifications(
                                         &info,
                                         leaving_user_tx.as_ref(),
+                                        ctx.voice_registry,
                                         ctx.user_manager,
                                         ctx.channel_manager,
                                     )
                                     .await;
                                 }
+
+                                // Also drop any queued voice join, telling the user it was cancelled
+                                if let Some(channel) = leave_voice_queue(
+                                    session_id,
+                                    ctx.voice_registry,
+                                    ctx.user_manager,
+                                )
+                                .await
+                                {
+                                    let cancelled = ServerMessage::VoiceQueueUpdate {
+                                        target: channel,
+                                        position: None,
+                                    };
+                                    ctx.user_manager
+                                        .send_to_session(session_id, cancelled)
+                                        .await;
+                                }
                             }
                         }
                     }
@@ -613,6 +664,7 @@ where
                             let disconnect_msg = ServerMessage::Error {
                                 message: err_account_disabled_by_admin(&user.locale),
                                 command: None,
+                                error_kind: None,
                             };
                             let _ = user.tx.send((disconnect_msg, None));
 
@@ -669,33 +721,35 @@ where
 
                     // Get earliest login time, locale, and avatar from all sessions
                     // Avatar uses "latest login wins"
-                    let (login_time, locale, avatar) = if !session_ids.is_empty() {
-                        let user_sessions = ctx
-                            .user_manager
-                            .get_sessions_by_username(&updated_account.username)
-                            .await;
+                    let (login_time, locale, avatar, last_active, client) =
+                        if !session_ids.is_empty() {
+                            let user_sessions = ctx
+                                .user_manager
+                                .get_sessions_by_username(&updated_account.username)
+                                .await;
 
-                        let login_time = user_sessions
-                            .iter()
-                            .map(|u| u.login_time)
-                            .min()
-                            .unwrap_or(0);
+                            let login_time = user_sessions
+                                .iter()
+                                .map(|u| u.login_time)
+                                .min()
+                                .unwrap_or(0);
 
-                        let locale = user_sessions
-                            .first()
-                            .map(|u| u.locale.clone())
-                            .unwrap_or_else(|| DEFAULT_LOCALE.to_string());
+                            let locale = user_sessions
+                                .first()
+                                .map(|u| u.locale.clone())
+                                .unwrap_or_else(|| DEFAULT_LOCALE.to_string());
 
-                        // Avatar from most recent login
-                        let avatar = user_sessions
-                            .iter()
-                            .max_by_key(|u| u.login_time)
-                            .and_then(|u| u.avatar.clone());
+                            // Avatar and client from most recent login
+                            let newest = user_sessions.iter().max_by_key(|u| u.login_time);
+                            let avatar = newest.and_then(|u| u.avatar.clone());
+                            let client = newest.and_then(|u| u.client.clone());
 
-                        (login_time, locale, avatar)
-                    } else {
-                        (0, DEFAULT_LOCALE.to_string(), None) // User not currently online
-                    };
+                            let last_active = user_sessions.iter().map(|u| u.last_active).max();
+
+                            (login_time, locale, avatar, last_active, client)
+                        } else {
+                            (0, DEFAULT_LOCALE.to_string(), None, None, None) // User not currently online
+                        };
 
                     let user_info = UserInfo {
                         username: updated_account.username.clone(),
@@ -710,6 +764,9 @@ where
                         avatar,
                         is_away: false,
                         status: None,
+                        is_dnd: false,
+                        last_active,
+                        client,
                     };
 
                     let user_updated = ServerMessage::UserUpdated {
@@ -770,7 +827,7 @@ where
             ctx.send_message(&response).await
         }
         Err(e) => {
-            eprintln!("Database error updating user: {}", e);
+            log_error!("Database error updating user: {}", e);
             return ctx
                 .send_error_and_disconnect(&err_database(ctx.locale), Some("UserUpdate"))
                 .await;
@@ -1814,6 +1871,7 @@ mod tests {
                 nickname: "editor".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .expect("Failed to add user");
@@ -2027,6 +2085,7 @@ mod tests {
                 nickname: "editor".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .expect("Failed to add user");
@@ -2049,6 +2108,7 @@ mod tests {
                 nickname: "admin".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .expect("Failed to add user");
@@ -2181,6 +2241,11 @@ mod tests {
             avatar: None,
             nickname: Some("Alice".to_string()),
             handshake_complete: true,
+            pow_challenge: None,
+            pow_nonce: None,
+            oidc: None,
+            login_token: None,
+            client: None,
         };
         let login_result = crate::handlers::handle_login(
             login_request,
@@ -2372,6 +2437,7 @@ mod tests {
                 nickname: "admin".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .unwrap();
@@ -2441,6 +2507,7 @@ mod tests {
                 nickname: "admin".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .unwrap();
@@ -2506,6 +2573,7 @@ mod tests {
                 nickname: "admin".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .unwrap();
@@ -2539,6 +2607,36 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_userupdate_guest_account_forbidden_permissions() {
+        let mut test_ctx = create_test_context().await;
+        let admin_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        // user_message is allowed for shared accounts, but not for the guest account
+        let request = UserUpdateRequest {
+            username: "guest".to_string(),
+            current_password: None,
+            requested_username: None,
+            requested_password: None,
+            requested_is_admin: None,
+            requested_enabled: None,
+            requested_permissions: Some(vec!["chat_send".to_string(), "user_message".to_string()]),
+            session_id: Some(admin_id),
+        };
+        let result = handle_user_update(request, &mut test_ctx.handler_context()).await;
+        assert!(result.is_ok(), "Handler should return Ok");
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::UserUpdateResponse { success, error, .. } => {
+                assert!(!success, "Should reject permissions guests can't have");
+                let error = error.expect("Should have an error");
+                assert!(error.contains("user_message"));
+                assert!(!error.contains("chat_send"));
+            }
+            _ => panic!("Expected UserUpdateResponse"),
+        }
+    }
+
     #[tokio::test]
     async fn test_userupdate_cannot_change_guest_password() {
         use crate::handlers::testing::read_server_message;
@@ -2574,6 +2672,7 @@ mod tests {
                 nickname: "admin".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .unwrap();
@@ -2640,6 +2739,7 @@ mod tests {
                 nickname: "bob".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .unwrap();
@@ -2716,6 +2816,7 @@ mod tests {
                 nickname: "bob".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .unwrap();
@@ -2803,6 +2904,7 @@ mod tests {
                 nickname: "bob".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .unwrap();
@@ -2876,6 +2978,7 @@ mod tests {
                 nickname: "bob".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .unwrap();
@@ -2961,6 +3064,7 @@ mod tests {
                 nickname: "bob".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .unwrap();
@@ -3049,6 +3153,7 @@ mod tests {
                 nickname: "bob".to_string(),
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .unwrap();
@@ -3127,6 +3232,7 @@ mod tests {
                 avatar: None,
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .expect("Failed to add voice user session");
@@ -3232,6 +3338,7 @@ mod tests {
                 avatar: None,
                 is_away: false,
                 status: None,
+                client: None,
             })
             .await
             .expect("Failed to add voice user session");
@@ -0,0 +1,246 @@
+//! Handler for VoiceClipDelete command - remove a clip from the soundboard library
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+
+use super::{
+    HandlerContext, err_authentication, err_database, err_not_logged_in, err_permission_denied,
+    err_voice_clip_not_found,
+};
+use crate::db::Permission;
+
+/// Helper to create a failed VoiceClipDeleteResponse
+fn error_response(error: String) -> ServerMessage {
+    ServerMessage::VoiceClipDeleteResponse {
+        success: false,
+        error: Some(error),
+    }
+}
+
+/// Handle VoiceClipDelete command - remove a clip from the soundboard library
+///
+/// Users with voice_clip_upload permission can delete their own clips;
+/// admins can delete any clip.
+pub async fn handle_voice_clip_delete<W>(
+    name: String,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first
+    let Some(session_id) = session_id else {
+        log_warn!(
+            "VoiceClipDelete request from {} without login",
+            ctx.peer_addr
+        );
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("VoiceClipDelete"))
+            .await;
+    };
+
+    // Get user from session
+    let user = match ctx.user_manager.get_user_by_session_id(session_id).await {
+        Some(u) => u,
+        None => {
+            return ctx
+                .send_error_and_disconnect(&err_authentication(ctx.locale), Some("VoiceClipDelete"))
+                .await;
+        }
+    };
+
+    // Check VoiceClipUpload permission (admins have all permissions)
+    if !user.has_permission(Permission::VoiceClipUpload) {
+        log_warn!(
+            "VoiceClipDelete from {} (user: {}) without permission",
+            ctx.peer_addr,
+            user.username
+        );
+        return ctx
+            .send_message(&error_response(err_permission_denied(ctx.locale)))
+            .await;
+    }
+
+    let clip = match ctx.db.voice_clips.get_clip(&name).await {
+        Ok(Some(clip)) => clip,
+        Ok(None) => {
+            return ctx
+                .send_message(&error_response(err_voice_clip_not_found(ctx.locale, &name)))
+                .await;
+        }
+        Err(e) => {
+            log_error!("Database error fetching voice clip: {}", e);
+            return ctx
+                .send_message(&error_response(err_database(ctx.locale)))
+                .await;
+        }
+    };
+
+    // Only the uploader or an admin may delete a clip
+    if clip.uploader_id != user.db_user_id && !user.is_admin {
+        log_warn!(
+            "VoiceClipDelete from {} (user: {}) for another user's clip '{}'",
+            ctx.peer_addr,
+            user.username,
+            clip.name
+        );
+        return ctx
+            .send_message(&error_response(err_permission_denied(ctx.locale)))
+            .await;
+    }
+
+    match ctx.db.voice_clips.delete_clip(&clip.name).await {
+        Ok(true) => {}
+        Ok(false) => {
+            // Deleted by someone else in the meantime
+            return ctx
+                .send_message(&error_response(err_voice_clip_not_found(ctx.locale, &name)))
+                .await;
+        }
+        Err(e) => {
+            log_error!("Database error deleting voice clip: {}", e);
+            return ctx
+                .send_message(&error_response(err_database(ctx.locale)))
+                .await;
+        }
+    }
+
+    let response = ServerMessage::VoiceClipDeleteResponse {
+        success: true,
+        error: None,
+    };
+    ctx.send_message(&response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::MAX_VOICE_CLIPS;
+    use crate::handlers::testing::{
+        DEFAULT_TEST_LOCALE, TestContext, create_test_context, login_user, read_server_message,
+        voice_clip_data_uri,
+    };
+
+    /// Store a clip uploaded by `username`
+    async fn add_clip(test_ctx: &TestContext, name: &str, username: &str) {
+        let user = test_ctx
+            .db
+            .users
+            .get_user_by_username(username)
+            .await
+            .unwrap()
+            .unwrap();
+        test_ctx
+            .db
+            .voice_clips
+            .create_clip(
+                name,
+                &voice_clip_data_uri(1000),
+                128,
+                1000,
+                user.id,
+                MAX_VOICE_CLIPS as i64,
+            )
+            .await
+            .unwrap();
+    }
+
+    /// Delete a clip and return the response's (success, error)
+    async fn delete(
+        test_ctx: &mut TestContext,
+        session_id: u32,
+        name: &str,
+    ) -> (bool, Option<String>) {
+        handle_voice_clip_delete(
+            name.to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        match read_server_message(test_ctx).await {
+            ServerMessage::VoiceClipDeleteResponse { success, error } => (success, error),
+            other => panic!("Expected VoiceClipDeleteResponse, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_voice_clip_delete_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result =
+            handle_voice_clip_delete("airhorn".to_string(), None, &mut test_ctx.handler_context())
+                .await;
+
+        assert!(result.is_err(), "Should disconnect unauthenticated user");
+    }
+
+    #[tokio::test]
+    async fn test_voice_clip_delete_own_clip() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::VoiceClipUpload],
+            false,
+        )
+        .await;
+        add_clip(&test_ctx, "airhorn", "alice").await;
+
+        let (success, error) = delete(&mut test_ctx, session_id, "AIRHORN").await;
+        assert!(success);
+        assert!(error.is_none());
+
+        let (success, error) = delete(&mut test_ctx, session_id, "airhorn").await;
+        assert!(!success);
+        assert_eq!(
+            error,
+            Some(err_voice_clip_not_found(DEFAULT_TEST_LOCALE, "airhorn"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_voice_clip_delete_other_users_clip() {
+        let mut test_ctx = create_test_context().await;
+        login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::VoiceClipUpload],
+            false,
+        )
+        .await;
+        let bob = login_user(
+            &mut test_ctx,
+            "bob",
+            "password",
+            &[Permission::VoiceClipUpload],
+            false,
+        )
+        .await;
+        let admin = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+        add_clip(&test_ctx, "airhorn", "alice").await;
+
+        // Another regular user cannot delete it
+        let (success, error) = delete(&mut test_ctx, bob, "airhorn").await;
+        assert!(!success);
+        assert_eq!(error, Some(err_permission_denied(DEFAULT_TEST_LOCALE)));
+
+        // An admin can
+        let (success, _) = delete(&mut test_ctx, admin, "airhorn").await;
+        assert!(success);
+
+        // Without the permission, not even own clips can be deleted
+        let carol = login_user(&mut test_ctx, "carol", "password", &[], false).await;
+        add_clip(&test_ctx, "rimshot", "carol").await;
+        let (success, error) = delete(&mut test_ctx, carol, "rimshot").await;
+        assert!(!success);
+        assert_eq!(error, Some(err_permission_denied(DEFAULT_TEST_LOCALE)));
+    }
+}
@@ -0,0 +1,171 @@
+//! Handler for VoiceClipList command - list the soundboard clip library
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::{ServerMessage, VoiceClipInfo};
+
+use super::{
+    HandlerContext, err_authentication, err_database, err_not_logged_in, err_permission_denied,
+};
+use crate::db::Permission;
+
+/// Handle VoiceClipList command - list the soundboard clip library
+///
+/// Requires voice_listen permission. Clip audio is not included; it is sent
+/// with `VoiceClipPlayed` when a clip is played.
+pub async fn handle_voice_clip_list<W>(
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first
+    let Some(session_id) = session_id else {
+        log_warn!("VoiceClipList request from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("VoiceClipList"))
+            .await;
+    };
+
+    // Get user from session
+    let user = match ctx.user_manager.get_user_by_session_id(session_id).await {
+        Some(u) => u,
+        None => {
+            return ctx
+                .send_error_and_disconnect(&err_authentication(ctx.locale), Some("VoiceClipList"))
+                .await;
+        }
+    };
+
+    // Check voice_listen permission
+    if !user.has_permission(Permission::VoiceListen) {
+        let response = ServerMessage::VoiceClipListResponse {
+            success: false,
+            error: Some(err_permission_denied(ctx.locale)),
+            clips: None,
+        };
+        return ctx.send_message(&response).await;
+    }
+
+    let records = match ctx.db.voice_clips.get_all_clips().await {
+        Ok(records) => records,
+        Err(e) => {
+            log_error!("Database error listing voice clips: {}", e);
+            let response = ServerMessage::VoiceClipListResponse {
+                success: false,
+                error: Some(err_database(ctx.locale)),
+                clips: None,
+            };
+            return ctx.send_message(&response).await;
+        }
+    };
+
+    let clips = records
+        .into_iter()
+        .map(|record| VoiceClipInfo {
+            name: record.name,
+            uploaded_by: record.uploader_username,
+            size: record.size.max(0) as u64,
+            duration_ms: record.duration_ms.max(0) as u64,
+        })
+        .collect();
+
+    let response = ServerMessage::VoiceClipListResponse {
+        success: true,
+        error: None,
+        clips: Some(clips),
+    };
+    ctx.send_message(&response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::MAX_VOICE_CLIPS;
+    use crate::handlers::testing::{
+        create_test_context, login_user, read_server_message, voice_clip_data_uri,
+    };
+
+    #[tokio::test]
+    async fn test_voice_clip_list_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_voice_clip_list(None, &mut test_ctx.handler_context()).await;
+
+        assert!(result.is_err(), "Should disconnect unauthenticated user");
+    }
+
+    #[tokio::test]
+    async fn test_voice_clip_list_requires_permission() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        handle_voice_clip_list(Some(session_id), &mut test_ctx.handler_context())
+            .await
+            .unwrap();
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::VoiceClipListResponse { success, clips, .. } => {
+                assert!(!success);
+                assert!(clips.is_none());
+            }
+            other => panic!("Expected VoiceClipListResponse, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_voice_clip_list_success() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::VoiceListen],
+            false,
+        )
+        .await;
+
+        let user = test_ctx
+            .db
+            .users
+            .get_user_by_username("alice")
+            .await
+            .unwrap()
+            .unwrap();
+        let data = voice_clip_data_uri(1500);
+        test_ctx
+            .db
+            .voice_clips
+            .create_clip("airhorn", &data, 128, 1500, user.id, MAX_VOICE_CLIPS as i64)
+            .await
+            .unwrap();
+
+        handle_voice_clip_list(Some(session_id), &mut test_ctx.handler_context())
+            .await
+            .unwrap();
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::VoiceClipListResponse {
+                success,
+                error,
+                clips,
+            } => {
+                assert!(success);
+                assert!(error.is_none());
+                assert_eq!(
+                    clips,
+                    Some(vec![VoiceClipInfo {
+                        name: "airhorn".to_string(),
+                        uploaded_by: "alice".to_string(),
+                        size: 128,
+                        duration_ms: 1500,
+                    }])
+                );
+            }
+            other => panic!("Expected VoiceClipListResponse, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,276 @@
+//! Handler for VoiceClipPlay command - play a soundboard clip in voice
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+
+use super::{
+    HandlerContext, err_authentication, err_database, err_not_logged_in, err_permission_denied,
+    err_voice_clip_not_found, err_voice_not_joined,
+};
+use crate::db::Permission;
+
+/// Helper to create a failed VoiceClipPlayResponse
+fn error_response(error: String) -> ServerMessage {
+    ServerMessage::VoiceClipPlayResponse {
+        success: false,
+        error: Some(error),
+    }
+}
+
+/// Handle VoiceClipPlay command - play a soundboard clip in voice
+///
+/// Requires voice_talk permission and an active voice session that isn't
+/// listening to a broadcast. The clip is sent as `VoiceClipPlayed` to every
+/// participant of the session, including the sender; clients play it locally
+/// at their own clip volume.
+pub async fn handle_voice_clip_play<W>(
+    name: String,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first
+    let Some(session_id) = session_id else {
+        log_warn!("VoiceClipPlay request from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("VoiceClipPlay"))
+            .await;
+    };
+
+    // Get user from session
+    let user = match ctx.user_manager.get_user_by_session_id(session_id).await {
+        Some(u) => u,
+        None => {
+            return ctx
+                .send_error_and_disconnect(&err_authentication(ctx.locale), Some("VoiceClipPlay"))
+                .await;
+        }
+    };
+
+    // Check voice_talk permission
+    if !user.has_permission(Permission::VoiceTalk) {
+        return ctx
+            .send_message(&error_response(err_permission_denied(ctx.locale)))
+            .await;
+    }
+
+    // Clips play into the user's current voice session
+    let Some(voice_session) = ctx.voice_registry.get_by_session_id(session_id).await else {
+        return ctx
+            .send_message(&error_response(err_voice_not_joined(ctx.locale)))
+            .await;
+    };
+
+    // Broadcast listeners only listen
+    if voice_session.is_listener() {
+        return ctx
+            .send_message(&error_response(err_permission_denied(ctx.locale)))
+            .await;
+    }
+
+    let (clip_name, data) = match ctx.db.voice_clips.get_clip_data(&name).await {
+        Ok(Some(clip)) => clip,
+        Ok(None) => {
+            return ctx
+                .send_message(&error_response(err_voice_clip_not_found(ctx.locale, &name)))
+                .await;
+        }
+        Err(e) => {
+            log_error!("Database error fetching voice clip: {}", e);
+            return ctx
+                .send_message(&error_response(err_database(ctx.locale)))
+                .await;
+        }
+    };
+
+    let response = ServerMessage::VoiceClipPlayResponse {
+        success: true,
+        error: None,
+    };
+    ctx.send_message(&response).await?;
+
+    let participants = ctx
+        .voice_registry
+        .get_sessions_for_target(&voice_session.target_key())
+        .await;
+
+    for participant in participants {
+        // Channels are named as-is; user message voice is named after the other user
+        let target = if voice_session.is_channel() {
+            voice_session.target[0].clone()
+        } else {
+            voice_session
+                .target
+                .iter()
+                .find(|nickname| !nickname.eq_ignore_ascii_case(&participant.nickname))
+                .cloned()
+                .unwrap_or_else(|| participant.nickname.clone())
+        };
+
+        let played = ServerMessage::VoiceClipPlayed {
+            nickname: voice_session.nickname.clone(),
+            target,
+            name: clip_name.clone(),
+            data: data.clone(),
+        };
+        ctx.user_manager
+            .send_to_session(participant.session_id, played)
+            .await;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{FEATURE_CHAT, MAX_VOICE_CLIPS};
+    use crate::handlers::chat_join::handle_chat_join;
+    use crate::handlers::testing::{
+        DEFAULT_TEST_LOCALE, TestContext, create_test_context, login_user,
+        login_user_with_features, read_channel_response, read_server_message, voice_clip_data_uri,
+    };
+    use crate::handlers::voice_join::handle_voice_join;
+
+    /// Log in alice, join #general and its voice, and return her session_id
+    async fn join_voice(test_ctx: &mut TestContext) -> u32 {
+        let session_id = login_user_with_features(
+            test_ctx,
+            "alice",
+            "password",
+            &[
+                Permission::VoiceListen,
+                Permission::VoiceTalk,
+                Permission::ChatJoin,
+                Permission::ChatCreate,
+            ],
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+
+        handle_chat_join(
+            "#general".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        let _ = read_server_message(test_ctx).await; // consume ChatJoinResponse
+
+        handle_voice_join(
+            "#general".to_string(),
+            false,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        let _ = read_server_message(test_ctx).await; // consume VoiceJoinResponse
+
+        session_id
+    }
+
+    async fn play(
+        test_ctx: &mut TestContext,
+        session_id: u32,
+        name: &str,
+    ) -> (bool, Option<String>) {
+        handle_voice_clip_play(
+            name.to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        match read_server_message(test_ctx).await {
+            ServerMessage::VoiceClipPlayResponse { success, error } => (success, error),
+            other => panic!("Expected VoiceClipPlayResponse, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_voice_clip_play_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result =
+            handle_voice_clip_play("airhorn".to_string(), None, &mut test_ctx.handler_context())
+                .await;
+
+        assert!(result.is_err(), "Should disconnect unauthenticated user");
+    }
+
+    #[tokio::test]
+    async fn test_voice_clip_play_requires_voice() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::VoiceTalk],
+            false,
+        )
+        .await;
+
+        let (success, error) = play(&mut test_ctx, session_id, "airhorn").await;
+
+        assert!(!success);
+        assert_eq!(error, Some(err_voice_not_joined(DEFAULT_TEST_LOCALE)));
+    }
+
+    #[tokio::test]
+    async fn test_voice_clip_play_success() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = join_voice(&mut test_ctx).await;
+
+        let (success, error) = play(&mut test_ctx, session_id, "airhorn").await;
+        assert!(!success);
+        assert_eq!(
+            error,
+            Some(err_voice_clip_not_found(DEFAULT_TEST_LOCALE, "airhorn"))
+        );
+
+        let user = test_ctx
+            .db
+            .users
+            .get_user_by_username("alice")
+            .await
+            .unwrap()
+            .unwrap();
+        let data = voice_clip_data_uri(1000);
+        test_ctx
+            .db
+            .voice_clips
+            .create_clip("airhorn", &data, 128, 1000, user.id, MAX_VOICE_CLIPS as i64)
+            .await
+            .unwrap();
+
+        let (success, error) = play(&mut test_ctx, session_id, "AIRHORN").await;
+        assert!(success);
+        assert!(error.is_none());
+
+        // The player hears the clip too
+        match read_channel_response(&mut test_ctx, |msg| {
+            matches!(msg, ServerMessage::VoiceClipPlayed { .. })
+        }) {
+            ServerMessage::VoiceClipPlayed {
+                nickname,
+                target,
+                name,
+                data: played_data,
+            } => {
+                assert_eq!(nickname, "alice");
+                assert_eq!(target, "#general");
+                assert_eq!(name, "airhorn");
+                assert_eq!(played_data, data);
+            }
+            other => panic!("Expected VoiceClipPlayed, got {:?}", other),
+        }
+    }
+}
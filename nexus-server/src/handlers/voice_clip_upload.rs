@@ -0,0 +1,378 @@
+//! Handler for VoiceClipUpload command - add a clip to the soundboard library
+
+use std::io;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+use nexus_common::validators::{self, VoiceClipDataError, VoiceClipNameError};
+use nexus_common::voice::{MAX_VOICE_CLIP_DURATION_MS, ogg_vorbis_duration_ms};
+
+use super::{
+    HandlerContext, err_authentication, err_database, err_not_logged_in, err_permission_denied,
+    err_voice_clip_exists, err_voice_clip_invalid_format, err_voice_clip_library_full,
+    err_voice_clip_name_empty, err_voice_clip_name_invalid, err_voice_clip_name_too_long,
+    err_voice_clip_too_large, err_voice_clip_too_long,
+};
+use crate::constants::MAX_VOICE_CLIPS;
+use crate::db::Permission;
+
+/// Helper to create a failed VoiceClipUploadResponse
+fn error_response(error: String) -> ServerMessage {
+    ServerMessage::VoiceClipUploadResponse {
+        success: false,
+        error: Some(error),
+    }
+}
+
+/// Handle VoiceClipUpload command - add a clip to the soundboard library
+///
+/// The audio must be Ogg Vorbis, at most `MAX_VOICE_CLIP_SIZE` bytes and
+/// `MAX_VOICE_CLIP_DURATION_MS` long. Clip names are unique (case-insensitive)
+/// and the library holds at most `MAX_VOICE_CLIPS` clips.
+pub async fn handle_voice_clip_upload<W>(
+    name: String,
+    data: String,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first
+    let Some(session_id) = session_id else {
+        log_warn!(
+            "VoiceClipUpload request from {} without login",
+            ctx.peer_addr
+        );
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("VoiceClipUpload"))
+            .await;
+    };
+
+    // Get user from session
+    let user = match ctx.user_manager.get_user_by_session_id(session_id).await {
+        Some(u) => u,
+        None => {
+            return ctx
+                .send_error_and_disconnect(&err_authentication(ctx.locale), Some("VoiceClipUpload"))
+                .await;
+        }
+    };
+
+    // Check VoiceClipUpload permission
+    if !user.has_permission(Permission::VoiceClipUpload) {
+        log_warn!(
+            "VoiceClipUpload from {} (user: {}) without permission",
+            ctx.peer_addr,
+            user.username
+        );
+        return ctx
+            .send_message(&error_response(err_permission_denied(ctx.locale)))
+            .await;
+    }
+
+    // Validate name
+    if let Err(e) = validators::validate_voice_clip_name(&name) {
+        let error_msg = match e {
+            VoiceClipNameError::Empty => err_voice_clip_name_empty(ctx.locale),
+            VoiceClipNameError::TooLong => {
+                err_voice_clip_name_too_long(ctx.locale, validators::MAX_VOICE_CLIP_NAME_LENGTH)
+            }
+            VoiceClipNameError::InvalidCharacters => err_voice_clip_name_invalid(ctx.locale),
+        };
+        return ctx.send_message(&error_response(error_msg)).await;
+    }
+
+    // Validate the data URI envelope
+    if let Err(e) = validators::validate_voice_clip_data(&data) {
+        let error_msg = match e {
+            VoiceClipDataError::TooLarge => err_voice_clip_too_large(ctx.locale),
+            VoiceClipDataError::InvalidFormat | VoiceClipDataError::UnsupportedType => {
+                err_voice_clip_invalid_format(ctx.locale)
+            }
+        };
+        return ctx.send_message(&error_response(error_msg)).await;
+    }
+
+    // Decode the audio to check its real size and duration
+    let Some(audio) = data
+        .split_once(";base64,")
+        .and_then(|(_, encoded)| BASE64.decode(encoded).ok())
+    else {
+        return ctx
+            .send_message(&error_response(err_voice_clip_invalid_format(ctx.locale)))
+            .await;
+    };
+
+    if audio.len() > validators::MAX_VOICE_CLIP_SIZE {
+        return ctx
+            .send_message(&error_response(err_voice_clip_too_large(ctx.locale)))
+            .await;
+    }
+
+    let Some(duration_ms) = ogg_vorbis_duration_ms(&audio) else {
+        return ctx
+            .send_message(&error_response(err_voice_clip_invalid_format(ctx.locale)))
+            .await;
+    };
+
+    if duration_ms > MAX_VOICE_CLIP_DURATION_MS {
+        return ctx
+            .send_message(&error_response(err_voice_clip_too_long(
+                ctx.locale,
+                MAX_VOICE_CLIP_DURATION_MS / 1000,
+            )))
+            .await;
+    }
+
+    // Names are unique (case-insensitive)
+    match ctx.db.voice_clips.get_clip(&name).await {
+        Ok(None) => {}
+        Ok(Some(existing)) => {
+            return ctx
+                .send_message(&error_response(err_voice_clip_exists(
+                    ctx.locale,
+                    &existing.name,
+                )))
+                .await;
+        }
+        Err(e) => {
+            log_error!("Database error checking voice clip: {}", e);
+            return ctx
+                .send_message(&error_response(err_database(ctx.locale)))
+                .await;
+        }
+    }
+
+    match ctx
+        .db
+        .voice_clips
+        .create_clip(
+            &name,
+            &data,
+            audio.len() as i64,
+            duration_ms as i64,
+            user.db_user_id,
+            MAX_VOICE_CLIPS as i64,
+        )
+        .await
+    {
+        Ok(true) => {}
+        Ok(false) => {
+            return ctx
+                .send_message(&error_response(err_voice_clip_library_full(
+                    ctx.locale,
+                    MAX_VOICE_CLIPS,
+                )))
+                .await;
+        }
+        Err(e) => {
+            log_error!("Database error creating voice clip: {}", e);
+            return ctx
+                .send_message(&error_response(err_database(ctx.locale)))
+                .await;
+        }
+    }
+
+    let response = ServerMessage::VoiceClipUploadResponse {
+        success: true,
+        error: None,
+    };
+    ctx.send_message(&response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::testing::{
+        DEFAULT_TEST_LOCALE, create_test_context, login_user, read_server_message,
+        voice_clip_data_uri,
+    };
+
+    /// Upload a clip and return the response's (success, error)
+    async fn upload(
+        test_ctx: &mut crate::handlers::testing::TestContext,
+        session_id: u32,
+        name: &str,
+        data: String,
+    ) -> (bool, Option<String>) {
+        handle_voice_clip_upload(
+            name.to_string(),
+            data,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        match read_server_message(test_ctx).await {
+            ServerMessage::VoiceClipUploadResponse { success, error } => (success, error),
+            other => panic!("Expected VoiceClipUploadResponse, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_voice_clip_upload_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_voice_clip_upload(
+            "airhorn".to_string(),
+            voice_clip_data_uri(1000),
+            None,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_err(), "Should disconnect unauthenticated user");
+    }
+
+    #[tokio::test]
+    async fn test_voice_clip_upload_requires_permission() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::VoiceTalk],
+            false,
+        )
+        .await;
+
+        let (success, error) = upload(
+            &mut test_ctx,
+            session_id,
+            "airhorn",
+            voice_clip_data_uri(1000),
+        )
+        .await;
+
+        assert!(!success);
+        assert_eq!(error, Some(err_permission_denied(DEFAULT_TEST_LOCALE)));
+    }
+
+    #[tokio::test]
+    async fn test_voice_clip_upload_success() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::VoiceClipUpload],
+            false,
+        )
+        .await;
+
+        let (success, error) = upload(
+            &mut test_ctx,
+            session_id,
+            "airhorn",
+            voice_clip_data_uri(2500),
+        )
+        .await;
+        assert!(success);
+        assert!(error.is_none());
+
+        let clip = test_ctx
+            .db
+            .voice_clips
+            .get_clip("airhorn")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(clip.duration_ms, 2500);
+        assert_eq!(clip.uploader_username, "alice");
+
+        // Same name in a different case is taken
+        let (success, error) = upload(
+            &mut test_ctx,
+            session_id,
+            "AirHorn",
+            voice_clip_data_uri(1000),
+        )
+        .await;
+        assert!(!success);
+        assert_eq!(
+            error,
+            Some(err_voice_clip_exists(DEFAULT_TEST_LOCALE, "airhorn"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_voice_clip_upload_validation() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(&mut test_ctx, "admin", "password", &[], true).await;
+
+        let (_, error) = upload(
+            &mut test_ctx,
+            session_id,
+            "air horn",
+            voice_clip_data_uri(1000),
+        )
+        .await;
+        assert_eq!(
+            error,
+            Some(err_voice_clip_name_invalid(DEFAULT_TEST_LOCALE))
+        );
+
+        let (_, error) = upload(
+            &mut test_ctx,
+            session_id,
+            "airhorn",
+            "data:audio/mpeg;base64,SUQz".to_string(),
+        )
+        .await;
+        assert_eq!(
+            error,
+            Some(err_voice_clip_invalid_format(DEFAULT_TEST_LOCALE))
+        );
+
+        // Right MIME type, but not Ogg Vorbis
+        let (_, error) = upload(
+            &mut test_ctx,
+            session_id,
+            "airhorn",
+            "data:audio/ogg;base64,SGVsbG8=".to_string(),
+        )
+        .await;
+        assert_eq!(
+            error,
+            Some(err_voice_clip_invalid_format(DEFAULT_TEST_LOCALE))
+        );
+
+        let (_, error) = upload(
+            &mut test_ctx,
+            session_id,
+            "airhorn",
+            voice_clip_data_uri(MAX_VOICE_CLIP_DURATION_MS + 1),
+        )
+        .await;
+        assert_eq!(
+            error,
+            Some(err_voice_clip_too_long(
+                DEFAULT_TEST_LOCALE,
+                MAX_VOICE_CLIP_DURATION_MS / 1000
+            ))
+        );
+
+        // Decoded audio over the size limit, even though the URI fits
+        let oversized = format!(
+            "data:audio/ogg;base64,{}",
+            BASE64.encode(vec![0u8; validators::MAX_VOICE_CLIP_SIZE + 1])
+        );
+        let (_, error) = upload(&mut test_ctx, session_id, "airhorn", oversized).await;
+        assert_eq!(error, Some(err_voice_clip_too_large(DEFAULT_TEST_LOCALE)));
+
+        assert!(
+            test_ctx
+                .db
+                .voice_clips
+                .get_all_clips()
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -7,12 +7,32 @@ use tokio::io::AsyncWrite;
 use nexus_common::protocol::ServerMessage;
 
 use super::{
-    HandlerContext, err_authentication, err_not_logged_in, err_voice_already_joined,
+    HandlerContext, err_authentication, err_not_logged_in, err_permission_denied,
+    err_voice_already_joined, err_voice_broadcast_busy, err_voice_broadcast_channel_only,
     err_voice_invalid_target, err_voice_listen_required, err_voice_not_channel_member,
     err_voice_target_not_online,
 };
 use crate::db::Permission;
-use crate::voice::VoiceSession;
+use crate::voice::{
+    VoiceRole, VoiceSession, broadcast_channel_voice_join, broadcast_voice_broadcast_update,
+    voice_broadcast_join_response, voice_join_success_response,
+};
+
+/// Helper to create a failed VoiceJoinResponse
+fn error_response(error: String) -> ServerMessage {
+    ServerMessage::VoiceJoinResponse {
+        success: false,
+        token: None,
+        target: None,
+        participants: None,
+        voice_address: None,
+        voice_port: None,
+        tcp_relay: None,
+        listener: None,
+        listeners: None,
+        error: Some(error),
+    }
+}
 
 /// Handle VoiceJoin command - join voice chat for a channel or user message
 ///
@@ -23,8 +43,18 @@ use crate::voice::VoiceSession;
 /// Internally, the server converts user message targets to a canonical array
 /// format `["alice", "bob"]` (sorted) for registry lookups. Clients only see
 /// simple string targets.
+///
+/// If a channel's voice limit is reached, the user is queued instead and
+/// receives `VoiceQueueUpdate` with their position. The `VoiceJoinResponse`
+/// is sent later, when a slot frees up.
+///
+/// With `broadcast`, a user with voice_broadcast permission starts a broadcast
+/// in a channel nobody is in voice for. While a channel is broadcasting,
+/// everyone else joining its voice becomes a listen-only listener, regardless
+/// of the voice limit.
 pub async fn handle_voice_join<W>(
     target: String,
+    broadcast: bool,
     session_id: Option<u32>,
     ctx: &mut HandlerContext<'_, W>,
 ) -> io::Result<()>
@@ -33,7 +63,7 @@ where
 {
     // Verify authentication first
     let Some(session_id) = session_id else {
-        eprintln!("VoiceJoin request from {} without login", ctx.peer_addr);
+        log_warn!("VoiceJoin request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("VoiceJoin"))
             .await;
@@ -51,43 +81,51 @@ where
 
     // Check voice_listen permission
     if !user.has_permission(Permission::VoiceListen) {
-        let response = ServerMessage::VoiceJoinResponse {
-            success: false,
-            token: None,
-            target: None,
-            participants: None,
-            error: Some(err_voice_listen_required(ctx.locale)),
-        };
-        return ctx.send_message(&response).await;
+        return ctx
+            .send_message(&error_response(err_voice_listen_required(ctx.locale)))
+            .await;
     }
 
     // Validate target is not empty
     if target.is_empty() {
-        let response = ServerMessage::VoiceJoinResponse {
-            success: false,
-            token: None,
-            target: None,
-            participants: None,
-            error: Some(err_voice_invalid_target(ctx.locale)),
-        };
-        return ctx.send_message(&response).await;
+        return ctx
+            .send_message(&error_response(err_voice_invalid_target(ctx.locale)))
+            .await;
     }
 
-    // Check if user is already in a voice session
-    if ctx.voice_registry.has_session(session_id).await {
-        let response = ServerMessage::VoiceJoinResponse {
-            success: false,
-            token: None,
-            target: None,
-            participants: None,
-            error: Some(err_voice_already_joined(ctx.locale)),
-        };
-        return ctx.send_message(&response).await;
+    // Check if user is already in a voice session (or waiting for one)
+    if ctx.voice_registry.has_session(session_id).await
+        || ctx.voice_registry.is_queued(session_id).await
+    {
+        return ctx
+            .send_message(&error_response(err_voice_already_joined(ctx.locale)))
+            .await;
     }
 
     // Validate target based on type and build internal target array
     let is_channel = target.starts_with('#');
 
+    // Broadcasting requires voice_broadcast permission and a channel target
+    if broadcast {
+        if !user.has_permission(Permission::VoiceBroadcast) {
+            log_warn!(
+                "VoiceJoin broadcast from {} (user: {}) without permission",
+                ctx.peer_addr,
+                user.username
+            );
+            return ctx
+                .send_message(&error_response(err_permission_denied(ctx.locale)))
+                .await;
+        }
+        if !is_channel {
+            return ctx
+                .send_message(&error_response(err_voice_broadcast_channel_only(
+                    ctx.locale,
+                )))
+                .await;
+        }
+    }
+
     // The target to return to the client (same as input for channels, same for user messages)
     let client_target = target.clone();
 
@@ -95,15 +133,90 @@ where
     let internal_target = if is_channel {
         // Channel voice: user must be a member of the channel
         if !ctx.channel_manager.is_member(&target, session_id).await {
-            let response = ServerMessage::VoiceJoinResponse {
-                success: false,
-                token: None,
-                target: None,
-                participants: None,
-                error: Some(err_voice_not_channel_member(ctx.locale, &target)),
-            };
+            return ctx
+                .send_message(&error_response(err_voice_not_channel_member(
+                    ctx.locale, &target,
+                )))
+                .await;
+        }
+
+        // Broadcasting channel: join as a listener (no voice limit, never transmits)
+        if let Some(broadcaster) = ctx.voice_registry.get_broadcaster(&target).await {
+            if broadcast {
+                return ctx
+                    .send_message(&error_response(err_voice_broadcast_busy(
+                        ctx.locale, &target,
+                    )))
+                    .await;
+            }
+
+            let voice_session = VoiceSession::new(
+                user.nickname.clone(),
+                vec![target.clone()],
+                session_id,
+                ctx.peer_addr.ip(),
+            )
+            .with_role(VoiceRole::Listener);
+            let token = ctx.voice_registry.add(voice_session).await;
+            let listeners = ctx.voice_registry.listener_count(&target).await;
+
+            broadcast_voice_broadcast_update(
+                &target,
+                ctx.voice_registry,
+                ctx.user_manager,
+                ctx.channel_manager,
+            )
+            .await;
+
+            let response = voice_broadcast_join_response(
+                ctx.voice_registry,
+                token,
+                target,
+                vec![broadcaster.nickname],
+                true,
+                listeners,
+            );
             return ctx.send_message(&response).await;
         }
+
+        if broadcast {
+            // A broadcast needs the channel's voice to itself
+            if !ctx
+                .voice_registry
+                .get_sessions_for_target(&target)
+                .await
+                .is_empty()
+            {
+                return ctx
+                    .send_message(&error_response(err_voice_broadcast_busy(
+                        ctx.locale, &target,
+                    )))
+                    .await;
+            }
+        } else {
+            // Full channel: wait in the queue for a free slot
+            let voice_limit = ctx
+                .channel_manager
+                .get_voice_limit(&target)
+                .await
+                .unwrap_or(0);
+            if voice_limit > 0
+                && ctx
+                    .voice_registry
+                    .get_sessions_for_target(&target)
+                    .await
+                    .len()
+                    >= voice_limit as usize
+            {
+                let position = ctx.voice_registry.enqueue(target.clone(), session_id).await;
+                let update = ServerMessage::VoiceQueueUpdate {
+                    target,
+                    position: Some(position),
+                };
+                return ctx.send_message(&update).await;
+            }
+        }
+
         vec![target]
     } else {
         // User message voice: target must be online
@@ -114,14 +227,11 @@ where
             .is_some();
 
         if !target_online {
-            let response = ServerMessage::VoiceJoinResponse {
-                success: false,
-                token: None,
-                target: None,
-                participants: None,
-                error: Some(err_voice_target_not_online(ctx.locale, &target)),
-            };
-            return ctx.send_message(&response).await;
+            return ctx
+                .send_message(&error_response(err_voice_target_not_online(
+                    ctx.locale, &target,
+                )))
+                .await;
         }
 
         // Build canonical sorted array [nick1, nick2]
@@ -144,12 +254,18 @@ where
         .await;
 
     // Create and add the voice session
+    let role = if broadcast {
+        VoiceRole::Broadcaster
+    } else {
+        VoiceRole::Participant
+    };
     let voice_session = VoiceSession::new(
         user.nickname.clone(),
         internal_target,
         session_id,
         ctx.peer_addr.ip(),
-    );
+    )
+    .with_role(role);
     let token = ctx.voice_registry.add(voice_session).await;
 
     // Add self to participants list (sorted by lowercase)
@@ -161,34 +277,14 @@ where
     if !nickname_already_in_voice {
         if is_channel {
             // For channels: broadcast to ALL channel members with voice_listen permission
-            // (not just voice participants) so everyone can see who's in voice
-            let channel_name = client_target.clone();
-            let members = ctx
-                .channel_manager
-                .get_members(&channel_name)
-                .await
-                .unwrap_or_default();
-
-            for member_session_id in members {
-                // Skip self
-                if member_session_id == session_id {
-                    continue;
-                }
-
-                // Check if member has voice_listen permission
-                if let Some(member) = ctx
-                    .user_manager
-                    .get_user_by_session_id(member_session_id)
-                    .await
-                    && member.has_permission(Permission::VoiceListen)
-                {
-                    let join_notification = ServerMessage::VoiceUserJoined {
-                        nickname: user.nickname.clone(),
-                        target: channel_name.clone(),
-                    };
-                    let _ = member.tx.send((join_notification, None));
-                }
-            }
+            broadcast_channel_voice_join(
+                &client_target,
+                &user.nickname,
+                session_id,
+                ctx.user_manager,
+                ctx.channel_manager,
+            )
+            .await;
         } else {
             // For user messages: only notify the other participant
             for participant_nickname in &participants {
@@ -213,14 +309,30 @@ where
         }
     }
 
+    // Announce the new broadcast; the broadcaster starts without listeners
+    if broadcast {
+        broadcast_voice_broadcast_update(
+            &client_target,
+            ctx.voice_registry,
+            ctx.user_manager,
+            ctx.channel_manager,
+        )
+        .await;
+
+        let response = voice_broadcast_join_response(
+            ctx.voice_registry,
+            token,
+            client_target,
+            participants,
+            false,
+            0,
+        );
+        return ctx.send_message(&response).await;
+    }
+
     // Send success response to the joining user
-    let response = ServerMessage::VoiceJoinResponse {
-        success: true,
-        token: Some(token),
-        target: Some(client_target),
-        participants: Some(participants),
-        error: None,
-    };
+    let response =
+        voice_join_success_response(ctx.voice_registry, token, client_target, participants);
     ctx.send_message(&response).await
 }
 
@@ -231,7 +343,8 @@ mod tests {
     use crate::db::Permission;
     use crate::handlers::chat_join::handle_chat_join;
     use crate::handlers::testing::{
-        create_test_context, login_user, login_user_with_features, read_server_message,
+        DEFAULT_TEST_LOCALE, create_test_context, login_user, login_user_with_features,
+        read_server_message,
     };
 
     #[tokio::test]
@@ -240,6 +353,7 @@ mod tests {
 
         let result = handle_voice_join(
             "#general".to_string(),
+            false,
             None,
             &mut test_ctx.handler_context(),
         )
@@ -257,6 +371,7 @@ mod tests {
 
         let result = handle_voice_join(
             "#general".to_string(),
+            false,
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -290,6 +405,7 @@ mod tests {
 
         let result = handle_voice_join(
             "".to_string(),
+            false,
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -323,6 +439,7 @@ mod tests {
 
         let result = handle_voice_join(
             "#general".to_string(),
+            false,
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -372,6 +489,7 @@ mod tests {
         // Now join voice
         let result = handle_voice_join(
             "#general".to_string(),
+            false,
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -386,9 +504,21 @@ mod tests {
                 token,
                 target,
                 participants,
+                voice_address,
+                voice_port,
+                tcp_relay,
+                listener,
+                listeners,
                 error,
             } => {
                 assert!(success, "Expected success, got error: {:?}", error);
+                // Default network settings: same host/port, no TCP relay
+                assert!(voice_address.is_none());
+                assert!(voice_port.is_none());
+                assert!(tcp_relay.is_none());
+                // Not a broadcast
+                assert!(listener.is_none());
+                assert!(listeners.is_none());
                 assert!(token.is_some());
                 assert_eq!(target, Some("#general".to_string()));
                 assert!(participants.is_some());
@@ -434,6 +564,7 @@ mod tests {
         // Join voice first time
         handle_voice_join(
             "#general".to_string(),
+            false,
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -444,6 +575,7 @@ mod tests {
         // Try to join voice again
         let result = handle_voice_join(
             "#general".to_string(),
+            false,
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -461,6 +593,297 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_voice_join_full_channel_queues() {
+        let mut test_ctx = create_test_context().await;
+
+        let permissions = [
+            Permission::VoiceListen,
+            Permission::ChatJoin,
+            Permission::ChatCreate,
+        ];
+        let alice = login_user_with_features(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &permissions,
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+        let bob = login_user_with_features(
+            &mut test_ctx,
+            "bob",
+            "password",
+            &permissions,
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+
+        for session_id in [alice, bob] {
+            handle_chat_join(
+                "#general".to_string(),
+                Some(session_id),
+                &mut test_ctx.handler_context(),
+            )
+            .await
+            .unwrap();
+            let _ = read_server_message(&mut test_ctx).await;
+        }
+        test_ctx
+            .channel_manager
+            .set_voice_limit("#general", 1)
+            .await
+            .unwrap();
+
+        // Alice takes the only slot
+        handle_voice_join(
+            "#general".to_string(),
+            false,
+            Some(alice),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        let _ = read_server_message(&mut test_ctx).await;
+
+        // Bob has to wait
+        handle_voice_join(
+            "#general".to_string(),
+            false,
+            Some(bob),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::VoiceQueueUpdate { target, position } => {
+                assert_eq!(target, "#general");
+                assert_eq!(position, Some(1));
+            }
+            _ => panic!("Expected VoiceQueueUpdate, got {:?}", response),
+        }
+        assert!(!test_ctx.voice_registry.has_session(bob).await);
+        assert!(test_ctx.voice_registry.is_queued(bob).await);
+
+        // Alice leaving frees the slot for Bob
+        crate::handlers::voice_leave::handle_voice_leave(
+            Some(alice),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        let _ = read_server_message(&mut test_ctx).await;
+
+        assert!(test_ctx.voice_registry.has_session(bob).await);
+        assert!(!test_ctx.voice_registry.is_queued(bob).await);
+    }
+
+    #[tokio::test]
+    async fn test_voice_join_broadcast_requires_permission() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user_with_features(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[
+                Permission::VoiceListen,
+                Permission::VoiceTalk,
+                Permission::ChatJoin,
+                Permission::ChatCreate,
+            ],
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+
+        handle_chat_join(
+            "#radio".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        let _ = read_server_message(&mut test_ctx).await;
+
+        handle_voice_join(
+            "#radio".to_string(),
+            true,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::VoiceJoinResponse { success, error, .. } => {
+                assert!(!success);
+                assert_eq!(error, Some(err_permission_denied(DEFAULT_TEST_LOCALE)));
+            }
+            other => panic!("Expected VoiceJoinResponse, got {:?}", other),
+        }
+        assert!(!test_ctx.voice_registry.has_session(session_id).await);
+    }
+
+    #[tokio::test]
+    async fn test_voice_join_broadcast_listeners() {
+        let mut test_ctx = create_test_context().await;
+
+        let permissions = [
+            Permission::VoiceListen,
+            Permission::VoiceTalk,
+            Permission::ChatJoin,
+            Permission::ChatCreate,
+        ];
+        let alice = login_user_with_features(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[
+                Permission::VoiceListen,
+                Permission::VoiceBroadcast,
+                Permission::ChatJoin,
+                Permission::ChatCreate,
+            ],
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+        let bob = login_user_with_features(
+            &mut test_ctx,
+            "bob",
+            "password",
+            &permissions,
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+        let carol = login_user_with_features(
+            &mut test_ctx,
+            "carol",
+            "password",
+            &permissions,
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+
+        for session_id in [alice, bob, carol] {
+            handle_chat_join(
+                "#radio".to_string(),
+                Some(session_id),
+                &mut test_ctx.handler_context(),
+            )
+            .await
+            .unwrap();
+            let _ = read_server_message(&mut test_ctx).await;
+        }
+        // The voice limit does not apply to listeners
+        test_ctx
+            .channel_manager
+            .set_voice_limit("#radio", 1)
+            .await
+            .unwrap();
+
+        // A broadcast can't be sent to a user
+        handle_voice_join(
+            "bob".to_string(),
+            true,
+            Some(alice),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::VoiceJoinResponse { success, error, .. } => {
+                assert!(!success);
+                assert_eq!(
+                    error,
+                    Some(err_voice_broadcast_channel_only(DEFAULT_TEST_LOCALE))
+                );
+            }
+            other => panic!("Expected VoiceJoinResponse, got {:?}", other),
+        }
+
+        handle_voice_join(
+            "#radio".to_string(),
+            true,
+            Some(alice),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        match read_server_message(&mut test_ctx).await {
+            ServerMessage::VoiceJoinResponse {
+                success,
+                participants,
+                listener,
+                listeners,
+                ..
+            } => {
+                assert!(success);
+                assert_eq!(participants, Some(vec!["alice".to_string()]));
+                assert_eq!(listener, Some(false));
+                assert_eq!(listeners, Some(0));
+            }
+            other => panic!("Expected VoiceJoinResponse, got {:?}", other),
+        }
+
+        for (session_id, expected_listeners) in [(bob, 1), (carol, 2)] {
+            handle_voice_join(
+                "#radio".to_string(),
+                false,
+                Some(session_id),
+                &mut test_ctx.handler_context(),
+            )
+            .await
+            .unwrap();
+            match read_server_message(&mut test_ctx).await {
+                ServerMessage::VoiceJoinResponse {
+                    success,
+                    participants,
+                    listener,
+                    listeners,
+                    ..
+                } => {
+                    assert!(success);
+                    assert_eq!(participants, Some(vec!["alice".to_string()]));
+                    assert_eq!(listener, Some(true));
+                    assert_eq!(listeners, Some(expected_listeners));
+                }
+                other => panic!("Expected VoiceJoinResponse, got {:?}", other),
+            }
+        }
+
+        let bob_session = test_ctx
+            .voice_registry
+            .get_by_session_id(bob)
+            .await
+            .unwrap();
+        assert!(bob_session.is_listener());
+        assert_eq!(bob_session.transmit_permission(), None);
+        assert_eq!(
+            test_ctx.voice_registry.get_participants("#radio").await,
+            vec!["alice"]
+        );
+
+        // Ending the broadcast removes its listeners
+        crate::handlers::voice_leave::handle_voice_leave(
+            Some(alice),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        let _ = read_server_message(&mut test_ctx).await;
+
+        assert!(!test_ctx.voice_registry.has_session(bob).await);
+        assert!(!test_ctx.voice_registry.has_session(carol).await);
+        assert_eq!(test_ctx.voice_registry.listener_count("#radio").await, 0);
+    }
+
     #[tokio::test]
     async fn test_voice_join_user_message_target_offline() {
         let mut test_ctx = create_test_context().await;
@@ -478,6 +901,7 @@ mod tests {
         // Try to join voice with offline user
         let result = handle_voice_join(
             "bob".to_string(),
+            false,
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -522,6 +946,7 @@ mod tests {
         // Alice joins voice with bob
         let result = handle_voice_join(
             "bob".to_string(),
+            false,
             Some(alice_session),
             &mut test_ctx.handler_context(),
         )
@@ -537,6 +962,7 @@ mod tests {
                 target,
                 participants,
                 error,
+                ..
             } => {
                 assert!(success);
                 assert!(token.is_some());
@@ -582,6 +1008,7 @@ mod tests {
         // Alice joins voice with bob
         handle_voice_join(
             "bob".to_string(),
+            false,
             Some(alice_session),
             &mut test_ctx.handler_context(),
         )
@@ -592,6 +1019,7 @@ mod tests {
         // Bob joins voice with alice
         handle_voice_join(
             "alice".to_string(),
+            false,
             Some(bob_session),
             &mut test_ctx.handler_context(),
         )
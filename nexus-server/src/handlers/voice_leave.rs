@@ -7,12 +7,13 @@ use tokio::io::AsyncWrite;
 use nexus_common::protocol::ServerMessage;
 
 use super::{HandlerContext, err_authentication, err_not_logged_in, err_voice_not_joined};
-use crate::voice::send_voice_leave_notifications;
+use crate::voice::{leave_voice_queue, send_voice_leave_notifications};
 
 /// Handle VoiceLeave command - leave current voice session
 ///
 /// Removes the user from their active voice session and broadcasts
-/// VoiceUserLeft to remaining participants.
+/// VoiceUserLeft to remaining participants. A user still waiting in a
+/// voice join queue is removed from the queue instead.
 pub async fn handle_voice_leave<W>(
     session_id: Option<u32>,
     ctx: &mut HandlerContext<'_, W>,
@@ -22,7 +23,7 @@ where
 {
     // Verify authentication first
     let Some(session_id) = session_id else {
-        eprintln!("VoiceLeave request from {} without login", ctx.peer_addr);
+        log_warn!("VoiceLeave request from {} without login", ctx.peer_addr);
         return ctx
             .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("VoiceLeave"))
             .await;
@@ -42,6 +43,18 @@ where
 
     // Remove the voice session and get notification info
     let Some(info) = ctx.voice_registry.remove_by_session_id(session_id).await else {
+        // Not in voice yet, but possibly waiting for a slot
+        if leave_voice_queue(session_id, ctx.voice_registry, ctx.user_manager)
+            .await
+            .is_some()
+        {
+            let response = ServerMessage::VoiceLeaveResponse {
+                success: true,
+                error: None,
+            };
+            return ctx.send_message(&response).await;
+        }
+
         // User was not in a voice session
         let response = ServerMessage::VoiceLeaveResponse {
             success: false,
@@ -51,7 +64,14 @@ where
     };
 
     // Broadcast VoiceUserLeft to remaining participants (not to self - this is explicit leave)
-    send_voice_leave_notifications(&info, None, ctx.user_manager, ctx.channel_manager).await;
+    send_voice_leave_notifications(
+        &info,
+        None,
+        ctx.voice_registry,
+        ctx.user_manager,
+        ctx.channel_manager,
+    )
+    .await;
 
     // Send success response
     let response = ServerMessage::VoiceLeaveResponse {
@@ -134,6 +154,7 @@ mod tests {
         // Join voice
         handle_voice_join(
             "#general".to_string(),
+            false,
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
@@ -189,6 +210,7 @@ mod tests {
         // Alice joins voice with bob
         handle_voice_join(
             "bob".to_string(),
+            false,
             Some(alice_session),
             &mut test_ctx.handler_context(),
         )
@@ -242,6 +264,7 @@ mod tests {
 
         handle_voice_join(
             "#general".to_string(),
+            false,
             Some(session_id),
             &mut test_ctx.handler_context(),
         )
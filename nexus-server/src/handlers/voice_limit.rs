@@ -0,0 +1,296 @@
+//! Handler for VoiceLimit command - set the voice participant limit of a channel
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+use nexus_common::validators;
+
+use super::{
+    HandlerContext, channel_error_to_message, err_authentication, err_channel_not_found,
+    err_chat_feature_not_enabled, err_database, err_not_logged_in, err_permission_denied,
+};
+use crate::constants::FEATURE_CHAT;
+use crate::db::Permission;
+use crate::voice::admit_queued_voice_joins;
+
+/// Handle VoiceLimit command - set the voice participant limit of a channel
+///
+/// A limit of 0 removes the limit. Users already in voice are never removed
+/// when the limit is lowered; raising it admits users from the join queue.
+pub async fn handle_voice_limit<W>(
+    channel: String,
+    limit: u32,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first
+    let Some(session_id) = session_id else {
+        log_warn!("VoiceLimit request from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("VoiceLimit"))
+            .await;
+    };
+
+    // Validate channel name
+    if let Err(e) = validators::validate_channel(&channel) {
+        let response = ServerMessage::VoiceLimitResponse {
+            success: false,
+            error: Some(channel_error_to_message(e, ctx.locale)),
+        };
+        return ctx.send_message(&response).await;
+    }
+
+    // Get user from session
+    let user = match ctx.user_manager.get_user_by_session_id(session_id).await {
+        Some(u) => u,
+        None => {
+            return ctx
+                .send_error_and_disconnect(&err_authentication(ctx.locale), Some("VoiceLimit"))
+                .await;
+        }
+    };
+
+    // Check chat feature
+    if !user.has_feature(FEATURE_CHAT) {
+        let response = ServerMessage::VoiceLimitResponse {
+            success: false,
+            error: Some(err_chat_feature_not_enabled(ctx.locale)),
+        };
+        return ctx.send_message(&response).await;
+    }
+
+    // Check VoiceLimit permission
+    if !user.has_permission(Permission::VoiceLimit) {
+        log_warn!(
+            "VoiceLimit from {} (user: {}) without permission",
+            ctx.peer_addr,
+            user.username
+        );
+        let response = ServerMessage::VoiceLimitResponse {
+            success: false,
+            error: Some(err_permission_denied(ctx.locale)),
+        };
+        return ctx.send_message(&response).await;
+    }
+
+    // Verify user is a member of the channel
+    // For security, always return "not found" to non-members to avoid leaking
+    // existence of secret channels
+    if !ctx.channel_manager.is_member(&channel, session_id).await {
+        let response = ServerMessage::VoiceLimitResponse {
+            success: false,
+            error: Some(err_channel_not_found(ctx.locale, &channel)),
+        };
+        return ctx.send_message(&response).await;
+    }
+
+    // Set the limit (ChannelManager handles persistence for persistent channels)
+    match ctx.channel_manager.set_voice_limit(&channel, limit).await {
+        Ok(true) => {} // Success, channel exists
+        Ok(false) => {
+            // Channel doesn't exist (race condition - was deleted after membership check)
+            let response = ServerMessage::VoiceLimitResponse {
+                success: false,
+                error: Some(err_channel_not_found(ctx.locale, &channel)),
+            };
+            return ctx.send_message(&response).await;
+        }
+        Err(e) => {
+            log_error!("Database error setting channel voice limit: {}", e);
+            let response = ServerMessage::VoiceLimitResponse {
+                success: false,
+                error: Some(err_database(ctx.locale)),
+            };
+            return ctx.send_message(&response).await;
+        }
+    }
+
+    // Send success response to the requester
+    let response = ServerMessage::VoiceLimitResponse {
+        success: true,
+        error: None,
+    };
+    ctx.send_message(&response).await?;
+
+    // Broadcast ChatUpdated to all channel members
+    let members = ctx
+        .channel_manager
+        .get_members(&channel)
+        .await
+        .unwrap_or_default();
+
+    let update_message = ServerMessage::ChatUpdated {
+        channel: channel.clone(),
+        topic: None,
+        topic_set_by: None,
+        secret: None,
+        secret_set_by: None,
+        voice_limit: Some(limit),
+        voice_limit_set_by: Some(user.nickname.clone()),
+        topic_locked: None,
+        topic_locked_set_by: None,
+        rules: None,
+        rules_set_by: None,
+        language: None,
+        language_set_by: None,
+    };
+
+    for member_session_id in members {
+        if let Some(member) = ctx
+            .user_manager
+            .get_user_by_session_id(member_session_id)
+            .await
+        {
+            // Only send to members with chat feature
+            if member.has_feature(FEATURE_CHAT) {
+                ctx.user_manager
+                    .send_to_session(member_session_id, update_message.clone())
+                    .await;
+            }
+        }
+    }
+
+    // A raised (or removed) limit may have freed slots for queued users
+    admit_queued_voice_joins(
+        &channel,
+        ctx.voice_registry,
+        ctx.user_manager,
+        ctx.channel_manager,
+    )
+    .await;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::chat_join::handle_chat_join;
+    use crate::handlers::testing::{
+        create_test_context, login_user, login_user_with_features, read_server_message,
+    };
+
+    #[tokio::test]
+    async fn test_voice_limit_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_voice_limit(
+            "#general".to_string(),
+            4,
+            None,
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_err(), "Should disconnect unauthenticated user");
+    }
+
+    #[tokio::test]
+    async fn test_voice_limit_requires_permission() {
+        let mut test_ctx = create_test_context().await;
+
+        let session_id = login_user(&mut test_ctx, "alice", "password", &[], false).await;
+
+        let result = handle_voice_limit(
+            "#general".to_string(),
+            4,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_ok());
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::VoiceLimitResponse { success, error } => {
+                assert!(!success);
+                assert!(error.is_some());
+            }
+            _ => panic!("Expected VoiceLimitResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_voice_limit_not_member() {
+        let mut test_ctx = create_test_context().await;
+
+        let session_id = login_user_with_features(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::VoiceLimit],
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+
+        let result = handle_voice_limit(
+            "#general".to_string(),
+            4,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_ok());
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::VoiceLimitResponse { success, .. } => assert!(!success),
+            _ => panic!("Expected VoiceLimitResponse, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_voice_limit_success() {
+        let mut test_ctx = create_test_context().await;
+
+        let session_id = login_user_with_features(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[
+                Permission::VoiceLimit,
+                Permission::ChatJoin,
+                Permission::ChatCreate,
+            ],
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+
+        handle_chat_join(
+            "#general".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        let _ = read_server_message(&mut test_ctx).await;
+
+        let result = handle_voice_limit(
+            "#general".to_string(),
+            4,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_ok());
+        let response = read_server_message(&mut test_ctx).await;
+        match response {
+            ServerMessage::VoiceLimitResponse { success, error } => {
+                assert!(success, "Expected success, got error: {:?}", error);
+            }
+            _ => panic!("Expected VoiceLimitResponse, got {:?}", response),
+        }
+        assert_eq!(
+            test_ctx.channel_manager.get_voice_limit("#general").await,
+            Some(4)
+        );
+    }
+}
@@ -0,0 +1,259 @@
+//! Handler for VoiceRelay command - voice packets relayed over the TCP connection
+
+use std::io;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use tokio::io::AsyncWrite;
+
+use nexus_common::voice::{VoiceMessageType, VoicePacket};
+
+use super::{HandlerContext, err_authentication, err_not_logged_in};
+use crate::voice::relay_voice_packet;
+
+/// Handle VoiceRelay command - a voice packet sent over TCP instead of DTLS
+///
+/// Used by clients whose UDP traffic is blocked, when the server advertised
+/// TCP relay in VoiceJoinResponse. The packet is handled like one received
+/// over DTLS: the first one marks the voice session as relayed, keepalives
+/// are ignored and audio is relayed to the other participants.
+///
+/// There is no response. Invalid packets, unknown tokens and packets sent
+/// while TCP relay is disabled are dropped silently.
+pub async fn handle_voice_relay<W>(
+    data: String,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first
+    let Some(session_id) = session_id else {
+        log_warn!("VoiceRelay request from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("VoiceRelay"))
+            .await;
+    };
+
+    // Verify session still exists (user might have disconnected)
+    if ctx
+        .user_manager
+        .get_user_by_session_id(session_id)
+        .await
+        .is_none()
+    {
+        return ctx
+            .send_error_and_disconnect(&err_authentication(ctx.locale), Some("VoiceRelay"))
+            .await;
+    }
+
+    if !ctx.voice_registry.network().tcp_relay {
+        if ctx.debug {
+            log_debug!(
+                "VoiceRelay from {} dropped: TCP relay is disabled",
+                ctx.peer_addr
+            );
+        }
+        return Ok(());
+    }
+
+    let Some(packet) = BASE64
+        .decode(&data)
+        .ok()
+        .and_then(|bytes| VoicePacket::from_bytes(&bytes))
+    else {
+        if ctx.debug {
+            log_debug!("VoiceRelay from {} dropped: invalid packet", ctx.peer_addr);
+        }
+        return Ok(());
+    };
+
+    // The token must belong to this connection's own voice session
+    let Some(session) = ctx
+        .voice_registry
+        .get_by_token(packet.token)
+        .await
+        .filter(|s| s.session_id == session_id)
+    else {
+        if ctx.debug {
+            log_debug!("VoiceRelay from {} dropped: unknown token", ctx.peer_addr);
+        }
+        return Ok(());
+    };
+
+    if !session.tcp_relay {
+        ctx.voice_registry.set_tcp_relay(packet.token).await;
+    }
+
+    match packet.msg_type {
+        VoiceMessageType::Keepalive => {}
+        VoiceMessageType::VoiceData
+        | VoiceMessageType::SpeakingStarted
+        | VoiceMessageType::SpeakingStopped => {
+            // Check the session's transmit permission (voice_talk, or voice_broadcast
+            // for broadcasters) before relaying; listeners never transmit
+            if let Some(permission) = session.transmit_permission()
+                && ctx
+                    .user_manager
+                    .has_permission(session_id, permission)
+                    .await
+                    == Some(true)
+            {
+                relay_voice_packet(
+                    &packet,
+                    &session.nickname,
+                    &session.target_key(),
+                    ctx.voice_registry,
+                    ctx.user_manager,
+                    ctx.debug,
+                )
+                .await;
+            } else if ctx.debug {
+                log_warn!(
+                    "VoiceRelay: {} lacks voice_talk permission, dropping packet",
+                    session.nickname
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::FEATURE_CHAT;
+    use crate::db::Permission;
+    use crate::handlers::chat_join::handle_chat_join;
+    use crate::handlers::testing::{
+        create_test_context, login_user_with_features, read_server_message,
+    };
+    use crate::handlers::voice_join::handle_voice_join;
+    use crate::voice::{VoiceNetworkConfig, VoiceRegistry};
+    use nexus_common::protocol::ServerMessage;
+    use uuid::Uuid;
+
+    /// Log in alice, join #general and its voice, and return (session_id, voice token)
+    async fn join_voice(test_ctx: &mut crate::handlers::testing::TestContext) -> (u32, Uuid) {
+        let session_id = login_user_with_features(
+            test_ctx,
+            "alice",
+            "password",
+            &[
+                Permission::VoiceListen,
+                Permission::VoiceTalk,
+                Permission::ChatJoin,
+                Permission::ChatCreate,
+            ],
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+
+        handle_chat_join(
+            "#general".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        let _ = read_server_message(test_ctx).await; // consume ChatJoinResponse
+
+        handle_voice_join(
+            "#general".to_string(),
+            false,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        let token = match read_server_message(test_ctx).await {
+            ServerMessage::VoiceJoinResponse {
+                token: Some(token),
+                tcp_relay,
+                ..
+            } => {
+                assert_eq!(
+                    tcp_relay,
+                    test_ctx.voice_registry.network().tcp_relay.then_some(true)
+                );
+                token
+            }
+            other => panic!("Expected VoiceJoinResponse, got {:?}", other),
+        };
+
+        (session_id, token)
+    }
+
+    fn keepalive(token: Uuid) -> String {
+        BASE64.encode(VoicePacket::keepalive(token, 0).to_bytes())
+    }
+
+    #[tokio::test]
+    async fn test_voice_relay_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result = handle_voice_relay(String::new(), None, &mut test_ctx.handler_context()).await;
+
+        assert!(result.is_err(), "Should disconnect unauthenticated user");
+    }
+
+    #[tokio::test]
+    async fn test_voice_relay_disabled() {
+        let mut test_ctx = create_test_context().await;
+        let (session_id, token) = join_voice(&mut test_ctx).await;
+
+        let result = handle_voice_relay(
+            keepalive(token),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+
+        assert!(result.is_ok());
+        let session = test_ctx.voice_registry.get_by_token(token).await.unwrap();
+        assert!(!session.tcp_relay);
+    }
+
+    #[tokio::test]
+    async fn test_voice_relay_marks_session() {
+        let mut test_ctx = create_test_context().await;
+        test_ctx.voice_registry = VoiceRegistry::with_network(VoiceNetworkConfig {
+            tcp_relay: true,
+            ..Default::default()
+        });
+        let (session_id, token) = join_voice(&mut test_ctx).await;
+
+        // Invalid data is ignored
+        let result = handle_voice_relay(
+            "not base64!".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+        let session = test_ctx.voice_registry.get_by_token(token).await.unwrap();
+        assert!(!session.tcp_relay);
+
+        // A session that no longer exists is disconnected
+        let result = handle_voice_relay(
+            keepalive(token),
+            Some(session_id + 1),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_err(), "Unknown session should be disconnected");
+
+        let result = handle_voice_relay(
+            keepalive(token),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await;
+        assert!(result.is_ok());
+        let session = test_ctx.voice_registry.get_by_token(token).await.unwrap();
+        assert!(session.tcp_relay);
+    }
+}
@@ -0,0 +1,255 @@
+//! Handler for VoiceTextSend command - send a text message to the voice session
+
+use std::io;
+
+use tokio::io::AsyncWrite;
+
+use nexus_common::protocol::ServerMessage;
+use nexus_common::validators::{self, VoiceTextError};
+
+use super::{
+    HandlerContext, err_authentication, err_chat_too_long, err_message_contains_newlines,
+    err_message_empty, err_message_invalid_characters, err_not_logged_in, err_permission_denied,
+    err_voice_not_joined,
+};
+
+/// Helper to create a failed VoiceTextSendResponse
+fn error_response(error: String) -> ServerMessage {
+    ServerMessage::VoiceTextSendResponse {
+        success: false,
+        error: Some(error),
+    }
+}
+
+/// Handle VoiceTextSend command - send a text message to the voice session
+///
+/// Requires an active voice session that isn't listening to a broadcast. The
+/// message is sent as `VoiceText` to every participant of the session,
+/// including the sender; clients show it as an overlay bubble.
+pub async fn handle_voice_text_send<W>(
+    message: String,
+    session_id: Option<u32>,
+    ctx: &mut HandlerContext<'_, W>,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // Verify authentication first
+    let Some(session_id) = session_id else {
+        log_warn!("VoiceTextSend request from {} without login", ctx.peer_addr);
+        return ctx
+            .send_error_and_disconnect(&err_not_logged_in(ctx.locale), Some("VoiceTextSend"))
+            .await;
+    };
+
+    // Validate message
+    if let Err(e) = validators::validate_voice_text(&message) {
+        let error_msg = match e {
+            VoiceTextError::Empty => err_message_empty(ctx.locale),
+            VoiceTextError::TooLong => {
+                err_chat_too_long(ctx.locale, validators::MAX_VOICE_TEXT_LENGTH)
+            }
+            VoiceTextError::ContainsNewlines => err_message_contains_newlines(ctx.locale),
+            VoiceTextError::InvalidCharacters => err_message_invalid_characters(ctx.locale),
+        };
+        return ctx.send_message(&error_response(error_msg)).await;
+    }
+
+    // Get user from session
+    if ctx
+        .user_manager
+        .get_user_by_session_id(session_id)
+        .await
+        .is_none()
+    {
+        return ctx
+            .send_error_and_disconnect(&err_authentication(ctx.locale), Some("VoiceTextSend"))
+            .await;
+    }
+
+    // Messages go to the user's current voice session (joining it already
+    // required voice_listen)
+    let Some(voice_session) = ctx.voice_registry.get_by_session_id(session_id).await else {
+        return ctx
+            .send_message(&error_response(err_voice_not_joined(ctx.locale)))
+            .await;
+    };
+
+    // Broadcast listeners only listen
+    if voice_session.is_listener() {
+        return ctx
+            .send_message(&error_response(err_permission_denied(ctx.locale)))
+            .await;
+    }
+
+    let response = ServerMessage::VoiceTextSendResponse {
+        success: true,
+        error: None,
+    };
+    ctx.send_message(&response).await?;
+
+    let participants = ctx
+        .voice_registry
+        .get_sessions_for_target(&voice_session.target_key())
+        .await;
+
+    for participant in participants {
+        // Channels are named as-is; user message voice is named after the other user
+        let target = if voice_session.is_channel() {
+            voice_session.target[0].clone()
+        } else {
+            voice_session
+                .target
+                .iter()
+                .find(|nickname| !nickname.eq_ignore_ascii_case(&participant.nickname))
+                .cloned()
+                .unwrap_or_else(|| participant.nickname.clone())
+        };
+
+        let text = ServerMessage::VoiceText {
+            nickname: voice_session.nickname.clone(),
+            target,
+            message: message.clone(),
+        };
+        ctx.user_manager
+            .send_to_session(participant.session_id, text)
+            .await;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::FEATURE_CHAT;
+    use crate::db::Permission;
+    use crate::handlers::chat_join::handle_chat_join;
+    use crate::handlers::testing::{
+        DEFAULT_TEST_LOCALE, TestContext, create_test_context, login_user,
+        login_user_with_features, read_channel_response, read_server_message,
+    };
+    use crate::handlers::voice_join::handle_voice_join;
+
+    async fn send(
+        test_ctx: &mut TestContext,
+        session_id: u32,
+        message: &str,
+    ) -> (bool, Option<String>) {
+        handle_voice_text_send(
+            message.to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+
+        match read_server_message(test_ctx).await {
+            ServerMessage::VoiceTextSendResponse { success, error } => (success, error),
+            other => panic!("Expected VoiceTextSendResponse, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_voice_text_send_requires_login() {
+        let mut test_ctx = create_test_context().await;
+
+        let result =
+            handle_voice_text_send("hi".to_string(), None, &mut test_ctx.handler_context()).await;
+
+        assert!(result.is_err(), "Should disconnect unauthenticated user");
+    }
+
+    #[tokio::test]
+    async fn test_voice_text_send_requires_voice() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::VoiceListen],
+            false,
+        )
+        .await;
+
+        let (success, error) = send(&mut test_ctx, session_id, "your mic is muted").await;
+
+        assert!(!success);
+        assert_eq!(error, Some(err_voice_not_joined(DEFAULT_TEST_LOCALE)));
+    }
+
+    #[tokio::test]
+    async fn test_voice_text_send_rejects_empty() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[Permission::VoiceListen],
+            false,
+        )
+        .await;
+
+        let (success, error) = send(&mut test_ctx, session_id, "   ").await;
+
+        assert!(!success);
+        assert_eq!(error, Some(err_message_empty(DEFAULT_TEST_LOCALE)));
+    }
+
+    #[tokio::test]
+    async fn test_voice_text_send_success() {
+        let mut test_ctx = create_test_context().await;
+        let session_id = login_user_with_features(
+            &mut test_ctx,
+            "alice",
+            "password",
+            &[
+                Permission::VoiceListen,
+                Permission::ChatJoin,
+                Permission::ChatCreate,
+            ],
+            false,
+            vec![FEATURE_CHAT.to_string()],
+        )
+        .await;
+
+        handle_chat_join(
+            "#general".to_string(),
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        let _ = read_server_message(&mut test_ctx).await; // consume ChatJoinResponse
+
+        handle_voice_join(
+            "#general".to_string(),
+            false,
+            Some(session_id),
+            &mut test_ctx.handler_context(),
+        )
+        .await
+        .unwrap();
+        let _ = read_server_message(&mut test_ctx).await; // consume VoiceJoinResponse
+
+        let (success, error) = send(&mut test_ctx, session_id, "your mic is muted").await;
+        assert!(success);
+        assert!(error.is_none());
+
+        // The sender sees their own message too
+        match read_channel_response(&mut test_ctx, |msg| {
+            matches!(msg, ServerMessage::VoiceText { .. })
+        }) {
+            ServerMessage::VoiceText {
+                nickname,
+                target,
+                message,
+            } => {
+                assert_eq!(nickname, "alice");
+                assert_eq!(target, "#general");
+                assert_eq!(message, "your mic is muted");
+            }
+            other => panic!("Expected VoiceText, got {:?}", other),
+        }
+    }
+}
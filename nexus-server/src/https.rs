@@ -0,0 +1,323 @@
+//! Minimal HTTPS client for the server's outgoing requests
+//!
+//! The server only makes a few kinds of request: single sign-on fetches the
+//! identity provider's discovery document and redeems authorization codes,
+//! and the storage monitor posts its alerts to a webhook. All of these are
+//! small JSON exchanges, so requests are sent as HTTP/1.0 with `Connection: close`
+//! and the response is read to the end of the stream, which keeps chunked
+//! encoding and connection reuse out of the picture. Certificates are checked
+//! against the Mozilla root store.
+
+use std::sync::{Arc, LazyLock};
+use std::time::Duration;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::time::timeout;
+use tokio_rustls::TlsConnector;
+use tokio_rustls::rustls::pki_types::ServerName;
+use tokio_rustls::rustls::{ClientConfig, RootCertStore};
+
+use crate::constants::HTTPS_REQUEST_TIMEOUT_SECS;
+
+/// Largest response accepted
+const MAX_RESPONSE_SIZE: usize = 256 * 1024;
+
+/// TLS connector trusting the Mozilla root certificates
+static TLS_CONNECTOR: LazyLock<TlsConnector> = LazyLock::new(|| {
+    let roots = RootCertStore {
+        roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
+    };
+    let config = ClientConfig::builder()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+    TlsConnector::from(Arc::new(config))
+});
+
+/// An `https://` URL split into the parts a request needs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct HttpsUrl {
+    pub host: String,
+    pub port: u16,
+    /// Path and query, always starting with `/`
+    pub path: String,
+}
+
+impl HttpsUrl {
+    /// Parse an `https://` URL (other schemes, user info and IPv6 hosts are refused)
+    pub fn parse(url: &str) -> Result<Self, String> {
+        let rest = url
+            .strip_prefix("https://")
+            .ok_or_else(|| format!("not an https URL: {url}"))?;
+        let rest = rest.split('#').next().unwrap_or_default();
+        let (authority, path) = match rest.find(['/', '?']) {
+            Some(i) if rest[i..].starts_with('/') => (&rest[..i], rest[i..].to_string()),
+            Some(i) => (&rest[..i], format!("/{}", &rest[i..])),
+            None => (rest, "/".to_string()),
+        };
+        if authority.is_empty() || authority.contains(['@', '[', ']']) {
+            return Err(format!("unsupported host in URL: {url}"));
+        }
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) => (
+                host,
+                port.parse::<u16>()
+                    .map_err(|_| format!("invalid port in URL: {url}"))?,
+            ),
+            None => (authority, 443),
+        };
+        if host.is_empty() {
+            return Err(format!("missing host in URL: {url}"));
+        }
+        Ok(Self {
+            host: host.to_ascii_lowercase(),
+            port,
+            path,
+        })
+    }
+
+    /// Host header value (the port is left out when it is the default)
+    fn host_header(&self) -> String {
+        if self.port == 443 {
+            self.host.clone()
+        } else {
+            format!("{}:{}", self.host, self.port)
+        }
+    }
+}
+
+/// A response to a request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct HttpResponse {
+    pub status: u16,
+    pub body: Vec<u8>,
+}
+
+/// Fetch a URL
+pub(super) async fn get(url: &HttpsUrl) -> Result<HttpResponse, String> {
+    let request = format!(
+        "GET {} HTTP/1.0\r\nHost: {}\r\nAccept: application/json\r\nConnection: close\r\n\r\n",
+        url.path,
+        url.host_header()
+    );
+    send(url, request.into_bytes()).await
+}
+
+/// POST an `application/x-www-form-urlencoded` body
+pub(super) async fn post_form(
+    url: &HttpsUrl,
+    fields: &[(&str, &str)],
+    authorization: Option<&str>,
+) -> Result<HttpResponse, String> {
+    let body = form_encode(fields);
+    let mut request = format!(
+        "POST {} HTTP/1.0\r\nHost: {}\r\nAccept: application/json\r\n\
+         Content-Type: application/x-www-form-urlencoded\r\nContent-Length: {}\r\n\
+         Connection: close\r\n",
+        url.path,
+        url.host_header(),
+        body.len()
+    );
+    if let Some(authorization) = authorization {
+        request.push_str(&format!("Authorization: {authorization}\r\n"));
+    }
+    request.push_str("\r\n");
+    request.push_str(&body);
+    send(url, request.into_bytes()).await
+}
+
+/// POST a JSON body
+pub(crate) async fn post_json(url: &HttpsUrl, body: &str) -> Result<HttpResponse, String> {
+    let request = format!(
+        "POST {} HTTP/1.0\r\nHost: {}\r\nContent-Type: application/json\r\n\
+         Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+        url.path,
+        url.host_header(),
+        body.len(),
+        body
+    );
+    send(url, request.into_bytes()).await
+}
+
+/// Send a request and read the whole response, within the request timeout
+async fn send(url: &HttpsUrl, request: Vec<u8>) -> Result<HttpResponse, String> {
+    let exchange = async {
+        let tcp = TcpStream::connect((url.host.as_str(), url.port))
+            .await
+            .map_err(|e| format!("failed to connect to {}: {e}", url.host))?;
+        let server_name = ServerName::try_from(url.host.clone())
+            .map_err(|e| format!("invalid host {}: {e}", url.host))?;
+        let mut tls = TLS_CONNECTOR
+            .connect(server_name, tcp)
+            .await
+            .map_err(|e| format!("TLS handshake with {} failed: {e}", url.host))?;
+
+        tls.write_all(&request)
+            .await
+            .map_err(|e| format!("failed to send request: {e}"))?;
+
+        let mut response = Vec::new();
+        (&mut tls)
+            .take(MAX_RESPONSE_SIZE as u64 + 1)
+            .read_to_end(&mut response)
+            .await
+            .map_err(|e| format!("failed to read response: {e}"))?;
+        if response.len() > MAX_RESPONSE_SIZE {
+            return Err("response too large".to_string());
+        }
+        parse_response(&response)
+    };
+
+    timeout(Duration::from_secs(HTTPS_REQUEST_TIMEOUT_SECS), exchange)
+        .await
+        .map_err(|_| format!("request to {} timed out", url.host))?
+}
+
+/// Split a raw HTTP response into its status and body
+fn parse_response(raw: &[u8]) -> Result<HttpResponse, String> {
+    let head_end = raw
+        .windows(4)
+        .position(|w| w == b"\r\n\r\n")
+        .ok_or("malformed HTTP response")?;
+    let head = std::str::from_utf8(&raw[..head_end]).map_err(|_| "malformed HTTP response")?;
+    let mut lines = head.split("\r\n");
+
+    let status = lines
+        .next()
+        .and_then(|line| {
+            let mut parts = line.split(' ');
+            parts
+                .next()
+                .filter(|version| version.starts_with("HTTP/"))?;
+            parts.next()?.parse::<u16>().ok()
+        })
+        .ok_or("malformed HTTP status line")?;
+
+    let chunked = lines.any(|line| {
+        line.split_once(':').is_some_and(|(name, value)| {
+            name.trim().eq_ignore_ascii_case("transfer-encoding")
+                && value.trim().eq_ignore_ascii_case("chunked")
+        })
+    });
+
+    let body = &raw[head_end + 4..];
+    let body = if chunked {
+        decode_chunked(body)?
+    } else {
+        body.to_vec()
+    };
+    Ok(HttpResponse { status, body })
+}
+
+/// Decode a chunked body (servers should not send one to HTTP/1.0, but some do)
+fn decode_chunked(mut data: &[u8]) -> Result<Vec<u8>, String> {
+    let mut body = Vec::new();
+    loop {
+        let line_end = data
+            .windows(2)
+            .position(|w| w == b"\r\n")
+            .ok_or("malformed chunked body")?;
+        let size_line = std::str::from_utf8(&data[..line_end]).map_err(|_| "malformed chunk")?;
+        let size_hex = size_line.split(';').next().unwrap_or_default().trim();
+        let size = usize::from_str_radix(size_hex, 16).map_err(|_| "malformed chunk size")?;
+        data = &data[line_end + 2..];
+        if size == 0 {
+            return Ok(body);
+        }
+        if data.len() < size {
+            return Err("truncated chunked body".to_string());
+        }
+        body.extend_from_slice(&data[..size]);
+        data = data.get(size + 2..).ok_or("truncated chunked body")?;
+    }
+}
+
+/// Encode fields as `application/x-www-form-urlencoded`
+pub(super) fn form_encode(fields: &[(&str, &str)]) -> String {
+    fields
+        .iter()
+        .map(|(name, value)| format!("{}={}", percent_encode(name), percent_encode(value)))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Percent-encode everything except unreserved characters
+pub(super) fn percent_encode(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_url() {
+        assert_eq!(
+            HttpsUrl::parse("https://ID.example.com/realms/nexus").unwrap(),
+            HttpsUrl {
+                host: "id.example.com".to_string(),
+                port: 443,
+                path: "/realms/nexus".to_string(),
+            }
+        );
+        assert_eq!(
+            HttpsUrl::parse("https://id.example.com:8443?x=1").unwrap(),
+            HttpsUrl {
+                host: "id.example.com".to_string(),
+                port: 8443,
+                path: "/?x=1".to_string(),
+            }
+        );
+        assert_eq!(HttpsUrl::parse("https://id.example.com").unwrap().path, "/");
+
+        assert!(HttpsUrl::parse("http://id.example.com/").is_err());
+        assert!(HttpsUrl::parse("https://user@id.example.com/").is_err());
+        assert!(HttpsUrl::parse("https://[::1]/").is_err());
+        assert!(HttpsUrl::parse("https://id.example.com:x/").is_err());
+        assert!(HttpsUrl::parse("https:///path").is_err());
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let response =
+            parse_response(b"HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\r\n{}").unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, b"{}");
+
+        let response = parse_response(
+            b"HTTP/1.1 400 Bad Request\r\nTransfer-Encoding: chunked\r\n\r\n\
+              4\r\n{\"a\"\r\n3;ext=1\r\n:1}\r\n0\r\n\r\n",
+        )
+        .unwrap();
+        assert_eq!(response.status, 400);
+        assert_eq!(response.body, b"{\"a\":1}");
+
+        assert!(parse_response(b"HTTP/1.1 200 OK\r\n").is_err());
+        assert!(parse_response(b"garbage\r\n\r\n").is_err());
+        assert!(
+            parse_response(b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\nff\r\nab")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_form_encode() {
+        assert_eq!(
+            form_encode(&[
+                ("grant_type", "authorization_code"),
+                ("redirect_uri", "http://127.0.0.1:5000/callback"),
+                ("code", "a b+c"),
+            ]),
+            "grant_type=authorization_code&redirect_uri=http%3A%2F%2F127.0.0.1%3A5000%2Fcallback\
+             &code=a%20b%2Bc"
+        );
+    }
+}
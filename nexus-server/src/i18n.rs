@@ -21,16 +21,19 @@ pub fn t(locale: &str, key: &str) -> String {
         let value = bundle.format_pattern(msg, None, &mut errors);
 
         if !errors.is_empty() {
-            eprintln!("{} '{}': {:?}", ERR_I18N_TRANSLATION_ERRORS, key, errors);
+            log_error!("{} '{}': {:?}", ERR_I18N_TRANSLATION_ERRORS, key, errors);
         }
 
         return value.to_string();
     }
 
     // Fallback to English if key missing in requested locale
-    eprintln!(
+    log_error!(
         "{} '{}' {} {}",
-        ERR_I18N_MISSING_KEY, key, MSG_I18N_FOR_LOCALE, locale
+        ERR_I18N_MISSING_KEY,
+        key,
+        MSG_I18N_FOR_LOCALE,
+        locale
     );
     if locale != DEFAULT_LOCALE {
         return t(DEFAULT_LOCALE, key);
@@ -61,16 +64,19 @@ pub fn t_args(locale: &str, key: &str, args: &[(&str, &str)]) -> String {
         let value = bundle.format_pattern(msg, Some(&fluent_args), &mut errors);
 
         if !errors.is_empty() {
-            eprintln!("{} '{}': {:?}", ERR_I18N_TRANSLATION_ERRORS, key, errors);
+            log_error!("{} '{}': {:?}", ERR_I18N_TRANSLATION_ERRORS, key, errors);
         }
 
         return value.to_string();
     }
 
     // Fallback to English if key missing in requested locale
-    eprintln!(
+    log_error!(
         "{} '{}' {} {}",
-        ERR_I18N_MISSING_KEY, key, MSG_I18N_FOR_LOCALE, locale
+        ERR_I18N_MISSING_KEY,
+        key,
+        MSG_I18N_FOR_LOCALE,
+        locale
     );
     if locale != DEFAULT_LOCALE {
         return t_args(DEFAULT_LOCALE, key, args);
@@ -0,0 +1,157 @@
+//! Idempotency keys for chat, user and group messages
+//!
+//! A client may attach a key to each message it sends. When the connection
+//! drops before the server's reply arrives, the client sends the message again
+//! with the same key after reconnecting, and the server acknowledges the
+//! repeat without posting it a second time.
+//!
+//! The repeat comes from a new session, so keys are remembered per sender
+//! nickname (which a reconnecting client takes back) for
+//! [`IDEMPOTENCY_WINDOW_SECS`]. Keys only live in memory, and at most
+//! [`MAX_IDEMPOTENCY_KEYS`] are kept; past that the oldest are forgotten early.
+
+use std::collections::{HashSet, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use chrono::Utc;
+
+use crate::constants::{IDEMPOTENCY_WINDOW_SECS, MAX_IDEMPOTENCY_KEYS};
+
+/// A key as remembered: lowercased sender nickname and the key
+type SeenKey = (String, String);
+
+/// Keys used within the window
+#[derive(Default)]
+struct SeenKeys {
+    /// Keys for lookups
+    keys: HashSet<SeenKey>,
+    /// Keys with their expiry timestamps, oldest first
+    expiry: VecDeque<(i64, SeenKey)>,
+}
+
+impl SeenKeys {
+    /// Record a sender's key at `now`, returning false if they already used it
+    ///
+    /// Keeps at most `max_keys`, forgetting the oldest to make room.
+    fn first_use(&mut self, sender: &str, key: &str, now: i64, max_keys: usize) -> bool {
+        while self
+            .expiry
+            .front()
+            .is_some_and(|(expires, _)| *expires <= now)
+        {
+            self.forget_oldest();
+        }
+
+        let seen_key = (sender.to_lowercase(), key.to_string());
+        if self.keys.contains(&seen_key) {
+            return false;
+        }
+        while self.keys.len() >= max_keys.max(1) {
+            self.forget_oldest();
+        }
+        self.keys.insert(seen_key.clone());
+        self.expiry
+            .push_back((now + IDEMPOTENCY_WINDOW_SECS, seen_key));
+        true
+    }
+
+    /// Forget the oldest key
+    fn forget_oldest(&mut self) {
+        if let Some((_, oldest)) = self.expiry.pop_front() {
+            self.keys.remove(&oldest);
+        }
+    }
+}
+
+/// Idempotency keys used within the window, across all senders
+///
+/// Cheap to clone; clones share the same keys.
+#[derive(Clone, Default)]
+pub struct IdempotencyKeys {
+    seen: Arc<Mutex<SeenKeys>>,
+}
+
+impl IdempotencyKeys {
+    /// Create an empty key table
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a sender's key, returning false if they already used it
+    ///
+    /// Call this once the message has passed validation and is about to be
+    /// delivered, so a rejected message can be sent again with the same key.
+    pub fn first_use(&self, sender: &str, key: &str) -> bool {
+        self.lock()
+            .first_use(sender, key, Utc::now().timestamp(), MAX_IDEMPOTENCY_KEYS)
+    }
+
+    /// Lock the key table
+    fn lock(&self) -> MutexGuard<'_, SeenKeys> {
+        self.seen.lock().expect("idempotency key lock poisoned")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_use() {
+        let keys = IdempotencyKeys::new();
+        assert!(keys.first_use("alice", "key-1"));
+        assert!(!keys.first_use("alice", "key-1"));
+        // Nicknames are case-insensitive
+        assert!(!keys.first_use("ALICE", "key-1"));
+
+        assert!(keys.first_use("alice", "key-2"));
+        assert!(keys.first_use("bob", "key-1"));
+    }
+
+    #[test]
+    fn test_key_expires() {
+        let mut seen = SeenKeys::default();
+        let now = Utc::now().timestamp();
+        assert!(seen.first_use("carol", "key-1", now, MAX_IDEMPOTENCY_KEYS));
+        assert!(!seen.first_use(
+            "carol",
+            "key-1",
+            now + IDEMPOTENCY_WINDOW_SECS - 1,
+            MAX_IDEMPOTENCY_KEYS
+        ));
+        assert!(seen.first_use(
+            "carol",
+            "key-1",
+            now + IDEMPOTENCY_WINDOW_SECS,
+            MAX_IDEMPOTENCY_KEYS
+        ));
+        assert_eq!(seen.keys.len(), 1);
+        assert_eq!(seen.expiry.len(), 1);
+    }
+
+    #[test]
+    fn test_oldest_keys_forgotten_at_cap() {
+        let mut seen = SeenKeys::default();
+        let now = Utc::now().timestamp();
+        assert!(seen.first_use("dave", "key-1", now, 2));
+        assert!(seen.first_use("dave", "key-2", now, 2));
+        assert!(seen.first_use("erin", "key-3", now, 2));
+        assert_eq!(seen.keys.len(), 2);
+        assert_eq!(seen.expiry.len(), 2);
+
+        // The oldest key made room and can be used again; newer ones can't
+        assert!(!seen.first_use("erin", "key-3", now, 2));
+        assert!(!seen.first_use("dave", "key-2", now, 2));
+        assert!(seen.first_use("dave", "key-1", now, 2));
+    }
+
+    #[test]
+    fn test_clones_share_keys() {
+        let keys = IdempotencyKeys::new();
+        assert!(keys.first_use("frank", "key-1"));
+        assert!(!keys.clone().first_use("frank", "key-1"));
+
+        // Separate tables don't
+        assert!(IdempotencyKeys::new().first_use("frank", "key-1"));
+    }
+}
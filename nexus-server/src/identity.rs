@@ -0,0 +1,151 @@
+//! Server identity key
+//!
+//! The identity key is a long-term Ed25519 key stored next to the database
+//! (`identity.key`). Clients pin its public key separately from the TLS
+//! certificate. At startup the server signs a statement naming its current
+//! certificate fingerprint (see [`nexus_common::identity`]), and sends the
+//! public key and signature in every HandshakeResponse. Replacing the
+//! certificate only changes the signature; clients that pinned the identity
+//! key accept the new certificate without a fingerprint mismatch dialog.
+//!
+//! Keep `identity.key` when moving or renewing a server. Losing it means
+//! clients see the new certificate as a mismatch again.
+
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+use ring::rand::SystemRandom;
+use ring::signature::{Ed25519KeyPair, KeyPair};
+
+use nexus_common::identity::identity_statement;
+
+use crate::constants::*;
+
+/// PEM tag for the PKCS#8 identity key
+const IDENTITY_KEY_PEM_TAG: &str = "PRIVATE KEY";
+
+/// The server's identity, as sent in HandshakeResponse
+#[derive(Debug, Clone)]
+pub struct ServerIdentity {
+    /// Hex-encoded Ed25519 public key
+    pub public_key: String,
+    /// Hex-encoded signature over the certificate statement
+    pub signature: String,
+}
+
+impl ServerIdentity {
+    /// Sign the statement for `certificate_fingerprint` with `key_pair`
+    pub fn new(key_pair: &Ed25519KeyPair, certificate_fingerprint: &str) -> Self {
+        let signature = key_pair.sign(&identity_statement(certificate_fingerprint));
+        Self {
+            public_key: hex::encode(key_pair.public_key().as_ref()),
+            signature: hex::encode(signature.as_ref()),
+        }
+    }
+}
+
+/// Load the identity key from `dir`, generating it on first run, and sign
+/// the statement for the current certificate
+pub fn load_or_generate_identity(
+    dir: &Path,
+    certificate_fingerprint: &str,
+) -> Result<ServerIdentity, String> {
+    let key_path = dir.join(IDENTITY_KEY_FILENAME);
+
+    let key_pair = if key_path.exists() {
+        let key_pem =
+            fs::read_to_string(&key_path).map_err(|e| format!("{}{}", ERR_READ_IDENTITY_KEY, e))?;
+        let key_der =
+            pem::parse(&key_pem).map_err(|e| format!("{}{}", ERR_PARSE_IDENTITY_KEY, e))?;
+        Ed25519KeyPair::from_pkcs8(key_der.contents())
+            .map_err(|e| format!("{}{}", ERR_PARSE_IDENTITY_KEY, e))?
+    } else {
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new())
+            .map_err(|_| ERR_GENERATE_IDENTITY_KEY.to_string())?;
+        let key_pem = pem::encode(&pem::Pem::new(IDENTITY_KEY_PEM_TAG, pkcs8.as_ref()));
+        write_private_file(&key_path, key_pem.as_bytes())
+            .map_err(|e| format!("{}{}", ERR_WRITE_IDENTITY_KEY, e))?;
+        println!("{}{}", MSG_IDENTITY_KEY_GENERATED, key_path.display());
+        Ed25519KeyPair::from_pkcs8(pkcs8.as_ref())
+            .map_err(|e| format!("{}{}", ERR_PARSE_IDENTITY_KEY, e))?
+    };
+
+    Ok(ServerIdentity::new(&key_pair, certificate_fingerprint))
+}
+
+/// Create a file readable only by the owner (on Unix) and write `contents`
+pub(crate) fn write_private_file(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options.open(path)?.write_all(contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use ring::signature::{ED25519, UnparsedPublicKey};
+    use tempfile::TempDir;
+
+    use super::*;
+
+    const FINGERPRINT: &str = "AA:BB:CC:DD";
+
+    fn verify(identity: &ServerIdentity, fingerprint: &str) -> bool {
+        let public_key = hex::decode(&identity.public_key).unwrap();
+        let signature = hex::decode(&identity.signature).unwrap();
+        UnparsedPublicKey::new(&ED25519, public_key)
+            .verify(&identity_statement(fingerprint), &signature)
+            .is_ok()
+    }
+
+    #[test]
+    fn test_generate_and_reload() {
+        let temp = TempDir::new().unwrap();
+
+        let first = load_or_generate_identity(temp.path(), FINGERPRINT).unwrap();
+        assert!(temp.path().join(IDENTITY_KEY_FILENAME).exists());
+        assert_eq!(
+            first.public_key.len(),
+            nexus_common::identity::IDENTITY_KEY_LENGTH
+        );
+        assert_eq!(
+            first.signature.len(),
+            nexus_common::identity::IDENTITY_SIGNATURE_LENGTH
+        );
+        assert!(verify(&first, FINGERPRINT));
+
+        // Same key after reload, new signature for a rotated certificate
+        let rotated = load_or_generate_identity(temp.path(), "11:22:33:44").unwrap();
+        assert_eq!(rotated.public_key, first.public_key);
+        assert!(verify(&rotated, "11:22:33:44"));
+        assert!(!verify(&rotated, FINGERPRINT));
+    }
+
+    #[test]
+    fn test_corrupt_key_file() {
+        let temp = TempDir::new().unwrap();
+        fs::write(temp.path().join(IDENTITY_KEY_FILENAME), "not a key").unwrap();
+
+        assert!(load_or_generate_identity(temp.path(), FINGERPRINT).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_key_file_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp = TempDir::new().unwrap();
+        load_or_generate_identity(temp.path(), FINGERPRINT).unwrap();
+
+        let mode = fs::metadata(temp.path().join(IDENTITY_KEY_FILENAME))
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+}
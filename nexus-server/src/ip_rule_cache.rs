@@ -17,7 +17,11 @@
 //!     !cache.is_banned(ip)
 //! }
 //! ```
+//!
+//! The cache also remembers DNS blocklist results (see `reputation`), so an
+//! address is looked up at most once per TTL.
 
+use std::collections::HashMap;
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -27,6 +31,9 @@ use iprange::IpRange;
 use crate::db::bans::BanRecord;
 use crate::db::trusts::TrustRecord;
 
+/// Most DNS blocklist results kept (expired ones are dropped first)
+const MAX_REPUTATION_ENTRIES: usize = 10_000;
+
 /// A cached rule entry (used for both bans and trusts)
 #[derive(Debug, Clone)]
 struct RuleEntry {
@@ -38,6 +45,15 @@ struct RuleEntry {
     expires_at: Option<i64>,
 }
 
+/// A cached DNS blocklist result
+#[derive(Debug, Clone)]
+struct CachedReputation {
+    /// Blocklist zone listing the address (None = not listed)
+    listed_in: Option<String>,
+    /// Unix timestamp when the address should be looked up again
+    expires_at: i64,
+}
+
 /// In-memory cache for IP access rules (trusts and bans)
 ///
 /// Provides fast O(log n) lookups using radix tries.
@@ -58,6 +74,8 @@ pub struct IpRuleCache {
     ban_entries: Vec<RuleEntry>,
     /// Earliest expiry timestamp across both trusts and bans (None if all are permanent)
     next_expiry: Option<i64>,
+    /// DNS blocklist results by (normalized) address
+    reputation: HashMap<IpAddr, CachedReputation>,
 }
 
 impl IpRuleCache {
@@ -71,6 +89,7 @@ impl IpRuleCache {
             trust_entries: Vec::new(),
             ban_entries: Vec::new(),
             next_expiry: None,
+            reputation: HashMap::new(),
         }
     }
 
@@ -195,6 +214,44 @@ impl IpRuleCache {
         }
     }
 
+    /// Get the cached DNS blocklist result for an IP address
+    ///
+    /// Returns `None` if the address hasn't been looked up or its result has
+    /// expired, `Some(None)` if it isn't listed, and `Some(Some(zone))` with
+    /// the blocklist zone listing it.
+    ///
+    /// IPv4-mapped IPv6 addresses (e.g., `::ffff:192.168.1.100`) are automatically
+    /// normalized to IPv4 before checking.
+    pub fn cached_reputation(&self, ip: IpAddr) -> Option<Option<String>> {
+        let ip = normalize_ip(ip);
+        self.reputation
+            .get(&ip)
+            .filter(|entry| entry.expires_at > current_timestamp())
+            .map(|entry| entry.listed_in.clone())
+    }
+
+    /// Cache a DNS blocklist result for an IP address until `expires_at`
+    ///
+    /// When the cache is full, expired results are dropped; if that isn't
+    /// enough, all results are dropped and will be looked up again.
+    pub fn set_reputation(&mut self, ip: IpAddr, listed_in: Option<String>, expires_at: i64) {
+        let ip = normalize_ip(ip);
+        if self.reputation.len() >= MAX_REPUTATION_ENTRIES && !self.reputation.contains_key(&ip) {
+            let now = current_timestamp();
+            self.reputation.retain(|_, entry| entry.expires_at > now);
+            if self.reputation.len() >= MAX_REPUTATION_ENTRIES {
+                self.reputation.clear();
+            }
+        }
+        self.reputation.insert(
+            ip,
+            CachedReputation {
+                listed_in,
+                expires_at,
+            },
+        );
+    }
+
     /// Check if the cache needs to be rebuilt due to expired entries
     ///
     /// This is a read-only check that can be used to determine if a write
@@ -479,7 +536,7 @@ pub fn parse_ip_or_cidr(s: &str) -> Option<IpNet> {
 /// This ensures that rules for `192.168.1.100` also match connections that
 /// appear as `::ffff:192.168.1.100` (which can happen when the server binds
 /// to `::` and receives IPv4 connections).
-fn normalize_ip(ip: IpAddr) -> IpAddr {
+pub fn normalize_ip(ip: IpAddr) -> IpAddr {
     match ip {
         IpAddr::V6(v6) => {
             if let Some(v4) = to_ipv4_mapped(&v6) {
@@ -967,4 +1024,59 @@ mod tests {
         assert_eq!(cache.trust_count(), 1);
         assert_eq!(cache.next_expiry, Some(now + 1800)); // Updated to earlier expiry
     }
+
+    // =========================================================================
+    // Reputation tests
+    // =========================================================================
+
+    #[test]
+    fn test_reputation_cache() {
+        let mut cache = IpRuleCache::new();
+        let now = current_timestamp();
+        let listed: IpAddr = "203.0.113.5".parse().unwrap();
+        let clean: IpAddr = "203.0.113.6".parse().unwrap();
+
+        assert_eq!(cache.cached_reputation(listed), None);
+
+        cache.set_reputation(listed, Some("dnsbl.example".to_string()), now + 3600);
+        cache.set_reputation(clean, None, now + 3600);
+        assert_eq!(
+            cache.cached_reputation(listed),
+            Some(Some("dnsbl.example".to_string()))
+        );
+        assert_eq!(cache.cached_reputation(clean), Some(None));
+
+        // IPv4-mapped IPv6 shares the IPv4 entry
+        let mapped: IpAddr = "::ffff:203.0.113.5".parse().unwrap();
+        assert_eq!(
+            cache.cached_reputation(mapped),
+            Some(Some("dnsbl.example".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_reputation_cache_expiry() {
+        let mut cache = IpRuleCache::new();
+        let now = current_timestamp();
+        let ip: IpAddr = "203.0.113.5".parse().unwrap();
+
+        cache.set_reputation(ip, Some("dnsbl.example".to_string()), now - 1);
+        assert_eq!(cache.cached_reputation(ip), None);
+    }
+
+    #[test]
+    fn test_reputation_cache_is_bounded() {
+        let mut cache = IpRuleCache::new();
+        let now = current_timestamp();
+
+        for i in 0..MAX_REPUTATION_ENTRIES as u32 {
+            cache.set_reputation(IpAddr::V4(Ipv4Addr::from(i)), None, now + 3600);
+        }
+        assert_eq!(cache.reputation.len(), MAX_REPUTATION_ENTRIES);
+
+        let ip: IpAddr = "203.0.113.5".parse().unwrap();
+        cache.set_reputation(ip, None, now + 3600);
+        assert!(cache.reputation.len() <= MAX_REPUTATION_ENTRIES);
+        assert_eq!(cache.cached_reputation(ip), Some(None));
+    }
 }
@@ -0,0 +1,316 @@
+//! BER encoding for LDAP messages
+//!
+//! LDAP messages are ASN.1 structures sent in BER (RFC 4511 section 5.1).
+//! The server only needs a handful of them, so this covers just enough: the
+//! universal types LDAP uses, definite lengths, and single-byte tags.
+
+/// Universal and LDAP tags used by the server
+pub(super) mod tag {
+    pub const BOOLEAN: u8 = 0x01;
+    pub const INTEGER: u8 = 0x02;
+    pub const OCTET_STRING: u8 = 0x04;
+    pub const ENUMERATED: u8 = 0x0a;
+    pub const SEQUENCE: u8 = 0x30;
+    pub const SET: u8 = 0x31;
+
+    pub const BIND_REQUEST: u8 = 0x60;
+    pub const BIND_RESPONSE: u8 = 0x61;
+    pub const UNBIND_REQUEST: u8 = 0x42;
+    pub const SEARCH_REQUEST: u8 = 0x63;
+    pub const SEARCH_RESULT_ENTRY: u8 = 0x64;
+    pub const SEARCH_RESULT_DONE: u8 = 0x65;
+    pub const SEARCH_RESULT_REFERENCE: u8 = 0x73;
+
+    /// Simple authentication choice in a bind request
+    pub const SIMPLE_AUTH: u8 = 0x80;
+}
+
+/// Encode an element with the given tag and content
+pub(super) fn element(tag: u8, content: &[u8]) -> Vec<u8> {
+    let mut encoded = Vec::with_capacity(content.len() + 6);
+    encoded.push(tag);
+    let len = content.len();
+    if len < 0x80 {
+        encoded.push(len as u8);
+    } else {
+        let bytes = len.to_be_bytes();
+        let skip = bytes.iter().take_while(|&&b| b == 0).count();
+        encoded.push(0x80 | (bytes.len() - skip) as u8);
+        encoded.extend_from_slice(&bytes[skip..]);
+    }
+    encoded.extend_from_slice(content);
+    encoded
+}
+
+/// Encode a constructed element from already encoded parts
+pub(super) fn constructed(tag: u8, parts: &[Vec<u8>]) -> Vec<u8> {
+    element(tag, &parts.concat())
+}
+
+/// Encode an integer (or enumerated) in the fewest bytes
+pub(super) fn integer(tag: u8, value: i64) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let mut start = 0;
+    // Drop leading bytes that only repeat the sign of the next one
+    while start < bytes.len() - 1
+        && ((bytes[start] == 0x00 && bytes[start + 1] & 0x80 == 0)
+            || (bytes[start] == 0xff && bytes[start + 1] & 0x80 != 0))
+    {
+        start += 1;
+    }
+    element(tag, &bytes[start..])
+}
+
+/// Encode a boolean
+pub(super) fn boolean(value: bool) -> Vec<u8> {
+    element(tag::BOOLEAN, &[if value { 0xff } else { 0x00 }])
+}
+
+/// Encode an octet string
+pub(super) fn octet_string(value: &[u8]) -> Vec<u8> {
+    element(tag::OCTET_STRING, value)
+}
+
+/// Split the header of an element from the start of a buffer
+///
+/// Returns the tag, the header length and the content length, or `None` if
+/// the buffer doesn't hold the whole header yet.
+pub(super) fn header(data: &[u8]) -> Result<Option<(u8, usize, usize)>, String> {
+    let (Some(&tag), Some(&first)) = (data.first(), data.get(1)) else {
+        return Ok(None);
+    };
+    if tag & 0x1f == 0x1f {
+        return Err("multi-byte tags are not supported".to_string());
+    }
+    if first < 0x80 {
+        return Ok(Some((tag, 2, first as usize)));
+    }
+    let count = (first & 0x7f) as usize;
+    if count == 0 || count > 4 {
+        return Err("unsupported length encoding".to_string());
+    }
+    let Some(bytes) = data.get(2..2 + count) else {
+        return Ok(None);
+    };
+    let len = bytes.iter().fold(0usize, |len, &b| (len << 8) | b as usize);
+    Ok(Some((tag, 2 + count, len)))
+}
+
+/// A decoded element borrowing its content
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct Element<'a> {
+    pub tag: u8,
+    pub content: &'a [u8],
+}
+
+impl<'a> Element<'a> {
+    /// The elements inside a constructed element
+    pub fn children(&self) -> Reader<'a> {
+        Reader::new(self.content)
+    }
+
+    /// The value of an integer or enumerated element
+    pub fn integer(&self) -> Result<i64, String> {
+        if self.content.is_empty() || self.content.len() > 8 {
+            return Err("malformed integer".to_string());
+        }
+        let negative = self.content[0] & 0x80 != 0;
+        let start = if negative { -1i64 } else { 0 };
+        Ok(self
+            .content
+            .iter()
+            .fold(start, |value, &b| (value << 8) | b as i64))
+    }
+
+    /// The value of an octet string as text (invalid UTF-8 is replaced)
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(self.content).into_owned()
+    }
+}
+
+/// Reads consecutive elements from a buffer
+#[derive(Debug, Clone)]
+pub(super) struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    /// Whether all elements have been read
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Read the next element, if any
+    pub fn next_element(&mut self) -> Result<Option<Element<'a>>, String> {
+        if self.data.is_empty() {
+            return Ok(None);
+        }
+        let (tag, header_len, len) = header(self.data)?.ok_or("truncated element")?;
+        let end = header_len.checked_add(len).ok_or("element too long")?;
+        let content = self.data.get(header_len..end).ok_or("truncated element")?;
+        self.data = &self.data[end..];
+        Ok(Some(Element { tag, content }))
+    }
+
+    /// Read the next element, which must have the given tag
+    pub fn expect(&mut self, tag: u8) -> Result<Element<'a>, String> {
+        match self.next_element()? {
+            Some(element) if element.tag == tag => Ok(element),
+            Some(element) => Err(format!(
+                "expected tag {tag:#04x}, found {:#04x}",
+                element.tag
+            )),
+            None => Err(format!("expected tag {tag:#04x}, found end of data")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Walk every element (and the elements inside them) the way the client does
+    fn walk(data: &[u8], depth: usize) {
+        let mut reader = Reader::new(data);
+        while let Ok(Some(element)) = reader.next_element() {
+            let _ = element.integer();
+            let _ = element.text();
+            if depth > 0 {
+                walk(element.content, depth - 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_integer() {
+        assert_eq!(integer(tag::INTEGER, 0), [0x02, 0x01, 0x00]);
+        assert_eq!(integer(tag::INTEGER, 3), [0x02, 0x01, 0x03]);
+        assert_eq!(integer(tag::INTEGER, 128), [0x02, 0x02, 0x00, 0x80]);
+        assert_eq!(integer(tag::INTEGER, 256), [0x02, 0x02, 0x01, 0x00]);
+        assert_eq!(integer(tag::INTEGER, -1), [0x02, 0x01, 0xff]);
+        assert_eq!(integer(tag::INTEGER, -129), [0x02, 0x02, 0xff, 0x7f]);
+        assert_eq!(integer(tag::ENUMERATED, 2), [0x0a, 0x01, 0x02]);
+
+        for value in [
+            0,
+            1,
+            127,
+            128,
+            255,
+            256,
+            65_535,
+            -1,
+            -128,
+            -129,
+            i64::MAX,
+            i64::MIN,
+        ] {
+            let encoded = integer(tag::INTEGER, value);
+            let element = Reader::new(&encoded).expect(tag::INTEGER).unwrap();
+            assert_eq!(element.integer().unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn test_long_lengths() {
+        let content = vec![b'x'; 300];
+        let encoded = octet_string(&content);
+        assert_eq!(&encoded[..4], [0x04, 0x82, 0x01, 0x2c]);
+        assert_eq!(header(&encoded).unwrap(), Some((0x04, 4, 300)));
+        let element = Reader::new(&encoded).expect(tag::OCTET_STRING).unwrap();
+        assert_eq!(element.content, &content[..]);
+
+        let encoded = octet_string(&[b'y'; 127]);
+        assert_eq!(&encoded[..2], [0x04, 0x7f]);
+    }
+
+    #[test]
+    fn test_nested() {
+        let encoded = constructed(
+            tag::SEQUENCE,
+            &[
+                integer(tag::INTEGER, 1),
+                boolean(true),
+                octet_string(b"dc=example"),
+            ],
+        );
+        let mut reader = Reader::new(&encoded);
+        let sequence = reader.expect(tag::SEQUENCE).unwrap();
+        assert!(reader.is_empty());
+
+        let mut children = sequence.children();
+        assert_eq!(children.expect(tag::INTEGER).unwrap().integer(), Ok(1));
+        assert_eq!(children.expect(tag::BOOLEAN).unwrap().content, [0xff]);
+        assert_eq!(
+            children.expect(tag::OCTET_STRING).unwrap().text(),
+            "dc=example"
+        );
+        assert_eq!(children.next_element(), Ok(None));
+    }
+
+    #[test]
+    fn test_partial_and_malformed() {
+        assert_eq!(header(&[]), Ok(None));
+        assert_eq!(header(&[0x30]), Ok(None));
+        assert_eq!(header(&[0x30, 0x82, 0x01]), Ok(None));
+        assert!(header(&[0x30, 0x80]).is_err());
+        assert!(header(&[0x30, 0x85, 0, 0, 0, 0, 1]).is_err());
+        assert!(header(&[0x1f, 0x01]).is_err());
+
+        assert!(Reader::new(&[0x04, 0x05, b'a']).next_element().is_err());
+        assert!(
+            Reader::new(&[0x02, 0x01, 0x00])
+                .expect(tag::OCTET_STRING)
+                .is_err()
+        );
+        assert!(
+            Reader::new(&[0x02, 0x00])
+                .expect(tag::INTEGER)
+                .unwrap()
+                .integer()
+                .is_err()
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn prop_arbitrary_bytes_never_panic(data in proptest::collection::vec(any::<u8>(), 0..512)) {
+            if let Ok(Some((_, header_len, _))) = header(&data) {
+                prop_assert!(header_len <= data.len());
+            }
+            walk(&data, 8);
+        }
+
+        #[test]
+        fn prop_integer_round_trips(value in any::<i64>()) {
+            let encoded = integer(tag::INTEGER, value);
+            let mut reader = Reader::new(&encoded);
+            prop_assert_eq!(reader.expect(tag::INTEGER).unwrap().integer(), Ok(value));
+            prop_assert!(reader.is_empty());
+        }
+
+        #[test]
+        fn prop_octet_string_round_trips(content in proptest::collection::vec(any::<u8>(), 0..70_000)) {
+            let encoded = octet_string(&content);
+            let mut reader = Reader::new(&encoded);
+            let element = reader.expect(tag::OCTET_STRING).unwrap();
+            prop_assert_eq!(element.content, &content[..]);
+            prop_assert!(reader.is_empty());
+        }
+
+        #[test]
+        fn prop_truncated_element_is_never_read(
+            content in proptest::collection::vec(any::<u8>(), 0..400),
+            cut in any::<prop::sample::Index>(),
+        ) {
+            let encoded = octet_string(&content);
+            let truncated = &encoded[..cut.index(encoded.len())];
+            prop_assert!(!matches!(Reader::new(truncated).next_element(), Ok(Some(_))));
+        }
+    }
+}
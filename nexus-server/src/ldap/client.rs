@@ -0,0 +1,536 @@
+//! Minimal LDAP client
+//!
+//! Logging in against a directory takes two operations: a simple bind with
+//! the user's DN and password, and (bound as the user) a search for the
+//! user's entry to read the groups it is a member of. Messages are sent one
+//! at a time and each answer is read before the next request, so there is no
+//! need to match up interleaved responses.
+
+use std::sync::Arc;
+
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio_rustls::TlsConnector;
+use tokio_rustls::rustls::pki_types::{CertificateDer, ServerName};
+use tokio_rustls::rustls::{ClientConfig, RootCertStore};
+
+use super::ber::{self, Reader, tag};
+
+/// Largest message accepted from the directory
+const MAX_MESSAGE_SIZE: usize = 1024 * 1024;
+
+/// LDAP protocol version sent in binds
+const LDAP_VERSION: i64 = 3;
+
+/// Result codes the client tells apart (RFC 4511 appendix A)
+const RESULT_SUCCESS: i64 = 0;
+const RESULT_SIZE_LIMIT_EXCEEDED: i64 = 4;
+const RESULT_NO_SUCH_OBJECT: i64 = 32;
+const RESULT_INVALID_CREDENTIALS: i64 = 49;
+
+/// Search scope covering the base object and everything below it
+const SCOPE_WHOLE_SUBTREE: i64 = 2;
+
+/// Never dereference aliases while searching
+const DEREF_NEVER: i64 = 0;
+
+/// Attribute listing the groups an entry is a member of
+const MEMBER_OF: &str = "memberOf";
+
+/// Where the directory is
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct LdapUrl {
+    pub host: String,
+    pub port: u16,
+    /// Whether the connection uses TLS from the start (`ldaps://`)
+    pub tls: bool,
+}
+
+impl LdapUrl {
+    /// Parse an `ldaps://` or `ldap://` URL (the path, if any, is ignored)
+    pub fn parse(url: &str) -> Result<Self, String> {
+        let (rest, tls, default_port) = if let Some(rest) = url.strip_prefix("ldaps://") {
+            (rest, true, 636)
+        } else if let Some(rest) = url.strip_prefix("ldap://") {
+            (rest, false, 389)
+        } else {
+            return Err(format!("not an ldap:// or ldaps:// URL: {url}"));
+        };
+        let authority = rest.split(['/', '?']).next().unwrap_or_default();
+        if authority.is_empty() || authority.contains(['@', '[', ']']) {
+            return Err(format!("unsupported host in URL: {url}"));
+        }
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) => (
+                host,
+                port.parse::<u16>()
+                    .map_err(|_| format!("invalid port in URL: {url}"))?,
+            ),
+            None => (authority, default_port),
+        };
+        if host.is_empty() {
+            return Err(format!("missing host in URL: {url}"));
+        }
+        Ok(Self {
+            host: host.to_ascii_lowercase(),
+            port,
+            tls,
+        })
+    }
+}
+
+/// Build the TLS connector for `ldaps://`, trusting the Mozilla roots and any extra CAs
+pub(super) fn tls_connector(
+    extra_roots: Vec<CertificateDer<'static>>,
+) -> Result<TlsConnector, String> {
+    let mut roots = RootCertStore {
+        roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
+    };
+    for cert in extra_roots {
+        roots
+            .add(cert)
+            .map_err(|e| format!("invalid CA certificate: {e}"))?;
+    }
+    let config = ClientConfig::builder()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+    Ok(TlsConnector::from(Arc::new(config)))
+}
+
+/// Open a connection to the directory
+pub(super) async fn connect(
+    url: &LdapUrl,
+    tls: &TlsConnector,
+) -> Result<Connection<Box<dyn Stream>>, String> {
+    let tcp = TcpStream::connect((url.host.as_str(), url.port))
+        .await
+        .map_err(|e| format!("failed to connect to {}: {e}", url.host))?;
+    let stream: Box<dyn Stream> = if url.tls {
+        let server_name = ServerName::try_from(url.host.clone())
+            .map_err(|e| format!("invalid host {}: {e}", url.host))?;
+        Box::new(
+            tls.connect(server_name, tcp)
+                .await
+                .map_err(|e| format!("TLS handshake with {} failed: {e}", url.host))?,
+        )
+    } else {
+        Box::new(tcp)
+    };
+    Ok(Connection::new(stream))
+}
+
+/// A plain or TLS stream to the directory
+pub(super) trait Stream: AsyncRead + AsyncWrite + Unpin + Send {}
+
+impl<S: AsyncRead + AsyncWrite + Unpin + Send> Stream for S {}
+
+/// The entry found for a user
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct UserEntry {
+    /// Distinguished name of the entry
+    pub dn: String,
+    /// Distinguished names of the groups it is a member of
+    pub groups: Vec<String>,
+}
+
+/// An open LDAP connection
+pub(super) struct Connection<S> {
+    stream: S,
+    next_message_id: i64,
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> Connection<S> {
+    pub fn new(stream: S) -> Self {
+        Self {
+            stream,
+            next_message_id: 1,
+        }
+    }
+
+    /// Simple bind; returns false if the directory refuses the credentials
+    ///
+    /// An empty password must never get here: most directories treat a bind
+    /// without one as an anonymous bind and report success.
+    pub async fn bind(&mut self, dn: &str, password: &str) -> Result<bool, String> {
+        debug_assert!(
+            !password.is_empty(),
+            "empty password would bind anonymously"
+        );
+        let request = ber::constructed(
+            tag::BIND_REQUEST,
+            &[
+                ber::integer(tag::INTEGER, LDAP_VERSION),
+                ber::octet_string(dn.as_bytes()),
+                ber::element(tag::SIMPLE_AUTH, password.as_bytes()),
+            ],
+        );
+        let message_id = self.send(request).await?;
+
+        let response = self.receive(message_id).await?;
+        let mut reader = Reader::new(&response);
+        let op = reader.expect(tag::BIND_RESPONSE)?;
+        match result_code(op.children())? {
+            (RESULT_SUCCESS, _) => Ok(true),
+            (RESULT_INVALID_CREDENTIALS, _) => Ok(false),
+            (code, message) => Err(format!("bind failed with result {code}: {message}")),
+        }
+    }
+
+    /// Find the one entry under `base` matching `filter` and read its groups
+    ///
+    /// Returns `None` if no entry or more than one entry matches.
+    pub async fn find_user(
+        &mut self,
+        base: &str,
+        filter: &[u8],
+    ) -> Result<Option<UserEntry>, String> {
+        let request = ber::constructed(
+            tag::SEARCH_REQUEST,
+            &[
+                ber::octet_string(base.as_bytes()),
+                ber::integer(tag::ENUMERATED, SCOPE_WHOLE_SUBTREE),
+                ber::integer(tag::ENUMERATED, DEREF_NEVER),
+                // Two entries are enough to tell that a filter is ambiguous
+                ber::integer(tag::INTEGER, 2),
+                ber::integer(tag::INTEGER, 0),
+                ber::boolean(false),
+                filter.to_vec(),
+                ber::constructed(tag::SEQUENCE, &[ber::octet_string(MEMBER_OF.as_bytes())]),
+            ],
+        );
+        let message_id = self.send(request).await?;
+
+        let mut entries = Vec::new();
+        loop {
+            let response = self.receive(message_id).await?;
+            let mut reader = Reader::new(&response);
+            let op = reader.next_element()?.ok_or("empty search response")?;
+            match op.tag {
+                tag::SEARCH_RESULT_ENTRY => entries.push(parse_entry(op.children())?),
+                // Referrals to other directories aren't followed
+                tag::SEARCH_RESULT_REFERENCE => {}
+                tag::SEARCH_RESULT_DONE => {
+                    return match result_code(op.children())? {
+                        (RESULT_SUCCESS, _) if entries.len() == 1 => Ok(entries.pop()),
+                        (
+                            RESULT_SUCCESS | RESULT_SIZE_LIMIT_EXCEEDED | RESULT_NO_SUCH_OBJECT,
+                            _,
+                        ) => Ok(None),
+                        (code, message) => {
+                            Err(format!("search failed with result {code}: {message}"))
+                        }
+                    };
+                }
+                other => return Err(format!("unexpected search response {other:#04x}")),
+            }
+        }
+    }
+
+    /// Say goodbye (errors are ignored; the connection is closing anyway)
+    pub async fn unbind(mut self) {
+        let request = ber::element(tag::UNBIND_REQUEST, &[]);
+        let _ = self.send(request).await;
+        let _ = self.stream.shutdown().await;
+    }
+
+    /// Wrap a protocol operation in an LDAPMessage and send it
+    async fn send(&mut self, op: Vec<u8>) -> Result<i64, String> {
+        let message_id = self.next_message_id;
+        self.next_message_id += 1;
+        let message =
+            ber::constructed(tag::SEQUENCE, &[ber::integer(tag::INTEGER, message_id), op]);
+        self.stream
+            .write_all(&message)
+            .await
+            .map_err(|e| format!("failed to send request: {e}"))?;
+        self.stream
+            .flush()
+            .await
+            .map_err(|e| format!("failed to send request: {e}"))?;
+        Ok(message_id)
+    }
+
+    /// Read the next message for a request and return its protocol operation
+    async fn receive(&mut self, message_id: i64) -> Result<Vec<u8>, String> {
+        let message = self.read_message().await?;
+        let mut reader = Reader::new(&message);
+        let mut children = reader.expect(tag::SEQUENCE)?.children();
+        let id = children.expect(tag::INTEGER)?.integer()?;
+        if id == 0 {
+            // Unsolicited notification, which in practice means "disconnecting"
+            return Err("the directory closed the connection".to_string());
+        }
+        if id != message_id {
+            return Err(format!("response for unknown request {id}"));
+        }
+        let op = children
+            .next_element()?
+            .ok_or("response has no operation")?;
+        Ok(ber::element(op.tag, op.content))
+    }
+
+    /// Read one whole BER element from the stream
+    async fn read_message(&mut self) -> Result<Vec<u8>, String> {
+        let mut message = vec![0u8; 2];
+        self.read_exact(&mut message).await?;
+        let (header_len, len) = loop {
+            if let Some((_, header_len, len)) = ber::header(&message)? {
+                break (header_len, len);
+            }
+            let mut byte = [0u8; 1];
+            self.read_exact(&mut byte).await?;
+            message.push(byte[0]);
+        };
+        if len > MAX_MESSAGE_SIZE {
+            return Err("response too large".to_string());
+        }
+        message.resize(header_len + len, 0);
+        self.read_exact(&mut message[header_len..]).await?;
+        Ok(message)
+    }
+
+    async fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), String> {
+        self.stream
+            .read_exact(buf)
+            .await
+            .map(|_| ())
+            .map_err(|e| format!("failed to read response: {e}"))
+    }
+}
+
+/// Read the result code and diagnostic message of an LDAPResult
+fn result_code(mut fields: Reader<'_>) -> Result<(i64, String), String> {
+    let code = fields.expect(tag::ENUMERATED)?.integer()?;
+    let _matched_dn = fields.expect(tag::OCTET_STRING)?;
+    let message = fields.expect(tag::OCTET_STRING)?.text();
+    Ok((code, message))
+}
+
+/// Read the DN and `memberOf` values of a search result entry
+fn parse_entry(mut fields: Reader<'_>) -> Result<UserEntry, String> {
+    let dn = fields.expect(tag::OCTET_STRING)?.text();
+    let mut groups = Vec::new();
+    let mut attributes = fields.expect(tag::SEQUENCE)?.children();
+    while let Some(attribute) = attributes.next_element()? {
+        let mut parts = attribute.children();
+        let name = parts.expect(tag::OCTET_STRING)?.text();
+        let mut values = parts.expect(tag::SET)?.children();
+        if !name.eq_ignore_ascii_case(MEMBER_OF) {
+            continue;
+        }
+        while let Some(value) = values.next_element()? {
+            groups.push(value.text());
+        }
+    }
+    Ok(UserEntry { dn, groups })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{DuplexStream, duplex};
+
+    /// An LDAPMessage from the directory
+    fn message(message_id: i64, op: Vec<u8>) -> Vec<u8> {
+        ber::constructed(tag::SEQUENCE, &[ber::integer(tag::INTEGER, message_id), op])
+    }
+
+    fn result(op_tag: u8, code: i64, diagnostic: &str) -> Vec<u8> {
+        ber::constructed(
+            op_tag,
+            &[
+                ber::integer(tag::ENUMERATED, code),
+                ber::octet_string(b""),
+                ber::octet_string(diagnostic.as_bytes()),
+            ],
+        )
+    }
+
+    fn entry(dn: &str, groups: &[&str]) -> Vec<u8> {
+        let values: Vec<Vec<u8>> = groups
+            .iter()
+            .map(|group| ber::octet_string(group.as_bytes()))
+            .collect();
+        ber::constructed(
+            tag::SEARCH_RESULT_ENTRY,
+            &[
+                ber::octet_string(dn.as_bytes()),
+                ber::constructed(
+                    tag::SEQUENCE,
+                    &[
+                        ber::constructed(
+                            tag::SEQUENCE,
+                            &[
+                                ber::octet_string(b"cn"),
+                                ber::constructed(tag::SET, &[ber::octet_string(b"Alice")]),
+                            ],
+                        ),
+                        ber::constructed(
+                            tag::SEQUENCE,
+                            &[
+                                ber::octet_string(b"memberOf"),
+                                ber::constructed(tag::SET, &values),
+                            ],
+                        ),
+                    ],
+                ),
+            ],
+        )
+    }
+
+    /// Read one request from the client side of a duplex stream
+    async fn read_request(server: &mut DuplexStream) -> (i64, u8, Vec<u8>) {
+        let mut connection = Connection::new(server);
+        let message = connection.read_message().await.unwrap();
+        let mut reader = Reader::new(&message);
+        let mut children = reader.expect(tag::SEQUENCE).unwrap().children();
+        let id = children.expect(tag::INTEGER).unwrap().integer().unwrap();
+        let op = children.next_element().unwrap().unwrap();
+        (id, op.tag, op.content.to_vec())
+    }
+
+    #[test]
+    fn test_parse_url() {
+        assert_eq!(
+            LdapUrl::parse("ldaps://DC1.example.com").unwrap(),
+            LdapUrl {
+                host: "dc1.example.com".to_string(),
+                port: 636,
+                tls: true,
+            }
+        );
+        assert_eq!(
+            LdapUrl::parse("ldap://ldap.example.com:3389/").unwrap(),
+            LdapUrl {
+                host: "ldap.example.com".to_string(),
+                port: 3389,
+                tls: false,
+            }
+        );
+        assert!(LdapUrl::parse("https://ldap.example.com").is_err());
+        assert!(LdapUrl::parse("ldaps://").is_err());
+        assert!(LdapUrl::parse("ldaps://user@host").is_err());
+        assert!(LdapUrl::parse("ldaps://host:x").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_bind() {
+        let (client, mut server) = duplex(4096);
+        let directory = tokio::spawn(async move {
+            let (id, op, content) = read_request(&mut server).await;
+            assert_eq!(op, tag::BIND_REQUEST);
+            let mut fields = Reader::new(&content);
+            assert_eq!(fields.expect(tag::INTEGER).unwrap().integer(), Ok(3));
+            assert_eq!(
+                fields.expect(tag::OCTET_STRING).unwrap().text(),
+                "uid=alice,dc=example"
+            );
+            assert_eq!(fields.expect(tag::SIMPLE_AUTH).unwrap().content, b"secret");
+            server
+                .write_all(&message(id, result(tag::BIND_RESPONSE, 0, "")))
+                .await
+                .unwrap();
+
+            let (id, _, _) = read_request(&mut server).await;
+            server
+                .write_all(&message(
+                    id,
+                    result(tag::BIND_RESPONSE, 49, "80090308: LdapErr"),
+                ))
+                .await
+                .unwrap();
+
+            let (id, _, _) = read_request(&mut server).await;
+            server
+                .write_all(&message(id, result(tag::BIND_RESPONSE, 51, "busy")))
+                .await
+                .unwrap();
+        });
+
+        let mut connection = Connection::new(client);
+        assert_eq!(
+            connection.bind("uid=alice,dc=example", "secret").await,
+            Ok(true)
+        );
+        assert_eq!(
+            connection.bind("uid=alice,dc=example", "wrong").await,
+            Ok(false)
+        );
+        assert!(connection.bind("uid=alice,dc=example", "x").await.is_err());
+        directory.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_find_user() {
+        let (client, mut server) = duplex(4096);
+        let directory = tokio::spawn(async move {
+            let (id, op, content) = read_request(&mut server).await;
+            assert_eq!(op, tag::SEARCH_REQUEST);
+            let mut fields = Reader::new(&content);
+            assert_eq!(
+                fields.expect(tag::OCTET_STRING).unwrap().text(),
+                "dc=example"
+            );
+            let mut replies = entry(
+                "uid=alice,ou=people,dc=example",
+                &["cn=staff,dc=example", "cn=it,dc=example"],
+            );
+            replies = message(id, replies);
+            replies.extend(message(
+                id,
+                ber::constructed(
+                    tag::SEARCH_RESULT_REFERENCE,
+                    &[ber::octet_string(b"ldap://other/")],
+                ),
+            ));
+            replies.extend(message(id, result(tag::SEARCH_RESULT_DONE, 0, "")));
+            server.write_all(&replies).await.unwrap();
+
+            // Nothing found
+            let (id, _, _) = read_request(&mut server).await;
+            server
+                .write_all(&message(id, result(tag::SEARCH_RESULT_DONE, 32, "")))
+                .await
+                .unwrap();
+
+            // Ambiguous
+            let (id, _, _) = read_request(&mut server).await;
+            let mut replies = message(id, entry("uid=a,dc=example", &[]));
+            replies.extend(message(id, entry("uid=b,dc=example", &[])));
+            replies.extend(message(id, result(tag::SEARCH_RESULT_DONE, 4, "")));
+            server.write_all(&replies).await.unwrap();
+        });
+
+        let filter = crate::ldap::filter::encode_filter("(uid=alice)").unwrap();
+        let mut connection = Connection::new(client);
+        assert_eq!(
+            connection.find_user("dc=example", &filter).await,
+            Ok(Some(UserEntry {
+                dn: "uid=alice,ou=people,dc=example".to_string(),
+                groups: vec![
+                    "cn=staff,dc=example".to_string(),
+                    "cn=it,dc=example".to_string()
+                ],
+            }))
+        );
+        assert_eq!(connection.find_user("dc=example", &filter).await, Ok(None));
+        assert_eq!(connection.find_user("dc=example", &filter).await, Ok(None));
+        directory.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_notice_of_disconnection() {
+        let (client, mut server) = duplex(4096);
+        let directory = tokio::spawn(async move {
+            read_request(&mut server).await;
+            server
+                .write_all(&message(0, result(0x78, 52, "shutting down")))
+                .await
+                .unwrap();
+        });
+
+        let mut connection = Connection::new(client);
+        assert!(connection.bind("uid=alice", "secret").await.is_err());
+        directory.await.unwrap();
+    }
+}
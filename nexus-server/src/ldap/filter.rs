@@ -0,0 +1,394 @@
+//! Search filters and escaping
+//!
+//! The user filter is configured as an RFC 4515 string and sent as the BER
+//! structure of RFC 4511 section 4.5.1. Usernames are escaped before they are
+//! put into a filter or a DN, so a username like `*` or `a)(uid=*` can't widen
+//! the search or change whose DN is bound.
+
+use super::ber::{self, tag};
+
+/// Filter choice tags (RFC 4511 section 4.5.1)
+const FILTER_AND: u8 = 0xa0;
+const FILTER_OR: u8 = 0xa1;
+const FILTER_NOT: u8 = 0xa2;
+const FILTER_EQUALITY: u8 = 0xa3;
+const FILTER_SUBSTRINGS: u8 = 0xa4;
+const FILTER_GREATER_OR_EQUAL: u8 = 0xa5;
+const FILTER_LESS_OR_EQUAL: u8 = 0xa6;
+const FILTER_PRESENT: u8 = 0x87;
+const FILTER_APPROX: u8 = 0xa8;
+
+/// Substring choice tags
+const SUBSTRING_INITIAL: u8 = 0x80;
+const SUBSTRING_ANY: u8 = 0x81;
+const SUBSTRING_FINAL: u8 = 0x82;
+
+/// Escape a value for use in a search filter (RFC 4515 section 3)
+pub(super) fn escape_filter_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '*' => escaped.push_str("\\2a"),
+            '(' => escaped.push_str("\\28"),
+            ')' => escaped.push_str("\\29"),
+            '\\' => escaped.push_str("\\5c"),
+            '\0' => escaped.push_str("\\00"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// Escape a value for use in a distinguished name (RFC 4514 section 2.4)
+pub(super) fn escape_dn_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    let last = value.chars().count().saturating_sub(1);
+    for (i, ch) in value.chars().enumerate() {
+        match ch {
+            '"' | '+' | ',' | ';' | '<' | '>' | '\\' | '=' => {
+                escaped.push('\\');
+                escaped.push(ch);
+            }
+            '#' if i == 0 => escaped.push_str("\\#"),
+            ' ' if i == 0 || i == last => escaped.push_str("\\ "),
+            '\0' => escaped.push_str("\\00"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// Encode a filter string as BER
+pub(super) fn encode_filter(filter: &str) -> Result<Vec<u8>, String> {
+    let mut parser = Parser {
+        text: filter.trim().as_bytes(),
+        pos: 0,
+    };
+    let encoded = parser.filter()?;
+    if parser.pos != parser.text.len() {
+        return Err(format!("unexpected text after filter: {filter}"));
+    }
+    Ok(encoded)
+}
+
+/// Recursive descent parser over a filter string
+struct Parser<'a> {
+    text: &'a [u8],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.text.get(self.pos).copied()
+    }
+
+    fn eat(&mut self, expected: u8) -> Result<(), String> {
+        if self.peek() == Some(expected) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(format!(
+                "expected '{}' at position {} of filter",
+                expected as char, self.pos
+            ))
+        }
+    }
+
+    /// `( filtercomp )`
+    fn filter(&mut self) -> Result<Vec<u8>, String> {
+        self.eat(b'(')?;
+        let encoded = match self.peek() {
+            Some(b'&') => {
+                self.pos += 1;
+                ber::element(FILTER_AND, &self.filter_list()?)
+            }
+            Some(b'|') => {
+                self.pos += 1;
+                ber::element(FILTER_OR, &self.filter_list()?)
+            }
+            Some(b'!') => {
+                self.pos += 1;
+                ber::element(FILTER_NOT, &self.filter()?)
+            }
+            _ => self.item()?,
+        };
+        self.eat(b')')?;
+        Ok(encoded)
+    }
+
+    /// One or more filters
+    fn filter_list(&mut self) -> Result<Vec<u8>, String> {
+        let mut encoded = self.filter()?;
+        while self.peek() == Some(b'(') {
+            encoded.extend(self.filter()?);
+        }
+        Ok(encoded)
+    }
+
+    /// `attr op value`
+    fn item(&mut self) -> Result<Vec<u8>, String> {
+        let start = self.pos;
+        while let Some(b) = self.peek() {
+            if b.is_ascii_alphanumeric() || b == b'-' || b == b'.' || b == b';' {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        let attribute = &self.text[start..self.pos];
+        if attribute.is_empty() {
+            return Err(format!("missing attribute at position {start} of filter"));
+        }
+
+        let choice = match self.peek() {
+            Some(b'=') => None,
+            Some(b'~') => Some(FILTER_APPROX),
+            Some(b'>') => Some(FILTER_GREATER_OR_EQUAL),
+            Some(b'<') => Some(FILTER_LESS_OR_EQUAL),
+            _ => {
+                return Err(format!(
+                    "unsupported filter operator at position {} of filter",
+                    self.pos
+                ));
+            }
+        };
+        if choice.is_some() {
+            self.pos += 1;
+        }
+        self.eat(b'=')?;
+
+        let start = self.pos;
+        while let Some(b) = self.peek() {
+            if b == b'(' || b == b')' {
+                break;
+            }
+            self.pos += 1;
+        }
+        let value = &self.text[start..self.pos];
+
+        if let Some(choice) = choice {
+            return Ok(ber::constructed(
+                choice,
+                &[
+                    ber::octet_string(attribute),
+                    ber::octet_string(&unescape(value)?),
+                ],
+            ));
+        }
+        if value == b"*" {
+            return Ok(ber::element(FILTER_PRESENT, attribute));
+        }
+        if !value.contains(&b'*') {
+            return Ok(ber::constructed(
+                FILTER_EQUALITY,
+                &[
+                    ber::octet_string(attribute),
+                    ber::octet_string(&unescape(value)?),
+                ],
+            ));
+        }
+
+        let parts: Vec<&[u8]> = value.split(|&b| b == b'*').collect();
+        let last = parts.len() - 1;
+        let mut substrings = Vec::new();
+        for (i, part) in parts.into_iter().enumerate() {
+            if part.is_empty() {
+                continue;
+            }
+            let choice = match i {
+                0 => SUBSTRING_INITIAL,
+                i if i == last => SUBSTRING_FINAL,
+                _ => SUBSTRING_ANY,
+            };
+            substrings.push(ber::element(choice, &unescape(part)?));
+        }
+        Ok(ber::constructed(
+            FILTER_SUBSTRINGS,
+            &[
+                ber::octet_string(attribute),
+                ber::constructed(tag::SEQUENCE, &substrings),
+            ],
+        ))
+    }
+}
+
+/// Decode `\XX` escapes in a filter value
+fn unescape(value: &[u8]) -> Result<Vec<u8>, String> {
+    let mut decoded = Vec::with_capacity(value.len());
+    let mut bytes = value.iter();
+    while let Some(&b) = bytes.next() {
+        if b != b'\\' {
+            decoded.push(b);
+            continue;
+        }
+        let (Some(&high), Some(&low)) = (bytes.next(), bytes.next()) else {
+            return Err("truncated escape in filter value".to_string());
+        };
+        let hex = [high, low];
+        let hex = std::str::from_utf8(&hex).map_err(|_| "invalid escape in filter value")?;
+        decoded.push(u8::from_str_radix(hex, 16).map_err(|_| "invalid escape in filter value")?);
+    }
+    Ok(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Undo `escape_dn_value` for values without NULs, failing on any
+    /// special character left unescaped
+    fn unescape_dn_value(escaped: &str) -> Result<String, char> {
+        let mut value = String::new();
+        let mut chars = escaped.chars();
+        while let Some(ch) = chars.next() {
+            match ch {
+                '\\' => value.push(chars.next().ok_or(ch)?),
+                '"' | '+' | ',' | ';' | '<' | '>' | '=' => return Err(ch),
+                _ => value.push(ch),
+            }
+        }
+        Ok(value)
+    }
+
+    #[test]
+    fn test_escape_filter_value() {
+        assert_eq!(escape_filter_value("alice"), "alice");
+        assert_eq!(escape_filter_value("a)(uid=*"), "a\\29\\28uid=\\2a");
+        assert_eq!(escape_filter_value("a\\b\0"), "a\\5cb\\00");
+        assert_eq!(escape_filter_value("Лена"), "Лена");
+    }
+
+    #[test]
+    fn test_escape_dn_value() {
+        assert_eq!(escape_dn_value("alice"), "alice");
+        assert_eq!(escape_dn_value("a,ou=admins"), "a\\,ou\\=admins");
+        assert_eq!(escape_dn_value("#1 "), "\\#1\\ ");
+        assert_eq!(escape_dn_value("x+y;<z>\""), "x\\+y\\;\\<z\\>\\\"");
+    }
+
+    #[test]
+    fn test_equality() {
+        assert_eq!(
+            encode_filter("(uid=alice)").unwrap(),
+            [
+                0xa3, 0x0c, 0x04, 0x03, b'u', b'i', b'd', 0x04, 0x05, b'a', b'l', b'i', b'c', b'e'
+            ]
+        );
+        // Escapes are decoded
+        assert_eq!(
+            encode_filter("(cn=a\\2a)").unwrap(),
+            [0xa3, 0x08, 0x04, 0x02, b'c', b'n', 0x04, 0x02, b'a', b'*']
+        );
+    }
+
+    #[test]
+    fn test_present_and_substrings() {
+        assert_eq!(
+            encode_filter("(objectClass=*)").unwrap(),
+            [
+                0x87, 0x0b, b'o', b'b', b'j', b'e', b'c', b't', b'C', b'l', b'a', b's', b's'
+            ]
+        );
+        assert_eq!(
+            encode_filter("(cn=a*b*c)").unwrap(),
+            [
+                0xa4, 0x0f, 0x04, 0x02, b'c', b'n', 0x30, 0x09, 0x80, 0x01, b'a', 0x81, 0x01, b'b',
+                0x82, 0x01, b'c'
+            ]
+        );
+        assert_eq!(
+            encode_filter("(cn=*b*)").unwrap(),
+            [
+                0xa4, 0x09, 0x04, 0x02, b'c', b'n', 0x30, 0x03, 0x81, 0x01, b'b'
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compound() {
+        let or = encode_filter("(|(uid=a)(sAMAccountName=a))").unwrap();
+        assert_eq!(or[0], 0xa1);
+        let mut children = ber::Reader::new(&or[2..]);
+        assert_eq!(children.next_element().unwrap().unwrap().tag, 0xa3);
+        assert_eq!(children.next_element().unwrap().unwrap().tag, 0xa3);
+        assert!(children.is_empty());
+
+        let and = encode_filter("(&(objectClass=person)(!(uid>=m)))").unwrap();
+        assert_eq!(and[0], 0xa0);
+        let mut children = ber::Reader::new(&and[2..]);
+        assert_eq!(children.next_element().unwrap().unwrap().tag, 0xa3);
+        let not = children.next_element().unwrap().unwrap();
+        assert_eq!(not.tag, 0xa2);
+        assert_eq!(not.content[0], 0xa5);
+    }
+
+    #[test]
+    fn test_escaped_username_stays_one_value() {
+        let username = escape_filter_value("x)(uid=*");
+        let encoded = encode_filter(&format!("(uid={username})")).unwrap();
+        let element = ber::Reader::new(&encoded).expect(0xa3).unwrap();
+        let mut children = element.children();
+        children.expect(tag::OCTET_STRING).unwrap();
+        assert_eq!(
+            children.expect(tag::OCTET_STRING).unwrap().content,
+            b"x)(uid=*"
+        );
+    }
+
+    #[test]
+    fn test_invalid_filters() {
+        for filter in [
+            "",
+            "uid=alice",
+            "(uid=alice",
+            "(uid=alice))",
+            "(=alice)",
+            "(uid:dn:=alice)",
+            "(uid=a\\zz)",
+            "(uid=a\\2)",
+            "(&)",
+        ] {
+            assert!(encode_filter(filter).is_err(), "{filter}");
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn prop_any_filter_string_never_panics(filter in "\\PC{0,64}") {
+            let _ = encode_filter(&filter);
+        }
+
+        #[test]
+        fn prop_filter_like_strings_never_panic(filter in "[()&|!=*~<>\\\\a-z0-9]{0,48}") {
+            if let Ok(encoded) = encode_filter(&filter) {
+                prop_assert!(ber::Reader::new(&encoded).next_element().is_ok());
+            }
+        }
+
+        #[test]
+        fn prop_escaped_value_is_one_exact_match(value in "\\PC{1,32}") {
+            let escaped = escape_filter_value(&value);
+            let encoded = encode_filter(&format!("(uid={escaped})")).unwrap();
+
+            let mut reader = ber::Reader::new(&encoded);
+            let element = reader.expect(FILTER_EQUALITY).unwrap();
+            prop_assert!(reader.is_empty());
+            let mut children = element.children();
+            prop_assert_eq!(children.expect(tag::OCTET_STRING).unwrap().content, b"uid");
+            prop_assert_eq!(
+                children.expect(tag::OCTET_STRING).unwrap().content,
+                value.as_bytes()
+            );
+            prop_assert!(children.is_empty());
+        }
+
+        #[test]
+        fn prop_escaped_dn_value_round_trips(value in "\\PC{0,32}") {
+            let escaped = escape_dn_value(&value);
+            prop_assert_eq!(unescape_dn_value(&escaped), Ok(value.clone()));
+            prop_assert!(!escaped.starts_with('#') && !escaped.starts_with(' '));
+            prop_assert!(!escaped.ends_with(' ') || escaped.ends_with("\\ "));
+        }
+    }
+}
@@ -0,0 +1,519 @@
+//! LDAP / Active Directory logins
+//!
+//! With `--ldap-url` and `--ldap-bind-dn`, users can log in with the username
+//! and password they already have in the organization's directory:
+//!
+//! 1. The server binds as the user, with the DN made from the bind DN
+//!    template (or a user principal name like `{username}@corp.example.com`
+//!    for Active Directory).
+//! 2. Still bound as the user, it searches `--ldap-search-base` with the user
+//!    filter and reads the `memberOf` groups of the entry it finds.
+//! 3. The groups are matched against the group mappings admins set up (see
+//!    [`crate::db::LdapDb`]); members of at least one mapped group get an
+//!    account with the combined permissions, created on first login and
+//!    updated on every login after that.
+//!
+//! Local accounts keep working: a username that already belongs to an
+//! account not created through the directory is always checked against the
+//! local password, so a directory entry can't take over an existing account.
+
+mod ber;
+mod client;
+mod filter;
+
+use std::fmt;
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::Arc;
+use std::time::Duration;
+
+use rand::RngExt;
+use rand::distr::Alphanumeric;
+use tokio::time::timeout;
+use tokio_rustls::TlsConnector;
+use tokio_rustls::rustls::pki_types::CertificateDer;
+
+use nexus_common::protocol::LdapGroupInfo;
+
+use crate::constants::LDAP_REQUEST_TIMEOUT_SECS;
+use crate::db::sql::GUEST_USERNAME;
+use crate::db::users::UserAccount;
+use crate::db::{self, Database, Permission, Permissions};
+use client::{LdapUrl, UserEntry};
+use filter::{encode_filter, escape_dn_value, escape_filter_value};
+
+/// Placeholder for the username in the bind DN template and user filter
+const USERNAME_PLACEHOLDER: &str = "{username}";
+
+/// Length of the random password set on new directory accounts
+const PROVISIONED_PASSWORD_LENGTH: usize = 32;
+
+/// Directory settings from the command line
+#[derive(Debug, Clone)]
+pub struct LdapConfig {
+    /// `ldaps://` or `ldap://` URL of the directory
+    pub url: String,
+    /// DN (or user principal name) to bind as, with `{username}` in it
+    pub bind_dn: String,
+    /// Where to search for user entries
+    pub search_base: String,
+    /// Filter that finds a user's entry, with `{username}` in it
+    pub user_filter: String,
+    /// PEM file with extra CA certificates to trust for `ldaps://`
+    pub ca_cert: Option<String>,
+}
+
+impl LdapConfig {
+    /// Check the settings before the server starts
+    pub fn validate(&self) -> Result<(), String> {
+        LdapUrl::parse(&self.url)?;
+        if !self.bind_dn.contains(USERNAME_PLACEHOLDER) {
+            return Err(format!("bind DN must contain {USERNAME_PLACEHOLDER}"));
+        }
+        if !self.user_filter.contains(USERNAME_PLACEHOLDER) {
+            return Err(format!("user filter must contain {USERNAME_PLACEHOLDER}"));
+        }
+        encode_filter(&self.user_filter.replace(USERNAME_PLACEHOLDER, "x"))
+            .map_err(|e| format!("invalid user filter: {e}"))?;
+        if let Some(path) = &self.ca_cert {
+            load_ca_certs(path)?;
+        }
+        Ok(())
+    }
+
+    /// Whether passwords travel unencrypted (`ldap://`)
+    pub fn is_plaintext(&self) -> bool {
+        LdapUrl::parse(&self.url).is_ok_and(|url| !url.tls)
+    }
+}
+
+/// Configured directory, ready to connect to
+struct Directory {
+    config: LdapConfig,
+    url: LdapUrl,
+    tls: TlsConnector,
+}
+
+/// Why a directory login failed
+#[derive(Debug)]
+pub enum LdapError {
+    /// The directory couldn't be reached or answered with an error
+    Unavailable(String),
+    /// The directory refused the password
+    InvalidCredentials,
+    /// The user isn't a member of any mapped group
+    NotInMappedGroup(String),
+    /// The account is disabled
+    AccountDisabled(String),
+    /// Database error
+    Database(sqlx::Error),
+}
+
+impl fmt::Display for LdapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LdapError::Unavailable(reason) => write!(f, "directory unavailable: {reason}"),
+            LdapError::InvalidCredentials => write!(f, "invalid credentials"),
+            LdapError::NotInMappedGroup(name) => {
+                write!(f, "{name} is not a member of any mapped group")
+            }
+            LdapError::AccountDisabled(name) => write!(f, "account {name} is disabled"),
+            LdapError::Database(e) => write!(f, "database error: {e}"),
+        }
+    }
+}
+
+impl From<sqlx::Error> for LdapError {
+    fn from(e: sqlx::Error) -> Self {
+        LdapError::Database(e)
+    }
+}
+
+/// Directory logins through the configured LDAP server
+///
+/// Cheap to clone. Without `--ldap-url` it's disabled and every login is
+/// left to local accounts.
+#[derive(Clone, Default)]
+pub struct Ldap {
+    directory: Option<Arc<Directory>>,
+}
+
+impl Ldap {
+    /// Directory logins with the given settings (checked with `validate` first)
+    pub fn new(config: LdapConfig) -> Result<Self, String> {
+        let url = LdapUrl::parse(&config.url)?;
+        let extra_roots = match &config.ca_cert {
+            Some(path) => load_ca_certs(path)?,
+            None => Vec::new(),
+        };
+        let tls = client::tls_connector(extra_roots)?;
+        Ok(Self {
+            directory: Some(Arc::new(Directory { config, url, tls })),
+        })
+    }
+
+    /// Log in with a username and password against the directory
+    ///
+    /// Returns `Ok(None)` when the login isn't the directory's to decide, so the
+    /// caller goes on with local accounts: directory logins are off, the username
+    /// belongs to a local account, or the directory doesn't know the user (or
+    /// knows them but not in a mapped group) and no account was created for them
+    /// yet. Accounts created through the directory are only ever checked against
+    /// it.
+    pub async fn login(
+        &self,
+        db: &Database,
+        username: &str,
+        password: &str,
+    ) -> Result<Option<UserAccount>, LdapError> {
+        let Some(directory) = &self.directory else {
+            return Ok(None);
+        };
+        if username.eq_ignore_ascii_case(GUEST_USERNAME) {
+            return Ok(None);
+        }
+
+        let existing = db.users.get_user_by_username(username).await?;
+        let linked = match &existing {
+            Some(account) => db.ldap.get_dn(account.id).await?.is_some(),
+            None => false,
+        };
+        if existing.is_some() && !linked {
+            return Ok(None);
+        }
+
+        let entry = match directory.lookup(username, password).await {
+            Ok(Some(entry)) => entry,
+            Ok(None) if linked => return Err(LdapError::InvalidCredentials),
+            Ok(None) => return Ok(None),
+            Err(e) if linked => return Err(LdapError::Unavailable(e)),
+            Err(e) => {
+                log_warn!("Directory lookup for {} failed: {}", username, e);
+                return Ok(None);
+            }
+        };
+
+        let mappings = db.ldap.list_groups().await?;
+        let Some((is_admin, permissions)) = access_for_groups(&mappings, &entry.groups) else {
+            if linked {
+                return Err(LdapError::NotInMappedGroup(username.to_string()));
+            }
+            log_info!(
+                "Directory user {} is not a member of any mapped group",
+                username
+            );
+            return Ok(None);
+        };
+
+        let account = match existing {
+            Some(account) => {
+                if !account.enabled {
+                    return Err(LdapError::AccountDisabled(account.username));
+                }
+                sync_account(db, &account, is_admin, &permissions).await?
+            }
+            None => create_account(db, username, is_admin, &permissions).await?,
+        };
+        db.ldap.link(account.id, &entry.dn).await?;
+        Ok(Some(account))
+    }
+
+    /// Check a password for an account created through the directory
+    ///
+    /// Returns `Ok(None)` for local accounts (and when directory logins are off),
+    /// whose passwords are checked locally. Used by transfer and WebDAV logins,
+    /// which don't update the account's permissions.
+    pub async fn check_password(
+        &self,
+        db: &Database,
+        account: &UserAccount,
+        password: &str,
+    ) -> Result<Option<bool>, LdapError> {
+        let Some(directory) = &self.directory else {
+            return Ok(None);
+        };
+        if db.ldap.get_dn(account.id).await?.is_none() {
+            return Ok(None);
+        }
+        match directory.lookup(&account.username, password).await {
+            Ok(entry) => Ok(Some(entry.is_some())),
+            Err(e) => Err(LdapError::Unavailable(e)),
+        }
+    }
+}
+
+/// Read the certificates in a PEM file
+fn load_ca_certs(path: &str) -> Result<Vec<CertificateDer<'static>>, String> {
+    let file = File::open(path).map_err(|e| format!("failed to open {path}: {e}"))?;
+    let certs = rustls_pemfile::certs(&mut BufReader::new(file))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("failed to read {path}: {e}"))?;
+    if certs.is_empty() {
+        return Err(format!("no certificates in {path}"));
+    }
+    Ok(certs)
+}
+
+impl Directory {
+    /// Bind as the user and find their entry
+    ///
+    /// Returns `None` if the password is wrong or no single entry matches.
+    async fn lookup(&self, username: &str, password: &str) -> Result<Option<UserEntry>, String> {
+        // An empty password would make this an anonymous bind, which succeeds
+        if password.is_empty() {
+            return Ok(None);
+        }
+        let bind_dn = self
+            .config
+            .bind_dn
+            .replace(USERNAME_PLACEHOLDER, &escape_dn_value(username));
+        let filter = encode_filter(
+            &self
+                .config
+                .user_filter
+                .replace(USERNAME_PLACEHOLDER, &escape_filter_value(username)),
+        )?;
+
+        let exchange = async {
+            let mut connection = client::connect(&self.url, &self.tls).await?;
+            let entry = if connection.bind(&bind_dn, password).await? {
+                connection
+                    .find_user(&self.config.search_base, &filter)
+                    .await?
+            } else {
+                None
+            };
+            connection.unbind().await;
+            Ok(entry)
+        };
+
+        timeout(Duration::from_secs(LDAP_REQUEST_TIMEOUT_SECS), exchange)
+            .await
+            .map_err(|_| format!("request to {} timed out", self.url.host))?
+    }
+}
+
+/// Combine the mappings of the groups a user is a member of
+///
+/// Returns `None` if none of the groups is mapped.
+fn access_for_groups(
+    mappings: &[LdapGroupInfo],
+    member_of: &[String],
+) -> Option<(bool, Permissions)> {
+    let member_of: Vec<String> = member_of.iter().map(|dn| normalize_dn(dn)).collect();
+    let mut matched = false;
+    let mut is_admin = false;
+    let mut permissions = Permissions::new();
+    for mapping in mappings {
+        if !member_of.contains(&normalize_dn(&mapping.group)) {
+            continue;
+        }
+        matched = true;
+        is_admin |= mapping.is_admin;
+        permissions.permissions.extend(
+            mapping
+                .permissions
+                .iter()
+                .filter_map(|p| Permission::parse(p)),
+        );
+    }
+    matched.then_some((is_admin, permissions))
+}
+
+/// Normalize a DN for comparison
+///
+/// Directories compare DNs case-insensitively and ignore spaces around the
+/// separators, so `CN=Staff, DC=example` and `cn=staff,dc=example` match.
+fn normalize_dn(dn: &str) -> String {
+    let mut normalized = String::with_capacity(dn.len());
+    let mut chars = dn.trim().chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => {
+                normalized.push(ch);
+                if let Some(escaped) = chars.next() {
+                    normalized.extend(escaped.to_lowercase());
+                }
+            }
+            ',' | '=' => {
+                while normalized.ends_with(' ') && !normalized.ends_with("\\ ") {
+                    normalized.pop();
+                }
+                normalized.push(ch);
+                while chars.peek() == Some(&' ') {
+                    chars.next();
+                }
+            }
+            _ => normalized.extend(ch.to_lowercase()),
+        }
+    }
+    normalized
+}
+
+/// Bring an existing directory account's admin flag and permissions up to date
+async fn sync_account(
+    db: &Database,
+    account: &UserAccount,
+    is_admin: bool,
+    permissions: &Permissions,
+) -> Result<UserAccount, LdapError> {
+    let updated = db
+        .users
+        .update_user(
+            &account.username,
+            None,
+            None,
+            Some(is_admin),
+            None,
+            Some(permissions),
+        )
+        .await?;
+    if !updated {
+        // The last admin can't be demoted; keep the account as it is
+        log_warn!(
+            "Could not update directory account {} from its groups",
+            account.username
+        );
+        return Ok(account.clone());
+    }
+    Ok(db
+        .users
+        .get_user_by_id(account.id)
+        .await?
+        .unwrap_or_else(|| account.clone()))
+}
+
+/// Create the account for a directory user on their first login
+async fn create_account(
+    db: &Database,
+    username: &str,
+    is_admin: bool,
+    permissions: &Permissions,
+) -> Result<UserAccount, LdapError> {
+    // Nobody knows this password; the account logs in through the directory
+    let password: String = rand::rng()
+        .sample_iter(Alphanumeric)
+        .take(PROVISIONED_PASSWORD_LENGTH)
+        .map(char::from)
+        .collect();
+    let hashed_password = db::hash_password(&password, false)
+        .map_err(|e| LdapError::Unavailable(format!("failed to hash password: {e}")))?;
+
+    let account = db
+        .users
+        .create_user(
+            username,
+            &hashed_password,
+            is_admin,
+            false,
+            true,
+            permissions,
+        )
+        .await?;
+    log_info!("Created directory account '{}'", account.username);
+    Ok(account)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mapping(group: &str, permissions: &[&str], is_admin: bool) -> LdapGroupInfo {
+        LdapGroupInfo {
+            group: group.to_string(),
+            permissions: permissions.iter().map(|p| p.to_string()).collect(),
+            is_admin,
+        }
+    }
+
+    #[test]
+    fn test_normalize_dn() {
+        assert_eq!(
+            normalize_dn(" CN=Staff , OU = Groups,DC=Example"),
+            "cn=staff,ou=groups,dc=example"
+        );
+        // Escaped separators and spaces are kept
+        assert_eq!(normalize_dn("CN=Smith\\, John"), "cn=smith\\, john");
+        assert_eq!(normalize_dn("CN=a\\ ,DC=x"), "cn=a\\ ,dc=x");
+    }
+
+    #[test]
+    fn test_access_for_groups() {
+        let mappings = [
+            mapping("CN=Staff,DC=example", &["chat_send", "file_list"], false),
+            mapping(
+                "CN=Uploaders,DC=example",
+                &["file_upload", "unknown"],
+                false,
+            ),
+            mapping("CN=IT,DC=example", &[], true),
+        ];
+
+        let (is_admin, permissions) = access_for_groups(
+            &mappings,
+            &[
+                "cn=staff, dc=example".to_string(),
+                "CN=Uploaders,DC=example".to_string(),
+                "CN=Other,DC=example".to_string(),
+            ],
+        )
+        .unwrap();
+        assert!(!is_admin);
+        assert_eq!(permissions.permissions.len(), 3);
+        assert!(permissions.permissions.contains(&Permission::ChatSend));
+        assert!(permissions.permissions.contains(&Permission::FileUpload));
+
+        let (is_admin, _) =
+            access_for_groups(&mappings, &["CN=IT,DC=example".to_string()]).unwrap();
+        assert!(is_admin);
+
+        assert!(access_for_groups(&mappings, &["CN=Other,DC=example".to_string()]).is_none());
+        assert!(access_for_groups(&mappings, &[]).is_none());
+        assert!(access_for_groups(&[], &["CN=IT,DC=example".to_string()]).is_none());
+    }
+
+    #[test]
+    fn test_validate_config() {
+        let config = LdapConfig {
+            url: "ldaps://dc1.example.com".to_string(),
+            bind_dn: "{username}@example.com".to_string(),
+            search_base: "DC=example,DC=com".to_string(),
+            user_filter: "(sAMAccountName={username})".to_string(),
+            ca_cert: None,
+        };
+        assert!(config.validate().is_ok());
+        assert!(!config.is_plaintext());
+
+        let plain = LdapConfig {
+            url: "ldap://ldap.example.com".to_string(),
+            ..config.clone()
+        };
+        assert!(plain.validate().is_ok());
+        assert!(plain.is_plaintext());
+
+        for broken in [
+            LdapConfig {
+                url: "https://dc1.example.com".to_string(),
+                ..config.clone()
+            },
+            LdapConfig {
+                bind_dn: "CN=Service,DC=example,DC=com".to_string(),
+                ..config.clone()
+            },
+            LdapConfig {
+                user_filter: "(uid=alice)".to_string(),
+                ..config.clone()
+            },
+            LdapConfig {
+                user_filter: "(uid={username}".to_string(),
+                ..config.clone()
+            },
+            LdapConfig {
+                ca_cert: Some("/nonexistent/ca.pem".to_string()),
+                ..config.clone()
+            },
+        ] {
+            assert!(broken.validate().is_err(), "{broken:?}");
+        }
+    }
+}
@@ -2,15 +2,40 @@
 //!
 //! This library exposes the server's internal modules for integration testing.
 
+#[macro_use]
+pub mod logging;
+
+pub mod broadcasts;
 pub mod channels;
 pub mod connection_tracker;
 pub mod constants;
 pub mod db;
+pub mod doors;
 pub mod files;
+pub mod groups;
+pub mod guests;
 pub mod handlers;
+pub mod https;
 pub mod i18n;
+pub mod idempotency;
+pub mod identity;
 pub mod ip_rule_cache;
+pub mod ldap;
+pub mod login_tokens;
+pub mod mdns;
+pub mod news_images;
+pub mod oidc;
+pub mod outbound;
+pub mod protocol_limits;
+pub mod ranks;
+pub mod reachability;
+pub mod reputation;
+pub mod scanner;
+pub mod stats;
+pub mod storage;
 pub mod transfers;
 pub mod upnp;
 pub mod users;
+pub mod util;
 pub mod voice;
+pub mod webdav;
@@ -0,0 +1,203 @@
+//! Server log
+//!
+//! Operator log lines go through the `log_error!`, `log_warn!`, `log_info!`
+//! and `log_debug!` macros. Each line is printed to stderr as before, kept in
+//! a short in-memory history and broadcast to live subscribers, so admins
+//! with `log_view` can follow the log from a client (see `LogSubscribe`).
+//!
+//! The history and feed live in a [`ServerLog`] the server creates at
+//! startup. It installs that log for the macros and hands it to connections,
+//! which subscribe through it.
+//!
+//! The installed log is kept in a global rather than passed through the
+//! handler context. The macros are called from everywhere, including code
+//! with no context to hand (the UPnP task, the transfer and WebDAV
+//! listeners, startup before any connection exists), and `eprintln!` itself
+//! is process-wide. Everything that reads the log goes through the
+//! `ServerLog` handle in the context; only writing is global.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock};
+
+use nexus_common::protocol::{LogEntry, LogLevel};
+use tokio::sync::broadcast;
+
+use crate::handlers::current_timestamp;
+
+/// Number of recent log lines kept for new subscribers
+pub const LOG_HISTORY_SIZE: usize = 500;
+
+/// Live log lines buffered per subscriber before it starts missing lines
+const LOG_CHANNEL_CAPACITY: usize = 1024;
+
+/// Recent log lines and the live feed, behind one lock so a subscriber
+/// never misses or duplicates a line between its history and the feed
+struct LogState {
+    history: VecDeque<LogEntry>,
+    sender: broadcast::Sender<LogEntry>,
+}
+
+/// Server log history and live feed
+///
+/// Cheap to clone; clones share the same history and feed.
+#[derive(Clone)]
+pub struct ServerLog {
+    state: Arc<Mutex<LogState>>,
+}
+
+/// Log the macros pass their lines to, once the server has installed it
+///
+/// Global rather than in the handler context; see the module docs for why.
+static INSTALLED: OnceLock<ServerLog> = OnceLock::new();
+
+/// Log an error
+macro_rules! log_error {
+    ($($arg:tt)*) => {
+        $crate::logging::log(
+            nexus_common::protocol::LogLevel::Error,
+            module_path!(),
+            format!($($arg)*),
+        )
+    };
+}
+
+/// Log a warning
+macro_rules! log_warn {
+    ($($arg:tt)*) => {
+        $crate::logging::log(
+            nexus_common::protocol::LogLevel::Warn,
+            module_path!(),
+            format!($($arg)*),
+        )
+    };
+}
+
+/// Log an informational message
+macro_rules! log_info {
+    ($($arg:tt)*) => {
+        $crate::logging::log(
+            nexus_common::protocol::LogLevel::Info,
+            module_path!(),
+            format!($($arg)*),
+        )
+    };
+}
+
+/// Log a debug message (callers only log these in `--debug` mode)
+macro_rules! log_debug {
+    ($($arg:tt)*) => {
+        $crate::logging::log(
+            nexus_common::protocol::LogLevel::Debug,
+            module_path!(),
+            format!($($arg)*),
+        )
+    };
+}
+
+impl Default for ServerLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ServerLog {
+    /// Create an empty log
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(LogState {
+                history: VecDeque::with_capacity(LOG_HISTORY_SIZE),
+                sender: broadcast::channel(LOG_CHANNEL_CAPACITY).0,
+            })),
+        }
+    }
+
+    /// Pass the logging macros' lines to this log (call once at startup)
+    pub fn install(&self) {
+        let _ = INSTALLED.set(self.clone());
+    }
+
+    /// Lock the history and feed
+    fn lock(&self) -> MutexGuard<'_, LogState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Keep a log line and pass it on to subscribers
+    ///
+    /// `module` is a `module_path!()`; the crate name is dropped.
+    pub fn record(&self, level: LogLevel, module: &str, message: String) {
+        let module = module
+            .split_once("::")
+            .map_or(module, |(_, rest)| rest)
+            .to_string();
+        let entry = LogEntry {
+            timestamp: current_timestamp(),
+            level,
+            module,
+            message,
+        };
+
+        let mut state = self.lock();
+        if state.history.len() == LOG_HISTORY_SIZE {
+            state.history.pop_front();
+        }
+        state.history.push_back(entry.clone());
+        // No receivers is fine - nobody is following the log
+        let _ = state.sender.send(entry);
+    }
+
+    /// Recent log lines (oldest first) and a receiver for the lines after them
+    pub fn subscribe(&self) -> (Vec<LogEntry>, broadcast::Receiver<LogEntry>) {
+        let state = self.lock();
+        (
+            state.history.iter().cloned().collect(),
+            state.sender.subscribe(),
+        )
+    }
+}
+
+/// Print a log line to stderr and pass it on to the installed server log
+///
+/// `module` is a `module_path!()`; the crate name is dropped.
+pub fn log(level: LogLevel, module: &str, message: String) {
+    eprintln!("{}", message);
+
+    if let Some(server_log) = INSTALLED.get() {
+        server_log.record(level, module, message);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_reaches_subscribers() {
+        let server_log = ServerLog::new();
+        let (_, mut receiver) = server_log.subscribe();
+        server_log.record(
+            LogLevel::Warn,
+            module_path!(),
+            "logging test 42".to_string(),
+        );
+
+        let entry = receiver.try_recv().expect("line should be broadcast");
+        assert_eq!(entry.message, "logging test 42");
+        assert_eq!(entry.level, LogLevel::Warn);
+        assert_eq!(entry.module, "logging::tests");
+
+        let (history, _) = server_log.subscribe();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].message, "logging test 42");
+    }
+
+    #[test]
+    fn test_history_is_bounded() {
+        let server_log = ServerLog::new();
+        for i in 0..LOG_HISTORY_SIZE + 10 {
+            server_log.record(LogLevel::Debug, module_path!(), format!("history test {i}"));
+        }
+        let (history, _) = server_log.subscribe();
+        assert_eq!(history.len(), LOG_HISTORY_SIZE);
+        assert_eq!(history[0].message, "history test 10");
+    }
+}
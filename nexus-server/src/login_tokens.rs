@@ -0,0 +1,202 @@
+//! Login tokens (session resumption)
+//!
+//! Every login response carries a random token that logs in again as the same
+//! account. Clients use it instead of the password for transfer connections
+//! and to reconnect after a dropped connection, so the password is sent once
+//! per session. Single sign-on logins have no password and rely on it.
+//!
+//! A token belongs to the session it was issued to:
+//!
+//! - It works while that session is logged in, and for
+//!   [`LOGIN_TOKEN_GRACE_SECS`] after it ends so the client can reconnect.
+//! - Kicking or banning the session revokes it at once.
+//! - Disabling or deleting the account, or changing its password, revokes the
+//!   account's tokens.
+//!
+//! Tokens only live in memory, so a server restart means logging in again.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use chrono::Utc;
+use rand::RngExt;
+
+use crate::constants::LOGIN_TOKEN_GRACE_SECS;
+use crate::db::Database;
+use crate::db::users::UserAccount;
+
+/// An outstanding token
+struct LoginToken {
+    /// Account the token logs in as
+    user_id: i64,
+    /// Session the token was issued to
+    session_id: u32,
+    /// Expiry timestamp, set once the session ends
+    expires: Option<i64>,
+}
+
+impl LoginToken {
+    /// Whether the token still logs in at `now`
+    fn is_valid(&self, now: i64) -> bool {
+        self.expires.is_none_or(|expires| expires > now)
+    }
+}
+
+/// Outstanding login tokens
+///
+/// Cheap to clone; clones share the same tokens.
+#[derive(Clone, Default)]
+pub struct LoginTokens {
+    /// Outstanding tokens by token string
+    tokens: Arc<Mutex<HashMap<String, LoginToken>>>,
+}
+
+impl LoginTokens {
+    /// Create an empty token table
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Lock the token table
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, LoginToken>> {
+        self.tokens.lock().expect("login token lock poisoned")
+    }
+
+    /// Issue a token that logs in as an account, for the session that logged in
+    pub fn issue(&self, user_id: i64, session_id: u32) -> String {
+        let bytes: [u8; 32] = rand::rng().random();
+        let token = hex::encode(bytes);
+        let now = Utc::now().timestamp();
+
+        let mut tokens = self.lock();
+        tokens.retain(|_, login_token| login_token.is_valid(now));
+        tokens.insert(
+            token.clone(),
+            LoginToken {
+                user_id,
+                session_id,
+                expires: None,
+            },
+        );
+        token
+    }
+
+    /// The account ID a token logs in as, if it is known and unexpired
+    pub fn redeem(&self, token: &str) -> Option<i64> {
+        self.lock()
+            .get(token)
+            .filter(|login_token| login_token.is_valid(Utc::now().timestamp()))
+            .map(|login_token| login_token.user_id)
+    }
+
+    /// The account a token logs in as (None for unknown or expired tokens)
+    pub async fn account(
+        &self,
+        db: &Database,
+        token: &str,
+    ) -> Result<Option<UserAccount>, sqlx::Error> {
+        match self.redeem(token) {
+            Some(user_id) => db.users.get_user_by_id(user_id).await,
+            None => Ok(None),
+        }
+    }
+
+    /// Start the grace period for a session's tokens (the session logged out)
+    pub fn end_session(&self, session_id: u32) {
+        let expires = Utc::now().timestamp() + LOGIN_TOKEN_GRACE_SECS;
+        for login_token in self.lock().values_mut() {
+            if login_token.session_id == session_id && login_token.expires.is_none() {
+                login_token.expires = Some(expires);
+            }
+        }
+    }
+
+    /// Revoke a session's tokens (the session was kicked or banned)
+    pub fn revoke_session(&self, session_id: u32) {
+        self.lock()
+            .retain(|_, login_token| login_token.session_id != session_id);
+    }
+
+    /// Revoke an account's tokens, except those of the session making the change
+    pub fn revoke_account(&self, user_id: i64, keep_session: Option<u32>) {
+        self.lock().retain(|_, login_token| {
+            login_token.user_id != user_id || Some(login_token.session_id) == keep_session
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nexus_common::validators::validate_login_token;
+
+    #[test]
+    fn test_issue_and_redeem() {
+        let tokens = LoginTokens::new();
+        let token = tokens.issue(42, 1);
+        assert!(validate_login_token(&token).is_ok());
+        assert_eq!(tokens.redeem(&token), Some(42));
+        // Tokens can be used more than once (every transfer connection logs in)
+        assert_eq!(tokens.redeem(&token), Some(42));
+
+        assert_ne!(tokens.issue(42, 1), token);
+        assert_eq!(tokens.redeem(&"0".repeat(64)), None);
+
+        // Clones share tokens; separate tables don't
+        assert_eq!(tokens.clone().redeem(&token), Some(42));
+        assert_eq!(LoginTokens::new().redeem(&token), None);
+    }
+
+    #[test]
+    fn test_expired_token() {
+        let tokens = LoginTokens::new();
+        let token = "e".repeat(64);
+        tokens.lock().insert(
+            token.clone(),
+            LoginToken {
+                user_id: 7,
+                session_id: 2,
+                expires: Some(Utc::now().timestamp() - 1),
+            },
+        );
+        assert_eq!(tokens.redeem(&token), None);
+    }
+
+    #[test]
+    fn test_end_session_keeps_token_for_grace_period() {
+        let tokens = LoginTokens::new();
+        let token = tokens.issue(43, 3);
+        tokens.end_session(3);
+        assert_eq!(tokens.redeem(&token), Some(43));
+        let expires = tokens
+            .lock()
+            .get(&token)
+            .and_then(|login_token| login_token.expires);
+        assert!(expires.is_some_and(|expires| expires > Utc::now().timestamp()));
+    }
+
+    #[test]
+    fn test_revoke_session() {
+        let tokens = LoginTokens::new();
+        let kicked = tokens.issue(44, 4);
+        let other = tokens.issue(44, 5);
+        tokens.revoke_session(4);
+        assert_eq!(tokens.redeem(&kicked), None);
+        assert_eq!(tokens.redeem(&other), Some(44));
+    }
+
+    #[test]
+    fn test_revoke_account() {
+        let tokens = LoginTokens::new();
+        let own = tokens.issue(45, 6);
+        let other = tokens.issue(45, 7);
+        let unrelated = tokens.issue(46, 8);
+        tokens.revoke_account(45, Some(6));
+        assert_eq!(tokens.redeem(&own), Some(45));
+        assert_eq!(tokens.redeem(&other), None);
+        assert_eq!(tokens.redeem(&unrelated), Some(46));
+
+        tokens.revoke_account(45, None);
+        assert_eq!(tokens.redeem(&own), None);
+    }
+}
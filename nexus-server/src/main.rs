@@ -1,19 +1,44 @@
 //! Nexus BBS Server
 
+#[macro_use]
+mod logging;
+
 mod args;
+mod broadcasts;
 mod channels;
 mod connection;
 mod connection_tracker;
 mod constants;
 mod db;
+mod doors;
 mod files;
+mod groups;
+mod guests;
 mod handlers;
+mod https;
 mod i18n;
+mod idempotency;
+mod identity;
 mod ip_rule_cache;
+mod ldap;
+mod login_tokens;
+mod mdns;
+mod news_images;
+mod oidc;
+mod outbound;
+mod protocol_limits;
+mod ranks;
+mod reachability;
+mod reputation;
+mod scanner;
+mod stats;
+mod storage;
 mod transfers;
 mod upnp;
 mod users;
+mod util;
 mod voice;
+mod webdav;
 mod websocket;
 
 use std::fs;
@@ -30,16 +55,37 @@ use tokio_rustls::TlsAcceptor;
 use tokio_rustls::rustls::ServerConfig;
 use tokio_rustls::rustls::pki_types::CertificateDer;
 
-use args::Args;
+use args::{Args, Command};
+use broadcasts::BroadcastManager;
 use channels::{Channel, ChannelManager};
 use connection::ConnectionParams;
 use connection_tracker::ConnectionTracker;
 use constants::*;
+use db::audit::AUDIT_FLUSH_INTERVAL;
+use db::message_history::MESSAGE_HISTORY_FLUSH_INTERVAL;
+use doors::Doors;
 use files::FileIndex;
+use groups::GroupManager;
+use guests::GuestConfig;
+use idempotency::IdempotencyKeys;
 use ip_rule_cache::IpRuleCache;
-use transfers::{TransferParams, TransferRegistry};
+use ldap::Ldap;
+use logging::ServerLog;
+use login_tokens::LoginTokens;
+use mdns::Mdns;
+use oidc::Oidc;
+use outbound::OutboundMetrics;
+use protocol_limits::{LimitsConfig, ProtocolLimits};
+use ranks::Ranks;
+use reachability::CheckConfig;
+use reputation::{ReputationConfig, ReputationPolicy, TARPIT_DELAY, check_reputation};
+use scanner::{ScannerMitigation, screen_connection};
+use stats::{STATS_FLUSH_INTERVAL, StatsCollector};
+use transfers::{TransferParams, TransferRegistry, sweep_upload_journal};
+use upnp::NatStatus;
 use users::UserManager;
-use voice::{VoiceRegistry, VoiceUdpServer, create_voice_listener};
+use voice::{VoiceNetworkConfig, VoiceRegistry, VoiceUdpServer, create_voice_listener};
+use webdav::WebDavParams;
 
 #[tokio::main]
 async fn main() {
@@ -50,13 +96,33 @@ async fn main() {
         .install_default()
         .expect("Failed to install rustls crypto provider");
 
+    // Keep log lines for admins following the server log from a client
+    let server_log = ServerLog::new();
+    server_log.install();
+
     let args = Args::parse();
 
     // Print banner first
     println!("{}{}", MSG_BANNER, env!("CARGO_PKG_VERSION"));
 
+    // Check command: test the ports from outside and exit (no database needed)
+    if args.command == Some(Command::Check) {
+        run_connectivity_check(&args).await;
+        return;
+    }
+
     // Setup database
-    let (database, user_manager, db_path) = setup_db(args.database).await;
+    let db_tuning = db::DbTuning {
+        journal_mode: args.db_journal_mode,
+        synchronous: args.db_synchronous,
+    };
+    let (database, user_manager, db_path) = setup_db(args.database, db_tuning).await;
+
+    // Import mode: load an export archive into the fresh database and exit
+    if let Some(import_path) = args.import {
+        run_import(&database, &import_path).await;
+        return;
+    }
 
     // Setup IP rule cache - cleanup expired entries, then load active ones
     let expired_bans = database
@@ -64,7 +130,7 @@ async fn main() {
         .cleanup_expired_bans()
         .await
         .unwrap_or_else(|e| {
-            eprintln!("Failed to cleanup expired bans: {}", e);
+            log_error!("Failed to cleanup expired bans: {}", e);
             0
         });
     let expired_trusts = database
@@ -72,13 +138,14 @@ async fn main() {
         .cleanup_expired_trusts()
         .await
         .unwrap_or_else(|e| {
-            eprintln!("Failed to cleanup expired trusts: {}", e);
+            log_error!("Failed to cleanup expired trusts: {}", e);
             0
         });
     if (expired_bans > 0 || expired_trusts > 0) && args.debug {
-        eprintln!(
+        log_debug!(
             "Cleaned up {} expired ban(s), {} expired trust(s)",
-            expired_bans, expired_trusts
+            expired_bans,
+            expired_trusts
         );
     }
 
@@ -87,7 +154,7 @@ async fn main() {
         .load_all_active_bans()
         .await
         .unwrap_or_else(|e| {
-            eprintln!("Failed to load bans: {}", e);
+            log_error!("Failed to load bans: {}", e);
             Vec::new()
         });
     let trust_records = database
@@ -95,7 +162,7 @@ async fn main() {
         .load_all_active_trusts()
         .await
         .unwrap_or_else(|e| {
-            eprintln!("Failed to load trusts: {}", e);
+            log_error!("Failed to load trusts: {}", e);
             Vec::new()
         });
     let ban_count = ban_records.len();
@@ -105,9 +172,10 @@ async fn main() {
         trust_records,
     )));
     if (ban_count > 0 || trust_count > 0) && args.debug {
-        eprintln!(
+        log_debug!(
             "Loaded {} active ban(s), {} active trust(s) into cache",
-            ban_count, trust_count
+            ban_count,
+            trust_count
         );
     }
 
@@ -135,18 +203,48 @@ async fn main() {
         )
         .await;
 
-    // Setup voice DTLS listener (same port as TCP, OS routes by protocol)
-    let voice_addr = SocketAddr::new(args.bind, args.port);
+    // Setup WebDAV listener if enabled (read-only gateway to the file area)
+    let webdav_listener = if args.webdav {
+        let webdav_addr = SocketAddr::new(args.bind, args.webdav_port);
+        match TcpListener::bind(webdav_addr).await {
+            Ok(listener) => {
+                println!("{}{}", MSG_WEBDAV_LISTENING, webdav_addr);
+                Some(listener)
+            }
+            Err(e) => {
+                log_error!("{}{}: {}", ERR_BIND_FAILED, webdav_addr, e);
+                std::process::exit(1);
+            }
+        }
+    } else {
+        None
+    };
+
     let cert_path = cert_dir.join(CERT_FILENAME);
     let key_path = cert_dir.join(KEY_FILENAME);
+
+    // Load or generate the server identity key and bind it to the certificate
+    let identity = match certificate_fingerprint(&cert_path)
+        .and_then(|fingerprint| identity::load_or_generate_identity(&cert_dir, &fingerprint))
+    {
+        Ok(identity) => Arc::new(identity),
+        Err(e) => {
+            log_error!("{}{}", ERR_IDENTITY_INIT, e);
+            std::process::exit(1);
+        }
+    };
+    println!("{}{}", MSG_IDENTITY_KEY, identity.public_key);
+
+    // Setup voice DTLS listener (same port as TCP, OS routes by protocol)
+    let voice_addr = SocketAddr::new(args.bind, args.port);
     let voice_listener = match create_voice_listener(voice_addr, &cert_path, &key_path).await {
         Ok(listener) => {
             println!("{}{}", MSG_VOICE_LISTENING, voice_addr);
             Some(listener)
         }
         Err(e) => {
-            eprintln!("Warning: Voice DTLS listener failed: {}", e);
-            eprintln!("Voice chat will be unavailable");
+            log_warn!("Warning: Voice DTLS listener failed: {}", e);
+            log_warn!("Voice chat will be unavailable");
             None
         }
     };
@@ -160,6 +258,7 @@ async fn main() {
     };
 
     // Setup UPnP port forwarding if requested (forwards WS ports only if enabled)
+    let nat_status = NatStatus::default();
     let upnp_handle = setup_upnp(
         args.upnp,
         args.bind,
@@ -171,16 +270,192 @@ async fn main() {
             None
         },
         transfer_websocket_port,
+        &nat_status,
     )
     .await;
 
+    // Advertise on the local network if requested
+    let mdns = if args.mdns {
+        setup_mdns(args.bind, args.port, &database, &cert_path).await
+    } else {
+        Mdns::default()
+    };
+
+    // Let admins run connectivity checks through the check service
+    let check_config = args.check_service.as_ref().map(|service| {
+        println!("{}{}", MSG_CHECK_SERVICE, service);
+        CheckConfig {
+            service: service.clone(),
+            targets: reachability::check_targets(
+                args.port,
+                transfer_port,
+                if websocket_enabled {
+                    Some(args.websocket_port)
+                } else {
+                    None
+                },
+                transfer_websocket_port,
+            ),
+        }
+    });
+
+    // Ranks earned from account activity
+    if !args.ranks.is_empty() {
+        let names: Vec<&str> = args.ranks.iter().map(|rank| rank.name.as_str()).collect();
+        println!("{}{}", MSG_RANKS, names.join(", "));
+    }
+    let ranks = Ranks::new(args.ranks.clone());
+
+    // Door programs users can run
+    if !args.doors.is_empty() {
+        let names: Vec<&str> = args.doors.iter().map(|door| door.name.as_str()).collect();
+        println!("{}{}", MSG_DOORS, names.join(", "));
+    }
+    let doors = Doors::new(args.doors.clone());
+
+    // Per-connection frame size and rate limits
+    if !args.max_frame_sizes.is_empty() {
+        let limits: Vec<String> = args
+            .max_frame_sizes
+            .iter()
+            .map(|limit| format!("{}={}", limit.message_type, limit.max))
+            .collect();
+        println!("{}{}", MSG_FRAME_SIZE_LIMITS, limits.join(", "));
+    }
+    let protocol_limits = ProtocolLimits::new(LimitsConfig {
+        frame_sizes: args.max_frame_sizes.clone(),
+        messages_per_second: args.max_messages_per_second,
+        bytes_per_minute: args.max_bytes_per_minute,
+        flood_action: args.flood_action,
+    });
+
+    // Guest limits and self-registration
+    if let Some(role) = &args.guest_upgrade_role {
+        println!("{}{}", MSG_GUEST_UPGRADE_ROLE, role);
+    }
+    let guest_config = GuestConfig {
+        max_per_ip: args.max_guests_per_ip,
+        upgrade_role: args.guest_upgrade_role.clone(),
+    };
+
+    // News image size and dimension limits
+    let news_image_limits =
+        news_images::clamped_limits(args.news_image_max_size, args.news_image_max_dimension);
+
+    // Free space safeguards for the file area and database disks
+    let storage_config = storage::StorageConfig {
+        min_free: args.min_free_space,
+        webhook: args.storage_webhook.clone(),
+    };
+    if let Err(e) = storage_config.validate() {
+        log_error!("{}{}", ERR_STORAGE_CONFIG, e);
+        std::process::exit(1);
+    }
+
+    // Single sign-on through an OpenID Connect provider
+    let oidc = if let (Some(issuer), Some(client_id)) = (&args.oidc_issuer, &args.oidc_client_id) {
+        let client_secret = match &args.oidc_client_secret_file {
+            Some(path) => match fs::read_to_string(path) {
+                Ok(secret) => Some(secret.trim_end().to_string()),
+                Err(e) => {
+                    log_error!("{}{}", ERR_OIDC_SECRET_FILE, e);
+                    std::process::exit(1);
+                }
+            },
+            None => args.oidc_client_secret.clone(),
+        };
+        let config = oidc::OidcConfig {
+            issuer: issuer.clone(),
+            client_id: client_id.clone(),
+            client_secret,
+            username_claim: args.oidc_username_claim.clone(),
+            role_account: args.oidc_role_account.clone(),
+        };
+        if let Err(e) = config.validate() {
+            log_error!("{}{}", ERR_OIDC_CONFIG, e);
+            std::process::exit(1);
+        }
+        println!("{}{}", MSG_OIDC_ISSUER, issuer);
+        Oidc::new(config)
+    } else {
+        Oidc::default()
+    };
+
+    // Logins against an LDAP or Active Directory server
+    let ldap = if let (Some(url), Some(bind_dn), Some(search_base)) =
+        (&args.ldap_url, &args.ldap_bind_dn, &args.ldap_search_base)
+    {
+        let config = ldap::LdapConfig {
+            url: url.clone(),
+            bind_dn: bind_dn.clone(),
+            search_base: search_base.clone(),
+            user_filter: args.ldap_user_filter.clone(),
+            ca_cert: args.ldap_ca_cert.clone(),
+        };
+        if let Err(e) = config.validate() {
+            log_error!("{}{}", ERR_LDAP_CONFIG, e);
+            std::process::exit(1);
+        }
+        println!("{}{}", MSG_LDAP_URL, url);
+        if config.is_plaintext() {
+            log_warn!("{}", WARN_LDAP_PLAINTEXT);
+        }
+        match Ldap::new(config) {
+            Ok(ldap) => ldap,
+            Err(e) => {
+                log_error!("{}{}", ERR_LDAP_CONFIG, e);
+                std::process::exit(1);
+            }
+        }
+    } else {
+        Ldap::default()
+    };
+
+    // Answer connectivity checks from other servers if requested
+    if let Some(port) = args.check_responder_port {
+        let addr = SocketAddr::new(args.bind, port);
+        match TcpListener::bind(addr).await {
+            Ok(listener) => {
+                reachability::spawn_check_responder(listener, ip_rule_cache.clone(), args.debug);
+                println!("{}{}", MSG_CHECK_RESPONDER, addr);
+            }
+            Err(e) => {
+                log_error!("{}{}: {}", ERR_BIND_FAILED, addr, e);
+                std::process::exit(1);
+            }
+        }
+    }
+
     // Setup connection tracking for DoS protection (load limits from database)
     let max_connections_per_ip = database.config.get_max_connections_per_ip().await;
     let max_transfers_per_ip = database.config.get_max_transfers_per_ip().await;
-    let connection_tracker = Arc::new(ConnectionTracker::new(
-        max_connections_per_ip,
-        max_transfers_per_ip,
-    ));
+    let connection_tracker = Arc::new(
+        ConnectionTracker::new(max_connections_per_ip, max_transfers_per_ip)
+            .with_scanner_mitigation(args.scanner_mitigation),
+    );
+    if args.scanner_mitigation != ScannerMitigation::Off {
+        println!(
+            "{}{}",
+            MSG_SCANNER_MITIGATION,
+            args.scanner_mitigation.as_str()
+        );
+    }
+
+    // Setup DNS blocklist checks for BBS connections (if any zones are given)
+    let reputation_config = if args.dnsbl_zones.is_empty() {
+        None
+    } else {
+        println!(
+            "{}{} ({})",
+            MSG_DNSBL,
+            args.dnsbl_zones.join(", "),
+            args.dnsbl_policy.as_str()
+        );
+        Some(Arc::new(ReputationConfig {
+            zones: args.dnsbl_zones.clone(),
+            policy: args.dnsbl_policy,
+        }))
+    };
 
     // Setup graceful shutdown handling
     let shutdown_signal = setup_shutdown_signal();
@@ -197,11 +472,63 @@ async fn main() {
     // Trigger initial index build in background
     file_index.trigger_reindex();
 
-    // Create transfer registry for tracking active transfers (enables ban disconnection)
-    let transfer_registry = Arc::new(TransferRegistry::new());
+    // Stale upload age for the journal sweep and file area cleanup
+    let stale_upload_age = Duration::from_secs(u64::from(args.stale_upload_days) * 86400);
+
+    // Create transfer registry for tracking active transfers (enables ban disconnection
+    // and queueing beyond the active transfer limit; load limits from database)
+    let max_active_transfers = database.config.get_max_active_transfers().await;
+    let transfer_small_files_first = database.config.get_transfer_small_files_first().await;
+    let transfer_registry = Arc::new(
+        TransferRegistry::with_limits(max_active_transfers, transfer_small_files_first)
+            .with_stale_upload_age(stale_upload_age),
+    );
+
+    // Clean up uploads abandoned while the server was down
+    match sweep_upload_journal(
+        &database.upload_journal,
+        transfer_registry.part_claims(),
+        file_root,
+        stale_upload_age,
+    )
+    .await
+    {
+        Ok(sweep) if sweep.resumable > 0 || sweep.removed > 0 => {
+            println!(
+                "{}{} resumable ({} bytes), {} stale removed ({} bytes)",
+                MSG_UNFINISHED_UPLOADS,
+                sweep.resumable,
+                sweep.resumable_bytes,
+                sweep.removed,
+                sweep.removed_bytes
+            );
+        }
+        Ok(_) => {}
+        Err(e) => log_error!("{}{}", ERR_UPLOAD_JOURNAL_SWEEP, e),
+    }
 
     // Create voice registry for tracking active voice sessions (ephemeral, in-memory only)
-    let voice_registry = VoiceRegistry::new();
+    let voice_registry = VoiceRegistry::with_network(VoiceNetworkConfig {
+        advertise_address: args.voice_advertise_address.clone(),
+        advertise_port: args.voice_advertise_port,
+        tcp_relay: args.voice_tcp_relay,
+    });
+
+    // Create idempotency key table for resent messages (in-memory only)
+    let idempotency_keys = IdempotencyKeys::new();
+
+    // Create group manager for group conversations (in-memory only)
+    let group_manager = GroupManager::new();
+
+    // Create login token table for session resumption (in-memory only)
+    let login_tokens = LoginTokens::new();
+
+    // Create statistics collector for daily activity (flushed to the database each minute)
+    let stats = Arc::new(StatsCollector::new());
+    let outbound_metrics = Arc::new(OutboundMetrics::new());
+
+    // Create broadcast manager for scheduled and acknowledged broadcasts (in-memory only)
+    let broadcast_manager = BroadcastManager::new();
 
     // Create channel manager for multi-channel chat (needed by voice server for broadcasts)
     let channel_manager = ChannelManager::new(database.channels.clone(), user_manager.clone());
@@ -232,12 +559,20 @@ async fn main() {
                     } else {
                         (Some(settings.topic), Some(settings.topic_set_by))
                     };
-                    channels_to_init.push(Channel::with_settings(
-                        name.to_string(),
-                        topic,
-                        topic_set_by,
-                        settings.secret,
-                    ));
+                    channels_to_init.push(Channel {
+                        entry_message: (!settings.entry_message.is_empty())
+                            .then_some(settings.entry_message),
+                        rules: (!settings.rules.is_empty()).then_some(settings.rules),
+                        language: (!settings.language.is_empty()).then_some(settings.language),
+                        ..Channel::with_settings(
+                            name.to_string(),
+                            topic,
+                            topic_set_by,
+                            settings.secret,
+                            settings.voice_limit,
+                            settings.topic_locked,
+                        )
+                    });
                 }
                 Ok(None) => {
                     // Channel in config but not in DB - create default settings
@@ -248,15 +583,20 @@ async fn main() {
                             topic: String::new(),
                             topic_set_by: String::new(),
                             secret: false,
+                            voice_limit: 0,
+                            topic_locked: false,
+                            entry_message: String::new(),
+                            rules: String::new(),
+                            language: String::new(),
                         })
                         .await
                     {
-                        eprintln!("Failed to create channel settings for {}: {}", name, e);
+                        log_error!("Failed to create channel settings for {}: {}", name, e);
                     }
                     channels_to_init.push(Channel::new(name.to_string()));
                 }
                 Err(e) => {
-                    eprintln!("Failed to load channel settings for {}: {}", name, e);
+                    log_error!("Failed to load channel settings for {}: {}", name, e);
                     channels_to_init.push(Channel::new(name.to_string()));
                 }
             }
@@ -272,12 +612,13 @@ async fn main() {
                         .delete_channel_settings(&settings.name)
                         .await
                     {
-                        eprintln!(
+                        log_error!(
                             "Failed to delete stale channel settings for {}: {}",
-                            settings.name, e
+                            settings.name,
+                            e
                         );
                     } else if args.debug {
-                        eprintln!("Pruned stale channel settings for {}", settings.name);
+                        log_info!("Pruned stale channel settings for {}", settings.name);
                     }
                 }
             }
@@ -287,7 +628,7 @@ async fn main() {
             .initialize_persistent_channels(channels_to_init)
             .await;
         if args.debug {
-            eprintln!("Initialized {} persistent channel(s)", channel_names.len());
+            log_debug!("Initialized {} persistent channel(s)", channel_names.len());
         }
     }
 
@@ -295,6 +636,28 @@ async fn main() {
     let file_index_for_timer = file_index.clone();
     let database_for_timer = database.clone();
     let debug_for_timer = args.debug;
+    let database_for_retention = database.clone();
+    let news_retention_days = args.news_retention_days;
+    let database_for_history = database.clone();
+    let database_for_snippets = database.clone();
+    let database_for_stats = database.clone();
+    let user_manager_for_stats = user_manager.clone();
+    let stats_for_flush = stats.clone();
+    let outbound_metrics_for_flush = outbound_metrics.clone();
+    let database_for_journal = database.clone();
+    let transfer_registry_for_journal = transfer_registry.clone();
+    let database_for_cleanup = database.clone();
+    let file_index_for_cleanup = file_index.clone();
+    let transfer_registry_for_cleanup = transfer_registry.clone();
+    let database_for_audit = database.clone();
+    let database_for_history_flush = database.clone();
+    let user_manager_for_broadcasts = user_manager.clone();
+    let channel_manager_for_broadcasts = channel_manager.clone();
+    let broadcast_manager_for_schedule = broadcast_manager.clone();
+    let user_manager_for_storage = user_manager.clone();
+    let file_changes = file_index.subscribe_changes();
+    let user_manager_for_file_changes = user_manager.clone();
+    let database_dir_for_storage = data_dir.to_path_buf();
 
     // Main server loops - accept incoming connections on both ports
     let debug = args.debug;
@@ -308,28 +671,51 @@ async fn main() {
 
                 // Remove port mapping
                 if let Err(e) = gateway.remove_port_mapping().await {
-                    eprintln!("{}{}", WARN_UPNP_REMOVE_MAPPING_FAILED, e);
+                    log_warn!("{}{}", WARN_UPNP_REMOVE_MAPPING_FAILED, e);
                 }
             }
+
+            // Tell LAN clients the server is going away
+            mdns.stop();
+
+            // Save statistics counted since the last flush
+            if let Err(e) = stats.flush(&database.stats).await {
+                log_error!("{}{}", ERR_STATS_FLUSH, e);
+            }
+
+            // Save queued audit log entries
+            if let Err(e) = database.audit.flush().await {
+                log_error!("{}{}", ERR_AUDIT_FLUSH, e);
+            }
+
+            // Save queued direct messages
+            if let Err(e) = database.message_history.flush().await {
+                log_error!("{}{}", ERR_MESSAGE_HISTORY_FLUSH, e);
+            }
         }
         // Main BBS port accept loop
         _ = async {
             loop {
                 match listener.accept().await {
                     Ok((socket, peer_addr)) => {
+                        // Penalized scanners are dropped before any other work
+                        if connection_tracker.is_penalized(peer_addr.ip()) {
+                            continue;
+                        }
+
                         // Check connection limit before accepting
                         let connection_guard = match connection_tracker.try_acquire(peer_addr.ip()) {
                             Some(guard) => guard,
                             None => {
                                 if debug {
-                                    eprintln!("{}{}", ERR_CONNECTION_LIMIT, peer_addr.ip());
+                                    log_debug!("{}{}", ERR_CONNECTION_LIMIT, peer_addr.ip());
                                 }
                                 // Just drop the socket - client will see connection reset
                                 continue;
                             }
                         };
 
-                        let params = ConnectionParams {
+                        let mut params = ConnectionParams {
                             peer_addr,
                             user_manager: user_manager.clone(),
                             db: database.clone(),
@@ -343,11 +729,31 @@ async fn main() {
                             channel_manager: channel_manager.clone(),
                             transfer_registry: transfer_registry.clone(),
                             voice_registry: voice_registry.clone(),
+                            idempotency_keys: idempotency_keys.clone(),
+                            group_manager: group_manager.clone(),
+                            login_tokens: login_tokens.clone(),
+                            broadcast_manager: broadcast_manager.clone(),
+                            server_log: server_log.clone(),
+                            stats: stats.clone(),
+                            outbound_metrics: outbound_metrics.clone(),
+                            oidc: oidc.clone(),
+                            doors: doors.clone(),
+                            ldap: ldap.clone(),
+                            guest_config: guest_config.clone(),
+                            news_image_limits,
+                            ranks: ranks.clone(),
+                            check_config: check_config.clone(),
+                            nat_status: nat_status.clone(),
+                            mdns: mdns.clone(),
+                            protocol_limits: protocol_limits.clone(),
+                            identity: identity.clone(),
+                            reputation_policy: None,
                         };
                         let tls_acceptor = tls_acceptor.clone();
 
-                        // Clone IP rule cache for pre-TLS check
+                        // Clone IP rule cache and blocklist config for pre-TLS checks
                         let ip_rule_cache_for_check = ip_rule_cache.clone();
+                        let reputation_config_for_check = reputation_config.clone();
 
                         // Spawn a new task to handle this connection
                         tokio::spawn(async move {
@@ -379,11 +785,43 @@ async fn main() {
                                 // IP is banned (and not trusted) - silently close connection
                                 // No TLS, no error message, no resources wasted
                                 if debug {
-                                    eprintln!("Rejected banned IP: {}", peer_addr.ip());
+                                    log_debug!("Rejected banned IP: {}", peer_addr.ip());
                                 }
                                 return;
                             }
 
+                            // Drop or drip scanner probes before TLS
+                            let Some(socket) = screen_connection(
+                                socket,
+                                peer_addr,
+                                &params.connection_tracker,
+                                &ip_rule_cache_for_check,
+                                debug,
+                            )
+                            .await
+                            else {
+                                return;
+                            };
+
+                            // Check DNS blocklists (cached) and apply the policy to listed IPs
+                            if let Some(config) = &reputation_config_for_check
+                                && check_reputation(peer_addr.ip(), config, &ip_rule_cache_for_check)
+                                    .await
+                                    .is_some()
+                            {
+                                match config.policy {
+                                    ReputationPolicy::Reject => {
+                                        if debug {
+                                            log_debug!("Rejected blocklisted IP: {}", peer_addr.ip());
+                                        }
+                                        return;
+                                    }
+                                    ReputationPolicy::Tarpit => tokio::time::sleep(TARPIT_DELAY).await,
+                                    ReputationPolicy::Pow => {}
+                                }
+                                params.reputation_policy = Some(config.policy);
+                            }
+
                             if let Err(e) =
                                 connection::handle_connection(socket, tls_acceptor, params).await
                             {
@@ -392,7 +830,7 @@ async fn main() {
                         });
                     }
                     Err(e) => {
-                        eprintln!("{}{}", ERR_ACCEPT, e);
+                        log_error!("{}{}", ERR_ACCEPT, e);
                     }
                 }
             }
@@ -402,30 +840,41 @@ async fn main() {
             loop {
                 match transfer_listener.accept().await {
                     Ok((socket, peer_addr)) => {
+                        // Penalized scanners are dropped before any other work
+                        if connection_tracker.is_penalized(peer_addr.ip()) {
+                            continue;
+                        }
+
                         // Check transfer connection limit before accepting
                         let transfer_guard = match connection_tracker.try_acquire_transfer(peer_addr.ip()) {
                             Some(guard) => guard,
                             None => {
                                 if debug {
-                                    eprintln!("{}{}", ERR_CONNECTION_LIMIT, peer_addr.ip());
+                                    log_debug!("{}{}", ERR_CONNECTION_LIMIT, peer_addr.ip());
                                 }
                                 // Just drop the socket - client will see connection reset
                                 continue;
                             }
                         };
 
-                        let params = TransferParams {
+                        let mut params = TransferParams {
                             peer_addr,
                             db: database.clone(),
                             debug,
                             file_root: Some(file_root),
                             file_index: file_index.clone(),
                             transfer_registry: transfer_registry.clone(),
+                            login_tokens: login_tokens.clone(),
+                            stats: stats.clone(),
+                            ldap: ldap.clone(),
+                            storage: storage_config.clone(),
+                            is_trusted: false,
                         };
                         let tls_acceptor = tls_acceptor.clone();
 
-                        // Clone IP rule cache for pre-TLS check
+                        // Clone IP rule cache and tracker for pre-TLS checks
                         let ip_rule_cache_for_check = ip_rule_cache.clone();
+                        let connection_tracker_for_check = connection_tracker.clone();
 
                         tokio::spawn(async move {
                             let _guard = transfer_guard;
@@ -454,11 +903,30 @@ async fn main() {
                             if !should_allow {
                                 // IP is banned (and not trusted) - silently close connection
                                 if debug {
-                                    eprintln!("Rejected banned IP on transfer port: {}", peer_addr.ip());
+                                    log_debug!("Rejected banned IP on transfer port: {}", peer_addr.ip());
                                 }
                                 return;
                             }
 
+                            // Drop or drip scanner probes before TLS
+                            let Some(socket) = screen_connection(
+                                socket,
+                                peer_addr,
+                                &connection_tracker_for_check,
+                                &ip_rule_cache_for_check,
+                                debug,
+                            )
+                            .await
+                            else {
+                                return;
+                            };
+
+                            // Trusted IPs get a larger fair share of transfer slots
+                            params.is_trusted = ip_rule_cache_for_check
+                                .read()
+                                .expect("ip rule cache lock poisoned")
+                                .is_trusted_read_only(peer_addr.ip());
+
                             if let Err(e) =
                                 transfers::handle_transfer_connection(socket, tls_acceptor, params)
                                     .await
@@ -468,7 +936,7 @@ async fn main() {
                         });
                     }
                     Err(e) => {
-                        eprintln!("{}{}", ERR_ACCEPT, e);
+                        log_error!("{}{}", ERR_ACCEPT, e);
                     }
                 }
             }
@@ -488,13 +956,13 @@ async fn main() {
                             Some(guard) => guard,
                             None => {
                                 if debug {
-                                    eprintln!("{}{}", ERR_CONNECTION_LIMIT, peer_addr.ip());
+                                    log_debug!("{}{}", ERR_CONNECTION_LIMIT, peer_addr.ip());
                                 }
                                 continue;
                             }
                         };
 
-                        let params = ConnectionParams {
+                        let mut params = ConnectionParams {
                             peer_addr,
                             user_manager: user_manager.clone(),
                             db: database.clone(),
@@ -508,9 +976,29 @@ async fn main() {
                             channel_manager: channel_manager.clone(),
                             transfer_registry: transfer_registry.clone(),
                             voice_registry: voice_registry.clone(),
+                            idempotency_keys: idempotency_keys.clone(),
+                            group_manager: group_manager.clone(),
+                            login_tokens: login_tokens.clone(),
+                            broadcast_manager: broadcast_manager.clone(),
+                            server_log: server_log.clone(),
+                            stats: stats.clone(),
+                            outbound_metrics: outbound_metrics.clone(),
+                            oidc: oidc.clone(),
+                            doors: doors.clone(),
+                            ldap: ldap.clone(),
+                            guest_config: guest_config.clone(),
+                            news_image_limits,
+                            ranks: ranks.clone(),
+                            check_config: check_config.clone(),
+                            nat_status: nat_status.clone(),
+                            mdns: mdns.clone(),
+                            protocol_limits: protocol_limits.clone(),
+                            identity: identity.clone(),
+                            reputation_policy: None,
                         };
                         let tls_acceptor = tls_acceptor.clone();
                         let ip_rule_cache_for_check = ip_rule_cache.clone();
+                        let reputation_config_for_check = reputation_config.clone();
 
                         tokio::spawn(async move {
                             let _guard = connection_guard;
@@ -533,11 +1021,33 @@ async fn main() {
 
                             if !should_allow {
                                 if debug {
-                                    eprintln!("Rejected banned IP on WebSocket port: {}", peer_addr.ip());
+                                    log_debug!("Rejected banned IP on WebSocket port: {}", peer_addr.ip());
                                 }
                                 return;
                             }
 
+                            // Check DNS blocklists (same as TCP)
+                            if let Some(config) = &reputation_config_for_check
+                                && check_reputation(peer_addr.ip(), config, &ip_rule_cache_for_check)
+                                    .await
+                                    .is_some()
+                            {
+                                match config.policy {
+                                    ReputationPolicy::Reject => {
+                                        if debug {
+                                            log_debug!(
+                                                "Rejected blocklisted IP on WebSocket port: {}",
+                                                peer_addr.ip()
+                                            );
+                                        }
+                                        return;
+                                    }
+                                    ReputationPolicy::Tarpit => tokio::time::sleep(TARPIT_DELAY).await,
+                                    ReputationPolicy::Pow => {}
+                                }
+                                params.reputation_policy = Some(config.policy);
+                            }
+
                             if let Err(e) =
                                 websocket::handle_websocket_connection(socket, tls_acceptor, params)
                                     .await
@@ -547,7 +1057,7 @@ async fn main() {
                         });
                     }
                     Err(e) => {
-                        eprintln!("{}{}", ERR_ACCEPT, e);
+                        log_error!("{}{}", ERR_ACCEPT, e);
                     }
                 }
             }
@@ -567,19 +1077,24 @@ async fn main() {
                             Some(guard) => guard,
                             None => {
                                 if debug {
-                                    eprintln!("{}{}", ERR_CONNECTION_LIMIT, peer_addr.ip());
+                                    log_debug!("{}{}", ERR_CONNECTION_LIMIT, peer_addr.ip());
                                 }
                                 continue;
                             }
                         };
 
-                        let params = TransferParams {
+                        let mut params = TransferParams {
                             peer_addr,
                             db: database.clone(),
                             debug,
                             file_root: Some(file_root),
                             file_index: file_index.clone(),
                             transfer_registry: transfer_registry.clone(),
+                            login_tokens: login_tokens.clone(),
+                            stats: stats.clone(),
+                            ldap: ldap.clone(),
+                            storage: storage_config.clone(),
+                            is_trusted: false,
                         };
                         let tls_acceptor = tls_acceptor.clone();
                         let ip_rule_cache_for_check = ip_rule_cache.clone();
@@ -605,11 +1120,16 @@ async fn main() {
 
                             if !should_allow {
                                 if debug {
-                                    eprintln!("Rejected banned IP on WebSocket transfer port: {}", peer_addr.ip());
+                                    log_debug!("Rejected banned IP on WebSocket transfer port: {}", peer_addr.ip());
                                 }
                                 return;
                             }
 
+                            params.is_trusted = ip_rule_cache_for_check
+                                .read()
+                                .expect("ip rule cache lock poisoned")
+                                .is_trusted_read_only(peer_addr.ip());
+
                             if let Err(e) =
                                 websocket::handle_websocket_transfer_connection(
                                     socket,
@@ -623,7 +1143,96 @@ async fn main() {
                         });
                     }
                     Err(e) => {
-                        eprintln!("{}{}", ERR_ACCEPT, e);
+                        log_error!("{}{}", ERR_ACCEPT, e);
+                    }
+                }
+            }
+        } => {}
+        // WebDAV port accept loop (only if enabled)
+        _ = async {
+            let Some(ref webdav_listener) = webdav_listener else {
+                // WebDAV disabled, just wait forever
+                std::future::pending::<()>().await;
+                return;
+            };
+            loop {
+                match webdav_listener.accept().await {
+                    Ok((socket, peer_addr)) => {
+                        // Penalized scanners are dropped before any other work
+                        if connection_tracker.is_penalized(peer_addr.ip()) {
+                            continue;
+                        }
+
+                        // Mounts count against the transfer connection limit
+                        let transfer_guard = match connection_tracker.try_acquire_transfer(peer_addr.ip()) {
+                            Some(guard) => guard,
+                            None => {
+                                if debug {
+                                    log_debug!("{}{}", ERR_CONNECTION_LIMIT, peer_addr.ip());
+                                }
+                                continue;
+                            }
+                        };
+
+                        let params = WebDavParams {
+                            peer_addr,
+                            db: database.clone(),
+                            debug,
+                            file_root,
+                            ldap: ldap.clone(),
+                        };
+                        let tls_acceptor = tls_acceptor.clone();
+                        let ip_rule_cache_for_check = ip_rule_cache.clone();
+                        let connection_tracker_for_check = connection_tracker.clone();
+
+                        tokio::spawn(async move {
+                            let _guard = transfer_guard;
+
+                            // Check IP rules BEFORE TLS handshake (same as transfers)
+                            let should_allow = {
+                                let cache = ip_rule_cache_for_check
+                                    .read()
+                                    .expect("ip rule cache lock poisoned");
+                                if cache.needs_rebuild() {
+                                    drop(cache);
+                                    ip_rule_cache_for_check
+                                        .write()
+                                        .expect("ip rule cache lock poisoned")
+                                        .should_allow(peer_addr.ip())
+                                } else {
+                                    cache.should_allow_read_only(peer_addr.ip())
+                                }
+                            };
+
+                            if !should_allow {
+                                if debug {
+                                    log_debug!("Rejected banned IP on WebDAV port: {}", peer_addr.ip());
+                                }
+                                return;
+                            }
+
+                            // Drop or drip scanner probes before TLS
+                            let Some(socket) = screen_connection(
+                                socket,
+                                peer_addr,
+                                &connection_tracker_for_check,
+                                &ip_rule_cache_for_check,
+                                debug,
+                            )
+                            .await
+                            else {
+                                return;
+                            };
+
+                            if let Err(e) =
+                                webdav::handle_webdav_connection(socket, tls_acceptor, params).await
+                            {
+                                log_connection_error(&e, peer_addr, debug);
+                            }
+                        });
+                    }
+                    Err(e) => {
+                        log_error!("{}{}", ERR_ACCEPT, e);
                     }
                 }
             }
@@ -655,12 +1264,157 @@ async fn main() {
                 // Check if dirty and not already reindexing
                 if file_index_for_timer.is_dirty() && !file_index_for_timer.is_reindexing() {
                     if debug_for_timer {
-                        eprintln!("File index is dirty, triggering reindex");
+                        log_debug!("File index is dirty, triggering reindex");
                     }
                     file_index_for_timer.trigger_reindex();
                 }
             }
         } => {}
+        // News retention task - deletes expired news each hour
+        _ = async {
+            let Some(days) = news_retention_days else {
+                // Retention disabled, just wait forever
+                std::future::pending::<()>().await;
+                return;
+            };
+            loop {
+                match database_for_retention.news.delete_news_older_than(days).await {
+                    Ok(deleted) if deleted > 0 && debug => {
+                        log_info!("Deleted {} news post(s) older than {} day(s)", deleted, days);
+                    }
+                    Ok(_) => {}
+                    Err(e) => log_error!("{}{}", ERR_NEWS_RETENTION, e),
+                }
+                tokio::time::sleep(Duration::from_secs(NEWS_RETENTION_CHECK_SECS)).await;
+            }
+        } => {}
+        // Message history task - deletes direct messages past each account's retention
+        _ = async {
+            loop {
+                match database_for_history.message_history.delete_expired().await {
+                    Ok(deleted) if deleted > 0 && debug => {
+                        log_info!("Deleted {} expired direct message(s) from history", deleted);
+                    }
+                    Ok(_) => {}
+                    Err(e) => log_error!("{}{}", ERR_MESSAGE_HISTORY_RETENTION, e),
+                }
+                tokio::time::sleep(Duration::from_secs(MESSAGE_HISTORY_RETENTION_CHECK_SECS)).await;
+            }
+        } => {}
+        // Snippet task - deletes snippets past their expiry
+        _ = async {
+            loop {
+                match database_for_snippets.snippets.delete_expired().await {
+                    Ok(deleted) if deleted > 0 && debug => {
+                        log_info!("Deleted {} expired snippet(s)", deleted);
+                    }
+                    Ok(_) => {}
+                    Err(e) => log_error!("{}{}", ERR_SNIPPET_CLEANUP, e),
+                }
+                tokio::time::sleep(Duration::from_secs(SNIPPET_CLEANUP_CHECK_SECS)).await;
+            }
+        } => {}
+        // Upload journal task - deletes stale unfinished uploads each hour
+        _ = async {
+            loop {
+                tokio::time::sleep(Duration::from_secs(UPLOAD_JOURNAL_SWEEP_SECS)).await;
+                match sweep_upload_journal(
+                    &database_for_journal.upload_journal,
+                    transfer_registry_for_journal.part_claims(),
+                    file_root,
+                    stale_upload_age,
+                )
+                .await
+                {
+                    Ok(sweep) if sweep.removed > 0 && debug => {
+                        log_info!(
+                            "Deleted {} stale unfinished upload(s) ({} bytes)",
+                            sweep.removed,
+                            sweep.removed_bytes
+                        );
+                    }
+                    Ok(_) => {}
+                    Err(e) => log_error!("{}{}", ERR_UPLOAD_JOURNAL_SWEEP, e),
+                }
+            }
+        } => {}
+        // File area cleanup task - reports leftovers of aborted transfers each day
+        _ = async {
+            loop {
+                tokio::time::sleep(Duration::from_secs(FILE_CLEANUP_SCAN_SECS)).await;
+                match transfers::cleanup::scan_file_area(
+                    &database_for_cleanup.upload_journal,
+                    transfer_registry_for_cleanup.part_claims(),
+                    &file_index_for_cleanup,
+                    file_root,
+                    stale_upload_age,
+                    false,
+                )
+                .await
+                {
+                    Ok(report) if report.orphaned_parts > 0 || report.temp_files > 0 => {
+                        log_warn!(
+                            "Found {} orphaned .part file(s) ({} bytes) and {} leftover temp file(s); an admin can review and delete them with /cleanup",
+                            report.orphaned_parts,
+                            report.orphaned_bytes,
+                            report.temp_files
+                        );
+                    }
+                    Ok(_) => {}
+                    Err(e) => log_error!("{}{}", ERR_FILE_CLEANUP, e),
+                }
+            }
+        } => {}
+        // Single sign-on discovery task - keeps the provider's endpoints current
+        _ = oidc.run_discovery(debug) => {}
+        // Statistics flush task - saves the day's counters each minute
+        _ = async {
+            loop {
+                tokio::time::sleep(STATS_FLUSH_INTERVAL).await;
+                // Sessions that stay online past midnight count toward the new day's peak
+                stats_for_flush.record_sessions(user_manager_for_stats.session_count().await);
+                if let Err(e) = stats_for_flush.flush(&database_for_stats.stats).await {
+                    log_error!("{}{}", ERR_STATS_FLUSH, e);
+                }
+                outbound_metrics_for_flush.log();
+            }
+        } => {}
+        // Audit log flush task - writes queued entries every few seconds
+        _ = async {
+            loop {
+                tokio::time::sleep(AUDIT_FLUSH_INTERVAL).await;
+                if let Err(e) = database_for_audit.audit.flush().await {
+                    log_error!("{}{}", ERR_AUDIT_FLUSH, e);
+                }
+            }
+        } => {}
+        // Message history flush task - writes queued direct messages every few seconds
+        _ = async {
+            loop {
+                tokio::time::sleep(MESSAGE_HISTORY_FLUSH_INTERVAL).await;
+                if let Err(e) = database_for_history_flush.message_history.flush().await {
+                    log_error!("{}{}", ERR_MESSAGE_HISTORY_FLUSH, e);
+                }
+            }
+        } => {}
+        // Scheduled broadcast task - sends broadcasts once they're due
+        _ = async {
+            loop {
+                tokio::time::sleep(Duration::from_secs(BROADCAST_SCHEDULE_CHECK_SECS)).await;
+                broadcast_manager_for_schedule
+                    .send_due(&user_manager_for_broadcasts, &channel_manager_for_broadcasts)
+                    .await;
+            }
+        } => {}
+        // Storage monitor task - alerts admins when free space crosses the minimum
+        _ = storage::run_monitor(
+            storage_config.clone(),
+            user_manager_for_storage,
+            file_root.to_path_buf(),
+            database_dir_for_storage,
+        ) => {}
+        // File change task - tells sessions watching a directory what changed in it
+        _ = files::watch::run_notifier(file_changes, user_manager_for_file_changes) => {}
     }
 }
 
@@ -774,41 +1528,120 @@ fn set_secure_permissions(path: &std::path::Path) -> Result<(), String> {
 /// Setup database connection and initialize user manager
 async fn setup_db(
     database_path: Option<std::path::PathBuf>,
+    tuning: db::DbTuning,
 ) -> (db::Database, UserManager, std::path::PathBuf) {
     // Determine database path (use provided path or platform default)
     let db_path = database_path.unwrap_or_else(|| match db::default_database_path() {
         Ok(path) => path,
         Err(e) => {
-            eprintln!("{}{}", ERR_GENERIC, e);
+            log_error!("{}{}", ERR_GENERIC, e);
             std::process::exit(1);
         }
     });
 
     // Initialize database connection pool and run migrations
-    let pool = match db::init_db(&db_path).await {
+    let pool = match db::init_db(&db_path, tuning).await {
         Ok(pool) => pool,
         Err(e) => {
-            eprintln!("{}{}", ERR_DATABASE_INIT, e);
+            log_error!("{}{}", ERR_DATABASE_INIT, e);
             std::process::exit(1);
         }
     };
     println!("{}{}", MSG_DATABASE, db_path.display());
 
     // Set secure permissions on database file (0o600) - Unix only
+    // (and on the WAL files beside it, which hold recent changes)
     #[cfg(unix)]
-    if let Err(e) = set_secure_permissions(&db_path) {
-        eprintln!("{}{}", ERR_SET_PERMISSIONS, e);
-        std::process::exit(1);
+    for path in db::tuning::database_files(&db_path) {
+        if let Err(e) = set_secure_permissions(&path) {
+            log_error!("{}{}", ERR_SET_PERMISSIONS, e);
+            std::process::exit(1);
+        }
     }
 
+    // Load the key that encrypts direct message history (beside the database)
+    let history_dir = db_path.parent().unwrap_or(std::path::Path::new("."));
+    let message_key = match db::message_history::load_or_generate_message_key(history_dir) {
+        Ok(key) => key,
+        Err(e) => {
+            log_error!("{}{}", ERR_MESSAGE_HISTORY_INIT, e);
+            std::process::exit(1);
+        }
+    };
+
     // Create database and user manager instances
     // Note: SqlitePool uses Arc internally, so clone() is cheap
-    let database = db::Database::new(pool);
+    let database = db::Database::new(pool).with_message_key(message_key);
     let user_manager = UserManager::new();
 
     (database, user_manager, db_path)
 }
 
+/// Import a server export archive, printing temporary passwords for the
+/// imported accounts
+async fn run_import(database: &db::Database, path: &Path) {
+    let archive: serde_json::Value = match fs::read(path)
+        .map_err(|e| e.to_string())
+        .and_then(|bytes| serde_json::from_slice(&bytes).map_err(|e| e.to_string()))
+    {
+        Ok(archive) => archive,
+        Err(e) => {
+            log_error!("{}{}", ERR_IMPORT_READ, e);
+            std::process::exit(1);
+        }
+    };
+
+    match db::archive::import_archive(database, &archive, false).await {
+        Ok(summary) => {
+            println!("{}{}", MSG_IMPORT_COMPLETE, summary);
+            if !summary.passwords.is_empty() {
+                println!("{}", MSG_IMPORT_PASSWORDS);
+                for (username, password) in &summary.passwords {
+                    println!("  {username}: {password}");
+                }
+            }
+        }
+        Err(e) => {
+            log_error!("{}{}", ERR_IMPORT, e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Test whether the configured ports are reachable from outside, printing
+/// one line per port
+async fn run_connectivity_check(args: &Args) {
+    let Some(service) = &args.check_service else {
+        log_error!("{}", ERR_CHECK_NO_SERVICE);
+        std::process::exit(1);
+    };
+    let config = CheckConfig {
+        service: service.clone(),
+        targets: reachability::check_targets(
+            args.port,
+            args.transfer_port,
+            args.websocket.then_some(args.websocket_port),
+            args.websocket.then_some(args.transfer_websocket_port),
+        ),
+    };
+
+    println!("{}{}", MSG_CHECK_RUNNING, service);
+    match reachability::run_check(&config, args.bind, true).await {
+        Ok(report) => {
+            if let Some(external_ip) = &report.external_ip {
+                println!("{}{}", MSG_CHECK_EXTERNAL_IP, external_ip);
+            }
+            for port in &report.ports {
+                println!("  {}", reachability::describe_port(port));
+            }
+        }
+        Err(e) => {
+            log_error!("{}{}", ERR_CHECK_FAILED, e);
+            std::process::exit(1);
+        }
+    }
+}
+
 /// Setup UPnP port forwarding if enabled
 async fn setup_upnp(
     enabled: bool,
@@ -817,6 +1650,7 @@ async fn setup_upnp(
     transfer_port: u16,
     websocket_port: Option<u16>,
     transfer_websocket_port: Option<u16>,
+    nat_status: &NatStatus,
 ) -> Option<(Arc<upnp::UpnpGateway>, tokio::task::JoinHandle<()>)> {
     if !enabled {
         return None;
@@ -828,6 +1662,7 @@ async fn setup_upnp(
         transfer_port,
         websocket_port,
         transfer_websocket_port,
+        nat_status.clone(),
     )
     .await
     {
@@ -838,14 +1673,39 @@ async fn setup_upnp(
             Some((gateway_arc, renewal_task))
         }
         Err(e) => {
-            eprintln!("{}{}", MSG_UPNP_WARNING, e);
-            eprintln!("{}", MSG_UPNP_CONTINUE);
-            eprintln!("{}", MSG_UPNP_MANUAL);
+            log_warn!("{}{}", MSG_UPNP_WARNING, e);
+            log_warn!("{}", MSG_UPNP_CONTINUE);
+            log_warn!("{}", MSG_UPNP_MANUAL);
             None
         }
     }
 }
 
+/// Start mDNS/DNS-SD advertising (failures are non-fatal)
+async fn setup_mdns(
+    bind: std::net::IpAddr,
+    port: u16,
+    database: &db::Database,
+    cert_path: &Path,
+) -> Mdns {
+    let server_name = database.config.get_server_name().await;
+    match certificate_fingerprint(cert_path)
+        .and_then(|fingerprint| Mdns::start(bind, port, &server_name, &fingerprint))
+    {
+        Ok(mdns) => {
+            if let Some(fullname) = mdns.fullname() {
+                println!("{}{}", MSG_MDNS_ADVERTISING, fullname);
+            }
+            mdns
+        }
+        Err(e) => {
+            log_warn!("{}{}", MSG_MDNS_WARNING, e);
+            log_warn!("{}", MSG_MDNS_CONTINUE);
+            Mdns::default()
+        }
+    }
+}
+
 /// Setup network: TCP listeners (main + transfer + optionally WebSocket) and TLS acceptor
 async fn setup_network(
     bind: std::net::IpAddr,
@@ -869,7 +1729,7 @@ async fn setup_network(
     let tls_acceptor = match load_or_generate_tls_config(&cert_dir) {
         Ok(acceptor) => acceptor,
         Err(e) => {
-            eprintln!("{}{}", ERR_TLS_INIT, e);
+            log_error!("{}{}", ERR_TLS_INIT, e);
             std::process::exit(1);
         }
     };
@@ -880,7 +1740,7 @@ async fn setup_network(
     let listener = match TcpListener::bind(addr).await {
         Ok(listener) => listener,
         Err(e) => {
-            eprintln!("{}{}: {}", ERR_BIND_FAILED, addr, e);
+            log_error!("{}{}: {}", ERR_BIND_FAILED, addr, e);
             std::process::exit(1);
         }
     };
@@ -891,7 +1751,7 @@ async fn setup_network(
     let transfer_listener = match TcpListener::bind(transfer_addr).await {
         Ok(listener) => listener,
         Err(e) => {
-            eprintln!("{}{}: {}", ERR_BIND_FAILED, transfer_addr, e);
+            log_error!("{}{}: {}", ERR_BIND_FAILED, transfer_addr, e);
             std::process::exit(1);
         }
     };
@@ -906,7 +1766,7 @@ async fn setup_network(
         let ws_listener = match TcpListener::bind(ws_addr).await {
             Ok(listener) => listener,
             Err(e) => {
-                eprintln!("{}{}: {}", ERR_BIND_FAILED, ws_addr, e);
+                log_error!("{}{}: {}", ERR_BIND_FAILED, ws_addr, e);
                 std::process::exit(1);
             }
         };
@@ -917,7 +1777,7 @@ async fn setup_network(
         let ws_transfer_listener = match TcpListener::bind(ws_transfer_addr).await {
             Ok(listener) => listener,
             Err(e) => {
-                eprintln!("{}{}: {}", ERR_BIND_FAILED, ws_transfer_addr, e);
+                log_error!("{}{}: {}", ERR_BIND_FAILED, ws_transfer_addr, e);
                 std::process::exit(1);
             }
         };
@@ -940,6 +1800,16 @@ async fn setup_network(
 
 /// Calculate and display certificate fingerprint (SHA-256)
 fn display_certificate_fingerprint(cert_path: &std::path::Path) -> Result<(), String> {
+    let fingerprint = certificate_fingerprint(cert_path)?;
+    println!("{}{}", MSG_CERT_FINGERPRINT, fingerprint);
+    Ok(())
+}
+
+/// Calculate the SHA-256 fingerprint of a certificate file
+///
+/// Returns a colon-separated uppercase hex string (e.g., "AA:BB:CC:..."),
+/// the same format clients display and pin.
+fn certificate_fingerprint(cert_path: &std::path::Path) -> Result<String, String> {
     // Read certificate file
     let cert_pem =
         fs::read_to_string(cert_path).map_err(|e| format!("{}{}", ERR_OPEN_CERT_FILE, e))?;
@@ -954,15 +1824,12 @@ fn display_certificate_fingerprint(cert_path: &std::path::Path) -> Result<(), St
 
     // Format as colon-separated hex string (uppercase)
     let hex_str = hex::encode_upper(fingerprint);
-    let fingerprint_str = hex_str
+    Ok(hex_str
         .as_bytes()
         .chunks(2)
         .map(|chunk| std::str::from_utf8(chunk).expect("hex encoding produces valid ASCII"))
         .collect::<Vec<_>>()
-        .join(":");
-
-    println!("{}{}", MSG_CERT_FINGERPRINT, fingerprint_str);
-    Ok(())
+        .join(":"))
 }
 
 /// Setup file area directories
@@ -974,14 +1841,14 @@ fn setup_file_area(file_root: Option<std::path::PathBuf>) -> std::path::PathBuf
     let root = file_root.unwrap_or_else(|| match files::default_file_root() {
         Ok(path) => path,
         Err(e) => {
-            eprintln!("{}{}", ERR_GENERIC, e);
+            log_error!("{}{}", ERR_GENERIC, e);
             std::process::exit(1);
         }
     });
 
     // Initialize file area directories (creates them if needed)
     if let Err(e) = files::init_file_area(&root) {
-        eprintln!("{}{}", ERR_GENERIC, e);
+        log_error!("{}{}", ERR_GENERIC, e);
         std::process::exit(1);
     }
 
@@ -990,7 +1857,7 @@ fn setup_file_area(file_root: Option<std::path::PathBuf>) -> std::path::PathBuf
     let canonical_root = match root.canonicalize() {
         Ok(path) => path,
         Err(e) => {
-            eprintln!("{}{}{}", ERR_GENERIC, ERR_FILE_ROOT_CANONICALIZE, e);
+            log_error!("{}{}{}", ERR_GENERIC, ERR_FILE_ROOT_CANONICALIZE, e);
             std::process::exit(1);
         }
     };
@@ -1016,12 +1883,12 @@ fn log_connection_error(error: &io::Error, peer_addr: SocketAddr, debug: bool) {
     // TLS handshake failures are debug-only (scanners, incompatible clients)
     if error_msg.contains(TLS_HANDSHAKE_FAILED_PREFIX) {
         if debug {
-            eprintln!("{}{}: {}", ERR_CONNECTION, peer_addr, error);
+            log_debug!("{}{}: {}", ERR_CONNECTION, peer_addr, error);
         }
         return;
     }
 
-    eprintln!("{}{}: {}", ERR_CONNECTION, peer_addr, error);
+    log_error!("{}{}: {}", ERR_CONNECTION, peer_addr, error);
 }
 
 /// Setup graceful shutdown signal handling (Ctrl+C)
@@ -0,0 +1,185 @@
+//! mDNS/DNS-SD (Bonjour) advertising for LAN discovery
+//!
+//! When enabled with the `--mdns` flag, the server registers a `_nexus._tcp`
+//! service on the local network so clients list it on their connection screen
+//! without anyone typing an address. The TXT record carries the server name
+//! (kept current when an admin renames the server) and version.
+//!
+//! The instance and host names come from the certificate fingerprint, so they
+//! stay the same across restarts and don't clash with other servers on the LAN.
+//!
+//! Like UPnP, all failures are non-fatal: the server keeps running without
+//! advertising and prints a warning.
+
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use mdns_sd::{ServiceDaemon, ServiceInfo};
+use nexus_common::{MDNS_SERVICE_TYPE, MDNS_TXT_NAME, MDNS_TXT_VERSION};
+
+use crate::constants::WARN_MDNS_UPDATE_FAILED;
+
+/// How long to wait for the goodbye packet to go out on shutdown
+const UNREGISTER_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Length of the fingerprint prefix used in the instance name
+const INSTANCE_ID_LENGTH: usize = 12;
+
+/// A registered service and what's needed to register it again
+struct Advertiser {
+    daemon: ServiceDaemon,
+    instance: String,
+    bind: IpAddr,
+    port: u16,
+    fullname: String,
+}
+
+impl Advertiser {
+    /// Build the service record with the given display name
+    fn service_info(&self, server_name: &str) -> Result<ServiceInfo, String> {
+        let host_name = format!("{}.local.", self.instance);
+        let properties = [
+            (MDNS_TXT_NAME, server_name),
+            (MDNS_TXT_VERSION, env!("CARGO_PKG_VERSION")),
+        ];
+        let info = if self.bind.is_unspecified() {
+            ServiceInfo::new(
+                MDNS_SERVICE_TYPE,
+                &self.instance,
+                &host_name,
+                "",
+                self.port,
+                &properties[..],
+            )
+            .map(ServiceInfo::enable_addr_auto)
+        } else {
+            ServiceInfo::new(
+                MDNS_SERVICE_TYPE,
+                &self.instance,
+                &host_name,
+                self.bind,
+                self.port,
+                &properties[..],
+            )
+        };
+        info.map_err(|e| e.to_string())
+    }
+}
+
+/// The server's advertisement (inactive without `--mdns`)
+///
+/// Cheap to clone; clones share the advertisement. Main keeps it to stop
+/// advertising on shutdown, and handlers use it to keep the name current.
+#[derive(Clone, Default)]
+pub struct Mdns {
+    advertiser: Arc<Mutex<Option<Advertiser>>>,
+}
+
+impl Mdns {
+    /// Start advertising the server on the local network
+    pub fn start(
+        bind: IpAddr,
+        port: u16,
+        server_name: &str,
+        certificate_fingerprint: &str,
+    ) -> Result<Self, String> {
+        let daemon = ServiceDaemon::new().map_err(|e| e.to_string())?;
+        let mut advertiser = Advertiser {
+            daemon,
+            instance: instance_name(certificate_fingerprint),
+            bind,
+            port,
+            fullname: String::new(),
+        };
+
+        let info = advertiser.service_info(server_name)?;
+        advertiser.fullname = info.get_fullname().to_string();
+        advertiser
+            .daemon
+            .register(info)
+            .map_err(|e| e.to_string())?;
+
+        Ok(Self {
+            advertiser: Arc::new(Mutex::new(Some(advertiser))),
+        })
+    }
+
+    /// The service's full DNS-SD name (None when not advertising)
+    pub fn fullname(&self) -> Option<String> {
+        self.lock()
+            .as_ref()
+            .map(|advertiser| advertiser.fullname.clone())
+    }
+
+    /// Update the advertised server name (no-op when not advertising)
+    pub fn rename(&self, server_name: &str) {
+        let guard = self.lock();
+        let Some(advertiser) = guard.as_ref() else {
+            return;
+        };
+        // Registering the same instance again replaces its records
+        let result = advertiser
+            .service_info(server_name)
+            .and_then(|info| advertiser.daemon.register(info).map_err(|e| e.to_string()));
+        if let Err(e) = result {
+            log_warn!("{}{}", WARN_MDNS_UPDATE_FAILED, e);
+        }
+    }
+
+    /// Stop advertising, telling clients the server is gone
+    ///
+    /// Later calls on any clone are no-ops.
+    pub fn stop(&self) {
+        let Some(advertiser) = self.lock().take() else {
+            return;
+        };
+        if let Ok(status) = advertiser.daemon.unregister(&advertiser.fullname) {
+            let _ = status.recv_timeout(UNREGISTER_TIMEOUT);
+        }
+        let _ = advertiser.daemon.shutdown();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Option<Advertiser>> {
+        self.advertiser.lock().expect("mDNS lock poisoned")
+    }
+}
+
+/// Stable instance name derived from the certificate fingerprint
+///
+/// Fingerprints are colon-separated hex ("AB:CD:..."); only the hex digits are used.
+fn instance_name(certificate_fingerprint: &str) -> String {
+    let id: String = certificate_fingerprint
+        .chars()
+        .filter(char::is_ascii_hexdigit)
+        .take(INSTANCE_ID_LENGTH)
+        .collect::<String>()
+        .to_lowercase();
+    format!("nexus-{id}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_instance_name_from_fingerprint() {
+        assert_eq!(
+            instance_name("AB:CD:EF:01:23:45:67:89:AB:CD"),
+            "nexus-abcdef012345"
+        );
+    }
+
+    #[test]
+    fn test_instance_name_short_fingerprint() {
+        assert_eq!(instance_name("AB:CD"), "nexus-abcd");
+    }
+
+    #[test]
+    fn test_inactive_advertisement_is_noop() {
+        let mdns = Mdns::default();
+        mdns.rename("Test Server");
+        mdns.stop();
+        assert!(mdns.fullname().is_none());
+    }
+}
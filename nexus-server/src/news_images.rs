@@ -0,0 +1,273 @@
+//! News image limits
+//!
+//! Every client that opens the news panel downloads every news image, so the
+//! server keeps them small. Raster images (PNG, JPEG, WebP) with a side longer
+//! than `--news-image-max-dimension`, or bigger than `--news-image-max-size`,
+//! are scaled down and re-encoded before they're stored: as lossless WebP, or
+//! as JPEG when that's smaller and the image has no transparency. An image
+//! that still doesn't fit at [`MIN_NEWS_IMAGE_DIMENSION`] pixels is rejected.
+//!
+//! SVG images can't be shrunk this way, so they must fit the size limit as
+//! they are.
+//!
+//! The limits are advertised in `ServerInfo` so clients can check an image
+//! before posting it.
+
+use std::io::Cursor;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::webp::WebPEncoder;
+use image::imageops::FilterType;
+use image::{DynamicImage, ImageFormat, ImageReader, Limits};
+use nexus_common::protocol::NewsImageLimits;
+use nexus_common::validators::{self, MAX_NEWS_IMAGE_SIZE, NewsImageError};
+
+use crate::constants::{
+    DEFAULT_NEWS_IMAGE_MAX_DIMENSION, MAX_NEWS_IMAGE_DECODE_ALLOC, MAX_NEWS_IMAGE_DECODE_DIMENSION,
+    MIN_NEWS_IMAGE_DIMENSION, NEWS_IMAGE_JPEG_QUALITY,
+};
+
+/// MIME type of SVG images, which are stored as they are
+const SVG_MIME_TYPE: &str = "image/svg+xml";
+
+/// Limits from the command line
+///
+/// The size limit can only be lowered: the protocol doesn't carry images
+/// bigger than [`MAX_NEWS_IMAGE_SIZE`].
+pub fn clamped_limits(max_size: u64, max_dimension: u32) -> NewsImageLimits {
+    NewsImageLimits {
+        max_size: max_size.min(MAX_NEWS_IMAGE_SIZE as u64) as u32,
+        max_dimension,
+    }
+}
+
+/// Limits used when none are given
+pub fn default_limits() -> NewsImageLimits {
+    NewsImageLimits {
+        max_size: MAX_NEWS_IMAGE_SIZE as u32,
+        max_dimension: DEFAULT_NEWS_IMAGE_MAX_DIMENSION,
+    }
+}
+
+/// Validate a news image data URI and bring it within `limits`
+///
+/// Returns the data URI to store, which is the original unless it had to be
+/// scaled down. Decoding and encoding run on the blocking thread pool.
+pub async fn prepare(data_uri: String, limits: NewsImageLimits) -> Result<String, NewsImageError> {
+    validators::validate_news_image(&data_uri)?;
+    match tokio::task::spawn_blocking(move || {
+        fit(&data_uri, limits).map(|fitted| fitted.unwrap_or(data_uri))
+    })
+    .await
+    {
+        Ok(result) => result,
+        Err(e) => {
+            log_error!("News image processing failed: {}", e);
+            Err(NewsImageError::InvalidFormat)
+        }
+    }
+}
+
+/// Fit a data URI within the limits, or None if it already fits
+fn fit(data_uri: &str, limits: NewsImageLimits) -> Result<Option<String>, NewsImageError> {
+    let (mime_type, payload) = data_uri
+        .strip_prefix("data:")
+        .and_then(|rest| rest.split_once(";base64,"))
+        .ok_or(NewsImageError::InvalidFormat)?;
+    let bytes = BASE64
+        .decode(payload)
+        .map_err(|_| NewsImageError::InvalidFormat)?;
+    let max_size = limits.max_size as usize;
+
+    if mime_type == SVG_MIME_TYPE {
+        return if bytes.len() > max_size {
+            Err(NewsImageError::TooLarge)
+        } else {
+            Ok(None)
+        };
+    }
+
+    let format = ImageFormat::from_mime_type(mime_type).ok_or(NewsImageError::UnsupportedType)?;
+    let (width, height) = reader(&bytes, format)
+        .into_dimensions()
+        .map_err(|_| NewsImageError::InvalidFormat)?;
+    let longest_side = width.max(height);
+    let too_wide = limits.max_dimension > 0 && longest_side > limits.max_dimension;
+    if !too_wide && bytes.len() <= max_size {
+        return Ok(None);
+    }
+
+    let image = reader(&bytes, format)
+        .decode()
+        .map_err(|_| NewsImageError::InvalidFormat)?;
+    let mut target = if too_wide {
+        limits.max_dimension
+    } else {
+        longest_side
+    };
+    loop {
+        let scaled = if target < longest_side {
+            image.resize(target, target, FilterType::Lanczos3)
+        } else {
+            image.clone()
+        };
+        let (mime_type, encoded) = encode_smallest(&scaled)?;
+        if encoded.len() <= max_size {
+            return Ok(Some(format!(
+                "data:{};base64,{}",
+                mime_type,
+                BASE64.encode(encoded)
+            )));
+        }
+        if target <= MIN_NEWS_IMAGE_DIMENSION {
+            return Err(NewsImageError::TooLarge);
+        }
+        target = (target * 3 / 4).max(MIN_NEWS_IMAGE_DIMENSION);
+    }
+}
+
+/// Reader for image bytes, with limits against decompression bombs
+fn reader(bytes: &[u8], format: ImageFormat) -> ImageReader<Cursor<&[u8]>> {
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_NEWS_IMAGE_DECODE_DIMENSION);
+    limits.max_image_height = Some(MAX_NEWS_IMAGE_DECODE_DIMENSION);
+    limits.max_alloc = Some(MAX_NEWS_IMAGE_DECODE_ALLOC);
+
+    let mut reader = ImageReader::with_format(Cursor::new(bytes), format);
+    reader.limits(limits);
+    reader
+}
+
+/// Encode an image as lossless WebP or, without transparency, JPEG,
+/// whichever is smaller
+fn encode_smallest(image: &DynamicImage) -> Result<(&'static str, Vec<u8>), NewsImageError> {
+    let has_alpha = image.color().has_alpha();
+    let image = if has_alpha {
+        DynamicImage::ImageRgba8(image.to_rgba8())
+    } else {
+        DynamicImage::ImageRgb8(image.to_rgb8())
+    };
+
+    let mut webp = Vec::new();
+    image
+        .write_with_encoder(WebPEncoder::new_lossless(&mut webp))
+        .map_err(|_| NewsImageError::InvalidFormat)?;
+    if has_alpha {
+        return Ok(("image/webp", webp));
+    }
+
+    let mut jpeg = Vec::new();
+    image
+        .write_with_encoder(JpegEncoder::new_with_quality(
+            &mut jpeg,
+            NEWS_IMAGE_JPEG_QUALITY,
+        ))
+        .map_err(|_| NewsImageError::InvalidFormat)?;
+    if jpeg.len() < webp.len() {
+        Ok(("image/jpeg", jpeg))
+    } else {
+        Ok(("image/webp", webp))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{GenericImageView, ImageBuffer, Rgb, Rgba};
+
+    fn png_data_uri(image: DynamicImage) -> String {
+        let mut png = Vec::new();
+        image
+            .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+            .unwrap();
+        format!("data:image/png;base64,{}", BASE64.encode(png))
+    }
+
+    /// Noisy image that doesn't compress well
+    fn noise(width: u32, height: u32) -> DynamicImage {
+        DynamicImage::ImageRgb8(ImageBuffer::from_fn(width, height, |x, y| {
+            let v = x.wrapping_mul(2_654_435_761) ^ y.wrapping_mul(40_503);
+            Rgb([v as u8, (v >> 8) as u8, (v >> 16) as u8])
+        }))
+    }
+
+    fn decode(data_uri: &str) -> DynamicImage {
+        let (_, payload) = data_uri.split_once(";base64,").unwrap();
+        image::load_from_memory(&BASE64.decode(payload).unwrap()).unwrap()
+    }
+
+    fn limits(max_size: u32, max_dimension: u32) -> NewsImageLimits {
+        NewsImageLimits {
+            max_size,
+            max_dimension,
+        }
+    }
+
+    #[test]
+    fn test_image_within_limits_is_kept() {
+        let uri = png_data_uri(noise(32, 16));
+        assert_eq!(fit(&uri, limits(512 * 1024, 1600)), Ok(None));
+    }
+
+    #[test]
+    fn test_wide_image_is_scaled_down() {
+        let uri = png_data_uri(DynamicImage::ImageRgba8(ImageBuffer::from_pixel(
+            3200,
+            800,
+            Rgba([10, 20, 30, 128]),
+        )));
+
+        let fitted = fit(&uri, limits(512 * 1024, 1600)).unwrap().unwrap();
+
+        // Transparency is kept, so it's stored as WebP
+        assert!(fitted.starts_with("data:image/webp;base64,"));
+        assert_eq!(decode(&fitted).dimensions(), (1600, 400));
+    }
+
+    #[test]
+    fn test_oversized_image_is_shrunk_to_fit() {
+        let uri = png_data_uri(noise(600, 600));
+        let max_size = 64 * 1024;
+
+        let fitted = fit(&uri, limits(max_size, 0)).unwrap().unwrap();
+
+        let (_, payload) = fitted.split_once(";base64,").unwrap();
+        assert!(BASE64.decode(payload).unwrap().len() <= max_size as usize);
+        assert!(decode(&fitted).width() <= 600);
+    }
+
+    #[test]
+    fn test_image_that_cant_fit_is_rejected() {
+        let uri = png_data_uri(noise(600, 600));
+        assert_eq!(fit(&uri, limits(16, 0)), Err(NewsImageError::TooLarge));
+    }
+
+    #[test]
+    fn test_svg_is_only_size_checked() {
+        let svg = "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"4000\" height=\"4000\"/>";
+        let uri = format!("data:image/svg+xml;base64,{}", BASE64.encode(svg));
+
+        assert_eq!(fit(&uri, limits(1024, 1600)), Ok(None));
+        assert_eq!(fit(&uri, limits(16, 1600)), Err(NewsImageError::TooLarge));
+    }
+
+    #[test]
+    fn test_undecodable_image_is_invalid() {
+        assert_eq!(
+            fit(
+                "data:image/png;base64,iVBORw0KGgo=",
+                limits(512 * 1024, 1600)
+            ),
+            Err(NewsImageError::InvalidFormat)
+        );
+        assert_eq!(
+            fit(
+                "data:image/png;base64,not base64!",
+                limits(512 * 1024, 1600)
+            ),
+            Err(NewsImageError::InvalidFormat)
+        );
+    }
+}
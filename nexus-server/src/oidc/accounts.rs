@@ -0,0 +1,196 @@
+//! Mapping identity provider subjects to Nexus accounts
+//!
+//! A subject already linked to an account logs in as that account, whatever
+//! its username claim says now. An unknown subject gets a new account named
+//! after its username claim, with the permissions of the configured role
+//! account, as long as the name is free. Existing accounts are never taken
+//! over by a matching name: that would let anyone who can pick their own
+//! username at the provider log in as an admin.
+
+use rand::RngExt;
+use rand::distr::Alphanumeric;
+
+use nexus_common::validators;
+
+use super::SsoError;
+use super::claims::IdTokenClaims;
+use crate::db::sql::GUEST_USERNAME;
+use crate::db::users::UserAccount;
+use crate::db::{self, Database, Permissions};
+
+/// Length of the random password set on new single sign-on accounts
+const PROVISIONED_PASSWORD_LENGTH: usize = 32;
+
+/// The username a new account for these claims would get
+///
+/// Email-style values (for a username claim of `email`) use the part before
+/// the `@`. Returns `None` if the claim is missing or isn't a valid username.
+pub(super) fn username_from_claims(claims: &IdTokenClaims, username_claim: &str) -> Option<String> {
+    let value = claims.string(username_claim)?;
+    let username = value.split('@').next().unwrap_or_default();
+    if validators::validate_username(username).is_err()
+        || username.eq_ignore_ascii_case(GUEST_USERNAME)
+    {
+        return None;
+    }
+    Some(username.to_string())
+}
+
+/// Find the account linked to the token's subject, creating one on first login
+pub(super) async fn find_or_create_account(
+    db: &Database,
+    claims: &IdTokenClaims,
+    username_claim: &str,
+    role_account: Option<&str>,
+) -> Result<UserAccount, SsoError> {
+    if let Some(user_id) = db
+        .oidc_identities
+        .get_user_id(&claims.issuer, &claims.subject)
+        .await?
+        && let Some(account) = db.users.get_user_by_id(user_id).await?
+    {
+        return Ok(account);
+    }
+
+    let Some(username) = username_from_claims(claims, username_claim) else {
+        let claimed = claims
+            .string(username_claim)
+            .unwrap_or_default()
+            .to_string();
+        return Err(SsoError::UsernameUnavailable(claimed));
+    };
+    if db.users.username_exists(&username).await? {
+        return Err(SsoError::UsernameUnavailable(username));
+    }
+
+    let permissions = match role_account {
+        Some(role) => match db.users.get_user_by_username(role).await? {
+            Some(role) => db.users.get_user_permissions(role.id).await?,
+            None => {
+                log_warn!("Single sign-on role account '{}' does not exist", role);
+                Permissions::new()
+            }
+        },
+        None => Permissions::new(),
+    };
+
+    // Nobody knows this password; the account logs in through the provider
+    let password: String = rand::rng()
+        .sample_iter(Alphanumeric)
+        .take(PROVISIONED_PASSWORD_LENGTH)
+        .map(char::from)
+        .collect();
+    let hashed_password = db::hash_password(&password, false)
+        .map_err(|e| SsoError::Failed(format!("failed to hash password: {e}")))?;
+
+    let account = db
+        .users
+        .create_user(
+            &username,
+            &hashed_password,
+            false,
+            false,
+            true,
+            &permissions,
+        )
+        .await?;
+    db.oidc_identities
+        .link(&claims.issuer, &claims.subject, account.id)
+        .await?;
+
+    log_info!(
+        "Created single sign-on account '{}' for {} subject {}",
+        account.username,
+        claims.issuer,
+        claims.subject
+    );
+    Ok(account)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Permission;
+    use crate::db::testing::create_test_db;
+    use serde_json::{Value, json};
+
+    fn claims(subject: &str, extra: Value) -> IdTokenClaims {
+        IdTokenClaims {
+            issuer: "https://id.example.com".to_string(),
+            subject: subject.to_string(),
+            claims: extra.as_object().unwrap().clone(),
+        }
+    }
+
+    #[test]
+    fn test_username_from_claims() {
+        let c = claims(
+            "1",
+            json!({"preferred_username": "alice", "email": "alice.smith@example.com"}),
+        );
+        assert_eq!(
+            username_from_claims(&c, "preferred_username"),
+            Some("alice".to_string())
+        );
+        assert_eq!(
+            username_from_claims(&c, "email"),
+            Some("alice.smith".to_string())
+        );
+        assert_eq!(username_from_claims(&c, "name"), None);
+
+        let c = claims("1", json!({"preferred_username": "Guest"}));
+        assert_eq!(username_from_claims(&c, "preferred_username"), None);
+        let c = claims("1", json!({"preferred_username": "has space"}));
+        assert_eq!(username_from_claims(&c, "preferred_username"), None);
+    }
+
+    #[tokio::test]
+    async fn test_provision_and_find() {
+        let db = Database::new(create_test_db().await);
+        let mut role_permissions = Permissions::new();
+        role_permissions.permissions.insert(Permission::ChatSend);
+        role_permissions.permissions.insert(Permission::FileList);
+        db.users
+            .create_user("members", "hash", false, false, false, &role_permissions)
+            .await
+            .unwrap();
+
+        let alice = claims("sub-alice", json!({"preferred_username": "alice"}));
+        let account = find_or_create_account(&db, &alice, "preferred_username", Some("members"))
+            .await
+            .unwrap();
+        assert_eq!(account.username, "alice");
+        assert!(account.enabled);
+        assert!(!account.is_admin);
+        let permissions = db.users.get_user_permissions(account.id).await.unwrap();
+        assert_eq!(permissions.permissions, role_permissions.permissions);
+
+        // The link wins over a changed username claim
+        let renamed = claims("sub-alice", json!({"preferred_username": "alicia"}));
+        let again = find_or_create_account(&db, &renamed, "preferred_username", Some("members"))
+            .await
+            .unwrap();
+        assert_eq!(again.id, account.id);
+    }
+
+    #[tokio::test]
+    async fn test_existing_username_is_not_taken_over() {
+        let db = Database::new(create_test_db().await);
+        db.users
+            .create_user("admin", "hash", true, false, true, &Permissions::new())
+            .await
+            .unwrap();
+
+        let impostor = claims("sub-x", json!({"preferred_username": "Admin"}));
+        assert!(matches!(
+            find_or_create_account(&db, &impostor, "preferred_username", None).await,
+            Err(SsoError::UsernameUnavailable(name)) if name == "Admin"
+        ));
+
+        let nameless = claims("sub-y", json!({}));
+        assert!(matches!(
+            find_or_create_account(&db, &nameless, "preferred_username", None).await,
+            Err(SsoError::UsernameUnavailable(_))
+        ));
+    }
+}
@@ -0,0 +1,190 @@
+//! ID token claim checks
+//!
+//! The ID token comes straight from the provider's token endpoint over a
+//! verified TLS connection, so (as OpenID Connect Core 3.1.3.7 allows) the
+//! TLS server check stands in for the token signature. What still has to be
+//! checked is that the token was issued by the configured provider, for this
+//! server, for this sign-in, and hasn't expired.
+
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use serde_json::{Map, Value};
+
+/// Seconds of clock difference tolerated when checking expiry
+const CLOCK_SKEW_SECS: i64 = 300;
+
+/// The checked claims of an ID token
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct IdTokenClaims {
+    /// Issuer the token came from
+    pub issuer: String,
+    /// Subject identifier (stable per person at the issuer)
+    pub subject: String,
+    /// All claims, for looking up the username claim
+    pub claims: Map<String, Value>,
+}
+
+impl IdTokenClaims {
+    /// A string claim, if present
+    pub fn string(&self, name: &str) -> Option<&str> {
+        self.claims.get(name).and_then(Value::as_str)
+    }
+}
+
+/// What the ID token must match
+pub(super) struct Expected<'a> {
+    pub issuer: &'a str,
+    pub client_id: &'a str,
+    pub nonce: &'a str,
+    /// Current Unix timestamp
+    pub now: i64,
+}
+
+/// Decode an ID token's payload and check its claims
+pub(super) fn check_id_token(
+    token: &str,
+    expected: &Expected<'_>,
+) -> Result<IdTokenClaims, String> {
+    let mut parts = token.split('.');
+    let (Some(_header), Some(payload), Some(_signature), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err("ID token is not a JWT".to_string());
+    };
+
+    let payload = URL_SAFE_NO_PAD
+        .decode(payload.trim_end_matches('='))
+        .map_err(|_| "ID token payload is not base64url")?;
+    let claims: Map<String, Value> =
+        serde_json::from_slice(&payload).map_err(|_| "ID token payload is not a JSON object")?;
+
+    let issuer = claims
+        .get("iss")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    if issuer != expected.issuer {
+        return Err(format!("ID token issuer {issuer:?} does not match"));
+    }
+
+    let audience_ok = match claims.get("aud") {
+        Some(Value::String(aud)) => aud == expected.client_id,
+        Some(Value::Array(auds)) => {
+            auds.iter().any(|aud| aud.as_str() == Some(expected.client_id))
+                // With several audiences the token must name us as its holder
+                && (auds.len() == 1
+                    || claims.get("azp").and_then(Value::as_str) == Some(expected.client_id))
+        }
+        _ => false,
+    };
+    if !audience_ok {
+        return Err("ID token was not issued for this server".to_string());
+    }
+
+    let expires = claims.get("exp").and_then(Value::as_i64).unwrap_or(0);
+    if expires + CLOCK_SKEW_SECS < expected.now {
+        return Err("ID token has expired".to_string());
+    }
+
+    if claims.get("nonce").and_then(Value::as_str) != Some(expected.nonce) {
+        return Err("ID token nonce does not match".to_string());
+    }
+
+    let subject = claims
+        .get("sub")
+        .and_then(Value::as_str)
+        .filter(|sub| !sub.is_empty())
+        .ok_or("ID token has no subject")?
+        .to_string();
+
+    Ok(IdTokenClaims {
+        issuer: issuer.to_string(),
+        subject,
+        claims,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const ISSUER: &str = "https://id.example.com";
+
+    fn token(claims: Value) -> String {
+        let header = URL_SAFE_NO_PAD.encode(br#"{"alg":"RS256"}"#);
+        let payload = URL_SAFE_NO_PAD.encode(claims.to_string());
+        format!("{header}.{payload}.c2lnbmF0dXJl")
+    }
+
+    fn expected(now: i64) -> Expected<'static> {
+        Expected {
+            issuer: ISSUER,
+            client_id: "nexus",
+            nonce: "n-0S6_WzA2Mj",
+            now,
+        }
+    }
+
+    fn valid_claims() -> Value {
+        json!({
+            "iss": ISSUER,
+            "sub": "248289761001",
+            "aud": "nexus",
+            "exp": 1000,
+            "nonce": "n-0S6_WzA2Mj",
+            "preferred_username": "alice",
+        })
+    }
+
+    #[test]
+    fn test_valid_token() {
+        let claims = check_id_token(&token(valid_claims()), &expected(900)).unwrap();
+        assert_eq!(claims.issuer, ISSUER);
+        assert_eq!(claims.subject, "248289761001");
+        assert_eq!(claims.string("preferred_username"), Some("alice"));
+        assert_eq!(claims.string("email"), None);
+
+        // A little clock skew is tolerated
+        assert!(check_id_token(&token(valid_claims()), &expected(1200)).is_ok());
+    }
+
+    #[test]
+    fn test_audience() {
+        let mut claims = valid_claims();
+        claims["aud"] = json!(["nexus"]);
+        assert!(check_id_token(&token(claims.clone()), &expected(900)).is_ok());
+
+        claims["aud"] = json!(["nexus", "other"]);
+        assert!(check_id_token(&token(claims.clone()), &expected(900)).is_err());
+        claims["azp"] = json!("nexus");
+        assert!(check_id_token(&token(claims.clone()), &expected(900)).is_ok());
+
+        claims["aud"] = json!("other");
+        assert!(check_id_token(&token(claims), &expected(900)).is_err());
+    }
+
+    #[test]
+    fn test_rejected_tokens() {
+        for (claim, value) in [
+            ("iss", json!("https://evil.example.com")),
+            ("exp", json!(100)),
+            ("nonce", json!("replayed")),
+            ("sub", json!("")),
+        ] {
+            let mut claims = valid_claims();
+            claims[claim] = value;
+            assert!(
+                check_id_token(&token(claims), &expected(900)).is_err(),
+                "{claim}"
+            );
+        }
+
+        let mut claims = valid_claims();
+        claims.as_object_mut().unwrap().remove("nonce");
+        assert!(check_id_token(&token(claims), &expected(900)).is_err());
+
+        assert!(check_id_token("not-a-jwt", &expected(900)).is_err());
+        assert!(check_id_token("a.!!!.c", &expected(900)).is_err());
+        assert!(check_id_token("a.b.c.d", &expected(900)).is_err());
+    }
+}
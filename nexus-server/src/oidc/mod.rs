@@ -0,0 +1,311 @@
+//! OpenID Connect single sign-on
+//!
+//! With `--oidc-issuer` and `--oidc-client-id`, users can log in through an
+//! external identity provider instead of with a Nexus password:
+//!
+//! 1. The server fetches the provider's discovery document and, once it has
+//!    one, advertises the authorization endpoint and client ID in every
+//!    handshake.
+//! 2. The client opens the browser at the authorization endpoint (authorization
+//!    code flow with PKCE) and receives the code on a loopback redirect.
+//! 3. The client sends the code, its PKCE verifier, the redirect URI and the
+//!    nonce in `Login`. The server redeems the code at the token endpoint
+//!    itself, so the client secret (if any) never leaves the server, and
+//!    checks the ID token it gets back (see [`claims`]).
+//! 4. The issuer and subject are mapped to an account, which is created on
+//!    first login (see [`accounts`]).
+//! 5. Like any login, the response carries a login token for transfer
+//!    connections and reconnects (see [`crate::login_tokens`]).
+
+mod accounts;
+mod claims;
+
+use std::fmt;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use chrono::Utc;
+use serde_json::Value;
+
+use nexus_common::protocol::{OidcLogin, OidcProvider};
+use nexus_common::validators::{MAX_OIDC_CLIENT_ID_LENGTH, MAX_OIDC_ENDPOINT_LENGTH};
+
+use crate::constants::{OIDC_DISCOVERY_REFRESH_SECS, OIDC_DISCOVERY_RETRY_SECS};
+use crate::db::Database;
+use crate::db::users::UserAccount;
+use crate::https::{self, HttpsUrl, percent_encode};
+use claims::{Expected, check_id_token};
+
+/// Single sign-on settings from the command line
+#[derive(Debug, Clone)]
+pub struct OidcConfig {
+    /// Issuer URL of the identity provider
+    pub issuer: String,
+    /// Client ID registered with the provider
+    pub client_id: String,
+    /// Client secret, for providers that treat the server as a confidential client
+    pub client_secret: Option<String>,
+    /// ID token claim that names new accounts
+    pub username_claim: String,
+    /// Account whose permissions new accounts get
+    pub role_account: Option<String>,
+}
+
+impl OidcConfig {
+    /// Check the settings before the server starts
+    pub fn validate(&self) -> Result<(), String> {
+        HttpsUrl::parse(&self.issuer)?;
+        if self.client_id.is_empty() || self.client_id.len() > MAX_OIDC_CLIENT_ID_LENGTH {
+            return Err(format!(
+                "client ID must be 1-{MAX_OIDC_CLIENT_ID_LENGTH} characters"
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Provider endpoints the server uses
+#[derive(Debug, Clone)]
+struct Endpoints {
+    authorization: String,
+    token: HttpsUrl,
+}
+
+/// Why a single sign-on login failed
+#[derive(Debug)]
+pub enum SsoError {
+    /// Single sign-on is off, or the provider hasn't been reached yet
+    Unavailable,
+    /// The provider refused the code, or the ID token failed a check
+    Failed(String),
+    /// A new account can't be created with this username (taken or invalid)
+    UsernameUnavailable(String),
+    /// The account is disabled
+    AccountDisabled(String),
+    /// Database error
+    Database(sqlx::Error),
+}
+
+impl fmt::Display for SsoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SsoError::Unavailable => write!(f, "single sign-on is unavailable"),
+            SsoError::Failed(reason) => write!(f, "{reason}"),
+            SsoError::UsernameUnavailable(name) => write!(f, "username {name:?} is unavailable"),
+            SsoError::AccountDisabled(name) => write!(f, "account {name} is disabled"),
+            SsoError::Database(e) => write!(f, "database error: {e}"),
+        }
+    }
+}
+
+impl From<sqlx::Error> for SsoError {
+    fn from(e: sqlx::Error) -> Self {
+        SsoError::Database(e)
+    }
+}
+
+/// Single sign-on through the configured provider
+///
+/// Cheap to clone; clones share the provider's endpoints. Without
+/// `--oidc-issuer` it's disabled: nothing is advertised and single sign-on
+/// logins are refused.
+#[derive(Clone, Default)]
+pub struct Oidc {
+    provider: Option<Arc<Provider>>,
+}
+
+/// Settings and discovered endpoints of the configured provider
+struct Provider {
+    config: OidcConfig,
+    /// Endpoints from the provider's discovery document, once fetched
+    endpoints: RwLock<Option<Endpoints>>,
+}
+
+impl Oidc {
+    /// Single sign-on through the provider in `config`
+    pub fn new(config: OidcConfig) -> Self {
+        Self {
+            provider: Some(Arc::new(Provider {
+                config,
+                endpoints: RwLock::new(None),
+            })),
+        }
+    }
+
+    /// Endpoints from the discovery document, if single sign-on is on and
+    /// the provider has been reached
+    fn endpoints(&self) -> Option<(&OidcConfig, Endpoints)> {
+        let provider = self.provider.as_ref()?;
+        let endpoints = provider
+            .endpoints
+            .read()
+            .expect("oidc endpoints lock poisoned")
+            .clone()?;
+        Some((&provider.config, endpoints))
+    }
+
+    /// The provider to advertise in handshakes, once its endpoints are known
+    pub fn provider(&self) -> Option<OidcProvider> {
+        self.endpoints().map(|(config, endpoints)| OidcProvider {
+            authorization_endpoint: endpoints.authorization,
+            client_id: config.client_id.clone(),
+        })
+    }
+
+    /// Keep the provider's endpoints up to date (runs forever)
+    ///
+    /// Retries every minute until the discovery document is fetched, then
+    /// refreshes it every few hours. A failed refresh keeps the old endpoints.
+    pub async fn run_discovery(&self, debug: bool) {
+        let Some(provider) = &self.provider else {
+            std::future::pending::<()>().await;
+            return;
+        };
+        loop {
+            let wait = match discover(&provider.config.issuer).await {
+                Ok(endpoints) => {
+                    if debug {
+                        log_debug!(
+                            "Single sign-on endpoints: {}, {}{}",
+                            endpoints.authorization,
+                            endpoints.token.host,
+                            endpoints.token.path
+                        );
+                    }
+                    *provider
+                        .endpoints
+                        .write()
+                        .expect("oidc endpoints lock poisoned") = Some(endpoints);
+                    OIDC_DISCOVERY_REFRESH_SECS
+                }
+                Err(e) => {
+                    log_warn!("Failed to fetch single sign-on configuration: {}", e);
+                    if self.provider().is_some() {
+                        OIDC_DISCOVERY_REFRESH_SECS
+                    } else {
+                        OIDC_DISCOVERY_RETRY_SECS
+                    }
+                }
+            };
+            tokio::time::sleep(Duration::from_secs(wait)).await;
+        }
+    }
+
+    /// Log in with the result of a browser sign-in
+    ///
+    /// Redeems the code, checks the ID token and returns the linked account,
+    /// creating it on first login.
+    pub async fn login(&self, db: &Database, login: &OidcLogin) -> Result<UserAccount, SsoError> {
+        let (config, endpoints) = self.endpoints().ok_or(SsoError::Unavailable)?;
+
+        let id_token = redeem_code(config, &endpoints.token, login)
+            .await
+            .map_err(SsoError::Failed)?;
+        let claims = check_id_token(
+            &id_token,
+            &Expected {
+                issuer: &config.issuer,
+                client_id: &config.client_id,
+                nonce: &login.nonce,
+                now: Utc::now().timestamp(),
+            },
+        )
+        .map_err(SsoError::Failed)?;
+
+        let account = accounts::find_or_create_account(
+            db,
+            &claims,
+            &config.username_claim,
+            config.role_account.as_deref(),
+        )
+        .await?;
+        if !account.enabled {
+            return Err(SsoError::AccountDisabled(account.username));
+        }
+        Ok(account)
+    }
+}
+
+/// Fetch the discovery document for an issuer
+async fn discover(issuer: &str) -> Result<Endpoints, String> {
+    let url = HttpsUrl::parse(&format!(
+        "{}/.well-known/openid-configuration",
+        issuer.trim_end_matches('/')
+    ))?;
+    let response = https::get(&url).await?;
+    if response.status != 200 {
+        return Err(format!("discovery returned HTTP {}", response.status));
+    }
+    let document: Value = serde_json::from_slice(&response.body)
+        .map_err(|_| "discovery document is not JSON".to_string())?;
+
+    // The document must be for exactly the configured issuer
+    if document.get("issuer").and_then(Value::as_str) != Some(issuer) {
+        return Err("discovery document is for a different issuer".to_string());
+    }
+
+    let authorization = document
+        .get("authorization_endpoint")
+        .and_then(Value::as_str)
+        .filter(|endpoint| endpoint.len() <= MAX_OIDC_ENDPOINT_LENGTH)
+        .filter(|endpoint| HttpsUrl::parse(endpoint).is_ok())
+        .ok_or("discovery document has no usable authorization endpoint")?;
+    let token = document
+        .get("token_endpoint")
+        .and_then(Value::as_str)
+        .ok_or("discovery document has no token endpoint")?;
+    let token = HttpsUrl::parse(token)?;
+
+    Ok(Endpoints {
+        authorization: authorization.to_string(),
+        token,
+    })
+}
+
+/// Exchange an authorization code for an ID token at the token endpoint
+async fn redeem_code(
+    config: &OidcConfig,
+    token_url: &HttpsUrl,
+    login: &OidcLogin,
+) -> Result<String, String> {
+    let fields = [
+        ("grant_type", "authorization_code"),
+        ("code", login.code.as_str()),
+        ("redirect_uri", login.redirect_uri.as_str()),
+        ("code_verifier", login.code_verifier.as_str()),
+        ("client_id", config.client_id.as_str()),
+    ];
+    // client_secret_basic: both halves are form-encoded before base64
+    let authorization = config.client_secret.as_ref().map(|secret| {
+        let credentials = format!(
+            "{}:{}",
+            percent_encode(&config.client_id),
+            percent_encode(secret)
+        );
+        format!("Basic {}", BASE64.encode(credentials))
+    });
+
+    let response = https::post_form(token_url, &fields, authorization.as_deref()).await?;
+    let body: Value = serde_json::from_slice(&response.body)
+        .map_err(|_| format!("token endpoint returned HTTP {}", response.status))?;
+
+    if response.status != 200 {
+        let error = body.get("error").and_then(Value::as_str).unwrap_or("error");
+        let description = body
+            .get("error_description")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        return Err(
+            format!("token endpoint refused the code: {error} {description}")
+                .trim_end()
+                .to_string(),
+        );
+    }
+
+    body.get("id_token")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| "token response has no ID token".to_string())
+}
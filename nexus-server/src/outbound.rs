@@ -0,0 +1,566 @@
+//! Outbound message queues
+//!
+//! Broadcasts and other messages from outside a connection's own handler
+//! (chat, presence, voice, door output, ...) are queued for the connection's
+//! task to write. Each queue holds at most [`OUTBOUND_QUEUE_CAPACITY`]
+//! messages, so a client that stops reading can't make the server buffer
+//! broadcasts without limit. When a queue is full:
+//!
+//! - Chat messages, relayed voice and streamed log lines are droppable: the
+//!   oldest droppable message makes room for the new one. A slow client
+//!   misses some chat rather than holding up the server.
+//! - Anything else (responses, presence and state updates) is never dropped.
+//!   If there's nothing droppable left to make room, the client is too far
+//!   behind to catch up and the queue is closed, which disconnects it.
+//!
+//! Senders that can wait, like door output, use [`OutboundSender::send_wait`]
+//! to wait for room instead, so the backpressure reaches the door program.
+//!
+//! Chat broadcasts are encoded once into a [`SharedFrame`] and every
+//! recipient's queue holds the same bytes, so a message to a large channel
+//! isn't cloned and serialized per member.
+//!
+//! Dropped messages and closed queues are counted in the server's
+//! [`OutboundMetrics`] and logged with the statistics flush.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::io;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use tokio::sync::Notify;
+
+use nexus_common::framing::MessageId;
+use nexus_common::io::encode_server_message;
+use nexus_common::protocol::ServerMessage;
+
+use crate::constants::OUTBOUND_QUEUE_CAPACITY;
+
+/// A queued message, with the ID to send it under (a new one if None)
+pub type OutboundMessage = (ServerMessage, Option<MessageId>);
+
+/// A queued message or shared frame
+#[derive(Debug)]
+pub enum Outgoing {
+    /// A message, encoded when it is written
+    Message(ServerMessage, Option<MessageId>),
+    /// A broadcast encoded once for every recipient
+    Frame(SharedFrame),
+}
+
+impl Outgoing {
+    /// The message being sent
+    pub fn message(&self) -> &ServerMessage {
+        match self {
+            Outgoing::Message(message, _) => message,
+            Outgoing::Frame(frame) => &frame.message,
+        }
+    }
+
+    /// The message and its ID (None for shared frames)
+    #[cfg(test)]
+    pub fn into_message(self) -> OutboundMessage {
+        match self {
+            Outgoing::Message(message, message_id) => (message, message_id),
+            Outgoing::Frame(frame) => ((*frame.message).clone(), None),
+        }
+    }
+}
+
+/// A broadcast encoded once and shared by every recipient's queue
+#[derive(Debug, Clone)]
+pub struct SharedFrame {
+    message: Arc<ServerMessage>,
+    bytes: Arc<[u8]>,
+}
+
+impl SharedFrame {
+    /// Encode a message under a new message ID (every recipient sees the same ID)
+    pub fn new(message: ServerMessage) -> io::Result<Self> {
+        let bytes = encode_server_message(&message, MessageId::new())?;
+        Ok(Self {
+            message: Arc::new(message),
+            bytes,
+        })
+    }
+
+    /// The encoded frame
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+/// Counters shared by every connection's queue, reported with the statistics flush
+#[derive(Debug, Default)]
+pub struct OutboundMetrics {
+    /// Droppable messages dropped since the last report
+    dropped: AtomicU64,
+    /// Queues closed for being full since the last report
+    overflowed: AtomicU64,
+    /// Largest queue length seen since the last report
+    peak_length: AtomicU64,
+}
+
+impl OutboundMetrics {
+    /// Create zeroed counters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Log what the queues dropped since the last report, if anything
+    pub fn log(&self) {
+        let dropped = self.dropped.swap(0, Ordering::Relaxed);
+        let overflowed = self.overflowed.swap(0, Ordering::Relaxed);
+        let peak = self.peak_length.swap(0, Ordering::Relaxed);
+        if dropped > 0 || overflowed > 0 {
+            log_info!(
+                "Outbound queues: {} messages dropped, {} clients disconnected for falling behind (longest queue {})",
+                dropped,
+                overflowed,
+                peak
+            );
+        }
+    }
+
+    fn record_length(&self, length: usize) {
+        self.peak_length.fetch_max(length as u64, Ordering::Relaxed);
+    }
+}
+
+/// Create a queue with the default capacity, counting into `metrics`
+pub fn channel(metrics: Arc<OutboundMetrics>) -> (OutboundSender, OutboundReceiver) {
+    channel_with_capacity(OUTBOUND_QUEUE_CAPACITY, metrics)
+}
+
+/// Create a queue holding at most `capacity` messages, counting into `metrics`
+pub fn channel_with_capacity(
+    capacity: usize,
+    metrics: Arc<OutboundMetrics>,
+) -> (OutboundSender, OutboundReceiver) {
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            queue: VecDeque::new(),
+            senders: 1,
+            closed: false,
+            overflowed: false,
+        }),
+        capacity: capacity.max(1),
+        metrics,
+        message_ready: Notify::new(),
+        space_ready: Notify::new(),
+    });
+    (
+        OutboundSender {
+            shared: shared.clone(),
+        },
+        OutboundReceiver { shared },
+    )
+}
+
+/// Whether a message may be dropped when its queue is full
+pub fn is_droppable(message: &ServerMessage) -> bool {
+    matches!(
+        message,
+        ServerMessage::ChatMessage { .. }
+            | ServerMessage::VoiceRelay { .. }
+            | ServerMessage::LogLine { .. }
+    )
+}
+
+/// Queue state shared by the senders and the receiver
+struct Shared {
+    state: Mutex<State>,
+    capacity: usize,
+    metrics: Arc<OutboundMetrics>,
+    /// Wakes the receiver when a message is queued or the queue closes
+    message_ready: Notify,
+    /// Wakes senders waiting in `send_wait` when a message is taken
+    space_ready: Notify,
+}
+
+struct State {
+    queue: VecDeque<Outgoing>,
+    /// Number of live senders (the queue closes when the last one drops)
+    senders: usize,
+    /// The receiver is gone or the queue overflowed
+    closed: bool,
+    /// The queue was closed for being full
+    overflowed: bool,
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().expect("outbound queue lock poisoned")
+    }
+}
+
+/// The queue was closed (the connection is gone or fell too far behind)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SendError;
+
+impl fmt::Display for SendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "outbound queue closed")
+    }
+}
+
+impl std::error::Error for SendError {}
+
+/// Why `try_recv` returned no message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TryRecvError {
+    /// Nothing is queued right now
+    Empty,
+    /// Nothing is queued and nothing more will be
+    Closed,
+}
+
+/// Sending half of an outbound queue (cheap to clone)
+pub struct OutboundSender {
+    shared: Arc<Shared>,
+}
+
+impl OutboundSender {
+    /// Queue a message without waiting
+    ///
+    /// A full queue drops its oldest droppable message to make room (or this
+    /// one, if it is droppable and nothing queued is). If nothing can be
+    /// dropped, the queue is closed and the connection disconnects.
+    pub fn send(&self, (message, message_id): OutboundMessage) -> Result<(), SendError> {
+        self.push(Outgoing::Message(message, message_id))
+    }
+
+    /// Queue a shared broadcast frame without waiting (see `send`)
+    pub fn send_frame(&self, frame: SharedFrame) -> Result<(), SendError> {
+        self.push(Outgoing::Frame(frame))
+    }
+
+    fn push(&self, outgoing: Outgoing) -> Result<(), SendError> {
+        let mut state = self.shared.lock();
+        if state.closed {
+            return Err(SendError);
+        }
+
+        if state.queue.len() >= self.shared.capacity {
+            if let Some(index) = state
+                .queue
+                .iter()
+                .position(|queued| is_droppable(queued.message()))
+            {
+                state.queue.remove(index);
+                self.shared.metrics.dropped.fetch_add(1, Ordering::Relaxed);
+            } else if is_droppable(outgoing.message()) {
+                self.shared.metrics.dropped.fetch_add(1, Ordering::Relaxed);
+                return Ok(());
+            } else {
+                state.closed = true;
+                state.overflowed = true;
+                state.queue.clear();
+                self.shared
+                    .metrics
+                    .overflowed
+                    .fetch_add(1, Ordering::Relaxed);
+                drop(state);
+                self.shared.message_ready.notify_one();
+                self.shared.space_ready.notify_waiters();
+                return Err(SendError);
+            }
+        }
+
+        state.queue.push_back(outgoing);
+        self.shared.metrics.record_length(state.queue.len());
+        drop(state);
+        self.shared.message_ready.notify_one();
+        Ok(())
+    }
+
+    /// Queue a message, waiting for room if the queue is full
+    pub async fn send_wait(&self, (message, message_id): OutboundMessage) -> Result<(), SendError> {
+        loop {
+            let space = self.shared.space_ready.notified();
+            {
+                let mut state = self.shared.lock();
+                if state.closed {
+                    return Err(SendError);
+                }
+                if state.queue.len() < self.shared.capacity {
+                    state
+                        .queue
+                        .push_back(Outgoing::Message(message, message_id));
+                    self.shared.metrics.record_length(state.queue.len());
+                    drop(state);
+                    self.shared.message_ready.notify_one();
+                    return Ok(());
+                }
+            }
+            space.await;
+        }
+    }
+}
+
+impl Clone for OutboundSender {
+    fn clone(&self) -> Self {
+        self.shared.lock().senders += 1;
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl Drop for OutboundSender {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.senders -= 1;
+        if state.senders == 0 {
+            drop(state);
+            self.shared.message_ready.notify_one();
+        }
+    }
+}
+
+impl fmt::Debug for OutboundSender {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OutboundSender").finish_non_exhaustive()
+    }
+}
+
+/// Receiving half of an outbound queue, owned by the connection task
+pub struct OutboundReceiver {
+    shared: Arc<Shared>,
+}
+
+impl OutboundReceiver {
+    /// Take the next message or frame, waiting for one
+    ///
+    /// Returns None once every sender is gone and the queue is empty, or as
+    /// soon as the queue overflows. Cancel safe.
+    pub async fn recv_outgoing(&mut self) -> Option<Outgoing> {
+        loop {
+            let ready = self.shared.message_ready.notified();
+            match self.try_recv_outgoing() {
+                Ok(message) => return Some(message),
+                Err(TryRecvError::Closed) => return None,
+                Err(TryRecvError::Empty) => ready.await,
+            }
+        }
+    }
+
+    /// Take the next message or frame if one is queued
+    pub fn try_recv_outgoing(&mut self) -> Result<Outgoing, TryRecvError> {
+        let mut state = self.shared.lock();
+        if state.overflowed {
+            return Err(TryRecvError::Closed);
+        }
+        match state.queue.pop_front() {
+            Some(message) => {
+                drop(state);
+                self.shared.space_ready.notify_one();
+                Ok(message)
+            }
+            None if state.closed || state.senders == 0 => Err(TryRecvError::Closed),
+            None => Err(TryRecvError::Empty),
+        }
+    }
+
+    /// Take the next message, waiting for one (frames are decoded)
+    #[cfg(test)]
+    pub async fn recv(&mut self) -> Option<OutboundMessage> {
+        self.recv_outgoing().await.map(Outgoing::into_message)
+    }
+
+    /// Take the next message if one is queued (frames are decoded)
+    #[cfg(test)]
+    pub fn try_recv(&mut self) -> Result<OutboundMessage, TryRecvError> {
+        self.try_recv_outgoing().map(Outgoing::into_message)
+    }
+
+    /// Whether the queue was closed for being full
+    pub fn overflowed(&self) -> bool {
+        self.shared.lock().overflowed
+    }
+}
+
+impl Drop for OutboundReceiver {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.closed = true;
+        state.queue.clear();
+        drop(state);
+        self.shared.space_ready.notify_waiters();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chat(message: &str) -> OutboundMessage {
+        (
+            ServerMessage::ChatMessage {
+                session_id: 1,
+                nickname: "alice".to_string(),
+                is_admin: false,
+                is_shared: false,
+                message: message.to_string(),
+                action: Default::default(),
+                channel: "#nexus".to_string(),
+                timestamp: 0,
+            },
+            None,
+        )
+    }
+
+    fn response() -> OutboundMessage {
+        (
+            ServerMessage::UserKickResponse {
+                success: true,
+                error: None,
+                nickname: None,
+            },
+            None,
+        )
+    }
+
+    fn chat_text(message: OutboundMessage) -> String {
+        match message.0 {
+            ServerMessage::ChatMessage { message, .. } => message,
+            other => panic!("Expected ChatMessage, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_messages_in_order() {
+        let (tx, mut rx) = channel_with_capacity(4, Arc::default());
+        tx.send(chat("one")).unwrap();
+        tx.send(chat("two")).unwrap();
+        assert_eq!(chat_text(rx.recv().await.unwrap()), "one");
+        assert_eq!(chat_text(rx.recv().await.unwrap()), "two");
+        assert_eq!(rx.try_recv().unwrap_err(), TryRecvError::Empty);
+    }
+
+    #[test]
+    fn test_full_queue_drops_oldest_chat() {
+        let (tx, mut rx) = channel_with_capacity(3, Arc::default());
+        tx.send(chat("one")).unwrap();
+        tx.send(response()).unwrap();
+        tx.send(chat("two")).unwrap();
+        tx.send(chat("three")).unwrap();
+
+        assert!(matches!(
+            rx.try_recv().unwrap().0,
+            ServerMessage::UserKickResponse { .. }
+        ));
+        assert_eq!(chat_text(rx.try_recv().unwrap()), "two");
+        assert_eq!(chat_text(rx.try_recv().unwrap()), "three");
+    }
+
+    #[test]
+    fn test_full_queue_of_responses_drops_new_chat() {
+        let (tx, mut rx) = channel_with_capacity(2, Arc::default());
+        tx.send(response()).unwrap();
+        tx.send(response()).unwrap();
+        tx.send(chat("lost")).unwrap();
+
+        assert!(rx.try_recv().is_ok());
+        assert!(rx.try_recv().is_ok());
+        assert_eq!(rx.try_recv().unwrap_err(), TryRecvError::Empty);
+    }
+
+    #[test]
+    fn test_shared_frame() {
+        let (first, mut first_rx) = channel(Arc::default());
+        let (second, mut second_rx) = channel(Arc::default());
+        let frame = SharedFrame::new(chat("hello").0).unwrap();
+        first.send_frame(frame.clone()).unwrap();
+        second.send_frame(frame.clone()).unwrap();
+
+        for rx in [&mut first_rx, &mut second_rx] {
+            match rx.try_recv_outgoing().unwrap() {
+                Outgoing::Frame(queued) => assert_eq!(queued.bytes(), frame.bytes()),
+                other => panic!("Expected a shared frame, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_full_queue_drops_oldest_shared_chat() {
+        let (tx, mut rx) = channel_with_capacity(2, Arc::default());
+        tx.send_frame(SharedFrame::new(chat("one").0).unwrap())
+            .unwrap();
+        tx.send(response()).unwrap();
+        tx.send(response()).unwrap();
+
+        assert!(matches!(
+            rx.try_recv().unwrap().0,
+            ServerMessage::UserKickResponse { .. }
+        ));
+    }
+
+    #[test]
+    fn test_overflow_closes_queue() {
+        let (tx, mut rx) = channel_with_capacity(2, Arc::default());
+        tx.send(response()).unwrap();
+        tx.send(response()).unwrap();
+        assert!(tx.send(response()).is_err());
+
+        assert!(rx.overflowed());
+        assert_eq!(rx.try_recv().unwrap_err(), TryRecvError::Closed);
+        assert!(tx.send(chat("late")).is_err());
+    }
+
+    #[test]
+    fn test_metrics_count_drops_and_overflows() {
+        let metrics = Arc::new(OutboundMetrics::new());
+        let (tx, _rx) = channel_with_capacity(2, metrics.clone());
+        tx.send(chat("one")).unwrap();
+        tx.send(response()).unwrap();
+        tx.send(response()).unwrap();
+        assert!(tx.send(response()).is_err());
+
+        assert_eq!(metrics.dropped.load(Ordering::Relaxed), 1);
+        assert_eq!(metrics.overflowed.load(Ordering::Relaxed), 1);
+        assert_eq!(metrics.peak_length.load(Ordering::Relaxed), 2);
+
+        metrics.log();
+        assert_eq!(metrics.dropped.load(Ordering::Relaxed), 0);
+        assert_eq!(metrics.overflowed.load(Ordering::Relaxed), 0);
+    }
+
+    #[tokio::test]
+    async fn test_closes_when_senders_drop() {
+        let (tx, mut rx) = channel(Arc::default());
+        let other = tx.clone();
+        tx.send(response()).unwrap();
+        drop(tx);
+        drop(other);
+
+        assert!(rx.recv().await.is_some());
+        assert!(rx.recv().await.is_none());
+        assert!(!rx.overflowed());
+    }
+
+    #[test]
+    fn test_send_fails_when_receiver_drops() {
+        let (tx, rx) = channel(Arc::default());
+        drop(rx);
+        assert!(tx.send(response()).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_send_wait_waits_for_room() {
+        let (tx, mut rx) = channel_with_capacity(1, Arc::default());
+        tx.send(response()).unwrap();
+
+        let waiting = tokio::spawn({
+            let tx = tx.clone();
+            async move { tx.send_wait(response()).await }
+        });
+        tokio::task::yield_now().await;
+        assert!(!waiting.is_finished());
+
+        assert!(rx.recv().await.is_some());
+        waiting.await.unwrap().unwrap();
+        assert!(rx.recv().await.is_some());
+    }
+}
@@ -0,0 +1,370 @@
+//! Per-connection protocol limits
+//!
+//! Defense in depth for the BBS port, on top of the protocol's own payload
+//! limit for each message type:
+//!
+//! - `--max-frame-size TYPE=BYTES` lowers the largest payload a client may
+//!   send for a message type. An oversized frame is rejected before its
+//!   payload is read, and the connection is closed.
+//! - `--max-messages-per-second` caps how many messages a connection may send
+//!   each second.
+//! - `--max-bytes-per-minute` caps how many bytes a connection may send each
+//!   minute.
+//!
+//! A connection over a rate limit is throttled (the server stops reading from
+//! it until the window ends) or, with `--flood-action disconnect`, closed.
+//! Oversized frames and the first rate limit each connection goes over are
+//! recorded in the audit log.
+//!
+//! The transfer port isn't covered: it reads one request per connection, and
+//! file data is streamed to disk rather than held in memory.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::io;
+use std::pin::Pin;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use tokio::io::{AsyncRead, ReadBuf};
+use tokio::time::Instant;
+
+use nexus_common::framing::is_known_message_type;
+
+use crate::constants::{DEFAULT_MAX_BYTES_PER_MINUTE, DEFAULT_MAX_MESSAGES_PER_SECOND};
+use crate::util::parse_bytes;
+
+/// Length of the message rate window
+const MESSAGE_WINDOW: Duration = Duration::from_secs(1);
+
+/// Length of the byte rate window
+const BYTE_WINDOW: Duration = Duration::from_secs(60);
+
+/// A tighter payload limit for one message type (`--max-frame-size`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameSizeLimit {
+    /// Message type name, e.g. `ChatSend`
+    pub message_type: String,
+    /// Largest payload accepted, in bytes
+    pub max: u64,
+}
+
+impl FromStr for FrameSizeLimit {
+    type Err = String;
+
+    /// Parse `TYPE=BYTES`, where BYTES may have a K, M, G or T suffix
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (message_type, max) = s
+            .split_once('=')
+            .ok_or_else(|| format!("expected TYPE=BYTES, got '{}'", s.trim()))?;
+        let message_type = message_type.trim();
+        if !is_known_message_type(message_type) {
+            return Err(format!("unknown message type '{message_type}'"));
+        }
+        let max = parse_bytes(max.trim())
+            .filter(|&max| max > 0)
+            .ok_or_else(|| format!("invalid size '{}' for {message_type}", max.trim()))?;
+
+        Ok(FrameSizeLimit {
+            message_type: message_type.to_string(),
+            max,
+        })
+    }
+}
+
+/// What happens to a connection over a rate limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum FloodAction {
+    /// Stop reading from the connection until the window ends
+    Throttle,
+    /// Close the connection
+    Disconnect,
+}
+
+impl FloodAction {
+    /// Action name as given on the command line
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FloodAction::Throttle => "throttle",
+            FloodAction::Disconnect => "disconnect",
+        }
+    }
+}
+
+/// Protocol limits from the command line
+#[derive(Debug, Clone)]
+pub struct LimitsConfig {
+    /// Tighter payload limits by message type
+    pub frame_sizes: Vec<FrameSizeLimit>,
+    /// Messages a connection may send per second (0 means unlimited)
+    pub messages_per_second: u32,
+    /// Bytes a connection may send per minute (0 means unlimited)
+    pub bytes_per_minute: u64,
+    /// What happens to a connection over a rate limit
+    pub flood_action: FloodAction,
+}
+
+/// Protocol limits every BBS connection is held to
+///
+/// Cheap to clone; clones share the payload limit table.
+#[derive(Debug, Clone)]
+pub struct ProtocolLimits {
+    /// Payload limits by message type, shared by every connection's reader
+    frame_sizes: Arc<HashMap<String, u64>>,
+    messages_per_second: u32,
+    bytes_per_minute: u64,
+    flood_action: FloodAction,
+}
+
+impl Default for ProtocolLimits {
+    fn default() -> Self {
+        Self::new(LimitsConfig {
+            frame_sizes: Vec::new(),
+            messages_per_second: DEFAULT_MAX_MESSAGES_PER_SECOND,
+            bytes_per_minute: DEFAULT_MAX_BYTES_PER_MINUTE,
+            flood_action: FloodAction::Throttle,
+        })
+    }
+}
+
+impl ProtocolLimits {
+    /// Create the limits from the command line options
+    pub fn new(config: LimitsConfig) -> Self {
+        let frame_sizes = config
+            .frame_sizes
+            .iter()
+            .map(|limit| (limit.message_type.clone(), limit.max))
+            .collect();
+        Self {
+            frame_sizes: Arc::new(frame_sizes),
+            messages_per_second: config.messages_per_second,
+            bytes_per_minute: config.bytes_per_minute,
+            flood_action: config.flood_action,
+        }
+    }
+
+    /// Payload limits for connection readers, if any were configured
+    pub fn frame_sizes(&self) -> Option<Arc<HashMap<String, u64>>> {
+        (!self.frame_sizes.is_empty()).then(|| Arc::clone(&self.frame_sizes))
+    }
+
+    /// What happens to a connection over a rate limit
+    pub fn flood_action(&self) -> FloodAction {
+        self.flood_action
+    }
+
+    /// A meter for a new connection, or None if both rate limits are off
+    pub fn rate_meter(&self) -> Option<RateMeter> {
+        (self.messages_per_second > 0 || self.bytes_per_minute > 0).then(|| {
+            RateMeter::new(
+                self.messages_per_second,
+                self.bytes_per_minute,
+                Instant::now(),
+            )
+        })
+    }
+}
+
+/// Parse a `--max-bytes-per-minute` value (K, M, G or T suffix allowed)
+pub fn parse_byte_count(s: &str) -> Result<u64, String> {
+    parse_bytes(s.trim()).ok_or_else(|| format!("invalid byte count '{}'", s.trim()))
+}
+
+/// A rate limit a connection went over
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimit {
+    /// Messages per second
+    Messages(u32),
+    /// Bytes per minute
+    Bytes(u64),
+}
+
+impl fmt::Display for RateLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RateLimit::Messages(max) => write!(f, "more than {max} messages per second"),
+            RateLimit::Bytes(max) => write!(f, "more than {max} bytes per minute"),
+        }
+    }
+}
+
+/// Counts what one connection sends against the rate limits
+#[derive(Debug)]
+pub struct RateMeter {
+    messages_per_second: u32,
+    bytes_per_minute: u64,
+    /// Start of the current message window
+    message_window: Instant,
+    /// Messages received in the current message window
+    messages: u32,
+    /// Start of the current byte window
+    byte_window: Instant,
+    /// Connection's byte count when the current byte window started
+    bytes_at_window: u64,
+}
+
+impl RateMeter {
+    /// Create a meter with the given limits (0 means unlimited)
+    pub fn new(messages_per_second: u32, bytes_per_minute: u64, now: Instant) -> Self {
+        Self {
+            messages_per_second,
+            bytes_per_minute,
+            message_window: now,
+            messages: 0,
+            byte_window: now,
+            bytes_at_window: 0,
+        }
+    }
+
+    /// Count a message, given everything the connection has sent so far
+    ///
+    /// Returns the limit the connection went over and how long until that
+    /// limit's window ends, if it went over one.
+    pub fn record(&mut self, now: Instant, total_bytes: u64) -> Option<(RateLimit, Duration)> {
+        if now.duration_since(self.message_window) >= MESSAGE_WINDOW {
+            self.message_window = now;
+            self.messages = 0;
+        }
+        if now.duration_since(self.byte_window) >= BYTE_WINDOW {
+            self.byte_window = now;
+            self.bytes_at_window = total_bytes;
+        }
+        self.messages = self.messages.saturating_add(1);
+
+        if self.bytes_per_minute > 0
+            && total_bytes.saturating_sub(self.bytes_at_window) > self.bytes_per_minute
+        {
+            let wait = BYTE_WINDOW.saturating_sub(now.duration_since(self.byte_window));
+            return Some((RateLimit::Bytes(self.bytes_per_minute), wait));
+        }
+        if self.messages_per_second > 0 && self.messages > self.messages_per_second {
+            let wait = MESSAGE_WINDOW.saturating_sub(now.duration_since(self.message_window));
+            return Some((RateLimit::Messages(self.messages_per_second), wait));
+        }
+        None
+    }
+}
+
+/// Reader that counts the bytes read through it
+pub struct CountingReader<R> {
+    inner: R,
+    count: Arc<AtomicU64>,
+}
+
+impl<R> CountingReader<R> {
+    /// Wrap a reader, adding every byte read to `count`
+    pub fn new(inner: R, count: Arc<AtomicU64>) -> Self {
+        Self { inner, count }
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for CountingReader<R> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let before = buf.filled().len();
+        let result = Pin::new(&mut self.inner).poll_read(cx, buf);
+        if let Poll::Ready(Ok(())) = result {
+            let read = (buf.filled().len() - before) as u64;
+            self.count.fetch_add(read, Ordering::Relaxed);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::AsyncReadExt;
+
+    #[test]
+    fn test_parse_frame_size_limit() {
+        assert_eq!(
+            "ChatSend=2K".parse::<FrameSizeLimit>(),
+            Ok(FrameSizeLimit {
+                message_type: "ChatSend".to_string(),
+                max: 2048,
+            })
+        );
+        assert!("ChatSend".parse::<FrameSizeLimit>().is_err());
+        assert!("Nonsense=100".parse::<FrameSizeLimit>().is_err());
+        assert!("ChatSend=0".parse::<FrameSizeLimit>().is_err());
+        assert!("ChatSend=lots".parse::<FrameSizeLimit>().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_protocol_limits_from_config() {
+        let limits = ProtocolLimits::new(LimitsConfig {
+            frame_sizes: vec!["ChatSend=2K".parse().unwrap()],
+            messages_per_second: 0,
+            bytes_per_minute: 0,
+            flood_action: FloodAction::Disconnect,
+        });
+        assert_eq!(limits.frame_sizes().unwrap().get("ChatSend"), Some(&2048));
+        assert_eq!(limits.flood_action(), FloodAction::Disconnect);
+        assert!(limits.rate_meter().is_none());
+
+        let defaults = ProtocolLimits::default();
+        assert!(defaults.frame_sizes().is_none());
+        assert_eq!(defaults.flood_action(), FloodAction::Throttle);
+        assert!(defaults.rate_meter().is_some());
+    }
+
+    #[test]
+    fn test_message_rate() {
+        let start = Instant::now();
+        let mut meter = RateMeter::new(2, 0, start);
+        assert_eq!(meter.record(start, 0), None);
+        assert_eq!(meter.record(start, 0), None);
+
+        let now = start + Duration::from_millis(400);
+        assert_eq!(
+            meter.record(now, 0),
+            Some((RateLimit::Messages(2), Duration::from_millis(600)))
+        );
+
+        // A new window starts over
+        let now = start + MESSAGE_WINDOW;
+        assert_eq!(meter.record(now, 0), None);
+    }
+
+    #[test]
+    fn test_byte_rate() {
+        let start = Instant::now();
+        let mut meter = RateMeter::new(0, 1000, start);
+        assert_eq!(meter.record(start, 600), None);
+        assert_eq!(meter.record(start, 1000), None);
+
+        let now = start + Duration::from_secs(20);
+        assert_eq!(
+            meter.record(now, 1001),
+            Some((RateLimit::Bytes(1000), Duration::from_secs(40)))
+        );
+
+        // The next window counts from where the connection was when it started
+        let now = start + BYTE_WINDOW;
+        assert_eq!(meter.record(now, 1500), None);
+        assert_eq!(
+            meter.record(now, 2600),
+            Some((RateLimit::Bytes(1000), BYTE_WINDOW))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_counting_reader() {
+        let count = Arc::new(AtomicU64::new(0));
+        let mut reader = CountingReader::new(&b"hello world"[..], count.clone());
+        let mut buf = [0u8; 5];
+        reader.read_exact(&mut buf).await.unwrap();
+        assert_eq!(count.load(Ordering::Relaxed), 5);
+
+        let mut rest = Vec::new();
+        reader.read_to_end(&mut rest).await.unwrap();
+        assert_eq!(count.load(Ordering::Relaxed), 11);
+    }
+}
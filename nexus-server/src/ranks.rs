@@ -0,0 +1,193 @@
+//! Operator-defined ranks
+//!
+//! Ranks are titles earned from an account's lifetime activity counters, in
+//! the spirit of the "Elite" and "Co-Sysop" levels of classic BBSes. Each
+//! `--rank` gives a name and the counters needed to earn it:
+//!
+//! ```text
+//! --rank Newbie --rank "Regular:logins=10" --rank "Elite:uploads=50,downloaded=10G"
+//! ```
+//!
+//! An account holds the last listed rank whose thresholds it meets, so ranks
+//! are listed from lowest to highest. A rank without thresholds applies to
+//! everyone. The counters are `logins`, `messages`, `uploads` and
+//! `downloaded` (bytes, with an optional K, M, G or T suffix).
+
+use std::str::FromStr;
+use std::sync::Arc;
+
+use nexus_common::protocol::{LeaderboardStat, UserStats};
+use nexus_common::validators::{RankNameError, validate_rank_name};
+
+use crate::util::parse_bytes;
+
+/// A rank and the counters needed to earn it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rank {
+    /// Name shown to users
+    pub name: String,
+    /// Minimum value of each listed counter
+    pub thresholds: Vec<(LeaderboardStat, u64)>,
+}
+
+impl Rank {
+    /// Whether an account with these counters has earned the rank
+    pub fn is_earned(&self, stats: &UserStats) -> bool {
+        self.thresholds
+            .iter()
+            .all(|(stat, minimum)| stat.value(stats) >= *minimum)
+    }
+}
+
+impl FromStr for Rank {
+    type Err = String;
+
+    /// Parse `NAME[:STAT=N,...]`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, thresholds) = match s.split_once(':') {
+            Some((name, thresholds)) => (name.trim(), Some(thresholds)),
+            None => (s.trim(), None),
+        };
+
+        validate_rank_name(name).map_err(|e| match e {
+            RankNameError::Empty => "rank name cannot be empty".to_string(),
+            RankNameError::TooLong => format!("rank name '{name}' is too long"),
+            RankNameError::InvalidCharacters => {
+                format!("rank name '{name}' contains invalid characters")
+            }
+        })?;
+
+        let thresholds = match thresholds {
+            Some(thresholds) => thresholds
+                .split(',')
+                .map(parse_threshold)
+                .collect::<Result<Vec<_>, _>>()?,
+            None => Vec::new(),
+        };
+
+        Ok(Rank {
+            name: name.to_string(),
+            thresholds,
+        })
+    }
+}
+
+/// Parse one `STAT=N` threshold
+fn parse_threshold(s: &str) -> Result<(LeaderboardStat, u64), String> {
+    let (stat, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("expected STAT=N, got '{}'", s.trim()))?;
+
+    let stat = match stat.trim() {
+        "logins" => LeaderboardStat::Logins,
+        "messages" => LeaderboardStat::Messages,
+        "uploads" => LeaderboardStat::Uploads,
+        "downloaded" => LeaderboardStat::DownloadBytes,
+        other => {
+            return Err(format!(
+                "unknown counter '{other}' (expected logins, messages, uploads or downloaded)"
+            ));
+        }
+    };
+
+    let value = value.trim();
+    let parsed = if stat == LeaderboardStat::DownloadBytes {
+        parse_bytes(value)
+    } else {
+        value.parse().ok()
+    };
+    let value = parsed.ok_or_else(|| format!("invalid value '{value}'"))?;
+
+    Ok((stat, value))
+}
+
+/// Ranks from `--rank`, lowest first
+///
+/// Cheap to clone; clones share the list.
+#[derive(Debug, Clone, Default)]
+pub struct Ranks {
+    ranks: Arc<Vec<Rank>>,
+}
+
+impl Ranks {
+    /// The ranks accounts can earn
+    pub fn new(ranks: Vec<Rank>) -> Self {
+        Self {
+            ranks: Arc::new(ranks),
+        }
+    }
+
+    /// The rank an account's counters earn, if any
+    pub fn rank_for(&self, stats: &UserStats) -> Option<String> {
+        highest_earned(&self.ranks, stats).map(|rank| rank.name.clone())
+    }
+}
+
+/// The last rank in `ranks` whose thresholds are met
+fn highest_earned<'a>(ranks: &'a [Rank], stats: &UserStats) -> Option<&'a Rank> {
+    ranks.iter().rev().find(|rank| rank.is_earned(stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rank() {
+        let rank: Rank = "Elite:uploads=50, downloaded=10G".parse().unwrap();
+        assert_eq!(rank.name, "Elite");
+        assert_eq!(
+            rank.thresholds,
+            vec![
+                (LeaderboardStat::Uploads, 50),
+                (LeaderboardStat::DownloadBytes, 10 * 1024 * 1024 * 1024),
+            ]
+        );
+
+        let rank: Rank = "Newbie".parse().unwrap();
+        assert_eq!(rank.name, "Newbie");
+        assert!(rank.thresholds.is_empty());
+    }
+
+    #[test]
+    fn test_parse_rank_errors() {
+        assert!("".parse::<Rank>().is_err());
+        assert!(":logins=1".parse::<Rank>().is_err());
+        assert!("Regular:logins".parse::<Rank>().is_err());
+        assert!("Regular:posts=5".parse::<Rank>().is_err());
+        assert!("Regular:logins=many".parse::<Rank>().is_err());
+        // Only byte counts take a suffix
+        assert!("Regular:logins=5K".parse::<Rank>().is_err());
+        assert!("Hoarder:downloaded=99999999T".parse::<Rank>().is_err());
+    }
+
+    #[test]
+    fn test_highest_earned_is_last_match() {
+        let ranks: Vec<Rank> = ["Newbie", "Regular:logins=10", "Poster:messages=100"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+
+        let mut stats = UserStats::default();
+        assert_eq!(highest_earned(&ranks, &stats).unwrap().name, "Newbie");
+
+        stats.logins = 10;
+        assert_eq!(highest_earned(&ranks, &stats).unwrap().name, "Regular");
+
+        // Later ranks win even without the earlier ones' thresholds
+        stats.logins = 0;
+        stats.messages = 100;
+        assert_eq!(highest_earned(&ranks, &stats).unwrap().name, "Poster");
+
+        assert!(highest_earned(&ranks[1..], &UserStats::default()).is_none());
+    }
+
+    #[test]
+    fn test_rank_for() {
+        let stats = UserStats::default();
+        assert_eq!(Ranks::default().rank_for(&stats), None);
+
+        let ranks = Ranks::new(vec!["Newbie".parse().unwrap()]);
+        assert_eq!(ranks.rank_for(&stats).as_deref(), Some("Newbie"));
+    }
+}
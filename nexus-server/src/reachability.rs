@@ -0,0 +1,650 @@
+//! Connectivity self-test
+//!
+//! "Why can't anyone connect?" almost always comes down to a port that isn't
+//! forwarded or is blocked by a firewall, which can only be seen from outside
+//! the server's network. The self-test asks a check service to connect back
+//! to each of the server's ports and reports what it saw.
+//!
+//! ## Check services
+//!
+//! A check service is any nexusd started with `--check-responder-port` (a
+//! friend's server, or a small VPS). It speaks a one-line text protocol over
+//! plain TCP:
+//!
+//! ```text
+//! → CHECK 9f86d081884c7d65 tcp:7500 tcp:7501 udp:7500
+//! ← ADDR 203.0.113.7
+//! ← tcp:7500 open
+//! ← tcp:7501 filtered
+//! ← udp:7500 sent
+//! ← END
+//! ```
+//!
+//! The responder only ever connects back to the address the request came
+//! from, so it can't be used to probe anyone else. TCP results are what the
+//! responder saw; for UDP it sends the token in a few datagrams and the
+//! requester reports whether one arrived.
+//!
+//! ## Running a check
+//!
+//! - `nexusd check --check-service HOST:PORT` tests the configured ports and
+//!   exits. Ports nothing is listening on are bound for the duration of the
+//!   check, so it works before the first start (run it with the server
+//!   stopped to test voice).
+//! - Admins can send `ConnectivityCheck` to a server started with
+//!   `--check-service`. Voice is reported as untested, since the voice server
+//!   owns the UDP port.
+
+use std::collections::HashSet;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+
+use futures_util::future::join_all;
+use rand::RngExt;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream, UdpSocket};
+use tokio::task::JoinHandle;
+use tokio::time::{sleep, timeout};
+
+use nexus_common::protocol::{CheckedService, PortCheck, PortReachability};
+
+use crate::constants::{
+    ERR_CHECK_SERVICE_CONNECT, ERR_CHECK_SERVICE_INVALID_RESPONSE, ERR_CHECK_SERVICE_REFUSED,
+    ERR_CHECK_SERVICE_TIMEOUT,
+};
+use crate::ip_rule_cache::IpRuleCache;
+
+/// First word of a check request
+const REQUEST_COMMAND: &str = "CHECK";
+
+/// Maximum length of a request line (a token and five ports fit easily)
+const MAX_REQUEST_LENGTH: u64 = 256;
+
+/// Maximum length of a whole response
+const MAX_RESPONSE_LENGTH: u64 = 1024;
+
+/// Maximum number of ports in one request (four TCP ports and voice)
+const MAX_CHECK_PORTS: usize = 5;
+
+/// Maximum length of a token
+const MAX_TOKEN_LENGTH: usize = 32;
+
+/// Maximum number of checks a responder runs at once
+const MAX_CONCURRENT_CHECKS: usize = 16;
+
+/// How long the responder waits for a request line
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long a connection (to the check service, or back to a port) may take
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long a whole check may take
+const CHECK_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Number of datagrams sent to each UDP port
+const UDP_PROBE_COUNT: usize = 3;
+
+/// Time between datagrams
+const UDP_PROBE_INTERVAL: Duration = Duration::from_millis(200);
+
+/// How long the requester waits for a datagram after the response
+const UDP_WAIT: Duration = Duration::from_secs(2);
+
+/// Where and what to check
+#[derive(Debug, Clone)]
+pub struct CheckConfig {
+    /// Check service address (`host:port`)
+    pub service: String,
+    /// Ports to check
+    pub targets: Vec<CheckTarget>,
+}
+
+/// A server port to check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CheckTarget {
+    pub service: CheckedService,
+    pub port: u16,
+}
+
+impl CheckTarget {
+    /// Whether the port is UDP (voice) rather than TCP
+    fn is_udp(&self) -> bool {
+        self.service == CheckedService::Voice
+    }
+
+    /// Port as written in requests and responses (`tcp:7500`)
+    fn label(&self) -> String {
+        let protocol = if self.is_udp() { "udp" } else { "tcp" };
+        format!("{protocol}:{}", self.port)
+    }
+}
+
+/// Result of a connectivity check
+#[derive(Debug, Clone)]
+pub struct CheckReport {
+    /// Address the check service saw the request come from
+    pub external_ip: Option<String>,
+    /// Result for each port, in the order they were given
+    pub ports: Vec<PortCheck>,
+}
+
+/// Ports a server with this configuration listens on
+pub fn check_targets(
+    port: u16,
+    transfer_port: u16,
+    websocket_port: Option<u16>,
+    transfer_websocket_port: Option<u16>,
+) -> Vec<CheckTarget> {
+    let mut targets = vec![
+        CheckTarget {
+            service: CheckedService::Bbs,
+            port,
+        },
+        CheckTarget {
+            service: CheckedService::Transfer,
+            port: transfer_port,
+        },
+    ];
+    if let Some(port) = websocket_port {
+        targets.push(CheckTarget {
+            service: CheckedService::Websocket,
+            port,
+        });
+    }
+    if let Some(port) = transfer_websocket_port {
+        targets.push(CheckTarget {
+            service: CheckedService::TransferWebsocket,
+            port,
+        });
+    }
+    // Voice shares the BBS port number, over UDP
+    targets.push(CheckTarget {
+        service: CheckedService::Voice,
+        port,
+    });
+    targets
+}
+
+/// One line summary of a port result (`BBS (tcp 7500): open`)
+pub fn describe_port(port: &PortCheck) -> String {
+    let (name, protocol) = match port.service {
+        CheckedService::Bbs => ("BBS", "tcp"),
+        CheckedService::Transfer => ("Transfer", "tcp"),
+        CheckedService::Websocket => ("WebSocket", "tcp"),
+        CheckedService::TransferWebsocket => ("WebSocket transfer", "tcp"),
+        CheckedService::Voice => ("Voice", "udp"),
+    };
+    let state = match port.state {
+        PortReachability::Open => "open",
+        PortReachability::Closed => "closed (connection refused)",
+        PortReachability::Filtered => "filtered (not forwarded, or blocked by a firewall)",
+        PortReachability::Untested => "untested",
+    };
+    format!("{name} ({protocol} {}): {state}", port.port)
+}
+
+// =============================================================================
+// Requester
+// =============================================================================
+
+/// Run a connectivity check through a check service
+///
+/// With `listen`, ports nothing is listening on are bound for the duration of
+/// the check (used by `nexusd check`, which runs without the server). Without
+/// it, UDP ports are reported as untested.
+pub async fn run_check(
+    config: &CheckConfig,
+    bind: IpAddr,
+    listen: bool,
+) -> Result<CheckReport, String> {
+    let CheckConfig { service, targets } = config;
+    let mut placeholders: Vec<JoinHandle<()>> = Vec::new();
+    let mut udp_sockets: Vec<(u16, UdpSocket)> = Vec::new();
+    if listen {
+        for target in targets {
+            if target.is_udp() {
+                if let Ok(socket) = UdpSocket::bind(SocketAddr::new(bind, target.port)).await {
+                    udp_sockets.push((target.port, socket));
+                }
+            } else if let Ok(listener) = TcpListener::bind(SocketAddr::new(bind, target.port)).await
+            {
+                placeholders.push(tokio::spawn(accept_and_close(listener)));
+            }
+        }
+    }
+
+    // Only UDP ports we can watch are worth asking about
+    let requested: Vec<CheckTarget> = targets
+        .iter()
+        .filter(|target| {
+            !target.is_udp() || udp_sockets.iter().any(|(port, _)| *port == target.port)
+        })
+        .copied()
+        .collect();
+
+    let token = hex::encode(rand::rng().random::<[u8; 8]>());
+    let result = match timeout(CHECK_TIMEOUT, request_check(service, &token, &requested)).await {
+        Ok(result) => result,
+        Err(_) => Err(ERR_CHECK_SERVICE_TIMEOUT.to_string()),
+    };
+
+    for placeholder in &placeholders {
+        placeholder.abort();
+    }
+    let (external_ip, answers) = result?;
+
+    let mut ports = Vec::with_capacity(targets.len());
+    for target in targets {
+        let state = if target.is_udp() {
+            match udp_sockets.iter().find(|(port, _)| *port == target.port) {
+                Some((_, socket)) => wait_for_token(socket, &token).await,
+                None => PortReachability::Untested,
+            }
+        } else {
+            answers
+                .iter()
+                .find(|(label, _)| *label == target.label())
+                .map(|(_, state)| *state)
+                .ok_or_else(|| ERR_CHECK_SERVICE_INVALID_RESPONSE.to_string())?
+        };
+        ports.push(PortCheck {
+            service: target.service,
+            port: target.port,
+            state,
+        });
+    }
+
+    Ok(CheckReport { external_ip, ports })
+}
+
+/// Send a check request and read the response
+///
+/// Returns the address the service saw and the state of each TCP port.
+async fn request_check(
+    service: &str,
+    token: &str,
+    targets: &[CheckTarget],
+) -> Result<(Option<String>, Vec<(String, PortReachability)>), String> {
+    let stream = match timeout(CONNECT_TIMEOUT, TcpStream::connect(service)).await {
+        Ok(Ok(stream)) => stream,
+        Ok(Err(e)) => return Err(format!("{ERR_CHECK_SERVICE_CONNECT}{e}")),
+        Err(_) => return Err(ERR_CHECK_SERVICE_TIMEOUT.to_string()),
+    };
+    let (reader, mut writer) = stream.into_split();
+
+    writer
+        .write_all(format_request(token, targets).as_bytes())
+        .await
+        .map_err(|e| format!("{ERR_CHECK_SERVICE_CONNECT}{e}"))?;
+
+    let mut lines = BufReader::new(reader.take(MAX_RESPONSE_LENGTH)).lines();
+    let mut external_ip = None;
+    let mut answers = Vec::new();
+    loop {
+        let line = lines
+            .next_line()
+            .await
+            .ok()
+            .flatten()
+            .ok_or_else(|| ERR_CHECK_SERVICE_INVALID_RESPONSE.to_string())?;
+        match parse_response_line(&line)? {
+            ResponseLine::Address(ip) => external_ip = Some(ip.to_string()),
+            ResponseLine::Port(label, state) => answers.push((label, state)),
+            ResponseLine::End => break,
+        }
+    }
+
+    Ok((external_ip, answers))
+}
+
+/// Accept and immediately close connections on a port the server isn't using
+async fn accept_and_close(listener: TcpListener) {
+    while listener.accept().await.is_ok() {}
+}
+
+/// Whether a datagram with the token arrives on a UDP port
+async fn wait_for_token(socket: &UdpSocket, token: &str) -> PortReachability {
+    let mut buf = [0u8; 64];
+    let received = timeout(UDP_WAIT, async {
+        loop {
+            if let Ok((len, _)) = socket.recv_from(&mut buf).await
+                && &buf[..len] == token.as_bytes()
+            {
+                return;
+            }
+        }
+    })
+    .await;
+    match received {
+        Ok(()) => PortReachability::Open,
+        Err(_) => PortReachability::Filtered,
+    }
+}
+
+/// Build a request line
+fn format_request(token: &str, targets: &[CheckTarget]) -> String {
+    let mut request = format!("{REQUEST_COMMAND} {token}");
+    for target in targets {
+        request.push(' ');
+        request.push_str(&target.label());
+    }
+    request.push('\n');
+    request
+}
+
+/// One line of a check service response
+#[derive(Debug, PartialEq, Eq)]
+enum ResponseLine {
+    /// `ADDR <ip>`
+    Address(IpAddr),
+    /// `tcp:<port> <state>` or `udp:<port> sent`
+    Port(String, PortReachability),
+    /// `END`
+    End,
+}
+
+/// Parse one line of a check service response
+fn parse_response_line(line: &str) -> Result<ResponseLine, String> {
+    let invalid = || ERR_CHECK_SERVICE_INVALID_RESPONSE.to_string();
+    let (word, rest) = line.split_once(' ').unwrap_or((line, ""));
+    match word {
+        "END" => Ok(ResponseLine::End),
+        "ERR" => Err(format!("{ERR_CHECK_SERVICE_REFUSED}{rest}")),
+        "ADDR" => rest
+            .parse()
+            .map(ResponseLine::Address)
+            .map_err(|_| invalid()),
+        label => {
+            let state = match rest {
+                "open" => PortReachability::Open,
+                "closed" => PortReachability::Closed,
+                "filtered" => PortReachability::Filtered,
+                // The requester decides for UDP
+                "sent" => PortReachability::Untested,
+                _ => return Err(invalid()),
+            };
+            Ok(ResponseLine::Port(label.to_string(), state))
+        }
+    }
+}
+
+// =============================================================================
+// Responder
+// =============================================================================
+
+/// A port the responder was asked to test
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ProbePort {
+    Tcp(u16),
+    Udp(u16),
+}
+
+/// Parse a request line into its token and ports
+fn parse_request(line: &str) -> Result<(&str, Vec<ProbePort>), &'static str> {
+    let mut words = line.split_whitespace();
+    if words.next() != Some(REQUEST_COMMAND) {
+        return Err("unknown request");
+    }
+    let token = words
+        .next()
+        .filter(|token| {
+            !token.is_empty()
+                && token.len() <= MAX_TOKEN_LENGTH
+                && token.chars().all(|c| c.is_ascii_hexdigit())
+        })
+        .ok_or("invalid token")?;
+
+    let mut ports = Vec::new();
+    for word in words {
+        let port = match word.split_once(':') {
+            Some(("tcp", port)) => port.parse().ok().map(ProbePort::Tcp),
+            Some(("udp", port)) => port.parse().ok().map(ProbePort::Udp),
+            _ => None,
+        }
+        .ok_or("invalid port")?;
+        if !ports.contains(&port) {
+            ports.push(port);
+        }
+    }
+    if ports.is_empty() || ports.len() > MAX_CHECK_PORTS {
+        return Err("invalid port count");
+    }
+
+    Ok((token, ports))
+}
+
+/// Answer connectivity checks from other servers
+///
+/// At most one check per address runs at a time, and banned addresses are
+/// refused.
+pub fn spawn_check_responder(
+    listener: TcpListener,
+    ip_rule_cache: Arc<RwLock<IpRuleCache>>,
+    debug: bool,
+) -> JoinHandle<()> {
+    let active: Arc<Mutex<HashSet<IpAddr>>> = Arc::new(Mutex::new(HashSet::new()));
+    tokio::spawn(async move {
+        loop {
+            let Ok((socket, peer_addr)) = listener.accept().await else {
+                continue;
+            };
+            let allowed = ip_rule_cache
+                .read()
+                .expect("ip rule cache lock poisoned")
+                .should_allow_read_only(peer_addr.ip());
+            if !allowed {
+                continue;
+            }
+            {
+                let mut active = active.lock().expect("check responder lock poisoned");
+                if active.len() >= MAX_CONCURRENT_CHECKS || !active.insert(peer_addr.ip()) {
+                    continue;
+                }
+            }
+
+            let active = active.clone();
+            tokio::spawn(async move {
+                if let Err(e) = answer_check(socket, peer_addr.ip()).await
+                    && debug
+                {
+                    log_debug!("Connectivity check from {} failed: {}", peer_addr, e);
+                }
+                active
+                    .lock()
+                    .expect("check responder lock poisoned")
+                    .remove(&peer_addr.ip());
+            });
+        }
+    })
+}
+
+/// Read one request, probe the ports and write the results
+async fn answer_check(socket: TcpStream, peer_ip: IpAddr) -> std::io::Result<()> {
+    let (reader, mut writer) = socket.into_split();
+    let mut line = String::new();
+    let mut reader = BufReader::new(reader.take(MAX_REQUEST_LENGTH));
+    match timeout(REQUEST_TIMEOUT, reader.read_line(&mut line)).await {
+        Ok(Ok(_)) => {}
+        Ok(Err(e)) => return Err(e),
+        Err(_) => return Ok(()),
+    }
+
+    let (token, ports) = match parse_request(line.trim_end()) {
+        Ok(request) => request,
+        Err(reason) => {
+            writer
+                .write_all(format!("ERR {reason}\n").as_bytes())
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let results = join_all(ports.iter().map(|port| probe_port(peer_ip, *port, token))).await;
+
+    let mut response = format!("ADDR {peer_ip}\n");
+    for (port, result) in ports.iter().zip(results) {
+        let line = match port {
+            ProbePort::Tcp(port) => format!("tcp:{port} {result}\n"),
+            ProbePort::Udp(port) => format!("udp:{port} {result}\n"),
+        };
+        response.push_str(&line);
+    }
+    response.push_str("END\n");
+    writer.write_all(response.as_bytes()).await
+}
+
+/// Probe one port on the requester, returning the word for the response
+async fn probe_port(peer_ip: IpAddr, port: ProbePort, token: &str) -> &'static str {
+    match port {
+        ProbePort::Tcp(port) => {
+            match timeout(CONNECT_TIMEOUT, TcpStream::connect((peer_ip, port))).await {
+                Ok(Ok(_)) => "open",
+                Ok(Err(e)) if e.kind() == std::io::ErrorKind::ConnectionRefused => "closed",
+                _ => "filtered",
+            }
+        }
+        ProbePort::Udp(port) => {
+            let local: SocketAddr = match peer_ip {
+                IpAddr::V4(_) => ([0, 0, 0, 0], 0).into(),
+                IpAddr::V6(_) => (std::net::Ipv6Addr::UNSPECIFIED, 0).into(),
+            };
+            if let Ok(socket) = UdpSocket::bind(local).await {
+                for _ in 0..UDP_PROBE_COUNT {
+                    let _ = socket.send_to(token.as_bytes(), (peer_ip, port)).await;
+                    sleep(UDP_PROBE_INTERVAL).await;
+                }
+            }
+            "sent"
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_targets() {
+        let targets = check_targets(7500, 7501, None, None);
+        let services: Vec<_> = targets.iter().map(|t| t.service).collect();
+        assert_eq!(
+            services,
+            vec![
+                CheckedService::Bbs,
+                CheckedService::Transfer,
+                CheckedService::Voice
+            ]
+        );
+
+        let targets = check_targets(7500, 7501, Some(7502), Some(7503));
+        assert_eq!(targets.len(), MAX_CHECK_PORTS);
+        assert_eq!(targets[4].label(), "udp:7500");
+    }
+
+    #[test]
+    fn test_describe_port() {
+        let port = PortCheck {
+            service: CheckedService::Voice,
+            port: 7500,
+            state: PortReachability::Open,
+        };
+        assert_eq!(describe_port(&port), "Voice (udp 7500): open");
+    }
+
+    #[test]
+    fn test_request_round_trip() {
+        let targets = check_targets(7500, 7501, None, None);
+        let request = format_request("abc123", &targets);
+        assert_eq!(request, "CHECK abc123 tcp:7500 tcp:7501 udp:7500\n");
+
+        let (token, ports) = parse_request(request.trim_end()).unwrap();
+        assert_eq!(token, "abc123");
+        assert_eq!(
+            ports,
+            vec![
+                ProbePort::Tcp(7500),
+                ProbePort::Tcp(7501),
+                ProbePort::Udp(7500)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_request_rejects_bad_input() {
+        assert!(parse_request("GET / HTTP/1.1").is_err());
+        assert!(parse_request("CHECK not-hex tcp:7500").is_err());
+        assert!(parse_request("CHECK abc123").is_err());
+        assert!(parse_request("CHECK abc123 tcp:99999").is_err());
+        assert!(parse_request("CHECK abc123 icmp:7500").is_err());
+        assert!(
+            parse_request("CHECK abc123 tcp:1 tcp:2 tcp:3 tcp:4 tcp:5 tcp:6").is_err(),
+            "too many ports"
+        );
+    }
+
+    #[test]
+    fn test_parse_response_line() {
+        assert_eq!(
+            parse_response_line("ADDR 203.0.113.7"),
+            Ok(ResponseLine::Address("203.0.113.7".parse().unwrap()))
+        );
+        assert_eq!(
+            parse_response_line("tcp:7500 filtered"),
+            Ok(ResponseLine::Port(
+                "tcp:7500".to_string(),
+                PortReachability::Filtered
+            ))
+        );
+        assert_eq!(parse_response_line("END"), Ok(ResponseLine::End));
+        assert!(parse_response_line("ERR invalid token").is_err());
+        assert!(parse_response_line("tcp:7500 maybe").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_check_over_loopback() {
+        let responder = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let service = responder.local_addr().unwrap().to_string();
+        let responder =
+            spawn_check_responder(responder, Arc::new(RwLock::new(IpRuleCache::new())), false);
+
+        // An open port, and one with nothing listening
+        let open = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let open_port = open.local_addr().unwrap().port();
+        let closed_port = {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            listener.local_addr().unwrap().port()
+        };
+        let targets = vec![
+            CheckTarget {
+                service: CheckedService::Bbs,
+                port: open_port,
+            },
+            CheckTarget {
+                service: CheckedService::Transfer,
+                port: closed_port,
+            },
+            CheckTarget {
+                service: CheckedService::Voice,
+                port: open_port,
+            },
+        ];
+
+        let config = CheckConfig { service, targets };
+        let report = run_check(&config, "127.0.0.1".parse().unwrap(), false)
+            .await
+            .unwrap();
+        responder.abort();
+
+        assert_eq!(report.external_ip.as_deref(), Some("127.0.0.1"));
+        let states: Vec<_> = report.ports.iter().map(|p| p.state).collect();
+        assert_eq!(
+            states,
+            vec![
+                PortReachability::Open,
+                PortReachability::Closed,
+                PortReachability::Untested
+            ]
+        );
+    }
+}
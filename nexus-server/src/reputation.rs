@@ -0,0 +1,299 @@
+//! IP reputation checks against DNS blocklists (DNSBLs)
+//!
+//! When started with one or more `--dnsbl` zones, the server looks up each new
+//! BBS connection's address in those blocklists before the TLS handshake and
+//! applies the `--dnsbl-policy` to listed addresses:
+//!
+//! - `reject`: close the connection like a ban
+//! - `tarpit`: delay the TLS handshake and every response until login
+//! - `pow`: require a proof of work with the login (see `nexus_common::pow`)
+//!
+//! ## Usage
+//!
+//! ```bash
+//! nexusd --dnsbl zen.spamhaus.org                          # Tarpit listed addresses
+//! nexusd --dnsbl dnsbl.dronebl.org --dnsbl-policy reject   # Reject listed addresses
+//! ```
+//!
+//! ## Lookups
+//!
+//! An address is listed if `<reversed address>.<zone>` resolves to an address
+//! in `127.0.0.0/8` (`127.255.255.0/24` is used by blocklists to report
+//! errors, such as queries through refused public resolvers, and is ignored).
+//! Zones are queried in parallel through the system resolver, and results are
+//! kept in the [`IpRuleCache`] for [`REPUTATION_CACHE_TTL`] seconds.
+//!
+//! Trusted addresses and private, loopback and link-local addresses are never
+//! looked up. A lookup that fails or times out counts as not listed, so an
+//! unreachable blocklist doesn't lock anyone out.
+
+use std::net::{IpAddr, Ipv4Addr};
+use std::sync::RwLock;
+use std::time::Duration;
+
+use futures_util::future::join_all;
+use tokio::net::lookup_host;
+use tokio::time::timeout;
+
+use crate::handlers::current_timestamp;
+use crate::ip_rule_cache::{IpRuleCache, normalize_ip};
+
+/// How long a blocklist result is cached (1 hour)
+pub const REPUTATION_CACHE_TTL: i64 = 3600;
+
+/// Delay before the TLS handshake and each response for tarpitted connections
+pub const TARPIT_DELAY: Duration = Duration::from_secs(3);
+
+/// Proof-of-work difficulty (leading zero bits) for the `pow` policy
+pub const POW_DIFFICULTY: u8 = 20;
+
+/// Timeout for a single blocklist query
+const DNSBL_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// What to do with connections from listed addresses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ReputationPolicy {
+    /// Close the connection before the TLS handshake
+    Reject,
+    /// Delay the TLS handshake and every response until login
+    Tarpit,
+    /// Require a proof of work with the login
+    Pow,
+}
+
+impl ReputationPolicy {
+    /// Policy name as given on the command line
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReputationPolicy::Reject => "reject",
+            ReputationPolicy::Tarpit => "tarpit",
+            ReputationPolicy::Pow => "pow",
+        }
+    }
+}
+
+/// DNS blocklist configuration
+#[derive(Debug, Clone)]
+pub struct ReputationConfig {
+    /// Blocklist zones to query (e.g. `zen.spamhaus.org`)
+    pub zones: Vec<String>,
+    /// Policy for listed addresses
+    pub policy: ReputationPolicy,
+}
+
+/// Check an address against the configured blocklists
+///
+/// Returns the zone listing the address, if any. Results are cached in the
+/// IP rule cache, so only the first connection per TTL waits for DNS.
+pub async fn check_reputation(
+    ip: IpAddr,
+    config: &ReputationConfig,
+    ip_rule_cache: &RwLock<IpRuleCache>,
+) -> Option<String> {
+    let ip = normalize_ip(ip);
+    if config.zones.is_empty() || !is_public(ip) {
+        return None;
+    }
+
+    {
+        let cache = ip_rule_cache.read().expect("ip rule cache lock poisoned");
+        if cache.is_trusted_read_only(ip) {
+            return None;
+        }
+        if let Some(listed_in) = cache.cached_reputation(ip) {
+            return listed_in;
+        }
+    }
+
+    let listed_in = lookup_listing(ip, &config.zones).await;
+    if let Some(zone) = &listed_in {
+        log_info!("{} is listed in {}", ip, zone);
+    }
+
+    ip_rule_cache
+        .write()
+        .expect("ip rule cache lock poisoned")
+        .set_reputation(
+            ip,
+            listed_in.clone(),
+            current_timestamp() + REPUTATION_CACHE_TTL,
+        );
+
+    listed_in
+}
+
+/// Query all zones in parallel, returning the first (in config order) listing the address
+async fn lookup_listing(ip: IpAddr, zones: &[String]) -> Option<String> {
+    let results = join_all(zones.iter().map(|zone| is_listed(ip, zone))).await;
+    zones
+        .iter()
+        .zip(results)
+        .find(|(_, listed)| *listed)
+        .map(|(zone, _)| zone.clone())
+}
+
+/// Query one zone for an address
+async fn is_listed(ip: IpAddr, zone: &str) -> bool {
+    let name = dnsbl_query_name(ip, zone);
+    match timeout(DNSBL_TIMEOUT, lookup_host((name.as_str(), 0))).await {
+        Ok(Ok(mut addrs)) => addrs.any(|addr| is_listing_answer(addr.ip())),
+        // NXDOMAIN (not listed), resolver errors and timeouts
+        _ => false,
+    }
+}
+
+/// Build the blocklist query name for an address
+///
+/// IPv4 octets are reversed (`1.2.3.4` → `4.3.2.1.<zone>`); IPv6 addresses
+/// use their 32 nibbles in reverse order, like `ip6.arpa`.
+pub fn dnsbl_query_name(ip: IpAddr, zone: &str) -> String {
+    let zone = zone.trim_end_matches('.');
+    match ip {
+        IpAddr::V4(v4) => {
+            let [a, b, c, d] = v4.octets();
+            format!("{d}.{c}.{b}.{a}.{zone}")
+        }
+        IpAddr::V6(v6) => {
+            let mut name = String::with_capacity(64 + zone.len());
+            for byte in v6.octets().iter().rev() {
+                name.push_str(&format!("{:x}.{:x}.", byte & 0x0f, byte >> 4));
+            }
+            name.push_str(zone);
+            name
+        }
+    }
+}
+
+/// Whether a blocklist answer means "listed"
+fn is_listing_answer(answer: IpAddr) -> bool {
+    match answer {
+        IpAddr::V4(v4) => {
+            let [a, b, c, _] = v4.octets();
+            a == 127 && !(b == 255 && c == 255)
+        }
+        IpAddr::V6(_) => false,
+    }
+}
+
+/// Whether an address is worth looking up (blocklists only list public addresses)
+fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => !is_non_public_v4(v4),
+        IpAddr::V6(v6) => {
+            !(v6.is_loopback()
+                || v6.is_unspecified()
+                || v6.is_unique_local()
+                || v6.is_unicast_link_local())
+        }
+    }
+}
+
+/// Private, loopback, link-local and other non-routable IPv4 ranges
+fn is_non_public_v4(v4: Ipv4Addr) -> bool {
+    let [a, b, ..] = v4.octets();
+    v4.is_private()
+        || v4.is_loopback()
+        || v4.is_link_local()
+        || v4.is_unspecified()
+        || v4.is_broadcast()
+        || v4.is_documentation()
+        // Carrier-grade NAT (100.64.0.0/10)
+        || (a == 100 && (b & 0xc0) == 64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dnsbl_query_name_ipv4() {
+        let ip: IpAddr = "192.0.2.99".parse().unwrap();
+        assert_eq!(
+            dnsbl_query_name(ip, "dnsbl.example"),
+            "99.2.0.192.dnsbl.example"
+        );
+        // Trailing dot on the zone is ignored
+        assert_eq!(
+            dnsbl_query_name(ip, "dnsbl.example."),
+            "99.2.0.192.dnsbl.example"
+        );
+    }
+
+    #[test]
+    fn test_dnsbl_query_name_ipv6() {
+        let ip: IpAddr = "2001:db8:1:2:3:4:567:89ab".parse().unwrap();
+        assert_eq!(
+            dnsbl_query_name(ip, "dnsbl.example"),
+            "b.a.9.8.7.6.5.0.4.0.0.0.3.0.0.0.2.0.0.0.1.0.0.0.8.b.d.0.1.0.0.2.dnsbl.example"
+        );
+    }
+
+    #[test]
+    fn test_policy_as_str_matches_cli_name() {
+        use clap::ValueEnum;
+        for policy in ReputationPolicy::value_variants() {
+            let value = policy.to_possible_value().unwrap();
+            assert_eq!(policy.as_str(), value.get_name());
+        }
+    }
+
+    #[test]
+    fn test_is_listing_answer() {
+        assert!(is_listing_answer("127.0.0.2".parse().unwrap()));
+        assert!(is_listing_answer("127.0.0.10".parse().unwrap()));
+        // Blocklist error codes
+        assert!(!is_listing_answer("127.255.255.254".parse().unwrap()));
+        assert!(!is_listing_answer("192.0.2.1".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_is_public() {
+        assert!(is_public("8.8.8.8".parse().unwrap()));
+        assert!(is_public("2606:4700::1111".parse().unwrap()));
+        assert!(!is_public("10.1.2.3".parse().unwrap()));
+        assert!(!is_public("192.168.1.1".parse().unwrap()));
+        assert!(!is_public("127.0.0.1".parse().unwrap()));
+        assert!(!is_public("100.64.0.1".parse().unwrap()));
+        assert!(!is_public("::1".parse().unwrap()));
+        assert!(!is_public("fd00::1".parse().unwrap()));
+        assert!(!is_public("fe80::1".parse().unwrap()));
+    }
+
+    #[tokio::test]
+    async fn test_check_reputation_uses_cache() {
+        let cache = RwLock::new(IpRuleCache::new());
+        let config = ReputationConfig {
+            zones: vec!["dnsbl.invalid".to_string()],
+            policy: ReputationPolicy::Reject,
+        };
+        let ip: IpAddr = "1.2.3.4".parse().unwrap();
+
+        // A cached result is returned without a lookup
+        cache.write().unwrap().set_reputation(
+            ip,
+            Some("dnsbl.invalid".to_string()),
+            current_timestamp() + REPUTATION_CACHE_TTL,
+        );
+        assert_eq!(
+            check_reputation(ip, &config, &cache).await,
+            Some("dnsbl.invalid".to_string())
+        );
+
+        // Trusted addresses are never listed
+        cache.write().unwrap().add_trust("1.2.3.4", None);
+        assert_eq!(check_reputation(ip, &config, &cache).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_check_reputation_skips_private_addresses() {
+        let cache = RwLock::new(IpRuleCache::new());
+        let config = ReputationConfig {
+            zones: vec!["dnsbl.invalid".to_string()],
+            policy: ReputationPolicy::Tarpit,
+        };
+        let ip: IpAddr = "192.168.1.10".parse().unwrap();
+
+        assert_eq!(check_reputation(ip, &config, &cache).await, None);
+        assert_eq!(cache.read().unwrap().cached_reputation(ip), None);
+    }
+}
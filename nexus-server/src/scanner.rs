@@ -0,0 +1,243 @@
+//! Scanner mitigation for the TCP ports
+//!
+//! Internet-facing servers see a steady stream of port scanners and protocol
+//! probes (HTTP requests, SSH banners, empty connects). With
+//! `--scanner-mitigation`, the server looks at the first byte of every new
+//! BBS and transfer connection before starting TLS. A first byte that doesn't
+//! start a TLS ClientHello marks the connection as a probe:
+//!
+//! - `drop`: close the connection immediately, without a TLS handshake
+//! - `drip`: trickle a short protocol banner one byte at a time, then close,
+//!   keeping the scanner busy at almost no cost to the server
+//!
+//! In both modes the address is penalized for [`SCANNER_PENALTY`]: further
+//! connections from it are closed at accept, before any other work or logging.
+//! Trusted addresses are never penalized. Connections that close or stay
+//! silent before sending anything are just dropped: an empty connect proves
+//! nothing (reachability checks and load balancer health checks look the
+//! same), so it doesn't earn a penalty.
+//!
+//! WebSocket ports aren't screened, since they usually sit behind a reverse
+//! proxy whose address would be penalized for everyone.
+
+use std::net::SocketAddr;
+use std::sync::RwLock;
+use std::time::Duration;
+
+use tokio::io::AsyncWriteExt;
+use tokio::net::TcpStream;
+use tokio::time::{Instant, sleep, timeout};
+
+use crate::connection_tracker::ConnectionTracker;
+use crate::ip_rule_cache::IpRuleCache;
+
+/// How long a probing address is refused
+pub const SCANNER_PENALTY: Duration = Duration::from_secs(10 * 60);
+
+/// Banner dripped to probes, so a human poking the port learns what it is
+const SCANNER_BANNER: &[u8] = b"Nexus BBS - TLS required\r\n";
+
+/// Time between banner bytes in drip mode
+const DRIP_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Longest a probe is kept dripping before it is closed
+const DRIP_MAX_DURATION: Duration = Duration::from_secs(120);
+
+/// How long to wait for a new connection's first byte
+const FIRST_BYTE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// First byte of a TLS handshake record (every ClientHello starts with it)
+const TLS_HANDSHAKE_RECORD: u8 = 0x16;
+
+/// What a new connection sent first
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FirstByte {
+    /// The start of a TLS handshake
+    Tls,
+    /// Something else: a probe
+    Other,
+    /// Nothing: closed, failed or silent until the timeout
+    Nothing,
+}
+
+/// How probes are handled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ScannerMitigation {
+    /// Probes go through the normal connection path (TLS errors are logged in debug mode)
+    Off,
+    /// Close probes immediately
+    Drop,
+    /// Trickle a banner to probes before closing
+    Drip,
+}
+
+impl ScannerMitigation {
+    /// Mode name as given on the command line
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScannerMitigation::Off => "off",
+            ScannerMitigation::Drop => "drop",
+            ScannerMitigation::Drip => "drip",
+        }
+    }
+}
+
+/// Screen a new TCP connection for scanner probes
+///
+/// Returns the socket if it starts like a TLS client (or mitigation is off).
+/// Connections that send nothing are closed by returning `None`. For
+/// anything else the address is penalized, the probe is answered according
+/// to the mitigation mode, and `None` is returned.
+pub async fn screen_connection(
+    socket: TcpStream,
+    peer_addr: SocketAddr,
+    connection_tracker: &ConnectionTracker,
+    ip_rule_cache: &RwLock<IpRuleCache>,
+    debug: bool,
+) -> Option<TcpStream> {
+    let mode = connection_tracker.scanner_mitigation();
+    if mode == ScannerMitigation::Off {
+        return Some(socket);
+    }
+    match first_byte(&socket).await {
+        FirstByte::Tls => return Some(socket),
+        FirstByte::Nothing => return None,
+        FirstByte::Other => {}
+    }
+
+    let is_trusted = ip_rule_cache
+        .read()
+        .expect("ip rule cache lock poisoned")
+        .is_trusted_read_only(peer_addr.ip());
+    if !is_trusted {
+        connection_tracker.penalize(peer_addr.ip(), SCANNER_PENALTY);
+    }
+    if debug {
+        log_debug!("Non-TLS probe from {}", peer_addr);
+    }
+
+    if mode == ScannerMitigation::Drip {
+        drip_banner(socket).await;
+    }
+    None
+}
+
+/// Peek at the client's first byte (waiting up to [`FIRST_BYTE_TIMEOUT`])
+async fn first_byte(socket: &TcpStream) -> FirstByte {
+    let mut first = [0u8; 1];
+    match timeout(FIRST_BYTE_TIMEOUT, socket.peek(&mut first)).await {
+        Ok(Ok(1)) if first[0] == TLS_HANDSHAKE_RECORD => FirstByte::Tls,
+        Ok(Ok(1)) => FirstByte::Other,
+        _ => FirstByte::Nothing,
+    }
+}
+
+/// Trickle the banner until the probe gives up or time runs out
+async fn drip_banner(mut socket: TcpStream) {
+    let deadline = Instant::now() + DRIP_MAX_DURATION;
+    for byte in SCANNER_BANNER.iter().cycle() {
+        if Instant::now() >= deadline || socket.write_all(&[*byte]).await.is_err() {
+            break;
+        }
+        sleep(DRIP_INTERVAL).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::AsyncReadExt;
+    use tokio::net::TcpListener;
+
+    /// Connect a client and return (client, server-side socket, server-side address)
+    async fn connected_pair() -> (TcpStream, TcpStream, SocketAddr) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let client = TcpStream::connect(listener.local_addr().unwrap())
+            .await
+            .unwrap();
+        let (server, peer_addr) = listener.accept().await.unwrap();
+        (client, server, peer_addr)
+    }
+
+    #[test]
+    fn test_mode_as_str_matches_cli_name() {
+        use clap::ValueEnum;
+        for mode in ScannerMitigation::value_variants() {
+            let value = mode.to_possible_value().unwrap();
+            assert_eq!(mode.as_str(), value.get_name());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tls_client_passes() {
+        let tracker = ConnectionTracker::new(0, 0).with_scanner_mitigation(ScannerMitigation::Drop);
+        let cache = RwLock::new(IpRuleCache::new());
+        let (mut client, server, peer_addr) = connected_pair().await;
+
+        client
+            .write_all(&[TLS_HANDSHAKE_RECORD, 3, 1])
+            .await
+            .unwrap();
+        let screened = screen_connection(server, peer_addr, &tracker, &cache, false).await;
+
+        // The peeked byte is still there for the TLS handshake
+        let mut server = screened.expect("TLS client should pass");
+        let mut first = [0u8; 1];
+        server.read_exact(&mut first).await.unwrap();
+        assert_eq!(first[0], TLS_HANDSHAKE_RECORD);
+        assert!(!tracker.is_penalized(peer_addr.ip()));
+    }
+
+    #[tokio::test]
+    async fn test_probe_is_dropped_and_penalized() {
+        let tracker = ConnectionTracker::new(0, 0).with_scanner_mitigation(ScannerMitigation::Drop);
+        let cache = RwLock::new(IpRuleCache::new());
+        let (mut client, server, peer_addr) = connected_pair().await;
+
+        client.write_all(b"GET / HTTP/1.1\r\n").await.unwrap();
+        let screened = screen_connection(server, peer_addr, &tracker, &cache, false).await;
+
+        assert!(screened.is_none());
+        assert!(tracker.is_penalized(peer_addr.ip()));
+    }
+
+    #[tokio::test]
+    async fn test_trusted_probe_is_not_penalized() {
+        let tracker = ConnectionTracker::new(0, 0).with_scanner_mitigation(ScannerMitigation::Drop);
+        let cache = RwLock::new(IpRuleCache::new());
+        cache.write().unwrap().add_trust("127.0.0.1", None);
+        let (mut client, server, peer_addr) = connected_pair().await;
+
+        client.write_all(b"SSH-2.0-probe\r\n").await.unwrap();
+        let screened = screen_connection(server, peer_addr, &tracker, &cache, false).await;
+
+        assert!(screened.is_none());
+        assert!(!tracker.is_penalized(peer_addr.ip()));
+    }
+
+    #[tokio::test]
+    async fn test_empty_connect_is_dropped_without_penalty() {
+        let tracker = ConnectionTracker::new(0, 0).with_scanner_mitigation(ScannerMitigation::Drip);
+        let cache = RwLock::new(IpRuleCache::new());
+        let (client, server, peer_addr) = connected_pair().await;
+
+        drop(client);
+        let screened = screen_connection(server, peer_addr, &tracker, &cache, false).await;
+
+        assert!(screened.is_none());
+        assert!(!tracker.is_penalized(peer_addr.ip()));
+    }
+
+    #[tokio::test]
+    async fn test_mitigation_off_passes_everything() {
+        let tracker = ConnectionTracker::new(0, 0);
+        let cache = RwLock::new(IpRuleCache::new());
+        let (mut client, server, peer_addr) = connected_pair().await;
+
+        client.write_all(b"GET / HTTP/1.1\r\n").await.unwrap();
+        let screened = screen_connection(server, peer_addr, &tracker, &cache, false).await;
+
+        assert!(screened.is_some());
+        assert!(!tracker.is_penalized(peer_addr.ip()));
+    }
+}
@@ -0,0 +1,642 @@
+//! Daily activity statistics
+//!
+//! Logins, chat and private messages, completed transfers (with their byte
+//! counts) and the peak number of concurrent sessions are counted in memory
+//! per UTC day. The counters are added to the `daily_stats` table every
+//! [`STATS_FLUSH_INTERVAL`] and at shutdown, in a single transaction, so a
+//! busy chat costs one commit a minute instead of a write per message. Admins
+//! read the totals with `StatsRequest`.
+//!
+//! Downloads and uploads are also counted per file, for the most downloaded
+//! and recent uploads listings. Files are keyed by their path relative to the
+//! file root, as in the file index (`/shared/song.mp3`).
+//!
+//! Logins, messages, uploads and downloaded bytes are also counted per account
+//! for user info, the leaderboard and [`crate::ranks`]. Each account's latest
+//! login time is kept with its counters, so logging in doesn't write to the
+//! database either.
+//!
+//! The server creates one collector at startup and hands it to connections
+//! and transfers, which count events through it, and to the flush task.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+use chrono::{NaiveDate, Utc};
+use nexus_common::protocol::{DailyStats, LeaderboardStat, UserStats};
+
+use crate::db::StatsDb;
+use crate::db::stats::{FileActivityRecord, UserStatsRecord};
+
+/// How often pending counters are written to the database
+pub const STATS_FLUSH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Maximum number of days in a statistics report
+pub const MAX_STATS_DAYS: u16 = 365;
+
+/// In-memory counters not yet written to the database
+#[derive(Debug, Default)]
+pub struct StatsCollector {
+    /// Daily counters, keyed by day
+    pending: Mutex<BTreeMap<String, DailyStats>>,
+    /// Per-file activity, keyed by path relative to the file root
+    files: Mutex<HashMap<String, PendingFile>>,
+    /// Per-account counters, keyed by account ID
+    users: Mutex<HashMap<i64, UserStats>>,
+}
+
+/// Counters taken from the collector to be written together
+#[derive(Debug, Default)]
+struct Pending {
+    days: BTreeMap<String, DailyStats>,
+    files: HashMap<String, PendingFile>,
+    users: HashMap<i64, UserStats>,
+}
+
+/// Activity for one file not yet written to the database
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct PendingFile {
+    downloads: u64,
+    /// Set when the file was uploaded since the last flush
+    uploaded_at: Option<i64>,
+}
+
+impl StatsCollector {
+    /// Create an empty collector
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count a successful login by an account and note when it happened
+    pub fn record_login(&self, user_id: i64) {
+        let now = Utc::now().timestamp();
+        self.update(|day| day.logins += 1);
+        self.update_user(user_id, |user| {
+            user.logins += 1;
+            user.last_login_at = Some(now);
+        });
+    }
+
+    /// Count a chat or private message sent by an account
+    pub fn record_message(&self, user_id: i64) {
+        self.update(|day| day.messages += 1);
+        self.update_user(user_id, |user| user.messages += 1);
+    }
+
+    /// Count a completed upload by an account and the bytes it received
+    pub fn record_upload(&self, user_id: i64, bytes: u64) {
+        self.update(|day| {
+            day.uploads += 1;
+            day.upload_bytes += bytes;
+        });
+        self.update_user(user_id, |user| user.uploads += 1);
+    }
+
+    /// Count a completed download by an account and the bytes it sent
+    pub fn record_download(&self, user_id: i64, bytes: u64) {
+        self.update(|day| {
+            day.downloads += 1;
+            day.download_bytes += bytes;
+        });
+        self.update_user(user_id, |user| user.download_bytes += bytes);
+    }
+
+    /// Count a completed download of one file
+    ///
+    /// Files outside `file_root` (reached through a symlink) aren't tracked.
+    pub fn record_file_download(&self, file_root: &Path, path: &Path) {
+        if let Some(key) = file_key(file_root, path) {
+            self.lock_files().entry(key).or_default().downloads += 1;
+        }
+    }
+
+    /// Note that a file was uploaded now
+    ///
+    /// A new file starts with no downloads, even if one with the same path
+    /// was downloaded before.
+    pub fn record_file_upload(&self, file_root: &Path, path: &Path) {
+        if let Some(key) = file_key(file_root, path) {
+            let uploaded_at = Utc::now().timestamp();
+            self.lock_files().insert(
+                key,
+                PendingFile {
+                    downloads: 0,
+                    uploaded_at: Some(uploaded_at),
+                },
+            );
+        }
+    }
+
+    /// Note the current number of sessions, keeping the day's peak
+    pub fn record_sessions(&self, sessions: usize) {
+        let sessions = u32::try_from(sessions).unwrap_or(u32::MAX);
+        self.update(|day| day.peak_sessions = day.peak_sessions.max(sessions));
+    }
+
+    /// Add the pending counters to the database
+    ///
+    /// Everything is written in one transaction. Counters that couldn't be
+    /// written are kept for the next flush.
+    pub async fn flush(&self, db: &StatsDb) -> Result<(), sqlx::Error> {
+        let pending = Pending {
+            days: std::mem::take(&mut *self.lock()),
+            files: std::mem::take(&mut *self.lock_files()),
+            users: std::mem::take(&mut *self.lock_users()),
+        };
+        self.write(db, pending).await
+    }
+
+    /// Get an account's lifetime counters
+    pub async fn user_stats(&self, db: &StatsDb, user_id: i64) -> Result<UserStats, sqlx::Error> {
+        self.flush_users(db).await?;
+        db.get_user_stats(user_id).await
+    }
+
+    /// Get the regular accounts with the highest value of `stat`
+    pub async fn leaderboard(
+        &self,
+        db: &StatsDb,
+        stat: LeaderboardStat,
+        limit: usize,
+    ) -> Result<Vec<UserStatsRecord>, sqlx::Error> {
+        self.flush_users(db).await?;
+        db.leaderboard(stat, limit).await
+    }
+
+    /// Get the most downloaded files whose path starts with `prefix`
+    pub async fn popular_files(
+        &self,
+        db: &StatsDb,
+        prefix: &str,
+        limit: usize,
+    ) -> Result<Vec<FileActivityRecord>, sqlx::Error> {
+        self.flush_files(db).await?;
+        db.popular_files(prefix, limit).await
+    }
+
+    /// Get files uploaded since `since` whose path starts with `prefix`, newest first
+    pub async fn recent_files(
+        &self,
+        db: &StatsDb,
+        prefix: &str,
+        since: i64,
+        limit: usize,
+    ) -> Result<Vec<FileActivityRecord>, sqlx::Error> {
+        self.flush_files(db).await?;
+        db.recent_files(prefix, since, limit).await
+    }
+
+    /// Follow a renamed or moved file or directory
+    pub async fn move_files(
+        &self,
+        db: &StatsDb,
+        file_root: &Path,
+        from: &Path,
+        to: &Path,
+    ) -> Result<(), sqlx::Error> {
+        let (Some(from), Some(to)) = (file_key(file_root, from), file_key(file_root, to)) else {
+            return Ok(());
+        };
+
+        {
+            let mut files = self.lock_files();
+            let from_dir = format!("{from}/");
+            let moved: Vec<String> = files
+                .keys()
+                .filter(|key| **key == from || key.starts_with(&from_dir))
+                .cloned()
+                .collect();
+            for key in moved {
+                if let Some(activity) = files.remove(&key) {
+                    files.insert(format!("{to}{}", &key[from.len()..]), activity);
+                }
+            }
+        }
+
+        db.move_file_activity(&from, &to).await
+    }
+
+    /// Add the pending per-file activity to the database
+    async fn flush_files(&self, db: &StatsDb) -> Result<(), sqlx::Error> {
+        let pending = Pending {
+            files: std::mem::take(&mut *self.lock_files()),
+            ..Pending::default()
+        };
+        self.write(db, pending).await
+    }
+
+    /// Add the pending per-account counters to the database
+    async fn flush_users(&self, db: &StatsDb) -> Result<(), sqlx::Error> {
+        let pending = Pending {
+            users: std::mem::take(&mut *self.lock_users()),
+            ..Pending::default()
+        };
+        self.write(db, pending).await
+    }
+
+    /// Write counters taken from the collector, putting them back if that fails
+    async fn write(&self, db: &StatsDb, pending: Pending) -> Result<(), sqlx::Error> {
+        let days: Vec<DailyStats> = pending.days.into_values().collect();
+        let files: Vec<FileActivityRecord> = pending
+            .files
+            .into_iter()
+            .map(|(path, activity)| FileActivityRecord {
+                path,
+                downloads: activity.downloads,
+                uploaded_at: activity.uploaded_at,
+            })
+            .collect();
+        let users: Vec<(i64, UserStats)> = pending.users.into_iter().collect();
+
+        let result = db.add_batch(&days, &files, &users).await;
+        if result.is_err() {
+            // Merge with whatever was counted while the write was running
+            let mut current = self.lock();
+            for counters in days {
+                let date = counters.date.clone();
+                merge(
+                    current
+                        .entry(date.clone())
+                        .or_insert_with(|| empty_day(date)),
+                    &counters,
+                );
+            }
+            drop(current);
+
+            let mut current = self.lock_files();
+            for file in files {
+                let older = PendingFile {
+                    downloads: file.downloads,
+                    uploaded_at: file.uploaded_at,
+                };
+                let newer = current.get(&file.path).copied();
+                current.insert(file.path, merge_file(older, newer));
+            }
+            drop(current);
+
+            let mut current = self.lock_users();
+            for (user_id, counters) in users {
+                merge_user(current.entry(user_id).or_default(), &counters);
+            }
+        }
+        result
+    }
+
+    /// Get the totals for the last `days` days (ending today), oldest first
+    ///
+    /// `days` is clamped to 1..=[`MAX_STATS_DAYS`]. Every day in the range is
+    /// returned, with zeros for days without activity, and counters that
+    /// haven't been flushed yet are included.
+    pub async fn report(&self, db: &StatsDb, days: u16) -> Result<Vec<DailyStats>, sqlx::Error> {
+        let days = days.clamp(1, MAX_STATS_DAYS);
+        let first = Utc::now().date_naive() - chrono::Duration::days(i64::from(days - 1));
+        let first_date = format_day(first);
+
+        let mut by_day: BTreeMap<String, DailyStats> = db
+            .list_since(&first_date)
+            .await?
+            .into_iter()
+            .map(|day| (day.date.clone(), day))
+            .collect();
+        for (date, counters) in self.lock().range(first_date..) {
+            merge(
+                by_day
+                    .entry(date.clone())
+                    .or_insert_with(|| empty_day(date.clone())),
+                counters,
+            );
+        }
+
+        Ok((0..i64::from(days))
+            .map(|offset| {
+                let date = format_day(first + chrono::Duration::days(offset));
+                by_day.remove(&date).unwrap_or_else(|| empty_day(date))
+            })
+            .collect())
+    }
+
+    /// Apply a change to today's counters
+    fn update(&self, change: impl FnOnce(&mut DailyStats)) {
+        let date = format_day(Utc::now().date_naive());
+        let mut pending = self.lock();
+        change(
+            pending
+                .entry(date.clone())
+                .or_insert_with(|| empty_day(date)),
+        );
+    }
+
+    /// Apply a change to an account's counters
+    fn update_user(&self, user_id: i64, change: impl FnOnce(&mut UserStats)) {
+        change(self.lock_users().entry(user_id).or_default());
+    }
+
+    fn lock(&self) -> MutexGuard<'_, BTreeMap<String, DailyStats>> {
+        self.pending.lock().expect("stats lock poisoned")
+    }
+
+    fn lock_files(&self) -> MutexGuard<'_, HashMap<String, PendingFile>> {
+        self.files.lock().expect("stats lock poisoned")
+    }
+
+    fn lock_users(&self) -> MutexGuard<'_, HashMap<i64, UserStats>> {
+        self.users.lock().expect("stats lock poisoned")
+    }
+}
+
+/// Get a file's path relative to the file root (`/shared/song.mp3`)
+///
+/// Returns `None` for paths outside the file root.
+pub fn file_key(file_root: &Path, path: &Path) -> Option<String> {
+    let relative = path.strip_prefix(file_root).ok()?;
+    Some(format!(
+        "/{}",
+        relative.to_string_lossy().replace('\\', "/")
+    ))
+}
+
+/// Combine a file's unwritten activity with activity recorded after it
+///
+/// An upload in the newer activity replaces everything before it.
+fn merge_file(older: PendingFile, newer: Option<PendingFile>) -> PendingFile {
+    match newer {
+        None => older,
+        Some(newer) if newer.uploaded_at.is_some() => newer,
+        Some(newer) => PendingFile {
+            downloads: older.downloads + newer.downloads,
+            uploaded_at: older.uploaded_at,
+        },
+    }
+}
+
+/// Add one set of counters to another (peaks keep the higher value)
+fn merge(total: &mut DailyStats, counters: &DailyStats) {
+    total.logins += counters.logins;
+    total.messages += counters.messages;
+    total.uploads += counters.uploads;
+    total.upload_bytes += counters.upload_bytes;
+    total.downloads += counters.downloads;
+    total.download_bytes += counters.download_bytes;
+    total.peak_sessions = total.peak_sessions.max(counters.peak_sessions);
+}
+
+/// Add one account's counters to another's (the later login time is kept)
+fn merge_user(total: &mut UserStats, counters: &UserStats) {
+    total.logins += counters.logins;
+    total.messages += counters.messages;
+    total.uploads += counters.uploads;
+    total.download_bytes += counters.download_bytes;
+    total.last_login_at = total.last_login_at.max(counters.last_login_at);
+}
+
+/// Zeroed counters for a day
+fn empty_day(date: String) -> DailyStats {
+    DailyStats {
+        date,
+        ..Default::default()
+    }
+}
+
+/// Format a day as stored in the database (`YYYY-MM-DD`)
+fn format_day(day: NaiveDate) -> String {
+    day.format("%Y-%m-%d").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::testing::create_test_db;
+    use crate::db::{Permissions, UserDb};
+
+    fn today() -> String {
+        format_day(Utc::now().date_naive())
+    }
+
+    #[tokio::test]
+    async fn test_report_includes_pending_counters() {
+        let db = StatsDb::new(create_test_db().await);
+        let collector = StatsCollector::new();
+
+        collector.record_login(1);
+        collector.record_message(1);
+        collector.record_message(2);
+        collector.record_upload(1, 100);
+        collector.record_download(2, 250);
+        collector.record_sessions(3);
+        collector.record_sessions(2);
+
+        let report = collector.report(&db, 1).await.unwrap();
+        assert_eq!(report.len(), 1);
+        assert_eq!(report[0].date, today());
+        assert_eq!(report[0].logins, 1);
+        assert_eq!(report[0].messages, 2);
+        assert_eq!(report[0].uploads, 1);
+        assert_eq!(report[0].upload_bytes, 100);
+        assert_eq!(report[0].downloads, 1);
+        assert_eq!(report[0].download_bytes, 250);
+        assert_eq!(report[0].peak_sessions, 3);
+    }
+
+    #[tokio::test]
+    async fn test_flush_moves_counters_to_database() {
+        let db = StatsDb::new(create_test_db().await);
+        let collector = StatsCollector::new();
+
+        collector.record_login(1);
+        collector.record_sessions(4);
+        collector.flush(&db).await.unwrap();
+        assert!(collector.lock().is_empty());
+
+        // New counters are added to the stored ones
+        collector.record_login(1);
+        collector.record_sessions(1);
+
+        let report = collector.report(&db, 1).await.unwrap();
+        assert_eq!(report[0].logins, 2);
+        assert_eq!(report[0].peak_sessions, 4);
+
+        let stored = db.list_since(&today()).await.unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].logins, 1);
+    }
+
+    #[tokio::test]
+    async fn test_failed_flush_keeps_counters() {
+        let db = StatsDb::new(create_test_db().await);
+        let collector = StatsCollector::new();
+
+        collector.record_login(1);
+        collector.record_file_download(Path::new("/srv/files"), Path::new("/srv/files/a.txt"));
+
+        // Nothing is lost when the write fails, and later counts add up
+        let closed = create_test_db().await;
+        closed.close().await;
+        let closed = StatsDb::new(closed);
+        assert!(collector.flush(&closed).await.is_err());
+        collector.record_login(1);
+        assert_eq!(collector.lock()[&today()].logins, 2);
+        assert_eq!(collector.lock_files()["/a.txt"].downloads, 1);
+
+        collector.flush(&db).await.unwrap();
+        let stored = db.list_since(&today()).await.unwrap();
+        assert_eq!(stored[0].logins, 2);
+        assert_eq!(db.popular_files("/", 10).await.unwrap()[0].downloads, 1);
+    }
+
+    #[tokio::test]
+    async fn test_report_fills_missing_days() {
+        let db = StatsDb::new(create_test_db().await);
+        let collector = StatsCollector::new();
+
+        let report = collector.report(&db, 7).await.unwrap();
+        assert_eq!(report.len(), 7);
+        assert_eq!(report[6].date, today());
+        assert!(report.windows(2).all(|pair| pair[0].date < pair[1].date));
+        assert!(report.iter().all(|day| day.logins == 0));
+    }
+
+    #[tokio::test]
+    async fn test_file_activity_is_flushed_before_listing() {
+        let db = StatsDb::new(create_test_db().await);
+        let collector = StatsCollector::new();
+        let root = Path::new("/srv/files");
+
+        collector.record_file_upload(root, &root.join("shared/new.txt"));
+        collector.record_file_download(root, &root.join("shared/new.txt"));
+        collector.record_file_download(root, &root.join("shared/new.txt"));
+        collector.record_file_download(root, &root.join("shared/old.txt"));
+        // Outside the file root
+        collector.record_file_download(root, Path::new("/etc/passwd"));
+
+        let popular = collector.popular_files(&db, "/", 10).await.unwrap();
+        assert_eq!(popular.len(), 2);
+        assert_eq!(popular[0].path, "/shared/new.txt");
+        assert_eq!(popular[0].downloads, 2);
+        assert_eq!(popular[1].path, "/shared/old.txt");
+        assert!(collector.lock_files().is_empty());
+
+        let recent = collector.recent_files(&db, "/", 0, 10).await.unwrap();
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].path, "/shared/new.txt");
+    }
+
+    #[tokio::test]
+    async fn test_move_files_follows_pending_and_stored_activity() {
+        let db = StatsDb::new(create_test_db().await);
+        let collector = StatsCollector::new();
+        let root = Path::new("/srv/files");
+
+        collector.record_file_download(root, &root.join("shared/docs/a.txt"));
+        collector.flush(&db).await.unwrap();
+        collector.record_file_download(root, &root.join("shared/docs/a.txt"));
+
+        collector
+            .move_files(
+                &db,
+                root,
+                &root.join("shared/docs"),
+                &root.join("shared/papers"),
+            )
+            .await
+            .unwrap();
+
+        let popular = collector.popular_files(&db, "/", 10).await.unwrap();
+        assert_eq!(popular.len(), 1);
+        assert_eq!(popular[0].path, "/shared/papers/a.txt");
+        assert_eq!(popular[0].downloads, 2);
+    }
+
+    #[test]
+    fn test_merge_file() {
+        let older = PendingFile {
+            downloads: 2,
+            uploaded_at: Some(100),
+        };
+        let downloads = PendingFile {
+            downloads: 3,
+            uploaded_at: None,
+        };
+        let upload = PendingFile {
+            downloads: 0,
+            uploaded_at: Some(200),
+        };
+
+        assert_eq!(merge_file(older, None), older);
+        assert_eq!(
+            merge_file(older, Some(downloads)),
+            PendingFile {
+                downloads: 5,
+                uploaded_at: Some(100),
+            }
+        );
+        assert_eq!(merge_file(older, Some(upload)), upload);
+    }
+
+    #[tokio::test]
+    async fn test_user_stats_include_pending_counters() {
+        let pool = create_test_db().await;
+        let db = StatsDb::new(pool.clone());
+        let alice = UserDb::new(pool)
+            .create_user("alice", "hash", false, false, true, &Permissions::new())
+            .await
+            .unwrap();
+        let collector = StatsCollector::new();
+
+        collector.record_login(alice.id);
+        collector.record_download(alice.id, 300);
+        collector.flush(&db).await.unwrap();
+        collector.record_login(alice.id);
+        collector.record_upload(alice.id, 100);
+
+        let stats = collector.user_stats(&db, alice.id).await.unwrap();
+        assert_eq!(stats.logins, 2);
+        assert_eq!(stats.uploads, 1);
+        assert_eq!(stats.download_bytes, 300);
+        assert!(stats.last_login_at.is_some());
+        assert!(collector.lock_users().is_empty());
+
+        let top = collector
+            .leaderboard(&db, LeaderboardStat::DownloadBytes, 10)
+            .await
+            .unwrap();
+        assert_eq!(top.len(), 1);
+        assert_eq!(top[0].username, "alice");
+    }
+
+    #[tokio::test]
+    async fn test_failed_flush_keeps_latest_login() {
+        let pool = create_test_db().await;
+        let db = StatsDb::new(pool.clone());
+        let alice = UserDb::new(pool)
+            .create_user("alice", "hash", false, false, true, &Permissions::new())
+            .await
+            .unwrap();
+        let collector = StatsCollector::new();
+
+        collector.update_user(alice.id, |user| user.last_login_at = Some(200));
+        let closed = create_test_db().await;
+        closed.close().await;
+        assert!(collector.flush(&StatsDb::new(closed)).await.is_err());
+        assert_eq!(collector.lock_users()[&alice.id].last_login_at, Some(200));
+
+        // A stored login time isn't replaced by an older one
+        collector.flush(&db).await.unwrap();
+        collector.update_user(alice.id, |user| user.last_login_at = Some(150));
+        let stats = collector.user_stats(&db, alice.id).await.unwrap();
+        assert_eq!(stats.last_login_at, Some(200));
+    }
+
+    #[tokio::test]
+    async fn test_report_clamps_days() {
+        let db = StatsDb::new(create_test_db().await);
+        let collector = StatsCollector::new();
+
+        assert_eq!(collector.report(&db, 0).await.unwrap().len(), 1);
+        assert_eq!(
+            collector.report(&db, u16::MAX).await.unwrap().len(),
+            usize::from(MAX_STATS_DAYS)
+        );
+    }
+}
@@ -0,0 +1,257 @@
+//! Disk space monitoring
+//!
+//! The server keeps `--min-free-space` bytes free on the disks holding the
+//! file area and the database:
+//!
+//! - An upload that would leave less than that free in the file area is
+//!   refused with `error_kind: "insufficient_storage"` before it's queued.
+//! - Both disks are checked every [`STORAGE_CHECK_INTERVAL_SECS`]. When one
+//!   drops below the minimum or recovers, online admins get a `StorageAlert`
+//!   and the alert is posted to `--storage-webhook` if one is set.
+//!
+//! A minimum of 0 turns both off.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use nexus_common::protocol::{ServerMessage, StorageVolume};
+use serde_json::json;
+
+use crate::constants::STORAGE_CHECK_INTERVAL_SECS;
+use crate::https::{self, HttpsUrl};
+use crate::users::UserManager;
+
+/// Storage settings from the command line
+///
+/// The default keeps no minimum, so the safeguards are off.
+#[derive(Debug, Clone, Default)]
+pub struct StorageConfig {
+    /// Bytes to keep free on each watched disk (0 turns the safeguards off)
+    pub min_free: u64,
+    /// `https://` URL to post alerts to, if any
+    pub webhook: Option<String>,
+}
+
+impl StorageConfig {
+    /// Check the settings before the server starts
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(webhook) = &self.webhook {
+            HttpsUrl::parse(webhook)?;
+        }
+        Ok(())
+    }
+
+    /// Whether `size` more bytes fit on the disk holding `path` while keeping
+    /// the minimum free
+    ///
+    /// Allowed when the safeguards are off or the free space can't be read.
+    pub fn has_room(&self, path: &Path, size: u64) -> bool {
+        if self.min_free == 0 {
+            return true;
+        }
+        match fs4::available_space(path) {
+            Ok(free) => free.saturating_sub(size) >= self.min_free,
+            Err(_) => true,
+        }
+    }
+}
+
+/// Free and total bytes on the disk holding `path` (None if they can't be read)
+pub fn disk_space(path: &Path) -> (Option<u64>, Option<u64>) {
+    (fs4::available_space(path).ok(), fs4::total_space(path).ok())
+}
+
+/// A disk the monitor watches, and whether it was low at the last check
+struct Watched {
+    volume: StorageVolume,
+    path: PathBuf,
+    low: bool,
+}
+
+/// Watch the file area and database disks, alerting when either crosses the
+/// minimum (runs forever)
+pub async fn run_monitor(
+    config: StorageConfig,
+    user_manager: UserManager,
+    file_root: PathBuf,
+    database_dir: PathBuf,
+) {
+    let min_free = config.min_free;
+    if min_free == 0 {
+        std::future::pending::<()>().await;
+        return;
+    }
+
+    let mut watched = [
+        Watched {
+            volume: StorageVolume::Files,
+            path: file_root,
+            low: false,
+        },
+        Watched {
+            volume: StorageVolume::Database,
+            path: database_dir,
+            low: false,
+        },
+    ];
+
+    loop {
+        for disk in &mut watched {
+            let (Some(free), Some(total)) = disk_space(&disk.path) else {
+                continue;
+            };
+            let low = free < min_free;
+            if low == disk.low {
+                continue;
+            }
+            disk.low = low;
+
+            if low {
+                log_warn!(
+                    "Low disk space for the {}: {} bytes free, minimum is {}",
+                    volume_name(disk.volume),
+                    free,
+                    min_free
+                );
+            } else {
+                log_info!(
+                    "Disk space for the {} recovered: {} bytes free",
+                    volume_name(disk.volume),
+                    free
+                );
+            }
+
+            let alert = ServerMessage::StorageAlert {
+                volume: disk.volume,
+                low,
+                free,
+                total,
+                threshold: min_free,
+            };
+            user_manager
+                .broadcast_where(alert, |user| user.is_admin)
+                .await;
+            if let Some(webhook) = &config.webhook {
+                post_webhook(webhook, disk.volume, low, free, total, min_free).await;
+            }
+        }
+        tokio::time::sleep(Duration::from_secs(STORAGE_CHECK_INTERVAL_SECS)).await;
+    }
+}
+
+/// Post an alert to the webhook
+async fn post_webhook(
+    webhook: &str,
+    volume: StorageVolume,
+    low: bool,
+    free: u64,
+    total: u64,
+    threshold: u64,
+) {
+    let url = match HttpsUrl::parse(webhook) {
+        Ok(url) => url,
+        Err(e) => {
+            log_warn!("Failed to post storage alert to webhook: {}", e);
+            return;
+        }
+    };
+
+    let body = webhook_body(volume, low, free, total, threshold);
+    match https::post_json(&url, &body).await {
+        Ok(response) if (200..300).contains(&response.status) => {}
+        Ok(response) => log_warn!("Storage webhook answered with status {}", response.status),
+        Err(e) => log_warn!("Failed to post storage alert to webhook: {}", e),
+    }
+}
+
+/// JSON posted to the webhook
+///
+/// `text` carries a readable summary, which chat services with incoming
+/// webhooks (Slack, Mattermost, ...) show as the message.
+fn webhook_body(volume: StorageVolume, low: bool, free: u64, total: u64, threshold: u64) -> String {
+    let text = if low {
+        format!(
+            "Nexus: low disk space for the {} ({} bytes free, minimum is {})",
+            volume_name(volume),
+            free,
+            threshold
+        )
+    } else {
+        format!(
+            "Nexus: disk space for the {} recovered ({} bytes free)",
+            volume_name(volume),
+            free
+        )
+    };
+    json!({
+        "event": if low { "storage_low" } else { "storage_recovered" },
+        "volume": volume,
+        "free": free,
+        "total": total,
+        "threshold": threshold,
+        "text": text,
+    })
+    .to_string()
+}
+
+/// Name of a volume in log lines and webhook text
+fn volume_name(volume: StorageVolume) -> &'static str {
+    match volume {
+        StorageVolume::Files => "file area",
+        StorageVolume::Database => "database",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_has_room() {
+        // Without a minimum every upload fits
+        let off = StorageConfig::default();
+        assert!(off.has_room(Path::new("."), u64::MAX));
+
+        // No disk has this much free
+        let strict = StorageConfig {
+            min_free: u64::MAX,
+            webhook: None,
+        };
+        assert!(!strict.has_room(Path::new("."), 0));
+    }
+
+    #[test]
+    fn test_validate_webhook() {
+        let config = |webhook: Option<&str>| StorageConfig {
+            min_free: 0,
+            webhook: webhook.map(str::to_string),
+        };
+        assert!(config(None).validate().is_ok());
+        assert!(
+            config(Some("https://hooks.example.com/alert"))
+                .validate()
+                .is_ok()
+        );
+        assert!(
+            config(Some("http://hooks.example.com/alert"))
+                .validate()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_webhook_body() {
+        let body: serde_json::Value =
+            serde_json::from_str(&webhook_body(StorageVolume::Files, true, 5, 100, 10)).unwrap();
+        assert_eq!(body["event"], "storage_low");
+        assert_eq!(body["volume"], "files");
+        assert_eq!(body["free"], 5);
+        assert!(body["text"].as_str().unwrap().contains("file area"));
+
+        let body: serde_json::Value =
+            serde_json::from_str(&webhook_body(StorageVolume::Database, false, 50, 100, 10))
+                .unwrap();
+        assert_eq!(body["event"], "storage_recovered");
+        assert_eq!(body["volume"], "database");
+    }
+}
@@ -18,13 +18,17 @@ use crate::db::sql::GUEST_USERNAME;
 use crate::db::{self, Database};
 use crate::handlers::{
     err_account_disabled, err_authentication, err_database, err_guest_disabled,
-    err_handshake_required, err_invalid_credentials, err_message_not_supported, err_not_logged_in,
-    err_version_client_too_new, err_version_empty, err_version_invalid_semver,
-    err_version_major_mismatch, err_version_too_long,
+    err_handshake_required, err_invalid_credentials, err_ldap_unavailable, err_login_token_expired,
+    err_message_not_supported, err_not_logged_in, err_version_client_too_new, err_version_empty,
+    err_version_invalid_semver, err_version_major_mismatch, err_version_too_long,
 };
+use crate::ldap::{Ldap, LdapError};
+use crate::login_tokens::LoginTokens;
 
 use super::helpers::{login_error_response, send_error_and_close};
-use super::types::{AuthenticatedUser, DownloadParams, TransferRequest, UploadParams};
+use super::types::{
+    AuthenticatedUser, DownloadParams, ExportParams, PortRequest, TransferRequest, UploadParams,
+};
 
 /// Handle the handshake phase for transfer connections
 pub(crate) async fn handle_transfer_handshake<R, W>(
@@ -46,12 +50,17 @@ where
     };
 
     let version = match received.message {
-        ClientMessage::Handshake { version } => version,
+        ClientMessage::Handshake { version, .. } => version,
         _ => {
             let response = ServerMessage::HandshakeResponse {
                 success: false,
                 version: Some(server_version_str.to_string()),
                 error: Some(err_handshake_required(locale)),
+                identity_key: None,
+                identity_signature: None,
+                pow_challenge: None,
+                pow_difficulty: None,
+                oidc: None,
             };
             send_server_message_with_id(frame_writer, &response, received.message_id).await?;
             return Err(io::Error::other("Expected Handshake message"));
@@ -73,6 +82,11 @@ where
                 success: false,
                 version: Some(server_version_str.to_string()),
                 error: Some(error_msg),
+                identity_key: None,
+                identity_signature: None,
+                pow_challenge: None,
+                pow_difficulty: None,
+                oidc: None,
             };
             send_server_message_with_id(frame_writer, &response, received.message_id).await?;
             return Err(io::Error::other("Invalid version string"));
@@ -86,6 +100,11 @@ where
                 success: true,
                 version: Some(server_version_str.to_string()),
                 error: None,
+                identity_key: None,
+                identity_signature: None,
+                pow_challenge: None,
+                pow_difficulty: None,
+                oidc: None,
             };
             send_server_message_with_id(frame_writer, &response, received.message_id).await?;
             Ok(())
@@ -102,6 +121,11 @@ where
                     server_major,
                     client_major,
                 )),
+                identity_key: None,
+                identity_signature: None,
+                pow_challenge: None,
+                pow_difficulty: None,
+                oidc: None,
             };
             send_server_message_with_id(frame_writer, &response, received.message_id).await?;
             Err(io::Error::other("Major version mismatch"))
@@ -118,6 +142,11 @@ where
                     server_version_str,
                     &version,
                 )),
+                identity_key: None,
+                identity_signature: None,
+                pow_challenge: None,
+                pow_difficulty: None,
+                oidc: None,
             };
             send_server_message_with_id(frame_writer, &response, received.message_id).await?;
             Err(io::Error::other(format!(
@@ -132,6 +161,8 @@ pub(crate) async fn handle_transfer_login<R, W>(
     frame_reader: &mut FrameReader<R>,
     frame_writer: &mut FrameWriter<W>,
     db: &Database,
+    ldap: &Ldap,
+    login_tokens: &LoginTokens,
     locale: &mut String,
 ) -> io::Result<AuthenticatedUser>
 where
@@ -145,20 +176,22 @@ where
         Err(e) => return Err(io::Error::other(format!("Failed to read login: {e}"))),
     };
 
-    let (raw_username, password, request_locale, request_nickname) = match received.message {
-        ClientMessage::Login {
-            username,
-            password,
-            locale: req_locale,
-            nickname,
-            ..
-        } => (username, password, req_locale, nickname),
-        _ => {
-            let response = login_error_response(err_not_logged_in(locale));
-            send_server_message_with_id(frame_writer, &response, received.message_id).await?;
-            return Err(io::Error::other("Expected Login message"));
-        }
-    };
+    let (raw_username, password, request_locale, request_nickname, login_token) =
+        match received.message {
+            ClientMessage::Login {
+                username,
+                password,
+                locale: req_locale,
+                nickname,
+                login_token,
+                ..
+            } => (username, password, req_locale, nickname, login_token),
+            _ => {
+                let response = login_error_response(err_not_logged_in(locale));
+                send_server_message_with_id(frame_writer, &response, received.message_id).await?;
+                return Err(io::Error::other("Expected Login message"));
+            }
+        };
 
     // Update locale for subsequent error messages
     *locale = request_locale.clone();
@@ -186,11 +219,23 @@ where
         return Err(io::Error::other("Invalid password"));
     }
 
-    // Look up user
-    let account = match db.users.get_user_by_username(&username).await {
+    // Look up user (by the token from the main login, if the client sent one)
+    let lookup = match &login_token {
+        Some(token) if validators::validate_login_token(token).is_ok() => {
+            login_tokens.account(db, token).await
+        }
+        Some(_) => Ok(None),
+        None => db.users.get_user_by_username(&username).await,
+    };
+    let account = match lookup {
         Ok(Some(acc)) => acc,
         Ok(None) => {
-            let response = login_error_response(err_invalid_credentials(locale));
+            let error_msg = if login_token.is_some() {
+                err_login_token_expired(locale)
+            } else {
+                err_invalid_credentials(locale)
+            };
+            let response = login_error_response(error_msg);
             send_server_message_with_id(frame_writer, &response, received.message_id).await?;
             return Err(io::Error::other("User not found"));
         }
@@ -201,8 +246,30 @@ where
         }
     };
 
-    // Verify password
-    let password_valid = if account.hashed_password.is_empty() {
+    // Directory accounts are checked against the directory
+    let directory_valid = if login_token.is_some() {
+        None
+    } else {
+        match ldap.check_password(db, &account, &password).await {
+            Ok(valid) => valid,
+            Err(e) => {
+                let error_msg = match e {
+                    LdapError::Database(_) => err_database(locale),
+                    _ => err_ldap_unavailable(locale),
+                };
+                let response = login_error_response(error_msg);
+                send_server_message_with_id(frame_writer, &response, received.message_id).await?;
+                return Err(io::Error::other(format!("Directory login failed: {e}")));
+            }
+        }
+    };
+
+    // Verify password (a login token stands in for it)
+    let password_valid = if login_token.is_some() {
+        true
+    } else if let Some(valid) = directory_valid {
+        valid
+    } else if account.hashed_password.is_empty() {
         // Guest account - password must be empty
         password.is_empty()
     } else {
@@ -226,10 +293,10 @@ where
 
     // Check if account is enabled
     if !account.enabled {
-        let error_msg = if username.to_lowercase() == GUEST_USERNAME {
+        let error_msg = if account.username.to_lowercase() == GUEST_USERNAME {
             err_guest_disabled(locale)
         } else {
-            err_account_disabled(locale, &username)
+            err_account_disabled(locale, &account.username)
         };
         let response = login_error_response(error_msg);
         send_server_message_with_id(frame_writer, &response, received.message_id).await?;
@@ -241,7 +308,10 @@ where
         HashSet::new()
     } else {
         match db.users.get_user_permissions(account.id).await {
-            Ok(perms) => perms.permissions,
+            Ok(mut perms) => {
+                perms.restrict_for_account(&account);
+                perms.permissions
+            }
             Err(_) => HashSet::new(),
         }
     };
@@ -264,10 +334,12 @@ where
         channels: None,
         locale: None,
         nickname: None,
+        login_token: None,
     };
     send_server_message_with_id(frame_writer, &response, received.message_id).await?;
 
     Ok(AuthenticatedUser {
+        user_id: account.id,
         nickname,
         username: account.username,
         is_admin: account.is_admin,
@@ -276,12 +348,12 @@ where
     })
 }
 
-/// Handle transfer request (FileDownload or FileUpload)
+/// Handle transfer request (FileDownload, FileUpload or ServerExport)
 pub(crate) async fn handle_transfer_request<R, W>(
     frame_reader: &mut FrameReader<R>,
     frame_writer: &mut FrameWriter<W>,
     locale: &str,
-) -> io::Result<TransferRequest>
+) -> io::Result<PortRequest>
 where
     R: AsyncReadExt + Unpin,
     W: AsyncWriteExt + Unpin,
@@ -294,20 +366,35 @@ where
     };
 
     match received.message {
-        ClientMessage::FileDownload { path, root } => {
-            Ok(TransferRequest::Download(DownloadParams { path, root }))
-        }
-        ClientMessage::FileUpload {
-            destination,
-            file_count,
-            total_size,
+        ClientMessage::FileDownload {
+            path,
             root,
-        } => Ok(TransferRequest::Upload(UploadParams {
+            include,
+        } => Ok(PortRequest::Transfer(TransferRequest::Download(
+            DownloadParams {
+                path,
+                root,
+                include,
+            },
+        ))),
+        ClientMessage::FileUpload {
             destination,
             file_count,
             total_size,
             root,
-        })),
+            overwrite,
+        } => Ok(PortRequest::Transfer(TransferRequest::Upload(
+            UploadParams {
+                destination,
+                file_count,
+                total_size,
+                root,
+                overwrite,
+            },
+        ))),
+        ClientMessage::ServerExport { include_ip_rules } => {
+            Ok(PortRequest::Export(ExportParams { include_ip_rules }))
+        }
         _ => {
             send_error_and_close(frame_writer, &err_message_not_supported(locale)).await?;
             Err(io::Error::other(
@@ -0,0 +1,273 @@
+//! File area cleanup
+//!
+//! Aborted transfers can leave junk behind that the hourly journal sweep
+//! (see [`sweep_upload_journal`](super::sweep_upload_journal)) doesn't see:
+//!
+//! - `.part` files with no journal entry, such as ones left from before the
+//!   journal existed or whose entry was lost
+//! - the search index's temp file, when a rebuild was cut off
+//! - journal entries whose `.part` file is gone
+//! - search index entries whose file is gone
+//!
+//! A scan reports them and, when asked, deletes them. A `.part` file without
+//! a journal entry only counts once it's been untouched for the stale upload
+//! age, and the report lists paths so an admin can check them first, since
+//! users can upload files whose names end in `.part`. The server runs a
+//! report-only scan once a day; admins delete with `FileCleanup`.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use nexus_common::protocol::FileCleanupReport;
+use walkdir::WalkDir;
+
+use super::journal::PartClaims;
+use crate::constants::FILE_CLEANUP_REPORT_PATHS;
+use crate::db::UploadJournalDb;
+use crate::files::FileIndex;
+use crate::stats::file_key;
+
+/// Extension of the files uploads are written to
+const PART_EXTENSION: &str = "part";
+
+/// A `.part` file found on disk
+struct PartFile {
+    path: PathBuf,
+    /// Path relative to the file root, as the journal records it
+    key: String,
+    size: u64,
+    modified: SystemTime,
+}
+
+/// Every `.part` file under the file root (blocking)
+///
+/// Symlinks aren't followed, so nothing outside the file area is touched.
+fn find_part_files(file_root: &Path) -> Vec<PartFile> {
+    WalkDir::new(file_root)
+        .min_depth(1)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            entry.file_type().is_file()
+                && entry
+                    .path()
+                    .extension()
+                    .is_some_and(|ext| ext == PART_EXTENSION)
+        })
+        .filter_map(|entry| {
+            let metadata = entry.metadata().ok()?;
+            Some(PartFile {
+                key: file_key(file_root, entry.path())?,
+                size: metadata.len(),
+                modified: metadata.modified().ok()?,
+                path: entry.into_path(),
+            })
+        })
+        .collect()
+}
+
+/// Look for leftovers of aborted transfers, deleting them if `delete` is set
+///
+/// Uploads in progress and `.part` files the journal can still resume are
+/// left alone. Deleting anything the search index lists starts a rebuild.
+pub async fn scan_file_area(
+    journal: &UploadJournalDb,
+    claims: &PartClaims,
+    file_index: &Arc<FileIndex>,
+    file_root: &Path,
+    stale_after: Duration,
+    delete: bool,
+) -> Result<FileCleanupReport, sqlx::Error> {
+    let mut report = FileCleanupReport {
+        deleted: delete,
+        ..Default::default()
+    };
+
+    // Journal entries whose .part file is gone
+    let mut journaled = HashSet::new();
+    for entry in journal.list().await? {
+        let part_path = file_root.join(entry.part_path.trim_start_matches('/'));
+        if !claims.is_claimed(&part_path) && tokio::fs::metadata(&part_path).await.is_err() {
+            report.missing_journal_entries += 1;
+            if delete {
+                journal.remove(&entry.part_path).await?;
+            }
+        }
+        journaled.insert(entry.part_path);
+    }
+
+    // Search index leftovers, counted before any .part file is deleted
+    let index = Arc::clone(file_index);
+    let (temp_size, missing) = tokio::task::spawn_blocking(move || {
+        (index.leftover_temp_file(delete), index.missing_entries())
+    })
+    .await
+    .unwrap_or_default();
+    if let Some(size) = temp_size {
+        report.temp_files = 1;
+        report.temp_bytes = size;
+    }
+    report.missing_index_entries = missing as u32;
+
+    // .part files no upload will resume
+    let root = file_root.to_path_buf();
+    let parts = tokio::task::spawn_blocking(move || find_part_files(&root))
+        .await
+        .unwrap_or_default();
+    let cutoff = SystemTime::now()
+        .checked_sub(stale_after)
+        .unwrap_or(SystemTime::UNIX_EPOCH);
+    for part in parts {
+        if journaled.contains(&part.key) || claims.is_claimed(&part.path) || part.modified > cutoff
+        {
+            continue;
+        }
+        if delete && let Err(e) = tokio::fs::remove_file(&part.path).await {
+            log_error!("Failed to remove {}: {}", part.path.display(), e);
+            continue;
+        }
+        report.orphaned_parts += 1;
+        report.orphaned_bytes += part.size;
+        if report.paths.len() < FILE_CLEANUP_REPORT_PATHS {
+            report.paths.push(part.key);
+        }
+    }
+
+    if delete && (report.orphaned_parts > 0 || report.missing_index_entries > 0) {
+        file_index.trigger_reindex();
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::testing::create_test_db;
+    use tempfile::TempDir;
+
+    /// A file area with a journaled upload, an orphaned .part file and a
+    /// journal entry whose file is gone
+    async fn setup() -> (UploadJournalDb, Arc<FileIndex>, TempDir, TempDir) {
+        let journal = UploadJournalDb::new(create_test_db().await);
+        let data_dir = TempDir::new().unwrap();
+        let file_root = TempDir::new().unwrap();
+        std::fs::create_dir(file_root.path().join("shared")).unwrap();
+        std::fs::write(file_root.path().join("shared/resumable.bin.part"), b"123").unwrap();
+        std::fs::write(file_root.path().join("shared/orphan.bin.part"), b"12345").unwrap();
+        std::fs::write(file_root.path().join("shared/song.mp3"), b"song").unwrap();
+
+        journal
+            .record(
+                "/shared/resumable.bin.part",
+                "/shared/resumable.bin",
+                "alice",
+                "abc",
+                10,
+                3,
+            )
+            .await
+            .unwrap();
+        journal
+            .record(
+                "/shared/gone.bin.part",
+                "/shared/gone.bin",
+                "alice",
+                "def",
+                10,
+                3,
+            )
+            .await
+            .unwrap();
+
+        let file_index = Arc::new(FileIndex::new(data_dir.path(), file_root.path()));
+        (journal, file_index, data_dir, file_root)
+    }
+
+    #[tokio::test]
+    async fn test_scan_reports_without_deleting() {
+        let (journal, file_index, _data_dir, file_root) = setup().await;
+
+        let report = scan_file_area(
+            &journal,
+            &PartClaims::new(),
+            &file_index,
+            file_root.path(),
+            Duration::ZERO,
+            false,
+        )
+        .await
+        .unwrap();
+        assert_eq!(report.orphaned_parts, 1);
+        assert_eq!(report.orphaned_bytes, 5);
+        assert_eq!(report.paths, vec!["/shared/orphan.bin.part"]);
+        assert_eq!(report.missing_journal_entries, 1);
+        assert!(!report.deleted);
+
+        assert!(file_root.path().join("shared/orphan.bin.part").exists());
+        assert_eq!(journal.list().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_scan_skips_recent_and_claimed_parts() {
+        let (journal, file_index, _data_dir, file_root) = setup().await;
+        let claims = PartClaims::new();
+
+        // Not untouched long enough yet
+        let report = scan_file_area(
+            &journal,
+            &claims,
+            &file_index,
+            file_root.path(),
+            Duration::from_secs(3600),
+            true,
+        )
+        .await
+        .unwrap();
+        assert_eq!(report.orphaned_parts, 0);
+
+        // Being written by an upload
+        let orphan = file_root.path().join("shared/orphan.bin.part");
+        let claim = claims.acquire(&orphan).unwrap();
+        let report = scan_file_area(
+            &journal,
+            &claims,
+            &file_index,
+            file_root.path(),
+            Duration::ZERO,
+            true,
+        )
+        .await
+        .unwrap();
+        assert_eq!(report.orphaned_parts, 0);
+        assert!(orphan.exists());
+        drop(claim);
+    }
+
+    #[tokio::test]
+    async fn test_scan_deletes_leftovers() {
+        let (journal, file_index, _data_dir, file_root) = setup().await;
+
+        let report = scan_file_area(
+            &journal,
+            &PartClaims::new(),
+            &file_index,
+            file_root.path(),
+            Duration::ZERO,
+            true,
+        )
+        .await
+        .unwrap();
+        assert_eq!(report.orphaned_parts, 1);
+        assert!(report.deleted);
+
+        assert!(!file_root.path().join("shared/orphan.bin.part").exists());
+        assert!(file_root.path().join("shared/resumable.bin.part").exists());
+        assert!(file_root.path().join("shared/song.mp3").exists());
+        let entries = journal.list().await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].part_path, "/shared/resumable.bin.part");
+    }
+}
@@ -6,6 +6,7 @@
 //! For large files, sends FileHashing keepalive messages periodically during
 //! hash computation to prevent client timeouts.
 
+use std::collections::HashSet;
 use std::io;
 use std::path::Path;
 
@@ -34,7 +35,8 @@ use super::hash::{compute_file_sha256_with_keepalive, compute_partial_sha256_wit
 use super::helpers::{
     TransferError, build_validated_path, check_permission, check_root_permission,
     generate_transfer_id, path_error_to_transfer_error, resolve_area_root,
-    send_download_error_and_close, send_download_transfer_error, validate_transfer_path,
+    send_download_error_and_close, send_download_transfer_error, validate_transfer_include,
+    validate_transfer_path,
 };
 use super::transfer::{StreamError, Transfer};
 use super::types::{AuthenticatedUser, DownloadParams, FileInfo};
@@ -51,6 +53,7 @@ where
     let DownloadParams {
         path: download_path,
         root: use_root,
+        include,
     } = params;
 
     // Extract values to avoid borrow checker issues
@@ -73,6 +76,10 @@ where
         Err(e) => return send_download_transfer_error(transfer.writer(), &e).await,
     };
 
+    if let Err(e) = validate_transfer_include(include.as_deref(), &locale) {
+        return send_download_transfer_error(transfer.writer(), &e).await;
+    }
+
     // Check dropbox access
     if !can_access_for_download(&resolved_path, &username, is_admin) {
         let err = TransferError::permission(err_transfer_access_denied(&locale));
@@ -84,7 +91,7 @@ where
         Ok(files) => files,
         Err(e) => {
             if debug {
-                eprintln!("Failed to scan files from {}: {e}", peer_addr);
+                log_debug!("Failed to scan files from {}: {e}", peer_addr);
             }
             let err_msg = err_transfer_read_failed(&locale);
             return send_download_error_and_close(
@@ -96,6 +103,12 @@ where
         }
     };
 
+    // Keep only the files the client selected from the manifest
+    let files = match include {
+        Some(include) => filter_included_files(files, &include),
+        None => files,
+    };
+
     // Calculate total size using saturating arithmetic to prevent overflow
     let total_size: u64 = files.iter().fold(0u64, |acc, f| acc.saturating_add(f.size));
     let file_count = files.len() as u64;
@@ -107,12 +120,33 @@ where
     let log_transfer_id = generate_transfer_id();
 
     if debug {
-        eprintln!(
+        log_debug!(
             "Download {log_transfer_id}: {} files, {} bytes from {}",
-            file_count, total_size, peer_addr
+            file_count,
+            total_size,
+            peer_addr
         );
     }
 
+    // Wait for an active transfer slot (sends TransferQueued while waiting)
+    match transfer.wait_for_slot().await {
+        Ok(()) => {}
+        Err(StreamError::Banned) => {
+            // Just close the socket - client gets ban reason on BBS connection
+            if debug {
+                log_debug!("Download {log_transfer_id}: Terminated by ban while queued");
+            }
+            let _ = transfer.writer().get_mut().shutdown().await;
+            return Ok(());
+        }
+        Err(e) => {
+            if debug {
+                log_debug!("Download {log_transfer_id}: Client left queue: {e}");
+            }
+            return Ok(());
+        }
+    }
+
     // Send FileDownloadResponse
     let response = ServerMessage::FileDownloadResponse {
         success: true,
@@ -124,7 +158,7 @@ where
     };
     if let Err(e) = transfer.send(&response).await {
         if debug {
-            eprintln!("Download {log_transfer_id}: Failed to send response: {e}");
+            log_debug!("Download {log_transfer_id}: Failed to send response: {e}");
         }
         return Ok(());
     }
@@ -183,18 +217,21 @@ where
         {
             Ok(()) => {
                 // bytes_transferred is updated inside stream_file_with_hash
+                transfer
+                    .stats()
+                    .record_file_download(transfer.file_root(), &file_info.absolute_path);
             }
             Err(StreamFileError::Banned) => {
                 // Just close the socket - client gets ban reason on BBS connection
                 if debug {
-                    eprintln!("Download {log_transfer_id}: Terminated by ban");
+                    log_debug!("Download {log_transfer_id}: Terminated by ban");
                 }
                 let _ = transfer.writer().get_mut().shutdown().await;
                 return Ok(());
             }
             Err(StreamFileError::Io(e)) => {
                 if debug {
-                    eprintln!(
+                    log_debug!(
                         "Download {log_transfer_id}: Error streaming {}: {e}",
                         file_info.relative_path
                     );
@@ -219,11 +256,17 @@ where
     };
     let _ = transfer.send(&complete).await; // Best effort - connection may be closing
 
+    if success {
+        transfer
+            .stats()
+            .record_download(transfer.user().user_id, transfer.bytes_transferred());
+    }
+
     if debug {
         if success {
-            eprintln!("Download {log_transfer_id}: Complete");
+            log_debug!("Download {log_transfer_id}: Complete");
         } else {
-            eprintln!("Download {log_transfer_id}: Failed");
+            log_debug!("Download {log_transfer_id}: Failed");
         }
     }
 
@@ -314,8 +357,20 @@ pub(crate) fn can_access_for_download(path: &Path, username: &str, is_admin: boo
     true
 }
 
+/// Filter scanned files down to an include list
+///
+/// Entries that don't match a scanned file (deleted since the manifest was
+/// built, or hidden by dropbox rules) are silently ignored.
+fn filter_included_files(files: Vec<FileInfo>, include: &[String]) -> Vec<FileInfo> {
+    let include: HashSet<&str> = include.iter().map(String::as_str).collect();
+    files
+        .into_iter()
+        .filter(|f| include.contains(f.relative_path.as_str()))
+        .collect()
+}
+
 /// Scan files to transfer from a path (file or directory)
-async fn scan_files_for_transfer(
+pub(crate) async fn scan_files_for_transfer(
     resolved_path: &Path,
     username: &str,
     is_admin: bool,
@@ -364,7 +419,7 @@ fn scan_directory_recursive<'a>(
 ) -> std::pin::Pin<Box<dyn std::future::Future<Output = io::Result<()>> + Send + 'a>> {
     Box::pin(async move {
         if debug {
-            eprintln!("Scanning directory: {:?} (prefix: {:?})", dir, prefix);
+            log_debug!("Scanning directory: {:?} (prefix: {:?})", dir, prefix);
         }
 
         let mut entries = tokio::fs::read_dir(dir).await?;
@@ -372,7 +427,7 @@ fn scan_directory_recursive<'a>(
         while let Some(entry) = entries.next_entry().await? {
             let path = entry.path();
             if debug {
-                eprintln!("  Processing entry: {:?}", path);
+                log_debug!("  Processing entry: {:?}", path);
             }
             // Use tokio::fs::metadata instead of entry.metadata() to follow symlinks.
             // entry.metadata() uses lstat which returns symlink metadata, not target metadata.
@@ -380,7 +435,7 @@ fn scan_directory_recursive<'a>(
                 Ok(m) => m,
                 Err(e) => {
                     if debug {
-                        eprintln!("  Skipping {:?} - metadata failed: {}", path, e);
+                        log_debug!("  Skipping {:?} - metadata failed: {}", path, e);
                     }
                     continue;
                 }
@@ -388,7 +443,7 @@ fn scan_directory_recursive<'a>(
             // Skip files with non-UTF-8 names
             let Some(file_name) = entry.file_name().to_str().map(|s| s.to_string()) else {
                 if debug {
-                    eprintln!("  Skipping non-UTF-8 filename: {:?}", entry.file_name());
+                    log_debug!("  Skipping non-UTF-8 filename: {:?}", entry.file_name());
                 }
                 continue;
             };
@@ -403,7 +458,7 @@ fn scan_directory_recursive<'a>(
             // that content.
             if !can_access_for_download(&path, username, is_admin) {
                 if debug {
-                    eprintln!("  Skipping {} - dropbox access denied", file_name);
+                    log_debug!("  Skipping {} - dropbox access denied", file_name);
                 }
                 continue;
             }
@@ -417,7 +472,7 @@ fn scan_directory_recursive<'a>(
 
             if metadata.is_file() {
                 if debug {
-                    eprintln!("  Adding file: {} (size: {})", relative, metadata.len());
+                    log_debug!("  Adding file: {} (size: {})", relative, metadata.len());
                 }
                 files.push(FileInfo {
                     relative_path: relative,
@@ -426,13 +481,13 @@ fn scan_directory_recursive<'a>(
                 });
             } else if metadata.is_dir() {
                 if debug {
-                    eprintln!("  Recursing into directory: {}", relative);
+                    log_debug!("  Recursing into directory: {}", relative);
                 }
                 // For subdirectories, use the relative path as the new prefix
                 scan_directory_recursive(&path, &relative, files, username, is_admin, debug)
                     .await?;
             } else if debug {
-                eprintln!(
+                log_info!(
                     "  Skipping {} - special file (not a regular file or directory)",
                     file_name
                 );
@@ -440,7 +495,7 @@ fn scan_directory_recursive<'a>(
         }
 
         if debug {
-            eprintln!(
+            log_debug!(
                 "Done scanning directory: {:?} (found {} files so far)",
                 dir,
                 files.len()
@@ -488,16 +543,17 @@ where
 
     if debug {
         if offset > 0 {
-            eprintln!(
+            log_debug!(
                 "Transfer {transfer_id}: Resuming {} from offset {} ({}%)",
                 file_info.relative_path,
                 offset,
                 (offset * 100) / file_info.size.max(1)
             );
         } else if file_info.size > 0 {
-            eprintln!(
+            log_debug!(
                 "Transfer {transfer_id}: Sending {} ({} bytes)",
-                file_info.relative_path, file_info.size
+                file_info.relative_path,
+                file_info.size
             );
         }
     }
@@ -505,7 +561,7 @@ where
     // If offset equals file size, file is already complete - skip streaming
     if offset >= file_info.size {
         if debug && file_info.size > 0 {
-            eprintln!(
+            log_debug!(
                 "Transfer {transfer_id}: {} already complete",
                 file_info.relative_path
             );
@@ -635,6 +691,32 @@ where
 mod tests {
     use super::*;
 
+    // ==========================================================================
+    // filter_included_files tests
+    // ==========================================================================
+
+    fn file_info(relative_path: &str) -> FileInfo {
+        FileInfo {
+            relative_path: relative_path.to_string(),
+            absolute_path: std::path::PathBuf::from("/tmp").join(relative_path),
+            size: 1,
+        }
+    }
+
+    #[test]
+    fn test_filter_included_files() {
+        let files = vec![
+            file_info("song.mp3"),
+            file_info("Jazz/tune.mp3"),
+            file_info("Jazz/other.mp3"),
+        ];
+        let include = vec!["Jazz/tune.mp3".to_string(), "missing.mp3".to_string()];
+
+        let filtered = filter_included_files(files, &include);
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].relative_path, "Jazz/tune.mp3");
+    }
+
     // ==========================================================================
     // can_access_for_download tests
     // ==========================================================================
@@ -0,0 +1,201 @@
+//! Server export handling for transfers
+//!
+//! An export is sent exactly like a single-file download, so clients can
+//! reuse their download code: FileDownloadResponse, then FileStart/FileData
+//! for one JSON file, then TransferComplete.
+//!
+//! Archives are small next to the file area (images and clip audio are size
+//! limited), so the archive is built in memory and sent without going
+//! through the transfer queue.
+
+use std::io;
+
+use chrono::Local;
+use sha2::{Digest, Sha256};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+use nexus_common::framing::{FrameReader, FrameWriter, MessageId};
+use nexus_common::io::{read_client_message_with_full_timeout, send_server_message_with_id};
+use nexus_common::protocol::{ClientMessage, ServerMessage};
+use nexus_common::{ERROR_KIND_IO_ERROR, ERROR_KIND_PERMISSION};
+
+use crate::db::{Database, archive};
+use crate::handlers::{err_database, err_permission_denied};
+
+use super::helpers::{generate_transfer_id, send_download_error_and_close};
+use super::types::{AuthenticatedUser, ExportParams};
+
+/// File name prefix of export archives (followed by a timestamp)
+const EXPORT_FILE_PREFIX: &str = "nexus-export-";
+
+/// Handle a server export request (admin only)
+pub(crate) async fn handle_export<R, W>(
+    frame_reader: &mut FrameReader<R>,
+    frame_writer: &mut FrameWriter<W>,
+    user: &AuthenticatedUser,
+    db: &Database,
+    locale: &str,
+    debug: bool,
+    params: ExportParams,
+) -> io::Result<()>
+where
+    R: AsyncReadExt + Unpin,
+    W: AsyncWriteExt + Unpin,
+{
+    if !user.is_admin {
+        return send_download_error_and_close(
+            frame_writer,
+            &err_permission_denied(locale),
+            Some(ERROR_KIND_PERMISSION),
+        )
+        .await;
+    }
+
+    let archive = match archive::export_archive(db, params.include_ip_rules).await {
+        Ok(archive) => archive,
+        Err(e) => {
+            if debug {
+                log_debug!("Export for {} failed: {e}", user.username);
+            }
+            return send_download_error_and_close(
+                frame_writer,
+                &err_database(locale),
+                Some(ERROR_KIND_IO_ERROR),
+            )
+            .await;
+        }
+    };
+
+    let bytes = serde_json::to_vec_pretty(&archive).map_err(io::Error::other)?;
+    let size = bytes.len() as u64;
+    let sha256 = hex::encode(Sha256::digest(&bytes));
+    let transfer_id = generate_transfer_id();
+
+    if debug {
+        log_debug!("Export {transfer_id}: {size} bytes for {}", user.username);
+    }
+
+    let response = ServerMessage::FileDownloadResponse {
+        success: true,
+        error: None,
+        error_kind: None,
+        size: Some(size),
+        file_count: Some(1),
+        transfer_id: Some(transfer_id.clone()),
+    };
+    send_server_message_with_id(frame_writer, &response, MessageId::new()).await?;
+
+    let file_start = ServerMessage::FileStart {
+        path: format!(
+            "{EXPORT_FILE_PREFIX}{}.json",
+            Local::now().format("%Y%m%d-%H%M%S")
+        ),
+        size,
+        sha256: sha256.clone(),
+    };
+    send_server_message_with_id(frame_writer, &file_start, MessageId::new()).await?;
+
+    let offset = read_resume_offset(frame_reader, &bytes, &sha256).await?;
+    if offset < size {
+        let mut remaining = &bytes[offset as usize..];
+        frame_writer
+            .write_streaming_frame(MessageId::new(), "FileData", &mut remaining, size - offset)
+            .await
+            .map_err(|e| io::Error::other(e.to_string()))?;
+    }
+
+    let complete = ServerMessage::TransferComplete {
+        success: true,
+        error: None,
+        error_kind: None,
+    };
+    let _ = send_server_message_with_id(frame_writer, &complete, MessageId::new()).await;
+
+    if debug {
+        log_debug!("Export {transfer_id}: Complete");
+    }
+
+    let _ = frame_writer.get_mut().shutdown().await;
+    Ok(())
+}
+
+/// Read the client's FileStartResponse and work out where to resume
+///
+/// Same rules as downloads: resume only when the client's partial file is a
+/// prefix of the archive, otherwise start over.
+async fn read_resume_offset<R>(
+    frame_reader: &mut FrameReader<R>,
+    bytes: &[u8],
+    sha256: &str,
+) -> io::Result<u64>
+where
+    R: AsyncReadExt + Unpin,
+{
+    loop {
+        let received = match read_client_message_with_full_timeout(frame_reader, None, None).await {
+            Ok(Some(msg)) => msg,
+            Ok(None) => {
+                return Err(io::Error::other(
+                    "Connection closed waiting for FileStartResponse",
+                ));
+            }
+            Err(e) => {
+                return Err(io::Error::other(format!(
+                    "Failed to read FileStartResponse: {e}"
+                )));
+            }
+        };
+
+        match received.message {
+            ClientMessage::FileStartResponse { size, sha256: hash } => {
+                return Ok(resume_offset(bytes, sha256, size, hash.as_deref()));
+            }
+            // Keepalive while the client hashes its local file
+            ClientMessage::FileHashing { .. } => continue,
+            _ => return Err(io::Error::other("Expected FileStartResponse message")),
+        }
+    }
+}
+
+/// Resume offset for a client holding `client_size` bytes hashing to `client_hash`
+fn resume_offset(bytes: &[u8], sha256: &str, client_size: u64, client_hash: Option<&str>) -> u64 {
+    let Some(client_hash) = client_hash else {
+        return 0;
+    };
+    let Ok(len) = usize::try_from(client_size) else {
+        return 0;
+    };
+    if len == 0 || len > bytes.len() {
+        return 0;
+    }
+    let matches = if len == bytes.len() {
+        client_hash == sha256
+    } else {
+        client_hash == hex::encode(Sha256::digest(&bytes[..len]))
+    };
+    if matches { client_size } else { 0 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash(bytes: &[u8]) -> String {
+        hex::encode(Sha256::digest(bytes))
+    }
+
+    #[test]
+    fn test_resume_offset() {
+        let bytes = b"{\"format\":\"nexus-export\"}";
+        let full = hash(bytes);
+
+        assert_eq!(resume_offset(bytes, &full, 0, None), 0);
+        assert_eq!(
+            resume_offset(bytes, &full, bytes.len() as u64, Some(&full)),
+            bytes.len() as u64
+        );
+        assert_eq!(resume_offset(bytes, &full, 5, Some(&hash(&bytes[..5]))), 5);
+        assert_eq!(resume_offset(bytes, &full, 5, Some(&full)), 0);
+        assert_eq!(resume_offset(bytes, &full, 1000, Some(&full)), 0);
+    }
+}
@@ -86,7 +86,7 @@ where
             Some(file) = keepalive_rx.recv() => {
                 let msg = ServerMessage::FileHashing { file };
                 if let Err(e) = send_server_message_with_id(writer, &msg, MessageId::new()).await {
-                    eprintln!("[HASH] Failed to send keepalive: {:?}", e);
+                    log_error!("[HASH] Failed to send keepalive: {:?}", e);
                     // Continue anyway - hash might complete before timeout
                 }
             }
@@ -11,10 +11,11 @@ use tokio::io::AsyncWriteExt;
 use nexus_common::framing::{FrameWriter, MessageId};
 use nexus_common::io::send_server_message_with_id;
 use nexus_common::protocol::ServerMessage;
-use nexus_common::validators::{self, FilePathError};
+use nexus_common::validators::{self, DownloadIncludeError, FilePathError};
 use nexus_common::{
-    ERROR_KIND_CONFLICT, ERROR_KIND_EXISTS, ERROR_KIND_HASH_MISMATCH, ERROR_KIND_INVALID,
-    ERROR_KIND_IO_ERROR, ERROR_KIND_NOT_FOUND, ERROR_KIND_PERMISSION, ERROR_KIND_PROTOCOL_ERROR,
+    ERROR_KIND_CONFLICT, ERROR_KIND_EXISTS, ERROR_KIND_HASH_MISMATCH,
+    ERROR_KIND_INSUFFICIENT_STORAGE, ERROR_KIND_INVALID, ERROR_KIND_IO_ERROR, ERROR_KIND_NOT_FOUND,
+    ERROR_KIND_PERMISSION, ERROR_KIND_PROTOCOL_ERROR, ERROR_KIND_UPLOAD_RULE,
 };
 
 use crate::db::Permission;
@@ -91,6 +92,16 @@ impl TransferError {
     pub fn hash_mismatch(message: impl Into<String>) -> Self {
         Self::new(message, ERROR_KIND_HASH_MISMATCH)
     }
+
+    /// Create an "upload_rule" error (file not allowed by the destination's upload rule)
+    pub fn upload_rule(message: impl Into<String>) -> Self {
+        Self::new(message, ERROR_KIND_UPLOAD_RULE)
+    }
+
+    /// Create an "insufficient_storage" error (upload would use the server's reserved free space)
+    pub fn insufficient_storage(message: impl Into<String>) -> Self {
+        Self::new(message, ERROR_KIND_INSUFFICIENT_STORAGE)
+    }
 }
 
 impl std::fmt::Display for TransferError {
@@ -122,6 +133,31 @@ pub(crate) fn validate_transfer_path(path: &str, locale: &str) -> Result<(), Tra
     Ok(())
 }
 
+/// Validate an optional download include list
+///
+/// Entries are matched against the server's own scan and never resolved
+/// directly, so they only need the same shape checks as a transfer path.
+pub(crate) fn validate_transfer_include(
+    include: Option<&[String]>,
+    locale: &str,
+) -> Result<(), TransferError> {
+    let Some(include) = include else {
+        return Ok(());
+    };
+    if let Err(e) = validators::validate_download_include(include) {
+        let error_msg = match e {
+            DownloadIncludeError::InvalidPath(FilePathError::TooLong) => {
+                err_transfer_path_too_long(locale)
+            }
+            DownloadIncludeError::Empty
+            | DownloadIncludeError::TooMany
+            | DownloadIncludeError::InvalidPath(_) => err_transfer_path_invalid(locale),
+        };
+        return Err(TransferError::invalid(error_msg));
+    }
+    Ok(())
+}
+
 /// Check if the user has the required permission
 ///
 /// Returns `Ok(())` if the user is admin or has the permission,
@@ -212,6 +248,7 @@ pub(crate) fn login_error_response(error: String) -> ServerMessage {
         locale: None,
         channels: None,
         nickname: None,
+        login_token: None,
     }
 }
 
@@ -300,6 +337,7 @@ where
     let response = ServerMessage::Error {
         message: error.to_string(),
         command: None,
+        error_kind: None,
     };
     let _ = send_server_message_with_id(frame_writer, &response, MessageId::new()).await;
     let _ = frame_writer.get_mut().shutdown().await;
@@ -351,6 +389,19 @@ mod tests {
             TransferError::hash_mismatch("x").kind,
             ERROR_KIND_HASH_MISMATCH
         );
+        assert_eq!(TransferError::upload_rule("x").kind, ERROR_KIND_UPLOAD_RULE);
+    }
+
+    #[test]
+    fn test_validate_transfer_include() {
+        assert!(validate_transfer_include(None, "en").is_ok());
+        assert!(validate_transfer_include(Some(&["a/b.txt".to_string()]), "en").is_ok());
+
+        let err = validate_transfer_include(Some(&[]), "en").unwrap_err();
+        assert_eq!(err.kind, ERROR_KIND_INVALID);
+
+        let err = validate_transfer_include(Some(&["bad\0".to_string()]), "en").unwrap_err();
+        assert_eq!(err.kind, ERROR_KIND_INVALID);
     }
 
     #[test]
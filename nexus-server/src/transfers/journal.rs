@@ -0,0 +1,333 @@
+//! Upload journal bookkeeping
+//!
+//! The database records every `.part` file while its upload is in progress
+//! (see [`UploadJournalDb`]). Uploads claim their `.part` file in
+//! [`PartClaims`] so two uploads never write the same one, and a sweep run at startup and every
+//! hour reconciles the journal with the disk:
+//!
+//! - entries whose `.part` file is gone (finished, deleted or moved) are dropped
+//! - the recorded byte count is corrected to what is actually on disk, since a
+//!   crash mid-upload leaves the count from when the upload started
+//! - `.part` files untouched for longer than the stale age are deleted
+//!
+//! Journal writes during an upload are best effort: a database error is
+//! logged and the upload carries on as it would without a journal.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+use chrono::Utc;
+
+use crate::db::UploadJournalDb;
+
+/// .part files being written by an upload right now
+///
+/// Owned by the [`TransferRegistry`](super::TransferRegistry), which every
+/// upload and sweep can reach.
+#[derive(Default)]
+pub struct PartClaims {
+    active: Mutex<HashSet<PathBuf>>,
+}
+
+impl PartClaims {
+    /// Create an empty claim set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Lock the claimed paths
+    fn lock(&self) -> MutexGuard<'_, HashSet<PathBuf>> {
+        self.active.lock().expect("upload journal lock poisoned")
+    }
+
+    /// Claim a .part file, or `None` if another upload is writing it
+    pub fn acquire(&self, path: &Path) -> Option<PartClaim<'_>> {
+        self.lock().insert(path.to_path_buf()).then(|| PartClaim {
+            claims: self,
+            path: path.to_path_buf(),
+        })
+    }
+
+    /// Whether an upload is writing the .part file right now
+    pub fn is_claimed(&self, path: &Path) -> bool {
+        self.lock().contains(path)
+    }
+}
+
+/// Claim on a .part file, released when dropped
+pub struct PartClaim<'a> {
+    claims: &'a PartClaims,
+    path: PathBuf,
+}
+
+impl Drop for PartClaim<'_> {
+    fn drop(&mut self) {
+        self.claims.lock().remove(&self.path);
+    }
+}
+
+/// Whether a leftover .part file was journaled for the same upload
+///
+/// The same expected hash and size mean the client is sending the same
+/// content again, so the file can be replaced instead of treated as another
+/// upload in progress.
+pub(crate) async fn is_same_upload(
+    journal: &UploadJournalDb,
+    part_key: &str,
+    sha256: &str,
+    size: u64,
+) -> bool {
+    match journal.get(part_key).await {
+        Ok(Some(entry)) => entry.sha256 == sha256 && entry.size == size,
+        Ok(None) => false,
+        Err(e) => {
+            log_error!("Upload journal error: {}", e);
+            false
+        }
+    }
+}
+
+/// Record an upload about to write its .part file
+pub(crate) async fn record_upload(
+    journal: &UploadJournalDb,
+    part_key: &str,
+    target_key: &str,
+    username: &str,
+    sha256: &str,
+    size: u64,
+    bytes_received: u64,
+) {
+    if let Err(e) = journal
+        .record(part_key, target_key, username, sha256, size, bytes_received)
+        .await
+    {
+        log_error!("Upload journal error: {}", e);
+    }
+}
+
+/// Record how much of an interrupted upload is on disk
+pub(crate) async fn record_interrupted(
+    journal: &UploadJournalDb,
+    part_key: &str,
+    part_path: &Path,
+) {
+    let result = match tokio::fs::metadata(part_path).await {
+        Ok(metadata) => journal.set_progress(part_key, metadata.len()).await,
+        Err(_) => journal.remove(part_key).await,
+    };
+    if let Err(e) = result {
+        log_error!("Upload journal error: {}", e);
+    }
+}
+
+/// Remove a finished (or discarded) upload from the journal
+pub(crate) async fn forget_upload(journal: &UploadJournalDb, part_key: &str) {
+    if let Err(e) = journal.remove(part_key).await {
+        log_error!("Upload journal error: {}", e);
+    }
+}
+
+/// Result of reconciling the journal with the disk
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct JournalSweep {
+    /// Uploads that can still be resumed
+    pub resumable: usize,
+    /// Bytes held by their .part files
+    pub resumable_bytes: u64,
+    /// Stale .part files deleted
+    pub removed: usize,
+    /// Bytes freed by deleting them
+    pub removed_bytes: u64,
+}
+
+/// Reconcile the upload journal with the .part files under the file root
+///
+/// Uploads in progress right now are left alone, however long they take.
+pub async fn sweep_upload_journal(
+    journal: &UploadJournalDb,
+    claims: &PartClaims,
+    file_root: &Path,
+    stale_after: Duration,
+) -> Result<JournalSweep, sqlx::Error> {
+    let cutoff = Utc::now().timestamp() - stale_after.as_secs() as i64;
+    let mut sweep = JournalSweep::default();
+
+    for entry in journal.list().await? {
+        let part_path = file_root.join(entry.part_path.trim_start_matches('/'));
+
+        if claims.is_claimed(&part_path) {
+            sweep.resumable += 1;
+            sweep.resumable_bytes += entry.bytes_received;
+            continue;
+        }
+
+        let Ok(metadata) = tokio::fs::metadata(&part_path).await else {
+            journal.remove(&entry.part_path).await?;
+            continue;
+        };
+        let size = metadata.len();
+
+        if entry.updated_at <= cutoff {
+            match tokio::fs::remove_file(&part_path).await {
+                Ok(()) => {
+                    sweep.removed += 1;
+                    sweep.removed_bytes += size;
+                    journal.remove(&entry.part_path).await?;
+                }
+                Err(e) => log_error!("Failed to remove {}: {}", part_path.display(), e),
+            }
+            continue;
+        }
+
+        if size != entry.bytes_received {
+            journal.correct_bytes(&entry.part_path, size).await?;
+        }
+        sweep.resumable += 1;
+        sweep.resumable_bytes += size;
+    }
+
+    Ok(sweep)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::testing::create_test_db;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_part_claim_is_exclusive() {
+        let claims = PartClaims::new();
+        let path = Path::new("/journal-test/claim.txt.part");
+
+        let claim = claims.acquire(path).unwrap();
+        assert!(claims.is_claimed(path));
+        assert!(claims.acquire(path).is_none());
+
+        drop(claim);
+        assert!(!claims.is_claimed(path));
+        assert!(claims.acquire(path).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_is_same_upload() {
+        let journal = UploadJournalDb::new(create_test_db().await);
+        let part = "/shared/song.mp3.part";
+        journal
+            .record(part, "/shared/song.mp3", "alice", "abc123", 1000, 0)
+            .await
+            .unwrap();
+
+        assert!(is_same_upload(&journal, part, "abc123", 1000).await);
+        assert!(!is_same_upload(&journal, part, "def456", 1000).await);
+        assert!(!is_same_upload(&journal, part, "abc123", 999).await);
+        assert!(!is_same_upload(&journal, "/shared/other.part", "abc123", 1000).await);
+    }
+
+    #[tokio::test]
+    async fn test_sweep_reconciles_with_disk() {
+        let journal = UploadJournalDb::new(create_test_db().await);
+        let claims = PartClaims::new();
+        let file_root = TempDir::new().unwrap();
+        std::fs::create_dir(file_root.path().join("shared")).unwrap();
+        std::fs::write(file_root.path().join("shared/partial.bin.part"), b"12345").unwrap();
+
+        // Crashed after writing 5 bytes, but only the start was recorded
+        journal
+            .record(
+                "/shared/partial.bin.part",
+                "/shared/partial.bin",
+                "alice",
+                "abc",
+                10,
+                0,
+            )
+            .await
+            .unwrap();
+        // Finished or deleted since
+        journal
+            .record(
+                "/shared/gone.bin.part",
+                "/shared/gone.bin",
+                "alice",
+                "def",
+                10,
+                3,
+            )
+            .await
+            .unwrap();
+
+        let sweep = sweep_upload_journal(
+            &journal,
+            &claims,
+            file_root.path(),
+            Duration::from_secs(3600),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            sweep,
+            JournalSweep {
+                resumable: 1,
+                resumable_bytes: 5,
+                removed: 0,
+                removed_bytes: 0,
+            }
+        );
+
+        let entries = journal.list().await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].part_path, "/shared/partial.bin.part");
+        assert_eq!(entries[0].bytes_received, 5);
+    }
+
+    #[tokio::test]
+    async fn test_sweep_removes_stale_parts() {
+        let journal = UploadJournalDb::new(create_test_db().await);
+        let claims = PartClaims::new();
+        let file_root = TempDir::new().unwrap();
+        std::fs::create_dir(file_root.path().join("shared")).unwrap();
+        let stale = file_root.path().join("shared/stale.bin.part");
+        std::fs::write(&stale, b"1234567").unwrap();
+        journal
+            .record(
+                "/shared/stale.bin.part",
+                "/shared/stale.bin",
+                "alice",
+                "abc",
+                10,
+                7,
+            )
+            .await
+            .unwrap();
+
+        // Nothing is stale yet
+        let sweep = sweep_upload_journal(
+            &journal,
+            &claims,
+            file_root.path(),
+            Duration::from_secs(3600),
+        )
+        .await
+        .unwrap();
+        assert_eq!(sweep.removed, 0);
+
+        // Everything is stale, but a claimed file is still being written
+        let claim = claims.acquire(&stale).unwrap();
+        sweep_upload_journal(&journal, &claims, file_root.path(), Duration::ZERO)
+            .await
+            .unwrap();
+        assert!(stale.exists());
+        drop(claim);
+
+        let sweep = sweep_upload_journal(&journal, &claims, file_root.path(), Duration::ZERO)
+            .await
+            .unwrap();
+        assert_eq!(sweep.removed, 1);
+        assert_eq!(sweep.removed_bytes, 7);
+        assert!(!stale.exists());
+        assert!(journal.list().await.unwrap().is_empty());
+    }
+}
@@ -7,23 +7,35 @@
 //! **Download flow:**
 //! 1. Client: Handshake → Server: HandshakeResponse
 //! 2. Client: Login → Server: LoginResponse (simplified: just success/error)
-//! 3. Client: FileDownload → Server: FileDownloadResponse
+//! 3. Client: FileDownload → Server: TransferQueued (zero or more, while waiting
+//!    for an active slot) → FileDownloadResponse
 //! 4. For each file: Server: FileStart → Client: FileStartResponse → Server: FileData
 //! 5. Server: TransferComplete
 //! 6. Server closes connection
 //!
+//! **Export flow** (admins only, works without a file area):
+//! 1-2. Handshake and login as above
+//! 3. Client: ServerExport → Server: FileDownloadResponse
+//! 4. Server: FileStart (one JSON archive) → Client: FileStartResponse → Server: FileData
+//! 5. Server: TransferComplete
+//! 6. Server closes connection
+//!
 //! **Upload flow:**
 //! 1. Client: Handshake → Server: HandshakeResponse
 //! 2. Client: Login → Server: LoginResponse (simplified: just success/error)
-//! 3. Client: FileUpload → Server: FileUploadResponse
+//! 3. Client: FileUpload → Server: TransferQueued (zero or more, while waiting
+//!    for an active slot) → FileUploadResponse
 //! 4. For each file: Client: FileStart → Server: FileStartResponse → Client: FileData
 //! 5. Server: TransferComplete
 //! 6. Server closes connection
 
 mod auth;
+pub mod cleanup;
 mod download;
+mod export;
 mod hash;
 mod helpers;
+mod journal;
 pub mod registry;
 mod transfer;
 mod types;
@@ -42,13 +54,16 @@ use crate::handlers::err_file_area_not_configured;
 
 use auth::{handle_transfer_handshake, handle_transfer_login, handle_transfer_request};
 use download::handle_download;
+use export::handle_export;
 use helpers::send_error_and_close;
 use registry::TransferDirection;
 use transfer::Transfer;
-use types::TransferRequest;
+use types::{PortRequest, TransferRequest};
 use upload::handle_upload;
 
 // Re-export public types
+pub(crate) use download::{can_access_for_download, scan_files_for_transfer};
+pub use journal::sweep_upload_journal;
 pub use registry::TransferRegistry;
 pub use types::TransferParams;
 
@@ -84,10 +99,15 @@ where
         file_root,
         file_index,
         transfer_registry,
+        login_tokens,
+        stats,
+        ldap,
+        storage,
+        is_trusted,
     } = params;
 
     if debug {
-        eprintln!("Transfer connection from {peer_addr}");
+        log_debug!("Transfer connection from {peer_addr}");
     }
 
     // Set up framed I/O
@@ -104,49 +124,74 @@ where
         handle_transfer_handshake(&mut frame_reader, &mut frame_writer, &locale).await;
     if let Err(e) = handshake_result {
         if debug {
-            eprintln!("Transfer handshake failed from {peer_addr}: {e}");
+            log_debug!("Transfer handshake failed from {peer_addr}: {e}");
         }
         let _ = frame_writer.get_mut().shutdown().await;
         return Ok(());
     }
 
     // Phase 2: Login (simplified - just authentication)
-    let user =
-        match handle_transfer_login(&mut frame_reader, &mut frame_writer, &db, &mut locale).await {
-            Ok(user) => user,
-            Err(e) => {
-                if debug {
-                    eprintln!("Transfer login failed from {peer_addr}: {e}");
-                }
-                let _ = frame_writer.get_mut().shutdown().await;
-                return Ok(());
+    let user = match handle_transfer_login(
+        &mut frame_reader,
+        &mut frame_writer,
+        &db,
+        &ldap,
+        &login_tokens,
+        &mut locale,
+    )
+    .await
+    {
+        Ok(user) => user,
+        Err(e) => {
+            if debug {
+                log_debug!("Transfer login failed from {peer_addr}: {e}");
             }
-        };
+            let _ = frame_writer.get_mut().shutdown().await;
+            return Ok(());
+        }
+    };
 
     if debug {
-        eprintln!("Transfer authenticated: {} from {peer_addr}", user.username);
+        log_debug!("Transfer authenticated: {} from {peer_addr}", user.username);
     }
 
-    // Phase 3: Transfer request (FileDownload or FileUpload)
-    let Some(file_root) = file_root else {
-        // File area not configured - send generic error since we don't know
-        // if this is a download or upload request yet
-        return send_error_and_close(&mut frame_writer, &err_file_area_not_configured(&locale))
-            .await;
-    };
-
+    // Phase 3: Transfer request (FileDownload, FileUpload or ServerExport)
     let request = match handle_transfer_request(&mut frame_reader, &mut frame_writer, &locale).await
     {
         Ok(req) => req,
         Err(e) => {
             if debug {
-                eprintln!("Transfer request failed from {peer_addr}: {e}");
+                log_debug!("Transfer request failed from {peer_addr}: {e}");
             }
             let _ = frame_writer.get_mut().shutdown().await;
             return Ok(());
         }
     };
 
+    // Exports don't touch the file area or take a transfer slot
+    let request = match request {
+        PortRequest::Transfer(request) => request,
+        PortRequest::Export(params) => {
+            return handle_export(
+                &mut frame_reader,
+                &mut frame_writer,
+                &user,
+                &db,
+                &locale,
+                debug,
+                params,
+            )
+            .await;
+        }
+    };
+
+    let Some(file_root) = file_root else {
+        // File area not configured - send generic error rather than a
+        // download or upload response
+        return send_error_and_close(&mut frame_writer, &err_file_area_not_configured(&locale))
+            .await;
+    };
+
     // Determine transfer direction, path, and size for registry metadata
     let (direction, path, total_size) = match &request {
         TransferRequest::Download(p) => {
@@ -173,6 +218,11 @@ where
         total_size,
     );
 
+    // Trusted IPs get a larger share when the scheduler picks who starts next
+    if is_trusted {
+        info.set_weight(db.config.get_trusted_transfer_weight().await);
+    }
+
     // Create Transfer struct that owns the connection and handles ban signals
     // The Transfer is automatically unregistered when dropped via RAII guard
     let mut transfer = Transfer::new(
@@ -186,19 +236,22 @@ where
         file_root,
         &file_index,
         &transfer_registry,
+        &stats,
         transfer_id,
     );
 
     // Dispatch to appropriate handler
     let result = match request {
         TransferRequest::Download(params) => handle_download(&mut transfer, params).await,
-        TransferRequest::Upload(params) => handle_upload(&mut transfer, params).await,
+        TransferRequest::Upload(params) => {
+            handle_upload(&mut transfer, &db, &storage, params).await
+        }
     };
 
     if debug {
         let elapsed = transfer.elapsed();
         let bytes = transfer.bytes_transferred();
-        eprintln!(
+        log_debug!(
             "Transfer {} complete: {} bytes in {:.2}s from {}",
             transfer.id(),
             bytes,
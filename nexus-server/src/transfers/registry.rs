@@ -6,15 +6,27 @@
 //!
 //! The registry stores `ActiveTransfer` structs with queryable metadata,
 //! enabling connection monitor integration.
-
+//!
+//! The registry also schedules transfers against a server-wide limit on
+//! simultaneously active transfers. Transfers beyond the limit wait in a
+//! priority queue: admins before regular users, then a fair-share order that
+//! interleaves users and IPs (a user or IP with fewer running transfers, scaled
+//! by its weight, goes first), then optionally smaller transfers before larger
+//! ones, then first-come first-served.
+
+use std::cmp::Reverse;
 use std::collections::HashMap;
+use std::net::IpAddr;
 use std::net::SocketAddr;
 use std::sync::Mutex;
-use std::sync::atomic::{AtomicU64, Ordering};
-use std::time::{Instant, SystemTime, UNIX_EPOCH};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, AtomicUsize, Ordering};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use nexus_common::protocol::TransferInfo;
-use tokio::sync::oneshot;
+use tokio::sync::{oneshot, watch};
+
+use super::journal::PartClaims;
+use crate::constants::DEFAULT_STALE_UPLOAD_DAYS;
 
 /// Unique identifier for a transfer session
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -52,6 +64,18 @@ impl std::fmt::Display for TransferDirection {
     }
 }
 
+/// Scheduling priority of a transfer in the queue
+///
+/// Higher priorities start before lower ones when the active transfer
+/// limit is reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TransferPriority {
+    /// Regular user transfer
+    Normal,
+    /// Admin transfer (always ahead of regular users)
+    Admin,
+}
+
 /// Runtime state for an active transfer
 ///
 /// This struct is shared between the registry and the Transfer via Arc.
@@ -82,6 +106,14 @@ pub struct ActiveTransfer {
     pub bytes_transferred: AtomicU64,
     /// When the transfer started
     pub started_at: Instant,
+    /// Queue priority (derived from the user's admin status)
+    pub priority: TransferPriority,
+    /// Fair-share weight (1 = normal, higher for trusted IPs)
+    weight: AtomicU32,
+    /// Whether the transfer has asked for a slot (set on first `try_start`)
+    waiting: AtomicBool,
+    /// Whether the transfer holds an active slot (false while queued)
+    running: AtomicBool,
     /// Channel to signal ban - wrapped in Mutex<Option<>> since we take it once
     ban_tx: Mutex<Option<oneshot::Sender<()>>>,
 }
@@ -113,12 +145,19 @@ impl ActiveTransfer {
             total_size: AtomicU64::new(total_size),
             bytes_transferred: AtomicU64::new(0),
             started_at: Instant::now(),
+            priority: if is_admin {
+                TransferPriority::Admin
+            } else {
+                TransferPriority::Normal
+            },
+            weight: AtomicU32::new(1),
+            waiting: AtomicBool::new(false),
+            running: AtomicBool::new(false),
             ban_tx: Mutex::new(Some(ban_tx)),
         }
     }
 
     /// Set the total size (used for downloads after path resolution)
-    #[allow(dead_code)] // Public API for future use
     pub fn set_total_size(&self, size: u64) {
         self.total_size.store(size, Ordering::Relaxed);
     }
@@ -143,6 +182,26 @@ impl ActiveTransfer {
         self.started_at.elapsed()
     }
 
+    /// Set the fair-share weight (values below 1 are treated as 1)
+    pub fn set_weight(&self, weight: u32) {
+        self.weight.store(weight.max(1), Ordering::Relaxed);
+    }
+
+    /// Get the fair-share weight
+    pub fn weight(&self) -> u32 {
+        self.weight.load(Ordering::Relaxed)
+    }
+
+    /// Whether the transfer holds an active slot (false while queued)
+    pub fn is_running(&self) -> bool {
+        self.running.load(Ordering::Relaxed)
+    }
+
+    /// Whether the transfer is waiting in the queue for a slot
+    pub fn is_queued(&self) -> bool {
+        self.waiting.load(Ordering::Relaxed) && !self.is_running()
+    }
+
     /// Send ban signal to this transfer (takes ownership of sender)
     ///
     /// Returns true if signal was sent, false if already sent or receiver dropped.
@@ -174,6 +233,8 @@ impl ActiveTransfer {
             total_size: self.get_total_size(),
             bytes_transferred: self.get_bytes_transferred(),
             started_at: now - self.elapsed().as_secs() as i64,
+            queued: self.is_queued(),
+            weight: self.weight(),
         }
     }
 }
@@ -192,6 +253,9 @@ impl std::fmt::Debug for ActiveTransfer {
             .field("total_size", &self.get_total_size())
             .field("bytes_transferred", &self.get_bytes_transferred())
             .field("started_at", &self.started_at)
+            .field("priority", &self.priority)
+            .field("weight", &self.weight())
+            .field("running", &self.is_running())
             .finish()
     }
 }
@@ -203,20 +267,154 @@ impl std::fmt::Debug for ActiveTransfer {
 /// - Unregistering transfers when they complete
 /// - Disconnecting all transfers matching a predicate (e.g., banned IPs)
 /// - Querying all active transfers for connection monitor
+/// - Queueing transfers beyond the server-wide active transfer limit
 ///
 /// The registry uses oneshot channels to signal bans, so transfer tasks can
 /// use `tokio::select!` to check for bans during I/O without polling.
+/// Queued transfers wait on a watch channel that is bumped whenever a slot
+/// may have become available (or a ban was issued).
 pub struct TransferRegistry {
     transfers: Mutex<HashMap<TransferId, std::sync::Arc<ActiveTransfer>>>,
     next_id: AtomicU64,
+    /// Maximum simultaneously running transfers (0 = unlimited)
+    max_active: AtomicUsize,
+    /// Whether smaller transfers are started before larger ones
+    small_files_first: AtomicBool,
+    /// Bumped whenever queued transfers should re-check their position
+    slot_tx: watch::Sender<u64>,
+    /// .part files being written by uploads
+    part_claims: PartClaims,
+    /// How long a `.part` file must sit untouched before it counts as orphaned
+    stale_upload_age: Duration,
 }
 
 impl TransferRegistry {
-    /// Create a new empty transfer registry
+    /// Create a new empty transfer registry with no active transfer limit
     pub fn new() -> Self {
+        Self::with_limits(0, false)
+    }
+
+    /// Create a new empty transfer registry with the given queue settings
+    ///
+    /// # Arguments
+    /// * `max_active` - Maximum simultaneously running transfers (0 = unlimited)
+    /// * `small_files_first` - Whether smaller transfers start before larger ones
+    pub fn with_limits(max_active: usize, small_files_first: bool) -> Self {
+        let (slot_tx, _) = watch::channel(0);
         Self {
             transfers: Mutex::new(HashMap::new()),
             next_id: AtomicU64::new(1),
+            max_active: AtomicUsize::new(max_active),
+            small_files_first: AtomicBool::new(small_files_first),
+            slot_tx,
+            part_claims: PartClaims::new(),
+            stale_upload_age: Duration::from_secs(u64::from(DEFAULT_STALE_UPLOAD_DAYS) * 86400),
+        }
+    }
+
+    /// Set how long a `.part` file must sit untouched before it counts as orphaned
+    pub fn with_stale_upload_age(mut self, stale_upload_age: Duration) -> Self {
+        self.stale_upload_age = stale_upload_age;
+        self
+    }
+
+    /// Update the maximum simultaneously running transfers (0 = unlimited)
+    ///
+    /// Queued transfers are woken so they can start if the limit was raised.
+    pub fn set_max_active_transfers(&self, max_active: usize) {
+        self.max_active.store(max_active, Ordering::Relaxed);
+        self.notify_slots();
+    }
+
+    /// Update whether smaller transfers start before larger ones
+    pub fn set_small_files_first(&self, enabled: bool) {
+        self.small_files_first.store(enabled, Ordering::Relaxed);
+        self.notify_slots();
+    }
+
+    /// .part files being written by uploads right now
+    pub fn part_claims(&self) -> &PartClaims {
+        &self.part_claims
+    }
+
+    /// How long a `.part` file must sit untouched before it counts as orphaned
+    pub fn stale_upload_age(&self) -> Duration {
+        self.stale_upload_age
+    }
+
+    /// Subscribe to slot availability changes
+    ///
+    /// The receiver is notified whenever a queued transfer should call
+    /// [`try_start`](Self::try_start) again.
+    pub fn subscribe_slots(&self) -> watch::Receiver<u64> {
+        self.slot_tx.subscribe()
+    }
+
+    /// Wake all queued transfers so they re-check their queue position
+    fn notify_slots(&self) {
+        self.slot_tx
+            .send_modify(|generation| *generation = generation.wrapping_add(1));
+    }
+
+    /// Try to move a registered transfer into an active slot
+    ///
+    /// Returns `Ok(())` if the transfer is (now) running, or `Err(position)`
+    /// with its 1-based position in the queue if it must keep waiting.
+    ///
+    /// Queue order is: admins before regular users, then fair share (see
+    /// [`fair_queue_order`]), then (if the small-files-first policy is
+    /// enabled) smaller total size first, then registration order.
+    pub fn try_start(&self, id: TransferId) -> Result<(), u32> {
+        let transfers = self
+            .transfers
+            .lock()
+            .expect("transfer registry lock poisoned");
+
+        let Some(info) = transfers.get(&id) else {
+            // Not registered (already unregistered) - nothing to wait for
+            return Ok(());
+        };
+        if info.is_running() {
+            return Ok(());
+        }
+        info.waiting.store(true, Ordering::Relaxed);
+
+        let max_active = self.max_active.load(Ordering::Relaxed);
+        if max_active == 0 {
+            info.running.store(true, Ordering::Relaxed);
+            return Ok(());
+        }
+
+        let running: Vec<&ActiveTransfer> = transfers
+            .values()
+            .filter(|t| t.is_running())
+            .map(|t| t.as_ref())
+            .collect();
+        let free_slots = max_active.saturating_sub(running.len());
+
+        let small_files_first = self.small_files_first.load(Ordering::Relaxed);
+        let mut queued: Vec<&ActiveTransfer> = transfers
+            .values()
+            .filter(|t| t.is_queued())
+            .map(|t| t.as_ref())
+            .collect();
+        queued.sort_by_key(|t| {
+            let size = if small_files_first {
+                t.get_total_size()
+            } else {
+                0
+            };
+            (Reverse(t.priority), size, t.id.0)
+        });
+        let order = fair_queue_order(&running, &queued);
+
+        // Queue index is always found since `info` itself is queued
+        let index = order.iter().position(|&t| t == id).unwrap_or(0);
+        if index < free_slots {
+            info.running.store(true, Ordering::Relaxed);
+            Ok(())
+        } else {
+            Err((index - free_slots + 1) as u32)
         }
     }
 
@@ -270,11 +468,18 @@ impl TransferRegistry {
     }
 
     /// Unregister a transfer (called when transfer completes or fails)
+    ///
+    /// Wakes queued transfers since a slot (or a queue position) was freed.
     pub fn unregister(&self, id: TransferId) {
-        self.transfers
+        let removed = self
+            .transfers
             .lock()
             .expect("transfer registry lock poisoned")
             .remove(&id);
+
+        if removed.is_some() {
+            self.notify_slots();
+        }
     }
 
     /// Disconnect all transfers where the predicate returns true for their IP
@@ -302,6 +507,12 @@ impl TransferRegistry {
                 count += 1;
             }
         }
+        drop(transfers);
+
+        // Wake queued transfers so banned ones notice the signal
+        if count > 0 {
+            self.notify_slots();
+        }
 
         count
     }
@@ -328,6 +539,17 @@ impl TransferRegistry {
             .expect("transfer registry lock poisoned")
             .len()
     }
+
+    /// Get the number of transfers waiting for a slot
+    #[allow(dead_code)] // Used in tests and future connection monitor
+    pub fn queued_count(&self) -> usize {
+        self.transfers
+            .lock()
+            .expect("transfer registry lock poisoned")
+            .values()
+            .filter(|t| t.is_queued())
+            .count()
+    }
 }
 
 impl Default for TransferRegistry {
@@ -336,6 +558,53 @@ impl Default for TransferRegistry {
     }
 }
 
+/// Scale factor so fair-share loads stay integral when divided by a weight
+const FAIR_SHARE_SCALE: u64 = 1_000;
+
+/// Interleave queued transfers fairly between users and IPs
+///
+/// `queued` must already be sorted by priority and tie-breakers. Repeatedly
+/// picks the highest-priority transfer whose user (by nickname, so shared
+/// account sessions count separately) and IP have the smallest weighted load,
+/// where load is the larger of the user's and the IP's running transfers
+/// (plus those already picked) divided by the transfer's weight. Ties keep
+/// the input order.
+///
+/// Returns transfer IDs in the order they should receive slots.
+fn fair_queue_order(running: &[&ActiveTransfer], queued: &[&ActiveTransfer]) -> Vec<TransferId> {
+    let mut by_user: HashMap<String, u64> = HashMap::new();
+    let mut by_ip: HashMap<IpAddr, u64> = HashMap::new();
+    for t in running {
+        *by_user.entry(t.nickname.to_lowercase()).or_default() += 1;
+        *by_ip.entry(t.peer_addr.ip()).or_default() += 1;
+    }
+
+    let mut remaining: Vec<&ActiveTransfer> = queued.to_vec();
+    let mut order = Vec::with_capacity(remaining.len());
+    while !remaining.is_empty() {
+        let load = |t: &ActiveTransfer| {
+            let user = by_user
+                .get(&t.nickname.to_lowercase())
+                .copied()
+                .unwrap_or(0);
+            let ip = by_ip.get(&t.peer_addr.ip()).copied().unwrap_or(0);
+            user.max(ip) * FAIR_SHARE_SCALE / u64::from(t.weight())
+        };
+        let next = remaining
+            .iter()
+            .enumerate()
+            .min_by_key(|(index, t)| (Reverse(t.priority), load(t), *index))
+            .map(|(index, _)| index)
+            .unwrap_or(0);
+
+        let picked = remaining.remove(next);
+        *by_user.entry(picked.nickname.to_lowercase()).or_default() += 1;
+        *by_ip.entry(picked.peer_addr.ip()).or_default() += 1;
+        order.push(picked.id);
+    }
+    order
+}
+
 /// RAII guard that unregisters an active transfer when dropped
 ///
 /// This ensures transfers are always unregistered even if the handler
@@ -749,6 +1018,227 @@ mod tests {
         assert_eq!(id.as_u64(), 42);
     }
 
+    fn register_queued(
+        registry: &TransferRegistry,
+        nickname: &str,
+        is_admin: bool,
+        total_size: u64,
+    ) -> TransferId {
+        let (id, _, _rx) = registry.register(
+            make_test_addr(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1))),
+            nickname.to_string(),
+            nickname.to_string(),
+            is_admin,
+            false,
+            TransferDirection::Download,
+            "/file".to_string(),
+            total_size,
+        );
+        id
+    }
+
+    /// Register a regular user's transfer from 10.0.0.<octet>
+    fn register_from(
+        registry: &TransferRegistry,
+        nickname: &str,
+        octet: u8,
+    ) -> std::sync::Arc<ActiveTransfer> {
+        let (_, info, _rx) = registry.register(
+            make_test_addr(IpAddr::V4(Ipv4Addr::new(10, 0, 0, octet))),
+            nickname.to_string(),
+            nickname.to_string(),
+            false,
+            false,
+            TransferDirection::Download,
+            "/file".to_string(),
+            0,
+        );
+        info
+    }
+
+    #[test]
+    fn test_try_start_unlimited() {
+        let registry = TransferRegistry::new();
+
+        let id1 = register_queued(&registry, "user1", false, 0);
+        let id2 = register_queued(&registry, "user2", false, 0);
+
+        assert_eq!(registry.try_start(id1), Ok(()));
+        assert_eq!(registry.try_start(id2), Ok(()));
+        assert_eq!(registry.queued_count(), 0);
+    }
+
+    #[test]
+    fn test_try_start_queues_beyond_limit() {
+        let registry = TransferRegistry::with_limits(1, false);
+
+        let id1 = register_queued(&registry, "user1", false, 0);
+        let id2 = register_queued(&registry, "user2", false, 0);
+        let id3 = register_queued(&registry, "user3", false, 0);
+
+        assert_eq!(registry.try_start(id1), Ok(()));
+        assert_eq!(registry.try_start(id2), Err(1));
+        assert_eq!(registry.try_start(id3), Err(2));
+        assert_eq!(registry.queued_count(), 2);
+
+        // Finishing the running transfer lets the head of the queue start
+        registry.unregister(id1);
+        assert_eq!(registry.try_start(id3), Err(1));
+        assert_eq!(registry.try_start(id2), Ok(()));
+        assert_eq!(registry.try_start(id3), Err(1));
+    }
+
+    #[test]
+    fn test_try_start_ignores_transfers_not_yet_waiting() {
+        let registry = TransferRegistry::with_limits(1, false);
+
+        // id1 is registered but still resolving its request (never called try_start)
+        let _id1 = register_queued(&registry, "user1", false, 0);
+        let id2 = register_queued(&registry, "user2", false, 0);
+
+        assert_eq!(registry.try_start(id2), Ok(()));
+    }
+
+    #[test]
+    fn test_try_start_admin_priority() {
+        let registry = TransferRegistry::with_limits(1, false);
+
+        let running = register_queued(&registry, "runner", false, 0);
+        assert_eq!(registry.try_start(running), Ok(()));
+
+        let user = register_queued(&registry, "user", false, 0);
+        let admin = register_queued(&registry, "admin", true, 0);
+        assert_eq!(registry.try_start(user), Err(1));
+
+        // Admin jumps ahead of the earlier regular user
+        assert_eq!(registry.try_start(admin), Err(1));
+        assert_eq!(registry.try_start(user), Err(2));
+
+        registry.unregister(running);
+        assert_eq!(registry.try_start(user), Err(1));
+        assert_eq!(registry.try_start(admin), Ok(()));
+    }
+
+    #[test]
+    fn test_try_start_small_files_first() {
+        let registry = TransferRegistry::with_limits(1, true);
+
+        let running = register_queued(&registry, "runner", false, 0);
+        assert_eq!(registry.try_start(running), Ok(()));
+
+        let huge = register_queued(&registry, "huge", false, 10_000_000_000);
+        let small = register_queued(&registry, "small", false, 1024);
+        assert_eq!(registry.try_start(huge), Err(1));
+        assert_eq!(registry.try_start(small), Err(1));
+        assert_eq!(registry.try_start(huge), Err(2));
+
+        // Disabling the policy restores first-come first-served order
+        registry.set_small_files_first(false);
+        assert_eq!(registry.try_start(huge), Err(1));
+        assert_eq!(registry.try_start(small), Err(2));
+    }
+
+    #[test]
+    fn test_fair_share_interleaves_users() {
+        let registry = TransferRegistry::with_limits(1, false);
+
+        // alice already has a transfer running and queues two more
+        let running = register_from(&registry, "alice", 1);
+        assert_eq!(registry.try_start(running.id), Ok(()));
+        let a1 = register_from(&registry, "alice", 1);
+        let a2 = register_from(&registry, "alice", 1);
+        assert_eq!(registry.try_start(a1.id), Err(1));
+        assert_eq!(registry.try_start(a2.id), Err(2));
+
+        // bob arrives later but has nothing running, so he goes first
+        let b1 = register_from(&registry, "bob", 2);
+        assert_eq!(registry.try_start(b1.id), Err(1));
+        assert_eq!(registry.try_start(a1.id), Err(2));
+        assert_eq!(registry.try_start(a2.id), Err(3));
+
+        // Once alice's transfer finishes, her queued transfers alternate with bob's
+        registry.unregister(running.id);
+        let b2 = register_from(&registry, "bob", 2);
+        assert_eq!(registry.try_start(b2.id), Err(3));
+        assert_eq!(registry.try_start(a1.id), Ok(()));
+        assert_eq!(registry.try_start(b1.id), Err(1));
+        assert_eq!(registry.try_start(a2.id), Err(2));
+    }
+
+    #[test]
+    fn test_fair_share_counts_per_ip() {
+        let registry = TransferRegistry::with_limits(1, false);
+
+        // Different nicknames behind the same IP share its load
+        let running = register_from(&registry, "alice", 1);
+        assert_eq!(registry.try_start(running.id), Ok(()));
+        let sock = register_from(&registry, "sockpuppet", 1);
+        let bob = register_from(&registry, "bob", 2);
+        assert_eq!(registry.try_start(sock.id), Err(1));
+        assert_eq!(registry.try_start(bob.id), Err(1));
+        assert_eq!(registry.try_start(sock.id), Err(2));
+    }
+
+    #[test]
+    fn test_fair_share_weight() {
+        let registry = TransferRegistry::with_limits(2, false);
+
+        let alice_running = register_from(&registry, "alice", 1);
+        let bob_running = register_from(&registry, "bob", 2);
+        alice_running.set_weight(2);
+        assert_eq!(registry.try_start(alice_running.id), Ok(()));
+        assert_eq!(registry.try_start(bob_running.id), Ok(()));
+
+        // Both have one transfer running, but alice's weight halves her load
+        let bob = register_from(&registry, "bob", 2);
+        let alice = register_from(&registry, "alice", 1);
+        alice.set_weight(2);
+        assert_eq!(registry.try_start(bob.id), Err(1));
+        assert_eq!(registry.try_start(alice.id), Err(1));
+        assert_eq!(registry.try_start(bob.id), Err(2));
+
+        // Weight is reported to the connection monitor
+        assert_eq!(alice.to_transfer_info().weight, 2);
+        assert!(alice.to_transfer_info().queued);
+        assert!(!alice_running.to_transfer_info().queued);
+    }
+
+    #[test]
+    fn test_set_weight_minimum_one() {
+        let registry = TransferRegistry::new();
+        let info = register_from(&registry, "alice", 1);
+        assert_eq!(info.weight(), 1);
+        info.set_weight(0);
+        assert_eq!(info.weight(), 1);
+    }
+
+    #[test]
+    fn test_raising_limit_starts_queued() {
+        let registry = TransferRegistry::with_limits(1, false);
+
+        let id1 = register_queued(&registry, "user1", false, 0);
+        let id2 = register_queued(&registry, "user2", false, 0);
+        assert_eq!(registry.try_start(id1), Ok(()));
+        assert_eq!(registry.try_start(id2), Err(1));
+
+        let slots = registry.subscribe_slots();
+        registry.set_max_active_transfers(2);
+        assert!(slots.has_changed().unwrap());
+        assert_eq!(registry.try_start(id2), Ok(()));
+    }
+
+    #[test]
+    fn test_unregister_notifies_slot_waiters() {
+        let registry = TransferRegistry::with_limits(1, false);
+
+        let id = register_queued(&registry, "user1", false, 0);
+        let slots = registry.subscribe_slots();
+        assert!(!slots.has_changed().unwrap());
+
+        registry.unregister(id);
+        assert!(slots.has_changed().unwrap());
+    }
+
     #[test]
     fn test_double_ban_signal() {
         let registry = TransferRegistry::new();
@@ -773,4 +1263,16 @@ mod tests {
         let disconnected2 = registry.disconnect_matching(|_| true);
         assert_eq!(disconnected2, 0);
     }
+
+    #[test]
+    fn test_stale_upload_age() {
+        let registry = TransferRegistry::new();
+        assert_eq!(
+            registry.stale_upload_age(),
+            Duration::from_secs(u64::from(DEFAULT_STALE_UPLOAD_DAYS) * 86400)
+        );
+
+        let registry = TransferRegistry::new().with_stale_upload_age(Duration::from_secs(60));
+        assert_eq!(registry.stale_upload_age(), Duration::from_secs(60));
+    }
 }
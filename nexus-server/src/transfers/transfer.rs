@@ -8,6 +8,7 @@ use std::io;
 use std::net::SocketAddr;
 use std::path::Path;
 use std::sync::Arc;
+use std::time::Duration;
 
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 use tokio::sync::oneshot;
@@ -17,12 +18,20 @@ use nexus_common::io::send_server_message_with_id;
 use nexus_common::protocol::ServerMessage;
 
 use crate::files::FileIndex;
+use crate::stats::StatsCollector;
 
+use super::journal::PartClaims;
 use super::registry::{ActiveTransfer, TransferId, TransferRegistry, TransferRegistryGuard};
+use super::types::AuthenticatedUser;
 
 /// Chunk size for streaming file data (64KB)
 const CHUNK_SIZE: usize = 64 * 1024;
-use super::types::AuthenticatedUser;
+
+/// Interval for re-sending TransferQueued while waiting for a slot
+///
+/// Must be well below the client's idle timeout so queued clients don't
+/// give up while waiting.
+const QUEUE_KEEPALIVE_INTERVAL: Duration = Duration::from_secs(15);
 
 /// Error type for streaming operations
 ///
@@ -90,6 +99,8 @@ pub struct Transfer<'a, R, W> {
     debug: bool,
     file_root: &'a Path,
     file_index: &'a Arc<FileIndex>,
+    registry: &'a TransferRegistry,
+    stats: &'a StatsCollector,
 
     // RAII cleanup (must be last so it drops after other fields)
     _guard: TransferRegistryGuard<'a>,
@@ -117,6 +128,7 @@ where
         file_root: &'a Path,
         file_index: &'a Arc<FileIndex>,
         registry: &'a TransferRegistry,
+        stats: &'a StatsCollector,
         transfer_id: TransferId,
     ) -> Self {
         Self {
@@ -130,6 +142,8 @@ where
             debug,
             file_root,
             file_index,
+            registry,
+            stats,
             _guard: TransferRegistryGuard::new(registry, transfer_id),
         }
     }
@@ -194,6 +208,16 @@ where
         self.file_index
     }
 
+    /// Get the statistics collector that counts finished transfers
+    pub fn stats(&self) -> &'a StatsCollector {
+        self.stats
+    }
+
+    /// Get the .part files being written by uploads
+    pub fn part_claims(&self) -> &'a PartClaims {
+        self.registry.part_claims()
+    }
+
     /// Send a server message
     pub async fn send(&mut self, msg: &ServerMessage) -> Result<(), StreamError> {
         self.send_with_id(msg, MessageId::new()).await
@@ -210,6 +234,64 @@ where
             .map_err(StreamError::Io)
     }
 
+    /// Wait until the registry grants this transfer an active slot
+    ///
+    /// Returns immediately when the server-wide active transfer limit is not
+    /// reached. Otherwise sends `TransferQueued` with the current queue
+    /// position, re-sending it whenever the position changes and periodically
+    /// as a keepalive, until a slot frees up.
+    ///
+    /// # Returns
+    /// * `Ok(())` once the transfer may proceed
+    /// * `Err(StreamError::Banned)` if banned while queued
+    /// * `Err(StreamError::Io(_))` if the client went away while queued
+    pub async fn wait_for_slot(&mut self) -> Result<(), StreamError> {
+        // Subscribe before the first check so no wakeup is missed
+        let mut slots = self.registry.subscribe_slots();
+        let mut last_position = None;
+
+        loop {
+            if self.is_banned() {
+                return Err(StreamError::Banned);
+            }
+
+            let position = match self.registry.try_start(self.info.id) {
+                Ok(()) => {
+                    if self.debug && last_position.is_some() {
+                        log_debug!(
+                            "Transfer {} left queue after {:.2}s",
+                            self.info.id,
+                            self.info.elapsed().as_secs_f64()
+                        );
+                    }
+                    return Ok(());
+                }
+                Err(position) => position,
+            };
+
+            if last_position != Some(position) {
+                if self.debug {
+                    log_debug!(
+                        "Transfer {} queued at position {position} from {}",
+                        self.info.id,
+                        self.info.peer_addr
+                    );
+                }
+                self.send(&ServerMessage::TransferQueued { position })
+                    .await?;
+                last_position = Some(position);
+            }
+
+            tokio::select! {
+                _ = slots.changed() => {}
+                _ = tokio::time::sleep(QUEUE_KEEPALIVE_INTERVAL) => {
+                    // Re-send the current position to keep the client's idle timer alive
+                    last_position = None;
+                }
+            }
+        }
+    }
+
     /// Stream a file to the client with periodic ban checking
     ///
     /// This method streams file data in chunks, checking for ban signals between
@@ -483,6 +565,7 @@ mod tests {
 
     fn make_test_user() -> AuthenticatedUser {
         AuthenticatedUser {
+            user_id: 1,
             nickname: "testuser".to_string(),
             username: "testuser".to_string(),
             is_admin: false,
@@ -539,6 +622,7 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let file_root = temp_dir.path();
         let file_index = make_test_file_index(&temp_dir);
+        let stats = StatsCollector::new();
 
         let transfer = Transfer::new(
             FrameReader::new(tokio::io::BufReader::new(server_read)),
@@ -551,6 +635,7 @@ mod tests {
             file_root,
             &file_index,
             &registry,
+            &stats,
             transfer_id,
         );
 
@@ -586,6 +671,7 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let file_root = temp_dir.path();
         let file_index = make_test_file_index(&temp_dir);
+        let stats = StatsCollector::new();
 
         let mut transfer = Transfer::new(
             FrameReader::new(tokio::io::BufReader::new(server_read)),
@@ -598,6 +684,7 @@ mod tests {
             file_root,
             &file_index,
             &registry,
+            &stats,
             transfer_id,
         );
 
@@ -616,6 +703,75 @@ mod tests {
         drop(client);
     }
 
+    #[tokio::test]
+    async fn test_wait_for_slot_queues_until_slot_free() {
+        let registry = TransferRegistry::with_limits(1, false);
+        let (client, server) = duplex(4096);
+        let (server_read, server_write) = tokio::io::split(server);
+        let (client_read, _client_write) = tokio::io::split(client);
+
+        // Another transfer occupies the only slot
+        let (other_id, _, _other_rx) = registry.register(
+            make_test_addr(),
+            "other".to_string(),
+            "other".to_string(),
+            false,
+            false,
+            TransferDirection::Download,
+            "/other.zip".to_string(),
+            0,
+        );
+        assert_eq!(registry.try_start(other_id), Ok(()));
+
+        let (transfer_id, info, ban_rx) = registry.register(
+            make_test_addr(),
+            "testuser".to_string(),
+            "testuser".to_string(),
+            false,
+            false,
+            TransferDirection::Download,
+            "/test/file.zip".to_string(),
+            0,
+        );
+
+        let temp_dir = TempDir::new().unwrap();
+        let file_root = temp_dir.path();
+        let file_index = make_test_file_index(&temp_dir);
+        let stats = StatsCollector::new();
+
+        let mut transfer = Transfer::new(
+            FrameReader::new(tokio::io::BufReader::new(server_read)),
+            FrameWriter::new(server_write),
+            ban_rx,
+            info.clone(),
+            make_test_user(),
+            "en".to_string(),
+            false,
+            file_root,
+            &file_index,
+            &registry,
+            &stats,
+            transfer_id,
+        );
+
+        let mut client_reader = FrameReader::new(tokio::io::BufReader::new(client_read));
+        let (result, ()) = tokio::join!(transfer.wait_for_slot(), async {
+            let received = nexus_common::io::read_server_message(&mut client_reader)
+                .await
+                .unwrap()
+                .unwrap();
+            assert!(matches!(
+                received.message,
+                ServerMessage::TransferQueued { position: 1 }
+            ));
+            // Free the slot
+            registry.unregister(other_id);
+        });
+
+        assert!(result.is_ok());
+        assert!(info.is_running());
+    }
+
     #[tokio::test]
     async fn test_transfer_send_works_when_banned() {
         // Verifies that send() still works even after ban (for protocol cleanup)
@@ -638,6 +794,7 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let file_root = temp_dir.path();
         let file_index = make_test_file_index(&temp_dir);
+        let stats = StatsCollector::new();
 
         let mut transfer = Transfer::new(
             FrameReader::new(tokio::io::BufReader::new(server_read)),
@@ -650,6 +807,7 @@ mod tests {
             file_root,
             &file_index,
             &registry,
+            &stats,
             transfer_id,
         );
 
@@ -661,6 +819,7 @@ mod tests {
         let msg = ServerMessage::Error {
             message: "Test".to_string(),
             command: None,
+            error_kind: None,
         };
         let result = transfer.send(&msg).await;
         assert!(result.is_ok());
@@ -690,6 +849,7 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let file_root = temp_dir.path();
         let file_index = make_test_file_index(&temp_dir);
+        let stats = StatsCollector::new();
 
         let mut transfer = Transfer::new(
             FrameReader::new(tokio::io::BufReader::new(server_read)),
@@ -702,6 +862,7 @@ mod tests {
             file_root,
             &file_index,
             &registry,
+            &stats,
             transfer_id,
         );
 
@@ -740,6 +901,7 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let file_root = temp_dir.path();
         let file_index = make_test_file_index(&temp_dir);
+        let stats = StatsCollector::new();
 
         let mut transfer = Transfer::new(
             FrameReader::new(tokio::io::BufReader::new(server_read)),
@@ -752,6 +914,7 @@ mod tests {
             file_root,
             &file_index,
             &registry,
+            &stats,
             transfer_id,
         );
 
@@ -794,6 +957,7 @@ mod tests {
             let temp_dir = TempDir::new().unwrap();
             let file_root = temp_dir.path();
             let file_index = make_test_file_index(&temp_dir);
+            let stats = StatsCollector::new();
 
             let _transfer = Transfer::new(
                 FrameReader::new(tokio::io::BufReader::new(server_read)),
@@ -806,6 +970,7 @@ mod tests {
                 file_root,
                 &file_index,
                 &registry,
+                &stats,
                 transfer_id,
             );
 
@@ -837,6 +1002,7 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let file_root = temp_dir.path();
         let file_index = make_test_file_index(&temp_dir);
+        let stats = StatsCollector::new();
 
         let mut transfer = Transfer::new(
             FrameReader::new(tokio::io::BufReader::new(server_read)),
@@ -849,6 +1015,7 @@ mod tests {
             file_root,
             &file_index,
             &registry,
+            &stats,
             transfer_id,
         );
 
@@ -7,8 +7,12 @@ use std::net::SocketAddr;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use crate::db::{Database, Permission};
+use crate::db::{Database, Permission, UploadJournalDb, UploadRuleDb};
 use crate::files::FileIndex;
+use crate::ldap::Ldap;
+use crate::login_tokens::LoginTokens;
+use crate::stats::StatsCollector;
+use crate::storage::StorageConfig;
 
 use super::registry::TransferRegistry;
 
@@ -21,6 +25,16 @@ pub struct TransferParams {
     pub file_index: Arc<FileIndex>,
     /// Transfer registry for ban signal handling
     pub transfer_registry: Arc<TransferRegistry>,
+    /// Login tokens issued on the BBS port (transfer logins redeem them)
+    pub login_tokens: LoginTokens,
+    /// Statistics collector that counts finished transfers
+    pub stats: Arc<StatsCollector>,
+    /// Directory logins (accounts created through LDAP are checked there)
+    pub ldap: Ldap,
+    /// Free space to keep on the file area's disk
+    pub storage: StorageConfig,
+    /// Whether the peer IP is on the trust list (gets a larger fair share)
+    pub is_trusted: bool,
 }
 
 /// Information about a file to transfer (for downloads)
@@ -35,6 +49,8 @@ pub(crate) struct FileInfo {
 
 /// Authenticated user information (minimal for transfer port)
 pub(crate) struct AuthenticatedUser {
+    /// Account ID in the database
+    pub user_id: i64,
     pub nickname: String,
     pub username: String,
     pub is_admin: bool,
@@ -42,7 +58,15 @@ pub(crate) struct AuthenticatedUser {
     pub permissions: HashSet<Permission>,
 }
 
-/// Request type after authentication (either download or upload)
+/// Request read after authentication
+pub(crate) enum PortRequest {
+    /// File download or upload
+    Transfer(TransferRequest),
+    /// Server export archive
+    Export(ExportParams),
+}
+
+/// Transfer request type (either download or upload)
 pub(crate) enum TransferRequest {
     Download(DownloadParams),
     Upload(UploadParams),
@@ -52,6 +76,8 @@ pub(crate) enum TransferRequest {
 pub(crate) struct DownloadParams {
     pub path: String,
     pub root: bool,
+    /// Optional subset of files to transfer (relative to the requested directory)
+    pub include: Option<Vec<String>>,
 }
 
 /// Parameters for an upload request
@@ -60,12 +86,22 @@ pub(crate) struct UploadParams {
     pub file_count: u64,
     pub total_size: u64,
     pub root: bool,
+    /// Replace existing files with different content
+    pub overwrite: bool,
+}
+
+/// Parameters for a server export request
+pub(crate) struct ExportParams {
+    pub include_ip_rules: bool,
 }
 
 /// Parameters for receiving a file upload
 pub(crate) struct ReceiveFileParams<'a> {
     pub area_root: &'a Path,
     pub destination: &'a Path,
+    pub upload_rules: &'a UploadRuleDb,
+    pub upload_journal: &'a UploadJournalDb,
+    pub overwrite: bool,
     pub locale: &'a str,
     pub debug: bool,
     pub transfer_id: &'a str,
@@ -10,18 +10,23 @@ use nexus_common::framing::{
     DEFAULT_PROGRESS_TIMEOUT, FrameHeader, FrameReader, FrameWriter, MessageId,
 };
 use nexus_common::io::{read_client_message_with_full_timeout, send_server_message_with_id};
-use nexus_common::protocol::{ClientMessage, ServerMessage};
+use nexus_common::protocol::{ClientMessage, FileChangeKind, ServerMessage, UploadRule};
 use nexus_common::validators;
 
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 
-use crate::db::Permission;
+use crate::db::{Database, Permission, UploadRuleDb};
+use crate::files::FileIndex;
 use crate::files::path::{allows_upload, validate_and_build_candidate_path};
 use crate::handlers::{
-    err_upload_conflict, err_upload_connection_lost, err_upload_destination_not_allowed,
-    err_upload_empty, err_upload_file_exists, err_upload_hash_mismatch, err_upload_path_invalid,
-    err_upload_protocol_error, err_upload_write_failed,
+    err_database, err_upload_conflict, err_upload_connection_lost,
+    err_upload_destination_not_allowed, err_upload_empty, err_upload_file_exists,
+    err_upload_hash_mismatch, err_upload_insufficient_storage, err_upload_path_invalid,
+    err_upload_protocol_error, err_upload_rule_extension, err_upload_rule_file_size,
+    err_upload_rule_total_size, err_upload_write_failed,
 };
+use crate::stats::file_key;
+use crate::storage::StorageConfig;
 
 use super::hash::{compute_file_sha256, compute_file_sha256_with_keepalive};
 use super::helpers::{
@@ -29,6 +34,7 @@ use super::helpers::{
     generate_transfer_id, path_error_to_transfer_error, resolve_area_root,
     send_upload_transfer_error, validate_transfer_path,
 };
+use super::journal::{forget_upload, is_same_upload, record_interrupted, record_upload};
 use super::transfer::{StreamError, Transfer};
 use super::types::{ReceiveFileParams, UploadParams};
 
@@ -45,6 +51,8 @@ const FALLBACK_PART_FILE_NAME: &str = "file.part";
 /// Handle a file upload request
 pub(crate) async fn handle_upload<R, W>(
     transfer: &mut Transfer<'_, R, W>,
+    db: &Database,
+    storage: &StorageConfig,
     params: UploadParams,
 ) -> io::Result<()>
 where
@@ -56,6 +64,7 @@ where
         file_count,
         total_size,
         root: use_root,
+        overwrite,
     } = params;
 
     // Extract values to avoid borrow checker issues
@@ -76,16 +85,70 @@ where
             Err(e) => return send_upload_transfer_error(transfer.writer(), &e).await,
         };
 
+    // Replacing existing files is as destructive as deleting them
+    if overwrite && let Err(e) = check_permission(transfer.user(), Permission::FileDelete, &locale)
+    {
+        return send_upload_transfer_error(transfer.writer(), &e).await;
+    }
+
+    // Check the destination's upload rule before queueing
+    let rule = match effective_upload_rule(
+        &db.upload_rules,
+        transfer.file_root(),
+        &resolved_destination,
+        &locale,
+    )
+    .await
+    {
+        Ok(rule) => rule,
+        Err(e) => return send_upload_transfer_error(transfer.writer(), &e).await,
+    };
+    if let Some(rule) = rule
+        && !rule.allows_total_size(total_size)
+    {
+        let max_size = rule.max_total_size.unwrap_or_default();
+        let err = TransferError::upload_rule(err_upload_rule_total_size(&locale, max_size));
+        return send_upload_transfer_error(transfer.writer(), &err).await;
+    }
+
+    // Keep the server's minimum free space (resumed uploads count in full)
+    if !storage.has_room(&area_root, total_size) {
+        let err = TransferError::insufficient_storage(err_upload_insufficient_storage(&locale));
+        return send_upload_transfer_error(transfer.writer(), &err).await;
+    }
+
     // Generate transfer ID for logging
     let log_transfer_id = generate_transfer_id();
 
     if debug {
-        eprintln!(
+        log_debug!(
             "Upload {log_transfer_id}: {} files, {} bytes to {} from {}",
-            file_count, total_size, destination, peer_addr
+            file_count,
+            total_size,
+            destination,
+            peer_addr
         );
     }
 
+    // Wait for an active transfer slot (sends TransferQueued while waiting)
+    match transfer.wait_for_slot().await {
+        Ok(()) => {}
+        Err(StreamError::Banned) => {
+            // Just close the socket - client gets ban reason on BBS connection
+            if debug {
+                log_debug!("Upload {log_transfer_id}: Terminated by ban while queued");
+            }
+            let _ = transfer.writer().get_mut().shutdown().await;
+            return Ok(());
+        }
+        Err(e) => {
+            if debug {
+                log_debug!("Upload {log_transfer_id}: Client left queue: {e}");
+            }
+            return Ok(());
+        }
+    }
+
     // Send FileUploadResponse
     let response = ServerMessage::FileUploadResponse {
         success: true,
@@ -95,7 +158,7 @@ where
     };
     if let Err(e) = transfer.send(&response).await {
         if debug {
-            eprintln!("Upload {log_transfer_id}: Failed to send response: {e}");
+            log_debug!("Upload {log_transfer_id}: Failed to send response: {e}");
         }
         return Ok(());
     }
@@ -109,6 +172,9 @@ where
         let params = ReceiveFileParams {
             area_root: &area_root,
             destination: &resolved_destination,
+            upload_rules: &db.upload_rules,
+            upload_journal: &db.upload_journal,
+            overwrite,
             locale: &locale,
             debug,
             transfer_id: &log_transfer_id,
@@ -121,14 +187,14 @@ where
             Err(ReceiveFileError::Banned) => {
                 // Just close the socket - client gets ban reason on BBS connection
                 if debug {
-                    eprintln!("Upload {log_transfer_id}: Terminated by ban");
+                    log_debug!("Upload {log_transfer_id}: Terminated by ban");
                 }
                 let _ = transfer.writer().get_mut().shutdown().await;
                 return Ok(());
             }
             Err(ReceiveFileError::Transfer(e)) => {
                 if debug {
-                    eprintln!(
+                    log_debug!(
                         "Upload {log_transfer_id}: Error receiving file {file_index}: {}",
                         e.message
                     );
@@ -149,19 +215,20 @@ where
     };
     let _ = transfer.send(&complete).await; // Best effort - connection may be closing
 
+    if transfer_success {
+        transfer
+            .stats()
+            .record_upload(transfer.user().user_id, transfer.bytes_transferred());
+    }
+
     if debug {
         if transfer_success {
-            eprintln!("Upload {log_transfer_id}: Complete");
+            log_debug!("Upload {log_transfer_id}: Complete");
         } else {
-            eprintln!("Upload {log_transfer_id}: Failed");
+            log_debug!("Upload {log_transfer_id}: Failed");
         }
     }
 
-    // Mark file index as dirty on successful upload so it gets rebuilt
-    if transfer_success {
-        transfer.file_index().mark_dirty();
-    }
-
     // Close connection
     let _ = transfer.writer().get_mut().shutdown().await;
 
@@ -216,6 +283,9 @@ where
     let ReceiveFileParams {
         area_root,
         destination,
+        upload_rules,
+        upload_journal,
+        overwrite,
         locale,
         debug,
         transfer_id,
@@ -227,9 +297,10 @@ where
         read_client_file_start(transfer.reader(), locale).await?;
 
     if debug {
-        eprintln!(
+        log_debug!(
             "Upload {transfer_id}: Receiving file {file_index}: {} ({} bytes)",
-            relative_path, file_size
+            relative_path,
+            file_size
         );
     }
 
@@ -237,6 +308,12 @@ where
     let (target_path, part_path) =
         validate_and_build_upload_paths(&relative_path, destination, area_root, locale)?;
 
+    // Files in folders created by the upload follow the nearest existing rule
+    let target_dir = target_path.parent().unwrap_or(destination);
+    let rule =
+        effective_upload_rule(upload_rules, transfer.file_root(), target_dir, locale).await?;
+    check_upload_rule(rule.as_ref(), &target_path, file_size, locale)?;
+
     // Check for conflicts and get existing file state
     // Sends FileHashing keepalives to client while hashing large existing files
     let (existing_size, existing_hash) = check_upload_conflicts_and_get_state(
@@ -245,6 +322,7 @@ where
         &part_path,
         file_size,
         &client_sha256,
+        overwrite,
         locale,
     )
     .await?;
@@ -261,8 +339,12 @@ where
     // Handle zero-byte files - NO FileData frame expected
     if file_size == 0 {
         create_empty_file(&target_path, locale).await?;
+        transfer
+            .stats()
+            .record_file_upload(transfer.file_root(), &target_path);
+        notify_uploaded(transfer.file_index(), destination, &target_path);
         if debug {
-            eprintln!("Upload {transfer_id}: Created empty file {}", relative_path);
+            log_debug!("Upload {transfer_id}: Created empty file {}", relative_path);
         }
         return Ok(());
     }
@@ -270,20 +352,41 @@ where
     // Check if file is already complete (sizes and hashes match) - NO FileData expected
     if is_file_already_complete(&existing_hash, &client_sha256, existing_size, file_size) {
         if debug {
-            eprintln!("Upload {transfer_id}: {} already complete", relative_path);
+            log_debug!("Upload {transfer_id}: {} already complete", relative_path);
         }
         finalize_part_file_if_exists(&part_path, &target_path, locale).await?;
+        if let Some(part_key) = file_key(transfer.file_root(), &part_path) {
+            forget_upload(upload_journal, &part_key).await;
+        }
+        notify_uploaded(transfer.file_index(), destination, &target_path);
         return Ok(());
     }
 
     // Read FileData header and calculate offset
     let (header, offset) = read_file_data_header(transfer.reader(), file_size, locale).await?;
 
+    // Only one upload writes a .part file at a time
+    let Some(_claim) = transfer.part_claims().acquire(&part_path) else {
+        return Err(TransferError::conflict(err_upload_conflict(locale)).into());
+    };
+
+    // A leftover .part file journaled for this same upload can be replaced: a
+    // crash may have left bytes the client can't match, so it starts over
+    let part_key = file_key(transfer.file_root(), &part_path);
+    let restart = offset == 0
+        && existing_size > 0
+        && match &part_key {
+            Some(part_key) => {
+                is_same_upload(upload_journal, part_key, &client_sha256, file_size).await
+            }
+            None => false,
+        };
+
     // Check for concurrent upload conflict
-    check_resume_conflict(offset, existing_size, locale)?;
+    check_resume_conflict(offset, existing_size, restart, locale)?;
 
     if debug && offset > 0 {
-        eprintln!(
+        log_debug!(
             "Upload {transfer_id}: Resuming {} from offset {} ({}%)",
             relative_path,
             offset,
@@ -291,30 +394,90 @@ where
         );
     }
 
+    if let Some(part_key) = &part_key
+        && let Some(target_key) = file_key(transfer.file_root(), &target_path)
+    {
+        record_upload(
+            upload_journal,
+            part_key,
+            &target_key,
+            &transfer.user().username,
+            &client_sha256,
+            file_size,
+            offset,
+        )
+        .await;
+    }
+
     // Stream file data to .part file with ban checking
-    let bytes_written =
-        stream_to_part_file(transfer, &header, &target_path, &part_path, offset, locale).await?;
+    let streamed = stream_to_part_file(
+        transfer,
+        &header,
+        &target_path,
+        &part_path,
+        offset,
+        restart,
+        locale,
+    )
+    .await;
+    let bytes_written = match streamed {
+        Ok(bytes_written) => bytes_written,
+        Err(e) => {
+            // Keep what arrived so the client can resume
+            if let Some(part_key) = &part_key {
+                record_interrupted(upload_journal, part_key, &part_path).await;
+            }
+            return Err(e);
+        }
+    };
 
     if debug {
-        eprintln!(
+        log_debug!(
             "Upload {transfer_id}: Received {} bytes for {}",
-            bytes_written, relative_path
+            bytes_written,
+            relative_path
         );
     }
 
-    // Verify hash and finalize
-    verify_and_finalize_upload(&part_path, &target_path, &client_sha256, locale).await?;
+    // Verify hash and finalize (a mismatched .part file is deleted too)
+    let verified =
+        verify_and_finalize_upload(&part_path, &target_path, &client_sha256, locale).await;
+    if let Some(part_key) = &part_key
+        && (verified.is_ok() || !part_path.exists())
+    {
+        forget_upload(upload_journal, part_key).await;
+    }
+    verified?;
+    transfer
+        .stats()
+        .record_file_upload(transfer.file_root(), &target_path);
+    notify_uploaded(transfer.file_index(), destination, &target_path);
 
     if debug {
-        eprintln!(
+        log_debug!(
             "Upload {transfer_id}: Completed {} ({} bytes, hash verified)",
-            relative_path, file_size
+            relative_path,
+            file_size
         );
     }
 
     Ok(())
 }
 
+/// Tell watchers about an uploaded file and the folders created for it
+///
+/// Each folder between the destination and the file is reported as added to
+/// its parent, since a watcher of the destination only sees the top one.
+/// Folders that already existed are reported again, which is harmless.
+fn notify_uploaded(file_index: &FileIndex, destination: &Path, target_path: &Path) {
+    for path in target_path
+        .ancestors()
+        .take_while(|path| path.starts_with(destination) && *path != destination)
+    {
+        file_index.notify_change(path, FileChangeKind::Added);
+    }
+}
+
 // =============================================================================
 // Validation Helpers
 // =============================================================================
@@ -409,6 +572,54 @@ where
     Ok((area_root, resolved_destination))
 }
 
+/// Get the upload rule that applies to a directory
+async fn effective_upload_rule(
+    upload_rules: &UploadRuleDb,
+    file_root: &Path,
+    dir: &Path,
+    locale: &str,
+) -> Result<Option<UploadRule>, TransferError> {
+    let Some(key) = file_key(file_root, dir) else {
+        return Ok(None);
+    };
+    upload_rules.effective(&key).await.map_err(|e| {
+        log_error!("Upload rule database error: {}", e);
+        TransferError::io_error(err_database(locale))
+    })
+}
+
+/// Check a file against the upload rule of the directory it goes into
+fn check_upload_rule(
+    rule: Option<&UploadRule>,
+    target_path: &Path,
+    file_size: u64,
+    locale: &str,
+) -> Result<(), TransferError> {
+    let Some(rule) = rule else {
+        return Ok(());
+    };
+    let name = target_path
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_default();
+
+    if !rule.allows_extension(&name) {
+        return Err(TransferError::upload_rule(err_upload_rule_extension(
+            locale,
+            &name,
+            &rule.extensions,
+        )));
+    }
+    if !rule.allows_file_size(file_size) {
+        return Err(TransferError::upload_rule(err_upload_rule_file_size(
+            locale,
+            &name,
+            rule.max_file_size.unwrap_or_default(),
+        )));
+    }
+    Ok(())
+}
+
 /// Validate relative path and build target/part paths
 fn validate_and_build_upload_paths(
     relative_path: &str,
@@ -476,6 +687,7 @@ async fn check_upload_conflicts_and_get_state<W>(
     part_path: &Path,
     file_size: u64,
     client_sha256: &str,
+    overwrite: bool,
     locale: &str,
 ) -> Result<(u64, Option<String>), TransferError>
 where
@@ -507,8 +719,9 @@ where
             false
         };
 
-        if !same_content {
-            // Different content - return error, don't auto-rename
+        // Different content - return error, don't auto-rename. When overwriting,
+        // the existing file stays in place until the new one is complete.
+        if !same_content && (!overwrite || target_path.is_dir()) {
             return Err(TransferError::exists(err_upload_file_exists(locale)));
         }
         // Same content - will be handled as "already complete" by caller
@@ -553,15 +766,19 @@ fn is_file_already_complete(
 }
 
 /// Check for concurrent upload conflict (different uploader) and offset mismatch
+///
+/// `restart` is set when the journal shows the existing .part file belongs to
+/// an earlier attempt at this same upload, which may then start over.
 fn check_resume_conflict(
     offset: u64,
     existing_size: u64,
+    restart: bool,
     locale: &str,
 ) -> Result<(), TransferError> {
     // CONFLICT DETECTION: If client is sending full file (offset == 0) but a .part file
     // already existed with data (existing_size > 0), this is a DIFFERENT uploader.
     // Return an error instead of overwriting - the original uploader can still resume.
-    if offset == 0 && existing_size > 0 {
+    if offset == 0 && existing_size > 0 && !restart {
         return Err(TransferError::conflict(err_upload_conflict(locale)));
     }
 
@@ -723,6 +940,7 @@ async fn stream_to_part_file<R, W>(
     target_path: &Path,
     part_path: &Path,
     offset: u64,
+    restart: bool,
     locale: &str,
 ) -> Result<u64, ReceiveFileError>
 where
@@ -738,8 +956,16 @@ where
 
     // Open .part file for writing
     // For fresh uploads (offset == 0), use create_new(true) to atomically fail if the file
-    // already exists. This prevents TOCTOU race conditions.
-    let file_result = if offset == 0 {
+    // already exists. This prevents TOCTOU race conditions. A restart truncates the
+    // leftover .part file of the same upload instead.
+    let file_result = if restart {
+        tokio::fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(part_path)
+            .await
+    } else if offset == 0 {
         tokio::fs::OpenOptions::new()
             .create_new(true) // Atomic: fails if file exists
             .write(true)
@@ -819,6 +1045,7 @@ async fn verify_and_finalize_upload(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use nexus_common::ERROR_KIND_UPLOAD_RULE;
     use tempfile::TempDir;
     use tokio::fs;
 
@@ -829,6 +1056,85 @@ mod tests {
         FrameWriter::new(Vec::new())
     }
 
+    // =========================================================================
+    // notify_uploaded tests
+    // =========================================================================
+
+    #[test]
+    fn test_notify_uploaded_reports_created_folders() {
+        let temp_dir = TempDir::new().unwrap();
+        let destination = temp_dir.path().canonicalize().unwrap();
+        std::fs::create_dir_all(destination.join("album/cd1")).unwrap();
+        let index = FileIndex::new(&destination, &destination);
+        let mut changes = index.subscribe_changes();
+
+        notify_uploaded(
+            &index,
+            &destination,
+            &destination.join("album/cd1/track.mp3"),
+        );
+
+        let mut reported = Vec::new();
+        while let Ok(change) = changes.try_recv() {
+            assert_eq!(change.kind, FileChangeKind::Added);
+            reported.push((change.dir, change.name));
+        }
+        assert_eq!(
+            reported,
+            vec![
+                (destination.join("album/cd1"), "track.mp3".to_string()),
+                (destination.join("album"), "cd1".to_string()),
+                (destination.clone(), "album".to_string()),
+            ]
+        );
+        assert!(index.is_dirty());
+    }
+
+    // =========================================================================
+    // check_upload_rule tests
+    // =========================================================================
+
+    #[test]
+    fn test_upload_rule_allows_matching_file() {
+        let rule = UploadRule {
+            extensions: vec!["mp3".to_string()],
+            max_file_size: Some(100),
+            max_total_size: None,
+        };
+        let target = Path::new("/files/music/song.MP3");
+
+        assert!(check_upload_rule(None, target, u64::MAX, TEST_LOCALE).is_ok());
+        assert!(check_upload_rule(Some(&rule), target, 100, TEST_LOCALE).is_ok());
+    }
+
+    #[test]
+    fn test_upload_rule_rejects_extension_and_size() {
+        let rule = UploadRule {
+            extensions: vec!["mp3".to_string()],
+            max_file_size: Some(100),
+            max_total_size: None,
+        };
+
+        let err = check_upload_rule(
+            Some(&rule),
+            Path::new("/files/music/setup.exe"),
+            1,
+            TEST_LOCALE,
+        )
+        .unwrap_err();
+        assert_eq!(err.kind, ERROR_KIND_UPLOAD_RULE);
+        assert!(err.message.contains("setup.exe"));
+
+        let err = check_upload_rule(
+            Some(&rule),
+            Path::new("/files/music/song.mp3"),
+            101,
+            TEST_LOCALE,
+        )
+        .unwrap_err();
+        assert_eq!(err.kind, ERROR_KIND_UPLOAD_RULE);
+    }
+
     // =========================================================================
     // validate_and_build_upload_paths tests
     // =========================================================================
@@ -1047,14 +1353,14 @@ mod tests {
     #[test]
     fn test_resume_conflict_fresh_upload_no_existing() {
         // Fresh upload (offset=0), no existing .part file (existing_size=0)
-        let result = check_resume_conflict(0, 0, TEST_LOCALE);
+        let result = check_resume_conflict(0, 0, false, TEST_LOCALE);
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_resume_conflict_resume_upload() {
         // Resume upload (offset>0), existing .part file
-        let result = check_resume_conflict(500, 500, TEST_LOCALE);
+        let result = check_resume_conflict(500, 500, false, TEST_LOCALE);
         assert!(result.is_ok());
     }
 
@@ -1063,20 +1369,27 @@ mod tests {
         use nexus_common::ERROR_KIND_CONFLICT;
 
         // Fresh upload (offset=0) but .part file exists with data - CONFLICT
-        let result = check_resume_conflict(0, 500, TEST_LOCALE);
+        let result = check_resume_conflict(0, 500, false, TEST_LOCALE);
         assert!(result.is_err());
 
         let err = result.unwrap_err();
         assert_eq!(err.kind, ERROR_KIND_CONFLICT);
     }
 
+    #[test]
+    fn test_resume_conflict_same_upload_restarts() {
+        // The journal shows the .part file is from an earlier attempt at this upload
+        let result = check_resume_conflict(0, 500, true, TEST_LOCALE);
+        assert!(result.is_ok());
+    }
+
     #[test]
     fn test_resume_conflict_offset_mismatch() {
         use nexus_common::ERROR_KIND_PROTOCOL_ERROR;
 
         // Resume with mismatched offset - client claims offset 1000 but .part is 500 bytes
         // This could be malicious attempt to corrupt the file
-        let result = check_resume_conflict(1000, 500, TEST_LOCALE);
+        let result = check_resume_conflict(1000, 500, false, TEST_LOCALE);
         assert!(result.is_err());
 
         let err = result.unwrap_err();
@@ -1086,7 +1399,7 @@ mod tests {
     #[test]
     fn test_resume_conflict_offset_matches() {
         // Valid resume - client's offset matches .part file size
-        let result = check_resume_conflict(500, 500, TEST_LOCALE);
+        let result = check_resume_conflict(500, 500, false, TEST_LOCALE);
         assert!(result.is_ok());
     }
 
@@ -1107,6 +1420,7 @@ mod tests {
             &part,
             100,
             "somehash",
+            false,
             TEST_LOCALE,
         )
         .await;
@@ -1133,6 +1447,7 @@ mod tests {
             &part,
             0,
             "anyhash",
+            false,
             TEST_LOCALE,
         )
         .await;
@@ -1158,6 +1473,7 @@ mod tests {
             &part,
             100,
             "newhash",
+            false,
             TEST_LOCALE,
         )
         .await;
@@ -1167,6 +1483,46 @@ mod tests {
         assert_eq!(err.kind, nexus_common::ERROR_KIND_EXISTS);
     }
 
+    #[tokio::test]
+    async fn test_conflicts_existing_file_overwrite() {
+        let temp_dir = TempDir::new().unwrap();
+        let target = temp_dir.path().join("existing.txt");
+        let part = temp_dir.path().join("existing.txt.part");
+        let folder = temp_dir.path().join("folder");
+        let folder_part = temp_dir.path().join("folder.part");
+        let mut writer = mock_writer();
+
+        fs::write(&target, b"existing content").await.unwrap();
+        fs::create_dir(&folder).await.unwrap();
+
+        // Overwriting a file with different content is allowed
+        let result = check_upload_conflicts_and_get_state(
+            &mut writer,
+            &target,
+            &part,
+            100,
+            "newhash",
+            true,
+            TEST_LOCALE,
+        )
+        .await;
+        assert_eq!(result.unwrap(), (0, None));
+        assert!(target.exists());
+
+        // A directory is never replaced by a file
+        let result = check_upload_conflicts_and_get_state(
+            &mut writer,
+            &folder,
+            &folder_part,
+            100,
+            "newhash",
+            true,
+            TEST_LOCALE,
+        )
+        .await;
+        assert_eq!(result.unwrap_err().kind, nexus_common::ERROR_KIND_EXISTS);
+    }
+
     #[tokio::test]
     async fn test_conflicts_existing_part_file() {
         let temp_dir = TempDir::new().unwrap();
@@ -1183,6 +1539,7 @@ mod tests {
             &part,
             1000,
             "somehash",
+            false,
             TEST_LOCALE,
         )
         .await;
@@ -0,0 +1,745 @@
+//! UPnP/IGD port forwarding for NAT traversal
+//!
+//! This module provides automatic port forwarding using the UPnP/IGD (Universal Plug and Play /
+//! Internet Gateway Device) protocol, with NAT-PMP and PCP as a fallback for routers without
+//! UPnP. When enabled with the `--upnp` flag, the server will:
+//!
+//! - Discover the local router via multicast, or NAT-PMP/PCP on the default gateway
+//! - Request TCP port forwarding with a 1-hour lease
+//! - Display the external IP address, and re-check it every 5 minutes
+//! - Automatically renew the lease every 30 minutes
+//! - Clean up port mapping on graceful shutdown
+//!
+//! The current state (method, external IP, mapped ports, last error) is kept in a
+//! [`NatStatus`], which admins see in `ServerInfo` and through `NatStatus` requests.
+//!
+//! ## Usage
+//!
+//! ```bash
+//! nexusd --upnp                    # Enable UPnP with default port
+//! nexusd --upnp --port 8080        # Enable UPnP with custom port
+//! ```
+//!
+//! ## How It Works
+//!
+//! 1. **Gateway Discovery**: Sends multicast packets to discover UPnP-capable routers; if none
+//!    answers, probes the default gateway for NAT-PMP/PCP
+//! 2. **Port Mapping**: Requests TCP port forwarding from router's WAN to server's LAN
+//! 3. **Lease Renewal**: Background task renews mapping every 30 minutes (50% of lease duration)
+//! 4. **Cleanup**: Removes port mapping when server shuts down cleanly (Ctrl+C)
+//!
+//! ## IPv4 Only
+//!
+//! UPnP/IGD is designed for IPv4 NAT traversal. The module:
+//! - Works with `--bind 0.0.0.0` (default)
+//! - Works with `--bind ::` (dual-stack mode, uses IPv4 routing)
+//! - Works with specific IPv4 addresses
+//! - Rejects specific IPv6 addresses (like Yggdrasil) with a helpful error
+//!
+//! ## Local IP Detection
+//!
+//! When bound to `0.0.0.0` or `::`, the module detects the actual local IP by creating a UDP
+//! socket and checking which interface the OS would use to reach a remote address. This is a
+//! pure routing table lookup - no packets are actually sent.
+//!
+//! ## Error Handling
+//!
+//! All UPnP failures are non-fatal. If UPnP setup fails, the server continues without port
+//! forwarding and prints a warning suggesting manual configuration.
+
+use std::net::{IpAddr, Ipv4Addr, SocketAddrV4};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use igd_next::{PortMappingProtocol, SearchOptions};
+use nexus_common::protocol::{NatInfo, NatMethod};
+use nexus_common::validators::MAX_ERROR_LENGTH;
+
+use crate::constants::*;
+use crate::handlers::current_timestamp;
+
+mod pcp;
+
+use pcp::PcpGateway;
+
+/// UPnP port mapping lease duration (in seconds)
+/// 3600 seconds = 1 hour
+const LEASE_DURATION: u32 = 3600;
+
+/// UPnP gateway search timeout (allows time for firewall approval dialogs)
+const SEARCH_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// How often the external IP address is checked
+const EXTERNAL_IP_CHECK_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// Protocol description for UPnP mapping
+const PROTOCOL_DESCRIPTION: &str = "Nexus BBS Server";
+
+/// Network addresses for routing detection
+const UDP_BIND_ADDRESS: &str = "0.0.0.0:0";
+
+/// Remote address for local routing table lookup (no actual connection is made)
+const ROUTING_TEST_ADDRESS: &str = "8.8.8.8:80";
+
+/// Current port forwarding state, written by the UPnP task and read by handlers
+///
+/// Cheap to clone; clones share the state.
+#[derive(Debug, Clone, Default)]
+pub struct NatStatus {
+    status: Arc<RwLock<Option<NatInfo>>>,
+}
+
+impl NatStatus {
+    /// Current port forwarding state, for admins
+    ///
+    /// Returns `None` if the server wasn't started with `--upnp`.
+    pub fn get(&self) -> Option<NatInfo> {
+        self.status
+            .read()
+            .expect("NAT status lock poisoned")
+            .clone()
+    }
+
+    /// Record the outcome of setup
+    fn set(&self, status: NatInfo) {
+        *self.status.write().expect("NAT status lock poisoned") = Some(status);
+    }
+
+    /// Update the port forwarding state (no-op before setup has recorded one)
+    fn update(&self, update: impl FnOnce(&mut NatInfo)) {
+        if let Some(status) = self
+            .status
+            .write()
+            .expect("NAT status lock poisoned")
+            .as_mut()
+        {
+            update(status);
+            status.checked_at = current_timestamp();
+        }
+    }
+}
+
+/// Error text for the NAT status, cut to fit the protocol limit
+fn status_error(error: &str) -> String {
+    error.chars().take(MAX_ERROR_LENGTH).collect()
+}
+
+/// How port mappings are requested from the router
+#[derive(Clone)]
+enum PortMapper {
+    /// UPnP Internet Gateway Device
+    Igd(igd_next::Gateway),
+    /// NAT-PMP or PCP gateway
+    Pcp(PcpGateway),
+}
+
+impl PortMapper {
+    /// Search for a UPnP gateway, falling back to NAT-PMP/PCP
+    async fn discover(local_ip: Ipv4Addr) -> Result<Self, String> {
+        let igd_error = match tokio::task::spawn_blocking(move || {
+            igd_next::search_gateway(SearchOptions {
+                timeout: Some(SEARCH_TIMEOUT),
+                ..Default::default()
+            })
+        })
+        .await
+        .map_err(|e| format!("{}{}", ERR_UPNP_SEARCH_TASK_FAILED, e))?
+        {
+            Ok(gateway) => return Ok(PortMapper::Igd(gateway)),
+            Err(e) => format!("{}{}", ERR_UPNP_GATEWAY_NOT_FOUND, e),
+        };
+
+        match PcpGateway::discover(local_ip).await {
+            Ok(gateway) => Ok(PortMapper::Pcp(gateway)),
+            Err(pcp_error) => Err(format!("{igd_error}; {pcp_error}")),
+        }
+    }
+
+    /// The protocol used for mappings
+    fn method(&self) -> NatMethod {
+        match self {
+            PortMapper::Igd(_) => NatMethod::Upnp,
+            PortMapper::Pcp(gateway) => gateway.method(),
+        }
+    }
+
+    /// Add or renew a port mapping
+    async fn add_port(
+        &self,
+        protocol: PortMappingProtocol,
+        port: u16,
+        local_ip: Ipv4Addr,
+    ) -> Result<(), String> {
+        match self {
+            PortMapper::Igd(gateway) => {
+                let socket = SocketAddrV4::new(local_ip, port);
+                let gw = gateway.clone();
+                tokio::task::spawn_blocking(move || {
+                    gw.add_port(
+                        protocol,
+                        port,
+                        std::net::SocketAddr::V4(socket),
+                        LEASE_DURATION,
+                        PROTOCOL_DESCRIPTION,
+                    )
+                })
+                .await
+                .map_err(|e| format!("{}{}", ERR_UPNP_PORT_FORWARD_TASK, e))?
+                .map_err(|e| format!("{}{}", ERR_UPNP_ADD_PORT_MAPPING, e))?;
+            }
+            PortMapper::Pcp(gateway) => {
+                gateway
+                    .map_port(protocol, port, LEASE_DURATION)
+                    .await
+                    .map_err(|e| format!("{}{}", ERR_UPNP_ADD_PORT_MAPPING, e))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove a port mapping
+    async fn remove_port(&self, protocol: PortMappingProtocol, port: u16) -> Result<(), String> {
+        match self {
+            PortMapper::Igd(gateway) => {
+                let gw = gateway.clone();
+                tokio::task::spawn_blocking(move || gw.remove_port(protocol, port))
+                    .await
+                    .map_err(|e| format!("{}{}", ERR_UPNP_REMOVE_PORT_TASK, e))?
+                    .map_err(|e| format!("{}{}", ERR_UPNP_REMOVE_PORT_MAPPING, e))?;
+            }
+            PortMapper::Pcp(gateway) => {
+                // A lifetime of 0 deletes the mapping
+                gateway
+                    .map_port(protocol, port, 0)
+                    .await
+                    .map_err(|e| format!("{}{}", ERR_UPNP_REMOVE_PORT_MAPPING, e))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Ask the router for its external address
+    ///
+    /// `probe_port` is a mapped TCP port, used by PCP to learn the address.
+    async fn external_ip(&self, probe_port: u16) -> Result<Ipv4Addr, String> {
+        let ip = match self {
+            PortMapper::Igd(gateway) => {
+                let gateway = gateway.clone();
+                let ip = tokio::task::spawn_blocking(move || gateway.get_external_ip())
+                    .await
+                    .map_err(|e| format!("{}{}", ERR_UPNP_GET_EXTERNAL_IP_TASK, e))?
+                    .map_err(|e| format!("{}{}", ERR_UPNP_GET_EXTERNAL_IP, e))?;
+                match ip {
+                    IpAddr::V4(ipv4) => ipv4,
+                    IpAddr::V6(_) => return Err(ERR_UPNP_IPV6_EXPECTED_IPV4.to_string()),
+                }
+            }
+            PortMapper::Pcp(gateway) => gateway
+                .external_ip(probe_port, LEASE_DURATION)
+                .await
+                .map_err(|e| format!("{}{}", ERR_UPNP_GET_EXTERNAL_IP, e))?,
+        };
+        Ok(ip)
+    }
+}
+
+/// Display name of a port mapping method
+fn method_name(method: NatMethod) -> &'static str {
+    match method {
+        NatMethod::Upnp => "UPnP",
+        NatMethod::Pcp => "PCP",
+        NatMethod::NatPmp => "NAT-PMP",
+    }
+}
+
+/// Port forwarding handle for managing port mappings
+pub struct UpnpGateway {
+    gateway: RwLock<PortMapper>,
+    /// Main BBS port
+    main_port: u16,
+    /// Transfer port for file downloads
+    transfer_port: u16,
+    /// WebSocket BBS port (None if WebSocket disabled)
+    websocket_port: Option<u16>,
+    /// WebSocket transfer port (None if WebSocket disabled)
+    transfer_websocket_port: Option<u16>,
+    local_addr: SocketAddrV4,
+    /// Where the port forwarding state is recorded
+    status: NatStatus,
+}
+
+impl UpnpGateway {
+    /// Search for a gateway and request port forwarding
+    ///
+    /// This performs the complete setup sequence:
+    /// 1. Determines the local IPv4 address (detects if bound to 0.0.0.0 or ::)
+    /// 2. Discovers a UPnP gateway on the network (15-second timeout), falling back
+    ///    to NAT-PMP/PCP on the default gateway
+    /// 3. Requests TCP and UDP port forwarding with a 1-hour lease
+    /// 4. Retrieves the external IP address from the gateway
+    ///
+    /// The outcome is recorded in `status`, including failures.
+    ///
+    /// # Arguments
+    /// * `bind_addr` - The IP address the server is bound to
+    /// * `main_port` - The main BBS port to forward
+    /// * `transfer_port` - The transfer port to forward
+    /// * `websocket_port` - The WebSocket BBS port to forward (None if disabled)
+    /// * `transfer_websocket_port` - The WebSocket transfer port to forward (None if disabled)
+    /// * `status` - Where the port forwarding state is recorded
+    ///
+    /// # Returns
+    /// * `Ok(UpnpGateway)` - Successfully configured port forwarding
+    /// * `Err(String)` - Failed to configure (gateway not found, port forwarding failed, etc.)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::net::IpAddr;
+    /// # use nexus_server::upnp::{NatStatus, UpnpGateway};
+    /// # async fn example() -> Result<(), String> {
+    /// let bind_addr: IpAddr = "0.0.0.0".parse().expect("valid IP address");
+    /// let status = NatStatus::default();
+    /// let gateway =
+    ///     UpnpGateway::setup(bind_addr, 7500, 7501, Some(7502), Some(7503), status).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn setup(
+        bind_addr: IpAddr,
+        main_port: u16,
+        transfer_port: u16,
+        websocket_port: Option<u16>,
+        transfer_websocket_port: Option<u16>,
+        status: NatStatus,
+    ) -> Result<Self, String> {
+        let tcp_ports: Vec<u16> = [
+            Some(main_port),
+            Some(transfer_port),
+            websocket_port,
+            transfer_websocket_port,
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        let result = Self::connect(
+            bind_addr,
+            main_port,
+            transfer_port,
+            websocket_port,
+            transfer_websocket_port,
+            status.clone(),
+        )
+        .await;
+
+        let info = match &result {
+            Ok((gateway, external_ip)) => NatInfo {
+                method: Some(gateway.method()),
+                external_ip: Some(external_ip.to_string()),
+                mapped: true,
+                tcp_ports,
+                udp_ports: vec![main_port],
+                checked_at: current_timestamp(),
+                error: None,
+            },
+            Err(e) => NatInfo {
+                method: None,
+                external_ip: None,
+                mapped: false,
+                tcp_ports,
+                udp_ports: vec![main_port],
+                checked_at: current_timestamp(),
+                error: Some(status_error(e)),
+            },
+        };
+        status.set(info);
+
+        result.map(|(gateway, _)| gateway)
+    }
+
+    /// Find a gateway and map all ports, returning the external IP address
+    async fn connect(
+        bind_addr: IpAddr,
+        main_port: u16,
+        transfer_port: u16,
+        websocket_port: Option<u16>,
+        transfer_websocket_port: Option<u16>,
+        status: NatStatus,
+    ) -> Result<(Self, Ipv4Addr), String> {
+        // UPnP only works with IPv4, but :: (dual-stack) binds IPv4 too
+        let local_addr = match bind_addr {
+            IpAddr::V4(ipv4) => {
+                // If bound to 0.0.0.0, we need to detect the actual local IP
+                if ipv4.is_unspecified() {
+                    Self::get_local_ipv4()?
+                } else {
+                    ipv4
+                }
+            }
+            IpAddr::V6(ipv6) => {
+                // :: (unspecified) enables dual-stack, so UPnP can work for IPv4
+                if ipv6.is_unspecified() {
+                    Self::get_local_ipv4()?
+                } else {
+                    // Specific IPv6 address (like Yggdrasil) - UPnP won't work
+                    return Err(ERR_IPV6_NOT_SUPPORTED.to_string());
+                }
+            }
+        };
+
+        let mapper = PortMapper::discover(local_addr).await?;
+
+        let gateway = Self {
+            gateway: RwLock::new(mapper.clone()),
+            main_port,
+            transfer_port,
+            websocket_port,
+            transfer_websocket_port,
+            local_addr: SocketAddrV4::new(local_addr, main_port),
+            status,
+        };
+
+        // Request port forwarding for all ports, then ask for the external IP
+        // (PCP only reports it for a mapped port)
+        gateway.add_port_mappings(&mapper).await?;
+        let external_ip = mapper.external_ip(main_port).await?;
+
+        // Print single summary line
+        let tcp_port_list = gateway
+            .tcp_ports()
+            .iter()
+            .map(|p| p.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        println!(
+            "{}{} → {} (TCP: {}, UDP: {}) via {}",
+            MSG_UPNP_CONFIGURED,
+            external_ip,
+            local_addr,
+            tcp_port_list,
+            main_port,
+            method_name(mapper.method())
+        );
+
+        Ok((gateway, external_ip))
+    }
+
+    /// The protocol currently used for mappings
+    fn method(&self) -> NatMethod {
+        self.gateway
+            .read()
+            .expect("UPnP gateway lock poisoned")
+            .method()
+    }
+
+    /// All forwarded TCP ports (BBS, transfer and WebSocket ports)
+    fn tcp_ports(&self) -> Vec<u16> {
+        [
+            Some(self.main_port),
+            Some(self.transfer_port),
+            self.websocket_port,
+            self.transfer_websocket_port,
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    /// Add (or renew) every port mapping on the given gateway
+    ///
+    /// TCP for all server ports, plus UDP on the main port for voice.
+    async fn add_port_mappings(&self, mapper: &PortMapper) -> Result<(), String> {
+        let local_ip = *self.local_addr.ip();
+        for port in self.tcp_ports() {
+            mapper
+                .add_port(PortMappingProtocol::TCP, port, local_ip)
+                .await?;
+        }
+        mapper
+            .add_port(PortMappingProtocol::UDP, self.main_port, local_ip)
+            .await
+    }
+
+    /// Remove port forwarding mappings from the router
+    ///
+    /// This is called during graceful shutdown to clean up the port mappings.
+    /// If removal fails, the mappings will expire after the lease duration (1 hour).
+    pub async fn remove_port_mapping(&self) -> Result<(), String> {
+        let mapper = self
+            .gateway
+            .read()
+            .expect("UPnP gateway lock poisoned")
+            .clone();
+
+        for port in self.tcp_ports() {
+            mapper.remove_port(PortMappingProtocol::TCP, port).await?;
+        }
+
+        // Remove UDP voice port mapping (same port as main)
+        mapper
+            .remove_port(PortMappingProtocol::UDP, self.main_port)
+            .await
+    }
+
+    /// Renew the port mapping leases
+    ///
+    /// Extends the port forwarding leases for another hour. This is called automatically
+    /// by the background renewal task every 30 minutes.
+    pub async fn renew_lease(&self) -> Result<(), String> {
+        let mapper = self
+            .gateway
+            .read()
+            .expect("UPnP gateway lock poisoned")
+            .clone();
+
+        self.add_port_mappings(&mapper)
+            .await
+            .map_err(|e| format!("{}{}", ERR_UPNP_RENEW_LEASE, e))
+    }
+
+    /// Re-discover gateway and re-establish port mappings
+    ///
+    /// Called when lease renewal fails, attempts to find the gateway again
+    /// (in case router rebooted or was replaced) and re-add the port mappings.
+    pub async fn rediscover_and_remap(&self) -> Result<(), String> {
+        let new_mapper = PortMapper::discover(*self.local_addr.ip()).await?;
+        self.add_port_mappings(&new_mapper).await?;
+
+        // Update stored gateway
+        *self.gateway.write().expect("UPnP gateway lock poisoned") = new_mapper;
+
+        Ok(())
+    }
+
+    /// Check the external IP address and record it in the NAT status
+    ///
+    /// Logs when the address changes, since clients using the old address
+    /// can no longer connect.
+    pub async fn refresh_external_ip(&self) -> Result<(), String> {
+        let mapper = self
+            .gateway
+            .read()
+            .expect("UPnP gateway lock poisoned")
+            .clone();
+        let external_ip = mapper.external_ip(self.main_port).await?.to_string();
+
+        self.status.update(|status| {
+            if let Some(old_ip) = &status.external_ip
+                && *old_ip != external_ip
+            {
+                log_info!(
+                    "{}{} → {}",
+                    MSG_UPNP_EXTERNAL_IP_CHANGED,
+                    old_ip,
+                    external_ip
+                );
+            }
+            status.external_ip = Some(external_ip);
+            if status.mapped {
+                status.error = None;
+            }
+        });
+        Ok(())
+    }
+
+    /// Get the local IPv4 address using UDP socket routing
+    ///
+    /// This helps determine the actual interface when bound to 0.0.0.0 or ::.
+    /// Creates a UDP socket and "connects" to a remote address, which causes the OS
+    /// to determine which local interface would be used. No actual packets are sent.
+    fn get_local_ipv4() -> Result<std::net::Ipv4Addr, String> {
+        use std::net::UdpSocket;
+
+        // Bind UDP socket to 0.0.0.0:0 and connect to a remote address
+        // This doesn't actually send packets but OS routing determines the interface
+        let socket = UdpSocket::bind(UDP_BIND_ADDRESS)
+            .map_err(|e| format!("{}{}", ERR_UPNP_CREATE_UDP_SOCKET, e))?;
+
+        // Try to "connect" to a remote address to determine routing
+        // This is purely local routing table lookup, no packets sent
+        socket
+            .connect(ROUTING_TEST_ADDRESS)
+            .map_err(|e| format!("{}{}", ERR_UPNP_DETERMINE_ROUTING, e))?;
+
+        match socket.local_addr() {
+            Ok(addr) => match addr.ip() {
+                IpAddr::V4(ipv4) if !ipv4.is_loopback() => Ok(ipv4),
+                IpAddr::V4(_) => Err(ERR_UPNP_LOOPBACK_ONLY.to_string()),
+                IpAddr::V6(_) => Err(ERR_UPNP_IPV6_EXPECTED_IPV4.to_string()),
+            },
+            Err(e) => Err(format!("{}{}", ERR_UPNP_GET_LOCAL_ADDRESS, e)),
+        }
+    }
+}
+
+/// Background task to renew port mappings and watch the external IP
+///
+/// Spawns a tokio task that renews the port mapping every 30 minutes (50% of the
+/// 1-hour lease duration), so the mapping never expires while the server is
+/// running, and checks the external IP address every 5 minutes.
+///
+/// If renewal fails, attempts to re-discover the gateway and re-establish mappings.
+/// The task should be aborted during server shutdown.
+///
+/// # Arguments
+/// * `gateway` - The port forwarding handle (Arc-wrapped for shared access)
+///
+/// # Returns
+/// A tokio task handle that can be aborted to stop renewal
+pub fn spawn_lease_renewal_task(gateway: Arc<UpnpGateway>) -> tokio::task::JoinHandle<()> {
+    // Renew at 50% of lease duration to ensure we don't lose the mapping
+    let renewal_interval = Duration::from_secs((LEASE_DURATION / 2) as u64);
+
+    tokio::spawn(async move {
+        let mut renewal = tokio::time::interval(renewal_interval);
+        let mut ip_check = tokio::time::interval(EXTERNAL_IP_CHECK_INTERVAL);
+        // Skip the first ticks (immediate)
+        renewal.tick().await;
+        ip_check.tick().await;
+
+        loop {
+            tokio::select! {
+                _ = renewal.tick() => {
+                    let Err(e) = gateway.renew_lease().await else {
+                        gateway.status.update(|status| {
+                            status.mapped = true;
+                            status.error = None;
+                        });
+                        continue;
+                    };
+
+                    log_warn!("{}{}", WARN_UPNP_RENEW_FAILED, e);
+                    log_info!("{}", MSG_UPNP_REDISCOVERING);
+
+                    // Try to re-discover gateway and re-establish mappings
+                    match gateway.rediscover_and_remap().await {
+                        Ok(()) => {
+                            log_info!("{}", MSG_UPNP_REDISCOVERED);
+                            let method = gateway.method();
+                            gateway.status.update(|status| {
+                                status.method = Some(method);
+                                status.mapped = true;
+                                status.error = None;
+                            });
+                        }
+                        Err(e2) => {
+                            log_warn!("{}{}", WARN_UPNP_REDISCOVER_FAILED, e2);
+                            log_warn!("{}", WARN_UPNP_PORT_EXPIRE);
+                            gateway.status.update(|status| {
+                                status.mapped = false;
+                                status.error = Some(status_error(&e2));
+                            });
+                        }
+                    }
+                }
+                _ = ip_check.tick() => {
+                    if let Err(e) = gateway.refresh_external_ip().await {
+                        log_warn!("{}{}", WARN_UPNP_EXTERNAL_IP_CHECK_FAILED, e);
+                        gateway.status.update(|status| status.error = Some(status_error(&e)));
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==========================================================================
+    // Constants tests
+    // ==========================================================================
+
+    #[test]
+    fn test_lease_duration_is_one_hour() {
+        assert_eq!(LEASE_DURATION, 3600);
+    }
+
+    #[test]
+    fn test_external_ip_checked_more_often_than_renewal() {
+        assert!(EXTERNAL_IP_CHECK_INTERVAL.as_secs() < (LEASE_DURATION / 2) as u64);
+    }
+
+    #[test]
+    fn test_search_timeout_is_reasonable() {
+        // Should be long enough for firewall dialogs but not too long
+        assert!(SEARCH_TIMEOUT.as_secs() >= 10);
+        assert!(SEARCH_TIMEOUT.as_secs() <= 30);
+    }
+
+    // ==========================================================================
+    // get_local_ipv4 tests
+    // ==========================================================================
+
+    #[test]
+    fn test_get_local_ipv4_returns_non_loopback() {
+        // This test requires network connectivity but doesn't send packets
+        match UpnpGateway::get_local_ipv4() {
+            Ok(ip) => {
+                assert!(!ip.is_loopback(), "Should not return loopback address");
+                assert!(!ip.is_unspecified(), "Should not return 0.0.0.0");
+            }
+            Err(_) => {
+                // May fail in isolated environments (containers, CI) - that's OK
+            }
+        }
+    }
+
+    // ==========================================================================
+    // NAT status tests
+    // ==========================================================================
+
+    #[test]
+    fn test_update_nat_status() {
+        let nat_status = NatStatus::default();
+        nat_status.update(|status| status.mapped = true);
+        assert!(nat_status.get().is_none());
+
+        nat_status.set(NatInfo {
+            method: Some(NatMethod::Pcp),
+            external_ip: Some("203.0.113.7".to_string()),
+            mapped: true,
+            tcp_ports: vec![7500, 7501],
+            udp_ports: vec![7500],
+            checked_at: 0,
+            error: None,
+        });
+
+        nat_status.update(|status| {
+            status.mapped = false;
+            status.error = Some(status_error(&"x".repeat(MAX_ERROR_LENGTH + 10)));
+        });
+
+        let status = nat_status.get().expect("status was recorded");
+        assert!(!status.mapped);
+        assert!(status.checked_at > 0);
+        // Errors are cut to fit the protocol limit
+        assert_eq!(status.error.map(|e| e.len()), Some(MAX_ERROR_LENGTH));
+    }
+
+    #[test]
+    fn test_method_names() {
+        assert_eq!(method_name(NatMethod::Upnp), "UPnP");
+        assert_eq!(method_name(NatMethod::Pcp), "PCP");
+        assert_eq!(method_name(NatMethod::NatPmp), "NAT-PMP");
+    }
+
+    #[test]
+    fn test_udp_bind_address_is_valid() {
+        // Verify the constant is a valid socket address
+        let addr: std::net::SocketAddr = UDP_BIND_ADDRESS.parse().expect("valid socket address");
+        assert!(addr.ip().is_unspecified());
+        assert_eq!(addr.port(), 0);
+    }
+
+    #[test]
+    fn test_routing_test_address_is_valid() {
+        // Verify the constant is a valid socket address
+        let addr: std::net::SocketAddr =
+            ROUTING_TEST_ADDRESS.parse().expect("valid socket address");
+        assert!(!addr.ip().is_unspecified());
+        assert!(addr.port() > 0);
+    }
+}
@@ -0,0 +1,440 @@
+//! NAT-PMP (RFC 6886) and PCP (RFC 6887) port mapping
+//!
+//! Fallback for routers that don't speak UPnP/IGD. Both protocols send small
+//! UDP requests to port 5351 of the default gateway. PCP is tried first; a
+//! gateway that answers in NAT-PMP (version 0) is used with NAT-PMP instead.
+//!
+//! The default gateway is read from `/proc/net/route` on Linux. Elsewhere the
+//! `.1` address of the local subnet is assumed, which matches most home routers.
+
+use std::net::{Ipv4Addr, SocketAddrV4};
+use std::time::Duration;
+
+use igd_next::PortMappingProtocol;
+use nexus_common::protocol::NatMethod;
+use rand::RngExt;
+use tokio::net::UdpSocket;
+use tokio::time::timeout;
+
+use crate::constants::*;
+
+/// Port the gateway listens on for both protocols
+const GATEWAY_PORT: u16 = 5351;
+
+/// NAT-PMP protocol version
+const NAT_PMP_VERSION: u8 = 0;
+
+/// PCP protocol version
+const PCP_VERSION: u8 = 2;
+
+/// PCP ANNOUNCE opcode (used to probe for a PCP server)
+const PCP_OPCODE_ANNOUNCE: u8 = 0;
+
+/// PCP MAP opcode
+const PCP_OPCODE_MAP: u8 = 1;
+
+/// NAT-PMP external address opcode
+const NAT_PMP_OPCODE_EXTERNAL_ADDRESS: u8 = 0;
+
+/// Response opcodes are the request opcode with the high bit set
+const RESPONSE_BIT: u8 = 0x80;
+
+/// Length of a PCP request/response header
+const PCP_HEADER_LENGTH: usize = 24;
+
+/// Length of a PCP MAP request/response (header + MAP data)
+const PCP_MAP_LENGTH: usize = 60;
+
+/// Length of a NAT-PMP mapping request
+const NAT_PMP_MAP_REQUEST_LENGTH: usize = 12;
+
+/// Length of a NAT-PMP mapping response
+const NAT_PMP_MAP_RESPONSE_LENGTH: usize = 16;
+
+/// Length of a NAT-PMP external address response
+const NAT_PMP_EXTERNAL_ADDRESS_LENGTH: usize = 12;
+
+/// Largest PCP message (RFC 6887 section 7)
+const MAX_MESSAGE_SIZE: usize = 1100;
+
+/// Wait for the first response, doubled on each retry (RFC 6886 section 3.1)
+const INITIAL_RETRY_TIMEOUT: Duration = Duration::from_millis(250);
+
+/// Requests sent before giving up (about 4 seconds in total)
+const MAX_ATTEMPTS: u32 = 4;
+
+/// Routing table with the default gateway (Linux)
+const PROC_NET_ROUTE: &str = "/proc/net/route";
+
+/// A NAT-PMP or PCP gateway
+#[derive(Debug, Clone)]
+pub struct PcpGateway {
+    gateway: SocketAddrV4,
+    local_ip: Ipv4Addr,
+    /// `Pcp` or `NatPmp`, depending on what the gateway answered
+    method: NatMethod,
+    /// PCP mapping nonce (identifies our mappings to the gateway)
+    nonce: [u8; 12],
+}
+
+impl PcpGateway {
+    /// Find a NAT-PMP or PCP gateway for the given local address
+    pub async fn discover(local_ip: Ipv4Addr) -> Result<Self, String> {
+        let gateway = SocketAddrV4::new(default_gateway(local_ip), GATEWAY_PORT);
+        let response = request(gateway, &pcp_header(PCP_OPCODE_ANNOUNCE, 0, local_ip)).await?;
+
+        // A NAT-PMP gateway rejects the PCP request with a version 0 "unsupported
+        // version" response, which still tells us it's there
+        let method = match response.first() {
+            Some(&PCP_VERSION) => NatMethod::Pcp,
+            Some(&NAT_PMP_VERSION) => NatMethod::NatPmp,
+            _ => return Err(ERR_PCP_INVALID_RESPONSE.to_string()),
+        };
+
+        Ok(Self {
+            gateway,
+            local_ip,
+            method,
+            nonce: rand::rng().random(),
+        })
+    }
+
+    /// The protocol spoken by the gateway
+    pub fn method(&self) -> NatMethod {
+        self.method
+    }
+
+    /// Add or renew a port mapping (a lifetime of 0 removes it)
+    ///
+    /// Returns the external address when the protocol reports it (PCP only).
+    pub async fn map_port(
+        &self,
+        protocol: PortMappingProtocol,
+        port: u16,
+        lifetime: u32,
+    ) -> Result<Option<Ipv4Addr>, String> {
+        if self.method == NatMethod::NatPmp {
+            let request_packet = nat_pmp_map_request(protocol, port, lifetime);
+            let response = request(self.gateway, &request_packet).await?;
+            parse_nat_pmp_map_response(&response, request_packet[1], port, lifetime)?;
+            Ok(None)
+        } else {
+            let request_packet =
+                pcp_map_request(self.local_ip, &self.nonce, protocol, port, lifetime);
+            let response = request(self.gateway, &request_packet).await?;
+            parse_pcp_map_response(&response, &self.nonce, port, lifetime).map(Some)
+        }
+    }
+
+    /// Ask the gateway for its external address
+    ///
+    /// PCP has no separate request for this, so the mapping for `probe_port`
+    /// (TCP) is renewed and its assigned address used.
+    pub async fn external_ip(&self, probe_port: u16, lifetime: u32) -> Result<Ipv4Addr, String> {
+        if self.method == NatMethod::NatPmp {
+            let response = request(
+                self.gateway,
+                &[NAT_PMP_VERSION, NAT_PMP_OPCODE_EXTERNAL_ADDRESS],
+            )
+            .await?;
+            parse_nat_pmp_external_address(&response)
+        } else {
+            self.map_port(PortMappingProtocol::TCP, probe_port, lifetime)
+                .await?
+                .ok_or_else(|| ERR_PCP_INVALID_RESPONSE.to_string())
+        }
+    }
+}
+
+/// Send a request and wait for the response, retrying with backoff
+async fn request(gateway: SocketAddrV4, packet: &[u8]) -> Result<Vec<u8>, String> {
+    let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0))
+        .await
+        .map_err(|e| format!("{}{}", ERR_UPNP_CREATE_UDP_SOCKET, e))?;
+    socket
+        .connect(gateway)
+        .await
+        .map_err(|e| format!("{}{}", ERR_UPNP_CREATE_UDP_SOCKET, e))?;
+
+    let mut buf = [0u8; MAX_MESSAGE_SIZE];
+    let mut wait = INITIAL_RETRY_TIMEOUT;
+    for _ in 0..MAX_ATTEMPTS {
+        socket
+            .send(packet)
+            .await
+            .map_err(|e| format!("{}{}: {}", ERR_PCP_NO_RESPONSE, gateway.ip(), e))?;
+        if let Ok(received) = timeout(wait, socket.recv(&mut buf)).await {
+            // An error here is usually ICMP port unreachable: nothing listens on 5351
+            let len =
+                received.map_err(|e| format!("{}{}: {}", ERR_PCP_NO_RESPONSE, gateway.ip(), e))?;
+            return Ok(buf[..len].to_vec());
+        }
+        wait *= 2;
+    }
+    Err(format!("{}{}", ERR_PCP_NO_RESPONSE, gateway.ip()))
+}
+
+/// PCP request header (RFC 6887 section 7.1)
+fn pcp_header(opcode: u8, lifetime: u32, local_ip: Ipv4Addr) -> Vec<u8> {
+    let mut packet = Vec::with_capacity(PCP_MAP_LENGTH);
+    packet.push(PCP_VERSION);
+    packet.push(opcode);
+    packet.extend_from_slice(&[0, 0]);
+    packet.extend_from_slice(&lifetime.to_be_bytes());
+    packet.extend_from_slice(&local_ip.to_ipv6_mapped().octets());
+    packet
+}
+
+/// PCP MAP request (RFC 6887 section 11.1)
+fn pcp_map_request(
+    local_ip: Ipv4Addr,
+    nonce: &[u8; 12],
+    protocol: PortMappingProtocol,
+    port: u16,
+    lifetime: u32,
+) -> Vec<u8> {
+    let mut packet = pcp_header(PCP_OPCODE_MAP, lifetime, local_ip);
+    packet.extend_from_slice(nonce);
+    packet.push(ip_protocol_number(protocol));
+    packet.extend_from_slice(&[0, 0, 0]);
+    packet.extend_from_slice(&port.to_be_bytes());
+    // Suggest the same external port, on any external address
+    packet.extend_from_slice(&port.to_be_bytes());
+    packet.extend_from_slice(&Ipv4Addr::UNSPECIFIED.to_ipv6_mapped().octets());
+    packet
+}
+
+/// Parse a PCP MAP response, returning the assigned external address
+fn parse_pcp_map_response(
+    response: &[u8],
+    nonce: &[u8; 12],
+    port: u16,
+    lifetime: u32,
+) -> Result<Ipv4Addr, String> {
+    if response.len() < PCP_MAP_LENGTH
+        || response[0] != PCP_VERSION
+        || response[1] != PCP_OPCODE_MAP | RESPONSE_BIT
+        || response[PCP_HEADER_LENGTH..PCP_HEADER_LENGTH + nonce.len()] != nonce[..]
+    {
+        return Err(ERR_PCP_INVALID_RESPONSE.to_string());
+    }
+    if response[3] != 0 {
+        return Err(format!("{}{}", ERR_PCP_RESULT_CODE, response[3]));
+    }
+
+    let external_port = u16::from_be_bytes([response[42], response[43]]);
+    if lifetime > 0 && external_port != port {
+        return Err(format!("{}{}", ERR_PCP_PORT_MISMATCH, external_port));
+    }
+
+    let mut external_ip = [0u8; 16];
+    external_ip.copy_from_slice(&response[44..PCP_MAP_LENGTH]);
+    std::net::Ipv6Addr::from(external_ip)
+        .to_ipv4_mapped()
+        .ok_or_else(|| ERR_PCP_INVALID_RESPONSE.to_string())
+}
+
+/// NAT-PMP mapping request (RFC 6886 section 3.3)
+fn nat_pmp_map_request(
+    protocol: PortMappingProtocol,
+    port: u16,
+    lifetime: u32,
+) -> [u8; NAT_PMP_MAP_REQUEST_LENGTH] {
+    let opcode = match protocol {
+        PortMappingProtocol::UDP => 1,
+        PortMappingProtocol::TCP => 2,
+    };
+    let mut packet = [0u8; NAT_PMP_MAP_REQUEST_LENGTH];
+    packet[0] = NAT_PMP_VERSION;
+    packet[1] = opcode;
+    packet[4..6].copy_from_slice(&port.to_be_bytes());
+    packet[6..8].copy_from_slice(&port.to_be_bytes());
+    packet[8..12].copy_from_slice(&lifetime.to_be_bytes());
+    packet
+}
+
+/// Check a NAT-PMP mapping response
+fn parse_nat_pmp_map_response(
+    response: &[u8],
+    opcode: u8,
+    port: u16,
+    lifetime: u32,
+) -> Result<(), String> {
+    check_nat_pmp_response(response, opcode, NAT_PMP_MAP_RESPONSE_LENGTH)?;
+    let external_port = u16::from_be_bytes([response[10], response[11]]);
+    if lifetime > 0 && external_port != port {
+        return Err(format!("{}{}", ERR_PCP_PORT_MISMATCH, external_port));
+    }
+    Ok(())
+}
+
+/// Parse a NAT-PMP external address response
+fn parse_nat_pmp_external_address(response: &[u8]) -> Result<Ipv4Addr, String> {
+    check_nat_pmp_response(
+        response,
+        NAT_PMP_OPCODE_EXTERNAL_ADDRESS,
+        NAT_PMP_EXTERNAL_ADDRESS_LENGTH,
+    )?;
+    Ok(Ipv4Addr::new(
+        response[8],
+        response[9],
+        response[10],
+        response[11],
+    ))
+}
+
+/// Check the common NAT-PMP response header (version, opcode, result code)
+fn check_nat_pmp_response(response: &[u8], opcode: u8, length: usize) -> Result<(), String> {
+    if response.len() < length
+        || response[0] != NAT_PMP_VERSION
+        || response[1] != opcode | RESPONSE_BIT
+    {
+        return Err(ERR_PCP_INVALID_RESPONSE.to_string());
+    }
+    let result = u16::from_be_bytes([response[2], response[3]]);
+    if result != 0 {
+        return Err(format!("{}{}", ERR_PCP_RESULT_CODE, result));
+    }
+    Ok(())
+}
+
+/// IANA protocol number used by PCP
+fn ip_protocol_number(protocol: PortMappingProtocol) -> u8 {
+    match protocol {
+        PortMappingProtocol::TCP => 6,
+        PortMappingProtocol::UDP => 17,
+    }
+}
+
+/// Find the default gateway, falling back to the `.1` address of the local subnet
+fn default_gateway(local_ip: Ipv4Addr) -> Ipv4Addr {
+    std::fs::read_to_string(PROC_NET_ROUTE)
+        .ok()
+        .and_then(|routes| parse_default_route(&routes))
+        .unwrap_or_else(|| {
+            let [a, b, c, _] = local_ip.octets();
+            Ipv4Addr::new(a, b, c, 1)
+        })
+}
+
+/// Find the default route's gateway in `/proc/net/route`
+///
+/// Addresses are hex in host byte order, e.g. `0101A8C0` for 192.168.1.1.
+fn parse_default_route(routes: &str) -> Option<Ipv4Addr> {
+    routes.lines().skip(1).find_map(|line| {
+        let mut fields = line.split_whitespace();
+        let (_iface, destination, gateway) = (fields.next()?, fields.next()?, fields.next()?);
+        if destination != "00000000" {
+            return None;
+        }
+        let gateway = u32::from_str_radix(gateway, 16).ok()?;
+        (gateway != 0).then(|| Ipv4Addr::from(gateway.to_ne_bytes()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NONCE: [u8; 12] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+
+    /// Build a PCP MAP response for the given request
+    fn pcp_map_response(request: &[u8], result: u8, external_port: u16) -> Vec<u8> {
+        let mut response = request.to_vec();
+        response[1] |= RESPONSE_BIT;
+        response[3] = result;
+        response[8..PCP_HEADER_LENGTH].fill(0);
+        response[42..44].copy_from_slice(&external_port.to_be_bytes());
+        response[44..60].copy_from_slice(&Ipv4Addr::new(203, 0, 113, 7).to_ipv6_mapped().octets());
+        response
+    }
+
+    #[test]
+    fn test_pcp_map_request_layout() {
+        let local_ip = Ipv4Addr::new(192, 168, 1, 20);
+        let packet = pcp_map_request(local_ip, &NONCE, PortMappingProtocol::UDP, 7500, 3600);
+
+        assert_eq!(packet.len(), PCP_MAP_LENGTH);
+        assert_eq!(&packet[..4], &[PCP_VERSION, PCP_OPCODE_MAP, 0, 0]);
+        assert_eq!(&packet[4..8], &3600u32.to_be_bytes());
+        assert_eq!(&packet[8..24], &local_ip.to_ipv6_mapped().octets());
+        assert_eq!(&packet[24..36], &NONCE);
+        assert_eq!(packet[36], 17);
+        assert_eq!(&packet[40..42], &7500u16.to_be_bytes());
+        assert_eq!(&packet[42..44], &7500u16.to_be_bytes());
+    }
+
+    #[test]
+    fn test_parse_pcp_map_response() {
+        let request = pcp_map_request(
+            Ipv4Addr::new(192, 168, 1, 20),
+            &NONCE,
+            PortMappingProtocol::TCP,
+            7500,
+            3600,
+        );
+
+        let response = pcp_map_response(&request, 0, 7500);
+        assert_eq!(
+            parse_pcp_map_response(&response, &NONCE, 7500, 3600),
+            Ok(Ipv4Addr::new(203, 0, 113, 7))
+        );
+
+        // Another client's nonce, an error result and a different port are all failures
+        assert!(parse_pcp_map_response(&response, &[0; 12], 7500, 3600).is_err());
+        let refused = pcp_map_response(&request, 8, 7500);
+        assert!(parse_pcp_map_response(&refused, &NONCE, 7500, 3600).is_err());
+        let moved = pcp_map_response(&request, 0, 17500);
+        assert!(parse_pcp_map_response(&moved, &NONCE, 7500, 3600).is_err());
+        assert!(parse_pcp_map_response(&response[..40], &NONCE, 7500, 3600).is_err());
+    }
+
+    #[test]
+    fn test_nat_pmp_map_request_layout() {
+        let packet = nat_pmp_map_request(PortMappingProtocol::TCP, 7501, 3600);
+        assert_eq!(
+            packet,
+            [0, 2, 0, 0, 0x1d, 0x4d, 0x1d, 0x4d, 0, 0, 0x0e, 0x10]
+        );
+        assert_eq!(nat_pmp_map_request(PortMappingProtocol::UDP, 7501, 0)[1], 1);
+    }
+
+    #[test]
+    fn test_parse_nat_pmp_responses() {
+        // Mapping response: version, opcode, result, epoch, internal port, external port, lifetime
+        let mapped = [
+            0, 0x82, 0, 0, 0, 0, 0, 1, 0x1d, 0x4d, 0x1d, 0x4d, 0, 0, 0x0e, 0x10,
+        ];
+        assert!(parse_nat_pmp_map_response(&mapped, 2, 7501, 3600).is_ok());
+        assert!(parse_nat_pmp_map_response(&mapped, 1, 7501, 3600).is_err());
+
+        let mut refused = mapped;
+        refused[3] = 2; // Not authorized
+        assert!(parse_nat_pmp_map_response(&refused, 2, 7501, 3600).is_err());
+
+        let address = [0, 0x80, 0, 0, 0, 0, 0, 1, 203, 0, 113, 7];
+        assert_eq!(
+            parse_nat_pmp_external_address(&address),
+            Ok(Ipv4Addr::new(203, 0, 113, 7))
+        );
+        assert!(parse_nat_pmp_external_address(&address[..8]).is_err());
+    }
+
+    #[test]
+    fn test_parse_default_route() {
+        let routes = "Iface\tDestination\tGateway \tFlags\tRefCnt\tUse\tMetric\tMask\n\
+                      eth0\t0001A8C0\t00000000\t0001\t0\t0\t0\t00FFFFFF\n\
+                      eth0\t00000000\t0101A8C0\t0003\t0\t0\t0\t00000000\n";
+        let expected = Ipv4Addr::from(0x0101A8C0u32.to_ne_bytes());
+        assert_eq!(parse_default_route(routes), Some(expected));
+        if cfg!(target_endian = "little") {
+            assert_eq!(expected, Ipv4Addr::new(192, 168, 1, 1));
+        }
+
+        // No default route
+        assert_eq!(
+            parse_default_route(&routes[..routes.rfind("eth0").unwrap()]),
+            None
+        );
+    }
+}
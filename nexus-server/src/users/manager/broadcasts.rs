@@ -1,9 +1,13 @@
 //! Broadcast methods for UserManager
 
-use nexus_common::protocol::{ServerInfo, ServerMessage};
+use nexus_common::protocol::{NatInfo, NewsImageLimits, ServerInfo, ServerMessage};
+use nexus_common::validators;
 
 use super::UserManager;
 use crate::db::Permission;
+use crate::handlers::server_time;
+use crate::outbound::SharedFrame;
+use crate::users::user::UserSession;
 
 /// Parameters for broadcasting server info updates
 pub struct ServerInfoBroadcastParams {
@@ -12,12 +16,18 @@ pub struct ServerInfoBroadcastParams {
     pub version: String,
     pub max_connections_per_ip: u32,
     pub max_transfers_per_ip: u32,
+    pub max_active_transfers: u32,
+    pub transfer_small_files_first: bool,
+    pub trusted_transfer_weight: u32,
     pub image: String,
     pub transfer_port: u16,
     pub transfer_websocket_port: Option<u16>,
     pub file_reindex_interval: u32,
     pub persistent_channels: String,
     pub auto_join_channels: String,
+    pub news_image_limits: NewsImageLimits,
+    /// Port forwarding state (sent to admins only)
+    pub nat: Option<NatInfo>,
 }
 
 impl UserManager {
@@ -26,12 +36,21 @@ impl UserManager {
     /// Returns true if the message was sent, false if the session doesn't exist
     /// or the channel is closed.
     pub async fn send_to_session(&self, session_id: u32, message: ServerMessage) -> bool {
-        let users = self.users.read().await;
-        if let Some(user) = users.get(&session_id) {
-            user.tx.send((message, None)).is_ok()
-        } else {
-            false
-        }
+        self.sessions
+            .with(session_id, |user| user.tx.send((message, None)).is_ok())
+            .await
+            .unwrap_or(false)
+    }
+
+    /// Send a shared broadcast frame to a specific session by session ID
+    ///
+    /// Returns true if the frame was queued, false if the session doesn't exist
+    /// or the channel is closed.
+    pub async fn send_frame_to_session(&self, session_id: u32, frame: &SharedFrame) -> bool {
+        self.sessions
+            .with(session_id, |user| user.tx.send_frame(frame.clone()).is_ok())
+            .await
+            .unwrap_or(false)
     }
 
     /// Broadcast a message to all connected users with proper disconnect notification
@@ -41,14 +60,13 @@ impl UserManager {
     pub async fn broadcast(&self, message: ServerMessage) {
         let mut disconnected = Vec::new();
 
-        {
-            let users = self.users.read().await;
-            for user in users.values() {
+        self.sessions
+            .for_each(|user| {
                 if user.tx.send((message.clone(), None)).is_err() {
                     disconnected.push(user.session_id);
                 }
-            }
-        }
+            })
+            .await;
 
         self.remove_disconnected(disconnected).await;
     }
@@ -67,25 +85,24 @@ impl UserManager {
     ) {
         let mut disconnected = Vec::new();
 
-        {
-            let users = self.users.read().await;
-            for user in users.values() {
+        self.sessions
+            .for_each(|user| {
                 // Check if user has the required feature
                 if !user.has_feature(feature) {
-                    continue;
+                    return;
                 }
 
                 // Check if user has the required permission (uses cached permissions, admin bypass)
                 if !user.has_permission(required_permission) {
-                    continue;
+                    return;
                 }
 
                 // Send message to this user
                 if user.tx.send((message.clone(), None)).is_err() {
                     disconnected.push(user.session_id);
                 }
-            }
-        }
+            })
+            .await;
 
         self.remove_disconnected(disconnected).await;
     }
@@ -101,16 +118,15 @@ impl UserManager {
 
         let username_lower = username.to_lowercase();
 
-        {
-            let users = self.users.read().await;
-            for user in users.values() {
+        self.sessions
+            .for_each(|user| {
                 if user.username.to_lowercase() == username_lower
                     && user.tx.send((message.clone(), None)).is_err()
                 {
                     disconnected.push(user.session_id);
                 }
-            }
-        }
+            })
+            .await;
 
         self.remove_disconnected(disconnected).await;
     }
@@ -125,16 +141,15 @@ impl UserManager {
     pub async fn broadcast_to_nickname(&self, nickname: &str, message: &ServerMessage) {
         let mut disconnected = Vec::new();
 
-        let nickname_lower = nickname.to_lowercase();
-
-        {
-            let users = self.users.read().await;
-            for user in users.values() {
-                if user.nickname.to_lowercase() == nickname_lower
-                    && user.tx.send((message.clone(), None)).is_err()
-                {
-                    disconnected.push(user.session_id);
-                }
+        for session_id in self.sessions.ids_for_nickname(nickname) {
+            let sent = self
+                .sessions
+                .with(session_id, |user| {
+                    user.tx.send((message.clone(), None)).is_ok()
+                })
+                .await;
+            if sent == Some(false) {
+                disconnected.push(session_id);
             }
         }
 
@@ -154,20 +169,43 @@ impl UserManager {
     ) {
         let mut disconnected = Vec::new();
 
-        {
-            let users = self.users.read().await;
-            for user in users.values() {
+        self.sessions
+            .for_each(|user| {
                 // Check if user has the required permission (uses cached permissions, admin bypass)
                 if !user.has_permission(required_permission) {
-                    continue;
+                    return;
                 }
 
                 // Send message to this user
                 if user.tx.send((message.clone(), None)).is_err() {
                     disconnected.push(user.session_id);
                 }
-            }
-        }
+            })
+            .await;
+
+        self.remove_disconnected(disconnected).await;
+    }
+
+    /// Broadcast a message to every session the filter picks
+    ///
+    /// The filter is called once per session, so it can also note which
+    /// sessions it picked (used for counting broadcast recipients).
+    ///
+    /// Automatically removes users whose channels have closed (disconnected connections).
+    pub async fn broadcast_where(
+        &self,
+        message: ServerMessage,
+        mut filter: impl FnMut(&UserSession) -> bool,
+    ) {
+        let mut disconnected = Vec::new();
+
+        self.sessions
+            .for_each(|user| {
+                if filter(user) && user.tx.send((message.clone(), None)).is_err() {
+                    disconnected.push(user.session_id);
+                }
+            })
+            .await;
 
         self.remove_disconnected(disconnected).await;
     }
@@ -187,27 +225,26 @@ impl UserManager {
     ) {
         let mut disconnected = Vec::new();
 
-        {
-            let users = self.users.read().await;
-            for user in users.values() {
+        self.sessions
+            .for_each(|user| {
                 // Skip excluded session
                 if let Some(excluded) = exclude_session_id
                     && user.session_id == excluded
                 {
-                    continue;
+                    return;
                 }
 
                 // Check if user has user_list permission (uses cached permissions, admin bypass)
                 if !user.has_permission(Permission::UserList) {
-                    continue;
+                    return;
                 }
 
                 // Send message to this user
                 if user.tx.send((message.clone(), None)).is_err() {
                     disconnected.push(user.session_id);
                 }
-            }
-        }
+            })
+            .await;
 
         self.remove_disconnected(disconnected).await;
     }
@@ -222,9 +259,8 @@ impl UserManager {
     pub async fn broadcast_server_info_updated(&self, params: ServerInfoBroadcastParams) {
         let mut disconnected = Vec::new();
 
-        {
-            let users = self.users.read().await;
-            for user in users.values() {
+        self.sessions
+            .for_each(|user| {
                 // Only send file_reindex_interval to admins or users with file_reindex permission
                 let file_reindex_interval =
                     if user.is_admin || user.has_permission(Permission::FileReindex) {
@@ -247,18 +283,44 @@ impl UserManager {
                     None
                 };
 
+                // Only send transfer queue settings to admins
+                let (max_active_transfers, transfer_small_files_first, trusted_transfer_weight) =
+                    if user.is_admin {
+                        (
+                            Some(params.max_active_transfers),
+                            Some(params.transfer_small_files_first),
+                            Some(params.trusted_transfer_weight),
+                        )
+                    } else {
+                        (None, None, None)
+                    };
+
+                // Only send port forwarding state to admins
+                let nat = if user.is_admin {
+                    params.nat.clone()
+                } else {
+                    None
+                };
+
                 let server_info = ServerInfo {
                     name: Some(params.name.clone()),
                     description: Some(params.description.clone()),
                     version: Some(params.version.clone()),
                     max_connections_per_ip: Some(params.max_connections_per_ip),
                     max_transfers_per_ip: Some(params.max_transfers_per_ip),
+                    max_active_transfers,
+                    transfer_small_files_first,
+                    trusted_transfer_weight,
                     image: Some(params.image.clone()),
                     transfer_port: params.transfer_port,
                     transfer_websocket_port: params.transfer_websocket_port,
                     file_reindex_interval,
                     persistent_channels,
                     auto_join_channels,
+                    nat,
+                    news_image_limits: Some(params.news_image_limits),
+                    max_message_length: Some(validators::MAX_MESSAGE_LENGTH as u32),
+                    time: Some(server_time()),
                 };
 
                 let message = ServerMessage::ServerInfoUpdated { server_info };
@@ -266,8 +328,8 @@ impl UserManager {
                 if user.tx.send((message, None)).is_err() {
                     disconnected.push(user.session_id);
                 }
-            }
-        }
+            })
+            .await;
 
         self.remove_disconnected(disconnected).await;
     }
@@ -0,0 +1,171 @@
+//! File watch methods for UserManager
+//!
+//! Sessions can watch directories and get a `FileChanged` message whenever an
+//! entry in one is added, removed or renamed, so a file browser can refresh
+//! without polling. Watchers need to keep the permissions the directory was
+//! watched with; sessions that lose them stop getting changes.
+
+use nexus_common::protocol::ServerMessage;
+
+use super::UserManager;
+use crate::db::Permission;
+use crate::files::{FileChange, WatchedDirectory};
+
+impl UserManager {
+    /// Replace the directories a session watches
+    ///
+    /// Returns false if the session doesn't exist.
+    pub async fn set_file_watches(&self, session_id: u32, watches: Vec<WatchedDirectory>) -> bool {
+        self.sessions
+            .with_mut(session_id, |user| user.file_watches = watches)
+            .await
+            .is_some()
+    }
+
+    /// Send a change to every session watching the directory it happened in
+    ///
+    /// Send errors are ignored; closed sessions are cleaned up by the next
+    /// broadcast.
+    pub async fn notify_file_change(&self, change: &FileChange) {
+        self.sessions
+            .for_each(|user| {
+                if !user.has_permission(Permission::FileList) {
+                    return;
+                }
+                let can_browse_root = user.has_permission(Permission::FileRoot);
+                for watch in &user.file_watches {
+                    if watch.dir != change.dir || (watch.root && !can_browse_root) {
+                        continue;
+                    }
+                    let message = ServerMessage::FileChanged {
+                        path: watch.path.clone(),
+                        root: watch.root,
+                        kind: change.kind,
+                        name: change.name.clone(),
+                        new_name: change.new_name.clone(),
+                    };
+                    let _ = user.tx.send((message, None));
+                }
+            })
+            .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::path::PathBuf;
+
+    use nexus_common::protocol::FileChangeKind;
+
+    use super::*;
+    use crate::outbound;
+    use crate::users::user::NewSessionParams;
+
+    async fn add_session(
+        users: &UserManager,
+        username: &str,
+        permissions: &[Permission],
+    ) -> (u32, outbound::OutboundReceiver) {
+        let (tx, rx) = outbound::channel(Default::default());
+        let session_id = users
+            .add_user(NewSessionParams {
+                session_id: 0,
+                db_user_id: 1,
+                username: username.to_string(),
+                is_admin: false,
+                is_shared: false,
+                permissions: permissions.iter().copied().collect::<HashSet<_>>(),
+                address: "127.0.0.1:12345".parse().unwrap(),
+                created_at: 0,
+                tx,
+                features: vec![],
+                locale: "en".to_string(),
+                avatar: None,
+                nickname: username.to_string(),
+                is_away: false,
+                status: None,
+                client: None,
+            })
+            .await
+            .unwrap();
+        (session_id, rx)
+    }
+
+    fn watch(dir: &str, path: &str, root: bool) -> WatchedDirectory {
+        WatchedDirectory {
+            dir: PathBuf::from(dir),
+            path: path.to_string(),
+            root,
+        }
+    }
+
+    fn change(dir: &str, name: &str) -> FileChange {
+        FileChange {
+            dir: PathBuf::from(dir),
+            kind: FileChangeKind::Added,
+            name: name.to_string(),
+            new_name: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_notify_file_change_reaches_watchers_only() {
+        let users = UserManager::new();
+        let (watcher, mut watcher_rx) = add_session(&users, "bob", &[Permission::FileList]).await;
+        let (_, mut other_rx) = add_session(&users, "carol", &[Permission::FileList]).await;
+        assert!(
+            users
+                .set_file_watches(
+                    watcher,
+                    vec![watch("/files/shared/Podcasts", "/Podcasts", false)]
+                )
+                .await
+        );
+
+        users
+            .notify_file_change(&change("/files/shared/Podcasts", "episode.mp3"))
+            .await;
+        match watcher_rx.try_recv() {
+            Ok((
+                ServerMessage::FileChanged {
+                    path, root, name, ..
+                },
+                _,
+            )) => {
+                assert_eq!(path, "/Podcasts");
+                assert!(!root);
+                assert_eq!(name, "episode.mp3");
+            }
+            other => panic!("expected FileChanged, got {other:?}"),
+        }
+        assert!(other_rx.try_recv().is_err());
+
+        // Other directories aren't reported
+        users
+            .notify_file_change(&change("/files/shared", "Podcasts"))
+            .await;
+        assert!(watcher_rx.try_recv().is_err());
+
+        // An empty list stops watching
+        assert!(users.set_file_watches(watcher, Vec::new()).await);
+        users
+            .notify_file_change(&change("/files/shared/Podcasts", "episode.mp3"))
+            .await;
+        assert!(watcher_rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_notify_file_change_needs_root_permission_for_root_watch() {
+        let users = UserManager::new();
+        let (watcher, mut watcher_rx) = add_session(&users, "bob", &[Permission::FileList]).await;
+        users
+            .set_file_watches(watcher, vec![watch("/files/shared", "/shared", true)])
+            .await;
+
+        users
+            .notify_file_change(&change("/files/shared", "song.mp3"))
+            .await;
+        assert!(watcher_rx.try_recv().is_err());
+    }
+}
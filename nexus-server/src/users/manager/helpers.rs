@@ -21,24 +21,16 @@ impl UserManager {
             return;
         }
 
-        // Collect user info before removing them (nickname for proper identification)
-        let users_to_remove: Vec<(u32, String)> = {
-            let users = self.users.read().await;
-            session_ids
-                .iter()
-                .filter_map(|&session_id| {
-                    users
-                        .get(&session_id)
-                        .map(|user| (session_id, user.nickname.clone()))
-                })
-                .collect()
-        };
-
-        // Remove users from the manager
-        {
-            let mut users = self.users.write().await;
-            for session_id in &session_ids {
-                users.remove(session_id);
+        // Remove users from the manager, keeping their nicknames for the broadcast
+        // (and their usernames for presence watchers)
+        let mut users_to_remove: Vec<(u32, String)> = Vec::new();
+        let mut usernames = Vec::new();
+        for session_id in session_ids {
+            if let Some(user) = self.sessions.remove(session_id).await {
+                users_to_remove.push((session_id, user.nickname));
+                if !user.is_shared {
+                    usernames.push(user.username);
+                }
             }
         }
 
@@ -52,20 +44,25 @@ impl UserManager {
             };
 
             // Send to users who have user_list permission (ignore send errors)
-            let users = self.users.read().await;
-            for user in users.values() {
-                // Skip the disconnecting user (already removed, but be explicit)
-                if user.session_id == session_id {
-                    continue;
-                }
+            self.sessions
+                .for_each(|user| {
+                    // Skip the disconnecting user (already removed, but be explicit)
+                    if user.session_id == session_id {
+                        return;
+                    }
 
-                // Check if user has user_list permission (uses cached permissions, admin bypass)
-                if user.has_permission(Permission::UserList) {
-                    // Ignore send errors - if this user's channel is also closed, they'll be
-                    // cleaned up on the next broadcast. We don't recurse here to avoid complexity.
-                    let _ = user.tx.send((message.clone(), None));
-                }
-            }
+                    // Check if user has user_list permission (uses cached permissions, admin bypass)
+                    if user.has_permission(Permission::UserList) {
+                        // Ignore send errors - if this user's channel is also closed, they'll be
+                        // cleaned up on the next broadcast. We don't recurse here to avoid complexity.
+                        let _ = user.tx.send((message.clone(), None));
+                    }
+                })
+                .await;
+        }
+
+        for username in usernames {
+            self.notify_presence(&username).await;
         }
     }
 
@@ -85,6 +82,9 @@ impl UserManager {
             avatar: session.avatar.clone(),
             is_away: session.is_away,
             status: session.status.clone(),
+            is_dnd: session.is_dnd,
+            last_active: Some(session.last_active),
+            client: session.client.clone(),
         }
     }
 
@@ -96,7 +96,8 @@ impl UserManager {
     /// - login_time: earliest session's login time (for "connected since" display)
     /// - session_ids: all session IDs
     /// - locale: from latest session
-    /// - avatar, is_away, status: from latest session ("latest login wins")
+    /// - avatar, is_away, status, is_dnd, client: from latest session ("latest login wins")
+    /// - last_active: most recent activity on any session
     ///
     /// For shared accounts (is_shared=true), this method should NOT be used - each session
     /// is a separate entry with its own nickname.
@@ -132,6 +133,9 @@ impl UserManager {
             avatar: latest_session.avatar.clone(),
             is_away: latest_session.is_away,
             status: latest_session.status.clone(),
+            is_dnd: latest_session.is_dnd,
+            last_active: sessions.iter().map(|s| s.last_active).max(),
+            client: latest_session.client.clone(),
         })
     }
 }
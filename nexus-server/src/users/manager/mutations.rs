@@ -8,6 +8,7 @@ use nexus_common::protocol::{ServerMessage, UserInfo};
 
 use super::UserManager;
 use crate::db::Permission;
+use crate::handlers::current_timestamp;
 use crate::users::user::{NewSessionParams, UserSession};
 
 /// Information about a disconnected session, used for broadcasting UserDisconnected
@@ -36,10 +37,10 @@ impl UserManager {
     /// The login handler performs a non-atomic pre-check via `is_nickname_in_use()`
     /// before calling this method. This provides two benefits:
     ///
-    /// 1. **Early rejection**: Most conflicts are caught without acquiring the write lock,
-    ///    reducing contention for legitimate requests.
+    /// 1. **Early rejection**: Most conflicts are caught before a session ID is assigned,
+    ///    keeping the claim path short for legitimate requests.
     ///
-    /// 2. **Atomic guarantee**: This method's check while holding the write lock prevents
+    /// 2. **Atomic guarantee**: This method's check under the nickname index lock prevents
     ///    race conditions where two simultaneous logins could both pass the pre-check
     ///    but only one should succeed.
     ///
@@ -50,36 +51,24 @@ impl UserManager {
     /// Returns `AddUserError::NicknameInUse` if the nickname is already taken by
     /// another session (shared or regular).
     pub async fn add_user(&self, mut params: NewSessionParams) -> Result<u32, AddUserError> {
-        // Acquire write lock first to ensure atomicity of nickname check + insert
-        let mut users = self.users.write().await;
-
-        // For shared accounts, check nickname uniqueness while holding the lock
-        // (Regular accounts have nickname == username, so this check is redundant for them,
-        // but we do it anyway for consistency)
-        if params.is_shared {
-            let nickname_lower = params.nickname.to_lowercase();
-
-            for user in users.values() {
-                // Check against existing nicknames (all sessions have nicknames now)
-                if user.nickname.to_lowercase() == nickname_lower {
-                    return Err(AddUserError::NicknameInUse);
-                }
-            }
-        }
-
-        // Nickname is unique (or not a shared account), proceed with adding
-        let session_id = self.next_session_id();
-        params.session_id = session_id;
-        let user = UserSession::new(params);
-        users.insert(session_id, user);
+        // For shared accounts, the nickname is checked and claimed in one step
+        // (Regular accounts have nickname == username, and share it across sessions)
+        let nickname = params.nickname.clone();
+        let session_id = self
+            .sessions
+            .insert(&nickname, params.is_shared, |session_id| {
+                params.session_id = session_id;
+                UserSession::new(params)
+            })
+            .await
+            .ok_or(AddUserError::NicknameInUse)?;
 
         Ok(session_id)
     }
 
     /// Remove a user by session ID
     pub async fn remove_user(&self, session_id: u32) -> Option<UserSession> {
-        let mut users = self.users.write().await;
-        users.remove(&session_id)
+        self.sessions.remove(session_id).await
     }
 
     /// Remove a user and broadcast UserDisconnected to other clients
@@ -106,6 +95,10 @@ impl UserManager {
             )
             .await;
 
+            if !user.is_shared {
+                self.notify_presence(&user.username).await;
+            }
+
             // For regular accounts, check if there are remaining sessions
             // If so, broadcast UserUpdated with the newest session's info (for avatar sync)
             if !user.is_shared {
@@ -131,6 +124,12 @@ impl UserManager {
                                     avatar: newest.avatar.clone(),
                                     is_away: newest.is_away,
                                     status: newest.status.clone(),
+                                    is_dnd: newest.is_dnd,
+                                    last_active: remaining_sessions
+                                        .iter()
+                                        .map(|s| s.last_active)
+                                        .max(),
+                                    client: newest.client.clone(),
                                 },
                             },
                             Some(session_id),
@@ -151,19 +150,30 @@ impl UserManager {
     ///
     /// For regular accounts, also updates nickname (since nickname == username).
     /// For shared accounts, nickname is independent and unchanged.
+    ///
+    /// Presence watchers of the old username see it go offline, and those of
+    /// the new one see it come online.
     pub async fn update_username(&self, db_user_id: i64, new_username: String) -> usize {
-        let mut users = self.users.write().await;
         let mut count = 0;
-
-        for user in users.values_mut() {
-            if user.db_user_id == db_user_id {
-                // For regular accounts, nickname == username, so update both
-                if !user.is_shared {
-                    user.nickname = new_username.clone();
+        let mut old_username = None;
+
+        self.sessions
+            .update_all(|user| {
+                if user.db_user_id == db_user_id {
+                    // For regular accounts, nickname == username, so update both
+                    if !user.is_shared {
+                        user.nickname = new_username.clone();
+                        old_username = Some(user.username.clone());
+                    }
+                    user.username = new_username.clone();
+                    count += 1;
                 }
-                user.username = new_username.clone();
-                count += 1;
-            }
+            })
+            .await;
+
+        if let Some(old_username) = old_username {
+            self.notify_presence(&old_username).await;
+            self.notify_presence(&new_username).await;
         }
 
         count
@@ -172,15 +182,16 @@ impl UserManager {
     /// Update admin status for a user by database user ID
     /// Returns the number of sessions updated
     pub async fn update_admin_status(&self, db_user_id: i64, is_admin: bool) -> usize {
-        let mut users = self.users.write().await;
         let mut count = 0;
 
-        for user in users.values_mut() {
-            if user.db_user_id == db_user_id {
-                user.is_admin = is_admin;
-                count += 1;
-            }
-        }
+        self.sessions
+            .update_all(|user| {
+                if user.db_user_id == db_user_id {
+                    user.is_admin = is_admin;
+                    count += 1;
+                }
+            })
+            .await;
 
         count
     }
@@ -192,36 +203,65 @@ impl UserManager {
         db_user_id: i64,
         permissions: HashSet<Permission>,
     ) -> usize {
-        let mut users = self.users.write().await;
         let mut count = 0;
 
-        for user in users.values_mut() {
-            if user.db_user_id == db_user_id {
-                user.permissions = permissions.clone();
-                count += 1;
-            }
-        }
+        self.sessions
+            .update_all(|user| {
+                if user.db_user_id == db_user_id {
+                    user.permissions = permissions.clone();
+                    count += 1;
+                }
+            })
+            .await;
 
         count
     }
 
     /// Set status and away flag for a session (by session_id)
     /// Returns the updated session if found
+    ///
+    /// Also tells presence watchers of the account about the change.
     pub async fn set_status(
         &self,
         session_id: u32,
         is_away: bool,
         status: Option<String>,
     ) -> Option<UserSession> {
-        let mut users = self.users.write().await;
-
-        if let Some(user) = users.get_mut(&session_id) {
-            user.is_away = is_away;
-            user.status = status;
-            Some(user.clone())
-        } else {
-            None
+        let session = self
+            .sessions
+            .with_mut(session_id, |user| {
+                user.is_away = is_away;
+                user.status = status;
+                user.clone()
+            })
+            .await?;
+
+        if !session.is_shared {
+            self.notify_presence(&session.username).await;
         }
+
+        Some(session)
+    }
+
+    /// Record that a session just sent a chat or user message
+    ///
+    /// Other users see the time as the user's idle time in the user list.
+    pub async fn touch_activity(&self, session_id: u32) {
+        let now = current_timestamp();
+        self.sessions
+            .with_mut(session_id, |user| user.last_active = now)
+            .await;
+    }
+
+    /// Set the do not disturb flag for a session (by session_id)
+    /// Returns the updated session if found
+    pub async fn set_dnd(&self, session_id: u32, is_dnd: bool) -> Option<UserSession> {
+        self.sessions
+            .with_mut(session_id, |user| {
+                user.is_dnd = is_dnd;
+                user.clone()
+            })
+            .await
     }
 
     /// Disconnect all sessions from a given IP address
@@ -253,25 +293,25 @@ impl UserManager {
         }
 
         // First, collect session IDs to disconnect
-        let session_ids: Vec<u32> = {
-            let users = self.users.read().await;
-            users
-                .values()
-                .filter(|u| u.address.ip().to_string() == ip)
-                .map(|u| u.session_id)
-                .collect()
-        };
+        let mut session_ids = Vec::new();
+        self.sessions
+            .for_each(|u| {
+                if u.address.ip().to_string() == ip {
+                    session_ids.push(u.session_id);
+                }
+            })
+            .await;
 
         if session_ids.is_empty() {
             return Vec::new();
         }
 
         // Send disconnect message to each session and remove them
-        let mut users = self.users.write().await;
         let mut disconnected = Vec::new();
+        let mut usernames = Vec::new();
 
         for session_id in session_ids {
-            if let Some(user) = users.remove(&session_id) {
+            if let Some(user) = self.sessions.remove(session_id).await {
                 // Build message with user's locale and send
                 // (ignore send errors - channel may already be closed)
                 let message = build_message(&user.locale);
@@ -280,9 +320,16 @@ impl UserManager {
                     session_id,
                     nickname: user.nickname.clone(),
                 });
+                if !user.is_shared {
+                    usernames.push(user.username);
+                }
             }
         }
 
+        for username in usernames {
+            self.notify_presence(&username).await;
+        }
+
         disconnected
     }
 
@@ -309,28 +356,26 @@ impl UserManager {
         S: Fn(&IpAddr) -> bool,
     {
         // First, collect session IDs to disconnect (excluding skipped IPs like trusted)
-        let session_ids: Vec<u32> = {
-            let users = self.users.read().await;
-            users
-                .values()
-                .filter(|u| {
-                    let ip = u.address.ip();
-                    range.contains(&ip) && !skip_ip(&ip)
-                })
-                .map(|u| u.session_id)
-                .collect()
-        };
+        let mut session_ids = Vec::new();
+        self.sessions
+            .for_each(|u| {
+                let ip = u.address.ip();
+                if range.contains(&ip) && !skip_ip(&ip) {
+                    session_ids.push(u.session_id);
+                }
+            })
+            .await;
 
         if session_ids.is_empty() {
             return Vec::new();
         }
 
         // Send disconnect message to each session and remove them
-        let mut users = self.users.write().await;
         let mut disconnected = Vec::new();
+        let mut usernames = Vec::new();
 
         for session_id in session_ids {
-            if let Some(user) = users.remove(&session_id) {
+            if let Some(user) = self.sessions.remove(session_id).await {
                 // Build message with user's locale and send
                 // (ignore send errors - channel may already be closed)
                 let message = build_message(&user.locale);
@@ -339,9 +384,16 @@ impl UserManager {
                     session_id,
                     nickname: user.nickname.clone(),
                 });
+                if !user.is_shared {
+                    usernames.push(user.username);
+                }
             }
         }
 
+        for username in usernames {
+            self.notify_presence(&username).await;
+        }
+
         disconnected
     }
 }
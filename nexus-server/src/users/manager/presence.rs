@@ -0,0 +1,179 @@
+//! Presence subscription methods for UserManager
+//!
+//! Sessions can watch specific accounts (a friends list) and get told when
+//! they come online, go offline, or change their away status, without needing
+//! the user_list permission. Presence is per account: shared account sessions
+//! don't count, and an account is online while any of its sessions is.
+
+use std::collections::HashSet;
+
+use nexus_common::protocol::{PresenceInfo, ServerMessage};
+
+use super::UserManager;
+
+impl UserManager {
+    /// Replace the set of usernames a session watches
+    ///
+    /// Usernames are stored lowercase. Returns false if the session doesn't exist.
+    pub async fn set_presence_subscriptions(&self, session_id: u32, usernames: &[String]) -> bool {
+        let subscriptions: HashSet<String> = usernames.iter().map(|u| u.to_lowercase()).collect();
+        self.sessions
+            .with_mut(session_id, |user| {
+                user.presence_subscriptions = subscriptions
+            })
+            .await
+            .is_some()
+    }
+
+    /// Get the current presence of an account (case-insensitive)
+    ///
+    /// Away status comes from the account's latest session ("latest login wins").
+    pub async fn get_presence(&self, username: &str) -> PresenceInfo {
+        let username_lower = username.to_lowercase();
+        let mut latest: Option<(i64, PresenceInfo)> = None;
+
+        self.sessions
+            .for_each(|user| {
+                if user.is_shared || user.username.to_lowercase() != username_lower {
+                    return;
+                }
+                if latest
+                    .as_ref()
+                    .is_none_or(|(login_time, _)| user.login_time >= *login_time)
+                {
+                    latest = Some((
+                        user.login_time,
+                        PresenceInfo {
+                            username: user.username.clone(),
+                            online: true,
+                            is_away: user.is_away,
+                            status: user.status.clone(),
+                        },
+                    ));
+                }
+            })
+            .await;
+
+        latest
+            .map(|(_, presence)| presence)
+            .unwrap_or(PresenceInfo {
+                username: username.to_string(),
+                online: false,
+                is_away: false,
+                status: None,
+            })
+    }
+
+    /// Send an account's current presence to every session watching it
+    ///
+    /// Call this after the account logs in or out, or changes its away status.
+    /// Send errors are ignored; closed sessions are cleaned up by the next
+    /// broadcast (this is also called while removing disconnected sessions).
+    pub async fn notify_presence(&self, username: &str) {
+        let username_lower = username.to_lowercase();
+        let mut watched = false;
+        self.sessions
+            .for_each(|user| watched |= user.presence_subscriptions.contains(&username_lower))
+            .await;
+        if !watched {
+            return;
+        }
+
+        let message = ServerMessage::PresenceUpdate {
+            user: self.get_presence(username).await,
+        };
+        self.sessions
+            .for_each(|user| {
+                if user.presence_subscriptions.contains(&username_lower) {
+                    let _ = user.tx.send((message.clone(), None));
+                }
+            })
+            .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::outbound;
+    use crate::users::user::NewSessionParams;
+
+    async fn add_session(
+        users: &UserManager,
+        username: &str,
+        nickname: &str,
+        is_shared: bool,
+    ) -> (u32, outbound::OutboundReceiver) {
+        let (tx, rx) = outbound::channel(Default::default());
+        let session_id = users
+            .add_user(NewSessionParams {
+                session_id: 0,
+                db_user_id: 1,
+                username: username.to_string(),
+                is_admin: false,
+                is_shared,
+                permissions: Default::default(),
+                address: "127.0.0.1:12345".parse().unwrap(),
+                created_at: 0,
+                tx,
+                features: vec![],
+                locale: "en".to_string(),
+                avatar: None,
+                nickname: nickname.to_string(),
+                is_away: false,
+                status: None,
+                client: None,
+            })
+            .await
+            .unwrap();
+        (session_id, rx)
+    }
+
+    #[tokio::test]
+    async fn test_presence_ignores_shared_sessions() {
+        let users = UserManager::new();
+        assert!(!users.get_presence("alice").await.online);
+
+        add_session(&users, "guest", "alice", true).await;
+        assert!(!users.get_presence("alice").await.online);
+        assert!(!users.get_presence("guest").await.online);
+
+        add_session(&users, "Alice", "Alice", false).await;
+        let presence = users.get_presence("ALICE").await;
+        assert!(presence.online);
+        assert_eq!(presence.username, "Alice");
+    }
+
+    #[tokio::test]
+    async fn test_notify_presence_reaches_watchers_only() {
+        let users = UserManager::new();
+        let (watcher, mut watcher_rx) = add_session(&users, "bob", "bob", false).await;
+        let (_, mut other_rx) = add_session(&users, "carol", "carol", false).await;
+        assert!(
+            users
+                .set_presence_subscriptions(watcher, &["Alice".to_string()])
+                .await
+        );
+
+        let (alice, _alice_rx) = add_session(&users, "alice", "alice", false).await;
+        users
+            .set_status(alice, true, Some("lunch".to_string()))
+            .await;
+        users.notify_presence("alice").await;
+
+        match watcher_rx.try_recv() {
+            Ok((ServerMessage::PresenceUpdate { user }, _)) => {
+                assert!(user.online);
+                assert!(user.is_away);
+                assert_eq!(user.status.as_deref(), Some("lunch"));
+            }
+            other => panic!("expected PresenceUpdate, got {other:?}"),
+        }
+        assert!(other_rx.try_recv().is_err());
+
+        // Dropping the subscription stops the updates
+        assert!(users.set_presence_subscriptions(watcher, &[]).await);
+        users.notify_presence("alice").await;
+        assert!(watcher_rx.try_recv().is_err());
+    }
+}
@@ -6,24 +6,29 @@ use super::UserManager;
 use crate::db::Permission;
 use crate::users::user::UserSession;
 
+#[cfg(test)]
+use crate::outbound;
 #[cfg(test)]
 use std::collections::HashSet;
 #[cfg(test)]
 use std::net::SocketAddr;
-#[cfg(test)]
-use tokio::sync::mpsc;
 
 impl UserManager {
     /// Get all connected users
     pub async fn get_all_users(&self) -> Vec<UserSession> {
-        let users = self.users.read().await;
-        users.values().cloned().collect()
+        let mut users = Vec::with_capacity(self.sessions.len());
+        self.sessions.for_each(|u| users.push(u.clone())).await;
+        users
+    }
+
+    /// Get the number of connected sessions
+    pub async fn session_count(&self) -> usize {
+        self.sessions.len()
     }
 
     /// Get a user by session ID
     pub async fn get_user_by_session_id(&self, session_id: u32) -> Option<UserSession> {
-        let users = self.users.read().await;
-        users.get(&session_id).cloned()
+        self.sessions.with(session_id, UserSession::clone).await
     }
 
     /// Check if a user has a specific permission (without cloning the session)
@@ -31,8 +36,9 @@ impl UserManager {
     /// This is optimized for hot paths like voice packet relay where we don't need
     /// the full UserSession, just a permission check. Returns None if user not found.
     pub async fn has_permission(&self, session_id: u32, permission: Permission) -> Option<bool> {
-        let users = self.users.read().await;
-        users.get(&session_id).map(|u| u.has_permission(permission))
+        self.sessions
+            .with(session_id, |u| u.has_permission(permission))
+            .await
     }
 
     /// Check if a user session exists (without cloning)
@@ -40,32 +46,37 @@ impl UserManager {
     /// Useful for checking if a user is still connected without the overhead of cloning.
     #[allow(dead_code)] // Useful helper for future use
     pub async fn session_exists(&self, session_id: u32) -> bool {
-        let users = self.users.read().await;
-        users.contains_key(&session_id)
+        self.sessions.with(session_id, |_| ()).await.is_some()
     }
 
     /// Get all sessions for a username (case-insensitive)
     ///
     /// Returns all sessions for a user who may be logged in from multiple devices.
     pub async fn get_sessions_by_username(&self, username: &str) -> Vec<UserSession> {
-        let users = self.users.read().await;
         let username_lower = username.to_lowercase();
-        users
-            .values()
-            .filter(|u| u.username.to_lowercase() == username_lower)
-            .cloned()
-            .collect()
+        let mut sessions = Vec::new();
+        self.sessions
+            .for_each(|u| {
+                if u.username.to_lowercase() == username_lower {
+                    sessions.push(u.clone());
+                }
+            })
+            .await;
+        sessions
     }
 
     /// Get all session IDs for a given username (case-insensitive)
     pub async fn get_session_ids_for_user(&self, username: &str) -> Vec<u32> {
-        let users = self.users.read().await;
         let username_lower = username.to_lowercase();
-        users
-            .iter()
-            .filter(|(_, user)| user.username.to_lowercase() == username_lower)
-            .map(|(session_id, _)| *session_id)
-            .collect()
+        let mut session_ids = Vec::new();
+        self.sessions
+            .for_each(|user| {
+                if user.username.to_lowercase() == username_lower {
+                    session_ids.push(user.session_id);
+                }
+            })
+            .await;
+        session_ids
     }
 
     /// Check if a nickname is already in use by an active session (case-insensitive)
@@ -74,11 +85,7 @@ impl UserManager {
     /// Since nickname is always populated (equals username for regular accounts),
     /// this effectively checks against all display names of logged-in users.
     pub async fn is_nickname_in_use(&self, nickname: &str) -> bool {
-        let users = self.users.read().await;
-        let nickname_lower = nickname.to_lowercase();
-        users
-            .values()
-            .any(|u| u.nickname.to_lowercase() == nickname_lower)
+        self.sessions.nickname_in_use(nickname)
     }
 
     /// Get a session by nickname (case-insensitive)
@@ -87,12 +94,12 @@ impl UserManager {
     /// this finds any user by their display name.
     /// Returns None if no session with that nickname is found.
     pub async fn get_session_by_nickname(&self, nickname: &str) -> Option<UserSession> {
-        let users = self.users.read().await;
-        let nickname_lower = nickname.to_lowercase();
-        users
-            .values()
-            .find(|u| u.nickname.to_lowercase() == nickname_lower)
-            .cloned()
+        for session_id in self.sessions.ids_for_nickname(nickname) {
+            if let Some(user) = self.sessions.with(session_id, UserSession::clone).await {
+                return Some(user);
+            }
+        }
+        None
     }
 
     /// Get all sessions with a specific nickname (case-insensitive)
@@ -104,33 +111,31 @@ impl UserManager {
     /// This is useful for operations that need to affect all sessions of a "user"
     /// as identified by their display name (e.g., kicking, disconnecting).
     pub async fn get_sessions_by_nickname(&self, nickname: &str) -> Vec<UserSession> {
-        let users = self.users.read().await;
-        let nickname_lower = nickname.to_lowercase();
-        users
-            .values()
-            .filter(|u| u.nickname.to_lowercase() == nickname_lower)
-            .cloned()
-            .collect()
+        let mut sessions = Vec::new();
+        for session_id in self.sessions.ids_for_nickname(nickname) {
+            if let Some(user) = self.sessions.with(session_id, UserSession::clone).await {
+                sessions.push(user);
+            }
+        }
+        sessions
     }
 
     /// Check if any admin is connected from a given IP address
     ///
     /// Used by the ban system to prevent banning an IP that has an admin connected.
     pub async fn is_admin_connected_from_ip(&self, ip: &str) -> bool {
-        let users = self.users.read().await;
-        users
-            .values()
+        self.sessions
             .any(|u| u.is_admin && u.address.ip().to_string() == ip)
+            .await
     }
 
     /// Check if any admin is connected from an IP within a given CIDR range
     ///
     /// Used by the ban system to prevent banning a CIDR range that contains an admin's IP.
     pub async fn is_admin_connected_in_range(&self, range: &IpNet) -> bool {
-        let users = self.users.read().await;
-        users
-            .values()
+        self.sessions
             .any(|u| u.is_admin && range.contains(&u.address.ip()))
+            .await
     }
 
     /// Get sorted nicknames for a list of session IDs
@@ -140,11 +145,12 @@ impl UserManager {
     ///
     /// Used by channel join handlers to build member lists.
     pub async fn get_nicknames_for_sessions(&self, session_ids: &[u32]) -> Vec<String> {
-        let users = self.users.read().await;
-        let mut nicknames: Vec<String> = session_ids
-            .iter()
-            .filter_map(|&session_id| users.get(&session_id).map(|u| u.nickname.clone()))
-            .collect();
+        let mut nicknames = Vec::with_capacity(session_ids.len());
+        for &session_id in session_ids {
+            if let Some(nickname) = self.sessions.with(session_id, |u| u.nickname.clone()).await {
+                nicknames.push(nickname);
+            }
+        }
         nicknames.sort_by_key(|n| n.to_lowercase());
         nicknames
     }
@@ -178,37 +184,27 @@ impl UserManager {
         nickname: &str,
         skip_session_id: Option<u32>,
     ) -> bool {
-        let users = self.users.read().await;
-        let nickname_lower = nickname.to_lowercase();
-
-        for &sid in session_ids {
-            if skip_session_id.is_some_and(|skip| skip == sid) {
-                continue;
-            }
-
-            let Some(user) = users.get(&sid) else {
-                continue;
-            };
-
-            if user.nickname.to_lowercase() == nickname_lower {
-                return true;
-            }
-        }
-
-        false
+        // Only the sessions holding this nickname need checking
+        self.sessions
+            .ids_for_nickname(nickname)
+            .into_iter()
+            .any(|sid| skip_session_id != Some(sid) && session_ids.contains(&sid))
     }
 
     /// Get all unique IP addresses for sessions with a given nickname
     ///
     /// Used by the ban system to get IPs when banning by nickname.
     pub async fn get_ips_for_nickname(&self, nickname: &str) -> Vec<String> {
-        let users = self.users.read().await;
-        let nickname_lower = nickname.to_lowercase();
-        let mut ips: Vec<String> = users
-            .values()
-            .filter(|u| u.nickname.to_lowercase() == nickname_lower)
-            .map(|u| u.address.ip().to_string())
-            .collect();
+        let mut ips = Vec::new();
+        for session_id in self.sessions.ids_for_nickname(nickname) {
+            if let Some(ip) = self
+                .sessions
+                .with(session_id, |u| u.address.ip().to_string())
+                .await
+            {
+                ips.push(ip);
+            }
+        }
         ips.sort();
         ips.dedup();
         ips
@@ -222,7 +218,7 @@ mod tests {
 
     /// Create a test session params with the given username and nickname
     fn test_session_params(username: &str, nickname: &str, is_shared: bool) -> NewSessionParams {
-        let (tx, _rx) = mpsc::unbounded_channel();
+        let (tx, _rx) = outbound::channel(Default::default());
         NewSessionParams {
             session_id: 0, // Will be assigned by add_user
             db_user_id: 1,
@@ -239,6 +235,7 @@ mod tests {
             nickname: nickname.to_string(),
             is_away: false,
             status: None,
+            client: None,
         }
     }
 
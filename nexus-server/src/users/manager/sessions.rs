@@ -0,0 +1,284 @@
+//! Sharded session storage for UserManager
+//!
+//! Sessions are spread across a fixed number of shards by session ID, each
+//! behind its own lock. Work on one session (queueing a chat line, checking a
+//! permission, setting away status) only waits on sessions in the same shard,
+//! and a broadcast holds one shard at a time instead of the whole table.
+//!
+//! Nicknames are tracked in a separate index so nickname lookups don't scan
+//! every shard, and so a shared account can claim a nickname atomically.
+//! The index lock is only ever taken last (inside a shard lock or on its own)
+//! and never held across an await, so the two can't deadlock.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+use tokio::sync::RwLock;
+
+use crate::users::user::UserSession;
+
+/// Number of session shards
+const SESSION_SHARDS: usize = 32;
+
+/// Sessions by ID, split across independently locked shards
+#[derive(Debug)]
+pub(super) struct SessionTable {
+    shards: Box<[RwLock<HashMap<u32, UserSession>>]>,
+    /// Session IDs by lowercase nickname
+    nicknames: Mutex<HashMap<String, Vec<u32>>>,
+    /// Number of sessions across all shards
+    len: AtomicUsize,
+    next_id: AtomicU32,
+}
+
+impl SessionTable {
+    /// Create an empty table
+    pub(super) fn new() -> Self {
+        Self {
+            shards: (0..SESSION_SHARDS)
+                .map(|_| RwLock::new(HashMap::new()))
+                .collect(),
+            nicknames: Mutex::new(HashMap::new()),
+            len: AtomicUsize::new(0),
+            next_id: AtomicU32::new(1),
+        }
+    }
+
+    fn shard(&self, session_id: u32) -> &RwLock<HashMap<u32, UserSession>> {
+        &self.shards[session_id as usize % SESSION_SHARDS]
+    }
+
+    fn index(&self) -> MutexGuard<'_, HashMap<String, Vec<u32>>> {
+        self.nicknames.lock().expect("nickname index lock poisoned")
+    }
+
+    /// Number of sessions
+    pub(super) fn len(&self) -> usize {
+        self.len.load(Ordering::Relaxed)
+    }
+
+    /// Assign a session ID and add the session built for it
+    ///
+    /// With `unique_nickname`, the nickname is checked and claimed under the
+    /// index lock, so two sessions can't take the same one. Returns None if
+    /// it's already in use.
+    pub(super) async fn insert(
+        &self,
+        nickname: &str,
+        unique_nickname: bool,
+        build: impl FnOnce(u32) -> UserSession,
+    ) -> Option<u32> {
+        let session_id = {
+            let mut nicknames = self.index();
+            let nickname_lower = nickname.to_lowercase();
+            if unique_nickname && nicknames.contains_key(&nickname_lower) {
+                return None;
+            }
+            let session_id = self.next_id.fetch_add(1, Ordering::Relaxed);
+            nicknames
+                .entry(nickname_lower)
+                .or_default()
+                .push(session_id);
+            session_id
+        };
+
+        let session = build(session_id);
+        self.shard(session_id)
+            .write()
+            .await
+            .insert(session_id, session);
+        self.len.fetch_add(1, Ordering::Relaxed);
+
+        Some(session_id)
+    }
+
+    /// Remove a session
+    pub(super) async fn remove(&self, session_id: u32) -> Option<UserSession> {
+        let session = self.shard(session_id).write().await.remove(&session_id)?;
+        self.len.fetch_sub(1, Ordering::Relaxed);
+        Self::unindex(&mut self.index(), &session.nickname, session_id);
+        Some(session)
+    }
+
+    /// Run `f` on a session, if it exists
+    pub(super) async fn with<R>(
+        &self,
+        session_id: u32,
+        f: impl FnOnce(&UserSession) -> R,
+    ) -> Option<R> {
+        self.shard(session_id).read().await.get(&session_id).map(f)
+    }
+
+    /// Run `f` on a session for modification, if it exists
+    ///
+    /// `f` must not change the nickname; use `update_all` for that.
+    pub(super) async fn with_mut<R>(
+        &self,
+        session_id: u32,
+        f: impl FnOnce(&mut UserSession) -> R,
+    ) -> Option<R> {
+        self.shard(session_id)
+            .write()
+            .await
+            .get_mut(&session_id)
+            .map(f)
+    }
+
+    /// Run `f` on every session, one shard at a time
+    pub(super) async fn for_each(&self, mut f: impl FnMut(&UserSession)) {
+        for shard in self.shards.iter() {
+            shard.read().await.values().for_each(&mut f);
+        }
+    }
+
+    /// Return true if `f` holds for any session
+    pub(super) async fn any(&self, mut f: impl FnMut(&UserSession) -> bool) -> bool {
+        for shard in self.shards.iter() {
+            if shard.read().await.values().any(&mut f) {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Run `f` on every session for modification, keeping the nickname index
+    /// in step with any nickname it changes
+    pub(super) async fn update_all(&self, mut f: impl FnMut(&mut UserSession)) {
+        for shard in self.shards.iter() {
+            let mut shard = shard.write().await;
+            for session in shard.values_mut() {
+                let previous = session.nickname.clone();
+                f(session);
+                if session.nickname != previous {
+                    let mut nicknames = self.index();
+                    Self::unindex(&mut nicknames, &previous, session.session_id);
+                    nicknames
+                        .entry(session.nickname.to_lowercase())
+                        .or_default()
+                        .push(session.session_id);
+                }
+            }
+        }
+    }
+
+    /// Session IDs with a nickname (case-insensitive)
+    pub(super) fn ids_for_nickname(&self, nickname: &str) -> Vec<u32> {
+        self.index()
+            .get(&nickname.to_lowercase())
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Return true if a nickname is in use (case-insensitive)
+    pub(super) fn nickname_in_use(&self, nickname: &str) -> bool {
+        self.index().contains_key(&nickname.to_lowercase())
+    }
+
+    fn unindex(nicknames: &mut HashMap<String, Vec<u32>>, nickname: &str, session_id: u32) {
+        let nickname_lower = nickname.to_lowercase();
+        if let Some(ids) = nicknames.get_mut(&nickname_lower) {
+            ids.retain(|&id| id != session_id);
+            if ids.is_empty() {
+                nicknames.remove(&nickname_lower);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::outbound;
+    use crate::users::user::NewSessionParams;
+
+    fn session(session_id: u32, nickname: &str) -> UserSession {
+        let (tx, _rx) = outbound::channel(Default::default());
+        UserSession::new(NewSessionParams {
+            session_id,
+            db_user_id: 1,
+            username: nickname.to_string(),
+            is_admin: false,
+            is_shared: false,
+            permissions: Default::default(),
+            address: "127.0.0.1:12345".parse().unwrap(),
+            created_at: 0,
+            tx,
+            features: vec![],
+            locale: "en".to_string(),
+            avatar: None,
+            nickname: nickname.to_string(),
+            is_away: false,
+            status: None,
+            client: None,
+        })
+    }
+
+    async fn insert(table: &SessionTable, nickname: &str, unique: bool) -> Option<u32> {
+        table
+            .insert(nickname, unique, |id| session(id, nickname))
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_sessions_spread_across_shards() {
+        let table = SessionTable::new();
+        for i in 0..SESSION_SHARDS * 2 {
+            insert(&table, &format!("user{i}"), false).await.unwrap();
+        }
+        assert_eq!(table.len(), SESSION_SHARDS * 2);
+
+        for shard in table.shards.iter() {
+            assert_eq!(shard.read().await.len(), 2);
+        }
+
+        let mut count = 0;
+        table.for_each(|_| count += 1).await;
+        assert_eq!(count, SESSION_SHARDS * 2);
+    }
+
+    #[tokio::test]
+    async fn test_unique_nickname_claim() {
+        let table = SessionTable::new();
+        let first = insert(&table, "Nick", true).await.unwrap();
+
+        // Taken, case-insensitively
+        assert_eq!(insert(&table, "nick", true).await, None);
+        assert_eq!(table.len(), 1);
+
+        // Sessions that don't need a unique nickname can share it
+        let second = insert(&table, "NICK", false).await.unwrap();
+        assert_eq!(table.ids_for_nickname("nick"), vec![first, second]);
+
+        // Freed once every session holding it is gone
+        table.remove(first).await.unwrap();
+        assert!(table.nickname_in_use("Nick"));
+        table.remove(second).await.unwrap();
+        assert!(!table.nickname_in_use("Nick"));
+        assert_eq!(table.len(), 0);
+        assert!(insert(&table, "nick", true).await.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_update_all_reindexes_nicknames() {
+        let table = SessionTable::new();
+        let alice = insert(&table, "alice", false).await.unwrap();
+        let bob = insert(&table, "bob", false).await.unwrap();
+
+        table
+            .update_all(|session| {
+                if session.nickname == "alice" {
+                    session.nickname = "carol".to_string();
+                }
+            })
+            .await;
+
+        assert!(!table.nickname_in_use("alice"));
+        assert_eq!(table.ids_for_nickname("Carol"), vec![alice]);
+        assert_eq!(table.ids_for_nickname("bob"), vec![bob]);
+        assert_eq!(
+            table.with(alice, |s| s.nickname.clone()).await.as_deref(),
+            Some("carol")
+        );
+    }
+}
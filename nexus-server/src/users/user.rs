@@ -3,11 +3,9 @@
 use std::collections::HashSet;
 use std::net::SocketAddr;
 
-use nexus_common::framing::MessageId;
-use nexus_common::protocol::ServerMessage;
-use tokio::sync::mpsc;
-
 use crate::db::Permission;
+use crate::files::WatchedDirectory;
+use crate::outbound::OutboundSender;
 
 /// Parameters for creating a new user session
 pub struct NewSessionParams {
@@ -19,7 +17,7 @@ pub struct NewSessionParams {
     pub permissions: HashSet<Permission>,
     pub address: SocketAddr,
     pub created_at: i64,
-    pub tx: mpsc::UnboundedSender<(ServerMessage, Option<MessageId>)>,
+    pub tx: OutboundSender,
     pub features: Vec<String>,
     pub locale: String,
     /// User's avatar as a data URI (ephemeral, not stored in DB)
@@ -30,6 +28,8 @@ pub struct NewSessionParams {
     pub is_away: bool,
     /// Optional status message (used for both away messages and general status)
     pub status: Option<String>,
+    /// Client software name sent in the handshake
+    pub client: Option<String>,
 }
 
 /// Represents a logged-in user session
@@ -57,8 +57,10 @@ pub struct UserSession {
     pub created_at: i64,
     /// When the user logged in (Unix timestamp)
     pub login_time: i64,
+    /// When the user last sent a chat or user message (Unix timestamp, starts at login)
+    pub last_active: i64,
     /// Channel sender for sending messages to this user
-    pub tx: mpsc::UnboundedSender<(ServerMessage, Option<MessageId>)>,
+    pub tx: OutboundSender,
     /// Features enabled for this user
     pub features: Vec<String>,
     /// User's preferred locale (e.g., "en", "en-US", "zh-CN")
@@ -71,11 +73,20 @@ pub struct UserSession {
     pub is_away: bool,
     /// Optional status message (used for both away messages and general status)
     pub status: Option<String>,
+    /// Whether this session's client is in do not disturb mode
+    pub is_dnd: bool,
+    /// Client software name sent in the handshake
+    pub client: Option<String>,
+    /// Usernames whose presence this session watches (lowercase)
+    pub presence_subscriptions: HashSet<String>,
+    /// Directories this session watches for changes
+    pub file_watches: Vec<WatchedDirectory>,
 }
 
 impl UserSession {
     /// Create a new user session
     pub fn new(params: NewSessionParams) -> Self {
+        let login_time = current_timestamp();
         Self {
             session_id: params.session_id,
             db_user_id: params.db_user_id,
@@ -85,7 +96,8 @@ impl UserSession {
             permissions: params.permissions,
             address: params.address,
             created_at: params.created_at,
-            login_time: current_timestamp(),
+            login_time,
+            last_active: login_time,
             tx: params.tx,
             features: params.features,
             locale: params.locale,
@@ -93,6 +105,10 @@ impl UserSession {
             nickname: params.nickname,
             is_away: params.is_away,
             status: params.status,
+            is_dnd: false,
+            client: params.client,
+            presence_subscriptions: HashSet::new(),
+            file_watches: Vec::new(),
         }
     }
 
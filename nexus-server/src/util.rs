@@ -0,0 +1,30 @@
+//! Small parsing helpers shared by the command line options
+
+/// Parse a byte count with an optional K, M, G or T suffix (powers of 1024)
+pub fn parse_bytes(s: &str) -> Option<u64> {
+    let (number, shift) = match s.char_indices().last()? {
+        (i, 'K' | 'k') => (&s[..i], 10),
+        (i, 'M' | 'm') => (&s[..i], 20),
+        (i, 'G' | 'g') => (&s[..i], 30),
+        (i, 'T' | 't') => (&s[..i], 40),
+        _ => (s, 0),
+    };
+    number.trim().parse::<u64>().ok()?.checked_mul(1 << shift)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_bytes() {
+        assert_eq!(parse_bytes("512"), Some(512));
+        assert_eq!(parse_bytes("2k"), Some(2048));
+        assert_eq!(parse_bytes("1M"), Some(1024 * 1024));
+        assert_eq!(parse_bytes("3 G"), Some(3 << 30));
+        assert_eq!(parse_bytes("G"), None);
+        assert_eq!(parse_bytes(""), None);
+        assert_eq!(parse_bytes("-1"), None);
+        assert_eq!(parse_bytes("99999999T"), None);
+    }
+}
@@ -13,21 +13,38 @@
 //!
 //! - One voice session per user on this server
 //! - Channel voice: user must be a member of the channel
+//! - Channel voice: joins beyond the channel's voice limit wait in a queue
+//!   and are admitted in order as slots free up
 //! - User message voice: target user must be online
+//! - Broadcast voice: a user with voice_broadcast permission may start a
+//!   broadcast in a channel with nobody in voice; everyone who joins the
+//!   channel's voice afterwards is a listen-only listener, not limited by the
+//!   channel's voice limit, and is removed when the broadcast ends
+//!
+//! ## Network
+//!
+//! - Clients send voice to the BBS host and port unless the server advertises
+//!   a different voice address/port (e.g. behind NAT or a UDP proxy)
+//! - If enabled, clients whose UDP is blocked may relay voice packets over their
+//!   TCP connection instead (VoiceRelay); the server bridges both transports
 
 mod registry;
 mod session;
 mod udp;
 
-use nexus_common::framing::MessageId;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
 use nexus_common::protocol::ServerMessage;
+use nexus_common::voice::{RelayedVoicePacket, VoicePacket};
+use uuid::Uuid;
 
 use crate::channels::ChannelManager;
 use crate::db::Permission;
+use crate::outbound::OutboundSender;
 use crate::users::UserManager;
 
-pub use registry::{VoiceLeaveInfo, VoiceRegistry};
-pub use session::VoiceSession;
+pub use registry::{VoiceLeaveInfo, VoiceNetworkConfig, VoiceRegistry};
+pub use session::{VoiceRole, VoiceSession};
 pub use udp::{VoiceUdpServer, create_voice_listener};
 
 /// Send VoiceUserLeft notifications for a voice leave event.
@@ -38,16 +55,18 @@ pub use udp::{VoiceUdpServer, create_voice_listener};
 /// - handlers/mod.rs (kick, delete, disable, ban)
 /// - voice/udp.rs (DTLS timeout)
 ///
+/// For channels, the freed slot is then offered to the voice join queue.
+///
 /// # Arguments
 /// * `info` - The computed leave info from `VoiceRegistry::remove_by_*`
 /// * `leaving_user_tx` - Channel to send notification to the leaving user (if available)
+/// * `voice_registry` - For admitting queued users into the freed slot
 /// * `user_manager` - For looking up remaining participants to notify
 /// * `channel_manager` - For broadcasting to channel members (channels only)
 pub async fn send_voice_leave_notifications(
     info: &VoiceLeaveInfo,
-    leaving_user_tx: Option<
-        &tokio::sync::mpsc::UnboundedSender<(ServerMessage, Option<MessageId>)>,
-    >,
+    leaving_user_tx: Option<&OutboundSender>,
+    voice_registry: &VoiceRegistry,
     user_manager: &UserManager,
     channel_manager: &ChannelManager,
 ) {
@@ -105,4 +124,345 @@ pub async fn send_voice_leave_notifications(
             }
         }
     }
+
+    if info.session.is_channel() {
+        let channel_name = info.session.target.first().cloned().unwrap_or_default();
+
+        match info.session.role {
+            VoiceRole::Broadcaster => {
+                // The broadcast is over: its listeners have nothing left to hear
+                for listener in voice_registry.remove_listeners(&channel_name).await {
+                    let leave_notification = ServerMessage::VoiceUserLeft {
+                        nickname: listener.nickname.clone(),
+                        target: channel_name.clone(),
+                    };
+                    user_manager
+                        .send_to_session(listener.session_id, leave_notification)
+                        .await;
+                }
+                broadcast_voice_broadcast_update(
+                    &channel_name,
+                    voice_registry,
+                    user_manager,
+                    channel_manager,
+                )
+                .await;
+            }
+            VoiceRole::Listener => {
+                broadcast_voice_broadcast_update(
+                    &channel_name,
+                    voice_registry,
+                    user_manager,
+                    channel_manager,
+                )
+                .await;
+            }
+            VoiceRole::Participant => {}
+        }
+
+        admit_queued_voice_joins(&channel_name, voice_registry, user_manager, channel_manager)
+            .await;
+    }
+}
+
+/// Build the VoiceBroadcastUpdate describing a channel's current broadcast.
+///
+/// `broadcaster` is None if nobody is broadcasting to the channel.
+pub async fn voice_broadcast_update(
+    channel_name: &str,
+    voice_registry: &VoiceRegistry,
+) -> ServerMessage {
+    let broadcaster = voice_registry
+        .get_broadcaster(channel_name)
+        .await
+        .map(|session| session.nickname);
+    let listeners = if broadcaster.is_some() {
+        voice_registry.listener_count(channel_name).await
+    } else {
+        0
+    };
+
+    ServerMessage::VoiceBroadcastUpdate {
+        target: channel_name.to_string(),
+        broadcaster,
+        listeners,
+    }
+}
+
+/// Send a channel's current broadcast state to every member with voice_listen permission.
+///
+/// Called when a broadcast starts or ends and whenever its listener count changes.
+pub async fn broadcast_voice_broadcast_update(
+    channel_name: &str,
+    voice_registry: &VoiceRegistry,
+    user_manager: &UserManager,
+    channel_manager: &ChannelManager,
+) {
+    let update = voice_broadcast_update(channel_name, voice_registry).await;
+    let members = channel_manager
+        .get_members(channel_name)
+        .await
+        .unwrap_or_default();
+
+    for member_session_id in members {
+        if let Some(member) = user_manager.get_user_by_session_id(member_session_id).await
+            && member.has_permission(Permission::VoiceListen)
+        {
+            let _ = member.tx.send((update.clone(), None));
+        }
+    }
+}
+
+/// Send VoiceUserJoined for a channel to every other member with voice_listen permission.
+///
+/// All members are notified (not just voice participants) so everyone can see who's in voice.
+pub async fn broadcast_channel_voice_join(
+    channel_name: &str,
+    nickname: &str,
+    joining_session_id: u32,
+    user_manager: &UserManager,
+    channel_manager: &ChannelManager,
+) {
+    let members = channel_manager
+        .get_members(channel_name)
+        .await
+        .unwrap_or_default();
+
+    for member_session_id in members {
+        // Skip the joining user
+        if member_session_id == joining_session_id {
+            continue;
+        }
+
+        // Check if member has voice_listen permission
+        if let Some(member) = user_manager.get_user_by_session_id(member_session_id).await
+            && member.has_permission(Permission::VoiceListen)
+        {
+            let join_notification = ServerMessage::VoiceUserJoined {
+                nickname: nickname.to_string(),
+                target: channel_name.to_string(),
+            };
+            let _ = member.tx.send((join_notification, None));
+        }
+    }
+}
+
+/// Admit users waiting in a channel's voice join queue while it has free slots.
+///
+/// Queued users are re-checked on admission: anyone who has disconnected, left the
+/// channel, lost voice_listen permission or joined voice elsewhere is skipped.
+/// Admitted users receive the VoiceJoinResponse they were waiting for, and
+/// everyone still waiting is told their new position.
+pub async fn admit_queued_voice_joins(
+    channel_name: &str,
+    voice_registry: &VoiceRegistry,
+    user_manager: &UserManager,
+    channel_manager: &ChannelManager,
+) {
+    let limit = channel_manager
+        .get_voice_limit(channel_name)
+        .await
+        .unwrap_or(0);
+
+    let mut admitted_any = false;
+    loop {
+        if limit > 0 {
+            let in_voice = voice_registry
+                .get_sessions_for_target(channel_name)
+                .await
+                .len();
+            if in_voice >= limit as usize {
+                break;
+            }
+        }
+
+        let Some(queued) = voice_registry.pop_queue(channel_name).await else {
+            break;
+        };
+        admitted_any = true;
+
+        let Some(user) = user_manager.get_user_by_session_id(queued.session_id).await else {
+            continue;
+        };
+        if !user.has_permission(Permission::VoiceListen)
+            || !channel_manager
+                .is_member(&queued.target, queued.session_id)
+                .await
+            || voice_registry.has_session(queued.session_id).await
+        {
+            continue;
+        }
+
+        let mut participants = voice_registry.get_participants(&queued.target).await;
+        let nickname_already_in_voice = voice_registry
+            .is_nickname_in_target(&queued.target, &user.nickname, None)
+            .await;
+
+        let voice_session = VoiceSession::new(
+            user.nickname.clone(),
+            vec![queued.target.clone()],
+            queued.session_id,
+            user.address.ip(),
+        );
+        let token = voice_registry.add(voice_session).await;
+
+        participants.push(user.nickname.clone());
+        participants.sort_by_key(|a| a.to_lowercase());
+
+        if !nickname_already_in_voice {
+            broadcast_channel_voice_join(
+                &queued.target,
+                &user.nickname,
+                queued.session_id,
+                user_manager,
+                channel_manager,
+            )
+            .await;
+        }
+
+        let response =
+            voice_join_success_response(voice_registry, token, queued.target, participants);
+        let _ = user.tx.send((response, None));
+    }
+
+    if admitted_any {
+        send_voice_queue_positions(channel_name, voice_registry, user_manager).await;
+    }
+}
+
+/// Build the VoiceJoinResponse for a successful join.
+///
+/// Includes the advertised voice address/port and whether TCP relay is available.
+pub fn voice_join_success_response(
+    voice_registry: &VoiceRegistry,
+    token: Uuid,
+    target: String,
+    participants: Vec<String>,
+) -> ServerMessage {
+    join_response(voice_registry, token, target, participants, None, None)
+}
+
+/// Build the VoiceJoinResponse for a successful join of a channel's broadcast.
+///
+/// `listener` is false for the broadcaster itself.
+pub fn voice_broadcast_join_response(
+    voice_registry: &VoiceRegistry,
+    token: Uuid,
+    target: String,
+    participants: Vec<String>,
+    listener: bool,
+    listeners: u32,
+) -> ServerMessage {
+    join_response(
+        voice_registry,
+        token,
+        target,
+        participants,
+        Some(listener),
+        Some(listeners),
+    )
+}
+
+fn join_response(
+    voice_registry: &VoiceRegistry,
+    token: Uuid,
+    target: String,
+    participants: Vec<String>,
+    listener: Option<bool>,
+    listeners: Option<u32>,
+) -> ServerMessage {
+    let network = voice_registry.network();
+    ServerMessage::VoiceJoinResponse {
+        success: true,
+        token: Some(token),
+        target: Some(target),
+        participants: Some(participants),
+        voice_address: network.advertise_address.clone(),
+        voice_port: network.advertise_port,
+        tcp_relay: network.tcp_relay.then_some(true),
+        listener,
+        listeners,
+        error: None,
+    }
+}
+
+/// Relay a voice packet to the other participants in the same voice session.
+///
+/// Participants connected over DTLS receive a RelayedVoicePacket on their DTLS
+/// connection; participants relaying over TCP receive it base64-encoded in a
+/// VoiceRelay message. Used by both the UDP server and the VoiceRelay handler.
+pub async fn relay_voice_packet(
+    packet: &VoicePacket,
+    sender_nickname: &str,
+    target_key: &str,
+    voice_registry: &VoiceRegistry,
+    user_manager: &UserManager,
+    debug: bool,
+) {
+    let sessions = voice_registry.get_sessions_for_target(target_key).await;
+
+    let relayed = RelayedVoicePacket::from_voice_packet(packet, sender_nickname.to_string());
+    let relayed_bytes = relayed.to_bytes();
+    let mut relayed_base64 = None;
+
+    for session in sessions {
+        // Don't send back to sender
+        if session.nickname == sender_nickname {
+            continue;
+        }
+
+        if session.tcp_relay {
+            let data = relayed_base64
+                .get_or_insert_with(|| BASE64.encode(&relayed_bytes))
+                .clone();
+            user_manager
+                .send_to_session(session.session_id, ServerMessage::VoiceRelay { data })
+                .await;
+            continue;
+        }
+
+        // Find the DTLS connection for this session
+        if let Some(udp_addr) = session.udp_addr
+            && let Some(conn) = voice_registry.get_dtls_conn(udp_addr).await
+            && let Err(e) = conn.send(&relayed_bytes).await
+            && debug
+        {
+            log_error!(
+                "Voice DTLS: Failed to relay to {} ({}): {}",
+                session.nickname,
+                udp_addr,
+                e
+            );
+        }
+    }
+}
+
+/// Remove a session from any voice join queue it is waiting in.
+///
+/// Users still waiting behind it are told their new position.
+/// Returns the channel the session was queued for, or None if it wasn't queued.
+pub async fn leave_voice_queue(
+    session_id: u32,
+    voice_registry: &VoiceRegistry,
+    user_manager: &UserManager,
+) -> Option<String> {
+    let channel_name = voice_registry.dequeue(session_id).await?;
+    send_voice_queue_positions(&channel_name, voice_registry, user_manager).await;
+    Some(channel_name)
+}
+
+/// Send every user waiting for a channel's voice their current queue position.
+async fn send_voice_queue_positions(
+    channel_name: &str,
+    voice_registry: &VoiceRegistry,
+    user_manager: &UserManager,
+) {
+    let queued = voice_registry.queued_session_ids(channel_name).await;
+    for (index, session_id) in queued.into_iter().enumerate() {
+        let update = ServerMessage::VoiceQueueUpdate {
+            target: channel_name.to_string(),
+            position: Some(index as u32 + 1),
+        };
+        user_manager.send_to_session(session_id, update).await;
+    }
 }
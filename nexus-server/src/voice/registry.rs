@@ -1,16 +1,20 @@
 //! Voice registry for managing active voice sessions
 //!
 //! The registry tracks all active voice sessions on the server and provides
-//! methods for adding, removing, and querying sessions.
+//! methods for adding, removing, and querying sessions. It also holds the
+//! join queues for channels whose voice participant limit has been reached,
+//! the voice network settings advertised to clients, and the open DTLS
+//! connections used to relay voice packets.
 
-use std::collections::{HashMap, HashSet};
-use std::net::IpAddr;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::net::{IpAddr, SocketAddr};
 use std::sync::Arc;
 
 use tokio::sync::RwLock;
 use uuid::Uuid;
+use webrtc_util::conn::Conn;
 
-use super::session::VoiceSession;
+use super::session::{VoiceRole, VoiceSession};
 
 /// Information needed to send VoiceUserLeft notifications after removing a session.
 ///
@@ -29,6 +33,26 @@ pub struct VoiceLeaveInfo {
     pub broadcast_target: String,
 }
 
+/// A user waiting for a free slot in a full voice channel
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueuedVoiceJoin {
+    /// TCP session ID of the waiting user
+    pub session_id: u32,
+    /// Channel name as sent by the client
+    pub target: String,
+}
+
+/// Voice network settings sent to clients in VoiceJoinResponse
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VoiceNetworkConfig {
+    /// Host clients should send UDP voice traffic to (None = the BBS host)
+    pub advertise_address: Option<String>,
+    /// Port clients should send UDP voice traffic to (None = the BBS port)
+    pub advertise_port: Option<u16>,
+    /// Whether clients may relay voice over their TCP connection when UDP is blocked
+    pub tcp_relay: bool,
+}
+
 /// Manages all active voice sessions on the server
 ///
 /// The registry is entirely in-memory - voice state is not persisted.
@@ -41,18 +65,37 @@ pub struct VoiceRegistry {
     session_id_to_token: Arc<RwLock<HashMap<u32, Uuid>>>,
     /// Set of IPs with active voice sessions (for O(1) UDP validation)
     active_ips: Arc<RwLock<HashSet<IpAddr>>>,
+    /// Map of channel name (lowercase) -> users waiting to join its voice, in order
+    queues: Arc<RwLock<HashMap<String, VecDeque<QueuedVoiceJoin>>>>,
+    /// Map of UDP address -> open DTLS connection (for relaying voice packets)
+    dtls_conns: Arc<RwLock<HashMap<SocketAddr, Arc<dyn Conn + Send + Sync>>>>,
+    /// Voice network settings advertised to clients
+    network: Arc<VoiceNetworkConfig>,
 }
 
 impl VoiceRegistry {
     /// Create a new empty voice registry
     pub fn new() -> Self {
+        Self::with_network(VoiceNetworkConfig::default())
+    }
+
+    /// Create a new empty voice registry with the given voice network settings
+    pub fn with_network(network: VoiceNetworkConfig) -> Self {
         Self {
             sessions: Arc::new(RwLock::new(HashMap::new())),
             session_id_to_token: Arc::new(RwLock::new(HashMap::new())),
             active_ips: Arc::new(RwLock::new(HashSet::new())),
+            queues: Arc::new(RwLock::new(HashMap::new())),
+            dtls_conns: Arc::new(RwLock::new(HashMap::new())),
+            network: Arc::new(network),
         }
     }
 
+    /// Get the voice network settings advertised to clients
+    pub fn network(&self) -> &VoiceNetworkConfig {
+        &self.network
+    }
+
     /// Add a voice session to the registry
     ///
     /// Returns the session's token for the client to use in UDP packets.
@@ -130,9 +173,11 @@ impl VoiceRegistry {
         let is_channel = session.is_channel();
 
         // Check if this nickname still has other sessions in voice for this target
-        let nickname_still_in_voice = self
-            .is_nickname_in_target(&target_key, &session.nickname, None)
-            .await;
+        // (listeners were never announced, so there is nothing to broadcast)
+        let nickname_still_in_voice = session.is_listener()
+            || self
+                .is_nickname_in_target(&target_key, &session.nickname, None)
+                .await;
 
         // Compute target string for the leaving user's notification
         let self_target = if is_channel {
@@ -227,23 +272,79 @@ impl VoiceRegistry {
             s.target_key().to_lowercase() == target_lower
                 && s.nickname.to_lowercase() == nickname_lower
                 && exclude_session_id != Some(s.session_id)
+                && !s.is_listener()
         })
     }
 
     /// Get all participants in a voice target (channel or user message)
     ///
     /// Returns a list of nicknames of users in voice for the given target.
+    /// Broadcast listeners are not participants.
     pub async fn get_participants(&self, target_key: &str) -> Vec<String> {
         let sessions = self.sessions.read().await;
         let target_lower = target_key.to_lowercase();
 
         sessions
             .values()
-            .filter(|s| s.target_key().to_lowercase() == target_lower)
+            .filter(|s| s.target_key().to_lowercase() == target_lower && !s.is_listener())
             .map(|s| s.nickname.clone())
             .collect()
     }
 
+    /// Get the session broadcasting to a channel, if any
+    pub async fn get_broadcaster(&self, channel: &str) -> Option<VoiceSession> {
+        let sessions = self.sessions.read().await;
+        let channel_lower = channel.to_lowercase();
+
+        sessions
+            .values()
+            .find(|s| {
+                s.role == VoiceRole::Broadcaster && s.target_key().to_lowercase() == channel_lower
+            })
+            .cloned()
+    }
+
+    /// Count the sessions listening to a channel's broadcast
+    pub async fn listener_count(&self, channel: &str) -> u32 {
+        let sessions = self.sessions.read().await;
+        let channel_lower = channel.to_lowercase();
+
+        sessions
+            .values()
+            .filter(|s| s.is_listener() && s.target_key().to_lowercase() == channel_lower)
+            .count() as u32
+    }
+
+    /// Remove every session listening to a channel's broadcast
+    ///
+    /// Called when the broadcast ends. Returns the removed sessions.
+    pub async fn remove_listeners(&self, channel: &str) -> Vec<VoiceSession> {
+        let mut sessions = self.sessions.write().await;
+        let mut id_to_token = self.session_id_to_token.write().await;
+        let mut active_ips = self.active_ips.write().await;
+        let channel_lower = channel.to_lowercase();
+
+        let tokens: Vec<Uuid> = sessions
+            .values()
+            .filter(|s| s.is_listener() && s.target_key().to_lowercase() == channel_lower)
+            .map(|s| s.token)
+            .collect();
+
+        let removed: Vec<VoiceSession> = tokens
+            .iter()
+            .filter_map(|token| sessions.remove(token))
+            .collect();
+
+        for session in &removed {
+            id_to_token.remove(&session.session_id);
+            if !sessions.values().any(|s| s.ip == session.ip) {
+                active_ips.remove(&session.ip);
+            }
+        }
+
+        removed
+    }
+
     /// Get all voice sessions for a target (channel or user message)
     ///
     /// Returns cloned sessions for broadcasting voice events.
@@ -271,6 +372,34 @@ impl VoiceRegistry {
         }
     }
 
+    /// Mark a session (identified by token) as relaying voice over TCP
+    ///
+    /// Called when the first VoiceRelay message is received from a client.
+    pub async fn set_tcp_relay(&self, token: Uuid) -> bool {
+        let mut sessions = self.sessions.write().await;
+        if let Some(session) = sessions.get_mut(&token) {
+            session.tcp_relay = true;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Register an open DTLS connection so voice packets can be relayed to it
+    pub async fn add_dtls_conn(&self, addr: SocketAddr, conn: Arc<dyn Conn + Send + Sync>) {
+        self.dtls_conns.write().await.insert(addr, conn);
+    }
+
+    /// Forget a closed DTLS connection
+    pub async fn remove_dtls_conn(&self, addr: SocketAddr) {
+        self.dtls_conns.write().await.remove(&addr);
+    }
+
+    /// Get the open DTLS connection for a UDP address
+    pub async fn get_dtls_conn(&self, addr: SocketAddr) -> Option<Arc<dyn Conn + Send + Sync>> {
+        self.dtls_conns.read().await.get(&addr).cloned()
+    }
+
     /// Update the nickname for all sessions belonging to a given session_id.
     ///
     /// Called when a user's username changes (for regular accounts, nickname == username).
@@ -288,10 +417,69 @@ impl VoiceRegistry {
         false
     }
 
+    /// Add a session to the end of a channel's voice join queue
+    ///
+    /// Returns the 1-based queue position.
+    pub async fn enqueue(&self, target: String, session_id: u32) -> u32 {
+        let mut queues = self.queues.write().await;
+        let queue = queues.entry(target.to_lowercase()).or_default();
+        queue.push_back(QueuedVoiceJoin { session_id, target });
+        queue.len() as u32
+    }
+
+    /// Check if a session is waiting in any voice join queue
+    pub async fn is_queued(&self, session_id: u32) -> bool {
+        let queues = self.queues.read().await;
+        queues
+            .values()
+            .any(|queue| queue.iter().any(|q| q.session_id == session_id))
+    }
+
+    /// Remove a session from whichever voice join queue it is waiting in
+    ///
+    /// Returns the channel it was queued for, or None if it wasn't queued.
+    pub async fn dequeue(&self, session_id: u32) -> Option<String> {
+        let mut queues = self.queues.write().await;
+        let key = queues
+            .iter()
+            .find(|(_, queue)| queue.iter().any(|q| q.session_id == session_id))
+            .map(|(key, _)| key.clone())?;
+
+        let queue = queues.get_mut(&key)?;
+        let index = queue.iter().position(|q| q.session_id == session_id)?;
+        let removed = queue.remove(index)?;
+        if queue.is_empty() {
+            queues.remove(&key);
+        }
+        Some(removed.target)
+    }
+
+    /// Take the user at the front of a channel's voice join queue
+    pub async fn pop_queue(&self, channel: &str) -> Option<QueuedVoiceJoin> {
+        let key = channel.to_lowercase();
+        let mut queues = self.queues.write().await;
+        let queue = queues.get_mut(&key)?;
+        let next = queue.pop_front();
+        if queue.is_empty() {
+            queues.remove(&key);
+        }
+        next
+    }
+
+    /// Get the session IDs waiting for a channel's voice, in queue order
+    pub async fn queued_session_ids(&self, channel: &str) -> Vec<u32> {
+        let queues = self.queues.read().await;
+        queues
+            .get(&channel.to_lowercase())
+            .map(|queue| queue.iter().map(|q| q.session_id).collect())
+            .unwrap_or_default()
+    }
+
     /// Find sessions that never established a UDP connection and are older than the timeout.
     ///
     /// Returns tokens of stale sessions that should be cleaned up.
     /// This handles the case where a client sends VoiceJoin but fails to connect via DTLS.
+    /// Sessions relaying voice over TCP are not stale - they leave with the TCP connection.
     pub async fn find_stale_sessions(&self, timeout_secs: u64) -> Vec<Uuid> {
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
@@ -303,7 +491,9 @@ impl VoiceRegistry {
             .iter()
             .filter(|(_, session)| {
                 // Session never got a UDP connection and is older than timeout
-                session.udp_addr.is_none() && (now - session.joined_at) > timeout_secs as i64
+                session.udp_addr.is_none()
+                    && !session.tcp_relay
+                    && (now - session.joined_at) > timeout_secs as i64
             })
             .map(|(token, _)| *token)
             .collect()
@@ -679,6 +869,107 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_broadcast_listeners() {
+        let registry = VoiceRegistry::new();
+        registry
+            .add(create_test_session("alice", "#radio", 1).with_role(VoiceRole::Broadcaster))
+            .await;
+        registry
+            .add(create_test_session("bob", "#radio", 2).with_role(VoiceRole::Listener))
+            .await;
+        registry
+            .add(create_test_session("carol", "#radio", 3).with_role(VoiceRole::Listener))
+            .await;
+
+        assert_eq!(
+            registry.get_broadcaster("#RADIO").await.unwrap().nickname,
+            "alice"
+        );
+        assert_eq!(registry.listener_count("#radio").await, 2);
+
+        // Listeners are not participants
+        assert_eq!(registry.get_participants("#radio").await, vec!["alice"]);
+        assert!(!registry.is_nickname_in_target("#radio", "bob", None).await);
+
+        // A leaving listener is not announced
+        let info = registry.remove_by_session_id(2).await.unwrap();
+        assert!(!info.should_broadcast);
+        assert_eq!(registry.listener_count("#radio").await, 1);
+
+        let removed = registry.remove_listeners("#radio").await;
+        assert_eq!(removed.len(), 1);
+        assert_eq!(removed[0].nickname, "carol");
+        assert!(!registry.has_session(3).await);
+        assert!(registry.has_session(1).await);
+    }
+
+    #[tokio::test]
+    async fn test_voice_queue_order() {
+        let registry = VoiceRegistry::new();
+
+        assert_eq!(registry.enqueue("#General".to_string(), 1).await, 1);
+        assert_eq!(registry.enqueue("#general".to_string(), 2).await, 2);
+        assert_eq!(registry.enqueue("#other".to_string(), 3).await, 1);
+
+        assert!(registry.is_queued(2).await);
+        assert_eq!(registry.queued_session_ids("#GENERAL").await, vec![1, 2]);
+
+        let next = registry.pop_queue("#general").await.unwrap();
+        assert_eq!(next.session_id, 1);
+        assert_eq!(next.target, "#General");
+        assert_eq!(registry.queued_session_ids("#general").await, vec![2]);
+    }
+
+    #[tokio::test]
+    async fn test_voice_queue_dequeue() {
+        let registry = VoiceRegistry::new();
+
+        registry.enqueue("#general".to_string(), 1).await;
+        registry.enqueue("#general".to_string(), 2).await;
+
+        assert_eq!(registry.dequeue(1).await, Some("#general".to_string()));
+        assert_eq!(registry.dequeue(1).await, None);
+        assert!(!registry.is_queued(1).await);
+        assert_eq!(registry.queued_session_ids("#general").await, vec![2]);
+
+        // Empty queues are dropped
+        registry.dequeue(2).await;
+        assert!(registry.pop_queue("#general").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_tcp_relay_sessions_are_not_stale() {
+        let registry = VoiceRegistry::new();
+        let mut relayed = create_test_session("alice", "#general", 1);
+        relayed.joined_at -= 120;
+        let mut silent = create_test_session("bob", "#general", 2);
+        silent.joined_at -= 120;
+        let relayed_token = registry.add(relayed).await;
+        let silent_token = registry.add(silent).await;
+
+        assert!(registry.set_tcp_relay(relayed_token).await);
+        assert!(!registry.set_tcp_relay(Uuid::new_v4()).await);
+
+        let stale = registry.find_stale_sessions(60).await;
+        assert_eq!(stale, vec![silent_token]);
+    }
+
+    #[tokio::test]
+    async fn test_with_network() {
+        let network = VoiceNetworkConfig {
+            advertise_address: Some("voice.example.com".to_string()),
+            advertise_port: Some(7600),
+            tcp_relay: true,
+        };
+        let registry = VoiceRegistry::with_network(network.clone());
+        assert_eq!(registry.network(), &network);
+        assert_eq!(
+            VoiceRegistry::new().network(),
+            &VoiceNetworkConfig::default()
+        );
+    }
+
     #[tokio::test]
     async fn test_update_nickname() {
         let registry = VoiceRegistry::new();
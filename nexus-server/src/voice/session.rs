@@ -7,6 +7,20 @@ use std::net::{IpAddr, SocketAddr};
 
 use uuid::Uuid;
 
+use crate::db::Permission;
+
+/// A voice session's role in its target
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum VoiceRole {
+    /// Regular two-way participant
+    #[default]
+    Participant,
+    /// Streams audio to a channel's listeners (one per channel)
+    Broadcaster,
+    /// Receives a channel's broadcast without transmitting
+    Listener,
+}
+
 /// Represents a user's active voice session
 ///
 /// Each user can have at most one voice session per server.
@@ -28,6 +42,11 @@ pub struct VoiceSession {
     pub session_id: u32,
     /// Client's IP address (for validating DTLS connections)
     pub ip: IpAddr,
+    /// Whether voice packets travel over the TCP connection instead of DTLS
+    /// (set when the first VoiceRelay message is received)
+    pub tcp_relay: bool,
+    /// Role in the voice target
+    pub role: VoiceRole,
 }
 
 impl VoiceSession {
@@ -48,6 +67,30 @@ impl VoiceSession {
             udp_addr: None,
             session_id,
             ip,
+            tcp_relay: false,
+            role: VoiceRole::Participant,
+        }
+    }
+
+    /// Set the session's role
+    pub fn with_role(mut self, role: VoiceRole) -> Self {
+        self.role = role;
+        self
+    }
+
+    /// Check if this session only listens to a broadcast
+    pub fn is_listener(&self) -> bool {
+        self.role == VoiceRole::Listener
+    }
+
+    /// Permission required to relay this session's voice packets
+    ///
+    /// Listeners never transmit, so they have none.
+    pub fn transmit_permission(&self) -> Option<Permission> {
+        match self.role {
+            VoiceRole::Participant => Some(Permission::VoiceTalk),
+            VoiceRole::Broadcaster => Some(Permission::VoiceBroadcast),
+            VoiceRole::Listener => None,
         }
     }
 
@@ -144,6 +187,24 @@ mod tests {
         assert_eq!(session.udp_addr, Some(addr));
     }
 
+    #[test]
+    fn test_transmit_permission() {
+        let ip: std::net::IpAddr = "192.168.1.1".parse().unwrap();
+        let session = VoiceSession::new("alice".to_string(), vec!["#general".to_string()], 1, ip);
+        assert_eq!(session.role, VoiceRole::Participant);
+        assert_eq!(session.transmit_permission(), Some(Permission::VoiceTalk));
+
+        let session = session.with_role(VoiceRole::Broadcaster);
+        assert_eq!(
+            session.transmit_permission(),
+            Some(Permission::VoiceBroadcast)
+        );
+
+        let session = session.with_role(VoiceRole::Listener);
+        assert!(session.is_listener());
+        assert_eq!(session.transmit_permission(), None);
+    }
+
     #[test]
     fn test_joined_at_is_recent() {
         let before = std::time::SystemTime::now()
@@ -17,6 +17,16 @@
 //! 3. Client sends VoicePacket with token for authentication
 //! 4. Server validates token, looks up session in VoiceRegistry
 //! 5. Server relays as RelayedVoicePacket to other participants
+//!
+//! Participants relaying voice over TCP (VoiceRelay) are bridged: packets they
+//! send are relayed here to DTLS clients, and DTLS audio reaches them over TCP.
+//!
+//! ## Connection Statistics
+//!
+//! The server tracks packet loss and jitter for the audio it receives from each
+//! client and reports them to everyone in the same voice session (VoiceStats)
+//! every few seconds. Clients lower or raise their bitrate based on their own
+//! report and show a quality indicator for the other participants.
 
 use std::collections::HashMap;
 use std::fs;
@@ -30,22 +40,23 @@ use dtls::crypto::Certificate;
 use dtls::listener::listen;
 use tokio::sync::RwLock;
 
+use uuid::Uuid;
 use webrtc_util::conn::{Conn, Listener};
 
+use nexus_common::protocol::ServerMessage;
 use nexus_common::voice::{
-    MAX_VOICE_PACKET_SIZE, RelayedVoicePacket, VOICE_SESSION_TIMEOUT_SECS, VoiceMessageType,
-    VoicePacket,
+    MAX_VOICE_PACKET_SIZE, VOICE_SESSION_TIMEOUT_SECS, VOICE_STATS_INTERVAL_SECS, VoiceMessageType,
+    VoicePacket, VoiceStreamStats,
 };
 
 /// Interval between stale client cleanup checks (seconds)
 const STALE_CLIENT_CHECK_INTERVAL_SECS: u64 = 30;
 
 use crate::channels::ChannelManager;
-use crate::db::Permission;
 use crate::ip_rule_cache::IpRuleCache;
 use crate::users::UserManager;
 
-use super::{VoiceRegistry, send_voice_leave_notifications};
+use super::{VoiceRegistry, relay_voice_packet, send_voice_leave_notifications};
 
 /// DTLS connection state for a voice client
 struct DtlsClient {
@@ -55,6 +66,10 @@ struct DtlsClient {
     addr: SocketAddr,
     /// Last packet received time (for timeout)
     last_packet: Instant,
+    /// Token of the session whose audio is being counted in `stats`
+    stats_token: Option<Uuid>,
+    /// Loss and jitter of the audio received from this client
+    stats: VoiceStreamStats,
 }
 
 /// Manages UDP/DTLS voice connections
@@ -115,6 +130,12 @@ impl VoiceUdpServer {
             cleanup_self.cleanup_loop().await;
         });
 
+        // Spawn connection statistics task
+        let stats_self = self.clone();
+        tokio::spawn(async move {
+            stats_self.stats_loop().await;
+        });
+
         // Accept loop
         loop {
             match self.listener.accept().await {
@@ -138,7 +159,7 @@ impl VoiceUdpServer {
 
                     if !should_allow {
                         if self.debug {
-                            eprintln!("Voice DTLS: Rejected banned IP {}", remote_addr.ip());
+                            log_debug!("Voice DTLS: Rejected banned IP {}", remote_addr.ip());
                         }
                         let _ = conn.close().await;
                         continue;
@@ -147,7 +168,7 @@ impl VoiceUdpServer {
                     // Reject IPs that don't have an active voice session
                     if !self.registry.has_session_for_ip(remote_addr.ip()).await {
                         if self.debug {
-                            eprintln!(
+                            log_debug!(
                                 "Voice DTLS: Rejected IP {} (no voice session)",
                                 remote_addr.ip()
                             );
@@ -157,7 +178,7 @@ impl VoiceUdpServer {
                     }
 
                     if self.debug {
-                        eprintln!("Voice DTLS: New connection from {}", remote_addr);
+                        log_debug!("Voice DTLS: New connection from {}", remote_addr);
                     }
 
                     // Create client state
@@ -165,6 +186,8 @@ impl VoiceUdpServer {
                         conn: conn.clone(),
                         addr: remote_addr,
                         last_packet: Instant::now(),
+                        stats_token: None,
+                        stats: VoiceStreamStats::new(),
                     }));
 
                     // Store client
@@ -172,6 +195,7 @@ impl VoiceUdpServer {
                         let mut clients = self.clients.write().await;
                         clients.insert(remote_addr, client.clone());
                     }
+                    self.registry.add_dtls_conn(remote_addr, conn.clone()).await;
 
                     // Spawn handler for this connection
                     let server = self.clone();
@@ -181,7 +205,7 @@ impl VoiceUdpServer {
                 }
                 Err(e) => {
                     if self.debug {
-                        eprintln!("Voice DTLS accept error: {}", e);
+                        log_debug!("Voice DTLS accept error: {}", e);
                     }
                 }
             }
@@ -217,20 +241,20 @@ impl VoiceUdpServer {
                 Ok(Ok(_)) => {
                     // Zero-length read, connection closed
                     if self.debug {
-                        eprintln!("Voice DTLS: Connection closed from {}", remote_addr);
+                        log_debug!("Voice DTLS: Connection closed from {}", remote_addr);
                     }
                     break;
                 }
                 Ok(Err(e)) => {
                     if self.debug {
-                        eprintln!("Voice DTLS read error from {}: {}", remote_addr, e);
+                        log_debug!("Voice DTLS read error from {}: {}", remote_addr, e);
                     }
                     break;
                 }
                 Err(_) => {
                     // Timeout
                     if self.debug {
-                        eprintln!("Voice DTLS: Connection timeout from {}", remote_addr);
+                        log_debug!("Voice DTLS: Connection timeout from {}", remote_addr);
                     }
                     break;
                 }
@@ -242,6 +266,7 @@ impl VoiceUdpServer {
             let mut clients = self.clients.write().await;
             clients.remove(&remote_addr);
         }
+        self.registry.remove_dtls_conn(remote_addr).await;
 
         // Close the connection
         let conn = {
@@ -260,22 +285,33 @@ impl VoiceUdpServer {
         let Some(packet) = VoicePacket::from_bytes(data) else {
             if self.debug {
                 let addr = client.read().await.addr;
-                eprintln!("Voice DTLS: Invalid packet from {}", addr);
+                log_debug!("Voice DTLS: Invalid packet from {}", addr);
             }
             return true; // Invalid packet, but keep connection
         };
 
-        // Update last packet time
+        // Update last packet time, and count audio for connection statistics
         {
+            let now = Instant::now();
             let mut client_guard = client.write().await;
-            client_guard.last_packet = Instant::now();
+            client_guard.last_packet = now;
+            if packet.msg_type == VoiceMessageType::VoiceData {
+                if client_guard.stats_token != Some(packet.token) {
+                    // New voice session on this connection - start counting afresh
+                    client_guard.stats_token = Some(packet.token);
+                    client_guard.stats = VoiceStreamStats::new();
+                }
+                client_guard
+                    .stats
+                    .record(packet.sequence, packet.timestamp, now);
+            }
         }
 
         // Always validate against registry - session may have been removed via VoiceLeave
         let Some(session) = self.registry.get_by_token(packet.token).await else {
             if self.debug {
                 let addr = client.read().await.addr;
-                eprintln!(
+                log_debug!(
                     "Voice DTLS: Session not found for token from {} - closing connection",
                     addr
                 );
@@ -299,28 +335,40 @@ impl VoiceUdpServer {
                 // Keepalive just updates last_packet time, already done above
                 if self.debug {
                     let addr = client.read().await.addr;
-                    eprintln!("Voice DTLS: Keepalive from {} ({})", sender_nickname, addr);
+                    log_debug!("Voice DTLS: Keepalive from {} ({})", sender_nickname, addr);
                 }
             }
             VoiceMessageType::VoiceData
             | VoiceMessageType::SpeakingStarted
             | VoiceMessageType::SpeakingStopped => {
-                // Check voice_talk permission before relaying
-                match self
-                    .user_manager
-                    .has_permission(session_id, Permission::VoiceTalk)
-                    .await
-                {
+                // Check the session's transmit permission before relaying (voice_talk,
+                // or voice_broadcast for broadcasters; listeners never transmit)
+                let has_permission = match session.transmit_permission() {
+                    Some(permission) => {
+                        self.user_manager
+                            .has_permission(session_id, permission)
+                            .await
+                    }
+                    None => Some(false),
+                };
+                match has_permission {
                     Some(true) => {
                         // User has permission, relay the packet
-                        self.relay_packet(&packet, &sender_nickname, &target_key)
-                            .await;
+                        relay_voice_packet(
+                            &packet,
+                            &sender_nickname,
+                            &target_key,
+                            &self.registry,
+                            &self.user_manager,
+                            self.debug,
+                        )
+                        .await;
                     }
                     Some(false) => {
                         // User lacks permission, drop packet silently
                         if self.debug {
-                            eprintln!(
-                                "Voice DTLS: {} lacks voice_talk permission, dropping packet",
+                            log_debug!(
+                                "Voice DTLS: {} lacks permission to transmit, dropping packet",
                                 sender_nickname
                             );
                         }
@@ -335,39 +383,59 @@ impl VoiceUdpServer {
         true // Keep connection alive
     }
 
-    /// Relay a voice packet to other participants in the same voice session
-    async fn relay_packet(&self, packet: &VoicePacket, sender_nickname: &str, target_key: &str) {
-        // Get all sessions for this target
-        let sessions = self.registry.get_sessions_for_target(target_key).await;
-
-        // Create relayed packet
-        let relayed = RelayedVoicePacket::from_voice_packet(packet, sender_nickname.to_string());
-        let relayed_bytes = relayed.to_bytes();
+    /// Statistics loop reporting each speaker's loss and jitter to their voice session
+    ///
+    /// Only clients that sent audio since the previous report are included.
+    async fn stats_loop(&self) {
+        let mut interval = tokio::time::interval(Duration::from_secs(VOICE_STATS_INTERVAL_SECS));
+        // The first tick completes immediately
+        interval.tick().await;
 
-        // Get client map for connection lookup
-        let clients = self.clients.read().await;
+        loop {
+            interval.tick().await;
 
-        for session in sessions {
-            // Don't send back to sender
-            if session.nickname == sender_nickname {
-                continue;
+            // Collect reports first so no client lock is held while sending
+            let mut reports = Vec::new();
+            {
+                let clients = self.clients.read().await;
+                for client in clients.values() {
+                    let mut client_guard = client.write().await;
+                    if let Some(token) = client_guard.stats_token
+                        && let Some(report) = client_guard.stats.take_report()
+                    {
+                        reports.push((token, report));
+                    }
+                }
             }
 
-            // Find the DTLS connection for this session
-            if let Some(udp_addr) = session.udp_addr
-                && let Some(client) = clients.get(&udp_addr)
-            {
-                let conn = {
-                    let client_guard = client.read().await;
-                    client_guard.conn.clone()
+            for (token, report) in reports {
+                let Some(session) = self.registry.get_by_token(token).await else {
+                    continue;
+                };
+
+                let message = ServerMessage::VoiceStats {
+                    nickname: session.nickname.clone(),
+                    loss_percent: report.loss_percent,
+                    jitter_ms: report.jitter_ms,
                 };
 
-                if let Err(e) = conn.send(&relayed_bytes).await
-                    && self.debug
+                // Includes the speaker, who adapts their bitrate from it
+                for participant in self
+                    .registry
+                    .get_sessions_for_target(&session.target_key())
+                    .await
                 {
-                    eprintln!(
-                        "Voice DTLS: Failed to relay to {} ({}): {}",
-                        session.nickname, udp_addr, e
+                    self.user_manager
+                        .send_to_session(participant.session_id, message.clone())
+                        .await;
+                }
+
+                if self.debug {
+                    log_debug!(
+                        "Voice DTLS: Stats for {}: {}% loss, {}ms jitter",
+                        session.nickname,
+                        report.loss_percent,
+                        report.jitter_ms
                     );
                 }
             }
@@ -397,9 +465,10 @@ impl VoiceUdpServer {
             // Remove timed-out clients
             for addr in timed_out_addrs {
                 if let Some(client) = clients.remove(&addr) {
+                    self.registry.remove_dtls_conn(addr).await;
                     let client_guard = client.read().await;
                     if self.debug {
-                        eprintln!("Voice DTLS: Cleanup timed out client: {}", addr);
+                        log_debug!("Voice DTLS: Cleanup timed out client: {}", addr);
                     }
                     // Close connection
                     let _ = client_guard.conn.close().await;
@@ -426,13 +495,14 @@ impl VoiceUdpServer {
                     send_voice_leave_notifications(
                         &info,
                         leaving_user_tx.as_ref(),
+                        &self.registry,
                         &self.user_manager,
                         &self.channel_manager,
                     )
                     .await;
 
                     if self.debug {
-                        eprintln!(
+                        log_debug!(
                             "Voice DTLS: Removed stale voice session for {} (no UDP connection)",
                             info.session.nickname
                         );
@@ -0,0 +1,132 @@
+//! HTTP Basic authentication against Nexus accounts
+//!
+//! Mirrors the transfer port login: an empty username means the guest
+//! account, shared accounts are limited to their shared permissions,
+//! directory accounts are checked against the directory, and disabled
+//! accounts are refused.
+
+use std::collections::HashSet;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+
+use nexus_common::validators::{self, PasswordError};
+
+use crate::db::sql::GUEST_USERNAME;
+use crate::db::{self, Database, Permission};
+use crate::ldap::Ldap;
+
+/// An authenticated WebDAV user
+#[derive(Debug, Clone)]
+pub(super) struct DavUser {
+    pub username: String,
+    pub is_admin: bool,
+    pub permissions: HashSet<Permission>,
+}
+
+impl DavUser {
+    /// Check if the user has a permission (admins have all)
+    pub fn has_permission(&self, permission: Permission) -> bool {
+        self.is_admin || self.permissions.contains(&permission)
+    }
+}
+
+/// Extract the username and password from a Basic Authorization header
+pub(super) fn parse_basic_auth(header: &str) -> Option<(String, String)> {
+    let (scheme, encoded) = header.trim().split_once(' ')?;
+    if !scheme.eq_ignore_ascii_case("basic") {
+        return None;
+    }
+    let decoded = BASE64.decode(encoded.trim()).ok()?;
+    let credentials = String::from_utf8(decoded).ok()?;
+    let (username, password) = credentials.split_once(':')?;
+    Some((username.to_string(), password.to_string()))
+}
+
+/// Check credentials against the database
+///
+/// Returns `Ok(None)` for wrong credentials or a disabled account.
+pub(super) async fn authenticate(
+    db: &Database,
+    ldap: &Ldap,
+    username: &str,
+    password: &str,
+) -> Result<Option<DavUser>, String> {
+    // Normalize empty username to "guest"
+    let username = if username.is_empty() {
+        GUEST_USERNAME
+    } else {
+        username
+    };
+
+    if username.to_lowercase() != GUEST_USERNAME && validators::validate_username(username).is_err()
+    {
+        return Ok(None);
+    }
+    if let Err(PasswordError::TooLong) = validators::validate_password_input(password) {
+        return Ok(None);
+    }
+
+    let account = match db.users.get_user_by_username(username).await {
+        Ok(Some(account)) => account,
+        Ok(None) => return Ok(None),
+        Err(e) => return Err(format!("Database error: {e}")),
+    };
+
+    let directory_valid = ldap
+        .check_password(db, &account, password)
+        .await
+        .map_err(|e| format!("Directory login failed: {e}"))?;
+    let password_valid = if let Some(valid) = directory_valid {
+        valid
+    } else if account.hashed_password.is_empty() {
+        // Guest account - password must be empty
+        password.is_empty()
+    } else {
+        db::verify_password(password, &account.hashed_password)
+            .map_err(|e| format!("Password verification error: {e}"))?
+    };
+    if !password_valid || !account.enabled {
+        return Ok(None);
+    }
+
+    let permissions = if account.is_admin {
+        HashSet::new()
+    } else {
+        match db.users.get_user_permissions(account.id).await {
+            Ok(mut perms) => {
+                perms.restrict_for_account(&account);
+                perms.permissions
+            }
+            Err(_) => HashSet::new(),
+        }
+    };
+
+    Ok(Some(DavUser {
+        username: account.username,
+        is_admin: account.is_admin,
+        permissions,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_basic_auth() {
+        // alice:secret:with:colons
+        assert_eq!(
+            parse_basic_auth("Basic YWxpY2U6c2VjcmV0OndpdGg6Y29sb25z"),
+            Some(("alice".to_string(), "secret:with:colons".to_string()))
+        );
+        // Guest logs in with an empty username and password
+        assert_eq!(
+            parse_basic_auth("basic Og=="),
+            Some((String::new(), String::new()))
+        );
+        assert_eq!(parse_basic_auth("Bearer YWxpY2U6c2VjcmV0"), None);
+        assert_eq!(parse_basic_auth("Basic !!!"), None);
+        assert_eq!(parse_basic_auth("Basic YWxpY2U="), None);
+    }
+}
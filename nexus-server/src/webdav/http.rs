@@ -0,0 +1,624 @@
+//! Minimal HTTP/1.1 request parsing and response writing
+//!
+//! Only what a read-only WebDAV share needs: requests are read head first,
+//! any body is discarded (PROPFIND bodies only ever ask for properties the
+//! gateway sends anyway), and responses are written with a known length.
+
+use std::io;
+
+use chrono::{DateTime, Utc};
+use tokio::fs::File;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+/// Maximum size of a request line plus headers
+const MAX_HEAD_SIZE: u64 = 16 * 1024;
+
+/// Maximum number of request headers
+const MAX_HEADERS: usize = 64;
+
+/// Maximum request body size (larger bodies are refused)
+const MAX_BODY_SIZE: u64 = 64 * 1024;
+
+/// A parsed HTTP request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct Request {
+    /// Request method (e.g. "PROPFIND")
+    pub method: String,
+    /// Decoded request path, without any query string
+    pub path: String,
+    /// Whether the request was HTTP/1.0
+    pub http10: bool,
+    /// Header names (lowercase) and values
+    pub headers: Vec<(String, String)>,
+}
+
+impl Request {
+    /// Get a header value by lowercase name
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Whether the client wants the connection kept open after this request
+    pub fn keep_alive(&self) -> bool {
+        let connection = self.header("connection").unwrap_or_default();
+        let has_token = |token: &str| {
+            connection
+                .split(',')
+                .any(|t| t.trim().eq_ignore_ascii_case(token))
+        };
+        if self.http10 {
+            has_token("keep-alive")
+        } else {
+            !has_token("close")
+        }
+    }
+}
+
+/// Response body
+pub(super) enum Body {
+    Empty,
+    Bytes(Vec<u8>),
+    /// `len` bytes from the file's current position
+    File {
+        file: File,
+        len: u64,
+    },
+}
+
+/// An HTTP response
+pub(super) struct Response {
+    pub status: u16,
+    pub headers: Vec<(&'static str, String)>,
+    pub body: Body,
+    /// Length to send in Content-Length (differs from the body for HEAD)
+    pub content_length: u64,
+}
+
+impl Response {
+    /// A response with no body
+    pub fn empty(status: u16) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: Body::Empty,
+            content_length: 0,
+        }
+    }
+
+    /// A response with an in-memory body
+    pub fn bytes(status: u16, content_type: &str, body: Vec<u8>) -> Self {
+        Self {
+            status,
+            headers: vec![("Content-Type", content_type.to_string())],
+            content_length: body.len() as u64,
+            body: Body::Bytes(body),
+        }
+    }
+
+    /// Add a header
+    pub fn with_header(mut self, name: &'static str, value: impl Into<String>) -> Self {
+        self.headers.push((name, value.into()));
+        self
+    }
+
+    /// Drop the body but keep its length (HEAD)
+    pub fn without_body(mut self) -> Self {
+        self.body = Body::Empty;
+        self
+    }
+}
+
+/// Reason phrase for the status codes the gateway sends
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        206 => "Partial Content",
+        207 => "Multi-Status",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        416 => "Range Not Satisfiable",
+        500 => "Internal Server Error",
+        _ => "Unknown",
+    }
+}
+
+/// Read one line of the request head, counting it against the head budget
+async fn read_head_line<R>(reader: &mut R, budget: &mut u64) -> io::Result<Option<String>>
+where
+    R: AsyncBufRead + Unpin,
+{
+    let mut line = Vec::new();
+    let read = (&mut *reader)
+        .take(*budget)
+        .read_until(b'\n', &mut line)
+        .await?;
+    if read == 0 {
+        return Ok(None);
+    }
+    if line.last() != Some(&b'\n') {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "Request head too large",
+        ));
+    }
+    *budget -= read as u64;
+
+    let line = String::from_utf8(line)
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "Request head is not UTF-8"))?;
+    Ok(Some(line.trim_end_matches(['\r', '\n']).to_string()))
+}
+
+/// Read the next request, discarding its body
+///
+/// Returns `None` when the client closed the connection between requests.
+pub(super) async fn read_request<R>(reader: &mut R) -> io::Result<Option<Request>>
+where
+    R: AsyncBufRead + Unpin,
+{
+    let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, msg.to_string());
+    let mut budget = MAX_HEAD_SIZE;
+
+    // Some clients send a stray CRLF after a request body
+    let request_line = loop {
+        match read_head_line(reader, &mut budget).await? {
+            None => return Ok(None),
+            Some(line) if line.is_empty() => continue,
+            Some(line) => break line,
+        }
+    };
+
+    let mut parts = request_line.split(' ');
+    let (Some(method), Some(target), Some(version), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(invalid("Malformed request line"));
+    };
+    let http10 = match version {
+        "HTTP/1.1" => false,
+        "HTTP/1.0" => true,
+        _ => return Err(invalid("Unsupported HTTP version")),
+    };
+    let path = parse_target(target).ok_or_else(|| invalid("Invalid request target"))?;
+
+    let mut headers = Vec::new();
+    loop {
+        let Some(line) = read_head_line(reader, &mut budget).await? else {
+            return Err(invalid("Connection closed in request head"));
+        };
+        if line.is_empty() {
+            break;
+        }
+        if headers.len() == MAX_HEADERS {
+            return Err(invalid("Too many headers"));
+        }
+        let (name, value) = line
+            .split_once(':')
+            .ok_or_else(|| invalid("Malformed header"))?;
+        headers.push((name.trim().to_ascii_lowercase(), value.trim().to_string()));
+    }
+
+    let request = Request {
+        method: method.to_string(),
+        path,
+        http10,
+        headers,
+    };
+
+    if request.header("transfer-encoding").is_some() {
+        return Err(invalid("Chunked request bodies are not supported"));
+    }
+    // Repeated lengths must agree, or the end of the body would be ambiguous
+    let mut lengths = request
+        .headers
+        .iter()
+        .filter(|(name, _)| name == "content-length")
+        .map(|(_, value)| value.as_str());
+    if let Some(length) = lengths.next() {
+        if lengths.any(|other| other != length) {
+            return Err(invalid("Conflicting Content-Length"));
+        }
+        if length.is_empty() || !length.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid("Invalid Content-Length"));
+        }
+        let length: u64 = length
+            .parse()
+            .map_err(|_| invalid("Invalid Content-Length"))?;
+        if length > MAX_BODY_SIZE {
+            return Err(invalid("Request body too large"));
+        }
+        let discarded =
+            tokio::io::copy(&mut (&mut *reader).take(length), &mut tokio::io::sink()).await?;
+        if discarded != length {
+            return Err(invalid("Connection closed in request body"));
+        }
+    }
+
+    Ok(Some(request))
+}
+
+/// Write a response (and its body)
+pub(super) async fn write_response<W>(
+    writer: &mut W,
+    response: Response,
+    keep_alive: bool,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let mut head = format!(
+        "HTTP/1.1 {} {}\r\n",
+        response.status,
+        reason_phrase(response.status)
+    );
+    for (name, value) in &response.headers {
+        head.push_str(&format!("{name}: {value}\r\n"));
+    }
+    head.push_str(&format!(
+        "Content-Length: {}\r\nConnection: {}\r\n\r\n",
+        response.content_length,
+        if keep_alive { "keep-alive" } else { "close" }
+    ));
+    writer.write_all(head.as_bytes()).await?;
+
+    match response.body {
+        Body::Empty => {}
+        Body::Bytes(bytes) => writer.write_all(&bytes).await?,
+        Body::File { file, len } => {
+            let copied = tokio::io::copy(&mut file.take(len), writer).await?;
+            if copied != len {
+                // The file shrank; the client can't tell where the body ends
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "File changed while sending",
+                ));
+            }
+        }
+    }
+    writer.flush().await
+}
+
+/// Extract the decoded path from a request target
+fn parse_target(target: &str) -> Option<String> {
+    // Absolute-form targets carry the scheme and host in front of the path
+    let target = match target.split_once("://") {
+        Some((_, rest)) => &rest[rest.find('/')?..],
+        None => target,
+    };
+    if !target.starts_with('/') {
+        return None;
+    }
+    let path = target.split(['?', '#']).next().unwrap_or_default();
+    percent_decode(path)
+}
+
+/// Decode `%XX` escapes in a path
+fn percent_decode(s: &str) -> Option<String> {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = bytes.get(i + 1..i + 3)?;
+            let hex = std::str::from_utf8(hex).ok()?;
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
+
+/// Percent-encode a path for use in an href (slashes are kept)
+pub(super) fn encode_href(path: &str) -> String {
+    let mut encoded = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'/' | b'-' | b'.' | b'_' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+/// Format a Unix timestamp as an HTTP date (RFC 1123)
+pub(super) fn http_date(timestamp: i64) -> String {
+    DateTime::<Utc>::from_timestamp(timestamp, 0)
+        .unwrap_or_default()
+        .format("%a, %d %b %Y %H:%M:%S GMT")
+        .to_string()
+}
+
+/// Requested part of a file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ByteRange {
+    /// The whole file
+    Full,
+    /// Inclusive start and end offsets
+    Partial(u64, u64),
+    /// A range that lies outside the file
+    Unsatisfiable,
+}
+
+/// Parse a Range header against a file size
+///
+/// Only single ranges are supported; multiple ranges and malformed headers
+/// are ignored and the whole file is sent, as HTTP allows.
+pub(super) fn parse_range(header: Option<&str>, size: u64) -> ByteRange {
+    let Some(spec) = header.and_then(|h| h.trim().strip_prefix("bytes=")) else {
+        return ByteRange::Full;
+    };
+    if spec.contains(',') {
+        return ByteRange::Full;
+    }
+    let Some((start, end)) = spec.trim().split_once('-') else {
+        return ByteRange::Full;
+    };
+
+    match (start.parse::<u64>(), end.parse::<u64>()) {
+        // Last `suffix` bytes
+        (Err(_), Ok(suffix)) if start.is_empty() => {
+            if suffix == 0 || size == 0 {
+                ByteRange::Unsatisfiable
+            } else {
+                ByteRange::Partial(size.saturating_sub(suffix), size - 1)
+            }
+        }
+        // From `start` to the end
+        (Ok(start), Err(_)) if end.is_empty() => {
+            if start >= size {
+                ByteRange::Unsatisfiable
+            } else {
+                ByteRange::Partial(start, size - 1)
+            }
+        }
+        (Ok(start), Ok(end)) if start <= end => {
+            if start >= size {
+                ByteRange::Unsatisfiable
+            } else {
+                ByteRange::Partial(start, end.min(size - 1))
+            }
+        }
+        _ => ByteRange::Full,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    async fn parse(raw: &str) -> io::Result<Option<Request>> {
+        let mut reader = tokio::io::BufReader::new(raw.as_bytes());
+        read_request(&mut reader).await
+    }
+
+    /// Read every request from raw bytes until the end or the first error
+    fn parse_all(raw: &[u8]) -> (Vec<Request>, Option<io::Error>) {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        runtime.block_on(async {
+            let mut reader = tokio::io::BufReader::new(raw);
+            let mut requests = Vec::new();
+            loop {
+                match read_request(&mut reader).await {
+                    Ok(Some(request)) => requests.push(request),
+                    Ok(None) => return (requests, None),
+                    Err(e) => return (requests, Some(e)),
+                }
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_read_request() {
+        let raw = "PROPFIND /Music/My%20Song.mp3?x=1 HTTP/1.1\r\n\
+                   Host: bbs.example.com\r\n\
+                   Depth: 1\r\n\
+                   Content-Length: 5\r\n\
+                   \r\n\
+                   <xml>";
+        let request = parse(raw).await.unwrap().unwrap();
+        assert_eq!(request.method, "PROPFIND");
+        assert_eq!(request.path, "/Music/My Song.mp3");
+        assert_eq!(request.header("depth"), Some("1"));
+        assert!(request.keep_alive());
+
+        assert!(parse("").await.unwrap().is_none());
+        assert!(parse("\r\n\r\n").await.unwrap().is_none());
+        assert!(parse("GET / HTTP/2\r\n\r\n").await.is_err());
+        assert!(parse("GET  / HTTP/1.1\r\n\r\n").await.is_err());
+        assert!(parse("GET / HTTP/1.1\r\nNo colon\r\n\r\n").await.is_err());
+        assert!(parse("GET / HTTP/1.1\r\nHost: x\r\n").await.is_err());
+        assert!(parse("GET \u{e9} HTTP/1.1\r\n\r\n").await.is_err());
+        assert!(parse("GET /%zz HTTP/1.1\r\n\r\n").await.is_err());
+        assert!(
+            parse("GET / HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\n")
+                .await
+                .is_err()
+        );
+        assert!(
+            parse("GET / HTTP/1.1\r\nContent-Length: 3\r\nContent-Length: 4\r\n\r\nabcd")
+                .await
+                .is_err()
+        );
+        assert!(
+            parse("GET / HTTP/1.1\r\nContent-Length: +3\r\n\r\nabc")
+                .await
+                .is_err()
+        );
+        assert!(
+            parse("GET / HTTP/1.1\r\nContent-Length: 3\r\n\r\nab")
+                .await
+                .is_err()
+        );
+
+        let request = parse("GET https://host:7504/a%2Fb HTTP/1.0\r\n\r\n")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(request.path, "/a/b");
+        assert!(!request.keep_alive());
+    }
+
+    #[test]
+    fn test_encode_href() {
+        assert_eq!(encode_href("/Music/My Song.mp3"), "/Music/My%20Song.mp3");
+        assert_eq!(
+            encode_href("/Uploads [NEXUS-UL]/café"),
+            "/Uploads%20%5BNEXUS-UL%5D/caf%C3%A9"
+        );
+        assert_eq!(
+            percent_decode(&encode_href("/a b/ü&#?")).unwrap(),
+            "/a b/ü&#?"
+        );
+    }
+
+    #[test]
+    fn test_http_date() {
+        assert_eq!(http_date(784111777), "Sun, 06 Nov 1994 08:49:37 GMT");
+    }
+
+    #[test]
+    fn test_parse_range() {
+        assert_eq!(parse_range(None, 100), ByteRange::Full);
+        assert_eq!(
+            parse_range(Some("bytes=0-49"), 100),
+            ByteRange::Partial(0, 49)
+        );
+        assert_eq!(
+            parse_range(Some("bytes=50-"), 100),
+            ByteRange::Partial(50, 99)
+        );
+        assert_eq!(
+            parse_range(Some("bytes=-10"), 100),
+            ByteRange::Partial(90, 99)
+        );
+        assert_eq!(
+            parse_range(Some("bytes=90-500"), 100),
+            ByteRange::Partial(90, 99)
+        );
+        assert_eq!(
+            parse_range(Some("bytes=100-"), 100),
+            ByteRange::Unsatisfiable
+        );
+        assert_eq!(parse_range(Some("bytes=0-1,5-6"), 100), ByteRange::Full);
+        assert_eq!(parse_range(Some("bytes=5-1"), 100), ByteRange::Full);
+        assert_eq!(parse_range(Some("items=0-1"), 100), ByteRange::Full);
+        assert_eq!(parse_range(Some("bytes=-"), 100), ByteRange::Full);
+        assert_eq!(parse_range(Some("bytes=-0"), 100), ByteRange::Unsatisfiable);
+        assert_eq!(parse_range(Some("bytes=0-"), 0), ByteRange::Unsatisfiable);
+        assert_eq!(parse_range(Some("bytes=-5"), 0), ByteRange::Unsatisfiable);
+        assert_eq!(
+            parse_range(Some("bytes=-500"), 100),
+            ByteRange::Partial(0, 99)
+        );
+        assert_eq!(
+            parse_range(Some("bytes=18446744073709551616-"), 100),
+            ByteRange::Full
+        );
+    }
+
+    /// Header lines that read_request accepts
+    fn header_line() -> impl Strategy<Value = (String, String)> {
+        ("[A-Za-z][A-Za-z0-9-]{0,15}", "[ -~]{0,40}").prop_filter(
+            "length and encoding headers change how the body is read",
+            |(name, _)| {
+                !name.eq_ignore_ascii_case("content-length")
+                    && !name.eq_ignore_ascii_case("transfer-encoding")
+            },
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn prop_arbitrary_bytes_never_panic(raw in proptest::collection::vec(any::<u8>(), 0..2048)) {
+            let (requests, _) = parse_all(&raw);
+            for request in requests {
+                prop_assert!(request.path.starts_with('/'));
+                prop_assert!(request.headers.len() <= MAX_HEADERS);
+            }
+        }
+
+        #[test]
+        fn prop_request_like_text_never_panics(
+            raw in "((GET|PROPFIND|HEAD) [ -~]{0,20} HTTP/1\\.[01]\r\n)?([ -~]{0,30}\r?\n){0,8}",
+        ) {
+            let (requests, _) = parse_all(raw.as_bytes());
+            for request in requests {
+                prop_assert!(request.path.starts_with('/'));
+            }
+        }
+
+        #[test]
+        fn prop_pipelined_requests_round_trip(
+            method in "[A-Z]{1,10}",
+            path in "(/[^/?#\\x00-\\x1f]{0,12}){1,4}",
+            headers in proptest::collection::vec(header_line(), 0..8),
+            body in proptest::collection::vec(any::<u8>(), 0..256),
+        ) {
+            let mut raw = format!("{method} {} HTTP/1.1\r\n", encode_href(&path)).into_bytes();
+            for (name, value) in &headers {
+                raw.extend_from_slice(format!("{name}: {value}\r\n").as_bytes());
+            }
+            raw.extend_from_slice(format!("Content-Length: {}\r\n\r\n", body.len()).as_bytes());
+            raw.extend_from_slice(&body);
+            // A second request right behind the body must be read intact
+            raw.extend_from_slice(b"OPTIONS / HTTP/1.1\r\n\r\n");
+
+            let (requests, error) = parse_all(&raw);
+            prop_assert!(error.is_none(), "{:?}", error);
+            prop_assert_eq!(requests.len(), 2);
+            prop_assert_eq!(&requests[0].method, &method);
+            prop_assert_eq!(&requests[0].path, &path);
+            for (name, value) in &headers {
+                let found = requests[0]
+                    .headers
+                    .iter()
+                    .any(|(n, v)| *n == name.to_ascii_lowercase() && v == value.trim());
+                prop_assert!(found);
+            }
+            prop_assert_eq!(&requests[1].method, "OPTIONS");
+        }
+
+        #[test]
+        fn prop_chunked_bodies_are_refused(
+            name in "(?i)transfer-encoding",
+            value in "[ -~]{0,20}",
+            chunks in "([0-9a-fA-F]{1,4}(;[a-z]{0,5})?\r\n[ -~]{0,16}\r\n){0,4}(0\r\n\r\n)?",
+        ) {
+            let raw = format!("PUT /a HTTP/1.1\r\n{name}: {value}\r\n\r\n{chunks}");
+            let (requests, error) = parse_all(raw.as_bytes());
+            prop_assert!(requests.is_empty());
+            prop_assert!(error.is_some());
+        }
+
+        #[test]
+        fn prop_range_stays_inside_file(header in "(bytes=)?[-0-9, ]{0,24}", size in 0u64..1_000_000) {
+            match parse_range(Some(&header), size) {
+                ByteRange::Partial(start, end) => prop_assert!(start <= end && end < size),
+                ByteRange::Full | ByteRange::Unsatisfiable => {}
+            }
+        }
+
+        #[test]
+        fn prop_range_bounds(start in 0u64..2_000, len in 0u64..2_000, size in 0u64..2_000) {
+            let end = start + len;
+            let expected = if start >= size {
+                ByteRange::Unsatisfiable
+            } else {
+                ByteRange::Partial(start, end.min(size - 1))
+            };
+            prop_assert_eq!(parse_range(Some(&format!("bytes={start}-{end}")), size), expected);
+        }
+    }
+}
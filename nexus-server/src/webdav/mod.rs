@@ -0,0 +1,515 @@
+//! Read-only WebDAV gateway for the file area (port 7504, enabled with `--webdav`)
+//!
+//! Lets users mount the file area in their operating system's file manager
+//! (Finder, Windows Explorer, GNOME Files, davfs2) and browse it without the
+//! client. It speaks plain HTTP/1.1 over TLS with the server's certificate:
+//!
+//! - `OPTIONS` advertises DAV class 1 and the allowed methods
+//! - `PROPFIND` describes a file or directory and, unless `Depth: 0` is
+//!   requested, the directory's contents (deeper listings are answered one
+//!   level at a time)
+//! - `GET` and `HEAD` download files, with single byte ranges so media can be
+//!   played and seeked in place
+//!
+//! Every other method (PUT, DELETE, MKCOL, LOCK, ...) is refused with 405,
+//! so the share mounts read-only.
+//!
+//! Requests authenticate with HTTP Basic auth against Nexus accounts (the
+//! guest account with an empty username and password, if it's enabled).
+//! Each user sees the same file area they'd see in the client: listing needs
+//! `file_list`, downloading needs `file_download`, dotfiles are hidden and
+//! drop box contents are only visible to their owner and admins.
+
+mod auth;
+mod http;
+mod propfind;
+
+use std::fs::Metadata;
+use std::io::{self, SeekFrom};
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use tokio::io::{AsyncSeekExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio_rustls::TlsAcceptor;
+
+use crate::db::{Database, Permission};
+use crate::files::{build_and_validate_candidate_path, resolve_path, resolve_user_area};
+use crate::handlers::{read_directory_entries, should_hide_entry};
+use crate::ldap::Ldap;
+
+use auth::{DavUser, authenticate, parse_basic_auth};
+use http::{Body, ByteRange, Request, Response, http_date, parse_range, read_request};
+use propfind::{DavEntry, etag, multistatus};
+
+/// How long an idle connection is kept open between requests
+const IDLE_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Methods the gateway answers
+const ALLOWED_METHODS: &str = "OPTIONS, PROPFIND, GET, HEAD";
+
+/// Methods allowed on a directory
+const ALLOWED_DIRECTORY_METHODS: &str = "OPTIONS, PROPFIND";
+
+/// Realm shown in the client's login prompt
+const AUTH_CHALLENGE: &str = "Basic realm=\"Nexus\", charset=\"UTF-8\"";
+
+/// Parameters for a WebDAV connection
+pub struct WebDavParams {
+    pub peer_addr: SocketAddr,
+    pub db: Database,
+    pub debug: bool,
+    pub file_root: &'static Path,
+    /// Directory logins (accounts created through LDAP are checked there)
+    pub ldap: Ldap,
+}
+
+/// Handle a WebDAV connection (HTTP/1.1 over TLS, keep-alive)
+pub async fn handle_webdav_connection(
+    socket: TcpStream,
+    tls_acceptor: TlsAcceptor,
+    params: WebDavParams,
+) -> io::Result<()> {
+    // Perform TLS handshake (mandatory, same cert as main port)
+    let tls_stream = tls_acceptor
+        .accept(socket)
+        .await
+        .map_err(|e| io::Error::other(format!("TLS handshake failed: {e}")))?;
+
+    let WebDavParams {
+        peer_addr,
+        db,
+        debug,
+        file_root,
+        ldap,
+    } = params;
+
+    let (reader, mut writer) = tokio::io::split(tls_stream);
+    let mut reader = BufReader::new(reader);
+
+    // Credentials already checked on this connection, so later requests
+    // don't pay for another password hash
+    let mut session: Option<(String, DavUser)> = None;
+
+    loop {
+        let request = match tokio::time::timeout(IDLE_TIMEOUT, read_request(&mut reader)).await {
+            Ok(Ok(Some(request))) => request,
+            Ok(Ok(None)) | Err(_) => break,
+            Ok(Err(e)) => {
+                if debug {
+                    log_debug!("WebDAV bad request from {peer_addr}: {e}");
+                }
+                let _ = http::write_response(&mut writer, Response::empty(400), false).await;
+                break;
+            }
+        };
+        let mut keep_alive = request.keep_alive();
+
+        let response = if request.method == "OPTIONS" {
+            options()
+        } else {
+            match authorize(&request, &db, &ldap, &mut session).await {
+                Ok(user) => {
+                    if debug {
+                        log_debug!(
+                            "WebDAV {} {} from {peer_addr} ({})",
+                            request.method,
+                            request.path,
+                            user.username
+                        );
+                    }
+                    handle_request(&request, &user, file_root).await
+                }
+                Err(response) => {
+                    // A wrong password ends the connection rather than
+                    // allowing unlimited guesses over it
+                    if request.header("authorization").is_some() {
+                        if debug {
+                            log_debug!("WebDAV login failed from {peer_addr}");
+                        }
+                        keep_alive = false;
+                    }
+                    response
+                }
+            }
+        };
+
+        if let Err(e) = http::write_response(&mut writer, response, keep_alive).await {
+            if debug {
+                log_debug!("WebDAV connection from {peer_addr} ended: {e}");
+            }
+            return Ok(());
+        }
+        if !keep_alive {
+            break;
+        }
+    }
+
+    let _ = writer.shutdown().await;
+    Ok(())
+}
+
+/// Authenticate a request, reusing the connection's earlier login
+///
+/// Returns the response to send instead when the request isn't authorized.
+async fn authorize(
+    request: &Request,
+    db: &Database,
+    ldap: &Ldap,
+    session: &mut Option<(String, DavUser)>,
+) -> Result<DavUser, Response> {
+    let challenge = || Response::empty(401).with_header("WWW-Authenticate", AUTH_CHALLENGE);
+
+    let Some(header) = request.header("authorization") else {
+        return Err(challenge());
+    };
+    if let Some((checked, user)) = session.as_ref()
+        && checked == header
+    {
+        return Ok(user.clone());
+    }
+
+    let Some((username, password)) = parse_basic_auth(header) else {
+        return Err(challenge());
+    };
+    match authenticate(db, ldap, &username, &password).await {
+        Ok(Some(user)) => {
+            *session = Some((header.to_string(), user.clone()));
+            Ok(user)
+        }
+        Ok(None) => Err(challenge()),
+        Err(e) => {
+            log_error!("WebDAV authentication error: {}", e);
+            Err(Response::empty(500))
+        }
+    }
+}
+
+/// Answer OPTIONS (no login needed, clients probe before authenticating)
+fn options() -> Response {
+    Response::empty(200)
+        .with_header("DAV", "1")
+        .with_header("Allow", ALLOWED_METHODS)
+}
+
+/// Dispatch an authenticated request
+async fn handle_request(request: &Request, user: &DavUser, file_root: &Path) -> Response {
+    match request.method.as_str() {
+        "PROPFIND" => handle_propfind(request, user, file_root).await,
+        "GET" => handle_get(request, user, file_root).await,
+        "HEAD" => handle_get(request, user, file_root).await.without_body(),
+        _ => Response::empty(405).with_header("Allow", ALLOWED_METHODS),
+    }
+}
+
+/// A request path resolved in the user's area
+struct Resolved {
+    /// Canonical root of the user's area
+    area_root: PathBuf,
+    /// Path under the area root, before symlinks are followed
+    candidate: PathBuf,
+    /// Canonical path on disk
+    path: PathBuf,
+}
+
+/// Resolve a request path in the user's area
+///
+/// Returns `None` for paths that don't exist and for paths the user can't
+/// see, so hidden files can't be told apart from missing ones.
+fn resolve(request_path: &str, user: &DavUser, file_root: &Path) -> Option<Resolved> {
+    // Dotfiles are left out of listings, so they can't be fetched either
+    if request_path
+        .split('/')
+        .any(|segment| segment.starts_with('.'))
+    {
+        return None;
+    }
+
+    let area_root = resolve_user_area(file_root, &user.username)
+        .canonicalize()
+        .ok()?;
+    let candidate = build_and_validate_candidate_path(&area_root, request_path).ok()?;
+    let path = resolve_path(&area_root, &candidate).ok()?;
+
+    // Drop box contents stay hidden from users who can't list them
+    if candidate != area_root
+        && let Some(parent) = candidate.parent()
+        && should_hide_entry(parent, &area_root, user.is_admin, &user.username)
+    {
+        return None;
+    }
+
+    Some(Resolved {
+        area_root,
+        candidate,
+        path,
+    })
+}
+
+/// Last modified time of a file as Unix timestamp
+fn modified_secs(metadata: &Metadata) -> i64 {
+    metadata
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+/// Join a share path and an entry name
+fn join_path(dir: &str, name: &str) -> String {
+    format!("{}/{}", dir.trim_end_matches('/'), name)
+}
+
+/// Handle PROPFIND: describe a resource and a directory's contents
+async fn handle_propfind(request: &Request, user: &DavUser, file_root: &Path) -> Response {
+    if !user.has_permission(Permission::FileList) {
+        return Response::empty(403);
+    }
+    let Some(resolved) = resolve(&request.path, user, file_root) else {
+        return Response::empty(404);
+    };
+    let Ok(metadata) = tokio::fs::metadata(&resolved.path).await else {
+        return Response::empty(404);
+    };
+
+    let name = if resolved.candidate == resolved.area_root {
+        String::new()
+    } else {
+        resolved
+            .candidate
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default()
+            .to_string()
+    };
+    let mut entries = vec![DavEntry {
+        path: request.path.clone(),
+        name,
+        is_directory: metadata.is_dir(),
+        size: if metadata.is_dir() { 0 } else { metadata.len() },
+        modified: modified_secs(&metadata),
+    }];
+
+    let list_contents = metadata.is_dir()
+        && request.header("depth") != Some("0")
+        && !should_hide_entry(
+            &resolved.candidate,
+            &resolved.area_root,
+            user.is_admin,
+            &user.username,
+        );
+    if list_contents {
+        let Resolved {
+            area_root, path, ..
+        } = resolved;
+        let contents =
+            tokio::task::spawn_blocking(move || read_directory_entries(&path, &area_root, false))
+                .await
+                .ok()
+                .flatten()
+                .unwrap_or_default();
+
+        entries.extend(contents.into_iter().map(|entry| DavEntry {
+            path: join_path(&request.path, &entry.name),
+            is_directory: entry.dir_type.is_some(),
+            size: entry.size,
+            modified: entry.modified,
+            name: entry.name,
+        }));
+    }
+
+    Response::bytes(
+        207,
+        "application/xml; charset=utf-8",
+        multistatus(&entries).into_bytes(),
+    )
+}
+
+/// Handle GET (and HEAD): send a file or part of it
+async fn handle_get(request: &Request, user: &DavUser, file_root: &Path) -> Response {
+    if !user.has_permission(Permission::FileDownload) {
+        return Response::empty(403);
+    }
+    let Some(resolved) = resolve(&request.path, user, file_root) else {
+        return Response::empty(404);
+    };
+    let Ok(metadata) = tokio::fs::metadata(&resolved.path).await else {
+        return Response::empty(404);
+    };
+    if metadata.is_dir() {
+        return Response::empty(405).with_header("Allow", ALLOWED_DIRECTORY_METHODS);
+    }
+    let Ok(mut file) = tokio::fs::File::open(&resolved.path).await else {
+        return Response::empty(404);
+    };
+
+    let size = metadata.len();
+    let modified = modified_secs(&metadata);
+    let (status, start, len) = match parse_range(request.header("range"), size) {
+        ByteRange::Full => (200, 0, size),
+        ByteRange::Partial(start, end) => (206, start, end - start + 1),
+        ByteRange::Unsatisfiable => {
+            return Response::empty(416).with_header("Content-Range", format!("bytes */{size}"));
+        }
+    };
+    if start > 0 && file.seek(SeekFrom::Start(start)).await.is_err() {
+        return Response::empty(500);
+    }
+
+    let response = Response {
+        status,
+        headers: vec![
+            ("Content-Type", "application/octet-stream".to_string()),
+            ("Accept-Ranges", "bytes".to_string()),
+            ("ETag", etag(size, modified)),
+            ("Last-Modified", http_date(modified)),
+        ],
+        body: Body::File { file, len },
+        content_length: len,
+    };
+    if status == 206 {
+        response.with_header(
+            "Content-Range",
+            format!("bytes {start}-{}/{size}", start + len - 1),
+        )
+    } else {
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::fs;
+
+    fn user(permissions: &[Permission]) -> DavUser {
+        DavUser {
+            username: "alice".to_string(),
+            is_admin: false,
+            permissions: permissions.iter().copied().collect::<HashSet<_>>(),
+        }
+    }
+
+    fn get_request(path: &str, range: Option<&str>) -> Request {
+        Request {
+            method: "GET".to_string(),
+            path: path.to_string(),
+            http10: false,
+            headers: range
+                .map(|range| vec![("range".to_string(), range.to_string())])
+                .unwrap_or_default(),
+        }
+    }
+
+    fn setup_file_area() -> tempfile::TempDir {
+        let file_root = tempfile::TempDir::new().unwrap();
+        let shared = file_root.path().join("shared");
+        fs::create_dir_all(shared.join("Music")).unwrap();
+        fs::write(shared.join("Music/song.mp3"), b"0123456789").unwrap();
+        fs::write(shared.join("Music/.hidden"), b"x").unwrap();
+        fs::create_dir_all(shared.join("Inbox [NEXUS-DB]")).unwrap();
+        fs::write(shared.join("Inbox [NEXUS-DB]/secret.txt"), b"x").unwrap();
+        file_root
+    }
+
+    #[tokio::test]
+    async fn test_propfind_lists_visible_entries() {
+        let file_root = setup_file_area();
+        let alice = user(&[Permission::FileList]);
+        let request = Request {
+            method: "PROPFIND".to_string(),
+            ..get_request("/", None)
+        };
+
+        let response = handle_request(&request, &alice, file_root.path()).await;
+        assert_eq!(response.status, 207);
+        let Body::Bytes(body) = response.body else {
+            panic!("Expected a multistatus body");
+        };
+        let xml = String::from_utf8(body).unwrap();
+        assert!(xml.contains("<D:href>/Music/</D:href>"));
+        assert!(xml.contains("<D:href>/Inbox%20%5BNEXUS-DB%5D/</D:href>"));
+
+        let request = Request {
+            method: "PROPFIND".to_string(),
+            ..get_request("/Music", None)
+        };
+        let response = handle_request(&request, &alice, file_root.path()).await;
+        let Body::Bytes(body) = response.body else {
+            panic!("Expected a multistatus body");
+        };
+        let xml = String::from_utf8(body).unwrap();
+        assert!(xml.contains("<D:href>/Music/song.mp3</D:href>"));
+        assert!(!xml.contains(".hidden"));
+
+        // Drop box contents and dotfiles are hidden
+        let request = Request {
+            method: "PROPFIND".to_string(),
+            ..get_request("/Inbox [NEXUS-DB]/secret.txt", None)
+        };
+        let response = handle_request(&request, &alice, file_root.path()).await;
+        assert_eq!(response.status, 404);
+        let request = Request {
+            method: "PROPFIND".to_string(),
+            ..get_request("/Music/.hidden", None)
+        };
+        let response = handle_request(&request, &alice, file_root.path()).await;
+        assert_eq!(response.status, 404);
+    }
+
+    #[tokio::test]
+    async fn test_get_checks_permissions_and_ranges() {
+        let file_root = setup_file_area();
+
+        let response = handle_request(
+            &get_request("/Music/song.mp3", None),
+            &user(&[Permission::FileList]),
+            file_root.path(),
+        )
+        .await;
+        assert_eq!(response.status, 403);
+
+        let alice = user(&[Permission::FileDownload]);
+        let response = handle_request(
+            &get_request("/Music/song.mp3", None),
+            &alice,
+            file_root.path(),
+        )
+        .await;
+        assert_eq!(response.status, 200);
+        assert_eq!(response.content_length, 10);
+
+        let response = handle_request(
+            &get_request("/Music/song.mp3", Some("bytes=4-")),
+            &alice,
+            file_root.path(),
+        )
+        .await;
+        assert_eq!(response.status, 206);
+        assert_eq!(response.content_length, 6);
+        assert!(
+            response
+                .headers
+                .contains(&("Content-Range", "bytes 4-9/10".to_string()))
+        );
+
+        let response = handle_request(
+            &get_request("/Music/song.mp3", Some("bytes=10-")),
+            &alice,
+            file_root.path(),
+        )
+        .await;
+        assert_eq!(response.status, 416);
+
+        let response = handle_request(&get_request("/Music", None), &alice, file_root.path()).await;
+        assert_eq!(response.status, 405);
+
+        let mut put = get_request("/Music/new.txt", None);
+        put.method = "PUT".to_string();
+        let response = handle_request(&put, &alice, file_root.path()).await;
+        assert_eq!(response.status, 405);
+    }
+}
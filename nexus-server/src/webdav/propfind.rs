@@ -0,0 +1,120 @@
+//! PROPFIND multistatus responses
+//!
+//! Every resource gets the same live properties, whatever the request body
+//! asked for: file managers only use these, and sending them all saves
+//! parsing XML.
+
+use super::http::{encode_href, http_date};
+
+/// A file or directory described in a PROPFIND response
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct DavEntry {
+    /// Decoded path of the resource in the share
+    pub path: String,
+    /// Filesystem name (empty for the share root)
+    pub name: String,
+    pub is_directory: bool,
+    /// File size in bytes (0 for directories)
+    pub size: u64,
+    /// Last modified time as Unix timestamp
+    pub modified: i64,
+}
+
+/// Entity tag for a file (changes whenever its size or modified time does)
+pub(super) fn etag(size: u64, modified: i64) -> String {
+    format!("\"{size:x}-{modified:x}\"")
+}
+
+/// Escape text for an XML element
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Build a multistatus document for the given entries
+pub(super) fn multistatus(entries: &[DavEntry]) -> String {
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<D:multistatus xmlns:D=\"DAV:\">\n",
+    );
+
+    for entry in entries {
+        // Collections are addressed with a trailing slash
+        let mut href = encode_href(&entry.path);
+        if entry.is_directory && !href.ends_with('/') {
+            href.push('/');
+        }
+
+        xml.push_str("<D:response><D:href>");
+        xml.push_str(&xml_escape(&href));
+        xml.push_str("</D:href><D:propstat><D:prop>");
+        xml.push_str(&format!(
+            "<D:displayname>{}</D:displayname>",
+            xml_escape(&entry.name)
+        ));
+        xml.push_str(&format!(
+            "<D:getlastmodified>{}</D:getlastmodified>",
+            http_date(entry.modified)
+        ));
+        if entry.is_directory {
+            xml.push_str("<D:resourcetype><D:collection/></D:resourcetype>");
+        } else {
+            xml.push_str(&format!(
+                "<D:resourcetype/><D:getcontentlength>{}</D:getcontentlength>\
+                 <D:getcontenttype>application/octet-stream</D:getcontenttype>\
+                 <D:getetag>{}</D:getetag>",
+                entry.size,
+                xml_escape(&etag(entry.size, entry.modified))
+            ));
+        }
+        xml.push_str("</D:prop><D:status>HTTP/1.1 200 OK</D:status></D:propstat></D:response>\n");
+    }
+
+    xml.push_str("</D:multistatus>\n");
+    xml
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multistatus() {
+        let xml = multistatus(&[
+            DavEntry {
+                path: "/Music".to_string(),
+                name: "Music".to_string(),
+                is_directory: true,
+                size: 0,
+                modified: 0,
+            },
+            DavEntry {
+                path: "/Music/Tom & Jerry.mp3".to_string(),
+                name: "Tom & Jerry.mp3".to_string(),
+                is_directory: false,
+                size: 255,
+                modified: 16,
+            },
+        ]);
+
+        assert!(xml.starts_with("<?xml"));
+        assert!(xml.contains("<D:href>/Music/</D:href>"));
+        assert!(xml.contains("<D:resourcetype><D:collection/></D:resourcetype>"));
+        assert!(xml.contains("<D:href>/Music/Tom%20%26%20Jerry.mp3</D:href>"));
+        assert!(xml.contains("<D:displayname>Tom &amp; Jerry.mp3</D:displayname>"));
+        assert!(xml.contains("<D:getcontentlength>255</D:getcontentlength>"));
+        assert!(xml.contains("<D:getetag>&quot;ff-10&quot;</D:getetag>"));
+        assert!(xml.contains("<D:getlastmodified>Thu, 01 Jan 1970 00:00:00 GMT"));
+        assert_eq!(xml.matches("<D:response>").count(), 2);
+        assert!(xml.ends_with("</D:multistatus>\n"));
+    }
+}
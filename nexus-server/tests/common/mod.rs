@@ -3,18 +3,17 @@
 use std::collections::HashSet;
 use std::net::SocketAddr;
 
-use nexus_common::framing::MessageId;
-use nexus_common::protocol::ServerMessage;
 use nexus_server::db::{Database, Permission};
+use nexus_server::outbound::{self, OutboundReceiver};
 use nexus_server::users::UserManager;
 use nexus_server::users::user::NewSessionParams;
-use tokio::sync::mpsc;
 
 /// Default locale for integration tests
 #[allow(unused)] // Not all test files use this
 pub const DEFAULT_TEST_LOCALE: &str = "en";
 
 /// Create an in-memory test database with migrations applied
+#[allow(unused)] // Not all test files use this
 pub async fn create_test_db() -> Database {
     let pool = sqlx::SqlitePool::connect(":memory:")
         .await
@@ -47,11 +46,8 @@ pub async fn add_test_user(
     username: &str,
     is_admin: bool,
     permissions: HashSet<Permission>,
-) -> (
-    u32,
-    mpsc::UnboundedReceiver<(ServerMessage, Option<MessageId>)>,
-) {
-    let (tx, rx) = mpsc::unbounded_channel();
+) -> (u32, OutboundReceiver) {
+    let (tx, rx) = outbound::channel(Default::default());
     let addr: SocketAddr = "127.0.0.1:8000".parse().unwrap();
     let created_at = chrono::Utc::now().timestamp();
 
@@ -73,6 +69,7 @@ pub async fn add_test_user(
             nickname: username.to_string(),
             is_away: false,
             status: None,
+            client: None,
         })
         .await
         .expect("Failed to add user to UserManager");
@@ -0,0 +1,338 @@
+//! Load test for UserManager under chat traffic
+//!
+//! Simulates a busy server: 1,200 sessions spread over a dozen channels, with
+//! concurrent senders fanning chat out to every channel member the way the
+//! chat handler does, while other tasks set away status and log sessions in
+//! and out. The same workload is run against a single-lock session map (how
+//! UserManager stored sessions before it was sharded) for comparison.
+//!
+//! Ignored by default since it's a benchmark. Run it in release mode:
+//!
+//! ```text
+//! cargo test --release -p nexus-server --test load -- --ignored --nocapture
+//! ```
+
+mod common;
+
+use std::collections::{HashMap, HashSet};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+
+use common::add_test_user;
+use nexus_common::protocol::{ChatAction, ServerMessage};
+use nexus_server::db::Permission;
+use nexus_server::outbound::{self, OutboundReceiver, SharedFrame};
+use nexus_server::users::UserManager;
+use nexus_server::users::user::{NewSessionParams, UserSession};
+
+/// Sessions in chat channels
+const SESSIONS: u32 = 1_200;
+
+/// Channels the sessions are spread over
+const CHANNELS: usize = 12;
+
+/// Concurrent chat senders
+const SENDERS: usize = 64;
+
+/// Messages each sender sends
+const MESSAGES_PER_SENDER: usize = 100;
+
+/// Concurrent tasks setting away status
+const STATUS_UPDATERS: usize = 8;
+
+/// Concurrent tasks logging sessions in and out
+const LOGIN_CHURNERS: usize = 4;
+
+/// Sessions stored the way UserManager stored them before sharding
+type SingleLockSessions = Arc<RwLock<HashMap<u32, UserSession>>>;
+
+/// Result of one run
+struct Throughput {
+    delivered: u64,
+    elapsed: Duration,
+}
+
+impl Throughput {
+    fn per_second(&self) -> f64 {
+        self.delivered as f64 / self.elapsed.as_secs_f64()
+    }
+}
+
+fn chat_permissions() -> HashSet<Permission> {
+    [Permission::ChatReceive].into_iter().collect()
+}
+
+fn session_params(session_id: u32) -> (NewSessionParams, OutboundReceiver) {
+    let (tx, rx) = outbound::channel(Default::default());
+    let params = NewSessionParams {
+        session_id,
+        db_user_id: session_id as i64,
+        username: format!("user{session_id}"),
+        is_admin: false,
+        is_shared: false,
+        permissions: chat_permissions(),
+        address: "127.0.0.1:8000".parse::<SocketAddr>().unwrap(),
+        created_at: 0,
+        tx,
+        features: vec!["chat".to_string()],
+        locale: "en".to_string(),
+        avatar: None,
+        nickname: format!("user{session_id}"),
+        is_away: false,
+        status: None,
+        client: None,
+    };
+    (params, rx)
+}
+
+fn chat_frame(session_id: u32, channel: usize) -> SharedFrame {
+    SharedFrame::new(ServerMessage::ChatMessage {
+        session_id,
+        nickname: format!("user{session_id}"),
+        is_admin: false,
+        is_shared: false,
+        message: "The quick brown fox jumps over the lazy dog".to_string(),
+        action: ChatAction::Normal,
+        channel: format!("#load{channel}"),
+        timestamp: 0,
+    })
+    .expect("chat frame encodes")
+}
+
+/// Split session IDs into channel member lists
+fn channels(session_ids: &[u32]) -> Arc<Vec<Vec<u32>>> {
+    let mut channels = vec![Vec::new(); CHANNELS];
+    for (i, &session_id) in session_ids.iter().enumerate() {
+        channels[i % CHANNELS].push(session_id);
+    }
+    Arc::new(channels)
+}
+
+/// Drain a session's queue until it closes, like a connection writing to its socket
+fn drain(mut rx: OutboundReceiver) -> JoinHandle<()> {
+    tokio::spawn(async move { while rx.recv_outgoing().await.is_some() {} })
+}
+
+fn report(name: &str, throughput: &Throughput) {
+    println!(
+        "{name}: {} chat deliveries in {:.2?} ({:.0}/s)",
+        throughput.delivered,
+        throughput.elapsed,
+        throughput.per_second()
+    );
+}
+
+async fn run_user_manager() -> Throughput {
+    let user_manager = UserManager::new();
+    let mut session_ids = Vec::new();
+    let mut drains = Vec::new();
+    for i in 0..SESSIONS {
+        let (session_id, rx) = add_test_user(
+            &user_manager,
+            i as i64,
+            &format!("user{i}"),
+            false,
+            chat_permissions(),
+        )
+        .await;
+        session_ids.push(session_id);
+        drains.push(drain(rx));
+    }
+    let channels = channels(&session_ids);
+    let session_ids = Arc::new(session_ids);
+    let stop = Arc::new(AtomicBool::new(false));
+    let delivered = Arc::new(AtomicU64::new(0));
+
+    let mut background = Vec::new();
+    for updater in 0..STATUS_UPDATERS {
+        let user_manager = user_manager.clone();
+        let session_ids = session_ids.clone();
+        let stop = stop.clone();
+        background.push(tokio::spawn(async move {
+            let mut i = updater;
+            while !stop.load(Ordering::Relaxed) {
+                let session_id = session_ids[i % session_ids.len()];
+                user_manager.set_status(session_id, i % 2 == 0, None).await;
+                i += STATUS_UPDATERS;
+                tokio::task::yield_now().await;
+            }
+        }));
+    }
+    for churner in 0..LOGIN_CHURNERS {
+        let user_manager = user_manager.clone();
+        let stop = stop.clone();
+        background.push(tokio::spawn(async move {
+            let username = format!("churn{churner}");
+            while !stop.load(Ordering::Relaxed) {
+                let (session_id, _rx) =
+                    add_test_user(&user_manager, -1, &username, false, HashSet::new()).await;
+                user_manager.remove_user_and_broadcast(session_id).await;
+                tokio::task::yield_now().await;
+            }
+        }));
+    }
+
+    let start = Instant::now();
+    let mut senders = Vec::new();
+    for sender in 0..SENDERS {
+        let user_manager = user_manager.clone();
+        let channels = channels.clone();
+        let delivered = delivered.clone();
+        senders.push(tokio::spawn(async move {
+            for i in 0..MESSAGES_PER_SENDER {
+                let channel = (sender + i) % CHANNELS;
+                let members = &channels[channel];
+                let frame = chat_frame(members[sender % members.len()], channel);
+                for &member_session_id in members {
+                    if let Some(member) =
+                        user_manager.get_user_by_session_id(member_session_id).await
+                        && member.has_feature("chat")
+                        && member.has_permission(Permission::ChatReceive)
+                        && user_manager
+                            .send_frame_to_session(member_session_id, &frame)
+                            .await
+                    {
+                        delivered.fetch_add(1, Ordering::Relaxed);
+                    }
+                }
+            }
+        }));
+    }
+    for sender in senders {
+        sender.await.unwrap();
+    }
+    let elapsed = start.elapsed();
+
+    stop.store(true, Ordering::Relaxed);
+    for task in background {
+        task.await.unwrap();
+    }
+    drains.iter().for_each(JoinHandle::abort);
+
+    Throughput {
+        delivered: delivered.load(Ordering::Relaxed),
+        elapsed,
+    }
+}
+
+async fn run_single_lock() -> Throughput {
+    let sessions: SingleLockSessions = Arc::new(RwLock::new(HashMap::new()));
+    let mut session_ids = Vec::new();
+    let mut drains = Vec::new();
+    for session_id in 1..=SESSIONS {
+        let (params, rx) = session_params(session_id);
+        sessions
+            .write()
+            .await
+            .insert(session_id, UserSession::new(params));
+        session_ids.push(session_id);
+        drains.push(drain(rx));
+    }
+    let channels = channels(&session_ids);
+    let session_ids = Arc::new(session_ids);
+    let stop = Arc::new(AtomicBool::new(false));
+    let delivered = Arc::new(AtomicU64::new(0));
+
+    let mut background = Vec::new();
+    for updater in 0..STATUS_UPDATERS {
+        let sessions = sessions.clone();
+        let session_ids = session_ids.clone();
+        let stop = stop.clone();
+        background.push(tokio::spawn(async move {
+            let mut i = updater;
+            while !stop.load(Ordering::Relaxed) {
+                let session_id = session_ids[i % session_ids.len()];
+                if let Some(user) = sessions.write().await.get_mut(&session_id) {
+                    user.is_away = i % 2 == 0;
+                    user.status = None;
+                }
+                i += STATUS_UPDATERS;
+                tokio::task::yield_now().await;
+            }
+        }));
+    }
+    for churner in 0..LOGIN_CHURNERS {
+        let sessions = sessions.clone();
+        let stop = stop.clone();
+        background.push(tokio::spawn(async move {
+            let session_id = SESSIONS + 1 + churner as u32;
+            while !stop.load(Ordering::Relaxed) {
+                let (params, _rx) = session_params(session_id);
+                sessions
+                    .write()
+                    .await
+                    .insert(session_id, UserSession::new(params));
+                sessions.write().await.remove(&session_id);
+                tokio::task::yield_now().await;
+            }
+        }));
+    }
+
+    let start = Instant::now();
+    let mut senders = Vec::new();
+    for sender in 0..SENDERS {
+        let sessions = sessions.clone();
+        let channels = channels.clone();
+        let delivered = delivered.clone();
+        senders.push(tokio::spawn(async move {
+            for i in 0..MESSAGES_PER_SENDER {
+                let channel = (sender + i) % CHANNELS;
+                let members = &channels[channel];
+                let frame = chat_frame(members[sender % members.len()], channel);
+                for &member_session_id in members {
+                    let member = sessions.read().await.get(&member_session_id).cloned();
+                    if let Some(member) = member
+                        && member.has_feature("chat")
+                        && member.has_permission(Permission::ChatReceive)
+                        && sessions
+                            .read()
+                            .await
+                            .get(&member_session_id)
+                            .is_some_and(|user| user.tx.send_frame(frame.clone()).is_ok())
+                    {
+                        delivered.fetch_add(1, Ordering::Relaxed);
+                    }
+                }
+            }
+        }));
+    }
+    for sender in senders {
+        sender.await.unwrap();
+    }
+    let elapsed = start.elapsed();
+
+    stop.store(true, Ordering::Relaxed);
+    for task in background {
+        task.await.unwrap();
+    }
+    drains.iter().for_each(JoinHandle::abort);
+
+    Throughput {
+        delivered: delivered.load(Ordering::Relaxed),
+        elapsed,
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 8)]
+#[ignore = "load test; run with --ignored in release mode"]
+async fn test_chat_throughput_under_load() {
+    let expected = (SENDERS * MESSAGES_PER_SENDER) as u64 * (SESSIONS as u64 / CHANNELS as u64);
+
+    let single_lock = run_single_lock().await;
+    report("single lock", &single_lock);
+    let sharded = run_user_manager().await;
+    report("UserManager", &sharded);
+    println!(
+        "UserManager ran at {:.1}x the single-lock throughput",
+        sharded.per_second() / single_lock.per_second()
+    );
+
+    // Every member got every message in both runs
+    assert_eq!(single_lock.delivered, expected);
+    assert_eq!(sharded.delivered, expected);
+}
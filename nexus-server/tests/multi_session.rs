@@ -94,24 +94,24 @@ async fn test_multi_session_partial_disconnect() {
     assert!(!remaining_sessions.contains(&session_id2));
 
     // Sessions 1 and 3 should receive UserDisconnected message
-    let msg1 = rx1.try_recv();
+    let msg1 = rx1.try_recv_outgoing();
     assert!(msg1.is_ok(), "Session 1 should receive disconnect message");
-    match msg1.unwrap().0 {
+    match msg1.unwrap().message() {
         ServerMessage::UserDisconnected {
             session_id,
             nickname,
         } => {
-            assert_eq!(session_id, session_id2);
+            assert_eq!(*session_id, session_id2);
             assert_eq!(nickname, "alice");
         }
         _ => panic!("Expected UserDisconnected"),
     }
 
-    let msg3 = rx3.try_recv();
+    let msg3 = rx3.try_recv_outgoing();
     assert!(msg3.is_ok(), "Session 3 should receive disconnect message");
 
     // Session 2's channel should be closed (already removed)
-    let msg2 = rx2.try_recv();
+    let msg2 = rx2.try_recv_outgoing();
     assert!(
         msg2.is_err(),
         "Session 20 should not receive message (already disconnected)"
@@ -206,6 +206,9 @@ async fn test_broadcast_respects_user_list_permission() {
                     avatar: None,
                     is_away: false,
                     status: None,
+                    is_dnd: false,
+                    last_active: None,
+                    client: None,
                 },
             },
             None, // Don't exclude anyone
@@ -213,29 +216,29 @@ async fn test_broadcast_respects_user_list_permission() {
         .await;
 
     // Admin should receive (has all permissions)
-    let msg_admin = rx_admin.try_recv();
+    let msg_admin = rx_admin.try_recv_outgoing();
     assert!(
         msg_admin.is_ok(),
         "Admin should receive UserConnected message"
     );
     assert!(matches!(
-        msg_admin.unwrap().0,
+        msg_admin.unwrap().message(),
         ServerMessage::UserConnected { .. }
     ));
 
     // User with permission should receive
-    let msg_with = rx_with.try_recv();
+    let msg_with = rx_with.try_recv_outgoing();
     assert!(
         msg_with.is_ok(),
         "User with user_list permission should receive message"
     );
     assert!(matches!(
-        msg_with.unwrap().0,
+        msg_with.unwrap().message(),
         ServerMessage::UserConnected { .. }
     ));
 
     // User without permission should NOT receive
-    let msg_without = rx_without.try_recv();
+    let msg_without = rx_without.try_recv_outgoing();
     assert!(
         msg_without.is_err(),
         "User without user_list permission should NOT receive message"
@@ -291,6 +294,9 @@ async fn test_broadcast_excludes_specified_session() {
                     avatar: None,
                     is_away: false,
                     status: None,
+                    is_dnd: false,
+                    last_active: None,
+                    client: None,
                 },
             },
             Some(session_id1), // Exclude session 1
@@ -298,16 +304,16 @@ async fn test_broadcast_excludes_specified_session() {
         .await;
 
     // Session 1 should NOT receive (excluded)
-    let msg1 = rx1.try_recv();
+    let msg1 = rx1.try_recv_outgoing();
     assert!(
         msg1.is_err(),
         "Session 1 should not receive message (excluded)"
     );
 
     // Session 2 should receive
-    let msg2 = rx2.try_recv();
+    let msg2 = rx2.try_recv_outgoing();
     assert!(msg2.is_ok(), "Session 2 should receive message");
-    match msg2.unwrap().0 {
+    match msg2.unwrap().message() {
         ServerMessage::UserConnected { .. } => {}
         _ => panic!("Expected UserConnected"),
     }
@@ -259,6 +259,7 @@ fn test_file_download_message_serialization() {
     let msg = ClientMessage::FileDownload {
         path: "/Documents/readme.txt".to_string(),
         root: false,
+        include: None,
     };
 
     let json = serde_json::to_string(&msg).unwrap();
@@ -268,7 +269,7 @@ fn test_file_download_message_serialization() {
     // Deserialize back
     let parsed: ClientMessage = serde_json::from_str(&json).unwrap();
     match parsed {
-        ClientMessage::FileDownload { path, root } => {
+        ClientMessage::FileDownload { path, root, .. } => {
             assert_eq!(path, "/Documents/readme.txt");
             assert!(!root);
         }
@@ -390,6 +391,7 @@ async fn test_frame_roundtrip_file_download() {
     let msg = ClientMessage::FileDownload {
         path: "/test/file.txt".to_string(),
         root: false,
+        include: None,
     };
     let payload = serde_json::to_vec(&msg).unwrap();
     let id = MessageId::new();
@@ -415,7 +417,7 @@ async fn test_frame_roundtrip_file_download() {
     // Verify payload
     let parsed: ClientMessage = serde_json::from_slice(&frame.payload).unwrap();
     match parsed {
-        ClientMessage::FileDownload { path, root } => {
+        ClientMessage::FileDownload { path, root, .. } => {
             assert_eq!(path, "/test/file.txt");
             assert!(!root);
         }
@@ -665,6 +667,7 @@ fn test_file_upload_message_serialization() {
         file_count: 3,
         total_size: 1048576,
         root: false,
+        overwrite: false,
     };
     let json = serde_json::to_string(&msg).unwrap();
 
@@ -682,6 +685,7 @@ fn test_file_upload_message_with_root() {
         file_count: 1,
         total_size: 512,
         root: true,
+        overwrite: false,
     };
     let json = serde_json::to_string(&msg).unwrap();
 
@@ -798,6 +802,7 @@ async fn test_frame_roundtrip_file_upload() {
         file_count: 5,
         total_size: 10485760,
         root: false,
+        overwrite: false,
     };
     let payload = serde_json::to_vec(&msg).unwrap();
     let id = MessageId::new();
@@ -828,11 +833,13 @@ async fn test_frame_roundtrip_file_upload() {
             file_count,
             total_size,
             root,
+            overwrite,
         } => {
             assert_eq!(destination, "/Uploads/Projects");
             assert_eq!(file_count, 5);
             assert_eq!(total_size, 10485760);
             assert!(!root);
+            assert!(!overwrite);
         }
         _ => panic!("Wrong message type"),
     }